@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1326,6 +1326,7 @@ pub struct VaultNotificationConfig {
 
 /// Errors returned by AbortMultipartUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AbortMultipartUploadError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1335,6 +1336,8 @@ pub enum AbortMultipartUploadError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AbortMultipartUploadError {
@@ -1342,27 +1345,31 @@ impl AbortMultipartUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AbortMultipartUploadError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AbortMultipartUploadError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AbortMultipartUploadError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AbortMultipartUploadError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AbortMultipartUploadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1376,12 +1383,14 @@ impl fmt::Display for AbortMultipartUploadError {
             AbortMultipartUploadError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             AbortMultipartUploadError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AbortMultipartUploadError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            AbortMultipartUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AbortMultipartUploadError {}
 /// Errors returned by AbortVaultLock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AbortVaultLockError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1391,6 +1400,8 @@ pub enum AbortVaultLockError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AbortVaultLockError {
@@ -1398,23 +1409,31 @@ impl AbortVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AbortVaultLockError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AbortVaultLockError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(AbortVaultLockError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AbortVaultLockError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AbortVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AbortVaultLockError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AbortVaultLockError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        AbortVaultLockError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AbortVaultLockError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1428,12 +1447,14 @@ impl fmt::Display for AbortVaultLockError {
             AbortVaultLockError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             AbortVaultLockError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AbortVaultLockError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            AbortVaultLockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AbortVaultLockError {}
 /// Errors returned by AddTagsToVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToVaultError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1445,6 +1466,8 @@ pub enum AddTagsToVaultError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToVaultError {
@@ -1452,26 +1475,34 @@ impl AddTagsToVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AddTagsToVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToVaultError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddTagsToVaultError::LimitExceeded(err.msg))
+                    return RusotoError::service(AddTagsToVaultError::LimitExceeded(err.msg), &res)
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(AddTagsToVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToVaultError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsToVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AddTagsToVaultError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddTagsToVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        AddTagsToVaultError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsToVaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1486,12 +1517,14 @@ impl fmt::Display for AddTagsToVaultError {
             AddTagsToVaultError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             AddTagsToVaultError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AddTagsToVaultError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            AddTagsToVaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToVaultError {}
 /// Errors returned by CompleteMultipartUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CompleteMultipartUploadError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1501,6 +1534,8 @@ pub enum CompleteMultipartUploadError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CompleteMultipartUploadError {
@@ -1508,27 +1543,31 @@ impl CompleteMultipartUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CompleteMultipartUploadError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CompleteMultipartUploadError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CompleteMultipartUploadError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompleteMultipartUploadError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CompleteMultipartUploadError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompleteMultipartUploadError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CompleteMultipartUploadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1546,12 +1585,14 @@ impl fmt::Display for CompleteMultipartUploadError {
             }
             CompleteMultipartUploadError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CompleteMultipartUploadError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CompleteMultipartUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CompleteMultipartUploadError {}
 /// Errors returned by CompleteVaultLock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CompleteVaultLockError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1561,6 +1602,8 @@ pub enum CompleteVaultLockError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CompleteVaultLockError {
@@ -1568,25 +1611,31 @@ impl CompleteVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CompleteVaultLockError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompleteVaultLockError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CompleteVaultLockError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompleteVaultLockError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CompleteVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CompleteVaultLockError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CompleteVaultLockError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompleteVaultLockError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CompleteVaultLockError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1600,12 +1649,14 @@ impl fmt::Display for CompleteVaultLockError {
             CompleteVaultLockError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             CompleteVaultLockError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CompleteVaultLockError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CompleteVaultLockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CompleteVaultLockError {}
 /// Errors returned by CreateVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVaultError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1615,6 +1666,8 @@ pub enum CreateVaultError {
     MissingParameterValue(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVaultError {
@@ -1622,19 +1675,28 @@ impl CreateVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateVaultError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        CreateVaultError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVaultError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateVaultError::LimitExceeded(err.msg), &res)
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateVaultError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        CreateVaultError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateVaultError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1648,12 +1710,14 @@ impl fmt::Display for CreateVaultError {
             CreateVaultError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateVaultError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             CreateVaultError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateVaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVaultError {}
 /// Errors returned by DeleteArchive
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteArchiveError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1663,6 +1727,8 @@ pub enum DeleteArchiveError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteArchiveError {
@@ -1670,19 +1736,31 @@ impl DeleteArchiveError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteArchiveError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteArchiveError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteArchiveError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteArchiveError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteArchiveError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteArchiveError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteArchiveError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteArchiveError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1696,12 +1774,14 @@ impl fmt::Display for DeleteArchiveError {
             DeleteArchiveError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteArchiveError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteArchiveError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteArchiveError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteArchiveError {}
 /// Errors returned by DeleteVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVaultError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1711,6 +1791,8 @@ pub enum DeleteVaultError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVaultError {
@@ -1718,19 +1800,28 @@ impl DeleteVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteVaultError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteVaultError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteVaultError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteVaultError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteVaultError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteVaultError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1744,12 +1835,14 @@ impl fmt::Display for DeleteVaultError {
             DeleteVaultError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteVaultError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteVaultError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteVaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVaultError {}
 /// Errors returned by DeleteVaultAccessPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVaultAccessPolicyError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1759,6 +1852,8 @@ pub enum DeleteVaultAccessPolicyError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVaultAccessPolicyError {
@@ -1766,27 +1861,31 @@ impl DeleteVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVaultAccessPolicyError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVaultAccessPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVaultAccessPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVaultAccessPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1804,12 +1903,14 @@ impl fmt::Display for DeleteVaultAccessPolicyError {
             }
             DeleteVaultAccessPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteVaultAccessPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteVaultAccessPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVaultAccessPolicyError {}
 /// Errors returned by DeleteVaultNotifications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVaultNotificationsError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1819,6 +1920,8 @@ pub enum DeleteVaultNotificationsError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVaultNotificationsError {
@@ -1826,27 +1929,31 @@ impl DeleteVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVaultNotificationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVaultNotificationsError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteVaultNotificationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVaultNotificationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVaultNotificationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVaultNotificationsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVaultNotificationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1864,12 +1971,14 @@ impl fmt::Display for DeleteVaultNotificationsError {
             }
             DeleteVaultNotificationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteVaultNotificationsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteVaultNotificationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVaultNotificationsError {}
 /// Errors returned by DescribeJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeJobError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1879,6 +1988,8 @@ pub enum DescribeJobError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeJobError {
@@ -1886,19 +1997,28 @@ impl DescribeJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DescribeJobError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        DescribeJobError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1912,12 +2032,14 @@ impl fmt::Display for DescribeJobError {
             DescribeJobError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeJobError {}
 /// Errors returned by DescribeVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVaultError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1927,6 +2049,8 @@ pub enum DescribeVaultError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVaultError {
@@ -1934,19 +2058,31 @@ impl DescribeVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeVaultError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DescribeVaultError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeVaultError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        DescribeVaultError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeVaultError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeVaultError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeVaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1960,12 +2096,14 @@ impl fmt::Display for DescribeVaultError {
             DescribeVaultError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeVaultError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeVaultError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeVaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeVaultError {}
 /// Errors returned by GetDataRetrievalPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDataRetrievalPolicyError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -1973,6 +2111,8 @@ pub enum GetDataRetrievalPolicyError {
     MissingParameterValue(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDataRetrievalPolicyError {
@@ -1980,22 +2120,25 @@ impl GetDataRetrievalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDataRetrievalPolicyError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDataRetrievalPolicyError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDataRetrievalPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDataRetrievalPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDataRetrievalPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2008,12 +2151,14 @@ impl fmt::Display for GetDataRetrievalPolicyError {
             GetDataRetrievalPolicyError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             GetDataRetrievalPolicyError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             GetDataRetrievalPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetDataRetrievalPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDataRetrievalPolicyError {}
 /// Errors returned by GetJobOutput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobOutputError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2023,6 +2168,8 @@ pub enum GetJobOutputError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobOutputError {
@@ -2030,19 +2177,28 @@ impl GetJobOutputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetJobOutputError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        GetJobOutputError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetJobOutputError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        GetJobOutputError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetJobOutputError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetJobOutputError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetJobOutputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetJobOutputError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobOutputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2056,12 +2212,14 @@ impl fmt::Display for GetJobOutputError {
             GetJobOutputError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             GetJobOutputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetJobOutputError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetJobOutputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobOutputError {}
 /// Errors returned by GetVaultAccessPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVaultAccessPolicyError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2071,6 +2229,8 @@ pub enum GetVaultAccessPolicyError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVaultAccessPolicyError {
@@ -2078,27 +2238,31 @@ impl GetVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVaultAccessPolicyError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVaultAccessPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVaultAccessPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2112,12 +2276,14 @@ impl fmt::Display for GetVaultAccessPolicyError {
             GetVaultAccessPolicyError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             GetVaultAccessPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetVaultAccessPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetVaultAccessPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVaultAccessPolicyError {}
 /// Errors returned by GetVaultLock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVaultLockError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2127,6 +2293,8 @@ pub enum GetVaultLockError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVaultLockError {
@@ -2134,19 +2302,28 @@ impl GetVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetVaultLockError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        GetVaultLockError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetVaultLockError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        GetVaultLockError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetVaultLockError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVaultLockError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetVaultLockError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVaultLockError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2160,12 +2337,14 @@ impl fmt::Display for GetVaultLockError {
             GetVaultLockError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             GetVaultLockError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetVaultLockError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetVaultLockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVaultLockError {}
 /// Errors returned by GetVaultNotifications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVaultNotificationsError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2175,6 +2354,8 @@ pub enum GetVaultNotificationsError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVaultNotificationsError {
@@ -2182,27 +2363,31 @@ impl GetVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVaultNotificationsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVaultNotificationsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVaultNotificationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVaultNotificationsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVaultNotificationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2216,12 +2401,14 @@ impl fmt::Display for GetVaultNotificationsError {
             GetVaultNotificationsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             GetVaultNotificationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetVaultNotificationsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetVaultNotificationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVaultNotificationsError {}
 /// Errors returned by InitiateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InitiateJobError {
     /// <p>Returned if there is insufficient capacity to process this expedited request. This error only applies to expedited retrievals and not to standard or bulk retrievals.</p>
     InsufficientCapacity(String),
@@ -2235,6 +2422,8 @@ pub enum InitiateJobError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InitiateJobError {
@@ -2242,25 +2431,37 @@ impl InitiateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InsufficientCapacityException" => {
-                    return RusotoError::Service(InitiateJobError::InsufficientCapacity(err.msg))
+                    return RusotoError::service(
+                        InitiateJobError::InsufficientCapacity(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(InitiateJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        InitiateJobError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(InitiateJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        InitiateJobError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyEnforcedException" => {
-                    return RusotoError::Service(InitiateJobError::PolicyEnforced(err.msg))
+                    return RusotoError::service(InitiateJobError::PolicyEnforced(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(InitiateJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InitiateJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        InitiateJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InitiateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2276,12 +2477,14 @@ impl fmt::Display for InitiateJobError {
             InitiateJobError::PolicyEnforced(ref cause) => write!(f, "{}", cause),
             InitiateJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             InitiateJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            InitiateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InitiateJobError {}
 /// Errors returned by InitiateMultipartUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InitiateMultipartUploadError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2291,6 +2494,8 @@ pub enum InitiateMultipartUploadError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InitiateMultipartUploadError {
@@ -2298,27 +2503,31 @@ impl InitiateMultipartUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         InitiateMultipartUploadError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         InitiateMultipartUploadError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateMultipartUploadError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateMultipartUploadError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InitiateMultipartUploadError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateMultipartUploadError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InitiateMultipartUploadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2336,12 +2545,14 @@ impl fmt::Display for InitiateMultipartUploadError {
             }
             InitiateMultipartUploadError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             InitiateMultipartUploadError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            InitiateMultipartUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InitiateMultipartUploadError {}
 /// Errors returned by InitiateVaultLock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InitiateVaultLockError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2351,6 +2562,8 @@ pub enum InitiateVaultLockError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InitiateVaultLockError {
@@ -2358,25 +2571,31 @@ impl InitiateVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(InitiateVaultLockError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateVaultLockError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(InitiateVaultLockError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateVaultLockError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        InitiateVaultLockError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InitiateVaultLockError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateVaultLockError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InitiateVaultLockError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2390,12 +2609,14 @@ impl fmt::Display for InitiateVaultLockError {
             InitiateVaultLockError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             InitiateVaultLockError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             InitiateVaultLockError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            InitiateVaultLockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InitiateVaultLockError {}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2405,6 +2626,8 @@ pub enum ListJobsError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsError {
@@ -2412,19 +2635,25 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListJobsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListJobsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListJobsError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListJobsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListJobsError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListJobsError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2438,12 +2667,14 @@ impl fmt::Display for ListJobsError {
             ListJobsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListJobsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListJobsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsError {}
 /// Errors returned by ListMultipartUploads
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMultipartUploadsError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2453,6 +2684,8 @@ pub enum ListMultipartUploadsError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMultipartUploadsError {
@@ -2460,27 +2693,31 @@ impl ListMultipartUploadsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMultipartUploadsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMultipartUploadsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMultipartUploadsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMultipartUploadsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMultipartUploadsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2494,12 +2731,14 @@ impl fmt::Display for ListMultipartUploadsError {
             ListMultipartUploadsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListMultipartUploadsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListMultipartUploadsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListMultipartUploadsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMultipartUploadsError {}
 /// Errors returned by ListParts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPartsError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2509,6 +2748,8 @@ pub enum ListPartsError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPartsError {
@@ -2516,19 +2757,25 @@ impl ListPartsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListPartsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListPartsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListPartsError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListPartsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPartsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListPartsError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPartsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListPartsError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPartsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2542,12 +2789,14 @@ impl fmt::Display for ListPartsError {
             ListPartsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListPartsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListPartsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListPartsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPartsError {}
 /// Errors returned by ListProvisionedCapacity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProvisionedCapacityError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2555,6 +2804,8 @@ pub enum ListProvisionedCapacityError {
     MissingParameterValue(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProvisionedCapacityError {
@@ -2562,22 +2813,25 @@ impl ListProvisionedCapacityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProvisionedCapacityError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProvisionedCapacityError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProvisionedCapacityError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProvisionedCapacityError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProvisionedCapacityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2594,12 +2848,14 @@ impl fmt::Display for ListProvisionedCapacityError {
                 write!(f, "{}", cause)
             }
             ListProvisionedCapacityError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListProvisionedCapacityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProvisionedCapacityError {}
 /// Errors returned by ListTagsForVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForVaultError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2609,6 +2865,8 @@ pub enum ListTagsForVaultError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForVaultError {
@@ -2616,23 +2874,31 @@ impl ListTagsForVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsForVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForVaultError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListTagsForVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForVaultError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTagsForVaultError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListTagsForVaultError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForVaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2646,12 +2912,14 @@ impl fmt::Display for ListTagsForVaultError {
             ListTagsForVaultError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListTagsForVaultError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForVaultError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListTagsForVaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForVaultError {}
 /// Errors returned by ListVaults
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVaultsError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2661,6 +2929,8 @@ pub enum ListVaultsError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVaultsError {
@@ -2668,19 +2938,25 @@ impl ListVaultsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListVaultsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListVaultsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListVaultsError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListVaultsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListVaultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListVaultsError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVaultsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListVaultsError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVaultsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2694,12 +2970,14 @@ impl fmt::Display for ListVaultsError {
             ListVaultsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListVaultsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListVaultsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListVaultsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVaultsError {}
 /// Errors returned by PurchaseProvisionedCapacity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PurchaseProvisionedCapacityError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2709,6 +2987,8 @@ pub enum PurchaseProvisionedCapacityError {
     MissingParameterValue(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PurchaseProvisionedCapacityError {
@@ -2718,27 +2998,36 @@ impl PurchaseProvisionedCapacityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PurchaseProvisionedCapacityError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PurchaseProvisionedCapacityError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PurchaseProvisionedCapacityError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PurchaseProvisionedCapacityError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PurchaseProvisionedCapacityError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PurchaseProvisionedCapacityError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2758,12 +3047,14 @@ impl fmt::Display for PurchaseProvisionedCapacityError {
             PurchaseProvisionedCapacityError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PurchaseProvisionedCapacityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PurchaseProvisionedCapacityError {}
 /// Errors returned by RemoveTagsFromVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromVaultError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2773,6 +3064,8 @@ pub enum RemoveTagsFromVaultError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromVaultError {
@@ -2780,27 +3073,31 @@ impl RemoveTagsFromVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromVaultError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromVaultError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromVaultError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromVaultError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsFromVaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2814,12 +3111,14 @@ impl fmt::Display for RemoveTagsFromVaultError {
             RemoveTagsFromVaultError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromVaultError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromVaultError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            RemoveTagsFromVaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromVaultError {}
 /// Errors returned by SetDataRetrievalPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetDataRetrievalPolicyError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2827,6 +3126,8 @@ pub enum SetDataRetrievalPolicyError {
     MissingParameterValue(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetDataRetrievalPolicyError {
@@ -2834,22 +3135,25 @@ impl SetDataRetrievalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetDataRetrievalPolicyError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetDataRetrievalPolicyError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetDataRetrievalPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetDataRetrievalPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetDataRetrievalPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2862,12 +3166,14 @@ impl fmt::Display for SetDataRetrievalPolicyError {
             SetDataRetrievalPolicyError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             SetDataRetrievalPolicyError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             SetDataRetrievalPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            SetDataRetrievalPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetDataRetrievalPolicyError {}
 /// Errors returned by SetVaultAccessPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetVaultAccessPolicyError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2877,6 +3183,8 @@ pub enum SetVaultAccessPolicyError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetVaultAccessPolicyError {
@@ -2884,27 +3192,31 @@ impl SetVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetVaultAccessPolicyError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetVaultAccessPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetVaultAccessPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2918,12 +3230,14 @@ impl fmt::Display for SetVaultAccessPolicyError {
             SetVaultAccessPolicyError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             SetVaultAccessPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SetVaultAccessPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            SetVaultAccessPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetVaultAccessPolicyError {}
 /// Errors returned by SetVaultNotifications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetVaultNotificationsError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2933,6 +3247,8 @@ pub enum SetVaultNotificationsError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetVaultNotificationsError {
@@ -2940,27 +3256,31 @@ impl SetVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetVaultNotificationsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetVaultNotificationsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetVaultNotificationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetVaultNotificationsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetVaultNotificationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2974,12 +3294,14 @@ impl fmt::Display for SetVaultNotificationsError {
             SetVaultNotificationsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             SetVaultNotificationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SetVaultNotificationsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            SetVaultNotificationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetVaultNotificationsError {}
 /// Errors returned by UploadArchive
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UploadArchiveError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -2991,6 +3313,8 @@ pub enum UploadArchiveError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UploadArchiveError {
@@ -2998,22 +3322,34 @@ impl UploadArchiveError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UploadArchiveError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UploadArchiveError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UploadArchiveError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        UploadArchiveError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "RequestTimeoutException" => {
-                    return RusotoError::Service(UploadArchiveError::RequestTimeout(err.msg))
+                    return RusotoError::service(UploadArchiveError::RequestTimeout(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UploadArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UploadArchiveError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UploadArchiveError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UploadArchiveError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UploadArchiveError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3028,12 +3364,14 @@ impl fmt::Display for UploadArchiveError {
             UploadArchiveError::RequestTimeout(ref cause) => write!(f, "{}", cause),
             UploadArchiveError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UploadArchiveError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            UploadArchiveError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UploadArchiveError {}
 /// Errors returned by UploadMultipartPart
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UploadMultipartPartError {
     /// <p>Returned if a parameter of the request is incorrectly specified.</p>
     InvalidParameterValue(String),
@@ -3045,6 +3383,8 @@ pub enum UploadMultipartPartError {
     ResourceNotFound(String),
     /// <p>Returned if the service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UploadMultipartPartError {
@@ -3052,30 +3392,37 @@ impl UploadMultipartPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UploadMultipartPartError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UploadMultipartPartError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UploadMultipartPartError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UploadMultipartPartError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "RequestTimeoutException" => {
-                    return RusotoError::Service(UploadMultipartPartError::RequestTimeout(err.msg))
+                    return RusotoError::service(
+                        UploadMultipartPartError::RequestTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UploadMultipartPartError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UploadMultipartPartError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UploadMultipartPartError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UploadMultipartPartError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UploadMultipartPartError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3090,6 +3437,7 @@ impl fmt::Display for UploadMultipartPartError {
             UploadMultipartPartError::RequestTimeout(ref cause) => write!(f, "{}", cause),
             UploadMultipartPartError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UploadMultipartPartError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            UploadMultipartPartError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3306,6 +3654,7 @@ impl GlacierClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> GlacierClient {
         GlacierClient {
             client: Client::shared(),
@@ -3331,142 +3680,219 @@ impl GlacierClient {
     pub fn new_with_client(client: Client, region: region::Region) -> GlacierClient {
         GlacierClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<GlacierClient> {
+        rusoto_core::ClientBuilder::new(GlacierClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> GlacierClient {
+        GlacierClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Glacier for GlacierClient {
-    /// <p>This operation aborts a multipart upload identified by the upload ID.</p> <p>After the Abort Multipart Upload request succeeds, you cannot upload any more parts to the multipart upload or complete the multipart upload. Aborting a completed upload fails. However, aborting an already-aborted upload will succeed, for a short time. For more information about uploading a part and completing a multipart upload, see <a>UploadMultipartPart</a> and <a>CompleteMultipartUpload</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-abort-upload.html">Abort Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn abort_multipart_upload(
+impl GlacierClient {
+    /// Calls `abort_multipart_upload`, also returning the metadata of the response.
+    pub async fn abort_multipart_upload_with_metadata(
         &self,
         input: AbortMultipartUploadInput,
-    ) -> Result<(), RusotoError<AbortMultipartUploadError>> {
-        let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
-            account_id = input.account_id,
-            upload_id = input.upload_id,
-            vault_name = input.vault_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-glacier-version", "2012-06-01");
+    ) -> Result<((), ResponseMetadata), RusotoError<AbortMultipartUploadError>> {
+        let request = self.build_abort_multipart_upload_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(AbortMultipartUploadError::from_response(response))
         }
     }
 
-    /// <p>This operation aborts the vault locking process if the vault lock is not in the <code>Locked</code> state. If the vault lock is in the <code>Locked</code> state when this operation is requested, the operation returns an <code>AccessDeniedException</code> error. Aborting the vault locking process removes the vault lock policy from the specified vault. </p> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can get the state of a vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>This operation is idempotent. You can successfully invoke this operation multiple times, if the vault lock is in the <code>InProgress</code> state or if there is no policy associated with the vault.</p>
-    async fn abort_vault_lock(
+    /// Presigns the request `abort_multipart_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_abort_multipart_upload(
         &self,
-        input: AbortVaultLockInput,
-    ) -> Result<(), RusotoError<AbortVaultLockError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AbortMultipartUploadInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_abort_multipart_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_abort_multipart_upload_request(
+        &self,
+        input: AbortMultipartUploadInput,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/lock-policy",
+            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
             account_id = input.account_id,
+            upload_id = input.upload_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `abort_vault_lock`, also returning the metadata of the response.
+    pub async fn abort_vault_lock_with_metadata(
+        &self,
+        input: AbortVaultLockInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<AbortVaultLockError>> {
+        let request = self.build_abort_vault_lock_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(AbortVaultLockError::from_response(response))
         }
     }
 
-    /// <p>This operation adds the specified tags to a vault. Each tag is composed of a key and a value. Each vault can have up to 10 tags. If your request would cause the tag limit for the vault to be exceeded, the operation throws the <code>LimitExceededException</code> error. If a tag already exists on the vault under a specified key, the existing key value will be overwritten. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. </p>
-    async fn add_tags_to_vault(
+    /// Presigns the request `abort_vault_lock` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_abort_vault_lock(
         &self,
-        input: AddTagsToVaultInput,
-    ) -> Result<(), RusotoError<AddTagsToVaultError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AbortVaultLockInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_abort_vault_lock_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_abort_vault_lock_request(&self, input: AbortVaultLockInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/tags",
+            "/{account_id}/vaults/{vault_name}/lock-policy",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+        request
+    }
 
-        let mut params = Params::new();
-        params.put("operation", "add");
-        request.set_params(params);
+    /// Calls `add_tags_to_vault`, also returning the metadata of the response.
+    pub async fn add_tags_to_vault_with_metadata(
+        &self,
+        input: AddTagsToVaultInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<AddTagsToVaultError>> {
+        let request = self.build_add_tags_to_vault_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(AddTagsToVaultError::from_response(response))
         }
     }
 
-    /// <p>You call this operation to inform Amazon S3 Glacier (Glacier) that all the archive parts have been uploaded and that Glacier can now assemble the archive from the uploaded parts. After assembling and saving the archive to the vault, Glacier returns the URI path of the newly created archive resource. Using the URI path, you can then access the archive. After you upload an archive, you should save the archive ID returned to retrieve the archive at a later point. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>.</p> <p>In the request, you must include the computed SHA256 tree hash of the entire archive you have uploaded. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. On the server side, Glacier also constructs the SHA256 tree hash of the assembled archive. If the values match, Glacier saves the archive to the vault; otherwise, it returns an error, and the operation fails. The <a>ListParts</a> operation returns a list of parts uploaded for a specific multipart upload. It includes checksum information for each uploaded part that can be used to debug a bad checksum issue.</p> <p>Additionally, Glacier also checks for any missing content ranges when assembling the archive, if missing content ranges are found, Glacier returns an error and the operation fails.</p> <p>Complete Multipart Upload is an idempotent operation. After your first successful complete multipart upload, if you call the operation again within a short period, the operation will succeed and return the same archive ID. This is useful in the event you experience a network issue that causes an aborted connection or receive a 500 server error, in which case you can repeat your Complete Multipart Upload request and get the same archive ID without creating duplicate archives. Note, however, that after the multipart upload completes, you cannot call the List Parts operation and the multipart upload will not appear in List Multipart Uploads response, even if idempotent complete is possible.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-complete-upload.html">Complete Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn complete_multipart_upload(
+    /// Presigns the request `add_tags_to_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_vault(
         &self,
-        input: CompleteMultipartUploadInput,
-    ) -> Result<ArchiveCreationOutput, RusotoError<CompleteMultipartUploadError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToVaultInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_vault_request(&self, input: AddTagsToVaultInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
+            "/{account_id}/vaults/{vault_name}/tags",
             account_id = input.account_id,
-            upload_id = input.upload_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("AddTagsToVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
-        if let Some(ref archive_size) = input.archive_size {
-            request.add_header("x-amz-archive-size", &archive_size.to_string());
-        }
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
 
-        if let Some(ref checksum) = input.checksum {
-            request.add_header("x-amz-sha256-tree-hash", &checksum.to_string());
-        }
+        let mut params = Params::new();
+        params.put("operation", "add");
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `complete_multipart_upload`, also returning the metadata of the response.
+    pub async fn complete_multipart_upload_with_metadata(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> Result<(ArchiveCreationOutput, ResponseMetadata), RusotoError<CompleteMultipartUploadError>>
+    {
+        let request = self.build_complete_multipart_upload_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ArchiveCreationOutput, _>()?;
             if let Some(archive_id) = response.headers.get("x-amz-archive-id") {
@@ -3482,67 +3908,127 @@ impl Glacier for GlacierClient {
                 result.location = Some(value)
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CompleteMultipartUploadError::from_response(response))
         }
     }
 
-    /// <p>This operation completes the vault locking process by transitioning the vault lock from the <code>InProgress</code> state to the <code>Locked</code> state, which causes the vault lock policy to become unchangeable. A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. You can obtain the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>This operation is idempotent. This request is always successful if the vault lock is in the <code>Locked</code> state and the provided lock ID matches the lock ID originally used to lock the vault.</p> <p>If an invalid lock ID is passed in the request when the vault lock is in the <code>Locked</code> state, the operation returns an <code>AccessDeniedException</code> error. If an invalid lock ID is passed in the request when the vault lock is in the <code>InProgress</code> state, the operation throws an <code>InvalidParameter</code> error.</p>
-    async fn complete_vault_lock(
+    /// Presigns the request `complete_multipart_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_complete_multipart_upload(
         &self,
-        input: CompleteVaultLockInput,
-    ) -> Result<(), RusotoError<CompleteVaultLockError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CompleteMultipartUploadInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_complete_multipart_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_complete_multipart_upload_request(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/lock-policy/{lock_id}",
+            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
             account_id = input.account_id,
-            lock_id = input.lock_id,
+            upload_id = input.upload_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        if let Some(ref archive_size) = input.archive_size {
+            request.add_header("x-amz-archive-size", &archive_size.to_string());
+        }
+
+        if let Some(ref checksum) = input.checksum {
+            request.add_header("x-amz-sha256-tree-hash", &checksum.to_string());
+        }
+
+        request
+    }
+
+    /// Calls `complete_vault_lock`, also returning the metadata of the response.
+    pub async fn complete_vault_lock_with_metadata(
+        &self,
+        input: CompleteVaultLockInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<CompleteVaultLockError>> {
+        let request = self.build_complete_vault_lock_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CompleteVaultLockError::from_response(response))
         }
     }
 
-    /// <p>This operation creates a new vault with the specified name. The name of the vault must be unique within a region for an AWS account. You can create up to 1,000 vaults per account. If you need to create more vaults, contact Amazon S3 Glacier.</p> <p>You must use the following guidelines when naming a vault.</p> <ul> <li> <p>Names can be between 1 and 255 characters long.</p> </li> <li> <p>Allowed characters are a-z, A-Z, 0-9, '_' (underscore), '-' (hyphen), and '.' (period).</p> </li> </ul> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/creating-vaults.html">Creating a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-put.html">Create Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn create_vault(
+    /// Presigns the request `complete_vault_lock` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_complete_vault_lock(
         &self,
-        input: CreateVaultInput,
-    ) -> Result<CreateVaultOutput, RusotoError<CreateVaultError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CompleteVaultLockInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_complete_vault_lock_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_complete_vault_lock_request(&self, input: CompleteVaultLockInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}",
+            "/{account_id}/vaults/{vault_name}/lock-policy/{lock_id}",
             account_id = input.account_id,
+            lock_id = input.lock_id,
             vault_name = input.vault_name
         );
 
-        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `create_vault`, also returning the metadata of the response.
+    pub async fn create_vault_with_metadata(
+        &self,
+        input: CreateVaultInput,
+    ) -> Result<(CreateVaultOutput, ResponseMetadata), RusotoError<CreateVaultError>> {
+        let request = self.build_create_vault_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateVaultOutput, _>()?;
             if let Some(location) = response.headers.get("Location") {
@@ -3550,261 +4036,460 @@ impl Glacier for GlacierClient {
                 result.location = Some(value)
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateVaultError::from_response(response))
         }
     }
 
-    /// <p>This operation deletes an archive from a vault. Subsequent requests to initiate a retrieval of this archive will fail. Archive retrievals that are in progress for this archive ID may or may not succeed according to the following scenarios:</p> <ul> <li> <p>If the archive retrieval job is actively preparing the data for download when Amazon S3 Glacier receives the delete archive request, the archival retrieval operation might fail.</p> </li> <li> <p>If the archive retrieval job has successfully prepared the archive for download when Amazon S3 Glacier receives the delete archive request, you will be able to download the output.</p> </li> </ul> <p>This operation is idempotent. Attempting to delete an already-deleted archive does not result in an error.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-an-archive.html">Deleting an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn delete_archive(
+    /// Presigns the request `create_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_vault(
         &self,
-        input: DeleteArchiveInput,
-    ) -> Result<(), RusotoError<DeleteArchiveError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateVaultInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_vault_request(&self, input: CreateVaultInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/archives/{archive_id}",
+            "/{account_id}/vaults/{vault_name}",
             account_id = input.account_id,
-            archive_id = input.archive_id,
             vault_name = input.vault_name
         );
 
-        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("CreateVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `delete_archive`, also returning the metadata of the response.
+    pub async fn delete_archive_with_metadata(
+        &self,
+        input: DeleteArchiveInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteArchiveError>> {
+        let request = self.build_delete_archive_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteArchiveError::from_response(response))
         }
     }
 
-    /// <p>This operation deletes a vault. Amazon S3 Glacier will delete a vault only if there are no archives in the vault as of the last inventory and there have been no writes to the vault since the last inventory. If either of these conditions is not satisfied, the vault deletion fails (that is, the vault is not removed) and Amazon S3 Glacier returns an error. You can use <a>DescribeVault</a> to return the number of archives in a vault, and you can use <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job (POST jobs)</a> to initiate a new inventory retrieval for a vault. The inventory contains the archive IDs you use to delete archives using <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive (DELETE archive)</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-vaults.html">Deleting a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-delete.html">Delete Vault </a> in the <i>Amazon S3 Glacier Developer Guide</i>. </p>
-    async fn delete_vault(
+    /// Presigns the request `delete_archive` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_archive(
         &self,
-        input: DeleteVaultInput,
-    ) -> Result<(), RusotoError<DeleteVaultError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteArchiveInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_archive_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_archive_request(&self, input: DeleteArchiveInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}",
+            "/{account_id}/vaults/{vault_name}/archives/{archive_id}",
             account_id = input.account_id,
+            archive_id = input.archive_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteArchive");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `delete_vault`, also returning the metadata of the response.
+    pub async fn delete_vault_with_metadata(
+        &self,
+        input: DeleteVaultInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVaultError>> {
+        let request = self.build_delete_vault_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteVaultError::from_response(response))
         }
     }
 
-    /// <p>This operation deletes the access policy associated with the specified vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely remove the access policy, and you might still see the effect of the policy for a short time after you send the delete request.</p> <p>This operation is idempotent. You can invoke delete multiple times, even if there is no policy associated with the vault. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
-    async fn delete_vault_access_policy(
+    /// Presigns the request `delete_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vault(
         &self,
-        input: DeleteVaultAccessPolicyInput,
-    ) -> Result<(), RusotoError<DeleteVaultAccessPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteVaultInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_vault_request(&self, input: DeleteVaultInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/access-policy",
+            "/{account_id}/vaults/{vault_name}",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `delete_vault_access_policy`, also returning the metadata of the response.
+    pub async fn delete_vault_access_policy_with_metadata(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVaultAccessPolicyError>> {
+        let request = self.build_delete_vault_access_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteVaultAccessPolicyError::from_response(response))
         }
     }
 
-    /// <p>This operation deletes the notification configuration set for a vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely disable the notifications and you might still receive some notifications for a short time after you send the delete request.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-delete.html">Delete Vault Notification Configuration </a> in the Amazon S3 Glacier Developer Guide. </p>
-    async fn delete_vault_notifications(
+    /// Presigns the request `delete_vault_access_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vault_access_policy(
         &self,
-        input: DeleteVaultNotificationsInput,
-    ) -> Result<(), RusotoError<DeleteVaultNotificationsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vault_access_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_vault_access_policy_request(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/notification-configuration",
+            "/{account_id}/vaults/{vault_name}/access-policy",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `delete_vault_notifications`, also returning the metadata of the response.
+    pub async fn delete_vault_notifications_with_metadata(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVaultNotificationsError>> {
+        let request = self.build_delete_vault_notifications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteVaultNotificationsError::from_response(response))
         }
     }
 
-    /// <p>This operation returns information about a job you previously initiated, including the job initiation date, the user who initiated the job, the job status code/message and the Amazon SNS topic to notify after Amazon S3 Glacier (Glacier) completes the job. For more information about initiating a job, see <a>InitiateJob</a>. </p> <note> <p>This operation enables you to check the status of your job. However, it is strongly recommended that you set up an Amazon SNS topic and specify it in your initiate job request so that Glacier can notify the topic after it completes the job.</p> </note> <p>A job ID will not expire for at least 24 hours after Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-describe-job-get.html">Describe Job</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn describe_job(
+    /// Presigns the request `delete_vault_notifications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vault_notifications(
         &self,
-        input: DescribeJobInput,
-    ) -> Result<GlacierJobDescription, RusotoError<DescribeJobError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteVaultNotificationsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vault_notifications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_vault_notifications_request(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/jobs/{job_id}",
+            "/{account_id}/vaults/{vault_name}/notification-configuration",
             account_id = input.account_id,
-            job_id = input.job_id,
             vault_name = input.vault_name
         );
 
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `describe_job`, also returning the metadata of the response.
+    pub async fn describe_job_with_metadata(
+        &self,
+        input: DescribeJobInput,
+    ) -> Result<(GlacierJobDescription, ResponseMetadata), RusotoError<DescribeJobError>> {
+        let request = self.build_describe_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GlacierJobDescription, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeJobError::from_response(response))
         }
     }
 
-    /// <p>This operation returns information about a vault, including the vault's Amazon Resource Name (ARN), the date the vault was created, the number of archives it contains, and the total size of all the archives in the vault. The number of archives and their total size are as of the last inventory generation. This means that if you add or remove an archive from a vault, and then immediately use Describe Vault, the change in contents will not be immediately reflected. If you want to retrieve the latest inventory of the vault, use <a>InitiateJob</a>. Amazon S3 Glacier generates vault inventories approximately daily. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-get.html">Describe Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn describe_vault(
+    /// Presigns the request `describe_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_job(
         &self,
-        input: DescribeVaultInput,
-    ) -> Result<DescribeVaultOutput, RusotoError<DescribeVaultError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeJobInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_job_request(&self, input: DescribeJobInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}",
+            "/{account_id}/vaults/{vault_name}/jobs/{job_id}",
             account_id = input.account_id,
+            job_id = input.job_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `describe_vault`, also returning the metadata of the response.
+    pub async fn describe_vault_with_metadata(
+        &self,
+        input: DescribeVaultInput,
+    ) -> Result<(DescribeVaultOutput, ResponseMetadata), RusotoError<DescribeVaultError>> {
+        let request = self.build_describe_vault_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeVaultOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeVaultError::from_response(response))
         }
     }
 
-    /// <p>This operation returns the current data retrieval policy for the account and region specified in the GET request. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>.</p>
-    async fn get_data_retrieval_policy(
+    /// Presigns the request `describe_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_vault(
         &self,
-        input: GetDataRetrievalPolicyInput,
-    ) -> Result<GetDataRetrievalPolicyOutput, RusotoError<GetDataRetrievalPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeVaultInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_vault_request(&self, input: DescribeVaultInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/policies/data-retrieval",
-            account_id = input.account_id
+            "/{account_id}/vaults/{vault_name}",
+            account_id = input.account_id,
+            vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `get_data_retrieval_policy`, also returning the metadata of the response.
+    pub async fn get_data_retrieval_policy_with_metadata(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> Result<
+        (GetDataRetrievalPolicyOutput, ResponseMetadata),
+        RusotoError<GetDataRetrievalPolicyError>,
+    > {
+        let request = self.build_get_data_retrieval_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDataRetrievalPolicyOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetDataRetrievalPolicyError::from_response(response))
         }
     }
 
-    /// <p>This operation downloads the output of the job you initiated using <a>InitiateJob</a>. Depending on the job type you specified when you initiated the job, the output will be either the content of an archive or a vault inventory.</p> <p>You can download all the job output or download a portion of the output by specifying a byte range. In the case of an archive retrieval job, depending on the byte range you specify, Amazon S3 Glacier (Glacier) returns the checksum for the portion of the data. You can compute the checksum on the client and verify that the values match to ensure the portion you downloaded is the correct data.</p> <p>A job ID will not expire for at least 24 hours after Glacier completes the job. That a byte range. For both archive and inventory retrieval jobs, you should verify the downloaded size against the size returned in the headers from the <b>Get Job Output</b> response.</p> <p>For archive retrieval jobs, you should also verify that the size is what you expected. If you download a portion of the output, the expected size is based on the range of bytes you specified. For example, if you specify a range of <code>bytes=0-1048575</code>, you should verify your download size is 1,048,576 bytes. If you download an entire archive, the expected size is the size of the archive when you uploaded it to Amazon S3 Glacier The expected size is also returned in the headers from the <b>Get Job Output</b> response.</p> <p>In the case of an archive retrieval job, depending on the byte range you specify, Glacier returns the checksum for the portion of the data. To ensure the portion you downloaded is the correct data, compute the checksum on the client, verify that the values match, and verify that the size is what you expected.</p> <p>A job ID does not expire for at least 24 hours after Glacier completes the job. That is, you can download the job output within the 24 hours period after Amazon Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory</a>, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/downloading-an-archive.html">Downloading an Archive</a>, and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-job-output-get.html">Get Job Output </a> </p>
-    async fn get_job_output(
+    /// Presigns the request `get_data_retrieval_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_data_retrieval_policy(
         &self,
-        input: GetJobOutputInput,
-    ) -> Result<GetJobOutputOutput, RusotoError<GetJobOutputError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetDataRetrievalPolicyInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_data_retrieval_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_data_retrieval_policy_request(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/jobs/{job_id}/output",
-            account_id = input.account_id,
-            job_id = input.job_id,
-            vault_name = input.vault_name
+            "/{account_id}/policies/data-retrieval",
+            account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetDataRetrievalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
-        if let Some(ref range) = input.range {
-            request.add_header("Range", &range.to_string());
-        }
+        request
+    }
+
+    /// Calls `get_job_output`, also returning the metadata of the response.
+    pub async fn get_job_output_with_metadata(
+        &self,
+        input: GetJobOutputInput,
+    ) -> Result<(GetJobOutputOutput, ResponseMetadata), RusotoError<GetJobOutputError>> {
+        let request = self.build_get_job_output_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
 
             let mut result = GetJobOutputOutput::default();
             result.body = Some(response.body);
@@ -3830,134 +4515,244 @@ impl Glacier for GlacierClient {
                 result.content_type = Some(value)
             };
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetJobOutputError::from_response(response))
         }
     }
 
-    /// <p>This operation retrieves the <code>access-policy</code> subresource set on the vault; for more information on setting this subresource, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-SetVaultAccessPolicy.html">Set Vault Access Policy (PUT access-policy)</a>. If there is no access policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>.</p>
-    async fn get_vault_access_policy(
+    /// Presigns the request `get_job_output` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job_output(
         &self,
-        input: GetVaultAccessPolicyInput,
-    ) -> Result<GetVaultAccessPolicyOutput, RusotoError<GetVaultAccessPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetJobOutputInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_output_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_output_request(&self, input: GetJobOutputInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/access-policy",
+            "/{account_id}/vaults/{vault_name}/jobs/{job_id}/output",
             account_id = input.account_id,
+            job_id = input.job_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetJobOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        if let Some(ref range) = input.range {
+            request.add_header("Range", &range.to_string());
+        }
+
+        request
+    }
+
+    /// Calls `get_vault_access_policy`, also returning the metadata of the response.
+    pub async fn get_vault_access_policy_with_metadata(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> Result<
+        (GetVaultAccessPolicyOutput, ResponseMetadata),
+        RusotoError<GetVaultAccessPolicyError>,
+    > {
+        let request = self.build_get_vault_access_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVaultAccessPolicyOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetVaultAccessPolicyError::from_response(response))
         }
     }
 
-    /// <p>This operation retrieves the following attributes from the <code>lock-policy</code> subresource set on the specified vault: </p> <ul> <li> <p>The vault lock policy set on the vault.</p> </li> <li> <p>The state of the vault lock, which is either <code>InProgess</code> or <code>Locked</code>.</p> </li> <li> <p>When the lock ID expires. The lock ID is used to complete the vault locking process.</p> </li> <li> <p>When the vault lock was initiated and put into the <code>InProgress</code> state.</p> </li> </ul> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can abort the vault locking process by calling <a>AbortVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>If there is no vault lock policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault lock policies, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p>
-    async fn get_vault_lock(
+    /// Presigns the request `get_vault_access_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_vault_access_policy(
         &self,
-        input: GetVaultLockInput,
-    ) -> Result<GetVaultLockOutput, RusotoError<GetVaultLockError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetVaultAccessPolicyInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_vault_access_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_vault_access_policy_request(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/lock-policy",
+            "/{account_id}/vaults/{vault_name}/access-policy",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `get_vault_lock`, also returning the metadata of the response.
+    pub async fn get_vault_lock_with_metadata(
+        &self,
+        input: GetVaultLockInput,
+    ) -> Result<(GetVaultLockOutput, ResponseMetadata), RusotoError<GetVaultLockError>> {
+        let request = self.build_get_vault_lock_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVaultLockOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetVaultLockError::from_response(response))
         }
     }
 
-    /// <p>This operation retrieves the <code>notification-configuration</code> subresource of the specified vault.</p> <p>For information about setting a notification configuration on a vault, see <a>SetVaultNotifications</a>. If a notification configuration for a vault is not set, the operation returns a <code>404 Not Found</code> error. For more information about vault notifications, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-get.html">Get Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn get_vault_notifications(
+    /// Presigns the request `get_vault_lock` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_vault_lock(
         &self,
-        input: GetVaultNotificationsInput,
-    ) -> Result<GetVaultNotificationsOutput, RusotoError<GetVaultNotificationsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetVaultLockInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_vault_lock_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_vault_lock_request(&self, input: GetVaultLockInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/notification-configuration",
+            "/{account_id}/vaults/{vault_name}/lock-policy",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `get_vault_notifications`, also returning the metadata of the response.
+    pub async fn get_vault_notifications_with_metadata(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> Result<
+        (GetVaultNotificationsOutput, ResponseMetadata),
+        RusotoError<GetVaultNotificationsError>,
+    > {
+        let request = self.build_get_vault_notifications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetVaultNotificationsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetVaultNotificationsError::from_response(response))
         }
     }
 
-    /// <p>This operation initiates a job of the specified type, which can be a select, an archival retrieval, or a vault retrieval. For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job</a>. </p>
-    async fn initiate_job(
+    /// Presigns the request `get_vault_notifications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_vault_notifications(
         &self,
-        input: InitiateJobInput,
-    ) -> Result<InitiateJobOutput, RusotoError<InitiateJobError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetVaultNotificationsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_vault_notifications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_vault_notifications_request(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/jobs",
+            "/{account_id}/vaults/{vault_name}/notification-configuration",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
-        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
-        let encoded = Some(serde_json::to_vec(&input.job_parameters).unwrap());
-        request.set_payload(encoded);
+        request
+    }
+
+    /// Calls `initiate_job`, also returning the metadata of the response.
+    pub async fn initiate_job_with_metadata(
+        &self,
+        input: InitiateJobInput,
+    ) -> Result<(InitiateJobOutput, ResponseMetadata), RusotoError<InitiateJobError>> {
+        let request = self.build_initiate_job_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InitiateJobOutput, _>()?;
             if let Some(job_id) = response.headers.get("x-amz-job-id") {
@@ -3973,46 +4768,65 @@ impl Glacier for GlacierClient {
                 result.location = Some(value)
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(InitiateJobError::from_response(response))
         }
     }
 
-    /// <p>This operation initiates a multipart upload. Amazon S3 Glacier creates a multipart upload resource and returns its ID in the response. The multipart upload ID is used in subsequent requests to upload parts of an archive (see <a>UploadMultipartPart</a>).</p> <p>When you initiate a multipart upload, you specify the part size in number of bytes. The part size must be a megabyte (1024 KB) multiplied by a power of 2-for example, 1048576 (1 MB), 2097152 (2 MB), 4194304 (4 MB), 8388608 (8 MB), and so on. The minimum allowable part size is 1 MB, and the maximum is 4 GB.</p> <p>Every part you upload to this resource (see <a>UploadMultipartPart</a>), except the last one, must have the same size. The last one can be the same size or smaller. For example, suppose you want to upload a 16.2 MB file. If you initiate the multipart upload with a part size of 4 MB, you will upload four parts of 4 MB each and one part of 0.2 MB. </p> <note> <p>You don't need to know the size of the archive when you start a multipart upload because Amazon S3 Glacier does not require you to specify the overall archive size.</p> </note> <p>After you complete the multipart upload, Amazon S3 Glacier (Glacier) removes the multipart upload resource referenced by the ID. Glacier also removes the multipart upload resource if you cancel the multipart upload or it may be removed if there is no activity for a period of 24 hours.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-initiate-upload.html">Initiate Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
-    async fn initiate_multipart_upload(
+    /// Presigns the request `initiate_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_initiate_job(
         &self,
-        input: InitiateMultipartUploadInput,
-    ) -> Result<InitiateMultipartUploadOutput, RusotoError<InitiateMultipartUploadError>> {
+        presigner: &rusoto_core::Presigner,
+        input: InitiateJobInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_initiate_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_initiate_job_request(&self, input: InitiateJobInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads",
+            "/{account_id}/vaults/{vault_name}/jobs",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
-        if let Some(ref archive_description) = input.archive_description {
-            request.add_header(
-                "x-amz-archive-description",
-                &archive_description.to_string(),
-            );
-        }
+        let encoded = Some(serde_json::to_vec(&input.job_parameters).unwrap());
+        request.set_payload(encoded);
 
-        if let Some(ref part_size) = input.part_size {
-            request.add_header("x-amz-part-size", &part_size.to_string());
-        }
+        request
+    }
+
+    /// Calls `initiate_multipart_upload`, also returning the metadata of the response.
+    pub async fn initiate_multipart_upload_with_metadata(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> Result<
+        (InitiateMultipartUploadOutput, ResponseMetadata),
+        RusotoError<InitiateMultipartUploadError>,
+    > {
+        let request = self.build_initiate_multipart_upload_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InitiateMultipartUploadOutput, _>()?;
             if let Some(location) = response.headers.get("Location") {
@@ -4024,38 +4838,74 @@ impl Glacier for GlacierClient {
                 result.upload_id = Some(value)
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(InitiateMultipartUploadError::from_response(response))
         }
     }
 
-    /// <p>This operation initiates the vault locking process by doing the following:</p> <ul> <li> <p>Installing a vault lock policy on the specified vault.</p> </li> <li> <p>Setting the lock state of vault lock to <code>InProgress</code>.</p> </li> <li> <p>Returning a lock ID, which is used to complete the vault locking process.</p> </li> </ul> <p>You can set one vault lock policy for each vault and this policy can be up to 20 KB in size. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>You must complete the vault locking process within 24 hours after the vault lock enters the <code>InProgress</code> state. After the 24 hour window ends, the lock ID expires, the vault automatically exits the <code>InProgress</code> state, and the vault lock policy is removed from the vault. You call <a>CompleteVaultLock</a> to complete the vault locking process by setting the state of the vault lock to <code>Locked</code>. </p> <p>After a vault lock is in the <code>Locked</code> state, you cannot initiate a new vault lock for the vault.</p> <p>You can abort the vault locking process by calling <a>AbortVaultLock</a>. You can get the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>.</p> <p>If this operation is called when the vault lock is in the <code>InProgress</code> state, the operation returns an <code>AccessDeniedException</code> error. When the vault lock is in the <code>InProgress</code> state you must call <a>AbortVaultLock</a> before you can initiate a new vault lock policy. </p>
-    async fn initiate_vault_lock(
+    /// Presigns the request `initiate_multipart_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_initiate_multipart_upload(
         &self,
-        input: InitiateVaultLockInput,
-    ) -> Result<InitiateVaultLockOutput, RusotoError<InitiateVaultLockError>> {
+        presigner: &rusoto_core::Presigner,
+        input: InitiateMultipartUploadInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_initiate_multipart_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_initiate_multipart_upload_request(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/lock-policy",
+            "/{account_id}/vaults/{vault_name}/multipart-uploads",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
-        let encoded = Some(serde_json::to_vec(&input.policy).unwrap());
-        request.set_payload(encoded);
+        if let Some(ref archive_description) = input.archive_description {
+            request.add_header(
+                "x-amz-archive-description",
+                &archive_description.to_string(),
+            );
+        }
+
+        if let Some(ref part_size) = input.part_size {
+            request.add_header("x-amz-part-size", &part_size.to_string());
+        }
+
+        request
+    }
+
+    /// Calls `initiate_vault_lock`, also returning the metadata of the response.
+    pub async fn initiate_vault_lock_with_metadata(
+        &self,
+        input: InitiateVaultLockInput,
+    ) -> Result<(InitiateVaultLockOutput, ResponseMetadata), RusotoError<InitiateVaultLockError>>
+    {
+        let request = self.build_initiate_vault_lock_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<InitiateVaultLockOutput, _>()?;
             if let Some(lock_id) = response.headers.get("x-amz-lock-id") {
@@ -4063,114 +4913,171 @@ impl Glacier for GlacierClient {
                 result.lock_id = Some(value)
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(InitiateVaultLockError::from_response(response))
         }
     }
 
-    /// <p>This operation lists jobs for a vault, including jobs that are in-progress and jobs that have recently finished. The List Job operation returns a list of these jobs sorted by job initiation time.</p> <note> <p>Amazon Glacier retains recently completed jobs for a period before deleting them; however, it eventually removes completed jobs. The output of completed jobs can be retrieved. Retaining completed jobs for a period of time after they have completed enables you to get a job output in the event you miss the job completion notification or your first attempt to download it fails. For example, suppose you start an archive retrieval job to download an archive. After the job completes, you start to download the archive but encounter a network error. In this scenario, you can retry and download the archive while the job exists.</p> </note> <p>The List Jobs operation supports pagination. You should always check the response <code>Marker</code> field. If there are no more jobs to list, the <code>Marker</code> field is set to <code>null</code>. If there are more jobs to list, the <code>Marker</code> field is set to a non-null value, which you can use to continue the pagination of the list. To return a list of jobs that begins at a specific job, set the marker request parameter to the <code>Marker</code> value for that job that you obtained from a previous List Jobs request.</p> <p>You can set a maximum limit for the number of jobs returned in the response by specifying the <code>limit</code> parameter in the request. The default limit is 50. The number of jobs returned might be fewer than the limit, but the number of returned jobs never exceeds the limit.</p> <p>Additionally, you can filter the jobs list returned by specifying the optional <code>statuscode</code> parameter or <code>completed</code> parameter, or both. Using the <code>statuscode</code> parameter, you can specify to return only jobs that match either the <code>InProgress</code>, <code>Succeeded</code>, or <code>Failed</code> status. Using the <code>completed</code> parameter, you can specify to return only jobs that were completed (<code>true</code>) or jobs that were not completed (<code>false</code>).</p> <p>For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-jobs-get.html">List Jobs</a>. </p>
-    async fn list_jobs(
+    /// Presigns the request `initiate_vault_lock` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_initiate_vault_lock(
         &self,
-        input: ListJobsInput,
-    ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: InitiateVaultLockInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_initiate_vault_lock_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_initiate_vault_lock_request(&self, input: InitiateVaultLockInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/jobs",
+            "/{account_id}/vaults/{vault_name}/lock-policy",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
-        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
-        let mut params = Params::new();
-        if let Some(ref x) = input.completed {
-            params.put("completed", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.statuscode {
-            params.put("statuscode", x);
-        }
-        request.set_params(params);
+        let encoded = Some(serde_json::to_vec(&input.policy).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `list_jobs`, also returning the metadata of the response.
+    pub async fn list_jobs_with_metadata(
+        &self,
+        input: ListJobsInput,
+    ) -> Result<(ListJobsOutput, ResponseMetadata), RusotoError<ListJobsError>> {
+        let request = self.build_list_jobs_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ListJobsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListJobsError::from_response(response))
         }
     }
 
-    /// <p>This operation lists in-progress multipart uploads for the specified vault. An in-progress multipart upload is a multipart upload that has been initiated by an <a>InitiateMultipartUpload</a> request, but has not yet been completed or aborted. The list returned in the List Multipart Upload response has no guaranteed order. </p> <p>The List Multipart Uploads operation supports pagination. By default, this operation returns up to 50 multipart uploads in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of multipart uploads that begins at a specific upload, set the <code>marker</code> request parameter to the value you obtained from a previous List Multipart Upload request. You can also limit the number of uploads returned in the response by specifying the <code>limit</code> parameter in the request.</p> <p>Note the difference between this operation and listing parts (<a>ListParts</a>). The List Multipart Uploads operation lists all multipart uploads for a vault and does not require a multipart upload ID. The List Parts operation requires a multipart upload ID since parts are associated with a single upload.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-uploads.html">List Multipart Uploads </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
-    async fn list_multipart_uploads(
+    /// Presigns the request `list_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs(
         &self,
-        input: ListMultipartUploadsInput,
-    ) -> Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListJobsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_request(&self, input: ListJobsInput) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads",
+            "/{account_id}/vaults/{vault_name}/jobs",
             account_id = input.account_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
         let mut params = Params::new();
+        if let Some(ref x) = input.completed {
+            params.put("completed", x);
+        }
         if let Some(ref x) = input.limit {
             params.put("limit", x);
         }
         if let Some(ref x) = input.marker {
             params.put("marker", x);
         }
+        if let Some(ref x) = input.statuscode {
+            params.put("statuscode", x);
+        }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_multipart_uploads`, also returning the metadata of the response.
+    pub async fn list_multipart_uploads_with_metadata(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> Result<
+        (ListMultipartUploadsOutput, ResponseMetadata),
+        RusotoError<ListMultipartUploadsError>,
+    > {
+        let request = self.build_list_multipart_uploads_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMultipartUploadsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListMultipartUploadsError::from_response(response))
         }
     }
 
-    /// <p>This operation lists the parts of an archive that have been uploaded in a specific multipart upload. You can make this request at any time during an in-progress multipart upload before you complete the upload (see <a>CompleteMultipartUpload</a>. List Parts returns an error for completed uploads. The list returned in the List Parts response is sorted by part range. </p> <p>The List Parts operation supports pagination. By default, this operation returns up to 50 uploaded parts in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of parts that begins at a specific part, set the <code>marker</code> request parameter to the value you obtained from a previous List Parts request. You can also limit the number of parts returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-parts.html">List Parts</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
-    async fn list_parts(
+    /// Presigns the request `list_multipart_uploads` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_multipart_uploads(
         &self,
-        input: ListPartsInput,
-    ) -> Result<ListPartsOutput, RusotoError<ListPartsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListMultipartUploadsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_multipart_uploads_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_multipart_uploads_request(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
+            "/{account_id}/vaults/{vault_name}/multipart-uploads",
             account_id = input.account_id,
-            upload_id = input.upload_id,
             vault_name = input.vault_name
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4183,59 +5090,179 @@ impl Glacier for GlacierClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_parts`, also returning the metadata of the response.
+    pub async fn list_parts_with_metadata(
+        &self,
+        input: ListPartsInput,
+    ) -> Result<(ListPartsOutput, ResponseMetadata), RusotoError<ListPartsError>> {
+        let request = self.build_list_parts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<ListPartsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListPartsError::from_response(response))
         }
     }
 
-    /// <p>This operation lists the provisioned capacity units for the specified AWS account.</p>
-    async fn list_provisioned_capacity(
+    /// Presigns the request `list_parts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_parts(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListPartsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_parts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_parts_request(&self, input: ListPartsInput) -> SignedRequest {
+        let request_uri = format!(
+            "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
+            account_id = input.account_id,
+            upload_id = input.upload_id,
+            vault_name = input.vault_name
+        );
+
+        let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListParts");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-glacier-version", "2012-06-01");
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `list_provisioned_capacity`, also returning the metadata of the response.
+    pub async fn list_provisioned_capacity_with_metadata(
         &self,
         input: ListProvisionedCapacityInput,
-    ) -> Result<ListProvisionedCapacityOutput, RusotoError<ListProvisionedCapacityError>> {
+    ) -> Result<
+        (ListProvisionedCapacityOutput, ResponseMetadata),
+        RusotoError<ListProvisionedCapacityError>,
+    > {
+        let request = self.build_list_provisioned_capacity_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProvisionedCapacityOutput, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListProvisionedCapacityError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `list_provisioned_capacity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_provisioned_capacity(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListProvisionedCapacityInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_provisioned_capacity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_provisioned_capacity_request(
+        &self,
+        input: ListProvisionedCapacityInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/{account_id}/provisioned-capacity",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListProvisionedCapacity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `list_tags_for_vault`, also returning the metadata of the response.
+    pub async fn list_tags_for_vault_with_metadata(
+        &self,
+        input: ListTagsForVaultInput,
+    ) -> Result<(ListTagsForVaultOutput, ResponseMetadata), RusotoError<ListTagsForVaultError>>
+    {
+        let request = self.build_list_tags_for_vault_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProvisionedCapacityOutput, _>()?;
+                .deserialize::<ListTagsForVaultOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProvisionedCapacityError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForVaultError::from_response(response))
         }
     }
 
-    /// <p>This operation lists all the tags attached to a vault. The operation returns an empty map if there are no tags. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>.</p>
-    async fn list_tags_for_vault(
+    /// Presigns the request `list_tags_for_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_vault(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForVaultInput,
-    ) -> Result<ListTagsForVaultOutput, RusotoError<ListTagsForVaultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_vault_request(&self, input: ListTagsForVaultInput) -> SignedRequest {
         let request_uri = format!(
             "/{account_id}/vaults/{vault_name}/tags",
             account_id = input.account_id,
@@ -4243,34 +5270,59 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListTagsForVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `list_vaults`, also returning the metadata of the response.
+    pub async fn list_vaults_with_metadata(
+        &self,
+        input: ListVaultsInput,
+    ) -> Result<(ListVaultsOutput, ResponseMetadata), RusotoError<ListVaultsError>> {
+        let request = self.build_list_vaults_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForVaultOutput, _>()?;
+                .deserialize::<ListVaultsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForVaultError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListVaultsError::from_response(response))
         }
     }
 
-    /// <p>This operation lists all vaults owned by the calling user's account. The list returned in the response is ASCII-sorted by vault name.</p> <p>By default, this operation returns up to 10 items. If there are more vaults to list, the response <code>marker</code> field contains the vault Amazon Resource Name (ARN) at which to continue the list with a new List Vaults request; otherwise, the <code>marker</code> field is <code>null</code>. To return a list of vaults that begins at a specific vault, set the <code>marker</code> request parameter to the vault ARN you obtained from a previous List Vaults request. You can also limit the number of vaults returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vaults-get.html">List Vaults </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn list_vaults(
+    /// Presigns the request `list_vaults` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_vaults(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVaultsInput,
-    ) -> Result<ListVaultsOutput, RusotoError<ListVaultsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_vaults_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_vaults_request(&self, input: ListVaultsInput) -> SignedRequest {
         let request_uri = format!("/{account_id}/vaults", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListVaults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4283,64 +5335,118 @@ impl Glacier for GlacierClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `purchase_provisioned_capacity`, also returning the metadata of the response.
+    pub async fn purchase_provisioned_capacity_with_metadata(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> Result<
+        (PurchaseProvisionedCapacityOutput, ResponseMetadata),
+        RusotoError<PurchaseProvisionedCapacityError>,
+    > {
+        let request = self.build_purchase_provisioned_capacity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVaultsOutput, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let mut result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PurchaseProvisionedCapacityOutput, _>()?;
+            if let Some(capacity_id) = response.headers.get("x-amz-capacity-id") {
+                let value = capacity_id.to_owned();
+                result.capacity_id = Some(value)
+            };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVaultsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PurchaseProvisionedCapacityError::from_response(response))
         }
     }
 
-    /// <p>This operation purchases a provisioned capacity unit for an AWS account. </p>
-    async fn purchase_provisioned_capacity(
+    /// Presigns the request `purchase_provisioned_capacity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_purchase_provisioned_capacity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PurchaseProvisionedCapacityInput,
-    ) -> Result<PurchaseProvisionedCapacityOutput, RusotoError<PurchaseProvisionedCapacityError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_purchase_provisioned_capacity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_purchase_provisioned_capacity_request(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/{account_id}/provisioned-capacity",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("PurchaseProvisionedCapacity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
+        request
+    }
+
+    /// Calls `remove_tags_from_vault`, also returning the metadata of the response.
+    pub async fn remove_tags_from_vault_with_metadata(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveTagsFromVaultError>> {
+        let request = self.build_remove_tags_from_vault_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PurchaseProvisionedCapacityOutput, _>()?;
-            if let Some(capacity_id) = response.headers.get("x-amz-capacity-id") {
-                let value = capacity_id.to_owned();
-                result.capacity_id = Some(value)
-            };
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PurchaseProvisionedCapacityError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RemoveTagsFromVaultError::from_response(response))
         }
     }
 
-    /// <p>This operation removes one or more tags from the set of tags attached to a vault. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. This operation is idempotent. The operation will be successful, even if there are no tags attached to the vault. </p>
-    async fn remove_tags_from_vault(
+    /// Presigns the request `remove_tags_from_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_vault(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsFromVaultInput,
-    ) -> Result<(), RusotoError<RemoveTagsFromVaultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_vault_request(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/{account_id}/vaults/{vault_name}/tags",
             account_id = input.account_id,
@@ -4348,6 +5454,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("RemoveTagsFromVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4358,60 +5465,113 @@ impl Glacier for GlacierClient {
         params.put("operation", "remove");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `set_data_retrieval_policy`, also returning the metadata of the response.
+    pub async fn set_data_retrieval_policy_with_metadata(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetDataRetrievalPolicyError>> {
+        let request = self.build_set_data_retrieval_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsFromVaultError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SetDataRetrievalPolicyError::from_response(response))
         }
     }
 
-    /// <p>This operation sets and then enacts a data retrieval policy in the region specified in the PUT request. You can set one policy per region for an AWS account. The policy is enacted within a few minutes of a successful PUT operation.</p> <p>The set policy operation does not affect retrieval jobs that were in progress before the policy was enacted. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>. </p>
-    async fn set_data_retrieval_policy(
+    /// Presigns the request `set_data_retrieval_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_data_retrieval_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetDataRetrievalPolicyInput,
-    ) -> Result<(), RusotoError<SetDataRetrievalPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_data_retrieval_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_data_retrieval_policy_request(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/{account_id}/policies/data-retrieval",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetDataRetrievalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `set_vault_access_policy`, also returning the metadata of the response.
+    pub async fn set_vault_access_policy_with_metadata(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetVaultAccessPolicyError>> {
+        let request = self.build_set_vault_access_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SetDataRetrievalPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SetVaultAccessPolicyError::from_response(response))
         }
     }
 
-    /// <p>This operation configures an access policy for a vault and will overwrite an existing policy. To configure a vault access policy, send a PUT request to the <code>access-policy</code> subresource of the vault. An access policy is specific to a vault and is also called a vault subresource. You can set one access policy per vault and the policy can be up to 20 KB in size. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
-    async fn set_vault_access_policy(
+    /// Presigns the request `set_vault_access_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_vault_access_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetVaultAccessPolicyInput,
-    ) -> Result<(), RusotoError<SetVaultAccessPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_vault_access_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_vault_access_policy_request(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/{account_id}/vaults/{vault_name}/access-policy",
             account_id = input.account_id,
@@ -4419,33 +5579,60 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
         let encoded = Some(serde_json::to_vec(&input.policy).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `set_vault_notifications`, also returning the metadata of the response.
+    pub async fn set_vault_notifications_with_metadata(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetVaultNotificationsError>> {
+        let request = self.build_set_vault_notifications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SetVaultAccessPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SetVaultNotificationsError::from_response(response))
         }
     }
 
-    /// <p>This operation configures notifications that will be sent when specific events happen to a vault. By default, you don't get any notifications.</p> <p>To configure vault notifications, send a PUT request to the <code>notification-configuration</code> subresource of the vault. The request should include a JSON document that provides an Amazon SNS topic and specific events for which you want Amazon S3 Glacier to send notifications to the topic.</p> <p>Amazon SNS topics must grant permission to the vault to be allowed to publish notifications to the topic. You can configure a vault to publish a notification for the following vault events:</p> <ul> <li> <p> <b>ArchiveRetrievalCompleted</b> This event occurs when a job that was initiated for an archive retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> <li> <p> <b>InventoryRetrievalCompleted</b> This event occurs when a job that was initiated for an inventory retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> </ul> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-put.html">Set Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn set_vault_notifications(
+    /// Presigns the request `set_vault_notifications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_vault_notifications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetVaultNotificationsInput,
-    ) -> Result<(), RusotoError<SetVaultNotificationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_vault_notifications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_vault_notifications_request(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/{account_id}/vaults/{vault_name}/notification-configuration",
             account_id = input.account_id,
@@ -4453,33 +5640,70 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
         let encoded = Some(serde_json::to_vec(&input.vault_notification_config).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `upload_archive`, also returning the metadata of the response.
+    pub async fn upload_archive_with_metadata(
+        &self,
+        input: UploadArchiveInput,
+    ) -> Result<(ArchiveCreationOutput, ResponseMetadata), RusotoError<UploadArchiveError>> {
+        let request = self.build_upload_archive_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let mut result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ArchiveCreationOutput, _>()?;
+            if let Some(archive_id) = response.headers.get("x-amz-archive-id") {
+                let value = archive_id.to_owned();
+                result.archive_id = Some(value)
+            };
+            if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
+                let value = checksum.to_owned();
+                result.checksum = Some(value)
+            };
+            if let Some(location) = response.headers.get("Location") {
+                let value = location.to_owned();
+                result.location = Some(value)
+            };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SetVaultNotificationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UploadArchiveError::from_response(response))
         }
     }
 
-    /// <p>This operation adds an archive to a vault. This is a synchronous operation, and for a successful upload, your data is durably persisted. Amazon S3 Glacier returns the archive ID in the <code>x-amz-archive-id</code> header of the response. </p> <p>You must use the archive ID to access your data in Amazon S3 Glacier. After you upload an archive, you should save the archive ID returned so that you can retrieve or delete the archive later. Besides saving the archive ID, you can also index it and give it a friendly name to allow for better searching. You can also use the optional archive description field to specify how the archive is referred to in an external index of archives, such as you might create in Amazon DynamoDB. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>. </p> <p>You must provide a SHA256 tree hash of the data you are uploading. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. </p> <p>You can optionally specify an archive description of up to 1,024 printable ASCII characters. You can get the archive description when you either retrieve the archive or get the vault inventory. For more information, see <a>InitiateJob</a>. Amazon Glacier does not interpret the description in any way. An archive description does not need to be unique. You cannot use the description to retrieve or sort the archive list. </p> <p>Archives are immutable. After you upload an archive, you cannot edit the archive or its description.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-an-archive.html">Uploading an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-post.html">Upload Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
-    async fn upload_archive(
+    /// Presigns the request `upload_archive` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upload_archive(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UploadArchiveInput,
-    ) -> Result<ArchiveCreationOutput, RusotoError<UploadArchiveError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upload_archive_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_upload_archive_request(&self, input: UploadArchiveInput) -> SignedRequest {
         let request_uri = format!(
             "/{account_id}/vaults/{vault_name}/archives",
             account_id = input.account_id,
@@ -4487,6 +5711,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadArchive");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4508,40 +5733,59 @@ impl Glacier for GlacierClient {
             request.add_header("x-amz-sha256-tree-hash", &checksum.to_string());
         }
 
+        request
+    }
+
+    /// Calls `upload_multipart_part`, also returning the metadata of the response.
+    pub async fn upload_multipart_part_with_metadata(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> Result<(UploadMultipartPartOutput, ResponseMetadata), RusotoError<UploadMultipartPartError>>
+    {
+        let request = self.build_upload_multipart_part_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ArchiveCreationOutput, _>()?;
-            if let Some(archive_id) = response.headers.get("x-amz-archive-id") {
-                let value = archive_id.to_owned();
-                result.archive_id = Some(value)
-            };
+                .deserialize::<UploadMultipartPartOutput, _>()?;
             if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
                 let value = checksum.to_owned();
                 result.checksum = Some(value)
             };
-            if let Some(location) = response.headers.get("Location") {
-                let value = location.to_owned();
-                result.location = Some(value)
-            };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UploadArchiveError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UploadMultipartPartError::from_response(response))
         }
     }
 
-    /// <p>This operation uploads a part of an archive. You can upload archive parts in any order. You can also upload them in parallel. You can upload up to 10,000 parts for a multipart upload.</p> <p>Amazon Glacier rejects your upload part request if any of the following conditions is true:</p> <ul> <li> <p> <b>SHA256 tree hash does not match</b>To ensure that part data is not corrupted in transmission, you compute a SHA256 tree hash of the part and include it in your request. Upon receiving the part data, Amazon S3 Glacier also computes a SHA256 tree hash. If these hash values don't match, the operation fails. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>.</p> </li> <li> <p> <b>Part size does not match</b>The size of each part except the last must match the size specified in the corresponding <a>InitiateMultipartUpload</a> request. The size of the last part must be the same size as, or smaller than, the specified size.</p> <note> <p>If you upload a part whose size is smaller than the part size you specified in your initiate multipart upload request and that part is not the last part, then the upload part request will succeed. However, the subsequent Complete Multipart Upload request will fail.</p> </note> </li> <li> <p> <b>Range does not align</b>The byte range value in the request does not align with the part size specified in the corresponding initiate request. For example, if you specify a part size of 4194304 bytes (4 MB), then 0 to 4194303 bytes (4 MB - 1) and 4194304 (4 MB) to 8388607 (8 MB - 1) are valid part ranges. However, if you set a range value of 2 MB to 6 MB, the range does not align with the part size and the upload will fail. </p> </li> </ul> <p>This operation is idempotent. If you upload the same part multiple times, the data included in the most recent request overwrites the previously uploaded data.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-upload-part.html">Upload Part </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
-    async fn upload_multipart_part(
+    /// Presigns the request `upload_multipart_part` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upload_multipart_part(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UploadMultipartPartInput,
-    ) -> Result<UploadMultipartPartOutput, RusotoError<UploadMultipartPartError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upload_multipart_part_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_upload_multipart_part_request(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/{account_id}/vaults/{vault_name}/multipart-uploads/{upload_id}",
             account_id = input.account_id,
@@ -4550,6 +5794,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadMultipartPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4568,24 +5813,559 @@ impl Glacier for GlacierClient {
             request.add_header("Content-Range", &range.to_string());
         }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListJobs` results for `input`, requesting each next page with the `Marker` of the last one as its `marker`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsInput,
+    ) -> impl futures::Stream<Item = Result<ListJobsOutput, RusotoError<ListJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlacierClient, input| async move { client.list_jobs(input).await },
+            |input: &ListJobsInput, output: &ListJobsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListJobsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `JobList` of every page of `ListJobs` results for `input`, as `list_jobs_pages` requests them.
+    pub fn list_jobs_stream(
+        &self,
+        input: ListJobsInput,
+    ) -> impl futures::Stream<Item = Result<GlacierJobDescription, RusotoError<ListJobsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_jobs_pages(input), |page| {
+            page.job_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListMultipartUploads` results for `input`, requesting each next page with the `Marker` of the last one as its `marker`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_multipart_uploads_pages(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlacierClient, input| async move { client.list_multipart_uploads(input).await },
+            |input: &ListMultipartUploadsInput, output: &ListMultipartUploadsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListMultipartUploadsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UploadsList` of every page of `ListMultipartUploads` results for `input`, as `list_multipart_uploads_pages` requests them.
+    pub fn list_multipart_uploads_stream(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> impl futures::Stream<Item = Result<UploadListElement, RusotoError<ListMultipartUploadsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_multipart_uploads_pages(input), |page| {
+            page.uploads_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListParts` results for `input`, requesting each next page with the `Marker` of the last one as its `marker`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_parts_pages(
+        &self,
+        input: ListPartsInput,
+    ) -> impl futures::Stream<Item = Result<ListPartsOutput, RusotoError<ListPartsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlacierClient, input| async move { client.list_parts(input).await },
+            |input: &ListPartsInput, output: &ListPartsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListPartsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parts` of every page of `ListParts` results for `input`, as `list_parts_pages` requests them.
+    pub fn list_parts_stream(
+        &self,
+        input: ListPartsInput,
+    ) -> impl futures::Stream<Item = Result<PartListElement, RusotoError<ListPartsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_parts_pages(input), |page| {
+            page.parts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListVaults` results for `input`, requesting each next page with the `Marker` of the last one as its `marker`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_vaults_pages(
+        &self,
+        input: ListVaultsInput,
+    ) -> impl futures::Stream<Item = Result<ListVaultsOutput, RusotoError<ListVaultsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlacierClient, input| async move { client.list_vaults(input).await },
+            |input: &ListVaultsInput, output: &ListVaultsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListVaultsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `VaultList` of every page of `ListVaults` results for `input`, as `list_vaults_pages` requests them.
+    pub fn list_vaults_stream(
+        &self,
+        input: ListVaultsInput,
+    ) -> impl futures::Stream<Item = Result<DescribeVaultOutput, RusotoError<ListVaultsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_vaults_pages(input), |page| {
+            page.vault_list.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `VaultExists`, polling `DescribeVault` with `input` every 3 seconds, up to 15 times.
+    pub async fn wait_until_vault_exists(
+        &self,
+        input: DescribeVaultInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeVaultError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(3), 15);
+        self.wait_until_vault_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `VaultExists` like `wait_until_vault_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_vault_exists_with(
+        &self,
+        input: DescribeVaultInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeVaultError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_vault(input.clone()),
+            |result| {
+                if rusoto_core::waiter::status(result) == Some(200) {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `VaultNotExists`, polling `DescribeVault` with `input` every 3 seconds, up to 15 times.
+    pub async fn wait_until_vault_not_exists(
+        &self,
+        input: DescribeVaultInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeVaultError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(3), 15);
+        self.wait_until_vault_not_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `VaultNotExists` like `wait_until_vault_not_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_vault_not_exists_with(
+        &self,
+        input: DescribeVaultInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeVaultError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_vault(input.clone()),
+            |result| {
+                if rusoto_core::waiter::status(result) == Some(200) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Glacier for GlacierClient {
+    /// <p>This operation aborts a multipart upload identified by the upload ID.</p> <p>After the Abort Multipart Upload request succeeds, you cannot upload any more parts to the multipart upload or complete the multipart upload. Aborting a completed upload fails. However, aborting an already-aborted upload will succeed, for a short time. For more information about uploading a part and completing a multipart upload, see <a>UploadMultipartPart</a> and <a>CompleteMultipartUpload</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-abort-upload.html">Abort Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadInput,
+    ) -> Result<(), RusotoError<AbortMultipartUploadError>> {
+        self.abort_multipart_upload_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UploadMultipartPartOutput, _>()?;
-            if let Some(checksum) = response.headers.get("x-amz-sha256-tree-hash") {
-                let value = checksum.to_owned();
-                result.checksum = Some(value)
-            };
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UploadMultipartPartError::from_response(response))
-        }
+    /// <p>This operation aborts the vault locking process if the vault lock is not in the <code>Locked</code> state. If the vault lock is in the <code>Locked</code> state when this operation is requested, the operation returns an <code>AccessDeniedException</code> error. Aborting the vault locking process removes the vault lock policy from the specified vault. </p> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can get the state of a vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>This operation is idempotent. You can successfully invoke this operation multiple times, if the vault lock is in the <code>InProgress</code> state or if there is no policy associated with the vault.</p>
+    async fn abort_vault_lock(
+        &self,
+        input: AbortVaultLockInput,
+    ) -> Result<(), RusotoError<AbortVaultLockError>> {
+        self.abort_vault_lock_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation adds the specified tags to a vault. Each tag is composed of a key and a value. Each vault can have up to 10 tags. If your request would cause the tag limit for the vault to be exceeded, the operation throws the <code>LimitExceededException</code> error. If a tag already exists on the vault under a specified key, the existing key value will be overwritten. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. </p>
+    async fn add_tags_to_vault(
+        &self,
+        input: AddTagsToVaultInput,
+    ) -> Result<(), RusotoError<AddTagsToVaultError>> {
+        self.add_tags_to_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>You call this operation to inform Amazon S3 Glacier (Glacier) that all the archive parts have been uploaded and that Glacier can now assemble the archive from the uploaded parts. After assembling and saving the archive to the vault, Glacier returns the URI path of the newly created archive resource. Using the URI path, you can then access the archive. After you upload an archive, you should save the archive ID returned to retrieve the archive at a later point. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>.</p> <p>In the request, you must include the computed SHA256 tree hash of the entire archive you have uploaded. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. On the server side, Glacier also constructs the SHA256 tree hash of the assembled archive. If the values match, Glacier saves the archive to the vault; otherwise, it returns an error, and the operation fails. The <a>ListParts</a> operation returns a list of parts uploaded for a specific multipart upload. It includes checksum information for each uploaded part that can be used to debug a bad checksum issue.</p> <p>Additionally, Glacier also checks for any missing content ranges when assembling the archive, if missing content ranges are found, Glacier returns an error and the operation fails.</p> <p>Complete Multipart Upload is an idempotent operation. After your first successful complete multipart upload, if you call the operation again within a short period, the operation will succeed and return the same archive ID. This is useful in the event you experience a network issue that causes an aborted connection or receive a 500 server error, in which case you can repeat your Complete Multipart Upload request and get the same archive ID without creating duplicate archives. Note, however, that after the multipart upload completes, you cannot call the List Parts operation and the multipart upload will not appear in List Multipart Uploads response, even if idempotent complete is possible.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-complete-upload.html">Complete Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> Result<ArchiveCreationOutput, RusotoError<CompleteMultipartUploadError>> {
+        self.complete_multipart_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation completes the vault locking process by transitioning the vault lock from the <code>InProgress</code> state to the <code>Locked</code> state, which causes the vault lock policy to become unchangeable. A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. You can obtain the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>This operation is idempotent. This request is always successful if the vault lock is in the <code>Locked</code> state and the provided lock ID matches the lock ID originally used to lock the vault.</p> <p>If an invalid lock ID is passed in the request when the vault lock is in the <code>Locked</code> state, the operation returns an <code>AccessDeniedException</code> error. If an invalid lock ID is passed in the request when the vault lock is in the <code>InProgress</code> state, the operation throws an <code>InvalidParameter</code> error.</p>
+    async fn complete_vault_lock(
+        &self,
+        input: CompleteVaultLockInput,
+    ) -> Result<(), RusotoError<CompleteVaultLockError>> {
+        self.complete_vault_lock_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation creates a new vault with the specified name. The name of the vault must be unique within a region for an AWS account. You can create up to 1,000 vaults per account. If you need to create more vaults, contact Amazon S3 Glacier.</p> <p>You must use the following guidelines when naming a vault.</p> <ul> <li> <p>Names can be between 1 and 255 characters long.</p> </li> <li> <p>Allowed characters are a-z, A-Z, 0-9, '_' (underscore), '-' (hyphen), and '.' (period).</p> </li> </ul> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/creating-vaults.html">Creating a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-put.html">Create Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn create_vault(
+        &self,
+        input: CreateVaultInput,
+    ) -> Result<CreateVaultOutput, RusotoError<CreateVaultError>> {
+        self.create_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation deletes an archive from a vault. Subsequent requests to initiate a retrieval of this archive will fail. Archive retrievals that are in progress for this archive ID may or may not succeed according to the following scenarios:</p> <ul> <li> <p>If the archive retrieval job is actively preparing the data for download when Amazon S3 Glacier receives the delete archive request, the archival retrieval operation might fail.</p> </li> <li> <p>If the archive retrieval job has successfully prepared the archive for download when Amazon S3 Glacier receives the delete archive request, you will be able to download the output.</p> </li> </ul> <p>This operation is idempotent. Attempting to delete an already-deleted archive does not result in an error.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-an-archive.html">Deleting an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn delete_archive(
+        &self,
+        input: DeleteArchiveInput,
+    ) -> Result<(), RusotoError<DeleteArchiveError>> {
+        self.delete_archive_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation deletes a vault. Amazon S3 Glacier will delete a vault only if there are no archives in the vault as of the last inventory and there have been no writes to the vault since the last inventory. If either of these conditions is not satisfied, the vault deletion fails (that is, the vault is not removed) and Amazon S3 Glacier returns an error. You can use <a>DescribeVault</a> to return the number of archives in a vault, and you can use <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job (POST jobs)</a> to initiate a new inventory retrieval for a vault. The inventory contains the archive IDs you use to delete archives using <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive (DELETE archive)</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-vaults.html">Deleting a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-delete.html">Delete Vault </a> in the <i>Amazon S3 Glacier Developer Guide</i>. </p>
+    async fn delete_vault(
+        &self,
+        input: DeleteVaultInput,
+    ) -> Result<(), RusotoError<DeleteVaultError>> {
+        self.delete_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation deletes the access policy associated with the specified vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely remove the access policy, and you might still see the effect of the policy for a short time after you send the delete request.</p> <p>This operation is idempotent. You can invoke delete multiple times, even if there is no policy associated with the vault. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
+    async fn delete_vault_access_policy(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<DeleteVaultAccessPolicyError>> {
+        self.delete_vault_access_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation deletes the notification configuration set for a vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely disable the notifications and you might still receive some notifications for a short time after you send the delete request.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-delete.html">Delete Vault Notification Configuration </a> in the Amazon S3 Glacier Developer Guide. </p>
+    async fn delete_vault_notifications(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> Result<(), RusotoError<DeleteVaultNotificationsError>> {
+        self.delete_vault_notifications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns information about a job you previously initiated, including the job initiation date, the user who initiated the job, the job status code/message and the Amazon SNS topic to notify after Amazon S3 Glacier (Glacier) completes the job. For more information about initiating a job, see <a>InitiateJob</a>. </p> <note> <p>This operation enables you to check the status of your job. However, it is strongly recommended that you set up an Amazon SNS topic and specify it in your initiate job request so that Glacier can notify the topic after it completes the job.</p> </note> <p>A job ID will not expire for at least 24 hours after Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-describe-job-get.html">Describe Job</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn describe_job(
+        &self,
+        input: DescribeJobInput,
+    ) -> Result<GlacierJobDescription, RusotoError<DescribeJobError>> {
+        self.describe_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns information about a vault, including the vault's Amazon Resource Name (ARN), the date the vault was created, the number of archives it contains, and the total size of all the archives in the vault. The number of archives and their total size are as of the last inventory generation. This means that if you add or remove an archive from a vault, and then immediately use Describe Vault, the change in contents will not be immediately reflected. If you want to retrieve the latest inventory of the vault, use <a>InitiateJob</a>. Amazon S3 Glacier generates vault inventories approximately daily. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-get.html">Describe Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn describe_vault(
+        &self,
+        input: DescribeVaultInput,
+    ) -> Result<DescribeVaultOutput, RusotoError<DescribeVaultError>> {
+        self.describe_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns the current data retrieval policy for the account and region specified in the GET request. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>.</p>
+    async fn get_data_retrieval_policy(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> Result<GetDataRetrievalPolicyOutput, RusotoError<GetDataRetrievalPolicyError>> {
+        self.get_data_retrieval_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation downloads the output of the job you initiated using <a>InitiateJob</a>. Depending on the job type you specified when you initiated the job, the output will be either the content of an archive or a vault inventory.</p> <p>You can download all the job output or download a portion of the output by specifying a byte range. In the case of an archive retrieval job, depending on the byte range you specify, Amazon S3 Glacier (Glacier) returns the checksum for the portion of the data. You can compute the checksum on the client and verify that the values match to ensure the portion you downloaded is the correct data.</p> <p>A job ID will not expire for at least 24 hours after Glacier completes the job. That a byte range. For both archive and inventory retrieval jobs, you should verify the downloaded size against the size returned in the headers from the <b>Get Job Output</b> response.</p> <p>For archive retrieval jobs, you should also verify that the size is what you expected. If you download a portion of the output, the expected size is based on the range of bytes you specified. For example, if you specify a range of <code>bytes=0-1048575</code>, you should verify your download size is 1,048,576 bytes. If you download an entire archive, the expected size is the size of the archive when you uploaded it to Amazon S3 Glacier The expected size is also returned in the headers from the <b>Get Job Output</b> response.</p> <p>In the case of an archive retrieval job, depending on the byte range you specify, Glacier returns the checksum for the portion of the data. To ensure the portion you downloaded is the correct data, compute the checksum on the client, verify that the values match, and verify that the size is what you expected.</p> <p>A job ID does not expire for at least 24 hours after Glacier completes the job. That is, you can download the job output within the 24 hours period after Amazon Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory</a>, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/downloading-an-archive.html">Downloading an Archive</a>, and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-job-output-get.html">Get Job Output </a> </p>
+    async fn get_job_output(
+        &self,
+        input: GetJobOutputInput,
+    ) -> Result<GetJobOutputOutput, RusotoError<GetJobOutputError>> {
+        self.get_job_output_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation retrieves the <code>access-policy</code> subresource set on the vault; for more information on setting this subresource, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-SetVaultAccessPolicy.html">Set Vault Access Policy (PUT access-policy)</a>. If there is no access policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>.</p>
+    async fn get_vault_access_policy(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> Result<GetVaultAccessPolicyOutput, RusotoError<GetVaultAccessPolicyError>> {
+        self.get_vault_access_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation retrieves the following attributes from the <code>lock-policy</code> subresource set on the specified vault: </p> <ul> <li> <p>The vault lock policy set on the vault.</p> </li> <li> <p>The state of the vault lock, which is either <code>InProgess</code> or <code>Locked</code>.</p> </li> <li> <p>When the lock ID expires. The lock ID is used to complete the vault locking process.</p> </li> <li> <p>When the vault lock was initiated and put into the <code>InProgress</code> state.</p> </li> </ul> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can abort the vault locking process by calling <a>AbortVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>If there is no vault lock policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault lock policies, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p>
+    async fn get_vault_lock(
+        &self,
+        input: GetVaultLockInput,
+    ) -> Result<GetVaultLockOutput, RusotoError<GetVaultLockError>> {
+        self.get_vault_lock_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation retrieves the <code>notification-configuration</code> subresource of the specified vault.</p> <p>For information about setting a notification configuration on a vault, see <a>SetVaultNotifications</a>. If a notification configuration for a vault is not set, the operation returns a <code>404 Not Found</code> error. For more information about vault notifications, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-get.html">Get Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn get_vault_notifications(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> Result<GetVaultNotificationsOutput, RusotoError<GetVaultNotificationsError>> {
+        self.get_vault_notifications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation initiates a job of the specified type, which can be a select, an archival retrieval, or a vault retrieval. For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job</a>. </p>
+    async fn initiate_job(
+        &self,
+        input: InitiateJobInput,
+    ) -> Result<InitiateJobOutput, RusotoError<InitiateJobError>> {
+        self.initiate_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation initiates a multipart upload. Amazon S3 Glacier creates a multipart upload resource and returns its ID in the response. The multipart upload ID is used in subsequent requests to upload parts of an archive (see <a>UploadMultipartPart</a>).</p> <p>When you initiate a multipart upload, you specify the part size in number of bytes. The part size must be a megabyte (1024 KB) multiplied by a power of 2-for example, 1048576 (1 MB), 2097152 (2 MB), 4194304 (4 MB), 8388608 (8 MB), and so on. The minimum allowable part size is 1 MB, and the maximum is 4 GB.</p> <p>Every part you upload to this resource (see <a>UploadMultipartPart</a>), except the last one, must have the same size. The last one can be the same size or smaller. For example, suppose you want to upload a 16.2 MB file. If you initiate the multipart upload with a part size of 4 MB, you will upload four parts of 4 MB each and one part of 0.2 MB. </p> <note> <p>You don't need to know the size of the archive when you start a multipart upload because Amazon S3 Glacier does not require you to specify the overall archive size.</p> </note> <p>After you complete the multipart upload, Amazon S3 Glacier (Glacier) removes the multipart upload resource referenced by the ID. Glacier also removes the multipart upload resource if you cancel the multipart upload or it may be removed if there is no activity for a period of 24 hours.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-initiate-upload.html">Initiate Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    async fn initiate_multipart_upload(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> Result<InitiateMultipartUploadOutput, RusotoError<InitiateMultipartUploadError>> {
+        self.initiate_multipart_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation initiates the vault locking process by doing the following:</p> <ul> <li> <p>Installing a vault lock policy on the specified vault.</p> </li> <li> <p>Setting the lock state of vault lock to <code>InProgress</code>.</p> </li> <li> <p>Returning a lock ID, which is used to complete the vault locking process.</p> </li> </ul> <p>You can set one vault lock policy for each vault and this policy can be up to 20 KB in size. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>You must complete the vault locking process within 24 hours after the vault lock enters the <code>InProgress</code> state. After the 24 hour window ends, the lock ID expires, the vault automatically exits the <code>InProgress</code> state, and the vault lock policy is removed from the vault. You call <a>CompleteVaultLock</a> to complete the vault locking process by setting the state of the vault lock to <code>Locked</code>. </p> <p>After a vault lock is in the <code>Locked</code> state, you cannot initiate a new vault lock for the vault.</p> <p>You can abort the vault locking process by calling <a>AbortVaultLock</a>. You can get the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>.</p> <p>If this operation is called when the vault lock is in the <code>InProgress</code> state, the operation returns an <code>AccessDeniedException</code> error. When the vault lock is in the <code>InProgress</code> state you must call <a>AbortVaultLock</a> before you can initiate a new vault lock policy. </p>
+    async fn initiate_vault_lock(
+        &self,
+        input: InitiateVaultLockInput,
+    ) -> Result<InitiateVaultLockOutput, RusotoError<InitiateVaultLockError>> {
+        self.initiate_vault_lock_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists jobs for a vault, including jobs that are in-progress and jobs that have recently finished. The List Job operation returns a list of these jobs sorted by job initiation time.</p> <note> <p>Amazon Glacier retains recently completed jobs for a period before deleting them; however, it eventually removes completed jobs. The output of completed jobs can be retrieved. Retaining completed jobs for a period of time after they have completed enables you to get a job output in the event you miss the job completion notification or your first attempt to download it fails. For example, suppose you start an archive retrieval job to download an archive. After the job completes, you start to download the archive but encounter a network error. In this scenario, you can retry and download the archive while the job exists.</p> </note> <p>The List Jobs operation supports pagination. You should always check the response <code>Marker</code> field. If there are no more jobs to list, the <code>Marker</code> field is set to <code>null</code>. If there are more jobs to list, the <code>Marker</code> field is set to a non-null value, which you can use to continue the pagination of the list. To return a list of jobs that begins at a specific job, set the marker request parameter to the <code>Marker</code> value for that job that you obtained from a previous List Jobs request.</p> <p>You can set a maximum limit for the number of jobs returned in the response by specifying the <code>limit</code> parameter in the request. The default limit is 50. The number of jobs returned might be fewer than the limit, but the number of returned jobs never exceeds the limit.</p> <p>Additionally, you can filter the jobs list returned by specifying the optional <code>statuscode</code> parameter or <code>completed</code> parameter, or both. Using the <code>statuscode</code> parameter, you can specify to return only jobs that match either the <code>InProgress</code>, <code>Succeeded</code>, or <code>Failed</code> status. Using the <code>completed</code> parameter, you can specify to return only jobs that were completed (<code>true</code>) or jobs that were not completed (<code>false</code>).</p> <p>For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-jobs-get.html">List Jobs</a>. </p>
+    async fn list_jobs(
+        &self,
+        input: ListJobsInput,
+    ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
+        self.list_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists in-progress multipart uploads for the specified vault. An in-progress multipart upload is a multipart upload that has been initiated by an <a>InitiateMultipartUpload</a> request, but has not yet been completed or aborted. The list returned in the List Multipart Upload response has no guaranteed order. </p> <p>The List Multipart Uploads operation supports pagination. By default, this operation returns up to 50 multipart uploads in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of multipart uploads that begins at a specific upload, set the <code>marker</code> request parameter to the value you obtained from a previous List Multipart Upload request. You can also limit the number of uploads returned in the response by specifying the <code>limit</code> parameter in the request.</p> <p>Note the difference between this operation and listing parts (<a>ListParts</a>). The List Multipart Uploads operation lists all multipart uploads for a vault and does not require a multipart upload ID. The List Parts operation requires a multipart upload ID since parts are associated with a single upload.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-uploads.html">List Multipart Uploads </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    async fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>> {
+        self.list_multipart_uploads_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists the parts of an archive that have been uploaded in a specific multipart upload. You can make this request at any time during an in-progress multipart upload before you complete the upload (see <a>CompleteMultipartUpload</a>. List Parts returns an error for completed uploads. The list returned in the List Parts response is sorted by part range. </p> <p>The List Parts operation supports pagination. By default, this operation returns up to 50 uploaded parts in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of parts that begins at a specific part, set the <code>marker</code> request parameter to the value you obtained from a previous List Parts request. You can also limit the number of parts returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-parts.html">List Parts</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    async fn list_parts(
+        &self,
+        input: ListPartsInput,
+    ) -> Result<ListPartsOutput, RusotoError<ListPartsError>> {
+        self.list_parts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists the provisioned capacity units for the specified AWS account.</p>
+    async fn list_provisioned_capacity(
+        &self,
+        input: ListProvisionedCapacityInput,
+    ) -> Result<ListProvisionedCapacityOutput, RusotoError<ListProvisionedCapacityError>> {
+        self.list_provisioned_capacity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists all the tags attached to a vault. The operation returns an empty map if there are no tags. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>.</p>
+    async fn list_tags_for_vault(
+        &self,
+        input: ListTagsForVaultInput,
+    ) -> Result<ListTagsForVaultOutput, RusotoError<ListTagsForVaultError>> {
+        self.list_tags_for_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists all vaults owned by the calling user's account. The list returned in the response is ASCII-sorted by vault name.</p> <p>By default, this operation returns up to 10 items. If there are more vaults to list, the response <code>marker</code> field contains the vault Amazon Resource Name (ARN) at which to continue the list with a new List Vaults request; otherwise, the <code>marker</code> field is <code>null</code>. To return a list of vaults that begins at a specific vault, set the <code>marker</code> request parameter to the vault ARN you obtained from a previous List Vaults request. You can also limit the number of vaults returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vaults-get.html">List Vaults </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn list_vaults(
+        &self,
+        input: ListVaultsInput,
+    ) -> Result<ListVaultsOutput, RusotoError<ListVaultsError>> {
+        self.list_vaults_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation purchases a provisioned capacity unit for an AWS account. </p>
+    async fn purchase_provisioned_capacity(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> Result<PurchaseProvisionedCapacityOutput, RusotoError<PurchaseProvisionedCapacityError>>
+    {
+        self.purchase_provisioned_capacity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation removes one or more tags from the set of tags attached to a vault. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. This operation is idempotent. The operation will be successful, even if there are no tags attached to the vault. </p>
+    async fn remove_tags_from_vault(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> Result<(), RusotoError<RemoveTagsFromVaultError>> {
+        self.remove_tags_from_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation sets and then enacts a data retrieval policy in the region specified in the PUT request. You can set one policy per region for an AWS account. The policy is enacted within a few minutes of a successful PUT operation.</p> <p>The set policy operation does not affect retrieval jobs that were in progress before the policy was enacted. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>. </p>
+    async fn set_data_retrieval_policy(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> Result<(), RusotoError<SetDataRetrievalPolicyError>> {
+        self.set_data_retrieval_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation configures an access policy for a vault and will overwrite an existing policy. To configure a vault access policy, send a PUT request to the <code>access-policy</code> subresource of the vault. An access policy is specific to a vault and is also called a vault subresource. You can set one access policy per vault and the policy can be up to 20 KB in size. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
+    async fn set_vault_access_policy(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<SetVaultAccessPolicyError>> {
+        self.set_vault_access_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation configures notifications that will be sent when specific events happen to a vault. By default, you don't get any notifications.</p> <p>To configure vault notifications, send a PUT request to the <code>notification-configuration</code> subresource of the vault. The request should include a JSON document that provides an Amazon SNS topic and specific events for which you want Amazon S3 Glacier to send notifications to the topic.</p> <p>Amazon SNS topics must grant permission to the vault to be allowed to publish notifications to the topic. You can configure a vault to publish a notification for the following vault events:</p> <ul> <li> <p> <b>ArchiveRetrievalCompleted</b> This event occurs when a job that was initiated for an archive retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> <li> <p> <b>InventoryRetrievalCompleted</b> This event occurs when a job that was initiated for an inventory retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> </ul> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-put.html">Set Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn set_vault_notifications(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> Result<(), RusotoError<SetVaultNotificationsError>> {
+        self.set_vault_notifications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation adds an archive to a vault. This is a synchronous operation, and for a successful upload, your data is durably persisted. Amazon S3 Glacier returns the archive ID in the <code>x-amz-archive-id</code> header of the response. </p> <p>You must use the archive ID to access your data in Amazon S3 Glacier. After you upload an archive, you should save the archive ID returned so that you can retrieve or delete the archive later. Besides saving the archive ID, you can also index it and give it a friendly name to allow for better searching. You can also use the optional archive description field to specify how the archive is referred to in an external index of archives, such as you might create in Amazon DynamoDB. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>. </p> <p>You must provide a SHA256 tree hash of the data you are uploading. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. </p> <p>You can optionally specify an archive description of up to 1,024 printable ASCII characters. You can get the archive description when you either retrieve the archive or get the vault inventory. For more information, see <a>InitiateJob</a>. Amazon Glacier does not interpret the description in any way. An archive description does not need to be unique. You cannot use the description to retrieve or sort the archive list. </p> <p>Archives are immutable. After you upload an archive, you cannot edit the archive or its description.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-an-archive.html">Uploading an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-post.html">Upload Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    async fn upload_archive(
+        &self,
+        input: UploadArchiveInput,
+    ) -> Result<ArchiveCreationOutput, RusotoError<UploadArchiveError>> {
+        self.upload_archive_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation uploads a part of an archive. You can upload archive parts in any order. You can also upload them in parallel. You can upload up to 10,000 parts for a multipart upload.</p> <p>Amazon Glacier rejects your upload part request if any of the following conditions is true:</p> <ul> <li> <p> <b>SHA256 tree hash does not match</b>To ensure that part data is not corrupted in transmission, you compute a SHA256 tree hash of the part and include it in your request. Upon receiving the part data, Amazon S3 Glacier also computes a SHA256 tree hash. If these hash values don't match, the operation fails. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>.</p> </li> <li> <p> <b>Part size does not match</b>The size of each part except the last must match the size specified in the corresponding <a>InitiateMultipartUpload</a> request. The size of the last part must be the same size as, or smaller than, the specified size.</p> <note> <p>If you upload a part whose size is smaller than the part size you specified in your initiate multipart upload request and that part is not the last part, then the upload part request will succeed. However, the subsequent Complete Multipart Upload request will fail.</p> </note> </li> <li> <p> <b>Range does not align</b>The byte range value in the request does not align with the part size specified in the corresponding initiate request. For example, if you specify a part size of 4194304 bytes (4 MB), then 0 to 4194303 bytes (4 MB - 1) and 4194304 (4 MB) to 8388607 (8 MB - 1) are valid part ranges. However, if you set a range value of 2 MB to 6 MB, the range does not align with the part size and the upload will fail. </p> </li> </ul> <p>This operation is idempotent. If you upload the same part multiple times, the data included in the most recent request overwrites the previously uploaded data.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-upload-part.html">Upload Part </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    async fn upload_multipart_part(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> Result<UploadMultipartPartOutput, RusotoError<UploadMultipartPartError>> {
+        self.upload_multipart_part_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -638,6 +638,7 @@ pub struct UpdateListenerResponse {
 
 /// Errors returned by CreateAccelerator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAcceleratorError {
     /// <p>There was an internal error for AWS Global Accelerator.</p>
     InternalServiceError(String),
@@ -645,6 +646,8 @@ pub enum CreateAcceleratorError {
     InvalidArgument(String),
     /// <p>Processing your request would cause you to exceed an AWS Global Accelerator limit.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAcceleratorError {
@@ -652,18 +655,25 @@ impl CreateAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateAcceleratorError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAcceleratorError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        CreateAcceleratorError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAcceleratorError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateAcceleratorError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAcceleratorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -676,12 +686,14 @@ impl fmt::Display for CreateAcceleratorError {
             CreateAcceleratorError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             CreateAcceleratorError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             CreateAcceleratorError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateAcceleratorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAcceleratorError {}
 /// Errors returned by CreateEndpointGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEndpointGroupError {
     /// <p>The accelerator that you specified doesn't exist.</p>
     AcceleratorNotFound(String),
@@ -697,6 +709,8 @@ pub enum CreateEndpointGroupError {
     LimitExceeded(String),
     /// <p>The listener that you specified doesn't exist.</p>
     ListenerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEndpointGroupError {
@@ -704,36 +718,49 @@ impl CreateEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::AcceleratorNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointGroupError::AcceleratorNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateEndpointGroupError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EndpointGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEndpointGroupError::EndpointGroupAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointGroupError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        CreateEndpointGroupError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateEndpointGroupError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::ListenerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointGroupError::ListenerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEndpointGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -752,12 +779,14 @@ impl fmt::Display for CreateEndpointGroupError {
             CreateEndpointGroupError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             CreateEndpointGroupError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateEndpointGroupError::ListenerNotFound(ref cause) => write!(f, "{}", cause),
+            CreateEndpointGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEndpointGroupError {}
 /// Errors returned by CreateListener
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateListenerError {
     /// <p>The accelerator that you specified doesn't exist.</p>
     AcceleratorNotFound(String),
@@ -769,6 +798,8 @@ pub enum CreateListenerError {
     InvalidPortRange(String),
     /// <p>Processing your request would cause you to exceed an AWS Global Accelerator limit.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateListenerError {
@@ -776,22 +807,34 @@ impl CreateListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(CreateListenerError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateListenerError::AcceleratorNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateListenerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        CreateListenerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        CreateListenerError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPortRangeException" => {
-                    return RusotoError::Service(CreateListenerError::InvalidPortRange(err.msg))
+                    return RusotoError::service(
+                        CreateListenerError::InvalidPortRange(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateListenerError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateListenerError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateListenerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -806,12 +849,14 @@ impl fmt::Display for CreateListenerError {
             CreateListenerError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             CreateListenerError::InvalidPortRange(ref cause) => write!(f, "{}", cause),
             CreateListenerError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateListenerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateListenerError {}
 /// Errors returned by DeleteAccelerator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAcceleratorError {
     /// <p>The accelerator that you specified could not be disabled.</p>
     AcceleratorNotDisabled(String),
@@ -823,6 +868,8 @@ pub enum DeleteAcceleratorError {
     InternalServiceError(String),
     /// <p>An argument that you specified is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAcceleratorError {
@@ -830,30 +877,37 @@ impl DeleteAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotDisabledException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::AcceleratorNotDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAcceleratorError::AcceleratorNotDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::AcceleratorNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAcceleratorError::AcceleratorNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "AssociatedListenerFoundException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::AssociatedListenerFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAcceleratorError::AssociatedListenerFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAcceleratorError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DeleteAcceleratorError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAcceleratorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -868,12 +922,14 @@ impl fmt::Display for DeleteAcceleratorError {
             DeleteAcceleratorError::AssociatedListenerFound(ref cause) => write!(f, "{}", cause),
             DeleteAcceleratorError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DeleteAcceleratorError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            DeleteAcceleratorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAcceleratorError {}
 /// Errors returned by DeleteEndpointGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEndpointGroupError {
     /// <p>The endpoint group that you specified doesn't exist.</p>
     EndpointGroupNotFound(String),
@@ -881,6 +937,8 @@ pub enum DeleteEndpointGroupError {
     InternalServiceError(String),
     /// <p>An argument that you specified is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEndpointGroupError {
@@ -888,20 +946,25 @@ impl DeleteEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(DeleteEndpointGroupError::EndpointGroupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEndpointGroupError::EndpointGroupNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteEndpointGroupError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEndpointGroupError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteEndpointGroupError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DeleteEndpointGroupError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEndpointGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -914,12 +977,14 @@ impl fmt::Display for DeleteEndpointGroupError {
             DeleteEndpointGroupError::EndpointGroupNotFound(ref cause) => write!(f, "{}", cause),
             DeleteEndpointGroupError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DeleteEndpointGroupError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            DeleteEndpointGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEndpointGroupError {}
 /// Errors returned by DeleteListener
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteListenerError {
     /// <p>The listener that you specified has an endpoint group associated with it. You must remove all dependent resources from a listener before you can delete it.</p>
     AssociatedEndpointGroupFound(String),
@@ -929,6 +994,8 @@ pub enum DeleteListenerError {
     InvalidArgument(String),
     /// <p>The listener that you specified doesn't exist.</p>
     ListenerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteListenerError {
@@ -936,21 +1003,31 @@ impl DeleteListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociatedEndpointGroupFoundException" => {
-                    return RusotoError::Service(DeleteListenerError::AssociatedEndpointGroupFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteListenerError::AssociatedEndpointGroupFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteListenerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DeleteListenerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DeleteListenerError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(DeleteListenerError::ListenerNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteListenerError::ListenerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteListenerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -964,12 +1041,14 @@ impl fmt::Display for DeleteListenerError {
             DeleteListenerError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DeleteListenerError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             DeleteListenerError::ListenerNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteListenerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteListenerError {}
 /// Errors returned by DescribeAccelerator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAcceleratorError {
     /// <p>The accelerator that you specified doesn't exist.</p>
     AcceleratorNotFound(String),
@@ -977,6 +1056,8 @@ pub enum DescribeAcceleratorError {
     InternalServiceError(String),
     /// <p>An argument that you specified is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAcceleratorError {
@@ -984,20 +1065,25 @@ impl DescribeAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(DescribeAcceleratorError::AcceleratorNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAcceleratorError::AcceleratorNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeAcceleratorError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAcceleratorError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DescribeAcceleratorError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAcceleratorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1010,12 +1096,14 @@ impl fmt::Display for DescribeAcceleratorError {
             DescribeAcceleratorError::AcceleratorNotFound(ref cause) => write!(f, "{}", cause),
             DescribeAcceleratorError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DescribeAcceleratorError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            DescribeAcceleratorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAcceleratorError {}
 /// Errors returned by DescribeAcceleratorAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAcceleratorAttributesError {
     /// <p>The accelerator that you specified doesn't exist.</p>
     AcceleratorNotFound(String),
@@ -1023,6 +1111,8 @@ pub enum DescribeAcceleratorAttributesError {
     InternalServiceError(String),
     /// <p>An argument that you specified is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAcceleratorAttributesError {
@@ -1032,22 +1122,30 @@ impl DescribeAcceleratorAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAcceleratorAttributesError::AcceleratorNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAcceleratorAttributesError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAcceleratorAttributesError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAcceleratorAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1066,12 +1164,16 @@ impl fmt::Display for DescribeAcceleratorAttributesError {
             DescribeAcceleratorAttributesError::InvalidArgument(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAcceleratorAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAcceleratorAttributesError {}
 /// Errors returned by DescribeEndpointGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEndpointGroupError {
     /// <p>The endpoint group that you specified doesn't exist.</p>
     EndpointGroupNotFound(String),
@@ -1079,6 +1181,8 @@ pub enum DescribeEndpointGroupError {
     InternalServiceError(String),
     /// <p>An argument that you specified is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEndpointGroupError {
@@ -1086,22 +1190,25 @@ impl DescribeEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(DescribeEndpointGroupError::EndpointGroupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEndpointGroupError::EndpointGroupNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeEndpointGroupError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEndpointGroupError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeEndpointGroupError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEndpointGroupError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEndpointGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1114,12 +1221,14 @@ impl fmt::Display for DescribeEndpointGroupError {
             DescribeEndpointGroupError::EndpointGroupNotFound(ref cause) => write!(f, "{}", cause),
             DescribeEndpointGroupError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DescribeEndpointGroupError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            DescribeEndpointGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEndpointGroupError {}
 /// Errors returned by DescribeListener
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeListenerError {
     /// <p>There was an internal error for AWS Global Accelerator.</p>
     InternalServiceError(String),
@@ -1127,6 +1236,8 @@ pub enum DescribeListenerError {
     InvalidArgument(String),
     /// <p>The listener that you specified doesn't exist.</p>
     ListenerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeListenerError {
@@ -1134,18 +1245,25 @@ impl DescribeListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeListenerError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeListenerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DescribeListenerError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(DescribeListenerError::ListenerNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeListenerError::ListenerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeListenerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1158,12 +1276,14 @@ impl fmt::Display for DescribeListenerError {
             DescribeListenerError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DescribeListenerError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             DescribeListenerError::ListenerNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeListenerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeListenerError {}
 /// Errors returned by ListAccelerators
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAcceleratorsError {
     /// <p>There was an internal error for AWS Global Accelerator.</p>
     InternalServiceError(String),
@@ -1171,6 +1291,8 @@ pub enum ListAcceleratorsError {
     InvalidArgument(String),
     /// <p>There isn't another item to return.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAcceleratorsError {
@@ -1178,18 +1300,25 @@ impl ListAcceleratorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListAcceleratorsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAcceleratorsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListAcceleratorsError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        ListAcceleratorsError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListAcceleratorsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListAcceleratorsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAcceleratorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1202,12 +1331,14 @@ impl fmt::Display for ListAcceleratorsError {
             ListAcceleratorsError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListAcceleratorsError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListAcceleratorsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListAcceleratorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAcceleratorsError {}
 /// Errors returned by ListEndpointGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEndpointGroupsError {
     /// <p>There was an internal error for AWS Global Accelerator.</p>
     InternalServiceError(String),
@@ -1217,6 +1348,8 @@ pub enum ListEndpointGroupsError {
     InvalidNextToken(String),
     /// <p>The listener that you specified doesn't exist.</p>
     ListenerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEndpointGroupsError {
@@ -1224,21 +1357,31 @@ impl ListEndpointGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEndpointGroupsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        ListEndpointGroupsError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListEndpointGroupsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::ListenerNotFound(err.msg))
+                    return RusotoError::service(
+                        ListEndpointGroupsError::ListenerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEndpointGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1252,12 +1395,14 @@ impl fmt::Display for ListEndpointGroupsError {
             ListEndpointGroupsError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListEndpointGroupsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListEndpointGroupsError::ListenerNotFound(ref cause) => write!(f, "{}", cause),
+            ListEndpointGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEndpointGroupsError {}
 /// Errors returned by ListListeners
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListListenersError {
     /// <p>The accelerator that you specified doesn't exist.</p>
     AcceleratorNotFound(String),
@@ -1267,6 +1412,8 @@ pub enum ListListenersError {
     InvalidArgument(String),
     /// <p>There isn't another item to return.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListListenersError {
@@ -1274,19 +1421,28 @@ impl ListListenersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(ListListenersError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service(
+                        ListListenersError::AcceleratorNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListListenersError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        ListListenersError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListListenersError::InvalidArgument(err.msg))
+                    return RusotoError::service(ListListenersError::InvalidArgument(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListListenersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListListenersError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListListenersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1300,12 +1456,14 @@ impl fmt::Display for ListListenersError {
             ListListenersError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListListenersError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListListenersError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListListenersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListListenersError {}
 /// Errors returned by UpdateAccelerator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAcceleratorError {
     /// <p>The accelerator that you specified doesn't exist.</p>
     AcceleratorNotFound(String),
@@ -1313,6 +1471,8 @@ pub enum UpdateAcceleratorError {
     InternalServiceError(String),
     /// <p>An argument that you specified is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAcceleratorError {
@@ -1320,20 +1480,25 @@ impl UpdateAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(UpdateAcceleratorError::AcceleratorNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAcceleratorError::AcceleratorNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateAcceleratorError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAcceleratorError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        UpdateAcceleratorError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAcceleratorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1346,12 +1511,14 @@ impl fmt::Display for UpdateAcceleratorError {
             UpdateAcceleratorError::AcceleratorNotFound(ref cause) => write!(f, "{}", cause),
             UpdateAcceleratorError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             UpdateAcceleratorError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            UpdateAcceleratorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAcceleratorError {}
 /// Errors returned by UpdateAcceleratorAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAcceleratorAttributesError {
     /// <p>The accelerator that you specified doesn't exist.</p>
     AcceleratorNotFound(String),
@@ -1361,6 +1528,8 @@ pub enum UpdateAcceleratorAttributesError {
     InternalServiceError(String),
     /// <p>An argument that you specified is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAcceleratorAttributesError {
@@ -1370,27 +1539,36 @@ impl UpdateAcceleratorAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateAcceleratorAttributesError::AcceleratorNotFound(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAcceleratorAttributesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAcceleratorAttributesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateAcceleratorAttributesError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateAcceleratorAttributesError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAcceleratorAttributesError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateAcceleratorAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1408,12 +1586,14 @@ impl fmt::Display for UpdateAcceleratorAttributesError {
                 write!(f, "{}", cause)
             }
             UpdateAcceleratorAttributesError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            UpdateAcceleratorAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAcceleratorAttributesError {}
 /// Errors returned by UpdateEndpointGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEndpointGroupError {
     /// <p>You don't have access permission.</p>
     AccessDenied(String),
@@ -1425,6 +1605,8 @@ pub enum UpdateEndpointGroupError {
     InvalidArgument(String),
     /// <p>Processing your request would cause you to exceed an AWS Global Accelerator limit.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEndpointGroupError {
@@ -1432,26 +1614,37 @@ impl UpdateEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateEndpointGroupError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::EndpointGroupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEndpointGroupError::EndpointGroupNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEndpointGroupError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        UpdateEndpointGroupError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateEndpointGroupError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateEndpointGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1466,12 +1659,14 @@ impl fmt::Display for UpdateEndpointGroupError {
             UpdateEndpointGroupError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             UpdateEndpointGroupError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             UpdateEndpointGroupError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            UpdateEndpointGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateEndpointGroupError {}
 /// Errors returned by UpdateListener
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateListenerError {
     /// <p>There was an internal error for AWS Global Accelerator.</p>
     InternalServiceError(String),
@@ -1483,6 +1678,8 @@ pub enum UpdateListenerError {
     LimitExceeded(String),
     /// <p>The listener that you specified doesn't exist.</p>
     ListenerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateListenerError {
@@ -1490,22 +1687,34 @@ impl UpdateListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateListenerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        UpdateListenerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        UpdateListenerError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPortRangeException" => {
-                    return RusotoError::Service(UpdateListenerError::InvalidPortRange(err.msg))
+                    return RusotoError::service(
+                        UpdateListenerError::InvalidPortRange(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateListenerError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateListenerError::LimitExceeded(err.msg), &res)
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(UpdateListenerError::ListenerNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateListenerError::ListenerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateListenerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1520,6 +1729,7 @@ impl fmt::Display for UpdateListenerError {
             UpdateListenerError::InvalidPortRange(ref cause) => write!(f, "{}", cause),
             UpdateListenerError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateListenerError::ListenerNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateListenerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1643,6 +1853,7 @@ impl GlobalAcceleratorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> GlobalAcceleratorClient {
         GlobalAcceleratorClient {
             client: Client::shared(),
@@ -1668,16 +1879,68 @@ impl GlobalAcceleratorClient {
     pub fn new_with_client(client: Client, region: region::Region) -> GlobalAcceleratorClient {
         GlobalAcceleratorClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<GlobalAcceleratorClient> {
+        rusoto_core::ClientBuilder::new(GlobalAcceleratorClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> GlobalAcceleratorClient {
+        GlobalAcceleratorClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl GlobalAccelerator for GlobalAcceleratorClient {
-    /// <p><p>Create an accelerator. An accelerator includes one or more listeners that process inbound connections and direct traffic to one or more endpoint groups, each of which includes endpoints, such as Network Load Balancers. To see an AWS CLI example of creating an accelerator, scroll down to <b>Example</b>.</p> <important> <p>You must specify the US-West-2 (Oregon) Region to create or update accelerators.</p> </important></p>
-    async fn create_accelerator(
+impl GlobalAcceleratorClient {
+    /// Calls `create_accelerator`, also returning the metadata of the response.
+    pub async fn create_accelerator_with_metadata(
         &self,
         input: CreateAcceleratorRequest,
-    ) -> Result<CreateAcceleratorResponse, RusotoError<CreateAcceleratorError>> {
+    ) -> Result<(CreateAcceleratorResponse, ResponseMetadata), RusotoError<CreateAcceleratorError>>
+    {
+        let request = self.build_create_accelerator_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAcceleratorResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAcceleratorError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_accelerator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_accelerator(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateAcceleratorRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_accelerator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_accelerator_request(&self, input: CreateAcceleratorRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("CreateAccelerator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1687,28 +1950,58 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_endpoint_group`, also returning the metadata of the response.
+    pub async fn create_endpoint_group_with_metadata(
+        &self,
+        input: CreateEndpointGroupRequest,
+    ) -> Result<
+        (CreateEndpointGroupResponse, ResponseMetadata),
+        RusotoError<CreateEndpointGroupError>,
+    > {
+        let request = self.build_create_endpoint_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAcceleratorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEndpointGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAcceleratorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEndpointGroupError::from_response(response))
         }
     }
 
-    /// <p>Create an endpoint group for the specified listener. An endpoint group is a collection of endpoints in one AWS Region. To see an AWS CLI example of creating an endpoint group, scroll down to <b>Example</b>.</p>
-    async fn create_endpoint_group(
+    /// Presigns the request `create_endpoint_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_endpoint_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEndpointGroupRequest,
-    ) -> Result<CreateEndpointGroupResponse, RusotoError<CreateEndpointGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_endpoint_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_endpoint_group_request(
+        &self,
+        input: CreateEndpointGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("CreateEndpointGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1718,55 +2011,99 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_listener`, also returning the metadata of the response.
+    pub async fn create_listener_with_metadata(
+        &self,
+        input: CreateListenerRequest,
+    ) -> Result<(CreateListenerResponse, ResponseMetadata), RusotoError<CreateListenerError>> {
+        let request = self.build_create_listener_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateEndpointGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateListenerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEndpointGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateListenerError::from_response(response))
         }
     }
 
-    /// <p>Create a listener to process inbound connections from clients to an accelerator. Connections arrive to assigned static IP addresses on a port, port range, or list of port ranges that you specify. To see an AWS CLI example of creating a listener, scroll down to <b>Example</b>.</p>
-    async fn create_listener(
+    /// Presigns the request `create_listener` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_listener(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateListenerRequest,
-    ) -> Result<CreateListenerResponse, RusotoError<CreateListenerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_listener_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_listener_request(&self, input: CreateListenerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("CreateListener");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.CreateListener");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_accelerator`, also returning the metadata of the response.
+    pub async fn delete_accelerator_with_metadata(
+        &self,
+        input: DeleteAcceleratorRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAcceleratorError>> {
+        let request = self.build_delete_accelerator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateListenerResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateListenerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAcceleratorError::from_response(response))
         }
     }
 
-    /// <p>Delete an accelerator. Note: before you can delete an accelerator, you must disable it and remove all dependent resources (listeners and endpoint groups).</p>
-    async fn delete_accelerator(
+    /// Presigns the request `delete_accelerator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_accelerator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAcceleratorRequest,
-    ) -> Result<(), RusotoError<DeleteAcceleratorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_accelerator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_accelerator_request(&self, input: DeleteAcceleratorRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DeleteAccelerator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1776,27 +2113,50 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_endpoint_group`, also returning the metadata of the response.
+    pub async fn delete_endpoint_group_with_metadata(
+        &self,
+        input: DeleteEndpointGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEndpointGroupError>> {
+        let request = self.build_delete_endpoint_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAcceleratorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEndpointGroupError::from_response(response))
         }
     }
 
-    /// <p>Delete an endpoint group from a listener.</p>
-    async fn delete_endpoint_group(
+    /// Presigns the request `delete_endpoint_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_endpoint_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEndpointGroupRequest,
-    ) -> Result<(), RusotoError<DeleteEndpointGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_endpoint_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_endpoint_group_request(
+        &self,
+        input: DeleteEndpointGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DeleteEndpointGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1806,54 +2166,105 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_listener`, also returning the metadata of the response.
+    pub async fn delete_listener_with_metadata(
+        &self,
+        input: DeleteListenerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteListenerError>> {
+        let request = self.build_delete_listener_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEndpointGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteListenerError::from_response(response))
         }
     }
 
-    /// <p>Delete a listener from an accelerator.</p>
-    async fn delete_listener(
+    /// Presigns the request `delete_listener` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_listener(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteListenerRequest,
-    ) -> Result<(), RusotoError<DeleteListenerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_listener_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_listener_request(&self, input: DeleteListenerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DeleteListener");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.DeleteListener");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_accelerator`, also returning the metadata of the response.
+    pub async fn describe_accelerator_with_metadata(
+        &self,
+        input: DescribeAcceleratorRequest,
+    ) -> Result<
+        (DescribeAcceleratorResponse, ResponseMetadata),
+        RusotoError<DescribeAcceleratorError>,
+    > {
+        let request = self.build_describe_accelerator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAcceleratorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteListenerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAcceleratorError::from_response(response))
         }
     }
 
-    /// <p>Describe an accelerator. To see an AWS CLI example of describing an accelerator, scroll down to <b>Example</b>.</p>
-    async fn describe_accelerator(
+    /// Presigns the request `describe_accelerator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_accelerator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAcceleratorRequest,
-    ) -> Result<DescribeAcceleratorResponse, RusotoError<DescribeAcceleratorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_accelerator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_accelerator_request(
+        &self,
+        input: DescribeAcceleratorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DescribeAccelerator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1863,31 +2274,58 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_accelerator_attributes`, also returning the metadata of the response.
+    pub async fn describe_accelerator_attributes_with_metadata(
+        &self,
+        input: DescribeAcceleratorAttributesRequest,
+    ) -> Result<
+        (DescribeAcceleratorAttributesResponse, ResponseMetadata),
+        RusotoError<DescribeAcceleratorAttributesError>,
+    > {
+        let request = self.build_describe_accelerator_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAcceleratorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAcceleratorAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAcceleratorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAcceleratorAttributesError::from_response(response))
         }
     }
 
-    /// <p>Describe the attributes of an accelerator.</p>
-    async fn describe_accelerator_attributes(
+    /// Presigns the request `describe_accelerator_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_accelerator_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAcceleratorAttributesRequest,
-    ) -> Result<
-        DescribeAcceleratorAttributesResponse,
-        RusotoError<DescribeAcceleratorAttributesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_accelerator_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_accelerator_attributes_request(
+        &self,
+        input: DescribeAcceleratorAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DescribeAcceleratorAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1897,28 +2335,58 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_endpoint_group`, also returning the metadata of the response.
+    pub async fn describe_endpoint_group_with_metadata(
+        &self,
+        input: DescribeEndpointGroupRequest,
+    ) -> Result<
+        (DescribeEndpointGroupResponse, ResponseMetadata),
+        RusotoError<DescribeEndpointGroupError>,
+    > {
+        let request = self.build_describe_endpoint_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAcceleratorAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEndpointGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAcceleratorAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEndpointGroupError::from_response(response))
         }
     }
 
-    /// <p>Describe an endpoint group.</p>
-    async fn describe_endpoint_group(
+    /// Presigns the request `describe_endpoint_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_endpoint_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEndpointGroupRequest,
-    ) -> Result<DescribeEndpointGroupResponse, RusotoError<DescribeEndpointGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_endpoint_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_endpoint_group_request(
+        &self,
+        input: DescribeEndpointGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DescribeEndpointGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1928,28 +2396,53 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_listener`, also returning the metadata of the response.
+    pub async fn describe_listener_with_metadata(
+        &self,
+        input: DescribeListenerRequest,
+    ) -> Result<(DescribeListenerResponse, ResponseMetadata), RusotoError<DescribeListenerError>>
+    {
+        let request = self.build_describe_listener_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEndpointGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeListenerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEndpointGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeListenerError::from_response(response))
         }
     }
 
-    /// <p>Describe a listener.</p>
-    async fn describe_listener(
+    /// Presigns the request `describe_listener` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_listener(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeListenerRequest,
-    ) -> Result<DescribeListenerResponse, RusotoError<DescribeListenerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_listener_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_listener_request(&self, input: DescribeListenerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("DescribeListener");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1959,28 +2452,53 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_accelerators`, also returning the metadata of the response.
+    pub async fn list_accelerators_with_metadata(
+        &self,
+        input: ListAcceleratorsRequest,
+    ) -> Result<(ListAcceleratorsResponse, ResponseMetadata), RusotoError<ListAcceleratorsError>>
+    {
+        let request = self.build_list_accelerators_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeListenerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAcceleratorsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeListenerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAcceleratorsError::from_response(response))
         }
     }
 
-    /// <p>List the accelerators for an AWS account.</p>
-    async fn list_accelerators(
+    /// Presigns the request `list_accelerators` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_accelerators(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAcceleratorsRequest,
-    ) -> Result<ListAcceleratorsResponse, RusotoError<ListAcceleratorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_accelerators_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_accelerators_request(&self, input: ListAcceleratorsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("ListAccelerators");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1990,28 +2508,56 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_endpoint_groups`, also returning the metadata of the response.
+    pub async fn list_endpoint_groups_with_metadata(
+        &self,
+        input: ListEndpointGroupsRequest,
+    ) -> Result<(ListEndpointGroupsResponse, ResponseMetadata), RusotoError<ListEndpointGroupsError>>
+    {
+        let request = self.build_list_endpoint_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAcceleratorsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEndpointGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAcceleratorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEndpointGroupsError::from_response(response))
         }
     }
 
-    /// <p>List the endpoint groups that are associated with a listener.</p>
-    async fn list_endpoint_groups(
+    /// Presigns the request `list_endpoint_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_endpoint_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEndpointGroupsRequest,
-    ) -> Result<ListEndpointGroupsResponse, RusotoError<ListEndpointGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_endpoint_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_endpoint_groups_request(
+        &self,
+        input: ListEndpointGroupsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("ListEndpointGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2021,55 +2567,105 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_listeners`, also returning the metadata of the response.
+    pub async fn list_listeners_with_metadata(
+        &self,
+        input: ListListenersRequest,
+    ) -> Result<(ListListenersResponse, ResponseMetadata), RusotoError<ListListenersError>> {
+        let request = self.build_list_listeners_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEndpointGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListListenersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEndpointGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListListenersError::from_response(response))
         }
     }
 
-    /// <p>List the listeners for an accelerator.</p>
-    async fn list_listeners(
+    /// Presigns the request `list_listeners` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_listeners(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListListenersRequest,
-    ) -> Result<ListListenersResponse, RusotoError<ListListenersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_listeners_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_listeners_request(&self, input: ListListenersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("ListListeners");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.ListListeners");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_accelerator`, also returning the metadata of the response.
+    pub async fn update_accelerator_with_metadata(
+        &self,
+        input: UpdateAcceleratorRequest,
+    ) -> Result<(UpdateAcceleratorResponse, ResponseMetadata), RusotoError<UpdateAcceleratorError>>
+    {
+        let request = self.build_update_accelerator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListListenersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAcceleratorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListListenersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAcceleratorError::from_response(response))
         }
     }
 
-    /// <p><p>Update an accelerator. To see an AWS CLI example of updating an accelerator, scroll down to <b>Example</b>.</p> <important> <p>You must specify the US-West-2 (Oregon) Region to create or update accelerators.</p> </important></p>
-    async fn update_accelerator(
+    /// Presigns the request `update_accelerator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_accelerator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAcceleratorRequest,
-    ) -> Result<UpdateAcceleratorResponse, RusotoError<UpdateAcceleratorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_accelerator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_accelerator_request(&self, input: UpdateAcceleratorRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("UpdateAccelerator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2079,29 +2675,58 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_accelerator_attributes`, also returning the metadata of the response.
+    pub async fn update_accelerator_attributes_with_metadata(
+        &self,
+        input: UpdateAcceleratorAttributesRequest,
+    ) -> Result<
+        (UpdateAcceleratorAttributesResponse, ResponseMetadata),
+        RusotoError<UpdateAcceleratorAttributesError>,
+    > {
+        let request = self.build_update_accelerator_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateAcceleratorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAcceleratorAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAcceleratorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAcceleratorAttributesError::from_response(response))
         }
     }
 
-    /// <p>Update the attributes for an accelerator. To see an AWS CLI example of updating an accelerator to enable flow logs, scroll down to <b>Example</b>.</p>
-    async fn update_accelerator_attributes(
+    /// Presigns the request `update_accelerator_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_accelerator_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAcceleratorAttributesRequest,
-    ) -> Result<UpdateAcceleratorAttributesResponse, RusotoError<UpdateAcceleratorAttributesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_accelerator_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_accelerator_attributes_request(
+        &self,
+        input: UpdateAcceleratorAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("UpdateAcceleratorAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2111,28 +2736,58 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_endpoint_group`, also returning the metadata of the response.
+    pub async fn update_endpoint_group_with_metadata(
+        &self,
+        input: UpdateEndpointGroupRequest,
+    ) -> Result<
+        (UpdateEndpointGroupResponse, ResponseMetadata),
+        RusotoError<UpdateEndpointGroupError>,
+    > {
+        let request = self.build_update_endpoint_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateAcceleratorAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateEndpointGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAcceleratorAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateEndpointGroupError::from_response(response))
         }
     }
 
-    /// <p>Update an endpoint group. To see an AWS CLI example of updating an endpoint group, scroll down to <b>Example</b>.</p>
-    async fn update_endpoint_group(
+    /// Presigns the request `update_endpoint_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_endpoint_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEndpointGroupRequest,
-    ) -> Result<UpdateEndpointGroupResponse, RusotoError<UpdateEndpointGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_endpoint_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_endpoint_group_request(
+        &self,
+        input: UpdateEndpointGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("UpdateEndpointGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2142,46 +2797,348 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_listener`, also returning the metadata of the response.
+    pub async fn update_listener_with_metadata(
+        &self,
+        input: UpdateListenerRequest,
+    ) -> Result<(UpdateListenerResponse, ResponseMetadata), RusotoError<UpdateListenerError>> {
+        let request = self.build_update_listener_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateEndpointGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateListenerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEndpointGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateListenerError::from_response(response))
         }
     }
 
-    /// <p>Update a listener.</p>
-    async fn update_listener(
+    /// Presigns the request `update_listener` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_listener(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateListenerRequest,
-    ) -> Result<UpdateListenerResponse, RusotoError<UpdateListenerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_listener_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_listener_request(&self, input: UpdateListenerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "globalaccelerator", &self.region, "/");
+        request.set_operation("UpdateListener");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.UpdateListener");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAccelerators` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_accelerators_pages(
+        &self,
+        input: ListAcceleratorsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAcceleratorsResponse, RusotoError<ListAcceleratorsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlobalAcceleratorClient, input| async move {
+                client.list_accelerators(input).await
+            },
+            |input: &ListAcceleratorsRequest, output: &ListAcceleratorsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAcceleratorsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Accelerators` of every page of `ListAccelerators` results for `input`, as `list_accelerators_pages` requests them.
+    pub fn list_accelerators_stream(
+        &self,
+        input: ListAcceleratorsRequest,
+    ) -> impl futures::Stream<Item = Result<Accelerator, RusotoError<ListAcceleratorsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_accelerators_pages(input), |page| {
+            page.accelerators.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListEndpointGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_endpoint_groups_pages(
+        &self,
+        input: ListEndpointGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListEndpointGroupsResponse, RusotoError<ListEndpointGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlobalAcceleratorClient, input| async move {
+                client.list_endpoint_groups(input).await
+            },
+            |input: &ListEndpointGroupsRequest, output: &ListEndpointGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEndpointGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EndpointGroups` of every page of `ListEndpointGroups` results for `input`, as `list_endpoint_groups_pages` requests them.
+    pub fn list_endpoint_groups_stream(
+        &self,
+        input: ListEndpointGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<EndpointGroup, RusotoError<ListEndpointGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_endpoint_groups_pages(input), |page| {
+            page.endpoint_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListListeners` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_listeners_pages(
+        &self,
+        input: ListListenersRequest,
+    ) -> impl futures::Stream<Item = Result<ListListenersResponse, RusotoError<ListListenersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlobalAcceleratorClient, input| async move { client.list_listeners(input).await },
+            |input: &ListListenersRequest, output: &ListListenersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListListenersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Listeners` of every page of `ListListeners` results for `input`, as `list_listeners_pages` requests them.
+    pub fn list_listeners_stream(
+        &self,
+        input: ListListenersRequest,
+    ) -> impl futures::Stream<Item = Result<Listener, RusotoError<ListListenersError>>> + Send {
+        rusoto_core::pagination::items(self.list_listeners_pages(input), |page| {
+            page.listeners.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl GlobalAccelerator for GlobalAcceleratorClient {
+    /// <p><p>Create an accelerator. An accelerator includes one or more listeners that process inbound connections and direct traffic to one or more endpoint groups, each of which includes endpoints, such as Network Load Balancers. To see an AWS CLI example of creating an accelerator, scroll down to <b>Example</b>.</p> <important> <p>You must specify the US-West-2 (Oregon) Region to create or update accelerators.</p> </important></p>
+    async fn create_accelerator(
+        &self,
+        input: CreateAcceleratorRequest,
+    ) -> Result<CreateAcceleratorResponse, RusotoError<CreateAcceleratorError>> {
+        self.create_accelerator_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateListenerResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateListenerError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create an endpoint group for the specified listener. An endpoint group is a collection of endpoints in one AWS Region. To see an AWS CLI example of creating an endpoint group, scroll down to <b>Example</b>.</p>
+    async fn create_endpoint_group(
+        &self,
+        input: CreateEndpointGroupRequest,
+    ) -> Result<CreateEndpointGroupResponse, RusotoError<CreateEndpointGroupError>> {
+        self.create_endpoint_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a listener to process inbound connections from clients to an accelerator. Connections arrive to assigned static IP addresses on a port, port range, or list of port ranges that you specify. To see an AWS CLI example of creating a listener, scroll down to <b>Example</b>.</p>
+    async fn create_listener(
+        &self,
+        input: CreateListenerRequest,
+    ) -> Result<CreateListenerResponse, RusotoError<CreateListenerError>> {
+        self.create_listener_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete an accelerator. Note: before you can delete an accelerator, you must disable it and remove all dependent resources (listeners and endpoint groups).</p>
+    async fn delete_accelerator(
+        &self,
+        input: DeleteAcceleratorRequest,
+    ) -> Result<(), RusotoError<DeleteAcceleratorError>> {
+        self.delete_accelerator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete an endpoint group from a listener.</p>
+    async fn delete_endpoint_group(
+        &self,
+        input: DeleteEndpointGroupRequest,
+    ) -> Result<(), RusotoError<DeleteEndpointGroupError>> {
+        self.delete_endpoint_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a listener from an accelerator.</p>
+    async fn delete_listener(
+        &self,
+        input: DeleteListenerRequest,
+    ) -> Result<(), RusotoError<DeleteListenerError>> {
+        self.delete_listener_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe an accelerator. To see an AWS CLI example of describing an accelerator, scroll down to <b>Example</b>.</p>
+    async fn describe_accelerator(
+        &self,
+        input: DescribeAcceleratorRequest,
+    ) -> Result<DescribeAcceleratorResponse, RusotoError<DescribeAcceleratorError>> {
+        self.describe_accelerator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe the attributes of an accelerator.</p>
+    async fn describe_accelerator_attributes(
+        &self,
+        input: DescribeAcceleratorAttributesRequest,
+    ) -> Result<
+        DescribeAcceleratorAttributesResponse,
+        RusotoError<DescribeAcceleratorAttributesError>,
+    > {
+        self.describe_accelerator_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe an endpoint group.</p>
+    async fn describe_endpoint_group(
+        &self,
+        input: DescribeEndpointGroupRequest,
+    ) -> Result<DescribeEndpointGroupResponse, RusotoError<DescribeEndpointGroupError>> {
+        self.describe_endpoint_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe a listener.</p>
+    async fn describe_listener(
+        &self,
+        input: DescribeListenerRequest,
+    ) -> Result<DescribeListenerResponse, RusotoError<DescribeListenerError>> {
+        self.describe_listener_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the accelerators for an AWS account.</p>
+    async fn list_accelerators(
+        &self,
+        input: ListAcceleratorsRequest,
+    ) -> Result<ListAcceleratorsResponse, RusotoError<ListAcceleratorsError>> {
+        self.list_accelerators_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the endpoint groups that are associated with a listener.</p>
+    async fn list_endpoint_groups(
+        &self,
+        input: ListEndpointGroupsRequest,
+    ) -> Result<ListEndpointGroupsResponse, RusotoError<ListEndpointGroupsError>> {
+        self.list_endpoint_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the listeners for an accelerator.</p>
+    async fn list_listeners(
+        &self,
+        input: ListListenersRequest,
+    ) -> Result<ListListenersResponse, RusotoError<ListListenersError>> {
+        self.list_listeners_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Update an accelerator. To see an AWS CLI example of updating an accelerator, scroll down to <b>Example</b>.</p> <important> <p>You must specify the US-West-2 (Oregon) Region to create or update accelerators.</p> </important></p>
+    async fn update_accelerator(
+        &self,
+        input: UpdateAcceleratorRequest,
+    ) -> Result<UpdateAcceleratorResponse, RusotoError<UpdateAcceleratorError>> {
+        self.update_accelerator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update the attributes for an accelerator. To see an AWS CLI example of updating an accelerator to enable flow logs, scroll down to <b>Example</b>.</p>
+    async fn update_accelerator_attributes(
+        &self,
+        input: UpdateAcceleratorAttributesRequest,
+    ) -> Result<UpdateAcceleratorAttributesResponse, RusotoError<UpdateAcceleratorAttributesError>>
+    {
+        self.update_accelerator_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update an endpoint group. To see an AWS CLI example of updating an endpoint group, scroll down to <b>Example</b>.</p>
+    async fn update_endpoint_group(
+        &self,
+        input: UpdateEndpointGroupRequest,
+    ) -> Result<UpdateEndpointGroupResponse, RusotoError<UpdateEndpointGroupError>> {
+        self.update_endpoint_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update a listener.</p>
+    async fn update_listener(
+        &self,
+        input: UpdateListenerRequest,
+    ) -> Result<UpdateListenerResponse, RusotoError<UpdateListenerError>> {
+        self.update_listener_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
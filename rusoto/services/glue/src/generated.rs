@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -5998,6 +5998,7 @@ pub struct XMLClassifier {
 
 /// Errors returned by BatchCreatePartition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchCreatePartitionError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -6013,6 +6014,8 @@ pub enum BatchCreatePartitionError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchCreatePartitionError {
@@ -6020,34 +6023,49 @@ impl BatchCreatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        BatchCreatePartitionError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        BatchCreatePartitionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        BatchCreatePartitionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchCreatePartitionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchCreatePartitionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchCreatePartitionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchCreatePartitionError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchCreatePartitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6066,17 +6084,21 @@ impl fmt::Display for BatchCreatePartitionError {
             BatchCreatePartitionError::ResourceNumberLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchCreatePartitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchCreatePartitionError {}
 /// Errors returned by BatchDeleteConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeleteConnectionError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeleteConnectionError {
@@ -6084,17 +6106,19 @@ impl BatchDeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeleteConnectionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteConnectionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteConnectionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteConnectionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDeleteConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6106,12 +6130,14 @@ impl fmt::Display for BatchDeleteConnectionError {
         match *self {
             BatchDeleteConnectionError::InternalService(ref cause) => write!(f, "{}", cause),
             BatchDeleteConnectionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchDeleteConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeleteConnectionError {}
 /// Errors returned by BatchDeletePartition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeletePartitionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -6121,6 +6147,8 @@ pub enum BatchDeletePartitionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeletePartitionError {
@@ -6128,23 +6156,31 @@ impl BatchDeletePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        BatchDeletePartitionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeletePartitionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchDeletePartitionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeletePartitionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDeletePartitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6158,12 +6194,14 @@ impl fmt::Display for BatchDeletePartitionError {
             BatchDeletePartitionError::InternalService(ref cause) => write!(f, "{}", cause),
             BatchDeletePartitionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchDeletePartitionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchDeletePartitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeletePartitionError {}
 /// Errors returned by BatchDeleteTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeleteTableError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -6173,6 +6211,8 @@ pub enum BatchDeleteTableError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeleteTableError {
@@ -6180,19 +6220,28 @@ impl BatchDeleteTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        BatchDeleteTableError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeleteTableError::InternalService(err.msg))
+                    return RusotoError::service(
+                        BatchDeleteTableError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeleteTableError::InvalidInput(err.msg))
+                    return RusotoError::service(BatchDeleteTableError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        BatchDeleteTableError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDeleteTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6206,12 +6255,14 @@ impl fmt::Display for BatchDeleteTableError {
             BatchDeleteTableError::InternalService(ref cause) => write!(f, "{}", cause),
             BatchDeleteTableError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchDeleteTableError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchDeleteTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeleteTableError {}
 /// Errors returned by BatchDeleteTableVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeleteTableVersionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -6221,6 +6272,8 @@ pub enum BatchDeleteTableVersionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeleteTableVersionError {
@@ -6228,27 +6281,31 @@ impl BatchDeleteTableVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteTableVersionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteTableVersionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteTableVersionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteTableVersionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDeleteTableVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6262,17 +6319,21 @@ impl fmt::Display for BatchDeleteTableVersionError {
             BatchDeleteTableVersionError::InternalService(ref cause) => write!(f, "{}", cause),
             BatchDeleteTableVersionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchDeleteTableVersionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchDeleteTableVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeleteTableVersionError {}
 /// Errors returned by BatchGetCrawlers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetCrawlersError {
     /// <p>The input provided was not valid.</p>
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetCrawlersError {
@@ -6280,13 +6341,16 @@ impl BatchGetCrawlersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetCrawlersError::InvalidInput(err.msg))
+                    return RusotoError::service(BatchGetCrawlersError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetCrawlersError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        BatchGetCrawlersError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetCrawlersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6298,12 +6362,14 @@ impl fmt::Display for BatchGetCrawlersError {
         match *self {
             BatchGetCrawlersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchGetCrawlersError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchGetCrawlersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetCrawlersError {}
 /// Errors returned by BatchGetDevEndpoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetDevEndpointsError {
     /// <p>Access to a resource was denied.</p>
     AccessDenied(String),
@@ -6313,6 +6379,8 @@ pub enum BatchGetDevEndpointsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetDevEndpointsError {
@@ -6320,23 +6388,31 @@ impl BatchGetDevEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        BatchGetDevEndpointsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetDevEndpointsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchGetDevEndpointsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetDevEndpointsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetDevEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6350,12 +6426,14 @@ impl fmt::Display for BatchGetDevEndpointsError {
             BatchGetDevEndpointsError::InternalService(ref cause) => write!(f, "{}", cause),
             BatchGetDevEndpointsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchGetDevEndpointsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchGetDevEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetDevEndpointsError {}
 /// Errors returned by BatchGetJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetJobsError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -6363,6 +6441,8 @@ pub enum BatchGetJobsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetJobsError {
@@ -6370,16 +6450,16 @@ impl BatchGetJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetJobsError::InternalService(err.msg))
+                    return RusotoError::service(BatchGetJobsError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(BatchGetJobsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetJobsError::OperationTimeout(err.msg))
+                    return RusotoError::service(BatchGetJobsError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6392,12 +6472,14 @@ impl fmt::Display for BatchGetJobsError {
             BatchGetJobsError::InternalService(ref cause) => write!(f, "{}", cause),
             BatchGetJobsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchGetJobsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchGetJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetJobsError {}
 /// Errors returned by BatchGetPartition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetPartitionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -6409,6 +6491,8 @@ pub enum BatchGetPartitionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetPartitionError {
@@ -6416,22 +6500,37 @@ impl BatchGetPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchGetPartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        BatchGetPartitionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(BatchGetPartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        BatchGetPartitionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetPartitionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        BatchGetPartitionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetPartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchGetPartitionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetPartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        BatchGetPartitionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetPartitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6446,12 +6545,14 @@ impl fmt::Display for BatchGetPartitionError {
             BatchGetPartitionError::InternalService(ref cause) => write!(f, "{}", cause),
             BatchGetPartitionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchGetPartitionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchGetPartitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetPartitionError {}
 /// Errors returned by BatchGetTriggers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetTriggersError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -6459,6 +6560,8 @@ pub enum BatchGetTriggersError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetTriggersError {
@@ -6466,16 +6569,22 @@ impl BatchGetTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetTriggersError::InternalService(err.msg))
+                    return RusotoError::service(
+                        BatchGetTriggersError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetTriggersError::InvalidInput(err.msg))
+                    return RusotoError::service(BatchGetTriggersError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetTriggersError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        BatchGetTriggersError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetTriggersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6488,12 +6597,14 @@ impl fmt::Display for BatchGetTriggersError {
             BatchGetTriggersError::InternalService(ref cause) => write!(f, "{}", cause),
             BatchGetTriggersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchGetTriggersError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchGetTriggersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetTriggersError {}
 /// Errors returned by BatchGetWorkflows
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetWorkflowsError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -6501,6 +6612,8 @@ pub enum BatchGetWorkflowsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetWorkflowsError {
@@ -6508,16 +6621,25 @@ impl BatchGetWorkflowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetWorkflowsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        BatchGetWorkflowsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetWorkflowsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchGetWorkflowsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetWorkflowsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        BatchGetWorkflowsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetWorkflowsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6530,12 +6652,14 @@ impl fmt::Display for BatchGetWorkflowsError {
             BatchGetWorkflowsError::InternalService(ref cause) => write!(f, "{}", cause),
             BatchGetWorkflowsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchGetWorkflowsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchGetWorkflowsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetWorkflowsError {}
 /// Errors returned by BatchStopJobRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GlueBatchStopJobRunError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -6543,6 +6667,8 @@ pub enum GlueBatchStopJobRunError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GlueBatchStopJobRunError {
@@ -6550,18 +6676,25 @@ impl GlueBatchStopJobRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GlueBatchStopJobRunError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GlueBatchStopJobRunError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GlueBatchStopJobRunError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GlueBatchStopJobRunError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GlueBatchStopJobRunError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GlueBatchStopJobRunError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GlueBatchStopJobRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6574,12 +6707,14 @@ impl fmt::Display for GlueBatchStopJobRunError {
             GlueBatchStopJobRunError::InternalService(ref cause) => write!(f, "{}", cause),
             GlueBatchStopJobRunError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GlueBatchStopJobRunError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GlueBatchStopJobRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GlueBatchStopJobRunError {}
 /// Errors returned by CancelMLTaskRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelMLTaskRunError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -6589,6 +6724,8 @@ pub enum CancelMLTaskRunError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelMLTaskRunError {
@@ -6596,19 +6733,28 @@ impl CancelMLTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        CancelMLTaskRunError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CancelMLTaskRunError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::InvalidInput(err.msg))
+                    return RusotoError::service(CancelMLTaskRunError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CancelMLTaskRunError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelMLTaskRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6622,12 +6768,14 @@ impl fmt::Display for CancelMLTaskRunError {
             CancelMLTaskRunError::InternalService(ref cause) => write!(f, "{}", cause),
             CancelMLTaskRunError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CancelMLTaskRunError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            CancelMLTaskRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelMLTaskRunError {}
 /// Errors returned by CreateClassifier
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClassifierError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -6635,6 +6783,8 @@ pub enum CreateClassifierError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClassifierError {
@@ -6642,16 +6792,22 @@ impl CreateClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateClassifierError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateClassifierError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateClassifierError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateClassifierError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CreateClassifierError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateClassifierError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6664,12 +6820,14 @@ impl fmt::Display for CreateClassifierError {
             CreateClassifierError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateClassifierError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateClassifierError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            CreateClassifierError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClassifierError {}
 /// Errors returned by CreateConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConnectionError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -6681,6 +6839,8 @@ pub enum CreateConnectionError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConnectionError {
@@ -6688,24 +6848,34 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConnectionError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateConnectionError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateConnectionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        CreateConnectionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateConnectionError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateConnectionError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CreateConnectionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConnectionError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6720,12 +6890,14 @@ impl fmt::Display for CreateConnectionError {
             CreateConnectionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateConnectionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             CreateConnectionError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConnectionError {}
 /// Errors returned by CreateCrawler
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCrawlerError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -6735,6 +6907,8 @@ pub enum CreateCrawlerError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCrawlerError {
@@ -6742,21 +6916,25 @@ impl CreateCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateCrawlerError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateCrawlerError::AlreadyExists(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCrawlerError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateCrawlerError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CreateCrawlerError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateCrawlerError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCrawlerError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCrawlerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6770,12 +6948,14 @@ impl fmt::Display for CreateCrawlerError {
             CreateCrawlerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateCrawlerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             CreateCrawlerError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateCrawlerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCrawlerError {}
 /// Errors returned by CreateDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatabaseError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -6789,6 +6969,8 @@ pub enum CreateDatabaseError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatabaseError {
@@ -6796,27 +6978,34 @@ impl CreateDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatabaseError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateDatabaseError::AlreadyExists(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateDatabaseError::GlueEncryption(err.msg))
+                    return RusotoError::service(CreateDatabaseError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateDatabaseError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreateDatabaseError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateDatabaseError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CreateDatabaseError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateDatabaseError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatabaseError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6832,12 +7021,14 @@ impl fmt::Display for CreateDatabaseError {
             CreateDatabaseError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateDatabaseError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             CreateDatabaseError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatabaseError {}
 /// Errors returned by CreateDevEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDevEndpointError {
     /// <p>Access to a resource was denied.</p>
     AccessDenied(String),
@@ -6853,6 +7044,8 @@ pub enum CreateDevEndpointError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDevEndpointError {
@@ -6860,32 +7053,49 @@ impl CreateDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDevEndpointError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateDevEndpointError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateDevEndpointError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateDevEndpointError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDevEndpointError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreateDevEndpointError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDevEndpointError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CreateDevEndpointError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDevEndpointError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDevEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6906,12 +7116,14 @@ impl fmt::Display for CreateDevEndpointError {
             CreateDevEndpointError::ResourceNumberLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDevEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDevEndpointError {}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateJobError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -6927,6 +7139,8 @@ pub enum CreateJobError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateJobError {
@@ -6934,32 +7148,37 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateJobError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateJobError::AlreadyExists(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateJobError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        CreateJobError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateJobError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateJobError::IdempotentParameterMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateJobError::InternalService(err.msg))
+                    return RusotoError::service(CreateJobError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateJobError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateJobError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateJobError::OperationTimeout(err.msg))
+                    return RusotoError::service(CreateJobError::OperationTimeout(err.msg), &res)
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateJobError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6976,12 +7195,14 @@ impl fmt::Display for CreateJobError {
             CreateJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateJobError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             CreateJobError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateJobError {}
 /// Errors returned by CreateMLTransform
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMLTransformError {
     /// <p>Access to a resource was denied.</p>
     AccessDenied(String),
@@ -6997,6 +7218,8 @@ pub enum CreateMLTransformError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMLTransformError {
@@ -7004,32 +7227,49 @@ impl CreateMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateMLTransformError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateMLTransformError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateMLTransformError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateMLTransformError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMLTransformError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateMLTransformError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreateMLTransformError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateMLTransformError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CreateMLTransformError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMLTransformError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMLTransformError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7050,12 +7290,14 @@ impl fmt::Display for CreateMLTransformError {
             CreateMLTransformError::ResourceNumberLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateMLTransformError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMLTransformError {}
 /// Errors returned by CreatePartition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePartitionError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -7071,6 +7313,8 @@ pub enum CreatePartitionError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePartitionError {
@@ -7078,30 +7322,43 @@ impl CreatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreatePartitionError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreatePartitionError::AlreadyExists(err.msg), &res)
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        CreatePartitionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        CreatePartitionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePartitionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreatePartitionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(CreatePartitionError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreatePartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CreatePartitionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreatePartitionError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePartitionError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePartitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7118,12 +7375,14 @@ impl fmt::Display for CreatePartitionError {
             CreatePartitionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreatePartitionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             CreatePartitionError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreatePartitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePartitionError {}
 /// Errors returned by CreateScript
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateScriptError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -7131,6 +7390,8 @@ pub enum CreateScriptError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateScriptError {
@@ -7138,16 +7399,16 @@ impl CreateScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateScriptError::InternalService(err.msg))
+                    return RusotoError::service(CreateScriptError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateScriptError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateScriptError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateScriptError::OperationTimeout(err.msg))
+                    return RusotoError::service(CreateScriptError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateScriptError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7160,12 +7421,14 @@ impl fmt::Display for CreateScriptError {
             CreateScriptError::InternalService(ref cause) => write!(f, "{}", cause),
             CreateScriptError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateScriptError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            CreateScriptError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateScriptError {}
 /// Errors returned by CreateSecurityConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSecurityConfigurationError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -7177,6 +7440,8 @@ pub enum CreateSecurityConfigurationError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSecurityConfigurationError {
@@ -7186,32 +7451,42 @@ impl CreateSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSecurityConfigurationError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSecurityConfigurationError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSecurityConfigurationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSecurityConfigurationError::OperationTimeout(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSecurityConfigurationError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateSecurityConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7228,12 +7503,14 @@ impl fmt::Display for CreateSecurityConfigurationError {
             CreateSecurityConfigurationError::ResourceNumberLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateSecurityConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSecurityConfigurationError {}
 /// Errors returned by CreateTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTableError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -7249,6 +7526,8 @@ pub enum CreateTableError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTableError {
@@ -7256,30 +7535,31 @@ impl CreateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateTableError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateTableError::AlreadyExists(err.msg), &res)
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(CreateTableError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateTableError::GlueEncryption(err.msg))
+                    return RusotoError::service(CreateTableError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTableError::InternalService(err.msg))
+                    return RusotoError::service(CreateTableError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateTableError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateTableError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(CreateTableError::OperationTimeout(err.msg), &res)
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateTableError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTableError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7296,12 +7576,14 @@ impl fmt::Display for CreateTableError {
             CreateTableError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateTableError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             CreateTableError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTableError {}
 /// Errors returned by CreateTrigger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTriggerError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -7319,6 +7601,8 @@ pub enum CreateTriggerError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTriggerError {
@@ -7326,37 +7610,43 @@ impl CreateTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateTriggerError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateTriggerError::AlreadyExists(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateTriggerError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTriggerError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(CreateTriggerError::EntityNotFound(err.msg), &res)
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateTriggerError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTriggerError::IdempotentParameterMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTriggerError::InternalService(err.msg))
+                    return RusotoError::service(CreateTriggerError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateTriggerError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CreateTriggerError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateTriggerError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTriggerError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTriggerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7374,12 +7664,14 @@ impl fmt::Display for CreateTriggerError {
             CreateTriggerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateTriggerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             CreateTriggerError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateTriggerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTriggerError {}
 /// Errors returned by CreateUserDefinedFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserDefinedFunctionError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -7395,6 +7687,8 @@ pub enum CreateUserDefinedFunctionError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserDefinedFunctionError {
@@ -7402,42 +7696,51 @@ impl CreateUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserDefinedFunctionError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserDefinedFunctionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::GlueEncryption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserDefinedFunctionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserDefinedFunctionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserDefinedFunctionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserDefinedFunctionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUserDefinedFunctionError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateUserDefinedFunctionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7456,12 +7759,14 @@ impl fmt::Display for CreateUserDefinedFunctionError {
             CreateUserDefinedFunctionError::ResourceNumberLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateUserDefinedFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserDefinedFunctionError {}
 /// Errors returned by CreateWorkflow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateWorkflowError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -7475,6 +7780,8 @@ pub enum CreateWorkflowError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateWorkflowError {
@@ -7482,29 +7789,37 @@ impl CreateWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateWorkflowError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateWorkflowError::AlreadyExists(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateWorkflowError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateWorkflowError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateWorkflowError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreateWorkflowError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateWorkflowError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        CreateWorkflowError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateWorkflowError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateWorkflowError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateWorkflowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7520,17 +7835,21 @@ impl fmt::Display for CreateWorkflowError {
             CreateWorkflowError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateWorkflowError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             CreateWorkflowError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateWorkflowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateWorkflowError {}
 /// Errors returned by DeleteClassifier
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClassifierError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClassifierError {
@@ -7538,13 +7857,19 @@ impl DeleteClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteClassifierError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteClassifierError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteClassifierError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteClassifierError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7556,17 +7881,21 @@ impl fmt::Display for DeleteClassifierError {
         match *self {
             DeleteClassifierError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             DeleteClassifierError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteClassifierError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClassifierError {}
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConnectionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConnectionError {
@@ -7574,13 +7903,19 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteConnectionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteConnectionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteConnectionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7592,12 +7927,14 @@ impl fmt::Display for DeleteConnectionError {
         match *self {
             DeleteConnectionError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             DeleteConnectionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConnectionError {}
 /// Errors returned by DeleteCrawler
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCrawlerError {
     /// <p>The operation cannot be performed because the crawler is already running.</p>
     CrawlerRunning(String),
@@ -7607,6 +7944,8 @@ pub enum DeleteCrawlerError {
     OperationTimeout(String),
     /// <p>The specified scheduler is transitioning.</p>
     SchedulerTransitioning(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCrawlerError {
@@ -7614,21 +7953,25 @@ impl DeleteCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerRunningException" => {
-                    return RusotoError::Service(DeleteCrawlerError::CrawlerRunning(err.msg))
+                    return RusotoError::service(DeleteCrawlerError::CrawlerRunning(err.msg), &res)
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(DeleteCrawlerError::EntityNotFound(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteCrawlerError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(DeleteCrawlerError::SchedulerTransitioning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCrawlerError::SchedulerTransitioning(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCrawlerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7642,12 +7985,14 @@ impl fmt::Display for DeleteCrawlerError {
             DeleteCrawlerError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             DeleteCrawlerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             DeleteCrawlerError::SchedulerTransitioning(ref cause) => write!(f, "{}", cause),
+            DeleteCrawlerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCrawlerError {}
 /// Errors returned by DeleteDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatabaseError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -7657,6 +8002,8 @@ pub enum DeleteDatabaseError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatabaseError {
@@ -7664,19 +8011,25 @@ impl DeleteDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteDatabaseError::EntityNotFound(err.msg))
+                    return RusotoError::service(DeleteDatabaseError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteDatabaseError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeleteDatabaseError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteDatabaseError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteDatabaseError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7690,12 +8043,14 @@ impl fmt::Display for DeleteDatabaseError {
             DeleteDatabaseError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteDatabaseError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteDatabaseError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatabaseError {}
 /// Errors returned by DeleteDevEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDevEndpointError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -7705,6 +8060,8 @@ pub enum DeleteDevEndpointError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDevEndpointError {
@@ -7712,19 +8069,31 @@ impl DeleteDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDevEndpointError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeleteDevEndpointError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteDevEndpointError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteDevEndpointError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDevEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7738,12 +8107,14 @@ impl fmt::Display for DeleteDevEndpointError {
             DeleteDevEndpointError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteDevEndpointError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteDevEndpointError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteDevEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDevEndpointError {}
 /// Errors returned by DeleteJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteJobError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -7751,6 +8122,8 @@ pub enum DeleteJobError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteJobError {
@@ -7758,16 +8131,16 @@ impl DeleteJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteJobError::InternalService(err.msg))
+                    return RusotoError::service(DeleteJobError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteJobError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteJobError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteJobError::OperationTimeout(err.msg))
+                    return RusotoError::service(DeleteJobError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7780,12 +8153,14 @@ impl fmt::Display for DeleteJobError {
             DeleteJobError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteJobError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteJobError {}
 /// Errors returned by DeleteMLTransform
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMLTransformError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -7795,6 +8170,8 @@ pub enum DeleteMLTransformError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMLTransformError {
@@ -7802,19 +8179,31 @@ impl DeleteMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteMLTransformError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteMLTransformError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteMLTransformError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeleteMLTransformError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteMLTransformError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteMLTransformError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMLTransformError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7828,12 +8217,14 @@ impl fmt::Display for DeleteMLTransformError {
             DeleteMLTransformError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteMLTransformError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteMLTransformError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteMLTransformError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMLTransformError {}
 /// Errors returned by DeletePartition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePartitionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -7843,6 +8234,8 @@ pub enum DeletePartitionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePartitionError {
@@ -7850,19 +8243,28 @@ impl DeletePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeletePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        DeletePartitionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePartitionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeletePartitionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(DeletePartitionError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeletePartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeletePartitionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePartitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7876,12 +8278,14 @@ impl fmt::Display for DeletePartitionError {
             DeletePartitionError::InternalService(ref cause) => write!(f, "{}", cause),
             DeletePartitionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeletePartitionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeletePartitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePartitionError {}
 /// Errors returned by DeleteResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourcePolicyError {
     /// <p>A specified condition was not satisfied.</p>
     ConditionCheckFailure(String),
@@ -7893,6 +8297,8 @@ pub enum DeleteResourcePolicyError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourcePolicyError {
@@ -7900,28 +8306,37 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionCheckFailureException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ConditionCheckFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::ConditionCheckFailure(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7936,12 +8351,14 @@ impl fmt::Display for DeleteResourcePolicyError {
             DeleteResourcePolicyError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteResourcePolicyError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteResourcePolicyError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourcePolicyError {}
 /// Errors returned by DeleteSecurityConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSecurityConfigurationError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -7951,6 +8368,8 @@ pub enum DeleteSecurityConfigurationError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSecurityConfigurationError {
@@ -7960,27 +8379,36 @@ impl DeleteSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSecurityConfigurationError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSecurityConfigurationError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSecurityConfigurationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteSecurityConfigurationError::OperationTimeout(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteSecurityConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7994,12 +8422,14 @@ impl fmt::Display for DeleteSecurityConfigurationError {
             DeleteSecurityConfigurationError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteSecurityConfigurationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteSecurityConfigurationError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteSecurityConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSecurityConfigurationError {}
 /// Errors returned by DeleteTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTableError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8009,6 +8439,8 @@ pub enum DeleteTableError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTableError {
@@ -8016,19 +8448,19 @@ impl DeleteTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(DeleteTableError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTableError::InternalService(err.msg))
+                    return RusotoError::service(DeleteTableError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTableError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteTableError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(DeleteTableError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8042,12 +8474,14 @@ impl fmt::Display for DeleteTableError {
             DeleteTableError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteTableError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteTableError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTableError {}
 /// Errors returned by DeleteTableVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTableVersionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8057,6 +8491,8 @@ pub enum DeleteTableVersionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTableVersionError {
@@ -8064,19 +8500,31 @@ impl DeleteTableVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteTableVersionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteTableVersionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTableVersionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeleteTableVersionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTableVersionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteTableVersionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteTableVersionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteTableVersionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTableVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8090,12 +8538,14 @@ impl fmt::Display for DeleteTableVersionError {
             DeleteTableVersionError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteTableVersionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteTableVersionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteTableVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTableVersionError {}
 /// Errors returned by DeleteTrigger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTriggerError {
     /// <p>Two processes are trying to modify a resource simultaneously.</p>
     ConcurrentModification(String),
@@ -8105,6 +8555,8 @@ pub enum DeleteTriggerError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTriggerError {
@@ -8112,21 +8564,25 @@ impl DeleteTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteTriggerError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTriggerError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTriggerError::InternalService(err.msg))
+                    return RusotoError::service(DeleteTriggerError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteTriggerError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteTriggerError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTriggerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8140,12 +8596,14 @@ impl fmt::Display for DeleteTriggerError {
             DeleteTriggerError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteTriggerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteTriggerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteTriggerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTriggerError {}
 /// Errors returned by DeleteUserDefinedFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserDefinedFunctionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8155,6 +8613,8 @@ pub enum DeleteUserDefinedFunctionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserDefinedFunctionError {
@@ -8162,27 +8622,33 @@ impl DeleteUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserDefinedFunctionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserDefinedFunctionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserDefinedFunctionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserDefinedFunctionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteUserDefinedFunctionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8196,12 +8662,14 @@ impl fmt::Display for DeleteUserDefinedFunctionError {
             DeleteUserDefinedFunctionError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteUserDefinedFunctionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteUserDefinedFunctionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteUserDefinedFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserDefinedFunctionError {}
 /// Errors returned by DeleteWorkflow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteWorkflowError {
     /// <p>Two processes are trying to modify a resource simultaneously.</p>
     ConcurrentModification(String),
@@ -8211,6 +8679,8 @@ pub enum DeleteWorkflowError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteWorkflowError {
@@ -8218,21 +8688,28 @@ impl DeleteWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteWorkflowError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteWorkflowError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteWorkflowError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeleteWorkflowError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteWorkflowError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteWorkflowError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteWorkflowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8246,17 +8723,21 @@ impl fmt::Display for DeleteWorkflowError {
             DeleteWorkflowError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteWorkflowError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteWorkflowError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeleteWorkflowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteWorkflowError {}
 /// Errors returned by GetCatalogImportStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCatalogImportStatusError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCatalogImportStatusError {
@@ -8264,17 +8745,19 @@ impl GetCatalogImportStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetCatalogImportStatusError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCatalogImportStatusError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCatalogImportStatusError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCatalogImportStatusError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCatalogImportStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8286,17 +8769,21 @@ impl fmt::Display for GetCatalogImportStatusError {
         match *self {
             GetCatalogImportStatusError::InternalService(ref cause) => write!(f, "{}", cause),
             GetCatalogImportStatusError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetCatalogImportStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCatalogImportStatusError {}
 /// Errors returned by GetClassifier
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetClassifierError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetClassifierError {
@@ -8304,13 +8791,16 @@ impl GetClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetClassifierError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetClassifierError::EntityNotFound(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetClassifierError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetClassifierError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8322,15 +8812,19 @@ impl fmt::Display for GetClassifierError {
         match *self {
             GetClassifierError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             GetClassifierError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetClassifierError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetClassifierError {}
 /// Errors returned by GetClassifiers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetClassifiersError {
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetClassifiersError {
@@ -8338,10 +8832,13 @@ impl GetClassifiersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetClassifiersError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetClassifiersError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetClassifiersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8352,12 +8849,14 @@ impl fmt::Display for GetClassifiersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetClassifiersError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetClassifiersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetClassifiersError {}
 /// Errors returned by GetConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConnectionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8367,6 +8866,8 @@ pub enum GetConnectionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConnectionError {
@@ -8374,19 +8875,22 @@ impl GetConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetConnectionError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetConnectionError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetConnectionError::GlueEncryption(err.msg))
+                    return RusotoError::service(GetConnectionError::GlueEncryption(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetConnectionError::InvalidInput(err.msg))
+                    return RusotoError::service(GetConnectionError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetConnectionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8400,12 +8904,14 @@ impl fmt::Display for GetConnectionError {
             GetConnectionError::GlueEncryption(ref cause) => write!(f, "{}", cause),
             GetConnectionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetConnectionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConnectionError {}
 /// Errors returned by GetConnections
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConnectionsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8415,6 +8921,8 @@ pub enum GetConnectionsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConnectionsError {
@@ -8422,19 +8930,22 @@ impl GetConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetConnectionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetConnectionsError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetConnectionsError::GlueEncryption(err.msg))
+                    return RusotoError::service(GetConnectionsError::GlueEncryption(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetConnectionsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetConnectionsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetConnectionsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetConnectionsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConnectionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8448,17 +8959,21 @@ impl fmt::Display for GetConnectionsError {
             GetConnectionsError::GlueEncryption(ref cause) => write!(f, "{}", cause),
             GetConnectionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetConnectionsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetConnectionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConnectionsError {}
 /// Errors returned by GetCrawler
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCrawlerError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCrawlerError {
@@ -8466,13 +8981,13 @@ impl GetCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetCrawlerError::EntityNotFound(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetCrawlerError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCrawlerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8484,15 +8999,19 @@ impl fmt::Display for GetCrawlerError {
         match *self {
             GetCrawlerError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             GetCrawlerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetCrawlerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCrawlerError {}
 /// Errors returned by GetCrawlerMetrics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCrawlerMetricsError {
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCrawlerMetricsError {
@@ -8500,10 +9019,13 @@ impl GetCrawlerMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCrawlerMetricsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetCrawlerMetricsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCrawlerMetricsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8514,15 +9036,19 @@ impl fmt::Display for GetCrawlerMetricsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetCrawlerMetricsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetCrawlerMetricsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCrawlerMetricsError {}
 /// Errors returned by GetCrawlers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCrawlersError {
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCrawlersError {
@@ -8530,10 +9056,10 @@ impl GetCrawlersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCrawlersError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetCrawlersError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCrawlersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8544,12 +9070,14 @@ impl fmt::Display for GetCrawlersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetCrawlersError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetCrawlersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCrawlersError {}
 /// Errors returned by GetDataCatalogEncryptionSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDataCatalogEncryptionSettingsError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -8557,6 +9085,8 @@ pub enum GetDataCatalogEncryptionSettingsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDataCatalogEncryptionSettingsError {
@@ -8566,22 +9096,30 @@ impl GetDataCatalogEncryptionSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDataCatalogEncryptionSettingsError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDataCatalogEncryptionSettingsError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDataCatalogEncryptionSettingsError::OperationTimeout(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetDataCatalogEncryptionSettingsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8600,12 +9138,16 @@ impl fmt::Display for GetDataCatalogEncryptionSettingsError {
             GetDataCatalogEncryptionSettingsError::OperationTimeout(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetDataCatalogEncryptionSettingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetDataCatalogEncryptionSettingsError {}
 /// Errors returned by GetDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDatabaseError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8617,6 +9159,8 @@ pub enum GetDatabaseError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDatabaseError {
@@ -8624,22 +9168,22 @@ impl GetDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDatabaseError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetDatabaseError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetDatabaseError::GlueEncryption(err.msg))
+                    return RusotoError::service(GetDatabaseError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDatabaseError::InternalService(err.msg))
+                    return RusotoError::service(GetDatabaseError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDatabaseError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetDatabaseError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8654,12 +9198,14 @@ impl fmt::Display for GetDatabaseError {
             GetDatabaseError::InternalService(ref cause) => write!(f, "{}", cause),
             GetDatabaseError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetDatabaseError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDatabaseError {}
 /// Errors returned by GetDatabases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDatabasesError {
     /// <p>An encryption operation failed.</p>
     GlueEncryption(String),
@@ -8669,6 +9215,8 @@ pub enum GetDatabasesError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDatabasesError {
@@ -8676,19 +9224,19 @@ impl GetDatabasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetDatabasesError::GlueEncryption(err.msg))
+                    return RusotoError::service(GetDatabasesError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDatabasesError::InternalService(err.msg))
+                    return RusotoError::service(GetDatabasesError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDatabasesError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDatabasesError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDatabasesError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetDatabasesError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDatabasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8702,12 +9250,14 @@ impl fmt::Display for GetDatabasesError {
             GetDatabasesError::InternalService(ref cause) => write!(f, "{}", cause),
             GetDatabasesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetDatabasesError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetDatabasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDatabasesError {}
 /// Errors returned by GetDataflowGraph
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDataflowGraphError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -8715,6 +9265,8 @@ pub enum GetDataflowGraphError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDataflowGraphError {
@@ -8722,16 +9274,22 @@ impl GetDataflowGraphError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDataflowGraphError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetDataflowGraphError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDataflowGraphError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDataflowGraphError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDataflowGraphError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetDataflowGraphError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDataflowGraphError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8744,12 +9302,14 @@ impl fmt::Display for GetDataflowGraphError {
             GetDataflowGraphError::InternalService(ref cause) => write!(f, "{}", cause),
             GetDataflowGraphError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetDataflowGraphError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetDataflowGraphError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDataflowGraphError {}
 /// Errors returned by GetDevEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDevEndpointError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8759,6 +9319,8 @@ pub enum GetDevEndpointError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDevEndpointError {
@@ -8766,19 +9328,25 @@ impl GetDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDevEndpointError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetDevEndpointError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetDevEndpointError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDevEndpointError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetDevEndpointError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDevEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8792,12 +9360,14 @@ impl fmt::Display for GetDevEndpointError {
             GetDevEndpointError::InternalService(ref cause) => write!(f, "{}", cause),
             GetDevEndpointError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetDevEndpointError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetDevEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDevEndpointError {}
 /// Errors returned by GetDevEndpoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDevEndpointsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8807,6 +9377,8 @@ pub enum GetDevEndpointsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDevEndpointsError {
@@ -8814,19 +9386,28 @@ impl GetDevEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDevEndpointsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        GetDevEndpointsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDevEndpointsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetDevEndpointsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDevEndpointsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDevEndpointsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDevEndpointsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetDevEndpointsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDevEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8840,12 +9421,14 @@ impl fmt::Display for GetDevEndpointsError {
             GetDevEndpointsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetDevEndpointsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetDevEndpointsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetDevEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDevEndpointsError {}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8855,6 +9438,8 @@ pub enum GetJobError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobError {
@@ -8862,19 +9447,19 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetJobError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobError::InternalService(err.msg))
+                    return RusotoError::service(GetJobError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobError::InvalidInput(err.msg))
+                    return RusotoError::service(GetJobError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetJobError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8888,12 +9473,14 @@ impl fmt::Display for GetJobError {
             GetJobError::InternalService(ref cause) => write!(f, "{}", cause),
             GetJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetJobError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobError {}
 /// Errors returned by GetJobBookmark
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobBookmarkError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8903,6 +9490,8 @@ pub enum GetJobBookmarkError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobBookmarkError {
@@ -8910,19 +9499,25 @@ impl GetJobBookmarkError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobBookmarkError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetJobBookmarkError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobBookmarkError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetJobBookmarkError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobBookmarkError::InvalidInput(err.msg))
+                    return RusotoError::service(GetJobBookmarkError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobBookmarkError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetJobBookmarkError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobBookmarkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8936,12 +9531,14 @@ impl fmt::Display for GetJobBookmarkError {
             GetJobBookmarkError::InternalService(ref cause) => write!(f, "{}", cause),
             GetJobBookmarkError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetJobBookmarkError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetJobBookmarkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobBookmarkError {}
 /// Errors returned by GetJobRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobRunError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8951,6 +9548,8 @@ pub enum GetJobRunError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobRunError {
@@ -8958,19 +9557,19 @@ impl GetJobRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetJobRunError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobRunError::InternalService(err.msg))
+                    return RusotoError::service(GetJobRunError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobRunError::InvalidInput(err.msg))
+                    return RusotoError::service(GetJobRunError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetJobRunError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8984,12 +9583,14 @@ impl fmt::Display for GetJobRunError {
             GetJobRunError::InternalService(ref cause) => write!(f, "{}", cause),
             GetJobRunError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetJobRunError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetJobRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobRunError {}
 /// Errors returned by GetJobRuns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobRunsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -8999,6 +9600,8 @@ pub enum GetJobRunsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobRunsError {
@@ -9006,19 +9609,19 @@ impl GetJobRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobRunsError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetJobRunsError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobRunsError::InternalService(err.msg))
+                    return RusotoError::service(GetJobRunsError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetJobRunsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobRunsError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetJobRunsError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobRunsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9032,12 +9635,14 @@ impl fmt::Display for GetJobRunsError {
             GetJobRunsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetJobRunsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetJobRunsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetJobRunsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobRunsError {}
 /// Errors returned by GetJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9047,6 +9652,8 @@ pub enum GetJobsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobsError {
@@ -9054,19 +9661,19 @@ impl GetJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobsError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetJobsError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobsError::InternalService(err.msg))
+                    return RusotoError::service(GetJobsError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetJobsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobsError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetJobsError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9080,12 +9687,14 @@ impl fmt::Display for GetJobsError {
             GetJobsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetJobsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetJobsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobsError {}
 /// Errors returned by GetMLTaskRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMLTaskRunError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9095,6 +9704,8 @@ pub enum GetMLTaskRunError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMLTaskRunError {
@@ -9102,19 +9713,19 @@ impl GetMLTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTaskRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetMLTaskRunError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTaskRunError::InternalService(err.msg))
+                    return RusotoError::service(GetMLTaskRunError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTaskRunError::InvalidInput(err.msg))
+                    return RusotoError::service(GetMLTaskRunError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTaskRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetMLTaskRunError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMLTaskRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9128,12 +9739,14 @@ impl fmt::Display for GetMLTaskRunError {
             GetMLTaskRunError::InternalService(ref cause) => write!(f, "{}", cause),
             GetMLTaskRunError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetMLTaskRunError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetMLTaskRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMLTaskRunError {}
 /// Errors returned by GetMLTaskRuns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMLTaskRunsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9143,6 +9756,8 @@ pub enum GetMLTaskRunsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMLTaskRunsError {
@@ -9150,19 +9765,22 @@ impl GetMLTaskRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetMLTaskRunsError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::InternalService(err.msg))
+                    return RusotoError::service(GetMLTaskRunsError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetMLTaskRunsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetMLTaskRunsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMLTaskRunsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9176,12 +9794,14 @@ impl fmt::Display for GetMLTaskRunsError {
             GetMLTaskRunsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetMLTaskRunsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetMLTaskRunsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetMLTaskRunsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMLTaskRunsError {}
 /// Errors returned by GetMLTransform
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMLTransformError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9191,6 +9811,8 @@ pub enum GetMLTransformError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMLTransformError {
@@ -9198,19 +9820,25 @@ impl GetMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTransformError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetMLTransformError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTransformError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetMLTransformError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service(GetMLTransformError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetMLTransformError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMLTransformError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9224,12 +9852,14 @@ impl fmt::Display for GetMLTransformError {
             GetMLTransformError::InternalService(ref cause) => write!(f, "{}", cause),
             GetMLTransformError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetMLTransformError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetMLTransformError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMLTransformError {}
 /// Errors returned by GetMLTransforms
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMLTransformsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9239,6 +9869,8 @@ pub enum GetMLTransformsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMLTransformsError {
@@ -9246,19 +9878,28 @@ impl GetMLTransformsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTransformsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        GetMLTransformsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTransformsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetMLTransformsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTransformsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetMLTransformsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTransformsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetMLTransformsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMLTransformsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9272,12 +9913,14 @@ impl fmt::Display for GetMLTransformsError {
             GetMLTransformsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetMLTransformsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetMLTransformsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetMLTransformsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMLTransformsError {}
 /// Errors returned by GetMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMappingError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9287,6 +9930,8 @@ pub enum GetMappingError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMappingError {
@@ -9294,19 +9939,19 @@ impl GetMappingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMappingError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetMappingError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMappingError::InternalService(err.msg))
+                    return RusotoError::service(GetMappingError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMappingError::InvalidInput(err.msg))
+                    return RusotoError::service(GetMappingError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMappingError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetMappingError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9320,12 +9965,14 @@ impl fmt::Display for GetMappingError {
             GetMappingError::InternalService(ref cause) => write!(f, "{}", cause),
             GetMappingError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetMappingError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMappingError {}
 /// Errors returned by GetPartition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPartitionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9337,6 +9984,8 @@ pub enum GetPartitionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPartitionError {
@@ -9344,22 +9993,22 @@ impl GetPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetPartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetPartitionError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetPartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(GetPartitionError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPartitionError::InternalService(err.msg))
+                    return RusotoError::service(GetPartitionError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(GetPartitionError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetPartitionError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPartitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9374,12 +10023,14 @@ impl fmt::Display for GetPartitionError {
             GetPartitionError::InternalService(ref cause) => write!(f, "{}", cause),
             GetPartitionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetPartitionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetPartitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPartitionError {}
 /// Errors returned by GetPartitions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPartitionsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9391,6 +10042,8 @@ pub enum GetPartitionsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPartitionsError {
@@ -9398,22 +10051,25 @@ impl GetPartitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetPartitionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetPartitionsError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetPartitionsError::GlueEncryption(err.msg))
+                    return RusotoError::service(GetPartitionsError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPartitionsError::InternalService(err.msg))
+                    return RusotoError::service(GetPartitionsError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPartitionsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetPartitionsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPartitionsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetPartitionsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPartitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9428,12 +10084,14 @@ impl fmt::Display for GetPartitionsError {
             GetPartitionsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetPartitionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetPartitionsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetPartitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPartitionsError {}
 /// Errors returned by GetPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPlanError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -9441,6 +10099,8 @@ pub enum GetPlanError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPlanError {
@@ -9448,16 +10108,16 @@ impl GetPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPlanError::InternalService(err.msg))
+                    return RusotoError::service(GetPlanError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPlanError::InvalidInput(err.msg))
+                    return RusotoError::service(GetPlanError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPlanError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetPlanError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9470,12 +10130,14 @@ impl fmt::Display for GetPlanError {
             GetPlanError::InternalService(ref cause) => write!(f, "{}", cause),
             GetPlanError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetPlanError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPlanError {}
 /// Errors returned by GetResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourcePolicyError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9485,6 +10147,8 @@ pub enum GetResourcePolicyError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourcePolicyError {
@@ -9492,19 +10156,31 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        GetResourcePolicyError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetResourcePolicyError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetResourcePolicyError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetResourcePolicyError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetResourcePolicyError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9518,12 +10194,14 @@ impl fmt::Display for GetResourcePolicyError {
             GetResourcePolicyError::InternalService(ref cause) => write!(f, "{}", cause),
             GetResourcePolicyError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetResourcePolicyError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourcePolicyError {}
 /// Errors returned by GetSecurityConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSecurityConfigurationError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9533,6 +10211,8 @@ pub enum GetSecurityConfigurationError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSecurityConfigurationError {
@@ -9540,27 +10220,31 @@ impl GetSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSecurityConfigurationError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSecurityConfigurationError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSecurityConfigurationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSecurityConfigurationError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSecurityConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9574,12 +10258,14 @@ impl fmt::Display for GetSecurityConfigurationError {
             GetSecurityConfigurationError::InternalService(ref cause) => write!(f, "{}", cause),
             GetSecurityConfigurationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetSecurityConfigurationError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetSecurityConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSecurityConfigurationError {}
 /// Errors returned by GetSecurityConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSecurityConfigurationsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9589,6 +10275,8 @@ pub enum GetSecurityConfigurationsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSecurityConfigurationsError {
@@ -9596,27 +10284,33 @@ impl GetSecurityConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSecurityConfigurationsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSecurityConfigurationsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSecurityConfigurationsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSecurityConfigurationsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetSecurityConfigurationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9630,12 +10324,14 @@ impl fmt::Display for GetSecurityConfigurationsError {
             GetSecurityConfigurationsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetSecurityConfigurationsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetSecurityConfigurationsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetSecurityConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSecurityConfigurationsError {}
 /// Errors returned by GetTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTableError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9647,6 +10343,8 @@ pub enum GetTableError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTableError {
@@ -9654,22 +10352,22 @@ impl GetTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetTableError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTableError::GlueEncryption(err.msg))
+                    return RusotoError::service(GetTableError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTableError::InternalService(err.msg))
+                    return RusotoError::service(GetTableError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTableError::InvalidInput(err.msg))
+                    return RusotoError::service(GetTableError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetTableError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9684,12 +10382,14 @@ impl fmt::Display for GetTableError {
             GetTableError::InternalService(ref cause) => write!(f, "{}", cause),
             GetTableError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetTableError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTableError {}
 /// Errors returned by GetTableVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTableVersionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9701,6 +10401,8 @@ pub enum GetTableVersionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTableVersionError {
@@ -9708,22 +10410,34 @@ impl GetTableVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTableVersionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        GetTableVersionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTableVersionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        GetTableVersionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTableVersionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetTableVersionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTableVersionError::InvalidInput(err.msg))
+                    return RusotoError::service(GetTableVersionError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTableVersionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetTableVersionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTableVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9738,12 +10452,14 @@ impl fmt::Display for GetTableVersionError {
             GetTableVersionError::InternalService(ref cause) => write!(f, "{}", cause),
             GetTableVersionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetTableVersionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetTableVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTableVersionError {}
 /// Errors returned by GetTableVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTableVersionsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9755,6 +10471,8 @@ pub enum GetTableVersionsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTableVersionsError {
@@ -9762,22 +10480,34 @@ impl GetTableVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTableVersionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        GetTableVersionsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTableVersionsError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        GetTableVersionsError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTableVersionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetTableVersionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTableVersionsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetTableVersionsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTableVersionsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetTableVersionsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTableVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9792,12 +10522,14 @@ impl fmt::Display for GetTableVersionsError {
             GetTableVersionsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetTableVersionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetTableVersionsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetTableVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTableVersionsError {}
 /// Errors returned by GetTables
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTablesError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9809,6 +10541,8 @@ pub enum GetTablesError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTablesError {
@@ -9816,22 +10550,22 @@ impl GetTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTablesError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetTablesError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTablesError::GlueEncryption(err.msg))
+                    return RusotoError::service(GetTablesError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTablesError::InternalService(err.msg))
+                    return RusotoError::service(GetTablesError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTablesError::InvalidInput(err.msg))
+                    return RusotoError::service(GetTablesError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTablesError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetTablesError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTablesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9846,12 +10580,14 @@ impl fmt::Display for GetTablesError {
             GetTablesError::InternalService(ref cause) => write!(f, "{}", cause),
             GetTablesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetTablesError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetTablesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTablesError {}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTagsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9861,6 +10597,8 @@ pub enum GetTagsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTagsError {
@@ -9868,19 +10606,19 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTagsError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetTagsError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTagsError::InternalService(err.msg))
+                    return RusotoError::service(GetTagsError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTagsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetTagsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTagsError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetTagsError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9894,12 +10632,14 @@ impl fmt::Display for GetTagsError {
             GetTagsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetTagsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetTagsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTagsError {}
 /// Errors returned by GetTrigger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTriggerError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9909,6 +10649,8 @@ pub enum GetTriggerError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTriggerError {
@@ -9916,19 +10658,19 @@ impl GetTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetTriggerError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTriggerError::InternalService(err.msg))
+                    return RusotoError::service(GetTriggerError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(GetTriggerError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetTriggerError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTriggerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9942,12 +10684,14 @@ impl fmt::Display for GetTriggerError {
             GetTriggerError::InternalService(ref cause) => write!(f, "{}", cause),
             GetTriggerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetTriggerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetTriggerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTriggerError {}
 /// Errors returned by GetTriggers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTriggersError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -9957,6 +10701,8 @@ pub enum GetTriggersError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTriggersError {
@@ -9964,19 +10710,19 @@ impl GetTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTriggersError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetTriggersError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTriggersError::InternalService(err.msg))
+                    return RusotoError::service(GetTriggersError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTriggersError::InvalidInput(err.msg))
+                    return RusotoError::service(GetTriggersError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTriggersError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetTriggersError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTriggersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9990,12 +10736,14 @@ impl fmt::Display for GetTriggersError {
             GetTriggersError::InternalService(ref cause) => write!(f, "{}", cause),
             GetTriggersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetTriggersError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetTriggersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTriggersError {}
 /// Errors returned by GetUserDefinedFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUserDefinedFunctionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10007,6 +10755,8 @@ pub enum GetUserDefinedFunctionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUserDefinedFunctionError {
@@ -10014,30 +10764,37 @@ impl GetUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::GlueEncryption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUserDefinedFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10052,12 +10809,14 @@ impl fmt::Display for GetUserDefinedFunctionError {
             GetUserDefinedFunctionError::InternalService(ref cause) => write!(f, "{}", cause),
             GetUserDefinedFunctionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetUserDefinedFunctionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetUserDefinedFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUserDefinedFunctionError {}
 /// Errors returned by GetUserDefinedFunctions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUserDefinedFunctionsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10069,6 +10828,8 @@ pub enum GetUserDefinedFunctionsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUserDefinedFunctionsError {
@@ -10076,32 +10837,37 @@ impl GetUserDefinedFunctionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::GlueEncryption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionsError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserDefinedFunctionsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUserDefinedFunctionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10116,12 +10882,14 @@ impl fmt::Display for GetUserDefinedFunctionsError {
             GetUserDefinedFunctionsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetUserDefinedFunctionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetUserDefinedFunctionsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetUserDefinedFunctionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUserDefinedFunctionsError {}
 /// Errors returned by GetWorkflow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetWorkflowError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10131,6 +10899,8 @@ pub enum GetWorkflowError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetWorkflowError {
@@ -10138,19 +10908,19 @@ impl GetWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetWorkflowError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowError::InternalService(err.msg))
+                    return RusotoError::service(GetWorkflowError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service(GetWorkflowError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service(GetWorkflowError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetWorkflowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10164,12 +10934,14 @@ impl fmt::Display for GetWorkflowError {
             GetWorkflowError::InternalService(ref cause) => write!(f, "{}", cause),
             GetWorkflowError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetWorkflowError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetWorkflowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetWorkflowError {}
 /// Errors returned by GetWorkflowRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetWorkflowRunError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10179,6 +10951,8 @@ pub enum GetWorkflowRunError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetWorkflowRunError {
@@ -10186,19 +10960,25 @@ impl GetWorkflowRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(GetWorkflowRunError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowRunError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetWorkflowRunError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowRunError::InvalidInput(err.msg))
+                    return RusotoError::service(GetWorkflowRunError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetWorkflowRunError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetWorkflowRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10212,12 +10992,14 @@ impl fmt::Display for GetWorkflowRunError {
             GetWorkflowRunError::InternalService(ref cause) => write!(f, "{}", cause),
             GetWorkflowRunError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetWorkflowRunError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetWorkflowRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetWorkflowRunError {}
 /// Errors returned by GetWorkflowRunProperties
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetWorkflowRunPropertiesError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10227,6 +11009,8 @@ pub enum GetWorkflowRunPropertiesError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetWorkflowRunPropertiesError {
@@ -10234,27 +11018,31 @@ impl GetWorkflowRunPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetWorkflowRunPropertiesError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetWorkflowRunPropertiesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetWorkflowRunPropertiesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetWorkflowRunPropertiesError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetWorkflowRunPropertiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10268,12 +11056,14 @@ impl fmt::Display for GetWorkflowRunPropertiesError {
             GetWorkflowRunPropertiesError::InternalService(ref cause) => write!(f, "{}", cause),
             GetWorkflowRunPropertiesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetWorkflowRunPropertiesError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetWorkflowRunPropertiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetWorkflowRunPropertiesError {}
 /// Errors returned by GetWorkflowRuns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetWorkflowRunsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10283,6 +11073,8 @@ pub enum GetWorkflowRunsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetWorkflowRunsError {
@@ -10290,19 +11082,28 @@ impl GetWorkflowRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        GetWorkflowRunsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetWorkflowRunsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetWorkflowRunsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        GetWorkflowRunsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetWorkflowRunsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10316,17 +11117,21 @@ impl fmt::Display for GetWorkflowRunsError {
             GetWorkflowRunsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetWorkflowRunsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetWorkflowRunsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            GetWorkflowRunsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetWorkflowRunsError {}
 /// Errors returned by ImportCatalogToGlue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportCatalogToGlueError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportCatalogToGlueError {
@@ -10334,15 +11139,19 @@ impl ImportCatalogToGlueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ImportCatalogToGlueError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ImportCatalogToGlueError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ImportCatalogToGlueError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportCatalogToGlueError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportCatalogToGlueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10354,15 +11163,19 @@ impl fmt::Display for ImportCatalogToGlueError {
         match *self {
             ImportCatalogToGlueError::InternalService(ref cause) => write!(f, "{}", cause),
             ImportCatalogToGlueError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            ImportCatalogToGlueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportCatalogToGlueError {}
 /// Errors returned by ListCrawlers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCrawlersError {
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCrawlersError {
@@ -10370,10 +11183,10 @@ impl ListCrawlersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListCrawlersError::OperationTimeout(err.msg))
+                    return RusotoError::service(ListCrawlersError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCrawlersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10384,12 +11197,14 @@ impl fmt::Display for ListCrawlersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListCrawlersError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            ListCrawlersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCrawlersError {}
 /// Errors returned by ListDevEndpoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDevEndpointsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10399,6 +11214,8 @@ pub enum ListDevEndpointsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDevEndpointsError {
@@ -10406,19 +11223,28 @@ impl ListDevEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListDevEndpointsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        ListDevEndpointsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListDevEndpointsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListDevEndpointsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDevEndpointsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListDevEndpointsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListDevEndpointsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        ListDevEndpointsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDevEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10432,12 +11258,14 @@ impl fmt::Display for ListDevEndpointsError {
             ListDevEndpointsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListDevEndpointsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListDevEndpointsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            ListDevEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDevEndpointsError {}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10447,6 +11275,8 @@ pub enum ListJobsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsError {
@@ -10454,19 +11284,19 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::EntityNotFound(err.msg))
+                    return RusotoError::service(ListJobsError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListJobsError::InternalService(err.msg))
+                    return RusotoError::service(ListJobsError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListJobsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListJobsError::OperationTimeout(err.msg))
+                    return RusotoError::service(ListJobsError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10480,12 +11310,14 @@ impl fmt::Display for ListJobsError {
             ListJobsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListJobsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListJobsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            ListJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsError {}
 /// Errors returned by ListTriggers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTriggersError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10495,6 +11327,8 @@ pub enum ListTriggersError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTriggersError {
@@ -10502,19 +11336,19 @@ impl ListTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListTriggersError::EntityNotFound(err.msg))
+                    return RusotoError::service(ListTriggersError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTriggersError::InternalService(err.msg))
+                    return RusotoError::service(ListTriggersError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTriggersError::InvalidInput(err.msg))
+                    return RusotoError::service(ListTriggersError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListTriggersError::OperationTimeout(err.msg))
+                    return RusotoError::service(ListTriggersError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTriggersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10528,12 +11362,14 @@ impl fmt::Display for ListTriggersError {
             ListTriggersError::InternalService(ref cause) => write!(f, "{}", cause),
             ListTriggersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListTriggersError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            ListTriggersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTriggersError {}
 /// Errors returned by ListWorkflows
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListWorkflowsError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -10541,6 +11377,8 @@ pub enum ListWorkflowsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListWorkflowsError {
@@ -10548,16 +11386,19 @@ impl ListWorkflowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListWorkflowsError::InternalService(err.msg))
+                    return RusotoError::service(ListWorkflowsError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListWorkflowsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListWorkflowsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListWorkflowsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        ListWorkflowsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListWorkflowsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10570,12 +11411,14 @@ impl fmt::Display for ListWorkflowsError {
             ListWorkflowsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListWorkflowsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListWorkflowsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            ListWorkflowsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListWorkflowsError {}
 /// Errors returned by PutDataCatalogEncryptionSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDataCatalogEncryptionSettingsError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -10583,6 +11426,8 @@ pub enum PutDataCatalogEncryptionSettingsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDataCatalogEncryptionSettingsError {
@@ -10592,22 +11437,30 @@ impl PutDataCatalogEncryptionSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDataCatalogEncryptionSettingsError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDataCatalogEncryptionSettingsError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDataCatalogEncryptionSettingsError::OperationTimeout(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutDataCatalogEncryptionSettingsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10626,12 +11479,16 @@ impl fmt::Display for PutDataCatalogEncryptionSettingsError {
             PutDataCatalogEncryptionSettingsError::OperationTimeout(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutDataCatalogEncryptionSettingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutDataCatalogEncryptionSettingsError {}
 /// Errors returned by PutResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutResourcePolicyError {
     /// <p>A specified condition was not satisfied.</p>
     ConditionCheckFailure(String),
@@ -10643,6 +11500,8 @@ pub enum PutResourcePolicyError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutResourcePolicyError {
@@ -10650,24 +11509,37 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionCheckFailureException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ConditionCheckFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourcePolicyError::ConditionCheckFailure(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(PutResourcePolicyError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10682,12 +11554,14 @@ impl fmt::Display for PutResourcePolicyError {
             PutResourcePolicyError::InternalService(ref cause) => write!(f, "{}", cause),
             PutResourcePolicyError::InvalidInput(ref cause) => write!(f, "{}", cause),
             PutResourcePolicyError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            PutResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutResourcePolicyError {}
 /// Errors returned by PutWorkflowRunProperties
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutWorkflowRunPropertiesError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -10703,6 +11577,8 @@ pub enum PutWorkflowRunPropertiesError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutWorkflowRunPropertiesError {
@@ -10710,42 +11586,49 @@ impl PutWorkflowRunPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutWorkflowRunPropertiesError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutWorkflowRunPropertiesError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutWorkflowRunPropertiesError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutWorkflowRunPropertiesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutWorkflowRunPropertiesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutWorkflowRunPropertiesError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutWorkflowRunPropertiesError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutWorkflowRunPropertiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10766,12 +11649,14 @@ impl fmt::Display for PutWorkflowRunPropertiesError {
             PutWorkflowRunPropertiesError::ResourceNumberLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutWorkflowRunPropertiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutWorkflowRunPropertiesError {}
 /// Errors returned by ResetJobBookmark
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetJobBookmarkError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10781,6 +11666,8 @@ pub enum ResetJobBookmarkError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetJobBookmarkError {
@@ -10788,19 +11675,28 @@ impl ResetJobBookmarkError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        ResetJobBookmarkError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ResetJobBookmarkError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::InvalidInput(err.msg))
+                    return RusotoError::service(ResetJobBookmarkError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        ResetJobBookmarkError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResetJobBookmarkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10814,12 +11710,14 @@ impl fmt::Display for ResetJobBookmarkError {
             ResetJobBookmarkError::InternalService(ref cause) => write!(f, "{}", cause),
             ResetJobBookmarkError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ResetJobBookmarkError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            ResetJobBookmarkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetJobBookmarkError {}
 /// Errors returned by SearchTables
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchTablesError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -10827,6 +11725,8 @@ pub enum SearchTablesError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchTablesError {
@@ -10834,16 +11734,16 @@ impl SearchTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(SearchTablesError::InternalService(err.msg))
+                    return RusotoError::service(SearchTablesError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SearchTablesError::InvalidInput(err.msg))
+                    return RusotoError::service(SearchTablesError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(SearchTablesError::OperationTimeout(err.msg))
+                    return RusotoError::service(SearchTablesError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchTablesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10856,12 +11756,14 @@ impl fmt::Display for SearchTablesError {
             SearchTablesError::InternalService(ref cause) => write!(f, "{}", cause),
             SearchTablesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             SearchTablesError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            SearchTablesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchTablesError {}
 /// Errors returned by StartCrawler
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartCrawlerError {
     /// <p>The operation cannot be performed because the crawler is already running.</p>
     CrawlerRunning(String),
@@ -10869,6 +11771,8 @@ pub enum StartCrawlerError {
     EntityNotFound(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartCrawlerError {
@@ -10876,16 +11780,16 @@ impl StartCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerRunningException" => {
-                    return RusotoError::Service(StartCrawlerError::CrawlerRunning(err.msg))
+                    return RusotoError::service(StartCrawlerError::CrawlerRunning(err.msg), &res)
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(StartCrawlerError::EntityNotFound(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(StartCrawlerError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartCrawlerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10898,12 +11802,14 @@ impl fmt::Display for StartCrawlerError {
             StartCrawlerError::CrawlerRunning(ref cause) => write!(f, "{}", cause),
             StartCrawlerError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             StartCrawlerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            StartCrawlerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartCrawlerError {}
 /// Errors returned by StartCrawlerSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartCrawlerScheduleError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10915,6 +11821,8 @@ pub enum StartCrawlerScheduleError {
     SchedulerRunning(String),
     /// <p>The specified scheduler is transitioning.</p>
     SchedulerTransitioning(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartCrawlerScheduleError {
@@ -10922,28 +11830,37 @@ impl StartCrawlerScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        StartCrawlerScheduleError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "NoScheduleException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::NoSchedule(err.msg))
+                    return RusotoError::service(
+                        StartCrawlerScheduleError::NoSchedule(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartCrawlerScheduleError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "SchedulerRunningException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::SchedulerRunning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartCrawlerScheduleError::SchedulerRunning(err.msg),
+                        &res,
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::SchedulerTransitioning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartCrawlerScheduleError::SchedulerTransitioning(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartCrawlerScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10958,12 +11875,14 @@ impl fmt::Display for StartCrawlerScheduleError {
             StartCrawlerScheduleError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             StartCrawlerScheduleError::SchedulerRunning(ref cause) => write!(f, "{}", cause),
             StartCrawlerScheduleError::SchedulerTransitioning(ref cause) => write!(f, "{}", cause),
+            StartCrawlerScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartCrawlerScheduleError {}
 /// Errors returned by StartExportLabelsTaskRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartExportLabelsTaskRunError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -10973,6 +11892,8 @@ pub enum StartExportLabelsTaskRunError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartExportLabelsTaskRunError {
@@ -10980,27 +11901,31 @@ impl StartExportLabelsTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExportLabelsTaskRunError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExportLabelsTaskRunError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExportLabelsTaskRunError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExportLabelsTaskRunError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartExportLabelsTaskRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11014,12 +11939,14 @@ impl fmt::Display for StartExportLabelsTaskRunError {
             StartExportLabelsTaskRunError::InternalService(ref cause) => write!(f, "{}", cause),
             StartExportLabelsTaskRunError::InvalidInput(ref cause) => write!(f, "{}", cause),
             StartExportLabelsTaskRunError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            StartExportLabelsTaskRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartExportLabelsTaskRunError {}
 /// Errors returned by StartImportLabelsTaskRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartImportLabelsTaskRunError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -11031,6 +11958,8 @@ pub enum StartImportLabelsTaskRunError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartImportLabelsTaskRunError {
@@ -11038,32 +11967,37 @@ impl StartImportLabelsTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImportLabelsTaskRunError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImportLabelsTaskRunError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImportLabelsTaskRunError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImportLabelsTaskRunError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartImportLabelsTaskRunError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartImportLabelsTaskRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11080,12 +12014,14 @@ impl fmt::Display for StartImportLabelsTaskRunError {
             StartImportLabelsTaskRunError::ResourceNumberLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartImportLabelsTaskRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartImportLabelsTaskRunError {}
 /// Errors returned by StartJobRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartJobRunError {
     /// <p>Too many jobs are being run concurrently.</p>
     ConcurrentRunsExceeded(String),
@@ -11099,6 +12035,8 @@ pub enum StartJobRunError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartJobRunError {
@@ -11106,27 +12044,31 @@ impl StartJobRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(StartJobRunError::ConcurrentRunsExceeded(err.msg))
+                    return RusotoError::service(
+                        StartJobRunError::ConcurrentRunsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartJobRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(StartJobRunError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartJobRunError::InternalService(err.msg))
+                    return RusotoError::service(StartJobRunError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartJobRunError::InvalidInput(err.msg))
+                    return RusotoError::service(StartJobRunError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartJobRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(StartJobRunError::OperationTimeout(err.msg), &res)
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(StartJobRunError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartJobRunError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartJobRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11142,12 +12084,14 @@ impl fmt::Display for StartJobRunError {
             StartJobRunError::InvalidInput(ref cause) => write!(f, "{}", cause),
             StartJobRunError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             StartJobRunError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            StartJobRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartJobRunError {}
 /// Errors returned by StartMLEvaluationTaskRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartMLEvaluationTaskRunError {
     /// <p>Too many jobs are being run concurrently.</p>
     ConcurrentRunsExceeded(String),
@@ -11161,6 +12105,8 @@ pub enum StartMLEvaluationTaskRunError {
     MLTransformNotReady(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartMLEvaluationTaskRunError {
@@ -11168,37 +12114,43 @@ impl StartMLEvaluationTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartMLEvaluationTaskRunError::ConcurrentRunsExceeded(err.msg),
+                        &res,
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartMLEvaluationTaskRunError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartMLEvaluationTaskRunError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartMLEvaluationTaskRunError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "MLTransformNotReadyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartMLEvaluationTaskRunError::MLTransformNotReady(err.msg),
+                        &res,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartMLEvaluationTaskRunError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartMLEvaluationTaskRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11216,12 +12168,14 @@ impl fmt::Display for StartMLEvaluationTaskRunError {
             StartMLEvaluationTaskRunError::InvalidInput(ref cause) => write!(f, "{}", cause),
             StartMLEvaluationTaskRunError::MLTransformNotReady(ref cause) => write!(f, "{}", cause),
             StartMLEvaluationTaskRunError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            StartMLEvaluationTaskRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartMLEvaluationTaskRunError {}
 /// Errors returned by StartMLLabelingSetGenerationTaskRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartMLLabelingSetGenerationTaskRunError {
     /// <p>Too many jobs are being run concurrently.</p>
     ConcurrentRunsExceeded(String),
@@ -11233,6 +12187,8 @@ pub enum StartMLLabelingSetGenerationTaskRunError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartMLLabelingSetGenerationTaskRunError {
@@ -11242,32 +12198,42 @@ impl StartMLLabelingSetGenerationTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartMLLabelingSetGenerationTaskRunError::ConcurrentRunsExceeded(err.msg),
+                        &res,
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartMLLabelingSetGenerationTaskRunError::EntityNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartMLLabelingSetGenerationTaskRunError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartMLLabelingSetGenerationTaskRunError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartMLLabelingSetGenerationTaskRunError::OperationTimeout(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartMLLabelingSetGenerationTaskRunError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11292,12 +12258,16 @@ impl fmt::Display for StartMLLabelingSetGenerationTaskRunError {
             StartMLLabelingSetGenerationTaskRunError::OperationTimeout(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartMLLabelingSetGenerationTaskRunError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StartMLLabelingSetGenerationTaskRunError {}
 /// Errors returned by StartTrigger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartTriggerError {
     /// <p>Too many jobs are being run concurrently.</p>
     ConcurrentRunsExceeded(String),
@@ -11311,6 +12281,8 @@ pub enum StartTriggerError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartTriggerError {
@@ -11318,27 +12290,31 @@ impl StartTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(StartTriggerError::ConcurrentRunsExceeded(err.msg))
+                    return RusotoError::service(
+                        StartTriggerError::ConcurrentRunsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(StartTriggerError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartTriggerError::InternalService(err.msg))
+                    return RusotoError::service(StartTriggerError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(StartTriggerError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(StartTriggerError::OperationTimeout(err.msg), &res)
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(StartTriggerError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTriggerError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartTriggerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11354,12 +12330,14 @@ impl fmt::Display for StartTriggerError {
             StartTriggerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             StartTriggerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             StartTriggerError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            StartTriggerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartTriggerError {}
 /// Errors returned by StartWorkflowRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartWorkflowRunError {
     /// <p>Too many jobs are being run concurrently.</p>
     ConcurrentRunsExceeded(String),
@@ -11373,6 +12351,8 @@ pub enum StartWorkflowRunError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartWorkflowRunError {
@@ -11380,29 +12360,40 @@ impl StartWorkflowRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(StartWorkflowRunError::ConcurrentRunsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartWorkflowRunError::ConcurrentRunsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartWorkflowRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        StartWorkflowRunError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartWorkflowRunError::InternalService(err.msg))
+                    return RusotoError::service(
+                        StartWorkflowRunError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartWorkflowRunError::InvalidInput(err.msg))
+                    return RusotoError::service(StartWorkflowRunError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartWorkflowRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        StartWorkflowRunError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartWorkflowRunError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartWorkflowRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11418,12 +12409,14 @@ impl fmt::Display for StartWorkflowRunError {
             StartWorkflowRunError::InvalidInput(ref cause) => write!(f, "{}", cause),
             StartWorkflowRunError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             StartWorkflowRunError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            StartWorkflowRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartWorkflowRunError {}
 /// Errors returned by StopCrawler
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopCrawlerError {
     /// <p>The specified crawler is not running.</p>
     CrawlerNotRunning(String),
@@ -11433,6 +12426,8 @@ pub enum StopCrawlerError {
     EntityNotFound(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopCrawlerError {
@@ -11440,19 +12435,19 @@ impl StopCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerNotRunningException" => {
-                    return RusotoError::Service(StopCrawlerError::CrawlerNotRunning(err.msg))
+                    return RusotoError::service(StopCrawlerError::CrawlerNotRunning(err.msg), &res)
                 }
                 "CrawlerStoppingException" => {
-                    return RusotoError::Service(StopCrawlerError::CrawlerStopping(err.msg))
+                    return RusotoError::service(StopCrawlerError::CrawlerStopping(err.msg), &res)
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(StopCrawlerError::EntityNotFound(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(StopCrawlerError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopCrawlerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11466,12 +12461,14 @@ impl fmt::Display for StopCrawlerError {
             StopCrawlerError::CrawlerStopping(ref cause) => write!(f, "{}", cause),
             StopCrawlerError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             StopCrawlerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            StopCrawlerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopCrawlerError {}
 /// Errors returned by StopCrawlerSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopCrawlerScheduleError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -11481,6 +12478,8 @@ pub enum StopCrawlerScheduleError {
     SchedulerNotRunning(String),
     /// <p>The specified scheduler is transitioning.</p>
     SchedulerTransitioning(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopCrawlerScheduleError {
@@ -11488,25 +12487,31 @@ impl StopCrawlerScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        StopCrawlerScheduleError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopCrawlerScheduleError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "SchedulerNotRunningException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::SchedulerNotRunning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopCrawlerScheduleError::SchedulerNotRunning(err.msg),
+                        &res,
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::SchedulerTransitioning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopCrawlerScheduleError::SchedulerTransitioning(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopCrawlerScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11520,12 +12525,14 @@ impl fmt::Display for StopCrawlerScheduleError {
             StopCrawlerScheduleError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             StopCrawlerScheduleError::SchedulerNotRunning(ref cause) => write!(f, "{}", cause),
             StopCrawlerScheduleError::SchedulerTransitioning(ref cause) => write!(f, "{}", cause),
+            StopCrawlerScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopCrawlerScheduleError {}
 /// Errors returned by StopTrigger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopTriggerError {
     /// <p>Two processes are trying to modify a resource simultaneously.</p>
     ConcurrentModification(String),
@@ -11537,6 +12544,8 @@ pub enum StopTriggerError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopTriggerError {
@@ -11544,22 +12553,25 @@ impl StopTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopTriggerError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        StopTriggerError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(StopTriggerError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopTriggerError::InternalService(err.msg))
+                    return RusotoError::service(StopTriggerError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(StopTriggerError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(StopTriggerError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopTriggerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11574,12 +12586,14 @@ impl fmt::Display for StopTriggerError {
             StopTriggerError::InternalService(ref cause) => write!(f, "{}", cause),
             StopTriggerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             StopTriggerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            StopTriggerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopTriggerError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -11589,6 +12603,8 @@ pub enum TagResourceError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -11596,19 +12612,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service(TagResourceError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(TagResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(TagResourceError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11622,12 +12638,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InternalService(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             TagResourceError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -11637,6 +12655,8 @@ pub enum UntagResourceError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -11644,19 +12664,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UntagResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11670,12 +12693,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InternalService(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UntagResourceError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateClassifier
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateClassifierError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -11685,6 +12710,8 @@ pub enum UpdateClassifierError {
     OperationTimeout(String),
     /// <p>There was a version conflict.</p>
     VersionMismatch(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateClassifierError {
@@ -11692,19 +12719,28 @@ impl UpdateClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateClassifierError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateClassifierError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateClassifierError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateClassifierError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateClassifierError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateClassifierError::VersionMismatch(err.msg))
+                    return RusotoError::service(
+                        UpdateClassifierError::VersionMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateClassifierError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11718,12 +12754,14 @@ impl fmt::Display for UpdateClassifierError {
             UpdateClassifierError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateClassifierError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             UpdateClassifierError::VersionMismatch(ref cause) => write!(f, "{}", cause),
+            UpdateClassifierError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateClassifierError {}
 /// Errors returned by UpdateConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConnectionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -11733,6 +12771,8 @@ pub enum UpdateConnectionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConnectionError {
@@ -11740,19 +12780,28 @@ impl UpdateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateConnectionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateConnectionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateConnectionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        UpdateConnectionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateConnectionError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateConnectionError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateConnectionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11766,12 +12815,14 @@ impl fmt::Display for UpdateConnectionError {
             UpdateConnectionError::GlueEncryption(ref cause) => write!(f, "{}", cause),
             UpdateConnectionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateConnectionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdateConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConnectionError {}
 /// Errors returned by UpdateCrawler
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCrawlerError {
     /// <p>The operation cannot be performed because the crawler is already running.</p>
     CrawlerRunning(String),
@@ -11783,6 +12834,8 @@ pub enum UpdateCrawlerError {
     OperationTimeout(String),
     /// <p>There was a version conflict.</p>
     VersionMismatch(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCrawlerError {
@@ -11790,22 +12843,25 @@ impl UpdateCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerRunningException" => {
-                    return RusotoError::Service(UpdateCrawlerError::CrawlerRunning(err.msg))
+                    return RusotoError::service(UpdateCrawlerError::CrawlerRunning(err.msg), &res)
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(UpdateCrawlerError::EntityNotFound(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateCrawlerError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateCrawlerError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateCrawlerError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateCrawlerError::VersionMismatch(err.msg))
+                    return RusotoError::service(UpdateCrawlerError::VersionMismatch(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateCrawlerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11820,12 +12876,14 @@ impl fmt::Display for UpdateCrawlerError {
             UpdateCrawlerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateCrawlerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             UpdateCrawlerError::VersionMismatch(ref cause) => write!(f, "{}", cause),
+            UpdateCrawlerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateCrawlerError {}
 /// Errors returned by UpdateCrawlerSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCrawlerScheduleError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -11837,6 +12895,8 @@ pub enum UpdateCrawlerScheduleError {
     SchedulerTransitioning(String),
     /// <p>There was a version conflict.</p>
     VersionMismatch(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCrawlerScheduleError {
@@ -11844,30 +12904,37 @@ impl UpdateCrawlerScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCrawlerScheduleError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateCrawlerScheduleError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCrawlerScheduleError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCrawlerScheduleError::SchedulerTransitioning(err.msg),
+                        &res,
                     )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::VersionMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCrawlerScheduleError::VersionMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateCrawlerScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11882,12 +12949,14 @@ impl fmt::Display for UpdateCrawlerScheduleError {
             UpdateCrawlerScheduleError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             UpdateCrawlerScheduleError::SchedulerTransitioning(ref cause) => write!(f, "{}", cause),
             UpdateCrawlerScheduleError::VersionMismatch(ref cause) => write!(f, "{}", cause),
+            UpdateCrawlerScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateCrawlerScheduleError {}
 /// Errors returned by UpdateDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDatabaseError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -11899,6 +12968,8 @@ pub enum UpdateDatabaseError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDatabaseError {
@@ -11906,22 +12977,28 @@ impl UpdateDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateDatabaseError::EntityNotFound(err.msg))
+                    return RusotoError::service(UpdateDatabaseError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateDatabaseError::GlueEncryption(err.msg))
+                    return RusotoError::service(UpdateDatabaseError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateDatabaseError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdateDatabaseError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateDatabaseError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateDatabaseError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11936,12 +13013,14 @@ impl fmt::Display for UpdateDatabaseError {
             UpdateDatabaseError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateDatabaseError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateDatabaseError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdateDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDatabaseError {}
 /// Errors returned by UpdateDevEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDevEndpointError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -11951,6 +13030,8 @@ pub enum UpdateDevEndpointError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDevEndpointError {
@@ -11958,19 +13039,31 @@ impl UpdateDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDevEndpointError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdateDevEndpointError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateDevEndpointError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateDevEndpointError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDevEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11984,12 +13077,14 @@ impl fmt::Display for UpdateDevEndpointError {
             UpdateDevEndpointError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateDevEndpointError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateDevEndpointError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdateDevEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDevEndpointError {}
 /// Errors returned by UpdateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateJobError {
     /// <p>Two processes are trying to modify a resource simultaneously.</p>
     ConcurrentModification(String),
@@ -12001,6 +13096,8 @@ pub enum UpdateJobError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateJobError {
@@ -12008,22 +13105,25 @@ impl UpdateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateJobError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        UpdateJobError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateJobError::EntityNotFound(err.msg))
+                    return RusotoError::service(UpdateJobError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateJobError::InternalService(err.msg))
+                    return RusotoError::service(UpdateJobError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateJobError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateJobError::OperationTimeout(err.msg))
+                    return RusotoError::service(UpdateJobError::OperationTimeout(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12038,12 +13138,14 @@ impl fmt::Display for UpdateJobError {
             UpdateJobError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateJobError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateJobError {}
 /// Errors returned by UpdateMLTransform
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMLTransformError {
     /// <p>Access to a resource was denied.</p>
     AccessDenied(String),
@@ -12055,6 +13157,8 @@ pub enum UpdateMLTransformError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMLTransformError {
@@ -12062,22 +13166,37 @@ impl UpdateMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateMLTransformError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateMLTransformError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateMLTransformError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateMLTransformError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateMLTransformError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdateMLTransformError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateMLTransformError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateMLTransformError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMLTransformError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12092,12 +13211,14 @@ impl fmt::Display for UpdateMLTransformError {
             UpdateMLTransformError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateMLTransformError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateMLTransformError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdateMLTransformError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMLTransformError {}
 /// Errors returned by UpdatePartition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePartitionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -12109,6 +13230,8 @@ pub enum UpdatePartitionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePartitionError {
@@ -12116,22 +13239,34 @@ impl UpdatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdatePartitionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        UpdatePartitionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePartitionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdatePartitionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdatePartitionError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdatePartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdatePartitionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePartitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12146,12 +13281,14 @@ impl fmt::Display for UpdatePartitionError {
             UpdatePartitionError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdatePartitionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdatePartitionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdatePartitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePartitionError {}
 /// Errors returned by UpdateTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTableError {
     /// <p>Two processes are trying to modify a resource simultaneously.</p>
     ConcurrentModification(String),
@@ -12167,6 +13304,8 @@ pub enum UpdateTableError {
     OperationTimeout(String),
     /// <p>A resource numerical limit was exceeded.</p>
     ResourceNumberLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTableError {
@@ -12174,30 +13313,34 @@ impl UpdateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTableError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        UpdateTableError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(UpdateTableError::EntityNotFound(err.msg), &res)
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateTableError::GlueEncryption(err.msg))
+                    return RusotoError::service(UpdateTableError::GlueEncryption(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTableError::InternalService(err.msg))
+                    return RusotoError::service(UpdateTableError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateTableError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateTableError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(UpdateTableError::OperationTimeout(err.msg), &res)
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(UpdateTableError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTableError::ResourceNumberLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12214,12 +13357,14 @@ impl fmt::Display for UpdateTableError {
             UpdateTableError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateTableError::OperationTimeout(ref cause) => write!(f, "{}", cause),
             UpdateTableError::ResourceNumberLimitExceeded(ref cause) => write!(f, "{}", cause),
+            UpdateTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTableError {}
 /// Errors returned by UpdateTrigger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTriggerError {
     /// <p>Two processes are trying to modify a resource simultaneously.</p>
     ConcurrentModification(String),
@@ -12231,6 +13376,8 @@ pub enum UpdateTriggerError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTriggerError {
@@ -12238,24 +13385,28 @@ impl UpdateTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTriggerError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTriggerError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(UpdateTriggerError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTriggerError::InternalService(err.msg))
+                    return RusotoError::service(UpdateTriggerError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateTriggerError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateTriggerError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTriggerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12270,12 +13421,14 @@ impl fmt::Display for UpdateTriggerError {
             UpdateTriggerError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateTriggerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateTriggerError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdateTriggerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTriggerError {}
 /// Errors returned by UpdateUserDefinedFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserDefinedFunctionError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -12287,6 +13440,8 @@ pub enum UpdateUserDefinedFunctionError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserDefinedFunctionError {
@@ -12294,32 +13449,39 @@ impl UpdateUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserDefinedFunctionError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::GlueEncryption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserDefinedFunctionError::GlueEncryption(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserDefinedFunctionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserDefinedFunctionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserDefinedFunctionError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateUserDefinedFunctionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -12334,12 +13496,14 @@ impl fmt::Display for UpdateUserDefinedFunctionError {
             UpdateUserDefinedFunctionError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateUserDefinedFunctionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateUserDefinedFunctionError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdateUserDefinedFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserDefinedFunctionError {}
 /// Errors returned by UpdateWorkflow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateWorkflowError {
     /// <p>Two processes are trying to modify a resource simultaneously.</p>
     ConcurrentModification(String),
@@ -12351,6 +13515,8 @@ pub enum UpdateWorkflowError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateWorkflowError {
@@ -12358,24 +13524,31 @@ impl UpdateWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateWorkflowError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateWorkflowError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateWorkflowError::EntityNotFound(err.msg))
+                    return RusotoError::service(UpdateWorkflowError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateWorkflowError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdateWorkflowError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateWorkflowError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateWorkflowError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateWorkflowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12390,6 +13563,7 @@ impl fmt::Display for UpdateWorkflowError {
             UpdateWorkflowError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateWorkflowError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateWorkflowError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdateWorkflowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -13154,6 +14328,7 @@ impl GlueClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> GlueClient {
         GlueClient {
             client: Client::shared(),
@@ -13179,2831 +14354,5559 @@ impl GlueClient {
     pub fn new_with_client(client: Client, region: region::Region) -> GlueClient {
         GlueClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<GlueClient> {
+        rusoto_core::ClientBuilder::new(GlueClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> GlueClient {
+        GlueClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Glue for GlueClient {
-    /// <p>Creates one or more partitions in a batch operation.</p>
-    async fn batch_create_partition(
+impl GlueClient {
+    /// Calls `batch_create_partition`, also returning the metadata of the response.
+    pub async fn batch_create_partition_with_metadata(
         &self,
         input: BatchCreatePartitionRequest,
-    ) -> Result<BatchCreatePartitionResponse, RusotoError<BatchCreatePartitionError>> {
+    ) -> Result<
+        (BatchCreatePartitionResponse, ResponseMetadata),
+        RusotoError<BatchCreatePartitionError>,
+    > {
+        let request = self.build_batch_create_partition_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchCreatePartitionResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchCreatePartitionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_create_partition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_create_partition(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchCreatePartitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_create_partition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_create_partition_request(
+        &self,
+        input: BatchCreatePartitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchCreatePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchCreatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_delete_connection`, also returning the metadata of the response.
+    pub async fn batch_delete_connection_with_metadata(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> Result<
+        (BatchDeleteConnectionResponse, ResponseMetadata),
+        RusotoError<BatchDeleteConnectionError>,
+    > {
+        let request = self.build_batch_delete_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchCreatePartitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDeleteConnectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchCreatePartitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDeleteConnectionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a list of connection definitions from the Data Catalog.</p>
-    async fn batch_delete_connection(
+    /// Presigns the request `batch_delete_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDeleteConnectionRequest,
-    ) -> Result<BatchDeleteConnectionResponse, RusotoError<BatchDeleteConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_connection_request(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_delete_partition`, also returning the metadata of the response.
+    pub async fn batch_delete_partition_with_metadata(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> Result<
+        (BatchDeletePartitionResponse, ResponseMetadata),
+        RusotoError<BatchDeletePartitionError>,
+    > {
+        let request = self.build_batch_delete_partition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDeleteConnectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDeletePartitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDeleteConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDeletePartitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes one or more partitions in a batch operation.</p>
-    async fn batch_delete_partition(
+    /// Presigns the request `batch_delete_partition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_partition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDeletePartitionRequest,
-    ) -> Result<BatchDeletePartitionResponse, RusotoError<BatchDeletePartitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_partition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_partition_request(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeletePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchDeletePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_delete_table`, also returning the metadata of the response.
+    pub async fn batch_delete_table_with_metadata(
+        &self,
+        input: BatchDeleteTableRequest,
+    ) -> Result<(BatchDeleteTableResponse, ResponseMetadata), RusotoError<BatchDeleteTableError>>
+    {
+        let request = self.build_batch_delete_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDeletePartitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDeleteTableResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDeletePartitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDeleteTableError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes multiple tables at once.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>BatchDeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
-    async fn batch_delete_table(
+    /// Presigns the request `batch_delete_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDeleteTableRequest,
-    ) -> Result<BatchDeleteTableResponse, RusotoError<BatchDeleteTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_table_request(&self, input: BatchDeleteTableRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeleteTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_delete_table_version`, also returning the metadata of the response.
+    pub async fn batch_delete_table_version_with_metadata(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> Result<
+        (BatchDeleteTableVersionResponse, ResponseMetadata),
+        RusotoError<BatchDeleteTableVersionError>,
+    > {
+        let request = self.build_batch_delete_table_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDeleteTableResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDeleteTableVersionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDeleteTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDeleteTableVersionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified batch of versions of a table.</p>
-    async fn batch_delete_table_version(
+    /// Presigns the request `batch_delete_table_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_table_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDeleteTableVersionRequest,
-    ) -> Result<BatchDeleteTableVersionResponse, RusotoError<BatchDeleteTableVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_table_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_table_version_request(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchDeleteTableVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteTableVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_crawlers`, also returning the metadata of the response.
+    pub async fn batch_get_crawlers_with_metadata(
+        &self,
+        input: BatchGetCrawlersRequest,
+    ) -> Result<(BatchGetCrawlersResponse, ResponseMetadata), RusotoError<BatchGetCrawlersError>>
+    {
+        let request = self.build_batch_get_crawlers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDeleteTableVersionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetCrawlersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDeleteTableVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetCrawlersError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of resource metadata for a given list of crawler names. After calling the <code>ListCrawlers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
-    async fn batch_get_crawlers(
+    /// Presigns the request `batch_get_crawlers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_crawlers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetCrawlersRequest,
-    ) -> Result<BatchGetCrawlersResponse, RusotoError<BatchGetCrawlersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_crawlers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_crawlers_request(&self, input: BatchGetCrawlersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetCrawlers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetCrawlers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_dev_endpoints`, also returning the metadata of the response.
+    pub async fn batch_get_dev_endpoints_with_metadata(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> Result<
+        (BatchGetDevEndpointsResponse, ResponseMetadata),
+        RusotoError<BatchGetDevEndpointsError>,
+    > {
+        let request = self.build_batch_get_dev_endpoints_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetCrawlersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetDevEndpointsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetCrawlersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetDevEndpointsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of resource metadata for a given list of development endpoint names. After calling the <code>ListDevEndpoints</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
-    async fn batch_get_dev_endpoints(
+    /// Presigns the request `batch_get_dev_endpoints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_dev_endpoints(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetDevEndpointsRequest,
-    ) -> Result<BatchGetDevEndpointsResponse, RusotoError<BatchGetDevEndpointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_dev_endpoints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_dev_endpoints_request(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetDevEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetDevEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_jobs`, also returning the metadata of the response.
+    pub async fn batch_get_jobs_with_metadata(
+        &self,
+        input: BatchGetJobsRequest,
+    ) -> Result<(BatchGetJobsResponse, ResponseMetadata), RusotoError<BatchGetJobsError>> {
+        let request = self.build_batch_get_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetDevEndpointsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetDevEndpointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of resource metadata for a given list of job names. After calling the <code>ListJobs</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags. </p>
-    async fn batch_get_jobs(
+    /// Presigns the request `batch_get_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetJobsRequest,
-    ) -> Result<BatchGetJobsResponse, RusotoError<BatchGetJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_jobs_request(&self, input: BatchGetJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_partition`, also returning the metadata of the response.
+    pub async fn batch_get_partition_with_metadata(
+        &self,
+        input: BatchGetPartitionRequest,
+    ) -> Result<(BatchGetPartitionResponse, ResponseMetadata), RusotoError<BatchGetPartitionError>>
+    {
+        let request = self.build_batch_get_partition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchGetJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetPartitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetPartitionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves partitions in a batch request.</p>
-    async fn batch_get_partition(
+    /// Presigns the request `batch_get_partition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_partition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetPartitionRequest,
-    ) -> Result<BatchGetPartitionResponse, RusotoError<BatchGetPartitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_partition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_partition_request(&self, input: BatchGetPartitionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetPartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_triggers`, also returning the metadata of the response.
+    pub async fn batch_get_triggers_with_metadata(
+        &self,
+        input: BatchGetTriggersRequest,
+    ) -> Result<(BatchGetTriggersResponse, ResponseMetadata), RusotoError<BatchGetTriggersError>>
+    {
+        let request = self.build_batch_get_triggers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetPartitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetTriggersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetPartitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetTriggersError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of resource metadata for a given list of trigger names. After calling the <code>ListTriggers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
-    async fn batch_get_triggers(
+    /// Presigns the request `batch_get_triggers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_triggers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetTriggersRequest,
-    ) -> Result<BatchGetTriggersResponse, RusotoError<BatchGetTriggersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_triggers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_triggers_request(&self, input: BatchGetTriggersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_workflows`, also returning the metadata of the response.
+    pub async fn batch_get_workflows_with_metadata(
+        &self,
+        input: BatchGetWorkflowsRequest,
+    ) -> Result<(BatchGetWorkflowsResponse, ResponseMetadata), RusotoError<BatchGetWorkflowsError>>
+    {
+        let request = self.build_batch_get_workflows_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetTriggersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetWorkflowsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetTriggersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetWorkflowsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of resource metadata for a given list of workflow names. After calling the <code>ListWorkflows</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
-    async fn batch_get_workflows(
+    /// Presigns the request `batch_get_workflows` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_workflows(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetWorkflowsRequest,
-    ) -> Result<BatchGetWorkflowsResponse, RusotoError<BatchGetWorkflowsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_workflows_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_workflows_request(&self, input: BatchGetWorkflowsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchGetWorkflows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchGetWorkflows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_stop_job_run`, also returning the metadata of the response.
+    pub async fn batch_stop_job_run_with_metadata(
+        &self,
+        input: BatchStopJobRunRequest,
+    ) -> Result<(BatchStopJobRunResponse, ResponseMetadata), RusotoError<GlueBatchStopJobRunError>>
+    {
+        let request = self.build_batch_stop_job_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetWorkflowsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchStopJobRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetWorkflowsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GlueBatchStopJobRunError::from_response(response))
         }
     }
 
-    /// <p>Stops one or more job runs for a specified job definition.</p>
-    async fn batch_stop_job_run(
+    /// Presigns the request `batch_stop_job_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_stop_job_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchStopJobRunRequest,
-    ) -> Result<BatchStopJobRunResponse, RusotoError<GlueBatchStopJobRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_stop_job_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_stop_job_run_request(&self, input: BatchStopJobRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("BatchStopJobRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.BatchStopJobRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_ml_task_run`, also returning the metadata of the response.
+    pub async fn cancel_ml_task_run_with_metadata(
+        &self,
+        input: CancelMLTaskRunRequest,
+    ) -> Result<(CancelMLTaskRunResponse, ResponseMetadata), RusotoError<CancelMLTaskRunError>>
+    {
+        let request = self.build_cancel_ml_task_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchStopJobRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelMLTaskRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GlueBatchStopJobRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelMLTaskRunError::from_response(response))
         }
     }
 
-    /// <p>Cancels (stops) a task run. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can cancel a machine learning task run at any time by calling <code>CancelMLTaskRun</code> with a task run's parent transform's <code>TransformID</code> and the task run's <code>TaskRunId</code>. </p>
-    async fn cancel_ml_task_run(
+    /// Presigns the request `cancel_ml_task_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_ml_task_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CancelMLTaskRunRequest,
-    ) -> Result<CancelMLTaskRunResponse, RusotoError<CancelMLTaskRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_ml_task_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_ml_task_run_request(&self, input: CancelMLTaskRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CancelMLTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CancelMLTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_classifier`, also returning the metadata of the response.
+    pub async fn create_classifier_with_metadata(
+        &self,
+        input: CreateClassifierRequest,
+    ) -> Result<(CreateClassifierResponse, ResponseMetadata), RusotoError<CreateClassifierError>>
+    {
+        let request = self.build_create_classifier_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CancelMLTaskRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateClassifierResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelMLTaskRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateClassifierError::from_response(response))
         }
     }
 
-    /// <p>Creates a classifier in the user's account. This can be a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field of the request is present.</p>
-    async fn create_classifier(
+    /// Presigns the request `create_classifier` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_classifier(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateClassifierRequest,
-    ) -> Result<CreateClassifierResponse, RusotoError<CreateClassifierError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_classifier_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_classifier_request(&self, input: CreateClassifierRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_connection`, also returning the metadata of the response.
+    pub async fn create_connection_with_metadata(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> Result<(CreateConnectionResponse, ResponseMetadata), RusotoError<CreateConnectionError>>
+    {
+        let request = self.build_create_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateClassifierResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateConnectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateClassifierError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateConnectionError::from_response(response))
         }
     }
 
-    /// <p>Creates a connection definition in the Data Catalog.</p>
-    async fn create_connection(
+    /// Presigns the request `create_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConnectionRequest,
-    ) -> Result<CreateConnectionResponse, RusotoError<CreateConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_connection_request(&self, input: CreateConnectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_crawler`, also returning the metadata of the response.
+    pub async fn create_crawler_with_metadata(
+        &self,
+        input: CreateCrawlerRequest,
+    ) -> Result<(CreateCrawlerResponse, ResponseMetadata), RusotoError<CreateCrawlerError>> {
+        let request = self.build_create_crawler_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateConnectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCrawlerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCrawlerError::from_response(response))
         }
     }
 
-    /// <p>Creates a new crawler with specified targets, role, configuration, and optional schedule. At least one crawl target must be specified, in the <code>s3Targets</code> field, the <code>jdbcTargets</code> field, or the <code>DynamoDBTargets</code> field.</p>
-    async fn create_crawler(
+    /// Presigns the request `create_crawler` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_crawler(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCrawlerRequest,
-    ) -> Result<CreateCrawlerResponse, RusotoError<CreateCrawlerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_crawler_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_crawler_request(&self, input: CreateCrawlerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_database`, also returning the metadata of the response.
+    pub async fn create_database_with_metadata(
+        &self,
+        input: CreateDatabaseRequest,
+    ) -> Result<(CreateDatabaseResponse, ResponseMetadata), RusotoError<CreateDatabaseError>> {
+        let request = self.build_create_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateCrawlerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDatabaseResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCrawlerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Creates a new database in a Data Catalog.</p>
-    async fn create_database(
+    /// Presigns the request `create_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDatabaseRequest,
-    ) -> Result<CreateDatabaseResponse, RusotoError<CreateDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_database_request(&self, input: CreateDatabaseRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_dev_endpoint`, also returning the metadata of the response.
+    pub async fn create_dev_endpoint_with_metadata(
+        &self,
+        input: CreateDevEndpointRequest,
+    ) -> Result<(CreateDevEndpointResponse, ResponseMetadata), RusotoError<CreateDevEndpointError>>
+    {
+        let request = self.build_create_dev_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDatabaseResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDevEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatabaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDevEndpointError::from_response(response))
         }
     }
 
-    /// <p>Creates a new development endpoint.</p>
-    async fn create_dev_endpoint(
+    /// Presigns the request `create_dev_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dev_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDevEndpointRequest,
-    ) -> Result<CreateDevEndpointResponse, RusotoError<CreateDevEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dev_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dev_endpoint_request(&self, input: CreateDevEndpointRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateDevEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_job`, also returning the metadata of the response.
+    pub async fn create_job_with_metadata(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<(CreateJobResponse, ResponseMetadata), RusotoError<CreateJobError>> {
+        let request = self.build_create_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDevEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDevEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateJobError::from_response(response))
         }
     }
 
-    /// <p>Creates a new job definition.</p>
-    async fn create_job(
+    /// Presigns the request `create_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateJobRequest,
-    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_job_request(&self, input: CreateJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_ml_transform`, also returning the metadata of the response.
+    pub async fn create_ml_transform_with_metadata(
+        &self,
+        input: CreateMLTransformRequest,
+    ) -> Result<(CreateMLTransformResponse, ResponseMetadata), RusotoError<CreateMLTransformError>>
+    {
+        let request = self.build_create_ml_transform_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateMLTransformResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateMLTransformError::from_response(response))
         }
     }
 
-    /// <p>Creates an AWS Glue machine learning transform. This operation creates the transform and all the necessary parameters to train it.</p> <p>Call this operation as the first step in the process of using a machine learning transform (such as the <code>FindMatches</code> transform) for deduplicating data. You can provide an optional <code>Description</code>, in addition to the parameters that you want to use for your algorithm.</p> <p>You must also specify certain parameters for the tasks that AWS Glue runs on your behalf as part of learning from your data and creating a high-quality machine learning transform. These parameters include <code>Role</code>, and optionally, <code>AllocatedCapacity</code>, <code>Timeout</code>, and <code>MaxRetries</code>. For more information, see <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-jobs-job.html">Jobs</a>.</p>
-    async fn create_ml_transform(
+    /// Presigns the request `create_ml_transform` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_ml_transform(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMLTransformRequest,
-    ) -> Result<CreateMLTransformResponse, RusotoError<CreateMLTransformError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_ml_transform_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_ml_transform_request(&self, input: CreateMLTransformRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateMLTransform");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_partition`, also returning the metadata of the response.
+    pub async fn create_partition_with_metadata(
+        &self,
+        input: CreatePartitionRequest,
+    ) -> Result<(CreatePartitionResponse, ResponseMetadata), RusotoError<CreatePartitionError>>
+    {
+        let request = self.build_create_partition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMLTransformResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePartitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMLTransformError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePartitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a new partition.</p>
-    async fn create_partition(
+    /// Presigns the request `create_partition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_partition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePartitionRequest,
-    ) -> Result<CreatePartitionResponse, RusotoError<CreatePartitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_partition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_partition_request(&self, input: CreatePartitionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreatePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_script`, also returning the metadata of the response.
+    pub async fn create_script_with_metadata(
+        &self,
+        input: CreateScriptRequest,
+    ) -> Result<(CreateScriptResponse, ResponseMetadata), RusotoError<CreateScriptError>> {
+        let request = self.build_create_script_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreatePartitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateScriptResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePartitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateScriptError::from_response(response))
         }
     }
 
-    /// <p>Transforms a directed acyclic graph (DAG) into code.</p>
-    async fn create_script(
+    /// Presigns the request `create_script` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_script(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateScriptRequest,
-    ) -> Result<CreateScriptResponse, RusotoError<CreateScriptError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_script_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_script_request(&self, input: CreateScriptRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_security_configuration`, also returning the metadata of the response.
+    pub async fn create_security_configuration_with_metadata(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> Result<
+        (CreateSecurityConfigurationResponse, ResponseMetadata),
+        RusotoError<CreateSecurityConfigurationError>,
+    > {
+        let request = self.build_create_security_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateScriptResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSecurityConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateScriptError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSecurityConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Creates a new security configuration. A security configuration is a set of security properties that can be used by AWS Glue. You can use a security configuration to encrypt data at rest. For information about using security configurations in AWS Glue, see <a href="https://docs.aws.amazon.com/glue/latest/dg/encryption-security-configuration.html">Encrypting Data Written by Crawlers, Jobs, and Development Endpoints</a>.</p>
-    async fn create_security_configuration(
+    /// Presigns the request `create_security_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_security_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSecurityConfigurationRequest,
-    ) -> Result<CreateSecurityConfigurationResponse, RusotoError<CreateSecurityConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_security_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_security_configuration_request(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateSecurityConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_table`, also returning the metadata of the response.
+    pub async fn create_table_with_metadata(
+        &self,
+        input: CreateTableRequest,
+    ) -> Result<(CreateTableResponse, ResponseMetadata), RusotoError<CreateTableError>> {
+        let request = self.build_create_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSecurityConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTableResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSecurityConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTableError::from_response(response))
         }
     }
 
-    /// <p>Creates a new table definition in the Data Catalog.</p>
-    async fn create_table(
+    /// Presigns the request `create_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTableRequest,
-    ) -> Result<CreateTableResponse, RusotoError<CreateTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_table_request(&self, input: CreateTableRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_trigger`, also returning the metadata of the response.
+    pub async fn create_trigger_with_metadata(
+        &self,
+        input: CreateTriggerRequest,
+    ) -> Result<(CreateTriggerResponse, ResponseMetadata), RusotoError<CreateTriggerError>> {
+        let request = self.build_create_trigger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTableResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTriggerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTriggerError::from_response(response))
         }
     }
 
-    /// <p>Creates a new trigger.</p>
-    async fn create_trigger(
+    /// Presigns the request `create_trigger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_trigger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTriggerRequest,
-    ) -> Result<CreateTriggerResponse, RusotoError<CreateTriggerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_trigger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_trigger_request(&self, input: CreateTriggerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user_defined_function`, also returning the metadata of the response.
+    pub async fn create_user_defined_function_with_metadata(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> Result<
+        (CreateUserDefinedFunctionResponse, ResponseMetadata),
+        RusotoError<CreateUserDefinedFunctionError>,
+    > {
+        let request = self.build_create_user_defined_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTriggerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserDefinedFunctionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTriggerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserDefinedFunctionError::from_response(response))
         }
     }
 
-    /// <p>Creates a new function definition in the Data Catalog.</p>
-    async fn create_user_defined_function(
+    /// Presigns the request `create_user_defined_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user_defined_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserDefinedFunctionRequest,
-    ) -> Result<CreateUserDefinedFunctionResponse, RusotoError<CreateUserDefinedFunctionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_defined_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_defined_function_request(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateUserDefinedFunction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_workflow`, also returning the metadata of the response.
+    pub async fn create_workflow_with_metadata(
+        &self,
+        input: CreateWorkflowRequest,
+    ) -> Result<(CreateWorkflowResponse, ResponseMetadata), RusotoError<CreateWorkflowError>> {
+        let request = self.build_create_workflow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUserDefinedFunctionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateWorkflowResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserDefinedFunctionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateWorkflowError::from_response(response))
         }
     }
 
-    /// <p>Creates a new workflow.</p>
-    async fn create_workflow(
+    /// Presigns the request `create_workflow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_workflow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateWorkflowRequest,
-    ) -> Result<CreateWorkflowResponse, RusotoError<CreateWorkflowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_workflow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_workflow_request(&self, input: CreateWorkflowRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("CreateWorkflow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.CreateWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_classifier`, also returning the metadata of the response.
+    pub async fn delete_classifier_with_metadata(
+        &self,
+        input: DeleteClassifierRequest,
+    ) -> Result<(DeleteClassifierResponse, ResponseMetadata), RusotoError<DeleteClassifierError>>
+    {
+        let request = self.build_delete_classifier_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateWorkflowResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteClassifierResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateWorkflowError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteClassifierError::from_response(response))
         }
     }
 
-    /// <p>Removes a classifier from the Data Catalog.</p>
-    async fn delete_classifier(
+    /// Presigns the request `delete_classifier` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_classifier(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteClassifierRequest,
-    ) -> Result<DeleteClassifierResponse, RusotoError<DeleteClassifierError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_classifier_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_classifier_request(&self, input: DeleteClassifierRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_connection`, also returning the metadata of the response.
+    pub async fn delete_connection_with_metadata(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<(DeleteConnectionResponse, ResponseMetadata), RusotoError<DeleteConnectionError>>
+    {
+        let request = self.build_delete_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteClassifierResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteConnectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteClassifierError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConnectionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a connection from the Data Catalog.</p>
-    async fn delete_connection(
+    /// Presigns the request `delete_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConnectionRequest,
-    ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_connection_request(&self, input: DeleteConnectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_crawler`, also returning the metadata of the response.
+    pub async fn delete_crawler_with_metadata(
+        &self,
+        input: DeleteCrawlerRequest,
+    ) -> Result<(DeleteCrawlerResponse, ResponseMetadata), RusotoError<DeleteCrawlerError>> {
+        let request = self.build_delete_crawler_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteConnectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteCrawlerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCrawlerError::from_response(response))
         }
     }
 
-    /// <p>Removes a specified crawler from the AWS Glue Data Catalog, unless the crawler state is <code>RUNNING</code>.</p>
-    async fn delete_crawler(
+    /// Presigns the request `delete_crawler` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_crawler(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCrawlerRequest,
-    ) -> Result<DeleteCrawlerResponse, RusotoError<DeleteCrawlerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_crawler_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_crawler_request(&self, input: DeleteCrawlerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_database`, also returning the metadata of the response.
+    pub async fn delete_database_with_metadata(
+        &self,
+        input: DeleteDatabaseRequest,
+    ) -> Result<(DeleteDatabaseResponse, ResponseMetadata), RusotoError<DeleteDatabaseError>> {
+        let request = self.build_delete_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteCrawlerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDatabaseResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCrawlerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDatabaseError::from_response(response))
         }
     }
 
-    /// <p><p>Removes a specified database from a Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the tables (and all table versions and partitions that might belong to the tables) and the user-defined functions in the deleted database. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteDatabase</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, <code>DeletePartition</code> or <code>BatchDeletePartition</code>, <code>DeleteUserDefinedFunction</code>, and <code>DeleteTable</code> or <code>BatchDeleteTable</code>, to delete any resources that belong to the database.</p> </note></p>
-    async fn delete_database(
+    /// Presigns the request `delete_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatabaseRequest,
-    ) -> Result<DeleteDatabaseResponse, RusotoError<DeleteDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_database_request(&self, input: DeleteDatabaseRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_dev_endpoint`, also returning the metadata of the response.
+    pub async fn delete_dev_endpoint_with_metadata(
+        &self,
+        input: DeleteDevEndpointRequest,
+    ) -> Result<(DeleteDevEndpointResponse, ResponseMetadata), RusotoError<DeleteDevEndpointError>>
+    {
+        let request = self.build_delete_dev_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteDatabaseResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDevEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatabaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDevEndpointError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified development endpoint.</p>
-    async fn delete_dev_endpoint(
+    /// Presigns the request `delete_dev_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dev_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDevEndpointRequest,
-    ) -> Result<DeleteDevEndpointResponse, RusotoError<DeleteDevEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dev_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dev_endpoint_request(&self, input: DeleteDevEndpointRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteDevEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_job`, also returning the metadata of the response.
+    pub async fn delete_job_with_metadata(
+        &self,
+        input: DeleteJobRequest,
+    ) -> Result<(DeleteJobResponse, ResponseMetadata), RusotoError<DeleteJobError>> {
+        let request = self.build_delete_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDevEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDevEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteJobError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified job definition. If the job definition is not found, no exception is thrown.</p>
-    async fn delete_job(
+    /// Presigns the request `delete_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteJobRequest,
-    ) -> Result<DeleteJobResponse, RusotoError<DeleteJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_job_request(&self, input: DeleteJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_ml_transform`, also returning the metadata of the response.
+    pub async fn delete_ml_transform_with_metadata(
+        &self,
+        input: DeleteMLTransformRequest,
+    ) -> Result<(DeleteMLTransformResponse, ResponseMetadata), RusotoError<DeleteMLTransformError>>
+    {
+        let request = self.build_delete_ml_transform_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteMLTransformResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteMLTransformError::from_response(response))
         }
     }
 
-    /// <p>Deletes an AWS Glue machine learning transform. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. If you no longer need a transform, you can delete it by calling <code>DeleteMLTransforms</code>. However, any AWS Glue jobs that still reference the deleted transform will no longer succeed.</p>
-    async fn delete_ml_transform(
+    /// Presigns the request `delete_ml_transform` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_ml_transform(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMLTransformRequest,
-    ) -> Result<DeleteMLTransformResponse, RusotoError<DeleteMLTransformError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_ml_transform_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_ml_transform_request(&self, input: DeleteMLTransformRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteMLTransform");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `delete_partition`, also returning the metadata of the response.
+    pub async fn delete_partition_with_metadata(
+        &self,
+        input: DeletePartitionRequest,
+    ) -> Result<(DeletePartitionResponse, ResponseMetadata), RusotoError<DeletePartitionError>>
+    {
+        let request = self.build_delete_partition_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMLTransformResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeletePartitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMLTransformError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePartitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified partition.</p>
-    async fn delete_partition(
+    /// Presigns the request `delete_partition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_partition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePartitionRequest,
-    ) -> Result<DeletePartitionResponse, RusotoError<DeletePartitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_partition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_partition_request(&self, input: DeletePartitionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeletePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeletePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_resource_policy`, also returning the metadata of the response.
+    pub async fn delete_resource_policy_with_metadata(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<
+        (DeleteResourcePolicyResponse, ResponseMetadata),
+        RusotoError<DeleteResourcePolicyError>,
+    > {
+        let request = self.build_delete_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeletePartitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteResourcePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePartitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified policy.</p>
-    async fn delete_resource_policy(
+    /// Presigns the request `delete_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourcePolicyRequest,
-    ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_policy_request(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_security_configuration`, also returning the metadata of the response.
+    pub async fn delete_security_configuration_with_metadata(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> Result<
+        (DeleteSecurityConfigurationResponse, ResponseMetadata),
+        RusotoError<DeleteSecurityConfigurationError>,
+    > {
+        let request = self.build_delete_security_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteResourcePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSecurityConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSecurityConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified security configuration.</p>
-    async fn delete_security_configuration(
+    /// Presigns the request `delete_security_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_security_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSecurityConfigurationRequest,
-    ) -> Result<DeleteSecurityConfigurationResponse, RusotoError<DeleteSecurityConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_security_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_security_configuration_request(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteSecurityConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_table`, also returning the metadata of the response.
+    pub async fn delete_table_with_metadata(
+        &self,
+        input: DeleteTableRequest,
+    ) -> Result<(DeleteTableResponse, ResponseMetadata), RusotoError<DeleteTableError>> {
+        let request = self.build_delete_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSecurityConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTableResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSecurityConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTableError::from_response(response))
         }
     }
 
-    /// <p><p>Removes a table definition from the Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
-    async fn delete_table(
+    /// Presigns the request `delete_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTableRequest,
-    ) -> Result<DeleteTableResponse, RusotoError<DeleteTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_table_request(&self, input: DeleteTableRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_table_version`, also returning the metadata of the response.
+    pub async fn delete_table_version_with_metadata(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> Result<(DeleteTableVersionResponse, ResponseMetadata), RusotoError<DeleteTableVersionError>>
+    {
+        let request = self.build_delete_table_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTableResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTableVersionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTableVersionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified version of a table.</p>
-    async fn delete_table_version(
+    /// Presigns the request `delete_table_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_table_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTableVersionRequest,
-    ) -> Result<DeleteTableVersionResponse, RusotoError<DeleteTableVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_table_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_table_version_request(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteTableVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteTableVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_trigger`, also returning the metadata of the response.
+    pub async fn delete_trigger_with_metadata(
+        &self,
+        input: DeleteTriggerRequest,
+    ) -> Result<(DeleteTriggerResponse, ResponseMetadata), RusotoError<DeleteTriggerError>> {
+        let request = self.build_delete_trigger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteTableVersionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTriggerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTableVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTriggerError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified trigger. If the trigger is not found, no exception is thrown.</p>
-    async fn delete_trigger(
+    /// Presigns the request `delete_trigger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_trigger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTriggerRequest,
-    ) -> Result<DeleteTriggerResponse, RusotoError<DeleteTriggerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_trigger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_trigger_request(&self, input: DeleteTriggerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user_defined_function`, also returning the metadata of the response.
+    pub async fn delete_user_defined_function_with_metadata(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> Result<
+        (DeleteUserDefinedFunctionResponse, ResponseMetadata),
+        RusotoError<DeleteUserDefinedFunctionError>,
+    > {
+        let request = self.build_delete_user_defined_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTriggerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteUserDefinedFunctionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTriggerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserDefinedFunctionError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing function definition from the Data Catalog.</p>
-    async fn delete_user_defined_function(
+    /// Presigns the request `delete_user_defined_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user_defined_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserDefinedFunctionRequest,
-    ) -> Result<DeleteUserDefinedFunctionResponse, RusotoError<DeleteUserDefinedFunctionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_defined_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_defined_function_request(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteUserDefinedFunction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_workflow`, also returning the metadata of the response.
+    pub async fn delete_workflow_with_metadata(
+        &self,
+        input: DeleteWorkflowRequest,
+    ) -> Result<(DeleteWorkflowResponse, ResponseMetadata), RusotoError<DeleteWorkflowError>> {
+        let request = self.build_delete_workflow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteUserDefinedFunctionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteWorkflowResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserDefinedFunctionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteWorkflowError::from_response(response))
         }
     }
 
-    /// <p>Deletes a workflow.</p>
-    async fn delete_workflow(
+    /// Presigns the request `delete_workflow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_workflow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteWorkflowRequest,
-    ) -> Result<DeleteWorkflowResponse, RusotoError<DeleteWorkflowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_workflow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_workflow_request(&self, input: DeleteWorkflowRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("DeleteWorkflow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.DeleteWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_catalog_import_status`, also returning the metadata of the response.
+    pub async fn get_catalog_import_status_with_metadata(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> Result<
+        (GetCatalogImportStatusResponse, ResponseMetadata),
+        RusotoError<GetCatalogImportStatusError>,
+    > {
+        let request = self.build_get_catalog_import_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteWorkflowResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCatalogImportStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteWorkflowError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCatalogImportStatusError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the status of a migration operation.</p>
-    async fn get_catalog_import_status(
+    /// Presigns the request `get_catalog_import_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_catalog_import_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCatalogImportStatusRequest,
-    ) -> Result<GetCatalogImportStatusResponse, RusotoError<GetCatalogImportStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_catalog_import_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_catalog_import_status_request(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCatalogImportStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetCatalogImportStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_classifier`, also returning the metadata of the response.
+    pub async fn get_classifier_with_metadata(
+        &self,
+        input: GetClassifierRequest,
+    ) -> Result<(GetClassifierResponse, ResponseMetadata), RusotoError<GetClassifierError>> {
+        let request = self.build_get_classifier_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCatalogImportStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetClassifierResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCatalogImportStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetClassifierError::from_response(response))
         }
     }
 
-    /// <p>Retrieve a classifier by name.</p>
-    async fn get_classifier(
+    /// Presigns the request `get_classifier` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_classifier(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetClassifierRequest,
-    ) -> Result<GetClassifierResponse, RusotoError<GetClassifierError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_classifier_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_classifier_request(&self, input: GetClassifierRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_classifiers`, also returning the metadata of the response.
+    pub async fn get_classifiers_with_metadata(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> Result<(GetClassifiersResponse, ResponseMetadata), RusotoError<GetClassifiersError>> {
+        let request = self.build_get_classifiers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetClassifierResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetClassifiersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetClassifierError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetClassifiersError::from_response(response))
         }
     }
 
-    /// <p>Lists all classifier objects in the Data Catalog.</p>
-    async fn get_classifiers(
+    /// Presigns the request `get_classifiers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_classifiers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetClassifiersRequest,
-    ) -> Result<GetClassifiersResponse, RusotoError<GetClassifiersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_classifiers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_classifiers_request(&self, input: GetClassifiersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetClassifiers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetClassifiers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_connection`, also returning the metadata of the response.
+    pub async fn get_connection_with_metadata(
+        &self,
+        input: GetConnectionRequest,
+    ) -> Result<(GetConnectionResponse, ResponseMetadata), RusotoError<GetConnectionError>> {
+        let request = self.build_get_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetClassifiersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConnectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetClassifiersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConnectionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a connection definition from the Data Catalog.</p>
-    async fn get_connection(
+    /// Presigns the request `get_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConnectionRequest,
-    ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_connection_request(&self, input: GetConnectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_connections`, also returning the metadata of the response.
+    pub async fn get_connections_with_metadata(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> Result<(GetConnectionsResponse, ResponseMetadata), RusotoError<GetConnectionsError>> {
+        let request = self.build_get_connections_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetConnectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConnectionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConnectionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of connection definitions from the Data Catalog.</p>
-    async fn get_connections(
+    /// Presigns the request `get_connections` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_connections(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConnectionsRequest,
-    ) -> Result<GetConnectionsResponse, RusotoError<GetConnectionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_connections_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_connections_request(&self, input: GetConnectionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_crawler`, also returning the metadata of the response.
+    pub async fn get_crawler_with_metadata(
+        &self,
+        input: GetCrawlerRequest,
+    ) -> Result<(GetCrawlerResponse, ResponseMetadata), RusotoError<GetCrawlerError>> {
+        let request = self.build_get_crawler_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetConnectionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCrawlerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConnectionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCrawlerError::from_response(response))
         }
     }
 
-    /// <p>Retrieves metadata for a specified crawler.</p>
-    async fn get_crawler(
+    /// Presigns the request `get_crawler` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_crawler(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCrawlerRequest,
-    ) -> Result<GetCrawlerResponse, RusotoError<GetCrawlerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_crawler_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_crawler_request(&self, input: GetCrawlerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_crawler_metrics`, also returning the metadata of the response.
+    pub async fn get_crawler_metrics_with_metadata(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> Result<(GetCrawlerMetricsResponse, ResponseMetadata), RusotoError<GetCrawlerMetricsError>>
+    {
+        let request = self.build_get_crawler_metrics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCrawlerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCrawlerMetricsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCrawlerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCrawlerMetricsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves metrics about specified crawlers.</p>
-    async fn get_crawler_metrics(
+    /// Presigns the request `get_crawler_metrics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_crawler_metrics(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCrawlerMetricsRequest,
-    ) -> Result<GetCrawlerMetricsResponse, RusotoError<GetCrawlerMetricsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_crawler_metrics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_crawler_metrics_request(&self, input: GetCrawlerMetricsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCrawlerMetrics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetCrawlerMetrics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_crawlers`, also returning the metadata of the response.
+    pub async fn get_crawlers_with_metadata(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> Result<(GetCrawlersResponse, ResponseMetadata), RusotoError<GetCrawlersError>> {
+        let request = self.build_get_crawlers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCrawlerMetricsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCrawlersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCrawlerMetricsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCrawlersError::from_response(response))
         }
     }
 
-    /// <p>Retrieves metadata for all crawlers defined in the customer account.</p>
-    async fn get_crawlers(
+    /// Presigns the request `get_crawlers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_crawlers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCrawlersRequest,
-    ) -> Result<GetCrawlersResponse, RusotoError<GetCrawlersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_crawlers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_crawlers_request(&self, input: GetCrawlersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetCrawlers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetCrawlers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCrawlersResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCrawlersError::from_response(response))
-        }
+        request
     }
 
-    /// <p>Retrieves the security configuration for a specified catalog.</p>
-    async fn get_data_catalog_encryption_settings(
+    /// Calls `get_data_catalog_encryption_settings`, also returning the metadata of the response.
+    pub async fn get_data_catalog_encryption_settings_with_metadata(
         &self,
         input: GetDataCatalogEncryptionSettingsRequest,
     ) -> Result<
-        GetDataCatalogEncryptionSettingsResponse,
+        (GetDataCatalogEncryptionSettingsResponse, ResponseMetadata),
         RusotoError<GetDataCatalogEncryptionSettingsError>,
     > {
-        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetDataCatalogEncryptionSettings");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        let request = self.build_get_data_catalog_encryption_settings_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDataCatalogEncryptionSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDataCatalogEncryptionSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetDataCatalogEncryptionSettingsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Retrieves the definition of a specified database.</p>
-    async fn get_database(
+    /// Presigns the request `get_data_catalog_encryption_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_data_catalog_encryption_settings(
         &self,
-        input: GetDatabaseRequest,
-    ) -> Result<GetDatabaseResponse, RusotoError<GetDatabaseError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_data_catalog_encryption_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_data_catalog_encryption_settings_request(
+        &self,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDataCatalogEncryptionSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetDatabase");
+        request.add_header("x-amz-target", "AWSGlue.GetDataCatalogEncryptionSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_database`, also returning the metadata of the response.
+    pub async fn get_database_with_metadata(
+        &self,
+        input: GetDatabaseRequest,
+    ) -> Result<(GetDatabaseResponse, ResponseMetadata), RusotoError<GetDatabaseError>> {
+        let request = self.build_get_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDatabaseResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDatabaseResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Retrieves all databases defined in a given Data Catalog.</p>
-    async fn get_databases(
+    /// Presigns the request `get_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_database(
         &self,
-        input: GetDatabasesRequest,
-    ) -> Result<GetDatabasesResponse, RusotoError<GetDatabasesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetDatabaseRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_database_request(&self, input: GetDatabaseRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSGlue.GetDatabases");
+        request.add_header("x-amz-target", "AWSGlue.GetDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_databases`, also returning the metadata of the response.
+    pub async fn get_databases_with_metadata(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> Result<(GetDatabasesResponse, ResponseMetadata), RusotoError<GetDatabasesError>> {
+        let request = self.build_get_databases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDatabasesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDatabasesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetDatabasesError::from_response(response))
         }
     }
 
-    /// <p>Transforms a Python script into a directed acyclic graph (DAG). </p>
-    async fn get_dataflow_graph(
+    /// Presigns the request `get_databases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_databases(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetDatabasesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_databases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_databases_request(&self, input: GetDatabasesRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDatabases");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSGlue.GetDatabases");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `get_dataflow_graph`, also returning the metadata of the response.
+    pub async fn get_dataflow_graph_with_metadata(
         &self,
         input: GetDataflowGraphRequest,
-    ) -> Result<GetDataflowGraphResponse, RusotoError<GetDataflowGraphError>> {
+    ) -> Result<(GetDataflowGraphResponse, ResponseMetadata), RusotoError<GetDataflowGraphError>>
+    {
+        let request = self.build_get_dataflow_graph_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDataflowGraphResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDataflowGraphError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `get_dataflow_graph` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dataflow_graph(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetDataflowGraphRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dataflow_graph_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dataflow_graph_request(&self, input: GetDataflowGraphRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDataflowGraph");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetDataflowGraph");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_dev_endpoint`, also returning the metadata of the response.
+    pub async fn get_dev_endpoint_with_metadata(
+        &self,
+        input: GetDevEndpointRequest,
+    ) -> Result<(GetDevEndpointResponse, ResponseMetadata), RusotoError<GetDevEndpointError>> {
+        let request = self.build_get_dev_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDataflowGraphResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDevEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDataflowGraphError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDevEndpointError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves information about a specified development endpoint.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address, and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
-    async fn get_dev_endpoint(
+    /// Presigns the request `get_dev_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dev_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDevEndpointRequest,
-    ) -> Result<GetDevEndpointResponse, RusotoError<GetDevEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dev_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dev_endpoint_request(&self, input: GetDevEndpointRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDevEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_dev_endpoints`, also returning the metadata of the response.
+    pub async fn get_dev_endpoints_with_metadata(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> Result<(GetDevEndpointsResponse, ResponseMetadata), RusotoError<GetDevEndpointsError>>
+    {
+        let request = self.build_get_dev_endpoints_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDevEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDevEndpointsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDevEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDevEndpointsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves all the development endpoints in this AWS account.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
-    async fn get_dev_endpoints(
+    /// Presigns the request `get_dev_endpoints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dev_endpoints(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDevEndpointsRequest,
-    ) -> Result<GetDevEndpointsResponse, RusotoError<GetDevEndpointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dev_endpoints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dev_endpoints_request(&self, input: GetDevEndpointsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetDevEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetDevEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_job`, also returning the metadata of the response.
+    pub async fn get_job_with_metadata(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<(GetJobResponse, ResponseMetadata), RusotoError<GetJobError>> {
+        let request = self.build_get_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDevEndpointsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDevEndpointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetJobError::from_response(response))
         }
     }
 
-    /// <p>Retrieves an existing job definition.</p>
-    async fn get_job(
+    /// Presigns the request `get_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobRequest,
-    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_request(&self, input: GetJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_job_bookmark`, also returning the metadata of the response.
+    pub async fn get_job_bookmark_with_metadata(
+        &self,
+        input: GetJobBookmarkRequest,
+    ) -> Result<(GetJobBookmarkResponse, ResponseMetadata), RusotoError<GetJobBookmarkError>> {
+        let request = self.build_get_job_bookmark_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetJobBookmarkResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetJobBookmarkError::from_response(response))
         }
     }
 
-    /// <p>Returns information on a job bookmark entry.</p>
-    async fn get_job_bookmark(
+    /// Presigns the request `get_job_bookmark` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job_bookmark(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobBookmarkRequest,
-    ) -> Result<GetJobBookmarkResponse, RusotoError<GetJobBookmarkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_bookmark_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_bookmark_request(&self, input: GetJobBookmarkRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobBookmark");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJobBookmark");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_job_run`, also returning the metadata of the response.
+    pub async fn get_job_run_with_metadata(
+        &self,
+        input: GetJobRunRequest,
+    ) -> Result<(GetJobRunResponse, ResponseMetadata), RusotoError<GetJobRunError>> {
+        let request = self.build_get_job_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetJobBookmarkResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetJobRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobBookmarkError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetJobRunError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the metadata for a given job run.</p>
-    async fn get_job_run(
+    /// Presigns the request `get_job_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobRunRequest,
-    ) -> Result<GetJobRunResponse, RusotoError<GetJobRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_run_request(&self, input: GetJobRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJobRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_job_runs`, also returning the metadata of the response.
+    pub async fn get_job_runs_with_metadata(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> Result<(GetJobRunsResponse, ResponseMetadata), RusotoError<GetJobRunsError>> {
+        let request = self.build_get_job_runs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetJobRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetJobRunsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetJobRunsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves metadata for all runs of a given job definition.</p>
-    async fn get_job_runs(
+    /// Presigns the request `get_job_runs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job_runs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobRunsRequest,
-    ) -> Result<GetJobRunsResponse, RusotoError<GetJobRunsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_runs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_runs_request(&self, input: GetJobRunsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJobRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_jobs`, also returning the metadata of the response.
+    pub async fn get_jobs_with_metadata(
+        &self,
+        input: GetJobsRequest,
+    ) -> Result<(GetJobsResponse, ResponseMetadata), RusotoError<GetJobsError>> {
+        let request = self.build_get_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetJobRunsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobRunsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetJobsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves all current job definitions.</p>
-    async fn get_jobs(
+    /// Presigns the request `get_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobsRequest,
-    ) -> Result<GetJobsResponse, RusotoError<GetJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_jobs_request(&self, input: GetJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ml_task_run`, also returning the metadata of the response.
+    pub async fn get_ml_task_run_with_metadata(
+        &self,
+        input: GetMLTaskRunRequest,
+    ) -> Result<(GetMLTaskRunResponse, ResponseMetadata), RusotoError<GetMLTaskRunError>> {
+        let request = self.build_get_ml_task_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMLTaskRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMLTaskRunError::from_response(response))
         }
     }
 
-    /// <p>Gets details for a specific task run on a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can check the stats of any task run by calling <code>GetMLTaskRun</code> with the <code>TaskRunID</code> and its parent transform's <code>TransformID</code>.</p>
-    async fn get_ml_task_run(
+    /// Presigns the request `get_ml_task_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ml_task_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMLTaskRunRequest,
-    ) -> Result<GetMLTaskRunResponse, RusotoError<GetMLTaskRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ml_task_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ml_task_run_request(&self, input: GetMLTaskRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMLTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ml_task_runs`, also returning the metadata of the response.
+    pub async fn get_ml_task_runs_with_metadata(
+        &self,
+        input: GetMLTaskRunsRequest,
+    ) -> Result<(GetMLTaskRunsResponse, ResponseMetadata), RusotoError<GetMLTaskRunsError>> {
+        let request = self.build_get_ml_task_runs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetMLTaskRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMLTaskRunsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMLTaskRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMLTaskRunsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of runs for a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can get a sortable, filterable list of machine learning task runs by calling <code>GetMLTaskRuns</code> with their parent transform's <code>TransformID</code> and other optional parameters as documented in this section.</p> <p>This operation returns a list of historic runs and must be paginated.</p>
-    async fn get_ml_task_runs(
+    /// Presigns the request `get_ml_task_runs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ml_task_runs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMLTaskRunsRequest,
-    ) -> Result<GetMLTaskRunsResponse, RusotoError<GetMLTaskRunsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ml_task_runs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ml_task_runs_request(&self, input: GetMLTaskRunsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTaskRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMLTaskRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ml_transform`, also returning the metadata of the response.
+    pub async fn get_ml_transform_with_metadata(
+        &self,
+        input: GetMLTransformRequest,
+    ) -> Result<(GetMLTransformResponse, ResponseMetadata), RusotoError<GetMLTransformError>> {
+        let request = self.build_get_ml_transform_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetMLTaskRunsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMLTransformResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMLTaskRunsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMLTransformError::from_response(response))
         }
     }
 
-    /// <p>Gets an AWS Glue machine learning transform artifact and all its corresponding metadata. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. You can retrieve their metadata by calling <code>GetMLTransform</code>.</p>
-    async fn get_ml_transform(
+    /// Presigns the request `get_ml_transform` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ml_transform(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMLTransformRequest,
-    ) -> Result<GetMLTransformResponse, RusotoError<GetMLTransformError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ml_transform_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ml_transform_request(&self, input: GetMLTransformRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTransform");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ml_transforms`, also returning the metadata of the response.
+    pub async fn get_ml_transforms_with_metadata(
+        &self,
+        input: GetMLTransformsRequest,
+    ) -> Result<(GetMLTransformsResponse, ResponseMetadata), RusotoError<GetMLTransformsError>>
+    {
+        let request = self.build_get_ml_transforms_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetMLTransformResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMLTransformsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMLTransformError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMLTransformsError::from_response(response))
         }
     }
 
-    /// <p>Gets a sortable, filterable list of existing AWS Glue machine learning transforms. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue, and you can retrieve their metadata by calling <code>GetMLTransforms</code>.</p>
-    async fn get_ml_transforms(
+    /// Presigns the request `get_ml_transforms` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ml_transforms(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMLTransformsRequest,
-    ) -> Result<GetMLTransformsResponse, RusotoError<GetMLTransformsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ml_transforms_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ml_transforms_request(&self, input: GetMLTransformsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMLTransforms");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMLTransforms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_mapping`, also returning the metadata of the response.
+    pub async fn get_mapping_with_metadata(
+        &self,
+        input: GetMappingRequest,
+    ) -> Result<(GetMappingResponse, ResponseMetadata), RusotoError<GetMappingError>> {
+        let request = self.build_get_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetMLTransformsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMappingResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMLTransformsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMappingError::from_response(response))
         }
     }
 
-    /// <p>Creates mappings.</p>
-    async fn get_mapping(
+    /// Presigns the request `get_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMappingRequest,
-    ) -> Result<GetMappingResponse, RusotoError<GetMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_mapping_request(&self, input: GetMappingRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetMapping");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetMapping");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_partition`, also returning the metadata of the response.
+    pub async fn get_partition_with_metadata(
+        &self,
+        input: GetPartitionRequest,
+    ) -> Result<(GetPartitionResponse, ResponseMetadata), RusotoError<GetPartitionError>> {
+        let request = self.build_get_partition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetMappingResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPartitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMappingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPartitionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a specified partition.</p>
-    async fn get_partition(
+    /// Presigns the request `get_partition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_partition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPartitionRequest,
-    ) -> Result<GetPartitionResponse, RusotoError<GetPartitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_partition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_partition_request(&self, input: GetPartitionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetPartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_partitions`, also returning the metadata of the response.
+    pub async fn get_partitions_with_metadata(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> Result<(GetPartitionsResponse, ResponseMetadata), RusotoError<GetPartitionsError>> {
+        let request = self.build_get_partitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPartitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPartitionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPartitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPartitionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about the partitions in a table.</p>
-    async fn get_partitions(
+    /// Presigns the request `get_partitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_partitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPartitionsRequest,
-    ) -> Result<GetPartitionsResponse, RusotoError<GetPartitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_partitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_partitions_request(&self, input: GetPartitionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetPartitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetPartitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_plan`, also returning the metadata of the response.
+    pub async fn get_plan_with_metadata(
+        &self,
+        input: GetPlanRequest,
+    ) -> Result<(GetPlanResponse, ResponseMetadata), RusotoError<GetPlanError>> {
+        let request = self.build_get_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPartitionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetPlanResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPartitionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPlanError::from_response(response))
         }
     }
 
-    /// <p>Gets code to perform a specified mapping.</p>
-    async fn get_plan(
+    /// Presigns the request `get_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPlanRequest,
-    ) -> Result<GetPlanResponse, RusotoError<GetPlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_plan_request(&self, input: GetPlanRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetPlan");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resource_policy`, also returning the metadata of the response.
+    pub async fn get_resource_policy_with_metadata(
+        &self,
+    ) -> Result<(GetResourcePolicyResponse, ResponseMetadata), RusotoError<GetResourcePolicyError>>
+    {
+        let request = self.build_get_resource_policy_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPlanResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResourcePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPlanError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a specified resource policy.</p>
-    async fn get_resource_policy(
+    /// Presigns the request `get_resource_policy` would send with `presigner` instead of sending it.
+    pub async fn presign_get_resource_policy(
         &self,
-    ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_policy_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_policy_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetResourcePolicy");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_security_configuration`, also returning the metadata of the response.
+    pub async fn get_security_configuration_with_metadata(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> Result<
+        (GetSecurityConfigurationResponse, ResponseMetadata),
+        RusotoError<GetSecurityConfigurationError>,
+    > {
+        let request = self.build_get_security_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourcePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSecurityConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSecurityConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a specified security configuration.</p>
-    async fn get_security_configuration(
+    /// Presigns the request `get_security_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_security_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSecurityConfigurationRequest,
-    ) -> Result<GetSecurityConfigurationResponse, RusotoError<GetSecurityConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_security_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_security_configuration_request(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetSecurityConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `get_security_configurations`, also returning the metadata of the response.
+    pub async fn get_security_configurations_with_metadata(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> Result<
+        (GetSecurityConfigurationsResponse, ResponseMetadata),
+        RusotoError<GetSecurityConfigurationsError>,
+    > {
+        let request = self.build_get_security_configurations_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSecurityConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSecurityConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSecurityConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSecurityConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of all security configurations.</p>
-    async fn get_security_configurations(
+    /// Presigns the request `get_security_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_security_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSecurityConfigurationsRequest,
-    ) -> Result<GetSecurityConfigurationsResponse, RusotoError<GetSecurityConfigurationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_security_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_security_configurations_request(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetSecurityConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetSecurityConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_table`, also returning the metadata of the response.
+    pub async fn get_table_with_metadata(
+        &self,
+        input: GetTableRequest,
+    ) -> Result<(GetTableResponse, ResponseMetadata), RusotoError<GetTableError>> {
+        let request = self.build_get_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSecurityConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTableResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSecurityConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTableError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the <code>Table</code> definition in a Data Catalog for a specified table.</p>
-    async fn get_table(
+    /// Presigns the request `get_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTableRequest,
-    ) -> Result<GetTableResponse, RusotoError<GetTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_table_request(&self, input: GetTableRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_table_version`, also returning the metadata of the response.
+    pub async fn get_table_version_with_metadata(
+        &self,
+        input: GetTableVersionRequest,
+    ) -> Result<(GetTableVersionResponse, ResponseMetadata), RusotoError<GetTableVersionError>>
+    {
+        let request = self.build_get_table_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTableResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTableVersionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTableVersionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a specified version of a table.</p>
-    async fn get_table_version(
+    /// Presigns the request `get_table_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_table_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTableVersionRequest,
-    ) -> Result<GetTableVersionResponse, RusotoError<GetTableVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_table_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_table_version_request(&self, input: GetTableVersionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTableVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTableVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_table_versions`, also returning the metadata of the response.
+    pub async fn get_table_versions_with_metadata(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> Result<(GetTableVersionsResponse, ResponseMetadata), RusotoError<GetTableVersionsError>>
+    {
+        let request = self.build_get_table_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTableVersionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTableVersionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTableVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTableVersionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of strings that identify available versions of a specified table.</p>
-    async fn get_table_versions(
+    /// Presigns the request `get_table_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_table_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTableVersionsRequest,
-    ) -> Result<GetTableVersionsResponse, RusotoError<GetTableVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_table_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_table_versions_request(&self, input: GetTableVersionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTableVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTableVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_tables`, also returning the metadata of the response.
+    pub async fn get_tables_with_metadata(
+        &self,
+        input: GetTablesRequest,
+    ) -> Result<(GetTablesResponse, ResponseMetadata), RusotoError<GetTablesError>> {
+        let request = self.build_get_tables_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetTableVersionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTablesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTableVersionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTablesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the definitions of some or all of the tables in a given <code>Database</code>.</p>
-    async fn get_tables(
+    /// Presigns the request `get_tables` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_tables(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTablesRequest,
-    ) -> Result<GetTablesResponse, RusotoError<GetTablesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_tables_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_tables_request(&self, input: GetTablesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTables");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_tags`, also returning the metadata of the response.
+    pub async fn get_tags_with_metadata(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<(GetTagsResponse, ResponseMetadata), RusotoError<GetTagsError>> {
+        let request = self.build_get_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTablesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTablesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTagsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of tags associated with a resource.</p>
-    async fn get_tags(
+    /// Presigns the request `get_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTagsRequest,
-    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_tags_request(&self, input: GetTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_trigger`, also returning the metadata of the response.
+    pub async fn get_trigger_with_metadata(
+        &self,
+        input: GetTriggerRequest,
+    ) -> Result<(GetTriggerResponse, ResponseMetadata), RusotoError<GetTriggerError>> {
+        let request = self.build_get_trigger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTriggerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTriggerError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the definition of a trigger.</p>
-    async fn get_trigger(
+    /// Presigns the request `get_trigger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_trigger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTriggerRequest,
-    ) -> Result<GetTriggerResponse, RusotoError<GetTriggerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_trigger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_trigger_request(&self, input: GetTriggerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_triggers`, also returning the metadata of the response.
+    pub async fn get_triggers_with_metadata(
+        &self,
+        input: GetTriggersRequest,
+    ) -> Result<(GetTriggersResponse, ResponseMetadata), RusotoError<GetTriggersError>> {
+        let request = self.build_get_triggers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTriggerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTriggersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTriggerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTriggersError::from_response(response))
         }
     }
 
-    /// <p>Gets all the triggers associated with a job.</p>
-    async fn get_triggers(
+    /// Presigns the request `get_triggers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_triggers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTriggersRequest,
-    ) -> Result<GetTriggersResponse, RusotoError<GetTriggersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_triggers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_triggers_request(&self, input: GetTriggersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_user_defined_function`, also returning the metadata of the response.
+    pub async fn get_user_defined_function_with_metadata(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> Result<
+        (GetUserDefinedFunctionResponse, ResponseMetadata),
+        RusotoError<GetUserDefinedFunctionError>,
+    > {
+        let request = self.build_get_user_defined_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTriggersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetUserDefinedFunctionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTriggersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetUserDefinedFunctionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a specified function definition from the Data Catalog.</p>
-    async fn get_user_defined_function(
+    /// Presigns the request `get_user_defined_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_user_defined_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUserDefinedFunctionRequest,
-    ) -> Result<GetUserDefinedFunctionResponse, RusotoError<GetUserDefinedFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_user_defined_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_user_defined_function_request(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetUserDefinedFunction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_user_defined_functions`, also returning the metadata of the response.
+    pub async fn get_user_defined_functions_with_metadata(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> Result<
+        (GetUserDefinedFunctionsResponse, ResponseMetadata),
+        RusotoError<GetUserDefinedFunctionsError>,
+    > {
+        let request = self.build_get_user_defined_functions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUserDefinedFunctionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetUserDefinedFunctionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUserDefinedFunctionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetUserDefinedFunctionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves multiple function definitions from the Data Catalog.</p>
-    async fn get_user_defined_functions(
+    /// Presigns the request `get_user_defined_functions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_user_defined_functions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUserDefinedFunctionsRequest,
-    ) -> Result<GetUserDefinedFunctionsResponse, RusotoError<GetUserDefinedFunctionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_user_defined_functions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_user_defined_functions_request(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetUserDefinedFunctions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunctions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_workflow`, also returning the metadata of the response.
+    pub async fn get_workflow_with_metadata(
+        &self,
+        input: GetWorkflowRequest,
+    ) -> Result<(GetWorkflowResponse, ResponseMetadata), RusotoError<GetWorkflowError>> {
+        let request = self.build_get_workflow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUserDefinedFunctionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetWorkflowResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUserDefinedFunctionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetWorkflowError::from_response(response))
         }
     }
 
-    /// <p>Retrieves resource metadata for a workflow.</p>
-    async fn get_workflow(
+    /// Presigns the request `get_workflow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_workflow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetWorkflowRequest,
-    ) -> Result<GetWorkflowResponse, RusotoError<GetWorkflowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_workflow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_workflow_request(&self, input: GetWorkflowRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_workflow_run`, also returning the metadata of the response.
+    pub async fn get_workflow_run_with_metadata(
+        &self,
+        input: GetWorkflowRunRequest,
+    ) -> Result<(GetWorkflowRunResponse, ResponseMetadata), RusotoError<GetWorkflowRunError>> {
+        let request = self.build_get_workflow_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetWorkflowResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetWorkflowRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetWorkflowError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetWorkflowRunError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the metadata for a given workflow run. </p>
-    async fn get_workflow_run(
+    /// Presigns the request `get_workflow_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_workflow_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetWorkflowRunRequest,
-    ) -> Result<GetWorkflowRunResponse, RusotoError<GetWorkflowRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_workflow_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_workflow_run_request(&self, input: GetWorkflowRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflowRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_workflow_run_properties`, also returning the metadata of the response.
+    pub async fn get_workflow_run_properties_with_metadata(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> Result<
+        (GetWorkflowRunPropertiesResponse, ResponseMetadata),
+        RusotoError<GetWorkflowRunPropertiesError>,
+    > {
+        let request = self.build_get_workflow_run_properties_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetWorkflowRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetWorkflowRunPropertiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetWorkflowRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetWorkflowRunPropertiesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the workflow run properties which were set during the run.</p>
-    async fn get_workflow_run_properties(
+    /// Presigns the request `get_workflow_run_properties` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_workflow_run_properties(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetWorkflowRunPropertiesRequest,
-    ) -> Result<GetWorkflowRunPropertiesResponse, RusotoError<GetWorkflowRunPropertiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_workflow_run_properties_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_workflow_run_properties_request(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflowRunProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRunProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_workflow_runs`, also returning the metadata of the response.
+    pub async fn get_workflow_runs_with_metadata(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> Result<(GetWorkflowRunsResponse, ResponseMetadata), RusotoError<GetWorkflowRunsError>>
+    {
+        let request = self.build_get_workflow_runs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetWorkflowRunPropertiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetWorkflowRunsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetWorkflowRunPropertiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetWorkflowRunsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves metadata for all runs of a given workflow.</p>
-    async fn get_workflow_runs(
+    /// Presigns the request `get_workflow_runs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_workflow_runs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetWorkflowRunsRequest,
-    ) -> Result<GetWorkflowRunsResponse, RusotoError<GetWorkflowRunsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_workflow_runs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_workflow_runs_request(&self, input: GetWorkflowRunsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("GetWorkflowRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_catalog_to_glue`, also returning the metadata of the response.
+    pub async fn import_catalog_to_glue_with_metadata(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> Result<
+        (ImportCatalogToGlueResponse, ResponseMetadata),
+        RusotoError<ImportCatalogToGlueError>,
+    > {
+        let request = self.build_import_catalog_to_glue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetWorkflowRunsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportCatalogToGlueResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetWorkflowRunsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportCatalogToGlueError::from_response(response))
         }
     }
 
-    /// <p>Imports an existing Amazon Athena Data Catalog to AWS Glue</p>
-    async fn import_catalog_to_glue(
+    /// Presigns the request `import_catalog_to_glue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_catalog_to_glue(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportCatalogToGlueRequest,
-    ) -> Result<ImportCatalogToGlueResponse, RusotoError<ImportCatalogToGlueError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_catalog_to_glue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_catalog_to_glue_request(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ImportCatalogToGlue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ImportCatalogToGlue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_crawlers`, also returning the metadata of the response.
+    pub async fn list_crawlers_with_metadata(
+        &self,
+        input: ListCrawlersRequest,
+    ) -> Result<(ListCrawlersResponse, ResponseMetadata), RusotoError<ListCrawlersError>> {
+        let request = self.build_list_crawlers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportCatalogToGlueResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCrawlersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportCatalogToGlueError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCrawlersError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the names of all crawler resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
-    async fn list_crawlers(
+    /// Presigns the request `list_crawlers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_crawlers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCrawlersRequest,
-    ) -> Result<ListCrawlersResponse, RusotoError<ListCrawlersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_crawlers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_crawlers_request(&self, input: ListCrawlersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListCrawlers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListCrawlers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_dev_endpoints`, also returning the metadata of the response.
+    pub async fn list_dev_endpoints_with_metadata(
+        &self,
+        input: ListDevEndpointsRequest,
+    ) -> Result<(ListDevEndpointsResponse, ResponseMetadata), RusotoError<ListDevEndpointsError>>
+    {
+        let request = self.build_list_dev_endpoints_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListCrawlersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDevEndpointsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCrawlersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDevEndpointsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the names of all <code>DevEndpoint</code> resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
-    async fn list_dev_endpoints(
+    /// Presigns the request `list_dev_endpoints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dev_endpoints(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDevEndpointsRequest,
-    ) -> Result<ListDevEndpointsResponse, RusotoError<ListDevEndpointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dev_endpoints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dev_endpoints_request(&self, input: ListDevEndpointsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListDevEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListDevEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_jobs`, also returning the metadata of the response.
+    pub async fn list_jobs_with_metadata(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<(ListJobsResponse, ResponseMetadata), RusotoError<ListJobsError>> {
+        let request = self.build_list_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDevEndpointsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDevEndpointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListJobsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the names of all job resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
-    async fn list_jobs(
+    /// Presigns the request `list_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJobsRequest,
-    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_request(&self, input: ListJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_triggers`, also returning the metadata of the response.
+    pub async fn list_triggers_with_metadata(
+        &self,
+        input: ListTriggersRequest,
+    ) -> Result<(ListTriggersResponse, ResponseMetadata), RusotoError<ListTriggersError>> {
+        let request = self.build_list_triggers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTriggersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTriggersError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the names of all trigger resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
-    async fn list_triggers(
+    /// Presigns the request `list_triggers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_triggers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTriggersRequest,
-    ) -> Result<ListTriggersResponse, RusotoError<ListTriggersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_triggers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_triggers_request(&self, input: ListTriggersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `list_workflows`, also returning the metadata of the response.
+    pub async fn list_workflows_with_metadata(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> Result<(ListWorkflowsResponse, ResponseMetadata), RusotoError<ListWorkflowsError>> {
+        let request = self.build_list_workflows_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTriggersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListWorkflowsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTriggersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListWorkflowsError::from_response(response))
         }
     }
 
-    /// <p>Lists names of workflows created in the account.</p>
-    async fn list_workflows(
+    /// Presigns the request `list_workflows` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_workflows(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListWorkflowsRequest,
-    ) -> Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_workflows_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_workflows_request(&self, input: ListWorkflowsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ListWorkflows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ListWorkflows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_data_catalog_encryption_settings`, also returning the metadata of the response.
+    pub async fn put_data_catalog_encryption_settings_with_metadata(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> Result<
+        (PutDataCatalogEncryptionSettingsResponse, ResponseMetadata),
+        RusotoError<PutDataCatalogEncryptionSettingsError>,
+    > {
+        let request = self.build_put_data_catalog_encryption_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListWorkflowsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutDataCatalogEncryptionSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListWorkflowsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutDataCatalogEncryptionSettingsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Sets the security configuration for a specified catalog. After the configuration has been set, the specified encryption is applied to every catalog write thereafter.</p>
-    async fn put_data_catalog_encryption_settings(
+    /// Presigns the request `put_data_catalog_encryption_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_data_catalog_encryption_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutDataCatalogEncryptionSettingsRequest,
-    ) -> Result<
-        PutDataCatalogEncryptionSettingsResponse,
-        RusotoError<PutDataCatalogEncryptionSettingsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_data_catalog_encryption_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_data_catalog_encryption_settings_request(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("PutDataCatalogEncryptionSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.PutDataCatalogEncryptionSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_resource_policy`, also returning the metadata of the response.
+    pub async fn put_resource_policy_with_metadata(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<(PutResourcePolicyResponse, ResponseMetadata), RusotoError<PutResourcePolicyError>>
+    {
+        let request = self.build_put_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutDataCatalogEncryptionSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutResourcePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutDataCatalogEncryptionSettingsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p>Sets the Data Catalog resource policy for access control.</p>
-    async fn put_resource_policy(
+    /// Presigns the request `put_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutResourcePolicyRequest,
-    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_resource_policy_request(&self, input: PutResourcePolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_workflow_run_properties`, also returning the metadata of the response.
+    pub async fn put_workflow_run_properties_with_metadata(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> Result<
+        (PutWorkflowRunPropertiesResponse, ResponseMetadata),
+        RusotoError<PutWorkflowRunPropertiesError>,
+    > {
+        let request = self.build_put_workflow_run_properties_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutResourcePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutWorkflowRunPropertiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutWorkflowRunPropertiesError::from_response(response))
         }
     }
 
-    /// <p>Puts the specified workflow run properties for the given workflow run. If a property already exists for the specified run, then it overrides the value otherwise adds the property to existing properties.</p>
-    async fn put_workflow_run_properties(
+    /// Presigns the request `put_workflow_run_properties` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_workflow_run_properties(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutWorkflowRunPropertiesRequest,
-    ) -> Result<PutWorkflowRunPropertiesResponse, RusotoError<PutWorkflowRunPropertiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_workflow_run_properties_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_workflow_run_properties_request(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("PutWorkflowRunProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.PutWorkflowRunProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reset_job_bookmark`, also returning the metadata of the response.
+    pub async fn reset_job_bookmark_with_metadata(
+        &self,
+        input: ResetJobBookmarkRequest,
+    ) -> Result<(ResetJobBookmarkResponse, ResponseMetadata), RusotoError<ResetJobBookmarkError>>
+    {
+        let request = self.build_reset_job_bookmark_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutWorkflowRunPropertiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResetJobBookmarkResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutWorkflowRunPropertiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResetJobBookmarkError::from_response(response))
         }
     }
 
-    /// <p>Resets a bookmark entry.</p>
-    async fn reset_job_bookmark(
+    /// Presigns the request `reset_job_bookmark` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_job_bookmark(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResetJobBookmarkRequest,
-    ) -> Result<ResetJobBookmarkResponse, RusotoError<ResetJobBookmarkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_job_bookmark_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_job_bookmark_request(&self, input: ResetJobBookmarkRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("ResetJobBookmark");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.ResetJobBookmark");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_tables`, also returning the metadata of the response.
+    pub async fn search_tables_with_metadata(
+        &self,
+        input: SearchTablesRequest,
+    ) -> Result<(SearchTablesResponse, ResponseMetadata), RusotoError<SearchTablesError>> {
+        let request = self.build_search_tables_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ResetJobBookmarkResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchTablesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResetJobBookmarkError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchTablesError::from_response(response))
         }
     }
 
-    /// <p>Searches a set of tables based on properties in the table metadata as well as on the parent database. You can search against text or filter conditions. </p> <p>You can only get tables that you have access to based on the security policies defined in Lake Formation. You need at least a read-only access to the table for it to be returned. If you do not have access to all the columns in the table, these columns will not be searched against when returning the list of tables back to you. If you have access to the columns but not the data in the columns, those columns and the associated metadata for those columns will be included in the search. </p>
-    async fn search_tables(
+    /// Presigns the request `search_tables` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_tables(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchTablesRequest,
-    ) -> Result<SearchTablesResponse, RusotoError<SearchTablesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_tables_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_tables_request(&self, input: SearchTablesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("SearchTables");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.SearchTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_crawler`, also returning the metadata of the response.
+    pub async fn start_crawler_with_metadata(
+        &self,
+        input: StartCrawlerRequest,
+    ) -> Result<(StartCrawlerResponse, ResponseMetadata), RusotoError<StartCrawlerError>> {
+        let request = self.build_start_crawler_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchTablesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartCrawlerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchTablesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartCrawlerError::from_response(response))
         }
     }
 
-    /// <p>Starts a crawl using the specified crawler, regardless of what is scheduled. If the crawler is already running, returns a <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-exceptions.html#aws-glue-api-exceptions-CrawlerRunningException">CrawlerRunningException</a>.</p>
-    async fn start_crawler(
+    /// Presigns the request `start_crawler` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_crawler(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartCrawlerRequest,
-    ) -> Result<StartCrawlerResponse, RusotoError<StartCrawlerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_crawler_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_crawler_request(&self, input: StartCrawlerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_crawler_schedule`, also returning the metadata of the response.
+    pub async fn start_crawler_schedule_with_metadata(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> Result<
+        (StartCrawlerScheduleResponse, ResponseMetadata),
+        RusotoError<StartCrawlerScheduleError>,
+    > {
+        let request = self.build_start_crawler_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartCrawlerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartCrawlerScheduleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartCrawlerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartCrawlerScheduleError::from_response(response))
         }
     }
 
-    /// <p>Changes the schedule state of the specified crawler to <code>SCHEDULED</code>, unless the crawler is already running or the schedule state is already <code>SCHEDULED</code>.</p>
-    async fn start_crawler_schedule(
+    /// Presigns the request `start_crawler_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_crawler_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartCrawlerScheduleRequest,
-    ) -> Result<StartCrawlerScheduleResponse, RusotoError<StartCrawlerScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_crawler_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_crawler_schedule_request(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartCrawlerSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartCrawlerSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_export_labels_task_run`, also returning the metadata of the response.
+    pub async fn start_export_labels_task_run_with_metadata(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> Result<
+        (StartExportLabelsTaskRunResponse, ResponseMetadata),
+        RusotoError<StartExportLabelsTaskRunError>,
+    > {
+        let request = self.build_start_export_labels_task_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartCrawlerScheduleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartExportLabelsTaskRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartCrawlerScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartExportLabelsTaskRunError::from_response(response))
         }
     }
 
-    /// <p>Begins an asynchronous task to export all labeled data for a particular transform. This task is the only label-related API call that is not part of the typical active learning workflow. You typically use <code>StartExportLabelsTaskRun</code> when you want to work with all of your existing labels at the same time, such as when you want to remove or change labels that were previously submitted as truth. This API operation accepts the <code>TransformId</code> whose labels you want to export and an Amazon Simple Storage Service (Amazon S3) path to export the labels to. The operation returns a <code>TaskRunId</code>. You can check on the status of your task run by calling the <code>GetMLTaskRun</code> API.</p>
-    async fn start_export_labels_task_run(
+    /// Presigns the request `start_export_labels_task_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_export_labels_task_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartExportLabelsTaskRunRequest,
-    ) -> Result<StartExportLabelsTaskRunResponse, RusotoError<StartExportLabelsTaskRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_export_labels_task_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_export_labels_task_run_request(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartExportLabelsTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartExportLabelsTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_import_labels_task_run`, also returning the metadata of the response.
+    pub async fn start_import_labels_task_run_with_metadata(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> Result<
+        (StartImportLabelsTaskRunResponse, ResponseMetadata),
+        RusotoError<StartImportLabelsTaskRunError>,
+    > {
+        let request = self.build_start_import_labels_task_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartExportLabelsTaskRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartImportLabelsTaskRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartExportLabelsTaskRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartImportLabelsTaskRunError::from_response(response))
         }
     }
 
-    /// <p>Enables you to provide additional labels (examples of truth) to be used to teach the machine learning transform and improve its quality. This API operation is generally used as part of the active learning workflow that starts with the <code>StartMLLabelingSetGenerationTaskRun</code> call and that ultimately results in improving the quality of your machine learning transform. </p> <p>After the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue machine learning will have generated a series of questions for humans to answer. (Answering these questions is often called 'labeling' in the machine learning workflows). In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” After the labeling process is finished, users upload their answers/labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform use the new and improved labels and perform a higher-quality transformation.</p> <p>By default, <code>StartMLLabelingSetGenerationTaskRun</code> continually learns from and combines all labels that you upload unless you set <code>Replace</code> to true. If you set <code>Replace</code> to true, <code>StartImportLabelsTaskRun</code> deletes and forgets all previously uploaded labels and learns only from the exact set that you upload. Replacing labels can be helpful if you realize that you previously uploaded incorrect labels, and you believe that they are having a negative effect on your transform quality.</p> <p>You can check on the status of your task run by calling the <code>GetMLTaskRun</code> operation. </p>
-    async fn start_import_labels_task_run(
+    /// Presigns the request `start_import_labels_task_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_import_labels_task_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartImportLabelsTaskRunRequest,
-    ) -> Result<StartImportLabelsTaskRunResponse, RusotoError<StartImportLabelsTaskRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_import_labels_task_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_import_labels_task_run_request(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartImportLabelsTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartImportLabelsTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_job_run`, also returning the metadata of the response.
+    pub async fn start_job_run_with_metadata(
+        &self,
+        input: StartJobRunRequest,
+    ) -> Result<(StartJobRunResponse, ResponseMetadata), RusotoError<StartJobRunError>> {
+        let request = self.build_start_job_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartImportLabelsTaskRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartJobRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartImportLabelsTaskRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartJobRunError::from_response(response))
         }
     }
 
-    /// <p>Starts a job run using a job definition.</p>
-    async fn start_job_run(
+    /// Presigns the request `start_job_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_job_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartJobRunRequest,
-    ) -> Result<StartJobRunResponse, RusotoError<StartJobRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_job_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_job_run_request(&self, input: StartJobRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartJobRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartJobRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_ml_evaluation_task_run`, also returning the metadata of the response.
+    pub async fn start_ml_evaluation_task_run_with_metadata(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> Result<
+        (StartMLEvaluationTaskRunResponse, ResponseMetadata),
+        RusotoError<StartMLEvaluationTaskRunError>,
+    > {
+        let request = self.build_start_ml_evaluation_task_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartJobRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartMLEvaluationTaskRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartJobRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartMLEvaluationTaskRunError::from_response(response))
         }
     }
 
-    /// <p>Starts a task to estimate the quality of the transform. </p> <p>When you provide label sets as examples of truth, AWS Glue machine learning uses some of those examples to learn from them. The rest of the labels are used as a test to estimate quality.</p> <p>Returns a unique identifier for the run. You can call <code>GetMLTaskRun</code> to get more information about the stats of the <code>EvaluationTaskRun</code>.</p>
-    async fn start_ml_evaluation_task_run(
+    /// Presigns the request `start_ml_evaluation_task_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_ml_evaluation_task_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartMLEvaluationTaskRunRequest,
-    ) -> Result<StartMLEvaluationTaskRunResponse, RusotoError<StartMLEvaluationTaskRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_ml_evaluation_task_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_ml_evaluation_task_run_request(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartMLEvaluationTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartMLEvaluationTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_ml_labeling_set_generation_task_run`, also returning the metadata of the response.
+    pub async fn start_ml_labeling_set_generation_task_run_with_metadata(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> Result<
+        (
+            StartMLLabelingSetGenerationTaskRunResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<StartMLLabelingSetGenerationTaskRunError>,
+    > {
+        let request = self.build_start_ml_labeling_set_generation_task_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartMLEvaluationTaskRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartMLLabelingSetGenerationTaskRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartMLEvaluationTaskRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartMLLabelingSetGenerationTaskRunError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Starts the active learning workflow for your machine learning transform to improve the transform's quality by generating label sets and adding labels.</p> <p>When the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue will have generated a "labeling set" or a set of questions for humans to answer.</p> <p>In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” </p> <p>After the labeling process is finished, you can upload your labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform will use the new and improved labels and perform a higher-quality transformation.</p>
-    async fn start_ml_labeling_set_generation_task_run(
+    /// Presigns the request `start_ml_labeling_set_generation_task_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_ml_labeling_set_generation_task_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartMLLabelingSetGenerationTaskRunRequest,
-    ) -> Result<
-        StartMLLabelingSetGenerationTaskRunResponse,
-        RusotoError<StartMLLabelingSetGenerationTaskRunError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_ml_labeling_set_generation_task_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_ml_labeling_set_generation_task_run_request(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartMLLabelingSetGenerationTaskRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16013,570 +19916,2966 @@ impl Glue for GlueClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_trigger`, also returning the metadata of the response.
+    pub async fn start_trigger_with_metadata(
+        &self,
+        input: StartTriggerRequest,
+    ) -> Result<(StartTriggerResponse, ResponseMetadata), RusotoError<StartTriggerError>> {
+        let request = self.build_start_trigger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartMLLabelingSetGenerationTaskRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartTriggerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartMLLabelingSetGenerationTaskRunError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartTriggerError::from_response(response))
         }
     }
 
-    /// <p>Starts an existing trigger. See <a href="https://docs.aws.amazon.com/glue/latest/dg/trigger-job.html">Triggering Jobs</a> for information about how different types of trigger are started.</p>
-    async fn start_trigger(
+    /// Presigns the request `start_trigger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_trigger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartTriggerRequest,
-    ) -> Result<StartTriggerResponse, RusotoError<StartTriggerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_trigger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_trigger_request(&self, input: StartTriggerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_workflow_run`, also returning the metadata of the response.
+    pub async fn start_workflow_run_with_metadata(
+        &self,
+        input: StartWorkflowRunRequest,
+    ) -> Result<(StartWorkflowRunResponse, ResponseMetadata), RusotoError<StartWorkflowRunError>>
+    {
+        let request = self.build_start_workflow_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartTriggerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartWorkflowRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartTriggerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartWorkflowRunError::from_response(response))
         }
     }
 
-    /// <p>Starts a new run of the specified workflow.</p>
-    async fn start_workflow_run(
+    /// Presigns the request `start_workflow_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_workflow_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartWorkflowRunRequest,
-    ) -> Result<StartWorkflowRunResponse, RusotoError<StartWorkflowRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_workflow_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_workflow_run_request(&self, input: StartWorkflowRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StartWorkflowRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StartWorkflowRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_crawler`, also returning the metadata of the response.
+    pub async fn stop_crawler_with_metadata(
+        &self,
+        input: StopCrawlerRequest,
+    ) -> Result<(StopCrawlerResponse, ResponseMetadata), RusotoError<StopCrawlerError>> {
+        let request = self.build_stop_crawler_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartWorkflowRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopCrawlerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartWorkflowRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopCrawlerError::from_response(response))
         }
     }
 
-    /// <p>If the specified crawler is running, stops the crawl.</p>
-    async fn stop_crawler(
+    /// Presigns the request `stop_crawler` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_crawler(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopCrawlerRequest,
-    ) -> Result<StopCrawlerResponse, RusotoError<StopCrawlerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_crawler_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_crawler_request(&self, input: StopCrawlerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StopCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StopCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_crawler_schedule`, also returning the metadata of the response.
+    pub async fn stop_crawler_schedule_with_metadata(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> Result<
+        (StopCrawlerScheduleResponse, ResponseMetadata),
+        RusotoError<StopCrawlerScheduleError>,
+    > {
+        let request = self.build_stop_crawler_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopCrawlerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopCrawlerScheduleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopCrawlerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopCrawlerScheduleError::from_response(response))
         }
     }
 
-    /// <p>Sets the schedule state of the specified crawler to <code>NOT_SCHEDULED</code>, but does not stop the crawler if it is already running.</p>
-    async fn stop_crawler_schedule(
+    /// Presigns the request `stop_crawler_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_crawler_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopCrawlerScheduleRequest,
-    ) -> Result<StopCrawlerScheduleResponse, RusotoError<StopCrawlerScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_crawler_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_crawler_schedule_request(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StopCrawlerSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StopCrawlerSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_trigger`, also returning the metadata of the response.
+    pub async fn stop_trigger_with_metadata(
+        &self,
+        input: StopTriggerRequest,
+    ) -> Result<(StopTriggerResponse, ResponseMetadata), RusotoError<StopTriggerError>> {
+        let request = self.build_stop_trigger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopCrawlerScheduleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopTriggerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopCrawlerScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopTriggerError::from_response(response))
         }
     }
 
-    /// <p>Stops a specified trigger.</p>
-    async fn stop_trigger(
+    /// Presigns the request `stop_trigger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_trigger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopTriggerRequest,
-    ) -> Result<StopTriggerResponse, RusotoError<StopTriggerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_trigger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_trigger_request(&self, input: StopTriggerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("StopTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.StopTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopTriggerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopTriggerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds tags to a resource. A tag is a label you can assign to an AWS resource. In AWS Glue, you can tag only certain resources. For information about what resources you can tag, see <a href="https://docs.aws.amazon.com/glue/latest/dg/monitor-tags.html">AWS Tags in AWS Glue</a>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_classifier`, also returning the metadata of the response.
+    pub async fn update_classifier_with_metadata(
+        &self,
+        input: UpdateClassifierRequest,
+    ) -> Result<(UpdateClassifierResponse, ResponseMetadata), RusotoError<UpdateClassifierError>>
+    {
+        let request = self.build_update_classifier_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateClassifierResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateClassifierError::from_response(response))
         }
     }
 
-    /// <p>Modifies an existing classifier (a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field is present).</p>
-    async fn update_classifier(
+    /// Presigns the request `update_classifier` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_classifier(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateClassifierRequest,
-    ) -> Result<UpdateClassifierResponse, RusotoError<UpdateClassifierError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_classifier_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_classifier_request(&self, input: UpdateClassifierRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_connection`, also returning the metadata of the response.
+    pub async fn update_connection_with_metadata(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> Result<(UpdateConnectionResponse, ResponseMetadata), RusotoError<UpdateConnectionError>>
+    {
+        let request = self.build_update_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateClassifierResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateConnectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateClassifierError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateConnectionError::from_response(response))
         }
     }
 
-    /// <p>Updates a connection definition in the Data Catalog.</p>
-    async fn update_connection(
+    /// Presigns the request `update_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConnectionRequest,
-    ) -> Result<UpdateConnectionResponse, RusotoError<UpdateConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_connection_request(&self, input: UpdateConnectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_crawler`, also returning the metadata of the response.
+    pub async fn update_crawler_with_metadata(
+        &self,
+        input: UpdateCrawlerRequest,
+    ) -> Result<(UpdateCrawlerResponse, ResponseMetadata), RusotoError<UpdateCrawlerError>> {
+        let request = self.build_update_crawler_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateConnectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateCrawlerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateCrawlerError::from_response(response))
         }
     }
 
-    /// <p>Updates a crawler. If a crawler is running, you must stop it using <code>StopCrawler</code> before updating it.</p>
-    async fn update_crawler(
+    /// Presigns the request `update_crawler` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_crawler(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCrawlerRequest,
-    ) -> Result<UpdateCrawlerResponse, RusotoError<UpdateCrawlerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_crawler_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_crawler_request(&self, input: UpdateCrawlerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateCrawler");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_crawler_schedule`, also returning the metadata of the response.
+    pub async fn update_crawler_schedule_with_metadata(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> Result<
+        (UpdateCrawlerScheduleResponse, ResponseMetadata),
+        RusotoError<UpdateCrawlerScheduleError>,
+    > {
+        let request = self.build_update_crawler_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateCrawlerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateCrawlerScheduleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCrawlerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateCrawlerScheduleError::from_response(response))
         }
     }
 
-    /// <p>Updates the schedule of a crawler using a <code>cron</code> expression. </p>
-    async fn update_crawler_schedule(
+    /// Presigns the request `update_crawler_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_crawler_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCrawlerScheduleRequest,
-    ) -> Result<UpdateCrawlerScheduleResponse, RusotoError<UpdateCrawlerScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_crawler_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_crawler_schedule_request(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateCrawlerSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateCrawlerSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_database`, also returning the metadata of the response.
+    pub async fn update_database_with_metadata(
+        &self,
+        input: UpdateDatabaseRequest,
+    ) -> Result<(UpdateDatabaseResponse, ResponseMetadata), RusotoError<UpdateDatabaseError>> {
+        let request = self.build_update_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateCrawlerScheduleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDatabaseResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCrawlerScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing database definition in a Data Catalog.</p>
-    async fn update_database(
+    /// Presigns the request `update_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDatabaseRequest,
-    ) -> Result<UpdateDatabaseResponse, RusotoError<UpdateDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_database_request(&self, input: UpdateDatabaseRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_dev_endpoint`, also returning the metadata of the response.
+    pub async fn update_dev_endpoint_with_metadata(
+        &self,
+        input: UpdateDevEndpointRequest,
+    ) -> Result<(UpdateDevEndpointResponse, ResponseMetadata), RusotoError<UpdateDevEndpointError>>
+    {
+        let request = self.build_update_dev_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateDatabaseResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDevEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDatabaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDevEndpointError::from_response(response))
         }
     }
 
-    /// <p>Updates a specified development endpoint.</p>
-    async fn update_dev_endpoint(
+    /// Presigns the request `update_dev_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_dev_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDevEndpointRequest,
-    ) -> Result<UpdateDevEndpointResponse, RusotoError<UpdateDevEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_dev_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_dev_endpoint_request(&self, input: UpdateDevEndpointRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateDevEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_job`, also returning the metadata of the response.
+    pub async fn update_job_with_metadata(
+        &self,
+        input: UpdateJobRequest,
+    ) -> Result<(UpdateJobResponse, ResponseMetadata), RusotoError<UpdateJobError>> {
+        let request = self.build_update_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDevEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDevEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateJobError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing job definition.</p>
-    async fn update_job(
+    /// Presigns the request `update_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateJobRequest,
-    ) -> Result<UpdateJobResponse, RusotoError<UpdateJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_job_request(&self, input: UpdateJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_ml_transform`, also returning the metadata of the response.
+    pub async fn update_ml_transform_with_metadata(
+        &self,
+        input: UpdateMLTransformRequest,
+    ) -> Result<(UpdateMLTransformResponse, ResponseMetadata), RusotoError<UpdateMLTransformError>>
+    {
+        let request = self.build_update_ml_transform_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateMLTransformResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateMLTransformError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing machine learning transform. Call this operation to tune the algorithm parameters to achieve better results.</p> <p>After calling this operation, you can call the <code>StartMLEvaluationTaskRun</code> operation to assess how well your new parameters achieved your goals (such as improving the quality of your machine learning transform, or making it more cost-effective).</p>
-    async fn update_ml_transform(
+    /// Presigns the request `update_ml_transform` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_ml_transform(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMLTransformRequest,
-    ) -> Result<UpdateMLTransformResponse, RusotoError<UpdateMLTransformError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_ml_transform_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_ml_transform_request(&self, input: UpdateMLTransformRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateMLTransform");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_partition`, also returning the metadata of the response.
+    pub async fn update_partition_with_metadata(
+        &self,
+        input: UpdatePartitionRequest,
+    ) -> Result<(UpdatePartitionResponse, ResponseMetadata), RusotoError<UpdatePartitionError>>
+    {
+        let request = self.build_update_partition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMLTransformResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePartitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMLTransformError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePartitionError::from_response(response))
         }
     }
 
-    /// <p>Updates a partition.</p>
-    async fn update_partition(
+    /// Presigns the request `update_partition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_partition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePartitionRequest,
-    ) -> Result<UpdatePartitionResponse, RusotoError<UpdatePartitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_partition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_partition_request(&self, input: UpdatePartitionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdatePartition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_table`, also returning the metadata of the response.
+    pub async fn update_table_with_metadata(
+        &self,
+        input: UpdateTableRequest,
+    ) -> Result<(UpdateTableResponse, ResponseMetadata), RusotoError<UpdateTableError>> {
+        let request = self.build_update_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdatePartitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTableResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePartitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTableError::from_response(response))
         }
     }
 
-    /// <p>Updates a metadata table in the Data Catalog.</p>
-    async fn update_table(
+    /// Presigns the request `update_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTableRequest,
-    ) -> Result<UpdateTableResponse, RusotoError<UpdateTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_table_request(&self, input: UpdateTableRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateTable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_trigger`, also returning the metadata of the response.
+    pub async fn update_trigger_with_metadata(
+        &self,
+        input: UpdateTriggerRequest,
+    ) -> Result<(UpdateTriggerResponse, ResponseMetadata), RusotoError<UpdateTriggerError>> {
+        let request = self.build_update_trigger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTableResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTriggerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTriggerError::from_response(response))
         }
     }
 
-    /// <p>Updates a trigger definition.</p>
-    async fn update_trigger(
+    /// Presigns the request `update_trigger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_trigger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTriggerRequest,
-    ) -> Result<UpdateTriggerResponse, RusotoError<UpdateTriggerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_trigger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_trigger_request(&self, input: UpdateTriggerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateTrigger");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_user_defined_function`, also returning the metadata of the response.
+    pub async fn update_user_defined_function_with_metadata(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> Result<
+        (UpdateUserDefinedFunctionResponse, ResponseMetadata),
+        RusotoError<UpdateUserDefinedFunctionError>,
+    > {
+        let request = self.build_update_user_defined_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTriggerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateUserDefinedFunctionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTriggerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUserDefinedFunctionError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing function definition in the Data Catalog.</p>
-    async fn update_user_defined_function(
+    /// Presigns the request `update_user_defined_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_defined_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserDefinedFunctionRequest,
-    ) -> Result<UpdateUserDefinedFunctionResponse, RusotoError<UpdateUserDefinedFunctionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_defined_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_defined_function_request(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateUserDefinedFunction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_workflow`, also returning the metadata of the response.
+    pub async fn update_workflow_with_metadata(
+        &self,
+        input: UpdateWorkflowRequest,
+    ) -> Result<(UpdateWorkflowResponse, ResponseMetadata), RusotoError<UpdateWorkflowError>> {
+        let request = self.build_update_workflow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateUserDefinedFunctionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateWorkflowResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserDefinedFunctionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateWorkflowError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing workflow.</p>
-    async fn update_workflow(
+    /// Presigns the request `update_workflow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_workflow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateWorkflowRequest,
-    ) -> Result<UpdateWorkflowResponse, RusotoError<UpdateWorkflowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_workflow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_workflow_request(&self, input: UpdateWorkflowRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "glue", &self.region, "/");
+        request.set_operation("UpdateWorkflow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSGlue.UpdateWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkflowResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateWorkflowError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `GetClassifiers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_classifiers_pages(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> impl futures::Stream<Item = Result<GetClassifiersResponse, RusotoError<GetClassifiersError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_classifiers(input).await },
+            |input: &GetClassifiersRequest, output: &GetClassifiersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetClassifiersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Classifiers` of every page of `GetClassifiers` results for `input`, as `get_classifiers_pages` requests them.
+    pub fn get_classifiers_stream(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> impl futures::Stream<Item = Result<Classifier, RusotoError<GetClassifiersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_classifiers_pages(input), |page| {
+            page.classifiers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetConnections` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_connections_pages(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> impl futures::Stream<Item = Result<GetConnectionsResponse, RusotoError<GetConnectionsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_connections(input).await },
+            |input: &GetConnectionsRequest, output: &GetConnectionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetConnectionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConnectionList` of every page of `GetConnections` results for `input`, as `get_connections_pages` requests them.
+    pub fn get_connections_stream(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> impl futures::Stream<Item = Result<Connection, RusotoError<GetConnectionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_connections_pages(input), |page| {
+            page.connection_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetCrawlerMetrics` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_crawler_metrics_pages(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetCrawlerMetricsResponse, RusotoError<GetCrawlerMetricsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_crawler_metrics(input).await },
+            |input: &GetCrawlerMetricsRequest, output: &GetCrawlerMetricsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetCrawlerMetricsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CrawlerMetricsList` of every page of `GetCrawlerMetrics` results for `input`, as `get_crawler_metrics_pages` requests them.
+    pub fn get_crawler_metrics_stream(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> impl futures::Stream<Item = Result<CrawlerMetrics, RusotoError<GetCrawlerMetricsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_crawler_metrics_pages(input), |page| {
+            page.crawler_metrics_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetCrawlers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_crawlers_pages(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> impl futures::Stream<Item = Result<GetCrawlersResponse, RusotoError<GetCrawlersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_crawlers(input).await },
+            |input: &GetCrawlersRequest, output: &GetCrawlersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetCrawlersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Crawlers` of every page of `GetCrawlers` results for `input`, as `get_crawlers_pages` requests them.
+    pub fn get_crawlers_stream(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> impl futures::Stream<Item = Result<Crawler, RusotoError<GetCrawlersError>>> + Send {
+        rusoto_core::pagination::items(self.get_crawlers_pages(input), |page| {
+            page.crawlers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDatabases` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_databases_pages(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> impl futures::Stream<Item = Result<GetDatabasesResponse, RusotoError<GetDatabasesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_databases(input).await },
+            |input: &GetDatabasesRequest, output: &GetDatabasesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetDatabasesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DatabaseList` of every page of `GetDatabases` results for `input`, as `get_databases_pages` requests them.
+    pub fn get_databases_stream(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> impl futures::Stream<Item = Result<Database, RusotoError<GetDatabasesError>>> + Send {
+        rusoto_core::pagination::items(self.get_databases_pages(input), |page| page.database_list)
+    }
+
+    /// The pages of `GetDevEndpoints` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_dev_endpoints_pages(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetDevEndpointsResponse, RusotoError<GetDevEndpointsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_dev_endpoints(input).await },
+            |input: &GetDevEndpointsRequest, output: &GetDevEndpointsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetDevEndpointsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DevEndpoints` of every page of `GetDevEndpoints` results for `input`, as `get_dev_endpoints_pages` requests them.
+    pub fn get_dev_endpoints_stream(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> impl futures::Stream<Item = Result<DevEndpoint, RusotoError<GetDevEndpointsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_dev_endpoints_pages(input), |page| {
+            page.dev_endpoints.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetJobRuns` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_job_runs_pages(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> impl futures::Stream<Item = Result<GetJobRunsResponse, RusotoError<GetJobRunsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_job_runs(input).await },
+            |input: &GetJobRunsRequest, output: &GetJobRunsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetJobRunsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `JobRuns` of every page of `GetJobRuns` results for `input`, as `get_job_runs_pages` requests them.
+    pub fn get_job_runs_stream(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> impl futures::Stream<Item = Result<JobRun, RusotoError<GetJobRunsError>>> + Send {
+        rusoto_core::pagination::items(self.get_job_runs_pages(input), |page| {
+            page.job_runs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_jobs_pages(
+        &self,
+        input: GetJobsRequest,
+    ) -> impl futures::Stream<Item = Result<GetJobsResponse, RusotoError<GetJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_jobs(input).await },
+            |input: &GetJobsRequest, output: &GetJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Jobs` of every page of `GetJobs` results for `input`, as `get_jobs_pages` requests them.
+    pub fn get_jobs_stream(
+        &self,
+        input: GetJobsRequest,
+    ) -> impl futures::Stream<Item = Result<Job, RusotoError<GetJobsError>>> + Send {
+        rusoto_core::pagination::items(self.get_jobs_pages(input), |page| {
+            page.jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetPartitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_partitions_pages(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> impl futures::Stream<Item = Result<GetPartitionsResponse, RusotoError<GetPartitionsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_partitions(input).await },
+            |input: &GetPartitionsRequest, output: &GetPartitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetPartitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Partitions` of every page of `GetPartitions` results for `input`, as `get_partitions_pages` requests them.
+    pub fn get_partitions_stream(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> impl futures::Stream<Item = Result<Partition, RusotoError<GetPartitionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_partitions_pages(input), |page| {
+            page.partitions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetSecurityConfigurations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_security_configurations_pages(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetSecurityConfigurationsResponse,
+            RusotoError<GetSecurityConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_security_configurations(input).await },
+            |input: &GetSecurityConfigurationsRequest,
+             output: &GetSecurityConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetSecurityConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SecurityConfigurations` of every page of `GetSecurityConfigurations` results for `input`, as `get_security_configurations_pages` requests them.
+    pub fn get_security_configurations_stream(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SecurityConfiguration, RusotoError<GetSecurityConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_security_configurations_pages(input), |page| {
+            page.security_configurations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetTableVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_table_versions_pages(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetTableVersionsResponse, RusotoError<GetTableVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_table_versions(input).await },
+            |input: &GetTableVersionsRequest, output: &GetTableVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetTableVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TableVersions` of every page of `GetTableVersions` results for `input`, as `get_table_versions_pages` requests them.
+    pub fn get_table_versions_stream(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<TableVersion, RusotoError<GetTableVersionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_table_versions_pages(input), |page| {
+            page.table_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetTables` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_tables_pages(
+        &self,
+        input: GetTablesRequest,
+    ) -> impl futures::Stream<Item = Result<GetTablesResponse, RusotoError<GetTablesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_tables(input).await },
+            |input: &GetTablesRequest, output: &GetTablesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetTablesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TableList` of every page of `GetTables` results for `input`, as `get_tables_pages` requests them.
+    pub fn get_tables_stream(
+        &self,
+        input: GetTablesRequest,
+    ) -> impl futures::Stream<Item = Result<Table, RusotoError<GetTablesError>>> + Send {
+        rusoto_core::pagination::items(self.get_tables_pages(input), |page| {
+            page.table_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetTriggers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_triggers_pages(
+        &self,
+        input: GetTriggersRequest,
+    ) -> impl futures::Stream<Item = Result<GetTriggersResponse, RusotoError<GetTriggersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_triggers(input).await },
+            |input: &GetTriggersRequest, output: &GetTriggersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetTriggersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Triggers` of every page of `GetTriggers` results for `input`, as `get_triggers_pages` requests them.
+    pub fn get_triggers_stream(
+        &self,
+        input: GetTriggersRequest,
+    ) -> impl futures::Stream<Item = Result<Trigger, RusotoError<GetTriggersError>>> + Send {
+        rusoto_core::pagination::items(self.get_triggers_pages(input), |page| {
+            page.triggers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetUserDefinedFunctions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_user_defined_functions_pages(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetUserDefinedFunctionsResponse, RusotoError<GetUserDefinedFunctionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_user_defined_functions(input).await },
+            |input: &GetUserDefinedFunctionsRequest, output: &GetUserDefinedFunctionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetUserDefinedFunctionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UserDefinedFunctions` of every page of `GetUserDefinedFunctions` results for `input`, as `get_user_defined_functions_pages` requests them.
+    pub fn get_user_defined_functions_stream(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<UserDefinedFunction, RusotoError<GetUserDefinedFunctionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_user_defined_functions_pages(input), |page| {
+            page.user_defined_functions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetWorkflowRuns` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_workflow_runs_pages(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetWorkflowRunsResponse, RusotoError<GetWorkflowRunsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.get_workflow_runs(input).await },
+            |input: &GetWorkflowRunsRequest, output: &GetWorkflowRunsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetWorkflowRunsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Runs` of every page of `GetWorkflowRuns` results for `input`, as `get_workflow_runs_pages` requests them.
+    pub fn get_workflow_runs_stream(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> impl futures::Stream<Item = Result<WorkflowRun, RusotoError<GetWorkflowRunsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_workflow_runs_pages(input), |page| {
+            page.runs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResponse, RusotoError<ListJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `JobNames` of every page of `ListJobs` results for `input`, as `list_jobs_pages` requests them.
+    pub fn list_jobs_stream(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListJobsError>>> + Send {
+        rusoto_core::pagination::items(self.list_jobs_pages(input), |page| {
+            page.job_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTriggers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_triggers_pages(
+        &self,
+        input: ListTriggersRequest,
+    ) -> impl futures::Stream<Item = Result<ListTriggersResponse, RusotoError<ListTriggersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.list_triggers(input).await },
+            |input: &ListTriggersRequest, output: &ListTriggersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTriggersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TriggerNames` of every page of `ListTriggers` results for `input`, as `list_triggers_pages` requests them.
+    pub fn list_triggers_stream(
+        &self,
+        input: ListTriggersRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListTriggersError>>> + Send {
+        rusoto_core::pagination::items(self.list_triggers_pages(input), |page| {
+            page.trigger_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListWorkflows` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_workflows_pages(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> impl futures::Stream<Item = Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GlueClient, input| async move { client.list_workflows(input).await },
+            |input: &ListWorkflowsRequest, output: &ListWorkflowsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListWorkflowsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Workflows` of every page of `ListWorkflows` results for `input`, as `list_workflows_pages` requests them.
+    pub fn list_workflows_stream(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListWorkflowsError>>> + Send {
+        rusoto_core::pagination::items(self.list_workflows_pages(input), |page| {
+            page.workflows.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Glue for GlueClient {
+    /// <p>Creates one or more partitions in a batch operation.</p>
+    async fn batch_create_partition(
+        &self,
+        input: BatchCreatePartitionRequest,
+    ) -> Result<BatchCreatePartitionResponse, RusotoError<BatchCreatePartitionError>> {
+        self.batch_create_partition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a list of connection definitions from the Data Catalog.</p>
+    async fn batch_delete_connection(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> Result<BatchDeleteConnectionResponse, RusotoError<BatchDeleteConnectionError>> {
+        self.batch_delete_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes one or more partitions in a batch operation.</p>
+    async fn batch_delete_partition(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> Result<BatchDeletePartitionResponse, RusotoError<BatchDeletePartitionError>> {
+        self.batch_delete_partition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes multiple tables at once.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>BatchDeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
+    async fn batch_delete_table(
+        &self,
+        input: BatchDeleteTableRequest,
+    ) -> Result<BatchDeleteTableResponse, RusotoError<BatchDeleteTableError>> {
+        self.batch_delete_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified batch of versions of a table.</p>
+    async fn batch_delete_table_version(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> Result<BatchDeleteTableVersionResponse, RusotoError<BatchDeleteTableVersionError>> {
+        self.batch_delete_table_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of crawler names. After calling the <code>ListCrawlers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    async fn batch_get_crawlers(
+        &self,
+        input: BatchGetCrawlersRequest,
+    ) -> Result<BatchGetCrawlersResponse, RusotoError<BatchGetCrawlersError>> {
+        self.batch_get_crawlers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of development endpoint names. After calling the <code>ListDevEndpoints</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    async fn batch_get_dev_endpoints(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> Result<BatchGetDevEndpointsResponse, RusotoError<BatchGetDevEndpointsError>> {
+        self.batch_get_dev_endpoints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of job names. After calling the <code>ListJobs</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags. </p>
+    async fn batch_get_jobs(
+        &self,
+        input: BatchGetJobsRequest,
+    ) -> Result<BatchGetJobsResponse, RusotoError<BatchGetJobsError>> {
+        self.batch_get_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves partitions in a batch request.</p>
+    async fn batch_get_partition(
+        &self,
+        input: BatchGetPartitionRequest,
+    ) -> Result<BatchGetPartitionResponse, RusotoError<BatchGetPartitionError>> {
+        self.batch_get_partition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of trigger names. After calling the <code>ListTriggers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    async fn batch_get_triggers(
+        &self,
+        input: BatchGetTriggersRequest,
+    ) -> Result<BatchGetTriggersResponse, RusotoError<BatchGetTriggersError>> {
+        self.batch_get_triggers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of workflow names. After calling the <code>ListWorkflows</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    async fn batch_get_workflows(
+        &self,
+        input: BatchGetWorkflowsRequest,
+    ) -> Result<BatchGetWorkflowsResponse, RusotoError<BatchGetWorkflowsError>> {
+        self.batch_get_workflows_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops one or more job runs for a specified job definition.</p>
+    async fn batch_stop_job_run(
+        &self,
+        input: BatchStopJobRunRequest,
+    ) -> Result<BatchStopJobRunResponse, RusotoError<GlueBatchStopJobRunError>> {
+        self.batch_stop_job_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels (stops) a task run. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can cancel a machine learning task run at any time by calling <code>CancelMLTaskRun</code> with a task run's parent transform's <code>TransformID</code> and the task run's <code>TaskRunId</code>. </p>
+    async fn cancel_ml_task_run(
+        &self,
+        input: CancelMLTaskRunRequest,
+    ) -> Result<CancelMLTaskRunResponse, RusotoError<CancelMLTaskRunError>> {
+        self.cancel_ml_task_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a classifier in the user's account. This can be a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field of the request is present.</p>
+    async fn create_classifier(
+        &self,
+        input: CreateClassifierRequest,
+    ) -> Result<CreateClassifierResponse, RusotoError<CreateClassifierError>> {
+        self.create_classifier_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a connection definition in the Data Catalog.</p>
+    async fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> Result<CreateConnectionResponse, RusotoError<CreateConnectionError>> {
+        self.create_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new crawler with specified targets, role, configuration, and optional schedule. At least one crawl target must be specified, in the <code>s3Targets</code> field, the <code>jdbcTargets</code> field, or the <code>DynamoDBTargets</code> field.</p>
+    async fn create_crawler(
+        &self,
+        input: CreateCrawlerRequest,
+    ) -> Result<CreateCrawlerResponse, RusotoError<CreateCrawlerError>> {
+        self.create_crawler_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new database in a Data Catalog.</p>
+    async fn create_database(
+        &self,
+        input: CreateDatabaseRequest,
+    ) -> Result<CreateDatabaseResponse, RusotoError<CreateDatabaseError>> {
+        self.create_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new development endpoint.</p>
+    async fn create_dev_endpoint(
+        &self,
+        input: CreateDevEndpointRequest,
+    ) -> Result<CreateDevEndpointResponse, RusotoError<CreateDevEndpointError>> {
+        self.create_dev_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new job definition.</p>
+    async fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.create_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an AWS Glue machine learning transform. This operation creates the transform and all the necessary parameters to train it.</p> <p>Call this operation as the first step in the process of using a machine learning transform (such as the <code>FindMatches</code> transform) for deduplicating data. You can provide an optional <code>Description</code>, in addition to the parameters that you want to use for your algorithm.</p> <p>You must also specify certain parameters for the tasks that AWS Glue runs on your behalf as part of learning from your data and creating a high-quality machine learning transform. These parameters include <code>Role</code>, and optionally, <code>AllocatedCapacity</code>, <code>Timeout</code>, and <code>MaxRetries</code>. For more information, see <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-jobs-job.html">Jobs</a>.</p>
+    async fn create_ml_transform(
+        &self,
+        input: CreateMLTransformRequest,
+    ) -> Result<CreateMLTransformResponse, RusotoError<CreateMLTransformError>> {
+        self.create_ml_transform_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new partition.</p>
+    async fn create_partition(
+        &self,
+        input: CreatePartitionRequest,
+    ) -> Result<CreatePartitionResponse, RusotoError<CreatePartitionError>> {
+        self.create_partition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Transforms a directed acyclic graph (DAG) into code.</p>
+    async fn create_script(
+        &self,
+        input: CreateScriptRequest,
+    ) -> Result<CreateScriptResponse, RusotoError<CreateScriptError>> {
+        self.create_script_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new security configuration. A security configuration is a set of security properties that can be used by AWS Glue. You can use a security configuration to encrypt data at rest. For information about using security configurations in AWS Glue, see <a href="https://docs.aws.amazon.com/glue/latest/dg/encryption-security-configuration.html">Encrypting Data Written by Crawlers, Jobs, and Development Endpoints</a>.</p>
+    async fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> Result<CreateSecurityConfigurationResponse, RusotoError<CreateSecurityConfigurationError>>
+    {
+        self.create_security_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new table definition in the Data Catalog.</p>
+    async fn create_table(
+        &self,
+        input: CreateTableRequest,
+    ) -> Result<CreateTableResponse, RusotoError<CreateTableError>> {
+        self.create_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new trigger.</p>
+    async fn create_trigger(
+        &self,
+        input: CreateTriggerRequest,
+    ) -> Result<CreateTriggerResponse, RusotoError<CreateTriggerError>> {
+        self.create_trigger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new function definition in the Data Catalog.</p>
+    async fn create_user_defined_function(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> Result<CreateUserDefinedFunctionResponse, RusotoError<CreateUserDefinedFunctionError>>
+    {
+        self.create_user_defined_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new workflow.</p>
+    async fn create_workflow(
+        &self,
+        input: CreateWorkflowRequest,
+    ) -> Result<CreateWorkflowResponse, RusotoError<CreateWorkflowError>> {
+        self.create_workflow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a classifier from the Data Catalog.</p>
+    async fn delete_classifier(
+        &self,
+        input: DeleteClassifierRequest,
+    ) -> Result<DeleteClassifierResponse, RusotoError<DeleteClassifierError>> {
+        self.delete_classifier_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a connection from the Data Catalog.</p>
+    async fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
+        self.delete_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a specified crawler from the AWS Glue Data Catalog, unless the crawler state is <code>RUNNING</code>.</p>
+    async fn delete_crawler(
+        &self,
+        input: DeleteCrawlerRequest,
+    ) -> Result<DeleteCrawlerResponse, RusotoError<DeleteCrawlerError>> {
+        self.delete_crawler_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes a specified database from a Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the tables (and all table versions and partitions that might belong to the tables) and the user-defined functions in the deleted database. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteDatabase</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, <code>DeletePartition</code> or <code>BatchDeletePartition</code>, <code>DeleteUserDefinedFunction</code>, and <code>DeleteTable</code> or <code>BatchDeleteTable</code>, to delete any resources that belong to the database.</p> </note></p>
+    async fn delete_database(
+        &self,
+        input: DeleteDatabaseRequest,
+    ) -> Result<DeleteDatabaseResponse, RusotoError<DeleteDatabaseError>> {
+        self.delete_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified development endpoint.</p>
+    async fn delete_dev_endpoint(
+        &self,
+        input: DeleteDevEndpointRequest,
+    ) -> Result<DeleteDevEndpointResponse, RusotoError<DeleteDevEndpointError>> {
+        self.delete_dev_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified job definition. If the job definition is not found, no exception is thrown.</p>
+    async fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> Result<DeleteJobResponse, RusotoError<DeleteJobError>> {
+        self.delete_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an AWS Glue machine learning transform. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. If you no longer need a transform, you can delete it by calling <code>DeleteMLTransforms</code>. However, any AWS Glue jobs that still reference the deleted transform will no longer succeed.</p>
+    async fn delete_ml_transform(
+        &self,
+        input: DeleteMLTransformRequest,
+    ) -> Result<DeleteMLTransformResponse, RusotoError<DeleteMLTransformError>> {
+        self.delete_ml_transform_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified partition.</p>
+    async fn delete_partition(
+        &self,
+        input: DeletePartitionRequest,
+    ) -> Result<DeletePartitionResponse, RusotoError<DeletePartitionError>> {
+        self.delete_partition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified policy.</p>
+    async fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
+        self.delete_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified security configuration.</p>
+    async fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> Result<DeleteSecurityConfigurationResponse, RusotoError<DeleteSecurityConfigurationError>>
+    {
+        self.delete_security_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes a table definition from the Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
+    async fn delete_table(
+        &self,
+        input: DeleteTableRequest,
+    ) -> Result<DeleteTableResponse, RusotoError<DeleteTableError>> {
+        self.delete_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified version of a table.</p>
+    async fn delete_table_version(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> Result<DeleteTableVersionResponse, RusotoError<DeleteTableVersionError>> {
+        self.delete_table_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified trigger. If the trigger is not found, no exception is thrown.</p>
+    async fn delete_trigger(
+        &self,
+        input: DeleteTriggerRequest,
+    ) -> Result<DeleteTriggerResponse, RusotoError<DeleteTriggerError>> {
+        self.delete_trigger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing function definition from the Data Catalog.</p>
+    async fn delete_user_defined_function(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> Result<DeleteUserDefinedFunctionResponse, RusotoError<DeleteUserDefinedFunctionError>>
+    {
+        self.delete_user_defined_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a workflow.</p>
+    async fn delete_workflow(
+        &self,
+        input: DeleteWorkflowRequest,
+    ) -> Result<DeleteWorkflowResponse, RusotoError<DeleteWorkflowError>> {
+        self.delete_workflow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the status of a migration operation.</p>
+    async fn get_catalog_import_status(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> Result<GetCatalogImportStatusResponse, RusotoError<GetCatalogImportStatusError>> {
+        self.get_catalog_import_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a classifier by name.</p>
+    async fn get_classifier(
+        &self,
+        input: GetClassifierRequest,
+    ) -> Result<GetClassifierResponse, RusotoError<GetClassifierError>> {
+        self.get_classifier_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all classifier objects in the Data Catalog.</p>
+    async fn get_classifiers(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> Result<GetClassifiersResponse, RusotoError<GetClassifiersError>> {
+        self.get_classifiers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a connection definition from the Data Catalog.</p>
+    async fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
+        self.get_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of connection definitions from the Data Catalog.</p>
+    async fn get_connections(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> Result<GetConnectionsResponse, RusotoError<GetConnectionsError>> {
+        self.get_connections_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves metadata for a specified crawler.</p>
+    async fn get_crawler(
+        &self,
+        input: GetCrawlerRequest,
+    ) -> Result<GetCrawlerResponse, RusotoError<GetCrawlerError>> {
+        self.get_crawler_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves metrics about specified crawlers.</p>
+    async fn get_crawler_metrics(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> Result<GetCrawlerMetricsResponse, RusotoError<GetCrawlerMetricsError>> {
+        self.get_crawler_metrics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves metadata for all crawlers defined in the customer account.</p>
+    async fn get_crawlers(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> Result<GetCrawlersResponse, RusotoError<GetCrawlersError>> {
+        self.get_crawlers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the security configuration for a specified catalog.</p>
+    async fn get_data_catalog_encryption_settings(
+        &self,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> Result<
+        GetDataCatalogEncryptionSettingsResponse,
+        RusotoError<GetDataCatalogEncryptionSettingsError>,
+    > {
+        self.get_data_catalog_encryption_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the definition of a specified database.</p>
+    async fn get_database(
+        &self,
+        input: GetDatabaseRequest,
+    ) -> Result<GetDatabaseResponse, RusotoError<GetDatabaseError>> {
+        self.get_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves all databases defined in a given Data Catalog.</p>
+    async fn get_databases(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> Result<GetDatabasesResponse, RusotoError<GetDatabasesError>> {
+        self.get_databases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Transforms a Python script into a directed acyclic graph (DAG). </p>
+    async fn get_dataflow_graph(
+        &self,
+        input: GetDataflowGraphRequest,
+    ) -> Result<GetDataflowGraphResponse, RusotoError<GetDataflowGraphError>> {
+        self.get_dataflow_graph_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves information about a specified development endpoint.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address, and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
+    async fn get_dev_endpoint(
+        &self,
+        input: GetDevEndpointRequest,
+    ) -> Result<GetDevEndpointResponse, RusotoError<GetDevEndpointError>> {
+        self.get_dev_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves all the development endpoints in this AWS account.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
+    async fn get_dev_endpoints(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> Result<GetDevEndpointsResponse, RusotoError<GetDevEndpointsError>> {
+        self.get_dev_endpoints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves an existing job definition.</p>
+    async fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+        self.get_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information on a job bookmark entry.</p>
+    async fn get_job_bookmark(
+        &self,
+        input: GetJobBookmarkRequest,
+    ) -> Result<GetJobBookmarkResponse, RusotoError<GetJobBookmarkError>> {
+        self.get_job_bookmark_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the metadata for a given job run.</p>
+    async fn get_job_run(
+        &self,
+        input: GetJobRunRequest,
+    ) -> Result<GetJobRunResponse, RusotoError<GetJobRunError>> {
+        self.get_job_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves metadata for all runs of a given job definition.</p>
+    async fn get_job_runs(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> Result<GetJobRunsResponse, RusotoError<GetJobRunsError>> {
+        self.get_job_runs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves all current job definitions.</p>
+    async fn get_jobs(
+        &self,
+        input: GetJobsRequest,
+    ) -> Result<GetJobsResponse, RusotoError<GetJobsError>> {
+        self.get_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details for a specific task run on a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can check the stats of any task run by calling <code>GetMLTaskRun</code> with the <code>TaskRunID</code> and its parent transform's <code>TransformID</code>.</p>
+    async fn get_ml_task_run(
+        &self,
+        input: GetMLTaskRunRequest,
+    ) -> Result<GetMLTaskRunResponse, RusotoError<GetMLTaskRunError>> {
+        self.get_ml_task_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of runs for a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can get a sortable, filterable list of machine learning task runs by calling <code>GetMLTaskRuns</code> with their parent transform's <code>TransformID</code> and other optional parameters as documented in this section.</p> <p>This operation returns a list of historic runs and must be paginated.</p>
+    async fn get_ml_task_runs(
+        &self,
+        input: GetMLTaskRunsRequest,
+    ) -> Result<GetMLTaskRunsResponse, RusotoError<GetMLTaskRunsError>> {
+        self.get_ml_task_runs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an AWS Glue machine learning transform artifact and all its corresponding metadata. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. You can retrieve their metadata by calling <code>GetMLTransform</code>.</p>
+    async fn get_ml_transform(
+        &self,
+        input: GetMLTransformRequest,
+    ) -> Result<GetMLTransformResponse, RusotoError<GetMLTransformError>> {
+        self.get_ml_transform_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a sortable, filterable list of existing AWS Glue machine learning transforms. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue, and you can retrieve their metadata by calling <code>GetMLTransforms</code>.</p>
+    async fn get_ml_transforms(
+        &self,
+        input: GetMLTransformsRequest,
+    ) -> Result<GetMLTransformsResponse, RusotoError<GetMLTransformsError>> {
+        self.get_ml_transforms_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates mappings.</p>
+    async fn get_mapping(
+        &self,
+        input: GetMappingRequest,
+    ) -> Result<GetMappingResponse, RusotoError<GetMappingError>> {
+        self.get_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a specified partition.</p>
+    async fn get_partition(
+        &self,
+        input: GetPartitionRequest,
+    ) -> Result<GetPartitionResponse, RusotoError<GetPartitionError>> {
+        self.get_partition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about the partitions in a table.</p>
+    async fn get_partitions(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> Result<GetPartitionsResponse, RusotoError<GetPartitionsError>> {
+        self.get_partitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets code to perform a specified mapping.</p>
+    async fn get_plan(
+        &self,
+        input: GetPlanRequest,
+    ) -> Result<GetPlanResponse, RusotoError<GetPlanError>> {
+        self.get_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a specified resource policy.</p>
+    async fn get_resource_policy(
+        &self,
+    ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
+        self.get_resource_policy_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a specified security configuration.</p>
+    async fn get_security_configuration(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> Result<GetSecurityConfigurationResponse, RusotoError<GetSecurityConfigurationError>> {
+        self.get_security_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of all security configurations.</p>
+    async fn get_security_configurations(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> Result<GetSecurityConfigurationsResponse, RusotoError<GetSecurityConfigurationsError>>
+    {
+        self.get_security_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the <code>Table</code> definition in a Data Catalog for a specified table.</p>
+    async fn get_table(
+        &self,
+        input: GetTableRequest,
+    ) -> Result<GetTableResponse, RusotoError<GetTableError>> {
+        self.get_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a specified version of a table.</p>
+    async fn get_table_version(
+        &self,
+        input: GetTableVersionRequest,
+    ) -> Result<GetTableVersionResponse, RusotoError<GetTableVersionError>> {
+        self.get_table_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of strings that identify available versions of a specified table.</p>
+    async fn get_table_versions(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> Result<GetTableVersionsResponse, RusotoError<GetTableVersionsError>> {
+        self.get_table_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the definitions of some or all of the tables in a given <code>Database</code>.</p>
+    async fn get_tables(
+        &self,
+        input: GetTablesRequest,
+    ) -> Result<GetTablesResponse, RusotoError<GetTablesError>> {
+        self.get_tables_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of tags associated with a resource.</p>
+    async fn get_tags(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+        self.get_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the definition of a trigger.</p>
+    async fn get_trigger(
+        &self,
+        input: GetTriggerRequest,
+    ) -> Result<GetTriggerResponse, RusotoError<GetTriggerError>> {
+        self.get_trigger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets all the triggers associated with a job.</p>
+    async fn get_triggers(
+        &self,
+        input: GetTriggersRequest,
+    ) -> Result<GetTriggersResponse, RusotoError<GetTriggersError>> {
+        self.get_triggers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a specified function definition from the Data Catalog.</p>
+    async fn get_user_defined_function(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> Result<GetUserDefinedFunctionResponse, RusotoError<GetUserDefinedFunctionError>> {
+        self.get_user_defined_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves multiple function definitions from the Data Catalog.</p>
+    async fn get_user_defined_functions(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> Result<GetUserDefinedFunctionsResponse, RusotoError<GetUserDefinedFunctionsError>> {
+        self.get_user_defined_functions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves resource metadata for a workflow.</p>
+    async fn get_workflow(
+        &self,
+        input: GetWorkflowRequest,
+    ) -> Result<GetWorkflowResponse, RusotoError<GetWorkflowError>> {
+        self.get_workflow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the metadata for a given workflow run. </p>
+    async fn get_workflow_run(
+        &self,
+        input: GetWorkflowRunRequest,
+    ) -> Result<GetWorkflowRunResponse, RusotoError<GetWorkflowRunError>> {
+        self.get_workflow_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the workflow run properties which were set during the run.</p>
+    async fn get_workflow_run_properties(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> Result<GetWorkflowRunPropertiesResponse, RusotoError<GetWorkflowRunPropertiesError>> {
+        self.get_workflow_run_properties_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves metadata for all runs of a given workflow.</p>
+    async fn get_workflow_runs(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> Result<GetWorkflowRunsResponse, RusotoError<GetWorkflowRunsError>> {
+        self.get_workflow_runs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Imports an existing Amazon Athena Data Catalog to AWS Glue</p>
+    async fn import_catalog_to_glue(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> Result<ImportCatalogToGlueResponse, RusotoError<ImportCatalogToGlueError>> {
+        self.import_catalog_to_glue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the names of all crawler resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    async fn list_crawlers(
+        &self,
+        input: ListCrawlersRequest,
+    ) -> Result<ListCrawlersResponse, RusotoError<ListCrawlersError>> {
+        self.list_crawlers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the names of all <code>DevEndpoint</code> resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    async fn list_dev_endpoints(
+        &self,
+        input: ListDevEndpointsRequest,
+    ) -> Result<ListDevEndpointsResponse, RusotoError<ListDevEndpointsError>> {
+        self.list_dev_endpoints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the names of all job resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    async fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.list_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the names of all trigger resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    async fn list_triggers(
+        &self,
+        input: ListTriggersRequest,
+    ) -> Result<ListTriggersResponse, RusotoError<ListTriggersError>> {
+        self.list_triggers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists names of workflows created in the account.</p>
+    async fn list_workflows(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>> {
+        self.list_workflows_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the security configuration for a specified catalog. After the configuration has been set, the specified encryption is applied to every catalog write thereafter.</p>
+    async fn put_data_catalog_encryption_settings(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> Result<
+        PutDataCatalogEncryptionSettingsResponse,
+        RusotoError<PutDataCatalogEncryptionSettingsError>,
+    > {
+        self.put_data_catalog_encryption_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the Data Catalog resource policy for access control.</p>
+    async fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.put_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Puts the specified workflow run properties for the given workflow run. If a property already exists for the specified run, then it overrides the value otherwise adds the property to existing properties.</p>
+    async fn put_workflow_run_properties(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> Result<PutWorkflowRunPropertiesResponse, RusotoError<PutWorkflowRunPropertiesError>> {
+        self.put_workflow_run_properties_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Resets a bookmark entry.</p>
+    async fn reset_job_bookmark(
+        &self,
+        input: ResetJobBookmarkRequest,
+    ) -> Result<ResetJobBookmarkResponse, RusotoError<ResetJobBookmarkError>> {
+        self.reset_job_bookmark_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches a set of tables based on properties in the table metadata as well as on the parent database. You can search against text or filter conditions. </p> <p>You can only get tables that you have access to based on the security policies defined in Lake Formation. You need at least a read-only access to the table for it to be returned. If you do not have access to all the columns in the table, these columns will not be searched against when returning the list of tables back to you. If you have access to the columns but not the data in the columns, those columns and the associated metadata for those columns will be included in the search. </p>
+    async fn search_tables(
+        &self,
+        input: SearchTablesRequest,
+    ) -> Result<SearchTablesResponse, RusotoError<SearchTablesError>> {
+        self.search_tables_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a crawl using the specified crawler, regardless of what is scheduled. If the crawler is already running, returns a <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-exceptions.html#aws-glue-api-exceptions-CrawlerRunningException">CrawlerRunningException</a>.</p>
+    async fn start_crawler(
+        &self,
+        input: StartCrawlerRequest,
+    ) -> Result<StartCrawlerResponse, RusotoError<StartCrawlerError>> {
+        self.start_crawler_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the schedule state of the specified crawler to <code>SCHEDULED</code>, unless the crawler is already running or the schedule state is already <code>SCHEDULED</code>.</p>
+    async fn start_crawler_schedule(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> Result<StartCrawlerScheduleResponse, RusotoError<StartCrawlerScheduleError>> {
+        self.start_crawler_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Begins an asynchronous task to export all labeled data for a particular transform. This task is the only label-related API call that is not part of the typical active learning workflow. You typically use <code>StartExportLabelsTaskRun</code> when you want to work with all of your existing labels at the same time, such as when you want to remove or change labels that were previously submitted as truth. This API operation accepts the <code>TransformId</code> whose labels you want to export and an Amazon Simple Storage Service (Amazon S3) path to export the labels to. The operation returns a <code>TaskRunId</code>. You can check on the status of your task run by calling the <code>GetMLTaskRun</code> API.</p>
+    async fn start_export_labels_task_run(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> Result<StartExportLabelsTaskRunResponse, RusotoError<StartExportLabelsTaskRunError>> {
+        self.start_export_labels_task_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables you to provide additional labels (examples of truth) to be used to teach the machine learning transform and improve its quality. This API operation is generally used as part of the active learning workflow that starts with the <code>StartMLLabelingSetGenerationTaskRun</code> call and that ultimately results in improving the quality of your machine learning transform. </p> <p>After the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue machine learning will have generated a series of questions for humans to answer. (Answering these questions is often called 'labeling' in the machine learning workflows). In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” After the labeling process is finished, users upload their answers/labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform use the new and improved labels and perform a higher-quality transformation.</p> <p>By default, <code>StartMLLabelingSetGenerationTaskRun</code> continually learns from and combines all labels that you upload unless you set <code>Replace</code> to true. If you set <code>Replace</code> to true, <code>StartImportLabelsTaskRun</code> deletes and forgets all previously uploaded labels and learns only from the exact set that you upload. Replacing labels can be helpful if you realize that you previously uploaded incorrect labels, and you believe that they are having a negative effect on your transform quality.</p> <p>You can check on the status of your task run by calling the <code>GetMLTaskRun</code> operation. </p>
+    async fn start_import_labels_task_run(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> Result<StartImportLabelsTaskRunResponse, RusotoError<StartImportLabelsTaskRunError>> {
+        self.start_import_labels_task_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a job run using a job definition.</p>
+    async fn start_job_run(
+        &self,
+        input: StartJobRunRequest,
+    ) -> Result<StartJobRunResponse, RusotoError<StartJobRunError>> {
+        self.start_job_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a task to estimate the quality of the transform. </p> <p>When you provide label sets as examples of truth, AWS Glue machine learning uses some of those examples to learn from them. The rest of the labels are used as a test to estimate quality.</p> <p>Returns a unique identifier for the run. You can call <code>GetMLTaskRun</code> to get more information about the stats of the <code>EvaluationTaskRun</code>.</p>
+    async fn start_ml_evaluation_task_run(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> Result<StartMLEvaluationTaskRunResponse, RusotoError<StartMLEvaluationTaskRunError>> {
+        self.start_ml_evaluation_task_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the active learning workflow for your machine learning transform to improve the transform's quality by generating label sets and adding labels.</p> <p>When the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue will have generated a "labeling set" or a set of questions for humans to answer.</p> <p>In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” </p> <p>After the labeling process is finished, you can upload your labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform will use the new and improved labels and perform a higher-quality transformation.</p>
+    async fn start_ml_labeling_set_generation_task_run(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> Result<
+        StartMLLabelingSetGenerationTaskRunResponse,
+        RusotoError<StartMLLabelingSetGenerationTaskRunError>,
+    > {
+        self.start_ml_labeling_set_generation_task_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an existing trigger. See <a href="https://docs.aws.amazon.com/glue/latest/dg/trigger-job.html">Triggering Jobs</a> for information about how different types of trigger are started.</p>
+    async fn start_trigger(
+        &self,
+        input: StartTriggerRequest,
+    ) -> Result<StartTriggerResponse, RusotoError<StartTriggerError>> {
+        self.start_trigger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a new run of the specified workflow.</p>
+    async fn start_workflow_run(
+        &self,
+        input: StartWorkflowRunRequest,
+    ) -> Result<StartWorkflowRunResponse, RusotoError<StartWorkflowRunError>> {
+        self.start_workflow_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>If the specified crawler is running, stops the crawl.</p>
+    async fn stop_crawler(
+        &self,
+        input: StopCrawlerRequest,
+    ) -> Result<StopCrawlerResponse, RusotoError<StopCrawlerError>> {
+        self.stop_crawler_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the schedule state of the specified crawler to <code>NOT_SCHEDULED</code>, but does not stop the crawler if it is already running.</p>
+    async fn stop_crawler_schedule(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> Result<StopCrawlerScheduleResponse, RusotoError<StopCrawlerScheduleError>> {
+        self.stop_crawler_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a specified trigger.</p>
+    async fn stop_trigger(
+        &self,
+        input: StopTriggerRequest,
+    ) -> Result<StopTriggerResponse, RusotoError<StopTriggerError>> {
+        self.stop_trigger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tags to a resource. A tag is a label you can assign to an AWS resource. In AWS Glue, you can tag only certain resources. For information about what resources you can tag, see <a href="https://docs.aws.amazon.com/glue/latest/dg/monitor-tags.html">AWS Tags in AWS Glue</a>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an existing classifier (a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field is present).</p>
+    async fn update_classifier(
+        &self,
+        input: UpdateClassifierRequest,
+    ) -> Result<UpdateClassifierResponse, RusotoError<UpdateClassifierError>> {
+        self.update_classifier_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a connection definition in the Data Catalog.</p>
+    async fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> Result<UpdateConnectionResponse, RusotoError<UpdateConnectionError>> {
+        self.update_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a crawler. If a crawler is running, you must stop it using <code>StopCrawler</code> before updating it.</p>
+    async fn update_crawler(
+        &self,
+        input: UpdateCrawlerRequest,
+    ) -> Result<UpdateCrawlerResponse, RusotoError<UpdateCrawlerError>> {
+        self.update_crawler_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the schedule of a crawler using a <code>cron</code> expression. </p>
+    async fn update_crawler_schedule(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> Result<UpdateCrawlerScheduleResponse, RusotoError<UpdateCrawlerScheduleError>> {
+        self.update_crawler_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing database definition in a Data Catalog.</p>
+    async fn update_database(
+        &self,
+        input: UpdateDatabaseRequest,
+    ) -> Result<UpdateDatabaseResponse, RusotoError<UpdateDatabaseError>> {
+        self.update_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a specified development endpoint.</p>
+    async fn update_dev_endpoint(
+        &self,
+        input: UpdateDevEndpointRequest,
+    ) -> Result<UpdateDevEndpointResponse, RusotoError<UpdateDevEndpointError>> {
+        self.update_dev_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing job definition.</p>
+    async fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> Result<UpdateJobResponse, RusotoError<UpdateJobError>> {
+        self.update_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing machine learning transform. Call this operation to tune the algorithm parameters to achieve better results.</p> <p>After calling this operation, you can call the <code>StartMLEvaluationTaskRun</code> operation to assess how well your new parameters achieved your goals (such as improving the quality of your machine learning transform, or making it more cost-effective).</p>
+    async fn update_ml_transform(
+        &self,
+        input: UpdateMLTransformRequest,
+    ) -> Result<UpdateMLTransformResponse, RusotoError<UpdateMLTransformError>> {
+        self.update_ml_transform_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a partition.</p>
+    async fn update_partition(
+        &self,
+        input: UpdatePartitionRequest,
+    ) -> Result<UpdatePartitionResponse, RusotoError<UpdatePartitionError>> {
+        self.update_partition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a metadata table in the Data Catalog.</p>
+    async fn update_table(
+        &self,
+        input: UpdateTableRequest,
+    ) -> Result<UpdateTableResponse, RusotoError<UpdateTableError>> {
+        self.update_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a trigger definition.</p>
+    async fn update_trigger(
+        &self,
+        input: UpdateTriggerRequest,
+    ) -> Result<UpdateTriggerResponse, RusotoError<UpdateTriggerError>> {
+        self.update_trigger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing function definition in the Data Catalog.</p>
+    async fn update_user_defined_function(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> Result<UpdateUserDefinedFunctionResponse, RusotoError<UpdateUserDefinedFunctionError>>
+    {
+        self.update_user_defined_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing workflow.</p>
+    async fn update_workflow(
+        &self,
+        input: UpdateWorkflowRequest,
+    ) -> Result<UpdateWorkflowResponse, RusotoError<UpdateWorkflowError>> {
+        self.update_workflow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
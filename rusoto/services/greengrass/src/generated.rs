@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3450,11 +3450,14 @@ pub struct VersionInformation {
 
 /// Errors returned by AssociateRoleToGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateRoleToGroupError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateRoleToGroupError {
@@ -3462,15 +3465,19 @@ impl AssociateRoleToGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociateRoleToGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        AssociateRoleToGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AssociateRoleToGroupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateRoleToGroupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateRoleToGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3482,17 +3489,21 @@ impl fmt::Display for AssociateRoleToGroupError {
         match *self {
             AssociateRoleToGroupError::BadRequest(ref cause) => write!(f, "{}", cause),
             AssociateRoleToGroupError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            AssociateRoleToGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateRoleToGroupError {}
 /// Errors returned by AssociateServiceRoleToAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateServiceRoleToAccountError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateServiceRoleToAccountError {
@@ -3502,17 +3513,24 @@ impl AssociateServiceRoleToAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociateServiceRoleToAccountError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateServiceRoleToAccountError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceRoleToAccountError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateServiceRoleToAccountError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3526,15 +3544,21 @@ impl fmt::Display for AssociateServiceRoleToAccountError {
             AssociateServiceRoleToAccountError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateServiceRoleToAccountError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateServiceRoleToAccountError {}
 /// Errors returned by CreateConnectorDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConnectorDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConnectorDefinitionError {
@@ -3542,12 +3566,15 @@ impl CreateConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConnectorDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConnectorDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateConnectorDefinitionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3558,15 +3585,19 @@ impl fmt::Display for CreateConnectorDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateConnectorDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateConnectorDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConnectorDefinitionError {}
 /// Errors returned by CreateConnectorDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConnectorDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConnectorDefinitionVersionError {
@@ -3576,12 +3607,18 @@ impl CreateConnectorDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConnectorDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConnectorDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateConnectorDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3592,15 +3629,21 @@ impl fmt::Display for CreateConnectorDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateConnectorDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateConnectorDefinitionVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateConnectorDefinitionVersionError {}
 /// Errors returned by CreateCoreDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCoreDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCoreDefinitionError {
@@ -3608,10 +3651,13 @@ impl CreateCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateCoreDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCoreDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3622,15 +3668,19 @@ impl fmt::Display for CreateCoreDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateCoreDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateCoreDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCoreDefinitionError {}
 /// Errors returned by CreateCoreDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCoreDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCoreDefinitionVersionError {
@@ -3640,12 +3690,18 @@ impl CreateCoreDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCoreDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCoreDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateCoreDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3656,15 +3712,19 @@ impl fmt::Display for CreateCoreDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateCoreDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateCoreDefinitionVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCoreDefinitionVersionError {}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentError {
@@ -3672,10 +3732,10 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(CreateDeploymentError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3686,15 +3746,19 @@ impl fmt::Display for CreateDeploymentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateDeploymentError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentError {}
 /// Errors returned by CreateDeviceDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeviceDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeviceDefinitionError {
@@ -3702,10 +3766,13 @@ impl CreateDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateDeviceDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeviceDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3716,15 +3783,19 @@ impl fmt::Display for CreateDeviceDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateDeviceDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateDeviceDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeviceDefinitionError {}
 /// Errors returned by CreateDeviceDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeviceDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeviceDefinitionVersionError {
@@ -3734,12 +3805,18 @@ impl CreateDeviceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeviceDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeviceDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateDeviceDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3750,15 +3827,21 @@ impl fmt::Display for CreateDeviceDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateDeviceDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateDeviceDefinitionVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateDeviceDefinitionVersionError {}
 /// Errors returned by CreateFunctionDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFunctionDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFunctionDefinitionError {
@@ -3766,10 +3849,13 @@ impl CreateFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateFunctionDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFunctionDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3780,15 +3866,19 @@ impl fmt::Display for CreateFunctionDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateFunctionDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateFunctionDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFunctionDefinitionError {}
 /// Errors returned by CreateFunctionDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFunctionDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFunctionDefinitionVersionError {
@@ -3798,12 +3888,18 @@ impl CreateFunctionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFunctionDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFunctionDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateFunctionDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3814,15 +3910,21 @@ impl fmt::Display for CreateFunctionDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateFunctionDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateFunctionDefinitionVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateFunctionDefinitionVersionError {}
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGroupError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGroupError {
@@ -3830,10 +3932,10 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupError::BadRequest(err.msg))
+                    return RusotoError::service(CreateGroupError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3844,17 +3946,21 @@ impl fmt::Display for CreateGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateGroupError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGroupError {}
 /// Errors returned by CreateGroupCertificateAuthority
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGroupCertificateAuthorityError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGroupCertificateAuthorityError {
@@ -3864,17 +3970,24 @@ impl CreateGroupCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupCertificateAuthorityError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGroupCertificateAuthorityError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateGroupCertificateAuthorityError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateGroupCertificateAuthorityError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3888,15 +4001,21 @@ impl fmt::Display for CreateGroupCertificateAuthorityError {
             CreateGroupCertificateAuthorityError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateGroupCertificateAuthorityError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateGroupCertificateAuthorityError {}
 /// Errors returned by CreateGroupVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGroupVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGroupVersionError {
@@ -3904,10 +4023,10 @@ impl CreateGroupVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupVersionError::BadRequest(err.msg))
+                    return RusotoError::service(CreateGroupVersionError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGroupVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3918,15 +4037,19 @@ impl fmt::Display for CreateGroupVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateGroupVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateGroupVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGroupVersionError {}
 /// Errors returned by CreateLoggerDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLoggerDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLoggerDefinitionError {
@@ -3934,10 +4057,13 @@ impl CreateLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateLoggerDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLoggerDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3948,15 +4074,19 @@ impl fmt::Display for CreateLoggerDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateLoggerDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateLoggerDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLoggerDefinitionError {}
 /// Errors returned by CreateLoggerDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLoggerDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLoggerDefinitionVersionError {
@@ -3966,12 +4096,18 @@ impl CreateLoggerDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateLoggerDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLoggerDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateLoggerDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3982,15 +4118,21 @@ impl fmt::Display for CreateLoggerDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateLoggerDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateLoggerDefinitionVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateLoggerDefinitionVersionError {}
 /// Errors returned by CreateResourceDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResourceDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResourceDefinitionError {
@@ -3998,10 +4140,13 @@ impl CreateResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateResourceDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateResourceDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4012,15 +4157,19 @@ impl fmt::Display for CreateResourceDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateResourceDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateResourceDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateResourceDefinitionError {}
 /// Errors returned by CreateResourceDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResourceDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResourceDefinitionVersionError {
@@ -4030,12 +4179,18 @@ impl CreateResourceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateResourceDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateResourceDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4046,17 +4201,23 @@ impl fmt::Display for CreateResourceDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateResourceDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateResourceDefinitionVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateResourceDefinitionVersionError {}
 /// Errors returned by CreateSoftwareUpdateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSoftwareUpdateJobError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSoftwareUpdateJobError {
@@ -4064,15 +4225,19 @@ impl CreateSoftwareUpdateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSoftwareUpdateJobError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateSoftwareUpdateJobError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateSoftwareUpdateJobError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSoftwareUpdateJobError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSoftwareUpdateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4084,15 +4249,19 @@ impl fmt::Display for CreateSoftwareUpdateJobError {
         match *self {
             CreateSoftwareUpdateJobError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateSoftwareUpdateJobError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateSoftwareUpdateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSoftwareUpdateJobError {}
 /// Errors returned by CreateSubscriptionDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSubscriptionDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSubscriptionDefinitionError {
@@ -4102,12 +4271,18 @@ impl CreateSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSubscriptionDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSubscriptionDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateSubscriptionDefinitionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4118,15 +4293,19 @@ impl fmt::Display for CreateSubscriptionDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateSubscriptionDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            CreateSubscriptionDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSubscriptionDefinitionError {}
 /// Errors returned by CreateSubscriptionDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSubscriptionDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSubscriptionDefinitionVersionError {
@@ -4136,12 +4315,18 @@ impl CreateSubscriptionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSubscriptionDefinitionVersionError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateSubscriptionDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4154,15 +4339,21 @@ impl fmt::Display for CreateSubscriptionDefinitionVersionError {
             CreateSubscriptionDefinitionVersionError::BadRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateSubscriptionDefinitionVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateSubscriptionDefinitionVersionError {}
 /// Errors returned by DeleteConnectorDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConnectorDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConnectorDefinitionError {
@@ -4170,12 +4361,15 @@ impl DeleteConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConnectorDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConnectorDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteConnectorDefinitionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4186,15 +4380,19 @@ impl fmt::Display for DeleteConnectorDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteConnectorDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            DeleteConnectorDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConnectorDefinitionError {}
 /// Errors returned by DeleteCoreDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCoreDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCoreDefinitionError {
@@ -4202,10 +4400,13 @@ impl DeleteCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteCoreDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCoreDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4216,15 +4417,19 @@ impl fmt::Display for DeleteCoreDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteCoreDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            DeleteCoreDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCoreDefinitionError {}
 /// Errors returned by DeleteDeviceDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeviceDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeviceDefinitionError {
@@ -4232,10 +4437,13 @@ impl DeleteDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteDeviceDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeviceDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4246,15 +4454,19 @@ impl fmt::Display for DeleteDeviceDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteDeviceDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            DeleteDeviceDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeviceDefinitionError {}
 /// Errors returned by DeleteFunctionDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFunctionDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFunctionDefinitionError {
@@ -4262,10 +4474,13 @@ impl DeleteFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteFunctionDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFunctionDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4276,15 +4491,19 @@ impl fmt::Display for DeleteFunctionDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteFunctionDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            DeleteFunctionDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFunctionDefinitionError {}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGroupError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGroupError {
@@ -4292,10 +4511,10 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGroupError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteGroupError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4306,15 +4525,19 @@ impl fmt::Display for DeleteGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteGroupError::BadRequest(ref cause) => write!(f, "{}", cause),
+            DeleteGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGroupError {}
 /// Errors returned by DeleteLoggerDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLoggerDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLoggerDefinitionError {
@@ -4322,10 +4545,13 @@ impl DeleteLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteLoggerDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLoggerDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4336,15 +4562,19 @@ impl fmt::Display for DeleteLoggerDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteLoggerDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            DeleteLoggerDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLoggerDefinitionError {}
 /// Errors returned by DeleteResourceDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourceDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourceDefinitionError {
@@ -4352,10 +4582,13 @@ impl DeleteResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteResourceDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourceDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4366,15 +4599,19 @@ impl fmt::Display for DeleteResourceDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteResourceDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            DeleteResourceDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourceDefinitionError {}
 /// Errors returned by DeleteSubscriptionDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSubscriptionDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSubscriptionDefinitionError {
@@ -4384,12 +4621,18 @@ impl DeleteSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSubscriptionDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSubscriptionDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteSubscriptionDefinitionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4400,17 +4643,21 @@ impl fmt::Display for DeleteSubscriptionDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteSubscriptionDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            DeleteSubscriptionDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSubscriptionDefinitionError {}
 /// Errors returned by DisassociateRoleFromGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateRoleFromGroupError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateRoleFromGroupError {
@@ -4418,17 +4665,21 @@ impl DisassociateRoleFromGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateRoleFromGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateRoleFromGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateRoleFromGroupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DisassociateRoleFromGroupError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4442,15 +4693,19 @@ impl fmt::Display for DisassociateRoleFromGroupError {
             DisassociateRoleFromGroupError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateRoleFromGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateRoleFromGroupError {}
 /// Errors returned by DisassociateServiceRoleFromAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateServiceRoleFromAccountError {
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateServiceRoleFromAccountError {
@@ -4460,12 +4715,18 @@ impl DisassociateServiceRoleFromAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateServiceRoleFromAccountError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateServiceRoleFromAccountError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4478,17 +4739,23 @@ impl fmt::Display for DisassociateServiceRoleFromAccountError {
             DisassociateServiceRoleFromAccountError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateServiceRoleFromAccountError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateServiceRoleFromAccountError {}
 /// Errors returned by GetAssociatedRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAssociatedRoleError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAssociatedRoleError {
@@ -4496,15 +4763,16 @@ impl GetAssociatedRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAssociatedRoleError::BadRequest(err.msg))
+                    return RusotoError::service(GetAssociatedRoleError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetAssociatedRoleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAssociatedRoleError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAssociatedRoleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4516,15 +4784,19 @@ impl fmt::Display for GetAssociatedRoleError {
         match *self {
             GetAssociatedRoleError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetAssociatedRoleError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetAssociatedRoleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAssociatedRoleError {}
 /// Errors returned by GetBulkDeploymentStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBulkDeploymentStatusError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBulkDeploymentStatusError {
@@ -4532,10 +4804,13 @@ impl GetBulkDeploymentStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBulkDeploymentStatusError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetBulkDeploymentStatusError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBulkDeploymentStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4546,17 +4821,21 @@ impl fmt::Display for GetBulkDeploymentStatusError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetBulkDeploymentStatusError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetBulkDeploymentStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBulkDeploymentStatusError {}
 /// Errors returned by GetConnectivityInfo
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConnectivityInfoError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConnectivityInfoError {
@@ -4564,15 +4843,19 @@ impl GetConnectivityInfoError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConnectivityInfoError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetConnectivityInfoError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetConnectivityInfoError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetConnectivityInfoError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConnectivityInfoError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4584,15 +4867,19 @@ impl fmt::Display for GetConnectivityInfoError {
         match *self {
             GetConnectivityInfoError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetConnectivityInfoError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetConnectivityInfoError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConnectivityInfoError {}
 /// Errors returned by GetConnectorDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConnectorDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConnectorDefinitionError {
@@ -4600,10 +4887,13 @@ impl GetConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConnectorDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetConnectorDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConnectorDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4614,15 +4904,19 @@ impl fmt::Display for GetConnectorDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetConnectorDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetConnectorDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConnectorDefinitionError {}
 /// Errors returned by GetConnectorDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConnectorDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConnectorDefinitionVersionError {
@@ -4632,12 +4926,18 @@ impl GetConnectorDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConnectorDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetConnectorDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetConnectorDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4648,15 +4948,21 @@ impl fmt::Display for GetConnectorDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetConnectorDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetConnectorDefinitionVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetConnectorDefinitionVersionError {}
 /// Errors returned by GetCoreDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCoreDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCoreDefinitionError {
@@ -4664,10 +4970,10 @@ impl GetCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(GetCoreDefinitionError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCoreDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4678,15 +4984,19 @@ impl fmt::Display for GetCoreDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetCoreDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetCoreDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCoreDefinitionError {}
 /// Errors returned by GetCoreDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCoreDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCoreDefinitionVersionError {
@@ -4694,10 +5004,13 @@ impl GetCoreDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCoreDefinitionVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetCoreDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCoreDefinitionVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4708,15 +5021,19 @@ impl fmt::Display for GetCoreDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetCoreDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetCoreDefinitionVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCoreDefinitionVersionError {}
 /// Errors returned by GetDeploymentStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentStatusError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentStatusError {
@@ -4724,10 +5041,13 @@ impl GetDeploymentStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentStatusError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetDeploymentStatusError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4738,15 +5058,19 @@ impl fmt::Display for GetDeploymentStatusError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetDeploymentStatusError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetDeploymentStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentStatusError {}
 /// Errors returned by GetDeviceDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeviceDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeviceDefinitionError {
@@ -4754,10 +5078,13 @@ impl GetDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetDeviceDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeviceDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4768,15 +5095,19 @@ impl fmt::Display for GetDeviceDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetDeviceDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetDeviceDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeviceDefinitionError {}
 /// Errors returned by GetDeviceDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeviceDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeviceDefinitionVersionError {
@@ -4786,12 +5117,15 @@ impl GetDeviceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeviceDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeviceDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetDeviceDefinitionVersionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4802,15 +5136,19 @@ impl fmt::Display for GetDeviceDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetDeviceDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetDeviceDefinitionVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeviceDefinitionVersionError {}
 /// Errors returned by GetFunctionDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFunctionDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFunctionDefinitionError {
@@ -4818,10 +5156,13 @@ impl GetFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetFunctionDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFunctionDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4832,15 +5173,19 @@ impl fmt::Display for GetFunctionDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetFunctionDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetFunctionDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFunctionDefinitionError {}
 /// Errors returned by GetFunctionDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFunctionDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFunctionDefinitionVersionError {
@@ -4850,12 +5195,18 @@ impl GetFunctionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFunctionDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFunctionDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetFunctionDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4866,15 +5217,19 @@ impl fmt::Display for GetFunctionDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetFunctionDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetFunctionDefinitionVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFunctionDefinitionVersionError {}
 /// Errors returned by GetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGroupError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGroupError {
@@ -4882,10 +5237,10 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupError::BadRequest(err.msg))
+                    return RusotoError::service(GetGroupError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4896,17 +5251,21 @@ impl fmt::Display for GetGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetGroupError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGroupError {}
 /// Errors returned by GetGroupCertificateAuthority
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGroupCertificateAuthorityError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGroupCertificateAuthorityError {
@@ -4916,17 +5275,24 @@ impl GetGroupCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupCertificateAuthorityError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetGroupCertificateAuthorityError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetGroupCertificateAuthorityError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetGroupCertificateAuthorityError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4940,17 +5306,21 @@ impl fmt::Display for GetGroupCertificateAuthorityError {
             GetGroupCertificateAuthorityError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetGroupCertificateAuthorityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGroupCertificateAuthorityError {}
 /// Errors returned by GetGroupCertificateConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGroupCertificateConfigurationError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGroupCertificateConfigurationError {
@@ -4960,17 +5330,24 @@ impl GetGroupCertificateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupCertificateConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetGroupCertificateConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetGroupCertificateConfigurationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetGroupCertificateConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4984,15 +5361,21 @@ impl fmt::Display for GetGroupCertificateConfigurationError {
             GetGroupCertificateConfigurationError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetGroupCertificateConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetGroupCertificateConfigurationError {}
 /// Errors returned by GetGroupVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGroupVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGroupVersionError {
@@ -5000,10 +5383,10 @@ impl GetGroupVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupVersionError::BadRequest(err.msg))
+                    return RusotoError::service(GetGroupVersionError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGroupVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5014,15 +5397,19 @@ impl fmt::Display for GetGroupVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetGroupVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetGroupVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGroupVersionError {}
 /// Errors returned by GetLoggerDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLoggerDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLoggerDefinitionError {
@@ -5030,10 +5417,13 @@ impl GetLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetLoggerDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLoggerDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5044,15 +5434,19 @@ impl fmt::Display for GetLoggerDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetLoggerDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetLoggerDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLoggerDefinitionError {}
 /// Errors returned by GetLoggerDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLoggerDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLoggerDefinitionVersionError {
@@ -5062,12 +5456,15 @@ impl GetLoggerDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetLoggerDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoggerDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetLoggerDefinitionVersionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5078,15 +5475,19 @@ impl fmt::Display for GetLoggerDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetLoggerDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetLoggerDefinitionVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLoggerDefinitionVersionError {}
 /// Errors returned by GetResourceDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourceDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourceDefinitionError {
@@ -5094,10 +5495,13 @@ impl GetResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetResourceDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourceDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5108,15 +5512,19 @@ impl fmt::Display for GetResourceDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetResourceDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetResourceDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourceDefinitionError {}
 /// Errors returned by GetResourceDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourceDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourceDefinitionVersionError {
@@ -5126,12 +5534,18 @@ impl GetResourceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourceDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetResourceDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5142,15 +5556,19 @@ impl fmt::Display for GetResourceDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetResourceDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetResourceDefinitionVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourceDefinitionVersionError {}
 /// Errors returned by GetServiceRoleForAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetServiceRoleForAccountError {
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetServiceRoleForAccountError {
@@ -5158,12 +5576,13 @@ impl GetServiceRoleForAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetServiceRoleForAccountError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetServiceRoleForAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5174,15 +5593,19 @@ impl fmt::Display for GetServiceRoleForAccountError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetServiceRoleForAccountError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetServiceRoleForAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetServiceRoleForAccountError {}
 /// Errors returned by GetSubscriptionDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSubscriptionDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSubscriptionDefinitionError {
@@ -5190,12 +5613,15 @@ impl GetSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSubscriptionDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSubscriptionDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetSubscriptionDefinitionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5206,15 +5632,19 @@ impl fmt::Display for GetSubscriptionDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetSubscriptionDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetSubscriptionDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSubscriptionDefinitionError {}
 /// Errors returned by GetSubscriptionDefinitionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSubscriptionDefinitionVersionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSubscriptionDefinitionVersionError {
@@ -5224,12 +5654,18 @@ impl GetSubscriptionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSubscriptionDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSubscriptionDefinitionVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetSubscriptionDefinitionVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5240,15 +5676,21 @@ impl fmt::Display for GetSubscriptionDefinitionVersionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetSubscriptionDefinitionVersionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            GetSubscriptionDefinitionVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetSubscriptionDefinitionVersionError {}
 /// Errors returned by ListBulkDeploymentDetailedReports
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBulkDeploymentDetailedReportsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBulkDeploymentDetailedReportsError {
@@ -5258,12 +5700,18 @@ impl ListBulkDeploymentDetailedReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListBulkDeploymentDetailedReportsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListBulkDeploymentDetailedReportsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5274,15 +5722,21 @@ impl fmt::Display for ListBulkDeploymentDetailedReportsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListBulkDeploymentDetailedReportsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListBulkDeploymentDetailedReportsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListBulkDeploymentDetailedReportsError {}
 /// Errors returned by ListBulkDeployments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBulkDeploymentsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBulkDeploymentsError {
@@ -5290,10 +5744,13 @@ impl ListBulkDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBulkDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListBulkDeploymentsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBulkDeploymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5304,15 +5761,19 @@ impl fmt::Display for ListBulkDeploymentsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListBulkDeploymentsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListBulkDeploymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBulkDeploymentsError {}
 /// Errors returned by ListConnectorDefinitionVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConnectorDefinitionVersionsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConnectorDefinitionVersionsError {
@@ -5322,12 +5783,18 @@ impl ListConnectorDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConnectorDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConnectorDefinitionVersionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListConnectorDefinitionVersionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5338,20 +5805,27 @@ impl fmt::Display for ListConnectorDefinitionVersionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListConnectorDefinitionVersionsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListConnectorDefinitionVersionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListConnectorDefinitionVersionsError {}
 /// Errors returned by ListConnectorDefinitions
 #[derive(Debug, PartialEq)]
-pub enum ListConnectorDefinitionsError {}
+#[non_exhaustive]
+pub enum ListConnectorDefinitionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListConnectorDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListConnectorDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConnectorDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5360,15 +5834,20 @@ impl ListConnectorDefinitionsError {
 impl fmt::Display for ListConnectorDefinitionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListConnectorDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListConnectorDefinitionsError {}
 /// Errors returned by ListCoreDefinitionVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCoreDefinitionVersionsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCoreDefinitionVersionsError {
@@ -5378,12 +5857,15 @@ impl ListCoreDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListCoreDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCoreDefinitionVersionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListCoreDefinitionVersionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5394,20 +5876,25 @@ impl fmt::Display for ListCoreDefinitionVersionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListCoreDefinitionVersionsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListCoreDefinitionVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCoreDefinitionVersionsError {}
 /// Errors returned by ListCoreDefinitions
 #[derive(Debug, PartialEq)]
-pub enum ListCoreDefinitionsError {}
+#[non_exhaustive]
+pub enum ListCoreDefinitionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListCoreDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListCoreDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCoreDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5416,15 +5903,20 @@ impl ListCoreDefinitionsError {
 impl fmt::Display for ListCoreDefinitionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListCoreDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListCoreDefinitionsError {}
 /// Errors returned by ListDeployments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeploymentsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeploymentsError {
@@ -5432,10 +5924,10 @@ impl ListDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(ListDeploymentsError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeploymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5446,15 +5938,19 @@ impl fmt::Display for ListDeploymentsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDeploymentsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListDeploymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeploymentsError {}
 /// Errors returned by ListDeviceDefinitionVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeviceDefinitionVersionsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeviceDefinitionVersionsError {
@@ -5464,12 +5960,18 @@ impl ListDeviceDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeviceDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeviceDefinitionVersionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListDeviceDefinitionVersionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5480,20 +5982,25 @@ impl fmt::Display for ListDeviceDefinitionVersionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDeviceDefinitionVersionsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListDeviceDefinitionVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeviceDefinitionVersionsError {}
 /// Errors returned by ListDeviceDefinitions
 #[derive(Debug, PartialEq)]
-pub enum ListDeviceDefinitionsError {}
+#[non_exhaustive]
+pub enum ListDeviceDefinitionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListDeviceDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDeviceDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeviceDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5502,15 +6009,20 @@ impl ListDeviceDefinitionsError {
 impl fmt::Display for ListDeviceDefinitionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListDeviceDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListDeviceDefinitionsError {}
 /// Errors returned by ListFunctionDefinitionVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFunctionDefinitionVersionsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFunctionDefinitionVersionsError {
@@ -5520,12 +6032,18 @@ impl ListFunctionDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFunctionDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFunctionDefinitionVersionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListFunctionDefinitionVersionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5536,20 +6054,27 @@ impl fmt::Display for ListFunctionDefinitionVersionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListFunctionDefinitionVersionsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListFunctionDefinitionVersionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListFunctionDefinitionVersionsError {}
 /// Errors returned by ListFunctionDefinitions
 #[derive(Debug, PartialEq)]
-pub enum ListFunctionDefinitionsError {}
+#[non_exhaustive]
+pub enum ListFunctionDefinitionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListFunctionDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListFunctionDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFunctionDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5558,17 +6083,22 @@ impl ListFunctionDefinitionsError {
 impl fmt::Display for ListFunctionDefinitionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListFunctionDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListFunctionDefinitionsError {}
 /// Errors returned by ListGroupCertificateAuthorities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGroupCertificateAuthoritiesError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGroupCertificateAuthoritiesError {
@@ -5578,17 +6108,24 @@ impl ListGroupCertificateAuthoritiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupCertificateAuthoritiesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListGroupCertificateAuthoritiesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListGroupCertificateAuthoritiesError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListGroupCertificateAuthoritiesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5602,15 +6139,21 @@ impl fmt::Display for ListGroupCertificateAuthoritiesError {
             ListGroupCertificateAuthoritiesError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListGroupCertificateAuthoritiesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListGroupCertificateAuthoritiesError {}
 /// Errors returned by ListGroupVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGroupVersionsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGroupVersionsError {
@@ -5618,10 +6161,10 @@ impl ListGroupVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(ListGroupVersionsError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGroupVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5632,20 +6175,25 @@ impl fmt::Display for ListGroupVersionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListGroupVersionsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListGroupVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGroupVersionsError {}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
-pub enum ListGroupsError {}
+#[non_exhaustive]
+pub enum ListGroupsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListGroupsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5654,15 +6202,20 @@ impl ListGroupsError {
 impl fmt::Display for ListGroupsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListGroupsError {}
 /// Errors returned by ListLoggerDefinitionVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLoggerDefinitionVersionsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLoggerDefinitionVersionsError {
@@ -5672,12 +6225,18 @@ impl ListLoggerDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListLoggerDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLoggerDefinitionVersionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListLoggerDefinitionVersionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5688,20 +6247,25 @@ impl fmt::Display for ListLoggerDefinitionVersionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListLoggerDefinitionVersionsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListLoggerDefinitionVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLoggerDefinitionVersionsError {}
 /// Errors returned by ListLoggerDefinitions
 #[derive(Debug, PartialEq)]
-pub enum ListLoggerDefinitionsError {}
+#[non_exhaustive]
+pub enum ListLoggerDefinitionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListLoggerDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListLoggerDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLoggerDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5710,15 +6274,20 @@ impl ListLoggerDefinitionsError {
 impl fmt::Display for ListLoggerDefinitionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListLoggerDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListLoggerDefinitionsError {}
 /// Errors returned by ListResourceDefinitionVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourceDefinitionVersionsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourceDefinitionVersionsError {
@@ -5728,12 +6297,18 @@ impl ListResourceDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListResourceDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceDefinitionVersionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListResourceDefinitionVersionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5744,20 +6319,27 @@ impl fmt::Display for ListResourceDefinitionVersionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListResourceDefinitionVersionsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListResourceDefinitionVersionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListResourceDefinitionVersionsError {}
 /// Errors returned by ListResourceDefinitions
 #[derive(Debug, PartialEq)]
-pub enum ListResourceDefinitionsError {}
+#[non_exhaustive]
+pub enum ListResourceDefinitionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListResourceDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListResourceDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResourceDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5766,15 +6348,20 @@ impl ListResourceDefinitionsError {
 impl fmt::Display for ListResourceDefinitionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListResourceDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListResourceDefinitionsError {}
 /// Errors returned by ListSubscriptionDefinitionVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSubscriptionDefinitionVersionsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSubscriptionDefinitionVersionsError {
@@ -5784,12 +6371,18 @@ impl ListSubscriptionDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListSubscriptionDefinitionVersionsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListSubscriptionDefinitionVersionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5802,13 +6395,20 @@ impl fmt::Display for ListSubscriptionDefinitionVersionsError {
             ListSubscriptionDefinitionVersionsError::BadRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListSubscriptionDefinitionVersionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListSubscriptionDefinitionVersionsError {}
 /// Errors returned by ListSubscriptionDefinitions
 #[derive(Debug, PartialEq)]
-pub enum ListSubscriptionDefinitionsError {}
+#[non_exhaustive]
+pub enum ListSubscriptionDefinitionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListSubscriptionDefinitionsError {
     pub fn from_response(
@@ -5817,7 +6417,12 @@ impl ListSubscriptionDefinitionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListSubscriptionDefinitionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5826,15 +6431,20 @@ impl ListSubscriptionDefinitionsError {
 impl fmt::Display for ListSubscriptionDefinitionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListSubscriptionDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListSubscriptionDefinitionsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -5842,10 +6452,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5856,15 +6469,19 @@ impl fmt::Display for ListTagsForResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ResetDeployments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetDeploymentsError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetDeploymentsError {
@@ -5872,10 +6489,10 @@ impl ResetDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ResetDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(ResetDeploymentsError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResetDeploymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5886,15 +6503,19 @@ impl fmt::Display for ResetDeploymentsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ResetDeploymentsError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ResetDeploymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetDeploymentsError {}
 /// Errors returned by StartBulkDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartBulkDeploymentError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartBulkDeploymentError {
@@ -5902,10 +6523,13 @@ impl StartBulkDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartBulkDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        StartBulkDeploymentError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartBulkDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5916,15 +6540,19 @@ impl fmt::Display for StartBulkDeploymentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StartBulkDeploymentError::BadRequest(ref cause) => write!(f, "{}", cause),
+            StartBulkDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartBulkDeploymentError {}
 /// Errors returned by StopBulkDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopBulkDeploymentError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopBulkDeploymentError {
@@ -5932,10 +6560,10 @@ impl StopBulkDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopBulkDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(StopBulkDeploymentError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopBulkDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5946,15 +6574,19 @@ impl fmt::Display for StopBulkDeploymentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopBulkDeploymentError::BadRequest(ref cause) => write!(f, "{}", cause),
+            StopBulkDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopBulkDeploymentError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -5962,10 +6594,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5976,15 +6608,19 @@ impl fmt::Display for TagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -5992,10 +6628,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6006,17 +6642,21 @@ impl fmt::Display for UntagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateConnectivityInfo
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConnectivityInfoError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConnectivityInfoError {
@@ -6024,15 +6664,19 @@ impl UpdateConnectivityInfoError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConnectivityInfoError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateConnectivityInfoError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateConnectivityInfoError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateConnectivityInfoError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateConnectivityInfoError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6044,15 +6688,19 @@ impl fmt::Display for UpdateConnectivityInfoError {
         match *self {
             UpdateConnectivityInfoError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateConnectivityInfoError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UpdateConnectivityInfoError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConnectivityInfoError {}
 /// Errors returned by UpdateConnectorDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConnectorDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConnectorDefinitionError {
@@ -6060,12 +6708,15 @@ impl UpdateConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConnectorDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateConnectorDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateConnectorDefinitionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6076,15 +6727,19 @@ impl fmt::Display for UpdateConnectorDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateConnectorDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UpdateConnectorDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConnectorDefinitionError {}
 /// Errors returned by UpdateCoreDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCoreDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCoreDefinitionError {
@@ -6092,10 +6747,13 @@ impl UpdateCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateCoreDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateCoreDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6106,15 +6764,19 @@ impl fmt::Display for UpdateCoreDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateCoreDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UpdateCoreDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateCoreDefinitionError {}
 /// Errors returned by UpdateDeviceDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeviceDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeviceDefinitionError {
@@ -6122,10 +6784,13 @@ impl UpdateDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeviceDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6136,15 +6801,19 @@ impl fmt::Display for UpdateDeviceDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateDeviceDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UpdateDeviceDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDeviceDefinitionError {}
 /// Errors returned by UpdateFunctionDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFunctionDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFunctionDefinitionError {
@@ -6152,10 +6821,13 @@ impl UpdateFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateFunctionDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFunctionDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6166,15 +6838,19 @@ impl fmt::Display for UpdateFunctionDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateFunctionDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UpdateFunctionDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFunctionDefinitionError {}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGroupError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGroupError {
@@ -6182,10 +6858,10 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGroupError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateGroupError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6196,17 +6872,21 @@ impl fmt::Display for UpdateGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateGroupError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UpdateGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGroupError {}
 /// Errors returned by UpdateGroupCertificateConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGroupCertificateConfigurationError {
     /// <p>General error information.</p>
     BadRequest(String),
     /// <p>General error information.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGroupCertificateConfigurationError {
@@ -6216,17 +6896,24 @@ impl UpdateGroupCertificateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateGroupCertificateConfigurationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateGroupCertificateConfigurationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateGroupCertificateConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6242,15 +6929,21 @@ impl fmt::Display for UpdateGroupCertificateConfigurationError {
             UpdateGroupCertificateConfigurationError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateGroupCertificateConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateGroupCertificateConfigurationError {}
 /// Errors returned by UpdateLoggerDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLoggerDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLoggerDefinitionError {
@@ -6258,10 +6951,13 @@ impl UpdateLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateLoggerDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateLoggerDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6272,15 +6968,19 @@ impl fmt::Display for UpdateLoggerDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateLoggerDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UpdateLoggerDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateLoggerDefinitionError {}
 /// Errors returned by UpdateResourceDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResourceDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateResourceDefinitionError {
@@ -6288,10 +6988,13 @@ impl UpdateResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateResourceDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateResourceDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6302,15 +7005,19 @@ impl fmt::Display for UpdateResourceDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateResourceDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UpdateResourceDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateResourceDefinitionError {}
 /// Errors returned by UpdateSubscriptionDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSubscriptionDefinitionError {
     /// <p>General error information.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSubscriptionDefinitionError {
@@ -6320,12 +7027,18 @@ impl UpdateSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateSubscriptionDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSubscriptionDefinitionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateSubscriptionDefinitionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6336,6 +7049,7 @@ impl fmt::Display for UpdateSubscriptionDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateSubscriptionDefinitionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UpdateSubscriptionDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -6949,6 +7663,7 @@ impl GreenGrassClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> GreenGrassClient {
         GreenGrassClient {
             client: Client::shared(),
@@ -6974,125 +7689,199 @@ impl GreenGrassClient {
     pub fn new_with_client(client: Client, region: region::Region) -> GreenGrassClient {
         GreenGrassClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<GreenGrassClient> {
+        rusoto_core::ClientBuilder::new(GreenGrassClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> GreenGrassClient {
+        GreenGrassClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl GreenGrass for GreenGrassClient {
-    /// <p>Associates a role with a group. Your Greengrass core will use the role to access AWS cloud services. The role&#39;s permissions should allow Greengrass core Lambda functions to perform actions against the cloud.</p>
-    async fn associate_role_to_group(
+impl GreenGrassClient {
+    /// Calls `associate_role_to_group`, also returning the metadata of the response.
+    pub async fn associate_role_to_group_with_metadata(
         &self,
         input: AssociateRoleToGroupRequest,
-    ) -> Result<AssociateRoleToGroupResponse, RusotoError<AssociateRoleToGroupError>> {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/role",
-            group_id = input.group_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (AssociateRoleToGroupResponse, ResponseMetadata),
+        RusotoError<AssociateRoleToGroupError>,
+    > {
+        let request = self.build_associate_role_to_group_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateRoleToGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(AssociateRoleToGroupError::from_response(response))
         }
     }
 
-    /// <p>Associates a role with your account. AWS IoT Greengrass will use the role to access your Lambda functions and AWS IoT resources. This is necessary for deployments to succeed. The role must have at least minimum permissions in the policy &#39;&#39;AWSGreengrassResourceAccessRolePolicy&#39;&#39;.</p>
-    async fn associate_service_role_to_account(
+    /// Presigns the request `associate_role_to_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_role_to_group(
         &self,
-        input: AssociateServiceRoleToAccountRequest,
-    ) -> Result<
-        AssociateServiceRoleToAccountResponse,
-        RusotoError<AssociateServiceRoleToAccountError>,
-    > {
-        let request_uri = "/greengrass/servicerole";
+        presigner: &rusoto_core::Presigner,
+        input: AssociateRoleToGroupRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_role_to_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_role_to_group_request(
+        &self,
+        input: AssociateRoleToGroupRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/role",
+            group_id = input.group_id
+        );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateRoleToGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `associate_service_role_to_account`, also returning the metadata of the response.
+    pub async fn associate_service_role_to_account_with_metadata(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> Result<
+        (AssociateServiceRoleToAccountResponse, ResponseMetadata),
+        RusotoError<AssociateServiceRoleToAccountError>,
+    > {
+        let request = self.build_associate_service_role_to_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateServiceRoleToAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(AssociateServiceRoleToAccountError::from_response(response))
         }
     }
 
-    /// <p>Creates a connector definition. You may provide the initial version of the connector definition now or use &#39;&#39;CreateConnectorDefinitionVersion&#39;&#39; at a later time.</p>
-    async fn create_connector_definition(
+    /// Presigns the request `associate_service_role_to_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_service_role_to_account(
         &self,
-        input: CreateConnectorDefinitionRequest,
-    ) -> Result<CreateConnectorDefinitionResponse, RusotoError<CreateConnectorDefinitionError>>
-    {
-        let request_uri = "/greengrass/definition/connectors";
+        presigner: &rusoto_core::Presigner,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_service_role_to_account_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+    fn build_associate_service_role_to_account_request(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> SignedRequest {
+        let request_uri = "/greengrass/servicerole";
+
+        let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateServiceRoleToAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        if let Some(ref amzn_client_token) = input.amzn_client_token {
-            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
-        }
+        request
+    }
+
+    /// Calls `create_connector_definition`, also returning the metadata of the response.
+    pub async fn create_connector_definition_with_metadata(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> Result<
+        (CreateConnectorDefinitionResponse, ResponseMetadata),
+        RusotoError<CreateConnectorDefinitionError>,
+    > {
+        let request = self.build_create_connector_definition_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateConnectorDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateConnectorDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a version of a connector definition which has already been defined.</p>
-    async fn create_connector_definition_version(
+    /// Presigns the request `create_connector_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_connector_definition(
         &self,
-        input: CreateConnectorDefinitionVersionRequest,
-    ) -> Result<
-        CreateConnectorDefinitionVersionResponse,
-        RusotoError<CreateConnectorDefinitionVersionError>,
-    > {
-        let request_uri = format!(
-            "/greengrass/definition/connectors/{connector_definition_id}/versions",
-            connector_definition_id = input.connector_definition_id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: CreateConnectorDefinitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_connector_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_connector_definition_request(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7102,33 +7891,66 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_connector_definition_version`, also returning the metadata of the response.
+    pub async fn create_connector_definition_version_with_metadata(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> Result<
+        (CreateConnectorDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<CreateConnectorDefinitionVersionError>,
+    > {
+        let request = self.build_create_connector_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateConnectorDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateConnectorDefinitionVersionError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Creates a core definition. You may provide the initial version of the core definition now or use &#39;&#39;CreateCoreDefinitionVersion&#39;&#39; at a later time. Greengrass groups must each contain exactly one Greengrass core.</p>
-    async fn create_core_definition(
+    /// Presigns the request `create_connector_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_connector_definition_version(
         &self,
-        input: CreateCoreDefinitionRequest,
-    ) -> Result<CreateCoreDefinitionResponse, RusotoError<CreateCoreDefinitionError>> {
-        let request_uri = "/greengrass/definition/cores";
+        presigner: &rusoto_core::Presigner,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_connector_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_connector_definition_version_request(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/greengrass/definition/connectors/{connector_definition_id}/versions",
+            connector_definition_id = input.connector_definition_id
+        );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7138,35 +7960,61 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_core_definition`, also returning the metadata of the response.
+    pub async fn create_core_definition_with_metadata(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> Result<
+        (CreateCoreDefinitionResponse, ResponseMetadata),
+        RusotoError<CreateCoreDefinitionError>,
+    > {
+        let request = self.build_create_core_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateCoreDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateCoreDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a version of a core definition that has already been defined. Greengrass groups must each contain exactly one Greengrass core.</p>
-    async fn create_core_definition_version(
+    /// Presigns the request `create_core_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_core_definition(
         &self,
-        input: CreateCoreDefinitionVersionRequest,
-    ) -> Result<CreateCoreDefinitionVersionResponse, RusotoError<CreateCoreDefinitionVersionError>>
-    {
-        let request_uri = format!(
-            "/greengrass/definition/cores/{core_definition_id}/versions",
-            core_definition_id = input.core_definition_id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: CreateCoreDefinitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_core_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_core_definition_request(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7176,34 +8024,64 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_core_definition_version`, also returning the metadata of the response.
+    pub async fn create_core_definition_version_with_metadata(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> Result<
+        (CreateCoreDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<CreateCoreDefinitionVersionError>,
+    > {
+        let request = self.build_create_core_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateCoreDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateCoreDefinitionVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates a deployment. &#39;&#39;CreateDeployment&#39;&#39; requests are idempotent with respect to the &#39;&#39;X-Amzn-Client-Token&#39;&#39; token and the request parameters.</p>
-    async fn create_deployment(
+    /// Presigns the request `create_core_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_core_definition_version(
         &self,
-        input: CreateDeploymentRequest,
-    ) -> Result<CreateDeploymentResponse, RusotoError<CreateDeploymentError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_core_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_core_definition_version_request(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/greengrass/groups/{group_id}/deployments",
-            group_id = input.group_id
+            "/greengrass/definition/cores/{core_definition_id}/versions",
+            core_definition_id = input.core_definition_id
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7213,31 +8091,59 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_deployment`, also returning the metadata of the response.
+    pub async fn create_deployment_with_metadata(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<(CreateDeploymentResponse, ResponseMetadata), RusotoError<CreateDeploymentError>>
+    {
+        let request = self.build_create_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeploymentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Creates a device definition. You may provide the initial version of the device definition now or use &#39;&#39;CreateDeviceDefinitionVersion&#39;&#39; at a later time.</p>
-    async fn create_device_definition(
+    /// Presigns the request `create_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment(
         &self,
-        input: CreateDeviceDefinitionRequest,
-    ) -> Result<CreateDeviceDefinitionResponse, RusotoError<CreateDeviceDefinitionError>> {
-        let request_uri = "/greengrass/definition/devices";
+        presigner: &rusoto_core::Presigner,
+        input: CreateDeploymentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_request(&self, input: CreateDeploymentRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/deployments",
+            group_id = input.group_id
+        );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7247,37 +8153,61 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_device_definition`, also returning the metadata of the response.
+    pub async fn create_device_definition_with_metadata(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> Result<
+        (CreateDeviceDefinitionResponse, ResponseMetadata),
+        RusotoError<CreateDeviceDefinitionError>,
+    > {
+        let request = self.build_create_device_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeviceDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateDeviceDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a version of a device definition that has already been defined.</p>
-    async fn create_device_definition_version(
+    /// Presigns the request `create_device_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_device_definition(
         &self,
-        input: CreateDeviceDefinitionVersionRequest,
-    ) -> Result<
-        CreateDeviceDefinitionVersionResponse,
-        RusotoError<CreateDeviceDefinitionVersionError>,
-    > {
-        let request_uri = format!(
-            "/greengrass/definition/devices/{device_definition_id}/versions",
-            device_definition_id = input.device_definition_id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: CreateDeviceDefinitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_device_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_device_definition_request(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7287,31 +8217,64 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_device_definition_version`, also returning the metadata of the response.
+    pub async fn create_device_definition_version_with_metadata(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> Result<
+        (CreateDeviceDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<CreateDeviceDefinitionVersionError>,
+    > {
+        let request = self.build_create_device_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeviceDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateDeviceDefinitionVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates a Lambda function definition which contains a list of Lambda functions and their configurations to be used in a group. You can create an initial version of the definition by providing a list of Lambda functions and their configurations now, or use &#39;&#39;CreateFunctionDefinitionVersion&#39;&#39; later.</p>
-    async fn create_function_definition(
+    /// Presigns the request `create_device_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_device_definition_version(
         &self,
-        input: CreateFunctionDefinitionRequest,
-    ) -> Result<CreateFunctionDefinitionResponse, RusotoError<CreateFunctionDefinitionError>> {
-        let request_uri = "/greengrass/definition/functions";
+        presigner: &rusoto_core::Presigner,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_device_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_device_definition_version_request(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/greengrass/definition/devices/{device_definition_id}/versions",
+            device_definition_id = input.device_definition_id
+        );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7321,37 +8284,61 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_function_definition`, also returning the metadata of the response.
+    pub async fn create_function_definition_with_metadata(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> Result<
+        (CreateFunctionDefinitionResponse, ResponseMetadata),
+        RusotoError<CreateFunctionDefinitionError>,
+    > {
+        let request = self.build_create_function_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFunctionDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateFunctionDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a version of a Lambda function definition that has already been defined.</p>
-    async fn create_function_definition_version(
+    /// Presigns the request `create_function_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_function_definition(
         &self,
-        input: CreateFunctionDefinitionVersionRequest,
-    ) -> Result<
-        CreateFunctionDefinitionVersionResponse,
-        RusotoError<CreateFunctionDefinitionVersionError>,
-    > {
-        let request_uri = format!(
-            "/greengrass/definition/functions/{function_definition_id}/versions",
-            function_definition_id = input.function_definition_id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: CreateFunctionDefinitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_function_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_function_definition_request(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7361,33 +8348,66 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_function_definition_version`, also returning the metadata of the response.
+    pub async fn create_function_definition_version_with_metadata(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> Result<
+        (CreateFunctionDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<CreateFunctionDefinitionVersionError>,
+    > {
+        let request = self.build_create_function_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFunctionDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateFunctionDefinitionVersionError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Creates a group. You may provide the initial version of the group or use &#39;&#39;CreateGroupVersion&#39;&#39; at a later time. Tip: You can use the &#39;&#39;gg<em>group</em>setup&#39;&#39; package (https://github.com/awslabs/aws-greengrass-group-setup) as a library or command-line application to create and deploy Greengrass groups.</p>
-    async fn create_group(
+    /// Presigns the request `create_function_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_function_definition_version(
         &self,
-        input: CreateGroupRequest,
-    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
-        let request_uri = "/greengrass/groups";
+        presigner: &rusoto_core::Presigner,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_function_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_function_definition_version_request(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/greengrass/definition/functions/{function_definition_id}/versions",
+            function_definition_id = input.function_definition_id
+        );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7397,107 +8417,186 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_group`, also returning the metadata of the response.
+    pub async fn create_group_with_metadata(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<(CreateGroupResponse, ResponseMetadata), RusotoError<CreateGroupError>> {
+        let request = self.build_create_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a CA for the group. If a CA already exists, it will rotate the existing CA.</p>
-    async fn create_group_certificate_authority(
+    /// Presigns the request `create_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_group(
         &self,
-        input: CreateGroupCertificateAuthorityRequest,
-    ) -> Result<
-        CreateGroupCertificateAuthorityResponse,
-        RusotoError<CreateGroupCertificateAuthorityError>,
-    > {
-        let request_uri = format!(
-            "/greengrass/groups/{group_id}/certificateauthorities",
-            group_id = input.group_id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: CreateGroupRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_group_request(&self, input: CreateGroupRequest) -> SignedRequest {
+        let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
         if let Some(ref amzn_client_token) = input.amzn_client_token {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_group_certificate_authority`, also returning the metadata of the response.
+    pub async fn create_group_certificate_authority_with_metadata(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> Result<
+        (CreateGroupCertificateAuthorityResponse, ResponseMetadata),
+        RusotoError<CreateGroupCertificateAuthorityError>,
+    > {
+        let request = self.build_create_group_certificate_authority_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGroupCertificateAuthorityResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateGroupCertificateAuthorityError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Creates a version of a group which has already been defined.</p>
-    async fn create_group_version(
+    /// Presigns the request `create_group_certificate_authority` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_group_certificate_authority(
         &self,
-        input: CreateGroupVersionRequest,
-    ) -> Result<CreateGroupVersionResponse, RusotoError<CreateGroupVersionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_group_certificate_authority_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_group_certificate_authority_request(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/greengrass/groups/{group_id}/versions",
+            "/greengrass/groups/{group_id}/certificateauthorities",
             group_id = input.group_id
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupCertificateAuthority");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
         if let Some(ref amzn_client_token) = input.amzn_client_token {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_group_version`, also returning the metadata of the response.
+    pub async fn create_group_version_with_metadata(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> Result<(CreateGroupVersionResponse, ResponseMetadata), RusotoError<CreateGroupVersionError>>
+    {
+        let request = self.build_create_group_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGroupVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateGroupVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates a logger definition. You may provide the initial version of the logger definition now or use &#39;&#39;CreateLoggerDefinitionVersion&#39;&#39; at a later time.</p>
-    async fn create_logger_definition(
+    /// Presigns the request `create_group_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_group_version(
         &self,
-        input: CreateLoggerDefinitionRequest,
-    ) -> Result<CreateLoggerDefinitionResponse, RusotoError<CreateLoggerDefinitionError>> {
-        let request_uri = "/greengrass/definition/loggers";
+        presigner: &rusoto_core::Presigner,
+        input: CreateGroupVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_group_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_group_version_request(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/greengrass/groups/{group_id}/versions",
+            group_id = input.group_id
+        );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7507,37 +8606,128 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_logger_definition`, also returning the metadata of the response.
+    pub async fn create_logger_definition_with_metadata(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> Result<
+        (CreateLoggerDefinitionResponse, ResponseMetadata),
+        RusotoError<CreateLoggerDefinitionError>,
+    > {
+        let request = self.build_create_logger_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateLoggerDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateLoggerDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a version of a logger definition that has already been defined.</p>
-    async fn create_logger_definition_version(
+    /// Presigns the request `create_logger_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_logger_definition(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateLoggerDefinitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_logger_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_logger_definition_request(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/greengrass/definition/loggers";
+
+        let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinition");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref amzn_client_token) = input.amzn_client_token {
+            request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
+        }
+
+        request
+    }
+
+    /// Calls `create_logger_definition_version`, also returning the metadata of the response.
+    pub async fn create_logger_definition_version_with_metadata(
         &self,
         input: CreateLoggerDefinitionVersionRequest,
     ) -> Result<
-        CreateLoggerDefinitionVersionResponse,
+        (CreateLoggerDefinitionVersionResponse, ResponseMetadata),
         RusotoError<CreateLoggerDefinitionVersionError>,
     > {
+        let request = self.build_create_logger_definition_version_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLoggerDefinitionVersionResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateLoggerDefinitionVersionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_logger_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_logger_definition_version(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_logger_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_logger_definition_version_request(
+        &self,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/loggers/{logger_definition_id}/versions",
             logger_definition_id = input.logger_definition_id
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7547,31 +8737,61 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_resource_definition`, also returning the metadata of the response.
+    pub async fn create_resource_definition_with_metadata(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> Result<
+        (CreateResourceDefinitionResponse, ResponseMetadata),
+        RusotoError<CreateResourceDefinitionError>,
+    > {
+        let request = self.build_create_resource_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLoggerDefinitionVersionResponse, _>()?;
+                .deserialize::<CreateResourceDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLoggerDefinitionVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateResourceDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a resource definition which contains a list of resources to be used in a group. You can create an initial version of the definition by providing a list of resources now, or use &#39;&#39;CreateResourceDefinitionVersion&#39;&#39; later.</p>
-    async fn create_resource_definition(
+    /// Presigns the request `create_resource_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resource_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResourceDefinitionRequest,
-    ) -> Result<CreateResourceDefinitionResponse, RusotoError<CreateResourceDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resource_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resource_definition_request(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7581,37 +8801,66 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_resource_definition_version`, also returning the metadata of the response.
+    pub async fn create_resource_definition_version_with_metadata(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> Result<
+        (CreateResourceDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<CreateResourceDefinitionVersionError>,
+    > {
+        let request = self.build_create_resource_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateResourceDefinitionResponse, _>()?;
+                .deserialize::<CreateResourceDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResourceDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateResourceDefinitionVersionError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Creates a version of a resource definition that has already been defined.</p>
-    async fn create_resource_definition_version(
+    /// Presigns the request `create_resource_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resource_definition_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResourceDefinitionVersionRequest,
-    ) -> Result<
-        CreateResourceDefinitionVersionResponse,
-        RusotoError<CreateResourceDefinitionVersionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resource_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resource_definition_version_request(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/resources/{resource_definition_id}/versions",
             resource_definition_id = input.resource_definition_id
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7621,33 +8870,61 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_software_update_job`, also returning the metadata of the response.
+    pub async fn create_software_update_job_with_metadata(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> Result<
+        (CreateSoftwareUpdateJobResponse, ResponseMetadata),
+        RusotoError<CreateSoftwareUpdateJobError>,
+    > {
+        let request = self.build_create_software_update_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateResourceDefinitionVersionResponse, _>()?;
+                .deserialize::<CreateSoftwareUpdateJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResourceDefinitionVersionError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateSoftwareUpdateJobError::from_response(response))
         }
     }
 
-    /// <p>Creates a software update for a core or group of cores (specified as an IoT thing group.) Use this to update the OTA Agent as well as the Greengrass core software. It makes use of the IoT Jobs feature which provides additional commands to manage a Greengrass core software update job.</p>
-    async fn create_software_update_job(
+    /// Presigns the request `create_software_update_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_software_update_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSoftwareUpdateJobRequest,
-    ) -> Result<CreateSoftwareUpdateJobResponse, RusotoError<CreateSoftwareUpdateJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_software_update_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_software_update_job_request(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/updates";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSoftwareUpdateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7657,32 +8934,61 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_subscription_definition`, also returning the metadata of the response.
+    pub async fn create_subscription_definition_with_metadata(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> Result<
+        (CreateSubscriptionDefinitionResponse, ResponseMetadata),
+        RusotoError<CreateSubscriptionDefinitionError>,
+    > {
+        let request = self.build_create_subscription_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSoftwareUpdateJobResponse, _>()?;
+                .deserialize::<CreateSubscriptionDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSoftwareUpdateJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateSubscriptionDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a subscription definition. You may provide the initial version of the subscription definition now or use &#39;&#39;CreateSubscriptionDefinitionVersion&#39;&#39; at a later time.</p>
-    async fn create_subscription_definition(
+    /// Presigns the request `create_subscription_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_subscription_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSubscriptionDefinitionRequest,
-    ) -> Result<CreateSubscriptionDefinitionResponse, RusotoError<CreateSubscriptionDefinitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_subscription_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_subscription_definition_request(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7692,37 +8998,69 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `create_subscription_definition_version`, also returning the metadata of the response.
+    pub async fn create_subscription_definition_version_with_metadata(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> Result<
+        (
+            CreateSubscriptionDefinitionVersionResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateSubscriptionDefinitionVersionError>,
+    > {
+        let request = self.build_create_subscription_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSubscriptionDefinitionResponse, _>()?;
+                .deserialize::<CreateSubscriptionDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSubscriptionDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateSubscriptionDefinitionVersionError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Creates a version of a subscription definition which has already been defined.</p>
-    async fn create_subscription_definition_version(
+    /// Presigns the request `create_subscription_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_subscription_definition_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSubscriptionDefinitionVersionRequest,
-    ) -> Result<
-        CreateSubscriptionDefinitionVersionResponse,
-        RusotoError<CreateSubscriptionDefinitionVersionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_subscription_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_subscription_definition_version_request(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/subscriptions/{subscription_definition_id}/versions",
             subscription_definition_id = input.subscription_definition_id
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7732,457 +9070,881 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `delete_connector_definition`, also returning the metadata of the response.
+    pub async fn delete_connector_definition_with_metadata(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> Result<
+        (DeleteConnectorDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteConnectorDefinitionError>,
+    > {
+        let request = self.build_delete_connector_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSubscriptionDefinitionVersionResponse, _>()?;
+                .deserialize::<DeleteConnectorDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSubscriptionDefinitionVersionError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteConnectorDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a connector definition.</p>
-    async fn delete_connector_definition(
+    /// Presigns the request `delete_connector_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_connector_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConnectorDefinitionRequest,
-    ) -> Result<DeleteConnectorDefinitionResponse, RusotoError<DeleteConnectorDefinitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_connector_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_connector_definition_request(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/connectors/{connector_definition_id}",
             connector_definition_id = input.connector_definition_id
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_core_definition`, also returning the metadata of the response.
+    pub async fn delete_core_definition_with_metadata(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> Result<
+        (DeleteCoreDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteCoreDefinitionError>,
+    > {
+        let request = self.build_delete_core_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteConnectorDefinitionResponse, _>()?;
+                .deserialize::<DeleteCoreDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConnectorDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteCoreDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a core definition.</p>
-    async fn delete_core_definition(
+    /// Presigns the request `delete_core_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_core_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCoreDefinitionRequest,
-    ) -> Result<DeleteCoreDefinitionResponse, RusotoError<DeleteCoreDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_core_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_core_definition_request(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/cores/{core_definition_id}",
             core_definition_id = input.core_definition_id
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_device_definition`, also returning the metadata of the response.
+    pub async fn delete_device_definition_with_metadata(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> Result<
+        (DeleteDeviceDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteDeviceDefinitionError>,
+    > {
+        let request = self.build_delete_device_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteCoreDefinitionResponse, _>()?;
+                .deserialize::<DeleteDeviceDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCoreDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDeviceDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a device definition.</p>
-    async fn delete_device_definition(
+    /// Presigns the request `delete_device_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_device_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeviceDefinitionRequest,
-    ) -> Result<DeleteDeviceDefinitionResponse, RusotoError<DeleteDeviceDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_device_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_device_definition_request(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/devices/{device_definition_id}",
             device_definition_id = input.device_definition_id
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_function_definition`, also returning the metadata of the response.
+    pub async fn delete_function_definition_with_metadata(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> Result<
+        (DeleteFunctionDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteFunctionDefinitionError>,
+    > {
+        let request = self.build_delete_function_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDeviceDefinitionResponse, _>()?;
+                .deserialize::<DeleteFunctionDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeviceDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFunctionDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Lambda function definition.</p>
-    async fn delete_function_definition(
+    /// Presigns the request `delete_function_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_function_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFunctionDefinitionRequest,
-    ) -> Result<DeleteFunctionDefinitionResponse, RusotoError<DeleteFunctionDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_function_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_function_definition_request(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/functions/{function_definition_id}",
             function_definition_id = input.function_definition_id
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_group`, also returning the metadata of the response.
+    pub async fn delete_group_with_metadata(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<(DeleteGroupResponse, ResponseMetadata), RusotoError<DeleteGroupError>> {
+        let request = self.build_delete_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteFunctionDefinitionResponse, _>()?;
+                .deserialize::<DeleteGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFunctionDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a group.</p>
-    async fn delete_group(
+    /// Presigns the request `delete_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGroupRequest,
-    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_group_request(&self, input: DeleteGroupRequest) -> SignedRequest {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_logger_definition`, also returning the metadata of the response.
+    pub async fn delete_logger_definition_with_metadata(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> Result<
+        (DeleteLoggerDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteLoggerDefinitionError>,
+    > {
+        let request = self.build_delete_logger_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGroupResponse, _>()?;
+                .deserialize::<DeleteLoggerDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteLoggerDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a logger definition.</p>
-    async fn delete_logger_definition(
+    /// Presigns the request `delete_logger_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_logger_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLoggerDefinitionRequest,
-    ) -> Result<DeleteLoggerDefinitionResponse, RusotoError<DeleteLoggerDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_logger_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_logger_definition_request(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/loggers/{logger_definition_id}",
             logger_definition_id = input.logger_definition_id
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `delete_resource_definition`, also returning the metadata of the response.
+    pub async fn delete_resource_definition_with_metadata(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> Result<
+        (DeleteResourceDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteResourceDefinitionError>,
+    > {
+        let request = self.build_delete_resource_definition_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLoggerDefinitionResponse, _>()?;
+                .deserialize::<DeleteResourceDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLoggerDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteResourceDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a resource definition.</p>
-    async fn delete_resource_definition(
+    /// Presigns the request `delete_resource_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourceDefinitionRequest,
-    ) -> Result<DeleteResourceDefinitionResponse, RusotoError<DeleteResourceDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_definition_request(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/resources/{resource_definition_id}",
             resource_definition_id = input.resource_definition_id
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_subscription_definition`, also returning the metadata of the response.
+    pub async fn delete_subscription_definition_with_metadata(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> Result<
+        (DeleteSubscriptionDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteSubscriptionDefinitionError>,
+    > {
+        let request = self.build_delete_subscription_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteResourceDefinitionResponse, _>()?;
+                .deserialize::<DeleteSubscriptionDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourceDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteSubscriptionDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a subscription definition.</p>
-    async fn delete_subscription_definition(
+    /// Presigns the request `delete_subscription_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_subscription_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSubscriptionDefinitionRequest,
-    ) -> Result<DeleteSubscriptionDefinitionResponse, RusotoError<DeleteSubscriptionDefinitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_subscription_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_subscription_definition_request(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/subscriptions/{subscription_definition_id}",
             subscription_definition_id = input.subscription_definition_id
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `disassociate_role_from_group`, also returning the metadata of the response.
+    pub async fn disassociate_role_from_group_with_metadata(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> Result<
+        (DisassociateRoleFromGroupResponse, ResponseMetadata),
+        RusotoError<DisassociateRoleFromGroupError>,
+    > {
+        let request = self.build_disassociate_role_from_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSubscriptionDefinitionResponse, _>()?;
+                .deserialize::<DisassociateRoleFromGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSubscriptionDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateRoleFromGroupError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the role from a group.</p>
-    async fn disassociate_role_from_group(
+    /// Presigns the request `disassociate_role_from_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_role_from_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateRoleFromGroupRequest,
-    ) -> Result<DisassociateRoleFromGroupResponse, RusotoError<DisassociateRoleFromGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_role_from_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_role_from_group_request(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/role",
             group_id = input.group_id
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateRoleFromGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `disassociate_service_role_from_account`, also returning the metadata of the response.
+    pub async fn disassociate_service_role_from_account_with_metadata(
+        &self,
+    ) -> Result<
+        (DisassociateServiceRoleFromAccountResponse, ResponseMetadata),
+        RusotoError<DisassociateServiceRoleFromAccountError>,
+    > {
+        let request = self.build_disassociate_service_role_from_account_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateRoleFromGroupResponse, _>()?;
+                .deserialize::<DisassociateServiceRoleFromAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateRoleFromGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateServiceRoleFromAccountError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Disassociates the service role from your account. Without a service role, deployments will not work.</p>
-    async fn disassociate_service_role_from_account(
+    /// Presigns the request `disassociate_service_role_from_account` would send with `presigner` instead of sending it.
+    pub async fn presign_disassociate_service_role_from_account(
         &self,
-    ) -> Result<
-        DisassociateServiceRoleFromAccountResponse,
-        RusotoError<DisassociateServiceRoleFromAccountError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_service_role_from_account_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_service_role_from_account_request(&self) -> SignedRequest {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateServiceRoleFromAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_associated_role`, also returning the metadata of the response.
+    pub async fn get_associated_role_with_metadata(
+        &self,
+        input: GetAssociatedRoleRequest,
+    ) -> Result<(GetAssociatedRoleResponse, ResponseMetadata), RusotoError<GetAssociatedRoleError>>
+    {
+        let request = self.build_get_associated_role_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateServiceRoleFromAccountResponse, _>()?;
+                .deserialize::<GetAssociatedRoleResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateServiceRoleFromAccountError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAssociatedRoleError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the role associated with a particular group.</p>
-    async fn get_associated_role(
+    /// Presigns the request `get_associated_role` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_associated_role(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAssociatedRoleRequest,
-    ) -> Result<GetAssociatedRoleResponse, RusotoError<GetAssociatedRoleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_associated_role_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_associated_role_request(&self, input: GetAssociatedRoleRequest) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/role",
             group_id = input.group_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetAssociatedRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_bulk_deployment_status`, also returning the metadata of the response.
+    pub async fn get_bulk_deployment_status_with_metadata(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> Result<
+        (GetBulkDeploymentStatusResponse, ResponseMetadata),
+        RusotoError<GetBulkDeploymentStatusError>,
+    > {
+        let request = self.build_get_bulk_deployment_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAssociatedRoleResponse, _>()?;
+                .deserialize::<GetBulkDeploymentStatusResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAssociatedRoleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBulkDeploymentStatusError::from_response(response))
         }
     }
 
-    /// <p>Returns the status of a bulk deployment.</p>
-    async fn get_bulk_deployment_status(
+    /// Presigns the request `get_bulk_deployment_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bulk_deployment_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBulkDeploymentStatusRequest,
-    ) -> Result<GetBulkDeploymentStatusResponse, RusotoError<GetBulkDeploymentStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bulk_deployment_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bulk_deployment_status_request(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/bulk/deployments/{bulk_deployment_id}/status",
             bulk_deployment_id = input.bulk_deployment_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetBulkDeploymentStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_connectivity_info`, also returning the metadata of the response.
+    pub async fn get_connectivity_info_with_metadata(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> Result<
+        (GetConnectivityInfoResponse, ResponseMetadata),
+        RusotoError<GetConnectivityInfoError>,
+    > {
+        let request = self.build_get_connectivity_info_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBulkDeploymentStatusResponse, _>()?;
+                .deserialize::<GetConnectivityInfoResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBulkDeploymentStatusError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetConnectivityInfoError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the connectivity information for a core.</p>
-    async fn get_connectivity_info(
+    /// Presigns the request `get_connectivity_info` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_connectivity_info(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConnectivityInfoRequest,
-    ) -> Result<GetConnectivityInfoResponse, RusotoError<GetConnectivityInfoError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_connectivity_info_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_connectivity_info_request(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/things/{thing_name}/connectivityInfo",
             thing_name = input.thing_name
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectivityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_connector_definition`, also returning the metadata of the response.
+    pub async fn get_connector_definition_with_metadata(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> Result<
+        (GetConnectorDefinitionResponse, ResponseMetadata),
+        RusotoError<GetConnectorDefinitionError>,
+    > {
+        let request = self.build_get_connector_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConnectivityInfoResponse, _>()?;
+                .deserialize::<GetConnectorDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConnectivityInfoError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetConnectorDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a connector definition.</p>
-    async fn get_connector_definition(
+    /// Presigns the request `get_connector_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_connector_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConnectorDefinitionRequest,
-    ) -> Result<GetConnectorDefinitionResponse, RusotoError<GetConnectorDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_connector_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_connector_definition_request(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/connectors/{connector_definition_id}",
             connector_definition_id = input.connector_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_connector_definition_version`, also returning the metadata of the response.
+    pub async fn get_connector_definition_version_with_metadata(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> Result<
+        (GetConnectorDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<GetConnectorDefinitionVersionError>,
+    > {
+        let request = self.build_get_connector_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConnectorDefinitionResponse, _>()?;
+                .deserialize::<GetConnectorDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConnectorDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetConnectorDefinitionVersionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a connector definition version, including the connectors that the version contains. Connectors are prebuilt modules that interact with local infrastructure, device protocols, AWS, and other cloud services.</p>
-    async fn get_connector_definition_version(
+    /// Presigns the request `get_connector_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_connector_definition_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConnectorDefinitionVersionRequest,
-    ) -> Result<
-        GetConnectorDefinitionVersionResponse,
-        RusotoError<GetConnectorDefinitionVersionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_connector_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_connector_definition_version_request(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/greengrass/definition/connectors/{connector_definition_id}/versions/{connector_definition_version_id}", connector_definition_id = input.connector_definition_id, connector_definition_version_id = input.connector_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8191,85 +9953,169 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_core_definition`, also returning the metadata of the response.
+    pub async fn get_core_definition_with_metadata(
+        &self,
+        input: GetCoreDefinitionRequest,
+    ) -> Result<(GetCoreDefinitionResponse, ResponseMetadata), RusotoError<GetCoreDefinitionError>>
+    {
+        let request = self.build_get_core_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConnectorDefinitionVersionResponse, _>()?;
+                .deserialize::<GetCoreDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConnectorDefinitionVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetCoreDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a core definition version.</p>
-    async fn get_core_definition(
+    /// Presigns the request `get_core_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_core_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCoreDefinitionRequest,
-    ) -> Result<GetCoreDefinitionResponse, RusotoError<GetCoreDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_core_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_core_definition_request(&self, input: GetCoreDefinitionRequest) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/cores/{core_definition_id}",
             core_definition_id = input.core_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_core_definition_version`, also returning the metadata of the response.
+    pub async fn get_core_definition_version_with_metadata(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> Result<
+        (GetCoreDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<GetCoreDefinitionVersionError>,
+    > {
+        let request = self.build_get_core_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCoreDefinitionResponse, _>()?;
+                .deserialize::<GetCoreDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCoreDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetCoreDefinitionVersionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a core definition version.</p>
-    async fn get_core_definition_version(
+    /// Presigns the request `get_core_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_core_definition_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCoreDefinitionVersionRequest,
-    ) -> Result<GetCoreDefinitionVersionResponse, RusotoError<GetCoreDefinitionVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_core_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_core_definition_version_request(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/greengrass/definition/cores/{core_definition_id}/versions/{core_definition_version_id}", core_definition_id = input.core_definition_id, core_definition_version_id = input.core_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_deployment_status`, also returning the metadata of the response.
+    pub async fn get_deployment_status_with_metadata(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> Result<
+        (GetDeploymentStatusResponse, ResponseMetadata),
+        RusotoError<GetDeploymentStatusError>,
+    > {
+        let request = self.build_get_deployment_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCoreDefinitionVersionResponse, _>()?;
+                .deserialize::<GetDeploymentStatusResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCoreDefinitionVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeploymentStatusError::from_response(response))
         }
     }
 
-    /// <p>Returns the status of a deployment.</p>
-    async fn get_deployment_status(
+    /// Presigns the request `get_deployment_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentStatusRequest,
-    ) -> Result<GetDeploymentStatusResponse, RusotoError<GetDeploymentStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_status_request(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/deployments/{deployment_id}/status",
             deployment_id = input.deployment_id,
@@ -8277,64 +10123,124 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeploymentStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_device_definition`, also returning the metadata of the response.
+    pub async fn get_device_definition_with_metadata(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> Result<
+        (GetDeviceDefinitionResponse, ResponseMetadata),
+        RusotoError<GetDeviceDefinitionError>,
+    > {
+        let request = self.build_get_device_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeploymentStatusResponse, _>()?;
+                .deserialize::<GetDeviceDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentStatusError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeviceDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a device definition.</p>
-    async fn get_device_definition(
+    /// Presigns the request `get_device_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_device_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeviceDefinitionRequest,
-    ) -> Result<GetDeviceDefinitionResponse, RusotoError<GetDeviceDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_device_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_device_definition_request(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/devices/{device_definition_id}",
             device_definition_id = input.device_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_device_definition_version`, also returning the metadata of the response.
+    pub async fn get_device_definition_version_with_metadata(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> Result<
+        (GetDeviceDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<GetDeviceDefinitionVersionError>,
+    > {
+        let request = self.build_get_device_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeviceDefinitionResponse, _>()?;
+                .deserialize::<GetDeviceDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeviceDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeviceDefinitionVersionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a device definition version.</p>
-    async fn get_device_definition_version(
+    /// Presigns the request `get_device_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_device_definition_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeviceDefinitionVersionRequest,
-    ) -> Result<GetDeviceDefinitionVersionResponse, RusotoError<GetDeviceDefinitionVersionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_device_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_device_definition_version_request(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/greengrass/definition/devices/{device_definition_id}/versions/{device_definition_version_id}", device_definition_id = input.device_definition_id, device_definition_version_id = input.device_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8343,62 +10249,121 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_function_definition`, also returning the metadata of the response.
+    pub async fn get_function_definition_with_metadata(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> Result<
+        (GetFunctionDefinitionResponse, ResponseMetadata),
+        RusotoError<GetFunctionDefinitionError>,
+    > {
+        let request = self.build_get_function_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeviceDefinitionVersionResponse, _>()?;
+                .deserialize::<GetFunctionDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeviceDefinitionVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFunctionDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a Lambda function definition, including its creation time and latest version.</p>
-    async fn get_function_definition(
+    /// Presigns the request `get_function_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_function_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFunctionDefinitionRequest,
-    ) -> Result<GetFunctionDefinitionResponse, RusotoError<GetFunctionDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_function_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_function_definition_request(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/functions/{function_definition_id}",
             function_definition_id = input.function_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_function_definition_version`, also returning the metadata of the response.
+    pub async fn get_function_definition_version_with_metadata(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> Result<
+        (GetFunctionDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<GetFunctionDefinitionVersionError>,
+    > {
+        let request = self.build_get_function_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFunctionDefinitionResponse, _>()?;
+                .deserialize::<GetFunctionDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFunctionDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFunctionDefinitionVersionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a Lambda function definition version, including which Lambda functions are included in the version and their configurations.</p>
-    async fn get_function_definition_version(
+    /// Presigns the request `get_function_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_function_definition_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFunctionDefinitionVersionRequest,
-    ) -> Result<GetFunctionDefinitionVersionResponse, RusotoError<GetFunctionDefinitionVersionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_function_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_function_definition_version_request(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/greengrass/definition/functions/{function_definition_id}/versions/{function_definition_version_id}", function_definition_id = input.function_definition_id, function_definition_version_id = input.function_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8407,56 +10372,108 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_group`, also returning the metadata of the response.
+    pub async fn get_group_with_metadata(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<(GetGroupResponse, ResponseMetadata), RusotoError<GetGroupError>> {
+        let request = self.build_get_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFunctionDefinitionVersionResponse, _>()?;
+                .deserialize::<GetGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFunctionDefinitionVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGroupError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a group.</p>
-    async fn get_group(
+    /// Presigns the request `get_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGroupRequest,
-    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_group_request(&self, input: GetGroupRequest) -> SignedRequest {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_group_certificate_authority`, also returning the metadata of the response.
+    pub async fn get_group_certificate_authority_with_metadata(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> Result<
+        (GetGroupCertificateAuthorityResponse, ResponseMetadata),
+        RusotoError<GetGroupCertificateAuthorityError>,
+    > {
+        let request = self.build_get_group_certificate_authority_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetGroupResponse, _>()?;
+                .deserialize::<GetGroupCertificateAuthorityResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGroupCertificateAuthorityError::from_response(response))
         }
     }
 
-    /// <p>Retreives the CA associated with a group. Returns the public key of the CA.</p>
-    async fn get_group_certificate_authority(
+    /// Presigns the request `get_group_certificate_authority` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_group_certificate_authority(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGroupCertificateAuthorityRequest,
-    ) -> Result<GetGroupCertificateAuthorityResponse, RusotoError<GetGroupCertificateAuthorityError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_group_certificate_authority_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_group_certificate_authority_request(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/certificateauthorities/{certificate_authority_id}",
             certificate_authority_id = input.certificate_authority_id,
@@ -8464,65 +10481,117 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateAuthority");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_group_certificate_configuration`, also returning the metadata of the response.
+    pub async fn get_group_certificate_configuration_with_metadata(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> Result<
+        (GetGroupCertificateConfigurationResponse, ResponseMetadata),
+        RusotoError<GetGroupCertificateConfigurationError>,
+    > {
+        let request = self.build_get_group_certificate_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetGroupCertificateAuthorityResponse, _>()?;
+                .deserialize::<GetGroupCertificateConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGroupCertificateAuthorityError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGroupCertificateConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the current configuration for the CA used by the group.</p>
-    async fn get_group_certificate_configuration(
+    /// Presigns the request `get_group_certificate_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_group_certificate_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGroupCertificateConfigurationRequest,
-    ) -> Result<
-        GetGroupCertificateConfigurationResponse,
-        RusotoError<GetGroupCertificateConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_group_certificate_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_group_certificate_configuration_request(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/certificateauthorities/configuration/expiry",
             group_id = input.group_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_group_version`, also returning the metadata of the response.
+    pub async fn get_group_version_with_metadata(
+        &self,
+        input: GetGroupVersionRequest,
+    ) -> Result<(GetGroupVersionResponse, ResponseMetadata), RusotoError<GetGroupVersionError>>
+    {
+        let request = self.build_get_group_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetGroupCertificateConfigurationResponse, _>()?;
+                .deserialize::<GetGroupVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGroupCertificateConfigurationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGroupVersionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a group version.</p>
-    async fn get_group_version(
+    /// Presigns the request `get_group_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_group_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGroupVersionRequest,
-    ) -> Result<GetGroupVersionResponse, RusotoError<GetGroupVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_group_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_group_version_request(&self, input: GetGroupVersionRequest) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/versions/{group_version_id}",
             group_id = input.group_id,
@@ -8530,64 +10599,124 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_logger_definition`, also returning the metadata of the response.
+    pub async fn get_logger_definition_with_metadata(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> Result<
+        (GetLoggerDefinitionResponse, ResponseMetadata),
+        RusotoError<GetLoggerDefinitionError>,
+    > {
+        let request = self.build_get_logger_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetGroupVersionResponse, _>()?;
+                .deserialize::<GetLoggerDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGroupVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLoggerDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a logger definition.</p>
-    async fn get_logger_definition(
+    /// Presigns the request `get_logger_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_logger_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLoggerDefinitionRequest,
-    ) -> Result<GetLoggerDefinitionResponse, RusotoError<GetLoggerDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_logger_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_logger_definition_request(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/loggers/{logger_definition_id}",
             logger_definition_id = input.logger_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_logger_definition_version`, also returning the metadata of the response.
+    pub async fn get_logger_definition_version_with_metadata(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> Result<
+        (GetLoggerDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<GetLoggerDefinitionVersionError>,
+    > {
+        let request = self.build_get_logger_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLoggerDefinitionResponse, _>()?;
+                .deserialize::<GetLoggerDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLoggerDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLoggerDefinitionVersionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a logger definition version.</p>
-    async fn get_logger_definition_version(
+    /// Presigns the request `get_logger_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_logger_definition_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLoggerDefinitionVersionRequest,
-    ) -> Result<GetLoggerDefinitionVersionResponse, RusotoError<GetLoggerDefinitionVersionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_logger_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_logger_definition_version_request(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/greengrass/definition/loggers/{logger_definition_id}/versions/{logger_definition_version_id}", logger_definition_id = input.logger_definition_id, logger_definition_version_id = input.logger_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8596,149 +10725,292 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_resource_definition`, also returning the metadata of the response.
+    pub async fn get_resource_definition_with_metadata(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> Result<
+        (GetResourceDefinitionResponse, ResponseMetadata),
+        RusotoError<GetResourceDefinitionError>,
+    > {
+        let request = self.build_get_resource_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLoggerDefinitionVersionResponse, _>()?;
+                .deserialize::<GetResourceDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLoggerDefinitionVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetResourceDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a resource definition, including its creation time and latest version.</p>
-    async fn get_resource_definition(
+    /// Presigns the request `get_resource_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourceDefinitionRequest,
-    ) -> Result<GetResourceDefinitionResponse, RusotoError<GetResourceDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_definition_request(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/resources/{resource_definition_id}",
             resource_definition_id = input.resource_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_resource_definition_version`, also returning the metadata of the response.
+    pub async fn get_resource_definition_version_with_metadata(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> Result<
+        (GetResourceDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<GetResourceDefinitionVersionError>,
+    > {
+        let request = self.build_get_resource_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourceDefinitionResponse, _>()?;
+                .deserialize::<GetResourceDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourceDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetResourceDefinitionVersionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a resource definition version, including which resources are included in the version.</p>
-    async fn get_resource_definition_version(
+    /// Presigns the request `get_resource_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_definition_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourceDefinitionVersionRequest,
-    ) -> Result<GetResourceDefinitionVersionResponse, RusotoError<GetResourceDefinitionVersionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_definition_version_request(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/greengrass/definition/resources/{resource_definition_id}/versions/{resource_definition_version_id}", resource_definition_id = input.resource_definition_id, resource_definition_version_id = input.resource_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_service_role_for_account`, also returning the metadata of the response.
+    pub async fn get_service_role_for_account_with_metadata(
+        &self,
+    ) -> Result<
+        (GetServiceRoleForAccountResponse, ResponseMetadata),
+        RusotoError<GetServiceRoleForAccountError>,
+    > {
+        let request = self.build_get_service_role_for_account_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourceDefinitionVersionResponse, _>()?;
+                .deserialize::<GetServiceRoleForAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourceDefinitionVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetServiceRoleForAccountError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the service role that is attached to your account.</p>
-    async fn get_service_role_for_account(
+    /// Presigns the request `get_service_role_for_account` would send with `presigner` instead of sending it.
+    pub async fn presign_get_service_role_for_account(
         &self,
-    ) -> Result<GetServiceRoleForAccountResponse, RusotoError<GetServiceRoleForAccountError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_service_role_for_account_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_service_role_for_account_request(&self) -> SignedRequest {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetServiceRoleForAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_subscription_definition`, also returning the metadata of the response.
+    pub async fn get_subscription_definition_with_metadata(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> Result<
+        (GetSubscriptionDefinitionResponse, ResponseMetadata),
+        RusotoError<GetSubscriptionDefinitionError>,
+    > {
+        let request = self.build_get_subscription_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetServiceRoleForAccountResponse, _>()?;
+                .deserialize::<GetSubscriptionDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetServiceRoleForAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSubscriptionDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a subscription definition.</p>
-    async fn get_subscription_definition(
+    /// Presigns the request `get_subscription_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_subscription_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSubscriptionDefinitionRequest,
-    ) -> Result<GetSubscriptionDefinitionResponse, RusotoError<GetSubscriptionDefinitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_subscription_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_subscription_definition_request(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/subscriptions/{subscription_definition_id}",
             subscription_definition_id = input.subscription_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_subscription_definition_version`, also returning the metadata of the response.
+    pub async fn get_subscription_definition_version_with_metadata(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> Result<
+        (GetSubscriptionDefinitionVersionResponse, ResponseMetadata),
+        RusotoError<GetSubscriptionDefinitionVersionError>,
+    > {
+        let request = self.build_get_subscription_definition_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSubscriptionDefinitionResponse, _>()?;
+                .deserialize::<GetSubscriptionDefinitionVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSubscriptionDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSubscriptionDefinitionVersionError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves information about a subscription definition version.</p>
-    async fn get_subscription_definition_version(
+    /// Presigns the request `get_subscription_definition_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_subscription_definition_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSubscriptionDefinitionVersionRequest,
-    ) -> Result<
-        GetSubscriptionDefinitionVersionResponse,
-        RusotoError<GetSubscriptionDefinitionVersionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_subscription_definition_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_subscription_definition_version_request(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/greengrass/definition/subscriptions/{subscription_definition_id}/versions/{subscription_definition_version_id}", subscription_definition_id = input.subscription_definition_id, subscription_definition_version_id = input.subscription_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8747,39 +11019,66 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_bulk_deployment_detailed_reports`, also returning the metadata of the response.
+    pub async fn list_bulk_deployment_detailed_reports_with_metadata(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> Result<
+        (ListBulkDeploymentDetailedReportsResponse, ResponseMetadata),
+        RusotoError<ListBulkDeploymentDetailedReportsError>,
+    > {
+        let request = self.build_list_bulk_deployment_detailed_reports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSubscriptionDefinitionVersionResponse, _>()?;
+                .deserialize::<ListBulkDeploymentDetailedReportsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSubscriptionDefinitionVersionError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBulkDeploymentDetailedReportsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Gets a paginated list of the deployments that have been started in a bulk deployment operation, and their current deployment status.</p>
-    async fn list_bulk_deployment_detailed_reports(
+    /// Presigns the request `list_bulk_deployment_detailed_reports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_bulk_deployment_detailed_reports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBulkDeploymentDetailedReportsRequest,
-    ) -> Result<
-        ListBulkDeploymentDetailedReportsResponse,
-        RusotoError<ListBulkDeploymentDetailedReportsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_bulk_deployment_detailed_reports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_bulk_deployment_detailed_reports_request(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/bulk/deployments/{bulk_deployment_id}/detailed-reports",
             bulk_deployment_id = input.bulk_deployment_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeploymentDetailedReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8791,33 +11090,61 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_bulk_deployments`, also returning the metadata of the response.
+    pub async fn list_bulk_deployments_with_metadata(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> Result<
+        (ListBulkDeploymentsResponse, ResponseMetadata),
+        RusotoError<ListBulkDeploymentsError>,
+    > {
+        let request = self.build_list_bulk_deployments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBulkDeploymentDetailedReportsResponse, _>()?;
+                .deserialize::<ListBulkDeploymentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBulkDeploymentDetailedReportsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBulkDeploymentsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of bulk deployments.</p>
-    async fn list_bulk_deployments(
+    /// Presigns the request `list_bulk_deployments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_bulk_deployments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBulkDeploymentsRequest,
-    ) -> Result<ListBulkDeploymentsResponse, RusotoError<ListBulkDeploymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_bulk_deployments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_bulk_deployments_request(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8829,37 +11156,66 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_connector_definition_versions`, also returning the metadata of the response.
+    pub async fn list_connector_definition_versions_with_metadata(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> Result<
+        (ListConnectorDefinitionVersionsResponse, ResponseMetadata),
+        RusotoError<ListConnectorDefinitionVersionsError>,
+    > {
+        let request = self.build_list_connector_definition_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBulkDeploymentsResponse, _>()?;
+                .deserialize::<ListConnectorDefinitionVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBulkDeploymentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConnectorDefinitionVersionsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists the versions of a connector definition, which are containers for connectors. Connectors run on the Greengrass core and contain built-in integration with local infrastructure, device protocols, AWS, and other cloud services.</p>
-    async fn list_connector_definition_versions(
+    /// Presigns the request `list_connector_definition_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_connector_definition_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConnectorDefinitionVersionsRequest,
-    ) -> Result<
-        ListConnectorDefinitionVersionsResponse,
-        RusotoError<ListConnectorDefinitionVersionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_connector_definition_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_connector_definition_versions_request(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/connectors/{connector_definition_id}/versions",
             connector_definition_id = input.connector_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8871,33 +11227,61 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_connector_definitions`, also returning the metadata of the response.
+    pub async fn list_connector_definitions_with_metadata(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> Result<
+        (ListConnectorDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListConnectorDefinitionsError>,
+    > {
+        let request = self.build_list_connector_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConnectorDefinitionVersionsResponse, _>()?;
+                .deserialize::<ListConnectorDefinitionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConnectorDefinitionVersionsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConnectorDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of connector definitions.</p>
-    async fn list_connector_definitions(
+    /// Presigns the request `list_connector_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_connector_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConnectorDefinitionsRequest,
-    ) -> Result<ListConnectorDefinitionsResponse, RusotoError<ListConnectorDefinitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_connector_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_connector_definitions_request(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8909,35 +11293,64 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_core_definition_versions`, also returning the metadata of the response.
+    pub async fn list_core_definition_versions_with_metadata(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> Result<
+        (ListCoreDefinitionVersionsResponse, ResponseMetadata),
+        RusotoError<ListCoreDefinitionVersionsError>,
+    > {
+        let request = self.build_list_core_definition_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConnectorDefinitionsResponse, _>()?;
+                .deserialize::<ListCoreDefinitionVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConnectorDefinitionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListCoreDefinitionVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the versions of a core definition.</p>
-    async fn list_core_definition_versions(
+    /// Presigns the request `list_core_definition_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_core_definition_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCoreDefinitionVersionsRequest,
-    ) -> Result<ListCoreDefinitionVersionsResponse, RusotoError<ListCoreDefinitionVersionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_core_definition_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_core_definition_versions_request(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/cores/{core_definition_id}/versions",
             core_definition_id = input.core_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8949,31 +11362,61 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_core_definitions`, also returning the metadata of the response.
+    pub async fn list_core_definitions_with_metadata(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> Result<
+        (ListCoreDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListCoreDefinitionsError>,
+    > {
+        let request = self.build_list_core_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCoreDefinitionVersionsResponse, _>()?;
+                .deserialize::<ListCoreDefinitionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCoreDefinitionVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListCoreDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of core definitions.</p>
-    async fn list_core_definitions(
+    /// Presigns the request `list_core_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_core_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCoreDefinitionsRequest,
-    ) -> Result<ListCoreDefinitionsResponse, RusotoError<ListCoreDefinitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_core_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_core_definitions_request(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8985,34 +11428,59 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_deployments`, also returning the metadata of the response.
+    pub async fn list_deployments_with_metadata(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> Result<(ListDeploymentsResponse, ResponseMetadata), RusotoError<ListDeploymentsError>>
+    {
+        let request = self.build_list_deployments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCoreDefinitionsResponse, _>()?;
+                .deserialize::<ListDeploymentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCoreDefinitionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDeploymentsError::from_response(response))
         }
     }
 
-    /// <p>Returns a history of deployments for the group.</p>
-    async fn list_deployments(
+    /// Presigns the request `list_deployments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deployments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeploymentsRequest,
-    ) -> Result<ListDeploymentsResponse, RusotoError<ListDeploymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deployments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deployments_request(&self, input: ListDeploymentsRequest) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/deployments",
             group_id = input.group_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9024,35 +11492,64 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_device_definition_versions`, also returning the metadata of the response.
+    pub async fn list_device_definition_versions_with_metadata(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> Result<
+        (ListDeviceDefinitionVersionsResponse, ResponseMetadata),
+        RusotoError<ListDeviceDefinitionVersionsError>,
+    > {
+        let request = self.build_list_device_definition_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeploymentsResponse, _>()?;
+                .deserialize::<ListDeviceDefinitionVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeploymentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDeviceDefinitionVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the versions of a device definition.</p>
-    async fn list_device_definition_versions(
+    /// Presigns the request `list_device_definition_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_device_definition_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeviceDefinitionVersionsRequest,
-    ) -> Result<ListDeviceDefinitionVersionsResponse, RusotoError<ListDeviceDefinitionVersionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_device_definition_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_device_definition_versions_request(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/devices/{device_definition_id}/versions",
             device_definition_id = input.device_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9064,31 +11561,61 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_device_definitions`, also returning the metadata of the response.
+    pub async fn list_device_definitions_with_metadata(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> Result<
+        (ListDeviceDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListDeviceDefinitionsError>,
+    > {
+        let request = self.build_list_device_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeviceDefinitionVersionsResponse, _>()?;
+                .deserialize::<ListDeviceDefinitionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeviceDefinitionVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDeviceDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of device definitions.</p>
-    async fn list_device_definitions(
+    /// Presigns the request `list_device_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_device_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeviceDefinitionsRequest,
-    ) -> Result<ListDeviceDefinitionsResponse, RusotoError<ListDeviceDefinitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_device_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_device_definitions_request(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9100,37 +11627,64 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_function_definition_versions`, also returning the metadata of the response.
+    pub async fn list_function_definition_versions_with_metadata(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> Result<
+        (ListFunctionDefinitionVersionsResponse, ResponseMetadata),
+        RusotoError<ListFunctionDefinitionVersionsError>,
+    > {
+        let request = self.build_list_function_definition_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeviceDefinitionsResponse, _>()?;
+                .deserialize::<ListFunctionDefinitionVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeviceDefinitionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFunctionDefinitionVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the versions of a Lambda function definition.</p>
-    async fn list_function_definition_versions(
+    /// Presigns the request `list_function_definition_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_function_definition_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFunctionDefinitionVersionsRequest,
-    ) -> Result<
-        ListFunctionDefinitionVersionsResponse,
-        RusotoError<ListFunctionDefinitionVersionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_function_definition_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_function_definition_versions_request(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/functions/{function_definition_id}/versions",
             function_definition_id = input.function_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9142,31 +11696,61 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_function_definitions`, also returning the metadata of the response.
+    pub async fn list_function_definitions_with_metadata(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> Result<
+        (ListFunctionDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListFunctionDefinitionsError>,
+    > {
+        let request = self.build_list_function_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFunctionDefinitionVersionsResponse, _>()?;
+                .deserialize::<ListFunctionDefinitionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFunctionDefinitionVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFunctionDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of Lambda function definitions.</p>
-    async fn list_function_definitions(
+    /// Presigns the request `list_function_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_function_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFunctionDefinitionsRequest,
-    ) -> Result<ListFunctionDefinitionsResponse, RusotoError<ListFunctionDefinitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_function_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_function_definitions_request(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9178,69 +11762,121 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_group_certificate_authorities`, also returning the metadata of the response.
+    pub async fn list_group_certificate_authorities_with_metadata(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> Result<
+        (ListGroupCertificateAuthoritiesResponse, ResponseMetadata),
+        RusotoError<ListGroupCertificateAuthoritiesError>,
+    > {
+        let request = self.build_list_group_certificate_authorities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFunctionDefinitionsResponse, _>()?;
+                .deserialize::<ListGroupCertificateAuthoritiesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFunctionDefinitionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListGroupCertificateAuthoritiesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the current CAs for a group.</p>
-    async fn list_group_certificate_authorities(
+    /// Presigns the request `list_group_certificate_authorities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_group_certificate_authorities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGroupCertificateAuthoritiesRequest,
-    ) -> Result<
-        ListGroupCertificateAuthoritiesResponse,
-        RusotoError<ListGroupCertificateAuthoritiesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_group_certificate_authorities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_group_certificate_authorities_request(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/certificateauthorities",
             group_id = input.group_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupCertificateAuthorities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_group_versions`, also returning the metadata of the response.
+    pub async fn list_group_versions_with_metadata(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> Result<(ListGroupVersionsResponse, ResponseMetadata), RusotoError<ListGroupVersionsError>>
+    {
+        let request = self.build_list_group_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGroupCertificateAuthoritiesResponse, _>()?;
+                .deserialize::<ListGroupVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGroupCertificateAuthoritiesError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListGroupVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the versions of a group.</p>
-    async fn list_group_versions(
+    /// Presigns the request `list_group_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_group_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGroupVersionsRequest,
-    ) -> Result<ListGroupVersionsResponse, RusotoError<ListGroupVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_group_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_group_versions_request(&self, input: ListGroupVersionsRequest) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/versions",
             group_id = input.group_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9252,31 +11888,55 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_groups`, also returning the metadata of the response.
+    pub async fn list_groups_with_metadata(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<(ListGroupsResponse, ResponseMetadata), RusotoError<ListGroupsError>> {
+        let request = self.build_list_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGroupVersionsResponse, _>()?;
+                .deserialize::<ListGroupsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGroupVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListGroupsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of groups.</p>
-    async fn list_groups(
+    /// Presigns the request `list_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGroupsRequest,
-    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_groups_request(&self, input: ListGroupsRequest) -> SignedRequest {
         let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9288,35 +11948,64 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_logger_definition_versions`, also returning the metadata of the response.
+    pub async fn list_logger_definition_versions_with_metadata(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> Result<
+        (ListLoggerDefinitionVersionsResponse, ResponseMetadata),
+        RusotoError<ListLoggerDefinitionVersionsError>,
+    > {
+        let request = self.build_list_logger_definition_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGroupsResponse, _>()?;
+                .deserialize::<ListLoggerDefinitionVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListLoggerDefinitionVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the versions of a logger definition.</p>
-    async fn list_logger_definition_versions(
+    /// Presigns the request `list_logger_definition_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_logger_definition_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLoggerDefinitionVersionsRequest,
-    ) -> Result<ListLoggerDefinitionVersionsResponse, RusotoError<ListLoggerDefinitionVersionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_logger_definition_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_logger_definition_versions_request(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/loggers/{logger_definition_id}/versions",
             logger_definition_id = input.logger_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9328,31 +12017,61 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_logger_definitions`, also returning the metadata of the response.
+    pub async fn list_logger_definitions_with_metadata(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> Result<
+        (ListLoggerDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListLoggerDefinitionsError>,
+    > {
+        let request = self.build_list_logger_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLoggerDefinitionVersionsResponse, _>()?;
+                .deserialize::<ListLoggerDefinitionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLoggerDefinitionVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListLoggerDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of logger definitions.</p>
-    async fn list_logger_definitions(
+    /// Presigns the request `list_logger_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_logger_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLoggerDefinitionsRequest,
-    ) -> Result<ListLoggerDefinitionsResponse, RusotoError<ListLoggerDefinitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_logger_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_logger_definitions_request(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/definition/loggers";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9364,37 +12083,64 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_resource_definition_versions`, also returning the metadata of the response.
+    pub async fn list_resource_definition_versions_with_metadata(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> Result<
+        (ListResourceDefinitionVersionsResponse, ResponseMetadata),
+        RusotoError<ListResourceDefinitionVersionsError>,
+    > {
+        let request = self.build_list_resource_definition_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLoggerDefinitionsResponse, _>()?;
+                .deserialize::<ListResourceDefinitionVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLoggerDefinitionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListResourceDefinitionVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the versions of a resource definition.</p>
-    async fn list_resource_definition_versions(
+    /// Presigns the request `list_resource_definition_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resource_definition_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourceDefinitionVersionsRequest,
-    ) -> Result<
-        ListResourceDefinitionVersionsResponse,
-        RusotoError<ListResourceDefinitionVersionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resource_definition_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resource_definition_versions_request(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/resources/{resource_definition_id}/versions",
             resource_definition_id = input.resource_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9406,31 +12152,61 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_resource_definitions`, also returning the metadata of the response.
+    pub async fn list_resource_definitions_with_metadata(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> Result<
+        (ListResourceDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListResourceDefinitionsError>,
+    > {
+        let request = self.build_list_resource_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourceDefinitionVersionsResponse, _>()?;
+                .deserialize::<ListResourceDefinitionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourceDefinitionVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListResourceDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of resource definitions.</p>
-    async fn list_resource_definitions(
+    /// Presigns the request `list_resource_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resource_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourceDefinitionsRequest,
-    ) -> Result<ListResourceDefinitionsResponse, RusotoError<ListResourceDefinitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resource_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resource_definitions_request(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9442,37 +12218,66 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_subscription_definition_versions`, also returning the metadata of the response.
+    pub async fn list_subscription_definition_versions_with_metadata(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> Result<
+        (ListSubscriptionDefinitionVersionsResponse, ResponseMetadata),
+        RusotoError<ListSubscriptionDefinitionVersionsError>,
+    > {
+        let request = self.build_list_subscription_definition_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourceDefinitionsResponse, _>()?;
+                .deserialize::<ListSubscriptionDefinitionVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourceDefinitionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSubscriptionDefinitionVersionsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists the versions of a subscription definition.</p>
-    async fn list_subscription_definition_versions(
+    /// Presigns the request `list_subscription_definition_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_subscription_definition_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSubscriptionDefinitionVersionsRequest,
-    ) -> Result<
-        ListSubscriptionDefinitionVersionsResponse,
-        RusotoError<ListSubscriptionDefinitionVersionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_subscription_definition_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_subscription_definition_versions_request(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/subscriptions/{subscription_definition_id}/versions",
             subscription_definition_id = input.subscription_definition_id
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9484,34 +12289,61 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_subscription_definitions`, also returning the metadata of the response.
+    pub async fn list_subscription_definitions_with_metadata(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> Result<
+        (ListSubscriptionDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListSubscriptionDefinitionsError>,
+    > {
+        let request = self.build_list_subscription_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSubscriptionDefinitionVersionsResponse, _>()?;
+                .deserialize::<ListSubscriptionDefinitionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSubscriptionDefinitionVersionsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSubscriptionDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of subscription definitions.</p>
-    async fn list_subscription_definitions(
+    /// Presigns the request `list_subscription_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_subscription_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSubscriptionDefinitionsRequest,
-    ) -> Result<ListSubscriptionDefinitionsResponse, RusotoError<ListSubscriptionDefinitionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_subscription_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_subscription_definitions_request(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9523,61 +12355,116 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSubscriptionDefinitionsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSubscriptionDefinitionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of resource tags for a resource arn.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `reset_deployments`, also returning the metadata of the response.
+    pub async fn reset_deployments_with_metadata(
+        &self,
+        input: ResetDeploymentsRequest,
+    ) -> Result<(ResetDeploymentsResponse, ResponseMetadata), RusotoError<ResetDeploymentsError>>
+    {
+        let request = self.build_reset_deployments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<ResetDeploymentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ResetDeploymentsError::from_response(response))
         }
     }
 
-    /// <p>Resets a group&#39;s deployments.</p>
-    async fn reset_deployments(
+    /// Presigns the request `reset_deployments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_deployments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResetDeploymentsRequest,
-    ) -> Result<ResetDeploymentsResponse, RusotoError<ResetDeploymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_deployments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_deployments_request(&self, input: ResetDeploymentsRequest) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/deployments/$reset",
             group_id = input.group_id
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("ResetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9587,31 +12474,61 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `start_bulk_deployment`, also returning the metadata of the response.
+    pub async fn start_bulk_deployment_with_metadata(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> Result<
+        (StartBulkDeploymentResponse, ResponseMetadata),
+        RusotoError<StartBulkDeploymentError>,
+    > {
+        let request = self.build_start_bulk_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ResetDeploymentsResponse, _>()?;
+                .deserialize::<StartBulkDeploymentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ResetDeploymentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartBulkDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Deploys multiple groups in one operation. This action starts the bulk deployment of a specified set of group versions. Each group version deployment will be triggered with an adaptive rate that has a fixed upper limit. We recommend that you include an &#39;&#39;X-Amzn-Client-Token&#39;&#39; token in every &#39;&#39;StartBulkDeployment&#39;&#39; request. These requests are idempotent with respect to the token and the request parameters.</p>
-    async fn start_bulk_deployment(
+    /// Presigns the request `start_bulk_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_bulk_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartBulkDeploymentRequest,
-    ) -> Result<StartBulkDeploymentResponse, RusotoError<StartBulkDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_bulk_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_bulk_deployment_request(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> SignedRequest {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("StartBulkDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9621,90 +12538,165 @@ impl GreenGrass for GreenGrassClient {
             request.add_header("X-Amzn-Client-Token", &amzn_client_token.to_string());
         }
 
+        request
+    }
+
+    /// Calls `stop_bulk_deployment`, also returning the metadata of the response.
+    pub async fn stop_bulk_deployment_with_metadata(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> Result<(StopBulkDeploymentResponse, ResponseMetadata), RusotoError<StopBulkDeploymentError>>
+    {
+        let request = self.build_stop_bulk_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartBulkDeploymentResponse, _>()?;
+                .deserialize::<StopBulkDeploymentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartBulkDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopBulkDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Stops the execution of a bulk deployment. This action returns a status of &#39;&#39;Stopping&#39;&#39; until the deployment is stopped. You cannot start a new bulk deployment while a previous deployment is in the &#39;&#39;Stopping&#39;&#39; state. This action doesn&#39;t rollback completed deployments or cancel pending deployments.</p>
-    async fn stop_bulk_deployment(
+    /// Presigns the request `stop_bulk_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_bulk_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopBulkDeploymentRequest,
-    ) -> Result<StopBulkDeploymentResponse, RusotoError<StopBulkDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_bulk_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_bulk_deployment_request(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/bulk/deployments/{bulk_deployment_id}/$stop",
             bulk_deployment_id = input.bulk_deployment_id
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("StopBulkDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopBulkDeploymentResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopBulkDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds tags to a Greengrass resource. Valid resources are &#39;Group&#39;, &#39;ConnectorDefinition&#39;, &#39;CoreDefinition&#39;, &#39;DeviceDefinition&#39;, &#39;FunctionDefinition&#39;, &#39;LoggerDefinition&#39;, &#39;SubscriptionDefinition&#39;, &#39;ResourceDefinition&#39;, and &#39;BulkDeployment&#39;.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Remove resource tags from a Greengrass Resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9713,353 +12705,2390 @@ impl GreenGrass for GreenGrassClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_connectivity_info`, also returning the metadata of the response.
+    pub async fn update_connectivity_info_with_metadata(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> Result<
+        (UpdateConnectivityInfoResponse, ResponseMetadata),
+        RusotoError<UpdateConnectivityInfoError>,
+    > {
+        let request = self.build_update_connectivity_info_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateConnectivityInfoResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateConnectivityInfoError::from_response(response))
         }
     }
 
-    /// <p>Updates the connectivity information for the core. Any devices that belong to the group which has this core will receive this information in order to find the location of the core and connect to it.</p>
-    async fn update_connectivity_info(
+    /// Presigns the request `update_connectivity_info` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_connectivity_info(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConnectivityInfoRequest,
-    ) -> Result<UpdateConnectivityInfoResponse, RusotoError<UpdateConnectivityInfoError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_connectivity_info_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_connectivity_info_request(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/things/{thing_name}/connectivityInfo",
             thing_name = input.thing_name
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectivityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_connector_definition`, also returning the metadata of the response.
+    pub async fn update_connector_definition_with_metadata(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> Result<
+        (UpdateConnectorDefinitionResponse, ResponseMetadata),
+        RusotoError<UpdateConnectorDefinitionError>,
+    > {
+        let request = self.build_update_connector_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateConnectivityInfoResponse, _>()?;
+                .deserialize::<UpdateConnectorDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConnectivityInfoError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateConnectorDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Updates a connector definition.</p>
-    async fn update_connector_definition(
+    /// Presigns the request `update_connector_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_connector_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConnectorDefinitionRequest,
-    ) -> Result<UpdateConnectorDefinitionResponse, RusotoError<UpdateConnectorDefinitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_connector_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_connector_definition_request(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/connectors/{connector_definition_id}",
             connector_definition_id = input.connector_definition_id
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_core_definition`, also returning the metadata of the response.
+    pub async fn update_core_definition_with_metadata(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> Result<
+        (UpdateCoreDefinitionResponse, ResponseMetadata),
+        RusotoError<UpdateCoreDefinitionError>,
+    > {
+        let request = self.build_update_core_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateConnectorDefinitionResponse, _>()?;
+                .deserialize::<UpdateCoreDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConnectorDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateCoreDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Updates a core definition.</p>
-    async fn update_core_definition(
+    /// Presigns the request `update_core_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_core_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCoreDefinitionRequest,
-    ) -> Result<UpdateCoreDefinitionResponse, RusotoError<UpdateCoreDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_core_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_core_definition_request(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/cores/{core_definition_id}",
             core_definition_id = input.core_definition_id
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_device_definition`, also returning the metadata of the response.
+    pub async fn update_device_definition_with_metadata(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> Result<
+        (UpdateDeviceDefinitionResponse, ResponseMetadata),
+        RusotoError<UpdateDeviceDefinitionError>,
+    > {
+        let request = self.build_update_device_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateCoreDefinitionResponse, _>()?;
+                .deserialize::<UpdateDeviceDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCoreDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDeviceDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Updates a device definition.</p>
-    async fn update_device_definition(
+    /// Presigns the request `update_device_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_device_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDeviceDefinitionRequest,
-    ) -> Result<UpdateDeviceDefinitionResponse, RusotoError<UpdateDeviceDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_device_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_device_definition_request(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/devices/{device_definition_id}",
             device_definition_id = input.device_definition_id
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_function_definition`, also returning the metadata of the response.
+    pub async fn update_function_definition_with_metadata(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> Result<
+        (UpdateFunctionDefinitionResponse, ResponseMetadata),
+        RusotoError<UpdateFunctionDefinitionError>,
+    > {
+        let request = self.build_update_function_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDeviceDefinitionResponse, _>()?;
+                .deserialize::<UpdateFunctionDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDeviceDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFunctionDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Updates a Lambda function definition.</p>
-    async fn update_function_definition(
+    /// Presigns the request `update_function_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_function_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFunctionDefinitionRequest,
-    ) -> Result<UpdateFunctionDefinitionResponse, RusotoError<UpdateFunctionDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_function_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_function_definition_request(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/functions/{function_definition_id}",
             function_definition_id = input.function_definition_id
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_group`, also returning the metadata of the response.
+    pub async fn update_group_with_metadata(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<(UpdateGroupResponse, ResponseMetadata), RusotoError<UpdateGroupError>> {
+        let request = self.build_update_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFunctionDefinitionResponse, _>()?;
+                .deserialize::<UpdateGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFunctionDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateGroupError::from_response(response))
         }
     }
 
-    /// <p>Updates a group.</p>
-    async fn update_group(
+    /// Presigns the request `update_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGroupRequest,
-    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_group_request(&self, input: UpdateGroupRequest) -> SignedRequest {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_group_certificate_configuration`, also returning the metadata of the response.
+    pub async fn update_group_certificate_configuration_with_metadata(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> Result<
+        (
+            UpdateGroupCertificateConfigurationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateGroupCertificateConfigurationError>,
+    > {
+        let request = self.build_update_group_certificate_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGroupResponse, _>()?;
+                .deserialize::<UpdateGroupCertificateConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateGroupCertificateConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Updates the Certificate expiry time for a group.</p>
-    async fn update_group_certificate_configuration(
+    /// Presigns the request `update_group_certificate_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_group_certificate_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGroupCertificateConfigurationRequest,
-    ) -> Result<
-        UpdateGroupCertificateConfigurationResponse,
-        RusotoError<UpdateGroupCertificateConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_group_certificate_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_group_certificate_configuration_request(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/groups/{group_id}/certificateauthorities/configuration/expiry",
             group_id = input.group_id
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroupCertificateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_logger_definition`, also returning the metadata of the response.
+    pub async fn update_logger_definition_with_metadata(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> Result<
+        (UpdateLoggerDefinitionResponse, ResponseMetadata),
+        RusotoError<UpdateLoggerDefinitionError>,
+    > {
+        let request = self.build_update_logger_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGroupCertificateConfigurationResponse, _>()?;
+                .deserialize::<UpdateLoggerDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGroupCertificateConfigurationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateLoggerDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Updates a logger definition.</p>
-    async fn update_logger_definition(
+    /// Presigns the request `update_logger_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_logger_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLoggerDefinitionRequest,
-    ) -> Result<UpdateLoggerDefinitionResponse, RusotoError<UpdateLoggerDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_logger_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_logger_definition_request(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/loggers/{logger_definition_id}",
             logger_definition_id = input.logger_definition_id
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_resource_definition`, also returning the metadata of the response.
+    pub async fn update_resource_definition_with_metadata(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> Result<
+        (UpdateResourceDefinitionResponse, ResponseMetadata),
+        RusotoError<UpdateResourceDefinitionError>,
+    > {
+        let request = self.build_update_resource_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateLoggerDefinitionResponse, _>()?;
+                .deserialize::<UpdateResourceDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLoggerDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateResourceDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Updates a resource definition.</p>
-    async fn update_resource_definition(
+    /// Presigns the request `update_resource_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_resource_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateResourceDefinitionRequest,
-    ) -> Result<UpdateResourceDefinitionResponse, RusotoError<UpdateResourceDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_resource_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_resource_definition_request(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/resources/{resource_definition_id}",
             resource_definition_id = input.resource_definition_id
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_subscription_definition`, also returning the metadata of the response.
+    pub async fn update_subscription_definition_with_metadata(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> Result<
+        (UpdateSubscriptionDefinitionResponse, ResponseMetadata),
+        RusotoError<UpdateSubscriptionDefinitionError>,
+    > {
+        let request = self.build_update_subscription_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateResourceDefinitionResponse, _>()?;
+                .deserialize::<UpdateSubscriptionDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateResourceDefinitionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateSubscriptionDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Updates a subscription definition.</p>
-    async fn update_subscription_definition(
+    /// Presigns the request `update_subscription_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_subscription_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSubscriptionDefinitionRequest,
-    ) -> Result<UpdateSubscriptionDefinitionResponse, RusotoError<UpdateSubscriptionDefinitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_subscription_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_subscription_definition_request(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/greengrass/definition/subscriptions/{subscription_definition_id}",
             subscription_definition_id = input.subscription_definition_id
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSubscriptionDefinitionResponse, _>()?;
+        request
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSubscriptionDefinitionError::from_response(response))
-        }
+    /// The pages of `ListBulkDeploymentDetailedReports` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_bulk_deployment_detailed_reports_pages(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListBulkDeploymentDetailedReportsResponse,
+            RusotoError<ListBulkDeploymentDetailedReportsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_bulk_deployment_detailed_reports(input).await
+            },
+            |input: &ListBulkDeploymentDetailedReportsRequest,
+             output: &ListBulkDeploymentDetailedReportsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBulkDeploymentDetailedReportsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Deployments` of every page of `ListBulkDeploymentDetailedReports` results for `input`, as `list_bulk_deployment_detailed_reports_pages` requests them.
+    pub fn list_bulk_deployment_detailed_reports_stream(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<BulkDeploymentResult, RusotoError<ListBulkDeploymentDetailedReportsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_bulk_deployment_detailed_reports_pages(input),
+            |page| page.deployments.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListBulkDeployments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_bulk_deployments_pages(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListBulkDeploymentsResponse, RusotoError<ListBulkDeploymentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move { client.list_bulk_deployments(input).await },
+            |input: &ListBulkDeploymentsRequest, output: &ListBulkDeploymentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBulkDeploymentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BulkDeployments` of every page of `ListBulkDeployments` results for `input`, as `list_bulk_deployments_pages` requests them.
+    pub fn list_bulk_deployments_stream(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<BulkDeployment, RusotoError<ListBulkDeploymentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_bulk_deployments_pages(input), |page| {
+            page.bulk_deployments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListConnectorDefinitionVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_connector_definition_versions_pages(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListConnectorDefinitionVersionsResponse,
+            RusotoError<ListConnectorDefinitionVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_connector_definition_versions(input).await
+            },
+            |input: &ListConnectorDefinitionVersionsRequest,
+             output: &ListConnectorDefinitionVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListConnectorDefinitionVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListConnectorDefinitionVersions` results for `input`, as `list_connector_definition_versions_pages` requests them.
+    pub fn list_connector_definition_versions_stream(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<VersionInformation, RusotoError<ListConnectorDefinitionVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_connector_definition_versions_pages(input),
+            |page| page.versions.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListConnectorDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_connector_definitions_pages(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListConnectorDefinitionsResponse, RusotoError<ListConnectorDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_connector_definitions(input).await
+            },
+            |input: &ListConnectorDefinitionsRequest, output: &ListConnectorDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListConnectorDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Definitions` of every page of `ListConnectorDefinitions` results for `input`, as `list_connector_definitions_pages` requests them.
+    pub fn list_connector_definitions_stream(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DefinitionInformation, RusotoError<ListConnectorDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_connector_definitions_pages(input), |page| {
+            page.definitions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCoreDefinitionVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_core_definition_versions_pages(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListCoreDefinitionVersionsResponse,
+            RusotoError<ListCoreDefinitionVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_core_definition_versions(input).await
+            },
+            |input: &ListCoreDefinitionVersionsRequest,
+             output: &ListCoreDefinitionVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCoreDefinitionVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListCoreDefinitionVersions` results for `input`, as `list_core_definition_versions_pages` requests them.
+    pub fn list_core_definition_versions_stream(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<VersionInformation, RusotoError<ListCoreDefinitionVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_core_definition_versions_pages(input), |page| {
+            page.versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCoreDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_core_definitions_pages(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListCoreDefinitionsResponse, RusotoError<ListCoreDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move { client.list_core_definitions(input).await },
+            |input: &ListCoreDefinitionsRequest, output: &ListCoreDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCoreDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Definitions` of every page of `ListCoreDefinitions` results for `input`, as `list_core_definitions_pages` requests them.
+    pub fn list_core_definitions_stream(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DefinitionInformation, RusotoError<ListCoreDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_core_definitions_pages(input), |page| {
+            page.definitions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeployments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_deployments_pages(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDeploymentsResponse, RusotoError<ListDeploymentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move { client.list_deployments(input).await },
+            |input: &ListDeploymentsRequest, output: &ListDeploymentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeploymentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Deployments` of every page of `ListDeployments` results for `input`, as `list_deployments_pages` requests them.
+    pub fn list_deployments_stream(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<Deployment, RusotoError<ListDeploymentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_deployments_pages(input), |page| {
+            page.deployments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeviceDefinitionVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_device_definition_versions_pages(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListDeviceDefinitionVersionsResponse,
+            RusotoError<ListDeviceDefinitionVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_device_definition_versions(input).await
+            },
+            |input: &ListDeviceDefinitionVersionsRequest,
+             output: &ListDeviceDefinitionVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeviceDefinitionVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListDeviceDefinitionVersions` results for `input`, as `list_device_definition_versions_pages` requests them.
+    pub fn list_device_definition_versions_stream(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<VersionInformation, RusotoError<ListDeviceDefinitionVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_device_definition_versions_pages(input), |page| {
+            page.versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeviceDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_device_definitions_pages(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDeviceDefinitionsResponse, RusotoError<ListDeviceDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move { client.list_device_definitions(input).await },
+            |input: &ListDeviceDefinitionsRequest, output: &ListDeviceDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeviceDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Definitions` of every page of `ListDeviceDefinitions` results for `input`, as `list_device_definitions_pages` requests them.
+    pub fn list_device_definitions_stream(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DefinitionInformation, RusotoError<ListDeviceDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_device_definitions_pages(input), |page| {
+            page.definitions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFunctionDefinitionVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_function_definition_versions_pages(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListFunctionDefinitionVersionsResponse,
+            RusotoError<ListFunctionDefinitionVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_function_definition_versions(input).await
+            },
+            |input: &ListFunctionDefinitionVersionsRequest,
+             output: &ListFunctionDefinitionVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFunctionDefinitionVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListFunctionDefinitionVersions` results for `input`, as `list_function_definition_versions_pages` requests them.
+    pub fn list_function_definition_versions_stream(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<VersionInformation, RusotoError<ListFunctionDefinitionVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_function_definition_versions_pages(input),
+            |page| page.versions.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListFunctionDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_function_definitions_pages(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListFunctionDefinitionsResponse, RusotoError<ListFunctionDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_function_definitions(input).await
+            },
+            |input: &ListFunctionDefinitionsRequest, output: &ListFunctionDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFunctionDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Definitions` of every page of `ListFunctionDefinitions` results for `input`, as `list_function_definitions_pages` requests them.
+    pub fn list_function_definitions_stream(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DefinitionInformation, RusotoError<ListFunctionDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_function_definitions_pages(input), |page| {
+            page.definitions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGroupVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_group_versions_pages(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListGroupVersionsResponse, RusotoError<ListGroupVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move { client.list_group_versions(input).await },
+            |input: &ListGroupVersionsRequest, output: &ListGroupVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGroupVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListGroupVersions` results for `input`, as `list_group_versions_pages` requests them.
+    pub fn list_group_versions_stream(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<VersionInformation, RusotoError<ListGroupVersionsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_group_versions_pages(input), |page| {
+            page.versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<ListGroupsResponse, RusotoError<ListGroupsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move { client.list_groups(input).await },
+            |input: &ListGroupsRequest, output: &ListGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Groups` of every page of `ListGroups` results for `input`, as `list_groups_pages` requests them.
+    pub fn list_groups_stream(
+        &self,
+        input: ListGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<GroupInformation, RusotoError<ListGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_groups_pages(input), |page| {
+            page.groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLoggerDefinitionVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_logger_definition_versions_pages(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListLoggerDefinitionVersionsResponse,
+            RusotoError<ListLoggerDefinitionVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_logger_definition_versions(input).await
+            },
+            |input: &ListLoggerDefinitionVersionsRequest,
+             output: &ListLoggerDefinitionVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLoggerDefinitionVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListLoggerDefinitionVersions` results for `input`, as `list_logger_definition_versions_pages` requests them.
+    pub fn list_logger_definition_versions_stream(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<VersionInformation, RusotoError<ListLoggerDefinitionVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_logger_definition_versions_pages(input), |page| {
+            page.versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLoggerDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_logger_definitions_pages(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListLoggerDefinitionsResponse, RusotoError<ListLoggerDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move { client.list_logger_definitions(input).await },
+            |input: &ListLoggerDefinitionsRequest, output: &ListLoggerDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLoggerDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Definitions` of every page of `ListLoggerDefinitions` results for `input`, as `list_logger_definitions_pages` requests them.
+    pub fn list_logger_definitions_stream(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DefinitionInformation, RusotoError<ListLoggerDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_logger_definitions_pages(input), |page| {
+            page.definitions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListResourceDefinitionVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resource_definition_versions_pages(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListResourceDefinitionVersionsResponse,
+            RusotoError<ListResourceDefinitionVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_resource_definition_versions(input).await
+            },
+            |input: &ListResourceDefinitionVersionsRequest,
+             output: &ListResourceDefinitionVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResourceDefinitionVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListResourceDefinitionVersions` results for `input`, as `list_resource_definition_versions_pages` requests them.
+    pub fn list_resource_definition_versions_stream(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<VersionInformation, RusotoError<ListResourceDefinitionVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_resource_definition_versions_pages(input),
+            |page| page.versions.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListResourceDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resource_definitions_pages(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListResourceDefinitionsResponse, RusotoError<ListResourceDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_resource_definitions(input).await
+            },
+            |input: &ListResourceDefinitionsRequest, output: &ListResourceDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResourceDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Definitions` of every page of `ListResourceDefinitions` results for `input`, as `list_resource_definitions_pages` requests them.
+    pub fn list_resource_definitions_stream(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DefinitionInformation, RusotoError<ListResourceDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_resource_definitions_pages(input), |page| {
+            page.definitions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSubscriptionDefinitionVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_subscription_definition_versions_pages(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListSubscriptionDefinitionVersionsResponse,
+            RusotoError<ListSubscriptionDefinitionVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_subscription_definition_versions(input).await
+            },
+            |input: &ListSubscriptionDefinitionVersionsRequest,
+             output: &ListSubscriptionDefinitionVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSubscriptionDefinitionVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListSubscriptionDefinitionVersions` results for `input`, as `list_subscription_definition_versions_pages` requests them.
+    pub fn list_subscription_definition_versions_stream(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<VersionInformation, RusotoError<ListSubscriptionDefinitionVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_subscription_definition_versions_pages(input),
+            |page| page.versions.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListSubscriptionDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_subscription_definitions_pages(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListSubscriptionDefinitionsResponse,
+            RusotoError<ListSubscriptionDefinitionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GreenGrassClient, input| async move {
+                client.list_subscription_definitions(input).await
+            },
+            |input: &ListSubscriptionDefinitionsRequest,
+             output: &ListSubscriptionDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSubscriptionDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Definitions` of every page of `ListSubscriptionDefinitions` results for `input`, as `list_subscription_definitions_pages` requests them.
+    pub fn list_subscription_definitions_stream(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DefinitionInformation, RusotoError<ListSubscriptionDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_subscription_definitions_pages(input), |page| {
+            page.definitions.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl GreenGrass for GreenGrassClient {
+    /// <p>Associates a role with a group. Your Greengrass core will use the role to access AWS cloud services. The role&#39;s permissions should allow Greengrass core Lambda functions to perform actions against the cloud.</p>
+    async fn associate_role_to_group(
+        &self,
+        input: AssociateRoleToGroupRequest,
+    ) -> Result<AssociateRoleToGroupResponse, RusotoError<AssociateRoleToGroupError>> {
+        self.associate_role_to_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a role with your account. AWS IoT Greengrass will use the role to access your Lambda functions and AWS IoT resources. This is necessary for deployments to succeed. The role must have at least minimum permissions in the policy &#39;&#39;AWSGreengrassResourceAccessRolePolicy&#39;&#39;.</p>
+    async fn associate_service_role_to_account(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> Result<
+        AssociateServiceRoleToAccountResponse,
+        RusotoError<AssociateServiceRoleToAccountError>,
+    > {
+        self.associate_service_role_to_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a connector definition. You may provide the initial version of the connector definition now or use &#39;&#39;CreateConnectorDefinitionVersion&#39;&#39; at a later time.</p>
+    async fn create_connector_definition(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> Result<CreateConnectorDefinitionResponse, RusotoError<CreateConnectorDefinitionError>>
+    {
+        self.create_connector_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of a connector definition which has already been defined.</p>
+    async fn create_connector_definition_version(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> Result<
+        CreateConnectorDefinitionVersionResponse,
+        RusotoError<CreateConnectorDefinitionVersionError>,
+    > {
+        self.create_connector_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a core definition. You may provide the initial version of the core definition now or use &#39;&#39;CreateCoreDefinitionVersion&#39;&#39; at a later time. Greengrass groups must each contain exactly one Greengrass core.</p>
+    async fn create_core_definition(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> Result<CreateCoreDefinitionResponse, RusotoError<CreateCoreDefinitionError>> {
+        self.create_core_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of a core definition that has already been defined. Greengrass groups must each contain exactly one Greengrass core.</p>
+    async fn create_core_definition_version(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> Result<CreateCoreDefinitionVersionResponse, RusotoError<CreateCoreDefinitionVersionError>>
+    {
+        self.create_core_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a deployment. &#39;&#39;CreateDeployment&#39;&#39; requests are idempotent with respect to the &#39;&#39;X-Amzn-Client-Token&#39;&#39; token and the request parameters.</p>
+    async fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResponse, RusotoError<CreateDeploymentError>> {
+        self.create_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a device definition. You may provide the initial version of the device definition now or use &#39;&#39;CreateDeviceDefinitionVersion&#39;&#39; at a later time.</p>
+    async fn create_device_definition(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> Result<CreateDeviceDefinitionResponse, RusotoError<CreateDeviceDefinitionError>> {
+        self.create_device_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of a device definition that has already been defined.</p>
+    async fn create_device_definition_version(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> Result<
+        CreateDeviceDefinitionVersionResponse,
+        RusotoError<CreateDeviceDefinitionVersionError>,
+    > {
+        self.create_device_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Lambda function definition which contains a list of Lambda functions and their configurations to be used in a group. You can create an initial version of the definition by providing a list of Lambda functions and their configurations now, or use &#39;&#39;CreateFunctionDefinitionVersion&#39;&#39; later.</p>
+    async fn create_function_definition(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> Result<CreateFunctionDefinitionResponse, RusotoError<CreateFunctionDefinitionError>> {
+        self.create_function_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of a Lambda function definition that has already been defined.</p>
+    async fn create_function_definition_version(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> Result<
+        CreateFunctionDefinitionVersionResponse,
+        RusotoError<CreateFunctionDefinitionVersionError>,
+    > {
+        self.create_function_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a group. You may provide the initial version of the group or use &#39;&#39;CreateGroupVersion&#39;&#39; at a later time. Tip: You can use the &#39;&#39;gg<em>group</em>setup&#39;&#39; package (https://github.com/awslabs/aws-greengrass-group-setup) as a library or command-line application to create and deploy Greengrass groups.</p>
+    async fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.create_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a CA for the group. If a CA already exists, it will rotate the existing CA.</p>
+    async fn create_group_certificate_authority(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> Result<
+        CreateGroupCertificateAuthorityResponse,
+        RusotoError<CreateGroupCertificateAuthorityError>,
+    > {
+        self.create_group_certificate_authority_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of a group which has already been defined.</p>
+    async fn create_group_version(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> Result<CreateGroupVersionResponse, RusotoError<CreateGroupVersionError>> {
+        self.create_group_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a logger definition. You may provide the initial version of the logger definition now or use &#39;&#39;CreateLoggerDefinitionVersion&#39;&#39; at a later time.</p>
+    async fn create_logger_definition(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> Result<CreateLoggerDefinitionResponse, RusotoError<CreateLoggerDefinitionError>> {
+        self.create_logger_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of a logger definition that has already been defined.</p>
+    async fn create_logger_definition_version(
+        &self,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> Result<
+        CreateLoggerDefinitionVersionResponse,
+        RusotoError<CreateLoggerDefinitionVersionError>,
+    > {
+        self.create_logger_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a resource definition which contains a list of resources to be used in a group. You can create an initial version of the definition by providing a list of resources now, or use &#39;&#39;CreateResourceDefinitionVersion&#39;&#39; later.</p>
+    async fn create_resource_definition(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> Result<CreateResourceDefinitionResponse, RusotoError<CreateResourceDefinitionError>> {
+        self.create_resource_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of a resource definition that has already been defined.</p>
+    async fn create_resource_definition_version(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> Result<
+        CreateResourceDefinitionVersionResponse,
+        RusotoError<CreateResourceDefinitionVersionError>,
+    > {
+        self.create_resource_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a software update for a core or group of cores (specified as an IoT thing group.) Use this to update the OTA Agent as well as the Greengrass core software. It makes use of the IoT Jobs feature which provides additional commands to manage a Greengrass core software update job.</p>
+    async fn create_software_update_job(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> Result<CreateSoftwareUpdateJobResponse, RusotoError<CreateSoftwareUpdateJobError>> {
+        self.create_software_update_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a subscription definition. You may provide the initial version of the subscription definition now or use &#39;&#39;CreateSubscriptionDefinitionVersion&#39;&#39; at a later time.</p>
+    async fn create_subscription_definition(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> Result<CreateSubscriptionDefinitionResponse, RusotoError<CreateSubscriptionDefinitionError>>
+    {
+        self.create_subscription_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of a subscription definition which has already been defined.</p>
+    async fn create_subscription_definition_version(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> Result<
+        CreateSubscriptionDefinitionVersionResponse,
+        RusotoError<CreateSubscriptionDefinitionVersionError>,
+    > {
+        self.create_subscription_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a connector definition.</p>
+    async fn delete_connector_definition(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> Result<DeleteConnectorDefinitionResponse, RusotoError<DeleteConnectorDefinitionError>>
+    {
+        self.delete_connector_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a core definition.</p>
+    async fn delete_core_definition(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> Result<DeleteCoreDefinitionResponse, RusotoError<DeleteCoreDefinitionError>> {
+        self.delete_core_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a device definition.</p>
+    async fn delete_device_definition(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> Result<DeleteDeviceDefinitionResponse, RusotoError<DeleteDeviceDefinitionError>> {
+        self.delete_device_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Lambda function definition.</p>
+    async fn delete_function_definition(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> Result<DeleteFunctionDefinitionResponse, RusotoError<DeleteFunctionDefinitionError>> {
+        self.delete_function_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a group.</p>
+    async fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+        self.delete_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a logger definition.</p>
+    async fn delete_logger_definition(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> Result<DeleteLoggerDefinitionResponse, RusotoError<DeleteLoggerDefinitionError>> {
+        self.delete_logger_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a resource definition.</p>
+    async fn delete_resource_definition(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> Result<DeleteResourceDefinitionResponse, RusotoError<DeleteResourceDefinitionError>> {
+        self.delete_resource_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a subscription definition.</p>
+    async fn delete_subscription_definition(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> Result<DeleteSubscriptionDefinitionResponse, RusotoError<DeleteSubscriptionDefinitionError>>
+    {
+        self.delete_subscription_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the role from a group.</p>
+    async fn disassociate_role_from_group(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> Result<DisassociateRoleFromGroupResponse, RusotoError<DisassociateRoleFromGroupError>>
+    {
+        self.disassociate_role_from_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the service role from your account. Without a service role, deployments will not work.</p>
+    async fn disassociate_service_role_from_account(
+        &self,
+    ) -> Result<
+        DisassociateServiceRoleFromAccountResponse,
+        RusotoError<DisassociateServiceRoleFromAccountError>,
+    > {
+        self.disassociate_service_role_from_account_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the role associated with a particular group.</p>
+    async fn get_associated_role(
+        &self,
+        input: GetAssociatedRoleRequest,
+    ) -> Result<GetAssociatedRoleResponse, RusotoError<GetAssociatedRoleError>> {
+        self.get_associated_role_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the status of a bulk deployment.</p>
+    async fn get_bulk_deployment_status(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> Result<GetBulkDeploymentStatusResponse, RusotoError<GetBulkDeploymentStatusError>> {
+        self.get_bulk_deployment_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the connectivity information for a core.</p>
+    async fn get_connectivity_info(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> Result<GetConnectivityInfoResponse, RusotoError<GetConnectivityInfoError>> {
+        self.get_connectivity_info_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a connector definition.</p>
+    async fn get_connector_definition(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> Result<GetConnectorDefinitionResponse, RusotoError<GetConnectorDefinitionError>> {
+        self.get_connector_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a connector definition version, including the connectors that the version contains. Connectors are prebuilt modules that interact with local infrastructure, device protocols, AWS, and other cloud services.</p>
+    async fn get_connector_definition_version(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> Result<
+        GetConnectorDefinitionVersionResponse,
+        RusotoError<GetConnectorDefinitionVersionError>,
+    > {
+        self.get_connector_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a core definition version.</p>
+    async fn get_core_definition(
+        &self,
+        input: GetCoreDefinitionRequest,
+    ) -> Result<GetCoreDefinitionResponse, RusotoError<GetCoreDefinitionError>> {
+        self.get_core_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a core definition version.</p>
+    async fn get_core_definition_version(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> Result<GetCoreDefinitionVersionResponse, RusotoError<GetCoreDefinitionVersionError>> {
+        self.get_core_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the status of a deployment.</p>
+    async fn get_deployment_status(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> Result<GetDeploymentStatusResponse, RusotoError<GetDeploymentStatusError>> {
+        self.get_deployment_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a device definition.</p>
+    async fn get_device_definition(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> Result<GetDeviceDefinitionResponse, RusotoError<GetDeviceDefinitionError>> {
+        self.get_device_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a device definition version.</p>
+    async fn get_device_definition_version(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> Result<GetDeviceDefinitionVersionResponse, RusotoError<GetDeviceDefinitionVersionError>>
+    {
+        self.get_device_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a Lambda function definition, including its creation time and latest version.</p>
+    async fn get_function_definition(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> Result<GetFunctionDefinitionResponse, RusotoError<GetFunctionDefinitionError>> {
+        self.get_function_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a Lambda function definition version, including which Lambda functions are included in the version and their configurations.</p>
+    async fn get_function_definition_version(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> Result<GetFunctionDefinitionVersionResponse, RusotoError<GetFunctionDefinitionVersionError>>
+    {
+        self.get_function_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a group.</p>
+    async fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+        self.get_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retreives the CA associated with a group. Returns the public key of the CA.</p>
+    async fn get_group_certificate_authority(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> Result<GetGroupCertificateAuthorityResponse, RusotoError<GetGroupCertificateAuthorityError>>
+    {
+        self.get_group_certificate_authority_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the current configuration for the CA used by the group.</p>
+    async fn get_group_certificate_configuration(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> Result<
+        GetGroupCertificateConfigurationResponse,
+        RusotoError<GetGroupCertificateConfigurationError>,
+    > {
+        self.get_group_certificate_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a group version.</p>
+    async fn get_group_version(
+        &self,
+        input: GetGroupVersionRequest,
+    ) -> Result<GetGroupVersionResponse, RusotoError<GetGroupVersionError>> {
+        self.get_group_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a logger definition.</p>
+    async fn get_logger_definition(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> Result<GetLoggerDefinitionResponse, RusotoError<GetLoggerDefinitionError>> {
+        self.get_logger_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a logger definition version.</p>
+    async fn get_logger_definition_version(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> Result<GetLoggerDefinitionVersionResponse, RusotoError<GetLoggerDefinitionVersionError>>
+    {
+        self.get_logger_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a resource definition, including its creation time and latest version.</p>
+    async fn get_resource_definition(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> Result<GetResourceDefinitionResponse, RusotoError<GetResourceDefinitionError>> {
+        self.get_resource_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a resource definition version, including which resources are included in the version.</p>
+    async fn get_resource_definition_version(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> Result<GetResourceDefinitionVersionResponse, RusotoError<GetResourceDefinitionVersionError>>
+    {
+        self.get_resource_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the service role that is attached to your account.</p>
+    async fn get_service_role_for_account(
+        &self,
+    ) -> Result<GetServiceRoleForAccountResponse, RusotoError<GetServiceRoleForAccountError>> {
+        self.get_service_role_for_account_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a subscription definition.</p>
+    async fn get_subscription_definition(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> Result<GetSubscriptionDefinitionResponse, RusotoError<GetSubscriptionDefinitionError>>
+    {
+        self.get_subscription_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a subscription definition version.</p>
+    async fn get_subscription_definition_version(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> Result<
+        GetSubscriptionDefinitionVersionResponse,
+        RusotoError<GetSubscriptionDefinitionVersionError>,
+    > {
+        self.get_subscription_definition_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a paginated list of the deployments that have been started in a bulk deployment operation, and their current deployment status.</p>
+    async fn list_bulk_deployment_detailed_reports(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> Result<
+        ListBulkDeploymentDetailedReportsResponse,
+        RusotoError<ListBulkDeploymentDetailedReportsError>,
+    > {
+        self.list_bulk_deployment_detailed_reports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of bulk deployments.</p>
+    async fn list_bulk_deployments(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> Result<ListBulkDeploymentsResponse, RusotoError<ListBulkDeploymentsError>> {
+        self.list_bulk_deployments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the versions of a connector definition, which are containers for connectors. Connectors run on the Greengrass core and contain built-in integration with local infrastructure, device protocols, AWS, and other cloud services.</p>
+    async fn list_connector_definition_versions(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> Result<
+        ListConnectorDefinitionVersionsResponse,
+        RusotoError<ListConnectorDefinitionVersionsError>,
+    > {
+        self.list_connector_definition_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of connector definitions.</p>
+    async fn list_connector_definitions(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> Result<ListConnectorDefinitionsResponse, RusotoError<ListConnectorDefinitionsError>> {
+        self.list_connector_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the versions of a core definition.</p>
+    async fn list_core_definition_versions(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> Result<ListCoreDefinitionVersionsResponse, RusotoError<ListCoreDefinitionVersionsError>>
+    {
+        self.list_core_definition_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of core definitions.</p>
+    async fn list_core_definitions(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> Result<ListCoreDefinitionsResponse, RusotoError<ListCoreDefinitionsError>> {
+        self.list_core_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a history of deployments for the group.</p>
+    async fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> Result<ListDeploymentsResponse, RusotoError<ListDeploymentsError>> {
+        self.list_deployments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the versions of a device definition.</p>
+    async fn list_device_definition_versions(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> Result<ListDeviceDefinitionVersionsResponse, RusotoError<ListDeviceDefinitionVersionsError>>
+    {
+        self.list_device_definition_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of device definitions.</p>
+    async fn list_device_definitions(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> Result<ListDeviceDefinitionsResponse, RusotoError<ListDeviceDefinitionsError>> {
+        self.list_device_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the versions of a Lambda function definition.</p>
+    async fn list_function_definition_versions(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> Result<
+        ListFunctionDefinitionVersionsResponse,
+        RusotoError<ListFunctionDefinitionVersionsError>,
+    > {
+        self.list_function_definition_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of Lambda function definitions.</p>
+    async fn list_function_definitions(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> Result<ListFunctionDefinitionsResponse, RusotoError<ListFunctionDefinitionsError>> {
+        self.list_function_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the current CAs for a group.</p>
+    async fn list_group_certificate_authorities(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> Result<
+        ListGroupCertificateAuthoritiesResponse,
+        RusotoError<ListGroupCertificateAuthoritiesError>,
+    > {
+        self.list_group_certificate_authorities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the versions of a group.</p>
+    async fn list_group_versions(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> Result<ListGroupVersionsResponse, RusotoError<ListGroupVersionsError>> {
+        self.list_group_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of groups.</p>
+    async fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.list_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the versions of a logger definition.</p>
+    async fn list_logger_definition_versions(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> Result<ListLoggerDefinitionVersionsResponse, RusotoError<ListLoggerDefinitionVersionsError>>
+    {
+        self.list_logger_definition_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of logger definitions.</p>
+    async fn list_logger_definitions(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> Result<ListLoggerDefinitionsResponse, RusotoError<ListLoggerDefinitionsError>> {
+        self.list_logger_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the versions of a resource definition.</p>
+    async fn list_resource_definition_versions(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> Result<
+        ListResourceDefinitionVersionsResponse,
+        RusotoError<ListResourceDefinitionVersionsError>,
+    > {
+        self.list_resource_definition_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of resource definitions.</p>
+    async fn list_resource_definitions(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> Result<ListResourceDefinitionsResponse, RusotoError<ListResourceDefinitionsError>> {
+        self.list_resource_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the versions of a subscription definition.</p>
+    async fn list_subscription_definition_versions(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> Result<
+        ListSubscriptionDefinitionVersionsResponse,
+        RusotoError<ListSubscriptionDefinitionVersionsError>,
+    > {
+        self.list_subscription_definition_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of subscription definitions.</p>
+    async fn list_subscription_definitions(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> Result<ListSubscriptionDefinitionsResponse, RusotoError<ListSubscriptionDefinitionsError>>
+    {
+        self.list_subscription_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of resource tags for a resource arn.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Resets a group&#39;s deployments.</p>
+    async fn reset_deployments(
+        &self,
+        input: ResetDeploymentsRequest,
+    ) -> Result<ResetDeploymentsResponse, RusotoError<ResetDeploymentsError>> {
+        self.reset_deployments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deploys multiple groups in one operation. This action starts the bulk deployment of a specified set of group versions. Each group version deployment will be triggered with an adaptive rate that has a fixed upper limit. We recommend that you include an &#39;&#39;X-Amzn-Client-Token&#39;&#39; token in every &#39;&#39;StartBulkDeployment&#39;&#39; request. These requests are idempotent with respect to the token and the request parameters.</p>
+    async fn start_bulk_deployment(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> Result<StartBulkDeploymentResponse, RusotoError<StartBulkDeploymentError>> {
+        self.start_bulk_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the execution of a bulk deployment. This action returns a status of &#39;&#39;Stopping&#39;&#39; until the deployment is stopped. You cannot start a new bulk deployment while a previous deployment is in the &#39;&#39;Stopping&#39;&#39; state. This action doesn&#39;t rollback completed deployments or cancel pending deployments.</p>
+    async fn stop_bulk_deployment(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> Result<StopBulkDeploymentResponse, RusotoError<StopBulkDeploymentError>> {
+        self.stop_bulk_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tags to a Greengrass resource. Valid resources are &#39;Group&#39;, &#39;ConnectorDefinition&#39;, &#39;CoreDefinition&#39;, &#39;DeviceDefinition&#39;, &#39;FunctionDefinition&#39;, &#39;LoggerDefinition&#39;, &#39;SubscriptionDefinition&#39;, &#39;ResourceDefinition&#39;, and &#39;BulkDeployment&#39;.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove resource tags from a Greengrass Resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the connectivity information for the core. Any devices that belong to the group which has this core will receive this information in order to find the location of the core and connect to it.</p>
+    async fn update_connectivity_info(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> Result<UpdateConnectivityInfoResponse, RusotoError<UpdateConnectivityInfoError>> {
+        self.update_connectivity_info_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a connector definition.</p>
+    async fn update_connector_definition(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> Result<UpdateConnectorDefinitionResponse, RusotoError<UpdateConnectorDefinitionError>>
+    {
+        self.update_connector_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a core definition.</p>
+    async fn update_core_definition(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> Result<UpdateCoreDefinitionResponse, RusotoError<UpdateCoreDefinitionError>> {
+        self.update_core_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a device definition.</p>
+    async fn update_device_definition(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> Result<UpdateDeviceDefinitionResponse, RusotoError<UpdateDeviceDefinitionError>> {
+        self.update_device_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a Lambda function definition.</p>
+    async fn update_function_definition(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> Result<UpdateFunctionDefinitionResponse, RusotoError<UpdateFunctionDefinitionError>> {
+        self.update_function_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a group.</p>
+    async fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+        self.update_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the Certificate expiry time for a group.</p>
+    async fn update_group_certificate_configuration(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> Result<
+        UpdateGroupCertificateConfigurationResponse,
+        RusotoError<UpdateGroupCertificateConfigurationError>,
+    > {
+        self.update_group_certificate_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a logger definition.</p>
+    async fn update_logger_definition(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> Result<UpdateLoggerDefinitionResponse, RusotoError<UpdateLoggerDefinitionError>> {
+        self.update_logger_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a resource definition.</p>
+    async fn update_resource_definition(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> Result<UpdateResourceDefinitionResponse, RusotoError<UpdateResourceDefinitionError>> {
+        self.update_resource_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a subscription definition.</p>
+    async fn update_subscription_definition(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> Result<UpdateSubscriptionDefinitionResponse, RusotoError<UpdateSubscriptionDefinitionError>>
+    {
+        self.update_subscription_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
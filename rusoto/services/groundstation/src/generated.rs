@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1153,6 +1153,7 @@ pub struct UplinkSpectrumConfig {
 
 /// Errors returned by CancelContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelContactError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1160,6 +1161,8 @@ pub enum CancelContactError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelContactError {
@@ -1167,16 +1170,22 @@ impl CancelContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CancelContactError::Dependency(err.msg))
+                    return RusotoError::service(CancelContactError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CancelContactError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CancelContactError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1189,12 +1198,14 @@ impl fmt::Display for CancelContactError {
             CancelContactError::Dependency(ref cause) => write!(f, "{}", cause),
             CancelContactError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CancelContactError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CancelContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelContactError {}
 /// Errors returned by CreateConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1202,6 +1213,8 @@ pub enum CreateConfigError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigError {
@@ -1209,16 +1222,16 @@ impl CreateConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CreateConfigError::Dependency(err.msg))
+                    return RusotoError::service(CreateConfigError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateConfigError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateConfigError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1231,12 +1244,14 @@ impl fmt::Display for CreateConfigError {
             CreateConfigError::Dependency(ref cause) => write!(f, "{}", cause),
             CreateConfigError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConfigError {}
 /// Errors returned by CreateDataflowEndpointGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataflowEndpointGroupError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1244,6 +1259,8 @@ pub enum CreateDataflowEndpointGroupError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataflowEndpointGroupError {
@@ -1253,22 +1270,30 @@ impl CreateDataflowEndpointGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CreateDataflowEndpointGroupError::Dependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataflowEndpointGroupError::Dependency(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataflowEndpointGroupError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataflowEndpointGroupError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateDataflowEndpointGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1281,12 +1306,14 @@ impl fmt::Display for CreateDataflowEndpointGroupError {
             CreateDataflowEndpointGroupError::Dependency(ref cause) => write!(f, "{}", cause),
             CreateDataflowEndpointGroupError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateDataflowEndpointGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateDataflowEndpointGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataflowEndpointGroupError {}
 /// Errors returned by CreateMissionProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMissionProfileError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1294,6 +1321,8 @@ pub enum CreateMissionProfileError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMissionProfileError {
@@ -1301,20 +1330,25 @@ impl CreateMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CreateMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service(
+                        CreateMissionProfileError::Dependency(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateMissionProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMissionProfileError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateMissionProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMissionProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMissionProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1327,12 +1361,14 @@ impl fmt::Display for CreateMissionProfileError {
             CreateMissionProfileError::Dependency(ref cause) => write!(f, "{}", cause),
             CreateMissionProfileError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateMissionProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateMissionProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMissionProfileError {}
 /// Errors returned by DeleteConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1340,6 +1376,8 @@ pub enum DeleteConfigError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigError {
@@ -1347,16 +1385,16 @@ impl DeleteConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DeleteConfigError::Dependency(err.msg))
+                    return RusotoError::service(DeleteConfigError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteConfigError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteConfigError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1369,12 +1407,14 @@ impl fmt::Display for DeleteConfigError {
             DeleteConfigError::Dependency(ref cause) => write!(f, "{}", cause),
             DeleteConfigError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConfigError {}
 /// Errors returned by DeleteDataflowEndpointGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDataflowEndpointGroupError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1382,6 +1422,8 @@ pub enum DeleteDataflowEndpointGroupError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDataflowEndpointGroupError {
@@ -1391,22 +1433,30 @@ impl DeleteDataflowEndpointGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DeleteDataflowEndpointGroupError::Dependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDataflowEndpointGroupError::Dependency(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDataflowEndpointGroupError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDataflowEndpointGroupError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteDataflowEndpointGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1419,12 +1469,14 @@ impl fmt::Display for DeleteDataflowEndpointGroupError {
             DeleteDataflowEndpointGroupError::Dependency(ref cause) => write!(f, "{}", cause),
             DeleteDataflowEndpointGroupError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteDataflowEndpointGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDataflowEndpointGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDataflowEndpointGroupError {}
 /// Errors returned by DeleteMissionProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMissionProfileError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1432,6 +1484,8 @@ pub enum DeleteMissionProfileError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMissionProfileError {
@@ -1439,20 +1493,25 @@ impl DeleteMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DeleteMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service(
+                        DeleteMissionProfileError::Dependency(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteMissionProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMissionProfileError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMissionProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMissionProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMissionProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1465,12 +1524,14 @@ impl fmt::Display for DeleteMissionProfileError {
             DeleteMissionProfileError::Dependency(ref cause) => write!(f, "{}", cause),
             DeleteMissionProfileError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteMissionProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteMissionProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMissionProfileError {}
 /// Errors returned by DescribeContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeContactError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1478,6 +1539,8 @@ pub enum DescribeContactError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeContactError {
@@ -1485,16 +1548,22 @@ impl DescribeContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DescribeContactError::Dependency(err.msg))
+                    return RusotoError::service(DescribeContactError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeContactError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeContactError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1507,12 +1576,14 @@ impl fmt::Display for DescribeContactError {
             DescribeContactError::Dependency(ref cause) => write!(f, "{}", cause),
             DescribeContactError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeContactError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeContactError {}
 /// Errors returned by GetConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConfigError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1520,6 +1591,8 @@ pub enum GetConfigError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConfigError {
@@ -1527,16 +1600,16 @@ impl GetConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetConfigError::Dependency(err.msg))
+                    return RusotoError::service(GetConfigError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetConfigError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetConfigError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1549,12 +1622,14 @@ impl fmt::Display for GetConfigError {
             GetConfigError::Dependency(ref cause) => write!(f, "{}", cause),
             GetConfigError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConfigError {}
 /// Errors returned by GetDataflowEndpointGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDataflowEndpointGroupError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1562,6 +1637,8 @@ pub enum GetDataflowEndpointGroupError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDataflowEndpointGroupError {
@@ -1569,20 +1646,25 @@ impl GetDataflowEndpointGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetDataflowEndpointGroupError::Dependency(err.msg))
+                    return RusotoError::service(
+                        GetDataflowEndpointGroupError::Dependency(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDataflowEndpointGroupError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDataflowEndpointGroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataflowEndpointGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDataflowEndpointGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDataflowEndpointGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1595,12 +1677,14 @@ impl fmt::Display for GetDataflowEndpointGroupError {
             GetDataflowEndpointGroupError::Dependency(ref cause) => write!(f, "{}", cause),
             GetDataflowEndpointGroupError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetDataflowEndpointGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetDataflowEndpointGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDataflowEndpointGroupError {}
 /// Errors returned by GetMinuteUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMinuteUsageError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1608,6 +1692,8 @@ pub enum GetMinuteUsageError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMinuteUsageError {
@@ -1615,16 +1701,22 @@ impl GetMinuteUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetMinuteUsageError::Dependency(err.msg))
+                    return RusotoError::service(GetMinuteUsageError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetMinuteUsageError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetMinuteUsageError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMinuteUsageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetMinuteUsageError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMinuteUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1637,12 +1729,14 @@ impl fmt::Display for GetMinuteUsageError {
             GetMinuteUsageError::Dependency(ref cause) => write!(f, "{}", cause),
             GetMinuteUsageError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetMinuteUsageError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetMinuteUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMinuteUsageError {}
 /// Errors returned by GetMissionProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMissionProfileError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1650,6 +1744,8 @@ pub enum GetMissionProfileError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMissionProfileError {
@@ -1657,16 +1753,22 @@ impl GetMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service(GetMissionProfileError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetMissionProfileError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetMissionProfileError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMissionProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetMissionProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMissionProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1679,12 +1781,14 @@ impl fmt::Display for GetMissionProfileError {
             GetMissionProfileError::Dependency(ref cause) => write!(f, "{}", cause),
             GetMissionProfileError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetMissionProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetMissionProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMissionProfileError {}
 /// Errors returned by GetSatellite
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSatelliteError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1692,6 +1796,8 @@ pub enum GetSatelliteError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSatelliteError {
@@ -1699,16 +1805,16 @@ impl GetSatelliteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetSatelliteError::Dependency(err.msg))
+                    return RusotoError::service(GetSatelliteError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSatelliteError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetSatelliteError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSatelliteError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetSatelliteError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSatelliteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1721,12 +1827,14 @@ impl fmt::Display for GetSatelliteError {
             GetSatelliteError::Dependency(ref cause) => write!(f, "{}", cause),
             GetSatelliteError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetSatelliteError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetSatelliteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSatelliteError {}
 /// Errors returned by ListConfigs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigsError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1734,6 +1842,8 @@ pub enum ListConfigsError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigsError {
@@ -1741,16 +1851,16 @@ impl ListConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListConfigsError::Dependency(err.msg))
+                    return RusotoError::service(ListConfigsError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListConfigsError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListConfigsError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListConfigsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConfigsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1763,12 +1873,14 @@ impl fmt::Display for ListConfigsError {
             ListConfigsError::Dependency(ref cause) => write!(f, "{}", cause),
             ListConfigsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListConfigsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListConfigsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigsError {}
 /// Errors returned by ListContacts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListContactsError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1776,6 +1888,8 @@ pub enum ListContactsError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListContactsError {
@@ -1783,16 +1897,16 @@ impl ListContactsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListContactsError::Dependency(err.msg))
+                    return RusotoError::service(ListContactsError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListContactsError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListContactsError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListContactsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListContactsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListContactsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1805,12 +1919,14 @@ impl fmt::Display for ListContactsError {
             ListContactsError::Dependency(ref cause) => write!(f, "{}", cause),
             ListContactsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListContactsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListContactsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListContactsError {}
 /// Errors returned by ListDataflowEndpointGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDataflowEndpointGroupsError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1818,6 +1934,8 @@ pub enum ListDataflowEndpointGroupsError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDataflowEndpointGroupsError {
@@ -1827,22 +1945,27 @@ impl ListDataflowEndpointGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListDataflowEndpointGroupsError::Dependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDataflowEndpointGroupsError::Dependency(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDataflowEndpointGroupsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDataflowEndpointGroupsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataflowEndpointGroupsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDataflowEndpointGroupsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListDataflowEndpointGroupsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1855,12 +1978,14 @@ impl fmt::Display for ListDataflowEndpointGroupsError {
             ListDataflowEndpointGroupsError::Dependency(ref cause) => write!(f, "{}", cause),
             ListDataflowEndpointGroupsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListDataflowEndpointGroupsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListDataflowEndpointGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDataflowEndpointGroupsError {}
 /// Errors returned by ListGroundStations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGroundStationsError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1868,6 +1993,8 @@ pub enum ListGroundStationsError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGroundStationsError {
@@ -1875,16 +2002,22 @@ impl ListGroundStationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListGroundStationsError::Dependency(err.msg))
+                    return RusotoError::service(ListGroundStationsError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroundStationsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListGroundStationsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroundStationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListGroundStationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGroundStationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1897,12 +2030,14 @@ impl fmt::Display for ListGroundStationsError {
             ListGroundStationsError::Dependency(ref cause) => write!(f, "{}", cause),
             ListGroundStationsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListGroundStationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListGroundStationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGroundStationsError {}
 /// Errors returned by ListMissionProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMissionProfilesError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1910,6 +2045,8 @@ pub enum ListMissionProfilesError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMissionProfilesError {
@@ -1917,20 +2054,25 @@ impl ListMissionProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListMissionProfilesError::Dependency(err.msg))
+                    return RusotoError::service(
+                        ListMissionProfilesError::Dependency(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListMissionProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMissionProfilesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMissionProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMissionProfilesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMissionProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1943,12 +2085,14 @@ impl fmt::Display for ListMissionProfilesError {
             ListMissionProfilesError::Dependency(ref cause) => write!(f, "{}", cause),
             ListMissionProfilesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListMissionProfilesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListMissionProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMissionProfilesError {}
 /// Errors returned by ListSatellites
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSatellitesError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1956,6 +2100,8 @@ pub enum ListSatellitesError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSatellitesError {
@@ -1963,16 +2109,22 @@ impl ListSatellitesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListSatellitesError::Dependency(err.msg))
+                    return RusotoError::service(ListSatellitesError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSatellitesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListSatellitesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSatellitesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListSatellitesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSatellitesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1985,12 +2137,14 @@ impl fmt::Display for ListSatellitesError {
             ListSatellitesError::Dependency(ref cause) => write!(f, "{}", cause),
             ListSatellitesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListSatellitesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListSatellitesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSatellitesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -1998,6 +2152,8 @@ pub enum ListTagsForResourceError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2005,20 +2161,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Dependency(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Dependency(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2031,12 +2192,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::Dependency(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ReserveContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReserveContactError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -2044,6 +2207,8 @@ pub enum ReserveContactError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReserveContactError {
@@ -2051,16 +2216,22 @@ impl ReserveContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ReserveContactError::Dependency(err.msg))
+                    return RusotoError::service(ReserveContactError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ReserveContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ReserveContactError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReserveContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ReserveContactError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ReserveContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2073,12 +2244,14 @@ impl fmt::Display for ReserveContactError {
             ReserveContactError::Dependency(ref cause) => write!(f, "{}", cause),
             ReserveContactError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ReserveContactError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ReserveContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReserveContactError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -2086,6 +2259,8 @@ pub enum TagResourceError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2093,16 +2268,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(TagResourceError::Dependency(err.msg))
+                    return RusotoError::service(TagResourceError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2115,12 +2290,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::Dependency(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -2128,6 +2305,8 @@ pub enum UntagResourceError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2135,16 +2314,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(UntagResourceError::Dependency(err.msg))
+                    return RusotoError::service(UntagResourceError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2157,12 +2342,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::Dependency(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -2170,6 +2357,8 @@ pub enum UpdateConfigError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigError {
@@ -2177,16 +2366,16 @@ impl UpdateConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(UpdateConfigError::Dependency(err.msg))
+                    return RusotoError::service(UpdateConfigError::Dependency(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service(UpdateConfigError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateConfigError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2199,12 +2388,14 @@ impl fmt::Display for UpdateConfigError {
             UpdateConfigError::Dependency(ref cause) => write!(f, "{}", cause),
             UpdateConfigError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConfigError {}
 /// Errors returned by UpdateMissionProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMissionProfileError {
     /// <p>Dependency encountered an error.</p>
     Dependency(String),
@@ -2212,6 +2403,8 @@ pub enum UpdateMissionProfileError {
     InvalidParameter(String),
     /// <p>Resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMissionProfileError {
@@ -2219,20 +2412,25 @@ impl UpdateMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(UpdateMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service(
+                        UpdateMissionProfileError::Dependency(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateMissionProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMissionProfileError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMissionProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMissionProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMissionProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2245,6 +2443,7 @@ impl fmt::Display for UpdateMissionProfileError {
             UpdateMissionProfileError::Dependency(ref cause) => write!(f, "{}", cause),
             UpdateMissionProfileError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateMissionProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateMissionProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2443,6 +2642,7 @@ impl GroundStationClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> GroundStationClient {
         GroundStationClient {
             client: Client::shared(),
@@ -2468,147 +2668,289 @@ impl GroundStationClient {
     pub fn new_with_client(client: Client, region: region::Region) -> GroundStationClient {
         GroundStationClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<GroundStationClient> {
+        rusoto_core::ClientBuilder::new(GroundStationClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> GroundStationClient {
+        GroundStationClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl GroundStation for GroundStationClient {
-    /// <p>Cancels a contact with a specified contact ID.</p>
-    async fn cancel_contact(
+impl GroundStationClient {
+    /// Calls `cancel_contact`, also returning the metadata of the response.
+    pub async fn cancel_contact_with_metadata(
         &self,
         input: CancelContactRequest,
-    ) -> Result<ContactIdResponse, RusotoError<CancelContactError>> {
+    ) -> Result<(ContactIdResponse, ResponseMetadata), RusotoError<CancelContactError>> {
+        let request = self.build_cancel_contact_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ContactIdResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CancelContactError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_contact(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelContactRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_contact_request(&self, input: CancelContactRequest) -> SignedRequest {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("CancelContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `create_config`, also returning the metadata of the response.
+    pub async fn create_config_with_metadata(
+        &self,
+        input: CreateConfigRequest,
+    ) -> Result<(ConfigIdResponse, ResponseMetadata), RusotoError<CreateConfigError>> {
+        let request = self.build_create_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ContactIdResponse, _>()?;
+                .deserialize::<ConfigIdResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelContactError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateConfigError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>Config</code> with the specified <code>configData</code> parameters.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;Only one type of &lt;code&gt;configData&lt;/code&gt; can be specified.&lt;/p&gt;
-    /// </code></pre>
-    async fn create_config(
+    /// Presigns the request `create_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConfigRequest,
-    ) -> Result<ConfigIdResponse, RusotoError<CreateConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_config_request(&self, input: CreateConfigRequest) -> SignedRequest {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_dataflow_endpoint_group`, also returning the metadata of the response.
+    pub async fn create_dataflow_endpoint_group_with_metadata(
+        &self,
+        input: CreateDataflowEndpointGroupRequest,
+    ) -> Result<
+        (DataflowEndpointGroupIdResponse, ResponseMetadata),
+        RusotoError<CreateDataflowEndpointGroupError>,
+    > {
+        let request = self.build_create_dataflow_endpoint_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfigIdResponse, _>()?;
+                .deserialize::<DataflowEndpointGroupIdResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConfigError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDataflowEndpointGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>DataflowEndpoint</code> group containing the specified list of <code>DataflowEndpoint</code> objects.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;The &lt;code&gt;name&lt;/code&gt; field in each endpoint is used in your mission profile &lt;code&gt;DataflowEndpointConfig&lt;/code&gt;
-    /// to specify which endpoints to use during a contact.&lt;/p&gt;
-    /// &lt;p&gt;When a contact uses multiple &lt;code&gt;DataflowEndpointConfig&lt;/code&gt; objects, each &lt;code&gt;Config&lt;/code&gt;
-    /// must match a &lt;code&gt;DataflowEndpoint&lt;/code&gt; in the same group.&lt;/p&gt;
-    /// </code></pre>
-    async fn create_dataflow_endpoint_group(
+    /// Presigns the request `create_dataflow_endpoint_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dataflow_endpoint_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataflowEndpointGroupRequest,
-    ) -> Result<DataflowEndpointGroupIdResponse, RusotoError<CreateDataflowEndpointGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dataflow_endpoint_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dataflow_endpoint_group_request(
+        &self,
+        input: CreateDataflowEndpointGroupRequest,
+    ) -> SignedRequest {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_mission_profile`, also returning the metadata of the response.
+    pub async fn create_mission_profile_with_metadata(
+        &self,
+        input: CreateMissionProfileRequest,
+    ) -> Result<(MissionProfileIdResponse, ResponseMetadata), RusotoError<CreateMissionProfileError>>
+    {
+        let request = self.build_create_mission_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DataflowEndpointGroupIdResponse, _>()?;
+                .deserialize::<MissionProfileIdResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataflowEndpointGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateMissionProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates a mission profile.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;
-    /// &lt;code&gt;dataflowEdges&lt;/code&gt; is a list of lists of strings. Each lower level list of strings
-    /// has two elements: a &lt;i&gt;from ARN&lt;/i&gt; and a &lt;i&gt;to ARN&lt;/i&gt;.&lt;/p&gt;
-    /// </code></pre>
-    async fn create_mission_profile(
+    /// Presigns the request `create_mission_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_mission_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMissionProfileRequest,
-    ) -> Result<MissionProfileIdResponse, RusotoError<CreateMissionProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_mission_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_mission_profile_request(
+        &self,
+        input: CreateMissionProfileRequest,
+    ) -> SignedRequest {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_config`, also returning the metadata of the response.
+    pub async fn delete_config_with_metadata(
+        &self,
+        input: DeleteConfigRequest,
+    ) -> Result<(ConfigIdResponse, ResponseMetadata), RusotoError<DeleteConfigError>> {
+        let request = self.build_delete_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<MissionProfileIdResponse, _>()?;
+                .deserialize::<ConfigIdResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMissionProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteConfigError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <code>Config</code>.</p>
-    async fn delete_config(
+    /// Presigns the request `delete_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConfigRequest,
-    ) -> Result<ConfigIdResponse, RusotoError<DeleteConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_config_request(&self, input: DeleteConfigRequest) -> SignedRequest {
         let request_uri = format!(
             "/config/{config_type}/{config_id}",
             config_id = input.config_id,
@@ -2616,121 +2958,224 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_dataflow_endpoint_group`, also returning the metadata of the response.
+    pub async fn delete_dataflow_endpoint_group_with_metadata(
+        &self,
+        input: DeleteDataflowEndpointGroupRequest,
+    ) -> Result<
+        (DataflowEndpointGroupIdResponse, ResponseMetadata),
+        RusotoError<DeleteDataflowEndpointGroupError>,
+    > {
+        let request = self.build_delete_dataflow_endpoint_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfigIdResponse, _>()?;
+                .deserialize::<DataflowEndpointGroupIdResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConfigError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDataflowEndpointGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a dataflow endpoint group.</p>
-    async fn delete_dataflow_endpoint_group(
+    /// Presigns the request `delete_dataflow_endpoint_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dataflow_endpoint_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDataflowEndpointGroupRequest,
-    ) -> Result<DataflowEndpointGroupIdResponse, RusotoError<DeleteDataflowEndpointGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dataflow_endpoint_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dataflow_endpoint_group_request(
+        &self,
+        input: DeleteDataflowEndpointGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/dataflowEndpointGroup/{dataflow_endpoint_group_id}",
             dataflow_endpoint_group_id = input.dataflow_endpoint_group_id
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_mission_profile`, also returning the metadata of the response.
+    pub async fn delete_mission_profile_with_metadata(
+        &self,
+        input: DeleteMissionProfileRequest,
+    ) -> Result<(MissionProfileIdResponse, ResponseMetadata), RusotoError<DeleteMissionProfileError>>
+    {
+        let request = self.build_delete_mission_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DataflowEndpointGroupIdResponse, _>()?;
+                .deserialize::<MissionProfileIdResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDataflowEndpointGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMissionProfileError::from_response(response))
         }
     }
 
-    /// <p>Deletes a mission profile.</p>
-    async fn delete_mission_profile(
+    /// Presigns the request `delete_mission_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_mission_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMissionProfileRequest,
-    ) -> Result<MissionProfileIdResponse, RusotoError<DeleteMissionProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_mission_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_mission_profile_request(
+        &self,
+        input: DeleteMissionProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/missionprofile/{mission_profile_id}",
             mission_profile_id = input.mission_profile_id
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_contact`, also returning the metadata of the response.
+    pub async fn describe_contact_with_metadata(
+        &self,
+        input: DescribeContactRequest,
+    ) -> Result<(DescribeContactResponse, ResponseMetadata), RusotoError<DescribeContactError>>
+    {
+        let request = self.build_describe_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<MissionProfileIdResponse, _>()?;
+                .deserialize::<DescribeContactResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMissionProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeContactError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing contact.</p>
-    async fn describe_contact(
+    /// Presigns the request `describe_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeContactRequest,
-    ) -> Result<DescribeContactResponse, RusotoError<DescribeContactError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_contact_request(&self, input: DescribeContactRequest) -> SignedRequest {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("DescribeContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_config`, also returning the metadata of the response.
+    pub async fn get_config_with_metadata(
+        &self,
+        input: GetConfigRequest,
+    ) -> Result<(GetConfigResponse, ResponseMetadata), RusotoError<GetConfigError>> {
+        let request = self.build_get_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeContactResponse, _>()?;
+                .deserialize::<GetConfigResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeContactError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetConfigError::from_response(response))
         }
     }
 
-    /// <p>Returns <code>Config</code> information.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;Only one &lt;code&gt;Config&lt;/code&gt; response can be returned.&lt;/p&gt;
-    /// </code></pre>
-    async fn get_config(
+    /// Presigns the request `get_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConfigRequest,
-    ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_config_request(&self, input: GetConfigRequest) -> SignedRequest {
         let request_uri = format!(
             "/config/{config_type}/{config_id}",
             config_id = input.config_id,
@@ -2738,153 +3183,281 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_dataflow_endpoint_group`, also returning the metadata of the response.
+    pub async fn get_dataflow_endpoint_group_with_metadata(
+        &self,
+        input: GetDataflowEndpointGroupRequest,
+    ) -> Result<
+        (GetDataflowEndpointGroupResponse, ResponseMetadata),
+        RusotoError<GetDataflowEndpointGroupError>,
+    > {
+        let request = self.build_get_dataflow_endpoint_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConfigResponse, _>()?;
+                .deserialize::<GetDataflowEndpointGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConfigError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDataflowEndpointGroupError::from_response(response))
         }
     }
 
-    /// <p>Returns the dataflow endpoint group.</p>
-    async fn get_dataflow_endpoint_group(
+    /// Presigns the request `get_dataflow_endpoint_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dataflow_endpoint_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDataflowEndpointGroupRequest,
-    ) -> Result<GetDataflowEndpointGroupResponse, RusotoError<GetDataflowEndpointGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dataflow_endpoint_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dataflow_endpoint_group_request(
+        &self,
+        input: GetDataflowEndpointGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/dataflowEndpointGroup/{dataflow_endpoint_group_id}",
             dataflow_endpoint_group_id = input.dataflow_endpoint_group_id
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_minute_usage`, also returning the metadata of the response.
+    pub async fn get_minute_usage_with_metadata(
+        &self,
+        input: GetMinuteUsageRequest,
+    ) -> Result<(GetMinuteUsageResponse, ResponseMetadata), RusotoError<GetMinuteUsageError>> {
+        let request = self.build_get_minute_usage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDataflowEndpointGroupResponse, _>()?;
+                .deserialize::<GetMinuteUsageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDataflowEndpointGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMinuteUsageError::from_response(response))
         }
     }
 
-    /// <p>Returns the number of minutes used by account.</p>
-    async fn get_minute_usage(
+    /// Presigns the request `get_minute_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_minute_usage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMinuteUsageRequest,
-    ) -> Result<GetMinuteUsageResponse, RusotoError<GetMinuteUsageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_minute_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_minute_usage_request(&self, input: GetMinuteUsageRequest) -> SignedRequest {
         let request_uri = "/minute-usage";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetMinuteUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_mission_profile`, also returning the metadata of the response.
+    pub async fn get_mission_profile_with_metadata(
+        &self,
+        input: GetMissionProfileRequest,
+    ) -> Result<(GetMissionProfileResponse, ResponseMetadata), RusotoError<GetMissionProfileError>>
+    {
+        let request = self.build_get_mission_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMinuteUsageResponse, _>()?;
+                .deserialize::<GetMissionProfileResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMinuteUsageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMissionProfileError::from_response(response))
         }
     }
 
-    /// <p>Returns a mission profile.</p>
-    async fn get_mission_profile(
+    /// Presigns the request `get_mission_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_mission_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMissionProfileRequest,
-    ) -> Result<GetMissionProfileResponse, RusotoError<GetMissionProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_mission_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_mission_profile_request(&self, input: GetMissionProfileRequest) -> SignedRequest {
         let request_uri = format!(
             "/missionprofile/{mission_profile_id}",
             mission_profile_id = input.mission_profile_id
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_satellite`, also returning the metadata of the response.
+    pub async fn get_satellite_with_metadata(
+        &self,
+        input: GetSatelliteRequest,
+    ) -> Result<(GetSatelliteResponse, ResponseMetadata), RusotoError<GetSatelliteError>> {
+        let request = self.build_get_satellite_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMissionProfileResponse, _>()?;
+                .deserialize::<GetSatelliteResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMissionProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSatelliteError::from_response(response))
         }
     }
 
-    /// <p>Returns a satellite.</p>
-    async fn get_satellite(
+    /// Presigns the request `get_satellite` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_satellite(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSatelliteRequest,
-    ) -> Result<GetSatelliteResponse, RusotoError<GetSatelliteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_satellite_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_satellite_request(&self, input: GetSatelliteRequest) -> SignedRequest {
         let request_uri = format!(
             "/satellite/{satellite_id}",
             satellite_id = input.satellite_id
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetSatellite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_configs`, also returning the metadata of the response.
+    pub async fn list_configs_with_metadata(
+        &self,
+        input: ListConfigsRequest,
+    ) -> Result<(ListConfigsResponse, ResponseMetadata), RusotoError<ListConfigsError>> {
+        let request = self.build_list_configs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSatelliteResponse, _>()?;
+                .deserialize::<ListConfigsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSatelliteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConfigsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of <code>Config</code> objects.</p>
-    async fn list_configs(
+    /// Presigns the request `list_configs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigsRequest,
-    ) -> Result<ListConfigsResponse, RusotoError<ListConfigsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configs_request(&self, input: ListConfigsRequest) -> SignedRequest {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2896,67 +3469,115 @@ impl GroundStation for GroundStationClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_contacts`, also returning the metadata of the response.
+    pub async fn list_contacts_with_metadata(
+        &self,
+        input: ListContactsRequest,
+    ) -> Result<(ListContactsResponse, ResponseMetadata), RusotoError<ListContactsError>> {
+        let request = self.build_list_contacts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConfigsResponse, _>()?;
+                .deserialize::<ListContactsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListContactsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of contacts.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;If &lt;code&gt;statusList&lt;/code&gt; contains AVAILABLE, the request must include
-    /// &lt;code&gt;groundstation&lt;/code&gt;, &lt;code&gt;missionprofileArn&lt;/code&gt;, and &lt;code&gt;satelliteArn&lt;/code&gt;.
-    /// &lt;/p&gt;
-    /// </code></pre>
-    async fn list_contacts(
+    /// Presigns the request `list_contacts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_contacts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListContactsRequest,
-    ) -> Result<ListContactsResponse, RusotoError<ListContactsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_contacts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_contacts_request(&self, input: ListContactsRequest) -> SignedRequest {
         let request_uri = "/contacts";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListContacts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_dataflow_endpoint_groups`, also returning the metadata of the response.
+    pub async fn list_dataflow_endpoint_groups_with_metadata(
+        &self,
+        input: ListDataflowEndpointGroupsRequest,
+    ) -> Result<
+        (ListDataflowEndpointGroupsResponse, ResponseMetadata),
+        RusotoError<ListDataflowEndpointGroupsError>,
+    > {
+        let request = self.build_list_dataflow_endpoint_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListContactsResponse, _>()?;
+                .deserialize::<ListDataflowEndpointGroupsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListContactsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDataflowEndpointGroupsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of <code>DataflowEndpoint</code> groups.</p>
-    async fn list_dataflow_endpoint_groups(
+    /// Presigns the request `list_dataflow_endpoint_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dataflow_endpoint_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDataflowEndpointGroupsRequest,
-    ) -> Result<ListDataflowEndpointGroupsResponse, RusotoError<ListDataflowEndpointGroupsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dataflow_endpoint_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dataflow_endpoint_groups_request(
+        &self,
+        input: ListDataflowEndpointGroupsRequest,
+    ) -> SignedRequest {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListDataflowEndpointGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2968,31 +3589,59 @@ impl GroundStation for GroundStationClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_ground_stations`, also returning the metadata of the response.
+    pub async fn list_ground_stations_with_metadata(
+        &self,
+        input: ListGroundStationsRequest,
+    ) -> Result<(ListGroundStationsResponse, ResponseMetadata), RusotoError<ListGroundStationsError>>
+    {
+        let request = self.build_list_ground_stations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDataflowEndpointGroupsResponse, _>()?;
+                .deserialize::<ListGroundStationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDataflowEndpointGroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListGroundStationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of ground stations. </p>
-    async fn list_ground_stations(
+    /// Presigns the request `list_ground_stations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_ground_stations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGroundStationsRequest,
-    ) -> Result<ListGroundStationsResponse, RusotoError<ListGroundStationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_ground_stations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_ground_stations_request(
+        &self,
+        input: ListGroundStationsRequest,
+    ) -> SignedRequest {
         let request_uri = "/groundstation";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListGroundStations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3004,31 +3653,61 @@ impl GroundStation for GroundStationClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_mission_profiles`, also returning the metadata of the response.
+    pub async fn list_mission_profiles_with_metadata(
+        &self,
+        input: ListMissionProfilesRequest,
+    ) -> Result<
+        (ListMissionProfilesResponse, ResponseMetadata),
+        RusotoError<ListMissionProfilesError>,
+    > {
+        let request = self.build_list_mission_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGroundStationsResponse, _>()?;
+                .deserialize::<ListMissionProfilesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGroundStationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListMissionProfilesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of mission profiles.</p>
-    async fn list_mission_profiles(
+    /// Presigns the request `list_mission_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_mission_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMissionProfilesRequest,
-    ) -> Result<ListMissionProfilesResponse, RusotoError<ListMissionProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_mission_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_mission_profiles_request(
+        &self,
+        input: ListMissionProfilesRequest,
+    ) -> SignedRequest {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListMissionProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3040,31 +3719,55 @@ impl GroundStation for GroundStationClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_satellites`, also returning the metadata of the response.
+    pub async fn list_satellites_with_metadata(
+        &self,
+        input: ListSatellitesRequest,
+    ) -> Result<(ListSatellitesResponse, ResponseMetadata), RusotoError<ListSatellitesError>> {
+        let request = self.build_list_satellites_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMissionProfilesResponse, _>()?;
+                .deserialize::<ListSatellitesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMissionProfilesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSatellitesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of satellites.</p>
-    async fn list_satellites(
+    /// Presigns the request `list_satellites` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_satellites(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSatellitesRequest,
-    ) -> Result<ListSatellitesResponse, RusotoError<ListSatellitesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_satellites_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_satellites_request(&self, input: ListSatellitesRequest) -> SignedRequest {
         let request_uri = "/satellite";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListSatellites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3076,118 +3779,220 @@ impl GroundStation for GroundStationClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSatellitesResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSatellitesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of tags or a specified resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `reserve_contact`, also returning the metadata of the response.
+    pub async fn reserve_contact_with_metadata(
+        &self,
+        input: ReserveContactRequest,
+    ) -> Result<(ContactIdResponse, ResponseMetadata), RusotoError<ReserveContactError>> {
+        let request = self.build_reserve_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<ContactIdResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ReserveContactError::from_response(response))
         }
     }
 
-    /// <p>Reserves a contact using specified parameters.</p>
-    async fn reserve_contact(
+    /// Presigns the request `reserve_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reserve_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReserveContactRequest,
-    ) -> Result<ContactIdResponse, RusotoError<ReserveContactError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reserve_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reserve_contact_request(&self, input: ReserveContactRequest) -> SignedRequest {
         let request_uri = "/contact";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("ReserveContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ContactIdResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ReserveContactError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Assigns a tag to a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deassigns a resource tag.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3196,32 +4001,51 @@ impl GroundStation for GroundStationClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_config`, also returning the metadata of the response.
+    pub async fn update_config_with_metadata(
+        &self,
+        input: UpdateConfigRequest,
+    ) -> Result<(ConfigIdResponse, ResponseMetadata), RusotoError<UpdateConfigError>> {
+        let request = self.build_update_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<ConfigIdResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateConfigError::from_response(response))
         }
     }
 
-    /// <p>Updates the <code>Config</code> used when scheduling contacts.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;Updating a &lt;code&gt;Config&lt;/code&gt; will not update the execution parameters
-    /// for existing future contacts scheduled with this &lt;code&gt;Config&lt;/code&gt;.&lt;/p&gt;
-    /// </code></pre>
-    async fn update_config(
+    /// Presigns the request `update_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConfigRequest,
-    ) -> Result<ConfigIdResponse, RusotoError<UpdateConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_config_request(&self, input: UpdateConfigRequest) -> SignedRequest {
         let request_uri = format!(
             "/config/{config_type}/{config_id}",
             config_id = input.config_id,
@@ -3229,62 +4053,629 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation("UpdateConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_mission_profile`, also returning the metadata of the response.
+    pub async fn update_mission_profile_with_metadata(
+        &self,
+        input: UpdateMissionProfileRequest,
+    ) -> Result<(MissionProfileIdResponse, ResponseMetadata), RusotoError<UpdateMissionProfileError>>
+    {
+        let request = self.build_update_mission_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfigIdResponse, _>()?;
+                .deserialize::<MissionProfileIdResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConfigError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateMissionProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates a mission profile.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;Updating a mission profile will not update the execution parameters
-    /// for existing future contacts.&lt;/p&gt;
-    /// </code></pre>
-    async fn update_mission_profile(
+    /// Presigns the request `update_mission_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_mission_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMissionProfileRequest,
-    ) -> Result<MissionProfileIdResponse, RusotoError<UpdateMissionProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_mission_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_mission_profile_request(
+        &self,
+        input: UpdateMissionProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/missionprofile/{mission_profile_id}",
             mission_profile_id = input.mission_profile_id
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation("UpdateMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<MissionProfileIdResponse, _>()?;
+        request
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMissionProfileError::from_response(response))
-        }
+    /// The pages of `ListConfigs` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_configs_pages(
+        &self,
+        input: ListConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ListConfigsResponse, RusotoError<ListConfigsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input| async move { client.list_configs(input).await },
+            |input: &ListConfigsRequest, output: &ListConfigsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListConfigsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `configList` of every page of `ListConfigs` results for `input`, as `list_configs_pages` requests them.
+    pub fn list_configs_stream(
+        &self,
+        input: ListConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ConfigListItem, RusotoError<ListConfigsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_configs_pages(input), |page| {
+            page.config_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListContacts` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_contacts_pages(
+        &self,
+        input: ListContactsRequest,
+    ) -> impl futures::Stream<Item = Result<ListContactsResponse, RusotoError<ListContactsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input| async move { client.list_contacts(input).await },
+            |input: &ListContactsRequest, output: &ListContactsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListContactsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `contactList` of every page of `ListContacts` results for `input`, as `list_contacts_pages` requests them.
+    pub fn list_contacts_stream(
+        &self,
+        input: ListContactsRequest,
+    ) -> impl futures::Stream<Item = Result<ContactData, RusotoError<ListContactsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_contacts_pages(input), |page| {
+            page.contact_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDataflowEndpointGroups` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_dataflow_endpoint_groups_pages(
+        &self,
+        input: ListDataflowEndpointGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListDataflowEndpointGroupsResponse,
+            RusotoError<ListDataflowEndpointGroupsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input| async move {
+                client.list_dataflow_endpoint_groups(input).await
+            },
+            |input: &ListDataflowEndpointGroupsRequest,
+             output: &ListDataflowEndpointGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDataflowEndpointGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `dataflowEndpointGroupList` of every page of `ListDataflowEndpointGroups` results for `input`, as `list_dataflow_endpoint_groups_pages` requests them.
+    pub fn list_dataflow_endpoint_groups_stream(
+        &self,
+        input: ListDataflowEndpointGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DataflowEndpointListItem, RusotoError<ListDataflowEndpointGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_dataflow_endpoint_groups_pages(input), |page| {
+            page.dataflow_endpoint_group_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGroundStations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_ground_stations_pages(
+        &self,
+        input: ListGroundStationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListGroundStationsResponse, RusotoError<ListGroundStationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input| async move { client.list_ground_stations(input).await },
+            |input: &ListGroundStationsRequest, output: &ListGroundStationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGroundStationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `groundStationList` of every page of `ListGroundStations` results for `input`, as `list_ground_stations_pages` requests them.
+    pub fn list_ground_stations_stream(
+        &self,
+        input: ListGroundStationsRequest,
+    ) -> impl futures::Stream<Item = Result<GroundStationData, RusotoError<ListGroundStationsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_ground_stations_pages(input), |page| {
+            page.ground_station_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListMissionProfiles` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_mission_profiles_pages(
+        &self,
+        input: ListMissionProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListMissionProfilesResponse, RusotoError<ListMissionProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input| async move {
+                client.list_mission_profiles(input).await
+            },
+            |input: &ListMissionProfilesRequest, output: &ListMissionProfilesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMissionProfilesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `missionProfileList` of every page of `ListMissionProfiles` results for `input`, as `list_mission_profiles_pages` requests them.
+    pub fn list_mission_profiles_stream(
+        &self,
+        input: ListMissionProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<MissionProfileListItem, RusotoError<ListMissionProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_mission_profiles_pages(input), |page| {
+            page.mission_profile_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSatellites` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_satellites_pages(
+        &self,
+        input: ListSatellitesRequest,
+    ) -> impl futures::Stream<Item = Result<ListSatellitesResponse, RusotoError<ListSatellitesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GroundStationClient, input| async move { client.list_satellites(input).await },
+            |input: &ListSatellitesRequest, output: &ListSatellitesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSatellitesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `satellites` of every page of `ListSatellites` results for `input`, as `list_satellites_pages` requests them.
+    pub fn list_satellites_stream(
+        &self,
+        input: ListSatellitesRequest,
+    ) -> impl futures::Stream<Item = Result<SatelliteListItem, RusotoError<ListSatellitesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_satellites_pages(input), |page| {
+            page.satellites.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `ContactScheduled`, polling `DescribeContact` with `input` every 5 seconds, up to 180 times.
+    pub async fn wait_until_contact_scheduled(
+        &self,
+        input: DescribeContactRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeContactError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 180);
+        self.wait_until_contact_scheduled_with(input, &waiter).await
+    }
+
+    /// Waits for `ContactScheduled` like `wait_until_contact_scheduled`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_contact_scheduled_with(
+        &self,
+        input: DescribeContactRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeContactError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_contact(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.contact_status.as_ref())
+                        .any(|value| value == "FAILED_TO_SCHEDULE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.contact_status.as_ref())
+                        .any(|value| value == "SCHEDULED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl GroundStation for GroundStationClient {
+    /// <p>Cancels a contact with a specified contact ID.</p>
+    async fn cancel_contact(
+        &self,
+        input: CancelContactRequest,
+    ) -> Result<ContactIdResponse, RusotoError<CancelContactError>> {
+        self.cancel_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>Config</code> with the specified <code>configData</code> parameters.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;Only one type of &lt;code&gt;configData&lt;/code&gt; can be specified.&lt;/p&gt;
+    /// </code></pre>
+    async fn create_config(
+        &self,
+        input: CreateConfigRequest,
+    ) -> Result<ConfigIdResponse, RusotoError<CreateConfigError>> {
+        self.create_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>DataflowEndpoint</code> group containing the specified list of <code>DataflowEndpoint</code> objects.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;The &lt;code&gt;name&lt;/code&gt; field in each endpoint is used in your mission profile &lt;code&gt;DataflowEndpointConfig&lt;/code&gt;
+    /// to specify which endpoints to use during a contact.&lt;/p&gt;
+    /// &lt;p&gt;When a contact uses multiple &lt;code&gt;DataflowEndpointConfig&lt;/code&gt; objects, each &lt;code&gt;Config&lt;/code&gt;
+    /// must match a &lt;code&gt;DataflowEndpoint&lt;/code&gt; in the same group.&lt;/p&gt;
+    /// </code></pre>
+    async fn create_dataflow_endpoint_group(
+        &self,
+        input: CreateDataflowEndpointGroupRequest,
+    ) -> Result<DataflowEndpointGroupIdResponse, RusotoError<CreateDataflowEndpointGroupError>>
+    {
+        self.create_dataflow_endpoint_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a mission profile.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;
+    /// &lt;code&gt;dataflowEdges&lt;/code&gt; is a list of lists of strings. Each lower level list of strings
+    /// has two elements: a &lt;i&gt;from ARN&lt;/i&gt; and a &lt;i&gt;to ARN&lt;/i&gt;.&lt;/p&gt;
+    /// </code></pre>
+    async fn create_mission_profile(
+        &self,
+        input: CreateMissionProfileRequest,
+    ) -> Result<MissionProfileIdResponse, RusotoError<CreateMissionProfileError>> {
+        self.create_mission_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <code>Config</code>.</p>
+    async fn delete_config(
+        &self,
+        input: DeleteConfigRequest,
+    ) -> Result<ConfigIdResponse, RusotoError<DeleteConfigError>> {
+        self.delete_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a dataflow endpoint group.</p>
+    async fn delete_dataflow_endpoint_group(
+        &self,
+        input: DeleteDataflowEndpointGroupRequest,
+    ) -> Result<DataflowEndpointGroupIdResponse, RusotoError<DeleteDataflowEndpointGroupError>>
+    {
+        self.delete_dataflow_endpoint_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a mission profile.</p>
+    async fn delete_mission_profile(
+        &self,
+        input: DeleteMissionProfileRequest,
+    ) -> Result<MissionProfileIdResponse, RusotoError<DeleteMissionProfileError>> {
+        self.delete_mission_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing contact.</p>
+    async fn describe_contact(
+        &self,
+        input: DescribeContactRequest,
+    ) -> Result<DescribeContactResponse, RusotoError<DescribeContactError>> {
+        self.describe_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns <code>Config</code> information.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;Only one &lt;code&gt;Config&lt;/code&gt; response can be returned.&lt;/p&gt;
+    /// </code></pre>
+    async fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
+        self.get_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the dataflow endpoint group.</p>
+    async fn get_dataflow_endpoint_group(
+        &self,
+        input: GetDataflowEndpointGroupRequest,
+    ) -> Result<GetDataflowEndpointGroupResponse, RusotoError<GetDataflowEndpointGroupError>> {
+        self.get_dataflow_endpoint_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the number of minutes used by account.</p>
+    async fn get_minute_usage(
+        &self,
+        input: GetMinuteUsageRequest,
+    ) -> Result<GetMinuteUsageResponse, RusotoError<GetMinuteUsageError>> {
+        self.get_minute_usage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a mission profile.</p>
+    async fn get_mission_profile(
+        &self,
+        input: GetMissionProfileRequest,
+    ) -> Result<GetMissionProfileResponse, RusotoError<GetMissionProfileError>> {
+        self.get_mission_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a satellite.</p>
+    async fn get_satellite(
+        &self,
+        input: GetSatelliteRequest,
+    ) -> Result<GetSatelliteResponse, RusotoError<GetSatelliteError>> {
+        self.get_satellite_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <code>Config</code> objects.</p>
+    async fn list_configs(
+        &self,
+        input: ListConfigsRequest,
+    ) -> Result<ListConfigsResponse, RusotoError<ListConfigsError>> {
+        self.list_configs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of contacts.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;If &lt;code&gt;statusList&lt;/code&gt; contains AVAILABLE, the request must include
+    /// &lt;code&gt;groundstation&lt;/code&gt;, &lt;code&gt;missionprofileArn&lt;/code&gt;, and &lt;code&gt;satelliteArn&lt;/code&gt;.
+    /// &lt;/p&gt;
+    /// </code></pre>
+    async fn list_contacts(
+        &self,
+        input: ListContactsRequest,
+    ) -> Result<ListContactsResponse, RusotoError<ListContactsError>> {
+        self.list_contacts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <code>DataflowEndpoint</code> groups.</p>
+    async fn list_dataflow_endpoint_groups(
+        &self,
+        input: ListDataflowEndpointGroupsRequest,
+    ) -> Result<ListDataflowEndpointGroupsResponse, RusotoError<ListDataflowEndpointGroupsError>>
+    {
+        self.list_dataflow_endpoint_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of ground stations. </p>
+    async fn list_ground_stations(
+        &self,
+        input: ListGroundStationsRequest,
+    ) -> Result<ListGroundStationsResponse, RusotoError<ListGroundStationsError>> {
+        self.list_ground_stations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of mission profiles.</p>
+    async fn list_mission_profiles(
+        &self,
+        input: ListMissionProfilesRequest,
+    ) -> Result<ListMissionProfilesResponse, RusotoError<ListMissionProfilesError>> {
+        self.list_mission_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of satellites.</p>
+    async fn list_satellites(
+        &self,
+        input: ListSatellitesRequest,
+    ) -> Result<ListSatellitesResponse, RusotoError<ListSatellitesError>> {
+        self.list_satellites_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of tags or a specified resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Reserves a contact using specified parameters.</p>
+    async fn reserve_contact(
+        &self,
+        input: ReserveContactRequest,
+    ) -> Result<ContactIdResponse, RusotoError<ReserveContactError>> {
+        self.reserve_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns a tag to a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deassigns a resource tag.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the <code>Config</code> used when scheduling contacts.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;Updating a &lt;code&gt;Config&lt;/code&gt; will not update the execution parameters
+    /// for existing future contacts scheduled with this &lt;code&gt;Config&lt;/code&gt;.&lt;/p&gt;
+    /// </code></pre>
+    async fn update_config(
+        &self,
+        input: UpdateConfigRequest,
+    ) -> Result<ConfigIdResponse, RusotoError<UpdateConfigError>> {
+        self.update_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a mission profile.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;Updating a mission profile will not update the execution parameters
+    /// for existing future contacts.&lt;/p&gt;
+    /// </code></pre>
+    async fn update_mission_profile(
+        &self,
+        input: UpdateMissionProfileRequest,
+    ) -> Result<MissionProfileIdResponse, RusotoError<UpdateMissionProfileError>> {
+        self.update_mission_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1956,11 +1956,14 @@ pub struct UpdateThreatIntelSetResponse {}
 
 /// Errors returned by AcceptInvitation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptInvitationError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptInvitationError {
@@ -1968,15 +1971,16 @@ impl AcceptInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AcceptInvitationError::BadRequest(err.msg))
+                    return RusotoError::service(AcceptInvitationError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AcceptInvitationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptInvitationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AcceptInvitationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1988,17 +1992,21 @@ impl fmt::Display for AcceptInvitationError {
         match *self {
             AcceptInvitationError::BadRequest(ref cause) => write!(f, "{}", cause),
             AcceptInvitationError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            AcceptInvitationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcceptInvitationError {}
 /// Errors returned by ArchiveFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ArchiveFindingsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ArchiveFindingsError {
@@ -2006,13 +2014,16 @@ impl ArchiveFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ArchiveFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(ArchiveFindingsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ArchiveFindingsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ArchiveFindingsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ArchiveFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2024,17 +2035,21 @@ impl fmt::Display for ArchiveFindingsError {
         match *self {
             ArchiveFindingsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ArchiveFindingsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ArchiveFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ArchiveFindingsError {}
 /// Errors returned by CreateDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDetectorError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDetectorError {
@@ -2042,13 +2057,16 @@ impl CreateDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDetectorError::BadRequest(err.msg))
+                    return RusotoError::service(CreateDetectorError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateDetectorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDetectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2060,17 +2078,21 @@ impl fmt::Display for CreateDetectorError {
         match *self {
             CreateDetectorError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateDetectorError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDetectorError {}
 /// Errors returned by CreateFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFilterError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFilterError {
@@ -2078,13 +2100,16 @@ impl CreateFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFilterError::BadRequest(err.msg))
+                    return RusotoError::service(CreateFilterError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateFilterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateFilterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2096,17 +2121,21 @@ impl fmt::Display for CreateFilterError {
         match *self {
             CreateFilterError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateFilterError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFilterError {}
 /// Errors returned by CreateIPSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIPSetError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIPSetError {
@@ -2114,13 +2143,16 @@ impl CreateIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIPSetError::BadRequest(err.msg))
+                    return RusotoError::service(CreateIPSetError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateIPSetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateIPSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2132,17 +2164,21 @@ impl fmt::Display for CreateIPSetError {
         match *self {
             CreateIPSetError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateIPSetError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateIPSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIPSetError {}
 /// Errors returned by CreateMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMembersError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMembersError {
@@ -2150,13 +2186,16 @@ impl CreateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMembersError::BadRequest(err.msg))
+                    return RusotoError::service(CreateMembersError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateMembersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2168,17 +2207,21 @@ impl fmt::Display for CreateMembersError {
         match *self {
             CreateMembersError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateMembersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMembersError {}
 /// Errors returned by CreatePublishingDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePublishingDestinationError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePublishingDestinationError {
@@ -2188,17 +2231,24 @@ impl CreatePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreatePublishingDestinationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePublishingDestinationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePublishingDestinationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreatePublishingDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2212,17 +2262,21 @@ impl fmt::Display for CreatePublishingDestinationError {
             CreatePublishingDestinationError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreatePublishingDestinationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePublishingDestinationError {}
 /// Errors returned by CreateSampleFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSampleFindingsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSampleFindingsError {
@@ -2230,15 +2284,19 @@ impl CreateSampleFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSampleFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateSampleFindingsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateSampleFindingsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSampleFindingsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSampleFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2250,17 +2308,21 @@ impl fmt::Display for CreateSampleFindingsError {
         match *self {
             CreateSampleFindingsError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateSampleFindingsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateSampleFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSampleFindingsError {}
 /// Errors returned by CreateThreatIntelSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateThreatIntelSetError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateThreatIntelSetError {
@@ -2268,15 +2330,19 @@ impl CreateThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateThreatIntelSetError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateThreatIntelSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateThreatIntelSetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateThreatIntelSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2288,17 +2354,21 @@ impl fmt::Display for CreateThreatIntelSetError {
         match *self {
             CreateThreatIntelSetError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateThreatIntelSetError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateThreatIntelSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateThreatIntelSetError {}
 /// Errors returned by DeclineInvitations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeclineInvitationsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeclineInvitationsError {
@@ -2306,15 +2376,16 @@ impl DeclineInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeclineInvitationsError::BadRequest(err.msg))
+                    return RusotoError::service(DeclineInvitationsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeclineInvitationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeclineInvitationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeclineInvitationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2326,17 +2397,21 @@ impl fmt::Display for DeclineInvitationsError {
         match *self {
             DeclineInvitationsError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeclineInvitationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeclineInvitationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeclineInvitationsError {}
 /// Errors returned by DeleteDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDetectorError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDetectorError {
@@ -2344,13 +2419,16 @@ impl DeleteDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDetectorError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteDetectorError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteDetectorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDetectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2362,17 +2440,21 @@ impl fmt::Display for DeleteDetectorError {
         match *self {
             DeleteDetectorError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteDetectorError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDetectorError {}
 /// Errors returned by DeleteFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFilterError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFilterError {
@@ -2380,13 +2462,16 @@ impl DeleteFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteFilterError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteFilterError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteFilterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteFilterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2398,17 +2483,21 @@ impl fmt::Display for DeleteFilterError {
         match *self {
             DeleteFilterError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteFilterError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFilterError {}
 /// Errors returned by DeleteIPSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIPSetError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIPSetError {
@@ -2416,13 +2505,16 @@ impl DeleteIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIPSetError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteIPSetError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteIPSetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIPSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2434,17 +2526,21 @@ impl fmt::Display for DeleteIPSetError {
         match *self {
             DeleteIPSetError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteIPSetError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteIPSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIPSetError {}
 /// Errors returned by DeleteInvitations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInvitationsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInvitationsError {
@@ -2452,15 +2548,16 @@ impl DeleteInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteInvitationsError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteInvitationsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteInvitationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInvitationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInvitationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2472,17 +2569,21 @@ impl fmt::Display for DeleteInvitationsError {
         match *self {
             DeleteInvitationsError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteInvitationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteInvitationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInvitationsError {}
 /// Errors returned by DeleteMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMembersError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMembersError {
@@ -2490,13 +2591,16 @@ impl DeleteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMembersError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteMembersError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteMembersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2508,17 +2612,21 @@ impl fmt::Display for DeleteMembersError {
         match *self {
             DeleteMembersError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteMembersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMembersError {}
 /// Errors returned by DeletePublishingDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePublishingDestinationError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePublishingDestinationError {
@@ -2528,17 +2636,24 @@ impl DeletePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeletePublishingDestinationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePublishingDestinationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePublishingDestinationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeletePublishingDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2552,17 +2667,21 @@ impl fmt::Display for DeletePublishingDestinationError {
             DeletePublishingDestinationError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeletePublishingDestinationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePublishingDestinationError {}
 /// Errors returned by DeleteThreatIntelSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteThreatIntelSetError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteThreatIntelSetError {
@@ -2570,15 +2689,19 @@ impl DeleteThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteThreatIntelSetError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteThreatIntelSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteThreatIntelSetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteThreatIntelSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2590,17 +2713,21 @@ impl fmt::Display for DeleteThreatIntelSetError {
         match *self {
             DeleteThreatIntelSetError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteThreatIntelSetError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteThreatIntelSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteThreatIntelSetError {}
 /// Errors returned by DescribePublishingDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePublishingDestinationError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePublishingDestinationError {
@@ -2610,17 +2737,24 @@ impl DescribePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribePublishingDestinationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePublishingDestinationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePublishingDestinationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribePublishingDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2634,17 +2768,23 @@ impl fmt::Display for DescribePublishingDestinationError {
             DescribePublishingDestinationError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribePublishingDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribePublishingDestinationError {}
 /// Errors returned by DisassociateFromMasterAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateFromMasterAccountError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateFromMasterAccountError {
@@ -2654,17 +2794,24 @@ impl DisassociateFromMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateFromMasterAccountError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateFromMasterAccountError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateFromMasterAccountError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2678,17 +2825,23 @@ impl fmt::Display for DisassociateFromMasterAccountError {
             DisassociateFromMasterAccountError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateFromMasterAccountError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateFromMasterAccountError {}
 /// Errors returned by DisassociateMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateMembersError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateMembersError {
@@ -2696,15 +2849,19 @@ impl DisassociateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateMembersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DisassociateMembersError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DisassociateMembersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateMembersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2716,17 +2873,21 @@ impl fmt::Display for DisassociateMembersError {
         match *self {
             DisassociateMembersError::BadRequest(ref cause) => write!(f, "{}", cause),
             DisassociateMembersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DisassociateMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateMembersError {}
 /// Errors returned by GetDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDetectorError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDetectorError {
@@ -2734,13 +2895,16 @@ impl GetDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDetectorError::BadRequest(err.msg))
+                    return RusotoError::service(GetDetectorError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetDetectorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDetectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2752,17 +2916,21 @@ impl fmt::Display for GetDetectorError {
         match *self {
             GetDetectorError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDetectorError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDetectorError {}
 /// Errors returned by GetFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFilterError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFilterError {
@@ -2770,13 +2938,13 @@ impl GetFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFilterError::BadRequest(err.msg))
+                    return RusotoError::service(GetFilterError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetFilterError::InternalServerError(err.msg))
+                    return RusotoError::service(GetFilterError::InternalServerError(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2788,17 +2956,21 @@ impl fmt::Display for GetFilterError {
         match *self {
             GetFilterError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetFilterError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFilterError {}
 /// Errors returned by GetFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFindingsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFindingsError {
@@ -2806,13 +2978,16 @@ impl GetFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(GetFindingsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetFindingsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetFindingsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2824,17 +2999,21 @@ impl fmt::Display for GetFindingsError {
         match *self {
             GetFindingsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetFindingsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFindingsError {}
 /// Errors returned by GetFindingsStatistics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFindingsStatisticsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFindingsStatisticsError {
@@ -2842,15 +3021,19 @@ impl GetFindingsStatisticsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFindingsStatisticsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetFindingsStatisticsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetFindingsStatisticsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFindingsStatisticsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFindingsStatisticsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2862,17 +3045,21 @@ impl fmt::Display for GetFindingsStatisticsError {
         match *self {
             GetFindingsStatisticsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetFindingsStatisticsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetFindingsStatisticsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFindingsStatisticsError {}
 /// Errors returned by GetIPSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIPSetError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIPSetError {
@@ -2880,13 +3067,13 @@ impl GetIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIPSetError::BadRequest(err.msg))
+                    return RusotoError::service(GetIPSetError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service(GetIPSetError::InternalServerError(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIPSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2898,17 +3085,21 @@ impl fmt::Display for GetIPSetError {
         match *self {
             GetIPSetError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetIPSetError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetIPSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIPSetError {}
 /// Errors returned by GetInvitationsCount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInvitationsCountError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInvitationsCountError {
@@ -2916,15 +3107,19 @@ impl GetInvitationsCountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetInvitationsCountError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetInvitationsCountError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetInvitationsCountError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInvitationsCountError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInvitationsCountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2936,17 +3131,21 @@ impl fmt::Display for GetInvitationsCountError {
         match *self {
             GetInvitationsCountError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetInvitationsCountError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetInvitationsCountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInvitationsCountError {}
 /// Errors returned by GetMasterAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMasterAccountError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMasterAccountError {
@@ -2954,15 +3153,16 @@ impl GetMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMasterAccountError::BadRequest(err.msg))
+                    return RusotoError::service(GetMasterAccountError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetMasterAccountError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMasterAccountError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMasterAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2974,17 +3174,21 @@ impl fmt::Display for GetMasterAccountError {
         match *self {
             GetMasterAccountError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetMasterAccountError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetMasterAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMasterAccountError {}
 /// Errors returned by GetMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMembersError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMembersError {
@@ -2992,13 +3196,16 @@ impl GetMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMembersError::BadRequest(err.msg))
+                    return RusotoError::service(GetMembersError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetMembersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3010,17 +3217,21 @@ impl fmt::Display for GetMembersError {
         match *self {
             GetMembersError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetMembersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMembersError {}
 /// Errors returned by GetThreatIntelSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetThreatIntelSetError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetThreatIntelSetError {
@@ -3028,15 +3239,16 @@ impl GetThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service(GetThreatIntelSetError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetThreatIntelSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetThreatIntelSetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetThreatIntelSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3048,17 +3260,21 @@ impl fmt::Display for GetThreatIntelSetError {
         match *self {
             GetThreatIntelSetError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetThreatIntelSetError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetThreatIntelSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetThreatIntelSetError {}
 /// Errors returned by InviteMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InviteMembersError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InviteMembersError {
@@ -3066,13 +3282,16 @@ impl InviteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(InviteMembersError::BadRequest(err.msg))
+                    return RusotoError::service(InviteMembersError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(InviteMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        InviteMembersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InviteMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3084,17 +3303,21 @@ impl fmt::Display for InviteMembersError {
         match *self {
             InviteMembersError::BadRequest(ref cause) => write!(f, "{}", cause),
             InviteMembersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            InviteMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InviteMembersError {}
 /// Errors returned by ListDetectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDetectorsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDetectorsError {
@@ -3102,13 +3325,16 @@ impl ListDetectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDetectorsError::BadRequest(err.msg))
+                    return RusotoError::service(ListDetectorsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListDetectorsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListDetectorsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDetectorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3120,17 +3346,21 @@ impl fmt::Display for ListDetectorsError {
         match *self {
             ListDetectorsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListDetectorsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListDetectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDetectorsError {}
 /// Errors returned by ListFilters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFiltersError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFiltersError {
@@ -3138,13 +3368,16 @@ impl ListFiltersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFiltersError::BadRequest(err.msg))
+                    return RusotoError::service(ListFiltersError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFiltersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListFiltersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFiltersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3156,17 +3389,21 @@ impl fmt::Display for ListFiltersError {
         match *self {
             ListFiltersError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListFiltersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListFiltersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFiltersError {}
 /// Errors returned by ListFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFindingsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFindingsError {
@@ -3174,13 +3411,16 @@ impl ListFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(ListFindingsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFindingsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListFindingsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3192,17 +3432,21 @@ impl fmt::Display for ListFindingsError {
         match *self {
             ListFindingsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListFindingsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFindingsError {}
 /// Errors returned by ListIPSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIPSetsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIPSetsError {
@@ -3210,13 +3454,16 @@ impl ListIPSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListIPSetsError::BadRequest(err.msg))
+                    return RusotoError::service(ListIPSetsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListIPSetsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListIPSetsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIPSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3228,17 +3475,21 @@ impl fmt::Display for ListIPSetsError {
         match *self {
             ListIPSetsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListIPSetsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListIPSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIPSetsError {}
 /// Errors returned by ListInvitations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInvitationsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInvitationsError {
@@ -3246,13 +3497,16 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInvitationsError::BadRequest(err.msg))
+                    return RusotoError::service(ListInvitationsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInvitationsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListInvitationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInvitationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3264,17 +3518,21 @@ impl fmt::Display for ListInvitationsError {
         match *self {
             ListInvitationsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListInvitationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListInvitationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInvitationsError {}
 /// Errors returned by ListMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMembersError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMembersError {
@@ -3282,13 +3540,16 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMembersError::BadRequest(err.msg))
+                    return RusotoError::service(ListMembersError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListMembersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3300,17 +3561,21 @@ impl fmt::Display for ListMembersError {
         match *self {
             ListMembersError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListMembersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMembersError {}
 /// Errors returned by ListPublishingDestinations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPublishingDestinationsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPublishingDestinationsError {
@@ -3320,17 +3585,21 @@ impl ListPublishingDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPublishingDestinationsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPublishingDestinationsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPublishingDestinationsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListPublishingDestinationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3344,17 +3613,21 @@ impl fmt::Display for ListPublishingDestinationsError {
             ListPublishingDestinationsError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListPublishingDestinationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPublishingDestinationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3362,15 +3635,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3382,17 +3659,21 @@ impl fmt::Display for ListTagsForResourceError {
         match *self {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListThreatIntelSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListThreatIntelSetsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListThreatIntelSetsError {
@@ -3400,15 +3681,19 @@ impl ListThreatIntelSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListThreatIntelSetsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListThreatIntelSetsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListThreatIntelSetsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListThreatIntelSetsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListThreatIntelSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3420,17 +3705,21 @@ impl fmt::Display for ListThreatIntelSetsError {
         match *self {
             ListThreatIntelSetsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListThreatIntelSetsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListThreatIntelSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListThreatIntelSetsError {}
 /// Errors returned by StartMonitoringMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartMonitoringMembersError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartMonitoringMembersError {
@@ -3438,15 +3727,19 @@ impl StartMonitoringMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartMonitoringMembersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        StartMonitoringMembersError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartMonitoringMembersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartMonitoringMembersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartMonitoringMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3458,17 +3751,21 @@ impl fmt::Display for StartMonitoringMembersError {
         match *self {
             StartMonitoringMembersError::BadRequest(ref cause) => write!(f, "{}", cause),
             StartMonitoringMembersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            StartMonitoringMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartMonitoringMembersError {}
 /// Errors returned by StopMonitoringMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopMonitoringMembersError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopMonitoringMembersError {
@@ -3476,15 +3773,19 @@ impl StopMonitoringMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopMonitoringMembersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        StopMonitoringMembersError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopMonitoringMembersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopMonitoringMembersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopMonitoringMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3496,17 +3797,21 @@ impl fmt::Display for StopMonitoringMembersError {
         match *self {
             StopMonitoringMembersError::BadRequest(ref cause) => write!(f, "{}", cause),
             StopMonitoringMembersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            StopMonitoringMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopMonitoringMembersError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -3514,13 +3819,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3532,17 +3840,21 @@ impl fmt::Display for TagResourceError {
         match *self {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UnarchiveFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnarchiveFindingsError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnarchiveFindingsError {
@@ -3550,15 +3862,16 @@ impl UnarchiveFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UnarchiveFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(UnarchiveFindingsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UnarchiveFindingsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnarchiveFindingsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnarchiveFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3570,17 +3883,21 @@ impl fmt::Display for UnarchiveFindingsError {
         match *self {
             UnarchiveFindingsError::BadRequest(ref cause) => write!(f, "{}", cause),
             UnarchiveFindingsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UnarchiveFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnarchiveFindingsError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -3588,13 +3905,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3606,17 +3926,21 @@ impl fmt::Display for UntagResourceError {
         match *self {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDetectorError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDetectorError {
@@ -3624,13 +3948,16 @@ impl UpdateDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDetectorError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateDetectorError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateDetectorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDetectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3642,17 +3969,21 @@ impl fmt::Display for UpdateDetectorError {
         match *self {
             UpdateDetectorError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateDetectorError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UpdateDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDetectorError {}
 /// Errors returned by UpdateFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFilterError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFilterError {
@@ -3660,13 +3991,16 @@ impl UpdateFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFilterError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateFilterError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFilterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateFilterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3678,17 +4012,21 @@ impl fmt::Display for UpdateFilterError {
         match *self {
             UpdateFilterError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateFilterError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UpdateFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFilterError {}
 /// Errors returned by UpdateFindingsFeedback
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFindingsFeedbackError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFindingsFeedbackError {
@@ -3696,15 +4034,19 @@ impl UpdateFindingsFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFindingsFeedbackError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateFindingsFeedbackError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFindingsFeedbackError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFindingsFeedbackError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFindingsFeedbackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3716,17 +4058,21 @@ impl fmt::Display for UpdateFindingsFeedbackError {
         match *self {
             UpdateFindingsFeedbackError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateFindingsFeedbackError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UpdateFindingsFeedbackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFindingsFeedbackError {}
 /// Errors returned by UpdateIPSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIPSetError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIPSetError {
@@ -3734,13 +4080,16 @@ impl UpdateIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIPSetError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateIPSetError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateIPSetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateIPSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3752,17 +4101,21 @@ impl fmt::Display for UpdateIPSetError {
         match *self {
             UpdateIPSetError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateIPSetError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UpdateIPSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateIPSetError {}
 /// Errors returned by UpdatePublishingDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePublishingDestinationError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePublishingDestinationError {
@@ -3772,17 +4125,24 @@ impl UpdatePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePublishingDestinationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePublishingDestinationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePublishingDestinationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdatePublishingDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3796,17 +4156,21 @@ impl fmt::Display for UpdatePublishingDestinationError {
             UpdatePublishingDestinationError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdatePublishingDestinationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePublishingDestinationError {}
 /// Errors returned by UpdateThreatIntelSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateThreatIntelSetError {
     /// <p>Bad request exception object.</p>
     BadRequest(String),
     /// <p>Internal server error exception object.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateThreatIntelSetError {
@@ -3814,15 +4178,19 @@ impl UpdateThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateThreatIntelSetError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateThreatIntelSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateThreatIntelSetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateThreatIntelSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3834,6 +4202,7 @@ impl fmt::Display for UpdateThreatIntelSetError {
         match *self {
             UpdateThreatIntelSetError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateThreatIntelSetError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UpdateThreatIntelSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4157,6 +4526,7 @@ impl GuardDutyClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> GuardDutyClient {
         GuardDutyClient {
             client: Client::shared(),
@@ -4182,367 +4552,702 @@ impl GuardDutyClient {
     pub fn new_with_client(client: Client, region: region::Region) -> GuardDutyClient {
         GuardDutyClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<GuardDutyClient> {
+        rusoto_core::ClientBuilder::new(GuardDutyClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> GuardDutyClient {
+        GuardDutyClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl GuardDuty for GuardDutyClient {
-    /// <p>Accepts the invitation to be monitored by a master GuardDuty account.</p>
-    async fn accept_invitation(
+impl GuardDutyClient {
+    /// Calls `accept_invitation`, also returning the metadata of the response.
+    pub async fn accept_invitation_with_metadata(
         &self,
         input: AcceptInvitationRequest,
-    ) -> Result<AcceptInvitationResponse, RusotoError<AcceptInvitationError>> {
+    ) -> Result<(AcceptInvitationResponse, ResponseMetadata), RusotoError<AcceptInvitationError>>
+    {
+        let request = self.build_accept_invitation_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcceptInvitationResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AcceptInvitationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `accept_invitation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_invitation(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AcceptInvitationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_invitation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_invitation_request(&self, input: AcceptInvitationRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/master",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `archive_findings`, also returning the metadata of the response.
+    pub async fn archive_findings_with_metadata(
+        &self,
+        input: ArchiveFindingsRequest,
+    ) -> Result<(ArchiveFindingsResponse, ResponseMetadata), RusotoError<ArchiveFindingsError>>
+    {
+        let request = self.build_archive_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AcceptInvitationResponse, _>()?;
+                .deserialize::<ArchiveFindingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AcceptInvitationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ArchiveFindingsError::from_response(response))
         }
     }
 
-    /// <p><p>Archives GuardDuty findings specified by the list of finding IDs.</p> <note> <p>Only the master account can archive findings. Member accounts do not have permission to archive findings from their accounts.</p> </note></p>
-    async fn archive_findings(
+    /// Presigns the request `archive_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_archive_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ArchiveFindingsRequest,
-    ) -> Result<ArchiveFindingsResponse, RusotoError<ArchiveFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_archive_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_archive_findings_request(&self, input: ArchiveFindingsRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/findings/archive",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ArchiveFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_detector`, also returning the metadata of the response.
+    pub async fn create_detector_with_metadata(
+        &self,
+        input: CreateDetectorRequest,
+    ) -> Result<(CreateDetectorResponse, ResponseMetadata), RusotoError<CreateDetectorError>> {
+        let request = self.build_create_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ArchiveFindingsResponse, _>()?;
+                .deserialize::<CreateDetectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ArchiveFindingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDetectorError::from_response(response))
         }
     }
 
-    /// <p>Creates a single Amazon GuardDuty detector. A detector is a resource that represents the GuardDuty service. To start using GuardDuty, you must create a detector in each region that you enable the service. You can have only one detector per account per region.</p>
-    async fn create_detector(
+    /// Presigns the request `create_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_detector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDetectorRequest,
-    ) -> Result<CreateDetectorResponse, RusotoError<CreateDetectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_detector_request(&self, input: CreateDetectorRequest) -> SignedRequest {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_filter`, also returning the metadata of the response.
+    pub async fn create_filter_with_metadata(
+        &self,
+        input: CreateFilterRequest,
+    ) -> Result<(CreateFilterResponse, ResponseMetadata), RusotoError<CreateFilterError>> {
+        let request = self.build_create_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDetectorResponse, _>()?;
+                .deserialize::<CreateFilterResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDetectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateFilterError::from_response(response))
         }
     }
 
-    /// <p>Creates a filter using the specified finding criteria.</p>
-    async fn create_filter(
+    /// Presigns the request `create_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFilterRequest,
-    ) -> Result<CreateFilterResponse, RusotoError<CreateFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_filter_request(&self, input: CreateFilterRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/filter",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_ip_set`, also returning the metadata of the response.
+    pub async fn create_ip_set_with_metadata(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> Result<(CreateIPSetResponse, ResponseMetadata), RusotoError<CreateIPSetError>> {
+        let request = self.build_create_ip_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateFilterResponse, _>()?;
+                .deserialize::<CreateIPSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFilterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateIPSetError::from_response(response))
         }
     }
 
-    /// <p>Creates a new IPSet, called Trusted IP list in the consoler user interface. An IPSet is a list IP addresses trusted for secure communication with AWS infrastructure and applications. GuardDuty does not generate findings for IP addresses included in IPSets. Only users from the master account can use this operation.</p>
-    async fn create_ip_set(
+    /// Presigns the request `create_ip_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_ip_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIPSetRequest,
-    ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_ip_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_ip_set_request(&self, input: CreateIPSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/ipset",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_members`, also returning the metadata of the response.
+    pub async fn create_members_with_metadata(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<(CreateMembersResponse, ResponseMetadata), RusotoError<CreateMembersError>> {
+        let request = self.build_create_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateIPSetResponse, _>()?;
+                .deserialize::<CreateMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIPSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateMembersError::from_response(response))
         }
     }
 
-    /// <p>Creates member accounts of the current AWS account by specifying a list of AWS account IDs. The current AWS account can then invite these members to manage GuardDuty in their accounts.</p>
-    async fn create_members(
+    /// Presigns the request `create_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMembersRequest,
-    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_members_request(&self, input: CreateMembersRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/member",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_publishing_destination`, also returning the metadata of the response.
+    pub async fn create_publishing_destination_with_metadata(
+        &self,
+        input: CreatePublishingDestinationRequest,
+    ) -> Result<
+        (CreatePublishingDestinationResponse, ResponseMetadata),
+        RusotoError<CreatePublishingDestinationError>,
+    > {
+        let request = self.build_create_publishing_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMembersResponse, _>()?;
+                .deserialize::<CreatePublishingDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreatePublishingDestinationError::from_response(response))
         }
     }
 
-    /// <p>Creates a publishing destination to send findings to. The resource to send findings to must exist before you use this operation.</p>
-    async fn create_publishing_destination(
+    /// Presigns the request `create_publishing_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_publishing_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePublishingDestinationRequest,
-    ) -> Result<CreatePublishingDestinationResponse, RusotoError<CreatePublishingDestinationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_publishing_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_publishing_destination_request(
+        &self,
+        input: CreatePublishingDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/publishingDestination",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreatePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_sample_findings`, also returning the metadata of the response.
+    pub async fn create_sample_findings_with_metadata(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> Result<
+        (CreateSampleFindingsResponse, ResponseMetadata),
+        RusotoError<CreateSampleFindingsError>,
+    > {
+        let request = self.build_create_sample_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePublishingDestinationResponse, _>()?;
+                .deserialize::<CreateSampleFindingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePublishingDestinationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateSampleFindingsError::from_response(response))
         }
     }
 
-    /// <p>Generates example findings of types specified by the list of finding types. If 'NULL' is specified for <code>findingTypes</code>, the API generates example findings of all supported finding types.</p>
-    async fn create_sample_findings(
+    /// Presigns the request `create_sample_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_sample_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSampleFindingsRequest,
-    ) -> Result<CreateSampleFindingsResponse, RusotoError<CreateSampleFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_sample_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_sample_findings_request(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/findings/create",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateSampleFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_threat_intel_set`, also returning the metadata of the response.
+    pub async fn create_threat_intel_set_with_metadata(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> Result<
+        (CreateThreatIntelSetResponse, ResponseMetadata),
+        RusotoError<CreateThreatIntelSetError>,
+    > {
+        let request = self.build_create_threat_intel_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSampleFindingsResponse, _>()?;
+                .deserialize::<CreateThreatIntelSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSampleFindingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateThreatIntelSetError::from_response(response))
         }
     }
 
-    /// <p>Create a new ThreatIntelSet. ThreatIntelSets consist of known malicious IP addresses. GuardDuty generates findings based on ThreatIntelSets. Only users of the master account can use this operation.</p>
-    async fn create_threat_intel_set(
+    /// Presigns the request `create_threat_intel_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_threat_intel_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateThreatIntelSetRequest,
-    ) -> Result<CreateThreatIntelSetResponse, RusotoError<CreateThreatIntelSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_threat_intel_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_threat_intel_set_request(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/threatintelset",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `decline_invitations`, also returning the metadata of the response.
+    pub async fn decline_invitations_with_metadata(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> Result<(DeclineInvitationsResponse, ResponseMetadata), RusotoError<DeclineInvitationsError>>
+    {
+        let request = self.build_decline_invitations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateThreatIntelSetResponse, _>()?;
+                .deserialize::<DeclineInvitationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateThreatIntelSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeclineInvitationsError::from_response(response))
         }
     }
 
-    /// <p>Declines invitations sent to the current member account by AWS account specified by their account IDs.</p>
-    async fn decline_invitations(
+    /// Presigns the request `decline_invitations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_decline_invitations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeclineInvitationsRequest,
-    ) -> Result<DeclineInvitationsResponse, RusotoError<DeclineInvitationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_decline_invitations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_decline_invitations_request(&self, input: DeclineInvitationsRequest) -> SignedRequest {
         let request_uri = "/invitation/decline";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeclineInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_detector`, also returning the metadata of the response.
+    pub async fn delete_detector_with_metadata(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> Result<(DeleteDetectorResponse, ResponseMetadata), RusotoError<DeleteDetectorError>> {
+        let request = self.build_delete_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeclineInvitationsResponse, _>()?;
+                .deserialize::<DeleteDetectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeclineInvitationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDetectorError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Amazon GuardDuty detector specified by the detector ID.</p>
-    async fn delete_detector(
+    /// Presigns the request `delete_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_detector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDetectorRequest,
-    ) -> Result<DeleteDetectorResponse, RusotoError<DeleteDetectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_detector_request(&self, input: DeleteDetectorRequest) -> SignedRequest {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_filter`, also returning the metadata of the response.
+    pub async fn delete_filter_with_metadata(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> Result<(DeleteFilterResponse, ResponseMetadata), RusotoError<DeleteFilterError>> {
+        let request = self.build_delete_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDetectorResponse, _>()?;
+                .deserialize::<DeleteFilterResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDetectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFilterError::from_response(response))
         }
     }
 
-    /// <p>Deletes the filter specified by the filter name.</p>
-    async fn delete_filter(
+    /// Presigns the request `delete_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFilterRequest,
-    ) -> Result<DeleteFilterResponse, RusotoError<DeleteFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_filter_request(&self, input: DeleteFilterRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/filter/{filter_name}",
             detector_id = input.detector_id,
@@ -4550,30 +5255,54 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_ip_set`, also returning the metadata of the response.
+    pub async fn delete_ip_set_with_metadata(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> Result<(DeleteIPSetResponse, ResponseMetadata), RusotoError<DeleteIPSetError>> {
+        let request = self.build_delete_ip_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteFilterResponse, _>()?;
+                .deserialize::<DeleteIPSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFilterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteIPSetError::from_response(response))
         }
     }
 
-    /// <p>Deletes the IPSet specified by the <code>ipSetId</code>. IPSets are called Trusted IP lists in the console user interface.</p>
-    async fn delete_ip_set(
+    /// Presigns the request `delete_ip_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_ip_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIPSetRequest,
-    ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_ip_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_ip_set_request(&self, input: DeleteIPSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/ipset/{ip_set_id}",
             detector_id = input.detector_id,
@@ -4581,94 +5310,172 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_invitations`, also returning the metadata of the response.
+    pub async fn delete_invitations_with_metadata(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> Result<(DeleteInvitationsResponse, ResponseMetadata), RusotoError<DeleteInvitationsError>>
+    {
+        let request = self.build_delete_invitations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteIPSetResponse, _>()?;
+                .deserialize::<DeleteInvitationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIPSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteInvitationsError::from_response(response))
         }
     }
 
-    /// <p>Deletes invitations sent to the current member account by AWS accounts specified by their account IDs.</p>
-    async fn delete_invitations(
+    /// Presigns the request `delete_invitations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_invitations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInvitationsRequest,
-    ) -> Result<DeleteInvitationsResponse, RusotoError<DeleteInvitationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_invitations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_invitations_request(&self, input: DeleteInvitationsRequest) -> SignedRequest {
         let request_uri = "/invitation/delete";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_members`, also returning the metadata of the response.
+    pub async fn delete_members_with_metadata(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<(DeleteMembersResponse, ResponseMetadata), RusotoError<DeleteMembersError>> {
+        let request = self.build_delete_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInvitationsResponse, _>()?;
+                .deserialize::<DeleteMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInvitationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMembersError::from_response(response))
         }
     }
 
-    /// <p>Deletes GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
-    async fn delete_members(
+    /// Presigns the request `delete_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMembersRequest,
-    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_members_request(&self, input: DeleteMembersRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/member/delete",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_publishing_destination`, also returning the metadata of the response.
+    pub async fn delete_publishing_destination_with_metadata(
+        &self,
+        input: DeletePublishingDestinationRequest,
+    ) -> Result<
+        (DeletePublishingDestinationResponse, ResponseMetadata),
+        RusotoError<DeletePublishingDestinationError>,
+    > {
+        let request = self.build_delete_publishing_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMembersResponse, _>()?;
+                .deserialize::<DeletePublishingDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeletePublishingDestinationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the publishing definition with the specified <code>destinationId</code>.</p>
-    async fn delete_publishing_destination(
+    /// Presigns the request `delete_publishing_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_publishing_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePublishingDestinationRequest,
-    ) -> Result<DeletePublishingDestinationResponse, RusotoError<DeletePublishingDestinationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_publishing_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_publishing_destination_request(
+        &self,
+        input: DeletePublishingDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/publishingDestination/{destination_id}",
             destination_id = input.destination_id,
@@ -4676,30 +5483,60 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeletePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_threat_intel_set`, also returning the metadata of the response.
+    pub async fn delete_threat_intel_set_with_metadata(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> Result<
+        (DeleteThreatIntelSetResponse, ResponseMetadata),
+        RusotoError<DeleteThreatIntelSetError>,
+    > {
+        let request = self.build_delete_threat_intel_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePublishingDestinationResponse, _>()?;
+                .deserialize::<DeleteThreatIntelSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePublishingDestinationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteThreatIntelSetError::from_response(response))
         }
     }
 
-    /// <p>Deletes ThreatIntelSet specified by the ThreatIntelSet ID.</p>
-    async fn delete_threat_intel_set(
+    /// Presigns the request `delete_threat_intel_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_threat_intel_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteThreatIntelSetRequest,
-    ) -> Result<DeleteThreatIntelSetResponse, RusotoError<DeleteThreatIntelSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_threat_intel_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_threat_intel_set_request(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/threatintelset/{threat_intel_set_id}",
             detector_id = input.detector_id,
@@ -4707,33 +5544,60 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_publishing_destination`, also returning the metadata of the response.
+    pub async fn describe_publishing_destination_with_metadata(
+        &self,
+        input: DescribePublishingDestinationRequest,
+    ) -> Result<
+        (DescribePublishingDestinationResponse, ResponseMetadata),
+        RusotoError<DescribePublishingDestinationError>,
+    > {
+        let request = self.build_describe_publishing_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteThreatIntelSetResponse, _>()?;
+                .deserialize::<DescribePublishingDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteThreatIntelSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribePublishingDestinationError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the publishing destination specified by the provided <code>destinationId</code>.</p>
-    async fn describe_publishing_destination(
+    /// Presigns the request `describe_publishing_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_publishing_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePublishingDestinationRequest,
-    ) -> Result<
-        DescribePublishingDestinationResponse,
-        RusotoError<DescribePublishingDestinationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_publishing_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_publishing_destination_request(
+        &self,
+        input: DescribePublishingDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/publishingDestination/{destination_id}",
             destination_id = input.destination_id,
@@ -4741,123 +5605,228 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("DescribePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `disassociate_from_master_account`, also returning the metadata of the response.
+    pub async fn disassociate_from_master_account_with_metadata(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> Result<
+        (DisassociateFromMasterAccountResponse, ResponseMetadata),
+        RusotoError<DisassociateFromMasterAccountError>,
+    > {
+        let request = self.build_disassociate_from_master_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePublishingDestinationResponse, _>()?;
+                .deserialize::<DisassociateFromMasterAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePublishingDestinationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateFromMasterAccountError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the current GuardDuty member account from its master account.</p>
-    async fn disassociate_from_master_account(
+    /// Presigns the request `disassociate_from_master_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_from_master_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateFromMasterAccountRequest,
-    ) -> Result<
-        DisassociateFromMasterAccountResponse,
-        RusotoError<DisassociateFromMasterAccountError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_from_master_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_from_master_account_request(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/master/disassociate",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateFromMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `disassociate_members`, also returning the metadata of the response.
+    pub async fn disassociate_members_with_metadata(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> Result<
+        (DisassociateMembersResponse, ResponseMetadata),
+        RusotoError<DisassociateMembersError>,
+    > {
+        let request = self.build_disassociate_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateFromMasterAccountResponse, _>()?;
+                .deserialize::<DisassociateMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateFromMasterAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateMembersError::from_response(response))
         }
     }
 
-    /// <p>Disassociates GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
-    async fn disassociate_members(
+    /// Presigns the request `disassociate_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateMembersRequest,
-    ) -> Result<DisassociateMembersResponse, RusotoError<DisassociateMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_members_request(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/member/disassociate",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_detector`, also returning the metadata of the response.
+    pub async fn get_detector_with_metadata(
+        &self,
+        input: GetDetectorRequest,
+    ) -> Result<(GetDetectorResponse, ResponseMetadata), RusotoError<GetDetectorError>> {
+        let request = self.build_get_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateMembersResponse, _>()?;
+                .deserialize::<GetDetectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDetectorError::from_response(response))
         }
     }
 
-    /// <p>Retrieves an Amazon GuardDuty detector specified by the detectorId.</p>
-    async fn get_detector(
+    /// Presigns the request `get_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_detector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDetectorRequest,
-    ) -> Result<GetDetectorResponse, RusotoError<GetDetectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_detector_request(&self, input: GetDetectorRequest) -> SignedRequest {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_filter`, also returning the metadata of the response.
+    pub async fn get_filter_with_metadata(
+        &self,
+        input: GetFilterRequest,
+    ) -> Result<(GetFilterResponse, ResponseMetadata), RusotoError<GetFilterError>> {
+        let request = self.build_get_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDetectorResponse, _>()?;
+                .deserialize::<GetFilterResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDetectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFilterError::from_response(response))
         }
     }
 
-    /// <p>Returns the details of the filter specified by the filter name.</p>
-    async fn get_filter(
+    /// Presigns the request `get_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFilterRequest,
-    ) -> Result<GetFilterResponse, RusotoError<GetFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_filter_request(&self, input: GetFilterRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/filter/{filter_name}",
             detector_id = input.detector_id,
@@ -4865,96 +5834,174 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_findings`, also returning the metadata of the response.
+    pub async fn get_findings_with_metadata(
+        &self,
+        input: GetFindingsRequest,
+    ) -> Result<(GetFindingsResponse, ResponseMetadata), RusotoError<GetFindingsError>> {
+        let request = self.build_get_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFilterResponse, _>()?;
+                .deserialize::<GetFindingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFilterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFindingsError::from_response(response))
         }
     }
 
-    /// <p>Describes Amazon GuardDuty findings specified by finding IDs.</p>
-    async fn get_findings(
+    /// Presigns the request `get_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFindingsRequest,
-    ) -> Result<GetFindingsResponse, RusotoError<GetFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_findings_request(&self, input: GetFindingsRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/findings/get",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_findings_statistics`, also returning the metadata of the response.
+    pub async fn get_findings_statistics_with_metadata(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> Result<
+        (GetFindingsStatisticsResponse, ResponseMetadata),
+        RusotoError<GetFindingsStatisticsError>,
+    > {
+        let request = self.build_get_findings_statistics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFindingsResponse, _>()?;
+                .deserialize::<GetFindingsStatisticsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFindingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFindingsStatisticsError::from_response(response))
         }
     }
 
-    /// <p>Lists Amazon GuardDuty findings' statistics for the specified detector ID.</p>
-    async fn get_findings_statistics(
+    /// Presigns the request `get_findings_statistics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_findings_statistics(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFindingsStatisticsRequest,
-    ) -> Result<GetFindingsStatisticsResponse, RusotoError<GetFindingsStatisticsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_findings_statistics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_findings_statistics_request(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/findings/statistics",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindingsStatistics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_ip_set`, also returning the metadata of the response.
+    pub async fn get_ip_set_with_metadata(
+        &self,
+        input: GetIPSetRequest,
+    ) -> Result<(GetIPSetResponse, ResponseMetadata), RusotoError<GetIPSetError>> {
+        let request = self.build_get_ip_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFindingsStatisticsResponse, _>()?;
+                .deserialize::<GetIPSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFindingsStatisticsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIPSetError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the IPSet specified by the <code>ipSetId</code>.</p>
-    async fn get_ip_set(
+    /// Presigns the request `get_ip_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ip_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIPSetRequest,
-    ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ip_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ip_set_request(&self, input: GetIPSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/ipset/{ip_set_id}",
             detector_id = input.detector_id,
@@ -4962,119 +6009,219 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_invitations_count`, also returning the metadata of the response.
+    pub async fn get_invitations_count_with_metadata(
+        &self,
+    ) -> Result<
+        (GetInvitationsCountResponse, ResponseMetadata),
+        RusotoError<GetInvitationsCountError>,
+    > {
+        let request = self.build_get_invitations_count_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIPSetResponse, _>()?;
+                .deserialize::<GetInvitationsCountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIPSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetInvitationsCountError::from_response(response))
         }
     }
 
-    /// <p>Returns the count of all GuardDuty membership invitations that were sent to the current member account except the currently accepted invitation.</p>
-    async fn get_invitations_count(
+    /// Presigns the request `get_invitations_count` would send with `presigner` instead of sending it.
+    pub async fn presign_get_invitations_count(
         &self,
-    ) -> Result<GetInvitationsCountResponse, RusotoError<GetInvitationsCountError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_invitations_count_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_invitations_count_request(&self) -> SignedRequest {
         let request_uri = "/invitation/count";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetInvitationsCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_master_account`, also returning the metadata of the response.
+    pub async fn get_master_account_with_metadata(
+        &self,
+        input: GetMasterAccountRequest,
+    ) -> Result<(GetMasterAccountResponse, ResponseMetadata), RusotoError<GetMasterAccountError>>
+    {
+        let request = self.build_get_master_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInvitationsCountResponse, _>()?;
+                .deserialize::<GetMasterAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInvitationsCountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMasterAccountError::from_response(response))
         }
     }
 
-    /// <p>Provides the details for the GuardDuty master account associated with the current GuardDuty member account.</p>
-    async fn get_master_account(
+    /// Presigns the request `get_master_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_master_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMasterAccountRequest,
-    ) -> Result<GetMasterAccountResponse, RusotoError<GetMasterAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_master_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_master_account_request(&self, input: GetMasterAccountRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/master",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_members`, also returning the metadata of the response.
+    pub async fn get_members_with_metadata(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<(GetMembersResponse, ResponseMetadata), RusotoError<GetMembersError>> {
+        let request = self.build_get_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMasterAccountResponse, _>()?;
+                .deserialize::<GetMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMasterAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMembersError::from_response(response))
         }
     }
 
-    /// <p>Retrieves GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
-    async fn get_members(
+    /// Presigns the request `get_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMembersRequest,
-    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_members_request(&self, input: GetMembersRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/member/get",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_threat_intel_set`, also returning the metadata of the response.
+    pub async fn get_threat_intel_set_with_metadata(
+        &self,
+        input: GetThreatIntelSetRequest,
+    ) -> Result<(GetThreatIntelSetResponse, ResponseMetadata), RusotoError<GetThreatIntelSetError>>
+    {
+        let request = self.build_get_threat_intel_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMembersResponse, _>()?;
+                .deserialize::<GetThreatIntelSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetThreatIntelSetError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the ThreatIntelSet that is specified by the ThreatIntelSet ID.</p>
-    async fn get_threat_intel_set(
+    /// Presigns the request `get_threat_intel_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_threat_intel_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetThreatIntelSetRequest,
-    ) -> Result<GetThreatIntelSetResponse, RusotoError<GetThreatIntelSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_threat_intel_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_threat_intel_set_request(&self, input: GetThreatIntelSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/threatintelset/{threat_intel_set_id}",
             detector_id = input.detector_id,
@@ -5082,66 +6229,115 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `invite_members`, also returning the metadata of the response.
+    pub async fn invite_members_with_metadata(
+        &self,
+        input: InviteMembersRequest,
+    ) -> Result<(InviteMembersResponse, ResponseMetadata), RusotoError<InviteMembersError>> {
+        let request = self.build_invite_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetThreatIntelSetResponse, _>()?;
+                .deserialize::<InviteMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetThreatIntelSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(InviteMembersError::from_response(response))
         }
     }
 
-    /// <p>Invites other AWS accounts (created as members of the current AWS account by CreateMembers) to enable GuardDuty and allow the current AWS account to view and manage these accounts' GuardDuty findings on their behalf as the master account.</p>
-    async fn invite_members(
+    /// Presigns the request `invite_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_invite_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InviteMembersRequest,
-    ) -> Result<InviteMembersResponse, RusotoError<InviteMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_invite_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_invite_members_request(&self, input: InviteMembersRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/member/invite",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("InviteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_detectors`, also returning the metadata of the response.
+    pub async fn list_detectors_with_metadata(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> Result<(ListDetectorsResponse, ResponseMetadata), RusotoError<ListDetectorsError>> {
+        let request = self.build_list_detectors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<InviteMembersResponse, _>()?;
+                .deserialize::<ListDetectorsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(InviteMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDetectorsError::from_response(response))
         }
     }
 
-    /// <p>Lists detectorIds of all the existing Amazon GuardDuty detector resources.</p>
-    async fn list_detectors(
+    /// Presigns the request `list_detectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_detectors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDetectorsRequest,
-    ) -> Result<ListDetectorsResponse, RusotoError<ListDetectorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_detectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_detectors_request(&self, input: ListDetectorsRequest) -> SignedRequest {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListDetectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5153,34 +6349,58 @@ impl GuardDuty for GuardDutyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_filters`, also returning the metadata of the response.
+    pub async fn list_filters_with_metadata(
+        &self,
+        input: ListFiltersRequest,
+    ) -> Result<(ListFiltersResponse, ResponseMetadata), RusotoError<ListFiltersError>> {
+        let request = self.build_list_filters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDetectorsResponse, _>()?;
+                .deserialize::<ListFiltersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDetectorsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFiltersError::from_response(response))
         }
     }
 
-    /// <p>Returns a paginated list of the current filters.</p>
-    async fn list_filters(
+    /// Presigns the request `list_filters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_filters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFiltersRequest,
-    ) -> Result<ListFiltersResponse, RusotoError<ListFiltersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_filters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_filters_request(&self, input: ListFiltersRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/filter",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFilters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5192,67 +6412,115 @@ impl GuardDuty for GuardDutyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_findings`, also returning the metadata of the response.
+    pub async fn list_findings_with_metadata(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<(ListFindingsResponse, ResponseMetadata), RusotoError<ListFindingsError>> {
+        let request = self.build_list_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFiltersResponse, _>()?;
+                .deserialize::<ListFindingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFiltersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFindingsError::from_response(response))
         }
     }
 
-    /// <p>Lists Amazon GuardDuty findings for the specified detector ID.</p>
-    async fn list_findings(
+    /// Presigns the request `list_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFindingsRequest,
-    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_findings_request(&self, input: ListFindingsRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/findings",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_ip_sets`, also returning the metadata of the response.
+    pub async fn list_ip_sets_with_metadata(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> Result<(ListIPSetsResponse, ResponseMetadata), RusotoError<ListIPSetsError>> {
+        let request = self.build_list_ip_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFindingsResponse, _>()?;
+                .deserialize::<ListIPSetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFindingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListIPSetsError::from_response(response))
         }
     }
 
-    /// <p>Lists the IPSets of the GuardDuty service specified by the detector ID. If you use this operation from a member account, the IPSets returned are the IPSets from the associated master account.</p>
-    async fn list_ip_sets(
+    /// Presigns the request `list_ip_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_ip_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIPSetsRequest,
-    ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_ip_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_ip_sets_request(&self, input: ListIPSetsRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/ipset",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListIPSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5264,31 +6532,56 @@ impl GuardDuty for GuardDutyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_invitations`, also returning the metadata of the response.
+    pub async fn list_invitations_with_metadata(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<(ListInvitationsResponse, ResponseMetadata), RusotoError<ListInvitationsError>>
+    {
+        let request = self.build_list_invitations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListIPSetsResponse, _>()?;
+                .deserialize::<ListInvitationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIPSetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListInvitationsError::from_response(response))
         }
     }
 
-    /// <p>Lists all GuardDuty membership invitations that were sent to the current AWS account.</p>
-    async fn list_invitations(
+    /// Presigns the request `list_invitations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_invitations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInvitationsRequest,
-    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_invitations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_invitations_request(&self, input: ListInvitationsRequest) -> SignedRequest {
         let request_uri = "/invitation";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5300,34 +6593,58 @@ impl GuardDuty for GuardDutyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_members`, also returning the metadata of the response.
+    pub async fn list_members_with_metadata(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<(ListMembersResponse, ResponseMetadata), RusotoError<ListMembersError>> {
+        let request = self.build_list_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInvitationsResponse, _>()?;
+                .deserialize::<ListMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInvitationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListMembersError::from_response(response))
         }
     }
 
-    /// <p>Lists details about all member accounts for the current GuardDuty master account.</p>
-    async fn list_members(
+    /// Presigns the request `list_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMembersRequest,
-    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_members_request(&self, input: ListMembersRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/member",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5342,35 +6659,64 @@ impl GuardDuty for GuardDutyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_publishing_destinations`, also returning the metadata of the response.
+    pub async fn list_publishing_destinations_with_metadata(
+        &self,
+        input: ListPublishingDestinationsRequest,
+    ) -> Result<
+        (ListPublishingDestinationsResponse, ResponseMetadata),
+        RusotoError<ListPublishingDestinationsError>,
+    > {
+        let request = self.build_list_publishing_destinations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMembersResponse, _>()?;
+                .deserialize::<ListPublishingDestinationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPublishingDestinationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of publishing destinations associated with the specified <code>dectectorId</code>.</p>
-    async fn list_publishing_destinations(
+    /// Presigns the request `list_publishing_destinations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_publishing_destinations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPublishingDestinationsRequest,
-    ) -> Result<ListPublishingDestinationsResponse, RusotoError<ListPublishingDestinationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_publishing_destinations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_publishing_destinations_request(
+        &self,
+        input: ListPublishingDestinationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/publishingDestination",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListPublishingDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5382,61 +6728,121 @@ impl GuardDuty for GuardDutyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPublishingDestinationsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPublishingDestinationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists tags for a resource. Tagging is currently supported for detectors, finding filters, IP sets, and Threat Intel sets, with a limit of 50 tags per resource. When invoked, this operation returns all assigned tags for a given resource..</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_threat_intel_sets`, also returning the metadata of the response.
+    pub async fn list_threat_intel_sets_with_metadata(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> Result<
+        (ListThreatIntelSetsResponse, ResponseMetadata),
+        RusotoError<ListThreatIntelSetsError>,
+    > {
+        let request = self.build_list_threat_intel_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<ListThreatIntelSetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListThreatIntelSetsError::from_response(response))
         }
     }
 
-    /// <p>Lists the ThreatIntelSets of the GuardDuty service specified by the detector ID. If you use this operation from a member account, the ThreatIntelSets associated with the master account are returned.</p>
-    async fn list_threat_intel_sets(
+    /// Presigns the request `list_threat_intel_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_threat_intel_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListThreatIntelSetsRequest,
-    ) -> Result<ListThreatIntelSetsResponse, RusotoError<ListThreatIntelSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_threat_intel_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_threat_intel_sets_request(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/threatintelset",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListThreatIntelSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5448,160 +6854,293 @@ impl GuardDuty for GuardDutyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `start_monitoring_members`, also returning the metadata of the response.
+    pub async fn start_monitoring_members_with_metadata(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> Result<
+        (StartMonitoringMembersResponse, ResponseMetadata),
+        RusotoError<StartMonitoringMembersError>,
+    > {
+        let request = self.build_start_monitoring_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListThreatIntelSetsResponse, _>()?;
+                .deserialize::<StartMonitoringMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListThreatIntelSetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartMonitoringMembersError::from_response(response))
         }
     }
 
-    /// <p>Turns on GuardDuty monitoring of the specified member accounts. Use this operation to restart monitoring of accounts that you stopped monitoring with the <code>StopMonitoringMembers</code> operation.</p>
-    async fn start_monitoring_members(
+    /// Presigns the request `start_monitoring_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_monitoring_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartMonitoringMembersRequest,
-    ) -> Result<StartMonitoringMembersResponse, RusotoError<StartMonitoringMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_monitoring_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_monitoring_members_request(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/member/start",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StartMonitoringMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `stop_monitoring_members`, also returning the metadata of the response.
+    pub async fn stop_monitoring_members_with_metadata(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> Result<
+        (StopMonitoringMembersResponse, ResponseMetadata),
+        RusotoError<StopMonitoringMembersError>,
+    > {
+        let request = self.build_stop_monitoring_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartMonitoringMembersResponse, _>()?;
+                .deserialize::<StopMonitoringMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartMonitoringMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopMonitoringMembersError::from_response(response))
         }
     }
 
-    /// <p>Stops GuardDuty monitoring for the specified member accounnts. Use the <code>StartMonitoringMembers</code> to restart monitoring for those accounts.</p>
-    async fn stop_monitoring_members(
+    /// Presigns the request `stop_monitoring_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_monitoring_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopMonitoringMembersRequest,
-    ) -> Result<StopMonitoringMembersResponse, RusotoError<StopMonitoringMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_monitoring_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_monitoring_members_request(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/member/stop",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StopMonitoringMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopMonitoringMembersResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopMonitoringMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds tags to a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `unarchive_findings`, also returning the metadata of the response.
+    pub async fn unarchive_findings_with_metadata(
+        &self,
+        input: UnarchiveFindingsRequest,
+    ) -> Result<(UnarchiveFindingsResponse, ResponseMetadata), RusotoError<UnarchiveFindingsError>>
+    {
+        let request = self.build_unarchive_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UnarchiveFindingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UnarchiveFindingsError::from_response(response))
         }
     }
 
-    /// <p>Unarchives GuardDuty findings specified by the <code>findingIds</code>.</p>
-    async fn unarchive_findings(
+    /// Presigns the request `unarchive_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unarchive_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnarchiveFindingsRequest,
-    ) -> Result<UnarchiveFindingsResponse, RusotoError<UnarchiveFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unarchive_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unarchive_findings_request(&self, input: UnarchiveFindingsRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/findings/unarchive",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UnarchiveFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UnarchiveFindingsResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UnarchiveFindingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5610,58 +7149,105 @@ impl GuardDuty for GuardDutyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_detector`, also returning the metadata of the response.
+    pub async fn update_detector_with_metadata(
+        &self,
+        input: UpdateDetectorRequest,
+    ) -> Result<(UpdateDetectorResponse, ResponseMetadata), RusotoError<UpdateDetectorError>> {
+        let request = self.build_update_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateDetectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDetectorError::from_response(response))
         }
     }
 
-    /// <p>Updates the Amazon GuardDuty detector specified by the detectorId.</p>
-    async fn update_detector(
+    /// Presigns the request `update_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_detector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDetectorRequest,
-    ) -> Result<UpdateDetectorResponse, RusotoError<UpdateDetectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_detector_request(&self, input: UpdateDetectorRequest) -> SignedRequest {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_filter`, also returning the metadata of the response.
+    pub async fn update_filter_with_metadata(
+        &self,
+        input: UpdateFilterRequest,
+    ) -> Result<(UpdateFilterResponse, ResponseMetadata), RusotoError<UpdateFilterError>> {
+        let request = self.build_update_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDetectorResponse, _>()?;
+                .deserialize::<UpdateFilterResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDetectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFilterError::from_response(response))
         }
     }
 
-    /// <p>Updates the filter specified by the filter name.</p>
-    async fn update_filter(
+    /// Presigns the request `update_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFilterRequest,
-    ) -> Result<UpdateFilterResponse, RusotoError<UpdateFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_filter_request(&self, input: UpdateFilterRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/filter/{filter_name}",
             detector_id = input.detector_id,
@@ -5669,66 +7255,120 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_findings_feedback`, also returning the metadata of the response.
+    pub async fn update_findings_feedback_with_metadata(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> Result<
+        (UpdateFindingsFeedbackResponse, ResponseMetadata),
+        RusotoError<UpdateFindingsFeedbackError>,
+    > {
+        let request = self.build_update_findings_feedback_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFilterResponse, _>()?;
+                .deserialize::<UpdateFindingsFeedbackResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFilterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFindingsFeedbackError::from_response(response))
         }
     }
 
-    /// <p>Marks the specified GuardDuty findings as useful or not useful.</p>
-    async fn update_findings_feedback(
+    /// Presigns the request `update_findings_feedback` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_findings_feedback(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFindingsFeedbackRequest,
-    ) -> Result<UpdateFindingsFeedbackResponse, RusotoError<UpdateFindingsFeedbackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_findings_feedback_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_findings_feedback_request(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/findings/feedback",
             detector_id = input.detector_id
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFindingsFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_ip_set`, also returning the metadata of the response.
+    pub async fn update_ip_set_with_metadata(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> Result<(UpdateIPSetResponse, ResponseMetadata), RusotoError<UpdateIPSetError>> {
+        let request = self.build_update_ip_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFindingsFeedbackResponse, _>()?;
+                .deserialize::<UpdateIPSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFindingsFeedbackError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateIPSetError::from_response(response))
         }
     }
 
-    /// <p>Updates the IPSet specified by the IPSet ID.</p>
-    async fn update_ip_set(
+    /// Presigns the request `update_ip_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_ip_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateIPSetRequest,
-    ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_ip_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_ip_set_request(&self, input: UpdateIPSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/ipset/{ip_set_id}",
             detector_id = input.detector_id,
@@ -5736,34 +7376,63 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_publishing_destination`, also returning the metadata of the response.
+    pub async fn update_publishing_destination_with_metadata(
+        &self,
+        input: UpdatePublishingDestinationRequest,
+    ) -> Result<
+        (UpdatePublishingDestinationResponse, ResponseMetadata),
+        RusotoError<UpdatePublishingDestinationError>,
+    > {
+        let request = self.build_update_publishing_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateIPSetResponse, _>()?;
+                .deserialize::<UpdatePublishingDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIPSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdatePublishingDestinationError::from_response(response))
         }
     }
 
-    /// <p>Updates information about the publishing destination specified by the <code>destinationId</code>.</p>
-    async fn update_publishing_destination(
+    /// Presigns the request `update_publishing_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_publishing_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePublishingDestinationRequest,
-    ) -> Result<UpdatePublishingDestinationResponse, RusotoError<UpdatePublishingDestinationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_publishing_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_publishing_destination_request(
+        &self,
+        input: UpdatePublishingDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/publishingDestination/{destination_id}",
             destination_id = input.destination_id,
@@ -5771,33 +7440,63 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdatePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_threat_intel_set`, also returning the metadata of the response.
+    pub async fn update_threat_intel_set_with_metadata(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> Result<
+        (UpdateThreatIntelSetResponse, ResponseMetadata),
+        RusotoError<UpdateThreatIntelSetError>,
+    > {
+        let request = self.build_update_threat_intel_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePublishingDestinationResponse, _>()?;
+                .deserialize::<UpdateThreatIntelSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePublishingDestinationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateThreatIntelSetError::from_response(response))
         }
     }
 
-    /// <p>Updates the ThreatIntelSet specified by ThreatIntelSet ID.</p>
-    async fn update_threat_intel_set(
+    /// Presigns the request `update_threat_intel_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_threat_intel_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateThreatIntelSetRequest,
-    ) -> Result<UpdateThreatIntelSetResponse, RusotoError<UpdateThreatIntelSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_threat_intel_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_threat_intel_set_request(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector/{detector_id}/threatintelset/{threat_intel_set_id}",
             detector_id = input.detector_id,
@@ -5805,25 +7504,765 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListDetectors` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_detectors_pages(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDetectorsResponse, RusotoError<ListDetectorsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input| async move { client.list_detectors(input).await },
+            |input: &ListDetectorsRequest, output: &ListDetectorsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDetectorsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DetectorIds` of every page of `ListDetectors` results for `input`, as `list_detectors_pages` requests them.
+    pub fn list_detectors_stream(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListDetectorsError>>> + Send {
+        rusoto_core::pagination::items(self.list_detectors_pages(input), |page| page.detector_ids)
+    }
+
+    /// The pages of `ListFilters` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_filters_pages(
+        &self,
+        input: ListFiltersRequest,
+    ) -> impl futures::Stream<Item = Result<ListFiltersResponse, RusotoError<ListFiltersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input| async move { client.list_filters(input).await },
+            |input: &ListFiltersRequest, output: &ListFiltersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFiltersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FilterNames` of every page of `ListFilters` results for `input`, as `list_filters_pages` requests them.
+    pub fn list_filters_stream(
+        &self,
+        input: ListFiltersRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListFiltersError>>> + Send {
+        rusoto_core::pagination::items(self.list_filters_pages(input), |page| page.filter_names)
+    }
+
+    /// The pages of `ListFindings` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_findings_pages(
+        &self,
+        input: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFindingsResponse, RusotoError<ListFindingsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input| async move { client.list_findings(input).await },
+            |input: &ListFindingsRequest, output: &ListFindingsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFindingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FindingIds` of every page of `ListFindings` results for `input`, as `list_findings_pages` requests them.
+    pub fn list_findings_stream(
+        &self,
+        input: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListFindingsError>>> + Send {
+        rusoto_core::pagination::items(self.list_findings_pages(input), |page| page.finding_ids)
+    }
+
+    /// The pages of `ListIPSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_ip_sets_pages(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListIPSetsResponse, RusotoError<ListIPSetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input| async move { client.list_ip_sets(input).await },
+            |input: &ListIPSetsRequest, output: &ListIPSetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIPSetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `IpSetIds` of every page of `ListIPSets` results for `input`, as `list_ip_sets_pages` requests them.
+    pub fn list_ip_sets_stream(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListIPSetsError>>> + Send {
+        rusoto_core::pagination::items(self.list_ip_sets_pages(input), |page| page.ip_set_ids)
+    }
+
+    /// The pages of `ListInvitations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_invitations_pages(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListInvitationsResponse, RusotoError<ListInvitationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input| async move { client.list_invitations(input).await },
+            |input: &ListInvitationsRequest, output: &ListInvitationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListInvitationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Invitations` of every page of `ListInvitations` results for `input`, as `list_invitations_pages` requests them.
+    pub fn list_invitations_stream(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> impl futures::Stream<Item = Result<Invitation, RusotoError<ListInvitationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_invitations_pages(input), |page| {
+            page.invitations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListMembers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_members_pages(
+        &self,
+        input: ListMembersRequest,
+    ) -> impl futures::Stream<Item = Result<ListMembersResponse, RusotoError<ListMembersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input| async move { client.list_members(input).await },
+            |input: &ListMembersRequest, output: &ListMembersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMembersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Members` of every page of `ListMembers` results for `input`, as `list_members_pages` requests them.
+    pub fn list_members_stream(
+        &self,
+        input: ListMembersRequest,
+    ) -> impl futures::Stream<Item = Result<Member, RusotoError<ListMembersError>>> + Send {
+        rusoto_core::pagination::items(self.list_members_pages(input), |page| {
+            page.members.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListThreatIntelSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_threat_intel_sets_pages(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListThreatIntelSetsResponse, RusotoError<ListThreatIntelSetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GuardDutyClient, input| async move { client.list_threat_intel_sets(input).await },
+            |input: &ListThreatIntelSetsRequest, output: &ListThreatIntelSetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListThreatIntelSetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ThreatIntelSetIds` of every page of `ListThreatIntelSets` results for `input`, as `list_threat_intel_sets_pages` requests them.
+    pub fn list_threat_intel_sets_stream(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListThreatIntelSetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_threat_intel_sets_pages(input), |page| {
+            page.threat_intel_set_ids
+        })
+    }
+}
+
+#[async_trait]
+impl GuardDuty for GuardDutyClient {
+    /// <p>Accepts the invitation to be monitored by a master GuardDuty account.</p>
+    async fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> Result<AcceptInvitationResponse, RusotoError<AcceptInvitationError>> {
+        self.accept_invitation_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateThreatIntelSetResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateThreatIntelSetError::from_response(response))
-        }
+    /// <p><p>Archives GuardDuty findings specified by the list of finding IDs.</p> <note> <p>Only the master account can archive findings. Member accounts do not have permission to archive findings from their accounts.</p> </note></p>
+    async fn archive_findings(
+        &self,
+        input: ArchiveFindingsRequest,
+    ) -> Result<ArchiveFindingsResponse, RusotoError<ArchiveFindingsError>> {
+        self.archive_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a single Amazon GuardDuty detector. A detector is a resource that represents the GuardDuty service. To start using GuardDuty, you must create a detector in each region that you enable the service. You can have only one detector per account per region.</p>
+    async fn create_detector(
+        &self,
+        input: CreateDetectorRequest,
+    ) -> Result<CreateDetectorResponse, RusotoError<CreateDetectorError>> {
+        self.create_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a filter using the specified finding criteria.</p>
+    async fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> Result<CreateFilterResponse, RusotoError<CreateFilterError>> {
+        self.create_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new IPSet, called Trusted IP list in the consoler user interface. An IPSet is a list IP addresses trusted for secure communication with AWS infrastructure and applications. GuardDuty does not generate findings for IP addresses included in IPSets. Only users from the master account can use this operation.</p>
+    async fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+        self.create_ip_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates member accounts of the current AWS account by specifying a list of AWS account IDs. The current AWS account can then invite these members to manage GuardDuty in their accounts.</p>
+    async fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+        self.create_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a publishing destination to send findings to. The resource to send findings to must exist before you use this operation.</p>
+    async fn create_publishing_destination(
+        &self,
+        input: CreatePublishingDestinationRequest,
+    ) -> Result<CreatePublishingDestinationResponse, RusotoError<CreatePublishingDestinationError>>
+    {
+        self.create_publishing_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates example findings of types specified by the list of finding types. If 'NULL' is specified for <code>findingTypes</code>, the API generates example findings of all supported finding types.</p>
+    async fn create_sample_findings(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> Result<CreateSampleFindingsResponse, RusotoError<CreateSampleFindingsError>> {
+        self.create_sample_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new ThreatIntelSet. ThreatIntelSets consist of known malicious IP addresses. GuardDuty generates findings based on ThreatIntelSets. Only users of the master account can use this operation.</p>
+    async fn create_threat_intel_set(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> Result<CreateThreatIntelSetResponse, RusotoError<CreateThreatIntelSetError>> {
+        self.create_threat_intel_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Declines invitations sent to the current member account by AWS account specified by their account IDs.</p>
+    async fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> Result<DeclineInvitationsResponse, RusotoError<DeclineInvitationsError>> {
+        self.decline_invitations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Amazon GuardDuty detector specified by the detector ID.</p>
+    async fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> Result<DeleteDetectorResponse, RusotoError<DeleteDetectorError>> {
+        self.delete_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the filter specified by the filter name.</p>
+    async fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> Result<DeleteFilterResponse, RusotoError<DeleteFilterError>> {
+        self.delete_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the IPSet specified by the <code>ipSetId</code>. IPSets are called Trusted IP lists in the console user interface.</p>
+    async fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
+        self.delete_ip_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes invitations sent to the current member account by AWS accounts specified by their account IDs.</p>
+    async fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> Result<DeleteInvitationsResponse, RusotoError<DeleteInvitationsError>> {
+        self.delete_invitations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
+    async fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+        self.delete_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the publishing definition with the specified <code>destinationId</code>.</p>
+    async fn delete_publishing_destination(
+        &self,
+        input: DeletePublishingDestinationRequest,
+    ) -> Result<DeletePublishingDestinationResponse, RusotoError<DeletePublishingDestinationError>>
+    {
+        self.delete_publishing_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes ThreatIntelSet specified by the ThreatIntelSet ID.</p>
+    async fn delete_threat_intel_set(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> Result<DeleteThreatIntelSetResponse, RusotoError<DeleteThreatIntelSetError>> {
+        self.delete_threat_intel_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the publishing destination specified by the provided <code>destinationId</code>.</p>
+    async fn describe_publishing_destination(
+        &self,
+        input: DescribePublishingDestinationRequest,
+    ) -> Result<
+        DescribePublishingDestinationResponse,
+        RusotoError<DescribePublishingDestinationError>,
+    > {
+        self.describe_publishing_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the current GuardDuty member account from its master account.</p>
+    async fn disassociate_from_master_account(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> Result<
+        DisassociateFromMasterAccountResponse,
+        RusotoError<DisassociateFromMasterAccountError>,
+    > {
+        self.disassociate_from_master_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
+    async fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> Result<DisassociateMembersResponse, RusotoError<DisassociateMembersError>> {
+        self.disassociate_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves an Amazon GuardDuty detector specified by the detectorId.</p>
+    async fn get_detector(
+        &self,
+        input: GetDetectorRequest,
+    ) -> Result<GetDetectorResponse, RusotoError<GetDetectorError>> {
+        self.get_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details of the filter specified by the filter name.</p>
+    async fn get_filter(
+        &self,
+        input: GetFilterRequest,
+    ) -> Result<GetFilterResponse, RusotoError<GetFilterError>> {
+        self.get_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes Amazon GuardDuty findings specified by finding IDs.</p>
+    async fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> Result<GetFindingsResponse, RusotoError<GetFindingsError>> {
+        self.get_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists Amazon GuardDuty findings' statistics for the specified detector ID.</p>
+    async fn get_findings_statistics(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> Result<GetFindingsStatisticsResponse, RusotoError<GetFindingsStatisticsError>> {
+        self.get_findings_statistics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the IPSet specified by the <code>ipSetId</code>.</p>
+    async fn get_ip_set(
+        &self,
+        input: GetIPSetRequest,
+    ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
+        self.get_ip_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the count of all GuardDuty membership invitations that were sent to the current member account except the currently accepted invitation.</p>
+    async fn get_invitations_count(
+        &self,
+    ) -> Result<GetInvitationsCountResponse, RusotoError<GetInvitationsCountError>> {
+        self.get_invitations_count_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides the details for the GuardDuty master account associated with the current GuardDuty member account.</p>
+    async fn get_master_account(
+        &self,
+        input: GetMasterAccountRequest,
+    ) -> Result<GetMasterAccountResponse, RusotoError<GetMasterAccountError>> {
+        self.get_master_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
+    async fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+        self.get_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the ThreatIntelSet that is specified by the ThreatIntelSet ID.</p>
+    async fn get_threat_intel_set(
+        &self,
+        input: GetThreatIntelSetRequest,
+    ) -> Result<GetThreatIntelSetResponse, RusotoError<GetThreatIntelSetError>> {
+        self.get_threat_intel_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Invites other AWS accounts (created as members of the current AWS account by CreateMembers) to enable GuardDuty and allow the current AWS account to view and manage these accounts' GuardDuty findings on their behalf as the master account.</p>
+    async fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> Result<InviteMembersResponse, RusotoError<InviteMembersError>> {
+        self.invite_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists detectorIds of all the existing Amazon GuardDuty detector resources.</p>
+    async fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> Result<ListDetectorsResponse, RusotoError<ListDetectorsError>> {
+        self.list_detectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a paginated list of the current filters.</p>
+    async fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> Result<ListFiltersResponse, RusotoError<ListFiltersError>> {
+        self.list_filters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists Amazon GuardDuty findings for the specified detector ID.</p>
+    async fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+        self.list_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the IPSets of the GuardDuty service specified by the detector ID. If you use this operation from a member account, the IPSets returned are the IPSets from the associated master account.</p>
+    async fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
+        self.list_ip_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all GuardDuty membership invitations that were sent to the current AWS account.</p>
+    async fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+        self.list_invitations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists details about all member accounts for the current GuardDuty master account.</p>
+    async fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+        self.list_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of publishing destinations associated with the specified <code>dectectorId</code>.</p>
+    async fn list_publishing_destinations(
+        &self,
+        input: ListPublishingDestinationsRequest,
+    ) -> Result<ListPublishingDestinationsResponse, RusotoError<ListPublishingDestinationsError>>
+    {
+        self.list_publishing_destinations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists tags for a resource. Tagging is currently supported for detectors, finding filters, IP sets, and Threat Intel sets, with a limit of 50 tags per resource. When invoked, this operation returns all assigned tags for a given resource..</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the ThreatIntelSets of the GuardDuty service specified by the detector ID. If you use this operation from a member account, the ThreatIntelSets associated with the master account are returned.</p>
+    async fn list_threat_intel_sets(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> Result<ListThreatIntelSetsResponse, RusotoError<ListThreatIntelSetsError>> {
+        self.list_threat_intel_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Turns on GuardDuty monitoring of the specified member accounts. Use this operation to restart monitoring of accounts that you stopped monitoring with the <code>StopMonitoringMembers</code> operation.</p>
+    async fn start_monitoring_members(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> Result<StartMonitoringMembersResponse, RusotoError<StartMonitoringMembersError>> {
+        self.start_monitoring_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops GuardDuty monitoring for the specified member accounnts. Use the <code>StartMonitoringMembers</code> to restart monitoring for those accounts.</p>
+    async fn stop_monitoring_members(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> Result<StopMonitoringMembersResponse, RusotoError<StopMonitoringMembersError>> {
+        self.stop_monitoring_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tags to a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Unarchives GuardDuty findings specified by the <code>findingIds</code>.</p>
+    async fn unarchive_findings(
+        &self,
+        input: UnarchiveFindingsRequest,
+    ) -> Result<UnarchiveFindingsResponse, RusotoError<UnarchiveFindingsError>> {
+        self.unarchive_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the Amazon GuardDuty detector specified by the detectorId.</p>
+    async fn update_detector(
+        &self,
+        input: UpdateDetectorRequest,
+    ) -> Result<UpdateDetectorResponse, RusotoError<UpdateDetectorError>> {
+        self.update_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the filter specified by the filter name.</p>
+    async fn update_filter(
+        &self,
+        input: UpdateFilterRequest,
+    ) -> Result<UpdateFilterResponse, RusotoError<UpdateFilterError>> {
+        self.update_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Marks the specified GuardDuty findings as useful or not useful.</p>
+    async fn update_findings_feedback(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> Result<UpdateFindingsFeedbackResponse, RusotoError<UpdateFindingsFeedbackError>> {
+        self.update_findings_feedback_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the IPSet specified by the IPSet ID.</p>
+    async fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
+        self.update_ip_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates information about the publishing destination specified by the <code>destinationId</code>.</p>
+    async fn update_publishing_destination(
+        &self,
+        input: UpdatePublishingDestinationRequest,
+    ) -> Result<UpdatePublishingDestinationResponse, RusotoError<UpdatePublishingDestinationError>>
+    {
+        self.update_publishing_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the ThreatIntelSet specified by ThreatIntelSet ID.</p>
+    async fn update_threat_intel_set(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> Result<UpdateThreatIntelSetResponse, RusotoError<UpdateThreatIntelSetError>> {
+        self.update_threat_intel_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -769,9 +769,12 @@ pub struct OrganizationEventFilter {
 
 /// Errors returned by DescribeAffectedAccountsForOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAffectedAccountsForOrganizationError {
     /// <p>The specified pagination token (<code>nextToken</code>) is not valid.</p>
     InvalidPaginationToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAffectedAccountsForOrganizationError {
@@ -781,14 +784,20 @@ impl DescribeAffectedAccountsForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAffectedAccountsForOrganizationError::InvalidPaginationToken(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAffectedAccountsForOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -801,17 +810,23 @@ impl fmt::Display for DescribeAffectedAccountsForOrganizationError {
             DescribeAffectedAccountsForOrganizationError::InvalidPaginationToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAffectedAccountsForOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAffectedAccountsForOrganizationError {}
 /// Errors returned by DescribeAffectedEntities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAffectedEntitiesError {
     /// <p>The specified pagination token (<code>nextToken</code>) is not valid.</p>
     InvalidPaginationToken(String),
     /// <p>The specified locale is not supported.</p>
     UnsupportedLocale(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAffectedEntitiesError {
@@ -819,17 +834,19 @@ impl DescribeAffectedEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAffectedEntitiesError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeAffectedEntitiesError::UnsupportedLocale(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAffectedEntitiesError::UnsupportedLocale(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAffectedEntitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -843,17 +860,21 @@ impl fmt::Display for DescribeAffectedEntitiesError {
                 write!(f, "{}", cause)
             }
             DescribeAffectedEntitiesError::UnsupportedLocale(ref cause) => write!(f, "{}", cause),
+            DescribeAffectedEntitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAffectedEntitiesError {}
 /// Errors returned by DescribeAffectedEntitiesForOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAffectedEntitiesForOrganizationError {
     /// <p>The specified pagination token (<code>nextToken</code>) is not valid.</p>
     InvalidPaginationToken(String),
     /// <p>The specified locale is not supported.</p>
     UnsupportedLocale(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAffectedEntitiesForOrganizationError {
@@ -863,19 +884,26 @@ impl DescribeAffectedEntitiesForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAffectedEntitiesForOrganizationError::InvalidPaginationToken(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAffectedEntitiesForOrganizationError::UnsupportedLocale(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAffectedEntitiesForOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -891,20 +919,27 @@ impl fmt::Display for DescribeAffectedEntitiesForOrganizationError {
             DescribeAffectedEntitiesForOrganizationError::UnsupportedLocale(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAffectedEntitiesForOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAffectedEntitiesForOrganizationError {}
 /// Errors returned by DescribeEntityAggregates
 #[derive(Debug, PartialEq)]
-pub enum DescribeEntityAggregatesError {}
+#[non_exhaustive]
+pub enum DescribeEntityAggregatesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEntityAggregatesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEntityAggregatesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEntityAggregatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -913,15 +948,20 @@ impl DescribeEntityAggregatesError {
 impl fmt::Display for DescribeEntityAggregatesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEntityAggregatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEntityAggregatesError {}
 /// Errors returned by DescribeEventAggregates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventAggregatesError {
     /// <p>The specified pagination token (<code>nextToken</code>) is not valid.</p>
     InvalidPaginationToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventAggregatesError {
@@ -929,12 +969,13 @@ impl DescribeEventAggregatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEventAggregatesError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventAggregatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -947,15 +988,19 @@ impl fmt::Display for DescribeEventAggregatesError {
             DescribeEventAggregatesError::InvalidPaginationToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEventAggregatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventAggregatesError {}
 /// Errors returned by DescribeEventDetails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventDetailsError {
     /// <p>The specified locale is not supported.</p>
     UnsupportedLocale(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventDetailsError {
@@ -963,12 +1008,13 @@ impl DescribeEventDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeEventDetailsError::UnsupportedLocale(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventDetailsError::UnsupportedLocale(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventDetailsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -979,15 +1025,19 @@ impl fmt::Display for DescribeEventDetailsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeEventDetailsError::UnsupportedLocale(ref cause) => write!(f, "{}", cause),
+            DescribeEventDetailsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventDetailsError {}
 /// Errors returned by DescribeEventDetailsForOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventDetailsForOrganizationError {
     /// <p>The specified locale is not supported.</p>
     UnsupportedLocale(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventDetailsForOrganizationError {
@@ -997,12 +1047,18 @@ impl DescribeEventDetailsForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEventDetailsForOrganizationError::UnsupportedLocale(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeEventDetailsForOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1015,17 +1071,23 @@ impl fmt::Display for DescribeEventDetailsForOrganizationError {
             DescribeEventDetailsForOrganizationError::UnsupportedLocale(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEventDetailsForOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEventDetailsForOrganizationError {}
 /// Errors returned by DescribeEventTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventTypesError {
     /// <p>The specified pagination token (<code>nextToken</code>) is not valid.</p>
     InvalidPaginationToken(String),
     /// <p>The specified locale is not supported.</p>
     UnsupportedLocale(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventTypesError {
@@ -1033,17 +1095,19 @@ impl DescribeEventTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(DescribeEventTypesError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventTypesError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeEventTypesError::UnsupportedLocale(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventTypesError::UnsupportedLocale(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1055,17 +1119,21 @@ impl fmt::Display for DescribeEventTypesError {
         match *self {
             DescribeEventTypesError::InvalidPaginationToken(ref cause) => write!(f, "{}", cause),
             DescribeEventTypesError::UnsupportedLocale(ref cause) => write!(f, "{}", cause),
+            DescribeEventTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventTypesError {}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventsError {
     /// <p>The specified pagination token (<code>nextToken</code>) is not valid.</p>
     InvalidPaginationToken(String),
     /// <p>The specified locale is not supported.</p>
     UnsupportedLocale(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventsError {
@@ -1073,15 +1141,19 @@ impl DescribeEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventsError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeEventsError::UnsupportedLocale(err.msg))
+                    return RusotoError::service(
+                        DescribeEventsError::UnsupportedLocale(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1093,17 +1165,21 @@ impl fmt::Display for DescribeEventsError {
         match *self {
             DescribeEventsError::InvalidPaginationToken(ref cause) => write!(f, "{}", cause),
             DescribeEventsError::UnsupportedLocale(ref cause) => write!(f, "{}", cause),
+            DescribeEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventsError {}
 /// Errors returned by DescribeEventsForOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventsForOrganizationError {
     /// <p>The specified pagination token (<code>nextToken</code>) is not valid.</p>
     InvalidPaginationToken(String),
     /// <p>The specified locale is not supported.</p>
     UnsupportedLocale(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventsForOrganizationError {
@@ -1113,17 +1189,24 @@ impl DescribeEventsForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEventsForOrganizationError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEventsForOrganizationError::UnsupportedLocale(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeEventsForOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1139,13 +1222,20 @@ impl fmt::Display for DescribeEventsForOrganizationError {
             DescribeEventsForOrganizationError::UnsupportedLocale(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEventsForOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEventsForOrganizationError {}
 /// Errors returned by DescribeHealthServiceStatusForOrganization
 #[derive(Debug, PartialEq)]
-pub enum DescribeHealthServiceStatusForOrganizationError {}
+#[non_exhaustive]
+pub enum DescribeHealthServiceStatusForOrganizationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeHealthServiceStatusForOrganizationError {
     pub fn from_response(
@@ -1154,7 +1244,12 @@ impl DescribeHealthServiceStatusForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeHealthServiceStatusForOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1163,15 +1258,22 @@ impl DescribeHealthServiceStatusForOrganizationError {
 impl fmt::Display for DescribeHealthServiceStatusForOrganizationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeHealthServiceStatusForOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeHealthServiceStatusForOrganizationError {}
 /// Errors returned by DisableHealthServiceAccessForOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableHealthServiceAccessForOrganizationError {
     /// <p> <a>EnableHealthServiceAccessForOrganization</a> is already in progress. Wait for the action to complete before trying again. To get the current status, use the <a>DescribeHealthServiceStatusForOrganization</a> operation.</p>
     ConcurrentModification(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableHealthServiceAccessForOrganizationError {
@@ -1181,14 +1283,20 @@ impl DisableHealthServiceAccessForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableHealthServiceAccessForOrganizationError::ConcurrentModification(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisableHealthServiceAccessForOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1201,15 +1309,21 @@ impl fmt::Display for DisableHealthServiceAccessForOrganizationError {
             DisableHealthServiceAccessForOrganizationError::ConcurrentModification(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisableHealthServiceAccessForOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisableHealthServiceAccessForOrganizationError {}
 /// Errors returned by EnableHealthServiceAccessForOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableHealthServiceAccessForOrganizationError {
     /// <p> <a>EnableHealthServiceAccessForOrganization</a> is already in progress. Wait for the action to complete before trying again. To get the current status, use the <a>DescribeHealthServiceStatusForOrganization</a> operation.</p>
     ConcurrentModification(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableHealthServiceAccessForOrganizationError {
@@ -1219,14 +1333,20 @@ impl EnableHealthServiceAccessForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableHealthServiceAccessForOrganizationError::ConcurrentModification(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        EnableHealthServiceAccessForOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1239,6 +1359,9 @@ impl fmt::Display for EnableHealthServiceAccessForOrganizationError {
             EnableHealthServiceAccessForOrganizationError::ConcurrentModification(ref cause) => {
                 write!(f, "{}", cause)
             }
+            EnableHealthServiceAccessForOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -1347,6 +1470,7 @@ impl AWSHealthClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AWSHealthClient {
         AWSHealthClient {
             client: Client::shared(),
@@ -1372,19 +1496,78 @@ impl AWSHealthClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AWSHealthClient {
         AWSHealthClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AWSHealthClient> {
+        rusoto_core::ClientBuilder::new(AWSHealthClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AWSHealthClient {
+        AWSHealthClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AWSHealth for AWSHealthClient {
-    /// <p>Returns a list of accounts in the organization from AWS Organizations that are affected by the provided event.</p> <p>Before you can call this operation, you must first enable AWS Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account.</p>
-    async fn describe_affected_accounts_for_organization(
+impl AWSHealthClient {
+    /// Calls `describe_affected_accounts_for_organization`, also returning the metadata of the response.
+    pub async fn describe_affected_accounts_for_organization_with_metadata(
         &self,
         input: DescribeAffectedAccountsForOrganizationRequest,
     ) -> Result<
-        DescribeAffectedAccountsForOrganizationResponse,
+        (
+            DescribeAffectedAccountsForOrganizationResponse,
+            ResponseMetadata,
+        ),
         RusotoError<DescribeAffectedAccountsForOrganizationError>,
     > {
+        let request = self.build_describe_affected_accounts_for_organization_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAffectedAccountsForOrganizationResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAffectedAccountsForOrganizationError::from_response(
+                response,
+            ))
+        }
+    }
+
+    /// Presigns the request `describe_affected_accounts_for_organization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_affected_accounts_for_organization(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAffectedAccountsForOrganizationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_affected_accounts_for_organization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_affected_accounts_for_organization_request(
+        &self,
+        input: DescribeAffectedAccountsForOrganizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeAffectedAccountsForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1394,30 +1577,58 @@ impl AWSHealth for AWSHealthClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_affected_entities`, also returning the metadata of the response.
+    pub async fn describe_affected_entities_with_metadata(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> Result<
+        (DescribeAffectedEntitiesResponse, ResponseMetadata),
+        RusotoError<DescribeAffectedEntitiesError>,
+    > {
+        let request = self.build_describe_affected_entities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAffectedAccountsForOrganizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAffectedEntitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAffectedAccountsForOrganizationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAffectedEntitiesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of entities that have been affected by the specified events, based on the specified filter criteria. Entities can refer to individual customer resources, groups of customer resources, or any other construct, depending on the AWS service. Events that have impact beyond that of the affected entities, or where the extent of impact is unknown, include at least one entity indicating this.</p> <p>At least one event ARN is required. Results are sorted by the <code>lastUpdatedTime</code> of the entity, starting with the most recent.</p>
-    async fn describe_affected_entities(
+    /// Presigns the request `describe_affected_entities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_affected_entities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAffectedEntitiesRequest,
-    ) -> Result<DescribeAffectedEntitiesResponse, RusotoError<DescribeAffectedEntitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_affected_entities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_affected_entities_request(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeAffectedEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1427,31 +1638,63 @@ impl AWSHealth for AWSHealthClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_affected_entities_for_organization`, also returning the metadata of the response.
+    pub async fn describe_affected_entities_for_organization_with_metadata(
+        &self,
+        input: DescribeAffectedEntitiesForOrganizationRequest,
+    ) -> Result<
+        (
+            DescribeAffectedEntitiesForOrganizationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeAffectedEntitiesForOrganizationError>,
+    > {
+        let request = self.build_describe_affected_entities_for_organization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAffectedEntitiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAffectedEntitiesForOrganizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAffectedEntitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAffectedEntitiesForOrganizationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns a list of entities that have been affected by one or more events for one or more accounts in your organization in AWS Organizations, based on the filter criteria. Entities can refer to individual customer resources, groups of customer resources, or any other construct, depending on the AWS service.</p> <p>At least one event ARN and account ID are required. Results are sorted by the <code>lastUpdatedTime</code> of the entity, starting with the most recent.</p> <p>Before you can call this operation, you must first enable AWS Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account. </p>
-    async fn describe_affected_entities_for_organization(
+    /// Presigns the request `describe_affected_entities_for_organization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_affected_entities_for_organization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAffectedEntitiesForOrganizationRequest,
-    ) -> Result<
-        DescribeAffectedEntitiesForOrganizationResponse,
-        RusotoError<DescribeAffectedEntitiesForOrganizationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_affected_entities_for_organization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_affected_entities_for_organization_request(
+        &self,
+        input: DescribeAffectedEntitiesForOrganizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeAffectedEntitiesForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1461,30 +1704,58 @@ impl AWSHealth for AWSHealthClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_entity_aggregates`, also returning the metadata of the response.
+    pub async fn describe_entity_aggregates_with_metadata(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> Result<
+        (DescribeEntityAggregatesResponse, ResponseMetadata),
+        RusotoError<DescribeEntityAggregatesError>,
+    > {
+        let request = self.build_describe_entity_aggregates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAffectedEntitiesForOrganizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEntityAggregatesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAffectedEntitiesForOrganizationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEntityAggregatesError::from_response(response))
         }
     }
 
-    /// <p>Returns the number of entities that are affected by each of the specified events. If no events are specified, the counts of all affected entities are returned.</p>
-    async fn describe_entity_aggregates(
+    /// Presigns the request `describe_entity_aggregates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_entity_aggregates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEntityAggregatesRequest,
-    ) -> Result<DescribeEntityAggregatesResponse, RusotoError<DescribeEntityAggregatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_entity_aggregates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_entity_aggregates_request(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEntityAggregates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1494,87 +1765,179 @@ impl AWSHealth for AWSHealthClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_aggregates`, also returning the metadata of the response.
+    pub async fn describe_event_aggregates_with_metadata(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> Result<
+        (DescribeEventAggregatesResponse, ResponseMetadata),
+        RusotoError<DescribeEventAggregatesError>,
+    > {
+        let request = self.build_describe_event_aggregates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEntityAggregatesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventAggregatesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEntityAggregatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventAggregatesError::from_response(response))
         }
     }
 
-    /// <p>Returns the number of events of each event type (issue, scheduled change, and account notification). If no filter is specified, the counts of all events in each category are returned.</p>
-    async fn describe_event_aggregates(
+    /// Presigns the request `describe_event_aggregates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_aggregates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventAggregatesRequest,
-    ) -> Result<DescribeEventAggregatesResponse, RusotoError<DescribeEventAggregatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_aggregates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_aggregates_request(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventAggregates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventAggregates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_details`, also returning the metadata of the response.
+    pub async fn describe_event_details_with_metadata(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> Result<
+        (DescribeEventDetailsResponse, ResponseMetadata),
+        RusotoError<DescribeEventDetailsError>,
+    > {
+        let request = self.build_describe_event_details_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventAggregatesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventDetailsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventAggregatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventDetailsError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed information about one or more specified events. Information includes standard event data (region, service, and so on, as returned by <a>DescribeEvents</a>), a detailed event description, and possible additional metadata that depends upon the nature of the event. Affected entities are not included; to retrieve those, use the <a>DescribeAffectedEntities</a> operation.</p> <p>If a specified event cannot be retrieved, an error message is returned for that event.</p>
-    async fn describe_event_details(
+    /// Presigns the request `describe_event_details` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_details(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventDetailsRequest,
-    ) -> Result<DescribeEventDetailsResponse, RusotoError<DescribeEventDetailsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_details_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_details_request(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_details_for_organization`, also returning the metadata of the response.
+    pub async fn describe_event_details_for_organization_with_metadata(
+        &self,
+        input: DescribeEventDetailsForOrganizationRequest,
+    ) -> Result<
+        (
+            DescribeEventDetailsForOrganizationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeEventDetailsForOrganizationError>,
+    > {
+        let request = self.build_describe_event_details_for_organization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventDetailsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventDetailsForOrganizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventDetailsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventDetailsForOrganizationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns detailed information about one or more specified events for one or more accounts in your organization. Information includes standard event data (Region, service, and so on, as returned by <a>DescribeEventsForOrganization</a>, a detailed event description, and possible additional metadata that depends upon the nature of the event. Affected entities are not included; to retrieve those, use the <a>DescribeAffectedEntitiesForOrganization</a> operation.</p> <p>Before you can call this operation, you must first enable AWS Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account.</p>
-    async fn describe_event_details_for_organization(
+    /// Presigns the request `describe_event_details_for_organization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_details_for_organization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventDetailsForOrganizationRequest,
-    ) -> Result<
-        DescribeEventDetailsForOrganizationResponse,
-        RusotoError<DescribeEventDetailsForOrganizationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_details_for_organization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_details_for_organization_request(
+        &self,
+        input: DescribeEventDetailsForOrganizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventDetailsForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1584,88 +1947,166 @@ impl AWSHealth for AWSHealthClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_types`, also returning the metadata of the response.
+    pub async fn describe_event_types_with_metadata(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> Result<(DescribeEventTypesResponse, ResponseMetadata), RusotoError<DescribeEventTypesError>>
+    {
+        let request = self.build_describe_event_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventDetailsForOrganizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventTypesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventDetailsForOrganizationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventTypesError::from_response(response))
         }
     }
 
-    /// <p>Returns the event types that meet the specified filter criteria. If no filter criteria are specified, all event types are returned, in no particular order.</p>
-    async fn describe_event_types(
+    /// Presigns the request `describe_event_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventTypesRequest,
-    ) -> Result<DescribeEventTypesResponse, RusotoError<DescribeEventTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_types_request(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventTypes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_events`, also returning the metadata of the response.
+    pub async fn describe_events_with_metadata(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<(DescribeEventsResponse, ResponseMetadata), RusotoError<DescribeEventsError>> {
+        let request = self.build_describe_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventTypesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventTypesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about events that meet the specified filter criteria. Events are returned in a summary form and do not include the detailed description, any additional metadata that depends on the event type, or any affected resources. To retrieve that information, use the <a>DescribeEventDetails</a> and <a>DescribeAffectedEntities</a> operations.</p> <p>If no filter criteria are specified, all events are returned. Results are sorted by <code>lastModifiedTime</code>, starting with the most recent.</p>
-    async fn describe_events(
+    /// Presigns the request `describe_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventsRequest,
-    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_request(&self, input: DescribeEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_events_for_organization`, also returning the metadata of the response.
+    pub async fn describe_events_for_organization_with_metadata(
+        &self,
+        input: DescribeEventsForOrganizationRequest,
+    ) -> Result<
+        (DescribeEventsForOrganizationResponse, ResponseMetadata),
+        RusotoError<DescribeEventsForOrganizationError>,
+    > {
+        let request = self.build_describe_events_for_organization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventsForOrganizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventsForOrganizationError::from_response(response))
         }
     }
 
-    /// <p>Returns information about events across your organization in AWS Organizations, meeting the specified filter criteria. Events are returned in a summary form and do not include the accounts impacted, detailed description, any additional metadata that depends on the event type, or any affected resources. To retrieve that information, use the <a>DescribeAffectedAccountsForOrganization</a>, <a>DescribeEventDetailsForOrganization</a>, and <a>DescribeAffectedEntitiesForOrganization</a> operations.</p> <p>If no filter criteria are specified, all events across your organization are returned. Results are sorted by <code>lastModifiedTime</code>, starting with the most recent.</p> <p>Before you can call this operation, you must first enable Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account.</p>
-    async fn describe_events_for_organization(
+    /// Presigns the request `describe_events_for_organization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events_for_organization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventsForOrganizationRequest,
-    ) -> Result<
-        DescribeEventsForOrganizationResponse,
-        RusotoError<DescribeEventsForOrganizationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_for_organization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_for_organization_request(
+        &self,
+        input: DescribeEventsForOrganizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeEventsForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1675,30 +2116,56 @@ impl AWSHealth for AWSHealthClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_health_service_status_for_organization`, also returning the metadata of the response.
+    pub async fn describe_health_service_status_for_organization_with_metadata(
+        &self,
+    ) -> Result<
+        (
+            DescribeHealthServiceStatusForOrganizationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeHealthServiceStatusForOrganizationError>,
+    > {
+        let request = self.build_describe_health_service_status_for_organization_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventsForOrganizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeHealthServiceStatusForOrganizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventsForOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeHealthServiceStatusForOrganizationError::from_response(response))
         }
     }
 
-    /// <p>This operation provides status information on enabling or disabling AWS Health to work with your organization. To call this operation, you must sign in as an IAM user, assume an IAM role, or sign in as the root user (not recommended) in the organization's master account.</p>
-    async fn describe_health_service_status_for_organization(
+    /// Presigns the request `describe_health_service_status_for_organization` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_health_service_status_for_organization(
         &self,
-    ) -> Result<
-        DescribeHealthServiceStatusForOrganizationResponse,
-        RusotoError<DescribeHealthServiceStatusForOrganizationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_health_service_status_for_organization_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_health_service_status_for_organization_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DescribeHealthServiceStatusForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1707,27 +2174,46 @@ impl AWSHealth for AWSHealthClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `disable_health_service_access_for_organization`, also returning the metadata of the response.
+    pub async fn disable_health_service_access_for_organization_with_metadata(
+        &self,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisableHealthServiceAccessForOrganizationError>>
+    {
+        let request = self.build_disable_health_service_access_for_organization_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeHealthServiceStatusForOrganizationResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHealthServiceStatusForOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableHealthServiceAccessForOrganizationError::from_response(response))
         }
     }
 
-    /// <p>Calling this operation disables Health from working with AWS Organizations. This does not remove the Service Linked Role (SLR) from the the master account in your organization. Use the IAM console, API, or AWS CLI to remove the SLR if desired. To call this operation, you must sign in as an IAM user, assume an IAM role, or sign in as the root user (not recommended) in the organization's master account.</p>
-    async fn disable_health_service_access_for_organization(
+    /// Presigns the request `disable_health_service_access_for_organization` would send with `presigner` instead of sending it.
+    pub async fn presign_disable_health_service_access_for_organization(
         &self,
-    ) -> Result<(), RusotoError<DisableHealthServiceAccessForOrganizationError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_health_service_access_for_organization_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_health_service_access_for_organization_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("DisableHealthServiceAccessForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1736,26 +2222,46 @@ impl AWSHealth for AWSHealthClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `enable_health_service_access_for_organization`, also returning the metadata of the response.
+    pub async fn enable_health_service_access_for_organization_with_metadata(
+        &self,
+    ) -> Result<((), ResponseMetadata), RusotoError<EnableHealthServiceAccessForOrganizationError>>
+    {
+        let request = self.build_enable_health_service_access_for_organization_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableHealthServiceAccessForOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableHealthServiceAccessForOrganizationError::from_response(response))
         }
     }
 
-    /// <p>Calling this operation enables AWS Health to work with AWS Organizations. This applies a Service Linked Role (SLR) to the master account in the organization. To learn more about the steps in this process, visit enabling service access for AWS Health in AWS Organizations. To call this operation, you must sign in as an IAM user, assume an IAM role, or sign in as the root user (not recommended) in the organization's master account.</p>
-    async fn enable_health_service_access_for_organization(
+    /// Presigns the request `enable_health_service_access_for_organization` would send with `presigner` instead of sending it.
+    pub async fn presign_enable_health_service_access_for_organization(
         &self,
-    ) -> Result<(), RusotoError<EnableHealthServiceAccessForOrganizationError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_health_service_access_for_organization_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_health_service_access_for_organization_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "health", &self.region, "/");
+        request.set_operation("EnableHealthServiceAccessForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1764,18 +2270,440 @@ impl AWSHealth for AWSHealthClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeAffectedAccountsForOrganization` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_affected_accounts_for_organization_pages(
+        &self,
+        input: DescribeAffectedAccountsForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeAffectedAccountsForOrganizationResponse,
+            RusotoError<DescribeAffectedAccountsForOrganizationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input| async move {
+                client
+                    .describe_affected_accounts_for_organization(input)
+                    .await
+            },
+            |input: &DescribeAffectedAccountsForOrganizationRequest,
+             output: &DescribeAffectedAccountsForOrganizationResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAffectedAccountsForOrganizationRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `affectedAccounts` of every page of `DescribeAffectedAccountsForOrganization` results for `input`, as `describe_affected_accounts_for_organization_pages` requests them.
+    pub fn describe_affected_accounts_for_organization_stream(
+        &self,
+        input: DescribeAffectedAccountsForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<String, RusotoError<DescribeAffectedAccountsForOrganizationError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_affected_accounts_for_organization_pages(input),
+            |page| page.affected_accounts.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeAffectedEntities` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_affected_entities_pages(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAffectedEntitiesResponse, RusotoError<DescribeAffectedEntitiesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input| async move {
+                client.describe_affected_entities(input).await
+            },
+            |input: &DescribeAffectedEntitiesRequest, output: &DescribeAffectedEntitiesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAffectedEntitiesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `entities` of every page of `DescribeAffectedEntities` results for `input`, as `describe_affected_entities_pages` requests them.
+    pub fn describe_affected_entities_stream(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AffectedEntity, RusotoError<DescribeAffectedEntitiesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_affected_entities_pages(input), |page| {
+            page.entities.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeAffectedEntitiesForOrganization` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_affected_entities_for_organization_pages(
+        &self,
+        input: DescribeAffectedEntitiesForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeAffectedEntitiesForOrganizationResponse,
+            RusotoError<DescribeAffectedEntitiesForOrganizationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input| async move {
+                client
+                    .describe_affected_entities_for_organization(input)
+                    .await
+            },
+            |input: &DescribeAffectedEntitiesForOrganizationRequest,
+             output: &DescribeAffectedEntitiesForOrganizationResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAffectedEntitiesForOrganizationRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `entities` of every page of `DescribeAffectedEntitiesForOrganization` results for `input`, as `describe_affected_entities_for_organization_pages` requests them.
+    pub fn describe_affected_entities_for_organization_stream(
+        &self,
+        input: DescribeAffectedEntitiesForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AffectedEntity, RusotoError<DescribeAffectedEntitiesForOrganizationError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_affected_entities_for_organization_pages(input),
+            |page| page.entities.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeEventAggregates` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_event_aggregates_pages(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeEventAggregatesResponse, RusotoError<DescribeEventAggregatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input| async move {
+                client.describe_event_aggregates(input).await
+            },
+            |input: &DescribeEventAggregatesRequest, output: &DescribeEventAggregatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEventAggregatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `eventAggregates` of every page of `DescribeEventAggregates` results for `input`, as `describe_event_aggregates_pages` requests them.
+    pub fn describe_event_aggregates_stream(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> impl futures::Stream<Item = Result<EventAggregate, RusotoError<DescribeEventAggregatesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_event_aggregates_pages(input), |page| {
+            page.event_aggregates.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEventTypes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_event_types_pages(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeEventTypesResponse, RusotoError<DescribeEventTypesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input| async move { client.describe_event_types(input).await },
+            |input: &DescribeEventTypesRequest, output: &DescribeEventTypesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEventTypesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `eventTypes` of every page of `DescribeEventTypes` results for `input`, as `describe_event_types_pages` requests them.
+    pub fn describe_event_types_stream(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<DescribeEventTypesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_event_types_pages(input), |page| {
+            page.event_types.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEvents` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeEventsResponse, RusotoError<DescribeEventsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input| async move { client.describe_events(input).await },
+            |input: &DescribeEventsRequest, output: &DescribeEventsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEventsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `events` of every page of `DescribeEvents` results for `input`, as `describe_events_pages` requests them.
+    pub fn describe_events_stream(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> impl futures::Stream<Item = Result<Event, RusotoError<DescribeEventsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEventsForOrganization` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_events_for_organization_pages(
+        &self,
+        input: DescribeEventsForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeEventsForOrganizationResponse,
+            RusotoError<DescribeEventsForOrganizationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSHealthClient, input| async move {
+                client.describe_events_for_organization(input).await
+            },
+            |input: &DescribeEventsForOrganizationRequest,
+             output: &DescribeEventsForOrganizationResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEventsForOrganizationRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `events` of every page of `DescribeEventsForOrganization` results for `input`, as `describe_events_for_organization_pages` requests them.
+    pub fn describe_events_for_organization_stream(
+        &self,
+        input: DescribeEventsForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<OrganizationEvent, RusotoError<DescribeEventsForOrganizationError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_events_for_organization_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl AWSHealth for AWSHealthClient {
+    /// <p>Returns a list of accounts in the organization from AWS Organizations that are affected by the provided event.</p> <p>Before you can call this operation, you must first enable AWS Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account.</p>
+    async fn describe_affected_accounts_for_organization(
+        &self,
+        input: DescribeAffectedAccountsForOrganizationRequest,
+    ) -> Result<
+        DescribeAffectedAccountsForOrganizationResponse,
+        RusotoError<DescribeAffectedAccountsForOrganizationError>,
+    > {
+        self.describe_affected_accounts_for_organization_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableHealthServiceAccessForOrganizationError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of entities that have been affected by the specified events, based on the specified filter criteria. Entities can refer to individual customer resources, groups of customer resources, or any other construct, depending on the AWS service. Events that have impact beyond that of the affected entities, or where the extent of impact is unknown, include at least one entity indicating this.</p> <p>At least one event ARN is required. Results are sorted by the <code>lastUpdatedTime</code> of the entity, starting with the most recent.</p>
+    async fn describe_affected_entities(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> Result<DescribeAffectedEntitiesResponse, RusotoError<DescribeAffectedEntitiesError>> {
+        self.describe_affected_entities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of entities that have been affected by one or more events for one or more accounts in your organization in AWS Organizations, based on the filter criteria. Entities can refer to individual customer resources, groups of customer resources, or any other construct, depending on the AWS service.</p> <p>At least one event ARN and account ID are required. Results are sorted by the <code>lastUpdatedTime</code> of the entity, starting with the most recent.</p> <p>Before you can call this operation, you must first enable AWS Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account. </p>
+    async fn describe_affected_entities_for_organization(
+        &self,
+        input: DescribeAffectedEntitiesForOrganizationRequest,
+    ) -> Result<
+        DescribeAffectedEntitiesForOrganizationResponse,
+        RusotoError<DescribeAffectedEntitiesForOrganizationError>,
+    > {
+        self.describe_affected_entities_for_organization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the number of entities that are affected by each of the specified events. If no events are specified, the counts of all affected entities are returned.</p>
+    async fn describe_entity_aggregates(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> Result<DescribeEntityAggregatesResponse, RusotoError<DescribeEntityAggregatesError>> {
+        self.describe_entity_aggregates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the number of events of each event type (issue, scheduled change, and account notification). If no filter is specified, the counts of all events in each category are returned.</p>
+    async fn describe_event_aggregates(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> Result<DescribeEventAggregatesResponse, RusotoError<DescribeEventAggregatesError>> {
+        self.describe_event_aggregates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about one or more specified events. Information includes standard event data (region, service, and so on, as returned by <a>DescribeEvents</a>), a detailed event description, and possible additional metadata that depends upon the nature of the event. Affected entities are not included; to retrieve those, use the <a>DescribeAffectedEntities</a> operation.</p> <p>If a specified event cannot be retrieved, an error message is returned for that event.</p>
+    async fn describe_event_details(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> Result<DescribeEventDetailsResponse, RusotoError<DescribeEventDetailsError>> {
+        self.describe_event_details_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about one or more specified events for one or more accounts in your organization. Information includes standard event data (Region, service, and so on, as returned by <a>DescribeEventsForOrganization</a>, a detailed event description, and possible additional metadata that depends upon the nature of the event. Affected entities are not included; to retrieve those, use the <a>DescribeAffectedEntitiesForOrganization</a> operation.</p> <p>Before you can call this operation, you must first enable AWS Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account.</p>
+    async fn describe_event_details_for_organization(
+        &self,
+        input: DescribeEventDetailsForOrganizationRequest,
+    ) -> Result<
+        DescribeEventDetailsForOrganizationResponse,
+        RusotoError<DescribeEventDetailsForOrganizationError>,
+    > {
+        self.describe_event_details_for_organization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the event types that meet the specified filter criteria. If no filter criteria are specified, all event types are returned, in no particular order.</p>
+    async fn describe_event_types(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> Result<DescribeEventTypesResponse, RusotoError<DescribeEventTypesError>> {
+        self.describe_event_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about events that meet the specified filter criteria. Events are returned in a summary form and do not include the detailed description, any additional metadata that depends on the event type, or any affected resources. To retrieve that information, use the <a>DescribeEventDetails</a> and <a>DescribeAffectedEntities</a> operations.</p> <p>If no filter criteria are specified, all events are returned. Results are sorted by <code>lastModifiedTime</code>, starting with the most recent.</p>
+    async fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.describe_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about events across your organization in AWS Organizations, meeting the specified filter criteria. Events are returned in a summary form and do not include the accounts impacted, detailed description, any additional metadata that depends on the event type, or any affected resources. To retrieve that information, use the <a>DescribeAffectedAccountsForOrganization</a>, <a>DescribeEventDetailsForOrganization</a>, and <a>DescribeAffectedEntitiesForOrganization</a> operations.</p> <p>If no filter criteria are specified, all events across your organization are returned. Results are sorted by <code>lastModifiedTime</code>, starting with the most recent.</p> <p>Before you can call this operation, you must first enable Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account.</p>
+    async fn describe_events_for_organization(
+        &self,
+        input: DescribeEventsForOrganizationRequest,
+    ) -> Result<
+        DescribeEventsForOrganizationResponse,
+        RusotoError<DescribeEventsForOrganizationError>,
+    > {
+        self.describe_events_for_organization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation provides status information on enabling or disabling AWS Health to work with your organization. To call this operation, you must sign in as an IAM user, assume an IAM role, or sign in as the root user (not recommended) in the organization's master account.</p>
+    async fn describe_health_service_status_for_organization(
+        &self,
+    ) -> Result<
+        DescribeHealthServiceStatusForOrganizationResponse,
+        RusotoError<DescribeHealthServiceStatusForOrganizationError>,
+    > {
+        self.describe_health_service_status_for_organization_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Calling this operation disables Health from working with AWS Organizations. This does not remove the Service Linked Role (SLR) from the the master account in your organization. Use the IAM console, API, or AWS CLI to remove the SLR if desired. To call this operation, you must sign in as an IAM user, assume an IAM role, or sign in as the root user (not recommended) in the organization's master account.</p>
+    async fn disable_health_service_access_for_organization(
+        &self,
+    ) -> Result<(), RusotoError<DisableHealthServiceAccessForOrganizationError>> {
+        self.disable_health_service_access_for_organization_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Calling this operation enables AWS Health to work with AWS Organizations. This applies a Service Linked Role (SLR) to the master account in the organization. To learn more about the steps in this process, visit enabling service access for AWS Health in AWS Organizations. To call this operation, you must sign in as an IAM user, assume an IAM role, or sign in as the root user (not recommended) in the organization's master account.</p>
+    async fn enable_health_service_access_for_organization(
+        &self,
+    ) -> Result<(), RusotoError<EnableHealthServiceAccessForOrganizationError>> {
+        self.enable_health_service_access_for_organization_with_metadata()
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2185,6 +2185,7 @@ pub struct UpdateInfrastructureConfigurationResponse {
 
 /// Errors returned by CancelImageCreation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelImageCreationError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -2202,6 +2203,8 @@ pub enum CancelImageCreationError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelImageCreationError {
@@ -2209,37 +2212,46 @@ impl CancelImageCreationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CancelImageCreationError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelImageCreationError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CancelImageCreationError::Client(err.msg))
+                    return RusotoError::service(CancelImageCreationError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CancelImageCreationError::Forbidden(err.msg))
+                    return RusotoError::service(CancelImageCreationError::Forbidden(err.msg), &res)
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelImageCreationError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelImageCreationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CancelImageCreationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CancelImageCreationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CancelImageCreationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CancelImageCreationError::Service(err.msg))
+                    return RusotoError::service(CancelImageCreationError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelImageCreationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelImageCreationError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelImageCreationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2259,12 +2271,14 @@ impl fmt::Display for CancelImageCreationError {
             CancelImageCreationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CancelImageCreationError::Service(ref cause) => write!(f, "{}", cause),
             CancelImageCreationError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CancelImageCreationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelImageCreationError {}
 /// Errors returned by CreateComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateComponentError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -2286,6 +2300,8 @@ pub enum CreateComponentError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateComponentError {
@@ -2293,45 +2309,55 @@ impl CreateComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateComponentError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateComponentError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateComponentError::Client(err.msg))
+                    return RusotoError::service(CreateComponentError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateComponentError::Forbidden(err.msg))
+                    return RusotoError::service(CreateComponentError::Forbidden(err.msg), &res)
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateComponentError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateComponentError::IdempotentParameterMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateComponentError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateComponentError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateComponentError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(CreateComponentError::InvalidVersionNumber(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateComponentError::InvalidVersionNumber(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateComponentError::ResourceInUse(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateComponentError::Service(err.msg))
+                    return RusotoError::service(CreateComponentError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateComponentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2351,12 +2377,14 @@ impl fmt::Display for CreateComponentError {
             CreateComponentError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateComponentError::Service(ref cause) => write!(f, "{}", cause),
             CreateComponentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateComponentError {}
 /// Errors returned by CreateDistributionConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDistributionConfigurationError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -2378,6 +2406,8 @@ pub enum CreateDistributionConfigurationError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDistributionConfigurationError {
@@ -2387,57 +2417,72 @@ impl CreateDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDistributionConfigurationError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateDistributionConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDistributionConfigurationError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateDistributionConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDistributionConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDistributionConfigurationError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDistributionConfigurationError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDistributionConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDistributionConfigurationError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDistributionConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDistributionConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDistributionConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDistributionConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateDistributionConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2471,12 +2516,16 @@ impl fmt::Display for CreateDistributionConfigurationError {
             CreateDistributionConfigurationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDistributionConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateDistributionConfigurationError {}
 /// Errors returned by CreateImage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateImageError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -2494,6 +2543,8 @@ pub enum CreateImageError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateImageError {
@@ -2501,33 +2552,40 @@ impl CreateImageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateImageError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateImageError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateImageError::Client(err.msg))
+                    return RusotoError::service(CreateImageError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateImageError::Forbidden(err.msg))
+                    return RusotoError::service(CreateImageError::Forbidden(err.msg), &res)
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateImageError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageError::IdempotentParameterMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateImageError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateImageError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateImageError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateImageError::ResourceInUse(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateImageError::Service(err.msg))
+                    return RusotoError::service(CreateImageError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateImageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateImageError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateImageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2545,12 +2603,14 @@ impl fmt::Display for CreateImageError {
             CreateImageError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateImageError::Service(ref cause) => write!(f, "{}", cause),
             CreateImageError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateImageError {}
 /// Errors returned by CreateImagePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateImagePipelineError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -2570,6 +2630,8 @@ pub enum CreateImagePipelineError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateImagePipelineError {
@@ -2577,42 +2639,52 @@ impl CreateImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateImagePipelineError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImagePipelineError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateImagePipelineError::Client(err.msg))
+                    return RusotoError::service(CreateImagePipelineError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service(CreateImagePipelineError::Forbidden(err.msg), &res)
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateImagePipelineError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateImagePipelineError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImagePipelineError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateImagePipelineError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateImagePipelineError::Service(err.msg))
+                    return RusotoError::service(CreateImagePipelineError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImagePipelineError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateImagePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2633,12 +2705,14 @@ impl fmt::Display for CreateImagePipelineError {
             CreateImagePipelineError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateImagePipelineError::Service(ref cause) => write!(f, "{}", cause),
             CreateImagePipelineError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateImagePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateImagePipelineError {}
 /// Errors returned by CreateImageRecipe
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateImageRecipeError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -2660,6 +2734,8 @@ pub enum CreateImageRecipeError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateImageRecipeError {
@@ -2667,47 +2743,58 @@ impl CreateImageRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateImageRecipeError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageRecipeError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateImageRecipeError::Client(err.msg))
+                    return RusotoError::service(CreateImageRecipeError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateImageRecipeError::Forbidden(err.msg))
+                    return RusotoError::service(CreateImageRecipeError::Forbidden(err.msg), &res)
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateImageRecipeError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateImageRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateImageRecipeError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(CreateImageRecipeError::InvalidVersionNumber(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageRecipeError::InvalidVersionNumber(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageRecipeError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateImageRecipeError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateImageRecipeError::Service(err.msg))
+                    return RusotoError::service(CreateImageRecipeError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageRecipeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateImageRecipeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2729,12 +2816,14 @@ impl fmt::Display for CreateImageRecipeError {
             CreateImageRecipeError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateImageRecipeError::Service(ref cause) => write!(f, "{}", cause),
             CreateImageRecipeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateImageRecipeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateImageRecipeError {}
 /// Errors returned by CreateInfrastructureConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInfrastructureConfigurationError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -2754,6 +2843,8 @@ pub enum CreateInfrastructureConfigurationError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInfrastructureConfigurationError {
@@ -2763,54 +2854,68 @@ impl CreateInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateInfrastructureConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInfrastructureConfigurationError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateInfrastructureConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInfrastructureConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInfrastructureConfigurationError::IdempotentParameterMismatch(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInfrastructureConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInfrastructureConfigurationError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInfrastructureConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInfrastructureConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInfrastructureConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInfrastructureConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateInfrastructureConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2841,12 +2946,16 @@ impl fmt::Display for CreateInfrastructureConfigurationError {
             CreateInfrastructureConfigurationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateInfrastructureConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateInfrastructureConfigurationError {}
 /// Errors returned by DeleteComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteComponentError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -2862,6 +2971,8 @@ pub enum DeleteComponentError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteComponentError {
@@ -2869,30 +2980,40 @@ impl DeleteComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteComponentError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteComponentError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteComponentError::Client(err.msg))
+                    return RusotoError::service(DeleteComponentError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteComponentError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteComponentError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteComponentError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteComponentError::ResourceDependency(err.msg))
+                    return RusotoError::service(
+                        DeleteComponentError::ResourceDependency(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteComponentError::Service(err.msg))
+                    return RusotoError::service(DeleteComponentError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteComponentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2909,12 +3030,14 @@ impl fmt::Display for DeleteComponentError {
             DeleteComponentError::ResourceDependency(ref cause) => write!(f, "{}", cause),
             DeleteComponentError::Service(ref cause) => write!(f, "{}", cause),
             DeleteComponentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteComponentError {}
 /// Errors returned by DeleteDistributionConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDistributionConfigurationError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -2930,6 +3053,8 @@ pub enum DeleteDistributionConfigurationError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDistributionConfigurationError {
@@ -2939,42 +3064,54 @@ impl DeleteDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDistributionConfigurationError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteDistributionConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDistributionConfigurationError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteDistributionConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDistributionConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDistributionConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDistributionConfigurationError::ResourceDependency(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDistributionConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDistributionConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDistributionConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteDistributionConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2999,12 +3136,16 @@ impl fmt::Display for DeleteDistributionConfigurationError {
             DeleteDistributionConfigurationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDistributionConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteDistributionConfigurationError {}
 /// Errors returned by DeleteImage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteImageError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3020,6 +3161,8 @@ pub enum DeleteImageError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteImageError {
@@ -3027,28 +3170,37 @@ impl DeleteImageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteImageError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteImageError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteImageError::Client(err.msg))
+                    return RusotoError::service(DeleteImageError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteImageError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteImageError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteImageError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteImageError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteImageError::ResourceDependency(err.msg))
+                    return RusotoError::service(
+                        DeleteImageError::ResourceDependency(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteImageError::Service(err.msg))
+                    return RusotoError::service(DeleteImageError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteImageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteImageError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteImageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3065,12 +3217,14 @@ impl fmt::Display for DeleteImageError {
             DeleteImageError::ResourceDependency(ref cause) => write!(f, "{}", cause),
             DeleteImageError::Service(ref cause) => write!(f, "{}", cause),
             DeleteImageError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteImageError {}
 /// Errors returned by DeleteImagePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteImagePipelineError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3086,6 +3240,8 @@ pub enum DeleteImagePipelineError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteImagePipelineError {
@@ -3093,34 +3249,40 @@ impl DeleteImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImagePipelineError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::Client(err.msg))
+                    return RusotoError::service(DeleteImagePipelineError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteImagePipelineError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteImagePipelineError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::ResourceDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImagePipelineError::ResourceDependency(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::Service(err.msg))
+                    return RusotoError::service(DeleteImagePipelineError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImagePipelineError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteImagePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3137,12 +3299,14 @@ impl fmt::Display for DeleteImagePipelineError {
             DeleteImagePipelineError::ResourceDependency(ref cause) => write!(f, "{}", cause),
             DeleteImagePipelineError::Service(ref cause) => write!(f, "{}", cause),
             DeleteImagePipelineError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteImagePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteImagePipelineError {}
 /// Errors returned by DeleteImageRecipe
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteImageRecipeError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3158,6 +3322,8 @@ pub enum DeleteImageRecipeError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteImageRecipeError {
@@ -3165,34 +3331,40 @@ impl DeleteImageRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImageRecipeError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::Client(err.msg))
+                    return RusotoError::service(DeleteImageRecipeError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteImageRecipeError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteImageRecipeError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::ResourceDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImageRecipeError::ResourceDependency(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::Service(err.msg))
+                    return RusotoError::service(DeleteImageRecipeError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImageRecipeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteImageRecipeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3209,12 +3381,14 @@ impl fmt::Display for DeleteImageRecipeError {
             DeleteImageRecipeError::ResourceDependency(ref cause) => write!(f, "{}", cause),
             DeleteImageRecipeError::Service(ref cause) => write!(f, "{}", cause),
             DeleteImageRecipeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteImageRecipeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteImageRecipeError {}
 /// Errors returned by DeleteInfrastructureConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInfrastructureConfigurationError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3230,6 +3404,8 @@ pub enum DeleteInfrastructureConfigurationError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInfrastructureConfigurationError {
@@ -3239,42 +3415,54 @@ impl DeleteInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteInfrastructureConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInfrastructureConfigurationError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteInfrastructureConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInfrastructureConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteInfrastructureConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteInfrastructureConfigurationError::ResourceDependency(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteInfrastructureConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInfrastructureConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteInfrastructureConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteInfrastructureConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3299,12 +3487,16 @@ impl fmt::Display for DeleteInfrastructureConfigurationError {
             DeleteInfrastructureConfigurationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteInfrastructureConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteInfrastructureConfigurationError {}
 /// Errors returned by GetComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetComponentError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3318,6 +3510,8 @@ pub enum GetComponentError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetComponentError {
@@ -3325,25 +3519,31 @@ impl GetComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetComponentError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetComponentError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetComponentError::Client(err.msg))
+                    return RusotoError::service(GetComponentError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetComponentError::Forbidden(err.msg))
+                    return RusotoError::service(GetComponentError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetComponentError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetComponentError::Service(err.msg))
+                    return RusotoError::service(GetComponentError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetComponentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3359,12 +3559,14 @@ impl fmt::Display for GetComponentError {
             GetComponentError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetComponentError::Service(ref cause) => write!(f, "{}", cause),
             GetComponentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetComponentError {}
 /// Errors returned by GetComponentPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetComponentPolicyError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3378,6 +3580,8 @@ pub enum GetComponentPolicyError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetComponentPolicyError {
@@ -3385,29 +3589,37 @@ impl GetComponentPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetComponentPolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetComponentPolicyError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetComponentPolicyError::Forbidden(err.msg))
+                    return RusotoError::service(GetComponentPolicyError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetComponentPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetComponentPolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetComponentPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetComponentPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetComponentPolicyError::Service(err.msg))
+                    return RusotoError::service(GetComponentPolicyError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetComponentPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetComponentPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetComponentPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3423,12 +3635,14 @@ impl fmt::Display for GetComponentPolicyError {
             GetComponentPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetComponentPolicyError::Service(ref cause) => write!(f, "{}", cause),
             GetComponentPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetComponentPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetComponentPolicyError {}
 /// Errors returned by GetDistributionConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDistributionConfigurationError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3442,6 +3656,8 @@ pub enum GetDistributionConfigurationError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDistributionConfigurationError {
@@ -3451,35 +3667,48 @@ impl GetDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDistributionConfigurationError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::Client(err.msg))
+                    return RusotoError::service(
+                        GetDistributionConfigurationError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDistributionConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDistributionConfigurationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDistributionConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDistributionConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetDistributionConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3499,12 +3728,14 @@ impl fmt::Display for GetDistributionConfigurationError {
             GetDistributionConfigurationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetDistributionConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDistributionConfigurationError {}
 /// Errors returned by GetImage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetImageError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3518,6 +3749,8 @@ pub enum GetImageError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetImageError {
@@ -3525,21 +3758,28 @@ impl GetImageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImageError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetImageError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service(GetImageError::Client(err.msg), &res)
                 }
-                "ClientException" => return RusotoError::Service(GetImageError::Client(err.msg)),
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImageError::Forbidden(err.msg))
+                    return RusotoError::service(GetImageError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImageError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetImageError::InvalidRequest(err.msg), &res)
+                }
+                "ServiceException" => {
+                    return RusotoError::service(GetImageError::Service(err.msg), &res)
                 }
-                "ServiceException" => return RusotoError::Service(GetImageError::Service(err.msg)),
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(GetImageError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetImageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3555,12 +3795,14 @@ impl fmt::Display for GetImageError {
             GetImageError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetImageError::Service(ref cause) => write!(f, "{}", cause),
             GetImageError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetImageError {}
 /// Errors returned by GetImagePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetImagePipelineError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3574,6 +3816,8 @@ pub enum GetImagePipelineError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetImagePipelineError {
@@ -3581,27 +3825,34 @@ impl GetImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImagePipelineError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetImagePipelineError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetImagePipelineError::Client(err.msg))
+                    return RusotoError::service(GetImagePipelineError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service(GetImagePipelineError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetImagePipelineError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImagePipelineError::Service(err.msg))
+                    return RusotoError::service(GetImagePipelineError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImagePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetImagePipelineError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetImagePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3617,12 +3868,14 @@ impl fmt::Display for GetImagePipelineError {
             GetImagePipelineError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetImagePipelineError::Service(ref cause) => write!(f, "{}", cause),
             GetImagePipelineError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetImagePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetImagePipelineError {}
 /// Errors returned by GetImagePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetImagePolicyError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3636,6 +3889,8 @@ pub enum GetImagePolicyError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetImagePolicyError {
@@ -3643,27 +3898,34 @@ impl GetImagePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImagePolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetImagePolicyError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImagePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(GetImagePolicyError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImagePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetImagePolicyError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetImagePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetImagePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImagePolicyError::Service(err.msg))
+                    return RusotoError::service(GetImagePolicyError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImagePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetImagePolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetImagePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3679,12 +3941,14 @@ impl fmt::Display for GetImagePolicyError {
             GetImagePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetImagePolicyError::Service(ref cause) => write!(f, "{}", cause),
             GetImagePolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetImagePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetImagePolicyError {}
 /// Errors returned by GetImageRecipe
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetImageRecipeError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3698,6 +3962,8 @@ pub enum GetImageRecipeError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetImageRecipeError {
@@ -3705,27 +3971,31 @@ impl GetImageRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImageRecipeError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetImageRecipeError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetImageRecipeError::Client(err.msg))
+                    return RusotoError::service(GetImageRecipeError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImageRecipeError::Forbidden(err.msg))
+                    return RusotoError::service(GetImageRecipeError::Forbidden(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImageRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetImageRecipeError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImageRecipeError::Service(err.msg))
+                    return RusotoError::service(GetImageRecipeError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImageRecipeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetImageRecipeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetImageRecipeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3741,12 +4011,14 @@ impl fmt::Display for GetImageRecipeError {
             GetImageRecipeError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetImageRecipeError::Service(ref cause) => write!(f, "{}", cause),
             GetImageRecipeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetImageRecipeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetImageRecipeError {}
 /// Errors returned by GetImageRecipePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetImageRecipePolicyError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3760,6 +4032,8 @@ pub enum GetImageRecipePolicyError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetImageRecipePolicyError {
@@ -3767,31 +4041,40 @@ impl GetImageRecipePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetImageRecipePolicyError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        GetImageRecipePolicyError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetImageRecipePolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetImageRecipePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::Service(err.msg))
+                    return RusotoError::service(GetImageRecipePolicyError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetImageRecipePolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetImageRecipePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3807,12 +4090,14 @@ impl fmt::Display for GetImageRecipePolicyError {
             GetImageRecipePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetImageRecipePolicyError::Service(ref cause) => write!(f, "{}", cause),
             GetImageRecipePolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetImageRecipePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetImageRecipePolicyError {}
 /// Errors returned by GetInfrastructureConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInfrastructureConfigurationError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3826,6 +4111,8 @@ pub enum GetInfrastructureConfigurationError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInfrastructureConfigurationError {
@@ -3835,37 +4122,48 @@ impl GetInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetInfrastructureConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInfrastructureConfigurationError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetInfrastructureConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInfrastructureConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetInfrastructureConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInfrastructureConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInfrastructureConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetInfrastructureConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetInfrastructureConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3887,12 +4185,16 @@ impl fmt::Display for GetInfrastructureConfigurationError {
             GetInfrastructureConfigurationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetInfrastructureConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetInfrastructureConfigurationError {}
 /// Errors returned by ImportComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportComponentError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -3914,6 +4216,8 @@ pub enum ImportComponentError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportComponentError {
@@ -3921,45 +4225,55 @@ impl ImportComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ImportComponentError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportComponentError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ImportComponentError::Client(err.msg))
+                    return RusotoError::service(ImportComponentError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ImportComponentError::Forbidden(err.msg))
+                    return RusotoError::service(ImportComponentError::Forbidden(err.msg), &res)
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(ImportComponentError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportComponentError::IdempotentParameterMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(ImportComponentError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportComponentError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ImportComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ImportComponentError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(ImportComponentError::InvalidVersionNumber(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportComponentError::InvalidVersionNumber(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ImportComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service(ImportComponentError::ResourceInUse(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ImportComponentError::Service(err.msg))
+                    return RusotoError::service(ImportComponentError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ImportComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ImportComponentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3979,12 +4293,14 @@ impl fmt::Display for ImportComponentError {
             ImportComponentError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             ImportComponentError::Service(ref cause) => write!(f, "{}", cause),
             ImportComponentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ImportComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportComponentError {}
 /// Errors returned by ListComponentBuildVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListComponentBuildVersionsError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4000,6 +4316,8 @@ pub enum ListComponentBuildVersionsError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListComponentBuildVersionsError {
@@ -4009,38 +4327,51 @@ impl ListComponentBuildVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListComponentBuildVersionsError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::Client(err.msg))
+                    return RusotoError::service(
+                        ListComponentBuildVersionsError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComponentBuildVersionsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListComponentBuildVersionsError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComponentBuildVersionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::Service(err.msg))
+                    return RusotoError::service(
+                        ListComponentBuildVersionsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListComponentBuildVersionsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListComponentBuildVersionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4063,12 +4394,14 @@ impl fmt::Display for ListComponentBuildVersionsError {
             ListComponentBuildVersionsError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListComponentBuildVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListComponentBuildVersionsError {}
 /// Errors returned by ListComponents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListComponentsError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4084,6 +4417,8 @@ pub enum ListComponentsError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListComponentsError {
@@ -4091,32 +4426,37 @@ impl ListComponentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListComponentsError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComponentsError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListComponentsError::Client(err.msg))
+                    return RusotoError::service(ListComponentsError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListComponentsError::Forbidden(err.msg))
+                    return RusotoError::service(ListComponentsError::Forbidden(err.msg), &res)
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListComponentsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComponentsError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListComponentsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListComponentsError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListComponentsError::Service(err.msg))
+                    return RusotoError::service(ListComponentsError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListComponentsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListComponentsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListComponentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4133,12 +4473,14 @@ impl fmt::Display for ListComponentsError {
             ListComponentsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListComponentsError::Service(ref cause) => write!(f, "{}", cause),
             ListComponentsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListComponentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListComponentsError {}
 /// Errors returned by ListDistributionConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDistributionConfigurationsError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4154,6 +4496,8 @@ pub enum ListDistributionConfigurationsError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDistributionConfigurationsError {
@@ -4163,42 +4507,54 @@ impl ListDistributionConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDistributionConfigurationsError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListDistributionConfigurationsError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDistributionConfigurationsError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListDistributionConfigurationsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDistributionConfigurationsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDistributionConfigurationsError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDistributionConfigurationsError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListDistributionConfigurationsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDistributionConfigurationsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDistributionConfigurationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListDistributionConfigurationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4223,12 +4579,16 @@ impl fmt::Display for ListDistributionConfigurationsError {
             ListDistributionConfigurationsError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListDistributionConfigurationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListDistributionConfigurationsError {}
 /// Errors returned by ListImageBuildVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListImageBuildVersionsError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4244,6 +4604,8 @@ pub enum ListImageBuildVersionsError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListImageBuildVersionsError {
@@ -4251,36 +4613,46 @@ impl ListImageBuildVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListImageBuildVersionsError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::Client(err.msg))
+                    return RusotoError::service(ListImageBuildVersionsError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        ListImageBuildVersionsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListImageBuildVersionsError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImageBuildVersionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::Service(err.msg))
+                    return RusotoError::service(
+                        ListImageBuildVersionsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImageBuildVersionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListImageBuildVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4299,12 +4671,14 @@ impl fmt::Display for ListImageBuildVersionsError {
             ListImageBuildVersionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListImageBuildVersionsError::Service(ref cause) => write!(f, "{}", cause),
             ListImageBuildVersionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListImageBuildVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListImageBuildVersionsError {}
 /// Errors returned by ListImagePipelineImages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListImagePipelineImagesError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4322,6 +4696,8 @@ pub enum ListImagePipelineImagesError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListImagePipelineImagesError {
@@ -4329,41 +4705,55 @@ impl ListImagePipelineImagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListImagePipelineImagesError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::Client(err.msg))
+                    return RusotoError::service(
+                        ListImagePipelineImagesError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        ListImagePipelineImagesError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListImagePipelineImagesError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImagePipelineImagesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImagePipelineImagesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::Service(err.msg))
+                    return RusotoError::service(
+                        ListImagePipelineImagesError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImagePipelineImagesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListImagePipelineImagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4385,12 +4775,14 @@ impl fmt::Display for ListImagePipelineImagesError {
             ListImagePipelineImagesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListImagePipelineImagesError::Service(ref cause) => write!(f, "{}", cause),
             ListImagePipelineImagesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListImagePipelineImagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListImagePipelineImagesError {}
 /// Errors returned by ListImagePipelines
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListImagePipelinesError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4406,6 +4798,8 @@ pub enum ListImagePipelinesError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListImagePipelinesError {
@@ -4413,34 +4807,40 @@ impl ListImagePipelinesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListImagePipelinesError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImagePipelinesError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImagePipelinesError::Client(err.msg))
+                    return RusotoError::service(ListImagePipelinesError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImagePipelinesError::Forbidden(err.msg))
+                    return RusotoError::service(ListImagePipelinesError::Forbidden(err.msg), &res)
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListImagePipelinesError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImagePipelinesError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImagePipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListImagePipelinesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImagePipelinesError::Service(err.msg))
+                    return RusotoError::service(ListImagePipelinesError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImagePipelinesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImagePipelinesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListImagePipelinesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4457,12 +4857,14 @@ impl fmt::Display for ListImagePipelinesError {
             ListImagePipelinesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListImagePipelinesError::Service(ref cause) => write!(f, "{}", cause),
             ListImagePipelinesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListImagePipelinesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListImagePipelinesError {}
 /// Errors returned by ListImageRecipes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListImageRecipesError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4478,6 +4880,8 @@ pub enum ListImageRecipesError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListImageRecipesError {
@@ -4485,32 +4889,40 @@ impl ListImageRecipesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListImageRecipesError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImageRecipesError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImageRecipesError::Client(err.msg))
+                    return RusotoError::service(ListImageRecipesError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImageRecipesError::Forbidden(err.msg))
+                    return RusotoError::service(ListImageRecipesError::Forbidden(err.msg), &res)
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListImageRecipesError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListImageRecipesError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImageRecipesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListImageRecipesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImageRecipesError::Service(err.msg))
+                    return RusotoError::service(ListImageRecipesError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImageRecipesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListImageRecipesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListImageRecipesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4527,12 +4939,14 @@ impl fmt::Display for ListImageRecipesError {
             ListImageRecipesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListImageRecipesError::Service(ref cause) => write!(f, "{}", cause),
             ListImageRecipesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListImageRecipesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListImageRecipesError {}
 /// Errors returned by ListImages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListImagesError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4548,6 +4962,8 @@ pub enum ListImagesError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListImagesError {
@@ -4555,26 +4971,34 @@ impl ListImagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListImagesError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListImagesError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service(ListImagesError::Client(err.msg), &res)
                 }
-                "ClientException" => return RusotoError::Service(ListImagesError::Client(err.msg)),
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImagesError::Forbidden(err.msg))
+                    return RusotoError::service(ListImagesError::Forbidden(err.msg), &res)
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListImagesError::InvalidPaginationToken(err.msg))
+                    return RusotoError::service(
+                        ListImagesError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImagesError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListImagesError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImagesError::Service(err.msg))
+                    return RusotoError::service(ListImagesError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImagesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListImagesError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListImagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4591,12 +5015,14 @@ impl fmt::Display for ListImagesError {
             ListImagesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListImagesError::Service(ref cause) => write!(f, "{}", cause),
             ListImagesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListImagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListImagesError {}
 /// Errors returned by ListInfrastructureConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInfrastructureConfigurationsError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4612,6 +5038,8 @@ pub enum ListInfrastructureConfigurationsError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInfrastructureConfigurationsError {
@@ -4621,42 +5049,54 @@ impl ListInfrastructureConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListInfrastructureConfigurationsError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListInfrastructureConfigurationsError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInfrastructureConfigurationsError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInfrastructureConfigurationsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInfrastructureConfigurationsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListInfrastructureConfigurationsError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListInfrastructureConfigurationsError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListInfrastructureConfigurationsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInfrastructureConfigurationsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListInfrastructureConfigurationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListInfrastructureConfigurationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4681,12 +5121,16 @@ impl fmt::Display for ListInfrastructureConfigurationsError {
             ListInfrastructureConfigurationsError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListInfrastructureConfigurationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListInfrastructureConfigurationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -4694,6 +5138,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the service encounters an unrecoverable exception.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -4701,20 +5147,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4727,12 +5175,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Service(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutComponentPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutComponentPolicyError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4750,6 +5200,8 @@ pub enum PutComponentPolicyError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutComponentPolicyError {
@@ -4757,37 +5209,46 @@ impl PutComponentPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(PutComponentPolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutComponentPolicyError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutComponentPolicyError::Client(err.msg))
+                    return RusotoError::service(PutComponentPolicyError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutComponentPolicyError::Forbidden(err.msg))
+                    return RusotoError::service(PutComponentPolicyError::Forbidden(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutComponentPolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutComponentPolicyError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutComponentPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        PutComponentPolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutComponentPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutComponentPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutComponentPolicyError::Service(err.msg))
+                    return RusotoError::service(PutComponentPolicyError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutComponentPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutComponentPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutComponentPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4805,12 +5266,14 @@ impl fmt::Display for PutComponentPolicyError {
             PutComponentPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutComponentPolicyError::Service(ref cause) => write!(f, "{}", cause),
             PutComponentPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutComponentPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutComponentPolicyError {}
 /// Errors returned by PutImagePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutImagePolicyError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4828,6 +5291,8 @@ pub enum PutImagePolicyError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutImagePolicyError {
@@ -4835,35 +5300,43 @@ impl PutImagePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(PutImagePolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutImagePolicyError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutImagePolicyError::Client(err.msg))
+                    return RusotoError::service(PutImagePolicyError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutImagePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(PutImagePolicyError::Forbidden(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutImagePolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutImagePolicyError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutImagePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(PutImagePolicyError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutImagePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutImagePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutImagePolicyError::Service(err.msg))
+                    return RusotoError::service(PutImagePolicyError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutImagePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        PutImagePolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutImagePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4881,12 +5354,14 @@ impl fmt::Display for PutImagePolicyError {
             PutImagePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutImagePolicyError::Service(ref cause) => write!(f, "{}", cause),
             PutImagePolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutImagePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutImagePolicyError {}
 /// Errors returned by PutImageRecipePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutImageRecipePolicyError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4904,6 +5379,8 @@ pub enum PutImageRecipePolicyError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutImageRecipePolicyError {
@@ -4911,39 +5388,49 @@ impl PutImageRecipePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutImageRecipePolicyError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::Client(err.msg))
+                    return RusotoError::service(PutImageRecipePolicyError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        PutImageRecipePolicyError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutImageRecipePolicyError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        PutImageRecipePolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutImageRecipePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::Service(err.msg))
+                    return RusotoError::service(PutImageRecipePolicyError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutImageRecipePolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutImageRecipePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4961,12 +5448,14 @@ impl fmt::Display for PutImageRecipePolicyError {
             PutImageRecipePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutImageRecipePolicyError::Service(ref cause) => write!(f, "{}", cause),
             PutImageRecipePolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutImageRecipePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutImageRecipePolicyError {}
 /// Errors returned by StartImagePipelineExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartImagePipelineExecutionError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -4986,6 +5475,8 @@ pub enum StartImagePipelineExecutionError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartImagePipelineExecutionError {
@@ -4995,48 +5486,66 @@ impl StartImagePipelineExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartImagePipelineExecutionError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::Client(err.msg))
+                    return RusotoError::service(
+                        StartImagePipelineExecutionError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImagePipelineExecutionError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartImagePipelineExecutionError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImagePipelineExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImagePipelineExecutionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartImagePipelineExecutionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::Service(err.msg))
+                    return RusotoError::service(
+                        StartImagePipelineExecutionError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartImagePipelineExecutionError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartImagePipelineExecutionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5061,12 +5570,14 @@ impl fmt::Display for StartImagePipelineExecutionError {
             StartImagePipelineExecutionError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartImagePipelineExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartImagePipelineExecutionError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -5074,6 +5585,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the service encounters an unrecoverable exception.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -5081,16 +5594,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service(TagResourceError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5103,12 +5616,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Service(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -5116,6 +5631,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the service encounters an unrecoverable exception.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -5123,16 +5640,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service(UntagResourceError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5145,12 +5668,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Service(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDistributionConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDistributionConfigurationError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -5170,6 +5695,8 @@ pub enum UpdateDistributionConfigurationError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDistributionConfigurationError {
@@ -5179,52 +5706,66 @@ impl UpdateDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDistributionConfigurationError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateDistributionConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDistributionConfigurationError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateDistributionConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDistributionConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDistributionConfigurationError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDistributionConfigurationError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDistributionConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDistributionConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateDistributionConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDistributionConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDistributionConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateDistributionConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5255,12 +5796,16 @@ impl fmt::Display for UpdateDistributionConfigurationError {
             UpdateDistributionConfigurationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateDistributionConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateDistributionConfigurationError {}
 /// Errors returned by UpdateImagePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateImagePipelineError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -5278,6 +5823,8 @@ pub enum UpdateImagePipelineError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateImagePipelineError {
@@ -5285,37 +5832,46 @@ impl UpdateImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateImagePipelineError::CallRateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::Client(err.msg))
+                    return RusotoError::service(UpdateImagePipelineError::Client(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateImagePipelineError::Forbidden(err.msg), &res)
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateImagePipelineError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateImagePipelineError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateImagePipelineError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::Service(err.msg))
+                    return RusotoError::service(UpdateImagePipelineError::Service(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateImagePipelineError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateImagePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5335,12 +5891,14 @@ impl fmt::Display for UpdateImagePipelineError {
             UpdateImagePipelineError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateImagePipelineError::Service(ref cause) => write!(f, "{}", cause),
             UpdateImagePipelineError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            UpdateImagePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateImagePipelineError {}
 /// Errors returned by UpdateInfrastructureConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateInfrastructureConfigurationError {
     /// <p>You have exceeded the permitted request rate for the specific operation.</p>
     CallRateLimitExceeded(String),
@@ -5358,6 +5916,8 @@ pub enum UpdateInfrastructureConfigurationError {
     Service(String),
     /// <p>The service is unable to process your request at this time.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateInfrastructureConfigurationError {
@@ -5367,49 +5927,62 @@ impl UpdateInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateInfrastructureConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateInfrastructureConfigurationError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInfrastructureConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateInfrastructureConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInfrastructureConfigurationError::IdempotentParameterMismatch(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInfrastructureConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInfrastructureConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateInfrastructureConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateInfrastructureConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInfrastructureConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateInfrastructureConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5437,6 +6010,9 @@ impl fmt::Display for UpdateInfrastructureConfigurationError {
             UpdateInfrastructureConfigurationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateInfrastructureConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -5734,6 +6310,7 @@ impl ImageBuilderClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ImageBuilderClient {
         ImageBuilderClient {
             client: Client::shared(),
@@ -5759,238 +6336,479 @@ impl ImageBuilderClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ImageBuilderClient {
         ImageBuilderClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ImageBuilderClient> {
+        rusoto_core::ClientBuilder::new(ImageBuilderClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ImageBuilderClient {
+        ImageBuilderClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ImageBuilder for ImageBuilderClient {
-    /// <p>CancelImageCreation cancels the creation of Image. This operation may only be used on images in a non-terminal state.</p>
-    async fn cancel_image_creation(
+impl ImageBuilderClient {
+    /// Calls `cancel_image_creation`, also returning the metadata of the response.
+    pub async fn cancel_image_creation_with_metadata(
         &self,
         input: CancelImageCreationRequest,
-    ) -> Result<CancelImageCreationResponse, RusotoError<CancelImageCreationError>> {
-        let request_uri = "/CancelImageCreation";
-
-        let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (CancelImageCreationResponse, ResponseMetadata),
+        RusotoError<CancelImageCreationError>,
+    > {
+        let request = self.build_cancel_image_creation_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelImageCreationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CancelImageCreationError::from_response(response))
         }
     }
 
-    /// <p>Creates a new component that can be used to build, validate, test and assess your image.</p>
-    async fn create_component(
+    /// Presigns the request `cancel_image_creation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_image_creation(
         &self,
-        input: CreateComponentRequest,
-    ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
-        let request_uri = "/CreateComponent";
+        presigner: &rusoto_core::Presigner,
+        input: CancelImageCreationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_image_creation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_image_creation_request(
+        &self,
+        input: CancelImageCreationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/CancelImageCreation";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CancelImageCreation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_component`, also returning the metadata of the response.
+    pub async fn create_component_with_metadata(
+        &self,
+        input: CreateComponentRequest,
+    ) -> Result<(CreateComponentResponse, ResponseMetadata), RusotoError<CreateComponentError>>
+    {
+        let request = self.build_create_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateComponentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateComponentError::from_response(response))
         }
     }
 
-    /// <p> Creates a new distribution configuration. Distribution configurations define and configure the outputs of your pipeline. </p>
-    async fn create_distribution_configuration(
+    /// Presigns the request `create_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_component(
         &self,
-        input: CreateDistributionConfigurationRequest,
-    ) -> Result<
-        CreateDistributionConfigurationResponse,
-        RusotoError<CreateDistributionConfigurationError>,
-    > {
-        let request_uri = "/CreateDistributionConfiguration";
+        presigner: &rusoto_core::Presigner,
+        input: CreateComponentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_component_request(&self, input: CreateComponentRequest) -> SignedRequest {
+        let request_uri = "/CreateComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_distribution_configuration`, also returning the metadata of the response.
+    pub async fn create_distribution_configuration_with_metadata(
+        &self,
+        input: CreateDistributionConfigurationRequest,
+    ) -> Result<
+        (CreateDistributionConfigurationResponse, ResponseMetadata),
+        RusotoError<CreateDistributionConfigurationError>,
+    > {
+        let request = self.build_create_distribution_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDistributionConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateDistributionConfigurationError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p> Creates a new image. This request will create a new image along with all of the configured output resources defined in the distribution configuration. </p>
-    async fn create_image(
+    /// Presigns the request `create_distribution_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_distribution_configuration(
         &self,
-        input: CreateImageRequest,
-    ) -> Result<CreateImageResponse, RusotoError<CreateImageError>> {
-        let request_uri = "/CreateImage";
+        presigner: &rusoto_core::Presigner,
+        input: CreateDistributionConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_distribution_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+    fn build_create_distribution_configuration_request(
+        &self,
+        input: CreateDistributionConfigurationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/CreateDistributionConfiguration";
+
+        let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateDistributionConfiguration");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_image`, also returning the metadata of the response.
+    pub async fn create_image_with_metadata(
+        &self,
+        input: CreateImageRequest,
+    ) -> Result<(CreateImageResponse, ResponseMetadata), RusotoError<CreateImageError>> {
+        let request = self.build_create_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateImageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateImageError::from_response(response))
         }
     }
 
-    /// <p> Creates a new image pipeline. Image pipelines enable you to automate the creation and distribution of images. </p>
-    async fn create_image_pipeline(
+    /// Presigns the request `create_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_image(
         &self,
-        input: CreateImagePipelineRequest,
-    ) -> Result<CreateImagePipelineResponse, RusotoError<CreateImagePipelineError>> {
-        let request_uri = "/CreateImagePipeline";
+        presigner: &rusoto_core::Presigner,
+        input: CreateImageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_image_request(&self, input: CreateImageRequest) -> SignedRequest {
+        let request_uri = "/CreateImage";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_image_pipeline`, also returning the metadata of the response.
+    pub async fn create_image_pipeline_with_metadata(
+        &self,
+        input: CreateImagePipelineRequest,
+    ) -> Result<
+        (CreateImagePipelineResponse, ResponseMetadata),
+        RusotoError<CreateImagePipelineError>,
+    > {
+        let request = self.build_create_image_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateImagePipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateImagePipelineError::from_response(response))
         }
     }
 
-    /// <p> Creates a new image recipe. Image Recipes defines how images are configured, tested and assessed. </p>
-    async fn create_image_recipe(
+    /// Presigns the request `create_image_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_image_pipeline(
         &self,
-        input: CreateImageRecipeRequest,
-    ) -> Result<CreateImageRecipeResponse, RusotoError<CreateImageRecipeError>> {
-        let request_uri = "/CreateImageRecipe";
+        presigner: &rusoto_core::Presigner,
+        input: CreateImagePipelineRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_image_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_image_pipeline_request(
+        &self,
+        input: CreateImagePipelineRequest,
+    ) -> SignedRequest {
+        let request_uri = "/CreateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_image_recipe`, also returning the metadata of the response.
+    pub async fn create_image_recipe_with_metadata(
+        &self,
+        input: CreateImageRecipeRequest,
+    ) -> Result<(CreateImageRecipeResponse, ResponseMetadata), RusotoError<CreateImageRecipeError>>
+    {
+        let request = self.build_create_image_recipe_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateImageRecipeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateImageRecipeError::from_response(response))
         }
     }
 
-    /// <p> Creates a new infrastructure configuration. An infrastructure configuration defines the environment in which your image will be built and tested. </p>
-    async fn create_infrastructure_configuration(
+    /// Presigns the request `create_image_recipe` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_image_recipe(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateImageRecipeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_image_recipe_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_image_recipe_request(&self, input: CreateImageRecipeRequest) -> SignedRequest {
+        let request_uri = "/CreateImageRecipe";
+
+        let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImageRecipe");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `create_infrastructure_configuration`, also returning the metadata of the response.
+    pub async fn create_infrastructure_configuration_with_metadata(
         &self,
         input: CreateInfrastructureConfigurationRequest,
     ) -> Result<
-        CreateInfrastructureConfigurationResponse,
+        (CreateInfrastructureConfigurationResponse, ResponseMetadata),
         RusotoError<CreateInfrastructureConfigurationError>,
     > {
+        let request = self.build_create_infrastructure_configuration_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateInfrastructureConfigurationResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateInfrastructureConfigurationError::from_response(
+                response,
+            ))
+        }
+    }
+
+    /// Presigns the request `create_infrastructure_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_infrastructure_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateInfrastructureConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_infrastructure_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_infrastructure_configuration_request(
+        &self,
+        input: CreateInfrastructureConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = "/CreateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_component`, also returning the metadata of the response.
+    pub async fn delete_component_with_metadata(
+        &self,
+        input: DeleteComponentRequest,
+    ) -> Result<(DeleteComponentResponse, ResponseMetadata), RusotoError<DeleteComponentError>>
+    {
+        let request = self.build_delete_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateInfrastructureConfigurationResponse, _>()?;
+                .deserialize::<DeleteComponentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateInfrastructureConfigurationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteComponentError::from_response(response))
         }
     }
 
-    /// <p> Deletes a component build version. </p>
-    async fn delete_component(
+    /// Presigns the request `delete_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteComponentRequest,
-    ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_component_request(&self, input: DeleteComponentRequest) -> SignedRequest {
         let request_uri = "/DeleteComponent";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6000,34 +6818,63 @@ impl ImageBuilder for ImageBuilderClient {
         );
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_distribution_configuration`, also returning the metadata of the response.
+    pub async fn delete_distribution_configuration_with_metadata(
+        &self,
+        input: DeleteDistributionConfigurationRequest,
+    ) -> Result<
+        (DeleteDistributionConfigurationResponse, ResponseMetadata),
+        RusotoError<DeleteDistributionConfigurationError>,
+    > {
+        let request = self.build_delete_distribution_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteComponentResponse, _>()?;
+                .deserialize::<DeleteDistributionConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteComponentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDistributionConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p> Deletes a distribution configuration. </p>
-    async fn delete_distribution_configuration(
+    /// Presigns the request `delete_distribution_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_distribution_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDistributionConfigurationRequest,
-    ) -> Result<
-        DeleteDistributionConfigurationResponse,
-        RusotoError<DeleteDistributionConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_distribution_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_distribution_configuration_request(
+        &self,
+        input: DeleteDistributionConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = "/DeleteDistributionConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6037,129 +6884,235 @@ impl ImageBuilder for ImageBuilderClient {
         );
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_image`, also returning the metadata of the response.
+    pub async fn delete_image_with_metadata(
+        &self,
+        input: DeleteImageRequest,
+    ) -> Result<(DeleteImageResponse, ResponseMetadata), RusotoError<DeleteImageError>> {
+        let request = self.build_delete_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDistributionConfigurationResponse, _>()?;
+                .deserialize::<DeleteImageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDistributionConfigurationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteImageError::from_response(response))
         }
     }
 
-    /// <p> Deletes an image. </p>
-    async fn delete_image(
+    /// Presigns the request `delete_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_image(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteImageRequest,
-    ) -> Result<DeleteImageResponse, RusotoError<DeleteImageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_image_request(&self, input: DeleteImageRequest) -> SignedRequest {
         let request_uri = "/DeleteImage";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("imageBuildVersionArn", &input.image_build_version_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_image_pipeline`, also returning the metadata of the response.
+    pub async fn delete_image_pipeline_with_metadata(
+        &self,
+        input: DeleteImagePipelineRequest,
+    ) -> Result<
+        (DeleteImagePipelineResponse, ResponseMetadata),
+        RusotoError<DeleteImagePipelineError>,
+    > {
+        let request = self.build_delete_image_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteImageResponse, _>()?;
+                .deserialize::<DeleteImagePipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteImageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteImagePipelineError::from_response(response))
         }
     }
 
-    /// <p> Deletes an image pipeline. </p>
-    async fn delete_image_pipeline(
+    /// Presigns the request `delete_image_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_image_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteImagePipelineRequest,
-    ) -> Result<DeleteImagePipelineResponse, RusotoError<DeleteImagePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_image_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_image_pipeline_request(
+        &self,
+        input: DeleteImagePipelineRequest,
+    ) -> SignedRequest {
         let request_uri = "/DeleteImagePipeline";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("imagePipelineArn", &input.image_pipeline_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_image_recipe`, also returning the metadata of the response.
+    pub async fn delete_image_recipe_with_metadata(
+        &self,
+        input: DeleteImageRecipeRequest,
+    ) -> Result<(DeleteImageRecipeResponse, ResponseMetadata), RusotoError<DeleteImageRecipeError>>
+    {
+        let request = self.build_delete_image_recipe_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteImagePipelineResponse, _>()?;
+                .deserialize::<DeleteImageRecipeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteImagePipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteImageRecipeError::from_response(response))
         }
     }
 
-    /// <p> Deletes an image recipe. </p>
-    async fn delete_image_recipe(
+    /// Presigns the request `delete_image_recipe` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_image_recipe(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteImageRecipeRequest,
-    ) -> Result<DeleteImageRecipeResponse, RusotoError<DeleteImageRecipeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_image_recipe_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_image_recipe_request(&self, input: DeleteImageRecipeRequest) -> SignedRequest {
         let request_uri = "/DeleteImageRecipe";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("imageRecipeArn", &input.image_recipe_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_infrastructure_configuration`, also returning the metadata of the response.
+    pub async fn delete_infrastructure_configuration_with_metadata(
+        &self,
+        input: DeleteInfrastructureConfigurationRequest,
+    ) -> Result<
+        (DeleteInfrastructureConfigurationResponse, ResponseMetadata),
+        RusotoError<DeleteInfrastructureConfigurationError>,
+    > {
+        let request = self.build_delete_infrastructure_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteImageRecipeResponse, _>()?;
+                .deserialize::<DeleteInfrastructureConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteImageRecipeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteInfrastructureConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p> Deletes an infrastructure configuration. </p>
-    async fn delete_infrastructure_configuration(
+    /// Presigns the request `delete_infrastructure_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_infrastructure_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInfrastructureConfigurationRequest,
-    ) -> Result<
-        DeleteInfrastructureConfigurationResponse,
-        RusotoError<DeleteInfrastructureConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_infrastructure_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_infrastructure_configuration_request(
+        &self,
+        input: DeleteInfrastructureConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = "/DeleteInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6169,33 +7122,55 @@ impl ImageBuilder for ImageBuilderClient {
         );
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_component`, also returning the metadata of the response.
+    pub async fn get_component_with_metadata(
+        &self,
+        input: GetComponentRequest,
+    ) -> Result<(GetComponentResponse, ResponseMetadata), RusotoError<GetComponentError>> {
+        let request = self.build_get_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInfrastructureConfigurationResponse, _>()?;
+                .deserialize::<GetComponentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInfrastructureConfigurationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetComponentError::from_response(response))
         }
     }
 
-    /// <p> Gets a component object. </p>
-    async fn get_component(
+    /// Presigns the request `get_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetComponentRequest,
-    ) -> Result<GetComponentResponse, RusotoError<GetComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_component_request(&self, input: GetComponentRequest) -> SignedRequest {
         let request_uri = "/GetComponent";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6205,63 +7180,120 @@ impl ImageBuilder for ImageBuilderClient {
         );
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_component_policy`, also returning the metadata of the response.
+    pub async fn get_component_policy_with_metadata(
+        &self,
+        input: GetComponentPolicyRequest,
+    ) -> Result<(GetComponentPolicyResponse, ResponseMetadata), RusotoError<GetComponentPolicyError>>
+    {
+        let request = self.build_get_component_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetComponentResponse, _>()?;
+                .deserialize::<GetComponentPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetComponentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetComponentPolicyError::from_response(response))
         }
     }
 
-    /// <p> Gets a component policy. </p>
-    async fn get_component_policy(
+    /// Presigns the request `get_component_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_component_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetComponentPolicyRequest,
-    ) -> Result<GetComponentPolicyResponse, RusotoError<GetComponentPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_component_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_component_policy_request(
+        &self,
+        input: GetComponentPolicyRequest,
+    ) -> SignedRequest {
         let request_uri = "/GetComponentPolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetComponentPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("componentArn", &input.component_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_distribution_configuration`, also returning the metadata of the response.
+    pub async fn get_distribution_configuration_with_metadata(
+        &self,
+        input: GetDistributionConfigurationRequest,
+    ) -> Result<
+        (GetDistributionConfigurationResponse, ResponseMetadata),
+        RusotoError<GetDistributionConfigurationError>,
+    > {
+        let request = self.build_get_distribution_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetComponentPolicyResponse, _>()?;
+                .deserialize::<GetDistributionConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetComponentPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDistributionConfigurationError::from_response(response))
         }
     }
 
-    /// <p> Gets a distribution configuration. </p>
-    async fn get_distribution_configuration(
+    /// Presigns the request `get_distribution_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_distribution_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDistributionConfigurationRequest,
-    ) -> Result<GetDistributionConfigurationResponse, RusotoError<GetDistributionConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_distribution_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_distribution_configuration_request(
+        &self,
+        input: GetDistributionConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = "/GetDistributionConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6271,189 +7303,343 @@ impl ImageBuilder for ImageBuilderClient {
         );
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_image`, also returning the metadata of the response.
+    pub async fn get_image_with_metadata(
+        &self,
+        input: GetImageRequest,
+    ) -> Result<(GetImageResponse, ResponseMetadata), RusotoError<GetImageError>> {
+        let request = self.build_get_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDistributionConfigurationResponse, _>()?;
+                .deserialize::<GetImageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDistributionConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetImageError::from_response(response))
         }
     }
 
-    /// <p> Gets an image. </p>
-    async fn get_image(
+    /// Presigns the request `get_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_image(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetImageRequest,
-    ) -> Result<GetImageResponse, RusotoError<GetImageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_image_request(&self, input: GetImageRequest) -> SignedRequest {
         let request_uri = "/GetImage";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("imageBuildVersionArn", &input.image_build_version_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_image_pipeline`, also returning the metadata of the response.
+    pub async fn get_image_pipeline_with_metadata(
+        &self,
+        input: GetImagePipelineRequest,
+    ) -> Result<(GetImagePipelineResponse, ResponseMetadata), RusotoError<GetImagePipelineError>>
+    {
+        let request = self.build_get_image_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetImageResponse, _>()?;
+                .deserialize::<GetImagePipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetImageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetImagePipelineError::from_response(response))
         }
     }
 
-    /// <p> Gets an image pipeline. </p>
-    async fn get_image_pipeline(
+    /// Presigns the request `get_image_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_image_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetImagePipelineRequest,
-    ) -> Result<GetImagePipelineResponse, RusotoError<GetImagePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_image_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_image_pipeline_request(&self, input: GetImagePipelineRequest) -> SignedRequest {
         let request_uri = "/GetImagePipeline";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("imagePipelineArn", &input.image_pipeline_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_image_policy`, also returning the metadata of the response.
+    pub async fn get_image_policy_with_metadata(
+        &self,
+        input: GetImagePolicyRequest,
+    ) -> Result<(GetImagePolicyResponse, ResponseMetadata), RusotoError<GetImagePolicyError>> {
+        let request = self.build_get_image_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetImagePipelineResponse, _>()?;
+                .deserialize::<GetImagePolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetImagePipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetImagePolicyError::from_response(response))
         }
     }
 
-    /// <p> Gets an image policy. </p>
-    async fn get_image_policy(
+    /// Presigns the request `get_image_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_image_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetImagePolicyRequest,
-    ) -> Result<GetImagePolicyResponse, RusotoError<GetImagePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_image_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_image_policy_request(&self, input: GetImagePolicyRequest) -> SignedRequest {
         let request_uri = "/GetImagePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImagePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("imageArn", &input.image_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_image_recipe`, also returning the metadata of the response.
+    pub async fn get_image_recipe_with_metadata(
+        &self,
+        input: GetImageRecipeRequest,
+    ) -> Result<(GetImageRecipeResponse, ResponseMetadata), RusotoError<GetImageRecipeError>> {
+        let request = self.build_get_image_recipe_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetImagePolicyResponse, _>()?;
+                .deserialize::<GetImageRecipeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetImagePolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetImageRecipeError::from_response(response))
         }
     }
 
-    /// <p> Gets an image recipe. </p>
-    async fn get_image_recipe(
+    /// Presigns the request `get_image_recipe` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_image_recipe(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetImageRecipeRequest,
-    ) -> Result<GetImageRecipeResponse, RusotoError<GetImageRecipeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_image_recipe_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_image_recipe_request(&self, input: GetImageRecipeRequest) -> SignedRequest {
         let request_uri = "/GetImageRecipe";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("imageRecipeArn", &input.image_recipe_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_image_recipe_policy`, also returning the metadata of the response.
+    pub async fn get_image_recipe_policy_with_metadata(
+        &self,
+        input: GetImageRecipePolicyRequest,
+    ) -> Result<
+        (GetImageRecipePolicyResponse, ResponseMetadata),
+        RusotoError<GetImageRecipePolicyError>,
+    > {
+        let request = self.build_get_image_recipe_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetImageRecipeResponse, _>()?;
+                .deserialize::<GetImageRecipePolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetImageRecipeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetImageRecipePolicyError::from_response(response))
         }
     }
 
-    /// <p> Gets an image recipe policy. </p>
-    async fn get_image_recipe_policy(
+    /// Presigns the request `get_image_recipe_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_image_recipe_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetImageRecipePolicyRequest,
-    ) -> Result<GetImageRecipePolicyResponse, RusotoError<GetImageRecipePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_image_recipe_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_image_recipe_policy_request(
+        &self,
+        input: GetImageRecipePolicyRequest,
+    ) -> SignedRequest {
         let request_uri = "/GetImageRecipePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImageRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("imageRecipeArn", &input.image_recipe_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_infrastructure_configuration`, also returning the metadata of the response.
+    pub async fn get_infrastructure_configuration_with_metadata(
+        &self,
+        input: GetInfrastructureConfigurationRequest,
+    ) -> Result<
+        (GetInfrastructureConfigurationResponse, ResponseMetadata),
+        RusotoError<GetInfrastructureConfigurationError>,
+    > {
+        let request = self.build_get_infrastructure_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetImageRecipePolicyResponse, _>()?;
+                .deserialize::<GetInfrastructureConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetImageRecipePolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetInfrastructureConfigurationError::from_response(response))
         }
     }
 
-    /// <p> Gets a infrastructure configuration. </p>
-    async fn get_infrastructure_configuration(
+    /// Presigns the request `get_infrastructure_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_infrastructure_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInfrastructureConfigurationRequest,
-    ) -> Result<
-        GetInfrastructureConfigurationResponse,
-        RusotoError<GetInfrastructureConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_infrastructure_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_infrastructure_configuration_request(
+        &self,
+        input: GetInfrastructureConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = "/GetInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6463,518 +7649,976 @@ impl ImageBuilder for ImageBuilderClient {
         );
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `import_component`, also returning the metadata of the response.
+    pub async fn import_component_with_metadata(
+        &self,
+        input: ImportComponentRequest,
+    ) -> Result<(ImportComponentResponse, ResponseMetadata), RusotoError<ImportComponentError>>
+    {
+        let request = self.build_import_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInfrastructureConfigurationResponse, _>()?;
+                .deserialize::<ImportComponentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInfrastructureConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ImportComponentError::from_response(response))
         }
     }
 
-    /// <p> Imports a component and transforms its data into a component document. </p>
-    async fn import_component(
+    /// Presigns the request `import_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportComponentRequest,
-    ) -> Result<ImportComponentResponse, RusotoError<ImportComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_component_request(&self, input: ImportComponentRequest) -> SignedRequest {
         let request_uri = "/ImportComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ImportComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_component_build_versions`, also returning the metadata of the response.
+    pub async fn list_component_build_versions_with_metadata(
+        &self,
+        input: ListComponentBuildVersionsRequest,
+    ) -> Result<
+        (ListComponentBuildVersionsResponse, ResponseMetadata),
+        RusotoError<ListComponentBuildVersionsError>,
+    > {
+        let request = self.build_list_component_build_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportComponentResponse, _>()?;
+                .deserialize::<ListComponentBuildVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportComponentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListComponentBuildVersionsError::from_response(response))
         }
     }
 
-    /// <p> Returns the list of component build versions for the specified semantic version. </p>
-    async fn list_component_build_versions(
+    /// Presigns the request `list_component_build_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_component_build_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListComponentBuildVersionsRequest,
-    ) -> Result<ListComponentBuildVersionsResponse, RusotoError<ListComponentBuildVersionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_component_build_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_component_build_versions_request(
+        &self,
+        input: ListComponentBuildVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/ListComponentBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListComponentBuildVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_components`, also returning the metadata of the response.
+    pub async fn list_components_with_metadata(
+        &self,
+        input: ListComponentsRequest,
+    ) -> Result<(ListComponentsResponse, ResponseMetadata), RusotoError<ListComponentsError>> {
+        let request = self.build_list_components_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListComponentBuildVersionsResponse, _>()?;
+                .deserialize::<ListComponentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListComponentBuildVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListComponentsError::from_response(response))
         }
     }
 
-    /// <p> Returns the list of component build versions for the specified semantic version. </p>
-    async fn list_components(
+    /// Presigns the request `list_components` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_components(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListComponentsRequest,
-    ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_components_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_components_request(&self, input: ListComponentsRequest) -> SignedRequest {
         let request_uri = "/ListComponents";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListComponents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_distribution_configurations`, also returning the metadata of the response.
+    pub async fn list_distribution_configurations_with_metadata(
+        &self,
+        input: ListDistributionConfigurationsRequest,
+    ) -> Result<
+        (ListDistributionConfigurationsResponse, ResponseMetadata),
+        RusotoError<ListDistributionConfigurationsError>,
+    > {
+        let request = self.build_list_distribution_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListComponentsResponse, _>()?;
+                .deserialize::<ListDistributionConfigurationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListComponentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDistributionConfigurationsError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of distribution configurations. </p>
-    async fn list_distribution_configurations(
+    /// Presigns the request `list_distribution_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_distribution_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDistributionConfigurationsRequest,
-    ) -> Result<
-        ListDistributionConfigurationsResponse,
-        RusotoError<ListDistributionConfigurationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_distribution_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_distribution_configurations_request(
+        &self,
+        input: ListDistributionConfigurationsRequest,
+    ) -> SignedRequest {
         let request_uri = "/ListDistributionConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListDistributionConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_image_build_versions`, also returning the metadata of the response.
+    pub async fn list_image_build_versions_with_metadata(
+        &self,
+        input: ListImageBuildVersionsRequest,
+    ) -> Result<
+        (ListImageBuildVersionsResponse, ResponseMetadata),
+        RusotoError<ListImageBuildVersionsError>,
+    > {
+        let request = self.build_list_image_build_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDistributionConfigurationsResponse, _>()?;
+                .deserialize::<ListImageBuildVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDistributionConfigurationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListImageBuildVersionsError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of distribution configurations. </p>
-    async fn list_image_build_versions(
+    /// Presigns the request `list_image_build_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_image_build_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListImageBuildVersionsRequest,
-    ) -> Result<ListImageBuildVersionsResponse, RusotoError<ListImageBuildVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_image_build_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_image_build_versions_request(
+        &self,
+        input: ListImageBuildVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/ListImageBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImageBuildVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_image_pipeline_images`, also returning the metadata of the response.
+    pub async fn list_image_pipeline_images_with_metadata(
+        &self,
+        input: ListImagePipelineImagesRequest,
+    ) -> Result<
+        (ListImagePipelineImagesResponse, ResponseMetadata),
+        RusotoError<ListImagePipelineImagesError>,
+    > {
+        let request = self.build_list_image_pipeline_images_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListImageBuildVersionsResponse, _>()?;
+                .deserialize::<ListImagePipelineImagesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListImageBuildVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListImagePipelineImagesError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of images created by the specified pipeline. </p>
-    async fn list_image_pipeline_images(
+    /// Presigns the request `list_image_pipeline_images` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_image_pipeline_images(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListImagePipelineImagesRequest,
-    ) -> Result<ListImagePipelineImagesResponse, RusotoError<ListImagePipelineImagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_image_pipeline_images_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_image_pipeline_images_request(
+        &self,
+        input: ListImagePipelineImagesRequest,
+    ) -> SignedRequest {
         let request_uri = "/ListImagePipelineImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImagePipelineImages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_image_pipelines`, also returning the metadata of the response.
+    pub async fn list_image_pipelines_with_metadata(
+        &self,
+        input: ListImagePipelinesRequest,
+    ) -> Result<(ListImagePipelinesResponse, ResponseMetadata), RusotoError<ListImagePipelinesError>>
+    {
+        let request = self.build_list_image_pipelines_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListImagePipelineImagesResponse, _>()?;
+                .deserialize::<ListImagePipelinesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListImagePipelineImagesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListImagePipelinesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of image pipelines. </p>
-    async fn list_image_pipelines(
+    /// Presigns the request `list_image_pipelines` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_image_pipelines(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListImagePipelinesRequest,
-    ) -> Result<ListImagePipelinesResponse, RusotoError<ListImagePipelinesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_image_pipelines_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_image_pipelines_request(
+        &self,
+        input: ListImagePipelinesRequest,
+    ) -> SignedRequest {
         let request_uri = "/ListImagePipelines";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImagePipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_image_recipes`, also returning the metadata of the response.
+    pub async fn list_image_recipes_with_metadata(
+        &self,
+        input: ListImageRecipesRequest,
+    ) -> Result<(ListImageRecipesResponse, ResponseMetadata), RusotoError<ListImageRecipesError>>
+    {
+        let request = self.build_list_image_recipes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListImagePipelinesResponse, _>()?;
+                .deserialize::<ListImageRecipesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListImagePipelinesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListImageRecipesError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of image recipes. </p>
-    async fn list_image_recipes(
+    /// Presigns the request `list_image_recipes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_image_recipes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListImageRecipesRequest,
-    ) -> Result<ListImageRecipesResponse, RusotoError<ListImageRecipesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_image_recipes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_image_recipes_request(&self, input: ListImageRecipesRequest) -> SignedRequest {
         let request_uri = "/ListImageRecipes";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImageRecipes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_images`, also returning the metadata of the response.
+    pub async fn list_images_with_metadata(
+        &self,
+        input: ListImagesRequest,
+    ) -> Result<(ListImagesResponse, ResponseMetadata), RusotoError<ListImagesError>> {
+        let request = self.build_list_images_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListImageRecipesResponse, _>()?;
+                .deserialize::<ListImagesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListImageRecipesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListImagesError::from_response(response))
         }
     }
 
-    /// <p> Returns the list of image build versions for the specified semantic version. </p>
-    async fn list_images(
+    /// Presigns the request `list_images` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_images(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListImagesRequest,
-    ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_images_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_images_request(&self, input: ListImagesRequest) -> SignedRequest {
         let request_uri = "/ListImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_infrastructure_configurations`, also returning the metadata of the response.
+    pub async fn list_infrastructure_configurations_with_metadata(
+        &self,
+        input: ListInfrastructureConfigurationsRequest,
+    ) -> Result<
+        (ListInfrastructureConfigurationsResponse, ResponseMetadata),
+        RusotoError<ListInfrastructureConfigurationsError>,
+    > {
+        let request = self.build_list_infrastructure_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListImagesResponse, _>()?;
+                .deserialize::<ListInfrastructureConfigurationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListImagesError::from_response(response))
-        }
-    }
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListInfrastructureConfigurationsError::from_response(
+                response,
+            ))
+        }
+    }
 
-    /// <p> Returns a list of infrastructure configurations. </p>
-    async fn list_infrastructure_configurations(
+    /// Presigns the request `list_infrastructure_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_infrastructure_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInfrastructureConfigurationsRequest,
-    ) -> Result<
-        ListInfrastructureConfigurationsResponse,
-        RusotoError<ListInfrastructureConfigurationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_infrastructure_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_infrastructure_configurations_request(
+        &self,
+        input: ListInfrastructureConfigurationsRequest,
+    ) -> SignedRequest {
         let request_uri = "/ListInfrastructureConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListInfrastructureConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInfrastructureConfigurationsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInfrastructureConfigurationsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p> Returns the list of tags for the specified resource. </p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `put_component_policy`, also returning the metadata of the response.
+    pub async fn put_component_policy_with_metadata(
+        &self,
+        input: PutComponentPolicyRequest,
+    ) -> Result<(PutComponentPolicyResponse, ResponseMetadata), RusotoError<PutComponentPolicyError>>
+    {
+        let request = self.build_put_component_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<PutComponentPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutComponentPolicyError::from_response(response))
         }
     }
 
-    /// <p> Applies a policy to a component. </p>
-    async fn put_component_policy(
+    /// Presigns the request `put_component_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_component_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutComponentPolicyRequest,
-    ) -> Result<PutComponentPolicyResponse, RusotoError<PutComponentPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_component_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_component_policy_request(
+        &self,
+        input: PutComponentPolicyRequest,
+    ) -> SignedRequest {
         let request_uri = "/PutComponentPolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutComponentPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_image_policy`, also returning the metadata of the response.
+    pub async fn put_image_policy_with_metadata(
+        &self,
+        input: PutImagePolicyRequest,
+    ) -> Result<(PutImagePolicyResponse, ResponseMetadata), RusotoError<PutImagePolicyError>> {
+        let request = self.build_put_image_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutComponentPolicyResponse, _>()?;
+                .deserialize::<PutImagePolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutComponentPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutImagePolicyError::from_response(response))
         }
     }
 
-    /// <p> Applies a policy to an image. </p>
-    async fn put_image_policy(
+    /// Presigns the request `put_image_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_image_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutImagePolicyRequest,
-    ) -> Result<PutImagePolicyResponse, RusotoError<PutImagePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_image_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_image_policy_request(&self, input: PutImagePolicyRequest) -> SignedRequest {
         let request_uri = "/PutImagePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutImagePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_image_recipe_policy`, also returning the metadata of the response.
+    pub async fn put_image_recipe_policy_with_metadata(
+        &self,
+        input: PutImageRecipePolicyRequest,
+    ) -> Result<
+        (PutImageRecipePolicyResponse, ResponseMetadata),
+        RusotoError<PutImageRecipePolicyError>,
+    > {
+        let request = self.build_put_image_recipe_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutImagePolicyResponse, _>()?;
+                .deserialize::<PutImageRecipePolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutImagePolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutImageRecipePolicyError::from_response(response))
         }
     }
 
-    /// <p> Applies a policy to an image recipe. </p>
-    async fn put_image_recipe_policy(
+    /// Presigns the request `put_image_recipe_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_image_recipe_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutImageRecipePolicyRequest,
-    ) -> Result<PutImageRecipePolicyResponse, RusotoError<PutImageRecipePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_image_recipe_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_image_recipe_policy_request(
+        &self,
+        input: PutImageRecipePolicyRequest,
+    ) -> SignedRequest {
         let request_uri = "/PutImageRecipePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutImageRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_image_pipeline_execution`, also returning the metadata of the response.
+    pub async fn start_image_pipeline_execution_with_metadata(
+        &self,
+        input: StartImagePipelineExecutionRequest,
+    ) -> Result<
+        (StartImagePipelineExecutionResponse, ResponseMetadata),
+        RusotoError<StartImagePipelineExecutionError>,
+    > {
+        let request = self.build_start_image_pipeline_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutImageRecipePolicyResponse, _>()?;
+                .deserialize::<StartImagePipelineExecutionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutImageRecipePolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartImagePipelineExecutionError::from_response(response))
         }
     }
 
-    /// <p> Manually triggers a pipeline to create an image. </p>
-    async fn start_image_pipeline_execution(
+    /// Presigns the request `start_image_pipeline_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_image_pipeline_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartImagePipelineExecutionRequest,
-    ) -> Result<StartImagePipelineExecutionResponse, RusotoError<StartImagePipelineExecutionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_image_pipeline_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_image_pipeline_execution_request(
+        &self,
+        input: StartImagePipelineExecutionRequest,
+    ) -> SignedRequest {
         let request_uri = "/StartImagePipelineExecution";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("StartImagePipelineExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartImagePipelineExecutionResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartImagePipelineExecutionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p> Adds a tag to a resource. </p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p> Removes a tag from a resource. </p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6983,120 +8627,1010 @@ impl ImageBuilder for ImageBuilderClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
-
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+        request
     }
 
-    /// <p> Updates a new distribution configuration. Distribution configurations define and configure the outputs of your pipeline. </p>
-    async fn update_distribution_configuration(
+    /// Calls `update_distribution_configuration`, also returning the metadata of the response.
+    pub async fn update_distribution_configuration_with_metadata(
         &self,
         input: UpdateDistributionConfigurationRequest,
     ) -> Result<
-        UpdateDistributionConfigurationResponse,
+        (UpdateDistributionConfigurationResponse, ResponseMetadata),
         RusotoError<UpdateDistributionConfigurationError>,
     > {
-        let request_uri = "/UpdateDistributionConfiguration";
-
-        let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+        let request = self.build_update_distribution_configuration_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDistributionConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateDistributionConfigurationError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p> Updates a new image pipeline. Image pipelines enable you to automate the creation and distribution of images. </p>
-    async fn update_image_pipeline(
+    /// Presigns the request `update_distribution_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_distribution_configuration(
         &self,
-        input: UpdateImagePipelineRequest,
-    ) -> Result<UpdateImagePipelineResponse, RusotoError<UpdateImagePipelineError>> {
-        let request_uri = "/UpdateImagePipeline";
+        presigner: &rusoto_core::Presigner,
+        input: UpdateDistributionConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_distribution_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_distribution_configuration_request(
+        &self,
+        input: UpdateDistributionConfigurationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/UpdateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_image_pipeline`, also returning the metadata of the response.
+    pub async fn update_image_pipeline_with_metadata(
+        &self,
+        input: UpdateImagePipelineRequest,
+    ) -> Result<
+        (UpdateImagePipelineResponse, ResponseMetadata),
+        RusotoError<UpdateImagePipelineError>,
+    > {
+        let request = self.build_update_image_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateImagePipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateImagePipelineError::from_response(response))
         }
     }
 
-    /// <p> Updates a new infrastructure configuration. An infrastructure configuration defines the environment in which your image will be built and tested. </p>
-    async fn update_infrastructure_configuration(
+    /// Presigns the request `update_image_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_image_pipeline(
         &self,
-        input: UpdateInfrastructureConfigurationRequest,
-    ) -> Result<
-        UpdateInfrastructureConfigurationResponse,
-        RusotoError<UpdateInfrastructureConfigurationError>,
-    > {
-        let request_uri = "/UpdateInfrastructureConfiguration";
+        presigner: &rusoto_core::Presigner,
+        input: UpdateImagePipelineRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_image_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_image_pipeline_request(
+        &self,
+        input: UpdateImagePipelineRequest,
+    ) -> SignedRequest {
+        let request_uri = "/UpdateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_infrastructure_configuration`, also returning the metadata of the response.
+    pub async fn update_infrastructure_configuration_with_metadata(
+        &self,
+        input: UpdateInfrastructureConfigurationRequest,
+    ) -> Result<
+        (UpdateInfrastructureConfigurationResponse, ResponseMetadata),
+        RusotoError<UpdateInfrastructureConfigurationError>,
+    > {
+        let request = self.build_update_infrastructure_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateInfrastructureConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateInfrastructureConfigurationError::from_response(
                 response,
             ))
         }
     }
+
+    /// Presigns the request `update_infrastructure_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_infrastructure_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateInfrastructureConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_infrastructure_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_infrastructure_configuration_request(
+        &self,
+        input: UpdateInfrastructureConfigurationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/UpdateInfrastructureConfiguration";
+
+        let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateInfrastructureConfiguration");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// The pages of `ListComponentBuildVersions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_component_build_versions_pages(
+        &self,
+        input: ListComponentBuildVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListComponentBuildVersionsResponse,
+            RusotoError<ListComponentBuildVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImageBuilderClient, input| async move {
+                client.list_component_build_versions(input).await
+            },
+            |input: &ListComponentBuildVersionsRequest,
+             output: &ListComponentBuildVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListComponentBuildVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `componentSummaryList` of every page of `ListComponentBuildVersions` results for `input`, as `list_component_build_versions_pages` requests them.
+    pub fn list_component_build_versions_stream(
+        &self,
+        input: ListComponentBuildVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ComponentSummary, RusotoError<ListComponentBuildVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_component_build_versions_pages(input), |page| {
+            page.component_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListComponents` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_components_pages(
+        &self,
+        input: ListComponentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListComponentsResponse, RusotoError<ListComponentsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImageBuilderClient, input| async move { client.list_components(input).await },
+            |input: &ListComponentsRequest, output: &ListComponentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListComponentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `componentVersionList` of every page of `ListComponents` results for `input`, as `list_components_pages` requests them.
+    pub fn list_components_stream(
+        &self,
+        input: ListComponentsRequest,
+    ) -> impl futures::Stream<Item = Result<ComponentVersion, RusotoError<ListComponentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_components_pages(input), |page| {
+            page.component_version_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDistributionConfigurations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_distribution_configurations_pages(
+        &self,
+        input: ListDistributionConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListDistributionConfigurationsResponse,
+            RusotoError<ListDistributionConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImageBuilderClient, input| async move {
+                client.list_distribution_configurations(input).await
+            },
+            |input: &ListDistributionConfigurationsRequest,
+             output: &ListDistributionConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDistributionConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `distributionConfigurationSummaryList` of every page of `ListDistributionConfigurations` results for `input`, as `list_distribution_configurations_pages` requests them.
+    pub fn list_distribution_configurations_stream(
+        &self,
+        input: ListDistributionConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DistributionConfigurationSummary,
+            RusotoError<ListDistributionConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(self.list_distribution_configurations_pages(input), |page| {
+            page.distribution_configuration_summary_list
+                .unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListImageBuildVersions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_image_build_versions_pages(
+        &self,
+        input: ListImageBuildVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListImageBuildVersionsResponse, RusotoError<ListImageBuildVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImageBuilderClient, input| async move {
+                client.list_image_build_versions(input).await
+            },
+            |input: &ListImageBuildVersionsRequest, output: &ListImageBuildVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListImageBuildVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `imageSummaryList` of every page of `ListImageBuildVersions` results for `input`, as `list_image_build_versions_pages` requests them.
+    pub fn list_image_build_versions_stream(
+        &self,
+        input: ListImageBuildVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<ImageSummary, RusotoError<ListImageBuildVersionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_image_build_versions_pages(input), |page| {
+            page.image_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListImagePipelineImages` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_image_pipeline_images_pages(
+        &self,
+        input: ListImagePipelineImagesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListImagePipelineImagesResponse, RusotoError<ListImagePipelineImagesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImageBuilderClient, input| async move {
+                client.list_image_pipeline_images(input).await
+            },
+            |input: &ListImagePipelineImagesRequest, output: &ListImagePipelineImagesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListImagePipelineImagesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `imageSummaryList` of every page of `ListImagePipelineImages` results for `input`, as `list_image_pipeline_images_pages` requests them.
+    pub fn list_image_pipeline_images_stream(
+        &self,
+        input: ListImagePipelineImagesRequest,
+    ) -> impl futures::Stream<Item = Result<ImageSummary, RusotoError<ListImagePipelineImagesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_image_pipeline_images_pages(input), |page| {
+            page.image_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListImagePipelines` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_image_pipelines_pages(
+        &self,
+        input: ListImagePipelinesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListImagePipelinesResponse, RusotoError<ListImagePipelinesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImageBuilderClient, input| async move { client.list_image_pipelines(input).await },
+            |input: &ListImagePipelinesRequest, output: &ListImagePipelinesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListImagePipelinesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `imagePipelineList` of every page of `ListImagePipelines` results for `input`, as `list_image_pipelines_pages` requests them.
+    pub fn list_image_pipelines_stream(
+        &self,
+        input: ListImagePipelinesRequest,
+    ) -> impl futures::Stream<Item = Result<ImagePipeline, RusotoError<ListImagePipelinesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_image_pipelines_pages(input), |page| {
+            page.image_pipeline_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListImageRecipes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_image_recipes_pages(
+        &self,
+        input: ListImageRecipesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListImageRecipesResponse, RusotoError<ListImageRecipesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImageBuilderClient, input| async move { client.list_image_recipes(input).await },
+            |input: &ListImageRecipesRequest, output: &ListImageRecipesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListImageRecipesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `imageRecipeSummaryList` of every page of `ListImageRecipes` results for `input`, as `list_image_recipes_pages` requests them.
+    pub fn list_image_recipes_stream(
+        &self,
+        input: ListImageRecipesRequest,
+    ) -> impl futures::Stream<Item = Result<ImageRecipeSummary, RusotoError<ListImageRecipesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_image_recipes_pages(input), |page| {
+            page.image_recipe_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListImages` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_images_pages(
+        &self,
+        input: ListImagesRequest,
+    ) -> impl futures::Stream<Item = Result<ListImagesResponse, RusotoError<ListImagesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImageBuilderClient, input| async move { client.list_images(input).await },
+            |input: &ListImagesRequest, output: &ListImagesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListImagesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `imageVersionList` of every page of `ListImages` results for `input`, as `list_images_pages` requests them.
+    pub fn list_images_stream(
+        &self,
+        input: ListImagesRequest,
+    ) -> impl futures::Stream<Item = Result<ImageVersion, RusotoError<ListImagesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_images_pages(input), |page| {
+            page.image_version_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListInfrastructureConfigurations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_infrastructure_configurations_pages(
+        &self,
+        input: ListInfrastructureConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListInfrastructureConfigurationsResponse,
+            RusotoError<ListInfrastructureConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ImageBuilderClient, input| async move {
+                client.list_infrastructure_configurations(input).await
+            },
+            |input: &ListInfrastructureConfigurationsRequest,
+             output: &ListInfrastructureConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListInfrastructureConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `infrastructureConfigurationSummaryList` of every page of `ListInfrastructureConfigurations` results for `input`, as `list_infrastructure_configurations_pages` requests them.
+    pub fn list_infrastructure_configurations_stream(
+        &self,
+        input: ListInfrastructureConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            InfrastructureConfigurationSummary,
+            RusotoError<ListInfrastructureConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_infrastructure_configurations_pages(input),
+            |page| {
+                page.infrastructure_configuration_summary_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+}
+
+#[async_trait]
+impl ImageBuilder for ImageBuilderClient {
+    /// <p>CancelImageCreation cancels the creation of Image. This operation may only be used on images in a non-terminal state.</p>
+    async fn cancel_image_creation(
+        &self,
+        input: CancelImageCreationRequest,
+    ) -> Result<CancelImageCreationResponse, RusotoError<CancelImageCreationError>> {
+        self.cancel_image_creation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new component that can be used to build, validate, test and assess your image.</p>
+    async fn create_component(
+        &self,
+        input: CreateComponentRequest,
+    ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
+        self.create_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates a new distribution configuration. Distribution configurations define and configure the outputs of your pipeline. </p>
+    async fn create_distribution_configuration(
+        &self,
+        input: CreateDistributionConfigurationRequest,
+    ) -> Result<
+        CreateDistributionConfigurationResponse,
+        RusotoError<CreateDistributionConfigurationError>,
+    > {
+        self.create_distribution_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates a new image. This request will create a new image along with all of the configured output resources defined in the distribution configuration. </p>
+    async fn create_image(
+        &self,
+        input: CreateImageRequest,
+    ) -> Result<CreateImageResponse, RusotoError<CreateImageError>> {
+        self.create_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates a new image pipeline. Image pipelines enable you to automate the creation and distribution of images. </p>
+    async fn create_image_pipeline(
+        &self,
+        input: CreateImagePipelineRequest,
+    ) -> Result<CreateImagePipelineResponse, RusotoError<CreateImagePipelineError>> {
+        self.create_image_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates a new image recipe. Image Recipes defines how images are configured, tested and assessed. </p>
+    async fn create_image_recipe(
+        &self,
+        input: CreateImageRecipeRequest,
+    ) -> Result<CreateImageRecipeResponse, RusotoError<CreateImageRecipeError>> {
+        self.create_image_recipe_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates a new infrastructure configuration. An infrastructure configuration defines the environment in which your image will be built and tested. </p>
+    async fn create_infrastructure_configuration(
+        &self,
+        input: CreateInfrastructureConfigurationRequest,
+    ) -> Result<
+        CreateInfrastructureConfigurationResponse,
+        RusotoError<CreateInfrastructureConfigurationError>,
+    > {
+        self.create_infrastructure_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a component build version. </p>
+    async fn delete_component(
+        &self,
+        input: DeleteComponentRequest,
+    ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
+        self.delete_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a distribution configuration. </p>
+    async fn delete_distribution_configuration(
+        &self,
+        input: DeleteDistributionConfigurationRequest,
+    ) -> Result<
+        DeleteDistributionConfigurationResponse,
+        RusotoError<DeleteDistributionConfigurationError>,
+    > {
+        self.delete_distribution_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes an image. </p>
+    async fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> Result<DeleteImageResponse, RusotoError<DeleteImageError>> {
+        self.delete_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes an image pipeline. </p>
+    async fn delete_image_pipeline(
+        &self,
+        input: DeleteImagePipelineRequest,
+    ) -> Result<DeleteImagePipelineResponse, RusotoError<DeleteImagePipelineError>> {
+        self.delete_image_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes an image recipe. </p>
+    async fn delete_image_recipe(
+        &self,
+        input: DeleteImageRecipeRequest,
+    ) -> Result<DeleteImageRecipeResponse, RusotoError<DeleteImageRecipeError>> {
+        self.delete_image_recipe_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes an infrastructure configuration. </p>
+    async fn delete_infrastructure_configuration(
+        &self,
+        input: DeleteInfrastructureConfigurationRequest,
+    ) -> Result<
+        DeleteInfrastructureConfigurationResponse,
+        RusotoError<DeleteInfrastructureConfigurationError>,
+    > {
+        self.delete_infrastructure_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets a component object. </p>
+    async fn get_component(
+        &self,
+        input: GetComponentRequest,
+    ) -> Result<GetComponentResponse, RusotoError<GetComponentError>> {
+        self.get_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets a component policy. </p>
+    async fn get_component_policy(
+        &self,
+        input: GetComponentPolicyRequest,
+    ) -> Result<GetComponentPolicyResponse, RusotoError<GetComponentPolicyError>> {
+        self.get_component_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets a distribution configuration. </p>
+    async fn get_distribution_configuration(
+        &self,
+        input: GetDistributionConfigurationRequest,
+    ) -> Result<GetDistributionConfigurationResponse, RusotoError<GetDistributionConfigurationError>>
+    {
+        self.get_distribution_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets an image. </p>
+    async fn get_image(
+        &self,
+        input: GetImageRequest,
+    ) -> Result<GetImageResponse, RusotoError<GetImageError>> {
+        self.get_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets an image pipeline. </p>
+    async fn get_image_pipeline(
+        &self,
+        input: GetImagePipelineRequest,
+    ) -> Result<GetImagePipelineResponse, RusotoError<GetImagePipelineError>> {
+        self.get_image_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets an image policy. </p>
+    async fn get_image_policy(
+        &self,
+        input: GetImagePolicyRequest,
+    ) -> Result<GetImagePolicyResponse, RusotoError<GetImagePolicyError>> {
+        self.get_image_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets an image recipe. </p>
+    async fn get_image_recipe(
+        &self,
+        input: GetImageRecipeRequest,
+    ) -> Result<GetImageRecipeResponse, RusotoError<GetImageRecipeError>> {
+        self.get_image_recipe_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets an image recipe policy. </p>
+    async fn get_image_recipe_policy(
+        &self,
+        input: GetImageRecipePolicyRequest,
+    ) -> Result<GetImageRecipePolicyResponse, RusotoError<GetImageRecipePolicyError>> {
+        self.get_image_recipe_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets a infrastructure configuration. </p>
+    async fn get_infrastructure_configuration(
+        &self,
+        input: GetInfrastructureConfigurationRequest,
+    ) -> Result<
+        GetInfrastructureConfigurationResponse,
+        RusotoError<GetInfrastructureConfigurationError>,
+    > {
+        self.get_infrastructure_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Imports a component and transforms its data into a component document. </p>
+    async fn import_component(
+        &self,
+        input: ImportComponentRequest,
+    ) -> Result<ImportComponentResponse, RusotoError<ImportComponentError>> {
+        self.import_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns the list of component build versions for the specified semantic version. </p>
+    async fn list_component_build_versions(
+        &self,
+        input: ListComponentBuildVersionsRequest,
+    ) -> Result<ListComponentBuildVersionsResponse, RusotoError<ListComponentBuildVersionsError>>
+    {
+        self.list_component_build_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns the list of component build versions for the specified semantic version. </p>
+    async fn list_components(
+        &self,
+        input: ListComponentsRequest,
+    ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
+        self.list_components_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of distribution configurations. </p>
+    async fn list_distribution_configurations(
+        &self,
+        input: ListDistributionConfigurationsRequest,
+    ) -> Result<
+        ListDistributionConfigurationsResponse,
+        RusotoError<ListDistributionConfigurationsError>,
+    > {
+        self.list_distribution_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of distribution configurations. </p>
+    async fn list_image_build_versions(
+        &self,
+        input: ListImageBuildVersionsRequest,
+    ) -> Result<ListImageBuildVersionsResponse, RusotoError<ListImageBuildVersionsError>> {
+        self.list_image_build_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of images created by the specified pipeline. </p>
+    async fn list_image_pipeline_images(
+        &self,
+        input: ListImagePipelineImagesRequest,
+    ) -> Result<ListImagePipelineImagesResponse, RusotoError<ListImagePipelineImagesError>> {
+        self.list_image_pipeline_images_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of image pipelines. </p>
+    async fn list_image_pipelines(
+        &self,
+        input: ListImagePipelinesRequest,
+    ) -> Result<ListImagePipelinesResponse, RusotoError<ListImagePipelinesError>> {
+        self.list_image_pipelines_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of image recipes. </p>
+    async fn list_image_recipes(
+        &self,
+        input: ListImageRecipesRequest,
+    ) -> Result<ListImageRecipesResponse, RusotoError<ListImageRecipesError>> {
+        self.list_image_recipes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns the list of image build versions for the specified semantic version. </p>
+    async fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
+        self.list_images_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of infrastructure configurations. </p>
+    async fn list_infrastructure_configurations(
+        &self,
+        input: ListInfrastructureConfigurationsRequest,
+    ) -> Result<
+        ListInfrastructureConfigurationsResponse,
+        RusotoError<ListInfrastructureConfigurationsError>,
+    > {
+        self.list_infrastructure_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns the list of tags for the specified resource. </p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Applies a policy to a component. </p>
+    async fn put_component_policy(
+        &self,
+        input: PutComponentPolicyRequest,
+    ) -> Result<PutComponentPolicyResponse, RusotoError<PutComponentPolicyError>> {
+        self.put_component_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Applies a policy to an image. </p>
+    async fn put_image_policy(
+        &self,
+        input: PutImagePolicyRequest,
+    ) -> Result<PutImagePolicyResponse, RusotoError<PutImagePolicyError>> {
+        self.put_image_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Applies a policy to an image recipe. </p>
+    async fn put_image_recipe_policy(
+        &self,
+        input: PutImageRecipePolicyRequest,
+    ) -> Result<PutImageRecipePolicyResponse, RusotoError<PutImageRecipePolicyError>> {
+        self.put_image_recipe_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Manually triggers a pipeline to create an image. </p>
+    async fn start_image_pipeline_execution(
+        &self,
+        input: StartImagePipelineExecutionRequest,
+    ) -> Result<StartImagePipelineExecutionResponse, RusotoError<StartImagePipelineExecutionError>>
+    {
+        self.start_image_pipeline_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Adds a tag to a resource. </p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Removes a tag from a resource. </p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Updates a new distribution configuration. Distribution configurations define and configure the outputs of your pipeline. </p>
+    async fn update_distribution_configuration(
+        &self,
+        input: UpdateDistributionConfigurationRequest,
+    ) -> Result<
+        UpdateDistributionConfigurationResponse,
+        RusotoError<UpdateDistributionConfigurationError>,
+    > {
+        self.update_distribution_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Updates a new image pipeline. Image pipelines enable you to automate the creation and distribution of images. </p>
+    async fn update_image_pipeline(
+        &self,
+        input: UpdateImagePipelineRequest,
+    ) -> Result<UpdateImagePipelineResponse, RusotoError<UpdateImagePipelineError>> {
+        self.update_image_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Updates a new infrastructure configuration. An infrastructure configuration defines the environment in which your image will be built and tested. </p>
+    async fn update_infrastructure_configuration(
+        &self,
+        input: UpdateInfrastructureConfigurationRequest,
+    ) -> Result<
+        UpdateInfrastructureConfigurationResponse,
+        RusotoError<UpdateInfrastructureConfigurationError>,
+    > {
+        self.update_infrastructure_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -885,6 +885,7 @@ impl WarningMessageDeserializer {
 }
 /// Errors returned by CancelJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelJobError {
     /// <p>The specified job ID has been canceled and is no longer valid.</p>
     CanceledJobId(String),
@@ -898,6 +899,8 @@ pub enum CancelJobError {
     InvalidVersion(String),
     /// <p>AWS Import/Export cannot cancel the job</p>
     UnableToCancelJobId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelJobError {
@@ -909,36 +912,42 @@ impl CancelJobError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(CancelJobError::CanceledJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelJobError::CanceledJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(CancelJobError::ExpiredJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelJobError::ExpiredJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(CancelJobError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelJobError::InvalidAccessKeyId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(CancelJobError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelJobError::InvalidJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(CancelJobError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelJobError::InvalidVersion(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnableToCancelJobIdException" => {
-                        return RusotoError::Service(CancelJobError::UnableToCancelJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelJobError::UnableToCancelJobId(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CancelJobError::Unhandled, &res),
                 }
             }
         }
@@ -963,12 +972,14 @@ impl fmt::Display for CancelJobError {
             CancelJobError::InvalidJobId(ref cause) => write!(f, "{}", cause),
             CancelJobError::InvalidVersion(ref cause) => write!(f, "{}", cause),
             CancelJobError::UnableToCancelJobId(ref cause) => write!(f, "{}", cause),
+            CancelJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelJobError {}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateJobError {
     /// <p>The account specified does not have the appropriate bucket permissions.</p>
     BucketPermission(String),
@@ -1002,6 +1013,8 @@ pub enum CreateJobError {
     MultipleRegions(String),
     /// <p>The specified bucket does not exist. Create the specified bucket or change the manifest&#39;s bucket, exportBucket, or logBucket field to a bucket that the account, as specified by the manifest&#39;s Access Key ID, has write permissions to.</p>
     NoSuchBucket(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateJobError {
@@ -1013,86 +1026,102 @@ impl CreateJobError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketPermissionException" => {
-                        return RusotoError::Service(CreateJobError::BucketPermission(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::BucketPermission(parsed_error.message),
+                            &res,
+                        )
                     }
                     "CreateJobQuotaExceededException" => {
-                        return RusotoError::Service(CreateJobError::CreateJobQuotaExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::CreateJobQuotaExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(CreateJobError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::InvalidAccessKeyId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidAddressException" => {
-                        return RusotoError::Service(CreateJobError::InvalidAddress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::InvalidAddress(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidCustomsException" => {
-                        return RusotoError::Service(CreateJobError::InvalidCustoms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::InvalidCustoms(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidFileSystemException" => {
-                        return RusotoError::Service(CreateJobError::InvalidFileSystem(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::InvalidFileSystem(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(CreateJobError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::InvalidJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidManifestFieldException" => {
-                        return RusotoError::Service(CreateJobError::InvalidManifestField(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::InvalidManifestField(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(CreateJobError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(CreateJobError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::InvalidVersion(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MalformedManifestException" => {
-                        return RusotoError::Service(CreateJobError::MalformedManifest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::MalformedManifest(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingCustomsException" => {
-                        return RusotoError::Service(CreateJobError::MissingCustoms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::MissingCustoms(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingManifestFieldException" => {
-                        return RusotoError::Service(CreateJobError::MissingManifestField(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::MissingManifestField(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameterException" => {
-                        return RusotoError::Service(CreateJobError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MultipleRegionsException" => {
-                        return RusotoError::Service(CreateJobError::MultipleRegions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::MultipleRegions(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchBucketException" => {
-                        return RusotoError::Service(CreateJobError::NoSuchBucket(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateJobError::NoSuchBucket(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateJobError::Unhandled, &res),
                 }
             }
         }
@@ -1127,12 +1156,14 @@ impl fmt::Display for CreateJobError {
             CreateJobError::MissingParameter(ref cause) => write!(f, "{}", cause),
             CreateJobError::MultipleRegions(ref cause) => write!(f, "{}", cause),
             CreateJobError::NoSuchBucket(ref cause) => write!(f, "{}", cause),
+            CreateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateJobError {}
 /// Errors returned by GetShippingLabel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetShippingLabelError {
     /// <p>The specified job ID has been canceled and is no longer valid.</p>
     CanceledJobId(String),
@@ -1148,6 +1179,8 @@ pub enum GetShippingLabelError {
     InvalidParameter(String),
     /// <p>The client tool version is invalid.</p>
     InvalidVersion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetShippingLabelError {
@@ -1159,41 +1192,48 @@ impl GetShippingLabelError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::CanceledJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetShippingLabelError::CanceledJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::ExpiredJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetShippingLabelError::ExpiredJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetShippingLabelError::InvalidAccessKeyId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidAddressException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidAddress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetShippingLabelError::InvalidAddress(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetShippingLabelError::InvalidJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetShippingLabelError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetShippingLabelError::InvalidVersion(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetShippingLabelError::Unhandled, &res),
                 }
             }
         }
@@ -1219,12 +1259,14 @@ impl fmt::Display for GetShippingLabelError {
             GetShippingLabelError::InvalidJobId(ref cause) => write!(f, "{}", cause),
             GetShippingLabelError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetShippingLabelError::InvalidVersion(ref cause) => write!(f, "{}", cause),
+            GetShippingLabelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetShippingLabelError {}
 /// Errors returned by GetStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetStatusError {
     /// <p>The specified job ID has been canceled and is no longer valid.</p>
     CanceledJobId(String),
@@ -1236,6 +1278,8 @@ pub enum GetStatusError {
     InvalidJobId(String),
     /// <p>The client tool version is invalid.</p>
     InvalidVersion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetStatusError {
@@ -1247,31 +1291,36 @@ impl GetStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(GetStatusError::CanceledJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetStatusError::CanceledJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(GetStatusError::ExpiredJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetStatusError::ExpiredJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(GetStatusError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetStatusError::InvalidAccessKeyId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(GetStatusError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetStatusError::InvalidJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(GetStatusError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetStatusError::InvalidVersion(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetStatusError::Unhandled, &res),
                 }
             }
         }
@@ -1295,12 +1344,14 @@ impl fmt::Display for GetStatusError {
             GetStatusError::InvalidAccessKeyId(ref cause) => write!(f, "{}", cause),
             GetStatusError::InvalidJobId(ref cause) => write!(f, "{}", cause),
             GetStatusError::InvalidVersion(ref cause) => write!(f, "{}", cause),
+            GetStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetStatusError {}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsError {
     /// <p>The AWS Access Key ID specified in the request did not match the manifest&#39;s accessKeyId value. The manifest and the request authentication must use the same AWS Access Key ID.</p>
     InvalidAccessKeyId(String),
@@ -1308,6 +1359,8 @@ pub enum ListJobsError {
     InvalidParameter(String),
     /// <p>The client tool version is invalid.</p>
     InvalidVersion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsError {
@@ -1319,21 +1372,24 @@ impl ListJobsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(ListJobsError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListJobsError::InvalidAccessKeyId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(ListJobsError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListJobsError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(ListJobsError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListJobsError::InvalidVersion(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListJobsError::Unhandled, &res),
                 }
             }
         }
@@ -1355,12 +1411,14 @@ impl fmt::Display for ListJobsError {
             ListJobsError::InvalidAccessKeyId(ref cause) => write!(f, "{}", cause),
             ListJobsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListJobsError::InvalidVersion(ref cause) => write!(f, "{}", cause),
+            ListJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsError {}
 /// Errors returned by UpdateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateJobError {
     /// <p>The account specified does not have the appropriate bucket permissions.</p>
     BucketPermission(String),
@@ -1398,6 +1456,8 @@ pub enum UpdateJobError {
     NoSuchBucket(String),
     /// <p>AWS Import/Export cannot update the job</p>
     UnableToUpdateJobId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateJobError {
@@ -1409,96 +1469,114 @@ impl UpdateJobError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketPermissionException" => {
-                        return RusotoError::Service(UpdateJobError::BucketPermission(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::BucketPermission(parsed_error.message),
+                            &res,
+                        )
                     }
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::CanceledJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::CanceledJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::ExpiredJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::ExpiredJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::InvalidAccessKeyId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidAddressException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidAddress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::InvalidAddress(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidCustomsException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidCustoms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::InvalidCustoms(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidFileSystemException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidFileSystem(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::InvalidFileSystem(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::InvalidJobId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidManifestFieldException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidManifestField(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::InvalidManifestField(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::InvalidVersion(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MalformedManifestException" => {
-                        return RusotoError::Service(UpdateJobError::MalformedManifest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::MalformedManifest(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingCustomsException" => {
-                        return RusotoError::Service(UpdateJobError::MissingCustoms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::MissingCustoms(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingManifestFieldException" => {
-                        return RusotoError::Service(UpdateJobError::MissingManifestField(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::MissingManifestField(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameterException" => {
-                        return RusotoError::Service(UpdateJobError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MultipleRegionsException" => {
-                        return RusotoError::Service(UpdateJobError::MultipleRegions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::MultipleRegions(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchBucketException" => {
-                        return RusotoError::Service(UpdateJobError::NoSuchBucket(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::NoSuchBucket(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnableToUpdateJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::UnableToUpdateJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateJobError::UnableToUpdateJobId(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UpdateJobError::Unhandled, &res),
                 }
             }
         }
@@ -1535,6 +1613,7 @@ impl fmt::Display for UpdateJobError {
             UpdateJobError::MultipleRegions(ref cause) => write!(f, "{}", cause),
             UpdateJobError::NoSuchBucket(ref cause) => write!(f, "{}", cause),
             UpdateJobError::UnableToUpdateJobId(ref cause) => write!(f, "{}", cause),
+            UpdateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1589,6 +1668,7 @@ impl ImportExportClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ImportExportClient {
         ImportExportClient {
             client: Client::shared(),
@@ -1614,40 +1694,49 @@ impl ImportExportClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ImportExportClient {
         ImportExportClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ImportExportClient> {
+        rusoto_core::ClientBuilder::new(ImportExportClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ImportExportClient {
+        ImportExportClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ImportExport for ImportExportClient {
-    /// <p>This operation cancels a specified job. Only the job owner can cancel it. The operation fails if the job has already started or is complete.</p>
-    async fn cancel_job(
+impl ImportExportClient {
+    /// Calls `cancel_job`, also returning the metadata of the response.
+    pub async fn cancel_job_with_metadata(
         &self,
         input: CancelJobInput,
-    ) -> Result<CancelJobOutput, RusotoError<CancelJobError>> {
-        let mut request = SignedRequest::new(
-            "POST",
-            "importexport",
-            &self.region,
-            "/?Operation=CancelJob",
-        );
-        let mut params = Params::new();
-
-        params.put("Action", "CancelJob");
-        params.put("Version", "2010-06-01");
-        CancelJobInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<(CancelJobOutput, ResponseMetadata), RusotoError<CancelJobError>> {
+        let request = self.build_cancel_job_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CancelJobError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -1666,39 +1755,63 @@ impl ImportExport for ImportExportClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>This operation initiates the process of scheduling an upload or download of your data. You include in the request a manifest that describes the data transfer specifics. The response to the request includes a job ID, which you can use in other operations, a signature that you use to identify your storage device, and the address where you should ship your storage device.</p>
-    async fn create_job(
+    /// Presigns the request `cancel_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_job(
         &self,
-        input: CreateJobInput,
-    ) -> Result<CreateJobOutput, RusotoError<CreateJobError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CancelJobInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_job_request(&self, input: CancelJobInput) -> SignedRequest {
         let mut request = SignedRequest::new(
             "POST",
             "importexport",
             &self.region,
-            "/?Operation=CreateJob",
+            "/?Operation=CancelJob",
         );
+        request.set_operation("CancelJob");
         let mut params = Params::new();
 
-        params.put("Action", "CreateJob");
+        params.put("Action", "CancelJob");
         params.put("Version", "2010-06-01");
-        CreateJobInputSerializer::serialize(&mut params, "", &input);
+        CancelJobInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_job`, also returning the metadata of the response.
+    pub async fn create_job_with_metadata(
+        &self,
+        input: CreateJobInput,
+    ) -> Result<(CreateJobOutput, ResponseMetadata), RusotoError<CreateJobError>> {
+        let request = self.build_create_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateJobError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -1717,39 +1830,64 @@ impl ImportExport for ImportExportClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>This operation generates a pre-paid UPS shipping label that you will use to ship your device to AWS for processing.</p>
-    async fn get_shipping_label(
+    /// Presigns the request `create_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_job(
         &self,
-        input: GetShippingLabelInput,
-    ) -> Result<GetShippingLabelOutput, RusotoError<GetShippingLabelError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateJobInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_job_request(&self, input: CreateJobInput) -> SignedRequest {
         let mut request = SignedRequest::new(
             "POST",
             "importexport",
             &self.region,
-            "/?Operation=GetShippingLabel",
+            "/?Operation=CreateJob",
         );
+        request.set_operation("CreateJob");
         let mut params = Params::new();
 
-        params.put("Action", "GetShippingLabel");
+        params.put("Action", "CreateJob");
         params.put("Version", "2010-06-01");
-        GetShippingLabelInputSerializer::serialize(&mut params, "", &input);
+        CreateJobInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_shipping_label`, also returning the metadata of the response.
+    pub async fn get_shipping_label_with_metadata(
+        &self,
+        input: GetShippingLabelInput,
+    ) -> Result<(GetShippingLabelOutput, ResponseMetadata), RusotoError<GetShippingLabelError>>
+    {
+        let request = self.build_get_shipping_label_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetShippingLabelError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -1771,39 +1909,63 @@ impl ImportExport for ImportExportClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>This operation returns information about a job, including where the job is in the processing pipeline, the status of the results, and the signature value associated with the job. You can only return information about jobs you own.</p>
-    async fn get_status(
+    /// Presigns the request `get_shipping_label` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_shipping_label(
         &self,
-        input: GetStatusInput,
-    ) -> Result<GetStatusOutput, RusotoError<GetStatusError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetShippingLabelInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_shipping_label_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_shipping_label_request(&self, input: GetShippingLabelInput) -> SignedRequest {
         let mut request = SignedRequest::new(
             "POST",
             "importexport",
             &self.region,
-            "/?Operation=GetStatus",
+            "/?Operation=GetShippingLabel",
         );
+        request.set_operation("GetShippingLabel");
         let mut params = Params::new();
 
-        params.put("Action", "GetStatus");
+        params.put("Action", "GetShippingLabel");
         params.put("Version", "2010-06-01");
-        GetStatusInputSerializer::serialize(&mut params, "", &input);
+        GetShippingLabelInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_status`, also returning the metadata of the response.
+    pub async fn get_status_with_metadata(
+        &self,
+        input: GetStatusInput,
+    ) -> Result<(GetStatusOutput, ResponseMetadata), RusotoError<GetStatusError>> {
+        let request = self.build_get_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetStatusError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -1822,35 +1984,63 @@ impl ImportExport for ImportExportClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>This operation returns the jobs associated with the requester. AWS Import/Export lists the jobs in reverse chronological order based on the date of creation. For example if Job Test1 was created 2009Dec30 and Test2 was created 2010Feb05, the ListJobs operation would return Test2 followed by Test1.</p>
-    async fn list_jobs(
+    /// Presigns the request `get_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_status(
         &self,
-        input: ListJobsInput,
-    ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
-        let mut request =
-            SignedRequest::new("POST", "importexport", &self.region, "/?Operation=ListJobs");
+        presigner: &rusoto_core::Presigner,
+        input: GetStatusInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_status_request(&self, input: GetStatusInput) -> SignedRequest {
+        let mut request = SignedRequest::new(
+            "POST",
+            "importexport",
+            &self.region,
+            "/?Operation=GetStatus",
+        );
+        request.set_operation("GetStatus");
         let mut params = Params::new();
 
-        params.put("Action", "ListJobs");
+        params.put("Action", "GetStatus");
         params.put("Version", "2010-06-01");
-        ListJobsInputSerializer::serialize(&mut params, "", &input);
+        GetStatusInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_jobs`, also returning the metadata of the response.
+    pub async fn list_jobs_with_metadata(
+        &self,
+        input: ListJobsInput,
+    ) -> Result<(ListJobsOutput, ResponseMetadata), RusotoError<ListJobsError>> {
+        let request = self.build_list_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListJobsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -1869,39 +2059,59 @@ impl ImportExport for ImportExportClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>You use this operation to change the parameters specified in the original manifest file by supplying a new manifest file. The manifest file attached to this request replaces the original manifest file. You can only use the operation after a CreateJob request but before the data transfer starts and you can only use it on jobs you own.</p>
-    async fn update_job(
+    /// Presigns the request `list_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs(
         &self,
-        input: UpdateJobInput,
-    ) -> Result<UpdateJobOutput, RusotoError<UpdateJobError>> {
-        let mut request = SignedRequest::new(
-            "POST",
-            "importexport",
-            &self.region,
-            "/?Operation=UpdateJob",
-        );
+        presigner: &rusoto_core::Presigner,
+        input: ListJobsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_request(&self, input: ListJobsInput) -> SignedRequest {
+        let mut request =
+            SignedRequest::new("POST", "importexport", &self.region, "/?Operation=ListJobs");
+        request.set_operation("ListJobs");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateJob");
+        params.put("Action", "ListJobs");
         params.put("Version", "2010-06-01");
-        UpdateJobInputSerializer::serialize(&mut params, "", &input);
+        ListJobsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_job`, also returning the metadata of the response.
+    pub async fn update_job_with_metadata(
+        &self,
+        input: UpdateJobInput,
+    ) -> Result<(UpdateJobOutput, ResponseMetadata), RusotoError<UpdateJobError>> {
+        let request = self.build_update_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateJobError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -1920,7 +2130,99 @@ impl ImportExport for ImportExportClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `update_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_job(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateJobInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_job_request(&self, input: UpdateJobInput) -> SignedRequest {
+        let mut request = SignedRequest::new(
+            "POST",
+            "importexport",
+            &self.region,
+            "/?Operation=UpdateJob",
+        );
+        request.set_operation("UpdateJob");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateJob");
+        params.put("Version", "2010-06-01");
+        UpdateJobInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+}
+
+#[async_trait]
+impl ImportExport for ImportExportClient {
+    /// <p>This operation cancels a specified job. Only the job owner can cancel it. The operation fails if the job has already started or is complete.</p>
+    async fn cancel_job(
+        &self,
+        input: CancelJobInput,
+    ) -> Result<CancelJobOutput, RusotoError<CancelJobError>> {
+        self.cancel_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation initiates the process of scheduling an upload or download of your data. You include in the request a manifest that describes the data transfer specifics. The response to the request includes a job ID, which you can use in other operations, a signature that you use to identify your storage device, and the address where you should ship your storage device.</p>
+    async fn create_job(
+        &self,
+        input: CreateJobInput,
+    ) -> Result<CreateJobOutput, RusotoError<CreateJobError>> {
+        self.create_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation generates a pre-paid UPS shipping label that you will use to ship your device to AWS for processing.</p>
+    async fn get_shipping_label(
+        &self,
+        input: GetShippingLabelInput,
+    ) -> Result<GetShippingLabelOutput, RusotoError<GetShippingLabelError>> {
+        self.get_shipping_label_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns information about a job, including where the job is in the processing pipeline, the status of the results, and the signature value associated with the job. You can only return information about jobs you own.</p>
+    async fn get_status(
+        &self,
+        input: GetStatusInput,
+    ) -> Result<GetStatusOutput, RusotoError<GetStatusError>> {
+        self.get_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns the jobs associated with the requester. AWS Import/Export lists the jobs in reverse chronological order based on the date of creation. For example if Job Test1 was created 2009Dec30 and Test2 was created 2010Feb05, the ListJobs operation would return Test2 followed by Test1.</p>
+    async fn list_jobs(
+        &self,
+        input: ListJobsInput,
+    ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
+        self.list_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>You use this operation to change the parameters specified in the original manifest file by supplying a new manifest file. The manifest file attached to this request replaces the original manifest file. You can only use the operation after a CreateJob request but before the data transfer starts and you can only use it on jobs you own.</p>
+    async fn update_job(
+        &self,
+        input: UpdateJobInput,
+    ) -> Result<UpdateJobOutput, RusotoError<UpdateJobError>> {
+        self.update_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
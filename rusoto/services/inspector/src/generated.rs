@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1542,6 +1542,7 @@ pub struct UpdateAssessmentTargetRequest {
 
 /// Errors returned by AddAttributesToFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddAttributesToFindingsError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -1553,6 +1554,8 @@ pub enum AddAttributesToFindingsError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddAttributesToFindingsError {
@@ -1560,30 +1563,37 @@ impl AddAttributesToFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddAttributesToFindingsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::Internal(err.msg))
+                    return RusotoError::service(
+                        AddAttributesToFindingsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddAttributesToFindingsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddAttributesToFindingsError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddAttributesToFindingsError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddAttributesToFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1600,12 +1610,14 @@ impl fmt::Display for AddAttributesToFindingsError {
             AddAttributesToFindingsError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddAttributesToFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddAttributesToFindingsError {}
 /// Errors returned by CreateAssessmentTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAssessmentTargetError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -1621,6 +1633,8 @@ pub enum CreateAssessmentTargetError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAssessmentTargetError {
@@ -1628,34 +1642,49 @@ impl CreateAssessmentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateAssessmentTargetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::Internal(err.msg))
+                    return RusotoError::service(
+                        CreateAssessmentTargetError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateAssessmentTargetError::InvalidCrossAccountRole(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateAssessmentTargetError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssessmentTargetError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        CreateAssessmentTargetError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAssessmentTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1676,12 +1705,14 @@ impl fmt::Display for CreateAssessmentTargetError {
             CreateAssessmentTargetError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateAssessmentTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAssessmentTargetError {}
 /// Errors returned by CreateAssessmentTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAssessmentTemplateError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -1695,6 +1726,8 @@ pub enum CreateAssessmentTemplateError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAssessmentTemplateError {
@@ -1702,35 +1735,43 @@ impl CreateAssessmentTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssessmentTemplateError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::Internal(err.msg))
+                    return RusotoError::service(
+                        CreateAssessmentTemplateError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssessmentTemplateError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssessmentTemplateError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssessmentTemplateError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateAssessmentTemplateError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAssessmentTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1748,12 +1789,14 @@ impl fmt::Display for CreateAssessmentTemplateError {
             CreateAssessmentTemplateError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateAssessmentTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAssessmentTemplateError {}
 /// Errors returned by CreateExclusionsPreview
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateExclusionsPreviewError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -1767,6 +1810,8 @@ pub enum CreateExclusionsPreviewError {
     PreviewGenerationInProgress(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateExclusionsPreviewError {
@@ -1774,35 +1819,43 @@ impl CreateExclusionsPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateExclusionsPreviewError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::Internal(err.msg))
+                    return RusotoError::service(
+                        CreateExclusionsPreviewError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateExclusionsPreviewError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateExclusionsPreviewError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "PreviewGenerationInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateExclusionsPreviewError::PreviewGenerationInProgress(err.msg),
+                        &res,
                     )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateExclusionsPreviewError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateExclusionsPreviewError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1822,12 +1875,14 @@ impl fmt::Display for CreateExclusionsPreviewError {
             CreateExclusionsPreviewError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateExclusionsPreviewError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateExclusionsPreviewError {}
 /// Errors returned by CreateResourceGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResourceGroupError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -1839,6 +1894,8 @@ pub enum CreateResourceGroupError {
     LimitExceeded(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResourceGroupError {
@@ -1846,24 +1903,34 @@ impl CreateResourceGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateResourceGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateResourceGroupError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateResourceGroupError::Internal(err.msg))
+                    return RusotoError::service(CreateResourceGroupError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateResourceGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateResourceGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResourceGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateResourceGroupError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateResourceGroupError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateResourceGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1880,12 +1947,14 @@ impl fmt::Display for CreateResourceGroupError {
             CreateResourceGroupError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateResourceGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateResourceGroupError {}
 /// Errors returned by DeleteAssessmentRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAssessmentRunError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -1899,6 +1968,8 @@ pub enum DeleteAssessmentRunError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAssessmentRunError {
@@ -1906,29 +1977,40 @@ impl DeleteAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteAssessmentRunError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::AssessmentRunInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAssessmentRunError::AssessmentRunInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::Internal(err.msg))
+                    return RusotoError::service(DeleteAssessmentRunError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteAssessmentRunError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        DeleteAssessmentRunError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAssessmentRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1946,12 +2028,14 @@ impl fmt::Display for DeleteAssessmentRunError {
             DeleteAssessmentRunError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteAssessmentRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAssessmentRunError {}
 /// Errors returned by DeleteAssessmentTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAssessmentTargetError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -1965,6 +2049,8 @@ pub enum DeleteAssessmentTargetError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAssessmentTargetError {
@@ -1972,29 +2058,43 @@ impl DeleteAssessmentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteAssessmentTargetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAssessmentTargetError::AssessmentRunInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::Internal(err.msg))
+                    return RusotoError::service(
+                        DeleteAssessmentTargetError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteAssessmentTargetError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        DeleteAssessmentTargetError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAssessmentTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2014,12 +2114,14 @@ impl fmt::Display for DeleteAssessmentTargetError {
             DeleteAssessmentTargetError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteAssessmentTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAssessmentTargetError {}
 /// Errors returned by DeleteAssessmentTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAssessmentTemplateError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2033,6 +2135,8 @@ pub enum DeleteAssessmentTemplateError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAssessmentTemplateError {
@@ -2040,35 +2144,43 @@ impl DeleteAssessmentTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAssessmentTemplateError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAssessmentTemplateError::AssessmentRunInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::Internal(err.msg))
+                    return RusotoError::service(
+                        DeleteAssessmentTemplateError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAssessmentTemplateError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAssessmentTemplateError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAssessmentTemplateError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAssessmentTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2088,17 +2200,21 @@ impl fmt::Display for DeleteAssessmentTemplateError {
             DeleteAssessmentTemplateError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteAssessmentTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAssessmentTemplateError {}
 /// Errors returned by DescribeAssessmentRuns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAssessmentRunsError {
     /// <p>Internal server error.</p>
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAssessmentRunsError {
@@ -2106,13 +2222,19 @@ impl DescribeAssessmentRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAssessmentRunsError::Internal(err.msg))
+                    return RusotoError::service(
+                        DescribeAssessmentRunsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAssessmentRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeAssessmentRunsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAssessmentRunsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2124,17 +2246,21 @@ impl fmt::Display for DescribeAssessmentRunsError {
         match *self {
             DescribeAssessmentRunsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeAssessmentRunsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeAssessmentRunsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAssessmentRunsError {}
 /// Errors returned by DescribeAssessmentTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAssessmentTargetsError {
     /// <p>Internal server error.</p>
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAssessmentTargetsError {
@@ -2142,15 +2268,21 @@ impl DescribeAssessmentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAssessmentTargetsError::Internal(err.msg))
+                    return RusotoError::service(
+                        DescribeAssessmentTargetsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAssessmentTargetsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAssessmentTargetsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeAssessmentTargetsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2162,17 +2294,21 @@ impl fmt::Display for DescribeAssessmentTargetsError {
         match *self {
             DescribeAssessmentTargetsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeAssessmentTargetsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeAssessmentTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAssessmentTargetsError {}
 /// Errors returned by DescribeAssessmentTemplates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAssessmentTemplatesError {
     /// <p>Internal server error.</p>
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAssessmentTemplatesError {
@@ -2182,17 +2318,24 @@ impl DescribeAssessmentTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAssessmentTemplatesError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAssessmentTemplatesError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAssessmentTemplatesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAssessmentTemplatesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAssessmentTemplatesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2204,15 +2347,19 @@ impl fmt::Display for DescribeAssessmentTemplatesError {
         match *self {
             DescribeAssessmentTemplatesError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeAssessmentTemplatesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeAssessmentTemplatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAssessmentTemplatesError {}
 /// Errors returned by DescribeCrossAccountAccessRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCrossAccountAccessRoleError {
     /// <p>Internal server error.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCrossAccountAccessRoleError {
@@ -2222,12 +2369,18 @@ impl DescribeCrossAccountAccessRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeCrossAccountAccessRoleError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCrossAccountAccessRoleError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeCrossAccountAccessRoleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2238,17 +2391,23 @@ impl fmt::Display for DescribeCrossAccountAccessRoleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeCrossAccountAccessRoleError::Internal(ref cause) => write!(f, "{}", cause),
+            DescribeCrossAccountAccessRoleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeCrossAccountAccessRoleError {}
 /// Errors returned by DescribeExclusions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeExclusionsError {
     /// <p>Internal server error.</p>
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeExclusionsError {
@@ -2256,13 +2415,16 @@ impl DescribeExclusionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeExclusionsError::Internal(err.msg))
+                    return RusotoError::service(DescribeExclusionsError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeExclusionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeExclusionsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeExclusionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2274,17 +2436,21 @@ impl fmt::Display for DescribeExclusionsError {
         match *self {
             DescribeExclusionsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeExclusionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeExclusionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeExclusionsError {}
 /// Errors returned by DescribeFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFindingsError {
     /// <p>Internal server error.</p>
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFindingsError {
@@ -2292,13 +2458,13 @@ impl DescribeFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeFindingsError::Internal(err.msg))
+                    return RusotoError::service(DescribeFindingsError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeFindingsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2310,17 +2476,21 @@ impl fmt::Display for DescribeFindingsError {
         match *self {
             DescribeFindingsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeFindingsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFindingsError {}
 /// Errors returned by DescribeResourceGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeResourceGroupsError {
     /// <p>Internal server error.</p>
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeResourceGroupsError {
@@ -2328,13 +2498,19 @@ impl DescribeResourceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeResourceGroupsError::Internal(err.msg))
+                    return RusotoError::service(
+                        DescribeResourceGroupsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeResourceGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeResourceGroupsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeResourceGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2346,17 +2522,21 @@ impl fmt::Display for DescribeResourceGroupsError {
         match *self {
             DescribeResourceGroupsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeResourceGroupsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeResourceGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeResourceGroupsError {}
 /// Errors returned by DescribeRulesPackages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRulesPackagesError {
     /// <p>Internal server error.</p>
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRulesPackagesError {
@@ -2364,13 +2544,19 @@ impl DescribeRulesPackagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeRulesPackagesError::Internal(err.msg))
+                    return RusotoError::service(
+                        DescribeRulesPackagesError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeRulesPackagesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeRulesPackagesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRulesPackagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2382,12 +2568,14 @@ impl fmt::Display for DescribeRulesPackagesError {
         match *self {
             DescribeRulesPackagesError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeRulesPackagesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeRulesPackagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRulesPackagesError {}
 /// Errors returned by GetAssessmentReport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAssessmentReportError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2403,6 +2591,8 @@ pub enum GetAssessmentReportError {
     ServiceTemporarilyUnavailable(String),
     /// <p>Used by the <a>GetAssessmentReport</a> API. The request was rejected because you tried to generate a report for an assessment run that existed before reporting was supported in Amazon Inspector. You can only generate reports for assessment runs that took place or will take place after generating reports in Amazon Inspector became available.</p>
     UnsupportedFeature(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAssessmentReportError {
@@ -2410,34 +2600,46 @@ impl GetAssessmentReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAssessmentReportError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetAssessmentReportError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(GetAssessmentReportError::AssessmentRunInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAssessmentReportError::AssessmentRunInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetAssessmentReportError::Internal(err.msg))
+                    return RusotoError::service(GetAssessmentReportError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAssessmentReportError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetAssessmentReportError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(GetAssessmentReportError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        GetAssessmentReportError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAssessmentReportError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(GetAssessmentReportError::UnsupportedFeature(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAssessmentReportError::UnsupportedFeature(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAssessmentReportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2456,12 +2658,14 @@ impl fmt::Display for GetAssessmentReportError {
                 write!(f, "{}", cause)
             }
             GetAssessmentReportError::UnsupportedFeature(ref cause) => write!(f, "{}", cause),
+            GetAssessmentReportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAssessmentReportError {}
 /// Errors returned by GetExclusionsPreview
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetExclusionsPreviewError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2471,6 +2675,8 @@ pub enum GetExclusionsPreviewError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetExclusionsPreviewError {
@@ -2478,19 +2684,28 @@ impl GetExclusionsPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetExclusionsPreviewError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::Internal(err.msg))
+                    return RusotoError::service(GetExclusionsPreviewError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetExclusionsPreviewError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        GetExclusionsPreviewError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetExclusionsPreviewError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2504,12 +2719,14 @@ impl fmt::Display for GetExclusionsPreviewError {
             GetExclusionsPreviewError::Internal(ref cause) => write!(f, "{}", cause),
             GetExclusionsPreviewError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetExclusionsPreviewError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            GetExclusionsPreviewError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetExclusionsPreviewError {}
 /// Errors returned by GetTelemetryMetadata
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTelemetryMetadataError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2519,6 +2736,8 @@ pub enum GetTelemetryMetadataError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTelemetryMetadataError {
@@ -2526,19 +2745,28 @@ impl GetTelemetryMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetTelemetryMetadataError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::Internal(err.msg))
+                    return RusotoError::service(GetTelemetryMetadataError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetTelemetryMetadataError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        GetTelemetryMetadataError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTelemetryMetadataError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2552,12 +2780,14 @@ impl fmt::Display for GetTelemetryMetadataError {
             GetTelemetryMetadataError::Internal(ref cause) => write!(f, "{}", cause),
             GetTelemetryMetadataError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetTelemetryMetadataError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            GetTelemetryMetadataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTelemetryMetadataError {}
 /// Errors returned by ListAssessmentRunAgents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssessmentRunAgentsError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2567,6 +2797,8 @@ pub enum ListAssessmentRunAgentsError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssessmentRunAgentsError {
@@ -2574,25 +2806,31 @@ impl ListAssessmentRunAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAssessmentRunAgentsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::Internal(err.msg))
+                    return RusotoError::service(
+                        ListAssessmentRunAgentsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAssessmentRunAgentsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAssessmentRunAgentsError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssessmentRunAgentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2606,12 +2844,14 @@ impl fmt::Display for ListAssessmentRunAgentsError {
             ListAssessmentRunAgentsError::Internal(ref cause) => write!(f, "{}", cause),
             ListAssessmentRunAgentsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListAssessmentRunAgentsError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            ListAssessmentRunAgentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAssessmentRunAgentsError {}
 /// Errors returned by ListAssessmentRuns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssessmentRunsError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2621,6 +2861,8 @@ pub enum ListAssessmentRunsError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssessmentRunsError {
@@ -2628,19 +2870,28 @@ impl ListAssessmentRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListAssessmentRunsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::Internal(err.msg))
+                    return RusotoError::service(ListAssessmentRunsError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListAssessmentRunsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        ListAssessmentRunsError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssessmentRunsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2654,12 +2905,14 @@ impl fmt::Display for ListAssessmentRunsError {
             ListAssessmentRunsError::Internal(ref cause) => write!(f, "{}", cause),
             ListAssessmentRunsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListAssessmentRunsError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            ListAssessmentRunsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAssessmentRunsError {}
 /// Errors returned by ListAssessmentTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssessmentTargetsError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2667,6 +2920,8 @@ pub enum ListAssessmentTargetsError {
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssessmentTargetsError {
@@ -2674,16 +2929,25 @@ impl ListAssessmentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentTargetsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListAssessmentTargetsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentTargetsError::Internal(err.msg))
+                    return RusotoError::service(
+                        ListAssessmentTargetsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentTargetsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListAssessmentTargetsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssessmentTargetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2696,12 +2960,14 @@ impl fmt::Display for ListAssessmentTargetsError {
             ListAssessmentTargetsError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListAssessmentTargetsError::Internal(ref cause) => write!(f, "{}", cause),
             ListAssessmentTargetsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListAssessmentTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAssessmentTargetsError {}
 /// Errors returned by ListAssessmentTemplates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssessmentTemplatesError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2711,6 +2977,8 @@ pub enum ListAssessmentTemplatesError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssessmentTemplatesError {
@@ -2718,25 +2986,31 @@ impl ListAssessmentTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAssessmentTemplatesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::Internal(err.msg))
+                    return RusotoError::service(
+                        ListAssessmentTemplatesError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAssessmentTemplatesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAssessmentTemplatesError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssessmentTemplatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2750,12 +3024,14 @@ impl fmt::Display for ListAssessmentTemplatesError {
             ListAssessmentTemplatesError::Internal(ref cause) => write!(f, "{}", cause),
             ListAssessmentTemplatesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListAssessmentTemplatesError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            ListAssessmentTemplatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAssessmentTemplatesError {}
 /// Errors returned by ListEventSubscriptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEventSubscriptionsError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2765,6 +3041,8 @@ pub enum ListEventSubscriptionsError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEventSubscriptionsError {
@@ -2772,19 +3050,31 @@ impl ListEventSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListEventSubscriptionsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::Internal(err.msg))
+                    return RusotoError::service(
+                        ListEventSubscriptionsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListEventSubscriptionsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        ListEventSubscriptionsError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEventSubscriptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2798,12 +3088,14 @@ impl fmt::Display for ListEventSubscriptionsError {
             ListEventSubscriptionsError::Internal(ref cause) => write!(f, "{}", cause),
             ListEventSubscriptionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListEventSubscriptionsError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            ListEventSubscriptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEventSubscriptionsError {}
 /// Errors returned by ListExclusions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListExclusionsError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2813,6 +3105,8 @@ pub enum ListExclusionsError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListExclusionsError {
@@ -2820,19 +3114,19 @@ impl ListExclusionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListExclusionsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListExclusionsError::AccessDenied(err.msg), &res)
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListExclusionsError::Internal(err.msg))
+                    return RusotoError::service(ListExclusionsError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListExclusionsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListExclusionsError::InvalidInput(err.msg), &res)
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListExclusionsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(ListExclusionsError::NoSuchEntity(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListExclusionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2846,12 +3140,14 @@ impl fmt::Display for ListExclusionsError {
             ListExclusionsError::Internal(ref cause) => write!(f, "{}", cause),
             ListExclusionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListExclusionsError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            ListExclusionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListExclusionsError {}
 /// Errors returned by ListFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFindingsError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2861,6 +3157,8 @@ pub enum ListFindingsError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFindingsError {
@@ -2868,19 +3166,19 @@ impl ListFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFindingsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListFindingsError::AccessDenied(err.msg), &res)
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListFindingsError::Internal(err.msg))
+                    return RusotoError::service(ListFindingsError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListFindingsError::InvalidInput(err.msg), &res)
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListFindingsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(ListFindingsError::NoSuchEntity(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2894,12 +3192,14 @@ impl fmt::Display for ListFindingsError {
             ListFindingsError::Internal(ref cause) => write!(f, "{}", cause),
             ListFindingsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListFindingsError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            ListFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFindingsError {}
 /// Errors returned by ListRulesPackages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRulesPackagesError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2907,6 +3207,8 @@ pub enum ListRulesPackagesError {
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRulesPackagesError {
@@ -2914,16 +3216,22 @@ impl ListRulesPackagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRulesPackagesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListRulesPackagesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListRulesPackagesError::Internal(err.msg))
+                    return RusotoError::service(ListRulesPackagesError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListRulesPackagesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListRulesPackagesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRulesPackagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2936,12 +3244,14 @@ impl fmt::Display for ListRulesPackagesError {
             ListRulesPackagesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListRulesPackagesError::Internal(ref cause) => write!(f, "{}", cause),
             ListRulesPackagesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListRulesPackagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRulesPackagesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -2951,6 +3261,8 @@ pub enum ListTagsForResourceError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2958,19 +3270,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2984,12 +3305,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::Internal(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PreviewAgents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PreviewAgentsError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -3001,6 +3324,8 @@ pub enum PreviewAgentsError {
     InvalidInput(String),
     /// <p>The request was rejected because it referenced an entity that does not exist. The error code describes the entity.</p>
     NoSuchEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PreviewAgentsError {
@@ -3008,24 +3333,25 @@ impl PreviewAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PreviewAgentsError::AccessDenied(err.msg))
+                    return RusotoError::service(PreviewAgentsError::AccessDenied(err.msg), &res)
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PreviewAgentsError::Internal(err.msg))
+                    return RusotoError::service(PreviewAgentsError::Internal(err.msg), &res)
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(PreviewAgentsError::InvalidCrossAccountRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PreviewAgentsError::InvalidCrossAccountRole(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PreviewAgentsError::InvalidInput(err.msg))
+                    return RusotoError::service(PreviewAgentsError::InvalidInput(err.msg), &res)
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(PreviewAgentsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(PreviewAgentsError::NoSuchEntity(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PreviewAgentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3040,12 +3366,14 @@ impl fmt::Display for PreviewAgentsError {
             PreviewAgentsError::InvalidCrossAccountRole(ref cause) => write!(f, "{}", cause),
             PreviewAgentsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             PreviewAgentsError::NoSuchEntity(ref cause) => write!(f, "{}", cause),
+            PreviewAgentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PreviewAgentsError {}
 /// Errors returned by RegisterCrossAccountAccessRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterCrossAccountAccessRoleError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -3057,6 +3385,8 @@ pub enum RegisterCrossAccountAccessRoleError {
     InvalidInput(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterCrossAccountAccessRoleError {
@@ -3066,32 +3396,42 @@ impl RegisterCrossAccountAccessRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterCrossAccountAccessRoleError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterCrossAccountAccessRoleError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RegisterCrossAccountAccessRoleError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterCrossAccountAccessRoleError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterCrossAccountAccessRoleError::InvalidCrossAccountRole(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterCrossAccountAccessRoleError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterCrossAccountAccessRoleError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterCrossAccountAccessRoleError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RegisterCrossAccountAccessRoleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3110,12 +3450,16 @@ impl fmt::Display for RegisterCrossAccountAccessRoleError {
             RegisterCrossAccountAccessRoleError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RegisterCrossAccountAccessRoleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RegisterCrossAccountAccessRoleError {}
 /// Errors returned by RemoveAttributesFromFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveAttributesFromFindingsError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -3127,6 +3471,8 @@ pub enum RemoveAttributesFromFindingsError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveAttributesFromFindingsError {
@@ -3136,32 +3482,42 @@ impl RemoveAttributesFromFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveAttributesFromFindingsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveAttributesFromFindingsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveAttributesFromFindingsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveAttributesFromFindingsError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveAttributesFromFindingsError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RemoveAttributesFromFindingsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3178,12 +3534,14 @@ impl fmt::Display for RemoveAttributesFromFindingsError {
             RemoveAttributesFromFindingsError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RemoveAttributesFromFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveAttributesFromFindingsError {}
 /// Errors returned by SetTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetTagsForResourceError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -3195,6 +3553,8 @@ pub enum SetTagsForResourceError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetTagsForResourceError {
@@ -3202,24 +3562,34 @@ impl SetTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SetTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        SetTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(SetTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(SetTagsForResourceError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SetTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        SetTagsForResourceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(SetTagsForResourceError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        SetTagsForResourceError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetTagsForResourceError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3236,12 +3606,14 @@ impl fmt::Display for SetTagsForResourceError {
             SetTagsForResourceError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SetTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetTagsForResourceError {}
 /// Errors returned by StartAssessmentRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartAssessmentRunError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -3259,6 +3631,8 @@ pub enum StartAssessmentRunError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartAssessmentRunError {
@@ -3266,37 +3640,52 @@ impl StartAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartAssessmentRunError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartAssessmentRunError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AgentsAlreadyRunningAssessmentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAssessmentRunError::AgentsAlreadyRunningAssessment(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(StartAssessmentRunError::Internal(err.msg))
+                    return RusotoError::service(StartAssessmentRunError::Internal(err.msg), &res)
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(StartAssessmentRunError::InvalidCrossAccountRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartAssessmentRunError::InvalidCrossAccountRole(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartAssessmentRunError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        StartAssessmentRunError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartAssessmentRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartAssessmentRunError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(StartAssessmentRunError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        StartAssessmentRunError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartAssessmentRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3318,12 +3707,14 @@ impl fmt::Display for StartAssessmentRunError {
             StartAssessmentRunError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartAssessmentRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartAssessmentRunError {}
 /// Errors returned by StopAssessmentRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopAssessmentRunError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -3335,6 +3726,8 @@ pub enum StopAssessmentRunError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopAssessmentRunError {
@@ -3342,24 +3735,34 @@ impl StopAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopAssessmentRunError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StopAssessmentRunError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(StopAssessmentRunError::Internal(err.msg))
+                    return RusotoError::service(StopAssessmentRunError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopAssessmentRunError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        StopAssessmentRunError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(StopAssessmentRunError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        StopAssessmentRunError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopAssessmentRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3376,12 +3779,14 @@ impl fmt::Display for StopAssessmentRunError {
             StopAssessmentRunError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StopAssessmentRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopAssessmentRunError {}
 /// Errors returned by SubscribeToEvent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubscribeToEventError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -3395,6 +3800,8 @@ pub enum SubscribeToEventError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubscribeToEventError {
@@ -3402,27 +3809,31 @@ impl SubscribeToEventError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubscribeToEventError::AccessDenied(err.msg))
+                    return RusotoError::service(SubscribeToEventError::AccessDenied(err.msg), &res)
                 }
                 "InternalException" => {
-                    return RusotoError::Service(SubscribeToEventError::Internal(err.msg))
+                    return RusotoError::service(SubscribeToEventError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SubscribeToEventError::InvalidInput(err.msg))
+                    return RusotoError::service(SubscribeToEventError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SubscribeToEventError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        SubscribeToEventError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(SubscribeToEventError::NoSuchEntity(err.msg))
+                    return RusotoError::service(SubscribeToEventError::NoSuchEntity(err.msg), &res)
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SubscribeToEventError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SubscribeToEventError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3440,12 +3851,14 @@ impl fmt::Display for SubscribeToEventError {
             SubscribeToEventError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SubscribeToEventError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubscribeToEventError {}
 /// Errors returned by UnsubscribeFromEvent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnsubscribeFromEventError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -3457,6 +3870,8 @@ pub enum UnsubscribeFromEventError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnsubscribeFromEventError {
@@ -3464,24 +3879,34 @@ impl UnsubscribeFromEventError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UnsubscribeFromEventError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::Internal(err.msg))
+                    return RusotoError::service(UnsubscribeFromEventError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UnsubscribeFromEventError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        UnsubscribeFromEventError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UnsubscribeFromEventError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnsubscribeFromEventError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3498,12 +3923,14 @@ impl fmt::Display for UnsubscribeFromEventError {
             UnsubscribeFromEventError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UnsubscribeFromEventError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnsubscribeFromEventError {}
 /// Errors returned by UpdateAssessmentTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAssessmentTargetError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -3515,6 +3942,8 @@ pub enum UpdateAssessmentTargetError {
     NoSuchEntity(String),
     /// <p>The serice is temporary unavailable.</p>
     ServiceTemporarilyUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAssessmentTargetError {
@@ -3522,24 +3951,37 @@ impl UpdateAssessmentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateAssessmentTargetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::Internal(err.msg))
+                    return RusotoError::service(
+                        UpdateAssessmentTargetError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateAssessmentTargetError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        UpdateAssessmentTargetError::NoSuchEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAssessmentTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3556,6 +3998,7 @@ impl fmt::Display for UpdateAssessmentTargetError {
             UpdateAssessmentTargetError::ServiceTemporarilyUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateAssessmentTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3798,6 +4241,7 @@ impl InspectorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> InspectorClient {
         InspectorClient {
             client: Client::shared(),
@@ -3823,295 +4267,629 @@ impl InspectorClient {
     pub fn new_with_client(client: Client, region: region::Region) -> InspectorClient {
         InspectorClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<InspectorClient> {
+        rusoto_core::ClientBuilder::new(InspectorClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> InspectorClient {
+        InspectorClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Inspector for InspectorClient {
-    /// <p>Assigns attributes (key and value pairs) to the findings that are specified by the ARNs of the findings.</p>
-    async fn add_attributes_to_findings(
+impl InspectorClient {
+    /// Calls `add_attributes_to_findings`, also returning the metadata of the response.
+    pub async fn add_attributes_to_findings_with_metadata(
         &self,
         input: AddAttributesToFindingsRequest,
-    ) -> Result<AddAttributesToFindingsResponse, RusotoError<AddAttributesToFindingsError>> {
+    ) -> Result<
+        (AddAttributesToFindingsResponse, ResponseMetadata),
+        RusotoError<AddAttributesToFindingsError>,
+    > {
+        let request = self.build_add_attributes_to_findings_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddAttributesToFindingsResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddAttributesToFindingsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_attributes_to_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_attributes_to_findings(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddAttributesToFindingsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_attributes_to_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_attributes_to_findings_request(
+        &self,
+        input: AddAttributesToFindingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("AddAttributesToFindings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.AddAttributesToFindings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_assessment_target`, also returning the metadata of the response.
+    pub async fn create_assessment_target_with_metadata(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> Result<
+        (CreateAssessmentTargetResponse, ResponseMetadata),
+        RusotoError<CreateAssessmentTargetError>,
+    > {
+        let request = self.build_create_assessment_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddAttributesToFindingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAssessmentTargetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddAttributesToFindingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAssessmentTargetError::from_response(response))
         }
     }
 
-    /// <p>Creates a new assessment target using the ARN of the resource group that is generated by <a>CreateResourceGroup</a>. If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments. You can create up to 50 assessment targets per AWS account. You can run up to 500 concurrent agents per AWS account. For more information, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html"> Amazon Inspector Assessment Targets</a>.</p>
-    async fn create_assessment_target(
+    /// Presigns the request `create_assessment_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_assessment_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAssessmentTargetRequest,
-    ) -> Result<CreateAssessmentTargetResponse, RusotoError<CreateAssessmentTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_assessment_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_assessment_target_request(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateAssessmentTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.CreateAssessmentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_assessment_template`, also returning the metadata of the response.
+    pub async fn create_assessment_template_with_metadata(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> Result<
+        (CreateAssessmentTemplateResponse, ResponseMetadata),
+        RusotoError<CreateAssessmentTemplateError>,
+    > {
+        let request = self.build_create_assessment_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAssessmentTargetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAssessmentTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAssessmentTargetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAssessmentTemplateError::from_response(response))
         }
     }
 
-    /// <p>Creates an assessment template for the assessment target that is specified by the ARN of the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments.</p>
-    async fn create_assessment_template(
+    /// Presigns the request `create_assessment_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_assessment_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAssessmentTemplateRequest,
-    ) -> Result<CreateAssessmentTemplateResponse, RusotoError<CreateAssessmentTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_assessment_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_assessment_template_request(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateAssessmentTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.CreateAssessmentTemplate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_exclusions_preview`, also returning the metadata of the response.
+    pub async fn create_exclusions_preview_with_metadata(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> Result<
+        (CreateExclusionsPreviewResponse, ResponseMetadata),
+        RusotoError<CreateExclusionsPreviewError>,
+    > {
+        let request = self.build_create_exclusions_preview_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAssessmentTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateExclusionsPreviewResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAssessmentTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateExclusionsPreviewError::from_response(response))
         }
     }
 
-    /// <p>Starts the generation of an exclusions preview for the specified assessment template. The exclusions preview lists the potential exclusions (ExclusionPreview) that Inspector can detect before it runs the assessment. </p>
-    async fn create_exclusions_preview(
+    /// Presigns the request `create_exclusions_preview` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_exclusions_preview(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateExclusionsPreviewRequest,
-    ) -> Result<CreateExclusionsPreviewResponse, RusotoError<CreateExclusionsPreviewError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_exclusions_preview_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_exclusions_preview_request(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateExclusionsPreview");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.CreateExclusionsPreview");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_resource_group`, also returning the metadata of the response.
+    pub async fn create_resource_group_with_metadata(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> Result<
+        (CreateResourceGroupResponse, ResponseMetadata),
+        RusotoError<CreateResourceGroupError>,
+    > {
+        let request = self.build_create_resource_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateExclusionsPreviewResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateResourceGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateExclusionsPreviewError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateResourceGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a resource group using the specified set of tags (key and value pairs) that are used to select the EC2 instances to be included in an Amazon Inspector assessment target. The created resource group is then used to create an Amazon Inspector assessment target. For more information, see <a>CreateAssessmentTarget</a>.</p>
-    async fn create_resource_group(
+    /// Presigns the request `create_resource_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resource_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResourceGroupRequest,
-    ) -> Result<CreateResourceGroupResponse, RusotoError<CreateResourceGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resource_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resource_group_request(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("CreateResourceGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.CreateResourceGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_assessment_run`, also returning the metadata of the response.
+    pub async fn delete_assessment_run_with_metadata(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAssessmentRunError>> {
+        let request = self.build_delete_assessment_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateResourceGroupResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResourceGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAssessmentRunError::from_response(response))
         }
     }
 
-    /// <p>Deletes the assessment run that is specified by the ARN of the assessment run.</p>
-    async fn delete_assessment_run(
+    /// Presigns the request `delete_assessment_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_assessment_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAssessmentRunRequest,
-    ) -> Result<(), RusotoError<DeleteAssessmentRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_assessment_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_assessment_run_request(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DeleteAssessmentRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_assessment_target`, also returning the metadata of the response.
+    pub async fn delete_assessment_target_with_metadata(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAssessmentTargetError>> {
+        let request = self.build_delete_assessment_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAssessmentRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAssessmentTargetError::from_response(response))
         }
     }
 
-    /// <p>Deletes the assessment target that is specified by the ARN of the assessment target.</p>
-    async fn delete_assessment_target(
+    /// Presigns the request `delete_assessment_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_assessment_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAssessmentTargetRequest,
-    ) -> Result<(), RusotoError<DeleteAssessmentTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_assessment_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_assessment_target_request(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DeleteAssessmentTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_assessment_template`, also returning the metadata of the response.
+    pub async fn delete_assessment_template_with_metadata(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAssessmentTemplateError>> {
+        let request = self.build_delete_assessment_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAssessmentTargetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAssessmentTemplateError::from_response(response))
         }
     }
 
-    /// <p>Deletes the assessment template that is specified by the ARN of the assessment template.</p>
-    async fn delete_assessment_template(
+    /// Presigns the request `delete_assessment_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_assessment_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAssessmentTemplateRequest,
-    ) -> Result<(), RusotoError<DeleteAssessmentTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_assessment_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_assessment_template_request(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DeleteAssessmentTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTemplate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_assessment_runs`, also returning the metadata of the response.
+    pub async fn describe_assessment_runs_with_metadata(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> Result<
+        (DescribeAssessmentRunsResponse, ResponseMetadata),
+        RusotoError<DescribeAssessmentRunsError>,
+    > {
+        let request = self.build_describe_assessment_runs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAssessmentRunsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAssessmentTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAssessmentRunsError::from_response(response))
         }
     }
 
-    /// <p>Describes the assessment runs that are specified by the ARNs of the assessment runs.</p>
-    async fn describe_assessment_runs(
+    /// Presigns the request `describe_assessment_runs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_assessment_runs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAssessmentRunsRequest,
-    ) -> Result<DescribeAssessmentRunsResponse, RusotoError<DescribeAssessmentRunsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_assessment_runs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_assessment_runs_request(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeAssessmentRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeAssessmentRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_assessment_targets`, also returning the metadata of the response.
+    pub async fn describe_assessment_targets_with_metadata(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> Result<
+        (DescribeAssessmentTargetsResponse, ResponseMetadata),
+        RusotoError<DescribeAssessmentTargetsError>,
+    > {
+        let request = self.build_describe_assessment_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAssessmentRunsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAssessmentTargetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAssessmentRunsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAssessmentTargetsError::from_response(response))
         }
     }
 
-    /// <p>Describes the assessment targets that are specified by the ARNs of the assessment targets.</p>
-    async fn describe_assessment_targets(
+    /// Presigns the request `describe_assessment_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_assessment_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAssessmentTargetsRequest,
-    ) -> Result<DescribeAssessmentTargetsResponse, RusotoError<DescribeAssessmentTargetsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_assessment_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_assessment_targets_request(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeAssessmentTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeAssessmentTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_assessment_templates`, also returning the metadata of the response.
+    pub async fn describe_assessment_templates_with_metadata(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> Result<
+        (DescribeAssessmentTemplatesResponse, ResponseMetadata),
+        RusotoError<DescribeAssessmentTemplatesError>,
+    > {
+        let request = self.build_describe_assessment_templates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAssessmentTargetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAssessmentTemplatesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAssessmentTargetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAssessmentTemplatesError::from_response(response))
         }
     }
 
-    /// <p>Describes the assessment templates that are specified by the ARNs of the assessment templates.</p>
-    async fn describe_assessment_templates(
+    /// Presigns the request `describe_assessment_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_assessment_templates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAssessmentTemplatesRequest,
-    ) -> Result<DescribeAssessmentTemplatesResponse, RusotoError<DescribeAssessmentTemplatesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_assessment_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_assessment_templates_request(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeAssessmentTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4121,30 +4899,53 @@ impl Inspector for InspectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_cross_account_access_role`, also returning the metadata of the response.
+    pub async fn describe_cross_account_access_role_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeCrossAccountAccessRoleResponse, ResponseMetadata),
+        RusotoError<DescribeCrossAccountAccessRoleError>,
+    > {
+        let request = self.build_describe_cross_account_access_role_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAssessmentTemplatesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCrossAccountAccessRoleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAssessmentTemplatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCrossAccountAccessRoleError::from_response(response))
         }
     }
 
-    /// <p>Describes the IAM role that enables Amazon Inspector to access your AWS account.</p>
-    async fn describe_cross_account_access_role(
+    /// Presigns the request `describe_cross_account_access_role` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_cross_account_access_role(
         &self,
-    ) -> Result<
-        DescribeCrossAccountAccessRoleResponse,
-        RusotoError<DescribeCrossAccountAccessRoleError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cross_account_access_role_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cross_account_access_role_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeCrossAccountAccessRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4153,501 +4954,1001 @@ impl Inspector for InspectorClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_exclusions`, also returning the metadata of the response.
+    pub async fn describe_exclusions_with_metadata(
+        &self,
+        input: DescribeExclusionsRequest,
+    ) -> Result<(DescribeExclusionsResponse, ResponseMetadata), RusotoError<DescribeExclusionsError>>
+    {
+        let request = self.build_describe_exclusions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCrossAccountAccessRoleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeExclusionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCrossAccountAccessRoleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeExclusionsError::from_response(response))
         }
     }
 
-    /// <p>Describes the exclusions that are specified by the exclusions' ARNs.</p>
-    async fn describe_exclusions(
+    /// Presigns the request `describe_exclusions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_exclusions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeExclusionsRequest,
-    ) -> Result<DescribeExclusionsResponse, RusotoError<DescribeExclusionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_exclusions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_exclusions_request(&self, input: DescribeExclusionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeExclusions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeExclusions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_findings`, also returning the metadata of the response.
+    pub async fn describe_findings_with_metadata(
+        &self,
+        input: DescribeFindingsRequest,
+    ) -> Result<(DescribeFindingsResponse, ResponseMetadata), RusotoError<DescribeFindingsError>>
+    {
+        let request = self.build_describe_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeExclusionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFindingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeExclusionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFindingsError::from_response(response))
         }
     }
 
-    /// <p>Describes the findings that are specified by the ARNs of the findings.</p>
-    async fn describe_findings(
+    /// Presigns the request `describe_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFindingsRequest,
-    ) -> Result<DescribeFindingsResponse, RusotoError<DescribeFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_findings_request(&self, input: DescribeFindingsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeFindings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeFindings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_resource_groups`, also returning the metadata of the response.
+    pub async fn describe_resource_groups_with_metadata(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> Result<
+        (DescribeResourceGroupsResponse, ResponseMetadata),
+        RusotoError<DescribeResourceGroupsError>,
+    > {
+        let request = self.build_describe_resource_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFindingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeResourceGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFindingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeResourceGroupsError::from_response(response))
         }
     }
 
-    /// <p>Describes the resource groups that are specified by the ARNs of the resource groups.</p>
-    async fn describe_resource_groups(
+    /// Presigns the request `describe_resource_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_resource_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeResourceGroupsRequest,
-    ) -> Result<DescribeResourceGroupsResponse, RusotoError<DescribeResourceGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_resource_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_resource_groups_request(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeResourceGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeResourceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_rules_packages`, also returning the metadata of the response.
+    pub async fn describe_rules_packages_with_metadata(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> Result<
+        (DescribeRulesPackagesResponse, ResponseMetadata),
+        RusotoError<DescribeRulesPackagesError>,
+    > {
+        let request = self.build_describe_rules_packages_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeResourceGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRulesPackagesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeResourceGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRulesPackagesError::from_response(response))
         }
     }
 
-    /// <p>Describes the rules packages that are specified by the ARNs of the rules packages.</p>
-    async fn describe_rules_packages(
+    /// Presigns the request `describe_rules_packages` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_rules_packages(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRulesPackagesRequest,
-    ) -> Result<DescribeRulesPackagesResponse, RusotoError<DescribeRulesPackagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_rules_packages_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_rules_packages_request(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("DescribeRulesPackages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.DescribeRulesPackages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_assessment_report`, also returning the metadata of the response.
+    pub async fn get_assessment_report_with_metadata(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> Result<
+        (GetAssessmentReportResponse, ResponseMetadata),
+        RusotoError<GetAssessmentReportError>,
+    > {
+        let request = self.build_get_assessment_report_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRulesPackagesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAssessmentReportResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRulesPackagesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAssessmentReportError::from_response(response))
         }
     }
 
-    /// <p>Produces an assessment report that includes detailed and comprehensive results of a specified assessment run. </p>
-    async fn get_assessment_report(
+    /// Presigns the request `get_assessment_report` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_assessment_report(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAssessmentReportRequest,
-    ) -> Result<GetAssessmentReportResponse, RusotoError<GetAssessmentReportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_assessment_report_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_assessment_report_request(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("GetAssessmentReport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.GetAssessmentReport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_exclusions_preview`, also returning the metadata of the response.
+    pub async fn get_exclusions_preview_with_metadata(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> Result<
+        (GetExclusionsPreviewResponse, ResponseMetadata),
+        RusotoError<GetExclusionsPreviewError>,
+    > {
+        let request = self.build_get_exclusions_preview_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAssessmentReportResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetExclusionsPreviewResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAssessmentReportError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetExclusionsPreviewError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the exclusions preview (a list of ExclusionPreview objects) specified by the preview token. You can obtain the preview token by running the CreateExclusionsPreview API.</p>
-    async fn get_exclusions_preview(
+    /// Presigns the request `get_exclusions_preview` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_exclusions_preview(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetExclusionsPreviewRequest,
-    ) -> Result<GetExclusionsPreviewResponse, RusotoError<GetExclusionsPreviewError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_exclusions_preview_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_exclusions_preview_request(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("GetExclusionsPreview");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.GetExclusionsPreview");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_telemetry_metadata`, also returning the metadata of the response.
+    pub async fn get_telemetry_metadata_with_metadata(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> Result<
+        (GetTelemetryMetadataResponse, ResponseMetadata),
+        RusotoError<GetTelemetryMetadataError>,
+    > {
+        let request = self.build_get_telemetry_metadata_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetExclusionsPreviewResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTelemetryMetadataResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetExclusionsPreviewError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTelemetryMetadataError::from_response(response))
         }
     }
 
-    /// <p>Information about the data that is collected for the specified assessment run.</p>
-    async fn get_telemetry_metadata(
+    /// Presigns the request `get_telemetry_metadata` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_telemetry_metadata(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTelemetryMetadataRequest,
-    ) -> Result<GetTelemetryMetadataResponse, RusotoError<GetTelemetryMetadataError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_telemetry_metadata_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_telemetry_metadata_request(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("GetTelemetryMetadata");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.GetTelemetryMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_assessment_run_agents`, also returning the metadata of the response.
+    pub async fn list_assessment_run_agents_with_metadata(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> Result<
+        (ListAssessmentRunAgentsResponse, ResponseMetadata),
+        RusotoError<ListAssessmentRunAgentsError>,
+    > {
+        let request = self.build_list_assessment_run_agents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetTelemetryMetadataResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssessmentRunAgentsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTelemetryMetadataError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssessmentRunAgentsError::from_response(response))
         }
     }
 
-    /// <p>Lists the agents of the assessment runs that are specified by the ARNs of the assessment runs.</p>
-    async fn list_assessment_run_agents(
+    /// Presigns the request `list_assessment_run_agents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_assessment_run_agents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssessmentRunAgentsRequest,
-    ) -> Result<ListAssessmentRunAgentsResponse, RusotoError<ListAssessmentRunAgentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_assessment_run_agents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_assessment_run_agents_request(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentRunAgents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListAssessmentRunAgents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_assessment_runs`, also returning the metadata of the response.
+    pub async fn list_assessment_runs_with_metadata(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> Result<(ListAssessmentRunsResponse, ResponseMetadata), RusotoError<ListAssessmentRunsError>>
+    {
+        let request = self.build_list_assessment_runs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssessmentRunAgentsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssessmentRunsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssessmentRunAgentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssessmentRunsError::from_response(response))
         }
     }
 
-    /// <p>Lists the assessment runs that correspond to the assessment templates that are specified by the ARNs of the assessment templates.</p>
-    async fn list_assessment_runs(
+    /// Presigns the request `list_assessment_runs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_assessment_runs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssessmentRunsRequest,
-    ) -> Result<ListAssessmentRunsResponse, RusotoError<ListAssessmentRunsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_assessment_runs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_assessment_runs_request(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListAssessmentRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_assessment_targets`, also returning the metadata of the response.
+    pub async fn list_assessment_targets_with_metadata(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> Result<
+        (ListAssessmentTargetsResponse, ResponseMetadata),
+        RusotoError<ListAssessmentTargetsError>,
+    > {
+        let request = self.build_list_assessment_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssessmentRunsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssessmentTargetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssessmentRunsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssessmentTargetsError::from_response(response))
         }
     }
 
-    /// <p>Lists the ARNs of the assessment targets within this AWS account. For more information about assessment targets, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html">Amazon Inspector Assessment Targets</a>.</p>
-    async fn list_assessment_targets(
+    /// Presigns the request `list_assessment_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_assessment_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssessmentTargetsRequest,
-    ) -> Result<ListAssessmentTargetsResponse, RusotoError<ListAssessmentTargetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_assessment_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_assessment_targets_request(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListAssessmentTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_assessment_templates`, also returning the metadata of the response.
+    pub async fn list_assessment_templates_with_metadata(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> Result<
+        (ListAssessmentTemplatesResponse, ResponseMetadata),
+        RusotoError<ListAssessmentTemplatesError>,
+    > {
+        let request = self.build_list_assessment_templates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssessmentTargetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssessmentTemplatesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssessmentTargetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssessmentTemplatesError::from_response(response))
         }
     }
 
-    /// <p>Lists the assessment templates that correspond to the assessment targets that are specified by the ARNs of the assessment targets.</p>
-    async fn list_assessment_templates(
+    /// Presigns the request `list_assessment_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_assessment_templates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssessmentTemplatesRequest,
-    ) -> Result<ListAssessmentTemplatesResponse, RusotoError<ListAssessmentTemplatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_assessment_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_assessment_templates_request(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListAssessmentTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListAssessmentTemplates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_event_subscriptions`, also returning the metadata of the response.
+    pub async fn list_event_subscriptions_with_metadata(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> Result<
+        (ListEventSubscriptionsResponse, ResponseMetadata),
+        RusotoError<ListEventSubscriptionsError>,
+    > {
+        let request = self.build_list_event_subscriptions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssessmentTemplatesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEventSubscriptionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssessmentTemplatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEventSubscriptionsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the event subscriptions for the assessment template that is specified by the ARN of the assessment template. For more information, see <a>SubscribeToEvent</a> and <a>UnsubscribeFromEvent</a>.</p>
-    async fn list_event_subscriptions(
+    /// Presigns the request `list_event_subscriptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_event_subscriptions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEventSubscriptionsRequest,
-    ) -> Result<ListEventSubscriptionsResponse, RusotoError<ListEventSubscriptionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_event_subscriptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_event_subscriptions_request(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListEventSubscriptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListEventSubscriptions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `list_exclusions`, also returning the metadata of the response.
+    pub async fn list_exclusions_with_metadata(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> Result<(ListExclusionsResponse, ResponseMetadata), RusotoError<ListExclusionsError>> {
+        let request = self.build_list_exclusions_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEventSubscriptionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListExclusionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEventSubscriptionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListExclusionsError::from_response(response))
         }
     }
 
-    /// <p>List exclusions that are generated by the assessment run.</p>
-    async fn list_exclusions(
+    /// Presigns the request `list_exclusions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_exclusions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListExclusionsRequest,
-    ) -> Result<ListExclusionsResponse, RusotoError<ListExclusionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_exclusions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_exclusions_request(&self, input: ListExclusionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListExclusions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListExclusions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_findings`, also returning the metadata of the response.
+    pub async fn list_findings_with_metadata(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<(ListFindingsResponse, ResponseMetadata), RusotoError<ListFindingsError>> {
+        let request = self.build_list_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListExclusionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListFindingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListExclusionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListFindingsError::from_response(response))
         }
     }
 
-    /// <p>Lists findings that are generated by the assessment runs that are specified by the ARNs of the assessment runs.</p>
-    async fn list_findings(
+    /// Presigns the request `list_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFindingsRequest,
-    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_findings_request(&self, input: ListFindingsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListFindings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListFindings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_rules_packages`, also returning the metadata of the response.
+    pub async fn list_rules_packages_with_metadata(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> Result<(ListRulesPackagesResponse, ResponseMetadata), RusotoError<ListRulesPackagesError>>
+    {
+        let request = self.build_list_rules_packages_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListFindingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRulesPackagesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFindingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRulesPackagesError::from_response(response))
         }
     }
 
-    /// <p>Lists all available Amazon Inspector rules packages.</p>
-    async fn list_rules_packages(
+    /// Presigns the request `list_rules_packages` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_rules_packages(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRulesPackagesRequest,
-    ) -> Result<ListRulesPackagesResponse, RusotoError<ListRulesPackagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_rules_packages_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_rules_packages_request(&self, input: ListRulesPackagesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListRulesPackages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListRulesPackages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRulesPackagesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRulesPackagesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists all tags associated with an assessment template.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `preview_agents`, also returning the metadata of the response.
+    pub async fn preview_agents_with_metadata(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> Result<(PreviewAgentsResponse, ResponseMetadata), RusotoError<PreviewAgentsError>> {
+        let request = self.build_preview_agents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PreviewAgentsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PreviewAgentsError::from_response(response))
         }
     }
 
-    /// <p>Previews the agents installed on the EC2 instances that are part of the specified assessment target.</p>
-    async fn preview_agents(
+    /// Presigns the request `preview_agents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_preview_agents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PreviewAgentsRequest,
-    ) -> Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_preview_agents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_preview_agents_request(&self, input: PreviewAgentsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("PreviewAgents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.PreviewAgents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_cross_account_access_role`, also returning the metadata of the response.
+    pub async fn register_cross_account_access_role_with_metadata(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RegisterCrossAccountAccessRoleError>> {
+        let request = self.build_register_cross_account_access_role_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PreviewAgentsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PreviewAgentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterCrossAccountAccessRoleError::from_response(response))
         }
     }
 
-    /// <p>Registers the IAM role that grants Amazon Inspector access to AWS Services needed to perform security assessments.</p>
-    async fn register_cross_account_access_role(
+    /// Presigns the request `register_cross_account_access_role` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_cross_account_access_role(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterCrossAccountAccessRoleRequest,
-    ) -> Result<(), RusotoError<RegisterCrossAccountAccessRoleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_cross_account_access_role_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_cross_account_access_role_request(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("RegisterCrossAccountAccessRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4657,28 +5958,58 @@ impl Inspector for InspectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_attributes_from_findings`, also returning the metadata of the response.
+    pub async fn remove_attributes_from_findings_with_metadata(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> Result<
+        (RemoveAttributesFromFindingsResponse, ResponseMetadata),
+        RusotoError<RemoveAttributesFromFindingsError>,
+    > {
+        let request = self.build_remove_attributes_from_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveAttributesFromFindingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterCrossAccountAccessRoleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveAttributesFromFindingsError::from_response(response))
         }
     }
 
-    /// <p>Removes entire attributes (key and value pairs) from the findings that are specified by the ARNs of the findings where an attribute with the specified key exists.</p>
-    async fn remove_attributes_from_findings(
+    /// Presigns the request `remove_attributes_from_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_attributes_from_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveAttributesFromFindingsRequest,
-    ) -> Result<RemoveAttributesFromFindingsResponse, RusotoError<RemoveAttributesFromFindingsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_attributes_from_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_attributes_from_findings_request(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("RemoveAttributesFromFindings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4688,182 +6019,1013 @@ impl Inspector for InspectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_tags_for_resource`, also returning the metadata of the response.
+    pub async fn set_tags_for_resource_with_metadata(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetTagsForResourceError>> {
+        let request = self.build_set_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RemoveAttributesFromFindingsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveAttributesFromFindingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Sets tags (key and value pairs) to the assessment template that is specified by the ARN of the assessment template.</p>
-    async fn set_tags_for_resource(
+    /// Presigns the request `set_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetTagsForResourceRequest,
-    ) -> Result<(), RusotoError<SetTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_tags_for_resource_request(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("SetTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.SetTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_assessment_run`, also returning the metadata of the response.
+    pub async fn start_assessment_run_with_metadata(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> Result<(StartAssessmentRunResponse, ResponseMetadata), RusotoError<StartAssessmentRunError>>
+    {
+        let request = self.build_start_assessment_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartAssessmentRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartAssessmentRunError::from_response(response))
         }
     }
 
-    /// <p>Starts the assessment run specified by the ARN of the assessment template. For this API to function properly, you must not exceed the limit of running up to 500 concurrent agents per AWS account.</p>
-    async fn start_assessment_run(
+    /// Presigns the request `start_assessment_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_assessment_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartAssessmentRunRequest,
-    ) -> Result<StartAssessmentRunResponse, RusotoError<StartAssessmentRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_assessment_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_assessment_run_request(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("StartAssessmentRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.StartAssessmentRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_assessment_run`, also returning the metadata of the response.
+    pub async fn stop_assessment_run_with_metadata(
+        &self,
+        input: StopAssessmentRunRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopAssessmentRunError>> {
+        let request = self.build_stop_assessment_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartAssessmentRunResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartAssessmentRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopAssessmentRunError::from_response(response))
         }
     }
 
-    /// <p>Stops the assessment run that is specified by the ARN of the assessment run.</p>
-    async fn stop_assessment_run(
+    /// Presigns the request `stop_assessment_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_assessment_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopAssessmentRunRequest,
-    ) -> Result<(), RusotoError<StopAssessmentRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_assessment_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_assessment_run_request(&self, input: StopAssessmentRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("StopAssessmentRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.StopAssessmentRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `subscribe_to_event`, also returning the metadata of the response.
+    pub async fn subscribe_to_event_with_metadata(
+        &self,
+        input: SubscribeToEventRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<SubscribeToEventError>> {
+        let request = self.build_subscribe_to_event_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopAssessmentRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SubscribeToEventError::from_response(response))
         }
     }
 
-    /// <p>Enables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
-    async fn subscribe_to_event(
+    /// Presigns the request `subscribe_to_event` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_subscribe_to_event(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SubscribeToEventRequest,
-    ) -> Result<(), RusotoError<SubscribeToEventError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_subscribe_to_event_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_subscribe_to_event_request(&self, input: SubscribeToEventRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("SubscribeToEvent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.SubscribeToEvent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `unsubscribe_from_event`, also returning the metadata of the response.
+    pub async fn unsubscribe_from_event_with_metadata(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UnsubscribeFromEventError>> {
+        let request = self.build_unsubscribe_from_event_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SubscribeToEventError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UnsubscribeFromEventError::from_response(response))
         }
     }
 
-    /// <p>Disables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
-    async fn unsubscribe_from_event(
+    /// Presigns the request `unsubscribe_from_event` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unsubscribe_from_event(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnsubscribeFromEventRequest,
-    ) -> Result<(), RusotoError<UnsubscribeFromEventError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unsubscribe_from_event_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unsubscribe_from_event_request(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("UnsubscribeFromEvent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.UnsubscribeFromEvent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_assessment_target`, also returning the metadata of the response.
+    pub async fn update_assessment_target_with_metadata(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateAssessmentTargetError>> {
+        let request = self.build_update_assessment_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UnsubscribeFromEventError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAssessmentTargetError::from_response(response))
         }
     }
 
-    /// <p>Updates the assessment target that is specified by the ARN of the assessment target.</p> <p>If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target.</p>
-    async fn update_assessment_target(
+    /// Presigns the request `update_assessment_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_assessment_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAssessmentTargetRequest,
-    ) -> Result<(), RusotoError<UpdateAssessmentTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_assessment_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_assessment_target_request(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "inspector", &self.region, "/");
+        request.set_operation("UpdateAssessmentTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "InspectorService.UpdateAssessmentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAssessmentTargetError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `ListAssessmentRunAgents` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_assessment_run_agents_pages(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAssessmentRunAgentsResponse, RusotoError<ListAssessmentRunAgentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input| async move {
+                client.list_assessment_run_agents(input).await
+            },
+            |input: &ListAssessmentRunAgentsRequest, output: &ListAssessmentRunAgentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssessmentRunAgentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `assessmentRunAgents` of every page of `ListAssessmentRunAgents` results for `input`, as `list_assessment_run_agents_pages` requests them.
+    pub fn list_assessment_run_agents_stream(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AssessmentRunAgent, RusotoError<ListAssessmentRunAgentsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_assessment_run_agents_pages(input), |page| {
+            page.assessment_run_agents
+        })
+    }
+
+    /// The pages of `ListAssessmentRuns` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_assessment_runs_pages(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAssessmentRunsResponse, RusotoError<ListAssessmentRunsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input| async move { client.list_assessment_runs(input).await },
+            |input: &ListAssessmentRunsRequest, output: &ListAssessmentRunsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssessmentRunsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `assessmentRunArns` of every page of `ListAssessmentRuns` results for `input`, as `list_assessment_runs_pages` requests them.
+    pub fn list_assessment_runs_stream(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListAssessmentRunsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_assessment_runs_pages(input), |page| {
+            page.assessment_run_arns
+        })
+    }
+
+    /// The pages of `ListAssessmentTargets` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_assessment_targets_pages(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAssessmentTargetsResponse, RusotoError<ListAssessmentTargetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input| async move { client.list_assessment_targets(input).await },
+            |input: &ListAssessmentTargetsRequest, output: &ListAssessmentTargetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssessmentTargetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `assessmentTargetArns` of every page of `ListAssessmentTargets` results for `input`, as `list_assessment_targets_pages` requests them.
+    pub fn list_assessment_targets_stream(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListAssessmentTargetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_assessment_targets_pages(input), |page| {
+            page.assessment_target_arns
+        })
+    }
+
+    /// The pages of `ListAssessmentTemplates` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_assessment_templates_pages(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAssessmentTemplatesResponse, RusotoError<ListAssessmentTemplatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input| async move {
+                client.list_assessment_templates(input).await
+            },
+            |input: &ListAssessmentTemplatesRequest, output: &ListAssessmentTemplatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssessmentTemplatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `assessmentTemplateArns` of every page of `ListAssessmentTemplates` results for `input`, as `list_assessment_templates_pages` requests them.
+    pub fn list_assessment_templates_stream(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListAssessmentTemplatesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_assessment_templates_pages(input), |page| {
+            page.assessment_template_arns
+        })
+    }
+
+    /// The pages of `ListEventSubscriptions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_event_subscriptions_pages(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListEventSubscriptionsResponse, RusotoError<ListEventSubscriptionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input| async move { client.list_event_subscriptions(input).await },
+            |input: &ListEventSubscriptionsRequest, output: &ListEventSubscriptionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEventSubscriptionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `subscriptions` of every page of `ListEventSubscriptions` results for `input`, as `list_event_subscriptions_pages` requests them.
+    pub fn list_event_subscriptions_stream(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> impl futures::Stream<Item = Result<Subscription, RusotoError<ListEventSubscriptionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_event_subscriptions_pages(input), |page| {
+            page.subscriptions
+        })
+    }
+
+    /// The pages of `ListExclusions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_exclusions_pages(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListExclusionsResponse, RusotoError<ListExclusionsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input| async move { client.list_exclusions(input).await },
+            |input: &ListExclusionsRequest, output: &ListExclusionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListExclusionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `exclusionArns` of every page of `ListExclusions` results for `input`, as `list_exclusions_pages` requests them.
+    pub fn list_exclusions_stream(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListExclusionsError>>> + Send {
+        rusoto_core::pagination::items(self.list_exclusions_pages(input), |page| {
+            page.exclusion_arns
+        })
+    }
+
+    /// The pages of `ListFindings` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_findings_pages(
+        &self,
+        input: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFindingsResponse, RusotoError<ListFindingsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input| async move { client.list_findings(input).await },
+            |input: &ListFindingsRequest, output: &ListFindingsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFindingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `findingArns` of every page of `ListFindings` results for `input`, as `list_findings_pages` requests them.
+    pub fn list_findings_stream(
+        &self,
+        input: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListFindingsError>>> + Send {
+        rusoto_core::pagination::items(self.list_findings_pages(input), |page| page.finding_arns)
+    }
+
+    /// The pages of `ListRulesPackages` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_rules_packages_pages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListRulesPackagesResponse, RusotoError<ListRulesPackagesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input| async move { client.list_rules_packages(input).await },
+            |input: &ListRulesPackagesRequest, output: &ListRulesPackagesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRulesPackagesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `rulesPackageArns` of every page of `ListRulesPackages` results for `input`, as `list_rules_packages_pages` requests them.
+    pub fn list_rules_packages_stream(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListRulesPackagesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_rules_packages_pages(input), |page| {
+            page.rules_package_arns
+        })
+    }
+
+    /// The pages of `PreviewAgents` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn preview_agents_pages(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> impl futures::Stream<Item = Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: InspectorClient, input| async move { client.preview_agents(input).await },
+            |input: &PreviewAgentsRequest, output: &PreviewAgentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(PreviewAgentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `agentPreviews` of every page of `PreviewAgents` results for `input`, as `preview_agents_pages` requests them.
+    pub fn preview_agents_stream(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> impl futures::Stream<Item = Result<AgentPreview, RusotoError<PreviewAgentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.preview_agents_pages(input), |page| page.agent_previews)
+    }
+}
+
+#[async_trait]
+impl Inspector for InspectorClient {
+    /// <p>Assigns attributes (key and value pairs) to the findings that are specified by the ARNs of the findings.</p>
+    async fn add_attributes_to_findings(
+        &self,
+        input: AddAttributesToFindingsRequest,
+    ) -> Result<AddAttributesToFindingsResponse, RusotoError<AddAttributesToFindingsError>> {
+        self.add_attributes_to_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new assessment target using the ARN of the resource group that is generated by <a>CreateResourceGroup</a>. If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments. You can create up to 50 assessment targets per AWS account. You can run up to 500 concurrent agents per AWS account. For more information, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html"> Amazon Inspector Assessment Targets</a>.</p>
+    async fn create_assessment_target(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> Result<CreateAssessmentTargetResponse, RusotoError<CreateAssessmentTargetError>> {
+        self.create_assessment_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an assessment template for the assessment target that is specified by the ARN of the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments.</p>
+    async fn create_assessment_template(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> Result<CreateAssessmentTemplateResponse, RusotoError<CreateAssessmentTemplateError>> {
+        self.create_assessment_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the generation of an exclusions preview for the specified assessment template. The exclusions preview lists the potential exclusions (ExclusionPreview) that Inspector can detect before it runs the assessment. </p>
+    async fn create_exclusions_preview(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> Result<CreateExclusionsPreviewResponse, RusotoError<CreateExclusionsPreviewError>> {
+        self.create_exclusions_preview_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a resource group using the specified set of tags (key and value pairs) that are used to select the EC2 instances to be included in an Amazon Inspector assessment target. The created resource group is then used to create an Amazon Inspector assessment target. For more information, see <a>CreateAssessmentTarget</a>.</p>
+    async fn create_resource_group(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> Result<CreateResourceGroupResponse, RusotoError<CreateResourceGroupError>> {
+        self.create_resource_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the assessment run that is specified by the ARN of the assessment run.</p>
+    async fn delete_assessment_run(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> Result<(), RusotoError<DeleteAssessmentRunError>> {
+        self.delete_assessment_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the assessment target that is specified by the ARN of the assessment target.</p>
+    async fn delete_assessment_target(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> Result<(), RusotoError<DeleteAssessmentTargetError>> {
+        self.delete_assessment_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the assessment template that is specified by the ARN of the assessment template.</p>
+    async fn delete_assessment_template(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> Result<(), RusotoError<DeleteAssessmentTemplateError>> {
+        self.delete_assessment_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the assessment runs that are specified by the ARNs of the assessment runs.</p>
+    async fn describe_assessment_runs(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> Result<DescribeAssessmentRunsResponse, RusotoError<DescribeAssessmentRunsError>> {
+        self.describe_assessment_runs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the assessment targets that are specified by the ARNs of the assessment targets.</p>
+    async fn describe_assessment_targets(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> Result<DescribeAssessmentTargetsResponse, RusotoError<DescribeAssessmentTargetsError>>
+    {
+        self.describe_assessment_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the assessment templates that are specified by the ARNs of the assessment templates.</p>
+    async fn describe_assessment_templates(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> Result<DescribeAssessmentTemplatesResponse, RusotoError<DescribeAssessmentTemplatesError>>
+    {
+        self.describe_assessment_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the IAM role that enables Amazon Inspector to access your AWS account.</p>
+    async fn describe_cross_account_access_role(
+        &self,
+    ) -> Result<
+        DescribeCrossAccountAccessRoleResponse,
+        RusotoError<DescribeCrossAccountAccessRoleError>,
+    > {
+        self.describe_cross_account_access_role_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the exclusions that are specified by the exclusions' ARNs.</p>
+    async fn describe_exclusions(
+        &self,
+        input: DescribeExclusionsRequest,
+    ) -> Result<DescribeExclusionsResponse, RusotoError<DescribeExclusionsError>> {
+        self.describe_exclusions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the findings that are specified by the ARNs of the findings.</p>
+    async fn describe_findings(
+        &self,
+        input: DescribeFindingsRequest,
+    ) -> Result<DescribeFindingsResponse, RusotoError<DescribeFindingsError>> {
+        self.describe_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the resource groups that are specified by the ARNs of the resource groups.</p>
+    async fn describe_resource_groups(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> Result<DescribeResourceGroupsResponse, RusotoError<DescribeResourceGroupsError>> {
+        self.describe_resource_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the rules packages that are specified by the ARNs of the rules packages.</p>
+    async fn describe_rules_packages(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> Result<DescribeRulesPackagesResponse, RusotoError<DescribeRulesPackagesError>> {
+        self.describe_rules_packages_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Produces an assessment report that includes detailed and comprehensive results of a specified assessment run. </p>
+    async fn get_assessment_report(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> Result<GetAssessmentReportResponse, RusotoError<GetAssessmentReportError>> {
+        self.get_assessment_report_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the exclusions preview (a list of ExclusionPreview objects) specified by the preview token. You can obtain the preview token by running the CreateExclusionsPreview API.</p>
+    async fn get_exclusions_preview(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> Result<GetExclusionsPreviewResponse, RusotoError<GetExclusionsPreviewError>> {
+        self.get_exclusions_preview_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Information about the data that is collected for the specified assessment run.</p>
+    async fn get_telemetry_metadata(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> Result<GetTelemetryMetadataResponse, RusotoError<GetTelemetryMetadataError>> {
+        self.get_telemetry_metadata_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the agents of the assessment runs that are specified by the ARNs of the assessment runs.</p>
+    async fn list_assessment_run_agents(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> Result<ListAssessmentRunAgentsResponse, RusotoError<ListAssessmentRunAgentsError>> {
+        self.list_assessment_run_agents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the assessment runs that correspond to the assessment templates that are specified by the ARNs of the assessment templates.</p>
+    async fn list_assessment_runs(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> Result<ListAssessmentRunsResponse, RusotoError<ListAssessmentRunsError>> {
+        self.list_assessment_runs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the ARNs of the assessment targets within this AWS account. For more information about assessment targets, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html">Amazon Inspector Assessment Targets</a>.</p>
+    async fn list_assessment_targets(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> Result<ListAssessmentTargetsResponse, RusotoError<ListAssessmentTargetsError>> {
+        self.list_assessment_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the assessment templates that correspond to the assessment targets that are specified by the ARNs of the assessment targets.</p>
+    async fn list_assessment_templates(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> Result<ListAssessmentTemplatesResponse, RusotoError<ListAssessmentTemplatesError>> {
+        self.list_assessment_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the event subscriptions for the assessment template that is specified by the ARN of the assessment template. For more information, see <a>SubscribeToEvent</a> and <a>UnsubscribeFromEvent</a>.</p>
+    async fn list_event_subscriptions(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> Result<ListEventSubscriptionsResponse, RusotoError<ListEventSubscriptionsError>> {
+        self.list_event_subscriptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List exclusions that are generated by the assessment run.</p>
+    async fn list_exclusions(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> Result<ListExclusionsResponse, RusotoError<ListExclusionsError>> {
+        self.list_exclusions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists findings that are generated by the assessment runs that are specified by the ARNs of the assessment runs.</p>
+    async fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+        self.list_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all available Amazon Inspector rules packages.</p>
+    async fn list_rules_packages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> Result<ListRulesPackagesResponse, RusotoError<ListRulesPackagesError>> {
+        self.list_rules_packages_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags associated with an assessment template.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Previews the agents installed on the EC2 instances that are part of the specified assessment target.</p>
+    async fn preview_agents(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>> {
+        self.preview_agents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers the IAM role that grants Amazon Inspector access to AWS Services needed to perform security assessments.</p>
+    async fn register_cross_account_access_role(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> Result<(), RusotoError<RegisterCrossAccountAccessRoleError>> {
+        self.register_cross_account_access_role_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes entire attributes (key and value pairs) from the findings that are specified by the ARNs of the findings where an attribute with the specified key exists.</p>
+    async fn remove_attributes_from_findings(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> Result<RemoveAttributesFromFindingsResponse, RusotoError<RemoveAttributesFromFindingsError>>
+    {
+        self.remove_attributes_from_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets tags (key and value pairs) to the assessment template that is specified by the ARN of the assessment template.</p>
+    async fn set_tags_for_resource(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> Result<(), RusotoError<SetTagsForResourceError>> {
+        self.set_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the assessment run specified by the ARN of the assessment template. For this API to function properly, you must not exceed the limit of running up to 500 concurrent agents per AWS account.</p>
+    async fn start_assessment_run(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> Result<StartAssessmentRunResponse, RusotoError<StartAssessmentRunError>> {
+        self.start_assessment_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the assessment run that is specified by the ARN of the assessment run.</p>
+    async fn stop_assessment_run(
+        &self,
+        input: StopAssessmentRunRequest,
+    ) -> Result<(), RusotoError<StopAssessmentRunError>> {
+        self.stop_assessment_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
+    async fn subscribe_to_event(
+        &self,
+        input: SubscribeToEventRequest,
+    ) -> Result<(), RusotoError<SubscribeToEventError>> {
+        self.subscribe_to_event_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
+    async fn unsubscribe_from_event(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> Result<(), RusotoError<UnsubscribeFromEventError>> {
+        self.unsubscribe_from_event_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the assessment target that is specified by the ARN of the assessment target.</p> <p>If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target.</p>
+    async fn update_assessment_target(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> Result<(), RusotoError<UpdateAssessmentTargetError>> {
+        self.update_assessment_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
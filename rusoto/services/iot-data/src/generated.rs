@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -104,6 +104,7 @@ pub struct UpdateThingShadowResponse {
 
 /// Errors returned by DeleteThingShadow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteThingShadowError {
     /// <p>An unexpected error has occurred.</p>
     InternalFailure(String),
@@ -121,6 +122,8 @@ pub enum DeleteThingShadowError {
     Unauthorized(String),
     /// <p>The document encoding is not supported.</p>
     UnsupportedDocumentEncoding(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteThingShadowError {
@@ -128,35 +131,52 @@ impl DeleteThingShadowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThingShadowError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteThingShadowError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteThingShadowError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteThingShadowError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(DeleteThingShadowError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        DeleteThingShadowError::MethodNotAllowed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThingShadowError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteThingShadowError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteThingShadowError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteThingShadowError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThingShadowError::Throttling(err.msg))
+                    return RusotoError::service(DeleteThingShadowError::Throttling(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteThingShadowError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteThingShadowError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedDocumentEncodingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteThingShadowError::UnsupportedDocumentEncoding(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteThingShadowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -176,12 +196,14 @@ impl fmt::Display for DeleteThingShadowError {
             DeleteThingShadowError::UnsupportedDocumentEncoding(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteThingShadowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteThingShadowError {}
 /// Errors returned by GetThingShadow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetThingShadowError {
     /// <p>An unexpected error has occurred.</p>
     InternalFailure(String),
@@ -199,6 +221,8 @@ pub enum GetThingShadowError {
     Unauthorized(String),
     /// <p>The document encoding is not supported.</p>
     UnsupportedDocumentEncoding(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetThingShadowError {
@@ -206,33 +230,46 @@ impl GetThingShadowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetThingShadowError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetThingShadowError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetThingShadowError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetThingShadowError::InvalidRequest(err.msg), &res)
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetThingShadowError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        GetThingShadowError::MethodNotAllowed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetThingShadowError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetThingShadowError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetThingShadowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetThingShadowError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetThingShadowError::Throttling(err.msg))
+                    return RusotoError::service(GetThingShadowError::Throttling(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetThingShadowError::Unauthorized(err.msg))
+                    return RusotoError::service(GetThingShadowError::Unauthorized(err.msg), &res)
                 }
                 "UnsupportedDocumentEncodingException" => {
-                    return RusotoError::Service(GetThingShadowError::UnsupportedDocumentEncoding(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetThingShadowError::UnsupportedDocumentEncoding(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetThingShadowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -250,12 +287,14 @@ impl fmt::Display for GetThingShadowError {
             GetThingShadowError::Throttling(ref cause) => write!(f, "{}", cause),
             GetThingShadowError::Unauthorized(ref cause) => write!(f, "{}", cause),
             GetThingShadowError::UnsupportedDocumentEncoding(ref cause) => write!(f, "{}", cause),
+            GetThingShadowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetThingShadowError {}
 /// Errors returned by Publish
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PublishError {
     /// <p>An unexpected error has occurred.</p>
     InternalFailure(String),
@@ -265,6 +304,8 @@ pub enum PublishError {
     MethodNotAllowed(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PublishError {
@@ -272,19 +313,19 @@ impl PublishError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(PublishError::InternalFailure(err.msg))
+                    return RusotoError::service(PublishError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PublishError::InvalidRequest(err.msg))
+                    return RusotoError::service(PublishError::InvalidRequest(err.msg), &res)
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(PublishError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(PublishError::MethodNotAllowed(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PublishError::Unauthorized(err.msg))
+                    return RusotoError::service(PublishError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PublishError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -298,12 +339,14 @@ impl fmt::Display for PublishError {
             PublishError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             PublishError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             PublishError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PublishError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PublishError {}
 /// Errors returned by UpdateThingShadow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateThingShadowError {
     /// <p>The specified version does not match the version of the document.</p>
     Conflict(String),
@@ -323,6 +366,8 @@ pub enum UpdateThingShadowError {
     Unauthorized(String),
     /// <p>The document encoding is not supported.</p>
     UnsupportedDocumentEncoding(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateThingShadowError {
@@ -330,40 +375,55 @@ impl UpdateThingShadowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateThingShadowError::Conflict(err.msg))
+                    return RusotoError::service(UpdateThingShadowError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThingShadowError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateThingShadowError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateThingShadowError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateThingShadowError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UpdateThingShadowError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        UpdateThingShadowError::MethodNotAllowed(err.msg),
+                        &res,
+                    )
                 }
                 "RequestEntityTooLargeException" => {
-                    return RusotoError::Service(UpdateThingShadowError::RequestEntityTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateThingShadowError::RequestEntityTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateThingShadowError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateThingShadowError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThingShadowError::Throttling(err.msg))
+                    return RusotoError::service(UpdateThingShadowError::Throttling(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateThingShadowError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateThingShadowError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedDocumentEncodingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateThingShadowError::UnsupportedDocumentEncoding(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateThingShadowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -384,6 +444,7 @@ impl fmt::Display for UpdateThingShadowError {
             UpdateThingShadowError::UnsupportedDocumentEncoding(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateThingShadowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -423,6 +484,7 @@ impl IotDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> IotDataClient {
         IotDataClient {
             client: Client::shared(),
@@ -448,75 +510,178 @@ impl IotDataClient {
     pub fn new_with_client(client: Client, region: region::Region) -> IotDataClient {
         IotDataClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<IotDataClient> {
+        rusoto_core::ClientBuilder::new(IotDataClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> IotDataClient {
+        IotDataClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl IotData for IotDataClient {
-    /// <p>Deletes the thing shadow for the specified thing.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/API_DeleteThingShadow.html">DeleteThingShadow</a> in the <i>AWS IoT Developer Guide</i>.</p>
-    async fn delete_thing_shadow(
+impl IotDataClient {
+    /// Calls `delete_thing_shadow`, also returning the metadata of the response.
+    pub async fn delete_thing_shadow_with_metadata(
         &self,
         input: DeleteThingShadowRequest,
-    ) -> Result<DeleteThingShadowResponse, RusotoError<DeleteThingShadowError>> {
+    ) -> Result<(DeleteThingShadowResponse, ResponseMetadata), RusotoError<DeleteThingShadowError>>
+    {
+        let request = self.build_delete_thing_shadow_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+
+            let mut result = DeleteThingShadowResponse::default();
+            result.payload = response.body;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteThingShadowError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_thing_shadow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_thing_shadow(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteThingShadowRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_thing_shadow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_thing_shadow_request(&self, input: DeleteThingShadowRequest) -> SignedRequest {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("DELETE", "iotdata", &self.region, &request_uri);
+        request.set_operation("DeleteThingShadow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
 
+        request
+    }
+
+    /// Calls `get_thing_shadow`, also returning the metadata of the response.
+    pub async fn get_thing_shadow_with_metadata(
+        &self,
+        input: GetThingShadowRequest,
+    ) -> Result<(GetThingShadowResponse, ResponseMetadata), RusotoError<GetThingShadowError>> {
+        let request = self.build_get_thing_shadow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
 
-            let mut result = DeleteThingShadowResponse::default();
-            result.payload = response.body;
+            let mut result = GetThingShadowResponse::default();
+            result.payload = Some(response.body);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteThingShadowError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetThingShadowError::from_response(response))
         }
     }
 
-    /// <p>Gets the thing shadow for the specified thing.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/API_GetThingShadow.html">GetThingShadow</a> in the <i>AWS IoT Developer Guide</i>.</p>
-    async fn get_thing_shadow(
+    /// Presigns the request `get_thing_shadow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_thing_shadow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetThingShadowRequest,
-    ) -> Result<GetThingShadowResponse, RusotoError<GetThingShadowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_thing_shadow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_thing_shadow_request(&self, input: GetThingShadowRequest) -> SignedRequest {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iotdata", &self.region, &request_uri);
+        request.set_operation("GetThingShadow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
 
+        request
+    }
+
+    /// Calls `publish`, also returning the metadata of the response.
+    pub async fn publish_with_metadata(
+        &self,
+        input: PublishRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PublishError>> {
+        let request = self.build_publish_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-
-            let mut result = GetThingShadowResponse::default();
-            result.payload = Some(response.body);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetThingShadowError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PublishError::from_response(response))
         }
     }
 
-    /// <p>Publishes state information.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/protocols.html#http">HTTP Protocol</a> in the <i>AWS IoT Developer Guide</i>.</p>
-    async fn publish(&self, input: PublishRequest) -> Result<(), RusotoError<PublishError>> {
+    /// Presigns the request `publish` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_publish(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PublishRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_publish_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_publish_request(&self, input: PublishRequest) -> SignedRequest {
         let request_uri = format!("/topics/{topic}", topic = input.topic);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation("Publish");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -533,51 +698,103 @@ impl IotData for IotDataClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_thing_shadow`, also returning the metadata of the response.
+    pub async fn update_thing_shadow_with_metadata(
+        &self,
+        input: UpdateThingShadowRequest,
+    ) -> Result<(UpdateThingShadowResponse, ResponseMetadata), RusotoError<UpdateThingShadowError>>
+    {
+        let request = self.build_update_thing_shadow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+
+            let mut result = UpdateThingShadowResponse::default();
+            result.payload = Some(response.body);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PublishError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateThingShadowError::from_response(response))
         }
     }
 
-    /// <p>Updates the thing shadow for the specified thing.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/API_UpdateThingShadow.html">UpdateThingShadow</a> in the <i>AWS IoT Developer Guide</i>.</p>
-    async fn update_thing_shadow(
+    /// Presigns the request `update_thing_shadow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_thing_shadow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateThingShadowRequest,
-    ) -> Result<UpdateThingShadowResponse, RusotoError<UpdateThingShadowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_thing_shadow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_thing_shadow_request(&self, input: UpdateThingShadowRequest) -> SignedRequest {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation("UpdateThingShadow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
         let encoded = Some(input.payload.to_owned());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl IotData for IotDataClient {
+    /// <p>Deletes the thing shadow for the specified thing.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/API_DeleteThingShadow.html">DeleteThingShadow</a> in the <i>AWS IoT Developer Guide</i>.</p>
+    async fn delete_thing_shadow(
+        &self,
+        input: DeleteThingShadowRequest,
+    ) -> Result<DeleteThingShadowResponse, RusotoError<DeleteThingShadowError>> {
+        self.delete_thing_shadow_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            .map(|(output, _)| output)
+    }
 
-            let mut result = UpdateThingShadowResponse::default();
-            result.payload = Some(response.body);
+    /// <p>Gets the thing shadow for the specified thing.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/API_GetThingShadow.html">GetThingShadow</a> in the <i>AWS IoT Developer Guide</i>.</p>
+    async fn get_thing_shadow(
+        &self,
+        input: GetThingShadowRequest,
+    ) -> Result<GetThingShadowResponse, RusotoError<GetThingShadowError>> {
+        self.get_thing_shadow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateThingShadowError::from_response(response))
-        }
+    /// <p>Publishes state information.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/protocols.html#http">HTTP Protocol</a> in the <i>AWS IoT Developer Guide</i>.</p>
+    async fn publish(&self, input: PublishRequest) -> Result<(), RusotoError<PublishError>> {
+        self.publish_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the thing shadow for the specified thing.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/API_UpdateThingShadow.html">UpdateThingShadow</a> in the <i>AWS IoT Developer Guide</i>.</p>
+    async fn update_thing_shadow(
+        &self,
+        input: UpdateThingShadowRequest,
+    ) -> Result<UpdateThingShadowResponse, RusotoError<UpdateThingShadowError>> {
+        self.update_thing_shadow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
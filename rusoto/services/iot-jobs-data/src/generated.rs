@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -250,6 +250,7 @@ pub struct UpdateJobExecutionResponse {
 
 /// Errors returned by DescribeJobExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeJobExecutionError {
     /// <p>The certificate is invalid.</p>
     CertificateValidation(String),
@@ -263,6 +264,8 @@ pub enum DescribeJobExecutionError {
     TerminalState(String),
     /// <p>The rate exceeds the limit.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeJobExecutionError {
@@ -270,31 +273,43 @@ impl DescribeJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::CertificateValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeJobExecutionError::CertificateValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeJobExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeJobExecutionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeJobExecutionError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TerminalStateException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::TerminalState(err.msg))
+                    return RusotoError::service(
+                        DescribeJobExecutionError::TerminalState(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeJobExecutionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeJobExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -310,12 +325,14 @@ impl fmt::Display for DescribeJobExecutionError {
             DescribeJobExecutionError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeJobExecutionError::TerminalState(ref cause) => write!(f, "{}", cause),
             DescribeJobExecutionError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeJobExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeJobExecutionError {}
 /// Errors returned by GetPendingJobExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPendingJobExecutionsError {
     /// <p>The certificate is invalid.</p>
     CertificateValidation(String),
@@ -327,6 +344,8 @@ pub enum GetPendingJobExecutionsError {
     ServiceUnavailable(String),
     /// <p>The rate exceeds the limit.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPendingJobExecutionsError {
@@ -334,30 +353,37 @@ impl GetPendingJobExecutionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPendingJobExecutionsError::CertificateValidation(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPendingJobExecutionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPendingJobExecutionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPendingJobExecutionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetPendingJobExecutionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPendingJobExecutionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -374,12 +400,14 @@ impl fmt::Display for GetPendingJobExecutionsError {
             GetPendingJobExecutionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetPendingJobExecutionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetPendingJobExecutionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetPendingJobExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPendingJobExecutionsError {}
 /// Errors returned by StartNextPendingJobExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartNextPendingJobExecutionError {
     /// <p>The certificate is invalid.</p>
     CertificateValidation(String),
@@ -391,6 +419,8 @@ pub enum StartNextPendingJobExecutionError {
     ServiceUnavailable(String),
     /// <p>The rate exceeds the limit.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartNextPendingJobExecutionError {
@@ -400,32 +430,42 @@ impl StartNextPendingJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartNextPendingJobExecutionError::CertificateValidation(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartNextPendingJobExecutionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartNextPendingJobExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartNextPendingJobExecutionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartNextPendingJobExecutionError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartNextPendingJobExecutionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartNextPendingJobExecutionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartNextPendingJobExecutionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -446,12 +486,14 @@ impl fmt::Display for StartNextPendingJobExecutionError {
                 write!(f, "{}", cause)
             }
             StartNextPendingJobExecutionError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartNextPendingJobExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartNextPendingJobExecutionError {}
 /// Errors returned by UpdateJobExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateJobExecutionError {
     /// <p>The certificate is invalid.</p>
     CertificateValidation(String),
@@ -465,6 +507,8 @@ pub enum UpdateJobExecutionError {
     ServiceUnavailable(String),
     /// <p>The rate exceeds the limit.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateJobExecutionError {
@@ -472,31 +516,40 @@ impl UpdateJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::CertificateValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateJobExecutionError::CertificateValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateJobExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::InvalidStateTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateJobExecutionError::InvalidStateTransition(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateJobExecutionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateJobExecutionError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service(UpdateJobExecutionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateJobExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -512,6 +565,7 @@ impl fmt::Display for UpdateJobExecutionError {
             UpdateJobExecutionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateJobExecutionError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateJobExecutionError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateJobExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -554,6 +608,7 @@ impl IotJobsDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> IotJobsDataClient {
         IotJobsDataClient {
             client: Client::shared(),
@@ -579,15 +634,72 @@ impl IotJobsDataClient {
     pub fn new_with_client(client: Client, region: region::Region) -> IotJobsDataClient {
         IotJobsDataClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<IotJobsDataClient> {
+        rusoto_core::ClientBuilder::new(IotJobsDataClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> IotJobsDataClient {
+        IotJobsDataClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl IotJobsData for IotJobsDataClient {
-    /// <p>Gets details of a job execution.</p>
-    async fn describe_job_execution(
+impl IotJobsDataClient {
+    /// Calls `describe_job_execution`, also returning the metadata of the response.
+    pub async fn describe_job_execution_with_metadata(
         &self,
         input: DescribeJobExecutionRequest,
-    ) -> Result<DescribeJobExecutionResponse, RusotoError<DescribeJobExecutionError>> {
+    ) -> Result<
+        (DescribeJobExecutionResponse, ResponseMetadata),
+        RusotoError<DescribeJobExecutionError>,
+    > {
+        let request = self.build_describe_job_execution_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeJobExecutionResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeJobExecutionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `describe_job_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_job_execution(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DescribeJobExecutionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_job_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_job_execution_request(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/things/{thing_name}/jobs/{job_id}",
             job_id = input.job_id,
@@ -595,6 +707,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("DescribeJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -608,92 +721,178 @@ impl IotJobsData for IotJobsDataClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_pending_job_executions`, also returning the metadata of the response.
+    pub async fn get_pending_job_executions_with_metadata(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> Result<
+        (GetPendingJobExecutionsResponse, ResponseMetadata),
+        RusotoError<GetPendingJobExecutionsError>,
+    > {
+        let request = self.build_get_pending_job_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeJobExecutionResponse, _>()?;
+                .deserialize::<GetPendingJobExecutionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeJobExecutionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetPendingJobExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Gets the list of all jobs for a thing that are not in a terminal status.</p>
-    async fn get_pending_job_executions(
+    /// Presigns the request `get_pending_job_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_pending_job_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPendingJobExecutionsRequest,
-    ) -> Result<GetPendingJobExecutionsResponse, RusotoError<GetPendingJobExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_pending_job_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_pending_job_executions_request(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/things/{thing_name}/jobs", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("GetPendingJobExecutions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
 
+        request
+    }
+
+    /// Calls `start_next_pending_job_execution`, also returning the metadata of the response.
+    pub async fn start_next_pending_job_execution_with_metadata(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> Result<
+        (StartNextPendingJobExecutionResponse, ResponseMetadata),
+        RusotoError<StartNextPendingJobExecutionError>,
+    > {
+        let request = self.build_start_next_pending_job_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPendingJobExecutionsResponse, _>()?;
+                .deserialize::<StartNextPendingJobExecutionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPendingJobExecutionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartNextPendingJobExecutionError::from_response(response))
         }
     }
 
-    /// <p>Gets and starts the next pending (status IN_PROGRESS or QUEUED) job execution for a thing.</p>
-    async fn start_next_pending_job_execution(
+    /// Presigns the request `start_next_pending_job_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_next_pending_job_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartNextPendingJobExecutionRequest,
-    ) -> Result<StartNextPendingJobExecutionResponse, RusotoError<StartNextPendingJobExecutionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_next_pending_job_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_next_pending_job_execution_request(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/things/{thing_name}/jobs/$next",
             thing_name = input.thing_name
         );
 
         let mut request = SignedRequest::new("PUT", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("StartNextPendingJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_job_execution`, also returning the metadata of the response.
+    pub async fn update_job_execution_with_metadata(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> Result<(UpdateJobExecutionResponse, ResponseMetadata), RusotoError<UpdateJobExecutionError>>
+    {
+        let request = self.build_update_job_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartNextPendingJobExecutionResponse, _>()?;
+                .deserialize::<UpdateJobExecutionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartNextPendingJobExecutionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateJobExecutionError::from_response(response))
         }
     }
 
-    /// <p>Updates the status of a job execution.</p>
-    async fn update_job_execution(
+    /// Presigns the request `update_job_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_job_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateJobExecutionRequest,
-    ) -> Result<UpdateJobExecutionResponse, RusotoError<UpdateJobExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_job_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_job_execution_request(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/things/{thing_name}/jobs/{job_id}",
             job_id = input.job_id,
@@ -701,26 +900,57 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("POST", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("UpdateJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl IotJobsData for IotJobsDataClient {
+    /// <p>Gets details of a job execution.</p>
+    async fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> Result<DescribeJobExecutionResponse, RusotoError<DescribeJobExecutionError>> {
+        self.describe_job_execution_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateJobExecutionResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateJobExecutionError::from_response(response))
-        }
+    /// <p>Gets the list of all jobs for a thing that are not in a terminal status.</p>
+    async fn get_pending_job_executions(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> Result<GetPendingJobExecutionsResponse, RusotoError<GetPendingJobExecutionsError>> {
+        self.get_pending_job_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets and starts the next pending (status IN_PROGRESS or QUEUED) job execution for a thing.</p>
+    async fn start_next_pending_job_execution(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> Result<StartNextPendingJobExecutionResponse, RusotoError<StartNextPendingJobExecutionError>>
+    {
+        self.start_next_pending_job_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the status of a job execution.</p>
+    async fn update_job_execution(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> Result<UpdateJobExecutionResponse, RusotoError<UpdateJobExecutionError>> {
+        self.update_job_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -384,12 +384,15 @@ pub struct UpdateDeviceStateResponse {}
 
 /// Errors returned by ClaimDevicesByClaimCode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ClaimDevicesByClaimCodeError {
     Forbidden(String),
 
     InternalFailure(String),
 
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ClaimDevicesByClaimCodeError {
@@ -397,20 +400,25 @@ impl ClaimDevicesByClaimCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ClaimDevicesByClaimCodeError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        ClaimDevicesByClaimCodeError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ClaimDevicesByClaimCodeError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ClaimDevicesByClaimCodeError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClaimDevicesByClaimCodeError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ClaimDevicesByClaimCodeError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ClaimDevicesByClaimCodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -423,18 +431,22 @@ impl fmt::Display for ClaimDevicesByClaimCodeError {
             ClaimDevicesByClaimCodeError::Forbidden(ref cause) => write!(f, "{}", cause),
             ClaimDevicesByClaimCodeError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ClaimDevicesByClaimCodeError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ClaimDevicesByClaimCodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ClaimDevicesByClaimCodeError {}
 /// Errors returned by DescribeDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDeviceError {
     InternalFailure(String),
 
     InvalidRequest(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDeviceError {
@@ -442,16 +454,22 @@ impl DescribeDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDeviceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeDeviceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDeviceError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeDeviceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDeviceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -464,12 +482,14 @@ impl fmt::Display for DescribeDeviceError {
             DescribeDeviceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DescribeDeviceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeDeviceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDeviceError {}
 /// Errors returned by FinalizeDeviceClaim
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FinalizeDeviceClaimError {
     InternalFailure(String),
 
@@ -480,6 +500,8 @@ pub enum FinalizeDeviceClaimError {
     ResourceConflict(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl FinalizeDeviceClaimError {
@@ -487,28 +509,37 @@ impl FinalizeDeviceClaimError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        FinalizeDeviceClaimError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        FinalizeDeviceClaimError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        FinalizeDeviceClaimError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        FinalizeDeviceClaimError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        FinalizeDeviceClaimError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(FinalizeDeviceClaimError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -523,18 +554,22 @@ impl fmt::Display for FinalizeDeviceClaimError {
             FinalizeDeviceClaimError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
             FinalizeDeviceClaimError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             FinalizeDeviceClaimError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            FinalizeDeviceClaimError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for FinalizeDeviceClaimError {}
 /// Errors returned by GetDeviceMethods
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeviceMethodsError {
     InternalFailure(String),
 
     InvalidRequest(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeviceMethodsError {
@@ -542,16 +577,25 @@ impl GetDeviceMethodsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDeviceMethodsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetDeviceMethodsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDeviceMethodsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetDeviceMethodsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeviceMethodsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetDeviceMethodsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeviceMethodsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -564,12 +608,14 @@ impl fmt::Display for GetDeviceMethodsError {
             GetDeviceMethodsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetDeviceMethodsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetDeviceMethodsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetDeviceMethodsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeviceMethodsError {}
 /// Errors returned by InitiateDeviceClaim
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InitiateDeviceClaimError {
     InternalFailure(String),
 
@@ -578,6 +624,8 @@ pub enum InitiateDeviceClaimError {
     ResourceConflict(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InitiateDeviceClaimError {
@@ -585,23 +633,31 @@ impl InitiateDeviceClaimError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        InitiateDeviceClaimError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        InitiateDeviceClaimError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateDeviceClaimError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateDeviceClaimError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InitiateDeviceClaimError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -615,12 +671,14 @@ impl fmt::Display for InitiateDeviceClaimError {
             InitiateDeviceClaimError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             InitiateDeviceClaimError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             InitiateDeviceClaimError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            InitiateDeviceClaimError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InitiateDeviceClaimError {}
 /// Errors returned by InvokeDeviceMethod
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InvokeDeviceMethodError {
     InternalFailure(String),
 
@@ -633,6 +691,8 @@ pub enum InvokeDeviceMethodError {
     ResourceConflict(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InvokeDeviceMethodError {
@@ -640,29 +700,43 @@ impl InvokeDeviceMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        InvokeDeviceMethodError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        InvokeDeviceMethodError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InvokeDeviceMethodError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "RangeNotSatisfiableException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::RangeNotSatisfiable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InvokeDeviceMethodError::RangeNotSatisfiable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        InvokeDeviceMethodError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        InvokeDeviceMethodError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InvokeDeviceMethodError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -678,12 +752,14 @@ impl fmt::Display for InvokeDeviceMethodError {
             InvokeDeviceMethodError::RangeNotSatisfiable(ref cause) => write!(f, "{}", cause),
             InvokeDeviceMethodError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             InvokeDeviceMethodError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            InvokeDeviceMethodError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InvokeDeviceMethodError {}
 /// Errors returned by ListDeviceEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeviceEventsError {
     InternalFailure(String),
 
@@ -692,6 +768,8 @@ pub enum ListDeviceEventsError {
     RangeNotSatisfiable(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeviceEventsError {
@@ -699,21 +777,31 @@ impl ListDeviceEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDeviceEventsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListDeviceEventsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDeviceEventsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListDeviceEventsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "RangeNotSatisfiableException" => {
-                    return RusotoError::Service(ListDeviceEventsError::RangeNotSatisfiable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeviceEventsError::RangeNotSatisfiable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDeviceEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListDeviceEventsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeviceEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -727,18 +815,22 @@ impl fmt::Display for ListDeviceEventsError {
             ListDeviceEventsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListDeviceEventsError::RangeNotSatisfiable(ref cause) => write!(f, "{}", cause),
             ListDeviceEventsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListDeviceEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeviceEventsError {}
 /// Errors returned by ListDevices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDevicesError {
     InternalFailure(String),
 
     InvalidRequest(String),
 
     RangeNotSatisfiable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDevicesError {
@@ -746,16 +838,19 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDevicesError::InternalFailure(err.msg))
+                    return RusotoError::service(ListDevicesError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListDevicesError::InvalidRequest(err.msg), &res)
                 }
                 "RangeNotSatisfiableException" => {
-                    return RusotoError::Service(ListDevicesError::RangeNotSatisfiable(err.msg))
+                    return RusotoError::service(
+                        ListDevicesError::RangeNotSatisfiable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDevicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -768,16 +863,20 @@ impl fmt::Display for ListDevicesError {
             ListDevicesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListDevicesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListDevicesError::RangeNotSatisfiable(ref cause) => write!(f, "{}", cause),
+            ListDevicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDevicesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     InternalFailure(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -785,15 +884,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -805,18 +908,22 @@ impl fmt::Display for ListTagsForResourceError {
         match *self {
             ListTagsForResourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     InternalFailure(String),
 
     InvalidRequest(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -824,16 +931,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(TagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -846,18 +953,22 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UnclaimDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnclaimDeviceError {
     InternalFailure(String),
 
     InvalidRequest(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnclaimDeviceError {
@@ -865,16 +976,19 @@ impl UnclaimDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UnclaimDeviceError::InternalFailure(err.msg))
+                    return RusotoError::service(UnclaimDeviceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UnclaimDeviceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UnclaimDeviceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnclaimDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UnclaimDeviceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnclaimDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -887,18 +1001,22 @@ impl fmt::Display for UnclaimDeviceError {
             UnclaimDeviceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UnclaimDeviceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UnclaimDeviceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UnclaimDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnclaimDeviceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     InternalFailure(String),
 
     InvalidRequest(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -906,16 +1024,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -928,18 +1049,22 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDeviceState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeviceStateError {
     InternalFailure(String),
 
     InvalidRequest(String),
 
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeviceStateError {
@@ -947,16 +1072,25 @@ impl UpdateDeviceStateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDeviceStateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDeviceStateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceStateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeviceStateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -969,6 +1103,7 @@ impl fmt::Display for UpdateDeviceStateError {
             UpdateDeviceStateError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateDeviceStateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateDeviceStateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateDeviceStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1082,6 +1217,7 @@ impl Iot1ClickDevicesClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> Iot1ClickDevicesClient {
         Iot1ClickDevicesClient {
             client: Client::shared(),
@@ -1107,216 +1243,422 @@ impl Iot1ClickDevicesClient {
     pub fn new_with_client(client: Client, region: region::Region) -> Iot1ClickDevicesClient {
         Iot1ClickDevicesClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<Iot1ClickDevicesClient> {
+        rusoto_core::ClientBuilder::new(Iot1ClickDevicesClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> Iot1ClickDevicesClient {
+        Iot1ClickDevicesClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Iot1ClickDevices for Iot1ClickDevicesClient {
-    /// <p>Adds device(s) to your account (i.e., claim one or more devices) if and only if you
-    /// received a claim code with the device(s).</p>
-    async fn claim_devices_by_claim_code(
+impl Iot1ClickDevicesClient {
+    /// Calls `claim_devices_by_claim_code`, also returning the metadata of the response.
+    pub async fn claim_devices_by_claim_code_with_metadata(
         &self,
         input: ClaimDevicesByClaimCodeRequest,
-    ) -> Result<ClaimDevicesByClaimCodeResponse, RusotoError<ClaimDevicesByClaimCodeError>> {
+    ) -> Result<
+        (ClaimDevicesByClaimCodeResponse, ResponseMetadata),
+        RusotoError<ClaimDevicesByClaimCodeError>,
+    > {
+        let request = self.build_claim_devices_by_claim_code_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ClaimDevicesByClaimCodeResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ClaimDevicesByClaimCodeError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `claim_devices_by_claim_code` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_claim_devices_by_claim_code(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_claim_devices_by_claim_code_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_claim_devices_by_claim_code_request(
+        &self,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/claims/{claim_code}", claim_code = input.claim_code);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("ClaimDevicesByClaimCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `describe_device`, also returning the metadata of the response.
+    pub async fn describe_device_with_metadata(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> Result<(DescribeDeviceResponse, ResponseMetadata), RusotoError<DescribeDeviceError>> {
+        let request = self.build_describe_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ClaimDevicesByClaimCodeResponse, _>()?;
+                .deserialize::<DescribeDeviceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ClaimDevicesByClaimCodeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDeviceError::from_response(response))
         }
     }
 
-    /// <p>Given a device ID, returns a DescribeDeviceResponse object describing the
-    /// details of the device.</p>
-    async fn describe_device(
+    /// Presigns the request `describe_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDeviceRequest,
-    ) -> Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_device_request(&self, input: DescribeDeviceRequest) -> SignedRequest {
         let request_uri = format!("/devices/{device_id}", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribeDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `finalize_device_claim`, also returning the metadata of the response.
+    pub async fn finalize_device_claim_with_metadata(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> Result<
+        (FinalizeDeviceClaimResponse, ResponseMetadata),
+        RusotoError<FinalizeDeviceClaimError>,
+    > {
+        let request = self.build_finalize_device_claim_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDeviceResponse, _>()?;
+                .deserialize::<FinalizeDeviceClaimResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDeviceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(FinalizeDeviceClaimError::from_response(response))
         }
     }
 
-    /// <p>Given a device ID, finalizes the claim request for the associated device.</p><note>
-    /// <p>Claiming a device consists of initiating a claim, then publishing a device event,
-    /// and finalizing the claim. For a device of type button, a device event can
-    /// be published by simply clicking the device.</p>
-    ///
-    /// <p></note></p>
-    async fn finalize_device_claim(
+    /// Presigns the request `finalize_device_claim` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_finalize_device_claim(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: FinalizeDeviceClaimRequest,
-    ) -> Result<FinalizeDeviceClaimResponse, RusotoError<FinalizeDeviceClaimError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_finalize_device_claim_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_finalize_device_claim_request(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/devices/{device_id}/finalize-claim",
             device_id = input.device_id
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("FinalizeDeviceClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_device_methods`, also returning the metadata of the response.
+    pub async fn get_device_methods_with_metadata(
+        &self,
+        input: GetDeviceMethodsRequest,
+    ) -> Result<(GetDeviceMethodsResponse, ResponseMetadata), RusotoError<GetDeviceMethodsError>>
+    {
+        let request = self.build_get_device_methods_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FinalizeDeviceClaimResponse, _>()?;
+                .deserialize::<GetDeviceMethodsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(FinalizeDeviceClaimError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeviceMethodsError::from_response(response))
         }
     }
 
-    /// <p>Given a device ID, returns the invokable methods associated with the device.</p>
-    async fn get_device_methods(
+    /// Presigns the request `get_device_methods` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_device_methods(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeviceMethodsRequest,
-    ) -> Result<GetDeviceMethodsResponse, RusotoError<GetDeviceMethodsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_device_methods_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_device_methods_request(&self, input: GetDeviceMethodsRequest) -> SignedRequest {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("GetDeviceMethods");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `initiate_device_claim`, also returning the metadata of the response.
+    pub async fn initiate_device_claim_with_metadata(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> Result<
+        (InitiateDeviceClaimResponse, ResponseMetadata),
+        RusotoError<InitiateDeviceClaimError>,
+    > {
+        let request = self.build_initiate_device_claim_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeviceMethodsResponse, _>()?;
+                .deserialize::<InitiateDeviceClaimResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeviceMethodsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(InitiateDeviceClaimError::from_response(response))
         }
     }
 
-    /// <p>Given a device ID, initiates a claim request for the associated device.</p><note>
-    /// <p>Claiming a device consists of initiating a claim, then publishing a device event,
-    /// and finalizing the claim. For a device of type button, a device event can
-    /// be published by simply clicking the device.</p>
-    ///
-    /// <p></note></p>
-    async fn initiate_device_claim(
+    /// Presigns the request `initiate_device_claim` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_initiate_device_claim(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InitiateDeviceClaimRequest,
-    ) -> Result<InitiateDeviceClaimResponse, RusotoError<InitiateDeviceClaimError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_initiate_device_claim_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_initiate_device_claim_request(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/devices/{device_id}/initiate-claim",
             device_id = input.device_id
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("InitiateDeviceClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `invoke_device_method`, also returning the metadata of the response.
+    pub async fn invoke_device_method_with_metadata(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> Result<(InvokeDeviceMethodResponse, ResponseMetadata), RusotoError<InvokeDeviceMethodError>>
+    {
+        let request = self.build_invoke_device_method_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<InitiateDeviceClaimResponse, _>()?;
+                .deserialize::<InvokeDeviceMethodResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(InitiateDeviceClaimError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(InvokeDeviceMethodError::from_response(response))
         }
     }
 
-    /// <p>Given a device ID, issues a request to invoke a named device method (with possible
-    /// parameters). See the "Example POST" code snippet below.</p>
-    async fn invoke_device_method(
+    /// Presigns the request `invoke_device_method` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_invoke_device_method(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InvokeDeviceMethodRequest,
-    ) -> Result<InvokeDeviceMethodResponse, RusotoError<InvokeDeviceMethodError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_invoke_device_method_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_invoke_device_method_request(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("InvokeDeviceMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_device_events`, also returning the metadata of the response.
+    pub async fn list_device_events_with_metadata(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> Result<(ListDeviceEventsResponse, ResponseMetadata), RusotoError<ListDeviceEventsError>>
+    {
+        let request = self.build_list_device_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<InvokeDeviceMethodResponse, _>()?;
+                .deserialize::<ListDeviceEventsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(InvokeDeviceMethodError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDeviceEventsError::from_response(response))
         }
     }
 
-    /// <p>Using a device ID, returns a DeviceEventsResponse object containing an
-    /// array of events for the device.</p>
-    async fn list_device_events(
+    /// Presigns the request `list_device_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_device_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeviceEventsRequest,
-    ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_device_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_device_events_request(&self, input: ListDeviceEventsRequest) -> SignedRequest {
         let request_uri = format!("/devices/{device_id}/events", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDeviceEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1332,31 +1674,55 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         params.put("toTimeStamp", &input.to_time_stamp);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_devices`, also returning the metadata of the response.
+    pub async fn list_devices_with_metadata(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<(ListDevicesResponse, ResponseMetadata), RusotoError<ListDevicesError>> {
+        let request = self.build_list_devices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeviceEventsResponse, _>()?;
+                .deserialize::<ListDevicesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeviceEventsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDevicesError::from_response(response))
         }
     }
 
-    /// <p>Lists the 1-Click compatible devices associated with your AWS account.</p>
-    async fn list_devices(
+    /// Presigns the request `list_devices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_devices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDevicesRequest,
-    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_devices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_devices_request(&self, input: ListDevicesRequest) -> SignedRequest {
         let request_uri = "/devices";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1373,121 +1739,220 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDevicesResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDevicesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags associated with the specified resource ARN.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates the tags associated with the resource ARN. See <a href="https://docs.aws.amazon.com/iot-1-click/latest/developerguide/1click-appendix.html#1click-limits">AWS IoT 1-Click Service Limits</a> for the maximum number of tags allowed per
-    /// resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `unclaim_device`, also returning the metadata of the response.
+    pub async fn unclaim_device_with_metadata(
+        &self,
+        input: UnclaimDeviceRequest,
+    ) -> Result<(UnclaimDeviceResponse, ResponseMetadata), RusotoError<UnclaimDeviceError>> {
+        let request = self.build_unclaim_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UnclaimDeviceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UnclaimDeviceError::from_response(response))
         }
     }
 
-    /// <p>Disassociates a device from your AWS account using its device ID.</p>
-    async fn unclaim_device(
+    /// Presigns the request `unclaim_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unclaim_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnclaimDeviceRequest,
-    ) -> Result<UnclaimDeviceResponse, RusotoError<UnclaimDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unclaim_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unclaim_device_request(&self, input: UnclaimDeviceRequest) -> SignedRequest {
         let request_uri = format!("/devices/{device_id}/unclaim", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UnclaimDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UnclaimDeviceResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UnclaimDeviceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Using tag keys, deletes the tags (key/value pairs) associated with the specified
-    /// resource ARN.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1498,51 +1963,212 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_device_state`, also returning the metadata of the response.
+    pub async fn update_device_state_with_metadata(
+        &self,
+        input: UpdateDeviceStateRequest,
+    ) -> Result<(UpdateDeviceStateResponse, ResponseMetadata), RusotoError<UpdateDeviceStateError>>
+    {
+        let request = self.build_update_device_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDeviceStateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDeviceStateError::from_response(response))
         }
     }
 
-    /// <p>Using a Boolean value (true or false), this operation
-    /// enables or disables the device given a device ID.</p>
-    async fn update_device_state(
+    /// Presigns the request `update_device_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_device_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDeviceStateRequest,
-    ) -> Result<UpdateDeviceStateResponse, RusotoError<UpdateDeviceStateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_device_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_device_state_request(&self, input: UpdateDeviceStateRequest) -> SignedRequest {
         let request_uri = format!("/devices/{device_id}/state", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceState");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Iot1ClickDevices for Iot1ClickDevicesClient {
+    /// <p>Adds device(s) to your account (i.e., claim one or more devices) if and only if you
+    /// received a claim code with the device(s).</p>
+    async fn claim_devices_by_claim_code(
+        &self,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> Result<ClaimDevicesByClaimCodeResponse, RusotoError<ClaimDevicesByClaimCodeError>> {
+        self.claim_devices_by_claim_code_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDeviceStateResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDeviceStateError::from_response(response))
-        }
+    /// <p>Given a device ID, returns a DescribeDeviceResponse object describing the
+    /// details of the device.</p>
+    async fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>> {
+        self.describe_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Given a device ID, finalizes the claim request for the associated device.</p><note>
+    /// <p>Claiming a device consists of initiating a claim, then publishing a device event,
+    /// and finalizing the claim. For a device of type button, a device event can
+    /// be published by simply clicking the device.</p>
+    ///
+    /// <p></note></p>
+    async fn finalize_device_claim(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> Result<FinalizeDeviceClaimResponse, RusotoError<FinalizeDeviceClaimError>> {
+        self.finalize_device_claim_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Given a device ID, returns the invokable methods associated with the device.</p>
+    async fn get_device_methods(
+        &self,
+        input: GetDeviceMethodsRequest,
+    ) -> Result<GetDeviceMethodsResponse, RusotoError<GetDeviceMethodsError>> {
+        self.get_device_methods_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Given a device ID, initiates a claim request for the associated device.</p><note>
+    /// <p>Claiming a device consists of initiating a claim, then publishing a device event,
+    /// and finalizing the claim. For a device of type button, a device event can
+    /// be published by simply clicking the device.</p>
+    ///
+    /// <p></note></p>
+    async fn initiate_device_claim(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> Result<InitiateDeviceClaimResponse, RusotoError<InitiateDeviceClaimError>> {
+        self.initiate_device_claim_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Given a device ID, issues a request to invoke a named device method (with possible
+    /// parameters). See the "Example POST" code snippet below.</p>
+    async fn invoke_device_method(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> Result<InvokeDeviceMethodResponse, RusotoError<InvokeDeviceMethodError>> {
+        self.invoke_device_method_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Using a device ID, returns a DeviceEventsResponse object containing an
+    /// array of events for the device.</p>
+    async fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
+        self.list_device_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the 1-Click compatible devices associated with your AWS account.</p>
+    async fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+        self.list_devices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags associated with the specified resource ARN.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates the tags associated with the resource ARN. See <a href="https://docs.aws.amazon.com/iot-1-click/latest/developerguide/1click-appendix.html#1click-limits">AWS IoT 1-Click Service Limits</a> for the maximum number of tags allowed per
+    /// resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a device from your AWS account using its device ID.</p>
+    async fn unclaim_device(
+        &self,
+        input: UnclaimDeviceRequest,
+    ) -> Result<UnclaimDeviceResponse, RusotoError<UnclaimDeviceError>> {
+        self.unclaim_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Using tag keys, deletes the tags (key/value pairs) associated with the specified
+    /// resource ARN.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Using a Boolean value (true or false), this operation
+    /// enables or disables the device given a device ID.</p>
+    async fn update_device_state(
+        &self,
+        input: UpdateDeviceStateRequest,
+    ) -> Result<UpdateDeviceStateResponse, RusotoError<UpdateDeviceStateError>> {
+        self.update_device_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
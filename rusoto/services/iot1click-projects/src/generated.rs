@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -448,6 +448,7 @@ pub struct UpdateProjectResponse {}
 
 /// Errors returned by AssociateDeviceWithPlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateDeviceWithPlacementError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -457,6 +458,8 @@ pub enum AssociateDeviceWithPlacementError {
     ResourceConflict(String),
     /// <p><p/></p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateDeviceWithPlacementError {
@@ -466,27 +469,36 @@ impl AssociateDeviceWithPlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDeviceWithPlacementError::InternalFailure(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateDeviceWithPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDeviceWithPlacementError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDeviceWithPlacementError::ResourceConflict(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDeviceWithPlacementError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateDeviceWithPlacementError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -504,12 +516,14 @@ impl fmt::Display for AssociateDeviceWithPlacementError {
             AssociateDeviceWithPlacementError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateDeviceWithPlacementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateDeviceWithPlacementError {}
 /// Errors returned by CreatePlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePlacementError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -519,6 +533,8 @@ pub enum CreatePlacementError {
     ResourceConflict(String),
     /// <p><p/></p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePlacementError {
@@ -526,19 +542,31 @@ impl CreatePlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreatePlacementError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreatePlacementError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePlacementError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreatePlacementError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreatePlacementError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        CreatePlacementError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePlacementError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreatePlacementError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePlacementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -552,12 +580,14 @@ impl fmt::Display for CreatePlacementError {
             CreatePlacementError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreatePlacementError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             CreatePlacementError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreatePlacementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePlacementError {}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProjectError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -565,6 +595,8 @@ pub enum CreateProjectError {
     InvalidRequest(String),
     /// <p><p/></p>
     ResourceConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProjectError {
@@ -572,16 +604,19 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateProjectError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateProjectError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateProjectError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -594,12 +629,14 @@ impl fmt::Display for CreateProjectError {
             CreateProjectError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateProjectError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateProjectError::ResourceConflict(ref cause) => write!(f, "{}", cause),
+            CreateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProjectError {}
 /// Errors returned by DeletePlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePlacementError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -609,6 +646,8 @@ pub enum DeletePlacementError {
     ResourceNotFound(String),
     /// <p><p/></p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePlacementError {
@@ -616,19 +655,31 @@ impl DeletePlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeletePlacementError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeletePlacementError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePlacementError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeletePlacementError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePlacementError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeletePlacementError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePlacementError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeletePlacementError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePlacementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -642,12 +693,14 @@ impl fmt::Display for DeletePlacementError {
             DeletePlacementError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeletePlacementError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeletePlacementError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeletePlacementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePlacementError {}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProjectError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -657,6 +710,8 @@ pub enum DeleteProjectError {
     ResourceNotFound(String),
     /// <p><p/></p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProjectError {
@@ -664,19 +719,22 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteProjectError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteProjectError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteProjectError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteProjectError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -690,12 +748,14 @@ impl fmt::Display for DeleteProjectError {
             DeleteProjectError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteProjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteProjectError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProjectError {}
 /// Errors returned by DescribePlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePlacementError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -703,6 +763,8 @@ pub enum DescribePlacementError {
     InvalidRequest(String),
     /// <p><p/></p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePlacementError {
@@ -710,16 +772,25 @@ impl DescribePlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribePlacementError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribePlacementError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePlacementError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribePlacementError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePlacementError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribePlacementError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePlacementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -732,12 +803,14 @@ impl fmt::Display for DescribePlacementError {
             DescribePlacementError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DescribePlacementError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribePlacementError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribePlacementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePlacementError {}
 /// Errors returned by DescribeProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProjectError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -745,6 +818,8 @@ pub enum DescribeProjectError {
     InvalidRequest(String),
     /// <p><p/></p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProjectError {
@@ -752,16 +827,25 @@ impl DescribeProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeProjectError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -774,12 +858,14 @@ impl fmt::Display for DescribeProjectError {
             DescribeProjectError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DescribeProjectError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeProjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProjectError {}
 /// Errors returned by DisassociateDeviceFromPlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateDeviceFromPlacementError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -789,6 +875,8 @@ pub enum DisassociateDeviceFromPlacementError {
     ResourceNotFound(String),
     /// <p><p/></p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateDeviceFromPlacementError {
@@ -798,27 +886,36 @@ impl DisassociateDeviceFromPlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDeviceFromPlacementError::InternalFailure(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDeviceFromPlacementError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDeviceFromPlacementError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDeviceFromPlacementError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateDeviceFromPlacementError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -840,12 +937,16 @@ impl fmt::Display for DisassociateDeviceFromPlacementError {
             DisassociateDeviceFromPlacementError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateDeviceFromPlacementError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateDeviceFromPlacementError {}
 /// Errors returned by GetDevicesInPlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDevicesInPlacementError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -853,6 +954,8 @@ pub enum GetDevicesInPlacementError {
     InvalidRequest(String),
     /// <p><p/></p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDevicesInPlacementError {
@@ -860,22 +963,25 @@ impl GetDevicesInPlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDevicesInPlacementError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDevicesInPlacementError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDevicesInPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDevicesInPlacementError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDevicesInPlacementError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDevicesInPlacementError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDevicesInPlacementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -888,12 +994,14 @@ impl fmt::Display for GetDevicesInPlacementError {
             GetDevicesInPlacementError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetDevicesInPlacementError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetDevicesInPlacementError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetDevicesInPlacementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDevicesInPlacementError {}
 /// Errors returned by ListPlacements
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPlacementsError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -901,6 +1009,8 @@ pub enum ListPlacementsError {
     InvalidRequest(String),
     /// <p><p/></p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPlacementsError {
@@ -908,16 +1018,22 @@ impl ListPlacementsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPlacementsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListPlacementsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPlacementsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListPlacementsError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPlacementsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListPlacementsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPlacementsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -930,17 +1046,21 @@ impl fmt::Display for ListPlacementsError {
             ListPlacementsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListPlacementsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListPlacementsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListPlacementsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPlacementsError {}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProjectsError {
     /// <p><p/></p>
     InternalFailure(String),
     /// <p><p/></p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProjectsError {
@@ -948,13 +1068,13 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListProjectsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListProjectsError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProjectsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListProjectsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -966,12 +1086,14 @@ impl fmt::Display for ListProjectsError {
         match *self {
             ListProjectsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListProjectsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListProjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProjectsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -979,6 +1101,8 @@ pub enum ListTagsForResourceError {
     InvalidRequest(String),
     /// <p><p/></p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -986,18 +1110,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1010,12 +1141,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1023,6 +1156,8 @@ pub enum TagResourceError {
     InvalidRequest(String),
     /// <p><p/></p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1030,16 +1165,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(TagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1052,12 +1187,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1065,6 +1202,8 @@ pub enum UntagResourceError {
     InvalidRequest(String),
     /// <p><p/></p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1072,16 +1211,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1094,12 +1236,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdatePlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePlacementError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1109,6 +1253,8 @@ pub enum UpdatePlacementError {
     ResourceNotFound(String),
     /// <p><p/></p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePlacementError {
@@ -1116,19 +1262,31 @@ impl UpdatePlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdatePlacementError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdatePlacementError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdatePlacementError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdatePlacementError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePlacementError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdatePlacementError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdatePlacementError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdatePlacementError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePlacementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1142,12 +1300,14 @@ impl fmt::Display for UpdatePlacementError {
             UpdatePlacementError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdatePlacementError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdatePlacementError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdatePlacementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePlacementError {}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProjectError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1157,6 +1317,8 @@ pub enum UpdateProjectError {
     ResourceNotFound(String),
     /// <p><p/></p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProjectError {
@@ -1164,19 +1326,22 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateProjectError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateProjectError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateProjectError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateProjectError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateProjectError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1190,6 +1355,7 @@ impl fmt::Display for UpdateProjectError {
             UpdateProjectError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateProjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateProjectError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1307,6 +1473,7 @@ impl Iot1ClickProjectsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> Iot1ClickProjectsClient {
         Iot1ClickProjectsClient {
             client: Client::shared(),
@@ -1332,16 +1499,72 @@ impl Iot1ClickProjectsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> Iot1ClickProjectsClient {
         Iot1ClickProjectsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<Iot1ClickProjectsClient> {
+        rusoto_core::ClientBuilder::new(Iot1ClickProjectsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> Iot1ClickProjectsClient {
+        Iot1ClickProjectsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Iot1ClickProjects for Iot1ClickProjectsClient {
-    /// <p>Associates a physical device with a placement.</p>
-    async fn associate_device_with_placement(
+impl Iot1ClickProjectsClient {
+    /// Calls `associate_device_with_placement`, also returning the metadata of the response.
+    pub async fn associate_device_with_placement_with_metadata(
         &self,
         input: AssociateDeviceWithPlacementRequest,
-    ) -> Result<AssociateDeviceWithPlacementResponse, RusotoError<AssociateDeviceWithPlacementError>>
-    {
+    ) -> Result<
+        (AssociateDeviceWithPlacementResponse, ResponseMetadata),
+        RusotoError<AssociateDeviceWithPlacementError>,
+    > {
+        let request = self.build_associate_device_with_placement_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateDeviceWithPlacementResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociateDeviceWithPlacementError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_device_with_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_device_with_placement(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateDeviceWithPlacementRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_device_with_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_device_with_placement_request(
+        &self,
+        input: AssociateDeviceWithPlacementRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}/devices/{device_template_name}",
             device_template_name = input.device_template_name,
@@ -1350,99 +1573,173 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("AssociateDeviceWithPlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_placement`, also returning the metadata of the response.
+    pub async fn create_placement_with_metadata(
+        &self,
+        input: CreatePlacementRequest,
+    ) -> Result<(CreatePlacementResponse, ResponseMetadata), RusotoError<CreatePlacementError>>
+    {
+        let request = self.build_create_placement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateDeviceWithPlacementResponse, _>()?;
+                .deserialize::<CreatePlacementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateDeviceWithPlacementError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreatePlacementError::from_response(response))
         }
     }
 
-    /// <p>Creates an empty placement.</p>
-    async fn create_placement(
+    /// Presigns the request `create_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_placement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePlacementRequest,
-    ) -> Result<CreatePlacementResponse, RusotoError<CreatePlacementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_placement_request(&self, input: CreatePlacementRequest) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}/placements",
             project_name = input.project_name
         );
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("CreatePlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_project`, also returning the metadata of the response.
+    pub async fn create_project_with_metadata(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<(CreateProjectResponse, ResponseMetadata), RusotoError<CreateProjectError>> {
+        let request = self.build_create_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePlacementResponse, _>()?;
+                .deserialize::<CreateProjectResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePlacementError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateProjectError::from_response(response))
         }
     }
 
-    /// <p>Creates an empty project with a placement template. A project contains zero or more placements that adhere to the placement template defined in the project.</p>
-    async fn create_project(
+    /// Presigns the request `create_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProjectRequest,
-    ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_project_request(&self, input: CreateProjectRequest) -> SignedRequest {
         let request_uri = "/projects";
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("CreateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_placement`, also returning the metadata of the response.
+    pub async fn delete_placement_with_metadata(
+        &self,
+        input: DeletePlacementRequest,
+    ) -> Result<(DeletePlacementResponse, ResponseMetadata), RusotoError<DeletePlacementError>>
+    {
+        let request = self.build_delete_placement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProjectResponse, _>()?;
+                .deserialize::<DeletePlacementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeletePlacementError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a placement. To delete a placement, it must not have any devices associated with it.</p> <note> <p>When you delete a placement, all associated data becomes irretrievable.</p> </note></p>
-    async fn delete_placement(
+    /// Presigns the request `delete_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_placement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePlacementRequest,
-    ) -> Result<DeletePlacementResponse, RusotoError<DeletePlacementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_placement_request(&self, input: DeletePlacementRequest) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}",
             placement_name = input.placement_name,
@@ -1450,64 +1747,113 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("DeletePlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `delete_project`, also returning the metadata of the response.
+    pub async fn delete_project_with_metadata(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<(DeleteProjectResponse, ResponseMetadata), RusotoError<DeleteProjectError>> {
+        let request = self.build_delete_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePlacementResponse, _>()?;
+                .deserialize::<DeleteProjectResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePlacementError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteProjectError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a project. To delete a project, it must not have any placements associated with it.</p> <note> <p>When you delete a project, all associated data becomes irretrievable.</p> </note></p>
-    async fn delete_project(
+    /// Presigns the request `delete_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProjectRequest,
-    ) -> Result<DeleteProjectResponse, RusotoError<DeleteProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_project_request(&self, input: DeleteProjectRequest) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}",
             project_name = input.project_name
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("DeleteProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `describe_placement`, also returning the metadata of the response.
+    pub async fn describe_placement_with_metadata(
+        &self,
+        input: DescribePlacementRequest,
+    ) -> Result<(DescribePlacementResponse, ResponseMetadata), RusotoError<DescribePlacementError>>
+    {
+        let request = self.build_describe_placement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteProjectResponse, _>()?;
+                .deserialize::<DescribePlacementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribePlacementError::from_response(response))
         }
     }
 
-    /// <p>Describes a placement in a project.</p>
-    async fn describe_placement(
+    /// Presigns the request `describe_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_placement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePlacementRequest,
-    ) -> Result<DescribePlacementResponse, RusotoError<DescribePlacementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_placement_request(&self, input: DescribePlacementRequest) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}",
             placement_name = input.placement_name,
@@ -1515,67 +1861,121 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribePlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `describe_project`, also returning the metadata of the response.
+    pub async fn describe_project_with_metadata(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<(DescribeProjectResponse, ResponseMetadata), RusotoError<DescribeProjectError>>
+    {
+        let request = self.build_describe_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePlacementResponse, _>()?;
+                .deserialize::<DescribeProjectResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePlacementError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeProjectError::from_response(response))
         }
     }
 
-    /// <p>Returns an object describing a project.</p>
-    async fn describe_project(
+    /// Presigns the request `describe_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProjectRequest,
-    ) -> Result<DescribeProjectResponse, RusotoError<DescribeProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_project_request(&self, input: DescribeProjectRequest) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}",
             project_name = input.project_name
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribeProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `disassociate_device_from_placement`, also returning the metadata of the response.
+    pub async fn disassociate_device_from_placement_with_metadata(
+        &self,
+        input: DisassociateDeviceFromPlacementRequest,
+    ) -> Result<
+        (DisassociateDeviceFromPlacementResponse, ResponseMetadata),
+        RusotoError<DisassociateDeviceFromPlacementError>,
+    > {
+        let request = self.build_disassociate_device_from_placement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProjectResponse, _>()?;
+                .deserialize::<DisassociateDeviceFromPlacementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateDeviceFromPlacementError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Removes a physical device from a placement.</p>
-    async fn disassociate_device_from_placement(
+    /// Presigns the request `disassociate_device_from_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_device_from_placement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateDeviceFromPlacementRequest,
-    ) -> Result<
-        DisassociateDeviceFromPlacementResponse,
-        RusotoError<DisassociateDeviceFromPlacementError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_device_from_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_device_from_placement_request(
+        &self,
+        input: DisassociateDeviceFromPlacementRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}/devices/{device_template_name}",
             device_template_name = input.device_template_name,
@@ -1584,34 +1984,62 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("DisassociateDeviceFromPlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `get_devices_in_placement`, also returning the metadata of the response.
+    pub async fn get_devices_in_placement_with_metadata(
+        &self,
+        input: GetDevicesInPlacementRequest,
+    ) -> Result<
+        (GetDevicesInPlacementResponse, ResponseMetadata),
+        RusotoError<GetDevicesInPlacementError>,
+    > {
+        let request = self.build_get_devices_in_placement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateDeviceFromPlacementResponse, _>()?;
+                .deserialize::<GetDevicesInPlacementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateDeviceFromPlacementError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDevicesInPlacementError::from_response(response))
         }
     }
 
-    /// <p>Returns an object enumerating the devices in a placement.</p>
-    async fn get_devices_in_placement(
+    /// Presigns the request `get_devices_in_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_devices_in_placement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDevicesInPlacementRequest,
-    ) -> Result<GetDevicesInPlacementResponse, RusotoError<GetDevicesInPlacementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_devices_in_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_devices_in_placement_request(
+        &self,
+        input: GetDevicesInPlacementRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}/devices",
             placement_name = input.placement_name,
@@ -1619,38 +2047,63 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("GetDevicesInPlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `list_placements`, also returning the metadata of the response.
+    pub async fn list_placements_with_metadata(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> Result<(ListPlacementsResponse, ResponseMetadata), RusotoError<ListPlacementsError>> {
+        let request = self.build_list_placements_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDevicesInPlacementResponse, _>()?;
+                .deserialize::<ListPlacementsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDevicesInPlacementError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPlacementsError::from_response(response))
         }
     }
 
-    /// <p>Lists the placement(s) of a project.</p>
-    async fn list_placements(
+    /// Presigns the request `list_placements` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_placements(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPlacementsRequest,
-    ) -> Result<ListPlacementsResponse, RusotoError<ListPlacementsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_placements_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_placements_request(&self, input: ListPlacementsRequest) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}/placements",
             project_name = input.project_name
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListPlacements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1664,31 +2117,55 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_projects`, also returning the metadata of the response.
+    pub async fn list_projects_with_metadata(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<(ListProjectsResponse, ResponseMetadata), RusotoError<ListProjectsError>> {
+        let request = self.build_list_projects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPlacementsResponse, _>()?;
+                .deserialize::<ListProjectsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPlacementsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListProjectsError::from_response(response))
         }
     }
 
-    /// <p>Lists the AWS IoT 1-Click project(s) associated with your AWS account and region.</p>
-    async fn list_projects(
+    /// Presigns the request `list_projects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_projects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProjectsRequest,
-    ) -> Result<ListProjectsResponse, RusotoError<ListProjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_projects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_projects_request(&self, input: ListProjectsRequest) -> SignedRequest {
         let request_uri = "/projects";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListProjects");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1702,91 +2179,169 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProjectsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProjectsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags (metadata key/value pairs) which you have assigned to the resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Creates or modifies tags for a resource. Tags are key/value pairs (metadata) that can be used to manage a resource. For more information, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags (metadata key/value pairs) from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1797,28 +2352,52 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_placement`, also returning the metadata of the response.
+    pub async fn update_placement_with_metadata(
+        &self,
+        input: UpdatePlacementRequest,
+    ) -> Result<(UpdatePlacementResponse, ResponseMetadata), RusotoError<UpdatePlacementError>>
+    {
+        let request = self.build_update_placement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdatePlacementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdatePlacementError::from_response(response))
         }
     }
 
-    /// <p>Updates a placement with the given attributes. To clear an attribute, pass an empty value (i.e., "").</p>
-    async fn update_placement(
+    /// Presigns the request `update_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_placement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePlacementRequest,
-    ) -> Result<UpdatePlacementResponse, RusotoError<UpdatePlacementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_placement_request(&self, input: UpdatePlacementRequest) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}/placements/{placement_name}",
             placement_name = input.placement_name,
@@ -1826,60 +2405,238 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdatePlacement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_project`, also returning the metadata of the response.
+    pub async fn update_project_with_metadata(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<(UpdateProjectResponse, ResponseMetadata), RusotoError<UpdateProjectError>> {
+        let request = self.build_update_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePlacementResponse, _>()?;
+                .deserialize::<UpdateProjectResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePlacementError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateProjectError::from_response(response))
         }
     }
 
-    /// <p>Updates a project associated with your AWS account and region. With the exception of device template names, you can pass just the values that need to be updated because the update request will change only the values that are provided. To clear a value, pass the empty string (i.e., <code>""</code>).</p>
-    async fn update_project(
+    /// Presigns the request `update_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProjectRequest,
-    ) -> Result<UpdateProjectResponse, RusotoError<UpdateProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_project_request(&self, input: UpdateProjectRequest) -> SignedRequest {
         let request_uri = format!(
             "/projects/{project_name}",
             project_name = input.project_name
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Iot1ClickProjects for Iot1ClickProjectsClient {
+    /// <p>Associates a physical device with a placement.</p>
+    async fn associate_device_with_placement(
+        &self,
+        input: AssociateDeviceWithPlacementRequest,
+    ) -> Result<AssociateDeviceWithPlacementResponse, RusotoError<AssociateDeviceWithPlacementError>>
+    {
+        self.associate_device_with_placement_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateProjectResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProjectError::from_response(response))
-        }
+    /// <p>Creates an empty placement.</p>
+    async fn create_placement(
+        &self,
+        input: CreatePlacementRequest,
+    ) -> Result<CreatePlacementResponse, RusotoError<CreatePlacementError>> {
+        self.create_placement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an empty project with a placement template. A project contains zero or more placements that adhere to the placement template defined in the project.</p>
+    async fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
+        self.create_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a placement. To delete a placement, it must not have any devices associated with it.</p> <note> <p>When you delete a placement, all associated data becomes irretrievable.</p> </note></p>
+    async fn delete_placement(
+        &self,
+        input: DeletePlacementRequest,
+    ) -> Result<DeletePlacementResponse, RusotoError<DeletePlacementError>> {
+        self.delete_placement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a project. To delete a project, it must not have any placements associated with it.</p> <note> <p>When you delete a project, all associated data becomes irretrievable.</p> </note></p>
+    async fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResponse, RusotoError<DeleteProjectError>> {
+        self.delete_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a placement in a project.</p>
+    async fn describe_placement(
+        &self,
+        input: DescribePlacementRequest,
+    ) -> Result<DescribePlacementResponse, RusotoError<DescribePlacementError>> {
+        self.describe_placement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an object describing a project.</p>
+    async fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<DescribeProjectResponse, RusotoError<DescribeProjectError>> {
+        self.describe_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a physical device from a placement.</p>
+    async fn disassociate_device_from_placement(
+        &self,
+        input: DisassociateDeviceFromPlacementRequest,
+    ) -> Result<
+        DisassociateDeviceFromPlacementResponse,
+        RusotoError<DisassociateDeviceFromPlacementError>,
+    > {
+        self.disassociate_device_from_placement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an object enumerating the devices in a placement.</p>
+    async fn get_devices_in_placement(
+        &self,
+        input: GetDevicesInPlacementRequest,
+    ) -> Result<GetDevicesInPlacementResponse, RusotoError<GetDevicesInPlacementError>> {
+        self.get_devices_in_placement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the placement(s) of a project.</p>
+    async fn list_placements(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> Result<ListPlacementsResponse, RusotoError<ListPlacementsError>> {
+        self.list_placements_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the AWS IoT 1-Click project(s) associated with your AWS account and region.</p>
+    async fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResponse, RusotoError<ListProjectsError>> {
+        self.list_projects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags (metadata key/value pairs) which you have assigned to the resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or modifies tags for a resource. Tags are key/value pairs (metadata) that can be used to manage a resource. For more information, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags (metadata key/value pairs) from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a placement with the given attributes. To clear an attribute, pass an empty value (i.e., "").</p>
+    async fn update_placement(
+        &self,
+        input: UpdatePlacementRequest,
+    ) -> Result<UpdatePlacementResponse, RusotoError<UpdatePlacementError>> {
+        self.update_placement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a project associated with your AWS account and region. With the exception of device template names, you can pass just the values that need to be updated because the update request will change only the values that are provided. To clear a value, pass the empty string (i.e., <code>""</code>).</p>
+    async fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResponse, RusotoError<UpdateProjectError>> {
+        self.update_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1772,6 +1772,7 @@ pub struct VersioningConfiguration {
 
 /// Errors returned by BatchPutMessage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchPutMessageError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -1783,6 +1784,8 @@ pub enum BatchPutMessageError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchPutMessageError {
@@ -1790,22 +1793,34 @@ impl BatchPutMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(BatchPutMessageError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        BatchPutMessageError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchPutMessageError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        BatchPutMessageError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchPutMessageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        BatchPutMessageError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchPutMessageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        BatchPutMessageError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchPutMessageError::Throttling(err.msg))
+                    return RusotoError::service(BatchPutMessageError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchPutMessageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1820,12 +1835,14 @@ impl fmt::Display for BatchPutMessageError {
             BatchPutMessageError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             BatchPutMessageError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchPutMessageError::Throttling(ref cause) => write!(f, "{}", cause),
+            BatchPutMessageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchPutMessageError {}
 /// Errors returned by CancelPipelineReprocessing
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelPipelineReprocessingError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -1837,6 +1854,8 @@ pub enum CancelPipelineReprocessingError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelPipelineReprocessingError {
@@ -1846,32 +1865,39 @@ impl CancelPipelineReprocessingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CancelPipelineReprocessingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelPipelineReprocessingError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelPipelineReprocessingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelPipelineReprocessingError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelPipelineReprocessingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelPipelineReprocessingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelPipelineReprocessingError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelPipelineReprocessingError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelPipelineReprocessingError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CancelPipelineReprocessingError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1888,12 +1914,14 @@ impl fmt::Display for CancelPipelineReprocessingError {
                 write!(f, "{}", cause)
             }
             CancelPipelineReprocessingError::Throttling(ref cause) => write!(f, "{}", cause),
+            CancelPipelineReprocessingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelPipelineReprocessingError {}
 /// Errors returned by CreateChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateChannelError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -1907,6 +1935,8 @@ pub enum CreateChannelError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateChannelError {
@@ -1914,25 +1944,31 @@ impl CreateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateChannelError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateChannelError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateChannelError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateChannelError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateChannelError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateChannelError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateChannelError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateChannelError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateChannelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateChannelError::Throttling(err.msg))
+                    return RusotoError::service(CreateChannelError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1948,12 +1984,14 @@ impl fmt::Display for CreateChannelError {
             CreateChannelError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateChannelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateChannelError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateChannelError {}
 /// Errors returned by CreateDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatasetError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -1967,6 +2005,8 @@ pub enum CreateDatasetError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatasetError {
@@ -1974,25 +2014,31 @@ impl CreateDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDatasetError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateDatasetError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDatasetError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateDatasetError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateDatasetError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDatasetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDatasetError::Throttling(err.msg))
+                    return RusotoError::service(CreateDatasetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2008,12 +2054,14 @@ impl fmt::Display for CreateDatasetError {
             CreateDatasetError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateDatasetError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateDatasetError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatasetError {}
 /// Errors returned by CreateDatasetContent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatasetContentError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2025,6 +2073,8 @@ pub enum CreateDatasetContentError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatasetContentError {
@@ -2032,28 +2082,37 @@ impl CreateDatasetContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDatasetContentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetContentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDatasetContentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetContentError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetContentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetContentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDatasetContentError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetContentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDatasetContentError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetContentError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatasetContentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2068,12 +2127,14 @@ impl fmt::Display for CreateDatasetContentError {
             CreateDatasetContentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateDatasetContentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateDatasetContentError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDatasetContentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatasetContentError {}
 /// Errors returned by CreateDatastore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatastoreError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2087,6 +2148,8 @@ pub enum CreateDatastoreError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatastoreError {
@@ -2094,27 +2157,37 @@ impl CreateDatastoreError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDatastoreError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateDatastoreError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDatastoreError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateDatastoreError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatastoreError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateDatastoreError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatastoreError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatastoreError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDatastoreError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateDatastoreError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDatastoreError::Throttling(err.msg))
+                    return RusotoError::service(CreateDatastoreError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatastoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2130,12 +2203,14 @@ impl fmt::Display for CreateDatastoreError {
             CreateDatastoreError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateDatastoreError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateDatastoreError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDatastoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatastoreError {}
 /// Errors returned by CreatePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePipelineError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2149,6 +2224,8 @@ pub enum CreatePipelineError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePipelineError {
@@ -2156,27 +2233,34 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreatePipelineError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreatePipelineError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreatePipelineError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreatePipelineError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePipelineError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePipelineError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreatePipelineError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreatePipelineError::Throttling(err.msg))
+                    return RusotoError::service(CreatePipelineError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2192,12 +2276,14 @@ impl fmt::Display for CreatePipelineError {
             CreatePipelineError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreatePipelineError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreatePipelineError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreatePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePipelineError {}
 /// Errors returned by DeleteChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteChannelError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2209,6 +2295,8 @@ pub enum DeleteChannelError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteChannelError {
@@ -2216,22 +2304,28 @@ impl DeleteChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteChannelError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteChannelError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteChannelError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteChannelError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteChannelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteChannelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteChannelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteChannelError::Throttling(err.msg))
+                    return RusotoError::service(DeleteChannelError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2246,12 +2340,14 @@ impl fmt::Display for DeleteChannelError {
             DeleteChannelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteChannelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteChannelError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteChannelError {}
 /// Errors returned by DeleteDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatasetError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2263,6 +2359,8 @@ pub enum DeleteDatasetError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatasetError {
@@ -2270,22 +2368,28 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDatasetError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteDatasetError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteDatasetError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDatasetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDatasetError::Throttling(err.msg))
+                    return RusotoError::service(DeleteDatasetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2300,12 +2404,14 @@ impl fmt::Display for DeleteDatasetError {
             DeleteDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDatasetError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteDatasetError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatasetError {}
 /// Errors returned by DeleteDatasetContent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatasetContentError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2317,6 +2423,8 @@ pub enum DeleteDatasetContentError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatasetContentError {
@@ -2324,28 +2432,37 @@ impl DeleteDatasetContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDatasetContentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetContentError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDatasetContentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDatasetContentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetContentError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatasetContentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2360,12 +2477,14 @@ impl fmt::Display for DeleteDatasetContentError {
             DeleteDatasetContentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDatasetContentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteDatasetContentError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteDatasetContentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatasetContentError {}
 /// Errors returned by DeleteDatastore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatastoreError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2377,6 +2496,8 @@ pub enum DeleteDatastoreError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatastoreError {
@@ -2384,22 +2505,34 @@ impl DeleteDatastoreError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDatastoreError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteDatastoreError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDatastoreError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteDatastoreError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatastoreError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDatastoreError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDatastoreError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteDatastoreError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDatastoreError::Throttling(err.msg))
+                    return RusotoError::service(DeleteDatastoreError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatastoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2414,12 +2547,14 @@ impl fmt::Display for DeleteDatastoreError {
             DeleteDatastoreError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDatastoreError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteDatastoreError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteDatastoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatastoreError {}
 /// Errors returned by DeletePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePipelineError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2431,6 +2566,8 @@ pub enum DeletePipelineError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePipelineError {
@@ -2438,22 +2575,31 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeletePipelineError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeletePipelineError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeletePipelineError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeletePipelineError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeletePipelineError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeletePipelineError::Throttling(err.msg))
+                    return RusotoError::service(DeletePipelineError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2468,12 +2614,14 @@ impl fmt::Display for DeletePipelineError {
             DeletePipelineError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeletePipelineError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeletePipelineError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeletePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePipelineError {}
 /// Errors returned by DescribeChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeChannelError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2485,6 +2633,8 @@ pub enum DescribeChannelError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeChannelError {
@@ -2492,22 +2642,34 @@ impl DescribeChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeChannelError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeChannelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeChannelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeChannelError::Throttling(err.msg))
+                    return RusotoError::service(DescribeChannelError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2522,12 +2684,14 @@ impl fmt::Display for DescribeChannelError {
             DescribeChannelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeChannelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeChannelError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeChannelError {}
 /// Errors returned by DescribeDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDatasetError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2539,6 +2703,8 @@ pub enum DescribeDatasetError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDatasetError {
@@ -2546,22 +2712,34 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDatasetError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDatasetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDatasetError::Throttling(err.msg))
+                    return RusotoError::service(DescribeDatasetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2576,12 +2754,14 @@ impl fmt::Display for DescribeDatasetError {
             DescribeDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDatasetError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeDatasetError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDatasetError {}
 /// Errors returned by DescribeDatastore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDatastoreError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2593,6 +2773,8 @@ pub enum DescribeDatastoreError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDatastoreError {
@@ -2600,24 +2782,34 @@ impl DescribeDatastoreError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDatastoreError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeDatastoreError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDatastoreError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeDatastoreError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatastoreError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDatastoreError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDatastoreError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDatastoreError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDatastoreError::Throttling(err.msg))
+                    return RusotoError::service(DescribeDatastoreError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDatastoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2632,12 +2824,14 @@ impl fmt::Display for DescribeDatastoreError {
             DescribeDatastoreError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDatastoreError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeDatastoreError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDatastoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDatastoreError {}
 /// Errors returned by DescribeLoggingOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoggingOptionsError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2649,6 +2843,8 @@ pub enum DescribeLoggingOptionsError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoggingOptionsError {
@@ -2656,30 +2852,37 @@ impl DescribeLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLoggingOptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2694,12 +2897,14 @@ impl fmt::Display for DescribeLoggingOptionsError {
             DescribeLoggingOptionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeLoggingOptionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeLoggingOptionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeLoggingOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLoggingOptionsError {}
 /// Errors returned by DescribePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePipelineError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2711,6 +2916,8 @@ pub enum DescribePipelineError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePipelineError {
@@ -2718,22 +2925,34 @@ impl DescribePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribePipelineError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribePipelineError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribePipelineError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribePipelineError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribePipelineError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribePipelineError::Throttling(err.msg))
+                    return RusotoError::service(DescribePipelineError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2748,12 +2967,14 @@ impl fmt::Display for DescribePipelineError {
             DescribePipelineError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribePipelineError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribePipelineError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePipelineError {}
 /// Errors returned by GetDatasetContent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDatasetContentError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2765,6 +2986,8 @@ pub enum GetDatasetContentError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDatasetContentError {
@@ -2772,24 +2995,34 @@ impl GetDatasetContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDatasetContentError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetDatasetContentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDatasetContentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetDatasetContentError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDatasetContentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetDatasetContentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDatasetContentError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDatasetContentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDatasetContentError::Throttling(err.msg))
+                    return RusotoError::service(GetDatasetContentError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDatasetContentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2804,12 +3037,14 @@ impl fmt::Display for GetDatasetContentError {
             GetDatasetContentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetDatasetContentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetDatasetContentError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetDatasetContentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDatasetContentError {}
 /// Errors returned by ListChannels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListChannelsError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2819,6 +3054,8 @@ pub enum ListChannelsError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListChannelsError {
@@ -2826,19 +3063,22 @@ impl ListChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListChannelsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListChannelsError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListChannelsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListChannelsError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListChannelsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListChannelsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListChannelsError::Throttling(err.msg))
+                    return RusotoError::service(ListChannelsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListChannelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2852,12 +3092,14 @@ impl fmt::Display for ListChannelsError {
             ListChannelsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListChannelsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListChannelsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListChannelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListChannelsError {}
 /// Errors returned by ListDatasetContents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatasetContentsError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2869,6 +3111,8 @@ pub enum ListDatasetContentsError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatasetContentsError {
@@ -2876,26 +3120,37 @@ impl ListDatasetContentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDatasetContentsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListDatasetContentsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDatasetContentsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListDatasetContentsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDatasetContentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDatasetContentsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDatasetContentsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDatasetContentsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDatasetContentsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListDatasetContentsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatasetContentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2910,12 +3165,14 @@ impl fmt::Display for ListDatasetContentsError {
             ListDatasetContentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDatasetContentsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListDatasetContentsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDatasetContentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatasetContentsError {}
 /// Errors returned by ListDatasets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatasetsError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2925,6 +3182,8 @@ pub enum ListDatasetsError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatasetsError {
@@ -2932,19 +3191,22 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDatasetsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListDatasetsError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListDatasetsError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDatasetsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListDatasetsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDatasetsError::Throttling(err.msg))
+                    return RusotoError::service(ListDatasetsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatasetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2958,12 +3220,14 @@ impl fmt::Display for ListDatasetsError {
             ListDatasetsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListDatasetsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListDatasetsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDatasetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatasetsError {}
 /// Errors returned by ListDatastores
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatastoresError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -2973,6 +3237,8 @@ pub enum ListDatastoresError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatastoresError {
@@ -2980,19 +3246,25 @@ impl ListDatastoresError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDatastoresError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListDatastoresError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDatastoresError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListDatastoresError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDatastoresError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListDatastoresError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDatastoresError::Throttling(err.msg))
+                    return RusotoError::service(ListDatastoresError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatastoresError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3006,12 +3278,14 @@ impl fmt::Display for ListDatastoresError {
             ListDatastoresError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListDatastoresError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListDatastoresError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDatastoresError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatastoresError {}
 /// Errors returned by ListPipelines
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPipelinesError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3021,6 +3295,8 @@ pub enum ListPipelinesError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPipelinesError {
@@ -3028,19 +3304,22 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPipelinesError::InternalFailure(err.msg))
+                    return RusotoError::service(ListPipelinesError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListPipelinesError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPipelinesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListPipelinesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPipelinesError::Throttling(err.msg))
+                    return RusotoError::service(ListPipelinesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPipelinesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3054,12 +3333,14 @@ impl fmt::Display for ListPipelinesError {
             ListPipelinesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListPipelinesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListPipelinesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListPipelinesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPipelinesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3073,6 +3354,8 @@ pub enum ListTagsForResourceError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3080,29 +3363,43 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3118,12 +3415,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutLoggingOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLoggingOptionsError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3133,6 +3432,8 @@ pub enum PutLoggingOptionsError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLoggingOptionsError {
@@ -3140,21 +3441,28 @@ impl PutLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        PutLoggingOptionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        PutLoggingOptionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLoggingOptionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::Throttling(err.msg))
+                    return RusotoError::service(PutLoggingOptionsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutLoggingOptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3168,12 +3476,14 @@ impl fmt::Display for PutLoggingOptionsError {
             PutLoggingOptionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             PutLoggingOptionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             PutLoggingOptionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            PutLoggingOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutLoggingOptionsError {}
 /// Errors returned by RunPipelineActivity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RunPipelineActivityError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3183,6 +3493,8 @@ pub enum RunPipelineActivityError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RunPipelineActivityError {
@@ -3190,21 +3502,31 @@ impl RunPipelineActivityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(RunPipelineActivityError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        RunPipelineActivityError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RunPipelineActivityError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        RunPipelineActivityError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RunPipelineActivityError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RunPipelineActivityError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RunPipelineActivityError::Throttling(err.msg))
+                    return RusotoError::service(
+                        RunPipelineActivityError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RunPipelineActivityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3218,12 +3540,14 @@ impl fmt::Display for RunPipelineActivityError {
             RunPipelineActivityError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             RunPipelineActivityError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             RunPipelineActivityError::Throttling(ref cause) => write!(f, "{}", cause),
+            RunPipelineActivityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RunPipelineActivityError {}
 /// Errors returned by SampleChannelData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SampleChannelDataError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3235,6 +3559,8 @@ pub enum SampleChannelDataError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SampleChannelDataError {
@@ -3242,24 +3568,34 @@ impl SampleChannelDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SampleChannelDataError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        SampleChannelDataError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SampleChannelDataError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        SampleChannelDataError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SampleChannelDataError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SampleChannelDataError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SampleChannelDataError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SampleChannelDataError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SampleChannelDataError::Throttling(err.msg))
+                    return RusotoError::service(SampleChannelDataError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SampleChannelDataError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3274,12 +3610,14 @@ impl fmt::Display for SampleChannelDataError {
             SampleChannelDataError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SampleChannelDataError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             SampleChannelDataError::Throttling(ref cause) => write!(f, "{}", cause),
+            SampleChannelDataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SampleChannelDataError {}
 /// Errors returned by StartPipelineReprocessing
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartPipelineReprocessingError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3293,6 +3631,8 @@ pub enum StartPipelineReprocessingError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartPipelineReprocessingError {
@@ -3300,37 +3640,45 @@ impl StartPipelineReprocessingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartPipelineReprocessingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartPipelineReprocessingError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartPipelineReprocessingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartPipelineReprocessingError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartPipelineReprocessingError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartPipelineReprocessingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartPipelineReprocessingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartPipelineReprocessingError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartPipelineReprocessingError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartPipelineReprocessingError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartPipelineReprocessingError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3348,12 +3696,14 @@ impl fmt::Display for StartPipelineReprocessingError {
             StartPipelineReprocessingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartPipelineReprocessingError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             StartPipelineReprocessingError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartPipelineReprocessingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartPipelineReprocessingError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3367,6 +3717,8 @@ pub enum TagResourceError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -3374,25 +3726,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(TagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(TagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3408,12 +3763,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             TagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3427,6 +3784,8 @@ pub enum UntagResourceError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -3434,25 +3793,31 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(UntagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(UntagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3468,12 +3833,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateChannelError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3485,6 +3852,8 @@ pub enum UpdateChannelError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateChannelError {
@@ -3492,22 +3861,28 @@ impl UpdateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateChannelError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateChannelError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateChannelError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateChannelError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateChannelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateChannelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateChannelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateChannelError::Throttling(err.msg))
+                    return RusotoError::service(UpdateChannelError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3522,12 +3897,14 @@ impl fmt::Display for UpdateChannelError {
             UpdateChannelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateChannelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateChannelError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateChannelError {}
 /// Errors returned by UpdateDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDatasetError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3539,6 +3916,8 @@ pub enum UpdateDatasetError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDatasetError {
@@ -3546,22 +3925,28 @@ impl UpdateDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDatasetError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateDatasetError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDatasetError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateDatasetError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDatasetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateDatasetError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDatasetError::Throttling(err.msg))
+                    return RusotoError::service(UpdateDatasetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3576,12 +3961,14 @@ impl fmt::Display for UpdateDatasetError {
             UpdateDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDatasetError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateDatasetError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDatasetError {}
 /// Errors returned by UpdateDatastore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDatastoreError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3593,6 +3980,8 @@ pub enum UpdateDatastoreError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDatastoreError {
@@ -3600,22 +3989,34 @@ impl UpdateDatastoreError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDatastoreError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateDatastoreError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDatastoreError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateDatastoreError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDatastoreError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDatastoreError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDatastoreError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateDatastoreError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDatastoreError::Throttling(err.msg))
+                    return RusotoError::service(UpdateDatastoreError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDatastoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3630,12 +4031,14 @@ impl fmt::Display for UpdateDatastoreError {
             UpdateDatastoreError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDatastoreError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateDatastoreError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDatastoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDatastoreError {}
 /// Errors returned by UpdatePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePipelineError {
     /// <p>There was an internal failure.</p>
     InternalFailure(String),
@@ -3649,6 +4052,8 @@ pub enum UpdatePipelineError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePipelineError {
@@ -3656,25 +4061,34 @@ impl UpdatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdatePipelineError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdatePipelineError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdatePipelineError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdatePipelineError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdatePipelineError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdatePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdatePipelineError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdatePipelineError::Throttling(err.msg))
+                    return RusotoError::service(UpdatePipelineError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3690,6 +4104,7 @@ impl fmt::Display for UpdatePipelineError {
             UpdatePipelineError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdatePipelineError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdatePipelineError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdatePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3911,6 +4326,7 @@ impl IotAnalyticsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> IotAnalyticsClient {
         IotAnalyticsClient {
             client: Client::shared(),
@@ -3936,46 +4352,127 @@ impl IotAnalyticsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> IotAnalyticsClient {
         IotAnalyticsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<IotAnalyticsClient> {
+        rusoto_core::ClientBuilder::new(IotAnalyticsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> IotAnalyticsClient {
+        IotAnalyticsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl IotAnalytics for IotAnalyticsClient {
-    /// <p>Sends messages to a channel.</p>
-    async fn batch_put_message(
+impl IotAnalyticsClient {
+    /// Calls `batch_put_message`, also returning the metadata of the response.
+    pub async fn batch_put_message_with_metadata(
         &self,
         input: BatchPutMessageRequest,
-    ) -> Result<BatchPutMessageResponse, RusotoError<BatchPutMessageError>> {
+    ) -> Result<(BatchPutMessageResponse, ResponseMetadata), RusotoError<BatchPutMessageError>>
+    {
+        let request = self.build_batch_put_message_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchPutMessageResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchPutMessageError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_put_message` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_put_message(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchPutMessageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_put_message_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_put_message_request(&self, input: BatchPutMessageRequest) -> SignedRequest {
         let request_uri = "/messages/batch";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("BatchPutMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `cancel_pipeline_reprocessing`, also returning the metadata of the response.
+    pub async fn cancel_pipeline_reprocessing_with_metadata(
+        &self,
+        input: CancelPipelineReprocessingRequest,
+    ) -> Result<
+        (CancelPipelineReprocessingResponse, ResponseMetadata),
+        RusotoError<CancelPipelineReprocessingError>,
+    > {
+        let request = self.build_cancel_pipeline_reprocessing_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchPutMessageResponse, _>()?;
+                .deserialize::<CancelPipelineReprocessingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchPutMessageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CancelPipelineReprocessingError::from_response(response))
         }
     }
 
-    /// <p>Cancels the reprocessing of data through the pipeline.</p>
-    async fn cancel_pipeline_reprocessing(
+    /// Presigns the request `cancel_pipeline_reprocessing` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_pipeline_reprocessing(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CancelPipelineReprocessingRequest,
-    ) -> Result<CancelPipelineReprocessingResponse, RusotoError<CancelPipelineReprocessingError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_pipeline_reprocessing_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_pipeline_reprocessing_request(
+        &self,
+        input: CancelPipelineReprocessingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/pipelines/{pipeline_name}/reprocessing/{reprocessing_id}",
             pipeline_name = input.pipeline_name,
@@ -3983,244 +4480,446 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CancelPipelineReprocessing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `create_channel`, also returning the metadata of the response.
+    pub async fn create_channel_with_metadata(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<(CreateChannelResponse, ResponseMetadata), RusotoError<CreateChannelError>> {
+        let request = self.build_create_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelPipelineReprocessingResponse, _>()?;
+                .deserialize::<CreateChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelPipelineReprocessingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateChannelError::from_response(response))
         }
     }
 
-    /// <p>Creates a channel. A channel collects data from an MQTT topic and archives the raw, unprocessed messages before publishing the data to a pipeline.</p>
-    async fn create_channel(
+    /// Presigns the request `create_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateChannelRequest,
-    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_channel_request(&self, input: CreateChannelRequest) -> SignedRequest {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_dataset`, also returning the metadata of the response.
+    pub async fn create_dataset_with_metadata(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<(CreateDatasetResponse, ResponseMetadata), RusotoError<CreateDatasetError>> {
+        let request = self.build_create_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateChannelResponse, _>()?;
+                .deserialize::<CreateDatasetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDatasetError::from_response(response))
         }
     }
 
-    /// <p>Creates a data set. A data set stores data retrieved from a data store by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application). This operation creates the skeleton of a data set. The data set can be populated manually by calling "CreateDatasetContent" or automatically according to a "trigger" you specify.</p>
-    async fn create_dataset(
+    /// Presigns the request `create_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDatasetRequest,
-    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dataset_request(&self, input: CreateDatasetRequest) -> SignedRequest {
         let request_uri = "/datasets";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_dataset_content`, also returning the metadata of the response.
+    pub async fn create_dataset_content_with_metadata(
+        &self,
+        input: CreateDatasetContentRequest,
+    ) -> Result<
+        (CreateDatasetContentResponse, ResponseMetadata),
+        RusotoError<CreateDatasetContentError>,
+    > {
+        let request = self.build_create_dataset_content_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDatasetResponse, _>()?;
+                .deserialize::<CreateDatasetContentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatasetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDatasetContentError::from_response(response))
         }
     }
 
-    /// <p>Creates the content of a data set by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application).</p>
-    async fn create_dataset_content(
+    /// Presigns the request `create_dataset_content` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dataset_content(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDatasetContentRequest,
-    ) -> Result<CreateDatasetContentResponse, RusotoError<CreateDatasetContentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dataset_content_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dataset_content_request(
+        &self,
+        input: CreateDatasetContentRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/datasets/{dataset_name}/content",
             dataset_name = input.dataset_name
         );
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateDatasetContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `create_datastore`, also returning the metadata of the response.
+    pub async fn create_datastore_with_metadata(
+        &self,
+        input: CreateDatastoreRequest,
+    ) -> Result<(CreateDatastoreResponse, ResponseMetadata), RusotoError<CreateDatastoreError>>
+    {
+        let request = self.build_create_datastore_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDatasetContentResponse, _>()?;
+                .deserialize::<CreateDatastoreResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatasetContentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDatastoreError::from_response(response))
         }
     }
 
-    /// <p>Creates a data store, which is a repository for messages.</p>
-    async fn create_datastore(
+    /// Presigns the request `create_datastore` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_datastore(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDatastoreRequest,
-    ) -> Result<CreateDatastoreResponse, RusotoError<CreateDatastoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_datastore_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_datastore_request(&self, input: CreateDatastoreRequest) -> SignedRequest {
         let request_uri = "/datastores";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreateDatastore");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_pipeline`, also returning the metadata of the response.
+    pub async fn create_pipeline_with_metadata(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> Result<(CreatePipelineResponse, ResponseMetadata), RusotoError<CreatePipelineError>> {
+        let request = self.build_create_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDatastoreResponse, _>()?;
+                .deserialize::<CreatePipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatastoreError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreatePipelineError::from_response(response))
         }
     }
 
-    /// <p>Creates a pipeline. A pipeline consumes messages from a channel and allows you to process the messages before storing them in a data store. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
-    async fn create_pipeline(
+    /// Presigns the request `create_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePipelineRequest,
-    ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_pipeline_request(&self, input: CreatePipelineRequest) -> SignedRequest {
         let request_uri = "/pipelines";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("CreatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_channel`, also returning the metadata of the response.
+    pub async fn delete_channel_with_metadata(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteChannelError>> {
+        let request = self.build_delete_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePipelineResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteChannelError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified channel.</p>
-    async fn delete_channel(
+    /// Presigns the request `delete_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteChannelRequest,
-    ) -> Result<(), RusotoError<DeleteChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_channel_request(&self, input: DeleteChannelRequest) -> SignedRequest {
         let request_uri = format!(
             "/channels/{channel_name}",
             channel_name = input.channel_name
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_dataset`, also returning the metadata of the response.
+    pub async fn delete_dataset_with_metadata(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDatasetError>> {
+        let request = self.build_delete_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDatasetError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified data set.</p> <p>You do not have to delete the content of the data set before you perform this operation.</p>
-    async fn delete_dataset(
+    /// Presigns the request `delete_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatasetRequest,
-    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dataset_request(&self, input: DeleteDatasetRequest) -> SignedRequest {
         let request_uri = format!(
             "/datasets/{dataset_name}",
             dataset_name = input.dataset_name
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_dataset_content`, also returning the metadata of the response.
+    pub async fn delete_dataset_content_with_metadata(
+        &self,
+        input: DeleteDatasetContentRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDatasetContentError>> {
+        let request = self.build_delete_dataset_content_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatasetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDatasetContentError::from_response(response))
         }
     }
 
-    /// <p>Deletes the content of the specified data set.</p>
-    async fn delete_dataset_content(
+    /// Presigns the request `delete_dataset_content` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dataset_content(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatasetContentRequest,
-    ) -> Result<(), RusotoError<DeleteDatasetContentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dataset_content_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dataset_content_request(
+        &self,
+        input: DeleteDatasetContentRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/datasets/{dataset_name}/content",
             dataset_name = input.dataset_name
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteDatasetContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4229,91 +4928,165 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_datastore`, also returning the metadata of the response.
+    pub async fn delete_datastore_with_metadata(
+        &self,
+        input: DeleteDatastoreRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDatastoreError>> {
+        let request = self.build_delete_datastore_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatasetContentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDatastoreError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified data store.</p>
-    async fn delete_datastore(
+    /// Presigns the request `delete_datastore` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_datastore(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatastoreRequest,
-    ) -> Result<(), RusotoError<DeleteDatastoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_datastore_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_datastore_request(&self, input: DeleteDatastoreRequest) -> SignedRequest {
         let request_uri = format!(
             "/datastores/{datastore_name}",
             datastore_name = input.datastore_name
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeleteDatastore");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_pipeline`, also returning the metadata of the response.
+    pub async fn delete_pipeline_with_metadata(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePipelineError>> {
+        let request = self.build_delete_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatastoreError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeletePipelineError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified pipeline.</p>
-    async fn delete_pipeline(
+    /// Presigns the request `delete_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePipelineRequest,
-    ) -> Result<(), RusotoError<DeletePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_pipeline_request(&self, input: DeletePipelineRequest) -> SignedRequest {
         let request_uri = format!(
             "/pipelines/{pipeline_name}",
             pipeline_name = input.pipeline_name
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DeletePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_channel`, also returning the metadata of the response.
+    pub async fn describe_channel_with_metadata(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<(DescribeChannelResponse, ResponseMetadata), RusotoError<DescribeChannelError>>
+    {
+        let request = self.build_describe_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeChannelError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a channel.</p>
-    async fn describe_channel(
+    /// Presigns the request `describe_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeChannelRequest,
-    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_channel_request(&self, input: DescribeChannelRequest) -> SignedRequest {
         let request_uri = format!(
             "/channels/{channel_name}",
             channel_name = input.channel_name
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4322,64 +5095,114 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_dataset`, also returning the metadata of the response.
+    pub async fn describe_dataset_with_metadata(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<(DescribeDatasetResponse, ResponseMetadata), RusotoError<DescribeDatasetError>>
+    {
+        let request = self.build_describe_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeChannelResponse, _>()?;
+                .deserialize::<DescribeDatasetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDatasetError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a data set.</p>
-    async fn describe_dataset(
+    /// Presigns the request `describe_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDatasetRequest,
-    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dataset_request(&self, input: DescribeDatasetRequest) -> SignedRequest {
         let request_uri = format!(
             "/datasets/{dataset_name}",
             dataset_name = input.dataset_name
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_datastore`, also returning the metadata of the response.
+    pub async fn describe_datastore_with_metadata(
+        &self,
+        input: DescribeDatastoreRequest,
+    ) -> Result<(DescribeDatastoreResponse, ResponseMetadata), RusotoError<DescribeDatastoreError>>
+    {
+        let request = self.build_describe_datastore_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDatasetResponse, _>()?;
+                .deserialize::<DescribeDatastoreResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDatasetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDatastoreError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a data store.</p>
-    async fn describe_datastore(
+    /// Presigns the request `describe_datastore` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_datastore(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDatastoreRequest,
-    ) -> Result<DescribeDatastoreResponse, RusotoError<DescribeDatastoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_datastore_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_datastore_request(&self, input: DescribeDatastoreRequest) -> SignedRequest {
         let request_uri = format!(
             "/datastores/{datastore_name}",
             datastore_name = input.datastore_name
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeDatastore");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4388,90 +5211,166 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_logging_options`, also returning the metadata of the response.
+    pub async fn describe_logging_options_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeLoggingOptionsResponse, ResponseMetadata),
+        RusotoError<DescribeLoggingOptionsError>,
+    > {
+        let request = self.build_describe_logging_options_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDatastoreResponse, _>()?;
+                .deserialize::<DescribeLoggingOptionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDatastoreError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeLoggingOptionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the current settings of the AWS IoT Analytics logging options.</p>
-    async fn describe_logging_options(
+    /// Presigns the request `describe_logging_options` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_logging_options(
         &self,
-    ) -> Result<DescribeLoggingOptionsResponse, RusotoError<DescribeLoggingOptionsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_logging_options_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_logging_options_request(&self) -> SignedRequest {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribeLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_pipeline`, also returning the metadata of the response.
+    pub async fn describe_pipeline_with_metadata(
+        &self,
+        input: DescribePipelineRequest,
+    ) -> Result<(DescribePipelineResponse, ResponseMetadata), RusotoError<DescribePipelineError>>
+    {
+        let request = self.build_describe_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLoggingOptionsResponse, _>()?;
+                .deserialize::<DescribePipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLoggingOptionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribePipelineError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a pipeline.</p>
-    async fn describe_pipeline(
+    /// Presigns the request `describe_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePipelineRequest,
-    ) -> Result<DescribePipelineResponse, RusotoError<DescribePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_pipeline_request(&self, input: DescribePipelineRequest) -> SignedRequest {
         let request_uri = format!(
             "/pipelines/{pipeline_name}",
             pipeline_name = input.pipeline_name
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("DescribePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_dataset_content`, also returning the metadata of the response.
+    pub async fn get_dataset_content_with_metadata(
+        &self,
+        input: GetDatasetContentRequest,
+    ) -> Result<(GetDatasetContentResponse, ResponseMetadata), RusotoError<GetDatasetContentError>>
+    {
+        let request = self.build_get_dataset_content_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePipelineResponse, _>()?;
+                .deserialize::<GetDatasetContentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDatasetContentError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the contents of a data set as pre-signed URIs.</p>
-    async fn get_dataset_content(
+    /// Presigns the request `get_dataset_content` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dataset_content(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDatasetContentRequest,
-    ) -> Result<GetDatasetContentResponse, RusotoError<GetDatasetContentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dataset_content_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dataset_content_request(&self, input: GetDatasetContentRequest) -> SignedRequest {
         let request_uri = format!(
             "/datasets/{dataset_name}/content",
             dataset_name = input.dataset_name
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("GetDatasetContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4480,31 +5379,55 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_channels`, also returning the metadata of the response.
+    pub async fn list_channels_with_metadata(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<(ListChannelsResponse, ResponseMetadata), RusotoError<ListChannelsError>> {
+        let request = self.build_list_channels_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDatasetContentResponse, _>()?;
+                .deserialize::<ListChannelsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDatasetContentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListChannelsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of channels.</p>
-    async fn list_channels(
+    /// Presigns the request `list_channels` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_channels(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListChannelsRequest,
-    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_channels_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_channels_request(&self, input: ListChannelsRequest) -> SignedRequest {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4516,34 +5439,64 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_dataset_contents`, also returning the metadata of the response.
+    pub async fn list_dataset_contents_with_metadata(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> Result<
+        (ListDatasetContentsResponse, ResponseMetadata),
+        RusotoError<ListDatasetContentsError>,
+    > {
+        let request = self.build_list_dataset_contents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListChannelsResponse, _>()?;
+                .deserialize::<ListDatasetContentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListChannelsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDatasetContentsError::from_response(response))
         }
     }
 
-    /// <p>Lists information about data set contents that have been created.</p>
-    async fn list_dataset_contents(
+    /// Presigns the request `list_dataset_contents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dataset_contents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatasetContentsRequest,
-    ) -> Result<ListDatasetContentsResponse, RusotoError<ListDatasetContentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dataset_contents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dataset_contents_request(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/datasets/{dataset_name}/contents",
             dataset_name = input.dataset_name
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListDatasetContents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4561,31 +5514,55 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_datasets`, also returning the metadata of the response.
+    pub async fn list_datasets_with_metadata(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<(ListDatasetsResponse, ResponseMetadata), RusotoError<ListDatasetsError>> {
+        let request = self.build_list_datasets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDatasetContentsResponse, _>()?;
+                .deserialize::<ListDatasetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatasetContentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDatasetsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about data sets.</p>
-    async fn list_datasets(
+    /// Presigns the request `list_datasets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_datasets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatasetsRequest,
-    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_datasets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_datasets_request(&self, input: ListDatasetsRequest) -> SignedRequest {
         let request_uri = "/datasets";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListDatasets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4597,31 +5574,55 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_datastores`, also returning the metadata of the response.
+    pub async fn list_datastores_with_metadata(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> Result<(ListDatastoresResponse, ResponseMetadata), RusotoError<ListDatastoresError>> {
+        let request = self.build_list_datastores_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDatasetsResponse, _>()?;
+                .deserialize::<ListDatastoresResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatasetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDatastoresError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of data stores.</p>
-    async fn list_datastores(
+    /// Presigns the request `list_datastores` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_datastores(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatastoresRequest,
-    ) -> Result<ListDatastoresResponse, RusotoError<ListDatastoresError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_datastores_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_datastores_request(&self, input: ListDatastoresRequest) -> SignedRequest {
         let request_uri = "/datastores";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListDatastores");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4633,31 +5634,55 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_pipelines`, also returning the metadata of the response.
+    pub async fn list_pipelines_with_metadata(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> Result<(ListPipelinesResponse, ResponseMetadata), RusotoError<ListPipelinesError>> {
+        let request = self.build_list_pipelines_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDatastoresResponse, _>()?;
+                .deserialize::<ListPipelinesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatastoresError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPipelinesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of pipelines.</p>
-    async fn list_pipelines(
+    /// Presigns the request `list_pipelines` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_pipelines(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPipelinesRequest,
-    ) -> Result<ListPipelinesResponse, RusotoError<ListPipelinesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_pipelines_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_pipelines_request(&self, input: ListPipelinesRequest) -> SignedRequest {
         let request_uri = "/pipelines";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListPipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4669,124 +5694,233 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPipelinesResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPipelinesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags (metadata) which you have assigned to the resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("resourceArn", &input.resource_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_logging_options`, also returning the metadata of the response.
+    pub async fn put_logging_options_with_metadata(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutLoggingOptionsError>> {
+        let request = self.build_put_logging_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutLoggingOptionsError::from_response(response))
         }
     }
 
-    /// <p>Sets or updates the AWS IoT Analytics logging options.</p> <p>Note that if you update the value of any <code>loggingOptions</code> field, it takes up to one minute for the change to take effect. Also, if you change the policy attached to the role you specified in the roleArn field (for example, to correct an invalid policy) it takes up to 5 minutes for that change to take effect. </p>
-    async fn put_logging_options(
+    /// Presigns the request `put_logging_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_logging_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutLoggingOptionsRequest,
-    ) -> Result<(), RusotoError<PutLoggingOptionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_logging_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_logging_options_request(&self, input: PutLoggingOptionsRequest) -> SignedRequest {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("PutLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `run_pipeline_activity`, also returning the metadata of the response.
+    pub async fn run_pipeline_activity_with_metadata(
+        &self,
+        input: RunPipelineActivityRequest,
+    ) -> Result<
+        (RunPipelineActivityResponse, ResponseMetadata),
+        RusotoError<RunPipelineActivityError>,
+    > {
+        let request = self.build_run_pipeline_activity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RunPipelineActivityResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutLoggingOptionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RunPipelineActivityError::from_response(response))
         }
     }
 
-    /// <p>Simulates the results of running a pipeline activity on a message payload.</p>
-    async fn run_pipeline_activity(
+    /// Presigns the request `run_pipeline_activity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_run_pipeline_activity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RunPipelineActivityRequest,
-    ) -> Result<RunPipelineActivityResponse, RusotoError<RunPipelineActivityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_run_pipeline_activity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_run_pipeline_activity_request(
+        &self,
+        input: RunPipelineActivityRequest,
+    ) -> SignedRequest {
         let request_uri = "/pipelineactivities/run";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("RunPipelineActivity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `sample_channel_data`, also returning the metadata of the response.
+    pub async fn sample_channel_data_with_metadata(
+        &self,
+        input: SampleChannelDataRequest,
+    ) -> Result<(SampleChannelDataResponse, ResponseMetadata), RusotoError<SampleChannelDataError>>
+    {
+        let request = self.build_sample_channel_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RunPipelineActivityResponse, _>()?;
+                .deserialize::<SampleChannelDataResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RunPipelineActivityError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SampleChannelDataError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a sample of messages from the specified channel ingested during the specified timeframe. Up to 10 messages can be retrieved.</p>
-    async fn sample_channel_data(
+    /// Presigns the request `sample_channel_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_sample_channel_data(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SampleChannelDataRequest,
-    ) -> Result<SampleChannelDataResponse, RusotoError<SampleChannelDataError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_sample_channel_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_sample_channel_data_request(&self, input: SampleChannelDataRequest) -> SignedRequest {
         let request_uri = format!(
             "/channels/{channel_name}/sample",
             channel_name = input.channel_name
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("SampleChannelData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4801,65 +5935,118 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `start_pipeline_reprocessing`, also returning the metadata of the response.
+    pub async fn start_pipeline_reprocessing_with_metadata(
+        &self,
+        input: StartPipelineReprocessingRequest,
+    ) -> Result<
+        (StartPipelineReprocessingResponse, ResponseMetadata),
+        RusotoError<StartPipelineReprocessingError>,
+    > {
+        let request = self.build_start_pipeline_reprocessing_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<SampleChannelDataResponse, _>()?;
+                .deserialize::<StartPipelineReprocessingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SampleChannelDataError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartPipelineReprocessingError::from_response(response))
         }
     }
 
-    /// <p>Starts the reprocessing of raw message data through the pipeline.</p>
-    async fn start_pipeline_reprocessing(
+    /// Presigns the request `start_pipeline_reprocessing` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_pipeline_reprocessing(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartPipelineReprocessingRequest,
-    ) -> Result<StartPipelineReprocessingResponse, RusotoError<StartPipelineReprocessingError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_pipeline_reprocessing_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_pipeline_reprocessing_request(
+        &self,
+        input: StartPipelineReprocessingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/pipelines/{pipeline_name}/reprocessing",
             pipeline_name = input.pipeline_name
         );
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("StartPipelineReprocessing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartPipelineReprocessingResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartPipelineReprocessingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata which can be used to manage a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4869,31 +6056,55 @@ impl IotAnalytics for IotAnalyticsClient {
         params.put("resourceArn", &input.resource_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the given tags (metadata) from the resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4903,148 +6114,574 @@ impl IotAnalytics for IotAnalyticsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_channel`, also returning the metadata of the response.
+    pub async fn update_channel_with_metadata(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateChannelError>> {
+        let request = self.build_update_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateChannelError::from_response(response))
         }
     }
 
-    /// <p>Updates the settings of a channel.</p>
-    async fn update_channel(
+    /// Presigns the request `update_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateChannelRequest,
-    ) -> Result<(), RusotoError<UpdateChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_channel_request(&self, input: UpdateChannelRequest) -> SignedRequest {
         let request_uri = format!(
             "/channels/{channel_name}",
             channel_name = input.channel_name
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_dataset`, also returning the metadata of the response.
+    pub async fn update_dataset_with_metadata(
+        &self,
+        input: UpdateDatasetRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateDatasetError>> {
+        let request = self.build_update_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDatasetError::from_response(response))
         }
     }
 
-    /// <p>Updates the settings of a data set.</p>
-    async fn update_dataset(
+    /// Presigns the request `update_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDatasetRequest,
-    ) -> Result<(), RusotoError<UpdateDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_dataset_request(&self, input: UpdateDatasetRequest) -> SignedRequest {
         let request_uri = format!(
             "/datasets/{dataset_name}",
             dataset_name = input.dataset_name
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdateDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_datastore`, also returning the metadata of the response.
+    pub async fn update_datastore_with_metadata(
+        &self,
+        input: UpdateDatastoreRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateDatastoreError>> {
+        let request = self.build_update_datastore_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDatasetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDatastoreError::from_response(response))
         }
     }
 
-    /// <p>Updates the settings of a data store.</p>
-    async fn update_datastore(
+    /// Presigns the request `update_datastore` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_datastore(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDatastoreRequest,
-    ) -> Result<(), RusotoError<UpdateDatastoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_datastore_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_datastore_request(&self, input: UpdateDatastoreRequest) -> SignedRequest {
         let request_uri = format!(
             "/datastores/{datastore_name}",
             datastore_name = input.datastore_name
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdateDatastore");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_pipeline`, also returning the metadata of the response.
+    pub async fn update_pipeline_with_metadata(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdatePipelineError>> {
+        let request = self.build_update_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDatastoreError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdatePipelineError::from_response(response))
         }
     }
 
-    /// <p>Updates the settings of a pipeline. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
-    async fn update_pipeline(
+    /// Presigns the request `update_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePipelineRequest,
-    ) -> Result<(), RusotoError<UpdatePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pipeline_request(&self, input: UpdatePipelineRequest) -> SignedRequest {
         let request_uri = format!(
             "/pipelines/{pipeline_name}",
             pipeline_name = input.pipeline_name
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation("UpdatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl IotAnalytics for IotAnalyticsClient {
+    /// <p>Sends messages to a channel.</p>
+    async fn batch_put_message(
+        &self,
+        input: BatchPutMessageRequest,
+    ) -> Result<BatchPutMessageResponse, RusotoError<BatchPutMessageError>> {
+        self.batch_put_message_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePipelineError::from_response(response))
-        }
+    /// <p>Cancels the reprocessing of data through the pipeline.</p>
+    async fn cancel_pipeline_reprocessing(
+        &self,
+        input: CancelPipelineReprocessingRequest,
+    ) -> Result<CancelPipelineReprocessingResponse, RusotoError<CancelPipelineReprocessingError>>
+    {
+        self.cancel_pipeline_reprocessing_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a channel. A channel collects data from an MQTT topic and archives the raw, unprocessed messages before publishing the data to a pipeline.</p>
+    async fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        self.create_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a data set. A data set stores data retrieved from a data store by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application). This operation creates the skeleton of a data set. The data set can be populated manually by calling "CreateDatasetContent" or automatically according to a "trigger" you specify.</p>
+    async fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+        self.create_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates the content of a data set by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application).</p>
+    async fn create_dataset_content(
+        &self,
+        input: CreateDatasetContentRequest,
+    ) -> Result<CreateDatasetContentResponse, RusotoError<CreateDatasetContentError>> {
+        self.create_dataset_content_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a data store, which is a repository for messages.</p>
+    async fn create_datastore(
+        &self,
+        input: CreateDatastoreRequest,
+    ) -> Result<CreateDatastoreResponse, RusotoError<CreateDatastoreError>> {
+        self.create_datastore_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a pipeline. A pipeline consumes messages from a channel and allows you to process the messages before storing them in a data store. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
+    async fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
+        self.create_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified channel.</p>
+    async fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<(), RusotoError<DeleteChannelError>> {
+        self.delete_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified data set.</p> <p>You do not have to delete the content of the data set before you perform this operation.</p>
+    async fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+        self.delete_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the content of the specified data set.</p>
+    async fn delete_dataset_content(
+        &self,
+        input: DeleteDatasetContentRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetContentError>> {
+        self.delete_dataset_content_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified data store.</p>
+    async fn delete_datastore(
+        &self,
+        input: DeleteDatastoreRequest,
+    ) -> Result<(), RusotoError<DeleteDatastoreError>> {
+        self.delete_datastore_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified pipeline.</p>
+    async fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> Result<(), RusotoError<DeletePipelineError>> {
+        self.delete_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a channel.</p>
+    async fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+        self.describe_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a data set.</p>
+    async fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.describe_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a data store.</p>
+    async fn describe_datastore(
+        &self,
+        input: DescribeDatastoreRequest,
+    ) -> Result<DescribeDatastoreResponse, RusotoError<DescribeDatastoreError>> {
+        self.describe_datastore_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the current settings of the AWS IoT Analytics logging options.</p>
+    async fn describe_logging_options(
+        &self,
+    ) -> Result<DescribeLoggingOptionsResponse, RusotoError<DescribeLoggingOptionsError>> {
+        self.describe_logging_options_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a pipeline.</p>
+    async fn describe_pipeline(
+        &self,
+        input: DescribePipelineRequest,
+    ) -> Result<DescribePipelineResponse, RusotoError<DescribePipelineError>> {
+        self.describe_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the contents of a data set as pre-signed URIs.</p>
+    async fn get_dataset_content(
+        &self,
+        input: GetDatasetContentRequest,
+    ) -> Result<GetDatasetContentResponse, RusotoError<GetDatasetContentError>> {
+        self.get_dataset_content_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of channels.</p>
+    async fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+        self.list_channels_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists information about data set contents that have been created.</p>
+    async fn list_dataset_contents(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> Result<ListDatasetContentsResponse, RusotoError<ListDatasetContentsError>> {
+        self.list_dataset_contents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about data sets.</p>
+    async fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.list_datasets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of data stores.</p>
+    async fn list_datastores(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> Result<ListDatastoresResponse, RusotoError<ListDatastoresError>> {
+        self.list_datastores_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of pipelines.</p>
+    async fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> Result<ListPipelinesResponse, RusotoError<ListPipelinesError>> {
+        self.list_pipelines_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags (metadata) which you have assigned to the resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets or updates the AWS IoT Analytics logging options.</p> <p>Note that if you update the value of any <code>loggingOptions</code> field, it takes up to one minute for the change to take effect. Also, if you change the policy attached to the role you specified in the roleArn field (for example, to correct an invalid policy) it takes up to 5 minutes for that change to take effect. </p>
+    async fn put_logging_options(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> Result<(), RusotoError<PutLoggingOptionsError>> {
+        self.put_logging_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Simulates the results of running a pipeline activity on a message payload.</p>
+    async fn run_pipeline_activity(
+        &self,
+        input: RunPipelineActivityRequest,
+    ) -> Result<RunPipelineActivityResponse, RusotoError<RunPipelineActivityError>> {
+        self.run_pipeline_activity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a sample of messages from the specified channel ingested during the specified timeframe. Up to 10 messages can be retrieved.</p>
+    async fn sample_channel_data(
+        &self,
+        input: SampleChannelDataRequest,
+    ) -> Result<SampleChannelDataResponse, RusotoError<SampleChannelDataError>> {
+        self.sample_channel_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the reprocessing of raw message data through the pipeline.</p>
+    async fn start_pipeline_reprocessing(
+        &self,
+        input: StartPipelineReprocessingRequest,
+    ) -> Result<StartPipelineReprocessingResponse, RusotoError<StartPipelineReprocessingError>>
+    {
+        self.start_pipeline_reprocessing_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata which can be used to manage a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the given tags (metadata) from the resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the settings of a channel.</p>
+    async fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<(), RusotoError<UpdateChannelError>> {
+        self.update_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the settings of a data set.</p>
+    async fn update_dataset(
+        &self,
+        input: UpdateDatasetRequest,
+    ) -> Result<(), RusotoError<UpdateDatasetError>> {
+        self.update_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the settings of a data store.</p>
+    async fn update_datastore(
+        &self,
+        input: UpdateDatastoreRequest,
+    ) -> Result<(), RusotoError<UpdateDatastoreError>> {
+        self.update_datastore_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the settings of a pipeline. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
+    async fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> Result<(), RusotoError<UpdatePipelineError>> {
+        self.update_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -338,6 +338,7 @@ pub struct VariableDefinition {
 
 /// Errors returned by BatchPutMessage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchPutMessageError {
     /// <p>An internal failure occured.</p>
     InternalFailure(String),
@@ -347,6 +348,8 @@ pub enum BatchPutMessageError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchPutMessageError {
@@ -354,19 +357,28 @@ impl BatchPutMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(BatchPutMessageError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        BatchPutMessageError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchPutMessageError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        BatchPutMessageError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchPutMessageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        BatchPutMessageError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchPutMessageError::Throttling(err.msg))
+                    return RusotoError::service(BatchPutMessageError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchPutMessageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -380,12 +392,14 @@ impl fmt::Display for BatchPutMessageError {
             BatchPutMessageError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             BatchPutMessageError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchPutMessageError::Throttling(ref cause) => write!(f, "{}", cause),
+            BatchPutMessageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchPutMessageError {}
 /// Errors returned by BatchUpdateDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchUpdateDetectorError {
     /// <p>An internal failure occured.</p>
     InternalFailure(String),
@@ -395,6 +409,8 @@ pub enum BatchUpdateDetectorError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchUpdateDetectorError {
@@ -402,21 +418,31 @@ impl BatchUpdateDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(BatchUpdateDetectorError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateDetectorError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchUpdateDetectorError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateDetectorError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdateDetectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchUpdateDetectorError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchUpdateDetectorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateDetectorError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchUpdateDetectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -430,12 +456,14 @@ impl fmt::Display for BatchUpdateDetectorError {
             BatchUpdateDetectorError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             BatchUpdateDetectorError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchUpdateDetectorError::Throttling(ref cause) => write!(f, "{}", cause),
+            BatchUpdateDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchUpdateDetectorError {}
 /// Errors returned by DescribeDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDetectorError {
     /// <p>An internal failure occured.</p>
     InternalFailure(String),
@@ -447,6 +475,8 @@ pub enum DescribeDetectorError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDetectorError {
@@ -454,22 +484,34 @@ impl DescribeDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDetectorError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeDetectorError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDetectorError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeDetectorError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDetectorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDetectorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDetectorError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeDetectorError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDetectorError::Throttling(err.msg))
+                    return RusotoError::service(DescribeDetectorError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDetectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -484,12 +526,14 @@ impl fmt::Display for DescribeDetectorError {
             DescribeDetectorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDetectorError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeDetectorError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDetectorError {}
 /// Errors returned by ListDetectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDetectorsError {
     /// <p>An internal failure occured.</p>
     InternalFailure(String),
@@ -501,6 +545,8 @@ pub enum ListDetectorsError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDetectorsError {
@@ -508,22 +554,28 @@ impl ListDetectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDetectorsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListDetectorsError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDetectorsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListDetectorsError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDetectorsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListDetectorsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDetectorsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListDetectorsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDetectorsError::Throttling(err.msg))
+                    return RusotoError::service(ListDetectorsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDetectorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -538,6 +590,7 @@ impl fmt::Display for ListDetectorsError {
             ListDetectorsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDetectorsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListDetectorsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDetectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -580,6 +633,7 @@ impl IotEventsDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> IotEventsDataClient {
         IotEventsDataClient {
             client: Client::shared(),
@@ -605,83 +659,191 @@ impl IotEventsDataClient {
     pub fn new_with_client(client: Client, region: region::Region) -> IotEventsDataClient {
         IotEventsDataClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<IotEventsDataClient> {
+        rusoto_core::ClientBuilder::new(IotEventsDataClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> IotEventsDataClient {
+        IotEventsDataClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl IotEventsData for IotEventsDataClient {
-    /// <p>Sends a set of messages to the AWS IoT Events system. Each message payload is transformed into the input you specify (<code>"inputName"</code>) and ingested into any detectors that monitor that input. If multiple messages are sent, the order in which the messages are processed isn't guaranteed. To guarantee ordering, you must send messages one at a time and wait for a successful response.</p>
-    async fn batch_put_message(
+impl IotEventsDataClient {
+    /// Calls `batch_put_message`, also returning the metadata of the response.
+    pub async fn batch_put_message_with_metadata(
         &self,
         input: BatchPutMessageRequest,
-    ) -> Result<BatchPutMessageResponse, RusotoError<BatchPutMessageError>> {
+    ) -> Result<(BatchPutMessageResponse, ResponseMetadata), RusotoError<BatchPutMessageError>>
+    {
+        let request = self.build_batch_put_message_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchPutMessageResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchPutMessageError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_put_message` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_put_message(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchPutMessageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_put_message_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_put_message_request(&self, input: BatchPutMessageRequest) -> SignedRequest {
         let request_uri = "/inputs/messages";
 
         let mut request = SignedRequest::new("POST", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("BatchPutMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `batch_update_detector`, also returning the metadata of the response.
+    pub async fn batch_update_detector_with_metadata(
+        &self,
+        input: BatchUpdateDetectorRequest,
+    ) -> Result<
+        (BatchUpdateDetectorResponse, ResponseMetadata),
+        RusotoError<BatchUpdateDetectorError>,
+    > {
+        let request = self.build_batch_update_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchPutMessageResponse, _>()?;
+                .deserialize::<BatchUpdateDetectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchPutMessageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchUpdateDetectorError::from_response(response))
         }
     }
 
-    /// <p>Updates the state, variable values, and timer settings of one or more detectors (instances) of a specified detector model.</p>
-    async fn batch_update_detector(
+    /// Presigns the request `batch_update_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_update_detector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchUpdateDetectorRequest,
-    ) -> Result<BatchUpdateDetectorResponse, RusotoError<BatchUpdateDetectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_update_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_update_detector_request(
+        &self,
+        input: BatchUpdateDetectorRequest,
+    ) -> SignedRequest {
         let request_uri = "/detectors";
 
         let mut request = SignedRequest::new("POST", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("BatchUpdateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_detector`, also returning the metadata of the response.
+    pub async fn describe_detector_with_metadata(
+        &self,
+        input: DescribeDetectorRequest,
+    ) -> Result<(DescribeDetectorResponse, ResponseMetadata), RusotoError<DescribeDetectorError>>
+    {
+        let request = self.build_describe_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchUpdateDetectorResponse, _>()?;
+                .deserialize::<DescribeDetectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchUpdateDetectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDetectorError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified detector (instance).</p>
-    async fn describe_detector(
+    /// Presigns the request `describe_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_detector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDetectorRequest,
-    ) -> Result<DescribeDetectorResponse, RusotoError<DescribeDetectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_detector_request(&self, input: DescribeDetectorRequest) -> SignedRequest {
         let request_uri = format!(
             "/detectors/{detector_model_name}/keyValues/",
             detector_model_name = input.detector_model_name
         );
 
         let mut request = SignedRequest::new("GET", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("DescribeDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -692,34 +854,58 @@ impl IotEventsData for IotEventsDataClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_detectors`, also returning the metadata of the response.
+    pub async fn list_detectors_with_metadata(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> Result<(ListDetectorsResponse, ResponseMetadata), RusotoError<ListDetectorsError>> {
+        let request = self.build_list_detectors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDetectorResponse, _>()?;
+                .deserialize::<ListDetectorsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDetectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDetectorsError::from_response(response))
         }
     }
 
-    /// <p>Lists detectors (the instances of a detector model).</p>
-    async fn list_detectors(
+    /// Presigns the request `list_detectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_detectors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDetectorsRequest,
-    ) -> Result<ListDetectorsResponse, RusotoError<ListDetectorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_detectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_detectors_request(&self, input: ListDetectorsRequest) -> SignedRequest {
         let request_uri = format!(
             "/detectors/{detector_model_name}",
             detector_model_name = input.detector_model_name
         );
 
         let mut request = SignedRequest::new("GET", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation("ListDetectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -736,20 +922,49 @@ impl IotEventsData for IotEventsDataClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl IotEventsData for IotEventsDataClient {
+    /// <p>Sends a set of messages to the AWS IoT Events system. Each message payload is transformed into the input you specify (<code>"inputName"</code>) and ingested into any detectors that monitor that input. If multiple messages are sent, the order in which the messages are processed isn't guaranteed. To guarantee ordering, you must send messages one at a time and wait for a successful response.</p>
+    async fn batch_put_message(
+        &self,
+        input: BatchPutMessageRequest,
+    ) -> Result<BatchPutMessageResponse, RusotoError<BatchPutMessageError>> {
+        self.batch_put_message_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDetectorsResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDetectorsError::from_response(response))
-        }
+    /// <p>Updates the state, variable values, and timer settings of one or more detectors (instances) of a specified detector model.</p>
+    async fn batch_update_detector(
+        &self,
+        input: BatchUpdateDetectorRequest,
+    ) -> Result<BatchUpdateDetectorResponse, RusotoError<BatchUpdateDetectorError>> {
+        self.batch_update_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified detector (instance).</p>
+    async fn describe_detector(
+        &self,
+        input: DescribeDetectorRequest,
+    ) -> Result<DescribeDetectorResponse, RusotoError<DescribeDetectorError>> {
+        self.describe_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists detectors (the instances of a detector model).</p>
+    async fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> Result<ListDetectorsResponse, RusotoError<ListDetectorsError>> {
+        self.list_detectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -837,6 +837,7 @@ pub struct UpdateInputResponse {
 
 /// Errors returned by CreateDetectorModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDetectorModelError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -852,6 +853,8 @@ pub enum CreateDetectorModelError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDetectorModelError {
@@ -859,32 +862,49 @@ impl CreateDetectorModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDetectorModelError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateDetectorModelError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDetectorModelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateDetectorModelError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDetectorModelError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDetectorModelError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDetectorModelError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDetectorModelError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDetectorModelError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateDetectorModelError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDetectorModelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDetectorModelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDetectorModelError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateDetectorModelError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDetectorModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -901,12 +921,14 @@ impl fmt::Display for CreateDetectorModelError {
             CreateDetectorModelError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateDetectorModelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateDetectorModelError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDetectorModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDetectorModelError {}
 /// Errors returned by CreateInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInputError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -918,6 +940,8 @@ pub enum CreateInputError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInputError {
@@ -925,22 +949,28 @@ impl CreateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateInputError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateInputError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateInputError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateInputError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateInputError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateInputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateInputError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateInputError::Throttling(err.msg))
+                    return RusotoError::service(CreateInputError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -955,12 +985,14 @@ impl fmt::Display for CreateInputError {
             CreateInputError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateInputError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateInputError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInputError {}
 /// Errors returned by DeleteDetectorModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDetectorModelError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -974,6 +1006,8 @@ pub enum DeleteDetectorModelError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDetectorModelError {
@@ -981,29 +1015,43 @@ impl DeleteDetectorModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteDetectorModelError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteDetectorModelError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteDetectorModelError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDetectorModelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDetectorModelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteDetectorModelError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDetectorModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1019,12 +1067,14 @@ impl fmt::Display for DeleteDetectorModelError {
             DeleteDetectorModelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDetectorModelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteDetectorModelError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteDetectorModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDetectorModelError {}
 /// Errors returned by DeleteInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInputError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1038,6 +1088,8 @@ pub enum DeleteInputError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInputError {
@@ -1045,25 +1097,28 @@ impl DeleteInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteInputError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteInputError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteInputError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteInputError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteInputError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInputError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteInputError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteInputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteInputError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteInputError::Throttling(err.msg))
+                    return RusotoError::service(DeleteInputError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1079,12 +1134,14 @@ impl fmt::Display for DeleteInputError {
             DeleteInputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteInputError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteInputError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInputError {}
 /// Errors returned by DescribeDetectorModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDetectorModelError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1096,6 +1153,8 @@ pub enum DescribeDetectorModelError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDetectorModelError {
@@ -1103,30 +1162,37 @@ impl DescribeDetectorModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDetectorModelError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDetectorModelError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDetectorModelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDetectorModelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeDetectorModelError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDetectorModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1141,12 +1207,14 @@ impl fmt::Display for DescribeDetectorModelError {
             DescribeDetectorModelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDetectorModelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeDetectorModelError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDetectorModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDetectorModelError {}
 /// Errors returned by DescribeInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInputError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1158,6 +1226,8 @@ pub enum DescribeInputError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInputError {
@@ -1165,22 +1235,28 @@ impl DescribeInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeInputError::InternalFailure(err.msg))
+                    return RusotoError::service(DescribeInputError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeInputError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeInputError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeInputError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeInputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeInputError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeInputError::Throttling(err.msg))
+                    return RusotoError::service(DescribeInputError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1195,12 +1271,14 @@ impl fmt::Display for DescribeInputError {
             DescribeInputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeInputError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeInputError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInputError {}
 /// Errors returned by DescribeLoggingOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoggingOptionsError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1214,6 +1292,8 @@ pub enum DescribeLoggingOptionsError {
     Throttling(String),
     /// <p>The requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoggingOptionsError {
@@ -1221,35 +1301,43 @@ impl DescribeLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLoggingOptionsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLoggingOptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1265,12 +1353,14 @@ impl fmt::Display for DescribeLoggingOptionsError {
             DescribeLoggingOptionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeLoggingOptionsError::Throttling(ref cause) => write!(f, "{}", cause),
             DescribeLoggingOptionsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DescribeLoggingOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLoggingOptionsError {}
 /// Errors returned by ListDetectorModelVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDetectorModelVersionsError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1282,6 +1372,8 @@ pub enum ListDetectorModelVersionsError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDetectorModelVersionsError {
@@ -1289,32 +1381,39 @@ impl ListDetectorModelVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDetectorModelVersionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDetectorModelVersionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDetectorModelVersionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDetectorModelVersionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDetectorModelVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDetectorModelVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDetectorModelVersionsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDetectorModelVersionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDetectorModelVersionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListDetectorModelVersionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1329,12 +1428,14 @@ impl fmt::Display for ListDetectorModelVersionsError {
             ListDetectorModelVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDetectorModelVersionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListDetectorModelVersionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDetectorModelVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDetectorModelVersionsError {}
 /// Errors returned by ListDetectorModels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDetectorModelsError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1344,6 +1445,8 @@ pub enum ListDetectorModelsError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDetectorModelsError {
@@ -1351,21 +1454,28 @@ impl ListDetectorModelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDetectorModelsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListDetectorModelsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDetectorModelsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListDetectorModelsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDetectorModelsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDetectorModelsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDetectorModelsError::Throttling(err.msg))
+                    return RusotoError::service(ListDetectorModelsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDetectorModelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1379,12 +1489,14 @@ impl fmt::Display for ListDetectorModelsError {
             ListDetectorModelsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListDetectorModelsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListDetectorModelsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDetectorModelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDetectorModelsError {}
 /// Errors returned by ListInputs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInputsError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1394,6 +1506,8 @@ pub enum ListInputsError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInputsError {
@@ -1401,19 +1515,19 @@ impl ListInputsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListInputsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListInputsError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInputsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListInputsError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListInputsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListInputsError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListInputsError::Throttling(err.msg))
+                    return RusotoError::service(ListInputsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInputsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1427,12 +1541,14 @@ impl fmt::Display for ListInputsError {
             ListInputsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListInputsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListInputsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListInputsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInputsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1444,6 +1560,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1451,24 +1569,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1483,12 +1614,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutLoggingOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLoggingOptionsError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1502,6 +1635,8 @@ pub enum PutLoggingOptionsError {
     Throttling(String),
     /// <p>The requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLoggingOptionsError {
@@ -1509,29 +1644,40 @@ impl PutLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        PutLoggingOptionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        PutLoggingOptionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        PutLoggingOptionsError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLoggingOptionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::Throttling(err.msg))
+                    return RusotoError::service(PutLoggingOptionsError::Throttling(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLoggingOptionsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutLoggingOptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1547,12 +1693,14 @@ impl fmt::Display for PutLoggingOptionsError {
             PutLoggingOptionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             PutLoggingOptionsError::Throttling(ref cause) => write!(f, "{}", cause),
             PutLoggingOptionsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            PutLoggingOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutLoggingOptionsError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1566,6 +1714,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1573,25 +1723,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(TagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(TagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1607,12 +1757,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1624,6 +1776,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1631,22 +1785,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(UntagResourceError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(UntagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1661,12 +1818,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDetectorModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDetectorModelError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1680,6 +1839,8 @@ pub enum UpdateDetectorModelError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDetectorModelError {
@@ -1687,29 +1848,43 @@ impl UpdateDetectorModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateDetectorModelError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateDetectorModelError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateDetectorModelError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDetectorModelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDetectorModelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateDetectorModelError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDetectorModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1725,12 +1900,14 @@ impl fmt::Display for UpdateDetectorModelError {
             UpdateDetectorModelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDetectorModelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateDetectorModelError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDetectorModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDetectorModelError {}
 /// Errors returned by UpdateInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateInputError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -1744,6 +1921,8 @@ pub enum UpdateInputError {
     ServiceUnavailable(String),
     /// <p>The request could not be completed due to throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateInputError {
@@ -1751,25 +1930,28 @@ impl UpdateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateInputError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateInputError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateInputError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateInputError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdateInputError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateInputError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateInputError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateInputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateInputError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateInputError::Throttling(err.msg))
+                    return RusotoError::service(UpdateInputError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1785,6 +1967,7 @@ impl fmt::Display for UpdateInputError {
             UpdateInputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateInputError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateInputError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1898,6 +2081,7 @@ impl IotEventsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> IotEventsClient {
         IotEventsClient {
             client: Client::shared(),
@@ -1923,138 +2107,304 @@ impl IotEventsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> IotEventsClient {
         IotEventsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<IotEventsClient> {
+        rusoto_core::ClientBuilder::new(IotEventsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> IotEventsClient {
+        IotEventsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl IotEvents for IotEventsClient {
-    /// <p>Creates a detector model.</p>
-    async fn create_detector_model(
+impl IotEventsClient {
+    /// Calls `create_detector_model`, also returning the metadata of the response.
+    pub async fn create_detector_model_with_metadata(
         &self,
         input: CreateDetectorModelRequest,
-    ) -> Result<CreateDetectorModelResponse, RusotoError<CreateDetectorModelError>> {
+    ) -> Result<
+        (CreateDetectorModelResponse, ResponseMetadata),
+        RusotoError<CreateDetectorModelError>,
+    > {
+        let request = self.build_create_detector_model_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDetectorModelResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDetectorModelError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_detector_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_detector_model(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateDetectorModelRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_detector_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_detector_model_request(
+        &self,
+        input: CreateDetectorModelRequest,
+    ) -> SignedRequest {
         let request_uri = "/detector-models";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("CreateDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_input`, also returning the metadata of the response.
+    pub async fn create_input_with_metadata(
+        &self,
+        input: CreateInputRequest,
+    ) -> Result<(CreateInputResponse, ResponseMetadata), RusotoError<CreateInputError>> {
+        let request = self.build_create_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDetectorModelResponse, _>()?;
+                .deserialize::<CreateInputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDetectorModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateInputError::from_response(response))
         }
     }
 
-    /// <p>Creates an input.</p>
-    async fn create_input(
+    /// Presigns the request `create_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_input(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateInputRequest,
-    ) -> Result<CreateInputResponse, RusotoError<CreateInputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_input_request(&self, input: CreateInputRequest) -> SignedRequest {
         let request_uri = "/inputs";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("CreateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_detector_model`, also returning the metadata of the response.
+    pub async fn delete_detector_model_with_metadata(
+        &self,
+        input: DeleteDetectorModelRequest,
+    ) -> Result<
+        (DeleteDetectorModelResponse, ResponseMetadata),
+        RusotoError<DeleteDetectorModelError>,
+    > {
+        let request = self.build_delete_detector_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateInputResponse, _>()?;
+                .deserialize::<DeleteDetectorModelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateInputError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDetectorModelError::from_response(response))
         }
     }
 
-    /// <p>Deletes a detector model. Any active instances of the detector model are also deleted.</p>
-    async fn delete_detector_model(
+    /// Presigns the request `delete_detector_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_detector_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDetectorModelRequest,
-    ) -> Result<DeleteDetectorModelResponse, RusotoError<DeleteDetectorModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_detector_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_detector_model_request(
+        &self,
+        input: DeleteDetectorModelRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector-models/{detector_model_name}",
             detector_model_name = input.detector_model_name
         );
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation("DeleteDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_input`, also returning the metadata of the response.
+    pub async fn delete_input_with_metadata(
+        &self,
+        input: DeleteInputRequest,
+    ) -> Result<(DeleteInputResponse, ResponseMetadata), RusotoError<DeleteInputError>> {
+        let request = self.build_delete_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDetectorModelResponse, _>()?;
+                .deserialize::<DeleteInputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDetectorModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteInputError::from_response(response))
         }
     }
 
-    /// <p>Deletes an input.</p>
-    async fn delete_input(
+    /// Presigns the request `delete_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_input(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInputRequest,
-    ) -> Result<DeleteInputResponse, RusotoError<DeleteInputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_input_request(&self, input: DeleteInputRequest) -> SignedRequest {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation("DeleteInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_detector_model`, also returning the metadata of the response.
+    pub async fn describe_detector_model_with_metadata(
+        &self,
+        input: DescribeDetectorModelRequest,
+    ) -> Result<
+        (DescribeDetectorModelResponse, ResponseMetadata),
+        RusotoError<DescribeDetectorModelError>,
+    > {
+        let request = self.build_describe_detector_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInputResponse, _>()?;
+                .deserialize::<DescribeDetectorModelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInputError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDetectorModelError::from_response(response))
         }
     }
 
-    /// <p>Describes a detector model. If the <code>"version"</code> parameter is not specified, information about the latest version is returned.</p>
-    async fn describe_detector_model(
+    /// Presigns the request `describe_detector_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_detector_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDetectorModelRequest,
-    ) -> Result<DescribeDetectorModelResponse, RusotoError<DescribeDetectorModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_detector_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_detector_model_request(
+        &self,
+        input: DescribeDetectorModelRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector-models/{detector_model_name}",
             detector_model_name = input.detector_model_name
         );
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("DescribeDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2063,88 +2413,167 @@ impl IotEvents for IotEventsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_input`, also returning the metadata of the response.
+    pub async fn describe_input_with_metadata(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<(DescribeInputResponse, ResponseMetadata), RusotoError<DescribeInputError>> {
+        let request = self.build_describe_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDetectorModelResponse, _>()?;
+                .deserialize::<DescribeInputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDetectorModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeInputError::from_response(response))
         }
     }
 
-    /// <p>Describes an input.</p>
-    async fn describe_input(
+    /// Presigns the request `describe_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_input(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInputRequest,
-    ) -> Result<DescribeInputResponse, RusotoError<DescribeInputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_input_request(&self, input: DescribeInputRequest) -> SignedRequest {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("DescribeInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_logging_options`, also returning the metadata of the response.
+    pub async fn describe_logging_options_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeLoggingOptionsResponse, ResponseMetadata),
+        RusotoError<DescribeLoggingOptionsError>,
+    > {
+        let request = self.build_describe_logging_options_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInputResponse, _>()?;
+                .deserialize::<DescribeLoggingOptionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInputError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeLoggingOptionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the current settings of the AWS IoT Events logging options.</p>
-    async fn describe_logging_options(
+    /// Presigns the request `describe_logging_options` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_logging_options(
         &self,
-    ) -> Result<DescribeLoggingOptionsResponse, RusotoError<DescribeLoggingOptionsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_logging_options_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_logging_options_request(&self) -> SignedRequest {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("DescribeLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_detector_model_versions`, also returning the metadata of the response.
+    pub async fn list_detector_model_versions_with_metadata(
+        &self,
+        input: ListDetectorModelVersionsRequest,
+    ) -> Result<
+        (ListDetectorModelVersionsResponse, ResponseMetadata),
+        RusotoError<ListDetectorModelVersionsError>,
+    > {
+        let request = self.build_list_detector_model_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLoggingOptionsResponse, _>()?;
+                .deserialize::<ListDetectorModelVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLoggingOptionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDetectorModelVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the versions of a detector model. Only the metadata associated with each detector model version is returned.</p>
-    async fn list_detector_model_versions(
+    /// Presigns the request `list_detector_model_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_detector_model_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDetectorModelVersionsRequest,
-    ) -> Result<ListDetectorModelVersionsResponse, RusotoError<ListDetectorModelVersionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_detector_model_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_detector_model_versions_request(
+        &self,
+        input: ListDetectorModelVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector-models/{detector_model_name}/versions",
             detector_model_name = input.detector_model_name
         );
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListDetectorModelVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2156,31 +2585,59 @@ impl IotEvents for IotEventsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_detector_models`, also returning the metadata of the response.
+    pub async fn list_detector_models_with_metadata(
+        &self,
+        input: ListDetectorModelsRequest,
+    ) -> Result<(ListDetectorModelsResponse, ResponseMetadata), RusotoError<ListDetectorModelsError>>
+    {
+        let request = self.build_list_detector_models_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDetectorModelVersionsResponse, _>()?;
+                .deserialize::<ListDetectorModelsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDetectorModelVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDetectorModelsError::from_response(response))
         }
     }
 
-    /// <p>Lists the detector models you have created. Only the metadata associated with each detector model is returned.</p>
-    async fn list_detector_models(
+    /// Presigns the request `list_detector_models` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_detector_models(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDetectorModelsRequest,
-    ) -> Result<ListDetectorModelsResponse, RusotoError<ListDetectorModelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_detector_models_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_detector_models_request(
+        &self,
+        input: ListDetectorModelsRequest,
+    ) -> SignedRequest {
         let request_uri = "/detector-models";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListDetectorModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2192,31 +2649,55 @@ impl IotEvents for IotEventsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_inputs`, also returning the metadata of the response.
+    pub async fn list_inputs_with_metadata(
+        &self,
+        input: ListInputsRequest,
+    ) -> Result<(ListInputsResponse, ResponseMetadata), RusotoError<ListInputsError>> {
+        let request = self.build_list_inputs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDetectorModelsResponse, _>()?;
+                .deserialize::<ListInputsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDetectorModelsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListInputsError::from_response(response))
         }
     }
 
-    /// <p>Lists the inputs you have created.</p>
-    async fn list_inputs(
+    /// Presigns the request `list_inputs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_inputs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInputsRequest,
-    ) -> Result<ListInputsResponse, RusotoError<ListInputsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_inputs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_inputs_request(&self, input: ListInputsRequest) -> SignedRequest {
         let request_uri = "/inputs";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListInputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2228,91 +2709,169 @@ impl IotEvents for IotEventsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInputsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInputsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags (metadata) you have assigned to the resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("resourceArn", &input.resource_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_logging_options`, also returning the metadata of the response.
+    pub async fn put_logging_options_with_metadata(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutLoggingOptionsError>> {
+        let request = self.build_put_logging_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutLoggingOptionsError::from_response(response))
         }
     }
 
-    /// <p>Sets or updates the AWS IoT Events logging options.</p> <p>If you update the value of any <code>"loggingOptions"</code> field, it takes up to one minute for the change to take effect. Also, if you change the policy attached to the role you specified in the <code>"roleArn"</code> field (for example, to correct an invalid policy) it takes up to five minutes for that change to take effect.</p>
-    async fn put_logging_options(
+    /// Presigns the request `put_logging_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_logging_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutLoggingOptionsRequest,
-    ) -> Result<(), RusotoError<PutLoggingOptionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_logging_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_logging_options_request(&self, input: PutLoggingOptionsRequest) -> SignedRequest {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("PUT", "iotevents", &self.region, &request_uri);
+        request.set_operation("PutLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutLoggingOptionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata that can be used to manage a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2322,31 +2881,55 @@ impl IotEvents for IotEventsClient {
         params.put("resourceArn", &input.resource_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the given tags (metadata) from the resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2356,83 +2939,286 @@ impl IotEvents for IotEventsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_detector_model`, also returning the metadata of the response.
+    pub async fn update_detector_model_with_metadata(
+        &self,
+        input: UpdateDetectorModelRequest,
+    ) -> Result<
+        (UpdateDetectorModelResponse, ResponseMetadata),
+        RusotoError<UpdateDetectorModelError>,
+    > {
+        let request = self.build_update_detector_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateDetectorModelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDetectorModelError::from_response(response))
         }
     }
 
-    /// <p>Updates a detector model. Detectors (instances) spawned by the previous version are deleted and then re-created as new inputs arrive.</p>
-    async fn update_detector_model(
+    /// Presigns the request `update_detector_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_detector_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDetectorModelRequest,
-    ) -> Result<UpdateDetectorModelResponse, RusotoError<UpdateDetectorModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_detector_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_detector_model_request(
+        &self,
+        input: UpdateDetectorModelRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/detector-models/{detector_model_name}",
             detector_model_name = input.detector_model_name
         );
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation("UpdateDetectorModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_input`, also returning the metadata of the response.
+    pub async fn update_input_with_metadata(
+        &self,
+        input: UpdateInputRequest,
+    ) -> Result<(UpdateInputResponse, ResponseMetadata), RusotoError<UpdateInputError>> {
+        let request = self.build_update_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDetectorModelResponse, _>()?;
+                .deserialize::<UpdateInputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDetectorModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateInputError::from_response(response))
         }
     }
 
-    /// <p>Updates an input.</p>
-    async fn update_input(
+    /// Presigns the request `update_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_input(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateInputRequest,
-    ) -> Result<UpdateInputResponse, RusotoError<UpdateInputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_input_request(&self, input: UpdateInputRequest) -> SignedRequest {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("PUT", "iotevents", &self.region, &request_uri);
+        request.set_operation("UpdateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl IotEvents for IotEventsClient {
+    /// <p>Creates a detector model.</p>
+    async fn create_detector_model(
+        &self,
+        input: CreateDetectorModelRequest,
+    ) -> Result<CreateDetectorModelResponse, RusotoError<CreateDetectorModelError>> {
+        self.create_detector_model_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateInputResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateInputError::from_response(response))
-        }
+    /// <p>Creates an input.</p>
+    async fn create_input(
+        &self,
+        input: CreateInputRequest,
+    ) -> Result<CreateInputResponse, RusotoError<CreateInputError>> {
+        self.create_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a detector model. Any active instances of the detector model are also deleted.</p>
+    async fn delete_detector_model(
+        &self,
+        input: DeleteDetectorModelRequest,
+    ) -> Result<DeleteDetectorModelResponse, RusotoError<DeleteDetectorModelError>> {
+        self.delete_detector_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an input.</p>
+    async fn delete_input(
+        &self,
+        input: DeleteInputRequest,
+    ) -> Result<DeleteInputResponse, RusotoError<DeleteInputError>> {
+        self.delete_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a detector model. If the <code>"version"</code> parameter is not specified, information about the latest version is returned.</p>
+    async fn describe_detector_model(
+        &self,
+        input: DescribeDetectorModelRequest,
+    ) -> Result<DescribeDetectorModelResponse, RusotoError<DescribeDetectorModelError>> {
+        self.describe_detector_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an input.</p>
+    async fn describe_input(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<DescribeInputResponse, RusotoError<DescribeInputError>> {
+        self.describe_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the current settings of the AWS IoT Events logging options.</p>
+    async fn describe_logging_options(
+        &self,
+    ) -> Result<DescribeLoggingOptionsResponse, RusotoError<DescribeLoggingOptionsError>> {
+        self.describe_logging_options_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the versions of a detector model. Only the metadata associated with each detector model version is returned.</p>
+    async fn list_detector_model_versions(
+        &self,
+        input: ListDetectorModelVersionsRequest,
+    ) -> Result<ListDetectorModelVersionsResponse, RusotoError<ListDetectorModelVersionsError>>
+    {
+        self.list_detector_model_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the detector models you have created. Only the metadata associated with each detector model is returned.</p>
+    async fn list_detector_models(
+        &self,
+        input: ListDetectorModelsRequest,
+    ) -> Result<ListDetectorModelsResponse, RusotoError<ListDetectorModelsError>> {
+        self.list_detector_models_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the inputs you have created.</p>
+    async fn list_inputs(
+        &self,
+        input: ListInputsRequest,
+    ) -> Result<ListInputsResponse, RusotoError<ListInputsError>> {
+        self.list_inputs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags (metadata) you have assigned to the resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets or updates the AWS IoT Events logging options.</p> <p>If you update the value of any <code>"loggingOptions"</code> field, it takes up to one minute for the change to take effect. Also, if you change the policy attached to the role you specified in the <code>"roleArn"</code> field (for example, to correct an invalid policy) it takes up to five minutes for that change to take effect.</p>
+    async fn put_logging_options(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> Result<(), RusotoError<PutLoggingOptionsError>> {
+        self.put_logging_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata that can be used to manage a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the given tags (metadata) from the resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a detector model. Detectors (instances) spawned by the previous version are deleted and then re-created as new inputs arrive.</p>
+    async fn update_detector_model(
+        &self,
+        input: UpdateDetectorModelRequest,
+    ) -> Result<UpdateDetectorModelResponse, RusotoError<UpdateDetectorModelError>> {
+        self.update_detector_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an input.</p>
+    async fn update_input(
+        &self,
+        input: UpdateInputRequest,
+    ) -> Result<UpdateInputResponse, RusotoError<UpdateInputError>> {
+        self.update_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1205,6 +1205,7 @@ pub struct UploadEntityDefinitionsResponse {
 
 /// Errors returned by AssociateEntityToThing
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateEntityToThingError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1214,6 +1215,8 @@ pub enum AssociateEntityToThingError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateEntityToThingError {
@@ -1221,25 +1224,31 @@ impl AssociateEntityToThingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateEntityToThingError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateEntityToThingError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateEntityToThingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::Throttling(err.msg))
+                    return RusotoError::service(
+                        AssociateEntityToThingError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateEntityToThingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1253,12 +1262,14 @@ impl fmt::Display for AssociateEntityToThingError {
             AssociateEntityToThingError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             AssociateEntityToThingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AssociateEntityToThingError::Throttling(ref cause) => write!(f, "{}", cause),
+            AssociateEntityToThingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateEntityToThingError {}
 /// Errors returned by CreateFlowTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFlowTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1270,6 +1281,8 @@ pub enum CreateFlowTemplateError {
     ResourceAlreadyExists(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFlowTemplateError {
@@ -1277,24 +1290,34 @@ impl CreateFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateFlowTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateFlowTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateFlowTemplateError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFlowTemplateError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(CreateFlowTemplateError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFlowTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1309,12 +1332,14 @@ impl fmt::Display for CreateFlowTemplateError {
             CreateFlowTemplateError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateFlowTemplateError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateFlowTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateFlowTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFlowTemplateError {}
 /// Errors returned by CreateSystemInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSystemInstanceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1326,6 +1351,8 @@ pub enum CreateSystemInstanceError {
     ResourceAlreadyExists(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSystemInstanceError {
@@ -1333,26 +1360,37 @@ impl CreateSystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSystemInstanceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateSystemInstanceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateSystemInstanceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSystemInstanceError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateSystemInstanceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSystemInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1367,12 +1405,14 @@ impl fmt::Display for CreateSystemInstanceError {
             CreateSystemInstanceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateSystemInstanceError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateSystemInstanceError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateSystemInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSystemInstanceError {}
 /// Errors returned by CreateSystemTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSystemTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1382,6 +1422,8 @@ pub enum CreateSystemTemplateError {
     ResourceAlreadyExists(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSystemTemplateError {
@@ -1389,23 +1431,31 @@ impl CreateSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSystemTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateSystemTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSystemTemplateError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateSystemTemplateError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSystemTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1419,12 +1469,14 @@ impl fmt::Display for CreateSystemTemplateError {
             CreateSystemTemplateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateSystemTemplateError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateSystemTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateSystemTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSystemTemplateError {}
 /// Errors returned by DeleteFlowTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFlowTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1434,6 +1486,8 @@ pub enum DeleteFlowTemplateError {
     ResourceInUse(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFlowTemplateError {
@@ -1441,19 +1495,28 @@ impl DeleteFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteFlowTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteFlowTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteFlowTemplateError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(DeleteFlowTemplateError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFlowTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1467,17 +1530,21 @@ impl fmt::Display for DeleteFlowTemplateError {
             DeleteFlowTemplateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteFlowTemplateError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteFlowTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteFlowTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFlowTemplateError {}
 /// Errors returned by DeleteNamespace
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNamespaceError {
     /// <p><p/></p>
     InternalFailure(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNamespaceError {
@@ -1485,13 +1552,16 @@ impl DeleteNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteNamespaceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteNamespaceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteNamespaceError::Throttling(err.msg))
+                    return RusotoError::service(DeleteNamespaceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNamespaceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1503,12 +1573,14 @@ impl fmt::Display for DeleteNamespaceError {
         match *self {
             DeleteNamespaceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteNamespaceError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteNamespaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNamespaceError {}
 /// Errors returned by DeleteSystemInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSystemInstanceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1518,6 +1590,8 @@ pub enum DeleteSystemInstanceError {
     ResourceInUse(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSystemInstanceError {
@@ -1525,21 +1599,31 @@ impl DeleteSystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSystemInstanceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteSystemInstanceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteSystemInstanceError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteSystemInstanceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSystemInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1553,12 +1637,14 @@ impl fmt::Display for DeleteSystemInstanceError {
             DeleteSystemInstanceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteSystemInstanceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteSystemInstanceError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteSystemInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSystemInstanceError {}
 /// Errors returned by DeleteSystemTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSystemTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1568,6 +1654,8 @@ pub enum DeleteSystemTemplateError {
     ResourceInUse(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSystemTemplateError {
@@ -1575,21 +1663,31 @@ impl DeleteSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSystemTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteSystemTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteSystemTemplateError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteSystemTemplateError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSystemTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1603,12 +1701,14 @@ impl fmt::Display for DeleteSystemTemplateError {
             DeleteSystemTemplateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteSystemTemplateError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteSystemTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteSystemTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSystemTemplateError {}
 /// Errors returned by DeploySystemInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeploySystemInstanceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1620,6 +1720,8 @@ pub enum DeploySystemInstanceError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeploySystemInstanceError {
@@ -1627,26 +1729,37 @@ impl DeploySystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeploySystemInstanceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeploySystemInstanceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeploySystemInstanceError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeploySystemInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeploySystemInstanceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeploySystemInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1661,12 +1774,14 @@ impl fmt::Display for DeploySystemInstanceError {
             DeploySystemInstanceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeploySystemInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeploySystemInstanceError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeploySystemInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeploySystemInstanceError {}
 /// Errors returned by DeprecateFlowTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeprecateFlowTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1676,6 +1791,8 @@ pub enum DeprecateFlowTemplateError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeprecateFlowTemplateError {
@@ -1683,25 +1800,31 @@ impl DeprecateFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateFlowTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateFlowTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateFlowTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeprecateFlowTemplateError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeprecateFlowTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1715,12 +1838,14 @@ impl fmt::Display for DeprecateFlowTemplateError {
             DeprecateFlowTemplateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeprecateFlowTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeprecateFlowTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeprecateFlowTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeprecateFlowTemplateError {}
 /// Errors returned by DeprecateSystemTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeprecateSystemTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1730,6 +1855,8 @@ pub enum DeprecateSystemTemplateError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeprecateSystemTemplateError {
@@ -1737,25 +1864,31 @@ impl DeprecateSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateSystemTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateSystemTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateSystemTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeprecateSystemTemplateError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeprecateSystemTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1769,12 +1902,14 @@ impl fmt::Display for DeprecateSystemTemplateError {
             DeprecateSystemTemplateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeprecateSystemTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeprecateSystemTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeprecateSystemTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeprecateSystemTemplateError {}
 /// Errors returned by DescribeNamespace
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeNamespaceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1784,6 +1919,8 @@ pub enum DescribeNamespaceError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeNamespaceError {
@@ -1791,19 +1928,28 @@ impl DescribeNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeNamespaceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeNamespaceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeNamespaceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeNamespaceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNamespaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeNamespaceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeNamespaceError::Throttling(err.msg))
+                    return RusotoError::service(DescribeNamespaceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeNamespaceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1817,12 +1963,14 @@ impl fmt::Display for DescribeNamespaceError {
             DescribeNamespaceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeNamespaceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeNamespaceError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeNamespaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeNamespaceError {}
 /// Errors returned by DissociateEntityFromThing
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DissociateEntityFromThingError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1832,6 +1980,8 @@ pub enum DissociateEntityFromThingError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DissociateEntityFromThingError {
@@ -1839,27 +1989,33 @@ impl DissociateEntityFromThingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DissociateEntityFromThingError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DissociateEntityFromThingError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DissociateEntityFromThingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DissociateEntityFromThingError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DissociateEntityFromThingError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1873,12 +2029,14 @@ impl fmt::Display for DissociateEntityFromThingError {
             DissociateEntityFromThingError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DissociateEntityFromThingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DissociateEntityFromThingError::Throttling(ref cause) => write!(f, "{}", cause),
+            DissociateEntityFromThingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DissociateEntityFromThingError {}
 /// Errors returned by GetEntities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEntitiesError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1888,6 +2046,8 @@ pub enum GetEntitiesError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEntitiesError {
@@ -1895,19 +2055,19 @@ impl GetEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetEntitiesError::InternalFailure(err.msg))
+                    return RusotoError::service(GetEntitiesError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetEntitiesError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEntitiesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetEntitiesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEntitiesError::Throttling(err.msg))
+                    return RusotoError::service(GetEntitiesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEntitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1921,12 +2081,14 @@ impl fmt::Display for GetEntitiesError {
             GetEntitiesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetEntitiesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetEntitiesError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetEntitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEntitiesError {}
 /// Errors returned by GetFlowTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFlowTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1936,6 +2098,8 @@ pub enum GetFlowTemplateError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFlowTemplateError {
@@ -1943,19 +2107,28 @@ impl GetFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetFlowTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetFlowTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFlowTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetFlowTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(GetFlowTemplateError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFlowTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1969,12 +2142,14 @@ impl fmt::Display for GetFlowTemplateError {
             GetFlowTemplateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetFlowTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFlowTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetFlowTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFlowTemplateError {}
 /// Errors returned by GetFlowTemplateRevisions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFlowTemplateRevisionsError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -1984,6 +2159,8 @@ pub enum GetFlowTemplateRevisionsError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFlowTemplateRevisionsError {
@@ -1991,25 +2168,31 @@ impl GetFlowTemplateRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFlowTemplateRevisionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFlowTemplateRevisionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFlowTemplateRevisionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetFlowTemplateRevisionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFlowTemplateRevisionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2023,12 +2206,14 @@ impl fmt::Display for GetFlowTemplateRevisionsError {
             GetFlowTemplateRevisionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetFlowTemplateRevisionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFlowTemplateRevisionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetFlowTemplateRevisionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFlowTemplateRevisionsError {}
 /// Errors returned by GetNamespaceDeletionStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetNamespaceDeletionStatusError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2036,6 +2221,8 @@ pub enum GetNamespaceDeletionStatusError {
     InvalidRequest(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetNamespaceDeletionStatusError {
@@ -2045,22 +2232,27 @@ impl GetNamespaceDeletionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetNamespaceDeletionStatusError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetNamespaceDeletionStatusError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNamespaceDeletionStatusError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetNamespaceDeletionStatusError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNamespaceDeletionStatusError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetNamespaceDeletionStatusError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetNamespaceDeletionStatusError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2073,12 +2265,14 @@ impl fmt::Display for GetNamespaceDeletionStatusError {
             GetNamespaceDeletionStatusError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetNamespaceDeletionStatusError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetNamespaceDeletionStatusError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetNamespaceDeletionStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetNamespaceDeletionStatusError {}
 /// Errors returned by GetSystemInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSystemInstanceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2088,6 +2282,8 @@ pub enum GetSystemInstanceError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSystemInstanceError {
@@ -2095,19 +2291,28 @@ impl GetSystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSystemInstanceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetSystemInstanceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetSystemInstanceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSystemInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetSystemInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(GetSystemInstanceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSystemInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2121,12 +2326,14 @@ impl fmt::Display for GetSystemInstanceError {
             GetSystemInstanceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetSystemInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetSystemInstanceError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetSystemInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSystemInstanceError {}
 /// Errors returned by GetSystemTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSystemTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2136,6 +2343,8 @@ pub enum GetSystemTemplateError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSystemTemplateError {
@@ -2143,19 +2352,28 @@ impl GetSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSystemTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetSystemTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetSystemTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSystemTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetSystemTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(GetSystemTemplateError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSystemTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2169,12 +2387,14 @@ impl fmt::Display for GetSystemTemplateError {
             GetSystemTemplateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetSystemTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetSystemTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetSystemTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSystemTemplateError {}
 /// Errors returned by GetSystemTemplateRevisions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSystemTemplateRevisionsError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2184,6 +2404,8 @@ pub enum GetSystemTemplateRevisionsError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSystemTemplateRevisionsError {
@@ -2193,27 +2415,33 @@ impl GetSystemTemplateRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSystemTemplateRevisionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSystemTemplateRevisionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSystemTemplateRevisionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSystemTemplateRevisionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetSystemTemplateRevisionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2227,12 +2455,14 @@ impl fmt::Display for GetSystemTemplateRevisionsError {
             GetSystemTemplateRevisionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetSystemTemplateRevisionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetSystemTemplateRevisionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetSystemTemplateRevisionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSystemTemplateRevisionsError {}
 /// Errors returned by GetUploadStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUploadStatusError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2242,6 +2472,8 @@ pub enum GetUploadStatusError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUploadStatusError {
@@ -2249,19 +2481,28 @@ impl GetUploadStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetUploadStatusError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetUploadStatusError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetUploadStatusError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetUploadStatusError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUploadStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetUploadStatusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetUploadStatusError::Throttling(err.msg))
+                    return RusotoError::service(GetUploadStatusError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUploadStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2275,12 +2516,14 @@ impl fmt::Display for GetUploadStatusError {
             GetUploadStatusError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetUploadStatusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetUploadStatusError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetUploadStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUploadStatusError {}
 /// Errors returned by ListFlowExecutionMessages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFlowExecutionMessagesError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2290,6 +2533,8 @@ pub enum ListFlowExecutionMessagesError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFlowExecutionMessagesError {
@@ -2297,27 +2542,33 @@ impl ListFlowExecutionMessagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFlowExecutionMessagesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFlowExecutionMessagesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFlowExecutionMessagesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFlowExecutionMessagesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListFlowExecutionMessagesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2331,12 +2582,14 @@ impl fmt::Display for ListFlowExecutionMessagesError {
             ListFlowExecutionMessagesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListFlowExecutionMessagesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListFlowExecutionMessagesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListFlowExecutionMessagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFlowExecutionMessagesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2346,6 +2599,8 @@ pub enum ListTagsForResourceError {
     ResourceAlreadyExists(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2353,21 +2608,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2381,12 +2646,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by SearchEntities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchEntitiesError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2394,6 +2661,8 @@ pub enum SearchEntitiesError {
     InvalidRequest(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchEntitiesError {
@@ -2401,16 +2670,19 @@ impl SearchEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchEntitiesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        SearchEntitiesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(SearchEntitiesError::InvalidRequest(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchEntitiesError::Throttling(err.msg))
+                    return RusotoError::service(SearchEntitiesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchEntitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2423,12 +2695,14 @@ impl fmt::Display for SearchEntitiesError {
             SearchEntitiesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             SearchEntitiesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             SearchEntitiesError::Throttling(ref cause) => write!(f, "{}", cause),
+            SearchEntitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchEntitiesError {}
 /// Errors returned by SearchFlowExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchFlowExecutionsError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2438,6 +2712,8 @@ pub enum SearchFlowExecutionsError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchFlowExecutionsError {
@@ -2445,23 +2721,31 @@ impl SearchFlowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchFlowExecutionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        SearchFlowExecutionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchFlowExecutionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        SearchFlowExecutionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchFlowExecutionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2475,12 +2759,14 @@ impl fmt::Display for SearchFlowExecutionsError {
             SearchFlowExecutionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             SearchFlowExecutionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SearchFlowExecutionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            SearchFlowExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchFlowExecutionsError {}
 /// Errors returned by SearchFlowTemplates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchFlowTemplatesError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2488,6 +2774,8 @@ pub enum SearchFlowTemplatesError {
     InvalidRequest(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchFlowTemplatesError {
@@ -2495,16 +2783,25 @@ impl SearchFlowTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchFlowTemplatesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        SearchFlowTemplatesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchFlowTemplatesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        SearchFlowTemplatesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFlowTemplatesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        SearchFlowTemplatesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchFlowTemplatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2517,12 +2814,14 @@ impl fmt::Display for SearchFlowTemplatesError {
             SearchFlowTemplatesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             SearchFlowTemplatesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             SearchFlowTemplatesError::Throttling(ref cause) => write!(f, "{}", cause),
+            SearchFlowTemplatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchFlowTemplatesError {}
 /// Errors returned by SearchSystemInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchSystemInstancesError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2530,6 +2829,8 @@ pub enum SearchSystemInstancesError {
     InvalidRequest(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchSystemInstancesError {
@@ -2537,20 +2838,25 @@ impl SearchSystemInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchSystemInstancesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchSystemInstancesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchSystemInstancesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchSystemInstancesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchSystemInstancesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        SearchSystemInstancesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchSystemInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2563,12 +2869,14 @@ impl fmt::Display for SearchSystemInstancesError {
             SearchSystemInstancesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             SearchSystemInstancesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             SearchSystemInstancesError::Throttling(ref cause) => write!(f, "{}", cause),
+            SearchSystemInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchSystemInstancesError {}
 /// Errors returned by SearchSystemTemplates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchSystemTemplatesError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2576,6 +2884,8 @@ pub enum SearchSystemTemplatesError {
     InvalidRequest(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchSystemTemplatesError {
@@ -2583,20 +2893,25 @@ impl SearchSystemTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchSystemTemplatesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchSystemTemplatesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchSystemTemplatesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchSystemTemplatesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchSystemTemplatesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        SearchSystemTemplatesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchSystemTemplatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2609,12 +2924,14 @@ impl fmt::Display for SearchSystemTemplatesError {
             SearchSystemTemplatesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             SearchSystemTemplatesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             SearchSystemTemplatesError::Throttling(ref cause) => write!(f, "{}", cause),
+            SearchSystemTemplatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchSystemTemplatesError {}
 /// Errors returned by SearchThings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchThingsError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2624,6 +2941,8 @@ pub enum SearchThingsError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchThingsError {
@@ -2631,19 +2950,19 @@ impl SearchThingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchThingsError::InternalFailure(err.msg))
+                    return RusotoError::service(SearchThingsError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchThingsError::InvalidRequest(err.msg))
+                    return RusotoError::service(SearchThingsError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchThingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(SearchThingsError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchThingsError::Throttling(err.msg))
+                    return RusotoError::service(SearchThingsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchThingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2657,12 +2976,14 @@ impl fmt::Display for SearchThingsError {
             SearchThingsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             SearchThingsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SearchThingsError::Throttling(ref cause) => write!(f, "{}", cause),
+            SearchThingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchThingsError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2672,6 +2993,8 @@ pub enum TagResourceError {
     ResourceAlreadyExists(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2679,19 +3002,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(TagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(TagResourceError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(TagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2705,12 +3031,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             TagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UndeploySystemInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UndeploySystemInstanceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2722,6 +3050,8 @@ pub enum UndeploySystemInstanceError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UndeploySystemInstanceError {
@@ -2729,30 +3059,37 @@ impl UndeploySystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UndeploySystemInstanceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UndeploySystemInstanceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UndeploySystemInstanceError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UndeploySystemInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UndeploySystemInstanceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UndeploySystemInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2767,12 +3104,14 @@ impl fmt::Display for UndeploySystemInstanceError {
             UndeploySystemInstanceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UndeploySystemInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UndeploySystemInstanceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UndeploySystemInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UndeploySystemInstanceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2782,6 +3121,8 @@ pub enum UntagResourceError {
     ResourceAlreadyExists(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2789,19 +3130,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(UntagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2815,12 +3159,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateFlowTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFlowTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2830,6 +3176,8 @@ pub enum UpdateFlowTemplateError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFlowTemplateError {
@@ -2837,19 +3185,28 @@ impl UpdateFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(UpdateFlowTemplateError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFlowTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2863,12 +3220,14 @@ impl fmt::Display for UpdateFlowTemplateError {
             UpdateFlowTemplateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateFlowTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateFlowTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateFlowTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFlowTemplateError {}
 /// Errors returned by UpdateSystemTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSystemTemplateError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2878,6 +3237,8 @@ pub enum UpdateSystemTemplateError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSystemTemplateError {
@@ -2885,23 +3246,31 @@ impl UpdateSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSystemTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateSystemTemplateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSystemTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateSystemTemplateError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSystemTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2915,12 +3284,14 @@ impl fmt::Display for UpdateSystemTemplateError {
             UpdateSystemTemplateError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateSystemTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateSystemTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateSystemTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateSystemTemplateError {}
 /// Errors returned by UploadEntityDefinitions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UploadEntityDefinitionsError {
     /// <p><p/></p>
     InternalFailure(String),
@@ -2928,6 +3299,8 @@ pub enum UploadEntityDefinitionsError {
     InvalidRequest(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UploadEntityDefinitionsError {
@@ -2935,20 +3308,25 @@ impl UploadEntityDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UploadEntityDefinitionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UploadEntityDefinitionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UploadEntityDefinitionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UploadEntityDefinitionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UploadEntityDefinitionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UploadEntityDefinitionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UploadEntityDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2961,6 +3339,7 @@ impl fmt::Display for UploadEntityDefinitionsError {
             UploadEntityDefinitionsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UploadEntityDefinitionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UploadEntityDefinitionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            UploadEntityDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3187,6 +3566,7 @@ impl IotThingsGraphClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> IotThingsGraphClient {
         IotThingsGraphClient {
             client: Client::shared(),
@@ -3212,16 +3592,73 @@ impl IotThingsGraphClient {
     pub fn new_with_client(client: Client, region: region::Region) -> IotThingsGraphClient {
         IotThingsGraphClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<IotThingsGraphClient> {
+        rusoto_core::ClientBuilder::new(IotThingsGraphClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> IotThingsGraphClient {
+        IotThingsGraphClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl IotThingsGraph for IotThingsGraphClient {
-    /// <p>Associates a device with a concrete thing that is in the user's registry.</p> <p>A thing can be associated with only one device at a time. If you associate a thing with a new device id, its previous association will be removed.</p>
-    async fn associate_entity_to_thing(
+impl IotThingsGraphClient {
+    /// Calls `associate_entity_to_thing`, also returning the metadata of the response.
+    pub async fn associate_entity_to_thing_with_metadata(
         &self,
         input: AssociateEntityToThingRequest,
-    ) -> Result<AssociateEntityToThingResponse, RusotoError<AssociateEntityToThingError>> {
+    ) -> Result<
+        (AssociateEntityToThingResponse, ResponseMetadata),
+        RusotoError<AssociateEntityToThingError>,
+    > {
+        let request = self.build_associate_entity_to_thing_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateEntityToThingResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateEntityToThingError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_entity_to_thing` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_entity_to_thing(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateEntityToThingRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_entity_to_thing_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_entity_to_thing_request(
+        &self,
+        input: AssociateEntityToThingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("AssociateEntityToThing");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3231,28 +3668,56 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_flow_template`, also returning the metadata of the response.
+    pub async fn create_flow_template_with_metadata(
+        &self,
+        input: CreateFlowTemplateRequest,
+    ) -> Result<(CreateFlowTemplateResponse, ResponseMetadata), RusotoError<CreateFlowTemplateError>>
+    {
+        let request = self.build_create_flow_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateEntityToThingResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateFlowTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateEntityToThingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateFlowTemplateError::from_response(response))
         }
     }
 
-    /// <p>Creates a workflow template. Workflows can be created only in the user's namespace. (The public namespace contains only entities.) The workflow can contain only entities in the specified namespace. The workflow is validated against the entities in the latest version of the user's namespace unless another namespace version is specified in the request.</p>
-    async fn create_flow_template(
+    /// Presigns the request `create_flow_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_flow_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFlowTemplateRequest,
-    ) -> Result<CreateFlowTemplateResponse, RusotoError<CreateFlowTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_flow_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_flow_template_request(
+        &self,
+        input: CreateFlowTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("CreateFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3262,28 +3727,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_system_instance`, also returning the metadata of the response.
+    pub async fn create_system_instance_with_metadata(
+        &self,
+        input: CreateSystemInstanceRequest,
+    ) -> Result<
+        (CreateSystemInstanceResponse, ResponseMetadata),
+        RusotoError<CreateSystemInstanceError>,
+    > {
+        let request = self.build_create_system_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateFlowTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSystemInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFlowTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSystemInstanceError::from_response(response))
         }
     }
 
-    /// <p>Creates a system instance. </p> <p>This action validates the system instance, prepares the deployment-related resources. For Greengrass deployments, it updates the Greengrass group that is specified by the <code>greengrassGroupName</code> parameter. It also adds a file to the S3 bucket specified by the <code>s3BucketName</code> parameter. You need to call <code>DeploySystemInstance</code> after running this action.</p> <p>For Greengrass deployments, since this action modifies and adds resources to a Greengrass group and an S3 bucket on the caller's behalf, the calling identity must have write permissions to both the specified Greengrass group and S3 bucket. Otherwise, the call will fail with an authorization error.</p> <p>For cloud deployments, this action requires a <code>flowActionsRoleArn</code> value. This is an IAM role that has permissions to access AWS services, such as AWS Lambda and AWS IoT, that the flow uses when it executes.</p> <p>If the definition document doesn't specify a version of the user's namespace, the latest version will be used by default.</p>
-    async fn create_system_instance(
+    /// Presigns the request `create_system_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_system_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSystemInstanceRequest,
-    ) -> Result<CreateSystemInstanceResponse, RusotoError<CreateSystemInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_system_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_system_instance_request(
+        &self,
+        input: CreateSystemInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("CreateSystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3293,28 +3788,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_system_template`, also returning the metadata of the response.
+    pub async fn create_system_template_with_metadata(
+        &self,
+        input: CreateSystemTemplateRequest,
+    ) -> Result<
+        (CreateSystemTemplateResponse, ResponseMetadata),
+        RusotoError<CreateSystemTemplateError>,
+    > {
+        let request = self.build_create_system_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSystemInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSystemTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSystemInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSystemTemplateError::from_response(response))
         }
     }
 
-    /// <p>Creates a system. The system is validated against the entities in the latest version of the user's namespace unless another namespace version is specified in the request.</p>
-    async fn create_system_template(
+    /// Presigns the request `create_system_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_system_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSystemTemplateRequest,
-    ) -> Result<CreateSystemTemplateResponse, RusotoError<CreateSystemTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_system_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_system_template_request(
+        &self,
+        input: CreateSystemTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("CreateSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3324,28 +3849,56 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_flow_template`, also returning the metadata of the response.
+    pub async fn delete_flow_template_with_metadata(
+        &self,
+        input: DeleteFlowTemplateRequest,
+    ) -> Result<(DeleteFlowTemplateResponse, ResponseMetadata), RusotoError<DeleteFlowTemplateError>>
+    {
+        let request = self.build_delete_flow_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSystemTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteFlowTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSystemTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteFlowTemplateError::from_response(response))
         }
     }
 
-    /// <p>Deletes a workflow. Any new system or deployment that contains this workflow will fail to update or deploy. Existing deployments that contain the workflow will continue to run (since they use a snapshot of the workflow taken at the time of deployment).</p>
-    async fn delete_flow_template(
+    /// Presigns the request `delete_flow_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_flow_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFlowTemplateRequest,
-    ) -> Result<DeleteFlowTemplateResponse, RusotoError<DeleteFlowTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_flow_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_flow_template_request(
+        &self,
+        input: DeleteFlowTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeleteFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3355,27 +3908,51 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_namespace`, also returning the metadata of the response.
+    pub async fn delete_namespace_with_metadata(
+        &self,
+    ) -> Result<(DeleteNamespaceResponse, ResponseMetadata), RusotoError<DeleteNamespaceError>>
+    {
+        let request = self.build_delete_namespace_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteFlowTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteNamespaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFlowTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteNamespaceError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified namespace. This action deletes all of the entities in the namespace. Delete the systems and flows that use entities in the namespace before performing this action.</p>
-    async fn delete_namespace(
+    /// Presigns the request `delete_namespace` would send with `presigner` instead of sending it.
+    pub async fn presign_delete_namespace(
         &self,
-    ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_namespace_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_namespace_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeleteNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3384,27 +3961,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `delete_system_instance`, also returning the metadata of the response.
+    pub async fn delete_system_instance_with_metadata(
+        &self,
+        input: DeleteSystemInstanceRequest,
+    ) -> Result<
+        (DeleteSystemInstanceResponse, ResponseMetadata),
+        RusotoError<DeleteSystemInstanceError>,
+    > {
+        let request = self.build_delete_system_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteNamespaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSystemInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNamespaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSystemInstanceError::from_response(response))
         }
     }
 
-    /// <p>Deletes a system instance. Only system instances that have never been deployed, or that have been undeployed can be deleted.</p> <p>Users can create a new system instance that has the same ID as a deleted system instance.</p>
-    async fn delete_system_instance(
+    /// Presigns the request `delete_system_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_system_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSystemInstanceRequest,
-    ) -> Result<DeleteSystemInstanceResponse, RusotoError<DeleteSystemInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_system_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_system_instance_request(
+        &self,
+        input: DeleteSystemInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeleteSystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3414,28 +4022,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_system_template`, also returning the metadata of the response.
+    pub async fn delete_system_template_with_metadata(
+        &self,
+        input: DeleteSystemTemplateRequest,
+    ) -> Result<
+        (DeleteSystemTemplateResponse, ResponseMetadata),
+        RusotoError<DeleteSystemTemplateError>,
+    > {
+        let request = self.build_delete_system_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSystemInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSystemTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSystemInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSystemTemplateError::from_response(response))
         }
     }
 
-    /// <p>Deletes a system. New deployments can't contain the system after its deletion. Existing deployments that contain the system will continue to work because they use a snapshot of the system that is taken when it is deployed.</p>
-    async fn delete_system_template(
+    /// Presigns the request `delete_system_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_system_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSystemTemplateRequest,
-    ) -> Result<DeleteSystemTemplateResponse, RusotoError<DeleteSystemTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_system_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_system_template_request(
+        &self,
+        input: DeleteSystemTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeleteSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3445,28 +4083,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deploy_system_instance`, also returning the metadata of the response.
+    pub async fn deploy_system_instance_with_metadata(
+        &self,
+        input: DeploySystemInstanceRequest,
+    ) -> Result<
+        (DeploySystemInstanceResponse, ResponseMetadata),
+        RusotoError<DeploySystemInstanceError>,
+    > {
+        let request = self.build_deploy_system_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSystemTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeploySystemInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSystemTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeploySystemInstanceError::from_response(response))
         }
     }
 
-    /// <p> <b>Greengrass and Cloud Deployments</b> </p> <p>Deploys the system instance to the target specified in <code>CreateSystemInstance</code>. </p> <p> <b>Greengrass Deployments</b> </p> <p>If the system or any workflows and entities have been updated before this action is called, then the deployment will create a new Amazon Simple Storage Service resource file and then deploy it.</p> <p>Since this action creates a Greengrass deployment on the caller's behalf, the calling identity must have write permissions to the specified Greengrass group. Otherwise, the call will fail with an authorization error.</p> <p>For information about the artifacts that get added to your Greengrass core device when you use this API, see <a href="https://docs.aws.amazon.com/thingsgraph/latest/ug/iot-tg-greengrass.html">AWS IoT Things Graph and AWS IoT Greengrass</a>.</p>
-    async fn deploy_system_instance(
+    /// Presigns the request `deploy_system_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deploy_system_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeploySystemInstanceRequest,
-    ) -> Result<DeploySystemInstanceResponse, RusotoError<DeploySystemInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deploy_system_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deploy_system_instance_request(
+        &self,
+        input: DeploySystemInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeploySystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3476,28 +4144,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deprecate_flow_template`, also returning the metadata of the response.
+    pub async fn deprecate_flow_template_with_metadata(
+        &self,
+        input: DeprecateFlowTemplateRequest,
+    ) -> Result<
+        (DeprecateFlowTemplateResponse, ResponseMetadata),
+        RusotoError<DeprecateFlowTemplateError>,
+    > {
+        let request = self.build_deprecate_flow_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeploySystemInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeprecateFlowTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeploySystemInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeprecateFlowTemplateError::from_response(response))
         }
     }
 
-    /// <p>Deprecates the specified workflow. This action marks the workflow for deletion. Deprecated flows can't be deployed, but existing deployments will continue to run.</p>
-    async fn deprecate_flow_template(
+    /// Presigns the request `deprecate_flow_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deprecate_flow_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeprecateFlowTemplateRequest,
-    ) -> Result<DeprecateFlowTemplateResponse, RusotoError<DeprecateFlowTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deprecate_flow_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deprecate_flow_template_request(
+        &self,
+        input: DeprecateFlowTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeprecateFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3507,28 +4205,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deprecate_system_template`, also returning the metadata of the response.
+    pub async fn deprecate_system_template_with_metadata(
+        &self,
+        input: DeprecateSystemTemplateRequest,
+    ) -> Result<
+        (DeprecateSystemTemplateResponse, ResponseMetadata),
+        RusotoError<DeprecateSystemTemplateError>,
+    > {
+        let request = self.build_deprecate_system_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeprecateFlowTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeprecateSystemTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeprecateFlowTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeprecateSystemTemplateError::from_response(response))
         }
     }
 
-    /// <p>Deprecates the specified system.</p>
-    async fn deprecate_system_template(
+    /// Presigns the request `deprecate_system_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deprecate_system_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeprecateSystemTemplateRequest,
-    ) -> Result<DeprecateSystemTemplateResponse, RusotoError<DeprecateSystemTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deprecate_system_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deprecate_system_template_request(
+        &self,
+        input: DeprecateSystemTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DeprecateSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3538,28 +4266,53 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_namespace`, also returning the metadata of the response.
+    pub async fn describe_namespace_with_metadata(
+        &self,
+        input: DescribeNamespaceRequest,
+    ) -> Result<(DescribeNamespaceResponse, ResponseMetadata), RusotoError<DescribeNamespaceError>>
+    {
+        let request = self.build_describe_namespace_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeprecateSystemTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeNamespaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeprecateSystemTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeNamespaceError::from_response(response))
         }
     }
 
-    /// <p>Gets the latest version of the user's namespace and the public version that it is tracking.</p>
-    async fn describe_namespace(
+    /// Presigns the request `describe_namespace` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_namespace(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeNamespaceRequest,
-    ) -> Result<DescribeNamespaceResponse, RusotoError<DescribeNamespaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_namespace_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_namespace_request(&self, input: DescribeNamespaceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DescribeNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3569,29 +4322,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `dissociate_entity_from_thing`, also returning the metadata of the response.
+    pub async fn dissociate_entity_from_thing_with_metadata(
+        &self,
+        input: DissociateEntityFromThingRequest,
+    ) -> Result<
+        (DissociateEntityFromThingResponse, ResponseMetadata),
+        RusotoError<DissociateEntityFromThingError>,
+    > {
+        let request = self.build_dissociate_entity_from_thing_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeNamespaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DissociateEntityFromThingResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeNamespaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DissociateEntityFromThingError::from_response(response))
         }
     }
 
-    /// <p>Dissociates a device entity from a concrete thing. The action takes only the type of the entity that you need to dissociate because only one entity of a particular type can be associated with a thing.</p>
-    async fn dissociate_entity_from_thing(
+    /// Presigns the request `dissociate_entity_from_thing` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_dissociate_entity_from_thing(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DissociateEntityFromThingRequest,
-    ) -> Result<DissociateEntityFromThingResponse, RusotoError<DissociateEntityFromThingError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_dissociate_entity_from_thing_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_dissociate_entity_from_thing_request(
+        &self,
+        input: DissociateEntityFromThingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("DissociateEntityFromThing");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3601,55 +4383,105 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_entities`, also returning the metadata of the response.
+    pub async fn get_entities_with_metadata(
+        &self,
+        input: GetEntitiesRequest,
+    ) -> Result<(GetEntitiesResponse, ResponseMetadata), RusotoError<GetEntitiesError>> {
+        let request = self.build_get_entities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DissociateEntityFromThingResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetEntitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DissociateEntityFromThingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetEntitiesError::from_response(response))
         }
     }
 
-    /// <p>Gets definitions of the specified entities. Uses the latest version of the user's namespace by default. This API returns the following TDM entities.</p> <ul> <li> <p>Properties</p> </li> <li> <p>States</p> </li> <li> <p>Events</p> </li> <li> <p>Actions</p> </li> <li> <p>Capabilities</p> </li> <li> <p>Mappings</p> </li> <li> <p>Devices</p> </li> <li> <p>Device Models</p> </li> <li> <p>Services</p> </li> </ul> <p>This action doesn't return definitions for systems, flows, and deployments.</p>
-    async fn get_entities(
+    /// Presigns the request `get_entities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_entities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEntitiesRequest,
-    ) -> Result<GetEntitiesResponse, RusotoError<GetEntitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_entities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_entities_request(&self, input: GetEntitiesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.GetEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_flow_template`, also returning the metadata of the response.
+    pub async fn get_flow_template_with_metadata(
+        &self,
+        input: GetFlowTemplateRequest,
+    ) -> Result<(GetFlowTemplateResponse, ResponseMetadata), RusotoError<GetFlowTemplateError>>
+    {
+        let request = self.build_get_flow_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetEntitiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetFlowTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEntitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetFlowTemplateError::from_response(response))
         }
     }
 
-    /// <p>Gets the latest version of the <code>DefinitionDocument</code> and <code>FlowTemplateSummary</code> for the specified workflow.</p>
-    async fn get_flow_template(
+    /// Presigns the request `get_flow_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_flow_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFlowTemplateRequest,
-    ) -> Result<GetFlowTemplateResponse, RusotoError<GetFlowTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_flow_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_flow_template_request(&self, input: GetFlowTemplateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3659,27 +4491,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_flow_template_revisions`, also returning the metadata of the response.
+    pub async fn get_flow_template_revisions_with_metadata(
+        &self,
+        input: GetFlowTemplateRevisionsRequest,
+    ) -> Result<
+        (GetFlowTemplateRevisionsResponse, ResponseMetadata),
+        RusotoError<GetFlowTemplateRevisionsError>,
+    > {
+        let request = self.build_get_flow_template_revisions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetFlowTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetFlowTemplateRevisionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFlowTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetFlowTemplateRevisionsError::from_response(response))
         }
     }
 
-    /// <p>Gets revisions of the specified workflow. Only the last 100 revisions are stored. If the workflow has been deprecated, this action will return revisions that occurred before the deprecation. This action won't work for workflows that have been deleted.</p>
-    async fn get_flow_template_revisions(
+    /// Presigns the request `get_flow_template_revisions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_flow_template_revisions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFlowTemplateRevisionsRequest,
-    ) -> Result<GetFlowTemplateRevisionsResponse, RusotoError<GetFlowTemplateRevisionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_flow_template_revisions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_flow_template_revisions_request(
+        &self,
+        input: GetFlowTemplateRevisionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetFlowTemplateRevisions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3689,28 +4552,53 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_namespace_deletion_status`, also returning the metadata of the response.
+    pub async fn get_namespace_deletion_status_with_metadata(
+        &self,
+    ) -> Result<
+        (GetNamespaceDeletionStatusResponse, ResponseMetadata),
+        RusotoError<GetNamespaceDeletionStatusError>,
+    > {
+        let request = self.build_get_namespace_deletion_status_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFlowTemplateRevisionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetNamespaceDeletionStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFlowTemplateRevisionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetNamespaceDeletionStatusError::from_response(response))
         }
     }
 
-    /// <p>Gets the status of a namespace deletion task.</p>
-    async fn get_namespace_deletion_status(
+    /// Presigns the request `get_namespace_deletion_status` would send with `presigner` instead of sending it.
+    pub async fn presign_get_namespace_deletion_status(
         &self,
-    ) -> Result<GetNamespaceDeletionStatusResponse, RusotoError<GetNamespaceDeletionStatusError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_namespace_deletion_status_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_namespace_deletion_status_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetNamespaceDeletionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3719,28 +4607,53 @@ impl IotThingsGraph for IotThingsGraphClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `get_system_instance`, also returning the metadata of the response.
+    pub async fn get_system_instance_with_metadata(
+        &self,
+        input: GetSystemInstanceRequest,
+    ) -> Result<(GetSystemInstanceResponse, ResponseMetadata), RusotoError<GetSystemInstanceError>>
+    {
+        let request = self.build_get_system_instance_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetNamespaceDeletionStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSystemInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetNamespaceDeletionStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSystemInstanceError::from_response(response))
         }
     }
 
-    /// <p>Gets a system instance.</p>
-    async fn get_system_instance(
+    /// Presigns the request `get_system_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_system_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSystemInstanceRequest,
-    ) -> Result<GetSystemInstanceResponse, RusotoError<GetSystemInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_system_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_system_instance_request(&self, input: GetSystemInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetSystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3750,28 +4663,53 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_system_template`, also returning the metadata of the response.
+    pub async fn get_system_template_with_metadata(
+        &self,
+        input: GetSystemTemplateRequest,
+    ) -> Result<(GetSystemTemplateResponse, ResponseMetadata), RusotoError<GetSystemTemplateError>>
+    {
+        let request = self.build_get_system_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSystemInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSystemTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSystemInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSystemTemplateError::from_response(response))
         }
     }
 
-    /// <p>Gets a system.</p>
-    async fn get_system_template(
+    /// Presigns the request `get_system_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_system_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSystemTemplateRequest,
-    ) -> Result<GetSystemTemplateResponse, RusotoError<GetSystemTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_system_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_system_template_request(&self, input: GetSystemTemplateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3781,29 +4719,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_system_template_revisions`, also returning the metadata of the response.
+    pub async fn get_system_template_revisions_with_metadata(
+        &self,
+        input: GetSystemTemplateRevisionsRequest,
+    ) -> Result<
+        (GetSystemTemplateRevisionsResponse, ResponseMetadata),
+        RusotoError<GetSystemTemplateRevisionsError>,
+    > {
+        let request = self.build_get_system_template_revisions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSystemTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSystemTemplateRevisionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSystemTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSystemTemplateRevisionsError::from_response(response))
         }
     }
 
-    /// <p>Gets revisions made to the specified system template. Only the previous 100 revisions are stored. If the system has been deprecated, this action will return the revisions that occurred before its deprecation. This action won't work with systems that have been deleted.</p>
-    async fn get_system_template_revisions(
+    /// Presigns the request `get_system_template_revisions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_system_template_revisions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSystemTemplateRevisionsRequest,
-    ) -> Result<GetSystemTemplateRevisionsResponse, RusotoError<GetSystemTemplateRevisionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_system_template_revisions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_system_template_revisions_request(
+        &self,
+        input: GetSystemTemplateRevisionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetSystemTemplateRevisions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3813,28 +4780,53 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_upload_status`, also returning the metadata of the response.
+    pub async fn get_upload_status_with_metadata(
+        &self,
+        input: GetUploadStatusRequest,
+    ) -> Result<(GetUploadStatusResponse, ResponseMetadata), RusotoError<GetUploadStatusError>>
+    {
+        let request = self.build_get_upload_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSystemTemplateRevisionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetUploadStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSystemTemplateRevisionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetUploadStatusError::from_response(response))
         }
     }
 
-    /// <p>Gets the status of the specified upload.</p>
-    async fn get_upload_status(
+    /// Presigns the request `get_upload_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_upload_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUploadStatusRequest,
-    ) -> Result<GetUploadStatusResponse, RusotoError<GetUploadStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_upload_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_upload_status_request(&self, input: GetUploadStatusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("GetUploadStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3844,28 +4836,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_flow_execution_messages`, also returning the metadata of the response.
+    pub async fn list_flow_execution_messages_with_metadata(
+        &self,
+        input: ListFlowExecutionMessagesRequest,
+    ) -> Result<
+        (ListFlowExecutionMessagesResponse, ResponseMetadata),
+        RusotoError<ListFlowExecutionMessagesError>,
+    > {
+        let request = self.build_list_flow_execution_messages_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetUploadStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListFlowExecutionMessagesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUploadStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListFlowExecutionMessagesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of objects that contain information about events in a flow execution.</p>
-    async fn list_flow_execution_messages(
+    /// Presigns the request `list_flow_execution_messages` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_flow_execution_messages(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFlowExecutionMessagesRequest,
-    ) -> Result<ListFlowExecutionMessagesResponse, RusotoError<ListFlowExecutionMessagesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_flow_execution_messages_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_flow_execution_messages_request(
+        &self,
+        input: ListFlowExecutionMessagesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("ListFlowExecutionMessages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3875,28 +4897,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFlowExecutionMessagesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFlowExecutionMessagesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists all tags on an AWS IoT Things Graph resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3906,28 +4958,52 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_entities`, also returning the metadata of the response.
+    pub async fn search_entities_with_metadata(
+        &self,
+        input: SearchEntitiesRequest,
+    ) -> Result<(SearchEntitiesResponse, ResponseMetadata), RusotoError<SearchEntitiesError>> {
+        let request = self.build_search_entities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchEntitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchEntitiesError::from_response(response))
         }
     }
 
-    /// <p>Searches for entities of the specified type. You can search for entities in your namespace and the public namespace that you're tracking.</p>
-    async fn search_entities(
+    /// Presigns the request `search_entities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_entities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchEntitiesRequest,
-    ) -> Result<SearchEntitiesResponse, RusotoError<SearchEntitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_entities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_entities_request(&self, input: SearchEntitiesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3937,27 +5013,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_flow_executions`, also returning the metadata of the response.
+    pub async fn search_flow_executions_with_metadata(
+        &self,
+        input: SearchFlowExecutionsRequest,
+    ) -> Result<
+        (SearchFlowExecutionsResponse, ResponseMetadata),
+        RusotoError<SearchFlowExecutionsError>,
+    > {
+        let request = self.build_search_flow_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchEntitiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchFlowExecutionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchEntitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchFlowExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Searches for AWS IoT Things Graph workflow execution instances.</p>
-    async fn search_flow_executions(
+    /// Presigns the request `search_flow_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_flow_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchFlowExecutionsRequest,
-    ) -> Result<SearchFlowExecutionsResponse, RusotoError<SearchFlowExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_flow_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_flow_executions_request(
+        &self,
+        input: SearchFlowExecutionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchFlowExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3967,28 +5074,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_flow_templates`, also returning the metadata of the response.
+    pub async fn search_flow_templates_with_metadata(
+        &self,
+        input: SearchFlowTemplatesRequest,
+    ) -> Result<
+        (SearchFlowTemplatesResponse, ResponseMetadata),
+        RusotoError<SearchFlowTemplatesError>,
+    > {
+        let request = self.build_search_flow_templates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchFlowExecutionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchFlowTemplatesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchFlowExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchFlowTemplatesError::from_response(response))
         }
     }
 
-    /// <p>Searches for summary information about workflows.</p>
-    async fn search_flow_templates(
+    /// Presigns the request `search_flow_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_flow_templates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchFlowTemplatesRequest,
-    ) -> Result<SearchFlowTemplatesResponse, RusotoError<SearchFlowTemplatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_flow_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_flow_templates_request(
+        &self,
+        input: SearchFlowTemplatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchFlowTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3998,28 +5135,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_system_instances`, also returning the metadata of the response.
+    pub async fn search_system_instances_with_metadata(
+        &self,
+        input: SearchSystemInstancesRequest,
+    ) -> Result<
+        (SearchSystemInstancesResponse, ResponseMetadata),
+        RusotoError<SearchSystemInstancesError>,
+    > {
+        let request = self.build_search_system_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchFlowTemplatesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchSystemInstancesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchFlowTemplatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchSystemInstancesError::from_response(response))
         }
     }
 
-    /// <p>Searches for system instances in the user's account.</p>
-    async fn search_system_instances(
+    /// Presigns the request `search_system_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_system_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchSystemInstancesRequest,
-    ) -> Result<SearchSystemInstancesResponse, RusotoError<SearchSystemInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_system_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_system_instances_request(
+        &self,
+        input: SearchSystemInstancesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchSystemInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4029,28 +5196,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_system_templates`, also returning the metadata of the response.
+    pub async fn search_system_templates_with_metadata(
+        &self,
+        input: SearchSystemTemplatesRequest,
+    ) -> Result<
+        (SearchSystemTemplatesResponse, ResponseMetadata),
+        RusotoError<SearchSystemTemplatesError>,
+    > {
+        let request = self.build_search_system_templates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchSystemInstancesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchSystemTemplatesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchSystemInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchSystemTemplatesError::from_response(response))
         }
     }
 
-    /// <p>Searches for summary information about systems in the user's account. You can filter by the ID of a workflow to return only systems that use the specified workflow.</p>
-    async fn search_system_templates(
+    /// Presigns the request `search_system_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_system_templates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchSystemTemplatesRequest,
-    ) -> Result<SearchSystemTemplatesResponse, RusotoError<SearchSystemTemplatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_system_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_system_templates_request(
+        &self,
+        input: SearchSystemTemplatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchSystemTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4060,82 +5257,162 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_things`, also returning the metadata of the response.
+    pub async fn search_things_with_metadata(
+        &self,
+        input: SearchThingsRequest,
+    ) -> Result<(SearchThingsResponse, ResponseMetadata), RusotoError<SearchThingsError>> {
+        let request = self.build_search_things_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchSystemTemplatesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchThingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchSystemTemplatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchThingsError::from_response(response))
         }
     }
 
-    /// <p>Searches for things associated with the specified entity. You can search by both device and device model.</p> <p>For example, if two different devices, camera1 and camera2, implement the camera device model, the user can associate thing1 to camera1 and thing2 to camera2. <code>SearchThings(camera2)</code> will return only thing2, but <code>SearchThings(camera)</code> will return both thing1 and thing2.</p> <p>This action searches for exact matches and doesn't perform partial text matching.</p>
-    async fn search_things(
+    /// Presigns the request `search_things` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_things(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchThingsRequest,
-    ) -> Result<SearchThingsResponse, RusotoError<SearchThingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_things_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_things_request(&self, input: SearchThingsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("SearchThings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.SearchThings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchThingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchThingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Creates a tag for the specified resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `undeploy_system_instance`, also returning the metadata of the response.
+    pub async fn undeploy_system_instance_with_metadata(
+        &self,
+        input: UndeploySystemInstanceRequest,
+    ) -> Result<
+        (UndeploySystemInstanceResponse, ResponseMetadata),
+        RusotoError<UndeploySystemInstanceError>,
+    > {
+        let request = self.build_undeploy_system_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UndeploySystemInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UndeploySystemInstanceError::from_response(response))
         }
     }
 
-    /// <p>Removes a system instance from its target (Cloud or Greengrass).</p>
-    async fn undeploy_system_instance(
+    /// Presigns the request `undeploy_system_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_undeploy_system_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UndeploySystemInstanceRequest,
-    ) -> Result<UndeploySystemInstanceResponse, RusotoError<UndeploySystemInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_undeploy_system_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_undeploy_system_instance_request(
+        &self,
+        input: UndeploySystemInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UndeploySystemInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4145,28 +5422,52 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UndeploySystemInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UndeploySystemInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes a tag from the specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4176,27 +5477,56 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_flow_template`, also returning the metadata of the response.
+    pub async fn update_flow_template_with_metadata(
+        &self,
+        input: UpdateFlowTemplateRequest,
+    ) -> Result<(UpdateFlowTemplateResponse, ResponseMetadata), RusotoError<UpdateFlowTemplateError>>
+    {
+        let request = self.build_update_flow_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateFlowTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateFlowTemplateError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified workflow. All deployed systems and system instances that use the workflow will see the changes in the flow when it is redeployed. If you don't want this behavior, copy the workflow (creating a new workflow with a different ID), and update the copy. The workflow can contain only entities in the specified namespace. </p>
-    async fn update_flow_template(
+    /// Presigns the request `update_flow_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_flow_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFlowTemplateRequest,
-    ) -> Result<UpdateFlowTemplateResponse, RusotoError<UpdateFlowTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_flow_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_flow_template_request(
+        &self,
+        input: UpdateFlowTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UpdateFlowTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4206,28 +5536,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_system_template`, also returning the metadata of the response.
+    pub async fn update_system_template_with_metadata(
+        &self,
+        input: UpdateSystemTemplateRequest,
+    ) -> Result<
+        (UpdateSystemTemplateResponse, ResponseMetadata),
+        RusotoError<UpdateSystemTemplateError>,
+    > {
+        let request = self.build_update_system_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFlowTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSystemTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFlowTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSystemTemplateError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified system. You don't need to run this action after updating a workflow. Any deployment that uses the system will see the changes in the system when it is redeployed.</p>
-    async fn update_system_template(
+    /// Presigns the request `update_system_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_system_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSystemTemplateRequest,
-    ) -> Result<UpdateSystemTemplateResponse, RusotoError<UpdateSystemTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_system_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_system_template_request(
+        &self,
+        input: UpdateSystemTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UpdateSystemTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4237,28 +5597,58 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `upload_entity_definitions`, also returning the metadata of the response.
+    pub async fn upload_entity_definitions_with_metadata(
+        &self,
+        input: UploadEntityDefinitionsRequest,
+    ) -> Result<
+        (UploadEntityDefinitionsResponse, ResponseMetadata),
+        RusotoError<UploadEntityDefinitionsError>,
+    > {
+        let request = self.build_upload_entity_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSystemTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UploadEntityDefinitionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSystemTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UploadEntityDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Asynchronously uploads one or more entity definitions to the user's namespace. The <code>document</code> parameter is required if <code>syncWithPublicNamespace</code> and <code>deleteExistingEntites</code> are false. If the <code>syncWithPublicNamespace</code> parameter is set to <code>true</code>, the user's namespace will synchronize with the latest version of the public namespace. If <code>deprecateExistingEntities</code> is set to true, all entities in the latest version will be deleted before the new <code>DefinitionDocument</code> is uploaded.</p> <p>When a user uploads entity definitions for the first time, the service creates a new namespace for the user. The new namespace tracks the public namespace. Currently users can have only one namespace. The namespace version increments whenever a user uploads entity definitions that are backwards-incompatible and whenever a user sets the <code>syncWithPublicNamespace</code> parameter or the <code>deprecateExistingEntities</code> parameter to <code>true</code>.</p> <p>The IDs for all of the entities should be in URN format. Each entity must be in the user's namespace. Users can't create entities in the public namespace, but entity definitions can refer to entities in the public namespace.</p> <p>Valid entities are <code>Device</code>, <code>DeviceModel</code>, <code>Service</code>, <code>Capability</code>, <code>State</code>, <code>Action</code>, <code>Event</code>, <code>Property</code>, <code>Mapping</code>, <code>Enum</code>. </p>
-    async fn upload_entity_definitions(
+    /// Presigns the request `upload_entity_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upload_entity_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UploadEntityDefinitionsRequest,
-    ) -> Result<UploadEntityDefinitionsResponse, RusotoError<UploadEntityDefinitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upload_entity_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_upload_entity_definitions_request(
+        &self,
+        input: UploadEntityDefinitionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "iotthingsgraph", &self.region, "/");
+        request.set_operation("UploadEntityDefinitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4268,19 +5658,759 @@ impl IotThingsGraph for IotThingsGraphClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UploadEntityDefinitionsResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UploadEntityDefinitionsError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `GetFlowTemplateRevisions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_flow_template_revisions_pages(
+        &self,
+        input: GetFlowTemplateRevisionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetFlowTemplateRevisionsResponse, RusotoError<GetFlowTemplateRevisionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move {
+                client.get_flow_template_revisions(input).await
+            },
+            |input: &GetFlowTemplateRevisionsRequest, output: &GetFlowTemplateRevisionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetFlowTemplateRevisionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `summaries` of every page of `GetFlowTemplateRevisions` results for `input`, as `get_flow_template_revisions_pages` requests them.
+    pub fn get_flow_template_revisions_stream(
+        &self,
+        input: GetFlowTemplateRevisionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<FlowTemplateSummary, RusotoError<GetFlowTemplateRevisionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_flow_template_revisions_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetSystemTemplateRevisions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_system_template_revisions_pages(
+        &self,
+        input: GetSystemTemplateRevisionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetSystemTemplateRevisionsResponse,
+            RusotoError<GetSystemTemplateRevisionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move {
+                client.get_system_template_revisions(input).await
+            },
+            |input: &GetSystemTemplateRevisionsRequest,
+             output: &GetSystemTemplateRevisionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetSystemTemplateRevisionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `summaries` of every page of `GetSystemTemplateRevisions` results for `input`, as `get_system_template_revisions_pages` requests them.
+    pub fn get_system_template_revisions_stream(
+        &self,
+        input: GetSystemTemplateRevisionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SystemTemplateSummary, RusotoError<GetSystemTemplateRevisionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_system_template_revisions_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFlowExecutionMessages` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_flow_execution_messages_pages(
+        &self,
+        input: ListFlowExecutionMessagesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListFlowExecutionMessagesResponse,
+            RusotoError<ListFlowExecutionMessagesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move {
+                client.list_flow_execution_messages(input).await
+            },
+            |input: &ListFlowExecutionMessagesRequest,
+             output: &ListFlowExecutionMessagesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFlowExecutionMessagesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `messages` of every page of `ListFlowExecutionMessages` results for `input`, as `list_flow_execution_messages_pages` requests them.
+    pub fn list_flow_execution_messages_stream(
+        &self,
+        input: ListFlowExecutionMessagesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<FlowExecutionMessage, RusotoError<ListFlowExecutionMessagesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_flow_execution_messages_pages(input), |page| {
+            page.messages.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SearchEntities` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn search_entities_pages(
+        &self,
+        input: SearchEntitiesRequest,
+    ) -> impl futures::Stream<Item = Result<SearchEntitiesResponse, RusotoError<SearchEntitiesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move { client.search_entities(input).await },
+            |input: &SearchEntitiesRequest, output: &SearchEntitiesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchEntitiesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `descriptions` of every page of `SearchEntities` results for `input`, as `search_entities_pages` requests them.
+    pub fn search_entities_stream(
+        &self,
+        input: SearchEntitiesRequest,
+    ) -> impl futures::Stream<Item = Result<EntityDescription, RusotoError<SearchEntitiesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.search_entities_pages(input), |page| {
+            page.descriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SearchFlowExecutions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn search_flow_executions_pages(
+        &self,
+        input: SearchFlowExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SearchFlowExecutionsResponse, RusotoError<SearchFlowExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move {
+                client.search_flow_executions(input).await
+            },
+            |input: &SearchFlowExecutionsRequest, output: &SearchFlowExecutionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchFlowExecutionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `summaries` of every page of `SearchFlowExecutions` results for `input`, as `search_flow_executions_pages` requests them.
+    pub fn search_flow_executions_stream(
+        &self,
+        input: SearchFlowExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<FlowExecutionSummary, RusotoError<SearchFlowExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.search_flow_executions_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SearchFlowTemplates` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn search_flow_templates_pages(
+        &self,
+        input: SearchFlowTemplatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SearchFlowTemplatesResponse, RusotoError<SearchFlowTemplatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move {
+                client.search_flow_templates(input).await
+            },
+            |input: &SearchFlowTemplatesRequest, output: &SearchFlowTemplatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchFlowTemplatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `summaries` of every page of `SearchFlowTemplates` results for `input`, as `search_flow_templates_pages` requests them.
+    pub fn search_flow_templates_stream(
+        &self,
+        input: SearchFlowTemplatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<FlowTemplateSummary, RusotoError<SearchFlowTemplatesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.search_flow_templates_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SearchSystemInstances` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn search_system_instances_pages(
+        &self,
+        input: SearchSystemInstancesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SearchSystemInstancesResponse, RusotoError<SearchSystemInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move {
+                client.search_system_instances(input).await
+            },
+            |input: &SearchSystemInstancesRequest, output: &SearchSystemInstancesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchSystemInstancesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `summaries` of every page of `SearchSystemInstances` results for `input`, as `search_system_instances_pages` requests them.
+    pub fn search_system_instances_stream(
+        &self,
+        input: SearchSystemInstancesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SystemInstanceSummary, RusotoError<SearchSystemInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.search_system_instances_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SearchSystemTemplates` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn search_system_templates_pages(
+        &self,
+        input: SearchSystemTemplatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SearchSystemTemplatesResponse, RusotoError<SearchSystemTemplatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move {
+                client.search_system_templates(input).await
+            },
+            |input: &SearchSystemTemplatesRequest, output: &SearchSystemTemplatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchSystemTemplatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `summaries` of every page of `SearchSystemTemplates` results for `input`, as `search_system_templates_pages` requests them.
+    pub fn search_system_templates_stream(
+        &self,
+        input: SearchSystemTemplatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SystemTemplateSummary, RusotoError<SearchSystemTemplatesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.search_system_templates_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SearchThings` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn search_things_pages(
+        &self,
+        input: SearchThingsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchThingsResponse, RusotoError<SearchThingsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: IotThingsGraphClient, input| async move { client.search_things(input).await },
+            |input: &SearchThingsRequest, output: &SearchThingsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchThingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `things` of every page of `SearchThings` results for `input`, as `search_things_pages` requests them.
+    pub fn search_things_stream(
+        &self,
+        input: SearchThingsRequest,
+    ) -> impl futures::Stream<Item = Result<Thing, RusotoError<SearchThingsError>>> + Send {
+        rusoto_core::pagination::items(self.search_things_pages(input), |page| {
+            page.things.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl IotThingsGraph for IotThingsGraphClient {
+    /// <p>Associates a device with a concrete thing that is in the user's registry.</p> <p>A thing can be associated with only one device at a time. If you associate a thing with a new device id, its previous association will be removed.</p>
+    async fn associate_entity_to_thing(
+        &self,
+        input: AssociateEntityToThingRequest,
+    ) -> Result<AssociateEntityToThingResponse, RusotoError<AssociateEntityToThingError>> {
+        self.associate_entity_to_thing_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a workflow template. Workflows can be created only in the user's namespace. (The public namespace contains only entities.) The workflow can contain only entities in the specified namespace. The workflow is validated against the entities in the latest version of the user's namespace unless another namespace version is specified in the request.</p>
+    async fn create_flow_template(
+        &self,
+        input: CreateFlowTemplateRequest,
+    ) -> Result<CreateFlowTemplateResponse, RusotoError<CreateFlowTemplateError>> {
+        self.create_flow_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a system instance. </p> <p>This action validates the system instance, prepares the deployment-related resources. For Greengrass deployments, it updates the Greengrass group that is specified by the <code>greengrassGroupName</code> parameter. It also adds a file to the S3 bucket specified by the <code>s3BucketName</code> parameter. You need to call <code>DeploySystemInstance</code> after running this action.</p> <p>For Greengrass deployments, since this action modifies and adds resources to a Greengrass group and an S3 bucket on the caller's behalf, the calling identity must have write permissions to both the specified Greengrass group and S3 bucket. Otherwise, the call will fail with an authorization error.</p> <p>For cloud deployments, this action requires a <code>flowActionsRoleArn</code> value. This is an IAM role that has permissions to access AWS services, such as AWS Lambda and AWS IoT, that the flow uses when it executes.</p> <p>If the definition document doesn't specify a version of the user's namespace, the latest version will be used by default.</p>
+    async fn create_system_instance(
+        &self,
+        input: CreateSystemInstanceRequest,
+    ) -> Result<CreateSystemInstanceResponse, RusotoError<CreateSystemInstanceError>> {
+        self.create_system_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a system. The system is validated against the entities in the latest version of the user's namespace unless another namespace version is specified in the request.</p>
+    async fn create_system_template(
+        &self,
+        input: CreateSystemTemplateRequest,
+    ) -> Result<CreateSystemTemplateResponse, RusotoError<CreateSystemTemplateError>> {
+        self.create_system_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a workflow. Any new system or deployment that contains this workflow will fail to update or deploy. Existing deployments that contain the workflow will continue to run (since they use a snapshot of the workflow taken at the time of deployment).</p>
+    async fn delete_flow_template(
+        &self,
+        input: DeleteFlowTemplateRequest,
+    ) -> Result<DeleteFlowTemplateResponse, RusotoError<DeleteFlowTemplateError>> {
+        self.delete_flow_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified namespace. This action deletes all of the entities in the namespace. Delete the systems and flows that use entities in the namespace before performing this action.</p>
+    async fn delete_namespace(
+        &self,
+    ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
+        self.delete_namespace_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a system instance. Only system instances that have never been deployed, or that have been undeployed can be deleted.</p> <p>Users can create a new system instance that has the same ID as a deleted system instance.</p>
+    async fn delete_system_instance(
+        &self,
+        input: DeleteSystemInstanceRequest,
+    ) -> Result<DeleteSystemInstanceResponse, RusotoError<DeleteSystemInstanceError>> {
+        self.delete_system_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a system. New deployments can't contain the system after its deletion. Existing deployments that contain the system will continue to work because they use a snapshot of the system that is taken when it is deployed.</p>
+    async fn delete_system_template(
+        &self,
+        input: DeleteSystemTemplateRequest,
+    ) -> Result<DeleteSystemTemplateResponse, RusotoError<DeleteSystemTemplateError>> {
+        self.delete_system_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> <b>Greengrass and Cloud Deployments</b> </p> <p>Deploys the system instance to the target specified in <code>CreateSystemInstance</code>. </p> <p> <b>Greengrass Deployments</b> </p> <p>If the system or any workflows and entities have been updated before this action is called, then the deployment will create a new Amazon Simple Storage Service resource file and then deploy it.</p> <p>Since this action creates a Greengrass deployment on the caller's behalf, the calling identity must have write permissions to the specified Greengrass group. Otherwise, the call will fail with an authorization error.</p> <p>For information about the artifacts that get added to your Greengrass core device when you use this API, see <a href="https://docs.aws.amazon.com/thingsgraph/latest/ug/iot-tg-greengrass.html">AWS IoT Things Graph and AWS IoT Greengrass</a>.</p>
+    async fn deploy_system_instance(
+        &self,
+        input: DeploySystemInstanceRequest,
+    ) -> Result<DeploySystemInstanceResponse, RusotoError<DeploySystemInstanceError>> {
+        self.deploy_system_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecates the specified workflow. This action marks the workflow for deletion. Deprecated flows can't be deployed, but existing deployments will continue to run.</p>
+    async fn deprecate_flow_template(
+        &self,
+        input: DeprecateFlowTemplateRequest,
+    ) -> Result<DeprecateFlowTemplateResponse, RusotoError<DeprecateFlowTemplateError>> {
+        self.deprecate_flow_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecates the specified system.</p>
+    async fn deprecate_system_template(
+        &self,
+        input: DeprecateSystemTemplateRequest,
+    ) -> Result<DeprecateSystemTemplateResponse, RusotoError<DeprecateSystemTemplateError>> {
+        self.deprecate_system_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the latest version of the user's namespace and the public version that it is tracking.</p>
+    async fn describe_namespace(
+        &self,
+        input: DescribeNamespaceRequest,
+    ) -> Result<DescribeNamespaceResponse, RusotoError<DescribeNamespaceError>> {
+        self.describe_namespace_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Dissociates a device entity from a concrete thing. The action takes only the type of the entity that you need to dissociate because only one entity of a particular type can be associated with a thing.</p>
+    async fn dissociate_entity_from_thing(
+        &self,
+        input: DissociateEntityFromThingRequest,
+    ) -> Result<DissociateEntityFromThingResponse, RusotoError<DissociateEntityFromThingError>>
+    {
+        self.dissociate_entity_from_thing_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets definitions of the specified entities. Uses the latest version of the user's namespace by default. This API returns the following TDM entities.</p> <ul> <li> <p>Properties</p> </li> <li> <p>States</p> </li> <li> <p>Events</p> </li> <li> <p>Actions</p> </li> <li> <p>Capabilities</p> </li> <li> <p>Mappings</p> </li> <li> <p>Devices</p> </li> <li> <p>Device Models</p> </li> <li> <p>Services</p> </li> </ul> <p>This action doesn't return definitions for systems, flows, and deployments.</p>
+    async fn get_entities(
+        &self,
+        input: GetEntitiesRequest,
+    ) -> Result<GetEntitiesResponse, RusotoError<GetEntitiesError>> {
+        self.get_entities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the latest version of the <code>DefinitionDocument</code> and <code>FlowTemplateSummary</code> for the specified workflow.</p>
+    async fn get_flow_template(
+        &self,
+        input: GetFlowTemplateRequest,
+    ) -> Result<GetFlowTemplateResponse, RusotoError<GetFlowTemplateError>> {
+        self.get_flow_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets revisions of the specified workflow. Only the last 100 revisions are stored. If the workflow has been deprecated, this action will return revisions that occurred before the deprecation. This action won't work for workflows that have been deleted.</p>
+    async fn get_flow_template_revisions(
+        &self,
+        input: GetFlowTemplateRevisionsRequest,
+    ) -> Result<GetFlowTemplateRevisionsResponse, RusotoError<GetFlowTemplateRevisionsError>> {
+        self.get_flow_template_revisions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the status of a namespace deletion task.</p>
+    async fn get_namespace_deletion_status(
+        &self,
+    ) -> Result<GetNamespaceDeletionStatusResponse, RusotoError<GetNamespaceDeletionStatusError>>
+    {
+        self.get_namespace_deletion_status_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a system instance.</p>
+    async fn get_system_instance(
+        &self,
+        input: GetSystemInstanceRequest,
+    ) -> Result<GetSystemInstanceResponse, RusotoError<GetSystemInstanceError>> {
+        self.get_system_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a system.</p>
+    async fn get_system_template(
+        &self,
+        input: GetSystemTemplateRequest,
+    ) -> Result<GetSystemTemplateResponse, RusotoError<GetSystemTemplateError>> {
+        self.get_system_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets revisions made to the specified system template. Only the previous 100 revisions are stored. If the system has been deprecated, this action will return the revisions that occurred before its deprecation. This action won't work with systems that have been deleted.</p>
+    async fn get_system_template_revisions(
+        &self,
+        input: GetSystemTemplateRevisionsRequest,
+    ) -> Result<GetSystemTemplateRevisionsResponse, RusotoError<GetSystemTemplateRevisionsError>>
+    {
+        self.get_system_template_revisions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the status of the specified upload.</p>
+    async fn get_upload_status(
+        &self,
+        input: GetUploadStatusRequest,
+    ) -> Result<GetUploadStatusResponse, RusotoError<GetUploadStatusError>> {
+        self.get_upload_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of objects that contain information about events in a flow execution.</p>
+    async fn list_flow_execution_messages(
+        &self,
+        input: ListFlowExecutionMessagesRequest,
+    ) -> Result<ListFlowExecutionMessagesResponse, RusotoError<ListFlowExecutionMessagesError>>
+    {
+        self.list_flow_execution_messages_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags on an AWS IoT Things Graph resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches for entities of the specified type. You can search for entities in your namespace and the public namespace that you're tracking.</p>
+    async fn search_entities(
+        &self,
+        input: SearchEntitiesRequest,
+    ) -> Result<SearchEntitiesResponse, RusotoError<SearchEntitiesError>> {
+        self.search_entities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches for AWS IoT Things Graph workflow execution instances.</p>
+    async fn search_flow_executions(
+        &self,
+        input: SearchFlowExecutionsRequest,
+    ) -> Result<SearchFlowExecutionsResponse, RusotoError<SearchFlowExecutionsError>> {
+        self.search_flow_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches for summary information about workflows.</p>
+    async fn search_flow_templates(
+        &self,
+        input: SearchFlowTemplatesRequest,
+    ) -> Result<SearchFlowTemplatesResponse, RusotoError<SearchFlowTemplatesError>> {
+        self.search_flow_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches for system instances in the user's account.</p>
+    async fn search_system_instances(
+        &self,
+        input: SearchSystemInstancesRequest,
+    ) -> Result<SearchSystemInstancesResponse, RusotoError<SearchSystemInstancesError>> {
+        self.search_system_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches for summary information about systems in the user's account. You can filter by the ID of a workflow to return only systems that use the specified workflow.</p>
+    async fn search_system_templates(
+        &self,
+        input: SearchSystemTemplatesRequest,
+    ) -> Result<SearchSystemTemplatesResponse, RusotoError<SearchSystemTemplatesError>> {
+        self.search_system_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches for things associated with the specified entity. You can search by both device and device model.</p> <p>For example, if two different devices, camera1 and camera2, implement the camera device model, the user can associate thing1 to camera1 and thing2 to camera2. <code>SearchThings(camera2)</code> will return only thing2, but <code>SearchThings(camera)</code> will return both thing1 and thing2.</p> <p>This action searches for exact matches and doesn't perform partial text matching.</p>
+    async fn search_things(
+        &self,
+        input: SearchThingsRequest,
+    ) -> Result<SearchThingsResponse, RusotoError<SearchThingsError>> {
+        self.search_things_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a tag for the specified resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a system instance from its target (Cloud or Greengrass).</p>
+    async fn undeploy_system_instance(
+        &self,
+        input: UndeploySystemInstanceRequest,
+    ) -> Result<UndeploySystemInstanceResponse, RusotoError<UndeploySystemInstanceError>> {
+        self.undeploy_system_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a tag from the specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified workflow. All deployed systems and system instances that use the workflow will see the changes in the flow when it is redeployed. If you don't want this behavior, copy the workflow (creating a new workflow with a different ID), and update the copy. The workflow can contain only entities in the specified namespace. </p>
+    async fn update_flow_template(
+        &self,
+        input: UpdateFlowTemplateRequest,
+    ) -> Result<UpdateFlowTemplateResponse, RusotoError<UpdateFlowTemplateError>> {
+        self.update_flow_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified system. You don't need to run this action after updating a workflow. Any deployment that uses the system will see the changes in the system when it is redeployed.</p>
+    async fn update_system_template(
+        &self,
+        input: UpdateSystemTemplateRequest,
+    ) -> Result<UpdateSystemTemplateResponse, RusotoError<UpdateSystemTemplateError>> {
+        self.update_system_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Asynchronously uploads one or more entity definitions to the user's namespace. The <code>document</code> parameter is required if <code>syncWithPublicNamespace</code> and <code>deleteExistingEntites</code> are false. If the <code>syncWithPublicNamespace</code> parameter is set to <code>true</code>, the user's namespace will synchronize with the latest version of the public namespace. If <code>deprecateExistingEntities</code> is set to true, all entities in the latest version will be deleted before the new <code>DefinitionDocument</code> is uploaded.</p> <p>When a user uploads entity definitions for the first time, the service creates a new namespace for the user. The new namespace tracks the public namespace. Currently users can have only one namespace. The namespace version increments whenever a user uploads entity definitions that are backwards-incompatible and whenever a user sets the <code>syncWithPublicNamespace</code> parameter or the <code>deprecateExistingEntities</code> parameter to <code>true</code>.</p> <p>The IDs for all of the entities should be in URN format. Each entity must be in the user's namespace. Users can't create entities in the public namespace, but entity definitions can refer to entities in the public namespace.</p> <p>Valid entities are <code>Device</code>, <code>DeviceModel</code>, <code>Service</code>, <code>Capability</code>, <code>State</code>, <code>Action</code>, <code>Event</code>, <code>Property</code>, <code>Mapping</code>, <code>Enum</code>. </p>
+    async fn upload_entity_definitions(
+        &self,
+        input: UploadEntityDefinitionsRequest,
+    ) -> Result<UploadEntityDefinitionsResponse, RusotoError<UploadEntityDefinitionsError>> {
+        self.upload_entity_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -1 +1,3 @@
+mod auth_token;
 
+pub use self::auth_token::{get_msk_auth_token, msk_auth_token, MskAuthToken};
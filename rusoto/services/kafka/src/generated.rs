@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1330,6 +1330,7 @@ pub struct ZookeeperNodeInfo {
 
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1352,6 +1353,8 @@ pub enum CreateClusterError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterError {
@@ -1359,28 +1362,34 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateClusterError::BadRequest(err.msg))
+                    return RusotoError::service(CreateClusterError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateClusterError::Conflict(err.msg))
+                    return RusotoError::service(CreateClusterError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateClusterError::Forbidden(err.msg))
+                    return RusotoError::service(CreateClusterError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateClusterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateClusterError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateClusterError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateClusterError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateClusterError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1397,12 +1406,14 @@ impl fmt::Display for CreateClusterError {
             CreateClusterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateClusterError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateClusterError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterError {}
 /// Errors returned by CreateConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1425,6 +1436,8 @@ pub enum CreateConfigurationError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationError {
@@ -1432,32 +1445,43 @@ impl CreateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateConfigurationError::Conflict(err.msg))
+                    return RusotoError::service(CreateConfigurationError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(CreateConfigurationError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateConfigurationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateConfigurationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateConfigurationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        CreateConfigurationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1474,12 +1498,14 @@ impl fmt::Display for CreateConfigurationError {
             CreateConfigurationError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateConfigurationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateConfigurationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConfigurationError {}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1493,6 +1519,8 @@ pub enum DeleteClusterError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterError {
@@ -1500,19 +1528,22 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteClusterError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteClusterError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteClusterError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteClusterError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteClusterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteClusterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::NotFound(err.msg))
+                    return RusotoError::service(DeleteClusterError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1526,12 +1557,14 @@ impl fmt::Display for DeleteClusterError {
             DeleteClusterError::Forbidden(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterError {}
 /// Errors returned by DescribeCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1548,6 +1581,8 @@ pub enum DescribeClusterError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterError {
@@ -1555,22 +1590,25 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeClusterError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeClusterError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeClusterError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeClusterError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeClusterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeClusterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeClusterError::NotFound(err.msg))
+                    return RusotoError::service(DescribeClusterError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeClusterError::Unauthorized(err.msg))
+                    return RusotoError::service(DescribeClusterError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1585,12 +1623,14 @@ impl fmt::Display for DescribeClusterError {
             DescribeClusterError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeClusterError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeClusterError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterError {}
 /// Errors returned by DescribeClusterOperation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterOperationError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1607,6 +1647,8 @@ pub enum DescribeClusterOperationError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterOperationError {
@@ -1614,26 +1656,37 @@ impl DescribeClusterOperationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeClusterOperationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeClusterOperationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeClusterOperationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeClusterOperationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeClusterOperationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeClusterOperationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1648,12 +1701,14 @@ impl fmt::Display for DescribeClusterOperationError {
             DescribeClusterOperationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeClusterOperationError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeClusterOperationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeClusterOperationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterOperationError {}
 /// Errors returned by DescribeConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1673,6 +1728,8 @@ pub enum DescribeConfigurationError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationError {
@@ -1680,29 +1737,43 @@ impl DescribeConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeConfigurationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeConfigurationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1718,12 +1789,14 @@ impl fmt::Display for DescribeConfigurationError {
             DescribeConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeConfigurationError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeConfigurationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConfigurationError {}
 /// Errors returned by DescribeConfigurationRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationRevisionError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1743,6 +1816,8 @@ pub enum DescribeConfigurationRevisionError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationRevisionError {
@@ -1752,37 +1827,48 @@ impl DescribeConfigurationRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationRevisionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationRevisionError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationRevisionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationRevisionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationRevisionError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationRevisionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConfigurationRevisionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1802,12 +1888,16 @@ impl fmt::Display for DescribeConfigurationRevisionError {
                 write!(f, "{}", cause)
             }
             DescribeConfigurationRevisionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeConfigurationRevisionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConfigurationRevisionError {}
 /// Errors returned by GetBootstrapBrokers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBootstrapBrokersError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1824,6 +1914,8 @@ pub enum GetBootstrapBrokersError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBootstrapBrokersError {
@@ -1831,24 +1923,31 @@ impl GetBootstrapBrokersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetBootstrapBrokersError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::Conflict(err.msg))
+                    return RusotoError::service(GetBootstrapBrokersError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::Forbidden(err.msg))
+                    return RusotoError::service(GetBootstrapBrokersError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBootstrapBrokersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetBootstrapBrokersError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBootstrapBrokersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1863,12 +1962,14 @@ impl fmt::Display for GetBootstrapBrokersError {
             GetBootstrapBrokersError::Forbidden(ref cause) => write!(f, "{}", cause),
             GetBootstrapBrokersError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetBootstrapBrokersError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetBootstrapBrokersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBootstrapBrokersError {}
 /// Errors returned by ListClusterOperations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListClusterOperationsError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1882,6 +1983,8 @@ pub enum ListClusterOperationsError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListClusterOperationsError {
@@ -1889,21 +1992,31 @@ impl ListClusterOperationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListClusterOperationsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListClusterOperationsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListClusterOperationsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        ListClusterOperationsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListClusterOperationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListClusterOperationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListClusterOperationsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        ListClusterOperationsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListClusterOperationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1917,12 +2030,14 @@ impl fmt::Display for ListClusterOperationsError {
             ListClusterOperationsError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListClusterOperationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListClusterOperationsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListClusterOperationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListClusterOperationsError {}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListClustersError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1936,6 +2051,8 @@ pub enum ListClustersError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListClustersError {
@@ -1943,19 +2060,22 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListClustersError::BadRequest(err.msg))
+                    return RusotoError::service(ListClustersError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListClustersError::Forbidden(err.msg))
+                    return RusotoError::service(ListClustersError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListClustersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListClustersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListClustersError::Unauthorized(err.msg))
+                    return RusotoError::service(ListClustersError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListClustersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1969,12 +2089,14 @@ impl fmt::Display for ListClustersError {
             ListClustersError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListClustersError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListClustersError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListClustersError {}
 /// Errors returned by ListConfigurationRevisions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigurationRevisionsError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -1994,6 +2116,8 @@ pub enum ListConfigurationRevisionsError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigurationRevisionsError {
@@ -2003,35 +2127,45 @@ impl ListConfigurationRevisionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationRevisionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationRevisionsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListConfigurationRevisionsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListConfigurationRevisionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListConfigurationRevisionsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationRevisionsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListConfigurationRevisionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2051,12 +2185,14 @@ impl fmt::Display for ListConfigurationRevisionsError {
                 write!(f, "{}", cause)
             }
             ListConfigurationRevisionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListConfigurationRevisionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigurationRevisionsError {}
 /// Errors returned by ListConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigurationsError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -2073,6 +2209,8 @@ pub enum ListConfigurationsError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigurationsError {
@@ -2080,26 +2218,31 @@ impl ListConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationsError::BadRequest(err.msg))
+                    return RusotoError::service(ListConfigurationsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationsError::Forbidden(err.msg))
+                    return RusotoError::service(ListConfigurationsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListConfigurationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListConfigurationsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        ListConfigurationsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConfigurationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2114,12 +2257,14 @@ impl fmt::Display for ListConfigurationsError {
             ListConfigurationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListConfigurationsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListConfigurationsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigurationsError {}
 /// Errors returned by ListNodes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListNodesError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -2133,6 +2278,8 @@ pub enum ListNodesError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListNodesError {
@@ -2140,19 +2287,19 @@ impl ListNodesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListNodesError::BadRequest(err.msg))
+                    return RusotoError::service(ListNodesError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListNodesError::Forbidden(err.msg))
+                    return RusotoError::service(ListNodesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListNodesError::InternalServerError(err.msg))
+                    return RusotoError::service(ListNodesError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListNodesError::NotFound(err.msg))
+                    return RusotoError::service(ListNodesError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListNodesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2166,12 +2313,14 @@ impl fmt::Display for ListNodesError {
             ListNodesError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListNodesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListNodesError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListNodesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListNodesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -2182,6 +2331,8 @@ pub enum ListTagsForResourceError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2189,18 +2340,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2213,12 +2368,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -2229,6 +2386,8 @@ pub enum TagResourceError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2236,16 +2395,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2258,12 +2420,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -2274,6 +2438,8 @@ pub enum UntagResourceError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2281,16 +2447,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2303,12 +2472,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateBrokerCount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBrokerCountError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -2325,6 +2496,8 @@ pub enum UpdateBrokerCountError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBrokerCountError {
@@ -2332,26 +2505,31 @@ impl UpdateBrokerCountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateBrokerCountError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateBrokerCountError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBrokerCountError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBrokerCountError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateBrokerCountError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBrokerCountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2366,12 +2544,14 @@ impl fmt::Display for UpdateBrokerCountError {
             UpdateBrokerCountError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateBrokerCountError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateBrokerCountError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateBrokerCountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBrokerCountError {}
 /// Errors returned by UpdateBrokerStorage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBrokerStorageError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -2388,6 +2568,8 @@ pub enum UpdateBrokerStorageError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBrokerStorageError {
@@ -2395,26 +2577,34 @@ impl UpdateBrokerStorageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateBrokerStorageError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateBrokerStorageError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBrokerStorageError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBrokerStorageError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateBrokerStorageError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBrokerStorageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2429,12 +2619,14 @@ impl fmt::Display for UpdateBrokerStorageError {
             UpdateBrokerStorageError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateBrokerStorageError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateBrokerStorageError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateBrokerStorageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBrokerStorageError {}
 /// Errors returned by UpdateClusterConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateClusterConfigurationError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -2454,6 +2646,8 @@ pub enum UpdateClusterConfigurationError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateClusterConfigurationError {
@@ -2463,35 +2657,45 @@ impl UpdateClusterConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateClusterConfigurationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateClusterConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateClusterConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterConfigurationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateClusterConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2511,12 +2715,14 @@ impl fmt::Display for UpdateClusterConfigurationError {
                 write!(f, "{}", cause)
             }
             UpdateClusterConfigurationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateClusterConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateClusterConfigurationError {}
 /// Errors returned by UpdateMonitoring
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMonitoringError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
@@ -2533,6 +2739,8 @@ pub enum UpdateMonitoringError {
     /// <pre><code>        &lt;p&gt;Returns information about an error.&lt;/p&gt;
     /// </code></pre>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMonitoringError {
@@ -2540,24 +2748,28 @@ impl UpdateMonitoringError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMonitoringError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateMonitoringError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMonitoringError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateMonitoringError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMonitoringError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMonitoringError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateMonitoringError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateMonitoringError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateMonitoringError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateMonitoringError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMonitoringError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2572,6 +2784,7 @@ impl fmt::Display for UpdateMonitoringError {
             UpdateMonitoringError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateMonitoringError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateMonitoringError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateMonitoringError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2733,6 +2946,7 @@ impl KafkaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KafkaClient {
         KafkaClient {
             client: Client::shared(),
@@ -2758,84 +2972,187 @@ impl KafkaClient {
     pub fn new_with_client(client: Client, region: region::Region) -> KafkaClient {
         KafkaClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KafkaClient> {
+        rusoto_core::ClientBuilder::new(KafkaClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KafkaClient {
+        KafkaClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Kafka for KafkaClient {
-    /// <pre><code>        &lt;p&gt;Creates a new MSK cluster.&lt;/p&gt;
-    /// </code></pre>
-    async fn create_cluster(
+impl KafkaClient {
+    /// Calls `create_cluster`, also returning the metadata of the response.
+    pub async fn create_cluster_with_metadata(
         &self,
         input: CreateClusterRequest,
-    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+    ) -> Result<(CreateClusterResponse, ResponseMetadata), RusotoError<CreateClusterError>> {
+        let request = self.build_create_cluster_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateClusterResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateClusterError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateClusterRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_request(&self, input: CreateClusterRequest) -> SignedRequest {
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("CreateCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_configuration`, also returning the metadata of the response.
+    pub async fn create_configuration_with_metadata(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> Result<
+        (CreateConfigurationResponse, ResponseMetadata),
+        RusotoError<CreateConfigurationError>,
+    > {
+        let request = self.build_create_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateClusterResponse, _>()?;
+                .deserialize::<CreateConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateClusterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateConfigurationError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Creates a new MSK configuration.&lt;/p&gt;
-    /// </code></pre>
-    async fn create_configuration(
+    /// Presigns the request `create_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConfigurationRequest,
-    ) -> Result<CreateConfigurationResponse, RusotoError<CreateConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_request(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("CreateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_cluster`, also returning the metadata of the response.
+    pub async fn delete_cluster_with_metadata(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<(DeleteClusterResponse, ResponseMetadata), RusotoError<DeleteClusterError>> {
+        let request = self.build_delete_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateConfigurationResponse, _>()?;
+                .deserialize::<DeleteClusterResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteClusterError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Deletes the MSK cluster specified by the Amazon Resource Name (ARN) in the request.&lt;/p&gt;
-    /// </code></pre>
-    async fn delete_cluster(
+    /// Presigns the request `delete_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteClusterRequest,
-    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_request(&self, input: DeleteClusterRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation("DeleteCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2844,122 +3161,229 @@ impl Kafka for KafkaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_cluster`, also returning the metadata of the response.
+    pub async fn describe_cluster_with_metadata(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<(DescribeClusterResponse, ResponseMetadata), RusotoError<DescribeClusterError>>
+    {
+        let request = self.build_describe_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteClusterResponse, _>()?;
+                .deserialize::<DescribeClusterResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteClusterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeClusterError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a description of the MSK cluster whose Amazon Resource Name (ARN) is specified in the request.&lt;/p&gt;
-    /// </code></pre>
-    async fn describe_cluster(
+    /// Presigns the request `describe_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeClusterRequest,
-    ) -> Result<DescribeClusterResponse, RusotoError<DescribeClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_request(&self, input: DescribeClusterRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cluster_operation`, also returning the metadata of the response.
+    pub async fn describe_cluster_operation_with_metadata(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> Result<
+        (DescribeClusterOperationResponse, ResponseMetadata),
+        RusotoError<DescribeClusterOperationError>,
+    > {
+        let request = self.build_describe_cluster_operation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeClusterResponse, _>()?;
+                .deserialize::<DescribeClusterOperationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeClusterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeClusterOperationError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a description of the cluster operation specified by the ARN.&lt;/p&gt;
-    /// </code></pre>
-    async fn describe_cluster_operation(
+    /// Presigns the request `describe_cluster_operation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster_operation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeClusterOperationRequest,
-    ) -> Result<DescribeClusterOperationResponse, RusotoError<DescribeClusterOperationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_operation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_operation_request(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/operations/{cluster_operation_arn}",
             cluster_operation_arn = input.cluster_operation_arn
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeClusterOperation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_configuration`, also returning the metadata of the response.
+    pub async fn describe_configuration_with_metadata(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> Result<
+        (DescribeConfigurationResponse, ResponseMetadata),
+        RusotoError<DescribeConfigurationError>,
+    > {
+        let request = self.build_describe_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeClusterOperationResponse, _>()?;
+                .deserialize::<DescribeConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeClusterOperationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeConfigurationError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a description of this MSK configuration.&lt;/p&gt;
-    /// </code></pre>
-    async fn describe_configuration(
+    /// Presigns the request `describe_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigurationRequest,
-    ) -> Result<DescribeConfigurationResponse, RusotoError<DescribeConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_request(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/configurations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_configuration_revision`, also returning the metadata of the response.
+    pub async fn describe_configuration_revision_with_metadata(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> Result<
+        (DescribeConfigurationRevisionResponse, ResponseMetadata),
+        RusotoError<DescribeConfigurationRevisionError>,
+    > {
+        let request = self.build_describe_configuration_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigurationResponse, _>()?;
+                .deserialize::<DescribeConfigurationRevisionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeConfigurationRevisionError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a description of this revision of the configuration.&lt;/p&gt;
-    /// </code></pre>
-    async fn describe_configuration_revision(
+    /// Presigns the request `describe_configuration_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration_revision(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigurationRevisionRequest,
-    ) -> Result<
-        DescribeConfigurationRevisionResponse,
-        RusotoError<DescribeConfigurationRevisionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_revision_request(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/configurations/{arn}/revisions/{revision}",
             arn = input.arn,
@@ -2967,68 +3391,127 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeConfigurationRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_bootstrap_brokers`, also returning the metadata of the response.
+    pub async fn get_bootstrap_brokers_with_metadata(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> Result<
+        (GetBootstrapBrokersResponse, ResponseMetadata),
+        RusotoError<GetBootstrapBrokersError>,
+    > {
+        let request = self.build_get_bootstrap_brokers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigurationRevisionResponse, _>()?;
+                .deserialize::<GetBootstrapBrokersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigurationRevisionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBootstrapBrokersError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;A list of brokers that a client application can use to bootstrap.&lt;/p&gt;
-    /// </code></pre>
-    async fn get_bootstrap_brokers(
+    /// Presigns the request `get_bootstrap_brokers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bootstrap_brokers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBootstrapBrokersRequest,
-    ) -> Result<GetBootstrapBrokersResponse, RusotoError<GetBootstrapBrokersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bootstrap_brokers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bootstrap_brokers_request(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}/bootstrap-brokers",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("GetBootstrapBrokers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_cluster_operations`, also returning the metadata of the response.
+    pub async fn list_cluster_operations_with_metadata(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> Result<
+        (ListClusterOperationsResponse, ResponseMetadata),
+        RusotoError<ListClusterOperationsError>,
+    > {
+        let request = self.build_list_cluster_operations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBootstrapBrokersResponse, _>()?;
+                .deserialize::<ListClusterOperationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBootstrapBrokersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListClusterOperationsError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a list of all the operations that have been performed on the specified MSK cluster.&lt;/p&gt;
-    /// </code></pre>
-    async fn list_cluster_operations(
+    /// Presigns the request `list_cluster_operations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_cluster_operations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListClusterOperationsRequest,
-    ) -> Result<ListClusterOperationsResponse, RusotoError<ListClusterOperationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_cluster_operations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_cluster_operations_request(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}/operations",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListClusterOperations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3040,32 +3523,55 @@ impl Kafka for KafkaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_clusters`, also returning the metadata of the response.
+    pub async fn list_clusters_with_metadata(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<(ListClustersResponse, ResponseMetadata), RusotoError<ListClustersError>> {
+        let request = self.build_list_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListClusterOperationsResponse, _>()?;
+                .deserialize::<ListClustersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListClusterOperationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListClustersError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a list of all the MSK clusters in the current Region.&lt;/p&gt;
-    /// </code></pre>
-    async fn list_clusters(
+    /// Presigns the request `list_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_clusters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListClustersRequest,
-    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_clusters_request(&self, input: ListClustersRequest) -> SignedRequest {
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListClusters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3080,33 +3586,61 @@ impl Kafka for KafkaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_configuration_revisions`, also returning the metadata of the response.
+    pub async fn list_configuration_revisions_with_metadata(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> Result<
+        (ListConfigurationRevisionsResponse, ResponseMetadata),
+        RusotoError<ListConfigurationRevisionsError>,
+    > {
+        let request = self.build_list_configuration_revisions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListClustersResponse, _>()?;
+                .deserialize::<ListConfigurationRevisionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListClustersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConfigurationRevisionsError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a list of all the MSK configurations in this Region.&lt;/p&gt;
-    /// </code></pre>
-    async fn list_configuration_revisions(
+    /// Presigns the request `list_configuration_revisions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configuration_revisions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigurationRevisionsRequest,
-    ) -> Result<ListConfigurationRevisionsResponse, RusotoError<ListConfigurationRevisionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configuration_revisions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configuration_revisions_request(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/configurations/{arn}/revisions", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListConfigurationRevisions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3118,32 +3652,56 @@ impl Kafka for KafkaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_configurations`, also returning the metadata of the response.
+    pub async fn list_configurations_with_metadata(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<(ListConfigurationsResponse, ResponseMetadata), RusotoError<ListConfigurationsError>>
+    {
+        let request = self.build_list_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConfigurationRevisionsResponse, _>()?;
+                .deserialize::<ListConfigurationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigurationRevisionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConfigurationsError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a list of all the MSK configurations in this Region.&lt;/p&gt;
-    /// </code></pre>
-    async fn list_configurations(
+    /// Presigns the request `list_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigurationsRequest,
-    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configurations_request(&self, input: ListConfigurationsRequest) -> SignedRequest {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3155,35 +3713,58 @@ impl Kafka for KafkaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_nodes`, also returning the metadata of the response.
+    pub async fn list_nodes_with_metadata(
+        &self,
+        input: ListNodesRequest,
+    ) -> Result<(ListNodesResponse, ResponseMetadata), RusotoError<ListNodesError>> {
+        let request = self.build_list_nodes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConfigurationsResponse, _>()?;
+                .deserialize::<ListNodesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigurationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListNodesError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a list of the broker nodes in the cluster.&lt;/p&gt;
-    /// </code></pre>
-    async fn list_nodes(
+    /// Presigns the request `list_nodes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_nodes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListNodesRequest,
-    ) -> Result<ListNodesResponse, RusotoError<ListNodesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_nodes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_nodes_request(&self, input: ListNodesRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}/nodes",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3195,90 +3776,164 @@ impl Kafka for KafkaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListNodesResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListNodesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Returns a list of the tags associated with the specified resource.&lt;/p&gt;
-    /// </code></pre>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Adds tags to the specified MSK resource.&lt;/p&gt;
-    /// </code></pre>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Removes the tags associated with the keys that are provided in the query.&lt;/p&gt;
-    /// </code></pre>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3287,156 +3942,664 @@ impl Kafka for KafkaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_broker_count`, also returning the metadata of the response.
+    pub async fn update_broker_count_with_metadata(
+        &self,
+        input: UpdateBrokerCountRequest,
+    ) -> Result<(UpdateBrokerCountResponse, ResponseMetadata), RusotoError<UpdateBrokerCountError>>
+    {
+        let request = self.build_update_broker_count_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateBrokerCountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateBrokerCountError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Updates the number of broker nodes in the cluster.&lt;/p&gt;
-    /// </code></pre>
-    async fn update_broker_count(
+    /// Presigns the request `update_broker_count` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_broker_count(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBrokerCountRequest,
-    ) -> Result<UpdateBrokerCountResponse, RusotoError<UpdateBrokerCountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_broker_count_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_broker_count_request(&self, input: UpdateBrokerCountRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}/nodes/count",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateBrokerCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_broker_storage`, also returning the metadata of the response.
+    pub async fn update_broker_storage_with_metadata(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> Result<
+        (UpdateBrokerStorageResponse, ResponseMetadata),
+        RusotoError<UpdateBrokerStorageError>,
+    > {
+        let request = self.build_update_broker_storage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateBrokerCountResponse, _>()?;
+                .deserialize::<UpdateBrokerStorageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBrokerCountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateBrokerStorageError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Updates the EBS storage associated with MSK brokers.&lt;/p&gt;
-    /// </code></pre>
-    async fn update_broker_storage(
+    /// Presigns the request `update_broker_storage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_broker_storage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBrokerStorageRequest,
-    ) -> Result<UpdateBrokerStorageResponse, RusotoError<UpdateBrokerStorageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_broker_storage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_broker_storage_request(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}/nodes/storage",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateBrokerStorage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_cluster_configuration`, also returning the metadata of the response.
+    pub async fn update_cluster_configuration_with_metadata(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> Result<
+        (UpdateClusterConfigurationResponse, ResponseMetadata),
+        RusotoError<UpdateClusterConfigurationError>,
+    > {
+        let request = self.build_update_cluster_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateBrokerStorageResponse, _>()?;
+                .deserialize::<UpdateClusterConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBrokerStorageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateClusterConfigurationError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Updates the cluster with the configuration that is specified in the request body.&lt;/p&gt;
-    /// </code></pre>
-    async fn update_cluster_configuration(
+    /// Presigns the request `update_cluster_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_cluster_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateClusterConfigurationRequest,
-    ) -> Result<UpdateClusterConfigurationResponse, RusotoError<UpdateClusterConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_cluster_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_cluster_configuration_request(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}/configuration",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateClusterConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_monitoring`, also returning the metadata of the response.
+    pub async fn update_monitoring_with_metadata(
+        &self,
+        input: UpdateMonitoringRequest,
+    ) -> Result<(UpdateMonitoringResponse, ResponseMetadata), RusotoError<UpdateMonitoringError>>
+    {
+        let request = self.build_update_monitoring_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateClusterConfigurationResponse, _>()?;
+                .deserialize::<UpdateMonitoringResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateClusterConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateMonitoringError::from_response(response))
         }
     }
 
-    /// <pre><code>        &lt;p&gt;Updates the monitoring settings for the cluster. You can use this operation to specify which Apache Kafka metrics you want Amazon MSK to send to Amazon CloudWatch. You can also specify settings for open monitoring with Prometheus.&lt;/p&gt;
-    /// </code></pre>
-    async fn update_monitoring(
+    /// Presigns the request `update_monitoring` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_monitoring(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMonitoringRequest,
-    ) -> Result<UpdateMonitoringResponse, RusotoError<UpdateMonitoringError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_monitoring_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_monitoring_request(&self, input: UpdateMonitoringRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/clusters/{cluster_arn}/monitoring",
             cluster_arn = input.cluster_arn
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateMonitoring");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListClusterOperations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_cluster_operations_pages(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListClusterOperationsResponse, RusotoError<ListClusterOperationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input| async move { client.list_cluster_operations(input).await },
+            |input: &ListClusterOperationsRequest, output: &ListClusterOperationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListClusterOperationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ClusterOperationInfoList` of every page of `ListClusterOperations` results for `input`, as `list_cluster_operations_pages` requests them.
+    pub fn list_cluster_operations_stream(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterOperationInfo, RusotoError<ListClusterOperationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_cluster_operations_pages(input), |page| {
+            page.cluster_operation_info_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListClusters` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<ListClustersResponse, RusotoError<ListClustersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input| async move { client.list_clusters(input).await },
+            |input: &ListClustersRequest, output: &ListClustersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListClustersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ClusterInfoList` of every page of `ListClusters` results for `input`, as `list_clusters_pages` requests them.
+    pub fn list_clusters_stream(
+        &self,
+        input: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<ClusterInfo, RusotoError<ListClustersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_clusters_pages(input), |page| {
+            page.cluster_info_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListConfigurationRevisions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_configuration_revisions_pages(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListConfigurationRevisionsResponse,
+            RusotoError<ListConfigurationRevisionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input| async move { client.list_configuration_revisions(input).await },
+            |input: &ListConfigurationRevisionsRequest,
+             output: &ListConfigurationRevisionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListConfigurationRevisionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Revisions` of every page of `ListConfigurationRevisions` results for `input`, as `list_configuration_revisions_pages` requests them.
+    pub fn list_configuration_revisions_stream(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ConfigurationRevision, RusotoError<ListConfigurationRevisionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_configuration_revisions_pages(input), |page| {
+            page.revisions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListConfigurations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_configurations_pages(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input| async move { client.list_configurations(input).await },
+            |input: &ListConfigurationsRequest, output: &ListConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Configurations` of every page of `ListConfigurations` results for `input`, as `list_configurations_pages` requests them.
+    pub fn list_configurations_stream(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> impl futures::Stream<Item = Result<Configuration, RusotoError<ListConfigurationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_configurations_pages(input), |page| {
+            page.configurations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListNodes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_nodes_pages(
+        &self,
+        input: ListNodesRequest,
+    ) -> impl futures::Stream<Item = Result<ListNodesResponse, RusotoError<ListNodesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KafkaClient, input| async move { client.list_nodes(input).await },
+            |input: &ListNodesRequest, output: &ListNodesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListNodesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `NodeInfoList` of every page of `ListNodes` results for `input`, as `list_nodes_pages` requests them.
+    pub fn list_nodes_stream(
+        &self,
+        input: ListNodesRequest,
+    ) -> impl futures::Stream<Item = Result<NodeInfo, RusotoError<ListNodesError>>> + Send {
+        rusoto_core::pagination::items(self.list_nodes_pages(input), |page| {
+            page.node_info_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Kafka for KafkaClient {
+    /// <pre><code>        &lt;p&gt;Creates a new MSK cluster.&lt;/p&gt;
+    /// </code></pre>
+    async fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.create_cluster_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMonitoringResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMonitoringError::from_response(response))
-        }
+    /// <pre><code>        &lt;p&gt;Creates a new MSK configuration.&lt;/p&gt;
+    /// </code></pre>
+    async fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> Result<CreateConfigurationResponse, RusotoError<CreateConfigurationError>> {
+        self.create_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Deletes the MSK cluster specified by the Amazon Resource Name (ARN) in the request.&lt;/p&gt;
+    /// </code></pre>
+    async fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.delete_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a description of the MSK cluster whose Amazon Resource Name (ARN) is specified in the request.&lt;/p&gt;
+    /// </code></pre>
+    async fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<DescribeClusterResponse, RusotoError<DescribeClusterError>> {
+        self.describe_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a description of the cluster operation specified by the ARN.&lt;/p&gt;
+    /// </code></pre>
+    async fn describe_cluster_operation(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> Result<DescribeClusterOperationResponse, RusotoError<DescribeClusterOperationError>> {
+        self.describe_cluster_operation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a description of this MSK configuration.&lt;/p&gt;
+    /// </code></pre>
+    async fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> Result<DescribeConfigurationResponse, RusotoError<DescribeConfigurationError>> {
+        self.describe_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a description of this revision of the configuration.&lt;/p&gt;
+    /// </code></pre>
+    async fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> Result<
+        DescribeConfigurationRevisionResponse,
+        RusotoError<DescribeConfigurationRevisionError>,
+    > {
+        self.describe_configuration_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;A list of brokers that a client application can use to bootstrap.&lt;/p&gt;
+    /// </code></pre>
+    async fn get_bootstrap_brokers(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> Result<GetBootstrapBrokersResponse, RusotoError<GetBootstrapBrokersError>> {
+        self.get_bootstrap_brokers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of all the operations that have been performed on the specified MSK cluster.&lt;/p&gt;
+    /// </code></pre>
+    async fn list_cluster_operations(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> Result<ListClusterOperationsResponse, RusotoError<ListClusterOperationsError>> {
+        self.list_cluster_operations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of all the MSK clusters in the current Region.&lt;/p&gt;
+    /// </code></pre>
+    async fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+        self.list_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of all the MSK configurations in this Region.&lt;/p&gt;
+    /// </code></pre>
+    async fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> Result<ListConfigurationRevisionsResponse, RusotoError<ListConfigurationRevisionsError>>
+    {
+        self.list_configuration_revisions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of all the MSK configurations in this Region.&lt;/p&gt;
+    /// </code></pre>
+    async fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+        self.list_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of the broker nodes in the cluster.&lt;/p&gt;
+    /// </code></pre>
+    async fn list_nodes(
+        &self,
+        input: ListNodesRequest,
+    ) -> Result<ListNodesResponse, RusotoError<ListNodesError>> {
+        self.list_nodes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of the tags associated with the specified resource.&lt;/p&gt;
+    /// </code></pre>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Adds tags to the specified MSK resource.&lt;/p&gt;
+    /// </code></pre>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Removes the tags associated with the keys that are provided in the query.&lt;/p&gt;
+    /// </code></pre>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Updates the number of broker nodes in the cluster.&lt;/p&gt;
+    /// </code></pre>
+    async fn update_broker_count(
+        &self,
+        input: UpdateBrokerCountRequest,
+    ) -> Result<UpdateBrokerCountResponse, RusotoError<UpdateBrokerCountError>> {
+        self.update_broker_count_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Updates the EBS storage associated with MSK brokers.&lt;/p&gt;
+    /// </code></pre>
+    async fn update_broker_storage(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> Result<UpdateBrokerStorageResponse, RusotoError<UpdateBrokerStorageError>> {
+        self.update_broker_storage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Updates the cluster with the configuration that is specified in the request body.&lt;/p&gt;
+    /// </code></pre>
+    async fn update_cluster_configuration(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> Result<UpdateClusterConfigurationResponse, RusotoError<UpdateClusterConfigurationError>>
+    {
+        self.update_cluster_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <pre><code>        &lt;p&gt;Updates the monitoring settings for the cluster. You can use this operation to specify which Apache Kafka metrics you want Amazon MSK to send to Amazon CloudWatch. You can also specify settings for open monitoring with Prometheus.&lt;/p&gt;
+    /// </code></pre>
+    async fn update_monitoring(
+        &self,
+        input: UpdateMonitoringRequest,
+    ) -> Result<UpdateMonitoringResponse, RusotoError<UpdateMonitoringError>> {
+        self.update_monitoring_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
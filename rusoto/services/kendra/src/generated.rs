@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1386,6 +1386,7 @@ pub struct UpdateIndexRequest {
 
 /// Errors returned by BatchDeleteDocument
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeleteDocumentError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1397,6 +1398,8 @@ pub enum BatchDeleteDocumentError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeleteDocumentError {
@@ -1404,24 +1407,34 @@ impl BatchDeleteDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        BatchDeleteDocumentError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::Conflict(err.msg))
+                    return RusotoError::service(BatchDeleteDocumentError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        BatchDeleteDocumentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteDocumentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::Throttling(err.msg))
+                    return RusotoError::service(
+                        BatchDeleteDocumentError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDeleteDocumentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1436,12 +1449,14 @@ impl fmt::Display for BatchDeleteDocumentError {
             BatchDeleteDocumentError::InternalServer(ref cause) => write!(f, "{}", cause),
             BatchDeleteDocumentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             BatchDeleteDocumentError::Throttling(ref cause) => write!(f, "{}", cause),
+            BatchDeleteDocumentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeleteDocumentError {}
 /// Errors returned by BatchPutDocument
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchPutDocumentError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1455,6 +1470,8 @@ pub enum BatchPutDocumentError {
     ServiceQuotaExceeded(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchPutDocumentError {
@@ -1462,27 +1479,34 @@ impl BatchPutDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchPutDocumentError::AccessDenied(err.msg))
+                    return RusotoError::service(BatchPutDocumentError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(BatchPutDocumentError::Conflict(err.msg))
+                    return RusotoError::service(BatchPutDocumentError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchPutDocumentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        BatchPutDocumentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchPutDocumentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        BatchPutDocumentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(BatchPutDocumentError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchPutDocumentError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchPutDocumentError::Throttling(err.msg))
+                    return RusotoError::service(BatchPutDocumentError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchPutDocumentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1498,12 +1522,14 @@ impl fmt::Display for BatchPutDocumentError {
             BatchPutDocumentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             BatchPutDocumentError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             BatchPutDocumentError::Throttling(ref cause) => write!(f, "{}", cause),
+            BatchPutDocumentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchPutDocumentError {}
 /// Errors returned by CreateDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataSourceError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1519,6 +1545,8 @@ pub enum CreateDataSourceError {
     ServiceQuotaExceeded(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataSourceError {
@@ -1526,32 +1554,40 @@ impl CreateDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateDataSourceError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service(CreateDataSourceError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateDataSourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceAlreadyExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSourceError::ResourceAlreadyExist(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateDataSourceError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSourceError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(CreateDataSourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1568,12 +1604,14 @@ impl fmt::Display for CreateDataSourceError {
             CreateDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateDataSourceError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateDataSourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataSourceError {}
 /// Errors returned by CreateFaq
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFaqError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1587,6 +1625,8 @@ pub enum CreateFaqError {
     ServiceQuotaExceeded(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFaqError {
@@ -1594,25 +1634,28 @@ impl CreateFaqError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateFaqError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateFaqError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateFaqError::Conflict(err.msg))
+                    return RusotoError::service(CreateFaqError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateFaqError::InternalServer(err.msg))
+                    return RusotoError::service(CreateFaqError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFaqError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateFaqError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateFaqError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateFaqError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateFaqError::Throttling(err.msg))
+                    return RusotoError::service(CreateFaqError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFaqError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1628,12 +1671,14 @@ impl fmt::Display for CreateFaqError {
             CreateFaqError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateFaqError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateFaqError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateFaqError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFaqError {}
 /// Errors returned by CreateIndex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIndexError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1645,6 +1690,8 @@ pub enum CreateIndexError {
     ServiceQuotaExceeded(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIndexError {
@@ -1652,22 +1699,28 @@ impl CreateIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateIndexError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateIndexError::InternalServer(err.msg))
+                    return RusotoError::service(CreateIndexError::InternalServer(err.msg), &res)
                 }
                 "ResourceAlreadyExistException" => {
-                    return RusotoError::Service(CreateIndexError::ResourceAlreadyExist(err.msg))
+                    return RusotoError::service(
+                        CreateIndexError::ResourceAlreadyExist(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateIndexError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateIndexError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIndexError::Throttling(err.msg))
+                    return RusotoError::service(CreateIndexError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateIndexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1682,12 +1735,14 @@ impl fmt::Display for CreateIndexError {
             CreateIndexError::ResourceAlreadyExist(ref cause) => write!(f, "{}", cause),
             CreateIndexError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateIndexError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateIndexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIndexError {}
 /// Errors returned by DeleteFaq
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFaqError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1699,6 +1754,8 @@ pub enum DeleteFaqError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFaqError {
@@ -1706,22 +1763,22 @@ impl DeleteFaqError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteFaqError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteFaqError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteFaqError::Conflict(err.msg))
+                    return RusotoError::service(DeleteFaqError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteFaqError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteFaqError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFaqError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteFaqError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFaqError::Throttling(err.msg))
+                    return RusotoError::service(DeleteFaqError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFaqError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1736,12 +1793,14 @@ impl fmt::Display for DeleteFaqError {
             DeleteFaqError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteFaqError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteFaqError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteFaqError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFaqError {}
 /// Errors returned by DeleteIndex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIndexError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1753,6 +1812,8 @@ pub enum DeleteIndexError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIndexError {
@@ -1760,22 +1821,22 @@ impl DeleteIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteIndexError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIndexError::Conflict(err.msg))
+                    return RusotoError::service(DeleteIndexError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteIndexError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteIndexError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteIndexError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteIndexError::Throttling(err.msg))
+                    return RusotoError::service(DeleteIndexError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIndexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1790,12 +1851,14 @@ impl fmt::Display for DeleteIndexError {
             DeleteIndexError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteIndexError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteIndexError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteIndexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIndexError {}
 /// Errors returned by DescribeDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDataSourceError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1805,6 +1868,8 @@ pub enum DescribeDataSourceError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDataSourceError {
@@ -1812,19 +1877,28 @@ impl DescribeDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(DescribeDataSourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1838,12 +1912,14 @@ impl fmt::Display for DescribeDataSourceError {
             DescribeDataSourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDataSourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDataSourceError {}
 /// Errors returned by DescribeFaq
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFaqError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1853,6 +1929,8 @@ pub enum DescribeFaqError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFaqError {
@@ -1860,19 +1938,19 @@ impl DescribeFaqError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeFaqError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeFaqError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeFaqError::InternalServer(err.msg))
+                    return RusotoError::service(DescribeFaqError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFaqError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeFaqError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeFaqError::Throttling(err.msg))
+                    return RusotoError::service(DescribeFaqError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFaqError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1886,12 +1964,14 @@ impl fmt::Display for DescribeFaqError {
             DescribeFaqError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeFaqError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeFaqError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeFaqError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFaqError {}
 /// Errors returned by DescribeIndex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeIndexError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1901,6 +1981,8 @@ pub enum DescribeIndexError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeIndexError {
@@ -1908,19 +1990,22 @@ impl DescribeIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeIndexError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeIndexError::InternalServer(err.msg))
+                    return RusotoError::service(DescribeIndexError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeIndexError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIndexError::Throttling(err.msg))
+                    return RusotoError::service(DescribeIndexError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeIndexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1934,12 +2019,14 @@ impl fmt::Display for DescribeIndexError {
             DescribeIndexError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeIndexError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeIndexError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeIndexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeIndexError {}
 /// Errors returned by ListDataSourceSyncJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDataSourceSyncJobsError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -1951,6 +2038,8 @@ pub enum ListDataSourceSyncJobsError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDataSourceSyncJobsError {
@@ -1958,26 +2047,37 @@ impl ListDataSourceSyncJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListDataSourceSyncJobsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        ListDataSourceSyncJobsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDataSourceSyncJobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDataSourceSyncJobsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListDataSourceSyncJobsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDataSourceSyncJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1992,12 +2092,14 @@ impl fmt::Display for ListDataSourceSyncJobsError {
             ListDataSourceSyncJobsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListDataSourceSyncJobsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDataSourceSyncJobsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDataSourceSyncJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDataSourceSyncJobsError {}
 /// Errors returned by ListDataSources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDataSourcesError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -2007,6 +2109,8 @@ pub enum ListDataSourcesError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDataSourcesError {
@@ -2014,19 +2118,25 @@ impl ListDataSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListDataSourcesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSourcesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListDataSourcesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSourcesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListDataSourcesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSourcesError::Throttling(err.msg))
+                    return RusotoError::service(ListDataSourcesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDataSourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2040,12 +2150,14 @@ impl fmt::Display for ListDataSourcesError {
             ListDataSourcesError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListDataSourcesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDataSourcesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDataSourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDataSourcesError {}
 /// Errors returned by ListFaqs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFaqsError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -2055,6 +2167,8 @@ pub enum ListFaqsError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFaqsError {
@@ -2062,19 +2176,19 @@ impl ListFaqsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFaqsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListFaqsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFaqsError::InternalServer(err.msg))
+                    return RusotoError::service(ListFaqsError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFaqsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListFaqsError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFaqsError::Throttling(err.msg))
+                    return RusotoError::service(ListFaqsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFaqsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2088,12 +2202,14 @@ impl fmt::Display for ListFaqsError {
             ListFaqsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListFaqsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListFaqsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListFaqsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFaqsError {}
 /// Errors returned by ListIndices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIndicesError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -2101,6 +2217,8 @@ pub enum ListIndicesError {
     InternalServer(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIndicesError {
@@ -2108,16 +2226,16 @@ impl ListIndicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIndicesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListIndicesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListIndicesError::InternalServer(err.msg))
+                    return RusotoError::service(ListIndicesError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIndicesError::Throttling(err.msg))
+                    return RusotoError::service(ListIndicesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIndicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2130,12 +2248,14 @@ impl fmt::Display for ListIndicesError {
             ListIndicesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListIndicesError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListIndicesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListIndicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIndicesError {}
 /// Errors returned by Query
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum QueryError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -2147,6 +2267,8 @@ pub enum QueryError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl QueryError {
@@ -2154,20 +2276,22 @@ impl QueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(QueryError::AccessDenied(err.msg))
+                    return RusotoError::service(QueryError::AccessDenied(err.msg), &res)
+                }
+                "ConflictException" => {
+                    return RusotoError::service(QueryError::Conflict(err.msg), &res)
                 }
-                "ConflictException" => return RusotoError::Service(QueryError::Conflict(err.msg)),
                 "InternalServerException" => {
-                    return RusotoError::Service(QueryError::InternalServer(err.msg))
+                    return RusotoError::service(QueryError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(QueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(QueryError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(QueryError::Throttling(err.msg))
+                    return RusotoError::service(QueryError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(QueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2182,12 +2306,14 @@ impl fmt::Display for QueryError {
             QueryError::InternalServer(ref cause) => write!(f, "{}", cause),
             QueryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             QueryError::Throttling(ref cause) => write!(f, "{}", cause),
+            QueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for QueryError {}
 /// Errors returned by StartDataSourceSyncJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDataSourceSyncJobError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -2201,6 +2327,8 @@ pub enum StartDataSourceSyncJobError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDataSourceSyncJobError {
@@ -2208,31 +2336,43 @@ impl StartDataSourceSyncJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartDataSourceSyncJobError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::Conflict(err.msg))
+                    return RusotoError::service(
+                        StartDataSourceSyncJobError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDataSourceSyncJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDataSourceSyncJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDataSourceSyncJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        StartDataSourceSyncJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartDataSourceSyncJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2248,12 +2388,14 @@ impl fmt::Display for StartDataSourceSyncJobError {
             StartDataSourceSyncJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StartDataSourceSyncJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartDataSourceSyncJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartDataSourceSyncJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartDataSourceSyncJobError {}
 /// Errors returned by StopDataSourceSyncJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopDataSourceSyncJobError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -2263,6 +2405,8 @@ pub enum StopDataSourceSyncJobError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopDataSourceSyncJobError {
@@ -2270,23 +2414,31 @@ impl StopDataSourceSyncJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StopDataSourceSyncJobError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopDataSourceSyncJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopDataSourceSyncJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        StopDataSourceSyncJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopDataSourceSyncJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2300,12 +2452,14 @@ impl fmt::Display for StopDataSourceSyncJobError {
             StopDataSourceSyncJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopDataSourceSyncJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopDataSourceSyncJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            StopDataSourceSyncJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopDataSourceSyncJobError {}
 /// Errors returned by SubmitFeedback
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubmitFeedbackError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -2317,6 +2471,8 @@ pub enum SubmitFeedbackError {
     ResourceUnavailable(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubmitFeedbackError {
@@ -2324,22 +2480,28 @@ impl SubmitFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitFeedbackError::AccessDenied(err.msg))
+                    return RusotoError::service(SubmitFeedbackError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SubmitFeedbackError::InternalServer(err.msg))
+                    return RusotoError::service(SubmitFeedbackError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubmitFeedbackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SubmitFeedbackError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(SubmitFeedbackError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        SubmitFeedbackError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SubmitFeedbackError::Throttling(err.msg))
+                    return RusotoError::service(SubmitFeedbackError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SubmitFeedbackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2354,12 +2516,14 @@ impl fmt::Display for SubmitFeedbackError {
             SubmitFeedbackError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SubmitFeedbackError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             SubmitFeedbackError::Throttling(ref cause) => write!(f, "{}", cause),
+            SubmitFeedbackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubmitFeedbackError {}
 /// Errors returned by UpdateDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDataSourceError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -2371,6 +2535,8 @@ pub enum UpdateDataSourceError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDataSourceError {
@@ -2378,22 +2544,28 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2408,12 +2580,14 @@ impl fmt::Display for UpdateDataSourceError {
             UpdateDataSourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDataSourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDataSourceError {}
 /// Errors returned by UpdateIndex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIndexError {
     /// <p><p/></p>
     AccessDenied(String),
@@ -2425,6 +2599,8 @@ pub enum UpdateIndexError {
     ResourceNotFound(String),
     /// <p><p/></p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIndexError {
@@ -2432,22 +2608,22 @@ impl UpdateIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateIndexError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIndexError::Conflict(err.msg))
+                    return RusotoError::service(UpdateIndexError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateIndexError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateIndexError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateIndexError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateIndexError::Throttling(err.msg))
+                    return RusotoError::service(UpdateIndexError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateIndexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2462,6 +2638,7 @@ impl fmt::Display for UpdateIndexError {
             UpdateIndexError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateIndexError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateIndexError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateIndexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2594,6 +2771,7 @@ impl KendraClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KendraClient {
         KendraClient {
             client: Client::shared(),
@@ -2619,16 +2797,73 @@ impl KendraClient {
     pub fn new_with_client(client: Client, region: region::Region) -> KendraClient {
         KendraClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KendraClient> {
+        rusoto_core::ClientBuilder::new(KendraClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KendraClient {
+        KendraClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Kendra for KendraClient {
-    /// <p>Removes one or more documents from an index. The documents must have been added with the <a>BatchPutDocument</a> operation.</p> <p>The documents are deleted asynchronously. You can see the progress of the deletion by using AWS CloudWatch. Any error messages releated to the processing of the batch are sent to you CloudWatch log.</p>
-    async fn batch_delete_document(
+impl KendraClient {
+    /// Calls `batch_delete_document`, also returning the metadata of the response.
+    pub async fn batch_delete_document_with_metadata(
         &self,
         input: BatchDeleteDocumentRequest,
-    ) -> Result<BatchDeleteDocumentResponse, RusotoError<BatchDeleteDocumentError>> {
+    ) -> Result<
+        (BatchDeleteDocumentResponse, ResponseMetadata),
+        RusotoError<BatchDeleteDocumentError>,
+    > {
+        let request = self.build_batch_delete_document_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDeleteDocumentResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDeleteDocumentError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_delete_document` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_document(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchDeleteDocumentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_document_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_document_request(
+        &self,
+        input: BatchDeleteDocumentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("BatchDeleteDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2638,189 +2873,360 @@ impl Kendra for KendraClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_put_document`, also returning the metadata of the response.
+    pub async fn batch_put_document_with_metadata(
+        &self,
+        input: BatchPutDocumentRequest,
+    ) -> Result<(BatchPutDocumentResponse, ResponseMetadata), RusotoError<BatchPutDocumentError>>
+    {
+        let request = self.build_batch_put_document_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDeleteDocumentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchPutDocumentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDeleteDocumentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchPutDocumentError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more documents to an index.</p> <p>The <code>BatchPutDocument</code> operation enables you to ingest inline documents or a set of documents stored in an Amazon S3 bucket. Use this operation to ingest your text and unstructured text into an index, add custom attributes to the documents, and to attach an access control list to the documents added to the index.</p> <p>The documents are indexed asynchronously. You can see the progress of the batch using AWS CloudWatch. Any error messages related to processing the batch are sent to your AWS CloudWatch log.</p>
-    async fn batch_put_document(
+    /// Presigns the request `batch_put_document` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_put_document(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchPutDocumentRequest,
-    ) -> Result<BatchPutDocumentResponse, RusotoError<BatchPutDocumentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_put_document_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_put_document_request(&self, input: BatchPutDocumentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("BatchPutDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.BatchPutDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_data_source`, also returning the metadata of the response.
+    pub async fn create_data_source_with_metadata(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<(CreateDataSourceResponse, ResponseMetadata), RusotoError<CreateDataSourceError>>
+    {
+        let request = self.build_create_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchPutDocumentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDataSourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchPutDocumentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Creates a data source that you use to with an Amazon Kendra index. </p> <p>You specify a name, connector type and description for your data source. You can choose between an S3 connector, a SharePoint Online connector, and a database connector.</p> <p>You also specify configuration information such as document metadata (author, source URI, and so on) and user context information.</p> <p> <code>CreateDataSource</code> is a synchronous operation. The operation returns 200 if the data source was successfully created. Otherwise, an exception is raised.</p>
-    async fn create_data_source(
+    /// Presigns the request `create_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataSourceRequest,
-    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_data_source_request(&self, input: CreateDataSourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("CreateDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_faq`, also returning the metadata of the response.
+    pub async fn create_faq_with_metadata(
+        &self,
+        input: CreateFaqRequest,
+    ) -> Result<(CreateFaqResponse, ResponseMetadata), RusotoError<CreateFaqError>> {
+        let request = self.build_create_faq_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDataSourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateFaqResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateFaqError::from_response(response))
         }
     }
 
-    /// <p>Creates an new set of frequently asked question (FAQ) questions and answers.</p>
-    async fn create_faq(
+    /// Presigns the request `create_faq` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_faq(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFaqRequest,
-    ) -> Result<CreateFaqResponse, RusotoError<CreateFaqError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_faq_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_faq_request(&self, input: CreateFaqRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("CreateFaq");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateFaq");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_index`, also returning the metadata of the response.
+    pub async fn create_index_with_metadata(
+        &self,
+        input: CreateIndexRequest,
+    ) -> Result<(CreateIndexResponse, ResponseMetadata), RusotoError<CreateIndexError>> {
+        let request = self.build_create_index_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateFaqResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateIndexResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFaqError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateIndexError::from_response(response))
         }
     }
 
-    /// <p>Creates a new Amazon Kendra index. Index creation is an asynchronous operation. To determine if index creation has completed, check the <code>Status</code> field returned from a call to . The <code>Status</code> field is set to <code>ACTIVE</code> when the index is ready to use.</p> <p>Once the index is active you can index your documents using the operation or using one of the supported data sources. </p>
-    async fn create_index(
+    /// Presigns the request `create_index` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_index(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIndexRequest,
-    ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_index_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_index_request(&self, input: CreateIndexRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("CreateIndex");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_faq`, also returning the metadata of the response.
+    pub async fn delete_faq_with_metadata(
+        &self,
+        input: DeleteFaqRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteFaqError>> {
+        let request = self.build_delete_faq_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateIndexResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIndexError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteFaqError::from_response(response))
         }
     }
 
-    /// <p>Removes an FAQ from an index.</p>
-    async fn delete_faq(&self, input: DeleteFaqRequest) -> Result<(), RusotoError<DeleteFaqError>> {
+    /// Presigns the request `delete_faq` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_faq(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteFaqRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_faq_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_faq_request(&self, input: DeleteFaqRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DeleteFaq");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteFaq");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_index`, also returning the metadata of the response.
+    pub async fn delete_index_with_metadata(
+        &self,
+        input: DeleteIndexRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteIndexError>> {
+        let request = self.build_delete_index_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFaqError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteIndexError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing Amazon Kendra index. An exception is not thrown if the index is already being deleted. While the index is being deleted, the <code>Status</code> field returned by a call to the <a>DescribeIndex</a> operation is set to <code>DELETING</code>.</p>
-    async fn delete_index(
+    /// Presigns the request `delete_index` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_index(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIndexRequest,
-    ) -> Result<(), RusotoError<DeleteIndexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_index_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_index_request(&self, input: DeleteIndexRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DeleteIndex");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_data_source`, also returning the metadata of the response.
+    pub async fn describe_data_source_with_metadata(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> Result<(DescribeDataSourceResponse, ResponseMetadata), RusotoError<DescribeDataSourceError>>
+    {
+        let request = self.build_describe_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDataSourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIndexError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a Amazon Kendra data source.</p>
-    async fn describe_data_source(
+    /// Presigns the request `describe_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDataSourceRequest,
-    ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_data_source_request(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DescribeDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2830,82 +3236,162 @@ impl Kendra for KendraClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_faq`, also returning the metadata of the response.
+    pub async fn describe_faq_with_metadata(
+        &self,
+        input: DescribeFaqRequest,
+    ) -> Result<(DescribeFaqResponse, ResponseMetadata), RusotoError<DescribeFaqError>> {
+        let request = self.build_describe_faq_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDataSourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFaqResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDataSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFaqError::from_response(response))
         }
     }
 
-    /// <p>Gets information about an FAQ list.</p>
-    async fn describe_faq(
+    /// Presigns the request `describe_faq` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_faq(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFaqRequest,
-    ) -> Result<DescribeFaqResponse, RusotoError<DescribeFaqError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_faq_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_faq_request(&self, input: DescribeFaqRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DescribeFaq");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.DescribeFaq");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_index`, also returning the metadata of the response.
+    pub async fn describe_index_with_metadata(
+        &self,
+        input: DescribeIndexRequest,
+    ) -> Result<(DescribeIndexResponse, ResponseMetadata), RusotoError<DescribeIndexError>> {
+        let request = self.build_describe_index_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeFaqResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeIndexResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFaqError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeIndexError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing Amazon Kendra index</p>
-    async fn describe_index(
+    /// Presigns the request `describe_index` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_index(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeIndexRequest,
-    ) -> Result<DescribeIndexResponse, RusotoError<DescribeIndexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_index_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_index_request(&self, input: DescribeIndexRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("DescribeIndex");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.DescribeIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_data_source_sync_jobs`, also returning the metadata of the response.
+    pub async fn list_data_source_sync_jobs_with_metadata(
+        &self,
+        input: ListDataSourceSyncJobsRequest,
+    ) -> Result<
+        (ListDataSourceSyncJobsResponse, ResponseMetadata),
+        RusotoError<ListDataSourceSyncJobsError>,
+    > {
+        let request = self.build_list_data_source_sync_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeIndexResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDataSourceSyncJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeIndexError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDataSourceSyncJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets statistics about synchronizing Amazon Kendra with a data source.</p>
-    async fn list_data_source_sync_jobs(
+    /// Presigns the request `list_data_source_sync_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_data_source_sync_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDataSourceSyncJobsRequest,
-    ) -> Result<ListDataSourceSyncJobsResponse, RusotoError<ListDataSourceSyncJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_data_source_sync_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_data_source_sync_jobs_request(
+        &self,
+        input: ListDataSourceSyncJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("ListDataSourceSyncJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2915,133 +3401,267 @@ impl Kendra for KendraClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_data_sources`, also returning the metadata of the response.
+    pub async fn list_data_sources_with_metadata(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<(ListDataSourcesResponse, ResponseMetadata), RusotoError<ListDataSourcesError>>
+    {
+        let request = self.build_list_data_sources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDataSourceSyncJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDataSourcesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDataSourceSyncJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDataSourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists the data sources that you have created.</p>
-    async fn list_data_sources(
+    /// Presigns the request `list_data_sources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_data_sources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDataSourcesRequest,
-    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_data_sources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_data_sources_request(&self, input: ListDataSourcesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("ListDataSources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListDataSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_faqs`, also returning the metadata of the response.
+    pub async fn list_faqs_with_metadata(
+        &self,
+        input: ListFaqsRequest,
+    ) -> Result<(ListFaqsResponse, ResponseMetadata), RusotoError<ListFaqsError>> {
+        let request = self.build_list_faqs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDataSourcesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListFaqsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDataSourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListFaqsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of FAQ lists associated with an index.</p>
-    async fn list_faqs(
+    /// Presigns the request `list_faqs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_faqs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFaqsRequest,
-    ) -> Result<ListFaqsResponse, RusotoError<ListFaqsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_faqs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_faqs_request(&self, input: ListFaqsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("ListFaqs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListFaqs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_indices`, also returning the metadata of the response.
+    pub async fn list_indices_with_metadata(
+        &self,
+        input: ListIndicesRequest,
+    ) -> Result<(ListIndicesResponse, ResponseMetadata), RusotoError<ListIndicesError>> {
+        let request = self.build_list_indices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListFaqsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListIndicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFaqsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListIndicesError::from_response(response))
         }
     }
 
-    /// <p>Lists the Amazon Kendra indexes that you have created.</p>
-    async fn list_indices(
+    /// Presigns the request `list_indices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_indices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIndicesRequest,
-    ) -> Result<ListIndicesResponse, RusotoError<ListIndicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_indices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_indices_request(&self, input: ListIndicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("ListIndices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListIndices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `query`, also returning the metadata of the response.
+    pub async fn query_with_metadata(
+        &self,
+        input: QueryRequest,
+    ) -> Result<(QueryResult, ResponseMetadata), RusotoError<QueryError>> {
+        let request = self.build_query_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListIndicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<QueryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIndicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(QueryError::from_response(response))
         }
     }
 
-    /// <p>Searches an active index. Use this API to search your documents using query. The <code>Query</code> operation enables to do faceted search and to filter results based on document attributes.</p> <p>It also enables you to provide user context that Amazon Kendra uses to enforce document access control in the search results. </p> <p>Amazon Kendra searches your index for text content and question and answer (FAQ) content. By default the response contains three types of results.</p> <ul> <li> <p>Relevant passages</p> </li> <li> <p>Matching FAQs</p> </li> <li> <p>Relevant documents</p> </li> </ul> <p>You can specify that the query return only one type of result using the <code>QueryResultTypeConfig</code> parameter.</p>
-    async fn query(&self, input: QueryRequest) -> Result<QueryResult, RusotoError<QueryError>> {
+    /// Presigns the request `query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_query(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: QueryRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_query_request(&self, input: QueryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("Query");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.Query");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_data_source_sync_job`, also returning the metadata of the response.
+    pub async fn start_data_source_sync_job_with_metadata(
+        &self,
+        input: StartDataSourceSyncJobRequest,
+    ) -> Result<
+        (StartDataSourceSyncJobResponse, ResponseMetadata),
+        RusotoError<StartDataSourceSyncJobError>,
+    > {
+        let request = self.build_start_data_source_sync_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<QueryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartDataSourceSyncJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(QueryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartDataSourceSyncJobError::from_response(response))
         }
     }
 
-    /// <p>Starts a synchronization job for a data source. If a synchronization job is already in progress, Amazon Kendra returns a <code>ResourceInUseException</code> exception.</p>
-    async fn start_data_source_sync_job(
+    /// Presigns the request `start_data_source_sync_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_data_source_sync_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDataSourceSyncJobRequest,
-    ) -> Result<StartDataSourceSyncJobResponse, RusotoError<StartDataSourceSyncJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_data_source_sync_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_data_source_sync_job_request(
+        &self,
+        input: StartDataSourceSyncJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("StartDataSourceSyncJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3051,28 +3671,50 @@ impl Kendra for KendraClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_data_source_sync_job`, also returning the metadata of the response.
+    pub async fn stop_data_source_sync_job_with_metadata(
+        &self,
+        input: StopDataSourceSyncJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopDataSourceSyncJobError>> {
+        let request = self.build_stop_data_source_sync_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartDataSourceSyncJobResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDataSourceSyncJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopDataSourceSyncJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a running synchronization job. You can't stop a scheduled synchronization job.</p>
-    async fn stop_data_source_sync_job(
+    /// Presigns the request `stop_data_source_sync_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_data_source_sync_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopDataSourceSyncJobRequest,
-    ) -> Result<(), RusotoError<StopDataSourceSyncJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_data_source_sync_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_data_source_sync_job_request(
+        &self,
+        input: StopDataSourceSyncJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("StopDataSourceSyncJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3082,99 +3724,344 @@ impl Kendra for KendraClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `submit_feedback`, also returning the metadata of the response.
+    pub async fn submit_feedback_with_metadata(
+        &self,
+        input: SubmitFeedbackRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<SubmitFeedbackError>> {
+        let request = self.build_submit_feedback_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopDataSourceSyncJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SubmitFeedbackError::from_response(response))
         }
     }
 
-    /// <p>Enables you to provide feedback to Amazon Kendra to improve the performance of the service. </p>
-    async fn submit_feedback(
+    /// Presigns the request `submit_feedback` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_submit_feedback(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SubmitFeedbackRequest,
-    ) -> Result<(), RusotoError<SubmitFeedbackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_submit_feedback_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_submit_feedback_request(&self, input: SubmitFeedbackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("SubmitFeedback");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.SubmitFeedback");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_data_source`, also returning the metadata of the response.
+    pub async fn update_data_source_with_metadata(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateDataSourceError>> {
+        let request = self.build_update_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SubmitFeedbackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing Amazon Kendra data source.</p>
-    async fn update_data_source(
+    /// Presigns the request `update_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDataSourceRequest,
-    ) -> Result<(), RusotoError<UpdateDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_data_source_request(&self, input: UpdateDataSourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("UpdateDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.UpdateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_index`, also returning the metadata of the response.
+    pub async fn update_index_with_metadata(
+        &self,
+        input: UpdateIndexRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateIndexError>> {
+        let request = self.build_update_index_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDataSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateIndexError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing Amazon Kendra index.</p>
-    async fn update_index(
+    /// Presigns the request `update_index` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_index(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateIndexRequest,
-    ) -> Result<(), RusotoError<UpdateIndexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_index_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_index_request(&self, input: UpdateIndexRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kendra", &self.region, "/");
+        request.set_operation("UpdateIndex");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSKendraFrontendService.UpdateIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Kendra for KendraClient {
+    /// <p>Removes one or more documents from an index. The documents must have been added with the <a>BatchPutDocument</a> operation.</p> <p>The documents are deleted asynchronously. You can see the progress of the deletion by using AWS CloudWatch. Any error messages releated to the processing of the batch are sent to you CloudWatch log.</p>
+    async fn batch_delete_document(
+        &self,
+        input: BatchDeleteDocumentRequest,
+    ) -> Result<BatchDeleteDocumentResponse, RusotoError<BatchDeleteDocumentError>> {
+        self.batch_delete_document_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIndexError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more documents to an index.</p> <p>The <code>BatchPutDocument</code> operation enables you to ingest inline documents or a set of documents stored in an Amazon S3 bucket. Use this operation to ingest your text and unstructured text into an index, add custom attributes to the documents, and to attach an access control list to the documents added to the index.</p> <p>The documents are indexed asynchronously. You can see the progress of the batch using AWS CloudWatch. Any error messages related to processing the batch are sent to your AWS CloudWatch log.</p>
+    async fn batch_put_document(
+        &self,
+        input: BatchPutDocumentRequest,
+    ) -> Result<BatchPutDocumentResponse, RusotoError<BatchPutDocumentError>> {
+        self.batch_put_document_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a data source that you use to with an Amazon Kendra index. </p> <p>You specify a name, connector type and description for your data source. You can choose between an S3 connector, a SharePoint Online connector, and a database connector.</p> <p>You also specify configuration information such as document metadata (author, source URI, and so on) and user context information.</p> <p> <code>CreateDataSource</code> is a synchronous operation. The operation returns 200 if the data source was successfully created. Otherwise, an exception is raised.</p>
+    async fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        self.create_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an new set of frequently asked question (FAQ) questions and answers.</p>
+    async fn create_faq(
+        &self,
+        input: CreateFaqRequest,
+    ) -> Result<CreateFaqResponse, RusotoError<CreateFaqError>> {
+        self.create_faq_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Amazon Kendra index. Index creation is an asynchronous operation. To determine if index creation has completed, check the <code>Status</code> field returned from a call to . The <code>Status</code> field is set to <code>ACTIVE</code> when the index is ready to use.</p> <p>Once the index is active you can index your documents using the operation or using one of the supported data sources. </p>
+    async fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
+        self.create_index_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes an FAQ from an index.</p>
+    async fn delete_faq(&self, input: DeleteFaqRequest) -> Result<(), RusotoError<DeleteFaqError>> {
+        self.delete_faq_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing Amazon Kendra index. An exception is not thrown if the index is already being deleted. While the index is being deleted, the <code>Status</code> field returned by a call to the <a>DescribeIndex</a> operation is set to <code>DELETING</code>.</p>
+    async fn delete_index(
+        &self,
+        input: DeleteIndexRequest,
+    ) -> Result<(), RusotoError<DeleteIndexError>> {
+        self.delete_index_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a Amazon Kendra data source.</p>
+    async fn describe_data_source(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
+        self.describe_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about an FAQ list.</p>
+    async fn describe_faq(
+        &self,
+        input: DescribeFaqRequest,
+    ) -> Result<DescribeFaqResponse, RusotoError<DescribeFaqError>> {
+        self.describe_faq_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing Amazon Kendra index</p>
+    async fn describe_index(
+        &self,
+        input: DescribeIndexRequest,
+    ) -> Result<DescribeIndexResponse, RusotoError<DescribeIndexError>> {
+        self.describe_index_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets statistics about synchronizing Amazon Kendra with a data source.</p>
+    async fn list_data_source_sync_jobs(
+        &self,
+        input: ListDataSourceSyncJobsRequest,
+    ) -> Result<ListDataSourceSyncJobsResponse, RusotoError<ListDataSourceSyncJobsError>> {
+        self.list_data_source_sync_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the data sources that you have created.</p>
+    async fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+        self.list_data_sources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of FAQ lists associated with an index.</p>
+    async fn list_faqs(
+        &self,
+        input: ListFaqsRequest,
+    ) -> Result<ListFaqsResponse, RusotoError<ListFaqsError>> {
+        self.list_faqs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the Amazon Kendra indexes that you have created.</p>
+    async fn list_indices(
+        &self,
+        input: ListIndicesRequest,
+    ) -> Result<ListIndicesResponse, RusotoError<ListIndicesError>> {
+        self.list_indices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches an active index. Use this API to search your documents using query. The <code>Query</code> operation enables to do faceted search and to filter results based on document attributes.</p> <p>It also enables you to provide user context that Amazon Kendra uses to enforce document access control in the search results. </p> <p>Amazon Kendra searches your index for text content and question and answer (FAQ) content. By default the response contains three types of results.</p> <ul> <li> <p>Relevant passages</p> </li> <li> <p>Matching FAQs</p> </li> <li> <p>Relevant documents</p> </li> </ul> <p>You can specify that the query return only one type of result using the <code>QueryResultTypeConfig</code> parameter.</p>
+    async fn query(&self, input: QueryRequest) -> Result<QueryResult, RusotoError<QueryError>> {
+        self.query_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a synchronization job for a data source. If a synchronization job is already in progress, Amazon Kendra returns a <code>ResourceInUseException</code> exception.</p>
+    async fn start_data_source_sync_job(
+        &self,
+        input: StartDataSourceSyncJobRequest,
+    ) -> Result<StartDataSourceSyncJobResponse, RusotoError<StartDataSourceSyncJobError>> {
+        self.start_data_source_sync_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a running synchronization job. You can't stop a scheduled synchronization job.</p>
+    async fn stop_data_source_sync_job(
+        &self,
+        input: StopDataSourceSyncJobRequest,
+    ) -> Result<(), RusotoError<StopDataSourceSyncJobError>> {
+        self.stop_data_source_sync_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables you to provide feedback to Amazon Kendra to improve the performance of the service. </p>
+    async fn submit_feedback(
+        &self,
+        input: SubmitFeedbackRequest,
+    ) -> Result<(), RusotoError<SubmitFeedbackError>> {
+        self.submit_feedback_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing Amazon Kendra data source.</p>
+    async fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<(), RusotoError<UpdateDataSourceError>> {
+        self.update_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing Amazon Kendra index.</p>
+    async fn update_index(
+        &self,
+        input: UpdateIndexRequest,
+    ) -> Result<(), RusotoError<UpdateIndexError>> {
+        self.update_index_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -71,6 +71,7 @@ pub struct StartSelector {
 
 /// Errors returned by GetMedia
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMediaError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -84,6 +85,8 @@ pub enum GetMediaError {
     NotAuthorized(String),
     /// <p>Status Code: 404, The stream with the given name does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMediaError {
@@ -91,25 +94,28 @@ impl GetMediaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetMediaError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(GetMediaError::ClientLimitExceeded(err.msg), &res)
                 }
                 "ConnectionLimitExceededException" => {
-                    return RusotoError::Service(GetMediaError::ConnectionLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetMediaError::ConnectionLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetMediaError::InvalidArgument(err.msg))
+                    return RusotoError::service(GetMediaError::InvalidArgument(err.msg), &res)
                 }
                 "InvalidEndpointException" => {
-                    return RusotoError::Service(GetMediaError::InvalidEndpoint(err.msg))
+                    return RusotoError::service(GetMediaError::InvalidEndpoint(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetMediaError::NotAuthorized(err.msg))
+                    return RusotoError::service(GetMediaError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMediaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetMediaError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMediaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -125,6 +131,7 @@ impl fmt::Display for GetMediaError {
             GetMediaError::InvalidEndpoint(ref cause) => write!(f, "{}", cause),
             GetMediaError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             GetMediaError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetMediaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -149,6 +156,7 @@ impl KinesisVideoMediaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KinesisVideoMediaClient {
         KinesisVideoMediaClient {
             client: Client::shared(),
@@ -174,30 +182,42 @@ impl KinesisVideoMediaClient {
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoMediaClient {
         KinesisVideoMediaClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KinesisVideoMediaClient> {
+        rusoto_core::ClientBuilder::new(KinesisVideoMediaClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KinesisVideoMediaClient {
+        KinesisVideoMediaClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl KinesisVideoMedia for KinesisVideoMediaClient {
-    /// <p><p> Use this API to retrieve media content from a Kinesis video stream. In the request, you identify the stream name or stream Amazon Resource Name (ARN), and the starting chunk. Kinesis Video Streams then returns a stream of chunks in order by fragment number.</p> <note> <p>You must first call the <code>GetDataEndpoint</code> API to get an endpoint. Then send the <code>GetMedia</code> requests to this endpoint using the <a href="https://docs.aws.amazon.com/cli/latest/reference/">--endpoint-url parameter</a>. </p> </note> <p>When you put media data (fragments) on a stream, Kinesis Video Streams stores each incoming fragment and related metadata in what is called a &quot;chunk.&quot; For more information, see <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_dataplane_PutMedia.html">PutMedia</a>. The <code>GetMedia</code> API returns a stream of these chunks starting from the chunk that you specify in the request. </p> <p>The following limits apply when using the <code>GetMedia</code> API:</p> <ul> <li> <p>A client can call <code>GetMedia</code> up to five times per second per stream. </p> </li> <li> <p>Kinesis Video Streams sends media data at a rate of up to 25 megabytes per second (or 200 megabits per second) during a <code>GetMedia</code> session. </p> </li> </ul> <note> <p>If an error is thrown after invoking a Kinesis Video Streams media API, in addition to the HTTP status code and the response body, it includes the following pieces of information: </p> <ul> <li> <p> <code>x-amz-ErrorType</code> HTTP header – contains a more specific error type in addition to what the HTTP status code provides. </p> </li> <li> <p> <code>x-amz-RequestId</code> HTTP header – if you want to report an issue to AWS, the support team can better diagnose the problem if given the Request Id.</p> </li> </ul> <p>Both the HTTP status code and the ErrorType header can be utilized to make programmatic decisions about whether errors are retry-able and under what conditions, as well as provide information on what actions the client programmer might need to take in order to successfully try again.</p> <p>For more information, see the <b>Errors</b> section at the bottom of this topic, as well as <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/CommonErrors.html">Common Errors</a>. </p> </note></p>
-    async fn get_media(
+impl KinesisVideoMediaClient {
+    /// Calls `get_media`, also returning the metadata of the response.
+    pub async fn get_media_with_metadata(
         &self,
         input: GetMediaInput,
-    ) -> Result<GetMediaOutput, RusotoError<GetMediaError>> {
-        let request_uri = "/getMedia";
-
-        let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<(GetMediaOutput, ResponseMetadata), RusotoError<GetMediaError>> {
+        let request = self.build_get_media_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
 
             let mut result = GetMediaOutput::default();
             result.payload = Some(response.body);
@@ -207,10 +227,49 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
                 result.content_type = Some(value)
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetMediaError::from_response(response))
         }
     }
+
+    /// Presigns the request `get_media` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_media(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetMediaInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_media_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_media_request(&self, input: GetMediaInput) -> SignedRequest {
+        let request_uri = "/getMedia";
+
+        let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetMedia");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+}
+
+#[async_trait]
+impl KinesisVideoMedia for KinesisVideoMediaClient {
+    /// <p><p> Use this API to retrieve media content from a Kinesis video stream. In the request, you identify the stream name or stream Amazon Resource Name (ARN), and the starting chunk. Kinesis Video Streams then returns a stream of chunks in order by fragment number.</p> <note> <p>You must first call the <code>GetDataEndpoint</code> API to get an endpoint. Then send the <code>GetMedia</code> requests to this endpoint using the <a href="https://docs.aws.amazon.com/cli/latest/reference/">--endpoint-url parameter</a>. </p> </note> <p>When you put media data (fragments) on a stream, Kinesis Video Streams stores each incoming fragment and related metadata in what is called a &quot;chunk.&quot; For more information, see <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_dataplane_PutMedia.html">PutMedia</a>. The <code>GetMedia</code> API returns a stream of these chunks starting from the chunk that you specify in the request. </p> <p>The following limits apply when using the <code>GetMedia</code> API:</p> <ul> <li> <p>A client can call <code>GetMedia</code> up to five times per second per stream. </p> </li> <li> <p>Kinesis Video Streams sends media data at a rate of up to 25 megabytes per second (or 200 megabits per second) during a <code>GetMedia</code> session. </p> </li> </ul> <note> <p>If an error is thrown after invoking a Kinesis Video Streams media API, in addition to the HTTP status code and the response body, it includes the following pieces of information: </p> <ul> <li> <p> <code>x-amz-ErrorType</code> HTTP header – contains a more specific error type in addition to what the HTTP status code provides. </p> </li> <li> <p> <code>x-amz-RequestId</code> HTTP header – if you want to report an issue to AWS, the support team can better diagnose the problem if given the Request Id.</p> </li> </ul> <p>Both the HTTP status code and the ErrorType header can be utilized to make programmatic decisions about whether errors are retry-able and under what conditions, as well as provide information on what actions the client programmer might need to take in order to successfully try again.</p> <p>For more information, see the <b>Errors</b> section at the bottom of this topic, as well as <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/CommonErrors.html">Common Errors</a>. </p> </note></p>
+    async fn get_media(
+        &self,
+        input: GetMediaInput,
+    ) -> Result<GetMediaOutput, RusotoError<GetMediaError>> {
+        self.get_media_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -100,6 +100,7 @@ pub struct SendAlexaOfferToMasterResponse {
 
 /// Errors returned by GetIceServerConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIceServerConfigError {
     /// <p>Your request was throttled because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -113,6 +114,8 @@ pub enum GetIceServerConfigError {
     ResourceNotFound(String),
     /// <p>If the client session is expired. Once the client is connected, the session is valid for 45 minutes. Client should reconnect to the channel to continue sending/receiving messages.</p>
     SessionExpired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIceServerConfigError {
@@ -120,27 +123,43 @@ impl GetIceServerConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetIceServerConfigError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIceServerConfigError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetIceServerConfigError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        GetIceServerConfigError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidClientException" => {
-                    return RusotoError::Service(GetIceServerConfigError::InvalidClient(err.msg))
+                    return RusotoError::service(
+                        GetIceServerConfigError::InvalidClient(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIceServerConfigError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        GetIceServerConfigError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetIceServerConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetIceServerConfigError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "SessionExpiredException" => {
-                    return RusotoError::Service(GetIceServerConfigError::SessionExpired(err.msg))
+                    return RusotoError::service(
+                        GetIceServerConfigError::SessionExpired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIceServerConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -156,12 +175,14 @@ impl fmt::Display for GetIceServerConfigError {
             GetIceServerConfigError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             GetIceServerConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetIceServerConfigError::SessionExpired(ref cause) => write!(f, "{}", cause),
+            GetIceServerConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIceServerConfigError {}
 /// Errors returned by SendAlexaOfferToMaster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendAlexaOfferToMasterError {
     /// <p>Your request was throttled because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -171,6 +192,8 @@ pub enum SendAlexaOfferToMasterError {
     NotAuthorized(String),
     /// <p>The specified resource is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendAlexaOfferToMasterError {
@@ -178,27 +201,31 @@ impl SendAlexaOfferToMasterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendAlexaOfferToMasterError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendAlexaOfferToMasterError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendAlexaOfferToMasterError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendAlexaOfferToMasterError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendAlexaOfferToMasterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -212,6 +239,7 @@ impl fmt::Display for SendAlexaOfferToMasterError {
             SendAlexaOfferToMasterError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             SendAlexaOfferToMasterError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             SendAlexaOfferToMasterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            SendAlexaOfferToMasterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -242,6 +270,7 @@ impl KinesisVideoSignalingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KinesisVideoSignalingClient {
         KinesisVideoSignalingClient {
             client: Client::shared(),
@@ -267,67 +296,162 @@ impl KinesisVideoSignalingClient {
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoSignalingClient {
         KinesisVideoSignalingClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KinesisVideoSignalingClient> {
+        rusoto_core::ClientBuilder::new(KinesisVideoSignalingClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KinesisVideoSignalingClient {
+        KinesisVideoSignalingClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl KinesisVideoSignaling for KinesisVideoSignalingClient {
-    /// <p>Gets the Interactive Connectivity Establishment (ICE) server configuration information, including URIs, username, and password which can be used to configure the WebRTC connection. The ICE component uses this configuration information to setup the WebRTC connection, including authenticating with the Traversal Using Relays around NAT (TURN) relay server. </p> <p>TURN is a protocol that is used to improve the connectivity of peer-to-peer applications. By providing a cloud-based relay service, TURN ensures that a connection can be established even when one or more peers are incapable of a direct peer-to-peer connection. For more information, see <a href="https://tools.ietf.org/html/draft-uberti-rtcweb-turn-rest-00">A REST API For Access To TURN Services</a>.</p> <p> You can invoke this API to establish a fallback mechanism in case either of the peers is unable to establish a direct peer-to-peer connection over a signaling channel. You must specify either a signaling channel ARN or the client ID in order to invoke this API.</p>
-    async fn get_ice_server_config(
+impl KinesisVideoSignalingClient {
+    /// Calls `get_ice_server_config`, also returning the metadata of the response.
+    pub async fn get_ice_server_config_with_metadata(
         &self,
         input: GetIceServerConfigRequest,
-    ) -> Result<GetIceServerConfigResponse, RusotoError<GetIceServerConfigError>> {
-        let request_uri = "/v1/get-ice-server-config";
-
-        let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<(GetIceServerConfigResponse, ResponseMetadata), RusotoError<GetIceServerConfigError>>
+    {
+        let request = self.build_get_ice_server_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetIceServerConfigResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetIceServerConfigError::from_response(response))
         }
     }
 
-    /// <p>This API allows you to connect WebRTC-enabled devices with Alexa display devices. When invoked, it sends the Alexa Session Description Protocol (SDP) offer to the master peer. The offer is delivered as soon as the master is connected to the specified signaling channel. This API returns the SDP answer from the connected master. If the master is not connected to the signaling channel, redelivery requests are made until the message expires.</p>
-    async fn send_alexa_offer_to_master(
+    /// Presigns the request `get_ice_server_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ice_server_config(
         &self,
-        input: SendAlexaOfferToMasterRequest,
-    ) -> Result<SendAlexaOfferToMasterResponse, RusotoError<SendAlexaOfferToMasterError>> {
-        let request_uri = "/v1/send-alexa-offer-to-master";
+        presigner: &rusoto_core::Presigner,
+        input: GetIceServerConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ice_server_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ice_server_config_request(
+        &self,
+        input: GetIceServerConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v1/get-ice-server-config";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetIceServerConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `send_alexa_offer_to_master`, also returning the metadata of the response.
+    pub async fn send_alexa_offer_to_master_with_metadata(
+        &self,
+        input: SendAlexaOfferToMasterRequest,
+    ) -> Result<
+        (SendAlexaOfferToMasterResponse, ResponseMetadata),
+        RusotoError<SendAlexaOfferToMasterError>,
+    > {
+        let request = self.build_send_alexa_offer_to_master_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SendAlexaOfferToMasterResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(SendAlexaOfferToMasterError::from_response(response))
         }
     }
+
+    /// Presigns the request `send_alexa_offer_to_master` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_alexa_offer_to_master(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SendAlexaOfferToMasterRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_alexa_offer_to_master_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_alexa_offer_to_master_request(
+        &self,
+        input: SendAlexaOfferToMasterRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v1/send-alexa-offer-to-master";
+
+        let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("SendAlexaOfferToMaster");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+}
+
+#[async_trait]
+impl KinesisVideoSignaling for KinesisVideoSignalingClient {
+    /// <p>Gets the Interactive Connectivity Establishment (ICE) server configuration information, including URIs, username, and password which can be used to configure the WebRTC connection. The ICE component uses this configuration information to setup the WebRTC connection, including authenticating with the Traversal Using Relays around NAT (TURN) relay server. </p> <p>TURN is a protocol that is used to improve the connectivity of peer-to-peer applications. By providing a cloud-based relay service, TURN ensures that a connection can be established even when one or more peers are incapable of a direct peer-to-peer connection. For more information, see <a href="https://tools.ietf.org/html/draft-uberti-rtcweb-turn-rest-00">A REST API For Access To TURN Services</a>.</p> <p> You can invoke this API to establish a fallback mechanism in case either of the peers is unable to establish a direct peer-to-peer connection over a signaling channel. You must specify either a signaling channel ARN or the client ID in order to invoke this API.</p>
+    async fn get_ice_server_config(
+        &self,
+        input: GetIceServerConfigRequest,
+    ) -> Result<GetIceServerConfigResponse, RusotoError<GetIceServerConfigError>> {
+        self.get_ice_server_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This API allows you to connect WebRTC-enabled devices with Alexa display devices. When invoked, it sends the Alexa Session Description Protocol (SDP) offer to the master peer. The offer is delivered as soon as the master is connected to the specified signaling channel. This API returns the SDP answer from the connected master. If the master is not connected to the signaling channel, redelivery requests are made until the message expires.</p>
+    async fn send_alexa_offer_to_master(
+        &self,
+        input: SendAlexaOfferToMasterRequest,
+    ) -> Result<SendAlexaOfferToMasterResponse, RusotoError<SendAlexaOfferToMasterError>> {
+        self.send_alexa_offer_to_master_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -1 +1,3 @@
+mod subscribe;
 
+pub use self::subscribe::SUBSCRIPTION_LIFETIME;
@@ -0,0 +1,263 @@
+//! Subscriptions to shards by enhanced fan-out consumers, renewed as Kinesis ends them.
+
+use std::time::{Duration, Instant};
+
+use futures::{stream, Stream, StreamExt};
+use rusoto_core::proto::event_stream::{Message, MessageStream};
+use rusoto_core::{ErrorMetadata, RusotoError};
+use serde::Deserialize;
+
+use crate::generated::{
+    KinesisClient, Record, StartingPosition, SubscribeToShardError, SubscribeToShardEvent,
+    SubscribeToShardInput,
+};
+
+/// How long Kinesis keeps a subscription to a shard open before ending it.
+pub const SUBSCRIPTION_LIFETIME: Duration = Duration::from_secs(5 * 60);
+
+type SubscribeError = RusotoError<SubscribeToShardError>;
+
+/// A `SubscribeToShardEvent`, whose continuation sequence number is missing once the shard is
+/// closed and all its records were sent.
+#[derive(Deserialize)]
+struct Event {
+    #[serde(rename = "ContinuationSequenceNumber")]
+    continuation_sequence_number: Option<String>,
+    #[serde(rename = "MillisBehindLatest")]
+    millis_behind_latest: i64,
+    #[serde(rename = "Records")]
+    records: Vec<Record>,
+}
+
+/// The subscriptions of a `subscribe_to_shard_stream`, each one starting at the continuation
+/// sequence number of the last event of the previous one.
+struct Subscription {
+    client: KinesisClient,
+    input: SubscribeToShardInput,
+    messages: Option<MessageStream>,
+    subscribed_at: Instant,
+    received: bool,
+    shard_closed: bool,
+}
+
+impl Subscription {
+    /// The next event of the shard, subscribing again when the current subscription ends,
+    /// `None` once the shard is closed or a subscription ends without any event.
+    async fn next_event(&mut self) -> Result<Option<SubscribeToShardEvent>, SubscribeError> {
+        loop {
+            if self.shard_closed {
+                return Ok(None);
+            }
+            let messages = match self.messages {
+                Some(ref mut messages) => messages,
+                None => {
+                    let (body, _) = self
+                        .client
+                        .subscribe_to_shard_event_stream(self.input.clone())
+                        .await?;
+                    self.subscribed_at = Instant::now();
+                    self.received = false;
+                    self.messages.get_or_insert(MessageStream::new(body))
+                }
+            };
+            let message = match messages.next().await {
+                Some(Ok(message)) => message,
+                Some(Err(err)) => return Err(RusotoError::ParseError(err.to_string())),
+                None => {
+                    self.messages = None;
+                    if self.received {
+                        continue;
+                    }
+                    return Ok(None);
+                }
+            };
+            if let Some(metadata) = message.error_metadata() {
+                return Err(service_error(metadata));
+            }
+            // The first message of a subscription is an `initial-response` event.
+            if let Some(event) = self
+                .decode_event(message)
+                .map_err(RusotoError::ParseError)?
+            {
+                if self.subscribed_at.elapsed() >= SUBSCRIPTION_LIFETIME {
+                    self.messages = None;
+                }
+                return Ok(Some(event));
+            }
+        }
+    }
+
+    /// The event sent as `message`, moving the starting position of the next subscription to
+    /// its continuation sequence number; `None` for messages of other types.
+    fn decode_event(&mut self, message: Message) -> Result<Option<SubscribeToShardEvent>, String> {
+        if message.event_type() != Some("SubscribeToShardEvent") {
+            return Ok(None);
+        }
+        let event: Event =
+            serde_json::from_slice(&message.payload).map_err(|err| err.to_string())?;
+        self.received = true;
+        match event.continuation_sequence_number {
+            Some(ref sequence_number) => {
+                self.input.starting_position = StartingPosition {
+                    type_: "AT_SEQUENCE_NUMBER".to_owned(),
+                    sequence_number: Some(sequence_number.clone()),
+                    timestamp: None,
+                };
+            }
+            None => self.shard_closed = true,
+        }
+        Ok(Some(SubscribeToShardEvent {
+            continuation_sequence_number: event.continuation_sequence_number.unwrap_or_default(),
+            millis_behind_latest: event.millis_behind_latest,
+            records: event.records,
+        }))
+    }
+}
+
+/// The error of the exception or error message of a subscription.
+fn service_error(metadata: ErrorMetadata) -> SubscribeError {
+    let message = metadata.message().unwrap_or_default().to_owned();
+    let error = match metadata.code() {
+        Some("InvalidArgumentException") => SubscribeToShardError::InvalidArgument(message),
+        Some("LimitExceededException") => SubscribeToShardError::LimitExceeded(message),
+        Some("ResourceInUseException") => SubscribeToShardError::ResourceInUse(message),
+        Some("ResourceNotFoundException") => SubscribeToShardError::ResourceNotFound(message),
+        _ => SubscribeToShardError::Unhandled(metadata.clone()),
+    };
+    RusotoError::Service(error, metadata)
+}
+
+impl KinesisClient {
+    /// Subscribes the consumer of `input` to its shard like `subscribe_to_shard`, returning
+    /// the batches of records Kinesis sends as a stream decoded as they arrive.
+    ///
+    /// Kinesis ends subscriptions after `SUBSCRIPTION_LIFETIME`, so the stream subscribes
+    /// again once one ends or has lasted that long, at the continuation sequence number of
+    /// the last event. It ends when the shard is closed, with an event whose continuation
+    /// sequence number is empty, when a subscription ends without any event, and after the
+    /// first error.
+    ///
+    /// `SubscribeToShard` is only served over HTTP/2, which the client negotiates by default.
+    ///
+    /// ```rust,ignore
+    /// let mut events = client.subscribe_to_shard_stream(input);
+    /// while let Some(event) = events.try_next().await? {
+    ///     for record in event.records {
+    ///         process(record.data);
+    ///     }
+    /// }
+    /// ```
+    pub fn subscribe_to_shard_stream(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> impl Stream<Item = Result<SubscribeToShardEvent, SubscribeError>> + Send {
+        let subscription = Subscription {
+            client: self.clone(),
+            input,
+            messages: None,
+            subscribed_at: Instant::now(),
+            received: false,
+            shard_closed: false,
+        };
+        stream::unfold(Some(subscription), |subscription| async move {
+            let mut subscription = subscription?;
+            match subscription.next_event().await {
+                Ok(Some(event)) => Some((Ok(event), Some(subscription))),
+                Ok(None) => None,
+                Err(err) => Some((Err(err), None)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    fn input() -> SubscribeToShardInput {
+        SubscribeToShardInput {
+            consumer_arn: "arn:aws:kinesis:us-east-1:123456789012:stream/s/consumer/c:1".to_owned(),
+            shard_id: "shardId-000000000000".to_owned(),
+            starting_position: StartingPosition {
+                type_: "LATEST".to_owned(),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn body(messages: &[Message]) -> Vec<u8> {
+        messages
+            .iter()
+            .flat_map(|message| message.encode().unwrap().to_vec())
+            .collect()
+    }
+
+    fn event(continuation: Option<&str>, data: &str) -> Message {
+        let payload = serde_json::json!({
+            "ContinuationSequenceNumber": continuation,
+            "MillisBehindLatest": 0,
+            "Records": [{"Data": data, "PartitionKey": "key", "SequenceNumber": "1"}],
+        });
+        Message::event("SubscribeToShardEvent", payload.to_string())
+    }
+
+    /// A subscription answered with `messages`, starting at the position of `starting_type`.
+    fn subscription(starting_type: &'static str, messages: &[Message]) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_request_checker(move |request| {
+                let payload = match request.payload {
+                    Some(SignedRequestPayload::Buffer(ref payload)) => payload,
+                    _ => panic!("unexpected payload"),
+                };
+                let input: serde_json::Value = serde_json::from_slice(payload).unwrap();
+                assert_eq!(input["StartingPosition"]["Type"], starting_type);
+            })
+            .with_binary_body(&body(messages))
+    }
+
+    #[tokio::test]
+    async fn resubscribes_at_the_continuation_sequence_number() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            subscription(
+                "LATEST",
+                &[
+                    Message::event("initial-response", "{}"),
+                    event(Some("2"), "YQ=="),
+                    event(Some("3"), "Yg=="),
+                ],
+            ),
+            subscription("AT_SEQUENCE_NUMBER", &[event(None, "Yw==")]),
+        ]);
+        let client = KinesisClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let events: Vec<_> = client.subscribe_to_shard_stream(input()).collect().await;
+        let data: Vec<_> = events
+            .into_iter()
+            .map(|event| event.unwrap().records[0].data.clone())
+            .collect();
+        assert_eq!(data, vec![&b"a"[..], &b"b"[..], &b"c"[..]]);
+    }
+
+    #[tokio::test]
+    async fn ends_with_the_exceptions_of_subscriptions() {
+        let exception = Message::new(
+            vec![(":message-type".to_owned(), "exception".into())],
+            r#"{"message": "consumer in use"}"#,
+        )
+        .with_header(":exception-type", "ResourceInUseException");
+        let dispatcher = subscription("LATEST", &[event(Some("2"), "YQ=="), exception]);
+        let client = KinesisClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let events: Vec<_> = client.subscribe_to_shard_stream(input()).collect().await;
+        assert_eq!(events.len(), 2);
+        match events[1] {
+            Err(RusotoError::Service(SubscribeToShardError::ResourceInUse(ref message), _)) => {
+                assert_eq!(message, "consumer in use")
+            }
+            ref result => panic!("unexpected result {:?}", result),
+        }
+    }
+}
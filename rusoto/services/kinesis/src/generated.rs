@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1073,6 +1073,7 @@ pub struct UpdateShardCountOutput {
 
 /// Errors returned by AddTagsToStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToStreamError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1082,6 +1083,8 @@ pub enum AddTagsToStreamError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToStreamError {
@@ -1089,19 +1092,25 @@ impl AddTagsToStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddTagsToStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        AddTagsToStreamError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddTagsToStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(AddTagsToStreamError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddTagsToStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(AddTagsToStreamError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsToStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AddTagsToStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsToStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1115,12 +1124,14 @@ impl fmt::Display for AddTagsToStreamError {
             AddTagsToStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             AddTagsToStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             AddTagsToStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AddTagsToStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToStreamError {}
 /// Errors returned by CreateStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStreamError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1128,6 +1139,8 @@ pub enum CreateStreamError {
     LimitExceeded(String),
     /// <p>The resource is not available for this operation. For successful operation, the resource must be in the <code>ACTIVE</code> state.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStreamError {
@@ -1135,16 +1148,16 @@ impl CreateStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(CreateStreamError::InvalidArgument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateStreamError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateStreamError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1157,12 +1170,14 @@ impl fmt::Display for CreateStreamError {
             CreateStreamError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             CreateStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            CreateStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStreamError {}
 /// Errors returned by DecreaseStreamRetentionPeriod
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DecreaseStreamRetentionPeriodError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1172,6 +1187,8 @@ pub enum DecreaseStreamRetentionPeriodError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DecreaseStreamRetentionPeriodError {
@@ -1181,27 +1198,36 @@ impl DecreaseStreamRetentionPeriodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DecreaseStreamRetentionPeriodError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DecreaseStreamRetentionPeriodError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DecreaseStreamRetentionPeriodError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DecreaseStreamRetentionPeriodError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DecreaseStreamRetentionPeriodError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DecreaseStreamRetentionPeriodError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DecreaseStreamRetentionPeriodError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1219,12 +1245,16 @@ impl fmt::Display for DecreaseStreamRetentionPeriodError {
             DecreaseStreamRetentionPeriodError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DecreaseStreamRetentionPeriodError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DecreaseStreamRetentionPeriodError {}
 /// Errors returned by DeleteStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStreamError {
     /// <p>The requested resource exceeds the maximum number allowed, or the number of concurrent stream requests exceeds the maximum number allowed. </p>
     LimitExceeded(String),
@@ -1232,6 +1262,8 @@ pub enum DeleteStreamError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStreamError {
@@ -1239,16 +1271,16 @@ impl DeleteStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteStreamError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteStreamError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteStreamError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1261,12 +1293,14 @@ impl fmt::Display for DeleteStreamError {
             DeleteStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStreamError {}
 /// Errors returned by DeregisterStreamConsumer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterStreamConsumerError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1274,6 +1308,8 @@ pub enum DeregisterStreamConsumerError {
     LimitExceeded(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterStreamConsumerError {
@@ -1281,22 +1317,25 @@ impl DeregisterStreamConsumerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeregisterStreamConsumerError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterStreamConsumerError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeregisterStreamConsumerError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterStreamConsumerError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterStreamConsumerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterStreamConsumerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterStreamConsumerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1309,15 +1348,19 @@ impl fmt::Display for DeregisterStreamConsumerError {
             DeregisterStreamConsumerError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             DeregisterStreamConsumerError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeregisterStreamConsumerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterStreamConsumerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterStreamConsumerError {}
 /// Errors returned by DescribeLimits
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLimitsError {
     /// <p>The requested resource exceeds the maximum number allowed, or the number of concurrent stream requests exceeds the maximum number allowed. </p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLimitsError {
@@ -1325,10 +1368,10 @@ impl DescribeLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeLimitsError::LimitExceeded(err.msg))
+                    return RusotoError::service(DescribeLimitsError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLimitsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1339,17 +1382,21 @@ impl fmt::Display for DescribeLimitsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeLimitsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            DescribeLimitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLimitsError {}
 /// Errors returned by DescribeStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStreamError {
     /// <p>The requested resource exceeds the maximum number allowed, or the number of concurrent stream requests exceeds the maximum number allowed. </p>
     LimitExceeded(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStreamError {
@@ -1357,13 +1404,16 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(DescribeStreamError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1375,12 +1425,14 @@ impl fmt::Display for DescribeStreamError {
         match *self {
             DescribeStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DescribeStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStreamError {}
 /// Errors returned by DescribeStreamConsumer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStreamConsumerError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1388,6 +1440,8 @@ pub enum DescribeStreamConsumerError {
     LimitExceeded(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStreamConsumerError {
@@ -1395,22 +1449,25 @@ impl DescribeStreamConsumerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeStreamConsumerError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStreamConsumerError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamConsumerError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStreamConsumerError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamConsumerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStreamConsumerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStreamConsumerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1423,17 +1480,21 @@ impl fmt::Display for DescribeStreamConsumerError {
             DescribeStreamConsumerError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             DescribeStreamConsumerError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DescribeStreamConsumerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStreamConsumerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStreamConsumerError {}
 /// Errors returned by DescribeStreamSummary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStreamSummaryError {
     /// <p>The requested resource exceeds the maximum number allowed, or the number of concurrent stream requests exceeds the maximum number allowed. </p>
     LimitExceeded(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStreamSummaryError {
@@ -1441,15 +1502,19 @@ impl DescribeStreamSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamSummaryError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DescribeStreamSummaryError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamSummaryError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStreamSummaryError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStreamSummaryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1461,12 +1526,14 @@ impl fmt::Display for DescribeStreamSummaryError {
         match *self {
             DescribeStreamSummaryError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DescribeStreamSummaryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStreamSummaryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStreamSummaryError {}
 /// Errors returned by DisableEnhancedMonitoring
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableEnhancedMonitoringError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1476,6 +1543,8 @@ pub enum DisableEnhancedMonitoringError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableEnhancedMonitoringError {
@@ -1483,27 +1552,33 @@ impl DisableEnhancedMonitoringError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableEnhancedMonitoringError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableEnhancedMonitoringError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableEnhancedMonitoringError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableEnhancedMonitoringError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DisableEnhancedMonitoringError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1517,12 +1592,14 @@ impl fmt::Display for DisableEnhancedMonitoringError {
             DisableEnhancedMonitoringError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DisableEnhancedMonitoringError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DisableEnhancedMonitoringError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisableEnhancedMonitoringError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableEnhancedMonitoringError {}
 /// Errors returned by EnableEnhancedMonitoring
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableEnhancedMonitoringError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1532,6 +1609,8 @@ pub enum EnableEnhancedMonitoringError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableEnhancedMonitoringError {
@@ -1539,27 +1618,31 @@ impl EnableEnhancedMonitoringError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableEnhancedMonitoringError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableEnhancedMonitoringError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableEnhancedMonitoringError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableEnhancedMonitoringError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableEnhancedMonitoringError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1573,12 +1656,14 @@ impl fmt::Display for EnableEnhancedMonitoringError {
             EnableEnhancedMonitoringError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             EnableEnhancedMonitoringError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             EnableEnhancedMonitoringError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            EnableEnhancedMonitoringError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableEnhancedMonitoringError {}
 /// Errors returned by GetRecords
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRecordsError {
     /// <p>The provided iterator exceeds the maximum age allowed.</p>
     ExpiredIterator(String),
@@ -1600,6 +1685,8 @@ pub enum GetRecordsError {
     ProvisionedThroughputExceeded(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRecordsError {
@@ -1607,39 +1694,40 @@ impl GetRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredIteratorException" => {
-                    return RusotoError::Service(GetRecordsError::ExpiredIterator(err.msg))
+                    return RusotoError::service(GetRecordsError::ExpiredIterator(err.msg), &res)
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetRecordsError::InvalidArgument(err.msg))
+                    return RusotoError::service(GetRecordsError::InvalidArgument(err.msg), &res)
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(GetRecordsError::KMSAccessDenied(err.msg))
+                    return RusotoError::service(GetRecordsError::KMSAccessDenied(err.msg), &res)
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(GetRecordsError::KMSDisabled(err.msg))
+                    return RusotoError::service(GetRecordsError::KMSDisabled(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetRecordsError::KMSInvalidState(err.msg))
+                    return RusotoError::service(GetRecordsError::KMSInvalidState(err.msg), &res)
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(GetRecordsError::KMSNotFound(err.msg))
+                    return RusotoError::service(GetRecordsError::KMSNotFound(err.msg), &res)
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(GetRecordsError::KMSOptInRequired(err.msg))
+                    return RusotoError::service(GetRecordsError::KMSOptInRequired(err.msg), &res)
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(GetRecordsError::KMSThrottling(err.msg))
+                    return RusotoError::service(GetRecordsError::KMSThrottling(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(GetRecordsError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRecordsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetRecordsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRecordsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1659,12 +1747,14 @@ impl fmt::Display for GetRecordsError {
             GetRecordsError::KMSThrottling(ref cause) => write!(f, "{}", cause),
             GetRecordsError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             GetRecordsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetRecordsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRecordsError {}
 /// Errors returned by GetShardIterator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetShardIteratorError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1672,6 +1762,8 @@ pub enum GetShardIteratorError {
     ProvisionedThroughputExceeded(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetShardIteratorError {
@@ -1679,18 +1771,25 @@ impl GetShardIteratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetShardIteratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        GetShardIteratorError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetShardIteratorError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetShardIteratorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetShardIteratorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetShardIteratorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1705,12 +1804,14 @@ impl fmt::Display for GetShardIteratorError {
                 write!(f, "{}", cause)
             }
             GetShardIteratorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetShardIteratorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetShardIteratorError {}
 /// Errors returned by IncreaseStreamRetentionPeriod
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum IncreaseStreamRetentionPeriodError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1720,6 +1821,8 @@ pub enum IncreaseStreamRetentionPeriodError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl IncreaseStreamRetentionPeriodError {
@@ -1729,27 +1832,36 @@ impl IncreaseStreamRetentionPeriodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseStreamRetentionPeriodError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(IncreaseStreamRetentionPeriodError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        IncreaseStreamRetentionPeriodError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(IncreaseStreamRetentionPeriodError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        IncreaseStreamRetentionPeriodError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseStreamRetentionPeriodError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        IncreaseStreamRetentionPeriodError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1767,12 +1879,16 @@ impl fmt::Display for IncreaseStreamRetentionPeriodError {
             IncreaseStreamRetentionPeriodError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            IncreaseStreamRetentionPeriodError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for IncreaseStreamRetentionPeriodError {}
 /// Errors returned by ListShards
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListShardsError {
     /// <p>The pagination token passed to the operation is expired.</p>
     ExpiredNextToken(String),
@@ -1784,6 +1900,8 @@ pub enum ListShardsError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListShardsError {
@@ -1791,22 +1909,22 @@ impl ListShardsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(ListShardsError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(ListShardsError::ExpiredNextToken(err.msg), &res)
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListShardsError::InvalidArgument(err.msg))
+                    return RusotoError::service(ListShardsError::InvalidArgument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListShardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListShardsError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ListShardsError::ResourceInUse(err.msg))
+                    return RusotoError::service(ListShardsError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListShardsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListShardsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListShardsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1821,12 +1939,14 @@ impl fmt::Display for ListShardsError {
             ListShardsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListShardsError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             ListShardsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListShardsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListShardsError {}
 /// Errors returned by ListStreamConsumers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStreamConsumersError {
     /// <p>The pagination token passed to the operation is expired.</p>
     ExpiredNextToken(String),
@@ -1838,6 +1958,8 @@ pub enum ListStreamConsumersError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStreamConsumersError {
@@ -1845,26 +1967,37 @@ impl ListStreamConsumersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(ListStreamConsumersError::ExpiredNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListStreamConsumersError::ExpiredNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListStreamConsumersError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        ListStreamConsumersError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListStreamConsumersError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListStreamConsumersError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ListStreamConsumersError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        ListStreamConsumersError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListStreamConsumersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListStreamConsumersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListStreamConsumersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1879,15 +2012,19 @@ impl fmt::Display for ListStreamConsumersError {
             ListStreamConsumersError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListStreamConsumersError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             ListStreamConsumersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListStreamConsumersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStreamConsumersError {}
 /// Errors returned by ListStreams
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStreamsError {
     /// <p>The requested resource exceeds the maximum number allowed, or the number of concurrent stream requests exceeds the maximum number allowed. </p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStreamsError {
@@ -1895,10 +2032,10 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListStreamsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListStreamsError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListStreamsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1909,12 +2046,14 @@ impl fmt::Display for ListStreamsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListStreamsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            ListStreamsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStreamsError {}
 /// Errors returned by ListTagsForStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForStreamError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1922,6 +2061,8 @@ pub enum ListTagsForStreamError {
     LimitExceeded(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForStreamError {
@@ -1929,16 +2070,25 @@ impl ListTagsForStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        ListTagsForStreamError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListTagsForStreamError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTagsForStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1951,12 +2101,14 @@ impl fmt::Display for ListTagsForStreamError {
             ListTagsForStreamError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListTagsForStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListTagsForStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForStreamError {}
 /// Errors returned by MergeShards
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MergeShardsError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -1966,6 +2118,8 @@ pub enum MergeShardsError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MergeShardsError {
@@ -1973,19 +2127,19 @@ impl MergeShardsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(MergeShardsError::InvalidArgument(err.msg))
+                    return RusotoError::service(MergeShardsError::InvalidArgument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(MergeShardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(MergeShardsError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(MergeShardsError::ResourceInUse(err.msg))
+                    return RusotoError::service(MergeShardsError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(MergeShardsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(MergeShardsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(MergeShardsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1999,12 +2153,14 @@ impl fmt::Display for MergeShardsError {
             MergeShardsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             MergeShardsError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             MergeShardsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            MergeShardsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for MergeShardsError {}
 /// Errors returned by PutRecord
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRecordError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -2024,6 +2180,8 @@ pub enum PutRecordError {
     ProvisionedThroughputExceeded(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRecordError {
@@ -2031,36 +2189,37 @@ impl PutRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordError::InvalidArgument(err.msg))
+                    return RusotoError::service(PutRecordError::InvalidArgument(err.msg), &res)
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(PutRecordError::KMSAccessDenied(err.msg))
+                    return RusotoError::service(PutRecordError::KMSAccessDenied(err.msg), &res)
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(PutRecordError::KMSDisabled(err.msg))
+                    return RusotoError::service(PutRecordError::KMSDisabled(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(PutRecordError::KMSInvalidState(err.msg))
+                    return RusotoError::service(PutRecordError::KMSInvalidState(err.msg), &res)
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(PutRecordError::KMSNotFound(err.msg))
+                    return RusotoError::service(PutRecordError::KMSNotFound(err.msg), &res)
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(PutRecordError::KMSOptInRequired(err.msg))
+                    return RusotoError::service(PutRecordError::KMSOptInRequired(err.msg), &res)
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(PutRecordError::KMSThrottling(err.msg))
+                    return RusotoError::service(PutRecordError::KMSThrottling(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(PutRecordError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutRecordError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(PutRecordError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRecordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2079,12 +2238,14 @@ impl fmt::Display for PutRecordError {
             PutRecordError::KMSThrottling(ref cause) => write!(f, "{}", cause),
             PutRecordError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             PutRecordError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutRecordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRecordError {}
 /// Errors returned by PutRecords
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRecordsError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -2104,6 +2265,8 @@ pub enum PutRecordsError {
     ProvisionedThroughputExceeded(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRecordsError {
@@ -2111,36 +2274,37 @@ impl PutRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordsError::InvalidArgument(err.msg))
+                    return RusotoError::service(PutRecordsError::InvalidArgument(err.msg), &res)
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(PutRecordsError::KMSAccessDenied(err.msg))
+                    return RusotoError::service(PutRecordsError::KMSAccessDenied(err.msg), &res)
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(PutRecordsError::KMSDisabled(err.msg))
+                    return RusotoError::service(PutRecordsError::KMSDisabled(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(PutRecordsError::KMSInvalidState(err.msg))
+                    return RusotoError::service(PutRecordsError::KMSInvalidState(err.msg), &res)
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(PutRecordsError::KMSNotFound(err.msg))
+                    return RusotoError::service(PutRecordsError::KMSNotFound(err.msg), &res)
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(PutRecordsError::KMSOptInRequired(err.msg))
+                    return RusotoError::service(PutRecordsError::KMSOptInRequired(err.msg), &res)
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(PutRecordsError::KMSThrottling(err.msg))
+                    return RusotoError::service(PutRecordsError::KMSThrottling(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(PutRecordsError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutRecordsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(PutRecordsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRecordsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2159,12 +2323,14 @@ impl fmt::Display for PutRecordsError {
             PutRecordsError::KMSThrottling(ref cause) => write!(f, "{}", cause),
             PutRecordsError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             PutRecordsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutRecordsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRecordsError {}
 /// Errors returned by RegisterStreamConsumer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterStreamConsumerError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -2174,6 +2340,8 @@ pub enum RegisterStreamConsumerError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterStreamConsumerError {
@@ -2181,27 +2349,31 @@ impl RegisterStreamConsumerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterStreamConsumerError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterStreamConsumerError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterStreamConsumerError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterStreamConsumerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterStreamConsumerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2215,12 +2387,14 @@ impl fmt::Display for RegisterStreamConsumerError {
             RegisterStreamConsumerError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             RegisterStreamConsumerError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             RegisterStreamConsumerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RegisterStreamConsumerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterStreamConsumerError {}
 /// Errors returned by RemoveTagsFromStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromStreamError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -2230,6 +2404,8 @@ pub enum RemoveTagsFromStreamError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromStreamError {
@@ -2237,23 +2413,31 @@ impl RemoveTagsFromStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromStreamError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsFromStreamError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsFromStreamError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsFromStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2267,12 +2451,14 @@ impl fmt::Display for RemoveTagsFromStreamError {
             RemoveTagsFromStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RemoveTagsFromStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromStreamError {}
 /// Errors returned by SplitShard
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SplitShardError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -2282,6 +2468,8 @@ pub enum SplitShardError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SplitShardError {
@@ -2289,19 +2477,19 @@ impl SplitShardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(SplitShardError::InvalidArgument(err.msg))
+                    return RusotoError::service(SplitShardError::InvalidArgument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SplitShardError::LimitExceeded(err.msg))
+                    return RusotoError::service(SplitShardError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(SplitShardError::ResourceInUse(err.msg))
+                    return RusotoError::service(SplitShardError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SplitShardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(SplitShardError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SplitShardError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2315,12 +2503,14 @@ impl fmt::Display for SplitShardError {
             SplitShardError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             SplitShardError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             SplitShardError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            SplitShardError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SplitShardError {}
 /// Errors returned by StartStreamEncryption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartStreamEncryptionError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -2342,6 +2532,8 @@ pub enum StartStreamEncryptionError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartStreamEncryptionError {
@@ -2349,47 +2541,67 @@ impl StartStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::KMSAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSDisabled(err.msg))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::KMSDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSNotFound(err.msg))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::KMSNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSOptInRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::KMSOptInRequired(err.msg),
+                        &res,
+                    )
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSThrottling(err.msg))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::KMSThrottling(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartStreamEncryptionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartStreamEncryptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2409,12 +2621,14 @@ impl fmt::Display for StartStreamEncryptionError {
             StartStreamEncryptionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StartStreamEncryptionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StartStreamEncryptionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartStreamEncryptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartStreamEncryptionError {}
 /// Errors returned by StopStreamEncryption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopStreamEncryptionError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -2424,6 +2638,8 @@ pub enum StopStreamEncryptionError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopStreamEncryptionError {
@@ -2431,23 +2647,31 @@ impl StopStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopStreamEncryptionError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StopStreamEncryptionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StopStreamEncryptionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopStreamEncryptionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopStreamEncryptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2461,12 +2685,14 @@ impl fmt::Display for StopStreamEncryptionError {
             StopStreamEncryptionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StopStreamEncryptionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StopStreamEncryptionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopStreamEncryptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopStreamEncryptionError {}
 /// Errors returned by SubscribeToShard
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubscribeToShardError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -2476,6 +2702,8 @@ pub enum SubscribeToShardError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubscribeToShardError {
@@ -2483,19 +2711,31 @@ impl SubscribeToShardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(SubscribeToShardError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        SubscribeToShardError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SubscribeToShardError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        SubscribeToShardError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(SubscribeToShardError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        SubscribeToShardError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubscribeToShardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SubscribeToShardError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SubscribeToShardError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2509,12 +2749,14 @@ impl fmt::Display for SubscribeToShardError {
             SubscribeToShardError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             SubscribeToShardError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             SubscribeToShardError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            SubscribeToShardError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubscribeToShardError {}
 /// Errors returned by UpdateShardCount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateShardCountError {
     /// <p>A specified parameter exceeds its restrictions, is not supported, or can't be used. For more information, see the returned message.</p>
     InvalidArgument(String),
@@ -2524,6 +2766,8 @@ pub enum UpdateShardCountError {
     ResourceInUse(String),
     /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateShardCountError {
@@ -2531,19 +2775,31 @@ impl UpdateShardCountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateShardCountError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        UpdateShardCountError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateShardCountError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateShardCountError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateShardCountError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateShardCountError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateShardCountError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateShardCountError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateShardCountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2557,6 +2813,7 @@ impl fmt::Display for UpdateShardCountError {
             UpdateShardCountError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateShardCountError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateShardCountError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateShardCountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2740,6 +2997,7 @@ impl KinesisClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KinesisClient {
         KinesisClient {
             client: Client::shared(),
@@ -2765,70 +3023,159 @@ impl KinesisClient {
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisClient {
         KinesisClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KinesisClient> {
+        rusoto_core::ClientBuilder::new(KinesisClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KinesisClient {
+        KinesisClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Kinesis for KinesisClient {
-    /// <p>Adds or updates tags for the specified Kinesis data stream. Each time you invoke this operation, you can specify up to 10 tags. If you want to add more than 10 tags to your stream, you can invoke this operation multiple times. In total, each stream can have up to 50 tags.</p> <p>If tags have already been assigned to the stream, <code>AddTagsToStream</code> overwrites any existing tags that correspond to the specified tag keys.</p> <p> <a>AddTagsToStream</a> has a limit of five transactions per second per account.</p>
-    async fn add_tags_to_stream(
+impl KinesisClient {
+    /// Calls `add_tags_to_stream`, also returning the metadata of the response.
+    pub async fn add_tags_to_stream_with_metadata(
         &self,
         input: AddTagsToStreamInput,
-    ) -> Result<(), RusotoError<AddTagsToStreamError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<AddTagsToStreamError>> {
+        let request = self.build_add_tags_to_stream_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsToStreamError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_tags_to_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_stream(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_stream_request(&self, input: AddTagsToStreamInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("AddTagsToStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.AddTagsToStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_stream`, also returning the metadata of the response.
+    pub async fn create_stream_with_metadata(
+        &self,
+        input: CreateStreamInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateStreamError>> {
+        let request = self.build_create_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsToStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateStreamError::from_response(response))
         }
     }
 
-    /// <p>Creates a Kinesis data stream. A stream captures and transports data records that are continuously emitted from different data sources or <i>producers</i>. Scale-out within a stream is explicitly supported by means of shards, which are uniquely identified groups of data records in a stream.</p> <p>You specify and control the number of shards that a stream is composed of. Each shard can support reads up to five transactions per second, up to a maximum data read total of 2 MB per second. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second. If the amount of data input increases or decreases, you can add or remove shards.</p> <p>The stream name identifies the stream. The name is scoped to the AWS account used by the application. It is also scoped by AWS Region. That is, two streams in two different accounts can have the same name, and two streams in the same account, but in two different Regions, can have the same name.</p> <p> <code>CreateStream</code> is an asynchronous operation. Upon receiving a <code>CreateStream</code> request, Kinesis Data Streams immediately returns and sets the stream status to <code>CREATING</code>. After the stream is created, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. You should perform read and write operations only on an <code>ACTIVE</code> stream. </p> <p>You receive a <code>LimitExceededException</code> when making a <code>CreateStream</code> request when you try to do one of the following:</p> <ul> <li> <p>Have more than five streams in the <code>CREATING</code> state at any point in time.</p> </li> <li> <p>Create more shards than are authorized for your account.</p> </li> </ul> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>You can use <code>DescribeStream</code> to check the stream status, which is returned in <code>StreamStatus</code>.</p> <p> <a>CreateStream</a> has a limit of five transactions per second per account.</p>
-    async fn create_stream(
+    /// Presigns the request `create_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateStreamInput,
-    ) -> Result<(), RusotoError<CreateStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stream_request(&self, input: CreateStreamInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("CreateStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.CreateStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `decrease_stream_retention_period`, also returning the metadata of the response.
+    pub async fn decrease_stream_retention_period_with_metadata(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DecreaseStreamRetentionPeriodError>> {
+        let request = self.build_decrease_stream_retention_period_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DecreaseStreamRetentionPeriodError::from_response(response))
         }
     }
 
-    /// <p>Decreases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The minimum value of a stream's retention period is 24 hours.</p> <p>This operation may result in lost data. For example, if the stream's retention period is 48 hours and is decreased to 24 hours, any data already in the stream that is older than 24 hours is inaccessible.</p>
-    async fn decrease_stream_retention_period(
+    /// Presigns the request `decrease_stream_retention_period` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_decrease_stream_retention_period(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DecreaseStreamRetentionPeriodInput,
-    ) -> Result<(), RusotoError<DecreaseStreamRetentionPeriodError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_decrease_stream_retention_period_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_decrease_stream_retention_period_request(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DecreaseStreamRetentionPeriod");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2838,299 +3185,585 @@ impl Kinesis for KinesisClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_stream`, also returning the metadata of the response.
+    pub async fn delete_stream_with_metadata(
+        &self,
+        input: DeleteStreamInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteStreamError>> {
+        let request = self.build_delete_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DecreaseStreamRetentionPeriodError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteStreamError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Kinesis data stream and all its shards and data. You must shut down any applications that are operating on the stream before you delete the stream. If an application attempts to operate on a deleted stream, it receives the exception <code>ResourceNotFoundException</code>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can delete it. After a <code>DeleteStream</code> request, the specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> <p> <b>Note:</b> Kinesis Data Streams might continue to accept data read and write operations, such as <a>PutRecord</a>, <a>PutRecords</a>, and <a>GetRecords</a>, on a stream in the <code>DELETING</code> state until the stream deletion is complete.</p> <p>When you delete a stream, any shards in that stream are also deleted, and any tags are dissociated from the stream.</p> <p>You can use the <a>DescribeStream</a> operation to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <a>DeleteStream</a> has a limit of five transactions per second per account.</p>
-    async fn delete_stream(
+    /// Presigns the request `delete_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteStreamInput,
-    ) -> Result<(), RusotoError<DeleteStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stream_request(&self, input: DeleteStreamInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DeleteStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DeleteStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_stream_consumer`, also returning the metadata of the response.
+    pub async fn deregister_stream_consumer_with_metadata(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeregisterStreamConsumerError>> {
+        let request = self.build_deregister_stream_consumer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterStreamConsumerError::from_response(response))
         }
     }
 
-    /// <p>To deregister a consumer, provide its ARN. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to deregister, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream. The description of a consumer contains its name and ARN.</p> <p>This operation has a limit of five transactions per second per account.</p>
-    async fn deregister_stream_consumer(
+    /// Presigns the request `deregister_stream_consumer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_stream_consumer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterStreamConsumerInput,
-    ) -> Result<(), RusotoError<DeregisterStreamConsumerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_stream_consumer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_stream_consumer_request(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DeregisterStreamConsumer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DeregisterStreamConsumer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_limits`, also returning the metadata of the response.
+    pub async fn describe_limits_with_metadata(
+        &self,
+    ) -> Result<(DescribeLimitsOutput, ResponseMetadata), RusotoError<DescribeLimitsError>> {
+        let request = self.build_describe_limits_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLimitsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterStreamConsumerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLimitsError::from_response(response))
         }
     }
 
-    /// <p>Describes the shard limits and usage for the account.</p> <p>If you update your account limits, the old limits might be returned for a few minutes.</p> <p>This operation has a limit of one transaction per second per account.</p>
-    async fn describe_limits(
+    /// Presigns the request `describe_limits` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_limits(
         &self,
-    ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_limits_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_limits_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeLimits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeLimits");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_stream`, also returning the metadata of the response.
+    pub async fn describe_stream_with_metadata(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<(DescribeStreamOutput, ResponseMetadata), RusotoError<DescribeStreamError>> {
+        let request = self.build_describe_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeLimitsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStreamOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLimitsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStreamError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified Kinesis data stream.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), creation time, enhanced metric configuration, and shard map. The shard map is an array of shard objects. For each shard object, there is the hash key and sequence number ranges that the shard spans, and the IDs of any earlier shards that played in a role in creating the shard. Every record ingested in the stream is identified by a sequence number, which is assigned when the record is put into the stream.</p> <p>You can limit the number of shards returned by each call. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-retrieve-shards.html">Retrieving Shards from a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>There are no guarantees about the chronological order shards returned. To process shards in chronological order, use the ID of the parent shard to track the lineage to the oldest shard.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
-    async fn describe_stream(
+    /// Presigns the request `describe_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStreamInput,
-    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stream_request(&self, input: DescribeStreamInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_stream_consumer`, also returning the metadata of the response.
+    pub async fn describe_stream_consumer_with_metadata(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> Result<
+        (DescribeStreamConsumerOutput, ResponseMetadata),
+        RusotoError<DescribeStreamConsumerError>,
+    > {
+        let request = self.build_describe_stream_consumer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeStreamOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStreamConsumerOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStreamConsumerError::from_response(response))
         }
     }
 
-    /// <p>To get the description of a registered consumer, provide the ARN of the consumer. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to describe, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream.</p> <p>This operation has a limit of 20 transactions per second per account.</p>
-    async fn describe_stream_consumer(
+    /// Presigns the request `describe_stream_consumer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stream_consumer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStreamConsumerInput,
-    ) -> Result<DescribeStreamConsumerOutput, RusotoError<DescribeStreamConsumerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stream_consumer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stream_consumer_request(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeStreamConsumer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamConsumer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_stream_summary`, also returning the metadata of the response.
+    pub async fn describe_stream_summary_with_metadata(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> Result<
+        (DescribeStreamSummaryOutput, ResponseMetadata),
+        RusotoError<DescribeStreamSummaryError>,
+    > {
+        let request = self.build_describe_stream_summary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeStreamConsumerOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStreamSummaryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStreamConsumerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStreamSummaryError::from_response(response))
         }
     }
 
-    /// <p>Provides a summarized description of the specified Kinesis data stream without the shard list.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), status, record retention period, approximate creation time, monitoring, encryption details, and open shard count. </p>
-    async fn describe_stream_summary(
+    /// Presigns the request `describe_stream_summary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stream_summary(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStreamSummaryInput,
-    ) -> Result<DescribeStreamSummaryOutput, RusotoError<DescribeStreamSummaryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stream_summary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stream_summary_request(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeStreamSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamSummary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_enhanced_monitoring`, also returning the metadata of the response.
+    pub async fn disable_enhanced_monitoring_with_metadata(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> Result<
+        (EnhancedMonitoringOutput, ResponseMetadata),
+        RusotoError<DisableEnhancedMonitoringError>,
+    > {
+        let request = self.build_disable_enhanced_monitoring_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeStreamSummaryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnhancedMonitoringOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStreamSummaryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableEnhancedMonitoringError::from_response(response))
         }
     }
 
-    /// <p>Disables enhanced monitoring.</p>
-    async fn disable_enhanced_monitoring(
+    /// Presigns the request `disable_enhanced_monitoring` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_enhanced_monitoring(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableEnhancedMonitoringInput,
-    ) -> Result<EnhancedMonitoringOutput, RusotoError<DisableEnhancedMonitoringError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_enhanced_monitoring_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_enhanced_monitoring_request(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DisableEnhancedMonitoring");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.DisableEnhancedMonitoring");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_enhanced_monitoring`, also returning the metadata of the response.
+    pub async fn enable_enhanced_monitoring_with_metadata(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> Result<
+        (EnhancedMonitoringOutput, ResponseMetadata),
+        RusotoError<EnableEnhancedMonitoringError>,
+    > {
+        let request = self.build_enable_enhanced_monitoring_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<EnhancedMonitoringOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnhancedMonitoringOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableEnhancedMonitoringError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableEnhancedMonitoringError::from_response(response))
         }
     }
 
-    /// <p>Enables enhanced Kinesis data stream monitoring for shard-level metrics.</p>
-    async fn enable_enhanced_monitoring(
+    /// Presigns the request `enable_enhanced_monitoring` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_enhanced_monitoring(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableEnhancedMonitoringInput,
-    ) -> Result<EnhancedMonitoringOutput, RusotoError<EnableEnhancedMonitoringError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_enhanced_monitoring_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_enhanced_monitoring_request(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("EnableEnhancedMonitoring");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.EnableEnhancedMonitoring");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_records`, also returning the metadata of the response.
+    pub async fn get_records_with_metadata(
+        &self,
+        input: GetRecordsInput,
+    ) -> Result<(GetRecordsOutput, ResponseMetadata), RusotoError<GetRecordsError>> {
+        let request = self.build_get_records_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<EnhancedMonitoringOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRecordsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableEnhancedMonitoringError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRecordsError::from_response(response))
         }
     }
 
-    /// <p>Gets data records from a Kinesis data stream's shard.</p> <p>Specify a shard iterator using the <code>ShardIterator</code> parameter. The shard iterator specifies the position in the shard from which you want to start reading data records sequentially. If there are no records available in the portion of the shard that the iterator points to, <a>GetRecords</a> returns an empty list. It might take multiple calls to get to a portion of the shard that contains records.</p> <p>You can scale by provisioning multiple shards per stream while considering service limits (for more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>). Your application should have one thread per shard, each reading continuously from its stream. To read from a stream continually, call <a>GetRecords</a> in a loop. Use <a>GetShardIterator</a> to get the shard iterator to specify in the first <a>GetRecords</a> call. <a>GetRecords</a> returns a new shard iterator in <code>NextShardIterator</code>. Specify the shard iterator returned in <code>NextShardIterator</code> in subsequent calls to <a>GetRecords</a>. If the shard has been closed, the shard iterator can't return more data and <a>GetRecords</a> returns <code>null</code> in <code>NextShardIterator</code>. You can terminate the loop when the shard is closed, or when the shard iterator reaches the record with the sequence number or other attribute that marks it as the last record to process.</p> <p>Each data record can be up to 1 MiB in size, and each shard can read up to 2 MiB per second. You can ensure that your calls don't exceed the maximum supported size or throughput by using the <code>Limit</code> parameter to specify the maximum number of records that <a>GetRecords</a> can return. Consider your average record size when determining this limit. The maximum number of records that can be returned per call is 10,000.</p> <p>The size of the data returned by <a>GetRecords</a> varies depending on the utilization of the shard. The maximum size of data that <a>GetRecords</a> can return is 10 MiB. If a call returns this amount of data, subsequent calls made within the next 5 seconds throw <code>ProvisionedThroughputExceededException</code>. If there is insufficient provisioned throughput on the stream, subsequent calls made within the next 1 second throw <code>ProvisionedThroughputExceededException</code>. <a>GetRecords</a> doesn't return any data when it throws an exception. For this reason, we recommend that you wait 1 second between calls to <a>GetRecords</a>. However, it's possible that the application will get exceptions for longer than 1 second.</p> <p>To detect whether the application is falling behind in processing, you can use the <code>MillisBehindLatest</code> response attribute. You can also monitor the stream using CloudWatch metrics and other mechanisms (see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring.html">Monitoring</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>).</p> <p>Each Amazon Kinesis record includes a value, <code>ApproximateArrivalTimestamp</code>, that is set when a stream successfully receives and stores a record. This is commonly referred to as a server-side time stamp, whereas a client-side time stamp is set when a data producer creates or sends the record to a stream (a data producer is any data source putting data records into a stream, for example with <a>PutRecords</a>). The time stamp has millisecond precision. There are no guarantees about the time stamp accuracy, or that the time stamp is always increasing. For example, records in a shard or across a stream might have time stamps that are out of order.</p> <p>This operation has a limit of five transactions per second per account.</p>
-    async fn get_records(
+    /// Presigns the request `get_records` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_records(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRecordsInput,
-    ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_records_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_records_request(&self, input: GetRecordsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("GetRecords");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.GetRecords");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_shard_iterator`, also returning the metadata of the response.
+    pub async fn get_shard_iterator_with_metadata(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> Result<(GetShardIteratorOutput, ResponseMetadata), RusotoError<GetShardIteratorError>>
+    {
+        let request = self.build_get_shard_iterator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetRecordsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetShardIteratorOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRecordsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetShardIteratorError::from_response(response))
         }
     }
 
-    /// <p>Gets an Amazon Kinesis shard iterator. A shard iterator expires 5 minutes after it is returned to the requester.</p> <p>A shard iterator specifies the shard position from which to start reading data records sequentially. The position is specified using the sequence number of a data record in a shard. A sequence number is the identifier associated with every record ingested in the stream, and is assigned when a record is put into the stream. Each stream has one or more shards.</p> <p>You must specify the shard iterator type. For example, you can set the <code>ShardIteratorType</code> parameter to read exactly from the position denoted by a specific sequence number by using the <code>AT_SEQUENCE_NUMBER</code> shard iterator type. Alternatively, the parameter can read right after the sequence number by using the <code>AFTER_SEQUENCE_NUMBER</code> shard iterator type, using sequence numbers returned by earlier calls to <a>PutRecord</a>, <a>PutRecords</a>, <a>GetRecords</a>, or <a>DescribeStream</a>. In the request, you can specify the shard iterator type <code>AT_TIMESTAMP</code> to read records from an arbitrary point in time, <code>TRIM_HORIZON</code> to cause <code>ShardIterator</code> to point to the last untrimmed record in the shard in the system (the oldest data record in the shard), or <code>LATEST</code> so that you always read the most recent data in the shard. </p> <p>When you read repeatedly from a stream, use a <a>GetShardIterator</a> request to get the first shard iterator for use in your first <a>GetRecords</a> request and for subsequent reads use the shard iterator returned by the <a>GetRecords</a> request in <code>NextShardIterator</code>. A new shard iterator is returned by every <a>GetRecords</a> request in <code>NextShardIterator</code>, which you use in the <code>ShardIterator</code> parameter of the next <a>GetRecords</a> request. </p> <p>If a <a>GetShardIterator</a> request is made too often, you receive a <code>ProvisionedThroughputExceededException</code>. For more information about throughput limits, see <a>GetRecords</a>, and <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the shard is closed, <a>GetShardIterator</a> returns a valid iterator for the last sequence number of the shard. A shard can be closed as a result of using <a>SplitShard</a> or <a>MergeShards</a>.</p> <p> <a>GetShardIterator</a> has a limit of five transactions per second per account per open shard.</p>
-    async fn get_shard_iterator(
+    /// Presigns the request `get_shard_iterator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_shard_iterator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetShardIteratorInput,
-    ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_shard_iterator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_shard_iterator_request(&self, input: GetShardIteratorInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("GetShardIterator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.GetShardIterator");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `increase_stream_retention_period`, also returning the metadata of the response.
+    pub async fn increase_stream_retention_period_with_metadata(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<IncreaseStreamRetentionPeriodError>> {
+        let request = self.build_increase_stream_retention_period_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetShardIteratorOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetShardIteratorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(IncreaseStreamRetentionPeriodError::from_response(response))
         }
     }
 
-    /// <p>Increases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The maximum value of a stream's retention period is 168 hours (7 days).</p> <p>If you choose a longer stream retention period, this operation increases the time period during which records that have not yet expired are accessible. However, it does not make previous, expired data (older than the stream's previous retention period) accessible after the operation has been called. For example, if a stream's retention period is set to 24 hours and is increased to 168 hours, any data that is older than 24 hours remains inaccessible to consumer applications.</p>
-    async fn increase_stream_retention_period(
+    /// Presigns the request `increase_stream_retention_period` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_increase_stream_retention_period(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: IncreaseStreamRetentionPeriodInput,
-    ) -> Result<(), RusotoError<IncreaseStreamRetentionPeriodError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_increase_stream_retention_period_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_increase_stream_retention_period_request(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("IncreaseStreamRetentionPeriod");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3140,398 +3773,1178 @@ impl Kinesis for KinesisClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_shards`, also returning the metadata of the response.
+    pub async fn list_shards_with_metadata(
+        &self,
+        input: ListShardsInput,
+    ) -> Result<(ListShardsOutput, ResponseMetadata), RusotoError<ListShardsError>> {
+        let request = self.build_list_shards_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListShardsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(IncreaseStreamRetentionPeriodError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListShardsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the shards in a stream and provides information about each shard. This operation has a limit of 100 transactions per second per data stream.</p> <important> <p>This API is a new operation that is used by the Amazon Kinesis Client Library (KCL). If you have a fine-grained IAM policy that only allows specific operations, you must update your policy to allow calls to this API. For more information, see <a href="https://docs.aws.amazon.com/streams/latest/dev/controlling-access.html">Controlling Access to Amazon Kinesis Data Streams Resources Using IAM</a>.</p> </important></p>
-    async fn list_shards(
+    /// Presigns the request `list_shards` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_shards(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListShardsInput,
-    ) -> Result<ListShardsOutput, RusotoError<ListShardsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_shards_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_shards_request(&self, input: ListShardsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListShards");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.ListShards");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_stream_consumers`, also returning the metadata of the response.
+    pub async fn list_stream_consumers_with_metadata(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> Result<(ListStreamConsumersOutput, ResponseMetadata), RusotoError<ListStreamConsumersError>>
+    {
+        let request = self.build_list_stream_consumers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListShardsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListStreamConsumersOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListShardsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListStreamConsumersError::from_response(response))
         }
     }
 
-    /// <p>Lists the consumers registered to receive data from a stream using enhanced fan-out, and provides information about each consumer.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
-    async fn list_stream_consumers(
+    /// Presigns the request `list_stream_consumers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_stream_consumers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListStreamConsumersInput,
-    ) -> Result<ListStreamConsumersOutput, RusotoError<ListStreamConsumersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_stream_consumers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_stream_consumers_request(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListStreamConsumers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.ListStreamConsumers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_streams`, also returning the metadata of the response.
+    pub async fn list_streams_with_metadata(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<(ListStreamsOutput, ResponseMetadata), RusotoError<ListStreamsError>> {
+        let request = self.build_list_streams_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListStreamConsumersOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListStreamsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListStreamConsumersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListStreamsError::from_response(response))
         }
     }
 
-    /// <p>Lists your Kinesis data streams.</p> <p>The number of streams may be too large to return from a single call to <code>ListStreams</code>. You can limit the number of returned streams using the <code>Limit</code> parameter. If you do not specify a value for the <code>Limit</code> parameter, Kinesis Data Streams uses the default limit, which is currently 10.</p> <p>You can detect if there are more streams available to list by using the <code>HasMoreStreams</code> flag from the returned output. If there are more streams available, you can request more streams by using the name of the last stream returned by the <code>ListStreams</code> request in the <code>ExclusiveStartStreamName</code> parameter in a subsequent request to <code>ListStreams</code>. The group of stream names returned by the subsequent request is then added to the list. You can continue this process until all the stream names have been collected in the list. </p> <p> <a>ListStreams</a> has a limit of five transactions per second per account.</p>
-    async fn list_streams(
+    /// Presigns the request `list_streams` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_streams(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListStreamsInput,
-    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_streams_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_streams_request(&self, input: ListStreamsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.ListStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_stream`, also returning the metadata of the response.
+    pub async fn list_tags_for_stream_with_metadata(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> Result<(ListTagsForStreamOutput, ResponseMetadata), RusotoError<ListTagsForStreamError>>
+    {
+        let request = self.build_list_tags_for_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListStreamsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForStreamOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListStreamsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForStreamError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for the specified Kinesis data stream. This operation has a limit of five transactions per second per account.</p>
-    async fn list_tags_for_stream(
+    /// Presigns the request `list_tags_for_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForStreamInput,
-    ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_stream_request(&self, input: ListTagsForStreamInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListTagsForStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.ListTagsForStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `merge_shards`, also returning the metadata of the response.
+    pub async fn merge_shards_with_metadata(
+        &self,
+        input: MergeShardsInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<MergeShardsError>> {
+        let request = self.build_merge_shards_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTagsForStreamOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MergeShardsError::from_response(response))
         }
     }
 
-    /// <p>Merges two adjacent shards in a Kinesis data stream and combines them into a single shard to reduce the stream's capacity to ingest and transport data. Two shards are considered adjacent if the union of the hash key ranges for the two shards form a contiguous set with no gaps. For example, if you have two shards, one with a hash key range of 276...381 and the other with a hash key range of 382...454, then you could merge these two shards into a single shard that would have a hash key range of 276...454. After the merge, the single child shard receives data for all hash key values covered by the two parent shards.</p> <p> <code>MergeShards</code> is called when there is a need to reduce the overall capacity of a stream because of excess capacity that is not being used. You must specify the shard to be merged and the adjacent shard for a stream. For more information about merging shards, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-merge.html">Merge Two Shards</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can call <code>MergeShards</code>. If a stream is in the <code>CREATING</code>, <code>UPDATING</code>, or <code>DELETING</code> state, <code>MergeShards</code> returns a <code>ResourceInUseException</code>. If the specified stream does not exist, <code>MergeShards</code> returns a <code>ResourceNotFoundException</code>. </p> <p>You can use <a>DescribeStream</a> to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <code>MergeShards</code> is an asynchronous operation. Upon receiving a <code>MergeShards</code> request, Amazon Kinesis Data Streams immediately returns a response and sets the <code>StreamStatus</code> to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the <code>StreamStatus</code> to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You use <a>DescribeStream</a> to determine the shard IDs that are specified in the <code>MergeShards</code> request. </p> <p>If you try to operate on too many streams in parallel using <a>CreateStream</a>, <a>DeleteStream</a>, <code>MergeShards</code>, or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>MergeShards</code> has a limit of five transactions per second per account.</p>
-    async fn merge_shards(
+    /// Presigns the request `merge_shards` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_merge_shards(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MergeShardsInput,
-    ) -> Result<(), RusotoError<MergeShardsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_merge_shards_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_merge_shards_request(&self, input: MergeShardsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("MergeShards");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.MergeShards");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_record`, also returning the metadata of the response.
+    pub async fn put_record_with_metadata(
+        &self,
+        input: PutRecordInput,
+    ) -> Result<(PutRecordOutput, ResponseMetadata), RusotoError<PutRecordError>> {
+        let request = self.build_put_record_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<PutRecordOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MergeShardsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRecordError::from_response(response))
         }
     }
 
-    /// <p>Writes a single data record into an Amazon Kinesis data stream. Call <code>PutRecord</code> to send data into the stream for real-time ingestion and subsequent processing, one record at a time. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; a partition key; and the data blob itself.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams to distribute data across shards. Kinesis Data Streams segregates the data records that belong to a stream into multiple shards, using the partition key associated with each data record to determine the shard to which a given data record belongs.</p> <p>Partition keys are Unicode strings, with a maximum length limit of 256 characters for each key. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards using the hash key ranges of the shards. You can override hashing the partition key to determine the shard by explicitly specifying a hash value using the <code>ExplicitHashKey</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p> <code>PutRecord</code> returns the shard ID of where the data record was placed and the sequence number that was assigned to the data record.</p> <p>Sequence numbers increase over time and are specific to a shard within a stream, not across all shards within a stream. To guarantee strictly increasing ordering, write serially to a shard and use the <code>SequenceNumberForOrdering</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If a <code>PutRecord</code> request cannot be processed because of insufficient provisioned throughput on the shard involved in the request, <code>PutRecord</code> throws <code>ProvisionedThroughputExceededException</code>. </p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
-    async fn put_record(
+    /// Presigns the request `put_record` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_record(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRecordInput,
-    ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_record_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_record_request(&self, input: PutRecordInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("PutRecord");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.PutRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_records`, also returning the metadata of the response.
+    pub async fn put_records_with_metadata(
+        &self,
+        input: PutRecordsInput,
+    ) -> Result<(PutRecordsOutput, ResponseMetadata), RusotoError<PutRecordsError>> {
+        let request = self.build_put_records_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutRecordOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRecordsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRecordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRecordsError::from_response(response))
         }
     }
 
-    /// <p>Writes multiple data records into a Kinesis data stream in a single call (also referred to as a <code>PutRecords</code> request). Use this operation to send data into the stream for data ingestion and processing. </p> <p>Each <code>PutRecords</code> request can support up to 500 records. Each record in the request can be as large as 1 MB, up to a limit of 5 MB for the entire request, including partition keys. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; and an array of request <code>Records</code>, with each record in the array requiring a partition key and data blob. The record size limit applies to the total size of the partition key and data blob.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams as input to a hash function that maps the partition key and associated data to a specific shard. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards. As a result of this hashing mechanism, all data records with the same partition key map to the same shard within the stream. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>Each record in the <code>Records</code> array may include an optional parameter, <code>ExplicitHashKey</code>, which overrides the partition key to shard mapping. This parameter allows a data producer to determine explicitly the shard where the record is stored. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>The <code>PutRecords</code> response includes an array of response <code>Records</code>. Each record in the response array directly correlates with a record in the request array using natural ordering, from the top to the bottom of the request and response. The response <code>Records</code> array always includes the same number of records as the request array.</p> <p>The response <code>Records</code> array includes both successfully and unsuccessfully processed records. Kinesis Data Streams attempts to process all records in each <code>PutRecords</code> request. A single record failure does not stop the processing of subsequent records.</p> <p>A successfully processed record includes <code>ShardId</code> and <code>SequenceNumber</code> values. The <code>ShardId</code> parameter identifies the shard in the stream where the record is stored. The <code>SequenceNumber</code> parameter is an identifier assigned to the put record, unique to all records in the stream.</p> <p>An unsuccessfully processed record includes <code>ErrorCode</code> and <code>ErrorMessage</code> values. <code>ErrorCode</code> reflects the type of error and can be one of the following values: <code>ProvisionedThroughputExceededException</code> or <code>InternalFailure</code>. <code>ErrorMessage</code> provides more detailed information about the <code>ProvisionedThroughputExceededException</code> exception including the account ID, stream name, and shard ID of the record that was throttled. For more information about partially successful responses, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-add-data-to-stream.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
-    async fn put_records(
+    /// Presigns the request `put_records` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_records(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRecordsInput,
-    ) -> Result<PutRecordsOutput, RusotoError<PutRecordsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_records_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_records_request(&self, input: PutRecordsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("PutRecords");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.PutRecords");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_stream_consumer`, also returning the metadata of the response.
+    pub async fn register_stream_consumer_with_metadata(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> Result<
+        (RegisterStreamConsumerOutput, ResponseMetadata),
+        RusotoError<RegisterStreamConsumerError>,
+    > {
+        let request = self.build_register_stream_consumer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutRecordsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterStreamConsumerOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRecordsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterStreamConsumerError::from_response(response))
         }
     }
 
-    /// <p>Registers a consumer with a Kinesis data stream. When you use this operation, the consumer you register can read data from the stream at a rate of up to 2 MiB per second. This rate is unaffected by the total number of consumers that read from the same stream.</p> <p>You can register up to 5 consumers per stream. A given consumer can only be registered with one stream.</p> <p>This operation has a limit of five transactions per second per account.</p>
-    async fn register_stream_consumer(
+    /// Presigns the request `register_stream_consumer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_stream_consumer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterStreamConsumerInput,
-    ) -> Result<RegisterStreamConsumerOutput, RusotoError<RegisterStreamConsumerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_stream_consumer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_stream_consumer_request(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("RegisterStreamConsumer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.RegisterStreamConsumer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags_from_stream`, also returning the metadata of the response.
+    pub async fn remove_tags_from_stream_with_metadata(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveTagsFromStreamError>> {
+        let request = self.build_remove_tags_from_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterStreamConsumerOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterStreamConsumerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsFromStreamError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from the specified Kinesis data stream. Removed tags are deleted and cannot be recovered after this operation successfully completes.</p> <p>If you specify a tag that does not exist, it is ignored.</p> <p> <a>RemoveTagsFromStream</a> has a limit of five transactions per second per account.</p>
-    async fn remove_tags_from_stream(
+    /// Presigns the request `remove_tags_from_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsFromStreamInput,
-    ) -> Result<(), RusotoError<RemoveTagsFromStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_stream_request(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("RemoveTagsFromStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.RemoveTagsFromStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `split_shard`, also returning the metadata of the response.
+    pub async fn split_shard_with_metadata(
+        &self,
+        input: SplitShardInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SplitShardError>> {
+        let request = self.build_split_shard_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsFromStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SplitShardError::from_response(response))
         }
     }
 
-    /// <p>Splits a shard into two new shards in the Kinesis data stream, to increase the stream's capacity to ingest and transport data. <code>SplitShard</code> is called when there is a need to increase the overall capacity of a stream because of an expected increase in the volume of data records being ingested. </p> <p>You can also use <code>SplitShard</code> when a shard appears to be approaching its maximum utilization; for example, the producers sending data into the specific shard are suddenly sending more than previously anticipated. You can also call <code>SplitShard</code> to increase stream capacity, so that more Kinesis Data Streams applications can simultaneously read data from the stream for real-time processing. </p> <p>You must specify the shard to be split and the new hash key, which is the position in the shard where the shard gets split in two. In many cases, the new hash key might be the average of the beginning and ending hash key, but it can be any hash key value in the range being mapped into the shard. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-split.html">Split a Shard</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>You can use <a>DescribeStream</a> to determine the shard ID and hash key values for the <code>ShardToSplit</code> and <code>NewStartingHashKey</code> parameters that are specified in the <code>SplitShard</code> request.</p> <p> <code>SplitShard</code> is an asynchronous operation. Upon receiving a <code>SplitShard</code> request, Kinesis Data Streams immediately returns a response and sets the stream status to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You can use <code>DescribeStream</code> to check the status of the stream, which is returned in <code>StreamStatus</code>. If the stream is in the <code>ACTIVE</code> state, you can call <code>SplitShard</code>. If a stream is in <code>CREATING</code> or <code>UPDATING</code> or <code>DELETING</code> states, <code>DescribeStream</code> returns a <code>ResourceInUseException</code>.</p> <p>If the specified stream does not exist, <code>DescribeStream</code> returns a <code>ResourceNotFoundException</code>. If you try to create more shards than are authorized for your account, you receive a <code>LimitExceededException</code>. </p> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>If you try to operate on too many streams simultaneously using <a>CreateStream</a>, <a>DeleteStream</a>, <a>MergeShards</a>, and/or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>SplitShard</code> has a limit of five transactions per second per account.</p>
-    async fn split_shard(
+    /// Presigns the request `split_shard` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_split_shard(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SplitShardInput,
-    ) -> Result<(), RusotoError<SplitShardError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_split_shard_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_split_shard_request(&self, input: SplitShardInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("SplitShard");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.SplitShard");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_stream_encryption`, also returning the metadata of the response.
+    pub async fn start_stream_encryption_with_metadata(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<StartStreamEncryptionError>> {
+        let request = self.build_start_stream_encryption_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SplitShardError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartStreamEncryptionError::from_response(response))
         }
     }
 
-    /// <p>Enables or updates server-side encryption using an AWS KMS key for a specified stream. </p> <p>Starting encryption is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Updating or applying encryption normally takes a few seconds to complete, but it can take minutes. You can continue to read and write data to your stream while its status is <code>UPDATING</code>. Once the status of the stream is <code>ACTIVE</code>, encryption begins for records written to the stream. </p> <p>API Limits: You can successfully apply a new AWS KMS key for server-side encryption 25 times in a rolling 24-hour period.</p> <p>Note: It can take up to 5 seconds after the stream is in an <code>ACTIVE</code> status before all records written to the stream are encrypted. After you enable encryption, you can verify that encryption is applied by inspecting the API response from <code>PutRecord</code> or <code>PutRecords</code>.</p>
-    async fn start_stream_encryption(
+    /// Presigns the request `start_stream_encryption` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_stream_encryption(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartStreamEncryptionInput,
-    ) -> Result<(), RusotoError<StartStreamEncryptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_stream_encryption_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_stream_encryption_request(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("StartStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.StartStreamEncryption");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_stream_encryption`, also returning the metadata of the response.
+    pub async fn stop_stream_encryption_with_metadata(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopStreamEncryptionError>> {
+        let request = self.build_stop_stream_encryption_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartStreamEncryptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopStreamEncryptionError::from_response(response))
         }
     }
 
-    /// <p>Disables server-side encryption for a specified stream. </p> <p>Stopping encryption is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Stopping encryption normally takes a few seconds to complete, but it can take minutes. You can continue to read and write data to your stream while its status is <code>UPDATING</code>. Once the status of the stream is <code>ACTIVE</code>, records written to the stream are no longer encrypted by Kinesis Data Streams. </p> <p>API Limits: You can successfully disable server-side encryption 25 times in a rolling 24-hour period. </p> <p>Note: It can take up to 5 seconds after the stream is in an <code>ACTIVE</code> status before all records written to the stream are no longer subject to encryption. After you disabled encryption, you can verify that encryption is not applied by inspecting the API response from <code>PutRecord</code> or <code>PutRecords</code>.</p>
-    async fn stop_stream_encryption(
+    /// Presigns the request `stop_stream_encryption` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_stream_encryption(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopStreamEncryptionInput,
-    ) -> Result<(), RusotoError<StopStreamEncryptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_stream_encryption_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_stream_encryption_request(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("StopStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.StopStreamEncryption");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `subscribe_to_shard`, also returning the metadata of the response.
+    pub async fn subscribe_to_shard_with_metadata(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> Result<(SubscribeToShardOutput, ResponseMetadata), RusotoError<SubscribeToShardError>>
+    {
+        let request = self.build_subscribe_to_shard_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SubscribeToShardOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopStreamEncryptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SubscribeToShardError::from_response(response))
         }
     }
 
-    /// <p>Call this operation from your consumer after you call <a>RegisterStreamConsumer</a> to register the consumer with Kinesis Data Streams. If the call succeeds, your consumer starts receiving events of type <a>SubscribeToShardEvent</a> for up to 5 minutes, after which time you need to call <code>SubscribeToShard</code> again to renew the subscription if you want to continue to receive records.</p> <p>You can make one call to <code>SubscribeToShard</code> per second per <code>ConsumerARN</code>. If your call succeeds, and then you call the operation again less than 5 seconds later, the second call generates a <a>ResourceInUseException</a>. If you call the operation a second time more than 5 seconds after the first call succeeds, the second call succeeds and the first connection gets shut down.</p>
-    async fn subscribe_to_shard(
+    /// Calls `subscribe_to_shard`, returning the event stream of the response undecoded.
+    pub(crate) async fn subscribe_to_shard_event_stream(
         &self,
         input: SubscribeToShardInput,
-    ) -> Result<SubscribeToShardOutput, RusotoError<SubscribeToShardError>> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.SubscribeToShard");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<(rusoto_core::ByteStream, ResponseMetadata), RusotoError<SubscribeToShardError>>
+    {
+        let request = self.build_subscribe_to_shard_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SubscribeToShardOutput, _>()
+            Ok((response.body, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(SubscribeToShardError::from_response(response))
         }
     }
 
-    /// <p>Updates the shard count of the specified stream to the specified number of shards.</p> <p>Updating the shard count is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Depending on the size of the stream, the scaling action could take a few minutes to complete. You can continue to read and write data to your stream while its status is <code>UPDATING</code>.</p> <p>To update the shard count, Kinesis Data Streams performs splits or merges on individual shards. This can cause short-lived shards to be created, in addition to the final shards. We recommend that you double or halve the shard count, as this results in the fewest number of splits or merges.</p> <p>This operation has the following default limits. By default, you cannot do the following:</p> <ul> <li> <p>Scale more than twice per rolling 24-hour period per stream</p> </li> <li> <p>Scale up to more than double your current shard count for a stream</p> </li> <li> <p>Scale down below half your current shard count for a stream</p> </li> <li> <p>Scale up to more than 500 shards in a stream</p> </li> <li> <p>Scale a stream with more than 500 shards down unless the result is less than 500 shards</p> </li> <li> <p>Scale up to more than the shard limit for your account</p> </li> </ul> <p>For the default limits for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To request an increase in the call rate limit, the shard limit for this API, or your overall shard limit, use the <a href="https://console.aws.amazon.com/support/v1#/case/create?issueType=service-limit-increase&amp;limitType=service-code-kinesis">limits form</a>.</p>
-    async fn update_shard_count(
+    /// Presigns the request `subscribe_to_shard` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_subscribe_to_shard(
         &self,
-        input: UpdateShardCountInput,
-    ) -> Result<UpdateShardCountOutput, RusotoError<UpdateShardCountError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SubscribeToShardInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_subscribe_to_shard_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_subscribe_to_shard_request(&self, input: SubscribeToShardInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("SubscribeToShard");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.UpdateShardCount");
+        request.add_header("x-amz-target", "Kinesis_20131202.SubscribeToShard");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_shard_count`, also returning the metadata of the response.
+    pub async fn update_shard_count_with_metadata(
+        &self,
+        input: UpdateShardCountInput,
+    ) -> Result<(UpdateShardCountOutput, ResponseMetadata), RusotoError<UpdateShardCountError>>
+    {
+        let request = self.build_update_shard_count_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateShardCountOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateShardCountOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(UpdateShardCountError::from_response(response))
         }
     }
+
+    /// Presigns the request `update_shard_count` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_shard_count(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateShardCountInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_shard_count_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_shard_count_request(&self, input: UpdateShardCountInput) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("UpdateShardCount");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.UpdateShardCount");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// The pages of `ListShards` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_shards_pages(
+        &self,
+        input: ListShardsInput,
+    ) -> impl futures::Stream<Item = Result<ListShardsOutput, RusotoError<ListShardsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisClient, input| async move { client.list_shards(input).await },
+            |input: &ListShardsInput, output: &ListShardsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListShardsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Shards` of every page of `ListShards` results for `input`, as `list_shards_pages` requests them.
+    pub fn list_shards_stream(
+        &self,
+        input: ListShardsInput,
+    ) -> impl futures::Stream<Item = Result<Shard, RusotoError<ListShardsError>>> + Send {
+        rusoto_core::pagination::items(self.list_shards_pages(input), |page| {
+            page.shards.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListStreamConsumers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_stream_consumers_pages(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListStreamConsumersOutput, RusotoError<ListStreamConsumersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisClient, input| async move { client.list_stream_consumers(input).await },
+            |input: &ListStreamConsumersInput, output: &ListStreamConsumersOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStreamConsumersInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Consumers` of every page of `ListStreamConsumers` results for `input`, as `list_stream_consumers_pages` requests them.
+    pub fn list_stream_consumers_stream(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> impl futures::Stream<Item = Result<Consumer, RusotoError<ListStreamConsumersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_stream_consumers_pages(input), |page| {
+            page.consumers.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `StreamExists`, polling `DescribeStream` with `input` every 10 seconds, up to 18 times.
+    pub async fn wait_until_stream_exists(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStreamError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(10), 18);
+        self.wait_until_stream_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `StreamExists` like `wait_until_stream_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_stream_exists_with(
+        &self,
+        input: DescribeStreamInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStreamError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_stream(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.stream_description)
+                        .map(|value| &value.stream_status)
+                        .any(|value| value == "ACTIVE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `StreamNotExists`, polling `DescribeStream` with `input` every 10 seconds, up to 18 times.
+    pub async fn wait_until_stream_not_exists(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStreamError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(10), 18);
+        self.wait_until_stream_not_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `StreamNotExists` like `wait_until_stream_not_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_stream_not_exists_with(
+        &self,
+        input: DescribeStreamInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStreamError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_stream(input.clone()),
+            |result| {
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Kinesis for KinesisClient {
+    /// <p>Adds or updates tags for the specified Kinesis data stream. Each time you invoke this operation, you can specify up to 10 tags. If you want to add more than 10 tags to your stream, you can invoke this operation multiple times. In total, each stream can have up to 50 tags.</p> <p>If tags have already been assigned to the stream, <code>AddTagsToStream</code> overwrites any existing tags that correspond to the specified tag keys.</p> <p> <a>AddTagsToStream</a> has a limit of five transactions per second per account.</p>
+    async fn add_tags_to_stream(
+        &self,
+        input: AddTagsToStreamInput,
+    ) -> Result<(), RusotoError<AddTagsToStreamError>> {
+        self.add_tags_to_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Kinesis data stream. A stream captures and transports data records that are continuously emitted from different data sources or <i>producers</i>. Scale-out within a stream is explicitly supported by means of shards, which are uniquely identified groups of data records in a stream.</p> <p>You specify and control the number of shards that a stream is composed of. Each shard can support reads up to five transactions per second, up to a maximum data read total of 2 MB per second. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second. If the amount of data input increases or decreases, you can add or remove shards.</p> <p>The stream name identifies the stream. The name is scoped to the AWS account used by the application. It is also scoped by AWS Region. That is, two streams in two different accounts can have the same name, and two streams in the same account, but in two different Regions, can have the same name.</p> <p> <code>CreateStream</code> is an asynchronous operation. Upon receiving a <code>CreateStream</code> request, Kinesis Data Streams immediately returns and sets the stream status to <code>CREATING</code>. After the stream is created, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. You should perform read and write operations only on an <code>ACTIVE</code> stream. </p> <p>You receive a <code>LimitExceededException</code> when making a <code>CreateStream</code> request when you try to do one of the following:</p> <ul> <li> <p>Have more than five streams in the <code>CREATING</code> state at any point in time.</p> </li> <li> <p>Create more shards than are authorized for your account.</p> </li> </ul> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>You can use <code>DescribeStream</code> to check the stream status, which is returned in <code>StreamStatus</code>.</p> <p> <a>CreateStream</a> has a limit of five transactions per second per account.</p>
+    async fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> Result<(), RusotoError<CreateStreamError>> {
+        self.create_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Decreases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The minimum value of a stream's retention period is 24 hours.</p> <p>This operation may result in lost data. For example, if the stream's retention period is 48 hours and is decreased to 24 hours, any data already in the stream that is older than 24 hours is inaccessible.</p>
+    async fn decrease_stream_retention_period(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> Result<(), RusotoError<DecreaseStreamRetentionPeriodError>> {
+        self.decrease_stream_retention_period_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Kinesis data stream and all its shards and data. You must shut down any applications that are operating on the stream before you delete the stream. If an application attempts to operate on a deleted stream, it receives the exception <code>ResourceNotFoundException</code>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can delete it. After a <code>DeleteStream</code> request, the specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> <p> <b>Note:</b> Kinesis Data Streams might continue to accept data read and write operations, such as <a>PutRecord</a>, <a>PutRecords</a>, and <a>GetRecords</a>, on a stream in the <code>DELETING</code> state until the stream deletion is complete.</p> <p>When you delete a stream, any shards in that stream are also deleted, and any tags are dissociated from the stream.</p> <p>You can use the <a>DescribeStream</a> operation to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <a>DeleteStream</a> has a limit of five transactions per second per account.</p>
+    async fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> Result<(), RusotoError<DeleteStreamError>> {
+        self.delete_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>To deregister a consumer, provide its ARN. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to deregister, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream. The description of a consumer contains its name and ARN.</p> <p>This operation has a limit of five transactions per second per account.</p>
+    async fn deregister_stream_consumer(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> Result<(), RusotoError<DeregisterStreamConsumerError>> {
+        self.deregister_stream_consumer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the shard limits and usage for the account.</p> <p>If you update your account limits, the old limits might be returned for a few minutes.</p> <p>This operation has a limit of one transaction per second per account.</p>
+    async fn describe_limits(
+        &self,
+    ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
+        self.describe_limits_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified Kinesis data stream.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), creation time, enhanced metric configuration, and shard map. The shard map is an array of shard objects. For each shard object, there is the hash key and sequence number ranges that the shard spans, and the IDs of any earlier shards that played in a role in creating the shard. Every record ingested in the stream is identified by a sequence number, which is assigned when the record is put into the stream.</p> <p>You can limit the number of shards returned by each call. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-retrieve-shards.html">Retrieving Shards from a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>There are no guarantees about the chronological order shards returned. To process shards in chronological order, use the ID of the parent shard to track the lineage to the oldest shard.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
+    async fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+        self.describe_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>To get the description of a registered consumer, provide the ARN of the consumer. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to describe, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream.</p> <p>This operation has a limit of 20 transactions per second per account.</p>
+    async fn describe_stream_consumer(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> Result<DescribeStreamConsumerOutput, RusotoError<DescribeStreamConsumerError>> {
+        self.describe_stream_consumer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a summarized description of the specified Kinesis data stream without the shard list.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), status, record retention period, approximate creation time, monitoring, encryption details, and open shard count. </p>
+    async fn describe_stream_summary(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> Result<DescribeStreamSummaryOutput, RusotoError<DescribeStreamSummaryError>> {
+        self.describe_stream_summary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables enhanced monitoring.</p>
+    async fn disable_enhanced_monitoring(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> Result<EnhancedMonitoringOutput, RusotoError<DisableEnhancedMonitoringError>> {
+        self.disable_enhanced_monitoring_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables enhanced Kinesis data stream monitoring for shard-level metrics.</p>
+    async fn enable_enhanced_monitoring(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> Result<EnhancedMonitoringOutput, RusotoError<EnableEnhancedMonitoringError>> {
+        self.enable_enhanced_monitoring_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets data records from a Kinesis data stream's shard.</p> <p>Specify a shard iterator using the <code>ShardIterator</code> parameter. The shard iterator specifies the position in the shard from which you want to start reading data records sequentially. If there are no records available in the portion of the shard that the iterator points to, <a>GetRecords</a> returns an empty list. It might take multiple calls to get to a portion of the shard that contains records.</p> <p>You can scale by provisioning multiple shards per stream while considering service limits (for more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>). Your application should have one thread per shard, each reading continuously from its stream. To read from a stream continually, call <a>GetRecords</a> in a loop. Use <a>GetShardIterator</a> to get the shard iterator to specify in the first <a>GetRecords</a> call. <a>GetRecords</a> returns a new shard iterator in <code>NextShardIterator</code>. Specify the shard iterator returned in <code>NextShardIterator</code> in subsequent calls to <a>GetRecords</a>. If the shard has been closed, the shard iterator can't return more data and <a>GetRecords</a> returns <code>null</code> in <code>NextShardIterator</code>. You can terminate the loop when the shard is closed, or when the shard iterator reaches the record with the sequence number or other attribute that marks it as the last record to process.</p> <p>Each data record can be up to 1 MiB in size, and each shard can read up to 2 MiB per second. You can ensure that your calls don't exceed the maximum supported size or throughput by using the <code>Limit</code> parameter to specify the maximum number of records that <a>GetRecords</a> can return. Consider your average record size when determining this limit. The maximum number of records that can be returned per call is 10,000.</p> <p>The size of the data returned by <a>GetRecords</a> varies depending on the utilization of the shard. The maximum size of data that <a>GetRecords</a> can return is 10 MiB. If a call returns this amount of data, subsequent calls made within the next 5 seconds throw <code>ProvisionedThroughputExceededException</code>. If there is insufficient provisioned throughput on the stream, subsequent calls made within the next 1 second throw <code>ProvisionedThroughputExceededException</code>. <a>GetRecords</a> doesn't return any data when it throws an exception. For this reason, we recommend that you wait 1 second between calls to <a>GetRecords</a>. However, it's possible that the application will get exceptions for longer than 1 second.</p> <p>To detect whether the application is falling behind in processing, you can use the <code>MillisBehindLatest</code> response attribute. You can also monitor the stream using CloudWatch metrics and other mechanisms (see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring.html">Monitoring</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>).</p> <p>Each Amazon Kinesis record includes a value, <code>ApproximateArrivalTimestamp</code>, that is set when a stream successfully receives and stores a record. This is commonly referred to as a server-side time stamp, whereas a client-side time stamp is set when a data producer creates or sends the record to a stream (a data producer is any data source putting data records into a stream, for example with <a>PutRecords</a>). The time stamp has millisecond precision. There are no guarantees about the time stamp accuracy, or that the time stamp is always increasing. For example, records in a shard or across a stream might have time stamps that are out of order.</p> <p>This operation has a limit of five transactions per second per account.</p>
+    async fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
+        self.get_records_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an Amazon Kinesis shard iterator. A shard iterator expires 5 minutes after it is returned to the requester.</p> <p>A shard iterator specifies the shard position from which to start reading data records sequentially. The position is specified using the sequence number of a data record in a shard. A sequence number is the identifier associated with every record ingested in the stream, and is assigned when a record is put into the stream. Each stream has one or more shards.</p> <p>You must specify the shard iterator type. For example, you can set the <code>ShardIteratorType</code> parameter to read exactly from the position denoted by a specific sequence number by using the <code>AT_SEQUENCE_NUMBER</code> shard iterator type. Alternatively, the parameter can read right after the sequence number by using the <code>AFTER_SEQUENCE_NUMBER</code> shard iterator type, using sequence numbers returned by earlier calls to <a>PutRecord</a>, <a>PutRecords</a>, <a>GetRecords</a>, or <a>DescribeStream</a>. In the request, you can specify the shard iterator type <code>AT_TIMESTAMP</code> to read records from an arbitrary point in time, <code>TRIM_HORIZON</code> to cause <code>ShardIterator</code> to point to the last untrimmed record in the shard in the system (the oldest data record in the shard), or <code>LATEST</code> so that you always read the most recent data in the shard. </p> <p>When you read repeatedly from a stream, use a <a>GetShardIterator</a> request to get the first shard iterator for use in your first <a>GetRecords</a> request and for subsequent reads use the shard iterator returned by the <a>GetRecords</a> request in <code>NextShardIterator</code>. A new shard iterator is returned by every <a>GetRecords</a> request in <code>NextShardIterator</code>, which you use in the <code>ShardIterator</code> parameter of the next <a>GetRecords</a> request. </p> <p>If a <a>GetShardIterator</a> request is made too often, you receive a <code>ProvisionedThroughputExceededException</code>. For more information about throughput limits, see <a>GetRecords</a>, and <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the shard is closed, <a>GetShardIterator</a> returns a valid iterator for the last sequence number of the shard. A shard can be closed as a result of using <a>SplitShard</a> or <a>MergeShards</a>.</p> <p> <a>GetShardIterator</a> has a limit of five transactions per second per account per open shard.</p>
+    async fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
+        self.get_shard_iterator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Increases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The maximum value of a stream's retention period is 168 hours (7 days).</p> <p>If you choose a longer stream retention period, this operation increases the time period during which records that have not yet expired are accessible. However, it does not make previous, expired data (older than the stream's previous retention period) accessible after the operation has been called. For example, if a stream's retention period is set to 24 hours and is increased to 168 hours, any data that is older than 24 hours remains inaccessible to consumer applications.</p>
+    async fn increase_stream_retention_period(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> Result<(), RusotoError<IncreaseStreamRetentionPeriodError>> {
+        self.increase_stream_retention_period_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the shards in a stream and provides information about each shard. This operation has a limit of 100 transactions per second per data stream.</p> <important> <p>This API is a new operation that is used by the Amazon Kinesis Client Library (KCL). If you have a fine-grained IAM policy that only allows specific operations, you must update your policy to allow calls to this API. For more information, see <a href="https://docs.aws.amazon.com/streams/latest/dev/controlling-access.html">Controlling Access to Amazon Kinesis Data Streams Resources Using IAM</a>.</p> </important></p>
+    async fn list_shards(
+        &self,
+        input: ListShardsInput,
+    ) -> Result<ListShardsOutput, RusotoError<ListShardsError>> {
+        self.list_shards_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the consumers registered to receive data from a stream using enhanced fan-out, and provides information about each consumer.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
+    async fn list_stream_consumers(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> Result<ListStreamConsumersOutput, RusotoError<ListStreamConsumersError>> {
+        self.list_stream_consumers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists your Kinesis data streams.</p> <p>The number of streams may be too large to return from a single call to <code>ListStreams</code>. You can limit the number of returned streams using the <code>Limit</code> parameter. If you do not specify a value for the <code>Limit</code> parameter, Kinesis Data Streams uses the default limit, which is currently 10.</p> <p>You can detect if there are more streams available to list by using the <code>HasMoreStreams</code> flag from the returned output. If there are more streams available, you can request more streams by using the name of the last stream returned by the <code>ListStreams</code> request in the <code>ExclusiveStartStreamName</code> parameter in a subsequent request to <code>ListStreams</code>. The group of stream names returned by the subsequent request is then added to the list. You can continue this process until all the stream names have been collected in the list. </p> <p> <a>ListStreams</a> has a limit of five transactions per second per account.</p>
+    async fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+        self.list_streams_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for the specified Kinesis data stream. This operation has a limit of five transactions per second per account.</p>
+    async fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
+        self.list_tags_for_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Merges two adjacent shards in a Kinesis data stream and combines them into a single shard to reduce the stream's capacity to ingest and transport data. Two shards are considered adjacent if the union of the hash key ranges for the two shards form a contiguous set with no gaps. For example, if you have two shards, one with a hash key range of 276...381 and the other with a hash key range of 382...454, then you could merge these two shards into a single shard that would have a hash key range of 276...454. After the merge, the single child shard receives data for all hash key values covered by the two parent shards.</p> <p> <code>MergeShards</code> is called when there is a need to reduce the overall capacity of a stream because of excess capacity that is not being used. You must specify the shard to be merged and the adjacent shard for a stream. For more information about merging shards, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-merge.html">Merge Two Shards</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can call <code>MergeShards</code>. If a stream is in the <code>CREATING</code>, <code>UPDATING</code>, or <code>DELETING</code> state, <code>MergeShards</code> returns a <code>ResourceInUseException</code>. If the specified stream does not exist, <code>MergeShards</code> returns a <code>ResourceNotFoundException</code>. </p> <p>You can use <a>DescribeStream</a> to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <code>MergeShards</code> is an asynchronous operation. Upon receiving a <code>MergeShards</code> request, Amazon Kinesis Data Streams immediately returns a response and sets the <code>StreamStatus</code> to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the <code>StreamStatus</code> to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You use <a>DescribeStream</a> to determine the shard IDs that are specified in the <code>MergeShards</code> request. </p> <p>If you try to operate on too many streams in parallel using <a>CreateStream</a>, <a>DeleteStream</a>, <code>MergeShards</code>, or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>MergeShards</code> has a limit of five transactions per second per account.</p>
+    async fn merge_shards(
+        &self,
+        input: MergeShardsInput,
+    ) -> Result<(), RusotoError<MergeShardsError>> {
+        self.merge_shards_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Writes a single data record into an Amazon Kinesis data stream. Call <code>PutRecord</code> to send data into the stream for real-time ingestion and subsequent processing, one record at a time. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; a partition key; and the data blob itself.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams to distribute data across shards. Kinesis Data Streams segregates the data records that belong to a stream into multiple shards, using the partition key associated with each data record to determine the shard to which a given data record belongs.</p> <p>Partition keys are Unicode strings, with a maximum length limit of 256 characters for each key. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards using the hash key ranges of the shards. You can override hashing the partition key to determine the shard by explicitly specifying a hash value using the <code>ExplicitHashKey</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p> <code>PutRecord</code> returns the shard ID of where the data record was placed and the sequence number that was assigned to the data record.</p> <p>Sequence numbers increase over time and are specific to a shard within a stream, not across all shards within a stream. To guarantee strictly increasing ordering, write serially to a shard and use the <code>SequenceNumberForOrdering</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If a <code>PutRecord</code> request cannot be processed because of insufficient provisioned throughput on the shard involved in the request, <code>PutRecord</code> throws <code>ProvisionedThroughputExceededException</code>. </p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
+    async fn put_record(
+        &self,
+        input: PutRecordInput,
+    ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
+        self.put_record_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Writes multiple data records into a Kinesis data stream in a single call (also referred to as a <code>PutRecords</code> request). Use this operation to send data into the stream for data ingestion and processing. </p> <p>Each <code>PutRecords</code> request can support up to 500 records. Each record in the request can be as large as 1 MB, up to a limit of 5 MB for the entire request, including partition keys. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; and an array of request <code>Records</code>, with each record in the array requiring a partition key and data blob. The record size limit applies to the total size of the partition key and data blob.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams as input to a hash function that maps the partition key and associated data to a specific shard. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards. As a result of this hashing mechanism, all data records with the same partition key map to the same shard within the stream. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>Each record in the <code>Records</code> array may include an optional parameter, <code>ExplicitHashKey</code>, which overrides the partition key to shard mapping. This parameter allows a data producer to determine explicitly the shard where the record is stored. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>The <code>PutRecords</code> response includes an array of response <code>Records</code>. Each record in the response array directly correlates with a record in the request array using natural ordering, from the top to the bottom of the request and response. The response <code>Records</code> array always includes the same number of records as the request array.</p> <p>The response <code>Records</code> array includes both successfully and unsuccessfully processed records. Kinesis Data Streams attempts to process all records in each <code>PutRecords</code> request. A single record failure does not stop the processing of subsequent records.</p> <p>A successfully processed record includes <code>ShardId</code> and <code>SequenceNumber</code> values. The <code>ShardId</code> parameter identifies the shard in the stream where the record is stored. The <code>SequenceNumber</code> parameter is an identifier assigned to the put record, unique to all records in the stream.</p> <p>An unsuccessfully processed record includes <code>ErrorCode</code> and <code>ErrorMessage</code> values. <code>ErrorCode</code> reflects the type of error and can be one of the following values: <code>ProvisionedThroughputExceededException</code> or <code>InternalFailure</code>. <code>ErrorMessage</code> provides more detailed information about the <code>ProvisionedThroughputExceededException</code> exception including the account ID, stream name, and shard ID of the record that was throttled. For more information about partially successful responses, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-add-data-to-stream.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
+    async fn put_records(
+        &self,
+        input: PutRecordsInput,
+    ) -> Result<PutRecordsOutput, RusotoError<PutRecordsError>> {
+        self.put_records_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a consumer with a Kinesis data stream. When you use this operation, the consumer you register can read data from the stream at a rate of up to 2 MiB per second. This rate is unaffected by the total number of consumers that read from the same stream.</p> <p>You can register up to 5 consumers per stream. A given consumer can only be registered with one stream.</p> <p>This operation has a limit of five transactions per second per account.</p>
+    async fn register_stream_consumer(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> Result<RegisterStreamConsumerOutput, RusotoError<RegisterStreamConsumerError>> {
+        self.register_stream_consumer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from the specified Kinesis data stream. Removed tags are deleted and cannot be recovered after this operation successfully completes.</p> <p>If you specify a tag that does not exist, it is ignored.</p> <p> <a>RemoveTagsFromStream</a> has a limit of five transactions per second per account.</p>
+    async fn remove_tags_from_stream(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> Result<(), RusotoError<RemoveTagsFromStreamError>> {
+        self.remove_tags_from_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Splits a shard into two new shards in the Kinesis data stream, to increase the stream's capacity to ingest and transport data. <code>SplitShard</code> is called when there is a need to increase the overall capacity of a stream because of an expected increase in the volume of data records being ingested. </p> <p>You can also use <code>SplitShard</code> when a shard appears to be approaching its maximum utilization; for example, the producers sending data into the specific shard are suddenly sending more than previously anticipated. You can also call <code>SplitShard</code> to increase stream capacity, so that more Kinesis Data Streams applications can simultaneously read data from the stream for real-time processing. </p> <p>You must specify the shard to be split and the new hash key, which is the position in the shard where the shard gets split in two. In many cases, the new hash key might be the average of the beginning and ending hash key, but it can be any hash key value in the range being mapped into the shard. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-split.html">Split a Shard</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>You can use <a>DescribeStream</a> to determine the shard ID and hash key values for the <code>ShardToSplit</code> and <code>NewStartingHashKey</code> parameters that are specified in the <code>SplitShard</code> request.</p> <p> <code>SplitShard</code> is an asynchronous operation. Upon receiving a <code>SplitShard</code> request, Kinesis Data Streams immediately returns a response and sets the stream status to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You can use <code>DescribeStream</code> to check the status of the stream, which is returned in <code>StreamStatus</code>. If the stream is in the <code>ACTIVE</code> state, you can call <code>SplitShard</code>. If a stream is in <code>CREATING</code> or <code>UPDATING</code> or <code>DELETING</code> states, <code>DescribeStream</code> returns a <code>ResourceInUseException</code>.</p> <p>If the specified stream does not exist, <code>DescribeStream</code> returns a <code>ResourceNotFoundException</code>. If you try to create more shards than are authorized for your account, you receive a <code>LimitExceededException</code>. </p> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>If you try to operate on too many streams simultaneously using <a>CreateStream</a>, <a>DeleteStream</a>, <a>MergeShards</a>, and/or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>SplitShard</code> has a limit of five transactions per second per account.</p>
+    async fn split_shard(
+        &self,
+        input: SplitShardInput,
+    ) -> Result<(), RusotoError<SplitShardError>> {
+        self.split_shard_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables or updates server-side encryption using an AWS KMS key for a specified stream. </p> <p>Starting encryption is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Updating or applying encryption normally takes a few seconds to complete, but it can take minutes. You can continue to read and write data to your stream while its status is <code>UPDATING</code>. Once the status of the stream is <code>ACTIVE</code>, encryption begins for records written to the stream. </p> <p>API Limits: You can successfully apply a new AWS KMS key for server-side encryption 25 times in a rolling 24-hour period.</p> <p>Note: It can take up to 5 seconds after the stream is in an <code>ACTIVE</code> status before all records written to the stream are encrypted. After you enable encryption, you can verify that encryption is applied by inspecting the API response from <code>PutRecord</code> or <code>PutRecords</code>.</p>
+    async fn start_stream_encryption(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> Result<(), RusotoError<StartStreamEncryptionError>> {
+        self.start_stream_encryption_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables server-side encryption for a specified stream. </p> <p>Stopping encryption is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Stopping encryption normally takes a few seconds to complete, but it can take minutes. You can continue to read and write data to your stream while its status is <code>UPDATING</code>. Once the status of the stream is <code>ACTIVE</code>, records written to the stream are no longer encrypted by Kinesis Data Streams. </p> <p>API Limits: You can successfully disable server-side encryption 25 times in a rolling 24-hour period. </p> <p>Note: It can take up to 5 seconds after the stream is in an <code>ACTIVE</code> status before all records written to the stream are no longer subject to encryption. After you disabled encryption, you can verify that encryption is not applied by inspecting the API response from <code>PutRecord</code> or <code>PutRecords</code>.</p>
+    async fn stop_stream_encryption(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> Result<(), RusotoError<StopStreamEncryptionError>> {
+        self.stop_stream_encryption_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Call this operation from your consumer after you call <a>RegisterStreamConsumer</a> to register the consumer with Kinesis Data Streams. If the call succeeds, your consumer starts receiving events of type <a>SubscribeToShardEvent</a> for up to 5 minutes, after which time you need to call <code>SubscribeToShard</code> again to renew the subscription if you want to continue to receive records.</p> <p>You can make one call to <code>SubscribeToShard</code> per second per <code>ConsumerARN</code>. If your call succeeds, and then you call the operation again less than 5 seconds later, the second call generates a <a>ResourceInUseException</a>. If you call the operation a second time more than 5 seconds after the first call succeeds, the second call succeeds and the first connection gets shut down.</p>
+    async fn subscribe_to_shard(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> Result<SubscribeToShardOutput, RusotoError<SubscribeToShardError>> {
+        self.subscribe_to_shard_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the shard count of the specified stream to the specified number of shards.</p> <p>Updating the shard count is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Depending on the size of the stream, the scaling action could take a few minutes to complete. You can continue to read and write data to your stream while its status is <code>UPDATING</code>.</p> <p>To update the shard count, Kinesis Data Streams performs splits or merges on individual shards. This can cause short-lived shards to be created, in addition to the final shards. We recommend that you double or halve the shard count, as this results in the fewest number of splits or merges.</p> <p>This operation has the following default limits. By default, you cannot do the following:</p> <ul> <li> <p>Scale more than twice per rolling 24-hour period per stream</p> </li> <li> <p>Scale up to more than double your current shard count for a stream</p> </li> <li> <p>Scale down below half your current shard count for a stream</p> </li> <li> <p>Scale up to more than 500 shards in a stream</p> </li> <li> <p>Scale a stream with more than 500 shards down unless the result is less than 500 shards</p> </li> <li> <p>Scale up to more than the shard limit for your account</p> </li> </ul> <p>For the default limits for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To request an increase in the call rate limit, the shard limit for this API, or your overall shard limit, use the <a href="https://console.aws.amazon.com/support/v1#/case/create?issueType=service-limit-increase&amp;limitType=service-code-kinesis">limits form</a>.</p>
+    async fn update_shard_count(
+        &self,
+        input: UpdateShardCountInput,
+    ) -> Result<UpdateShardCountOutput, RusotoError<UpdateShardCountError>> {
+        self.update_shard_count_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1306,6 +1306,7 @@ pub struct UpdateApplicationResponse {}
 
 /// Errors returned by AddApplicationCloudWatchLoggingOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationCloudWatchLoggingOptionError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -1317,6 +1318,8 @@ pub enum AddApplicationCloudWatchLoggingOptionError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationCloudWatchLoggingOptionError {
@@ -1326,32 +1329,42 @@ impl AddApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AddApplicationCloudWatchLoggingOptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1376,12 +1389,16 @@ impl fmt::Display for AddApplicationCloudWatchLoggingOptionError {
             AddApplicationCloudWatchLoggingOptionError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddApplicationCloudWatchLoggingOptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AddApplicationCloudWatchLoggingOptionError {}
 /// Errors returned by AddApplicationInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationInputError {
     /// <p>User-provided application code (query) is invalid. This can be a simple syntax error.</p>
     CodeValidation(String),
@@ -1395,6 +1412,8 @@ pub enum AddApplicationInputError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationInputError {
@@ -1402,31 +1421,43 @@ impl AddApplicationInputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(AddApplicationInputError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        AddApplicationInputError::CodeValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationInputError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationInputError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationInputError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        AddApplicationInputError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        AddApplicationInputError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationInputError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddApplicationInputError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationInputError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddApplicationInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1442,12 +1473,14 @@ impl fmt::Display for AddApplicationInputError {
             AddApplicationInputError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             AddApplicationInputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AddApplicationInputError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            AddApplicationInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddApplicationInputError {}
 /// Errors returned by AddApplicationInputProcessingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationInputProcessingConfigurationError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -1459,6 +1492,8 @@ pub enum AddApplicationInputProcessingConfigurationError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationInputProcessingConfigurationError {
@@ -1468,36 +1503,46 @@ impl AddApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::ConcurrentModification(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::UnsupportedOperation(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AddApplicationInputProcessingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1522,12 +1567,16 @@ impl fmt::Display for AddApplicationInputProcessingConfigurationError {
             AddApplicationInputProcessingConfigurationError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddApplicationInputProcessingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AddApplicationInputProcessingConfigurationError {}
 /// Errors returned by AddApplicationOutput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationOutputError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -1539,6 +1588,8 @@ pub enum AddApplicationOutputError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationOutputError {
@@ -1546,30 +1597,37 @@ impl AddApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationOutputError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationOutputError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationOutputError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        AddApplicationOutputError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationOutputError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddApplicationOutputError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationOutputError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddApplicationOutputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1584,12 +1642,14 @@ impl fmt::Display for AddApplicationOutputError {
             AddApplicationOutputError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             AddApplicationOutputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AddApplicationOutputError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            AddApplicationOutputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddApplicationOutputError {}
 /// Errors returned by AddApplicationReferenceDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationReferenceDataSourceError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -1601,6 +1661,8 @@ pub enum AddApplicationReferenceDataSourceError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationReferenceDataSourceError {
@@ -1610,32 +1672,42 @@ impl AddApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AddApplicationReferenceDataSourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1660,12 +1732,16 @@ impl fmt::Display for AddApplicationReferenceDataSourceError {
             AddApplicationReferenceDataSourceError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddApplicationReferenceDataSourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AddApplicationReferenceDataSourceError {}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationError {
     /// <p>User-provided application code (query) is invalid. This can be a simple syntax error.</p>
     CodeValidation(String),
@@ -1679,6 +1755,8 @@ pub enum CreateApplicationError {
     ResourceInUse(String),
     /// <p>Application created with too many tags, or too many tags added to an application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationError {
@@ -1686,27 +1764,40 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(CreateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::CodeValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateApplicationError::TooManyTags(err.msg))
+                    return RusotoError::service(CreateApplicationError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1722,12 +1813,14 @@ impl fmt::Display for CreateApplicationError {
             CreateApplicationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationError {}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -1737,6 +1830,8 @@ pub enum DeleteApplicationError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationError {
@@ -1744,23 +1839,31 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1774,12 +1877,14 @@ impl fmt::Display for DeleteApplicationError {
             DeleteApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationError {}
 /// Errors returned by DeleteApplicationCloudWatchLoggingOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationCloudWatchLoggingOptionError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -1791,6 +1896,8 @@ pub enum DeleteApplicationCloudWatchLoggingOptionError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationCloudWatchLoggingOptionError {
@@ -1800,36 +1907,46 @@ impl DeleteApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::UnsupportedOperation(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteApplicationCloudWatchLoggingOptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1854,12 +1971,16 @@ impl fmt::Display for DeleteApplicationCloudWatchLoggingOptionError {
             DeleteApplicationCloudWatchLoggingOptionError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApplicationCloudWatchLoggingOptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteApplicationCloudWatchLoggingOptionError {}
 /// Errors returned by DeleteApplicationInputProcessingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationInputProcessingConfigurationError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -1871,6 +1992,8 @@ pub enum DeleteApplicationInputProcessingConfigurationError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationInputProcessingConfigurationError {
@@ -1880,40 +2003,50 @@ impl DeleteApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::ConcurrentModification(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::InvalidArgument(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::ResourceNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::UnsupportedOperation(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteApplicationInputProcessingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1938,12 +2071,16 @@ impl fmt::Display for DeleteApplicationInputProcessingConfigurationError {
             DeleteApplicationInputProcessingConfigurationError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApplicationInputProcessingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteApplicationInputProcessingConfigurationError {}
 /// Errors returned by DeleteApplicationOutput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationOutputError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -1955,6 +2092,8 @@ pub enum DeleteApplicationOutputError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationOutputError {
@@ -1962,32 +2101,37 @@ impl DeleteApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationOutputError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationOutputError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationOutputError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationOutputError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationOutputError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApplicationOutputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2004,12 +2148,14 @@ impl fmt::Display for DeleteApplicationOutputError {
             DeleteApplicationOutputError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteApplicationOutputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteApplicationOutputError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationOutputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationOutputError {}
 /// Errors returned by DeleteApplicationReferenceDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationReferenceDataSourceError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -2021,6 +2167,8 @@ pub enum DeleteApplicationReferenceDataSourceError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationReferenceDataSourceError {
@@ -2030,32 +2178,42 @@ impl DeleteApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteApplicationReferenceDataSourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2080,17 +2238,23 @@ impl fmt::Display for DeleteApplicationReferenceDataSourceError {
             DeleteApplicationReferenceDataSourceError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApplicationReferenceDataSourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteApplicationReferenceDataSourceError {}
 /// Errors returned by DescribeApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeApplicationError {
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeApplicationError {
@@ -2098,17 +2262,19 @@ impl DescribeApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2120,12 +2286,14 @@ impl fmt::Display for DescribeApplicationError {
         match *self {
             DescribeApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeApplicationError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DescribeApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeApplicationError {}
 /// Errors returned by DiscoverInputSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DiscoverInputSchemaError {
     /// <p>Specified input parameter value is invalid.</p>
     InvalidArgument(String),
@@ -2135,6 +2303,8 @@ pub enum DiscoverInputSchemaError {
     ServiceUnavailable(String),
     /// <p>Data format is not valid. Amazon Kinesis Analytics is not able to detect schema for the given streaming source.</p>
     UnableToDetectSchema(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DiscoverInputSchemaError {
@@ -2142,25 +2312,31 @@ impl DiscoverInputSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DiscoverInputSchemaError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DiscoverInputSchemaError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnableToDetectSchemaException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::UnableToDetectSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DiscoverInputSchemaError::UnableToDetectSchema(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DiscoverInputSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2176,20 +2352,25 @@ impl fmt::Display for DiscoverInputSchemaError {
             }
             DiscoverInputSchemaError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DiscoverInputSchemaError::UnableToDetectSchema(ref cause) => write!(f, "{}", cause),
+            DiscoverInputSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DiscoverInputSchemaError {}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
-pub enum ListApplicationsError {}
+#[non_exhaustive]
+pub enum ListApplicationsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListApplicationsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListApplicationsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2198,12 +2379,15 @@ impl ListApplicationsError {
 impl fmt::Display for ListApplicationsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListApplicationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -2211,6 +2395,8 @@ pub enum ListTagsForResourceError {
     InvalidArgument(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2218,20 +2404,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2244,12 +2435,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by StartApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartApplicationError {
     /// <p>User-provided application configuration is not valid.</p>
     InvalidApplicationConfiguration(String),
@@ -2261,6 +2454,8 @@ pub enum StartApplicationError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartApplicationError {
@@ -2268,26 +2463,37 @@ impl StartApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartApplicationError::InvalidApplicationConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StartApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        StartApplicationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StartApplicationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StartApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartApplicationError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2304,12 +2510,14 @@ impl fmt::Display for StartApplicationError {
             StartApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StartApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartApplicationError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            StartApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartApplicationError {}
 /// Errors returned by StopApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopApplicationError {
     /// <p>Application is not available for this operation.</p>
     ResourceInUse(String),
@@ -2317,6 +2525,8 @@ pub enum StopApplicationError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopApplicationError {
@@ -2324,18 +2534,22 @@ impl StopApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(StopApplicationError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StopApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopApplicationError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2348,12 +2562,14 @@ impl fmt::Display for StopApplicationError {
             StopApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StopApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopApplicationError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            StopApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopApplicationError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -2365,6 +2581,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>Application created with too many tags, or too many tags added to an application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2372,22 +2590,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidArgument(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2402,12 +2623,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Exception thrown as a result of concurrent modification to an application. For example, two individuals attempting to edit the same application at the same time.</p>
     ConcurrentModification(String),
@@ -2419,6 +2642,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>Application created with too many tags, or too many tags added to an application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2426,24 +2651,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidArgument(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(UntagResourceError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2458,12 +2687,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApplicationError {
     /// <p>User-provided application code (query) is invalid. This can be a simple syntax error.</p>
     CodeValidation(String),
@@ -2477,6 +2708,8 @@ pub enum UpdateApplicationError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApplicationError {
@@ -2484,29 +2717,43 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(UpdateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::CodeValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(UpdateApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2522,6 +2769,7 @@ impl fmt::Display for UpdateApplicationError {
             UpdateApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            UpdateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2678,6 +2926,7 @@ impl KinesisAnalyticsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KinesisAnalyticsClient {
         KinesisAnalyticsClient {
             client: Client::shared(),
@@ -2703,19 +2952,78 @@ impl KinesisAnalyticsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisAnalyticsClient {
         KinesisAnalyticsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KinesisAnalyticsClient> {
+        rusoto_core::ClientBuilder::new(KinesisAnalyticsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KinesisAnalyticsClient {
+        KinesisAnalyticsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl KinesisAnalytics for KinesisAnalyticsClient {
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a CloudWatch log stream to monitor application configuration errors. For more information about using CloudWatch log streams with Amazon Kinesis Analytics applications, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/cloudwatch-logs.html">Working with Amazon CloudWatch Logs</a>.</p></p>
-    async fn add_application_cloud_watch_logging_option(
+impl KinesisAnalyticsClient {
+    /// Calls `add_application_cloud_watch_logging_option`, also returning the metadata of the response.
+    pub async fn add_application_cloud_watch_logging_option_with_metadata(
         &self,
         input: AddApplicationCloudWatchLoggingOptionRequest,
     ) -> Result<
-        AddApplicationCloudWatchLoggingOptionResponse,
+        (
+            AddApplicationCloudWatchLoggingOptionResponse,
+            ResponseMetadata,
+        ),
         RusotoError<AddApplicationCloudWatchLoggingOptionError>,
     > {
+        let request = self.build_add_application_cloud_watch_logging_option_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationCloudWatchLoggingOptionResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddApplicationCloudWatchLoggingOptionError::from_response(
+                response,
+            ))
+        }
+    }
+
+    /// Presigns the request `add_application_cloud_watch_logging_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_cloud_watch_logging_option(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_cloud_watch_logging_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_cloud_watch_logging_option_request(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2725,30 +3033,58 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_application_input`, also returning the metadata of the response.
+    pub async fn add_application_input_with_metadata(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> Result<
+        (AddApplicationInputResponse, ResponseMetadata),
+        RusotoError<AddApplicationInputError>,
+    > {
+        let request = self.build_add_application_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationCloudWatchLoggingOptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationInputResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddApplicationCloudWatchLoggingOptionError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddApplicationInputError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p> Adds a streaming source to your Amazon Kinesis application. For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p> <p>You can add a streaming source either when you create an application or you can use this operation to add a streaming source after you create an application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_CreateApplication.html">CreateApplication</a>.</p> <p>Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationInput</code> action.</p></p>
-    async fn add_application_input(
+    /// Presigns the request `add_application_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_input(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddApplicationInputRequest,
-    ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_input_request(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2758,31 +3094,61 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_application_input_processing_configuration`, also returning the metadata of the response.
+    pub async fn add_application_input_processing_configuration_with_metadata(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        (
+            AddApplicationInputProcessingConfigurationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<AddApplicationInputProcessingConfigurationError>,
+    > {
+        let request = self.build_add_application_input_processing_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationInputResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationInputProcessingConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddApplicationInputError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddApplicationInputProcessingConfigurationError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a> to an application. An input processor preprocesses records on the input stream before the application&#39;s SQL code executes. Currently, the only input processor available is <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a>.</p></p>
-    async fn add_application_input_processing_configuration(
+    /// Presigns the request `add_application_input_processing_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_input_processing_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddApplicationInputProcessingConfigurationRequest,
-    ) -> Result<
-        AddApplicationInputProcessingConfigurationResponse,
-        RusotoError<AddApplicationInputProcessingConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_input_processing_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_input_processing_configuration_request(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInputProcessingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2792,28 +3158,58 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_application_output`, also returning the metadata of the response.
+    pub async fn add_application_output_with_metadata(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> Result<
+        (AddApplicationOutputResponse, ResponseMetadata),
+        RusotoError<AddApplicationOutputError>,
+    > {
+        let request = self.build_add_application_output_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationInputProcessingConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationOutputResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddApplicationInputProcessingConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddApplicationOutputError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an external destination to your Amazon Kinesis Analytics application.</p> <p>If you want Amazon Kinesis Analytics to deliver data from an in-application stream within your application to an external destination (such as an Amazon Kinesis stream, an Amazon Kinesis Firehose delivery stream, or an AWS Lambda function), you add the relevant configuration to your application using this operation. You can configure one or more outputs for your application. Each output configuration maps an in-application stream and an external destination.</p> <p> You can use one of the output configurations to deliver data from your in-application error stream to an external destination so that you can analyze the errors. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Understanding Application Output (Destination)</a>. </p> <p> Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version.</p> <p>For the limits on the number of application inputs and outputs you can configure, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action.</p></p>
-    async fn add_application_output(
+    /// Presigns the request `add_application_output` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_output(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddApplicationOutputRequest,
-    ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_output_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_output_request(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationOutput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2823,31 +3219,60 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_application_reference_data_source`, also returning the metadata of the response.
+    pub async fn add_application_reference_data_source_with_metadata(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        (AddApplicationReferenceDataSourceResponse, ResponseMetadata),
+        RusotoError<AddApplicationReferenceDataSourceError>,
+    > {
+        let request = self.build_add_application_reference_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationOutputResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationReferenceDataSourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddApplicationOutputError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddApplicationReferenceDataSourceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a reference data source to an existing application.</p> <p>Amazon Kinesis Analytics reads reference data (that is, an Amazon S3 object) and creates an in-application table within your application. In the request, you provide the source (S3 bucket name and object key name), name of the in-application table to create, and the necessary mapping information that describes how data in Amazon S3 object maps to columns in the resulting in-application table.</p> <p> For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. For the limits on data sources you can add to your application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>. </p> <p> This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action. </p></p>
-    async fn add_application_reference_data_source(
+    /// Presigns the request `add_application_reference_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_reference_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddApplicationReferenceDataSourceRequest,
-    ) -> Result<
-        AddApplicationReferenceDataSourceResponse,
-        RusotoError<AddApplicationReferenceDataSourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_reference_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_reference_data_source_request(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationReferenceDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2857,30 +3282,53 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_application`, also returning the metadata of the response.
+    pub async fn create_application_with_metadata(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<(CreateApplicationResponse, ResponseMetadata), RusotoError<CreateApplicationError>>
+    {
+        let request = self.build_create_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationReferenceDataSourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddApplicationReferenceDataSourceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateApplicationError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p> Creates an Amazon Kinesis Analytics application. You can configure each application with one streaming source as input, application code to process the input, and up to three destinations where you want Amazon Kinesis Analytics to write the output data from your application. For an overview, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works.html">How it Works</a>. </p> <p>In the input configuration, you map the streaming source to an in-application stream, which you can think of as a constantly updating table. In the mapping, you must provide a schema for the in-application stream and map each data column in the in-application stream to a data element in the streaming source.</p> <p>Your application code is one or more SQL statements that read input data, transform it, and generate output. Your application code can create one or more SQL artifacts like SQL streams or pumps.</p> <p>In the output configuration, you can configure the application to write data from in-application streams created in your applications to up to three destinations.</p> <p> To read data from your source stream or write data to destination streams, Amazon Kinesis Analytics needs your permissions. You grant these permissions by creating IAM roles. This operation requires permissions to perform the <code>kinesisanalytics:CreateApplication</code> action. </p> <p> For introductory exercises to create an Amazon Kinesis Analytics application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/getting-started.html">Getting Started</a>. </p></p>
-    async fn create_application(
+    /// Presigns the request `create_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateApplicationRequest,
-    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_request(&self, input: CreateApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2890,28 +3338,53 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application`, also returning the metadata of the response.
+    pub async fn delete_application_with_metadata(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<(DeleteApplicationResponse, ResponseMetadata), RusotoError<DeleteApplicationError>>
+    {
+        let request = self.build_delete_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes the specified application. Amazon Kinesis Analytics halts application execution and deletes the application, including any application artifacts (such as in-application streams, reference table, and application code).</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DeleteApplication</code> action.</p></p>
-    async fn delete_application(
+    /// Presigns the request `delete_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationRequest,
-    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_request(&self, input: DeleteApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2921,31 +3394,61 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application_cloud_watch_logging_option`, also returning the metadata of the response.
+    pub async fn delete_application_cloud_watch_logging_option_with_metadata(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        (
+            DeleteApplicationCloudWatchLoggingOptionResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
+    > {
+        let request = self.build_delete_application_cloud_watch_logging_option_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationCloudWatchLoggingOptionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationCloudWatchLoggingOptionError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes a CloudWatch log stream from an application. For more information about using CloudWatch log streams with Amazon Kinesis Analytics applications, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/cloudwatch-logs.html">Working with Amazon CloudWatch Logs</a>.</p></p>
-    async fn delete_application_cloud_watch_logging_option(
+    /// Presigns the request `delete_application_cloud_watch_logging_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_cloud_watch_logging_option(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationCloudWatchLoggingOptionRequest,
-    ) -> Result<
-        DeleteApplicationCloudWatchLoggingOptionResponse,
-        RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_cloud_watch_logging_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_cloud_watch_logging_option_request(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2955,31 +3458,62 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application_input_processing_configuration`, also returning the metadata of the response.
+    pub async fn delete_application_input_processing_configuration_with_metadata(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        (
+            DeleteApplicationInputProcessingConfigurationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+    > {
+        let request = self.build_delete_application_input_processing_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationCloudWatchLoggingOptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationInputProcessingConfigurationResponse, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationCloudWatchLoggingOptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationInputProcessingConfigurationError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a> from an input.</p></p>
-    async fn delete_application_input_processing_configuration(
+    /// Presigns the request `delete_application_input_processing_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_input_processing_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationInputProcessingConfigurationRequest,
-    ) -> Result<
-        DeleteApplicationInputProcessingConfigurationResponse,
-        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_input_processing_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_input_processing_configuration_request(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationInputProcessingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2989,28 +3523,58 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application_output`, also returning the metadata of the response.
+    pub async fn delete_application_output_with_metadata(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> Result<
+        (DeleteApplicationOutputResponse, ResponseMetadata),
+        RusotoError<DeleteApplicationOutputError>,
+    > {
+        let request = self.build_delete_application_output_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationInputProcessingConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationOutputResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationInputProcessingConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationOutputError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes output destination configuration from your application configuration. Amazon Kinesis Analytics will no longer write data from the corresponding in-application stream to the external output destination.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DeleteApplicationOutput</code> action.</p></p>
-    async fn delete_application_output(
+    /// Presigns the request `delete_application_output` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_output(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationOutputRequest,
-    ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
-        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_output_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_output_request(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationOutput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3020,31 +3584,63 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application_reference_data_source`, also returning the metadata of the response.
+    pub async fn delete_application_reference_data_source_with_metadata(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        (
+            DeleteApplicationReferenceDataSourceResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteApplicationReferenceDataSourceError>,
+    > {
+        let request = self.build_delete_application_reference_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationOutputResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationReferenceDataSourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationOutputError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationReferenceDataSourceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes a reference data source configuration from the specified application configuration.</p> <p>If the application is running, Amazon Kinesis Analytics immediately removes the in-application table that you created using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_AddApplicationReferenceDataSource.html">AddApplicationReferenceDataSource</a> operation. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics.DeleteApplicationReferenceDataSource</code> action.</p></p>
-    async fn delete_application_reference_data_source(
+    /// Presigns the request `delete_application_reference_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_reference_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationReferenceDataSourceRequest,
-    ) -> Result<
-        DeleteApplicationReferenceDataSourceResponse,
-        RusotoError<DeleteApplicationReferenceDataSourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_reference_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_reference_data_source_request(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationReferenceDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3054,30 +3650,58 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_application`, also returning the metadata of the response.
+    pub async fn describe_application_with_metadata(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<
+        (DescribeApplicationResponse, ResponseMetadata),
+        RusotoError<DescribeApplicationError>,
+    > {
+        let request = self.build_describe_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationReferenceDataSourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationReferenceDataSourceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeApplicationError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Returns information about a specific Amazon Kinesis Analytics application.</p> <p>If you want to retrieve a list of all applications in your account, use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_ListApplications.html">ListApplications</a> operation.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DescribeApplication</code> action. You can use <code>DescribeApplication</code> to get the current application versionId, which you need to call other operations such as <code>Update</code>. </p></p>
-    async fn describe_application(
+    /// Presigns the request `describe_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeApplicationRequest,
-    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_application_request(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DescribeApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3087,28 +3711,58 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `discover_input_schema`, also returning the metadata of the response.
+    pub async fn discover_input_schema_with_metadata(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> Result<
+        (DiscoverInputSchemaResponse, ResponseMetadata),
+        RusotoError<DiscoverInputSchemaError>,
+    > {
+        let request = self.build_discover_input_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DiscoverInputSchemaResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DiscoverInputSchemaError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Infers a schema by evaluating sample records on the specified streaming source (Amazon Kinesis stream or Amazon Kinesis Firehose delivery stream) or S3 object. In the response, the operation returns the inferred schema and also the sample records that the operation used to infer the schema.</p> <p> You can use the inferred schema when configuring a streaming source for your application. For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. Note that when you create an application using the Amazon Kinesis Analytics console, the console uses this operation to infer a schema and show it in the console user interface. </p> <p> This operation requires permissions to perform the <code>kinesisanalytics:DiscoverInputSchema</code> action. </p></p>
-    async fn discover_input_schema(
+    /// Presigns the request `discover_input_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_discover_input_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DiscoverInputSchemaRequest,
-    ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_discover_input_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_discover_input_schema_request(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DiscoverInputSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3118,56 +3772,111 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_applications`, also returning the metadata of the response.
+    pub async fn list_applications_with_metadata(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<(ListApplicationsResponse, ResponseMetadata), RusotoError<ListApplicationsError>>
+    {
+        let request = self.build_list_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DiscoverInputSchemaResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListApplicationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DiscoverInputSchemaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListApplicationsError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Returns a list of Amazon Kinesis Analytics applications in your account. For each application, the response includes the application name, Amazon Resource Name (ARN), and status. If the response returns the <code>HasMoreApplications</code> value as true, you can send another request by adding the <code>ExclusiveStartApplicationName</code> in the request body, and set the value of this to the last application name from the previous response. </p> <p>If you want detailed information about a specific application, use <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a>.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:ListApplications</code> action.</p></p>
-    async fn list_applications(
+    /// Presigns the request `list_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_applications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationsRequest,
-    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_applications_request(&self, input: ListApplicationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApplicationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the list of key-value tags assigned to the application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3177,137 +3886,263 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_application`, also returning the metadata of the response.
+    pub async fn start_application_with_metadata(
+        &self,
+        input: StartApplicationRequest,
+    ) -> Result<(StartApplicationResponse, ResponseMetadata), RusotoError<StartApplicationError>>
+    {
+        let request = self.build_start_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartApplicationError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Starts the specified Amazon Kinesis Analytics application. After creating an application, you must exclusively call this operation to start your application.</p> <p>After the application starts, it begins consuming the input data, processes it, and writes the output to the configured destination.</p> <p> The application status must be <code>READY</code> for you to start an application. You can get the application status in the console or using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation.</p> <p>After you start the application, you can stop the application from processing the input by calling the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_StopApplication.html">StopApplication</a> operation.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:StartApplication</code> action.</p></p>
-    async fn start_application(
+    /// Presigns the request `start_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartApplicationRequest,
-    ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_application_request(&self, input: StartApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StartApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StartApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_application`, also returning the metadata of the response.
+    pub async fn stop_application_with_metadata(
+        &self,
+        input: StopApplicationRequest,
+    ) -> Result<(StopApplicationResponse, ResponseMetadata), RusotoError<StopApplicationError>>
+    {
+        let request = self.build_stop_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopApplicationError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Stops the application from processing input data. You can stop an application only if it is in the running state. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the application state. After the application is stopped, Amazon Kinesis Analytics stops reading data from the input, the application stops processing data, and there is no output written to the destination. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics:StopApplication</code> action.</p></p>
-    async fn stop_application(
+    /// Presigns the request `stop_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopApplicationRequest,
-    ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_application_request(&self, input: StopApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StopApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StopApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more key-value tags to a Kinesis Analytics application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from a Kinesis Analytics application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_application`, also returning the metadata of the response.
+    pub async fn update_application_with_metadata(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<(UpdateApplicationResponse, ResponseMetadata), RusotoError<UpdateApplicationError>>
+    {
+        let request = self.build_update_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateApplicationError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Updates an existing Amazon Kinesis Analytics application. Using this API, you can update application code, input configuration, and output configuration. </p> <p>Note that Amazon Kinesis Analytics updates the <code>CurrentApplicationVersionId</code> each time you update your application. </p> <p>This operation requires permission for the <code>kinesisanalytics:UpdateApplication</code> action.</p></p>
-    async fn update_application(
+    /// Presigns the request `update_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApplicationRequest,
-    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_request(&self, input: UpdateApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3317,19 +4152,227 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl KinesisAnalytics for KinesisAnalyticsClient {
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a CloudWatch log stream to monitor application configuration errors. For more information about using CloudWatch log streams with Amazon Kinesis Analytics applications, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/cloudwatch-logs.html">Working with Amazon CloudWatch Logs</a>.</p></p>
+    async fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<AddApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.add_application_cloud_watch_logging_option_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApplicationResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApplicationError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p> Adds a streaming source to your Amazon Kinesis application. For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p> <p>You can add a streaming source either when you create an application or you can use this operation to add a streaming source after you create an application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_CreateApplication.html">CreateApplication</a>.</p> <p>Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationInput</code> action.</p></p>
+    async fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
+        self.add_application_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a> to an application. An input processor preprocesses records on the input stream before the application&#39;s SQL code executes. Currently, the only input processor available is <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a>.</p></p>
+    async fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        AddApplicationInputProcessingConfigurationResponse,
+        RusotoError<AddApplicationInputProcessingConfigurationError>,
+    > {
+        self.add_application_input_processing_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an external destination to your Amazon Kinesis Analytics application.</p> <p>If you want Amazon Kinesis Analytics to deliver data from an in-application stream within your application to an external destination (such as an Amazon Kinesis stream, an Amazon Kinesis Firehose delivery stream, or an AWS Lambda function), you add the relevant configuration to your application using this operation. You can configure one or more outputs for your application. Each output configuration maps an in-application stream and an external destination.</p> <p> You can use one of the output configurations to deliver data from your in-application error stream to an external destination so that you can analyze the errors. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Understanding Application Output (Destination)</a>. </p> <p> Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version.</p> <p>For the limits on the number of application inputs and outputs you can configure, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action.</p></p>
+    async fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
+        self.add_application_output_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a reference data source to an existing application.</p> <p>Amazon Kinesis Analytics reads reference data (that is, an Amazon S3 object) and creates an in-application table within your application. In the request, you provide the source (S3 bucket name and object key name), name of the in-application table to create, and the necessary mapping information that describes how data in Amazon S3 object maps to columns in the resulting in-application table.</p> <p> For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. For the limits on data sources you can add to your application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>. </p> <p> This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action. </p></p>
+    async fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        AddApplicationReferenceDataSourceResponse,
+        RusotoError<AddApplicationReferenceDataSourceError>,
+    > {
+        self.add_application_reference_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p> Creates an Amazon Kinesis Analytics application. You can configure each application with one streaming source as input, application code to process the input, and up to three destinations where you want Amazon Kinesis Analytics to write the output data from your application. For an overview, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works.html">How it Works</a>. </p> <p>In the input configuration, you map the streaming source to an in-application stream, which you can think of as a constantly updating table. In the mapping, you must provide a schema for the in-application stream and map each data column in the in-application stream to a data element in the streaming source.</p> <p>Your application code is one or more SQL statements that read input data, transform it, and generate output. Your application code can create one or more SQL artifacts like SQL streams or pumps.</p> <p>In the output configuration, you can configure the application to write data from in-application streams created in your applications to up to three destinations.</p> <p> To read data from your source stream or write data to destination streams, Amazon Kinesis Analytics needs your permissions. You grant these permissions by creating IAM roles. This operation requires permissions to perform the <code>kinesisanalytics:CreateApplication</code> action. </p> <p> For introductory exercises to create an Amazon Kinesis Analytics application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/getting-started.html">Getting Started</a>. </p></p>
+    async fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.create_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes the specified application. Amazon Kinesis Analytics halts application execution and deletes the application, including any application artifacts (such as in-application streams, reference table, and application code).</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DeleteApplication</code> action.</p></p>
+    async fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        self.delete_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes a CloudWatch log stream from an application. For more information about using CloudWatch log streams with Amazon Kinesis Analytics applications, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/cloudwatch-logs.html">Working with Amazon CloudWatch Logs</a>.</p></p>
+    async fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.delete_application_cloud_watch_logging_option_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a> from an input.</p></p>
+    async fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+    > {
+        self.delete_application_input_processing_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes output destination configuration from your application configuration. Amazon Kinesis Analytics will no longer write data from the corresponding in-application stream to the external output destination.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DeleteApplicationOutput</code> action.</p></p>
+    async fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
+        self.delete_application_output_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes a reference data source configuration from the specified application configuration.</p> <p>If the application is running, Amazon Kinesis Analytics immediately removes the in-application table that you created using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_AddApplicationReferenceDataSource.html">AddApplicationReferenceDataSource</a> operation. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics.DeleteApplicationReferenceDataSource</code> action.</p></p>
+    async fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        DeleteApplicationReferenceDataSourceResponse,
+        RusotoError<DeleteApplicationReferenceDataSourceError>,
+    > {
+        self.delete_application_reference_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Returns information about a specific Amazon Kinesis Analytics application.</p> <p>If you want to retrieve a list of all applications in your account, use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_ListApplications.html">ListApplications</a> operation.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DescribeApplication</code> action. You can use <code>DescribeApplication</code> to get the current application versionId, which you need to call other operations such as <code>Update</code>. </p></p>
+    async fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+        self.describe_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Infers a schema by evaluating sample records on the specified streaming source (Amazon Kinesis stream or Amazon Kinesis Firehose delivery stream) or S3 object. In the response, the operation returns the inferred schema and also the sample records that the operation used to infer the schema.</p> <p> You can use the inferred schema when configuring a streaming source for your application. For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. Note that when you create an application using the Amazon Kinesis Analytics console, the console uses this operation to infer a schema and show it in the console user interface. </p> <p> This operation requires permissions to perform the <code>kinesisanalytics:DiscoverInputSchema</code> action. </p></p>
+    async fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
+        self.discover_input_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Returns a list of Amazon Kinesis Analytics applications in your account. For each application, the response includes the application name, Amazon Resource Name (ARN), and status. If the response returns the <code>HasMoreApplications</code> value as true, you can send another request by adding the <code>ExclusiveStartApplicationName</code> in the request body, and set the value of this to the last application name from the previous response. </p> <p>If you want detailed information about a specific application, use <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a>.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:ListApplications</code> action.</p></p>
+    async fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.list_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the list of key-value tags assigned to the application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Starts the specified Amazon Kinesis Analytics application. After creating an application, you must exclusively call this operation to start your application.</p> <p>After the application starts, it begins consuming the input data, processes it, and writes the output to the configured destination.</p> <p> The application status must be <code>READY</code> for you to start an application. You can get the application status in the console or using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation.</p> <p>After you start the application, you can stop the application from processing the input by calling the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_StopApplication.html">StopApplication</a> operation.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:StartApplication</code> action.</p></p>
+    async fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
+        self.start_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Stops the application from processing input data. You can stop an application only if it is in the running state. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the application state. After the application is stopped, Amazon Kinesis Analytics stops reading data from the input, the application stops processing data, and there is no output written to the destination. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics:StopApplication</code> action.</p></p>
+    async fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
+        self.stop_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more key-value tags to a Kinesis Analytics application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from a Kinesis Analytics application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Updates an existing Amazon Kinesis Analytics application. Using this API, you can update application code, input configuration, and output configuration. </p> <p>Note that Amazon Kinesis Analytics updates the <code>CurrentApplicationVersionId</code> each time you update your application. </p> <p>This operation requires permission for the <code>kinesisanalytics:UpdateApplication</code> action.</p></p>
+    async fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.update_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2207,6 +2207,7 @@ pub struct VpcConfigurationUpdate {
 
 /// Errors returned by AddApplicationCloudWatchLoggingOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationCloudWatchLoggingOptionError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -2220,6 +2221,8 @@ pub enum AddApplicationCloudWatchLoggingOptionError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationCloudWatchLoggingOptionError {
@@ -2229,39 +2232,50 @@ impl AddApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AddApplicationCloudWatchLoggingOptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2289,12 +2303,16 @@ impl fmt::Display for AddApplicationCloudWatchLoggingOptionError {
             AddApplicationCloudWatchLoggingOptionError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddApplicationCloudWatchLoggingOptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AddApplicationCloudWatchLoggingOptionError {}
 /// Errors returned by AddApplicationInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationInputError {
     /// <p>The user-provided application code (query) is not valid. This can be a simple syntax error.</p>
     CodeValidation(String),
@@ -2308,6 +2326,8 @@ pub enum AddApplicationInputError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationInputError {
@@ -2315,29 +2335,43 @@ impl AddApplicationInputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(AddApplicationInputError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        AddApplicationInputError::CodeValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationInputError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationInputError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationInputError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        AddApplicationInputError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddApplicationInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        AddApplicationInputError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        AddApplicationInputError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationInputError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddApplicationInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2353,12 +2387,14 @@ impl fmt::Display for AddApplicationInputError {
             AddApplicationInputError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             AddApplicationInputError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             AddApplicationInputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AddApplicationInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddApplicationInputError {}
 /// Errors returned by AddApplicationInputProcessingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationInputProcessingConfigurationError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -2370,6 +2406,8 @@ pub enum AddApplicationInputProcessingConfigurationError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationInputProcessingConfigurationError {
@@ -2379,34 +2417,44 @@ impl AddApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::ConcurrentModification(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationInputProcessingConfigurationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AddApplicationInputProcessingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2431,12 +2479,16 @@ impl fmt::Display for AddApplicationInputProcessingConfigurationError {
             AddApplicationInputProcessingConfigurationError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddApplicationInputProcessingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AddApplicationInputProcessingConfigurationError {}
 /// Errors returned by AddApplicationOutput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationOutputError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -2448,6 +2500,8 @@ pub enum AddApplicationOutputError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationOutputError {
@@ -2455,28 +2509,37 @@ impl AddApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationOutputError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationOutputError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationOutputError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddApplicationOutputError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        AddApplicationOutputError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        AddApplicationOutputError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddApplicationOutputError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddApplicationOutputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2491,12 +2554,14 @@ impl fmt::Display for AddApplicationOutputError {
             AddApplicationOutputError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             AddApplicationOutputError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             AddApplicationOutputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AddApplicationOutputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddApplicationOutputError {}
 /// Errors returned by AddApplicationReferenceDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationReferenceDataSourceError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -2508,6 +2573,8 @@ pub enum AddApplicationReferenceDataSourceError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationReferenceDataSourceError {
@@ -2517,32 +2584,42 @@ impl AddApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AddApplicationReferenceDataSourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2567,12 +2644,16 @@ impl fmt::Display for AddApplicationReferenceDataSourceError {
             AddApplicationReferenceDataSourceError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddApplicationReferenceDataSourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AddApplicationReferenceDataSourceError {}
 /// Errors returned by AddApplicationVpcConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddApplicationVpcConfigurationError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -2582,6 +2663,8 @@ pub enum AddApplicationVpcConfigurationError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddApplicationVpcConfigurationError {
@@ -2591,27 +2674,36 @@ impl AddApplicationVpcConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationVpcConfigurationError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationVpcConfigurationError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationVpcConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddApplicationVpcConfigurationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AddApplicationVpcConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2631,12 +2723,16 @@ impl fmt::Display for AddApplicationVpcConfigurationError {
             AddApplicationVpcConfigurationError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddApplicationVpcConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AddApplicationVpcConfigurationError {}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationError {
     /// <p>The user-provided application code (query) is not valid. This can be a simple syntax error.</p>
     CodeValidation(String),
@@ -2652,6 +2748,8 @@ pub enum CreateApplicationError {
     ResourceInUse(String),
     /// <p>Application created with too many tags, or too many tags added to an application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationError {
@@ -2659,30 +2757,46 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(CreateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::CodeValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateApplicationError::TooManyTags(err.msg))
+                    return RusotoError::service(CreateApplicationError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2699,12 +2813,14 @@ impl fmt::Display for CreateApplicationError {
             CreateApplicationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationError {}
 /// Errors returned by CreateApplicationSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationSnapshotError {
     /// <p>The specified input parameter value is not valid.</p>
     InvalidArgument(String),
@@ -2718,6 +2834,8 @@ pub enum CreateApplicationSnapshotError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationSnapshotError {
@@ -2725,37 +2843,45 @@ impl CreateApplicationSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationSnapshotError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationSnapshotError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationSnapshotError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationSnapshotError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationSnapshotError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateApplicationSnapshotError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateApplicationSnapshotError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2773,12 +2899,14 @@ impl fmt::Display for CreateApplicationSnapshotError {
             CreateApplicationSnapshotError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateApplicationSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationSnapshotError {}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -2792,6 +2920,8 @@ pub enum DeleteApplicationError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationError {
@@ -2799,29 +2929,43 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationError::InvalidApplicationConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2839,12 +2983,14 @@ impl fmt::Display for DeleteApplicationError {
             DeleteApplicationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationError {}
 /// Errors returned by DeleteApplicationCloudWatchLoggingOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationCloudWatchLoggingOptionError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -2858,6 +3004,8 @@ pub enum DeleteApplicationCloudWatchLoggingOptionError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationCloudWatchLoggingOptionError {
@@ -2867,39 +3015,50 @@ impl DeleteApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
-                "InvalidApplicationConfigurationException" => return RusotoError::Service(
+                "InvalidApplicationConfigurationException" => return RusotoError::service(
                     DeleteApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteApplicationCloudWatchLoggingOptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2927,12 +3086,16 @@ impl fmt::Display for DeleteApplicationCloudWatchLoggingOptionError {
             DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApplicationCloudWatchLoggingOptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteApplicationCloudWatchLoggingOptionError {}
 /// Errors returned by DeleteApplicationInputProcessingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationInputProcessingConfigurationError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -2944,6 +3107,8 @@ pub enum DeleteApplicationInputProcessingConfigurationError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationInputProcessingConfigurationError {
@@ -2953,38 +3118,48 @@ impl DeleteApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::ConcurrentModification(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::InvalidArgument(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationInputProcessingConfigurationError::ResourceNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteApplicationInputProcessingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3009,12 +3184,16 @@ impl fmt::Display for DeleteApplicationInputProcessingConfigurationError {
             DeleteApplicationInputProcessingConfigurationError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApplicationInputProcessingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteApplicationInputProcessingConfigurationError {}
 /// Errors returned by DeleteApplicationOutput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationOutputError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -3026,6 +3205,8 @@ pub enum DeleteApplicationOutputError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationOutputError {
@@ -3033,32 +3214,37 @@ impl DeleteApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationOutputError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationOutputError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationOutputError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationOutputError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationOutputError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApplicationOutputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3075,12 +3261,14 @@ impl fmt::Display for DeleteApplicationOutputError {
             DeleteApplicationOutputError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteApplicationOutputError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteApplicationOutputError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationOutputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationOutputError {}
 /// Errors returned by DeleteApplicationReferenceDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationReferenceDataSourceError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -3092,6 +3280,8 @@ pub enum DeleteApplicationReferenceDataSourceError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationReferenceDataSourceError {
@@ -3101,32 +3291,42 @@ impl DeleteApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteApplicationReferenceDataSourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3151,12 +3351,16 @@ impl fmt::Display for DeleteApplicationReferenceDataSourceError {
             DeleteApplicationReferenceDataSourceError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApplicationReferenceDataSourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteApplicationReferenceDataSourceError {}
 /// Errors returned by DeleteApplicationSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationSnapshotError {
     /// <p>The specified input parameter value is not valid.</p>
     InvalidArgument(String),
@@ -3168,6 +3372,8 @@ pub enum DeleteApplicationSnapshotError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationSnapshotError {
@@ -3175,32 +3381,39 @@ impl DeleteApplicationSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationSnapshotError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationSnapshotError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationSnapshotError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationSnapshotError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationSnapshotError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteApplicationSnapshotError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3217,12 +3430,14 @@ impl fmt::Display for DeleteApplicationSnapshotError {
             DeleteApplicationSnapshotError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApplicationSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationSnapshotError {}
 /// Errors returned by DeleteApplicationVpcConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationVpcConfigurationError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -3232,6 +3447,8 @@ pub enum DeleteApplicationVpcConfigurationError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationVpcConfigurationError {
@@ -3241,27 +3458,36 @@ impl DeleteApplicationVpcConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationVpcConfigurationError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationVpcConfigurationError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationVpcConfigurationError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApplicationVpcConfigurationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteApplicationVpcConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3283,12 +3509,16 @@ impl fmt::Display for DeleteApplicationVpcConfigurationError {
             DeleteApplicationVpcConfigurationError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApplicationVpcConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteApplicationVpcConfigurationError {}
 /// Errors returned by DescribeApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeApplicationError {
     /// <p>The specified input parameter value is not valid.</p>
     InvalidArgument(String),
@@ -3296,6 +3526,8 @@ pub enum DescribeApplicationError {
     InvalidRequest(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeApplicationError {
@@ -3303,18 +3535,25 @@ impl DescribeApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DescribeApplicationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeApplicationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3327,12 +3566,14 @@ impl fmt::Display for DescribeApplicationError {
             DescribeApplicationError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             DescribeApplicationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeApplicationError {}
 /// Errors returned by DescribeApplicationSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeApplicationSnapshotError {
     /// <p>The specified input parameter value is not valid.</p>
     InvalidArgument(String),
@@ -3340,6 +3581,8 @@ pub enum DescribeApplicationSnapshotError {
     ResourceNotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeApplicationSnapshotError {
@@ -3349,22 +3592,30 @@ impl DescribeApplicationSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeApplicationSnapshotError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationSnapshotError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeApplicationSnapshotError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeApplicationSnapshotError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeApplicationSnapshotError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3379,12 +3630,14 @@ impl fmt::Display for DescribeApplicationSnapshotError {
             DescribeApplicationSnapshotError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeApplicationSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeApplicationSnapshotError {}
 /// Errors returned by DiscoverInputSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DiscoverInputSchemaError {
     /// <p>The specified input parameter value is not valid.</p>
     InvalidArgument(String),
@@ -3396,6 +3649,8 @@ pub enum DiscoverInputSchemaError {
     ServiceUnavailable(String),
     /// <p>The data format is not valid. Amazon Kinesis Data Analytics cannot detect the schema for the given streaming source.</p>
     UnableToDetectSchema(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DiscoverInputSchemaError {
@@ -3403,28 +3658,37 @@ impl DiscoverInputSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DiscoverInputSchemaError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DiscoverInputSchemaError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DiscoverInputSchemaError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnableToDetectSchemaException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::UnableToDetectSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DiscoverInputSchemaError::UnableToDetectSchema(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DiscoverInputSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3441,17 +3705,21 @@ impl fmt::Display for DiscoverInputSchemaError {
             }
             DiscoverInputSchemaError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DiscoverInputSchemaError::UnableToDetectSchema(ref cause) => write!(f, "{}", cause),
+            DiscoverInputSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DiscoverInputSchemaError {}
 /// Errors returned by ListApplicationSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationSnapshotsError {
     /// <p>The specified input parameter value is not valid.</p>
     InvalidArgument(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation. </p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationSnapshotsError {
@@ -3459,17 +3727,19 @@ impl ListApplicationSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListApplicationSnapshotsError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationSnapshotsError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApplicationSnapshotsError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationSnapshotsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3483,15 +3753,19 @@ impl fmt::Display for ListApplicationSnapshotsError {
             ListApplicationSnapshotsError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListApplicationSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationSnapshotsError {}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationsError {
     /// <p>The request JSON is not valid for the operation.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationsError {
@@ -3499,10 +3773,13 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListApplicationsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListApplicationsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3513,12 +3790,14 @@ impl fmt::Display for ListApplicationsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListApplicationsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -3526,6 +3805,8 @@ pub enum ListTagsForResourceError {
     InvalidArgument(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3533,20 +3814,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3559,12 +3845,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by StartApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartApplicationError {
     /// <p>The user-provided application configuration is not valid.</p>
     InvalidApplicationConfiguration(String),
@@ -3576,6 +3864,8 @@ pub enum StartApplicationError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartApplicationError {
@@ -3583,24 +3873,37 @@ impl StartApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartApplicationError::InvalidApplicationConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StartApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        StartApplicationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StartApplicationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StartApplicationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3617,12 +3920,14 @@ impl fmt::Display for StartApplicationError {
             StartApplicationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StartApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartApplicationError {}
 /// Errors returned by StopApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopApplicationError {
     /// <p>The user-provided application configuration is not valid.</p>
     InvalidApplicationConfiguration(String),
@@ -3634,6 +3939,8 @@ pub enum StopApplicationError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopApplicationError {
@@ -3641,24 +3948,34 @@ impl StopApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopApplicationError::InvalidApplicationConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StopApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        StopApplicationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StopApplicationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(StopApplicationError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3675,12 +3992,14 @@ impl fmt::Display for StopApplicationError {
             StopApplicationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StopApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopApplicationError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -3692,6 +4011,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>Application created with too many tags, or too many tags added to an application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -3699,22 +4020,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidArgument(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3729,12 +4053,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Exception thrown as a result of concurrent modifications to an application. This error can be the result of attempting to modify an application without using the current application ID.</p>
     ConcurrentModification(String),
@@ -3746,6 +4072,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>Application created with too many tags, or too many tags added to an application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -3753,24 +4081,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidArgument(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(UntagResourceError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3785,12 +4117,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApplicationError {
     /// <p>The user-provided application code (query) is not valid. This can be a simple syntax error.</p>
     CodeValidation(String),
@@ -3806,6 +4140,8 @@ pub enum UpdateApplicationError {
     ResourceInUse(String),
     /// <p>Specified application can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApplicationError {
@@ -3813,32 +4149,49 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(UpdateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::CodeValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApplicationError::InvalidApplicationConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3857,6 +4210,7 @@ impl fmt::Display for UpdateApplicationError {
             UpdateApplicationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4055,6 +4409,7 @@ impl KinesisAnalyticsV2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KinesisAnalyticsV2Client {
         KinesisAnalyticsV2Client {
             client: Client::shared(),
@@ -4080,315 +4435,625 @@ impl KinesisAnalyticsV2Client {
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisAnalyticsV2Client {
         KinesisAnalyticsV2Client { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KinesisAnalyticsV2Client> {
+        rusoto_core::ClientBuilder::new(KinesisAnalyticsV2Client::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KinesisAnalyticsV2Client {
+        KinesisAnalyticsV2Client {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
-    /// <p>Adds an Amazon CloudWatch log stream to monitor application configuration errors.</p>
-    async fn add_application_cloud_watch_logging_option(
+impl KinesisAnalyticsV2Client {
+    /// Calls `add_application_cloud_watch_logging_option`, also returning the metadata of the response.
+    pub async fn add_application_cloud_watch_logging_option_with_metadata(
         &self,
         input: AddApplicationCloudWatchLoggingOptionRequest,
     ) -> Result<
-        AddApplicationCloudWatchLoggingOptionResponse,
+        (
+            AddApplicationCloudWatchLoggingOptionResponse,
+            ResponseMetadata,
+        ),
         RusotoError<AddApplicationCloudWatchLoggingOptionError>,
     > {
-        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "KinesisAnalytics_20180523.AddApplicationCloudWatchLoggingOption",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        let request = self.build_add_application_cloud_watch_logging_option_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationCloudWatchLoggingOptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationCloudWatchLoggingOptionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AddApplicationCloudWatchLoggingOptionError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p> Adds a streaming source to your SQL-based Amazon Kinesis Data Analytics application. </p> <p>You can add a streaming source when you create an application, or you can use this operation to add a streaming source after you create an application. For more information, see <a>CreateApplication</a>.</p> <p>Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a>DescribeApplication</a> operation to find the current application version. </p>
-    async fn add_application_input(
+    /// Presigns the request `add_application_cloud_watch_logging_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_cloud_watch_logging_option(
         &self,
-        input: AddApplicationInputRequest,
-    ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_cloud_watch_logging_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_cloud_watch_logging_option_request(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "KinesisAnalytics_20180523.AddApplicationInput",
+            "KinesisAnalytics_20180523.AddApplicationCloudWatchLoggingOption",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_application_input`, also returning the metadata of the response.
+    pub async fn add_application_input_with_metadata(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> Result<
+        (AddApplicationInputResponse, ResponseMetadata),
+        RusotoError<AddApplicationInputError>,
+    > {
+        let request = self.build_add_application_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationInputResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationInputResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AddApplicationInputError::from_response(response))
         }
     }
 
-    /// <p>Adds an <a>InputProcessingConfiguration</a> to an SQL-based Kinesis Data Analytics application. An input processor pre-processes records on the input stream before the application's SQL code executes. Currently, the only input processor available is <a href="https://aws.amazon.com/documentation/lambda/">AWS Lambda</a>.</p>
-    async fn add_application_input_processing_configuration(
+    /// Presigns the request `add_application_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_input(
         &self,
-        input: AddApplicationInputProcessingConfigurationRequest,
-    ) -> Result<
-        AddApplicationInputProcessingConfigurationResponse,
-        RusotoError<AddApplicationInputProcessingConfigurationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: AddApplicationInputRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_input_request(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "KinesisAnalytics_20180523.AddApplicationInputProcessingConfiguration",
+            "KinesisAnalytics_20180523.AddApplicationInput",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_application_input_processing_configuration`, also returning the metadata of the response.
+    pub async fn add_application_input_processing_configuration_with_metadata(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        (
+            AddApplicationInputProcessingConfigurationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<AddApplicationInputProcessingConfigurationError>,
+    > {
+        let request = self.build_add_application_input_processing_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationInputProcessingConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationInputProcessingConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AddApplicationInputProcessingConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Adds an external destination to your SQL-based Amazon Kinesis Data Analytics application.</p> <p>If you want Kinesis Data Analytics to deliver data from an in-application stream within your application to an external destination (such as an Kinesis data stream, a Kinesis Data Firehose delivery stream, or an AWS Lambda function), you add the relevant configuration to your application using this operation. You can configure one or more outputs for your application. Each output configuration maps an in-application stream and an external destination.</p> <p> You can use one of the output configurations to deliver data from your in-application error stream to an external destination so that you can analyze the errors. </p> <p> Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a>DescribeApplication</a> operation to find the current application version.</p>
-    async fn add_application_output(
+    /// Presigns the request `add_application_input_processing_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_input_processing_configuration(
         &self,
-        input: AddApplicationOutputRequest,
-    ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_input_processing_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_input_processing_configuration_request(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInputProcessingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "KinesisAnalytics_20180523.AddApplicationOutput",
+            "KinesisAnalytics_20180523.AddApplicationInputProcessingConfiguration",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_application_output`, also returning the metadata of the response.
+    pub async fn add_application_output_with_metadata(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> Result<
+        (AddApplicationOutputResponse, ResponseMetadata),
+        RusotoError<AddApplicationOutputError>,
+    > {
+        let request = self.build_add_application_output_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationOutputResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationOutputResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AddApplicationOutputError::from_response(response))
         }
     }
 
-    /// <p>Adds a reference data source to an existing SQL-based Amazon Kinesis Data Analytics application.</p> <p>Kinesis Data Analytics reads reference data (that is, an Amazon S3 object) and creates an in-application table within your application. In the request, you provide the source (S3 bucket name and object key name), name of the in-application table to create, and the necessary mapping information that describes how data in an Amazon S3 object maps to columns in the resulting in-application table.</p>
-    async fn add_application_reference_data_source(
+    /// Presigns the request `add_application_output` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_output(
         &self,
-        input: AddApplicationReferenceDataSourceRequest,
-    ) -> Result<
-        AddApplicationReferenceDataSourceResponse,
-        RusotoError<AddApplicationReferenceDataSourceError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: AddApplicationOutputRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_output_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_output_request(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationOutput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "KinesisAnalytics_20180523.AddApplicationReferenceDataSource",
+            "KinesisAnalytics_20180523.AddApplicationOutput",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_application_reference_data_source`, also returning the metadata of the response.
+    pub async fn add_application_reference_data_source_with_metadata(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        (AddApplicationReferenceDataSourceResponse, ResponseMetadata),
+        RusotoError<AddApplicationReferenceDataSourceError>,
+    > {
+        let request = self.build_add_application_reference_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationReferenceDataSourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationReferenceDataSourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AddApplicationReferenceDataSourceError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p><p>Adds a Virtual Private Cloud (VPC) configuration to the application. Applications can use VPCs to store and access resources securely.</p> <p>Note the following about VPC configurations for Kinesis Data Analytics applications:</p> <ul> <li> <p>VPC configurations are not supported for SQL applications.</p> </li> <li> <p>When a VPC is added to a Kinesis Data Analytics application, the application can no longer be accessed from the Internet directly. To enable Internet access to the application, add an Internet gateway to your VPC.</p> </li> </ul></p>
-    async fn add_application_vpc_configuration(
+    /// Presigns the request `add_application_reference_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_reference_data_source(
         &self,
-        input: AddApplicationVpcConfigurationRequest,
-    ) -> Result<
-        AddApplicationVpcConfigurationResponse,
-        RusotoError<AddApplicationVpcConfigurationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_reference_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_reference_data_source_request(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationReferenceDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "KinesisAnalytics_20180523.AddApplicationVpcConfiguration",
+            "KinesisAnalytics_20180523.AddApplicationReferenceDataSource",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_application_vpc_configuration`, also returning the metadata of the response.
+    pub async fn add_application_vpc_configuration_with_metadata(
+        &self,
+        input: AddApplicationVpcConfigurationRequest,
+    ) -> Result<
+        (AddApplicationVpcConfigurationResponse, ResponseMetadata),
+        RusotoError<AddApplicationVpcConfigurationError>,
+    > {
+        let request = self.build_add_application_vpc_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddApplicationVpcConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddApplicationVpcConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AddApplicationVpcConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon Kinesis Data Analytics application. For information about creating a Kinesis Data Analytics application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/getting-started.html">Creating an Application</a>. </p>
-    async fn create_application(
+    /// Presigns the request `add_application_vpc_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_application_vpc_configuration(
         &self,
-        input: CreateApplicationRequest,
-    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AddApplicationVpcConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_application_vpc_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_application_vpc_configuration_request(
+        &self,
+        input: AddApplicationVpcConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationVpcConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "KinesisAnalytics_20180523.CreateApplication",
+            "KinesisAnalytics_20180523.AddApplicationVpcConfiguration",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `create_application`, also returning the metadata of the response.
+    pub async fn create_application_with_metadata(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<(CreateApplicationResponse, ResponseMetadata), RusotoError<CreateApplicationError>>
+    {
+        let request = self.build_create_application_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateApplicationError::from_response(response))
         }
     }
 
-    /// <p>Creates a snapshot of the application's state data.</p>
-    async fn create_application_snapshot(
+    /// Presigns the request `create_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application(
         &self,
-        input: CreateApplicationSnapshotRequest,
-    ) -> Result<CreateApplicationSnapshotResponse, RusotoError<CreateApplicationSnapshotError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_request(&self, input: CreateApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "KinesisAnalytics_20180523.CreateApplicationSnapshot",
+            "KinesisAnalytics_20180523.CreateApplication",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_application_snapshot`, also returning the metadata of the response.
+    pub async fn create_application_snapshot_with_metadata(
+        &self,
+        input: CreateApplicationSnapshotRequest,
+    ) -> Result<
+        (CreateApplicationSnapshotResponse, ResponseMetadata),
+        RusotoError<CreateApplicationSnapshotError>,
+    > {
+        let request = self.build_create_application_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApplicationSnapshotResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApplicationSnapshotResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateApplicationSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified application. Kinesis Data Analytics halts application execution and deletes the application.</p>
-    async fn delete_application(
+    /// Presigns the request `create_application_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application_snapshot(
         &self,
-        input: DeleteApplicationRequest,
-    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateApplicationSnapshotRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_snapshot_request(
+        &self,
+        input: CreateApplicationSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("CreateApplicationSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "KinesisAnalytics_20180523.DeleteApplication",
+            "KinesisAnalytics_20180523.CreateApplicationSnapshot",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application`, also returning the metadata of the response.
+    pub async fn delete_application_with_metadata(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<(DeleteApplicationResponse, ResponseMetadata), RusotoError<DeleteApplicationError>>
+    {
+        let request = self.build_delete_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(DeleteApplicationError::from_response(response))
         }
     }
 
-    /// <p>Deletes an Amazon CloudWatch log stream from an Amazon Kinesis Data Analytics application. </p>
-    async fn delete_application_cloud_watch_logging_option(
+    /// Presigns the request `delete_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_request(&self, input: DeleteApplicationRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplication");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "KinesisAnalytics_20180523.DeleteApplication",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `delete_application_cloud_watch_logging_option`, also returning the metadata of the response.
+    pub async fn delete_application_cloud_watch_logging_option_with_metadata(
         &self,
         input: DeleteApplicationCloudWatchLoggingOptionRequest,
     ) -> Result<
-        DeleteApplicationCloudWatchLoggingOptionResponse,
+        (
+            DeleteApplicationCloudWatchLoggingOptionResponse,
+            ResponseMetadata,
+        ),
         RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
     > {
+        let request = self.build_delete_application_cloud_watch_logging_option_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationCloudWatchLoggingOptionResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationCloudWatchLoggingOptionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_application_cloud_watch_logging_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_cloud_watch_logging_option(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_cloud_watch_logging_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_cloud_watch_logging_option_request(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4398,31 +5063,62 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application_input_processing_configuration`, also returning the metadata of the response.
+    pub async fn delete_application_input_processing_configuration_with_metadata(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        (
+            DeleteApplicationInputProcessingConfigurationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+    > {
+        let request = self.build_delete_application_input_processing_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationCloudWatchLoggingOptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationInputProcessingConfigurationResponse, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationCloudWatchLoggingOptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationInputProcessingConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes an <a>InputProcessingConfiguration</a> from an input.</p>
-    async fn delete_application_input_processing_configuration(
+    /// Presigns the request `delete_application_input_processing_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_input_processing_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationInputProcessingConfigurationRequest,
-    ) -> Result<
-        DeleteApplicationInputProcessingConfigurationResponse,
-        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_input_processing_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_input_processing_configuration_request(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationInputProcessingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4432,28 +5128,58 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application_output`, also returning the metadata of the response.
+    pub async fn delete_application_output_with_metadata(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> Result<
+        (DeleteApplicationOutputResponse, ResponseMetadata),
+        RusotoError<DeleteApplicationOutputError>,
+    > {
+        let request = self.build_delete_application_output_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationInputProcessingConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationOutputResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationInputProcessingConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationOutputError::from_response(response))
         }
     }
 
-    /// <p>Deletes the output destination configuration from your SQL-based Amazon Kinesis Data Analytics application's configuration. Kinesis Data Analytics will no longer write data from the corresponding in-application stream to the external output destination.</p>
-    async fn delete_application_output(
+    /// Presigns the request `delete_application_output` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_output(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationOutputRequest,
-    ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_output_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_output_request(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationOutput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4463,31 +5189,63 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application_reference_data_source`, also returning the metadata of the response.
+    pub async fn delete_application_reference_data_source_with_metadata(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        (
+            DeleteApplicationReferenceDataSourceResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteApplicationReferenceDataSourceError>,
+    > {
+        let request = self.build_delete_application_reference_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationOutputResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationReferenceDataSourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationOutputError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationReferenceDataSourceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes a reference data source configuration from the specified SQL-based Amazon Kinesis Data Analytics application's configuration.</p> <p>If the application is running, Kinesis Data Analytics immediately removes the in-application table that you created using the <a>AddApplicationReferenceDataSource</a> operation. </p>
-    async fn delete_application_reference_data_source(
+    /// Presigns the request `delete_application_reference_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_reference_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationReferenceDataSourceRequest,
-    ) -> Result<
-        DeleteApplicationReferenceDataSourceResponse,
-        RusotoError<DeleteApplicationReferenceDataSourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_reference_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_reference_data_source_request(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationReferenceDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4497,31 +5255,58 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application_snapshot`, also returning the metadata of the response.
+    pub async fn delete_application_snapshot_with_metadata(
+        &self,
+        input: DeleteApplicationSnapshotRequest,
+    ) -> Result<
+        (DeleteApplicationSnapshotResponse, ResponseMetadata),
+        RusotoError<DeleteApplicationSnapshotError>,
+    > {
+        let request = self.build_delete_application_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationReferenceDataSourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationSnapshotResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationReferenceDataSourceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Deletes a snapshot of application state.</p>
-    async fn delete_application_snapshot(
+    /// Presigns the request `delete_application_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationSnapshotRequest,
-    ) -> Result<DeleteApplicationSnapshotResponse, RusotoError<DeleteApplicationSnapshotError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_snapshot_request(
+        &self,
+        input: DeleteApplicationSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4531,31 +5316,60 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application_vpc_configuration`, also returning the metadata of the response.
+    pub async fn delete_application_vpc_configuration_with_metadata(
+        &self,
+        input: DeleteApplicationVpcConfigurationRequest,
+    ) -> Result<
+        (DeleteApplicationVpcConfigurationResponse, ResponseMetadata),
+        RusotoError<DeleteApplicationVpcConfigurationError>,
+    > {
+        let request = self.build_delete_application_vpc_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationSnapshotResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationVpcConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationVpcConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Removes a VPC configuration from a Kinesis Data Analytics application.</p>
-    async fn delete_application_vpc_configuration(
+    /// Presigns the request `delete_application_vpc_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_vpc_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationVpcConfigurationRequest,
-    ) -> Result<
-        DeleteApplicationVpcConfigurationResponse,
-        RusotoError<DeleteApplicationVpcConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_vpc_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_vpc_configuration_request(
+        &self,
+        input: DeleteApplicationVpcConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationVpcConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4565,30 +5379,58 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_application`, also returning the metadata of the response.
+    pub async fn describe_application_with_metadata(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<
+        (DescribeApplicationResponse, ResponseMetadata),
+        RusotoError<DescribeApplicationError>,
+    > {
+        let request = self.build_describe_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationVpcConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationVpcConfigurationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeApplicationError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific Amazon Kinesis Data Analytics application.</p> <p>If you want to retrieve a list of all applications in your account, use the <a>ListApplications</a> operation.</p>
-    async fn describe_application(
+    /// Presigns the request `describe_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeApplicationRequest,
-    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_application_request(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DescribeApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4598,29 +5440,58 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_application_snapshot`, also returning the metadata of the response.
+    pub async fn describe_application_snapshot_with_metadata(
+        &self,
+        input: DescribeApplicationSnapshotRequest,
+    ) -> Result<
+        (DescribeApplicationSnapshotResponse, ResponseMetadata),
+        RusotoError<DescribeApplicationSnapshotError>,
+    > {
+        let request = self.build_describe_application_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeApplicationSnapshotResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeApplicationSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a snapshot of application state data.</p>
-    async fn describe_application_snapshot(
+    /// Presigns the request `describe_application_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_application_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeApplicationSnapshotRequest,
-    ) -> Result<DescribeApplicationSnapshotResponse, RusotoError<DescribeApplicationSnapshotError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_application_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_application_snapshot_request(
+        &self,
+        input: DescribeApplicationSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DescribeApplicationSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4630,28 +5501,58 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `discover_input_schema`, also returning the metadata of the response.
+    pub async fn discover_input_schema_with_metadata(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> Result<
+        (DiscoverInputSchemaResponse, ResponseMetadata),
+        RusotoError<DiscoverInputSchemaError>,
+    > {
+        let request = self.build_discover_input_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeApplicationSnapshotResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DiscoverInputSchemaResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeApplicationSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DiscoverInputSchemaError::from_response(response))
         }
     }
 
-    /// <p>Infers a schema for an SQL-based Amazon Kinesis Data Analytics application by evaluating sample records on the specified streaming source (Kinesis data stream or Kinesis Data Firehose delivery stream) or Amazon S3 object. In the response, the operation returns the inferred schema and also the sample records that the operation used to infer the schema.</p> <p> You can use the inferred schema when configuring a streaming source for your application. When you create an application using the Kinesis Data Analytics console, the console uses this operation to infer a schema and show it in the console user interface. </p>
-    async fn discover_input_schema(
+    /// Presigns the request `discover_input_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_discover_input_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DiscoverInputSchemaRequest,
-    ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_discover_input_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_discover_input_schema_request(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DiscoverInputSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4661,28 +5562,58 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_application_snapshots`, also returning the metadata of the response.
+    pub async fn list_application_snapshots_with_metadata(
+        &self,
+        input: ListApplicationSnapshotsRequest,
+    ) -> Result<
+        (ListApplicationSnapshotsResponse, ResponseMetadata),
+        RusotoError<ListApplicationSnapshotsError>,
+    > {
+        let request = self.build_list_application_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DiscoverInputSchemaResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListApplicationSnapshotsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DiscoverInputSchemaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListApplicationSnapshotsError::from_response(response))
         }
     }
 
-    /// <p>Lists information about the current application snapshots.</p>
-    async fn list_application_snapshots(
+    /// Presigns the request `list_application_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_application_snapshots(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationSnapshotsRequest,
-    ) -> Result<ListApplicationSnapshotsResponse, RusotoError<ListApplicationSnapshotsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_application_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_application_snapshots_request(
+        &self,
+        input: ListApplicationSnapshotsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListApplicationSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4692,56 +5623,111 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_applications`, also returning the metadata of the response.
+    pub async fn list_applications_with_metadata(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<(ListApplicationsResponse, ResponseMetadata), RusotoError<ListApplicationsError>>
+    {
+        let request = self.build_list_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApplicationSnapshotsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListApplicationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationSnapshotsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListApplicationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of Amazon Kinesis Data Analytics applications in your account. For each application, the response includes the application name, Amazon Resource Name (ARN), and status. </p> <p>If you want detailed information about a specific application, use <a>DescribeApplication</a>.</p>
-    async fn list_applications(
+    /// Presigns the request `list_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_applications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationsRequest,
-    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
-        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_applications_request(&self, input: ListApplicationsRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApplicationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the list of key-value tags assigned to the application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/how-tagging.html">Using Tagging</a>.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4751,137 +5737,263 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_application`, also returning the metadata of the response.
+    pub async fn start_application_with_metadata(
+        &self,
+        input: StartApplicationRequest,
+    ) -> Result<(StartApplicationResponse, ResponseMetadata), RusotoError<StartApplicationError>>
+    {
+        let request = self.build_start_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartApplicationError::from_response(response))
         }
     }
 
-    /// <p>Starts the specified Amazon Kinesis Data Analytics application. After creating an application, you must exclusively call this operation to start your application.</p>
-    async fn start_application(
+    /// Presigns the request `start_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartApplicationRequest,
-    ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_application_request(&self, input: StartApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StartApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.StartApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_application`, also returning the metadata of the response.
+    pub async fn stop_application_with_metadata(
+        &self,
+        input: StopApplicationRequest,
+    ) -> Result<(StopApplicationResponse, ResponseMetadata), RusotoError<StopApplicationError>>
+    {
+        let request = self.build_stop_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopApplicationError::from_response(response))
         }
     }
 
-    /// <p>Stops the application from processing data. You can stop an application only if it is in the running state. You can use the <a>DescribeApplication</a> operation to find the application state. </p>
-    async fn stop_application(
+    /// Presigns the request `stop_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopApplicationRequest,
-    ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_application_request(&self, input: StopApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StopApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.StopApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more key-value tags to a Kinesis Analytics application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/how-tagging.html">Using Tagging</a>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from a Kinesis Analytics application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/how-tagging.html">Using Tagging</a>.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_application`, also returning the metadata of the response.
+    pub async fn update_application_with_metadata(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<(UpdateApplicationResponse, ResponseMetadata), RusotoError<UpdateApplicationError>>
+    {
+        let request = self.build_update_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateApplicationError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing Amazon Kinesis Data Analytics application. Using this operation, you can update application code, input configuration, and output configuration. </p> <p>Kinesis Data Analytics updates the <code>ApplicationVersionId</code> each time you update your application. </p>
-    async fn update_application(
+    /// Presigns the request `update_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApplicationRequest,
-    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_request(&self, input: UpdateApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4891,19 +6003,375 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListApplicationSnapshots` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_application_snapshots_pages(
+        &self,
+        input: ListApplicationSnapshotsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListApplicationSnapshotsResponse, RusotoError<ListApplicationSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisAnalyticsV2Client, input| async move {
+                client.list_application_snapshots(input).await
+            },
+            |input: &ListApplicationSnapshotsRequest, output: &ListApplicationSnapshotsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApplicationSnapshotsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SnapshotSummaries` of every page of `ListApplicationSnapshots` results for `input`, as `list_application_snapshots_pages` requests them.
+    pub fn list_application_snapshots_stream(
+        &self,
+        input: ListApplicationSnapshotsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SnapshotDetails, RusotoError<ListApplicationSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_application_snapshots_pages(input), |page| {
+            page.snapshot_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListApplications` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_applications_pages(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListApplicationsResponse, RusotoError<ListApplicationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisAnalyticsV2Client, input| async move {
+                client.list_applications(input).await
+            },
+            |input: &ListApplicationsRequest, output: &ListApplicationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApplicationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ApplicationSummaries` of every page of `ListApplications` results for `input`, as `list_applications_pages` requests them.
+    pub fn list_applications_stream(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> impl futures::Stream<Item = Result<ApplicationSummary, RusotoError<ListApplicationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_applications_pages(input), |page| {
+            page.application_summaries
+        })
+    }
+}
+
+#[async_trait]
+impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
+    /// <p>Adds an Amazon CloudWatch log stream to monitor application configuration errors.</p>
+    async fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<AddApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.add_application_cloud_watch_logging_option_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApplicationResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApplicationError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Adds a streaming source to your SQL-based Amazon Kinesis Data Analytics application. </p> <p>You can add a streaming source when you create an application, or you can use this operation to add a streaming source after you create an application. For more information, see <a>CreateApplication</a>.</p> <p>Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a>DescribeApplication</a> operation to find the current application version. </p>
+    async fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
+        self.add_application_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an <a>InputProcessingConfiguration</a> to an SQL-based Kinesis Data Analytics application. An input processor pre-processes records on the input stream before the application's SQL code executes. Currently, the only input processor available is <a href="https://aws.amazon.com/documentation/lambda/">AWS Lambda</a>.</p>
+    async fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        AddApplicationInputProcessingConfigurationResponse,
+        RusotoError<AddApplicationInputProcessingConfigurationError>,
+    > {
+        self.add_application_input_processing_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an external destination to your SQL-based Amazon Kinesis Data Analytics application.</p> <p>If you want Kinesis Data Analytics to deliver data from an in-application stream within your application to an external destination (such as an Kinesis data stream, a Kinesis Data Firehose delivery stream, or an AWS Lambda function), you add the relevant configuration to your application using this operation. You can configure one or more outputs for your application. Each output configuration maps an in-application stream and an external destination.</p> <p> You can use one of the output configurations to deliver data from your in-application error stream to an external destination so that you can analyze the errors. </p> <p> Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a>DescribeApplication</a> operation to find the current application version.</p>
+    async fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
+        self.add_application_output_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a reference data source to an existing SQL-based Amazon Kinesis Data Analytics application.</p> <p>Kinesis Data Analytics reads reference data (that is, an Amazon S3 object) and creates an in-application table within your application. In the request, you provide the source (S3 bucket name and object key name), name of the in-application table to create, and the necessary mapping information that describes how data in an Amazon S3 object maps to columns in the resulting in-application table.</p>
+    async fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        AddApplicationReferenceDataSourceResponse,
+        RusotoError<AddApplicationReferenceDataSourceError>,
+    > {
+        self.add_application_reference_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Adds a Virtual Private Cloud (VPC) configuration to the application. Applications can use VPCs to store and access resources securely.</p> <p>Note the following about VPC configurations for Kinesis Data Analytics applications:</p> <ul> <li> <p>VPC configurations are not supported for SQL applications.</p> </li> <li> <p>When a VPC is added to a Kinesis Data Analytics application, the application can no longer be accessed from the Internet directly. To enable Internet access to the application, add an Internet gateway to your VPC.</p> </li> </ul></p>
+    async fn add_application_vpc_configuration(
+        &self,
+        input: AddApplicationVpcConfigurationRequest,
+    ) -> Result<
+        AddApplicationVpcConfigurationResponse,
+        RusotoError<AddApplicationVpcConfigurationError>,
+    > {
+        self.add_application_vpc_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon Kinesis Data Analytics application. For information about creating a Kinesis Data Analytics application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/getting-started.html">Creating an Application</a>. </p>
+    async fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.create_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a snapshot of the application's state data.</p>
+    async fn create_application_snapshot(
+        &self,
+        input: CreateApplicationSnapshotRequest,
+    ) -> Result<CreateApplicationSnapshotResponse, RusotoError<CreateApplicationSnapshotError>>
+    {
+        self.create_application_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified application. Kinesis Data Analytics halts application execution and deletes the application.</p>
+    async fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        self.delete_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Amazon CloudWatch log stream from an Amazon Kinesis Data Analytics application. </p>
+    async fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.delete_application_cloud_watch_logging_option_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an <a>InputProcessingConfiguration</a> from an input.</p>
+    async fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+    > {
+        self.delete_application_input_processing_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the output destination configuration from your SQL-based Amazon Kinesis Data Analytics application's configuration. Kinesis Data Analytics will no longer write data from the corresponding in-application stream to the external output destination.</p>
+    async fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
+        self.delete_application_output_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a reference data source configuration from the specified SQL-based Amazon Kinesis Data Analytics application's configuration.</p> <p>If the application is running, Kinesis Data Analytics immediately removes the in-application table that you created using the <a>AddApplicationReferenceDataSource</a> operation. </p>
+    async fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        DeleteApplicationReferenceDataSourceResponse,
+        RusotoError<DeleteApplicationReferenceDataSourceError>,
+    > {
+        self.delete_application_reference_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a snapshot of application state.</p>
+    async fn delete_application_snapshot(
+        &self,
+        input: DeleteApplicationSnapshotRequest,
+    ) -> Result<DeleteApplicationSnapshotResponse, RusotoError<DeleteApplicationSnapshotError>>
+    {
+        self.delete_application_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a VPC configuration from a Kinesis Data Analytics application.</p>
+    async fn delete_application_vpc_configuration(
+        &self,
+        input: DeleteApplicationVpcConfigurationRequest,
+    ) -> Result<
+        DeleteApplicationVpcConfigurationResponse,
+        RusotoError<DeleteApplicationVpcConfigurationError>,
+    > {
+        self.delete_application_vpc_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific Amazon Kinesis Data Analytics application.</p> <p>If you want to retrieve a list of all applications in your account, use the <a>ListApplications</a> operation.</p>
+    async fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+        self.describe_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a snapshot of application state data.</p>
+    async fn describe_application_snapshot(
+        &self,
+        input: DescribeApplicationSnapshotRequest,
+    ) -> Result<DescribeApplicationSnapshotResponse, RusotoError<DescribeApplicationSnapshotError>>
+    {
+        self.describe_application_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Infers a schema for an SQL-based Amazon Kinesis Data Analytics application by evaluating sample records on the specified streaming source (Kinesis data stream or Kinesis Data Firehose delivery stream) or Amazon S3 object. In the response, the operation returns the inferred schema and also the sample records that the operation used to infer the schema.</p> <p> You can use the inferred schema when configuring a streaming source for your application. When you create an application using the Kinesis Data Analytics console, the console uses this operation to infer a schema and show it in the console user interface. </p>
+    async fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
+        self.discover_input_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists information about the current application snapshots.</p>
+    async fn list_application_snapshots(
+        &self,
+        input: ListApplicationSnapshotsRequest,
+    ) -> Result<ListApplicationSnapshotsResponse, RusotoError<ListApplicationSnapshotsError>> {
+        self.list_application_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of Amazon Kinesis Data Analytics applications in your account. For each application, the response includes the application name, Amazon Resource Name (ARN), and status. </p> <p>If you want detailed information about a specific application, use <a>DescribeApplication</a>.</p>
+    async fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.list_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the list of key-value tags assigned to the application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/how-tagging.html">Using Tagging</a>.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the specified Amazon Kinesis Data Analytics application. After creating an application, you must exclusively call this operation to start your application.</p>
+    async fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
+        self.start_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the application from processing data. You can stop an application only if it is in the running state. You can use the <a>DescribeApplication</a> operation to find the application state. </p>
+    async fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
+        self.stop_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more key-value tags to a Kinesis Analytics application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/how-tagging.html">Using Tagging</a>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from a Kinesis Analytics application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/how-tagging.html">Using Tagging</a>.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing Amazon Kinesis Data Analytics application. Using this operation, you can update application code, input configuration, and output configuration. </p> <p>Kinesis Data Analytics updates the <code>ApplicationVersionId</code> each time you update your application. </p>
+    async fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.update_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
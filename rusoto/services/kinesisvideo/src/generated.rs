@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -626,6 +626,7 @@ pub struct UpdateStreamOutput {}
 
 /// Errors returned by CreateSignalingChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSignalingChannelError {
     /// <p>You do not have required permissions to perform this operation.</p>
     AccessDenied(String),
@@ -639,6 +640,8 @@ pub enum CreateSignalingChannelError {
     ResourceInUse(String),
     /// <p>You have exceeded the limit of tags that you can associate with the resource. Kinesis video streams support up to 50 tags. </p>
     TagsPerResourceExceededLimit(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSignalingChannelError {
@@ -646,35 +649,43 @@ impl CreateSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateSignalingChannelError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountChannelLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSignalingChannelError::AccountChannelLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSignalingChannelError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSignalingChannelError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSignalingChannelError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSignalingChannelError::TagsPerResourceExceededLimit(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSignalingChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -694,12 +705,14 @@ impl fmt::Display for CreateSignalingChannelError {
             CreateSignalingChannelError::TagsPerResourceExceededLimit(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateSignalingChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSignalingChannelError {}
 /// Errors returned by CreateStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStreamError {
     /// <p>The number of streams created for the account is too high.</p>
     AccountStreamLimitExceeded(String),
@@ -715,6 +728,8 @@ pub enum CreateStreamError {
     ResourceInUse(String),
     /// <p>You have exceeded the limit of tags that you can associate with the resource. Kinesis video streams support up to 50 tags. </p>
     TagsPerResourceExceededLimit(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStreamError {
@@ -722,34 +737,40 @@ impl CreateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountStreamLimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::AccountStreamLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStreamError::AccountStreamLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateStreamError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DeviceStreamLimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::DeviceStreamLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStreamError::DeviceStreamLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(CreateStreamError::InvalidArgument(err.msg), &res)
                 }
                 "InvalidDeviceException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidDevice(err.msg))
+                    return RusotoError::service(CreateStreamError::InvalidDevice(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateStreamError::ResourceInUse(err.msg), &res)
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(CreateStreamError::TagsPerResourceExceededLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStreamError::TagsPerResourceExceededLimit(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -766,12 +787,14 @@ impl fmt::Display for CreateStreamError {
             CreateStreamError::InvalidDevice(ref cause) => write!(f, "{}", cause),
             CreateStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateStreamError::TagsPerResourceExceededLimit(ref cause) => write!(f, "{}", cause),
+            CreateStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStreamError {}
 /// Errors returned by DeleteSignalingChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSignalingChannelError {
     /// <p>You do not have required permissions to perform this operation.</p>
     AccessDenied(String),
@@ -783,6 +806,8 @@ pub enum DeleteSignalingChannelError {
     ResourceNotFound(String),
     /// <p>The stream version that you specified is not the latest version. To get the latest version, use the <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_DescribeStream.html">DescribeStream</a> API.</p>
     VersionMismatch(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSignalingChannelError {
@@ -790,30 +815,37 @@ impl DeleteSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteSignalingChannelError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSignalingChannelError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSignalingChannelError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSignalingChannelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::VersionMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSignalingChannelError::VersionMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSignalingChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -828,12 +860,14 @@ impl fmt::Display for DeleteSignalingChannelError {
             DeleteSignalingChannelError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             DeleteSignalingChannelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteSignalingChannelError::VersionMismatch(ref cause) => write!(f, "{}", cause),
+            DeleteSignalingChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSignalingChannelError {}
 /// Errors returned by DeleteStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStreamError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -845,6 +879,8 @@ pub enum DeleteStreamError {
     ResourceNotFound(String),
     /// <p>The stream version that you specified is not the latest version. To get the latest version, use the <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_DescribeStream.html">DescribeStream</a> API.</p>
     VersionMismatch(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStreamError {
@@ -852,22 +888,25 @@ impl DeleteStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(DeleteStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteStreamError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(DeleteStreamError::InvalidArgument(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(DeleteStreamError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteStreamError::ResourceNotFound(err.msg), &res)
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(DeleteStreamError::VersionMismatch(err.msg))
+                    return RusotoError::service(DeleteStreamError::VersionMismatch(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -882,12 +921,14 @@ impl fmt::Display for DeleteStreamError {
             DeleteStreamError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DeleteStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteStreamError::VersionMismatch(ref cause) => write!(f, "{}", cause),
+            DeleteStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStreamError {}
 /// Errors returned by DescribeSignalingChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSignalingChannelError {
     /// <p>You do not have required permissions to perform this operation.</p>
     AccessDenied(String),
@@ -897,6 +938,8 @@ pub enum DescribeSignalingChannelError {
     InvalidArgument(String),
     /// <p>Amazon Kinesis Video Streams can't find the stream that you specified.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSignalingChannelError {
@@ -904,27 +947,31 @@ impl DescribeSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeSignalingChannelError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSignalingChannelError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSignalingChannelError::ClientLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeSignalingChannelError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSignalingChannelError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSignalingChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSignalingChannelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSignalingChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -938,12 +985,14 @@ impl fmt::Display for DescribeSignalingChannelError {
             DescribeSignalingChannelError::ClientLimitExceeded(ref cause) => write!(f, "{}", cause),
             DescribeSignalingChannelError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             DescribeSignalingChannelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSignalingChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSignalingChannelError {}
 /// Errors returned by DescribeStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStreamError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -953,6 +1002,8 @@ pub enum DescribeStreamError {
     NotAuthorized(String),
     /// <p>Amazon Kinesis Video Streams can't find the stream that you specified.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStreamError {
@@ -960,19 +1011,28 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DescribeStreamError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        DescribeStreamError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(DescribeStreamError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -986,12 +1046,14 @@ impl fmt::Display for DescribeStreamError {
             DescribeStreamError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             DescribeStreamError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStreamError {}
 /// Errors returned by GetDataEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDataEndpointError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -1001,6 +1063,8 @@ pub enum GetDataEndpointError {
     NotAuthorized(String),
     /// <p>Amazon Kinesis Video Streams can't find the stream that you specified.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDataEndpointError {
@@ -1008,19 +1072,28 @@ impl GetDataEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetDataEndpointError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetDataEndpointError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetDataEndpointError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        GetDataEndpointError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetDataEndpointError::NotAuthorized(err.msg))
+                    return RusotoError::service(GetDataEndpointError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetDataEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDataEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1034,12 +1107,14 @@ impl fmt::Display for GetDataEndpointError {
             GetDataEndpointError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             GetDataEndpointError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             GetDataEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetDataEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDataEndpointError {}
 /// Errors returned by GetSignalingChannelEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSignalingChannelEndpointError {
     /// <p>You do not have required permissions to perform this operation.</p>
     AccessDenied(String),
@@ -1051,6 +1126,8 @@ pub enum GetSignalingChannelEndpointError {
     ResourceInUse(String),
     /// <p>Amazon Kinesis Video Streams can't find the stream that you specified.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSignalingChannelEndpointError {
@@ -1060,32 +1137,42 @@ impl GetSignalingChannelEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSignalingChannelEndpointError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSignalingChannelEndpointError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetSignalingChannelEndpointError::ClientLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetSignalingChannelEndpointError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSignalingChannelEndpointError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(GetSignalingChannelEndpointError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSignalingChannelEndpointError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetSignalingChannelEndpointError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetSignalingChannelEndpointError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1102,12 +1189,14 @@ impl fmt::Display for GetSignalingChannelEndpointError {
             GetSignalingChannelEndpointError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             GetSignalingChannelEndpointError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             GetSignalingChannelEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetSignalingChannelEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSignalingChannelEndpointError {}
 /// Errors returned by ListSignalingChannels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSignalingChannelsError {
     /// <p>You do not have required permissions to perform this operation.</p>
     AccessDenied(String),
@@ -1115,6 +1204,8 @@ pub enum ListSignalingChannelsError {
     ClientLimitExceeded(String),
     /// <p>The value for this input parameter is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSignalingChannelsError {
@@ -1122,20 +1213,25 @@ impl ListSignalingChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSignalingChannelsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListSignalingChannelsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListSignalingChannelsError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSignalingChannelsError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListSignalingChannelsError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSignalingChannelsError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSignalingChannelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1148,17 +1244,21 @@ impl fmt::Display for ListSignalingChannelsError {
             ListSignalingChannelsError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListSignalingChannelsError::ClientLimitExceeded(ref cause) => write!(f, "{}", cause),
             ListSignalingChannelsError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            ListSignalingChannelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSignalingChannelsError {}
 /// Errors returned by ListStreams
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStreamsError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
     /// <p>The value for this input parameter is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStreamsError {
@@ -1166,13 +1266,16 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListStreamsError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListStreamsError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListStreamsError::InvalidArgument(err.msg))
+                    return RusotoError::service(ListStreamsError::InvalidArgument(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListStreamsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1184,12 +1287,14 @@ impl fmt::Display for ListStreamsError {
         match *self {
             ListStreamsError::ClientLimitExceeded(ref cause) => write!(f, "{}", cause),
             ListStreamsError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            ListStreamsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStreamsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>You do not have required permissions to perform this operation.</p>
     AccessDenied(String),
@@ -1199,6 +1304,8 @@ pub enum ListTagsForResourceError {
     InvalidArgument(String),
     /// <p>Amazon Kinesis Video Streams can't find the stream that you specified.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1206,23 +1313,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1236,12 +1351,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::ClientLimitExceeded(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTagsForStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForStreamError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -1253,6 +1370,8 @@ pub enum ListTagsForStreamError {
     NotAuthorized(String),
     /// <p>Amazon Kinesis Video Streams can't find the stream that you specified.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForStreamError {
@@ -1260,26 +1379,37 @@ impl ListTagsForStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListTagsForStreamError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForStreamError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        ListTagsForStreamError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceFormatException" => {
-                    return RusotoError::Service(ListTagsForStreamError::InvalidResourceFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForStreamError::InvalidResourceFormat(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListTagsForStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListTagsForStreamError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTagsForStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1294,12 +1424,14 @@ impl fmt::Display for ListTagsForStreamError {
             ListTagsForStreamError::InvalidResourceFormat(ref cause) => write!(f, "{}", cause),
             ListTagsForStreamError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListTagsForStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForStreamError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>You do not have required permissions to perform this operation.</p>
     AccessDenied(String),
@@ -1311,6 +1443,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>You have exceeded the limit of tags that you can associate with the resource. Kinesis video streams support up to 50 tags. </p>
     TagsPerResourceExceededLimit(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1318,24 +1452,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(TagResourceError::AccessDenied(err.msg), &res)
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidArgument(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(TagResourceError::TagsPerResourceExceededLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::TagsPerResourceExceededLimit(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1350,12 +1488,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TagsPerResourceExceededLimit(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by TagStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagStreamError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -1369,6 +1509,8 @@ pub enum TagStreamError {
     ResourceNotFound(String),
     /// <p>You have exceeded the limit of tags that you can associate with the resource. Kinesis video streams support up to 50 tags. </p>
     TagsPerResourceExceededLimit(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagStreamError {
@@ -1376,27 +1518,31 @@ impl TagStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(TagStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(TagStreamError::ClientLimitExceeded(err.msg), &res)
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(TagStreamError::InvalidArgument(err.msg), &res)
                 }
                 "InvalidResourceFormatException" => {
-                    return RusotoError::Service(TagStreamError::InvalidResourceFormat(err.msg))
+                    return RusotoError::service(
+                        TagStreamError::InvalidResourceFormat(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(TagStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(TagStreamError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagStreamError::ResourceNotFound(err.msg), &res)
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(TagStreamError::TagsPerResourceExceededLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagStreamError::TagsPerResourceExceededLimit(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1412,12 +1558,14 @@ impl fmt::Display for TagStreamError {
             TagStreamError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             TagStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagStreamError::TagsPerResourceExceededLimit(ref cause) => write!(f, "{}", cause),
+            TagStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagStreamError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>You do not have required permissions to perform this operation.</p>
     AccessDenied(String),
@@ -1427,6 +1575,8 @@ pub enum UntagResourceError {
     InvalidArgument(String),
     /// <p>Amazon Kinesis Video Streams can't find the stream that you specified.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1434,19 +1584,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UntagResourceError::AccessDenied(err.msg), &res)
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidArgument(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1460,12 +1616,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::ClientLimitExceeded(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UntagStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagStreamError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -1477,6 +1635,8 @@ pub enum UntagStreamError {
     NotAuthorized(String),
     /// <p>Amazon Kinesis Video Streams can't find the stream that you specified.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagStreamError {
@@ -1484,22 +1644,28 @@ impl UntagStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UntagStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UntagStreamError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(UntagStreamError::InvalidArgument(err.msg), &res)
                 }
                 "InvalidResourceFormatException" => {
-                    return RusotoError::Service(UntagStreamError::InvalidResourceFormat(err.msg))
+                    return RusotoError::service(
+                        UntagStreamError::InvalidResourceFormat(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UntagStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(UntagStreamError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UntagStreamError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1514,12 +1680,14 @@ impl fmt::Display for UntagStreamError {
             UntagStreamError::InvalidResourceFormat(ref cause) => write!(f, "{}", cause),
             UntagStreamError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             UntagStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagStreamError {}
 /// Errors returned by UpdateDataRetention
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDataRetentionError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -1533,6 +1701,8 @@ pub enum UpdateDataRetentionError {
     ResourceNotFound(String),
     /// <p>The stream version that you specified is not the latest version. To get the latest version, use the <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_DescribeStream.html">DescribeStream</a> API.</p>
     VersionMismatch(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDataRetentionError {
@@ -1540,29 +1710,43 @@ impl UpdateDataRetentionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataRetentionError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        UpdateDataRetentionError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateDataRetentionError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateDataRetentionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataRetentionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::VersionMismatch(err.msg))
+                    return RusotoError::service(
+                        UpdateDataRetentionError::VersionMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDataRetentionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1578,12 +1762,14 @@ impl fmt::Display for UpdateDataRetentionError {
             UpdateDataRetentionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateDataRetentionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDataRetentionError::VersionMismatch(ref cause) => write!(f, "{}", cause),
+            UpdateDataRetentionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDataRetentionError {}
 /// Errors returned by UpdateSignalingChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSignalingChannelError {
     /// <p>You do not have required permissions to perform this operation.</p>
     AccessDenied(String),
@@ -1597,6 +1783,8 @@ pub enum UpdateSignalingChannelError {
     ResourceNotFound(String),
     /// <p>The stream version that you specified is not the latest version. To get the latest version, use the <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_DescribeStream.html">DescribeStream</a> API.</p>
     VersionMismatch(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSignalingChannelError {
@@ -1604,35 +1792,43 @@ impl UpdateSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateSignalingChannelError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSignalingChannelError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSignalingChannelError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSignalingChannelError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSignalingChannelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::VersionMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSignalingChannelError::VersionMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSignalingChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1648,12 +1844,14 @@ impl fmt::Display for UpdateSignalingChannelError {
             UpdateSignalingChannelError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateSignalingChannelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateSignalingChannelError::VersionMismatch(ref cause) => write!(f, "{}", cause),
+            UpdateSignalingChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateSignalingChannelError {}
 /// Errors returned by UpdateStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStreamError {
     /// <p>Kinesis Video Streams has throttled the request because you have exceeded the limit of allowed client calls. Try making the call later.</p>
     ClientLimitExceeded(String),
@@ -1667,6 +1865,8 @@ pub enum UpdateStreamError {
     ResourceNotFound(String),
     /// <p>The stream version that you specified is not the latest version. To get the latest version, use the <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_DescribeStream.html">DescribeStream</a> API.</p>
     VersionMismatch(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStreamError {
@@ -1674,25 +1874,28 @@ impl UpdateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UpdateStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateStreamError::ClientLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(UpdateStreamError::InvalidArgument(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(UpdateStreamError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdateStreamError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateStreamError::ResourceNotFound(err.msg), &res)
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateStreamError::VersionMismatch(err.msg))
+                    return RusotoError::service(UpdateStreamError::VersionMismatch(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1708,6 +1911,7 @@ impl fmt::Display for UpdateStreamError {
             UpdateStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateStreamError::VersionMismatch(ref cause) => write!(f, "{}", cause),
+            UpdateStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1840,6 +2044,7 @@ impl KinesisVideoClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KinesisVideoClient {
         KinesisVideoClient {
             client: Client::shared(),
@@ -1865,578 +2070,1360 @@ impl KinesisVideoClient {
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoClient {
         KinesisVideoClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KinesisVideoClient> {
+        rusoto_core::ClientBuilder::new(KinesisVideoClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KinesisVideoClient {
+        KinesisVideoClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl KinesisVideo for KinesisVideoClient {
-    /// <p>Creates a signaling channel. </p> <p> <code>CreateSignalingChannel</code> is an asynchronous operation.</p>
-    async fn create_signaling_channel(
+impl KinesisVideoClient {
+    /// Calls `create_signaling_channel`, also returning the metadata of the response.
+    pub async fn create_signaling_channel_with_metadata(
         &self,
         input: CreateSignalingChannelInput,
-    ) -> Result<CreateSignalingChannelOutput, RusotoError<CreateSignalingChannelError>> {
-        let request_uri = "/createSignalingChannel";
-
-        let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (CreateSignalingChannelOutput, ResponseMetadata),
+        RusotoError<CreateSignalingChannelError>,
+    > {
+        let request = self.build_create_signaling_channel_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSignalingChannelOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateSignalingChannelError::from_response(response))
         }
     }
 
-    /// <p>Creates a new Kinesis video stream. </p> <p>When you create a new stream, Kinesis Video Streams assigns it a version number. When you change the stream's metadata, Kinesis Video Streams updates the version. </p> <p> <code>CreateStream</code> is an asynchronous operation.</p> <p>For information about how the service works, see <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/how-it-works.html">How it Works</a>. </p> <p>You must have permissions for the <code>KinesisVideo:CreateStream</code> action.</p>
-    async fn create_stream(
+    /// Presigns the request `create_signaling_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_signaling_channel(
         &self,
-        input: CreateStreamInput,
-    ) -> Result<CreateStreamOutput, RusotoError<CreateStreamError>> {
-        let request_uri = "/createStream";
+        presigner: &rusoto_core::Presigner,
+        input: CreateSignalingChannelInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_signaling_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_signaling_channel_request(
+        &self,
+        input: CreateSignalingChannelInput,
+    ) -> SignedRequest {
+        let request_uri = "/createSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("CreateSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_stream`, also returning the metadata of the response.
+    pub async fn create_stream_with_metadata(
+        &self,
+        input: CreateStreamInput,
+    ) -> Result<(CreateStreamOutput, ResponseMetadata), RusotoError<CreateStreamError>> {
+        let request = self.build_create_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateStreamOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateStreamError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified signaling channel. <code>DeleteSignalingChannel</code> is an asynchronous operation. If you don't specify the channel's current version, the most recent version is deleted.</p>
-    async fn delete_signaling_channel(
+    /// Presigns the request `create_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stream(
         &self,
-        input: DeleteSignalingChannelInput,
-    ) -> Result<DeleteSignalingChannelOutput, RusotoError<DeleteSignalingChannelError>> {
-        let request_uri = "/deleteSignalingChannel";
+        presigner: &rusoto_core::Presigner,
+        input: CreateStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stream_request(&self, input: CreateStreamInput) -> SignedRequest {
+        let request_uri = "/createStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("CreateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_signaling_channel`, also returning the metadata of the response.
+    pub async fn delete_signaling_channel_with_metadata(
+        &self,
+        input: DeleteSignalingChannelInput,
+    ) -> Result<
+        (DeleteSignalingChannelOutput, ResponseMetadata),
+        RusotoError<DeleteSignalingChannelError>,
+    > {
+        let request = self.build_delete_signaling_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteSignalingChannelOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteSignalingChannelError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Kinesis video stream and the data contained in the stream. </p> <p>This method marks the stream for deletion, and makes the data in the stream inaccessible immediately.</p> <p> </p> <p> To ensure that you have the latest version of the stream before deleting it, you can specify the stream version. Kinesis Video Streams assigns a version to each stream. When you update a stream, Kinesis Video Streams assigns a new version number. To get the latest stream version, use the <code>DescribeStream</code> API. </p> <p>This operation requires permission for the <code>KinesisVideo:DeleteStream</code> action.</p>
-    async fn delete_stream(
+    /// Presigns the request `delete_signaling_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_signaling_channel(
         &self,
-        input: DeleteStreamInput,
-    ) -> Result<DeleteStreamOutput, RusotoError<DeleteStreamError>> {
-        let request_uri = "/deleteStream";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteSignalingChannelInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_signaling_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_signaling_channel_request(
+        &self,
+        input: DeleteSignalingChannelInput,
+    ) -> SignedRequest {
+        let request_uri = "/deleteSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DeleteSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_stream`, also returning the metadata of the response.
+    pub async fn delete_stream_with_metadata(
+        &self,
+        input: DeleteStreamInput,
+    ) -> Result<(DeleteStreamOutput, ResponseMetadata), RusotoError<DeleteStreamError>> {
+        let request = self.build_delete_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteStreamOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteStreamError::from_response(response))
         }
     }
 
-    /// <p>Returns the most current information about the signaling channel. You must specify either the name or the ARN of the channel that you want to describe.</p>
-    async fn describe_signaling_channel(
+    /// Presigns the request `delete_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stream(
         &self,
-        input: DescribeSignalingChannelInput,
-    ) -> Result<DescribeSignalingChannelOutput, RusotoError<DescribeSignalingChannelError>> {
-        let request_uri = "/describeSignalingChannel";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stream_request(&self, input: DeleteStreamInput) -> SignedRequest {
+        let request_uri = "/deleteStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DeleteStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_signaling_channel`, also returning the metadata of the response.
+    pub async fn describe_signaling_channel_with_metadata(
+        &self,
+        input: DescribeSignalingChannelInput,
+    ) -> Result<
+        (DescribeSignalingChannelOutput, ResponseMetadata),
+        RusotoError<DescribeSignalingChannelError>,
+    > {
+        let request = self.build_describe_signaling_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSignalingChannelOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeSignalingChannelError::from_response(response))
         }
     }
 
-    /// <p>Returns the most current information about the specified stream. You must specify either the <code>StreamName</code> or the <code>StreamARN</code>. </p>
-    async fn describe_stream(
+    /// Presigns the request `describe_signaling_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_signaling_channel(
         &self,
-        input: DescribeStreamInput,
-    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
-        let request_uri = "/describeStream";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSignalingChannelInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_signaling_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_signaling_channel_request(
+        &self,
+        input: DescribeSignalingChannelInput,
+    ) -> SignedRequest {
+        let request_uri = "/describeSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DescribeSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_stream`, also returning the metadata of the response.
+    pub async fn describe_stream_with_metadata(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<(DescribeStreamOutput, ResponseMetadata), RusotoError<DescribeStreamError>> {
+        let request = self.build_describe_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeStreamOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeStreamError::from_response(response))
         }
     }
 
-    /// <p>Gets an endpoint for a specified stream for either reading or writing. Use this endpoint in your application to read from the specified stream (using the <code>GetMedia</code> or <code>GetMediaForFragmentList</code> operations) or write to it (using the <code>PutMedia</code> operation). </p> <note> <p>The returned endpoint does not have the API name appended. The client needs to add the API name to the returned endpoint.</p> </note> <p>In the request, specify the stream either by <code>StreamName</code> or <code>StreamARN</code>.</p>
-    async fn get_data_endpoint(
+    /// Presigns the request `describe_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stream(
         &self,
-        input: GetDataEndpointInput,
-    ) -> Result<GetDataEndpointOutput, RusotoError<GetDataEndpointError>> {
-        let request_uri = "/getDataEndpoint";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stream_request(&self, input: DescribeStreamInput) -> SignedRequest {
+        let request_uri = "/describeStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DescribeStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_data_endpoint`, also returning the metadata of the response.
+    pub async fn get_data_endpoint_with_metadata(
+        &self,
+        input: GetDataEndpointInput,
+    ) -> Result<(GetDataEndpointOutput, ResponseMetadata), RusotoError<GetDataEndpointError>> {
+        let request = self.build_get_data_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDataEndpointOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetDataEndpointError::from_response(response))
         }
     }
 
-    /// <p>Provides an endpoint for the specified signaling channel to send and receive messages. This API uses the <code>SingleMasterChannelEndpointConfiguration</code> input parameter, which consists of the <code>Protocols</code> and <code>Role</code> properties.</p> <p> <code>Protocols</code> is used to determine the communication mechanism. For example, specifying <code>WSS</code> as the protocol, results in this API producing a secure websocket endpoint, and specifying <code>HTTPS</code> as the protocol, results in this API generating an HTTPS endpoint. </p> <p> <code>Role</code> determines the messaging permissions. A <code>MASTER</code> role results in this API generating an endpoint that a client can use to communicate with any of the viewers on the channel. A <code>VIEWER</code> role results in this API generating an endpoint that a client can use to communicate only with a <code>MASTER</code>. </p>
-    async fn get_signaling_channel_endpoint(
+    /// Presigns the request `get_data_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_data_endpoint(
         &self,
-        input: GetSignalingChannelEndpointInput,
-    ) -> Result<GetSignalingChannelEndpointOutput, RusotoError<GetSignalingChannelEndpointError>>
-    {
-        let request_uri = "/getSignalingChannelEndpoint";
+        presigner: &rusoto_core::Presigner,
+        input: GetDataEndpointInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_data_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_data_endpoint_request(&self, input: GetDataEndpointInput) -> SignedRequest {
+        let request_uri = "/getDataEndpoint";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetDataEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_signaling_channel_endpoint`, also returning the metadata of the response.
+    pub async fn get_signaling_channel_endpoint_with_metadata(
+        &self,
+        input: GetSignalingChannelEndpointInput,
+    ) -> Result<
+        (GetSignalingChannelEndpointOutput, ResponseMetadata),
+        RusotoError<GetSignalingChannelEndpointError>,
+    > {
+        let request = self.build_get_signaling_channel_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSignalingChannelEndpointOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetSignalingChannelEndpointError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <code>ChannelInfo</code> objects. Each object describes a signaling channel. To retrieve only those channels that satisfy a specific condition, you can specify a <code>ChannelNameCondition</code>.</p>
-    async fn list_signaling_channels(
+    /// Presigns the request `get_signaling_channel_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_signaling_channel_endpoint(
         &self,
-        input: ListSignalingChannelsInput,
-    ) -> Result<ListSignalingChannelsOutput, RusotoError<ListSignalingChannelsError>> {
-        let request_uri = "/listSignalingChannels";
+        presigner: &rusoto_core::Presigner,
+        input: GetSignalingChannelEndpointInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_signaling_channel_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_signaling_channel_endpoint_request(
+        &self,
+        input: GetSignalingChannelEndpointInput,
+    ) -> SignedRequest {
+        let request_uri = "/getSignalingChannelEndpoint";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetSignalingChannelEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_signaling_channels`, also returning the metadata of the response.
+    pub async fn list_signaling_channels_with_metadata(
+        &self,
+        input: ListSignalingChannelsInput,
+    ) -> Result<
+        (ListSignalingChannelsOutput, ResponseMetadata),
+        RusotoError<ListSignalingChannelsError>,
+    > {
+        let request = self.build_list_signaling_channels_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSignalingChannelsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListSignalingChannelsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <code>StreamInfo</code> objects. Each object describes a stream. To retrieve only streams that satisfy a specific condition, you can specify a <code>StreamNameCondition</code>. </p>
-    async fn list_streams(
+    /// Presigns the request `list_signaling_channels` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_signaling_channels(
         &self,
-        input: ListStreamsInput,
-    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
-        let request_uri = "/listStreams";
+        presigner: &rusoto_core::Presigner,
+        input: ListSignalingChannelsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_signaling_channels_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_signaling_channels_request(
+        &self,
+        input: ListSignalingChannelsInput,
+    ) -> SignedRequest {
+        let request_uri = "/listSignalingChannels";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListSignalingChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_streams`, also returning the metadata of the response.
+    pub async fn list_streams_with_metadata(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<(ListStreamsOutput, ResponseMetadata), RusotoError<ListStreamsError>> {
+        let request = self.build_list_streams_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListStreamsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListStreamsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of tags associated with the specified signaling channel.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_streams` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_streams(
         &self,
-        input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
-        let request_uri = "/ListTagsForResource";
+        presigner: &rusoto_core::Presigner,
+        input: ListStreamsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_streams_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_streams_request(&self, input: ListStreamsInput) -> SignedRequest {
+        let request_uri = "/listStreams";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListStreams");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of tags associated with the specified stream.</p> <p>In the request, you must specify either the <code>StreamName</code> or the <code>StreamARN</code>. </p>
-    async fn list_tags_for_stream(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
-        input: ListTagsForStreamInput,
-    ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
-        let request_uri = "/listTagsForStream";
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
+        let request_uri = "/ListTagsForResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_tags_for_stream`, also returning the metadata of the response.
+    pub async fn list_tags_for_stream_with_metadata(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> Result<(ListTagsForStreamOutput, ResponseMetadata), RusotoError<ListTagsForStreamError>>
+    {
+        let request = self.build_list_tags_for_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForStreamOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListTagsForStreamError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to a signaling channel. A <i>tag</i> is a key-value pair (the value is optional) that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
-    async fn tag_resource(
+    /// Presigns the request `list_tags_for_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_stream(
         &self,
-        input: TagResourceInput,
-    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
-        let request_uri = "/TagResource";
-
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_stream_request(&self, input: ListTagsForStreamInput) -> SignedRequest {
+        let request_uri = "/listTagsForStream";
+
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListTagsForStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(TagResourceOutput, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to a stream. A <i>tag</i> is a key-value pair (the value is optional) that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p> <p>You must provide either the <code>StreamName</code> or the <code>StreamARN</code>.</p> <p>This operation requires permission for the <code>KinesisVideo:TagStream</code> action.</p> <p>Kinesis video streams support up to 50 tags.</p>
-    async fn tag_stream(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
-        input: TagStreamInput,
-    ) -> Result<TagStreamOutput, RusotoError<TagStreamError>> {
-        let request_uri = "/tagStream";
+        presigner: &rusoto_core::Presigner,
+        input: TagResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
+        let request_uri = "/TagResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_stream`, also returning the metadata of the response.
+    pub async fn tag_stream_with_metadata(
+        &self,
+        input: TagStreamInput,
+    ) -> Result<(TagStreamOutput, ResponseMetadata), RusotoError<TagStreamError>> {
+        let request = self.build_tag_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<TagStreamOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(TagStreamError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from a signaling channel. In the request, specify only a tag key or keys; don't specify the value. If you specify a tag key that does not exist, it's ignored.</p>
-    async fn untag_resource(
+    /// Presigns the request `tag_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_stream(
         &self,
-        input: UntagResourceInput,
-    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
-        let request_uri = "/UntagResource";
+        presigner: &rusoto_core::Presigner,
+        input: TagStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_stream_request(&self, input: TagStreamInput) -> SignedRequest {
+        let request_uri = "/tagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("TagStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(UntagResourceOutput, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from a stream. In the request, specify only a tag key or keys; don't specify the value. If you specify a tag key that does not exist, it's ignored.</p> <p>In the request, you must provide the <code>StreamName</code> or <code>StreamARN</code>.</p>
-    async fn untag_stream(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
-        input: UntagStreamInput,
-    ) -> Result<UntagStreamOutput, RusotoError<UntagStreamError>> {
-        let request_uri = "/untagStream";
+        presigner: &rusoto_core::Presigner,
+        input: UntagResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
+        let request_uri = "/UntagResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_stream`, also returning the metadata of the response.
+    pub async fn untag_stream_with_metadata(
+        &self,
+        input: UntagStreamInput,
+    ) -> Result<(UntagStreamOutput, ResponseMetadata), RusotoError<UntagStreamError>> {
+        let request = self.build_untag_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagStreamOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UntagStreamError::from_response(response))
         }
     }
 
-    /// <p><p> Increases or decreases the stream&#39;s data retention period by the value that you specify. To indicate whether you want to increase or decrease the data retention period, specify the <code>Operation</code> parameter in the request body. In the request, you must specify either the <code>StreamName</code> or the <code>StreamARN</code>. </p> <note> <p>The retention period that you specify replaces the current value.</p> </note> <p>This operation requires permission for the <code>KinesisVideo:UpdateDataRetention</code> action.</p> <p>Changing the data retention period affects the data in the stream as follows:</p> <ul> <li> <p>If the data retention period is increased, existing data is retained for the new retention period. For example, if the data retention period is increased from one hour to seven hours, all existing data is retained for seven hours.</p> </li> <li> <p>If the data retention period is decreased, existing data is retained for the new retention period. For example, if the data retention period is decreased from seven hours to one hour, all existing data is retained for one hour, and any data older than one hour is deleted immediately.</p> </li> </ul></p>
-    async fn update_data_retention(
+    /// Presigns the request `untag_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_stream(
         &self,
-        input: UpdateDataRetentionInput,
-    ) -> Result<UpdateDataRetentionOutput, RusotoError<UpdateDataRetentionError>> {
-        let request_uri = "/updateDataRetention";
+        presigner: &rusoto_core::Presigner,
+        input: UntagStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_stream_request(&self, input: UntagStreamInput) -> SignedRequest {
+        let request_uri = "/untagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UntagStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_data_retention`, also returning the metadata of the response.
+    pub async fn update_data_retention_with_metadata(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> Result<(UpdateDataRetentionOutput, ResponseMetadata), RusotoError<UpdateDataRetentionError>>
+    {
+        let request = self.build_update_data_retention_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateDataRetentionOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateDataRetentionError::from_response(response))
         }
     }
 
-    /// <p>Updates the existing signaling channel. This is an asynchronous operation and takes time to complete. </p> <p>If the <code>MessageTtlSeconds</code> value is updated (either increased or reduced), then it only applies to new messages sent via this channel after it's been updated. Existing messages are still expire as per the previous <code>MessageTtlSeconds</code> value.</p>
-    async fn update_signaling_channel(
+    /// Presigns the request `update_data_retention` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_data_retention(
         &self,
-        input: UpdateSignalingChannelInput,
-    ) -> Result<UpdateSignalingChannelOutput, RusotoError<UpdateSignalingChannelError>> {
-        let request_uri = "/updateSignalingChannel";
+        presigner: &rusoto_core::Presigner,
+        input: UpdateDataRetentionInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_data_retention_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_data_retention_request(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> SignedRequest {
+        let request_uri = "/updateDataRetention";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateDataRetention");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_signaling_channel`, also returning the metadata of the response.
+    pub async fn update_signaling_channel_with_metadata(
+        &self,
+        input: UpdateSignalingChannelInput,
+    ) -> Result<
+        (UpdateSignalingChannelOutput, ResponseMetadata),
+        RusotoError<UpdateSignalingChannelError>,
+    > {
+        let request = self.build_update_signaling_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateSignalingChannelOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateSignalingChannelError::from_response(response))
         }
     }
 
-    /// <p>Updates stream metadata, such as the device name and media type.</p> <p>You must provide the stream name or the Amazon Resource Name (ARN) of the stream.</p> <p>To make sure that you have the latest version of the stream before updating it, you can specify the stream version. Kinesis Video Streams assigns a version to each stream. When you update a stream, Kinesis Video Streams assigns a new version number. To get the latest stream version, use the <code>DescribeStream</code> API. </p> <p> <code>UpdateStream</code> is an asynchronous operation, and takes time to complete.</p>
-    async fn update_stream(
+    /// Presigns the request `update_signaling_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_signaling_channel(
         &self,
-        input: UpdateStreamInput,
-    ) -> Result<UpdateStreamOutput, RusotoError<UpdateStreamError>> {
-        let request_uri = "/updateStream";
+        presigner: &rusoto_core::Presigner,
+        input: UpdateSignalingChannelInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_signaling_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_signaling_channel_request(
+        &self,
+        input: UpdateSignalingChannelInput,
+    ) -> SignedRequest {
+        let request_uri = "/updateSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_stream`, also returning the metadata of the response.
+    pub async fn update_stream_with_metadata(
+        &self,
+        input: UpdateStreamInput,
+    ) -> Result<(UpdateStreamOutput, ResponseMetadata), RusotoError<UpdateStreamError>> {
+        let request = self.build_update_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateStreamOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateStreamError::from_response(response))
         }
     }
+
+    /// Presigns the request `update_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_stream(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_stream_request(&self, input: UpdateStreamInput) -> SignedRequest {
+        let request_uri = "/updateStream";
+
+        let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateStream");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// The pages of `ListSignalingChannels` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_signaling_channels_pages(
+        &self,
+        input: ListSignalingChannelsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListSignalingChannelsOutput, RusotoError<ListSignalingChannelsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisVideoClient, input| async move {
+                client.list_signaling_channels(input).await
+            },
+            |input: &ListSignalingChannelsInput, output: &ListSignalingChannelsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSignalingChannelsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ChannelInfoList` of every page of `ListSignalingChannels` results for `input`, as `list_signaling_channels_pages` requests them.
+    pub fn list_signaling_channels_stream(
+        &self,
+        input: ListSignalingChannelsInput,
+    ) -> impl futures::Stream<Item = Result<ChannelInfo, RusotoError<ListSignalingChannelsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_signaling_channels_pages(input), |page| {
+            page.channel_info_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListStreams` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_streams_pages(
+        &self,
+        input: ListStreamsInput,
+    ) -> impl futures::Stream<Item = Result<ListStreamsOutput, RusotoError<ListStreamsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KinesisVideoClient, input| async move { client.list_streams(input).await },
+            |input: &ListStreamsInput, output: &ListStreamsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStreamsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `StreamInfoList` of every page of `ListStreams` results for `input`, as `list_streams_pages` requests them.
+    pub fn list_streams_stream(
+        &self,
+        input: ListStreamsInput,
+    ) -> impl futures::Stream<Item = Result<StreamInfo, RusotoError<ListStreamsError>>> + Send {
+        rusoto_core::pagination::items(self.list_streams_pages(input), |page| {
+            page.stream_info_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl KinesisVideo for KinesisVideoClient {
+    /// <p>Creates a signaling channel. </p> <p> <code>CreateSignalingChannel</code> is an asynchronous operation.</p>
+    async fn create_signaling_channel(
+        &self,
+        input: CreateSignalingChannelInput,
+    ) -> Result<CreateSignalingChannelOutput, RusotoError<CreateSignalingChannelError>> {
+        self.create_signaling_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Kinesis video stream. </p> <p>When you create a new stream, Kinesis Video Streams assigns it a version number. When you change the stream's metadata, Kinesis Video Streams updates the version. </p> <p> <code>CreateStream</code> is an asynchronous operation.</p> <p>For information about how the service works, see <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/how-it-works.html">How it Works</a>. </p> <p>You must have permissions for the <code>KinesisVideo:CreateStream</code> action.</p>
+    async fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> Result<CreateStreamOutput, RusotoError<CreateStreamError>> {
+        self.create_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified signaling channel. <code>DeleteSignalingChannel</code> is an asynchronous operation. If you don't specify the channel's current version, the most recent version is deleted.</p>
+    async fn delete_signaling_channel(
+        &self,
+        input: DeleteSignalingChannelInput,
+    ) -> Result<DeleteSignalingChannelOutput, RusotoError<DeleteSignalingChannelError>> {
+        self.delete_signaling_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Kinesis video stream and the data contained in the stream. </p> <p>This method marks the stream for deletion, and makes the data in the stream inaccessible immediately.</p> <p> </p> <p> To ensure that you have the latest version of the stream before deleting it, you can specify the stream version. Kinesis Video Streams assigns a version to each stream. When you update a stream, Kinesis Video Streams assigns a new version number. To get the latest stream version, use the <code>DescribeStream</code> API. </p> <p>This operation requires permission for the <code>KinesisVideo:DeleteStream</code> action.</p>
+    async fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> Result<DeleteStreamOutput, RusotoError<DeleteStreamError>> {
+        self.delete_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the most current information about the signaling channel. You must specify either the name or the ARN of the channel that you want to describe.</p>
+    async fn describe_signaling_channel(
+        &self,
+        input: DescribeSignalingChannelInput,
+    ) -> Result<DescribeSignalingChannelOutput, RusotoError<DescribeSignalingChannelError>> {
+        self.describe_signaling_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the most current information about the specified stream. You must specify either the <code>StreamName</code> or the <code>StreamARN</code>. </p>
+    async fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+        self.describe_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an endpoint for a specified stream for either reading or writing. Use this endpoint in your application to read from the specified stream (using the <code>GetMedia</code> or <code>GetMediaForFragmentList</code> operations) or write to it (using the <code>PutMedia</code> operation). </p> <note> <p>The returned endpoint does not have the API name appended. The client needs to add the API name to the returned endpoint.</p> </note> <p>In the request, specify the stream either by <code>StreamName</code> or <code>StreamARN</code>.</p>
+    async fn get_data_endpoint(
+        &self,
+        input: GetDataEndpointInput,
+    ) -> Result<GetDataEndpointOutput, RusotoError<GetDataEndpointError>> {
+        self.get_data_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides an endpoint for the specified signaling channel to send and receive messages. This API uses the <code>SingleMasterChannelEndpointConfiguration</code> input parameter, which consists of the <code>Protocols</code> and <code>Role</code> properties.</p> <p> <code>Protocols</code> is used to determine the communication mechanism. For example, specifying <code>WSS</code> as the protocol, results in this API producing a secure websocket endpoint, and specifying <code>HTTPS</code> as the protocol, results in this API generating an HTTPS endpoint. </p> <p> <code>Role</code> determines the messaging permissions. A <code>MASTER</code> role results in this API generating an endpoint that a client can use to communicate with any of the viewers on the channel. A <code>VIEWER</code> role results in this API generating an endpoint that a client can use to communicate only with a <code>MASTER</code>. </p>
+    async fn get_signaling_channel_endpoint(
+        &self,
+        input: GetSignalingChannelEndpointInput,
+    ) -> Result<GetSignalingChannelEndpointOutput, RusotoError<GetSignalingChannelEndpointError>>
+    {
+        self.get_signaling_channel_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <code>ChannelInfo</code> objects. Each object describes a signaling channel. To retrieve only those channels that satisfy a specific condition, you can specify a <code>ChannelNameCondition</code>.</p>
+    async fn list_signaling_channels(
+        &self,
+        input: ListSignalingChannelsInput,
+    ) -> Result<ListSignalingChannelsOutput, RusotoError<ListSignalingChannelsError>> {
+        self.list_signaling_channels_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <code>StreamInfo</code> objects. Each object describes a stream. To retrieve only streams that satisfy a specific condition, you can specify a <code>StreamNameCondition</code>. </p>
+    async fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+        self.list_streams_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of tags associated with the specified signaling channel.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of tags associated with the specified stream.</p> <p>In the request, you must specify either the <code>StreamName</code> or the <code>StreamARN</code>. </p>
+    async fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
+        self.list_tags_for_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to a signaling channel. A <i>tag</i> is a key-value pair (the value is optional) that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to a stream. A <i>tag</i> is a key-value pair (the value is optional) that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p> <p>You must provide either the <code>StreamName</code> or the <code>StreamARN</code>.</p> <p>This operation requires permission for the <code>KinesisVideo:TagStream</code> action.</p> <p>Kinesis video streams support up to 50 tags.</p>
+    async fn tag_stream(
+        &self,
+        input: TagStreamInput,
+    ) -> Result<TagStreamOutput, RusotoError<TagStreamError>> {
+        self.tag_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from a signaling channel. In the request, specify only a tag key or keys; don't specify the value. If you specify a tag key that does not exist, it's ignored.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from a stream. In the request, specify only a tag key or keys; don't specify the value. If you specify a tag key that does not exist, it's ignored.</p> <p>In the request, you must provide the <code>StreamName</code> or <code>StreamARN</code>.</p>
+    async fn untag_stream(
+        &self,
+        input: UntagStreamInput,
+    ) -> Result<UntagStreamOutput, RusotoError<UntagStreamError>> {
+        self.untag_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Increases or decreases the stream&#39;s data retention period by the value that you specify. To indicate whether you want to increase or decrease the data retention period, specify the <code>Operation</code> parameter in the request body. In the request, you must specify either the <code>StreamName</code> or the <code>StreamARN</code>. </p> <note> <p>The retention period that you specify replaces the current value.</p> </note> <p>This operation requires permission for the <code>KinesisVideo:UpdateDataRetention</code> action.</p> <p>Changing the data retention period affects the data in the stream as follows:</p> <ul> <li> <p>If the data retention period is increased, existing data is retained for the new retention period. For example, if the data retention period is increased from one hour to seven hours, all existing data is retained for seven hours.</p> </li> <li> <p>If the data retention period is decreased, existing data is retained for the new retention period. For example, if the data retention period is decreased from seven hours to one hour, all existing data is retained for one hour, and any data older than one hour is deleted immediately.</p> </li> </ul></p>
+    async fn update_data_retention(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> Result<UpdateDataRetentionOutput, RusotoError<UpdateDataRetentionError>> {
+        self.update_data_retention_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the existing signaling channel. This is an asynchronous operation and takes time to complete. </p> <p>If the <code>MessageTtlSeconds</code> value is updated (either increased or reduced), then it only applies to new messages sent via this channel after it's been updated. Existing messages are still expire as per the previous <code>MessageTtlSeconds</code> value.</p>
+    async fn update_signaling_channel(
+        &self,
+        input: UpdateSignalingChannelInput,
+    ) -> Result<UpdateSignalingChannelOutput, RusotoError<UpdateSignalingChannelError>> {
+        self.update_signaling_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates stream metadata, such as the device name and media type.</p> <p>You must provide the stream name or the Amazon Resource Name (ARN) of the stream.</p> <p>To make sure that you have the latest version of the stream before updating it, you can specify the stream version. Kinesis Video Streams assigns a version to each stream. When you update a stream, Kinesis Video Streams assigns a new version number. To get the latest stream version, use the <code>DescribeStream</code> API. </p> <p> <code>UpdateStream</code> is an asynchronous operation, and takes time to complete.</p>
+    async fn update_stream(
+        &self,
+        input: UpdateStreamInput,
+    ) -> Result<UpdateStreamOutput, RusotoError<UpdateStreamError>> {
+        self.update_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
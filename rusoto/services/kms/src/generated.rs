@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1504,6 +1504,7 @@ pub struct VerifyResponse {
 
 /// Errors returned by CancelKeyDeletion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelKeyDeletionError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -1515,6 +1516,8 @@ pub enum CancelKeyDeletionError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelKeyDeletionError {
@@ -1522,22 +1525,28 @@ impl CancelKeyDeletionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        CancelKeyDeletionError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::InvalidArn(err.msg))
+                    return RusotoError::service(CancelKeyDeletionError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::KMSInternal(err.msg))
+                    return RusotoError::service(CancelKeyDeletionError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        CancelKeyDeletionError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::NotFound(err.msg))
+                    return RusotoError::service(CancelKeyDeletionError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelKeyDeletionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1552,12 +1561,14 @@ impl fmt::Display for CancelKeyDeletionError {
             CancelKeyDeletionError::KMSInternal(ref cause) => write!(f, "{}", cause),
             CancelKeyDeletionError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             CancelKeyDeletionError::NotFound(ref cause) => write!(f, "{}", cause),
+            CancelKeyDeletionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelKeyDeletionError {}
 /// Errors returned by ConnectCustomKeyStore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConnectCustomKeyStoreError {
     /// <p>The request was rejected because the associated AWS CloudHSM cluster did not meet the configuration requirements for a custom key store.</p> <ul> <li> <p>The cluster must be configured with private subnets in at least two different Availability Zones in the Region.</p> </li> <li> <p>The <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/configure-sg.html">security group for the cluster</a> (cloudhsm-cluster-<i>&lt;cluster-id&gt;</i>-sg) must include inbound rules and outbound rules that allow TCP traffic on ports 2223-2225. The <b>Source</b> in the inbound rules and the <b>Destination</b> in the outbound rules must match the security group ID. These rules are set by default when you create the cluster. Do not delete or change them. To get information about a particular security group, use the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeSecurityGroups.html">DescribeSecurityGroups</a> operation.</p> </li> <li> <p>The cluster must contain at least as many HSMs as the operation requires. To add HSMs, use the AWS CloudHSM <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_CreateHsm.html">CreateHsm</a> operation.</p> <p>For the <a>CreateCustomKeyStore</a>, <a>UpdateCustomKeyStore</a>, and <a>CreateKey</a> operations, the AWS CloudHSM cluster must have at least two active HSMs, each in a different Availability Zone. For the <a>ConnectCustomKeyStore</a> operation, the AWS CloudHSM must contain at least one active HSM.</p> </li> </ul> <p>For information about the requirements for an AWS CloudHSM cluster that is associated with a custom key store, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">Assemble the Prerequisites</a> in the <i>AWS Key Management Service Developer Guide</i>. For information about creating a private subnet for an AWS CloudHSM cluster, see <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/create-subnets.html">Create a Private Subnet</a> in the <i>AWS CloudHSM User Guide</i>. For information about cluster security groups, see <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/configure-sg.html">Configure a Default Security Group</a> in the <i> <i>AWS CloudHSM User Guide</i> </i>. </p>
     CloudHsmClusterInvalidConfiguration(String),
@@ -1569,6 +1580,8 @@ pub enum ConnectCustomKeyStoreError {
     CustomKeyStoreNotFound(String),
     /// <p>The request was rejected because an internal exception occurred. The request can be retried.</p>
     KMSInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConnectCustomKeyStoreError {
@@ -1576,30 +1589,37 @@ impl ConnectCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConnectCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "CloudHsmClusterNotActiveException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConnectCustomKeyStoreError::CloudHsmClusterNotActive(err.msg),
+                        &res,
                     )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConnectCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
+                        &res,
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConnectCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
+                        &res,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ConnectCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        ConnectCustomKeyStoreError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ConnectCustomKeyStoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1620,12 +1640,14 @@ impl fmt::Display for ConnectCustomKeyStoreError {
             }
             ConnectCustomKeyStoreError::CustomKeyStoreNotFound(ref cause) => write!(f, "{}", cause),
             ConnectCustomKeyStoreError::KMSInternal(ref cause) => write!(f, "{}", cause),
+            ConnectCustomKeyStoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ConnectCustomKeyStoreError {}
 /// Errors returned by CreateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAliasError {
     /// <p>The request was rejected because it attempted to create a resource that already exists.</p>
     AlreadyExists(String),
@@ -1641,6 +1663,8 @@ pub enum CreateAliasError {
     LimitExceeded(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAliasError {
@@ -1648,28 +1672,28 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateAliasError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateAliasError::AlreadyExists(err.msg), &res)
                 }
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CreateAliasError::DependencyTimeout(err.msg))
+                    return RusotoError::service(CreateAliasError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidAliasNameException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidAliasName(err.msg))
+                    return RusotoError::service(CreateAliasError::InvalidAliasName(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateAliasError::KMSInternal(err.msg))
+                    return RusotoError::service(CreateAliasError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(CreateAliasError::KMSInvalidState(err.msg))
+                    return RusotoError::service(CreateAliasError::KMSInvalidState(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateAliasError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::NotFound(err.msg))
+                    return RusotoError::service(CreateAliasError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1686,12 +1710,14 @@ impl fmt::Display for CreateAliasError {
             CreateAliasError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             CreateAliasError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateAliasError::NotFound(ref cause) => write!(f, "{}", cause),
+            CreateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAliasError {}
 /// Errors returned by CreateCustomKeyStore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCustomKeyStoreError {
     /// <p>The request was rejected because the specified AWS CloudHSM cluster is already associated with a custom key store or it shares a backup history with a cluster that is associated with a custom key store. Each custom key store must be associated with a different AWS CloudHSM cluster.</p> <p>Clusters that share a backup history have the same cluster certificate. To view the cluster certificate of a cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation.</p>
     CloudHsmClusterInUse(String),
@@ -1707,6 +1733,8 @@ pub enum CreateCustomKeyStoreError {
     IncorrectTrustAnchor(String),
     /// <p>The request was rejected because an internal exception occurred. The request can be retried.</p>
     KMSInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCustomKeyStoreError {
@@ -1714,40 +1742,49 @@ impl CreateCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInUseException" => {
-                    return RusotoError::Service(CreateCustomKeyStoreError::CloudHsmClusterInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCustomKeyStoreError::CloudHsmClusterInUse(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "CloudHsmClusterNotActiveException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCustomKeyStoreError::CloudHsmClusterNotActive(err.msg),
+                        &res,
                     )
                 }
                 "CloudHsmClusterNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCustomKeyStoreError::CloudHsmClusterNotFound(err.msg),
+                        &res,
                     )
                 }
                 "CustomKeyStoreNameInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCustomKeyStoreError::CustomKeyStoreNameInUse(err.msg),
+                        &res,
                     )
                 }
                 "IncorrectTrustAnchorException" => {
-                    return RusotoError::Service(CreateCustomKeyStoreError::IncorrectTrustAnchor(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCustomKeyStoreError::IncorrectTrustAnchor(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        CreateCustomKeyStoreError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCustomKeyStoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1768,12 +1805,14 @@ impl fmt::Display for CreateCustomKeyStoreError {
             CreateCustomKeyStoreError::CustomKeyStoreNameInUse(ref cause) => write!(f, "{}", cause),
             CreateCustomKeyStoreError::IncorrectTrustAnchor(ref cause) => write!(f, "{}", cause),
             CreateCustomKeyStoreError::KMSInternal(ref cause) => write!(f, "{}", cause),
+            CreateCustomKeyStoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCustomKeyStoreError {}
 /// Errors returned by CreateGrant
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGrantError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -1791,6 +1830,8 @@ pub enum CreateGrantError {
     LimitExceeded(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGrantError {
@@ -1798,31 +1839,31 @@ impl CreateGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CreateGrantError::DependencyTimeout(err.msg))
+                    return RusotoError::service(CreateGrantError::DependencyTimeout(err.msg), &res)
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(CreateGrantError::Disabled(err.msg))
+                    return RusotoError::service(CreateGrantError::Disabled(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateGrantError::InvalidArn(err.msg))
+                    return RusotoError::service(CreateGrantError::InvalidArn(err.msg), &res)
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(CreateGrantError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(CreateGrantError::InvalidGrantToken(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateGrantError::KMSInternal(err.msg))
+                    return RusotoError::service(CreateGrantError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(CreateGrantError::KMSInvalidState(err.msg))
+                    return RusotoError::service(CreateGrantError::KMSInvalidState(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGrantError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateGrantError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateGrantError::NotFound(err.msg))
+                    return RusotoError::service(CreateGrantError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGrantError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1840,12 +1881,14 @@ impl fmt::Display for CreateGrantError {
             CreateGrantError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             CreateGrantError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateGrantError::NotFound(ref cause) => write!(f, "{}", cause),
+            CreateGrantError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGrantError {}
 /// Errors returned by CreateKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateKeyError {
     /// <p>The request was rejected because the associated AWS CloudHSM cluster did not meet the configuration requirements for a custom key store.</p> <ul> <li> <p>The cluster must be configured with private subnets in at least two different Availability Zones in the Region.</p> </li> <li> <p>The <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/configure-sg.html">security group for the cluster</a> (cloudhsm-cluster-<i>&lt;cluster-id&gt;</i>-sg) must include inbound rules and outbound rules that allow TCP traffic on ports 2223-2225. The <b>Source</b> in the inbound rules and the <b>Destination</b> in the outbound rules must match the security group ID. These rules are set by default when you create the cluster. Do not delete or change them. To get information about a particular security group, use the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeSecurityGroups.html">DescribeSecurityGroups</a> operation.</p> </li> <li> <p>The cluster must contain at least as many HSMs as the operation requires. To add HSMs, use the AWS CloudHSM <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_CreateHsm.html">CreateHsm</a> operation.</p> <p>For the <a>CreateCustomKeyStore</a>, <a>UpdateCustomKeyStore</a>, and <a>CreateKey</a> operations, the AWS CloudHSM cluster must have at least two active HSMs, each in a different Availability Zone. For the <a>ConnectCustomKeyStore</a> operation, the AWS CloudHSM must contain at least one active HSM.</p> </li> </ul> <p>For information about the requirements for an AWS CloudHSM cluster that is associated with a custom key store, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">Assemble the Prerequisites</a> in the <i>AWS Key Management Service Developer Guide</i>. For information about creating a private subnet for an AWS CloudHSM cluster, see <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/create-subnets.html">Create a Private Subnet</a> in the <i>AWS CloudHSM User Guide</i>. For information about cluster security groups, see <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/configure-sg.html">Configure a Default Security Group</a> in the <i> <i>AWS CloudHSM User Guide</i> </i>. </p>
     CloudHsmClusterInvalidConfiguration(String),
@@ -1867,6 +1910,8 @@ pub enum CreateKeyError {
     Tag(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateKeyError {
@@ -1874,39 +1919,50 @@ impl CreateKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateKeyError::CloudHsmClusterInvalidConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(CreateKeyError::CustomKeyStoreInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateKeyError::CustomKeyStoreInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(CreateKeyError::CustomKeyStoreNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateKeyError::CustomKeyStoreNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CreateKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(CreateKeyError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(CreateKeyError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(CreateKeyError::KMSInternal(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateKeyError::LimitExceeded(err.msg), &res)
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(CreateKeyError::MalformedPolicyDocument(err.msg))
+                    return RusotoError::service(
+                        CreateKeyError::MalformedPolicyDocument(err.msg),
+                        &res,
+                    )
                 }
-                "TagException" => return RusotoError::Service(CreateKeyError::Tag(err.msg)),
+                "TagException" => return RusotoError::service(CreateKeyError::Tag(err.msg), &res),
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateKeyError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        CreateKeyError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1928,12 +1984,14 @@ impl fmt::Display for CreateKeyError {
             CreateKeyError::MalformedPolicyDocument(ref cause) => write!(f, "{}", cause),
             CreateKeyError::Tag(ref cause) => write!(f, "{}", cause),
             CreateKeyError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            CreateKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateKeyError {}
 /// Errors returned by Decrypt
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DecryptError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -1955,6 +2013,8 @@ pub enum DecryptError {
     KeyUnavailable(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DecryptError {
@@ -1962,37 +2022,37 @@ impl DecryptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DecryptError::DependencyTimeout(err.msg))
+                    return RusotoError::service(DecryptError::DependencyTimeout(err.msg), &res)
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(DecryptError::Disabled(err.msg))
+                    return RusotoError::service(DecryptError::Disabled(err.msg), &res)
                 }
                 "IncorrectKeyException" => {
-                    return RusotoError::Service(DecryptError::IncorrectKey(err.msg))
+                    return RusotoError::service(DecryptError::IncorrectKey(err.msg), &res)
                 }
                 "InvalidCiphertextException" => {
-                    return RusotoError::Service(DecryptError::InvalidCiphertext(err.msg))
+                    return RusotoError::service(DecryptError::InvalidCiphertext(err.msg), &res)
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(DecryptError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(DecryptError::InvalidGrantToken(err.msg), &res)
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(DecryptError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(DecryptError::InvalidKeyUsage(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DecryptError::KMSInternal(err.msg))
+                    return RusotoError::service(DecryptError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DecryptError::KMSInvalidState(err.msg))
+                    return RusotoError::service(DecryptError::KMSInvalidState(err.msg), &res)
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(DecryptError::KeyUnavailable(err.msg))
+                    return RusotoError::service(DecryptError::KeyUnavailable(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DecryptError::NotFound(err.msg))
+                    return RusotoError::service(DecryptError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DecryptError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2012,12 +2072,14 @@ impl fmt::Display for DecryptError {
             DecryptError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             DecryptError::KeyUnavailable(ref cause) => write!(f, "{}", cause),
             DecryptError::NotFound(ref cause) => write!(f, "{}", cause),
+            DecryptError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DecryptError {}
 /// Errors returned by DeleteAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAliasError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2027,6 +2089,8 @@ pub enum DeleteAliasError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAliasError {
@@ -2034,19 +2098,19 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DeleteAliasError::DependencyTimeout(err.msg))
+                    return RusotoError::service(DeleteAliasError::DependencyTimeout(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DeleteAliasError::KMSInternal(err.msg))
+                    return RusotoError::service(DeleteAliasError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DeleteAliasError::KMSInvalidState(err.msg))
+                    return RusotoError::service(DeleteAliasError::KMSInvalidState(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAliasError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2060,12 +2124,14 @@ impl fmt::Display for DeleteAliasError {
             DeleteAliasError::KMSInternal(ref cause) => write!(f, "{}", cause),
             DeleteAliasError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             DeleteAliasError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAliasError {}
 /// Errors returned by DeleteCustomKeyStore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCustomKeyStoreError {
     /// <p>The request was rejected because the custom key store contains AWS KMS customer master keys (CMKs). After verifying that you do not need to use the CMKs, use the <a>ScheduleKeyDeletion</a> operation to delete the CMKs. After they are deleted, you can delete the custom key store.</p>
     CustomKeyStoreHasCMKs(String),
@@ -2075,6 +2141,8 @@ pub enum DeleteCustomKeyStoreError {
     CustomKeyStoreNotFound(String),
     /// <p>The request was rejected because an internal exception occurred. The request can be retried.</p>
     KMSInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCustomKeyStoreError {
@@ -2082,25 +2150,31 @@ impl DeleteCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreHasCMKsException" => {
-                    return RusotoError::Service(DeleteCustomKeyStoreError::CustomKeyStoreHasCMKs(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCustomKeyStoreError::CustomKeyStoreHasCMKs(err.msg),
+                        &res,
+                    )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
+                        &res,
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(DeleteCustomKeyStoreError::CustomKeyStoreNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DeleteCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        DeleteCustomKeyStoreError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCustomKeyStoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2116,12 +2190,14 @@ impl fmt::Display for DeleteCustomKeyStoreError {
             }
             DeleteCustomKeyStoreError::CustomKeyStoreNotFound(ref cause) => write!(f, "{}", cause),
             DeleteCustomKeyStoreError::KMSInternal(ref cause) => write!(f, "{}", cause),
+            DeleteCustomKeyStoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCustomKeyStoreError {}
 /// Errors returned by DeleteImportedKeyMaterial
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteImportedKeyMaterialError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2135,6 +2211,8 @@ pub enum DeleteImportedKeyMaterialError {
     NotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteImportedKeyMaterialError {
@@ -2142,35 +2220,45 @@ impl DeleteImportedKeyMaterialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImportedKeyMaterialError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImportedKeyMaterialError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::KMSInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImportedKeyMaterialError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImportedKeyMaterialError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteImportedKeyMaterialError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteImportedKeyMaterialError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteImportedKeyMaterialError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2188,17 +2276,21 @@ impl fmt::Display for DeleteImportedKeyMaterialError {
             DeleteImportedKeyMaterialError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteImportedKeyMaterialError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteImportedKeyMaterialError {}
 /// Errors returned by DescribeCustomKeyStores
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCustomKeyStoresError {
     /// <p>The request was rejected because AWS KMS cannot find a custom key store with the specified key store name or ID.</p>
     CustomKeyStoreNotFound(String),
     /// <p>The request was rejected because an internal exception occurred. The request can be retried.</p>
     KMSInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCustomKeyStoresError {
@@ -2206,15 +2298,19 @@ impl DescribeCustomKeyStoresError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCustomKeyStoresError::CustomKeyStoreNotFound(err.msg),
+                        &res,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DescribeCustomKeyStoresError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        DescribeCustomKeyStoresError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCustomKeyStoresError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2228,12 +2324,14 @@ impl fmt::Display for DescribeCustomKeyStoresError {
                 write!(f, "{}", cause)
             }
             DescribeCustomKeyStoresError::KMSInternal(ref cause) => write!(f, "{}", cause),
+            DescribeCustomKeyStoresError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCustomKeyStoresError {}
 /// Errors returned by DescribeKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeKeyError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2243,6 +2341,8 @@ pub enum DescribeKeyError {
     KMSInternal(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeKeyError {
@@ -2250,19 +2350,19 @@ impl DescribeKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DescribeKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(DescribeKeyError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DescribeKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(DescribeKeyError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DescribeKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(DescribeKeyError::KMSInternal(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeKeyError::NotFound(err.msg))
+                    return RusotoError::service(DescribeKeyError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2276,12 +2376,14 @@ impl fmt::Display for DescribeKeyError {
             DescribeKeyError::InvalidArn(ref cause) => write!(f, "{}", cause),
             DescribeKeyError::KMSInternal(ref cause) => write!(f, "{}", cause),
             DescribeKeyError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeKeyError {}
 /// Errors returned by DisableKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableKeyError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2293,6 +2395,8 @@ pub enum DisableKeyError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableKeyError {
@@ -2300,22 +2404,22 @@ impl DisableKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DisableKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(DisableKeyError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DisableKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(DisableKeyError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DisableKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(DisableKeyError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DisableKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(DisableKeyError::KMSInvalidState(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisableKeyError::NotFound(err.msg))
+                    return RusotoError::service(DisableKeyError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2330,12 +2434,14 @@ impl fmt::Display for DisableKeyError {
             DisableKeyError::KMSInternal(ref cause) => write!(f, "{}", cause),
             DisableKeyError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             DisableKeyError::NotFound(ref cause) => write!(f, "{}", cause),
+            DisableKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableKeyError {}
 /// Errors returned by DisableKeyRotation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableKeyRotationError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2351,6 +2457,8 @@ pub enum DisableKeyRotationError {
     NotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableKeyRotationError {
@@ -2358,32 +2466,40 @@ impl DisableKeyRotationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DisableKeyRotationError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableKeyRotationError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(DisableKeyRotationError::Disabled(err.msg))
+                    return RusotoError::service(DisableKeyRotationError::Disabled(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DisableKeyRotationError::InvalidArn(err.msg))
+                    return RusotoError::service(DisableKeyRotationError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DisableKeyRotationError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        DisableKeyRotationError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DisableKeyRotationError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        DisableKeyRotationError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisableKeyRotationError::NotFound(err.msg))
+                    return RusotoError::service(DisableKeyRotationError::NotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DisableKeyRotationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableKeyRotationError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableKeyRotationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2400,12 +2516,14 @@ impl fmt::Display for DisableKeyRotationError {
             DisableKeyRotationError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             DisableKeyRotationError::NotFound(ref cause) => write!(f, "{}", cause),
             DisableKeyRotationError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DisableKeyRotationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableKeyRotationError {}
 /// Errors returned by DisconnectCustomKeyStore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisconnectCustomKeyStoreError {
     /// <p><p>The request was rejected because of the <code>ConnectionState</code> of the custom key store. To get the <code>ConnectionState</code> of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>This exception is thrown under the following conditions:</p> <ul> <li> <p>You requested the <a>CreateKey</a> or <a>GenerateRandom</a> operation in a custom key store that is not connected. These operations are valid only when the custom key store <code>ConnectionState</code> is <code>CONNECTED</code>.</p> </li> <li> <p>You requested the <a>UpdateCustomKeyStore</a> or <a>DeleteCustomKeyStore</a> operation on a custom key store that is not disconnected. This operation is valid only when the custom key store <code>ConnectionState</code> is <code>DISCONNECTED</code>.</p> </li> <li> <p>You requested the <a>ConnectCustomKeyStore</a> operation on a custom key store with a <code>ConnectionState</code> of <code>DISCONNECTING</code> or <code>FAILED</code>. This operation is valid for all other <code>ConnectionState</code> values.</p> </li> </ul></p>
     CustomKeyStoreInvalidState(String),
@@ -2413,6 +2531,8 @@ pub enum DisconnectCustomKeyStoreError {
     CustomKeyStoreNotFound(String),
     /// <p>The request was rejected because an internal exception occurred. The request can be retried.</p>
     KMSInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisconnectCustomKeyStoreError {
@@ -2420,22 +2540,25 @@ impl DisconnectCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisconnectCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
+                        &res,
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisconnectCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
+                        &res,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DisconnectCustomKeyStoreError::KMSInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisconnectCustomKeyStoreError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisconnectCustomKeyStoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2452,12 +2575,14 @@ impl fmt::Display for DisconnectCustomKeyStoreError {
                 write!(f, "{}", cause)
             }
             DisconnectCustomKeyStoreError::KMSInternal(ref cause) => write!(f, "{}", cause),
+            DisconnectCustomKeyStoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisconnectCustomKeyStoreError {}
 /// Errors returned by EnableKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableKeyError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2471,6 +2596,8 @@ pub enum EnableKeyError {
     LimitExceeded(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableKeyError {
@@ -2478,25 +2605,25 @@ impl EnableKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(EnableKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(EnableKeyError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(EnableKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(EnableKeyError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(EnableKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(EnableKeyError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(EnableKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(EnableKeyError::KMSInvalidState(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(EnableKeyError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EnableKeyError::NotFound(err.msg))
+                    return RusotoError::service(EnableKeyError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2512,12 +2639,14 @@ impl fmt::Display for EnableKeyError {
             EnableKeyError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             EnableKeyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             EnableKeyError::NotFound(ref cause) => write!(f, "{}", cause),
+            EnableKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableKeyError {}
 /// Errors returned by EnableKeyRotation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableKeyRotationError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2533,6 +2662,8 @@ pub enum EnableKeyRotationError {
     NotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableKeyRotationError {
@@ -2540,30 +2671,37 @@ impl EnableKeyRotationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(EnableKeyRotationError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        EnableKeyRotationError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(EnableKeyRotationError::Disabled(err.msg))
+                    return RusotoError::service(EnableKeyRotationError::Disabled(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(EnableKeyRotationError::InvalidArn(err.msg))
+                    return RusotoError::service(EnableKeyRotationError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(EnableKeyRotationError::KMSInternal(err.msg))
+                    return RusotoError::service(EnableKeyRotationError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(EnableKeyRotationError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        EnableKeyRotationError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EnableKeyRotationError::NotFound(err.msg))
+                    return RusotoError::service(EnableKeyRotationError::NotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(EnableKeyRotationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableKeyRotationError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableKeyRotationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2580,12 +2718,14 @@ impl fmt::Display for EnableKeyRotationError {
             EnableKeyRotationError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             EnableKeyRotationError::NotFound(ref cause) => write!(f, "{}", cause),
             EnableKeyRotationError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            EnableKeyRotationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableKeyRotationError {}
 /// Errors returned by Encrypt
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EncryptError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2603,6 +2743,8 @@ pub enum EncryptError {
     KeyUnavailable(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EncryptError {
@@ -2610,31 +2752,31 @@ impl EncryptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(EncryptError::DependencyTimeout(err.msg))
+                    return RusotoError::service(EncryptError::DependencyTimeout(err.msg), &res)
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(EncryptError::Disabled(err.msg))
+                    return RusotoError::service(EncryptError::Disabled(err.msg), &res)
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(EncryptError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(EncryptError::InvalidGrantToken(err.msg), &res)
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(EncryptError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(EncryptError::InvalidKeyUsage(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(EncryptError::KMSInternal(err.msg))
+                    return RusotoError::service(EncryptError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(EncryptError::KMSInvalidState(err.msg))
+                    return RusotoError::service(EncryptError::KMSInvalidState(err.msg), &res)
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(EncryptError::KeyUnavailable(err.msg))
+                    return RusotoError::service(EncryptError::KeyUnavailable(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EncryptError::NotFound(err.msg))
+                    return RusotoError::service(EncryptError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EncryptError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2652,12 +2794,14 @@ impl fmt::Display for EncryptError {
             EncryptError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             EncryptError::KeyUnavailable(ref cause) => write!(f, "{}", cause),
             EncryptError::NotFound(ref cause) => write!(f, "{}", cause),
+            EncryptError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EncryptError {}
 /// Errors returned by GenerateDataKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateDataKeyError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2675,6 +2819,8 @@ pub enum GenerateDataKeyError {
     KeyUnavailable(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateDataKeyError {
@@ -2682,31 +2828,46 @@ impl GenerateDataKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GenerateDataKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        GenerateDataKeyError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GenerateDataKeyError::Disabled(err.msg))
+                    return RusotoError::service(GenerateDataKeyError::Disabled(err.msg), &res)
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(GenerateDataKeyError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(
+                        GenerateDataKeyError::InvalidGrantToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(GenerateDataKeyError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(
+                        GenerateDataKeyError::InvalidKeyUsage(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateDataKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(GenerateDataKeyError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GenerateDataKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        GenerateDataKeyError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(GenerateDataKeyError::KeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        GenerateDataKeyError::KeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateDataKeyError::NotFound(err.msg))
+                    return RusotoError::service(GenerateDataKeyError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GenerateDataKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2724,12 +2885,14 @@ impl fmt::Display for GenerateDataKeyError {
             GenerateDataKeyError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             GenerateDataKeyError::KeyUnavailable(ref cause) => write!(f, "{}", cause),
             GenerateDataKeyError::NotFound(ref cause) => write!(f, "{}", cause),
+            GenerateDataKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GenerateDataKeyError {}
 /// Errors returned by GenerateDataKeyPair
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateDataKeyPairError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2747,6 +2910,8 @@ pub enum GenerateDataKeyPairError {
     KeyUnavailable(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateDataKeyPairError {
@@ -2754,35 +2919,49 @@ impl GenerateDataKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateDataKeyPairError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::Disabled(err.msg))
+                    return RusotoError::service(GenerateDataKeyPairError::Disabled(err.msg), &res)
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::InvalidGrantToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateDataKeyPairError::InvalidGrantToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(
+                        GenerateDataKeyPairError::InvalidKeyUsage(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        GenerateDataKeyPairError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        GenerateDataKeyPairError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::KeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        GenerateDataKeyPairError::KeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(GenerateDataKeyPairError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GenerateDataKeyPairError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2800,12 +2979,14 @@ impl fmt::Display for GenerateDataKeyPairError {
             GenerateDataKeyPairError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             GenerateDataKeyPairError::KeyUnavailable(ref cause) => write!(f, "{}", cause),
             GenerateDataKeyPairError::NotFound(ref cause) => write!(f, "{}", cause),
+            GenerateDataKeyPairError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GenerateDataKeyPairError {}
 /// Errors returned by GenerateDataKeyPairWithoutPlaintext
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateDataKeyPairWithoutPlaintextError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2823,6 +3004,8 @@ pub enum GenerateDataKeyPairWithoutPlaintextError {
     KeyUnavailable(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateDataKeyPairWithoutPlaintextError {
@@ -2832,47 +3015,60 @@ impl GenerateDataKeyPairWithoutPlaintextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyPairWithoutPlaintextError::DependencyTimeout(err.msg),
+                        &res,
                     )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyPairWithoutPlaintextError::Disabled(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyPairWithoutPlaintextError::InvalidGrantToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyPairWithoutPlaintextError::InvalidKeyUsage(err.msg),
+                        &res,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyPairWithoutPlaintextError::KMSInternal(err.msg),
+                        &res,
                     )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyPairWithoutPlaintextError::KMSInvalidState(err.msg),
+                        &res,
                     )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyPairWithoutPlaintextError::KeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyPairWithoutPlaintextError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GenerateDataKeyPairWithoutPlaintextError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2902,12 +3098,16 @@ impl fmt::Display for GenerateDataKeyPairWithoutPlaintextError {
                 write!(f, "{}", cause)
             }
             GenerateDataKeyPairWithoutPlaintextError::NotFound(ref cause) => write!(f, "{}", cause),
+            GenerateDataKeyPairWithoutPlaintextError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GenerateDataKeyPairWithoutPlaintextError {}
 /// Errors returned by GenerateDataKeyWithoutPlaintext
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateDataKeyWithoutPlaintextError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -2925,6 +3125,8 @@ pub enum GenerateDataKeyWithoutPlaintextError {
     KeyUnavailable(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateDataKeyWithoutPlaintextError {
@@ -2934,47 +3136,60 @@ impl GenerateDataKeyWithoutPlaintextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyWithoutPlaintextError::DependencyTimeout(err.msg),
+                        &res,
                     )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GenerateDataKeyWithoutPlaintextError::Disabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateDataKeyWithoutPlaintextError::Disabled(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyWithoutPlaintextError::InvalidGrantToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyWithoutPlaintextError::InvalidKeyUsage(err.msg),
+                        &res,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateDataKeyWithoutPlaintextError::KMSInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateDataKeyWithoutPlaintextError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyWithoutPlaintextError::KMSInvalidState(err.msg),
+                        &res,
                     )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataKeyWithoutPlaintextError::KeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateDataKeyWithoutPlaintextError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateDataKeyWithoutPlaintextError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GenerateDataKeyWithoutPlaintextError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3002,12 +3217,16 @@ impl fmt::Display for GenerateDataKeyWithoutPlaintextError {
                 write!(f, "{}", cause)
             }
             GenerateDataKeyWithoutPlaintextError::NotFound(ref cause) => write!(f, "{}", cause),
+            GenerateDataKeyWithoutPlaintextError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GenerateDataKeyWithoutPlaintextError {}
 /// Errors returned by GenerateRandom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateRandomError {
     /// <p><p>The request was rejected because of the <code>ConnectionState</code> of the custom key store. To get the <code>ConnectionState</code> of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>This exception is thrown under the following conditions:</p> <ul> <li> <p>You requested the <a>CreateKey</a> or <a>GenerateRandom</a> operation in a custom key store that is not connected. These operations are valid only when the custom key store <code>ConnectionState</code> is <code>CONNECTED</code>.</p> </li> <li> <p>You requested the <a>UpdateCustomKeyStore</a> or <a>DeleteCustomKeyStore</a> operation on a custom key store that is not disconnected. This operation is valid only when the custom key store <code>ConnectionState</code> is <code>DISCONNECTED</code>.</p> </li> <li> <p>You requested the <a>ConnectCustomKeyStore</a> operation on a custom key store with a <code>ConnectionState</code> of <code>DISCONNECTING</code> or <code>FAILED</code>. This operation is valid for all other <code>ConnectionState</code> values.</p> </li> </ul></p>
     CustomKeyStoreInvalidState(String),
@@ -3017,6 +3236,8 @@ pub enum GenerateRandomError {
     DependencyTimeout(String),
     /// <p>The request was rejected because an internal exception occurred. The request can be retried.</p>
     KMSInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateRandomError {
@@ -3024,23 +3245,28 @@ impl GenerateRandomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(GenerateRandomError::CustomKeyStoreInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateRandomError::CustomKeyStoreInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(GenerateRandomError::CustomKeyStoreNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateRandomError::CustomKeyStoreNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GenerateRandomError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        GenerateRandomError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateRandomError::KMSInternal(err.msg))
+                    return RusotoError::service(GenerateRandomError::KMSInternal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GenerateRandomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3054,12 +3280,14 @@ impl fmt::Display for GenerateRandomError {
             GenerateRandomError::CustomKeyStoreNotFound(ref cause) => write!(f, "{}", cause),
             GenerateRandomError::DependencyTimeout(ref cause) => write!(f, "{}", cause),
             GenerateRandomError::KMSInternal(ref cause) => write!(f, "{}", cause),
+            GenerateRandomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GenerateRandomError {}
 /// Errors returned by GetKeyPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetKeyPolicyError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3071,6 +3299,8 @@ pub enum GetKeyPolicyError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetKeyPolicyError {
@@ -3078,22 +3308,25 @@ impl GetKeyPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetKeyPolicyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        GetKeyPolicyError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetKeyPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(GetKeyPolicyError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetKeyPolicyError::KMSInternal(err.msg))
+                    return RusotoError::service(GetKeyPolicyError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetKeyPolicyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(GetKeyPolicyError::KMSInvalidState(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyPolicyError::NotFound(err.msg))
+                    return RusotoError::service(GetKeyPolicyError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetKeyPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3108,12 +3341,14 @@ impl fmt::Display for GetKeyPolicyError {
             GetKeyPolicyError::KMSInternal(ref cause) => write!(f, "{}", cause),
             GetKeyPolicyError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             GetKeyPolicyError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetKeyPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetKeyPolicyError {}
 /// Errors returned by GetKeyRotationStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetKeyRotationStatusError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3127,6 +3362,8 @@ pub enum GetKeyRotationStatusError {
     NotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetKeyRotationStatusError {
@@ -3134,31 +3371,40 @@ impl GetKeyRotationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetKeyRotationStatusError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        GetKeyRotationStatusError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        GetKeyRotationStatusError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetKeyRotationStatusError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::NotFound(err.msg))
+                    return RusotoError::service(GetKeyRotationStatusError::NotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetKeyRotationStatusError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetKeyRotationStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3174,12 +3420,14 @@ impl fmt::Display for GetKeyRotationStatusError {
             GetKeyRotationStatusError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             GetKeyRotationStatusError::NotFound(ref cause) => write!(f, "{}", cause),
             GetKeyRotationStatusError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            GetKeyRotationStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetKeyRotationStatusError {}
 /// Errors returned by GetParametersForImport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetParametersForImportError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3193,6 +3441,8 @@ pub enum GetParametersForImportError {
     NotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetParametersForImportError {
@@ -3200,31 +3450,43 @@ impl GetParametersForImportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetParametersForImportError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParametersForImportError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetParametersForImportError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        GetParametersForImportError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetParametersForImportError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        GetParametersForImportError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetParametersForImportError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParametersForImportError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetParametersForImportError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetParametersForImportError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetParametersForImportError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParametersForImportError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetParametersForImportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3240,12 +3502,14 @@ impl fmt::Display for GetParametersForImportError {
             GetParametersForImportError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             GetParametersForImportError::NotFound(ref cause) => write!(f, "{}", cause),
             GetParametersForImportError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            GetParametersForImportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetParametersForImportError {}
 /// Errors returned by GetPublicKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPublicKeyError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3267,6 +3531,8 @@ pub enum GetPublicKeyError {
     NotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPublicKeyError {
@@ -3274,37 +3540,46 @@ impl GetPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetPublicKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        GetPublicKeyError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GetPublicKeyError::Disabled(err.msg))
+                    return RusotoError::service(GetPublicKeyError::Disabled(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetPublicKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(GetPublicKeyError::InvalidArn(err.msg), &res)
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(GetPublicKeyError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(
+                        GetPublicKeyError::InvalidGrantToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(GetPublicKeyError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(GetPublicKeyError::InvalidKeyUsage(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetPublicKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(GetPublicKeyError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetPublicKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(GetPublicKeyError::KMSInvalidState(err.msg), &res)
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(GetPublicKeyError::KeyUnavailable(err.msg))
+                    return RusotoError::service(GetPublicKeyError::KeyUnavailable(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPublicKeyError::NotFound(err.msg))
+                    return RusotoError::service(GetPublicKeyError::NotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetPublicKeyError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        GetPublicKeyError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPublicKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3324,12 +3599,14 @@ impl fmt::Display for GetPublicKeyError {
             GetPublicKeyError::KeyUnavailable(ref cause) => write!(f, "{}", cause),
             GetPublicKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             GetPublicKeyError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            GetPublicKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPublicKeyError {}
 /// Errors returned by ImportKeyMaterial
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportKeyMaterialError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3351,6 +3628,8 @@ pub enum ImportKeyMaterialError {
     NotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportKeyMaterialError {
@@ -3358,45 +3637,58 @@ impl ImportKeyMaterialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        ImportKeyMaterialError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ExpiredImportTokenException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::ExpiredImportToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportKeyMaterialError::ExpiredImportToken(err.msg),
+                        &res,
+                    )
                 }
                 "IncorrectKeyMaterialException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::IncorrectKeyMaterial(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportKeyMaterialError::IncorrectKeyMaterial(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::InvalidArn(err.msg))
+                    return RusotoError::service(ImportKeyMaterialError::InvalidArn(err.msg), &res)
                 }
                 "InvalidCiphertextException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::InvalidCiphertext(err.msg))
+                    return RusotoError::service(
+                        ImportKeyMaterialError::InvalidCiphertext(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImportTokenException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::InvalidImportToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportKeyMaterialError::InvalidImportToken(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::KMSInternal(err.msg))
+                    return RusotoError::service(ImportKeyMaterialError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        ImportKeyMaterialError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::NotFound(err.msg))
+                    return RusotoError::service(ImportKeyMaterialError::NotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportKeyMaterialError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportKeyMaterialError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3416,12 +3708,14 @@ impl fmt::Display for ImportKeyMaterialError {
             ImportKeyMaterialError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             ImportKeyMaterialError::NotFound(ref cause) => write!(f, "{}", cause),
             ImportKeyMaterialError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            ImportKeyMaterialError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportKeyMaterialError {}
 /// Errors returned by ListAliases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAliasesError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3433,6 +3727,8 @@ pub enum ListAliasesError {
     KMSInternal(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAliasesError {
@@ -3440,22 +3736,22 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListAliasesError::DependencyTimeout(err.msg))
+                    return RusotoError::service(ListAliasesError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidArn(err.msg))
+                    return RusotoError::service(ListAliasesError::InvalidArn(err.msg), &res)
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidMarker(err.msg))
+                    return RusotoError::service(ListAliasesError::InvalidMarker(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListAliasesError::KMSInternal(err.msg))
+                    return RusotoError::service(ListAliasesError::KMSInternal(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::NotFound(err.msg))
+                    return RusotoError::service(ListAliasesError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAliasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3470,12 +3766,14 @@ impl fmt::Display for ListAliasesError {
             ListAliasesError::InvalidMarker(ref cause) => write!(f, "{}", cause),
             ListAliasesError::KMSInternal(ref cause) => write!(f, "{}", cause),
             ListAliasesError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListAliasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAliasesError {}
 /// Errors returned by ListGrants
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGrantsError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3489,6 +3787,8 @@ pub enum ListGrantsError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGrantsError {
@@ -3496,25 +3796,25 @@ impl ListGrantsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListGrantsError::DependencyTimeout(err.msg))
+                    return RusotoError::service(ListGrantsError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListGrantsError::InvalidArn(err.msg))
+                    return RusotoError::service(ListGrantsError::InvalidArn(err.msg), &res)
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListGrantsError::InvalidMarker(err.msg))
+                    return RusotoError::service(ListGrantsError::InvalidMarker(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListGrantsError::KMSInternal(err.msg))
+                    return RusotoError::service(ListGrantsError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ListGrantsError::KMSInvalidState(err.msg))
+                    return RusotoError::service(ListGrantsError::KMSInvalidState(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListGrantsError::NotFound(err.msg))
+                    return RusotoError::service(ListGrantsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGrantsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3530,12 +3830,14 @@ impl fmt::Display for ListGrantsError {
             ListGrantsError::KMSInternal(ref cause) => write!(f, "{}", cause),
             ListGrantsError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             ListGrantsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListGrantsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGrantsError {}
 /// Errors returned by ListKeyPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListKeyPoliciesError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3547,6 +3849,8 @@ pub enum ListKeyPoliciesError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListKeyPoliciesError {
@@ -3554,22 +3858,28 @@ impl ListKeyPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        ListKeyPoliciesError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::InvalidArn(err.msg))
+                    return RusotoError::service(ListKeyPoliciesError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::KMSInternal(err.msg))
+                    return RusotoError::service(ListKeyPoliciesError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        ListKeyPoliciesError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::NotFound(err.msg))
+                    return RusotoError::service(ListKeyPoliciesError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListKeyPoliciesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3584,12 +3894,14 @@ impl fmt::Display for ListKeyPoliciesError {
             ListKeyPoliciesError::KMSInternal(ref cause) => write!(f, "{}", cause),
             ListKeyPoliciesError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             ListKeyPoliciesError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListKeyPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListKeyPoliciesError {}
 /// Errors returned by ListKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListKeysError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3597,6 +3909,8 @@ pub enum ListKeysError {
     InvalidMarker(String),
     /// <p>The request was rejected because an internal exception occurred. The request can be retried.</p>
     KMSInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListKeysError {
@@ -3604,16 +3918,16 @@ impl ListKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListKeysError::DependencyTimeout(err.msg))
+                    return RusotoError::service(ListKeysError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListKeysError::InvalidMarker(err.msg))
+                    return RusotoError::service(ListKeysError::InvalidMarker(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListKeysError::KMSInternal(err.msg))
+                    return RusotoError::service(ListKeysError::KMSInternal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListKeysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3626,12 +3940,14 @@ impl fmt::Display for ListKeysError {
             ListKeysError::DependencyTimeout(ref cause) => write!(f, "{}", cause),
             ListKeysError::InvalidMarker(ref cause) => write!(f, "{}", cause),
             ListKeysError::KMSInternal(ref cause) => write!(f, "{}", cause),
+            ListKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListKeysError {}
 /// Errors returned by ListResourceTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourceTagsError {
     /// <p>The request was rejected because a specified ARN, or an ARN in a key policy, is not valid.</p>
     InvalidArn(String),
@@ -3641,6 +3957,8 @@ pub enum ListResourceTagsError {
     KMSInternal(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourceTagsError {
@@ -3648,19 +3966,22 @@ impl ListResourceTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListResourceTagsError::InvalidArn(err.msg))
+                    return RusotoError::service(ListResourceTagsError::InvalidArn(err.msg), &res)
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListResourceTagsError::InvalidMarker(err.msg))
+                    return RusotoError::service(
+                        ListResourceTagsError::InvalidMarker(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListResourceTagsError::KMSInternal(err.msg))
+                    return RusotoError::service(ListResourceTagsError::KMSInternal(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListResourceTagsError::NotFound(err.msg))
+                    return RusotoError::service(ListResourceTagsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResourceTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3674,12 +3995,14 @@ impl fmt::Display for ListResourceTagsError {
             ListResourceTagsError::InvalidMarker(ref cause) => write!(f, "{}", cause),
             ListResourceTagsError::KMSInternal(ref cause) => write!(f, "{}", cause),
             ListResourceTagsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListResourceTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourceTagsError {}
 /// Errors returned by ListRetirableGrants
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRetirableGrantsError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3691,6 +4014,8 @@ pub enum ListRetirableGrantsError {
     KMSInternal(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRetirableGrantsError {
@@ -3698,24 +4023,34 @@ impl ListRetirableGrantsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRetirableGrantsError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListRetirableGrantsError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::InvalidMarker(err.msg))
+                    return RusotoError::service(
+                        ListRetirableGrantsError::InvalidMarker(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        ListRetirableGrantsError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::NotFound(err.msg))
+                    return RusotoError::service(ListRetirableGrantsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRetirableGrantsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3730,12 +4065,14 @@ impl fmt::Display for ListRetirableGrantsError {
             ListRetirableGrantsError::InvalidMarker(ref cause) => write!(f, "{}", cause),
             ListRetirableGrantsError::KMSInternal(ref cause) => write!(f, "{}", cause),
             ListRetirableGrantsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListRetirableGrantsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRetirableGrantsError {}
 /// Errors returned by PutKeyPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutKeyPolicyError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3753,6 +4090,8 @@ pub enum PutKeyPolicyError {
     NotFound(String),
     /// <p>The request was rejected because a specified parameter is not supported or a specified resource is not valid for this operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutKeyPolicyError {
@@ -3760,33 +4099,40 @@ impl PutKeyPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(PutKeyPolicyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        PutKeyPolicyError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PutKeyPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(PutKeyPolicyError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(PutKeyPolicyError::KMSInternal(err.msg))
+                    return RusotoError::service(PutKeyPolicyError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(PutKeyPolicyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(PutKeyPolicyError::KMSInvalidState(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutKeyPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutKeyPolicyError::LimitExceeded(err.msg), &res)
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(PutKeyPolicyError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutKeyPolicyError::MalformedPolicyDocument(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutKeyPolicyError::NotFound(err.msg))
+                    return RusotoError::service(PutKeyPolicyError::NotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutKeyPolicyError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        PutKeyPolicyError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutKeyPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3804,12 +4150,14 @@ impl fmt::Display for PutKeyPolicyError {
             PutKeyPolicyError::MalformedPolicyDocument(ref cause) => write!(f, "{}", cause),
             PutKeyPolicyError::NotFound(ref cause) => write!(f, "{}", cause),
             PutKeyPolicyError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            PutKeyPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutKeyPolicyError {}
 /// Errors returned by ReEncrypt
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReEncryptError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3831,6 +4179,8 @@ pub enum ReEncryptError {
     KeyUnavailable(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReEncryptError {
@@ -3838,37 +4188,37 @@ impl ReEncryptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ReEncryptError::DependencyTimeout(err.msg))
+                    return RusotoError::service(ReEncryptError::DependencyTimeout(err.msg), &res)
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(ReEncryptError::Disabled(err.msg))
+                    return RusotoError::service(ReEncryptError::Disabled(err.msg), &res)
                 }
                 "IncorrectKeyException" => {
-                    return RusotoError::Service(ReEncryptError::IncorrectKey(err.msg))
+                    return RusotoError::service(ReEncryptError::IncorrectKey(err.msg), &res)
                 }
                 "InvalidCiphertextException" => {
-                    return RusotoError::Service(ReEncryptError::InvalidCiphertext(err.msg))
+                    return RusotoError::service(ReEncryptError::InvalidCiphertext(err.msg), &res)
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(ReEncryptError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(ReEncryptError::InvalidGrantToken(err.msg), &res)
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(ReEncryptError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(ReEncryptError::InvalidKeyUsage(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ReEncryptError::KMSInternal(err.msg))
+                    return RusotoError::service(ReEncryptError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ReEncryptError::KMSInvalidState(err.msg))
+                    return RusotoError::service(ReEncryptError::KMSInvalidState(err.msg), &res)
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(ReEncryptError::KeyUnavailable(err.msg))
+                    return RusotoError::service(ReEncryptError::KeyUnavailable(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ReEncryptError::NotFound(err.msg))
+                    return RusotoError::service(ReEncryptError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ReEncryptError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3888,12 +4238,14 @@ impl fmt::Display for ReEncryptError {
             ReEncryptError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             ReEncryptError::KeyUnavailable(ref cause) => write!(f, "{}", cause),
             ReEncryptError::NotFound(ref cause) => write!(f, "{}", cause),
+            ReEncryptError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReEncryptError {}
 /// Errors returned by RetireGrant
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RetireGrantError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3909,6 +4261,8 @@ pub enum RetireGrantError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RetireGrantError {
@@ -3916,28 +4270,28 @@ impl RetireGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(RetireGrantError::DependencyTimeout(err.msg))
+                    return RusotoError::service(RetireGrantError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RetireGrantError::InvalidArn(err.msg))
+                    return RusotoError::service(RetireGrantError::InvalidArn(err.msg), &res)
                 }
                 "InvalidGrantIdException" => {
-                    return RusotoError::Service(RetireGrantError::InvalidGrantId(err.msg))
+                    return RusotoError::service(RetireGrantError::InvalidGrantId(err.msg), &res)
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(RetireGrantError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(RetireGrantError::InvalidGrantToken(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(RetireGrantError::KMSInternal(err.msg))
+                    return RusotoError::service(RetireGrantError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(RetireGrantError::KMSInvalidState(err.msg))
+                    return RusotoError::service(RetireGrantError::KMSInvalidState(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RetireGrantError::NotFound(err.msg))
+                    return RusotoError::service(RetireGrantError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RetireGrantError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3954,12 +4308,14 @@ impl fmt::Display for RetireGrantError {
             RetireGrantError::KMSInternal(ref cause) => write!(f, "{}", cause),
             RetireGrantError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             RetireGrantError::NotFound(ref cause) => write!(f, "{}", cause),
+            RetireGrantError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RetireGrantError {}
 /// Errors returned by RevokeGrant
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RevokeGrantError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -3973,6 +4329,8 @@ pub enum RevokeGrantError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RevokeGrantError {
@@ -3980,25 +4338,25 @@ impl RevokeGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(RevokeGrantError::DependencyTimeout(err.msg))
+                    return RusotoError::service(RevokeGrantError::DependencyTimeout(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RevokeGrantError::InvalidArn(err.msg))
+                    return RusotoError::service(RevokeGrantError::InvalidArn(err.msg), &res)
                 }
                 "InvalidGrantIdException" => {
-                    return RusotoError::Service(RevokeGrantError::InvalidGrantId(err.msg))
+                    return RusotoError::service(RevokeGrantError::InvalidGrantId(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(RevokeGrantError::KMSInternal(err.msg))
+                    return RusotoError::service(RevokeGrantError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(RevokeGrantError::KMSInvalidState(err.msg))
+                    return RusotoError::service(RevokeGrantError::KMSInvalidState(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RevokeGrantError::NotFound(err.msg))
+                    return RusotoError::service(RevokeGrantError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RevokeGrantError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4014,12 +4372,14 @@ impl fmt::Display for RevokeGrantError {
             RevokeGrantError::KMSInternal(ref cause) => write!(f, "{}", cause),
             RevokeGrantError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             RevokeGrantError::NotFound(ref cause) => write!(f, "{}", cause),
+            RevokeGrantError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RevokeGrantError {}
 /// Errors returned by ScheduleKeyDeletion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ScheduleKeyDeletionError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -4031,6 +4391,8 @@ pub enum ScheduleKeyDeletionError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ScheduleKeyDeletionError {
@@ -4038,24 +4400,34 @@ impl ScheduleKeyDeletionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ScheduleKeyDeletionError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ScheduleKeyDeletionError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        ScheduleKeyDeletionError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        ScheduleKeyDeletionError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::NotFound(err.msg))
+                    return RusotoError::service(ScheduleKeyDeletionError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ScheduleKeyDeletionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4070,12 +4442,14 @@ impl fmt::Display for ScheduleKeyDeletionError {
             ScheduleKeyDeletionError::KMSInternal(ref cause) => write!(f, "{}", cause),
             ScheduleKeyDeletionError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             ScheduleKeyDeletionError::NotFound(ref cause) => write!(f, "{}", cause),
+            ScheduleKeyDeletionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ScheduleKeyDeletionError {}
 /// Errors returned by Sign
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SignError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -4093,6 +4467,8 @@ pub enum SignError {
     KeyUnavailable(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SignError {
@@ -4100,27 +4476,31 @@ impl SignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(SignError::DependencyTimeout(err.msg))
+                    return RusotoError::service(SignError::DependencyTimeout(err.msg), &res)
+                }
+                "DisabledException" => {
+                    return RusotoError::service(SignError::Disabled(err.msg), &res)
                 }
-                "DisabledException" => return RusotoError::Service(SignError::Disabled(err.msg)),
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(SignError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(SignError::InvalidGrantToken(err.msg), &res)
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(SignError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(SignError::InvalidKeyUsage(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(SignError::KMSInternal(err.msg))
+                    return RusotoError::service(SignError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(SignError::KMSInvalidState(err.msg))
+                    return RusotoError::service(SignError::KMSInvalidState(err.msg), &res)
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(SignError::KeyUnavailable(err.msg))
+                    return RusotoError::service(SignError::KeyUnavailable(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(SignError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(SignError::NotFound(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SignError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4138,12 +4518,14 @@ impl fmt::Display for SignError {
             SignError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             SignError::KeyUnavailable(ref cause) => write!(f, "{}", cause),
             SignError::NotFound(ref cause) => write!(f, "{}", cause),
+            SignError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SignError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The request was rejected because a specified ARN, or an ARN in a key policy, is not valid.</p>
     InvalidArn(String),
@@ -4157,6 +4539,8 @@ pub enum TagResourceError {
     NotFound(String),
     /// <p>The request was rejected because one or more tags are not valid.</p>
     Tag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4164,23 +4548,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(TagResourceError::KMSInternal(err.msg))
+                    return RusotoError::service(TagResourceError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(TagResourceError::KMSInvalidState(err.msg))
+                    return RusotoError::service(TagResourceError::KMSInvalidState(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
+                }
+                "TagException" => {
+                    return RusotoError::service(TagResourceError::Tag(err.msg), &res)
                 }
-                "TagException" => return RusotoError::Service(TagResourceError::Tag(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4196,12 +4582,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Tag(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The request was rejected because a specified ARN, or an ARN in a key policy, is not valid.</p>
     InvalidArn(String),
@@ -4213,6 +4601,8 @@ pub enum UntagResourceError {
     NotFound(String),
     /// <p>The request was rejected because one or more tags are not valid.</p>
     Tag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4220,20 +4610,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidArn(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UntagResourceError::KMSInternal(err.msg))
+                    return RusotoError::service(UntagResourceError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(UntagResourceError::KMSInvalidState(err.msg))
+                    return RusotoError::service(UntagResourceError::KMSInvalidState(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
+                }
+                "TagException" => {
+                    return RusotoError::service(UntagResourceError::Tag(err.msg), &res)
                 }
-                "TagException" => return RusotoError::Service(UntagResourceError::Tag(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4248,12 +4640,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Tag(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAliasError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -4263,6 +4657,8 @@ pub enum UpdateAliasError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAliasError {
@@ -4270,19 +4666,19 @@ impl UpdateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(UpdateAliasError::DependencyTimeout(err.msg))
+                    return RusotoError::service(UpdateAliasError::DependencyTimeout(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UpdateAliasError::KMSInternal(err.msg))
+                    return RusotoError::service(UpdateAliasError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(UpdateAliasError::KMSInvalidState(err.msg))
+                    return RusotoError::service(UpdateAliasError::KMSInvalidState(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAliasError::NotFound(err.msg))
+                    return RusotoError::service(UpdateAliasError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4296,12 +4692,14 @@ impl fmt::Display for UpdateAliasError {
             UpdateAliasError::KMSInternal(ref cause) => write!(f, "{}", cause),
             UpdateAliasError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             UpdateAliasError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAliasError {}
 /// Errors returned by UpdateCustomKeyStore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCustomKeyStoreError {
     /// <p>The request was rejected because the associated AWS CloudHSM cluster did not meet the configuration requirements for a custom key store.</p> <ul> <li> <p>The cluster must be configured with private subnets in at least two different Availability Zones in the Region.</p> </li> <li> <p>The <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/configure-sg.html">security group for the cluster</a> (cloudhsm-cluster-<i>&lt;cluster-id&gt;</i>-sg) must include inbound rules and outbound rules that allow TCP traffic on ports 2223-2225. The <b>Source</b> in the inbound rules and the <b>Destination</b> in the outbound rules must match the security group ID. These rules are set by default when you create the cluster. Do not delete or change them. To get information about a particular security group, use the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeSecurityGroups.html">DescribeSecurityGroups</a> operation.</p> </li> <li> <p>The cluster must contain at least as many HSMs as the operation requires. To add HSMs, use the AWS CloudHSM <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_CreateHsm.html">CreateHsm</a> operation.</p> <p>For the <a>CreateCustomKeyStore</a>, <a>UpdateCustomKeyStore</a>, and <a>CreateKey</a> operations, the AWS CloudHSM cluster must have at least two active HSMs, each in a different Availability Zone. For the <a>ConnectCustomKeyStore</a> operation, the AWS CloudHSM must contain at least one active HSM.</p> </li> </ul> <p>For information about the requirements for an AWS CloudHSM cluster that is associated with a custom key store, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">Assemble the Prerequisites</a> in the <i>AWS Key Management Service Developer Guide</i>. For information about creating a private subnet for an AWS CloudHSM cluster, see <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/create-subnets.html">Create a Private Subnet</a> in the <i>AWS CloudHSM User Guide</i>. For information about cluster security groups, see <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/configure-sg.html">Configure a Default Security Group</a> in the <i> <i>AWS CloudHSM User Guide</i> </i>. </p>
     CloudHsmClusterInvalidConfiguration(String),
@@ -4319,6 +4717,8 @@ pub enum UpdateCustomKeyStoreError {
     CustomKeyStoreNotFound(String),
     /// <p>The request was rejected because an internal exception occurred. The request can be retried.</p>
     KMSInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCustomKeyStoreError {
@@ -4326,45 +4726,55 @@ impl UpdateCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "CloudHsmClusterNotActiveException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCustomKeyStoreError::CloudHsmClusterNotActive(err.msg),
+                        &res,
                     )
                 }
                 "CloudHsmClusterNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCustomKeyStoreError::CloudHsmClusterNotFound(err.msg),
+                        &res,
                     )
                 }
                 "CloudHsmClusterNotRelatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCustomKeyStoreError::CloudHsmClusterNotRelated(err.msg),
+                        &res,
                     )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
+                        &res,
                     )
                 }
                 "CustomKeyStoreNameInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCustomKeyStoreError::CustomKeyStoreNameInUse(err.msg),
+                        &res,
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(UpdateCustomKeyStoreError::CustomKeyStoreNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UpdateCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        UpdateCustomKeyStoreError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateCustomKeyStoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4390,12 +4800,14 @@ impl fmt::Display for UpdateCustomKeyStoreError {
             UpdateCustomKeyStoreError::CustomKeyStoreNameInUse(ref cause) => write!(f, "{}", cause),
             UpdateCustomKeyStoreError::CustomKeyStoreNotFound(ref cause) => write!(f, "{}", cause),
             UpdateCustomKeyStoreError::KMSInternal(ref cause) => write!(f, "{}", cause),
+            UpdateCustomKeyStoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateCustomKeyStoreError {}
 /// Errors returned by UpdateKeyDescription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateKeyDescriptionError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -4407,6 +4819,8 @@ pub enum UpdateKeyDescriptionError {
     KMSInvalidState(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateKeyDescriptionError {
@@ -4414,26 +4828,34 @@ impl UpdateKeyDescriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateKeyDescriptionError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        UpdateKeyDescriptionError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        UpdateKeyDescriptionError::KMSInternal(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateKeyDescriptionError::KMSInvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::NotFound(err.msg))
+                    return RusotoError::service(UpdateKeyDescriptionError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateKeyDescriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4448,12 +4870,14 @@ impl fmt::Display for UpdateKeyDescriptionError {
             UpdateKeyDescriptionError::KMSInternal(ref cause) => write!(f, "{}", cause),
             UpdateKeyDescriptionError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             UpdateKeyDescriptionError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateKeyDescriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateKeyDescriptionError {}
 /// Errors returned by Verify
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum VerifyError {
     /// <p>The system timed out while trying to fulfill the request. The request can be retried.</p>
     DependencyTimeout(String),
@@ -4473,6 +4897,8 @@ pub enum VerifyError {
     KeyUnavailable(String),
     /// <p>The request was rejected because the specified entity or resource could not be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl VerifyError {
@@ -4480,30 +4906,34 @@ impl VerifyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(VerifyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(VerifyError::DependencyTimeout(err.msg), &res)
+                }
+                "DisabledException" => {
+                    return RusotoError::service(VerifyError::Disabled(err.msg), &res)
                 }
-                "DisabledException" => return RusotoError::Service(VerifyError::Disabled(err.msg)),
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(VerifyError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(VerifyError::InvalidGrantToken(err.msg), &res)
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(VerifyError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(VerifyError::InvalidKeyUsage(err.msg), &res)
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(VerifyError::KMSInternal(err.msg))
+                    return RusotoError::service(VerifyError::KMSInternal(err.msg), &res)
                 }
                 "KMSInvalidSignatureException" => {
-                    return RusotoError::Service(VerifyError::KMSInvalidSignature(err.msg))
+                    return RusotoError::service(VerifyError::KMSInvalidSignature(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(VerifyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(VerifyError::KMSInvalidState(err.msg), &res)
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(VerifyError::KeyUnavailable(err.msg))
+                    return RusotoError::service(VerifyError::KeyUnavailable(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(VerifyError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(VerifyError::NotFound(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(VerifyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4522,6 +4952,7 @@ impl fmt::Display for VerifyError {
             VerifyError::KMSInvalidState(ref cause) => write!(f, "{}", cause),
             VerifyError::KeyUnavailable(ref cause) => write!(f, "{}", cause),
             VerifyError::NotFound(ref cause) => write!(f, "{}", cause),
+            VerifyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4816,6 +5247,7 @@ impl KmsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KmsClient {
         KmsClient {
             client: Client::shared(),
@@ -4841,563 +5273,1127 @@ impl KmsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> KmsClient {
         KmsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KmsClient> {
+        rusoto_core::ClientBuilder::new(KmsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KmsClient {
+        KmsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Kms for KmsClient {
-    /// <p>Cancels the deletion of a customer master key (CMK). When this operation succeeds, the key state of the CMK is <code>Disabled</code>. To enable the CMK, use <a>EnableKey</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling and canceling deletion of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn cancel_key_deletion(
+impl KmsClient {
+    /// Calls `cancel_key_deletion`, also returning the metadata of the response.
+    pub async fn cancel_key_deletion_with_metadata(
         &self,
         input: CancelKeyDeletionRequest,
-    ) -> Result<CancelKeyDeletionResponse, RusotoError<CancelKeyDeletionError>> {
+    ) -> Result<(CancelKeyDeletionResponse, ResponseMetadata), RusotoError<CancelKeyDeletionError>>
+    {
+        let request = self.build_cancel_key_deletion_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelKeyDeletionResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelKeyDeletionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_key_deletion` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_key_deletion(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelKeyDeletionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_key_deletion_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_key_deletion_request(&self, input: CancelKeyDeletionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CancelKeyDeletion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CancelKeyDeletion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `connect_custom_key_store`, also returning the metadata of the response.
+    pub async fn connect_custom_key_store_with_metadata(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> Result<
+        (ConnectCustomKeyStoreResponse, ResponseMetadata),
+        RusotoError<ConnectCustomKeyStoreError>,
+    > {
+        let request = self.build_connect_custom_key_store_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelKeyDeletionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConnectCustomKeyStoreResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelKeyDeletionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ConnectCustomKeyStoreError::from_response(response))
         }
     }
 
-    /// <p>Connects or reconnects a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> to its associated AWS CloudHSM cluster.</p> <p>The custom key store must be connected before you can create customer master keys (CMKs) in the key store or use the CMKs it contains. You can disconnect and reconnect a custom key store at any time.</p> <p>To connect a custom key store, its associated AWS CloudHSM cluster must have at least one active HSM. To get the number of active HSMs in a cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation. To add HSMs to the cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_CreateHsm.html">CreateHsm</a> operation. Also, the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user</a> (CU) must not be logged into the cluster. This prevents AWS KMS from using this account to log in.</p> <p>The connection process can take an extended amount of time to complete; up to 20 minutes. This operation starts the connection process, but it does not wait for it to complete. When it succeeds, this operation quickly returns an HTTP 200 response and a JSON object with no properties. However, this response does not indicate that the custom key store is connected. To get the connection state of the custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>During the connection process, AWS KMS finds the AWS CloudHSM cluster that is associated with the custom key store, creates the connection infrastructure, connects to the cluster, logs into the AWS CloudHSM client as the <code>kmsuser</code> CU, and rotates its password.</p> <p>The <code>ConnectCustomKeyStore</code> operation might fail for various reasons. To find the reason, use the <a>DescribeCustomKeyStores</a> operation and see the <code>ConnectionErrorCode</code> in the response. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>To fix the failure, use the <a>DisconnectCustomKeyStore</a> operation to disconnect the custom key store, correct the error, use the <a>UpdateCustomKeyStore</a> operation if necessary, and then use <code>ConnectCustomKeyStore</code> again.</p> <p>If you are having trouble connecting or disconnecting a custom key store, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn connect_custom_key_store(
+    /// Presigns the request `connect_custom_key_store` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_connect_custom_key_store(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ConnectCustomKeyStoreRequest,
-    ) -> Result<ConnectCustomKeyStoreResponse, RusotoError<ConnectCustomKeyStoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_connect_custom_key_store_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_connect_custom_key_store_request(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ConnectCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ConnectCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_alias`, also returning the metadata of the response.
+    pub async fn create_alias_with_metadata(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateAliasError>> {
+        let request = self.build_create_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConnectCustomKeyStoreResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ConnectCustomKeyStoreError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAliasError::from_response(response))
         }
     }
 
-    /// <p>Creates a display name for a customer managed customer master key (CMK). You can use an alias to identify a CMK in cryptographic operations, such as <a>Encrypt</a> and <a>GenerateDataKey</a>. You can change the CMK associated with the alias at any time.</p> <p>Aliases are easier to remember than key IDs. They can also help to simplify your applications. For example, if you use an alias in your code, you can change the CMK your code uses by associating a given alias with a different CMK. </p> <p>To run the same code in multiple AWS regions, use an alias in your code, such as <code>alias/ApplicationKey</code>. Then, in each AWS Region, create an <code>alias/ApplicationKey</code> alias that is associated with a CMK in that Region. When you run your code, it uses the <code>alias/ApplicationKey</code> CMK for that AWS Region without any Region-specific code.</p> <p>This operation does not return a response. To get the alias that you created, use the <a>ListAliases</a> operation.</p> <p>To use aliases successfully, be aware of the following information.</p> <ul> <li> <p>Each alias points to only one CMK at a time, although a single CMK can have multiple aliases. The alias and its associated CMK must be in the same AWS account and Region. </p> </li> <li> <p>You can associate an alias with any customer managed CMK in the same AWS account and Region. However, you do not have permission to associate an alias with an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMK</a> or an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-owned-cmk">AWS owned CMK</a>. </p> </li> <li> <p>To change the CMK associated with an alias, use the <a>UpdateAlias</a> operation. The current CMK and the new CMK must be the same type (both symmetric or both asymmetric) and they must have the same key usage (<code>ENCRYPT_DECRYPT</code> or <code>SIGN_VERIFY</code>). This restriction prevents cryptographic errors in code that uses aliases.</p> </li> <li> <p>The alias name must begin with <code>alias/</code> followed by a name, such as <code>alias/ExampleAlias</code>. It can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). The alias name cannot begin with <code>alias/aws/</code>. The <code>alias/aws/</code> prefix is reserved for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMKs</a>. </p> </li> <li> <p>The alias name must be unique within an AWS Region. However, you can use the same alias name in multiple Regions of the same AWS account. Each instance of the alias is associated with a CMK in its Region.</p> </li> <li> <p>After you create an alias, you cannot change its alias name. However, you can use the <a>DeleteAlias</a> operation to delete the alias and then create a new alias with the desired name.</p> </li> <li> <p>You can use an alias name or alias ARN to identify a CMK in AWS KMS cryptographic operations and in the <a>DescribeKey</a> operation. However, you cannot use alias names or alias ARNs in API operations that manage CMKs, such as <a>DisableKey</a> or <a>GetKeyPolicy</a>. For information about the valid CMK identifiers for each AWS KMS API operation, see the descriptions of the <code>KeyId</code> parameter in the API operation documentation.</p> </li> </ul> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases and alias ARNs of CMKs in each AWS account and Region, use the <a>ListAliases</a> operation.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn create_alias(
+    /// Presigns the request `create_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAliasRequest,
-    ) -> Result<(), RusotoError<CreateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_alias_request(&self, input: CreateAliasRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_custom_key_store`, also returning the metadata of the response.
+    pub async fn create_custom_key_store_with_metadata(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> Result<
+        (CreateCustomKeyStoreResponse, ResponseMetadata),
+        RusotoError<CreateCustomKeyStoreError>,
+    > {
+        let request = self.build_create_custom_key_store_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCustomKeyStoreResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAliasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCustomKeyStoreError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> that is associated with an <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/clusters.html">AWS CloudHSM cluster</a> that you own and manage.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>Before you create the custom key store, you must assemble the required elements, including an AWS CloudHSM cluster that fulfills the requirements for a custom key store. For details about the required elements, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">Assemble the Prerequisites</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>When the operation completes successfully, it returns the ID of the new custom key store. Before you can use your new custom key store, you need to use the <a>ConnectCustomKeyStore</a> operation to connect the new key store to its AWS CloudHSM cluster. Even if you are not going to use your custom key store immediately, you might want to connect it to verify that all settings are correct and then disconnect it until you are ready to use it.</p> <p>For help with failures, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn create_custom_key_store(
+    /// Presigns the request `create_custom_key_store` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_custom_key_store(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCustomKeyStoreRequest,
-    ) -> Result<CreateCustomKeyStoreResponse, RusotoError<CreateCustomKeyStoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_custom_key_store_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_custom_key_store_request(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CreateCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_grant`, also returning the metadata of the response.
+    pub async fn create_grant_with_metadata(
+        &self,
+        input: CreateGrantRequest,
+    ) -> Result<(CreateGrantResponse, ResponseMetadata), RusotoError<CreateGrantError>> {
+        let request = self.build_create_grant_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCustomKeyStoreResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateGrantResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCustomKeyStoreError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateGrantError::from_response(response))
         }
     }
 
-    /// <p>Adds a grant to a customer master key (CMK). The grant allows the grantee principal to use the CMK when the conditions specified in the grant are met. When setting permissions, grants are an alternative to key policies. </p> <p>To create a grant that allows a cryptographic operation only when the request includes a particular <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">encryption context</a>, use the <code>Constraints</code> parameter. For details, see <a>GrantConstraints</a>.</p> <p>You can create grants on symmetric and asymmetric CMKs. However, if the grant allows an operation that the CMK does not support, <code>CreateGrant</code> fails with a <code>ValidationException</code>. </p> <ul> <li> <p>Grants for symmetric CMKs cannot allow operations that are not supported for symmetric CMKs, including <a>Sign</a>, <a>Verify</a>, and <a>GetPublicKey</a>. (There are limited exceptions to this rule for legacy operations, but you should not create a grant for an operation that AWS KMS does not support.)</p> </li> <li> <p>Grants for asymmetric CMKs cannot allow operations that are not supported for asymmetric CMKs, including operations that <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GenerateDataKey">generate data keys</a> or <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GenerateDataKeyPair">data key pairs</a>, or operations related to <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic key rotation</a>, <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">imported key material</a>, or CMKs in <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key stores</a>.</p> </li> <li> <p>Grants for asymmetric CMKs with a <code>KeyUsage</code> of <code>ENCRYPT_DECRYPT</code> cannot allow the <a>Sign</a> or <a>Verify</a> operations. Grants for asymmetric CMKs with a <code>KeyUsage</code> of <code>SIGN_VERIFY</code> cannot allow the <a>Encrypt</a> or <a>Decrypt</a> operations.</p> </li> <li> <p>Grants for asymmetric CMKs cannot include an encryption context grant constraint. An encryption context is not supported on asymmetric CMKs.</p> </li> </ul> <p>For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter. For more information about grants, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/grants.html">Grants</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn create_grant(
+    /// Presigns the request `create_grant` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_grant(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGrantRequest,
-    ) -> Result<CreateGrantResponse, RusotoError<CreateGrantError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_grant_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_grant_request(&self, input: CreateGrantRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateGrant");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CreateGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_key`, also returning the metadata of the response.
+    pub async fn create_key_with_metadata(
+        &self,
+        input: CreateKeyRequest,
+    ) -> Result<(CreateKeyResponse, ResponseMetadata), RusotoError<CreateKeyError>> {
+        let request = self.build_create_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateGrantResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateKeyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGrantError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateKeyError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a unique customer managed <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master-keys">customer master key</a> (CMK) in your AWS account and Region. You cannot use this operation to create a CMK in a different AWS account.</p> <p>You can use the <code>CreateKey</code> operation to create symmetric or asymmetric CMKs.</p> <ul> <li> <p> <b>Symmetric CMKs</b> contain a 256-bit symmetric key that never leaves AWS KMS unencrypted. To use the CMK, you must call AWS KMS. You can use a symmetric CMK to encrypt and decrypt small amounts of data, but they are typically used to generate <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data keys</a> and <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-key-pairs">data keys pairs</a>. For details, see <a>GenerateDataKey</a> and <a>GenerateDataKeyPair</a>.</p> </li> <li> <p> <b>Asymmetric CMKs</b> can contain an RSA key pair or an Elliptic Curve (ECC) key pair. The private key in an asymmetric CMK never leaves AWS KMS unencrypted. However, you can use the <a>GetPublicKey</a> operation to download the public key so it can be used outside of AWS KMS. CMKs with RSA key pairs can be used to encrypt or decrypt data or sign and verify messages (but not both). CMKs with ECC key pairs can be used only to sign and verify messages.</p> </li> </ul> <p>For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To create different types of CMKs, use the following guidance:</p> <dl> <dt>Asymmetric CMKs</dt> <dd> <p>To create an asymmetric CMK, use the <code>CustomerMasterKeySpec</code> parameter to specify the type of key material in the CMK. Then, use the <code>KeyUsage</code> parameter to determine whether the CMK will be used to encrypt and decrypt or sign and verify. You can&#39;t change these properties after the CMK is created.</p> <p> </p> </dd> <dt>Symmetric CMKs</dt> <dd> <p>When creating a symmetric CMK, you don&#39;t need to specify the <code>CustomerMasterKeySpec</code> or <code>KeyUsage</code> parameters. The default value for <code>CustomerMasterKeySpec</code>, <code>SYMMETRIC<em>DEFAULT</code>, and the default value for <code>KeyUsage</code>, <code>ENCRYPT</em>DECRYPT</code>, are the only valid values for symmetric CMKs. </p> <p> </p> </dd> <dt>Imported Key Material</dt> <dd> <p>To import your own key material, begin by creating a symmetric CMK with no key material. To do this, use the <code>Origin</code> parameter of <code>CreateKey</code> with a value of <code>EXTERNAL</code>. Next, use <a>GetParametersForImport</a> operation to get a public key and import token, and use the public key to encrypt your key material. Then, use <a>ImportKeyMaterial</a> with your import token to import the key material. For step-by-step instructions, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>. You cannot import the key material into an asymmetric CMK.</p> <p> </p> </dd> <dt>Custom Key Stores</dt> <dd> <p>To create a symmetric CMK in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, use the <code>CustomKeyStoreId</code> parameter to specify the custom key store. You must also use the <code>Origin</code> parameter with a value of <code>AWS_CLOUDHSM</code>. The AWS CloudHSM cluster that is associated with the custom key store must have at least two active HSMs in different Availability Zones in the AWS Region. </p> <p>You cannot create an asymmetric CMK in a custom key store. For information about custom key stores in AWS KMS see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Using Custom Key Stores</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> </dd> </dl></p>
-    async fn create_key(
+    /// Presigns the request `create_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateKeyRequest,
-    ) -> Result<CreateKeyResponse, RusotoError<CreateKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_key_request(&self, input: CreateKeyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.CreateKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `decrypt`, also returning the metadata of the response.
+    pub async fn decrypt_with_metadata(
+        &self,
+        input: DecryptRequest,
+    ) -> Result<(DecryptResponse, ResponseMetadata), RusotoError<DecryptError>> {
+        let request = self.build_decrypt_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateKeyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DecryptResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DecryptError::from_response(response))
         }
     }
 
-    /// <p>Decrypts ciphertext that was encrypted by a AWS KMS customer master key (CMK) using any of the following operations:</p> <ul> <li> <p> <a>Encrypt</a> </p> </li> <li> <p> <a>GenerateDataKey</a> </p> </li> <li> <p> <a>GenerateDataKeyPair</a> </p> </li> <li> <p> <a>GenerateDataKeyWithoutPlaintext</a> </p> </li> <li> <p> <a>GenerateDataKeyPairWithoutPlaintext</a> </p> </li> </ul> <p>You can use this operation to decrypt ciphertext that was encrypted under a symmetric or asymmetric CMK. When the CMK is asymmetric, you must specify the CMK and the encryption algorithm that was used to encrypt the ciphertext. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The Decrypt operation also decrypts ciphertext that was encrypted outside of AWS KMS by the public key in an AWS KMS asymmetric CMK. However, it cannot decrypt ciphertext produced by other libraries, such as the <a href="https://docs.aws.amazon.com/encryption-sdk/latest/developer-guide/">AWS Encryption SDK</a> or <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingClientSideEncryption.html">Amazon S3 client-side encryption</a>. These libraries return a ciphertext format that is incompatible with AWS KMS.</p> <p>If the ciphertext was encrypted under a symmetric CMK, you do not need to specify the CMK or the encryption algorithm. AWS KMS can get this information from metadata that it adds to the symmetric ciphertext blob. However, if you prefer, you can specify the <code>KeyId</code> to ensure that a particular CMK is used to decrypt the ciphertext. If you specify a different CMK than the one used to encrypt the ciphertext, the <code>Decrypt</code> operation fails.</p> <p>Whenever possible, use key policies to give users permission to call the Decrypt operation on a particular CMK, instead of using IAM policies. Otherwise, you might create an IAM user policy that gives the user Decrypt permission on all CMKs. This user could decrypt ciphertext that was encrypted by CMKs in other accounts if the key policy for the cross-account CMK permits it. If you must use an IAM policy for <code>Decrypt</code> permissions, limit the user to particular CMKs or particular trusted accounts.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn decrypt(
+    /// Presigns the request `decrypt` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_decrypt(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DecryptRequest,
-    ) -> Result<DecryptResponse, RusotoError<DecryptError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_decrypt_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_decrypt_request(&self, input: DecryptRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Decrypt");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.Decrypt");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_alias`, also returning the metadata of the response.
+    pub async fn delete_alias_with_metadata(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAliasError>> {
+        let request = self.build_delete_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DecryptResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DecryptError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAliasError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified alias. You cannot perform this operation on an alias in a different AWS account. </p> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs, use the <a>ListAliases</a> operation. </p> <p>Each CMK can have multiple aliases. To change the alias of a CMK, use <a>DeleteAlias</a> to delete the current alias and <a>CreateAlias</a> to create a new alias. To associate an existing alias with a different customer master key (CMK), call <a>UpdateAlias</a>.</p>
-    async fn delete_alias(
+    /// Presigns the request `delete_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAliasRequest,
-    ) -> Result<(), RusotoError<DeleteAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_alias_request(&self, input: DeleteAliasRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DeleteAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DeleteAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_custom_key_store`, also returning the metadata of the response.
+    pub async fn delete_custom_key_store_with_metadata(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> Result<
+        (DeleteCustomKeyStoreResponse, ResponseMetadata),
+        RusotoError<DeleteCustomKeyStoreError>,
+    > {
+        let request = self.build_delete_custom_key_store_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteCustomKeyStoreResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAliasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCustomKeyStoreError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. This operation does not delete the AWS CloudHSM cluster that is associated with the custom key store, or affect any users or keys in the cluster.</p> <p>The custom key store that you delete cannot contain any AWS KMS <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">customer master keys (CMKs)</a>. Before deleting the key store, verify that you will never need to use any of the CMKs in the key store for any cryptographic operations. Then, use <a>ScheduleKeyDeletion</a> to delete the AWS KMS customer master keys (CMKs) from the key store. When the scheduled waiting period expires, the <code>ScheduleKeyDeletion</code> operation deletes the CMKs. Then it makes a best effort to delete the key material from the associated cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>After all CMKs are deleted from AWS KMS, use <a>DisconnectCustomKeyStore</a> to disconnect the key store from AWS KMS. Then, you can delete the custom key store.</p> <p>Instead of deleting the custom key store, consider using <a>DisconnectCustomKeyStore</a> to disconnect it from AWS KMS. While the key store is disconnected, you cannot create or use the CMKs in the key store. But, you do not need to delete CMKs and you can reconnect a disconnected custom key store at any time.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
-    async fn delete_custom_key_store(
+    /// Presigns the request `delete_custom_key_store` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_custom_key_store(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCustomKeyStoreRequest,
-    ) -> Result<DeleteCustomKeyStoreResponse, RusotoError<DeleteCustomKeyStoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_custom_key_store_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_custom_key_store_request(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DeleteCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DeleteCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_imported_key_material`, also returning the metadata of the response.
+    pub async fn delete_imported_key_material_with_metadata(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteImportedKeyMaterialError>> {
+        let request = self.build_delete_imported_key_material_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteCustomKeyStoreResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCustomKeyStoreError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteImportedKeyMaterialError::from_response(response))
         }
     }
 
-    /// <p>Deletes key material that you previously imported. This operation makes the specified customer master key (CMK) unusable. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>When the specified CMK is in the <code>PendingDeletion</code> state, this operation does not change the CMK's state. Otherwise, it changes the CMK's state to <code>PendingImport</code>.</p> <p>After you delete key material, you can use <a>ImportKeyMaterial</a> to reimport the same key material into the CMK.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn delete_imported_key_material(
+    /// Presigns the request `delete_imported_key_material` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_imported_key_material(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteImportedKeyMaterialRequest,
-    ) -> Result<(), RusotoError<DeleteImportedKeyMaterialError>> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_imported_key_material_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_imported_key_material_request(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DeleteImportedKeyMaterial");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DeleteImportedKeyMaterial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_custom_key_stores`, also returning the metadata of the response.
+    pub async fn describe_custom_key_stores_with_metadata(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> Result<
+        (DescribeCustomKeyStoresResponse, ResponseMetadata),
+        RusotoError<DescribeCustomKeyStoresError>,
+    > {
+        let request = self.build_describe_custom_key_stores_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCustomKeyStoresResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteImportedKeyMaterialError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCustomKeyStoresError::from_response(response))
         }
     }
 
-    /// <p>Gets information about <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key stores</a> in the account and region.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>By default, this operation returns information about all custom key stores in the account and region. To get only information about a particular custom key store, use either the <code>CustomKeyStoreName</code> or <code>CustomKeyStoreId</code> parameter (but not both).</p> <p>To determine whether the custom key store is connected to its AWS CloudHSM cluster, use the <code>ConnectionState</code> element in the response. If an attempt to connect the custom key store failed, the <code>ConnectionState</code> value is <code>FAILED</code> and the <code>ConnectionErrorCode</code> element in the response indicates the cause of the failure. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>Custom key stores have a <code>DISCONNECTED</code> connection state if the key store has never been connected or you use the <a>DisconnectCustomKeyStore</a> operation to disconnect it. If your custom key store state is <code>CONNECTED</code> but you are having trouble using it, make sure that its associated AWS CloudHSM cluster is active and contains the minimum number of HSMs required for the operation, if any.</p> <p> For help repairing your custom key store, see the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting Custom Key Stores</a> topic in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn describe_custom_key_stores(
+    /// Presigns the request `describe_custom_key_stores` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_custom_key_stores(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCustomKeyStoresRequest,
-    ) -> Result<DescribeCustomKeyStoresResponse, RusotoError<DescribeCustomKeyStoresError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_custom_key_stores_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_custom_key_stores_request(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DescribeCustomKeyStores");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DescribeCustomKeyStores");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_key`, also returning the metadata of the response.
+    pub async fn describe_key_with_metadata(
+        &self,
+        input: DescribeKeyRequest,
+    ) -> Result<(DescribeKeyResponse, ResponseMetadata), RusotoError<DescribeKeyError>> {
+        let request = self.build_describe_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCustomKeyStoresResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeKeyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCustomKeyStoresError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeKeyError::from_response(response))
         }
     }
 
-    /// <p>Provides detailed information about a customer master key (CMK). You can run <code>DescribeKey</code> on a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#customer-cmk">customer managed CMK</a> or an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMK</a>.</p> <p>This detailed information includes the key ARN, creation date (and deletion date, if applicable), the key state, and the origin and expiration date (if any) of the key material. For CMKs in custom key stores, it includes information about the custom key store, such as the key store ID and the AWS CloudHSM cluster ID. It includes fields, like <code>KeySpec</code>, that help you distinguish symmetric from asymmetric CMKs. It also provides information that is particularly important to asymmetric CMKs, such as the key usage (encryption or signing) and the encryption algorithms or signing algorithms that the CMK supports.</p> <p> <code>DescribeKey</code> does not return the following information:</p> <ul> <li> <p>Aliases associated with the CMK. To get this information, use <a>ListAliases</a>.</p> </li> <li> <p>Whether automatic key rotation is enabled on the CMK. To get this information, use <a>GetKeyRotationStatus</a>. Also, some key states prevent a CMK from being automatically rotated. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html#rotate-keys-how-it-works">How Automatic Key Rotation Works</a> in <i>AWS Key Management Service Developer Guide</i>.</p> </li> <li> <p>Tags on the CMK. To get this information, use <a>ListResourceTags</a>.</p> </li> <li> <p>Key policies and grants on the CMK. To get this information, use <a>GetKeyPolicy</a> and <a>ListGrants</a>.</p> </li> </ul> <p>If you call the <code>DescribeKey</code> operation on a <i>predefined AWS alias</i>, that is, an AWS alias with no key ID, AWS KMS creates an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">AWS managed CMK</a>. Then, it associates the alias with the new CMK, and returns the <code>KeyId</code> and <code>Arn</code> of the new CMK in the response.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
-    async fn describe_key(
+    /// Presigns the request `describe_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeKeyRequest,
-    ) -> Result<DescribeKeyResponse, RusotoError<DescribeKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_key_request(&self, input: DescribeKeyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DescribeKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DescribeKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_key`, also returning the metadata of the response.
+    pub async fn disable_key_with_metadata(
+        &self,
+        input: DisableKeyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisableKeyError>> {
+        let request = self.build_disable_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeKeyResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableKeyError::from_response(response))
         }
     }
 
-    /// <p>Sets the state of a customer master key (CMK) to disabled, thereby preventing its use for cryptographic operations. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about how key state affects the use of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects the Use of a Customer Master Key</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn disable_key(
+    /// Presigns the request `disable_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableKeyRequest,
-    ) -> Result<(), RusotoError<DisableKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_key_request(&self, input: DisableKeyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DisableKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DisableKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_key_rotation`, also returning the metadata of the response.
+    pub async fn disable_key_rotation_with_metadata(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisableKeyRotationError>> {
+        let request = self.build_disable_key_rotation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableKeyRotationError::from_response(response))
         }
     }
 
-    /// <p>Disables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified symmetric customer master key (CMK).</p> <p> You cannot enable automatic rotation of asymmetric CMKs, CMKs with imported key material, or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn disable_key_rotation(
+    /// Presigns the request `disable_key_rotation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_key_rotation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableKeyRotationRequest,
-    ) -> Result<(), RusotoError<DisableKeyRotationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_key_rotation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_key_rotation_request(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DisableKeyRotation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DisableKeyRotation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disconnect_custom_key_store`, also returning the metadata of the response.
+    pub async fn disconnect_custom_key_store_with_metadata(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> Result<
+        (DisconnectCustomKeyStoreResponse, ResponseMetadata),
+        RusotoError<DisconnectCustomKeyStoreError>,
+    > {
+        let request = self.build_disconnect_custom_key_store_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisconnectCustomKeyStoreResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableKeyRotationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisconnectCustomKeyStoreError::from_response(response))
         }
     }
 
-    /// <p>Disconnects the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> from its associated AWS CloudHSM cluster. While a custom key store is disconnected, you can manage the custom key store and its customer master keys (CMKs), but you cannot create or use CMKs in the custom key store. You can reconnect the custom key store at any time.</p> <note> <p>While a custom key store is disconnected, all attempts to create customer master keys (CMKs) in the custom key store or to use existing CMKs in cryptographic operations will fail. This action can prevent users from storing and accessing sensitive data.</p> </note> <p/> <p>To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation. To reconnect a custom key store, use the <a>ConnectCustomKeyStore</a> operation.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
-    async fn disconnect_custom_key_store(
+    /// Presigns the request `disconnect_custom_key_store` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disconnect_custom_key_store(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisconnectCustomKeyStoreRequest,
-    ) -> Result<DisconnectCustomKeyStoreResponse, RusotoError<DisconnectCustomKeyStoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disconnect_custom_key_store_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disconnect_custom_key_store_request(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DisconnectCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.DisconnectCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_key`, also returning the metadata of the response.
+    pub async fn enable_key_with_metadata(
+        &self,
+        input: EnableKeyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<EnableKeyError>> {
+        let request = self.build_enable_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisconnectCustomKeyStoreResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisconnectCustomKeyStoreError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableKeyError::from_response(response))
         }
     }
 
-    /// <p>Sets the key state of a customer master key (CMK) to enabled. This allows you to use the CMK for cryptographic operations. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn enable_key(&self, input: EnableKeyRequest) -> Result<(), RusotoError<EnableKeyError>> {
+    /// Presigns the request `enable_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_key(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: EnableKeyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_key_request(&self, input: EnableKeyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("EnableKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.EnableKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_key_rotation`, also returning the metadata of the response.
+    pub async fn enable_key_rotation_with_metadata(
+        &self,
+        input: EnableKeyRotationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<EnableKeyRotationError>> {
+        let request = self.build_enable_key_rotation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableKeyRotationError::from_response(response))
         }
     }
 
-    /// <p>Enables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified symmetric customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>You cannot enable automatic rotation of asymmetric CMKs, CMKs with imported key material, or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn enable_key_rotation(
+    /// Presigns the request `enable_key_rotation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_key_rotation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableKeyRotationRequest,
-    ) -> Result<(), RusotoError<EnableKeyRotationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_key_rotation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_key_rotation_request(&self, input: EnableKeyRotationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("EnableKeyRotation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.EnableKeyRotation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `encrypt`, also returning the metadata of the response.
+    pub async fn encrypt_with_metadata(
+        &self,
+        input: EncryptRequest,
+    ) -> Result<(EncryptResponse, ResponseMetadata), RusotoError<EncryptError>> {
+        let request = self.build_encrypt_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<EncryptResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableKeyRotationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EncryptError::from_response(response))
         }
     }
 
-    /// <p>Encrypts plaintext into ciphertext by using a customer master key (CMK). The <code>Encrypt</code> operation has two primary use cases:</p> <ul> <li> <p>You can encrypt small amounts of arbitrary data, such as a personal identifier or database password, or other sensitive information. </p> </li> <li> <p>You can use the <code>Encrypt</code> operation to move encrypted data from one AWS region to another. In the first region, generate a data key and use the plaintext key to encrypt the data. Then, in the new region, call the <code>Encrypt</code> method on same plaintext data key. Now, you can safely move the encrypted data and encrypted data key to the new region, and decrypt in the new region when necessary.</p> </li> </ul> <p>You don't need to use the <code>Encrypt</code> operation to encrypt a data key. The <a>GenerateDataKey</a> and <a>GenerateDataKeyPair</a> operations return a plaintext data key and an encrypted copy of that data key.</p> <p>When you encrypt data, you must specify a symmetric or asymmetric CMK to use in the encryption operation. The CMK must have a <code>KeyUsage</code> value of <code>ENCRYPT_DECRYPT.</code> To find the <code>KeyUsage</code> of a CMK, use the <a>DescribeKey</a> operation. </p> <p>If you use a symmetric CMK, you can use an encryption context to add additional security to your encryption operation. If you specify an <code>EncryptionContext</code> when encrypting data, you must specify the same encryption context (a case-sensitive exact match) when decrypting the data. Otherwise, the request to decrypt fails with an <code>InvalidCiphertextException</code>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>If you specify an asymmetric CMK, you must also specify the encryption algorithm. The algorithm must be compatible with the CMK type.</p> <important> <p>When you use an asymmetric CMK to encrypt or reencrypt data, be sure to record the CMK and encryption algorithm that you choose. You will be required to provide the same CMK and encryption algorithm when you decrypt the data. If the CMK and algorithm do not match the values used to encrypt the data, the decrypt operation fails.</p> <p>You are not required to supply the CMK ID and encryption algorithm when you decrypt with symmetric CMKs because AWS KMS stores this information in the ciphertext blob. AWS KMS cannot store metadata in ciphertext generated with asymmetric keys. The standard format for asymmetric key ciphertext does not include configurable fields.</p> </important> <p>The maximum size of the data that you can encrypt varies with the type of CMK and the encryption algorithm that you choose.</p> <ul> <li> <p>Symmetric CMKs</p> <ul> <li> <p> <code>SYMMETRIC_DEFAULT</code>: 4096 bytes</p> </li> </ul> </li> <li> <p> <code>RSA_2048</code> </p> <ul> <li> <p> <code>RSAES_OAEP_SHA_1</code>: 214 bytes</p> </li> <li> <p> <code>RSAES_OAEP_SHA_256</code>: 190 bytes</p> </li> </ul> </li> <li> <p> <code>RSA_3072</code> </p> <ul> <li> <p> <code>RSAES_OAEP_SHA_1</code>: 342 bytes</p> </li> <li> <p> <code>RSAES_OAEP_SHA_256</code>: 318 bytes</p> </li> </ul> </li> <li> <p> <code>RSA_4096</code> </p> <ul> <li> <p> <code>RSAES_OAEP_SHA_1</code>: 470 bytes</p> </li> <li> <p> <code>RSAES_OAEP_SHA_256</code>: 446 bytes</p> </li> </ul> </li> </ul> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
-    async fn encrypt(
+    /// Presigns the request `encrypt` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_encrypt(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EncryptRequest,
-    ) -> Result<EncryptResponse, RusotoError<EncryptError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_encrypt_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_encrypt_request(&self, input: EncryptRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Encrypt");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.Encrypt");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `generate_data_key`, also returning the metadata of the response.
+    pub async fn generate_data_key_with_metadata(
+        &self,
+        input: GenerateDataKeyRequest,
+    ) -> Result<(GenerateDataKeyResponse, ResponseMetadata), RusotoError<GenerateDataKeyError>>
+    {
+        let request = self.build_generate_data_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<EncryptResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GenerateDataKeyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EncryptError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GenerateDataKeyError::from_response(response))
         }
     }
 
-    /// <p><p>Generates a unique symmetric data key. This operation returns a plaintext copy of the data key and a copy that is encrypted under a customer master key (CMK) that you specify. You can use the plaintext key to encrypt your data outside of AWS KMS and store the encrypted data key with the encrypted data.</p> <p> <code>GenerateDataKey</code> returns a unique data key for each request. The bytes in the key are not related to the caller or CMK that is used to encrypt the data key.</p> <p>To generate a data key, specify the symmetric CMK that will be used to encrypt the data key. You cannot use an asymmetric CMK to generate data keys. To get the type of your CMK, use the <a>DescribeKey</a> operation.</p> <p>You must also specify the length of the data key. Use either the <code>KeySpec</code> or <code>NumberOfBytes</code> parameters (but not both). For 128-bit and 256-bit data keys, use the <code>KeySpec</code> parameter. </p> <p>If the operation succeeds, the plaintext copy of the data key is in the <code>Plaintext</code> field of the response, and the encrypted copy of the data key in the <code>CiphertextBlob</code> field.</p> <p>To get only an encrypted copy of the data key, use <a>GenerateDataKeyWithoutPlaintext</a>. To generate an asymmetric data key pair, use the <a>GenerateDataKeyPair</a> or <a>GenerateDataKeyPairWithoutPlaintext</a> operation. To get a cryptographically secure random byte string, use <a>GenerateRandom</a>.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an InvalidCiphertextException. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>We recommend that you use the following pattern to encrypt data locally in your application:</p> <ol> <li> <p>Use the <code>GenerateDataKey</code> operation to get a data encryption key.</p> </li> <li> <p>Use the plaintext data key (returned in the <code>Plaintext</code> field of the response) to encrypt data locally, then erase the plaintext data key from memory.</p> </li> <li> <p>Store the encrypted data key (returned in the <code>CiphertextBlob</code> field of the response) alongside the locally encrypted data.</p> </li> </ol> <p>To decrypt data locally:</p> <ol> <li> <p>Use the <a>Decrypt</a> operation to decrypt the encrypted data key. The operation returns a plaintext copy of the data key.</p> </li> <li> <p>Use the plaintext data key to decrypt data locally, then erase the plaintext data key from memory.</p> </li> </ol></p>
-    async fn generate_data_key(
+    /// Presigns the request `generate_data_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_data_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GenerateDataKeyRequest,
-    ) -> Result<GenerateDataKeyResponse, RusotoError<GenerateDataKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_data_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_data_key_request(&self, input: GenerateDataKeyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GenerateDataKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `generate_data_key_pair`, also returning the metadata of the response.
+    pub async fn generate_data_key_pair_with_metadata(
+        &self,
+        input: GenerateDataKeyPairRequest,
+    ) -> Result<
+        (GenerateDataKeyPairResponse, ResponseMetadata),
+        RusotoError<GenerateDataKeyPairError>,
+    > {
+        let request = self.build_generate_data_key_pair_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GenerateDataKeyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GenerateDataKeyPairResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateDataKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GenerateDataKeyPairError::from_response(response))
         }
     }
 
-    /// <p>Generates a unique asymmetric data key pair. The <code>GenerateDataKeyPair</code> operation returns a plaintext public key, a plaintext private key, and a copy of the private key that is encrypted under the symmetric CMK you specify. You can use the data key pair to perform asymmetric cryptography outside of AWS KMS.</p> <p> <code>GenerateDataKeyPair</code> returns a unique data key pair for each request. The bytes in the keys are not related to the caller or the CMK that is used to encrypt the private key.</p> <p>You can use the public key that <code>GenerateDataKeyPair</code> returns to encrypt data or verify a signature outside of AWS KMS. Then, store the encrypted private key with the data. When you are ready to decrypt data or sign a message, you can use the <a>Decrypt</a> operation to decrypt the encrypted private key.</p> <p>To generate a data key pair, you must specify a symmetric customer master key (CMK) to encrypt the private key in a data key pair. You cannot use an asymmetric CMK. To get the type of your CMK, use the <a>DescribeKey</a> operation.</p> <p>If you are using the data key pair to encrypt data, or for any operation where you don't immediately need a private key, consider using the <a>GenerateDataKeyPairWithoutPlaintext</a> operation. <code>GenerateDataKeyPairWithoutPlaintext</code> returns a plaintext public key and an encrypted private key, but omits the plaintext private key that you need only to decrypt ciphertext or sign a message. Later, when you need to decrypt the data or sign a message, use the <a>Decrypt</a> operation to decrypt the encrypted private key in the data key pair.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an InvalidCiphertextException. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn generate_data_key_pair(
+    /// Presigns the request `generate_data_key_pair` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_data_key_pair(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GenerateDataKeyPairRequest,
-    ) -> Result<GenerateDataKeyPairResponse, RusotoError<GenerateDataKeyPairError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_data_key_pair_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_data_key_pair_request(
+        &self,
+        input: GenerateDataKeyPairRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GenerateDataKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `generate_data_key_pair_without_plaintext`, also returning the metadata of the response.
+    pub async fn generate_data_key_pair_without_plaintext_with_metadata(
+        &self,
+        input: GenerateDataKeyPairWithoutPlaintextRequest,
+    ) -> Result<
+        (
+            GenerateDataKeyPairWithoutPlaintextResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GenerateDataKeyPairWithoutPlaintextError>,
+    > {
+        let request = self.build_generate_data_key_pair_without_plaintext_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GenerateDataKeyPairResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GenerateDataKeyPairWithoutPlaintextResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateDataKeyPairError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GenerateDataKeyPairWithoutPlaintextError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Generates a unique asymmetric data key pair. The <code>GenerateDataKeyPairWithoutPlaintext</code> operation returns a plaintext public key and a copy of the private key that is encrypted under the symmetric CMK you specify. Unlike <a>GenerateDataKeyPair</a>, this operation does not return a plaintext private key. </p> <p>To generate a data key pair, you must specify a symmetric customer master key (CMK) to encrypt the private key in the data key pair. You cannot use an asymmetric CMK. To get the type of your CMK, use the <code>KeySpec</code> field in the <a>DescribeKey</a> response.</p> <p>You can use the public key that <code>GenerateDataKeyPairWithoutPlaintext</code> returns to encrypt data or verify a signature outside of AWS KMS. Then, store the encrypted private key with the data. When you are ready to decrypt data or sign a message, you can use the <a>Decrypt</a> operation to decrypt the encrypted private key.</p> <p> <code>GenerateDataKeyPairWithoutPlaintext</code> returns a unique data key pair for each request. The bytes in the key are not related to the caller or CMK that is used to encrypt the private key.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an InvalidCiphertextException. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn generate_data_key_pair_without_plaintext(
+    /// Presigns the request `generate_data_key_pair_without_plaintext` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_data_key_pair_without_plaintext(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GenerateDataKeyPairWithoutPlaintextRequest,
-    ) -> Result<
-        GenerateDataKeyPairWithoutPlaintextResponse,
-        RusotoError<GenerateDataKeyPairWithoutPlaintextError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_data_key_pair_without_plaintext_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_data_key_pair_without_plaintext_request(
+        &self,
+        input: GenerateDataKeyPairWithoutPlaintextRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKeyPairWithoutPlaintext");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5407,33 +6403,60 @@ impl Kms for KmsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `generate_data_key_without_plaintext`, also returning the metadata of the response.
+    pub async fn generate_data_key_without_plaintext_with_metadata(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> Result<
+        (GenerateDataKeyWithoutPlaintextResponse, ResponseMetadata),
+        RusotoError<GenerateDataKeyWithoutPlaintextError>,
+    > {
+        let request = self.build_generate_data_key_without_plaintext_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GenerateDataKeyPairWithoutPlaintextResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GenerateDataKeyWithoutPlaintextResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateDataKeyPairWithoutPlaintextError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GenerateDataKeyWithoutPlaintextError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Generates a unique symmetric data key. This operation returns a data key that is encrypted under a customer master key (CMK) that you specify. To request an asymmetric data key pair, use the <a>GenerateDataKeyPair</a> or <a>GenerateDataKeyPairWithoutPlaintext</a> operations.</p> <p> <code>GenerateDataKeyWithoutPlaintext</code> is identical to the <a>GenerateDataKey</a> operation except that returns only the encrypted copy of the data key. This operation is useful for systems that need to encrypt data at some point, but not immediately. When you need to encrypt the data, you call the <a>Decrypt</a> operation on the encrypted copy of the key. </p> <p>It's also useful in distributed systems with different levels of trust. For example, you might store encrypted data in containers. One component of your system creates new containers and stores an encrypted data key with each container. Then, a different component puts the data into the containers. That component first decrypts the data key, uses the plaintext data key to encrypt data, puts the encrypted data into the container, and then destroys the plaintext data key. In this system, the component that creates the containers never sees the plaintext data key.</p> <p> <code>GenerateDataKeyWithoutPlaintext</code> returns a unique data key for each request. The bytes in the keys are not related to the caller or CMK that is used to encrypt the private key.</p> <p>To generate a data key, you must specify the symmetric customer master key (CMK) that is used to encrypt the data key. You cannot use an asymmetric CMK to generate a data key. To get the type of your CMK, use the <a>DescribeKey</a> operation.</p> <p>If the operation succeeds, you will find the encrypted copy of the data key in the <code>CiphertextBlob</code> field.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an InvalidCiphertextException. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn generate_data_key_without_plaintext(
+    /// Presigns the request `generate_data_key_without_plaintext` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_data_key_without_plaintext(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GenerateDataKeyWithoutPlaintextRequest,
-    ) -> Result<
-        GenerateDataKeyWithoutPlaintextResponse,
-        RusotoError<GenerateDataKeyWithoutPlaintextError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_data_key_without_plaintext_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_data_key_without_plaintext_request(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKeyWithoutPlaintext");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5443,672 +6466,1989 @@ impl Kms for KmsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `generate_random`, also returning the metadata of the response.
+    pub async fn generate_random_with_metadata(
+        &self,
+        input: GenerateRandomRequest,
+    ) -> Result<(GenerateRandomResponse, ResponseMetadata), RusotoError<GenerateRandomError>> {
+        let request = self.build_generate_random_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GenerateDataKeyWithoutPlaintextResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GenerateRandomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateDataKeyWithoutPlaintextError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GenerateRandomError::from_response(response))
         }
     }
 
-    /// <p>Returns a random byte string that is cryptographically secure.</p> <p>By default, the random byte string is generated in AWS KMS. To generate the byte string in the AWS CloudHSM cluster that is associated with a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, specify the custom key store ID.</p> <p>For more information about entropy and random number generation, see the <a href="https://d0.awsstatic.com/whitepapers/KMS-Cryptographic-Details.pdf">AWS Key Management Service Cryptographic Details</a> whitepaper.</p>
-    async fn generate_random(
+    /// Presigns the request `generate_random` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_random(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GenerateRandomRequest,
-    ) -> Result<GenerateRandomResponse, RusotoError<GenerateRandomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_random_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_random_request(&self, input: GenerateRandomRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateRandom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GenerateRandom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_key_policy`, also returning the metadata of the response.
+    pub async fn get_key_policy_with_metadata(
+        &self,
+        input: GetKeyPolicyRequest,
+    ) -> Result<(GetKeyPolicyResponse, ResponseMetadata), RusotoError<GetKeyPolicyError>> {
+        let request = self.build_get_key_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GenerateRandomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetKeyPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateRandomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetKeyPolicyError::from_response(response))
         }
     }
 
-    /// <p>Gets a key policy attached to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p>
-    async fn get_key_policy(
+    /// Presigns the request `get_key_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_key_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetKeyPolicyRequest,
-    ) -> Result<GetKeyPolicyResponse, RusotoError<GetKeyPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_key_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_key_policy_request(&self, input: GetKeyPolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetKeyPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GetKeyPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_key_rotation_status`, also returning the metadata of the response.
+    pub async fn get_key_rotation_status_with_metadata(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> Result<
+        (GetKeyRotationStatusResponse, ResponseMetadata),
+        RusotoError<GetKeyRotationStatusError>,
+    > {
+        let request = self.build_get_key_rotation_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetKeyPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetKeyRotationStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetKeyPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetKeyRotationStatusError::from_response(response))
         }
     }
 
-    /// <p>Gets a Boolean value that indicates whether <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> is enabled for the specified customer master key (CMK).</p> <p>You cannot enable automatic rotation of asymmetric CMKs, CMKs with imported key material, or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. The key rotation status for these CMKs is always <code>false</code>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <ul> <li> <p>Disabled: The key rotation status does not change when you disable a CMK. However, while the CMK is disabled, AWS KMS does not rotate the backing key.</p> </li> <li> <p>Pending deletion: While a CMK is pending deletion, its key rotation status is <code>false</code> and AWS KMS does not rotate the backing key. If you cancel the deletion, the original key rotation status is restored.</p> </li> </ul> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
-    async fn get_key_rotation_status(
+    /// Presigns the request `get_key_rotation_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_key_rotation_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetKeyRotationStatusRequest,
-    ) -> Result<GetKeyRotationStatusResponse, RusotoError<GetKeyRotationStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_key_rotation_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_key_rotation_status_request(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetKeyRotationStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GetKeyRotationStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_parameters_for_import`, also returning the metadata of the response.
+    pub async fn get_parameters_for_import_with_metadata(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> Result<
+        (GetParametersForImportResponse, ResponseMetadata),
+        RusotoError<GetParametersForImportError>,
+    > {
+        let request = self.build_get_parameters_for_import_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetKeyRotationStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetParametersForImportResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetKeyRotationStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetParametersForImportError::from_response(response))
         }
     }
 
-    /// <p>Returns the items you need to import key material into a symmetric, customer managed customer master key (CMK). For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>This operation returns a public key and an import token. Use the public key to encrypt the symmetric key material. Store the import token to send with a subsequent <a>ImportKeyMaterial</a> request.</p> <p>You must specify the key ID of the symmetric CMK into which you will import key material. This CMK's <code>Origin</code> must be <code>EXTERNAL</code>. You must also specify the wrapping algorithm and type of wrapping key (public key) that you will use to encrypt the key material. You cannot perform this operation on an asymmetric CMK or on any CMK in a different AWS account.</p> <p>To import key material, you must use the public key and import token from the same response. These items are valid for 24 hours. The expiration date and time appear in the <code>GetParametersForImport</code> response. You cannot use an expired token in an <a>ImportKeyMaterial</a> request. If your key and token expire, send another <code>GetParametersForImport</code> request.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn get_parameters_for_import(
+    /// Presigns the request `get_parameters_for_import` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_parameters_for_import(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetParametersForImportRequest,
-    ) -> Result<GetParametersForImportResponse, RusotoError<GetParametersForImportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_parameters_for_import_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_parameters_for_import_request(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetParametersForImport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GetParametersForImport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_public_key`, also returning the metadata of the response.
+    pub async fn get_public_key_with_metadata(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> Result<(GetPublicKeyResponse, ResponseMetadata), RusotoError<GetPublicKeyError>> {
+        let request = self.build_get_public_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetParametersForImportResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPublicKeyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetParametersForImportError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPublicKeyError::from_response(response))
         }
     }
 
-    /// <p>Returns the public key of an asymmetric CMK. Unlike the private key of a asymmetric CMK, which never leaves AWS KMS unencrypted, callers with <code>kms:GetPublicKey</code> permission can download the public key of an asymmetric CMK. You can share the public key to allow others to encrypt messages and verify signatures outside of AWS KMS. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>You do not need to download the public key. Instead, you can use the public key within AWS KMS by calling the <a>Encrypt</a>, <a>ReEncrypt</a>, or <a>Verify</a> operations with the identifier of an asymmetric CMK. When you use the public key within AWS KMS, you benefit from the authentication, authorization, and logging that are part of every AWS KMS operation. You also reduce of risk of encrypting data that cannot be decrypted. These features are not effective outside of AWS KMS. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/download-public-key.html#download-public-key-considerations">Special Considerations for Downloading Public Keys</a>.</p> <p>To help you use the public key safely outside of AWS KMS, <code>GetPublicKey</code> returns important information about the public key in the response, including:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-CustomerMasterKeySpec">CustomerMasterKeySpec</a>: The type of key material in the public key, such as <code>RSA_4096</code> or <code>ECC_NIST_P521</code>.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-KeyUsage">KeyUsage</a>: Whether the key is used for encryption or signing.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-EncryptionAlgorithms">EncryptionAlgorithms</a> or <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-SigningAlgorithms">SigningAlgorithms</a>: A list of the encryption algorithms or the signing algorithms for the key.</p> </li> </ul> <p>Although AWS KMS cannot enforce these restrictions on external operations, it is crucial that you use this information to prevent the public key from being used improperly. For example, you can prevent a public signing key from being used encrypt data, or prevent a public key from being used with an encryption algorithm that is not supported by AWS KMS. You can also avoid errors, such as using the wrong signing algorithm in a verification operation.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn get_public_key(
+    /// Presigns the request `get_public_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_public_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPublicKeyRequest,
-    ) -> Result<GetPublicKeyResponse, RusotoError<GetPublicKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_public_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_public_key_request(&self, input: GetPublicKeyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.GetPublicKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_key_material`, also returning the metadata of the response.
+    pub async fn import_key_material_with_metadata(
+        &self,
+        input: ImportKeyMaterialRequest,
+    ) -> Result<(ImportKeyMaterialResponse, ResponseMetadata), RusotoError<ImportKeyMaterialError>>
+    {
+        let request = self.build_import_key_material_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPublicKeyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportKeyMaterialResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPublicKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportKeyMaterialError::from_response(response))
         }
     }
 
-    /// <p>Imports key material into an existing symmetric AWS KMS customer master key (CMK) that was created without key material. After you successfully import key material into a CMK, you can <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html#reimport-key-material">reimport the same key material</a> into that CMK, but you cannot import different key material.</p> <p>You cannot perform this operation on an asymmetric CMK or on any CMK in a different AWS account. For more information about creating CMKs with no key material and then importing key material, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Before using this operation, call <a>GetParametersForImport</a>. Its response includes a public key and an import token. Use the public key to encrypt the key material. Then, submit the import token from the same <code>GetParametersForImport</code> response.</p> <p>When calling this operation, you must specify the following values:</p> <ul> <li> <p>The key ID or key ARN of a CMK with no key material. Its <code>Origin</code> must be <code>EXTERNAL</code>.</p> <p>To create a CMK with no key material, call <a>CreateKey</a> and set the value of its <code>Origin</code> parameter to <code>EXTERNAL</code>. To get the <code>Origin</code> of a CMK, call <a>DescribeKey</a>.)</p> </li> <li> <p>The encrypted key material. To get the public key to encrypt the key material, call <a>GetParametersForImport</a>.</p> </li> <li> <p>The import token that <a>GetParametersForImport</a> returned. You must use a public key and token from the same <code>GetParametersForImport</code> response.</p> </li> <li> <p>Whether the key material expires and if so, when. If you set an expiration date, AWS KMS deletes the key material from the CMK on the specified date, and the CMK becomes unusable. To use the CMK again, you must reimport the same key material. The only way to change an expiration date is by reimporting the same key material and specifying a new expiration date. </p> </li> </ul> <p>When this operation is successful, the key state of the CMK changes from <code>PendingImport</code> to <code>Enabled</code>, and you can use the CMK.</p> <p>If this operation fails, use the exception to help determine the problem. If the error is related to the key material, the import token, or wrapping key, use <a>GetParametersForImport</a> to get a new public key and import token for the CMK and repeat the import procedure. For help, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html#importing-keys-overview">How To Import Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn import_key_material(
+    /// Presigns the request `import_key_material` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_key_material(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportKeyMaterialRequest,
-    ) -> Result<ImportKeyMaterialResponse, RusotoError<ImportKeyMaterialError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_key_material_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_key_material_request(&self, input: ImportKeyMaterialRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ImportKeyMaterial");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ImportKeyMaterial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_aliases`, also returning the metadata of the response.
+    pub async fn list_aliases_with_metadata(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<(ListAliasesResponse, ResponseMetadata), RusotoError<ListAliasesError>> {
+        let request = self.build_list_aliases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportKeyMaterialResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAliasesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportKeyMaterialError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAliasesError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of aliases in the caller's AWS account and region. You cannot list aliases in other accounts. For more information about aliases, see <a>CreateAlias</a>.</p> <p>By default, the ListAliases command returns all aliases in the account and region. To get only the aliases that point to a particular customer master key (CMK), use the <code>KeyId</code> parameter.</p> <p>The <code>ListAliases</code> response can include aliases that you created and associated with your customer managed CMKs, and aliases that AWS created and associated with AWS managed CMKs in your account. You can recognize AWS aliases because their names have the format <code>aws/&lt;service-name&gt;</code>, such as <code>aws/dynamodb</code>.</p> <p>The response might also include aliases that have no <code>TargetKeyId</code> field. These are predefined aliases that AWS has created but has not yet associated with a CMK. Aliases that AWS creates in your account, including predefined aliases, do not count against your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/limits.html#aliases-limit">AWS KMS aliases quota</a>.</p>
-    async fn list_aliases(
+    /// Presigns the request `list_aliases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_aliases(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAliasesRequest,
-    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_aliases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_aliases_request(&self, input: ListAliasesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListAliases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListAliases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_grants`, also returning the metadata of the response.
+    pub async fn list_grants_with_metadata(
+        &self,
+        input: ListGrantsRequest,
+    ) -> Result<(ListGrantsResponse, ResponseMetadata), RusotoError<ListGrantsError>> {
+        let request = self.build_list_grants_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAliasesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGrantsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAliasesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListGrantsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of all grants for the specified customer master key (CMK).</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
-    async fn list_grants(
+    /// Presigns the request `list_grants` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_grants(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGrantsRequest,
-    ) -> Result<ListGrantsResponse, RusotoError<ListGrantsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_grants_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_grants_request(&self, input: ListGrantsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListGrants");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListGrants");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_key_policies`, also returning the metadata of the response.
+    pub async fn list_key_policies_with_metadata(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> Result<(ListKeyPoliciesResponse, ResponseMetadata), RusotoError<ListKeyPoliciesError>>
+    {
+        let request = self.build_list_key_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListGrantsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListKeyPoliciesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGrantsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListKeyPoliciesError::from_response(response))
         }
     }
 
-    /// <p>Gets the names of the key policies that are attached to a customer master key (CMK). This operation is designed to get policy names that you can use in a <a>GetKeyPolicy</a> operation. However, the only valid policy name is <code>default</code>. You cannot perform this operation on a CMK in a different AWS account.</p>
-    async fn list_key_policies(
+    /// Presigns the request `list_key_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_key_policies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListKeyPoliciesRequest,
-    ) -> Result<ListKeyPoliciesResponse, RusotoError<ListKeyPoliciesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_key_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_key_policies_request(&self, input: ListKeyPoliciesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListKeyPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListKeyPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_keys`, also returning the metadata of the response.
+    pub async fn list_keys_with_metadata(
+        &self,
+        input: ListKeysRequest,
+    ) -> Result<(ListKeysResponse, ResponseMetadata), RusotoError<ListKeysError>> {
+        let request = self.build_list_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListKeyPoliciesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListKeysResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListKeyPoliciesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListKeysError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of all customer master keys (CMKs) in the caller's AWS account and Region.</p>
-    async fn list_keys(
+    /// Presigns the request `list_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_keys(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListKeysRequest,
-    ) -> Result<ListKeysResponse, RusotoError<ListKeysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_keys_request(&self, input: ListKeysRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListKeys");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resource_tags`, also returning the metadata of the response.
+    pub async fn list_resource_tags_with_metadata(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> Result<(ListResourceTagsResponse, ResponseMetadata), RusotoError<ListResourceTagsError>>
+    {
+        let request = self.build_list_resource_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListKeysResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResourceTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListKeysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResourceTagsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all tags for the specified customer master key (CMK).</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p>
-    async fn list_resource_tags(
+    /// Presigns the request `list_resource_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resource_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourceTagsRequest,
-    ) -> Result<ListResourceTagsResponse, RusotoError<ListResourceTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resource_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resource_tags_request(&self, input: ListResourceTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListResourceTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListResourceTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_retirable_grants`, also returning the metadata of the response.
+    pub async fn list_retirable_grants_with_metadata(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> Result<(ListGrantsResponse, ResponseMetadata), RusotoError<ListRetirableGrantsError>> {
+        let request = self.build_list_retirable_grants_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourceTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGrantsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourceTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRetirableGrantsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all grants for which the grant's <code>RetiringPrincipal</code> matches the one specified.</p> <p>A typical use is to list all grants that you are able to retire. To retire a grant, use <a>RetireGrant</a>.</p>
-    async fn list_retirable_grants(
+    /// Presigns the request `list_retirable_grants` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_retirable_grants(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRetirableGrantsRequest,
-    ) -> Result<ListGrantsResponse, RusotoError<ListRetirableGrantsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_retirable_grants_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_retirable_grants_request(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListRetirableGrants");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ListRetirableGrants");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_key_policy`, also returning the metadata of the response.
+    pub async fn put_key_policy_with_metadata(
+        &self,
+        input: PutKeyPolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutKeyPolicyError>> {
+        let request = self.build_put_key_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListGrantsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRetirableGrantsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutKeyPolicyError::from_response(response))
         }
     }
 
-    /// <p>Attaches a key policy to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about key policies, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">Key Policies</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn put_key_policy(
+    /// Presigns the request `put_key_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_key_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutKeyPolicyRequest,
-    ) -> Result<(), RusotoError<PutKeyPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_key_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_key_policy_request(&self, input: PutKeyPolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("PutKeyPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.PutKeyPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `re_encrypt`, also returning the metadata of the response.
+    pub async fn re_encrypt_with_metadata(
+        &self,
+        input: ReEncryptRequest,
+    ) -> Result<(ReEncryptResponse, ResponseMetadata), RusotoError<ReEncryptError>> {
+        let request = self.build_re_encrypt_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ReEncryptResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutKeyPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ReEncryptError::from_response(response))
         }
     }
 
-    /// <p>Decrypts ciphertext and then reencrypts it entirely within AWS KMS. You can use this operation to change the customer master key (CMK) under which data is encrypted, such as when you <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html#rotate-keys-manually">manually rotate</a> a CMK or change the CMK that protects a ciphertext. You can also use it to reencrypt ciphertext under the same CMK, such as to change the encryption context of a ciphertext. </p> <p>The <code>ReEncrypt</code> operation can decrypt ciphertext that was encrypted by using an AWS KMS CMK in an AWS KMS operation, such as <a>Encrypt</a> or <a>GenerateDataKey</a>. It can also decrypt ciphertext that was encrypted by using the public key of an asymmetric CMK outside of AWS KMS. However, it cannot decrypt ciphertext produced by other libraries, such as the <a href="https://docs.aws.amazon.com/encryption-sdk/latest/developer-guide/">AWS Encryption SDK</a> or <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingClientSideEncryption.html">Amazon S3 client-side encryption</a>. These libraries return a ciphertext format that is incompatible with AWS KMS.</p> <p>When you use the <code>ReEncrypt</code> operation, you need to provide information for the decrypt operation and the subsequent encrypt operation.</p> <ul> <li> <p>If your ciphertext was encrypted under an asymmetric CMK, you must identify the <i>source CMK</i>, that is, the CMK that encrypted the ciphertext. You must also supply the encryption algorithm that was used. This information is required to decrypt the data.</p> </li> <li> <p>It is optional, but you can specify a source CMK even when the ciphertext was encrypted under a symmetric CMK. This ensures that the ciphertext is decrypted only by using a particular CMK. If the CMK that you specify cannot decrypt the ciphertext, the <code>ReEncrypt</code> operation fails.</p> </li> <li> <p>To reencrypt the data, you must specify the <i>destination CMK</i>, that is, the CMK that re-encrypts the data after it is decrypted. You can select a symmetric or asymmetric CMK. If the destination CMK is an asymmetric CMK, you must also provide the encryption algorithm. The algorithm that you choose must be compatible with the CMK.</p> <important> <p>When you use an asymmetric CMK to encrypt or reencrypt data, be sure to record the CMK and encryption algorithm that you choose. You will be required to provide the same CMK and encryption algorithm when you decrypt the data. If the CMK and algorithm do not match the values used to encrypt the data, the decrypt operation fails.</p> <p>You are not required to supply the CMK ID and encryption algorithm when you decrypt with symmetric CMKs because AWS KMS stores this information in the ciphertext blob. AWS KMS cannot store metadata in ciphertext generated with asymmetric keys. The standard format for asymmetric key ciphertext does not include configurable fields.</p> </important> </li> </ul> <p>Unlike other AWS KMS API operations, <code>ReEncrypt</code> callers must have two permissions:</p> <ul> <li> <p> <code>kms:EncryptFrom</code> permission on the source CMK</p> </li> <li> <p> <code>kms:EncryptTo</code> permission on the destination CMK</p> </li> </ul> <p>To permit reencryption from</p> <p> or to a CMK, include the <code>"kms:ReEncrypt*"</code> permission in your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">key policy</a>. This permission is automatically included in the key policy when you use the console to create a CMK. But you must include it manually when you create a CMK programmatically or when you use the <a>PutKeyPolicy</a> operation set a key policy.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn re_encrypt(
+    /// Presigns the request `re_encrypt` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_re_encrypt(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReEncryptRequest,
-    ) -> Result<ReEncryptResponse, RusotoError<ReEncryptError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_re_encrypt_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_re_encrypt_request(&self, input: ReEncryptRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ReEncrypt");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ReEncrypt");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `retire_grant`, also returning the metadata of the response.
+    pub async fn retire_grant_with_metadata(
+        &self,
+        input: RetireGrantRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RetireGrantError>> {
+        let request = self.build_retire_grant_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ReEncryptResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ReEncryptError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RetireGrantError::from_response(response))
         }
     }
 
-    /// <p>Retires a grant. To clean up, you can retire a grant when you're done using it. You should revoke a grant when you intend to actively deny operations that depend on it. The following are permitted to call this API:</p> <ul> <li> <p>The AWS account (root user) under which the grant was created</p> </li> <li> <p>The <code>RetiringPrincipal</code>, if present in the grant</p> </li> <li> <p>The <code>GranteePrincipal</code>, if <code>RetireGrant</code> is an operation specified in the grant</p> </li> </ul> <p>You must identify the grant to retire by its grant token or by a combination of the grant ID and the Amazon Resource Name (ARN) of the customer master key (CMK). A grant token is a unique variable-length base64-encoded string. A grant ID is a 64 character unique identifier of a grant. The <a>CreateGrant</a> operation returns both.</p>
-    async fn retire_grant(
+    /// Presigns the request `retire_grant` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_retire_grant(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RetireGrantRequest,
-    ) -> Result<(), RusotoError<RetireGrantError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_retire_grant_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_retire_grant_request(&self, input: RetireGrantRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("RetireGrant");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.RetireGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `revoke_grant`, also returning the metadata of the response.
+    pub async fn revoke_grant_with_metadata(
+        &self,
+        input: RevokeGrantRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RevokeGrantError>> {
+        let request = self.build_revoke_grant_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RetireGrantError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RevokeGrantError::from_response(response))
         }
     }
 
-    /// <p>Revokes the specified grant for the specified customer master key (CMK). You can revoke a grant to actively deny operations that depend on it.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
-    async fn revoke_grant(
+    /// Presigns the request `revoke_grant` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_revoke_grant(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RevokeGrantRequest,
-    ) -> Result<(), RusotoError<RevokeGrantError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_revoke_grant_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_revoke_grant_request(&self, input: RevokeGrantRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("RevokeGrant");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.RevokeGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `schedule_key_deletion`, also returning the metadata of the response.
+    pub async fn schedule_key_deletion_with_metadata(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> Result<
+        (ScheduleKeyDeletionResponse, ResponseMetadata),
+        RusotoError<ScheduleKeyDeletionError>,
+    > {
+        let request = self.build_schedule_key_deletion_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ScheduleKeyDeletionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RevokeGrantError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ScheduleKeyDeletionError::from_response(response))
         }
     }
 
-    /// <p>Schedules the deletion of a customer master key (CMK). You may provide a waiting period, specified in days, before deletion occurs. If you do not provide a waiting period, the default period of 30 days is used. When this operation is successful, the key state of the CMK changes to <code>PendingDeletion</code>. Before the waiting period ends, you can use <a>CancelKeyDeletion</a> to cancel the deletion of the CMK. After the waiting period ends, AWS KMS deletes the CMK and all AWS KMS data associated with it, including all aliases that refer to it.</p> <important> <p>Deleting a CMK is a destructive and potentially dangerous operation. When a CMK is deleted, all data that was encrypted under the CMK is unrecoverable. To prevent the use of a CMK without deleting it, use <a>DisableKey</a>.</p> </important> <p>If you schedule deletion of a CMK from a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, when the waiting period expires, <code>ScheduleKeyDeletion</code> deletes the CMK from AWS KMS. Then AWS KMS makes a best effort to delete the key material from the associated AWS CloudHSM cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling a CMK for deletion, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn schedule_key_deletion(
+    /// Presigns the request `schedule_key_deletion` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_schedule_key_deletion(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ScheduleKeyDeletionRequest,
-    ) -> Result<ScheduleKeyDeletionResponse, RusotoError<ScheduleKeyDeletionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_schedule_key_deletion_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_schedule_key_deletion_request(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ScheduleKeyDeletion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.ScheduleKeyDeletion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `sign`, also returning the metadata of the response.
+    pub async fn sign_with_metadata(
+        &self,
+        input: SignRequest,
+    ) -> Result<(SignResponse, ResponseMetadata), RusotoError<SignError>> {
+        let request = self.build_sign_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ScheduleKeyDeletionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<SignResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ScheduleKeyDeletionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SignError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a href="https://en.wikipedia.org/wiki/Digital_signature">digital signature</a> for a message or message digest by using the private key in an asymmetric CMK. To verify the signature, use the <a>Verify</a> operation, or use the public key in the same asymmetric CMK outside of AWS KMS. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Digital signatures are generated and verified by using asymmetric key pair, such as an RSA or ECC pair that is represented by an asymmetric customer master key (CMK). The key owner (or an authorized user) uses their private key to sign a message. Anyone with the public key can verify that the message was signed with that particular private key and that the message hasn't changed since it was signed. </p> <p>To use the <code>Sign</code> operation, provide the following information:</p> <ul> <li> <p>Use the <code>KeyId</code> parameter to identify an asymmetric CMK with a <code>KeyUsage</code> value of <code>SIGN_VERIFY</code>. To get the <code>KeyUsage</code> value of a CMK, use the <a>DescribeKey</a> operation. The caller must have <code>kms:Sign</code> permission on the CMK.</p> </li> <li> <p>Use the <code>Message</code> parameter to specify the message or message digest to sign. You can submit messages of up to 4096 bytes. To sign a larger message, generate a hash digest of the message, and then provide the hash digest in the <code>Message</code> parameter. To indicate whether the message is a full message or a digest, use the <code>MessageType</code> parameter.</p> </li> <li> <p>Choose a signing algorithm that is compatible with the CMK. </p> </li> </ul> <important> <p>When signing a message, be sure to record the CMK and the signing algorithm. This information is required to verify the signature.</p> </important> <p>To verify the signature that this operation generates, use the <a>Verify</a> operation. Or use the <a>GetPublicKey</a> operation to download the public key and then use the public key to verify the signature outside of AWS KMS. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn sign(&self, input: SignRequest) -> Result<SignResponse, RusotoError<SignError>> {
+    /// Presigns the request `sign` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_sign(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SignRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_sign_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_sign_request(&self, input: SignRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Sign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.Sign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SignResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SignError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds or edits tags for a customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>Each tag consists of a tag key and a tag value. Tag keys and tag values are both required, but tag values can be empty (null) strings.</p> <p>You can only use a tag key once for each CMK. If you use the tag key again, AWS KMS replaces the current tag value with the specified value.</p> <p>For information about the rules that apply to tag keys and tag values, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>To remove a tag, specify the tag key. To change the tag value of an existing tag key, use <a>TagResource</a>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_alias`, also returning the metadata of the response.
+    pub async fn update_alias_with_metadata(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateAliasError>> {
+        let request = self.build_update_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAliasError::from_response(response))
         }
     }
 
-    /// <p>Associates an existing AWS KMS alias with a different customer master key (CMK). Each alias is associated with only one CMK at a time, although a CMK can have multiple aliases. The alias and the CMK must be in the same AWS account and region. You cannot perform this operation on an alias in a different AWS account. </p> <p>The current and new CMK must be the same type (both symmetric or both asymmetric), and they must have the same key usage (<code>ENCRYPT_DECRYPT</code> or <code>SIGN_VERIFY</code>). This restriction prevents errors in code that uses aliases. If you must assign an alias to a different type of CMK, use <a>DeleteAlias</a> to delete the old alias and <a>CreateAlias</a> to create a new alias.</p> <p>You cannot use <code>UpdateAlias</code> to change an alias name. To change an alias name, use <a>DeleteAlias</a> to delete the old alias and <a>CreateAlias</a> to create a new alias.</p> <p>Because an alias is not a property of a CMK, you can create, update, and delete the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs in the account, use the <a>ListAliases</a> operation. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn update_alias(
+    /// Presigns the request `update_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAliasRequest,
-    ) -> Result<(), RusotoError<UpdateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_alias_request(&self, input: UpdateAliasRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UpdateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.UpdateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_custom_key_store`, also returning the metadata of the response.
+    pub async fn update_custom_key_store_with_metadata(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> Result<
+        (UpdateCustomKeyStoreResponse, ResponseMetadata),
+        RusotoError<UpdateCustomKeyStoreError>,
+    > {
+        let request = self.build_update_custom_key_store_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateCustomKeyStoreResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAliasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateCustomKeyStoreError::from_response(response))
         }
     }
 
-    /// <p>Changes the properties of a custom key store. Use the <code>CustomKeyStoreId</code> parameter to identify the custom key store you want to edit. Use the remaining parameters to change the properties of the custom key store.</p> <p>You can only update a custom key store that is disconnected. To disconnect the custom key store, use <a>DisconnectCustomKeyStore</a>. To reconnect the custom key store after the update completes, use <a>ConnectCustomKeyStore</a>. To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>Use the parameters of <code>UpdateCustomKeyStore</code> to edit your keystore settings.</p> <ul> <li> <p>Use the <b>NewCustomKeyStoreName</b> parameter to change the friendly name of the custom key store to the value that you specify.</p> <p> </p> </li> <li> <p>Use the <b>KeyStorePassword</b> parameter tell AWS KMS the current password of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user (CU)</a> in the associated AWS CloudHSM cluster. You can use this parameter to <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-password">fix connection failures</a> that occur when AWS KMS cannot log into the associated cluster because the <code>kmsuser</code> password has changed. This value does not change the password in the AWS CloudHSM cluster.</p> <p> </p> </li> <li> <p>Use the <b>CloudHsmClusterId</b> parameter to associate the custom key store with a different, but related, AWS CloudHSM cluster. You can use this parameter to repair a custom key store if its AWS CloudHSM cluster becomes corrupted or is deleted, or when you need to create or restore a cluster from a backup. </p> </li> </ul> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
-    async fn update_custom_key_store(
+    /// Presigns the request `update_custom_key_store` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_custom_key_store(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCustomKeyStoreRequest,
-    ) -> Result<UpdateCustomKeyStoreResponse, RusotoError<UpdateCustomKeyStoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_custom_key_store_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_custom_key_store_request(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UpdateCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.UpdateCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_key_description`, also returning the metadata of the response.
+    pub async fn update_key_description_with_metadata(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateKeyDescriptionError>> {
+        let request = self.build_update_key_description_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateCustomKeyStoreResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCustomKeyStoreError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateKeyDescriptionError::from_response(response))
         }
     }
 
-    /// <p>Updates the description of a customer master key (CMK). To see the description of a CMK, use <a>DescribeKey</a>. </p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn update_key_description(
+    /// Presigns the request `update_key_description` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_key_description(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateKeyDescriptionRequest,
-    ) -> Result<(), RusotoError<UpdateKeyDescriptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_key_description_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_key_description_request(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UpdateKeyDescription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.UpdateKeyDescription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `verify`, also returning the metadata of the response.
+    pub async fn verify_with_metadata(
+        &self,
+        input: VerifyRequest,
+    ) -> Result<(VerifyResponse, ResponseMetadata), RusotoError<VerifyError>> {
+        let request = self.build_verify_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<VerifyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateKeyDescriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(VerifyError::from_response(response))
         }
     }
 
-    /// <p>Verifies a digital signature that was generated by the <a>Sign</a> operation. </p> <p/> <p>Verification confirms that an authorized user signed the message with the specified CMK and signing algorithm, and the message hasn't changed since it was signed. If the signature is verified, the value of the <code>SignatureValid</code> field in the response is <code>True</code>. If the signature verification fails, the <code>Verify</code> operation fails with an <code>KMSInvalidSignatureException</code> exception.</p> <p>A digital signature is generated by using the private key in an asymmetric CMK. The signature is verified by using the public key in the same asymmetric CMK. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To verify a digital signature, you can use the <code>Verify</code> operation. Specify the same asymmetric CMK, message, and signing algorithm that were used to produce the signature.</p> <p>You can also verify the digital signature by using the public key of the CMK outside of AWS KMS. Use the <a>GetPublicKey</a> operation to download the public key in the asymmetric CMK and then use the public key to verify the signature outside of AWS KMS. The advantage of using the <code>Verify</code> operation is that it is performed within AWS KMS. As a result, it's easy to call, the operation is performed within the FIPS boundary, it is logged in AWS CloudTrail, and you can use key policy and IAM policy to determine who is authorized to use the CMK to verify signatures.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
-    async fn verify(
+    /// Presigns the request `verify` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_verify(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: VerifyRequest,
-    ) -> Result<VerifyResponse, RusotoError<VerifyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_verify_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_verify_request(&self, input: VerifyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Verify");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TrentService.Verify");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VerifyResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(VerifyError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `DescribeCustomKeyStores` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker` while its `Truncated` is true.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_custom_key_stores_pages(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeCustomKeyStoresResponse, RusotoError<DescribeCustomKeyStoresError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input| async move { client.describe_custom_key_stores(input).await },
+            |input: &DescribeCustomKeyStoresRequest, output: &DescribeCustomKeyStoresResponse| {
+                if output.truncated != Some(true) {
+                    return None;
+                }
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeCustomKeyStoresRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CustomKeyStores` of every page of `DescribeCustomKeyStores` results for `input`, as `describe_custom_key_stores_pages` requests them.
+    pub fn describe_custom_key_stores_stream(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> impl futures::Stream<
+        Item = Result<CustomKeyStoresListEntry, RusotoError<DescribeCustomKeyStoresError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_custom_key_stores_pages(input), |page| {
+            page.custom_key_stores.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAliases` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker` while its `Truncated` is true.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_aliases_pages(
+        &self,
+        input: ListAliasesRequest,
+    ) -> impl futures::Stream<Item = Result<ListAliasesResponse, RusotoError<ListAliasesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input| async move { client.list_aliases(input).await },
+            |input: &ListAliasesRequest, output: &ListAliasesResponse| {
+                if output.truncated != Some(true) {
+                    return None;
+                }
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListAliasesRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Aliases` of every page of `ListAliases` results for `input`, as `list_aliases_pages` requests them.
+    pub fn list_aliases_stream(
+        &self,
+        input: ListAliasesRequest,
+    ) -> impl futures::Stream<Item = Result<AliasListEntry, RusotoError<ListAliasesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_aliases_pages(input), |page| {
+            page.aliases.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGrants` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker` while its `Truncated` is true.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_grants_pages(
+        &self,
+        input: ListGrantsRequest,
+    ) -> impl futures::Stream<Item = Result<ListGrantsResponse, RusotoError<ListGrantsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input| async move { client.list_grants(input).await },
+            |input: &ListGrantsRequest, output: &ListGrantsResponse| {
+                if output.truncated != Some(true) {
+                    return None;
+                }
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListGrantsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Grants` of every page of `ListGrants` results for `input`, as `list_grants_pages` requests them.
+    pub fn list_grants_stream(
+        &self,
+        input: ListGrantsRequest,
+    ) -> impl futures::Stream<Item = Result<GrantListEntry, RusotoError<ListGrantsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_grants_pages(input), |page| {
+            page.grants.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListKeyPolicies` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker` while its `Truncated` is true.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_key_policies_pages(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListKeyPoliciesResponse, RusotoError<ListKeyPoliciesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input| async move { client.list_key_policies(input).await },
+            |input: &ListKeyPoliciesRequest, output: &ListKeyPoliciesResponse| {
+                if output.truncated != Some(true) {
+                    return None;
+                }
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListKeyPoliciesRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PolicyNames` of every page of `ListKeyPolicies` results for `input`, as `list_key_policies_pages` requests them.
+    pub fn list_key_policies_stream(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListKeyPoliciesError>>> + Send {
+        rusoto_core::pagination::items(self.list_key_policies_pages(input), |page| {
+            page.policy_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListKeys` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker` while its `Truncated` is true.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_keys_pages(
+        &self,
+        input: ListKeysRequest,
+    ) -> impl futures::Stream<Item = Result<ListKeysResponse, RusotoError<ListKeysError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input| async move { client.list_keys(input).await },
+            |input: &ListKeysRequest, output: &ListKeysResponse| {
+                if output.truncated != Some(true) {
+                    return None;
+                }
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListKeysRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Keys` of every page of `ListKeys` results for `input`, as `list_keys_pages` requests them.
+    pub fn list_keys_stream(
+        &self,
+        input: ListKeysRequest,
+    ) -> impl futures::Stream<Item = Result<KeyListEntry, RusotoError<ListKeysError>>> + Send {
+        rusoto_core::pagination::items(self.list_keys_pages(input), |page| {
+            page.keys.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListResourceTags` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker` while its `Truncated` is true.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_resource_tags_pages(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListResourceTagsResponse, RusotoError<ListResourceTagsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input| async move { client.list_resource_tags(input).await },
+            |input: &ListResourceTagsRequest, output: &ListResourceTagsResponse| {
+                if output.truncated != Some(true) {
+                    return None;
+                }
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListResourceTagsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListResourceTags` results for `input`, as `list_resource_tags_pages` requests them.
+    pub fn list_resource_tags_stream(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListResourceTagsError>>> + Send {
+        rusoto_core::pagination::items(self.list_resource_tags_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRetirableGrants` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker` while its `Truncated` is true.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_retirable_grants_pages(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> impl futures::Stream<Item = Result<ListGrantsResponse, RusotoError<ListRetirableGrantsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: KmsClient, input| async move { client.list_retirable_grants(input).await },
+            |input: &ListRetirableGrantsRequest, output: &ListGrantsResponse| {
+                if output.truncated != Some(true) {
+                    return None;
+                }
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListRetirableGrantsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Grants` of every page of `ListRetirableGrants` results for `input`, as `list_retirable_grants_pages` requests them.
+    pub fn list_retirable_grants_stream(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> impl futures::Stream<Item = Result<GrantListEntry, RusotoError<ListRetirableGrantsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_retirable_grants_pages(input), |page| {
+            page.grants.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Kms for KmsClient {
+    /// <p>Cancels the deletion of a customer master key (CMK). When this operation succeeds, the key state of the CMK is <code>Disabled</code>. To enable the CMK, use <a>EnableKey</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling and canceling deletion of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn cancel_key_deletion(
+        &self,
+        input: CancelKeyDeletionRequest,
+    ) -> Result<CancelKeyDeletionResponse, RusotoError<CancelKeyDeletionError>> {
+        self.cancel_key_deletion_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Connects or reconnects a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> to its associated AWS CloudHSM cluster.</p> <p>The custom key store must be connected before you can create customer master keys (CMKs) in the key store or use the CMKs it contains. You can disconnect and reconnect a custom key store at any time.</p> <p>To connect a custom key store, its associated AWS CloudHSM cluster must have at least one active HSM. To get the number of active HSMs in a cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation. To add HSMs to the cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_CreateHsm.html">CreateHsm</a> operation. Also, the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user</a> (CU) must not be logged into the cluster. This prevents AWS KMS from using this account to log in.</p> <p>The connection process can take an extended amount of time to complete; up to 20 minutes. This operation starts the connection process, but it does not wait for it to complete. When it succeeds, this operation quickly returns an HTTP 200 response and a JSON object with no properties. However, this response does not indicate that the custom key store is connected. To get the connection state of the custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>During the connection process, AWS KMS finds the AWS CloudHSM cluster that is associated with the custom key store, creates the connection infrastructure, connects to the cluster, logs into the AWS CloudHSM client as the <code>kmsuser</code> CU, and rotates its password.</p> <p>The <code>ConnectCustomKeyStore</code> operation might fail for various reasons. To find the reason, use the <a>DescribeCustomKeyStores</a> operation and see the <code>ConnectionErrorCode</code> in the response. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>To fix the failure, use the <a>DisconnectCustomKeyStore</a> operation to disconnect the custom key store, correct the error, use the <a>UpdateCustomKeyStore</a> operation if necessary, and then use <code>ConnectCustomKeyStore</code> again.</p> <p>If you are having trouble connecting or disconnecting a custom key store, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn connect_custom_key_store(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> Result<ConnectCustomKeyStoreResponse, RusotoError<ConnectCustomKeyStoreError>> {
+        self.connect_custom_key_store_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a display name for a customer managed customer master key (CMK). You can use an alias to identify a CMK in cryptographic operations, such as <a>Encrypt</a> and <a>GenerateDataKey</a>. You can change the CMK associated with the alias at any time.</p> <p>Aliases are easier to remember than key IDs. They can also help to simplify your applications. For example, if you use an alias in your code, you can change the CMK your code uses by associating a given alias with a different CMK. </p> <p>To run the same code in multiple AWS regions, use an alias in your code, such as <code>alias/ApplicationKey</code>. Then, in each AWS Region, create an <code>alias/ApplicationKey</code> alias that is associated with a CMK in that Region. When you run your code, it uses the <code>alias/ApplicationKey</code> CMK for that AWS Region without any Region-specific code.</p> <p>This operation does not return a response. To get the alias that you created, use the <a>ListAliases</a> operation.</p> <p>To use aliases successfully, be aware of the following information.</p> <ul> <li> <p>Each alias points to only one CMK at a time, although a single CMK can have multiple aliases. The alias and its associated CMK must be in the same AWS account and Region. </p> </li> <li> <p>You can associate an alias with any customer managed CMK in the same AWS account and Region. However, you do not have permission to associate an alias with an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMK</a> or an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-owned-cmk">AWS owned CMK</a>. </p> </li> <li> <p>To change the CMK associated with an alias, use the <a>UpdateAlias</a> operation. The current CMK and the new CMK must be the same type (both symmetric or both asymmetric) and they must have the same key usage (<code>ENCRYPT_DECRYPT</code> or <code>SIGN_VERIFY</code>). This restriction prevents cryptographic errors in code that uses aliases.</p> </li> <li> <p>The alias name must begin with <code>alias/</code> followed by a name, such as <code>alias/ExampleAlias</code>. It can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). The alias name cannot begin with <code>alias/aws/</code>. The <code>alias/aws/</code> prefix is reserved for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMKs</a>. </p> </li> <li> <p>The alias name must be unique within an AWS Region. However, you can use the same alias name in multiple Regions of the same AWS account. Each instance of the alias is associated with a CMK in its Region.</p> </li> <li> <p>After you create an alias, you cannot change its alias name. However, you can use the <a>DeleteAlias</a> operation to delete the alias and then create a new alias with the desired name.</p> </li> <li> <p>You can use an alias name or alias ARN to identify a CMK in AWS KMS cryptographic operations and in the <a>DescribeKey</a> operation. However, you cannot use alias names or alias ARNs in API operations that manage CMKs, such as <a>DisableKey</a> or <a>GetKeyPolicy</a>. For information about the valid CMK identifiers for each AWS KMS API operation, see the descriptions of the <code>KeyId</code> parameter in the API operation documentation.</p> </li> </ul> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases and alias ARNs of CMKs in each AWS account and Region, use the <a>ListAliases</a> operation.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<(), RusotoError<CreateAliasError>> {
+        self.create_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> that is associated with an <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/clusters.html">AWS CloudHSM cluster</a> that you own and manage.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>Before you create the custom key store, you must assemble the required elements, including an AWS CloudHSM cluster that fulfills the requirements for a custom key store. For details about the required elements, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">Assemble the Prerequisites</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>When the operation completes successfully, it returns the ID of the new custom key store. Before you can use your new custom key store, you need to use the <a>ConnectCustomKeyStore</a> operation to connect the new key store to its AWS CloudHSM cluster. Even if you are not going to use your custom key store immediately, you might want to connect it to verify that all settings are correct and then disconnect it until you are ready to use it.</p> <p>For help with failures, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn create_custom_key_store(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> Result<CreateCustomKeyStoreResponse, RusotoError<CreateCustomKeyStoreError>> {
+        self.create_custom_key_store_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a grant to a customer master key (CMK). The grant allows the grantee principal to use the CMK when the conditions specified in the grant are met. When setting permissions, grants are an alternative to key policies. </p> <p>To create a grant that allows a cryptographic operation only when the request includes a particular <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">encryption context</a>, use the <code>Constraints</code> parameter. For details, see <a>GrantConstraints</a>.</p> <p>You can create grants on symmetric and asymmetric CMKs. However, if the grant allows an operation that the CMK does not support, <code>CreateGrant</code> fails with a <code>ValidationException</code>. </p> <ul> <li> <p>Grants for symmetric CMKs cannot allow operations that are not supported for symmetric CMKs, including <a>Sign</a>, <a>Verify</a>, and <a>GetPublicKey</a>. (There are limited exceptions to this rule for legacy operations, but you should not create a grant for an operation that AWS KMS does not support.)</p> </li> <li> <p>Grants for asymmetric CMKs cannot allow operations that are not supported for asymmetric CMKs, including operations that <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GenerateDataKey">generate data keys</a> or <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GenerateDataKeyPair">data key pairs</a>, or operations related to <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic key rotation</a>, <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">imported key material</a>, or CMKs in <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key stores</a>.</p> </li> <li> <p>Grants for asymmetric CMKs with a <code>KeyUsage</code> of <code>ENCRYPT_DECRYPT</code> cannot allow the <a>Sign</a> or <a>Verify</a> operations. Grants for asymmetric CMKs with a <code>KeyUsage</code> of <code>SIGN_VERIFY</code> cannot allow the <a>Encrypt</a> or <a>Decrypt</a> operations.</p> </li> <li> <p>Grants for asymmetric CMKs cannot include an encryption context grant constraint. An encryption context is not supported on asymmetric CMKs.</p> </li> </ul> <p>For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter. For more information about grants, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/grants.html">Grants</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn create_grant(
+        &self,
+        input: CreateGrantRequest,
+    ) -> Result<CreateGrantResponse, RusotoError<CreateGrantError>> {
+        self.create_grant_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a unique customer managed <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master-keys">customer master key</a> (CMK) in your AWS account and Region. You cannot use this operation to create a CMK in a different AWS account.</p> <p>You can use the <code>CreateKey</code> operation to create symmetric or asymmetric CMKs.</p> <ul> <li> <p> <b>Symmetric CMKs</b> contain a 256-bit symmetric key that never leaves AWS KMS unencrypted. To use the CMK, you must call AWS KMS. You can use a symmetric CMK to encrypt and decrypt small amounts of data, but they are typically used to generate <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data keys</a> and <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-key-pairs">data keys pairs</a>. For details, see <a>GenerateDataKey</a> and <a>GenerateDataKeyPair</a>.</p> </li> <li> <p> <b>Asymmetric CMKs</b> can contain an RSA key pair or an Elliptic Curve (ECC) key pair. The private key in an asymmetric CMK never leaves AWS KMS unencrypted. However, you can use the <a>GetPublicKey</a> operation to download the public key so it can be used outside of AWS KMS. CMKs with RSA key pairs can be used to encrypt or decrypt data or sign and verify messages (but not both). CMKs with ECC key pairs can be used only to sign and verify messages.</p> </li> </ul> <p>For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To create different types of CMKs, use the following guidance:</p> <dl> <dt>Asymmetric CMKs</dt> <dd> <p>To create an asymmetric CMK, use the <code>CustomerMasterKeySpec</code> parameter to specify the type of key material in the CMK. Then, use the <code>KeyUsage</code> parameter to determine whether the CMK will be used to encrypt and decrypt or sign and verify. You can&#39;t change these properties after the CMK is created.</p> <p> </p> </dd> <dt>Symmetric CMKs</dt> <dd> <p>When creating a symmetric CMK, you don&#39;t need to specify the <code>CustomerMasterKeySpec</code> or <code>KeyUsage</code> parameters. The default value for <code>CustomerMasterKeySpec</code>, <code>SYMMETRIC<em>DEFAULT</code>, and the default value for <code>KeyUsage</code>, <code>ENCRYPT</em>DECRYPT</code>, are the only valid values for symmetric CMKs. </p> <p> </p> </dd> <dt>Imported Key Material</dt> <dd> <p>To import your own key material, begin by creating a symmetric CMK with no key material. To do this, use the <code>Origin</code> parameter of <code>CreateKey</code> with a value of <code>EXTERNAL</code>. Next, use <a>GetParametersForImport</a> operation to get a public key and import token, and use the public key to encrypt your key material. Then, use <a>ImportKeyMaterial</a> with your import token to import the key material. For step-by-step instructions, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>. You cannot import the key material into an asymmetric CMK.</p> <p> </p> </dd> <dt>Custom Key Stores</dt> <dd> <p>To create a symmetric CMK in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, use the <code>CustomKeyStoreId</code> parameter to specify the custom key store. You must also use the <code>Origin</code> parameter with a value of <code>AWS_CLOUDHSM</code>. The AWS CloudHSM cluster that is associated with the custom key store must have at least two active HSMs in different Availability Zones in the AWS Region. </p> <p>You cannot create an asymmetric CMK in a custom key store. For information about custom key stores in AWS KMS see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Using Custom Key Stores</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> </dd> </dl></p>
+    async fn create_key(
+        &self,
+        input: CreateKeyRequest,
+    ) -> Result<CreateKeyResponse, RusotoError<CreateKeyError>> {
+        self.create_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Decrypts ciphertext that was encrypted by a AWS KMS customer master key (CMK) using any of the following operations:</p> <ul> <li> <p> <a>Encrypt</a> </p> </li> <li> <p> <a>GenerateDataKey</a> </p> </li> <li> <p> <a>GenerateDataKeyPair</a> </p> </li> <li> <p> <a>GenerateDataKeyWithoutPlaintext</a> </p> </li> <li> <p> <a>GenerateDataKeyPairWithoutPlaintext</a> </p> </li> </ul> <p>You can use this operation to decrypt ciphertext that was encrypted under a symmetric or asymmetric CMK. When the CMK is asymmetric, you must specify the CMK and the encryption algorithm that was used to encrypt the ciphertext. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The Decrypt operation also decrypts ciphertext that was encrypted outside of AWS KMS by the public key in an AWS KMS asymmetric CMK. However, it cannot decrypt ciphertext produced by other libraries, such as the <a href="https://docs.aws.amazon.com/encryption-sdk/latest/developer-guide/">AWS Encryption SDK</a> or <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingClientSideEncryption.html">Amazon S3 client-side encryption</a>. These libraries return a ciphertext format that is incompatible with AWS KMS.</p> <p>If the ciphertext was encrypted under a symmetric CMK, you do not need to specify the CMK or the encryption algorithm. AWS KMS can get this information from metadata that it adds to the symmetric ciphertext blob. However, if you prefer, you can specify the <code>KeyId</code> to ensure that a particular CMK is used to decrypt the ciphertext. If you specify a different CMK than the one used to encrypt the ciphertext, the <code>Decrypt</code> operation fails.</p> <p>Whenever possible, use key policies to give users permission to call the Decrypt operation on a particular CMK, instead of using IAM policies. Otherwise, you might create an IAM user policy that gives the user Decrypt permission on all CMKs. This user could decrypt ciphertext that was encrypted by CMKs in other accounts if the key policy for the cross-account CMK permits it. If you must use an IAM policy for <code>Decrypt</code> permissions, limit the user to particular CMKs or particular trusted accounts.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn decrypt(
+        &self,
+        input: DecryptRequest,
+    ) -> Result<DecryptResponse, RusotoError<DecryptError>> {
+        self.decrypt_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified alias. You cannot perform this operation on an alias in a different AWS account. </p> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs, use the <a>ListAliases</a> operation. </p> <p>Each CMK can have multiple aliases. To change the alias of a CMK, use <a>DeleteAlias</a> to delete the current alias and <a>CreateAlias</a> to create a new alias. To associate an existing alias with a different customer master key (CMK), call <a>UpdateAlias</a>.</p>
+    async fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<(), RusotoError<DeleteAliasError>> {
+        self.delete_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. This operation does not delete the AWS CloudHSM cluster that is associated with the custom key store, or affect any users or keys in the cluster.</p> <p>The custom key store that you delete cannot contain any AWS KMS <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">customer master keys (CMKs)</a>. Before deleting the key store, verify that you will never need to use any of the CMKs in the key store for any cryptographic operations. Then, use <a>ScheduleKeyDeletion</a> to delete the AWS KMS customer master keys (CMKs) from the key store. When the scheduled waiting period expires, the <code>ScheduleKeyDeletion</code> operation deletes the CMKs. Then it makes a best effort to delete the key material from the associated cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>After all CMKs are deleted from AWS KMS, use <a>DisconnectCustomKeyStore</a> to disconnect the key store from AWS KMS. Then, you can delete the custom key store.</p> <p>Instead of deleting the custom key store, consider using <a>DisconnectCustomKeyStore</a> to disconnect it from AWS KMS. While the key store is disconnected, you cannot create or use the CMKs in the key store. But, you do not need to delete CMKs and you can reconnect a disconnected custom key store at any time.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
+    async fn delete_custom_key_store(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> Result<DeleteCustomKeyStoreResponse, RusotoError<DeleteCustomKeyStoreError>> {
+        self.delete_custom_key_store_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes key material that you previously imported. This operation makes the specified customer master key (CMK) unusable. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>When the specified CMK is in the <code>PendingDeletion</code> state, this operation does not change the CMK's state. Otherwise, it changes the CMK's state to <code>PendingImport</code>.</p> <p>After you delete key material, you can use <a>ImportKeyMaterial</a> to reimport the same key material into the CMK.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn delete_imported_key_material(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> Result<(), RusotoError<DeleteImportedKeyMaterialError>> {
+        self.delete_imported_key_material_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key stores</a> in the account and region.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>By default, this operation returns information about all custom key stores in the account and region. To get only information about a particular custom key store, use either the <code>CustomKeyStoreName</code> or <code>CustomKeyStoreId</code> parameter (but not both).</p> <p>To determine whether the custom key store is connected to its AWS CloudHSM cluster, use the <code>ConnectionState</code> element in the response. If an attempt to connect the custom key store failed, the <code>ConnectionState</code> value is <code>FAILED</code> and the <code>ConnectionErrorCode</code> element in the response indicates the cause of the failure. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>Custom key stores have a <code>DISCONNECTED</code> connection state if the key store has never been connected or you use the <a>DisconnectCustomKeyStore</a> operation to disconnect it. If your custom key store state is <code>CONNECTED</code> but you are having trouble using it, make sure that its associated AWS CloudHSM cluster is active and contains the minimum number of HSMs required for the operation, if any.</p> <p> For help repairing your custom key store, see the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting Custom Key Stores</a> topic in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn describe_custom_key_stores(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> Result<DescribeCustomKeyStoresResponse, RusotoError<DescribeCustomKeyStoresError>> {
+        self.describe_custom_key_stores_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides detailed information about a customer master key (CMK). You can run <code>DescribeKey</code> on a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#customer-cmk">customer managed CMK</a> or an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMK</a>.</p> <p>This detailed information includes the key ARN, creation date (and deletion date, if applicable), the key state, and the origin and expiration date (if any) of the key material. For CMKs in custom key stores, it includes information about the custom key store, such as the key store ID and the AWS CloudHSM cluster ID. It includes fields, like <code>KeySpec</code>, that help you distinguish symmetric from asymmetric CMKs. It also provides information that is particularly important to asymmetric CMKs, such as the key usage (encryption or signing) and the encryption algorithms or signing algorithms that the CMK supports.</p> <p> <code>DescribeKey</code> does not return the following information:</p> <ul> <li> <p>Aliases associated with the CMK. To get this information, use <a>ListAliases</a>.</p> </li> <li> <p>Whether automatic key rotation is enabled on the CMK. To get this information, use <a>GetKeyRotationStatus</a>. Also, some key states prevent a CMK from being automatically rotated. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html#rotate-keys-how-it-works">How Automatic Key Rotation Works</a> in <i>AWS Key Management Service Developer Guide</i>.</p> </li> <li> <p>Tags on the CMK. To get this information, use <a>ListResourceTags</a>.</p> </li> <li> <p>Key policies and grants on the CMK. To get this information, use <a>GetKeyPolicy</a> and <a>ListGrants</a>.</p> </li> </ul> <p>If you call the <code>DescribeKey</code> operation on a <i>predefined AWS alias</i>, that is, an AWS alias with no key ID, AWS KMS creates an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">AWS managed CMK</a>. Then, it associates the alias with the new CMK, and returns the <code>KeyId</code> and <code>Arn</code> of the new CMK in the response.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
+    async fn describe_key(
+        &self,
+        input: DescribeKeyRequest,
+    ) -> Result<DescribeKeyResponse, RusotoError<DescribeKeyError>> {
+        self.describe_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the state of a customer master key (CMK) to disabled, thereby preventing its use for cryptographic operations. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about how key state affects the use of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects the Use of a Customer Master Key</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn disable_key(
+        &self,
+        input: DisableKeyRequest,
+    ) -> Result<(), RusotoError<DisableKeyError>> {
+        self.disable_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified symmetric customer master key (CMK).</p> <p> You cannot enable automatic rotation of asymmetric CMKs, CMKs with imported key material, or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn disable_key_rotation(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> Result<(), RusotoError<DisableKeyRotationError>> {
+        self.disable_key_rotation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disconnects the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> from its associated AWS CloudHSM cluster. While a custom key store is disconnected, you can manage the custom key store and its customer master keys (CMKs), but you cannot create or use CMKs in the custom key store. You can reconnect the custom key store at any time.</p> <note> <p>While a custom key store is disconnected, all attempts to create customer master keys (CMKs) in the custom key store or to use existing CMKs in cryptographic operations will fail. This action can prevent users from storing and accessing sensitive data.</p> </note> <p/> <p>To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation. To reconnect a custom key store, use the <a>ConnectCustomKeyStore</a> operation.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
+    async fn disconnect_custom_key_store(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> Result<DisconnectCustomKeyStoreResponse, RusotoError<DisconnectCustomKeyStoreError>> {
+        self.disconnect_custom_key_store_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the key state of a customer master key (CMK) to enabled. This allows you to use the CMK for cryptographic operations. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn enable_key(&self, input: EnableKeyRequest) -> Result<(), RusotoError<EnableKeyError>> {
+        self.enable_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified symmetric customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>You cannot enable automatic rotation of asymmetric CMKs, CMKs with imported key material, or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn enable_key_rotation(
+        &self,
+        input: EnableKeyRotationRequest,
+    ) -> Result<(), RusotoError<EnableKeyRotationError>> {
+        self.enable_key_rotation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Encrypts plaintext into ciphertext by using a customer master key (CMK). The <code>Encrypt</code> operation has two primary use cases:</p> <ul> <li> <p>You can encrypt small amounts of arbitrary data, such as a personal identifier or database password, or other sensitive information. </p> </li> <li> <p>You can use the <code>Encrypt</code> operation to move encrypted data from one AWS region to another. In the first region, generate a data key and use the plaintext key to encrypt the data. Then, in the new region, call the <code>Encrypt</code> method on same plaintext data key. Now, you can safely move the encrypted data and encrypted data key to the new region, and decrypt in the new region when necessary.</p> </li> </ul> <p>You don't need to use the <code>Encrypt</code> operation to encrypt a data key. The <a>GenerateDataKey</a> and <a>GenerateDataKeyPair</a> operations return a plaintext data key and an encrypted copy of that data key.</p> <p>When you encrypt data, you must specify a symmetric or asymmetric CMK to use in the encryption operation. The CMK must have a <code>KeyUsage</code> value of <code>ENCRYPT_DECRYPT.</code> To find the <code>KeyUsage</code> of a CMK, use the <a>DescribeKey</a> operation. </p> <p>If you use a symmetric CMK, you can use an encryption context to add additional security to your encryption operation. If you specify an <code>EncryptionContext</code> when encrypting data, you must specify the same encryption context (a case-sensitive exact match) when decrypting the data. Otherwise, the request to decrypt fails with an <code>InvalidCiphertextException</code>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>If you specify an asymmetric CMK, you must also specify the encryption algorithm. The algorithm must be compatible with the CMK type.</p> <important> <p>When you use an asymmetric CMK to encrypt or reencrypt data, be sure to record the CMK and encryption algorithm that you choose. You will be required to provide the same CMK and encryption algorithm when you decrypt the data. If the CMK and algorithm do not match the values used to encrypt the data, the decrypt operation fails.</p> <p>You are not required to supply the CMK ID and encryption algorithm when you decrypt with symmetric CMKs because AWS KMS stores this information in the ciphertext blob. AWS KMS cannot store metadata in ciphertext generated with asymmetric keys. The standard format for asymmetric key ciphertext does not include configurable fields.</p> </important> <p>The maximum size of the data that you can encrypt varies with the type of CMK and the encryption algorithm that you choose.</p> <ul> <li> <p>Symmetric CMKs</p> <ul> <li> <p> <code>SYMMETRIC_DEFAULT</code>: 4096 bytes</p> </li> </ul> </li> <li> <p> <code>RSA_2048</code> </p> <ul> <li> <p> <code>RSAES_OAEP_SHA_1</code>: 214 bytes</p> </li> <li> <p> <code>RSAES_OAEP_SHA_256</code>: 190 bytes</p> </li> </ul> </li> <li> <p> <code>RSA_3072</code> </p> <ul> <li> <p> <code>RSAES_OAEP_SHA_1</code>: 342 bytes</p> </li> <li> <p> <code>RSAES_OAEP_SHA_256</code>: 318 bytes</p> </li> </ul> </li> <li> <p> <code>RSA_4096</code> </p> <ul> <li> <p> <code>RSAES_OAEP_SHA_1</code>: 470 bytes</p> </li> <li> <p> <code>RSAES_OAEP_SHA_256</code>: 446 bytes</p> </li> </ul> </li> </ul> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
+    async fn encrypt(
+        &self,
+        input: EncryptRequest,
+    ) -> Result<EncryptResponse, RusotoError<EncryptError>> {
+        self.encrypt_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Generates a unique symmetric data key. This operation returns a plaintext copy of the data key and a copy that is encrypted under a customer master key (CMK) that you specify. You can use the plaintext key to encrypt your data outside of AWS KMS and store the encrypted data key with the encrypted data.</p> <p> <code>GenerateDataKey</code> returns a unique data key for each request. The bytes in the key are not related to the caller or CMK that is used to encrypt the data key.</p> <p>To generate a data key, specify the symmetric CMK that will be used to encrypt the data key. You cannot use an asymmetric CMK to generate data keys. To get the type of your CMK, use the <a>DescribeKey</a> operation.</p> <p>You must also specify the length of the data key. Use either the <code>KeySpec</code> or <code>NumberOfBytes</code> parameters (but not both). For 128-bit and 256-bit data keys, use the <code>KeySpec</code> parameter. </p> <p>If the operation succeeds, the plaintext copy of the data key is in the <code>Plaintext</code> field of the response, and the encrypted copy of the data key in the <code>CiphertextBlob</code> field.</p> <p>To get only an encrypted copy of the data key, use <a>GenerateDataKeyWithoutPlaintext</a>. To generate an asymmetric data key pair, use the <a>GenerateDataKeyPair</a> or <a>GenerateDataKeyPairWithoutPlaintext</a> operation. To get a cryptographically secure random byte string, use <a>GenerateRandom</a>.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an InvalidCiphertextException. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>We recommend that you use the following pattern to encrypt data locally in your application:</p> <ol> <li> <p>Use the <code>GenerateDataKey</code> operation to get a data encryption key.</p> </li> <li> <p>Use the plaintext data key (returned in the <code>Plaintext</code> field of the response) to encrypt data locally, then erase the plaintext data key from memory.</p> </li> <li> <p>Store the encrypted data key (returned in the <code>CiphertextBlob</code> field of the response) alongside the locally encrypted data.</p> </li> </ol> <p>To decrypt data locally:</p> <ol> <li> <p>Use the <a>Decrypt</a> operation to decrypt the encrypted data key. The operation returns a plaintext copy of the data key.</p> </li> <li> <p>Use the plaintext data key to decrypt data locally, then erase the plaintext data key from memory.</p> </li> </ol></p>
+    async fn generate_data_key(
+        &self,
+        input: GenerateDataKeyRequest,
+    ) -> Result<GenerateDataKeyResponse, RusotoError<GenerateDataKeyError>> {
+        self.generate_data_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a unique asymmetric data key pair. The <code>GenerateDataKeyPair</code> operation returns a plaintext public key, a plaintext private key, and a copy of the private key that is encrypted under the symmetric CMK you specify. You can use the data key pair to perform asymmetric cryptography outside of AWS KMS.</p> <p> <code>GenerateDataKeyPair</code> returns a unique data key pair for each request. The bytes in the keys are not related to the caller or the CMK that is used to encrypt the private key.</p> <p>You can use the public key that <code>GenerateDataKeyPair</code> returns to encrypt data or verify a signature outside of AWS KMS. Then, store the encrypted private key with the data. When you are ready to decrypt data or sign a message, you can use the <a>Decrypt</a> operation to decrypt the encrypted private key.</p> <p>To generate a data key pair, you must specify a symmetric customer master key (CMK) to encrypt the private key in a data key pair. You cannot use an asymmetric CMK. To get the type of your CMK, use the <a>DescribeKey</a> operation.</p> <p>If you are using the data key pair to encrypt data, or for any operation where you don't immediately need a private key, consider using the <a>GenerateDataKeyPairWithoutPlaintext</a> operation. <code>GenerateDataKeyPairWithoutPlaintext</code> returns a plaintext public key and an encrypted private key, but omits the plaintext private key that you need only to decrypt ciphertext or sign a message. Later, when you need to decrypt the data or sign a message, use the <a>Decrypt</a> operation to decrypt the encrypted private key in the data key pair.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an InvalidCiphertextException. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn generate_data_key_pair(
+        &self,
+        input: GenerateDataKeyPairRequest,
+    ) -> Result<GenerateDataKeyPairResponse, RusotoError<GenerateDataKeyPairError>> {
+        self.generate_data_key_pair_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a unique asymmetric data key pair. The <code>GenerateDataKeyPairWithoutPlaintext</code> operation returns a plaintext public key and a copy of the private key that is encrypted under the symmetric CMK you specify. Unlike <a>GenerateDataKeyPair</a>, this operation does not return a plaintext private key. </p> <p>To generate a data key pair, you must specify a symmetric customer master key (CMK) to encrypt the private key in the data key pair. You cannot use an asymmetric CMK. To get the type of your CMK, use the <code>KeySpec</code> field in the <a>DescribeKey</a> response.</p> <p>You can use the public key that <code>GenerateDataKeyPairWithoutPlaintext</code> returns to encrypt data or verify a signature outside of AWS KMS. Then, store the encrypted private key with the data. When you are ready to decrypt data or sign a message, you can use the <a>Decrypt</a> operation to decrypt the encrypted private key.</p> <p> <code>GenerateDataKeyPairWithoutPlaintext</code> returns a unique data key pair for each request. The bytes in the key are not related to the caller or CMK that is used to encrypt the private key.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an InvalidCiphertextException. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn generate_data_key_pair_without_plaintext(
+        &self,
+        input: GenerateDataKeyPairWithoutPlaintextRequest,
+    ) -> Result<
+        GenerateDataKeyPairWithoutPlaintextResponse,
+        RusotoError<GenerateDataKeyPairWithoutPlaintextError>,
+    > {
+        self.generate_data_key_pair_without_plaintext_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a unique symmetric data key. This operation returns a data key that is encrypted under a customer master key (CMK) that you specify. To request an asymmetric data key pair, use the <a>GenerateDataKeyPair</a> or <a>GenerateDataKeyPairWithoutPlaintext</a> operations.</p> <p> <code>GenerateDataKeyWithoutPlaintext</code> is identical to the <a>GenerateDataKey</a> operation except that returns only the encrypted copy of the data key. This operation is useful for systems that need to encrypt data at some point, but not immediately. When you need to encrypt the data, you call the <a>Decrypt</a> operation on the encrypted copy of the key. </p> <p>It's also useful in distributed systems with different levels of trust. For example, you might store encrypted data in containers. One component of your system creates new containers and stores an encrypted data key with each container. Then, a different component puts the data into the containers. That component first decrypts the data key, uses the plaintext data key to encrypt data, puts the encrypted data into the container, and then destroys the plaintext data key. In this system, the component that creates the containers never sees the plaintext data key.</p> <p> <code>GenerateDataKeyWithoutPlaintext</code> returns a unique data key for each request. The bytes in the keys are not related to the caller or CMK that is used to encrypt the private key.</p> <p>To generate a data key, you must specify the symmetric customer master key (CMK) that is used to encrypt the data key. You cannot use an asymmetric CMK to generate a data key. To get the type of your CMK, use the <a>DescribeKey</a> operation.</p> <p>If the operation succeeds, you will find the encrypted copy of the data key in the <code>CiphertextBlob</code> field.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an InvalidCiphertextException. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn generate_data_key_without_plaintext(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> Result<
+        GenerateDataKeyWithoutPlaintextResponse,
+        RusotoError<GenerateDataKeyWithoutPlaintextError>,
+    > {
+        self.generate_data_key_without_plaintext_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a random byte string that is cryptographically secure.</p> <p>By default, the random byte string is generated in AWS KMS. To generate the byte string in the AWS CloudHSM cluster that is associated with a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, specify the custom key store ID.</p> <p>For more information about entropy and random number generation, see the <a href="https://d0.awsstatic.com/whitepapers/KMS-Cryptographic-Details.pdf">AWS Key Management Service Cryptographic Details</a> whitepaper.</p>
+    async fn generate_random(
+        &self,
+        input: GenerateRandomRequest,
+    ) -> Result<GenerateRandomResponse, RusotoError<GenerateRandomError>> {
+        self.generate_random_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a key policy attached to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p>
+    async fn get_key_policy(
+        &self,
+        input: GetKeyPolicyRequest,
+    ) -> Result<GetKeyPolicyResponse, RusotoError<GetKeyPolicyError>> {
+        self.get_key_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a Boolean value that indicates whether <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> is enabled for the specified customer master key (CMK).</p> <p>You cannot enable automatic rotation of asymmetric CMKs, CMKs with imported key material, or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. The key rotation status for these CMKs is always <code>false</code>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <ul> <li> <p>Disabled: The key rotation status does not change when you disable a CMK. However, while the CMK is disabled, AWS KMS does not rotate the backing key.</p> </li> <li> <p>Pending deletion: While a CMK is pending deletion, its key rotation status is <code>false</code> and AWS KMS does not rotate the backing key. If you cancel the deletion, the original key rotation status is restored.</p> </li> </ul> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
+    async fn get_key_rotation_status(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> Result<GetKeyRotationStatusResponse, RusotoError<GetKeyRotationStatusError>> {
+        self.get_key_rotation_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the items you need to import key material into a symmetric, customer managed customer master key (CMK). For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>This operation returns a public key and an import token. Use the public key to encrypt the symmetric key material. Store the import token to send with a subsequent <a>ImportKeyMaterial</a> request.</p> <p>You must specify the key ID of the symmetric CMK into which you will import key material. This CMK's <code>Origin</code> must be <code>EXTERNAL</code>. You must also specify the wrapping algorithm and type of wrapping key (public key) that you will use to encrypt the key material. You cannot perform this operation on an asymmetric CMK or on any CMK in a different AWS account.</p> <p>To import key material, you must use the public key and import token from the same response. These items are valid for 24 hours. The expiration date and time appear in the <code>GetParametersForImport</code> response. You cannot use an expired token in an <a>ImportKeyMaterial</a> request. If your key and token expire, send another <code>GetParametersForImport</code> request.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn get_parameters_for_import(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> Result<GetParametersForImportResponse, RusotoError<GetParametersForImportError>> {
+        self.get_parameters_for_import_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the public key of an asymmetric CMK. Unlike the private key of a asymmetric CMK, which never leaves AWS KMS unencrypted, callers with <code>kms:GetPublicKey</code> permission can download the public key of an asymmetric CMK. You can share the public key to allow others to encrypt messages and verify signatures outside of AWS KMS. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>You do not need to download the public key. Instead, you can use the public key within AWS KMS by calling the <a>Encrypt</a>, <a>ReEncrypt</a>, or <a>Verify</a> operations with the identifier of an asymmetric CMK. When you use the public key within AWS KMS, you benefit from the authentication, authorization, and logging that are part of every AWS KMS operation. You also reduce of risk of encrypting data that cannot be decrypted. These features are not effective outside of AWS KMS. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/download-public-key.html#download-public-key-considerations">Special Considerations for Downloading Public Keys</a>.</p> <p>To help you use the public key safely outside of AWS KMS, <code>GetPublicKey</code> returns important information about the public key in the response, including:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-CustomerMasterKeySpec">CustomerMasterKeySpec</a>: The type of key material in the public key, such as <code>RSA_4096</code> or <code>ECC_NIST_P521</code>.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-KeyUsage">KeyUsage</a>: Whether the key is used for encryption or signing.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-EncryptionAlgorithms">EncryptionAlgorithms</a> or <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-SigningAlgorithms">SigningAlgorithms</a>: A list of the encryption algorithms or the signing algorithms for the key.</p> </li> </ul> <p>Although AWS KMS cannot enforce these restrictions on external operations, it is crucial that you use this information to prevent the public key from being used improperly. For example, you can prevent a public signing key from being used encrypt data, or prevent a public key from being used with an encryption algorithm that is not supported by AWS KMS. You can also avoid errors, such as using the wrong signing algorithm in a verification operation.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> Result<GetPublicKeyResponse, RusotoError<GetPublicKeyError>> {
+        self.get_public_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Imports key material into an existing symmetric AWS KMS customer master key (CMK) that was created without key material. After you successfully import key material into a CMK, you can <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html#reimport-key-material">reimport the same key material</a> into that CMK, but you cannot import different key material.</p> <p>You cannot perform this operation on an asymmetric CMK or on any CMK in a different AWS account. For more information about creating CMKs with no key material and then importing key material, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Before using this operation, call <a>GetParametersForImport</a>. Its response includes a public key and an import token. Use the public key to encrypt the key material. Then, submit the import token from the same <code>GetParametersForImport</code> response.</p> <p>When calling this operation, you must specify the following values:</p> <ul> <li> <p>The key ID or key ARN of a CMK with no key material. Its <code>Origin</code> must be <code>EXTERNAL</code>.</p> <p>To create a CMK with no key material, call <a>CreateKey</a> and set the value of its <code>Origin</code> parameter to <code>EXTERNAL</code>. To get the <code>Origin</code> of a CMK, call <a>DescribeKey</a>.)</p> </li> <li> <p>The encrypted key material. To get the public key to encrypt the key material, call <a>GetParametersForImport</a>.</p> </li> <li> <p>The import token that <a>GetParametersForImport</a> returned. You must use a public key and token from the same <code>GetParametersForImport</code> response.</p> </li> <li> <p>Whether the key material expires and if so, when. If you set an expiration date, AWS KMS deletes the key material from the CMK on the specified date, and the CMK becomes unusable. To use the CMK again, you must reimport the same key material. The only way to change an expiration date is by reimporting the same key material and specifying a new expiration date. </p> </li> </ul> <p>When this operation is successful, the key state of the CMK changes from <code>PendingImport</code> to <code>Enabled</code>, and you can use the CMK.</p> <p>If this operation fails, use the exception to help determine the problem. If the error is related to the key material, the import token, or wrapping key, use <a>GetParametersForImport</a> to get a new public key and import token for the CMK and repeat the import procedure. For help, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html#importing-keys-overview">How To Import Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn import_key_material(
+        &self,
+        input: ImportKeyMaterialRequest,
+    ) -> Result<ImportKeyMaterialResponse, RusotoError<ImportKeyMaterialError>> {
+        self.import_key_material_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of aliases in the caller's AWS account and region. You cannot list aliases in other accounts. For more information about aliases, see <a>CreateAlias</a>.</p> <p>By default, the ListAliases command returns all aliases in the account and region. To get only the aliases that point to a particular customer master key (CMK), use the <code>KeyId</code> parameter.</p> <p>The <code>ListAliases</code> response can include aliases that you created and associated with your customer managed CMKs, and aliases that AWS created and associated with AWS managed CMKs in your account. You can recognize AWS aliases because their names have the format <code>aws/&lt;service-name&gt;</code>, such as <code>aws/dynamodb</code>.</p> <p>The response might also include aliases that have no <code>TargetKeyId</code> field. These are predefined aliases that AWS has created but has not yet associated with a CMK. Aliases that AWS creates in your account, including predefined aliases, do not count against your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/limits.html#aliases-limit">AWS KMS aliases quota</a>.</p>
+    async fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+        self.list_aliases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of all grants for the specified customer master key (CMK).</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
+    async fn list_grants(
+        &self,
+        input: ListGrantsRequest,
+    ) -> Result<ListGrantsResponse, RusotoError<ListGrantsError>> {
+        self.list_grants_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the names of the key policies that are attached to a customer master key (CMK). This operation is designed to get policy names that you can use in a <a>GetKeyPolicy</a> operation. However, the only valid policy name is <code>default</code>. You cannot perform this operation on a CMK in a different AWS account.</p>
+    async fn list_key_policies(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> Result<ListKeyPoliciesResponse, RusotoError<ListKeyPoliciesError>> {
+        self.list_key_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of all customer master keys (CMKs) in the caller's AWS account and Region.</p>
+    async fn list_keys(
+        &self,
+        input: ListKeysRequest,
+    ) -> Result<ListKeysResponse, RusotoError<ListKeysError>> {
+        self.list_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all tags for the specified customer master key (CMK).</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p>
+    async fn list_resource_tags(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> Result<ListResourceTagsResponse, RusotoError<ListResourceTagsError>> {
+        self.list_resource_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all grants for which the grant's <code>RetiringPrincipal</code> matches the one specified.</p> <p>A typical use is to list all grants that you are able to retire. To retire a grant, use <a>RetireGrant</a>.</p>
+    async fn list_retirable_grants(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> Result<ListGrantsResponse, RusotoError<ListRetirableGrantsError>> {
+        self.list_retirable_grants_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches a key policy to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about key policies, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">Key Policies</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn put_key_policy(
+        &self,
+        input: PutKeyPolicyRequest,
+    ) -> Result<(), RusotoError<PutKeyPolicyError>> {
+        self.put_key_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Decrypts ciphertext and then reencrypts it entirely within AWS KMS. You can use this operation to change the customer master key (CMK) under which data is encrypted, such as when you <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html#rotate-keys-manually">manually rotate</a> a CMK or change the CMK that protects a ciphertext. You can also use it to reencrypt ciphertext under the same CMK, such as to change the encryption context of a ciphertext. </p> <p>The <code>ReEncrypt</code> operation can decrypt ciphertext that was encrypted by using an AWS KMS CMK in an AWS KMS operation, such as <a>Encrypt</a> or <a>GenerateDataKey</a>. It can also decrypt ciphertext that was encrypted by using the public key of an asymmetric CMK outside of AWS KMS. However, it cannot decrypt ciphertext produced by other libraries, such as the <a href="https://docs.aws.amazon.com/encryption-sdk/latest/developer-guide/">AWS Encryption SDK</a> or <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingClientSideEncryption.html">Amazon S3 client-side encryption</a>. These libraries return a ciphertext format that is incompatible with AWS KMS.</p> <p>When you use the <code>ReEncrypt</code> operation, you need to provide information for the decrypt operation and the subsequent encrypt operation.</p> <ul> <li> <p>If your ciphertext was encrypted under an asymmetric CMK, you must identify the <i>source CMK</i>, that is, the CMK that encrypted the ciphertext. You must also supply the encryption algorithm that was used. This information is required to decrypt the data.</p> </li> <li> <p>It is optional, but you can specify a source CMK even when the ciphertext was encrypted under a symmetric CMK. This ensures that the ciphertext is decrypted only by using a particular CMK. If the CMK that you specify cannot decrypt the ciphertext, the <code>ReEncrypt</code> operation fails.</p> </li> <li> <p>To reencrypt the data, you must specify the <i>destination CMK</i>, that is, the CMK that re-encrypts the data after it is decrypted. You can select a symmetric or asymmetric CMK. If the destination CMK is an asymmetric CMK, you must also provide the encryption algorithm. The algorithm that you choose must be compatible with the CMK.</p> <important> <p>When you use an asymmetric CMK to encrypt or reencrypt data, be sure to record the CMK and encryption algorithm that you choose. You will be required to provide the same CMK and encryption algorithm when you decrypt the data. If the CMK and algorithm do not match the values used to encrypt the data, the decrypt operation fails.</p> <p>You are not required to supply the CMK ID and encryption algorithm when you decrypt with symmetric CMKs because AWS KMS stores this information in the ciphertext blob. AWS KMS cannot store metadata in ciphertext generated with asymmetric keys. The standard format for asymmetric key ciphertext does not include configurable fields.</p> </important> </li> </ul> <p>Unlike other AWS KMS API operations, <code>ReEncrypt</code> callers must have two permissions:</p> <ul> <li> <p> <code>kms:EncryptFrom</code> permission on the source CMK</p> </li> <li> <p> <code>kms:EncryptTo</code> permission on the destination CMK</p> </li> </ul> <p>To permit reencryption from</p> <p> or to a CMK, include the <code>"kms:ReEncrypt*"</code> permission in your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">key policy</a>. This permission is automatically included in the key policy when you use the console to create a CMK. But you must include it manually when you create a CMK programmatically or when you use the <a>PutKeyPolicy</a> operation set a key policy.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn re_encrypt(
+        &self,
+        input: ReEncryptRequest,
+    ) -> Result<ReEncryptResponse, RusotoError<ReEncryptError>> {
+        self.re_encrypt_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retires a grant. To clean up, you can retire a grant when you're done using it. You should revoke a grant when you intend to actively deny operations that depend on it. The following are permitted to call this API:</p> <ul> <li> <p>The AWS account (root user) under which the grant was created</p> </li> <li> <p>The <code>RetiringPrincipal</code>, if present in the grant</p> </li> <li> <p>The <code>GranteePrincipal</code>, if <code>RetireGrant</code> is an operation specified in the grant</p> </li> </ul> <p>You must identify the grant to retire by its grant token or by a combination of the grant ID and the Amazon Resource Name (ARN) of the customer master key (CMK). A grant token is a unique variable-length base64-encoded string. A grant ID is a 64 character unique identifier of a grant. The <a>CreateGrant</a> operation returns both.</p>
+    async fn retire_grant(
+        &self,
+        input: RetireGrantRequest,
+    ) -> Result<(), RusotoError<RetireGrantError>> {
+        self.retire_grant_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Revokes the specified grant for the specified customer master key (CMK). You can revoke a grant to actively deny operations that depend on it.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
+    async fn revoke_grant(
+        &self,
+        input: RevokeGrantRequest,
+    ) -> Result<(), RusotoError<RevokeGrantError>> {
+        self.revoke_grant_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Schedules the deletion of a customer master key (CMK). You may provide a waiting period, specified in days, before deletion occurs. If you do not provide a waiting period, the default period of 30 days is used. When this operation is successful, the key state of the CMK changes to <code>PendingDeletion</code>. Before the waiting period ends, you can use <a>CancelKeyDeletion</a> to cancel the deletion of the CMK. After the waiting period ends, AWS KMS deletes the CMK and all AWS KMS data associated with it, including all aliases that refer to it.</p> <important> <p>Deleting a CMK is a destructive and potentially dangerous operation. When a CMK is deleted, all data that was encrypted under the CMK is unrecoverable. To prevent the use of a CMK without deleting it, use <a>DisableKey</a>.</p> </important> <p>If you schedule deletion of a CMK from a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, when the waiting period expires, <code>ScheduleKeyDeletion</code> deletes the CMK from AWS KMS. Then AWS KMS makes a best effort to delete the key material from the associated AWS CloudHSM cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling a CMK for deletion, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn schedule_key_deletion(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> Result<ScheduleKeyDeletionResponse, RusotoError<ScheduleKeyDeletionError>> {
+        self.schedule_key_deletion_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a href="https://en.wikipedia.org/wiki/Digital_signature">digital signature</a> for a message or message digest by using the private key in an asymmetric CMK. To verify the signature, use the <a>Verify</a> operation, or use the public key in the same asymmetric CMK outside of AWS KMS. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Digital signatures are generated and verified by using asymmetric key pair, such as an RSA or ECC pair that is represented by an asymmetric customer master key (CMK). The key owner (or an authorized user) uses their private key to sign a message. Anyone with the public key can verify that the message was signed with that particular private key and that the message hasn't changed since it was signed. </p> <p>To use the <code>Sign</code> operation, provide the following information:</p> <ul> <li> <p>Use the <code>KeyId</code> parameter to identify an asymmetric CMK with a <code>KeyUsage</code> value of <code>SIGN_VERIFY</code>. To get the <code>KeyUsage</code> value of a CMK, use the <a>DescribeKey</a> operation. The caller must have <code>kms:Sign</code> permission on the CMK.</p> </li> <li> <p>Use the <code>Message</code> parameter to specify the message or message digest to sign. You can submit messages of up to 4096 bytes. To sign a larger message, generate a hash digest of the message, and then provide the hash digest in the <code>Message</code> parameter. To indicate whether the message is a full message or a digest, use the <code>MessageType</code> parameter.</p> </li> <li> <p>Choose a signing algorithm that is compatible with the CMK. </p> </li> </ul> <important> <p>When signing a message, be sure to record the CMK and the signing algorithm. This information is required to verify the signature.</p> </important> <p>To verify the signature that this operation generates, use the <a>Verify</a> operation. Or use the <a>GetPublicKey</a> operation to download the public key and then use the public key to verify the signature outside of AWS KMS. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn sign(&self, input: SignRequest) -> Result<SignResponse, RusotoError<SignError>> {
+        self.sign_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or edits tags for a customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>Each tag consists of a tag key and a tag value. Tag keys and tag values are both required, but tag values can be empty (null) strings.</p> <p>You can only use a tag key once for each CMK. If you use the tag key again, AWS KMS replaces the current tag value with the specified value.</p> <p>For information about the rules that apply to tag keys and tag values, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>To remove a tag, specify the tag key. To change the tag value of an existing tag key, use <a>TagResource</a>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates an existing AWS KMS alias with a different customer master key (CMK). Each alias is associated with only one CMK at a time, although a CMK can have multiple aliases. The alias and the CMK must be in the same AWS account and region. You cannot perform this operation on an alias in a different AWS account. </p> <p>The current and new CMK must be the same type (both symmetric or both asymmetric), and they must have the same key usage (<code>ENCRYPT_DECRYPT</code> or <code>SIGN_VERIFY</code>). This restriction prevents errors in code that uses aliases. If you must assign an alias to a different type of CMK, use <a>DeleteAlias</a> to delete the old alias and <a>CreateAlias</a> to create a new alias.</p> <p>You cannot use <code>UpdateAlias</code> to change an alias name. To change an alias name, use <a>DeleteAlias</a> to delete the old alias and <a>CreateAlias</a> to create a new alias.</p> <p>Because an alias is not a property of a CMK, you can create, update, and delete the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs in the account, use the <a>ListAliases</a> operation. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> Result<(), RusotoError<UpdateAliasError>> {
+        self.update_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the properties of a custom key store. Use the <code>CustomKeyStoreId</code> parameter to identify the custom key store you want to edit. Use the remaining parameters to change the properties of the custom key store.</p> <p>You can only update a custom key store that is disconnected. To disconnect the custom key store, use <a>DisconnectCustomKeyStore</a>. To reconnect the custom key store after the update completes, use <a>ConnectCustomKeyStore</a>. To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>Use the parameters of <code>UpdateCustomKeyStore</code> to edit your keystore settings.</p> <ul> <li> <p>Use the <b>NewCustomKeyStoreName</b> parameter to change the friendly name of the custom key store to the value that you specify.</p> <p> </p> </li> <li> <p>Use the <b>KeyStorePassword</b> parameter tell AWS KMS the current password of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user (CU)</a> in the associated AWS CloudHSM cluster. You can use this parameter to <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-password">fix connection failures</a> that occur when AWS KMS cannot log into the associated cluster because the <code>kmsuser</code> password has changed. This value does not change the password in the AWS CloudHSM cluster.</p> <p> </p> </li> <li> <p>Use the <b>CloudHsmClusterId</b> parameter to associate the custom key store with a different, but related, AWS CloudHSM cluster. You can use this parameter to repair a custom key store if its AWS CloudHSM cluster becomes corrupted or is deleted, or when you need to create or restore a cluster from a backup. </p> </li> </ul> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
+    async fn update_custom_key_store(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> Result<UpdateCustomKeyStoreResponse, RusotoError<UpdateCustomKeyStoreError>> {
+        self.update_custom_key_store_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the description of a customer master key (CMK). To see the description of a CMK, use <a>DescribeKey</a>. </p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn update_key_description(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> Result<(), RusotoError<UpdateKeyDescriptionError>> {
+        self.update_key_description_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Verifies a digital signature that was generated by the <a>Sign</a> operation. </p> <p/> <p>Verification confirms that an authorized user signed the message with the specified CMK and signing algorithm, and the message hasn't changed since it was signed. If the signature is verified, the value of the <code>SignatureValid</code> field in the response is <code>True</code>. If the signature verification fails, the <code>Verify</code> operation fails with an <code>KMSInvalidSignatureException</code> exception.</p> <p>A digital signature is generated by using the private key in an asymmetric CMK. The signature is verified by using the public key in the same asymmetric CMK. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To verify a digital signature, you can use the <code>Verify</code> operation. Specify the same asymmetric CMK, message, and signing algorithm that were used to produce the signature.</p> <p>You can also verify the digital signature by using the public key of the CMK outside of AWS KMS. Use the <a>GetPublicKey</a> operation to download the public key in the asymmetric CMK and then use the public key to verify the signature outside of AWS KMS. The advantage of using the <code>Verify</code> operation is that it is performed within AWS KMS. As a result, it's easy to call, the operation is performed within the FIPS boundary, it is logged in AWS CloudTrail, and you can use key policy and IAM policy to determine who is authorized to use the CMK to verify signatures.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    async fn verify(
+        &self,
+        input: VerifyRequest,
+    ) -> Result<VerifyResponse, RusotoError<VerifyError>> {
+        self.verify_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
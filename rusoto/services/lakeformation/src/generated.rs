@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -558,11 +558,14 @@ pub struct UpdateResourceResponse {}
 
 /// Errors returned by BatchGrantPermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGrantPermissionsError {
     /// <p>The input provided was not valid.</p>
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGrantPermissionsError {
@@ -570,15 +573,19 @@ impl BatchGrantPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGrantPermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchGrantPermissionsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGrantPermissionsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGrantPermissionsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGrantPermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -590,17 +597,21 @@ impl fmt::Display for BatchGrantPermissionsError {
         match *self {
             BatchGrantPermissionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchGrantPermissionsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchGrantPermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGrantPermissionsError {}
 /// Errors returned by BatchRevokePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchRevokePermissionsError {
     /// <p>The input provided was not valid.</p>
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchRevokePermissionsError {
@@ -608,15 +619,19 @@ impl BatchRevokePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchRevokePermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchRevokePermissionsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchRevokePermissionsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchRevokePermissionsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchRevokePermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -628,12 +643,14 @@ impl fmt::Display for BatchRevokePermissionsError {
         match *self {
             BatchRevokePermissionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchRevokePermissionsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            BatchRevokePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchRevokePermissionsError {}
 /// Errors returned by DeregisterResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterResourceError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -643,6 +660,8 @@ pub enum DeregisterResourceError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterResourceError {
@@ -650,19 +669,31 @@ impl DeregisterResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeregisterResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        DeregisterResourceError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeregisterResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeregisterResourceError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeregisterResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeregisterResourceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeregisterResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DeregisterResourceError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -676,12 +707,14 @@ impl fmt::Display for DeregisterResourceError {
             DeregisterResourceError::InternalService(ref cause) => write!(f, "{}", cause),
             DeregisterResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeregisterResourceError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DeregisterResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterResourceError {}
 /// Errors returned by DescribeResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeResourceError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -691,6 +724,8 @@ pub enum DescribeResourceError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeResourceError {
@@ -698,19 +733,28 @@ impl DescribeResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeResourceError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DescribeResourceError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeResourceError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DescribeResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        DescribeResourceError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -724,12 +768,14 @@ impl fmt::Display for DescribeResourceError {
             DescribeResourceError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeResourceError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            DescribeResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeResourceError {}
 /// Errors returned by GetDataLakeSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDataLakeSettingsError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -737,6 +783,8 @@ pub enum GetDataLakeSettingsError {
     InternalService(String),
     /// <p>The input provided was not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDataLakeSettingsError {
@@ -744,16 +792,25 @@ impl GetDataLakeSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDataLakeSettingsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        GetDataLakeSettingsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDataLakeSettingsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetDataLakeSettingsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDataLakeSettingsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetDataLakeSettingsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDataLakeSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -766,12 +823,14 @@ impl fmt::Display for GetDataLakeSettingsError {
             GetDataLakeSettingsError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             GetDataLakeSettingsError::InternalService(ref cause) => write!(f, "{}", cause),
             GetDataLakeSettingsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            GetDataLakeSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDataLakeSettingsError {}
 /// Errors returned by GetEffectivePermissionsForPath
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEffectivePermissionsForPathError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -781,6 +840,8 @@ pub enum GetEffectivePermissionsForPathError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEffectivePermissionsForPathError {
@@ -790,27 +851,36 @@ impl GetEffectivePermissionsForPathError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEffectivePermissionsForPathError::EntityNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEffectivePermissionsForPathError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetEffectivePermissionsForPathError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEffectivePermissionsForPathError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEffectivePermissionsForPathError::OperationTimeout(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetEffectivePermissionsForPathError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -830,12 +900,16 @@ impl fmt::Display for GetEffectivePermissionsForPathError {
             GetEffectivePermissionsForPathError::OperationTimeout(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetEffectivePermissionsForPathError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetEffectivePermissionsForPathError {}
 /// Errors returned by GrantPermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GrantPermissionsError {
     /// <p>Two processes are trying to modify a resource simultaneously.</p>
     ConcurrentModification(String),
@@ -843,6 +917,8 @@ pub enum GrantPermissionsError {
     EntityNotFound(String),
     /// <p>The input provided was not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GrantPermissionsError {
@@ -850,18 +926,22 @@ impl GrantPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GrantPermissionsError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GrantPermissionsError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GrantPermissionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        GrantPermissionsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GrantPermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(GrantPermissionsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GrantPermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -874,12 +954,14 @@ impl fmt::Display for GrantPermissionsError {
             GrantPermissionsError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             GrantPermissionsError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             GrantPermissionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            GrantPermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GrantPermissionsError {}
 /// Errors returned by ListPermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPermissionsError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -887,6 +969,8 @@ pub enum ListPermissionsError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPermissionsError {
@@ -894,16 +978,22 @@ impl ListPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPermissionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListPermissionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListPermissionsError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListPermissionsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        ListPermissionsError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -916,12 +1006,14 @@ impl fmt::Display for ListPermissionsError {
             ListPermissionsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListPermissionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListPermissionsError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            ListPermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPermissionsError {}
 /// Errors returned by ListResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourcesError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
@@ -929,6 +1021,8 @@ pub enum ListResourcesError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourcesError {
@@ -936,16 +1030,19 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListResourcesError::InternalService(err.msg))
+                    return RusotoError::service(ListResourcesError::InternalService(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(ListResourcesError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListResourcesError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        ListResourcesError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -958,17 +1055,21 @@ impl fmt::Display for ListResourcesError {
             ListResourcesError::InternalService(ref cause) => write!(f, "{}", cause),
             ListResourcesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListResourcesError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            ListResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourcesError {}
 /// Errors returned by PutDataLakeSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDataLakeSettingsError {
     /// <p>An internal service error occurred.</p>
     InternalService(String),
     /// <p>The input provided was not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDataLakeSettingsError {
@@ -976,13 +1077,19 @@ impl PutDataLakeSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutDataLakeSettingsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        PutDataLakeSettingsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutDataLakeSettingsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        PutDataLakeSettingsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutDataLakeSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -994,12 +1101,14 @@ impl fmt::Display for PutDataLakeSettingsError {
         match *self {
             PutDataLakeSettingsError::InternalService(ref cause) => write!(f, "{}", cause),
             PutDataLakeSettingsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            PutDataLakeSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutDataLakeSettingsError {}
 /// Errors returned by RegisterResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterResourceError {
     /// <p>A resource to be created or added already exists.</p>
     AlreadyExists(String),
@@ -1009,6 +1118,8 @@ pub enum RegisterResourceError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterResourceError {
@@ -1016,19 +1127,28 @@ impl RegisterResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(RegisterResourceError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        RegisterResourceError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RegisterResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        RegisterResourceError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(RegisterResourceError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(RegisterResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        RegisterResourceError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1042,12 +1162,14 @@ impl fmt::Display for RegisterResourceError {
             RegisterResourceError::InternalService(ref cause) => write!(f, "{}", cause),
             RegisterResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             RegisterResourceError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            RegisterResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterResourceError {}
 /// Errors returned by RevokePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RevokePermissionsError {
     /// <p>Two processes are trying to modify a resource simultaneously.</p>
     ConcurrentModification(String),
@@ -1055,6 +1177,8 @@ pub enum RevokePermissionsError {
     EntityNotFound(String),
     /// <p>The input provided was not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RevokePermissionsError {
@@ -1062,18 +1186,25 @@ impl RevokePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RevokePermissionsError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RevokePermissionsError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(RevokePermissionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        RevokePermissionsError::EntityNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RevokePermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        RevokePermissionsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RevokePermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1086,12 +1217,14 @@ impl fmt::Display for RevokePermissionsError {
             RevokePermissionsError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             RevokePermissionsError::EntityNotFound(ref cause) => write!(f, "{}", cause),
             RevokePermissionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            RevokePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RevokePermissionsError {}
 /// Errors returned by UpdateResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResourceError {
     /// <p>A specified entity does not exist</p>
     EntityNotFound(String),
@@ -1101,6 +1234,8 @@ pub enum UpdateResourceError {
     InvalidInput(String),
     /// <p>The operation timed out.</p>
     OperationTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateResourceError {
@@ -1108,19 +1243,25 @@ impl UpdateResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(UpdateResourceError::EntityNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdateResourceError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateResourceError::InvalidInput(err.msg), &res)
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateResourceError::OperationTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1134,6 +1275,7 @@ impl fmt::Display for UpdateResourceError {
             UpdateResourceError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateResourceError::OperationTimeout(ref cause) => write!(f, "{}", cause),
+            UpdateResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1233,6 +1375,7 @@ impl LakeFormationClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> LakeFormationClient {
         LakeFormationClient {
             client: Client::shared(),
@@ -1258,159 +1401,353 @@ impl LakeFormationClient {
     pub fn new_with_client(client: Client, region: region::Region) -> LakeFormationClient {
         LakeFormationClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<LakeFormationClient> {
+        rusoto_core::ClientBuilder::new(LakeFormationClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> LakeFormationClient {
+        LakeFormationClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl LakeFormation for LakeFormationClient {
-    /// <p>Batch operation to grant permissions to the principal.</p>
-    async fn batch_grant_permissions(
+impl LakeFormationClient {
+    /// Calls `batch_grant_permissions`, also returning the metadata of the response.
+    pub async fn batch_grant_permissions_with_metadata(
         &self,
         input: BatchGrantPermissionsRequest,
-    ) -> Result<BatchGrantPermissionsResponse, RusotoError<BatchGrantPermissionsError>> {
+    ) -> Result<
+        (BatchGrantPermissionsResponse, ResponseMetadata),
+        RusotoError<BatchGrantPermissionsError>,
+    > {
+        let request = self.build_batch_grant_permissions_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGrantPermissionsResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGrantPermissionsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_grant_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_grant_permissions(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchGrantPermissionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_grant_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_grant_permissions_request(
+        &self,
+        input: BatchGrantPermissionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("BatchGrantPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.BatchGrantPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_revoke_permissions`, also returning the metadata of the response.
+    pub async fn batch_revoke_permissions_with_metadata(
+        &self,
+        input: BatchRevokePermissionsRequest,
+    ) -> Result<
+        (BatchRevokePermissionsResponse, ResponseMetadata),
+        RusotoError<BatchRevokePermissionsError>,
+    > {
+        let request = self.build_batch_revoke_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGrantPermissionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchRevokePermissionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGrantPermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchRevokePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Batch operation to revoke permissions from the principal.</p>
-    async fn batch_revoke_permissions(
+    /// Presigns the request `batch_revoke_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_revoke_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchRevokePermissionsRequest,
-    ) -> Result<BatchRevokePermissionsResponse, RusotoError<BatchRevokePermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_revoke_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_revoke_permissions_request(
+        &self,
+        input: BatchRevokePermissionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("BatchRevokePermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.BatchRevokePermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_resource`, also returning the metadata of the response.
+    pub async fn deregister_resource_with_metadata(
+        &self,
+        input: DeregisterResourceRequest,
+    ) -> Result<(DeregisterResourceResponse, ResponseMetadata), RusotoError<DeregisterResourceError>>
+    {
+        let request = self.build_deregister_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchRevokePermissionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchRevokePermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterResourceError::from_response(response))
         }
     }
 
-    /// <p>Deregisters the resource as managed by the Data Catalog.</p> <p>When you deregister a path, Lake Formation removes the path from the inline policy attached to your service-linked role.</p>
-    async fn deregister_resource(
+    /// Presigns the request `deregister_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterResourceRequest,
-    ) -> Result<DeregisterResourceResponse, RusotoError<DeregisterResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_resource_request(&self, input: DeregisterResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("DeregisterResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.DeregisterResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_resource`, also returning the metadata of the response.
+    pub async fn describe_resource_with_metadata(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> Result<(DescribeResourceResponse, ResponseMetadata), RusotoError<DescribeResourceError>>
+    {
+        let request = self.build_describe_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the current data access role for the given resource registered in AWS Lake Formation.</p>
-    async fn describe_resource(
+    /// Presigns the request `describe_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeResourceRequest,
-    ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_resource_request(&self, input: DescribeResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("DescribeResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.DescribeResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_data_lake_settings`, also returning the metadata of the response.
+    pub async fn get_data_lake_settings_with_metadata(
+        &self,
+        input: GetDataLakeSettingsRequest,
+    ) -> Result<
+        (GetDataLakeSettingsResponse, ResponseMetadata),
+        RusotoError<GetDataLakeSettingsError>,
+    > {
+        let request = self.build_get_data_lake_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDataLakeSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDataLakeSettingsError::from_response(response))
         }
     }
 
-    /// <p>The AWS Lake Formation principal.</p>
-    async fn get_data_lake_settings(
+    /// Presigns the request `get_data_lake_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_data_lake_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDataLakeSettingsRequest,
-    ) -> Result<GetDataLakeSettingsResponse, RusotoError<GetDataLakeSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_data_lake_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_data_lake_settings_request(
+        &self,
+        input: GetDataLakeSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("GetDataLakeSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.GetDataLakeSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_effective_permissions_for_path`, also returning the metadata of the response.
+    pub async fn get_effective_permissions_for_path_with_metadata(
+        &self,
+        input: GetEffectivePermissionsForPathRequest,
+    ) -> Result<
+        (GetEffectivePermissionsForPathResponse, ResponseMetadata),
+        RusotoError<GetEffectivePermissionsForPathError>,
+    > {
+        let request = self.build_get_effective_permissions_for_path_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDataLakeSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetEffectivePermissionsForPathResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDataLakeSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetEffectivePermissionsForPathError::from_response(response))
         }
     }
 
-    /// <p>Returns the permissions for a specified table or database resource located at a path in Amazon S3.</p>
-    async fn get_effective_permissions_for_path(
+    /// Presigns the request `get_effective_permissions_for_path` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_effective_permissions_for_path(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEffectivePermissionsForPathRequest,
-    ) -> Result<
-        GetEffectivePermissionsForPathResponse,
-        RusotoError<GetEffectivePermissionsForPathError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_effective_permissions_for_path_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_effective_permissions_for_path_request(
+        &self,
+        input: GetEffectivePermissionsForPathRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("GetEffectivePermissionsForPath");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1420,212 +1757,516 @@ impl LakeFormation for LakeFormationClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `grant_permissions`, also returning the metadata of the response.
+    pub async fn grant_permissions_with_metadata(
+        &self,
+        input: GrantPermissionsRequest,
+    ) -> Result<(GrantPermissionsResponse, ResponseMetadata), RusotoError<GrantPermissionsError>>
+    {
+        let request = self.build_grant_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetEffectivePermissionsForPathResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GrantPermissionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEffectivePermissionsForPathError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GrantPermissionsError::from_response(response))
         }
     }
 
-    /// <p>Grants permissions to the principal to access metadata in the Data Catalog and data organized in underlying data storage such as Amazon S3.</p> <p>For information about permissions, see <a href="https://docs-aws.amazon.com/michigan/latest/dg/security-data-access.html">Security and Access Control to Metadata and Data</a>.</p>
-    async fn grant_permissions(
+    /// Presigns the request `grant_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_grant_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GrantPermissionsRequest,
-    ) -> Result<GrantPermissionsResponse, RusotoError<GrantPermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_grant_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_grant_permissions_request(&self, input: GrantPermissionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("GrantPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.GrantPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_permissions`, also returning the metadata of the response.
+    pub async fn list_permissions_with_metadata(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<(ListPermissionsResponse, ResponseMetadata), RusotoError<ListPermissionsError>>
+    {
+        let request = self.build_list_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GrantPermissionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPermissionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GrantPermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPermissionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the principal permissions on the resource, filtered by the permissions of the caller. For example, if you are granted an ALTER permission, you are able to see only the principal permissions for ALTER.</p> <p>This operation returns only those permissions that have been explicitly granted.</p> <p>For information about permissions, see <a href="https://docs-aws.amazon.com/michigan/latest/dg/security-data-access.html">Security and Access Control to Metadata and Data</a>.</p>
-    async fn list_permissions(
+    /// Presigns the request `list_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPermissionsRequest,
-    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_permissions_request(&self, input: ListPermissionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("ListPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.ListPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resources`, also returning the metadata of the response.
+    pub async fn list_resources_with_metadata(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<(ListResourcesResponse, ResponseMetadata), RusotoError<ListResourcesError>> {
+        let request = self.build_list_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPermissionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResourcesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists the resources registered to be managed by the Data Catalog.</p>
-    async fn list_resources(
+    /// Presigns the request `list_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourcesRequest,
-    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resources_request(&self, input: ListResourcesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("ListResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.ListResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_data_lake_settings`, also returning the metadata of the response.
+    pub async fn put_data_lake_settings_with_metadata(
+        &self,
+        input: PutDataLakeSettingsRequest,
+    ) -> Result<
+        (PutDataLakeSettingsResponse, ResponseMetadata),
+        RusotoError<PutDataLakeSettingsError>,
+    > {
+        let request = self.build_put_data_lake_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListResourcesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutDataLakeSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutDataLakeSettingsError::from_response(response))
         }
     }
 
-    /// <p>The AWS Lake Formation principal.</p>
-    async fn put_data_lake_settings(
+    /// Presigns the request `put_data_lake_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_data_lake_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutDataLakeSettingsRequest,
-    ) -> Result<PutDataLakeSettingsResponse, RusotoError<PutDataLakeSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_data_lake_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_data_lake_settings_request(
+        &self,
+        input: PutDataLakeSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("PutDataLakeSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.PutDataLakeSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_resource`, also returning the metadata of the response.
+    pub async fn register_resource_with_metadata(
+        &self,
+        input: RegisterResourceRequest,
+    ) -> Result<(RegisterResourceResponse, ResponseMetadata), RusotoError<RegisterResourceError>>
+    {
+        let request = self.build_register_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutDataLakeSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutDataLakeSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterResourceError::from_response(response))
         }
     }
 
-    /// <p>Registers the resource as managed by the Data Catalog.</p> <p>To add or update data, Lake Formation needs read/write access to the chosen Amazon S3 path. Choose a role that you know has permission to do this, or choose the AWSServiceRoleForLakeFormationDataAccess service-linked role. When you register the first Amazon S3 path, the service-linked role and a new inline policy are created on your behalf. Lake Formation adds the first path to the inline policy and attaches it to the service-linked role. When you register subsequent paths, Lake Formation adds the path to the existing policy.</p>
-    async fn register_resource(
+    /// Presigns the request `register_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterResourceRequest,
-    ) -> Result<RegisterResourceResponse, RusotoError<RegisterResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_resource_request(&self, input: RegisterResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("RegisterResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.RegisterResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `revoke_permissions`, also returning the metadata of the response.
+    pub async fn revoke_permissions_with_metadata(
+        &self,
+        input: RevokePermissionsRequest,
+    ) -> Result<(RevokePermissionsResponse, ResponseMetadata), RusotoError<RevokePermissionsError>>
+    {
+        let request = self.build_revoke_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RevokePermissionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RevokePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Revokes permissions to the principal to access metadata in the Data Catalog and data organized in underlying data storage such as Amazon S3.</p>
-    async fn revoke_permissions(
+    /// Presigns the request `revoke_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_revoke_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RevokePermissionsRequest,
-    ) -> Result<RevokePermissionsResponse, RusotoError<RevokePermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_revoke_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_revoke_permissions_request(&self, input: RevokePermissionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("RevokePermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.RevokePermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_resource`, also returning the metadata of the response.
+    pub async fn update_resource_with_metadata(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<(UpdateResourceResponse, ResponseMetadata), RusotoError<UpdateResourceError>> {
+        let request = self.build_update_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RevokePermissionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RevokePermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateResourceError::from_response(response))
         }
     }
 
-    /// <p>Updates the data access role used for vending access to the given (registered) resource in AWS Lake Formation. </p>
-    async fn update_resource(
+    /// Presigns the request `update_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateResourceRequest,
-    ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_resource_request(&self, input: UpdateResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lakeformation", &self.region, "/");
+        request.set_operation("UpdateResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLakeFormation.UpdateResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl LakeFormation for LakeFormationClient {
+    /// <p>Batch operation to grant permissions to the principal.</p>
+    async fn batch_grant_permissions(
+        &self,
+        input: BatchGrantPermissionsRequest,
+    ) -> Result<BatchGrantPermissionsResponse, RusotoError<BatchGrantPermissionsError>> {
+        self.batch_grant_permissions_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateResourceResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateResourceError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Batch operation to revoke permissions from the principal.</p>
+    async fn batch_revoke_permissions(
+        &self,
+        input: BatchRevokePermissionsRequest,
+    ) -> Result<BatchRevokePermissionsResponse, RusotoError<BatchRevokePermissionsError>> {
+        self.batch_revoke_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters the resource as managed by the Data Catalog.</p> <p>When you deregister a path, Lake Formation removes the path from the inline policy attached to your service-linked role.</p>
+    async fn deregister_resource(
+        &self,
+        input: DeregisterResourceRequest,
+    ) -> Result<DeregisterResourceResponse, RusotoError<DeregisterResourceError>> {
+        self.deregister_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the current data access role for the given resource registered in AWS Lake Formation.</p>
+    async fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
+        self.describe_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The AWS Lake Formation principal.</p>
+    async fn get_data_lake_settings(
+        &self,
+        input: GetDataLakeSettingsRequest,
+    ) -> Result<GetDataLakeSettingsResponse, RusotoError<GetDataLakeSettingsError>> {
+        self.get_data_lake_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the permissions for a specified table or database resource located at a path in Amazon S3.</p>
+    async fn get_effective_permissions_for_path(
+        &self,
+        input: GetEffectivePermissionsForPathRequest,
+    ) -> Result<
+        GetEffectivePermissionsForPathResponse,
+        RusotoError<GetEffectivePermissionsForPathError>,
+    > {
+        self.get_effective_permissions_for_path_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Grants permissions to the principal to access metadata in the Data Catalog and data organized in underlying data storage such as Amazon S3.</p> <p>For information about permissions, see <a href="https://docs-aws.amazon.com/michigan/latest/dg/security-data-access.html">Security and Access Control to Metadata and Data</a>.</p>
+    async fn grant_permissions(
+        &self,
+        input: GrantPermissionsRequest,
+    ) -> Result<GrantPermissionsResponse, RusotoError<GrantPermissionsError>> {
+        self.grant_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the principal permissions on the resource, filtered by the permissions of the caller. For example, if you are granted an ALTER permission, you are able to see only the principal permissions for ALTER.</p> <p>This operation returns only those permissions that have been explicitly granted.</p> <p>For information about permissions, see <a href="https://docs-aws.amazon.com/michigan/latest/dg/security-data-access.html">Security and Access Control to Metadata and Data</a>.</p>
+    async fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+        self.list_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resources registered to be managed by the Data Catalog.</p>
+    async fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+        self.list_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The AWS Lake Formation principal.</p>
+    async fn put_data_lake_settings(
+        &self,
+        input: PutDataLakeSettingsRequest,
+    ) -> Result<PutDataLakeSettingsResponse, RusotoError<PutDataLakeSettingsError>> {
+        self.put_data_lake_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers the resource as managed by the Data Catalog.</p> <p>To add or update data, Lake Formation needs read/write access to the chosen Amazon S3 path. Choose a role that you know has permission to do this, or choose the AWSServiceRoleForLakeFormationDataAccess service-linked role. When you register the first Amazon S3 path, the service-linked role and a new inline policy are created on your behalf. Lake Formation adds the first path to the inline policy and attaches it to the service-linked role. When you register subsequent paths, Lake Formation adds the path to the existing policy.</p>
+    async fn register_resource(
+        &self,
+        input: RegisterResourceRequest,
+    ) -> Result<RegisterResourceResponse, RusotoError<RegisterResourceError>> {
+        self.register_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Revokes permissions to the principal to access metadata in the Data Catalog and data organized in underlying data storage such as Amazon S3.</p>
+    async fn revoke_permissions(
+        &self,
+        input: RevokePermissionsRequest,
+    ) -> Result<RevokePermissionsResponse, RusotoError<RevokePermissionsError>> {
+        self.revoke_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the data access role used for vending access to the given (registered) resource in AWS Lake Formation. </p>
+    async fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
+        self.update_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
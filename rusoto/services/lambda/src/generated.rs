@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1931,6 +1931,7 @@ pub struct VpcConfigResponse {
 
 /// Errors returned by AddLayerVersionPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddLayerVersionPermissionError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -1946,6 +1947,8 @@ pub enum AddLayerVersionPermissionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddLayerVersionPermissionError {
@@ -1953,40 +1956,51 @@ impl AddLayerVersionPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddLayerVersionPermissionError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "PolicyLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddLayerVersionPermissionError::PolicyLengthExceeded(err.msg),
+                        &res,
                     )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddLayerVersionPermissionError::PreconditionFailed(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddLayerVersionPermissionError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddLayerVersionPermissionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::Service(err.msg))
+                    return RusotoError::service(
+                        AddLayerVersionPermissionError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddLayerVersionPermissionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(AddLayerVersionPermissionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2007,12 +2021,14 @@ impl fmt::Display for AddLayerVersionPermissionError {
             AddLayerVersionPermissionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AddLayerVersionPermissionError::Service(ref cause) => write!(f, "{}", cause),
             AddLayerVersionPermissionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            AddLayerVersionPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddLayerVersionPermissionError {}
 /// Errors returned by AddPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddPermissionError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2028,6 +2044,8 @@ pub enum AddPermissionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddPermissionError {
@@ -2035,28 +2053,43 @@ impl AddPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AddPermissionError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        AddPermissionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyLengthExceededException" => {
-                    return RusotoError::Service(AddPermissionError::PolicyLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        AddPermissionError::PolicyLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(AddPermissionError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        AddPermissionError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AddPermissionError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        AddPermissionError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AddPermissionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddPermissionError::Service(err.msg))
+                    return RusotoError::service(AddPermissionError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddPermissionError::TooManyRequests(err.msg))
+                    return RusotoError::service(AddPermissionError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddPermissionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2073,12 +2106,14 @@ impl fmt::Display for AddPermissionError {
             AddPermissionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AddPermissionError::Service(ref cause) => write!(f, "{}", cause),
             AddPermissionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            AddPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddPermissionError {}
 /// Errors returned by CreateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAliasError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2090,6 +2125,8 @@ pub enum CreateAliasError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAliasError {
@@ -2097,22 +2134,25 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        CreateAliasError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateAliasError::ResourceConflict(err.msg))
+                    return RusotoError::service(CreateAliasError::ResourceConflict(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateAliasError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateAliasError::Service(err.msg))
+                    return RusotoError::service(CreateAliasError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateAliasError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2127,12 +2167,14 @@ impl fmt::Display for CreateAliasError {
             CreateAliasError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateAliasError::Service(ref cause) => write!(f, "{}", cause),
             CreateAliasError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAliasError {}
 /// Errors returned by CreateEventSourceMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEventSourceMappingError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2144,6 +2186,8 @@ pub enum CreateEventSourceMappingError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEventSourceMappingError {
@@ -2151,30 +2195,37 @@ impl CreateEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEventSourceMappingError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEventSourceMappingError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEventSourceMappingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service(
+                        CreateEventSourceMappingError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEventSourceMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEventSourceMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2191,12 +2242,14 @@ impl fmt::Display for CreateEventSourceMappingError {
             CreateEventSourceMappingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateEventSourceMappingError::Service(ref cause) => write!(f, "{}", cause),
             CreateEventSourceMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateEventSourceMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEventSourceMappingError {}
 /// Errors returned by CreateFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFunctionError {
     /// <p>You have exceeded your maximum total code size per account. <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">Learn more</a> </p>
     CodeStorageExceeded(String),
@@ -2210,6 +2263,8 @@ pub enum CreateFunctionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFunctionError {
@@ -2217,27 +2272,40 @@ impl CreateFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(CreateFunctionError::CodeStorageExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateFunctionError::CodeStorageExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateFunctionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFunctionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateFunctionError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        CreateFunctionError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFunctionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateFunctionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateFunctionError::Service(err.msg))
+                    return RusotoError::service(CreateFunctionError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateFunctionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateFunctionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2253,12 +2321,14 @@ impl fmt::Display for CreateFunctionError {
             CreateFunctionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateFunctionError::Service(ref cause) => write!(f, "{}", cause),
             CreateFunctionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFunctionError {}
 /// Errors returned by DeleteAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAliasError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2268,6 +2338,8 @@ pub enum DeleteAliasError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAliasError {
@@ -2275,19 +2347,22 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteAliasError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteAliasError::ResourceConflict(err.msg))
+                    return RusotoError::service(DeleteAliasError::ResourceConflict(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteAliasError::Service(err.msg))
+                    return RusotoError::service(DeleteAliasError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteAliasError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2301,12 +2376,14 @@ impl fmt::Display for DeleteAliasError {
             DeleteAliasError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             DeleteAliasError::Service(ref cause) => write!(f, "{}", cause),
             DeleteAliasError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAliasError {}
 /// Errors returned by DeleteEventSourceMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEventSourceMappingError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2318,6 +2395,8 @@ pub enum DeleteEventSourceMappingError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEventSourceMappingError {
@@ -2325,30 +2404,37 @@ impl DeleteEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteEventSourceMappingError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEventSourceMappingError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEventSourceMappingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service(
+                        DeleteEventSourceMappingError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEventSourceMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEventSourceMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2365,12 +2451,14 @@ impl fmt::Display for DeleteEventSourceMappingError {
             DeleteEventSourceMappingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteEventSourceMappingError::Service(ref cause) => write!(f, "{}", cause),
             DeleteEventSourceMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteEventSourceMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEventSourceMappingError {}
 /// Errors returned by DeleteFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFunctionError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2382,6 +2470,8 @@ pub enum DeleteFunctionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFunctionError {
@@ -2389,24 +2479,34 @@ impl DeleteFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteFunctionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFunctionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteFunctionError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        DeleteFunctionError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFunctionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteFunctionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionError::Service(err.msg))
+                    return RusotoError::service(DeleteFunctionError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFunctionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteFunctionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2421,12 +2521,14 @@ impl fmt::Display for DeleteFunctionError {
             DeleteFunctionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteFunctionError::Service(ref cause) => write!(f, "{}", cause),
             DeleteFunctionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFunctionError {}
 /// Errors returned by DeleteFunctionConcurrency
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFunctionConcurrencyError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2438,6 +2540,8 @@ pub enum DeleteFunctionConcurrencyError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFunctionConcurrencyError {
@@ -2445,30 +2549,39 @@ impl DeleteFunctionConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteFunctionConcurrencyError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFunctionConcurrencyError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFunctionConcurrencyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::Service(err.msg))
+                    return RusotoError::service(
+                        DeleteFunctionConcurrencyError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFunctionConcurrencyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteFunctionConcurrencyError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2485,12 +2598,14 @@ impl fmt::Display for DeleteFunctionConcurrencyError {
             DeleteFunctionConcurrencyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteFunctionConcurrencyError::Service(ref cause) => write!(f, "{}", cause),
             DeleteFunctionConcurrencyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteFunctionConcurrencyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFunctionConcurrencyError {}
 /// Errors returned by DeleteFunctionEventInvokeConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFunctionEventInvokeConfigError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2500,6 +2615,8 @@ pub enum DeleteFunctionEventInvokeConfigError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFunctionEventInvokeConfigError {
@@ -2509,27 +2626,36 @@ impl DeleteFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionEventInvokeConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFunctionEventInvokeConfigError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteFunctionEventInvokeConfigError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteFunctionEventInvokeConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2549,17 +2675,23 @@ impl fmt::Display for DeleteFunctionEventInvokeConfigError {
             DeleteFunctionEventInvokeConfigError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteFunctionEventInvokeConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteFunctionEventInvokeConfigError {}
 /// Errors returned by DeleteLayerVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLayerVersionError {
     /// <p>The AWS Lambda service encountered an internal error.</p>
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLayerVersionError {
@@ -2567,13 +2699,16 @@ impl DeleteLayerVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLayerVersionError::Service(err.msg))
+                    return RusotoError::service(DeleteLayerVersionError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteLayerVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteLayerVersionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLayerVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2585,12 +2720,14 @@ impl fmt::Display for DeleteLayerVersionError {
         match *self {
             DeleteLayerVersionError::Service(ref cause) => write!(f, "{}", cause),
             DeleteLayerVersionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteLayerVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLayerVersionError {}
 /// Errors returned by DeleteProvisionedConcurrencyConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProvisionedConcurrencyConfigError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2602,6 +2739,8 @@ pub enum DeleteProvisionedConcurrencyConfigError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProvisionedConcurrencyConfigError {
@@ -2611,32 +2750,42 @@ impl DeleteProvisionedConcurrencyConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProvisionedConcurrencyConfigError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProvisionedConcurrencyConfigError::ResourceConflict(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProvisionedConcurrencyConfigError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteProvisionedConcurrencyConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProvisionedConcurrencyConfigError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProvisionedConcurrencyConfigError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteProvisionedConcurrencyConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2659,17 +2808,23 @@ impl fmt::Display for DeleteProvisionedConcurrencyConfigError {
             DeleteProvisionedConcurrencyConfigError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteProvisionedConcurrencyConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteProvisionedConcurrencyConfigError {}
 /// Errors returned by GetAccountSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAccountSettingsError {
     /// <p>The AWS Lambda service encountered an internal error.</p>
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAccountSettingsError {
@@ -2677,13 +2832,16 @@ impl GetAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Service(err.msg))
+                    return RusotoError::service(GetAccountSettingsError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAccountSettingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetAccountSettingsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAccountSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2695,12 +2853,14 @@ impl fmt::Display for GetAccountSettingsError {
         match *self {
             GetAccountSettingsError::Service(ref cause) => write!(f, "{}", cause),
             GetAccountSettingsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetAccountSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAccountSettingsError {}
 /// Errors returned by GetAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAliasError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2710,6 +2870,8 @@ pub enum GetAliasError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAliasError {
@@ -2717,17 +2879,22 @@ impl GetAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        GetAliasError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetAliasError::ResourceNotFound(err.msg), &res)
+                }
+                "ServiceException" => {
+                    return RusotoError::service(GetAliasError::Service(err.msg), &res)
                 }
-                "ServiceException" => return RusotoError::Service(GetAliasError::Service(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetAliasError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2741,12 +2908,14 @@ impl fmt::Display for GetAliasError {
             GetAliasError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetAliasError::Service(ref cause) => write!(f, "{}", cause),
             GetAliasError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAliasError {}
 /// Errors returned by GetEventSourceMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEventSourceMappingError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2756,6 +2925,8 @@ pub enum GetEventSourceMappingError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEventSourceMappingError {
@@ -2763,25 +2934,28 @@ impl GetEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEventSourceMappingError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEventSourceMappingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service(GetEventSourceMappingError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEventSourceMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEventSourceMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2795,12 +2969,14 @@ impl fmt::Display for GetEventSourceMappingError {
             GetEventSourceMappingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetEventSourceMappingError::Service(ref cause) => write!(f, "{}", cause),
             GetEventSourceMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetEventSourceMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEventSourceMappingError {}
 /// Errors returned by GetFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFunctionError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2810,6 +2986,8 @@ pub enum GetFunctionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFunctionError {
@@ -2817,19 +2995,22 @@ impl GetFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetFunctionError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        GetFunctionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFunctionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetFunctionError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionError::Service(err.msg))
+                    return RusotoError::service(GetFunctionError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetFunctionError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetFunctionError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2843,12 +3024,14 @@ impl fmt::Display for GetFunctionError {
             GetFunctionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFunctionError::Service(ref cause) => write!(f, "{}", cause),
             GetFunctionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFunctionError {}
 /// Errors returned by GetFunctionConcurrency
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFunctionConcurrencyError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2858,6 +3041,8 @@ pub enum GetFunctionConcurrencyError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFunctionConcurrencyError {
@@ -2865,25 +3050,31 @@ impl GetFunctionConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetFunctionConcurrencyError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFunctionConcurrencyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFunctionConcurrencyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionConcurrencyError::Service(err.msg))
+                    return RusotoError::service(
+                        GetFunctionConcurrencyError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetFunctionConcurrencyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFunctionConcurrencyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFunctionConcurrencyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2897,12 +3088,14 @@ impl fmt::Display for GetFunctionConcurrencyError {
             GetFunctionConcurrencyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFunctionConcurrencyError::Service(ref cause) => write!(f, "{}", cause),
             GetFunctionConcurrencyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetFunctionConcurrencyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFunctionConcurrencyError {}
 /// Errors returned by GetFunctionConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFunctionConfigurationError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2912,6 +3105,8 @@ pub enum GetFunctionConfigurationError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFunctionConfigurationError {
@@ -2919,25 +3114,31 @@ impl GetFunctionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetFunctionConfigurationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFunctionConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFunctionConfigurationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionConfigurationError::Service(err.msg))
+                    return RusotoError::service(
+                        GetFunctionConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetFunctionConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFunctionConfigurationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFunctionConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2953,12 +3154,14 @@ impl fmt::Display for GetFunctionConfigurationError {
             GetFunctionConfigurationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFunctionConfigurationError::Service(ref cause) => write!(f, "{}", cause),
             GetFunctionConfigurationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetFunctionConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFunctionConfigurationError {}
 /// Errors returned by GetFunctionEventInvokeConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFunctionEventInvokeConfigError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -2968,6 +3171,8 @@ pub enum GetFunctionEventInvokeConfigError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFunctionEventInvokeConfigError {
@@ -2977,27 +3182,36 @@ impl GetFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionEventInvokeConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFunctionEventInvokeConfigError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetFunctionEventInvokeConfigError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetFunctionEventInvokeConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3015,12 +3229,14 @@ impl fmt::Display for GetFunctionEventInvokeConfigError {
             }
             GetFunctionEventInvokeConfigError::Service(ref cause) => write!(f, "{}", cause),
             GetFunctionEventInvokeConfigError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetFunctionEventInvokeConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFunctionEventInvokeConfigError {}
 /// Errors returned by GetLayerVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLayerVersionError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3030,6 +3246,8 @@ pub enum GetLayerVersionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLayerVersionError {
@@ -3037,21 +3255,28 @@ impl GetLayerVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLayerVersionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLayerVersionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLayerVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetLayerVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLayerVersionError::Service(err.msg))
+                    return RusotoError::service(GetLayerVersionError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetLayerVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetLayerVersionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLayerVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3065,12 +3290,14 @@ impl fmt::Display for GetLayerVersionError {
             GetLayerVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLayerVersionError::Service(ref cause) => write!(f, "{}", cause),
             GetLayerVersionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetLayerVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLayerVersionError {}
 /// Errors returned by GetLayerVersionByArn
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLayerVersionByArnError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3080,6 +3307,8 @@ pub enum GetLayerVersionByArnError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLayerVersionByArnError {
@@ -3087,25 +3316,28 @@ impl GetLayerVersionByArnError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLayerVersionByArnError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLayerVersionByArnError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::Service(err.msg))
+                    return RusotoError::service(GetLayerVersionByArnError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLayerVersionByArnError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLayerVersionByArnError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3119,12 +3351,14 @@ impl fmt::Display for GetLayerVersionByArnError {
             GetLayerVersionByArnError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLayerVersionByArnError::Service(ref cause) => write!(f, "{}", cause),
             GetLayerVersionByArnError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetLayerVersionByArnError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLayerVersionByArnError {}
 /// Errors returned by GetLayerVersionPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLayerVersionPolicyError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3134,6 +3368,8 @@ pub enum GetLayerVersionPolicyError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLayerVersionPolicyError {
@@ -3141,25 +3377,28 @@ impl GetLayerVersionPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLayerVersionPolicyError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLayerVersionPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::Service(err.msg))
+                    return RusotoError::service(GetLayerVersionPolicyError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLayerVersionPolicyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLayerVersionPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3173,12 +3412,14 @@ impl fmt::Display for GetLayerVersionPolicyError {
             GetLayerVersionPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLayerVersionPolicyError::Service(ref cause) => write!(f, "{}", cause),
             GetLayerVersionPolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetLayerVersionPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLayerVersionPolicyError {}
 /// Errors returned by GetPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPolicyError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3188,6 +3429,8 @@ pub enum GetPolicyError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPolicyError {
@@ -3195,19 +3438,22 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        GetPolicyError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetPolicyError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetPolicyError::Service(err.msg))
+                    return RusotoError::service(GetPolicyError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetPolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetPolicyError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3221,12 +3467,14 @@ impl fmt::Display for GetPolicyError {
             GetPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetPolicyError::Service(ref cause) => write!(f, "{}", cause),
             GetPolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPolicyError {}
 /// Errors returned by GetProvisionedConcurrencyConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetProvisionedConcurrencyConfigError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3238,6 +3486,8 @@ pub enum GetProvisionedConcurrencyConfigError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetProvisionedConcurrencyConfigError {
@@ -3247,34 +3497,44 @@ impl GetProvisionedConcurrencyConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetProvisionedConcurrencyConfigError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ProvisionedConcurrencyConfigNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetProvisionedConcurrencyConfigError::ProvisionedConcurrencyConfigNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetProvisionedConcurrencyConfigError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetProvisionedConcurrencyConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetProvisionedConcurrencyConfigError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetProvisionedConcurrencyConfigError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetProvisionedConcurrencyConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3297,12 +3557,16 @@ impl fmt::Display for GetProvisionedConcurrencyConfigError {
             GetProvisionedConcurrencyConfigError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetProvisionedConcurrencyConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetProvisionedConcurrencyConfigError {}
 /// Errors returned by Invoke
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InvokeError {
     /// <p>Need additional permissions to configure VPC settings.</p>
     EC2AccessDenied(String),
@@ -3348,6 +3612,8 @@ pub enum InvokeError {
     TooManyRequests(String),
     /// <p>The content type of the <code>Invoke</code> request body is not JSON.</p>
     UnsupportedMediaType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InvokeError {
@@ -3355,71 +3621,76 @@ impl InvokeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EC2AccessDeniedException" => {
-                    return RusotoError::Service(InvokeError::EC2AccessDenied(err.msg))
+                    return RusotoError::service(InvokeError::EC2AccessDenied(err.msg), &res)
                 }
                 "EC2ThrottledException" => {
-                    return RusotoError::Service(InvokeError::EC2Throttled(err.msg))
+                    return RusotoError::service(InvokeError::EC2Throttled(err.msg), &res)
                 }
                 "EC2UnexpectedException" => {
-                    return RusotoError::Service(InvokeError::EC2Unexpected(err.msg))
+                    return RusotoError::service(InvokeError::EC2Unexpected(err.msg), &res)
                 }
                 "ENILimitReachedException" => {
-                    return RusotoError::Service(InvokeError::ENILimitReached(err.msg))
+                    return RusotoError::service(InvokeError::ENILimitReached(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(InvokeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(InvokeError::InvalidParameterValue(err.msg), &res)
                 }
                 "InvalidRequestContentException" => {
-                    return RusotoError::Service(InvokeError::InvalidRequestContent(err.msg))
+                    return RusotoError::service(InvokeError::InvalidRequestContent(err.msg), &res)
                 }
                 "InvalidRuntimeException" => {
-                    return RusotoError::Service(InvokeError::InvalidRuntime(err.msg))
+                    return RusotoError::service(InvokeError::InvalidRuntime(err.msg), &res)
                 }
                 "InvalidSecurityGroupIDException" => {
-                    return RusotoError::Service(InvokeError::InvalidSecurityGroupID(err.msg))
+                    return RusotoError::service(InvokeError::InvalidSecurityGroupID(err.msg), &res)
                 }
                 "InvalidSubnetIDException" => {
-                    return RusotoError::Service(InvokeError::InvalidSubnetID(err.msg))
+                    return RusotoError::service(InvokeError::InvalidSubnetID(err.msg), &res)
                 }
                 "InvalidZipFileException" => {
-                    return RusotoError::Service(InvokeError::InvalidZipFile(err.msg))
+                    return RusotoError::service(InvokeError::InvalidZipFile(err.msg), &res)
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(InvokeError::KMSAccessDenied(err.msg))
+                    return RusotoError::service(InvokeError::KMSAccessDenied(err.msg), &res)
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(InvokeError::KMSDisabled(err.msg))
+                    return RusotoError::service(InvokeError::KMSDisabled(err.msg), &res)
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(InvokeError::KMSInvalidState(err.msg))
+                    return RusotoError::service(InvokeError::KMSInvalidState(err.msg), &res)
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(InvokeError::KMSNotFound(err.msg))
+                    return RusotoError::service(InvokeError::KMSNotFound(err.msg), &res)
                 }
                 "RequestTooLargeException" => {
-                    return RusotoError::Service(InvokeError::RequestTooLarge(err.msg))
+                    return RusotoError::service(InvokeError::RequestTooLarge(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InvokeError::ResourceConflict(err.msg))
+                    return RusotoError::service(InvokeError::ResourceConflict(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvokeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(InvokeError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(InvokeError::ResourceNotReady(err.msg))
+                    return RusotoError::service(InvokeError::ResourceNotReady(err.msg), &res)
+                }
+                "ServiceException" => {
+                    return RusotoError::service(InvokeError::Service(err.msg), &res)
                 }
-                "ServiceException" => return RusotoError::Service(InvokeError::Service(err.msg)),
                 "SubnetIPAddressLimitReachedException" => {
-                    return RusotoError::Service(InvokeError::SubnetIPAddressLimitReached(err.msg))
+                    return RusotoError::service(
+                        InvokeError::SubnetIPAddressLimitReached(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InvokeError::TooManyRequests(err.msg))
+                    return RusotoError::service(InvokeError::TooManyRequests(err.msg), &res)
                 }
                 "UnsupportedMediaTypeException" => {
-                    return RusotoError::Service(InvokeError::UnsupportedMediaType(err.msg))
+                    return RusotoError::service(InvokeError::UnsupportedMediaType(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InvokeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3451,12 +3722,14 @@ impl fmt::Display for InvokeError {
             InvokeError::SubnetIPAddressLimitReached(ref cause) => write!(f, "{}", cause),
             InvokeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             InvokeError::UnsupportedMediaType(ref cause) => write!(f, "{}", cause),
+            InvokeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InvokeError {}
 /// Errors returned by InvokeAsync
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InvokeAsyncError {
     /// <p>The request body could not be parsed as JSON.</p>
     InvalidRequestContent(String),
@@ -3468,6 +3741,8 @@ pub enum InvokeAsyncError {
     ResourceNotFound(String),
     /// <p>The AWS Lambda service encountered an internal error.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InvokeAsyncError {
@@ -3475,22 +3750,25 @@ impl InvokeAsyncError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestContentException" => {
-                    return RusotoError::Service(InvokeAsyncError::InvalidRequestContent(err.msg))
+                    return RusotoError::service(
+                        InvokeAsyncError::InvalidRequestContent(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRuntimeException" => {
-                    return RusotoError::Service(InvokeAsyncError::InvalidRuntime(err.msg))
+                    return RusotoError::service(InvokeAsyncError::InvalidRuntime(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InvokeAsyncError::ResourceConflict(err.msg))
+                    return RusotoError::service(InvokeAsyncError::ResourceConflict(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvokeAsyncError::ResourceNotFound(err.msg))
+                    return RusotoError::service(InvokeAsyncError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(InvokeAsyncError::Service(err.msg))
+                    return RusotoError::service(InvokeAsyncError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InvokeAsyncError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3505,12 +3783,14 @@ impl fmt::Display for InvokeAsyncError {
             InvokeAsyncError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             InvokeAsyncError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             InvokeAsyncError::Service(ref cause) => write!(f, "{}", cause),
+            InvokeAsyncError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InvokeAsyncError {}
 /// Errors returned by ListAliases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAliasesError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3520,6 +3800,8 @@ pub enum ListAliasesError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAliasesError {
@@ -3527,19 +3809,22 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListAliasesError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListAliasesError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAliasesError::Service(err.msg))
+                    return RusotoError::service(ListAliasesError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAliasesError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListAliasesError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAliasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3553,12 +3838,14 @@ impl fmt::Display for ListAliasesError {
             ListAliasesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListAliasesError::Service(ref cause) => write!(f, "{}", cause),
             ListAliasesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListAliasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAliasesError {}
 /// Errors returned by ListEventSourceMappings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEventSourceMappingsError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3568,6 +3855,8 @@ pub enum ListEventSourceMappingsError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEventSourceMappingsError {
@@ -3575,25 +3864,31 @@ impl ListEventSourceMappingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListEventSourceMappingsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListEventSourceMappingsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEventSourceMappingsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListEventSourceMappingsError::Service(err.msg))
+                    return RusotoError::service(
+                        ListEventSourceMappingsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEventSourceMappingsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEventSourceMappingsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEventSourceMappingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3609,12 +3904,14 @@ impl fmt::Display for ListEventSourceMappingsError {
             ListEventSourceMappingsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListEventSourceMappingsError::Service(ref cause) => write!(f, "{}", cause),
             ListEventSourceMappingsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListEventSourceMappingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEventSourceMappingsError {}
 /// Errors returned by ListFunctionEventInvokeConfigs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFunctionEventInvokeConfigsError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3624,6 +3921,8 @@ pub enum ListFunctionEventInvokeConfigsError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFunctionEventInvokeConfigsError {
@@ -3633,27 +3932,36 @@ impl ListFunctionEventInvokeConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListFunctionEventInvokeConfigsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListFunctionEventInvokeConfigsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListFunctionEventInvokeConfigsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFunctionEventInvokeConfigsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListFunctionEventInvokeConfigsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListFunctionEventInvokeConfigsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3673,12 +3981,16 @@ impl fmt::Display for ListFunctionEventInvokeConfigsError {
             ListFunctionEventInvokeConfigsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListFunctionEventInvokeConfigsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListFunctionEventInvokeConfigsError {}
 /// Errors returned by ListFunctions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFunctionsError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3686,6 +3998,8 @@ pub enum ListFunctionsError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFunctionsError {
@@ -3693,16 +4007,19 @@ impl ListFunctionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListFunctionsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListFunctionsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListFunctionsError::Service(err.msg))
+                    return RusotoError::service(ListFunctionsError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListFunctionsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListFunctionsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFunctionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3715,12 +4032,14 @@ impl fmt::Display for ListFunctionsError {
             ListFunctionsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListFunctionsError::Service(ref cause) => write!(f, "{}", cause),
             ListFunctionsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListFunctionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFunctionsError {}
 /// Errors returned by ListLayerVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLayerVersionsError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3730,6 +4049,8 @@ pub enum ListLayerVersionsError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLayerVersionsError {
@@ -3737,21 +4058,28 @@ impl ListLayerVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListLayerVersionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLayerVersionsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLayerVersionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListLayerVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListLayerVersionsError::Service(err.msg))
+                    return RusotoError::service(ListLayerVersionsError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListLayerVersionsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListLayerVersionsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLayerVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3765,12 +4093,14 @@ impl fmt::Display for ListLayerVersionsError {
             ListLayerVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListLayerVersionsError::Service(ref cause) => write!(f, "{}", cause),
             ListLayerVersionsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListLayerVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLayerVersionsError {}
 /// Errors returned by ListLayers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLayersError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3778,6 +4108,8 @@ pub enum ListLayersError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLayersError {
@@ -3785,16 +4117,19 @@ impl ListLayersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListLayersError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListLayersError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListLayersError::Service(err.msg))
+                    return RusotoError::service(ListLayersError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListLayersError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListLayersError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLayersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3807,12 +4142,14 @@ impl fmt::Display for ListLayersError {
             ListLayersError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListLayersError::Service(ref cause) => write!(f, "{}", cause),
             ListLayersError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListLayersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLayersError {}
 /// Errors returned by ListProvisionedConcurrencyConfigs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProvisionedConcurrencyConfigsError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3822,6 +4159,8 @@ pub enum ListProvisionedConcurrencyConfigsError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProvisionedConcurrencyConfigsError {
@@ -3831,27 +4170,36 @@ impl ListProvisionedConcurrencyConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProvisionedConcurrencyConfigsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProvisionedConcurrencyConfigsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListProvisionedConcurrencyConfigsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProvisionedConcurrencyConfigsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProvisionedConcurrencyConfigsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListProvisionedConcurrencyConfigsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3871,12 +4219,16 @@ impl fmt::Display for ListProvisionedConcurrencyConfigsError {
             ListProvisionedConcurrencyConfigsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListProvisionedConcurrencyConfigsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListProvisionedConcurrencyConfigsError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3886,6 +4238,8 @@ pub enum ListTagsError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
@@ -3893,17 +4247,22 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListTagsError::ResourceNotFound(err.msg), &res)
+                }
+                "ServiceException" => {
+                    return RusotoError::service(ListTagsError::Service(err.msg), &res)
                 }
-                "ServiceException" => return RusotoError::Service(ListTagsError::Service(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListTagsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3917,12 +4276,14 @@ impl fmt::Display for ListTagsError {
             ListTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsError::Service(ref cause) => write!(f, "{}", cause),
             ListTagsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by ListVersionsByFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVersionsByFunctionError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -3932,6 +4293,8 @@ pub enum ListVersionsByFunctionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVersionsByFunctionError {
@@ -3939,25 +4302,31 @@ impl ListVersionsByFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVersionsByFunctionError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListVersionsByFunctionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVersionsByFunctionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListVersionsByFunctionError::Service(err.msg))
+                    return RusotoError::service(
+                        ListVersionsByFunctionError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVersionsByFunctionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVersionsByFunctionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVersionsByFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3971,12 +4340,14 @@ impl fmt::Display for ListVersionsByFunctionError {
             ListVersionsByFunctionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListVersionsByFunctionError::Service(ref cause) => write!(f, "{}", cause),
             ListVersionsByFunctionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListVersionsByFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVersionsByFunctionError {}
 /// Errors returned by PublishLayerVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PublishLayerVersionError {
     /// <p>You have exceeded your maximum total code size per account. <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">Learn more</a> </p>
     CodeStorageExceeded(String),
@@ -3988,6 +4359,8 @@ pub enum PublishLayerVersionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PublishLayerVersionError {
@@ -3995,28 +4368,34 @@ impl PublishLayerVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(PublishLayerVersionError::CodeStorageExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PublishLayerVersionError::CodeStorageExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PublishLayerVersionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PublishLayerVersionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PublishLayerVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PublishLayerVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PublishLayerVersionError::Service(err.msg))
+                    return RusotoError::service(PublishLayerVersionError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PublishLayerVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        PublishLayerVersionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PublishLayerVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4031,12 +4410,14 @@ impl fmt::Display for PublishLayerVersionError {
             PublishLayerVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PublishLayerVersionError::Service(ref cause) => write!(f, "{}", cause),
             PublishLayerVersionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            PublishLayerVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PublishLayerVersionError {}
 /// Errors returned by PublishVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PublishVersionError {
     /// <p>You have exceeded your maximum total code size per account. <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">Learn more</a> </p>
     CodeStorageExceeded(String),
@@ -4052,6 +4433,8 @@ pub enum PublishVersionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PublishVersionError {
@@ -4059,30 +4442,46 @@ impl PublishVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(PublishVersionError::CodeStorageExceeded(err.msg))
+                    return RusotoError::service(
+                        PublishVersionError::CodeStorageExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PublishVersionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PublishVersionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PublishVersionError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        PublishVersionError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(PublishVersionError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        PublishVersionError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PublishVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PublishVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PublishVersionError::Service(err.msg))
+                    return RusotoError::service(PublishVersionError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PublishVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        PublishVersionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PublishVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4099,12 +4498,14 @@ impl fmt::Display for PublishVersionError {
             PublishVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PublishVersionError::Service(ref cause) => write!(f, "{}", cause),
             PublishVersionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            PublishVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PublishVersionError {}
 /// Errors returned by PutFunctionConcurrency
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutFunctionConcurrencyError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4116,6 +4517,8 @@ pub enum PutFunctionConcurrencyError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutFunctionConcurrencyError {
@@ -4123,30 +4526,37 @@ impl PutFunctionConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutFunctionConcurrencyError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFunctionConcurrencyError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFunctionConcurrencyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::Service(err.msg))
+                    return RusotoError::service(
+                        PutFunctionConcurrencyError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFunctionConcurrencyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutFunctionConcurrencyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4161,12 +4571,14 @@ impl fmt::Display for PutFunctionConcurrencyError {
             PutFunctionConcurrencyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutFunctionConcurrencyError::Service(ref cause) => write!(f, "{}", cause),
             PutFunctionConcurrencyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            PutFunctionConcurrencyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutFunctionConcurrencyError {}
 /// Errors returned by PutFunctionEventInvokeConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutFunctionEventInvokeConfigError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4176,6 +4588,8 @@ pub enum PutFunctionEventInvokeConfigError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutFunctionEventInvokeConfigError {
@@ -4185,27 +4599,36 @@ impl PutFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutFunctionEventInvokeConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFunctionEventInvokeConfigError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutFunctionEventInvokeConfigError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutFunctionEventInvokeConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4223,12 +4646,14 @@ impl fmt::Display for PutFunctionEventInvokeConfigError {
             }
             PutFunctionEventInvokeConfigError::Service(ref cause) => write!(f, "{}", cause),
             PutFunctionEventInvokeConfigError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            PutFunctionEventInvokeConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutFunctionEventInvokeConfigError {}
 /// Errors returned by PutProvisionedConcurrencyConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutProvisionedConcurrencyConfigError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4240,6 +4665,8 @@ pub enum PutProvisionedConcurrencyConfigError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutProvisionedConcurrencyConfigError {
@@ -4249,32 +4676,42 @@ impl PutProvisionedConcurrencyConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutProvisionedConcurrencyConfigError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutProvisionedConcurrencyConfigError::ResourceConflict(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutProvisionedConcurrencyConfigError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutProvisionedConcurrencyConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutProvisionedConcurrencyConfigError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutProvisionedConcurrencyConfigError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutProvisionedConcurrencyConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4297,12 +4734,16 @@ impl fmt::Display for PutProvisionedConcurrencyConfigError {
             PutProvisionedConcurrencyConfigError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutProvisionedConcurrencyConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutProvisionedConcurrencyConfigError {}
 /// Errors returned by RemoveLayerVersionPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveLayerVersionPermissionError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4314,6 +4755,8 @@ pub enum RemoveLayerVersionPermissionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveLayerVersionPermissionError {
@@ -4323,32 +4766,42 @@ impl RemoveLayerVersionPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveLayerVersionPermissionError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveLayerVersionPermissionError::PreconditionFailed(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveLayerVersionPermissionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveLayerVersionPermissionError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveLayerVersionPermissionError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveLayerVersionPermissionError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RemoveLayerVersionPermissionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4369,12 +4822,14 @@ impl fmt::Display for RemoveLayerVersionPermissionError {
             }
             RemoveLayerVersionPermissionError::Service(ref cause) => write!(f, "{}", cause),
             RemoveLayerVersionPermissionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            RemoveLayerVersionPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveLayerVersionPermissionError {}
 /// Errors returned by RemovePermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemovePermissionError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4386,6 +4841,8 @@ pub enum RemovePermissionError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemovePermissionError {
@@ -4393,24 +4850,34 @@ impl RemovePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RemovePermissionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemovePermissionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(RemovePermissionError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        RemovePermissionError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemovePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RemovePermissionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemovePermissionError::Service(err.msg))
+                    return RusotoError::service(RemovePermissionError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemovePermissionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        RemovePermissionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemovePermissionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4425,12 +4892,14 @@ impl fmt::Display for RemovePermissionError {
             RemovePermissionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RemovePermissionError::Service(ref cause) => write!(f, "{}", cause),
             RemovePermissionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            RemovePermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemovePermissionError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4442,6 +4911,8 @@ pub enum TagResourceError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4449,22 +4920,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(TagResourceError::ResourceConflict(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceConflict(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service(TagResourceError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4479,12 +4953,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Service(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4496,6 +4972,8 @@ pub enum UntagResourceError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4503,22 +4981,31 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service(UntagResourceError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4533,12 +5020,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Service(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAliasError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4552,6 +5041,8 @@ pub enum UpdateAliasError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAliasError {
@@ -4559,25 +5050,31 @@ impl UpdateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UpdateAliasError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(UpdateAliasError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        UpdateAliasError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateAliasError::ResourceConflict(err.msg))
+                    return RusotoError::service(UpdateAliasError::ResourceConflict(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateAliasError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateAliasError::Service(err.msg))
+                    return RusotoError::service(UpdateAliasError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateAliasError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4593,12 +5090,14 @@ impl fmt::Display for UpdateAliasError {
             UpdateAliasError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateAliasError::Service(ref cause) => write!(f, "{}", cause),
             UpdateAliasError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAliasError {}
 /// Errors returned by UpdateEventSourceMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEventSourceMappingError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4612,6 +5111,8 @@ pub enum UpdateEventSourceMappingError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEventSourceMappingError {
@@ -4619,35 +5120,43 @@ impl UpdateEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateEventSourceMappingError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEventSourceMappingError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEventSourceMappingError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEventSourceMappingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service(
+                        UpdateEventSourceMappingError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEventSourceMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateEventSourceMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4665,12 +5174,14 @@ impl fmt::Display for UpdateEventSourceMappingError {
             UpdateEventSourceMappingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateEventSourceMappingError::Service(ref cause) => write!(f, "{}", cause),
             UpdateEventSourceMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateEventSourceMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateEventSourceMappingError {}
 /// Errors returned by UpdateFunctionCode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFunctionCodeError {
     /// <p>You have exceeded your maximum total code size per account. <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">Learn more</a> </p>
     CodeStorageExceeded(String),
@@ -4686,6 +5197,8 @@ pub enum UpdateFunctionCodeError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFunctionCodeError {
@@ -4693,34 +5206,46 @@ impl UpdateFunctionCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::CodeStorageExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFunctionCodeError::CodeStorageExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFunctionCodeError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFunctionCodeError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        UpdateFunctionCodeError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateFunctionCodeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::Service(err.msg))
+                    return RusotoError::service(UpdateFunctionCodeError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateFunctionCodeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFunctionCodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4737,12 +5262,14 @@ impl fmt::Display for UpdateFunctionCodeError {
             UpdateFunctionCodeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateFunctionCodeError::Service(ref cause) => write!(f, "{}", cause),
             UpdateFunctionCodeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateFunctionCodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFunctionCodeError {}
 /// Errors returned by UpdateFunctionConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFunctionConfigurationError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4756,6 +5283,8 @@ pub enum UpdateFunctionConfigurationError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFunctionConfigurationError {
@@ -4765,35 +5294,48 @@ impl UpdateFunctionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFunctionConfigurationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFunctionConfigurationError::PreconditionFailed(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFunctionConfigurationError::ResourceConflict(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFunctionConfigurationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateFunctionConfigurationError::Service(err.msg))
+                    return RusotoError::service(
+                        UpdateFunctionConfigurationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFunctionConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFunctionConfigurationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateFunctionConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4813,12 +5355,14 @@ impl fmt::Display for UpdateFunctionConfigurationError {
             UpdateFunctionConfigurationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateFunctionConfigurationError::Service(ref cause) => write!(f, "{}", cause),
             UpdateFunctionConfigurationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateFunctionConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFunctionConfigurationError {}
 /// Errors returned by UpdateFunctionEventInvokeConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFunctionEventInvokeConfigError {
     /// <p>One of the parameters in the request is invalid.</p>
     InvalidParameterValue(String),
@@ -4828,6 +5372,8 @@ pub enum UpdateFunctionEventInvokeConfigError {
     Service(String),
     /// <p>The request throughput limit was exceeded.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFunctionEventInvokeConfigError {
@@ -4837,27 +5383,36 @@ impl UpdateFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateFunctionEventInvokeConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFunctionEventInvokeConfigError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFunctionEventInvokeConfigError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateFunctionEventInvokeConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4877,6 +5432,9 @@ impl fmt::Display for UpdateFunctionEventInvokeConfigError {
             UpdateFunctionEventInvokeConfigError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateFunctionEventInvokeConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -5200,6 +5758,7 @@ impl LambdaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> LambdaClient {
         LambdaClient {
             client: Client::shared(),
@@ -5225,16 +5784,72 @@ impl LambdaClient {
     pub fn new_with_client(client: Client, region: region::Region) -> LambdaClient {
         LambdaClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<LambdaClient> {
+        rusoto_core::ClientBuilder::new(LambdaClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> LambdaClient {
+        LambdaClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Lambda for LambdaClient {
-    /// <p>Adds permissions to the resource-based policy of a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Use this action to grant layer usage permission to other accounts. You can grant permission to a single account, all AWS accounts, or all accounts in an organization.</p> <p>To revoke permission, call <a>RemoveLayerVersionPermission</a> with the statement ID that you specified when you added it.</p>
-    async fn add_layer_version_permission(
+impl LambdaClient {
+    /// Calls `add_layer_version_permission`, also returning the metadata of the response.
+    pub async fn add_layer_version_permission_with_metadata(
         &self,
         input: AddLayerVersionPermissionRequest,
-    ) -> Result<AddLayerVersionPermissionResponse, RusotoError<AddLayerVersionPermissionError>>
-    {
+    ) -> Result<
+        (AddLayerVersionPermissionResponse, ResponseMetadata),
+        RusotoError<AddLayerVersionPermissionError>,
+    > {
+        let request = self.build_add_layer_version_permission_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddLayerVersionPermissionResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AddLayerVersionPermissionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_layer_version_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_layer_version_permission(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddLayerVersionPermissionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_layer_version_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_layer_version_permission_request(
+        &self,
+        input: AddLayerVersionPermissionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2018-10-31/layers/{layer_name}/versions/{version_number}/policy",
             layer_name = input.layer_name,
@@ -5242,6 +5857,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddLayerVersionPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5253,34 +5869,58 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `add_permission`, also returning the metadata of the response.
+    pub async fn add_permission_with_metadata(
+        &self,
+        input: AddPermissionRequest,
+    ) -> Result<(AddPermissionResponse, ResponseMetadata), RusotoError<AddPermissionError>> {
+        let request = self.build_add_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddLayerVersionPermissionResponse, _>()?;
+                .deserialize::<AddPermissionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AddLayerVersionPermissionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AddPermissionError::from_response(response))
         }
     }
 
-    /// <p>Grants an AWS service or another account permission to use a function. You can apply the policy at the function level, or specify a qualifier to restrict access to a single version or alias. If you use a qualifier, the invoker must use the full Amazon Resource Name (ARN) of that version or alias to invoke the function.</p> <p>To grant permission to another account, specify the account ID as the <code>Principal</code>. For AWS services, the principal is a domain-style identifier defined by the service, like <code>s3.amazonaws.com</code> or <code>sns.amazonaws.com</code>. For AWS services, you can also specify the ARN or owning account of the associated resource as the <code>SourceArn</code> or <code>SourceAccount</code>. If you grant permission to a service principal without specifying the source, other accounts could potentially configure resources in their account to invoke your Lambda function.</p> <p>This action adds a statement to a resource-based permissions policy for the function. For more information about function policies, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">Lambda Function Policies</a>. </p>
-    async fn add_permission(
+    /// Presigns the request `add_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddPermissionRequest,
-    ) -> Result<AddPermissionResponse, RusotoError<AddPermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_permission_request(&self, input: AddPermissionRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/policy",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5292,121 +5932,221 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `create_alias`, also returning the metadata of the response.
+    pub async fn create_alias_with_metadata(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<(AliasConfiguration, ResponseMetadata), RusotoError<CreateAliasError>> {
+        let request = self.build_create_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddPermissionResponse, _>()?;
+                .deserialize::<AliasConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AddPermissionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateAliasError::from_response(response))
         }
     }
 
-    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a> for a Lambda function version. Use aliases to provide clients with a function identifier that you can update to invoke a different version.</p> <p>You can also map an alias to split invocation requests between two versions. Use the <code>RoutingConfig</code> parameter to specify a second version and the percentage of invocation requests that it receives.</p>
-    async fn create_alias(
+    /// Presigns the request `create_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAliasRequest,
-    ) -> Result<AliasConfiguration, RusotoError<CreateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_alias_request(&self, input: CreateAliasRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/aliases",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_event_source_mapping`, also returning the metadata of the response.
+    pub async fn create_event_source_mapping_with_metadata(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> Result<
+        (EventSourceMappingConfiguration, ResponseMetadata),
+        RusotoError<CreateEventSourceMappingError>,
+    > {
+        let request = self.build_create_event_source_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AliasConfiguration, _>()?;
+                .deserialize::<EventSourceMappingConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateEventSourceMappingError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a mapping between an event source and an AWS Lambda function. Lambda reads items from the event source and triggers the function.</p> <p>For details about each event source type, see the following topics.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-ddb.html">Using AWS Lambda with Amazon DynamoDB</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-kinesis.html">Using AWS Lambda with Amazon Kinesis</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-sqs.html">Using AWS Lambda with Amazon SQS</a> </p> </li> </ul> <p>The following error handling options are only available for stream sources (DynamoDB and Kinesis):</p> <ul> <li> <p> <code>BisectBatchOnFunctionError</code> - If the function returns an error, split the batch in two and retry.</p> </li> <li> <p> <code>DestinationConfig</code> - Send discarded records to an Amazon SQS queue or Amazon SNS topic.</p> </li> <li> <p> <code>MaximumRecordAgeInSeconds</code> - Discard records older than the specified age.</p> </li> <li> <p> <code>MaximumRetryAttempts</code> - Discard records after the specified number of retries.</p> </li> <li> <p> <code>ParallelizationFactor</code> - Process multiple batches from each shard concurrently.</p> </li> </ul></p>
-    async fn create_event_source_mapping(
+    /// Presigns the request `create_event_source_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_event_source_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEventSourceMappingRequest,
-    ) -> Result<EventSourceMappingConfiguration, RusotoError<CreateEventSourceMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_event_source_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_event_source_mapping_request(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_function`, also returning the metadata of the response.
+    pub async fn create_function_with_metadata(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> Result<(FunctionConfiguration, ResponseMetadata), RusotoError<CreateFunctionError>> {
+        let request = self.build_create_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<EventSourceMappingConfiguration, _>()?;
+                .deserialize::<FunctionConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEventSourceMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateFunctionError::from_response(response))
         }
     }
 
-    /// <p>Creates a Lambda function. To create a function, you need a <a href="https://docs.aws.amazon.com/lambda/latest/dg/deployment-package-v2.html">deployment package</a> and an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-permission-model.html#lambda-intro-execution-role">execution role</a>. The deployment package contains your function code. The execution role grants the function permission to use AWS services, such as Amazon CloudWatch Logs for log streaming and AWS X-Ray for request tracing.</p> <p>When you create a function, Lambda provisions an instance of the function and its supporting resources. If your function connects to a VPC, this process can take a minute or so. During this time, you can't invoke or modify the function. The <code>State</code>, <code>StateReason</code>, and <code>StateReasonCode</code> fields in the response from <a>GetFunctionConfiguration</a> indicate when the function is ready to invoke. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/functions-states.html">Function States</a>.</p> <p>A function has an unpublished version, and can have published versions and aliases. The unpublished version changes when you update your function's code and configuration. A published version is a snapshot of your function code and configuration that can't be changed. An alias is a named resource that maps to a version, and can be changed to map to a different version. Use the <code>Publish</code> parameter to create version <code>1</code> of your function from its initial configuration.</p> <p>The other parameters let you configure version-specific and function-level settings. You can modify version-specific settings later with <a>UpdateFunctionConfiguration</a>. Function-level settings apply to both the unpublished and published versions of the function, and include tags (<a>TagResource</a>) and per-function concurrency limits (<a>PutFunctionConcurrency</a>).</p> <p>If another account or an AWS service invokes your function, use <a>AddPermission</a> to grant permission by creating a resource-based IAM policy. You can grant permissions at the function level, on a version, or on an alias.</p> <p>To invoke your function directly, use <a>Invoke</a>. To invoke your function in response to events in other AWS services, create an event source mapping (<a>CreateEventSourceMapping</a>), or configure a function trigger in the other service. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-invocation.html">Invoking Functions</a>.</p>
-    async fn create_function(
+    /// Presigns the request `create_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFunctionRequest,
-    ) -> Result<FunctionConfiguration, RusotoError<CreateFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_function_request(&self, input: CreateFunctionRequest) -> SignedRequest {
         let request_uri = "/2015-03-31/functions";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `delete_alias`, also returning the metadata of the response.
+    pub async fn delete_alias_with_metadata(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAliasError>> {
+        let request = self.build_delete_alias_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FunctionConfiguration, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFunctionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAliasError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
-    async fn delete_alias(
+    /// Presigns the request `delete_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAliasRequest,
-    ) -> Result<(), RusotoError<DeleteAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_alias_request(&self, input: DeleteAliasRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/aliases/{name}",
             function_name = input.function_name,
@@ -5414,65 +6154,120 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_event_source_mapping`, also returning the metadata of the response.
+    pub async fn delete_event_source_mapping_with_metadata(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> Result<
+        (EventSourceMappingConfiguration, ResponseMetadata),
+        RusotoError<DeleteEventSourceMappingError>,
+    > {
+        let request = self.build_delete_event_source_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EventSourceMappingConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteEventSourceMappingError::from_response(response))
         }
     }
 
-    /// <p>Deletes an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-invocation-modes.html">event source mapping</a>. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p> <p>When you delete an event source mapping, it enters a <code>Deleting</code> state and might not be completely deleted for several seconds.</p>
-    async fn delete_event_source_mapping(
+    /// Presigns the request `delete_event_source_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_event_source_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEventSourceMappingRequest,
-    ) -> Result<EventSourceMappingConfiguration, RusotoError<DeleteEventSourceMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_event_source_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_event_source_mapping_request(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/event-source-mappings/{uuid}",
             uuid = input.uuid
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_function`, also returning the metadata of the response.
+    pub async fn delete_function_with_metadata(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteFunctionError>> {
+        let request = self.build_delete_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<EventSourceMappingConfiguration, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEventSourceMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFunctionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Lambda function. To delete a specific function version, use the <code>Qualifier</code> parameter. Otherwise, all versions and aliases are deleted.</p> <p>To delete Lambda event source mappings that invoke a function, use <a>DeleteEventSourceMapping</a>. For AWS services and resources that invoke your function directly, delete the trigger in the service where you originally configured it.</p>
-    async fn delete_function(
+    /// Presigns the request `delete_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFunctionRequest,
-    ) -> Result<(), RusotoError<DeleteFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_function_request(&self, input: DeleteFunctionRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5481,62 +6276,118 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_function_concurrency`, also returning the metadata of the response.
+    pub async fn delete_function_concurrency_with_metadata(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteFunctionConcurrencyError>> {
+        let request = self.build_delete_function_concurrency_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFunctionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFunctionConcurrencyError::from_response(response))
         }
     }
 
-    /// <p>Removes a concurrent execution limit from a function.</p>
-    async fn delete_function_concurrency(
+    /// Presigns the request `delete_function_concurrency` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_function_concurrency(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFunctionConcurrencyRequest,
-    ) -> Result<(), RusotoError<DeleteFunctionConcurrencyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_function_concurrency_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_function_concurrency_request(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2017-10-31/functions/{function_name}/concurrency",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_function_event_invoke_config`, also returning the metadata of the response.
+    pub async fn delete_function_event_invoke_config_with_metadata(
+        &self,
+        input: DeleteFunctionEventInvokeConfigRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteFunctionEventInvokeConfigError>> {
+        let request = self.build_delete_function_event_invoke_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFunctionConcurrencyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFunctionEventInvokeConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes the configuration for asynchronous invocation for a function, version, or alias.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
-    async fn delete_function_event_invoke_config(
+    /// Presigns the request `delete_function_event_invoke_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_function_event_invoke_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFunctionEventInvokeConfigRequest,
-    ) -> Result<(), RusotoError<DeleteFunctionEventInvokeConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_function_event_invoke_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_function_event_invoke_config_request(
+        &self,
+        input: DeleteFunctionEventInvokeConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-25/functions/{function_name}/event-invoke-config",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5545,29 +6396,53 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_layer_version`, also returning the metadata of the response.
+    pub async fn delete_layer_version_with_metadata(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteLayerVersionError>> {
+        let request = self.build_delete_layer_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFunctionEventInvokeConfigError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteLayerVersionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Deleted versions can no longer be viewed or added to functions. To avoid breaking functions, a copy of the version remains in Lambda until no functions refer to it.</p>
-    async fn delete_layer_version(
+    /// Presigns the request `delete_layer_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_layer_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLayerVersionRequest,
-    ) -> Result<(), RusotoError<DeleteLayerVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_layer_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_layer_version_request(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2018-10-31/layers/{layer_name}/versions/{version_number}",
             layer_name = input.layer_name,
@@ -5575,90 +6450,166 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_provisioned_concurrency_config`, also returning the metadata of the response.
+    pub async fn delete_provisioned_concurrency_config_with_metadata(
+        &self,
+        input: DeleteProvisionedConcurrencyConfigRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteProvisionedConcurrencyConfigError>> {
+        let request = self.build_delete_provisioned_concurrency_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLayerVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteProvisionedConcurrencyConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes the provisioned concurrency configuration for a function.</p>
-    async fn delete_provisioned_concurrency_config(
+    /// Presigns the request `delete_provisioned_concurrency_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_provisioned_concurrency_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProvisionedConcurrencyConfigRequest,
-    ) -> Result<(), RusotoError<DeleteProvisionedConcurrencyConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_provisioned_concurrency_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_provisioned_concurrency_config_request(
+        &self,
+        input: DeleteProvisionedConcurrencyConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-30/functions/{function_name}/provisioned-concurrency",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("Qualifier", &input.qualifier);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_account_settings`, also returning the metadata of the response.
+    pub async fn get_account_settings_with_metadata(
+        &self,
+    ) -> Result<(GetAccountSettingsResponse, ResponseMetadata), RusotoError<GetAccountSettingsError>>
+    {
+        let request = self.build_get_account_settings_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAccountSettingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProvisionedConcurrencyConfigError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAccountSettingsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves details about your account's <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limits</a> and usage in an AWS Region.</p>
-    async fn get_account_settings(
+    /// Presigns the request `get_account_settings` would send with `presigner` instead of sending it.
+    pub async fn presign_get_account_settings(
         &self,
-    ) -> Result<GetAccountSettingsResponse, RusotoError<GetAccountSettingsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_account_settings_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_account_settings_request(&self) -> SignedRequest {
         let request_uri = "/2016-08-19/account-settings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_alias`, also returning the metadata of the response.
+    pub async fn get_alias_with_metadata(
+        &self,
+        input: GetAliasRequest,
+    ) -> Result<(AliasConfiguration, ResponseMetadata), RusotoError<GetAliasError>> {
+        let request = self.build_get_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAccountSettingsResponse, _>()?;
+                .deserialize::<AliasConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAccountSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAliasError::from_response(response))
         }
     }
 
-    /// <p>Returns details about a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
-    async fn get_alias(
+    /// Presigns the request `get_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAliasRequest,
-    ) -> Result<AliasConfiguration, RusotoError<GetAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_alias_request(&self, input: GetAliasRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/aliases/{name}",
             function_name = input.function_name,
@@ -5666,66 +6617,121 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_event_source_mapping`, also returning the metadata of the response.
+    pub async fn get_event_source_mapping_with_metadata(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> Result<
+        (EventSourceMappingConfiguration, ResponseMetadata),
+        RusotoError<GetEventSourceMappingError>,
+    > {
+        let request = self.build_get_event_source_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AliasConfiguration, _>()?;
+                .deserialize::<EventSourceMappingConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetEventSourceMappingError::from_response(response))
         }
     }
 
-    /// <p>Returns details about an event source mapping. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p>
-    async fn get_event_source_mapping(
+    /// Presigns the request `get_event_source_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_event_source_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEventSourceMappingRequest,
-    ) -> Result<EventSourceMappingConfiguration, RusotoError<GetEventSourceMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_event_source_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_event_source_mapping_request(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/event-source-mappings/{uuid}",
             uuid = input.uuid
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_function`, also returning the metadata of the response.
+    pub async fn get_function_with_metadata(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<(GetFunctionResponse, ResponseMetadata), RusotoError<GetFunctionError>> {
+        let request = self.build_get_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<EventSourceMappingConfiguration, _>()?;
+                .deserialize::<GetFunctionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEventSourceMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFunctionError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the function or function version, with a link to download the deployment package that's valid for 10 minutes. If you specify a function version, only details that are specific to that version are returned.</p>
-    async fn get_function(
+    /// Presigns the request `get_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFunctionRequest,
-    ) -> Result<GetFunctionResponse, RusotoError<GetFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_function_request(&self, input: GetFunctionRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5734,64 +6740,122 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_function_concurrency`, also returning the metadata of the response.
+    pub async fn get_function_concurrency_with_metadata(
+        &self,
+        input: GetFunctionConcurrencyRequest,
+    ) -> Result<
+        (GetFunctionConcurrencyResponse, ResponseMetadata),
+        RusotoError<GetFunctionConcurrencyError>,
+    > {
+        let request = self.build_get_function_concurrency_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFunctionResponse, _>()?;
+                .deserialize::<GetFunctionConcurrencyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFunctionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFunctionConcurrencyError::from_response(response))
         }
     }
 
-    /// <p>Returns details about the concurrency configuration for a function. To set a concurrency limit for a function, use <a>PutFunctionConcurrency</a>.</p>
-    async fn get_function_concurrency(
+    /// Presigns the request `get_function_concurrency` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_function_concurrency(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFunctionConcurrencyRequest,
-    ) -> Result<GetFunctionConcurrencyResponse, RusotoError<GetFunctionConcurrencyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_function_concurrency_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_function_concurrency_request(
+        &self,
+        input: GetFunctionConcurrencyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-30/functions/{function_name}/concurrency",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_function_configuration`, also returning the metadata of the response.
+    pub async fn get_function_configuration_with_metadata(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<(FunctionConfiguration, ResponseMetadata), RusotoError<GetFunctionConfigurationError>>
+    {
+        let request = self.build_get_function_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFunctionConcurrencyResponse, _>()?;
+                .deserialize::<FunctionConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFunctionConcurrencyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFunctionConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Returns the version-specific settings of a Lambda function or version. The output includes only options that can vary between versions of a function. To modify these settings, use <a>UpdateFunctionConfiguration</a>.</p> <p>To get all of a function's details, including function-level settings, use <a>GetFunction</a>.</p>
-    async fn get_function_configuration(
+    /// Presigns the request `get_function_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_function_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFunctionConfigurationRequest,
-    ) -> Result<FunctionConfiguration, RusotoError<GetFunctionConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_function_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_function_configuration_request(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/configuration",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5800,34 +6864,64 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_function_event_invoke_config`, also returning the metadata of the response.
+    pub async fn get_function_event_invoke_config_with_metadata(
+        &self,
+        input: GetFunctionEventInvokeConfigRequest,
+    ) -> Result<
+        (FunctionEventInvokeConfig, ResponseMetadata),
+        RusotoError<GetFunctionEventInvokeConfigError>,
+    > {
+        let request = self.build_get_function_event_invoke_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FunctionConfiguration, _>()?;
+                .deserialize::<FunctionEventInvokeConfig, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFunctionConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFunctionEventInvokeConfigError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the configuration for asynchronous invocation for a function, version, or alias.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
-    async fn get_function_event_invoke_config(
+    /// Presigns the request `get_function_event_invoke_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_function_event_invoke_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFunctionEventInvokeConfigRequest,
-    ) -> Result<FunctionEventInvokeConfig, RusotoError<GetFunctionEventInvokeConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_function_event_invoke_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_function_event_invoke_config_request(
+        &self,
+        input: GetFunctionEventInvokeConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-25/functions/{function_name}/event-invoke-config",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5836,28 +6930,52 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_layer_version`, also returning the metadata of the response.
+    pub async fn get_layer_version_with_metadata(
+        &self,
+        input: GetLayerVersionRequest,
+    ) -> Result<(GetLayerVersionResponse, ResponseMetadata), RusotoError<GetLayerVersionError>>
+    {
+        let request = self.build_get_layer_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FunctionEventInvokeConfig, _>()?;
+                .deserialize::<GetLayerVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFunctionEventInvokeConfigError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLayerVersionError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
-    async fn get_layer_version(
+    /// Presigns the request `get_layer_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_layer_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLayerVersionRequest,
-    ) -> Result<GetLayerVersionResponse, RusotoError<GetLayerVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_layer_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_layer_version_request(&self, input: GetLayerVersionRequest) -> SignedRequest {
         let request_uri = format!(
             "/2018-10-31/layers/{layer_name}/versions/{version_number}",
             layer_name = input.layer_name,
@@ -5865,33 +6983,62 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `get_layer_version_by_arn`, also returning the metadata of the response.
+    pub async fn get_layer_version_by_arn_with_metadata(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> Result<(GetLayerVersionResponse, ResponseMetadata), RusotoError<GetLayerVersionByArnError>>
+    {
+        let request = self.build_get_layer_version_by_arn_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetLayerVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLayerVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLayerVersionByArnError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
-    async fn get_layer_version_by_arn(
+    /// Presigns the request `get_layer_version_by_arn` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_layer_version_by_arn(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLayerVersionByArnRequest,
-    ) -> Result<GetLayerVersionResponse, RusotoError<GetLayerVersionByArnError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_layer_version_by_arn_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_layer_version_by_arn_request(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> SignedRequest {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionByArn");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5899,28 +7046,57 @@ impl Lambda for LambdaClient {
         params.put("find", "LayerVersion");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_layer_version_policy`, also returning the metadata of the response.
+    pub async fn get_layer_version_policy_with_metadata(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> Result<
+        (GetLayerVersionPolicyResponse, ResponseMetadata),
+        RusotoError<GetLayerVersionPolicyError>,
+    > {
+        let request = self.build_get_layer_version_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLayerVersionResponse, _>()?;
+                .deserialize::<GetLayerVersionPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLayerVersionByArnError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLayerVersionPolicyError::from_response(response))
         }
     }
 
-    /// <p>Returns the permission policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
-    async fn get_layer_version_policy(
+    /// Presigns the request `get_layer_version_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_layer_version_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLayerVersionPolicyRequest,
-    ) -> Result<GetLayerVersionPolicyResponse, RusotoError<GetLayerVersionPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_layer_version_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_layer_version_policy_request(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2018-10-31/layers/{layer_name}/versions/{version_number}/policy",
             layer_name = input.layer_name,
@@ -5928,36 +7104,61 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_policy`, also returning the metadata of the response.
+    pub async fn get_policy_with_metadata(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<(GetPolicyResponse, ResponseMetadata), RusotoError<GetPolicyError>> {
+        let request = self.build_get_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLayerVersionPolicyResponse, _>()?;
+                .deserialize::<GetPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLayerVersionPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetPolicyError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">resource-based IAM policy</a> for a function, version, or alias.</p>
-    async fn get_policy(
+    /// Presigns the request `get_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPolicyRequest,
-    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_policy_request(&self, input: GetPolicyRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/policy",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5966,73 +7167,138 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_provisioned_concurrency_config`, also returning the metadata of the response.
+    pub async fn get_provisioned_concurrency_config_with_metadata(
+        &self,
+        input: GetProvisionedConcurrencyConfigRequest,
+    ) -> Result<
+        (GetProvisionedConcurrencyConfigResponse, ResponseMetadata),
+        RusotoError<GetProvisionedConcurrencyConfigError>,
+    > {
+        let request = self.build_get_provisioned_concurrency_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPolicyResponse, _>()?;
+                .deserialize::<GetProvisionedConcurrencyConfigResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetProvisionedConcurrencyConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the provisioned concurrency configuration for a function's alias or version.</p>
-    async fn get_provisioned_concurrency_config(
+    /// Presigns the request `get_provisioned_concurrency_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_provisioned_concurrency_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetProvisionedConcurrencyConfigRequest,
-    ) -> Result<
-        GetProvisionedConcurrencyConfigResponse,
-        RusotoError<GetProvisionedConcurrencyConfigError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_provisioned_concurrency_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_provisioned_concurrency_config_request(
+        &self,
+        input: GetProvisionedConcurrencyConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-30/functions/{function_name}/provisioned-concurrency",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("Qualifier", &input.qualifier);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `invoke`, also returning the metadata of the response.
+    pub async fn invoke_with_metadata(
+        &self,
+        input: InvocationRequest,
+    ) -> Result<(InvocationResponse, ResponseMetadata), RusotoError<InvokeError>> {
+        let request = self.build_invoke_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetProvisionedConcurrencyConfigResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
 
-            Ok(result)
+            let mut result = InvocationResponse::default();
+            result.payload = Some(response.body);
+
+            if let Some(executed_version) = response.headers.get("X-Amz-Executed-Version") {
+                let value = executed_version.to_owned();
+                result.executed_version = Some(value)
+            };
+            if let Some(function_error) = response.headers.get("X-Amz-Function-Error") {
+                let value = function_error.to_owned();
+                result.function_error = Some(value)
+            };
+            if let Some(log_result) = response.headers.get("X-Amz-Log-Result") {
+                let value = log_result.to_owned();
+                result.log_result = Some(value)
+            };
+            result.status_code = Some(response.status.as_u16() as i64);
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetProvisionedConcurrencyConfigError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(InvokeError::from_response(response))
         }
     }
 
-    /// <p>Invokes a Lambda function. You can invoke a function synchronously (and wait for the response), or asynchronously. To invoke a function asynchronously, set <code>InvocationType</code> to <code>Event</code>.</p> <p>For <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-sync.html">synchronous invocation</a>, details about the function response, including errors, are included in the response body and headers. For either invocation type, you can find more information in the <a href="https://docs.aws.amazon.com/lambda/latest/dg/monitoring-functions.html">execution log</a> and <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-x-ray.html">trace</a>.</p> <p>When an error occurs, your function may be invoked multiple times. Retry behavior varies by error type, client, event source, and invocation type. For example, if you invoke a function asynchronously and it returns an error, Lambda executes the function up to two more times. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/retries-on-errors.html">Retry Behavior</a>.</p> <p>For <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html">asynchronous invocation</a>, Lambda adds events to a queue before sending them to your function. If your function does not have enough capacity to keep up with the queue, events may be lost. Occasionally, your function may receive the same event multiple times, even if no error occurs. To retain events that were not processed, configure your function with a <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html#dlq">dead-letter queue</a>.</p> <p>The status code in the API response doesn't reflect function errors. Error codes are reserved for errors that prevent your function from executing, such as permissions errors, <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limit errors</a>, or issues with your function's code and configuration. For example, Lambda returns <code>TooManyRequestsException</code> if executing the function would cause you to exceed a concurrency limit at either the account level (<code>ConcurrentInvocationLimitExceeded</code>) or function level (<code>ReservedFunctionConcurrentInvocationLimitExceeded</code>).</p> <p>For functions with a long timeout, your client might be disconnected during synchronous invocation while it waits for a response. Configure your HTTP client, SDK, firewall, proxy, or operating system to allow for long connections with timeout or keep-alive settings.</p> <p>This operation requires permission for the <code>lambda:InvokeFunction</code> action.</p>
-    async fn invoke(
+    /// Presigns the request `invoke` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_invoke(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InvocationRequest,
-    ) -> Result<InvocationResponse, RusotoError<InvokeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_invoke_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_invoke_request(&self, input: InvocationRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/invocations",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("Invoke");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = if let Some(ref payload) = input.payload {
@@ -6059,82 +7325,116 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `invoke_async`, also returning the metadata of the response.
+    pub async fn invoke_async_with_metadata(
+        &self,
+        input: InvokeAsyncRequest,
+    ) -> Result<(InvokeAsyncResponse, ResponseMetadata), RusotoError<InvokeAsyncError>> {
+        let request = self.build_invoke_async_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-
-            let mut result = InvocationResponse::default();
-            result.payload = Some(response.body);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let mut result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<InvokeAsyncResponse, _>()?;
 
-            if let Some(executed_version) = response.headers.get("X-Amz-Executed-Version") {
-                let value = executed_version.to_owned();
-                result.executed_version = Some(value)
-            };
-            if let Some(function_error) = response.headers.get("X-Amz-Function-Error") {
-                let value = function_error.to_owned();
-                result.function_error = Some(value)
-            };
-            if let Some(log_result) = response.headers.get("X-Amz-Log-Result") {
-                let value = log_result.to_owned();
-                result.log_result = Some(value)
-            };
-            result.status_code = Some(response.status.as_u16() as i64);
-            Ok(result)
+            result.status = Some(response.status.as_u16() as i64);
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(InvokeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(InvokeAsyncError::from_response(response))
         }
     }
 
-    /// <p><important> <p>For asynchronous function invocation, use <a>Invoke</a>.</p> </important> <p>Invokes a function asynchronously.</p></p>
-    async fn invoke_async(
+    /// Presigns the request `invoke_async` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_invoke_async(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InvokeAsyncRequest,
-    ) -> Result<InvokeAsyncResponse, RusotoError<InvokeAsyncError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_invoke_async_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_invoke_async_request(&self, input: InvokeAsyncRequest) -> SignedRequest {
         let request_uri = format!(
             "/2014-11-13/functions/{function_name}/invoke-async/",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("InvokeAsync");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.invoke_args.to_owned());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_aliases`, also returning the metadata of the response.
+    pub async fn list_aliases_with_metadata(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<(ListAliasesResponse, ResponseMetadata), RusotoError<ListAliasesError>> {
+        let request = self.build_list_aliases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<InvokeAsyncResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAliasesResponse, _>()?;
 
-            result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(InvokeAsyncError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAliasesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">aliases</a> for a Lambda function.</p>
-    async fn list_aliases(
+    /// Presigns the request `list_aliases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_aliases(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAliasesRequest,
-    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_aliases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_aliases_request(&self, input: ListAliasesRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/aliases",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6149,31 +7449,61 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_event_source_mappings`, also returning the metadata of the response.
+    pub async fn list_event_source_mappings_with_metadata(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> Result<
+        (ListEventSourceMappingsResponse, ResponseMetadata),
+        RusotoError<ListEventSourceMappingsError>,
+    > {
+        let request = self.build_list_event_source_mappings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAliasesResponse, _>()?;
+                .deserialize::<ListEventSourceMappingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAliasesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListEventSourceMappingsError::from_response(response))
         }
     }
 
-    /// <p>Lists event source mappings. Specify an <code>EventSourceArn</code> to only show event source mappings for a single event source.</p>
-    async fn list_event_source_mappings(
+    /// Presigns the request `list_event_source_mappings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_event_source_mappings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEventSourceMappingsRequest,
-    ) -> Result<ListEventSourceMappingsResponse, RusotoError<ListEventSourceMappingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_event_source_mappings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_event_source_mappings_request(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListEventSourceMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6191,37 +7521,64 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_function_event_invoke_configs`, also returning the metadata of the response.
+    pub async fn list_function_event_invoke_configs_with_metadata(
+        &self,
+        input: ListFunctionEventInvokeConfigsRequest,
+    ) -> Result<
+        (ListFunctionEventInvokeConfigsResponse, ResponseMetadata),
+        RusotoError<ListFunctionEventInvokeConfigsError>,
+    > {
+        let request = self.build_list_function_event_invoke_configs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEventSourceMappingsResponse, _>()?;
+                .deserialize::<ListFunctionEventInvokeConfigsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEventSourceMappingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFunctionEventInvokeConfigsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of configurations for asynchronous invocation for a function.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
-    async fn list_function_event_invoke_configs(
+    /// Presigns the request `list_function_event_invoke_configs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_function_event_invoke_configs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFunctionEventInvokeConfigsRequest,
-    ) -> Result<
-        ListFunctionEventInvokeConfigsResponse,
-        RusotoError<ListFunctionEventInvokeConfigsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_function_event_invoke_configs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_function_event_invoke_configs_request(
+        &self,
+        input: ListFunctionEventInvokeConfigsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-25/functions/{function_name}/event-invoke-config/list",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctionEventInvokeConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6233,31 +7590,55 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_functions`, also returning the metadata of the response.
+    pub async fn list_functions_with_metadata(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> Result<(ListFunctionsResponse, ResponseMetadata), RusotoError<ListFunctionsError>> {
+        let request = self.build_list_functions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFunctionEventInvokeConfigsResponse, _>()?;
+                .deserialize::<ListFunctionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFunctionEventInvokeConfigsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFunctionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of Lambda functions, with the version-specific configuration of each.</p> <p>Set <code>FunctionVersion</code> to <code>ALL</code> to include all published versions of each function in addition to the unpublished version. To get more information about a function or version, use <a>GetFunction</a>.</p>
-    async fn list_functions(
+    /// Presigns the request `list_functions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_functions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFunctionsRequest,
-    ) -> Result<ListFunctionsResponse, RusotoError<ListFunctionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_functions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_functions_request(&self, input: ListFunctionsRequest) -> SignedRequest {
         let request_uri = "/2015-03-31/functions/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6275,34 +7656,59 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_layer_versions`, also returning the metadata of the response.
+    pub async fn list_layer_versions_with_metadata(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> Result<(ListLayerVersionsResponse, ResponseMetadata), RusotoError<ListLayerVersionsError>>
+    {
+        let request = self.build_list_layer_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFunctionsResponse, _>()?;
+                .deserialize::<ListLayerVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFunctionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListLayerVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the versions of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Versions that have been deleted aren't listed. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only versions that indicate that they're compatible with that runtime.</p>
-    async fn list_layer_versions(
+    /// Presigns the request `list_layer_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_layer_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLayerVersionsRequest,
-    ) -> Result<ListLayerVersionsResponse, RusotoError<ListLayerVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_layer_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_layer_versions_request(&self, input: ListLayerVersionsRequest) -> SignedRequest {
         let request_uri = format!(
             "/2018-10-31/layers/{layer_name}/versions",
             layer_name = input.layer_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayerVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6317,31 +7723,55 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_layers`, also returning the metadata of the response.
+    pub async fn list_layers_with_metadata(
+        &self,
+        input: ListLayersRequest,
+    ) -> Result<(ListLayersResponse, ResponseMetadata), RusotoError<ListLayersError>> {
+        let request = self.build_list_layers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLayerVersionsResponse, _>()?;
+                .deserialize::<ListLayersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLayerVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListLayersError::from_response(response))
         }
     }
 
-    /// <p>Lists <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layers</a> and shows information about the latest version of each. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only layers that indicate that they're compatible with that runtime.</p>
-    async fn list_layers(
+    /// Presigns the request `list_layers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_layers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLayersRequest,
-    ) -> Result<ListLayersResponse, RusotoError<ListLayersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_layers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_layers_request(&self, input: ListLayersRequest) -> SignedRequest {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6356,37 +7786,66 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_provisioned_concurrency_configs`, also returning the metadata of the response.
+    pub async fn list_provisioned_concurrency_configs_with_metadata(
+        &self,
+        input: ListProvisionedConcurrencyConfigsRequest,
+    ) -> Result<
+        (ListProvisionedConcurrencyConfigsResponse, ResponseMetadata),
+        RusotoError<ListProvisionedConcurrencyConfigsError>,
+    > {
+        let request = self.build_list_provisioned_concurrency_configs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLayersResponse, _>()?;
+                .deserialize::<ListProvisionedConcurrencyConfigsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLayersError::from_response(response))
-        }
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListProvisionedConcurrencyConfigsError::from_response(
+                response,
+            ))
+        }
     }
 
-    /// <p>Retrieves a list of provisioned concurrency configurations for a function.</p>
-    async fn list_provisioned_concurrency_configs(
+    /// Presigns the request `list_provisioned_concurrency_configs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_provisioned_concurrency_configs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProvisionedConcurrencyConfigsRequest,
-    ) -> Result<
-        ListProvisionedConcurrencyConfigsResponse,
-        RusotoError<ListProvisionedConcurrencyConfigsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_provisioned_concurrency_configs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_provisioned_concurrency_configs_request(
+        &self,
+        input: ListProvisionedConcurrencyConfigsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-30/functions/{function_name}/provisioned-concurrency",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListProvisionedConcurrencyConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6399,63 +7858,115 @@ impl Lambda for LambdaClient {
         params.put("List", "ALL");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<(ListTagsResponse, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProvisionedConcurrencyConfigsResponse, _>()?;
+                .deserialize::<ListTagsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProvisionedConcurrencyConfigsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Returns a function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a>. You can also view tags with <a>GetFunction</a>.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsRequest,
-    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsRequest) -> SignedRequest {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_versions_by_function`, also returning the metadata of the response.
+    pub async fn list_versions_by_function_with_metadata(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> Result<
+        (ListVersionsByFunctionResponse, ResponseMetadata),
+        RusotoError<ListVersionsByFunctionError>,
+    > {
+        let request = self.build_list_versions_by_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsResponse, _>()?;
+                .deserialize::<ListVersionsByFunctionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListVersionsByFunctionError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">versions</a>, with the version-specific configuration of each. </p>
-    async fn list_versions_by_function(
+    /// Presigns the request `list_versions_by_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_versions_by_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVersionsByFunctionRequest,
-    ) -> Result<ListVersionsByFunctionResponse, RusotoError<ListVersionsByFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_versions_by_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_versions_by_function_request(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/versions",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListVersionsByFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6467,133 +7978,244 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `publish_layer_version`, also returning the metadata of the response.
+    pub async fn publish_layer_version_with_metadata(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> Result<
+        (PublishLayerVersionResponse, ResponseMetadata),
+        RusotoError<PublishLayerVersionError>,
+    > {
+        let request = self.build_publish_layer_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVersionsByFunctionResponse, _>()?;
+                .deserialize::<PublishLayerVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVersionsByFunctionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PublishLayerVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a> from a ZIP archive. Each time you call <code>PublishLayerVersion</code> with the same layer name, a new version is created.</p> <p>Add layers to your function with <a>CreateFunction</a> or <a>UpdateFunctionConfiguration</a>.</p>
-    async fn publish_layer_version(
+    /// Presigns the request `publish_layer_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_publish_layer_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PublishLayerVersionRequest,
-    ) -> Result<PublishLayerVersionResponse, RusotoError<PublishLayerVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_publish_layer_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_publish_layer_version_request(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2018-10-31/layers/{layer_name}/versions",
             layer_name = input.layer_name
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `publish_version`, also returning the metadata of the response.
+    pub async fn publish_version_with_metadata(
+        &self,
+        input: PublishVersionRequest,
+    ) -> Result<(FunctionConfiguration, ResponseMetadata), RusotoError<PublishVersionError>> {
+        let request = self.build_publish_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PublishLayerVersionResponse, _>()?;
+                .deserialize::<FunctionConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PublishLayerVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PublishVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">version</a> from the current code and configuration of a function. Use versions to create a snapshot of your function code and configuration that doesn't change.</p> <p>AWS Lambda doesn't publish a version if the function's configuration and code haven't changed since the last version. Use <a>UpdateFunctionCode</a> or <a>UpdateFunctionConfiguration</a> to update the function before publishing a version.</p> <p>Clients can invoke versions directly or with an alias. To create an alias, use <a>CreateAlias</a>.</p>
-    async fn publish_version(
+    /// Presigns the request `publish_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_publish_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PublishVersionRequest,
-    ) -> Result<FunctionConfiguration, RusotoError<PublishVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_publish_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_publish_version_request(&self, input: PublishVersionRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/versions",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_function_concurrency`, also returning the metadata of the response.
+    pub async fn put_function_concurrency_with_metadata(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> Result<(Concurrency, ResponseMetadata), RusotoError<PutFunctionConcurrencyError>> {
+        let request = self.build_put_function_concurrency_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FunctionConfiguration, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Concurrency, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PublishVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutFunctionConcurrencyError::from_response(response))
         }
     }
 
-    /// <p>Sets the maximum number of simultaneous executions for a function, and reserves capacity for that concurrency level.</p> <p>Concurrency settings apply to the function as a whole, including all published versions and the unpublished version. Reserving concurrency both ensures that your function has capacity to process the specified number of events simultaneously, and prevents it from scaling beyond that level. Use <a>GetFunction</a> to see the current setting for a function.</p> <p>Use <a>GetAccountSettings</a> to see your Regional concurrency limit. You can reserve concurrency for as many functions as you like, as long as you leave at least 100 simultaneous executions unreserved for functions that aren't configured with a per-function limit. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/concurrent-executions.html">Managing Concurrency</a>.</p>
-    async fn put_function_concurrency(
+    /// Presigns the request `put_function_concurrency` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_function_concurrency(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutFunctionConcurrencyRequest,
-    ) -> Result<Concurrency, RusotoError<PutFunctionConcurrencyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_function_concurrency_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_function_concurrency_request(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2017-10-31/functions/{function_name}/concurrency",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_function_event_invoke_config`, also returning the metadata of the response.
+    pub async fn put_function_event_invoke_config_with_metadata(
+        &self,
+        input: PutFunctionEventInvokeConfigRequest,
+    ) -> Result<
+        (FunctionEventInvokeConfig, ResponseMetadata),
+        RusotoError<PutFunctionEventInvokeConfigError>,
+    > {
+        let request = self.build_put_function_event_invoke_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Concurrency, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<FunctionEventInvokeConfig, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutFunctionConcurrencyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutFunctionEventInvokeConfigError::from_response(response))
         }
     }
 
-    /// <p>Configures options for <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html">asynchronous invocation</a> on a function, version, or alias.</p> <p>By default, Lambda retries an asynchronous invocation twice if the function returns an error. It retains events in a queue for up to six hours. When an event fails all processing attempts or stays in the asynchronous invocation queue for too long, Lambda discards it. To retain discarded events, configure a dead-letter queue with <a>UpdateFunctionConfiguration</a>.</p>
-    async fn put_function_event_invoke_config(
+    /// Presigns the request `put_function_event_invoke_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_function_event_invoke_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutFunctionEventInvokeConfigRequest,
-    ) -> Result<FunctionEventInvokeConfig, RusotoError<PutFunctionEventInvokeConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_function_event_invoke_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_function_event_invoke_config_request(
+        &self,
+        input: PutFunctionEventInvokeConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-25/functions/{function_name}/event-invoke-config",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6605,37 +8227,66 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_provisioned_concurrency_config`, also returning the metadata of the response.
+    pub async fn put_provisioned_concurrency_config_with_metadata(
+        &self,
+        input: PutProvisionedConcurrencyConfigRequest,
+    ) -> Result<
+        (PutProvisionedConcurrencyConfigResponse, ResponseMetadata),
+        RusotoError<PutProvisionedConcurrencyConfigError>,
+    > {
+        let request = self.build_put_provisioned_concurrency_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FunctionEventInvokeConfig, _>()?;
+                .deserialize::<PutProvisionedConcurrencyConfigResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutFunctionEventInvokeConfigError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutProvisionedConcurrencyConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Adds a provisioned concurrency configuration to a function's alias or version.</p>
-    async fn put_provisioned_concurrency_config(
+    /// Presigns the request `put_provisioned_concurrency_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_provisioned_concurrency_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutProvisionedConcurrencyConfigRequest,
-    ) -> Result<
-        PutProvisionedConcurrencyConfigResponse,
-        RusotoError<PutProvisionedConcurrencyConfigError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_provisioned_concurrency_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_provisioned_concurrency_config_request(
+        &self,
+        input: PutProvisionedConcurrencyConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-30/functions/{function_name}/provisioned-concurrency",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6645,30 +8296,53 @@ impl Lambda for LambdaClient {
         params.put("Qualifier", &input.qualifier);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `remove_layer_version_permission`, also returning the metadata of the response.
+    pub async fn remove_layer_version_permission_with_metadata(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveLayerVersionPermissionError>> {
+        let request = self.build_remove_layer_version_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutProvisionedConcurrencyConfigResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutProvisionedConcurrencyConfigError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RemoveLayerVersionPermissionError::from_response(response))
         }
     }
 
-    /// <p>Removes a statement from the permissions policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
-    async fn remove_layer_version_permission(
+    /// Presigns the request `remove_layer_version_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_layer_version_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveLayerVersionPermissionRequest,
-    ) -> Result<(), RusotoError<RemoveLayerVersionPermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_layer_version_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_layer_version_permission_request(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2018-10-31/layers/{layer_name}/versions/{version_number}/policy/{statement_id}",
             layer_name = input.layer_name,
@@ -6677,6 +8351,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemoveLayerVersionPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6685,27 +8360,50 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `remove_permission`, also returning the metadata of the response.
+    pub async fn remove_permission_with_metadata(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemovePermissionError>> {
+        let request = self.build_remove_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveLayerVersionPermissionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RemovePermissionError::from_response(response))
         }
     }
 
-    /// <p>Revokes function-use permission from an AWS service or another account. You can get the ID of the statement from the output of <a>GetPolicy</a>.</p>
-    async fn remove_permission(
+    /// Presigns the request `remove_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemovePermissionRequest,
-    ) -> Result<(), RusotoError<RemovePermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_permission_request(&self, input: RemovePermissionRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/policy/{statement_id}",
             function_name = input.function_name,
@@ -6713,6 +8411,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemovePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6724,59 +8423,107 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RemovePermissionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> to a function.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> from a function.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6785,27 +8532,51 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_alias`, also returning the metadata of the response.
+    pub async fn update_alias_with_metadata(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> Result<(AliasConfiguration, ResponseMetadata), RusotoError<UpdateAliasError>> {
+        let request = self.build_update_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AliasConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateAliasError::from_response(response))
         }
     }
 
-    /// <p>Updates the configuration of a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
-    async fn update_alias(
+    /// Presigns the request `update_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAliasRequest,
-    ) -> Result<AliasConfiguration, RusotoError<UpdateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_alias_request(&self, input: UpdateAliasRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/aliases/{name}",
             function_name = input.function_name,
@@ -6813,138 +8584,259 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_event_source_mapping`, also returning the metadata of the response.
+    pub async fn update_event_source_mapping_with_metadata(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> Result<
+        (EventSourceMappingConfiguration, ResponseMetadata),
+        RusotoError<UpdateEventSourceMappingError>,
+    > {
+        let request = self.build_update_event_source_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AliasConfiguration, _>()?;
+                .deserialize::<EventSourceMappingConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateEventSourceMappingError::from_response(response))
         }
     }
 
-    /// <p><p>Updates an event source mapping. You can change the function that AWS Lambda invokes, or pause invocation and resume later from the same location.</p> <p>The following error handling options are only available for stream sources (DynamoDB and Kinesis):</p> <ul> <li> <p> <code>BisectBatchOnFunctionError</code> - If the function returns an error, split the batch in two and retry.</p> </li> <li> <p> <code>DestinationConfig</code> - Send discarded records to an Amazon SQS queue or Amazon SNS topic.</p> </li> <li> <p> <code>MaximumRecordAgeInSeconds</code> - Discard records older than the specified age.</p> </li> <li> <p> <code>MaximumRetryAttempts</code> - Discard records after the specified number of retries.</p> </li> <li> <p> <code>ParallelizationFactor</code> - Process multiple batches from each shard concurrently.</p> </li> </ul></p>
-    async fn update_event_source_mapping(
+    /// Presigns the request `update_event_source_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_event_source_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEventSourceMappingRequest,
-    ) -> Result<EventSourceMappingConfiguration, RusotoError<UpdateEventSourceMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_event_source_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_event_source_mapping_request(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/event-source-mappings/{uuid}",
             uuid = input.uuid
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_function_code`, also returning the metadata of the response.
+    pub async fn update_function_code_with_metadata(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> Result<(FunctionConfiguration, ResponseMetadata), RusotoError<UpdateFunctionCodeError>>
+    {
+        let request = self.build_update_function_code_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<EventSourceMappingConfiguration, _>()?;
+                .deserialize::<FunctionConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEventSourceMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFunctionCodeError::from_response(response))
         }
     }
 
-    /// <p>Updates a Lambda function's code.</p> <p>The function's code is locked when you publish a version. You can't modify the code of a published version, only the unpublished version.</p>
-    async fn update_function_code(
+    /// Presigns the request `update_function_code` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_function_code(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFunctionCodeRequest,
-    ) -> Result<FunctionConfiguration, RusotoError<UpdateFunctionCodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_function_code_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_function_code_request(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/code",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_function_configuration`, also returning the metadata of the response.
+    pub async fn update_function_configuration_with_metadata(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> Result<
+        (FunctionConfiguration, ResponseMetadata),
+        RusotoError<UpdateFunctionConfigurationError>,
+    > {
+        let request = self.build_update_function_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<FunctionConfiguration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFunctionCodeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFunctionConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Modify the version-specific settings of a Lambda function.</p> <p>When you update a function, Lambda provisions an instance of the function and its supporting resources. If your function connects to a VPC, this process can take a minute. During this time, you can't modify the function, but you can still invoke it. The <code>LastUpdateStatus</code>, <code>LastUpdateStatusReason</code>, and <code>LastUpdateStatusReasonCode</code> fields in the response from <a>GetFunctionConfiguration</a> indicate when the update is complete and the function is processing events with the new configuration. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/functions-states.html">Function States</a>.</p> <p>These settings can vary between versions of a function and are locked when you publish a version. You can't modify the configuration of a published version, only the unpublished version.</p> <p>To configure function concurrency, use <a>PutFunctionConcurrency</a>. To grant invoke permissions to an account or AWS service, use <a>AddPermission</a>.</p>
-    async fn update_function_configuration(
+    /// Presigns the request `update_function_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_function_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFunctionConfigurationRequest,
-    ) -> Result<FunctionConfiguration, RusotoError<UpdateFunctionConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_function_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_function_configuration_request(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-03-31/functions/{function_name}/configuration",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_function_event_invoke_config`, also returning the metadata of the response.
+    pub async fn update_function_event_invoke_config_with_metadata(
+        &self,
+        input: UpdateFunctionEventInvokeConfigRequest,
+    ) -> Result<
+        (FunctionEventInvokeConfig, ResponseMetadata),
+        RusotoError<UpdateFunctionEventInvokeConfigError>,
+    > {
+        let request = self.build_update_function_event_invoke_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FunctionConfiguration, _>()?;
+                .deserialize::<FunctionEventInvokeConfig, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFunctionConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFunctionEventInvokeConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Updates the configuration for asynchronous invocation for a function, version, or alias.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
-    async fn update_function_event_invoke_config(
+    /// Presigns the request `update_function_event_invoke_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_function_event_invoke_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFunctionEventInvokeConfigRequest,
-    ) -> Result<FunctionEventInvokeConfig, RusotoError<UpdateFunctionEventInvokeConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_function_event_invoke_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_function_event_invoke_config_request(
+        &self,
+        input: UpdateFunctionEventInvokeConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2019-09-25/functions/{function_name}/event-invoke-config",
             function_name = input.function_name
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6956,22 +8848,1117 @@ impl Lambda for LambdaClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAliases` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_aliases_pages(
+        &self,
+        input: ListAliasesRequest,
+    ) -> impl futures::Stream<Item = Result<ListAliasesResponse, RusotoError<ListAliasesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input| async move { client.list_aliases(input).await },
+            |input: &ListAliasesRequest, output: &ListAliasesResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListAliasesRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Aliases` of every page of `ListAliases` results for `input`, as `list_aliases_pages` requests them.
+    pub fn list_aliases_stream(
+        &self,
+        input: ListAliasesRequest,
+    ) -> impl futures::Stream<Item = Result<AliasConfiguration, RusotoError<ListAliasesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_aliases_pages(input), |page| {
+            page.aliases.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListEventSourceMappings` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_event_source_mappings_pages(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListEventSourceMappingsResponse, RusotoError<ListEventSourceMappingsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input| async move { client.list_event_source_mappings(input).await },
+            |input: &ListEventSourceMappingsRequest, output: &ListEventSourceMappingsResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListEventSourceMappingsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EventSourceMappings` of every page of `ListEventSourceMappings` results for `input`, as `list_event_source_mappings_pages` requests them.
+    pub fn list_event_source_mappings_stream(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<EventSourceMappingConfiguration, RusotoError<ListEventSourceMappingsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_event_source_mappings_pages(input), |page| {
+            page.event_source_mappings.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFunctionEventInvokeConfigs` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_function_event_invoke_configs_pages(
+        &self,
+        input: ListFunctionEventInvokeConfigsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListFunctionEventInvokeConfigsResponse,
+            RusotoError<ListFunctionEventInvokeConfigsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input| async move {
+                client.list_function_event_invoke_configs(input).await
+            },
+            |input: &ListFunctionEventInvokeConfigsRequest,
+             output: &ListFunctionEventInvokeConfigsResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListFunctionEventInvokeConfigsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FunctionEventInvokeConfigs` of every page of `ListFunctionEventInvokeConfigs` results for `input`, as `list_function_event_invoke_configs_pages` requests them.
+    pub fn list_function_event_invoke_configs_stream(
+        &self,
+        input: ListFunctionEventInvokeConfigsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<FunctionEventInvokeConfig, RusotoError<ListFunctionEventInvokeConfigsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_function_event_invoke_configs_pages(input),
+            |page| page.function_event_invoke_configs.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListFunctions` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_functions_pages(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFunctionsResponse, RusotoError<ListFunctionsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input| async move { client.list_functions(input).await },
+            |input: &ListFunctionsRequest, output: &ListFunctionsResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListFunctionsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Functions` of every page of `ListFunctions` results for `input`, as `list_functions_pages` requests them.
+    pub fn list_functions_stream(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> impl futures::Stream<Item = Result<FunctionConfiguration, RusotoError<ListFunctionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_functions_pages(input), |page| {
+            page.functions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLayerVersions` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_layer_versions_pages(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListLayerVersionsResponse, RusotoError<ListLayerVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input| async move { client.list_layer_versions(input).await },
+            |input: &ListLayerVersionsRequest, output: &ListLayerVersionsResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListLayerVersionsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LayerVersions` of every page of `ListLayerVersions` results for `input`, as `list_layer_versions_pages` requests them.
+    pub fn list_layer_versions_stream(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<LayerVersionsListItem, RusotoError<ListLayerVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_layer_versions_pages(input), |page| {
+            page.layer_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLayers` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_layers_pages(
+        &self,
+        input: ListLayersRequest,
+    ) -> impl futures::Stream<Item = Result<ListLayersResponse, RusotoError<ListLayersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input| async move { client.list_layers(input).await },
+            |input: &ListLayersRequest, output: &ListLayersResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListLayersRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Layers` of every page of `ListLayers` results for `input`, as `list_layers_pages` requests them.
+    pub fn list_layers_stream(
+        &self,
+        input: ListLayersRequest,
+    ) -> impl futures::Stream<Item = Result<LayersListItem, RusotoError<ListLayersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_layers_pages(input), |page| {
+            page.layers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListProvisionedConcurrencyConfigs` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_provisioned_concurrency_configs_pages(
+        &self,
+        input: ListProvisionedConcurrencyConfigsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListProvisionedConcurrencyConfigsResponse,
+            RusotoError<ListProvisionedConcurrencyConfigsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input| async move {
+                client.list_provisioned_concurrency_configs(input).await
+            },
+            |input: &ListProvisionedConcurrencyConfigsRequest,
+             output: &ListProvisionedConcurrencyConfigsResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListProvisionedConcurrencyConfigsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProvisionedConcurrencyConfigs` of every page of `ListProvisionedConcurrencyConfigs` results for `input`, as `list_provisioned_concurrency_configs_pages` requests them.
+    pub fn list_provisioned_concurrency_configs_stream(
+        &self,
+        input: ListProvisionedConcurrencyConfigsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ProvisionedConcurrencyConfigListItem,
+            RusotoError<ListProvisionedConcurrencyConfigsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_provisioned_concurrency_configs_pages(input),
+            |page| page.provisioned_concurrency_configs.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListVersionsByFunction` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_versions_by_function_pages(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListVersionsByFunctionResponse, RusotoError<ListVersionsByFunctionError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LambdaClient, input| async move { client.list_versions_by_function(input).await },
+            |input: &ListVersionsByFunctionRequest, output: &ListVersionsByFunctionResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListVersionsByFunctionRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListVersionsByFunction` results for `input`, as `list_versions_by_function_pages` requests them.
+    pub fn list_versions_by_function_stream(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> impl futures::Stream<
+        Item = Result<FunctionConfiguration, RusotoError<ListVersionsByFunctionError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_versions_by_function_pages(input), |page| {
+            page.versions.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `FunctionActive`, polling `GetFunctionConfiguration` with `input` every 5 seconds, up to 60 times.
+    pub async fn wait_until_function_active(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_function_active_with(input, &waiter).await
+    }
+
+    /// Waits for `FunctionActive` like `wait_until_function_active`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_function_active_with(
+        &self,
+        input: GetFunctionConfigurationRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_function_configuration(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Active"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Pending"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `FunctionActiveV2`, polling `GetFunction` with `input` every 1 seconds, up to 300 times.
+    pub async fn wait_until_function_active_v2(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(1), 300);
+        self.wait_until_function_active_v2_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FunctionEventInvokeConfig, _>()?;
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFunctionEventInvokeConfigError::from_response(
-                response,
-            ))
-        }
+    /// Waits for `FunctionActiveV2` like `wait_until_function_active_v2`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_function_active_v2_with(
+        &self,
+        input: GetFunctionRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_function(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.configuration.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Active"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.configuration.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.configuration.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Pending"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `FunctionExists`, polling `GetFunction` with `input` every 1 seconds, up to 20 times.
+    pub async fn wait_until_function_exists(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(1), 20);
+        self.wait_until_function_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `FunctionExists` like `wait_until_function_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_function_exists_with(
+        &self,
+        input: GetFunctionRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_function(input.clone()),
+            |result| {
+                if result.is_ok() {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `FunctionUpdated`, polling `GetFunctionConfiguration` with `input` every 5 seconds, up to 60 times.
+    pub async fn wait_until_function_updated(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_function_updated_with(input, &waiter).await
+    }
+
+    /// Waits for `FunctionUpdated` like `wait_until_function_updated`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_function_updated_with(
+        &self,
+        input: GetFunctionConfigurationRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_function_configuration(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.last_update_status.as_ref())
+                        .any(|value| value == "Successful"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.last_update_status.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.last_update_status.as_ref())
+                        .any(|value| value == "InProgress"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `FunctionUpdatedV2`, polling `GetFunction` with `input` every 1 seconds, up to 300 times.
+    pub async fn wait_until_function_updated_v2(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(1), 300);
+        self.wait_until_function_updated_v2_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `FunctionUpdatedV2` like `wait_until_function_updated_v2`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_function_updated_v2_with(
+        &self,
+        input: GetFunctionRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_function(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.configuration.as_ref())
+                        .filter_map(|value| value.last_update_status.as_ref())
+                        .any(|value| value == "Successful"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.configuration.as_ref())
+                        .filter_map(|value| value.last_update_status.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.configuration.as_ref())
+                        .filter_map(|value| value.last_update_status.as_ref())
+                        .any(|value| value == "InProgress"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `PublishedVersionActive`, polling `GetFunctionConfiguration` with `input` every 5 seconds, up to 312 times.
+    pub async fn wait_until_published_version_active(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 312);
+        self.wait_until_published_version_active_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `PublishedVersionActive` like `wait_until_published_version_active`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_published_version_active_with(
+        &self,
+        input: GetFunctionConfigurationRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetFunctionConfigurationError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_function_configuration(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Active"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Pending"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Lambda for LambdaClient {
+    /// <p>Adds permissions to the resource-based policy of a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Use this action to grant layer usage permission to other accounts. You can grant permission to a single account, all AWS accounts, or all accounts in an organization.</p> <p>To revoke permission, call <a>RemoveLayerVersionPermission</a> with the statement ID that you specified when you added it.</p>
+    async fn add_layer_version_permission(
+        &self,
+        input: AddLayerVersionPermissionRequest,
+    ) -> Result<AddLayerVersionPermissionResponse, RusotoError<AddLayerVersionPermissionError>>
+    {
+        self.add_layer_version_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Grants an AWS service or another account permission to use a function. You can apply the policy at the function level, or specify a qualifier to restrict access to a single version or alias. If you use a qualifier, the invoker must use the full Amazon Resource Name (ARN) of that version or alias to invoke the function.</p> <p>To grant permission to another account, specify the account ID as the <code>Principal</code>. For AWS services, the principal is a domain-style identifier defined by the service, like <code>s3.amazonaws.com</code> or <code>sns.amazonaws.com</code>. For AWS services, you can also specify the ARN or owning account of the associated resource as the <code>SourceArn</code> or <code>SourceAccount</code>. If you grant permission to a service principal without specifying the source, other accounts could potentially configure resources in their account to invoke your Lambda function.</p> <p>This action adds a statement to a resource-based permissions policy for the function. For more information about function policies, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">Lambda Function Policies</a>. </p>
+    async fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> Result<AddPermissionResponse, RusotoError<AddPermissionError>> {
+        self.add_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a> for a Lambda function version. Use aliases to provide clients with a function identifier that you can update to invoke a different version.</p> <p>You can also map an alias to split invocation requests between two versions. Use the <code>RoutingConfig</code> parameter to specify a second version and the percentage of invocation requests that it receives.</p>
+    async fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<AliasConfiguration, RusotoError<CreateAliasError>> {
+        self.create_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a mapping between an event source and an AWS Lambda function. Lambda reads items from the event source and triggers the function.</p> <p>For details about each event source type, see the following topics.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-ddb.html">Using AWS Lambda with Amazon DynamoDB</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-kinesis.html">Using AWS Lambda with Amazon Kinesis</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-sqs.html">Using AWS Lambda with Amazon SQS</a> </p> </li> </ul> <p>The following error handling options are only available for stream sources (DynamoDB and Kinesis):</p> <ul> <li> <p> <code>BisectBatchOnFunctionError</code> - If the function returns an error, split the batch in two and retry.</p> </li> <li> <p> <code>DestinationConfig</code> - Send discarded records to an Amazon SQS queue or Amazon SNS topic.</p> </li> <li> <p> <code>MaximumRecordAgeInSeconds</code> - Discard records older than the specified age.</p> </li> <li> <p> <code>MaximumRetryAttempts</code> - Discard records after the specified number of retries.</p> </li> <li> <p> <code>ParallelizationFactor</code> - Process multiple batches from each shard concurrently.</p> </li> </ul></p>
+    async fn create_event_source_mapping(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<CreateEventSourceMappingError>> {
+        self.create_event_source_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Lambda function. To create a function, you need a <a href="https://docs.aws.amazon.com/lambda/latest/dg/deployment-package-v2.html">deployment package</a> and an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-permission-model.html#lambda-intro-execution-role">execution role</a>. The deployment package contains your function code. The execution role grants the function permission to use AWS services, such as Amazon CloudWatch Logs for log streaming and AWS X-Ray for request tracing.</p> <p>When you create a function, Lambda provisions an instance of the function and its supporting resources. If your function connects to a VPC, this process can take a minute or so. During this time, you can't invoke or modify the function. The <code>State</code>, <code>StateReason</code>, and <code>StateReasonCode</code> fields in the response from <a>GetFunctionConfiguration</a> indicate when the function is ready to invoke. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/functions-states.html">Function States</a>.</p> <p>A function has an unpublished version, and can have published versions and aliases. The unpublished version changes when you update your function's code and configuration. A published version is a snapshot of your function code and configuration that can't be changed. An alias is a named resource that maps to a version, and can be changed to map to a different version. Use the <code>Publish</code> parameter to create version <code>1</code> of your function from its initial configuration.</p> <p>The other parameters let you configure version-specific and function-level settings. You can modify version-specific settings later with <a>UpdateFunctionConfiguration</a>. Function-level settings apply to both the unpublished and published versions of the function, and include tags (<a>TagResource</a>) and per-function concurrency limits (<a>PutFunctionConcurrency</a>).</p> <p>If another account or an AWS service invokes your function, use <a>AddPermission</a> to grant permission by creating a resource-based IAM policy. You can grant permissions at the function level, on a version, or on an alias.</p> <p>To invoke your function directly, use <a>Invoke</a>. To invoke your function in response to events in other AWS services, create an event source mapping (<a>CreateEventSourceMapping</a>), or configure a function trigger in the other service. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-invocation.html">Invoking Functions</a>.</p>
+    async fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<CreateFunctionError>> {
+        self.create_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
+    async fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<(), RusotoError<DeleteAliasError>> {
+        self.delete_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-invocation-modes.html">event source mapping</a>. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p> <p>When you delete an event source mapping, it enters a <code>Deleting</code> state and might not be completely deleted for several seconds.</p>
+    async fn delete_event_source_mapping(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<DeleteEventSourceMappingError>> {
+        self.delete_event_source_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Lambda function. To delete a specific function version, use the <code>Qualifier</code> parameter. Otherwise, all versions and aliases are deleted.</p> <p>To delete Lambda event source mappings that invoke a function, use <a>DeleteEventSourceMapping</a>. For AWS services and resources that invoke your function directly, delete the trigger in the service where you originally configured it.</p>
+    async fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionError>> {
+        self.delete_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a concurrent execution limit from a function.</p>
+    async fn delete_function_concurrency(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionConcurrencyError>> {
+        self.delete_function_concurrency_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the configuration for asynchronous invocation for a function, version, or alias.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
+    async fn delete_function_event_invoke_config(
+        &self,
+        input: DeleteFunctionEventInvokeConfigRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionEventInvokeConfigError>> {
+        self.delete_function_event_invoke_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Deleted versions can no longer be viewed or added to functions. To avoid breaking functions, a copy of the version remains in Lambda until no functions refer to it.</p>
+    async fn delete_layer_version(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> Result<(), RusotoError<DeleteLayerVersionError>> {
+        self.delete_layer_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the provisioned concurrency configuration for a function.</p>
+    async fn delete_provisioned_concurrency_config(
+        &self,
+        input: DeleteProvisionedConcurrencyConfigRequest,
+    ) -> Result<(), RusotoError<DeleteProvisionedConcurrencyConfigError>> {
+        self.delete_provisioned_concurrency_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves details about your account's <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limits</a> and usage in an AWS Region.</p>
+    async fn get_account_settings(
+        &self,
+    ) -> Result<GetAccountSettingsResponse, RusotoError<GetAccountSettingsError>> {
+        self.get_account_settings_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns details about a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
+    async fn get_alias(
+        &self,
+        input: GetAliasRequest,
+    ) -> Result<AliasConfiguration, RusotoError<GetAliasError>> {
+        self.get_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns details about an event source mapping. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p>
+    async fn get_event_source_mapping(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<GetEventSourceMappingError>> {
+        self.get_event_source_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the function or function version, with a link to download the deployment package that's valid for 10 minutes. If you specify a function version, only details that are specific to that version are returned.</p>
+    async fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<GetFunctionResponse, RusotoError<GetFunctionError>> {
+        self.get_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns details about the concurrency configuration for a function. To set a concurrency limit for a function, use <a>PutFunctionConcurrency</a>.</p>
+    async fn get_function_concurrency(
+        &self,
+        input: GetFunctionConcurrencyRequest,
+    ) -> Result<GetFunctionConcurrencyResponse, RusotoError<GetFunctionConcurrencyError>> {
+        self.get_function_concurrency_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the version-specific settings of a Lambda function or version. The output includes only options that can vary between versions of a function. To modify these settings, use <a>UpdateFunctionConfiguration</a>.</p> <p>To get all of a function's details, including function-level settings, use <a>GetFunction</a>.</p>
+    async fn get_function_configuration(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<GetFunctionConfigurationError>> {
+        self.get_function_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the configuration for asynchronous invocation for a function, version, or alias.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
+    async fn get_function_event_invoke_config(
+        &self,
+        input: GetFunctionEventInvokeConfigRequest,
+    ) -> Result<FunctionEventInvokeConfig, RusotoError<GetFunctionEventInvokeConfigError>> {
+        self.get_function_event_invoke_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
+    async fn get_layer_version(
+        &self,
+        input: GetLayerVersionRequest,
+    ) -> Result<GetLayerVersionResponse, RusotoError<GetLayerVersionError>> {
+        self.get_layer_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
+    async fn get_layer_version_by_arn(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> Result<GetLayerVersionResponse, RusotoError<GetLayerVersionByArnError>> {
+        self.get_layer_version_by_arn_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the permission policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
+    async fn get_layer_version_policy(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> Result<GetLayerVersionPolicyResponse, RusotoError<GetLayerVersionPolicyError>> {
+        self.get_layer_version_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">resource-based IAM policy</a> for a function, version, or alias.</p>
+    async fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.get_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the provisioned concurrency configuration for a function's alias or version.</p>
+    async fn get_provisioned_concurrency_config(
+        &self,
+        input: GetProvisionedConcurrencyConfigRequest,
+    ) -> Result<
+        GetProvisionedConcurrencyConfigResponse,
+        RusotoError<GetProvisionedConcurrencyConfigError>,
+    > {
+        self.get_provisioned_concurrency_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Invokes a Lambda function. You can invoke a function synchronously (and wait for the response), or asynchronously. To invoke a function asynchronously, set <code>InvocationType</code> to <code>Event</code>.</p> <p>For <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-sync.html">synchronous invocation</a>, details about the function response, including errors, are included in the response body and headers. For either invocation type, you can find more information in the <a href="https://docs.aws.amazon.com/lambda/latest/dg/monitoring-functions.html">execution log</a> and <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-x-ray.html">trace</a>.</p> <p>When an error occurs, your function may be invoked multiple times. Retry behavior varies by error type, client, event source, and invocation type. For example, if you invoke a function asynchronously and it returns an error, Lambda executes the function up to two more times. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/retries-on-errors.html">Retry Behavior</a>.</p> <p>For <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html">asynchronous invocation</a>, Lambda adds events to a queue before sending them to your function. If your function does not have enough capacity to keep up with the queue, events may be lost. Occasionally, your function may receive the same event multiple times, even if no error occurs. To retain events that were not processed, configure your function with a <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html#dlq">dead-letter queue</a>.</p> <p>The status code in the API response doesn't reflect function errors. Error codes are reserved for errors that prevent your function from executing, such as permissions errors, <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limit errors</a>, or issues with your function's code and configuration. For example, Lambda returns <code>TooManyRequestsException</code> if executing the function would cause you to exceed a concurrency limit at either the account level (<code>ConcurrentInvocationLimitExceeded</code>) or function level (<code>ReservedFunctionConcurrentInvocationLimitExceeded</code>).</p> <p>For functions with a long timeout, your client might be disconnected during synchronous invocation while it waits for a response. Configure your HTTP client, SDK, firewall, proxy, or operating system to allow for long connections with timeout or keep-alive settings.</p> <p>This operation requires permission for the <code>lambda:InvokeFunction</code> action.</p>
+    async fn invoke(
+        &self,
+        input: InvocationRequest,
+    ) -> Result<InvocationResponse, RusotoError<InvokeError>> {
+        self.invoke_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><important> <p>For asynchronous function invocation, use <a>Invoke</a>.</p> </important> <p>Invokes a function asynchronously.</p></p>
+    async fn invoke_async(
+        &self,
+        input: InvokeAsyncRequest,
+    ) -> Result<InvokeAsyncResponse, RusotoError<InvokeAsyncError>> {
+        self.invoke_async_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">aliases</a> for a Lambda function.</p>
+    async fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+        self.list_aliases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists event source mappings. Specify an <code>EventSourceArn</code> to only show event source mappings for a single event source.</p>
+    async fn list_event_source_mappings(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> Result<ListEventSourceMappingsResponse, RusotoError<ListEventSourceMappingsError>> {
+        self.list_event_source_mappings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of configurations for asynchronous invocation for a function.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
+    async fn list_function_event_invoke_configs(
+        &self,
+        input: ListFunctionEventInvokeConfigsRequest,
+    ) -> Result<
+        ListFunctionEventInvokeConfigsResponse,
+        RusotoError<ListFunctionEventInvokeConfigsError>,
+    > {
+        self.list_function_event_invoke_configs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of Lambda functions, with the version-specific configuration of each.</p> <p>Set <code>FunctionVersion</code> to <code>ALL</code> to include all published versions of each function in addition to the unpublished version. To get more information about a function or version, use <a>GetFunction</a>.</p>
+    async fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> Result<ListFunctionsResponse, RusotoError<ListFunctionsError>> {
+        self.list_functions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the versions of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Versions that have been deleted aren't listed. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only versions that indicate that they're compatible with that runtime.</p>
+    async fn list_layer_versions(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> Result<ListLayerVersionsResponse, RusotoError<ListLayerVersionsError>> {
+        self.list_layer_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layers</a> and shows information about the latest version of each. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only layers that indicate that they're compatible with that runtime.</p>
+    async fn list_layers(
+        &self,
+        input: ListLayersRequest,
+    ) -> Result<ListLayersResponse, RusotoError<ListLayersError>> {
+        self.list_layers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of provisioned concurrency configurations for a function.</p>
+    async fn list_provisioned_concurrency_configs(
+        &self,
+        input: ListProvisionedConcurrencyConfigsRequest,
+    ) -> Result<
+        ListProvisionedConcurrencyConfigsResponse,
+        RusotoError<ListProvisionedConcurrencyConfigsError>,
+    > {
+        self.list_provisioned_concurrency_configs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a>. You can also view tags with <a>GetFunction</a>.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">versions</a>, with the version-specific configuration of each. </p>
+    async fn list_versions_by_function(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> Result<ListVersionsByFunctionResponse, RusotoError<ListVersionsByFunctionError>> {
+        self.list_versions_by_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a> from a ZIP archive. Each time you call <code>PublishLayerVersion</code> with the same layer name, a new version is created.</p> <p>Add layers to your function with <a>CreateFunction</a> or <a>UpdateFunctionConfiguration</a>.</p>
+    async fn publish_layer_version(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> Result<PublishLayerVersionResponse, RusotoError<PublishLayerVersionError>> {
+        self.publish_layer_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">version</a> from the current code and configuration of a function. Use versions to create a snapshot of your function code and configuration that doesn't change.</p> <p>AWS Lambda doesn't publish a version if the function's configuration and code haven't changed since the last version. Use <a>UpdateFunctionCode</a> or <a>UpdateFunctionConfiguration</a> to update the function before publishing a version.</p> <p>Clients can invoke versions directly or with an alias. To create an alias, use <a>CreateAlias</a>.</p>
+    async fn publish_version(
+        &self,
+        input: PublishVersionRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<PublishVersionError>> {
+        self.publish_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the maximum number of simultaneous executions for a function, and reserves capacity for that concurrency level.</p> <p>Concurrency settings apply to the function as a whole, including all published versions and the unpublished version. Reserving concurrency both ensures that your function has capacity to process the specified number of events simultaneously, and prevents it from scaling beyond that level. Use <a>GetFunction</a> to see the current setting for a function.</p> <p>Use <a>GetAccountSettings</a> to see your Regional concurrency limit. You can reserve concurrency for as many functions as you like, as long as you leave at least 100 simultaneous executions unreserved for functions that aren't configured with a per-function limit. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/concurrent-executions.html">Managing Concurrency</a>.</p>
+    async fn put_function_concurrency(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> Result<Concurrency, RusotoError<PutFunctionConcurrencyError>> {
+        self.put_function_concurrency_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures options for <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html">asynchronous invocation</a> on a function, version, or alias.</p> <p>By default, Lambda retries an asynchronous invocation twice if the function returns an error. It retains events in a queue for up to six hours. When an event fails all processing attempts or stays in the asynchronous invocation queue for too long, Lambda discards it. To retain discarded events, configure a dead-letter queue with <a>UpdateFunctionConfiguration</a>.</p>
+    async fn put_function_event_invoke_config(
+        &self,
+        input: PutFunctionEventInvokeConfigRequest,
+    ) -> Result<FunctionEventInvokeConfig, RusotoError<PutFunctionEventInvokeConfigError>> {
+        self.put_function_event_invoke_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a provisioned concurrency configuration to a function's alias or version.</p>
+    async fn put_provisioned_concurrency_config(
+        &self,
+        input: PutProvisionedConcurrencyConfigRequest,
+    ) -> Result<
+        PutProvisionedConcurrencyConfigResponse,
+        RusotoError<PutProvisionedConcurrencyConfigError>,
+    > {
+        self.put_provisioned_concurrency_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a statement from the permissions policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
+    async fn remove_layer_version_permission(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> Result<(), RusotoError<RemoveLayerVersionPermissionError>> {
+        self.remove_layer_version_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Revokes function-use permission from an AWS service or another account. You can get the ID of the statement from the output of <a>GetPolicy</a>.</p>
+    async fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.remove_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> to a function.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> from a function.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the configuration of a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
+    async fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> Result<AliasConfiguration, RusotoError<UpdateAliasError>> {
+        self.update_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates an event source mapping. You can change the function that AWS Lambda invokes, or pause invocation and resume later from the same location.</p> <p>The following error handling options are only available for stream sources (DynamoDB and Kinesis):</p> <ul> <li> <p> <code>BisectBatchOnFunctionError</code> - If the function returns an error, split the batch in two and retry.</p> </li> <li> <p> <code>DestinationConfig</code> - Send discarded records to an Amazon SQS queue or Amazon SNS topic.</p> </li> <li> <p> <code>MaximumRecordAgeInSeconds</code> - Discard records older than the specified age.</p> </li> <li> <p> <code>MaximumRetryAttempts</code> - Discard records after the specified number of retries.</p> </li> <li> <p> <code>ParallelizationFactor</code> - Process multiple batches from each shard concurrently.</p> </li> </ul></p>
+    async fn update_event_source_mapping(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<UpdateEventSourceMappingError>> {
+        self.update_event_source_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a Lambda function's code.</p> <p>The function's code is locked when you publish a version. You can't modify the code of a published version, only the unpublished version.</p>
+    async fn update_function_code(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<UpdateFunctionCodeError>> {
+        self.update_function_code_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modify the version-specific settings of a Lambda function.</p> <p>When you update a function, Lambda provisions an instance of the function and its supporting resources. If your function connects to a VPC, this process can take a minute. During this time, you can't modify the function, but you can still invoke it. The <code>LastUpdateStatus</code>, <code>LastUpdateStatusReason</code>, and <code>LastUpdateStatusReasonCode</code> fields in the response from <a>GetFunctionConfiguration</a> indicate when the update is complete and the function is processing events with the new configuration. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/functions-states.html">Function States</a>.</p> <p>These settings can vary between versions of a function and are locked when you publish a version. You can't modify the configuration of a published version, only the unpublished version.</p> <p>To configure function concurrency, use <a>PutFunctionConcurrency</a>. To grant invoke permissions to an account or AWS service, use <a>AddPermission</a>.</p>
+    async fn update_function_configuration(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<UpdateFunctionConfigurationError>> {
+        self.update_function_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the configuration for asynchronous invocation for a function, version, or alias.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
+    async fn update_function_event_invoke_config(
+        &self,
+        input: UpdateFunctionEventInvokeConfigRequest,
+    ) -> Result<FunctionEventInvokeConfig, RusotoError<UpdateFunctionEventInvokeConfigError>> {
+        self.update_function_event_invoke_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1985,6 +1985,7 @@ pub struct UtteranceList {
 
 /// Errors returned by CreateBotVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBotVersionError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -1998,6 +1999,8 @@ pub enum CreateBotVersionError {
     NotFound(String),
     /// <p> The checksum of the resource that you are trying to change does not match the checksum in the request. Check the resource's checksum and try again.</p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBotVersionError {
@@ -2005,25 +2008,34 @@ impl CreateBotVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBotVersionError::BadRequest(err.msg))
+                    return RusotoError::service(CreateBotVersionError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBotVersionError::Conflict(err.msg))
+                    return RusotoError::service(CreateBotVersionError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBotVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateBotVersionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBotVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateBotVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBotVersionError::NotFound(err.msg))
+                    return RusotoError::service(CreateBotVersionError::NotFound(err.msg), &res)
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(CreateBotVersionError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        CreateBotVersionError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBotVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2039,12 +2051,14 @@ impl fmt::Display for CreateBotVersionError {
             CreateBotVersionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateBotVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateBotVersionError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
+            CreateBotVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBotVersionError {}
 /// Errors returned by CreateIntentVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIntentVersionError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2058,6 +2072,8 @@ pub enum CreateIntentVersionError {
     NotFound(String),
     /// <p> The checksum of the resource that you are trying to change does not match the checksum in the request. Check the resource's checksum and try again.</p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIntentVersionError {
@@ -2065,27 +2081,37 @@ impl CreateIntentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIntentVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateIntentVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIntentVersionError::Conflict(err.msg))
+                    return RusotoError::service(CreateIntentVersionError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateIntentVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateIntentVersionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIntentVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateIntentVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateIntentVersionError::NotFound(err.msg))
+                    return RusotoError::service(CreateIntentVersionError::NotFound(err.msg), &res)
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(CreateIntentVersionError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIntentVersionError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateIntentVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2101,12 +2127,14 @@ impl fmt::Display for CreateIntentVersionError {
             CreateIntentVersionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateIntentVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateIntentVersionError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
+            CreateIntentVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIntentVersionError {}
 /// Errors returned by CreateSlotTypeVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSlotTypeVersionError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2120,6 +2148,8 @@ pub enum CreateSlotTypeVersionError {
     NotFound(String),
     /// <p> The checksum of the resource that you are trying to change does not match the checksum in the request. Check the resource's checksum and try again.</p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSlotTypeVersionError {
@@ -2127,29 +2157,43 @@ impl CreateSlotTypeVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateSlotTypeVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        CreateSlotTypeVersionError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSlotTypeVersionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateSlotTypeVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateSlotTypeVersionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSlotTypeVersionError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSlotTypeVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2165,12 +2209,14 @@ impl fmt::Display for CreateSlotTypeVersionError {
             CreateSlotTypeVersionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateSlotTypeVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateSlotTypeVersionError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
+            CreateSlotTypeVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSlotTypeVersionError {}
 /// Errors returned by DeleteBot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBotError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2184,6 +2230,8 @@ pub enum DeleteBotError {
     NotFound(String),
     /// <p>The resource that you are attempting to delete is referred to by another resource. Use this information to remove references to the resource that you are trying to delete.</p> <p>The body of the exception contains a JSON object that describes the resource.</p> <p> <code>{ "resourceType": BOT | BOTALIAS | BOTCHANNEL | INTENT,</code> </p> <p> <code>"resourceReference": {</code> </p> <p> <code>"name": <i>string</i>, "version": <i>string</i> } }</code> </p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBotError {
@@ -2191,25 +2239,25 @@ impl DeleteBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteBotError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotError::Conflict(err.msg))
+                    return RusotoError::service(DeleteBotError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteBotError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteBotError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotError::NotFound(err.msg))
+                    return RusotoError::service(DeleteBotError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteBotError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteBotError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2225,12 +2273,14 @@ impl fmt::Display for DeleteBotError {
             DeleteBotError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteBotError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteBotError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteBotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBotError {}
 /// Errors returned by DeleteBotAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBotAliasError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2244,6 +2294,8 @@ pub enum DeleteBotAliasError {
     NotFound(String),
     /// <p>The resource that you are attempting to delete is referred to by another resource. Use this information to remove references to the resource that you are trying to delete.</p> <p>The body of the exception contains a JSON object that describes the resource.</p> <p> <code>{ "resourceType": BOT | BOTALIAS | BOTCHANNEL | INTENT,</code> </p> <p> <code>"resourceReference": {</code> </p> <p> <code>"name": <i>string</i>, "version": <i>string</i> } }</code> </p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBotAliasError {
@@ -2251,25 +2303,28 @@ impl DeleteBotAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotAliasError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteBotAliasError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotAliasError::Conflict(err.msg))
+                    return RusotoError::service(DeleteBotAliasError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteBotAliasError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteBotAliasError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotAliasError::NotFound(err.msg))
+                    return RusotoError::service(DeleteBotAliasError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteBotAliasError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteBotAliasError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBotAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2285,12 +2340,14 @@ impl fmt::Display for DeleteBotAliasError {
             DeleteBotAliasError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteBotAliasError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteBotAliasError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteBotAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBotAliasError {}
 /// Errors returned by DeleteBotChannelAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBotChannelAssociationError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2302,6 +2359,8 @@ pub enum DeleteBotChannelAssociationError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBotChannelAssociationError {
@@ -2311,32 +2370,42 @@ impl DeleteBotChannelAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBotChannelAssociationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBotChannelAssociationError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBotChannelAssociationError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBotChannelAssociationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBotChannelAssociationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteBotChannelAssociationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2351,12 +2420,14 @@ impl fmt::Display for DeleteBotChannelAssociationError {
             DeleteBotChannelAssociationError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteBotChannelAssociationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteBotChannelAssociationError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteBotChannelAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBotChannelAssociationError {}
 /// Errors returned by DeleteBotVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBotVersionError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2370,6 +2441,8 @@ pub enum DeleteBotVersionError {
     NotFound(String),
     /// <p>The resource that you are attempting to delete is referred to by another resource. Use this information to remove references to the resource that you are trying to delete.</p> <p>The body of the exception contains a JSON object that describes the resource.</p> <p> <code>{ "resourceType": BOT | BOTALIAS | BOTCHANNEL | INTENT,</code> </p> <p> <code>"resourceReference": {</code> </p> <p> <code>"name": <i>string</i>, "version": <i>string</i> } }</code> </p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBotVersionError {
@@ -2377,25 +2450,34 @@ impl DeleteBotVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotVersionError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteBotVersionError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotVersionError::Conflict(err.msg))
+                    return RusotoError::service(DeleteBotVersionError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteBotVersionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteBotVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotVersionError::NotFound(err.msg))
+                    return RusotoError::service(DeleteBotVersionError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteBotVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteBotVersionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBotVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2411,12 +2493,14 @@ impl fmt::Display for DeleteBotVersionError {
             DeleteBotVersionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteBotVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteBotVersionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteBotVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBotVersionError {}
 /// Errors returned by DeleteIntent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIntentError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2430,6 +2514,8 @@ pub enum DeleteIntentError {
     NotFound(String),
     /// <p>The resource that you are attempting to delete is referred to by another resource. Use this information to remove references to the resource that you are trying to delete.</p> <p>The body of the exception contains a JSON object that describes the resource.</p> <p> <code>{ "resourceType": BOT | BOTALIAS | BOTCHANNEL | INTENT,</code> </p> <p> <code>"resourceReference": {</code> </p> <p> <code>"name": <i>string</i>, "version": <i>string</i> } }</code> </p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIntentError {
@@ -2437,25 +2523,25 @@ impl DeleteIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIntentError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteIntentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntentError::Conflict(err.msg))
+                    return RusotoError::service(DeleteIntentError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteIntentError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteIntentError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteIntentError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntentError::NotFound(err.msg))
+                    return RusotoError::service(DeleteIntentError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteIntentError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteIntentError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIntentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2471,12 +2557,14 @@ impl fmt::Display for DeleteIntentError {
             DeleteIntentError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteIntentError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteIntentError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteIntentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIntentError {}
 /// Errors returned by DeleteIntentVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIntentVersionError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2490,6 +2578,8 @@ pub enum DeleteIntentVersionError {
     NotFound(String),
     /// <p>The resource that you are attempting to delete is referred to by another resource. Use this information to remove references to the resource that you are trying to delete.</p> <p>The body of the exception contains a JSON object that describes the resource.</p> <p> <code>{ "resourceType": BOT | BOTALIAS | BOTCHANNEL | INTENT,</code> </p> <p> <code>"resourceReference": {</code> </p> <p> <code>"name": <i>string</i>, "version": <i>string</i> } }</code> </p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIntentVersionError {
@@ -2497,25 +2587,37 @@ impl DeleteIntentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteIntentVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::Conflict(err.msg))
+                    return RusotoError::service(DeleteIntentVersionError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteIntentVersionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteIntentVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::NotFound(err.msg))
+                    return RusotoError::service(DeleteIntentVersionError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteIntentVersionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIntentVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2531,12 +2633,14 @@ impl fmt::Display for DeleteIntentVersionError {
             DeleteIntentVersionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteIntentVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteIntentVersionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteIntentVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIntentVersionError {}
 /// Errors returned by DeleteSlotType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSlotTypeError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2550,6 +2654,8 @@ pub enum DeleteSlotTypeError {
     NotFound(String),
     /// <p>The resource that you are attempting to delete is referred to by another resource. Use this information to remove references to the resource that you are trying to delete.</p> <p>The body of the exception contains a JSON object that describes the resource.</p> <p> <code>{ "resourceType": BOT | BOTALIAS | BOTCHANNEL | INTENT,</code> </p> <p> <code>"resourceReference": {</code> </p> <p> <code>"name": <i>string</i>, "version": <i>string</i> } }</code> </p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSlotTypeError {
@@ -2557,25 +2663,28 @@ impl DeleteSlotTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteSlotTypeError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::Conflict(err.msg))
+                    return RusotoError::service(DeleteSlotTypeError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteSlotTypeError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteSlotTypeError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::NotFound(err.msg))
+                    return RusotoError::service(DeleteSlotTypeError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteSlotTypeError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSlotTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2591,12 +2700,14 @@ impl fmt::Display for DeleteSlotTypeError {
             DeleteSlotTypeError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteSlotTypeError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteSlotTypeError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteSlotTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSlotTypeError {}
 /// Errors returned by DeleteSlotTypeVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSlotTypeVersionError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2610,6 +2721,8 @@ pub enum DeleteSlotTypeVersionError {
     NotFound(String),
     /// <p>The resource that you are attempting to delete is referred to by another resource. Use this information to remove references to the resource that you are trying to delete.</p> <p>The body of the exception contains a JSON object that describes the resource.</p> <p> <code>{ "resourceType": BOT | BOTALIAS | BOTCHANNEL | INTENT,</code> </p> <p> <code>"resourceReference": {</code> </p> <p> <code>"name": <i>string</i>, "version": <i>string</i> } }</code> </p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSlotTypeVersionError {
@@ -2617,27 +2730,43 @@ impl DeleteSlotTypeVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteSlotTypeVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteSlotTypeVersionError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSlotTypeVersionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteSlotTypeVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteSlotTypeVersionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteSlotTypeVersionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSlotTypeVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2653,12 +2782,14 @@ impl fmt::Display for DeleteSlotTypeVersionError {
             DeleteSlotTypeVersionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteSlotTypeVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteSlotTypeVersionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteSlotTypeVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSlotTypeVersionError {}
 /// Errors returned by DeleteUtterances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUtterancesError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2668,6 +2799,8 @@ pub enum DeleteUtterancesError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUtterancesError {
@@ -2675,19 +2808,25 @@ impl DeleteUtterancesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUtterancesError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteUtterancesError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteUtterancesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteUtterancesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteUtterancesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteUtterancesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUtterancesError::NotFound(err.msg))
+                    return RusotoError::service(DeleteUtterancesError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUtterancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2701,12 +2840,14 @@ impl fmt::Display for DeleteUtterancesError {
             DeleteUtterancesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteUtterancesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteUtterancesError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteUtterancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUtterancesError {}
 /// Errors returned by GetBot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBotError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2716,6 +2857,8 @@ pub enum GetBotError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBotError {
@@ -2723,17 +2866,19 @@ impl GetBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotError::BadRequest(err.msg))
+                    return RusotoError::service(GetBotError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotError::InternalFailure(err.msg))
+                    return RusotoError::service(GetBotError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetBotError::LimitExceeded(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(GetBotError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(GetBotError::NotFound(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2747,12 +2892,14 @@ impl fmt::Display for GetBotError {
             GetBotError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBotError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetBotError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetBotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBotError {}
 /// Errors returned by GetBotAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBotAliasError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2762,6 +2909,8 @@ pub enum GetBotAliasError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBotAliasError {
@@ -2769,19 +2918,19 @@ impl GetBotAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotAliasError::BadRequest(err.msg))
+                    return RusotoError::service(GetBotAliasError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(GetBotAliasError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetBotAliasError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotAliasError::NotFound(err.msg))
+                    return RusotoError::service(GetBotAliasError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBotAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2795,12 +2944,14 @@ impl fmt::Display for GetBotAliasError {
             GetBotAliasError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBotAliasError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetBotAliasError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetBotAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBotAliasError {}
 /// Errors returned by GetBotAliases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBotAliasesError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2808,6 +2959,8 @@ pub enum GetBotAliasesError {
     InternalFailure(String),
     /// <p>The request exceeded a limit. Try your request again.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBotAliasesError {
@@ -2815,16 +2968,16 @@ impl GetBotAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotAliasesError::BadRequest(err.msg))
+                    return RusotoError::service(GetBotAliasesError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service(GetBotAliasesError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotAliasesError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetBotAliasesError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBotAliasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2837,12 +2990,14 @@ impl fmt::Display for GetBotAliasesError {
             GetBotAliasesError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetBotAliasesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBotAliasesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetBotAliasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBotAliasesError {}
 /// Errors returned by GetBotChannelAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBotChannelAssociationError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2852,6 +3007,8 @@ pub enum GetBotChannelAssociationError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBotChannelAssociationError {
@@ -2859,23 +3016,31 @@ impl GetBotChannelAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetBotChannelAssociationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBotChannelAssociationError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBotChannelAssociationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetBotChannelAssociationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBotChannelAssociationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2889,12 +3054,14 @@ impl fmt::Display for GetBotChannelAssociationError {
             GetBotChannelAssociationError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBotChannelAssociationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetBotChannelAssociationError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetBotChannelAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBotChannelAssociationError {}
 /// Errors returned by GetBotChannelAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBotChannelAssociationsError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2902,6 +3069,8 @@ pub enum GetBotChannelAssociationsError {
     InternalFailure(String),
     /// <p>The request exceeded a limit. Try your request again.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBotChannelAssociationsError {
@@ -2909,22 +3078,27 @@ impl GetBotChannelAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotChannelAssociationsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBotChannelAssociationsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotChannelAssociationsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBotChannelAssociationsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotChannelAssociationsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBotChannelAssociationsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetBotChannelAssociationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2937,12 +3111,14 @@ impl fmt::Display for GetBotChannelAssociationsError {
             GetBotChannelAssociationsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetBotChannelAssociationsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBotChannelAssociationsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetBotChannelAssociationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBotChannelAssociationsError {}
 /// Errors returned by GetBotVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBotVersionsError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -2952,6 +3128,8 @@ pub enum GetBotVersionsError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBotVersionsError {
@@ -2959,19 +3137,22 @@ impl GetBotVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(GetBotVersionsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetBotVersionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotVersionsError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetBotVersionsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotVersionsError::NotFound(err.msg))
+                    return RusotoError::service(GetBotVersionsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBotVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2985,12 +3166,14 @@ impl fmt::Display for GetBotVersionsError {
             GetBotVersionsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBotVersionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetBotVersionsError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetBotVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBotVersionsError {}
 /// Errors returned by GetBots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBotsError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3000,6 +3183,8 @@ pub enum GetBotsError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBotsError {
@@ -3007,19 +3192,19 @@ impl GetBotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotsError::BadRequest(err.msg))
+                    return RusotoError::service(GetBotsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotsError::InternalFailure(err.msg))
+                    return RusotoError::service(GetBotsError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotsError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetBotsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotsError::NotFound(err.msg))
+                    return RusotoError::service(GetBotsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBotsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3033,12 +3218,14 @@ impl fmt::Display for GetBotsError {
             GetBotsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBotsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetBotsError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetBotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBotsError {}
 /// Errors returned by GetBuiltinIntent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBuiltinIntentError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3048,6 +3235,8 @@ pub enum GetBuiltinIntentError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBuiltinIntentError {
@@ -3055,19 +3244,25 @@ impl GetBuiltinIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::BadRequest(err.msg))
+                    return RusotoError::service(GetBuiltinIntentError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetBuiltinIntentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetBuiltinIntentError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::NotFound(err.msg))
+                    return RusotoError::service(GetBuiltinIntentError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBuiltinIntentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3081,12 +3276,14 @@ impl fmt::Display for GetBuiltinIntentError {
             GetBuiltinIntentError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBuiltinIntentError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetBuiltinIntentError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetBuiltinIntentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBuiltinIntentError {}
 /// Errors returned by GetBuiltinIntents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBuiltinIntentsError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3094,6 +3291,8 @@ pub enum GetBuiltinIntentsError {
     InternalFailure(String),
     /// <p>The request exceeded a limit. Try your request again.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBuiltinIntentsError {
@@ -3101,16 +3300,22 @@ impl GetBuiltinIntentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBuiltinIntentsError::BadRequest(err.msg))
+                    return RusotoError::service(GetBuiltinIntentsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBuiltinIntentsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetBuiltinIntentsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBuiltinIntentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetBuiltinIntentsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBuiltinIntentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3123,12 +3328,14 @@ impl fmt::Display for GetBuiltinIntentsError {
             GetBuiltinIntentsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetBuiltinIntentsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBuiltinIntentsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetBuiltinIntentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBuiltinIntentsError {}
 /// Errors returned by GetBuiltinSlotTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBuiltinSlotTypesError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3136,6 +3343,8 @@ pub enum GetBuiltinSlotTypesError {
     InternalFailure(String),
     /// <p>The request exceeded a limit. Try your request again.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBuiltinSlotTypesError {
@@ -3143,16 +3352,25 @@ impl GetBuiltinSlotTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBuiltinSlotTypesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetBuiltinSlotTypesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBuiltinSlotTypesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetBuiltinSlotTypesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBuiltinSlotTypesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetBuiltinSlotTypesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBuiltinSlotTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3165,12 +3383,14 @@ impl fmt::Display for GetBuiltinSlotTypesError {
             GetBuiltinSlotTypesError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetBuiltinSlotTypesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBuiltinSlotTypesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetBuiltinSlotTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBuiltinSlotTypesError {}
 /// Errors returned by GetExport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetExportError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3180,6 +3400,8 @@ pub enum GetExportError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetExportError {
@@ -3187,19 +3409,19 @@ impl GetExportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetExportError::BadRequest(err.msg))
+                    return RusotoError::service(GetExportError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetExportError::InternalFailure(err.msg))
+                    return RusotoError::service(GetExportError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetExportError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetExportError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetExportError::NotFound(err.msg))
+                    return RusotoError::service(GetExportError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetExportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3213,12 +3435,14 @@ impl fmt::Display for GetExportError {
             GetExportError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetExportError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetExportError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetExportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetExportError {}
 /// Errors returned by GetImport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetImportError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3228,6 +3452,8 @@ pub enum GetImportError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetImportError {
@@ -3235,19 +3461,19 @@ impl GetImportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetImportError::BadRequest(err.msg))
+                    return RusotoError::service(GetImportError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetImportError::InternalFailure(err.msg))
+                    return RusotoError::service(GetImportError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetImportError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetImportError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetImportError::NotFound(err.msg))
+                    return RusotoError::service(GetImportError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetImportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3261,12 +3487,14 @@ impl fmt::Display for GetImportError {
             GetImportError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetImportError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetImportError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetImportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetImportError {}
 /// Errors returned by GetIntent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntentError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3276,6 +3504,8 @@ pub enum GetIntentError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntentError {
@@ -3283,19 +3513,19 @@ impl GetIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntentError::BadRequest(err.msg))
+                    return RusotoError::service(GetIntentError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntentError::InternalFailure(err.msg))
+                    return RusotoError::service(GetIntentError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetIntentError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntentError::NotFound(err.msg))
+                    return RusotoError::service(GetIntentError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3309,12 +3539,14 @@ impl fmt::Display for GetIntentError {
             GetIntentError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetIntentError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetIntentError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetIntentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntentError {}
 /// Errors returned by GetIntentVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntentVersionsError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3324,6 +3556,8 @@ pub enum GetIntentVersionsError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntentVersionsError {
@@ -3331,19 +3565,25 @@ impl GetIntentVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntentVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(GetIntentVersionsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntentVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetIntentVersionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIntentVersionsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetIntentVersionsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntentVersionsError::NotFound(err.msg))
+                    return RusotoError::service(GetIntentVersionsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntentVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3357,12 +3597,14 @@ impl fmt::Display for GetIntentVersionsError {
             GetIntentVersionsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetIntentVersionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetIntentVersionsError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetIntentVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntentVersionsError {}
 /// Errors returned by GetIntents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntentsError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3372,6 +3614,8 @@ pub enum GetIntentsError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntentsError {
@@ -3379,19 +3623,19 @@ impl GetIntentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntentsError::BadRequest(err.msg))
+                    return RusotoError::service(GetIntentsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntentsError::InternalFailure(err.msg))
+                    return RusotoError::service(GetIntentsError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIntentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetIntentsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntentsError::NotFound(err.msg))
+                    return RusotoError::service(GetIntentsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3405,12 +3649,14 @@ impl fmt::Display for GetIntentsError {
             GetIntentsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetIntentsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetIntentsError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetIntentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntentsError {}
 /// Errors returned by GetSlotType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSlotTypeError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3420,6 +3666,8 @@ pub enum GetSlotTypeError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSlotTypeError {
@@ -3427,19 +3675,19 @@ impl GetSlotTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSlotTypeError::BadRequest(err.msg))
+                    return RusotoError::service(GetSlotTypeError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSlotTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(GetSlotTypeError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSlotTypeError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetSlotTypeError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSlotTypeError::NotFound(err.msg))
+                    return RusotoError::service(GetSlotTypeError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSlotTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3453,12 +3701,14 @@ impl fmt::Display for GetSlotTypeError {
             GetSlotTypeError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetSlotTypeError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetSlotTypeError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetSlotTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSlotTypeError {}
 /// Errors returned by GetSlotTypeVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSlotTypeVersionsError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3468,6 +3718,8 @@ pub enum GetSlotTypeVersionsError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSlotTypeVersionsError {
@@ -3475,19 +3727,28 @@ impl GetSlotTypeVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetSlotTypeVersionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetSlotTypeVersionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetSlotTypeVersionsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::NotFound(err.msg))
+                    return RusotoError::service(GetSlotTypeVersionsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSlotTypeVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3501,12 +3762,14 @@ impl fmt::Display for GetSlotTypeVersionsError {
             GetSlotTypeVersionsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetSlotTypeVersionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetSlotTypeVersionsError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetSlotTypeVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSlotTypeVersionsError {}
 /// Errors returned by GetSlotTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSlotTypesError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3516,6 +3779,8 @@ pub enum GetSlotTypesError {
     LimitExceeded(String),
     /// <p>The resource specified in the request was not found. Check the resource and try again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSlotTypesError {
@@ -3523,19 +3788,19 @@ impl GetSlotTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSlotTypesError::BadRequest(err.msg))
+                    return RusotoError::service(GetSlotTypesError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSlotTypesError::InternalFailure(err.msg))
+                    return RusotoError::service(GetSlotTypesError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSlotTypesError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetSlotTypesError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSlotTypesError::NotFound(err.msg))
+                    return RusotoError::service(GetSlotTypesError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSlotTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3549,12 +3814,14 @@ impl fmt::Display for GetSlotTypesError {
             GetSlotTypesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetSlotTypesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetSlotTypesError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetSlotTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSlotTypesError {}
 /// Errors returned by GetUtterancesView
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUtterancesViewError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3562,6 +3829,8 @@ pub enum GetUtterancesViewError {
     InternalFailure(String),
     /// <p>The request exceeded a limit. Try your request again.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUtterancesViewError {
@@ -3569,16 +3838,22 @@ impl GetUtterancesViewError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUtterancesViewError::BadRequest(err.msg))
+                    return RusotoError::service(GetUtterancesViewError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetUtterancesViewError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetUtterancesViewError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetUtterancesViewError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetUtterancesViewError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUtterancesViewError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3591,12 +3866,14 @@ impl fmt::Display for GetUtterancesViewError {
             GetUtterancesViewError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetUtterancesViewError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetUtterancesViewError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetUtterancesViewError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUtterancesViewError {}
 /// Errors returned by PutBot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutBotError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3608,6 +3885,8 @@ pub enum PutBotError {
     LimitExceeded(String),
     /// <p> The checksum of the resource that you are trying to change does not match the checksum in the request. Check the resource's checksum and try again.</p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutBotError {
@@ -3615,20 +3894,22 @@ impl PutBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutBotError::BadRequest(err.msg))
+                    return RusotoError::service(PutBotError::BadRequest(err.msg), &res)
+                }
+                "ConflictException" => {
+                    return RusotoError::service(PutBotError::Conflict(err.msg), &res)
                 }
-                "ConflictException" => return RusotoError::Service(PutBotError::Conflict(err.msg)),
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutBotError::InternalFailure(err.msg))
+                    return RusotoError::service(PutBotError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutBotError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutBotError::LimitExceeded(err.msg), &res)
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutBotError::PreconditionFailed(err.msg))
+                    return RusotoError::service(PutBotError::PreconditionFailed(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutBotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3643,12 +3924,14 @@ impl fmt::Display for PutBotError {
             PutBotError::InternalFailure(ref cause) => write!(f, "{}", cause),
             PutBotError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutBotError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
+            PutBotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutBotError {}
 /// Errors returned by PutBotAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutBotAliasError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3660,6 +3943,8 @@ pub enum PutBotAliasError {
     LimitExceeded(String),
     /// <p> The checksum of the resource that you are trying to change does not match the checksum in the request. Check the resource's checksum and try again.</p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutBotAliasError {
@@ -3667,22 +3952,25 @@ impl PutBotAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutBotAliasError::BadRequest(err.msg))
+                    return RusotoError::service(PutBotAliasError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutBotAliasError::Conflict(err.msg))
+                    return RusotoError::service(PutBotAliasError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutBotAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(PutBotAliasError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutBotAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutBotAliasError::LimitExceeded(err.msg), &res)
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutBotAliasError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        PutBotAliasError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutBotAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3697,12 +3985,14 @@ impl fmt::Display for PutBotAliasError {
             PutBotAliasError::InternalFailure(ref cause) => write!(f, "{}", cause),
             PutBotAliasError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutBotAliasError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
+            PutBotAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutBotAliasError {}
 /// Errors returned by PutIntent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutIntentError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3714,6 +4004,8 @@ pub enum PutIntentError {
     LimitExceeded(String),
     /// <p> The checksum of the resource that you are trying to change does not match the checksum in the request. Check the resource's checksum and try again.</p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutIntentError {
@@ -3721,22 +4013,22 @@ impl PutIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutIntentError::BadRequest(err.msg))
+                    return RusotoError::service(PutIntentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutIntentError::Conflict(err.msg))
+                    return RusotoError::service(PutIntentError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutIntentError::InternalFailure(err.msg))
+                    return RusotoError::service(PutIntentError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutIntentError::LimitExceeded(err.msg), &res)
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutIntentError::PreconditionFailed(err.msg))
+                    return RusotoError::service(PutIntentError::PreconditionFailed(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutIntentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3751,12 +4043,14 @@ impl fmt::Display for PutIntentError {
             PutIntentError::InternalFailure(ref cause) => write!(f, "{}", cause),
             PutIntentError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutIntentError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
+            PutIntentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutIntentError {}
 /// Errors returned by PutSlotType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutSlotTypeError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3768,6 +4062,8 @@ pub enum PutSlotTypeError {
     LimitExceeded(String),
     /// <p> The checksum of the resource that you are trying to change does not match the checksum in the request. Check the resource's checksum and try again.</p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutSlotTypeError {
@@ -3775,22 +4071,25 @@ impl PutSlotTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutSlotTypeError::BadRequest(err.msg))
+                    return RusotoError::service(PutSlotTypeError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutSlotTypeError::Conflict(err.msg))
+                    return RusotoError::service(PutSlotTypeError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutSlotTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(PutSlotTypeError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSlotTypeError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutSlotTypeError::LimitExceeded(err.msg), &res)
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutSlotTypeError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        PutSlotTypeError::PreconditionFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutSlotTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3805,12 +4104,14 @@ impl fmt::Display for PutSlotTypeError {
             PutSlotTypeError::InternalFailure(ref cause) => write!(f, "{}", cause),
             PutSlotTypeError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutSlotTypeError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
+            PutSlotTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutSlotTypeError {}
 /// Errors returned by StartImport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartImportError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and try again.</p>
     BadRequest(String),
@@ -3818,6 +4119,8 @@ pub enum StartImportError {
     InternalFailure(String),
     /// <p>The request exceeded a limit. Try your request again.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartImportError {
@@ -3825,16 +4128,16 @@ impl StartImportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartImportError::BadRequest(err.msg))
+                    return RusotoError::service(StartImportError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartImportError::InternalFailure(err.msg))
+                    return RusotoError::service(StartImportError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartImportError::LimitExceeded(err.msg))
+                    return RusotoError::service(StartImportError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartImportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3847,6 +4150,7 @@ impl fmt::Display for StartImportError {
             StartImportError::BadRequest(ref cause) => write!(f, "{}", cause),
             StartImportError::InternalFailure(ref cause) => write!(f, "{}", cause),
             StartImportError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            StartImportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4078,6 +4382,7 @@ impl LexModelsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> LexModelsClient {
         LexModelsClient {
             client: Client::shared(),
@@ -4103,133 +4408,295 @@ impl LexModelsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> LexModelsClient {
         LexModelsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<LexModelsClient> {
+        rusoto_core::ClientBuilder::new(LexModelsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> LexModelsClient {
+        LexModelsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl LexModels for LexModelsClient {
-    /// <p>Creates a new version of the bot based on the <code>$LATEST</code> version. If the <code>$LATEST</code> version of this resource hasn't changed since you created the last version, Amazon Lex doesn't create a new version. It returns the last created version.</p> <note> <p>You can update only the <code>$LATEST</code> version of the bot. You can't update the numbered versions that you create with the <code>CreateBotVersion</code> operation.</p> </note> <p> When you create the first version of a bot, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p> This operation requires permission for the <code>lex:CreateBotVersion</code> action. </p>
-    async fn create_bot_version(
+impl LexModelsClient {
+    /// Calls `create_bot_version`, also returning the metadata of the response.
+    pub async fn create_bot_version_with_metadata(
         &self,
         input: CreateBotVersionRequest,
-    ) -> Result<CreateBotVersionResponse, RusotoError<CreateBotVersionError>> {
+    ) -> Result<(CreateBotVersionResponse, ResponseMetadata), RusotoError<CreateBotVersionError>>
+    {
+        let request = self.build_create_bot_version_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBotVersionResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateBotVersionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_bot_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_bot_version(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateBotVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_bot_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_bot_version_request(&self, input: CreateBotVersionRequest) -> SignedRequest {
         let request_uri = format!("/bots/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateBotVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_intent_version`, also returning the metadata of the response.
+    pub async fn create_intent_version_with_metadata(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> Result<
+        (CreateIntentVersionResponse, ResponseMetadata),
+        RusotoError<CreateIntentVersionError>,
+    > {
+        let request = self.build_create_intent_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBotVersionResponse, _>()?;
+                .deserialize::<CreateIntentVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBotVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateIntentVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates a new version of an intent based on the <code>$LATEST</code> version of the intent. If the <code>$LATEST</code> version of this intent hasn't changed since you last updated it, Amazon Lex doesn't create a new version. It returns the last version you created.</p> <note> <p>You can update only the <code>$LATEST</code> version of the intent. You can't update the numbered versions that you create with the <code>CreateIntentVersion</code> operation.</p> </note> <p> When you create a version of an intent, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions to perform the <code>lex:CreateIntentVersion</code> action. </p>
-    async fn create_intent_version(
+    /// Presigns the request `create_intent_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_intent_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIntentVersionRequest,
-    ) -> Result<CreateIntentVersionResponse, RusotoError<CreateIntentVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_intent_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_intent_version_request(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/intents/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateIntentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_slot_type_version`, also returning the metadata of the response.
+    pub async fn create_slot_type_version_with_metadata(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> Result<
+        (CreateSlotTypeVersionResponse, ResponseMetadata),
+        RusotoError<CreateSlotTypeVersionError>,
+    > {
+        let request = self.build_create_slot_type_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateIntentVersionResponse, _>()?;
+                .deserialize::<CreateSlotTypeVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIntentVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateSlotTypeVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates a new version of a slot type based on the <code>$LATEST</code> version of the specified slot type. If the <code>$LATEST</code> version of this resource has not changed since the last version that you created, Amazon Lex doesn't create a new version. It returns the last version that you created. </p> <note> <p>You can update only the <code>$LATEST</code> version of a slot type. You can't update the numbered versions that you create with the <code>CreateSlotTypeVersion</code> operation.</p> </note> <p>When you create a version of a slot type, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions for the <code>lex:CreateSlotTypeVersion</code> action.</p>
-    async fn create_slot_type_version(
+    /// Presigns the request `create_slot_type_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_slot_type_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSlotTypeVersionRequest,
-    ) -> Result<CreateSlotTypeVersionResponse, RusotoError<CreateSlotTypeVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_slot_type_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_slot_type_version_request(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/slottypes/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateSlotTypeVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_bot`, also returning the metadata of the response.
+    pub async fn delete_bot_with_metadata(
+        &self,
+        input: DeleteBotRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBotError>> {
+        let request = self.build_delete_bot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSlotTypeVersionResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSlotTypeVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBotError::from_response(response))
         }
     }
 
-    /// <p>Deletes all versions of the bot, including the <code>$LATEST</code> version. To delete a specific version of the bot, use the <a>DeleteBotVersion</a> operation. The <code>DeleteBot</code> operation doesn't immediately remove the bot schema. Instead, it is marked for deletion and removed later.</p> <p>Amazon Lex stores utterances indefinitely for improving the ability of your bot to respond to user inputs. These utterances are not removed when the bot is deleted. To remove the utterances, use the <a>DeleteUtterances</a> operation.</p> <p>If a bot has an alias, you can't delete it. Instead, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the alias that refers to the bot. To remove the reference to the bot, delete the alias. If you get the same exception again, delete the referring alias until the <code>DeleteBot</code> operation is successful.</p> <p>This operation requires permissions for the <code>lex:DeleteBot</code> action.</p>
-    async fn delete_bot(&self, input: DeleteBotRequest) -> Result<(), RusotoError<DeleteBotError>> {
+    /// Presigns the request `delete_bot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bot(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteBotRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_bot_request(&self, input: DeleteBotRequest) -> SignedRequest {
         let request_uri = format!("/bots/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `delete_bot_alias`, also returning the metadata of the response.
+    pub async fn delete_bot_alias_with_metadata(
+        &self,
+        input: DeleteBotAliasRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBotAliasError>> {
+        let request = self.build_delete_bot_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBotError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBotAliasError::from_response(response))
         }
     }
 
-    /// <p>Deletes an alias for the specified bot. </p> <p>You can't delete an alias that is used in the association between a bot and a messaging channel. If an alias is used in a channel association, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the channel association that refers to the bot. You can remove the reference to the alias by deleting the channel association. If you get the same exception again, delete the referring association until the <code>DeleteBotAlias</code> operation is successful.</p>
-    async fn delete_bot_alias(
+    /// Presigns the request `delete_bot_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bot_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBotAliasRequest,
-    ) -> Result<(), RusotoError<DeleteBotAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bot_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_bot_alias_request(&self, input: DeleteBotAliasRequest) -> SignedRequest {
         let request_uri = format!(
             "/bots/{bot_name}/aliases/{name}",
             bot_name = input.bot_name,
@@ -4237,31 +4704,58 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `delete_bot_channel_association`, also returning the metadata of the response.
+    pub async fn delete_bot_channel_association_with_metadata(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBotChannelAssociationError>> {
+        let request = self.build_delete_bot_channel_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBotAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBotChannelAssociationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permission for the <code>lex:DeleteBotChannelAssociation</code> action.</p>
-    async fn delete_bot_channel_association(
+    /// Presigns the request `delete_bot_channel_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bot_channel_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBotChannelAssociationRequest,
-    ) -> Result<(), RusotoError<DeleteBotChannelAssociationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bot_channel_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_bot_channel_association_request(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/bots/{bot_name}/aliases/{alias_name}/channels/{name}",
             alias_name = input.bot_alias,
@@ -4270,31 +4764,55 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotChannelAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `delete_bot_version`, also returning the metadata of the response.
+    pub async fn delete_bot_version_with_metadata(
+        &self,
+        input: DeleteBotVersionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBotVersionError>> {
+        let request = self.build_delete_bot_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBotChannelAssociationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBotVersionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specific version of a bot. To delete all versions of a bot, use the <a>DeleteBot</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteBotVersion</code> action.</p>
-    async fn delete_bot_version(
+    /// Presigns the request `delete_bot_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bot_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBotVersionRequest,
-    ) -> Result<(), RusotoError<DeleteBotVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bot_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_bot_version_request(&self, input: DeleteBotVersionRequest) -> SignedRequest {
         let request_uri = format!(
             "/bots/{name}/versions/{version}",
             name = input.name,
@@ -4302,59 +4820,110 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `delete_intent`, also returning the metadata of the response.
+    pub async fn delete_intent_with_metadata(
+        &self,
+        input: DeleteIntentRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteIntentError>> {
+        let request = self.build_delete_intent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBotVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteIntentError::from_response(response))
         }
     }
 
-    /// <p>Deletes all versions of the intent, including the <code>$LATEST</code> version. To delete a specific version of the intent, use the <a>DeleteIntentVersion</a> operation.</p> <p> You can delete a version of an intent only if it is not referenced. To delete an intent that is referred to in one or more bots (see <a>how-it-works</a>), you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, it provides an example reference that shows where the intent is referenced. To remove the reference to the intent, either update the bot or delete it. If you get the same exception when you attempt to delete the intent again, repeat until the intent has no references and the call to <code>DeleteIntent</code> is successful. </p> </note> <p> This operation requires permission for the <code>lex:DeleteIntent</code> action. </p>
-    async fn delete_intent(
+    /// Presigns the request `delete_intent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_intent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIntentRequest,
-    ) -> Result<(), RusotoError<DeleteIntentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_intent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_intent_request(&self, input: DeleteIntentRequest) -> SignedRequest {
         let request_uri = format!("/intents/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `delete_intent_version`, also returning the metadata of the response.
+    pub async fn delete_intent_version_with_metadata(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteIntentVersionError>> {
+        let request = self.build_delete_intent_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIntentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteIntentVersionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specific version of an intent. To delete all versions of a intent, use the <a>DeleteIntent</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteIntentVersion</code> action.</p>
-    async fn delete_intent_version(
+    /// Presigns the request `delete_intent_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_intent_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIntentVersionRequest,
-    ) -> Result<(), RusotoError<DeleteIntentVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_intent_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_intent_version_request(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/intents/{name}/versions/{version}",
             name = input.name,
@@ -4362,59 +4931,110 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `delete_slot_type`, also returning the metadata of the response.
+    pub async fn delete_slot_type_with_metadata(
+        &self,
+        input: DeleteSlotTypeRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSlotTypeError>> {
+        let request = self.build_delete_slot_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIntentVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteSlotTypeError::from_response(response))
         }
     }
 
-    /// <p>Deletes all versions of the slot type, including the <code>$LATEST</code> version. To delete a specific version of the slot type, use the <a>DeleteSlotTypeVersion</a> operation.</p> <p> You can delete a version of a slot type only if it is not referenced. To delete a slot type that is referred to in one or more intents, you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, the exception provides an example reference that shows the intent where the slot type is referenced. To remove the reference to the slot type, either update the intent or delete it. If you get the same exception when you attempt to delete the slot type again, repeat until the slot type has no references and the <code>DeleteSlotType</code> call is successful. </p> </note> <p>This operation requires permission for the <code>lex:DeleteSlotType</code> action.</p>
-    async fn delete_slot_type(
+    /// Presigns the request `delete_slot_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_slot_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSlotTypeRequest,
-    ) -> Result<(), RusotoError<DeleteSlotTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_slot_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_slot_type_request(&self, input: DeleteSlotTypeRequest) -> SignedRequest {
         let request_uri = format!("/slottypes/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `delete_slot_type_version`, also returning the metadata of the response.
+    pub async fn delete_slot_type_version_with_metadata(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSlotTypeVersionError>> {
+        let request = self.build_delete_slot_type_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSlotTypeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteSlotTypeVersionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specific version of a slot type. To delete all versions of a slot type, use the <a>DeleteSlotType</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteSlotTypeVersion</code> action.</p>
-    async fn delete_slot_type_version(
+    /// Presigns the request `delete_slot_type_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_slot_type_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSlotTypeVersionRequest,
-    ) -> Result<(), RusotoError<DeleteSlotTypeVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_slot_type_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_slot_type_version_request(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/slottypes/{name}/version/{version}",
             name = input.name,
@@ -4422,31 +5042,55 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotTypeVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `delete_utterances`, also returning the metadata of the response.
+    pub async fn delete_utterances_with_metadata(
+        &self,
+        input: DeleteUtterancesRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUtterancesError>> {
+        let request = self.build_delete_utterances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSlotTypeVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteUtterancesError::from_response(response))
         }
     }
 
-    /// <p>Deletes stored utterances.</p> <p>Amazon Lex stores the utterances that users send to your bot. Utterances are stored for 15 days for use with the <a>GetUtterancesView</a> operation, and then stored indefinitely for use in improving the ability of your bot to respond to user input.</p> <p>Use the <code>DeleteUtterances</code> operation to manually delete stored utterances for a specific user. When you use the <code>DeleteUtterances</code> operation, utterances stored for improving your bot's ability to respond to user input are deleted immediately. Utterances stored for use with the <code>GetUtterancesView</code> operation are deleted after 15 days.</p> <p>This operation requires permissions for the <code>lex:DeleteUtterances</code> action.</p>
-    async fn delete_utterances(
+    /// Presigns the request `delete_utterances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_utterances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUtterancesRequest,
-    ) -> Result<(), RusotoError<DeleteUtterancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_utterances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_utterances_request(&self, input: DeleteUtterancesRequest) -> SignedRequest {
         let request_uri = format!(
             "/bots/{bot_name}/utterances/{user_id}",
             bot_name = input.bot_name,
@@ -4454,31 +5098,56 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteUtterances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `get_bot`, also returning the metadata of the response.
+    pub async fn get_bot_with_metadata(
+        &self,
+        input: GetBotRequest,
+    ) -> Result<(GetBotResponse, ResponseMetadata), RusotoError<GetBotError>> {
+        let request = self.build_get_bot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetBotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUtterancesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBotError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata information for a specific bot. You must provide the bot name and the bot version or alias. </p> <p> This operation requires permissions for the <code>lex:GetBot</code> action. </p>
-    async fn get_bot(
+    /// Presigns the request `get_bot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBotRequest,
-    ) -> Result<GetBotResponse, RusotoError<GetBotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bot_request(&self, input: GetBotRequest) -> SignedRequest {
         let request_uri = format!(
             "/bots/{name}/versions/{versionoralias}",
             name = input.name,
@@ -4486,32 +5155,56 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `get_bot_alias`, also returning the metadata of the response.
+    pub async fn get_bot_alias_with_metadata(
+        &self,
+        input: GetBotAliasRequest,
+    ) -> Result<(GetBotAliasResponse, ResponseMetadata), RusotoError<GetBotAliasError>> {
+        let request = self.build_get_bot_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetBotResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetBotAliasResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBotError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBotAliasError::from_response(response))
         }
     }
 
-    /// <p>Returns information about an Amazon Lex bot alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:GetBotAlias</code> action.</p>
-    async fn get_bot_alias(
+    /// Presigns the request `get_bot_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bot_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBotAliasRequest,
-    ) -> Result<GetBotAliasResponse, RusotoError<GetBotAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bot_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bot_alias_request(&self, input: GetBotAliasRequest) -> SignedRequest {
         let request_uri = format!(
             "/bots/{bot_name}/aliases/{name}",
             bot_name = input.bot_name,
@@ -4519,35 +5212,60 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `get_bot_aliases`, also returning the metadata of the response.
+    pub async fn get_bot_aliases_with_metadata(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> Result<(GetBotAliasesResponse, ResponseMetadata), RusotoError<GetBotAliasesError>> {
+        let request = self.build_get_bot_aliases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBotAliasResponse, _>()?;
+                .deserialize::<GetBotAliasesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBotAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBotAliasesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of aliases for a specified Amazon Lex bot.</p> <p>This operation requires permissions for the <code>lex:GetBotAliases</code> action.</p>
-    async fn get_bot_aliases(
+    /// Presigns the request `get_bot_aliases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bot_aliases(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBotAliasesRequest,
-    ) -> Result<GetBotAliasesResponse, RusotoError<GetBotAliasesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bot_aliases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bot_aliases_request(&self, input: GetBotAliasesRequest) -> SignedRequest {
         let request_uri = format!("/bots/{bot_name}/aliases/", bot_name = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4564,28 +5282,57 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bot_channel_association`, also returning the metadata of the response.
+    pub async fn get_bot_channel_association_with_metadata(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> Result<
+        (GetBotChannelAssociationResponse, ResponseMetadata),
+        RusotoError<GetBotChannelAssociationError>,
+    > {
+        let request = self.build_get_bot_channel_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBotAliasesResponse, _>()?;
+                .deserialize::<GetBotChannelAssociationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBotAliasesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBotChannelAssociationError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permissions for the <code>lex:GetBotChannelAssociation</code> action.</p>
-    async fn get_bot_channel_association(
+    /// Presigns the request `get_bot_channel_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bot_channel_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBotChannelAssociationRequest,
-    ) -> Result<GetBotChannelAssociationResponse, RusotoError<GetBotChannelAssociationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bot_channel_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bot_channel_association_request(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/bots/{bot_name}/aliases/{alias_name}/channels/{name}",
             alias_name = input.bot_alias,
@@ -4594,33 +5341,62 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `get_bot_channel_associations`, also returning the metadata of the response.
+    pub async fn get_bot_channel_associations_with_metadata(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> Result<
+        (GetBotChannelAssociationsResponse, ResponseMetadata),
+        RusotoError<GetBotChannelAssociationsError>,
+    > {
+        let request = self.build_get_bot_channel_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBotChannelAssociationResponse, _>()?;
+                .deserialize::<GetBotChannelAssociationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBotChannelAssociationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBotChannelAssociationsError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of all of the channels associated with the specified bot. </p> <p>The <code>GetBotChannelAssociations</code> operation requires permissions for the <code>lex:GetBotChannelAssociations</code> action.</p>
-    async fn get_bot_channel_associations(
+    /// Presigns the request `get_bot_channel_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bot_channel_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBotChannelAssociationsRequest,
-    ) -> Result<GetBotChannelAssociationsResponse, RusotoError<GetBotChannelAssociationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bot_channel_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bot_channel_associations_request(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/bots/{bot_name}/aliases/{alias_name}/channels/",
             alias_name = input.bot_alias,
@@ -4628,6 +5404,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4644,31 +5421,55 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bot_versions`, also returning the metadata of the response.
+    pub async fn get_bot_versions_with_metadata(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> Result<(GetBotVersionsResponse, ResponseMetadata), RusotoError<GetBotVersionsError>> {
+        let request = self.build_get_bot_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBotChannelAssociationsResponse, _>()?;
+                .deserialize::<GetBotVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBotChannelAssociationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBotVersionsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about all of the versions of a bot.</p> <p>The <code>GetBotVersions</code> operation returns a <code>BotMetadata</code> object for each version of a bot. For example, if a bot has three numbered versions, the <code>GetBotVersions</code> operation returns four <code>BotMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetBotVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetBotVersions</code> action.</p>
-    async fn get_bot_versions(
+    /// Presigns the request `get_bot_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bot_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBotVersionsRequest,
-    ) -> Result<GetBotVersionsResponse, RusotoError<GetBotVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bot_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bot_versions_request(&self, input: GetBotVersionsRequest) -> SignedRequest {
         let request_uri = format!("/bots/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4682,31 +5483,55 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bots`, also returning the metadata of the response.
+    pub async fn get_bots_with_metadata(
+        &self,
+        input: GetBotsRequest,
+    ) -> Result<(GetBotsResponse, ResponseMetadata), RusotoError<GetBotsError>> {
+        let request = self.build_get_bots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBotVersionsResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetBotsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBotVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBotsError::from_response(response))
         }
     }
 
-    /// <p>Returns bot information as follows: </p> <ul> <li> <p>If you provide the <code>nameContains</code> field, the response includes information for the <code>$LATEST</code> version of all bots whose name contains the specified string.</p> </li> <li> <p>If you don't specify the <code>nameContains</code> field, the operation returns information about the <code>$LATEST</code> version of all of your bots.</p> </li> </ul> <p>This operation requires permission for the <code>lex:GetBots</code> action.</p>
-    async fn get_bots(
+    /// Presigns the request `get_bots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bots(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBotsRequest,
-    ) -> Result<GetBotsResponse, RusotoError<GetBotsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bots_request(&self, input: GetBotsRequest) -> SignedRequest {
         let request_uri = "/bots/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4723,60 +5548,110 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_builtin_intent`, also returning the metadata of the response.
+    pub async fn get_builtin_intent_with_metadata(
+        &self,
+        input: GetBuiltinIntentRequest,
+    ) -> Result<(GetBuiltinIntentResponse, ResponseMetadata), RusotoError<GetBuiltinIntentError>>
+    {
+        let request = self.build_get_builtin_intent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetBotsResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetBuiltinIntentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBotsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBuiltinIntentError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a built-in intent.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntent</code> action.</p>
-    async fn get_builtin_intent(
+    /// Presigns the request `get_builtin_intent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_builtin_intent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBuiltinIntentRequest,
-    ) -> Result<GetBuiltinIntentResponse, RusotoError<GetBuiltinIntentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_builtin_intent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_builtin_intent_request(&self, input: GetBuiltinIntentRequest) -> SignedRequest {
         let request_uri = format!("/builtins/intents/{signature}", signature = input.signature);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `get_builtin_intents`, also returning the metadata of the response.
+    pub async fn get_builtin_intents_with_metadata(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> Result<(GetBuiltinIntentsResponse, ResponseMetadata), RusotoError<GetBuiltinIntentsError>>
+    {
+        let request = self.build_get_builtin_intents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBuiltinIntentResponse, _>()?;
+                .deserialize::<GetBuiltinIntentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBuiltinIntentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBuiltinIntentsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of built-in intents that meet the specified criteria.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntents</code> action.</p>
-    async fn get_builtin_intents(
+    /// Presigns the request `get_builtin_intents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_builtin_intents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBuiltinIntentsRequest,
-    ) -> Result<GetBuiltinIntentsResponse, RusotoError<GetBuiltinIntentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_builtin_intents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_builtin_intents_request(&self, input: GetBuiltinIntentsRequest) -> SignedRequest {
         let request_uri = "/builtins/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4796,31 +5671,61 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_builtin_slot_types`, also returning the metadata of the response.
+    pub async fn get_builtin_slot_types_with_metadata(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> Result<
+        (GetBuiltinSlotTypesResponse, ResponseMetadata),
+        RusotoError<GetBuiltinSlotTypesError>,
+    > {
+        let request = self.build_get_builtin_slot_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBuiltinIntentsResponse, _>()?;
+                .deserialize::<GetBuiltinSlotTypesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBuiltinIntentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBuiltinSlotTypesError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of built-in slot types that meet the specified criteria.</p> <p>For a list of built-in slot types, see <a href="https://developer.amazon.com/public/solutions/alexa/alexa-skills-kit/docs/built-in-intent-ref/slot-type-reference">Slot Type Reference</a> in the <i>Alexa Skills Kit</i>.</p> <p>This operation requires permission for the <code>lex:GetBuiltInSlotTypes</code> action.</p>
-    async fn get_builtin_slot_types(
+    /// Presigns the request `get_builtin_slot_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_builtin_slot_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBuiltinSlotTypesRequest,
-    ) -> Result<GetBuiltinSlotTypesResponse, RusotoError<GetBuiltinSlotTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_builtin_slot_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_builtin_slot_types_request(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> SignedRequest {
         let request_uri = "/builtins/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinSlotTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4840,31 +5745,55 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_export`, also returning the metadata of the response.
+    pub async fn get_export_with_metadata(
+        &self,
+        input: GetExportRequest,
+    ) -> Result<(GetExportResponse, ResponseMetadata), RusotoError<GetExportError>> {
+        let request = self.build_get_export_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBuiltinSlotTypesResponse, _>()?;
+                .deserialize::<GetExportResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBuiltinSlotTypesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetExportError::from_response(response))
         }
     }
 
-    /// <p>Exports the contents of a Amazon Lex resource in a specified format. </p>
-    async fn get_export(
+    /// Presigns the request `get_export` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_export(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetExportRequest,
-    ) -> Result<GetExportResponse, RusotoError<GetExportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_export_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_export_request(&self, input: GetExportRequest) -> SignedRequest {
         let request_uri = "/exports/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetExport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4876,57 +5805,104 @@ impl LexModels for LexModelsClient {
         params.put("version", &input.version);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_import`, also returning the metadata of the response.
+    pub async fn get_import_with_metadata(
+        &self,
+        input: GetImportRequest,
+    ) -> Result<(GetImportResponse, ResponseMetadata), RusotoError<GetImportError>> {
+        let request = self.build_get_import_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetExportResponse, _>()?;
+                .deserialize::<GetImportResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetExportError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetImportError::from_response(response))
         }
     }
 
-    /// <p>Gets information about an import job started with the <code>StartImport</code> operation.</p>
-    async fn get_import(
+    /// Presigns the request `get_import` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_import(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetImportRequest,
-    ) -> Result<GetImportResponse, RusotoError<GetImportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_import_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_import_request(&self, input: GetImportRequest) -> SignedRequest {
         let request_uri = format!("/imports/{import_id}", import_id = input.import_id);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `get_intent`, also returning the metadata of the response.
+    pub async fn get_intent_with_metadata(
+        &self,
+        input: GetIntentRequest,
+    ) -> Result<(GetIntentResponse, ResponseMetadata), RusotoError<GetIntentError>> {
+        let request = self.build_get_intent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetImportResponse, _>()?;
+                .deserialize::<GetIntentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetImportError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntentError::from_response(response))
         }
     }
 
-    /// <p> Returns information about an intent. In addition to the intent name, you must specify the intent version. </p> <p> This operation requires permissions to perform the <code>lex:GetIntent</code> action. </p>
-    async fn get_intent(
+    /// Presigns the request `get_intent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_intent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntentRequest,
-    ) -> Result<GetIntentResponse, RusotoError<GetIntentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_intent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_intent_request(&self, input: GetIntentRequest) -> SignedRequest {
         let request_uri = format!(
             "/intents/{name}/versions/{version}",
             name = input.name,
@@ -4934,35 +5910,61 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `get_intent_versions`, also returning the metadata of the response.
+    pub async fn get_intent_versions_with_metadata(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> Result<(GetIntentVersionsResponse, ResponseMetadata), RusotoError<GetIntentVersionsError>>
+    {
+        let request = self.build_get_intent_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIntentResponse, _>()?;
+                .deserialize::<GetIntentVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntentVersionsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about all of the versions of an intent.</p> <p>The <code>GetIntentVersions</code> operation returns an <code>IntentMetadata</code> object for each version of an intent. For example, if an intent has three numbered versions, the <code>GetIntentVersions</code> operation returns four <code>IntentMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetIntentVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetIntentVersions</code> action.</p>
-    async fn get_intent_versions(
+    /// Presigns the request `get_intent_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_intent_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntentVersionsRequest,
-    ) -> Result<GetIntentVersionsResponse, RusotoError<GetIntentVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_intent_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_intent_versions_request(&self, input: GetIntentVersionsRequest) -> SignedRequest {
         let request_uri = format!("/intents/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntentVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4976,31 +5978,55 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_intents`, also returning the metadata of the response.
+    pub async fn get_intents_with_metadata(
+        &self,
+        input: GetIntentsRequest,
+    ) -> Result<(GetIntentsResponse, ResponseMetadata), RusotoError<GetIntentsError>> {
+        let request = self.build_get_intents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIntentVersionsResponse, _>()?;
+                .deserialize::<GetIntentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntentVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntentsError::from_response(response))
         }
     }
 
-    /// <p>Returns intent information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all intents that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all intents. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetIntents</code> action. </p>
-    async fn get_intents(
+    /// Presigns the request `get_intents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_intents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntentsRequest,
-    ) -> Result<GetIntentsResponse, RusotoError<GetIntentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_intents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_intents_request(&self, input: GetIntentsRequest) -> SignedRequest {
         let request_uri = "/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5017,28 +6043,51 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_slot_type`, also returning the metadata of the response.
+    pub async fn get_slot_type_with_metadata(
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> Result<(GetSlotTypeResponse, ResponseMetadata), RusotoError<GetSlotTypeError>> {
+        let request = self.build_get_slot_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIntentsResponse, _>()?;
+                .deserialize::<GetSlotTypeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSlotTypeError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific version of a slot type. In addition to specifying the slot type name, you must specify the slot type version.</p> <p>This operation requires permissions for the <code>lex:GetSlotType</code> action.</p>
-    async fn get_slot_type(
+    /// Presigns the request `get_slot_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_slot_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSlotTypeRequest,
-    ) -> Result<GetSlotTypeResponse, RusotoError<GetSlotTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_slot_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_slot_type_request(&self, input: GetSlotTypeRequest) -> SignedRequest {
         let request_uri = format!(
             "/slottypes/{name}/versions/{version}",
             name = input.name,
@@ -5046,35 +6095,66 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
 
+        request
+    }
+
+    /// Calls `get_slot_type_versions`, also returning the metadata of the response.
+    pub async fn get_slot_type_versions_with_metadata(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> Result<
+        (GetSlotTypeVersionsResponse, ResponseMetadata),
+        RusotoError<GetSlotTypeVersionsError>,
+    > {
+        let request = self.build_get_slot_type_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSlotTypeResponse, _>()?;
+                .deserialize::<GetSlotTypeVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSlotTypeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSlotTypeVersionsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about all versions of a slot type.</p> <p>The <code>GetSlotTypeVersions</code> operation returns a <code>SlotTypeMetadata</code> object for each version of a slot type. For example, if a slot type has three numbered versions, the <code>GetSlotTypeVersions</code> operation returns four <code>SlotTypeMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetSlotTypeVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetSlotTypeVersions</code> action.</p>
-    async fn get_slot_type_versions(
+    /// Presigns the request `get_slot_type_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_slot_type_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSlotTypeVersionsRequest,
-    ) -> Result<GetSlotTypeVersionsResponse, RusotoError<GetSlotTypeVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_slot_type_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_slot_type_versions_request(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/slottypes/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypeVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5088,31 +6168,55 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_slot_types`, also returning the metadata of the response.
+    pub async fn get_slot_types_with_metadata(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> Result<(GetSlotTypesResponse, ResponseMetadata), RusotoError<GetSlotTypesError>> {
+        let request = self.build_get_slot_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSlotTypeVersionsResponse, _>()?;
+                .deserialize::<GetSlotTypesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSlotTypeVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSlotTypesError::from_response(response))
         }
     }
 
-    /// <p>Returns slot type information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all slot types that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all slot types. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetSlotTypes</code> action. </p>
-    async fn get_slot_types(
+    /// Presigns the request `get_slot_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_slot_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSlotTypesRequest,
-    ) -> Result<GetSlotTypesResponse, RusotoError<GetSlotTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_slot_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_slot_types_request(&self, input: GetSlotTypesRequest) -> SignedRequest {
         let request_uri = "/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5129,31 +6233,56 @@ impl LexModels for LexModelsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_utterances_view`, also returning the metadata of the response.
+    pub async fn get_utterances_view_with_metadata(
+        &self,
+        input: GetUtterancesViewRequest,
+    ) -> Result<(GetUtterancesViewResponse, ResponseMetadata), RusotoError<GetUtterancesViewError>>
+    {
+        let request = self.build_get_utterances_view_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSlotTypesResponse, _>()?;
+                .deserialize::<GetUtterancesViewResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSlotTypesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUtterancesViewError::from_response(response))
         }
     }
 
-    /// <p>Use the <code>GetUtterancesView</code> operation to get information about the utterances that your users have made to your bot. You can use this list to tune the utterances that your bot responds to.</p> <p>For example, say that you have created a bot to order flowers. After your users have used your bot for a while, use the <code>GetUtterancesView</code> operation to see the requests that they have made and whether they have been successful. You might find that the utterance "I want flowers" is not being recognized. You could add this utterance to the <code>OrderFlowers</code> intent so that your bot recognizes that utterance.</p> <p>After you publish a new version of a bot, you can get information about the old version and the new so that you can compare the performance across the two versions. </p> <p>Utterance statistics are generated once a day. Data is available for the last 15 days. You can request information for up to 5 versions of your bot in each request. Amazon Lex returns the most frequent utterances received by the bot in the last 15 days. The response contains information about a maximum of 100 utterances for each version.</p> <p>If you set <code>childDirected</code> field to true when you created your bot, or if you opted out of participating in improving Amazon Lex, utterances are not available.</p> <p>This operation requires permissions for the <code>lex:GetUtterancesView</code> action.</p>
-    async fn get_utterances_view(
+    /// Presigns the request `get_utterances_view` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_utterances_view(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUtterancesViewRequest,
-    ) -> Result<GetUtterancesViewResponse, RusotoError<GetUtterancesViewError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_utterances_view_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_utterances_view_request(&self, input: GetUtterancesViewRequest) -> SignedRequest {
         let request_uri = format!("/bots/{botname}/utterances", botname = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetUtterancesView");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5166,59 +6295,106 @@ impl LexModels for LexModelsClient {
         params.put("view", "aggregation");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_bot`, also returning the metadata of the response.
+    pub async fn put_bot_with_metadata(
+        &self,
+        input: PutBotRequest,
+    ) -> Result<(PutBotResponse, ResponseMetadata), RusotoError<PutBotError>> {
+        let request = self.build_put_bot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUtterancesViewResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<PutBotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUtterancesViewError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutBotError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon Lex conversational bot or replaces an existing bot. When you create or update a bot you are only required to specify a name, a locale, and whether the bot is directed toward children under age 13. You can use this to add intents later, or to remove intents from an existing bot. When you create a bot with the minimum information, the bot is created or updated but Amazon Lex returns the <code/> response <code>FAILED</code>. You can build the bot after you add one or more intents. For more information about Amazon Lex bots, see <a>how-it-works</a>. </p> <p>If you specify the name of an existing bot, the fields in the request replace the existing values in the <code>$LATEST</code> version of the bot. Amazon Lex removes any fields that you don't provide values for in the request, except for the <code>idleTTLInSeconds</code> and <code>privacySettings</code> fields, which are set to their default values. If you don't specify values for required fields, Amazon Lex throws an exception.</p> <p>This operation requires permissions for the <code>lex:PutBot</code> action. For more information, see <a>security-iam</a>.</p>
-    async fn put_bot(
+    /// Presigns the request `put_bot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBotRequest,
-    ) -> Result<PutBotResponse, RusotoError<PutBotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_bot_request(&self, input: PutBotRequest) -> SignedRequest {
         let request_uri = format!("/bots/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_bot_alias`, also returning the metadata of the response.
+    pub async fn put_bot_alias_with_metadata(
+        &self,
+        input: PutBotAliasRequest,
+    ) -> Result<(PutBotAliasResponse, ResponseMetadata), RusotoError<PutBotAliasError>> {
+        let request = self.build_put_bot_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<PutBotResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutBotAliasResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutBotError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutBotAliasError::from_response(response))
         }
     }
 
-    /// <p>Creates an alias for the specified version of the bot or replaces an alias for the specified bot. To change the version of the bot that the alias points to, replace the alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:PutBotAlias</code> action. </p>
-    async fn put_bot_alias(
+    /// Presigns the request `put_bot_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bot_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBotAliasRequest,
-    ) -> Result<PutBotAliasResponse, RusotoError<PutBotAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bot_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_bot_alias_request(&self, input: PutBotAliasRequest) -> SignedRequest {
         let request_uri = format!(
             "/bots/{bot_name}/aliases/{name}",
             bot_name = input.bot_name,
@@ -5226,119 +6402,912 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `put_intent`, also returning the metadata of the response.
+    pub async fn put_intent_with_metadata(
+        &self,
+        input: PutIntentRequest,
+    ) -> Result<(PutIntentResponse, ResponseMetadata), RusotoError<PutIntentError>> {
+        let request = self.build_put_intent_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutBotAliasResponse, _>()?;
+                .deserialize::<PutIntentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutBotAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutIntentError::from_response(response))
         }
     }
 
-    /// <p>Creates an intent or replaces an existing intent.</p> <p>To define the interaction between the user and your bot, you use one or more intents. For a pizza ordering bot, for example, you would create an <code>OrderPizza</code> intent. </p> <p>To create an intent or replace an existing intent, you must provide the following:</p> <ul> <li> <p>Intent name. For example, <code>OrderPizza</code>.</p> </li> <li> <p>Sample utterances. For example, "Can I order a pizza, please." and "I want to order a pizza."</p> </li> <li> <p>Information to be gathered. You specify slot types for the information that your bot will request from the user. You can specify standard slot types, such as a date or a time, or custom slot types such as the size and crust of a pizza.</p> </li> <li> <p>How the intent will be fulfilled. You can provide a Lambda function or configure the intent to return the intent information to the client application. If you use a Lambda function, when all of the intent information is available, Amazon Lex invokes your Lambda function. If you configure your intent to return the intent information to the client application. </p> </li> </ul> <p>You can specify other optional information in the request, such as:</p> <ul> <li> <p>A confirmation prompt to ask the user to confirm an intent. For example, "Shall I order your pizza?"</p> </li> <li> <p>A conclusion statement to send to the user after the intent has been fulfilled. For example, "I placed your pizza order."</p> </li> <li> <p>A follow-up prompt that asks the user for additional activity. For example, asking "Do you want to order a drink with your pizza?"</p> </li> </ul> <p>If you specify an existing intent name to update the intent, Amazon Lex replaces the values in the <code>$LATEST</code> version of the intent with the values in the request. Amazon Lex removes fields that you don't provide in the request. If you don't specify the required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of an intent, the <code>status</code> field of any bot that uses the <code>$LATEST</code> version of the intent is set to <code>NOT_BUILT</code>.</p> <p>For more information, see <a>how-it-works</a>.</p> <p>This operation requires permissions for the <code>lex:PutIntent</code> action.</p>
-    async fn put_intent(
+    /// Presigns the request `put_intent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_intent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutIntentRequest,
-    ) -> Result<PutIntentResponse, RusotoError<PutIntentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_intent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_intent_request(&self, input: PutIntentRequest) -> SignedRequest {
         let request_uri = format!("/intents/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_slot_type`, also returning the metadata of the response.
+    pub async fn put_slot_type_with_metadata(
+        &self,
+        input: PutSlotTypeRequest,
+    ) -> Result<(PutSlotTypeResponse, ResponseMetadata), RusotoError<PutSlotTypeError>> {
+        let request = self.build_put_slot_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutIntentResponse, _>()?;
+                .deserialize::<PutSlotTypeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutIntentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutSlotTypeError::from_response(response))
         }
     }
 
-    /// <p>Creates a custom slot type or replaces an existing custom slot type.</p> <p>To create a custom slot type, specify a name for the slot type and a set of enumeration values, which are the values that a slot of this type can assume. For more information, see <a>how-it-works</a>.</p> <p>If you specify the name of an existing slot type, the fields in the request replace the existing values in the <code>$LATEST</code> version of the slot type. Amazon Lex removes the fields that you don't provide in the request. If you don't specify required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of a slot type, if a bot uses the <code>$LATEST</code> version of an intent that contains the slot type, the bot's <code>status</code> field is set to <code>NOT_BUILT</code>.</p> <p>This operation requires permissions for the <code>lex:PutSlotType</code> action.</p>
-    async fn put_slot_type(
+    /// Presigns the request `put_slot_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_slot_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutSlotTypeRequest,
-    ) -> Result<PutSlotTypeResponse, RusotoError<PutSlotTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_slot_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_slot_type_request(&self, input: PutSlotTypeRequest) -> SignedRequest {
         let request_uri = format!("/slottypes/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_import`, also returning the metadata of the response.
+    pub async fn start_import_with_metadata(
+        &self,
+        input: StartImportRequest,
+    ) -> Result<(StartImportResponse, ResponseMetadata), RusotoError<StartImportError>> {
+        let request = self.build_start_import_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutSlotTypeResponse, _>()?;
+                .deserialize::<StartImportResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutSlotTypeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartImportError::from_response(response))
         }
     }
 
-    /// <p>Starts a job to import a resource to Amazon Lex.</p>
-    async fn start_import(
+    /// Presigns the request `start_import` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_import(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartImportRequest,
-    ) -> Result<StartImportResponse, RusotoError<StartImportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_import_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_import_request(&self, input: StartImportRequest) -> SignedRequest {
         let request_uri = "/imports/";
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("StartImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetBotAliases` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_bot_aliases_pages(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> impl futures::Stream<Item = Result<GetBotAliasesResponse, RusotoError<GetBotAliasesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move { client.get_bot_aliases(input).await },
+            |input: &GetBotAliasesRequest, output: &GetBotAliasesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetBotAliasesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BotAliases` of every page of `GetBotAliases` results for `input`, as `get_bot_aliases_pages` requests them.
+    pub fn get_bot_aliases_stream(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> impl futures::Stream<Item = Result<BotAliasMetadata, RusotoError<GetBotAliasesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_bot_aliases_pages(input), |page| {
+            page.bot_aliases.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetBotChannelAssociations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_bot_channel_associations_pages(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetBotChannelAssociationsResponse,
+            RusotoError<GetBotChannelAssociationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move {
+                client.get_bot_channel_associations(input).await
+            },
+            |input: &GetBotChannelAssociationsRequest,
+             output: &GetBotChannelAssociationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetBotChannelAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `botChannelAssociations` of every page of `GetBotChannelAssociations` results for `input`, as `get_bot_channel_associations_pages` requests them.
+    pub fn get_bot_channel_associations_stream(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<BotChannelAssociation, RusotoError<GetBotChannelAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_bot_channel_associations_pages(input), |page| {
+            page.bot_channel_associations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetBotVersions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_bot_versions_pages(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<GetBotVersionsResponse, RusotoError<GetBotVersionsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move { client.get_bot_versions(input).await },
+            |input: &GetBotVersionsRequest, output: &GetBotVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetBotVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `bots` of every page of `GetBotVersions` results for `input`, as `get_bot_versions_pages` requests them.
+    pub fn get_bot_versions_stream(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<BotMetadata, RusotoError<GetBotVersionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_bot_versions_pages(input), |page| {
+            page.bots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetBots` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_bots_pages(
+        &self,
+        input: GetBotsRequest,
+    ) -> impl futures::Stream<Item = Result<GetBotsResponse, RusotoError<GetBotsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move { client.get_bots(input).await },
+            |input: &GetBotsRequest, output: &GetBotsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetBotsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `bots` of every page of `GetBots` results for `input`, as `get_bots_pages` requests them.
+    pub fn get_bots_stream(
+        &self,
+        input: GetBotsRequest,
+    ) -> impl futures::Stream<Item = Result<BotMetadata, RusotoError<GetBotsError>>> + Send {
+        rusoto_core::pagination::items(self.get_bots_pages(input), |page| {
+            page.bots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetBuiltinIntents` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_builtin_intents_pages(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetBuiltinIntentsResponse, RusotoError<GetBuiltinIntentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move { client.get_builtin_intents(input).await },
+            |input: &GetBuiltinIntentsRequest, output: &GetBuiltinIntentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetBuiltinIntentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `intents` of every page of `GetBuiltinIntents` results for `input`, as `get_builtin_intents_pages` requests them.
+    pub fn get_builtin_intents_stream(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<BuiltinIntentMetadata, RusotoError<GetBuiltinIntentsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_builtin_intents_pages(input), |page| {
+            page.intents.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetBuiltinSlotTypes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_builtin_slot_types_pages(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetBuiltinSlotTypesResponse, RusotoError<GetBuiltinSlotTypesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move { client.get_builtin_slot_types(input).await },
+            |input: &GetBuiltinSlotTypesRequest, output: &GetBuiltinSlotTypesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetBuiltinSlotTypesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `slotTypes` of every page of `GetBuiltinSlotTypes` results for `input`, as `get_builtin_slot_types_pages` requests them.
+    pub fn get_builtin_slot_types_stream(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<BuiltinSlotTypeMetadata, RusotoError<GetBuiltinSlotTypesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_builtin_slot_types_pages(input), |page| {
+            page.slot_types.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetIntentVersions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_intent_versions_pages(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetIntentVersionsResponse, RusotoError<GetIntentVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move { client.get_intent_versions(input).await },
+            |input: &GetIntentVersionsRequest, output: &GetIntentVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetIntentVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `intents` of every page of `GetIntentVersions` results for `input`, as `get_intent_versions_pages` requests them.
+    pub fn get_intent_versions_stream(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<IntentMetadata, RusotoError<GetIntentVersionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_intent_versions_pages(input), |page| {
+            page.intents.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetIntents` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_intents_pages(
+        &self,
+        input: GetIntentsRequest,
+    ) -> impl futures::Stream<Item = Result<GetIntentsResponse, RusotoError<GetIntentsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move { client.get_intents(input).await },
+            |input: &GetIntentsRequest, output: &GetIntentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetIntentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `intents` of every page of `GetIntents` results for `input`, as `get_intents_pages` requests them.
+    pub fn get_intents_stream(
+        &self,
+        input: GetIntentsRequest,
+    ) -> impl futures::Stream<Item = Result<IntentMetadata, RusotoError<GetIntentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_intents_pages(input), |page| {
+            page.intents.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetSlotTypeVersions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_slot_type_versions_pages(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetSlotTypeVersionsResponse, RusotoError<GetSlotTypeVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move { client.get_slot_type_versions(input).await },
+            |input: &GetSlotTypeVersionsRequest, output: &GetSlotTypeVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetSlotTypeVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `slotTypes` of every page of `GetSlotTypeVersions` results for `input`, as `get_slot_type_versions_pages` requests them.
+    pub fn get_slot_type_versions_stream(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<SlotTypeMetadata, RusotoError<GetSlotTypeVersionsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.get_slot_type_versions_pages(input), |page| {
+            page.slot_types.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetSlotTypes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_slot_types_pages(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> impl futures::Stream<Item = Result<GetSlotTypesResponse, RusotoError<GetSlotTypesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LexModelsClient, input| async move { client.get_slot_types(input).await },
+            |input: &GetSlotTypesRequest, output: &GetSlotTypesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetSlotTypesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `slotTypes` of every page of `GetSlotTypes` results for `input`, as `get_slot_types_pages` requests them.
+    pub fn get_slot_types_stream(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> impl futures::Stream<Item = Result<SlotTypeMetadata, RusotoError<GetSlotTypesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_slot_types_pages(input), |page| {
+            page.slot_types.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl LexModels for LexModelsClient {
+    /// <p>Creates a new version of the bot based on the <code>$LATEST</code> version. If the <code>$LATEST</code> version of this resource hasn't changed since you created the last version, Amazon Lex doesn't create a new version. It returns the last created version.</p> <note> <p>You can update only the <code>$LATEST</code> version of the bot. You can't update the numbered versions that you create with the <code>CreateBotVersion</code> operation.</p> </note> <p> When you create the first version of a bot, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p> This operation requires permission for the <code>lex:CreateBotVersion</code> action. </p>
+    async fn create_bot_version(
+        &self,
+        input: CreateBotVersionRequest,
+    ) -> Result<CreateBotVersionResponse, RusotoError<CreateBotVersionError>> {
+        self.create_bot_version_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartImportResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartImportError::from_response(response))
-        }
+    /// <p>Creates a new version of an intent based on the <code>$LATEST</code> version of the intent. If the <code>$LATEST</code> version of this intent hasn't changed since you last updated it, Amazon Lex doesn't create a new version. It returns the last version you created.</p> <note> <p>You can update only the <code>$LATEST</code> version of the intent. You can't update the numbered versions that you create with the <code>CreateIntentVersion</code> operation.</p> </note> <p> When you create a version of an intent, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions to perform the <code>lex:CreateIntentVersion</code> action. </p>
+    async fn create_intent_version(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> Result<CreateIntentVersionResponse, RusotoError<CreateIntentVersionError>> {
+        self.create_intent_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new version of a slot type based on the <code>$LATEST</code> version of the specified slot type. If the <code>$LATEST</code> version of this resource has not changed since the last version that you created, Amazon Lex doesn't create a new version. It returns the last version that you created. </p> <note> <p>You can update only the <code>$LATEST</code> version of a slot type. You can't update the numbered versions that you create with the <code>CreateSlotTypeVersion</code> operation.</p> </note> <p>When you create a version of a slot type, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions for the <code>lex:CreateSlotTypeVersion</code> action.</p>
+    async fn create_slot_type_version(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> Result<CreateSlotTypeVersionResponse, RusotoError<CreateSlotTypeVersionError>> {
+        self.create_slot_type_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes all versions of the bot, including the <code>$LATEST</code> version. To delete a specific version of the bot, use the <a>DeleteBotVersion</a> operation. The <code>DeleteBot</code> operation doesn't immediately remove the bot schema. Instead, it is marked for deletion and removed later.</p> <p>Amazon Lex stores utterances indefinitely for improving the ability of your bot to respond to user inputs. These utterances are not removed when the bot is deleted. To remove the utterances, use the <a>DeleteUtterances</a> operation.</p> <p>If a bot has an alias, you can't delete it. Instead, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the alias that refers to the bot. To remove the reference to the bot, delete the alias. If you get the same exception again, delete the referring alias until the <code>DeleteBot</code> operation is successful.</p> <p>This operation requires permissions for the <code>lex:DeleteBot</code> action.</p>
+    async fn delete_bot(&self, input: DeleteBotRequest) -> Result<(), RusotoError<DeleteBotError>> {
+        self.delete_bot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an alias for the specified bot. </p> <p>You can't delete an alias that is used in the association between a bot and a messaging channel. If an alias is used in a channel association, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the channel association that refers to the bot. You can remove the reference to the alias by deleting the channel association. If you get the same exception again, delete the referring association until the <code>DeleteBotAlias</code> operation is successful.</p>
+    async fn delete_bot_alias(
+        &self,
+        input: DeleteBotAliasRequest,
+    ) -> Result<(), RusotoError<DeleteBotAliasError>> {
+        self.delete_bot_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permission for the <code>lex:DeleteBotChannelAssociation</code> action.</p>
+    async fn delete_bot_channel_association(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> Result<(), RusotoError<DeleteBotChannelAssociationError>> {
+        self.delete_bot_channel_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specific version of a bot. To delete all versions of a bot, use the <a>DeleteBot</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteBotVersion</code> action.</p>
+    async fn delete_bot_version(
+        &self,
+        input: DeleteBotVersionRequest,
+    ) -> Result<(), RusotoError<DeleteBotVersionError>> {
+        self.delete_bot_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes all versions of the intent, including the <code>$LATEST</code> version. To delete a specific version of the intent, use the <a>DeleteIntentVersion</a> operation.</p> <p> You can delete a version of an intent only if it is not referenced. To delete an intent that is referred to in one or more bots (see <a>how-it-works</a>), you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, it provides an example reference that shows where the intent is referenced. To remove the reference to the intent, either update the bot or delete it. If you get the same exception when you attempt to delete the intent again, repeat until the intent has no references and the call to <code>DeleteIntent</code> is successful. </p> </note> <p> This operation requires permission for the <code>lex:DeleteIntent</code> action. </p>
+    async fn delete_intent(
+        &self,
+        input: DeleteIntentRequest,
+    ) -> Result<(), RusotoError<DeleteIntentError>> {
+        self.delete_intent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specific version of an intent. To delete all versions of a intent, use the <a>DeleteIntent</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteIntentVersion</code> action.</p>
+    async fn delete_intent_version(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> Result<(), RusotoError<DeleteIntentVersionError>> {
+        self.delete_intent_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes all versions of the slot type, including the <code>$LATEST</code> version. To delete a specific version of the slot type, use the <a>DeleteSlotTypeVersion</a> operation.</p> <p> You can delete a version of a slot type only if it is not referenced. To delete a slot type that is referred to in one or more intents, you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, the exception provides an example reference that shows the intent where the slot type is referenced. To remove the reference to the slot type, either update the intent or delete it. If you get the same exception when you attempt to delete the slot type again, repeat until the slot type has no references and the <code>DeleteSlotType</code> call is successful. </p> </note> <p>This operation requires permission for the <code>lex:DeleteSlotType</code> action.</p>
+    async fn delete_slot_type(
+        &self,
+        input: DeleteSlotTypeRequest,
+    ) -> Result<(), RusotoError<DeleteSlotTypeError>> {
+        self.delete_slot_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specific version of a slot type. To delete all versions of a slot type, use the <a>DeleteSlotType</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteSlotTypeVersion</code> action.</p>
+    async fn delete_slot_type_version(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> Result<(), RusotoError<DeleteSlotTypeVersionError>> {
+        self.delete_slot_type_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes stored utterances.</p> <p>Amazon Lex stores the utterances that users send to your bot. Utterances are stored for 15 days for use with the <a>GetUtterancesView</a> operation, and then stored indefinitely for use in improving the ability of your bot to respond to user input.</p> <p>Use the <code>DeleteUtterances</code> operation to manually delete stored utterances for a specific user. When you use the <code>DeleteUtterances</code> operation, utterances stored for improving your bot's ability to respond to user input are deleted immediately. Utterances stored for use with the <code>GetUtterancesView</code> operation are deleted after 15 days.</p> <p>This operation requires permissions for the <code>lex:DeleteUtterances</code> action.</p>
+    async fn delete_utterances(
+        &self,
+        input: DeleteUtterancesRequest,
+    ) -> Result<(), RusotoError<DeleteUtterancesError>> {
+        self.delete_utterances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata information for a specific bot. You must provide the bot name and the bot version or alias. </p> <p> This operation requires permissions for the <code>lex:GetBot</code> action. </p>
+    async fn get_bot(
+        &self,
+        input: GetBotRequest,
+    ) -> Result<GetBotResponse, RusotoError<GetBotError>> {
+        self.get_bot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about an Amazon Lex bot alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:GetBotAlias</code> action.</p>
+    async fn get_bot_alias(
+        &self,
+        input: GetBotAliasRequest,
+    ) -> Result<GetBotAliasResponse, RusotoError<GetBotAliasError>> {
+        self.get_bot_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of aliases for a specified Amazon Lex bot.</p> <p>This operation requires permissions for the <code>lex:GetBotAliases</code> action.</p>
+    async fn get_bot_aliases(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> Result<GetBotAliasesResponse, RusotoError<GetBotAliasesError>> {
+        self.get_bot_aliases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permissions for the <code>lex:GetBotChannelAssociation</code> action.</p>
+    async fn get_bot_channel_association(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> Result<GetBotChannelAssociationResponse, RusotoError<GetBotChannelAssociationError>> {
+        self.get_bot_channel_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of all of the channels associated with the specified bot. </p> <p>The <code>GetBotChannelAssociations</code> operation requires permissions for the <code>lex:GetBotChannelAssociations</code> action.</p>
+    async fn get_bot_channel_associations(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> Result<GetBotChannelAssociationsResponse, RusotoError<GetBotChannelAssociationsError>>
+    {
+        self.get_bot_channel_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about all of the versions of a bot.</p> <p>The <code>GetBotVersions</code> operation returns a <code>BotMetadata</code> object for each version of a bot. For example, if a bot has three numbered versions, the <code>GetBotVersions</code> operation returns four <code>BotMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetBotVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetBotVersions</code> action.</p>
+    async fn get_bot_versions(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> Result<GetBotVersionsResponse, RusotoError<GetBotVersionsError>> {
+        self.get_bot_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns bot information as follows: </p> <ul> <li> <p>If you provide the <code>nameContains</code> field, the response includes information for the <code>$LATEST</code> version of all bots whose name contains the specified string.</p> </li> <li> <p>If you don't specify the <code>nameContains</code> field, the operation returns information about the <code>$LATEST</code> version of all of your bots.</p> </li> </ul> <p>This operation requires permission for the <code>lex:GetBots</code> action.</p>
+    async fn get_bots(
+        &self,
+        input: GetBotsRequest,
+    ) -> Result<GetBotsResponse, RusotoError<GetBotsError>> {
+        self.get_bots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a built-in intent.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntent</code> action.</p>
+    async fn get_builtin_intent(
+        &self,
+        input: GetBuiltinIntentRequest,
+    ) -> Result<GetBuiltinIntentResponse, RusotoError<GetBuiltinIntentError>> {
+        self.get_builtin_intent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of built-in intents that meet the specified criteria.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntents</code> action.</p>
+    async fn get_builtin_intents(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> Result<GetBuiltinIntentsResponse, RusotoError<GetBuiltinIntentsError>> {
+        self.get_builtin_intents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of built-in slot types that meet the specified criteria.</p> <p>For a list of built-in slot types, see <a href="https://developer.amazon.com/public/solutions/alexa/alexa-skills-kit/docs/built-in-intent-ref/slot-type-reference">Slot Type Reference</a> in the <i>Alexa Skills Kit</i>.</p> <p>This operation requires permission for the <code>lex:GetBuiltInSlotTypes</code> action.</p>
+    async fn get_builtin_slot_types(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> Result<GetBuiltinSlotTypesResponse, RusotoError<GetBuiltinSlotTypesError>> {
+        self.get_builtin_slot_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Exports the contents of a Amazon Lex resource in a specified format. </p>
+    async fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> Result<GetExportResponse, RusotoError<GetExportError>> {
+        self.get_export_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about an import job started with the <code>StartImport</code> operation.</p>
+    async fn get_import(
+        &self,
+        input: GetImportRequest,
+    ) -> Result<GetImportResponse, RusotoError<GetImportError>> {
+        self.get_import_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns information about an intent. In addition to the intent name, you must specify the intent version. </p> <p> This operation requires permissions to perform the <code>lex:GetIntent</code> action. </p>
+    async fn get_intent(
+        &self,
+        input: GetIntentRequest,
+    ) -> Result<GetIntentResponse, RusotoError<GetIntentError>> {
+        self.get_intent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about all of the versions of an intent.</p> <p>The <code>GetIntentVersions</code> operation returns an <code>IntentMetadata</code> object for each version of an intent. For example, if an intent has three numbered versions, the <code>GetIntentVersions</code> operation returns four <code>IntentMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetIntentVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetIntentVersions</code> action.</p>
+    async fn get_intent_versions(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> Result<GetIntentVersionsResponse, RusotoError<GetIntentVersionsError>> {
+        self.get_intent_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns intent information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all intents that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all intents. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetIntents</code> action. </p>
+    async fn get_intents(
+        &self,
+        input: GetIntentsRequest,
+    ) -> Result<GetIntentsResponse, RusotoError<GetIntentsError>> {
+        self.get_intents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific version of a slot type. In addition to specifying the slot type name, you must specify the slot type version.</p> <p>This operation requires permissions for the <code>lex:GetSlotType</code> action.</p>
+    async fn get_slot_type(
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> Result<GetSlotTypeResponse, RusotoError<GetSlotTypeError>> {
+        self.get_slot_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about all versions of a slot type.</p> <p>The <code>GetSlotTypeVersions</code> operation returns a <code>SlotTypeMetadata</code> object for each version of a slot type. For example, if a slot type has three numbered versions, the <code>GetSlotTypeVersions</code> operation returns four <code>SlotTypeMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetSlotTypeVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetSlotTypeVersions</code> action.</p>
+    async fn get_slot_type_versions(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> Result<GetSlotTypeVersionsResponse, RusotoError<GetSlotTypeVersionsError>> {
+        self.get_slot_type_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns slot type information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all slot types that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all slot types. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetSlotTypes</code> action. </p>
+    async fn get_slot_types(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> Result<GetSlotTypesResponse, RusotoError<GetSlotTypesError>> {
+        self.get_slot_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Use the <code>GetUtterancesView</code> operation to get information about the utterances that your users have made to your bot. You can use this list to tune the utterances that your bot responds to.</p> <p>For example, say that you have created a bot to order flowers. After your users have used your bot for a while, use the <code>GetUtterancesView</code> operation to see the requests that they have made and whether they have been successful. You might find that the utterance "I want flowers" is not being recognized. You could add this utterance to the <code>OrderFlowers</code> intent so that your bot recognizes that utterance.</p> <p>After you publish a new version of a bot, you can get information about the old version and the new so that you can compare the performance across the two versions. </p> <p>Utterance statistics are generated once a day. Data is available for the last 15 days. You can request information for up to 5 versions of your bot in each request. Amazon Lex returns the most frequent utterances received by the bot in the last 15 days. The response contains information about a maximum of 100 utterances for each version.</p> <p>If you set <code>childDirected</code> field to true when you created your bot, or if you opted out of participating in improving Amazon Lex, utterances are not available.</p> <p>This operation requires permissions for the <code>lex:GetUtterancesView</code> action.</p>
+    async fn get_utterances_view(
+        &self,
+        input: GetUtterancesViewRequest,
+    ) -> Result<GetUtterancesViewResponse, RusotoError<GetUtterancesViewError>> {
+        self.get_utterances_view_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon Lex conversational bot or replaces an existing bot. When you create or update a bot you are only required to specify a name, a locale, and whether the bot is directed toward children under age 13. You can use this to add intents later, or to remove intents from an existing bot. When you create a bot with the minimum information, the bot is created or updated but Amazon Lex returns the <code/> response <code>FAILED</code>. You can build the bot after you add one or more intents. For more information about Amazon Lex bots, see <a>how-it-works</a>. </p> <p>If you specify the name of an existing bot, the fields in the request replace the existing values in the <code>$LATEST</code> version of the bot. Amazon Lex removes any fields that you don't provide values for in the request, except for the <code>idleTTLInSeconds</code> and <code>privacySettings</code> fields, which are set to their default values. If you don't specify values for required fields, Amazon Lex throws an exception.</p> <p>This operation requires permissions for the <code>lex:PutBot</code> action. For more information, see <a>security-iam</a>.</p>
+    async fn put_bot(
+        &self,
+        input: PutBotRequest,
+    ) -> Result<PutBotResponse, RusotoError<PutBotError>> {
+        self.put_bot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an alias for the specified version of the bot or replaces an alias for the specified bot. To change the version of the bot that the alias points to, replace the alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:PutBotAlias</code> action. </p>
+    async fn put_bot_alias(
+        &self,
+        input: PutBotAliasRequest,
+    ) -> Result<PutBotAliasResponse, RusotoError<PutBotAliasError>> {
+        self.put_bot_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an intent or replaces an existing intent.</p> <p>To define the interaction between the user and your bot, you use one or more intents. For a pizza ordering bot, for example, you would create an <code>OrderPizza</code> intent. </p> <p>To create an intent or replace an existing intent, you must provide the following:</p> <ul> <li> <p>Intent name. For example, <code>OrderPizza</code>.</p> </li> <li> <p>Sample utterances. For example, "Can I order a pizza, please." and "I want to order a pizza."</p> </li> <li> <p>Information to be gathered. You specify slot types for the information that your bot will request from the user. You can specify standard slot types, such as a date or a time, or custom slot types such as the size and crust of a pizza.</p> </li> <li> <p>How the intent will be fulfilled. You can provide a Lambda function or configure the intent to return the intent information to the client application. If you use a Lambda function, when all of the intent information is available, Amazon Lex invokes your Lambda function. If you configure your intent to return the intent information to the client application. </p> </li> </ul> <p>You can specify other optional information in the request, such as:</p> <ul> <li> <p>A confirmation prompt to ask the user to confirm an intent. For example, "Shall I order your pizza?"</p> </li> <li> <p>A conclusion statement to send to the user after the intent has been fulfilled. For example, "I placed your pizza order."</p> </li> <li> <p>A follow-up prompt that asks the user for additional activity. For example, asking "Do you want to order a drink with your pizza?"</p> </li> </ul> <p>If you specify an existing intent name to update the intent, Amazon Lex replaces the values in the <code>$LATEST</code> version of the intent with the values in the request. Amazon Lex removes fields that you don't provide in the request. If you don't specify the required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of an intent, the <code>status</code> field of any bot that uses the <code>$LATEST</code> version of the intent is set to <code>NOT_BUILT</code>.</p> <p>For more information, see <a>how-it-works</a>.</p> <p>This operation requires permissions for the <code>lex:PutIntent</code> action.</p>
+    async fn put_intent(
+        &self,
+        input: PutIntentRequest,
+    ) -> Result<PutIntentResponse, RusotoError<PutIntentError>> {
+        self.put_intent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a custom slot type or replaces an existing custom slot type.</p> <p>To create a custom slot type, specify a name for the slot type and a set of enumeration values, which are the values that a slot of this type can assume. For more information, see <a>how-it-works</a>.</p> <p>If you specify the name of an existing slot type, the fields in the request replace the existing values in the <code>$LATEST</code> version of the slot type. Amazon Lex removes the fields that you don't provide in the request. If you don't specify required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of a slot type, if a bot uses the <code>$LATEST</code> version of an intent that contains the slot type, the bot's <code>status</code> field is set to <code>NOT_BUILT</code>.</p> <p>This operation requires permissions for the <code>lex:PutSlotType</code> action.</p>
+    async fn put_slot_type(
+        &self,
+        input: PutSlotTypeRequest,
+    ) -> Result<PutSlotTypeResponse, RusotoError<PutSlotTypeError>> {
+        self.put_slot_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a job to import a resource to Amazon Lex.</p>
+    async fn start_import(
+        &self,
+        input: StartImportRequest,
+    ) -> Result<StartImportResponse, RusotoError<StartImportError>> {
+        self.start_import_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -424,6 +424,7 @@ pub struct SentimentResponse {
 
 /// Errors returned by DeleteSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSessionError {
     /// <p> Request validation failed, there is no usable message in the context, or the bot build failed, is still in progress, or contains unbuilt changes. </p>
     BadRequest(String),
@@ -435,6 +436,8 @@ pub enum DeleteSessionError {
     LimitExceeded(String),
     /// <p>The resource (such as the Amazon Lex bot or an alias) that is referred to is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSessionError {
@@ -442,22 +445,22 @@ impl DeleteSessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSessionError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteSessionError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSessionError::Conflict(err.msg))
+                    return RusotoError::service(DeleteSessionError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSessionError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteSessionError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteSessionError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSessionError::NotFound(err.msg))
+                    return RusotoError::service(DeleteSessionError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -472,12 +475,14 @@ impl fmt::Display for DeleteSessionError {
             DeleteSessionError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteSessionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteSessionError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSessionError {}
 /// Errors returned by GetSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSessionError {
     /// <p> Request validation failed, there is no usable message in the context, or the bot build failed, is still in progress, or contains unbuilt changes. </p>
     BadRequest(String),
@@ -487,6 +492,8 @@ pub enum GetSessionError {
     LimitExceeded(String),
     /// <p>The resource (such as the Amazon Lex bot or an alias) that is referred to is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSessionError {
@@ -494,19 +501,19 @@ impl GetSessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSessionError::BadRequest(err.msg))
+                    return RusotoError::service(GetSessionError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSessionError::InternalFailure(err.msg))
+                    return RusotoError::service(GetSessionError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetSessionError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSessionError::NotFound(err.msg))
+                    return RusotoError::service(GetSessionError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -520,12 +527,14 @@ impl fmt::Display for GetSessionError {
             GetSessionError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetSessionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetSessionError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSessionError {}
 /// Errors returned by PostContent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PostContentError {
     /// <p>Either the Amazon Lex bot is still building, or one of the dependent services (Amazon Polly, AWS Lambda) failed with an internal service error.</p>
     BadGateway(String),
@@ -549,6 +558,8 @@ pub enum PostContentError {
     RequestTimeout(String),
     /// <p>The Content-Type header (<code>PostContent</code> API) has an invalid value. </p>
     UnsupportedMediaType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PostContentError {
@@ -556,40 +567,43 @@ impl PostContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PostContentError::BadGateway(err.msg))
+                    return RusotoError::service(PostContentError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PostContentError::BadRequest(err.msg))
+                    return RusotoError::service(PostContentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PostContentError::Conflict(err.msg))
+                    return RusotoError::service(PostContentError::Conflict(err.msg), &res)
                 }
                 "DependencyFailedException" => {
-                    return RusotoError::Service(PostContentError::DependencyFailed(err.msg))
+                    return RusotoError::service(PostContentError::DependencyFailed(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PostContentError::InternalFailure(err.msg))
+                    return RusotoError::service(PostContentError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PostContentError::LimitExceeded(err.msg))
+                    return RusotoError::service(PostContentError::LimitExceeded(err.msg), &res)
                 }
                 "LoopDetectedException" => {
-                    return RusotoError::Service(PostContentError::LoopDetected(err.msg))
+                    return RusotoError::service(PostContentError::LoopDetected(err.msg), &res)
                 }
                 "NotAcceptableException" => {
-                    return RusotoError::Service(PostContentError::NotAcceptable(err.msg))
+                    return RusotoError::service(PostContentError::NotAcceptable(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PostContentError::NotFound(err.msg))
+                    return RusotoError::service(PostContentError::NotFound(err.msg), &res)
                 }
                 "RequestTimeoutException" => {
-                    return RusotoError::Service(PostContentError::RequestTimeout(err.msg))
+                    return RusotoError::service(PostContentError::RequestTimeout(err.msg), &res)
                 }
                 "UnsupportedMediaTypeException" => {
-                    return RusotoError::Service(PostContentError::UnsupportedMediaType(err.msg))
+                    return RusotoError::service(
+                        PostContentError::UnsupportedMediaType(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PostContentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -610,12 +624,14 @@ impl fmt::Display for PostContentError {
             PostContentError::NotFound(ref cause) => write!(f, "{}", cause),
             PostContentError::RequestTimeout(ref cause) => write!(f, "{}", cause),
             PostContentError::UnsupportedMediaType(ref cause) => write!(f, "{}", cause),
+            PostContentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PostContentError {}
 /// Errors returned by PostText
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PostTextError {
     /// <p>Either the Amazon Lex bot is still building, or one of the dependent services (Amazon Polly, AWS Lambda) failed with an internal service error.</p>
     BadGateway(String),
@@ -633,6 +649,8 @@ pub enum PostTextError {
     LoopDetected(String),
     /// <p>The resource (such as the Amazon Lex bot or an alias) that is referred to is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PostTextError {
@@ -640,31 +658,31 @@ impl PostTextError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PostTextError::BadGateway(err.msg))
+                    return RusotoError::service(PostTextError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PostTextError::BadRequest(err.msg))
+                    return RusotoError::service(PostTextError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PostTextError::Conflict(err.msg))
+                    return RusotoError::service(PostTextError::Conflict(err.msg), &res)
                 }
                 "DependencyFailedException" => {
-                    return RusotoError::Service(PostTextError::DependencyFailed(err.msg))
+                    return RusotoError::service(PostTextError::DependencyFailed(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PostTextError::InternalFailure(err.msg))
+                    return RusotoError::service(PostTextError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PostTextError::LimitExceeded(err.msg))
+                    return RusotoError::service(PostTextError::LimitExceeded(err.msg), &res)
                 }
                 "LoopDetectedException" => {
-                    return RusotoError::Service(PostTextError::LoopDetected(err.msg))
+                    return RusotoError::service(PostTextError::LoopDetected(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PostTextError::NotFound(err.msg))
+                    return RusotoError::service(PostTextError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PostTextError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -682,12 +700,14 @@ impl fmt::Display for PostTextError {
             PostTextError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PostTextError::LoopDetected(ref cause) => write!(f, "{}", cause),
             PostTextError::NotFound(ref cause) => write!(f, "{}", cause),
+            PostTextError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PostTextError {}
 /// Errors returned by PutSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutSessionError {
     /// <p>Either the Amazon Lex bot is still building, or one of the dependent services (Amazon Polly, AWS Lambda) failed with an internal service error.</p>
     BadGateway(String),
@@ -705,6 +725,8 @@ pub enum PutSessionError {
     NotAcceptable(String),
     /// <p>The resource (such as the Amazon Lex bot or an alias) that is referred to is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutSessionError {
@@ -712,31 +734,31 @@ impl PutSessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PutSessionError::BadGateway(err.msg))
+                    return RusotoError::service(PutSessionError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutSessionError::BadRequest(err.msg))
+                    return RusotoError::service(PutSessionError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutSessionError::Conflict(err.msg))
+                    return RusotoError::service(PutSessionError::Conflict(err.msg), &res)
                 }
                 "DependencyFailedException" => {
-                    return RusotoError::Service(PutSessionError::DependencyFailed(err.msg))
+                    return RusotoError::service(PutSessionError::DependencyFailed(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutSessionError::InternalFailure(err.msg))
+                    return RusotoError::service(PutSessionError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutSessionError::LimitExceeded(err.msg), &res)
                 }
                 "NotAcceptableException" => {
-                    return RusotoError::Service(PutSessionError::NotAcceptable(err.msg))
+                    return RusotoError::service(PutSessionError::NotAcceptable(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutSessionError::NotFound(err.msg))
+                    return RusotoError::service(PutSessionError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -754,6 +776,7 @@ impl fmt::Display for PutSessionError {
             PutSessionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutSessionError::NotAcceptable(ref cause) => write!(f, "{}", cause),
             PutSessionError::NotFound(ref cause) => write!(f, "{}", cause),
+            PutSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -802,6 +825,7 @@ impl LexRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> LexRuntimeClient {
         LexRuntimeClient {
             client: Client::shared(),
@@ -827,129 +851,164 @@ impl LexRuntimeClient {
     pub fn new_with_client(client: Client, region: region::Region) -> LexRuntimeClient {
         LexRuntimeClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<LexRuntimeClient> {
+        rusoto_core::ClientBuilder::new(LexRuntimeClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> LexRuntimeClient {
+        LexRuntimeClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl LexRuntime for LexRuntimeClient {
-    /// <p>Removes session information for a specified bot, alias, and user ID. </p>
-    async fn delete_session(
+impl LexRuntimeClient {
+    /// Calls `delete_session`, also returning the metadata of the response.
+    pub async fn delete_session_with_metadata(
         &self,
         input: DeleteSessionRequest,
-    ) -> Result<DeleteSessionResponse, RusotoError<DeleteSessionError>> {
-        let request_uri = format!(
-            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/session",
-            bot_alias = input.bot_alias,
-            bot_name = input.bot_name,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("runtime.lex".to_string());
+    ) -> Result<(DeleteSessionResponse, ResponseMetadata), RusotoError<DeleteSessionError>> {
+        let request = self.build_delete_session_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteSessionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteSessionError::from_response(response))
         }
     }
 
-    /// <p>Returns session information for a specified bot, alias, and user ID.</p>
-    async fn get_session(
+    /// Presigns the request `delete_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_session(
         &self,
-        input: GetSessionRequest,
-    ) -> Result<GetSessionResponse, RusotoError<GetSessionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteSessionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_session_request(&self, input: DeleteSessionRequest) -> SignedRequest {
         let request_uri = format!(
-            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/session/",
+            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/session",
             bot_alias = input.bot_alias,
             bot_name = input.bot_name,
             user_id = input.user_id
         );
 
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
 
-        let mut params = Params::new();
-        if let Some(ref x) = input.checkpoint_label_filter {
-            params.put("checkpointLabelFilter", x);
-        }
-        request.set_params(params);
+        request
+    }
+
+    /// Calls `get_session`, also returning the metadata of the response.
+    pub async fn get_session_with_metadata(
+        &self,
+        input: GetSessionRequest,
+    ) -> Result<(GetSessionResponse, ResponseMetadata), RusotoError<GetSessionError>> {
+        let request = self.build_get_session_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetSessionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetSessionError::from_response(response))
         }
     }
 
-    /// <p> Sends user input (text or speech) to Amazon Lex. Clients use this API to send text and audio requests to Amazon Lex at runtime. Amazon Lex interprets the user input using the machine learning model that it built for the bot. </p> <p>The <code>PostContent</code> operation supports audio input at 8kHz and 16kHz. You can use 8kHz audio to achieve higher speech recognition accuracy in telephone audio applications. </p> <p> In response, Amazon Lex returns the next message to convey to the user. Consider the following example messages: </p> <ul> <li> <p> For a user input "I would like a pizza," Amazon Lex might return a response with a message eliciting slot data (for example, <code>PizzaSize</code>): "What size pizza would you like?". </p> </li> <li> <p> After the user provides all of the pizza order information, Amazon Lex might return a response with a message to get user confirmation: "Order the pizza?". </p> </li> <li> <p> After the user replies "Yes" to the confirmation prompt, Amazon Lex might return a conclusion statement: "Thank you, your cheese pizza has been ordered.". </p> </li> </ul> <p> Not all Amazon Lex messages require a response from the user. For example, conclusion statements do not require a response. Some messages require only a yes or no response. In addition to the <code>message</code>, Amazon Lex provides additional context about the message in the response that you can use to enhance client behavior, such as displaying the appropriate client user interface. Consider the following examples: </p> <ul> <li> <p> If the message is to elicit slot data, Amazon Lex returns the following context information: </p> <ul> <li> <p> <code>x-amz-lex-dialog-state</code> header set to <code>ElicitSlot</code> </p> </li> <li> <p> <code>x-amz-lex-intent-name</code> header set to the intent name in the current context </p> </li> <li> <p> <code>x-amz-lex-slot-to-elicit</code> header set to the slot name for which the <code>message</code> is eliciting information </p> </li> <li> <p> <code>x-amz-lex-slots</code> header set to a map of slots configured for the intent with their current values </p> </li> </ul> </li> <li> <p> If the message is a confirmation prompt, the <code>x-amz-lex-dialog-state</code> header is set to <code>Confirmation</code> and the <code>x-amz-lex-slot-to-elicit</code> header is omitted. </p> </li> <li> <p> If the message is a clarification prompt configured for the intent, indicating that the user intent is not understood, the <code>x-amz-dialog-state</code> header is set to <code>ElicitIntent</code> and the <code>x-amz-slot-to-elicit</code> header is omitted. </p> </li> </ul> <p> In addition, Amazon Lex also returns your application-specific <code>sessionAttributes</code>. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/context-mgmt.html">Managing Conversation Context</a>. </p>
-    async fn post_content(
+    /// Presigns the request `get_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_session(
         &self,
-        input: PostContentRequest,
-    ) -> Result<PostContentResponse, RusotoError<PostContentError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetSessionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_session_request(&self, input: GetSessionRequest) -> SignedRequest {
         let request_uri = format!(
-            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/content",
+            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/session/",
             bot_alias = input.bot_alias,
             bot_name = input.bot_name,
             user_id = input.user_id
         );
 
-        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
-        let encoded = Some(input.input_stream.to_owned());
-        request.set_payload(encoded);
 
-        if let Some(ref accept) = input.accept {
-            request.add_header("Accept", &accept.to_string());
+        let mut params = Params::new();
+        if let Some(ref x) = input.checkpoint_label_filter {
+            params.put("checkpointLabelFilter", x);
         }
-        request.add_header("Content-Type", &input.content_type);
+        request.set_params(params);
 
-        if let Some(ref request_attributes) = input.request_attributes {
-            request.add_header(
-                "x-amz-lex-request-attributes",
-                &request_attributes.to_string(),
-            );
-        }
+        request
+    }
 
-        if let Some(ref session_attributes) = input.session_attributes {
-            request.add_header(
-                "x-amz-lex-session-attributes",
-                &session_attributes.to_string(),
-            );
-        }
+    /// Calls `post_content`, also returning the metadata of the response.
+    pub async fn post_content_with_metadata(
+        &self,
+        input: PostContentRequest,
+    ) -> Result<(PostContentResponse, ResponseMetadata), RusotoError<PostContentError>> {
+        let request = self.build_post_content_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
 
             let mut result = PostContentResponse::default();
             result.audio_stream = Some(response.body);
@@ -999,79 +1058,142 @@ impl LexRuntime for LexRuntimeClient {
                 result.slots = Some(value)
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PostContentError::from_response(response))
         }
     }
 
-    /// <p>Sends user input to Amazon Lex. Client applications can use this API to send requests to Amazon Lex at runtime. Amazon Lex then interprets the user input using the machine learning model it built for the bot. </p> <p> In response, Amazon Lex returns the next <code>message</code> to convey to the user an optional <code>responseCard</code> to display. Consider the following example messages: </p> <ul> <li> <p> For a user input "I would like a pizza", Amazon Lex might return a response with a message eliciting slot data (for example, PizzaSize): "What size pizza would you like?" </p> </li> <li> <p> After the user provides all of the pizza order information, Amazon Lex might return a response with a message to obtain user confirmation "Proceed with the pizza order?". </p> </li> <li> <p> After the user replies to a confirmation prompt with a "yes", Amazon Lex might return a conclusion statement: "Thank you, your cheese pizza has been ordered.". </p> </li> </ul> <p> Not all Amazon Lex messages require a user response. For example, a conclusion statement does not require a response. Some messages require only a "yes" or "no" user response. In addition to the <code>message</code>, Amazon Lex provides additional context about the message in the response that you might use to enhance client behavior, for example, to display the appropriate client user interface. These are the <code>slotToElicit</code>, <code>dialogState</code>, <code>intentName</code>, and <code>slots</code> fields in the response. Consider the following examples: </p> <ul> <li> <p>If the message is to elicit slot data, Amazon Lex returns the following context information:</p> <ul> <li> <p> <code>dialogState</code> set to ElicitSlot </p> </li> <li> <p> <code>intentName</code> set to the intent name in the current context </p> </li> <li> <p> <code>slotToElicit</code> set to the slot name for which the <code>message</code> is eliciting information </p> </li> <li> <p> <code>slots</code> set to a map of slots, configured for the intent, with currently known values </p> </li> </ul> </li> <li> <p> If the message is a confirmation prompt, the <code>dialogState</code> is set to ConfirmIntent and <code>SlotToElicit</code> is set to null. </p> </li> <li> <p>If the message is a clarification prompt (configured for the intent) that indicates that user intent is not understood, the <code>dialogState</code> is set to ElicitIntent and <code>slotToElicit</code> is set to null. </p> </li> </ul> <p> In addition, Amazon Lex also returns your application-specific <code>sessionAttributes</code>. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/context-mgmt.html">Managing Conversation Context</a>. </p>
-    async fn post_text(
+    /// Presigns the request `post_content` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_post_content(
         &self,
-        input: PostTextRequest,
-    ) -> Result<PostTextResponse, RusotoError<PostTextError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PostContentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_post_content_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_post_content_request(&self, input: PostContentRequest) -> SignedRequest {
         let request_uri = format!(
-            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/text",
+            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/content",
             bot_alias = input.bot_alias,
             bot_name = input.bot_name,
             user_id = input.user_id
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        let encoded = Some(input.input_stream.to_owned());
         request.set_payload(encoded);
 
+        if let Some(ref accept) = input.accept {
+            request.add_header("Accept", &accept.to_string());
+        }
+        request.add_header("Content-Type", &input.content_type);
+
+        if let Some(ref request_attributes) = input.request_attributes {
+            request.add_header(
+                "x-amz-lex-request-attributes",
+                &request_attributes.to_string(),
+            );
+        }
+
+        if let Some(ref session_attributes) = input.session_attributes {
+            request.add_header(
+                "x-amz-lex-session-attributes",
+                &session_attributes.to_string(),
+            );
+        }
+
+        request
+    }
+
+    /// Calls `post_text`, also returning the metadata of the response.
+    pub async fn post_text_with_metadata(
+        &self,
+        input: PostTextRequest,
+    ) -> Result<(PostTextResponse, ResponseMetadata), RusotoError<PostTextError>> {
+        let request = self.build_post_text_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PostTextResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PostTextError::from_response(response))
         }
     }
 
-    /// <p>Creates a new session or modifies an existing session with an Amazon Lex bot. Use this operation to enable your application to set the state of the bot.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/how-session-api.html">Managing Sessions</a>.</p>
-    async fn put_session(
+    /// Presigns the request `post_text` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_post_text(
         &self,
-        input: PutSessionRequest,
-    ) -> Result<PutSessionResponse, RusotoError<PutSessionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PostTextRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_post_text_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_post_text_request(&self, input: PostTextRequest) -> SignedRequest {
         let request_uri = format!(
-            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/session",
+            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/text",
             bot_alias = input.bot_alias,
             bot_name = input.bot_name,
             user_id = input.user_id
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostText");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        if let Some(ref accept) = input.accept {
-            request.add_header("Accept", &accept.to_string());
-        }
+        request
+    }
+
+    /// Calls `put_session`, also returning the metadata of the response.
+    pub async fn put_session_with_metadata(
+        &self,
+        input: PutSessionRequest,
+    ) -> Result<(PutSessionResponse, ResponseMetadata), RusotoError<PutSessionError>> {
+        let request = self.build_put_session_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
 
             let mut result = PutSessionResponse::default();
             result.audio_stream = Some(response.body);
@@ -1113,10 +1235,99 @@ impl LexRuntime for LexRuntimeClient {
                 result.slots = Some(value)
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PutSessionError::from_response(response))
         }
     }
+
+    /// Presigns the request `put_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_session(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutSessionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_session_request(&self, input: PutSessionRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/session",
+            bot_alias = input.bot_alias,
+            bot_name = input.bot_name,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PutSession");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("runtime.lex".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref accept) = input.accept {
+            request.add_header("Accept", &accept.to_string());
+        }
+
+        request
+    }
+}
+
+#[async_trait]
+impl LexRuntime for LexRuntimeClient {
+    /// <p>Removes session information for a specified bot, alias, and user ID. </p>
+    async fn delete_session(
+        &self,
+        input: DeleteSessionRequest,
+    ) -> Result<DeleteSessionResponse, RusotoError<DeleteSessionError>> {
+        self.delete_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns session information for a specified bot, alias, and user ID.</p>
+    async fn get_session(
+        &self,
+        input: GetSessionRequest,
+    ) -> Result<GetSessionResponse, RusotoError<GetSessionError>> {
+        self.get_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Sends user input (text or speech) to Amazon Lex. Clients use this API to send text and audio requests to Amazon Lex at runtime. Amazon Lex interprets the user input using the machine learning model that it built for the bot. </p> <p>The <code>PostContent</code> operation supports audio input at 8kHz and 16kHz. You can use 8kHz audio to achieve higher speech recognition accuracy in telephone audio applications. </p> <p> In response, Amazon Lex returns the next message to convey to the user. Consider the following example messages: </p> <ul> <li> <p> For a user input "I would like a pizza," Amazon Lex might return a response with a message eliciting slot data (for example, <code>PizzaSize</code>): "What size pizza would you like?". </p> </li> <li> <p> After the user provides all of the pizza order information, Amazon Lex might return a response with a message to get user confirmation: "Order the pizza?". </p> </li> <li> <p> After the user replies "Yes" to the confirmation prompt, Amazon Lex might return a conclusion statement: "Thank you, your cheese pizza has been ordered.". </p> </li> </ul> <p> Not all Amazon Lex messages require a response from the user. For example, conclusion statements do not require a response. Some messages require only a yes or no response. In addition to the <code>message</code>, Amazon Lex provides additional context about the message in the response that you can use to enhance client behavior, such as displaying the appropriate client user interface. Consider the following examples: </p> <ul> <li> <p> If the message is to elicit slot data, Amazon Lex returns the following context information: </p> <ul> <li> <p> <code>x-amz-lex-dialog-state</code> header set to <code>ElicitSlot</code> </p> </li> <li> <p> <code>x-amz-lex-intent-name</code> header set to the intent name in the current context </p> </li> <li> <p> <code>x-amz-lex-slot-to-elicit</code> header set to the slot name for which the <code>message</code> is eliciting information </p> </li> <li> <p> <code>x-amz-lex-slots</code> header set to a map of slots configured for the intent with their current values </p> </li> </ul> </li> <li> <p> If the message is a confirmation prompt, the <code>x-amz-lex-dialog-state</code> header is set to <code>Confirmation</code> and the <code>x-amz-lex-slot-to-elicit</code> header is omitted. </p> </li> <li> <p> If the message is a clarification prompt configured for the intent, indicating that the user intent is not understood, the <code>x-amz-dialog-state</code> header is set to <code>ElicitIntent</code> and the <code>x-amz-slot-to-elicit</code> header is omitted. </p> </li> </ul> <p> In addition, Amazon Lex also returns your application-specific <code>sessionAttributes</code>. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/context-mgmt.html">Managing Conversation Context</a>. </p>
+    async fn post_content(
+        &self,
+        input: PostContentRequest,
+    ) -> Result<PostContentResponse, RusotoError<PostContentError>> {
+        self.post_content_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends user input to Amazon Lex. Client applications can use this API to send requests to Amazon Lex at runtime. Amazon Lex then interprets the user input using the machine learning model it built for the bot. </p> <p> In response, Amazon Lex returns the next <code>message</code> to convey to the user an optional <code>responseCard</code> to display. Consider the following example messages: </p> <ul> <li> <p> For a user input "I would like a pizza", Amazon Lex might return a response with a message eliciting slot data (for example, PizzaSize): "What size pizza would you like?" </p> </li> <li> <p> After the user provides all of the pizza order information, Amazon Lex might return a response with a message to obtain user confirmation "Proceed with the pizza order?". </p> </li> <li> <p> After the user replies to a confirmation prompt with a "yes", Amazon Lex might return a conclusion statement: "Thank you, your cheese pizza has been ordered.". </p> </li> </ul> <p> Not all Amazon Lex messages require a user response. For example, a conclusion statement does not require a response. Some messages require only a "yes" or "no" user response. In addition to the <code>message</code>, Amazon Lex provides additional context about the message in the response that you might use to enhance client behavior, for example, to display the appropriate client user interface. These are the <code>slotToElicit</code>, <code>dialogState</code>, <code>intentName</code>, and <code>slots</code> fields in the response. Consider the following examples: </p> <ul> <li> <p>If the message is to elicit slot data, Amazon Lex returns the following context information:</p> <ul> <li> <p> <code>dialogState</code> set to ElicitSlot </p> </li> <li> <p> <code>intentName</code> set to the intent name in the current context </p> </li> <li> <p> <code>slotToElicit</code> set to the slot name for which the <code>message</code> is eliciting information </p> </li> <li> <p> <code>slots</code> set to a map of slots, configured for the intent, with currently known values </p> </li> </ul> </li> <li> <p> If the message is a confirmation prompt, the <code>dialogState</code> is set to ConfirmIntent and <code>SlotToElicit</code> is set to null. </p> </li> <li> <p>If the message is a clarification prompt (configured for the intent) that indicates that user intent is not understood, the <code>dialogState</code> is set to ElicitIntent and <code>slotToElicit</code> is set to null. </p> </li> </ul> <p> In addition, Amazon Lex also returns your application-specific <code>sessionAttributes</code>. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/context-mgmt.html">Managing Conversation Context</a>. </p>
+    async fn post_text(
+        &self,
+        input: PostTextRequest,
+    ) -> Result<PostTextResponse, RusotoError<PostTextError>> {
+        self.post_text_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new session or modifies an existing session with an Amazon Lex bot. Use this operation to enable your application to set the state of the bot.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/how-session-api.html">Managing Sessions</a>.</p>
+    async fn put_session(
+        &self,
+        input: PutSessionRequest,
+    ) -> Result<PutSessionResponse, RusotoError<PutSessionError>> {
+        self.put_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
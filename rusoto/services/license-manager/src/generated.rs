@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -826,6 +826,7 @@ pub struct UpdateServiceSettingsResponse {}
 
 /// Errors returned by CreateLicenseConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLicenseConfigurationError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -839,6 +840,8 @@ pub enum CreateLicenseConfigurationError {
     ResourceLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLicenseConfigurationError {
@@ -848,37 +851,45 @@ impl CreateLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLicenseConfigurationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLicenseConfigurationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CreateLicenseConfigurationError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLicenseConfigurationError::Authorization(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateLicenseConfigurationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateLicenseConfigurationError::RateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateLicenseConfigurationError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateLicenseConfigurationError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLicenseConfigurationError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateLicenseConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -898,12 +909,14 @@ impl fmt::Display for CreateLicenseConfigurationError {
                 write!(f, "{}", cause)
             }
             CreateLicenseConfigurationError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            CreateLicenseConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLicenseConfigurationError {}
 /// Errors returned by DeleteLicenseConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLicenseConfigurationError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -915,6 +928,8 @@ pub enum DeleteLicenseConfigurationError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLicenseConfigurationError {
@@ -924,32 +939,39 @@ impl DeleteLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLicenseConfigurationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLicenseConfigurationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(DeleteLicenseConfigurationError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLicenseConfigurationError::Authorization(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteLicenseConfigurationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteLicenseConfigurationError::RateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DeleteLicenseConfigurationError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLicenseConfigurationError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteLicenseConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -966,12 +988,14 @@ impl fmt::Display for DeleteLicenseConfigurationError {
             }
             DeleteLicenseConfigurationError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteLicenseConfigurationError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            DeleteLicenseConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLicenseConfigurationError {}
 /// Errors returned by GetLicenseConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLicenseConfigurationError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -983,6 +1007,8 @@ pub enum GetLicenseConfigurationError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLicenseConfigurationError {
@@ -990,32 +1016,37 @@ impl GetLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLicenseConfigurationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLicenseConfigurationError::Authorization(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetLicenseConfigurationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLicenseConfigurationError::RateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLicenseConfigurationError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLicenseConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1032,12 +1063,14 @@ impl fmt::Display for GetLicenseConfigurationError {
             }
             GetLicenseConfigurationError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             GetLicenseConfigurationError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            GetLicenseConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLicenseConfigurationError {}
 /// Errors returned by GetServiceSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetServiceSettingsError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1047,6 +1080,8 @@ pub enum GetServiceSettingsError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetServiceSettingsError {
@@ -1054,21 +1089,31 @@ impl GetServiceSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetServiceSettingsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetServiceSettingsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetServiceSettingsError::Authorization(err.msg))
+                    return RusotoError::service(
+                        GetServiceSettingsError::Authorization(err.msg),
+                        &res,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetServiceSettingsError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetServiceSettingsError::RateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetServiceSettingsError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        GetServiceSettingsError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetServiceSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1082,12 +1127,14 @@ impl fmt::Display for GetServiceSettingsError {
             GetServiceSettingsError::Authorization(ref cause) => write!(f, "{}", cause),
             GetServiceSettingsError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             GetServiceSettingsError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            GetServiceSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetServiceSettingsError {}
 /// Errors returned by ListAssociationsForLicenseConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssociationsForLicenseConfigurationError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1101,6 +1148,8 @@ pub enum ListAssociationsForLicenseConfigurationError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssociationsForLicenseConfigurationError {
@@ -1110,39 +1159,50 @@ impl ListAssociationsForLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAssociationsForLicenseConfigurationError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAssociationsForLicenseConfigurationError::Authorization(err.msg),
+                        &res,
                     )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAssociationsForLicenseConfigurationError::FilterLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAssociationsForLicenseConfigurationError::InvalidParameterValue(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAssociationsForLicenseConfigurationError::RateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAssociationsForLicenseConfigurationError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListAssociationsForLicenseConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1170,12 +1230,16 @@ impl fmt::Display for ListAssociationsForLicenseConfigurationError {
             ListAssociationsForLicenseConfigurationError::ServerInternal(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListAssociationsForLicenseConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListAssociationsForLicenseConfigurationError {}
 /// Errors returned by ListFailuresForLicenseConfigurationOperations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFailuresForLicenseConfigurationOperationsError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1187,6 +1251,8 @@ pub enum ListFailuresForLicenseConfigurationOperationsError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFailuresForLicenseConfigurationOperationsError {
@@ -1196,36 +1262,46 @@ impl ListFailuresForLicenseConfigurationOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListFailuresForLicenseConfigurationOperationsError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListFailuresForLicenseConfigurationOperationsError::Authorization(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListFailuresForLicenseConfigurationOperationsError::InvalidParameterValue(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListFailuresForLicenseConfigurationOperationsError::RateLimitExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListFailuresForLicenseConfigurationOperationsError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListFailuresForLicenseConfigurationOperationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1250,12 +1326,16 @@ impl fmt::Display for ListFailuresForLicenseConfigurationOperationsError {
             ListFailuresForLicenseConfigurationOperationsError::ServerInternal(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListFailuresForLicenseConfigurationOperationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListFailuresForLicenseConfigurationOperationsError {}
 /// Errors returned by ListLicenseConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLicenseConfigurationsError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1269,6 +1349,8 @@ pub enum ListLicenseConfigurationsError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLicenseConfigurationsError {
@@ -1276,37 +1358,45 @@ impl ListLicenseConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLicenseConfigurationsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLicenseConfigurationsError::Authorization(err.msg),
+                        &res,
+                    )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLicenseConfigurationsError::FilterLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLicenseConfigurationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLicenseConfigurationsError::RateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLicenseConfigurationsError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListLicenseConfigurationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1326,12 +1416,14 @@ impl fmt::Display for ListLicenseConfigurationsError {
             }
             ListLicenseConfigurationsError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             ListLicenseConfigurationsError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            ListLicenseConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLicenseConfigurationsError {}
 /// Errors returned by ListLicenseSpecificationsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLicenseSpecificationsForResourceError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1343,6 +1435,8 @@ pub enum ListLicenseSpecificationsForResourceError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLicenseSpecificationsForResourceError {
@@ -1352,32 +1446,42 @@ impl ListLicenseSpecificationsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLicenseSpecificationsForResourceError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLicenseSpecificationsForResourceError::Authorization(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLicenseSpecificationsForResourceError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLicenseSpecificationsForResourceError::RateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLicenseSpecificationsForResourceError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListLicenseSpecificationsForResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1402,12 +1506,16 @@ impl fmt::Display for ListLicenseSpecificationsForResourceError {
             ListLicenseSpecificationsForResourceError::ServerInternal(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListLicenseSpecificationsForResourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListLicenseSpecificationsForResourceError {}
 /// Errors returned by ListResourceInventory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourceInventoryError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1423,6 +1531,8 @@ pub enum ListResourceInventoryError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourceInventoryError {
@@ -1430,38 +1540,49 @@ impl ListResourceInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListResourceInventoryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListResourceInventoryError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListResourceInventoryError::Authorization(err.msg))
+                    return RusotoError::service(
+                        ListResourceInventoryError::Authorization(err.msg),
+                        &res,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(ListResourceInventoryError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceInventoryError::FailedDependency(err.msg),
+                        &res,
+                    )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(ListResourceInventoryError::FilterLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceInventoryError::FilterLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListResourceInventoryError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceInventoryError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListResourceInventoryError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceInventoryError::RateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourceInventoryError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceInventoryError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResourceInventoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1478,12 +1599,14 @@ impl fmt::Display for ListResourceInventoryError {
             ListResourceInventoryError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListResourceInventoryError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             ListResourceInventoryError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            ListResourceInventoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourceInventoryError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1495,6 +1618,8 @@ pub enum ListTagsForResourceError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1502,26 +1627,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Authorization(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Authorization(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::RateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1536,12 +1672,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListUsageForLicenseConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUsageForLicenseConfigurationError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1555,6 +1693,8 @@ pub enum ListUsageForLicenseConfigurationError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUsageForLicenseConfigurationError {
@@ -1564,37 +1704,48 @@ impl ListUsageForLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListUsageForLicenseConfigurationError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListUsageForLicenseConfigurationError::Authorization(err.msg),
+                        &res,
                     )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListUsageForLicenseConfigurationError::FilterLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListUsageForLicenseConfigurationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListUsageForLicenseConfigurationError::RateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListUsageForLicenseConfigurationError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListUsageForLicenseConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1622,12 +1773,16 @@ impl fmt::Display for ListUsageForLicenseConfigurationError {
             ListUsageForLicenseConfigurationError::ServerInternal(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListUsageForLicenseConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListUsageForLicenseConfigurationError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1639,6 +1794,8 @@ pub enum TagResourceError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1646,22 +1803,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(TagResourceError::AccessDenied(err.msg), &res)
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(TagResourceError::Authorization(err.msg))
+                    return RusotoError::service(TagResourceError::Authorization(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::RateLimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::RateLimitExceeded(err.msg), &res)
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(TagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(TagResourceError::ServerInternal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1676,12 +1836,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             TagResourceError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1693,6 +1855,8 @@ pub enum UntagResourceError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1700,22 +1864,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UntagResourceError::AccessDenied(err.msg), &res)
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(UntagResourceError::Authorization(err.msg))
+                    return RusotoError::service(UntagResourceError::Authorization(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::RateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::RateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UntagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(UntagResourceError::ServerInternal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1730,12 +1900,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             UntagResourceError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateLicenseConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLicenseConfigurationError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1747,6 +1919,8 @@ pub enum UpdateLicenseConfigurationError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLicenseConfigurationError {
@@ -1756,32 +1930,39 @@ impl UpdateLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLicenseConfigurationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLicenseConfigurationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(UpdateLicenseConfigurationError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLicenseConfigurationError::Authorization(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLicenseConfigurationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLicenseConfigurationError::RateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UpdateLicenseConfigurationError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLicenseConfigurationError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateLicenseConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1798,12 +1979,14 @@ impl fmt::Display for UpdateLicenseConfigurationError {
             }
             UpdateLicenseConfigurationError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateLicenseConfigurationError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            UpdateLicenseConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateLicenseConfigurationError {}
 /// Errors returned by UpdateLicenseSpecificationsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLicenseSpecificationsForResourceError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1819,6 +2002,8 @@ pub enum UpdateLicenseSpecificationsForResourceError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLicenseSpecificationsForResourceError {
@@ -1828,42 +2013,54 @@ impl UpdateLicenseSpecificationsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLicenseSpecificationsForResourceError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLicenseSpecificationsForResourceError::Authorization(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLicenseSpecificationsForResourceError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLicenseSpecificationsForResourceError::InvalidResourceState(err.msg),
+                        &res,
                     )
                 }
                 "LicenseUsageException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLicenseSpecificationsForResourceError::LicenseUsage(err.msg),
+                        &res,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLicenseSpecificationsForResourceError::RateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLicenseSpecificationsForResourceError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateLicenseSpecificationsForResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1894,12 +2091,16 @@ impl fmt::Display for UpdateLicenseSpecificationsForResourceError {
             UpdateLicenseSpecificationsForResourceError::ServerInternal(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateLicenseSpecificationsForResourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateLicenseSpecificationsForResourceError {}
 /// Errors returned by UpdateServiceSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServiceSettingsError {
     /// <p>Access to resource denied.</p>
     AccessDenied(String),
@@ -1911,6 +2112,8 @@ pub enum UpdateServiceSettingsError {
     RateLimitExceeded(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServiceSettingsError {
@@ -1918,28 +2121,37 @@ impl UpdateServiceSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateServiceSettingsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::Authorization(err.msg))
+                    return RusotoError::service(
+                        UpdateServiceSettingsError::Authorization(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServiceSettingsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServiceSettingsError::RateLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServiceSettingsError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateServiceSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1954,6 +2166,7 @@ impl fmt::Display for UpdateServiceSettingsError {
             UpdateServiceSettingsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             UpdateServiceSettingsError::RateLimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateServiceSettingsError::ServerInternal(ref cause) => write!(f, "{}", cause),
+            UpdateServiceSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2082,6 +2295,7 @@ impl LicenseManagerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> LicenseManagerClient {
         LicenseManagerClient {
             client: Client::shared(),
@@ -2107,17 +2321,73 @@ impl LicenseManagerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> LicenseManagerClient {
         LicenseManagerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<LicenseManagerClient> {
+        rusoto_core::ClientBuilder::new(LicenseManagerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> LicenseManagerClient {
+        LicenseManagerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl LicenseManager for LicenseManagerClient {
-    /// <p>Creates a license configuration.</p> <p>A license configuration is an abstraction of a customer license agreement that can be consumed and enforced by License Manager. Components include specifications for the license type (licensing by instance, socket, CPU, or vCPU), allowed tenancy (shared tenancy, Dedicated Instance, Dedicated Host, or all of these), host affinity (how long a VM must be associated with a host), and the number of licenses purchased and used.</p>
-    async fn create_license_configuration(
+impl LicenseManagerClient {
+    /// Calls `create_license_configuration`, also returning the metadata of the response.
+    pub async fn create_license_configuration_with_metadata(
         &self,
         input: CreateLicenseConfigurationRequest,
-    ) -> Result<CreateLicenseConfigurationResponse, RusotoError<CreateLicenseConfigurationError>>
-    {
+    ) -> Result<
+        (CreateLicenseConfigurationResponse, ResponseMetadata),
+        RusotoError<CreateLicenseConfigurationError>,
+    > {
+        let request = self.build_create_license_configuration_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLicenseConfigurationResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLicenseConfigurationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_license_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_license_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateLicenseConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_license_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_license_configuration_request(
+        &self,
+        input: CreateLicenseConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("CreateLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2127,29 +2397,58 @@ impl LicenseManager for LicenseManagerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_license_configuration`, also returning the metadata of the response.
+    pub async fn delete_license_configuration_with_metadata(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> Result<
+        (DeleteLicenseConfigurationResponse, ResponseMetadata),
+        RusotoError<DeleteLicenseConfigurationError>,
+    > {
+        let request = self.build_delete_license_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLicenseConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLicenseConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLicenseConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLicenseConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified license configuration.</p> <p>You cannot delete a license configuration that is in use.</p>
-    async fn delete_license_configuration(
+    /// Presigns the request `delete_license_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_license_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLicenseConfigurationRequest,
-    ) -> Result<DeleteLicenseConfigurationResponse, RusotoError<DeleteLicenseConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_license_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_license_configuration_request(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("DeleteLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2159,85 +2458,171 @@ impl LicenseManager for LicenseManagerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_license_configuration`, also returning the metadata of the response.
+    pub async fn get_license_configuration_with_metadata(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> Result<
+        (GetLicenseConfigurationResponse, ResponseMetadata),
+        RusotoError<GetLicenseConfigurationError>,
+    > {
+        let request = self.build_get_license_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLicenseConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLicenseConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLicenseConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLicenseConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Gets detailed information about the specified license configuration.</p>
-    async fn get_license_configuration(
+    /// Presigns the request `get_license_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_license_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLicenseConfigurationRequest,
-    ) -> Result<GetLicenseConfigurationResponse, RusotoError<GetLicenseConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_license_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_license_configuration_request(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("GetLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.GetLicenseConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_service_settings`, also returning the metadata of the response.
+    pub async fn get_service_settings_with_metadata(
+        &self,
+    ) -> Result<(GetServiceSettingsResponse, ResponseMetadata), RusotoError<GetServiceSettingsError>>
+    {
+        let request = self.build_get_service_settings_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLicenseConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetServiceSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLicenseConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetServiceSettingsError::from_response(response))
         }
     }
 
-    /// <p>Gets the License Manager settings for the current Region.</p>
-    async fn get_service_settings(
+    /// Presigns the request `get_service_settings` would send with `presigner` instead of sending it.
+    pub async fn presign_get_service_settings(
         &self,
-    ) -> Result<GetServiceSettingsResponse, RusotoError<GetServiceSettingsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_service_settings_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_service_settings_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("GetServiceSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.GetServiceSettings");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `list_associations_for_license_configuration`, also returning the metadata of the response.
+    pub async fn list_associations_for_license_configuration_with_metadata(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> Result<
+        (
+            ListAssociationsForLicenseConfigurationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListAssociationsForLicenseConfigurationError>,
+    > {
+        let request = self.build_list_associations_for_license_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetServiceSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssociationsForLicenseConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetServiceSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssociationsForLicenseConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists the resource associations for the specified license configuration.</p> <p>Resource associations need not consume licenses from a license configuration. For example, an AMI or a stopped instance might not consume a license (depending on the license rules).</p>
-    async fn list_associations_for_license_configuration(
+    /// Presigns the request `list_associations_for_license_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_associations_for_license_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssociationsForLicenseConfigurationRequest,
-    ) -> Result<
-        ListAssociationsForLicenseConfigurationResponse,
-        RusotoError<ListAssociationsForLicenseConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_associations_for_license_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_associations_for_license_configuration_request(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListAssociationsForLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2247,33 +2632,62 @@ impl LicenseManager for LicenseManagerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_failures_for_license_configuration_operations`, also returning the metadata of the response.
+    pub async fn list_failures_for_license_configuration_operations_with_metadata(
+        &self,
+        input: ListFailuresForLicenseConfigurationOperationsRequest,
+    ) -> Result<
+        (
+            ListFailuresForLicenseConfigurationOperationsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListFailuresForLicenseConfigurationOperationsError>,
+    > {
+        let request = self.build_list_failures_for_license_configuration_operations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssociationsForLicenseConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListFailuresForLicenseConfigurationOperationsResponse, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssociationsForLicenseConfigurationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListFailuresForLicenseConfigurationOperationsError::from_response(response))
         }
     }
 
-    /// <p>Lists the license configuration operations that failed.</p>
-    async fn list_failures_for_license_configuration_operations(
+    /// Presigns the request `list_failures_for_license_configuration_operations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_failures_for_license_configuration_operations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFailuresForLicenseConfigurationOperationsRequest,
-    ) -> Result<
-        ListFailuresForLicenseConfigurationOperationsResponse,
-        RusotoError<ListFailuresForLicenseConfigurationOperationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_failures_for_license_configuration_operations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_failures_for_license_configuration_operations_request(
+        &self,
+        input: ListFailuresForLicenseConfigurationOperationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListFailuresForLicenseConfigurationOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2283,29 +2697,58 @@ impl LicenseManager for LicenseManagerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_license_configurations`, also returning the metadata of the response.
+    pub async fn list_license_configurations_with_metadata(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> Result<
+        (ListLicenseConfigurationsResponse, ResponseMetadata),
+        RusotoError<ListLicenseConfigurationsError>,
+    > {
+        let request = self.build_list_license_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFailuresForLicenseConfigurationOperationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLicenseConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFailuresForLicenseConfigurationOperationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLicenseConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Lists the license configurations for your account.</p>
-    async fn list_license_configurations(
+    /// Presigns the request `list_license_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_license_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLicenseConfigurationsRequest,
-    ) -> Result<ListLicenseConfigurationsResponse, RusotoError<ListLicenseConfigurationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_license_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_license_configurations_request(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListLicenseConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2315,31 +2758,63 @@ impl LicenseManager for LicenseManagerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_license_specifications_for_resource`, also returning the metadata of the response.
+    pub async fn list_license_specifications_for_resource_with_metadata(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> Result<
+        (
+            ListLicenseSpecificationsForResourceResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListLicenseSpecificationsForResourceError>,
+    > {
+        let request = self.build_list_license_specifications_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLicenseConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLicenseSpecificationsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLicenseConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLicenseSpecificationsForResourceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Describes the license configurations for the specified resource.</p>
-    async fn list_license_specifications_for_resource(
+    /// Presigns the request `list_license_specifications_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_license_specifications_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLicenseSpecificationsForResourceRequest,
-    ) -> Result<
-        ListLicenseSpecificationsForResourceResponse,
-        RusotoError<ListLicenseSpecificationsForResourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_license_specifications_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_license_specifications_for_resource_request(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListLicenseSpecificationsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2349,89 +2824,176 @@ impl LicenseManager for LicenseManagerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resource_inventory`, also returning the metadata of the response.
+    pub async fn list_resource_inventory_with_metadata(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> Result<
+        (ListResourceInventoryResponse, ResponseMetadata),
+        RusotoError<ListResourceInventoryError>,
+    > {
+        let request = self.build_list_resource_inventory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLicenseSpecificationsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResourceInventoryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLicenseSpecificationsForResourceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResourceInventoryError::from_response(response))
         }
     }
 
-    /// <p>Lists resources managed using Systems Manager inventory.</p>
-    async fn list_resource_inventory(
+    /// Presigns the request `list_resource_inventory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resource_inventory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourceInventoryRequest,
-    ) -> Result<ListResourceInventoryResponse, RusotoError<ListResourceInventoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resource_inventory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resource_inventory_request(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListResourceInventory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.ListResourceInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourceInventoryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourceInventoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for the specified license configuration.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `list_usage_for_license_configuration`, also returning the metadata of the response.
+    pub async fn list_usage_for_license_configuration_with_metadata(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> Result<
+        (ListUsageForLicenseConfigurationResponse, ResponseMetadata),
+        RusotoError<ListUsageForLicenseConfigurationError>,
+    > {
+        let request = self.build_list_usage_for_license_configuration_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUsageForLicenseConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUsageForLicenseConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists all license usage records for a license configuration, displaying license consumption details by resource at a selected point in time. Use this action to audit the current license consumption for any license inventory and configuration.</p>
-    async fn list_usage_for_license_configuration(
+    /// Presigns the request `list_usage_for_license_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_usage_for_license_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUsageForLicenseConfigurationRequest,
-    ) -> Result<
-        ListUsageForLicenseConfigurationResponse,
-        RusotoError<ListUsageForLicenseConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_usage_for_license_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_usage_for_license_configuration_request(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListUsageForLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2441,85 +3003,162 @@ impl LicenseManager for LicenseManagerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUsageForLicenseConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUsageForLicenseConfigurationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds the specified tags to the specified license configuration.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from the specified license configuration.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_license_configuration`, also returning the metadata of the response.
+    pub async fn update_license_configuration_with_metadata(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> Result<
+        (UpdateLicenseConfigurationResponse, ResponseMetadata),
+        RusotoError<UpdateLicenseConfigurationError>,
+    > {
+        let request = self.build_update_license_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateLicenseConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateLicenseConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Modifies the attributes of an existing license configuration.</p> <p>A license configuration is an abstraction of a customer license agreement that can be consumed and enforced by License Manager. Components include specifications for the license type (licensing by instance, socket, CPU, or vCPU), allowed tenancy (shared tenancy, Dedicated Instance, Dedicated Host, or all of these), host affinity (how long a VM must be associated with a host), and the number of licenses purchased and used.</p>
-    async fn update_license_configuration(
+    /// Presigns the request `update_license_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_license_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLicenseConfigurationRequest,
-    ) -> Result<UpdateLicenseConfigurationResponse, RusotoError<UpdateLicenseConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_license_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_license_configuration_request(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UpdateLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2529,31 +3168,63 @@ impl LicenseManager for LicenseManagerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_license_specifications_for_resource`, also returning the metadata of the response.
+    pub async fn update_license_specifications_for_resource_with_metadata(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> Result<
+        (
+            UpdateLicenseSpecificationsForResourceResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateLicenseSpecificationsForResourceError>,
+    > {
+        let request = self.build_update_license_specifications_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateLicenseConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateLicenseSpecificationsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLicenseConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateLicenseSpecificationsForResourceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Adds or removes the specified license configurations for the specified AWS resource.</p> <p>You can update the license specifications of AMIs, instances, and hosts. You cannot update the license specifications for launch templates and AWS CloudFormation templates, as they send license configurations to the operation that creates the resource.</p>
-    async fn update_license_specifications_for_resource(
+    /// Presigns the request `update_license_specifications_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_license_specifications_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLicenseSpecificationsForResourceRequest,
-    ) -> Result<
-        UpdateLicenseSpecificationsForResourceResponse,
-        RusotoError<UpdateLicenseSpecificationsForResourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_license_specifications_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_license_specifications_for_resource_request(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UpdateLicenseSpecificationsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2563,49 +3234,472 @@ impl LicenseManager for LicenseManagerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_service_settings`, also returning the metadata of the response.
+    pub async fn update_service_settings_with_metadata(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> Result<
+        (UpdateServiceSettingsResponse, ResponseMetadata),
+        RusotoError<UpdateServiceSettingsError>,
+    > {
+        let request = self.build_update_service_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateLicenseSpecificationsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateServiceSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLicenseSpecificationsForResourceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateServiceSettingsError::from_response(response))
         }
     }
 
-    /// <p>Updates License Manager settings for the current Region.</p>
-    async fn update_service_settings(
+    /// Presigns the request `update_service_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_service_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateServiceSettingsRequest,
-    ) -> Result<UpdateServiceSettingsResponse, RusotoError<UpdateServiceSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_service_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_service_settings_request(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UpdateServiceSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.UpdateServiceSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAssociationsForLicenseConfiguration` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_associations_for_license_configuration_pages(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListAssociationsForLicenseConfigurationResponse,
+            RusotoError<ListAssociationsForLicenseConfigurationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient, input| async move {
+                client
+                    .list_associations_for_license_configuration(input)
+                    .await
+            },
+            |input: &ListAssociationsForLicenseConfigurationRequest,
+             output: &ListAssociationsForLicenseConfigurationResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssociationsForLicenseConfigurationRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LicenseConfigurationAssociations` of every page of `ListAssociationsForLicenseConfiguration` results for `input`, as `list_associations_for_license_configuration_pages` requests them.
+    pub fn list_associations_for_license_configuration_stream(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            LicenseConfigurationAssociation,
+            RusotoError<ListAssociationsForLicenseConfigurationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_associations_for_license_configuration_pages(input),
+            |page| page.license_configuration_associations.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListLicenseConfigurations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_license_configurations_pages(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListLicenseConfigurationsResponse,
+            RusotoError<ListLicenseConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient, input| async move {
+                client.list_license_configurations(input).await
+            },
+            |input: &ListLicenseConfigurationsRequest,
+             output: &ListLicenseConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLicenseConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LicenseConfigurations` of every page of `ListLicenseConfigurations` results for `input`, as `list_license_configurations_pages` requests them.
+    pub fn list_license_configurations_stream(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<LicenseConfiguration, RusotoError<ListLicenseConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_license_configurations_pages(input), |page| {
+            page.license_configurations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLicenseSpecificationsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_license_specifications_for_resource_pages(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListLicenseSpecificationsForResourceResponse,
+            RusotoError<ListLicenseSpecificationsForResourceError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient, input| async move {
+                client.list_license_specifications_for_resource(input).await
+            },
+            |input: &ListLicenseSpecificationsForResourceRequest,
+             output: &ListLicenseSpecificationsForResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLicenseSpecificationsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LicenseSpecifications` of every page of `ListLicenseSpecificationsForResource` results for `input`, as `list_license_specifications_for_resource_pages` requests them.
+    pub fn list_license_specifications_for_resource_stream(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<LicenseSpecification, RusotoError<ListLicenseSpecificationsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_license_specifications_for_resource_pages(input),
+            |page| page.license_specifications.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListResourceInventory` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resource_inventory_pages(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListResourceInventoryResponse, RusotoError<ListResourceInventoryError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient, input| async move {
+                client.list_resource_inventory(input).await
+            },
+            |input: &ListResourceInventoryRequest, output: &ListResourceInventoryResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResourceInventoryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceInventoryList` of every page of `ListResourceInventory` results for `input`, as `list_resource_inventory_pages` requests them.
+    pub fn list_resource_inventory_stream(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ResourceInventory, RusotoError<ListResourceInventoryError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_resource_inventory_pages(input), |page| {
+            page.resource_inventory_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUsageForLicenseConfiguration` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_usage_for_license_configuration_pages(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListUsageForLicenseConfigurationResponse,
+            RusotoError<ListUsageForLicenseConfigurationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LicenseManagerClient, input| async move {
+                client.list_usage_for_license_configuration(input).await
+            },
+            |input: &ListUsageForLicenseConfigurationRequest,
+             output: &ListUsageForLicenseConfigurationResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUsageForLicenseConfigurationRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LicenseConfigurationUsageList` of every page of `ListUsageForLicenseConfiguration` results for `input`, as `list_usage_for_license_configuration_pages` requests them.
+    pub fn list_usage_for_license_configuration_stream(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            LicenseConfigurationUsage,
+            RusotoError<ListUsageForLicenseConfigurationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_usage_for_license_configuration_pages(input),
+            |page| page.license_configuration_usage_list.unwrap_or_default(),
+        )
+    }
+}
+
+#[async_trait]
+impl LicenseManager for LicenseManagerClient {
+    /// <p>Creates a license configuration.</p> <p>A license configuration is an abstraction of a customer license agreement that can be consumed and enforced by License Manager. Components include specifications for the license type (licensing by instance, socket, CPU, or vCPU), allowed tenancy (shared tenancy, Dedicated Instance, Dedicated Host, or all of these), host affinity (how long a VM must be associated with a host), and the number of licenses purchased and used.</p>
+    async fn create_license_configuration(
+        &self,
+        input: CreateLicenseConfigurationRequest,
+    ) -> Result<CreateLicenseConfigurationResponse, RusotoError<CreateLicenseConfigurationError>>
+    {
+        self.create_license_configuration_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateServiceSettingsResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateServiceSettingsError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified license configuration.</p> <p>You cannot delete a license configuration that is in use.</p>
+    async fn delete_license_configuration(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> Result<DeleteLicenseConfigurationResponse, RusotoError<DeleteLicenseConfigurationError>>
+    {
+        self.delete_license_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets detailed information about the specified license configuration.</p>
+    async fn get_license_configuration(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> Result<GetLicenseConfigurationResponse, RusotoError<GetLicenseConfigurationError>> {
+        self.get_license_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the License Manager settings for the current Region.</p>
+    async fn get_service_settings(
+        &self,
+    ) -> Result<GetServiceSettingsResponse, RusotoError<GetServiceSettingsError>> {
+        self.get_service_settings_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resource associations for the specified license configuration.</p> <p>Resource associations need not consume licenses from a license configuration. For example, an AMI or a stopped instance might not consume a license (depending on the license rules).</p>
+    async fn list_associations_for_license_configuration(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> Result<
+        ListAssociationsForLicenseConfigurationResponse,
+        RusotoError<ListAssociationsForLicenseConfigurationError>,
+    > {
+        self.list_associations_for_license_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the license configuration operations that failed.</p>
+    async fn list_failures_for_license_configuration_operations(
+        &self,
+        input: ListFailuresForLicenseConfigurationOperationsRequest,
+    ) -> Result<
+        ListFailuresForLicenseConfigurationOperationsResponse,
+        RusotoError<ListFailuresForLicenseConfigurationOperationsError>,
+    > {
+        self.list_failures_for_license_configuration_operations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the license configurations for your account.</p>
+    async fn list_license_configurations(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> Result<ListLicenseConfigurationsResponse, RusotoError<ListLicenseConfigurationsError>>
+    {
+        self.list_license_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the license configurations for the specified resource.</p>
+    async fn list_license_specifications_for_resource(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> Result<
+        ListLicenseSpecificationsForResourceResponse,
+        RusotoError<ListLicenseSpecificationsForResourceError>,
+    > {
+        self.list_license_specifications_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists resources managed using Systems Manager inventory.</p>
+    async fn list_resource_inventory(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> Result<ListResourceInventoryResponse, RusotoError<ListResourceInventoryError>> {
+        self.list_resource_inventory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for the specified license configuration.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all license usage records for a license configuration, displaying license consumption details by resource at a selected point in time. Use this action to audit the current license consumption for any license inventory and configuration.</p>
+    async fn list_usage_for_license_configuration(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> Result<
+        ListUsageForLicenseConfigurationResponse,
+        RusotoError<ListUsageForLicenseConfigurationError>,
+    > {
+        self.list_usage_for_license_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified tags to the specified license configuration.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from the specified license configuration.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the attributes of an existing license configuration.</p> <p>A license configuration is an abstraction of a customer license agreement that can be consumed and enforced by License Manager. Components include specifications for the license type (licensing by instance, socket, CPU, or vCPU), allowed tenancy (shared tenancy, Dedicated Instance, Dedicated Host, or all of these), host affinity (how long a VM must be associated with a host), and the number of licenses purchased and used.</p>
+    async fn update_license_configuration(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> Result<UpdateLicenseConfigurationResponse, RusotoError<UpdateLicenseConfigurationError>>
+    {
+        self.update_license_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or removes the specified license configurations for the specified AWS resource.</p> <p>You can update the license specifications of AMIs, instances, and hosts. You cannot update the license specifications for launch templates and AWS CloudFormation templates, as they send license configurations to the operation that creates the resource.</p>
+    async fn update_license_specifications_for_resource(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> Result<
+        UpdateLicenseSpecificationsForResourceResponse,
+        RusotoError<UpdateLicenseSpecificationsForResourceError>,
+    > {
+        self.update_license_specifications_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates License Manager settings for the current Region.</p>
+    async fn update_service_settings(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> Result<UpdateServiceSettingsResponse, RusotoError<UpdateServiceSettingsError>> {
+        self.update_service_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -4344,6 +4344,7 @@ pub struct UpdateRelationalDatabaseResult {
 
 /// Errors returned by AllocateStaticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AllocateStaticIpError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -4359,6 +4360,8 @@ pub enum AllocateStaticIpError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AllocateStaticIpError {
@@ -4366,30 +4369,37 @@ impl AllocateStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AllocateStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(AllocateStaticIpError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(AllocateStaticIpError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AllocateStaticIpError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AllocateStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(AllocateStaticIpError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AllocateStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(AllocateStaticIpError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(AllocateStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        AllocateStaticIpError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AllocateStaticIpError::Service(err.msg))
+                    return RusotoError::service(AllocateStaticIpError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(AllocateStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        AllocateStaticIpError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AllocateStaticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4406,12 +4416,14 @@ impl fmt::Display for AllocateStaticIpError {
             AllocateStaticIpError::OperationFailure(ref cause) => write!(f, "{}", cause),
             AllocateStaticIpError::Service(ref cause) => write!(f, "{}", cause),
             AllocateStaticIpError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            AllocateStaticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AllocateStaticIpError {}
 /// Errors returned by AttachDisk
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachDiskError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -4427,6 +4439,8 @@ pub enum AttachDiskError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachDiskError {
@@ -4434,28 +4448,31 @@ impl AttachDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(AttachDiskError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(AttachDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        AttachDiskError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(AttachDiskError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachDiskError::NotFound(err.msg))
+                    return RusotoError::service(AttachDiskError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(AttachDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(AttachDiskError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachDiskError::Service(err.msg))
+                    return RusotoError::service(AttachDiskError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(AttachDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(AttachDiskError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AttachDiskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4472,12 +4489,14 @@ impl fmt::Display for AttachDiskError {
             AttachDiskError::OperationFailure(ref cause) => write!(f, "{}", cause),
             AttachDiskError::Service(ref cause) => write!(f, "{}", cause),
             AttachDiskError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            AttachDiskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachDiskError {}
 /// Errors returned by AttachInstancesToLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachInstancesToLoadBalancerError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -4493,6 +4512,8 @@ pub enum AttachInstancesToLoadBalancerError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachInstancesToLoadBalancerError {
@@ -4502,42 +4523,54 @@ impl AttachInstancesToLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachInstancesToLoadBalancerError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AttachInstancesToLoadBalancerError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachInstancesToLoadBalancerError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachInstancesToLoadBalancerError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AttachInstancesToLoadBalancerError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachInstancesToLoadBalancerError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AttachInstancesToLoadBalancerError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AttachInstancesToLoadBalancerError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4560,12 +4593,16 @@ impl fmt::Display for AttachInstancesToLoadBalancerError {
             AttachInstancesToLoadBalancerError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AttachInstancesToLoadBalancerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AttachInstancesToLoadBalancerError {}
 /// Errors returned by AttachLoadBalancerTlsCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachLoadBalancerTlsCertificateError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -4581,6 +4618,8 @@ pub enum AttachLoadBalancerTlsCertificateError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachLoadBalancerTlsCertificateError {
@@ -4590,42 +4629,54 @@ impl AttachLoadBalancerTlsCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AttachLoadBalancerTlsCertificateError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AttachLoadBalancerTlsCertificateError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AttachLoadBalancerTlsCertificateError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachLoadBalancerTlsCertificateError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachLoadBalancerTlsCertificateError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AttachLoadBalancerTlsCertificateError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachLoadBalancerTlsCertificateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachLoadBalancerTlsCertificateError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AttachLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AttachLoadBalancerTlsCertificateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4652,12 +4703,16 @@ impl fmt::Display for AttachLoadBalancerTlsCertificateError {
             AttachLoadBalancerTlsCertificateError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AttachLoadBalancerTlsCertificateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AttachLoadBalancerTlsCertificateError {}
 /// Errors returned by AttachStaticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachStaticIpError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -4673,6 +4728,8 @@ pub enum AttachStaticIpError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachStaticIpError {
@@ -4680,30 +4737,37 @@ impl AttachStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(AttachStaticIpError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(AttachStaticIpError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachStaticIpError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(AttachStaticIpError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(AttachStaticIpError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(AttachStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        AttachStaticIpError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachStaticIpError::Service(err.msg))
+                    return RusotoError::service(AttachStaticIpError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(AttachStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        AttachStaticIpError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AttachStaticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4720,12 +4784,14 @@ impl fmt::Display for AttachStaticIpError {
             AttachStaticIpError::OperationFailure(ref cause) => write!(f, "{}", cause),
             AttachStaticIpError::Service(ref cause) => write!(f, "{}", cause),
             AttachStaticIpError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            AttachStaticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachStaticIpError {}
 /// Errors returned by CloseInstancePublicPorts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CloseInstancePublicPortsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -4741,6 +4807,8 @@ pub enum CloseInstancePublicPortsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CloseInstancePublicPortsError {
@@ -4748,38 +4816,49 @@ impl CloseInstancePublicPortsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CloseInstancePublicPortsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CloseInstancePublicPortsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CloseInstancePublicPortsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CloseInstancePublicPortsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CloseInstancePublicPortsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::Service(err.msg))
+                    return RusotoError::service(
+                        CloseInstancePublicPortsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CloseInstancePublicPortsError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CloseInstancePublicPortsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4798,12 +4877,14 @@ impl fmt::Display for CloseInstancePublicPortsError {
             CloseInstancePublicPortsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CloseInstancePublicPortsError::Service(ref cause) => write!(f, "{}", cause),
             CloseInstancePublicPortsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CloseInstancePublicPortsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CloseInstancePublicPortsError {}
 /// Errors returned by CopySnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopySnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -4819,6 +4900,8 @@ pub enum CopySnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopySnapshotError {
@@ -4826,28 +4909,31 @@ impl CopySnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CopySnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(CopySnapshotError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CopySnapshotError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        CopySnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CopySnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(CopySnapshotError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CopySnapshotError::NotFound(err.msg))
+                    return RusotoError::service(CopySnapshotError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CopySnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(CopySnapshotError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CopySnapshotError::Service(err.msg))
+                    return RusotoError::service(CopySnapshotError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CopySnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(CopySnapshotError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CopySnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4864,12 +4950,14 @@ impl fmt::Display for CopySnapshotError {
             CopySnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CopySnapshotError::Service(ref cause) => write!(f, "{}", cause),
             CopySnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CopySnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopySnapshotError {}
 /// Errors returned by CreateCloudFormationStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCloudFormationStackError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -4885,6 +4973,8 @@ pub enum CreateCloudFormationStackError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCloudFormationStackError {
@@ -4892,38 +4982,51 @@ impl CreateCloudFormationStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCloudFormationStackError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCloudFormationStackError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCloudFormationStackError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateCloudFormationStackError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCloudFormationStackError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::Service(err.msg))
+                    return RusotoError::service(
+                        CreateCloudFormationStackError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCloudFormationStackError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateCloudFormationStackError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4942,12 +5045,14 @@ impl fmt::Display for CreateCloudFormationStackError {
             CreateCloudFormationStackError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateCloudFormationStackError::Service(ref cause) => write!(f, "{}", cause),
             CreateCloudFormationStackError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateCloudFormationStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCloudFormationStackError {}
 /// Errors returned by CreateDisk
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDiskError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -4963,6 +5068,8 @@ pub enum CreateDiskError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDiskError {
@@ -4970,28 +5077,31 @@ impl CreateDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateDiskError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        CreateDiskError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateDiskError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDiskError::NotFound(err.msg))
+                    return RusotoError::service(CreateDiskError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(CreateDiskError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDiskError::Service(err.msg))
+                    return RusotoError::service(CreateDiskError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(CreateDiskError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDiskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5008,12 +5118,14 @@ impl fmt::Display for CreateDiskError {
             CreateDiskError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateDiskError::Service(ref cause) => write!(f, "{}", cause),
             CreateDiskError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateDiskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDiskError {}
 /// Errors returned by CreateDiskFromSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDiskFromSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5029,6 +5141,8 @@ pub enum CreateDiskFromSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDiskFromSnapshotError {
@@ -5036,34 +5150,49 @@ impl CreateDiskFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateDiskFromSnapshotError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDiskFromSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDiskFromSnapshotError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDiskFromSnapshotError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDiskFromSnapshotError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::Service(err.msg))
+                    return RusotoError::service(
+                        CreateDiskFromSnapshotError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDiskFromSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDiskFromSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5082,12 +5211,14 @@ impl fmt::Display for CreateDiskFromSnapshotError {
             CreateDiskFromSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateDiskFromSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             CreateDiskFromSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateDiskFromSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDiskFromSnapshotError {}
 /// Errors returned by CreateDiskSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDiskSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5103,6 +5234,8 @@ pub enum CreateDiskSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDiskSnapshotError {
@@ -5110,30 +5243,43 @@ impl CreateDiskSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateDiskSnapshotError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDiskSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDiskSnapshotError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(CreateDiskSnapshotError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        CreateDiskSnapshotError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::Service(err.msg))
+                    return RusotoError::service(CreateDiskSnapshotError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        CreateDiskSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDiskSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5150,12 +5296,14 @@ impl fmt::Display for CreateDiskSnapshotError {
             CreateDiskSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateDiskSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             CreateDiskSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateDiskSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDiskSnapshotError {}
 /// Errors returned by CreateDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDomainError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5171,6 +5319,8 @@ pub enum CreateDomainError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDomainError {
@@ -5178,28 +5328,31 @@ impl CreateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDomainError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateDomainError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDomainError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        CreateDomainError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateDomainError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainError::NotFound(err.msg))
+                    return RusotoError::service(CreateDomainError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDomainError::OperationFailure(err.msg))
+                    return RusotoError::service(CreateDomainError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDomainError::Service(err.msg))
+                    return RusotoError::service(CreateDomainError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDomainError::Unauthenticated(err.msg))
+                    return RusotoError::service(CreateDomainError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5216,12 +5369,14 @@ impl fmt::Display for CreateDomainError {
             CreateDomainError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateDomainError::Service(ref cause) => write!(f, "{}", cause),
             CreateDomainError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDomainError {}
 /// Errors returned by CreateDomainEntry
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDomainEntryError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5237,6 +5392,8 @@ pub enum CreateDomainEntryError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDomainEntryError {
@@ -5244,30 +5401,43 @@ impl CreateDomainEntryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDomainEntryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateDomainEntryError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDomainEntryError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDomainEntryError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDomainEntryError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDomainEntryError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainEntryError::NotFound(err.msg))
+                    return RusotoError::service(CreateDomainEntryError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDomainEntryError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        CreateDomainEntryError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDomainEntryError::Service(err.msg))
+                    return RusotoError::service(CreateDomainEntryError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDomainEntryError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        CreateDomainEntryError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDomainEntryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5284,12 +5454,14 @@ impl fmt::Display for CreateDomainEntryError {
             CreateDomainEntryError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateDomainEntryError::Service(ref cause) => write!(f, "{}", cause),
             CreateDomainEntryError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateDomainEntryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDomainEntryError {}
 /// Errors returned by CreateInstanceSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInstanceSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5305,6 +5477,8 @@ pub enum CreateInstanceSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInstanceSnapshotError {
@@ -5312,34 +5486,49 @@ impl CreateInstanceSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateInstanceSnapshotError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInstanceSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateInstanceSnapshotError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateInstanceSnapshotError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInstanceSnapshotError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::Service(err.msg))
+                    return RusotoError::service(
+                        CreateInstanceSnapshotError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInstanceSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateInstanceSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5358,12 +5547,14 @@ impl fmt::Display for CreateInstanceSnapshotError {
             CreateInstanceSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateInstanceSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             CreateInstanceSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateInstanceSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInstanceSnapshotError {}
 /// Errors returned by CreateInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInstancesError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5379,6 +5570,8 @@ pub enum CreateInstancesError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInstancesError {
@@ -5386,30 +5579,37 @@ impl CreateInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateInstancesError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateInstancesError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateInstancesError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInstancesError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateInstancesError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstancesError::NotFound(err.msg))
+                    return RusotoError::service(CreateInstancesError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateInstancesError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        CreateInstancesError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInstancesError::Service(err.msg))
+                    return RusotoError::service(CreateInstancesError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateInstancesError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        CreateInstancesError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5426,12 +5626,14 @@ impl fmt::Display for CreateInstancesError {
             CreateInstancesError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateInstancesError::Service(ref cause) => write!(f, "{}", cause),
             CreateInstancesError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInstancesError {}
 /// Errors returned by CreateInstancesFromSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInstancesFromSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5447,6 +5649,8 @@ pub enum CreateInstancesFromSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInstancesFromSnapshotError {
@@ -5456,40 +5660,54 @@ impl CreateInstancesFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInstancesFromSnapshotError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInstancesFromSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInstancesFromSnapshotError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInstancesFromSnapshotError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInstancesFromSnapshotError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::Service(err.msg))
+                    return RusotoError::service(
+                        CreateInstancesFromSnapshotError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInstancesFromSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateInstancesFromSnapshotError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5508,12 +5726,14 @@ impl fmt::Display for CreateInstancesFromSnapshotError {
             CreateInstancesFromSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateInstancesFromSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             CreateInstancesFromSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateInstancesFromSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInstancesFromSnapshotError {}
 /// Errors returned by CreateKeyPair
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateKeyPairError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5529,6 +5749,8 @@ pub enum CreateKeyPairError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateKeyPairError {
@@ -5536,30 +5758,34 @@ impl CreateKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateKeyPairError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateKeyPairError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateKeyPairError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateKeyPairError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(CreateKeyPairError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        CreateKeyPairError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateKeyPairError::Service(err.msg))
+                    return RusotoError::service(CreateKeyPairError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service(CreateKeyPairError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateKeyPairError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5576,12 +5802,14 @@ impl fmt::Display for CreateKeyPairError {
             CreateKeyPairError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateKeyPairError::Service(ref cause) => write!(f, "{}", cause),
             CreateKeyPairError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateKeyPairError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateKeyPairError {}
 /// Errors returned by CreateLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLoadBalancerError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5597,6 +5825,8 @@ pub enum CreateLoadBalancerError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLoadBalancerError {
@@ -5604,30 +5834,43 @@ impl CreateLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateLoadBalancerError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLoadBalancerError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateLoadBalancerError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::NotFound(err.msg))
+                    return RusotoError::service(CreateLoadBalancerError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        CreateLoadBalancerError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::Service(err.msg))
+                    return RusotoError::service(CreateLoadBalancerError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        CreateLoadBalancerError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLoadBalancerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5644,12 +5887,14 @@ impl fmt::Display for CreateLoadBalancerError {
             CreateLoadBalancerError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateLoadBalancerError::Service(ref cause) => write!(f, "{}", cause),
             CreateLoadBalancerError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateLoadBalancerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLoadBalancerError {}
 /// Errors returned by CreateLoadBalancerTlsCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLoadBalancerTlsCertificateError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5665,6 +5910,8 @@ pub enum CreateLoadBalancerTlsCertificateError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLoadBalancerTlsCertificateError {
@@ -5674,42 +5921,54 @@ impl CreateLoadBalancerTlsCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateLoadBalancerTlsCertificateError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateLoadBalancerTlsCertificateError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateLoadBalancerTlsCertificateError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateLoadBalancerTlsCertificateError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLoadBalancerTlsCertificateError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateLoadBalancerTlsCertificateError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateLoadBalancerTlsCertificateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLoadBalancerTlsCertificateError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateLoadBalancerTlsCertificateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5736,12 +5995,16 @@ impl fmt::Display for CreateLoadBalancerTlsCertificateError {
             CreateLoadBalancerTlsCertificateError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateLoadBalancerTlsCertificateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateLoadBalancerTlsCertificateError {}
 /// Errors returned by CreateRelationalDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRelationalDatabaseError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5757,6 +6020,8 @@ pub enum CreateRelationalDatabaseError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRelationalDatabaseError {
@@ -5764,38 +6029,49 @@ impl CreateRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRelationalDatabaseError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRelationalDatabaseError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateRelationalDatabaseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRelationalDatabaseError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        CreateRelationalDatabaseError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRelationalDatabaseError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRelationalDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5814,12 +6090,14 @@ impl fmt::Display for CreateRelationalDatabaseError {
             CreateRelationalDatabaseError::OperationFailure(ref cause) => write!(f, "{}", cause),
             CreateRelationalDatabaseError::Service(ref cause) => write!(f, "{}", cause),
             CreateRelationalDatabaseError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            CreateRelationalDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRelationalDatabaseError {}
 /// Errors returned by CreateRelationalDatabaseFromSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRelationalDatabaseFromSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5835,6 +6113,8 @@ pub enum CreateRelationalDatabaseFromSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRelationalDatabaseFromSnapshotError {
@@ -5844,42 +6124,54 @@ impl CreateRelationalDatabaseFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseFromSnapshotError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseFromSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseFromSnapshotError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseFromSnapshotError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseFromSnapshotError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseFromSnapshotError::Service(err.msg),
+                        &res,
                     )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseFromSnapshotError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateRelationalDatabaseFromSnapshotError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5908,12 +6200,16 @@ impl fmt::Display for CreateRelationalDatabaseFromSnapshotError {
             CreateRelationalDatabaseFromSnapshotError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateRelationalDatabaseFromSnapshotError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateRelationalDatabaseFromSnapshotError {}
 /// Errors returned by CreateRelationalDatabaseSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRelationalDatabaseSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -5929,6 +6225,8 @@ pub enum CreateRelationalDatabaseSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRelationalDatabaseSnapshotError {
@@ -5938,42 +6236,54 @@ impl CreateRelationalDatabaseSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseSnapshotError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseSnapshotError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseSnapshotError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRelationalDatabaseSnapshotError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseSnapshotError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseSnapshotError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRelationalDatabaseSnapshotError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateRelationalDatabaseSnapshotError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6000,12 +6310,16 @@ impl fmt::Display for CreateRelationalDatabaseSnapshotError {
             CreateRelationalDatabaseSnapshotError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateRelationalDatabaseSnapshotError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateRelationalDatabaseSnapshotError {}
 /// Errors returned by DeleteAutoSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAutoSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6019,6 +6333,8 @@ pub enum DeleteAutoSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAutoSnapshotError {
@@ -6026,25 +6342,37 @@ impl DeleteAutoSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteAutoSnapshotError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteAutoSnapshotError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAutoSnapshotError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteAutoSnapshotError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::Service(err.msg))
+                    return RusotoError::service(DeleteAutoSnapshotError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        DeleteAutoSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAutoSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6060,12 +6388,14 @@ impl fmt::Display for DeleteAutoSnapshotError {
             DeleteAutoSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteAutoSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             DeleteAutoSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteAutoSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAutoSnapshotError {}
 /// Errors returned by DeleteDisk
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDiskError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6081,6 +6411,8 @@ pub enum DeleteDiskError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDiskError {
@@ -6088,28 +6420,31 @@ impl DeleteDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteDiskError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        DeleteDiskError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteDiskError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDiskError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDiskError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(DeleteDiskError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDiskError::Service(err.msg))
+                    return RusotoError::service(DeleteDiskError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(DeleteDiskError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDiskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6126,12 +6461,14 @@ impl fmt::Display for DeleteDiskError {
             DeleteDiskError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteDiskError::Service(ref cause) => write!(f, "{}", cause),
             DeleteDiskError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteDiskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDiskError {}
 /// Errors returned by DeleteDiskSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDiskSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6147,6 +6484,8 @@ pub enum DeleteDiskSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDiskSnapshotError {
@@ -6154,30 +6493,43 @@ impl DeleteDiskSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteDiskSnapshotError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDiskSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteDiskSnapshotError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDiskSnapshotError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteDiskSnapshotError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::Service(err.msg))
+                    return RusotoError::service(DeleteDiskSnapshotError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        DeleteDiskSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDiskSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6194,12 +6546,14 @@ impl fmt::Display for DeleteDiskSnapshotError {
             DeleteDiskSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteDiskSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             DeleteDiskSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteDiskSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDiskSnapshotError {}
 /// Errors returned by DeleteDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDomainError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6215,6 +6569,8 @@ pub enum DeleteDomainError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDomainError {
@@ -6222,28 +6578,31 @@ impl DeleteDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDomainError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteDomainError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDomainError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        DeleteDomainError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteDomainError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDomainError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDomainError::OperationFailure(err.msg))
+                    return RusotoError::service(DeleteDomainError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDomainError::Service(err.msg))
+                    return RusotoError::service(DeleteDomainError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDomainError::Unauthenticated(err.msg))
+                    return RusotoError::service(DeleteDomainError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6260,12 +6619,14 @@ impl fmt::Display for DeleteDomainError {
             DeleteDomainError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteDomainError::Service(ref cause) => write!(f, "{}", cause),
             DeleteDomainError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDomainError {}
 /// Errors returned by DeleteDomainEntry
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDomainEntryError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6281,6 +6642,8 @@ pub enum DeleteDomainEntryError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDomainEntryError {
@@ -6288,30 +6651,43 @@ impl DeleteDomainEntryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteDomainEntryError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDomainEntryError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteDomainEntryError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDomainEntryError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteDomainEntryError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::Service(err.msg))
+                    return RusotoError::service(DeleteDomainEntryError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        DeleteDomainEntryError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDomainEntryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6328,12 +6704,14 @@ impl fmt::Display for DeleteDomainEntryError {
             DeleteDomainEntryError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteDomainEntryError::Service(ref cause) => write!(f, "{}", cause),
             DeleteDomainEntryError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteDomainEntryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDomainEntryError {}
 /// Errors returned by DeleteInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInstanceError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6349,6 +6727,8 @@ pub enum DeleteInstanceError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInstanceError {
@@ -6356,30 +6736,37 @@ impl DeleteInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteInstanceError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteInstanceError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInstanceError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteInstanceError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceError::NotFound(err.msg))
+                    return RusotoError::service(DeleteInstanceError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteInstanceError::Service(err.msg))
+                    return RusotoError::service(DeleteInstanceError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6396,12 +6783,14 @@ impl fmt::Display for DeleteInstanceError {
             DeleteInstanceError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteInstanceError::Service(ref cause) => write!(f, "{}", cause),
             DeleteInstanceError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInstanceError {}
 /// Errors returned by DeleteInstanceSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInstanceSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6417,6 +6806,8 @@ pub enum DeleteInstanceSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInstanceSnapshotError {
@@ -6424,34 +6815,49 @@ impl DeleteInstanceSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceSnapshotError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteInstanceSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceSnapshotError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceSnapshotError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInstanceSnapshotError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::Service(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceSnapshotError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInstanceSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInstanceSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6470,12 +6876,14 @@ impl fmt::Display for DeleteInstanceSnapshotError {
             DeleteInstanceSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteInstanceSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             DeleteInstanceSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteInstanceSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInstanceSnapshotError {}
 /// Errors returned by DeleteKeyPair
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteKeyPairError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6491,6 +6899,8 @@ pub enum DeleteKeyPairError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteKeyPairError {
@@ -6498,30 +6908,34 @@ impl DeleteKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteKeyPairError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteKeyPairError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteKeyPairError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteKeyPairError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(DeleteKeyPairError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteKeyPairError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteKeyPairError::Service(err.msg))
+                    return RusotoError::service(DeleteKeyPairError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service(DeleteKeyPairError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteKeyPairError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6538,12 +6952,14 @@ impl fmt::Display for DeleteKeyPairError {
             DeleteKeyPairError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteKeyPairError::Service(ref cause) => write!(f, "{}", cause),
             DeleteKeyPairError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteKeyPairError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteKeyPairError {}
 /// Errors returned by DeleteKnownHostKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteKnownHostKeysError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6559,6 +6975,8 @@ pub enum DeleteKnownHostKeysError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteKnownHostKeysError {
@@ -6566,32 +6984,43 @@ impl DeleteKnownHostKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteKnownHostKeysError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteKnownHostKeysError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteKnownHostKeysError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::NotFound(err.msg))
+                    return RusotoError::service(DeleteKnownHostKeysError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteKnownHostKeysError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::Service(err.msg))
+                    return RusotoError::service(DeleteKnownHostKeysError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        DeleteKnownHostKeysError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteKnownHostKeysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6608,12 +7037,14 @@ impl fmt::Display for DeleteKnownHostKeysError {
             DeleteKnownHostKeysError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteKnownHostKeysError::Service(ref cause) => write!(f, "{}", cause),
             DeleteKnownHostKeysError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteKnownHostKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteKnownHostKeysError {}
 /// Errors returned by DeleteLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLoadBalancerError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6629,6 +7060,8 @@ pub enum DeleteLoadBalancerError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLoadBalancerError {
@@ -6636,30 +7069,43 @@ impl DeleteLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteLoadBalancerError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLoadBalancerError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteLoadBalancerError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::NotFound(err.msg))
+                    return RusotoError::service(DeleteLoadBalancerError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteLoadBalancerError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::Service(err.msg))
+                    return RusotoError::service(DeleteLoadBalancerError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        DeleteLoadBalancerError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLoadBalancerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6676,12 +7122,14 @@ impl fmt::Display for DeleteLoadBalancerError {
             DeleteLoadBalancerError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteLoadBalancerError::Service(ref cause) => write!(f, "{}", cause),
             DeleteLoadBalancerError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteLoadBalancerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLoadBalancerError {}
 /// Errors returned by DeleteLoadBalancerTlsCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLoadBalancerTlsCertificateError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6697,6 +7145,8 @@ pub enum DeleteLoadBalancerTlsCertificateError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLoadBalancerTlsCertificateError {
@@ -6706,42 +7156,54 @@ impl DeleteLoadBalancerTlsCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteLoadBalancerTlsCertificateError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteLoadBalancerTlsCertificateError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteLoadBalancerTlsCertificateError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteLoadBalancerTlsCertificateError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLoadBalancerTlsCertificateError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteLoadBalancerTlsCertificateError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLoadBalancerTlsCertificateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLoadBalancerTlsCertificateError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteLoadBalancerTlsCertificateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6768,12 +7230,16 @@ impl fmt::Display for DeleteLoadBalancerTlsCertificateError {
             DeleteLoadBalancerTlsCertificateError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteLoadBalancerTlsCertificateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteLoadBalancerTlsCertificateError {}
 /// Errors returned by DeleteRelationalDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRelationalDatabaseError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6789,6 +7255,8 @@ pub enum DeleteRelationalDatabaseError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRelationalDatabaseError {
@@ -6796,38 +7264,49 @@ impl DeleteRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRelationalDatabaseError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRelationalDatabaseError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteRelationalDatabaseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRelationalDatabaseError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        DeleteRelationalDatabaseError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRelationalDatabaseError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRelationalDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6846,12 +7325,14 @@ impl fmt::Display for DeleteRelationalDatabaseError {
             DeleteRelationalDatabaseError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DeleteRelationalDatabaseError::Service(ref cause) => write!(f, "{}", cause),
             DeleteRelationalDatabaseError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DeleteRelationalDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRelationalDatabaseError {}
 /// Errors returned by DeleteRelationalDatabaseSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRelationalDatabaseSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6867,6 +7348,8 @@ pub enum DeleteRelationalDatabaseSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRelationalDatabaseSnapshotError {
@@ -6876,42 +7359,54 @@ impl DeleteRelationalDatabaseSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRelationalDatabaseSnapshotError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRelationalDatabaseSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRelationalDatabaseSnapshotError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseSnapshotError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRelationalDatabaseSnapshotError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRelationalDatabaseSnapshotError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseSnapshotError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRelationalDatabaseSnapshotError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteRelationalDatabaseSnapshotError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6938,12 +7433,16 @@ impl fmt::Display for DeleteRelationalDatabaseSnapshotError {
             DeleteRelationalDatabaseSnapshotError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteRelationalDatabaseSnapshotError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteRelationalDatabaseSnapshotError {}
 /// Errors returned by DetachDisk
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachDiskError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -6959,6 +7458,8 @@ pub enum DetachDiskError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachDiskError {
@@ -6966,28 +7467,31 @@ impl DetachDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(DetachDiskError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DetachDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        DetachDiskError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DetachDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(DetachDiskError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachDiskError::NotFound(err.msg))
+                    return RusotoError::service(DetachDiskError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DetachDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(DetachDiskError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachDiskError::Service(err.msg))
+                    return RusotoError::service(DetachDiskError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DetachDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(DetachDiskError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetachDiskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7004,12 +7508,14 @@ impl fmt::Display for DetachDiskError {
             DetachDiskError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DetachDiskError::Service(ref cause) => write!(f, "{}", cause),
             DetachDiskError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DetachDiskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachDiskError {}
 /// Errors returned by DetachInstancesFromLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachInstancesFromLoadBalancerError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7025,6 +7531,8 @@ pub enum DetachInstancesFromLoadBalancerError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachInstancesFromLoadBalancerError {
@@ -7034,42 +7542,54 @@ impl DetachInstancesFromLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetachInstancesFromLoadBalancerError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetachInstancesFromLoadBalancerError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetachInstancesFromLoadBalancerError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachInstancesFromLoadBalancerError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetachInstancesFromLoadBalancerError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetachInstancesFromLoadBalancerError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachInstancesFromLoadBalancerError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetachInstancesFromLoadBalancerError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetachInstancesFromLoadBalancerError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DetachInstancesFromLoadBalancerError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7092,12 +7612,16 @@ impl fmt::Display for DetachInstancesFromLoadBalancerError {
             DetachInstancesFromLoadBalancerError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DetachInstancesFromLoadBalancerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DetachInstancesFromLoadBalancerError {}
 /// Errors returned by DetachStaticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachStaticIpError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7113,6 +7637,8 @@ pub enum DetachStaticIpError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachStaticIpError {
@@ -7120,30 +7646,37 @@ impl DetachStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(DetachStaticIpError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DetachStaticIpError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetachStaticIpError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DetachStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(DetachStaticIpError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(DetachStaticIpError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DetachStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        DetachStaticIpError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachStaticIpError::Service(err.msg))
+                    return RusotoError::service(DetachStaticIpError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DetachStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        DetachStaticIpError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetachStaticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7160,12 +7693,14 @@ impl fmt::Display for DetachStaticIpError {
             DetachStaticIpError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DetachStaticIpError::Service(ref cause) => write!(f, "{}", cause),
             DetachStaticIpError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DetachStaticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachStaticIpError {}
 /// Errors returned by DisableAddOn
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableAddOnError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7179,6 +7714,8 @@ pub enum DisableAddOnError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableAddOnError {
@@ -7186,25 +7723,25 @@ impl DisableAddOnError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableAddOnError::AccessDenied(err.msg))
+                    return RusotoError::service(DisableAddOnError::AccessDenied(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisableAddOnError::InvalidInput(err.msg))
+                    return RusotoError::service(DisableAddOnError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisableAddOnError::NotFound(err.msg))
+                    return RusotoError::service(DisableAddOnError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DisableAddOnError::OperationFailure(err.msg))
+                    return RusotoError::service(DisableAddOnError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableAddOnError::Service(err.msg))
+                    return RusotoError::service(DisableAddOnError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DisableAddOnError::Unauthenticated(err.msg))
+                    return RusotoError::service(DisableAddOnError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableAddOnError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7220,12 +7757,14 @@ impl fmt::Display for DisableAddOnError {
             DisableAddOnError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DisableAddOnError::Service(ref cause) => write!(f, "{}", cause),
             DisableAddOnError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DisableAddOnError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableAddOnError {}
 /// Errors returned by DownloadDefaultKeyPair
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DownloadDefaultKeyPairError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7241,6 +7780,8 @@ pub enum DownloadDefaultKeyPairError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DownloadDefaultKeyPairError {
@@ -7248,34 +7789,49 @@ impl DownloadDefaultKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DownloadDefaultKeyPairError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DownloadDefaultKeyPairError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DownloadDefaultKeyPairError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DownloadDefaultKeyPairError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DownloadDefaultKeyPairError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::Service(err.msg))
+                    return RusotoError::service(
+                        DownloadDefaultKeyPairError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DownloadDefaultKeyPairError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DownloadDefaultKeyPairError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7294,12 +7850,14 @@ impl fmt::Display for DownloadDefaultKeyPairError {
             DownloadDefaultKeyPairError::OperationFailure(ref cause) => write!(f, "{}", cause),
             DownloadDefaultKeyPairError::Service(ref cause) => write!(f, "{}", cause),
             DownloadDefaultKeyPairError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            DownloadDefaultKeyPairError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DownloadDefaultKeyPairError {}
 /// Errors returned by EnableAddOn
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableAddOnError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7313,6 +7871,8 @@ pub enum EnableAddOnError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableAddOnError {
@@ -7320,25 +7880,25 @@ impl EnableAddOnError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableAddOnError::AccessDenied(err.msg))
+                    return RusotoError::service(EnableAddOnError::AccessDenied(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableAddOnError::InvalidInput(err.msg))
+                    return RusotoError::service(EnableAddOnError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EnableAddOnError::NotFound(err.msg))
+                    return RusotoError::service(EnableAddOnError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(EnableAddOnError::OperationFailure(err.msg))
+                    return RusotoError::service(EnableAddOnError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableAddOnError::Service(err.msg))
+                    return RusotoError::service(EnableAddOnError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(EnableAddOnError::Unauthenticated(err.msg))
+                    return RusotoError::service(EnableAddOnError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableAddOnError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7354,12 +7914,14 @@ impl fmt::Display for EnableAddOnError {
             EnableAddOnError::OperationFailure(ref cause) => write!(f, "{}", cause),
             EnableAddOnError::Service(ref cause) => write!(f, "{}", cause),
             EnableAddOnError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            EnableAddOnError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableAddOnError {}
 /// Errors returned by ExportSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExportSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7375,6 +7937,8 @@ pub enum ExportSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExportSnapshotError {
@@ -7382,30 +7946,37 @@ impl ExportSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ExportSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(ExportSnapshotError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(ExportSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ExportSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(ExportSnapshotError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExportSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(ExportSnapshotError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ExportSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        ExportSnapshotError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ExportSnapshotError::Service(err.msg))
+                    return RusotoError::service(ExportSnapshotError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ExportSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        ExportSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExportSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7422,12 +7993,14 @@ impl fmt::Display for ExportSnapshotError {
             ExportSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             ExportSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             ExportSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            ExportSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExportSnapshotError {}
 /// Errors returned by GetActiveNames
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetActiveNamesError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7443,6 +8016,8 @@ pub enum GetActiveNamesError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetActiveNamesError {
@@ -7450,30 +8025,37 @@ impl GetActiveNamesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetActiveNamesError::AccessDenied(err.msg))
+                    return RusotoError::service(GetActiveNamesError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetActiveNamesError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetActiveNamesError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetActiveNamesError::InvalidInput(err.msg))
+                    return RusotoError::service(GetActiveNamesError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetActiveNamesError::NotFound(err.msg))
+                    return RusotoError::service(GetActiveNamesError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetActiveNamesError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        GetActiveNamesError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetActiveNamesError::Service(err.msg))
+                    return RusotoError::service(GetActiveNamesError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetActiveNamesError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        GetActiveNamesError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetActiveNamesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7490,12 +8072,14 @@ impl fmt::Display for GetActiveNamesError {
             GetActiveNamesError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetActiveNamesError::Service(ref cause) => write!(f, "{}", cause),
             GetActiveNamesError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetActiveNamesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetActiveNamesError {}
 /// Errors returned by GetAutoSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAutoSnapshotsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7509,6 +8093,8 @@ pub enum GetAutoSnapshotsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAutoSnapshotsError {
@@ -7516,25 +8102,31 @@ impl GetAutoSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::AccessDenied(err.msg))
+                    return RusotoError::service(GetAutoSnapshotsError::AccessDenied(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetAutoSnapshotsError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::NotFound(err.msg))
+                    return RusotoError::service(GetAutoSnapshotsError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        GetAutoSnapshotsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::Service(err.msg))
+                    return RusotoError::service(GetAutoSnapshotsError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        GetAutoSnapshotsError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAutoSnapshotsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7550,12 +8142,14 @@ impl fmt::Display for GetAutoSnapshotsError {
             GetAutoSnapshotsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetAutoSnapshotsError::Service(ref cause) => write!(f, "{}", cause),
             GetAutoSnapshotsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetAutoSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAutoSnapshotsError {}
 /// Errors returned by GetBlueprints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBlueprintsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7571,6 +8165,8 @@ pub enum GetBlueprintsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBlueprintsError {
@@ -7578,30 +8174,34 @@ impl GetBlueprintsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetBlueprintsError::AccessDenied(err.msg))
+                    return RusotoError::service(GetBlueprintsError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetBlueprintsError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBlueprintsError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetBlueprintsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetBlueprintsError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBlueprintsError::NotFound(err.msg))
+                    return RusotoError::service(GetBlueprintsError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetBlueprintsError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        GetBlueprintsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetBlueprintsError::Service(err.msg))
+                    return RusotoError::service(GetBlueprintsError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetBlueprintsError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetBlueprintsError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBlueprintsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7618,12 +8218,14 @@ impl fmt::Display for GetBlueprintsError {
             GetBlueprintsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetBlueprintsError::Service(ref cause) => write!(f, "{}", cause),
             GetBlueprintsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetBlueprintsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBlueprintsError {}
 /// Errors returned by GetBundles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBundlesError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7639,6 +8241,8 @@ pub enum GetBundlesError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBundlesError {
@@ -7646,28 +8250,31 @@ impl GetBundlesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetBundlesError::AccessDenied(err.msg))
+                    return RusotoError::service(GetBundlesError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetBundlesError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetBundlesError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetBundlesError::InvalidInput(err.msg))
+                    return RusotoError::service(GetBundlesError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBundlesError::NotFound(err.msg))
+                    return RusotoError::service(GetBundlesError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetBundlesError::OperationFailure(err.msg))
+                    return RusotoError::service(GetBundlesError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetBundlesError::Service(err.msg))
+                    return RusotoError::service(GetBundlesError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetBundlesError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetBundlesError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBundlesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7684,12 +8291,14 @@ impl fmt::Display for GetBundlesError {
             GetBundlesError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetBundlesError::Service(ref cause) => write!(f, "{}", cause),
             GetBundlesError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetBundlesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBundlesError {}
 /// Errors returned by GetCloudFormationStackRecords
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCloudFormationStackRecordsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7705,6 +8314,8 @@ pub enum GetCloudFormationStackRecordsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCloudFormationStackRecordsError {
@@ -7714,42 +8325,54 @@ impl GetCloudFormationStackRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCloudFormationStackRecordsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCloudFormationStackRecordsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCloudFormationStackRecordsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCloudFormationStackRecordsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCloudFormationStackRecordsError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCloudFormationStackRecordsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCloudFormationStackRecordsError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetCloudFormationStackRecordsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7772,12 +8395,16 @@ impl fmt::Display for GetCloudFormationStackRecordsError {
             GetCloudFormationStackRecordsError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetCloudFormationStackRecordsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetCloudFormationStackRecordsError {}
 /// Errors returned by GetDisk
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDiskError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7793,6 +8420,8 @@ pub enum GetDiskError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDiskError {
@@ -7800,26 +8429,31 @@ impl GetDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(GetDiskError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetDiskError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDiskError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDiskError::NotFound(err.msg))
+                    return RusotoError::service(GetDiskError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(GetDiskError::OperationFailure(err.msg), &res)
+                }
+                "ServiceException" => {
+                    return RusotoError::service(GetDiskError::Service(err.msg), &res)
                 }
-                "ServiceException" => return RusotoError::Service(GetDiskError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetDiskError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDiskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7836,12 +8470,14 @@ impl fmt::Display for GetDiskError {
             GetDiskError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetDiskError::Service(ref cause) => write!(f, "{}", cause),
             GetDiskError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetDiskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDiskError {}
 /// Errors returned by GetDiskSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDiskSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7857,6 +8493,8 @@ pub enum GetDiskSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDiskSnapshotError {
@@ -7864,30 +8502,37 @@ impl GetDiskSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(GetDiskSnapshotError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiskSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDiskSnapshotError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(GetDiskSnapshotError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        GetDiskSnapshotError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::Service(err.msg))
+                    return RusotoError::service(GetDiskSnapshotError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        GetDiskSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDiskSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7904,12 +8549,14 @@ impl fmt::Display for GetDiskSnapshotError {
             GetDiskSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetDiskSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             GetDiskSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetDiskSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDiskSnapshotError {}
 /// Errors returned by GetDiskSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDiskSnapshotsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7925,6 +8572,8 @@ pub enum GetDiskSnapshotsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDiskSnapshotsError {
@@ -7932,30 +8581,37 @@ impl GetDiskSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::AccessDenied(err.msg))
+                    return RusotoError::service(GetDiskSnapshotsError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiskSnapshotsError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDiskSnapshotsError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::NotFound(err.msg))
+                    return RusotoError::service(GetDiskSnapshotsError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        GetDiskSnapshotsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::Service(err.msg))
+                    return RusotoError::service(GetDiskSnapshotsError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        GetDiskSnapshotsError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDiskSnapshotsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7972,12 +8628,14 @@ impl fmt::Display for GetDiskSnapshotsError {
             GetDiskSnapshotsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetDiskSnapshotsError::Service(ref cause) => write!(f, "{}", cause),
             GetDiskSnapshotsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetDiskSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDiskSnapshotsError {}
 /// Errors returned by GetDisks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDisksError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -7993,6 +8651,8 @@ pub enum GetDisksError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDisksError {
@@ -8000,26 +8660,31 @@ impl GetDisksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDisksError::AccessDenied(err.msg))
+                    return RusotoError::service(GetDisksError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDisksError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetDisksError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDisksError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDisksError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDisksError::NotFound(err.msg))
+                    return RusotoError::service(GetDisksError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDisksError::OperationFailure(err.msg))
+                    return RusotoError::service(GetDisksError::OperationFailure(err.msg), &res)
+                }
+                "ServiceException" => {
+                    return RusotoError::service(GetDisksError::Service(err.msg), &res)
                 }
-                "ServiceException" => return RusotoError::Service(GetDisksError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDisksError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetDisksError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDisksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8036,12 +8701,14 @@ impl fmt::Display for GetDisksError {
             GetDisksError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetDisksError::Service(ref cause) => write!(f, "{}", cause),
             GetDisksError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetDisksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDisksError {}
 /// Errors returned by GetDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8057,6 +8724,8 @@ pub enum GetDomainError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainError {
@@ -8064,28 +8733,31 @@ impl GetDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDomainError::AccessDenied(err.msg))
+                    return RusotoError::service(GetDomainError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDomainError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetDomainError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDomainError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainError::NotFound(err.msg))
+                    return RusotoError::service(GetDomainError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDomainError::OperationFailure(err.msg))
+                    return RusotoError::service(GetDomainError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDomainError::Service(err.msg))
+                    return RusotoError::service(GetDomainError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDomainError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetDomainError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8102,12 +8774,14 @@ impl fmt::Display for GetDomainError {
             GetDomainError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetDomainError::Service(ref cause) => write!(f, "{}", cause),
             GetDomainError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainError {}
 /// Errors returned by GetDomains
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8123,6 +8797,8 @@ pub enum GetDomainsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainsError {
@@ -8130,28 +8806,31 @@ impl GetDomainsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDomainsError::AccessDenied(err.msg))
+                    return RusotoError::service(GetDomainsError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDomainsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetDomainsError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDomainsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDomainsError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainsError::NotFound(err.msg))
+                    return RusotoError::service(GetDomainsError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDomainsError::OperationFailure(err.msg))
+                    return RusotoError::service(GetDomainsError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDomainsError::Service(err.msg))
+                    return RusotoError::service(GetDomainsError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDomainsError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetDomainsError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDomainsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8168,12 +8847,14 @@ impl fmt::Display for GetDomainsError {
             GetDomainsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetDomainsError::Service(ref cause) => write!(f, "{}", cause),
             GetDomainsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetDomainsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainsError {}
 /// Errors returned by GetExportSnapshotRecords
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetExportSnapshotRecordsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8189,6 +8870,8 @@ pub enum GetExportSnapshotRecordsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetExportSnapshotRecordsError {
@@ -8196,38 +8879,49 @@ impl GetExportSnapshotRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetExportSnapshotRecordsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetExportSnapshotRecordsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetExportSnapshotRecordsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetExportSnapshotRecordsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetExportSnapshotRecordsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::Service(err.msg))
+                    return RusotoError::service(
+                        GetExportSnapshotRecordsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetExportSnapshotRecordsError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetExportSnapshotRecordsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8246,12 +8940,14 @@ impl fmt::Display for GetExportSnapshotRecordsError {
             GetExportSnapshotRecordsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetExportSnapshotRecordsError::Service(ref cause) => write!(f, "{}", cause),
             GetExportSnapshotRecordsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetExportSnapshotRecordsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetExportSnapshotRecordsError {}
 /// Errors returned by GetInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstanceError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8267,6 +8963,8 @@ pub enum GetInstanceError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstanceError {
@@ -8274,28 +8972,31 @@ impl GetInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(GetInstanceError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetInstanceError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(GetInstanceError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceError::NotFound(err.msg))
+                    return RusotoError::service(GetInstanceError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(GetInstanceError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceError::Service(err.msg))
+                    return RusotoError::service(GetInstanceError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetInstanceError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8312,12 +9013,14 @@ impl fmt::Display for GetInstanceError {
             GetInstanceError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetInstanceError::Service(ref cause) => write!(f, "{}", cause),
             GetInstanceError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstanceError {}
 /// Errors returned by GetInstanceAccessDetails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstanceAccessDetailsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8333,6 +9036,8 @@ pub enum GetInstanceAccessDetailsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstanceAccessDetailsError {
@@ -8340,38 +9045,49 @@ impl GetInstanceAccessDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceAccessDetailsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetInstanceAccessDetailsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceAccessDetailsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetInstanceAccessDetailsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceAccessDetailsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::Service(err.msg))
+                    return RusotoError::service(
+                        GetInstanceAccessDetailsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceAccessDetailsError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstanceAccessDetailsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8390,12 +9106,14 @@ impl fmt::Display for GetInstanceAccessDetailsError {
             GetInstanceAccessDetailsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetInstanceAccessDetailsError::Service(ref cause) => write!(f, "{}", cause),
             GetInstanceAccessDetailsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetInstanceAccessDetailsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstanceAccessDetailsError {}
 /// Errors returned by GetInstanceMetricData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstanceMetricDataError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8411,6 +9129,8 @@ pub enum GetInstanceMetricDataError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstanceMetricDataError {
@@ -8418,34 +9138,46 @@ impl GetInstanceMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetInstanceMetricDataError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetInstanceMetricDataError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetInstanceMetricDataError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetInstanceMetricDataError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceMetricDataError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::Service(err.msg))
+                    return RusotoError::service(GetInstanceMetricDataError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceMetricDataError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstanceMetricDataError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8462,12 +9194,14 @@ impl fmt::Display for GetInstanceMetricDataError {
             GetInstanceMetricDataError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetInstanceMetricDataError::Service(ref cause) => write!(f, "{}", cause),
             GetInstanceMetricDataError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetInstanceMetricDataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstanceMetricDataError {}
 /// Errors returned by GetInstancePortStates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstancePortStatesError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8483,6 +9217,8 @@ pub enum GetInstancePortStatesError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstancePortStatesError {
@@ -8490,34 +9226,46 @@ impl GetInstancePortStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetInstancePortStatesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetInstancePortStatesError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetInstancePortStatesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetInstancePortStatesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstancePortStatesError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::Service(err.msg))
+                    return RusotoError::service(GetInstancePortStatesError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstancePortStatesError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstancePortStatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8534,12 +9282,14 @@ impl fmt::Display for GetInstancePortStatesError {
             GetInstancePortStatesError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetInstancePortStatesError::Service(ref cause) => write!(f, "{}", cause),
             GetInstancePortStatesError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetInstancePortStatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstancePortStatesError {}
 /// Errors returned by GetInstanceSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstanceSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8555,6 +9305,8 @@ pub enum GetInstanceSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstanceSnapshotError {
@@ -8562,32 +9314,43 @@ impl GetInstanceSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetInstanceSnapshotError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetInstanceSnapshotError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(GetInstanceSnapshotError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceSnapshotError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::Service(err.msg))
+                    return RusotoError::service(GetInstanceSnapshotError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        GetInstanceSnapshotError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstanceSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8604,12 +9367,14 @@ impl fmt::Display for GetInstanceSnapshotError {
             GetInstanceSnapshotError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetInstanceSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             GetInstanceSnapshotError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetInstanceSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstanceSnapshotError {}
 /// Errors returned by GetInstanceSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstanceSnapshotsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8625,6 +9390,8 @@ pub enum GetInstanceSnapshotsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstanceSnapshotsError {
@@ -8632,34 +9399,43 @@ impl GetInstanceSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetInstanceSnapshotsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceSnapshotsError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetInstanceSnapshotsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::NotFound(err.msg))
+                    return RusotoError::service(GetInstanceSnapshotsError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceSnapshotsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::Service(err.msg))
+                    return RusotoError::service(GetInstanceSnapshotsError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceSnapshotsError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstanceSnapshotsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8676,12 +9452,14 @@ impl fmt::Display for GetInstanceSnapshotsError {
             GetInstanceSnapshotsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetInstanceSnapshotsError::Service(ref cause) => write!(f, "{}", cause),
             GetInstanceSnapshotsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetInstanceSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstanceSnapshotsError {}
 /// Errors returned by GetInstanceState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstanceStateError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8697,6 +9475,8 @@ pub enum GetInstanceStateError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstanceStateError {
@@ -8704,30 +9484,37 @@ impl GetInstanceStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceStateError::AccessDenied(err.msg))
+                    return RusotoError::service(GetInstanceStateError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceStateError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstanceStateError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceStateError::InvalidInput(err.msg))
+                    return RusotoError::service(GetInstanceStateError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceStateError::NotFound(err.msg))
+                    return RusotoError::service(GetInstanceStateError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceStateError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        GetInstanceStateError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceStateError::Service(err.msg))
+                    return RusotoError::service(GetInstanceStateError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceStateError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        GetInstanceStateError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstanceStateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8744,12 +9531,14 @@ impl fmt::Display for GetInstanceStateError {
             GetInstanceStateError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetInstanceStateError::Service(ref cause) => write!(f, "{}", cause),
             GetInstanceStateError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetInstanceStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstanceStateError {}
 /// Errors returned by GetInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstancesError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8765,6 +9554,8 @@ pub enum GetInstancesError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstancesError {
@@ -8772,28 +9563,31 @@ impl GetInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstancesError::AccessDenied(err.msg))
+                    return RusotoError::service(GetInstancesError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstancesError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetInstancesError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service(GetInstancesError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstancesError::NotFound(err.msg))
+                    return RusotoError::service(GetInstancesError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstancesError::OperationFailure(err.msg))
+                    return RusotoError::service(GetInstancesError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstancesError::Service(err.msg))
+                    return RusotoError::service(GetInstancesError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstancesError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetInstancesError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8810,12 +9604,14 @@ impl fmt::Display for GetInstancesError {
             GetInstancesError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetInstancesError::Service(ref cause) => write!(f, "{}", cause),
             GetInstancesError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstancesError {}
 /// Errors returned by GetKeyPair
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetKeyPairError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8831,6 +9627,8 @@ pub enum GetKeyPairError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetKeyPairError {
@@ -8838,28 +9636,31 @@ impl GetKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(GetKeyPairError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetKeyPairError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetKeyPairError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(GetKeyPairError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(GetKeyPairError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service(GetKeyPairError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetKeyPairError::Service(err.msg))
+                    return RusotoError::service(GetKeyPairError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetKeyPairError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetKeyPairError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8876,12 +9677,14 @@ impl fmt::Display for GetKeyPairError {
             GetKeyPairError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetKeyPairError::Service(ref cause) => write!(f, "{}", cause),
             GetKeyPairError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetKeyPairError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetKeyPairError {}
 /// Errors returned by GetKeyPairs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetKeyPairsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8897,6 +9700,8 @@ pub enum GetKeyPairsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetKeyPairsError {
@@ -8904,28 +9709,31 @@ impl GetKeyPairsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetKeyPairsError::AccessDenied(err.msg))
+                    return RusotoError::service(GetKeyPairsError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetKeyPairsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetKeyPairsError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetKeyPairsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetKeyPairsError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyPairsError::NotFound(err.msg))
+                    return RusotoError::service(GetKeyPairsError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetKeyPairsError::OperationFailure(err.msg))
+                    return RusotoError::service(GetKeyPairsError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetKeyPairsError::Service(err.msg))
+                    return RusotoError::service(GetKeyPairsError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetKeyPairsError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetKeyPairsError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetKeyPairsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8942,12 +9750,14 @@ impl fmt::Display for GetKeyPairsError {
             GetKeyPairsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetKeyPairsError::Service(ref cause) => write!(f, "{}", cause),
             GetKeyPairsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetKeyPairsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetKeyPairsError {}
 /// Errors returned by GetLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLoadBalancerError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -8963,6 +9773,8 @@ pub enum GetLoadBalancerError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLoadBalancerError {
@@ -8970,30 +9782,37 @@ impl GetLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancerError::AccessDenied(err.msg))
+                    return RusotoError::service(GetLoadBalancerError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetLoadBalancerError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancerError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancerError::InvalidInput(err.msg))
+                    return RusotoError::service(GetLoadBalancerError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancerError::NotFound(err.msg))
+                    return RusotoError::service(GetLoadBalancerError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetLoadBalancerError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        GetLoadBalancerError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancerError::Service(err.msg))
+                    return RusotoError::service(GetLoadBalancerError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetLoadBalancerError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        GetLoadBalancerError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLoadBalancerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9010,12 +9829,14 @@ impl fmt::Display for GetLoadBalancerError {
             GetLoadBalancerError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetLoadBalancerError::Service(ref cause) => write!(f, "{}", cause),
             GetLoadBalancerError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetLoadBalancerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLoadBalancerError {}
 /// Errors returned by GetLoadBalancerMetricData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLoadBalancerMetricDataError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9031,6 +9852,8 @@ pub enum GetLoadBalancerMetricDataError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLoadBalancerMetricDataError {
@@ -9038,38 +9861,51 @@ impl GetLoadBalancerMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancerMetricDataError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetLoadBalancerMetricDataError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancerMetricDataError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetLoadBalancerMetricDataError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancerMetricDataError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::Service(err.msg))
+                    return RusotoError::service(
+                        GetLoadBalancerMetricDataError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancerMetricDataError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetLoadBalancerMetricDataError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9088,12 +9924,14 @@ impl fmt::Display for GetLoadBalancerMetricDataError {
             GetLoadBalancerMetricDataError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetLoadBalancerMetricDataError::Service(ref cause) => write!(f, "{}", cause),
             GetLoadBalancerMetricDataError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetLoadBalancerMetricDataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLoadBalancerMetricDataError {}
 /// Errors returned by GetLoadBalancerTlsCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLoadBalancerTlsCertificatesError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9109,6 +9947,8 @@ pub enum GetLoadBalancerTlsCertificatesError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLoadBalancerTlsCertificatesError {
@@ -9118,42 +9958,54 @@ impl GetLoadBalancerTlsCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancerTlsCertificatesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetLoadBalancerTlsCertificatesError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancerTlsCertificatesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancerTlsCertificatesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetLoadBalancerTlsCertificatesError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancerTlsCertificatesError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetLoadBalancerTlsCertificatesError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetLoadBalancerTlsCertificatesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9176,12 +10028,16 @@ impl fmt::Display for GetLoadBalancerTlsCertificatesError {
             GetLoadBalancerTlsCertificatesError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetLoadBalancerTlsCertificatesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetLoadBalancerTlsCertificatesError {}
 /// Errors returned by GetLoadBalancers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLoadBalancersError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9197,6 +10053,8 @@ pub enum GetLoadBalancersError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLoadBalancersError {
@@ -9204,30 +10062,37 @@ impl GetLoadBalancersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancersError::AccessDenied(err.msg))
+                    return RusotoError::service(GetLoadBalancersError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetLoadBalancersError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoadBalancersError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancersError::InvalidInput(err.msg))
+                    return RusotoError::service(GetLoadBalancersError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancersError::NotFound(err.msg))
+                    return RusotoError::service(GetLoadBalancersError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetLoadBalancersError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        GetLoadBalancersError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancersError::Service(err.msg))
+                    return RusotoError::service(GetLoadBalancersError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetLoadBalancersError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        GetLoadBalancersError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLoadBalancersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9244,12 +10109,14 @@ impl fmt::Display for GetLoadBalancersError {
             GetLoadBalancersError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetLoadBalancersError::Service(ref cause) => write!(f, "{}", cause),
             GetLoadBalancersError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetLoadBalancersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLoadBalancersError {}
 /// Errors returned by GetOperation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOperationError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9265,6 +10132,8 @@ pub enum GetOperationError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOperationError {
@@ -9272,28 +10141,31 @@ impl GetOperationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOperationError::AccessDenied(err.msg))
+                    return RusotoError::service(GetOperationError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetOperationError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetOperationError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetOperationError::InvalidInput(err.msg))
+                    return RusotoError::service(GetOperationError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOperationError::NotFound(err.msg))
+                    return RusotoError::service(GetOperationError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetOperationError::OperationFailure(err.msg))
+                    return RusotoError::service(GetOperationError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetOperationError::Service(err.msg))
+                    return RusotoError::service(GetOperationError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetOperationError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetOperationError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOperationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9310,12 +10182,14 @@ impl fmt::Display for GetOperationError {
             GetOperationError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetOperationError::Service(ref cause) => write!(f, "{}", cause),
             GetOperationError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetOperationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOperationError {}
 /// Errors returned by GetOperations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOperationsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9331,6 +10205,8 @@ pub enum GetOperationsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOperationsError {
@@ -9338,30 +10214,34 @@ impl GetOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOperationsError::AccessDenied(err.msg))
+                    return RusotoError::service(GetOperationsError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetOperationsError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOperationsError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetOperationsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetOperationsError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOperationsError::NotFound(err.msg))
+                    return RusotoError::service(GetOperationsError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetOperationsError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        GetOperationsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetOperationsError::Service(err.msg))
+                    return RusotoError::service(GetOperationsError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetOperationsError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetOperationsError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOperationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9378,12 +10258,14 @@ impl fmt::Display for GetOperationsError {
             GetOperationsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetOperationsError::Service(ref cause) => write!(f, "{}", cause),
             GetOperationsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetOperationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOperationsError {}
 /// Errors returned by GetOperationsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOperationsForResourceError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9399,6 +10281,8 @@ pub enum GetOperationsForResourceError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOperationsForResourceError {
@@ -9406,38 +10290,49 @@ impl GetOperationsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOperationsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOperationsForResourceError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOperationsForResourceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetOperationsForResourceError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOperationsForResourceError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::Service(err.msg))
+                    return RusotoError::service(
+                        GetOperationsForResourceError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOperationsForResourceError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOperationsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9456,12 +10351,14 @@ impl fmt::Display for GetOperationsForResourceError {
             GetOperationsForResourceError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetOperationsForResourceError::Service(ref cause) => write!(f, "{}", cause),
             GetOperationsForResourceError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetOperationsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOperationsForResourceError {}
 /// Errors returned by GetRegions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRegionsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9477,6 +10374,8 @@ pub enum GetRegionsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRegionsError {
@@ -9484,28 +10383,31 @@ impl GetRegionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRegionsError::AccessDenied(err.msg))
+                    return RusotoError::service(GetRegionsError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetRegionsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetRegionsError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRegionsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetRegionsError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRegionsError::NotFound(err.msg))
+                    return RusotoError::service(GetRegionsError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetRegionsError::OperationFailure(err.msg))
+                    return RusotoError::service(GetRegionsError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRegionsError::Service(err.msg))
+                    return RusotoError::service(GetRegionsError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRegionsError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetRegionsError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRegionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9522,12 +10424,14 @@ impl fmt::Display for GetRegionsError {
             GetRegionsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetRegionsError::Service(ref cause) => write!(f, "{}", cause),
             GetRegionsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetRegionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRegionsError {}
 /// Errors returned by GetRelationalDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9543,6 +10447,8 @@ pub enum GetRelationalDatabaseError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseError {
@@ -9550,34 +10456,46 @@ impl GetRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetRelationalDatabaseError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetRelationalDatabaseError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetRelationalDatabaseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(GetRelationalDatabaseError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRelationalDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9594,12 +10512,14 @@ impl fmt::Display for GetRelationalDatabaseError {
             GetRelationalDatabaseError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetRelationalDatabaseError::Service(ref cause) => write!(f, "{}", cause),
             GetRelationalDatabaseError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetRelationalDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRelationalDatabaseError {}
 /// Errors returned by GetRelationalDatabaseBlueprints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseBlueprintsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9615,6 +10535,8 @@ pub enum GetRelationalDatabaseBlueprintsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseBlueprintsError {
@@ -9624,42 +10546,54 @@ impl GetRelationalDatabaseBlueprintsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseBlueprintsError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseBlueprintsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseBlueprintsError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBlueprintsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseBlueprintsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseBlueprintsError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBlueprintsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseBlueprintsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseBlueprintsError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseBlueprintsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9682,12 +10616,16 @@ impl fmt::Display for GetRelationalDatabaseBlueprintsError {
             GetRelationalDatabaseBlueprintsError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRelationalDatabaseBlueprintsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRelationalDatabaseBlueprintsError {}
 /// Errors returned by GetRelationalDatabaseBundles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseBundlesError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9703,6 +10641,8 @@ pub enum GetRelationalDatabaseBundlesError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseBundlesError {
@@ -9712,42 +10652,54 @@ impl GetRelationalDatabaseBundlesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseBundlesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseBundlesError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseBundlesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseBundlesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseBundlesError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseBundlesError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseBundlesError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseBundlesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9768,12 +10720,14 @@ impl fmt::Display for GetRelationalDatabaseBundlesError {
             }
             GetRelationalDatabaseBundlesError::Service(ref cause) => write!(f, "{}", cause),
             GetRelationalDatabaseBundlesError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetRelationalDatabaseBundlesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRelationalDatabaseBundlesError {}
 /// Errors returned by GetRelationalDatabaseEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseEventsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9789,6 +10743,8 @@ pub enum GetRelationalDatabaseEventsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseEventsError {
@@ -9798,40 +10754,54 @@ impl GetRelationalDatabaseEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseEventsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseEventsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseEventsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseEventsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseEventsError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::Service(err.msg))
+                    return RusotoError::service(
+                        GetRelationalDatabaseEventsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseEventsError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseEventsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9850,12 +10820,14 @@ impl fmt::Display for GetRelationalDatabaseEventsError {
             GetRelationalDatabaseEventsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetRelationalDatabaseEventsError::Service(ref cause) => write!(f, "{}", cause),
             GetRelationalDatabaseEventsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetRelationalDatabaseEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRelationalDatabaseEventsError {}
 /// Errors returned by GetRelationalDatabaseLogEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseLogEventsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9871,6 +10843,8 @@ pub enum GetRelationalDatabaseLogEventsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseLogEventsError {
@@ -9880,42 +10854,54 @@ impl GetRelationalDatabaseLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseLogEventsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseLogEventsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseLogEventsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseLogEventsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseLogEventsError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseLogEventsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseLogEventsError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseLogEventsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9938,12 +10924,16 @@ impl fmt::Display for GetRelationalDatabaseLogEventsError {
             GetRelationalDatabaseLogEventsError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRelationalDatabaseLogEventsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRelationalDatabaseLogEventsError {}
 /// Errors returned by GetRelationalDatabaseLogStreams
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseLogStreamsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -9959,6 +10949,8 @@ pub enum GetRelationalDatabaseLogStreamsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseLogStreamsError {
@@ -9968,42 +10960,54 @@ impl GetRelationalDatabaseLogStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseLogStreamsError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseLogStreamsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseLogStreamsError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogStreamsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseLogStreamsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseLogStreamsError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogStreamsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseLogStreamsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseLogStreamsError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseLogStreamsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10026,12 +11030,16 @@ impl fmt::Display for GetRelationalDatabaseLogStreamsError {
             GetRelationalDatabaseLogStreamsError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRelationalDatabaseLogStreamsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRelationalDatabaseLogStreamsError {}
 /// Errors returned by GetRelationalDatabaseMasterUserPassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseMasterUserPasswordError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10047,6 +11055,8 @@ pub enum GetRelationalDatabaseMasterUserPasswordError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseMasterUserPasswordError {
@@ -10056,44 +11066,56 @@ impl GetRelationalDatabaseMasterUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMasterUserPasswordError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMasterUserPasswordError::AccountSetupInProgress(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMasterUserPasswordError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMasterUserPasswordError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMasterUserPasswordError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMasterUserPasswordError::Service(err.msg),
+                        &res,
                     )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMasterUserPasswordError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseMasterUserPasswordError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10124,12 +11146,16 @@ impl fmt::Display for GetRelationalDatabaseMasterUserPasswordError {
             GetRelationalDatabaseMasterUserPasswordError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRelationalDatabaseMasterUserPasswordError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRelationalDatabaseMasterUserPasswordError {}
 /// Errors returned by GetRelationalDatabaseMetricData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseMetricDataError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10145,6 +11171,8 @@ pub enum GetRelationalDatabaseMetricDataError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseMetricDataError {
@@ -10154,42 +11182,54 @@ impl GetRelationalDatabaseMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMetricDataError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMetricDataError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMetricDataError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseMetricDataError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseMetricDataError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMetricDataError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseMetricDataError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseMetricDataError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseMetricDataError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseMetricDataError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10212,12 +11252,16 @@ impl fmt::Display for GetRelationalDatabaseMetricDataError {
             GetRelationalDatabaseMetricDataError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRelationalDatabaseMetricDataError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRelationalDatabaseMetricDataError {}
 /// Errors returned by GetRelationalDatabaseParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseParametersError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10233,6 +11277,8 @@ pub enum GetRelationalDatabaseParametersError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseParametersError {
@@ -10242,42 +11288,54 @@ impl GetRelationalDatabaseParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseParametersError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseParametersError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseParametersError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseParametersError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseParametersError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseParametersError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseParametersError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseParametersError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseParametersError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseParametersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10300,12 +11358,16 @@ impl fmt::Display for GetRelationalDatabaseParametersError {
             GetRelationalDatabaseParametersError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRelationalDatabaseParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRelationalDatabaseParametersError {}
 /// Errors returned by GetRelationalDatabaseSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseSnapshotError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10321,6 +11383,8 @@ pub enum GetRelationalDatabaseSnapshotError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseSnapshotError {
@@ -10330,42 +11394,54 @@ impl GetRelationalDatabaseSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseSnapshotError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseSnapshotError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseSnapshotError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseSnapshotError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseSnapshotError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseSnapshotError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseSnapshotError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10388,12 +11464,16 @@ impl fmt::Display for GetRelationalDatabaseSnapshotError {
             GetRelationalDatabaseSnapshotError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRelationalDatabaseSnapshotError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRelationalDatabaseSnapshotError {}
 /// Errors returned by GetRelationalDatabaseSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabaseSnapshotsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10409,6 +11489,8 @@ pub enum GetRelationalDatabaseSnapshotsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabaseSnapshotsError {
@@ -10418,42 +11500,54 @@ impl GetRelationalDatabaseSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseSnapshotsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseSnapshotsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseSnapshotsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseSnapshotsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseSnapshotsError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabaseSnapshotsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabaseSnapshotsError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRelationalDatabaseSnapshotsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10476,12 +11570,16 @@ impl fmt::Display for GetRelationalDatabaseSnapshotsError {
             GetRelationalDatabaseSnapshotsError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRelationalDatabaseSnapshotsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRelationalDatabaseSnapshotsError {}
 /// Errors returned by GetRelationalDatabases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRelationalDatabasesError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10497,6 +11595,8 @@ pub enum GetRelationalDatabasesError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRelationalDatabasesError {
@@ -10504,34 +11604,49 @@ impl GetRelationalDatabasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetRelationalDatabasesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRelationalDatabasesError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetRelationalDatabasesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetRelationalDatabasesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabasesError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::Service(err.msg))
+                    return RusotoError::service(
+                        GetRelationalDatabasesError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRelationalDatabasesError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRelationalDatabasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10550,12 +11665,14 @@ impl fmt::Display for GetRelationalDatabasesError {
             GetRelationalDatabasesError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetRelationalDatabasesError::Service(ref cause) => write!(f, "{}", cause),
             GetRelationalDatabasesError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetRelationalDatabasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRelationalDatabasesError {}
 /// Errors returned by GetStaticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetStaticIpError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10571,6 +11688,8 @@ pub enum GetStaticIpError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetStaticIpError {
@@ -10578,28 +11697,31 @@ impl GetStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(GetStaticIpError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetStaticIpError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetStaticIpError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(GetStaticIpError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(GetStaticIpError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(GetStaticIpError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetStaticIpError::Service(err.msg))
+                    return RusotoError::service(GetStaticIpError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetStaticIpError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetStaticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10616,12 +11738,14 @@ impl fmt::Display for GetStaticIpError {
             GetStaticIpError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetStaticIpError::Service(ref cause) => write!(f, "{}", cause),
             GetStaticIpError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetStaticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetStaticIpError {}
 /// Errors returned by GetStaticIps
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetStaticIpsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10637,6 +11761,8 @@ pub enum GetStaticIpsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetStaticIpsError {
@@ -10644,28 +11770,31 @@ impl GetStaticIpsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetStaticIpsError::AccessDenied(err.msg))
+                    return RusotoError::service(GetStaticIpsError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetStaticIpsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        GetStaticIpsError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetStaticIpsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetStaticIpsError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStaticIpsError::NotFound(err.msg))
+                    return RusotoError::service(GetStaticIpsError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetStaticIpsError::OperationFailure(err.msg))
+                    return RusotoError::service(GetStaticIpsError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetStaticIpsError::Service(err.msg))
+                    return RusotoError::service(GetStaticIpsError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetStaticIpsError::Unauthenticated(err.msg))
+                    return RusotoError::service(GetStaticIpsError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetStaticIpsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10682,12 +11811,14 @@ impl fmt::Display for GetStaticIpsError {
             GetStaticIpsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             GetStaticIpsError::Service(ref cause) => write!(f, "{}", cause),
             GetStaticIpsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            GetStaticIpsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetStaticIpsError {}
 /// Errors returned by ImportKeyPair
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportKeyPairError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10703,6 +11834,8 @@ pub enum ImportKeyPairError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportKeyPairError {
@@ -10710,30 +11843,34 @@ impl ImportKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ImportKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(ImportKeyPairError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(ImportKeyPairError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportKeyPairError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ImportKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(ImportKeyPairError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(ImportKeyPairError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ImportKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        ImportKeyPairError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ImportKeyPairError::Service(err.msg))
+                    return RusotoError::service(ImportKeyPairError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ImportKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service(ImportKeyPairError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportKeyPairError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10750,12 +11887,14 @@ impl fmt::Display for ImportKeyPairError {
             ImportKeyPairError::OperationFailure(ref cause) => write!(f, "{}", cause),
             ImportKeyPairError::Service(ref cause) => write!(f, "{}", cause),
             ImportKeyPairError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            ImportKeyPairError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportKeyPairError {}
 /// Errors returned by IsVpcPeered
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum IsVpcPeeredError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10771,6 +11910,8 @@ pub enum IsVpcPeeredError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl IsVpcPeeredError {
@@ -10778,28 +11919,31 @@ impl IsVpcPeeredError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(IsVpcPeeredError::AccessDenied(err.msg))
+                    return RusotoError::service(IsVpcPeeredError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(IsVpcPeeredError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        IsVpcPeeredError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(IsVpcPeeredError::InvalidInput(err.msg))
+                    return RusotoError::service(IsVpcPeeredError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(IsVpcPeeredError::NotFound(err.msg))
+                    return RusotoError::service(IsVpcPeeredError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(IsVpcPeeredError::OperationFailure(err.msg))
+                    return RusotoError::service(IsVpcPeeredError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(IsVpcPeeredError::Service(err.msg))
+                    return RusotoError::service(IsVpcPeeredError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(IsVpcPeeredError::Unauthenticated(err.msg))
+                    return RusotoError::service(IsVpcPeeredError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(IsVpcPeeredError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10816,12 +11960,14 @@ impl fmt::Display for IsVpcPeeredError {
             IsVpcPeeredError::OperationFailure(ref cause) => write!(f, "{}", cause),
             IsVpcPeeredError::Service(ref cause) => write!(f, "{}", cause),
             IsVpcPeeredError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            IsVpcPeeredError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for IsVpcPeeredError {}
 /// Errors returned by OpenInstancePublicPorts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum OpenInstancePublicPortsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10837,6 +11983,8 @@ pub enum OpenInstancePublicPortsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl OpenInstancePublicPortsError {
@@ -10844,38 +11992,49 @@ impl OpenInstancePublicPortsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        OpenInstancePublicPortsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OpenInstancePublicPortsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        OpenInstancePublicPortsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        OpenInstancePublicPortsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        OpenInstancePublicPortsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::Service(err.msg))
+                    return RusotoError::service(
+                        OpenInstancePublicPortsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        OpenInstancePublicPortsError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(OpenInstancePublicPortsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10894,12 +12053,14 @@ impl fmt::Display for OpenInstancePublicPortsError {
             OpenInstancePublicPortsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             OpenInstancePublicPortsError::Service(ref cause) => write!(f, "{}", cause),
             OpenInstancePublicPortsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            OpenInstancePublicPortsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for OpenInstancePublicPortsError {}
 /// Errors returned by PeerVpc
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PeerVpcError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10915,6 +12076,8 @@ pub enum PeerVpcError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PeerVpcError {
@@ -10922,26 +12085,31 @@ impl PeerVpcError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PeerVpcError::AccessDenied(err.msg))
+                    return RusotoError::service(PeerVpcError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(PeerVpcError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        PeerVpcError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PeerVpcError::InvalidInput(err.msg))
+                    return RusotoError::service(PeerVpcError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PeerVpcError::NotFound(err.msg))
+                    return RusotoError::service(PeerVpcError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(PeerVpcError::OperationFailure(err.msg))
+                    return RusotoError::service(PeerVpcError::OperationFailure(err.msg), &res)
+                }
+                "ServiceException" => {
+                    return RusotoError::service(PeerVpcError::Service(err.msg), &res)
                 }
-                "ServiceException" => return RusotoError::Service(PeerVpcError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(PeerVpcError::Unauthenticated(err.msg))
+                    return RusotoError::service(PeerVpcError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PeerVpcError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10958,12 +12126,14 @@ impl fmt::Display for PeerVpcError {
             PeerVpcError::OperationFailure(ref cause) => write!(f, "{}", cause),
             PeerVpcError::Service(ref cause) => write!(f, "{}", cause),
             PeerVpcError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            PeerVpcError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PeerVpcError {}
 /// Errors returned by PutInstancePublicPorts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutInstancePublicPortsError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -10979,6 +12149,8 @@ pub enum PutInstancePublicPortsError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutInstancePublicPortsError {
@@ -10986,34 +12158,49 @@ impl PutInstancePublicPortsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        PutInstancePublicPortsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutInstancePublicPortsError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        PutInstancePublicPortsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        PutInstancePublicPortsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInstancePublicPortsError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::Service(err.msg))
+                    return RusotoError::service(
+                        PutInstancePublicPortsError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInstancePublicPortsError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutInstancePublicPortsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11032,12 +12219,14 @@ impl fmt::Display for PutInstancePublicPortsError {
             PutInstancePublicPortsError::OperationFailure(ref cause) => write!(f, "{}", cause),
             PutInstancePublicPortsError::Service(ref cause) => write!(f, "{}", cause),
             PutInstancePublicPortsError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            PutInstancePublicPortsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutInstancePublicPortsError {}
 /// Errors returned by RebootInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootInstanceError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11053,6 +12242,8 @@ pub enum RebootInstanceError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootInstanceError {
@@ -11060,30 +12251,37 @@ impl RebootInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RebootInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(RebootInstanceError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(RebootInstanceError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RebootInstanceError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RebootInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(RebootInstanceError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RebootInstanceError::NotFound(err.msg))
+                    return RusotoError::service(RebootInstanceError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(RebootInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        RebootInstanceError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RebootInstanceError::Service(err.msg))
+                    return RusotoError::service(RebootInstanceError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(RebootInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        RebootInstanceError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RebootInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11100,12 +12298,14 @@ impl fmt::Display for RebootInstanceError {
             RebootInstanceError::OperationFailure(ref cause) => write!(f, "{}", cause),
             RebootInstanceError::Service(ref cause) => write!(f, "{}", cause),
             RebootInstanceError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            RebootInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootInstanceError {}
 /// Errors returned by RebootRelationalDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootRelationalDatabaseError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11121,6 +12321,8 @@ pub enum RebootRelationalDatabaseError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootRelationalDatabaseError {
@@ -11128,38 +12330,49 @@ impl RebootRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RebootRelationalDatabaseError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RebootRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RebootRelationalDatabaseError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        RebootRelationalDatabaseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RebootRelationalDatabaseError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        RebootRelationalDatabaseError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RebootRelationalDatabaseError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RebootRelationalDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11178,12 +12391,14 @@ impl fmt::Display for RebootRelationalDatabaseError {
             RebootRelationalDatabaseError::OperationFailure(ref cause) => write!(f, "{}", cause),
             RebootRelationalDatabaseError::Service(ref cause) => write!(f, "{}", cause),
             RebootRelationalDatabaseError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            RebootRelationalDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootRelationalDatabaseError {}
 /// Errors returned by ReleaseStaticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReleaseStaticIpError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11199,6 +12414,8 @@ pub enum ReleaseStaticIpError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReleaseStaticIpError {
@@ -11206,30 +12423,37 @@ impl ReleaseStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(ReleaseStaticIpError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ReleaseStaticIpError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(ReleaseStaticIpError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(ReleaseStaticIpError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        ReleaseStaticIpError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::Service(err.msg))
+                    return RusotoError::service(ReleaseStaticIpError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        ReleaseStaticIpError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ReleaseStaticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11246,12 +12470,14 @@ impl fmt::Display for ReleaseStaticIpError {
             ReleaseStaticIpError::OperationFailure(ref cause) => write!(f, "{}", cause),
             ReleaseStaticIpError::Service(ref cause) => write!(f, "{}", cause),
             ReleaseStaticIpError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            ReleaseStaticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReleaseStaticIpError {}
 /// Errors returned by StartInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartInstanceError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11267,6 +12493,8 @@ pub enum StartInstanceError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartInstanceError {
@@ -11274,30 +12502,34 @@ impl StartInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(StartInstanceError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(StartInstanceError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartInstanceError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(StartInstanceError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartInstanceError::NotFound(err.msg))
+                    return RusotoError::service(StartInstanceError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StartInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        StartInstanceError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartInstanceError::Service(err.msg))
+                    return RusotoError::service(StartInstanceError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StartInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(StartInstanceError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11314,12 +12546,14 @@ impl fmt::Display for StartInstanceError {
             StartInstanceError::OperationFailure(ref cause) => write!(f, "{}", cause),
             StartInstanceError::Service(ref cause) => write!(f, "{}", cause),
             StartInstanceError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            StartInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartInstanceError {}
 /// Errors returned by StartRelationalDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartRelationalDatabaseError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11335,6 +12569,8 @@ pub enum StartRelationalDatabaseError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartRelationalDatabaseError {
@@ -11342,38 +12578,49 @@ impl StartRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartRelationalDatabaseError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartRelationalDatabaseError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        StartRelationalDatabaseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartRelationalDatabaseError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        StartRelationalDatabaseError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartRelationalDatabaseError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartRelationalDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11392,12 +12639,14 @@ impl fmt::Display for StartRelationalDatabaseError {
             StartRelationalDatabaseError::OperationFailure(ref cause) => write!(f, "{}", cause),
             StartRelationalDatabaseError::Service(ref cause) => write!(f, "{}", cause),
             StartRelationalDatabaseError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            StartRelationalDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartRelationalDatabaseError {}
 /// Errors returned by StopInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopInstanceError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11413,6 +12662,8 @@ pub enum StopInstanceError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopInstanceError {
@@ -11420,28 +12671,31 @@ impl StopInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(StopInstanceError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(StopInstanceError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        StopInstanceError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(StopInstanceError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopInstanceError::NotFound(err.msg))
+                    return RusotoError::service(StopInstanceError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StopInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(StopInstanceError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StopInstanceError::Service(err.msg))
+                    return RusotoError::service(StopInstanceError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StopInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(StopInstanceError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11458,12 +12712,14 @@ impl fmt::Display for StopInstanceError {
             StopInstanceError::OperationFailure(ref cause) => write!(f, "{}", cause),
             StopInstanceError::Service(ref cause) => write!(f, "{}", cause),
             StopInstanceError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            StopInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopInstanceError {}
 /// Errors returned by StopRelationalDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopRelationalDatabaseError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11479,6 +12735,8 @@ pub enum StopRelationalDatabaseError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopRelationalDatabaseError {
@@ -11486,34 +12744,49 @@ impl StopRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StopRelationalDatabaseError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        StopRelationalDatabaseError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        StopRelationalDatabaseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopRelationalDatabaseError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        StopRelationalDatabaseError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopRelationalDatabaseError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopRelationalDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11532,12 +12805,14 @@ impl fmt::Display for StopRelationalDatabaseError {
             StopRelationalDatabaseError::OperationFailure(ref cause) => write!(f, "{}", cause),
             StopRelationalDatabaseError::Service(ref cause) => write!(f, "{}", cause),
             StopRelationalDatabaseError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            StopRelationalDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopRelationalDatabaseError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11553,6 +12828,8 @@ pub enum TagResourceError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -11560,28 +12837,31 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(TagResourceError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(TagResourceError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(TagResourceError::OperationFailure(err.msg))
+                    return RusotoError::service(TagResourceError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service(TagResourceError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(TagResourceError::Unauthenticated(err.msg))
+                    return RusotoError::service(TagResourceError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11598,12 +12878,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::OperationFailure(ref cause) => write!(f, "{}", cause),
             TagResourceError::Service(ref cause) => write!(f, "{}", cause),
             TagResourceError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UnpeerVpc
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnpeerVpcError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11619,6 +12901,8 @@ pub enum UnpeerVpcError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnpeerVpcError {
@@ -11626,28 +12910,31 @@ impl UnpeerVpcError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UnpeerVpcError::AccessDenied(err.msg))
+                    return RusotoError::service(UnpeerVpcError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(UnpeerVpcError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        UnpeerVpcError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UnpeerVpcError::InvalidInput(err.msg))
+                    return RusotoError::service(UnpeerVpcError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UnpeerVpcError::NotFound(err.msg))
+                    return RusotoError::service(UnpeerVpcError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UnpeerVpcError::OperationFailure(err.msg))
+                    return RusotoError::service(UnpeerVpcError::OperationFailure(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UnpeerVpcError::Service(err.msg))
+                    return RusotoError::service(UnpeerVpcError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UnpeerVpcError::Unauthenticated(err.msg))
+                    return RusotoError::service(UnpeerVpcError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnpeerVpcError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11664,12 +12951,14 @@ impl fmt::Display for UnpeerVpcError {
             UnpeerVpcError::OperationFailure(ref cause) => write!(f, "{}", cause),
             UnpeerVpcError::Service(ref cause) => write!(f, "{}", cause),
             UnpeerVpcError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            UnpeerVpcError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnpeerVpcError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11685,6 +12974,8 @@ pub enum UntagResourceError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -11692,30 +12983,34 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UntagResourceError::AccessDenied(err.msg), &res)
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(UntagResourceError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidInput(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UntagResourceError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service(UntagResourceError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UntagResourceError::Unauthenticated(err.msg))
+                    return RusotoError::service(UntagResourceError::Unauthenticated(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11732,12 +13027,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::OperationFailure(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Service(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDomainEntry
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDomainEntryError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11753,6 +13050,8 @@ pub enum UpdateDomainEntryError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDomainEntryError {
@@ -11760,30 +13059,43 @@ impl UpdateDomainEntryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainEntryError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainEntryError::AccountSetupInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainEntryError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDomainEntryError::NotFound(err.msg), &res)
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainEntryError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::Service(err.msg))
+                    return RusotoError::service(UpdateDomainEntryError::Service(err.msg), &res)
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainEntryError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDomainEntryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11800,12 +13112,14 @@ impl fmt::Display for UpdateDomainEntryError {
             UpdateDomainEntryError::OperationFailure(ref cause) => write!(f, "{}", cause),
             UpdateDomainEntryError::Service(ref cause) => write!(f, "{}", cause),
             UpdateDomainEntryError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            UpdateDomainEntryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDomainEntryError {}
 /// Errors returned by UpdateLoadBalancerAttribute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLoadBalancerAttributeError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11821,6 +13135,8 @@ pub enum UpdateLoadBalancerAttributeError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLoadBalancerAttributeError {
@@ -11830,40 +13146,54 @@ impl UpdateLoadBalancerAttributeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLoadBalancerAttributeError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLoadBalancerAttributeError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLoadBalancerAttributeError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLoadBalancerAttributeError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateLoadBalancerAttributeError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::Service(err.msg))
+                    return RusotoError::service(
+                        UpdateLoadBalancerAttributeError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLoadBalancerAttributeError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateLoadBalancerAttributeError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11882,12 +13212,14 @@ impl fmt::Display for UpdateLoadBalancerAttributeError {
             UpdateLoadBalancerAttributeError::OperationFailure(ref cause) => write!(f, "{}", cause),
             UpdateLoadBalancerAttributeError::Service(ref cause) => write!(f, "{}", cause),
             UpdateLoadBalancerAttributeError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            UpdateLoadBalancerAttributeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateLoadBalancerAttributeError {}
 /// Errors returned by UpdateRelationalDatabase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRelationalDatabaseError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11903,6 +13235,8 @@ pub enum UpdateRelationalDatabaseError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRelationalDatabaseError {
@@ -11910,38 +13244,49 @@ impl UpdateRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRelationalDatabaseError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRelationalDatabaseError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateRelationalDatabaseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRelationalDatabaseError::OperationFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        UpdateRelationalDatabaseError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRelationalDatabaseError::Unauthenticated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRelationalDatabaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11960,12 +13305,14 @@ impl fmt::Display for UpdateRelationalDatabaseError {
             UpdateRelationalDatabaseError::OperationFailure(ref cause) => write!(f, "{}", cause),
             UpdateRelationalDatabaseError::Service(ref cause) => write!(f, "{}", cause),
             UpdateRelationalDatabaseError::Unauthenticated(ref cause) => write!(f, "{}", cause),
+            UpdateRelationalDatabaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRelationalDatabaseError {}
 /// Errors returned by UpdateRelationalDatabaseParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRelationalDatabaseParametersError {
     /// <p>Lightsail throws this exception when the user cannot be authenticated or uses invalid credentials to access a resource.</p>
     AccessDenied(String),
@@ -11981,6 +13328,8 @@ pub enum UpdateRelationalDatabaseParametersError {
     Service(String),
     /// <p>Lightsail throws this exception when the user has not been authenticated.</p>
     Unauthenticated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRelationalDatabaseParametersError {
@@ -11990,42 +13339,54 @@ impl UpdateRelationalDatabaseParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRelationalDatabaseParametersError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRelationalDatabaseParametersError::AccountSetupInProgress(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRelationalDatabaseParametersError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseParametersError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRelationalDatabaseParametersError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRelationalDatabaseParametersError::OperationFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseParametersError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRelationalDatabaseParametersError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRelationalDatabaseParametersError::Unauthenticated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateRelationalDatabaseParametersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -12052,6 +13413,9 @@ impl fmt::Display for UpdateRelationalDatabaseParametersError {
             UpdateRelationalDatabaseParametersError::Unauthenticated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateRelationalDatabaseParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -12738,6 +14102,7 @@ impl LightsailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> LightsailClient {
         LightsailClient {
             client: Client::shared(),
@@ -12763,71 +14128,178 @@ impl LightsailClient {
     pub fn new_with_client(client: Client, region: region::Region) -> LightsailClient {
         LightsailClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<LightsailClient> {
+        rusoto_core::ClientBuilder::new(LightsailClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> LightsailClient {
+        LightsailClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Lightsail for LightsailClient {
-    /// <p>Allocates a static IP address.</p>
-    async fn allocate_static_ip(
+impl LightsailClient {
+    /// Calls `allocate_static_ip`, also returning the metadata of the response.
+    pub async fn allocate_static_ip_with_metadata(
         &self,
         input: AllocateStaticIpRequest,
-    ) -> Result<AllocateStaticIpResult, RusotoError<AllocateStaticIpError>> {
+    ) -> Result<(AllocateStaticIpResult, ResponseMetadata), RusotoError<AllocateStaticIpError>>
+    {
+        let request = self.build_allocate_static_ip_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AllocateStaticIpResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AllocateStaticIpError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `allocate_static_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_allocate_static_ip(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AllocateStaticIpRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_allocate_static_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_allocate_static_ip_request(&self, input: AllocateStaticIpRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AllocateStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.AllocateStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `attach_disk`, also returning the metadata of the response.
+    pub async fn attach_disk_with_metadata(
+        &self,
+        input: AttachDiskRequest,
+    ) -> Result<(AttachDiskResult, ResponseMetadata), RusotoError<AttachDiskError>> {
+        let request = self.build_attach_disk_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AllocateStaticIpResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AttachDiskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AllocateStaticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AttachDiskError::from_response(response))
         }
     }
 
-    /// <p>Attaches a block storage disk to a running or stopped Lightsail instance and exposes it to the instance with the specified disk name.</p> <p>The <code>attach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn attach_disk(
+    /// Presigns the request `attach_disk` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_disk(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachDiskRequest,
-    ) -> Result<AttachDiskResult, RusotoError<AttachDiskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_disk_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_disk_request(&self, input: AttachDiskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.AttachDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `attach_instances_to_load_balancer`, also returning the metadata of the response.
+    pub async fn attach_instances_to_load_balancer_with_metadata(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> Result<
+        (AttachInstancesToLoadBalancerResult, ResponseMetadata),
+        RusotoError<AttachInstancesToLoadBalancerError>,
+    > {
+        let request = self.build_attach_instances_to_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AttachDiskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AttachInstancesToLoadBalancerResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachDiskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AttachInstancesToLoadBalancerError::from_response(response))
         }
     }
 
-    /// <p>Attaches one or more Lightsail instances to a load balancer.</p> <p>After some time, the instances are attached to the load balancer and the health check status is available.</p> <p>The <code>attach instances to load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn attach_instances_to_load_balancer(
+    /// Presigns the request `attach_instances_to_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_instances_to_load_balancer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachInstancesToLoadBalancerRequest,
-    ) -> Result<AttachInstancesToLoadBalancerResult, RusotoError<AttachInstancesToLoadBalancerError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_instances_to_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_instances_to_load_balancer_request(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachInstancesToLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12837,31 +14309,60 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `attach_load_balancer_tls_certificate`, also returning the metadata of the response.
+    pub async fn attach_load_balancer_tls_certificate_with_metadata(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        (AttachLoadBalancerTlsCertificateResult, ResponseMetadata),
+        RusotoError<AttachLoadBalancerTlsCertificateError>,
+    > {
+        let request = self.build_attach_load_balancer_tls_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AttachInstancesToLoadBalancerResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AttachLoadBalancerTlsCertificateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachInstancesToLoadBalancerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AttachLoadBalancerTlsCertificateError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Attaches a Transport Layer Security (TLS) certificate to your load balancer. TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>Once you create and validate your certificate, you can attach it to your load balancer. You can also use this API to rotate the certificates on your account. Use the <code>attach load balancer tls certificate</code> operation with the non-attached certificate, and it will replace the existing one and become the attached certificate.</p> <p>The <code>attach load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn attach_load_balancer_tls_certificate(
+    /// Presigns the request `attach_load_balancer_tls_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_load_balancer_tls_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachLoadBalancerTlsCertificateRequest,
-    ) -> Result<
-        AttachLoadBalancerTlsCertificateResult,
-        RusotoError<AttachLoadBalancerTlsCertificateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_load_balancer_tls_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_load_balancer_tls_certificate_request(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachLoadBalancerTlsCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12871,57 +14372,110 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `attach_static_ip`, also returning the metadata of the response.
+    pub async fn attach_static_ip_with_metadata(
+        &self,
+        input: AttachStaticIpRequest,
+    ) -> Result<(AttachStaticIpResult, ResponseMetadata), RusotoError<AttachStaticIpError>> {
+        let request = self.build_attach_static_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AttachLoadBalancerTlsCertificateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AttachStaticIpResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachLoadBalancerTlsCertificateError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AttachStaticIpError::from_response(response))
         }
     }
 
-    /// <p>Attaches a static IP address to a specific Amazon Lightsail instance.</p>
-    async fn attach_static_ip(
+    /// Presigns the request `attach_static_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_static_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachStaticIpRequest,
-    ) -> Result<AttachStaticIpResult, RusotoError<AttachStaticIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_static_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_static_ip_request(&self, input: AttachStaticIpRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.AttachStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `close_instance_public_ports`, also returning the metadata of the response.
+    pub async fn close_instance_public_ports_with_metadata(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> Result<
+        (CloseInstancePublicPortsResult, ResponseMetadata),
+        RusotoError<CloseInstancePublicPortsError>,
+    > {
+        let request = self.build_close_instance_public_ports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AttachStaticIpResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CloseInstancePublicPortsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachStaticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CloseInstancePublicPortsError::from_response(response))
         }
     }
 
-    /// <p>Closes the public ports on a specific Amazon Lightsail instance.</p> <p>The <code>close instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn close_instance_public_ports(
+    /// Presigns the request `close_instance_public_ports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_close_instance_public_ports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CloseInstancePublicPortsRequest,
-    ) -> Result<CloseInstancePublicPortsResult, RusotoError<CloseInstancePublicPortsError>> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_close_instance_public_ports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_close_instance_public_ports_request(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CloseInstancePublicPorts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12931,55 +14485,110 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `copy_snapshot`, also returning the metadata of the response.
+    pub async fn copy_snapshot_with_metadata(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> Result<(CopySnapshotResult, ResponseMetadata), RusotoError<CopySnapshotError>> {
+        let request = self.build_copy_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CloseInstancePublicPortsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CopySnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CloseInstancePublicPortsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CopySnapshotError::from_response(response))
         }
     }
 
-    /// <p>Copies a manual snapshot of an instance or disk as another manual snapshot, or copies an automatic snapshot of an instance or disk as a manual snapshot. This operation can also be used to copy a manual or automatic snapshot of an instance or a disk from one AWS Region to another in Amazon Lightsail.</p> <p>When copying a <i>manual snapshot</i>, be sure to define the <code>source region</code>, <code>source snapshot name</code>, and <code>target snapshot name</code> parameters.</p> <p>When copying an <i>automatic snapshot</i>, be sure to define the <code>source region</code>, <code>source resource name</code>, <code>target snapshot name</code>, and either the <code>restore date</code> or the <code>use latest restorable auto snapshot</code> parameters.</p>
-    async fn copy_snapshot(
+    /// Presigns the request `copy_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CopySnapshotRequest,
-    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_snapshot_request(&self, input: CopySnapshotRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CopySnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CopySnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_cloud_formation_stack`, also returning the metadata of the response.
+    pub async fn create_cloud_formation_stack_with_metadata(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> Result<
+        (CreateCloudFormationStackResult, ResponseMetadata),
+        RusotoError<CreateCloudFormationStackError>,
+    > {
+        let request = self.build_create_cloud_formation_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CopySnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCloudFormationStackResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CopySnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCloudFormationStackError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an AWS CloudFormation stack, which creates a new Amazon EC2 instance from an exported Amazon Lightsail snapshot. This operation results in a CloudFormation stack record that can be used to track the AWS CloudFormation stack created. Use the <code>get cloud formation stack records</code> operation to get a list of the CloudFormation stacks created.</p> <important> <p>Wait until after your new Amazon EC2 instance is created before running the <code>create cloud formation stack</code> operation again with the same export snapshot record.</p> </important></p>
-    async fn create_cloud_formation_stack(
+    /// Presigns the request `create_cloud_formation_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cloud_formation_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCloudFormationStackRequest,
-    ) -> Result<CreateCloudFormationStackResult, RusotoError<CreateCloudFormationStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cloud_formation_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cloud_formation_stack_request(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateCloudFormationStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12989,221 +14598,439 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_disk`, also returning the metadata of the response.
+    pub async fn create_disk_with_metadata(
+        &self,
+        input: CreateDiskRequest,
+    ) -> Result<(CreateDiskResult, ResponseMetadata), RusotoError<CreateDiskError>> {
+        let request = self.build_create_disk_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCloudFormationStackResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDiskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCloudFormationStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDiskError::from_response(response))
         }
     }
 
-    /// <p>Creates a block storage disk that can be attached to an Amazon Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>).</p> <p>The <code>create disk</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_disk(
+    /// Presigns the request `create_disk` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_disk(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDiskRequest,
-    ) -> Result<CreateDiskResult, RusotoError<CreateDiskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_disk_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_disk_request(&self, input: CreateDiskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_disk_from_snapshot`, also returning the metadata of the response.
+    pub async fn create_disk_from_snapshot_with_metadata(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> Result<
+        (CreateDiskFromSnapshotResult, ResponseMetadata),
+        RusotoError<CreateDiskFromSnapshotError>,
+    > {
+        let request = self.build_create_disk_from_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDiskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDiskFromSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDiskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDiskFromSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Creates a block storage disk from a manual or automatic snapshot of a disk. The resulting disk can be attached to an Amazon Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>).</p> <p>The <code>create disk from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>disk snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_disk_from_snapshot(
+    /// Presigns the request `create_disk_from_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_disk_from_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDiskFromSnapshotRequest,
-    ) -> Result<CreateDiskFromSnapshotResult, RusotoError<CreateDiskFromSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_disk_from_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_disk_from_snapshot_request(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDiskFromSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskFromSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_disk_snapshot`, also returning the metadata of the response.
+    pub async fn create_disk_snapshot_with_metadata(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> Result<(CreateDiskSnapshotResult, ResponseMetadata), RusotoError<CreateDiskSnapshotError>>
+    {
+        let request = self.build_create_disk_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDiskFromSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDiskSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDiskFromSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDiskSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Creates a snapshot of a block storage disk. You can use snapshots for backups, to make copies of disks, and to save data before shutting down a Lightsail instance.</p> <p>You can take a snapshot of an attached disk that is in use; however, snapshots only capture data that has been written to your disk at the time the snapshot command is issued. This may exclude any data that has been cached by any applications or the operating system. If you can pause any file systems on the disk long enough to take a snapshot, your snapshot should be complete. Nevertheless, if you cannot pause all file writes to the disk, you should unmount the disk from within the Lightsail instance, issue the create disk snapshot command, and then remount the disk to ensure a consistent and complete snapshot. You may remount and use your disk while the snapshot status is pending.</p> <p>You can also use this operation to create a snapshot of an instance's system volume. You might want to do this, for example, to recover data from the system volume of a botched instance or to create a backup of the system volume like you would for a block storage disk. To create a snapshot of a system volume, just define the <code>instance name</code> parameter when issuing the snapshot command, and a snapshot of the defined instance's system volume will be created. After the snapshot is available, you can create a block storage disk from the snapshot and attach it to a running instance to access the data on the disk.</p> <p>The <code>create disk snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_disk_snapshot(
+    /// Presigns the request `create_disk_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_disk_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDiskSnapshotRequest,
-    ) -> Result<CreateDiskSnapshotResult, RusotoError<CreateDiskSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_disk_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_disk_snapshot_request(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDiskSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_domain`, also returning the metadata of the response.
+    pub async fn create_domain_with_metadata(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<(CreateDomainResult, ResponseMetadata), RusotoError<CreateDomainError>> {
+        let request = self.build_create_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDiskSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDomainResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDiskSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDomainError::from_response(response))
         }
     }
 
-    /// <p>Creates a domain resource for the specified domain (e.g., example.com).</p> <p>The <code>create domain</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_domain(
+    /// Presigns the request `create_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDomainRequest,
-    ) -> Result<CreateDomainResult, RusotoError<CreateDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_domain_request(&self, input: CreateDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_domain_entry`, also returning the metadata of the response.
+    pub async fn create_domain_entry_with_metadata(
+        &self,
+        input: CreateDomainEntryRequest,
+    ) -> Result<(CreateDomainEntryResult, ResponseMetadata), RusotoError<CreateDomainEntryError>>
+    {
+        let request = self.build_create_domain_entry_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDomainResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDomainEntryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDomainEntryError::from_response(response))
         }
     }
 
-    /// <p>Creates one of the following entry records associated with the domain: Address (A), canonical name (CNAME), mail exchanger (MX), name server (NS), start of authority (SOA), service locator (SRV), or text (TXT).</p> <p>The <code>create domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_domain_entry(
+    /// Presigns the request `create_domain_entry` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_domain_entry(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDomainEntryRequest,
-    ) -> Result<CreateDomainEntryResult, RusotoError<CreateDomainEntryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_domain_entry_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_domain_entry_request(&self, input: CreateDomainEntryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDomainEntry");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDomainEntry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_instance_snapshot`, also returning the metadata of the response.
+    pub async fn create_instance_snapshot_with_metadata(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> Result<
+        (CreateInstanceSnapshotResult, ResponseMetadata),
+        RusotoError<CreateInstanceSnapshotError>,
+    > {
+        let request = self.build_create_instance_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDomainEntryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateInstanceSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDomainEntryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateInstanceSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Creates a snapshot of a specific virtual private server, or <i>instance</i>. You can use a snapshot to create a new instance that is based on that snapshot.</p> <p>The <code>create instance snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_instance_snapshot(
+    /// Presigns the request `create_instance_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_instance_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateInstanceSnapshotRequest,
-    ) -> Result<CreateInstanceSnapshotResult, RusotoError<CreateInstanceSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_instance_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_instance_snapshot_request(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateInstanceSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateInstanceSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_instances`, also returning the metadata of the response.
+    pub async fn create_instances_with_metadata(
+        &self,
+        input: CreateInstancesRequest,
+    ) -> Result<(CreateInstancesResult, ResponseMetadata), RusotoError<CreateInstancesError>> {
+        let request = self.build_create_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateInstanceSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateInstancesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateInstanceSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateInstancesError::from_response(response))
         }
     }
 
-    /// <p>Creates one or more Amazon Lightsail instances.</p> <p>The <code>create instances</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_instances(
+    /// Presigns the request `create_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateInstancesRequest,
-    ) -> Result<CreateInstancesResult, RusotoError<CreateInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_instances_request(&self, input: CreateInstancesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_instances_from_snapshot`, also returning the metadata of the response.
+    pub async fn create_instances_from_snapshot_with_metadata(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> Result<
+        (CreateInstancesFromSnapshotResult, ResponseMetadata),
+        RusotoError<CreateInstancesFromSnapshotError>,
+    > {
+        let request = self.build_create_instances_from_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateInstancesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateInstancesFromSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateInstancesFromSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Creates one or more new instances from a manual or automatic snapshot of an instance.</p> <p>The <code>create instances from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>instance snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_instances_from_snapshot(
+    /// Presigns the request `create_instances_from_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_instances_from_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateInstancesFromSnapshotRequest,
-    ) -> Result<CreateInstancesFromSnapshotResult, RusotoError<CreateInstancesFromSnapshotError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_instances_from_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_instances_from_snapshot_request(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateInstancesFromSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13213,86 +15040,168 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_key_pair`, also returning the metadata of the response.
+    pub async fn create_key_pair_with_metadata(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> Result<(CreateKeyPairResult, ResponseMetadata), RusotoError<CreateKeyPairError>> {
+        let request = self.build_create_key_pair_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateInstancesFromSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateKeyPairResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateInstancesFromSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateKeyPairError::from_response(response))
         }
     }
 
-    /// <p>Creates an SSH key pair.</p> <p>The <code>create key pair</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_key_pair(
+    /// Presigns the request `create_key_pair` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_key_pair(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateKeyPairRequest,
-    ) -> Result<CreateKeyPairResult, RusotoError<CreateKeyPairError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_key_pair_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_key_pair_request(&self, input: CreateKeyPairRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_load_balancer`, also returning the metadata of the response.
+    pub async fn create_load_balancer_with_metadata(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> Result<(CreateLoadBalancerResult, ResponseMetadata), RusotoError<CreateLoadBalancerError>>
+    {
+        let request = self.build_create_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateKeyPairResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLoadBalancerResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateKeyPairError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLoadBalancerError::from_response(response))
         }
     }
 
-    /// <p>Creates a Lightsail load balancer. To learn more about deciding whether to load balance your application, see <a href="https://lightsail.aws.amazon.com/ls/docs/how-to/article/configure-lightsail-instances-for-load-balancing">Configure your Lightsail instances for load balancing</a>. You can create up to 5 load balancers per AWS Region in your account.</p> <p>When you create a load balancer, you can specify a unique name and port settings. To change additional load balancer settings, use the <code>UpdateLoadBalancerAttribute</code> operation.</p> <p>The <code>create load balancer</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_load_balancer(
+    /// Presigns the request `create_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_load_balancer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLoadBalancerRequest,
-    ) -> Result<CreateLoadBalancerResult, RusotoError<CreateLoadBalancerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_load_balancer_request(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.CreateLoadBalancer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_load_balancer_tls_certificate`, also returning the metadata of the response.
+    pub async fn create_load_balancer_tls_certificate_with_metadata(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        (CreateLoadBalancerTlsCertificateResult, ResponseMetadata),
+        RusotoError<CreateLoadBalancerTlsCertificateError>,
+    > {
+        let request = self.build_create_load_balancer_tls_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLoadBalancerResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLoadBalancerTlsCertificateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLoadBalancerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLoadBalancerTlsCertificateError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Creates a Lightsail load balancer TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>The <code>create load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_load_balancer_tls_certificate(
+    /// Presigns the request `create_load_balancer_tls_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_load_balancer_tls_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLoadBalancerTlsCertificateRequest,
-    ) -> Result<
-        CreateLoadBalancerTlsCertificateResult,
-        RusotoError<CreateLoadBalancerTlsCertificateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_load_balancer_tls_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_load_balancer_tls_certificate_request(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateLoadBalancerTlsCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13302,30 +15211,58 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_relational_database`, also returning the metadata of the response.
+    pub async fn create_relational_database_with_metadata(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> Result<
+        (CreateRelationalDatabaseResult, ResponseMetadata),
+        RusotoError<CreateRelationalDatabaseError>,
+    > {
+        let request = self.build_create_relational_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLoadBalancerTlsCertificateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRelationalDatabaseResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLoadBalancerTlsCertificateError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRelationalDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Creates a new database in Amazon Lightsail.</p> <p>The <code>create relational database</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_relational_database(
+    /// Presigns the request `create_relational_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_relational_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRelationalDatabaseRequest,
-    ) -> Result<CreateRelationalDatabaseResult, RusotoError<CreateRelationalDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_relational_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_relational_database_request(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13335,31 +15272,60 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_relational_database_from_snapshot`, also returning the metadata of the response.
+    pub async fn create_relational_database_from_snapshot_with_metadata(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> Result<
+        (CreateRelationalDatabaseFromSnapshotResult, ResponseMetadata),
+        RusotoError<CreateRelationalDatabaseFromSnapshotError>,
+    > {
+        let request = self.build_create_relational_database_from_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRelationalDatabaseResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRelationalDatabaseFromSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRelationalDatabaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRelationalDatabaseFromSnapshotError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Creates a new database from an existing database snapshot in Amazon Lightsail.</p> <p>You can create a new database from a snapshot in if something goes wrong with your original database, or to change it to a different plan, such as a high availability or standard plan.</p> <p>The <code>create relational database from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by relationalDatabaseSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_relational_database_from_snapshot(
+    /// Presigns the request `create_relational_database_from_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_relational_database_from_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRelationalDatabaseFromSnapshotRequest,
-    ) -> Result<
-        CreateRelationalDatabaseFromSnapshotResult,
-        RusotoError<CreateRelationalDatabaseFromSnapshotError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_relational_database_from_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_relational_database_from_snapshot_request(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateRelationalDatabaseFromSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13369,33 +15335,60 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_relational_database_snapshot`, also returning the metadata of the response.
+    pub async fn create_relational_database_snapshot_with_metadata(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> Result<
+        (CreateRelationalDatabaseSnapshotResult, ResponseMetadata),
+        RusotoError<CreateRelationalDatabaseSnapshotError>,
+    > {
+        let request = self.build_create_relational_database_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRelationalDatabaseFromSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRelationalDatabaseSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRelationalDatabaseFromSnapshotError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRelationalDatabaseSnapshotError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Creates a snapshot of your database in Amazon Lightsail. You can use snapshots for backups, to make copies of a database, and to save data before deleting a database.</p> <p>The <code>create relational database snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn create_relational_database_snapshot(
+    /// Presigns the request `create_relational_database_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_relational_database_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRelationalDatabaseSnapshotRequest,
-    ) -> Result<
-        CreateRelationalDatabaseSnapshotResult,
-        RusotoError<CreateRelationalDatabaseSnapshotError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_relational_database_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_relational_database_snapshot_request(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateRelationalDatabaseSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13405,308 +15398,603 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `delete_auto_snapshot`, also returning the metadata of the response.
+    pub async fn delete_auto_snapshot_with_metadata(
+        &self,
+        input: DeleteAutoSnapshotRequest,
+    ) -> Result<(DeleteAutoSnapshotResult, ResponseMetadata), RusotoError<DeleteAutoSnapshotError>>
+    {
+        let request = self.build_delete_auto_snapshot_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRelationalDatabaseSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteAutoSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRelationalDatabaseSnapshotError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAutoSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Deletes an automatic snapshot of an instance or disk. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
-    async fn delete_auto_snapshot(
+    /// Presigns the request `delete_auto_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_auto_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAutoSnapshotRequest,
-    ) -> Result<DeleteAutoSnapshotResult, RusotoError<DeleteAutoSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_auto_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_auto_snapshot_request(
+        &self,
+        input: DeleteAutoSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteAutoSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteAutoSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_disk`, also returning the metadata of the response.
+    pub async fn delete_disk_with_metadata(
+        &self,
+        input: DeleteDiskRequest,
+    ) -> Result<(DeleteDiskResult, ResponseMetadata), RusotoError<DeleteDiskError>> {
+        let request = self.build_delete_disk_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteAutoSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDiskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAutoSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDiskError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified block storage disk. The disk must be in the <code>available</code> state (not attached to a Lightsail instance).</p> <note> <p>The disk may remain in the <code>deleting</code> state for several minutes.</p> </note> <p>The <code>delete disk</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_disk(
+    /// Presigns the request `delete_disk` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_disk(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDiskRequest,
-    ) -> Result<DeleteDiskResult, RusotoError<DeleteDiskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_disk_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_disk_request(&self, input: DeleteDiskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_disk_snapshot`, also returning the metadata of the response.
+    pub async fn delete_disk_snapshot_with_metadata(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> Result<(DeleteDiskSnapshotResult, ResponseMetadata), RusotoError<DeleteDiskSnapshotError>>
+    {
+        let request = self.build_delete_disk_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteDiskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDiskSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDiskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDiskSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified disk snapshot.</p> <p>When you make periodic snapshots of a disk, the snapshots are incremental, and only the blocks on the device that have changed since your last snapshot are saved in the new snapshot. When you delete a snapshot, only the data not needed for any other snapshot is removed. So regardless of which prior snapshots have been deleted, all active snapshots will have access to all the information needed to restore the disk.</p> <p>The <code>delete disk snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_disk_snapshot(
+    /// Presigns the request `delete_disk_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_disk_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDiskSnapshotRequest,
-    ) -> Result<DeleteDiskSnapshotResult, RusotoError<DeleteDiskSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_disk_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_disk_snapshot_request(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDiskSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDiskSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_domain`, also returning the metadata of the response.
+    pub async fn delete_domain_with_metadata(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<(DeleteDomainResult, ResponseMetadata), RusotoError<DeleteDomainError>> {
+        let request = self.build_delete_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDiskSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDomainResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDiskSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDomainError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified domain recordset and all of its domain records.</p> <p>The <code>delete domain</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_domain(
+    /// Presigns the request `delete_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDomainRequest,
-    ) -> Result<DeleteDomainResult, RusotoError<DeleteDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_domain_request(&self, input: DeleteDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_domain_entry`, also returning the metadata of the response.
+    pub async fn delete_domain_entry_with_metadata(
+        &self,
+        input: DeleteDomainEntryRequest,
+    ) -> Result<(DeleteDomainEntryResult, ResponseMetadata), RusotoError<DeleteDomainEntryError>>
+    {
+        let request = self.build_delete_domain_entry_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteDomainResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDomainEntryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDomainEntryError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specific domain entry.</p> <p>The <code>delete domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_domain_entry(
+    /// Presigns the request `delete_domain_entry` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_domain_entry(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDomainEntryRequest,
-    ) -> Result<DeleteDomainEntryResult, RusotoError<DeleteDomainEntryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_domain_entry_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_domain_entry_request(&self, input: DeleteDomainEntryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDomainEntry");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomainEntry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_instance`, also returning the metadata of the response.
+    pub async fn delete_instance_with_metadata(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> Result<(DeleteInstanceResult, ResponseMetadata), RusotoError<DeleteInstanceError>> {
+        let request = self.build_delete_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteDomainEntryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDomainEntryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteInstanceError::from_response(response))
         }
     }
 
-    /// <p>Deletes an Amazon Lightsail instance.</p> <p>The <code>delete instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_instance(
+    /// Presigns the request `delete_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInstanceRequest,
-    ) -> Result<DeleteInstanceResult, RusotoError<DeleteInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_instance_request(&self, input: DeleteInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_instance_snapshot`, also returning the metadata of the response.
+    pub async fn delete_instance_snapshot_with_metadata(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> Result<
+        (DeleteInstanceSnapshotResult, ResponseMetadata),
+        RusotoError<DeleteInstanceSnapshotError>,
+    > {
+        let request = self.build_delete_instance_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteInstanceSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteInstanceSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specific snapshot of a virtual private server (or <i>instance</i>).</p> <p>The <code>delete instance snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_instance_snapshot(
+    /// Presigns the request `delete_instance_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_instance_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInstanceSnapshotRequest,
-    ) -> Result<DeleteInstanceSnapshotResult, RusotoError<DeleteInstanceSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_instance_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_instance_snapshot_request(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteInstanceSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstanceSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_key_pair`, also returning the metadata of the response.
+    pub async fn delete_key_pair_with_metadata(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> Result<(DeleteKeyPairResult, ResponseMetadata), RusotoError<DeleteKeyPairError>> {
+        let request = self.build_delete_key_pair_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInstanceSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteKeyPairResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInstanceSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteKeyPairError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specific SSH key pair.</p> <p>The <code>delete key pair</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>key pair name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_key_pair(
+    /// Presigns the request `delete_key_pair` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_key_pair(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteKeyPairRequest,
-    ) -> Result<DeleteKeyPairResult, RusotoError<DeleteKeyPairError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_key_pair_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_key_pair_request(&self, input: DeleteKeyPairRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_known_host_keys`, also returning the metadata of the response.
+    pub async fn delete_known_host_keys_with_metadata(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> Result<(DeleteKnownHostKeysResult, ResponseMetadata), RusotoError<DeleteKnownHostKeysError>>
+    {
+        let request = self.build_delete_known_host_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteKeyPairResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteKnownHostKeysResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteKeyPairError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteKnownHostKeysError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes the known host key or certificate used by the Amazon Lightsail browser-based SSH or RDP clients to authenticate an instance. This operation enables the Lightsail browser-based SSH or RDP clients to connect to the instance after a host key mismatch.</p> <important> <p>Perform this operation only if you were expecting the host key or certificate mismatch or if you are familiar with the new host key or certificate on the instance. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-troubleshooting-browser-based-ssh-rdp-client-connection">Troubleshooting connection issues when using the Amazon Lightsail browser-based SSH or RDP client</a>.</p> </important></p>
-    async fn delete_known_host_keys(
+    /// Presigns the request `delete_known_host_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_known_host_keys(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteKnownHostKeysRequest,
-    ) -> Result<DeleteKnownHostKeysResult, RusotoError<DeleteKnownHostKeysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_known_host_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_known_host_keys_request(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteKnownHostKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteKnownHostKeys");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_load_balancer`, also returning the metadata of the response.
+    pub async fn delete_load_balancer_with_metadata(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> Result<(DeleteLoadBalancerResult, ResponseMetadata), RusotoError<DeleteLoadBalancerError>>
+    {
+        let request = self.build_delete_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteKnownHostKeysResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLoadBalancerResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteKnownHostKeysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLoadBalancerError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Lightsail load balancer and all its associated SSL/TLS certificates. Once the load balancer is deleted, you will need to create a new load balancer, create a new certificate, and verify domain ownership again.</p> <p>The <code>delete load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_load_balancer(
+    /// Presigns the request `delete_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_load_balancer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLoadBalancerRequest,
-    ) -> Result<DeleteLoadBalancerResult, RusotoError<DeleteLoadBalancerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_load_balancer_request(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteLoadBalancer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_load_balancer_tls_certificate`, also returning the metadata of the response.
+    pub async fn delete_load_balancer_tls_certificate_with_metadata(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        (DeleteLoadBalancerTlsCertificateResult, ResponseMetadata),
+        RusotoError<DeleteLoadBalancerTlsCertificateError>,
+    > {
+        let request = self.build_delete_load_balancer_tls_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLoadBalancerResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLoadBalancerTlsCertificateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLoadBalancerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLoadBalancerTlsCertificateError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes an SSL/TLS certificate associated with a Lightsail load balancer.</p> <p>The <code>delete load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_load_balancer_tls_certificate(
+    /// Presigns the request `delete_load_balancer_tls_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_load_balancer_tls_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLoadBalancerTlsCertificateRequest,
-    ) -> Result<
-        DeleteLoadBalancerTlsCertificateResult,
-        RusotoError<DeleteLoadBalancerTlsCertificateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_load_balancer_tls_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_load_balancer_tls_certificate_request(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerTlsCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13716,30 +16004,58 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_relational_database`, also returning the metadata of the response.
+    pub async fn delete_relational_database_with_metadata(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> Result<
+        (DeleteRelationalDatabaseResult, ResponseMetadata),
+        RusotoError<DeleteRelationalDatabaseError>,
+    > {
+        let request = self.build_delete_relational_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLoadBalancerTlsCertificateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRelationalDatabaseResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLoadBalancerTlsCertificateError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRelationalDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Deletes a database in Amazon Lightsail.</p> <p>The <code>delete relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_relational_database(
+    /// Presigns the request `delete_relational_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_relational_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRelationalDatabaseRequest,
-    ) -> Result<DeleteRelationalDatabaseResult, RusotoError<DeleteRelationalDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_relational_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_relational_database_request(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13749,31 +16065,60 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_relational_database_snapshot`, also returning the metadata of the response.
+    pub async fn delete_relational_database_snapshot_with_metadata(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> Result<
+        (DeleteRelationalDatabaseSnapshotResult, ResponseMetadata),
+        RusotoError<DeleteRelationalDatabaseSnapshotError>,
+    > {
+        let request = self.build_delete_relational_database_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRelationalDatabaseResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRelationalDatabaseSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRelationalDatabaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRelationalDatabaseSnapshotError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes a database snapshot in Amazon Lightsail.</p> <p>The <code>delete relational database snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn delete_relational_database_snapshot(
+    /// Presigns the request `delete_relational_database_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_relational_database_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRelationalDatabaseSnapshotRequest,
-    ) -> Result<
-        DeleteRelationalDatabaseSnapshotResult,
-        RusotoError<DeleteRelationalDatabaseSnapshotError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_relational_database_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_relational_database_snapshot_request(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteRelationalDatabaseSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13783,60 +16128,112 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detach_disk`, also returning the metadata of the response.
+    pub async fn detach_disk_with_metadata(
+        &self,
+        input: DetachDiskRequest,
+    ) -> Result<(DetachDiskResult, ResponseMetadata), RusotoError<DetachDiskError>> {
+        let request = self.build_detach_disk_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRelationalDatabaseSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetachDiskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRelationalDatabaseSnapshotError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetachDiskError::from_response(response))
         }
     }
 
-    /// <p>Detaches a stopped block storage disk from a Lightsail instance. Make sure to unmount any file systems on the device within your operating system before stopping the instance and detaching the disk.</p> <p>The <code>detach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn detach_disk(
+    /// Presigns the request `detach_disk` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_disk(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachDiskRequest,
-    ) -> Result<DetachDiskResult, RusotoError<DetachDiskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_disk_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_disk_request(&self, input: DetachDiskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DetachDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DetachDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detach_instances_from_load_balancer`, also returning the metadata of the response.
+    pub async fn detach_instances_from_load_balancer_with_metadata(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> Result<
+        (DetachInstancesFromLoadBalancerResult, ResponseMetadata),
+        RusotoError<DetachInstancesFromLoadBalancerError>,
+    > {
+        let request = self.build_detach_instances_from_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetachDiskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetachInstancesFromLoadBalancerResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachDiskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetachInstancesFromLoadBalancerError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Detaches the specified instances from a Lightsail load balancer.</p> <p>This operation waits until the instances are no longer needed before they are detached from the load balancer.</p> <p>The <code>detach instances from load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn detach_instances_from_load_balancer(
+    /// Presigns the request `detach_instances_from_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_instances_from_load_balancer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachInstancesFromLoadBalancerRequest,
-    ) -> Result<
-        DetachInstancesFromLoadBalancerResult,
-        RusotoError<DetachInstancesFromLoadBalancerError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_instances_from_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_instances_from_load_balancer_request(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DetachInstancesFromLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13846,273 +16243,527 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detach_static_ip`, also returning the metadata of the response.
+    pub async fn detach_static_ip_with_metadata(
+        &self,
+        input: DetachStaticIpRequest,
+    ) -> Result<(DetachStaticIpResult, ResponseMetadata), RusotoError<DetachStaticIpError>> {
+        let request = self.build_detach_static_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetachInstancesFromLoadBalancerResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetachStaticIpResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachInstancesFromLoadBalancerError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetachStaticIpError::from_response(response))
         }
     }
 
-    /// <p>Detaches a static IP from the Amazon Lightsail instance to which it is attached.</p>
-    async fn detach_static_ip(
+    /// Presigns the request `detach_static_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_static_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachStaticIpRequest,
-    ) -> Result<DetachStaticIpResult, RusotoError<DetachStaticIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_static_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_static_ip_request(&self, input: DetachStaticIpRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DetachStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DetachStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `disable_add_on`, also returning the metadata of the response.
+    pub async fn disable_add_on_with_metadata(
+        &self,
+        input: DisableAddOnRequest,
+    ) -> Result<(DisableAddOnResult, ResponseMetadata), RusotoError<DisableAddOnError>> {
+        let request = self.build_disable_add_on_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetachStaticIpResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableAddOnResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachStaticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableAddOnError::from_response(response))
         }
     }
 
-    /// <p>Disables an add-on for an Amazon Lightsail resource. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
-    async fn disable_add_on(
+    /// Presigns the request `disable_add_on` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_add_on(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableAddOnRequest,
-    ) -> Result<DisableAddOnResult, RusotoError<DisableAddOnError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_add_on_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_add_on_request(&self, input: DisableAddOnRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DisableAddOn");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DisableAddOn");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `download_default_key_pair`, also returning the metadata of the response.
+    pub async fn download_default_key_pair_with_metadata(
+        &self,
+    ) -> Result<
+        (DownloadDefaultKeyPairResult, ResponseMetadata),
+        RusotoError<DownloadDefaultKeyPairError>,
+    > {
+        let request = self.build_download_default_key_pair_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DisableAddOnResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DownloadDefaultKeyPairResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableAddOnError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DownloadDefaultKeyPairError::from_response(response))
         }
     }
 
-    /// <p>Downloads the default SSH key pair from the user's account.</p>
-    async fn download_default_key_pair(
+    /// Presigns the request `download_default_key_pair` would send with `presigner` instead of sending it.
+    pub async fn presign_download_default_key_pair(
         &self,
-    ) -> Result<DownloadDefaultKeyPairResult, RusotoError<DownloadDefaultKeyPairError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_download_default_key_pair_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_download_default_key_pair_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DownloadDefaultKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.DownloadDefaultKeyPair");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `enable_add_on`, also returning the metadata of the response.
+    pub async fn enable_add_on_with_metadata(
+        &self,
+        input: EnableAddOnRequest,
+    ) -> Result<(EnableAddOnResult, ResponseMetadata), RusotoError<EnableAddOnError>> {
+        let request = self.build_enable_add_on_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DownloadDefaultKeyPairResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnableAddOnResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DownloadDefaultKeyPairError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableAddOnError::from_response(response))
         }
     }
 
-    /// <p>Enables or modifies an add-on for an Amazon Lightsail resource. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
-    async fn enable_add_on(
+    /// Presigns the request `enable_add_on` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_add_on(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableAddOnRequest,
-    ) -> Result<EnableAddOnResult, RusotoError<EnableAddOnError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_add_on_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_add_on_request(&self, input: EnableAddOnRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("EnableAddOn");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.EnableAddOn");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `export_snapshot`, also returning the metadata of the response.
+    pub async fn export_snapshot_with_metadata(
+        &self,
+        input: ExportSnapshotRequest,
+    ) -> Result<(ExportSnapshotResult, ResponseMetadata), RusotoError<ExportSnapshotError>> {
+        let request = self.build_export_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<EnableAddOnResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ExportSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableAddOnError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ExportSnapshotError::from_response(response))
         }
     }
 
-    /// <p><p>Exports an Amazon Lightsail instance or block storage disk snapshot to Amazon Elastic Compute Cloud (Amazon EC2). This operation results in an export snapshot record that can be used with the <code>create cloud formation stack</code> operation to create new Amazon EC2 instances.</p> <p>Exported instance snapshots appear in Amazon EC2 as Amazon Machine Images (AMIs), and the instance system disk appears as an Amazon Elastic Block Store (Amazon EBS) volume. Exported disk snapshots appear in Amazon EC2 as Amazon EBS volumes. Snapshots are exported to the same Amazon Web Services Region in Amazon EC2 as the source Lightsail snapshot.</p> <p/> <p>The <code>export snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>source snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p> <note> <p>Use the <code>get instance snapshots</code> or <code>get disk snapshots</code> operations to get a list of snapshots that you can export to Amazon EC2.</p> </note></p>
-    async fn export_snapshot(
+    /// Presigns the request `export_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_export_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExportSnapshotRequest,
-    ) -> Result<ExportSnapshotResult, RusotoError<ExportSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_export_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_export_snapshot_request(&self, input: ExportSnapshotRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("ExportSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.ExportSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_active_names`, also returning the metadata of the response.
+    pub async fn get_active_names_with_metadata(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> Result<(GetActiveNamesResult, ResponseMetadata), RusotoError<GetActiveNamesError>> {
+        let request = self.build_get_active_names_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ExportSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetActiveNamesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ExportSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetActiveNamesError::from_response(response))
         }
     }
 
-    /// <p>Returns the names of all active (not deleted) resources.</p>
-    async fn get_active_names(
+    /// Presigns the request `get_active_names` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_active_names(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetActiveNamesRequest,
-    ) -> Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_active_names_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_active_names_request(&self, input: GetActiveNamesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetActiveNames");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetActiveNames");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_auto_snapshots`, also returning the metadata of the response.
+    pub async fn get_auto_snapshots_with_metadata(
+        &self,
+        input: GetAutoSnapshotsRequest,
+    ) -> Result<(GetAutoSnapshotsResult, ResponseMetadata), RusotoError<GetAutoSnapshotsError>>
+    {
+        let request = self.build_get_auto_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetActiveNamesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAutoSnapshotsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetActiveNamesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAutoSnapshotsError::from_response(response))
         }
     }
 
-    /// <p>Returns the available automatic snapshots for an instance or disk. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
-    async fn get_auto_snapshots(
+    /// Presigns the request `get_auto_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_auto_snapshots(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAutoSnapshotsRequest,
-    ) -> Result<GetAutoSnapshotsResult, RusotoError<GetAutoSnapshotsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_auto_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_auto_snapshots_request(&self, input: GetAutoSnapshotsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetAutoSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetAutoSnapshots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_blueprints`, also returning the metadata of the response.
+    pub async fn get_blueprints_with_metadata(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> Result<(GetBlueprintsResult, ResponseMetadata), RusotoError<GetBlueprintsError>> {
+        let request = self.build_get_blueprints_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetAutoSnapshotsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetBlueprintsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAutoSnapshotsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetBlueprintsError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the list of available instance images, or <i>blueprints</i>. You can use a blueprint to create a new instance already running a specific operating system, as well as a preinstalled app or development stack. The software each instance is running depends on the blueprint image you choose.</p> <note> <p>Use active blueprints when creating new instances. Inactive blueprints are listed to support customers with existing instances and are not necessarily available to create new instances. Blueprints are marked inactive when they become outdated due to operating system updates or new application releases.</p> </note></p>
-    async fn get_blueprints(
+    /// Presigns the request `get_blueprints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_blueprints(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBlueprintsRequest,
-    ) -> Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_blueprints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_blueprints_request(&self, input: GetBlueprintsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetBlueprints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetBlueprints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_bundles`, also returning the metadata of the response.
+    pub async fn get_bundles_with_metadata(
+        &self,
+        input: GetBundlesRequest,
+    ) -> Result<(GetBundlesResult, ResponseMetadata), RusotoError<GetBundlesError>> {
+        let request = self.build_get_bundles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetBlueprintsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetBundlesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBlueprintsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetBundlesError::from_response(response))
         }
     }
 
-    /// <p>Returns the list of bundles that are available for purchase. A bundle describes the specs for your virtual private server (or <i>instance</i>).</p>
-    async fn get_bundles(
+    /// Presigns the request `get_bundles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bundles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBundlesRequest,
-    ) -> Result<GetBundlesResult, RusotoError<GetBundlesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bundles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bundles_request(&self, input: GetBundlesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetBundles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetBundles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_cloud_formation_stack_records`, also returning the metadata of the response.
+    pub async fn get_cloud_formation_stack_records_with_metadata(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> Result<
+        (GetCloudFormationStackRecordsResult, ResponseMetadata),
+        RusotoError<GetCloudFormationStackRecordsError>,
+    > {
+        let request = self.build_get_cloud_formation_stack_records_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetBundlesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCloudFormationStackRecordsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBundlesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCloudFormationStackRecordsError::from_response(response))
         }
     }
 
-    /// <p>Returns the CloudFormation stack record created as a result of the <code>create cloud formation stack</code> operation.</p> <p>An AWS CloudFormation stack is used to create a new Amazon EC2 instance from an exported Lightsail snapshot.</p>
-    async fn get_cloud_formation_stack_records(
+    /// Presigns the request `get_cloud_formation_stack_records` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cloud_formation_stack_records(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCloudFormationStackRecordsRequest,
-    ) -> Result<GetCloudFormationStackRecordsResult, RusotoError<GetCloudFormationStackRecordsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cloud_formation_stack_records_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_cloud_formation_stack_records_request(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetCloudFormationStackRecords");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14122,190 +16773,371 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_disk`, also returning the metadata of the response.
+    pub async fn get_disk_with_metadata(
+        &self,
+        input: GetDiskRequest,
+    ) -> Result<(GetDiskResult, ResponseMetadata), RusotoError<GetDiskError>> {
+        let request = self.build_get_disk_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCloudFormationStackRecordsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetDiskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCloudFormationStackRecordsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDiskError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific block storage disk.</p>
-    async fn get_disk(
+    /// Presigns the request `get_disk` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_disk(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDiskRequest,
-    ) -> Result<GetDiskResult, RusotoError<GetDiskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_disk_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_disk_request(&self, input: GetDiskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDisk");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_disk_snapshot`, also returning the metadata of the response.
+    pub async fn get_disk_snapshot_with_metadata(
+        &self,
+        input: GetDiskSnapshotRequest,
+    ) -> Result<(GetDiskSnapshotResult, ResponseMetadata), RusotoError<GetDiskSnapshotError>> {
+        let request = self.build_get_disk_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDiskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDiskSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDiskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDiskSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific block storage disk snapshot.</p>
-    async fn get_disk_snapshot(
+    /// Presigns the request `get_disk_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_disk_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDiskSnapshotRequest,
-    ) -> Result<GetDiskSnapshotResult, RusotoError<GetDiskSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_disk_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_disk_snapshot_request(&self, input: GetDiskSnapshotRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDiskSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_disk_snapshots`, also returning the metadata of the response.
+    pub async fn get_disk_snapshots_with_metadata(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> Result<(GetDiskSnapshotsResult, ResponseMetadata), RusotoError<GetDiskSnapshotsError>>
+    {
+        let request = self.build_get_disk_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDiskSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDiskSnapshotsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDiskSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDiskSnapshotsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all block storage disk snapshots in your AWS account and region.</p> <p>If you are describing a long list of disk snapshots, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
-    async fn get_disk_snapshots(
+    /// Presigns the request `get_disk_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_disk_snapshots(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDiskSnapshotsRequest,
-    ) -> Result<GetDiskSnapshotsResult, RusotoError<GetDiskSnapshotsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_disk_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_disk_snapshots_request(&self, input: GetDiskSnapshotsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDiskSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_disks`, also returning the metadata of the response.
+    pub async fn get_disks_with_metadata(
+        &self,
+        input: GetDisksRequest,
+    ) -> Result<(GetDisksResult, ResponseMetadata), RusotoError<GetDisksError>> {
+        let request = self.build_get_disks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDiskSnapshotsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetDisksResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDiskSnapshotsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDisksError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all block storage disks in your AWS account and region.</p> <p>If you are describing a long list of disks, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
-    async fn get_disks(
+    /// Presigns the request `get_disks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_disks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDisksRequest,
-    ) -> Result<GetDisksResult, RusotoError<GetDisksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_disks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_disks_request(&self, input: GetDisksRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDisks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDisks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_domain`, also returning the metadata of the response.
+    pub async fn get_domain_with_metadata(
+        &self,
+        input: GetDomainRequest,
+    ) -> Result<(GetDomainResult, ResponseMetadata), RusotoError<GetDomainError>> {
+        let request = self.build_get_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDisksResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetDomainResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDisksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDomainError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific domain recordset.</p>
-    async fn get_domain(
+    /// Presigns the request `get_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainRequest,
-    ) -> Result<GetDomainResult, RusotoError<GetDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_request(&self, input: GetDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_domains`, also returning the metadata of the response.
+    pub async fn get_domains_with_metadata(
+        &self,
+        input: GetDomainsRequest,
+    ) -> Result<(GetDomainsResult, ResponseMetadata), RusotoError<GetDomainsError>> {
+        let request = self.build_get_domains_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDomainResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDomainsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDomainsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all domains in the user's account.</p>
-    async fn get_domains(
+    /// Presigns the request `get_domains` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domains(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainsRequest,
-    ) -> Result<GetDomainsResult, RusotoError<GetDomainsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domains_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domains_request(&self, input: GetDomainsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDomains");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_export_snapshot_records`, also returning the metadata of the response.
+    pub async fn get_export_snapshot_records_with_metadata(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> Result<
+        (GetExportSnapshotRecordsResult, ResponseMetadata),
+        RusotoError<GetExportSnapshotRecordsError>,
+    > {
+        let request = self.build_get_export_snapshot_records_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDomainsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetExportSnapshotRecordsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetExportSnapshotRecordsError::from_response(response))
         }
     }
 
-    /// <p>Returns the export snapshot record created as a result of the <code>export snapshot</code> operation.</p> <p>An export snapshot record can be used to create a new Amazon EC2 instance and its related resources with the <code>create cloud formation stack</code> operation.</p>
-    async fn get_export_snapshot_records(
+    /// Presigns the request `get_export_snapshot_records` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_export_snapshot_records(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetExportSnapshotRecordsRequest,
-    ) -> Result<GetExportSnapshotRecordsResult, RusotoError<GetExportSnapshotRecordsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_export_snapshot_records_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_export_snapshot_records_request(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetExportSnapshotRecords");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14315,55 +17147,110 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instance`, also returning the metadata of the response.
+    pub async fn get_instance_with_metadata(
+        &self,
+        input: GetInstanceRequest,
+    ) -> Result<(GetInstanceResult, ResponseMetadata), RusotoError<GetInstanceError>> {
+        let request = self.build_get_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetExportSnapshotRecordsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetExportSnapshotRecordsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstanceError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific Amazon Lightsail instance, which is a virtual private server.</p>
-    async fn get_instance(
+    /// Presigns the request `get_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstanceRequest,
-    ) -> Result<GetInstanceResult, RusotoError<GetInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_request(&self, input: GetInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instance_access_details`, also returning the metadata of the response.
+    pub async fn get_instance_access_details_with_metadata(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> Result<
+        (GetInstanceAccessDetailsResult, ResponseMetadata),
+        RusotoError<GetInstanceAccessDetailsError>,
+    > {
+        let request = self.build_get_instance_access_details_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstanceAccessDetailsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstanceAccessDetailsError::from_response(response))
         }
     }
 
-    /// <p>Returns temporary SSH keys you can use to connect to a specific virtual private server, or <i>instance</i>.</p> <p>The <code>get instance access details</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn get_instance_access_details(
+    /// Presigns the request `get_instance_access_details` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance_access_details(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstanceAccessDetailsRequest,
-    ) -> Result<GetInstanceAccessDetailsResult, RusotoError<GetInstanceAccessDetailsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_access_details_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_access_details_request(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceAccessDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14373,275 +17260,549 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instance_metric_data`, also returning the metadata of the response.
+    pub async fn get_instance_metric_data_with_metadata(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> Result<
+        (GetInstanceMetricDataResult, ResponseMetadata),
+        RusotoError<GetInstanceMetricDataError>,
+    > {
+        let request = self.build_get_instance_metric_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInstanceAccessDetailsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstanceMetricDataResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstanceAccessDetailsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstanceMetricDataError::from_response(response))
         }
     }
 
-    /// <p>Returns the data points for the specified Amazon Lightsail instance metric, given an instance name.</p>
-    async fn get_instance_metric_data(
+    /// Presigns the request `get_instance_metric_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance_metric_data(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstanceMetricDataRequest,
-    ) -> Result<GetInstanceMetricDataResult, RusotoError<GetInstanceMetricDataError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_metric_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_metric_data_request(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceMetricData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceMetricData");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
+        request
+    }
+
+    /// Calls `get_instance_port_states`, also returning the metadata of the response.
+    pub async fn get_instance_port_states_with_metadata(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> Result<
+        (GetInstancePortStatesResult, ResponseMetadata),
+        RusotoError<GetInstancePortStatesError>,
+    > {
+        let request = self.build_get_instance_port_states_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInstanceMetricDataResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstancePortStatesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstanceMetricDataError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstancePortStatesError::from_response(response))
         }
     }
 
-    /// <p>Returns the port states for a specific virtual private server, or <i>instance</i>.</p>
-    async fn get_instance_port_states(
+    /// Presigns the request `get_instance_port_states` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance_port_states(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstancePortStatesRequest,
-    ) -> Result<GetInstancePortStatesResult, RusotoError<GetInstancePortStatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_port_states_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_port_states_request(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstancePortStates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstancePortStates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instance_snapshot`, also returning the metadata of the response.
+    pub async fn get_instance_snapshot_with_metadata(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> Result<(GetInstanceSnapshotResult, ResponseMetadata), RusotoError<GetInstanceSnapshotError>>
+    {
+        let request = self.build_get_instance_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInstancePortStatesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstanceSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstancePortStatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstanceSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific instance snapshot.</p>
-    async fn get_instance_snapshot(
+    /// Presigns the request `get_instance_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstanceSnapshotRequest,
-    ) -> Result<GetInstanceSnapshotResult, RusotoError<GetInstanceSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_snapshot_request(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instance_snapshots`, also returning the metadata of the response.
+    pub async fn get_instance_snapshots_with_metadata(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> Result<
+        (GetInstanceSnapshotsResult, ResponseMetadata),
+        RusotoError<GetInstanceSnapshotsError>,
+    > {
+        let request = self.build_get_instance_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInstanceSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstanceSnapshotsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstanceSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstanceSnapshotsError::from_response(response))
         }
     }
 
-    /// <p>Returns all instance snapshots for the user's account.</p>
-    async fn get_instance_snapshots(
+    /// Presigns the request `get_instance_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance_snapshots(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstanceSnapshotsRequest,
-    ) -> Result<GetInstanceSnapshotsResult, RusotoError<GetInstanceSnapshotsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_snapshots_request(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instance_state`, also returning the metadata of the response.
+    pub async fn get_instance_state_with_metadata(
+        &self,
+        input: GetInstanceStateRequest,
+    ) -> Result<(GetInstanceStateResult, ResponseMetadata), RusotoError<GetInstanceStateError>>
+    {
+        let request = self.build_get_instance_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInstanceSnapshotsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstanceStateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstanceSnapshotsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstanceStateError::from_response(response))
         }
     }
 
-    /// <p>Returns the state of a specific instance. Works on one instance at a time.</p>
-    async fn get_instance_state(
+    /// Presigns the request `get_instance_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstanceStateRequest,
-    ) -> Result<GetInstanceStateResult, RusotoError<GetInstanceStateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_state_request(&self, input: GetInstanceStateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instances`, also returning the metadata of the response.
+    pub async fn get_instances_with_metadata(
+        &self,
+        input: GetInstancesRequest,
+    ) -> Result<(GetInstancesResult, ResponseMetadata), RusotoError<GetInstancesError>> {
+        let request = self.build_get_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceStateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstancesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstanceStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstancesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all Amazon Lightsail virtual private servers, or <i>instances</i>.</p>
-    async fn get_instances(
+    /// Presigns the request `get_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstancesRequest,
-    ) -> Result<GetInstancesResult, RusotoError<GetInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instances_request(&self, input: GetInstancesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_key_pair`, also returning the metadata of the response.
+    pub async fn get_key_pair_with_metadata(
+        &self,
+        input: GetKeyPairRequest,
+    ) -> Result<(GetKeyPairResult, ResponseMetadata), RusotoError<GetKeyPairError>> {
+        let request = self.build_get_key_pair_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetInstancesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetKeyPairResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetKeyPairError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific key pair.</p>
-    async fn get_key_pair(
+    /// Presigns the request `get_key_pair` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_key_pair(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetKeyPairRequest,
-    ) -> Result<GetKeyPairResult, RusotoError<GetKeyPairError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_key_pair_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_key_pair_request(&self, input: GetKeyPairRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_key_pairs`, also returning the metadata of the response.
+    pub async fn get_key_pairs_with_metadata(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> Result<(GetKeyPairsResult, ResponseMetadata), RusotoError<GetKeyPairsError>> {
+        let request = self.build_get_key_pairs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetKeyPairResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetKeyPairsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetKeyPairError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetKeyPairsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all key pairs in the user's account.</p>
-    async fn get_key_pairs(
+    /// Presigns the request `get_key_pairs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_key_pairs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetKeyPairsRequest,
-    ) -> Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_key_pairs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_key_pairs_request(&self, input: GetKeyPairsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetKeyPairs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPairs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_load_balancer`, also returning the metadata of the response.
+    pub async fn get_load_balancer_with_metadata(
+        &self,
+        input: GetLoadBalancerRequest,
+    ) -> Result<(GetLoadBalancerResult, ResponseMetadata), RusotoError<GetLoadBalancerError>> {
+        let request = self.build_get_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetKeyPairsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLoadBalancerResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetKeyPairsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLoadBalancerError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified Lightsail load balancer.</p>
-    async fn get_load_balancer(
+    /// Presigns the request `get_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_load_balancer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLoadBalancerRequest,
-    ) -> Result<GetLoadBalancerResult, RusotoError<GetLoadBalancerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_load_balancer_request(&self, input: GetLoadBalancerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_load_balancer_metric_data`, also returning the metadata of the response.
+    pub async fn get_load_balancer_metric_data_with_metadata(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> Result<
+        (GetLoadBalancerMetricDataResult, ResponseMetadata),
+        RusotoError<GetLoadBalancerMetricDataError>,
+    > {
+        let request = self.build_get_load_balancer_metric_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetLoadBalancerResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLoadBalancerMetricDataResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLoadBalancerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLoadBalancerMetricDataError::from_response(response))
         }
     }
 
-    /// <p>Returns information about health metrics for your Lightsail load balancer.</p>
-    async fn get_load_balancer_metric_data(
+    /// Presigns the request `get_load_balancer_metric_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_load_balancer_metric_data(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLoadBalancerMetricDataRequest,
-    ) -> Result<GetLoadBalancerMetricDataResult, RusotoError<GetLoadBalancerMetricDataError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_load_balancer_metric_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_load_balancer_metric_data_request(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancerMetricData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14651,31 +17812,58 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_load_balancer_tls_certificates`, also returning the metadata of the response.
+    pub async fn get_load_balancer_tls_certificates_with_metadata(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> Result<
+        (GetLoadBalancerTlsCertificatesResult, ResponseMetadata),
+        RusotoError<GetLoadBalancerTlsCertificatesError>,
+    > {
+        let request = self.build_get_load_balancer_tls_certificates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLoadBalancerMetricDataResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLoadBalancerTlsCertificatesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLoadBalancerMetricDataError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLoadBalancerTlsCertificatesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the TLS certificates that are associated with the specified Lightsail load balancer.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>You can have a maximum of 2 certificates associated with a Lightsail load balancer. One is active and the other is inactive.</p>
-    async fn get_load_balancer_tls_certificates(
+    /// Presigns the request `get_load_balancer_tls_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_load_balancer_tls_certificates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLoadBalancerTlsCertificatesRequest,
-    ) -> Result<
-        GetLoadBalancerTlsCertificatesResult,
-        RusotoError<GetLoadBalancerTlsCertificatesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_load_balancer_tls_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_load_balancer_tls_certificates_request(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancerTlsCertificates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14685,109 +17873,215 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_load_balancers`, also returning the metadata of the response.
+    pub async fn get_load_balancers_with_metadata(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> Result<(GetLoadBalancersResult, ResponseMetadata), RusotoError<GetLoadBalancersError>>
+    {
+        let request = self.build_get_load_balancers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLoadBalancerTlsCertificatesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLoadBalancersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLoadBalancerTlsCertificatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLoadBalancersError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all load balancers in an account.</p> <p>If you are describing a long list of load balancers, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
-    async fn get_load_balancers(
+    /// Presigns the request `get_load_balancers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_load_balancers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLoadBalancersRequest,
-    ) -> Result<GetLoadBalancersResult, RusotoError<GetLoadBalancersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_load_balancers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_load_balancers_request(&self, input: GetLoadBalancersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_operation`, also returning the metadata of the response.
+    pub async fn get_operation_with_metadata(
+        &self,
+        input: GetOperationRequest,
+    ) -> Result<(GetOperationResult, ResponseMetadata), RusotoError<GetOperationError>> {
+        let request = self.build_get_operation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetLoadBalancersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOperationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLoadBalancersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOperationError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific operation. Operations include events such as when you create an instance, allocate a static IP, attach a static IP, and so on.</p>
-    async fn get_operation(
+    /// Presigns the request `get_operation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_operation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOperationRequest,
-    ) -> Result<GetOperationResult, RusotoError<GetOperationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_operation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_operation_request(&self, input: GetOperationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetOperation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetOperation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_operations`, also returning the metadata of the response.
+    pub async fn get_operations_with_metadata(
+        &self,
+        input: GetOperationsRequest,
+    ) -> Result<(GetOperationsResult, ResponseMetadata), RusotoError<GetOperationsError>> {
+        let request = self.build_get_operations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetOperationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOperationsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOperationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOperationsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all operations.</p> <p>Results are returned from oldest to newest, up to a maximum of 200. Results can be paged by making each subsequent call to <code>GetOperations</code> use the maximum (last) <code>statusChangedAt</code> value from the previous request.</p>
-    async fn get_operations(
+    /// Presigns the request `get_operations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_operations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOperationsRequest,
-    ) -> Result<GetOperationsResult, RusotoError<GetOperationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_operations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_operations_request(&self, input: GetOperationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetOperations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_operations_for_resource`, also returning the metadata of the response.
+    pub async fn get_operations_for_resource_with_metadata(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> Result<
+        (GetOperationsForResourceResult, ResponseMetadata),
+        RusotoError<GetOperationsForResourceError>,
+    > {
+        let request = self.build_get_operations_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetOperationsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOperationsForResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOperationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOperationsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Gets operations for a specific resource (e.g., an instance or a static IP).</p>
-    async fn get_operations_for_resource(
+    /// Presigns the request `get_operations_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_operations_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOperationsForResourceRequest,
-    ) -> Result<GetOperationsForResourceResult, RusotoError<GetOperationsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_operations_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_operations_for_resource_request(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetOperationsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14797,186 +18091,353 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_regions`, also returning the metadata of the response.
+    pub async fn get_regions_with_metadata(
+        &self,
+        input: GetRegionsRequest,
+    ) -> Result<(GetRegionsResult, ResponseMetadata), RusotoError<GetRegionsError>> {
+        let request = self.build_get_regions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetOperationsForResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRegionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOperationsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRegionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all valid regions for Amazon Lightsail. Use the <code>include availability zones</code> parameter to also return the Availability Zones in a region.</p>
-    async fn get_regions(
+    /// Presigns the request `get_regions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_regions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRegionsRequest,
-    ) -> Result<GetRegionsResult, RusotoError<GetRegionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_regions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_regions_request(&self, input: GetRegionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRegions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetRegions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_database`, also returning the metadata of the response.
+    pub async fn get_relational_database_with_metadata(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> Result<
+        (GetRelationalDatabaseResult, ResponseMetadata),
+        RusotoError<GetRelationalDatabaseError>,
+    > {
+        let request = self.build_get_relational_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetRegionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRegionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRelationalDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific database in Amazon Lightsail.</p>
-    async fn get_relational_database(
+    /// Presigns the request `get_relational_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRelationalDatabaseRequest,
-    ) -> Result<GetRelationalDatabaseResult, RusotoError<GetRelationalDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_request(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRelationalDatabaseError::from_response(response))
-        }
+        request
     }
 
-    /// <p>Returns a list of available database blueprints in Amazon Lightsail. A blueprint describes the major engine version of a database.</p> <p>You can use a blueprint ID to create a new database that runs a specific database engine.</p>
-    async fn get_relational_database_blueprints(
+    /// Calls `get_relational_database_blueprints`, also returning the metadata of the response.
+    pub async fn get_relational_database_blueprints_with_metadata(
         &self,
         input: GetRelationalDatabaseBlueprintsRequest,
     ) -> Result<
-        GetRelationalDatabaseBlueprintsResult,
+        (GetRelationalDatabaseBlueprintsResult, ResponseMetadata),
         RusotoError<GetRelationalDatabaseBlueprintsError>,
     > {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseBlueprints",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        let request = self.build_get_relational_database_blueprints_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseBlueprintsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseBlueprintsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetRelationalDatabaseBlueprintsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns the list of bundles that are available in Amazon Lightsail. A bundle describes the performance specifications for a database.</p> <p>You can use a bundle ID to create a new database with explicit performance specifications.</p>
-    async fn get_relational_database_bundles(
+    /// Presigns the request `get_relational_database_blueprints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_blueprints(
         &self,
-        input: GetRelationalDatabaseBundlesRequest,
-    ) -> Result<GetRelationalDatabaseBundlesResult, RusotoError<GetRelationalDatabaseBundlesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_blueprints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_blueprints_request(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseBlueprints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseBundles",
+            "Lightsail_20161128.GetRelationalDatabaseBlueprints",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_database_bundles`, also returning the metadata of the response.
+    pub async fn get_relational_database_bundles_with_metadata(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> Result<
+        (GetRelationalDatabaseBundlesResult, ResponseMetadata),
+        RusotoError<GetRelationalDatabaseBundlesError>,
+    > {
+        let request = self.build_get_relational_database_bundles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseBundlesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseBundlesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetRelationalDatabaseBundlesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of events for a specific database in Amazon Lightsail.</p>
-    async fn get_relational_database_events(
+    /// Presigns the request `get_relational_database_bundles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_bundles(
         &self,
-        input: GetRelationalDatabaseEventsRequest,
-    ) -> Result<GetRelationalDatabaseEventsResult, RusotoError<GetRelationalDatabaseEventsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_bundles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_bundles_request(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseBundles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseEvents",
+            "Lightsail_20161128.GetRelationalDatabaseBundles",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_database_events`, also returning the metadata of the response.
+    pub async fn get_relational_database_events_with_metadata(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> Result<
+        (GetRelationalDatabaseEventsResult, ResponseMetadata),
+        RusotoError<GetRelationalDatabaseEventsError>,
+    > {
+        let request = self.build_get_relational_database_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseEventsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseEventsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetRelationalDatabaseEventsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of log events for a database in Amazon Lightsail.</p>
-    async fn get_relational_database_log_events(
+    /// Presigns the request `get_relational_database_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_events(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_events_request(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseEvents",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `get_relational_database_log_events`, also returning the metadata of the response.
+    pub async fn get_relational_database_log_events_with_metadata(
         &self,
         input: GetRelationalDatabaseLogEventsRequest,
     ) -> Result<
-        GetRelationalDatabaseLogEventsResult,
+        (GetRelationalDatabaseLogEventsResult, ResponseMetadata),
         RusotoError<GetRelationalDatabaseLogEventsError>,
     > {
+        let request = self.build_get_relational_database_log_events_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseLogEventsResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRelationalDatabaseLogEventsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `get_relational_database_log_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_log_events(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_log_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_log_events_request(
+        &self,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseLogEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14986,31 +18447,60 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_database_log_streams`, also returning the metadata of the response.
+    pub async fn get_relational_database_log_streams_with_metadata(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> Result<
+        (GetRelationalDatabaseLogStreamsResult, ResponseMetadata),
+        RusotoError<GetRelationalDatabaseLogStreamsError>,
+    > {
+        let request = self.build_get_relational_database_log_streams_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseLogEventsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseLogStreamsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRelationalDatabaseLogEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRelationalDatabaseLogStreamsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns a list of available log streams for a specific database in Amazon Lightsail.</p>
-    async fn get_relational_database_log_streams(
+    /// Presigns the request `get_relational_database_log_streams` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_log_streams(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRelationalDatabaseLogStreamsRequest,
-    ) -> Result<
-        GetRelationalDatabaseLogStreamsResult,
-        RusotoError<GetRelationalDatabaseLogStreamsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_log_streams_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_log_streams_request(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseLogStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15020,33 +18510,63 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_database_master_user_password`, also returning the metadata of the response.
+    pub async fn get_relational_database_master_user_password_with_metadata(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> Result<
+        (
+            GetRelationalDatabaseMasterUserPasswordResult,
+            ResponseMetadata,
+        ),
+        RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
+    > {
+        let request = self.build_get_relational_database_master_user_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseLogStreamsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseMasterUserPasswordResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRelationalDatabaseLogStreamsError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRelationalDatabaseMasterUserPasswordError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns the current, previous, or pending versions of the master user password for a Lightsail database.</p> <p>The <code>GetRelationalDatabaseMasterUserPassword</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName.</p>
-    async fn get_relational_database_master_user_password(
+    /// Presigns the request `get_relational_database_master_user_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_master_user_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRelationalDatabaseMasterUserPasswordRequest,
-    ) -> Result<
-        GetRelationalDatabaseMasterUserPasswordResult,
-        RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_master_user_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_master_user_password_request(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseMasterUserPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15056,33 +18576,60 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_database_metric_data`, also returning the metadata of the response.
+    pub async fn get_relational_database_metric_data_with_metadata(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> Result<
+        (GetRelationalDatabaseMetricDataResult, ResponseMetadata),
+        RusotoError<GetRelationalDatabaseMetricDataError>,
+    > {
+        let request = self.build_get_relational_database_metric_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseMasterUserPasswordResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseMetricDataResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRelationalDatabaseMasterUserPasswordError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRelationalDatabaseMetricDataError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns the data points of the specified metric for a database in Amazon Lightsail.</p>
-    async fn get_relational_database_metric_data(
+    /// Presigns the request `get_relational_database_metric_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_metric_data(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRelationalDatabaseMetricDataRequest,
-    ) -> Result<
-        GetRelationalDatabaseMetricDataResult,
-        RusotoError<GetRelationalDatabaseMetricDataError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_metric_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_metric_data_request(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseMetricData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15092,33 +18639,60 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_database_parameters`, also returning the metadata of the response.
+    pub async fn get_relational_database_parameters_with_metadata(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> Result<
+        (GetRelationalDatabaseParametersResult, ResponseMetadata),
+        RusotoError<GetRelationalDatabaseParametersError>,
+    > {
+        let request = self.build_get_relational_database_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseMetricDataResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseParametersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRelationalDatabaseMetricDataError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRelationalDatabaseParametersError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns all of the runtime parameters offered by the underlying database software, or engine, for a specific database in Amazon Lightsail.</p> <p>In addition to the parameter names and values, this operation returns other information about each parameter. This information includes whether changes require a reboot, whether the parameter is modifiable, the allowed values, and the data types.</p>
-    async fn get_relational_database_parameters(
+    /// Presigns the request `get_relational_database_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRelationalDatabaseParametersRequest,
-    ) -> Result<
-        GetRelationalDatabaseParametersResult,
-        RusotoError<GetRelationalDatabaseParametersError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_parameters_request(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15128,31 +18702,58 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_database_snapshot`, also returning the metadata of the response.
+    pub async fn get_relational_database_snapshot_with_metadata(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> Result<
+        (GetRelationalDatabaseSnapshotResult, ResponseMetadata),
+        RusotoError<GetRelationalDatabaseSnapshotError>,
+    > {
+        let request = self.build_get_relational_database_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseParametersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRelationalDatabaseParametersError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRelationalDatabaseSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific database snapshot in Amazon Lightsail.</p>
-    async fn get_relational_database_snapshot(
+    /// Presigns the request `get_relational_database_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRelationalDatabaseSnapshotRequest,
-    ) -> Result<GetRelationalDatabaseSnapshotResult, RusotoError<GetRelationalDatabaseSnapshotError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_snapshot_request(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15162,31 +18763,58 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_database_snapshots`, also returning the metadata of the response.
+    pub async fn get_relational_database_snapshots_with_metadata(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> Result<
+        (GetRelationalDatabaseSnapshotsResult, ResponseMetadata),
+        RusotoError<GetRelationalDatabaseSnapshotsError>,
+    > {
+        let request = self.build_get_relational_database_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabaseSnapshotsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRelationalDatabaseSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRelationalDatabaseSnapshotsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all of your database snapshots in Amazon Lightsail.</p>
-    async fn get_relational_database_snapshots(
+    /// Presigns the request `get_relational_database_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_database_snapshots(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRelationalDatabaseSnapshotsRequest,
-    ) -> Result<
-        GetRelationalDatabaseSnapshotsResult,
-        RusotoError<GetRelationalDatabaseSnapshotsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_database_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_database_snapshots_request(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15196,266 +18824,538 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_relational_databases`, also returning the metadata of the response.
+    pub async fn get_relational_databases_with_metadata(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> Result<
+        (GetRelationalDatabasesResult, ResponseMetadata),
+        RusotoError<GetRelationalDatabasesError>,
+    > {
+        let request = self.build_get_relational_databases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabaseSnapshotsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRelationalDatabasesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRelationalDatabaseSnapshotsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRelationalDatabasesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all of your databases in Amazon Lightsail.</p>
-    async fn get_relational_databases(
+    /// Presigns the request `get_relational_databases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_relational_databases(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRelationalDatabasesRequest,
-    ) -> Result<GetRelationalDatabasesResult, RusotoError<GetRelationalDatabasesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_relational_databases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_relational_databases_request(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_static_ip`, also returning the metadata of the response.
+    pub async fn get_static_ip_with_metadata(
+        &self,
+        input: GetStaticIpRequest,
+    ) -> Result<(GetStaticIpResult, ResponseMetadata), RusotoError<GetStaticIpError>> {
+        let request = self.build_get_static_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRelationalDatabasesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetStaticIpResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRelationalDatabasesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetStaticIpError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific static IP.</p>
-    async fn get_static_ip(
+    /// Presigns the request `get_static_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_static_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetStaticIpRequest,
-    ) -> Result<GetStaticIpResult, RusotoError<GetStaticIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_static_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_static_ip_request(&self, input: GetStaticIpRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_static_ips`, also returning the metadata of the response.
+    pub async fn get_static_ips_with_metadata(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> Result<(GetStaticIpsResult, ResponseMetadata), RusotoError<GetStaticIpsError>> {
+        let request = self.build_get_static_ips_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetStaticIpResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetStaticIpsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetStaticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetStaticIpsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all static IPs in the user's account.</p>
-    async fn get_static_ips(
+    /// Presigns the request `get_static_ips` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_static_ips(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetStaticIpsRequest,
-    ) -> Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_static_ips_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_static_ips_request(&self, input: GetStaticIpsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetStaticIps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_key_pair`, also returning the metadata of the response.
+    pub async fn import_key_pair_with_metadata(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> Result<(ImportKeyPairResult, ResponseMetadata), RusotoError<ImportKeyPairError>> {
+        let request = self.build_import_key_pair_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetStaticIpsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportKeyPairResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetStaticIpsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportKeyPairError::from_response(response))
         }
     }
 
-    /// <p>Imports a public SSH key from a specific key pair.</p>
-    async fn import_key_pair(
+    /// Presigns the request `import_key_pair` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_key_pair(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportKeyPairRequest,
-    ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_key_pair_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_key_pair_request(&self, input: ImportKeyPairRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("ImportKeyPair");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.ImportKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `is_vpc_peered`, also returning the metadata of the response.
+    pub async fn is_vpc_peered_with_metadata(
+        &self,
+    ) -> Result<(IsVpcPeeredResult, ResponseMetadata), RusotoError<IsVpcPeeredError>> {
+        let request = self.build_is_vpc_peered_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ImportKeyPairResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<IsVpcPeeredResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportKeyPairError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(IsVpcPeeredError::from_response(response))
         }
     }
 
-    /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
-    async fn is_vpc_peered(&self) -> Result<IsVpcPeeredResult, RusotoError<IsVpcPeeredError>> {
+    /// Presigns the request `is_vpc_peered` would send with `presigner` instead of sending it.
+    pub async fn presign_is_vpc_peered(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_is_vpc_peered_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_is_vpc_peered_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("IsVpcPeered");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.IsVpcPeered");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `open_instance_public_ports`, also returning the metadata of the response.
+    pub async fn open_instance_public_ports_with_metadata(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> Result<
+        (OpenInstancePublicPortsResult, ResponseMetadata),
+        RusotoError<OpenInstancePublicPortsError>,
+    > {
+        let request = self.build_open_instance_public_ports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<IsVpcPeeredResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<OpenInstancePublicPortsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(IsVpcPeeredError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(OpenInstancePublicPortsError::from_response(response))
         }
     }
 
-    /// <p>Adds public ports to an Amazon Lightsail instance.</p> <p>The <code>open instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn open_instance_public_ports(
+    /// Presigns the request `open_instance_public_ports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_open_instance_public_ports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: OpenInstancePublicPortsRequest,
-    ) -> Result<OpenInstancePublicPortsResult, RusotoError<OpenInstancePublicPortsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_open_instance_public_ports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_open_instance_public_ports_request(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("OpenInstancePublicPorts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.OpenInstancePublicPorts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `peer_vpc`, also returning the metadata of the response.
+    pub async fn peer_vpc_with_metadata(
+        &self,
+    ) -> Result<(PeerVpcResult, ResponseMetadata), RusotoError<PeerVpcError>> {
+        let request = self.build_peer_vpc_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<OpenInstancePublicPortsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<PeerVpcResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(OpenInstancePublicPortsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PeerVpcError::from_response(response))
         }
     }
 
-    /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
-    async fn peer_vpc(&self) -> Result<PeerVpcResult, RusotoError<PeerVpcError>> {
+    /// Presigns the request `peer_vpc` would send with `presigner` instead of sending it.
+    pub async fn presign_peer_vpc(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_peer_vpc_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_peer_vpc_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("PeerVpc");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.PeerVpc");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `put_instance_public_ports`, also returning the metadata of the response.
+    pub async fn put_instance_public_ports_with_metadata(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> Result<
+        (PutInstancePublicPortsResult, ResponseMetadata),
+        RusotoError<PutInstancePublicPortsError>,
+    > {
+        let request = self.build_put_instance_public_ports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PeerVpcResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutInstancePublicPortsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PeerVpcError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutInstancePublicPortsError::from_response(response))
         }
     }
 
-    /// <p>Sets the specified open ports for an Amazon Lightsail instance, and closes all ports for every protocol not included in the current request.</p> <p>The <code>put instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn put_instance_public_ports(
+    /// Presigns the request `put_instance_public_ports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_instance_public_ports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutInstancePublicPortsRequest,
-    ) -> Result<PutInstancePublicPortsResult, RusotoError<PutInstancePublicPortsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_instance_public_ports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_instance_public_ports_request(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("PutInstancePublicPorts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.PutInstancePublicPorts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reboot_instance`, also returning the metadata of the response.
+    pub async fn reboot_instance_with_metadata(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> Result<(RebootInstanceResult, ResponseMetadata), RusotoError<RebootInstanceError>> {
+        let request = self.build_reboot_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutInstancePublicPortsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RebootInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutInstancePublicPortsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RebootInstanceError::from_response(response))
         }
     }
 
-    /// <p>Restarts a specific instance.</p> <p>The <code>reboot instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn reboot_instance(
+    /// Presigns the request `reboot_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RebootInstanceRequest,
-    ) -> Result<RebootInstanceResult, RusotoError<RebootInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_instance_request(&self, input: RebootInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("RebootInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.RebootInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reboot_relational_database`, also returning the metadata of the response.
+    pub async fn reboot_relational_database_with_metadata(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> Result<
+        (RebootRelationalDatabaseResult, ResponseMetadata),
+        RusotoError<RebootRelationalDatabaseError>,
+    > {
+        let request = self.build_reboot_relational_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RebootInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RebootRelationalDatabaseResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RebootInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RebootRelationalDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Restarts a specific database in Amazon Lightsail.</p> <p>The <code>reboot relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn reboot_relational_database(
+    /// Presigns the request `reboot_relational_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_relational_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RebootRelationalDatabaseRequest,
-    ) -> Result<RebootRelationalDatabaseResult, RusotoError<RebootRelationalDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_relational_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_relational_database_request(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("RebootRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15465,270 +19365,536 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `release_static_ip`, also returning the metadata of the response.
+    pub async fn release_static_ip_with_metadata(
+        &self,
+        input: ReleaseStaticIpRequest,
+    ) -> Result<(ReleaseStaticIpResult, ResponseMetadata), RusotoError<ReleaseStaticIpError>> {
+        let request = self.build_release_static_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RebootRelationalDatabaseResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ReleaseStaticIpResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RebootRelationalDatabaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ReleaseStaticIpError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specific static IP from your account.</p>
-    async fn release_static_ip(
+    /// Presigns the request `release_static_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_release_static_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReleaseStaticIpRequest,
-    ) -> Result<ReleaseStaticIpResult, RusotoError<ReleaseStaticIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_release_static_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_release_static_ip_request(&self, input: ReleaseStaticIpRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("ReleaseStaticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.ReleaseStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_instance`, also returning the metadata of the response.
+    pub async fn start_instance_with_metadata(
+        &self,
+        input: StartInstanceRequest,
+    ) -> Result<(StartInstanceResult, ResponseMetadata), RusotoError<StartInstanceError>> {
+        let request = self.build_start_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ReleaseStaticIpResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ReleaseStaticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartInstanceError::from_response(response))
         }
     }
 
-    /// <p>Starts a specific Amazon Lightsail instance from a stopped state. To restart an instance, use the <code>reboot instance</code> operation.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>start instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn start_instance(
+    /// Presigns the request `start_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartInstanceRequest,
-    ) -> Result<StartInstanceResult, RusotoError<StartInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_instance_request(&self, input: StartInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StartInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.StartInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_relational_database`, also returning the metadata of the response.
+    pub async fn start_relational_database_with_metadata(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> Result<
+        (StartRelationalDatabaseResult, ResponseMetadata),
+        RusotoError<StartRelationalDatabaseError>,
+    > {
+        let request = self.build_start_relational_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartRelationalDatabaseResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartRelationalDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Starts a specific database from a stopped state in Amazon Lightsail. To restart a database, use the <code>reboot relational database</code> operation.</p> <p>The <code>start relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn start_relational_database(
+    /// Presigns the request `start_relational_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_relational_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartRelationalDatabaseRequest,
-    ) -> Result<StartRelationalDatabaseResult, RusotoError<StartRelationalDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_relational_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_relational_database_request(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StartRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.StartRelationalDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_instance`, also returning the metadata of the response.
+    pub async fn stop_instance_with_metadata(
+        &self,
+        input: StopInstanceRequest,
+    ) -> Result<(StopInstanceResult, ResponseMetadata), RusotoError<StopInstanceError>> {
+        let request = self.build_stop_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartRelationalDatabaseResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartRelationalDatabaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopInstanceError::from_response(response))
         }
     }
 
-    /// <p>Stops a specific Amazon Lightsail instance that is currently running.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>stop instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn stop_instance(
+    /// Presigns the request `stop_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopInstanceRequest,
-    ) -> Result<StopInstanceResult, RusotoError<StopInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_instance_request(&self, input: StopInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StopInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.StopInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_relational_database`, also returning the metadata of the response.
+    pub async fn stop_relational_database_with_metadata(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> Result<
+        (StopRelationalDatabaseResult, ResponseMetadata),
+        RusotoError<StopRelationalDatabaseError>,
+    > {
+        let request = self.build_stop_relational_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopRelationalDatabaseResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopRelationalDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Stops a specific database that is currently running in Amazon Lightsail.</p> <p>The <code>stop relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn stop_relational_database(
+    /// Presigns the request `stop_relational_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_relational_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopRelationalDatabaseRequest,
-    ) -> Result<StopRelationalDatabaseResult, RusotoError<StopRelationalDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_relational_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_relational_database_request(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StopRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.StopRelationalDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResult, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopRelationalDatabaseResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopRelationalDatabaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to the specified Amazon Lightsail resource. Each resource can have a maximum of 50 tags. Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-tags">Lightsail Dev Guide</a>.</p> <p>The <code>tag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>resource name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `unpeer_vpc`, also returning the metadata of the response.
+    pub async fn unpeer_vpc_with_metadata(
+        &self,
+    ) -> Result<(UnpeerVpcResult, ResponseMetadata), RusotoError<UnpeerVpcError>> {
+        let request = self.build_unpeer_vpc_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<UnpeerVpcResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UnpeerVpcError::from_response(response))
         }
     }
 
-    /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
-    async fn unpeer_vpc(&self) -> Result<UnpeerVpcResult, RusotoError<UnpeerVpcError>> {
+    /// Presigns the request `unpeer_vpc` would send with `presigner` instead of sending it.
+    pub async fn presign_unpeer_vpc(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unpeer_vpc_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unpeer_vpc_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UnpeerVpc");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.UnpeerVpc");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResult, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UnpeerVpcResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UnpeerVpcError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified set of tag keys and their values from the specified Amazon Lightsail resource.</p> <p>The <code>untag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>resource name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_domain_entry`, also returning the metadata of the response.
+    pub async fn update_domain_entry_with_metadata(
+        &self,
+        input: UpdateDomainEntryRequest,
+    ) -> Result<(UpdateDomainEntryResult, ResponseMetadata), RusotoError<UpdateDomainEntryError>>
+    {
+        let request = self.build_update_domain_entry_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDomainEntryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDomainEntryError::from_response(response))
         }
     }
 
-    /// <p>Updates a domain recordset after it is created.</p> <p>The <code>update domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn update_domain_entry(
+    /// Presigns the request `update_domain_entry` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_domain_entry(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDomainEntryRequest,
-    ) -> Result<UpdateDomainEntryResult, RusotoError<UpdateDomainEntryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_domain_entry_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_domain_entry_request(&self, input: UpdateDomainEntryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateDomainEntry");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Lightsail_20161128.UpdateDomainEntry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_load_balancer_attribute`, also returning the metadata of the response.
+    pub async fn update_load_balancer_attribute_with_metadata(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> Result<
+        (UpdateLoadBalancerAttributeResult, ResponseMetadata),
+        RusotoError<UpdateLoadBalancerAttributeError>,
+    > {
+        let request = self.build_update_load_balancer_attribute_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateDomainEntryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateLoadBalancerAttributeResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDomainEntryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateLoadBalancerAttributeError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified attribute for a load balancer. You can only update one attribute at a time.</p> <p>The <code>update load balancer attribute</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn update_load_balancer_attribute(
+    /// Presigns the request `update_load_balancer_attribute` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_load_balancer_attribute(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLoadBalancerAttributeRequest,
-    ) -> Result<UpdateLoadBalancerAttributeResult, RusotoError<UpdateLoadBalancerAttributeError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_load_balancer_attribute_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_load_balancer_attribute_request(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateLoadBalancerAttribute");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15738,28 +19904,58 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_relational_database`, also returning the metadata of the response.
+    pub async fn update_relational_database_with_metadata(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> Result<
+        (UpdateRelationalDatabaseResult, ResponseMetadata),
+        RusotoError<UpdateRelationalDatabaseError>,
+    > {
+        let request = self.build_update_relational_database_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateLoadBalancerAttributeResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRelationalDatabaseResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLoadBalancerAttributeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRelationalDatabaseError::from_response(response))
         }
     }
 
-    /// <p>Allows the update of one or more attributes of a database in Amazon Lightsail.</p> <p>Updates are applied immediately, or in cases where the updates could result in an outage, are applied during the database's predefined maintenance window.</p> <p>The <code>update relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn update_relational_database(
+    /// Presigns the request `update_relational_database` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_relational_database(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRelationalDatabaseRequest,
-    ) -> Result<UpdateRelationalDatabaseResult, RusotoError<UpdateRelationalDatabaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_relational_database_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_relational_database_request(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateRelationalDatabase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15769,31 +19965,60 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_relational_database_parameters`, also returning the metadata of the response.
+    pub async fn update_relational_database_parameters_with_metadata(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> Result<
+        (UpdateRelationalDatabaseParametersResult, ResponseMetadata),
+        RusotoError<UpdateRelationalDatabaseParametersError>,
+    > {
+        let request = self.build_update_relational_database_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRelationalDatabaseResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRelationalDatabaseParametersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRelationalDatabaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRelationalDatabaseParametersError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Allows the update of one or more parameters of a database in Amazon Lightsail.</p> <p>Parameter updates don't cause outages; therefore, their application is not subject to the preferred maintenance window. However, there are two ways in which parameter updates are applied: <code>dynamic</code> or <code>pending-reboot</code>. Parameters marked with a <code>dynamic</code> apply type are applied immediately. Parameters marked with a <code>pending-reboot</code> apply type are applied only after the database is rebooted using the <code>reboot relational database</code> operation.</p> <p>The <code>update relational database parameters</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
-    async fn update_relational_database_parameters(
+    /// Presigns the request `update_relational_database_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_relational_database_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRelationalDatabaseParametersRequest,
-    ) -> Result<
-        UpdateRelationalDatabaseParametersResult,
-        RusotoError<UpdateRelationalDatabaseParametersError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_relational_database_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_relational_database_parameters_request(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateRelationalDatabaseParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15803,21 +20028,1864 @@ impl Lightsail for LightsailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRelationalDatabaseParametersResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRelationalDatabaseParametersError::from_response(
-                response,
-            ))
-        }
+        request
+    }
+
+    /// The pages of `GetActiveNames` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_active_names_pages(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> impl futures::Stream<Item = Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_active_names(input).await },
+            |input: &GetActiveNamesRequest, output: &GetActiveNamesResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetActiveNamesRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `activeNames` of every page of `GetActiveNames` results for `input`, as `get_active_names_pages` requests them.
+    pub fn get_active_names_stream(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<GetActiveNamesError>>> + Send {
+        rusoto_core::pagination::items(self.get_active_names_pages(input), |page| {
+            page.active_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetBlueprints` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_blueprints_pages(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> impl futures::Stream<Item = Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_blueprints(input).await },
+            |input: &GetBlueprintsRequest, output: &GetBlueprintsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetBlueprintsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `blueprints` of every page of `GetBlueprints` results for `input`, as `get_blueprints_pages` requests them.
+    pub fn get_blueprints_stream(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> impl futures::Stream<Item = Result<Blueprint, RusotoError<GetBlueprintsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_blueprints_pages(input), |page| {
+            page.blueprints.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetBundles` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_bundles_pages(
+        &self,
+        input: GetBundlesRequest,
+    ) -> impl futures::Stream<Item = Result<GetBundlesResult, RusotoError<GetBundlesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_bundles(input).await },
+            |input: &GetBundlesRequest, output: &GetBundlesResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetBundlesRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `bundles` of every page of `GetBundles` results for `input`, as `get_bundles_pages` requests them.
+    pub fn get_bundles_stream(
+        &self,
+        input: GetBundlesRequest,
+    ) -> impl futures::Stream<Item = Result<Bundle, RusotoError<GetBundlesError>>> + Send {
+        rusoto_core::pagination::items(self.get_bundles_pages(input), |page| {
+            page.bundles.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetCloudFormationStackRecords` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_cloud_formation_stack_records_pages(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetCloudFormationStackRecordsResult,
+            RusotoError<GetCloudFormationStackRecordsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move {
+                client.get_cloud_formation_stack_records(input).await
+            },
+            |input: &GetCloudFormationStackRecordsRequest,
+             output: &GetCloudFormationStackRecordsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetCloudFormationStackRecordsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `cloudFormationStackRecords` of every page of `GetCloudFormationStackRecords` results for `input`, as `get_cloud_formation_stack_records_pages` requests them.
+    pub fn get_cloud_formation_stack_records_stream(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<CloudFormationStackRecord, RusotoError<GetCloudFormationStackRecordsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_cloud_formation_stack_records_pages(input),
+            |page| page.cloud_formation_stack_records.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetDiskSnapshots` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_disk_snapshots_pages(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetDiskSnapshotsResult, RusotoError<GetDiskSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_disk_snapshots(input).await },
+            |input: &GetDiskSnapshotsRequest, output: &GetDiskSnapshotsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetDiskSnapshotsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `diskSnapshots` of every page of `GetDiskSnapshots` results for `input`, as `get_disk_snapshots_pages` requests them.
+    pub fn get_disk_snapshots_stream(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> impl futures::Stream<Item = Result<DiskSnapshot, RusotoError<GetDiskSnapshotsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_disk_snapshots_pages(input), |page| {
+            page.disk_snapshots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDisks` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_disks_pages(
+        &self,
+        input: GetDisksRequest,
+    ) -> impl futures::Stream<Item = Result<GetDisksResult, RusotoError<GetDisksError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_disks(input).await },
+            |input: &GetDisksRequest, output: &GetDisksResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetDisksRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `disks` of every page of `GetDisks` results for `input`, as `get_disks_pages` requests them.
+    pub fn get_disks_stream(
+        &self,
+        input: GetDisksRequest,
+    ) -> impl futures::Stream<Item = Result<Disk, RusotoError<GetDisksError>>> + Send {
+        rusoto_core::pagination::items(self.get_disks_pages(input), |page| {
+            page.disks.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDomains` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_domains_pages(
+        &self,
+        input: GetDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<GetDomainsResult, RusotoError<GetDomainsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_domains(input).await },
+            |input: &GetDomainsRequest, output: &GetDomainsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetDomainsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `domains` of every page of `GetDomains` results for `input`, as `get_domains_pages` requests them.
+    pub fn get_domains_stream(
+        &self,
+        input: GetDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<Domain, RusotoError<GetDomainsError>>> + Send {
+        rusoto_core::pagination::items(self.get_domains_pages(input), |page| {
+            page.domains.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetExportSnapshotRecords` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_export_snapshot_records_pages(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetExportSnapshotRecordsResult, RusotoError<GetExportSnapshotRecordsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move {
+                client.get_export_snapshot_records(input).await
+            },
+            |input: &GetExportSnapshotRecordsRequest, output: &GetExportSnapshotRecordsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetExportSnapshotRecordsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `exportSnapshotRecords` of every page of `GetExportSnapshotRecords` results for `input`, as `get_export_snapshot_records_pages` requests them.
+    pub fn get_export_snapshot_records_stream(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ExportSnapshotRecord, RusotoError<GetExportSnapshotRecordsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_export_snapshot_records_pages(input), |page| {
+            page.export_snapshot_records.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetInstanceSnapshots` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_instance_snapshots_pages(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetInstanceSnapshotsResult, RusotoError<GetInstanceSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_instance_snapshots(input).await },
+            |input: &GetInstanceSnapshotsRequest, output: &GetInstanceSnapshotsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetInstanceSnapshotsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `instanceSnapshots` of every page of `GetInstanceSnapshots` results for `input`, as `get_instance_snapshots_pages` requests them.
+    pub fn get_instance_snapshots_stream(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> impl futures::Stream<Item = Result<InstanceSnapshot, RusotoError<GetInstanceSnapshotsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.get_instance_snapshots_pages(input), |page| {
+            page.instance_snapshots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetInstances` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_instances_pages(
+        &self,
+        input: GetInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<GetInstancesResult, RusotoError<GetInstancesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_instances(input).await },
+            |input: &GetInstancesRequest, output: &GetInstancesResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetInstancesRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `instances` of every page of `GetInstances` results for `input`, as `get_instances_pages` requests them.
+    pub fn get_instances_stream(
+        &self,
+        input: GetInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<Instance, RusotoError<GetInstancesError>>> + Send {
+        rusoto_core::pagination::items(self.get_instances_pages(input), |page| {
+            page.instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetKeyPairs` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_key_pairs_pages(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> impl futures::Stream<Item = Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_key_pairs(input).await },
+            |input: &GetKeyPairsRequest, output: &GetKeyPairsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetKeyPairsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `keyPairs` of every page of `GetKeyPairs` results for `input`, as `get_key_pairs_pages` requests them.
+    pub fn get_key_pairs_stream(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> impl futures::Stream<Item = Result<KeyPair, RusotoError<GetKeyPairsError>>> + Send {
+        rusoto_core::pagination::items(self.get_key_pairs_pages(input), |page| {
+            page.key_pairs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetLoadBalancers` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_load_balancers_pages(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetLoadBalancersResult, RusotoError<GetLoadBalancersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_load_balancers(input).await },
+            |input: &GetLoadBalancersRequest, output: &GetLoadBalancersResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetLoadBalancersRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `loadBalancers` of every page of `GetLoadBalancers` results for `input`, as `get_load_balancers_pages` requests them.
+    pub fn get_load_balancers_stream(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> impl futures::Stream<Item = Result<LoadBalancer, RusotoError<GetLoadBalancersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_load_balancers_pages(input), |page| {
+            page.load_balancers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetOperations` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_operations_pages(
+        &self,
+        input: GetOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<GetOperationsResult, RusotoError<GetOperationsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_operations(input).await },
+            |input: &GetOperationsRequest, output: &GetOperationsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetOperationsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `operations` of every page of `GetOperations` results for `input`, as `get_operations_pages` requests them.
+    pub fn get_operations_stream(
+        &self,
+        input: GetOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<Operation, RusotoError<GetOperationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_operations_pages(input), |page| {
+            page.operations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetRelationalDatabaseBlueprints` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_relational_database_blueprints_pages(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetRelationalDatabaseBlueprintsResult,
+            RusotoError<GetRelationalDatabaseBlueprintsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move {
+                client.get_relational_database_blueprints(input).await
+            },
+            |input: &GetRelationalDatabaseBlueprintsRequest,
+             output: &GetRelationalDatabaseBlueprintsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetRelationalDatabaseBlueprintsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `blueprints` of every page of `GetRelationalDatabaseBlueprints` results for `input`, as `get_relational_database_blueprints_pages` requests them.
+    pub fn get_relational_database_blueprints_stream(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            RelationalDatabaseBlueprint,
+            RusotoError<GetRelationalDatabaseBlueprintsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_relational_database_blueprints_pages(input),
+            |page| page.blueprints.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetRelationalDatabaseBundles` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_relational_database_bundles_pages(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetRelationalDatabaseBundlesResult,
+            RusotoError<GetRelationalDatabaseBundlesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move {
+                client.get_relational_database_bundles(input).await
+            },
+            |input: &GetRelationalDatabaseBundlesRequest,
+             output: &GetRelationalDatabaseBundlesResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetRelationalDatabaseBundlesRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `bundles` of every page of `GetRelationalDatabaseBundles` results for `input`, as `get_relational_database_bundles_pages` requests them.
+    pub fn get_relational_database_bundles_stream(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RelationalDatabaseBundle, RusotoError<GetRelationalDatabaseBundlesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_relational_database_bundles_pages(input), |page| {
+            page.bundles.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetRelationalDatabaseEvents` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_relational_database_events_pages(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetRelationalDatabaseEventsResult,
+            RusotoError<GetRelationalDatabaseEventsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move {
+                client.get_relational_database_events(input).await
+            },
+            |input: &GetRelationalDatabaseEventsRequest,
+             output: &GetRelationalDatabaseEventsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetRelationalDatabaseEventsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `relationalDatabaseEvents` of every page of `GetRelationalDatabaseEvents` results for `input`, as `get_relational_database_events_pages` requests them.
+    pub fn get_relational_database_events_stream(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RelationalDatabaseEvent, RusotoError<GetRelationalDatabaseEventsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_relational_database_events_pages(input), |page| {
+            page.relational_database_events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetRelationalDatabaseParameters` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_relational_database_parameters_pages(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetRelationalDatabaseParametersResult,
+            RusotoError<GetRelationalDatabaseParametersError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move {
+                client.get_relational_database_parameters(input).await
+            },
+            |input: &GetRelationalDatabaseParametersRequest,
+             output: &GetRelationalDatabaseParametersResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetRelationalDatabaseParametersRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `parameters` of every page of `GetRelationalDatabaseParameters` results for `input`, as `get_relational_database_parameters_pages` requests them.
+    pub fn get_relational_database_parameters_stream(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            RelationalDatabaseParameter,
+            RusotoError<GetRelationalDatabaseParametersError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_relational_database_parameters_pages(input),
+            |page| page.parameters.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetRelationalDatabaseSnapshots` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_relational_database_snapshots_pages(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetRelationalDatabaseSnapshotsResult,
+            RusotoError<GetRelationalDatabaseSnapshotsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move {
+                client.get_relational_database_snapshots(input).await
+            },
+            |input: &GetRelationalDatabaseSnapshotsRequest,
+             output: &GetRelationalDatabaseSnapshotsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetRelationalDatabaseSnapshotsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `relationalDatabaseSnapshots` of every page of `GetRelationalDatabaseSnapshots` results for `input`, as `get_relational_database_snapshots_pages` requests them.
+    pub fn get_relational_database_snapshots_stream(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RelationalDatabaseSnapshot, RusotoError<GetRelationalDatabaseSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_relational_database_snapshots_pages(input),
+            |page| page.relational_database_snapshots.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetRelationalDatabases` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_relational_databases_pages(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetRelationalDatabasesResult, RusotoError<GetRelationalDatabasesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_relational_databases(input).await },
+            |input: &GetRelationalDatabasesRequest, output: &GetRelationalDatabasesResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetRelationalDatabasesRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `relationalDatabases` of every page of `GetRelationalDatabases` results for `input`, as `get_relational_databases_pages` requests them.
+    pub fn get_relational_databases_stream(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RelationalDatabase, RusotoError<GetRelationalDatabasesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_relational_databases_pages(input), |page| {
+            page.relational_databases.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetStaticIps` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `pageToken`.
+    pub fn get_static_ips_pages(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> impl futures::Stream<Item = Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: LightsailClient, input| async move { client.get_static_ips(input).await },
+            |input: &GetStaticIpsRequest, output: &GetStaticIpsResult| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(GetStaticIpsRequest {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `staticIps` of every page of `GetStaticIps` results for `input`, as `get_static_ips_pages` requests them.
+    pub fn get_static_ips_stream(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> impl futures::Stream<Item = Result<StaticIp, RusotoError<GetStaticIpsError>>> + Send {
+        rusoto_core::pagination::items(self.get_static_ips_pages(input), |page| {
+            page.static_ips.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Lightsail for LightsailClient {
+    /// <p>Allocates a static IP address.</p>
+    async fn allocate_static_ip(
+        &self,
+        input: AllocateStaticIpRequest,
+    ) -> Result<AllocateStaticIpResult, RusotoError<AllocateStaticIpError>> {
+        self.allocate_static_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches a block storage disk to a running or stopped Lightsail instance and exposes it to the instance with the specified disk name.</p> <p>The <code>attach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn attach_disk(
+        &self,
+        input: AttachDiskRequest,
+    ) -> Result<AttachDiskResult, RusotoError<AttachDiskError>> {
+        self.attach_disk_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches one or more Lightsail instances to a load balancer.</p> <p>After some time, the instances are attached to the load balancer and the health check status is available.</p> <p>The <code>attach instances to load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn attach_instances_to_load_balancer(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> Result<AttachInstancesToLoadBalancerResult, RusotoError<AttachInstancesToLoadBalancerError>>
+    {
+        self.attach_instances_to_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches a Transport Layer Security (TLS) certificate to your load balancer. TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>Once you create and validate your certificate, you can attach it to your load balancer. You can also use this API to rotate the certificates on your account. Use the <code>attach load balancer tls certificate</code> operation with the non-attached certificate, and it will replace the existing one and become the attached certificate.</p> <p>The <code>attach load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn attach_load_balancer_tls_certificate(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        AttachLoadBalancerTlsCertificateResult,
+        RusotoError<AttachLoadBalancerTlsCertificateError>,
+    > {
+        self.attach_load_balancer_tls_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches a static IP address to a specific Amazon Lightsail instance.</p>
+    async fn attach_static_ip(
+        &self,
+        input: AttachStaticIpRequest,
+    ) -> Result<AttachStaticIpResult, RusotoError<AttachStaticIpError>> {
+        self.attach_static_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Closes the public ports on a specific Amazon Lightsail instance.</p> <p>The <code>close instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn close_instance_public_ports(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> Result<CloseInstancePublicPortsResult, RusotoError<CloseInstancePublicPortsError>> {
+        self.close_instance_public_ports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Copies a manual snapshot of an instance or disk as another manual snapshot, or copies an automatic snapshot of an instance or disk as a manual snapshot. This operation can also be used to copy a manual or automatic snapshot of an instance or a disk from one AWS Region to another in Amazon Lightsail.</p> <p>When copying a <i>manual snapshot</i>, be sure to define the <code>source region</code>, <code>source snapshot name</code>, and <code>target snapshot name</code> parameters.</p> <p>When copying an <i>automatic snapshot</i>, be sure to define the <code>source region</code>, <code>source resource name</code>, <code>target snapshot name</code>, and either the <code>restore date</code> or the <code>use latest restorable auto snapshot</code> parameters.</p>
+    async fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+        self.copy_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an AWS CloudFormation stack, which creates a new Amazon EC2 instance from an exported Amazon Lightsail snapshot. This operation results in a CloudFormation stack record that can be used to track the AWS CloudFormation stack created. Use the <code>get cloud formation stack records</code> operation to get a list of the CloudFormation stacks created.</p> <important> <p>Wait until after your new Amazon EC2 instance is created before running the <code>create cloud formation stack</code> operation again with the same export snapshot record.</p> </important></p>
+    async fn create_cloud_formation_stack(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> Result<CreateCloudFormationStackResult, RusotoError<CreateCloudFormationStackError>> {
+        self.create_cloud_formation_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a block storage disk that can be attached to an Amazon Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>).</p> <p>The <code>create disk</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_disk(
+        &self,
+        input: CreateDiskRequest,
+    ) -> Result<CreateDiskResult, RusotoError<CreateDiskError>> {
+        self.create_disk_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a block storage disk from a manual or automatic snapshot of a disk. The resulting disk can be attached to an Amazon Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>).</p> <p>The <code>create disk from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>disk snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_disk_from_snapshot(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> Result<CreateDiskFromSnapshotResult, RusotoError<CreateDiskFromSnapshotError>> {
+        self.create_disk_from_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a snapshot of a block storage disk. You can use snapshots for backups, to make copies of disks, and to save data before shutting down a Lightsail instance.</p> <p>You can take a snapshot of an attached disk that is in use; however, snapshots only capture data that has been written to your disk at the time the snapshot command is issued. This may exclude any data that has been cached by any applications or the operating system. If you can pause any file systems on the disk long enough to take a snapshot, your snapshot should be complete. Nevertheless, if you cannot pause all file writes to the disk, you should unmount the disk from within the Lightsail instance, issue the create disk snapshot command, and then remount the disk to ensure a consistent and complete snapshot. You may remount and use your disk while the snapshot status is pending.</p> <p>You can also use this operation to create a snapshot of an instance's system volume. You might want to do this, for example, to recover data from the system volume of a botched instance or to create a backup of the system volume like you would for a block storage disk. To create a snapshot of a system volume, just define the <code>instance name</code> parameter when issuing the snapshot command, and a snapshot of the defined instance's system volume will be created. After the snapshot is available, you can create a block storage disk from the snapshot and attach it to a running instance to access the data on the disk.</p> <p>The <code>create disk snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_disk_snapshot(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> Result<CreateDiskSnapshotResult, RusotoError<CreateDiskSnapshotError>> {
+        self.create_disk_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a domain resource for the specified domain (e.g., example.com).</p> <p>The <code>create domain</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<CreateDomainResult, RusotoError<CreateDomainError>> {
+        self.create_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates one of the following entry records associated with the domain: Address (A), canonical name (CNAME), mail exchanger (MX), name server (NS), start of authority (SOA), service locator (SRV), or text (TXT).</p> <p>The <code>create domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_domain_entry(
+        &self,
+        input: CreateDomainEntryRequest,
+    ) -> Result<CreateDomainEntryResult, RusotoError<CreateDomainEntryError>> {
+        self.create_domain_entry_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a snapshot of a specific virtual private server, or <i>instance</i>. You can use a snapshot to create a new instance that is based on that snapshot.</p> <p>The <code>create instance snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_instance_snapshot(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> Result<CreateInstanceSnapshotResult, RusotoError<CreateInstanceSnapshotError>> {
+        self.create_instance_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates one or more Amazon Lightsail instances.</p> <p>The <code>create instances</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_instances(
+        &self,
+        input: CreateInstancesRequest,
+    ) -> Result<CreateInstancesResult, RusotoError<CreateInstancesError>> {
+        self.create_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates one or more new instances from a manual or automatic snapshot of an instance.</p> <p>The <code>create instances from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>instance snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_instances_from_snapshot(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> Result<CreateInstancesFromSnapshotResult, RusotoError<CreateInstancesFromSnapshotError>>
+    {
+        self.create_instances_from_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an SSH key pair.</p> <p>The <code>create key pair</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> Result<CreateKeyPairResult, RusotoError<CreateKeyPairError>> {
+        self.create_key_pair_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Lightsail load balancer. To learn more about deciding whether to load balance your application, see <a href="https://lightsail.aws.amazon.com/ls/docs/how-to/article/configure-lightsail-instances-for-load-balancing">Configure your Lightsail instances for load balancing</a>. You can create up to 5 load balancers per AWS Region in your account.</p> <p>When you create a load balancer, you can specify a unique name and port settings. To change additional load balancer settings, use the <code>UpdateLoadBalancerAttribute</code> operation.</p> <p>The <code>create load balancer</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> Result<CreateLoadBalancerResult, RusotoError<CreateLoadBalancerError>> {
+        self.create_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Lightsail load balancer TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>The <code>create load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_load_balancer_tls_certificate(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        CreateLoadBalancerTlsCertificateResult,
+        RusotoError<CreateLoadBalancerTlsCertificateError>,
+    > {
+        self.create_load_balancer_tls_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new database in Amazon Lightsail.</p> <p>The <code>create relational database</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_relational_database(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> Result<CreateRelationalDatabaseResult, RusotoError<CreateRelationalDatabaseError>> {
+        self.create_relational_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new database from an existing database snapshot in Amazon Lightsail.</p> <p>You can create a new database from a snapshot in if something goes wrong with your original database, or to change it to a different plan, such as a high availability or standard plan.</p> <p>The <code>create relational database from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by relationalDatabaseSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_relational_database_from_snapshot(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> Result<
+        CreateRelationalDatabaseFromSnapshotResult,
+        RusotoError<CreateRelationalDatabaseFromSnapshotError>,
+    > {
+        self.create_relational_database_from_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a snapshot of your database in Amazon Lightsail. You can use snapshots for backups, to make copies of a database, and to save data before deleting a database.</p> <p>The <code>create relational database snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn create_relational_database_snapshot(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> Result<
+        CreateRelationalDatabaseSnapshotResult,
+        RusotoError<CreateRelationalDatabaseSnapshotError>,
+    > {
+        self.create_relational_database_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an automatic snapshot of an instance or disk. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
+    async fn delete_auto_snapshot(
+        &self,
+        input: DeleteAutoSnapshotRequest,
+    ) -> Result<DeleteAutoSnapshotResult, RusotoError<DeleteAutoSnapshotError>> {
+        self.delete_auto_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified block storage disk. The disk must be in the <code>available</code> state (not attached to a Lightsail instance).</p> <note> <p>The disk may remain in the <code>deleting</code> state for several minutes.</p> </note> <p>The <code>delete disk</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_disk(
+        &self,
+        input: DeleteDiskRequest,
+    ) -> Result<DeleteDiskResult, RusotoError<DeleteDiskError>> {
+        self.delete_disk_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified disk snapshot.</p> <p>When you make periodic snapshots of a disk, the snapshots are incremental, and only the blocks on the device that have changed since your last snapshot are saved in the new snapshot. When you delete a snapshot, only the data not needed for any other snapshot is removed. So regardless of which prior snapshots have been deleted, all active snapshots will have access to all the information needed to restore the disk.</p> <p>The <code>delete disk snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_disk_snapshot(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> Result<DeleteDiskSnapshotResult, RusotoError<DeleteDiskSnapshotError>> {
+        self.delete_disk_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified domain recordset and all of its domain records.</p> <p>The <code>delete domain</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<DeleteDomainResult, RusotoError<DeleteDomainError>> {
+        self.delete_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specific domain entry.</p> <p>The <code>delete domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_domain_entry(
+        &self,
+        input: DeleteDomainEntryRequest,
+    ) -> Result<DeleteDomainEntryResult, RusotoError<DeleteDomainEntryError>> {
+        self.delete_domain_entry_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Amazon Lightsail instance.</p> <p>The <code>delete instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> Result<DeleteInstanceResult, RusotoError<DeleteInstanceError>> {
+        self.delete_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specific snapshot of a virtual private server (or <i>instance</i>).</p> <p>The <code>delete instance snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_instance_snapshot(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> Result<DeleteInstanceSnapshotResult, RusotoError<DeleteInstanceSnapshotError>> {
+        self.delete_instance_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specific SSH key pair.</p> <p>The <code>delete key pair</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>key pair name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> Result<DeleteKeyPairResult, RusotoError<DeleteKeyPairError>> {
+        self.delete_key_pair_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the known host key or certificate used by the Amazon Lightsail browser-based SSH or RDP clients to authenticate an instance. This operation enables the Lightsail browser-based SSH or RDP clients to connect to the instance after a host key mismatch.</p> <important> <p>Perform this operation only if you were expecting the host key or certificate mismatch or if you are familiar with the new host key or certificate on the instance. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-troubleshooting-browser-based-ssh-rdp-client-connection">Troubleshooting connection issues when using the Amazon Lightsail browser-based SSH or RDP client</a>.</p> </important></p>
+    async fn delete_known_host_keys(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> Result<DeleteKnownHostKeysResult, RusotoError<DeleteKnownHostKeysError>> {
+        self.delete_known_host_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Lightsail load balancer and all its associated SSL/TLS certificates. Once the load balancer is deleted, you will need to create a new load balancer, create a new certificate, and verify domain ownership again.</p> <p>The <code>delete load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> Result<DeleteLoadBalancerResult, RusotoError<DeleteLoadBalancerError>> {
+        self.delete_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an SSL/TLS certificate associated with a Lightsail load balancer.</p> <p>The <code>delete load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_load_balancer_tls_certificate(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        DeleteLoadBalancerTlsCertificateResult,
+        RusotoError<DeleteLoadBalancerTlsCertificateError>,
+    > {
+        self.delete_load_balancer_tls_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a database in Amazon Lightsail.</p> <p>The <code>delete relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_relational_database(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> Result<DeleteRelationalDatabaseResult, RusotoError<DeleteRelationalDatabaseError>> {
+        self.delete_relational_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a database snapshot in Amazon Lightsail.</p> <p>The <code>delete relational database snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn delete_relational_database_snapshot(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> Result<
+        DeleteRelationalDatabaseSnapshotResult,
+        RusotoError<DeleteRelationalDatabaseSnapshotError>,
+    > {
+        self.delete_relational_database_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches a stopped block storage disk from a Lightsail instance. Make sure to unmount any file systems on the device within your operating system before stopping the instance and detaching the disk.</p> <p>The <code>detach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn detach_disk(
+        &self,
+        input: DetachDiskRequest,
+    ) -> Result<DetachDiskResult, RusotoError<DetachDiskError>> {
+        self.detach_disk_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches the specified instances from a Lightsail load balancer.</p> <p>This operation waits until the instances are no longer needed before they are detached from the load balancer.</p> <p>The <code>detach instances from load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn detach_instances_from_load_balancer(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> Result<
+        DetachInstancesFromLoadBalancerResult,
+        RusotoError<DetachInstancesFromLoadBalancerError>,
+    > {
+        self.detach_instances_from_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches a static IP from the Amazon Lightsail instance to which it is attached.</p>
+    async fn detach_static_ip(
+        &self,
+        input: DetachStaticIpRequest,
+    ) -> Result<DetachStaticIpResult, RusotoError<DetachStaticIpError>> {
+        self.detach_static_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables an add-on for an Amazon Lightsail resource. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
+    async fn disable_add_on(
+        &self,
+        input: DisableAddOnRequest,
+    ) -> Result<DisableAddOnResult, RusotoError<DisableAddOnError>> {
+        self.disable_add_on_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Downloads the default SSH key pair from the user's account.</p>
+    async fn download_default_key_pair(
+        &self,
+    ) -> Result<DownloadDefaultKeyPairResult, RusotoError<DownloadDefaultKeyPairError>> {
+        self.download_default_key_pair_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables or modifies an add-on for an Amazon Lightsail resource. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
+    async fn enable_add_on(
+        &self,
+        input: EnableAddOnRequest,
+    ) -> Result<EnableAddOnResult, RusotoError<EnableAddOnError>> {
+        self.enable_add_on_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Exports an Amazon Lightsail instance or block storage disk snapshot to Amazon Elastic Compute Cloud (Amazon EC2). This operation results in an export snapshot record that can be used with the <code>create cloud formation stack</code> operation to create new Amazon EC2 instances.</p> <p>Exported instance snapshots appear in Amazon EC2 as Amazon Machine Images (AMIs), and the instance system disk appears as an Amazon Elastic Block Store (Amazon EBS) volume. Exported disk snapshots appear in Amazon EC2 as Amazon EBS volumes. Snapshots are exported to the same Amazon Web Services Region in Amazon EC2 as the source Lightsail snapshot.</p> <p/> <p>The <code>export snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>source snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p> <note> <p>Use the <code>get instance snapshots</code> or <code>get disk snapshots</code> operations to get a list of snapshots that you can export to Amazon EC2.</p> </note></p>
+    async fn export_snapshot(
+        &self,
+        input: ExportSnapshotRequest,
+    ) -> Result<ExportSnapshotResult, RusotoError<ExportSnapshotError>> {
+        self.export_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the names of all active (not deleted) resources.</p>
+    async fn get_active_names(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>> {
+        self.get_active_names_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the available automatic snapshots for an instance or disk. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
+    async fn get_auto_snapshots(
+        &self,
+        input: GetAutoSnapshotsRequest,
+    ) -> Result<GetAutoSnapshotsResult, RusotoError<GetAutoSnapshotsError>> {
+        self.get_auto_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the list of available instance images, or <i>blueprints</i>. You can use a blueprint to create a new instance already running a specific operating system, as well as a preinstalled app or development stack. The software each instance is running depends on the blueprint image you choose.</p> <note> <p>Use active blueprints when creating new instances. Inactive blueprints are listed to support customers with existing instances and are not necessarily available to create new instances. Blueprints are marked inactive when they become outdated due to operating system updates or new application releases.</p> </note></p>
+    async fn get_blueprints(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>> {
+        self.get_blueprints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of bundles that are available for purchase. A bundle describes the specs for your virtual private server (or <i>instance</i>).</p>
+    async fn get_bundles(
+        &self,
+        input: GetBundlesRequest,
+    ) -> Result<GetBundlesResult, RusotoError<GetBundlesError>> {
+        self.get_bundles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the CloudFormation stack record created as a result of the <code>create cloud formation stack</code> operation.</p> <p>An AWS CloudFormation stack is used to create a new Amazon EC2 instance from an exported Lightsail snapshot.</p>
+    async fn get_cloud_formation_stack_records(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> Result<GetCloudFormationStackRecordsResult, RusotoError<GetCloudFormationStackRecordsError>>
+    {
+        self.get_cloud_formation_stack_records_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific block storage disk.</p>
+    async fn get_disk(
+        &self,
+        input: GetDiskRequest,
+    ) -> Result<GetDiskResult, RusotoError<GetDiskError>> {
+        self.get_disk_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific block storage disk snapshot.</p>
+    async fn get_disk_snapshot(
+        &self,
+        input: GetDiskSnapshotRequest,
+    ) -> Result<GetDiskSnapshotResult, RusotoError<GetDiskSnapshotError>> {
+        self.get_disk_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all block storage disk snapshots in your AWS account and region.</p> <p>If you are describing a long list of disk snapshots, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    async fn get_disk_snapshots(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> Result<GetDiskSnapshotsResult, RusotoError<GetDiskSnapshotsError>> {
+        self.get_disk_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all block storage disks in your AWS account and region.</p> <p>If you are describing a long list of disks, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    async fn get_disks(
+        &self,
+        input: GetDisksRequest,
+    ) -> Result<GetDisksResult, RusotoError<GetDisksError>> {
+        self.get_disks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific domain recordset.</p>
+    async fn get_domain(
+        &self,
+        input: GetDomainRequest,
+    ) -> Result<GetDomainResult, RusotoError<GetDomainError>> {
+        self.get_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all domains in the user's account.</p>
+    async fn get_domains(
+        &self,
+        input: GetDomainsRequest,
+    ) -> Result<GetDomainsResult, RusotoError<GetDomainsError>> {
+        self.get_domains_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the export snapshot record created as a result of the <code>export snapshot</code> operation.</p> <p>An export snapshot record can be used to create a new Amazon EC2 instance and its related resources with the <code>create cloud formation stack</code> operation.</p>
+    async fn get_export_snapshot_records(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> Result<GetExportSnapshotRecordsResult, RusotoError<GetExportSnapshotRecordsError>> {
+        self.get_export_snapshot_records_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific Amazon Lightsail instance, which is a virtual private server.</p>
+    async fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> Result<GetInstanceResult, RusotoError<GetInstanceError>> {
+        self.get_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns temporary SSH keys you can use to connect to a specific virtual private server, or <i>instance</i>.</p> <p>The <code>get instance access details</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn get_instance_access_details(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> Result<GetInstanceAccessDetailsResult, RusotoError<GetInstanceAccessDetailsError>> {
+        self.get_instance_access_details_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the data points for the specified Amazon Lightsail instance metric, given an instance name.</p>
+    async fn get_instance_metric_data(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> Result<GetInstanceMetricDataResult, RusotoError<GetInstanceMetricDataError>> {
+        self.get_instance_metric_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the port states for a specific virtual private server, or <i>instance</i>.</p>
+    async fn get_instance_port_states(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> Result<GetInstancePortStatesResult, RusotoError<GetInstancePortStatesError>> {
+        self.get_instance_port_states_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific instance snapshot.</p>
+    async fn get_instance_snapshot(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> Result<GetInstanceSnapshotResult, RusotoError<GetInstanceSnapshotError>> {
+        self.get_instance_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all instance snapshots for the user's account.</p>
+    async fn get_instance_snapshots(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> Result<GetInstanceSnapshotsResult, RusotoError<GetInstanceSnapshotsError>> {
+        self.get_instance_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the state of a specific instance. Works on one instance at a time.</p>
+    async fn get_instance_state(
+        &self,
+        input: GetInstanceStateRequest,
+    ) -> Result<GetInstanceStateResult, RusotoError<GetInstanceStateError>> {
+        self.get_instance_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all Amazon Lightsail virtual private servers, or <i>instances</i>.</p>
+    async fn get_instances(
+        &self,
+        input: GetInstancesRequest,
+    ) -> Result<GetInstancesResult, RusotoError<GetInstancesError>> {
+        self.get_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific key pair.</p>
+    async fn get_key_pair(
+        &self,
+        input: GetKeyPairRequest,
+    ) -> Result<GetKeyPairResult, RusotoError<GetKeyPairError>> {
+        self.get_key_pair_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all key pairs in the user's account.</p>
+    async fn get_key_pairs(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>> {
+        self.get_key_pairs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified Lightsail load balancer.</p>
+    async fn get_load_balancer(
+        &self,
+        input: GetLoadBalancerRequest,
+    ) -> Result<GetLoadBalancerResult, RusotoError<GetLoadBalancerError>> {
+        self.get_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about health metrics for your Lightsail load balancer.</p>
+    async fn get_load_balancer_metric_data(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> Result<GetLoadBalancerMetricDataResult, RusotoError<GetLoadBalancerMetricDataError>> {
+        self.get_load_balancer_metric_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the TLS certificates that are associated with the specified Lightsail load balancer.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>You can have a maximum of 2 certificates associated with a Lightsail load balancer. One is active and the other is inactive.</p>
+    async fn get_load_balancer_tls_certificates(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> Result<
+        GetLoadBalancerTlsCertificatesResult,
+        RusotoError<GetLoadBalancerTlsCertificatesError>,
+    > {
+        self.get_load_balancer_tls_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all load balancers in an account.</p> <p>If you are describing a long list of load balancers, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    async fn get_load_balancers(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> Result<GetLoadBalancersResult, RusotoError<GetLoadBalancersError>> {
+        self.get_load_balancers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific operation. Operations include events such as when you create an instance, allocate a static IP, attach a static IP, and so on.</p>
+    async fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> Result<GetOperationResult, RusotoError<GetOperationError>> {
+        self.get_operation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all operations.</p> <p>Results are returned from oldest to newest, up to a maximum of 200. Results can be paged by making each subsequent call to <code>GetOperations</code> use the maximum (last) <code>statusChangedAt</code> value from the previous request.</p>
+    async fn get_operations(
+        &self,
+        input: GetOperationsRequest,
+    ) -> Result<GetOperationsResult, RusotoError<GetOperationsError>> {
+        self.get_operations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets operations for a specific resource (e.g., an instance or a static IP).</p>
+    async fn get_operations_for_resource(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> Result<GetOperationsForResourceResult, RusotoError<GetOperationsForResourceError>> {
+        self.get_operations_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all valid regions for Amazon Lightsail. Use the <code>include availability zones</code> parameter to also return the Availability Zones in a region.</p>
+    async fn get_regions(
+        &self,
+        input: GetRegionsRequest,
+    ) -> Result<GetRegionsResult, RusotoError<GetRegionsError>> {
+        self.get_regions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific database in Amazon Lightsail.</p>
+    async fn get_relational_database(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> Result<GetRelationalDatabaseResult, RusotoError<GetRelationalDatabaseError>> {
+        self.get_relational_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of available database blueprints in Amazon Lightsail. A blueprint describes the major engine version of a database.</p> <p>You can use a blueprint ID to create a new database that runs a specific database engine.</p>
+    async fn get_relational_database_blueprints(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> Result<
+        GetRelationalDatabaseBlueprintsResult,
+        RusotoError<GetRelationalDatabaseBlueprintsError>,
+    > {
+        self.get_relational_database_blueprints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of bundles that are available in Amazon Lightsail. A bundle describes the performance specifications for a database.</p> <p>You can use a bundle ID to create a new database with explicit performance specifications.</p>
+    async fn get_relational_database_bundles(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> Result<GetRelationalDatabaseBundlesResult, RusotoError<GetRelationalDatabaseBundlesError>>
+    {
+        self.get_relational_database_bundles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of events for a specific database in Amazon Lightsail.</p>
+    async fn get_relational_database_events(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> Result<GetRelationalDatabaseEventsResult, RusotoError<GetRelationalDatabaseEventsError>>
+    {
+        self.get_relational_database_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of log events for a database in Amazon Lightsail.</p>
+    async fn get_relational_database_log_events(
+        &self,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> Result<
+        GetRelationalDatabaseLogEventsResult,
+        RusotoError<GetRelationalDatabaseLogEventsError>,
+    > {
+        self.get_relational_database_log_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of available log streams for a specific database in Amazon Lightsail.</p>
+    async fn get_relational_database_log_streams(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> Result<
+        GetRelationalDatabaseLogStreamsResult,
+        RusotoError<GetRelationalDatabaseLogStreamsError>,
+    > {
+        self.get_relational_database_log_streams_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the current, previous, or pending versions of the master user password for a Lightsail database.</p> <p>The <code>GetRelationalDatabaseMasterUserPassword</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName.</p>
+    async fn get_relational_database_master_user_password(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> Result<
+        GetRelationalDatabaseMasterUserPasswordResult,
+        RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
+    > {
+        self.get_relational_database_master_user_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the data points of the specified metric for a database in Amazon Lightsail.</p>
+    async fn get_relational_database_metric_data(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> Result<
+        GetRelationalDatabaseMetricDataResult,
+        RusotoError<GetRelationalDatabaseMetricDataError>,
+    > {
+        self.get_relational_database_metric_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all of the runtime parameters offered by the underlying database software, or engine, for a specific database in Amazon Lightsail.</p> <p>In addition to the parameter names and values, this operation returns other information about each parameter. This information includes whether changes require a reboot, whether the parameter is modifiable, the allowed values, and the data types.</p>
+    async fn get_relational_database_parameters(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> Result<
+        GetRelationalDatabaseParametersResult,
+        RusotoError<GetRelationalDatabaseParametersError>,
+    > {
+        self.get_relational_database_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific database snapshot in Amazon Lightsail.</p>
+    async fn get_relational_database_snapshot(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> Result<GetRelationalDatabaseSnapshotResult, RusotoError<GetRelationalDatabaseSnapshotError>>
+    {
+        self.get_relational_database_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all of your database snapshots in Amazon Lightsail.</p>
+    async fn get_relational_database_snapshots(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> Result<
+        GetRelationalDatabaseSnapshotsResult,
+        RusotoError<GetRelationalDatabaseSnapshotsError>,
+    > {
+        self.get_relational_database_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all of your databases in Amazon Lightsail.</p>
+    async fn get_relational_databases(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> Result<GetRelationalDatabasesResult, RusotoError<GetRelationalDatabasesError>> {
+        self.get_relational_databases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific static IP.</p>
+    async fn get_static_ip(
+        &self,
+        input: GetStaticIpRequest,
+    ) -> Result<GetStaticIpResult, RusotoError<GetStaticIpError>> {
+        self.get_static_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all static IPs in the user's account.</p>
+    async fn get_static_ips(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>> {
+        self.get_static_ips_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Imports a public SSH key from a specific key pair.</p>
+    async fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
+        self.import_key_pair_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
+    async fn is_vpc_peered(&self) -> Result<IsVpcPeeredResult, RusotoError<IsVpcPeeredError>> {
+        self.is_vpc_peered_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds public ports to an Amazon Lightsail instance.</p> <p>The <code>open instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn open_instance_public_ports(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> Result<OpenInstancePublicPortsResult, RusotoError<OpenInstancePublicPortsError>> {
+        self.open_instance_public_ports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
+    async fn peer_vpc(&self) -> Result<PeerVpcResult, RusotoError<PeerVpcError>> {
+        self.peer_vpc_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the specified open ports for an Amazon Lightsail instance, and closes all ports for every protocol not included in the current request.</p> <p>The <code>put instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn put_instance_public_ports(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> Result<PutInstancePublicPortsResult, RusotoError<PutInstancePublicPortsError>> {
+        self.put_instance_public_ports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Restarts a specific instance.</p> <p>The <code>reboot instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> Result<RebootInstanceResult, RusotoError<RebootInstanceError>> {
+        self.reboot_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Restarts a specific database in Amazon Lightsail.</p> <p>The <code>reboot relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn reboot_relational_database(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> Result<RebootRelationalDatabaseResult, RusotoError<RebootRelationalDatabaseError>> {
+        self.reboot_relational_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specific static IP from your account.</p>
+    async fn release_static_ip(
+        &self,
+        input: ReleaseStaticIpRequest,
+    ) -> Result<ReleaseStaticIpResult, RusotoError<ReleaseStaticIpError>> {
+        self.release_static_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a specific Amazon Lightsail instance from a stopped state. To restart an instance, use the <code>reboot instance</code> operation.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>start instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> Result<StartInstanceResult, RusotoError<StartInstanceError>> {
+        self.start_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a specific database from a stopped state in Amazon Lightsail. To restart a database, use the <code>reboot relational database</code> operation.</p> <p>The <code>start relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn start_relational_database(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> Result<StartRelationalDatabaseResult, RusotoError<StartRelationalDatabaseError>> {
+        self.start_relational_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a specific Amazon Lightsail instance that is currently running.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>stop instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> Result<StopInstanceResult, RusotoError<StopInstanceError>> {
+        self.stop_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a specific database that is currently running in Amazon Lightsail.</p> <p>The <code>stop relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn stop_relational_database(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> Result<StopRelationalDatabaseResult, RusotoError<StopRelationalDatabaseError>> {
+        self.stop_relational_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to the specified Amazon Lightsail resource. Each resource can have a maximum of 50 tags. Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-tags">Lightsail Dev Guide</a>.</p> <p>The <code>tag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>resource name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
+    async fn unpeer_vpc(&self) -> Result<UnpeerVpcResult, RusotoError<UnpeerVpcError>> {
+        self.unpeer_vpc_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified set of tag keys and their values from the specified Amazon Lightsail resource.</p> <p>The <code>untag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>resource name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a domain recordset after it is created.</p> <p>The <code>update domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn update_domain_entry(
+        &self,
+        input: UpdateDomainEntryRequest,
+    ) -> Result<UpdateDomainEntryResult, RusotoError<UpdateDomainEntryError>> {
+        self.update_domain_entry_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified attribute for a load balancer. You can only update one attribute at a time.</p> <p>The <code>update load balancer attribute</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn update_load_balancer_attribute(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> Result<UpdateLoadBalancerAttributeResult, RusotoError<UpdateLoadBalancerAttributeError>>
+    {
+        self.update_load_balancer_attribute_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows the update of one or more attributes of a database in Amazon Lightsail.</p> <p>Updates are applied immediately, or in cases where the updates could result in an outage, are applied during the database's predefined maintenance window.</p> <p>The <code>update relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn update_relational_database(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> Result<UpdateRelationalDatabaseResult, RusotoError<UpdateRelationalDatabaseError>> {
+        self.update_relational_database_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows the update of one or more parameters of a database in Amazon Lightsail.</p> <p>Parameter updates don't cause outages; therefore, their application is not subject to the preferred maintenance window. However, there are two ways in which parameter updates are applied: <code>dynamic</code> or <code>pending-reboot</code>. Parameters marked with a <code>dynamic</code> apply type are applied immediately. Parameters marked with a <code>pending-reboot</code> apply type are applied only after the database is rebooted using the <code>reboot relational database</code> operation.</p> <p>The <code>update relational database parameters</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    async fn update_relational_database_parameters(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> Result<
+        UpdateRelationalDatabaseParametersResult,
+        RusotoError<UpdateRelationalDatabaseParametersError>,
+    > {
+        self.update_relational_database_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1313,6 +1313,7 @@ pub struct UntagLogGroupRequest {
 
 /// Errors returned by AssociateKmsKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateKmsKeyError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1322,6 +1323,8 @@ pub enum AssociateKmsKeyError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateKmsKeyError {
@@ -1329,19 +1332,31 @@ impl AssociateKmsKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AssociateKmsKeyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        AssociateKmsKeyError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AssociateKmsKeyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        AssociateKmsKeyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateKmsKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1355,12 +1370,14 @@ impl fmt::Display for AssociateKmsKeyError {
             AssociateKmsKeyError::OperationAborted(ref cause) => write!(f, "{}", cause),
             AssociateKmsKeyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AssociateKmsKeyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            AssociateKmsKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateKmsKeyError {}
 /// Errors returned by CancelExportTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelExportTaskError {
     /// <p>The operation is not valid on the specified resource.</p>
     InvalidOperation(String),
@@ -1370,6 +1387,8 @@ pub enum CancelExportTaskError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelExportTaskError {
@@ -1377,19 +1396,31 @@ impl CancelExportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidOperationException" => {
-                    return RusotoError::Service(CancelExportTaskError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        CancelExportTaskError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelExportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CancelExportTaskError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelExportTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CancelExportTaskError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelExportTaskError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CancelExportTaskError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelExportTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1403,12 +1434,14 @@ impl fmt::Display for CancelExportTaskError {
             CancelExportTaskError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CancelExportTaskError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CancelExportTaskError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CancelExportTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelExportTaskError {}
 /// Errors returned by CreateExportTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateExportTaskError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1422,6 +1455,8 @@ pub enum CreateExportTaskError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateExportTaskError {
@@ -1429,27 +1464,43 @@ impl CreateExportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateExportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateExportTaskError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateExportTaskError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateExportTaskError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(CreateExportTaskError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        CreateExportTaskError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateExportTaskError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateExportTaskError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateExportTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateExportTaskError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateExportTaskError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateExportTaskError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateExportTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1465,12 +1516,14 @@ impl fmt::Display for CreateExportTaskError {
             CreateExportTaskError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateExportTaskError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateExportTaskError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateExportTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateExportTaskError {}
 /// Errors returned by CreateLogGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLogGroupError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1482,6 +1535,8 @@ pub enum CreateLogGroupError {
     ResourceAlreadyExists(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLogGroupError {
@@ -1489,24 +1544,34 @@ impl CreateLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateLogGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateLogGroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateLogGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateLogGroupError::LimitExceeded(err.msg), &res)
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(CreateLogGroupError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        CreateLogGroupError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLogGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLogGroupError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateLogGroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateLogGroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLogGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1521,12 +1586,14 @@ impl fmt::Display for CreateLogGroupError {
             CreateLogGroupError::OperationAborted(ref cause) => write!(f, "{}", cause),
             CreateLogGroupError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateLogGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateLogGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLogGroupError {}
 /// Errors returned by CreateLogStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLogStreamError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1536,6 +1603,8 @@ pub enum CreateLogStreamError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLogStreamError {
@@ -1543,21 +1612,31 @@ impl CreateLogStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateLogStreamError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateLogStreamError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLogStreamError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLogStreamError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLogStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateLogStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateLogStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateLogStreamError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLogStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1571,12 +1650,14 @@ impl fmt::Display for CreateLogStreamError {
             CreateLogStreamError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateLogStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateLogStreamError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateLogStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLogStreamError {}
 /// Errors returned by DeleteDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDestinationError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1586,6 +1667,8 @@ pub enum DeleteDestinationError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDestinationError {
@@ -1593,21 +1676,31 @@ impl DeleteDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteDestinationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteDestinationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteDestinationError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        DeleteDestinationError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDestinationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDestinationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDestinationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDestinationError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDestinationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1621,12 +1714,14 @@ impl fmt::Display for DeleteDestinationError {
             DeleteDestinationError::OperationAborted(ref cause) => write!(f, "{}", cause),
             DeleteDestinationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDestinationError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteDestinationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDestinationError {}
 /// Errors returned by DeleteLogGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLogGroupError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1636,6 +1731,8 @@ pub enum DeleteLogGroupError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLogGroupError {
@@ -1643,19 +1740,31 @@ impl DeleteLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLogGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteLogGroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteLogGroupError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        DeleteLogGroupError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteLogGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteLogGroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteLogGroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLogGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1669,12 +1778,14 @@ impl fmt::Display for DeleteLogGroupError {
             DeleteLogGroupError::OperationAborted(ref cause) => write!(f, "{}", cause),
             DeleteLogGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteLogGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteLogGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLogGroupError {}
 /// Errors returned by DeleteLogStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLogStreamError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1684,6 +1795,8 @@ pub enum DeleteLogStreamError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLogStreamError {
@@ -1691,19 +1804,31 @@ impl DeleteLogStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLogStreamError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteLogStreamError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteLogStreamError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        DeleteLogStreamError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLogStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteLogStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteLogStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteLogStreamError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLogStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1717,12 +1842,14 @@ impl fmt::Display for DeleteLogStreamError {
             DeleteLogStreamError::OperationAborted(ref cause) => write!(f, "{}", cause),
             DeleteLogStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteLogStreamError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteLogStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLogStreamError {}
 /// Errors returned by DeleteMetricFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMetricFilterError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1732,6 +1859,8 @@ pub enum DeleteMetricFilterError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMetricFilterError {
@@ -1739,21 +1868,31 @@ impl DeleteMetricFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteMetricFilterError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        DeleteMetricFilterError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteMetricFilterError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMetricFilterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMetricFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1767,12 +1906,14 @@ impl fmt::Display for DeleteMetricFilterError {
             DeleteMetricFilterError::OperationAborted(ref cause) => write!(f, "{}", cause),
             DeleteMetricFilterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteMetricFilterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteMetricFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMetricFilterError {}
 /// Errors returned by DeleteResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourcePolicyError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1780,6 +1921,8 @@ pub enum DeleteResourcePolicyError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourcePolicyError {
@@ -1787,22 +1930,25 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1815,12 +1961,14 @@ impl fmt::Display for DeleteResourcePolicyError {
             DeleteResourcePolicyError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteResourcePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteResourcePolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourcePolicyError {}
 /// Errors returned by DeleteRetentionPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRetentionPolicyError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1830,6 +1978,8 @@ pub enum DeleteRetentionPolicyError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRetentionPolicyError {
@@ -1837,27 +1987,31 @@ impl DeleteRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRetentionPolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::OperationAborted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRetentionPolicyError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRetentionPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRetentionPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRetentionPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1871,12 +2025,14 @@ impl fmt::Display for DeleteRetentionPolicyError {
             DeleteRetentionPolicyError::OperationAborted(ref cause) => write!(f, "{}", cause),
             DeleteRetentionPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteRetentionPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteRetentionPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRetentionPolicyError {}
 /// Errors returned by DeleteSubscriptionFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSubscriptionFilterError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -1886,6 +2042,8 @@ pub enum DeleteSubscriptionFilterError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSubscriptionFilterError {
@@ -1893,27 +2051,31 @@ impl DeleteSubscriptionFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSubscriptionFilterError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::OperationAborted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSubscriptionFilterError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSubscriptionFilterError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSubscriptionFilterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSubscriptionFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1927,17 +2089,21 @@ impl fmt::Display for DeleteSubscriptionFilterError {
             DeleteSubscriptionFilterError::OperationAborted(ref cause) => write!(f, "{}", cause),
             DeleteSubscriptionFilterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteSubscriptionFilterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteSubscriptionFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSubscriptionFilterError {}
 /// Errors returned by DescribeDestinations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDestinationsError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDestinationsError {
@@ -1945,17 +2111,19 @@ impl DescribeDestinationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDestinationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDestinationsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDestinationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDestinationsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDestinationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1967,17 +2135,21 @@ impl fmt::Display for DescribeDestinationsError {
         match *self {
             DescribeDestinationsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeDestinationsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeDestinationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDestinationsError {}
 /// Errors returned by DescribeExportTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeExportTasksError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeExportTasksError {
@@ -1985,17 +2157,19 @@ impl DescribeExportTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeExportTasksError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeExportTasksError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeExportTasksError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeExportTasksError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeExportTasksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2007,17 +2181,21 @@ impl fmt::Display for DescribeExportTasksError {
         match *self {
             DescribeExportTasksError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeExportTasksError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeExportTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeExportTasksError {}
 /// Errors returned by DescribeLogGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLogGroupsError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLogGroupsError {
@@ -2025,15 +2203,19 @@ impl DescribeLogGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLogGroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeLogGroupsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLogGroupsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLogGroupsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLogGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2045,12 +2227,14 @@ impl fmt::Display for DescribeLogGroupsError {
         match *self {
             DescribeLogGroupsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeLogGroupsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeLogGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLogGroupsError {}
 /// Errors returned by DescribeLogStreams
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLogStreamsError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2058,6 +2242,8 @@ pub enum DescribeLogStreamsError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLogStreamsError {
@@ -2065,18 +2251,25 @@ impl DescribeLogStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLogStreamsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeLogStreamsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLogStreamsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeLogStreamsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLogStreamsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLogStreamsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLogStreamsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2089,12 +2282,14 @@ impl fmt::Display for DescribeLogStreamsError {
             DescribeLogStreamsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeLogStreamsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeLogStreamsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeLogStreamsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLogStreamsError {}
 /// Errors returned by DescribeMetricFilters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMetricFiltersError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2102,6 +2297,8 @@ pub enum DescribeMetricFiltersError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMetricFiltersError {
@@ -2109,22 +2306,25 @@ impl DescribeMetricFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeMetricFiltersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMetricFiltersError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeMetricFiltersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMetricFiltersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeMetricFiltersError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMetricFiltersError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMetricFiltersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2137,12 +2337,14 @@ impl fmt::Display for DescribeMetricFiltersError {
             DescribeMetricFiltersError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeMetricFiltersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeMetricFiltersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeMetricFiltersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMetricFiltersError {}
 /// Errors returned by DescribeQueries
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeQueriesError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2150,6 +2352,8 @@ pub enum DescribeQueriesError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeQueriesError {
@@ -2157,16 +2361,25 @@ impl DescribeQueriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeQueriesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeQueriesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeQueriesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeQueriesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeQueriesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeQueriesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeQueriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2179,17 +2392,21 @@ impl fmt::Display for DescribeQueriesError {
             DescribeQueriesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeQueriesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeQueriesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeQueriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeQueriesError {}
 /// Errors returned by DescribeResourcePolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeResourcePoliciesError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeResourcePoliciesError {
@@ -2197,17 +2414,19 @@ impl DescribeResourcePoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeResourcePoliciesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeResourcePoliciesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeResourcePoliciesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeResourcePoliciesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeResourcePoliciesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2219,12 +2438,14 @@ impl fmt::Display for DescribeResourcePoliciesError {
         match *self {
             DescribeResourcePoliciesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeResourcePoliciesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeResourcePoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeResourcePoliciesError {}
 /// Errors returned by DescribeSubscriptionFilters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSubscriptionFiltersError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2232,6 +2453,8 @@ pub enum DescribeSubscriptionFiltersError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSubscriptionFiltersError {
@@ -2241,22 +2464,30 @@ impl DescribeSubscriptionFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubscriptionFiltersError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubscriptionFiltersError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubscriptionFiltersError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeSubscriptionFiltersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2271,12 +2502,14 @@ impl fmt::Display for DescribeSubscriptionFiltersError {
             DescribeSubscriptionFiltersError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeSubscriptionFiltersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSubscriptionFiltersError {}
 /// Errors returned by DisassociateKmsKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateKmsKeyError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2286,6 +2519,8 @@ pub enum DisassociateKmsKeyError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateKmsKeyError {
@@ -2293,21 +2528,31 @@ impl DisassociateKmsKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DisassociateKmsKeyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        DisassociateKmsKeyError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DisassociateKmsKeyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateKmsKeyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateKmsKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2321,12 +2566,14 @@ impl fmt::Display for DisassociateKmsKeyError {
             DisassociateKmsKeyError::OperationAborted(ref cause) => write!(f, "{}", cause),
             DisassociateKmsKeyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DisassociateKmsKeyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DisassociateKmsKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateKmsKeyError {}
 /// Errors returned by FilterLogEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FilterLogEventsError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2334,6 +2581,8 @@ pub enum FilterLogEventsError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl FilterLogEventsError {
@@ -2341,16 +2590,25 @@ impl FilterLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(FilterLogEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        FilterLogEventsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(FilterLogEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        FilterLogEventsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(FilterLogEventsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        FilterLogEventsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(FilterLogEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2363,12 +2621,14 @@ impl fmt::Display for FilterLogEventsError {
             FilterLogEventsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             FilterLogEventsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             FilterLogEventsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            FilterLogEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for FilterLogEventsError {}
 /// Errors returned by GetLogEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLogEventsError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2376,6 +2636,8 @@ pub enum GetLogEventsError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLogEventsError {
@@ -2383,16 +2645,19 @@ impl GetLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLogEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetLogEventsError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLogEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetLogEventsError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLogEventsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetLogEventsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLogEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2405,12 +2670,14 @@ impl fmt::Display for GetLogEventsError {
             GetLogEventsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetLogEventsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLogEventsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetLogEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLogEventsError {}
 /// Errors returned by GetLogGroupFields
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLogGroupFieldsError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2420,6 +2687,8 @@ pub enum GetLogGroupFieldsError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLogGroupFieldsError {
@@ -2427,21 +2696,31 @@ impl GetLogGroupFieldsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetLogGroupFieldsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetLogGroupFieldsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetLogGroupFieldsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLogGroupFieldsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLogGroupFieldsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2455,12 +2734,14 @@ impl fmt::Display for GetLogGroupFieldsError {
             GetLogGroupFieldsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetLogGroupFieldsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLogGroupFieldsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetLogGroupFieldsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLogGroupFieldsError {}
 /// Errors returned by GetLogRecord
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLogRecordError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2470,6 +2751,8 @@ pub enum GetLogRecordError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLogRecordError {
@@ -2477,19 +2760,22 @@ impl GetLogRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLogRecordError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetLogRecordError::InvalidParameter(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLogRecordError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetLogRecordError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLogRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetLogRecordError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLogRecordError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetLogRecordError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLogRecordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2503,12 +2789,14 @@ impl fmt::Display for GetLogRecordError {
             GetLogRecordError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetLogRecordError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLogRecordError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetLogRecordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLogRecordError {}
 /// Errors returned by GetQueryResults
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetQueryResultsError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2516,6 +2804,8 @@ pub enum GetQueryResultsError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetQueryResultsError {
@@ -2523,16 +2813,25 @@ impl GetQueryResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetQueryResultsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetQueryResultsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetQueryResultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetQueryResultsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetQueryResultsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetQueryResultsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetQueryResultsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2545,17 +2844,21 @@ impl fmt::Display for GetQueryResultsError {
             GetQueryResultsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetQueryResultsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetQueryResultsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetQueryResultsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetQueryResultsError {}
 /// Errors returned by ListTagsLogGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsLogGroupError {
     /// <p>The specified resource does not exist.</p>
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsLogGroupError {
@@ -2563,13 +2866,19 @@ impl ListTagsLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTagsLogGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsLogGroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListTagsLogGroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsLogGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2581,12 +2890,14 @@ impl fmt::Display for ListTagsLogGroupError {
         match *self {
             ListTagsLogGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsLogGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListTagsLogGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsLogGroupError {}
 /// Errors returned by PutDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDestinationError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2594,6 +2905,8 @@ pub enum PutDestinationError {
     OperationAborted(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDestinationError {
@@ -2601,16 +2914,25 @@ impl PutDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutDestinationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        PutDestinationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutDestinationError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        PutDestinationError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutDestinationError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        PutDestinationError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutDestinationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2623,12 +2945,14 @@ impl fmt::Display for PutDestinationError {
             PutDestinationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             PutDestinationError::OperationAborted(ref cause) => write!(f, "{}", cause),
             PutDestinationError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutDestinationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutDestinationError {}
 /// Errors returned by PutDestinationPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDestinationPolicyError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2636,6 +2960,8 @@ pub enum PutDestinationPolicyError {
     OperationAborted(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDestinationPolicyError {
@@ -2643,22 +2969,25 @@ impl PutDestinationPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutDestinationPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDestinationPolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutDestinationPolicyError::OperationAborted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDestinationPolicyError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutDestinationPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDestinationPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutDestinationPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2671,12 +3000,14 @@ impl fmt::Display for PutDestinationPolicyError {
             PutDestinationPolicyError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             PutDestinationPolicyError::OperationAborted(ref cause) => write!(f, "{}", cause),
             PutDestinationPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutDestinationPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutDestinationPolicyError {}
 /// Errors returned by PutLogEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLogEventsError {
     /// <p>The event was already logged.</p>
     DataAlreadyAccepted(String),
@@ -2690,6 +3021,8 @@ pub enum PutLogEventsError {
     ServiceUnavailable(String),
     /// <p>The most likely cause is an invalid AWS access key ID or secret key.</p>
     UnrecognizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLogEventsError {
@@ -2697,25 +3030,37 @@ impl PutLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataAlreadyAcceptedException" => {
-                    return RusotoError::Service(PutLogEventsError::DataAlreadyAccepted(err.msg))
+                    return RusotoError::service(
+                        PutLogEventsError::DataAlreadyAccepted(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutLogEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(PutLogEventsError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidSequenceTokenException" => {
-                    return RusotoError::Service(PutLogEventsError::InvalidSequenceToken(err.msg))
+                    return RusotoError::service(
+                        PutLogEventsError::InvalidSequenceToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutLogEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(PutLogEventsError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutLogEventsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        PutLogEventsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnrecognizedClientException" => {
-                    return RusotoError::Service(PutLogEventsError::UnrecognizedClient(err.msg))
+                    return RusotoError::service(
+                        PutLogEventsError::UnrecognizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutLogEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2731,12 +3076,14 @@ impl fmt::Display for PutLogEventsError {
             PutLogEventsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutLogEventsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             PutLogEventsError::UnrecognizedClient(ref cause) => write!(f, "{}", cause),
+            PutLogEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutLogEventsError {}
 /// Errors returned by PutMetricFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutMetricFilterError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2748,6 +3095,8 @@ pub enum PutMetricFilterError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutMetricFilterError {
@@ -2755,22 +3104,34 @@ impl PutMetricFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutMetricFilterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        PutMetricFilterError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutMetricFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutMetricFilterError::LimitExceeded(err.msg), &res)
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutMetricFilterError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        PutMetricFilterError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutMetricFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutMetricFilterError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutMetricFilterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        PutMetricFilterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutMetricFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2785,12 +3146,14 @@ impl fmt::Display for PutMetricFilterError {
             PutMetricFilterError::OperationAborted(ref cause) => write!(f, "{}", cause),
             PutMetricFilterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutMetricFilterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutMetricFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutMetricFilterError {}
 /// Errors returned by PutResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutResourcePolicyError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2798,6 +3161,8 @@ pub enum PutResourcePolicyError {
     LimitExceeded(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutResourcePolicyError {
@@ -2805,18 +3170,25 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutResourcePolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourcePolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2829,12 +3201,14 @@ impl fmt::Display for PutResourcePolicyError {
             PutResourcePolicyError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             PutResourcePolicyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutResourcePolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutResourcePolicyError {}
 /// Errors returned by PutRetentionPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRetentionPolicyError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2844,6 +3218,8 @@ pub enum PutRetentionPolicyError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRetentionPolicyError {
@@ -2851,21 +3227,31 @@ impl PutRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        PutRetentionPolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        PutRetentionPolicyError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutRetentionPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutRetentionPolicyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRetentionPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2879,12 +3265,14 @@ impl fmt::Display for PutRetentionPolicyError {
             PutRetentionPolicyError::OperationAborted(ref cause) => write!(f, "{}", cause),
             PutRetentionPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutRetentionPolicyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutRetentionPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRetentionPolicyError {}
 /// Errors returned by PutSubscriptionFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutSubscriptionFilterError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2896,6 +3284,8 @@ pub enum PutSubscriptionFilterError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutSubscriptionFilterError {
@@ -2903,30 +3293,37 @@ impl PutSubscriptionFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutSubscriptionFilterError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutSubscriptionFilterError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::OperationAborted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutSubscriptionFilterError::OperationAborted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutSubscriptionFilterError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutSubscriptionFilterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutSubscriptionFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2941,12 +3338,14 @@ impl fmt::Display for PutSubscriptionFilterError {
             PutSubscriptionFilterError::OperationAborted(ref cause) => write!(f, "{}", cause),
             PutSubscriptionFilterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutSubscriptionFilterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutSubscriptionFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutSubscriptionFilterError {}
 /// Errors returned by StartQuery
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartQueryError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -2958,6 +3357,8 @@ pub enum StartQueryError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartQueryError {
@@ -2965,22 +3366,22 @@ impl StartQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartQueryError::InvalidParameter(err.msg))
+                    return RusotoError::service(StartQueryError::InvalidParameter(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartQueryError::LimitExceeded(err.msg))
+                    return RusotoError::service(StartQueryError::LimitExceeded(err.msg), &res)
                 }
                 "MalformedQueryException" => {
-                    return RusotoError::Service(StartQueryError::MalformedQuery(err.msg))
+                    return RusotoError::service(StartQueryError::MalformedQuery(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartQueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StartQueryError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartQueryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(StartQueryError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartQueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2995,12 +3396,14 @@ impl fmt::Display for StartQueryError {
             StartQueryError::MalformedQuery(ref cause) => write!(f, "{}", cause),
             StartQueryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartQueryError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            StartQueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartQueryError {}
 /// Errors returned by StopQuery
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopQueryError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
@@ -3008,6 +3411,8 @@ pub enum StopQueryError {
     ResourceNotFound(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopQueryError {
@@ -3015,16 +3420,16 @@ impl StopQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopQueryError::InvalidParameter(err.msg))
+                    return RusotoError::service(StopQueryError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopQueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StopQueryError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopQueryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(StopQueryError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopQueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3037,17 +3442,21 @@ impl fmt::Display for StopQueryError {
             StopQueryError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             StopQueryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopQueryError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            StopQueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopQueryError {}
 /// Errors returned by TagLogGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagLogGroupError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
     /// <p>The specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagLogGroupError {
@@ -3055,13 +3464,13 @@ impl TagLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagLogGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagLogGroupError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagLogGroupError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagLogGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3073,17 +3482,21 @@ impl fmt::Display for TagLogGroupError {
         match *self {
             TagLogGroupError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             TagLogGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagLogGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagLogGroupError {}
 /// Errors returned by TestMetricFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestMetricFilterError {
     /// <p>A parameter is specified incorrectly.</p>
     InvalidParameter(String),
     /// <p>The service cannot complete the request.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestMetricFilterError {
@@ -3091,13 +3504,19 @@ impl TestMetricFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TestMetricFilterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        TestMetricFilterError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TestMetricFilterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        TestMetricFilterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TestMetricFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3109,15 +3528,19 @@ impl fmt::Display for TestMetricFilterError {
         match *self {
             TestMetricFilterError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             TestMetricFilterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            TestMetricFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TestMetricFilterError {}
 /// Errors returned by UntagLogGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagLogGroupError {
     /// <p>The specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagLogGroupError {
@@ -3125,10 +3548,13 @@ impl UntagLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagLogGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagLogGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3139,6 +3565,7 @@ impl fmt::Display for UntagLogGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UntagLogGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagLogGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3391,6 +3818,7 @@ impl CloudWatchLogsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudWatchLogsClient {
         CloudWatchLogsClient {
             client: Client::shared(),
@@ -3416,1073 +3844,2760 @@ impl CloudWatchLogsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchLogsClient {
         CloudWatchLogsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudWatchLogsClient> {
+        rusoto_core::ClientBuilder::new(CloudWatchLogsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudWatchLogsClient {
+        CloudWatchLogsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudWatchLogs for CloudWatchLogsClient {
-    /// <p>Associates the specified AWS Key Management Service (AWS KMS) customer master key (CMK) with the specified log group.</p> <p>Associating an AWS KMS CMK with a log group overrides any existing associations between the log group and a CMK. After a CMK is associated with a log group, all newly ingested data for the log group is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <note> <p> <b>Important:</b> CloudWatch Logs supports only symmetric CMKs. Do not use an associate an asymmetric CMK with your log group. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a>.</p> </note> <p>Note that it can take up to 5 minutes for this operation to take effect.</p> <p>If you attempt to associate a CMK with a log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p>
-    async fn associate_kms_key(
+impl CloudWatchLogsClient {
+    /// Calls `associate_kms_key`, also returning the metadata of the response.
+    pub async fn associate_kms_key_with_metadata(
         &self,
         input: AssociateKmsKeyRequest,
-    ) -> Result<(), RusotoError<AssociateKmsKeyError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<AssociateKmsKeyError>> {
+        let request = self.build_associate_kms_key_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateKmsKeyError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_kms_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_kms_key(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateKmsKeyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_kms_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_kms_key_request(&self, input: AssociateKmsKeyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("AssociateKmsKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.AssociateKmsKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_export_task`, also returning the metadata of the response.
+    pub async fn cancel_export_task_with_metadata(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CancelExportTaskError>> {
+        let request = self.build_cancel_export_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateKmsKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelExportTaskError::from_response(response))
         }
     }
 
-    /// <p>Cancels the specified export task.</p> <p>The task must be in the <code>PENDING</code> or <code>RUNNING</code> state.</p>
-    async fn cancel_export_task(
+    /// Presigns the request `cancel_export_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_export_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CancelExportTaskRequest,
-    ) -> Result<(), RusotoError<CancelExportTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_export_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_export_task_request(&self, input: CancelExportTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CancelExportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.CancelExportTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_export_task`, also returning the metadata of the response.
+    pub async fn create_export_task_with_metadata(
+        &self,
+        input: CreateExportTaskRequest,
+    ) -> Result<(CreateExportTaskResponse, ResponseMetadata), RusotoError<CreateExportTaskError>>
+    {
+        let request = self.build_create_export_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateExportTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelExportTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateExportTaskError::from_response(response))
         }
     }
 
-    /// <p>Creates an export task, which allows you to efficiently export data from a log group to an Amazon S3 bucket.</p> <p>This is an asynchronous call. If all the required information is provided, this operation initiates an export task and responds with the ID of the task. After the task has started, you can use <a>DescribeExportTasks</a> to get the status of the export task. Each account can only have one active (<code>RUNNING</code> or <code>PENDING</code>) export task at a time. To cancel an export task, use <a>CancelExportTask</a>.</p> <p>You can export logs from multiple log groups or multiple time ranges to the same S3 bucket. To separate out log data for each export task, you can specify a prefix to be used as the Amazon S3 key prefix for all exported objects.</p> <p>Exporting to S3 buckets that are encrypted with AES-256 is supported. Exporting to S3 buckets encrypted with SSE-KMS is not supported. </p>
-    async fn create_export_task(
+    /// Presigns the request `create_export_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_export_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateExportTaskRequest,
-    ) -> Result<CreateExportTaskResponse, RusotoError<CreateExportTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_export_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_export_task_request(&self, input: CreateExportTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CreateExportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.CreateExportTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_log_group`, also returning the metadata of the response.
+    pub async fn create_log_group_with_metadata(
+        &self,
+        input: CreateLogGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateLogGroupError>> {
+        let request = self.build_create_log_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateExportTaskResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateExportTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLogGroupError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a log group with the specified name.</p> <p>You can create up to 20,000 log groups per account.</p> <p>You must use the following guidelines when naming a log group:</p> <ul> <li> <p>Log group names must be unique within a region for an AWS account.</p> </li> <li> <p>Log group names can be between 1 and 512 characters long.</p> </li> <li> <p>Log group names consist of the following characters: a-z, A-Z, 0-9, &#39;_&#39; (underscore), &#39;-&#39; (hyphen), &#39;/&#39; (forward slash), &#39;.&#39; (period), and &#39;#&#39; (number sign)</p> </li> </ul> <p>If you associate a AWS Key Management Service (AWS KMS) customer master key (CMK) with the log group, ingested data is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <p>If you attempt to associate a CMK with the log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p> <note> <p> <b>Important:</b> CloudWatch Logs supports only symmetric CMKs. Do not associate an asymmetric CMK with your log group. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a>.</p> </note></p>
-    async fn create_log_group(
+    /// Presigns the request `create_log_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_log_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLogGroupRequest,
-    ) -> Result<(), RusotoError<CreateLogGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_log_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_log_group_request(&self, input: CreateLogGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CreateLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.CreateLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_log_stream`, also returning the metadata of the response.
+    pub async fn create_log_stream_with_metadata(
+        &self,
+        input: CreateLogStreamRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateLogStreamError>> {
+        let request = self.build_create_log_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLogGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLogStreamError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a log stream for the specified log group.</p> <p>There is no limit on the number of log streams that you can create for a log group. There is a limit of 50 TPS on <code>CreateLogStream</code> operations, after which transactions are throttled.</p> <p>You must use the following guidelines when naming a log stream:</p> <ul> <li> <p>Log stream names must be unique within the log group.</p> </li> <li> <p>Log stream names can be between 1 and 512 characters long.</p> </li> <li> <p>The &#39;:&#39; (colon) and &#39;*&#39; (asterisk) characters are not allowed.</p> </li> </ul></p>
-    async fn create_log_stream(
+    /// Presigns the request `create_log_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_log_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLogStreamRequest,
-    ) -> Result<(), RusotoError<CreateLogStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_log_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_log_stream_request(&self, input: CreateLogStreamRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CreateLogStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.CreateLogStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_destination`, also returning the metadata of the response.
+    pub async fn delete_destination_with_metadata(
+        &self,
+        input: DeleteDestinationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDestinationError>> {
+        let request = self.build_delete_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLogStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDestinationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified destination, and eventually disables all the subscription filters that publish to it. This operation does not delete the physical resource encapsulated by the destination.</p>
-    async fn delete_destination(
+    /// Presigns the request `delete_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDestinationRequest,
-    ) -> Result<(), RusotoError<DeleteDestinationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_destination_request(&self, input: DeleteDestinationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteDestination");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteDestination");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_log_group`, also returning the metadata of the response.
+    pub async fn delete_log_group_with_metadata(
+        &self,
+        input: DeleteLogGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteLogGroupError>> {
+        let request = self.build_delete_log_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDestinationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLogGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified log group and permanently deletes all the archived log events associated with the log group.</p>
-    async fn delete_log_group(
+    /// Presigns the request `delete_log_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_log_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLogGroupRequest,
-    ) -> Result<(), RusotoError<DeleteLogGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_log_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_log_group_request(&self, input: DeleteLogGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_log_stream`, also returning the metadata of the response.
+    pub async fn delete_log_stream_with_metadata(
+        &self,
+        input: DeleteLogStreamRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteLogStreamError>> {
+        let request = self.build_delete_log_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLogGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLogStreamError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified log stream and permanently deletes all the archived log events associated with the log stream.</p>
-    async fn delete_log_stream(
+    /// Presigns the request `delete_log_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_log_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLogStreamRequest,
-    ) -> Result<(), RusotoError<DeleteLogStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_log_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_log_stream_request(&self, input: DeleteLogStreamRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteLogStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteLogStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_metric_filter`, also returning the metadata of the response.
+    pub async fn delete_metric_filter_with_metadata(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteMetricFilterError>> {
+        let request = self.build_delete_metric_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLogStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteMetricFilterError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified metric filter.</p>
-    async fn delete_metric_filter(
+    /// Presigns the request `delete_metric_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_metric_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMetricFilterRequest,
-    ) -> Result<(), RusotoError<DeleteMetricFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_metric_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_metric_filter_request(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteMetricFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteMetricFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_resource_policy`, also returning the metadata of the response.
+    pub async fn delete_resource_policy_with_metadata(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteResourcePolicyError>> {
+        let request = self.build_delete_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMetricFilterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes a resource policy from this account. This revokes the access of the identities in that policy to put log events to this account.</p>
-    async fn delete_resource_policy(
+    /// Presigns the request `delete_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourcePolicyRequest,
-    ) -> Result<(), RusotoError<DeleteResourcePolicyError>> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_policy_request(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_retention_policy`, also returning the metadata of the response.
+    pub async fn delete_retention_policy_with_metadata(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRetentionPolicyError>> {
+        let request = self.build_delete_retention_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRetentionPolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified retention policy.</p> <p>Log events do not expire if they belong to log groups without a retention policy.</p>
-    async fn delete_retention_policy(
+    /// Presigns the request `delete_retention_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_retention_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRetentionPolicyRequest,
-    ) -> Result<(), RusotoError<DeleteRetentionPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_retention_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_retention_policy_request(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteRetentionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_subscription_filter`, also returning the metadata of the response.
+    pub async fn delete_subscription_filter_with_metadata(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSubscriptionFilterError>> {
+        let request = self.build_delete_subscription_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRetentionPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSubscriptionFilterError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified subscription filter.</p>
-    async fn delete_subscription_filter(
+    /// Presigns the request `delete_subscription_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_subscription_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSubscriptionFilterRequest,
-    ) -> Result<(), RusotoError<DeleteSubscriptionFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_subscription_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_subscription_filter_request(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteSubscriptionFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DeleteSubscriptionFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_destinations`, also returning the metadata of the response.
+    pub async fn describe_destinations_with_metadata(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> Result<
+        (DescribeDestinationsResponse, ResponseMetadata),
+        RusotoError<DescribeDestinationsError>,
+    > {
+        let request = self.build_describe_destinations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDestinationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSubscriptionFilterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDestinationsError::from_response(response))
         }
     }
 
-    /// <p>Lists all your destinations. The results are ASCII-sorted by destination name.</p>
-    async fn describe_destinations(
+    /// Presigns the request `describe_destinations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_destinations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDestinationsRequest,
-    ) -> Result<DescribeDestinationsResponse, RusotoError<DescribeDestinationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_destinations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_destinations_request(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeDestinations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeDestinations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_export_tasks`, also returning the metadata of the response.
+    pub async fn describe_export_tasks_with_metadata(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<
+        (DescribeExportTasksResponse, ResponseMetadata),
+        RusotoError<DescribeExportTasksError>,
+    > {
+        let request = self.build_describe_export_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDestinationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeExportTasksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDestinationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeExportTasksError::from_response(response))
         }
     }
 
-    /// <p>Lists the specified export tasks. You can list all your export tasks or filter the results based on task ID or task status.</p>
-    async fn describe_export_tasks(
+    /// Presigns the request `describe_export_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_export_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeExportTasksRequest,
-    ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_export_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_export_tasks_request(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeExportTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeExportTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_log_groups`, also returning the metadata of the response.
+    pub async fn describe_log_groups_with_metadata(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> Result<(DescribeLogGroupsResponse, ResponseMetadata), RusotoError<DescribeLogGroupsError>>
+    {
+        let request = self.build_describe_log_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeExportTasksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLogGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeExportTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLogGroupsError::from_response(response))
         }
     }
 
-    /// <p>Lists the specified log groups. You can list all your log groups or filter the results by prefix. The results are ASCII-sorted by log group name.</p>
-    async fn describe_log_groups(
+    /// Presigns the request `describe_log_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_log_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLogGroupsRequest,
-    ) -> Result<DescribeLogGroupsResponse, RusotoError<DescribeLogGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_log_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_log_groups_request(&self, input: DescribeLogGroupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeLogGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeLogGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_log_streams`, also returning the metadata of the response.
+    pub async fn describe_log_streams_with_metadata(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> Result<(DescribeLogStreamsResponse, ResponseMetadata), RusotoError<DescribeLogStreamsError>>
+    {
+        let request = self.build_describe_log_streams_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLogGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLogStreamsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLogGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLogStreamsError::from_response(response))
         }
     }
 
-    /// <p>Lists the log streams for the specified log group. You can list all the log streams or filter the results by prefix. You can also control how the results are ordered.</p> <p>This operation has a limit of five transactions per second, after which transactions are throttled.</p>
-    async fn describe_log_streams(
+    /// Presigns the request `describe_log_streams` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_log_streams(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLogStreamsRequest,
-    ) -> Result<DescribeLogStreamsResponse, RusotoError<DescribeLogStreamsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_log_streams_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_log_streams_request(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeLogStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeLogStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_metric_filters`, also returning the metadata of the response.
+    pub async fn describe_metric_filters_with_metadata(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> Result<
+        (DescribeMetricFiltersResponse, ResponseMetadata),
+        RusotoError<DescribeMetricFiltersError>,
+    > {
+        let request = self.build_describe_metric_filters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLogStreamsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMetricFiltersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLogStreamsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMetricFiltersError::from_response(response))
         }
     }
 
-    /// <p>Lists the specified metric filters. You can list all the metric filters or filter the results by log name, prefix, metric name, or metric namespace. The results are ASCII-sorted by filter name.</p>
-    async fn describe_metric_filters(
+    /// Presigns the request `describe_metric_filters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_metric_filters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMetricFiltersRequest,
-    ) -> Result<DescribeMetricFiltersResponse, RusotoError<DescribeMetricFiltersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_metric_filters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_metric_filters_request(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeMetricFilters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeMetricFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_queries`, also returning the metadata of the response.
+    pub async fn describe_queries_with_metadata(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> Result<(DescribeQueriesResponse, ResponseMetadata), RusotoError<DescribeQueriesError>>
+    {
+        let request = self.build_describe_queries_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMetricFiltersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeQueriesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMetricFiltersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeQueriesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of CloudWatch Logs Insights queries that are scheduled, executing, or have been executed recently in this account. You can request all queries, or limit it to queries of a specific log group or queries with a certain status.</p>
-    async fn describe_queries(
+    /// Presigns the request `describe_queries` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_queries(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeQueriesRequest,
-    ) -> Result<DescribeQueriesResponse, RusotoError<DescribeQueriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_queries_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_queries_request(&self, input: DescribeQueriesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeQueries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeQueries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_resource_policies`, also returning the metadata of the response.
+    pub async fn describe_resource_policies_with_metadata(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> Result<
+        (DescribeResourcePoliciesResponse, ResponseMetadata),
+        RusotoError<DescribeResourcePoliciesError>,
+    > {
+        let request = self.build_describe_resource_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeQueriesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeResourcePoliciesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeQueriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeResourcePoliciesError::from_response(response))
         }
     }
 
-    /// <p>Lists the resource policies in this account.</p>
-    async fn describe_resource_policies(
+    /// Presigns the request `describe_resource_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_resource_policies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeResourcePoliciesRequest,
-    ) -> Result<DescribeResourcePoliciesResponse, RusotoError<DescribeResourcePoliciesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_resource_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_resource_policies_request(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeResourcePolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeResourcePolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_subscription_filters`, also returning the metadata of the response.
+    pub async fn describe_subscription_filters_with_metadata(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> Result<
+        (DescribeSubscriptionFiltersResponse, ResponseMetadata),
+        RusotoError<DescribeSubscriptionFiltersError>,
+    > {
+        let request = self.build_describe_subscription_filters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeResourcePoliciesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSubscriptionFiltersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeResourcePoliciesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSubscriptionFiltersError::from_response(response))
         }
     }
 
-    /// <p>Lists the subscription filters for the specified log group. You can list all the subscription filters or filter the results by prefix. The results are ASCII-sorted by filter name.</p>
-    async fn describe_subscription_filters(
+    /// Presigns the request `describe_subscription_filters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_subscription_filters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSubscriptionFiltersRequest,
-    ) -> Result<DescribeSubscriptionFiltersResponse, RusotoError<DescribeSubscriptionFiltersError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_subscription_filters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_subscription_filters_request(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeSubscriptionFilters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DescribeSubscriptionFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_kms_key`, also returning the metadata of the response.
+    pub async fn disassociate_kms_key_with_metadata(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisassociateKmsKeyError>> {
+        let request = self.build_disassociate_kms_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSubscriptionFiltersResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSubscriptionFiltersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateKmsKeyError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the associated AWS Key Management Service (AWS KMS) customer master key (CMK) from the specified log group.</p> <p>After the AWS KMS CMK is disassociated from the log group, AWS CloudWatch Logs stops encrypting newly ingested data for the log group. All previously ingested data remains encrypted, and AWS CloudWatch Logs requires permissions for the CMK whenever the encrypted data is requested.</p> <p>Note that it can take up to 5 minutes for this operation to take effect.</p>
-    async fn disassociate_kms_key(
+    /// Presigns the request `disassociate_kms_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_kms_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateKmsKeyRequest,
-    ) -> Result<(), RusotoError<DisassociateKmsKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_kms_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_kms_key_request(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DisassociateKmsKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.DisassociateKmsKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `filter_log_events`, also returning the metadata of the response.
+    pub async fn filter_log_events_with_metadata(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> Result<(FilterLogEventsResponse, ResponseMetadata), RusotoError<FilterLogEventsError>>
+    {
+        let request = self.build_filter_log_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<FilterLogEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateKmsKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(FilterLogEventsError::from_response(response))
         }
     }
 
-    /// <p>Lists log events from the specified log group. You can list all the log events or filter the results using a filter pattern, a time range, and the name of the log stream.</p> <p>By default, this operation returns as many log events as can fit in 1 MB (up to 10,000 log events), or all the events found within the time range that you specify. If the results include a token, then there are more log events available, and you can get additional results by specifying the token in a subsequent call.</p>
-    async fn filter_log_events(
+    /// Presigns the request `filter_log_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_filter_log_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: FilterLogEventsRequest,
-    ) -> Result<FilterLogEventsResponse, RusotoError<FilterLogEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_filter_log_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_filter_log_events_request(&self, input: FilterLogEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("FilterLogEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.FilterLogEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_log_events`, also returning the metadata of the response.
+    pub async fn get_log_events_with_metadata(
+        &self,
+        input: GetLogEventsRequest,
+    ) -> Result<(GetLogEventsResponse, ResponseMetadata), RusotoError<GetLogEventsError>> {
+        let request = self.build_get_log_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<FilterLogEventsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLogEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(FilterLogEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLogEventsError::from_response(response))
         }
     }
 
-    /// <p>Lists log events from the specified log stream. You can list all the log events or filter using a time range.</p> <p>By default, this operation returns as many log events as can fit in a response size of 1MB (up to 10,000 log events). You can get additional log events by specifying one of the tokens in a subsequent call.</p>
-    async fn get_log_events(
+    /// Presigns the request `get_log_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_log_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLogEventsRequest,
-    ) -> Result<GetLogEventsResponse, RusotoError<GetLogEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_log_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_log_events_request(&self, input: GetLogEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetLogEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.GetLogEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_log_group_fields`, also returning the metadata of the response.
+    pub async fn get_log_group_fields_with_metadata(
+        &self,
+        input: GetLogGroupFieldsRequest,
+    ) -> Result<(GetLogGroupFieldsResponse, ResponseMetadata), RusotoError<GetLogGroupFieldsError>>
+    {
+        let request = self.build_get_log_group_fields_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetLogEventsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLogGroupFieldsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLogEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLogGroupFieldsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the fields that are included in log events in the specified log group, along with the percentage of log events that contain each field. The search is limited to a time period that you specify.</p> <p>In the results, fields that start with @ are fields generated by CloudWatch Logs. For example, <code>@timestamp</code> is the timestamp of each log event.</p> <p>The response results are sorted by the frequency percentage, starting with the highest percentage.</p>
-    async fn get_log_group_fields(
+    /// Presigns the request `get_log_group_fields` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_log_group_fields(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLogGroupFieldsRequest,
-    ) -> Result<GetLogGroupFieldsResponse, RusotoError<GetLogGroupFieldsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_log_group_fields_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_log_group_fields_request(&self, input: GetLogGroupFieldsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetLogGroupFields");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.GetLogGroupFields");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_log_record`, also returning the metadata of the response.
+    pub async fn get_log_record_with_metadata(
+        &self,
+        input: GetLogRecordRequest,
+    ) -> Result<(GetLogRecordResponse, ResponseMetadata), RusotoError<GetLogRecordError>> {
+        let request = self.build_get_log_record_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLogGroupFieldsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLogRecordResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLogGroupFieldsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLogRecordError::from_response(response))
         }
     }
 
-    /// <p>Retrieves all the fields and values of a single log event. All fields are retrieved, even if the original query that produced the <code>logRecordPointer</code> retrieved only a subset of fields. Fields are returned as field name/field value pairs.</p> <p>Additionally, the entire unparsed log event is returned within <code>@message</code>.</p>
-    async fn get_log_record(
+    /// Presigns the request `get_log_record` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_log_record(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLogRecordRequest,
-    ) -> Result<GetLogRecordResponse, RusotoError<GetLogRecordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_log_record_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_log_record_request(&self, input: GetLogRecordRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetLogRecord");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.GetLogRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_query_results`, also returning the metadata of the response.
+    pub async fn get_query_results_with_metadata(
+        &self,
+        input: GetQueryResultsRequest,
+    ) -> Result<(GetQueryResultsResponse, ResponseMetadata), RusotoError<GetQueryResultsError>>
+    {
+        let request = self.build_get_query_results_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetLogRecordResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetQueryResultsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLogRecordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetQueryResultsError::from_response(response))
         }
     }
 
-    /// <p>Returns the results from the specified query.</p> <p>Only the fields requested in the query are returned, along with a <code>@ptr</code> field which is the identifier for the log record. You can use the value of <code>@ptr</code> in a operation to get the full log record.</p> <p> <code>GetQueryResults</code> does not start a query execution. To run a query, use .</p> <p>If the value of the <code>Status</code> field in the output is <code>Running</code>, this operation returns only partial results. If you see a value of <code>Scheduled</code> or <code>Running</code> for the status, you can retry the operation later to see the final results. </p>
-    async fn get_query_results(
+    /// Presigns the request `get_query_results` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_query_results(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetQueryResultsRequest,
-    ) -> Result<GetQueryResultsResponse, RusotoError<GetQueryResultsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_query_results_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_query_results_request(&self, input: GetQueryResultsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetQueryResults");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.GetQueryResults");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_log_group`, also returning the metadata of the response.
+    pub async fn list_tags_log_group_with_metadata(
+        &self,
+        input: ListTagsLogGroupRequest,
+    ) -> Result<(ListTagsLogGroupResponse, ResponseMetadata), RusotoError<ListTagsLogGroupError>>
+    {
+        let request = self.build_list_tags_log_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetQueryResultsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsLogGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetQueryResultsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsLogGroupError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for the specified log group.</p>
-    async fn list_tags_log_group(
+    /// Presigns the request `list_tags_log_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_log_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsLogGroupRequest,
-    ) -> Result<ListTagsLogGroupResponse, RusotoError<ListTagsLogGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_log_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_log_group_request(&self, input: ListTagsLogGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("ListTagsLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.ListTagsLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_destination`, also returning the metadata of the response.
+    pub async fn put_destination_with_metadata(
+        &self,
+        input: PutDestinationRequest,
+    ) -> Result<(PutDestinationResponse, ResponseMetadata), RusotoError<PutDestinationError>> {
+        let request = self.build_put_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsLogGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutDestinationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsLogGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutDestinationError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a destination. This operation is used only to create destinations for cross-account subscriptions.</p> <p>A destination encapsulates a physical resource (such as an Amazon Kinesis stream) and enables you to subscribe to a real-time stream of log events for a different account, ingested using <a>PutLogEvents</a>.</p> <p>Through an access policy, a destination controls what is written to it. By default, <code>PutDestination</code> does not set any access policy with the destination, which means a cross-account user cannot call <a>PutSubscriptionFilter</a> against this destination. To enable this, the destination owner must call <a>PutDestinationPolicy</a> after <code>PutDestination</code>.</p>
-    async fn put_destination(
+    /// Presigns the request `put_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutDestinationRequest,
-    ) -> Result<PutDestinationResponse, RusotoError<PutDestinationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_destination_request(&self, input: PutDestinationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutDestination");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutDestination");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_destination_policy`, also returning the metadata of the response.
+    pub async fn put_destination_policy_with_metadata(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutDestinationPolicyError>> {
+        let request = self.build_put_destination_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutDestinationResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutDestinationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutDestinationPolicyError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates an access policy associated with an existing destination. An access policy is an <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies_overview.html">IAM policy document</a> that is used to authorize claims to register a subscription filter against a given destination.</p>
-    async fn put_destination_policy(
+    /// Presigns the request `put_destination_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_destination_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutDestinationPolicyRequest,
-    ) -> Result<(), RusotoError<PutDestinationPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_destination_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_destination_policy_request(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutDestinationPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutDestinationPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_log_events`, also returning the metadata of the response.
+    pub async fn put_log_events_with_metadata(
+        &self,
+        input: PutLogEventsRequest,
+    ) -> Result<(PutLogEventsResponse, ResponseMetadata), RusotoError<PutLogEventsError>> {
+        let request = self.build_put_log_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutLogEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutDestinationPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutLogEventsError::from_response(response))
         }
     }
 
-    /// <p>Uploads a batch of log events to the specified log stream.</p> <p>You must include the sequence token obtained from the response of the previous call. An upload in a newly created log stream does not require a sequence token. You can also get the sequence token in the <code>expectedSequenceToken</code> field from <code>InvalidSequenceTokenException</code>. If you call <code>PutLogEvents</code> twice within a narrow time period using the same value for <code>sequenceToken</code>, both calls may be successful, or one may be rejected.</p> <p>The batch of events must satisfy the following constraints:</p> <ul> <li> <p>The maximum batch size is 1,048,576 bytes, and this size is calculated as the sum of all event messages in UTF-8, plus 26 bytes for each log event.</p> </li> <li> <p>None of the log events in the batch can be more than 2 hours in the future.</p> </li> <li> <p>None of the log events in the batch can be older than 14 days or older than the retention period of the log group.</p> </li> <li> <p>The log events in the batch must be in chronological ordered by their timestamp. The timestamp is the time the event occurred, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. (In AWS Tools for PowerShell and the AWS SDK for .NET, the timestamp is specified in .NET format: yyyy-mm-ddThh:mm:ss. For example, 2017-09-15T13:45:30.) </p> </li> <li> <p>A batch of log events in a single request cannot span more than 24 hours. Otherwise, the operation fails.</p> </li> <li> <p>The maximum number of log events in a batch is 10,000.</p> </li> <li> <p>There is a quota of 5 requests per second per log stream. Additional requests are throttled. This quota can't be changed.</p> </li> </ul> <p>If a call to PutLogEvents returns "UnrecognizedClientException" the most likely cause is an invalid AWS access key ID or secret key. </p>
-    async fn put_log_events(
+    /// Presigns the request `put_log_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_log_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutLogEventsRequest,
-    ) -> Result<PutLogEventsResponse, RusotoError<PutLogEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_log_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_log_events_request(&self, input: PutLogEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutLogEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutLogEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_metric_filter`, also returning the metadata of the response.
+    pub async fn put_metric_filter_with_metadata(
+        &self,
+        input: PutMetricFilterRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutMetricFilterError>> {
+        let request = self.build_put_metric_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutLogEventsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutLogEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutMetricFilterError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a metric filter and associates it with the specified log group. Metric filters allow you to configure rules to extract metric data from log events ingested through <a>PutLogEvents</a>.</p> <p>The maximum number of metric filters that can be associated with a log group is 100.</p>
-    async fn put_metric_filter(
+    /// Presigns the request `put_metric_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_metric_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutMetricFilterRequest,
-    ) -> Result<(), RusotoError<PutMetricFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_metric_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_metric_filter_request(&self, input: PutMetricFilterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutMetricFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutMetricFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_resource_policy`, also returning the metadata of the response.
+    pub async fn put_resource_policy_with_metadata(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<(PutResourcePolicyResponse, ResponseMetadata), RusotoError<PutResourcePolicyError>>
+    {
+        let request = self.build_put_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutResourcePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutMetricFilterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a resource policy allowing other AWS services to put log events to this account, such as Amazon Route 53. An account can have up to 10 resource policies per region.</p>
-    async fn put_resource_policy(
+    /// Presigns the request `put_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutResourcePolicyRequest,
-    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_resource_policy_request(&self, input: PutResourcePolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_retention_policy`, also returning the metadata of the response.
+    pub async fn put_retention_policy_with_metadata(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutRetentionPolicyError>> {
+        let request = self.build_put_retention_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutResourcePolicyResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRetentionPolicyError::from_response(response))
         }
     }
 
-    /// <p>Sets the retention of the specified log group. A retention policy allows you to configure the number of days for which to retain log events in the specified log group.</p>
-    async fn put_retention_policy(
+    /// Presigns the request `put_retention_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_retention_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRetentionPolicyRequest,
-    ) -> Result<(), RusotoError<PutRetentionPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_retention_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_retention_policy_request(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutRetentionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_subscription_filter`, also returning the metadata of the response.
+    pub async fn put_subscription_filter_with_metadata(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutSubscriptionFilterError>> {
+        let request = self.build_put_subscription_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRetentionPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutSubscriptionFilterError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a subscription filter and associates it with the specified log group. Subscription filters allow you to subscribe to a real-time stream of log events ingested through <a>PutLogEvents</a> and have them delivered to a specific destination. Currently, the supported destinations are:</p> <ul> <li> <p>An Amazon Kinesis stream belonging to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>A logical destination that belongs to a different account, for cross-account delivery.</p> </li> <li> <p>An Amazon Kinesis Firehose delivery stream that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>An AWS Lambda function that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> </ul> <p>There can only be one subscription filter associated with a log group. If you are updating an existing filter, you must specify the correct name in <code>filterName</code>. Otherwise, the call fails because you cannot associate a second filter with a log group.</p>
-    async fn put_subscription_filter(
+    /// Presigns the request `put_subscription_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_subscription_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutSubscriptionFilterRequest,
-    ) -> Result<(), RusotoError<PutSubscriptionFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_subscription_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_subscription_filter_request(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutSubscriptionFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.PutSubscriptionFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_query`, also returning the metadata of the response.
+    pub async fn start_query_with_metadata(
+        &self,
+        input: StartQueryRequest,
+    ) -> Result<(StartQueryResponse, ResponseMetadata), RusotoError<StartQueryError>> {
+        let request = self.build_start_query_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartQueryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutSubscriptionFilterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartQueryError::from_response(response))
         }
     }
 
-    /// <p>Schedules a query of a log group using CloudWatch Logs Insights. You specify the log group and time range to query, and the query string to use.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/CWL_QuerySyntax.html">CloudWatch Logs Insights Query Syntax</a>.</p> <p>Queries time out after 15 minutes of execution. If your queries are timing out, reduce the time range being searched, or partition your query into a number of queries.</p>
-    async fn start_query(
+    /// Presigns the request `start_query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_query(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartQueryRequest,
-    ) -> Result<StartQueryResponse, RusotoError<StartQueryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_query_request(&self, input: StartQueryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("StartQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.StartQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_query`, also returning the metadata of the response.
+    pub async fn stop_query_with_metadata(
+        &self,
+        input: StopQueryRequest,
+    ) -> Result<(StopQueryResponse, ResponseMetadata), RusotoError<StopQueryError>> {
+        let request = self.build_stop_query_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartQueryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopQueryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartQueryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopQueryError::from_response(response))
         }
     }
 
-    /// <p>Stops a CloudWatch Logs Insights query that is in progress. If the query has already ended, the operation returns an error indicating that the specified query is not running.</p>
-    async fn stop_query(
+    /// Presigns the request `stop_query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_query(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopQueryRequest,
-    ) -> Result<StopQueryResponse, RusotoError<StopQueryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_query_request(&self, input: StopQueryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("StopQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.StopQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_log_group`, also returning the metadata of the response.
+    pub async fn tag_log_group_with_metadata(
+        &self,
+        input: TagLogGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagLogGroupError>> {
+        let request = self.build_tag_log_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopQueryResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopQueryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagLogGroupError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates the specified tags for the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To remove tags, use <a>UntagLogGroup</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/log-group-tagging.html">Tag Log Groups in Amazon CloudWatch Logs</a> in the <i>Amazon CloudWatch Logs User Guide</i>.</p>
-    async fn tag_log_group(
+    /// Presigns the request `tag_log_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_log_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagLogGroupRequest,
-    ) -> Result<(), RusotoError<TagLogGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_log_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_log_group_request(&self, input: TagLogGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("TagLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.TagLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `test_metric_filter`, also returning the metadata of the response.
+    pub async fn test_metric_filter_with_metadata(
+        &self,
+        input: TestMetricFilterRequest,
+    ) -> Result<(TestMetricFilterResponse, ResponseMetadata), RusotoError<TestMetricFilterError>>
+    {
+        let request = self.build_test_metric_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TestMetricFilterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagLogGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TestMetricFilterError::from_response(response))
         }
     }
 
-    /// <p>Tests the filter pattern of a metric filter against a sample of log event messages. You can use this operation to validate the correctness of a metric filter pattern.</p>
-    async fn test_metric_filter(
+    /// Presigns the request `test_metric_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_metric_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TestMetricFilterRequest,
-    ) -> Result<TestMetricFilterResponse, RusotoError<TestMetricFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_metric_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_metric_filter_request(&self, input: TestMetricFilterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("TestMetricFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.TestMetricFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_log_group`, also returning the metadata of the response.
+    pub async fn untag_log_group_with_metadata(
+        &self,
+        input: UntagLogGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagLogGroupError>> {
+        let request = self.build_untag_log_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<TestMetricFilterResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TestMetricFilterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagLogGroupError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To add tags, use <a>UntagLogGroup</a>.</p>
-    async fn untag_log_group(
+    /// Presigns the request `untag_log_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_log_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagLogGroupRequest,
-    ) -> Result<(), RusotoError<UntagLogGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_log_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_log_group_request(&self, input: UntagLogGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("UntagLogGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Logs_20140328.UntagLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeDestinations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn describe_destinations_pages(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeDestinationsResponse, RusotoError<DescribeDestinationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input| async move {
+                client.describe_destinations(input).await
+            },
+            |input: &DescribeDestinationsRequest, output: &DescribeDestinationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeDestinationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `destinations` of every page of `DescribeDestinations` results for `input`, as `describe_destinations_pages` requests them.
+    pub fn describe_destinations_stream(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> impl futures::Stream<Item = Result<Destination, RusotoError<DescribeDestinationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_destinations_pages(input), |page| {
+            page.destinations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeExportTasks` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn describe_export_tasks_pages(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input| async move {
+                client.describe_export_tasks(input).await
+            },
+            |input: &DescribeExportTasksRequest, output: &DescribeExportTasksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeExportTasksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `exportTasks` of every page of `DescribeExportTasks` results for `input`, as `describe_export_tasks_pages` requests them.
+    pub fn describe_export_tasks_stream(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> impl futures::Stream<Item = Result<ExportTask, RusotoError<DescribeExportTasksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_export_tasks_pages(input), |page| {
+            page.export_tasks.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeLogGroups` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn describe_log_groups_pages(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeLogGroupsResponse, RusotoError<DescribeLogGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input| async move { client.describe_log_groups(input).await },
+            |input: &DescribeLogGroupsRequest, output: &DescribeLogGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeLogGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `logGroups` of every page of `DescribeLogGroups` results for `input`, as `describe_log_groups_pages` requests them.
+    pub fn describe_log_groups_stream(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<LogGroup, RusotoError<DescribeLogGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_log_groups_pages(input), |page| {
+            page.log_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeLogStreams` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn describe_log_streams_pages(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeLogStreamsResponse, RusotoError<DescribeLogStreamsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input| async move {
+                client.describe_log_streams(input).await
+            },
+            |input: &DescribeLogStreamsRequest, output: &DescribeLogStreamsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeLogStreamsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `logStreams` of every page of `DescribeLogStreams` results for `input`, as `describe_log_streams_pages` requests them.
+    pub fn describe_log_streams_stream(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> impl futures::Stream<Item = Result<LogStream, RusotoError<DescribeLogStreamsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_log_streams_pages(input), |page| {
+            page.log_streams.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeMetricFilters` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn describe_metric_filters_pages(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeMetricFiltersResponse, RusotoError<DescribeMetricFiltersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input| async move {
+                client.describe_metric_filters(input).await
+            },
+            |input: &DescribeMetricFiltersRequest, output: &DescribeMetricFiltersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMetricFiltersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `metricFilters` of every page of `DescribeMetricFilters` results for `input`, as `describe_metric_filters_pages` requests them.
+    pub fn describe_metric_filters_stream(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> impl futures::Stream<Item = Result<MetricFilter, RusotoError<DescribeMetricFiltersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_metric_filters_pages(input), |page| {
+            page.metric_filters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeQueries` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_queries_pages(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeQueriesResponse, RusotoError<DescribeQueriesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input| async move { client.describe_queries(input).await },
+            |input: &DescribeQueriesRequest, output: &DescribeQueriesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeQueriesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `queries` of every page of `DescribeQueries` results for `input`, as `describe_queries_pages` requests them.
+    pub fn describe_queries_stream(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> impl futures::Stream<Item = Result<QueryInfo, RusotoError<DescribeQueriesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_queries_pages(input), |page| {
+            page.queries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeResourcePolicies` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn describe_resource_policies_pages(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeResourcePoliciesResponse, RusotoError<DescribeResourcePoliciesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input| async move {
+                client.describe_resource_policies(input).await
+            },
+            |input: &DescribeResourcePoliciesRequest, output: &DescribeResourcePoliciesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeResourcePoliciesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `resourcePolicies` of every page of `DescribeResourcePolicies` results for `input`, as `describe_resource_policies_pages` requests them.
+    pub fn describe_resource_policies_stream(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ResourcePolicy, RusotoError<DescribeResourcePoliciesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_resource_policies_pages(input), |page| {
+            page.resource_policies.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSubscriptionFilters` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn describe_subscription_filters_pages(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeSubscriptionFiltersResponse,
+            RusotoError<DescribeSubscriptionFiltersError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input| async move {
+                client.describe_subscription_filters(input).await
+            },
+            |input: &DescribeSubscriptionFiltersRequest,
+             output: &DescribeSubscriptionFiltersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeSubscriptionFiltersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `subscriptionFilters` of every page of `DescribeSubscriptionFilters` results for `input`, as `describe_subscription_filters_pages` requests them.
+    pub fn describe_subscription_filters_stream(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SubscriptionFilter, RusotoError<DescribeSubscriptionFiltersError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_subscription_filters_pages(input), |page| {
+            page.subscription_filters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `FilterLogEvents` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn filter_log_events_pages(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<FilterLogEventsResponse, RusotoError<FilterLogEventsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchLogsClient, input| async move { client.filter_log_events(input).await },
+            |input: &FilterLogEventsRequest, output: &FilterLogEventsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(FilterLogEventsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+}
+
+#[async_trait]
+impl CloudWatchLogs for CloudWatchLogsClient {
+    /// <p>Associates the specified AWS Key Management Service (AWS KMS) customer master key (CMK) with the specified log group.</p> <p>Associating an AWS KMS CMK with a log group overrides any existing associations between the log group and a CMK. After a CMK is associated with a log group, all newly ingested data for the log group is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <note> <p> <b>Important:</b> CloudWatch Logs supports only symmetric CMKs. Do not use an associate an asymmetric CMK with your log group. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a>.</p> </note> <p>Note that it can take up to 5 minutes for this operation to take effect.</p> <p>If you attempt to associate a CMK with a log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p>
+    async fn associate_kms_key(
+        &self,
+        input: AssociateKmsKeyRequest,
+    ) -> Result<(), RusotoError<AssociateKmsKeyError>> {
+        self.associate_kms_key_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagLogGroupError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels the specified export task.</p> <p>The task must be in the <code>PENDING</code> or <code>RUNNING</code> state.</p>
+    async fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> Result<(), RusotoError<CancelExportTaskError>> {
+        self.cancel_export_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an export task, which allows you to efficiently export data from a log group to an Amazon S3 bucket.</p> <p>This is an asynchronous call. If all the required information is provided, this operation initiates an export task and responds with the ID of the task. After the task has started, you can use <a>DescribeExportTasks</a> to get the status of the export task. Each account can only have one active (<code>RUNNING</code> or <code>PENDING</code>) export task at a time. To cancel an export task, use <a>CancelExportTask</a>.</p> <p>You can export logs from multiple log groups or multiple time ranges to the same S3 bucket. To separate out log data for each export task, you can specify a prefix to be used as the Amazon S3 key prefix for all exported objects.</p> <p>Exporting to S3 buckets that are encrypted with AES-256 is supported. Exporting to S3 buckets encrypted with SSE-KMS is not supported. </p>
+    async fn create_export_task(
+        &self,
+        input: CreateExportTaskRequest,
+    ) -> Result<CreateExportTaskResponse, RusotoError<CreateExportTaskError>> {
+        self.create_export_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a log group with the specified name.</p> <p>You can create up to 20,000 log groups per account.</p> <p>You must use the following guidelines when naming a log group:</p> <ul> <li> <p>Log group names must be unique within a region for an AWS account.</p> </li> <li> <p>Log group names can be between 1 and 512 characters long.</p> </li> <li> <p>Log group names consist of the following characters: a-z, A-Z, 0-9, &#39;_&#39; (underscore), &#39;-&#39; (hyphen), &#39;/&#39; (forward slash), &#39;.&#39; (period), and &#39;#&#39; (number sign)</p> </li> </ul> <p>If you associate a AWS Key Management Service (AWS KMS) customer master key (CMK) with the log group, ingested data is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <p>If you attempt to associate a CMK with the log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p> <note> <p> <b>Important:</b> CloudWatch Logs supports only symmetric CMKs. Do not associate an asymmetric CMK with your log group. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a>.</p> </note></p>
+    async fn create_log_group(
+        &self,
+        input: CreateLogGroupRequest,
+    ) -> Result<(), RusotoError<CreateLogGroupError>> {
+        self.create_log_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a log stream for the specified log group.</p> <p>There is no limit on the number of log streams that you can create for a log group. There is a limit of 50 TPS on <code>CreateLogStream</code> operations, after which transactions are throttled.</p> <p>You must use the following guidelines when naming a log stream:</p> <ul> <li> <p>Log stream names must be unique within the log group.</p> </li> <li> <p>Log stream names can be between 1 and 512 characters long.</p> </li> <li> <p>The &#39;:&#39; (colon) and &#39;*&#39; (asterisk) characters are not allowed.</p> </li> </ul></p>
+    async fn create_log_stream(
+        &self,
+        input: CreateLogStreamRequest,
+    ) -> Result<(), RusotoError<CreateLogStreamError>> {
+        self.create_log_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified destination, and eventually disables all the subscription filters that publish to it. This operation does not delete the physical resource encapsulated by the destination.</p>
+    async fn delete_destination(
+        &self,
+        input: DeleteDestinationRequest,
+    ) -> Result<(), RusotoError<DeleteDestinationError>> {
+        self.delete_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified log group and permanently deletes all the archived log events associated with the log group.</p>
+    async fn delete_log_group(
+        &self,
+        input: DeleteLogGroupRequest,
+    ) -> Result<(), RusotoError<DeleteLogGroupError>> {
+        self.delete_log_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified log stream and permanently deletes all the archived log events associated with the log stream.</p>
+    async fn delete_log_stream(
+        &self,
+        input: DeleteLogStreamRequest,
+    ) -> Result<(), RusotoError<DeleteLogStreamError>> {
+        self.delete_log_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified metric filter.</p>
+    async fn delete_metric_filter(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> Result<(), RusotoError<DeleteMetricFilterError>> {
+        self.delete_metric_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a resource policy from this account. This revokes the access of the identities in that policy to put log events to this account.</p>
+    async fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<(), RusotoError<DeleteResourcePolicyError>> {
+        self.delete_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified retention policy.</p> <p>Log events do not expire if they belong to log groups without a retention policy.</p>
+    async fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteRetentionPolicyError>> {
+        self.delete_retention_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified subscription filter.</p>
+    async fn delete_subscription_filter(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> Result<(), RusotoError<DeleteSubscriptionFilterError>> {
+        self.delete_subscription_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all your destinations. The results are ASCII-sorted by destination name.</p>
+    async fn describe_destinations(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> Result<DescribeDestinationsResponse, RusotoError<DescribeDestinationsError>> {
+        self.describe_destinations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the specified export tasks. You can list all your export tasks or filter the results based on task ID or task status.</p>
+    async fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
+        self.describe_export_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the specified log groups. You can list all your log groups or filter the results by prefix. The results are ASCII-sorted by log group name.</p>
+    async fn describe_log_groups(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> Result<DescribeLogGroupsResponse, RusotoError<DescribeLogGroupsError>> {
+        self.describe_log_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the log streams for the specified log group. You can list all the log streams or filter the results by prefix. You can also control how the results are ordered.</p> <p>This operation has a limit of five transactions per second, after which transactions are throttled.</p>
+    async fn describe_log_streams(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> Result<DescribeLogStreamsResponse, RusotoError<DescribeLogStreamsError>> {
+        self.describe_log_streams_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the specified metric filters. You can list all the metric filters or filter the results by log name, prefix, metric name, or metric namespace. The results are ASCII-sorted by filter name.</p>
+    async fn describe_metric_filters(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> Result<DescribeMetricFiltersResponse, RusotoError<DescribeMetricFiltersError>> {
+        self.describe_metric_filters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of CloudWatch Logs Insights queries that are scheduled, executing, or have been executed recently in this account. You can request all queries, or limit it to queries of a specific log group or queries with a certain status.</p>
+    async fn describe_queries(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> Result<DescribeQueriesResponse, RusotoError<DescribeQueriesError>> {
+        self.describe_queries_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resource policies in this account.</p>
+    async fn describe_resource_policies(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> Result<DescribeResourcePoliciesResponse, RusotoError<DescribeResourcePoliciesError>> {
+        self.describe_resource_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the subscription filters for the specified log group. You can list all the subscription filters or filter the results by prefix. The results are ASCII-sorted by filter name.</p>
+    async fn describe_subscription_filters(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> Result<DescribeSubscriptionFiltersResponse, RusotoError<DescribeSubscriptionFiltersError>>
+    {
+        self.describe_subscription_filters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the associated AWS Key Management Service (AWS KMS) customer master key (CMK) from the specified log group.</p> <p>After the AWS KMS CMK is disassociated from the log group, AWS CloudWatch Logs stops encrypting newly ingested data for the log group. All previously ingested data remains encrypted, and AWS CloudWatch Logs requires permissions for the CMK whenever the encrypted data is requested.</p> <p>Note that it can take up to 5 minutes for this operation to take effect.</p>
+    async fn disassociate_kms_key(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> Result<(), RusotoError<DisassociateKmsKeyError>> {
+        self.disassociate_kms_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists log events from the specified log group. You can list all the log events or filter the results using a filter pattern, a time range, and the name of the log stream.</p> <p>By default, this operation returns as many log events as can fit in 1 MB (up to 10,000 log events), or all the events found within the time range that you specify. If the results include a token, then there are more log events available, and you can get additional results by specifying the token in a subsequent call.</p>
+    async fn filter_log_events(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> Result<FilterLogEventsResponse, RusotoError<FilterLogEventsError>> {
+        self.filter_log_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists log events from the specified log stream. You can list all the log events or filter using a time range.</p> <p>By default, this operation returns as many log events as can fit in a response size of 1MB (up to 10,000 log events). You can get additional log events by specifying one of the tokens in a subsequent call.</p>
+    async fn get_log_events(
+        &self,
+        input: GetLogEventsRequest,
+    ) -> Result<GetLogEventsResponse, RusotoError<GetLogEventsError>> {
+        self.get_log_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the fields that are included in log events in the specified log group, along with the percentage of log events that contain each field. The search is limited to a time period that you specify.</p> <p>In the results, fields that start with @ are fields generated by CloudWatch Logs. For example, <code>@timestamp</code> is the timestamp of each log event.</p> <p>The response results are sorted by the frequency percentage, starting with the highest percentage.</p>
+    async fn get_log_group_fields(
+        &self,
+        input: GetLogGroupFieldsRequest,
+    ) -> Result<GetLogGroupFieldsResponse, RusotoError<GetLogGroupFieldsError>> {
+        self.get_log_group_fields_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves all the fields and values of a single log event. All fields are retrieved, even if the original query that produced the <code>logRecordPointer</code> retrieved only a subset of fields. Fields are returned as field name/field value pairs.</p> <p>Additionally, the entire unparsed log event is returned within <code>@message</code>.</p>
+    async fn get_log_record(
+        &self,
+        input: GetLogRecordRequest,
+    ) -> Result<GetLogRecordResponse, RusotoError<GetLogRecordError>> {
+        self.get_log_record_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the results from the specified query.</p> <p>Only the fields requested in the query are returned, along with a <code>@ptr</code> field which is the identifier for the log record. You can use the value of <code>@ptr</code> in a operation to get the full log record.</p> <p> <code>GetQueryResults</code> does not start a query execution. To run a query, use .</p> <p>If the value of the <code>Status</code> field in the output is <code>Running</code>, this operation returns only partial results. If you see a value of <code>Scheduled</code> or <code>Running</code> for the status, you can retry the operation later to see the final results. </p>
+    async fn get_query_results(
+        &self,
+        input: GetQueryResultsRequest,
+    ) -> Result<GetQueryResultsResponse, RusotoError<GetQueryResultsError>> {
+        self.get_query_results_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for the specified log group.</p>
+    async fn list_tags_log_group(
+        &self,
+        input: ListTagsLogGroupRequest,
+    ) -> Result<ListTagsLogGroupResponse, RusotoError<ListTagsLogGroupError>> {
+        self.list_tags_log_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a destination. This operation is used only to create destinations for cross-account subscriptions.</p> <p>A destination encapsulates a physical resource (such as an Amazon Kinesis stream) and enables you to subscribe to a real-time stream of log events for a different account, ingested using <a>PutLogEvents</a>.</p> <p>Through an access policy, a destination controls what is written to it. By default, <code>PutDestination</code> does not set any access policy with the destination, which means a cross-account user cannot call <a>PutSubscriptionFilter</a> against this destination. To enable this, the destination owner must call <a>PutDestinationPolicy</a> after <code>PutDestination</code>.</p>
+    async fn put_destination(
+        &self,
+        input: PutDestinationRequest,
+    ) -> Result<PutDestinationResponse, RusotoError<PutDestinationError>> {
+        self.put_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates an access policy associated with an existing destination. An access policy is an <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies_overview.html">IAM policy document</a> that is used to authorize claims to register a subscription filter against a given destination.</p>
+    async fn put_destination_policy(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> Result<(), RusotoError<PutDestinationPolicyError>> {
+        self.put_destination_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Uploads a batch of log events to the specified log stream.</p> <p>You must include the sequence token obtained from the response of the previous call. An upload in a newly created log stream does not require a sequence token. You can also get the sequence token in the <code>expectedSequenceToken</code> field from <code>InvalidSequenceTokenException</code>. If you call <code>PutLogEvents</code> twice within a narrow time period using the same value for <code>sequenceToken</code>, both calls may be successful, or one may be rejected.</p> <p>The batch of events must satisfy the following constraints:</p> <ul> <li> <p>The maximum batch size is 1,048,576 bytes, and this size is calculated as the sum of all event messages in UTF-8, plus 26 bytes for each log event.</p> </li> <li> <p>None of the log events in the batch can be more than 2 hours in the future.</p> </li> <li> <p>None of the log events in the batch can be older than 14 days or older than the retention period of the log group.</p> </li> <li> <p>The log events in the batch must be in chronological ordered by their timestamp. The timestamp is the time the event occurred, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. (In AWS Tools for PowerShell and the AWS SDK for .NET, the timestamp is specified in .NET format: yyyy-mm-ddThh:mm:ss. For example, 2017-09-15T13:45:30.) </p> </li> <li> <p>A batch of log events in a single request cannot span more than 24 hours. Otherwise, the operation fails.</p> </li> <li> <p>The maximum number of log events in a batch is 10,000.</p> </li> <li> <p>There is a quota of 5 requests per second per log stream. Additional requests are throttled. This quota can't be changed.</p> </li> </ul> <p>If a call to PutLogEvents returns "UnrecognizedClientException" the most likely cause is an invalid AWS access key ID or secret key. </p>
+    async fn put_log_events(
+        &self,
+        input: PutLogEventsRequest,
+    ) -> Result<PutLogEventsResponse, RusotoError<PutLogEventsError>> {
+        self.put_log_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a metric filter and associates it with the specified log group. Metric filters allow you to configure rules to extract metric data from log events ingested through <a>PutLogEvents</a>.</p> <p>The maximum number of metric filters that can be associated with a log group is 100.</p>
+    async fn put_metric_filter(
+        &self,
+        input: PutMetricFilterRequest,
+    ) -> Result<(), RusotoError<PutMetricFilterError>> {
+        self.put_metric_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a resource policy allowing other AWS services to put log events to this account, such as Amazon Route 53. An account can have up to 10 resource policies per region.</p>
+    async fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.put_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the retention of the specified log group. A retention policy allows you to configure the number of days for which to retain log events in the specified log group.</p>
+    async fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> Result<(), RusotoError<PutRetentionPolicyError>> {
+        self.put_retention_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a subscription filter and associates it with the specified log group. Subscription filters allow you to subscribe to a real-time stream of log events ingested through <a>PutLogEvents</a> and have them delivered to a specific destination. Currently, the supported destinations are:</p> <ul> <li> <p>An Amazon Kinesis stream belonging to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>A logical destination that belongs to a different account, for cross-account delivery.</p> </li> <li> <p>An Amazon Kinesis Firehose delivery stream that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>An AWS Lambda function that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> </ul> <p>There can only be one subscription filter associated with a log group. If you are updating an existing filter, you must specify the correct name in <code>filterName</code>. Otherwise, the call fails because you cannot associate a second filter with a log group.</p>
+    async fn put_subscription_filter(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> Result<(), RusotoError<PutSubscriptionFilterError>> {
+        self.put_subscription_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Schedules a query of a log group using CloudWatch Logs Insights. You specify the log group and time range to query, and the query string to use.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/CWL_QuerySyntax.html">CloudWatch Logs Insights Query Syntax</a>.</p> <p>Queries time out after 15 minutes of execution. If your queries are timing out, reduce the time range being searched, or partition your query into a number of queries.</p>
+    async fn start_query(
+        &self,
+        input: StartQueryRequest,
+    ) -> Result<StartQueryResponse, RusotoError<StartQueryError>> {
+        self.start_query_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a CloudWatch Logs Insights query that is in progress. If the query has already ended, the operation returns an error indicating that the specified query is not running.</p>
+    async fn stop_query(
+        &self,
+        input: StopQueryRequest,
+    ) -> Result<StopQueryResponse, RusotoError<StopQueryError>> {
+        self.stop_query_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates the specified tags for the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To remove tags, use <a>UntagLogGroup</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/log-group-tagging.html">Tag Log Groups in Amazon CloudWatch Logs</a> in the <i>Amazon CloudWatch Logs User Guide</i>.</p>
+    async fn tag_log_group(
+        &self,
+        input: TagLogGroupRequest,
+    ) -> Result<(), RusotoError<TagLogGroupError>> {
+        self.tag_log_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Tests the filter pattern of a metric filter against a sample of log event messages. You can use this operation to validate the correctness of a metric filter pattern.</p>
+    async fn test_metric_filter(
+        &self,
+        input: TestMetricFilterRequest,
+    ) -> Result<TestMetricFilterResponse, RusotoError<TestMetricFilterError>> {
+        self.test_metric_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To add tags, use <a>UntagLogGroup</a>.</p>
+    async fn untag_log_group(
+        &self,
+        input: UntagLogGroupRequest,
+    ) -> Result<(), RusotoError<UntagLogGroupError>> {
+        self.untag_log_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1646,6 +1646,7 @@ pub struct UpdateMLModelOutput {
 
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -1657,6 +1658,8 @@ pub enum AddTagsError {
     ResourceNotFound(String),
 
     TagLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsError {
@@ -1664,22 +1667,22 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(AddTagsError::InternalServer(err.msg))
+                    return RusotoError::service(AddTagsError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AddTagsError::InvalidInput(err.msg))
+                    return RusotoError::service(AddTagsError::InvalidInput(err.msg), &res)
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(AddTagsError::InvalidTag(err.msg))
+                    return RusotoError::service(AddTagsError::InvalidTag(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(AddTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(AddTagsError::TagLimitExceeded(err.msg))
+                    return RusotoError::service(AddTagsError::TagLimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1694,12 +1697,14 @@ impl fmt::Display for AddTagsError {
             AddTagsError::InvalidTag(ref cause) => write!(f, "{}", cause),
             AddTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AddTagsError::TagLimitExceeded(ref cause) => write!(f, "{}", cause),
+            AddTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsError {}
 /// Errors returned by CreateBatchPrediction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBatchPredictionError {
     /// <p>A second request to use or change an object was not allowed. This can result from retrying a request using a parameter that was not present in the original request.</p>
     IdempotentParameterMismatch(String),
@@ -1707,6 +1712,8 @@ pub enum CreateBatchPredictionError {
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBatchPredictionError {
@@ -1714,20 +1721,25 @@ impl CreateBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateBatchPredictionError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateBatchPredictionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBatchPredictionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateBatchPredictionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBatchPredictionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1742,12 +1754,14 @@ impl fmt::Display for CreateBatchPredictionError {
             }
             CreateBatchPredictionError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateBatchPredictionError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            CreateBatchPredictionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBatchPredictionError {}
 /// Errors returned by CreateDataSourceFromRDS
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataSourceFromRDSError {
     /// <p>A second request to use or change an object was not allowed. This can result from retrying a request using a parameter that was not present in the original request.</p>
     IdempotentParameterMismatch(String),
@@ -1755,6 +1769,8 @@ pub enum CreateDataSourceFromRDSError {
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataSourceFromRDSError {
@@ -1762,22 +1778,25 @@ impl CreateDataSourceFromRDSError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataSourceFromRDSError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceFromRDSError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSourceFromRDSError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDataSourceFromRDSError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSourceFromRDSError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDataSourceFromRDSError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1792,12 +1811,14 @@ impl fmt::Display for CreateDataSourceFromRDSError {
             }
             CreateDataSourceFromRDSError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateDataSourceFromRDSError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            CreateDataSourceFromRDSError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataSourceFromRDSError {}
 /// Errors returned by CreateDataSourceFromRedshift
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataSourceFromRedshiftError {
     /// <p>A second request to use or change an object was not allowed. This can result from retrying a request using a parameter that was not present in the original request.</p>
     IdempotentParameterMismatch(String),
@@ -1805,6 +1826,8 @@ pub enum CreateDataSourceFromRedshiftError {
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataSourceFromRedshiftError {
@@ -1814,22 +1837,30 @@ impl CreateDataSourceFromRedshiftError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataSourceFromRedshiftError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceFromRedshiftError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSourceFromRedshiftError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDataSourceFromRedshiftError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSourceFromRedshiftError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateDataSourceFromRedshiftError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1844,12 +1875,14 @@ impl fmt::Display for CreateDataSourceFromRedshiftError {
             }
             CreateDataSourceFromRedshiftError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateDataSourceFromRedshiftError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            CreateDataSourceFromRedshiftError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataSourceFromRedshiftError {}
 /// Errors returned by CreateDataSourceFromS3
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataSourceFromS3Error {
     /// <p>A second request to use or change an object was not allowed. This can result from retrying a request using a parameter that was not present in the original request.</p>
     IdempotentParameterMismatch(String),
@@ -1857,6 +1890,8 @@ pub enum CreateDataSourceFromS3Error {
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataSourceFromS3Error {
@@ -1864,20 +1899,25 @@ impl CreateDataSourceFromS3Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataSourceFromS3Error::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceFromS3Error::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSourceFromS3Error::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDataSourceFromS3Error::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDataSourceFromS3Error::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDataSourceFromS3Error::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1892,12 +1932,14 @@ impl fmt::Display for CreateDataSourceFromS3Error {
             }
             CreateDataSourceFromS3Error::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateDataSourceFromS3Error::InvalidInput(ref cause) => write!(f, "{}", cause),
+            CreateDataSourceFromS3Error::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataSourceFromS3Error {}
 /// Errors returned by CreateEvaluation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEvaluationError {
     /// <p>A second request to use or change an object was not allowed. This can result from retrying a request using a parameter that was not present in the original request.</p>
     IdempotentParameterMismatch(String),
@@ -1905,6 +1947,8 @@ pub enum CreateEvaluationError {
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEvaluationError {
@@ -1912,18 +1956,22 @@ impl CreateEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEvaluationError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateEvaluationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateEvaluationError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEvaluationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1936,12 +1984,14 @@ impl fmt::Display for CreateEvaluationError {
             CreateEvaluationError::IdempotentParameterMismatch(ref cause) => write!(f, "{}", cause),
             CreateEvaluationError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateEvaluationError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            CreateEvaluationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEvaluationError {}
 /// Errors returned by CreateMLModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMLModelError {
     /// <p>A second request to use or change an object was not allowed. This can result from retrying a request using a parameter that was not present in the original request.</p>
     IdempotentParameterMismatch(String),
@@ -1949,6 +1999,8 @@ pub enum CreateMLModelError {
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMLModelError {
@@ -1956,18 +2008,19 @@ impl CreateMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateMLModelError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMLModelError::IdempotentParameterMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateMLModelError::InternalServer(err.msg))
+                    return RusotoError::service(CreateMLModelError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateMLModelError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMLModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1980,12 +2033,14 @@ impl fmt::Display for CreateMLModelError {
             CreateMLModelError::IdempotentParameterMismatch(ref cause) => write!(f, "{}", cause),
             CreateMLModelError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateMLModelError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            CreateMLModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMLModelError {}
 /// Errors returned by CreateRealtimeEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRealtimeEndpointError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -1993,6 +2048,8 @@ pub enum CreateRealtimeEndpointError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRealtimeEndpointError {
@@ -2000,20 +2057,25 @@ impl CreateRealtimeEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRealtimeEndpointError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRealtimeEndpointError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateRealtimeEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateRealtimeEndpointError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateRealtimeEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRealtimeEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRealtimeEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2026,12 +2088,14 @@ impl fmt::Display for CreateRealtimeEndpointError {
             CreateRealtimeEndpointError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateRealtimeEndpointError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateRealtimeEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateRealtimeEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRealtimeEndpointError {}
 /// Errors returned by DeleteBatchPrediction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBatchPredictionError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2039,6 +2103,8 @@ pub enum DeleteBatchPredictionError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBatchPredictionError {
@@ -2046,20 +2112,25 @@ impl DeleteBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteBatchPredictionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBatchPredictionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteBatchPredictionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBatchPredictionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBatchPredictionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBatchPredictionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2072,12 +2143,14 @@ impl fmt::Display for DeleteBatchPredictionError {
             DeleteBatchPredictionError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteBatchPredictionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteBatchPredictionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteBatchPredictionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBatchPredictionError {}
 /// Errors returned by DeleteDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDataSourceError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2085,6 +2158,8 @@ pub enum DeleteDataSourceError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDataSourceError {
@@ -2092,16 +2167,22 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteDataSourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteDataSourceError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2114,12 +2195,14 @@ impl fmt::Display for DeleteDataSourceError {
             DeleteDataSourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteDataSourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDataSourceError {}
 /// Errors returned by DeleteEvaluation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEvaluationError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2127,6 +2210,8 @@ pub enum DeleteEvaluationError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEvaluationError {
@@ -2134,16 +2219,22 @@ impl DeleteEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteEvaluationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteEvaluationError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEvaluationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteEvaluationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEvaluationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2156,12 +2247,14 @@ impl fmt::Display for DeleteEvaluationError {
             DeleteEvaluationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteEvaluationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteEvaluationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteEvaluationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEvaluationError {}
 /// Errors returned by DeleteMLModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMLModelError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2169,6 +2262,8 @@ pub enum DeleteMLModelError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMLModelError {
@@ -2176,16 +2271,19 @@ impl DeleteMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteMLModelError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteMLModelError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteMLModelError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMLModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteMLModelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMLModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2198,12 +2296,14 @@ impl fmt::Display for DeleteMLModelError {
             DeleteMLModelError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteMLModelError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteMLModelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteMLModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMLModelError {}
 /// Errors returned by DeleteRealtimeEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRealtimeEndpointError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2211,6 +2311,8 @@ pub enum DeleteRealtimeEndpointError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRealtimeEndpointError {
@@ -2218,20 +2320,25 @@ impl DeleteRealtimeEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRealtimeEndpointError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRealtimeEndpointError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteRealtimeEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteRealtimeEndpointError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRealtimeEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRealtimeEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRealtimeEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2244,12 +2351,14 @@ impl fmt::Display for DeleteRealtimeEndpointError {
             DeleteRealtimeEndpointError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteRealtimeEndpointError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteRealtimeEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteRealtimeEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRealtimeEndpointError {}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTagsError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2259,6 +2368,8 @@ pub enum DeleteTagsError {
     InvalidTag(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTagsError {
@@ -2266,19 +2377,19 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteTagsError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteTagsError::InvalidInput(err.msg), &res)
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidTag(err.msg))
+                    return RusotoError::service(DeleteTagsError::InvalidTag(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2292,17 +2403,21 @@ impl fmt::Display for DeleteTagsError {
             DeleteTagsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::InvalidTag(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTagsError {}
 /// Errors returned by DescribeBatchPredictions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBatchPredictionsError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBatchPredictionsError {
@@ -2310,17 +2425,19 @@ impl DescribeBatchPredictionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeBatchPredictionsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBatchPredictionsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeBatchPredictionsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBatchPredictionsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBatchPredictionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2332,17 +2449,21 @@ impl fmt::Display for DescribeBatchPredictionsError {
         match *self {
             DescribeBatchPredictionsError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeBatchPredictionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeBatchPredictionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBatchPredictionsError {}
 /// Errors returned by DescribeDataSources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDataSourcesError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDataSourcesError {
@@ -2350,13 +2471,19 @@ impl DescribeDataSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDataSourcesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSourcesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDataSourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSourcesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDataSourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2368,17 +2495,21 @@ impl fmt::Display for DescribeDataSourcesError {
         match *self {
             DescribeDataSourcesError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeDataSourcesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeDataSourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDataSourcesError {}
 /// Errors returned by DescribeEvaluations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEvaluationsError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEvaluationsError {
@@ -2386,13 +2517,19 @@ impl DescribeEvaluationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEvaluationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeEvaluationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeEvaluationsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeEvaluationsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEvaluationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2404,17 +2541,21 @@ impl fmt::Display for DescribeEvaluationsError {
         match *self {
             DescribeEvaluationsError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeEvaluationsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeEvaluationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEvaluationsError {}
 /// Errors returned by DescribeMLModels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMLModelsError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMLModelsError {
@@ -2422,13 +2563,16 @@ impl DescribeMLModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeMLModelsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeMLModelsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeMLModelsError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeMLModelsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMLModelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2440,12 +2584,14 @@ impl fmt::Display for DescribeMLModelsError {
         match *self {
             DescribeMLModelsError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeMLModelsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DescribeMLModelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMLModelsError {}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTagsError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2453,6 +2599,8 @@ pub enum DescribeTagsError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTagsError {
@@ -2460,16 +2608,16 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeTagsError::InternalServer(err.msg))
+                    return RusotoError::service(DescribeTagsError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeTagsError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeTagsError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2482,12 +2630,14 @@ impl fmt::Display for DescribeTagsError {
             DescribeTagsError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTagsError {}
 /// Errors returned by GetBatchPrediction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBatchPredictionError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2495,6 +2645,8 @@ pub enum GetBatchPredictionError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBatchPredictionError {
@@ -2502,16 +2654,25 @@ impl GetBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetBatchPredictionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetBatchPredictionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetBatchPredictionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBatchPredictionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetBatchPredictionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBatchPredictionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2524,12 +2685,14 @@ impl fmt::Display for GetBatchPredictionError {
             GetBatchPredictionError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetBatchPredictionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetBatchPredictionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetBatchPredictionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBatchPredictionError {}
 /// Errors returned by GetDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDataSourceError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2537,6 +2700,8 @@ pub enum GetDataSourceError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDataSourceError {
@@ -2544,16 +2709,19 @@ impl GetDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(GetDataSourceError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDataSourceError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDataSourceError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2566,12 +2734,14 @@ impl fmt::Display for GetDataSourceError {
             GetDataSourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetDataSourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDataSourceError {}
 /// Errors returned by GetEvaluation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEvaluationError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2579,6 +2749,8 @@ pub enum GetEvaluationError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEvaluationError {
@@ -2586,16 +2758,19 @@ impl GetEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service(GetEvaluationError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service(GetEvaluationError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEvaluationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetEvaluationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEvaluationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2608,12 +2783,14 @@ impl fmt::Display for GetEvaluationError {
             GetEvaluationError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetEvaluationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetEvaluationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetEvaluationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEvaluationError {}
 /// Errors returned by GetMLModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMLModelError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2621,6 +2798,8 @@ pub enum GetMLModelError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMLModelError {
@@ -2628,16 +2807,16 @@ impl GetMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetMLModelError::InternalServer(err.msg))
+                    return RusotoError::service(GetMLModelError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service(GetMLModelError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMLModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetMLModelError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMLModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2650,12 +2829,14 @@ impl fmt::Display for GetMLModelError {
             GetMLModelError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetMLModelError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetMLModelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetMLModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMLModelError {}
 /// Errors returned by Predict
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PredictError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2667,6 +2848,8 @@ pub enum PredictError {
     PredictorNotMounted(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PredictError {
@@ -2674,22 +2857,22 @@ impl PredictError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PredictError::InternalServer(err.msg))
+                    return RusotoError::service(PredictError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PredictError::InvalidInput(err.msg))
+                    return RusotoError::service(PredictError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PredictError::LimitExceeded(err.msg))
+                    return RusotoError::service(PredictError::LimitExceeded(err.msg), &res)
                 }
                 "PredictorNotMountedException" => {
-                    return RusotoError::Service(PredictError::PredictorNotMounted(err.msg))
+                    return RusotoError::service(PredictError::PredictorNotMounted(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PredictError::ResourceNotFound(err.msg))
+                    return RusotoError::service(PredictError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PredictError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2704,12 +2887,14 @@ impl fmt::Display for PredictError {
             PredictError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PredictError::PredictorNotMounted(ref cause) => write!(f, "{}", cause),
             PredictError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PredictError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PredictError {}
 /// Errors returned by UpdateBatchPrediction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBatchPredictionError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2717,6 +2902,8 @@ pub enum UpdateBatchPredictionError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBatchPredictionError {
@@ -2724,20 +2911,25 @@ impl UpdateBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateBatchPredictionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBatchPredictionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateBatchPredictionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateBatchPredictionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBatchPredictionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBatchPredictionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2750,12 +2942,14 @@ impl fmt::Display for UpdateBatchPredictionError {
             UpdateBatchPredictionError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateBatchPredictionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateBatchPredictionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateBatchPredictionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBatchPredictionError {}
 /// Errors returned by UpdateDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDataSourceError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2763,6 +2957,8 @@ pub enum UpdateDataSourceError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDataSourceError {
@@ -2770,16 +2966,22 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2792,12 +2994,14 @@ impl fmt::Display for UpdateDataSourceError {
             UpdateDataSourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateDataSourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDataSourceError {}
 /// Errors returned by UpdateEvaluation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEvaluationError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2805,6 +3009,8 @@ pub enum UpdateEvaluationError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEvaluationError {
@@ -2812,16 +3018,22 @@ impl UpdateEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateEvaluationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateEvaluationError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEvaluationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateEvaluationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateEvaluationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2834,12 +3046,14 @@ impl fmt::Display for UpdateEvaluationError {
             UpdateEvaluationError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateEvaluationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateEvaluationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateEvaluationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateEvaluationError {}
 /// Errors returned by UpdateMLModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMLModelError {
     /// <p>An error on the server occurred when trying to process a request.</p>
     InternalServer(String),
@@ -2847,6 +3061,8 @@ pub enum UpdateMLModelError {
     InvalidInput(String),
     /// <p>A specified resource cannot be located.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMLModelError {
@@ -2854,16 +3070,19 @@ impl UpdateMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateMLModelError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateMLModelError::InternalServer(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateMLModelError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMLModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateMLModelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMLModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2876,6 +3095,7 @@ impl fmt::Display for UpdateMLModelError {
             UpdateMLModelError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateMLModelError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateMLModelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateMLModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3062,6 +3282,7 @@ impl MachineLearningClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MachineLearningClient {
         MachineLearningClient {
             client: Client::shared(),
@@ -3087,100 +3308,241 @@ impl MachineLearningClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MachineLearningClient {
         MachineLearningClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MachineLearningClient> {
+        rusoto_core::ClientBuilder::new(MachineLearningClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MachineLearningClient {
+        MachineLearningClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MachineLearning for MachineLearningClient {
-    /// <p>Adds one or more tags to an object, up to a limit of 10. Each tag consists of a key and an optional value. If you add a tag using a key that is already associated with the ML object, <code>AddTags</code> updates the tag's value.</p>
-    async fn add_tags(
+impl MachineLearningClient {
+    /// Calls `add_tags`, also returning the metadata of the response.
+    pub async fn add_tags_with_metadata(
         &self,
         input: AddTagsInput,
-    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+    ) -> Result<(AddTagsOutput, ResponseMetadata), RusotoError<AddTagsError>> {
+        let request = self.build_add_tags_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_request(&self, input: AddTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("AddTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_batch_prediction`, also returning the metadata of the response.
+    pub async fn create_batch_prediction_with_metadata(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> Result<
+        (CreateBatchPredictionOutput, ResponseMetadata),
+        RusotoError<CreateBatchPredictionError>,
+    > {
+        let request = self.build_create_batch_prediction_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBatchPredictionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBatchPredictionError::from_response(response))
         }
     }
 
-    /// <p>Generates predictions for a group of observations. The observations to process exist in one or more data files referenced by a <code>DataSource</code>. This operation creates a new <code>BatchPrediction</code>, and uses an <code>MLModel</code> and the data files referenced by the <code>DataSource</code> as information sources. </p> <p><code>CreateBatchPrediction</code> is an asynchronous operation. In response to <code>CreateBatchPrediction</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>BatchPrediction</code> status to <code>PENDING</code>. After the <code>BatchPrediction</code> completes, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can poll for status updates by using the <a>GetBatchPrediction</a> operation and checking the <code>Status</code> parameter of the result. After the <code>COMPLETED</code> status appears, the results are available in the location specified by the <code>OutputUri</code> parameter.</p>
-    async fn create_batch_prediction(
+    /// Presigns the request `create_batch_prediction` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_batch_prediction(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBatchPredictionInput,
-    ) -> Result<CreateBatchPredictionOutput, RusotoError<CreateBatchPredictionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_batch_prediction_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_batch_prediction_request(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateBatchPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_data_source_from_rds`, also returning the metadata of the response.
+    pub async fn create_data_source_from_rds_with_metadata(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> Result<
+        (CreateDataSourceFromRDSOutput, ResponseMetadata),
+        RusotoError<CreateDataSourceFromRDSError>,
+    > {
+        let request = self.build_create_data_source_from_rds_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBatchPredictionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDataSourceFromRDSOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBatchPredictionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDataSourceFromRDSError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>DataSource</code> object from an <a href="http://aws.amazon.com/rds/"> Amazon Relational Database Service</a> (Amazon RDS). A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRDS</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRDS</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used only to perform <code>&gt;CreateMLModel</code>&gt;, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML cannot accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p>
-    async fn create_data_source_from_rds(
+    /// Presigns the request `create_data_source_from_rds` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_data_source_from_rds(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataSourceFromRDSInput,
-    ) -> Result<CreateDataSourceFromRDSOutput, RusotoError<CreateDataSourceFromRDSError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_data_source_from_rds_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_data_source_from_rds_request(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateDataSourceFromRDS");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromRDS");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_data_source_from_redshift`, also returning the metadata of the response.
+    pub async fn create_data_source_from_redshift_with_metadata(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> Result<
+        (CreateDataSourceFromRedshiftOutput, ResponseMetadata),
+        RusotoError<CreateDataSourceFromRedshiftError>,
+    > {
+        let request = self.build_create_data_source_from_redshift_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDataSourceFromRDSOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDataSourceFromRedshiftOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataSourceFromRDSError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDataSourceFromRedshiftError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a <code>DataSource</code> from a database hosted on an Amazon Redshift cluster. A <code>DataSource</code> references data that can be used to perform either <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRedshift</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRedshift</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in <code>COMPLETED</code> or <code>PENDING</code> states can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can&#39;t accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observations should be contained in the database hosted on an Amazon Redshift cluster and should be specified by a <code>SelectSqlQuery</code> query. Amazon ML executes an <code>Unload</code> command in Amazon Redshift to transfer the result set of the <code>SelectSqlQuery</code> query to <code>S3StagingLocation</code>.</p> <p>After the <code>DataSource</code> has been created, it&#39;s ready for use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also requires a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p> &lt;?oxy<em>insert</em>start author=&quot;laurama&quot; timestamp=&quot;20160406T153842-0700&quot;&gt;<p>You can&#39;t change an existing datasource, but you can copy and modify the settings from an existing Amazon Redshift datasource to create a new datasource. To do so, call <code>GetDataSource</code> for an existing datasource and copy the values to a <code>CreateDataSource</code> call. Change the settings that you want to change and make sure that all required fields have the appropriate values.</p> &lt;?oxy<em>insert</em>end&gt;</p>
-    async fn create_data_source_from_redshift(
+    /// Presigns the request `create_data_source_from_redshift` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_data_source_from_redshift(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataSourceFromRedshiftInput,
-    ) -> Result<CreateDataSourceFromRedshiftOutput, RusotoError<CreateDataSourceFromRedshiftError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_data_source_from_redshift_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_data_source_from_redshift_request(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateDataSourceFromRedshift");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3190,676 +3552,1937 @@ impl MachineLearning for MachineLearningClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_data_source_from_s3`, also returning the metadata of the response.
+    pub async fn create_data_source_from_s3_with_metadata(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> Result<
+        (CreateDataSourceFromS3Output, ResponseMetadata),
+        RusotoError<CreateDataSourceFromS3Error>,
+    > {
+        let request = self.build_create_data_source_from_s3_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDataSourceFromRedshiftOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDataSourceFromS3Output, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataSourceFromRedshiftError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDataSourceFromS3Error::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>DataSource</code> object. A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromS3</code> is an asynchronous operation. In response to <code>CreateDataSourceFromS3</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> has been created and is ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code> or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can't accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observation data used in a <code>DataSource</code> should be ready to use; that is, it should have a consistent structure, and missing data values should be kept to a minimum. The observation data must reside in one or more .csv files in an Amazon Simple Storage Service (Amazon S3) location, along with a schema that describes the data items by name and type. The same schema must be used for all of the data files referenced by the <code>DataSource</code>. </p> <p>After the <code>DataSource</code> has been created, it's ready to use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also needs a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p>
-    async fn create_data_source_from_s3(
+    /// Presigns the request `create_data_source_from_s3` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_data_source_from_s3(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataSourceFromS3Input,
-    ) -> Result<CreateDataSourceFromS3Output, RusotoError<CreateDataSourceFromS3Error>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_data_source_from_s3_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_data_source_from_s3_request(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateDataSourceFromS3");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromS3");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_evaluation`, also returning the metadata of the response.
+    pub async fn create_evaluation_with_metadata(
+        &self,
+        input: CreateEvaluationInput,
+    ) -> Result<(CreateEvaluationOutput, ResponseMetadata), RusotoError<CreateEvaluationError>>
+    {
+        let request = self.build_create_evaluation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDataSourceFromS3Output, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEvaluationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataSourceFromS3Error::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEvaluationError::from_response(response))
         }
     }
 
-    /// <p>Creates a new <code>Evaluation</code> of an <code>MLModel</code>. An <code>MLModel</code> is evaluated on a set of observations associated to a <code>DataSource</code>. Like a <code>DataSource</code> for an <code>MLModel</code>, the <code>DataSource</code> for an <code>Evaluation</code> contains values for the <code>Target Variable</code>. The <code>Evaluation</code> compares the predicted result for each observation to the actual outcome and provides a summary so that you know how effective the <code>MLModel</code> functions on the test data. Evaluation generates a relevant performance metric, such as BinaryAUC, RegressionRMSE or MulticlassAvgFScore based on the corresponding <code>MLModelType</code>: <code>BINARY</code>, <code>REGRESSION</code> or <code>MULTICLASS</code>. </p> <p><code>CreateEvaluation</code> is an asynchronous operation. In response to <code>CreateEvaluation</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the evaluation status to <code>PENDING</code>. After the <code>Evaluation</code> is created and ready for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetEvaluation</code> operation to check progress of the evaluation during the creation operation.</p>
-    async fn create_evaluation(
+    /// Presigns the request `create_evaluation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_evaluation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEvaluationInput,
-    ) -> Result<CreateEvaluationOutput, RusotoError<CreateEvaluationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_evaluation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_evaluation_request(&self, input: CreateEvaluationInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateEvaluation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_ml_model`, also returning the metadata of the response.
+    pub async fn create_ml_model_with_metadata(
+        &self,
+        input: CreateMLModelInput,
+    ) -> Result<(CreateMLModelOutput, ResponseMetadata), RusotoError<CreateMLModelError>> {
+        let request = self.build_create_ml_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateEvaluationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateMLModelOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEvaluationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateMLModelError::from_response(response))
         }
     }
 
-    /// <p>Creates a new <code>MLModel</code> using the <code>DataSource</code> and the recipe as information sources. </p> <p>An <code>MLModel</code> is nearly immutable. Users can update only the <code>MLModelName</code> and the <code>ScoreThreshold</code> in an <code>MLModel</code> without creating a new <code>MLModel</code>. </p> <p><code>CreateMLModel</code> is an asynchronous operation. In response to <code>CreateMLModel</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>MLModel</code> status to <code>PENDING</code>. After the <code>MLModel</code> has been created and ready is for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetMLModel</code> operation to check the progress of the <code>MLModel</code> during the creation operation.</p> <p> <code>CreateMLModel</code> requires a <code>DataSource</code> with computed statistics, which can be created by setting <code>ComputeStatistics</code> to <code>true</code> in <code>CreateDataSourceFromRDS</code>, <code>CreateDataSourceFromS3</code>, or <code>CreateDataSourceFromRedshift</code> operations. </p>
-    async fn create_ml_model(
+    /// Presigns the request `create_ml_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_ml_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMLModelInput,
-    ) -> Result<CreateMLModelOutput, RusotoError<CreateMLModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_ml_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_ml_model_request(&self, input: CreateMLModelInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateMLModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_realtime_endpoint`, also returning the metadata of the response.
+    pub async fn create_realtime_endpoint_with_metadata(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> Result<
+        (CreateRealtimeEndpointOutput, ResponseMetadata),
+        RusotoError<CreateRealtimeEndpointError>,
+    > {
+        let request = self.build_create_realtime_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateMLModelOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRealtimeEndpointOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMLModelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRealtimeEndpointError::from_response(response))
         }
     }
 
-    /// <p>Creates a real-time endpoint for the <code>MLModel</code>. The endpoint contains the URI of the <code>MLModel</code>; that is, the location to send real-time prediction requests for the specified <code>MLModel</code>.</p>
-    async fn create_realtime_endpoint(
+    /// Presigns the request `create_realtime_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_realtime_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRealtimeEndpointInput,
-    ) -> Result<CreateRealtimeEndpointOutput, RusotoError<CreateRealtimeEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_realtime_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_realtime_endpoint_request(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateRealtimeEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.CreateRealtimeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_batch_prediction`, also returning the metadata of the response.
+    pub async fn delete_batch_prediction_with_metadata(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> Result<
+        (DeleteBatchPredictionOutput, ResponseMetadata),
+        RusotoError<DeleteBatchPredictionError>,
+    > {
+        let request = self.build_delete_batch_prediction_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRealtimeEndpointOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBatchPredictionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRealtimeEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBatchPredictionError::from_response(response))
         }
     }
 
-    /// <p>Assigns the DELETED status to a <code>BatchPrediction</code>, rendering it unusable.</p> <p>After using the <code>DeleteBatchPrediction</code> operation, you can use the <a>GetBatchPrediction</a> operation to verify that the status of the <code>BatchPrediction</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteBatchPrediction</code> operation is irreversible.</p>
-    async fn delete_batch_prediction(
+    /// Presigns the request `delete_batch_prediction` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_batch_prediction(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBatchPredictionInput,
-    ) -> Result<DeleteBatchPredictionOutput, RusotoError<DeleteBatchPredictionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_batch_prediction_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_batch_prediction_request(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteBatchPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_data_source`, also returning the metadata of the response.
+    pub async fn delete_data_source_with_metadata(
+        &self,
+        input: DeleteDataSourceInput,
+    ) -> Result<(DeleteDataSourceOutput, ResponseMetadata), RusotoError<DeleteDataSourceError>>
+    {
+        let request = self.build_delete_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteBatchPredictionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDataSourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBatchPredictionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Assigns the DELETED status to a <code>DataSource</code>, rendering it unusable.</p> <p>After using the <code>DeleteDataSource</code> operation, you can use the <a>GetDataSource</a> operation to verify that the status of the <code>DataSource</code> changed to DELETED.</p> <p><b>Caution:</b> The results of the <code>DeleteDataSource</code> operation are irreversible.</p>
-    async fn delete_data_source(
+    /// Presigns the request `delete_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDataSourceInput,
-    ) -> Result<DeleteDataSourceOutput, RusotoError<DeleteDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_data_source_request(&self, input: DeleteDataSourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_evaluation`, also returning the metadata of the response.
+    pub async fn delete_evaluation_with_metadata(
+        &self,
+        input: DeleteEvaluationInput,
+    ) -> Result<(DeleteEvaluationOutput, ResponseMetadata), RusotoError<DeleteEvaluationError>>
+    {
+        let request = self.build_delete_evaluation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteDataSourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteEvaluationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDataSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEvaluationError::from_response(response))
         }
     }
 
-    /// <p><p>Assigns the <code>DELETED</code> status to an <code>Evaluation</code>, rendering it unusable.</p> <p>After invoking the <code>DeleteEvaluation</code> operation, you can use the <code>GetEvaluation</code> operation to verify that the status of the <code>Evaluation</code> changed to <code>DELETED</code>.</p> <caution><title>Caution</title> <p>The results of the <code>DeleteEvaluation</code> operation are irreversible.</p></caution></p>
-    async fn delete_evaluation(
+    /// Presigns the request `delete_evaluation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_evaluation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEvaluationInput,
-    ) -> Result<DeleteEvaluationOutput, RusotoError<DeleteEvaluationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_evaluation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_evaluation_request(&self, input: DeleteEvaluationInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteEvaluation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_ml_model`, also returning the metadata of the response.
+    pub async fn delete_ml_model_with_metadata(
+        &self,
+        input: DeleteMLModelInput,
+    ) -> Result<(DeleteMLModelOutput, ResponseMetadata), RusotoError<DeleteMLModelError>> {
+        let request = self.build_delete_ml_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteEvaluationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteMLModelOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEvaluationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteMLModelError::from_response(response))
         }
     }
 
-    /// <p>Assigns the <code>DELETED</code> status to an <code>MLModel</code>, rendering it unusable.</p> <p>After using the <code>DeleteMLModel</code> operation, you can use the <code>GetMLModel</code> operation to verify that the status of the <code>MLModel</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteMLModel</code> operation is irreversible.</p>
-    async fn delete_ml_model(
+    /// Presigns the request `delete_ml_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_ml_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMLModelInput,
-    ) -> Result<DeleteMLModelOutput, RusotoError<DeleteMLModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_ml_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_ml_model_request(&self, input: DeleteMLModelInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteMLModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_realtime_endpoint`, also returning the metadata of the response.
+    pub async fn delete_realtime_endpoint_with_metadata(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> Result<
+        (DeleteRealtimeEndpointOutput, ResponseMetadata),
+        RusotoError<DeleteRealtimeEndpointError>,
+    > {
+        let request = self.build_delete_realtime_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteMLModelOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRealtimeEndpointOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMLModelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRealtimeEndpointError::from_response(response))
         }
     }
 
-    /// <p>Deletes a real time endpoint of an <code>MLModel</code>.</p>
-    async fn delete_realtime_endpoint(
+    /// Presigns the request `delete_realtime_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_realtime_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRealtimeEndpointInput,
-    ) -> Result<DeleteRealtimeEndpointOutput, RusotoError<DeleteRealtimeEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_realtime_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_realtime_endpoint_request(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteRealtimeEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteRealtimeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_tags`, also returning the metadata of the response.
+    pub async fn delete_tags_with_metadata(
+        &self,
+        input: DeleteTagsInput,
+    ) -> Result<(DeleteTagsOutput, ResponseMetadata), RusotoError<DeleteTagsError>> {
+        let request = self.build_delete_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRealtimeEndpointOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTagsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRealtimeEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTagsError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified tags associated with an ML object. After this operation is complete, you can't recover deleted tags.</p> <p>If you specify a tag that doesn't exist, Amazon ML ignores it.</p>
-    async fn delete_tags(
+    /// Presigns the request `delete_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTagsInput,
-    ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tags_request(&self, input: DeleteTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_batch_predictions`, also returning the metadata of the response.
+    pub async fn describe_batch_predictions_with_metadata(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> Result<
+        (DescribeBatchPredictionsOutput, ResponseMetadata),
+        RusotoError<DescribeBatchPredictionsError>,
+    > {
+        let request = self.build_describe_batch_predictions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBatchPredictionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBatchPredictionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of <code>BatchPrediction</code> operations that match the search criteria in the request.</p>
-    async fn describe_batch_predictions(
+    /// Presigns the request `describe_batch_predictions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_batch_predictions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBatchPredictionsInput,
-    ) -> Result<DescribeBatchPredictionsOutput, RusotoError<DescribeBatchPredictionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_batch_predictions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_batch_predictions_request(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeBatchPredictions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeBatchPredictions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_data_sources`, also returning the metadata of the response.
+    pub async fn describe_data_sources_with_metadata(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> Result<(DescribeDataSourcesOutput, ResponseMetadata), RusotoError<DescribeDataSourcesError>>
+    {
+        let request = self.build_describe_data_sources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBatchPredictionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDataSourcesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBatchPredictionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDataSourcesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of <code>DataSource</code> that match the search criteria in the request.</p>
-    async fn describe_data_sources(
+    /// Presigns the request `describe_data_sources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_data_sources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDataSourcesInput,
-    ) -> Result<DescribeDataSourcesOutput, RusotoError<DescribeDataSourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_data_sources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_data_sources_request(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeDataSources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeDataSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_evaluations`, also returning the metadata of the response.
+    pub async fn describe_evaluations_with_metadata(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> Result<(DescribeEvaluationsOutput, ResponseMetadata), RusotoError<DescribeEvaluationsError>>
+    {
+        let request = self.build_describe_evaluations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDataSourcesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEvaluationsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDataSourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEvaluationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of <code>DescribeEvaluations</code> that match the search criteria in the request.</p>
-    async fn describe_evaluations(
+    /// Presigns the request `describe_evaluations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_evaluations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEvaluationsInput,
-    ) -> Result<DescribeEvaluationsOutput, RusotoError<DescribeEvaluationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_evaluations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_evaluations_request(&self, input: DescribeEvaluationsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeEvaluations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeEvaluations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_ml_models`, also returning the metadata of the response.
+    pub async fn describe_ml_models_with_metadata(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> Result<(DescribeMLModelsOutput, ResponseMetadata), RusotoError<DescribeMLModelsError>>
+    {
+        let request = self.build_describe_ml_models_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEvaluationsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMLModelsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEvaluationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMLModelsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of <code>MLModel</code> that match the search criteria in the request.</p>
-    async fn describe_ml_models(
+    /// Presigns the request `describe_ml_models` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_ml_models(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMLModelsInput,
-    ) -> Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_ml_models_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_ml_models_request(&self, input: DescribeMLModelsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeMLModels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeMLModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_tags`, also returning the metadata of the response.
+    pub async fn describe_tags_with_metadata(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<(DescribeTagsOutput, ResponseMetadata), RusotoError<DescribeTagsError>> {
+        let request = self.build_describe_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeMLModelsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTagsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMLModelsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTagsError::from_response(response))
         }
     }
 
-    /// <p>Describes one or more of the tags for your Amazon ML object.</p>
-    async fn describe_tags(
+    /// Presigns the request `describe_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTagsInput,
-    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tags_request(&self, input: DescribeTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_batch_prediction`, also returning the metadata of the response.
+    pub async fn get_batch_prediction_with_metadata(
+        &self,
+        input: GetBatchPredictionInput,
+    ) -> Result<(GetBatchPredictionOutput, ResponseMetadata), RusotoError<GetBatchPredictionError>>
+    {
+        let request = self.build_get_batch_prediction_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetBatchPredictionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetBatchPredictionError::from_response(response))
         }
     }
 
-    /// <p>Returns a <code>BatchPrediction</code> that includes detailed metadata, status, and data file information for a <code>Batch Prediction</code> request.</p>
-    async fn get_batch_prediction(
+    /// Presigns the request `get_batch_prediction` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_batch_prediction(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBatchPredictionInput,
-    ) -> Result<GetBatchPredictionOutput, RusotoError<GetBatchPredictionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_batch_prediction_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_batch_prediction_request(&self, input: GetBatchPredictionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetBatchPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.GetBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_data_source`, also returning the metadata of the response.
+    pub async fn get_data_source_with_metadata(
+        &self,
+        input: GetDataSourceInput,
+    ) -> Result<(GetDataSourceOutput, ResponseMetadata), RusotoError<GetDataSourceError>> {
+        let request = self.build_get_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBatchPredictionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDataSourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBatchPredictionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a <code>DataSource</code> that includes metadata and data file information, as well as the current status of the <code>DataSource</code>.</p> <p><code>GetDataSource</code> provides results in normal or verbose format. The verbose format adds the schema description and the list of files pointed to by the DataSource to the normal format.</p>
-    async fn get_data_source(
+    /// Presigns the request `get_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDataSourceInput,
-    ) -> Result<GetDataSourceOutput, RusotoError<GetDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_data_source_request(&self, input: GetDataSourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.GetDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_evaluation`, also returning the metadata of the response.
+    pub async fn get_evaluation_with_metadata(
+        &self,
+        input: GetEvaluationInput,
+    ) -> Result<(GetEvaluationOutput, ResponseMetadata), RusotoError<GetEvaluationError>> {
+        let request = self.build_get_evaluation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDataSourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetEvaluationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDataSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetEvaluationError::from_response(response))
         }
     }
 
-    /// <p>Returns an <code>Evaluation</code> that includes metadata as well as the current status of the <code>Evaluation</code>.</p>
-    async fn get_evaluation(
+    /// Presigns the request `get_evaluation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_evaluation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEvaluationInput,
-    ) -> Result<GetEvaluationOutput, RusotoError<GetEvaluationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_evaluation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_evaluation_request(&self, input: GetEvaluationInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetEvaluation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.GetEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ml_model`, also returning the metadata of the response.
+    pub async fn get_ml_model_with_metadata(
+        &self,
+        input: GetMLModelInput,
+    ) -> Result<(GetMLModelOutput, ResponseMetadata), RusotoError<GetMLModelError>> {
+        let request = self.build_get_ml_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetEvaluationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMLModelOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEvaluationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMLModelError::from_response(response))
         }
     }
 
-    /// <p>Returns an <code>MLModel</code> that includes detailed metadata, data source information, and the current status of the <code>MLModel</code>.</p> <p><code>GetMLModel</code> provides results in normal or verbose format. </p>
-    async fn get_ml_model(
+    /// Presigns the request `get_ml_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ml_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMLModelInput,
-    ) -> Result<GetMLModelOutput, RusotoError<GetMLModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ml_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ml_model_request(&self, input: GetMLModelInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetMLModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.GetMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `predict`, also returning the metadata of the response.
+    pub async fn predict_with_metadata(
+        &self,
+        input: PredictInput,
+    ) -> Result<(PredictOutput, ResponseMetadata), RusotoError<PredictError>> {
+        let request = self.build_predict_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetMLModelOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<PredictOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMLModelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PredictError::from_response(response))
         }
     }
 
-    /// <p><p>Generates a prediction for the observation using the specified <code>ML Model</code>.</p> <note><title>Note</title> <p>Not all response parameters will be populated. Whether a response parameter is populated depends on the type of model requested.</p></note></p>
-    async fn predict(
+    /// Presigns the request `predict` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_predict(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PredictInput,
-    ) -> Result<PredictOutput, RusotoError<PredictError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_predict_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_predict_request(&self, input: PredictInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("Predict");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.Predict");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_batch_prediction`, also returning the metadata of the response.
+    pub async fn update_batch_prediction_with_metadata(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> Result<
+        (UpdateBatchPredictionOutput, ResponseMetadata),
+        RusotoError<UpdateBatchPredictionError>,
+    > {
+        let request = self.build_update_batch_prediction_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PredictOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateBatchPredictionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PredictError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateBatchPredictionError::from_response(response))
         }
     }
 
-    /// <p>Updates the <code>BatchPredictionName</code> of a <code>BatchPrediction</code>.</p> <p>You can use the <code>GetBatchPrediction</code> operation to view the contents of the updated data element.</p>
-    async fn update_batch_prediction(
+    /// Presigns the request `update_batch_prediction` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_batch_prediction(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBatchPredictionInput,
-    ) -> Result<UpdateBatchPredictionOutput, RusotoError<UpdateBatchPredictionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_batch_prediction_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_batch_prediction_request(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateBatchPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_data_source`, also returning the metadata of the response.
+    pub async fn update_data_source_with_metadata(
+        &self,
+        input: UpdateDataSourceInput,
+    ) -> Result<(UpdateDataSourceOutput, ResponseMetadata), RusotoError<UpdateDataSourceError>>
+    {
+        let request = self.build_update_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateBatchPredictionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDataSourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBatchPredictionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Updates the <code>DataSourceName</code> of a <code>DataSource</code>.</p> <p>You can use the <code>GetDataSource</code> operation to view the contents of the updated data element.</p>
-    async fn update_data_source(
+    /// Presigns the request `update_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDataSourceInput,
-    ) -> Result<UpdateDataSourceOutput, RusotoError<UpdateDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_data_source_request(&self, input: UpdateDataSourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_evaluation`, also returning the metadata of the response.
+    pub async fn update_evaluation_with_metadata(
+        &self,
+        input: UpdateEvaluationInput,
+    ) -> Result<(UpdateEvaluationOutput, ResponseMetadata), RusotoError<UpdateEvaluationError>>
+    {
+        let request = self.build_update_evaluation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateDataSourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateEvaluationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDataSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateEvaluationError::from_response(response))
         }
     }
 
-    /// <p>Updates the <code>EvaluationName</code> of an <code>Evaluation</code>.</p> <p>You can use the <code>GetEvaluation</code> operation to view the contents of the updated data element.</p>
-    async fn update_evaluation(
+    /// Presigns the request `update_evaluation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_evaluation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEvaluationInput,
-    ) -> Result<UpdateEvaluationOutput, RusotoError<UpdateEvaluationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_evaluation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_evaluation_request(&self, input: UpdateEvaluationInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateEvaluation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_ml_model`, also returning the metadata of the response.
+    pub async fn update_ml_model_with_metadata(
+        &self,
+        input: UpdateMLModelInput,
+    ) -> Result<(UpdateMLModelOutput, ResponseMetadata), RusotoError<UpdateMLModelError>> {
+        let request = self.build_update_ml_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateEvaluationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateMLModelOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEvaluationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateMLModelError::from_response(response))
         }
     }
 
-    /// <p>Updates the <code>MLModelName</code> and the <code>ScoreThreshold</code> of an <code>MLModel</code>.</p> <p>You can use the <code>GetMLModel</code> operation to view the contents of the updated data element.</p>
-    async fn update_ml_model(
+    /// Presigns the request `update_ml_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_ml_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMLModelInput,
-    ) -> Result<UpdateMLModelOutput, RusotoError<UpdateMLModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_ml_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_ml_model_request(&self, input: UpdateMLModelInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateMLModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeBatchPredictions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_batch_predictions_pages(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeBatchPredictionsOutput, RusotoError<DescribeBatchPredictionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MachineLearningClient, input| async move {
+                client.describe_batch_predictions(input).await
+            },
+            |input: &DescribeBatchPredictionsInput, output: &DescribeBatchPredictionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeBatchPredictionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Results` of every page of `DescribeBatchPredictions` results for `input`, as `describe_batch_predictions_pages` requests them.
+    pub fn describe_batch_predictions_stream(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<BatchPrediction, RusotoError<DescribeBatchPredictionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_batch_predictions_pages(input), |page| {
+            page.results.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDataSources` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_data_sources_pages(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeDataSourcesOutput, RusotoError<DescribeDataSourcesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MachineLearningClient, input| async move {
+                client.describe_data_sources(input).await
+            },
+            |input: &DescribeDataSourcesInput, output: &DescribeDataSourcesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeDataSourcesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Results` of every page of `DescribeDataSources` results for `input`, as `describe_data_sources_pages` requests them.
+    pub fn describe_data_sources_stream(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> impl futures::Stream<Item = Result<DataSource, RusotoError<DescribeDataSourcesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_data_sources_pages(input), |page| {
+            page.results.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEvaluations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_evaluations_pages(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeEvaluationsOutput, RusotoError<DescribeEvaluationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MachineLearningClient, input| async move {
+                client.describe_evaluations(input).await
+            },
+            |input: &DescribeEvaluationsInput, output: &DescribeEvaluationsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEvaluationsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Results` of every page of `DescribeEvaluations` results for `input`, as `describe_evaluations_pages` requests them.
+    pub fn describe_evaluations_stream(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> impl futures::Stream<Item = Result<Evaluation, RusotoError<DescribeEvaluationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_evaluations_pages(input), |page| {
+            page.results.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeMLModels` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_ml_models_pages(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MachineLearningClient, input| async move { client.describe_ml_models(input).await },
+            |input: &DescribeMLModelsInput, output: &DescribeMLModelsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMLModelsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Results` of every page of `DescribeMLModels` results for `input`, as `describe_ml_models_pages` requests them.
+    pub fn describe_ml_models_stream(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> impl futures::Stream<Item = Result<MLModel, RusotoError<DescribeMLModelsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_ml_models_pages(input), |page| {
+            page.results.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `BatchPredictionAvailable`, polling `DescribeBatchPredictions` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_batch_prediction_available(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeBatchPredictionsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_batch_prediction_available_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateMLModelOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMLModelError::from_response(response))
-        }
+    }
+
+    /// Waits for `BatchPredictionAvailable` like `wait_until_batch_prediction_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_batch_prediction_available_with(
+        &self,
+        input: DescribeBatchPredictionsInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeBatchPredictionsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_batch_predictions(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.results.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "COMPLETED")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.results.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `DataSourceAvailable`, polling `DescribeDataSources` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_data_source_available(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDataSourcesError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_data_source_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `DataSourceAvailable` like `wait_until_data_source_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_data_source_available_with(
+        &self,
+        input: DescribeDataSourcesInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDataSourcesError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_data_sources(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.results.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "COMPLETED")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.results.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `EvaluationAvailable`, polling `DescribeEvaluations` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_evaluation_available(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEvaluationsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_evaluation_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `EvaluationAvailable` like `wait_until_evaluation_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_evaluation_available_with(
+        &self,
+        input: DescribeEvaluationsInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEvaluationsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_evaluations(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.results.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "COMPLETED")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.results.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `MLModelAvailable`, polling `DescribeMLModels` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_ml_model_available(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMLModelsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_ml_model_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `MLModelAvailable` like `wait_until_ml_model_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_ml_model_available_with(
+        &self,
+        input: DescribeMLModelsInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMLModelsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_ml_models(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.results.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "COMPLETED")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.results.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl MachineLearning for MachineLearningClient {
+    /// <p>Adds one or more tags to an object, up to a limit of 10. Each tag consists of a key and an optional value. If you add a tag using a key that is already associated with the ML object, <code>AddTags</code> updates the tag's value.</p>
+    async fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.add_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates predictions for a group of observations. The observations to process exist in one or more data files referenced by a <code>DataSource</code>. This operation creates a new <code>BatchPrediction</code>, and uses an <code>MLModel</code> and the data files referenced by the <code>DataSource</code> as information sources. </p> <p><code>CreateBatchPrediction</code> is an asynchronous operation. In response to <code>CreateBatchPrediction</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>BatchPrediction</code> status to <code>PENDING</code>. After the <code>BatchPrediction</code> completes, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can poll for status updates by using the <a>GetBatchPrediction</a> operation and checking the <code>Status</code> parameter of the result. After the <code>COMPLETED</code> status appears, the results are available in the location specified by the <code>OutputUri</code> parameter.</p>
+    async fn create_batch_prediction(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> Result<CreateBatchPredictionOutput, RusotoError<CreateBatchPredictionError>> {
+        self.create_batch_prediction_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>DataSource</code> object from an <a href="http://aws.amazon.com/rds/"> Amazon Relational Database Service</a> (Amazon RDS). A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRDS</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRDS</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used only to perform <code>&gt;CreateMLModel</code>&gt;, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML cannot accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p>
+    async fn create_data_source_from_rds(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> Result<CreateDataSourceFromRDSOutput, RusotoError<CreateDataSourceFromRDSError>> {
+        self.create_data_source_from_rds_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a <code>DataSource</code> from a database hosted on an Amazon Redshift cluster. A <code>DataSource</code> references data that can be used to perform either <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRedshift</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRedshift</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in <code>COMPLETED</code> or <code>PENDING</code> states can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can&#39;t accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observations should be contained in the database hosted on an Amazon Redshift cluster and should be specified by a <code>SelectSqlQuery</code> query. Amazon ML executes an <code>Unload</code> command in Amazon Redshift to transfer the result set of the <code>SelectSqlQuery</code> query to <code>S3StagingLocation</code>.</p> <p>After the <code>DataSource</code> has been created, it&#39;s ready for use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also requires a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p> &lt;?oxy<em>insert</em>start author=&quot;laurama&quot; timestamp=&quot;20160406T153842-0700&quot;&gt;<p>You can&#39;t change an existing datasource, but you can copy and modify the settings from an existing Amazon Redshift datasource to create a new datasource. To do so, call <code>GetDataSource</code> for an existing datasource and copy the values to a <code>CreateDataSource</code> call. Change the settings that you want to change and make sure that all required fields have the appropriate values.</p> &lt;?oxy<em>insert</em>end&gt;</p>
+    async fn create_data_source_from_redshift(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> Result<CreateDataSourceFromRedshiftOutput, RusotoError<CreateDataSourceFromRedshiftError>>
+    {
+        self.create_data_source_from_redshift_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>DataSource</code> object. A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromS3</code> is an asynchronous operation. In response to <code>CreateDataSourceFromS3</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> has been created and is ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code> or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can't accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observation data used in a <code>DataSource</code> should be ready to use; that is, it should have a consistent structure, and missing data values should be kept to a minimum. The observation data must reside in one or more .csv files in an Amazon Simple Storage Service (Amazon S3) location, along with a schema that describes the data items by name and type. The same schema must be used for all of the data files referenced by the <code>DataSource</code>. </p> <p>After the <code>DataSource</code> has been created, it's ready to use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also needs a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p>
+    async fn create_data_source_from_s3(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> Result<CreateDataSourceFromS3Output, RusotoError<CreateDataSourceFromS3Error>> {
+        self.create_data_source_from_s3_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new <code>Evaluation</code> of an <code>MLModel</code>. An <code>MLModel</code> is evaluated on a set of observations associated to a <code>DataSource</code>. Like a <code>DataSource</code> for an <code>MLModel</code>, the <code>DataSource</code> for an <code>Evaluation</code> contains values for the <code>Target Variable</code>. The <code>Evaluation</code> compares the predicted result for each observation to the actual outcome and provides a summary so that you know how effective the <code>MLModel</code> functions on the test data. Evaluation generates a relevant performance metric, such as BinaryAUC, RegressionRMSE or MulticlassAvgFScore based on the corresponding <code>MLModelType</code>: <code>BINARY</code>, <code>REGRESSION</code> or <code>MULTICLASS</code>. </p> <p><code>CreateEvaluation</code> is an asynchronous operation. In response to <code>CreateEvaluation</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the evaluation status to <code>PENDING</code>. After the <code>Evaluation</code> is created and ready for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetEvaluation</code> operation to check progress of the evaluation during the creation operation.</p>
+    async fn create_evaluation(
+        &self,
+        input: CreateEvaluationInput,
+    ) -> Result<CreateEvaluationOutput, RusotoError<CreateEvaluationError>> {
+        self.create_evaluation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new <code>MLModel</code> using the <code>DataSource</code> and the recipe as information sources. </p> <p>An <code>MLModel</code> is nearly immutable. Users can update only the <code>MLModelName</code> and the <code>ScoreThreshold</code> in an <code>MLModel</code> without creating a new <code>MLModel</code>. </p> <p><code>CreateMLModel</code> is an asynchronous operation. In response to <code>CreateMLModel</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>MLModel</code> status to <code>PENDING</code>. After the <code>MLModel</code> has been created and ready is for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetMLModel</code> operation to check the progress of the <code>MLModel</code> during the creation operation.</p> <p> <code>CreateMLModel</code> requires a <code>DataSource</code> with computed statistics, which can be created by setting <code>ComputeStatistics</code> to <code>true</code> in <code>CreateDataSourceFromRDS</code>, <code>CreateDataSourceFromS3</code>, or <code>CreateDataSourceFromRedshift</code> operations. </p>
+    async fn create_ml_model(
+        &self,
+        input: CreateMLModelInput,
+    ) -> Result<CreateMLModelOutput, RusotoError<CreateMLModelError>> {
+        self.create_ml_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a real-time endpoint for the <code>MLModel</code>. The endpoint contains the URI of the <code>MLModel</code>; that is, the location to send real-time prediction requests for the specified <code>MLModel</code>.</p>
+    async fn create_realtime_endpoint(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> Result<CreateRealtimeEndpointOutput, RusotoError<CreateRealtimeEndpointError>> {
+        self.create_realtime_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns the DELETED status to a <code>BatchPrediction</code>, rendering it unusable.</p> <p>After using the <code>DeleteBatchPrediction</code> operation, you can use the <a>GetBatchPrediction</a> operation to verify that the status of the <code>BatchPrediction</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteBatchPrediction</code> operation is irreversible.</p>
+    async fn delete_batch_prediction(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> Result<DeleteBatchPredictionOutput, RusotoError<DeleteBatchPredictionError>> {
+        self.delete_batch_prediction_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns the DELETED status to a <code>DataSource</code>, rendering it unusable.</p> <p>After using the <code>DeleteDataSource</code> operation, you can use the <a>GetDataSource</a> operation to verify that the status of the <code>DataSource</code> changed to DELETED.</p> <p><b>Caution:</b> The results of the <code>DeleteDataSource</code> operation are irreversible.</p>
+    async fn delete_data_source(
+        &self,
+        input: DeleteDataSourceInput,
+    ) -> Result<DeleteDataSourceOutput, RusotoError<DeleteDataSourceError>> {
+        self.delete_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Assigns the <code>DELETED</code> status to an <code>Evaluation</code>, rendering it unusable.</p> <p>After invoking the <code>DeleteEvaluation</code> operation, you can use the <code>GetEvaluation</code> operation to verify that the status of the <code>Evaluation</code> changed to <code>DELETED</code>.</p> <caution><title>Caution</title> <p>The results of the <code>DeleteEvaluation</code> operation are irreversible.</p></caution></p>
+    async fn delete_evaluation(
+        &self,
+        input: DeleteEvaluationInput,
+    ) -> Result<DeleteEvaluationOutput, RusotoError<DeleteEvaluationError>> {
+        self.delete_evaluation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns the <code>DELETED</code> status to an <code>MLModel</code>, rendering it unusable.</p> <p>After using the <code>DeleteMLModel</code> operation, you can use the <code>GetMLModel</code> operation to verify that the status of the <code>MLModel</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteMLModel</code> operation is irreversible.</p>
+    async fn delete_ml_model(
+        &self,
+        input: DeleteMLModelInput,
+    ) -> Result<DeleteMLModelOutput, RusotoError<DeleteMLModelError>> {
+        self.delete_ml_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a real time endpoint of an <code>MLModel</code>.</p>
+    async fn delete_realtime_endpoint(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> Result<DeleteRealtimeEndpointOutput, RusotoError<DeleteRealtimeEndpointError>> {
+        self.delete_realtime_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified tags associated with an ML object. After this operation is complete, you can't recover deleted tags.</p> <p>If you specify a tag that doesn't exist, Amazon ML ignores it.</p>
+    async fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
+        self.delete_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <code>BatchPrediction</code> operations that match the search criteria in the request.</p>
+    async fn describe_batch_predictions(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> Result<DescribeBatchPredictionsOutput, RusotoError<DescribeBatchPredictionsError>> {
+        self.describe_batch_predictions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <code>DataSource</code> that match the search criteria in the request.</p>
+    async fn describe_data_sources(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> Result<DescribeDataSourcesOutput, RusotoError<DescribeDataSourcesError>> {
+        self.describe_data_sources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <code>DescribeEvaluations</code> that match the search criteria in the request.</p>
+    async fn describe_evaluations(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> Result<DescribeEvaluationsOutput, RusotoError<DescribeEvaluationsError>> {
+        self.describe_evaluations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <code>MLModel</code> that match the search criteria in the request.</p>
+    async fn describe_ml_models(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>> {
+        self.describe_ml_models_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more of the tags for your Amazon ML object.</p>
+    async fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        self.describe_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a <code>BatchPrediction</code> that includes detailed metadata, status, and data file information for a <code>Batch Prediction</code> request.</p>
+    async fn get_batch_prediction(
+        &self,
+        input: GetBatchPredictionInput,
+    ) -> Result<GetBatchPredictionOutput, RusotoError<GetBatchPredictionError>> {
+        self.get_batch_prediction_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a <code>DataSource</code> that includes metadata and data file information, as well as the current status of the <code>DataSource</code>.</p> <p><code>GetDataSource</code> provides results in normal or verbose format. The verbose format adds the schema description and the list of files pointed to by the DataSource to the normal format.</p>
+    async fn get_data_source(
+        &self,
+        input: GetDataSourceInput,
+    ) -> Result<GetDataSourceOutput, RusotoError<GetDataSourceError>> {
+        self.get_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an <code>Evaluation</code> that includes metadata as well as the current status of the <code>Evaluation</code>.</p>
+    async fn get_evaluation(
+        &self,
+        input: GetEvaluationInput,
+    ) -> Result<GetEvaluationOutput, RusotoError<GetEvaluationError>> {
+        self.get_evaluation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an <code>MLModel</code> that includes detailed metadata, data source information, and the current status of the <code>MLModel</code>.</p> <p><code>GetMLModel</code> provides results in normal or verbose format. </p>
+    async fn get_ml_model(
+        &self,
+        input: GetMLModelInput,
+    ) -> Result<GetMLModelOutput, RusotoError<GetMLModelError>> {
+        self.get_ml_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Generates a prediction for the observation using the specified <code>ML Model</code>.</p> <note><title>Note</title> <p>Not all response parameters will be populated. Whether a response parameter is populated depends on the type of model requested.</p></note></p>
+    async fn predict(
+        &self,
+        input: PredictInput,
+    ) -> Result<PredictOutput, RusotoError<PredictError>> {
+        self.predict_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the <code>BatchPredictionName</code> of a <code>BatchPrediction</code>.</p> <p>You can use the <code>GetBatchPrediction</code> operation to view the contents of the updated data element.</p>
+    async fn update_batch_prediction(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> Result<UpdateBatchPredictionOutput, RusotoError<UpdateBatchPredictionError>> {
+        self.update_batch_prediction_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the <code>DataSourceName</code> of a <code>DataSource</code>.</p> <p>You can use the <code>GetDataSource</code> operation to view the contents of the updated data element.</p>
+    async fn update_data_source(
+        &self,
+        input: UpdateDataSourceInput,
+    ) -> Result<UpdateDataSourceOutput, RusotoError<UpdateDataSourceError>> {
+        self.update_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the <code>EvaluationName</code> of an <code>Evaluation</code>.</p> <p>You can use the <code>GetEvaluation</code> operation to view the contents of the updated data element.</p>
+    async fn update_evaluation(
+        &self,
+        input: UpdateEvaluationInput,
+    ) -> Result<UpdateEvaluationOutput, RusotoError<UpdateEvaluationError>> {
+        self.update_evaluation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the <code>MLModelName</code> and the <code>ScoreThreshold</code> of an <code>MLModel</code>.</p> <p>You can use the <code>GetMLModel</code> operation to view the contents of the updated data element.</p>
+    async fn update_ml_model(
+        &self,
+        input: UpdateMLModelInput,
+    ) -> Result<UpdateMLModelOutput, RusotoError<UpdateMLModelError>> {
+        self.update_ml_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -257,6 +257,7 @@ pub struct UpdateS3ResourcesResult {
 
 /// Errors returned by AssociateMemberAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateMemberAccountError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -264,6 +265,8 @@ pub enum AssociateMemberAccountError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded. </p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateMemberAccountError {
@@ -271,18 +274,25 @@ impl AssociateMemberAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(AssociateMemberAccountError::Internal(err.msg))
+                    return RusotoError::service(
+                        AssociateMemberAccountError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateMemberAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        AssociateMemberAccountError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateMemberAccountError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateMemberAccountError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateMemberAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -295,12 +305,14 @@ impl fmt::Display for AssociateMemberAccountError {
             AssociateMemberAccountError::Internal(ref cause) => write!(f, "{}", cause),
             AssociateMemberAccountError::InvalidInput(ref cause) => write!(f, "{}", cause),
             AssociateMemberAccountError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            AssociateMemberAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateMemberAccountError {}
 /// Errors returned by AssociateS3Resources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateS3ResourcesError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -310,6 +322,8 @@ pub enum AssociateS3ResourcesError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded. </p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateS3ResourcesError {
@@ -317,19 +331,28 @@ impl AssociateS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        AssociateS3ResourcesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service(AssociateS3ResourcesError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        AssociateS3ResourcesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        AssociateS3ResourcesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateS3ResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -343,17 +366,21 @@ impl fmt::Display for AssociateS3ResourcesError {
             AssociateS3ResourcesError::Internal(ref cause) => write!(f, "{}", cause),
             AssociateS3ResourcesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             AssociateS3ResourcesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            AssociateS3ResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateS3ResourcesError {}
 /// Errors returned by DisassociateMemberAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateMemberAccountError {
     /// <p>Internal server error.</p>
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter. </p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateMemberAccountError {
@@ -361,15 +388,21 @@ impl DisassociateMemberAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateMemberAccountError::Internal(err.msg))
+                    return RusotoError::service(
+                        DisassociateMemberAccountError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateMemberAccountError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateMemberAccountError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DisassociateMemberAccountError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -381,12 +414,14 @@ impl fmt::Display for DisassociateMemberAccountError {
         match *self {
             DisassociateMemberAccountError::Internal(ref cause) => write!(f, "{}", cause),
             DisassociateMemberAccountError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DisassociateMemberAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateMemberAccountError {}
 /// Errors returned by DisassociateS3Resources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateS3ResourcesError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -394,6 +429,8 @@ pub enum DisassociateS3ResourcesError {
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter. </p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateS3ResourcesError {
@@ -401,20 +438,25 @@ impl DisassociateS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateS3ResourcesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateS3ResourcesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service(
+                        DisassociateS3ResourcesError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateS3ResourcesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateS3ResourcesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateS3ResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -427,17 +469,21 @@ impl fmt::Display for DisassociateS3ResourcesError {
             DisassociateS3ResourcesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             DisassociateS3ResourcesError::Internal(ref cause) => write!(f, "{}", cause),
             DisassociateS3ResourcesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DisassociateS3ResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateS3ResourcesError {}
 /// Errors returned by ListMemberAccounts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMemberAccountsError {
     /// <p>Internal server error.</p>
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter. </p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMemberAccountsError {
@@ -445,13 +491,16 @@ impl ListMemberAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListMemberAccountsError::Internal(err.msg))
+                    return RusotoError::service(ListMemberAccountsError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMemberAccountsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListMemberAccountsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMemberAccountsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -463,12 +512,14 @@ impl fmt::Display for ListMemberAccountsError {
         match *self {
             ListMemberAccountsError::Internal(ref cause) => write!(f, "{}", cause),
             ListMemberAccountsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListMemberAccountsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMemberAccountsError {}
 /// Errors returned by ListS3Resources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListS3ResourcesError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -476,6 +527,8 @@ pub enum ListS3ResourcesError {
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter. </p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListS3ResourcesError {
@@ -483,16 +536,16 @@ impl ListS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListS3ResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListS3ResourcesError::AccessDenied(err.msg), &res)
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service(ListS3ResourcesError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListS3ResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(ListS3ResourcesError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListS3ResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -505,12 +558,14 @@ impl fmt::Display for ListS3ResourcesError {
             ListS3ResourcesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListS3ResourcesError::Internal(ref cause) => write!(f, "{}", cause),
             ListS3ResourcesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListS3ResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListS3ResourcesError {}
 /// Errors returned by UpdateS3Resources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateS3ResourcesError {
     /// <p>You do not have required permissions to access the requested resource.</p>
     AccessDenied(String),
@@ -518,6 +573,8 @@ pub enum UpdateS3ResourcesError {
     Internal(String),
     /// <p>The request was rejected because an invalid or out-of-range value was supplied for an input parameter. </p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateS3ResourcesError {
@@ -525,16 +582,22 @@ impl UpdateS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateS3ResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateS3ResourcesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service(UpdateS3ResourcesError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateS3ResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateS3ResourcesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateS3ResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -547,6 +610,7 @@ impl fmt::Display for UpdateS3ResourcesError {
             UpdateS3ResourcesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             UpdateS3ResourcesError::Internal(ref cause) => write!(f, "{}", cause),
             UpdateS3ResourcesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            UpdateS3ResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -607,6 +671,7 @@ impl MacieClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MacieClient {
         MacieClient {
             client: Client::shared(),
@@ -632,199 +697,471 @@ impl MacieClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MacieClient {
         MacieClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MacieClient> {
+        rusoto_core::ClientBuilder::new(MacieClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MacieClient {
+        MacieClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Macie for MacieClient {
-    /// <p>Associates a specified AWS account with Amazon Macie as a member account.</p>
-    async fn associate_member_account(
+impl MacieClient {
+    /// Calls `associate_member_account`, also returning the metadata of the response.
+    pub async fn associate_member_account_with_metadata(
         &self,
         input: AssociateMemberAccountRequest,
-    ) -> Result<(), RusotoError<AssociateMemberAccountError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<AssociateMemberAccountError>> {
+        let request = self.build_associate_member_account_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateMemberAccountError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_member_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_member_account(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateMemberAccountRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_member_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_member_account_request(
+        &self,
+        input: AssociateMemberAccountRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("AssociateMemberAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.AssociateMemberAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_s3_resources`, also returning the metadata of the response.
+    pub async fn associate_s3_resources_with_metadata(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> Result<
+        (AssociateS3ResourcesResult, ResponseMetadata),
+        RusotoError<AssociateS3ResourcesError>,
+    > {
+        let request = self.build_associate_s3_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateS3ResourcesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateMemberAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateS3ResourcesError::from_response(response))
         }
     }
 
-    /// <p>Associates specified S3 resources with Amazon Macie for monitoring and data classification. If memberAccountId isn't specified, the action associates specified S3 resources with Macie for the current master account. If memberAccountId is specified, the action associates specified S3 resources with Macie for the specified member account. </p>
-    async fn associate_s3_resources(
+    /// Presigns the request `associate_s3_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_s3_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateS3ResourcesRequest,
-    ) -> Result<AssociateS3ResourcesResult, RusotoError<AssociateS3ResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_s3_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_s3_resources_request(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("AssociateS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.AssociateS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_member_account`, also returning the metadata of the response.
+    pub async fn disassociate_member_account_with_metadata(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisassociateMemberAccountError>> {
+        let request = self.build_disassociate_member_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateS3ResourcesResult, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateS3ResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateMemberAccountError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified member account from Amazon Macie.</p>
-    async fn disassociate_member_account(
+    /// Presigns the request `disassociate_member_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_member_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateMemberAccountRequest,
-    ) -> Result<(), RusotoError<DisassociateMemberAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_member_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_member_account_request(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("DisassociateMemberAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.DisassociateMemberAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_s3_resources`, also returning the metadata of the response.
+    pub async fn disassociate_s3_resources_with_metadata(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> Result<
+        (DisassociateS3ResourcesResult, ResponseMetadata),
+        RusotoError<DisassociateS3ResourcesError>,
+    > {
+        let request = self.build_disassociate_s3_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateS3ResourcesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateMemberAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateS3ResourcesError::from_response(response))
         }
     }
 
-    /// <p>Removes specified S3 resources from being monitored by Amazon Macie. If memberAccountId isn't specified, the action removes specified S3 resources from Macie for the current master account. If memberAccountId is specified, the action removes specified S3 resources from Macie for the specified member account.</p>
-    async fn disassociate_s3_resources(
+    /// Presigns the request `disassociate_s3_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_s3_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateS3ResourcesRequest,
-    ) -> Result<DisassociateS3ResourcesResult, RusotoError<DisassociateS3ResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_s3_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_s3_resources_request(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("DisassociateS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.DisassociateS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_member_accounts`, also returning the metadata of the response.
+    pub async fn list_member_accounts_with_metadata(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> Result<(ListMemberAccountsResult, ResponseMetadata), RusotoError<ListMemberAccountsError>>
+    {
+        let request = self.build_list_member_accounts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateS3ResourcesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListMemberAccountsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateS3ResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListMemberAccountsError::from_response(response))
         }
     }
 
-    /// <p>Lists all Amazon Macie member accounts for the current Amazon Macie master account.</p>
-    async fn list_member_accounts(
+    /// Presigns the request `list_member_accounts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_member_accounts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMemberAccountsRequest,
-    ) -> Result<ListMemberAccountsResult, RusotoError<ListMemberAccountsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_member_accounts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_member_accounts_request(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("ListMemberAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.ListMemberAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_s3_resources`, also returning the metadata of the response.
+    pub async fn list_s3_resources_with_metadata(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> Result<(ListS3ResourcesResult, ResponseMetadata), RusotoError<ListS3ResourcesError>> {
+        let request = self.build_list_s3_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMemberAccountsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListS3ResourcesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMemberAccountsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListS3ResourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists all the S3 resources associated with Amazon Macie. If memberAccountId isn't specified, the action lists the S3 resources associated with Amazon Macie for the current master account. If memberAccountId is specified, the action lists the S3 resources associated with Amazon Macie for the specified member account. </p>
-    async fn list_s3_resources(
+    /// Presigns the request `list_s3_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_s3_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListS3ResourcesRequest,
-    ) -> Result<ListS3ResourcesResult, RusotoError<ListS3ResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_s3_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_s3_resources_request(&self, input: ListS3ResourcesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("ListS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.ListS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_s3_resources`, also returning the metadata of the response.
+    pub async fn update_s3_resources_with_metadata(
+        &self,
+        input: UpdateS3ResourcesRequest,
+    ) -> Result<(UpdateS3ResourcesResult, ResponseMetadata), RusotoError<UpdateS3ResourcesError>>
+    {
+        let request = self.build_update_s3_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListS3ResourcesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateS3ResourcesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListS3ResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateS3ResourcesError::from_response(response))
         }
     }
 
-    /// <p>Updates the classification types for the specified S3 resources. If memberAccountId isn't specified, the action updates the classification types of the S3 resources associated with Amazon Macie for the current master account. If memberAccountId is specified, the action updates the classification types of the S3 resources associated with Amazon Macie for the specified member account. </p>
-    async fn update_s3_resources(
+    /// Presigns the request `update_s3_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_s3_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateS3ResourcesRequest,
-    ) -> Result<UpdateS3ResourcesResult, RusotoError<UpdateS3ResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_s3_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_s3_resources_request(&self, input: UpdateS3ResourcesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("UpdateS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.UpdateS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Macie for MacieClient {
+    /// <p>Associates a specified AWS account with Amazon Macie as a member account.</p>
+    async fn associate_member_account(
+        &self,
+        input: AssociateMemberAccountRequest,
+    ) -> Result<(), RusotoError<AssociateMemberAccountError>> {
+        self.associate_member_account_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateS3ResourcesResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateS3ResourcesError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates specified S3 resources with Amazon Macie for monitoring and data classification. If memberAccountId isn't specified, the action associates specified S3 resources with Macie for the current master account. If memberAccountId is specified, the action associates specified S3 resources with Macie for the specified member account. </p>
+    async fn associate_s3_resources(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> Result<AssociateS3ResourcesResult, RusotoError<AssociateS3ResourcesError>> {
+        self.associate_s3_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified member account from Amazon Macie.</p>
+    async fn disassociate_member_account(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> Result<(), RusotoError<DisassociateMemberAccountError>> {
+        self.disassociate_member_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes specified S3 resources from being monitored by Amazon Macie. If memberAccountId isn't specified, the action removes specified S3 resources from Macie for the current master account. If memberAccountId is specified, the action removes specified S3 resources from Macie for the specified member account.</p>
+    async fn disassociate_s3_resources(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> Result<DisassociateS3ResourcesResult, RusotoError<DisassociateS3ResourcesError>> {
+        self.disassociate_s3_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all Amazon Macie member accounts for the current Amazon Macie master account.</p>
+    async fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> Result<ListMemberAccountsResult, RusotoError<ListMemberAccountsError>> {
+        self.list_member_accounts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the S3 resources associated with Amazon Macie. If memberAccountId isn't specified, the action lists the S3 resources associated with Amazon Macie for the current master account. If memberAccountId is specified, the action lists the S3 resources associated with Amazon Macie for the specified member account. </p>
+    async fn list_s3_resources(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> Result<ListS3ResourcesResult, RusotoError<ListS3ResourcesError>> {
+        self.list_s3_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the classification types for the specified S3 resources. If memberAccountId isn't specified, the action updates the classification types of the S3 resources associated with Amazon Macie for the current master account. If memberAccountId is specified, the action updates the classification types of the S3 resources associated with Amazon Macie for the specified member account. </p>
+    async fn update_s3_resources(
+        &self,
+        input: UpdateS3ResourcesRequest,
+    ) -> Result<UpdateS3ResourcesResult, RusotoError<UpdateS3ResourcesError>> {
+        self.update_s3_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
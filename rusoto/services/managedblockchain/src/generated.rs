@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1036,6 +1036,7 @@ pub struct VotingPolicy {
 
 /// Errors returned by CreateMember
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMemberError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1053,6 +1054,8 @@ pub enum CreateMemberError {
     ResourceNotReady(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMemberError {
@@ -1060,31 +1063,40 @@ impl CreateMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateMemberError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateMemberError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        CreateMemberError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateMemberError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateMemberError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateMemberError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateMemberError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceNotReady(err.msg))
+                    return RusotoError::service(CreateMemberError::ResourceNotReady(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateMemberError::Throttling(err.msg))
+                    return RusotoError::service(CreateMemberError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMemberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1102,12 +1114,14 @@ impl fmt::Display for CreateMemberError {
             CreateMemberError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateMemberError::ResourceNotReady(ref cause) => write!(f, "{}", cause),
             CreateMemberError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateMemberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMemberError {}
 /// Errors returned by CreateNetwork
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNetworkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1121,6 +1135,8 @@ pub enum CreateNetworkError {
     ResourceLimitExceeded(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNetworkError {
@@ -1128,25 +1144,34 @@ impl CreateNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateNetworkError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateNetworkError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        CreateNetworkError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNetworkError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateNetworkError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateNetworkError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateNetworkError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateNetworkError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateNetworkError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateNetworkError::Throttling(err.msg))
+                    return RusotoError::service(CreateNetworkError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNetworkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1162,12 +1187,14 @@ impl fmt::Display for CreateNetworkError {
             CreateNetworkError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateNetworkError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateNetworkError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateNetworkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNetworkError {}
 /// Errors returned by CreateNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNodeError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1185,6 +1212,8 @@ pub enum CreateNodeError {
     ResourceNotReady(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNodeError {
@@ -1192,31 +1221,40 @@ impl CreateNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNodeError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateNodeError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        CreateNodeError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateNodeError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateNodeError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateNodeError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateNodeError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceNotReady(err.msg))
+                    return RusotoError::service(CreateNodeError::ResourceNotReady(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateNodeError::Throttling(err.msg))
+                    return RusotoError::service(CreateNodeError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1234,12 +1272,14 @@ impl fmt::Display for CreateNodeError {
             CreateNodeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateNodeError::ResourceNotReady(ref cause) => write!(f, "{}", cause),
             CreateNodeError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNodeError {}
 /// Errors returned by CreateProposal
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProposalError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1253,6 +1293,8 @@ pub enum CreateProposalError {
     ResourceNotReady(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProposalError {
@@ -1260,25 +1302,34 @@ impl CreateProposalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProposalError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateProposalError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateProposalError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        CreateProposalError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateProposalError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateProposalError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProposalError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateProposalError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(CreateProposalError::ResourceNotReady(err.msg))
+                    return RusotoError::service(
+                        CreateProposalError::ResourceNotReady(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProposalError::Throttling(err.msg))
+                    return RusotoError::service(CreateProposalError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProposalError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1294,12 +1345,14 @@ impl fmt::Display for CreateProposalError {
             CreateProposalError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateProposalError::ResourceNotReady(ref cause) => write!(f, "{}", cause),
             CreateProposalError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateProposalError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProposalError {}
 /// Errors returned by DeleteMember
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMemberError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1313,6 +1366,8 @@ pub enum DeleteMemberError {
     ResourceNotReady(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMemberError {
@@ -1320,25 +1375,28 @@ impl DeleteMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteMemberError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteMemberError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DeleteMemberError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteMemberError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteMemberError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(DeleteMemberError::ResourceNotReady(err.msg))
+                    return RusotoError::service(DeleteMemberError::ResourceNotReady(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteMemberError::Throttling(err.msg))
+                    return RusotoError::service(DeleteMemberError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMemberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1354,12 +1412,14 @@ impl fmt::Display for DeleteMemberError {
             DeleteMemberError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteMemberError::ResourceNotReady(ref cause) => write!(f, "{}", cause),
             DeleteMemberError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteMemberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMemberError {}
 /// Errors returned by DeleteNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNodeError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1373,6 +1433,8 @@ pub enum DeleteNodeError {
     ResourceNotReady(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNodeError {
@@ -1380,25 +1442,28 @@ impl DeleteNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteNodeError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteNodeError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DeleteNodeError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteNodeError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteNodeError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(DeleteNodeError::ResourceNotReady(err.msg))
+                    return RusotoError::service(DeleteNodeError::ResourceNotReady(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteNodeError::Throttling(err.msg))
+                    return RusotoError::service(DeleteNodeError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1414,12 +1479,14 @@ impl fmt::Display for DeleteNodeError {
             DeleteNodeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteNodeError::ResourceNotReady(ref cause) => write!(f, "{}", cause),
             DeleteNodeError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNodeError {}
 /// Errors returned by GetMember
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMemberError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1431,6 +1498,8 @@ pub enum GetMemberError {
     ResourceNotFound(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMemberError {
@@ -1438,22 +1507,25 @@ impl GetMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetMemberError::AccessDenied(err.msg))
+                    return RusotoError::service(GetMemberError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        GetMemberError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetMemberError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetMemberError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetMemberError::Throttling(err.msg))
+                    return RusotoError::service(GetMemberError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMemberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1468,12 +1540,14 @@ impl fmt::Display for GetMemberError {
             GetMemberError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetMemberError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetMemberError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetMemberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMemberError {}
 /// Errors returned by GetNetwork
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetNetworkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1485,6 +1559,8 @@ pub enum GetNetworkError {
     ResourceNotFound(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetNetworkError {
@@ -1492,22 +1568,25 @@ impl GetNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(GetNetworkError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetNetworkError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        GetNetworkError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNetworkError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetNetworkError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetNetworkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetNetworkError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNetworkError::Throttling(err.msg))
+                    return RusotoError::service(GetNetworkError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetNetworkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1522,12 +1601,14 @@ impl fmt::Display for GetNetworkError {
             GetNetworkError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetNetworkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetNetworkError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetNetworkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetNetworkError {}
 /// Errors returned by GetNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetNodeError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1539,6 +1620,8 @@ pub enum GetNodeError {
     ResourceNotFound(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetNodeError {
@@ -1546,22 +1629,22 @@ impl GetNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetNodeError::AccessDenied(err.msg))
+                    return RusotoError::service(GetNodeError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service(GetNodeError::InternalServiceError(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetNodeError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetNodeError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNodeError::Throttling(err.msg))
+                    return RusotoError::service(GetNodeError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1576,12 +1659,14 @@ impl fmt::Display for GetNodeError {
             GetNodeError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetNodeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetNodeError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetNodeError {}
 /// Errors returned by GetProposal
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetProposalError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1593,6 +1678,8 @@ pub enum GetProposalError {
     ResourceNotFound(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetProposalError {
@@ -1600,22 +1687,25 @@ impl GetProposalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetProposalError::AccessDenied(err.msg))
+                    return RusotoError::service(GetProposalError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetProposalError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        GetProposalError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetProposalError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetProposalError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProposalError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetProposalError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetProposalError::Throttling(err.msg))
+                    return RusotoError::service(GetProposalError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetProposalError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1630,12 +1720,14 @@ impl fmt::Display for GetProposalError {
             GetProposalError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetProposalError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetProposalError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetProposalError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetProposalError {}
 /// Errors returned by ListInvitations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInvitationsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1649,6 +1741,8 @@ pub enum ListInvitationsError {
     ResourceNotFound(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInvitationsError {
@@ -1656,29 +1750,37 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListInvitationsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListInvitationsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListInvitationsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInvitationsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInvitationsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListInvitationsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(ListInvitationsError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInvitationsError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListInvitationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListInvitationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListInvitationsError::Throttling(err.msg))
+                    return RusotoError::service(ListInvitationsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInvitationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1694,12 +1796,14 @@ impl fmt::Display for ListInvitationsError {
             ListInvitationsError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             ListInvitationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListInvitationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListInvitationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInvitationsError {}
 /// Errors returned by ListMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMembersError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1709,6 +1813,8 @@ pub enum ListMembersError {
     InvalidRequest(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMembersError {
@@ -1716,19 +1822,22 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListMembersError::AccessDenied(err.msg))
+                    return RusotoError::service(ListMembersError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListMembersError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        ListMembersError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListMembersError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListMembersError::InvalidRequest(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListMembersError::Throttling(err.msg))
+                    return RusotoError::service(ListMembersError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1742,12 +1851,14 @@ impl fmt::Display for ListMembersError {
             ListMembersError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListMembersError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListMembersError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMembersError {}
 /// Errors returned by ListNetworks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListNetworksError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1757,6 +1868,8 @@ pub enum ListNetworksError {
     InvalidRequest(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListNetworksError {
@@ -1764,19 +1877,22 @@ impl ListNetworksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListNetworksError::AccessDenied(err.msg))
+                    return RusotoError::service(ListNetworksError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListNetworksError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        ListNetworksError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNetworksError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListNetworksError::InvalidRequest(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListNetworksError::Throttling(err.msg))
+                    return RusotoError::service(ListNetworksError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListNetworksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1790,12 +1906,14 @@ impl fmt::Display for ListNetworksError {
             ListNetworksError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListNetworksError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListNetworksError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListNetworksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListNetworksError {}
 /// Errors returned by ListNodes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListNodesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1805,6 +1923,8 @@ pub enum ListNodesError {
     InvalidRequest(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListNodesError {
@@ -1812,19 +1932,22 @@ impl ListNodesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListNodesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListNodesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListNodesError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        ListNodesError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNodesError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListNodesError::InvalidRequest(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListNodesError::Throttling(err.msg))
+                    return RusotoError::service(ListNodesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListNodesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1838,12 +1961,14 @@ impl fmt::Display for ListNodesError {
             ListNodesError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListNodesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListNodesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListNodesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListNodesError {}
 /// Errors returned by ListProposalVotes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProposalVotesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1853,6 +1978,8 @@ pub enum ListProposalVotesError {
     InvalidRequest(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProposalVotesError {
@@ -1860,21 +1987,28 @@ impl ListProposalVotesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProposalVotesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListProposalVotesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListProposalVotesError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProposalVotesError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProposalVotesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListProposalVotesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProposalVotesError::Throttling(err.msg))
+                    return RusotoError::service(ListProposalVotesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProposalVotesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1888,12 +2022,14 @@ impl fmt::Display for ListProposalVotesError {
             ListProposalVotesError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListProposalVotesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListProposalVotesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListProposalVotesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProposalVotesError {}
 /// Errors returned by ListProposals
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProposalsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1905,6 +2041,8 @@ pub enum ListProposalsError {
     ResourceNotFound(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProposalsError {
@@ -1912,22 +2050,28 @@ impl ListProposalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProposalsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListProposalsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListProposalsError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        ListProposalsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProposalsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListProposalsError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProposalsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListProposalsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProposalsError::Throttling(err.msg))
+                    return RusotoError::service(ListProposalsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProposalsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1942,12 +2086,14 @@ impl fmt::Display for ListProposalsError {
             ListProposalsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListProposalsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListProposalsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListProposalsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProposalsError {}
 /// Errors returned by RejectInvitation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RejectInvitationError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1961,6 +2107,8 @@ pub enum RejectInvitationError {
     ResourceNotFound(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RejectInvitationError {
@@ -1968,27 +2116,37 @@ impl RejectInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RejectInvitationError::AccessDenied(err.msg))
+                    return RusotoError::service(RejectInvitationError::AccessDenied(err.msg), &res)
                 }
                 "IllegalActionException" => {
-                    return RusotoError::Service(RejectInvitationError::IllegalAction(err.msg))
+                    return RusotoError::service(
+                        RejectInvitationError::IllegalAction(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(RejectInvitationError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RejectInvitationError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RejectInvitationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        RejectInvitationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RejectInvitationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RejectInvitationError::Throttling(err.msg))
+                    return RusotoError::service(RejectInvitationError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RejectInvitationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2004,12 +2162,14 @@ impl fmt::Display for RejectInvitationError {
             RejectInvitationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             RejectInvitationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RejectInvitationError::Throttling(ref cause) => write!(f, "{}", cause),
+            RejectInvitationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RejectInvitationError {}
 /// Errors returned by VoteOnProposal
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum VoteOnProposalError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2023,6 +2183,8 @@ pub enum VoteOnProposalError {
     ResourceNotFound(String),
     /// <p>The request or operation could not be performed because a service is throttling requests. The most common source of throttling errors is launching EC2 instances such that your service limit for EC2 instances is exceeded. Request a limit increase or delete unused resources if possible.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl VoteOnProposalError {
@@ -2030,25 +2192,31 @@ impl VoteOnProposalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(VoteOnProposalError::AccessDenied(err.msg))
+                    return RusotoError::service(VoteOnProposalError::AccessDenied(err.msg), &res)
                 }
                 "IllegalActionException" => {
-                    return RusotoError::Service(VoteOnProposalError::IllegalAction(err.msg))
+                    return RusotoError::service(VoteOnProposalError::IllegalAction(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(VoteOnProposalError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        VoteOnProposalError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(VoteOnProposalError::InvalidRequest(err.msg))
+                    return RusotoError::service(VoteOnProposalError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(VoteOnProposalError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        VoteOnProposalError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(VoteOnProposalError::Throttling(err.msg))
+                    return RusotoError::service(VoteOnProposalError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(VoteOnProposalError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2064,6 +2232,7 @@ impl fmt::Display for VoteOnProposalError {
             VoteOnProposalError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             VoteOnProposalError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             VoteOnProposalError::Throttling(ref cause) => write!(f, "{}", cause),
+            VoteOnProposalError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2190,6 +2359,7 @@ impl ManagedBlockchainClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ManagedBlockchainClient {
         ManagedBlockchainClient {
             client: Client::shared(),
@@ -2215,15 +2385,66 @@ impl ManagedBlockchainClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ManagedBlockchainClient {
         ManagedBlockchainClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ManagedBlockchainClient> {
+        rusoto_core::ClientBuilder::new(ManagedBlockchainClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ManagedBlockchainClient {
+        ManagedBlockchainClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ManagedBlockchain for ManagedBlockchainClient {
-    /// <p>Creates a member within a Managed Blockchain network.</p>
-    async fn create_member(
+impl ManagedBlockchainClient {
+    /// Calls `create_member`, also returning the metadata of the response.
+    pub async fn create_member_with_metadata(
         &self,
         input: CreateMemberInput,
-    ) -> Result<CreateMemberOutput, RusotoError<CreateMemberError>> {
+    ) -> Result<(CreateMemberOutput, ResponseMetadata), RusotoError<CreateMemberError>> {
+        let request = self.build_create_member_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateMemberOutput, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateMemberError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_member` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_member(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateMemberInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_member_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_member_request(&self, input: CreateMemberInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/members",
             network_id = input.network_id
@@ -2231,64 +2452,112 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_network`, also returning the metadata of the response.
+    pub async fn create_network_with_metadata(
+        &self,
+        input: CreateNetworkInput,
+    ) -> Result<(CreateNetworkOutput, ResponseMetadata), RusotoError<CreateNetworkError>> {
+        let request = self.build_create_network_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMemberOutput, _>()?;
+                .deserialize::<CreateNetworkOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMemberError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateNetworkError::from_response(response))
         }
     }
 
-    /// <p>Creates a new blockchain network using Amazon Managed Blockchain.</p>
-    async fn create_network(
+    /// Presigns the request `create_network` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_network(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNetworkInput,
-    ) -> Result<CreateNetworkOutput, RusotoError<CreateNetworkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_network_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_network_request(&self, input: CreateNetworkInput) -> SignedRequest {
         let request_uri = "/networks";
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_node`, also returning the metadata of the response.
+    pub async fn create_node_with_metadata(
+        &self,
+        input: CreateNodeInput,
+    ) -> Result<(CreateNodeOutput, ResponseMetadata), RusotoError<CreateNodeError>> {
+        let request = self.build_create_node_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateNetworkOutput, _>()?;
+                .deserialize::<CreateNodeOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNetworkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateNodeError::from_response(response))
         }
     }
 
-    /// <p>Creates a peer node in a member.</p>
-    async fn create_node(
+    /// Presigns the request `create_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_node(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNodeInput,
-    ) -> Result<CreateNodeOutput, RusotoError<CreateNodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_node_request(&self, input: CreateNodeInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/members/{member_id}/nodes",
             member_id = input.member_id,
@@ -2297,33 +2566,57 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_proposal`, also returning the metadata of the response.
+    pub async fn create_proposal_with_metadata(
+        &self,
+        input: CreateProposalInput,
+    ) -> Result<(CreateProposalOutput, ResponseMetadata), RusotoError<CreateProposalError>> {
+        let request = self.build_create_proposal_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateNodeOutput, _>()?;
+                .deserialize::<CreateProposalOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNodeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateProposalError::from_response(response))
         }
     }
 
-    /// <p>Creates a proposal for a change to the network that other members of the network can vote on, for example, a proposal to add a new member to the network. Any member can create a proposal.</p>
-    async fn create_proposal(
+    /// Presigns the request `create_proposal` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_proposal(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProposalInput,
-    ) -> Result<CreateProposalOutput, RusotoError<CreateProposalError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_proposal_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_proposal_request(&self, input: CreateProposalInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/proposals",
             network_id = input.network_id
@@ -2331,33 +2624,57 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_member`, also returning the metadata of the response.
+    pub async fn delete_member_with_metadata(
+        &self,
+        input: DeleteMemberInput,
+    ) -> Result<(DeleteMemberOutput, ResponseMetadata), RusotoError<DeleteMemberError>> {
+        let request = self.build_delete_member_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProposalOutput, _>()?;
+                .deserialize::<DeleteMemberOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProposalError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMemberError::from_response(response))
         }
     }
 
-    /// <p>Deletes a member. Deleting a member removes the member and all associated resources from the network. <code>DeleteMember</code> can only be called for a specified <code>MemberId</code> if the principal performing the action is associated with the AWS account that owns the member. In all other cases, the <code>DeleteMember</code> action is carried out as the result of an approved proposal to remove a member. If <code>MemberId</code> is the last member in a network specified by the last AWS account, the network is deleted also.</p>
-    async fn delete_member(
+    /// Presigns the request `delete_member` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_member(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMemberInput,
-    ) -> Result<DeleteMemberOutput, RusotoError<DeleteMemberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_member_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_member_request(&self, input: DeleteMemberInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/members/{member_id}",
             member_id = input.member_id,
@@ -2366,30 +2683,54 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("DeleteMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_node`, also returning the metadata of the response.
+    pub async fn delete_node_with_metadata(
+        &self,
+        input: DeleteNodeInput,
+    ) -> Result<(DeleteNodeOutput, ResponseMetadata), RusotoError<DeleteNodeError>> {
+        let request = self.build_delete_node_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMemberOutput, _>()?;
+                .deserialize::<DeleteNodeOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMemberError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteNodeError::from_response(response))
         }
     }
 
-    /// <p>Deletes a peer node from a member that your AWS account owns. All data on the node is lost and cannot be recovered.</p>
-    async fn delete_node(
+    /// Presigns the request `delete_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_node(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteNodeInput,
-    ) -> Result<DeleteNodeOutput, RusotoError<DeleteNodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_node_request(&self, input: DeleteNodeInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/members/{member_id}/nodes/{node_id}",
             member_id = input.member_id,
@@ -2399,30 +2740,54 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("DeleteNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_member`, also returning the metadata of the response.
+    pub async fn get_member_with_metadata(
+        &self,
+        input: GetMemberInput,
+    ) -> Result<(GetMemberOutput, ResponseMetadata), RusotoError<GetMemberError>> {
+        let request = self.build_get_member_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteNodeOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetMemberOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNodeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMemberError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed information about a member.</p>
-    async fn get_member(
+    /// Presigns the request `get_member` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_member(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMemberInput,
-    ) -> Result<GetMemberOutput, RusotoError<GetMemberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_member_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_member_request(&self, input: GetMemberInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/members/{member_id}",
             member_id = input.member_id,
@@ -2431,58 +2796,106 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_network`, also returning the metadata of the response.
+    pub async fn get_network_with_metadata(
+        &self,
+        input: GetNetworkInput,
+    ) -> Result<(GetNetworkOutput, ResponseMetadata), RusotoError<GetNetworkError>> {
+        let request = self.build_get_network_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetMemberOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetNetworkOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMemberError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetNetworkError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed information about a network.</p>
-    async fn get_network(
+    /// Presigns the request `get_network` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_network(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetNetworkInput,
-    ) -> Result<GetNetworkOutput, RusotoError<GetNetworkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_network_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_network_request(&self, input: GetNetworkInput) -> SignedRequest {
         let request_uri = format!("/networks/{network_id}", network_id = input.network_id);
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_node`, also returning the metadata of the response.
+    pub async fn get_node_with_metadata(
+        &self,
+        input: GetNodeInput,
+    ) -> Result<(GetNodeOutput, ResponseMetadata), RusotoError<GetNodeError>> {
+        let request = self.build_get_node_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetNetworkOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetNodeOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetNetworkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetNodeError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed information about a peer node.</p>
-    async fn get_node(
+    /// Presigns the request `get_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_node(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetNodeInput,
-    ) -> Result<GetNodeOutput, RusotoError<GetNodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_node_request(&self, input: GetNodeInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/members/{member_id}/nodes/{node_id}",
             member_id = input.member_id,
@@ -2492,30 +2905,54 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_proposal`, also returning the metadata of the response.
+    pub async fn get_proposal_with_metadata(
+        &self,
+        input: GetProposalInput,
+    ) -> Result<(GetProposalOutput, ResponseMetadata), RusotoError<GetProposalError>> {
+        let request = self.build_get_proposal_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetNodeOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetProposalOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetNodeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetProposalError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed information about a proposal.</p>
-    async fn get_proposal(
+    /// Presigns the request `get_proposal` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_proposal(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetProposalInput,
-    ) -> Result<GetProposalOutput, RusotoError<GetProposalError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_proposal_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_proposal_request(&self, input: GetProposalInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/proposals/{proposal_id}",
             network_id = input.network_id,
@@ -2524,34 +2961,59 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_invitations`, also returning the metadata of the response.
+    pub async fn list_invitations_with_metadata(
+        &self,
+        input: ListInvitationsInput,
+    ) -> Result<(ListInvitationsOutput, ResponseMetadata), RusotoError<ListInvitationsError>> {
+        let request = self.build_list_invitations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetProposalOutput, _>()?;
+                .deserialize::<ListInvitationsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetProposalError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListInvitationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a listing of all invitations made on the specified network.</p>
-    async fn list_invitations(
+    /// Presigns the request `list_invitations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_invitations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInvitationsInput,
-    ) -> Result<ListInvitationsOutput, RusotoError<ListInvitationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_invitations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_invitations_request(&self, input: ListInvitationsInput) -> SignedRequest {
         let request_uri = "/invitations";
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2563,28 +3025,51 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_members`, also returning the metadata of the response.
+    pub async fn list_members_with_metadata(
+        &self,
+        input: ListMembersInput,
+    ) -> Result<(ListMembersOutput, ResponseMetadata), RusotoError<ListMembersError>> {
+        let request = self.build_list_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInvitationsOutput, _>()?;
+                .deserialize::<ListMembersOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInvitationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListMembersError::from_response(response))
         }
     }
 
-    /// <p>Returns a listing of the members in a network and properties of their configurations.</p>
-    async fn list_members(
+    /// Presigns the request `list_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMembersInput,
-    ) -> Result<ListMembersOutput, RusotoError<ListMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_members_request(&self, input: ListMembersInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/members",
             network_id = input.network_id
@@ -2592,6 +3077,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2612,32 +3098,56 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_networks`, also returning the metadata of the response.
+    pub async fn list_networks_with_metadata(
+        &self,
+        input: ListNetworksInput,
+    ) -> Result<(ListNetworksOutput, ResponseMetadata), RusotoError<ListNetworksError>> {
+        let request = self.build_list_networks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMembersOutput, _>()?;
+                .deserialize::<ListNetworksOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListNetworksError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the networks in which the current AWS account has members.</p>
-    async fn list_networks(
+    /// Presigns the request `list_networks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_networks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListNetworksInput,
-    ) -> Result<ListNetworksOutput, RusotoError<ListNetworksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_networks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_networks_request(&self, input: ListNetworksInput) -> SignedRequest {
         let request_uri = "/networks";
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListNetworks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2658,28 +3168,51 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_nodes`, also returning the metadata of the response.
+    pub async fn list_nodes_with_metadata(
+        &self,
+        input: ListNodesInput,
+    ) -> Result<(ListNodesOutput, ResponseMetadata), RusotoError<ListNodesError>> {
+        let request = self.build_list_nodes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListNetworksOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListNodesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListNetworksError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListNodesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the nodes within a network.</p>
-    async fn list_nodes(
+    /// Presigns the request `list_nodes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_nodes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListNodesInput,
-    ) -> Result<ListNodesOutput, RusotoError<ListNodesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_nodes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_nodes_request(&self, input: ListNodesInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/members/{member_id}/nodes",
             member_id = input.member_id,
@@ -2688,6 +3221,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2702,28 +3236,52 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_proposal_votes`, also returning the metadata of the response.
+    pub async fn list_proposal_votes_with_metadata(
+        &self,
+        input: ListProposalVotesInput,
+    ) -> Result<(ListProposalVotesOutput, ResponseMetadata), RusotoError<ListProposalVotesError>>
+    {
+        let request = self.build_list_proposal_votes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<ListNodesOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProposalVotesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListNodesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListProposalVotesError::from_response(response))
         }
     }
 
-    /// <p>Returns the listing of votes for a specified proposal, including the value of each vote and the unique identifier of the member that cast the vote.</p>
-    async fn list_proposal_votes(
+    /// Presigns the request `list_proposal_votes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_proposal_votes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProposalVotesInput,
-    ) -> Result<ListProposalVotesOutput, RusotoError<ListProposalVotesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_proposal_votes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_proposal_votes_request(&self, input: ListProposalVotesInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/proposals/{proposal_id}/votes",
             network_id = input.network_id,
@@ -2732,6 +3290,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListProposalVotes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2743,28 +3302,51 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_proposals`, also returning the metadata of the response.
+    pub async fn list_proposals_with_metadata(
+        &self,
+        input: ListProposalsInput,
+    ) -> Result<(ListProposalsOutput, ResponseMetadata), RusotoError<ListProposalsError>> {
+        let request = self.build_list_proposals_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProposalVotesOutput, _>()?;
+                .deserialize::<ListProposalsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProposalVotesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListProposalsError::from_response(response))
         }
     }
 
-    /// <p>Returns a listing of proposals for the network.</p>
-    async fn list_proposals(
+    /// Presigns the request `list_proposals` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_proposals(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProposalsInput,
-    ) -> Result<ListProposalsOutput, RusotoError<ListProposalsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_proposals_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_proposals_request(&self, input: ListProposalsInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/proposals",
             network_id = input.network_id
@@ -2772,6 +3354,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListProposals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2783,28 +3366,52 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `reject_invitation`, also returning the metadata of the response.
+    pub async fn reject_invitation_with_metadata(
+        &self,
+        input: RejectInvitationInput,
+    ) -> Result<(RejectInvitationOutput, ResponseMetadata), RusotoError<RejectInvitationError>>
+    {
+        let request = self.build_reject_invitation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProposalsOutput, _>()?;
+                .deserialize::<RejectInvitationOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProposalsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RejectInvitationError::from_response(response))
         }
     }
 
-    /// <p>Rejects an invitation to join a network. This action can be called by a principal in an AWS account that has received an invitation to create a member and join a network.</p>
-    async fn reject_invitation(
+    /// Presigns the request `reject_invitation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reject_invitation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RejectInvitationInput,
-    ) -> Result<RejectInvitationOutput, RusotoError<RejectInvitationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reject_invitation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reject_invitation_request(&self, input: RejectInvitationInput) -> SignedRequest {
         let request_uri = format!(
             "/invitations/{invitation_id}",
             invitation_id = input.invitation_id
@@ -2812,30 +3419,54 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("RejectInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `vote_on_proposal`, also returning the metadata of the response.
+    pub async fn vote_on_proposal_with_metadata(
+        &self,
+        input: VoteOnProposalInput,
+    ) -> Result<(VoteOnProposalOutput, ResponseMetadata), RusotoError<VoteOnProposalError>> {
+        let request = self.build_vote_on_proposal_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RejectInvitationOutput, _>()?;
+                .deserialize::<VoteOnProposalOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RejectInvitationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(VoteOnProposalError::from_response(response))
         }
     }
 
-    /// <p>Casts a vote for a specified <code>ProposalId</code> on behalf of a member. The member to vote as, specified by <code>VoterMemberId</code>, must be in the same AWS account as the principal that calls the action.</p>
-    async fn vote_on_proposal(
+    /// Presigns the request `vote_on_proposal` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_vote_on_proposal(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: VoteOnProposalInput,
-    ) -> Result<VoteOnProposalOutput, RusotoError<VoteOnProposalError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_vote_on_proposal_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_vote_on_proposal_request(&self, input: VoteOnProposalInput) -> SignedRequest {
         let request_uri = format!(
             "/networks/{network_id}/proposals/{proposal_id}/votes",
             network_id = input.network_id,
@@ -2844,25 +3475,195 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("VoteOnProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl ManagedBlockchain for ManagedBlockchainClient {
+    /// <p>Creates a member within a Managed Blockchain network.</p>
+    async fn create_member(
+        &self,
+        input: CreateMemberInput,
+    ) -> Result<CreateMemberOutput, RusotoError<CreateMemberError>> {
+        self.create_member_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<VoteOnProposalOutput, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(VoteOnProposalError::from_response(response))
-        }
+    /// <p>Creates a new blockchain network using Amazon Managed Blockchain.</p>
+    async fn create_network(
+        &self,
+        input: CreateNetworkInput,
+    ) -> Result<CreateNetworkOutput, RusotoError<CreateNetworkError>> {
+        self.create_network_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a peer node in a member.</p>
+    async fn create_node(
+        &self,
+        input: CreateNodeInput,
+    ) -> Result<CreateNodeOutput, RusotoError<CreateNodeError>> {
+        self.create_node_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a proposal for a change to the network that other members of the network can vote on, for example, a proposal to add a new member to the network. Any member can create a proposal.</p>
+    async fn create_proposal(
+        &self,
+        input: CreateProposalInput,
+    ) -> Result<CreateProposalOutput, RusotoError<CreateProposalError>> {
+        self.create_proposal_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a member. Deleting a member removes the member and all associated resources from the network. <code>DeleteMember</code> can only be called for a specified <code>MemberId</code> if the principal performing the action is associated with the AWS account that owns the member. In all other cases, the <code>DeleteMember</code> action is carried out as the result of an approved proposal to remove a member. If <code>MemberId</code> is the last member in a network specified by the last AWS account, the network is deleted also.</p>
+    async fn delete_member(
+        &self,
+        input: DeleteMemberInput,
+    ) -> Result<DeleteMemberOutput, RusotoError<DeleteMemberError>> {
+        self.delete_member_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a peer node from a member that your AWS account owns. All data on the node is lost and cannot be recovered.</p>
+    async fn delete_node(
+        &self,
+        input: DeleteNodeInput,
+    ) -> Result<DeleteNodeOutput, RusotoError<DeleteNodeError>> {
+        self.delete_node_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about a member.</p>
+    async fn get_member(
+        &self,
+        input: GetMemberInput,
+    ) -> Result<GetMemberOutput, RusotoError<GetMemberError>> {
+        self.get_member_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about a network.</p>
+    async fn get_network(
+        &self,
+        input: GetNetworkInput,
+    ) -> Result<GetNetworkOutput, RusotoError<GetNetworkError>> {
+        self.get_network_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about a peer node.</p>
+    async fn get_node(
+        &self,
+        input: GetNodeInput,
+    ) -> Result<GetNodeOutput, RusotoError<GetNodeError>> {
+        self.get_node_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about a proposal.</p>
+    async fn get_proposal(
+        &self,
+        input: GetProposalInput,
+    ) -> Result<GetProposalOutput, RusotoError<GetProposalError>> {
+        self.get_proposal_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a listing of all invitations made on the specified network.</p>
+    async fn list_invitations(
+        &self,
+        input: ListInvitationsInput,
+    ) -> Result<ListInvitationsOutput, RusotoError<ListInvitationsError>> {
+        self.list_invitations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a listing of the members in a network and properties of their configurations.</p>
+    async fn list_members(
+        &self,
+        input: ListMembersInput,
+    ) -> Result<ListMembersOutput, RusotoError<ListMembersError>> {
+        self.list_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the networks in which the current AWS account has members.</p>
+    async fn list_networks(
+        &self,
+        input: ListNetworksInput,
+    ) -> Result<ListNetworksOutput, RusotoError<ListNetworksError>> {
+        self.list_networks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the nodes within a network.</p>
+    async fn list_nodes(
+        &self,
+        input: ListNodesInput,
+    ) -> Result<ListNodesOutput, RusotoError<ListNodesError>> {
+        self.list_nodes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the listing of votes for a specified proposal, including the value of each vote and the unique identifier of the member that cast the vote.</p>
+    async fn list_proposal_votes(
+        &self,
+        input: ListProposalVotesInput,
+    ) -> Result<ListProposalVotesOutput, RusotoError<ListProposalVotesError>> {
+        self.list_proposal_votes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a listing of proposals for the network.</p>
+    async fn list_proposals(
+        &self,
+        input: ListProposalsInput,
+    ) -> Result<ListProposalsOutput, RusotoError<ListProposalsError>> {
+        self.list_proposals_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Rejects an invitation to join a network. This action can be called by a principal in an AWS account that has received an invitation to create a member and join a network.</p>
+    async fn reject_invitation(
+        &self,
+        input: RejectInvitationInput,
+    ) -> Result<RejectInvitationOutput, RusotoError<RejectInvitationError>> {
+        self.reject_invitation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Casts a vote for a specified <code>ProposalId</code> on behalf of a member. The member to vote as, specified by <code>VoterMemberId</code>, must be in the same AWS account as the principal that calls the action.</p>
+    async fn vote_on_proposal(
+        &self,
+        input: VoteOnProposalInput,
+    ) -> Result<VoteOnProposalOutput, RusotoError<VoteOnProposalError>> {
+        self.vote_on_proposal_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -395,6 +395,7 @@ pub struct StartChangeSetResponse {
 
 /// Errors returned by CancelChangeSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelChangeSetError {
     /// <p>Access is denied.</p>
     AccessDenied(String),
@@ -406,6 +407,8 @@ pub enum CancelChangeSetError {
     ResourceNotFound(String),
     /// <p>Too many requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelChangeSetError {
@@ -413,22 +416,28 @@ impl CancelChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelChangeSetError::AccessDenied(err.msg))
+                    return RusotoError::service(CancelChangeSetError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CancelChangeSetError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CancelChangeSetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CancelChangeSetError::ResourceInUse(err.msg))
+                    return RusotoError::service(CancelChangeSetError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelChangeSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CancelChangeSetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelChangeSetError::Throttling(err.msg))
+                    return RusotoError::service(CancelChangeSetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelChangeSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -443,12 +452,14 @@ impl fmt::Display for CancelChangeSetError {
             CancelChangeSetError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CancelChangeSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CancelChangeSetError::Throttling(ref cause) => write!(f, "{}", cause),
+            CancelChangeSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelChangeSetError {}
 /// Errors returned by DescribeChangeSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeChangeSetError {
     /// <p>Access is denied.</p>
     AccessDenied(String),
@@ -458,6 +469,8 @@ pub enum DescribeChangeSetError {
     ResourceNotFound(String),
     /// <p>Too many requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeChangeSetError {
@@ -465,19 +478,28 @@ impl DescribeChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeChangeSetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeChangeSetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeChangeSetError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DescribeChangeSetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeChangeSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeChangeSetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeChangeSetError::Throttling(err.msg))
+                    return RusotoError::service(DescribeChangeSetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeChangeSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -491,12 +513,14 @@ impl fmt::Display for DescribeChangeSetError {
             DescribeChangeSetError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeChangeSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeChangeSetError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeChangeSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeChangeSetError {}
 /// Errors returned by DescribeEntity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEntityError {
     /// <p>Access is denied.</p>
     AccessDenied(String),
@@ -508,6 +532,8 @@ pub enum DescribeEntityError {
     ResourceNotSupported(String),
     /// <p>Too many requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEntityError {
@@ -515,22 +541,31 @@ impl DescribeEntityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeEntityError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeEntityError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeEntityError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DescribeEntityError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEntityError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeEntityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotSupportedException" => {
-                    return RusotoError::Service(DescribeEntityError::ResourceNotSupported(err.msg))
+                    return RusotoError::service(
+                        DescribeEntityError::ResourceNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeEntityError::Throttling(err.msg))
+                    return RusotoError::service(DescribeEntityError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEntityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -545,12 +580,14 @@ impl fmt::Display for DescribeEntityError {
             DescribeEntityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeEntityError::ResourceNotSupported(ref cause) => write!(f, "{}", cause),
             DescribeEntityError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeEntityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEntityError {}
 /// Errors returned by ListChangeSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListChangeSetsError {
     /// <p>Access is denied.</p>
     AccessDenied(String),
@@ -558,6 +595,8 @@ pub enum ListChangeSetsError {
     InternalService(String),
     /// <p>Too many requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListChangeSetsError {
@@ -565,16 +604,19 @@ impl ListChangeSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListChangeSetsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListChangeSetsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListChangeSetsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListChangeSetsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListChangeSetsError::Throttling(err.msg))
+                    return RusotoError::service(ListChangeSetsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListChangeSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -587,12 +629,14 @@ impl fmt::Display for ListChangeSetsError {
             ListChangeSetsError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListChangeSetsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListChangeSetsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListChangeSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListChangeSetsError {}
 /// Errors returned by ListEntities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEntitiesError {
     /// <p>Access is denied.</p>
     AccessDenied(String),
@@ -602,6 +646,8 @@ pub enum ListEntitiesError {
     ResourceNotFound(String),
     /// <p>Too many requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEntitiesError {
@@ -609,19 +655,19 @@ impl ListEntitiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListEntitiesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListEntitiesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListEntitiesError::InternalService(err.msg))
+                    return RusotoError::service(ListEntitiesError::InternalService(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListEntitiesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListEntitiesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListEntitiesError::Throttling(err.msg))
+                    return RusotoError::service(ListEntitiesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEntitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -635,12 +681,14 @@ impl fmt::Display for ListEntitiesError {
             ListEntitiesError::InternalService(ref cause) => write!(f, "{}", cause),
             ListEntitiesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListEntitiesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListEntitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEntitiesError {}
 /// Errors returned by StartChangeSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartChangeSetError {
     /// <p>Access is denied.</p>
     AccessDenied(String),
@@ -654,6 +702,8 @@ pub enum StartChangeSetError {
     ServiceQuotaExceeded(String),
     /// <p>Too many requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartChangeSetError {
@@ -661,25 +711,34 @@ impl StartChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartChangeSetError::AccessDenied(err.msg))
+                    return RusotoError::service(StartChangeSetError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartChangeSetError::InternalService(err.msg))
+                    return RusotoError::service(
+                        StartChangeSetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartChangeSetError::ResourceInUse(err.msg))
+                    return RusotoError::service(StartChangeSetError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartChangeSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartChangeSetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartChangeSetError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        StartChangeSetError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartChangeSetError::Throttling(err.msg))
+                    return RusotoError::service(StartChangeSetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartChangeSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -695,6 +754,7 @@ impl fmt::Display for StartChangeSetError {
             StartChangeSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartChangeSetError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             StartChangeSetError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartChangeSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -749,6 +809,7 @@ impl MarketplaceCatalogClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MarketplaceCatalogClient {
         MarketplaceCatalogClient {
             client: Client::shared(),
@@ -774,19 +835,72 @@ impl MarketplaceCatalogClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceCatalogClient {
         MarketplaceCatalogClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MarketplaceCatalogClient> {
+        rusoto_core::ClientBuilder::new(MarketplaceCatalogClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MarketplaceCatalogClient {
+        MarketplaceCatalogClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MarketplaceCatalog for MarketplaceCatalogClient {
-    /// <p>Used to cancel an open change request. Must be sent before the status of the request changes to <code>APPLYING</code>, the final stage of completing your change request. You can describe a change during the 60-day request history retention period for API calls.</p>
-    async fn cancel_change_set(
+impl MarketplaceCatalogClient {
+    /// Calls `cancel_change_set`, also returning the metadata of the response.
+    pub async fn cancel_change_set_with_metadata(
         &self,
         input: CancelChangeSetRequest,
-    ) -> Result<CancelChangeSetResponse, RusotoError<CancelChangeSetError>> {
+    ) -> Result<(CancelChangeSetResponse, ResponseMetadata), RusotoError<CancelChangeSetError>>
+    {
+        let request = self.build_cancel_change_set_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelChangeSetResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CancelChangeSetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_change_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_change_set(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelChangeSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_change_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_change_set_request(&self, input: CancelChangeSetRequest) -> SignedRequest {
         let request_uri = "/CancelChangeSet";
 
         let mut request =
             SignedRequest::new("PATCH", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("CancelChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -796,31 +910,56 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         params.put("changeSetId", &input.change_set_id);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_change_set`, also returning the metadata of the response.
+    pub async fn describe_change_set_with_metadata(
+        &self,
+        input: DescribeChangeSetRequest,
+    ) -> Result<(DescribeChangeSetResponse, ResponseMetadata), RusotoError<DescribeChangeSetError>>
+    {
+        let request = self.build_describe_change_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelChangeSetResponse, _>()?;
+                .deserialize::<DescribeChangeSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelChangeSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeChangeSetError::from_response(response))
         }
     }
 
-    /// <p>Provides information about a given change set.</p>
-    async fn describe_change_set(
+    /// Presigns the request `describe_change_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_change_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeChangeSetRequest,
-    ) -> Result<DescribeChangeSetResponse, RusotoError<DescribeChangeSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_change_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_change_set_request(&self, input: DescribeChangeSetRequest) -> SignedRequest {
         let request_uri = "/DescribeChangeSet";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("DescribeChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -830,31 +969,55 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         params.put("changeSetId", &input.change_set_id);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_entity`, also returning the metadata of the response.
+    pub async fn describe_entity_with_metadata(
+        &self,
+        input: DescribeEntityRequest,
+    ) -> Result<(DescribeEntityResponse, ResponseMetadata), RusotoError<DescribeEntityError>> {
+        let request = self.build_describe_entity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeChangeSetResponse, _>()?;
+                .deserialize::<DescribeEntityResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeChangeSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeEntityError::from_response(response))
         }
     }
 
-    /// <p>Returns the metadata and content of the entity.</p>
-    async fn describe_entity(
+    /// Presigns the request `describe_entity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_entity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEntityRequest,
-    ) -> Result<DescribeEntityResponse, RusotoError<DescribeEntityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_entity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_entity_request(&self, input: DescribeEntityRequest) -> SignedRequest {
         let request_uri = "/DescribeEntity";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("DescribeEntity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -864,113 +1027,309 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         params.put("entityId", &input.entity_id);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_change_sets`, also returning the metadata of the response.
+    pub async fn list_change_sets_with_metadata(
+        &self,
+        input: ListChangeSetsRequest,
+    ) -> Result<(ListChangeSetsResponse, ResponseMetadata), RusotoError<ListChangeSetsError>> {
+        let request = self.build_list_change_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEntityResponse, _>()?;
+                .deserialize::<ListChangeSetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEntityError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListChangeSetsError::from_response(response))
         }
     }
 
-    /// <p>Returns the list of change sets owned by the account being used to make the call. You can filter this list by providing any combination of <code>entityId</code>, <code>ChangeSetName</code>, and status. If you provide more than one filter, the API operation applies a logical AND between the filters.</p> <p>You can describe a change during the 60-day request history retention period for API calls.</p>
-    async fn list_change_sets(
+    /// Presigns the request `list_change_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_change_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListChangeSetsRequest,
-    ) -> Result<ListChangeSetsResponse, RusotoError<ListChangeSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_change_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_change_sets_request(&self, input: ListChangeSetsRequest) -> SignedRequest {
         let request_uri = "/ListChangeSets";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("ListChangeSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_entities`, also returning the metadata of the response.
+    pub async fn list_entities_with_metadata(
+        &self,
+        input: ListEntitiesRequest,
+    ) -> Result<(ListEntitiesResponse, ResponseMetadata), RusotoError<ListEntitiesError>> {
+        let request = self.build_list_entities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListChangeSetsResponse, _>()?;
+                .deserialize::<ListEntitiesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListChangeSetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListEntitiesError::from_response(response))
         }
     }
 
-    /// <p>Provides the list of entities of a given type.</p>
-    async fn list_entities(
+    /// Presigns the request `list_entities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_entities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEntitiesRequest,
-    ) -> Result<ListEntitiesResponse, RusotoError<ListEntitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_entities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_entities_request(&self, input: ListEntitiesRequest) -> SignedRequest {
         let request_uri = "/ListEntities";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("ListEntities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_change_set`, also returning the metadata of the response.
+    pub async fn start_change_set_with_metadata(
+        &self,
+        input: StartChangeSetRequest,
+    ) -> Result<(StartChangeSetResponse, ResponseMetadata), RusotoError<StartChangeSetError>> {
+        let request = self.build_start_change_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEntitiesResponse, _>()?;
+                .deserialize::<StartChangeSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEntitiesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartChangeSetError::from_response(response))
         }
     }
 
-    /// <p>This operation allows you to request changes in your entities.</p>
-    async fn start_change_set(
+    /// Presigns the request `start_change_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_change_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartChangeSetRequest,
-    ) -> Result<StartChangeSetResponse, RusotoError<StartChangeSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_change_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_change_set_request(&self, input: StartChangeSetRequest) -> SignedRequest {
         let request_uri = "/StartChangeSet";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("StartChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListChangeSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_change_sets_pages(
+        &self,
+        input: ListChangeSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListChangeSetsResponse, RusotoError<ListChangeSetsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MarketplaceCatalogClient, input| async move {
+                client.list_change_sets(input).await
+            },
+            |input: &ListChangeSetsRequest, output: &ListChangeSetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListChangeSetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ChangeSetSummaryList` of every page of `ListChangeSets` results for `input`, as `list_change_sets_pages` requests them.
+    pub fn list_change_sets_stream(
+        &self,
+        input: ListChangeSetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ChangeSetSummaryListItem, RusotoError<ListChangeSetsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_change_sets_pages(input), |page| {
+            page.change_set_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListEntities` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_entities_pages(
+        &self,
+        input: ListEntitiesRequest,
+    ) -> impl futures::Stream<Item = Result<ListEntitiesResponse, RusotoError<ListEntitiesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MarketplaceCatalogClient, input| async move { client.list_entities(input).await },
+            |input: &ListEntitiesRequest, output: &ListEntitiesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEntitiesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EntitySummaryList` of every page of `ListEntities` results for `input`, as `list_entities_pages` requests them.
+    pub fn list_entities_stream(
+        &self,
+        input: ListEntitiesRequest,
+    ) -> impl futures::Stream<Item = Result<EntitySummary, RusotoError<ListEntitiesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_entities_pages(input), |page| {
+            page.entity_summary_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl MarketplaceCatalog for MarketplaceCatalogClient {
+    /// <p>Used to cancel an open change request. Must be sent before the status of the request changes to <code>APPLYING</code>, the final stage of completing your change request. You can describe a change during the 60-day request history retention period for API calls.</p>
+    async fn cancel_change_set(
+        &self,
+        input: CancelChangeSetRequest,
+    ) -> Result<CancelChangeSetResponse, RusotoError<CancelChangeSetError>> {
+        self.cancel_change_set_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartChangeSetResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartChangeSetError::from_response(response))
-        }
+    /// <p>Provides information about a given change set.</p>
+    async fn describe_change_set(
+        &self,
+        input: DescribeChangeSetRequest,
+    ) -> Result<DescribeChangeSetResponse, RusotoError<DescribeChangeSetError>> {
+        self.describe_change_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the metadata and content of the entity.</p>
+    async fn describe_entity(
+        &self,
+        input: DescribeEntityRequest,
+    ) -> Result<DescribeEntityResponse, RusotoError<DescribeEntityError>> {
+        self.describe_entity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of change sets owned by the account being used to make the call. You can filter this list by providing any combination of <code>entityId</code>, <code>ChangeSetName</code>, and status. If you provide more than one filter, the API operation applies a logical AND between the filters.</p> <p>You can describe a change during the 60-day request history retention period for API calls.</p>
+    async fn list_change_sets(
+        &self,
+        input: ListChangeSetsRequest,
+    ) -> Result<ListChangeSetsResponse, RusotoError<ListChangeSetsError>> {
+        self.list_change_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides the list of entities of a given type.</p>
+    async fn list_entities(
+        &self,
+        input: ListEntitiesRequest,
+    ) -> Result<ListEntitiesResponse, RusotoError<ListEntitiesError>> {
+        self.list_entities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation allows you to request changes in your entities.</p>
+    async fn start_change_set(
+        &self,
+        input: StartChangeSetRequest,
+    ) -> Result<StartChangeSetResponse, RusotoError<StartChangeSetError>> {
+        self.start_change_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -109,6 +109,7 @@ pub struct GetEntitlementsResult {
 
 /// Errors returned by GetEntitlements
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEntitlementsError {
     /// <p>An internal error has occurred. Retry your request. If the problem persists, post a message with details on the AWS forums.</p>
     InternalServiceError(String),
@@ -116,6 +117,8 @@ pub enum GetEntitlementsError {
     InvalidParameter(String),
     /// <p>The calls to the GetEntitlements API are throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEntitlementsError {
@@ -123,18 +126,22 @@ impl GetEntitlementsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetEntitlementsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEntitlementsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetEntitlementsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetEntitlementsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEntitlementsError::Throttling(err.msg))
+                    return RusotoError::service(GetEntitlementsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEntitlementsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -147,6 +154,7 @@ impl fmt::Display for GetEntitlementsError {
             GetEntitlementsError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             GetEntitlementsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetEntitlementsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetEntitlementsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -171,6 +179,7 @@ impl MarketplaceEntitlementClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MarketplaceEntitlementClient {
         MarketplaceEntitlementClient {
             client: Client::shared(),
@@ -196,34 +205,124 @@ impl MarketplaceEntitlementClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceEntitlementClient {
         MarketplaceEntitlementClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MarketplaceEntitlementClient> {
+        rusoto_core::ClientBuilder::new(MarketplaceEntitlementClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MarketplaceEntitlementClient {
+        MarketplaceEntitlementClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MarketplaceEntitlement for MarketplaceEntitlementClient {
-    /// <p>GetEntitlements retrieves entitlement values for a given product. The results can be filtered based on customer identifier or product dimensions.</p>
-    async fn get_entitlements(
+impl MarketplaceEntitlementClient {
+    /// Calls `get_entitlements`, also returning the metadata of the response.
+    pub async fn get_entitlements_with_metadata(
         &self,
         input: GetEntitlementsRequest,
-    ) -> Result<GetEntitlementsResult, RusotoError<GetEntitlementsError>> {
-        let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
-        request.set_endpoint_prefix("entitlement.marketplace".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSMPEntitlementService.GetEntitlements");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<(GetEntitlementsResult, ResponseMetadata), RusotoError<GetEntitlementsError>> {
+        let request = self.build_get_entitlements_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetEntitlementsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetEntitlementsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetEntitlementsError::from_response(response))
         }
     }
+
+    /// Presigns the request `get_entitlements` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_entitlements(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetEntitlementsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_entitlements_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_entitlements_request(&self, input: GetEntitlementsRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("GetEntitlements");
+        request.set_endpoint_prefix("entitlement.marketplace".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSMPEntitlementService.GetEntitlements");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// The pages of `GetEntitlements` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_entitlements_pages(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> impl futures::Stream<Item = Result<GetEntitlementsResult, RusotoError<GetEntitlementsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MarketplaceEntitlementClient, input| async move {
+                client.get_entitlements(input).await
+            },
+            |input: &GetEntitlementsRequest, output: &GetEntitlementsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetEntitlementsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Entitlements` of every page of `GetEntitlements` results for `input`, as `get_entitlements_pages` requests them.
+    pub fn get_entitlements_stream(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> impl futures::Stream<Item = Result<Entitlement, RusotoError<GetEntitlementsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_entitlements_pages(input), |page| {
+            page.entitlements.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl MarketplaceEntitlement for MarketplaceEntitlementClient {
+    /// <p>GetEntitlements retrieves entitlement values for a given product. The results can be filtered based on customer identifier or product dimensions.</p>
+    async fn get_entitlements(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> Result<GetEntitlementsResult, RusotoError<GetEntitlementsError>> {
+        self.get_entitlements_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -104,9 +104,12 @@ pub struct StartSupportDataExportResult {
 
 /// Errors returned by GenerateDataSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateDataSetError {
     /// <p>This exception is thrown when an internal service error occurs.</p>
     MarketplaceCommerceAnalytics(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateDataSetError {
@@ -114,12 +117,13 @@ impl GenerateDataSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "MarketplaceCommerceAnalyticsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GenerateDataSetError::MarketplaceCommerceAnalytics(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GenerateDataSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -130,15 +134,19 @@ impl fmt::Display for GenerateDataSetError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GenerateDataSetError::MarketplaceCommerceAnalytics(ref cause) => write!(f, "{}", cause),
+            GenerateDataSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GenerateDataSetError {}
 /// Errors returned by StartSupportDataExport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartSupportDataExportError {
     /// <p>This exception is thrown when an internal service error occurs.</p>
     MarketplaceCommerceAnalytics(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartSupportDataExportError {
@@ -146,12 +154,13 @@ impl StartSupportDataExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "MarketplaceCommerceAnalyticsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartSupportDataExportError::MarketplaceCommerceAnalytics(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartSupportDataExportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -164,6 +173,7 @@ impl fmt::Display for StartSupportDataExportError {
             StartSupportDataExportError::MarketplaceCommerceAnalytics(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartSupportDataExportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -194,6 +204,7 @@ impl MarketplaceCommerceAnalyticsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MarketplaceCommerceAnalyticsClient {
         MarketplaceCommerceAnalyticsClient {
             client: Client::shared(),
@@ -222,17 +233,71 @@ impl MarketplaceCommerceAnalyticsClient {
     ) -> MarketplaceCommerceAnalyticsClient {
         MarketplaceCommerceAnalyticsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MarketplaceCommerceAnalyticsClient> {
+        rusoto_core::ClientBuilder::new(MarketplaceCommerceAnalyticsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(
+        &self,
+        config: &rusoto_core::OperationConfig,
+    ) -> MarketplaceCommerceAnalyticsClient {
+        MarketplaceCommerceAnalyticsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
-    /// <p>Given a data set type and data set publication date, asynchronously publishes the requested data set to the specified S3 bucket and notifies the specified SNS topic once the data is available. Returns a unique request identifier that can be used to correlate requests with notifications from the SNS topic. Data sets will be published in comma-separated values (CSV) format with the file name {data<em>set</em>type}_YYYY-MM-DD.csv. If a file with the same name already exists (e.g. if the same data set is requested twice), the original file will be overwritten by the new file. Requires a Role with an attached permissions policy providing Allow permissions for the following actions: s3:PutObject, s3:GetBucketLocation, sns:GetTopicAttributes, sns:Publish, iam:GetRolePolicy.</p>
-    async fn generate_data_set(
+impl MarketplaceCommerceAnalyticsClient {
+    /// Calls `generate_data_set`, also returning the metadata of the response.
+    pub async fn generate_data_set_with_metadata(
         &self,
         input: GenerateDataSetRequest,
-    ) -> Result<GenerateDataSetResult, RusotoError<GenerateDataSetError>> {
+    ) -> Result<(GenerateDataSetResult, ResponseMetadata), RusotoError<GenerateDataSetError>> {
+        let request = self.build_generate_data_set_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GenerateDataSetResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GenerateDataSetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `generate_data_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_data_set(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GenerateDataSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_data_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_data_set_request(&self, input: GenerateDataSetRequest) -> SignedRequest {
         let mut request =
             SignedRequest::new("POST", "marketplacecommerceanalytics", &self.region, "/");
+        request.set_operation("GenerateDataSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -242,28 +307,59 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_support_data_export`, also returning the metadata of the response.
+    pub async fn start_support_data_export_with_metadata(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> Result<
+        (StartSupportDataExportResult, ResponseMetadata),
+        RusotoError<StartSupportDataExportError>,
+    > {
+        let request = self.build_start_support_data_export_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GenerateDataSetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartSupportDataExportResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateDataSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartSupportDataExportError::from_response(response))
         }
     }
 
-    /// <p>Given a data set type and a from date, asynchronously publishes the requested customer support data to the specified S3 bucket and notifies the specified SNS topic once the data is available. Returns a unique request identifier that can be used to correlate requests with notifications from the SNS topic. Data sets will be published in comma-separated values (CSV) format with the file name {data<em>set</em>type}_YYYY-MM-DD&#39;T&#39;HH-mm-ss&#39;Z&#39;.csv. If a file with the same name already exists (e.g. if the same data set is requested twice), the original file will be overwritten by the new file. Requires a Role with an attached permissions policy providing Allow permissions for the following actions: s3:PutObject, s3:GetBucketLocation, sns:GetTopicAttributes, sns:Publish, iam:GetRolePolicy.</p>
-    async fn start_support_data_export(
+    /// Presigns the request `start_support_data_export` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_support_data_export(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartSupportDataExportRequest,
-    ) -> Result<StartSupportDataExportResult, RusotoError<StartSupportDataExportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_support_data_export_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_support_data_export_request(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> SignedRequest {
         let mut request =
             SignedRequest::new("POST", "marketplacecommerceanalytics", &self.region, "/");
+        request.set_operation("StartSupportDataExport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -273,19 +369,29 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
+    /// <p>Given a data set type and data set publication date, asynchronously publishes the requested data set to the specified S3 bucket and notifies the specified SNS topic once the data is available. Returns a unique request identifier that can be used to correlate requests with notifications from the SNS topic. Data sets will be published in comma-separated values (CSV) format with the file name {data<em>set</em>type}_YYYY-MM-DD.csv. If a file with the same name already exists (e.g. if the same data set is requested twice), the original file will be overwritten by the new file. Requires a Role with an attached permissions policy providing Allow permissions for the following actions: s3:PutObject, s3:GetBucketLocation, sns:GetTopicAttributes, sns:Publish, iam:GetRolePolicy.</p>
+    async fn generate_data_set(
+        &self,
+        input: GenerateDataSetRequest,
+    ) -> Result<GenerateDataSetResult, RusotoError<GenerateDataSetError>> {
+        self.generate_data_set_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartSupportDataExportResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartSupportDataExportError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Given a data set type and a from date, asynchronously publishes the requested customer support data to the specified S3 bucket and notifies the specified SNS topic once the data is available. Returns a unique request identifier that can be used to correlate requests with notifications from the SNS topic. Data sets will be published in comma-separated values (CSV) format with the file name {data<em>set</em>type}_YYYY-MM-DD&#39;T&#39;HH-mm-ss&#39;Z&#39;.csv. If a file with the same name already exists (e.g. if the same data set is requested twice), the original file will be overwritten by the new file. Requires a Role with an attached permissions policy providing Allow permissions for the following actions: s3:PutObject, s3:GetBucketLocation, sns:GetTopicAttributes, sns:Publish, iam:GetRolePolicy.</p>
+    async fn start_support_data_export(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> Result<StartSupportDataExportResult, RusotoError<StartSupportDataExportError>> {
+        self.start_support_data_export_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -923,6 +923,7 @@ pub struct UpdateFlowSourceResponse {
 
 /// Errors returned by AddFlowOutputs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddFlowOutputsError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     AddFlowOutputs420(String),
@@ -938,6 +939,8 @@ pub enum AddFlowOutputsError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddFlowOutputsError {
@@ -945,28 +948,40 @@ impl AddFlowOutputsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AddFlowOutputs420Exception" => {
-                    return RusotoError::Service(AddFlowOutputsError::AddFlowOutputs420(err.msg))
+                    return RusotoError::service(
+                        AddFlowOutputsError::AddFlowOutputs420(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(AddFlowOutputsError::BadRequest(err.msg))
+                    return RusotoError::service(AddFlowOutputsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AddFlowOutputsError::Forbidden(err.msg))
+                    return RusotoError::service(AddFlowOutputsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AddFlowOutputsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        AddFlowOutputsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AddFlowOutputsError::NotFound(err.msg))
+                    return RusotoError::service(AddFlowOutputsError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddFlowOutputsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        AddFlowOutputsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddFlowOutputsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AddFlowOutputsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddFlowOutputsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -983,12 +998,14 @@ impl fmt::Display for AddFlowOutputsError {
             AddFlowOutputsError::NotFound(ref cause) => write!(f, "{}", cause),
             AddFlowOutputsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             AddFlowOutputsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            AddFlowOutputsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddFlowOutputsError {}
 /// Errors returned by CreateFlow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFlowError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1002,6 +1019,8 @@ pub enum CreateFlowError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFlowError {
@@ -1009,25 +1028,28 @@ impl CreateFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFlowError::BadRequest(err.msg))
+                    return RusotoError::service(CreateFlowError::BadRequest(err.msg), &res)
                 }
                 "CreateFlow420Exception" => {
-                    return RusotoError::Service(CreateFlowError::CreateFlow420(err.msg))
+                    return RusotoError::service(CreateFlowError::CreateFlow420(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateFlowError::Forbidden(err.msg))
+                    return RusotoError::service(CreateFlowError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateFlowError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(CreateFlowError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateFlowError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFlowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1043,12 +1065,14 @@ impl fmt::Display for CreateFlowError {
             CreateFlowError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateFlowError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateFlowError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateFlowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFlowError {}
 /// Errors returned by DeleteFlow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFlowError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1062,6 +1086,8 @@ pub enum DeleteFlowError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFlowError {
@@ -1069,25 +1095,28 @@ impl DeleteFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteFlowError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteFlowError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteFlowError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteFlowError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteFlowError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteFlowError::NotFound(err.msg))
+                    return RusotoError::service(DeleteFlowError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(DeleteFlowError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteFlowError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFlowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1103,12 +1132,14 @@ impl fmt::Display for DeleteFlowError {
             DeleteFlowError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteFlowError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteFlowError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteFlowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFlowError {}
 /// Errors returned by DescribeFlow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFlowError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1122,6 +1153,8 @@ pub enum DescribeFlowError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFlowError {
@@ -1129,25 +1162,31 @@ impl DescribeFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeFlowError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeFlowError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeFlowError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeFlowError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeFlowError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFlowError::NotFound(err.msg))
+                    return RusotoError::service(DescribeFlowError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeFlowError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(DescribeFlowError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFlowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1163,12 +1202,14 @@ impl fmt::Display for DescribeFlowError {
             DescribeFlowError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeFlowError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeFlowError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeFlowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFlowError {}
 /// Errors returned by GrantFlowEntitlements
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GrantFlowEntitlementsError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1184,6 +1225,8 @@ pub enum GrantFlowEntitlementsError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GrantFlowEntitlementsError {
@@ -1191,36 +1234,49 @@ impl GrantFlowEntitlementsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GrantFlowEntitlementsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        GrantFlowEntitlementsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GrantFlowEntitlements420Exception" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GrantFlowEntitlementsError::GrantFlowEntitlements420(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GrantFlowEntitlementsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GrantFlowEntitlementsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GrantFlowEntitlementsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GrantFlowEntitlementsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GrantFlowEntitlementsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1239,12 +1295,14 @@ impl fmt::Display for GrantFlowEntitlementsError {
             GrantFlowEntitlementsError::NotFound(ref cause) => write!(f, "{}", cause),
             GrantFlowEntitlementsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GrantFlowEntitlementsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GrantFlowEntitlementsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GrantFlowEntitlementsError {}
 /// Errors returned by ListEntitlements
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEntitlementsError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1254,6 +1312,8 @@ pub enum ListEntitlementsError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEntitlementsError {
@@ -1261,21 +1321,28 @@ impl ListEntitlementsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEntitlementsError::BadRequest(err.msg))
+                    return RusotoError::service(ListEntitlementsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListEntitlementsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntitlementsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListEntitlementsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListEntitlementsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntitlementsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListEntitlementsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEntitlementsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1289,12 +1356,14 @@ impl fmt::Display for ListEntitlementsError {
             ListEntitlementsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListEntitlementsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListEntitlementsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListEntitlementsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEntitlementsError {}
 /// Errors returned by ListFlows
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFlowsError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1304,6 +1373,8 @@ pub enum ListFlowsError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFlowsError {
@@ -1311,19 +1382,19 @@ impl ListFlowsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFlowsError::BadRequest(err.msg))
+                    return RusotoError::service(ListFlowsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFlowsError::InternalServerError(err.msg))
+                    return RusotoError::service(ListFlowsError::InternalServerError(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListFlowsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListFlowsError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListFlowsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListFlowsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFlowsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1337,12 +1408,14 @@ impl fmt::Display for ListFlowsError {
             ListFlowsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListFlowsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListFlowsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListFlowsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFlowsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1350,6 +1423,8 @@ pub enum ListTagsForResourceError {
     InternalServerError(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1357,18 +1432,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1381,12 +1460,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by RemoveFlowOutput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveFlowOutputError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1400,6 +1481,8 @@ pub enum RemoveFlowOutputError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveFlowOutputError {
@@ -1407,27 +1490,34 @@ impl RemoveFlowOutputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::BadRequest(err.msg))
+                    return RusotoError::service(RemoveFlowOutputError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::Forbidden(err.msg))
+                    return RusotoError::service(RemoveFlowOutputError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveFlowOutputError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::NotFound(err.msg))
+                    return RusotoError::service(RemoveFlowOutputError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        RemoveFlowOutputError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        RemoveFlowOutputError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveFlowOutputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1443,12 +1533,14 @@ impl fmt::Display for RemoveFlowOutputError {
             RemoveFlowOutputError::NotFound(ref cause) => write!(f, "{}", cause),
             RemoveFlowOutputError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             RemoveFlowOutputError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            RemoveFlowOutputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveFlowOutputError {}
 /// Errors returned by RevokeFlowEntitlement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RevokeFlowEntitlementError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1462,6 +1554,8 @@ pub enum RevokeFlowEntitlementError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RevokeFlowEntitlementError {
@@ -1469,31 +1563,43 @@ impl RevokeFlowEntitlementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        RevokeFlowEntitlementError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        RevokeFlowEntitlementError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RevokeFlowEntitlementError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::NotFound(err.msg))
+                    return RusotoError::service(
+                        RevokeFlowEntitlementError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RevokeFlowEntitlementError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RevokeFlowEntitlementError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RevokeFlowEntitlementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1509,12 +1615,14 @@ impl fmt::Display for RevokeFlowEntitlementError {
             RevokeFlowEntitlementError::NotFound(ref cause) => write!(f, "{}", cause),
             RevokeFlowEntitlementError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             RevokeFlowEntitlementError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            RevokeFlowEntitlementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RevokeFlowEntitlementError {}
 /// Errors returned by StartFlow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartFlowError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1528,6 +1636,8 @@ pub enum StartFlowError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartFlowError {
@@ -1535,25 +1645,25 @@ impl StartFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartFlowError::BadRequest(err.msg))
+                    return RusotoError::service(StartFlowError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartFlowError::Forbidden(err.msg))
+                    return RusotoError::service(StartFlowError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(StartFlowError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartFlowError::NotFound(err.msg))
+                    return RusotoError::service(StartFlowError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(StartFlowError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(StartFlowError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartFlowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1569,12 +1679,14 @@ impl fmt::Display for StartFlowError {
             StartFlowError::NotFound(ref cause) => write!(f, "{}", cause),
             StartFlowError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             StartFlowError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartFlowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartFlowError {}
 /// Errors returned by StopFlow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopFlowError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1588,6 +1700,8 @@ pub enum StopFlowError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopFlowError {
@@ -1595,25 +1709,25 @@ impl StopFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopFlowError::BadRequest(err.msg))
+                    return RusotoError::service(StopFlowError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopFlowError::Forbidden(err.msg))
+                    return RusotoError::service(StopFlowError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(StopFlowError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopFlowError::NotFound(err.msg))
+                    return RusotoError::service(StopFlowError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(StopFlowError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(StopFlowError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopFlowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1629,12 +1743,14 @@ impl fmt::Display for StopFlowError {
             StopFlowError::NotFound(ref cause) => write!(f, "{}", cause),
             StopFlowError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             StopFlowError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StopFlowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopFlowError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1642,6 +1758,8 @@ pub enum TagResourceError {
     InternalServerError(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1649,16 +1767,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1671,12 +1792,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1684,6 +1807,8 @@ pub enum UntagResourceError {
     InternalServerError(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1691,16 +1816,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1713,12 +1841,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateFlowEntitlement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFlowEntitlementError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1732,6 +1862,8 @@ pub enum UpdateFlowEntitlementError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFlowEntitlementError {
@@ -1739,31 +1871,43 @@ impl UpdateFlowEntitlementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowEntitlementError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowEntitlementError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFlowEntitlementError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowEntitlementError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFlowEntitlementError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFlowEntitlementError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFlowEntitlementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1779,12 +1923,14 @@ impl fmt::Display for UpdateFlowEntitlementError {
             UpdateFlowEntitlementError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateFlowEntitlementError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateFlowEntitlementError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateFlowEntitlementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFlowEntitlementError {}
 /// Errors returned by UpdateFlowOutput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFlowOutputError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1798,6 +1944,8 @@ pub enum UpdateFlowOutputError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFlowOutputError {
@@ -1805,27 +1953,34 @@ impl UpdateFlowOutputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateFlowOutputError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateFlowOutputError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFlowOutputError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::NotFound(err.msg))
+                    return RusotoError::service(UpdateFlowOutputError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowOutputError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowOutputError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFlowOutputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1841,12 +1996,14 @@ impl fmt::Display for UpdateFlowOutputError {
             UpdateFlowOutputError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateFlowOutputError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateFlowOutputError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateFlowOutputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFlowOutputError {}
 /// Errors returned by UpdateFlowSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFlowSourceError {
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     BadRequest(String),
@@ -1860,6 +2017,8 @@ pub enum UpdateFlowSourceError {
     ServiceUnavailable(String),
     /// <p>Exception raised by AWS Elemental MediaConnect. See the error message and documentation for the operation for more information on the cause of this exception.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFlowSourceError {
@@ -1867,27 +2026,34 @@ impl UpdateFlowSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateFlowSourceError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateFlowSourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFlowSourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::NotFound(err.msg))
+                    return RusotoError::service(UpdateFlowSourceError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowSourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateFlowSourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFlowSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1903,6 +2069,7 @@ impl fmt::Display for UpdateFlowSourceError {
             UpdateFlowSourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateFlowSourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateFlowSourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateFlowSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2023,6 +2190,7 @@ impl MediaConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MediaConnectClient {
         MediaConnectClient {
             client: Client::shared(),
@@ -2048,165 +2216,344 @@ impl MediaConnectClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MediaConnectClient {
         MediaConnectClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MediaConnectClient> {
+        rusoto_core::ClientBuilder::new(MediaConnectClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MediaConnectClient {
+        MediaConnectClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MediaConnect for MediaConnectClient {
-    /// <p>Adds outputs to an existing flow. You can create up to 20 outputs per flow.</p>
-    async fn add_flow_outputs(
+impl MediaConnectClient {
+    /// Calls `add_flow_outputs`, also returning the metadata of the response.
+    pub async fn add_flow_outputs_with_metadata(
         &self,
         input: AddFlowOutputsRequest,
-    ) -> Result<AddFlowOutputsResponse, RusotoError<AddFlowOutputsError>> {
+    ) -> Result<(AddFlowOutputsResponse, ResponseMetadata), RusotoError<AddFlowOutputsError>> {
+        let request = self.build_add_flow_outputs_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddFlowOutputsResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AddFlowOutputsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_flow_outputs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_flow_outputs(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddFlowOutputsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_flow_outputs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_flow_outputs_request(&self, input: AddFlowOutputsRequest) -> SignedRequest {
         let request_uri = format!("/v1/flows/{flow_arn}/outputs", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("AddFlowOutputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_flow`, also returning the metadata of the response.
+    pub async fn create_flow_with_metadata(
+        &self,
+        input: CreateFlowRequest,
+    ) -> Result<(CreateFlowResponse, ResponseMetadata), RusotoError<CreateFlowError>> {
+        let request = self.build_create_flow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddFlowOutputsResponse, _>()?;
+                .deserialize::<CreateFlowResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AddFlowOutputsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateFlowError::from_response(response))
         }
     }
 
-    /// <p>Creates a new flow. The request must include one source. The request optionally can include outputs (up to 20) and entitlements (up to 50).</p>
-    async fn create_flow(
+    /// Presigns the request `create_flow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_flow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFlowRequest,
-    ) -> Result<CreateFlowResponse, RusotoError<CreateFlowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_flow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_flow_request(&self, input: CreateFlowRequest) -> SignedRequest {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("CreateFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_flow`, also returning the metadata of the response.
+    pub async fn delete_flow_with_metadata(
+        &self,
+        input: DeleteFlowRequest,
+    ) -> Result<(DeleteFlowResponse, ResponseMetadata), RusotoError<DeleteFlowError>> {
+        let request = self.build_delete_flow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateFlowResponse, _>()?;
+                .deserialize::<DeleteFlowResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFlowError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFlowError::from_response(response))
         }
     }
 
-    /// <p>Deletes a flow. Before you can delete a flow, you must stop the flow.</p>
-    async fn delete_flow(
+    /// Presigns the request `delete_flow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_flow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFlowRequest,
-    ) -> Result<DeleteFlowResponse, RusotoError<DeleteFlowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_flow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_flow_request(&self, input: DeleteFlowRequest) -> SignedRequest {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DeleteFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_flow`, also returning the metadata of the response.
+    pub async fn describe_flow_with_metadata(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<(DescribeFlowResponse, ResponseMetadata), RusotoError<DescribeFlowError>> {
+        let request = self.build_describe_flow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteFlowResponse, _>()?;
+                .deserialize::<DescribeFlowResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFlowError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeFlowError::from_response(response))
         }
     }
 
-    /// <p>Displays the details of a flow. The response includes the flow ARN, name, and Availability Zone, as well as details about the source, outputs, and entitlements.</p>
-    async fn describe_flow(
+    /// Presigns the request `describe_flow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_flow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFlowRequest,
-    ) -> Result<DescribeFlowResponse, RusotoError<DescribeFlowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_flow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_flow_request(&self, input: DescribeFlowRequest) -> SignedRequest {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DescribeFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `grant_flow_entitlements`, also returning the metadata of the response.
+    pub async fn grant_flow_entitlements_with_metadata(
+        &self,
+        input: GrantFlowEntitlementsRequest,
+    ) -> Result<
+        (GrantFlowEntitlementsResponse, ResponseMetadata),
+        RusotoError<GrantFlowEntitlementsError>,
+    > {
+        let request = self.build_grant_flow_entitlements_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFlowResponse, _>()?;
+                .deserialize::<GrantFlowEntitlementsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFlowError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GrantFlowEntitlementsError::from_response(response))
         }
     }
 
-    /// <p>Grants entitlements to an existing flow.</p>
-    async fn grant_flow_entitlements(
+    /// Presigns the request `grant_flow_entitlements` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_grant_flow_entitlements(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GrantFlowEntitlementsRequest,
-    ) -> Result<GrantFlowEntitlementsResponse, RusotoError<GrantFlowEntitlementsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_grant_flow_entitlements_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_grant_flow_entitlements_request(
+        &self,
+        input: GrantFlowEntitlementsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/flows/{flow_arn}/entitlements",
             flow_arn = input.flow_arn
         );
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("GrantFlowEntitlements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_entitlements`, also returning the metadata of the response.
+    pub async fn list_entitlements_with_metadata(
+        &self,
+        input: ListEntitlementsRequest,
+    ) -> Result<(ListEntitlementsResponse, ResponseMetadata), RusotoError<ListEntitlementsError>>
+    {
+        let request = self.build_list_entitlements_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GrantFlowEntitlementsResponse, _>()?;
+                .deserialize::<ListEntitlementsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GrantFlowEntitlementsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListEntitlementsError::from_response(response))
         }
     }
 
-    /// <p>Displays a list of all entitlements that have been granted to this account. This request returns 20 results per page.</p>
-    async fn list_entitlements(
+    /// Presigns the request `list_entitlements` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_entitlements(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEntitlementsRequest,
-    ) -> Result<ListEntitlementsResponse, RusotoError<ListEntitlementsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_entitlements_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_entitlements_request(&self, input: ListEntitlementsRequest) -> SignedRequest {
         let request_uri = "/v1/entitlements";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListEntitlements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2218,31 +2565,55 @@ impl MediaConnect for MediaConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_flows`, also returning the metadata of the response.
+    pub async fn list_flows_with_metadata(
+        &self,
+        input: ListFlowsRequest,
+    ) -> Result<(ListFlowsResponse, ResponseMetadata), RusotoError<ListFlowsError>> {
+        let request = self.build_list_flows_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEntitlementsResponse, _>()?;
+                .deserialize::<ListFlowsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEntitlementsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFlowsError::from_response(response))
         }
     }
 
-    /// <p>Displays a list of flows that are associated with this account. This request returns a paginated result.</p>
-    async fn list_flows(
+    /// Presigns the request `list_flows` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_flows(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFlowsRequest,
-    ) -> Result<ListFlowsResponse, RusotoError<ListFlowsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_flows_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_flows_request(&self, input: ListFlowsRequest) -> SignedRequest {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListFlows");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2254,55 +2625,109 @@ impl MediaConnect for MediaConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFlowsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFlowsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>List all tags on an AWS Elemental MediaConnect resource</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_flow_output`, also returning the metadata of the response.
+    pub async fn remove_flow_output_with_metadata(
+        &self,
+        input: RemoveFlowOutputRequest,
+    ) -> Result<(RemoveFlowOutputResponse, ResponseMetadata), RusotoError<RemoveFlowOutputError>>
+    {
+        let request = self.build_remove_flow_output_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<RemoveFlowOutputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RemoveFlowOutputError::from_response(response))
         }
     }
 
-    /// <p>Removes an output from an existing flow. This request can be made only on an output that does not have an entitlement associated with it. If the output has an entitlement, you must revoke the entitlement instead. When an entitlement is revoked from a flow, the service automatically removes the associated output.</p>
-    async fn remove_flow_output(
+    /// Presigns the request `remove_flow_output` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_flow_output(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveFlowOutputRequest,
-    ) -> Result<RemoveFlowOutputResponse, RusotoError<RemoveFlowOutputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_flow_output_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_flow_output_request(&self, input: RemoveFlowOutputRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/flows/{flow_arn}/outputs/{output_arn}",
             flow_arn = input.flow_arn,
@@ -2310,30 +2735,60 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RemoveFlowOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `revoke_flow_entitlement`, also returning the metadata of the response.
+    pub async fn revoke_flow_entitlement_with_metadata(
+        &self,
+        input: RevokeFlowEntitlementRequest,
+    ) -> Result<
+        (RevokeFlowEntitlementResponse, ResponseMetadata),
+        RusotoError<RevokeFlowEntitlementError>,
+    > {
+        let request = self.build_revoke_flow_entitlement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RemoveFlowOutputResponse, _>()?;
+                .deserialize::<RevokeFlowEntitlementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveFlowOutputError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RevokeFlowEntitlementError::from_response(response))
         }
     }
 
-    /// <p>Revokes an entitlement from a flow. Once an entitlement is revoked, the content becomes unavailable to the subscriber and the associated output is removed.</p>
-    async fn revoke_flow_entitlement(
+    /// Presigns the request `revoke_flow_entitlement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_revoke_flow_entitlement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RevokeFlowEntitlementRequest,
-    ) -> Result<RevokeFlowEntitlementResponse, RusotoError<RevokeFlowEntitlementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_revoke_flow_entitlement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_revoke_flow_entitlement_request(
+        &self,
+        input: RevokeFlowEntitlementRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/flows/{flow_arn}/entitlements/{entitlement_arn}",
             entitlement_arn = input.entitlement_arn,
@@ -2341,116 +2796,212 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RevokeFlowEntitlement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `start_flow`, also returning the metadata of the response.
+    pub async fn start_flow_with_metadata(
+        &self,
+        input: StartFlowRequest,
+    ) -> Result<(StartFlowResponse, ResponseMetadata), RusotoError<StartFlowError>> {
+        let request = self.build_start_flow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RevokeFlowEntitlementResponse, _>()?;
+                .deserialize::<StartFlowResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RevokeFlowEntitlementError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartFlowError::from_response(response))
         }
     }
 
-    /// <p>Starts a flow.</p>
-    async fn start_flow(
+    /// Presigns the request `start_flow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_flow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartFlowRequest,
-    ) -> Result<StartFlowResponse, RusotoError<StartFlowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_flow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_flow_request(&self, input: StartFlowRequest) -> SignedRequest {
         let request_uri = format!("/v1/flows/start/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("StartFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `stop_flow`, also returning the metadata of the response.
+    pub async fn stop_flow_with_metadata(
+        &self,
+        input: StopFlowRequest,
+    ) -> Result<(StopFlowResponse, ResponseMetadata), RusotoError<StopFlowError>> {
+        let request = self.build_stop_flow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartFlowResponse, _>()?;
+                .deserialize::<StopFlowResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartFlowError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopFlowError::from_response(response))
         }
     }
 
-    /// <p>Stops a flow.</p>
-    async fn stop_flow(
+    /// Presigns the request `stop_flow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_flow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopFlowRequest,
-    ) -> Result<StopFlowResponse, RusotoError<StopFlowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_flow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_flow_request(&self, input: StopFlowRequest) -> SignedRequest {
         let request_uri = format!("/v1/flows/stop/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("StopFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopFlowResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopFlowError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes specified tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2459,27 +3010,57 @@ impl MediaConnect for MediaConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_flow_entitlement`, also returning the metadata of the response.
+    pub async fn update_flow_entitlement_with_metadata(
+        &self,
+        input: UpdateFlowEntitlementRequest,
+    ) -> Result<
+        (UpdateFlowEntitlementResponse, ResponseMetadata),
+        RusotoError<UpdateFlowEntitlementError>,
+    > {
+        let request = self.build_update_flow_entitlement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateFlowEntitlementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFlowEntitlementError::from_response(response))
         }
     }
 
-    /// <p>You can change an entitlement&#39;s description, subscribers, and encryption. If you change the subscribers, the service will remove the outputs that are are used by the subscribers that are removed.</p>
-    async fn update_flow_entitlement(
+    /// Presigns the request `update_flow_entitlement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_flow_entitlement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFlowEntitlementRequest,
-    ) -> Result<UpdateFlowEntitlementResponse, RusotoError<UpdateFlowEntitlementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_flow_entitlement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_flow_entitlement_request(
+        &self,
+        input: UpdateFlowEntitlementRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/flows/{flow_arn}/entitlements/{entitlement_arn}",
             entitlement_arn = input.entitlement_arn,
@@ -2487,33 +3068,58 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowEntitlement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_flow_output`, also returning the metadata of the response.
+    pub async fn update_flow_output_with_metadata(
+        &self,
+        input: UpdateFlowOutputRequest,
+    ) -> Result<(UpdateFlowOutputResponse, ResponseMetadata), RusotoError<UpdateFlowOutputError>>
+    {
+        let request = self.build_update_flow_output_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFlowEntitlementResponse, _>()?;
+                .deserialize::<UpdateFlowOutputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFlowEntitlementError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFlowOutputError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing flow output.</p>
-    async fn update_flow_output(
+    /// Presigns the request `update_flow_output` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_flow_output(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFlowOutputRequest,
-    ) -> Result<UpdateFlowOutputResponse, RusotoError<UpdateFlowOutputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_flow_output_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_flow_output_request(&self, input: UpdateFlowOutputRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/flows/{flow_arn}/outputs/{output_arn}",
             flow_arn = input.flow_arn,
@@ -2521,33 +3127,58 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_flow_source`, also returning the metadata of the response.
+    pub async fn update_flow_source_with_metadata(
+        &self,
+        input: UpdateFlowSourceRequest,
+    ) -> Result<(UpdateFlowSourceResponse, ResponseMetadata), RusotoError<UpdateFlowSourceError>>
+    {
+        let request = self.build_update_flow_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFlowOutputResponse, _>()?;
+                .deserialize::<UpdateFlowSourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFlowOutputError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFlowSourceError::from_response(response))
         }
     }
 
-    /// <p>Updates the source of a flow.</p>
-    async fn update_flow_source(
+    /// Presigns the request `update_flow_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_flow_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFlowSourceRequest,
-    ) -> Result<UpdateFlowSourceResponse, RusotoError<UpdateFlowSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_flow_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_flow_source_request(&self, input: UpdateFlowSourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/flows/{flow_arn}/source/{source_arn}",
             flow_arn = input.flow_arn,
@@ -2555,25 +3186,485 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListEntitlements` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_entitlements_pages(
+        &self,
+        input: ListEntitlementsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListEntitlementsResponse, RusotoError<ListEntitlementsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaConnectClient, input| async move { client.list_entitlements(input).await },
+            |input: &ListEntitlementsRequest, output: &ListEntitlementsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEntitlementsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Entitlements` of every page of `ListEntitlements` results for `input`, as `list_entitlements_pages` requests them.
+    pub fn list_entitlements_stream(
+        &self,
+        input: ListEntitlementsRequest,
+    ) -> impl futures::Stream<Item = Result<ListedEntitlement, RusotoError<ListEntitlementsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_entitlements_pages(input), |page| {
+            page.entitlements.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFlows` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_flows_pages(
+        &self,
+        input: ListFlowsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFlowsResponse, RusotoError<ListFlowsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaConnectClient, input| async move { client.list_flows(input).await },
+            |input: &ListFlowsRequest, output: &ListFlowsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFlowsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Flows` of every page of `ListFlows` results for `input`, as `list_flows_pages` requests them.
+    pub fn list_flows_stream(
+        &self,
+        input: ListFlowsRequest,
+    ) -> impl futures::Stream<Item = Result<ListedFlow, RusotoError<ListFlowsError>>> + Send {
+        rusoto_core::pagination::items(self.list_flows_pages(input), |page| {
+            page.flows.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `FlowActive`, polling `DescribeFlow` with `input` every 3 seconds, up to 40 times.
+    pub async fn wait_until_flow_active(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(3), 40);
+        self.wait_until_flow_active_with(input, &waiter).await
+    }
+
+    /// Waits for `FlowActive` like `wait_until_flow_active`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_flow_active_with(
+        &self,
+        input: DescribeFlowRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_flow(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "ACTIVE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "STARTING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "UPDATING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("InternalServerErrorException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ServiceUnavailableException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "STANDBY"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "ERROR"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `FlowDeleted`, polling `DescribeFlow` with `input` every 3 seconds, up to 40 times.
+    pub async fn wait_until_flow_deleted(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(3), 40);
+        self.wait_until_flow_deleted_with(input, &waiter).await
+    }
+
+    /// Waits for `FlowDeleted` like `wait_until_flow_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_flow_deleted_with(
+        &self,
+        input: DescribeFlowRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_flow(input.clone()),
+            |result| {
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("NotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "DELETING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("InternalServerErrorException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ServiceUnavailableException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "ERROR"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `FlowStandby`, polling `DescribeFlow` with `input` every 3 seconds, up to 40 times.
+    pub async fn wait_until_flow_standby(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(3), 40);
+        self.wait_until_flow_standby_with(input, &waiter).await
+    }
+
+    /// Waits for `FlowStandby` like `wait_until_flow_standby`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_flow_standby_with(
+        &self,
+        input: DescribeFlowRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFlowError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_flow(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "STANDBY"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "STOPPING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("InternalServerErrorException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ServiceUnavailableException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.flow.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "ERROR"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl MediaConnect for MediaConnectClient {
+    /// <p>Adds outputs to an existing flow. You can create up to 20 outputs per flow.</p>
+    async fn add_flow_outputs(
+        &self,
+        input: AddFlowOutputsRequest,
+    ) -> Result<AddFlowOutputsResponse, RusotoError<AddFlowOutputsError>> {
+        self.add_flow_outputs_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFlowSourceResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFlowSourceError::from_response(response))
-        }
+    /// <p>Creates a new flow. The request must include one source. The request optionally can include outputs (up to 20) and entitlements (up to 50).</p>
+    async fn create_flow(
+        &self,
+        input: CreateFlowRequest,
+    ) -> Result<CreateFlowResponse, RusotoError<CreateFlowError>> {
+        self.create_flow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a flow. Before you can delete a flow, you must stop the flow.</p>
+    async fn delete_flow(
+        &self,
+        input: DeleteFlowRequest,
+    ) -> Result<DeleteFlowResponse, RusotoError<DeleteFlowError>> {
+        self.delete_flow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays the details of a flow. The response includes the flow ARN, name, and Availability Zone, as well as details about the source, outputs, and entitlements.</p>
+    async fn describe_flow(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<DescribeFlowResponse, RusotoError<DescribeFlowError>> {
+        self.describe_flow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Grants entitlements to an existing flow.</p>
+    async fn grant_flow_entitlements(
+        &self,
+        input: GrantFlowEntitlementsRequest,
+    ) -> Result<GrantFlowEntitlementsResponse, RusotoError<GrantFlowEntitlementsError>> {
+        self.grant_flow_entitlements_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays a list of all entitlements that have been granted to this account. This request returns 20 results per page.</p>
+    async fn list_entitlements(
+        &self,
+        input: ListEntitlementsRequest,
+    ) -> Result<ListEntitlementsResponse, RusotoError<ListEntitlementsError>> {
+        self.list_entitlements_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays a list of flows that are associated with this account. This request returns a paginated result.</p>
+    async fn list_flows(
+        &self,
+        input: ListFlowsRequest,
+    ) -> Result<ListFlowsResponse, RusotoError<ListFlowsError>> {
+        self.list_flows_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all tags on an AWS Elemental MediaConnect resource</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes an output from an existing flow. This request can be made only on an output that does not have an entitlement associated with it. If the output has an entitlement, you must revoke the entitlement instead. When an entitlement is revoked from a flow, the service automatically removes the associated output.</p>
+    async fn remove_flow_output(
+        &self,
+        input: RemoveFlowOutputRequest,
+    ) -> Result<RemoveFlowOutputResponse, RusotoError<RemoveFlowOutputError>> {
+        self.remove_flow_output_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Revokes an entitlement from a flow. Once an entitlement is revoked, the content becomes unavailable to the subscriber and the associated output is removed.</p>
+    async fn revoke_flow_entitlement(
+        &self,
+        input: RevokeFlowEntitlementRequest,
+    ) -> Result<RevokeFlowEntitlementResponse, RusotoError<RevokeFlowEntitlementError>> {
+        self.revoke_flow_entitlement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a flow.</p>
+    async fn start_flow(
+        &self,
+        input: StartFlowRequest,
+    ) -> Result<StartFlowResponse, RusotoError<StartFlowError>> {
+        self.start_flow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a flow.</p>
+    async fn stop_flow(
+        &self,
+        input: StopFlowRequest,
+    ) -> Result<StopFlowResponse, RusotoError<StopFlowError>> {
+        self.stop_flow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>You can change an entitlement&#39;s description, subscribers, and encryption. If you change the subscribers, the service will remove the outputs that are are used by the subscribers that are removed.</p>
+    async fn update_flow_entitlement(
+        &self,
+        input: UpdateFlowEntitlementRequest,
+    ) -> Result<UpdateFlowEntitlementResponse, RusotoError<UpdateFlowEntitlementError>> {
+        self.update_flow_entitlement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing flow output.</p>
+    async fn update_flow_output(
+        &self,
+        input: UpdateFlowOutputRequest,
+    ) -> Result<UpdateFlowOutputResponse, RusotoError<UpdateFlowOutputError>> {
+        self.update_flow_output_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the source of a flow.</p>
+    async fn update_flow_source(
+        &self,
+        input: UpdateFlowSourceRequest,
+    ) -> Result<UpdateFlowSourceResponse, RusotoError<UpdateFlowSourceError>> {
+        self.update_flow_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -4521,6 +4521,7 @@ pub struct WavSettings {
 
 /// Errors returned by AssociateCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateCertificateError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -4534,6 +4535,8 @@ pub enum AssociateCertificateError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateCertificateError {
@@ -4541,29 +4544,37 @@ impl AssociateCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociateCertificateError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        AssociateCertificateError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateCertificateError::Conflict(err.msg))
+                    return RusotoError::service(AssociateCertificateError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AssociateCertificateError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        AssociateCertificateError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AssociateCertificateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCertificateError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateCertificateError::NotFound(err.msg))
+                    return RusotoError::service(AssociateCertificateError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AssociateCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCertificateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4579,12 +4590,14 @@ impl fmt::Display for AssociateCertificateError {
             AssociateCertificateError::InternalServerError(ref cause) => write!(f, "{}", cause),
             AssociateCertificateError::NotFound(ref cause) => write!(f, "{}", cause),
             AssociateCertificateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            AssociateCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateCertificateError {}
 /// Errors returned by CancelJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelJobError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -4598,6 +4611,8 @@ pub enum CancelJobError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelJobError {
@@ -4605,25 +4620,25 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CancelJobError::BadRequest(err.msg))
+                    return RusotoError::service(CancelJobError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CancelJobError::Conflict(err.msg))
+                    return RusotoError::service(CancelJobError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CancelJobError::Forbidden(err.msg))
+                    return RusotoError::service(CancelJobError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CancelJobError::InternalServerError(err.msg))
+                    return RusotoError::service(CancelJobError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CancelJobError::NotFound(err.msg))
+                    return RusotoError::service(CancelJobError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CancelJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(CancelJobError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4639,12 +4654,14 @@ impl fmt::Display for CancelJobError {
             CancelJobError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CancelJobError::NotFound(ref cause) => write!(f, "{}", cause),
             CancelJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CancelJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelJobError {}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateJobError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -4658,6 +4675,8 @@ pub enum CreateJobError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateJobError {
@@ -4665,25 +4684,25 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateJobError::BadRequest(err.msg))
+                    return RusotoError::service(CreateJobError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateJobError::Conflict(err.msg))
+                    return RusotoError::service(CreateJobError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateJobError::Forbidden(err.msg))
+                    return RusotoError::service(CreateJobError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateJobError::InternalServerError(err.msg))
+                    return RusotoError::service(CreateJobError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateJobError::NotFound(err.msg))
+                    return RusotoError::service(CreateJobError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateJobError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4699,12 +4718,14 @@ impl fmt::Display for CreateJobError {
             CreateJobError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateJobError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateJobError {}
 /// Errors returned by CreateJobTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateJobTemplateError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -4718,6 +4739,8 @@ pub enum CreateJobTemplateError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateJobTemplateError {
@@ -4725,27 +4748,31 @@ impl CreateJobTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateJobTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(CreateJobTemplateError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateJobTemplateError::Conflict(err.msg))
+                    return RusotoError::service(CreateJobTemplateError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateJobTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(CreateJobTemplateError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateJobTemplateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateJobTemplateError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateJobTemplateError::NotFound(err.msg))
+                    return RusotoError::service(CreateJobTemplateError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateJobTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateJobTemplateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateJobTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4761,12 +4788,14 @@ impl fmt::Display for CreateJobTemplateError {
             CreateJobTemplateError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateJobTemplateError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateJobTemplateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateJobTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateJobTemplateError {}
 /// Errors returned by CreatePreset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePresetError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -4780,6 +4809,8 @@ pub enum CreatePresetError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePresetError {
@@ -4787,25 +4818,28 @@ impl CreatePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreatePresetError::BadRequest(err.msg))
+                    return RusotoError::service(CreatePresetError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreatePresetError::Conflict(err.msg))
+                    return RusotoError::service(CreatePresetError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePresetError::Forbidden(err.msg))
+                    return RusotoError::service(CreatePresetError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreatePresetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreatePresetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePresetError::NotFound(err.msg))
+                    return RusotoError::service(CreatePresetError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreatePresetError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreatePresetError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePresetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4821,12 +4855,14 @@ impl fmt::Display for CreatePresetError {
             CreatePresetError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreatePresetError::NotFound(ref cause) => write!(f, "{}", cause),
             CreatePresetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreatePresetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePresetError {}
 /// Errors returned by CreateQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateQueueError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -4840,6 +4876,8 @@ pub enum CreateQueueError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateQueueError {
@@ -4847,25 +4885,28 @@ impl CreateQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateQueueError::BadRequest(err.msg))
+                    return RusotoError::service(CreateQueueError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateQueueError::Conflict(err.msg))
+                    return RusotoError::service(CreateQueueError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateQueueError::Forbidden(err.msg))
+                    return RusotoError::service(CreateQueueError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateQueueError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateQueueError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateQueueError::NotFound(err.msg))
+                    return RusotoError::service(CreateQueueError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateQueueError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateQueueError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4881,12 +4922,14 @@ impl fmt::Display for CreateQueueError {
             CreateQueueError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateQueueError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateQueueError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateQueueError {}
 /// Errors returned by DeleteJobTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteJobTemplateError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -4900,6 +4943,8 @@ pub enum DeleteJobTemplateError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteJobTemplateError {
@@ -4907,27 +4952,31 @@ impl DeleteJobTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteJobTemplateError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::Conflict(err.msg))
+                    return RusotoError::service(DeleteJobTemplateError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteJobTemplateError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteJobTemplateError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::NotFound(err.msg))
+                    return RusotoError::service(DeleteJobTemplateError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteJobTemplateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteJobTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4943,12 +4992,14 @@ impl fmt::Display for DeleteJobTemplateError {
             DeleteJobTemplateError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteJobTemplateError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteJobTemplateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteJobTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteJobTemplateError {}
 /// Errors returned by DeletePreset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePresetError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -4962,6 +5013,8 @@ pub enum DeletePresetError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePresetError {
@@ -4969,25 +5022,28 @@ impl DeletePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeletePresetError::BadRequest(err.msg))
+                    return RusotoError::service(DeletePresetError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeletePresetError::Conflict(err.msg))
+                    return RusotoError::service(DeletePresetError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePresetError::Forbidden(err.msg))
+                    return RusotoError::service(DeletePresetError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeletePresetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeletePresetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePresetError::NotFound(err.msg))
+                    return RusotoError::service(DeletePresetError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePresetError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeletePresetError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePresetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5003,12 +5059,14 @@ impl fmt::Display for DeletePresetError {
             DeletePresetError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeletePresetError::NotFound(ref cause) => write!(f, "{}", cause),
             DeletePresetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeletePresetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePresetError {}
 /// Errors returned by DeleteQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteQueueError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5022,6 +5080,8 @@ pub enum DeleteQueueError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteQueueError {
@@ -5029,25 +5089,28 @@ impl DeleteQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteQueueError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteQueueError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteQueueError::Conflict(err.msg))
+                    return RusotoError::service(DeleteQueueError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteQueueError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteQueueError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteQueueError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteQueueError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteQueueError::NotFound(err.msg))
+                    return RusotoError::service(DeleteQueueError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteQueueError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteQueueError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5063,12 +5126,14 @@ impl fmt::Display for DeleteQueueError {
             DeleteQueueError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteQueueError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteQueueError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteQueueError {}
 /// Errors returned by DescribeEndpoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEndpointsError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5082,6 +5147,8 @@ pub enum DescribeEndpointsError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEndpointsError {
@@ -5089,27 +5156,31 @@ impl DescribeEndpointsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEndpointsError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeEndpointsError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEndpointsError::Conflict(err.msg))
+                    return RusotoError::service(DescribeEndpointsError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEndpointsError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeEndpointsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeEndpointsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEndpointsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEndpointsError::NotFound(err.msg))
+                    return RusotoError::service(DescribeEndpointsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEndpointsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeEndpointsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5125,12 +5196,14 @@ impl fmt::Display for DescribeEndpointsError {
             DescribeEndpointsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeEndpointsError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeEndpointsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEndpointsError {}
 /// Errors returned by DisassociateCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateCertificateError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5144,6 +5217,8 @@ pub enum DisassociateCertificateError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateCertificateError {
@@ -5151,29 +5226,43 @@ impl DisassociateCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateCertificateError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DisassociateCertificateError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateCertificateError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DisassociateCertificateError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DisassociateCertificateError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DisassociateCertificateError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DisassociateCertificateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateCertificateError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateCertificateError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DisassociateCertificateError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisassociateCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateCertificateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5189,12 +5278,14 @@ impl fmt::Display for DisassociateCertificateError {
             DisassociateCertificateError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DisassociateCertificateError::NotFound(ref cause) => write!(f, "{}", cause),
             DisassociateCertificateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DisassociateCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateCertificateError {}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5208,6 +5299,8 @@ pub enum GetJobError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobError {
@@ -5215,21 +5308,25 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetJobError::BadRequest(err.msg))
+                    return RusotoError::service(GetJobError::BadRequest(err.msg), &res)
+                }
+                "ConflictException" => {
+                    return RusotoError::service(GetJobError::Conflict(err.msg), &res)
                 }
-                "ConflictException" => return RusotoError::Service(GetJobError::Conflict(err.msg)),
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetJobError::Forbidden(err.msg))
+                    return RusotoError::service(GetJobError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetJobError::InternalServerError(err.msg))
+                    return RusotoError::service(GetJobError::InternalServerError(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(GetJobError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(GetJobError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetJobError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5245,12 +5342,14 @@ impl fmt::Display for GetJobError {
             GetJobError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetJobError::NotFound(ref cause) => write!(f, "{}", cause),
             GetJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobError {}
 /// Errors returned by GetJobTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobTemplateError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5264,6 +5363,8 @@ pub enum GetJobTemplateError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobTemplateError {
@@ -5271,25 +5372,31 @@ impl GetJobTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetJobTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(GetJobTemplateError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetJobTemplateError::Conflict(err.msg))
+                    return RusotoError::service(GetJobTemplateError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetJobTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(GetJobTemplateError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetJobTemplateError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetJobTemplateError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetJobTemplateError::NotFound(err.msg))
+                    return RusotoError::service(GetJobTemplateError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetJobTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetJobTemplateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5305,12 +5412,14 @@ impl fmt::Display for GetJobTemplateError {
             GetJobTemplateError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetJobTemplateError::NotFound(ref cause) => write!(f, "{}", cause),
             GetJobTemplateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetJobTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobTemplateError {}
 /// Errors returned by GetPreset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPresetError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5324,6 +5433,8 @@ pub enum GetPresetError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPresetError {
@@ -5331,25 +5442,25 @@ impl GetPresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPresetError::BadRequest(err.msg))
+                    return RusotoError::service(GetPresetError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetPresetError::Conflict(err.msg))
+                    return RusotoError::service(GetPresetError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPresetError::Forbidden(err.msg))
+                    return RusotoError::service(GetPresetError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetPresetError::InternalServerError(err.msg))
+                    return RusotoError::service(GetPresetError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPresetError::NotFound(err.msg))
+                    return RusotoError::service(GetPresetError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetPresetError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetPresetError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPresetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5365,12 +5476,14 @@ impl fmt::Display for GetPresetError {
             GetPresetError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetPresetError::NotFound(ref cause) => write!(f, "{}", cause),
             GetPresetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetPresetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPresetError {}
 /// Errors returned by GetQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetQueueError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5384,6 +5497,8 @@ pub enum GetQueueError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetQueueError {
@@ -5391,25 +5506,25 @@ impl GetQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetQueueError::BadRequest(err.msg))
+                    return RusotoError::service(GetQueueError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetQueueError::Conflict(err.msg))
+                    return RusotoError::service(GetQueueError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetQueueError::Forbidden(err.msg))
+                    return RusotoError::service(GetQueueError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetQueueError::InternalServerError(err.msg))
+                    return RusotoError::service(GetQueueError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetQueueError::NotFound(err.msg))
+                    return RusotoError::service(GetQueueError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetQueueError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetQueueError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5425,12 +5540,14 @@ impl fmt::Display for GetQueueError {
             GetQueueError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetQueueError::NotFound(ref cause) => write!(f, "{}", cause),
             GetQueueError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetQueueError {}
 /// Errors returned by ListJobTemplates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobTemplatesError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5444,6 +5561,8 @@ pub enum ListJobTemplatesError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobTemplatesError {
@@ -5451,27 +5570,31 @@ impl ListJobTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListJobTemplatesError::BadRequest(err.msg))
+                    return RusotoError::service(ListJobTemplatesError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListJobTemplatesError::Conflict(err.msg))
+                    return RusotoError::service(ListJobTemplatesError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListJobTemplatesError::Forbidden(err.msg))
+                    return RusotoError::service(ListJobTemplatesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListJobTemplatesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListJobTemplatesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListJobTemplatesError::NotFound(err.msg))
+                    return RusotoError::service(ListJobTemplatesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListJobTemplatesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListJobTemplatesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobTemplatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5487,12 +5610,14 @@ impl fmt::Display for ListJobTemplatesError {
             ListJobTemplatesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListJobTemplatesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListJobTemplatesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListJobTemplatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobTemplatesError {}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5506,6 +5631,8 @@ pub enum ListJobsError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsError {
@@ -5513,25 +5640,25 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListJobsError::BadRequest(err.msg))
+                    return RusotoError::service(ListJobsError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListJobsError::Conflict(err.msg))
+                    return RusotoError::service(ListJobsError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListJobsError::Forbidden(err.msg))
+                    return RusotoError::service(ListJobsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListJobsError::InternalServerError(err.msg))
+                    return RusotoError::service(ListJobsError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListJobsError::NotFound(err.msg))
+                    return RusotoError::service(ListJobsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListJobsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListJobsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5547,12 +5674,14 @@ impl fmt::Display for ListJobsError {
             ListJobsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListJobsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListJobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsError {}
 /// Errors returned by ListPresets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPresetsError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5566,6 +5695,8 @@ pub enum ListPresetsError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPresetsError {
@@ -5573,25 +5704,28 @@ impl ListPresetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPresetsError::BadRequest(err.msg))
+                    return RusotoError::service(ListPresetsError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListPresetsError::Conflict(err.msg))
+                    return RusotoError::service(ListPresetsError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPresetsError::Forbidden(err.msg))
+                    return RusotoError::service(ListPresetsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListPresetsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListPresetsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListPresetsError::NotFound(err.msg))
+                    return RusotoError::service(ListPresetsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPresetsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListPresetsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPresetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5607,12 +5741,14 @@ impl fmt::Display for ListPresetsError {
             ListPresetsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListPresetsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListPresetsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListPresetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPresetsError {}
 /// Errors returned by ListQueues
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListQueuesError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5626,6 +5762,8 @@ pub enum ListQueuesError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListQueuesError {
@@ -5633,25 +5771,28 @@ impl ListQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListQueuesError::BadRequest(err.msg))
+                    return RusotoError::service(ListQueuesError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListQueuesError::Conflict(err.msg))
+                    return RusotoError::service(ListQueuesError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListQueuesError::Forbidden(err.msg))
+                    return RusotoError::service(ListQueuesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListQueuesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListQueuesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListQueuesError::NotFound(err.msg))
+                    return RusotoError::service(ListQueuesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListQueuesError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListQueuesError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListQueuesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5667,12 +5808,14 @@ impl fmt::Display for ListQueuesError {
             ListQueuesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListQueuesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListQueuesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListQueuesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListQueuesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5686,6 +5829,8 @@ pub enum ListTagsForResourceError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -5693,27 +5838,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Conflict(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5729,12 +5881,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5748,6 +5902,8 @@ pub enum TagResourceError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -5755,25 +5911,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service(TagResourceError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(TagResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5789,12 +5948,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5808,6 +5969,8 @@ pub enum UntagResourceError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -5815,25 +5978,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service(UntagResourceError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(UntagResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5849,12 +6015,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateJobTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateJobTemplateError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5868,6 +6036,8 @@ pub enum UpdateJobTemplateError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateJobTemplateError {
@@ -5875,27 +6045,31 @@ impl UpdateJobTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateJobTemplateError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::Conflict(err.msg))
+                    return RusotoError::service(UpdateJobTemplateError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateJobTemplateError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateJobTemplateError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::NotFound(err.msg))
+                    return RusotoError::service(UpdateJobTemplateError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateJobTemplateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateJobTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5911,12 +6085,14 @@ impl fmt::Display for UpdateJobTemplateError {
             UpdateJobTemplateError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateJobTemplateError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateJobTemplateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateJobTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateJobTemplateError {}
 /// Errors returned by UpdatePreset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePresetError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5930,6 +6106,8 @@ pub enum UpdatePresetError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePresetError {
@@ -5937,25 +6115,28 @@ impl UpdatePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePresetError::BadRequest(err.msg))
+                    return RusotoError::service(UpdatePresetError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdatePresetError::Conflict(err.msg))
+                    return RusotoError::service(UpdatePresetError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePresetError::Forbidden(err.msg))
+                    return RusotoError::service(UpdatePresetError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdatePresetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdatePresetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdatePresetError::NotFound(err.msg))
+                    return RusotoError::service(UpdatePresetError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdatePresetError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdatePresetError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePresetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5971,12 +6152,14 @@ impl fmt::Display for UpdatePresetError {
             UpdatePresetError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdatePresetError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdatePresetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdatePresetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePresetError {}
 /// Errors returned by UpdateQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateQueueError {
     /// <p>The service can&#39;t process your request because of a problem in the request. Please check your request form and syntax.</p>
     BadRequest(String),
@@ -5990,6 +6173,8 @@ pub enum UpdateQueueError {
     NotFound(String),
     /// <p>Too many requests have been sent in too short of a time. The service limits the rate at which it will accept requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateQueueError {
@@ -5997,25 +6182,28 @@ impl UpdateQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateQueueError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateQueueError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateQueueError::Conflict(err.msg))
+                    return RusotoError::service(UpdateQueueError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateQueueError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateQueueError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateQueueError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateQueueError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateQueueError::NotFound(err.msg))
+                    return RusotoError::service(UpdateQueueError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateQueueError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateQueueError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6031,6 +6219,7 @@ impl fmt::Display for UpdateQueueError {
             UpdateQueueError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateQueueError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateQueueError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -6199,6 +6388,7 @@ impl MediaConvertClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MediaConvertClient {
         MediaConvertClient {
             client: Client::shared(),
@@ -6224,441 +6414,869 @@ impl MediaConvertClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MediaConvertClient {
         MediaConvertClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MediaConvertClient> {
+        rusoto_core::ClientBuilder::new(MediaConvertClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MediaConvertClient {
+        MediaConvertClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MediaConvert for MediaConvertClient {
-    /// <p>Associates an AWS Certificate Manager (ACM) Amazon Resource Name (ARN) with AWS Elemental MediaConvert.</p>
-    async fn associate_certificate(
+impl MediaConvertClient {
+    /// Calls `associate_certificate`, also returning the metadata of the response.
+    pub async fn associate_certificate_with_metadata(
         &self,
         input: AssociateCertificateRequest,
-    ) -> Result<AssociateCertificateResponse, RusotoError<AssociateCertificateError>> {
-        let request_uri = "/2017-08-29/certificates";
-
-        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (AssociateCertificateResponse, ResponseMetadata),
+        RusotoError<AssociateCertificateError>,
+    > {
+        let request = self.build_associate_certificate_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<AssociateCertificateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(AssociateCertificateError::from_response(response))
         }
     }
 
-    /// <p>Permanently cancel a job. Once you have canceled a job, you can&#39;t start it again.</p>
-    async fn cancel_job(
+    /// Presigns the request `associate_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_certificate(
         &self,
-        input: CancelJobRequest,
-    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
-        let request_uri = format!("/2017-08-29/jobs/{id}", id = input.id);
+        presigner: &rusoto_core::Presigner,
+        input: AssociateCertificateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+    fn build_associate_certificate_request(
+        &self,
+        input: AssociateCertificateRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2017-08-29/certificates";
+
+        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("AssociateCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `cancel_job`, also returning the metadata of the response.
+    pub async fn cancel_job_with_metadata(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<(CancelJobResponse, ResponseMetadata), RusotoError<CancelJobError>> {
+        let request = self.build_cancel_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CancelJobError::from_response(response))
         }
     }
 
-    /// <p>Create a new transcoding job. For information about jobs and job settings, see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
-    async fn create_job(
+    /// Presigns the request `cancel_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_job(
         &self,
-        input: CreateJobRequest,
-    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
-        let request_uri = "/2017-08-29/jobs";
+        presigner: &rusoto_core::Presigner,
+        input: CancelJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_job_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+    fn build_cancel_job_request(&self, input: CancelJobRequest) -> SignedRequest {
+        let request_uri = format!("/2017-08-29/jobs/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+        request
+    }
+
+    /// Calls `create_job`, also returning the metadata of the response.
+    pub async fn create_job_with_metadata(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<(CreateJobResponse, ResponseMetadata), RusotoError<CreateJobError>> {
+        let request = self.build_create_job_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateJobError::from_response(response))
         }
     }
 
-    /// <p>Create a new job template. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
-    async fn create_job_template(
+    /// Presigns the request `create_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_job(
         &self,
-        input: CreateJobTemplateRequest,
-    ) -> Result<CreateJobTemplateResponse, RusotoError<CreateJobTemplateError>> {
-        let request_uri = "/2017-08-29/jobTemplates";
+        presigner: &rusoto_core::Presigner,
+        input: CreateJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_job_request(&self, input: CreateJobRequest) -> SignedRequest {
+        let request_uri = "/2017-08-29/jobs";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_job_template`, also returning the metadata of the response.
+    pub async fn create_job_template_with_metadata(
+        &self,
+        input: CreateJobTemplateRequest,
+    ) -> Result<(CreateJobTemplateResponse, ResponseMetadata), RusotoError<CreateJobTemplateError>>
+    {
+        let request = self.build_create_job_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateJobTemplateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateJobTemplateError::from_response(response))
         }
     }
 
-    /// <p>Create a new preset. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
-    async fn create_preset(
+    /// Presigns the request `create_job_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_job_template(
         &self,
-        input: CreatePresetRequest,
-    ) -> Result<CreatePresetResponse, RusotoError<CreatePresetError>> {
-        let request_uri = "/2017-08-29/presets";
+        presigner: &rusoto_core::Presigner,
+        input: CreateJobTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_job_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_job_template_request(&self, input: CreateJobTemplateRequest) -> SignedRequest {
+        let request_uri = "/2017-08-29/jobTemplates";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CreateJobTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_preset`, also returning the metadata of the response.
+    pub async fn create_preset_with_metadata(
+        &self,
+        input: CreatePresetRequest,
+    ) -> Result<(CreatePresetResponse, ResponseMetadata), RusotoError<CreatePresetError>> {
+        let request = self.build_create_preset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreatePresetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreatePresetError::from_response(response))
         }
     }
 
-    /// <p>Create a new transcoding queue. For information about queues, see Working With Queues in the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/working-with-queues.html</p>
-    async fn create_queue(
+    /// Presigns the request `create_preset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_preset(
         &self,
-        input: CreateQueueRequest,
-    ) -> Result<CreateQueueResponse, RusotoError<CreateQueueError>> {
-        let request_uri = "/2017-08-29/queues";
+        presigner: &rusoto_core::Presigner,
+        input: CreatePresetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_preset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_preset_request(&self, input: CreatePresetRequest) -> SignedRequest {
+        let request_uri = "/2017-08-29/presets";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CreatePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_queue`, also returning the metadata of the response.
+    pub async fn create_queue_with_metadata(
+        &self,
+        input: CreateQueueRequest,
+    ) -> Result<(CreateQueueResponse, ResponseMetadata), RusotoError<CreateQueueError>> {
+        let request = self.build_create_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateQueueResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateQueueError::from_response(response))
         }
     }
 
-    /// <p>Permanently delete a job template you have created.</p>
-    async fn delete_job_template(
+    /// Presigns the request `create_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_queue(
         &self,
-        input: DeleteJobTemplateRequest,
-    ) -> Result<DeleteJobTemplateResponse, RusotoError<DeleteJobTemplateError>> {
-        let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
+        presigner: &rusoto_core::Presigner,
+        input: CreateQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+    fn build_create_queue_request(&self, input: CreateQueueRequest) -> SignedRequest {
+        let request_uri = "/2017-08-29/queues";
+
+        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CreateQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `delete_job_template`, also returning the metadata of the response.
+    pub async fn delete_job_template_with_metadata(
+        &self,
+        input: DeleteJobTemplateRequest,
+    ) -> Result<(DeleteJobTemplateResponse, ResponseMetadata), RusotoError<DeleteJobTemplateError>>
+    {
+        let request = self.build_delete_job_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteJobTemplateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteJobTemplateError::from_response(response))
         }
     }
 
-    /// <p>Permanently delete a preset you have created.</p>
-    async fn delete_preset(
+    /// Presigns the request `delete_job_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_job_template(
         &self,
-        input: DeletePresetRequest,
-    ) -> Result<DeletePresetResponse, RusotoError<DeletePresetError>> {
-        let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
+        presigner: &rusoto_core::Presigner,
+        input: DeleteJobTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_job_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_job_template_request(&self, input: DeleteJobTemplateRequest) -> SignedRequest {
+        let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DeleteJobTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_preset`, also returning the metadata of the response.
+    pub async fn delete_preset_with_metadata(
+        &self,
+        input: DeletePresetRequest,
+    ) -> Result<(DeletePresetResponse, ResponseMetadata), RusotoError<DeletePresetError>> {
+        let request = self.build_delete_preset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeletePresetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeletePresetError::from_response(response))
         }
     }
 
-    /// <p>Permanently delete a queue you have created.</p>
-    async fn delete_queue(
+    /// Presigns the request `delete_preset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_preset(
         &self,
-        input: DeleteQueueRequest,
-    ) -> Result<DeleteQueueResponse, RusotoError<DeleteQueueError>> {
-        let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
+        presigner: &rusoto_core::Presigner,
+        input: DeletePresetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_preset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_preset_request(&self, input: DeletePresetRequest) -> SignedRequest {
+        let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DeletePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_queue`, also returning the metadata of the response.
+    pub async fn delete_queue_with_metadata(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> Result<(DeleteQueueResponse, ResponseMetadata), RusotoError<DeleteQueueError>> {
+        let request = self.build_delete_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteQueueResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteQueueError::from_response(response))
         }
     }
 
-    /// <p>Send an request with an empty body to the regional API endpoint to get your account API endpoint.</p>
-    async fn describe_endpoints(
+    /// Presigns the request `delete_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_queue(
         &self,
-        input: DescribeEndpointsRequest,
-    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
-        let request_uri = "/2017-08-29/endpoints";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+    fn build_delete_queue_request(&self, input: DeleteQueueRequest) -> SignedRequest {
+        let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
+
+        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DeleteQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+        request
+    }
+
+    /// Calls `describe_endpoints`, also returning the metadata of the response.
+    pub async fn describe_endpoints_with_metadata(
+        &self,
+        input: DescribeEndpointsRequest,
+    ) -> Result<(DescribeEndpointsResponse, ResponseMetadata), RusotoError<DescribeEndpointsError>>
+    {
+        let request = self.build_describe_endpoints_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeEndpointsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeEndpointsError::from_response(response))
         }
     }
 
-    /// <p>Removes an association between the Amazon Resource Name (ARN) of an AWS Certificate Manager (ACM) certificate and an AWS Elemental MediaConvert resource.</p>
-    async fn disassociate_certificate(
+    /// Presigns the request `describe_endpoints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_endpoints(
         &self,
-        input: DisassociateCertificateRequest,
-    ) -> Result<DisassociateCertificateResponse, RusotoError<DisassociateCertificateError>> {
-        let request_uri = format!("/2017-08-29/certificates/{arn}", arn = input.arn);
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEndpointsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_endpoints_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+    fn build_describe_endpoints_request(&self, input: DescribeEndpointsRequest) -> SignedRequest {
+        let request_uri = "/2017-08-29/endpoints";
+
+        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DescribeEndpoints");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `disassociate_certificate`, also returning the metadata of the response.
+    pub async fn disassociate_certificate_with_metadata(
+        &self,
+        input: DisassociateCertificateRequest,
+    ) -> Result<
+        (DisassociateCertificateResponse, ResponseMetadata),
+        RusotoError<DisassociateCertificateError>,
+    > {
+        let request = self.build_disassociate_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateCertificateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DisassociateCertificateError::from_response(response))
         }
     }
 
-    /// <p>Retrieve the JSON for a specific completed transcoding job.</p>
-    async fn get_job(
+    /// Presigns the request `disassociate_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_certificate(
         &self,
-        input: GetJobRequest,
-    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
-        let request_uri = format!("/2017-08-29/jobs/{id}", id = input.id);
+        presigner: &rusoto_core::Presigner,
+        input: DisassociateCertificateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+    fn build_disassociate_certificate_request(
+        &self,
+        input: DisassociateCertificateRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2017-08-29/certificates/{arn}", arn = input.arn);
+
+        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DisassociateCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_job`, also returning the metadata of the response.
+    pub async fn get_job_with_metadata(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<(GetJobResponse, ResponseMetadata), RusotoError<GetJobError>> {
+        let request = self.build_get_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<GetJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetJobError::from_response(response))
         }
     }
 
-    /// <p>Retrieve the JSON for a specific job template.</p>
-    async fn get_job_template(
+    /// Presigns the request `get_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job(
         &self,
-        input: GetJobTemplateRequest,
-    ) -> Result<GetJobTemplateResponse, RusotoError<GetJobTemplateError>> {
-        let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
+        presigner: &rusoto_core::Presigner,
+        input: GetJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_request(&self, input: GetJobRequest) -> SignedRequest {
+        let request_uri = format!("/2017-08-29/jobs/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("GetJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_job_template`, also returning the metadata of the response.
+    pub async fn get_job_template_with_metadata(
+        &self,
+        input: GetJobTemplateRequest,
+    ) -> Result<(GetJobTemplateResponse, ResponseMetadata), RusotoError<GetJobTemplateError>> {
+        let request = self.build_get_job_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetJobTemplateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetJobTemplateError::from_response(response))
         }
     }
 
-    /// <p>Retrieve the JSON for a specific preset.</p>
-    async fn get_preset(
+    /// Presigns the request `get_job_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job_template(
         &self,
-        input: GetPresetRequest,
-    ) -> Result<GetPresetResponse, RusotoError<GetPresetError>> {
-        let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
+        presigner: &rusoto_core::Presigner,
+        input: GetJobTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_template_request(&self, input: GetJobTemplateRequest) -> SignedRequest {
+        let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("GetJobTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_preset`, also returning the metadata of the response.
+    pub async fn get_preset_with_metadata(
+        &self,
+        input: GetPresetRequest,
+    ) -> Result<(GetPresetResponse, ResponseMetadata), RusotoError<GetPresetError>> {
+        let request = self.build_get_preset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetPresetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetPresetError::from_response(response))
         }
     }
 
-    /// <p>Retrieve the JSON for a specific queue.</p>
-    async fn get_queue(
+    /// Presigns the request `get_preset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_preset(
         &self,
-        input: GetQueueRequest,
-    ) -> Result<GetQueueResponse, RusotoError<GetQueueError>> {
-        let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
+        presigner: &rusoto_core::Presigner,
+        input: GetPresetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_preset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_preset_request(&self, input: GetPresetRequest) -> SignedRequest {
+        let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("GetPreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_queue`, also returning the metadata of the response.
+    pub async fn get_queue_with_metadata(
+        &self,
+        input: GetQueueRequest,
+    ) -> Result<(GetQueueResponse, ResponseMetadata), RusotoError<GetQueueError>> {
+        let request = self.build_get_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetQueueResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetQueueError::from_response(response))
         }
     }
 
-    /// <p>Retrieve a JSON array of up to twenty of your job templates. This will return the templates themselves, not just a list of them. To retrieve the next twenty templates, use the nextToken string returned with the array</p>
-    async fn list_job_templates(
+    /// Presigns the request `get_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_queue(
         &self,
-        input: ListJobTemplatesRequest,
-    ) -> Result<ListJobTemplatesResponse, RusotoError<ListJobTemplatesError>> {
-        let request_uri = "/2017-08-29/jobTemplates";
+        presigner: &rusoto_core::Presigner,
+        input: GetQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_queue_request(&self, input: GetQueueRequest) -> SignedRequest {
+        let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
+
+        let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("GetQueue");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request
+    }
+
+    /// Calls `list_job_templates`, also returning the metadata of the response.
+    pub async fn list_job_templates_with_metadata(
+        &self,
+        input: ListJobTemplatesRequest,
+    ) -> Result<(ListJobTemplatesResponse, ResponseMetadata), RusotoError<ListJobTemplatesError>>
+    {
+        let request = self.build_list_job_templates_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListJobTemplatesResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListJobTemplatesError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `list_job_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_job_templates(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListJobTemplatesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_job_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_job_templates_request(&self, input: ListJobTemplatesRequest) -> SignedRequest {
+        let request_uri = "/2017-08-29/jobTemplates";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListJobTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6679,31 +7297,55 @@ impl MediaConvert for MediaConvertClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_jobs`, also returning the metadata of the response.
+    pub async fn list_jobs_with_metadata(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<(ListJobsResponse, ResponseMetadata), RusotoError<ListJobsError>> {
+        let request = self.build_list_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListJobTemplatesResponse, _>()?;
+                .deserialize::<ListJobsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJobTemplatesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListJobsError::from_response(response))
         }
     }
 
-    /// <p>Retrieve a JSON array of up to twenty of your most recently created jobs. This array includes in-process, completed, and errored jobs. This will return the jobs themselves, not just a list of the jobs. To retrieve the twenty next most recent jobs, use the nextToken string returned with the array.</p>
-    async fn list_jobs(
+    /// Presigns the request `list_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJobsRequest,
-    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_request(&self, input: ListJobsRequest) -> SignedRequest {
         let request_uri = "/2017-08-29/jobs";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6724,31 +7366,55 @@ impl MediaConvert for MediaConvertClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_presets`, also returning the metadata of the response.
+    pub async fn list_presets_with_metadata(
+        &self,
+        input: ListPresetsRequest,
+    ) -> Result<(ListPresetsResponse, ResponseMetadata), RusotoError<ListPresetsError>> {
+        let request = self.build_list_presets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListJobsResponse, _>()?;
+                .deserialize::<ListPresetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJobsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPresetsError::from_response(response))
         }
     }
 
-    /// <p>Retrieve a JSON array of up to twenty of your presets. This will return the presets themselves, not just a list of them. To retrieve the next twenty presets, use the nextToken string returned with the array.</p>
-    async fn list_presets(
+    /// Presigns the request `list_presets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_presets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPresetsRequest,
-    ) -> Result<ListPresetsResponse, RusotoError<ListPresetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_presets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_presets_request(&self, input: ListPresetsRequest) -> SignedRequest {
         let request_uri = "/2017-08-29/presets";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListPresets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6769,31 +7435,55 @@ impl MediaConvert for MediaConvertClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_queues`, also returning the metadata of the response.
+    pub async fn list_queues_with_metadata(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<(ListQueuesResponse, ResponseMetadata), RusotoError<ListQueuesError>> {
+        let request = self.build_list_queues_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPresetsResponse, _>()?;
+                .deserialize::<ListQueuesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPresetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListQueuesError::from_response(response))
         }
     }
 
-    /// <p>Retrieve a JSON array of up to twenty of your queues. This will return the queues themselves, not just a list of them. To retrieve the next twenty queues, use the nextToken string returned with the array.</p>
-    async fn list_queues(
+    /// Presigns the request `list_queues` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_queues(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListQueuesRequest,
-    ) -> Result<ListQueuesResponse, RusotoError<ListQueuesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_queues_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_queues_request(&self, input: ListQueuesRequest) -> SignedRequest {
         let request_uri = "/2017-08-29/queues";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6811,197 +7501,766 @@ impl MediaConvert for MediaConvertClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListQueuesResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListQueuesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieve the tags for a MediaConvert resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/2017-08-29/tags/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Add tags to a MediaConvert queue, preset, or job template. For information about tagging, see the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/tagging-resources.html</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = "/2017-08-29/tags";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Remove tags from a MediaConvert queue, preset, or job template. For information about tagging, see the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/tagging-resources.html</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/2017-08-29/tags/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_job_template`, also returning the metadata of the response.
+    pub async fn update_job_template_with_metadata(
+        &self,
+        input: UpdateJobTemplateRequest,
+    ) -> Result<(UpdateJobTemplateResponse, ResponseMetadata), RusotoError<UpdateJobTemplateError>>
+    {
+        let request = self.build_update_job_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateJobTemplateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateJobTemplateError::from_response(response))
         }
     }
 
-    /// <p>Modify one of your existing job templates.</p>
-    async fn update_job_template(
+    /// Presigns the request `update_job_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_job_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateJobTemplateRequest,
-    ) -> Result<UpdateJobTemplateResponse, RusotoError<UpdateJobTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_job_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_job_template_request(&self, input: UpdateJobTemplateRequest) -> SignedRequest {
         let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("UpdateJobTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_preset`, also returning the metadata of the response.
+    pub async fn update_preset_with_metadata(
+        &self,
+        input: UpdatePresetRequest,
+    ) -> Result<(UpdatePresetResponse, ResponseMetadata), RusotoError<UpdatePresetError>> {
+        let request = self.build_update_preset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateJobTemplateResponse, _>()?;
+                .deserialize::<UpdatePresetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateJobTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdatePresetError::from_response(response))
         }
     }
 
-    /// <p>Modify one of your existing presets.</p>
-    async fn update_preset(
+    /// Presigns the request `update_preset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_preset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePresetRequest,
-    ) -> Result<UpdatePresetResponse, RusotoError<UpdatePresetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_preset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_preset_request(&self, input: UpdatePresetRequest) -> SignedRequest {
         let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("UpdatePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_queue`, also returning the metadata of the response.
+    pub async fn update_queue_with_metadata(
+        &self,
+        input: UpdateQueueRequest,
+    ) -> Result<(UpdateQueueResponse, ResponseMetadata), RusotoError<UpdateQueueError>> {
+        let request = self.build_update_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePresetResponse, _>()?;
+                .deserialize::<UpdateQueueResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePresetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateQueueError::from_response(response))
         }
     }
 
-    /// <p>Modify one of your existing queues.</p>
-    async fn update_queue(
+    /// Presigns the request `update_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_queue(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateQueueRequest,
-    ) -> Result<UpdateQueueResponse, RusotoError<UpdateQueueError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_queue_request(&self, input: UpdateQueueRequest) -> SignedRequest {
         let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("UpdateQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeEndpoints` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_endpoints_pages(
+        &self,
+        input: DescribeEndpointsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaConvertClient, input| async move { client.describe_endpoints(input).await },
+            |input: &DescribeEndpointsRequest, output: &DescribeEndpointsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEndpointsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Endpoints` of every page of `DescribeEndpoints` results for `input`, as `describe_endpoints_pages` requests them.
+    pub fn describe_endpoints_stream(
+        &self,
+        input: DescribeEndpointsRequest,
+    ) -> impl futures::Stream<Item = Result<Endpoint, RusotoError<DescribeEndpointsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_endpoints_pages(input), |page| {
+            page.endpoints.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListJobTemplates` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_job_templates_pages(
+        &self,
+        input: ListJobTemplatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListJobTemplatesResponse, RusotoError<ListJobTemplatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaConvertClient, input| async move { client.list_job_templates(input).await },
+            |input: &ListJobTemplatesRequest, output: &ListJobTemplatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListJobTemplatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `JobTemplates` of every page of `ListJobTemplates` results for `input`, as `list_job_templates_pages` requests them.
+    pub fn list_job_templates_stream(
+        &self,
+        input: ListJobTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<JobTemplate, RusotoError<ListJobTemplatesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_job_templates_pages(input), |page| {
+            page.job_templates.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResponse, RusotoError<ListJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaConvertClient, input| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Jobs` of every page of `ListJobs` results for `input`, as `list_jobs_pages` requests them.
+    pub fn list_jobs_stream(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<Job, RusotoError<ListJobsError>>> + Send {
+        rusoto_core::pagination::items(self.list_jobs_pages(input), |page| {
+            page.jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPresets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_presets_pages(
+        &self,
+        input: ListPresetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListPresetsResponse, RusotoError<ListPresetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaConvertClient, input| async move { client.list_presets(input).await },
+            |input: &ListPresetsRequest, output: &ListPresetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPresetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Presets` of every page of `ListPresets` results for `input`, as `list_presets_pages` requests them.
+    pub fn list_presets_stream(
+        &self,
+        input: ListPresetsRequest,
+    ) -> impl futures::Stream<Item = Result<Preset, RusotoError<ListPresetsError>>> + Send {
+        rusoto_core::pagination::items(self.list_presets_pages(input), |page| {
+            page.presets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListQueues` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_queues_pages(
+        &self,
+        input: ListQueuesRequest,
+    ) -> impl futures::Stream<Item = Result<ListQueuesResponse, RusotoError<ListQueuesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaConvertClient, input| async move { client.list_queues(input).await },
+            |input: &ListQueuesRequest, output: &ListQueuesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListQueuesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Queues` of every page of `ListQueues` results for `input`, as `list_queues_pages` requests them.
+    pub fn list_queues_stream(
+        &self,
+        input: ListQueuesRequest,
+    ) -> impl futures::Stream<Item = Result<Queue, RusotoError<ListQueuesError>>> + Send {
+        rusoto_core::pagination::items(self.list_queues_pages(input), |page| {
+            page.queues.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl MediaConvert for MediaConvertClient {
+    /// <p>Associates an AWS Certificate Manager (ACM) Amazon Resource Name (ARN) with AWS Elemental MediaConvert.</p>
+    async fn associate_certificate(
+        &self,
+        input: AssociateCertificateRequest,
+    ) -> Result<AssociateCertificateResponse, RusotoError<AssociateCertificateError>> {
+        self.associate_certificate_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateQueueResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateQueueError::from_response(response))
-        }
+    /// <p>Permanently cancel a job. Once you have canceled a job, you can&#39;t start it again.</p>
+    async fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.cancel_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new transcoding job. For information about jobs and job settings, see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
+    async fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.create_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new job template. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
+    async fn create_job_template(
+        &self,
+        input: CreateJobTemplateRequest,
+    ) -> Result<CreateJobTemplateResponse, RusotoError<CreateJobTemplateError>> {
+        self.create_job_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new preset. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
+    async fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> Result<CreatePresetResponse, RusotoError<CreatePresetError>> {
+        self.create_preset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new transcoding queue. For information about queues, see Working With Queues in the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/working-with-queues.html</p>
+    async fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> Result<CreateQueueResponse, RusotoError<CreateQueueError>> {
+        self.create_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently delete a job template you have created.</p>
+    async fn delete_job_template(
+        &self,
+        input: DeleteJobTemplateRequest,
+    ) -> Result<DeleteJobTemplateResponse, RusotoError<DeleteJobTemplateError>> {
+        self.delete_job_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently delete a preset you have created.</p>
+    async fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> Result<DeletePresetResponse, RusotoError<DeletePresetError>> {
+        self.delete_preset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently delete a queue you have created.</p>
+    async fn delete_queue(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> Result<DeleteQueueResponse, RusotoError<DeleteQueueError>> {
+        self.delete_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Send an request with an empty body to the regional API endpoint to get your account API endpoint.</p>
+    async fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsRequest,
+    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        self.describe_endpoints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes an association between the Amazon Resource Name (ARN) of an AWS Certificate Manager (ACM) certificate and an AWS Elemental MediaConvert resource.</p>
+    async fn disassociate_certificate(
+        &self,
+        input: DisassociateCertificateRequest,
+    ) -> Result<DisassociateCertificateResponse, RusotoError<DisassociateCertificateError>> {
+        self.disassociate_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve the JSON for a specific completed transcoding job.</p>
+    async fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+        self.get_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve the JSON for a specific job template.</p>
+    async fn get_job_template(
+        &self,
+        input: GetJobTemplateRequest,
+    ) -> Result<GetJobTemplateResponse, RusotoError<GetJobTemplateError>> {
+        self.get_job_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve the JSON for a specific preset.</p>
+    async fn get_preset(
+        &self,
+        input: GetPresetRequest,
+    ) -> Result<GetPresetResponse, RusotoError<GetPresetError>> {
+        self.get_preset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve the JSON for a specific queue.</p>
+    async fn get_queue(
+        &self,
+        input: GetQueueRequest,
+    ) -> Result<GetQueueResponse, RusotoError<GetQueueError>> {
+        self.get_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a JSON array of up to twenty of your job templates. This will return the templates themselves, not just a list of them. To retrieve the next twenty templates, use the nextToken string returned with the array</p>
+    async fn list_job_templates(
+        &self,
+        input: ListJobTemplatesRequest,
+    ) -> Result<ListJobTemplatesResponse, RusotoError<ListJobTemplatesError>> {
+        self.list_job_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a JSON array of up to twenty of your most recently created jobs. This array includes in-process, completed, and errored jobs. This will return the jobs themselves, not just a list of the jobs. To retrieve the twenty next most recent jobs, use the nextToken string returned with the array.</p>
+    async fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.list_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a JSON array of up to twenty of your presets. This will return the presets themselves, not just a list of them. To retrieve the next twenty presets, use the nextToken string returned with the array.</p>
+    async fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> Result<ListPresetsResponse, RusotoError<ListPresetsError>> {
+        self.list_presets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a JSON array of up to twenty of your queues. This will return the queues themselves, not just a list of them. To retrieve the next twenty queues, use the nextToken string returned with the array.</p>
+    async fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<ListQueuesResponse, RusotoError<ListQueuesError>> {
+        self.list_queues_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve the tags for a MediaConvert resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add tags to a MediaConvert queue, preset, or job template. For information about tagging, see the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/tagging-resources.html</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove tags from a MediaConvert queue, preset, or job template. For information about tagging, see the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/tagging-resources.html</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modify one of your existing job templates.</p>
+    async fn update_job_template(
+        &self,
+        input: UpdateJobTemplateRequest,
+    ) -> Result<UpdateJobTemplateResponse, RusotoError<UpdateJobTemplateError>> {
+        self.update_job_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modify one of your existing presets.</p>
+    async fn update_preset(
+        &self,
+        input: UpdatePresetRequest,
+    ) -> Result<UpdatePresetResponse, RusotoError<UpdatePresetError>> {
+        self.update_preset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modify one of your existing queues.</p>
+    async fn update_queue(
+        &self,
+        input: UpdateQueueRequest,
+    ) -> Result<UpdateQueueResponse, RusotoError<UpdateQueueError>> {
+        self.update_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
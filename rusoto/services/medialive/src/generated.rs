@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -5755,6 +5755,7 @@ pub struct WebvttDestinationSettings {}
 
 /// Errors returned by BatchUpdateSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchUpdateScheduleError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -5772,6 +5773,8 @@ pub enum BatchUpdateScheduleError {
     TooManyRequests(String),
     /// <p>Placeholder documentation for UnprocessableEntityException</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchUpdateScheduleError {
@@ -5779,35 +5782,49 @@ impl BatchUpdateScheduleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateScheduleError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateScheduleError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::Forbidden(err.msg))
+                    return RusotoError::service(BatchUpdateScheduleError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateScheduleError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchUpdateScheduleError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::NotFound(err.msg))
+                    return RusotoError::service(BatchUpdateScheduleError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateScheduleError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchUpdateScheduleError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchUpdateScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5825,12 +5842,14 @@ impl fmt::Display for BatchUpdateScheduleError {
             BatchUpdateScheduleError::NotFound(ref cause) => write!(f, "{}", cause),
             BatchUpdateScheduleError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             BatchUpdateScheduleError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            BatchUpdateScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchUpdateScheduleError {}
 /// Errors returned by CreateChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateChannelError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -5848,6 +5867,8 @@ pub enum CreateChannelError {
     TooManyRequests(String),
     /// <p>Placeholder documentation for UnprocessableEntityException</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateChannelError {
@@ -5855,31 +5876,37 @@ impl CreateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateChannelError::BadGateway(err.msg))
+                    return RusotoError::service(CreateChannelError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateChannelError::BadRequest(err.msg))
+                    return RusotoError::service(CreateChannelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateChannelError::Conflict(err.msg))
+                    return RusotoError::service(CreateChannelError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateChannelError::Forbidden(err.msg))
+                    return RusotoError::service(CreateChannelError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(CreateChannelError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateChannelError::TooManyRequests(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        CreateChannelError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5897,12 +5924,14 @@ impl fmt::Display for CreateChannelError {
             CreateChannelError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateChannelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateChannelError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            CreateChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateChannelError {}
 /// Errors returned by CreateInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInputError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -5916,6 +5945,8 @@ pub enum CreateInputError {
     InternalServerError(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInputError {
@@ -5923,25 +5954,28 @@ impl CreateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateInputError::BadGateway(err.msg))
+                    return RusotoError::service(CreateInputError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateInputError::BadRequest(err.msg))
+                    return RusotoError::service(CreateInputError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateInputError::Forbidden(err.msg))
+                    return RusotoError::service(CreateInputError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service(CreateInputError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateInputError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateInputError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateInputError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateInputError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5957,12 +5991,14 @@ impl fmt::Display for CreateInputError {
             CreateInputError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             CreateInputError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateInputError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInputError {}
 /// Errors returned by CreateInputSecurityGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInputSecurityGroupError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -5976,6 +6012,8 @@ pub enum CreateInputSecurityGroupError {
     InternalServerError(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInputSecurityGroupError {
@@ -5983,31 +6021,43 @@ impl CreateInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        CreateInputSecurityGroupError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateInputSecurityGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreateInputSecurityGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInputSecurityGroupError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateInputSecurityGroupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInputSecurityGroupError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateInputSecurityGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6023,12 +6073,14 @@ impl fmt::Display for CreateInputSecurityGroupError {
             CreateInputSecurityGroupError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             CreateInputSecurityGroupError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateInputSecurityGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateInputSecurityGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInputSecurityGroupError {}
 /// Errors returned by CreateMultiplex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMultiplexError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6046,6 +6098,8 @@ pub enum CreateMultiplexError {
     TooManyRequests(String),
     /// <p>Placeholder documentation for UnprocessableEntityException</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMultiplexError {
@@ -6053,31 +6107,43 @@ impl CreateMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(CreateMultiplexError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(CreateMultiplexError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(CreateMultiplexError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(CreateMultiplexError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        CreateMultiplexError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateMultiplexError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateMultiplexError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateMultiplexError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        CreateMultiplexError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMultiplexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6095,12 +6161,14 @@ impl fmt::Display for CreateMultiplexError {
             CreateMultiplexError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateMultiplexError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateMultiplexError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            CreateMultiplexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMultiplexError {}
 /// Errors returned by CreateMultiplexProgram
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMultiplexProgramError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6118,6 +6186,8 @@ pub enum CreateMultiplexProgramError {
     TooManyRequests(String),
     /// <p>Placeholder documentation for UnprocessableEntityException</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMultiplexProgramError {
@@ -6125,39 +6195,55 @@ impl CreateMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        CreateMultiplexProgramError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateMultiplexProgramError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::Conflict(err.msg))
+                    return RusotoError::service(
+                        CreateMultiplexProgramError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreateMultiplexProgramError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMultiplexProgramError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMultiplexProgramError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMultiplexProgramError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMultiplexProgramError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMultiplexProgramError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6175,12 +6261,14 @@ impl fmt::Display for CreateMultiplexProgramError {
             CreateMultiplexProgramError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateMultiplexProgramError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateMultiplexProgramError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            CreateMultiplexProgramError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMultiplexProgramError {}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTagsError {
     /// <p>Placeholder documentation for BadRequestException</p>
     BadRequest(String),
@@ -6190,6 +6278,8 @@ pub enum CreateTagsError {
     InternalServerError(String),
     /// <p>Placeholder documentation for NotFoundException</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTagsError {
@@ -6197,19 +6287,22 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateTagsError::BadRequest(err.msg))
+                    return RusotoError::service(CreateTagsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateTagsError::Forbidden(err.msg))
+                    return RusotoError::service(CreateTagsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateTagsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::NotFound(err.msg))
+                    return RusotoError::service(CreateTagsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6223,12 +6316,14 @@ impl fmt::Display for CreateTagsError {
             CreateTagsError::Forbidden(ref cause) => write!(f, "{}", cause),
             CreateTagsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateTagsError::NotFound(ref cause) => write!(f, "{}", cause),
+            CreateTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTagsError {}
 /// Errors returned by DeleteChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteChannelError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6246,6 +6341,8 @@ pub enum DeleteChannelError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteChannelError {
@@ -6253,31 +6350,34 @@ impl DeleteChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteChannelError::BadGateway(err.msg))
+                    return RusotoError::service(DeleteChannelError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteChannelError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteChannelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteChannelError::Conflict(err.msg))
+                    return RusotoError::service(DeleteChannelError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteChannelError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(DeleteChannelError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteChannelError::NotFound(err.msg))
+                    return RusotoError::service(DeleteChannelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteChannelError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6295,12 +6395,14 @@ impl fmt::Display for DeleteChannelError {
             DeleteChannelError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteChannelError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteChannelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteChannelError {}
 /// Errors returned by DeleteInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInputError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6318,6 +6420,8 @@ pub enum DeleteInputError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInputError {
@@ -6325,31 +6429,34 @@ impl DeleteInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteInputError::BadGateway(err.msg))
+                    return RusotoError::service(DeleteInputError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteInputError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteInputError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteInputError::Conflict(err.msg))
+                    return RusotoError::service(DeleteInputError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteInputError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteInputError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service(DeleteInputError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteInputError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteInputError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInputError::NotFound(err.msg))
+                    return RusotoError::service(DeleteInputError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteInputError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteInputError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6367,12 +6474,14 @@ impl fmt::Display for DeleteInputError {
             DeleteInputError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteInputError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteInputError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInputError {}
 /// Errors returned by DeleteInputSecurityGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInputSecurityGroupError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6388,6 +6497,8 @@ pub enum DeleteInputSecurityGroupError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInputSecurityGroupError {
@@ -6395,34 +6506,49 @@ impl DeleteInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        DeleteInputSecurityGroupError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteInputSecurityGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DeleteInputSecurityGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInputSecurityGroupError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteInputSecurityGroupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteInputSecurityGroupError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInputSecurityGroupError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInputSecurityGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6439,12 +6565,14 @@ impl fmt::Display for DeleteInputSecurityGroupError {
             DeleteInputSecurityGroupError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteInputSecurityGroupError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteInputSecurityGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteInputSecurityGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInputSecurityGroupError {}
 /// Errors returned by DeleteMultiplex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMultiplexError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6462,6 +6590,8 @@ pub enum DeleteMultiplexError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMultiplexError {
@@ -6469,31 +6599,40 @@ impl DeleteMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(DeleteMultiplexError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteMultiplexError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(DeleteMultiplexError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteMultiplexError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteMultiplexError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteMultiplexError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(DeleteMultiplexError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteMultiplexError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMultiplexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6511,12 +6650,14 @@ impl fmt::Display for DeleteMultiplexError {
             DeleteMultiplexError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteMultiplexError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteMultiplexError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteMultiplexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMultiplexError {}
 /// Errors returned by DeleteMultiplexProgram
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMultiplexProgramError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6534,6 +6675,8 @@ pub enum DeleteMultiplexProgramError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMultiplexProgramError {
@@ -6541,37 +6684,55 @@ impl DeleteMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        DeleteMultiplexProgramError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteMultiplexProgramError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteMultiplexProgramError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DeleteMultiplexProgramError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMultiplexProgramError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMultiplexProgramError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteMultiplexProgramError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMultiplexProgramError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMultiplexProgramError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6589,12 +6750,14 @@ impl fmt::Display for DeleteMultiplexProgramError {
             DeleteMultiplexProgramError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteMultiplexProgramError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteMultiplexProgramError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteMultiplexProgramError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMultiplexProgramError {}
 /// Errors returned by DeleteReservation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReservationError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6612,6 +6775,8 @@ pub enum DeleteReservationError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReservationError {
@@ -6619,33 +6784,40 @@ impl DeleteReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteReservationError::BadGateway(err.msg))
+                    return RusotoError::service(DeleteReservationError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteReservationError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteReservationError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteReservationError::Conflict(err.msg))
+                    return RusotoError::service(DeleteReservationError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteReservationError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteReservationError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteReservationError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteReservationError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteReservationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteReservationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteReservationError::NotFound(err.msg))
+                    return RusotoError::service(DeleteReservationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteReservationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteReservationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6663,12 +6835,14 @@ impl fmt::Display for DeleteReservationError {
             DeleteReservationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteReservationError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteReservationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteReservationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReservationError {}
 /// Errors returned by DeleteSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteScheduleError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6684,6 +6858,8 @@ pub enum DeleteScheduleError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteScheduleError {
@@ -6691,28 +6867,34 @@ impl DeleteScheduleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteScheduleError::BadGateway(err.msg))
+                    return RusotoError::service(DeleteScheduleError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteScheduleError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteScheduleError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteScheduleError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteScheduleError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteScheduleError::GatewayTimeout(err.msg))
+                    return RusotoError::service(DeleteScheduleError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteScheduleError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteScheduleError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteScheduleError::NotFound(err.msg))
+                    return RusotoError::service(DeleteScheduleError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteScheduleError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteScheduleError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6729,12 +6911,14 @@ impl fmt::Display for DeleteScheduleError {
             DeleteScheduleError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteScheduleError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteScheduleError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteScheduleError {}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTagsError {
     /// <p>Placeholder documentation for BadRequestException</p>
     BadRequest(String),
@@ -6744,6 +6928,8 @@ pub enum DeleteTagsError {
     InternalServerError(String),
     /// <p>Placeholder documentation for NotFoundException</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTagsError {
@@ -6751,19 +6937,22 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTagsError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteTagsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteTagsError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteTagsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteTagsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::NotFound(err.msg))
+                    return RusotoError::service(DeleteTagsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6777,12 +6966,14 @@ impl fmt::Display for DeleteTagsError {
             DeleteTagsError::Forbidden(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTagsError {}
 /// Errors returned by DescribeChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeChannelError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6798,6 +6989,8 @@ pub enum DescribeChannelError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeChannelError {
@@ -6805,28 +6998,37 @@ impl DescribeChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeChannelError::BadGateway(err.msg))
+                    return RusotoError::service(DescribeChannelError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeChannelError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeChannelError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeChannelError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeChannelError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeChannelError::NotFound(err.msg))
+                    return RusotoError::service(DescribeChannelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6843,12 +7045,14 @@ impl fmt::Display for DescribeChannelError {
             DescribeChannelError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeChannelError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeChannelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeChannelError {}
 /// Errors returned by DescribeInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInputError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6864,6 +7068,8 @@ pub enum DescribeInputError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInputError {
@@ -6871,28 +7077,31 @@ impl DescribeInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeInputError::BadGateway(err.msg))
+                    return RusotoError::service(DescribeInputError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeInputError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeInputError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeInputError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeInputError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service(DescribeInputError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeInputError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeInputError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInputError::NotFound(err.msg))
+                    return RusotoError::service(DescribeInputError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeInputError::TooManyRequests(err.msg))
+                    return RusotoError::service(DescribeInputError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6909,12 +7118,14 @@ impl fmt::Display for DescribeInputError {
             DescribeInputError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeInputError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeInputError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInputError {}
 /// Errors returned by DescribeInputSecurityGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInputSecurityGroupError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -6930,6 +7141,8 @@ pub enum DescribeInputSecurityGroupError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInputSecurityGroupError {
@@ -6939,40 +7152,51 @@ impl DescribeInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::BadGateway(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInputSecurityGroupError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInputSecurityGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInputSecurityGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInputSecurityGroupError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInputSecurityGroupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeInputSecurityGroupError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInputSecurityGroupError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeInputSecurityGroupError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6991,12 +7215,14 @@ impl fmt::Display for DescribeInputSecurityGroupError {
             }
             DescribeInputSecurityGroupError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeInputSecurityGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeInputSecurityGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInputSecurityGroupError {}
 /// Errors returned by DescribeMultiplex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMultiplexError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7012,6 +7238,8 @@ pub enum DescribeMultiplexError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMultiplexError {
@@ -7019,30 +7247,37 @@ impl DescribeMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(DescribeMultiplexError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeMultiplexError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeMultiplexError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        DescribeMultiplexError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeMultiplexError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMultiplexError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(DescribeMultiplexError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeMultiplexError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMultiplexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7059,12 +7294,14 @@ impl fmt::Display for DescribeMultiplexError {
             DescribeMultiplexError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeMultiplexError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeMultiplexError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeMultiplexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMultiplexError {}
 /// Errors returned by DescribeMultiplexProgram
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMultiplexProgramError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7080,6 +7317,8 @@ pub enum DescribeMultiplexProgramError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMultiplexProgramError {
@@ -7087,34 +7326,49 @@ impl DescribeMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        DescribeMultiplexProgramError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeMultiplexProgramError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeMultiplexProgramError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMultiplexProgramError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMultiplexProgramError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeMultiplexProgramError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMultiplexProgramError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMultiplexProgramError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7131,12 +7385,14 @@ impl fmt::Display for DescribeMultiplexProgramError {
             DescribeMultiplexProgramError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeMultiplexProgramError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeMultiplexProgramError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeMultiplexProgramError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMultiplexProgramError {}
 /// Errors returned by DescribeOffering
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeOfferingError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7152,6 +7408,8 @@ pub enum DescribeOfferingError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeOfferingError {
@@ -7159,30 +7417,37 @@ impl DescribeOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeOfferingError::BadGateway(err.msg))
+                    return RusotoError::service(DescribeOfferingError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeOfferingError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeOfferingError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeOfferingError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeOfferingError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeOfferingError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        DescribeOfferingError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeOfferingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOfferingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeOfferingError::NotFound(err.msg))
+                    return RusotoError::service(DescribeOfferingError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOfferingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeOfferingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeOfferingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7199,12 +7464,14 @@ impl fmt::Display for DescribeOfferingError {
             DescribeOfferingError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeOfferingError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeOfferingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeOfferingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeOfferingError {}
 /// Errors returned by DescribeReservation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReservationError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7220,6 +7487,8 @@ pub enum DescribeReservationError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReservationError {
@@ -7227,30 +7496,43 @@ impl DescribeReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeReservationError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        DescribeReservationError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeReservationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeReservationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeReservationError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeReservationError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeReservationError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        DescribeReservationError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeReservationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeReservationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeReservationError::NotFound(err.msg))
+                    return RusotoError::service(DescribeReservationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeReservationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeReservationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7267,12 +7549,14 @@ impl fmt::Display for DescribeReservationError {
             DescribeReservationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeReservationError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeReservationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeReservationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReservationError {}
 /// Errors returned by DescribeSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScheduleError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7288,6 +7572,8 @@ pub enum DescribeScheduleError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScheduleError {
@@ -7295,30 +7581,37 @@ impl DescribeScheduleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeScheduleError::BadGateway(err.msg))
+                    return RusotoError::service(DescribeScheduleError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeScheduleError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeScheduleError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeScheduleError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeScheduleError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeScheduleError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        DescribeScheduleError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeScheduleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScheduleError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeScheduleError::NotFound(err.msg))
+                    return RusotoError::service(DescribeScheduleError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeScheduleError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeScheduleError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7335,12 +7628,14 @@ impl fmt::Display for DescribeScheduleError {
             DescribeScheduleError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeScheduleError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeScheduleError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScheduleError {}
 /// Errors returned by ListChannels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListChannelsError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7354,6 +7649,8 @@ pub enum ListChannelsError {
     InternalServerError(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListChannelsError {
@@ -7361,25 +7658,28 @@ impl ListChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListChannelsError::BadGateway(err.msg))
+                    return RusotoError::service(ListChannelsError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListChannelsError::BadRequest(err.msg))
+                    return RusotoError::service(ListChannelsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelsError::Forbidden(err.msg))
+                    return RusotoError::service(ListChannelsError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListChannelsError::GatewayTimeout(err.msg))
+                    return RusotoError::service(ListChannelsError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListChannelsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListChannelsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListChannelsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListChannelsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListChannelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7395,12 +7695,14 @@ impl fmt::Display for ListChannelsError {
             ListChannelsError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             ListChannelsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListChannelsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListChannelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListChannelsError {}
 /// Errors returned by ListInputSecurityGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInputSecurityGroupsError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7414,6 +7716,8 @@ pub enum ListInputSecurityGroupsError {
     InternalServerError(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInputSecurityGroupsError {
@@ -7421,31 +7725,43 @@ impl ListInputSecurityGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        ListInputSecurityGroupsError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListInputSecurityGroupsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        ListInputSecurityGroupsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInputSecurityGroupsError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInputSecurityGroupsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInputSecurityGroupsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInputSecurityGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7461,12 +7777,14 @@ impl fmt::Display for ListInputSecurityGroupsError {
             ListInputSecurityGroupsError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             ListInputSecurityGroupsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListInputSecurityGroupsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListInputSecurityGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInputSecurityGroupsError {}
 /// Errors returned by ListInputs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInputsError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7480,6 +7798,8 @@ pub enum ListInputsError {
     InternalServerError(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInputsError {
@@ -7487,25 +7807,28 @@ impl ListInputsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListInputsError::BadGateway(err.msg))
+                    return RusotoError::service(ListInputsError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInputsError::BadRequest(err.msg))
+                    return RusotoError::service(ListInputsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInputsError::Forbidden(err.msg))
+                    return RusotoError::service(ListInputsError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListInputsError::GatewayTimeout(err.msg))
+                    return RusotoError::service(ListInputsError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInputsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListInputsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListInputsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListInputsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInputsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7521,12 +7844,14 @@ impl fmt::Display for ListInputsError {
             ListInputsError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             ListInputsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListInputsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListInputsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInputsError {}
 /// Errors returned by ListMultiplexPrograms
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMultiplexProgramsError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7542,6 +7867,8 @@ pub enum ListMultiplexProgramsError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMultiplexProgramsError {
@@ -7549,34 +7876,49 @@ impl ListMultiplexProgramsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        ListMultiplexProgramsError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListMultiplexProgramsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        ListMultiplexProgramsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMultiplexProgramsError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMultiplexProgramsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListMultiplexProgramsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMultiplexProgramsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMultiplexProgramsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7593,12 +7935,14 @@ impl fmt::Display for ListMultiplexProgramsError {
             ListMultiplexProgramsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListMultiplexProgramsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListMultiplexProgramsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListMultiplexProgramsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMultiplexProgramsError {}
 /// Errors returned by ListMultiplexes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMultiplexesError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7612,6 +7956,8 @@ pub enum ListMultiplexesError {
     InternalServerError(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMultiplexesError {
@@ -7619,25 +7965,34 @@ impl ListMultiplexesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListMultiplexesError::BadGateway(err.msg))
+                    return RusotoError::service(ListMultiplexesError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMultiplexesError::BadRequest(err.msg))
+                    return RusotoError::service(ListMultiplexesError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMultiplexesError::Forbidden(err.msg))
+                    return RusotoError::service(ListMultiplexesError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListMultiplexesError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        ListMultiplexesError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMultiplexesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListMultiplexesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListMultiplexesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListMultiplexesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMultiplexesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7653,12 +8008,14 @@ impl fmt::Display for ListMultiplexesError {
             ListMultiplexesError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             ListMultiplexesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListMultiplexesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListMultiplexesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMultiplexesError {}
 /// Errors returned by ListOfferings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOfferingsError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7672,6 +8029,8 @@ pub enum ListOfferingsError {
     InternalServerError(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOfferingsError {
@@ -7679,25 +8038,28 @@ impl ListOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListOfferingsError::BadGateway(err.msg))
+                    return RusotoError::service(ListOfferingsError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListOfferingsError::BadRequest(err.msg))
+                    return RusotoError::service(ListOfferingsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListOfferingsError::Forbidden(err.msg))
+                    return RusotoError::service(ListOfferingsError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListOfferingsError::GatewayTimeout(err.msg))
+                    return RusotoError::service(ListOfferingsError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListOfferingsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListOfferingsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListOfferingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListOfferingsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOfferingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7713,12 +8075,14 @@ impl fmt::Display for ListOfferingsError {
             ListOfferingsError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             ListOfferingsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListOfferingsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListOfferingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOfferingsError {}
 /// Errors returned by ListReservations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListReservationsError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7732,6 +8096,8 @@ pub enum ListReservationsError {
     InternalServerError(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListReservationsError {
@@ -7739,27 +8105,34 @@ impl ListReservationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListReservationsError::BadGateway(err.msg))
+                    return RusotoError::service(ListReservationsError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListReservationsError::BadRequest(err.msg))
+                    return RusotoError::service(ListReservationsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListReservationsError::Forbidden(err.msg))
+                    return RusotoError::service(ListReservationsError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListReservationsError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        ListReservationsError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListReservationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListReservationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListReservationsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListReservationsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListReservationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7775,12 +8148,14 @@ impl fmt::Display for ListReservationsError {
             ListReservationsError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             ListReservationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListReservationsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListReservationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListReservationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Placeholder documentation for BadRequestException</p>
     BadRequest(String),
@@ -7790,6 +8165,8 @@ pub enum ListTagsForResourceError {
     InternalServerError(String),
     /// <p>Placeholder documentation for NotFoundException</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -7797,21 +8174,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7825,12 +8206,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PurchaseOffering
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PurchaseOfferingError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7848,6 +8231,8 @@ pub enum PurchaseOfferingError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PurchaseOfferingError {
@@ -7855,33 +8240,40 @@ impl PurchaseOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PurchaseOfferingError::BadGateway(err.msg))
+                    return RusotoError::service(PurchaseOfferingError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PurchaseOfferingError::BadRequest(err.msg))
+                    return RusotoError::service(PurchaseOfferingError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Conflict(err.msg))
+                    return RusotoError::service(PurchaseOfferingError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Forbidden(err.msg))
+                    return RusotoError::service(PurchaseOfferingError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(PurchaseOfferingError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        PurchaseOfferingError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(PurchaseOfferingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PurchaseOfferingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotFound(err.msg))
+                    return RusotoError::service(PurchaseOfferingError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PurchaseOfferingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        PurchaseOfferingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PurchaseOfferingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7899,12 +8291,14 @@ impl fmt::Display for PurchaseOfferingError {
             PurchaseOfferingError::InternalServerError(ref cause) => write!(f, "{}", cause),
             PurchaseOfferingError::NotFound(ref cause) => write!(f, "{}", cause),
             PurchaseOfferingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            PurchaseOfferingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PurchaseOfferingError {}
 /// Errors returned by StartChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartChannelError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7922,6 +8316,8 @@ pub enum StartChannelError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartChannelError {
@@ -7929,31 +8325,34 @@ impl StartChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StartChannelError::BadGateway(err.msg))
+                    return RusotoError::service(StartChannelError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StartChannelError::BadRequest(err.msg))
+                    return RusotoError::service(StartChannelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartChannelError::Conflict(err.msg))
+                    return RusotoError::service(StartChannelError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartChannelError::Forbidden(err.msg))
+                    return RusotoError::service(StartChannelError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StartChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(StartChannelError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        StartChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartChannelError::NotFound(err.msg))
+                    return RusotoError::service(StartChannelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(StartChannelError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7971,12 +8370,14 @@ impl fmt::Display for StartChannelError {
             StartChannelError::InternalServerError(ref cause) => write!(f, "{}", cause),
             StartChannelError::NotFound(ref cause) => write!(f, "{}", cause),
             StartChannelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartChannelError {}
 /// Errors returned by StartMultiplex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartMultiplexError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -7994,6 +8395,8 @@ pub enum StartMultiplexError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartMultiplexError {
@@ -8001,31 +8404,37 @@ impl StartMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StartMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(StartMultiplexError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StartMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(StartMultiplexError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(StartMultiplexError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(StartMultiplexError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StartMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(StartMultiplexError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        StartMultiplexError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(StartMultiplexError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        StartMultiplexError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartMultiplexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8043,12 +8452,14 @@ impl fmt::Display for StartMultiplexError {
             StartMultiplexError::InternalServerError(ref cause) => write!(f, "{}", cause),
             StartMultiplexError::NotFound(ref cause) => write!(f, "{}", cause),
             StartMultiplexError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartMultiplexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartMultiplexError {}
 /// Errors returned by StopChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopChannelError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -8066,6 +8477,8 @@ pub enum StopChannelError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopChannelError {
@@ -8073,31 +8486,34 @@ impl StopChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StopChannelError::BadGateway(err.msg))
+                    return RusotoError::service(StopChannelError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StopChannelError::BadRequest(err.msg))
+                    return RusotoError::service(StopChannelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StopChannelError::Conflict(err.msg))
+                    return RusotoError::service(StopChannelError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopChannelError::Forbidden(err.msg))
+                    return RusotoError::service(StopChannelError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StopChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(StopChannelError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        StopChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopChannelError::NotFound(err.msg))
+                    return RusotoError::service(StopChannelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(StopChannelError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8115,12 +8531,14 @@ impl fmt::Display for StopChannelError {
             StopChannelError::InternalServerError(ref cause) => write!(f, "{}", cause),
             StopChannelError::NotFound(ref cause) => write!(f, "{}", cause),
             StopChannelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StopChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopChannelError {}
 /// Errors returned by StopMultiplex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopMultiplexError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -8138,6 +8556,8 @@ pub enum StopMultiplexError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopMultiplexError {
@@ -8145,31 +8565,34 @@ impl StopMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StopMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(StopMultiplexError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StopMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(StopMultiplexError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StopMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(StopMultiplexError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(StopMultiplexError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StopMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(StopMultiplexError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        StopMultiplexError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(StopMultiplexError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(StopMultiplexError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopMultiplexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8187,12 +8610,14 @@ impl fmt::Display for StopMultiplexError {
             StopMultiplexError::InternalServerError(ref cause) => write!(f, "{}", cause),
             StopMultiplexError::NotFound(ref cause) => write!(f, "{}", cause),
             StopMultiplexError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StopMultiplexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopMultiplexError {}
 /// Errors returned by UpdateChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateChannelError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -8208,6 +8633,8 @@ pub enum UpdateChannelError {
     InternalServerError(String),
     /// <p>Placeholder documentation for UnprocessableEntityException</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateChannelError {
@@ -8215,28 +8642,34 @@ impl UpdateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateChannelError::BadGateway(err.msg))
+                    return RusotoError::service(UpdateChannelError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateChannelError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateChannelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateChannelError::Conflict(err.msg))
+                    return RusotoError::service(UpdateChannelError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateChannelError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(UpdateChannelError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        UpdateChannelError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8253,12 +8686,14 @@ impl fmt::Display for UpdateChannelError {
             UpdateChannelError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             UpdateChannelError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateChannelError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            UpdateChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateChannelError {}
 /// Errors returned by UpdateChannelClass
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateChannelClassError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -8278,6 +8713,8 @@ pub enum UpdateChannelClassError {
     TooManyRequests(String),
     /// <p>Placeholder documentation for UnprocessableEntityException</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateChannelClassError {
@@ -8285,38 +8722,46 @@ impl UpdateChannelClassError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateChannelClassError::BadGateway(err.msg))
+                    return RusotoError::service(UpdateChannelClassError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateChannelClassError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateChannelClassError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateChannelClassError::Conflict(err.msg))
+                    return RusotoError::service(UpdateChannelClassError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelClassError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateChannelClassError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateChannelClassError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateChannelClassError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateChannelClassError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateChannelClassError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateChannelClassError::NotFound(err.msg))
+                    return RusotoError::service(UpdateChannelClassError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateChannelClassError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateChannelClassError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateChannelClassError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateChannelClassError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateChannelClassError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8335,12 +8780,14 @@ impl fmt::Display for UpdateChannelClassError {
             UpdateChannelClassError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateChannelClassError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateChannelClassError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            UpdateChannelClassError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateChannelClassError {}
 /// Errors returned by UpdateInput
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateInputError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -8356,6 +8803,8 @@ pub enum UpdateInputError {
     InternalServerError(String),
     /// <p>Placeholder documentation for NotFoundException</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateInputError {
@@ -8363,28 +8812,31 @@ impl UpdateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateInputError::BadGateway(err.msg))
+                    return RusotoError::service(UpdateInputError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateInputError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateInputError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateInputError::Conflict(err.msg))
+                    return RusotoError::service(UpdateInputError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInputError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateInputError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service(UpdateInputError::GatewayTimeout(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateInputError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateInputError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInputError::NotFound(err.msg))
+                    return RusotoError::service(UpdateInputError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateInputError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8401,12 +8853,14 @@ impl fmt::Display for UpdateInputError {
             UpdateInputError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             UpdateInputError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateInputError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateInputError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateInputError {}
 /// Errors returned by UpdateInputSecurityGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateInputSecurityGroupError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -8422,6 +8876,8 @@ pub enum UpdateInputSecurityGroupError {
     InternalServerError(String),
     /// <p>Placeholder documentation for NotFoundException</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateInputSecurityGroupError {
@@ -8429,32 +8885,49 @@ impl UpdateInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        UpdateInputSecurityGroupError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateInputSecurityGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateInputSecurityGroupError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateInputSecurityGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateInputSecurityGroupError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInputSecurityGroupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateInputSecurityGroupError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateInputSecurityGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8471,12 +8944,14 @@ impl fmt::Display for UpdateInputSecurityGroupError {
             UpdateInputSecurityGroupError::GatewayTimeout(ref cause) => write!(f, "{}", cause),
             UpdateInputSecurityGroupError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateInputSecurityGroupError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateInputSecurityGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateInputSecurityGroupError {}
 /// Errors returned by UpdateMultiplex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMultiplexError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -8494,6 +8969,8 @@ pub enum UpdateMultiplexError {
     NotFound(String),
     /// <p>Placeholder documentation for UnprocessableEntityException</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMultiplexError {
@@ -8501,31 +8978,40 @@ impl UpdateMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(UpdateMultiplexError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateMultiplexError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(UpdateMultiplexError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateMultiplexError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateMultiplexError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateMultiplexError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(UpdateMultiplexError::NotFound(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateMultiplexError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        UpdateMultiplexError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMultiplexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8543,12 +9029,14 @@ impl fmt::Display for UpdateMultiplexError {
             UpdateMultiplexError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateMultiplexError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateMultiplexError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            UpdateMultiplexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMultiplexError {}
 /// Errors returned by UpdateMultiplexProgram
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMultiplexProgramError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -8566,6 +9054,8 @@ pub enum UpdateMultiplexProgramError {
     NotFound(String),
     /// <p>Placeholder documentation for UnprocessableEntityException</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMultiplexProgramError {
@@ -8573,37 +9063,55 @@ impl UpdateMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        UpdateMultiplexProgramError::BadGateway(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateMultiplexProgramError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateMultiplexProgramError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateMultiplexProgramError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMultiplexProgramError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMultiplexProgramError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateMultiplexProgramError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMultiplexProgramError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMultiplexProgramError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8621,12 +9129,14 @@ impl fmt::Display for UpdateMultiplexProgramError {
             UpdateMultiplexProgramError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateMultiplexProgramError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateMultiplexProgramError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            UpdateMultiplexProgramError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMultiplexProgramError {}
 /// Errors returned by UpdateReservation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateReservationError {
     /// <p>Placeholder documentation for BadGatewayException</p>
     BadGateway(String),
@@ -8644,6 +9154,8 @@ pub enum UpdateReservationError {
     NotFound(String),
     /// <p>Placeholder documentation for TooManyRequestsException</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateReservationError {
@@ -8651,33 +9163,40 @@ impl UpdateReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateReservationError::BadGateway(err.msg))
+                    return RusotoError::service(UpdateReservationError::BadGateway(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateReservationError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateReservationError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateReservationError::Conflict(err.msg))
+                    return RusotoError::service(UpdateReservationError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateReservationError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateReservationError::Forbidden(err.msg), &res)
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateReservationError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        UpdateReservationError::GatewayTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateReservationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateReservationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateReservationError::NotFound(err.msg))
+                    return RusotoError::service(UpdateReservationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateReservationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateReservationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8695,6 +9214,7 @@ impl fmt::Display for UpdateReservationError {
             UpdateReservationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateReservationError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateReservationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateReservationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -8971,6 +9491,7 @@ impl MediaLiveClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MediaLiveClient {
         MediaLiveClient {
             client: Client::shared(),
@@ -8996,347 +9517,694 @@ impl MediaLiveClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MediaLiveClient {
         MediaLiveClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MediaLiveClient> {
+        rusoto_core::ClientBuilder::new(MediaLiveClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MediaLiveClient {
+        MediaLiveClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MediaLive for MediaLiveClient {
-    /// <p>Update a channel schedule</p>
-    async fn batch_update_schedule(
+impl MediaLiveClient {
+    /// Calls `batch_update_schedule`, also returning the metadata of the response.
+    pub async fn batch_update_schedule_with_metadata(
         &self,
         input: BatchUpdateScheduleRequest,
-    ) -> Result<BatchUpdateScheduleResponse, RusotoError<BatchUpdateScheduleError>> {
-        let request_uri = format!(
-            "/prod/channels/{channel_id}/schedule",
-            channel_id = input.channel_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (BatchUpdateScheduleResponse, ResponseMetadata),
+        RusotoError<BatchUpdateScheduleError>,
+    > {
+        let request = self.build_batch_update_schedule_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchUpdateScheduleResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(BatchUpdateScheduleError::from_response(response))
         }
     }
 
-    /// <p>Creates a new channel</p>
-    async fn create_channel(
+    /// Presigns the request `batch_update_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_update_schedule(
         &self,
-        input: CreateChannelRequest,
-    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
-        let request_uri = "/prod/channels";
+        presigner: &rusoto_core::Presigner,
+        input: BatchUpdateScheduleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_update_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+    fn build_batch_update_schedule_request(
+        &self,
+        input: BatchUpdateScheduleRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/prod/channels/{channel_id}/schedule",
+            channel_id = input.channel_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("BatchUpdateSchedule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_channel`, also returning the metadata of the response.
+    pub async fn create_channel_with_metadata(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<(CreateChannelResponse, ResponseMetadata), RusotoError<CreateChannelError>> {
+        let request = self.build_create_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateChannelError::from_response(response))
         }
     }
 
-    /// <p>Create an input</p>
-    async fn create_input(
+    /// Presigns the request `create_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_channel(
         &self,
-        input: CreateInputRequest,
-    ) -> Result<CreateInputResponse, RusotoError<CreateInputError>> {
-        let request_uri = "/prod/inputs";
+        presigner: &rusoto_core::Presigner,
+        input: CreateChannelRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_channel_request(&self, input: CreateChannelRequest) -> SignedRequest {
+        let request_uri = "/prod/channels";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_input`, also returning the metadata of the response.
+    pub async fn create_input_with_metadata(
+        &self,
+        input: CreateInputRequest,
+    ) -> Result<(CreateInputResponse, ResponseMetadata), RusotoError<CreateInputError>> {
+        let request = self.build_create_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateInputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateInputError::from_response(response))
         }
     }
 
-    /// <p>Creates a Input Security Group</p>
-    async fn create_input_security_group(
+    /// Presigns the request `create_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_input(
         &self,
-        input: CreateInputSecurityGroupRequest,
-    ) -> Result<CreateInputSecurityGroupResponse, RusotoError<CreateInputSecurityGroupError>> {
-        let request_uri = "/prod/inputSecurityGroups";
+        presigner: &rusoto_core::Presigner,
+        input: CreateInputRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_input_request(&self, input: CreateInputRequest) -> SignedRequest {
+        let request_uri = "/prod/inputs";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_input_security_group`, also returning the metadata of the response.
+    pub async fn create_input_security_group_with_metadata(
+        &self,
+        input: CreateInputSecurityGroupRequest,
+    ) -> Result<
+        (CreateInputSecurityGroupResponse, ResponseMetadata),
+        RusotoError<CreateInputSecurityGroupError>,
+    > {
+        let request = self.build_create_input_security_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateInputSecurityGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateInputSecurityGroupError::from_response(response))
         }
     }
 
-    /// <p>Create a new multiplex.</p>
-    async fn create_multiplex(
+    /// Presigns the request `create_input_security_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_input_security_group(
         &self,
-        input: CreateMultiplexRequest,
-    ) -> Result<CreateMultiplexResponse, RusotoError<CreateMultiplexError>> {
-        let request_uri = "/prod/multiplexes";
+        presigner: &rusoto_core::Presigner,
+        input: CreateInputSecurityGroupRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_input_security_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_input_security_group_request(
+        &self,
+        input: CreateInputSecurityGroupRequest,
+    ) -> SignedRequest {
+        let request_uri = "/prod/inputSecurityGroups";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_multiplex`, also returning the metadata of the response.
+    pub async fn create_multiplex_with_metadata(
+        &self,
+        input: CreateMultiplexRequest,
+    ) -> Result<(CreateMultiplexResponse, ResponseMetadata), RusotoError<CreateMultiplexError>>
+    {
+        let request = self.build_create_multiplex_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMultiplexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateMultiplexError::from_response(response))
         }
     }
 
-    /// <p>Create a new program in the multiplex.</p>
-    async fn create_multiplex_program(
+    /// Presigns the request `create_multiplex` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_multiplex(
         &self,
-        input: CreateMultiplexProgramRequest,
-    ) -> Result<CreateMultiplexProgramResponse, RusotoError<CreateMultiplexProgramError>> {
-        let request_uri = format!(
-            "/prod/multiplexes/{multiplex_id}/programs",
-            multiplex_id = input.multiplex_id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: CreateMultiplexRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_multiplex_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_multiplex_request(&self, input: CreateMultiplexRequest) -> SignedRequest {
+        let request_uri = "/prod/multiplexes";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_multiplex_program`, also returning the metadata of the response.
+    pub async fn create_multiplex_program_with_metadata(
+        &self,
+        input: CreateMultiplexProgramRequest,
+    ) -> Result<
+        (CreateMultiplexProgramResponse, ResponseMetadata),
+        RusotoError<CreateMultiplexProgramError>,
+    > {
+        let request = self.build_create_multiplex_program_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMultiplexProgramResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateMultiplexProgramError::from_response(response))
         }
     }
 
-    /// <p>Create tags for a resource</p>
-    async fn create_tags(
+    /// Presigns the request `create_multiplex_program` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_multiplex_program(
         &self,
-        input: CreateTagsRequest,
-    ) -> Result<(), RusotoError<CreateTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateMultiplexProgramRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_multiplex_program_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_multiplex_program_request(
+        &self,
+        input: CreateMultiplexProgramRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/prod/tags/{resource_arn}",
-            resource_arn = input.resource_arn
+            "/prod/multiplexes/{multiplex_id}/programs",
+            multiplex_id = input.multiplex_id
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_tags`, also returning the metadata of the response.
+    pub async fn create_tags_with_metadata(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateTagsError>> {
+        let request = self.build_create_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateTagsError::from_response(response))
         }
     }
 
-    /// <p>Starts deletion of channel. The associated outputs are also deleted.</p>
-    async fn delete_channel(
+    /// Presigns the request `create_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_tags(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateTagsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_tags_request(&self, input: CreateTagsRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/prod/tags/{resource_arn}",
+            resource_arn = input.resource_arn
+        );
+
+        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateTags");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `delete_channel`, also returning the metadata of the response.
+    pub async fn delete_channel_with_metadata(
         &self,
         input: DeleteChannelRequest,
-    ) -> Result<DeleteChannelResponse, RusotoError<DeleteChannelError>> {
+    ) -> Result<(DeleteChannelResponse, ResponseMetadata), RusotoError<DeleteChannelError>> {
+        let request = self.build_delete_channel_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteChannelResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteChannelError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_channel(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteChannelRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_channel_request(&self, input: DeleteChannelRequest) -> SignedRequest {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_input`, also returning the metadata of the response.
+    pub async fn delete_input_with_metadata(
+        &self,
+        input: DeleteInputRequest,
+    ) -> Result<(DeleteInputResponse, ResponseMetadata), RusotoError<DeleteInputError>> {
+        let request = self.build_delete_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteChannelResponse, _>()?;
+                .deserialize::<DeleteInputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteInputError::from_response(response))
         }
     }
 
-    /// <p>Deletes the input end point</p>
-    async fn delete_input(
+    /// Presigns the request `delete_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_input(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInputRequest,
-    ) -> Result<DeleteInputResponse, RusotoError<DeleteInputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_input_request(&self, input: DeleteInputRequest) -> SignedRequest {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_input_security_group`, also returning the metadata of the response.
+    pub async fn delete_input_security_group_with_metadata(
+        &self,
+        input: DeleteInputSecurityGroupRequest,
+    ) -> Result<
+        (DeleteInputSecurityGroupResponse, ResponseMetadata),
+        RusotoError<DeleteInputSecurityGroupError>,
+    > {
+        let request = self.build_delete_input_security_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInputResponse, _>()?;
+                .deserialize::<DeleteInputSecurityGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInputError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteInputSecurityGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes an Input Security Group</p>
-    async fn delete_input_security_group(
+    /// Presigns the request `delete_input_security_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_input_security_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInputSecurityGroupRequest,
-    ) -> Result<DeleteInputSecurityGroupResponse, RusotoError<DeleteInputSecurityGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_input_security_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_input_security_group_request(
+        &self,
+        input: DeleteInputSecurityGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/inputSecurityGroups/{input_security_group_id}",
             input_security_group_id = input.input_security_group_id
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_multiplex`, also returning the metadata of the response.
+    pub async fn delete_multiplex_with_metadata(
+        &self,
+        input: DeleteMultiplexRequest,
+    ) -> Result<(DeleteMultiplexResponse, ResponseMetadata), RusotoError<DeleteMultiplexError>>
+    {
+        let request = self.build_delete_multiplex_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInputSecurityGroupResponse, _>()?;
+                .deserialize::<DeleteMultiplexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInputSecurityGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMultiplexError::from_response(response))
         }
     }
 
-    /// <p>Delete a multiplex. The multiplex must be idle.</p>
-    async fn delete_multiplex(
+    /// Presigns the request `delete_multiplex` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_multiplex(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMultiplexRequest,
-    ) -> Result<DeleteMultiplexResponse, RusotoError<DeleteMultiplexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_multiplex_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_multiplex_request(&self, input: DeleteMultiplexRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}",
             multiplex_id = input.multiplex_id
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_multiplex_program`, also returning the metadata of the response.
+    pub async fn delete_multiplex_program_with_metadata(
+        &self,
+        input: DeleteMultiplexProgramRequest,
+    ) -> Result<
+        (DeleteMultiplexProgramResponse, ResponseMetadata),
+        RusotoError<DeleteMultiplexProgramError>,
+    > {
+        let request = self.build_delete_multiplex_program_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMultiplexResponse, _>()?;
+                .deserialize::<DeleteMultiplexProgramResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMultiplexError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMultiplexProgramError::from_response(response))
         }
     }
 
-    /// <p>Delete a program from a multiplex.</p>
-    async fn delete_multiplex_program(
+    /// Presigns the request `delete_multiplex_program` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_multiplex_program(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMultiplexProgramRequest,
-    ) -> Result<DeleteMultiplexProgramResponse, RusotoError<DeleteMultiplexProgramError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_multiplex_program_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_multiplex_program_request(
+        &self,
+        input: DeleteMultiplexProgramRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}/programs/{program_name}",
             multiplex_id = input.multiplex_id,
@@ -9344,96 +10212,169 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_reservation`, also returning the metadata of the response.
+    pub async fn delete_reservation_with_metadata(
+        &self,
+        input: DeleteReservationRequest,
+    ) -> Result<(DeleteReservationResponse, ResponseMetadata), RusotoError<DeleteReservationError>>
+    {
+        let request = self.build_delete_reservation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMultiplexProgramResponse, _>()?;
+                .deserialize::<DeleteReservationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMultiplexProgramError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteReservationError::from_response(response))
         }
     }
 
-    /// <p>Delete an expired reservation.</p>
-    async fn delete_reservation(
+    /// Presigns the request `delete_reservation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_reservation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteReservationRequest,
-    ) -> Result<DeleteReservationResponse, RusotoError<DeleteReservationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_reservation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_reservation_request(&self, input: DeleteReservationRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/reservations/{reservation_id}",
             reservation_id = input.reservation_id
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_schedule`, also returning the metadata of the response.
+    pub async fn delete_schedule_with_metadata(
+        &self,
+        input: DeleteScheduleRequest,
+    ) -> Result<(DeleteScheduleResponse, ResponseMetadata), RusotoError<DeleteScheduleError>> {
+        let request = self.build_delete_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteReservationResponse, _>()?;
+                .deserialize::<DeleteScheduleResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteReservationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteScheduleError::from_response(response))
         }
     }
 
-    /// <p>Delete all schedule actions on a channel.</p>
-    async fn delete_schedule(
+    /// Presigns the request `delete_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteScheduleRequest,
-    ) -> Result<DeleteScheduleResponse, RusotoError<DeleteScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_schedule_request(&self, input: DeleteScheduleRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/channels/{channel_id}/schedule",
             channel_id = input.channel_id
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteSchedule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_tags`, also returning the metadata of the response.
+    pub async fn delete_tags_with_metadata(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteTagsError>> {
+        let request = self.build_delete_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteScheduleResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteScheduleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteTagsError::from_response(response))
         }
     }
 
-    /// <p>Removes tags for a resource</p>
-    async fn delete_tags(
+    /// Presigns the request `delete_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTagsRequest,
-    ) -> Result<(), RusotoError<DeleteTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tags_request(&self, input: DeleteTagsRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/tags/{resource_arn}",
             resource_arn = input.resource_arn
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9442,142 +10383,275 @@ impl MediaLive for MediaLiveClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_channel`, also returning the metadata of the response.
+    pub async fn describe_channel_with_metadata(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<(DescribeChannelResponse, ResponseMetadata), RusotoError<DescribeChannelError>>
+    {
+        let request = self.build_describe_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeChannelError::from_response(response))
         }
     }
 
-    /// <p>Gets details about a channel</p>
-    async fn describe_channel(
+    /// Presigns the request `describe_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeChannelRequest,
-    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_channel_request(&self, input: DescribeChannelRequest) -> SignedRequest {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_input`, also returning the metadata of the response.
+    pub async fn describe_input_with_metadata(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<(DescribeInputResponse, ResponseMetadata), RusotoError<DescribeInputError>> {
+        let request = self.build_describe_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeChannelResponse, _>()?;
+                .deserialize::<DescribeInputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeInputError::from_response(response))
         }
     }
 
-    /// <p>Produces details about an input</p>
-    async fn describe_input(
+    /// Presigns the request `describe_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_input(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInputRequest,
-    ) -> Result<DescribeInputResponse, RusotoError<DescribeInputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_input_request(&self, input: DescribeInputRequest) -> SignedRequest {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_input_security_group`, also returning the metadata of the response.
+    pub async fn describe_input_security_group_with_metadata(
+        &self,
+        input: DescribeInputSecurityGroupRequest,
+    ) -> Result<
+        (DescribeInputSecurityGroupResponse, ResponseMetadata),
+        RusotoError<DescribeInputSecurityGroupError>,
+    > {
+        let request = self.build_describe_input_security_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInputResponse, _>()?;
+                .deserialize::<DescribeInputSecurityGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInputError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeInputSecurityGroupError::from_response(response))
         }
     }
 
-    /// <p>Produces a summary of an Input Security Group</p>
-    async fn describe_input_security_group(
+    /// Presigns the request `describe_input_security_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_input_security_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInputSecurityGroupRequest,
-    ) -> Result<DescribeInputSecurityGroupResponse, RusotoError<DescribeInputSecurityGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_input_security_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_input_security_group_request(
+        &self,
+        input: DescribeInputSecurityGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/inputSecurityGroups/{input_security_group_id}",
             input_security_group_id = input.input_security_group_id
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_multiplex`, also returning the metadata of the response.
+    pub async fn describe_multiplex_with_metadata(
+        &self,
+        input: DescribeMultiplexRequest,
+    ) -> Result<(DescribeMultiplexResponse, ResponseMetadata), RusotoError<DescribeMultiplexError>>
+    {
+        let request = self.build_describe_multiplex_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInputSecurityGroupResponse, _>()?;
+                .deserialize::<DescribeMultiplexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInputSecurityGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeMultiplexError::from_response(response))
         }
     }
 
-    /// <p>Gets details about a multiplex.</p>
-    async fn describe_multiplex(
+    /// Presigns the request `describe_multiplex` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_multiplex(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMultiplexRequest,
-    ) -> Result<DescribeMultiplexResponse, RusotoError<DescribeMultiplexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_multiplex_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_multiplex_request(&self, input: DescribeMultiplexRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}",
             multiplex_id = input.multiplex_id
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_multiplex_program`, also returning the metadata of the response.
+    pub async fn describe_multiplex_program_with_metadata(
+        &self,
+        input: DescribeMultiplexProgramRequest,
+    ) -> Result<
+        (DescribeMultiplexProgramResponse, ResponseMetadata),
+        RusotoError<DescribeMultiplexProgramError>,
+    > {
+        let request = self.build_describe_multiplex_program_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMultiplexResponse, _>()?;
+                .deserialize::<DescribeMultiplexProgramResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMultiplexError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeMultiplexProgramError::from_response(response))
         }
     }
 
-    /// <p>Get the details for a program in a multiplex.</p>
-    async fn describe_multiplex_program(
+    /// Presigns the request `describe_multiplex_program` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_multiplex_program(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMultiplexProgramRequest,
-    ) -> Result<DescribeMultiplexProgramResponse, RusotoError<DescribeMultiplexProgramError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_multiplex_program_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_multiplex_program_request(
+        &self,
+        input: DescribeMultiplexProgramRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}/programs/{program_name}",
             multiplex_id = input.multiplex_id,
@@ -9585,96 +10659,177 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_offering`, also returning the metadata of the response.
+    pub async fn describe_offering_with_metadata(
+        &self,
+        input: DescribeOfferingRequest,
+    ) -> Result<(DescribeOfferingResponse, ResponseMetadata), RusotoError<DescribeOfferingError>>
+    {
+        let request = self.build_describe_offering_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMultiplexProgramResponse, _>()?;
+                .deserialize::<DescribeOfferingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMultiplexProgramError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeOfferingError::from_response(response))
         }
     }
 
-    /// <p>Get details for an offering.</p>
-    async fn describe_offering(
+    /// Presigns the request `describe_offering` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_offering(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeOfferingRequest,
-    ) -> Result<DescribeOfferingResponse, RusotoError<DescribeOfferingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_offering_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_offering_request(&self, input: DescribeOfferingRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/offerings/{offering_id}",
             offering_id = input.offering_id
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `describe_reservation`, also returning the metadata of the response.
+    pub async fn describe_reservation_with_metadata(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> Result<
+        (DescribeReservationResponse, ResponseMetadata),
+        RusotoError<DescribeReservationError>,
+    > {
+        let request = self.build_describe_reservation_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOfferingResponse, _>()?;
+                .deserialize::<DescribeReservationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOfferingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeReservationError::from_response(response))
         }
     }
 
-    /// <p>Get details for a reservation.</p>
-    async fn describe_reservation(
+    /// Presigns the request `describe_reservation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_reservation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeReservationRequest,
-    ) -> Result<DescribeReservationResponse, RusotoError<DescribeReservationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_reservation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_reservation_request(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/reservations/{reservation_id}",
             reservation_id = input.reservation_id
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_schedule`, also returning the metadata of the response.
+    pub async fn describe_schedule_with_metadata(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> Result<(DescribeScheduleResponse, ResponseMetadata), RusotoError<DescribeScheduleError>>
+    {
+        let request = self.build_describe_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReservationResponse, _>()?;
+                .deserialize::<DescribeScheduleResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReservationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeScheduleError::from_response(response))
         }
     }
 
-    /// <p>Get a channel schedule</p>
-    async fn describe_schedule(
+    /// Presigns the request `describe_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeScheduleRequest,
-    ) -> Result<DescribeScheduleResponse, RusotoError<DescribeScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_schedule_request(&self, input: DescribeScheduleRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/channels/{channel_id}/schedule",
             channel_id = input.channel_id
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeSchedule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9686,31 +10841,55 @@ impl MediaLive for MediaLiveClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_channels`, also returning the metadata of the response.
+    pub async fn list_channels_with_metadata(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<(ListChannelsResponse, ResponseMetadata), RusotoError<ListChannelsError>> {
+        let request = self.build_list_channels_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeScheduleResponse, _>()?;
+                .deserialize::<ListChannelsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeScheduleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListChannelsError::from_response(response))
         }
     }
 
-    /// <p>Produces list of channels that have been created</p>
-    async fn list_channels(
+    /// Presigns the request `list_channels` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_channels(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListChannelsRequest,
-    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_channels_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_channels_request(&self, input: ListChannelsRequest) -> SignedRequest {
         let request_uri = "/prod/channels";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9722,31 +10901,61 @@ impl MediaLive for MediaLiveClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_input_security_groups`, also returning the metadata of the response.
+    pub async fn list_input_security_groups_with_metadata(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> Result<
+        (ListInputSecurityGroupsResponse, ResponseMetadata),
+        RusotoError<ListInputSecurityGroupsError>,
+    > {
+        let request = self.build_list_input_security_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListChannelsResponse, _>()?;
+                .deserialize::<ListInputSecurityGroupsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListChannelsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListInputSecurityGroupsError::from_response(response))
         }
     }
 
-    /// <p>Produces a list of Input Security Groups for an account</p>
-    async fn list_input_security_groups(
+    /// Presigns the request `list_input_security_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_input_security_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInputSecurityGroupsRequest,
-    ) -> Result<ListInputSecurityGroupsResponse, RusotoError<ListInputSecurityGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_input_security_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_input_security_groups_request(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> SignedRequest {
         let request_uri = "/prod/inputSecurityGroups";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9758,31 +10967,55 @@ impl MediaLive for MediaLiveClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_inputs`, also returning the metadata of the response.
+    pub async fn list_inputs_with_metadata(
+        &self,
+        input: ListInputsRequest,
+    ) -> Result<(ListInputsResponse, ResponseMetadata), RusotoError<ListInputsError>> {
+        let request = self.build_list_inputs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInputSecurityGroupsResponse, _>()?;
+                .deserialize::<ListInputsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInputSecurityGroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListInputsError::from_response(response))
         }
     }
 
-    /// <p>Produces list of inputs that have been created</p>
-    async fn list_inputs(
+    /// Presigns the request `list_inputs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_inputs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInputsRequest,
-    ) -> Result<ListInputsResponse, RusotoError<ListInputsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_inputs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_inputs_request(&self, input: ListInputsRequest) -> SignedRequest {
         let request_uri = "/prod/inputs";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9794,34 +11027,64 @@ impl MediaLive for MediaLiveClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_multiplex_programs`, also returning the metadata of the response.
+    pub async fn list_multiplex_programs_with_metadata(
+        &self,
+        input: ListMultiplexProgramsRequest,
+    ) -> Result<
+        (ListMultiplexProgramsResponse, ResponseMetadata),
+        RusotoError<ListMultiplexProgramsError>,
+    > {
+        let request = self.build_list_multiplex_programs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInputsResponse, _>()?;
+                .deserialize::<ListMultiplexProgramsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInputsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListMultiplexProgramsError::from_response(response))
         }
     }
 
-    /// <p>List the programs that currently exist for a specific multiplex.</p>
-    async fn list_multiplex_programs(
+    /// Presigns the request `list_multiplex_programs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_multiplex_programs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMultiplexProgramsRequest,
-    ) -> Result<ListMultiplexProgramsResponse, RusotoError<ListMultiplexProgramsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_multiplex_programs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_multiplex_programs_request(
+        &self,
+        input: ListMultiplexProgramsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}/programs",
             multiplex_id = input.multiplex_id
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListMultiplexPrograms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9833,31 +11096,56 @@ impl MediaLive for MediaLiveClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_multiplexes`, also returning the metadata of the response.
+    pub async fn list_multiplexes_with_metadata(
+        &self,
+        input: ListMultiplexesRequest,
+    ) -> Result<(ListMultiplexesResponse, ResponseMetadata), RusotoError<ListMultiplexesError>>
+    {
+        let request = self.build_list_multiplexes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMultiplexProgramsResponse, _>()?;
+                .deserialize::<ListMultiplexesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMultiplexProgramsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListMultiplexesError::from_response(response))
         }
     }
 
-    /// <p>Retrieve a list of the existing multiplexes.</p>
-    async fn list_multiplexes(
+    /// Presigns the request `list_multiplexes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_multiplexes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMultiplexesRequest,
-    ) -> Result<ListMultiplexesResponse, RusotoError<ListMultiplexesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_multiplexes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_multiplexes_request(&self, input: ListMultiplexesRequest) -> SignedRequest {
         let request_uri = "/prod/multiplexes";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListMultiplexes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9869,31 +11157,55 @@ impl MediaLive for MediaLiveClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_offerings`, also returning the metadata of the response.
+    pub async fn list_offerings_with_metadata(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> Result<(ListOfferingsResponse, ResponseMetadata), RusotoError<ListOfferingsError>> {
+        let request = self.build_list_offerings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMultiplexesResponse, _>()?;
+                .deserialize::<ListOfferingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMultiplexesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListOfferingsError::from_response(response))
         }
     }
 
-    /// <p>List offerings available for purchase.</p>
-    async fn list_offerings(
+    /// Presigns the request `list_offerings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_offerings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOfferingsRequest,
-    ) -> Result<ListOfferingsResponse, RusotoError<ListOfferingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_offerings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_offerings_request(&self, input: ListOfferingsRequest) -> SignedRequest {
         let request_uri = "/prod/offerings";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9935,31 +11247,56 @@ impl MediaLive for MediaLiveClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_reservations`, also returning the metadata of the response.
+    pub async fn list_reservations_with_metadata(
+        &self,
+        input: ListReservationsRequest,
+    ) -> Result<(ListReservationsResponse, ResponseMetadata), RusotoError<ListReservationsError>>
+    {
+        let request = self.build_list_reservations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListOfferingsResponse, _>()?;
+                .deserialize::<ListReservationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOfferingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListReservationsError::from_response(response))
         }
     }
 
-    /// <p>List purchased reservations.</p>
-    async fn list_reservations(
+    /// Presigns the request `list_reservations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_reservations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListReservationsRequest,
-    ) -> Result<ListReservationsResponse, RusotoError<ListReservationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_reservations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_reservations_request(&self, input: ListReservationsRequest) -> SignedRequest {
         let request_uri = "/prod/reservations";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListReservations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9995,370 +11332,681 @@ impl MediaLive for MediaLiveClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListReservationsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListReservationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Produces list of tags that have been created for a resource</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/tags/{resource_arn}",
             resource_arn = input.resource_arn
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `purchase_offering`, also returning the metadata of the response.
+    pub async fn purchase_offering_with_metadata(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> Result<(PurchaseOfferingResponse, ResponseMetadata), RusotoError<PurchaseOfferingError>>
+    {
+        let request = self.build_purchase_offering_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<PurchaseOfferingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PurchaseOfferingError::from_response(response))
         }
     }
 
-    /// <p>Purchase an offering and create a reservation.</p>
-    async fn purchase_offering(
+    /// Presigns the request `purchase_offering` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_purchase_offering(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PurchaseOfferingRequest,
-    ) -> Result<PurchaseOfferingResponse, RusotoError<PurchaseOfferingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_purchase_offering_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_purchase_offering_request(&self, input: PurchaseOfferingRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/offerings/{offering_id}/purchase",
             offering_id = input.offering_id
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("PurchaseOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_channel`, also returning the metadata of the response.
+    pub async fn start_channel_with_metadata(
+        &self,
+        input: StartChannelRequest,
+    ) -> Result<(StartChannelResponse, ResponseMetadata), RusotoError<StartChannelError>> {
+        let request = self.build_start_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PurchaseOfferingResponse, _>()?;
+                .deserialize::<StartChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PurchaseOfferingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartChannelError::from_response(response))
         }
     }
 
-    /// <p>Starts an existing channel</p>
-    async fn start_channel(
+    /// Presigns the request `start_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartChannelRequest,
-    ) -> Result<StartChannelResponse, RusotoError<StartChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_channel_request(&self, input: StartChannelRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/channels/{channel_id}/start",
             channel_id = input.channel_id
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StartChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `start_multiplex`, also returning the metadata of the response.
+    pub async fn start_multiplex_with_metadata(
+        &self,
+        input: StartMultiplexRequest,
+    ) -> Result<(StartMultiplexResponse, ResponseMetadata), RusotoError<StartMultiplexError>> {
+        let request = self.build_start_multiplex_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartChannelResponse, _>()?;
+                .deserialize::<StartMultiplexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartMultiplexError::from_response(response))
         }
     }
 
-    /// <p>Start (run) the multiplex. Starting the multiplex does not start the channels. You must explicitly start each channel.</p>
-    async fn start_multiplex(
+    /// Presigns the request `start_multiplex` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_multiplex(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartMultiplexRequest,
-    ) -> Result<StartMultiplexResponse, RusotoError<StartMultiplexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_multiplex_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_multiplex_request(&self, input: StartMultiplexRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}/start",
             multiplex_id = input.multiplex_id
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StartMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `stop_channel`, also returning the metadata of the response.
+    pub async fn stop_channel_with_metadata(
+        &self,
+        input: StopChannelRequest,
+    ) -> Result<(StopChannelResponse, ResponseMetadata), RusotoError<StopChannelError>> {
+        let request = self.build_stop_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartMultiplexResponse, _>()?;
+                .deserialize::<StopChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartMultiplexError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopChannelError::from_response(response))
         }
     }
 
-    /// <p>Stops a running channel</p>
-    async fn stop_channel(
+    /// Presigns the request `stop_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopChannelRequest,
-    ) -> Result<StopChannelResponse, RusotoError<StopChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_channel_request(&self, input: StopChannelRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/channels/{channel_id}/stop",
             channel_id = input.channel_id
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StopChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `stop_multiplex`, also returning the metadata of the response.
+    pub async fn stop_multiplex_with_metadata(
+        &self,
+        input: StopMultiplexRequest,
+    ) -> Result<(StopMultiplexResponse, ResponseMetadata), RusotoError<StopMultiplexError>> {
+        let request = self.build_stop_multiplex_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopChannelResponse, _>()?;
+                .deserialize::<StopMultiplexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopMultiplexError::from_response(response))
         }
     }
 
-    /// <p>Stops a running multiplex. If the multiplex isn&#39;t running, this action has no effect.</p>
-    async fn stop_multiplex(
+    /// Presigns the request `stop_multiplex` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_multiplex(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopMultiplexRequest,
-    ) -> Result<StopMultiplexResponse, RusotoError<StopMultiplexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_multiplex_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_multiplex_request(&self, input: StopMultiplexRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}/stop",
             multiplex_id = input.multiplex_id
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StopMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `update_channel`, also returning the metadata of the response.
+    pub async fn update_channel_with_metadata(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<(UpdateChannelResponse, ResponseMetadata), RusotoError<UpdateChannelError>> {
+        let request = self.build_update_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopMultiplexResponse, _>()?;
+                .deserialize::<UpdateChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopMultiplexError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateChannelError::from_response(response))
         }
     }
 
-    /// <p>Updates a channel.</p>
-    async fn update_channel(
+    /// Presigns the request `update_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateChannelRequest,
-    ) -> Result<UpdateChannelResponse, RusotoError<UpdateChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_channel_request(&self, input: UpdateChannelRequest) -> SignedRequest {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_channel_class`, also returning the metadata of the response.
+    pub async fn update_channel_class_with_metadata(
+        &self,
+        input: UpdateChannelClassRequest,
+    ) -> Result<(UpdateChannelClassResponse, ResponseMetadata), RusotoError<UpdateChannelClassError>>
+    {
+        let request = self.build_update_channel_class_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateChannelResponse, _>()?;
+                .deserialize::<UpdateChannelClassResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateChannelClassError::from_response(response))
         }
     }
 
-    /// <p>Changes the class of the channel.</p>
-    async fn update_channel_class(
+    /// Presigns the request `update_channel_class` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_channel_class(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateChannelClassRequest,
-    ) -> Result<UpdateChannelClassResponse, RusotoError<UpdateChannelClassError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_channel_class_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_channel_class_request(
+        &self,
+        input: UpdateChannelClassRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/channels/{channel_id}/channelClass",
             channel_id = input.channel_id
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateChannelClass");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_input`, also returning the metadata of the response.
+    pub async fn update_input_with_metadata(
+        &self,
+        input: UpdateInputRequest,
+    ) -> Result<(UpdateInputResponse, ResponseMetadata), RusotoError<UpdateInputError>> {
+        let request = self.build_update_input_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateChannelClassResponse, _>()?;
+                .deserialize::<UpdateInputResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateChannelClassError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateInputError::from_response(response))
         }
     }
 
-    /// <p>Updates an input.</p>
-    async fn update_input(
+    /// Presigns the request `update_input` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_input(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateInputRequest,
-    ) -> Result<UpdateInputResponse, RusotoError<UpdateInputError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_input_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_input_request(&self, input: UpdateInputRequest) -> SignedRequest {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_input_security_group`, also returning the metadata of the response.
+    pub async fn update_input_security_group_with_metadata(
+        &self,
+        input: UpdateInputSecurityGroupRequest,
+    ) -> Result<
+        (UpdateInputSecurityGroupResponse, ResponseMetadata),
+        RusotoError<UpdateInputSecurityGroupError>,
+    > {
+        let request = self.build_update_input_security_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateInputResponse, _>()?;
+                .deserialize::<UpdateInputSecurityGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateInputError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateInputSecurityGroupError::from_response(response))
         }
     }
 
-    /// <p>Update an Input Security Group&#39;s Whilelists.</p>
-    async fn update_input_security_group(
+    /// Presigns the request `update_input_security_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_input_security_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateInputSecurityGroupRequest,
-    ) -> Result<UpdateInputSecurityGroupResponse, RusotoError<UpdateInputSecurityGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_input_security_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_input_security_group_request(
+        &self,
+        input: UpdateInputSecurityGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/inputSecurityGroups/{input_security_group_id}",
             input_security_group_id = input.input_security_group_id
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_multiplex`, also returning the metadata of the response.
+    pub async fn update_multiplex_with_metadata(
+        &self,
+        input: UpdateMultiplexRequest,
+    ) -> Result<(UpdateMultiplexResponse, ResponseMetadata), RusotoError<UpdateMultiplexError>>
+    {
+        let request = self.build_update_multiplex_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateInputSecurityGroupResponse, _>()?;
+                .deserialize::<UpdateMultiplexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateInputSecurityGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateMultiplexError::from_response(response))
         }
     }
 
-    /// <p>Updates a multiplex.</p>
-    async fn update_multiplex(
+    /// Presigns the request `update_multiplex` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_multiplex(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMultiplexRequest,
-    ) -> Result<UpdateMultiplexResponse, RusotoError<UpdateMultiplexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_multiplex_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_multiplex_request(&self, input: UpdateMultiplexRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}",
             multiplex_id = input.multiplex_id
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_multiplex_program`, also returning the metadata of the response.
+    pub async fn update_multiplex_program_with_metadata(
+        &self,
+        input: UpdateMultiplexProgramRequest,
+    ) -> Result<
+        (UpdateMultiplexProgramResponse, ResponseMetadata),
+        RusotoError<UpdateMultiplexProgramError>,
+    > {
+        let request = self.build_update_multiplex_program_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMultiplexResponse, _>()?;
+                .deserialize::<UpdateMultiplexProgramResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMultiplexError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateMultiplexProgramError::from_response(response))
         }
     }
 
-    /// <p>Update a program in a multiplex.</p>
-    async fn update_multiplex_program(
+    /// Presigns the request `update_multiplex_program` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_multiplex_program(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMultiplexProgramRequest,
-    ) -> Result<UpdateMultiplexProgramResponse, RusotoError<UpdateMultiplexProgramError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_multiplex_program_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_multiplex_program_request(
+        &self,
+        input: UpdateMultiplexProgramRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}/programs/{program_name}",
             multiplex_id = input.multiplex_id,
@@ -10366,58 +12014,1307 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_reservation`, also returning the metadata of the response.
+    pub async fn update_reservation_with_metadata(
+        &self,
+        input: UpdateReservationRequest,
+    ) -> Result<(UpdateReservationResponse, ResponseMetadata), RusotoError<UpdateReservationError>>
+    {
+        let request = self.build_update_reservation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMultiplexProgramResponse, _>()?;
+                .deserialize::<UpdateReservationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMultiplexProgramError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateReservationError::from_response(response))
         }
     }
 
-    /// <p>Update reservation.</p>
-    async fn update_reservation(
+    /// Presigns the request `update_reservation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_reservation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateReservationRequest,
-    ) -> Result<UpdateReservationResponse, RusotoError<UpdateReservationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_reservation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_reservation_request(&self, input: UpdateReservationRequest) -> SignedRequest {
         let request_uri = format!(
             "/prod/reservations/{reservation_id}",
             reservation_id = input.reservation_id
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeSchedule` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_schedule_pages(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeScheduleResponse, RusotoError<DescribeScheduleError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaLiveClient, input| async move { client.describe_schedule(input).await },
+            |input: &DescribeScheduleRequest, output: &DescribeScheduleResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScheduleRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScheduleActions` of every page of `DescribeSchedule` results for `input`, as `describe_schedule_pages` requests them.
+    pub fn describe_schedule_stream(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> impl futures::Stream<Item = Result<ScheduleAction, RusotoError<DescribeScheduleError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_schedule_pages(input), |page| {
+            page.schedule_actions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListChannels` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_channels_pages(
+        &self,
+        input: ListChannelsRequest,
+    ) -> impl futures::Stream<Item = Result<ListChannelsResponse, RusotoError<ListChannelsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaLiveClient, input| async move { client.list_channels(input).await },
+            |input: &ListChannelsRequest, output: &ListChannelsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListChannelsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Channels` of every page of `ListChannels` results for `input`, as `list_channels_pages` requests them.
+    pub fn list_channels_stream(
+        &self,
+        input: ListChannelsRequest,
+    ) -> impl futures::Stream<Item = Result<ChannelSummary, RusotoError<ListChannelsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_channels_pages(input), |page| {
+            page.channels.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListInputSecurityGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_input_security_groups_pages(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListInputSecurityGroupsResponse, RusotoError<ListInputSecurityGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaLiveClient, input| async move {
+                client.list_input_security_groups(input).await
+            },
+            |input: &ListInputSecurityGroupsRequest, output: &ListInputSecurityGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListInputSecurityGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `InputSecurityGroups` of every page of `ListInputSecurityGroups` results for `input`, as `list_input_security_groups_pages` requests them.
+    pub fn list_input_security_groups_stream(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<InputSecurityGroup, RusotoError<ListInputSecurityGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_input_security_groups_pages(input), |page| {
+            page.input_security_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListInputs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_inputs_pages(
+        &self,
+        input: ListInputsRequest,
+    ) -> impl futures::Stream<Item = Result<ListInputsResponse, RusotoError<ListInputsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaLiveClient, input| async move { client.list_inputs(input).await },
+            |input: &ListInputsRequest, output: &ListInputsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListInputsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Inputs` of every page of `ListInputs` results for `input`, as `list_inputs_pages` requests them.
+    pub fn list_inputs_stream(
+        &self,
+        input: ListInputsRequest,
+    ) -> impl futures::Stream<Item = Result<Input, RusotoError<ListInputsError>>> + Send {
+        rusoto_core::pagination::items(self.list_inputs_pages(input), |page| {
+            page.inputs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListMultiplexPrograms` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_multiplex_programs_pages(
+        &self,
+        input: ListMultiplexProgramsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListMultiplexProgramsResponse, RusotoError<ListMultiplexProgramsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaLiveClient, input| async move { client.list_multiplex_programs(input).await },
+            |input: &ListMultiplexProgramsRequest, output: &ListMultiplexProgramsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMultiplexProgramsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `MultiplexPrograms` of every page of `ListMultiplexPrograms` results for `input`, as `list_multiplex_programs_pages` requests them.
+    pub fn list_multiplex_programs_stream(
+        &self,
+        input: ListMultiplexProgramsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<MultiplexProgramSummary, RusotoError<ListMultiplexProgramsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_multiplex_programs_pages(input), |page| {
+            page.multiplex_programs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListMultiplexes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_multiplexes_pages(
+        &self,
+        input: ListMultiplexesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListMultiplexesResponse, RusotoError<ListMultiplexesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaLiveClient, input| async move { client.list_multiplexes(input).await },
+            |input: &ListMultiplexesRequest, output: &ListMultiplexesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMultiplexesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Multiplexes` of every page of `ListMultiplexes` results for `input`, as `list_multiplexes_pages` requests them.
+    pub fn list_multiplexes_stream(
+        &self,
+        input: ListMultiplexesRequest,
+    ) -> impl futures::Stream<Item = Result<MultiplexSummary, RusotoError<ListMultiplexesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_multiplexes_pages(input), |page| {
+            page.multiplexes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOfferings` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_offerings_pages(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> impl futures::Stream<Item = Result<ListOfferingsResponse, RusotoError<ListOfferingsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaLiveClient, input| async move { client.list_offerings(input).await },
+            |input: &ListOfferingsRequest, output: &ListOfferingsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOfferingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Offerings` of every page of `ListOfferings` results for `input`, as `list_offerings_pages` requests them.
+    pub fn list_offerings_stream(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> impl futures::Stream<Item = Result<Offering, RusotoError<ListOfferingsError>>> + Send {
+        rusoto_core::pagination::items(self.list_offerings_pages(input), |page| {
+            page.offerings.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListReservations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_reservations_pages(
+        &self,
+        input: ListReservationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListReservationsResponse, RusotoError<ListReservationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaLiveClient, input| async move { client.list_reservations(input).await },
+            |input: &ListReservationsRequest, output: &ListReservationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListReservationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Reservations` of every page of `ListReservations` results for `input`, as `list_reservations_pages` requests them.
+    pub fn list_reservations_stream(
+        &self,
+        input: ListReservationsRequest,
+    ) -> impl futures::Stream<Item = Result<Reservation, RusotoError<ListReservationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_reservations_pages(input), |page| {
+            page.reservations.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `ChannelCreated`, polling `DescribeChannel` with `input` every 3 seconds, up to 5 times.
+    pub async fn wait_until_channel_created(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChannelError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(3), 5);
+        self.wait_until_channel_created_with(input, &waiter).await
+    }
+
+    /// Waits for `ChannelCreated` like `wait_until_channel_created`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_channel_created_with(
+        &self,
+        input: DescribeChannelRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChannelError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_channel(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "IDLE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "CREATING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "CREATE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ChannelDeleted`, polling `DescribeChannel` with `input` every 5 seconds, up to 84 times.
+    pub async fn wait_until_channel_deleted(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChannelError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 84);
+        self.wait_until_channel_deleted_with(input, &waiter).await
+    }
+
+    /// Waits for `ChannelDeleted` like `wait_until_channel_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_channel_deleted_with(
+        &self,
+        input: DescribeChannelRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChannelError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_channel(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "DELETED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "DELETING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ChannelRunning`, polling `DescribeChannel` with `input` every 5 seconds, up to 120 times.
+    pub async fn wait_until_channel_running(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChannelError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 120);
+        self.wait_until_channel_running_with(input, &waiter).await
+    }
+
+    /// Waits for `ChannelRunning` like `wait_until_channel_running`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_channel_running_with(
+        &self,
+        input: DescribeChannelRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChannelError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_channel(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "RUNNING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "STARTING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ChannelStopped`, polling `DescribeChannel` with `input` every 5 seconds, up to 60 times.
+    pub async fn wait_until_channel_stopped(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChannelError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_channel_stopped_with(input, &waiter).await
+    }
+
+    /// Waits for `ChannelStopped` like `wait_until_channel_stopped`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_channel_stopped_with(
+        &self,
+        input: DescribeChannelRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChannelError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_channel(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "IDLE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "STOPPING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `InputAttached`, polling `DescribeInput` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_input_attached(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInputError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_input_attached_with(input, &waiter).await
+    }
+
+    /// Waits for `InputAttached` like `wait_until_input_attached`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_input_attached_with(
+        &self,
+        input: DescribeInputRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInputError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_input(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "ATTACHED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "DETACHED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `InputDeleted`, polling `DescribeInput` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_input_deleted(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInputError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_input_deleted_with(input, &waiter).await
+    }
+
+    /// Waits for `InputDeleted` like `wait_until_input_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_input_deleted_with(
+        &self,
+        input: DescribeInputRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInputError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_input(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "DELETED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "DELETING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `InputDetached`, polling `DescribeInput` with `input` every 5 seconds, up to 84 times.
+    pub async fn wait_until_input_detached(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInputError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 84);
+        self.wait_until_input_detached_with(input, &waiter).await
+    }
+
+    /// Waits for `InputDetached` like `wait_until_input_detached`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_input_detached_with(
+        &self,
+        input: DescribeInputRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInputError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_input(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "DETACHED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "CREATING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "ATTACHED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `MultiplexCreated`, polling `DescribeMultiplex` with `input` every 3 seconds, up to 5 times.
+    pub async fn wait_until_multiplex_created(
+        &self,
+        input: DescribeMultiplexRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMultiplexError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(3), 5);
+        self.wait_until_multiplex_created_with(input, &waiter).await
+    }
+
+    /// Waits for `MultiplexCreated` like `wait_until_multiplex_created`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_multiplex_created_with(
+        &self,
+        input: DescribeMultiplexRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMultiplexError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_multiplex(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "IDLE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "CREATING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "CREATE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `MultiplexDeleted`, polling `DescribeMultiplex` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_multiplex_deleted(
+        &self,
+        input: DescribeMultiplexRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMultiplexError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_multiplex_deleted_with(input, &waiter).await
+    }
+
+    /// Waits for `MultiplexDeleted` like `wait_until_multiplex_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_multiplex_deleted_with(
+        &self,
+        input: DescribeMultiplexRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMultiplexError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_multiplex(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "DELETED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "DELETING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `MultiplexRunning`, polling `DescribeMultiplex` with `input` every 5 seconds, up to 120 times.
+    pub async fn wait_until_multiplex_running(
+        &self,
+        input: DescribeMultiplexRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMultiplexError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 120);
+        self.wait_until_multiplex_running_with(input, &waiter).await
+    }
+
+    /// Waits for `MultiplexRunning` like `wait_until_multiplex_running`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_multiplex_running_with(
+        &self,
+        input: DescribeMultiplexRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMultiplexError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_multiplex(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "RUNNING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "STARTING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `MultiplexStopped`, polling `DescribeMultiplex` with `input` every 5 seconds, up to 28 times.
+    pub async fn wait_until_multiplex_stopped(
+        &self,
+        input: DescribeMultiplexRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMultiplexError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 28);
+        self.wait_until_multiplex_stopped_with(input, &waiter).await
+    }
+
+    /// Waits for `MultiplexStopped` like `wait_until_multiplex_stopped`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_multiplex_stopped_with(
+        &self,
+        input: DescribeMultiplexRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeMultiplexError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_multiplex(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "IDLE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "STOPPING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if rusoto_core::waiter::status(result) == Some(500) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl MediaLive for MediaLiveClient {
+    /// <p>Update a channel schedule</p>
+    async fn batch_update_schedule(
+        &self,
+        input: BatchUpdateScheduleRequest,
+    ) -> Result<BatchUpdateScheduleResponse, RusotoError<BatchUpdateScheduleError>> {
+        self.batch_update_schedule_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateReservationResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateReservationError::from_response(response))
-        }
+    /// <p>Creates a new channel</p>
+    async fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        self.create_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create an input</p>
+    async fn create_input(
+        &self,
+        input: CreateInputRequest,
+    ) -> Result<CreateInputResponse, RusotoError<CreateInputError>> {
+        self.create_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Input Security Group</p>
+    async fn create_input_security_group(
+        &self,
+        input: CreateInputSecurityGroupRequest,
+    ) -> Result<CreateInputSecurityGroupResponse, RusotoError<CreateInputSecurityGroupError>> {
+        self.create_input_security_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new multiplex.</p>
+    async fn create_multiplex(
+        &self,
+        input: CreateMultiplexRequest,
+    ) -> Result<CreateMultiplexResponse, RusotoError<CreateMultiplexError>> {
+        self.create_multiplex_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new program in the multiplex.</p>
+    async fn create_multiplex_program(
+        &self,
+        input: CreateMultiplexProgramRequest,
+    ) -> Result<CreateMultiplexProgramResponse, RusotoError<CreateMultiplexProgramError>> {
+        self.create_multiplex_program_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create tags for a resource</p>
+    async fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.create_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts deletion of channel. The associated outputs are also deleted.</p>
+    async fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<DeleteChannelResponse, RusotoError<DeleteChannelError>> {
+        self.delete_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the input end point</p>
+    async fn delete_input(
+        &self,
+        input: DeleteInputRequest,
+    ) -> Result<DeleteInputResponse, RusotoError<DeleteInputError>> {
+        self.delete_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Input Security Group</p>
+    async fn delete_input_security_group(
+        &self,
+        input: DeleteInputSecurityGroupRequest,
+    ) -> Result<DeleteInputSecurityGroupResponse, RusotoError<DeleteInputSecurityGroupError>> {
+        self.delete_input_security_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a multiplex. The multiplex must be idle.</p>
+    async fn delete_multiplex(
+        &self,
+        input: DeleteMultiplexRequest,
+    ) -> Result<DeleteMultiplexResponse, RusotoError<DeleteMultiplexError>> {
+        self.delete_multiplex_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a program from a multiplex.</p>
+    async fn delete_multiplex_program(
+        &self,
+        input: DeleteMultiplexProgramRequest,
+    ) -> Result<DeleteMultiplexProgramResponse, RusotoError<DeleteMultiplexProgramError>> {
+        self.delete_multiplex_program_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete an expired reservation.</p>
+    async fn delete_reservation(
+        &self,
+        input: DeleteReservationRequest,
+    ) -> Result<DeleteReservationResponse, RusotoError<DeleteReservationError>> {
+        self.delete_reservation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete all schedule actions on a channel.</p>
+    async fn delete_schedule(
+        &self,
+        input: DeleteScheduleRequest,
+    ) -> Result<DeleteScheduleResponse, RusotoError<DeleteScheduleError>> {
+        self.delete_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags for a resource</p>
+    async fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.delete_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details about a channel</p>
+    async fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+        self.describe_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Produces details about an input</p>
+    async fn describe_input(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<DescribeInputResponse, RusotoError<DescribeInputError>> {
+        self.describe_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Produces a summary of an Input Security Group</p>
+    async fn describe_input_security_group(
+        &self,
+        input: DescribeInputSecurityGroupRequest,
+    ) -> Result<DescribeInputSecurityGroupResponse, RusotoError<DescribeInputSecurityGroupError>>
+    {
+        self.describe_input_security_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details about a multiplex.</p>
+    async fn describe_multiplex(
+        &self,
+        input: DescribeMultiplexRequest,
+    ) -> Result<DescribeMultiplexResponse, RusotoError<DescribeMultiplexError>> {
+        self.describe_multiplex_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the details for a program in a multiplex.</p>
+    async fn describe_multiplex_program(
+        &self,
+        input: DescribeMultiplexProgramRequest,
+    ) -> Result<DescribeMultiplexProgramResponse, RusotoError<DescribeMultiplexProgramError>> {
+        self.describe_multiplex_program_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get details for an offering.</p>
+    async fn describe_offering(
+        &self,
+        input: DescribeOfferingRequest,
+    ) -> Result<DescribeOfferingResponse, RusotoError<DescribeOfferingError>> {
+        self.describe_offering_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get details for a reservation.</p>
+    async fn describe_reservation(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> Result<DescribeReservationResponse, RusotoError<DescribeReservationError>> {
+        self.describe_reservation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get a channel schedule</p>
+    async fn describe_schedule(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> Result<DescribeScheduleResponse, RusotoError<DescribeScheduleError>> {
+        self.describe_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Produces list of channels that have been created</p>
+    async fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+        self.list_channels_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Produces a list of Input Security Groups for an account</p>
+    async fn list_input_security_groups(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> Result<ListInputSecurityGroupsResponse, RusotoError<ListInputSecurityGroupsError>> {
+        self.list_input_security_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Produces list of inputs that have been created</p>
+    async fn list_inputs(
+        &self,
+        input: ListInputsRequest,
+    ) -> Result<ListInputsResponse, RusotoError<ListInputsError>> {
+        self.list_inputs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the programs that currently exist for a specific multiplex.</p>
+    async fn list_multiplex_programs(
+        &self,
+        input: ListMultiplexProgramsRequest,
+    ) -> Result<ListMultiplexProgramsResponse, RusotoError<ListMultiplexProgramsError>> {
+        self.list_multiplex_programs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a list of the existing multiplexes.</p>
+    async fn list_multiplexes(
+        &self,
+        input: ListMultiplexesRequest,
+    ) -> Result<ListMultiplexesResponse, RusotoError<ListMultiplexesError>> {
+        self.list_multiplexes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List offerings available for purchase.</p>
+    async fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> Result<ListOfferingsResponse, RusotoError<ListOfferingsError>> {
+        self.list_offerings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List purchased reservations.</p>
+    async fn list_reservations(
+        &self,
+        input: ListReservationsRequest,
+    ) -> Result<ListReservationsResponse, RusotoError<ListReservationsError>> {
+        self.list_reservations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Produces list of tags that have been created for a resource</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Purchase an offering and create a reservation.</p>
+    async fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> Result<PurchaseOfferingResponse, RusotoError<PurchaseOfferingError>> {
+        self.purchase_offering_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an existing channel</p>
+    async fn start_channel(
+        &self,
+        input: StartChannelRequest,
+    ) -> Result<StartChannelResponse, RusotoError<StartChannelError>> {
+        self.start_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Start (run) the multiplex. Starting the multiplex does not start the channels. You must explicitly start each channel.</p>
+    async fn start_multiplex(
+        &self,
+        input: StartMultiplexRequest,
+    ) -> Result<StartMultiplexResponse, RusotoError<StartMultiplexError>> {
+        self.start_multiplex_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a running channel</p>
+    async fn stop_channel(
+        &self,
+        input: StopChannelRequest,
+    ) -> Result<StopChannelResponse, RusotoError<StopChannelError>> {
+        self.stop_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a running multiplex. If the multiplex isn&#39;t running, this action has no effect.</p>
+    async fn stop_multiplex(
+        &self,
+        input: StopMultiplexRequest,
+    ) -> Result<StopMultiplexResponse, RusotoError<StopMultiplexError>> {
+        self.stop_multiplex_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a channel.</p>
+    async fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<UpdateChannelResponse, RusotoError<UpdateChannelError>> {
+        self.update_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the class of the channel.</p>
+    async fn update_channel_class(
+        &self,
+        input: UpdateChannelClassRequest,
+    ) -> Result<UpdateChannelClassResponse, RusotoError<UpdateChannelClassError>> {
+        self.update_channel_class_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an input.</p>
+    async fn update_input(
+        &self,
+        input: UpdateInputRequest,
+    ) -> Result<UpdateInputResponse, RusotoError<UpdateInputError>> {
+        self.update_input_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update an Input Security Group&#39;s Whilelists.</p>
+    async fn update_input_security_group(
+        &self,
+        input: UpdateInputSecurityGroupRequest,
+    ) -> Result<UpdateInputSecurityGroupResponse, RusotoError<UpdateInputSecurityGroupError>> {
+        self.update_input_security_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a multiplex.</p>
+    async fn update_multiplex(
+        &self,
+        input: UpdateMultiplexRequest,
+    ) -> Result<UpdateMultiplexResponse, RusotoError<UpdateMultiplexError>> {
+        self.update_multiplex_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update a program in a multiplex.</p>
+    async fn update_multiplex_program(
+        &self,
+        input: UpdateMultiplexProgramRequest,
+    ) -> Result<UpdateMultiplexProgramResponse, RusotoError<UpdateMultiplexProgramError>> {
+        self.update_multiplex_program_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update reservation.</p>
+    async fn update_reservation(
+        &self,
+        input: UpdateReservationRequest,
+    ) -> Result<UpdateReservationResponse, RusotoError<UpdateReservationError>> {
+        self.update_reservation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -699,6 +699,7 @@ pub struct StreamSelection {
 
 /// Errors returned by CreateAsset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAssetError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -712,6 +713,8 @@ pub enum CreateAssetError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAssetError {
@@ -719,25 +722,34 @@ impl CreateAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAssetError::Forbidden(err.msg))
+                    return RusotoError::service(CreateAssetError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateAssetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateAssetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAssetError::NotFound(err.msg))
+                    return RusotoError::service(CreateAssetError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAssetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateAssetError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAssetError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateAssetError::TooManyRequests(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateAssetError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        CreateAssetError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAssetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -753,12 +765,14 @@ impl fmt::Display for CreateAssetError {
             CreateAssetError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateAssetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateAssetError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            CreateAssetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAssetError {}
 /// Errors returned by CreatePackagingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePackagingConfigurationError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -772,6 +786,8 @@ pub enum CreatePackagingConfigurationError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePackagingConfigurationError {
@@ -781,37 +797,48 @@ impl CreatePackagingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePackagingConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePackagingConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePackagingConfigurationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePackagingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePackagingConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePackagingConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePackagingConfigurationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePackagingConfigurationError::UnprocessableEntity(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreatePackagingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -833,12 +860,14 @@ impl fmt::Display for CreatePackagingConfigurationError {
             CreatePackagingConfigurationError::UnprocessableEntity(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreatePackagingConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePackagingConfigurationError {}
 /// Errors returned by CreatePackagingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePackagingGroupError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -852,6 +881,8 @@ pub enum CreatePackagingGroupError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePackagingGroupError {
@@ -859,33 +890,40 @@ impl CreatePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreatePackagingGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePackagingGroupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service(CreatePackagingGroupError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePackagingGroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePackagingGroupError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePackagingGroupError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePackagingGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -901,12 +939,14 @@ impl fmt::Display for CreatePackagingGroupError {
             CreatePackagingGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreatePackagingGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreatePackagingGroupError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            CreatePackagingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePackagingGroupError {}
 /// Errors returned by DeleteAsset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAssetError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -920,6 +960,8 @@ pub enum DeleteAssetError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAssetError {
@@ -927,25 +969,34 @@ impl DeleteAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAssetError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteAssetError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteAssetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteAssetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAssetError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAssetError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAssetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteAssetError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAssetError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteAssetError::TooManyRequests(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteAssetError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        DeleteAssetError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAssetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -961,12 +1012,14 @@ impl fmt::Display for DeleteAssetError {
             DeleteAssetError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteAssetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteAssetError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DeleteAssetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAssetError {}
 /// Errors returned by DeletePackagingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePackagingConfigurationError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -980,6 +1033,8 @@ pub enum DeletePackagingConfigurationError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePackagingConfigurationError {
@@ -989,37 +1044,48 @@ impl DeletePackagingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePackagingConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePackagingConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePackagingConfigurationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePackagingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePackagingConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePackagingConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePackagingConfigurationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePackagingConfigurationError::UnprocessableEntity(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeletePackagingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1041,12 +1107,14 @@ impl fmt::Display for DeletePackagingConfigurationError {
             DeletePackagingConfigurationError::UnprocessableEntity(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeletePackagingConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePackagingConfigurationError {}
 /// Errors returned by DeletePackagingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePackagingGroupError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1060,6 +1128,8 @@ pub enum DeletePackagingGroupError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePackagingGroupError {
@@ -1067,33 +1137,40 @@ impl DeletePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DeletePackagingGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePackagingGroupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service(DeletePackagingGroupError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePackagingGroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePackagingGroupError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePackagingGroupError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePackagingGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1109,12 +1186,14 @@ impl fmt::Display for DeletePackagingGroupError {
             DeletePackagingGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeletePackagingGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeletePackagingGroupError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DeletePackagingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePackagingGroupError {}
 /// Errors returned by DescribeAsset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAssetError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1128,6 +1207,8 @@ pub enum DescribeAssetError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAssetError {
@@ -1135,25 +1216,34 @@ impl DescribeAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeAssetError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeAssetError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeAssetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeAssetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeAssetError::NotFound(err.msg))
+                    return RusotoError::service(DescribeAssetError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeAssetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeAssetError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeAssetError::TooManyRequests(err.msg))
+                    return RusotoError::service(DescribeAssetError::TooManyRequests(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribeAssetError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        DescribeAssetError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAssetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1169,12 +1259,14 @@ impl fmt::Display for DescribeAssetError {
             DescribeAssetError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeAssetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribeAssetError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DescribeAssetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAssetError {}
 /// Errors returned by DescribePackagingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePackagingConfigurationError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1188,6 +1280,8 @@ pub enum DescribePackagingConfigurationError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePackagingConfigurationError {
@@ -1197,37 +1291,48 @@ impl DescribePackagingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribePackagingConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePackagingConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePackagingConfigurationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribePackagingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePackagingConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePackagingConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePackagingConfigurationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePackagingConfigurationError::UnprocessableEntity(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribePackagingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1251,12 +1356,16 @@ impl fmt::Display for DescribePackagingConfigurationError {
             DescribePackagingConfigurationError::UnprocessableEntity(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribePackagingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribePackagingConfigurationError {}
 /// Errors returned by DescribePackagingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePackagingGroupError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1270,6 +1379,8 @@ pub enum DescribePackagingGroupError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePackagingGroupError {
@@ -1277,33 +1388,43 @@ impl DescribePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribePackagingGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePackagingGroupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribePackagingGroupError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePackagingGroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePackagingGroupError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePackagingGroupError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePackagingGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1319,12 +1440,14 @@ impl fmt::Display for DescribePackagingGroupError {
             DescribePackagingGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribePackagingGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribePackagingGroupError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DescribePackagingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePackagingGroupError {}
 /// Errors returned by ListAssets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssetsError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1338,6 +1461,8 @@ pub enum ListAssetsError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssetsError {
@@ -1345,25 +1470,31 @@ impl ListAssetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAssetsError::Forbidden(err.msg))
+                    return RusotoError::service(ListAssetsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListAssetsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListAssetsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAssetsError::NotFound(err.msg))
+                    return RusotoError::service(ListAssetsError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAssetsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListAssetsError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAssetsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListAssetsError::TooManyRequests(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListAssetsError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        ListAssetsError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1379,12 +1510,14 @@ impl fmt::Display for ListAssetsError {
             ListAssetsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListAssetsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListAssetsError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            ListAssetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAssetsError {}
 /// Errors returned by ListPackagingConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPackagingConfigurationsError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1398,6 +1531,8 @@ pub enum ListPackagingConfigurationsError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPackagingConfigurationsError {
@@ -1407,37 +1542,48 @@ impl ListPackagingConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPackagingConfigurationsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPackagingConfigurationsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPackagingConfigurationsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListPackagingConfigurationsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPackagingConfigurationsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPackagingConfigurationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPackagingConfigurationsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPackagingConfigurationsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPackagingConfigurationsError::UnprocessableEntity(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListPackagingConfigurationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1459,12 +1605,14 @@ impl fmt::Display for ListPackagingConfigurationsError {
             ListPackagingConfigurationsError::UnprocessableEntity(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListPackagingConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPackagingConfigurationsError {}
 /// Errors returned by ListPackagingGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPackagingGroupsError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1478,6 +1626,8 @@ pub enum ListPackagingGroupsError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPackagingGroupsError {
@@ -1485,31 +1635,37 @@ impl ListPackagingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::Forbidden(err.msg))
+                    return RusotoError::service(ListPackagingGroupsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPackagingGroupsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::NotFound(err.msg))
+                    return RusotoError::service(ListPackagingGroupsError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPackagingGroupsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListPackagingGroupsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPackagingGroupsError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPackagingGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1525,6 +1681,7 @@ impl fmt::Display for ListPackagingGroupsError {
             ListPackagingGroupsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListPackagingGroupsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListPackagingGroupsError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            ListPackagingGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1618,6 +1775,7 @@ impl MediaPackageVodClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MediaPackageVodClient {
         MediaPackageVodClient {
             client: Client::shared(),
@@ -1643,281 +1801,580 @@ impl MediaPackageVodClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MediaPackageVodClient {
         MediaPackageVodClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MediaPackageVodClient> {
+        rusoto_core::ClientBuilder::new(MediaPackageVodClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MediaPackageVodClient {
+        MediaPackageVodClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MediaPackageVod for MediaPackageVodClient {
-    /// <p>Creates a new MediaPackage VOD Asset resource.</p>
-    async fn create_asset(
+impl MediaPackageVodClient {
+    /// Calls `create_asset`, also returning the metadata of the response.
+    pub async fn create_asset_with_metadata(
         &self,
         input: CreateAssetRequest,
-    ) -> Result<CreateAssetResponse, RusotoError<CreateAssetError>> {
+    ) -> Result<(CreateAssetResponse, ResponseMetadata), RusotoError<CreateAssetError>> {
+        let request = self.build_create_asset_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAssetResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateAssetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_asset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_asset(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateAssetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_asset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_asset_request(&self, input: CreateAssetRequest) -> SignedRequest {
         let request_uri = "/assets";
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreateAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_packaging_configuration`, also returning the metadata of the response.
+    pub async fn create_packaging_configuration_with_metadata(
+        &self,
+        input: CreatePackagingConfigurationRequest,
+    ) -> Result<
+        (CreatePackagingConfigurationResponse, ResponseMetadata),
+        RusotoError<CreatePackagingConfigurationError>,
+    > {
+        let request = self.build_create_packaging_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAssetResponse, _>()?;
+                .deserialize::<CreatePackagingConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAssetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreatePackagingConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Creates a new MediaPackage VOD PackagingConfiguration resource.</p>
-    async fn create_packaging_configuration(
+    /// Presigns the request `create_packaging_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_packaging_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePackagingConfigurationRequest,
-    ) -> Result<CreatePackagingConfigurationResponse, RusotoError<CreatePackagingConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_packaging_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_packaging_configuration_request(
+        &self,
+        input: CreatePackagingConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = "/packaging_configurations";
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreatePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_packaging_group`, also returning the metadata of the response.
+    pub async fn create_packaging_group_with_metadata(
+        &self,
+        input: CreatePackagingGroupRequest,
+    ) -> Result<
+        (CreatePackagingGroupResponse, ResponseMetadata),
+        RusotoError<CreatePackagingGroupError>,
+    > {
+        let request = self.build_create_packaging_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePackagingConfigurationResponse, _>()?;
+                .deserialize::<CreatePackagingGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePackagingConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreatePackagingGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a new MediaPackage VOD PackagingGroup resource.</p>
-    async fn create_packaging_group(
+    /// Presigns the request `create_packaging_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_packaging_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePackagingGroupRequest,
-    ) -> Result<CreatePackagingGroupResponse, RusotoError<CreatePackagingGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_packaging_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_packaging_group_request(
+        &self,
+        input: CreatePackagingGroupRequest,
+    ) -> SignedRequest {
         let request_uri = "/packaging_groups";
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreatePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_asset`, also returning the metadata of the response.
+    pub async fn delete_asset_with_metadata(
+        &self,
+        input: DeleteAssetRequest,
+    ) -> Result<(DeleteAssetResponse, ResponseMetadata), RusotoError<DeleteAssetError>> {
+        let request = self.build_delete_asset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePackagingGroupResponse, _>()?;
+                .deserialize::<DeleteAssetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePackagingGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAssetError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing MediaPackage VOD Asset resource.</p>
-    async fn delete_asset(
+    /// Presigns the request `delete_asset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_asset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAssetRequest,
-    ) -> Result<DeleteAssetResponse, RusotoError<DeleteAssetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_asset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_asset_request(&self, input: DeleteAssetRequest) -> SignedRequest {
         let request_uri = format!("/assets/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeleteAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_packaging_configuration`, also returning the metadata of the response.
+    pub async fn delete_packaging_configuration_with_metadata(
+        &self,
+        input: DeletePackagingConfigurationRequest,
+    ) -> Result<
+        (DeletePackagingConfigurationResponse, ResponseMetadata),
+        RusotoError<DeletePackagingConfigurationError>,
+    > {
+        let request = self.build_delete_packaging_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteAssetResponse, _>()?;
+                .deserialize::<DeletePackagingConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAssetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeletePackagingConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes a MediaPackage VOD PackagingConfiguration resource.</p>
-    async fn delete_packaging_configuration(
+    /// Presigns the request `delete_packaging_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_packaging_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePackagingConfigurationRequest,
-    ) -> Result<DeletePackagingConfigurationResponse, RusotoError<DeletePackagingConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_packaging_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_packaging_configuration_request(
+        &self,
+        input: DeletePackagingConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/packaging_configurations/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeletePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_packaging_group`, also returning the metadata of the response.
+    pub async fn delete_packaging_group_with_metadata(
+        &self,
+        input: DeletePackagingGroupRequest,
+    ) -> Result<
+        (DeletePackagingGroupResponse, ResponseMetadata),
+        RusotoError<DeletePackagingGroupError>,
+    > {
+        let request = self.build_delete_packaging_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePackagingConfigurationResponse, _>()?;
+                .deserialize::<DeletePackagingGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePackagingConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeletePackagingGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a MediaPackage VOD PackagingGroup resource.</p>
-    async fn delete_packaging_group(
+    /// Presigns the request `delete_packaging_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_packaging_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePackagingGroupRequest,
-    ) -> Result<DeletePackagingGroupResponse, RusotoError<DeletePackagingGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_packaging_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_packaging_group_request(
+        &self,
+        input: DeletePackagingGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/packaging_groups/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeletePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_asset`, also returning the metadata of the response.
+    pub async fn describe_asset_with_metadata(
+        &self,
+        input: DescribeAssetRequest,
+    ) -> Result<(DescribeAssetResponse, ResponseMetadata), RusotoError<DescribeAssetError>> {
+        let request = self.build_describe_asset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePackagingGroupResponse, _>()?;
+                .deserialize::<DescribeAssetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePackagingGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeAssetError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of a MediaPackage VOD Asset resource.</p>
-    async fn describe_asset(
+    /// Presigns the request `describe_asset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_asset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAssetRequest,
-    ) -> Result<DescribeAssetResponse, RusotoError<DescribeAssetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_asset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_asset_request(&self, input: DescribeAssetRequest) -> SignedRequest {
         let request_uri = format!("/assets/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribeAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_packaging_configuration`, also returning the metadata of the response.
+    pub async fn describe_packaging_configuration_with_metadata(
+        &self,
+        input: DescribePackagingConfigurationRequest,
+    ) -> Result<
+        (DescribePackagingConfigurationResponse, ResponseMetadata),
+        RusotoError<DescribePackagingConfigurationError>,
+    > {
+        let request = self.build_describe_packaging_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAssetResponse, _>()?;
+                .deserialize::<DescribePackagingConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAssetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribePackagingConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of a MediaPackage VOD PackagingConfiguration resource.</p>
-    async fn describe_packaging_configuration(
+    /// Presigns the request `describe_packaging_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_packaging_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePackagingConfigurationRequest,
-    ) -> Result<
-        DescribePackagingConfigurationResponse,
-        RusotoError<DescribePackagingConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_packaging_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_packaging_configuration_request(
+        &self,
+        input: DescribePackagingConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/packaging_configurations/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_packaging_group`, also returning the metadata of the response.
+    pub async fn describe_packaging_group_with_metadata(
+        &self,
+        input: DescribePackagingGroupRequest,
+    ) -> Result<
+        (DescribePackagingGroupResponse, ResponseMetadata),
+        RusotoError<DescribePackagingGroupError>,
+    > {
+        let request = self.build_describe_packaging_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePackagingConfigurationResponse, _>()?;
+                .deserialize::<DescribePackagingGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePackagingConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribePackagingGroupError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of a MediaPackage VOD PackagingGroup resource.</p>
-    async fn describe_packaging_group(
+    /// Presigns the request `describe_packaging_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_packaging_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePackagingGroupRequest,
-    ) -> Result<DescribePackagingGroupResponse, RusotoError<DescribePackagingGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_packaging_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_packaging_group_request(
+        &self,
+        input: DescribePackagingGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/packaging_groups/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_assets`, also returning the metadata of the response.
+    pub async fn list_assets_with_metadata(
+        &self,
+        input: ListAssetsRequest,
+    ) -> Result<(ListAssetsResponse, ResponseMetadata), RusotoError<ListAssetsError>> {
+        let request = self.build_list_assets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePackagingGroupResponse, _>()?;
+                .deserialize::<ListAssetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePackagingGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAssetsError::from_response(response))
         }
     }
 
-    /// <p>Returns a collection of MediaPackage VOD Asset resources.</p>
-    async fn list_assets(
+    /// Presigns the request `list_assets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_assets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssetsRequest,
-    ) -> Result<ListAssetsResponse, RusotoError<ListAssetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_assets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_assets_request(&self, input: ListAssetsRequest) -> SignedRequest {
         let request_uri = "/assets";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListAssets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1932,32 +2389,61 @@ impl MediaPackageVod for MediaPackageVodClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_packaging_configurations`, also returning the metadata of the response.
+    pub async fn list_packaging_configurations_with_metadata(
+        &self,
+        input: ListPackagingConfigurationsRequest,
+    ) -> Result<
+        (ListPackagingConfigurationsResponse, ResponseMetadata),
+        RusotoError<ListPackagingConfigurationsError>,
+    > {
+        let request = self.build_list_packaging_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssetsResponse, _>()?;
+                .deserialize::<ListPackagingConfigurationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPackagingConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a collection of MediaPackage VOD PackagingConfiguration resources.</p>
-    async fn list_packaging_configurations(
+    /// Presigns the request `list_packaging_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_packaging_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPackagingConfigurationsRequest,
-    ) -> Result<ListPackagingConfigurationsResponse, RusotoError<ListPackagingConfigurationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_packaging_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_packaging_configurations_request(
+        &self,
+        input: ListPackagingConfigurationsRequest,
+    ) -> SignedRequest {
         let request_uri = "/packaging_configurations";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListPackagingConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1972,31 +2458,61 @@ impl MediaPackageVod for MediaPackageVodClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_packaging_groups`, also returning the metadata of the response.
+    pub async fn list_packaging_groups_with_metadata(
+        &self,
+        input: ListPackagingGroupsRequest,
+    ) -> Result<
+        (ListPackagingGroupsResponse, ResponseMetadata),
+        RusotoError<ListPackagingGroupsError>,
+    > {
+        let request = self.build_list_packaging_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPackagingConfigurationsResponse, _>()?;
+                .deserialize::<ListPackagingGroupsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPackagingConfigurationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPackagingGroupsError::from_response(response))
         }
     }
 
-    /// <p>Returns a collection of MediaPackage VOD PackagingGroup resources.</p>
-    async fn list_packaging_groups(
+    /// Presigns the request `list_packaging_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_packaging_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPackagingGroupsRequest,
-    ) -> Result<ListPackagingGroupsResponse, RusotoError<ListPackagingGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_packaging_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_packaging_groups_request(
+        &self,
+        input: ListPackagingGroupsRequest,
+    ) -> SignedRequest {
         let request_uri = "/packaging_groups";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListPackagingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2008,20 +2524,254 @@ impl MediaPackageVod for MediaPackageVodClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAssets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_assets_pages(
+        &self,
+        input: ListAssetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAssetsResponse, RusotoError<ListAssetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageVodClient, input| async move { client.list_assets(input).await },
+            |input: &ListAssetsRequest, output: &ListAssetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Assets` of every page of `ListAssets` results for `input`, as `list_assets_pages` requests them.
+    pub fn list_assets_stream(
+        &self,
+        input: ListAssetsRequest,
+    ) -> impl futures::Stream<Item = Result<AssetShallow, RusotoError<ListAssetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_assets_pages(input), |page| {
+            page.assets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPackagingConfigurations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_packaging_configurations_pages(
+        &self,
+        input: ListPackagingConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListPackagingConfigurationsResponse,
+            RusotoError<ListPackagingConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageVodClient, input| async move {
+                client.list_packaging_configurations(input).await
+            },
+            |input: &ListPackagingConfigurationsRequest,
+             output: &ListPackagingConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPackagingConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PackagingConfigurations` of every page of `ListPackagingConfigurations` results for `input`, as `list_packaging_configurations_pages` requests them.
+    pub fn list_packaging_configurations_stream(
+        &self,
+        input: ListPackagingConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<PackagingConfiguration, RusotoError<ListPackagingConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_packaging_configurations_pages(input), |page| {
+            page.packaging_configurations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPackagingGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_packaging_groups_pages(
+        &self,
+        input: ListPackagingGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListPackagingGroupsResponse, RusotoError<ListPackagingGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageVodClient, input| async move {
+                client.list_packaging_groups(input).await
+            },
+            |input: &ListPackagingGroupsRequest, output: &ListPackagingGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPackagingGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PackagingGroups` of every page of `ListPackagingGroups` results for `input`, as `list_packaging_groups_pages` requests them.
+    pub fn list_packaging_groups_stream(
+        &self,
+        input: ListPackagingGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<PackagingGroup, RusotoError<ListPackagingGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_packaging_groups_pages(input), |page| {
+            page.packaging_groups.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl MediaPackageVod for MediaPackageVodClient {
+    /// <p>Creates a new MediaPackage VOD Asset resource.</p>
+    async fn create_asset(
+        &self,
+        input: CreateAssetRequest,
+    ) -> Result<CreateAssetResponse, RusotoError<CreateAssetError>> {
+        self.create_asset_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPackagingGroupsResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPackagingGroupsError::from_response(response))
-        }
+    /// <p>Creates a new MediaPackage VOD PackagingConfiguration resource.</p>
+    async fn create_packaging_configuration(
+        &self,
+        input: CreatePackagingConfigurationRequest,
+    ) -> Result<CreatePackagingConfigurationResponse, RusotoError<CreatePackagingConfigurationError>>
+    {
+        self.create_packaging_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new MediaPackage VOD PackagingGroup resource.</p>
+    async fn create_packaging_group(
+        &self,
+        input: CreatePackagingGroupRequest,
+    ) -> Result<CreatePackagingGroupResponse, RusotoError<CreatePackagingGroupError>> {
+        self.create_packaging_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing MediaPackage VOD Asset resource.</p>
+    async fn delete_asset(
+        &self,
+        input: DeleteAssetRequest,
+    ) -> Result<DeleteAssetResponse, RusotoError<DeleteAssetError>> {
+        self.delete_asset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a MediaPackage VOD PackagingConfiguration resource.</p>
+    async fn delete_packaging_configuration(
+        &self,
+        input: DeletePackagingConfigurationRequest,
+    ) -> Result<DeletePackagingConfigurationResponse, RusotoError<DeletePackagingConfigurationError>>
+    {
+        self.delete_packaging_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a MediaPackage VOD PackagingGroup resource.</p>
+    async fn delete_packaging_group(
+        &self,
+        input: DeletePackagingGroupRequest,
+    ) -> Result<DeletePackagingGroupResponse, RusotoError<DeletePackagingGroupError>> {
+        self.delete_packaging_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of a MediaPackage VOD Asset resource.</p>
+    async fn describe_asset(
+        &self,
+        input: DescribeAssetRequest,
+    ) -> Result<DescribeAssetResponse, RusotoError<DescribeAssetError>> {
+        self.describe_asset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of a MediaPackage VOD PackagingConfiguration resource.</p>
+    async fn describe_packaging_configuration(
+        &self,
+        input: DescribePackagingConfigurationRequest,
+    ) -> Result<
+        DescribePackagingConfigurationResponse,
+        RusotoError<DescribePackagingConfigurationError>,
+    > {
+        self.describe_packaging_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of a MediaPackage VOD PackagingGroup resource.</p>
+    async fn describe_packaging_group(
+        &self,
+        input: DescribePackagingGroupRequest,
+    ) -> Result<DescribePackagingGroupResponse, RusotoError<DescribePackagingGroupError>> {
+        self.describe_packaging_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a collection of MediaPackage VOD Asset resources.</p>
+    async fn list_assets(
+        &self,
+        input: ListAssetsRequest,
+    ) -> Result<ListAssetsResponse, RusotoError<ListAssetsError>> {
+        self.list_assets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a collection of MediaPackage VOD PackagingConfiguration resources.</p>
+    async fn list_packaging_configurations(
+        &self,
+        input: ListPackagingConfigurationsRequest,
+    ) -> Result<ListPackagingConfigurationsResponse, RusotoError<ListPackagingConfigurationsError>>
+    {
+        self.list_packaging_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a collection of MediaPackage VOD PackagingGroup resources.</p>
+    async fn list_packaging_groups(
+        &self,
+        input: ListPackagingGroupsRequest,
+    ) -> Result<ListPackagingGroupsResponse, RusotoError<ListPackagingGroupsError>> {
+        self.list_packaging_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
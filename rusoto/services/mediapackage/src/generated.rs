@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1367,6 +1367,7 @@ pub struct UpdateOriginEndpointResponse {
 
 /// Errors returned by CreateChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateChannelError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1380,6 +1381,8 @@ pub enum CreateChannelError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateChannelError {
@@ -1387,25 +1390,34 @@ impl CreateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateChannelError::Forbidden(err.msg))
+                    return RusotoError::service(CreateChannelError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateChannelError::NotFound(err.msg))
+                    return RusotoError::service(CreateChannelError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateChannelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateChannelError::TooManyRequests(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        CreateChannelError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1421,12 +1433,14 @@ impl fmt::Display for CreateChannelError {
             CreateChannelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateChannelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateChannelError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            CreateChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateChannelError {}
 /// Errors returned by CreateHarvestJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHarvestJobError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1440,6 +1454,8 @@ pub enum CreateHarvestJobError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHarvestJobError {
@@ -1447,29 +1463,37 @@ impl CreateHarvestJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateHarvestJobError::Forbidden(err.msg))
+                    return RusotoError::service(CreateHarvestJobError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateHarvestJobError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHarvestJobError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateHarvestJobError::NotFound(err.msg))
+                    return RusotoError::service(CreateHarvestJobError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateHarvestJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateHarvestJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateHarvestJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateHarvestJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateHarvestJobError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHarvestJobError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHarvestJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1485,12 +1509,14 @@ impl fmt::Display for CreateHarvestJobError {
             CreateHarvestJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateHarvestJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateHarvestJobError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            CreateHarvestJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHarvestJobError {}
 /// Errors returned by CreateOriginEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateOriginEndpointError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1504,6 +1530,8 @@ pub enum CreateOriginEndpointError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateOriginEndpointError {
@@ -1511,33 +1539,40 @@ impl CreateOriginEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreateOriginEndpointError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOriginEndpointError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::NotFound(err.msg))
+                    return RusotoError::service(CreateOriginEndpointError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOriginEndpointError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOriginEndpointError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOriginEndpointError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateOriginEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1553,12 +1588,14 @@ impl fmt::Display for CreateOriginEndpointError {
             CreateOriginEndpointError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateOriginEndpointError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateOriginEndpointError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            CreateOriginEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateOriginEndpointError {}
 /// Errors returned by DeleteChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteChannelError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1572,6 +1609,8 @@ pub enum DeleteChannelError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteChannelError {
@@ -1579,25 +1618,34 @@ impl DeleteChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteChannelError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteChannelError::NotFound(err.msg))
+                    return RusotoError::service(DeleteChannelError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteChannelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteChannelError::TooManyRequests(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        DeleteChannelError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1613,12 +1661,14 @@ impl fmt::Display for DeleteChannelError {
             DeleteChannelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteChannelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteChannelError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DeleteChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteChannelError {}
 /// Errors returned by DeleteOriginEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteOriginEndpointError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1632,6 +1682,8 @@ pub enum DeleteOriginEndpointError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteOriginEndpointError {
@@ -1639,33 +1691,40 @@ impl DeleteOriginEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DeleteOriginEndpointError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOriginEndpointError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::NotFound(err.msg))
+                    return RusotoError::service(DeleteOriginEndpointError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOriginEndpointError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOriginEndpointError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOriginEndpointError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteOriginEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1681,12 +1740,14 @@ impl fmt::Display for DeleteOriginEndpointError {
             DeleteOriginEndpointError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteOriginEndpointError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteOriginEndpointError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DeleteOriginEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteOriginEndpointError {}
 /// Errors returned by DescribeChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeChannelError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1700,6 +1761,8 @@ pub enum DescribeChannelError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeChannelError {
@@ -1707,25 +1770,37 @@ impl DescribeChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeChannelError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeChannelError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeChannelError::NotFound(err.msg))
+                    return RusotoError::service(DescribeChannelError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribeChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        DescribeChannelError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1741,12 +1816,14 @@ impl fmt::Display for DescribeChannelError {
             DescribeChannelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeChannelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribeChannelError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DescribeChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeChannelError {}
 /// Errors returned by DescribeHarvestJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHarvestJobError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1760,6 +1837,8 @@ pub enum DescribeHarvestJobError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHarvestJobError {
@@ -1767,31 +1846,37 @@ impl DescribeHarvestJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeHarvestJobError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeHarvestJobError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::NotFound(err.msg))
+                    return RusotoError::service(DescribeHarvestJobError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeHarvestJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeHarvestJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeHarvestJobError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeHarvestJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1807,12 +1892,14 @@ impl fmt::Display for DescribeHarvestJobError {
             DescribeHarvestJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeHarvestJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribeHarvestJobError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DescribeHarvestJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHarvestJobError {}
 /// Errors returned by DescribeOriginEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeOriginEndpointError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1826,6 +1913,8 @@ pub enum DescribeOriginEndpointError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeOriginEndpointError {
@@ -1833,33 +1922,43 @@ impl DescribeOriginEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeOriginEndpointError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOriginEndpointError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeOriginEndpointError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOriginEndpointError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOriginEndpointError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOriginEndpointError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeOriginEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1875,12 +1974,14 @@ impl fmt::Display for DescribeOriginEndpointError {
             DescribeOriginEndpointError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeOriginEndpointError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribeOriginEndpointError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DescribeOriginEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeOriginEndpointError {}
 /// Errors returned by ListChannels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListChannelsError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1894,6 +1995,8 @@ pub enum ListChannelsError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListChannelsError {
@@ -1901,25 +2004,34 @@ impl ListChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelsError::Forbidden(err.msg))
+                    return RusotoError::service(ListChannelsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListChannelsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListChannelsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListChannelsError::NotFound(err.msg))
+                    return RusotoError::service(ListChannelsError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListChannelsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListChannelsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListChannelsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListChannelsError::TooManyRequests(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListChannelsError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        ListChannelsError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListChannelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1935,12 +2047,14 @@ impl fmt::Display for ListChannelsError {
             ListChannelsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListChannelsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListChannelsError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            ListChannelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListChannelsError {}
 /// Errors returned by ListHarvestJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHarvestJobsError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -1954,6 +2068,8 @@ pub enum ListHarvestJobsError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHarvestJobsError {
@@ -1961,25 +2077,37 @@ impl ListHarvestJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListHarvestJobsError::Forbidden(err.msg))
+                    return RusotoError::service(ListHarvestJobsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListHarvestJobsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListHarvestJobsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListHarvestJobsError::NotFound(err.msg))
+                    return RusotoError::service(ListHarvestJobsError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListHarvestJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListHarvestJobsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListHarvestJobsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListHarvestJobsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListHarvestJobsError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        ListHarvestJobsError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListHarvestJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1995,12 +2123,14 @@ impl fmt::Display for ListHarvestJobsError {
             ListHarvestJobsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListHarvestJobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListHarvestJobsError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            ListHarvestJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHarvestJobsError {}
 /// Errors returned by ListOriginEndpoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOriginEndpointsError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -2014,6 +2144,8 @@ pub enum ListOriginEndpointsError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOriginEndpointsError {
@@ -2021,31 +2153,37 @@ impl ListOriginEndpointsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::Forbidden(err.msg))
+                    return RusotoError::service(ListOriginEndpointsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOriginEndpointsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::NotFound(err.msg))
+                    return RusotoError::service(ListOriginEndpointsError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOriginEndpointsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListOriginEndpointsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOriginEndpointsError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOriginEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2061,20 +2199,25 @@ impl fmt::Display for ListOriginEndpointsError {
             ListOriginEndpointsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListOriginEndpointsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListOriginEndpointsError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            ListOriginEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOriginEndpointsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
-pub enum ListTagsForResourceError {}
+#[non_exhaustive]
+pub enum ListTagsForResourceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListTagsForResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsForResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2083,12 +2226,15 @@ impl ListTagsForResourceError {
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by RotateChannelCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RotateChannelCredentialsError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -2102,6 +2248,8 @@ pub enum RotateChannelCredentialsError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RotateChannelCredentialsError {
@@ -2109,33 +2257,43 @@ impl RotateChannelCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(RotateChannelCredentialsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        RotateChannelCredentialsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RotateChannelCredentialsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RotateChannelCredentialsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        RotateChannelCredentialsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RotateChannelCredentialsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RotateChannelCredentialsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RotateChannelCredentialsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RotateChannelCredentialsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RotateChannelCredentialsError::UnprocessableEntity(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RotateChannelCredentialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2151,12 +2309,14 @@ impl fmt::Display for RotateChannelCredentialsError {
             RotateChannelCredentialsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             RotateChannelCredentialsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             RotateChannelCredentialsError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            RotateChannelCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RotateChannelCredentialsError {}
 /// Errors returned by RotateIngestEndpointCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RotateIngestEndpointCredentialsError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -2170,6 +2330,8 @@ pub enum RotateIngestEndpointCredentialsError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RotateIngestEndpointCredentialsError {
@@ -2179,37 +2341,48 @@ impl RotateIngestEndpointCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(RotateIngestEndpointCredentialsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RotateIngestEndpointCredentialsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RotateIngestEndpointCredentialsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RotateIngestEndpointCredentialsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RotateIngestEndpointCredentialsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RotateIngestEndpointCredentialsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RotateIngestEndpointCredentialsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RotateIngestEndpointCredentialsError::UnprocessableEntity(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RotateIngestEndpointCredentialsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2233,20 +2406,27 @@ impl fmt::Display for RotateIngestEndpointCredentialsError {
             RotateIngestEndpointCredentialsError::UnprocessableEntity(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RotateIngestEndpointCredentialsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RotateIngestEndpointCredentialsError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
-pub enum TagResourceError {}
+#[non_exhaustive]
+pub enum TagResourceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2255,20 +2435,26 @@ impl TagResourceError {
 impl fmt::Display for TagResourceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
-pub enum UntagResourceError {}
+#[non_exhaustive]
+pub enum UntagResourceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UntagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UntagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2277,12 +2463,15 @@ impl UntagResourceError {
 impl fmt::Display for UntagResourceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateChannelError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -2296,6 +2485,8 @@ pub enum UpdateChannelError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateChannelError {
@@ -2303,25 +2494,34 @@ impl UpdateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateChannelError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateChannelError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateChannelError::NotFound(err.msg))
+                    return RusotoError::service(UpdateChannelError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateChannelError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateChannelError::TooManyRequests(err.msg), &res)
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        UpdateChannelError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2337,12 +2537,14 @@ impl fmt::Display for UpdateChannelError {
             UpdateChannelError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateChannelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateChannelError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            UpdateChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateChannelError {}
 /// Errors returned by UpdateOriginEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateOriginEndpointError {
     /// <p>The client is not authorized to access the requested resource.</p>
     Forbidden(String),
@@ -2356,6 +2558,8 @@ pub enum UpdateOriginEndpointError {
     TooManyRequests(String),
     /// <p>The parameters sent in the request are not valid.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateOriginEndpointError {
@@ -2363,33 +2567,40 @@ impl UpdateOriginEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateOriginEndpointError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateOriginEndpointError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::NotFound(err.msg))
+                    return RusotoError::service(UpdateOriginEndpointError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateOriginEndpointError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateOriginEndpointError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateOriginEndpointError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateOriginEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2405,6 +2616,7 @@ impl fmt::Display for UpdateOriginEndpointError {
             UpdateOriginEndpointError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateOriginEndpointError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateOriginEndpointError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            UpdateOriginEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2531,6 +2743,7 @@ impl MediaPackageClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MediaPackageClient {
         MediaPackageClient {
             client: Client::shared(),
@@ -2556,243 +2769,511 @@ impl MediaPackageClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MediaPackageClient {
         MediaPackageClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MediaPackageClient> {
+        rusoto_core::ClientBuilder::new(MediaPackageClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MediaPackageClient {
+        MediaPackageClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MediaPackage for MediaPackageClient {
-    /// <p>Creates a new Channel.</p>
-    async fn create_channel(
+impl MediaPackageClient {
+    /// Calls `create_channel`, also returning the metadata of the response.
+    pub async fn create_channel_with_metadata(
         &self,
         input: CreateChannelRequest,
-    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+    ) -> Result<(CreateChannelResponse, ResponseMetadata), RusotoError<CreateChannelError>> {
+        let request = self.build_create_channel_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateChannelResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateChannelError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_channel(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateChannelRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_channel_request(&self, input: CreateChannelRequest) -> SignedRequest {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_harvest_job`, also returning the metadata of the response.
+    pub async fn create_harvest_job_with_metadata(
+        &self,
+        input: CreateHarvestJobRequest,
+    ) -> Result<(CreateHarvestJobResponse, ResponseMetadata), RusotoError<CreateHarvestJobError>>
+    {
+        let request = self.build_create_harvest_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateChannelResponse, _>()?;
+                .deserialize::<CreateHarvestJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateHarvestJobError::from_response(response))
         }
     }
 
-    /// <p>Creates a new HarvestJob record.</p>
-    async fn create_harvest_job(
+    /// Presigns the request `create_harvest_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_harvest_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateHarvestJobRequest,
-    ) -> Result<CreateHarvestJobResponse, RusotoError<CreateHarvestJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_harvest_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_harvest_job_request(&self, input: CreateHarvestJobRequest) -> SignedRequest {
         let request_uri = "/harvest_jobs";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("CreateHarvestJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_origin_endpoint`, also returning the metadata of the response.
+    pub async fn create_origin_endpoint_with_metadata(
+        &self,
+        input: CreateOriginEndpointRequest,
+    ) -> Result<
+        (CreateOriginEndpointResponse, ResponseMetadata),
+        RusotoError<CreateOriginEndpointError>,
+    > {
+        let request = self.build_create_origin_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateHarvestJobResponse, _>()?;
+                .deserialize::<CreateOriginEndpointResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHarvestJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateOriginEndpointError::from_response(response))
         }
     }
 
-    /// <p>Creates a new OriginEndpoint record.</p>
-    async fn create_origin_endpoint(
+    /// Presigns the request `create_origin_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_origin_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateOriginEndpointRequest,
-    ) -> Result<CreateOriginEndpointResponse, RusotoError<CreateOriginEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_origin_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_origin_endpoint_request(
+        &self,
+        input: CreateOriginEndpointRequest,
+    ) -> SignedRequest {
         let request_uri = "/origin_endpoints";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("CreateOriginEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_channel`, also returning the metadata of the response.
+    pub async fn delete_channel_with_metadata(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<(DeleteChannelResponse, ResponseMetadata), RusotoError<DeleteChannelError>> {
+        let request = self.build_delete_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateOriginEndpointResponse, _>()?;
+                .deserialize::<DeleteChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateOriginEndpointError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteChannelError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing Channel.</p>
-    async fn delete_channel(
+    /// Presigns the request `delete_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteChannelRequest,
-    ) -> Result<DeleteChannelResponse, RusotoError<DeleteChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_channel_request(&self, input: DeleteChannelRequest) -> SignedRequest {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_origin_endpoint`, also returning the metadata of the response.
+    pub async fn delete_origin_endpoint_with_metadata(
+        &self,
+        input: DeleteOriginEndpointRequest,
+    ) -> Result<
+        (DeleteOriginEndpointResponse, ResponseMetadata),
+        RusotoError<DeleteOriginEndpointError>,
+    > {
+        let request = self.build_delete_origin_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteChannelResponse, _>()?;
+                .deserialize::<DeleteOriginEndpointResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteOriginEndpointError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing OriginEndpoint.</p>
-    async fn delete_origin_endpoint(
+    /// Presigns the request `delete_origin_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_origin_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteOriginEndpointRequest,
-    ) -> Result<DeleteOriginEndpointResponse, RusotoError<DeleteOriginEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_origin_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_origin_endpoint_request(
+        &self,
+        input: DeleteOriginEndpointRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DeleteOriginEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_channel`, also returning the metadata of the response.
+    pub async fn describe_channel_with_metadata(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<(DescribeChannelResponse, ResponseMetadata), RusotoError<DescribeChannelError>>
+    {
+        let request = self.build_describe_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteOriginEndpointResponse, _>()?;
+                .deserialize::<DescribeChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteOriginEndpointError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeChannelError::from_response(response))
         }
     }
 
-    /// <p>Gets details about a Channel.</p>
-    async fn describe_channel(
+    /// Presigns the request `describe_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeChannelRequest,
-    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_channel_request(&self, input: DescribeChannelRequest) -> SignedRequest {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_harvest_job`, also returning the metadata of the response.
+    pub async fn describe_harvest_job_with_metadata(
+        &self,
+        input: DescribeHarvestJobRequest,
+    ) -> Result<(DescribeHarvestJobResponse, ResponseMetadata), RusotoError<DescribeHarvestJobError>>
+    {
+        let request = self.build_describe_harvest_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeChannelResponse, _>()?;
+                .deserialize::<DescribeHarvestJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeHarvestJobError::from_response(response))
         }
     }
 
-    /// <p>Gets details about an existing HarvestJob.</p>
-    async fn describe_harvest_job(
+    /// Presigns the request `describe_harvest_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_harvest_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHarvestJobRequest,
-    ) -> Result<DescribeHarvestJobResponse, RusotoError<DescribeHarvestJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_harvest_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_harvest_job_request(
+        &self,
+        input: DescribeHarvestJobRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/harvest_jobs/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DescribeHarvestJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_origin_endpoint`, also returning the metadata of the response.
+    pub async fn describe_origin_endpoint_with_metadata(
+        &self,
+        input: DescribeOriginEndpointRequest,
+    ) -> Result<
+        (DescribeOriginEndpointResponse, ResponseMetadata),
+        RusotoError<DescribeOriginEndpointError>,
+    > {
+        let request = self.build_describe_origin_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeHarvestJobResponse, _>()?;
+                .deserialize::<DescribeOriginEndpointResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHarvestJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeOriginEndpointError::from_response(response))
         }
     }
 
-    /// <p>Gets details about an existing OriginEndpoint.</p>
-    async fn describe_origin_endpoint(
+    /// Presigns the request `describe_origin_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_origin_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeOriginEndpointRequest,
-    ) -> Result<DescribeOriginEndpointResponse, RusotoError<DescribeOriginEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_origin_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_origin_endpoint_request(
+        &self,
+        input: DescribeOriginEndpointRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DescribeOriginEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_channels`, also returning the metadata of the response.
+    pub async fn list_channels_with_metadata(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<(ListChannelsResponse, ResponseMetadata), RusotoError<ListChannelsError>> {
+        let request = self.build_list_channels_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOriginEndpointResponse, _>()?;
+                .deserialize::<ListChannelsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOriginEndpointError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListChannelsError::from_response(response))
         }
     }
 
-    /// <p>Returns a collection of Channels.</p>
-    async fn list_channels(
+    /// Presigns the request `list_channels` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_channels(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListChannelsRequest,
-    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_channels_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_channels_request(&self, input: ListChannelsRequest) -> SignedRequest {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2804,31 +3285,56 @@ impl MediaPackage for MediaPackageClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_harvest_jobs`, also returning the metadata of the response.
+    pub async fn list_harvest_jobs_with_metadata(
+        &self,
+        input: ListHarvestJobsRequest,
+    ) -> Result<(ListHarvestJobsResponse, ResponseMetadata), RusotoError<ListHarvestJobsError>>
+    {
+        let request = self.build_list_harvest_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListChannelsResponse, _>()?;
+                .deserialize::<ListHarvestJobsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListChannelsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListHarvestJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns a collection of HarvestJob records.</p>
-    async fn list_harvest_jobs(
+    /// Presigns the request `list_harvest_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_harvest_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHarvestJobsRequest,
-    ) -> Result<ListHarvestJobsResponse, RusotoError<ListHarvestJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_harvest_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_harvest_jobs_request(&self, input: ListHarvestJobsRequest) -> SignedRequest {
         let request_uri = "/harvest_jobs";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListHarvestJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2846,31 +3352,61 @@ impl MediaPackage for MediaPackageClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        request
+    }
+
+    /// Calls `list_origin_endpoints`, also returning the metadata of the response.
+    pub async fn list_origin_endpoints_with_metadata(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> Result<
+        (ListOriginEndpointsResponse, ResponseMetadata),
+        RusotoError<ListOriginEndpointsError>,
+    > {
+        let request = self.build_list_origin_endpoints_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListHarvestJobsResponse, _>()?;
+                .deserialize::<ListOriginEndpointsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHarvestJobsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListOriginEndpointsError::from_response(response))
         }
     }
 
-    /// <p>Returns a collection of OriginEndpoint records.</p>
-    async fn list_origin_endpoints(
+    /// Presigns the request `list_origin_endpoints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_origin_endpoints(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOriginEndpointsRequest,
-    ) -> Result<ListOriginEndpointsResponse, RusotoError<ListOriginEndpointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_origin_endpoints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_origin_endpoints_request(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> SignedRequest {
         let request_uri = "/origin_endpoints";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListOriginEndpoints");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2885,84 +3421,173 @@ impl MediaPackage for MediaPackageClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListOriginEndpointsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOriginEndpointsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `rotate_channel_credentials`, also returning the metadata of the response.
+    pub async fn rotate_channel_credentials_with_metadata(
+        &self,
+        input: RotateChannelCredentialsRequest,
+    ) -> Result<
+        (RotateChannelCredentialsResponse, ResponseMetadata),
+        RusotoError<RotateChannelCredentialsError>,
+    > {
+        let request = self.build_rotate_channel_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<RotateChannelCredentialsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RotateChannelCredentialsError::from_response(response))
         }
     }
 
-    /// <p>Changes the Channel&#39;s first IngestEndpoint&#39;s username and password. WARNING - This API is deprecated. Please use RotateIngestEndpointCredentials instead</p>
-    async fn rotate_channel_credentials(
+    /// Presigns the request `rotate_channel_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_rotate_channel_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RotateChannelCredentialsRequest,
-    ) -> Result<RotateChannelCredentialsResponse, RusotoError<RotateChannelCredentialsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_rotate_channel_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_rotate_channel_credentials_request(
+        &self,
+        input: RotateChannelCredentialsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/channels/{id}/credentials", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("RotateChannelCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `rotate_ingest_endpoint_credentials`, also returning the metadata of the response.
+    pub async fn rotate_ingest_endpoint_credentials_with_metadata(
+        &self,
+        input: RotateIngestEndpointCredentialsRequest,
+    ) -> Result<
+        (RotateIngestEndpointCredentialsResponse, ResponseMetadata),
+        RusotoError<RotateIngestEndpointCredentialsError>,
+    > {
+        let request = self.build_rotate_ingest_endpoint_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RotateChannelCredentialsResponse, _>()?;
+                .deserialize::<RotateIngestEndpointCredentialsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RotateChannelCredentialsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RotateIngestEndpointCredentialsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Rotate the IngestEndpoint&#39;s username and password, as specified by the IngestEndpoint&#39;s id.</p>
-    async fn rotate_ingest_endpoint_credentials(
+    /// Presigns the request `rotate_ingest_endpoint_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_rotate_ingest_endpoint_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RotateIngestEndpointCredentialsRequest,
-    ) -> Result<
-        RotateIngestEndpointCredentialsResponse,
-        RusotoError<RotateIngestEndpointCredentialsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_rotate_ingest_endpoint_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_rotate_ingest_endpoint_credentials_request(
+        &self,
+        input: RotateIngestEndpointCredentialsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/channels/{id}/ingest_endpoints/{ingest_endpoint_id}/credentials",
             id = input.id,
@@ -2970,62 +3595,110 @@ impl MediaPackage for MediaPackageClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("RotateIngestEndpointCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RotateIngestEndpointCredentialsResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RotateIngestEndpointCredentialsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3034,79 +3707,412 @@ impl MediaPackage for MediaPackageClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_channel`, also returning the metadata of the response.
+    pub async fn update_channel_with_metadata(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<(UpdateChannelResponse, ResponseMetadata), RusotoError<UpdateChannelError>> {
+        let request = self.build_update_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateChannelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateChannelError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing Channel.</p>
-    async fn update_channel(
+    /// Presigns the request `update_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateChannelRequest,
-    ) -> Result<UpdateChannelResponse, RusotoError<UpdateChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_channel_request(&self, input: UpdateChannelRequest) -> SignedRequest {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_origin_endpoint`, also returning the metadata of the response.
+    pub async fn update_origin_endpoint_with_metadata(
+        &self,
+        input: UpdateOriginEndpointRequest,
+    ) -> Result<
+        (UpdateOriginEndpointResponse, ResponseMetadata),
+        RusotoError<UpdateOriginEndpointError>,
+    > {
+        let request = self.build_update_origin_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateChannelResponse, _>()?;
+                .deserialize::<UpdateOriginEndpointResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateChannelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateOriginEndpointError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing OriginEndpoint.</p>
-    async fn update_origin_endpoint(
+    /// Presigns the request `update_origin_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_origin_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateOriginEndpointRequest,
-    ) -> Result<UpdateOriginEndpointResponse, RusotoError<UpdateOriginEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_origin_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_origin_endpoint_request(
+        &self,
+        input: UpdateOriginEndpointRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("UpdateOriginEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListChannels` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_channels_pages(
+        &self,
+        input: ListChannelsRequest,
+    ) -> impl futures::Stream<Item = Result<ListChannelsResponse, RusotoError<ListChannelsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageClient, input| async move { client.list_channels(input).await },
+            |input: &ListChannelsRequest, output: &ListChannelsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListChannelsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Channels` of every page of `ListChannels` results for `input`, as `list_channels_pages` requests them.
+    pub fn list_channels_stream(
+        &self,
+        input: ListChannelsRequest,
+    ) -> impl futures::Stream<Item = Result<Channel, RusotoError<ListChannelsError>>> + Send {
+        rusoto_core::pagination::items(self.list_channels_pages(input), |page| {
+            page.channels.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListHarvestJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_harvest_jobs_pages(
+        &self,
+        input: ListHarvestJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListHarvestJobsResponse, RusotoError<ListHarvestJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageClient, input| async move { client.list_harvest_jobs(input).await },
+            |input: &ListHarvestJobsRequest, output: &ListHarvestJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHarvestJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HarvestJobs` of every page of `ListHarvestJobs` results for `input`, as `list_harvest_jobs_pages` requests them.
+    pub fn list_harvest_jobs_stream(
+        &self,
+        input: ListHarvestJobsRequest,
+    ) -> impl futures::Stream<Item = Result<HarvestJob, RusotoError<ListHarvestJobsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_harvest_jobs_pages(input), |page| {
+            page.harvest_jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOriginEndpoints` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_origin_endpoints_pages(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListOriginEndpointsResponse, RusotoError<ListOriginEndpointsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaPackageClient, input| async move { client.list_origin_endpoints(input).await },
+            |input: &ListOriginEndpointsRequest, output: &ListOriginEndpointsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOriginEndpointsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OriginEndpoints` of every page of `ListOriginEndpoints` results for `input`, as `list_origin_endpoints_pages` requests them.
+    pub fn list_origin_endpoints_stream(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> impl futures::Stream<Item = Result<OriginEndpoint, RusotoError<ListOriginEndpointsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_origin_endpoints_pages(input), |page| {
+            page.origin_endpoints.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl MediaPackage for MediaPackageClient {
+    /// <p>Creates a new Channel.</p>
+    async fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        self.create_channel_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateOriginEndpointResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateOriginEndpointError::from_response(response))
-        }
+    /// <p>Creates a new HarvestJob record.</p>
+    async fn create_harvest_job(
+        &self,
+        input: CreateHarvestJobRequest,
+    ) -> Result<CreateHarvestJobResponse, RusotoError<CreateHarvestJobError>> {
+        self.create_harvest_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new OriginEndpoint record.</p>
+    async fn create_origin_endpoint(
+        &self,
+        input: CreateOriginEndpointRequest,
+    ) -> Result<CreateOriginEndpointResponse, RusotoError<CreateOriginEndpointError>> {
+        self.create_origin_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing Channel.</p>
+    async fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<DeleteChannelResponse, RusotoError<DeleteChannelError>> {
+        self.delete_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing OriginEndpoint.</p>
+    async fn delete_origin_endpoint(
+        &self,
+        input: DeleteOriginEndpointRequest,
+    ) -> Result<DeleteOriginEndpointResponse, RusotoError<DeleteOriginEndpointError>> {
+        self.delete_origin_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details about a Channel.</p>
+    async fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+        self.describe_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details about an existing HarvestJob.</p>
+    async fn describe_harvest_job(
+        &self,
+        input: DescribeHarvestJobRequest,
+    ) -> Result<DescribeHarvestJobResponse, RusotoError<DescribeHarvestJobError>> {
+        self.describe_harvest_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details about an existing OriginEndpoint.</p>
+    async fn describe_origin_endpoint(
+        &self,
+        input: DescribeOriginEndpointRequest,
+    ) -> Result<DescribeOriginEndpointResponse, RusotoError<DescribeOriginEndpointError>> {
+        self.describe_origin_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a collection of Channels.</p>
+    async fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+        self.list_channels_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a collection of HarvestJob records.</p>
+    async fn list_harvest_jobs(
+        &self,
+        input: ListHarvestJobsRequest,
+    ) -> Result<ListHarvestJobsResponse, RusotoError<ListHarvestJobsError>> {
+        self.list_harvest_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a collection of OriginEndpoint records.</p>
+    async fn list_origin_endpoints(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> Result<ListOriginEndpointsResponse, RusotoError<ListOriginEndpointsError>> {
+        self.list_origin_endpoints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the Channel&#39;s first IngestEndpoint&#39;s username and password. WARNING - This API is deprecated. Please use RotateIngestEndpointCredentials instead</p>
+    async fn rotate_channel_credentials(
+        &self,
+        input: RotateChannelCredentialsRequest,
+    ) -> Result<RotateChannelCredentialsResponse, RusotoError<RotateChannelCredentialsError>> {
+        self.rotate_channel_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Rotate the IngestEndpoint&#39;s username and password, as specified by the IngestEndpoint&#39;s id.</p>
+    async fn rotate_ingest_endpoint_credentials(
+        &self,
+        input: RotateIngestEndpointCredentialsRequest,
+    ) -> Result<
+        RotateIngestEndpointCredentialsResponse,
+        RusotoError<RotateIngestEndpointCredentialsError>,
+    > {
+        self.rotate_ingest_endpoint_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing Channel.</p>
+    async fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<UpdateChannelResponse, RusotoError<UpdateChannelError>> {
+        self.update_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing OriginEndpoint.</p>
+    async fn update_origin_endpoint(
+        &self,
+        input: UpdateOriginEndpointRequest,
+    ) -> Result<UpdateOriginEndpointResponse, RusotoError<UpdateOriginEndpointError>> {
+        self.update_origin_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -366,6 +366,7 @@ pub struct UntagResourceOutput {}
 
 /// Errors returned by CreateContainer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateContainerError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -373,6 +374,8 @@ pub enum CreateContainerError {
     InternalServerError(String),
     /// <p>A service limit has been exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateContainerError {
@@ -380,16 +383,22 @@ impl CreateContainerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(CreateContainerError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        CreateContainerError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateContainerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateContainerError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateContainerError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateContainerError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateContainerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -402,12 +411,14 @@ impl fmt::Display for CreateContainerError {
             CreateContainerError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             CreateContainerError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateContainerError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateContainerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateContainerError {}
 /// Errors returned by DeleteContainer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteContainerError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -415,6 +426,8 @@ pub enum DeleteContainerError {
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteContainerError {
@@ -422,16 +435,25 @@ impl DeleteContainerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteContainerError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteContainerError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteContainerError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteContainerError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteContainerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteContainerError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteContainerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -444,12 +466,14 @@ impl fmt::Display for DeleteContainerError {
             DeleteContainerError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             DeleteContainerError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             DeleteContainerError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteContainerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteContainerError {}
 /// Errors returned by DeleteContainerPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteContainerPolicyError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -459,6 +483,8 @@ pub enum DeleteContainerPolicyError {
     InternalServerError(String),
     /// <p>The policy that you specified in the request does not exist.</p>
     PolicyNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteContainerPolicyError {
@@ -466,27 +492,31 @@ impl DeleteContainerPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::ContainerInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteContainerPolicyError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteContainerPolicyError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteContainerPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::PolicyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteContainerPolicyError::PolicyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteContainerPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -500,12 +530,14 @@ impl fmt::Display for DeleteContainerPolicyError {
             DeleteContainerPolicyError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             DeleteContainerPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteContainerPolicyError::PolicyNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteContainerPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteContainerPolicyError {}
 /// Errors returned by DeleteCorsPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCorsPolicyError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -515,6 +547,8 @@ pub enum DeleteCorsPolicyError {
     CorsPolicyNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCorsPolicyError {
@@ -522,21 +556,31 @@ impl DeleteCorsPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteCorsPolicyError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteCorsPolicyError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CorsPolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::CorsPolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteCorsPolicyError::CorsPolicyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCorsPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCorsPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -550,12 +594,14 @@ impl fmt::Display for DeleteCorsPolicyError {
             DeleteCorsPolicyError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             DeleteCorsPolicyError::CorsPolicyNotFound(ref cause) => write!(f, "{}", cause),
             DeleteCorsPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteCorsPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCorsPolicyError {}
 /// Errors returned by DeleteLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLifecyclePolicyError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -565,6 +611,8 @@ pub enum DeleteLifecyclePolicyError {
     InternalServerError(String),
     /// <p>The policy that you specified in the request does not exist.</p>
     PolicyNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLifecyclePolicyError {
@@ -572,27 +620,31 @@ impl DeleteLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::ContainerInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLifecyclePolicyError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLifecyclePolicyError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLifecyclePolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::PolicyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLifecyclePolicyError::PolicyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -606,17 +658,21 @@ impl fmt::Display for DeleteLifecyclePolicyError {
             DeleteLifecyclePolicyError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             DeleteLifecyclePolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteLifecyclePolicyError::PolicyNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLifecyclePolicyError {}
 /// Errors returned by DescribeContainer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeContainerError {
     /// <p>The container that you specified in the request does not exist.</p>
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeContainerError {
@@ -624,15 +680,19 @@ impl DescribeContainerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DescribeContainerError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeContainerError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeContainerError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeContainerError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeContainerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -644,12 +704,14 @@ impl fmt::Display for DescribeContainerError {
         match *self {
             DescribeContainerError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             DescribeContainerError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeContainerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeContainerError {}
 /// Errors returned by GetContainerPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetContainerPolicyError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -659,6 +721,8 @@ pub enum GetContainerPolicyError {
     InternalServerError(String),
     /// <p>The policy that you specified in the request does not exist.</p>
     PolicyNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetContainerPolicyError {
@@ -666,23 +730,31 @@ impl GetContainerPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetContainerPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        GetContainerPolicyError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetContainerPolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContainerPolicyError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetContainerPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContainerPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(GetContainerPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        GetContainerPolicyError::PolicyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetContainerPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -696,12 +768,14 @@ impl fmt::Display for GetContainerPolicyError {
             GetContainerPolicyError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             GetContainerPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetContainerPolicyError::PolicyNotFound(ref cause) => write!(f, "{}", cause),
+            GetContainerPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetContainerPolicyError {}
 /// Errors returned by GetCorsPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCorsPolicyError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -711,6 +785,8 @@ pub enum GetCorsPolicyError {
     CorsPolicyNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCorsPolicyError {
@@ -718,19 +794,28 @@ impl GetCorsPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetCorsPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(GetCorsPolicyError::ContainerInUse(err.msg), &res)
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetCorsPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        GetCorsPolicyError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CorsPolicyNotFoundException" => {
-                    return RusotoError::Service(GetCorsPolicyError::CorsPolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        GetCorsPolicyError::CorsPolicyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCorsPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetCorsPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCorsPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -744,12 +829,14 @@ impl fmt::Display for GetCorsPolicyError {
             GetCorsPolicyError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             GetCorsPolicyError::CorsPolicyNotFound(ref cause) => write!(f, "{}", cause),
             GetCorsPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetCorsPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCorsPolicyError {}
 /// Errors returned by GetLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLifecyclePolicyError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -759,6 +846,8 @@ pub enum GetLifecyclePolicyError {
     InternalServerError(String),
     /// <p>The policy that you specified in the request does not exist.</p>
     PolicyNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLifecyclePolicyError {
@@ -766,23 +855,31 @@ impl GetLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::PolicyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -796,15 +893,19 @@ impl fmt::Display for GetLifecyclePolicyError {
             GetLifecyclePolicyError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             GetLifecyclePolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetLifecyclePolicyError::PolicyNotFound(ref cause) => write!(f, "{}", cause),
+            GetLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLifecyclePolicyError {}
 /// Errors returned by ListContainers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListContainersError {
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListContainersError {
@@ -812,10 +913,13 @@ impl ListContainersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListContainersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListContainersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListContainersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -826,12 +930,14 @@ impl fmt::Display for ListContainersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListContainersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListContainersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListContainersError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -839,6 +945,8 @@ pub enum ListTagsForResourceError {
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -846,20 +954,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -872,12 +985,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutContainerPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutContainerPolicyError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -885,6 +1000,8 @@ pub enum PutContainerPolicyError {
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutContainerPolicyError {
@@ -892,20 +1009,25 @@ impl PutContainerPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutContainerPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        PutContainerPolicyError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutContainerPolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutContainerPolicyError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutContainerPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutContainerPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutContainerPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -918,12 +1040,14 @@ impl fmt::Display for PutContainerPolicyError {
             PutContainerPolicyError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             PutContainerPolicyError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             PutContainerPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            PutContainerPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutContainerPolicyError {}
 /// Errors returned by PutCorsPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutCorsPolicyError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -931,6 +1055,8 @@ pub enum PutCorsPolicyError {
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutCorsPolicyError {
@@ -938,16 +1064,22 @@ impl PutCorsPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutCorsPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(PutCorsPolicyError::ContainerInUse(err.msg), &res)
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutCorsPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        PutCorsPolicyError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutCorsPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        PutCorsPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutCorsPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -960,12 +1092,14 @@ impl fmt::Display for PutCorsPolicyError {
             PutCorsPolicyError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             PutCorsPolicyError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             PutCorsPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            PutCorsPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutCorsPolicyError {}
 /// Errors returned by PutLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLifecyclePolicyError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -973,6 +1107,8 @@ pub enum PutLifecyclePolicyError {
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLifecyclePolicyError {
@@ -980,20 +1116,25 @@ impl PutLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        PutLifecyclePolicyError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLifecyclePolicyError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLifecyclePolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1006,12 +1147,14 @@ impl fmt::Display for PutLifecyclePolicyError {
             PutLifecyclePolicyError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             PutLifecyclePolicyError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             PutLifecyclePolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            PutLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutLifecyclePolicyError {}
 /// Errors returned by StartAccessLogging
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartAccessLoggingError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -1019,6 +1162,8 @@ pub enum StartAccessLoggingError {
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartAccessLoggingError {
@@ -1026,20 +1171,25 @@ impl StartAccessLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(StartAccessLoggingError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        StartAccessLoggingError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(StartAccessLoggingError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartAccessLoggingError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartAccessLoggingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartAccessLoggingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartAccessLoggingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1052,12 +1202,14 @@ impl fmt::Display for StartAccessLoggingError {
             StartAccessLoggingError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             StartAccessLoggingError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             StartAccessLoggingError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            StartAccessLoggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartAccessLoggingError {}
 /// Errors returned by StopAccessLogging
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopAccessLoggingError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -1065,6 +1217,8 @@ pub enum StopAccessLoggingError {
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopAccessLoggingError {
@@ -1072,18 +1226,25 @@ impl StopAccessLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(StopAccessLoggingError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        StopAccessLoggingError::ContainerInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(StopAccessLoggingError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        StopAccessLoggingError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopAccessLoggingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopAccessLoggingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopAccessLoggingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1096,12 +1257,14 @@ impl fmt::Display for StopAccessLoggingError {
             StopAccessLoggingError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             StopAccessLoggingError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             StopAccessLoggingError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            StopAccessLoggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopAccessLoggingError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -1109,6 +1272,8 @@ pub enum TagResourceError {
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1116,16 +1281,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(TagResourceError::ContainerInUse(err.msg))
+                    return RusotoError::service(TagResourceError::ContainerInUse(err.msg), &res)
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ContainerNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ContainerNotFound(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1138,12 +1306,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             TagResourceError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The container that you specified in the request already exists or is being updated.</p>
     ContainerInUse(String),
@@ -1151,6 +1321,8 @@ pub enum UntagResourceError {
     ContainerNotFound(String),
     /// <p>The service is temporarily unavailable.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1158,16 +1330,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ContainerInUse(err.msg))
+                    return RusotoError::service(UntagResourceError::ContainerInUse(err.msg), &res)
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ContainerNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1180,6 +1358,7 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::ContainerInUse(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ContainerNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1306,6 +1485,7 @@ impl MediaStoreClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MediaStoreClient {
         MediaStoreClient {
             client: Client::shared(),
@@ -1331,501 +1511,1198 @@ impl MediaStoreClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MediaStoreClient {
         MediaStoreClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MediaStoreClient> {
+        rusoto_core::ClientBuilder::new(MediaStoreClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MediaStoreClient {
+        MediaStoreClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MediaStore for MediaStoreClient {
-    /// <p>Creates a storage container to hold objects. A container is similar to a bucket in the Amazon S3 service.</p>
-    async fn create_container(
+impl MediaStoreClient {
+    /// Calls `create_container`, also returning the metadata of the response.
+    pub async fn create_container_with_metadata(
         &self,
         input: CreateContainerInput,
-    ) -> Result<CreateContainerOutput, RusotoError<CreateContainerError>> {
+    ) -> Result<(CreateContainerOutput, ResponseMetadata), RusotoError<CreateContainerError>> {
+        let request = self.build_create_container_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateContainerOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateContainerError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_container` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_container(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateContainerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_container_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_container_request(&self, input: CreateContainerInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("CreateContainer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.CreateContainer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_container`, also returning the metadata of the response.
+    pub async fn delete_container_with_metadata(
+        &self,
+        input: DeleteContainerInput,
+    ) -> Result<(DeleteContainerOutput, ResponseMetadata), RusotoError<DeleteContainerError>> {
+        let request = self.build_delete_container_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateContainerOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteContainerOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateContainerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteContainerError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified container. Before you make a <code>DeleteContainer</code> request, delete any objects in the container or in any folders in the container. You can delete only empty containers. </p>
-    async fn delete_container(
+    /// Presigns the request `delete_container` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_container(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteContainerInput,
-    ) -> Result<DeleteContainerOutput, RusotoError<DeleteContainerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_container_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_container_request(&self, input: DeleteContainerInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteContainer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_container_policy`, also returning the metadata of the response.
+    pub async fn delete_container_policy_with_metadata(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> Result<
+        (DeleteContainerPolicyOutput, ResponseMetadata),
+        RusotoError<DeleteContainerPolicyError>,
+    > {
+        let request = self.build_delete_container_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteContainerOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteContainerPolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteContainerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteContainerPolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the access policy that is associated with the specified container.</p>
-    async fn delete_container_policy(
+    /// Presigns the request `delete_container_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_container_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteContainerPolicyInput,
-    ) -> Result<DeleteContainerPolicyOutput, RusotoError<DeleteContainerPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_container_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_container_policy_request(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteContainerPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainerPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_cors_policy`, also returning the metadata of the response.
+    pub async fn delete_cors_policy_with_metadata(
+        &self,
+        input: DeleteCorsPolicyInput,
+    ) -> Result<(DeleteCorsPolicyOutput, ResponseMetadata), RusotoError<DeleteCorsPolicyError>>
+    {
+        let request = self.build_delete_cors_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteContainerPolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteCorsPolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteContainerPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCorsPolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the cross-origin resource sharing (CORS) configuration information that is set for the container.</p> <p>To use this operation, you must have permission to perform the <code>MediaStore:DeleteCorsPolicy</code> action. The container owner has this permission by default and can grant this permission to others.</p>
-    async fn delete_cors_policy(
+    /// Presigns the request `delete_cors_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cors_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCorsPolicyInput,
-    ) -> Result<DeleteCorsPolicyOutput, RusotoError<DeleteCorsPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cors_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cors_policy_request(&self, input: DeleteCorsPolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteCorsPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteCorsPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn delete_lifecycle_policy_with_metadata(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> Result<
+        (DeleteLifecyclePolicyOutput, ResponseMetadata),
+        RusotoError<DeleteLifecyclePolicyError>,
+    > {
+        let request = self.build_delete_lifecycle_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteCorsPolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLifecyclePolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCorsPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLifecyclePolicyError::from_response(response))
         }
     }
 
-    /// <p>Removes an object lifecycle policy from a container. It takes up to 20 minutes for the change to take effect.</p>
-    async fn delete_lifecycle_policy(
+    /// Presigns the request `delete_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_lifecycle_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLifecyclePolicyInput,
-    ) -> Result<DeleteLifecyclePolicyOutput, RusotoError<DeleteLifecyclePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_lifecycle_policy_request(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteLifecyclePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteLifecyclePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_container`, also returning the metadata of the response.
+    pub async fn describe_container_with_metadata(
+        &self,
+        input: DescribeContainerInput,
+    ) -> Result<(DescribeContainerOutput, ResponseMetadata), RusotoError<DescribeContainerError>>
+    {
+        let request = self.build_describe_container_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLifecyclePolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeContainerOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLifecyclePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeContainerError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the properties of the requested container. This request is commonly used to retrieve the endpoint of a container. An endpoint is a value assigned by the service when a new container is created. A container's endpoint does not change after it has been assigned. The <code>DescribeContainer</code> request returns a single <code>Container</code> object based on <code>ContainerName</code>. To return all <code>Container</code> objects that are associated with a specified AWS account, use <a>ListContainers</a>.</p>
-    async fn describe_container(
+    /// Presigns the request `describe_container` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_container(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeContainerInput,
-    ) -> Result<DescribeContainerOutput, RusotoError<DescribeContainerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_container_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_container_request(&self, input: DescribeContainerInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DescribeContainer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.DescribeContainer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_container_policy`, also returning the metadata of the response.
+    pub async fn get_container_policy_with_metadata(
+        &self,
+        input: GetContainerPolicyInput,
+    ) -> Result<(GetContainerPolicyOutput, ResponseMetadata), RusotoError<GetContainerPolicyError>>
+    {
+        let request = self.build_get_container_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeContainerOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetContainerPolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeContainerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetContainerPolicyError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the access policy for the specified container. For information about the data that is included in an access policy, see the <a href="https://aws.amazon.com/documentation/iam/">AWS Identity and Access Management User Guide</a>.</p>
-    async fn get_container_policy(
+    /// Presigns the request `get_container_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_container_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetContainerPolicyInput,
-    ) -> Result<GetContainerPolicyOutput, RusotoError<GetContainerPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_container_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_container_policy_request(&self, input: GetContainerPolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("GetContainerPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.GetContainerPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_cors_policy`, also returning the metadata of the response.
+    pub async fn get_cors_policy_with_metadata(
+        &self,
+        input: GetCorsPolicyInput,
+    ) -> Result<(GetCorsPolicyOutput, ResponseMetadata), RusotoError<GetCorsPolicyError>> {
+        let request = self.build_get_cors_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetContainerPolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCorsPolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetContainerPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCorsPolicyError::from_response(response))
         }
     }
 
-    /// <p>Returns the cross-origin resource sharing (CORS) configuration information that is set for the container.</p> <p>To use this operation, you must have permission to perform the <code>MediaStore:GetCorsPolicy</code> action. By default, the container owner has this permission and can grant it to others.</p>
-    async fn get_cors_policy(
+    /// Presigns the request `get_cors_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cors_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCorsPolicyInput,
-    ) -> Result<GetCorsPolicyOutput, RusotoError<GetCorsPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cors_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_cors_policy_request(&self, input: GetCorsPolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("GetCorsPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.GetCorsPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn get_lifecycle_policy_with_metadata(
+        &self,
+        input: GetLifecyclePolicyInput,
+    ) -> Result<(GetLifecyclePolicyOutput, ResponseMetadata), RusotoError<GetLifecyclePolicyError>>
+    {
+        let request = self.build_get_lifecycle_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCorsPolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLifecyclePolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCorsPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLifecyclePolicyError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the object lifecycle policy that is assigned to a container.</p>
-    async fn get_lifecycle_policy(
+    /// Presigns the request `get_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_lifecycle_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLifecyclePolicyInput,
-    ) -> Result<GetLifecyclePolicyOutput, RusotoError<GetLifecyclePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_lifecycle_policy_request(&self, input: GetLifecyclePolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("GetLifecyclePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.GetLifecyclePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_containers`, also returning the metadata of the response.
+    pub async fn list_containers_with_metadata(
+        &self,
+        input: ListContainersInput,
+    ) -> Result<(ListContainersOutput, ResponseMetadata), RusotoError<ListContainersError>> {
+        let request = self.build_list_containers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLifecyclePolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListContainersOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLifecyclePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListContainersError::from_response(response))
         }
     }
 
-    /// <p>Lists the properties of all containers in AWS Elemental MediaStore. </p> <p>You can query to receive all the containers in one response. Or you can include the <code>MaxResults</code> parameter to receive a limited number of containers in each response. In this case, the response includes a token. To get the next set of containers, send the command again, this time with the <code>NextToken</code> parameter (with the returned token as its value). The next set of responses appears, with a token if there are still more containers to receive. </p> <p>See also <a>DescribeContainer</a>, which gets the properties of one container. </p>
-    async fn list_containers(
+    /// Presigns the request `list_containers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_containers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListContainersInput,
-    ) -> Result<ListContainersOutput, RusotoError<ListContainersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_containers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_containers_request(&self, input: ListContainersInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("ListContainers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.ListContainers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListContainersOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListContainersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the tags assigned to the specified container. </p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_container_policy`, also returning the metadata of the response.
+    pub async fn put_container_policy_with_metadata(
+        &self,
+        input: PutContainerPolicyInput,
+    ) -> Result<(PutContainerPolicyOutput, ResponseMetadata), RusotoError<PutContainerPolicyError>>
+    {
+        let request = self.build_put_container_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutContainerPolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutContainerPolicyError::from_response(response))
         }
     }
 
-    /// <p>Creates an access policy for the specified container to restrict the users and clients that can access it. For information about the data that is included in an access policy, see the <a href="https://aws.amazon.com/documentation/iam/">AWS Identity and Access Management User Guide</a>.</p> <p>For this release of the REST API, you can create only one policy for a container. If you enter <code>PutContainerPolicy</code> twice, the second command modifies the existing policy. </p>
-    async fn put_container_policy(
+    /// Presigns the request `put_container_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_container_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutContainerPolicyInput,
-    ) -> Result<PutContainerPolicyOutput, RusotoError<PutContainerPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_container_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_container_policy_request(&self, input: PutContainerPolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("PutContainerPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.PutContainerPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_cors_policy`, also returning the metadata of the response.
+    pub async fn put_cors_policy_with_metadata(
+        &self,
+        input: PutCorsPolicyInput,
+    ) -> Result<(PutCorsPolicyOutput, ResponseMetadata), RusotoError<PutCorsPolicyError>> {
+        let request = self.build_put_cors_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutContainerPolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutCorsPolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutContainerPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutCorsPolicyError::from_response(response))
         }
     }
 
-    /// <p>Sets the cross-origin resource sharing (CORS) configuration on a container so that the container can service cross-origin requests. For example, you might want to enable a request whose origin is http://www.example.com to access your AWS Elemental MediaStore container at my.example.container.com by using the browser's XMLHttpRequest capability.</p> <p>To enable CORS on a container, you attach a CORS policy to the container. In the CORS policy, you configure rules that identify origins and the HTTP methods that can be executed on your container. The policy can contain up to 398,000 characters. You can add up to 100 rules to a CORS policy. If more than one rule applies, the service uses the first applicable rule listed.</p> <p>To learn more about CORS, see <a href="https://docs.aws.amazon.com/mediastore/latest/ug/cors-policy.html">Cross-Origin Resource Sharing (CORS) in AWS Elemental MediaStore</a>.</p>
-    async fn put_cors_policy(
+    /// Presigns the request `put_cors_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_cors_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutCorsPolicyInput,
-    ) -> Result<PutCorsPolicyOutput, RusotoError<PutCorsPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_cors_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_cors_policy_request(&self, input: PutCorsPolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("PutCorsPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.PutCorsPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn put_lifecycle_policy_with_metadata(
+        &self,
+        input: PutLifecyclePolicyInput,
+    ) -> Result<(PutLifecyclePolicyOutput, ResponseMetadata), RusotoError<PutLifecyclePolicyError>>
+    {
+        let request = self.build_put_lifecycle_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutCorsPolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutLifecyclePolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutCorsPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutLifecyclePolicyError::from_response(response))
         }
     }
 
-    /// <p>Writes an object lifecycle policy to a container. If the container already has an object lifecycle policy, the service replaces the existing policy with the new policy. It takes up to 20 minutes for the change to take effect.</p> <p>For information about how to construct an object lifecycle policy, see <a href="https://docs.aws.amazon.com/mediastore/latest/ug/policies-object-lifecycle-components.html">Components of an Object Lifecycle Policy</a>.</p>
-    async fn put_lifecycle_policy(
+    /// Presigns the request `put_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_lifecycle_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutLifecyclePolicyInput,
-    ) -> Result<PutLifecyclePolicyOutput, RusotoError<PutLifecyclePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_lifecycle_policy_request(&self, input: PutLifecyclePolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("PutLifecyclePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.PutLifecyclePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_access_logging`, also returning the metadata of the response.
+    pub async fn start_access_logging_with_metadata(
+        &self,
+        input: StartAccessLoggingInput,
+    ) -> Result<(StartAccessLoggingOutput, ResponseMetadata), RusotoError<StartAccessLoggingError>>
+    {
+        let request = self.build_start_access_logging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutLifecyclePolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartAccessLoggingOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutLifecyclePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartAccessLoggingError::from_response(response))
         }
     }
 
-    /// <p>Starts access logging on the specified container. When you enable access logging on a container, MediaStore delivers access logs for objects stored in that container to Amazon CloudWatch Logs.</p>
-    async fn start_access_logging(
+    /// Presigns the request `start_access_logging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_access_logging(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartAccessLoggingInput,
-    ) -> Result<StartAccessLoggingOutput, RusotoError<StartAccessLoggingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_access_logging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_access_logging_request(&self, input: StartAccessLoggingInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("StartAccessLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.StartAccessLogging");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_access_logging`, also returning the metadata of the response.
+    pub async fn stop_access_logging_with_metadata(
+        &self,
+        input: StopAccessLoggingInput,
+    ) -> Result<(StopAccessLoggingOutput, ResponseMetadata), RusotoError<StopAccessLoggingError>>
+    {
+        let request = self.build_stop_access_logging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartAccessLoggingOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopAccessLoggingOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartAccessLoggingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopAccessLoggingError::from_response(response))
         }
     }
 
-    /// <p>Stops access logging on the specified container. When you stop access logging on a container, MediaStore stops sending access logs to Amazon CloudWatch Logs. These access logs are not saved and are not retrievable.</p>
-    async fn stop_access_logging(
+    /// Presigns the request `stop_access_logging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_access_logging(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopAccessLoggingInput,
-    ) -> Result<StopAccessLoggingOutput, RusotoError<StopAccessLoggingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_access_logging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_access_logging_request(&self, input: StopAccessLoggingInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("StopAccessLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.StopAccessLogging");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(TagResourceOutput, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopAccessLoggingOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopAccessLoggingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds tags to the specified AWS Elemental MediaStore container. Tags are key:value pairs that you can associate with AWS resources. For example, the tag key might be "customer" and the tag value might be "companyA." You can specify one or more tags to add to each container. You can add up to 50 tags to each container. For more information about tagging, including naming and usage conventions, see <a href="https://docs.aws.amazon.com/mediastore/latest/ug/tagging.html">Tagging Resources in MediaStore</a>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(UntagResourceOutput, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from the specified container. You can specify one or more tags to remove. </p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListContainers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_containers_pages(
+        &self,
+        input: ListContainersInput,
+    ) -> impl futures::Stream<Item = Result<ListContainersOutput, RusotoError<ListContainersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaStoreClient, input| async move { client.list_containers(input).await },
+            |input: &ListContainersInput, output: &ListContainersOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListContainersInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Containers` of every page of `ListContainers` results for `input`, as `list_containers_pages` requests them.
+    pub fn list_containers_stream(
+        &self,
+        input: ListContainersInput,
+    ) -> impl futures::Stream<Item = Result<Container, RusotoError<ListContainersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_containers_pages(input), |page| page.containers)
+    }
+}
+
+#[async_trait]
+impl MediaStore for MediaStoreClient {
+    /// <p>Creates a storage container to hold objects. A container is similar to a bucket in the Amazon S3 service.</p>
+    async fn create_container(
+        &self,
+        input: CreateContainerInput,
+    ) -> Result<CreateContainerOutput, RusotoError<CreateContainerError>> {
+        self.create_container_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified container. Before you make a <code>DeleteContainer</code> request, delete any objects in the container or in any folders in the container. You can delete only empty containers. </p>
+    async fn delete_container(
+        &self,
+        input: DeleteContainerInput,
+    ) -> Result<DeleteContainerOutput, RusotoError<DeleteContainerError>> {
+        self.delete_container_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the access policy that is associated with the specified container.</p>
+    async fn delete_container_policy(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> Result<DeleteContainerPolicyOutput, RusotoError<DeleteContainerPolicyError>> {
+        self.delete_container_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the cross-origin resource sharing (CORS) configuration information that is set for the container.</p> <p>To use this operation, you must have permission to perform the <code>MediaStore:DeleteCorsPolicy</code> action. The container owner has this permission by default and can grant this permission to others.</p>
+    async fn delete_cors_policy(
+        &self,
+        input: DeleteCorsPolicyInput,
+    ) -> Result<DeleteCorsPolicyOutput, RusotoError<DeleteCorsPolicyError>> {
+        self.delete_cors_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes an object lifecycle policy from a container. It takes up to 20 minutes for the change to take effect.</p>
+    async fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> Result<DeleteLifecyclePolicyOutput, RusotoError<DeleteLifecyclePolicyError>> {
+        self.delete_lifecycle_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the properties of the requested container. This request is commonly used to retrieve the endpoint of a container. An endpoint is a value assigned by the service when a new container is created. A container's endpoint does not change after it has been assigned. The <code>DescribeContainer</code> request returns a single <code>Container</code> object based on <code>ContainerName</code>. To return all <code>Container</code> objects that are associated with a specified AWS account, use <a>ListContainers</a>.</p>
+    async fn describe_container(
+        &self,
+        input: DescribeContainerInput,
+    ) -> Result<DescribeContainerOutput, RusotoError<DescribeContainerError>> {
+        self.describe_container_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the access policy for the specified container. For information about the data that is included in an access policy, see the <a href="https://aws.amazon.com/documentation/iam/">AWS Identity and Access Management User Guide</a>.</p>
+    async fn get_container_policy(
+        &self,
+        input: GetContainerPolicyInput,
+    ) -> Result<GetContainerPolicyOutput, RusotoError<GetContainerPolicyError>> {
+        self.get_container_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the cross-origin resource sharing (CORS) configuration information that is set for the container.</p> <p>To use this operation, you must have permission to perform the <code>MediaStore:GetCorsPolicy</code> action. By default, the container owner has this permission and can grant it to others.</p>
+    async fn get_cors_policy(
+        &self,
+        input: GetCorsPolicyInput,
+    ) -> Result<GetCorsPolicyOutput, RusotoError<GetCorsPolicyError>> {
+        self.get_cors_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the object lifecycle policy that is assigned to a container.</p>
+    async fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyInput,
+    ) -> Result<GetLifecyclePolicyOutput, RusotoError<GetLifecyclePolicyError>> {
+        self.get_lifecycle_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the properties of all containers in AWS Elemental MediaStore. </p> <p>You can query to receive all the containers in one response. Or you can include the <code>MaxResults</code> parameter to receive a limited number of containers in each response. In this case, the response includes a token. To get the next set of containers, send the command again, this time with the <code>NextToken</code> parameter (with the returned token as its value). The next set of responses appears, with a token if there are still more containers to receive. </p> <p>See also <a>DescribeContainer</a>, which gets the properties of one container. </p>
+    async fn list_containers(
+        &self,
+        input: ListContainersInput,
+    ) -> Result<ListContainersOutput, RusotoError<ListContainersError>> {
+        self.list_containers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the tags assigned to the specified container. </p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an access policy for the specified container to restrict the users and clients that can access it. For information about the data that is included in an access policy, see the <a href="https://aws.amazon.com/documentation/iam/">AWS Identity and Access Management User Guide</a>.</p> <p>For this release of the REST API, you can create only one policy for a container. If you enter <code>PutContainerPolicy</code> twice, the second command modifies the existing policy. </p>
+    async fn put_container_policy(
+        &self,
+        input: PutContainerPolicyInput,
+    ) -> Result<PutContainerPolicyOutput, RusotoError<PutContainerPolicyError>> {
+        self.put_container_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the cross-origin resource sharing (CORS) configuration on a container so that the container can service cross-origin requests. For example, you might want to enable a request whose origin is http://www.example.com to access your AWS Elemental MediaStore container at my.example.container.com by using the browser's XMLHttpRequest capability.</p> <p>To enable CORS on a container, you attach a CORS policy to the container. In the CORS policy, you configure rules that identify origins and the HTTP methods that can be executed on your container. The policy can contain up to 398,000 characters. You can add up to 100 rules to a CORS policy. If more than one rule applies, the service uses the first applicable rule listed.</p> <p>To learn more about CORS, see <a href="https://docs.aws.amazon.com/mediastore/latest/ug/cors-policy.html">Cross-Origin Resource Sharing (CORS) in AWS Elemental MediaStore</a>.</p>
+    async fn put_cors_policy(
+        &self,
+        input: PutCorsPolicyInput,
+    ) -> Result<PutCorsPolicyOutput, RusotoError<PutCorsPolicyError>> {
+        self.put_cors_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Writes an object lifecycle policy to a container. If the container already has an object lifecycle policy, the service replaces the existing policy with the new policy. It takes up to 20 minutes for the change to take effect.</p> <p>For information about how to construct an object lifecycle policy, see <a href="https://docs.aws.amazon.com/mediastore/latest/ug/policies-object-lifecycle-components.html">Components of an Object Lifecycle Policy</a>.</p>
+    async fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyInput,
+    ) -> Result<PutLifecyclePolicyOutput, RusotoError<PutLifecyclePolicyError>> {
+        self.put_lifecycle_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts access logging on the specified container. When you enable access logging on a container, MediaStore delivers access logs for objects stored in that container to Amazon CloudWatch Logs.</p>
+    async fn start_access_logging(
+        &self,
+        input: StartAccessLoggingInput,
+    ) -> Result<StartAccessLoggingOutput, RusotoError<StartAccessLoggingError>> {
+        self.start_access_logging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops access logging on the specified container. When you stop access logging on a container, MediaStore stops sending access logs to Amazon CloudWatch Logs. These access logs are not saved and are not retrievable.</p>
+    async fn stop_access_logging(
+        &self,
+        input: StopAccessLoggingInput,
+    ) -> Result<StopAccessLoggingOutput, RusotoError<StopAccessLoggingError>> {
+        self.stop_access_logging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tags to the specified AWS Elemental MediaStore container. Tags are key:value pairs that you can associate with AWS resources. For example, the tag key might be "customer" and the tag value might be "companyA." You can specify one or more tags to add to each container. You can add up to 50 tags to each container. For more information about tagging, including naming and usage conventions, see <a href="https://docs.aws.amazon.com/mediastore/latest/ug/tagging.html">Tagging Resources in MediaStore</a>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from the specified container. You can specify one or more tags to remove. </p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -399,7 +399,11 @@ pub struct UntagResourceRequest {
 
 /// Errors returned by DeletePlaybackConfiguration
 #[derive(Debug, PartialEq)]
-pub enum DeletePlaybackConfigurationError {}
+#[non_exhaustive]
+pub enum DeletePlaybackConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeletePlaybackConfigurationError {
     pub fn from_response(
@@ -408,7 +412,12 @@ impl DeletePlaybackConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeletePlaybackConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -417,20 +426,26 @@ impl DeletePlaybackConfigurationError {
 impl fmt::Display for DeletePlaybackConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeletePlaybackConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeletePlaybackConfigurationError {}
 /// Errors returned by GetPlaybackConfiguration
 #[derive(Debug, PartialEq)]
-pub enum GetPlaybackConfigurationError {}
+#[non_exhaustive]
+pub enum GetPlaybackConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetPlaybackConfigurationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetPlaybackConfigurationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPlaybackConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -439,13 +454,19 @@ impl GetPlaybackConfigurationError {
 impl fmt::Display for GetPlaybackConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetPlaybackConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetPlaybackConfigurationError {}
 /// Errors returned by ListPlaybackConfigurations
 #[derive(Debug, PartialEq)]
-pub enum ListPlaybackConfigurationsError {}
+#[non_exhaustive]
+pub enum ListPlaybackConfigurationsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListPlaybackConfigurationsError {
     pub fn from_response(
@@ -454,7 +475,9 @@ impl ListPlaybackConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListPlaybackConfigurationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -463,15 +486,20 @@ impl ListPlaybackConfigurationsError {
 impl fmt::Display for ListPlaybackConfigurationsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListPlaybackConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListPlaybackConfigurationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Invalid request parameters.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -479,10 +507,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -493,20 +524,25 @@ impl fmt::Display for ListTagsForResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutPlaybackConfiguration
 #[derive(Debug, PartialEq)]
-pub enum PutPlaybackConfigurationError {}
+#[non_exhaustive]
+pub enum PutPlaybackConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutPlaybackConfigurationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutPlaybackConfigurationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutPlaybackConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -515,15 +551,20 @@ impl PutPlaybackConfigurationError {
 impl fmt::Display for PutPlaybackConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutPlaybackConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutPlaybackConfigurationError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Invalid request parameters.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -531,10 +572,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -545,15 +586,19 @@ impl fmt::Display for TagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Invalid request parameters.</p>
     BadRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -561,10 +606,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -575,6 +620,7 @@ impl fmt::Display for UntagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -635,6 +681,7 @@ impl MediaTailorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MediaTailorClient {
         MediaTailorClient {
             client: Client::shared(),
@@ -660,78 +707,194 @@ impl MediaTailorClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MediaTailorClient {
         MediaTailorClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MediaTailorClient> {
+        rusoto_core::ClientBuilder::new(MediaTailorClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MediaTailorClient {
+        MediaTailorClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MediaTailor for MediaTailorClient {
-    /// <p>Deletes the playback configuration for the specified name. </p>
-    async fn delete_playback_configuration(
+impl MediaTailorClient {
+    /// Calls `delete_playback_configuration`, also returning the metadata of the response.
+    pub async fn delete_playback_configuration_with_metadata(
         &self,
         input: DeletePlaybackConfigurationRequest,
-    ) -> Result<DeletePlaybackConfigurationResponse, RusotoError<DeletePlaybackConfigurationError>>
-    {
+    ) -> Result<
+        (DeletePlaybackConfigurationResponse, ResponseMetadata),
+        RusotoError<DeletePlaybackConfigurationError>,
+    > {
+        let request = self.build_delete_playback_configuration_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeletePlaybackConfigurationResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeletePlaybackConfigurationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_playback_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_playback_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeletePlaybackConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_playback_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_playback_configuration_request(
+        &self,
+        input: DeletePlaybackConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/playbackConfiguration/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "mediatailor", &self.region, &request_uri);
+        request.set_operation("DeletePlaybackConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
 
+        request
+    }
+
+    /// Calls `get_playback_configuration`, also returning the metadata of the response.
+    pub async fn get_playback_configuration_with_metadata(
+        &self,
+        input: GetPlaybackConfigurationRequest,
+    ) -> Result<
+        (GetPlaybackConfigurationResponse, ResponseMetadata),
+        RusotoError<GetPlaybackConfigurationError>,
+    > {
+        let request = self.build_get_playback_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePlaybackConfigurationResponse, _>()?;
+                .deserialize::<GetPlaybackConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePlaybackConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetPlaybackConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Returns the playback configuration for the specified name. </p>
-    async fn get_playback_configuration(
+    /// Presigns the request `get_playback_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_playback_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPlaybackConfigurationRequest,
-    ) -> Result<GetPlaybackConfigurationResponse, RusotoError<GetPlaybackConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_playback_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_playback_configuration_request(
+        &self,
+        input: GetPlaybackConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/playbackConfiguration/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediatailor", &self.region, &request_uri);
+        request.set_operation("GetPlaybackConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
 
+        request
+    }
+
+    /// Calls `list_playback_configurations`, also returning the metadata of the response.
+    pub async fn list_playback_configurations_with_metadata(
+        &self,
+        input: ListPlaybackConfigurationsRequest,
+    ) -> Result<
+        (ListPlaybackConfigurationsResponse, ResponseMetadata),
+        RusotoError<ListPlaybackConfigurationsError>,
+    > {
+        let request = self.build_list_playback_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPlaybackConfigurationResponse, _>()?;
+                .deserialize::<ListPlaybackConfigurationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPlaybackConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPlaybackConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the playback configurations defined in AWS Elemental MediaTailor. You can specify a maximum number of configurations to return at a time. The default maximum is 50. Results are returned in pagefuls. If MediaTailor has more configurations than the specified maximum, it provides parameters in the response that you can use to retrieve the next pageful. </p>
-    async fn list_playback_configurations(
+    /// Presigns the request `list_playback_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_playback_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPlaybackConfigurationsRequest,
-    ) -> Result<ListPlaybackConfigurationsResponse, RusotoError<ListPlaybackConfigurationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_playback_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_playback_configurations_request(
+        &self,
+        input: ListPlaybackConfigurationsRequest,
+    ) -> SignedRequest {
         let request_uri = "/playbackConfigurations";
 
         let mut request = SignedRequest::new("GET", "mediatailor", &self.region, &request_uri);
+        request.set_operation("ListPlaybackConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
@@ -745,121 +908,228 @@ impl MediaTailor for MediaTailorClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPlaybackConfigurationsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPlaybackConfigurationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the tags assigned to the specified playback configuration resource. </p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediatailor", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
 
+        request
+    }
+
+    /// Calls `put_playback_configuration`, also returning the metadata of the response.
+    pub async fn put_playback_configuration_with_metadata(
+        &self,
+        input: PutPlaybackConfigurationRequest,
+    ) -> Result<
+        (PutPlaybackConfigurationResponse, ResponseMetadata),
+        RusotoError<PutPlaybackConfigurationError>,
+    > {
+        let request = self.build_put_playback_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<PutPlaybackConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutPlaybackConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Adds a new playback configuration to AWS Elemental MediaTailor. </p>
-    async fn put_playback_configuration(
+    /// Presigns the request `put_playback_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_playback_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutPlaybackConfigurationRequest,
-    ) -> Result<PutPlaybackConfigurationResponse, RusotoError<PutPlaybackConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_playback_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_playback_configuration_request(
+        &self,
+        input: PutPlaybackConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = "/playbackConfiguration";
 
         let mut request = SignedRequest::new("PUT", "mediatailor", &self.region, &request_uri);
+        request.set_operation("PutPlaybackConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutPlaybackConfigurationResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutPlaybackConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds tags to the specified playback configuration resource. You can specify one or more tags to add. </p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediatailor", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from the specified playback configuration resource. You can specify one or more tags to remove. </p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediatailor", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.mediatailor".to_string());
@@ -870,19 +1140,125 @@ impl MediaTailor for MediaTailorClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListPlaybackConfigurations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_playback_configurations_pages(
+        &self,
+        input: ListPlaybackConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListPlaybackConfigurationsResponse,
+            RusotoError<ListPlaybackConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MediaTailorClient, input| async move {
+                client.list_playback_configurations(input).await
+            },
+            |input: &ListPlaybackConfigurationsRequest,
+             output: &ListPlaybackConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPlaybackConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `ListPlaybackConfigurations` results for `input`, as `list_playback_configurations_pages` requests them.
+    pub fn list_playback_configurations_stream(
+        &self,
+        input: ListPlaybackConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<PlaybackConfiguration, RusotoError<ListPlaybackConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_playback_configurations_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl MediaTailor for MediaTailorClient {
+    /// <p>Deletes the playback configuration for the specified name. </p>
+    async fn delete_playback_configuration(
+        &self,
+        input: DeletePlaybackConfigurationRequest,
+    ) -> Result<DeletePlaybackConfigurationResponse, RusotoError<DeletePlaybackConfigurationError>>
+    {
+        self.delete_playback_configuration_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+    /// <p>Returns the playback configuration for the specified name. </p>
+    async fn get_playback_configuration(
+        &self,
+        input: GetPlaybackConfigurationRequest,
+    ) -> Result<GetPlaybackConfigurationResponse, RusotoError<GetPlaybackConfigurationError>> {
+        self.get_playback_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the playback configurations defined in AWS Elemental MediaTailor. You can specify a maximum number of configurations to return at a time. The default maximum is 50. Results are returned in pagefuls. If MediaTailor has more configurations than the specified maximum, it provides parameters in the response that you can use to retrieve the next pageful. </p>
+    async fn list_playback_configurations(
+        &self,
+        input: ListPlaybackConfigurationsRequest,
+    ) -> Result<ListPlaybackConfigurationsResponse, RusotoError<ListPlaybackConfigurationsError>>
+    {
+        self.list_playback_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the tags assigned to the specified playback configuration resource. </p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a new playback configuration to AWS Elemental MediaTailor. </p>
+    async fn put_playback_configuration(
+        &self,
+        input: PutPlaybackConfigurationRequest,
+    ) -> Result<PutPlaybackConfigurationResponse, RusotoError<PutPlaybackConfigurationError>> {
+        self.put_playback_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tags to the specified playback configuration resource. You can specify one or more tags to add. </p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from the specified playback configuration resource. You can specify one or more tags to remove. </p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -170,6 +170,7 @@ pub struct UsageRecordResult {
 
 /// Errors returned by BatchMeterUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchMeterUsageError {
     /// <p>The API is disabled in the Region.</p>
     DisabledApi(String),
@@ -185,6 +186,8 @@ pub enum BatchMeterUsageError {
     Throttling(String),
     /// <p>The timestamp value passed in the meterUsage() is out of allowed range.</p>
     TimestampOutOfBounds(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchMeterUsageError {
@@ -192,36 +195,43 @@ impl BatchMeterUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DisabledApiException" => {
-                    return RusotoError::Service(BatchMeterUsageError::DisabledApi(err.msg))
+                    return RusotoError::service(BatchMeterUsageError::DisabledApi(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchMeterUsageError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCustomerIdentifierException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidCustomerIdentifier(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchMeterUsageError::InvalidCustomerIdentifier(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidProductCodeException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidProductCode(err.msg))
+                    return RusotoError::service(
+                        BatchMeterUsageError::InvalidProductCode(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUsageDimensionException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidUsageDimension(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchMeterUsageError::InvalidUsageDimension(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchMeterUsageError::Throttling(err.msg))
+                    return RusotoError::service(BatchMeterUsageError::Throttling(err.msg), &res)
                 }
                 "TimestampOutOfBoundsException" => {
-                    return RusotoError::Service(BatchMeterUsageError::TimestampOutOfBounds(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchMeterUsageError::TimestampOutOfBounds(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchMeterUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -238,12 +248,14 @@ impl fmt::Display for BatchMeterUsageError {
             BatchMeterUsageError::InvalidUsageDimension(ref cause) => write!(f, "{}", cause),
             BatchMeterUsageError::Throttling(ref cause) => write!(f, "{}", cause),
             BatchMeterUsageError::TimestampOutOfBounds(ref cause) => write!(f, "{}", cause),
+            BatchMeterUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchMeterUsageError {}
 /// Errors returned by MeterUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MeterUsageError {
     /// <p>Exception thrown when the customer does not have a valid subscription for the product.</p>
     CustomerNotEntitled(String),
@@ -261,6 +273,8 @@ pub enum MeterUsageError {
     Throttling(String),
     /// <p>The timestamp value passed in the meterUsage() is out of allowed range.</p>
     TimestampOutOfBounds(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MeterUsageError {
@@ -268,31 +282,46 @@ impl MeterUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomerNotEntitledException" => {
-                    return RusotoError::Service(MeterUsageError::CustomerNotEntitled(err.msg))
+                    return RusotoError::service(
+                        MeterUsageError::CustomerNotEntitled(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateRequestException" => {
-                    return RusotoError::Service(MeterUsageError::DuplicateRequest(err.msg))
+                    return RusotoError::service(MeterUsageError::DuplicateRequest(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(MeterUsageError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        MeterUsageError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEndpointRegionException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidEndpointRegion(err.msg))
+                    return RusotoError::service(
+                        MeterUsageError::InvalidEndpointRegion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidProductCodeException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidProductCode(err.msg))
+                    return RusotoError::service(MeterUsageError::InvalidProductCode(err.msg), &res)
                 }
                 "InvalidUsageDimensionException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidUsageDimension(err.msg))
+                    return RusotoError::service(
+                        MeterUsageError::InvalidUsageDimension(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(MeterUsageError::Throttling(err.msg))
+                    return RusotoError::service(MeterUsageError::Throttling(err.msg), &res)
                 }
                 "TimestampOutOfBoundsException" => {
-                    return RusotoError::Service(MeterUsageError::TimestampOutOfBounds(err.msg))
+                    return RusotoError::service(
+                        MeterUsageError::TimestampOutOfBounds(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(MeterUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -310,12 +339,14 @@ impl fmt::Display for MeterUsageError {
             MeterUsageError::InvalidUsageDimension(ref cause) => write!(f, "{}", cause),
             MeterUsageError::Throttling(ref cause) => write!(f, "{}", cause),
             MeterUsageError::TimestampOutOfBounds(ref cause) => write!(f, "{}", cause),
+            MeterUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for MeterUsageError {}
 /// Errors returned by RegisterUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterUsageError {
     /// <p>Exception thrown when the customer does not have a valid subscription for the product.</p>
     CustomerNotEntitled(String),
@@ -333,6 +364,8 @@ pub enum RegisterUsageError {
     PlatformNotSupported(String),
     /// <p>The calls to the API are throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterUsageError {
@@ -340,33 +373,46 @@ impl RegisterUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomerNotEntitledException" => {
-                    return RusotoError::Service(RegisterUsageError::CustomerNotEntitled(err.msg))
+                    return RusotoError::service(
+                        RegisterUsageError::CustomerNotEntitled(err.msg),
+                        &res,
+                    )
                 }
                 "DisabledApiException" => {
-                    return RusotoError::Service(RegisterUsageError::DisabledApi(err.msg))
+                    return RusotoError::service(RegisterUsageError::DisabledApi(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(RegisterUsageError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        RegisterUsageError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidProductCodeException" => {
-                    return RusotoError::Service(RegisterUsageError::InvalidProductCode(err.msg))
+                    return RusotoError::service(
+                        RegisterUsageError::InvalidProductCode(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPublicKeyVersionException" => {
-                    return RusotoError::Service(RegisterUsageError::InvalidPublicKeyVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterUsageError::InvalidPublicKeyVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRegionException" => {
-                    return RusotoError::Service(RegisterUsageError::InvalidRegion(err.msg))
+                    return RusotoError::service(RegisterUsageError::InvalidRegion(err.msg), &res)
                 }
                 "PlatformNotSupportedException" => {
-                    return RusotoError::Service(RegisterUsageError::PlatformNotSupported(err.msg))
+                    return RusotoError::service(
+                        RegisterUsageError::PlatformNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterUsageError::Throttling(err.msg))
+                    return RusotoError::service(RegisterUsageError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -384,12 +430,14 @@ impl fmt::Display for RegisterUsageError {
             RegisterUsageError::InvalidRegion(ref cause) => write!(f, "{}", cause),
             RegisterUsageError::PlatformNotSupported(ref cause) => write!(f, "{}", cause),
             RegisterUsageError::Throttling(ref cause) => write!(f, "{}", cause),
+            RegisterUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterUsageError {}
 /// Errors returned by ResolveCustomer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResolveCustomerError {
     /// <p>The API is disabled in the Region.</p>
     DisabledApi(String),
@@ -401,6 +449,8 @@ pub enum ResolveCustomerError {
     InvalidToken(String),
     /// <p>The calls to the API are throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResolveCustomerError {
@@ -408,24 +458,25 @@ impl ResolveCustomerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DisabledApiException" => {
-                    return RusotoError::Service(ResolveCustomerError::DisabledApi(err.msg))
+                    return RusotoError::service(ResolveCustomerError::DisabledApi(err.msg), &res)
                 }
                 "ExpiredTokenException" => {
-                    return RusotoError::Service(ResolveCustomerError::ExpiredToken(err.msg))
+                    return RusotoError::service(ResolveCustomerError::ExpiredToken(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ResolveCustomerError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResolveCustomerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTokenException" => {
-                    return RusotoError::Service(ResolveCustomerError::InvalidToken(err.msg))
+                    return RusotoError::service(ResolveCustomerError::InvalidToken(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ResolveCustomerError::Throttling(err.msg))
+                    return RusotoError::service(ResolveCustomerError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResolveCustomerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -440,6 +491,7 @@ impl fmt::Display for ResolveCustomerError {
             ResolveCustomerError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ResolveCustomerError::InvalidToken(ref cause) => write!(f, "{}", cause),
             ResolveCustomerError::Throttling(ref cause) => write!(f, "{}", cause),
+            ResolveCustomerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -482,6 +534,7 @@ impl MarketplaceMeteringClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MarketplaceMeteringClient {
         MarketplaceMeteringClient {
             client: Client::shared(),
@@ -507,115 +560,272 @@ impl MarketplaceMeteringClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceMeteringClient {
         MarketplaceMeteringClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MarketplaceMeteringClient> {
+        rusoto_core::ClientBuilder::new(MarketplaceMeteringClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MarketplaceMeteringClient {
+        MarketplaceMeteringClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MarketplaceMetering for MarketplaceMeteringClient {
-    /// <p>BatchMeterUsage is called from a SaaS application listed on the AWS Marketplace to post metering records for a set of customers.</p> <p>For identical requests, the API is idempotent; requests can be retried with the same records or a subset of the input records.</p> <p>Every request to BatchMeterUsage is for one product. If you need to meter usage for multiple products, you must make multiple calls to BatchMeterUsage.</p> <p>BatchMeterUsage can process up to 25 UsageRecords at a time.</p>
-    async fn batch_meter_usage(
+impl MarketplaceMeteringClient {
+    /// Calls `batch_meter_usage`, also returning the metadata of the response.
+    pub async fn batch_meter_usage_with_metadata(
         &self,
         input: BatchMeterUsageRequest,
-    ) -> Result<BatchMeterUsageResult, RusotoError<BatchMeterUsageError>> {
+    ) -> Result<(BatchMeterUsageResult, ResponseMetadata), RusotoError<BatchMeterUsageError>> {
+        let request = self.build_batch_meter_usage_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchMeterUsageResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchMeterUsageError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_meter_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_meter_usage(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchMeterUsageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_meter_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_meter_usage_request(&self, input: BatchMeterUsageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("BatchMeterUsage");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.BatchMeterUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `meter_usage`, also returning the metadata of the response.
+    pub async fn meter_usage_with_metadata(
+        &self,
+        input: MeterUsageRequest,
+    ) -> Result<(MeterUsageResult, ResponseMetadata), RusotoError<MeterUsageError>> {
+        let request = self.build_meter_usage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchMeterUsageResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<MeterUsageResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchMeterUsageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MeterUsageError::from_response(response))
         }
     }
 
-    /// <p>API to emit metering records. For identical requests, the API is idempotent. It simply returns the metering record ID.</p> <p>MeterUsage is authenticated on the buyer's AWS account using credentials from the EC2 instance, ECS task, or EKS pod.</p>
-    async fn meter_usage(
+    /// Presigns the request `meter_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_meter_usage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MeterUsageRequest,
-    ) -> Result<MeterUsageResult, RusotoError<MeterUsageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_meter_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_meter_usage_request(&self, input: MeterUsageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("MeterUsage");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.MeterUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_usage`, also returning the metadata of the response.
+    pub async fn register_usage_with_metadata(
+        &self,
+        input: RegisterUsageRequest,
+    ) -> Result<(RegisterUsageResult, ResponseMetadata), RusotoError<RegisterUsageError>> {
+        let request = self.build_register_usage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<MeterUsageResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterUsageResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MeterUsageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterUsageError::from_response(response))
         }
     }
 
-    /// <p><p>Paid container software products sold through AWS Marketplace must integrate with the AWS Marketplace Metering Service and call the RegisterUsage operation for software entitlement and metering. Free and BYOL products for Amazon ECS or Amazon EKS aren&#39;t required to call RegisterUsage, but you may choose to do so if you would like to receive usage data in your seller reports. The sections below explain the behavior of RegisterUsage. RegisterUsage performs two primary functions: metering and entitlement.</p> <ul> <li> <p> <i>Entitlement</i>: RegisterUsage allows you to verify that the customer running your paid software is subscribed to your product on AWS Marketplace, enabling you to guard against unauthorized use. Your container image that integrates with RegisterUsage is only required to guard against unauthorized use at container startup, as such a CustomerNotSubscribedException/PlatformNotSupportedException will only be thrown on the initial call to RegisterUsage. Subsequent calls from the same Amazon ECS task instance (e.g. task-id) or Amazon EKS pod will not throw a CustomerNotSubscribedException, even if the customer unsubscribes while the Amazon ECS task or Amazon EKS pod is still running.</p> </li> <li> <p> <i>Metering</i>: RegisterUsage meters software use per ECS task, per hour, or per pod for Amazon EKS with usage prorated to the second. A minimum of 1 minute of usage applies to tasks that are short lived. For example, if a customer has a 10 node Amazon ECS or Amazon EKS cluster and a service configured as a Daemon Set, then Amazon ECS or Amazon EKS will launch a task on all 10 cluster nodes and the customer will be charged: (10 * hourly_rate). Metering for software use is automatically handled by the AWS Marketplace Metering Control Plane -- your software is not required to perform any metering specific actions, other than call RegisterUsage once for metering of software use to commence. The AWS Marketplace Metering Control Plane will also continue to bill customers for running ECS tasks and Amazon EKS pods, regardless of the customers subscription state, removing the need for your software to perform entitlement checks at runtime.</p> </li> </ul></p>
-    async fn register_usage(
+    /// Presigns the request `register_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_usage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterUsageRequest,
-    ) -> Result<RegisterUsageResult, RusotoError<RegisterUsageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_usage_request(&self, input: RegisterUsageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("RegisterUsage");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.RegisterUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `resolve_customer`, also returning the metadata of the response.
+    pub async fn resolve_customer_with_metadata(
+        &self,
+        input: ResolveCustomerRequest,
+    ) -> Result<(ResolveCustomerResult, ResponseMetadata), RusotoError<ResolveCustomerError>> {
+        let request = self.build_resolve_customer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RegisterUsageResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResolveCustomerResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterUsageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResolveCustomerError::from_response(response))
         }
     }
 
-    /// <p>ResolveCustomer is called by a SaaS application during the registration process. When a buyer visits your website during the registration process, the buyer submits a registration token through their browser. The registration token is resolved through this API to obtain a CustomerIdentifier and product code.</p>
-    async fn resolve_customer(
+    /// Presigns the request `resolve_customer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resolve_customer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResolveCustomerRequest,
-    ) -> Result<ResolveCustomerResult, RusotoError<ResolveCustomerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resolve_customer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resolve_customer_request(&self, input: ResolveCustomerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("ResolveCustomer");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.ResolveCustomer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl MarketplaceMetering for MarketplaceMeteringClient {
+    /// <p>BatchMeterUsage is called from a SaaS application listed on the AWS Marketplace to post metering records for a set of customers.</p> <p>For identical requests, the API is idempotent; requests can be retried with the same records or a subset of the input records.</p> <p>Every request to BatchMeterUsage is for one product. If you need to meter usage for multiple products, you must make multiple calls to BatchMeterUsage.</p> <p>BatchMeterUsage can process up to 25 UsageRecords at a time.</p>
+    async fn batch_meter_usage(
+        &self,
+        input: BatchMeterUsageRequest,
+    ) -> Result<BatchMeterUsageResult, RusotoError<BatchMeterUsageError>> {
+        self.batch_meter_usage_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ResolveCustomerResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResolveCustomerError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>API to emit metering records. For identical requests, the API is idempotent. It simply returns the metering record ID.</p> <p>MeterUsage is authenticated on the buyer's AWS account using credentials from the EC2 instance, ECS task, or EKS pod.</p>
+    async fn meter_usage(
+        &self,
+        input: MeterUsageRequest,
+    ) -> Result<MeterUsageResult, RusotoError<MeterUsageError>> {
+        self.meter_usage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Paid container software products sold through AWS Marketplace must integrate with the AWS Marketplace Metering Service and call the RegisterUsage operation for software entitlement and metering. Free and BYOL products for Amazon ECS or Amazon EKS aren&#39;t required to call RegisterUsage, but you may choose to do so if you would like to receive usage data in your seller reports. The sections below explain the behavior of RegisterUsage. RegisterUsage performs two primary functions: metering and entitlement.</p> <ul> <li> <p> <i>Entitlement</i>: RegisterUsage allows you to verify that the customer running your paid software is subscribed to your product on AWS Marketplace, enabling you to guard against unauthorized use. Your container image that integrates with RegisterUsage is only required to guard against unauthorized use at container startup, as such a CustomerNotSubscribedException/PlatformNotSupportedException will only be thrown on the initial call to RegisterUsage. Subsequent calls from the same Amazon ECS task instance (e.g. task-id) or Amazon EKS pod will not throw a CustomerNotSubscribedException, even if the customer unsubscribes while the Amazon ECS task or Amazon EKS pod is still running.</p> </li> <li> <p> <i>Metering</i>: RegisterUsage meters software use per ECS task, per hour, or per pod for Amazon EKS with usage prorated to the second. A minimum of 1 minute of usage applies to tasks that are short lived. For example, if a customer has a 10 node Amazon ECS or Amazon EKS cluster and a service configured as a Daemon Set, then Amazon ECS or Amazon EKS will launch a task on all 10 cluster nodes and the customer will be charged: (10 * hourly_rate). Metering for software use is automatically handled by the AWS Marketplace Metering Control Plane -- your software is not required to perform any metering specific actions, other than call RegisterUsage once for metering of software use to commence. The AWS Marketplace Metering Control Plane will also continue to bill customers for running ECS tasks and Amazon EKS pods, regardless of the customers subscription state, removing the need for your software to perform entitlement checks at runtime.</p> </li> </ul></p>
+    async fn register_usage(
+        &self,
+        input: RegisterUsageRequest,
+    ) -> Result<RegisterUsageResult, RusotoError<RegisterUsageError>> {
+        self.register_usage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>ResolveCustomer is called by a SaaS application during the registration process. When a buyer visits your website during the registration process, the buyer submits a registration token through their browser. The registration token is resolved through this API to obtain a CustomerIdentifier and product code.</p>
+    async fn resolve_customer(
+        &self,
+        input: ResolveCustomerRequest,
+    ) -> Result<ResolveCustomerResult, RusotoError<ResolveCustomerError>> {
+        self.resolve_customer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -564,6 +564,7 @@ pub struct Task {
 
 /// Errors returned by AssociateCreatedArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateCreatedArtifactError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -581,6 +582,8 @@ pub enum AssociateCreatedArtifactError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateCreatedArtifactError {
@@ -588,47 +591,55 @@ impl AssociateCreatedArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCreatedArtifactError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCreatedArtifactError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCreatedArtifactError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateCreatedArtifactError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCreatedArtifactError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCreatedArtifactError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCreatedArtifactError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateCreatedArtifactError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateCreatedArtifactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -648,12 +659,14 @@ impl fmt::Display for AssociateCreatedArtifactError {
             AssociateCreatedArtifactError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateCreatedArtifactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateCreatedArtifactError {}
 /// Errors returned by AssociateDiscoveredResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateDiscoveredResourceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -673,6 +686,8 @@ pub enum AssociateDiscoveredResourceError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateDiscoveredResourceError {
@@ -682,52 +697,66 @@ impl AssociateDiscoveredResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDiscoveredResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDiscoveredResourceError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDiscoveredResourceError::HomeRegionNotSet(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDiscoveredResourceError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDiscoveredResourceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::PolicyError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDiscoveredResourceError::PolicyError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDiscoveredResourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDiscoveredResourceError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDiscoveredResourceError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateDiscoveredResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -752,12 +781,14 @@ impl fmt::Display for AssociateDiscoveredResourceError {
             AssociateDiscoveredResourceError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateDiscoveredResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateDiscoveredResourceError {}
 /// Errors returned by CreateProgressUpdateStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProgressUpdateStreamError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -773,6 +804,8 @@ pub enum CreateProgressUpdateStreamError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProgressUpdateStreamError {
@@ -782,42 +815,51 @@ impl CreateProgressUpdateStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProgressUpdateStreamError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProgressUpdateStreamError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProgressUpdateStreamError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateProgressUpdateStreamError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProgressUpdateStreamError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateProgressUpdateStreamError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateProgressUpdateStreamError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateProgressUpdateStreamError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -840,12 +882,14 @@ impl fmt::Display for CreateProgressUpdateStreamError {
             CreateProgressUpdateStreamError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateProgressUpdateStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProgressUpdateStreamError {}
 /// Errors returned by DeleteProgressUpdateStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProgressUpdateStreamError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -863,6 +907,8 @@ pub enum DeleteProgressUpdateStreamError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProgressUpdateStreamError {
@@ -872,47 +918,57 @@ impl DeleteProgressUpdateStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProgressUpdateStreamError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProgressUpdateStreamError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProgressUpdateStreamError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProgressUpdateStreamError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProgressUpdateStreamError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProgressUpdateStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProgressUpdateStreamError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProgressUpdateStreamError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteProgressUpdateStreamError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -936,12 +992,14 @@ impl fmt::Display for DeleteProgressUpdateStreamError {
             DeleteProgressUpdateStreamError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteProgressUpdateStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProgressUpdateStreamError {}
 /// Errors returned by DescribeApplicationState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeApplicationStateError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -957,6 +1015,8 @@ pub enum DescribeApplicationStateError {
     ResourceNotFound(String),
     /// <p>Exception raised when there is an internal, configuration, or dependency error encountered.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeApplicationStateError {
@@ -964,42 +1024,49 @@ impl DescribeApplicationStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationStateError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationStateError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeApplicationStateError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationStateError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::PolicyError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationStateError::PolicyError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationStateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationStateError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeApplicationStateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1016,12 +1083,14 @@ impl fmt::Display for DescribeApplicationStateError {
             DescribeApplicationStateError::PolicyError(ref cause) => write!(f, "{}", cause),
             DescribeApplicationStateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeApplicationStateError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeApplicationStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeApplicationStateError {}
 /// Errors returned by DescribeMigrationTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMigrationTaskError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1035,6 +1104,8 @@ pub enum DescribeMigrationTaskError {
     ResourceNotFound(String),
     /// <p>Exception raised when there is an internal, configuration, or dependency error encountered.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMigrationTaskError {
@@ -1042,33 +1113,43 @@ impl DescribeMigrationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeMigrationTaskError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMigrationTaskError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMigrationTaskError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeMigrationTaskError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMigrationTaskError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMigrationTaskError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMigrationTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1084,12 +1165,14 @@ impl fmt::Display for DescribeMigrationTaskError {
             DescribeMigrationTaskError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeMigrationTaskError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeMigrationTaskError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeMigrationTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMigrationTaskError {}
 /// Errors returned by DisassociateCreatedArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateCreatedArtifactError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1107,6 +1190,8 @@ pub enum DisassociateCreatedArtifactError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateCreatedArtifactError {
@@ -1116,47 +1201,60 @@ impl DisassociateCreatedArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateCreatedArtifactError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateCreatedArtifactError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateCreatedArtifactError::HomeRegionNotSet(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateCreatedArtifactError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateCreatedArtifactError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateCreatedArtifactError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateCreatedArtifactError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateCreatedArtifactError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateCreatedArtifactError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1180,12 +1278,14 @@ impl fmt::Display for DisassociateCreatedArtifactError {
             DisassociateCreatedArtifactError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateCreatedArtifactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateCreatedArtifactError {}
 /// Errors returned by DisassociateDiscoveredResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateDiscoveredResourceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1203,6 +1303,8 @@ pub enum DisassociateDiscoveredResourceError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateDiscoveredResourceError {
@@ -1212,47 +1314,60 @@ impl DisassociateDiscoveredResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateDiscoveredResourceError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateDiscoveredResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDiscoveredResourceError::DryRunOperation(err.msg),
+                        &res,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDiscoveredResourceError::HomeRegionNotSet(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDiscoveredResourceError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateDiscoveredResourceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateDiscoveredResourceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDiscoveredResourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDiscoveredResourceError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDiscoveredResourceError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateDiscoveredResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1282,12 +1397,16 @@ impl fmt::Display for DisassociateDiscoveredResourceError {
             DisassociateDiscoveredResourceError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateDiscoveredResourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateDiscoveredResourceError {}
 /// Errors returned by ImportMigrationTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportMigrationTaskError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1305,6 +1424,8 @@ pub enum ImportMigrationTaskError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportMigrationTaskError {
@@ -1312,41 +1433,55 @@ impl ImportMigrationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ImportMigrationTaskError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(ImportMigrationTaskError::DryRunOperation(err.msg))
+                    return RusotoError::service(
+                        ImportMigrationTaskError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportMigrationTaskError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ImportMigrationTaskError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportMigrationTaskError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ImportMigrationTaskError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportMigrationTaskError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportMigrationTaskError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(ImportMigrationTaskError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportMigrationTaskError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportMigrationTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1364,12 +1499,14 @@ impl fmt::Display for ImportMigrationTaskError {
             ImportMigrationTaskError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ImportMigrationTaskError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ImportMigrationTaskError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            ImportMigrationTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportMigrationTaskError {}
 /// Errors returned by ListApplicationStates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationStatesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1381,6 +1518,8 @@ pub enum ListApplicationStatesError {
     InvalidInput(String),
     /// <p>Exception raised when there is an internal, configuration, or dependency error encountered.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationStatesError {
@@ -1388,28 +1527,37 @@ impl ListApplicationStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListApplicationStatesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListApplicationStatesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListApplicationStatesError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationStatesError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListApplicationStatesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationStatesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListApplicationStatesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListApplicationStatesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListApplicationStatesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationStatesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationStatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1424,12 +1572,14 @@ impl fmt::Display for ListApplicationStatesError {
             ListApplicationStatesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListApplicationStatesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListApplicationStatesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListApplicationStatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationStatesError {}
 /// Errors returned by ListCreatedArtifacts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCreatedArtifactsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1443,6 +1593,8 @@ pub enum ListCreatedArtifactsError {
     ResourceNotFound(String),
     /// <p>Exception raised when there is an internal, configuration, or dependency error encountered.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCreatedArtifactsError {
@@ -1450,33 +1602,43 @@ impl ListCreatedArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListCreatedArtifactsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCreatedArtifactsError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCreatedArtifactsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListCreatedArtifactsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCreatedArtifactsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCreatedArtifactsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCreatedArtifactsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1492,12 +1654,14 @@ impl fmt::Display for ListCreatedArtifactsError {
             ListCreatedArtifactsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListCreatedArtifactsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListCreatedArtifactsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListCreatedArtifactsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCreatedArtifactsError {}
 /// Errors returned by ListDiscoveredResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDiscoveredResourcesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1511,6 +1675,8 @@ pub enum ListDiscoveredResourcesError {
     ResourceNotFound(String),
     /// <p>Exception raised when there is an internal, configuration, or dependency error encountered.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDiscoveredResourcesError {
@@ -1518,37 +1684,43 @@ impl ListDiscoveredResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDiscoveredResourcesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDiscoveredResourcesError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDiscoveredResourcesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDiscoveredResourcesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDiscoveredResourcesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDiscoveredResourcesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDiscoveredResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1564,12 +1736,14 @@ impl fmt::Display for ListDiscoveredResourcesError {
             ListDiscoveredResourcesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListDiscoveredResourcesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDiscoveredResourcesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListDiscoveredResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDiscoveredResourcesError {}
 /// Errors returned by ListMigrationTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMigrationTasksError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1585,6 +1759,8 @@ pub enum ListMigrationTasksError {
     ResourceNotFound(String),
     /// <p>Exception raised when there is an internal, configuration, or dependency error encountered.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMigrationTasksError {
@@ -1592,32 +1768,49 @@ impl ListMigrationTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListMigrationTasksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListMigrationTasksError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListMigrationTasksError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        ListMigrationTasksError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListMigrationTasksError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMigrationTasksError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMigrationTasksError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListMigrationTasksError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(ListMigrationTasksError::PolicyError(err.msg))
+                    return RusotoError::service(
+                        ListMigrationTasksError::PolicyError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMigrationTasksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListMigrationTasksError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMigrationTasksError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListMigrationTasksError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMigrationTasksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1634,12 +1827,14 @@ impl fmt::Display for ListMigrationTasksError {
             ListMigrationTasksError::PolicyError(ref cause) => write!(f, "{}", cause),
             ListMigrationTasksError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListMigrationTasksError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListMigrationTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMigrationTasksError {}
 /// Errors returned by ListProgressUpdateStreams
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProgressUpdateStreamsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1651,6 +1846,8 @@ pub enum ListProgressUpdateStreamsError {
     InvalidInput(String),
     /// <p>Exception raised when there is an internal, configuration, or dependency error encountered.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProgressUpdateStreamsError {
@@ -1658,32 +1855,39 @@ impl ListProgressUpdateStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProgressUpdateStreamsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProgressUpdateStreamsError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProgressUpdateStreamsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProgressUpdateStreamsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProgressUpdateStreamsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListProgressUpdateStreamsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1700,12 +1904,14 @@ impl fmt::Display for ListProgressUpdateStreamsError {
             }
             ListProgressUpdateStreamsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListProgressUpdateStreamsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListProgressUpdateStreamsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProgressUpdateStreamsError {}
 /// Errors returned by NotifyApplicationState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum NotifyApplicationStateError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1725,6 +1931,8 @@ pub enum NotifyApplicationStateError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl NotifyApplicationStateError {
@@ -1732,46 +1940,61 @@ impl NotifyApplicationStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        NotifyApplicationStateError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(NotifyApplicationStateError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyApplicationStateError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyApplicationStateError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(NotifyApplicationStateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyApplicationStateError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        NotifyApplicationStateError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::PolicyError(err.msg))
+                    return RusotoError::service(
+                        NotifyApplicationStateError::PolicyError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyApplicationStateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyApplicationStateError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         NotifyApplicationStateError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(NotifyApplicationStateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1790,12 +2013,14 @@ impl fmt::Display for NotifyApplicationStateError {
             NotifyApplicationStateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             NotifyApplicationStateError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             NotifyApplicationStateError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            NotifyApplicationStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for NotifyApplicationStateError {}
 /// Errors returned by NotifyMigrationTaskState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum NotifyMigrationTaskStateError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1813,6 +2038,8 @@ pub enum NotifyMigrationTaskStateError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl NotifyMigrationTaskStateError {
@@ -1820,47 +2047,55 @@ impl NotifyMigrationTaskStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyMigrationTaskStateError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyMigrationTaskStateError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyMigrationTaskStateError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         NotifyMigrationTaskStateError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyMigrationTaskStateError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyMigrationTaskStateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyMigrationTaskStateError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         NotifyMigrationTaskStateError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(NotifyMigrationTaskStateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1880,12 +2115,14 @@ impl fmt::Display for NotifyMigrationTaskStateError {
             NotifyMigrationTaskStateError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            NotifyMigrationTaskStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for NotifyMigrationTaskStateError {}
 /// Errors returned by PutResourceAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutResourceAttributesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1903,6 +2140,8 @@ pub enum PutResourceAttributesError {
     ServiceUnavailable(String),
     /// <p>Exception raised to indicate a request was not authorized when the <code>DryRun</code> flag is set to "true".</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutResourceAttributesError {
@@ -1910,43 +2149,55 @@ impl PutResourceAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutResourceAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        PutResourceAttributesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(PutResourceAttributesError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourceAttributesError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(PutResourceAttributesError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourceAttributesError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutResourceAttributesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourceAttributesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutResourceAttributesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        PutResourceAttributesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutResourceAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourceAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutResourceAttributesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourceAttributesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(PutResourceAttributesError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourceAttributesError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutResourceAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1964,6 +2215,7 @@ impl fmt::Display for PutResourceAttributesError {
             PutResourceAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutResourceAttributesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             PutResourceAttributesError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            PutResourceAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2087,6 +2339,7 @@ impl MigrationHubClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MigrationHubClient {
         MigrationHubClient {
             client: Client::shared(),
@@ -2112,45 +2365,131 @@ impl MigrationHubClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubClient {
         MigrationHubClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MigrationHubClient> {
+        rusoto_core::ClientBuilder::new(MigrationHubClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MigrationHubClient {
+        MigrationHubClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MigrationHub for MigrationHubClient {
-    /// <p><p>Associates a created artifact of an AWS cloud resource, the target receiving the migration, with the migration task performed by a migration tool. This API has the following traits:</p> <ul> <li> <p>Migration tools can call the <code>AssociateCreatedArtifact</code> operation to indicate which AWS artifact is associated with a migration task.</p> </li> <li> <p>The created artifact name must be provided in ARN (Amazon Resource Name) format which will contain information about type and region; for example: <code>arn:aws:ec2:us-east-1:488216288981:image/ami-6d0ba87b</code>.</p> </li> <li> <p>Examples of the AWS resource behind the created artifact are, AMI&#39;s, EC2 instance, or DMS endpoint, etc.</p> </li> </ul></p>
-    async fn associate_created_artifact(
+impl MigrationHubClient {
+    /// Calls `associate_created_artifact`, also returning the metadata of the response.
+    pub async fn associate_created_artifact_with_metadata(
         &self,
         input: AssociateCreatedArtifactRequest,
-    ) -> Result<AssociateCreatedArtifactResult, RusotoError<AssociateCreatedArtifactError>> {
+    ) -> Result<
+        (AssociateCreatedArtifactResult, ResponseMetadata),
+        RusotoError<AssociateCreatedArtifactError>,
+    > {
+        let request = self.build_associate_created_artifact_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateCreatedArtifactResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateCreatedArtifactError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_created_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_created_artifact(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateCreatedArtifactRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_created_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_created_artifact_request(
+        &self,
+        input: AssociateCreatedArtifactRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("AssociateCreatedArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.AssociateCreatedArtifact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_discovered_resource`, also returning the metadata of the response.
+    pub async fn associate_discovered_resource_with_metadata(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> Result<
+        (AssociateDiscoveredResourceResult, ResponseMetadata),
+        RusotoError<AssociateDiscoveredResourceError>,
+    > {
+        let request = self.build_associate_discovered_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateCreatedArtifactResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateDiscoveredResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateCreatedArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateDiscoveredResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates a discovered resource ID from Application Discovery Service with a migration task.</p>
-    async fn associate_discovered_resource(
+    /// Presigns the request `associate_discovered_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_discovered_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateDiscoveredResourceRequest,
-    ) -> Result<AssociateDiscoveredResourceResult, RusotoError<AssociateDiscoveredResourceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_discovered_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_discovered_resource_request(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("AssociateDiscoveredResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2160,143 +2499,290 @@ impl MigrationHub for MigrationHubClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_progress_update_stream`, also returning the metadata of the response.
+    pub async fn create_progress_update_stream_with_metadata(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> Result<
+        (CreateProgressUpdateStreamResult, ResponseMetadata),
+        RusotoError<CreateProgressUpdateStreamError>,
+    > {
+        let request = self.build_create_progress_update_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateDiscoveredResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProgressUpdateStreamResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateDiscoveredResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProgressUpdateStreamError::from_response(response))
         }
     }
 
-    /// <p>Creates a progress update stream which is an AWS resource used for access control as well as a namespace for migration task names that is implicitly linked to your AWS account. It must uniquely identify the migration tool as it is used for all updates made by the tool; however, it does not need to be unique for each AWS account because it is scoped to the AWS account.</p>
-    async fn create_progress_update_stream(
+    /// Presigns the request `create_progress_update_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_progress_update_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProgressUpdateStreamRequest,
-    ) -> Result<CreateProgressUpdateStreamResult, RusotoError<CreateProgressUpdateStreamError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_progress_update_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_progress_update_stream_request(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("CreateProgressUpdateStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.CreateProgressUpdateStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_progress_update_stream`, also returning the metadata of the response.
+    pub async fn delete_progress_update_stream_with_metadata(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> Result<
+        (DeleteProgressUpdateStreamResult, ResponseMetadata),
+        RusotoError<DeleteProgressUpdateStreamError>,
+    > {
+        let request = self.build_delete_progress_update_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProgressUpdateStreamResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteProgressUpdateStreamResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProgressUpdateStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteProgressUpdateStreamError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a progress update stream, including all of its tasks, which was previously created as an AWS resource used for access control. This API has the following traits:</p> <ul> <li> <p>The only parameter needed for <code>DeleteProgressUpdateStream</code> is the stream name (same as a <code>CreateProgressUpdateStream</code> call).</p> </li> <li> <p>The call will return, and a background process will asynchronously delete the stream and all of its resources (tasks, associated resources, resource attributes, created artifacts).</p> </li> <li> <p>If the stream takes time to be deleted, it might still show up on a <code>ListProgressUpdateStreams</code> call.</p> </li> <li> <p> <code>CreateProgressUpdateStream</code>, <code>ImportMigrationTask</code>, <code>NotifyMigrationTaskState</code>, and all Associate[*] APIs related to the tasks belonging to the stream will throw &quot;InvalidInputException&quot; if the stream of the same name is in the process of being deleted.</p> </li> <li> <p>Once the stream and all of its resources are deleted, <code>CreateProgressUpdateStream</code> for a stream of the same name will succeed, and that stream will be an entirely new logical resource (without any resources associated with the old stream).</p> </li> </ul></p>
-    async fn delete_progress_update_stream(
+    /// Presigns the request `delete_progress_update_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_progress_update_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProgressUpdateStreamRequest,
-    ) -> Result<DeleteProgressUpdateStreamResult, RusotoError<DeleteProgressUpdateStreamError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_progress_update_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_progress_update_stream_request(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DeleteProgressUpdateStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.DeleteProgressUpdateStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_application_state`, also returning the metadata of the response.
+    pub async fn describe_application_state_with_metadata(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> Result<
+        (DescribeApplicationStateResult, ResponseMetadata),
+        RusotoError<DescribeApplicationStateError>,
+    > {
+        let request = self.build_describe_application_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteProgressUpdateStreamResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeApplicationStateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProgressUpdateStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeApplicationStateError::from_response(response))
         }
     }
 
-    /// <p>Gets the migration status of an application.</p>
-    async fn describe_application_state(
+    /// Presigns the request `describe_application_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_application_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeApplicationStateRequest,
-    ) -> Result<DescribeApplicationStateResult, RusotoError<DescribeApplicationStateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_application_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_application_state_request(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DescribeApplicationState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeApplicationState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_migration_task`, also returning the metadata of the response.
+    pub async fn describe_migration_task_with_metadata(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> Result<
+        (DescribeMigrationTaskResult, ResponseMetadata),
+        RusotoError<DescribeMigrationTaskError>,
+    > {
+        let request = self.build_describe_migration_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeApplicationStateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMigrationTaskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeApplicationStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMigrationTaskError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of all attributes associated with a specific migration task.</p>
-    async fn describe_migration_task(
+    /// Presigns the request `describe_migration_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_migration_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMigrationTaskRequest,
-    ) -> Result<DescribeMigrationTaskResult, RusotoError<DescribeMigrationTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_migration_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_migration_task_request(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DescribeMigrationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeMigrationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_created_artifact`, also returning the metadata of the response.
+    pub async fn disassociate_created_artifact_with_metadata(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> Result<
+        (DisassociateCreatedArtifactResult, ResponseMetadata),
+        RusotoError<DisassociateCreatedArtifactError>,
+    > {
+        let request = self.build_disassociate_created_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMigrationTaskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateCreatedArtifactResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMigrationTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateCreatedArtifactError::from_response(response))
         }
     }
 
-    /// <p><p>Disassociates a created artifact of an AWS resource with a migration task performed by a migration tool that was previously associated. This API has the following traits:</p> <ul> <li> <p>A migration user can call the <code>DisassociateCreatedArtifacts</code> operation to disassociate a created AWS Artifact from a migration task.</p> </li> <li> <p>The created artifact name must be provided in ARN (Amazon Resource Name) format which will contain information about type and region; for example: <code>arn:aws:ec2:us-east-1:488216288981:image/ami-6d0ba87b</code>.</p> </li> <li> <p>Examples of the AWS resource behind the created artifact are, AMI&#39;s, EC2 instance, or RDS instance, etc.</p> </li> </ul></p>
-    async fn disassociate_created_artifact(
+    /// Presigns the request `disassociate_created_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_created_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateCreatedArtifactRequest,
-    ) -> Result<DisassociateCreatedArtifactResult, RusotoError<DisassociateCreatedArtifactError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_created_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_created_artifact_request(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DisassociateCreatedArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2306,31 +2792,58 @@ impl MigrationHub for MigrationHubClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_discovered_resource`, also returning the metadata of the response.
+    pub async fn disassociate_discovered_resource_with_metadata(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> Result<
+        (DisassociateDiscoveredResourceResult, ResponseMetadata),
+        RusotoError<DisassociateDiscoveredResourceError>,
+    > {
+        let request = self.build_disassociate_discovered_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateCreatedArtifactResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateDiscoveredResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateCreatedArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateDiscoveredResourceError::from_response(response))
         }
     }
 
-    /// <p>Disassociate an Application Discovery Service discovered resource from a migration task.</p>
-    async fn disassociate_discovered_resource(
+    /// Presigns the request `disassociate_discovered_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_discovered_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateDiscoveredResourceRequest,
-    ) -> Result<
-        DisassociateDiscoveredResourceResult,
-        RusotoError<DisassociateDiscoveredResourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_discovered_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_discovered_resource_request(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DisassociateDiscoveredResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2340,271 +2853,900 @@ impl MigrationHub for MigrationHubClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `import_migration_task`, also returning the metadata of the response.
+    pub async fn import_migration_task_with_metadata(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> Result<(ImportMigrationTaskResult, ResponseMetadata), RusotoError<ImportMigrationTaskError>>
+    {
+        let request = self.build_import_migration_task_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateDiscoveredResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportMigrationTaskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateDiscoveredResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportMigrationTaskError::from_response(response))
         }
     }
 
-    /// <p>Registers a new migration task which represents a server, database, etc., being migrated to AWS by a migration tool.</p> <p>This API is a prerequisite to calling the <code>NotifyMigrationTaskState</code> API as the migration tool must first register the migration task with Migration Hub.</p>
-    async fn import_migration_task(
+    /// Presigns the request `import_migration_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_migration_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportMigrationTaskRequest,
-    ) -> Result<ImportMigrationTaskResult, RusotoError<ImportMigrationTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_migration_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_migration_task_request(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ImportMigrationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ImportMigrationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_application_states`, also returning the metadata of the response.
+    pub async fn list_application_states_with_metadata(
+        &self,
+        input: ListApplicationStatesRequest,
+    ) -> Result<
+        (ListApplicationStatesResult, ResponseMetadata),
+        RusotoError<ListApplicationStatesError>,
+    > {
+        let request = self.build_list_application_states_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportMigrationTaskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListApplicationStatesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportMigrationTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListApplicationStatesError::from_response(response))
         }
     }
 
-    /// <p>Lists all the migration statuses for your applications. If you use the optional <code>ApplicationIds</code> parameter, only the migration statuses for those applications will be returned.</p>
-    async fn list_application_states(
+    /// Presigns the request `list_application_states` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_application_states(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationStatesRequest,
-    ) -> Result<ListApplicationStatesResult, RusotoError<ListApplicationStatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_application_states_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_application_states_request(
+        &self,
+        input: ListApplicationStatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListApplicationStates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListApplicationStates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_created_artifacts`, also returning the metadata of the response.
+    pub async fn list_created_artifacts_with_metadata(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> Result<
+        (ListCreatedArtifactsResult, ResponseMetadata),
+        RusotoError<ListCreatedArtifactsError>,
+    > {
+        let request = self.build_list_created_artifacts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApplicationStatesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCreatedArtifactsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationStatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCreatedArtifactsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the created artifacts attached to a given migration task in an update stream. This API has the following traits:</p> <ul> <li> <p>Gets the list of the created artifacts while migration is taking place.</p> </li> <li> <p>Shows the artifacts created by the migration tool that was associated by the <code>AssociateCreatedArtifact</code> API. </p> </li> <li> <p>Lists created artifacts in a paginated interface. </p> </li> </ul></p>
-    async fn list_created_artifacts(
+    /// Presigns the request `list_created_artifacts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_created_artifacts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCreatedArtifactsRequest,
-    ) -> Result<ListCreatedArtifactsResult, RusotoError<ListCreatedArtifactsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_created_artifacts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_created_artifacts_request(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListCreatedArtifacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListCreatedArtifacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_discovered_resources`, also returning the metadata of the response.
+    pub async fn list_discovered_resources_with_metadata(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> Result<
+        (ListDiscoveredResourcesResult, ResponseMetadata),
+        RusotoError<ListDiscoveredResourcesError>,
+    > {
+        let request = self.build_list_discovered_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCreatedArtifactsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDiscoveredResourcesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCreatedArtifactsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDiscoveredResourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists discovered resources associated with the given <code>MigrationTask</code>.</p>
-    async fn list_discovered_resources(
+    /// Presigns the request `list_discovered_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_discovered_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDiscoveredResourcesRequest,
-    ) -> Result<ListDiscoveredResourcesResult, RusotoError<ListDiscoveredResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_discovered_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_discovered_resources_request(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListDiscoveredResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListDiscoveredResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_migration_tasks`, also returning the metadata of the response.
+    pub async fn list_migration_tasks_with_metadata(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> Result<(ListMigrationTasksResult, ResponseMetadata), RusotoError<ListMigrationTasksError>>
+    {
+        let request = self.build_list_migration_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDiscoveredResourcesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListMigrationTasksResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDiscoveredResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListMigrationTasksError::from_response(response))
         }
     }
 
-    /// <p><p>Lists all, or filtered by resource name, migration tasks associated with the user account making this call. This API has the following traits:</p> <ul> <li> <p>Can show a summary list of the most recent migration tasks.</p> </li> <li> <p>Can show a summary list of migration tasks associated with a given discovered resource.</p> </li> <li> <p>Lists migration tasks in a paginated interface.</p> </li> </ul></p>
-    async fn list_migration_tasks(
+    /// Presigns the request `list_migration_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_migration_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMigrationTasksRequest,
-    ) -> Result<ListMigrationTasksResult, RusotoError<ListMigrationTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_migration_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_migration_tasks_request(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListMigrationTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListMigrationTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_progress_update_streams`, also returning the metadata of the response.
+    pub async fn list_progress_update_streams_with_metadata(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> Result<
+        (ListProgressUpdateStreamsResult, ResponseMetadata),
+        RusotoError<ListProgressUpdateStreamsError>,
+    > {
+        let request = self.build_list_progress_update_streams_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMigrationTasksResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProgressUpdateStreamsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMigrationTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProgressUpdateStreamsError::from_response(response))
         }
     }
 
-    /// <p>Lists progress update streams associated with the user account making this call.</p>
-    async fn list_progress_update_streams(
+    /// Presigns the request `list_progress_update_streams` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_progress_update_streams(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProgressUpdateStreamsRequest,
-    ) -> Result<ListProgressUpdateStreamsResult, RusotoError<ListProgressUpdateStreamsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_progress_update_streams_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_progress_update_streams_request(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListProgressUpdateStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListProgressUpdateStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `notify_application_state`, also returning the metadata of the response.
+    pub async fn notify_application_state_with_metadata(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> Result<
+        (NotifyApplicationStateResult, ResponseMetadata),
+        RusotoError<NotifyApplicationStateError>,
+    > {
+        let request = self.build_notify_application_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProgressUpdateStreamsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<NotifyApplicationStateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProgressUpdateStreamsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(NotifyApplicationStateError::from_response(response))
         }
     }
 
-    /// <p>Sets the migration state of an application. For a given application identified by the value passed to <code>ApplicationId</code>, its status is set or updated by passing one of three values to <code>Status</code>: <code>NOT_STARTED | IN_PROGRESS | COMPLETED</code>.</p>
-    async fn notify_application_state(
+    /// Presigns the request `notify_application_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_notify_application_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: NotifyApplicationStateRequest,
-    ) -> Result<NotifyApplicationStateResult, RusotoError<NotifyApplicationStateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_notify_application_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_notify_application_state_request(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("NotifyApplicationState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyApplicationState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `notify_migration_task_state`, also returning the metadata of the response.
+    pub async fn notify_migration_task_state_with_metadata(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> Result<
+        (NotifyMigrationTaskStateResult, ResponseMetadata),
+        RusotoError<NotifyMigrationTaskStateError>,
+    > {
+        let request = self.build_notify_migration_task_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<NotifyApplicationStateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<NotifyMigrationTaskStateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(NotifyApplicationStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(NotifyMigrationTaskStateError::from_response(response))
         }
     }
 
-    /// <p><p>Notifies Migration Hub of the current status, progress, or other detail regarding a migration task. This API has the following traits:</p> <ul> <li> <p>Migration tools will call the <code>NotifyMigrationTaskState</code> API to share the latest progress and status.</p> </li> <li> <p> <code>MigrationTaskName</code> is used for addressing updates to the correct target.</p> </li> <li> <p> <code>ProgressUpdateStream</code> is used for access control and to provide a namespace for each migration tool.</p> </li> </ul></p>
-    async fn notify_migration_task_state(
+    /// Presigns the request `notify_migration_task_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_notify_migration_task_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: NotifyMigrationTaskStateRequest,
-    ) -> Result<NotifyMigrationTaskStateResult, RusotoError<NotifyMigrationTaskStateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_notify_migration_task_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_notify_migration_task_state_request(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("NotifyMigrationTaskState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyMigrationTaskState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_resource_attributes`, also returning the metadata of the response.
+    pub async fn put_resource_attributes_with_metadata(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> Result<
+        (PutResourceAttributesResult, ResponseMetadata),
+        RusotoError<PutResourceAttributesError>,
+    > {
+        let request = self.build_put_resource_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<NotifyMigrationTaskStateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutResourceAttributesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(NotifyMigrationTaskStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutResourceAttributesError::from_response(response))
         }
     }
 
-    /// <p><p>Provides identifying details of the resource being migrated so that it can be associated in the Application Discovery Service repository. This association occurs asynchronously after <code>PutResourceAttributes</code> returns.</p> <important> <ul> <li> <p>Keep in mind that subsequent calls to PutResourceAttributes will override previously stored attributes. For example, if it is first called with a MAC address, but later, it is desired to <i>add</i> an IP address, it will then be required to call it with <i>both</i> the IP and MAC addresses to prevent overriding the MAC address.</p> </li> <li> <p>Note the instructions regarding the special use case of the <a href="https://docs.aws.amazon.com/migrationhub/latest/ug/API_PutResourceAttributes.html#migrationhub-PutResourceAttributes-request-ResourceAttributeList"> <code>ResourceAttributeList</code> </a> parameter when specifying any &quot;VM&quot; related value.</p> </li> </ul> </important> <note> <p>Because this is an asynchronous call, it will always return 200, whether an association occurs or not. To confirm if an association was found based on the provided details, call <code>ListDiscoveredResources</code>.</p> </note></p>
-    async fn put_resource_attributes(
+    /// Presigns the request `put_resource_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_resource_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutResourceAttributesRequest,
-    ) -> Result<PutResourceAttributesResult, RusotoError<PutResourceAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_resource_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_resource_attributes_request(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("PutResourceAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.PutResourceAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListApplicationStates` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_application_states_pages(
+        &self,
+        input: ListApplicationStatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListApplicationStatesResult, RusotoError<ListApplicationStatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input| async move {
+                client.list_application_states(input).await
+            },
+            |input: &ListApplicationStatesRequest, output: &ListApplicationStatesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApplicationStatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ApplicationStateList` of every page of `ListApplicationStates` results for `input`, as `list_application_states_pages` requests them.
+    pub fn list_application_states_stream(
+        &self,
+        input: ListApplicationStatesRequest,
+    ) -> impl futures::Stream<Item = Result<ApplicationState, RusotoError<ListApplicationStatesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_application_states_pages(input), |page| {
+            page.application_state_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCreatedArtifacts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_created_artifacts_pages(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListCreatedArtifactsResult, RusotoError<ListCreatedArtifactsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input| async move {
+                client.list_created_artifacts(input).await
+            },
+            |input: &ListCreatedArtifactsRequest, output: &ListCreatedArtifactsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCreatedArtifactsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CreatedArtifactList` of every page of `ListCreatedArtifacts` results for `input`, as `list_created_artifacts_pages` requests them.
+    pub fn list_created_artifacts_stream(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> impl futures::Stream<Item = Result<CreatedArtifact, RusotoError<ListCreatedArtifactsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_created_artifacts_pages(input), |page| {
+            page.created_artifact_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDiscoveredResources` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_discovered_resources_pages(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDiscoveredResourcesResult, RusotoError<ListDiscoveredResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input| async move {
+                client.list_discovered_resources(input).await
+            },
+            |input: &ListDiscoveredResourcesRequest, output: &ListDiscoveredResourcesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDiscoveredResourcesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DiscoveredResourceList` of every page of `ListDiscoveredResources` results for `input`, as `list_discovered_resources_pages` requests them.
+    pub fn list_discovered_resources_stream(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DiscoveredResource, RusotoError<ListDiscoveredResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_discovered_resources_pages(input), |page| {
+            page.discovered_resource_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListMigrationTasks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_migration_tasks_pages(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListMigrationTasksResult, RusotoError<ListMigrationTasksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input| async move { client.list_migration_tasks(input).await },
+            |input: &ListMigrationTasksRequest, output: &ListMigrationTasksResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMigrationTasksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `MigrationTaskSummaryList` of every page of `ListMigrationTasks` results for `input`, as `list_migration_tasks_pages` requests them.
+    pub fn list_migration_tasks_stream(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<MigrationTaskSummary, RusotoError<ListMigrationTasksError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_migration_tasks_pages(input), |page| {
+            page.migration_task_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListProgressUpdateStreams` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_progress_update_streams_pages(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListProgressUpdateStreamsResult, RusotoError<ListProgressUpdateStreamsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MigrationHubClient, input| async move {
+                client.list_progress_update_streams(input).await
+            },
+            |input: &ListProgressUpdateStreamsRequest, output: &ListProgressUpdateStreamsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListProgressUpdateStreamsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProgressUpdateStreamSummaryList` of every page of `ListProgressUpdateStreams` results for `input`, as `list_progress_update_streams_pages` requests them.
+    pub fn list_progress_update_streams_stream(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ProgressUpdateStreamSummary, RusotoError<ListProgressUpdateStreamsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_progress_update_streams_pages(input), |page| {
+            page.progress_update_stream_summary_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl MigrationHub for MigrationHubClient {
+    /// <p><p>Associates a created artifact of an AWS cloud resource, the target receiving the migration, with the migration task performed by a migration tool. This API has the following traits:</p> <ul> <li> <p>Migration tools can call the <code>AssociateCreatedArtifact</code> operation to indicate which AWS artifact is associated with a migration task.</p> </li> <li> <p>The created artifact name must be provided in ARN (Amazon Resource Name) format which will contain information about type and region; for example: <code>arn:aws:ec2:us-east-1:488216288981:image/ami-6d0ba87b</code>.</p> </li> <li> <p>Examples of the AWS resource behind the created artifact are, AMI&#39;s, EC2 instance, or DMS endpoint, etc.</p> </li> </ul></p>
+    async fn associate_created_artifact(
+        &self,
+        input: AssociateCreatedArtifactRequest,
+    ) -> Result<AssociateCreatedArtifactResult, RusotoError<AssociateCreatedArtifactError>> {
+        self.associate_created_artifact_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutResourceAttributesResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutResourceAttributesError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a discovered resource ID from Application Discovery Service with a migration task.</p>
+    async fn associate_discovered_resource(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> Result<AssociateDiscoveredResourceResult, RusotoError<AssociateDiscoveredResourceError>>
+    {
+        self.associate_discovered_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a progress update stream which is an AWS resource used for access control as well as a namespace for migration task names that is implicitly linked to your AWS account. It must uniquely identify the migration tool as it is used for all updates made by the tool; however, it does not need to be unique for each AWS account because it is scoped to the AWS account.</p>
+    async fn create_progress_update_stream(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> Result<CreateProgressUpdateStreamResult, RusotoError<CreateProgressUpdateStreamError>>
+    {
+        self.create_progress_update_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a progress update stream, including all of its tasks, which was previously created as an AWS resource used for access control. This API has the following traits:</p> <ul> <li> <p>The only parameter needed for <code>DeleteProgressUpdateStream</code> is the stream name (same as a <code>CreateProgressUpdateStream</code> call).</p> </li> <li> <p>The call will return, and a background process will asynchronously delete the stream and all of its resources (tasks, associated resources, resource attributes, created artifacts).</p> </li> <li> <p>If the stream takes time to be deleted, it might still show up on a <code>ListProgressUpdateStreams</code> call.</p> </li> <li> <p> <code>CreateProgressUpdateStream</code>, <code>ImportMigrationTask</code>, <code>NotifyMigrationTaskState</code>, and all Associate[*] APIs related to the tasks belonging to the stream will throw &quot;InvalidInputException&quot; if the stream of the same name is in the process of being deleted.</p> </li> <li> <p>Once the stream and all of its resources are deleted, <code>CreateProgressUpdateStream</code> for a stream of the same name will succeed, and that stream will be an entirely new logical resource (without any resources associated with the old stream).</p> </li> </ul></p>
+    async fn delete_progress_update_stream(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> Result<DeleteProgressUpdateStreamResult, RusotoError<DeleteProgressUpdateStreamError>>
+    {
+        self.delete_progress_update_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the migration status of an application.</p>
+    async fn describe_application_state(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> Result<DescribeApplicationStateResult, RusotoError<DescribeApplicationStateError>> {
+        self.describe_application_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of all attributes associated with a specific migration task.</p>
+    async fn describe_migration_task(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> Result<DescribeMigrationTaskResult, RusotoError<DescribeMigrationTaskError>> {
+        self.describe_migration_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Disassociates a created artifact of an AWS resource with a migration task performed by a migration tool that was previously associated. This API has the following traits:</p> <ul> <li> <p>A migration user can call the <code>DisassociateCreatedArtifacts</code> operation to disassociate a created AWS Artifact from a migration task.</p> </li> <li> <p>The created artifact name must be provided in ARN (Amazon Resource Name) format which will contain information about type and region; for example: <code>arn:aws:ec2:us-east-1:488216288981:image/ami-6d0ba87b</code>.</p> </li> <li> <p>Examples of the AWS resource behind the created artifact are, AMI&#39;s, EC2 instance, or RDS instance, etc.</p> </li> </ul></p>
+    async fn disassociate_created_artifact(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> Result<DisassociateCreatedArtifactResult, RusotoError<DisassociateCreatedArtifactError>>
+    {
+        self.disassociate_created_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociate an Application Discovery Service discovered resource from a migration task.</p>
+    async fn disassociate_discovered_resource(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> Result<
+        DisassociateDiscoveredResourceResult,
+        RusotoError<DisassociateDiscoveredResourceError>,
+    > {
+        self.disassociate_discovered_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a new migration task which represents a server, database, etc., being migrated to AWS by a migration tool.</p> <p>This API is a prerequisite to calling the <code>NotifyMigrationTaskState</code> API as the migration tool must first register the migration task with Migration Hub.</p>
+    async fn import_migration_task(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> Result<ImportMigrationTaskResult, RusotoError<ImportMigrationTaskError>> {
+        self.import_migration_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the migration statuses for your applications. If you use the optional <code>ApplicationIds</code> parameter, only the migration statuses for those applications will be returned.</p>
+    async fn list_application_states(
+        &self,
+        input: ListApplicationStatesRequest,
+    ) -> Result<ListApplicationStatesResult, RusotoError<ListApplicationStatesError>> {
+        self.list_application_states_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the created artifacts attached to a given migration task in an update stream. This API has the following traits:</p> <ul> <li> <p>Gets the list of the created artifacts while migration is taking place.</p> </li> <li> <p>Shows the artifacts created by the migration tool that was associated by the <code>AssociateCreatedArtifact</code> API. </p> </li> <li> <p>Lists created artifacts in a paginated interface. </p> </li> </ul></p>
+    async fn list_created_artifacts(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> Result<ListCreatedArtifactsResult, RusotoError<ListCreatedArtifactsError>> {
+        self.list_created_artifacts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists discovered resources associated with the given <code>MigrationTask</code>.</p>
+    async fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> Result<ListDiscoveredResourcesResult, RusotoError<ListDiscoveredResourcesError>> {
+        self.list_discovered_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists all, or filtered by resource name, migration tasks associated with the user account making this call. This API has the following traits:</p> <ul> <li> <p>Can show a summary list of the most recent migration tasks.</p> </li> <li> <p>Can show a summary list of migration tasks associated with a given discovered resource.</p> </li> <li> <p>Lists migration tasks in a paginated interface.</p> </li> </ul></p>
+    async fn list_migration_tasks(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> Result<ListMigrationTasksResult, RusotoError<ListMigrationTasksError>> {
+        self.list_migration_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists progress update streams associated with the user account making this call.</p>
+    async fn list_progress_update_streams(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> Result<ListProgressUpdateStreamsResult, RusotoError<ListProgressUpdateStreamsError>> {
+        self.list_progress_update_streams_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the migration state of an application. For a given application identified by the value passed to <code>ApplicationId</code>, its status is set or updated by passing one of three values to <code>Status</code>: <code>NOT_STARTED | IN_PROGRESS | COMPLETED</code>.</p>
+    async fn notify_application_state(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> Result<NotifyApplicationStateResult, RusotoError<NotifyApplicationStateError>> {
+        self.notify_application_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Notifies Migration Hub of the current status, progress, or other detail regarding a migration task. This API has the following traits:</p> <ul> <li> <p>Migration tools will call the <code>NotifyMigrationTaskState</code> API to share the latest progress and status.</p> </li> <li> <p> <code>MigrationTaskName</code> is used for addressing updates to the correct target.</p> </li> <li> <p> <code>ProgressUpdateStream</code> is used for access control and to provide a namespace for each migration tool.</p> </li> </ul></p>
+    async fn notify_migration_task_state(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> Result<NotifyMigrationTaskStateResult, RusotoError<NotifyMigrationTaskStateError>> {
+        self.notify_migration_task_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Provides identifying details of the resource being migrated so that it can be associated in the Application Discovery Service repository. This association occurs asynchronously after <code>PutResourceAttributes</code> returns.</p> <important> <ul> <li> <p>Keep in mind that subsequent calls to PutResourceAttributes will override previously stored attributes. For example, if it is first called with a MAC address, but later, it is desired to <i>add</i> an IP address, it will then be required to call it with <i>both</i> the IP and MAC addresses to prevent overriding the MAC address.</p> </li> <li> <p>Note the instructions regarding the special use case of the <a href="https://docs.aws.amazon.com/migrationhub/latest/ug/API_PutResourceAttributes.html#migrationhub-PutResourceAttributes-request-ResourceAttributeList"> <code>ResourceAttributeList</code> </a> parameter when specifying any &quot;VM&quot; related value.</p> </li> </ul> </important> <note> <p>Because this is an asynchronous call, it will always return 200, whether an association occurs or not. To confirm if an association was found based on the provided details, call <code>ListDiscoveredResources</code>.</p> </note></p>
+    async fn put_resource_attributes(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> Result<PutResourceAttributesResult, RusotoError<PutResourceAttributesError>> {
+        self.put_resource_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
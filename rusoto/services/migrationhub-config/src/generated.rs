@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -135,6 +135,7 @@ pub struct Target {
 
 /// Errors returned by CreateHomeRegionControl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHomeRegionControlError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -146,6 +147,8 @@ pub enum CreateHomeRegionControlError {
     InvalidInput(String),
     /// <p>Exception raised when a request fails due to temporary unavailability of the service.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHomeRegionControlError {
@@ -153,32 +156,37 @@ impl CreateHomeRegionControlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHomeRegionControlError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHomeRegionControlError::DryRunOperation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHomeRegionControlError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHomeRegionControlError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHomeRegionControlError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHomeRegionControlError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -193,12 +201,14 @@ impl fmt::Display for CreateHomeRegionControlError {
             CreateHomeRegionControlError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateHomeRegionControlError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateHomeRegionControlError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateHomeRegionControlError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHomeRegionControlError {}
 /// Errors returned by DescribeHomeRegionControls
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHomeRegionControlsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -208,6 +218,8 @@ pub enum DescribeHomeRegionControlsError {
     InvalidInput(String),
     /// <p>Exception raised when a request fails due to temporary unavailability of the service.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHomeRegionControlsError {
@@ -217,27 +229,33 @@ impl DescribeHomeRegionControlsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeHomeRegionControlsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeHomeRegionControlsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeHomeRegionControlsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeHomeRegionControlsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeHomeRegionControlsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeHomeRegionControlsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeHomeRegionControlsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -255,12 +273,14 @@ impl fmt::Display for DescribeHomeRegionControlsError {
             DescribeHomeRegionControlsError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeHomeRegionControlsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHomeRegionControlsError {}
 /// Errors returned by GetHomeRegion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetHomeRegionError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -270,6 +290,8 @@ pub enum GetHomeRegionError {
     InvalidInput(String),
     /// <p>Exception raised when a request fails due to temporary unavailability of the service.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetHomeRegionError {
@@ -277,19 +299,25 @@ impl GetHomeRegionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetHomeRegionError::AccessDenied(err.msg))
+                    return RusotoError::service(GetHomeRegionError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetHomeRegionError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetHomeRegionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetHomeRegionError::InvalidInput(err.msg))
+                    return RusotoError::service(GetHomeRegionError::InvalidInput(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetHomeRegionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetHomeRegionError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetHomeRegionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -303,6 +331,7 @@ impl fmt::Display for GetHomeRegionError {
             GetHomeRegionError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetHomeRegionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetHomeRegionError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetHomeRegionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -337,6 +366,7 @@ impl MigrationHubConfigClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MigrationHubConfigClient {
         MigrationHubConfigClient {
             client: Client::shared(),
@@ -362,16 +392,73 @@ impl MigrationHubConfigClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubConfigClient {
         MigrationHubConfigClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MigrationHubConfigClient> {
+        rusoto_core::ClientBuilder::new(MigrationHubConfigClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MigrationHubConfigClient {
+        MigrationHubConfigClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MigrationHubConfig for MigrationHubConfigClient {
-    /// <p>This API sets up the home region for the calling account only.</p>
-    async fn create_home_region_control(
+impl MigrationHubConfigClient {
+    /// Calls `create_home_region_control`, also returning the metadata of the response.
+    pub async fn create_home_region_control_with_metadata(
         &self,
         input: CreateHomeRegionControlRequest,
-    ) -> Result<CreateHomeRegionControlResult, RusotoError<CreateHomeRegionControlError>> {
+    ) -> Result<
+        (CreateHomeRegionControlResult, ResponseMetadata),
+        RusotoError<CreateHomeRegionControlError>,
+    > {
+        let request = self.build_create_home_region_control_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHomeRegionControlResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHomeRegionControlError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_home_region_control` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_home_region_control(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateHomeRegionControlRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_home_region_control_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_home_region_control_request(
+        &self,
+        input: CreateHomeRegionControlRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("CreateHomeRegionControl");
         request.set_endpoint_prefix("migrationhub-config".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -381,29 +468,58 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_home_region_controls`, also returning the metadata of the response.
+    pub async fn describe_home_region_controls_with_metadata(
+        &self,
+        input: DescribeHomeRegionControlsRequest,
+    ) -> Result<
+        (DescribeHomeRegionControlsResult, ResponseMetadata),
+        RusotoError<DescribeHomeRegionControlsError>,
+    > {
+        let request = self.build_describe_home_region_controls_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateHomeRegionControlResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeHomeRegionControlsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHomeRegionControlError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeHomeRegionControlsError::from_response(response))
         }
     }
 
-    /// <p>This API permits filtering on the <code>ControlId</code>, <code>HomeRegion</code>, and <code>RegionControlScope</code> fields.</p>
-    async fn describe_home_region_controls(
+    /// Presigns the request `describe_home_region_controls` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_home_region_controls(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHomeRegionControlsRequest,
-    ) -> Result<DescribeHomeRegionControlsResult, RusotoError<DescribeHomeRegionControlsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_home_region_controls_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_home_region_controls_request(
+        &self,
+        input: DescribeHomeRegionControlsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DescribeHomeRegionControls");
         request.set_endpoint_prefix("migrationhub-config".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -413,27 +529,50 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_home_region`, also returning the metadata of the response.
+    pub async fn get_home_region_with_metadata(
+        &self,
+    ) -> Result<(GetHomeRegionResult, ResponseMetadata), RusotoError<GetHomeRegionError>> {
+        let request = self.build_get_home_region_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeHomeRegionControlsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetHomeRegionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHomeRegionControlsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetHomeRegionError::from_response(response))
         }
     }
 
-    /// <p>Returns the calling account’s home region, if configured. This API is used by other AWS services to determine the regional endpoint for calling AWS Application Discovery Service and Migration Hub. You must call <code>GetHomeRegion</code> at least once before you call any other AWS Application Discovery Service and AWS Migration Hub APIs, to obtain the account's Migration Hub home region.</p>
-    async fn get_home_region(
+    /// Presigns the request `get_home_region` would send with `presigner` instead of sending it.
+    pub async fn presign_get_home_region(
         &self,
-    ) -> Result<GetHomeRegionResult, RusotoError<GetHomeRegionError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_home_region_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_home_region_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("GetHomeRegion");
         request.set_endpoint_prefix("migrationhub-config".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -442,18 +581,39 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl MigrationHubConfig for MigrationHubConfigClient {
+    /// <p>This API sets up the home region for the calling account only.</p>
+    async fn create_home_region_control(
+        &self,
+        input: CreateHomeRegionControlRequest,
+    ) -> Result<CreateHomeRegionControlResult, RusotoError<CreateHomeRegionControlError>> {
+        self.create_home_region_control_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetHomeRegionResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetHomeRegionError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This API permits filtering on the <code>ControlId</code>, <code>HomeRegion</code>, and <code>RegionControlScope</code> fields.</p>
+    async fn describe_home_region_controls(
+        &self,
+        input: DescribeHomeRegionControlsRequest,
+    ) -> Result<DescribeHomeRegionControlsResult, RusotoError<DescribeHomeRegionControlsError>>
+    {
+        self.describe_home_region_controls_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the calling account’s home region, if configured. This API is used by other AWS services to determine the regional endpoint for calling AWS Application Discovery Service and Migration Hub. You must call <code>GetHomeRegion</code> at least once before you call any other AWS Application Discovery Service and AWS Migration Hub APIs, to obtain the account's Migration Hub home region.</p>
+    async fn get_home_region(
+        &self,
+    ) -> Result<GetHomeRegionResult, RusotoError<GetHomeRegionError>> {
+        self.get_home_region_with_metadata()
+            .await
+            .map(|(output, _)| output)
     }
 }
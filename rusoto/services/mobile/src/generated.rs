@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -355,6 +355,7 @@ pub struct UpdateProjectResult {
 
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProjectError {
     /// <p> The request cannot be processed because some parameter is not valid or the project state prevents the operation from being performed. </p>
     BadRequest(String),
@@ -370,6 +371,8 @@ pub enum CreateProjectError {
     TooManyRequests(String),
     /// <p> Credentials of the caller are insufficient to authorize the request. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProjectError {
@@ -377,28 +380,31 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateProjectError::BadRequest(err.msg))
+                    return RusotoError::service(CreateProjectError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateProjectError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateProjectError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateProjectError::NotFound(err.msg))
+                    return RusotoError::service(CreateProjectError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateProjectError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateProjectError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -415,12 +421,14 @@ impl fmt::Display for CreateProjectError {
             CreateProjectError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateProjectError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateProjectError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProjectError {}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProjectError {
     /// <p> The service has encountered an unexpected error condition which prevents it from servicing the request. </p>
     InternalFailure(String),
@@ -432,6 +440,8 @@ pub enum DeleteProjectError {
     TooManyRequests(String),
     /// <p> Credentials of the caller are insufficient to authorize the request. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProjectError {
@@ -439,22 +449,25 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteProjectError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProjectError::NotFound(err.msg))
+                    return RusotoError::service(DeleteProjectError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteProjectError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteProjectError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteProjectError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -469,12 +482,14 @@ impl fmt::Display for DeleteProjectError {
             DeleteProjectError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteProjectError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteProjectError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProjectError {}
 /// Errors returned by DescribeBundle
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBundleError {
     /// <p> The request cannot be processed because some parameter is not valid or the project state prevents the operation from being performed. </p>
     BadRequest(String),
@@ -488,6 +503,8 @@ pub enum DescribeBundleError {
     TooManyRequests(String),
     /// <p> Credentials of the caller are insufficient to authorize the request. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBundleError {
@@ -495,25 +512,34 @@ impl DescribeBundleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeBundleError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeBundleError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeBundleError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeBundleError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBundleError::NotFound(err.msg))
+                    return RusotoError::service(DescribeBundleError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeBundleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeBundleError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeBundleError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeBundleError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeBundleError::Unauthorized(err.msg))
+                    return RusotoError::service(DescribeBundleError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBundleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -529,12 +555,14 @@ impl fmt::Display for DescribeBundleError {
             DescribeBundleError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeBundleError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribeBundleError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeBundleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBundleError {}
 /// Errors returned by DescribeProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProjectError {
     /// <p> The request cannot be processed because some parameter is not valid or the project state prevents the operation from being performed. </p>
     BadRequest(String),
@@ -548,6 +576,8 @@ pub enum DescribeProjectError {
     TooManyRequests(String),
     /// <p> Credentials of the caller are insufficient to authorize the request. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProjectError {
@@ -555,25 +585,34 @@ impl DescribeProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeProjectError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeProjectError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeProjectError::NotFound(err.msg))
+                    return RusotoError::service(DescribeProjectError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(DescribeProjectError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -589,12 +628,14 @@ impl fmt::Display for DescribeProjectError {
             DescribeProjectError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeProjectError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribeProjectError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProjectError {}
 /// Errors returned by ExportBundle
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExportBundleError {
     /// <p> The request cannot be processed because some parameter is not valid or the project state prevents the operation from being performed. </p>
     BadRequest(String),
@@ -608,6 +649,8 @@ pub enum ExportBundleError {
     TooManyRequests(String),
     /// <p> Credentials of the caller are insufficient to authorize the request. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExportBundleError {
@@ -615,25 +658,28 @@ impl ExportBundleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExportBundleError::BadRequest(err.msg))
+                    return RusotoError::service(ExportBundleError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ExportBundleError::InternalFailure(err.msg))
+                    return RusotoError::service(ExportBundleError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExportBundleError::NotFound(err.msg))
+                    return RusotoError::service(ExportBundleError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ExportBundleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ExportBundleError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ExportBundleError::TooManyRequests(err.msg))
+                    return RusotoError::service(ExportBundleError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ExportBundleError::Unauthorized(err.msg))
+                    return RusotoError::service(ExportBundleError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExportBundleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -649,12 +695,14 @@ impl fmt::Display for ExportBundleError {
             ExportBundleError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ExportBundleError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ExportBundleError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ExportBundleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExportBundleError {}
 /// Errors returned by ExportProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExportProjectError {
     /// <p> The request cannot be processed because some parameter is not valid or the project state prevents the operation from being performed. </p>
     BadRequest(String),
@@ -668,6 +716,8 @@ pub enum ExportProjectError {
     TooManyRequests(String),
     /// <p> Credentials of the caller are insufficient to authorize the request. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExportProjectError {
@@ -675,25 +725,28 @@ impl ExportProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExportProjectError::BadRequest(err.msg))
+                    return RusotoError::service(ExportProjectError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ExportProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(ExportProjectError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExportProjectError::NotFound(err.msg))
+                    return RusotoError::service(ExportProjectError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ExportProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ExportProjectError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ExportProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(ExportProjectError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ExportProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(ExportProjectError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExportProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -709,12 +762,14 @@ impl fmt::Display for ExportProjectError {
             ExportProjectError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ExportProjectError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ExportProjectError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ExportProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExportProjectError {}
 /// Errors returned by ListBundles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBundlesError {
     /// <p> The request cannot be processed because some parameter is not valid or the project state prevents the operation from being performed. </p>
     BadRequest(String),
@@ -726,6 +781,8 @@ pub enum ListBundlesError {
     TooManyRequests(String),
     /// <p> Credentials of the caller are insufficient to authorize the request. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBundlesError {
@@ -733,22 +790,25 @@ impl ListBundlesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBundlesError::BadRequest(err.msg))
+                    return RusotoError::service(ListBundlesError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBundlesError::InternalFailure(err.msg))
+                    return RusotoError::service(ListBundlesError::InternalFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBundlesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListBundlesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListBundlesError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListBundlesError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBundlesError::Unauthorized(err.msg))
+                    return RusotoError::service(ListBundlesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBundlesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -763,12 +823,14 @@ impl fmt::Display for ListBundlesError {
             ListBundlesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListBundlesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListBundlesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListBundlesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBundlesError {}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProjectsError {
     /// <p> The request cannot be processed because some parameter is not valid or the project state prevents the operation from being performed. </p>
     BadRequest(String),
@@ -780,6 +842,8 @@ pub enum ListProjectsError {
     TooManyRequests(String),
     /// <p> Credentials of the caller are insufficient to authorize the request. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProjectsError {
@@ -787,22 +851,25 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListProjectsError::BadRequest(err.msg))
+                    return RusotoError::service(ListProjectsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListProjectsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListProjectsError::InternalFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProjectsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListProjectsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListProjectsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListProjectsError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListProjectsError::Unauthorized(err.msg))
+                    return RusotoError::service(ListProjectsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -817,12 +884,14 @@ impl fmt::Display for ListProjectsError {
             ListProjectsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListProjectsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListProjectsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListProjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProjectsError {}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProjectError {
     /// <p> Account Action is required in order to continue the request. </p>
     AccountActionRequired(String),
@@ -840,6 +909,8 @@ pub enum UpdateProjectError {
     TooManyRequests(String),
     /// <p> Credentials of the caller are insufficient to authorize the request. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProjectError {
@@ -847,31 +918,37 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountActionRequiredException" => {
-                    return RusotoError::Service(UpdateProjectError::AccountActionRequired(err.msg))
+                    return RusotoError::service(
+                        UpdateProjectError::AccountActionRequired(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateProjectError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateProjectError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateProjectError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateProjectError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::NotFound(err.msg))
+                    return RusotoError::service(UpdateProjectError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateProjectError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateProjectError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateProjectError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -889,6 +966,7 @@ impl fmt::Display for UpdateProjectError {
             UpdateProjectError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateProjectError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateProjectError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -961,6 +1039,7 @@ impl MobileClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MobileClient {
         MobileClient {
             client: Client::shared(),
@@ -986,19 +1065,71 @@ impl MobileClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MobileClient {
         MobileClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MobileClient> {
+        rusoto_core::ClientBuilder::new(MobileClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MobileClient {
+        MobileClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Mobile for MobileClient {
-    /// <p> Creates an AWS Mobile Hub project. </p>
-    async fn create_project(
+impl MobileClient {
+    /// Calls `create_project`, also returning the metadata of the response.
+    pub async fn create_project_with_metadata(
         &self,
         input: CreateProjectRequest,
-    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+    ) -> Result<(CreateProjectResult, ResponseMetadata), RusotoError<CreateProjectError>> {
+        let request = self.build_create_project_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProjectResult, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateProjectError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_project(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateProjectRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_project_request(&self, input: CreateProjectRequest) -> SignedRequest {
         let request_uri = "/projects";
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("CreateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1021,92 +1152,164 @@ impl Mobile for MobileClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_project`, also returning the metadata of the response.
+    pub async fn delete_project_with_metadata(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<(DeleteProjectResult, ResponseMetadata), RusotoError<DeleteProjectError>> {
+        let request = self.build_delete_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProjectResult, _>()?;
+                .deserialize::<DeleteProjectResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteProjectError::from_response(response))
         }
     }
 
-    /// <p> Delets a project in AWS Mobile Hub. </p>
-    async fn delete_project(
+    /// Presigns the request `delete_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProjectRequest,
-    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_project_request(&self, input: DeleteProjectRequest) -> SignedRequest {
         let request_uri = format!("/projects/{project_id}", project_id = input.project_id);
 
         let mut request =
             SignedRequest::new("DELETE", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DeleteProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
 
+        request
+    }
+
+    /// Calls `describe_bundle`, also returning the metadata of the response.
+    pub async fn describe_bundle_with_metadata(
+        &self,
+        input: DescribeBundleRequest,
+    ) -> Result<(DescribeBundleResult, ResponseMetadata), RusotoError<DescribeBundleError>> {
+        let request = self.build_describe_bundle_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteProjectResult, _>()?;
+                .deserialize::<DescribeBundleResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeBundleError::from_response(response))
         }
     }
 
-    /// <p> Get the bundle details for the requested bundle id. </p>
-    async fn describe_bundle(
+    /// Presigns the request `describe_bundle` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_bundle(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBundleRequest,
-    ) -> Result<DescribeBundleResult, RusotoError<DescribeBundleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_bundle_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_bundle_request(&self, input: DescribeBundleRequest) -> SignedRequest {
         let request_uri = format!("/bundles/{bundle_id}", bundle_id = input.bundle_id);
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DescribeBundle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
 
+        request
+    }
+
+    /// Calls `describe_project`, also returning the metadata of the response.
+    pub async fn describe_project_with_metadata(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<(DescribeProjectResult, ResponseMetadata), RusotoError<DescribeProjectError>> {
+        let request = self.build_describe_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBundleResult, _>()?;
+                .deserialize::<DescribeProjectResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBundleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeProjectError::from_response(response))
         }
     }
 
-    /// <p> Gets details about a project in AWS Mobile Hub. </p>
-    async fn describe_project(
+    /// Presigns the request `describe_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProjectRequest,
-    ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_project_request(&self, input: DescribeProjectRequest) -> SignedRequest {
         let request_uri = "/project";
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DescribeProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1118,32 +1321,56 @@ impl Mobile for MobileClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `export_bundle`, also returning the metadata of the response.
+    pub async fn export_bundle_with_metadata(
+        &self,
+        input: ExportBundleRequest,
+    ) -> Result<(ExportBundleResult, ResponseMetadata), RusotoError<ExportBundleError>> {
+        let request = self.build_export_bundle_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProjectResult, _>()?;
+                .deserialize::<ExportBundleResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ExportBundleError::from_response(response))
         }
     }
 
-    /// <p> Generates customized software development kit (SDK) and or tool packages used to integrate mobile web or mobile app clients with backend AWS resources. </p>
-    async fn export_bundle(
+    /// Presigns the request `export_bundle` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_export_bundle(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExportBundleRequest,
-    ) -> Result<ExportBundleResult, RusotoError<ExportBundleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_export_bundle_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_export_bundle_request(&self, input: ExportBundleRequest) -> SignedRequest {
         let request_uri = format!("/bundles/{bundle_id}", bundle_id = input.bundle_id);
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ExportBundle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1157,62 +1384,110 @@ impl Mobile for MobileClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `export_project`, also returning the metadata of the response.
+    pub async fn export_project_with_metadata(
+        &self,
+        input: ExportProjectRequest,
+    ) -> Result<(ExportProjectResult, ResponseMetadata), RusotoError<ExportProjectError>> {
+        let request = self.build_export_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ExportBundleResult, _>()?;
+                .deserialize::<ExportProjectResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ExportBundleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ExportProjectError::from_response(response))
         }
     }
 
-    /// <p> Exports project configuration to a snapshot which can be downloaded and shared. Note that mobile app push credentials are encrypted in exported projects, so they can only be shared successfully within the same AWS account. </p>
-    async fn export_project(
+    /// Presigns the request `export_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_export_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExportProjectRequest,
-    ) -> Result<ExportProjectResult, RusotoError<ExportProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_export_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_export_project_request(&self, input: ExportProjectRequest) -> SignedRequest {
         let request_uri = format!("/exports/{project_id}", project_id = input.project_id);
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ExportProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
 
+        request
+    }
+
+    /// Calls `list_bundles`, also returning the metadata of the response.
+    pub async fn list_bundles_with_metadata(
+        &self,
+        input: ListBundlesRequest,
+    ) -> Result<(ListBundlesResult, ResponseMetadata), RusotoError<ListBundlesError>> {
+        let request = self.build_list_bundles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ExportProjectResult, _>()?;
+                .deserialize::<ListBundlesResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ExportProjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBundlesError::from_response(response))
         }
     }
 
-    /// <p> List all available bundles. </p>
-    async fn list_bundles(
+    /// Presigns the request `list_bundles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_bundles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBundlesRequest,
-    ) -> Result<ListBundlesResult, RusotoError<ListBundlesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_bundles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_bundles_request(&self, input: ListBundlesRequest) -> SignedRequest {
         let request_uri = "/bundles";
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ListBundles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1226,32 +1501,56 @@ impl Mobile for MobileClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_projects`, also returning the metadata of the response.
+    pub async fn list_projects_with_metadata(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<(ListProjectsResult, ResponseMetadata), RusotoError<ListProjectsError>> {
+        let request = self.build_list_projects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBundlesResult, _>()?;
+                .deserialize::<ListProjectsResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBundlesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListProjectsError::from_response(response))
         }
     }
 
-    /// <p> Lists projects in AWS Mobile Hub. </p>
-    async fn list_projects(
+    /// Presigns the request `list_projects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_projects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProjectsRequest,
-    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_projects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_projects_request(&self, input: ListProjectsRequest) -> SignedRequest {
         let request_uri = "/projects";
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ListProjects");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1265,32 +1564,56 @@ impl Mobile for MobileClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_project`, also returning the metadata of the response.
+    pub async fn update_project_with_metadata(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<(UpdateProjectResult, ResponseMetadata), RusotoError<UpdateProjectError>> {
+        let request = self.build_update_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProjectsResult, _>()?;
+                .deserialize::<UpdateProjectResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProjectsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateProjectError::from_response(response))
         }
     }
 
-    /// <p> Update an existing project. </p>
-    async fn update_project(
+    /// Presigns the request `update_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProjectRequest,
-    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_project_request(&self, input: UpdateProjectRequest) -> SignedRequest {
         let request_uri = "/update";
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("UpdateProject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1305,20 +1628,99 @@ impl Mobile for MobileClient {
         params.put("projectId", &input.project_id);
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Mobile for MobileClient {
+    /// <p> Creates an AWS Mobile Hub project. </p>
+    async fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+        self.create_project_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateProjectResult, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProjectError::from_response(response))
-        }
+    /// <p> Delets a project in AWS Mobile Hub. </p>
+    async fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+        self.delete_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Get the bundle details for the requested bundle id. </p>
+    async fn describe_bundle(
+        &self,
+        input: DescribeBundleRequest,
+    ) -> Result<DescribeBundleResult, RusotoError<DescribeBundleError>> {
+        self.describe_bundle_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets details about a project in AWS Mobile Hub. </p>
+    async fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
+        self.describe_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Generates customized software development kit (SDK) and or tool packages used to integrate mobile web or mobile app clients with backend AWS resources. </p>
+    async fn export_bundle(
+        &self,
+        input: ExportBundleRequest,
+    ) -> Result<ExportBundleResult, RusotoError<ExportBundleError>> {
+        self.export_bundle_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Exports project configuration to a snapshot which can be downloaded and shared. Note that mobile app push credentials are encrypted in exported projects, so they can only be shared successfully within the same AWS account. </p>
+    async fn export_project(
+        &self,
+        input: ExportProjectRequest,
+    ) -> Result<ExportProjectResult, RusotoError<ExportProjectError>> {
+        self.export_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> List all available bundles. </p>
+    async fn list_bundles(
+        &self,
+        input: ListBundlesRequest,
+    ) -> Result<ListBundlesResult, RusotoError<ListBundlesError>> {
+        self.list_bundles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Lists projects in AWS Mobile Hub. </p>
+    async fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+        self.list_projects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Update an existing project. </p>
+    async fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+        self.update_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1207,6 +1207,7 @@ pub struct WeeklyStartTime {
 
 /// Errors returned by CreateBroker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBrokerError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1218,6 +1219,8 @@ pub enum CreateBrokerError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBrokerError {
@@ -1225,22 +1228,25 @@ impl CreateBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(CreateBrokerError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBrokerError::Conflict(err.msg))
+                    return RusotoError::service(CreateBrokerError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(CreateBrokerError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateBrokerError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBrokerError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateBrokerError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBrokerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1255,12 +1261,14 @@ impl fmt::Display for CreateBrokerError {
             CreateBrokerError::Forbidden(ref cause) => write!(f, "{}", cause),
             CreateBrokerError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateBrokerError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateBrokerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBrokerError {}
 /// Errors returned by CreateConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1270,6 +1278,8 @@ pub enum CreateConfigurationError {
     Forbidden(String),
     /// <p>Returns information about an error.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationError {
@@ -1277,21 +1287,25 @@ impl CreateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateConfigurationError::Conflict(err.msg))
+                    return RusotoError::service(CreateConfigurationError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(CreateConfigurationError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1305,12 +1319,14 @@ impl fmt::Display for CreateConfigurationError {
             CreateConfigurationError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateConfigurationError::Forbidden(ref cause) => write!(f, "{}", cause),
             CreateConfigurationError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConfigurationError {}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTagsError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1320,6 +1336,8 @@ pub enum CreateTagsError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTagsError {
@@ -1327,19 +1345,22 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateTagsError::BadRequest(err.msg))
+                    return RusotoError::service(CreateTagsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateTagsError::Forbidden(err.msg))
+                    return RusotoError::service(CreateTagsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateTagsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::NotFound(err.msg))
+                    return RusotoError::service(CreateTagsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1353,12 +1374,14 @@ impl fmt::Display for CreateTagsError {
             CreateTagsError::Forbidden(ref cause) => write!(f, "{}", cause),
             CreateTagsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateTagsError::NotFound(ref cause) => write!(f, "{}", cause),
+            CreateTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTagsError {}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1370,6 +1393,8 @@ pub enum CreateUserError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserError {
@@ -1377,22 +1402,25 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUserError::BadRequest(err.msg))
+                    return RusotoError::service(CreateUserError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUserError::Conflict(err.msg))
+                    return RusotoError::service(CreateUserError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateUserError::Forbidden(err.msg))
+                    return RusotoError::service(CreateUserError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateUserError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateUserError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUserError::NotFound(err.msg))
+                    return RusotoError::service(CreateUserError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1407,12 +1435,14 @@ impl fmt::Display for CreateUserError {
             CreateUserError::Forbidden(ref cause) => write!(f, "{}", cause),
             CreateUserError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateUserError::NotFound(ref cause) => write!(f, "{}", cause),
+            CreateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserError {}
 /// Errors returned by DeleteBroker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBrokerError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1422,6 +1452,8 @@ pub enum DeleteBrokerError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBrokerError {
@@ -1429,19 +1461,22 @@ impl DeleteBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteBrokerError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteBrokerError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteBrokerError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBrokerError::NotFound(err.msg))
+                    return RusotoError::service(DeleteBrokerError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBrokerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1455,12 +1490,14 @@ impl fmt::Display for DeleteBrokerError {
             DeleteBrokerError::Forbidden(ref cause) => write!(f, "{}", cause),
             DeleteBrokerError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteBrokerError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteBrokerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBrokerError {}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTagsError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1470,6 +1507,8 @@ pub enum DeleteTagsError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTagsError {
@@ -1477,19 +1516,22 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTagsError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteTagsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteTagsError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteTagsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteTagsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::NotFound(err.msg))
+                    return RusotoError::service(DeleteTagsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1503,12 +1545,14 @@ impl fmt::Display for DeleteTagsError {
             DeleteTagsError::Forbidden(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTagsError {}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1518,6 +1562,8 @@ pub enum DeleteUserError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserError {
@@ -1525,19 +1571,22 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUserError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteUserError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteUserError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteUserError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteUserError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteUserError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::NotFound(err.msg))
+                    return RusotoError::service(DeleteUserError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1551,12 +1600,14 @@ impl fmt::Display for DeleteUserError {
             DeleteUserError::Forbidden(ref cause) => write!(f, "{}", cause),
             DeleteUserError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteUserError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserError {}
 /// Errors returned by DescribeBroker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBrokerError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1566,6 +1617,8 @@ pub enum DescribeBrokerError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBrokerError {
@@ -1573,19 +1626,22 @@ impl DescribeBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeBrokerError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeBrokerError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeBrokerError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBrokerError::NotFound(err.msg))
+                    return RusotoError::service(DescribeBrokerError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBrokerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1599,12 +1655,14 @@ impl fmt::Display for DescribeBrokerError {
             DescribeBrokerError::Forbidden(ref cause) => write!(f, "{}", cause),
             DescribeBrokerError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeBrokerError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeBrokerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBrokerError {}
 /// Errors returned by DescribeBrokerEngineTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBrokerEngineTypesError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1612,6 +1670,8 @@ pub enum DescribeBrokerEngineTypesError {
     Forbidden(String),
     /// <p>Returns information about an error.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBrokerEngineTypesError {
@@ -1619,20 +1679,27 @@ impl DescribeBrokerEngineTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeBrokerEngineTypesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBrokerEngineTypesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeBrokerEngineTypesError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeBrokerEngineTypesError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeBrokerEngineTypesError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeBrokerEngineTypesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1647,12 +1714,14 @@ impl fmt::Display for DescribeBrokerEngineTypesError {
             DescribeBrokerEngineTypesError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeBrokerEngineTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBrokerEngineTypesError {}
 /// Errors returned by DescribeBrokerInstanceOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBrokerInstanceOptionsError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1660,6 +1729,8 @@ pub enum DescribeBrokerInstanceOptionsError {
     Forbidden(String),
     /// <p>Returns information about an error.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBrokerInstanceOptionsError {
@@ -1669,22 +1740,30 @@ impl DescribeBrokerInstanceOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeBrokerInstanceOptionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBrokerInstanceOptionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeBrokerInstanceOptionsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBrokerInstanceOptionsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeBrokerInstanceOptionsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeBrokerInstanceOptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1699,12 +1778,16 @@ impl fmt::Display for DescribeBrokerInstanceOptionsError {
             DescribeBrokerInstanceOptionsError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeBrokerInstanceOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeBrokerInstanceOptionsError {}
 /// Errors returned by DescribeConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1714,6 +1797,8 @@ pub enum DescribeConfigurationError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationError {
@@ -1721,21 +1806,31 @@ impl DescribeConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1749,12 +1844,14 @@ impl fmt::Display for DescribeConfigurationError {
             DescribeConfigurationError::Forbidden(ref cause) => write!(f, "{}", cause),
             DescribeConfigurationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConfigurationError {}
 /// Errors returned by DescribeConfigurationRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationRevisionError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1764,6 +1861,8 @@ pub enum DescribeConfigurationRevisionError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationRevisionError {
@@ -1773,27 +1872,36 @@ impl DescribeConfigurationRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationRevisionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationRevisionError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationRevisionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationRevisionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConfigurationRevisionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1809,12 +1917,16 @@ impl fmt::Display for DescribeConfigurationRevisionError {
                 write!(f, "{}", cause)
             }
             DescribeConfigurationRevisionError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeConfigurationRevisionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConfigurationRevisionError {}
 /// Errors returned by DescribeUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1824,6 +1936,8 @@ pub enum DescribeUserError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserError {
@@ -1831,19 +1945,22 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeUserError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeUserError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeUserError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeUserError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeUserError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeUserError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::NotFound(err.msg))
+                    return RusotoError::service(DescribeUserError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1857,12 +1974,14 @@ impl fmt::Display for DescribeUserError {
             DescribeUserError::Forbidden(ref cause) => write!(f, "{}", cause),
             DescribeUserError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeUserError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserError {}
 /// Errors returned by ListBrokers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBrokersError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1870,6 +1989,8 @@ pub enum ListBrokersError {
     Forbidden(String),
     /// <p>Returns information about an error.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBrokersError {
@@ -1877,16 +1998,19 @@ impl ListBrokersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBrokersError::BadRequest(err.msg))
+                    return RusotoError::service(ListBrokersError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListBrokersError::Forbidden(err.msg))
+                    return RusotoError::service(ListBrokersError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListBrokersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListBrokersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBrokersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1899,12 +2023,14 @@ impl fmt::Display for ListBrokersError {
             ListBrokersError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListBrokersError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListBrokersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListBrokersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBrokersError {}
 /// Errors returned by ListConfigurationRevisions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigurationRevisionsError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1914,6 +2040,8 @@ pub enum ListConfigurationRevisionsError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigurationRevisionsError {
@@ -1923,25 +2051,33 @@ impl ListConfigurationRevisionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationRevisionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationRevisionsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListConfigurationRevisionsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListConfigurationRevisionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListConfigurationRevisionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1957,12 +2093,14 @@ impl fmt::Display for ListConfigurationRevisionsError {
                 write!(f, "{}", cause)
             }
             ListConfigurationRevisionsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListConfigurationRevisionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigurationRevisionsError {}
 /// Errors returned by ListConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigurationsError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -1970,6 +2108,8 @@ pub enum ListConfigurationsError {
     Forbidden(String),
     /// <p>Returns information about an error.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigurationsError {
@@ -1977,18 +2117,19 @@ impl ListConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationsError::BadRequest(err.msg))
+                    return RusotoError::service(ListConfigurationsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationsError::Forbidden(err.msg))
+                    return RusotoError::service(ListConfigurationsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConfigurationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2001,12 +2142,14 @@ impl fmt::Display for ListConfigurationsError {
             ListConfigurationsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListConfigurationsError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListConfigurationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigurationsError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -2016,6 +2159,8 @@ pub enum ListTagsError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
@@ -2023,19 +2168,19 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsError::BadRequest(err.msg))
+                    return RusotoError::service(ListTagsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsError::Forbidden(err.msg))
+                    return RusotoError::service(ListTagsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(ListTagsError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2049,12 +2194,14 @@ impl fmt::Display for ListTagsError {
             ListTagsError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListTagsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTagsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUsersError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -2064,6 +2211,8 @@ pub enum ListUsersError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUsersError {
@@ -2071,19 +2220,19 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListUsersError::BadRequest(err.msg))
+                    return RusotoError::service(ListUsersError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListUsersError::Forbidden(err.msg))
+                    return RusotoError::service(ListUsersError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListUsersError::InternalServerError(err.msg))
+                    return RusotoError::service(ListUsersError::InternalServerError(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUsersError::NotFound(err.msg))
+                    return RusotoError::service(ListUsersError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2097,12 +2246,14 @@ impl fmt::Display for ListUsersError {
             ListUsersError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListUsersError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListUsersError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUsersError {}
 /// Errors returned by RebootBroker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootBrokerError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -2112,6 +2263,8 @@ pub enum RebootBrokerError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootBrokerError {
@@ -2119,19 +2272,22 @@ impl RebootBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RebootBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(RebootBrokerError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RebootBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(RebootBrokerError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RebootBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        RebootBrokerError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RebootBrokerError::NotFound(err.msg))
+                    return RusotoError::service(RebootBrokerError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RebootBrokerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2145,12 +2301,14 @@ impl fmt::Display for RebootBrokerError {
             RebootBrokerError::Forbidden(ref cause) => write!(f, "{}", cause),
             RebootBrokerError::InternalServerError(ref cause) => write!(f, "{}", cause),
             RebootBrokerError::NotFound(ref cause) => write!(f, "{}", cause),
+            RebootBrokerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootBrokerError {}
 /// Errors returned by UpdateBroker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBrokerError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -2162,6 +2320,8 @@ pub enum UpdateBrokerError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBrokerError {
@@ -2169,22 +2329,25 @@ impl UpdateBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateBrokerError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateBrokerError::Conflict(err.msg))
+                    return RusotoError::service(UpdateBrokerError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateBrokerError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateBrokerError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBrokerError::NotFound(err.msg))
+                    return RusotoError::service(UpdateBrokerError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBrokerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2199,12 +2362,14 @@ impl fmt::Display for UpdateBrokerError {
             UpdateBrokerError::Forbidden(ref cause) => write!(f, "{}", cause),
             UpdateBrokerError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateBrokerError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateBrokerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBrokerError {}
 /// Errors returned by UpdateConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigurationError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -2216,6 +2381,8 @@ pub enum UpdateConfigurationError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigurationError {
@@ -2223,24 +2390,28 @@ impl UpdateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateConfigurationError::Conflict(err.msg))
+                    return RusotoError::service(UpdateConfigurationError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateConfigurationError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateConfigurationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(UpdateConfigurationError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2255,12 +2426,14 @@ impl fmt::Display for UpdateConfigurationError {
             UpdateConfigurationError::Forbidden(ref cause) => write!(f, "{}", cause),
             UpdateConfigurationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConfigurationError {}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserError {
     /// <p>Returns information about an error.</p>
     BadRequest(String),
@@ -2272,6 +2445,8 @@ pub enum UpdateUserError {
     InternalServerError(String),
     /// <p>Returns information about an error.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserError {
@@ -2279,22 +2454,25 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateUserError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateUserError::Conflict(err.msg))
+                    return RusotoError::service(UpdateUserError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateUserError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateUserError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateUserError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::NotFound(err.msg))
+                    return RusotoError::service(UpdateUserError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2309,6 +2487,7 @@ impl fmt::Display for UpdateUserError {
             UpdateUserError::Forbidden(ref cause) => write!(f, "{}", cause),
             UpdateUserError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateUserError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2465,6 +2644,7 @@ impl MQClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MQClient {
         MQClient {
             client: Client::shared(),
@@ -2490,104 +2670,233 @@ impl MQClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MQClient {
         MQClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MQClient> {
+        rusoto_core::ClientBuilder::new(MQClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MQClient {
+        MQClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MQ for MQClient {
-    /// <p>Creates a broker. Note: This API is asynchronous.</p>
-    async fn create_broker(
+impl MQClient {
+    /// Calls `create_broker`, also returning the metadata of the response.
+    pub async fn create_broker_with_metadata(
         &self,
         input: CreateBrokerRequest,
-    ) -> Result<CreateBrokerResponse, RusotoError<CreateBrokerError>> {
+    ) -> Result<(CreateBrokerResponse, ResponseMetadata), RusotoError<CreateBrokerError>> {
+        let request = self.build_create_broker_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBrokerResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateBrokerError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_broker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_broker(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateBrokerRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_broker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_broker_request(&self, input: CreateBrokerRequest) -> SignedRequest {
         let request_uri = "/v1/brokers";
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_configuration`, also returning the metadata of the response.
+    pub async fn create_configuration_with_metadata(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> Result<
+        (CreateConfigurationResponse, ResponseMetadata),
+        RusotoError<CreateConfigurationError>,
+    > {
+        let request = self.build_create_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBrokerResponse, _>()?;
+                .deserialize::<CreateConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBrokerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Creates a new configuration for the specified configuration name. Amazon MQ uses the default configuration (the engine type and version).</p>
-    async fn create_configuration(
+    /// Presigns the request `create_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConfigurationRequest,
-    ) -> Result<CreateConfigurationResponse, RusotoError<CreateConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_request(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_tags`, also returning the metadata of the response.
+    pub async fn create_tags_with_metadata(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateTagsError>> {
+        let request = self.build_create_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateConfigurationResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateTagsError::from_response(response))
         }
     }
 
-    /// <p>Add a tag to a resource.</p>
-    async fn create_tags(
+    /// Presigns the request `create_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTagsRequest,
-    ) -> Result<(), RusotoError<CreateTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_tags_request(&self, input: CreateTagsRequest) -> SignedRequest {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_user`, also returning the metadata of the response.
+    pub async fn create_user_with_metadata(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<(CreateUserResponse, ResponseMetadata), RusotoError<CreateUserError>> {
+        let request = self.build_create_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateUserError::from_response(response))
         }
     }
 
-    /// <p>Creates an ActiveMQ user.</p>
-    async fn create_user(
+    /// Presigns the request `create_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserRequest,
-    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_request(&self, input: CreateUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/brokers/{broker_id}/users/{username}",
             broker_id = input.broker_id,
@@ -2595,63 +2904,111 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_broker`, also returning the metadata of the response.
+    pub async fn delete_broker_with_metadata(
+        &self,
+        input: DeleteBrokerRequest,
+    ) -> Result<(DeleteBrokerResponse, ResponseMetadata), RusotoError<DeleteBrokerError>> {
+        let request = self.build_delete_broker_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUserResponse, _>()?;
+                .deserialize::<DeleteBrokerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBrokerError::from_response(response))
         }
     }
 
-    /// <p>Deletes a broker. Note: This API is asynchronous.</p>
-    async fn delete_broker(
+    /// Presigns the request `delete_broker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_broker(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBrokerRequest,
-    ) -> Result<DeleteBrokerResponse, RusotoError<DeleteBrokerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_broker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_broker_request(&self, input: DeleteBrokerRequest) -> SignedRequest {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation("DeleteBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_tags`, also returning the metadata of the response.
+    pub async fn delete_tags_with_metadata(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteTagsError>> {
+        let request = self.build_delete_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteBrokerResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBrokerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteTagsError::from_response(response))
         }
     }
 
-    /// <p>Removes a tag from a resource.</p>
-    async fn delete_tags(
+    /// Presigns the request `delete_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTagsRequest,
-    ) -> Result<(), RusotoError<DeleteTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tags_request(&self, input: DeleteTagsRequest) -> SignedRequest {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2660,27 +3017,51 @@ impl MQ for MQClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_user`, also returning the metadata of the response.
+    pub async fn delete_user_with_metadata(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(DeleteUserResponse, ResponseMetadata), RusotoError<DeleteUserError>> {
+        let request = self.build_delete_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteUserError::from_response(response))
         }
     }
 
-    /// <p>Deletes an ActiveMQ user.</p>
-    async fn delete_user(
+    /// Presigns the request `delete_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserRequest,
-    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_request(&self, input: DeleteUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/brokers/{broker_id}/users/{username}",
             broker_id = input.broker_id,
@@ -2688,61 +3069,115 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_broker`, also returning the metadata of the response.
+    pub async fn describe_broker_with_metadata(
+        &self,
+        input: DescribeBrokerRequest,
+    ) -> Result<(DescribeBrokerResponse, ResponseMetadata), RusotoError<DescribeBrokerError>> {
+        let request = self.build_describe_broker_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteUserResponse, _>()?;
+                .deserialize::<DescribeBrokerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeBrokerError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified broker.</p>
-    async fn describe_broker(
+    /// Presigns the request `describe_broker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_broker(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBrokerRequest,
-    ) -> Result<DescribeBrokerResponse, RusotoError<DescribeBrokerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_broker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_broker_request(&self, input: DescribeBrokerRequest) -> SignedRequest {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_broker_engine_types`, also returning the metadata of the response.
+    pub async fn describe_broker_engine_types_with_metadata(
+        &self,
+        input: DescribeBrokerEngineTypesRequest,
+    ) -> Result<
+        (DescribeBrokerEngineTypesResponse, ResponseMetadata),
+        RusotoError<DescribeBrokerEngineTypesError>,
+    > {
+        let request = self.build_describe_broker_engine_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBrokerResponse, _>()?;
+                .deserialize::<DescribeBrokerEngineTypesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBrokerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeBrokerEngineTypesError::from_response(response))
         }
     }
 
-    /// <p>Describe available engine types and versions.</p>
-    async fn describe_broker_engine_types(
+    /// Presigns the request `describe_broker_engine_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_broker_engine_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBrokerEngineTypesRequest,
-    ) -> Result<DescribeBrokerEngineTypesResponse, RusotoError<DescribeBrokerEngineTypesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_broker_engine_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_broker_engine_types_request(
+        &self,
+        input: DescribeBrokerEngineTypesRequest,
+    ) -> SignedRequest {
         let request_uri = "/v1/broker-engine-types";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeBrokerEngineTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2757,34 +3192,61 @@ impl MQ for MQClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_broker_instance_options`, also returning the metadata of the response.
+    pub async fn describe_broker_instance_options_with_metadata(
+        &self,
+        input: DescribeBrokerInstanceOptionsRequest,
+    ) -> Result<
+        (DescribeBrokerInstanceOptionsResponse, ResponseMetadata),
+        RusotoError<DescribeBrokerInstanceOptionsError>,
+    > {
+        let request = self.build_describe_broker_instance_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBrokerEngineTypesResponse, _>()?;
+                .deserialize::<DescribeBrokerInstanceOptionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBrokerEngineTypesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeBrokerInstanceOptionsError::from_response(response))
         }
     }
 
-    /// <p>Describe available broker instance options.</p>
-    async fn describe_broker_instance_options(
+    /// Presigns the request `describe_broker_instance_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_broker_instance_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBrokerInstanceOptionsRequest,
-    ) -> Result<
-        DescribeBrokerInstanceOptionsResponse,
-        RusotoError<DescribeBrokerInstanceOptionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_broker_instance_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_broker_instance_options_request(
+        &self,
+        input: DescribeBrokerInstanceOptionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/v1/broker-instance-options";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeBrokerInstanceOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2805,61 +3267,117 @@ impl MQ for MQClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_configuration`, also returning the metadata of the response.
+    pub async fn describe_configuration_with_metadata(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> Result<
+        (DescribeConfigurationResponse, ResponseMetadata),
+        RusotoError<DescribeConfigurationError>,
+    > {
+        let request = self.build_describe_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBrokerInstanceOptionsResponse, _>()?;
+                .deserialize::<DescribeConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBrokerInstanceOptionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified configuration.</p>
-    async fn describe_configuration(
+    /// Presigns the request `describe_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigurationRequest,
-    ) -> Result<DescribeConfigurationResponse, RusotoError<DescribeConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_request(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/configurations/{configuration_id}",
             configuration_id = input.configuration_id
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_configuration_revision`, also returning the metadata of the response.
+    pub async fn describe_configuration_revision_with_metadata(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> Result<
+        (DescribeConfigurationRevisionResponse, ResponseMetadata),
+        RusotoError<DescribeConfigurationRevisionError>,
+    > {
+        let request = self.build_describe_configuration_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigurationResponse, _>()?;
+                .deserialize::<DescribeConfigurationRevisionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeConfigurationRevisionError::from_response(response))
         }
     }
 
-    /// <p>Returns the specified configuration revision for the specified configuration.</p>
-    async fn describe_configuration_revision(
+    /// Presigns the request `describe_configuration_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration_revision(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigurationRevisionRequest,
-    ) -> Result<
-        DescribeConfigurationRevisionResponse,
-        RusotoError<DescribeConfigurationRevisionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_revision_request(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/configurations/{configuration_id}/revisions/{configuration_revision}",
             configuration_id = input.configuration_id,
@@ -2867,30 +3385,54 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeConfigurationRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_user`, also returning the metadata of the response.
+    pub async fn describe_user_with_metadata(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<(DescribeUserResponse, ResponseMetadata), RusotoError<DescribeUserError>> {
+        let request = self.build_describe_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigurationRevisionResponse, _>()?;
+                .deserialize::<DescribeUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigurationRevisionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeUserError::from_response(response))
         }
     }
 
-    /// <p>Returns information about an ActiveMQ user.</p>
-    async fn describe_user(
+    /// Presigns the request `describe_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserRequest,
-    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_request(&self, input: DescribeUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/brokers/{broker_id}/users/{username}",
             broker_id = input.broker_id,
@@ -2898,33 +3440,58 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_brokers`, also returning the metadata of the response.
+    pub async fn list_brokers_with_metadata(
+        &self,
+        input: ListBrokersRequest,
+    ) -> Result<(ListBrokersResponse, ResponseMetadata), RusotoError<ListBrokersError>> {
+        let request = self.build_list_brokers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserResponse, _>()?;
+                .deserialize::<ListBrokersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBrokersError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all brokers.</p>
-    async fn list_brokers(
+    /// Presigns the request `list_brokers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_brokers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBrokersRequest,
-    ) -> Result<ListBrokersResponse, RusotoError<ListBrokersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_brokers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_brokers_request(&self, input: ListBrokersRequest) -> SignedRequest {
         let request_uri = "/v1/brokers";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListBrokers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2936,35 +3503,64 @@ impl MQ for MQClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_configuration_revisions`, also returning the metadata of the response.
+    pub async fn list_configuration_revisions_with_metadata(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> Result<
+        (ListConfigurationRevisionsResponse, ResponseMetadata),
+        RusotoError<ListConfigurationRevisionsError>,
+    > {
+        let request = self.build_list_configuration_revisions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBrokersResponse, _>()?;
+                .deserialize::<ListConfigurationRevisionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBrokersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConfigurationRevisionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all revisions for the specified configuration.</p>
-    async fn list_configuration_revisions(
+    /// Presigns the request `list_configuration_revisions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configuration_revisions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigurationRevisionsRequest,
-    ) -> Result<ListConfigurationRevisionsResponse, RusotoError<ListConfigurationRevisionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configuration_revisions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configuration_revisions_request(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/configurations/{configuration_id}/revisions",
             configuration_id = input.configuration_id
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListConfigurationRevisions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2976,31 +3572,56 @@ impl MQ for MQClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_configurations`, also returning the metadata of the response.
+    pub async fn list_configurations_with_metadata(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<(ListConfigurationsResponse, ResponseMetadata), RusotoError<ListConfigurationsError>>
+    {
+        let request = self.build_list_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConfigurationRevisionsResponse, _>()?;
+                .deserialize::<ListConfigurationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigurationRevisionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all configurations.</p>
-    async fn list_configurations(
+    /// Presigns the request `list_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigurationsRequest,
-    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configurations_request(&self, input: ListConfigurationsRequest) -> SignedRequest {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3012,58 +3633,106 @@ impl MQ for MQClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<(ListTagsResponse, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConfigurationsResponse, _>()?;
+                .deserialize::<ListTagsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigurationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Lists tags for a resource.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsRequest,
-    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsRequest) -> SignedRequest {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_users`, also returning the metadata of the response.
+    pub async fn list_users_with_metadata(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<(ListUsersResponse, ResponseMetadata), RusotoError<ListUsersError>> {
+        let request = self.build_list_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsResponse, _>()?;
+                .deserialize::<ListUsersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListUsersError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all ActiveMQ users.</p>
-    async fn list_users(
+    /// Presigns the request `list_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUsersRequest,
-    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_users_request(&self, input: ListUsersRequest) -> SignedRequest {
         let request_uri = format!("/v1/brokers/{broker_id}/users", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3075,121 +3744,222 @@ impl MQ for MQClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `reboot_broker`, also returning the metadata of the response.
+    pub async fn reboot_broker_with_metadata(
+        &self,
+        input: RebootBrokerRequest,
+    ) -> Result<(RebootBrokerResponse, ResponseMetadata), RusotoError<RebootBrokerError>> {
+        let request = self.build_reboot_broker_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUsersResponse, _>()?;
+                .deserialize::<RebootBrokerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUsersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RebootBrokerError::from_response(response))
         }
     }
 
-    /// <p>Reboots a broker. Note: This API is asynchronous.</p>
-    async fn reboot_broker(
+    /// Presigns the request `reboot_broker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_broker(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RebootBrokerRequest,
-    ) -> Result<RebootBrokerResponse, RusotoError<RebootBrokerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_broker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_broker_request(&self, input: RebootBrokerRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/brokers/{broker_id}/reboot",
             broker_id = input.broker_id
         );
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("RebootBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `update_broker`, also returning the metadata of the response.
+    pub async fn update_broker_with_metadata(
+        &self,
+        input: UpdateBrokerRequest,
+    ) -> Result<(UpdateBrokerResponse, ResponseMetadata), RusotoError<UpdateBrokerError>> {
+        let request = self.build_update_broker_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RebootBrokerResponse, _>()?;
+                .deserialize::<UpdateBrokerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RebootBrokerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateBrokerError::from_response(response))
         }
     }
 
-    /// <p>Adds a pending configuration change to a broker.</p>
-    async fn update_broker(
+    /// Presigns the request `update_broker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_broker(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBrokerRequest,
-    ) -> Result<UpdateBrokerResponse, RusotoError<UpdateBrokerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_broker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_broker_request(&self, input: UpdateBrokerRequest) -> SignedRequest {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation("UpdateBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_configuration`, also returning the metadata of the response.
+    pub async fn update_configuration_with_metadata(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> Result<
+        (UpdateConfigurationResponse, ResponseMetadata),
+        RusotoError<UpdateConfigurationError>,
+    > {
+        let request = self.build_update_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateBrokerResponse, _>()?;
+                .deserialize::<UpdateConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBrokerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified configuration.</p>
-    async fn update_configuration(
+    /// Presigns the request `update_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConfigurationRequest,
-    ) -> Result<UpdateConfigurationResponse, RusotoError<UpdateConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_configuration_request(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/configurations/{configuration_id}",
             configuration_id = input.configuration_id
         );
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation("UpdateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_user`, also returning the metadata of the response.
+    pub async fn update_user_with_metadata(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<(UpdateUserResponse, ResponseMetadata), RusotoError<UpdateUserError>> {
+        let request = self.build_update_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateConfigurationResponse, _>()?;
+                .deserialize::<UpdateUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUserError::from_response(response))
         }
     }
 
-    /// <p>Updates the information for an ActiveMQ user.</p>
-    async fn update_user(
+    /// Presigns the request `update_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserRequest,
-    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_request(&self, input: UpdateUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/brokers/{broker_id}/users/{username}",
             broker_id = input.broker_id,
@@ -3197,25 +3967,279 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListBrokers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_brokers_pages(
+        &self,
+        input: ListBrokersRequest,
+    ) -> impl futures::Stream<Item = Result<ListBrokersResponse, RusotoError<ListBrokersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MQClient, input| async move { client.list_brokers(input).await },
+            |input: &ListBrokersRequest, output: &ListBrokersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBrokersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BrokerSummaries` of every page of `ListBrokers` results for `input`, as `list_brokers_pages` requests them.
+    pub fn list_brokers_stream(
+        &self,
+        input: ListBrokersRequest,
+    ) -> impl futures::Stream<Item = Result<BrokerSummary, RusotoError<ListBrokersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_brokers_pages(input), |page| {
+            page.broker_summaries.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl MQ for MQClient {
+    /// <p>Creates a broker. Note: This API is asynchronous.</p>
+    async fn create_broker(
+        &self,
+        input: CreateBrokerRequest,
+    ) -> Result<CreateBrokerResponse, RusotoError<CreateBrokerError>> {
+        self.create_broker_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateUserResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserError::from_response(response))
-        }
+    /// <p>Creates a new configuration for the specified configuration name. Amazon MQ uses the default configuration (the engine type and version).</p>
+    async fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> Result<CreateConfigurationResponse, RusotoError<CreateConfigurationError>> {
+        self.create_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add a tag to a resource.</p>
+    async fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.create_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an ActiveMQ user.</p>
+    async fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.create_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a broker. Note: This API is asynchronous.</p>
+    async fn delete_broker(
+        &self,
+        input: DeleteBrokerRequest,
+    ) -> Result<DeleteBrokerResponse, RusotoError<DeleteBrokerError>> {
+        self.delete_broker_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a tag from a resource.</p>
+    async fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.delete_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an ActiveMQ user.</p>
+    async fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.delete_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified broker.</p>
+    async fn describe_broker(
+        &self,
+        input: DescribeBrokerRequest,
+    ) -> Result<DescribeBrokerResponse, RusotoError<DescribeBrokerError>> {
+        self.describe_broker_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe available engine types and versions.</p>
+    async fn describe_broker_engine_types(
+        &self,
+        input: DescribeBrokerEngineTypesRequest,
+    ) -> Result<DescribeBrokerEngineTypesResponse, RusotoError<DescribeBrokerEngineTypesError>>
+    {
+        self.describe_broker_engine_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe available broker instance options.</p>
+    async fn describe_broker_instance_options(
+        &self,
+        input: DescribeBrokerInstanceOptionsRequest,
+    ) -> Result<
+        DescribeBrokerInstanceOptionsResponse,
+        RusotoError<DescribeBrokerInstanceOptionsError>,
+    > {
+        self.describe_broker_instance_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified configuration.</p>
+    async fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> Result<DescribeConfigurationResponse, RusotoError<DescribeConfigurationError>> {
+        self.describe_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the specified configuration revision for the specified configuration.</p>
+    async fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> Result<
+        DescribeConfigurationRevisionResponse,
+        RusotoError<DescribeConfigurationRevisionError>,
+    > {
+        self.describe_configuration_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about an ActiveMQ user.</p>
+    async fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.describe_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all brokers.</p>
+    async fn list_brokers(
+        &self,
+        input: ListBrokersRequest,
+    ) -> Result<ListBrokersResponse, RusotoError<ListBrokersError>> {
+        self.list_brokers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all revisions for the specified configuration.</p>
+    async fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> Result<ListConfigurationRevisionsResponse, RusotoError<ListConfigurationRevisionsError>>
+    {
+        self.list_configuration_revisions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all configurations.</p>
+    async fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+        self.list_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists tags for a resource.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all ActiveMQ users.</p>
+    async fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.list_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Reboots a broker. Note: This API is asynchronous.</p>
+    async fn reboot_broker(
+        &self,
+        input: RebootBrokerRequest,
+    ) -> Result<RebootBrokerResponse, RusotoError<RebootBrokerError>> {
+        self.reboot_broker_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a pending configuration change to a broker.</p>
+    async fn update_broker(
+        &self,
+        input: UpdateBrokerRequest,
+    ) -> Result<UpdateBrokerResponse, RusotoError<UpdateBrokerError>> {
+        self.update_broker_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified configuration.</p>
+    async fn update_configuration(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> Result<UpdateConfigurationResponse, RusotoError<UpdateConfigurationError>> {
+        self.update_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the information for an ActiveMQ user.</p>
+    async fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.update_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
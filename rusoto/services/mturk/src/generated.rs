@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1576,11 +1576,14 @@ pub struct WorkerBlock {
 
 /// Errors returned by AcceptQualificationRequest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptQualificationRequestError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptQualificationRequestError {
@@ -1590,17 +1593,21 @@ impl AcceptQualificationRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(AcceptQualificationRequestError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptQualificationRequestError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(AcceptQualificationRequestError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptQualificationRequestError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(AcceptQualificationRequestError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1612,17 +1619,21 @@ impl fmt::Display for AcceptQualificationRequestError {
         match *self {
             AcceptQualificationRequestError::RequestError(ref cause) => write!(f, "{}", cause),
             AcceptQualificationRequestError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            AcceptQualificationRequestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcceptQualificationRequestError {}
 /// Errors returned by ApproveAssignment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ApproveAssignmentError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ApproveAssignmentError {
@@ -1630,13 +1641,19 @@ impl ApproveAssignmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ApproveAssignmentError::RequestError(err.msg))
+                    return RusotoError::service(
+                        ApproveAssignmentError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ApproveAssignmentError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        ApproveAssignmentError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ApproveAssignmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1648,17 +1665,21 @@ impl fmt::Display for ApproveAssignmentError {
         match *self {
             ApproveAssignmentError::RequestError(ref cause) => write!(f, "{}", cause),
             ApproveAssignmentError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ApproveAssignmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ApproveAssignmentError {}
 /// Errors returned by AssociateQualificationWithWorker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateQualificationWithWorkerError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateQualificationWithWorkerError {
@@ -1668,17 +1689,24 @@ impl AssociateQualificationWithWorkerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateQualificationWithWorkerError::RequestError(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateQualificationWithWorkerError::ServiceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateQualificationWithWorkerError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1694,17 +1722,23 @@ impl fmt::Display for AssociateQualificationWithWorkerError {
             AssociateQualificationWithWorkerError::ServiceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateQualificationWithWorkerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateQualificationWithWorkerError {}
 /// Errors returned by CreateAdditionalAssignmentsForHIT
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAdditionalAssignmentsForHITError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAdditionalAssignmentsForHITError {
@@ -1714,17 +1748,24 @@ impl CreateAdditionalAssignmentsForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateAdditionalAssignmentsForHITError::RequestError(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateAdditionalAssignmentsForHITError::ServiceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateAdditionalAssignmentsForHITError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1740,17 +1781,23 @@ impl fmt::Display for CreateAdditionalAssignmentsForHITError {
             CreateAdditionalAssignmentsForHITError::ServiceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateAdditionalAssignmentsForHITError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateAdditionalAssignmentsForHITError {}
 /// Errors returned by CreateHIT
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHITError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHITError {
@@ -1758,13 +1805,13 @@ impl CreateHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateHITError::RequestError(err.msg))
+                    return RusotoError::service(CreateHITError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateHITError::ServiceFault(err.msg))
+                    return RusotoError::service(CreateHITError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHITError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1776,17 +1823,21 @@ impl fmt::Display for CreateHITError {
         match *self {
             CreateHITError::RequestError(ref cause) => write!(f, "{}", cause),
             CreateHITError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            CreateHITError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHITError {}
 /// Errors returned by CreateHITType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHITTypeError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHITTypeError {
@@ -1794,13 +1845,13 @@ impl CreateHITTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateHITTypeError::RequestError(err.msg))
+                    return RusotoError::service(CreateHITTypeError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateHITTypeError::ServiceFault(err.msg))
+                    return RusotoError::service(CreateHITTypeError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHITTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1812,17 +1863,21 @@ impl fmt::Display for CreateHITTypeError {
         match *self {
             CreateHITTypeError::RequestError(ref cause) => write!(f, "{}", cause),
             CreateHITTypeError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            CreateHITTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHITTypeError {}
 /// Errors returned by CreateHITWithHITType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHITWithHITTypeError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHITWithHITTypeError {
@@ -1830,13 +1885,19 @@ impl CreateHITWithHITTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateHITWithHITTypeError::RequestError(err.msg))
+                    return RusotoError::service(
+                        CreateHITWithHITTypeError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateHITWithHITTypeError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        CreateHITWithHITTypeError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHITWithHITTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1848,17 +1909,21 @@ impl fmt::Display for CreateHITWithHITTypeError {
         match *self {
             CreateHITWithHITTypeError::RequestError(ref cause) => write!(f, "{}", cause),
             CreateHITWithHITTypeError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            CreateHITWithHITTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHITWithHITTypeError {}
 /// Errors returned by CreateQualificationType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateQualificationTypeError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateQualificationTypeError {
@@ -1866,17 +1931,19 @@ impl CreateQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateQualificationTypeError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateQualificationTypeError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateQualificationTypeError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateQualificationTypeError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateQualificationTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1888,17 +1955,21 @@ impl fmt::Display for CreateQualificationTypeError {
         match *self {
             CreateQualificationTypeError::RequestError(ref cause) => write!(f, "{}", cause),
             CreateQualificationTypeError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            CreateQualificationTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateQualificationTypeError {}
 /// Errors returned by CreateWorkerBlock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateWorkerBlockError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateWorkerBlockError {
@@ -1906,13 +1977,19 @@ impl CreateWorkerBlockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateWorkerBlockError::RequestError(err.msg))
+                    return RusotoError::service(
+                        CreateWorkerBlockError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateWorkerBlockError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        CreateWorkerBlockError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateWorkerBlockError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1924,17 +2001,21 @@ impl fmt::Display for CreateWorkerBlockError {
         match *self {
             CreateWorkerBlockError::RequestError(ref cause) => write!(f, "{}", cause),
             CreateWorkerBlockError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            CreateWorkerBlockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateWorkerBlockError {}
 /// Errors returned by DeleteHIT
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteHITError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteHITError {
@@ -1942,13 +2023,13 @@ impl DeleteHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(DeleteHITError::RequestError(err.msg))
+                    return RusotoError::service(DeleteHITError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(DeleteHITError::ServiceFault(err.msg))
+                    return RusotoError::service(DeleteHITError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteHITError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1960,17 +2041,21 @@ impl fmt::Display for DeleteHITError {
         match *self {
             DeleteHITError::RequestError(ref cause) => write!(f, "{}", cause),
             DeleteHITError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            DeleteHITError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteHITError {}
 /// Errors returned by DeleteQualificationType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteQualificationTypeError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteQualificationTypeError {
@@ -1978,17 +2063,19 @@ impl DeleteQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(DeleteQualificationTypeError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteQualificationTypeError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(DeleteQualificationTypeError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteQualificationTypeError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteQualificationTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2000,17 +2087,21 @@ impl fmt::Display for DeleteQualificationTypeError {
         match *self {
             DeleteQualificationTypeError::RequestError(ref cause) => write!(f, "{}", cause),
             DeleteQualificationTypeError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            DeleteQualificationTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteQualificationTypeError {}
 /// Errors returned by DeleteWorkerBlock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteWorkerBlockError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteWorkerBlockError {
@@ -2018,13 +2109,19 @@ impl DeleteWorkerBlockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(DeleteWorkerBlockError::RequestError(err.msg))
+                    return RusotoError::service(
+                        DeleteWorkerBlockError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(DeleteWorkerBlockError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        DeleteWorkerBlockError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteWorkerBlockError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2036,17 +2133,21 @@ impl fmt::Display for DeleteWorkerBlockError {
         match *self {
             DeleteWorkerBlockError::RequestError(ref cause) => write!(f, "{}", cause),
             DeleteWorkerBlockError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            DeleteWorkerBlockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteWorkerBlockError {}
 /// Errors returned by DisassociateQualificationFromWorker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateQualificationFromWorkerError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateQualificationFromWorkerError {
@@ -2056,17 +2157,24 @@ impl DisassociateQualificationFromWorkerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateQualificationFromWorkerError::RequestError(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateQualificationFromWorkerError::ServiceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateQualificationFromWorkerError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2082,17 +2190,23 @@ impl fmt::Display for DisassociateQualificationFromWorkerError {
             DisassociateQualificationFromWorkerError::ServiceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateQualificationFromWorkerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateQualificationFromWorkerError {}
 /// Errors returned by GetAccountBalance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAccountBalanceError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAccountBalanceError {
@@ -2100,13 +2214,19 @@ impl GetAccountBalanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetAccountBalanceError::RequestError(err.msg))
+                    return RusotoError::service(
+                        GetAccountBalanceError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetAccountBalanceError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        GetAccountBalanceError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAccountBalanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2118,17 +2238,21 @@ impl fmt::Display for GetAccountBalanceError {
         match *self {
             GetAccountBalanceError::RequestError(ref cause) => write!(f, "{}", cause),
             GetAccountBalanceError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            GetAccountBalanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAccountBalanceError {}
 /// Errors returned by GetAssignment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAssignmentError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAssignmentError {
@@ -2136,13 +2260,13 @@ impl GetAssignmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetAssignmentError::RequestError(err.msg))
+                    return RusotoError::service(GetAssignmentError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetAssignmentError::ServiceFault(err.msg))
+                    return RusotoError::service(GetAssignmentError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAssignmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2154,17 +2278,21 @@ impl fmt::Display for GetAssignmentError {
         match *self {
             GetAssignmentError::RequestError(ref cause) => write!(f, "{}", cause),
             GetAssignmentError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            GetAssignmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAssignmentError {}
 /// Errors returned by GetFileUploadURL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFileUploadURLError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFileUploadURLError {
@@ -2172,13 +2300,13 @@ impl GetFileUploadURLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetFileUploadURLError::RequestError(err.msg))
+                    return RusotoError::service(GetFileUploadURLError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetFileUploadURLError::ServiceFault(err.msg))
+                    return RusotoError::service(GetFileUploadURLError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFileUploadURLError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2190,27 +2318,35 @@ impl fmt::Display for GetFileUploadURLError {
         match *self {
             GetFileUploadURLError::RequestError(ref cause) => write!(f, "{}", cause),
             GetFileUploadURLError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            GetFileUploadURLError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFileUploadURLError {}
 /// Errors returned by GetHIT
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetHITError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetHITError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetHITError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "RequestError" => return RusotoError::Service(GetHITError::RequestError(err.msg)),
-                "ServiceFault" => return RusotoError::Service(GetHITError::ServiceFault(err.msg)),
+                "RequestError" => {
+                    return RusotoError::service(GetHITError::RequestError(err.msg), &res)
+                }
+                "ServiceFault" => {
+                    return RusotoError::service(GetHITError::ServiceFault(err.msg), &res)
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetHITError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2222,17 +2358,21 @@ impl fmt::Display for GetHITError {
         match *self {
             GetHITError::RequestError(ref cause) => write!(f, "{}", cause),
             GetHITError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            GetHITError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetHITError {}
 /// Errors returned by GetQualificationScore
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetQualificationScoreError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetQualificationScoreError {
@@ -2240,13 +2380,19 @@ impl GetQualificationScoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetQualificationScoreError::RequestError(err.msg))
+                    return RusotoError::service(
+                        GetQualificationScoreError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetQualificationScoreError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        GetQualificationScoreError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetQualificationScoreError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2258,17 +2404,21 @@ impl fmt::Display for GetQualificationScoreError {
         match *self {
             GetQualificationScoreError::RequestError(ref cause) => write!(f, "{}", cause),
             GetQualificationScoreError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            GetQualificationScoreError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetQualificationScoreError {}
 /// Errors returned by GetQualificationType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetQualificationTypeError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetQualificationTypeError {
@@ -2276,13 +2426,19 @@ impl GetQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetQualificationTypeError::RequestError(err.msg))
+                    return RusotoError::service(
+                        GetQualificationTypeError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetQualificationTypeError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        GetQualificationTypeError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetQualificationTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2294,17 +2450,21 @@ impl fmt::Display for GetQualificationTypeError {
         match *self {
             GetQualificationTypeError::RequestError(ref cause) => write!(f, "{}", cause),
             GetQualificationTypeError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            GetQualificationTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetQualificationTypeError {}
 /// Errors returned by ListAssignmentsForHIT
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssignmentsForHITError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssignmentsForHITError {
@@ -2312,13 +2472,19 @@ impl ListAssignmentsForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListAssignmentsForHITError::RequestError(err.msg))
+                    return RusotoError::service(
+                        ListAssignmentsForHITError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListAssignmentsForHITError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        ListAssignmentsForHITError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssignmentsForHITError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2330,17 +2496,21 @@ impl fmt::Display for ListAssignmentsForHITError {
         match *self {
             ListAssignmentsForHITError::RequestError(ref cause) => write!(f, "{}", cause),
             ListAssignmentsForHITError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ListAssignmentsForHITError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAssignmentsForHITError {}
 /// Errors returned by ListBonusPayments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBonusPaymentsError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBonusPaymentsError {
@@ -2348,13 +2518,19 @@ impl ListBonusPaymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListBonusPaymentsError::RequestError(err.msg))
+                    return RusotoError::service(
+                        ListBonusPaymentsError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListBonusPaymentsError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        ListBonusPaymentsError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBonusPaymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2366,17 +2542,21 @@ impl fmt::Display for ListBonusPaymentsError {
         match *self {
             ListBonusPaymentsError::RequestError(ref cause) => write!(f, "{}", cause),
             ListBonusPaymentsError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ListBonusPaymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBonusPaymentsError {}
 /// Errors returned by ListHITs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHITsError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHITsError {
@@ -2384,13 +2564,13 @@ impl ListHITsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListHITsError::RequestError(err.msg))
+                    return RusotoError::service(ListHITsError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListHITsError::ServiceFault(err.msg))
+                    return RusotoError::service(ListHITsError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListHITsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2402,17 +2582,21 @@ impl fmt::Display for ListHITsError {
         match *self {
             ListHITsError::RequestError(ref cause) => write!(f, "{}", cause),
             ListHITsError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ListHITsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHITsError {}
 /// Errors returned by ListHITsForQualificationType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHITsForQualificationTypeError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHITsForQualificationTypeError {
@@ -2422,17 +2606,24 @@ impl ListHITsForQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListHITsForQualificationTypeError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHITsForQualificationTypeError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListHITsForQualificationTypeError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHITsForQualificationTypeError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListHITsForQualificationTypeError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2444,17 +2635,21 @@ impl fmt::Display for ListHITsForQualificationTypeError {
         match *self {
             ListHITsForQualificationTypeError::RequestError(ref cause) => write!(f, "{}", cause),
             ListHITsForQualificationTypeError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ListHITsForQualificationTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHITsForQualificationTypeError {}
 /// Errors returned by ListQualificationRequests
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListQualificationRequestsError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListQualificationRequestsError {
@@ -2462,17 +2657,21 @@ impl ListQualificationRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListQualificationRequestsError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListQualificationRequestsError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListQualificationRequestsError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListQualificationRequestsError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListQualificationRequestsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2484,17 +2683,21 @@ impl fmt::Display for ListQualificationRequestsError {
         match *self {
             ListQualificationRequestsError::RequestError(ref cause) => write!(f, "{}", cause),
             ListQualificationRequestsError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ListQualificationRequestsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListQualificationRequestsError {}
 /// Errors returned by ListQualificationTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListQualificationTypesError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListQualificationTypesError {
@@ -2502,13 +2705,19 @@ impl ListQualificationTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListQualificationTypesError::RequestError(err.msg))
+                    return RusotoError::service(
+                        ListQualificationTypesError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListQualificationTypesError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        ListQualificationTypesError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListQualificationTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2520,17 +2729,21 @@ impl fmt::Display for ListQualificationTypesError {
         match *self {
             ListQualificationTypesError::RequestError(ref cause) => write!(f, "{}", cause),
             ListQualificationTypesError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ListQualificationTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListQualificationTypesError {}
 /// Errors returned by ListReviewPolicyResultsForHIT
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListReviewPolicyResultsForHITError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListReviewPolicyResultsForHITError {
@@ -2540,17 +2753,24 @@ impl ListReviewPolicyResultsForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListReviewPolicyResultsForHITError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListReviewPolicyResultsForHITError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListReviewPolicyResultsForHITError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListReviewPolicyResultsForHITError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListReviewPolicyResultsForHITError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2562,17 +2782,23 @@ impl fmt::Display for ListReviewPolicyResultsForHITError {
         match *self {
             ListReviewPolicyResultsForHITError::RequestError(ref cause) => write!(f, "{}", cause),
             ListReviewPolicyResultsForHITError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ListReviewPolicyResultsForHITError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListReviewPolicyResultsForHITError {}
 /// Errors returned by ListReviewableHITs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListReviewableHITsError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListReviewableHITsError {
@@ -2580,13 +2806,19 @@ impl ListReviewableHITsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListReviewableHITsError::RequestError(err.msg))
+                    return RusotoError::service(
+                        ListReviewableHITsError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListReviewableHITsError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        ListReviewableHITsError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListReviewableHITsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2598,17 +2830,21 @@ impl fmt::Display for ListReviewableHITsError {
         match *self {
             ListReviewableHITsError::RequestError(ref cause) => write!(f, "{}", cause),
             ListReviewableHITsError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ListReviewableHITsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListReviewableHITsError {}
 /// Errors returned by ListWorkerBlocks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListWorkerBlocksError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListWorkerBlocksError {
@@ -2616,13 +2852,13 @@ impl ListWorkerBlocksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListWorkerBlocksError::RequestError(err.msg))
+                    return RusotoError::service(ListWorkerBlocksError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListWorkerBlocksError::ServiceFault(err.msg))
+                    return RusotoError::service(ListWorkerBlocksError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListWorkerBlocksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2634,17 +2870,21 @@ impl fmt::Display for ListWorkerBlocksError {
         match *self {
             ListWorkerBlocksError::RequestError(ref cause) => write!(f, "{}", cause),
             ListWorkerBlocksError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            ListWorkerBlocksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListWorkerBlocksError {}
 /// Errors returned by ListWorkersWithQualificationType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListWorkersWithQualificationTypeError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListWorkersWithQualificationTypeError {
@@ -2654,17 +2894,24 @@ impl ListWorkersWithQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListWorkersWithQualificationTypeError::RequestError(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListWorkersWithQualificationTypeError::ServiceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListWorkersWithQualificationTypeError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2680,17 +2927,23 @@ impl fmt::Display for ListWorkersWithQualificationTypeError {
             ListWorkersWithQualificationTypeError::ServiceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListWorkersWithQualificationTypeError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListWorkersWithQualificationTypeError {}
 /// Errors returned by NotifyWorkers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum NotifyWorkersError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl NotifyWorkersError {
@@ -2698,13 +2951,13 @@ impl NotifyWorkersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(NotifyWorkersError::RequestError(err.msg))
+                    return RusotoError::service(NotifyWorkersError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(NotifyWorkersError::ServiceFault(err.msg))
+                    return RusotoError::service(NotifyWorkersError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(NotifyWorkersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2716,17 +2969,21 @@ impl fmt::Display for NotifyWorkersError {
         match *self {
             NotifyWorkersError::RequestError(ref cause) => write!(f, "{}", cause),
             NotifyWorkersError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            NotifyWorkersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for NotifyWorkersError {}
 /// Errors returned by RejectAssignment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RejectAssignmentError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RejectAssignmentError {
@@ -2734,13 +2991,13 @@ impl RejectAssignmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(RejectAssignmentError::RequestError(err.msg))
+                    return RusotoError::service(RejectAssignmentError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(RejectAssignmentError::ServiceFault(err.msg))
+                    return RusotoError::service(RejectAssignmentError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RejectAssignmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2752,17 +3009,21 @@ impl fmt::Display for RejectAssignmentError {
         match *self {
             RejectAssignmentError::RequestError(ref cause) => write!(f, "{}", cause),
             RejectAssignmentError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            RejectAssignmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RejectAssignmentError {}
 /// Errors returned by RejectQualificationRequest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RejectQualificationRequestError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RejectQualificationRequestError {
@@ -2772,17 +3033,21 @@ impl RejectQualificationRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(RejectQualificationRequestError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RejectQualificationRequestError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(RejectQualificationRequestError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RejectQualificationRequestError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(RejectQualificationRequestError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2794,17 +3059,21 @@ impl fmt::Display for RejectQualificationRequestError {
         match *self {
             RejectQualificationRequestError::RequestError(ref cause) => write!(f, "{}", cause),
             RejectQualificationRequestError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            RejectQualificationRequestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RejectQualificationRequestError {}
 /// Errors returned by SendBonus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendBonusError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendBonusError {
@@ -2812,13 +3081,13 @@ impl SendBonusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(SendBonusError::RequestError(err.msg))
+                    return RusotoError::service(SendBonusError::RequestError(err.msg), &res)
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(SendBonusError::ServiceFault(err.msg))
+                    return RusotoError::service(SendBonusError::ServiceFault(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendBonusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2830,17 +3099,21 @@ impl fmt::Display for SendBonusError {
         match *self {
             SendBonusError::RequestError(ref cause) => write!(f, "{}", cause),
             SendBonusError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            SendBonusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendBonusError {}
 /// Errors returned by SendTestEventNotification
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendTestEventNotificationError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendTestEventNotificationError {
@@ -2848,17 +3121,21 @@ impl SendTestEventNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(SendTestEventNotificationError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendTestEventNotificationError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(SendTestEventNotificationError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendTestEventNotificationError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(SendTestEventNotificationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2870,17 +3147,21 @@ impl fmt::Display for SendTestEventNotificationError {
         match *self {
             SendTestEventNotificationError::RequestError(ref cause) => write!(f, "{}", cause),
             SendTestEventNotificationError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            SendTestEventNotificationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendTestEventNotificationError {}
 /// Errors returned by UpdateExpirationForHIT
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateExpirationForHITError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateExpirationForHITError {
@@ -2888,13 +3169,19 @@ impl UpdateExpirationForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateExpirationForHITError::RequestError(err.msg))
+                    return RusotoError::service(
+                        UpdateExpirationForHITError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateExpirationForHITError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        UpdateExpirationForHITError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateExpirationForHITError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2906,17 +3193,21 @@ impl fmt::Display for UpdateExpirationForHITError {
         match *self {
             UpdateExpirationForHITError::RequestError(ref cause) => write!(f, "{}", cause),
             UpdateExpirationForHITError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            UpdateExpirationForHITError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateExpirationForHITError {}
 /// Errors returned by UpdateHITReviewStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateHITReviewStatusError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateHITReviewStatusError {
@@ -2924,13 +3215,19 @@ impl UpdateHITReviewStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateHITReviewStatusError::RequestError(err.msg))
+                    return RusotoError::service(
+                        UpdateHITReviewStatusError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateHITReviewStatusError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        UpdateHITReviewStatusError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateHITReviewStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2942,17 +3239,21 @@ impl fmt::Display for UpdateHITReviewStatusError {
         match *self {
             UpdateHITReviewStatusError::RequestError(ref cause) => write!(f, "{}", cause),
             UpdateHITReviewStatusError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            UpdateHITReviewStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateHITReviewStatusError {}
 /// Errors returned by UpdateHITTypeOfHIT
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateHITTypeOfHITError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateHITTypeOfHITError {
@@ -2960,13 +3261,19 @@ impl UpdateHITTypeOfHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateHITTypeOfHITError::RequestError(err.msg))
+                    return RusotoError::service(
+                        UpdateHITTypeOfHITError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateHITTypeOfHITError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        UpdateHITTypeOfHITError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateHITTypeOfHITError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2978,17 +3285,21 @@ impl fmt::Display for UpdateHITTypeOfHITError {
         match *self {
             UpdateHITTypeOfHITError::RequestError(ref cause) => write!(f, "{}", cause),
             UpdateHITTypeOfHITError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            UpdateHITTypeOfHITError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateHITTypeOfHITError {}
 /// Errors returned by UpdateNotificationSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNotificationSettingsError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNotificationSettingsError {
@@ -2998,17 +3309,21 @@ impl UpdateNotificationSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateNotificationSettingsError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNotificationSettingsError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateNotificationSettingsError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNotificationSettingsError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateNotificationSettingsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3020,17 +3335,21 @@ impl fmt::Display for UpdateNotificationSettingsError {
         match *self {
             UpdateNotificationSettingsError::RequestError(ref cause) => write!(f, "{}", cause),
             UpdateNotificationSettingsError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            UpdateNotificationSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateNotificationSettingsError {}
 /// Errors returned by UpdateQualificationType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateQualificationTypeError {
     /// <p>Your request is invalid.</p>
     RequestError(String),
     /// <p>Amazon Mechanical Turk is temporarily unable to process your request. Try your call again.</p>
     ServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateQualificationTypeError {
@@ -3038,17 +3357,19 @@ impl UpdateQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateQualificationTypeError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateQualificationTypeError::RequestError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateQualificationTypeError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateQualificationTypeError::ServiceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateQualificationTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3060,6 +3381,7 @@ impl fmt::Display for UpdateQualificationTypeError {
         match *self {
             UpdateQualificationTypeError::RequestError(ref cause) => write!(f, "{}", cause),
             UpdateQualificationTypeError::ServiceFault(ref cause) => write!(f, "{}", cause),
+            UpdateQualificationTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3326,6 +3648,7 @@ impl MechanicalTurkClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> MechanicalTurkClient {
         MechanicalTurkClient {
             client: Client::shared(),
@@ -3351,17 +3674,73 @@ impl MechanicalTurkClient {
     pub fn new_with_client(client: Client, region: region::Region) -> MechanicalTurkClient {
         MechanicalTurkClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<MechanicalTurkClient> {
+        rusoto_core::ClientBuilder::new(MechanicalTurkClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> MechanicalTurkClient {
+        MechanicalTurkClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl MechanicalTurk for MechanicalTurkClient {
-    /// <p> The <code>AcceptQualificationRequest</code> operation approves a Worker's request for a Qualification. </p> <p> Only the owner of the Qualification type can grant a Qualification request for that type. </p> <p> A successful request for the <code>AcceptQualificationRequest</code> operation returns with no errors and an empty body. </p>
-    async fn accept_qualification_request(
+impl MechanicalTurkClient {
+    /// Calls `accept_qualification_request`, also returning the metadata of the response.
+    pub async fn accept_qualification_request_with_metadata(
         &self,
         input: AcceptQualificationRequestRequest,
-    ) -> Result<AcceptQualificationRequestResponse, RusotoError<AcceptQualificationRequestError>>
-    {
+    ) -> Result<
+        (AcceptQualificationRequestResponse, ResponseMetadata),
+        RusotoError<AcceptQualificationRequestError>,
+    > {
+        let request = self.build_accept_qualification_request_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcceptQualificationRequestResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AcceptQualificationRequestError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `accept_qualification_request` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_qualification_request(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AcceptQualificationRequestRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_qualification_request_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_qualification_request_request(
+        &self,
+        input: AcceptQualificationRequestRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("AcceptQualificationRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3371,28 +3750,53 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `approve_assignment`, also returning the metadata of the response.
+    pub async fn approve_assignment_with_metadata(
+        &self,
+        input: ApproveAssignmentRequest,
+    ) -> Result<(ApproveAssignmentResponse, ResponseMetadata), RusotoError<ApproveAssignmentError>>
+    {
+        let request = self.build_approve_assignment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AcceptQualificationRequestResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ApproveAssignmentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AcceptQualificationRequestError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ApproveAssignmentError::from_response(response))
         }
     }
 
-    /// <p> The <code>ApproveAssignment</code> operation approves the results of a completed assignment. </p> <p> Approving an assignment initiates two payments from the Requester's Amazon.com account </p> <ul> <li> <p> The Worker who submitted the results is paid the reward specified in the HIT. </p> </li> <li> <p> Amazon Mechanical Turk fees are debited. </p> </li> </ul> <p> If the Requester's account does not have adequate funds for these payments, the call to ApproveAssignment returns an exception, and the approval is not processed. You can include an optional feedback message with the approval, which the Worker can see in the Status section of the web site. </p> <p> You can also call this operation for assignments that were previous rejected and approve them by explicitly overriding the previous rejection. This only works on rejected assignments that were submitted within the previous 30 days and only if the assignment's related HIT has not been deleted. </p>
-    async fn approve_assignment(
+    /// Presigns the request `approve_assignment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_approve_assignment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ApproveAssignmentRequest,
-    ) -> Result<ApproveAssignmentResponse, RusotoError<ApproveAssignmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_approve_assignment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_approve_assignment_request(&self, input: ApproveAssignmentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ApproveAssignment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3402,31 +3806,60 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_qualification_with_worker`, also returning the metadata of the response.
+    pub async fn associate_qualification_with_worker_with_metadata(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> Result<
+        (AssociateQualificationWithWorkerResponse, ResponseMetadata),
+        RusotoError<AssociateQualificationWithWorkerError>,
+    > {
+        let request = self.build_associate_qualification_with_worker_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ApproveAssignmentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateQualificationWithWorkerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ApproveAssignmentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateQualificationWithWorkerError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p> The <code>AssociateQualificationWithWorker</code> operation gives a Worker a Qualification. <code>AssociateQualificationWithWorker</code> does not require that the Worker submit a Qualification request. It gives the Qualification directly to the Worker. </p> <p> You can only assign a Qualification of a Qualification type that you created (using the <code>CreateQualificationType</code> operation). </p> <note> <p> Note: <code>AssociateQualificationWithWorker</code> does not affect any pending Qualification requests for the Qualification by the Worker. If you assign a Qualification to a Worker, then later grant a Qualification request made by the Worker, the granting of the request may modify the Qualification score. To resolve a pending Qualification request without affecting the Qualification the Worker already has, reject the request with the <code>RejectQualificationRequest</code> operation. </p> </note></p>
-    async fn associate_qualification_with_worker(
+    /// Presigns the request `associate_qualification_with_worker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_qualification_with_worker(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateQualificationWithWorkerRequest,
-    ) -> Result<
-        AssociateQualificationWithWorkerResponse,
-        RusotoError<AssociateQualificationWithWorkerError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_qualification_with_worker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_qualification_with_worker_request(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("AssociateQualificationWithWorker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3436,33 +3869,60 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_additional_assignments_for_hit`, also returning the metadata of the response.
+    pub async fn create_additional_assignments_for_hit_with_metadata(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> Result<
+        (CreateAdditionalAssignmentsForHITResponse, ResponseMetadata),
+        RusotoError<CreateAdditionalAssignmentsForHITError>,
+    > {
+        let request = self.build_create_additional_assignments_for_hit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateQualificationWithWorkerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAdditionalAssignmentsForHITResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateQualificationWithWorkerError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAdditionalAssignmentsForHITError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p><p> The <code>CreateAdditionalAssignmentsForHIT</code> operation increases the maximum number of assignments of an existing HIT. </p> <p> To extend the maximum number of assignments, specify the number of additional assignments.</p> <note> <ul> <li> <p>HITs created with fewer than 10 assignments cannot be extended to have 10 or more assignments. Attempting to add assignments in a way that brings the total number of assignments for a HIT from fewer than 10 assignments to 10 or more assignments will result in an <code>AWS.MechanicalTurk.InvalidMaximumAssignmentsIncrease</code> exception.</p> </li> <li> <p>HITs that were created before July 22, 2015 cannot be extended. Attempting to extend HITs that were created before July 22, 2015 will result in an <code>AWS.MechanicalTurk.HITTooOldForExtension</code> exception. </p> </li> </ul> </note></p>
-    async fn create_additional_assignments_for_hit(
+    /// Presigns the request `create_additional_assignments_for_hit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_additional_assignments_for_hit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAdditionalAssignmentsForHITRequest,
-    ) -> Result<
-        CreateAdditionalAssignmentsForHITResponse,
-        RusotoError<CreateAdditionalAssignmentsForHITError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_additional_assignments_for_hit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_additional_assignments_for_hit_request(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateAdditionalAssignmentsForHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3472,57 +3932,104 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_hit`, also returning the metadata of the response.
+    pub async fn create_hit_with_metadata(
+        &self,
+        input: CreateHITRequest,
+    ) -> Result<(CreateHITResponse, ResponseMetadata), RusotoError<CreateHITError>> {
+        let request = self.build_create_hit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAdditionalAssignmentsForHITResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHITResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAdditionalAssignmentsForHITError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHITError::from_response(response))
         }
     }
 
-    /// <p><p>The <code>CreateHIT</code> operation creates a new Human Intelligence Task (HIT). The new HIT is made available for Workers to find and accept on the Amazon Mechanical Turk website. </p> <p> This operation allows you to specify a new HIT by passing in values for the properties of the HIT, such as its title, reward amount and number of assignments. When you pass these values to <code>CreateHIT</code>, a new HIT is created for you, with a new <code>HITTypeID</code>. The HITTypeID can be used to create additional HITs in the future without needing to specify common parameters such as the title, description and reward amount each time.</p> <p> An alternative way to create HITs is to first generate a HITTypeID using the <code>CreateHITType</code> operation and then call the <code>CreateHITWithHITType</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHIT also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>.</p> </note></p>
-    async fn create_hit(
+    /// Presigns the request `create_hit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateHITRequest,
-    ) -> Result<CreateHITResponse, RusotoError<CreateHITError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_hit_request(&self, input: CreateHITRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.CreateHIT");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_hit_type`, also returning the metadata of the response.
+    pub async fn create_hit_type_with_metadata(
+        &self,
+        input: CreateHITTypeRequest,
+    ) -> Result<(CreateHITTypeResponse, ResponseMetadata), RusotoError<CreateHITTypeError>> {
+        let request = self.build_create_hit_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateHITResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHITTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHITError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHITTypeError::from_response(response))
         }
     }
 
-    /// <p> The <code>CreateHITType</code> operation creates a new HIT type. This operation allows you to define a standard set of HIT properties to use when creating HITs. If you register a HIT type with values that match an existing HIT type, the HIT type ID of the existing type will be returned. </p>
-    async fn create_hit_type(
+    /// Presigns the request `create_hit_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hit_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateHITTypeRequest,
-    ) -> Result<CreateHITTypeResponse, RusotoError<CreateHITTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hit_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_hit_type_request(&self, input: CreateHITTypeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateHITType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3532,27 +4039,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_hit_with_hit_type`, also returning the metadata of the response.
+    pub async fn create_hit_with_hit_type_with_metadata(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> Result<
+        (CreateHITWithHITTypeResponse, ResponseMetadata),
+        RusotoError<CreateHITWithHITTypeError>,
+    > {
+        let request = self.build_create_hit_with_hit_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateHITTypeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHITWithHITTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHITTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHITWithHITTypeError::from_response(response))
         }
     }
 
-    /// <p><p> The <code>CreateHITWithHITType</code> operation creates a new Human Intelligence Task (HIT) using an existing HITTypeID generated by the <code>CreateHITType</code> operation. </p> <p> This is an alternative way to create HITs from the <code>CreateHIT</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHITWithHITType also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>. </p> </note></p>
-    async fn create_hit_with_hit_type(
+    /// Presigns the request `create_hit_with_hit_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hit_with_hit_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateHITWithHITTypeRequest,
-    ) -> Result<CreateHITWithHITTypeResponse, RusotoError<CreateHITWithHITTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hit_with_hit_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_hit_with_hit_type_request(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateHITWithHITType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3562,28 +4100,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_qualification_type`, also returning the metadata of the response.
+    pub async fn create_qualification_type_with_metadata(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> Result<
+        (CreateQualificationTypeResponse, ResponseMetadata),
+        RusotoError<CreateQualificationTypeError>,
+    > {
+        let request = self.build_create_qualification_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateHITWithHITTypeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateQualificationTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHITWithHITTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateQualificationTypeError::from_response(response))
         }
     }
 
-    /// <p> The <code>CreateQualificationType</code> operation creates a new Qualification type, which is represented by a <code>QualificationType</code> data structure. </p>
-    async fn create_qualification_type(
+    /// Presigns the request `create_qualification_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_qualification_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateQualificationTypeRequest,
-    ) -> Result<CreateQualificationTypeResponse, RusotoError<CreateQualificationTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_qualification_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_qualification_type_request(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3593,28 +4161,53 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_worker_block`, also returning the metadata of the response.
+    pub async fn create_worker_block_with_metadata(
+        &self,
+        input: CreateWorkerBlockRequest,
+    ) -> Result<(CreateWorkerBlockResponse, ResponseMetadata), RusotoError<CreateWorkerBlockError>>
+    {
+        let request = self.build_create_worker_block_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateQualificationTypeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateWorkerBlockResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateQualificationTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateWorkerBlockError::from_response(response))
         }
     }
 
-    /// <p>The <code>CreateWorkerBlock</code> operation allows you to prevent a Worker from working on your HITs. For example, you can block a Worker who is producing poor quality work. You can block up to 100,000 Workers.</p>
-    async fn create_worker_block(
+    /// Presigns the request `create_worker_block` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_worker_block(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateWorkerBlockRequest,
-    ) -> Result<CreateWorkerBlockResponse, RusotoError<CreateWorkerBlockError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_worker_block_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_worker_block_request(&self, input: CreateWorkerBlockRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateWorkerBlock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3624,55 +4217,110 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_hit`, also returning the metadata of the response.
+    pub async fn delete_hit_with_metadata(
+        &self,
+        input: DeleteHITRequest,
+    ) -> Result<(DeleteHITResponse, ResponseMetadata), RusotoError<DeleteHITError>> {
+        let request = self.build_delete_hit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateWorkerBlockResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteHITResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateWorkerBlockError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteHITError::from_response(response))
         }
     }
 
-    /// <p><p> The <code>DeleteHIT</code> operation is used to delete HIT that is no longer needed. Only the Requester who created the HIT can delete it. </p> <p> You can only dispose of HITs that are in the <code>Reviewable</code> state, with all of their submitted assignments already either approved or rejected. If you call the DeleteHIT operation on a HIT that is not in the <code>Reviewable</code> state (for example, that has not expired, or still has active assignments), or on a HIT that is Reviewable but without all of its submitted assignments already approved or rejected, the service will return an error. </p> <note> <ul> <li> <p> HITs are automatically disposed of after 120 days. </p> </li> <li> <p> After you dispose of a HIT, you can no longer approve the HIT&#39;s rejected assignments. </p> </li> <li> <p> Disposed HITs are not returned in results for the ListHITs operation. </p> </li> <li> <p> Disposing HITs can improve the performance of operations such as ListReviewableHITs and ListHITs. </p> </li> </ul> </note></p>
-    async fn delete_hit(
+    /// Presigns the request `delete_hit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_hit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteHITRequest,
-    ) -> Result<DeleteHITResponse, RusotoError<DeleteHITError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_hit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_hit_request(&self, input: DeleteHITRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DeleteHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.DeleteHIT");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_qualification_type`, also returning the metadata of the response.
+    pub async fn delete_qualification_type_with_metadata(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> Result<
+        (DeleteQualificationTypeResponse, ResponseMetadata),
+        RusotoError<DeleteQualificationTypeError>,
+    > {
+        let request = self.build_delete_qualification_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteHITResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteQualificationTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteHITError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteQualificationTypeError::from_response(response))
         }
     }
 
-    /// <p><p> The <code>DeleteQualificationType</code> deletes a Qualification type and deletes any HIT types that are associated with the Qualification type. </p> <p>This operation does not revoke Qualifications already assigned to Workers because the Qualifications might be needed for active HITs. If there are any pending requests for the Qualification type, Amazon Mechanical Turk rejects those requests. After you delete a Qualification type, you can no longer use it to create HITs or HIT types.</p> <note> <p>DeleteQualificationType must wait for all the HITs that use the deleted Qualification type to be deleted before completing. It may take up to 48 hours before DeleteQualificationType completes and the unique name of the Qualification type is available for reuse with CreateQualificationType.</p> </note></p>
-    async fn delete_qualification_type(
+    /// Presigns the request `delete_qualification_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_qualification_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteQualificationTypeRequest,
-    ) -> Result<DeleteQualificationTypeResponse, RusotoError<DeleteQualificationTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_qualification_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_qualification_type_request(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DeleteQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3682,28 +4330,53 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_worker_block`, also returning the metadata of the response.
+    pub async fn delete_worker_block_with_metadata(
+        &self,
+        input: DeleteWorkerBlockRequest,
+    ) -> Result<(DeleteWorkerBlockResponse, ResponseMetadata), RusotoError<DeleteWorkerBlockError>>
+    {
+        let request = self.build_delete_worker_block_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteQualificationTypeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteWorkerBlockResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteQualificationTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteWorkerBlockError::from_response(response))
         }
     }
 
-    /// <p>The <code>DeleteWorkerBlock</code> operation allows you to reinstate a blocked Worker to work on your HITs. This operation reverses the effects of the CreateWorkerBlock operation. You need the Worker ID to use this operation. If the Worker ID is missing or invalid, this operation fails and returns the message “WorkerId is invalid.” If the specified Worker is not blocked, this operation returns successfully.</p>
-    async fn delete_worker_block(
+    /// Presigns the request `delete_worker_block` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_worker_block(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteWorkerBlockRequest,
-    ) -> Result<DeleteWorkerBlockResponse, RusotoError<DeleteWorkerBlockError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_worker_block_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_worker_block_request(&self, input: DeleteWorkerBlockRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DeleteWorkerBlock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3713,31 +4386,63 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_qualification_from_worker`, also returning the metadata of the response.
+    pub async fn disassociate_qualification_from_worker_with_metadata(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> Result<
+        (
+            DisassociateQualificationFromWorkerResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DisassociateQualificationFromWorkerError>,
+    > {
+        let request = self.build_disassociate_qualification_from_worker_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteWorkerBlockResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateQualificationFromWorkerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteWorkerBlockError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateQualificationFromWorkerError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p> The <code>DisassociateQualificationFromWorker</code> revokes a previously granted Qualification from a user. </p> <p> You can provide a text message explaining why the Qualification was revoked. The user who had the Qualification can see this message. </p>
-    async fn disassociate_qualification_from_worker(
+    /// Presigns the request `disassociate_qualification_from_worker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_qualification_from_worker(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateQualificationFromWorkerRequest,
-    ) -> Result<
-        DisassociateQualificationFromWorkerResponse,
-        RusotoError<DisassociateQualificationFromWorkerError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_qualification_from_worker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_qualification_from_worker_request(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DisassociateQualificationFromWorker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3747,29 +4452,51 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_account_balance`, also returning the metadata of the response.
+    pub async fn get_account_balance_with_metadata(
+        &self,
+    ) -> Result<(GetAccountBalanceResponse, ResponseMetadata), RusotoError<GetAccountBalanceError>>
+    {
+        let request = self.build_get_account_balance_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateQualificationFromWorkerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAccountBalanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateQualificationFromWorkerError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAccountBalanceError::from_response(response))
         }
     }
 
-    /// <p>The <code>GetAccountBalance</code> operation retrieves the amount of money in your Amazon Mechanical Turk account.</p>
-    async fn get_account_balance(
+    /// Presigns the request `get_account_balance` would send with `presigner` instead of sending it.
+    pub async fn presign_get_account_balance(
         &self,
-    ) -> Result<GetAccountBalanceResponse, RusotoError<GetAccountBalanceError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_account_balance_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_account_balance_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetAccountBalance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3778,28 +4505,52 @@ impl MechanicalTurk for MechanicalTurkClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_assignment`, also returning the metadata of the response.
+    pub async fn get_assignment_with_metadata(
+        &self,
+        input: GetAssignmentRequest,
+    ) -> Result<(GetAssignmentResponse, ResponseMetadata), RusotoError<GetAssignmentError>> {
+        let request = self.build_get_assignment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAccountBalanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAssignmentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAccountBalanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAssignmentError::from_response(response))
         }
     }
 
-    /// <p> The <code>GetAssignment</code> operation retrieves the details of the specified Assignment. </p>
-    async fn get_assignment(
+    /// Presigns the request `get_assignment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_assignment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAssignmentRequest,
-    ) -> Result<GetAssignmentResponse, RusotoError<GetAssignmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_assignment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_assignment_request(&self, input: GetAssignmentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetAssignment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3809,27 +4560,53 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_file_upload_url`, also returning the metadata of the response.
+    pub async fn get_file_upload_url_with_metadata(
+        &self,
+        input: GetFileUploadURLRequest,
+    ) -> Result<(GetFileUploadURLResponse, ResponseMetadata), RusotoError<GetFileUploadURLError>>
+    {
+        let request = self.build_get_file_upload_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetAssignmentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetFileUploadURLResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAssignmentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetFileUploadURLError::from_response(response))
         }
     }
 
-    /// <p> The <code>GetFileUploadURL</code> operation generates and returns a temporary URL. You use the temporary URL to retrieve a file uploaded by a Worker as an answer to a FileUploadAnswer question for a HIT. The temporary URL is generated the instant the GetFileUploadURL operation is called, and is valid for 60 seconds. You can get a temporary file upload URL any time until the HIT is disposed. After the HIT is disposed, any uploaded files are deleted, and cannot be retrieved. Pending Deprecation on December 12, 2017. The Answer Specification structure will no longer support the <code>FileUploadAnswer</code> element to be used for the QuestionForm data structure. Instead, we recommend that Requesters who want to create HITs asking Workers to upload files to use Amazon S3. </p>
-    async fn get_file_upload_url(
+    /// Presigns the request `get_file_upload_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_file_upload_url(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFileUploadURLRequest,
-    ) -> Result<GetFileUploadURLResponse, RusotoError<GetFileUploadURLError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_file_upload_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_file_upload_url_request(&self, input: GetFileUploadURLRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetFileUploadURL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3839,55 +4616,110 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_hit`, also returning the metadata of the response.
+    pub async fn get_hit_with_metadata(
+        &self,
+        input: GetHITRequest,
+    ) -> Result<(GetHITResponse, ResponseMetadata), RusotoError<GetHITError>> {
+        let request = self.build_get_hit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFileUploadURLResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetHITResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFileUploadURLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetHITError::from_response(response))
         }
     }
 
-    /// <p> The <code>GetHIT</code> operation retrieves the details of the specified HIT. </p>
-    async fn get_hit(
+    /// Presigns the request `get_hit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_hit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetHITRequest,
-    ) -> Result<GetHITResponse, RusotoError<GetHITError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_hit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_hit_request(&self, input: GetHITRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.GetHIT");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_qualification_score`, also returning the metadata of the response.
+    pub async fn get_qualification_score_with_metadata(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> Result<
+        (GetQualificationScoreResponse, ResponseMetadata),
+        RusotoError<GetQualificationScoreError>,
+    > {
+        let request = self.build_get_qualification_score_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetHITResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetQualificationScoreResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetHITError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetQualificationScoreError::from_response(response))
         }
     }
 
-    /// <p> The <code>GetQualificationScore</code> operation returns the value of a Worker's Qualification for a given Qualification type. </p> <p> To get a Worker's Qualification, you must know the Worker's ID. The Worker's ID is included in the assignment data returned by the <code>ListAssignmentsForHIT</code> operation. </p> <p>Only the owner of a Qualification type can query the value of a Worker's Qualification of that type.</p>
-    async fn get_qualification_score(
+    /// Presigns the request `get_qualification_score` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_qualification_score(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetQualificationScoreRequest,
-    ) -> Result<GetQualificationScoreResponse, RusotoError<GetQualificationScoreError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_qualification_score_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_qualification_score_request(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetQualificationScore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3897,28 +4729,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_qualification_type`, also returning the metadata of the response.
+    pub async fn get_qualification_type_with_metadata(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> Result<
+        (GetQualificationTypeResponse, ResponseMetadata),
+        RusotoError<GetQualificationTypeError>,
+    > {
+        let request = self.build_get_qualification_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetQualificationScoreResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetQualificationTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetQualificationScoreError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetQualificationTypeError::from_response(response))
         }
     }
 
-    /// <p> The <code>GetQualificationType</code>operation retrieves information about a Qualification type using its ID. </p>
-    async fn get_qualification_type(
+    /// Presigns the request `get_qualification_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_qualification_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetQualificationTypeRequest,
-    ) -> Result<GetQualificationTypeResponse, RusotoError<GetQualificationTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_qualification_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_qualification_type_request(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3928,28 +4790,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_assignments_for_hit`, also returning the metadata of the response.
+    pub async fn list_assignments_for_hit_with_metadata(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> Result<
+        (ListAssignmentsForHITResponse, ResponseMetadata),
+        RusotoError<ListAssignmentsForHITError>,
+    > {
+        let request = self.build_list_assignments_for_hit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetQualificationTypeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssignmentsForHITResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetQualificationTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssignmentsForHITError::from_response(response))
         }
     }
 
-    /// <p> The <code>ListAssignmentsForHIT</code> operation retrieves completed assignments for a HIT. You can use this operation to retrieve the results for a HIT. </p> <p> You can get assignments for a HIT at any time, even if the HIT is not yet Reviewable. If a HIT requested multiple assignments, and has received some results but has not yet become Reviewable, you can still retrieve the partial results with this operation. </p> <p> Use the AssignmentStatus parameter to control which set of assignments for a HIT are returned. The ListAssignmentsForHIT operation can return submitted assignments awaiting approval, or it can return assignments that have already been approved or rejected. You can set AssignmentStatus=Approved,Rejected to get assignments that have already been approved and rejected together in one result set. </p> <p> Only the Requester who created the HIT can retrieve the assignments for that HIT. </p> <p> Results are sorted and divided into numbered pages and the operation returns a single page of results. You can use the parameters of the operation to control sorting and pagination. </p>
-    async fn list_assignments_for_hit(
+    /// Presigns the request `list_assignments_for_hit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_assignments_for_hit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssignmentsForHITRequest,
-    ) -> Result<ListAssignmentsForHITResponse, RusotoError<ListAssignmentsForHITError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_assignments_for_hit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_assignments_for_hit_request(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListAssignmentsForHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3959,28 +4851,53 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_bonus_payments`, also returning the metadata of the response.
+    pub async fn list_bonus_payments_with_metadata(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> Result<(ListBonusPaymentsResponse, ResponseMetadata), RusotoError<ListBonusPaymentsError>>
+    {
+        let request = self.build_list_bonus_payments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssignmentsForHITResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBonusPaymentsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssignmentsForHITError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBonusPaymentsError::from_response(response))
         }
     }
 
-    /// <p> The <code>ListBonusPayments</code> operation retrieves the amounts of bonuses you have paid to Workers for a given HIT or assignment. </p>
-    async fn list_bonus_payments(
+    /// Presigns the request `list_bonus_payments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_bonus_payments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBonusPaymentsRequest,
-    ) -> Result<ListBonusPaymentsResponse, RusotoError<ListBonusPaymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_bonus_payments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_bonus_payments_request(&self, input: ListBonusPaymentsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListBonusPayments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3990,56 +4907,110 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_hi_ts`, also returning the metadata of the response.
+    pub async fn list_hi_ts_with_metadata(
+        &self,
+        input: ListHITsRequest,
+    ) -> Result<(ListHITsResponse, ResponseMetadata), RusotoError<ListHITsError>> {
+        let request = self.build_list_hi_ts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBonusPaymentsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListHITsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBonusPaymentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListHITsError::from_response(response))
         }
     }
 
-    /// <p> The <code>ListHITs</code> operation returns all of a Requester's HITs. The operation returns HITs of any status, except for HITs that have been deleted of with the DeleteHIT operation or that have been auto-deleted. </p>
-    async fn list_hi_ts(
+    /// Presigns the request `list_hi_ts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_hi_ts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHITsRequest,
-    ) -> Result<ListHITsResponse, RusotoError<ListHITsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_hi_ts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_hi_ts_request(&self, input: ListHITsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListHITs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.ListHITs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_hi_ts_for_qualification_type`, also returning the metadata of the response.
+    pub async fn list_hi_ts_for_qualification_type_with_metadata(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> Result<
+        (ListHITsForQualificationTypeResponse, ResponseMetadata),
+        RusotoError<ListHITsForQualificationTypeError>,
+    > {
+        let request = self.build_list_hi_ts_for_qualification_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListHITsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListHITsForQualificationTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHITsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListHITsForQualificationTypeError::from_response(response))
         }
     }
 
-    /// <p> The <code>ListHITsForQualificationType</code> operation returns the HITs that use the given Qualification type for a Qualification requirement. The operation returns HITs of any status, except for HITs that have been deleted with the <code>DeleteHIT</code> operation or that have been auto-deleted. </p>
-    async fn list_hi_ts_for_qualification_type(
+    /// Presigns the request `list_hi_ts_for_qualification_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_hi_ts_for_qualification_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHITsForQualificationTypeRequest,
-    ) -> Result<ListHITsForQualificationTypeResponse, RusotoError<ListHITsForQualificationTypeError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_hi_ts_for_qualification_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_hi_ts_for_qualification_type_request(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListHITsForQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4049,29 +5020,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_qualification_requests`, also returning the metadata of the response.
+    pub async fn list_qualification_requests_with_metadata(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> Result<
+        (ListQualificationRequestsResponse, ResponseMetadata),
+        RusotoError<ListQualificationRequestsError>,
+    > {
+        let request = self.build_list_qualification_requests_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListHITsForQualificationTypeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListQualificationRequestsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHITsForQualificationTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListQualificationRequestsError::from_response(response))
         }
     }
 
-    /// <p> The <code>ListQualificationRequests</code> operation retrieves requests for Qualifications of a particular Qualification type. The owner of the Qualification type calls this operation to poll for pending requests, and accepts them using the AcceptQualification operation. </p>
-    async fn list_qualification_requests(
+    /// Presigns the request `list_qualification_requests` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_qualification_requests(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListQualificationRequestsRequest,
-    ) -> Result<ListQualificationRequestsResponse, RusotoError<ListQualificationRequestsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_qualification_requests_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_qualification_requests_request(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListQualificationRequests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4081,28 +5081,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_qualification_types`, also returning the metadata of the response.
+    pub async fn list_qualification_types_with_metadata(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> Result<
+        (ListQualificationTypesResponse, ResponseMetadata),
+        RusotoError<ListQualificationTypesError>,
+    > {
+        let request = self.build_list_qualification_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListQualificationRequestsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListQualificationTypesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListQualificationRequestsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListQualificationTypesError::from_response(response))
         }
     }
 
-    /// <p> The <code>ListQualificationTypes</code> operation returns a list of Qualification types, filtered by an optional search term. </p>
-    async fn list_qualification_types(
+    /// Presigns the request `list_qualification_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_qualification_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListQualificationTypesRequest,
-    ) -> Result<ListQualificationTypesResponse, RusotoError<ListQualificationTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_qualification_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_qualification_types_request(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListQualificationTypes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4112,31 +5142,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_review_policy_results_for_hit`, also returning the metadata of the response.
+    pub async fn list_review_policy_results_for_hit_with_metadata(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> Result<
+        (ListReviewPolicyResultsForHITResponse, ResponseMetadata),
+        RusotoError<ListReviewPolicyResultsForHITError>,
+    > {
+        let request = self.build_list_review_policy_results_for_hit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListQualificationTypesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListReviewPolicyResultsForHITResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListQualificationTypesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListReviewPolicyResultsForHITError::from_response(response))
         }
     }
 
-    /// <p> The <code>ListReviewPolicyResultsForHIT</code> operation retrieves the computed results and the actions taken in the course of executing your Review Policies for a given HIT. For information about how to specify Review Policies when you call CreateHIT, see Review Policies. The ListReviewPolicyResultsForHIT operation can return results for both Assignment-level and HIT-level review results. </p>
-    async fn list_review_policy_results_for_hit(
+    /// Presigns the request `list_review_policy_results_for_hit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_review_policy_results_for_hit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListReviewPolicyResultsForHITRequest,
-    ) -> Result<
-        ListReviewPolicyResultsForHITResponse,
-        RusotoError<ListReviewPolicyResultsForHITError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_review_policy_results_for_hit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_review_policy_results_for_hit_request(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListReviewPolicyResultsForHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4146,28 +5203,56 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_reviewable_hi_ts`, also returning the metadata of the response.
+    pub async fn list_reviewable_hi_ts_with_metadata(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> Result<(ListReviewableHITsResponse, ResponseMetadata), RusotoError<ListReviewableHITsError>>
+    {
+        let request = self.build_list_reviewable_hi_ts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListReviewPolicyResultsForHITResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListReviewableHITsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListReviewPolicyResultsForHITError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListReviewableHITsError::from_response(response))
         }
     }
 
-    /// <p> The <code>ListReviewableHITs</code> operation retrieves the HITs with Status equal to Reviewable or Status equal to Reviewing that belong to the Requester calling the operation. </p>
-    async fn list_reviewable_hi_ts(
+    /// Presigns the request `list_reviewable_hi_ts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_reviewable_hi_ts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListReviewableHITsRequest,
-    ) -> Result<ListReviewableHITsResponse, RusotoError<ListReviewableHITsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_reviewable_hi_ts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_reviewable_hi_ts_request(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListReviewableHITs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4177,28 +5262,53 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_worker_blocks`, also returning the metadata of the response.
+    pub async fn list_worker_blocks_with_metadata(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> Result<(ListWorkerBlocksResponse, ResponseMetadata), RusotoError<ListWorkerBlocksError>>
+    {
+        let request = self.build_list_worker_blocks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListReviewableHITsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListWorkerBlocksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListReviewableHITsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListWorkerBlocksError::from_response(response))
         }
     }
 
-    /// <p>The <code>ListWorkersBlocks</code> operation retrieves a list of Workers who are blocked from working on your HITs.</p>
-    async fn list_worker_blocks(
+    /// Presigns the request `list_worker_blocks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_worker_blocks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListWorkerBlocksRequest,
-    ) -> Result<ListWorkerBlocksResponse, RusotoError<ListWorkerBlocksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_worker_blocks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_worker_blocks_request(&self, input: ListWorkerBlocksRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListWorkerBlocks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4208,31 +5318,60 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_workers_with_qualification_type`, also returning the metadata of the response.
+    pub async fn list_workers_with_qualification_type_with_metadata(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> Result<
+        (ListWorkersWithQualificationTypeResponse, ResponseMetadata),
+        RusotoError<ListWorkersWithQualificationTypeError>,
+    > {
+        let request = self.build_list_workers_with_qualification_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListWorkerBlocksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListWorkersWithQualificationTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListWorkerBlocksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListWorkersWithQualificationTypeError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p> The <code>ListWorkersWithQualificationType</code> operation returns all of the Workers that have been associated with a given Qualification type. </p>
-    async fn list_workers_with_qualification_type(
+    /// Presigns the request `list_workers_with_qualification_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_workers_with_qualification_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListWorkersWithQualificationTypeRequest,
-    ) -> Result<
-        ListWorkersWithQualificationTypeResponse,
-        RusotoError<ListWorkersWithQualificationTypeError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_workers_with_qualification_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_workers_with_qualification_type_request(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListWorkersWithQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4242,30 +5381,52 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `notify_workers`, also returning the metadata of the response.
+    pub async fn notify_workers_with_metadata(
+        &self,
+        input: NotifyWorkersRequest,
+    ) -> Result<(NotifyWorkersResponse, ResponseMetadata), RusotoError<NotifyWorkersError>> {
+        let request = self.build_notify_workers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListWorkersWithQualificationTypeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<NotifyWorkersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListWorkersWithQualificationTypeError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(NotifyWorkersError::from_response(response))
         }
     }
 
-    /// <p> The <code>NotifyWorkers</code> operation sends an email to one or more Workers that you specify with the Worker ID. You can specify up to 100 Worker IDs to send the same message with a single call to the NotifyWorkers operation. The NotifyWorkers operation will send a notification email to a Worker only if you have previously approved or rejected work from the Worker. </p>
-    async fn notify_workers(
+    /// Presigns the request `notify_workers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_notify_workers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: NotifyWorkersRequest,
-    ) -> Result<NotifyWorkersResponse, RusotoError<NotifyWorkersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_notify_workers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_notify_workers_request(&self, input: NotifyWorkersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("NotifyWorkers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4275,27 +5436,53 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reject_assignment`, also returning the metadata of the response.
+    pub async fn reject_assignment_with_metadata(
+        &self,
+        input: RejectAssignmentRequest,
+    ) -> Result<(RejectAssignmentResponse, ResponseMetadata), RusotoError<RejectAssignmentError>>
+    {
+        let request = self.build_reject_assignment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<NotifyWorkersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RejectAssignmentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(NotifyWorkersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RejectAssignmentError::from_response(response))
         }
     }
 
-    /// <p> The <code>RejectAssignment</code> operation rejects the results of a completed assignment. </p> <p> You can include an optional feedback message with the rejection, which the Worker can see in the Status section of the web site. When you include a feedback message with the rejection, it helps the Worker understand why the assignment was rejected, and can improve the quality of the results the Worker submits in the future. </p> <p> Only the Requester who created the HIT can reject an assignment for the HIT. </p>
-    async fn reject_assignment(
+    /// Presigns the request `reject_assignment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reject_assignment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RejectAssignmentRequest,
-    ) -> Result<RejectAssignmentResponse, RusotoError<RejectAssignmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reject_assignment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reject_assignment_request(&self, input: RejectAssignmentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("RejectAssignment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4305,29 +5492,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reject_qualification_request`, also returning the metadata of the response.
+    pub async fn reject_qualification_request_with_metadata(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> Result<
+        (RejectQualificationRequestResponse, ResponseMetadata),
+        RusotoError<RejectQualificationRequestError>,
+    > {
+        let request = self.build_reject_qualification_request_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RejectAssignmentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RejectQualificationRequestResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RejectAssignmentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RejectQualificationRequestError::from_response(response))
         }
     }
 
-    /// <p> The <code>RejectQualificationRequest</code> operation rejects a user's request for a Qualification. </p> <p> You can provide a text message explaining why the request was rejected. The Worker who made the request can see this message.</p>
-    async fn reject_qualification_request(
+    /// Presigns the request `reject_qualification_request` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reject_qualification_request(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RejectQualificationRequestRequest,
-    ) -> Result<RejectQualificationRequestResponse, RusotoError<RejectQualificationRequestError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reject_qualification_request_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reject_qualification_request_request(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("RejectQualificationRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4337,56 +5553,110 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `send_bonus`, also returning the metadata of the response.
+    pub async fn send_bonus_with_metadata(
+        &self,
+        input: SendBonusRequest,
+    ) -> Result<(SendBonusResponse, ResponseMetadata), RusotoError<SendBonusError>> {
+        let request = self.build_send_bonus_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RejectQualificationRequestResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendBonusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RejectQualificationRequestError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendBonusError::from_response(response))
         }
     }
 
-    /// <p> The <code>SendBonus</code> operation issues a payment of money from your account to a Worker. This payment happens separately from the reward you pay to the Worker when you approve the Worker's assignment. The SendBonus operation requires the Worker's ID and the assignment ID as parameters to initiate payment of the bonus. You must include a message that explains the reason for the bonus payment, as the Worker may not be expecting the payment. Amazon Mechanical Turk collects a fee for bonus payments, similar to the HIT listing fee. This operation fails if your account does not have enough funds to pay for both the bonus and the fees. </p>
-    async fn send_bonus(
+    /// Presigns the request `send_bonus` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_bonus(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendBonusRequest,
-    ) -> Result<SendBonusResponse, RusotoError<SendBonusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_bonus_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_bonus_request(&self, input: SendBonusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("SendBonus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.SendBonus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `send_test_event_notification`, also returning the metadata of the response.
+    pub async fn send_test_event_notification_with_metadata(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> Result<
+        (SendTestEventNotificationResponse, ResponseMetadata),
+        RusotoError<SendTestEventNotificationError>,
+    > {
+        let request = self.build_send_test_event_notification_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SendBonusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendTestEventNotificationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendBonusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendTestEventNotificationError::from_response(response))
         }
     }
 
-    /// <p> The <code>SendTestEventNotification</code> operation causes Amazon Mechanical Turk to send a notification message as if a HIT event occurred, according to the provided notification specification. This allows you to test notifications without setting up notifications for a real HIT type and trying to trigger them using the website. When you call this operation, the service attempts to send the test notification immediately. </p>
-    async fn send_test_event_notification(
+    /// Presigns the request `send_test_event_notification` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_test_event_notification(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendTestEventNotificationRequest,
-    ) -> Result<SendTestEventNotificationResponse, RusotoError<SendTestEventNotificationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_test_event_notification_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_test_event_notification_request(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("SendTestEventNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4396,28 +5666,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_expiration_for_hit`, also returning the metadata of the response.
+    pub async fn update_expiration_for_hit_with_metadata(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> Result<
+        (UpdateExpirationForHITResponse, ResponseMetadata),
+        RusotoError<UpdateExpirationForHITError>,
+    > {
+        let request = self.build_update_expiration_for_hit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SendTestEventNotificationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateExpirationForHITResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendTestEventNotificationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateExpirationForHITError::from_response(response))
         }
     }
 
-    /// <p> The <code>UpdateExpirationForHIT</code> operation allows you update the expiration time of a HIT. If you update it to a time in the past, the HIT will be immediately expired. </p>
-    async fn update_expiration_for_hit(
+    /// Presigns the request `update_expiration_for_hit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_expiration_for_hit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateExpirationForHITRequest,
-    ) -> Result<UpdateExpirationForHITResponse, RusotoError<UpdateExpirationForHITError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_expiration_for_hit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_expiration_for_hit_request(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateExpirationForHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4427,28 +5727,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_hit_review_status`, also returning the metadata of the response.
+    pub async fn update_hit_review_status_with_metadata(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> Result<
+        (UpdateHITReviewStatusResponse, ResponseMetadata),
+        RusotoError<UpdateHITReviewStatusError>,
+    > {
+        let request = self.build_update_hit_review_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateExpirationForHITResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateHITReviewStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateExpirationForHITError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateHITReviewStatusError::from_response(response))
         }
     }
 
-    /// <p> The <code>UpdateHITReviewStatus</code> operation updates the status of a HIT. If the status is Reviewable, this operation can update the status to Reviewing, or it can revert a Reviewing HIT back to the Reviewable status. </p>
-    async fn update_hit_review_status(
+    /// Presigns the request `update_hit_review_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_hit_review_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateHITReviewStatusRequest,
-    ) -> Result<UpdateHITReviewStatusResponse, RusotoError<UpdateHITReviewStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_hit_review_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_hit_review_status_request(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateHITReviewStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4458,28 +5788,56 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_hit_type_of_hit`, also returning the metadata of the response.
+    pub async fn update_hit_type_of_hit_with_metadata(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> Result<(UpdateHITTypeOfHITResponse, ResponseMetadata), RusotoError<UpdateHITTypeOfHITError>>
+    {
+        let request = self.build_update_hit_type_of_hit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateHITReviewStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateHITTypeOfHITResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateHITReviewStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateHITTypeOfHITError::from_response(response))
         }
     }
 
-    /// <p> The <code>UpdateHITTypeOfHIT</code> operation allows you to change the HITType properties of a HIT. This operation disassociates the HIT from its old HITType properties and associates it with the new HITType properties. The HIT takes on the properties of the new HITType in place of the old ones. </p>
-    async fn update_hit_type_of_hit(
+    /// Presigns the request `update_hit_type_of_hit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_hit_type_of_hit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateHITTypeOfHITRequest,
-    ) -> Result<UpdateHITTypeOfHITResponse, RusotoError<UpdateHITTypeOfHITError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_hit_type_of_hit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_hit_type_of_hit_request(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateHITTypeOfHIT");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4489,29 +5847,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_notification_settings`, also returning the metadata of the response.
+    pub async fn update_notification_settings_with_metadata(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> Result<
+        (UpdateNotificationSettingsResponse, ResponseMetadata),
+        RusotoError<UpdateNotificationSettingsError>,
+    > {
+        let request = self.build_update_notification_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateHITTypeOfHITResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateNotificationSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateHITTypeOfHITError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateNotificationSettingsError::from_response(response))
         }
     }
 
-    /// <p> The <code>UpdateNotificationSettings</code> operation creates, updates, disables or re-enables notifications for a HIT type. If you call the UpdateNotificationSettings operation for a HIT type that already has a notification specification, the operation replaces the old specification with a new one. You can call the UpdateNotificationSettings operation to enable or disable notifications for the HIT type, without having to modify the notification specification itself by providing updates to the Active status without specifying a new notification specification. To change the Active status of a HIT type's notifications, the HIT type must already have a notification specification, or one must be provided in the same call to <code>UpdateNotificationSettings</code>. </p>
-    async fn update_notification_settings(
+    /// Presigns the request `update_notification_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_notification_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNotificationSettingsRequest,
-    ) -> Result<UpdateNotificationSettingsResponse, RusotoError<UpdateNotificationSettingsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_notification_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_notification_settings_request(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateNotificationSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4521,28 +5908,58 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_qualification_type`, also returning the metadata of the response.
+    pub async fn update_qualification_type_with_metadata(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> Result<
+        (UpdateQualificationTypeResponse, ResponseMetadata),
+        RusotoError<UpdateQualificationTypeError>,
+    > {
+        let request = self.build_update_qualification_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNotificationSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateQualificationTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNotificationSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateQualificationTypeError::from_response(response))
         }
     }
 
-    /// <p> The <code>UpdateQualificationType</code> operation modifies the attributes of an existing Qualification type, which is represented by a QualificationType data structure. Only the owner of a Qualification type can modify its attributes. </p> <p> Most attributes of a Qualification type can be changed after the type has been created. However, the Name and Keywords fields cannot be modified. The RetryDelayInSeconds parameter can be modified or added to change the delay or to enable retries, but RetryDelayInSeconds cannot be used to disable retries. </p> <p> You can use this operation to update the test for a Qualification type. The test is updated based on the values specified for the Test, TestDurationInSeconds and AnswerKey parameters. All three parameters specify the updated test. If you are updating the test for a type, you must specify the Test and TestDurationInSeconds parameters. The AnswerKey parameter is optional; omitting it specifies that the updated test does not have an answer key. </p> <p> If you omit the Test parameter, the test for the Qualification type is unchanged. There is no way to remove a test from a Qualification type that has one. If the type already has a test, you cannot update it to be AutoGranted. If the Qualification type does not have a test and one is provided by an update, the type will henceforth have a test. </p> <p> If you want to update the test duration or answer key for an existing test without changing the questions, you must specify a Test parameter with the original questions, along with the updated values. </p> <p> If you provide an updated Test but no AnswerKey, the new test will not have an answer key. Requests for such Qualifications must be granted manually. </p> <p> You can also update the AutoGranted and AutoGrantedValue attributes of the Qualification type.</p>
-    async fn update_qualification_type(
+    /// Presigns the request `update_qualification_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_qualification_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateQualificationTypeRequest,
-    ) -> Result<UpdateQualificationTypeResponse, RusotoError<UpdateQualificationTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_qualification_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_qualification_type_request(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateQualificationType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4552,19 +5969,778 @@ impl MechanicalTurk for MechanicalTurkClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateQualificationTypeResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateQualificationTypeError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `ListAssignmentsForHIT` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_assignments_for_hit_pages(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAssignmentsForHITResponse, RusotoError<ListAssignmentsForHITError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input| async move {
+                client.list_assignments_for_hit(input).await
+            },
+            |input: &ListAssignmentsForHITRequest, output: &ListAssignmentsForHITResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssignmentsForHITRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Assignments` of every page of `ListAssignmentsForHIT` results for `input`, as `list_assignments_for_hit_pages` requests them.
+    pub fn list_assignments_for_hit_stream(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> impl futures::Stream<Item = Result<Assignment, RusotoError<ListAssignmentsForHITError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_assignments_for_hit_pages(input), |page| {
+            page.assignments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBonusPayments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_bonus_payments_pages(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListBonusPaymentsResponse, RusotoError<ListBonusPaymentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input| async move { client.list_bonus_payments(input).await },
+            |input: &ListBonusPaymentsRequest, output: &ListBonusPaymentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBonusPaymentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BonusPayments` of every page of `ListBonusPayments` results for `input`, as `list_bonus_payments_pages` requests them.
+    pub fn list_bonus_payments_stream(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> impl futures::Stream<Item = Result<BonusPayment, RusotoError<ListBonusPaymentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_bonus_payments_pages(input), |page| {
+            page.bonus_payments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListHITs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_hi_ts_pages(
+        &self,
+        input: ListHITsRequest,
+    ) -> impl futures::Stream<Item = Result<ListHITsResponse, RusotoError<ListHITsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input| async move { client.list_hi_ts(input).await },
+            |input: &ListHITsRequest, output: &ListHITsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHITsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HITs` of every page of `ListHITs` results for `input`, as `list_hi_ts_pages` requests them.
+    pub fn list_hi_ts_stream(
+        &self,
+        input: ListHITsRequest,
+    ) -> impl futures::Stream<Item = Result<HIT, RusotoError<ListHITsError>>> + Send {
+        rusoto_core::pagination::items(self.list_hi_ts_pages(input), |page| {
+            page.hi_ts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListHITsForQualificationType` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_hi_ts_for_qualification_type_pages(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListHITsForQualificationTypeResponse,
+            RusotoError<ListHITsForQualificationTypeError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input| async move {
+                client.list_hi_ts_for_qualification_type(input).await
+            },
+            |input: &ListHITsForQualificationTypeRequest,
+             output: &ListHITsForQualificationTypeResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHITsForQualificationTypeRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HITs` of every page of `ListHITsForQualificationType` results for `input`, as `list_hi_ts_for_qualification_type_pages` requests them.
+    pub fn list_hi_ts_for_qualification_type_stream(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> impl futures::Stream<Item = Result<HIT, RusotoError<ListHITsForQualificationTypeError>>> + Send
+    {
+        rusoto_core::pagination::items(
+            self.list_hi_ts_for_qualification_type_pages(input),
+            |page| page.hi_ts.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListQualificationRequests` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_qualification_requests_pages(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListQualificationRequestsResponse,
+            RusotoError<ListQualificationRequestsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input| async move {
+                client.list_qualification_requests(input).await
+            },
+            |input: &ListQualificationRequestsRequest,
+             output: &ListQualificationRequestsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListQualificationRequestsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `QualificationRequests` of every page of `ListQualificationRequests` results for `input`, as `list_qualification_requests_pages` requests them.
+    pub fn list_qualification_requests_stream(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<QualificationRequest, RusotoError<ListQualificationRequestsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_qualification_requests_pages(input), |page| {
+            page.qualification_requests.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListQualificationTypes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_qualification_types_pages(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListQualificationTypesResponse, RusotoError<ListQualificationTypesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input| async move {
+                client.list_qualification_types(input).await
+            },
+            |input: &ListQualificationTypesRequest, output: &ListQualificationTypesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListQualificationTypesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `QualificationTypes` of every page of `ListQualificationTypes` results for `input`, as `list_qualification_types_pages` requests them.
+    pub fn list_qualification_types_stream(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<QualificationType, RusotoError<ListQualificationTypesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_qualification_types_pages(input), |page| {
+            page.qualification_types.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListReviewableHITs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_reviewable_hi_ts_pages(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListReviewableHITsResponse, RusotoError<ListReviewableHITsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input| async move {
+                client.list_reviewable_hi_ts(input).await
+            },
+            |input: &ListReviewableHITsRequest, output: &ListReviewableHITsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListReviewableHITsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HITs` of every page of `ListReviewableHITs` results for `input`, as `list_reviewable_hi_ts_pages` requests them.
+    pub fn list_reviewable_hi_ts_stream(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> impl futures::Stream<Item = Result<HIT, RusotoError<ListReviewableHITsError>>> + Send {
+        rusoto_core::pagination::items(self.list_reviewable_hi_ts_pages(input), |page| {
+            page.hi_ts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListWorkerBlocks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_worker_blocks_pages(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListWorkerBlocksResponse, RusotoError<ListWorkerBlocksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input| async move { client.list_worker_blocks(input).await },
+            |input: &ListWorkerBlocksRequest, output: &ListWorkerBlocksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListWorkerBlocksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `WorkerBlocks` of every page of `ListWorkerBlocks` results for `input`, as `list_worker_blocks_pages` requests them.
+    pub fn list_worker_blocks_stream(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> impl futures::Stream<Item = Result<WorkerBlock, RusotoError<ListWorkerBlocksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_worker_blocks_pages(input), |page| {
+            page.worker_blocks.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListWorkersWithQualificationType` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_workers_with_qualification_type_pages(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListWorkersWithQualificationTypeResponse,
+            RusotoError<ListWorkersWithQualificationTypeError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: MechanicalTurkClient, input| async move {
+                client.list_workers_with_qualification_type(input).await
+            },
+            |input: &ListWorkersWithQualificationTypeRequest,
+             output: &ListWorkersWithQualificationTypeResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListWorkersWithQualificationTypeRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Qualifications` of every page of `ListWorkersWithQualificationType` results for `input`, as `list_workers_with_qualification_type_pages` requests them.
+    pub fn list_workers_with_qualification_type_stream(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> impl futures::Stream<
+        Item = Result<Qualification, RusotoError<ListWorkersWithQualificationTypeError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_workers_with_qualification_type_pages(input),
+            |page| page.qualifications.unwrap_or_default(),
+        )
+    }
+}
+
+#[async_trait]
+impl MechanicalTurk for MechanicalTurkClient {
+    /// <p> The <code>AcceptQualificationRequest</code> operation approves a Worker's request for a Qualification. </p> <p> Only the owner of the Qualification type can grant a Qualification request for that type. </p> <p> A successful request for the <code>AcceptQualificationRequest</code> operation returns with no errors and an empty body. </p>
+    async fn accept_qualification_request(
+        &self,
+        input: AcceptQualificationRequestRequest,
+    ) -> Result<AcceptQualificationRequestResponse, RusotoError<AcceptQualificationRequestError>>
+    {
+        self.accept_qualification_request_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ApproveAssignment</code> operation approves the results of a completed assignment. </p> <p> Approving an assignment initiates two payments from the Requester's Amazon.com account </p> <ul> <li> <p> The Worker who submitted the results is paid the reward specified in the HIT. </p> </li> <li> <p> Amazon Mechanical Turk fees are debited. </p> </li> </ul> <p> If the Requester's account does not have adequate funds for these payments, the call to ApproveAssignment returns an exception, and the approval is not processed. You can include an optional feedback message with the approval, which the Worker can see in the Status section of the web site. </p> <p> You can also call this operation for assignments that were previous rejected and approve them by explicitly overriding the previous rejection. This only works on rejected assignments that were submitted within the previous 30 days and only if the assignment's related HIT has not been deleted. </p>
+    async fn approve_assignment(
+        &self,
+        input: ApproveAssignmentRequest,
+    ) -> Result<ApproveAssignmentResponse, RusotoError<ApproveAssignmentError>> {
+        self.approve_assignment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> The <code>AssociateQualificationWithWorker</code> operation gives a Worker a Qualification. <code>AssociateQualificationWithWorker</code> does not require that the Worker submit a Qualification request. It gives the Qualification directly to the Worker. </p> <p> You can only assign a Qualification of a Qualification type that you created (using the <code>CreateQualificationType</code> operation). </p> <note> <p> Note: <code>AssociateQualificationWithWorker</code> does not affect any pending Qualification requests for the Qualification by the Worker. If you assign a Qualification to a Worker, then later grant a Qualification request made by the Worker, the granting of the request may modify the Qualification score. To resolve a pending Qualification request without affecting the Qualification the Worker already has, reject the request with the <code>RejectQualificationRequest</code> operation. </p> </note></p>
+    async fn associate_qualification_with_worker(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> Result<
+        AssociateQualificationWithWorkerResponse,
+        RusotoError<AssociateQualificationWithWorkerError>,
+    > {
+        self.associate_qualification_with_worker_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> The <code>CreateAdditionalAssignmentsForHIT</code> operation increases the maximum number of assignments of an existing HIT. </p> <p> To extend the maximum number of assignments, specify the number of additional assignments.</p> <note> <ul> <li> <p>HITs created with fewer than 10 assignments cannot be extended to have 10 or more assignments. Attempting to add assignments in a way that brings the total number of assignments for a HIT from fewer than 10 assignments to 10 or more assignments will result in an <code>AWS.MechanicalTurk.InvalidMaximumAssignmentsIncrease</code> exception.</p> </li> <li> <p>HITs that were created before July 22, 2015 cannot be extended. Attempting to extend HITs that were created before July 22, 2015 will result in an <code>AWS.MechanicalTurk.HITTooOldForExtension</code> exception. </p> </li> </ul> </note></p>
+    async fn create_additional_assignments_for_hit(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> Result<
+        CreateAdditionalAssignmentsForHITResponse,
+        RusotoError<CreateAdditionalAssignmentsForHITError>,
+    > {
+        self.create_additional_assignments_for_hit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>The <code>CreateHIT</code> operation creates a new Human Intelligence Task (HIT). The new HIT is made available for Workers to find and accept on the Amazon Mechanical Turk website. </p> <p> This operation allows you to specify a new HIT by passing in values for the properties of the HIT, such as its title, reward amount and number of assignments. When you pass these values to <code>CreateHIT</code>, a new HIT is created for you, with a new <code>HITTypeID</code>. The HITTypeID can be used to create additional HITs in the future without needing to specify common parameters such as the title, description and reward amount each time.</p> <p> An alternative way to create HITs is to first generate a HITTypeID using the <code>CreateHITType</code> operation and then call the <code>CreateHITWithHITType</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHIT also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>.</p> </note></p>
+    async fn create_hit(
+        &self,
+        input: CreateHITRequest,
+    ) -> Result<CreateHITResponse, RusotoError<CreateHITError>> {
+        self.create_hit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>CreateHITType</code> operation creates a new HIT type. This operation allows you to define a standard set of HIT properties to use when creating HITs. If you register a HIT type with values that match an existing HIT type, the HIT type ID of the existing type will be returned. </p>
+    async fn create_hit_type(
+        &self,
+        input: CreateHITTypeRequest,
+    ) -> Result<CreateHITTypeResponse, RusotoError<CreateHITTypeError>> {
+        self.create_hit_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> The <code>CreateHITWithHITType</code> operation creates a new Human Intelligence Task (HIT) using an existing HITTypeID generated by the <code>CreateHITType</code> operation. </p> <p> This is an alternative way to create HITs from the <code>CreateHIT</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHITWithHITType also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>. </p> </note></p>
+    async fn create_hit_with_hit_type(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> Result<CreateHITWithHITTypeResponse, RusotoError<CreateHITWithHITTypeError>> {
+        self.create_hit_with_hit_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>CreateQualificationType</code> operation creates a new Qualification type, which is represented by a <code>QualificationType</code> data structure. </p>
+    async fn create_qualification_type(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> Result<CreateQualificationTypeResponse, RusotoError<CreateQualificationTypeError>> {
+        self.create_qualification_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>CreateWorkerBlock</code> operation allows you to prevent a Worker from working on your HITs. For example, you can block a Worker who is producing poor quality work. You can block up to 100,000 Workers.</p>
+    async fn create_worker_block(
+        &self,
+        input: CreateWorkerBlockRequest,
+    ) -> Result<CreateWorkerBlockResponse, RusotoError<CreateWorkerBlockError>> {
+        self.create_worker_block_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> The <code>DeleteHIT</code> operation is used to delete HIT that is no longer needed. Only the Requester who created the HIT can delete it. </p> <p> You can only dispose of HITs that are in the <code>Reviewable</code> state, with all of their submitted assignments already either approved or rejected. If you call the DeleteHIT operation on a HIT that is not in the <code>Reviewable</code> state (for example, that has not expired, or still has active assignments), or on a HIT that is Reviewable but without all of its submitted assignments already approved or rejected, the service will return an error. </p> <note> <ul> <li> <p> HITs are automatically disposed of after 120 days. </p> </li> <li> <p> After you dispose of a HIT, you can no longer approve the HIT&#39;s rejected assignments. </p> </li> <li> <p> Disposed HITs are not returned in results for the ListHITs operation. </p> </li> <li> <p> Disposing HITs can improve the performance of operations such as ListReviewableHITs and ListHITs. </p> </li> </ul> </note></p>
+    async fn delete_hit(
+        &self,
+        input: DeleteHITRequest,
+    ) -> Result<DeleteHITResponse, RusotoError<DeleteHITError>> {
+        self.delete_hit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> The <code>DeleteQualificationType</code> deletes a Qualification type and deletes any HIT types that are associated with the Qualification type. </p> <p>This operation does not revoke Qualifications already assigned to Workers because the Qualifications might be needed for active HITs. If there are any pending requests for the Qualification type, Amazon Mechanical Turk rejects those requests. After you delete a Qualification type, you can no longer use it to create HITs or HIT types.</p> <note> <p>DeleteQualificationType must wait for all the HITs that use the deleted Qualification type to be deleted before completing. It may take up to 48 hours before DeleteQualificationType completes and the unique name of the Qualification type is available for reuse with CreateQualificationType.</p> </note></p>
+    async fn delete_qualification_type(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> Result<DeleteQualificationTypeResponse, RusotoError<DeleteQualificationTypeError>> {
+        self.delete_qualification_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>DeleteWorkerBlock</code> operation allows you to reinstate a blocked Worker to work on your HITs. This operation reverses the effects of the CreateWorkerBlock operation. You need the Worker ID to use this operation. If the Worker ID is missing or invalid, this operation fails and returns the message “WorkerId is invalid.” If the specified Worker is not blocked, this operation returns successfully.</p>
+    async fn delete_worker_block(
+        &self,
+        input: DeleteWorkerBlockRequest,
+    ) -> Result<DeleteWorkerBlockResponse, RusotoError<DeleteWorkerBlockError>> {
+        self.delete_worker_block_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>DisassociateQualificationFromWorker</code> revokes a previously granted Qualification from a user. </p> <p> You can provide a text message explaining why the Qualification was revoked. The user who had the Qualification can see this message. </p>
+    async fn disassociate_qualification_from_worker(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> Result<
+        DisassociateQualificationFromWorkerResponse,
+        RusotoError<DisassociateQualificationFromWorkerError>,
+    > {
+        self.disassociate_qualification_from_worker_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>GetAccountBalance</code> operation retrieves the amount of money in your Amazon Mechanical Turk account.</p>
+    async fn get_account_balance(
+        &self,
+    ) -> Result<GetAccountBalanceResponse, RusotoError<GetAccountBalanceError>> {
+        self.get_account_balance_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>GetAssignment</code> operation retrieves the details of the specified Assignment. </p>
+    async fn get_assignment(
+        &self,
+        input: GetAssignmentRequest,
+    ) -> Result<GetAssignmentResponse, RusotoError<GetAssignmentError>> {
+        self.get_assignment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>GetFileUploadURL</code> operation generates and returns a temporary URL. You use the temporary URL to retrieve a file uploaded by a Worker as an answer to a FileUploadAnswer question for a HIT. The temporary URL is generated the instant the GetFileUploadURL operation is called, and is valid for 60 seconds. You can get a temporary file upload URL any time until the HIT is disposed. After the HIT is disposed, any uploaded files are deleted, and cannot be retrieved. Pending Deprecation on December 12, 2017. The Answer Specification structure will no longer support the <code>FileUploadAnswer</code> element to be used for the QuestionForm data structure. Instead, we recommend that Requesters who want to create HITs asking Workers to upload files to use Amazon S3. </p>
+    async fn get_file_upload_url(
+        &self,
+        input: GetFileUploadURLRequest,
+    ) -> Result<GetFileUploadURLResponse, RusotoError<GetFileUploadURLError>> {
+        self.get_file_upload_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>GetHIT</code> operation retrieves the details of the specified HIT. </p>
+    async fn get_hit(
+        &self,
+        input: GetHITRequest,
+    ) -> Result<GetHITResponse, RusotoError<GetHITError>> {
+        self.get_hit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>GetQualificationScore</code> operation returns the value of a Worker's Qualification for a given Qualification type. </p> <p> To get a Worker's Qualification, you must know the Worker's ID. The Worker's ID is included in the assignment data returned by the <code>ListAssignmentsForHIT</code> operation. </p> <p>Only the owner of a Qualification type can query the value of a Worker's Qualification of that type.</p>
+    async fn get_qualification_score(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> Result<GetQualificationScoreResponse, RusotoError<GetQualificationScoreError>> {
+        self.get_qualification_score_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>GetQualificationType</code>operation retrieves information about a Qualification type using its ID. </p>
+    async fn get_qualification_type(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> Result<GetQualificationTypeResponse, RusotoError<GetQualificationTypeError>> {
+        self.get_qualification_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListAssignmentsForHIT</code> operation retrieves completed assignments for a HIT. You can use this operation to retrieve the results for a HIT. </p> <p> You can get assignments for a HIT at any time, even if the HIT is not yet Reviewable. If a HIT requested multiple assignments, and has received some results but has not yet become Reviewable, you can still retrieve the partial results with this operation. </p> <p> Use the AssignmentStatus parameter to control which set of assignments for a HIT are returned. The ListAssignmentsForHIT operation can return submitted assignments awaiting approval, or it can return assignments that have already been approved or rejected. You can set AssignmentStatus=Approved,Rejected to get assignments that have already been approved and rejected together in one result set. </p> <p> Only the Requester who created the HIT can retrieve the assignments for that HIT. </p> <p> Results are sorted and divided into numbered pages and the operation returns a single page of results. You can use the parameters of the operation to control sorting and pagination. </p>
+    async fn list_assignments_for_hit(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> Result<ListAssignmentsForHITResponse, RusotoError<ListAssignmentsForHITError>> {
+        self.list_assignments_for_hit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListBonusPayments</code> operation retrieves the amounts of bonuses you have paid to Workers for a given HIT or assignment. </p>
+    async fn list_bonus_payments(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> Result<ListBonusPaymentsResponse, RusotoError<ListBonusPaymentsError>> {
+        self.list_bonus_payments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListHITs</code> operation returns all of a Requester's HITs. The operation returns HITs of any status, except for HITs that have been deleted of with the DeleteHIT operation or that have been auto-deleted. </p>
+    async fn list_hi_ts(
+        &self,
+        input: ListHITsRequest,
+    ) -> Result<ListHITsResponse, RusotoError<ListHITsError>> {
+        self.list_hi_ts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListHITsForQualificationType</code> operation returns the HITs that use the given Qualification type for a Qualification requirement. The operation returns HITs of any status, except for HITs that have been deleted with the <code>DeleteHIT</code> operation or that have been auto-deleted. </p>
+    async fn list_hi_ts_for_qualification_type(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> Result<ListHITsForQualificationTypeResponse, RusotoError<ListHITsForQualificationTypeError>>
+    {
+        self.list_hi_ts_for_qualification_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListQualificationRequests</code> operation retrieves requests for Qualifications of a particular Qualification type. The owner of the Qualification type calls this operation to poll for pending requests, and accepts them using the AcceptQualification operation. </p>
+    async fn list_qualification_requests(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> Result<ListQualificationRequestsResponse, RusotoError<ListQualificationRequestsError>>
+    {
+        self.list_qualification_requests_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListQualificationTypes</code> operation returns a list of Qualification types, filtered by an optional search term. </p>
+    async fn list_qualification_types(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> Result<ListQualificationTypesResponse, RusotoError<ListQualificationTypesError>> {
+        self.list_qualification_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListReviewPolicyResultsForHIT</code> operation retrieves the computed results and the actions taken in the course of executing your Review Policies for a given HIT. For information about how to specify Review Policies when you call CreateHIT, see Review Policies. The ListReviewPolicyResultsForHIT operation can return results for both Assignment-level and HIT-level review results. </p>
+    async fn list_review_policy_results_for_hit(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> Result<
+        ListReviewPolicyResultsForHITResponse,
+        RusotoError<ListReviewPolicyResultsForHITError>,
+    > {
+        self.list_review_policy_results_for_hit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListReviewableHITs</code> operation retrieves the HITs with Status equal to Reviewable or Status equal to Reviewing that belong to the Requester calling the operation. </p>
+    async fn list_reviewable_hi_ts(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> Result<ListReviewableHITsResponse, RusotoError<ListReviewableHITsError>> {
+        self.list_reviewable_hi_ts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>ListWorkersBlocks</code> operation retrieves a list of Workers who are blocked from working on your HITs.</p>
+    async fn list_worker_blocks(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> Result<ListWorkerBlocksResponse, RusotoError<ListWorkerBlocksError>> {
+        self.list_worker_blocks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListWorkersWithQualificationType</code> operation returns all of the Workers that have been associated with a given Qualification type. </p>
+    async fn list_workers_with_qualification_type(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> Result<
+        ListWorkersWithQualificationTypeResponse,
+        RusotoError<ListWorkersWithQualificationTypeError>,
+    > {
+        self.list_workers_with_qualification_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>NotifyWorkers</code> operation sends an email to one or more Workers that you specify with the Worker ID. You can specify up to 100 Worker IDs to send the same message with a single call to the NotifyWorkers operation. The NotifyWorkers operation will send a notification email to a Worker only if you have previously approved or rejected work from the Worker. </p>
+    async fn notify_workers(
+        &self,
+        input: NotifyWorkersRequest,
+    ) -> Result<NotifyWorkersResponse, RusotoError<NotifyWorkersError>> {
+        self.notify_workers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>RejectAssignment</code> operation rejects the results of a completed assignment. </p> <p> You can include an optional feedback message with the rejection, which the Worker can see in the Status section of the web site. When you include a feedback message with the rejection, it helps the Worker understand why the assignment was rejected, and can improve the quality of the results the Worker submits in the future. </p> <p> Only the Requester who created the HIT can reject an assignment for the HIT. </p>
+    async fn reject_assignment(
+        &self,
+        input: RejectAssignmentRequest,
+    ) -> Result<RejectAssignmentResponse, RusotoError<RejectAssignmentError>> {
+        self.reject_assignment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>RejectQualificationRequest</code> operation rejects a user's request for a Qualification. </p> <p> You can provide a text message explaining why the request was rejected. The Worker who made the request can see this message.</p>
+    async fn reject_qualification_request(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> Result<RejectQualificationRequestResponse, RusotoError<RejectQualificationRequestError>>
+    {
+        self.reject_qualification_request_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>SendBonus</code> operation issues a payment of money from your account to a Worker. This payment happens separately from the reward you pay to the Worker when you approve the Worker's assignment. The SendBonus operation requires the Worker's ID and the assignment ID as parameters to initiate payment of the bonus. You must include a message that explains the reason for the bonus payment, as the Worker may not be expecting the payment. Amazon Mechanical Turk collects a fee for bonus payments, similar to the HIT listing fee. This operation fails if your account does not have enough funds to pay for both the bonus and the fees. </p>
+    async fn send_bonus(
+        &self,
+        input: SendBonusRequest,
+    ) -> Result<SendBonusResponse, RusotoError<SendBonusError>> {
+        self.send_bonus_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>SendTestEventNotification</code> operation causes Amazon Mechanical Turk to send a notification message as if a HIT event occurred, according to the provided notification specification. This allows you to test notifications without setting up notifications for a real HIT type and trying to trigger them using the website. When you call this operation, the service attempts to send the test notification immediately. </p>
+    async fn send_test_event_notification(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> Result<SendTestEventNotificationResponse, RusotoError<SendTestEventNotificationError>>
+    {
+        self.send_test_event_notification_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>UpdateExpirationForHIT</code> operation allows you update the expiration time of a HIT. If you update it to a time in the past, the HIT will be immediately expired. </p>
+    async fn update_expiration_for_hit(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> Result<UpdateExpirationForHITResponse, RusotoError<UpdateExpirationForHITError>> {
+        self.update_expiration_for_hit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>UpdateHITReviewStatus</code> operation updates the status of a HIT. If the status is Reviewable, this operation can update the status to Reviewing, or it can revert a Reviewing HIT back to the Reviewable status. </p>
+    async fn update_hit_review_status(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> Result<UpdateHITReviewStatusResponse, RusotoError<UpdateHITReviewStatusError>> {
+        self.update_hit_review_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>UpdateHITTypeOfHIT</code> operation allows you to change the HITType properties of a HIT. This operation disassociates the HIT from its old HITType properties and associates it with the new HITType properties. The HIT takes on the properties of the new HITType in place of the old ones. </p>
+    async fn update_hit_type_of_hit(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> Result<UpdateHITTypeOfHITResponse, RusotoError<UpdateHITTypeOfHITError>> {
+        self.update_hit_type_of_hit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>UpdateNotificationSettings</code> operation creates, updates, disables or re-enables notifications for a HIT type. If you call the UpdateNotificationSettings operation for a HIT type that already has a notification specification, the operation replaces the old specification with a new one. You can call the UpdateNotificationSettings operation to enable or disable notifications for the HIT type, without having to modify the notification specification itself by providing updates to the Active status without specifying a new notification specification. To change the Active status of a HIT type's notifications, the HIT type must already have a notification specification, or one must be provided in the same call to <code>UpdateNotificationSettings</code>. </p>
+    async fn update_notification_settings(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> Result<UpdateNotificationSettingsResponse, RusotoError<UpdateNotificationSettingsError>>
+    {
+        self.update_notification_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>UpdateQualificationType</code> operation modifies the attributes of an existing Qualification type, which is represented by a QualificationType data structure. Only the owner of a Qualification type can modify its attributes. </p> <p> Most attributes of a Qualification type can be changed after the type has been created. However, the Name and Keywords fields cannot be modified. The RetryDelayInSeconds parameter can be modified or added to change the delay or to enable retries, but RetryDelayInSeconds cannot be used to disable retries. </p> <p> You can use this operation to update the test for a Qualification type. The test is updated based on the values specified for the Test, TestDurationInSeconds and AnswerKey parameters. All three parameters specify the updated test. If you are updating the test for a type, you must specify the Test and TestDurationInSeconds parameters. The AnswerKey parameter is optional; omitting it specifies that the updated test does not have an answer key. </p> <p> If you omit the Test parameter, the test for the Qualification type is unchanged. There is no way to remove a test from a Qualification type that has one. If the type already has a test, you cannot update it to be AutoGranted. If the Qualification type does not have a test and one is provided by an update, the type will henceforth have a test. </p> <p> If you want to update the test duration or answer key for an existing test without changing the questions, you must specify a Test parameter with the original questions, along with the updated values. </p> <p> If you provide an updated Test but no AnswerKey, the new test will not have an answer key. Requests for such Qualifications must be granted manually. </p> <p> You can also update the AutoGranted and AutoGrantedValue attributes of the Qualification type.</p>
+    async fn update_qualification_type(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> Result<UpdateQualificationTypeResponse, RusotoError<UpdateQualificationTypeError>> {
+        self.update_qualification_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
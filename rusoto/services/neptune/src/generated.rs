@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -8316,6 +8316,7 @@ impl VpcSecurityGroupMembershipListDeserializer {
 }
 /// Errors returned by AddRoleToDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddRoleToDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
@@ -8325,6 +8326,8 @@ pub enum AddRoleToDBClusterError {
     DBClusterRoleQuotaExceededFault(String),
     /// <p>The DB cluster is not in a valid state.</p>
     InvalidDBClusterStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddRoleToDBClusterError {
@@ -8336,32 +8339,36 @@ impl AddRoleToDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddRoleToDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBClusterRoleAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterRoleQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddRoleToDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AddRoleToDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -8390,17 +8397,21 @@ impl fmt::Display for AddRoleToDBClusterError {
             AddRoleToDBClusterError::InvalidDBClusterStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddRoleToDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddRoleToDBClusterError {}
 /// Errors returned by AddSourceIdentifierToSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddSourceIdentifierToSubscriptionError {
     /// <p>The source could not be found.</p>
     SourceNotFoundFault(String),
     /// <p>The designated subscription could not be found.</p>
     SubscriptionNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddSourceIdentifierToSubscriptionError {
@@ -8414,20 +8425,27 @@ impl AddSourceIdentifierToSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            AddSourceIdentifierToSubscriptionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8452,12 +8470,16 @@ impl fmt::Display for AddSourceIdentifierToSubscriptionError {
             AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddSourceIdentifierToSubscriptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AddSourceIdentifierToSubscriptionError {}
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToResourceError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
@@ -8465,6 +8487,8 @@ pub enum AddTagsToResourceError {
     DBInstanceNotFoundFault(String),
     /// <p> <i>DBSnapshotIdentifier</i> does not refer to an existing DB snapshot.</p>
     DBSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToResourceError {
@@ -8476,21 +8500,24 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AddTagsToResourceError::Unhandled, &res),
                 }
             }
         }
@@ -8512,15 +8539,19 @@ impl fmt::Display for AddTagsToResourceError {
             AddTagsToResourceError::DBClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::DBInstanceNotFoundFault(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::DBSnapshotNotFoundFault(ref cause) => write!(f, "{}", cause),
+            AddTagsToResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToResourceError {}
 /// Errors returned by ApplyPendingMaintenanceAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ApplyPendingMaintenanceActionError {
     /// <p>The specified resource ID was not found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ApplyPendingMaintenanceActionError {
@@ -8534,13 +8565,19 @@ impl ApplyPendingMaintenanceActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ApplyPendingMaintenanceActionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8562,12 +8599,16 @@ impl fmt::Display for ApplyPendingMaintenanceActionError {
             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ApplyPendingMaintenanceActionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ApplyPendingMaintenanceActionError {}
 /// Errors returned by CopyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyDBClusterParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
     DBParameterGroupAlreadyExistsFault(String),
@@ -8575,6 +8616,8 @@ pub enum CopyDBClusterParameterGroupError {
     DBParameterGroupNotFoundFault(String),
     /// <p>Request would result in user exceeding the allowed number of DB parameter groups.</p>
     DBParameterGroupQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyDBClusterParameterGroupError {
@@ -8588,27 +8631,35 @@ impl CopyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CopyDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8636,12 +8687,14 @@ impl fmt::Display for CopyDBClusterParameterGroupError {
             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CopyDBClusterParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyDBClusterParameterGroupError {}
 /// Errors returned by CopyDBClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyDBClusterSnapshotError {
     /// <p>User already has a DB cluster snapshot with the given identifier.</p>
     DBClusterSnapshotAlreadyExistsFault(String),
@@ -8655,6 +8708,8 @@ pub enum CopyDBClusterSnapshotError {
     KMSKeyNotAccessibleFault(String),
     /// <p>Request would result in user exceeding the allowed number of DB snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyDBClusterSnapshotError {
@@ -8666,48 +8721,56 @@ impl CopyDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(CopyDBClusterSnapshotError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -8744,12 +8807,14 @@ impl fmt::Display for CopyDBClusterSnapshotError {
             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CopyDBClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyDBClusterSnapshotError {}
 /// Errors returned by CopyDBParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyDBParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
     DBParameterGroupAlreadyExistsFault(String),
@@ -8757,6 +8822,8 @@ pub enum CopyDBParameterGroupError {
     DBParameterGroupNotFoundFault(String),
     /// <p>Request would result in user exceeding the allowed number of DB parameter groups.</p>
     DBParameterGroupQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyDBParameterGroupError {
@@ -8768,27 +8835,30 @@ impl CopyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CopyDBParameterGroupError::Unhandled, &res),
                 }
             }
         }
@@ -8816,12 +8886,14 @@ impl fmt::Display for CopyDBParameterGroupError {
             CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CopyDBParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyDBParameterGroupError {}
 /// Errors returned by CreateDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBClusterError {
     /// <p>User already has a DB cluster with the given identifier.</p>
     DBClusterAlreadyExistsFault(String),
@@ -8853,6 +8925,8 @@ pub enum CreateDBClusterError {
     KMSKeyNotAccessibleFault(String),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
     StorageQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBClusterError {
@@ -8864,89 +8938,104 @@ impl CreateDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBClusterError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InsufficientStorageClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBClusterError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -8988,17 +9077,21 @@ impl fmt::Display for CreateDBClusterError {
             CreateDBClusterError::InvalidVPCNetworkStateFault(ref cause) => write!(f, "{}", cause),
             CreateDBClusterError::KMSKeyNotAccessibleFault(ref cause) => write!(f, "{}", cause),
             CreateDBClusterError::StorageQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            CreateDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDBClusterError {}
 /// Errors returned by CreateDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBClusterParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
     DBParameterGroupAlreadyExistsFault(String),
     /// <p>Request would result in user exceeding the allowed number of DB parameter groups.</p>
     DBParameterGroupQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBClusterParameterGroupError {
@@ -9012,20 +9105,27 @@ impl CreateDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9050,12 +9150,16 @@ impl fmt::Display for CreateDBClusterParameterGroupError {
             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDBClusterParameterGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateDBClusterParameterGroupError {}
 /// Errors returned by CreateDBClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBClusterSnapshotError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
@@ -9067,6 +9171,8 @@ pub enum CreateDBClusterSnapshotError {
     InvalidDBClusterStateFault(String),
     /// <p>Request would result in user exceeding the allowed number of DB snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBClusterSnapshotError {
@@ -9078,41 +9184,51 @@ impl CreateDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateDBClusterSnapshotError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9146,12 +9262,14 @@ impl fmt::Display for CreateDBClusterSnapshotError {
             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDBClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDBClusterSnapshotError {}
 /// Errors returned by CreateDBInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBInstanceError {
     /// <p>Specified CIDRIP or EC2 security group is not authorized for the specified DB security group.</p> <p>Neptune may not also be authorized via IAM to perform necessary actions on your behalf.</p>
     AuthorizationNotFoundFault(String),
@@ -9189,6 +9307,8 @@ pub enum CreateDBInstanceError {
     StorageQuotaExceededFault(String),
     /// <p> <i>StorageType</i> specified cannot be associated with the DB Instance.</p>
     StorageTypeNotSupportedFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBInstanceError {
@@ -9200,112 +9320,130 @@ impl CreateDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBInstanceError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBInstanceError::DomainNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBInstanceError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateDBInstanceError::Unhandled, &res),
                 }
             }
         }
@@ -9356,17 +9494,21 @@ impl fmt::Display for CreateDBInstanceError {
             CreateDBInstanceError::StorageTypeNotSupportedFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDBInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDBInstanceError {}
 /// Errors returned by CreateDBParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBParameterGroupError {
     /// <p>A DB parameter group with the same name exists.</p>
     DBParameterGroupAlreadyExistsFault(String),
     /// <p>Request would result in user exceeding the allowed number of DB parameter groups.</p>
     DBParameterGroupQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBParameterGroupError {
@@ -9378,20 +9520,24 @@ impl CreateDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(CreateDBParameterGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9416,12 +9562,14 @@ impl fmt::Display for CreateDBParameterGroupError {
             CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDBParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDBParameterGroupError {}
 /// Errors returned by CreateDBSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBSubnetGroupError {
     /// <p> <i>DBSubnetGroupName</i> is already used by an existing DB subnet group.</p>
     DBSubnetGroupAlreadyExistsFault(String),
@@ -9433,6 +9581,8 @@ pub enum CreateDBSubnetGroupError {
     DBSubnetQuotaExceededFault(String),
     /// <p>The requested subnet is invalid, or multiple subnets were requested that are not all in a common VPC.</p>
     InvalidSubnet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBSubnetGroupError {
@@ -9444,39 +9594,44 @@ impl CreateDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateDBSubnetGroupError::Unhandled, &res),
                 }
             }
         }
@@ -9508,12 +9663,14 @@ impl fmt::Display for CreateDBSubnetGroupError {
                 write!(f, "{}", cause)
             }
             CreateDBSubnetGroupError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
+            CreateDBSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDBSubnetGroupError {}
 /// Errors returned by CreateEventSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEventSubscriptionError {
     /// <p>You have exceeded the number of events you can subscribe to.</p>
     EventSubscriptionQuotaExceededFault(String),
@@ -9529,6 +9686,8 @@ pub enum CreateEventSubscriptionError {
     SubscriptionAlreadyExistFault(String),
     /// <p>The designated subscription category could not be found.</p>
     SubscriptionCategoryNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEventSubscriptionError {
@@ -9540,53 +9699,65 @@ impl CreateEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SubscriptionAlreadyExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateEventSubscriptionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9622,12 +9793,14 @@ impl fmt::Display for CreateEventSubscriptionError {
             CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateEventSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEventSubscriptionError {}
 /// Errors returned by DeleteDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
@@ -9639,6 +9812,8 @@ pub enum DeleteDBClusterError {
     InvalidDBClusterStateFault(String),
     /// <p>Request would result in user exceeding the allowed number of DB snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBClusterError {
@@ -9650,35 +9825,40 @@ impl DeleteDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -9706,17 +9886,21 @@ impl fmt::Display for DeleteDBClusterError {
             }
             DeleteDBClusterError::InvalidDBClusterStateFault(ref cause) => write!(f, "{}", cause),
             DeleteDBClusterError::SnapshotQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            DeleteDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDBClusterError {}
 /// Errors returned by DeleteDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBClusterParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
     InvalidDBParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBClusterParameterGroupError {
@@ -9730,20 +9914,27 @@ impl DeleteDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9768,17 +9959,23 @@ impl fmt::Display for DeleteDBClusterParameterGroupError {
             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDBClusterParameterGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteDBClusterParameterGroupError {}
 /// Errors returned by DeleteDBClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBClusterSnapshotError {
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
     DBClusterSnapshotNotFoundFault(String),
     /// <p>The supplied value is not a valid DB cluster snapshot state.</p>
     InvalidDBClusterSnapshotStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBClusterSnapshotError {
@@ -9790,20 +9987,27 @@ impl DeleteDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteDBClusterSnapshotError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9828,12 +10032,14 @@ impl fmt::Display for DeleteDBClusterSnapshotError {
             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDBClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDBClusterSnapshotError {}
 /// Errors returned by DeleteDBInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBInstanceError {
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
     DBInstanceNotFoundFault(String),
@@ -9845,6 +10051,8 @@ pub enum DeleteDBInstanceError {
     InvalidDBInstanceStateFault(String),
     /// <p>Request would result in user exceeding the allowed number of DB snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBInstanceError {
@@ -9856,35 +10064,40 @@ impl DeleteDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDBInstanceError::Unhandled, &res),
                 }
             }
         }
@@ -9910,17 +10123,21 @@ impl fmt::Display for DeleteDBInstanceError {
             DeleteDBInstanceError::InvalidDBClusterStateFault(ref cause) => write!(f, "{}", cause),
             DeleteDBInstanceError::InvalidDBInstanceStateFault(ref cause) => write!(f, "{}", cause),
             DeleteDBInstanceError::SnapshotQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            DeleteDBInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDBInstanceError {}
 /// Errors returned by DeleteDBParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
     InvalidDBParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBParameterGroupError {
@@ -9932,20 +10149,24 @@ impl DeleteDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteDBParameterGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9970,12 +10191,14 @@ impl fmt::Display for DeleteDBParameterGroupError {
             DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDBParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDBParameterGroupError {}
 /// Errors returned by DeleteDBSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBSubnetGroupError {
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
     DBSubnetGroupNotFoundFault(String),
@@ -9983,6 +10206,8 @@ pub enum DeleteDBSubnetGroupError {
     InvalidDBSubnetGroupStateFault(String),
     /// <p>The DB subnet is not in the <i>available</i> state.</p>
     InvalidDBSubnetStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBSubnetGroupError {
@@ -9994,27 +10219,30 @@ impl DeleteDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDBSubnetGroupError::Unhandled, &res),
                 }
             }
         }
@@ -10042,17 +10270,21 @@ impl fmt::Display for DeleteDBSubnetGroupError {
             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDBSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDBSubnetGroupError {}
 /// Errors returned by DeleteEventSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEventSubscriptionError {
     /// <p>The event subscription is in an invalid state.</p>
     InvalidEventSubscriptionStateFault(String),
     /// <p>The designated subscription could not be found.</p>
     SubscriptionNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEventSubscriptionError {
@@ -10064,20 +10296,27 @@ impl DeleteEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidEventSubscriptionState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteEventSubscriptionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10102,15 +10341,19 @@ impl fmt::Display for DeleteEventSubscriptionError {
             DeleteEventSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteEventSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEventSubscriptionError {}
 /// Errors returned by DescribeDBClusterParameterGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClusterParameterGroupsError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClusterParameterGroupsError {
@@ -10124,13 +10367,19 @@ impl DescribeDBClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDBClusterParameterGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10152,15 +10401,21 @@ impl fmt::Display for DescribeDBClusterParameterGroupsError {
             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBClusterParameterGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDBClusterParameterGroupsError {}
 /// Errors returned by DescribeDBClusterParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClusterParametersError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClusterParametersError {
@@ -10174,13 +10429,19 @@ impl DescribeDBClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDBClusterParametersError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10202,15 +10463,19 @@ impl fmt::Display for DescribeDBClusterParametersError {
             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBClusterParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBClusterParametersError {}
 /// Errors returned by DescribeDBClusterSnapshotAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClusterSnapshotAttributesError {
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
     DBClusterSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClusterSnapshotAttributesError {
@@ -10223,12 +10488,18 @@ impl DescribeDBClusterSnapshotAttributesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
+                    "DBClusterSnapshotNotFoundFault" => return RusotoError::service(
                         DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDBClusterSnapshotAttributesError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -10250,15 +10521,21 @@ impl fmt::Display for DescribeDBClusterSnapshotAttributesError {
             DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBClusterSnapshotAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDBClusterSnapshotAttributesError {}
 /// Errors returned by DescribeDBClusterSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClusterSnapshotsError {
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
     DBClusterSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClusterSnapshotsError {
@@ -10272,13 +10549,19 @@ impl DescribeDBClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDBClusterSnapshotsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10300,15 +10583,19 @@ impl fmt::Display for DescribeDBClusterSnapshotsError {
             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBClusterSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBClusterSnapshotsError {}
 /// Errors returned by DescribeDBClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClustersError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClustersError {
@@ -10320,11 +10607,12 @@ impl DescribeDBClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeDBClustersError::Unhandled, &res),
                 }
             }
         }
@@ -10344,13 +10632,18 @@ impl fmt::Display for DescribeDBClustersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeDBClustersError::DBClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeDBClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBClustersError {}
 /// Errors returned by DescribeDBEngineVersions
 #[derive(Debug, PartialEq)]
-pub enum DescribeDBEngineVersionsError {}
+#[non_exhaustive]
+pub enum DescribeDBEngineVersionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeDBEngineVersionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeDBEngineVersionsError> {
@@ -10358,10 +10651,8 @@ impl DescribeDBEngineVersionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeDBEngineVersionsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10378,15 +10669,20 @@ impl DescribeDBEngineVersionsError {
 impl fmt::Display for DescribeDBEngineVersionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeDBEngineVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeDBEngineVersionsError {}
 /// Errors returned by DescribeDBInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBInstancesError {
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
     DBInstanceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBInstancesError {
@@ -10398,11 +10694,12 @@ impl DescribeDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeDBInstancesError::Unhandled, &res),
                 }
             }
         }
@@ -10422,15 +10719,19 @@ impl fmt::Display for DescribeDBInstancesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeDBInstancesError::DBInstanceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeDBInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBInstancesError {}
 /// Errors returned by DescribeDBParameterGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBParameterGroupsError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBParameterGroupsError {
@@ -10442,13 +10743,19 @@ impl DescribeDBParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDBParameterGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10470,15 +10777,19 @@ impl fmt::Display for DescribeDBParameterGroupsError {
             DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBParameterGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBParameterGroupsError {}
 /// Errors returned by DescribeDBParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBParametersError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBParametersError {
@@ -10490,13 +10801,14 @@ impl DescribeDBParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBParametersError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeDBParametersError::Unhandled, &res),
                 }
             }
         }
@@ -10518,15 +10830,19 @@ impl fmt::Display for DescribeDBParametersError {
             DescribeDBParametersError::DBParameterGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBParametersError {}
 /// Errors returned by DescribeDBSubnetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBSubnetGroupsError {
     /// <p> <i>DBSubnetGroupName</i> does not refer to an existing DB subnet group.</p>
     DBSubnetGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBSubnetGroupsError {
@@ -10538,13 +10854,16 @@ impl DescribeDBSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeDBSubnetGroupsError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -10566,13 +10885,18 @@ impl fmt::Display for DescribeDBSubnetGroupsError {
             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBSubnetGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBSubnetGroupsError {}
 /// Errors returned by DescribeEngineDefaultClusterParameters
 #[derive(Debug, PartialEq)]
-pub enum DescribeEngineDefaultClusterParametersError {}
+#[non_exhaustive]
+pub enum DescribeEngineDefaultClusterParametersError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEngineDefaultClusterParametersError {
     pub fn from_response(
@@ -10582,10 +10906,11 @@ impl DescribeEngineDefaultClusterParametersError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DescribeEngineDefaultClusterParametersError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -10602,13 +10927,21 @@ impl DescribeEngineDefaultClusterParametersError {
 impl fmt::Display for DescribeEngineDefaultClusterParametersError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEngineDefaultClusterParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeEngineDefaultClusterParametersError {}
 /// Errors returned by DescribeEngineDefaultParameters
 #[derive(Debug, PartialEq)]
-pub enum DescribeEngineDefaultParametersError {}
+#[non_exhaustive]
+pub enum DescribeEngineDefaultParametersError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEngineDefaultParametersError {
     pub fn from_response(
@@ -10618,10 +10951,11 @@ impl DescribeEngineDefaultParametersError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DescribeEngineDefaultParametersError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -10638,13 +10972,21 @@ impl DescribeEngineDefaultParametersError {
 impl fmt::Display for DescribeEngineDefaultParametersError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEngineDefaultParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeEngineDefaultParametersError {}
 /// Errors returned by DescribeEventCategories
 #[derive(Debug, PartialEq)]
-pub enum DescribeEventCategoriesError {}
+#[non_exhaustive]
+pub enum DescribeEventCategoriesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEventCategoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventCategoriesError> {
@@ -10652,10 +10994,8 @@ impl DescribeEventCategoriesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeEventCategoriesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10672,15 +11012,20 @@ impl DescribeEventCategoriesError {
 impl fmt::Display for DescribeEventCategoriesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEventCategoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEventCategoriesError {}
 /// Errors returned by DescribeEventSubscriptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventSubscriptionsError {
     /// <p>The designated subscription could not be found.</p>
     SubscriptionNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventSubscriptionsError {
@@ -10694,13 +11039,19 @@ impl DescribeEventSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeEventSubscriptionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10722,13 +11073,18 @@ impl fmt::Display for DescribeEventSubscriptionsError {
             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEventSubscriptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventSubscriptionsError {}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
-pub enum DescribeEventsError {}
+#[non_exhaustive]
+pub enum DescribeEventsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEventsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventsError> {
@@ -10736,10 +11092,8 @@ impl DescribeEventsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeEventsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10756,13 +11110,19 @@ impl DescribeEventsError {
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEventsError {}
 /// Errors returned by DescribeOrderableDBInstanceOptions
 #[derive(Debug, PartialEq)]
-pub enum DescribeOrderableDBInstanceOptionsError {}
+#[non_exhaustive]
+pub enum DescribeOrderableDBInstanceOptionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeOrderableDBInstanceOptionsError {
     pub fn from_response(
@@ -10772,10 +11132,11 @@ impl DescribeOrderableDBInstanceOptionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DescribeOrderableDBInstanceOptionsError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -10792,15 +11153,22 @@ impl DescribeOrderableDBInstanceOptionsError {
 impl fmt::Display for DescribeOrderableDBInstanceOptionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeOrderableDBInstanceOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeOrderableDBInstanceOptionsError {}
 /// Errors returned by DescribePendingMaintenanceActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePendingMaintenanceActionsError {
     /// <p>The specified resource ID was not found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePendingMaintenanceActionsError {
@@ -10814,13 +11182,19 @@ impl DescribePendingMaintenanceActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribePendingMaintenanceActionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10842,17 +11216,23 @@ impl fmt::Display for DescribePendingMaintenanceActionsError {
             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribePendingMaintenanceActionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribePendingMaintenanceActionsError {}
 /// Errors returned by DescribeValidDBInstanceModifications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeValidDBInstanceModificationsError {
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
     DBInstanceNotFoundFault(String),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
     InvalidDBInstanceStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeValidDBInstanceModificationsError {
@@ -10866,20 +11246,27 @@ impl DescribeValidDBInstanceModificationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeValidDBInstanceModificationsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10904,12 +11291,16 @@ impl fmt::Display for DescribeValidDBInstanceModificationsError {
             DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeValidDBInstanceModificationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeValidDBInstanceModificationsError {}
 /// Errors returned by FailoverDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FailoverDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
@@ -10917,6 +11308,8 @@ pub enum FailoverDBClusterError {
     InvalidDBClusterStateFault(String),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
     InvalidDBInstanceStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl FailoverDBClusterError {
@@ -10928,25 +11321,28 @@ impl FailoverDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             FailoverDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             FailoverDBClusterError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(FailoverDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -10970,12 +11366,14 @@ impl fmt::Display for FailoverDBClusterError {
             FailoverDBClusterError::InvalidDBInstanceStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            FailoverDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for FailoverDBClusterError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
@@ -10983,6 +11381,8 @@ pub enum ListTagsForResourceError {
     DBInstanceNotFoundFault(String),
     /// <p> <i>DBSnapshotIdentifier</i> does not refer to an existing DB snapshot.</p>
     DBSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -10994,21 +11394,24 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
                 }
             }
         }
@@ -11030,12 +11433,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::DBClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::DBInstanceNotFoundFault(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::DBSnapshotNotFoundFault(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ModifyDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBClusterError {
     /// <p>User already has a DB cluster with the given identifier.</p>
     DBClusterAlreadyExistsFault(String),
@@ -11059,6 +11464,8 @@ pub enum ModifyDBClusterError {
     InvalidVPCNetworkStateFault(String),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
     StorageQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBClusterError {
@@ -11070,67 +11477,78 @@ impl ModifyDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyDBClusterError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -11166,17 +11584,21 @@ impl fmt::Display for ModifyDBClusterError {
             ModifyDBClusterError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
             ModifyDBClusterError::InvalidVPCNetworkStateFault(ref cause) => write!(f, "{}", cause),
             ModifyDBClusterError::StorageQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            ModifyDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyDBClusterError {}
 /// Errors returned by ModifyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBClusterParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
     InvalidDBParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBClusterParameterGroupError {
@@ -11190,20 +11612,27 @@ impl ModifyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11228,12 +11657,16 @@ impl fmt::Display for ModifyDBClusterParameterGroupError {
             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyDBClusterParameterGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ModifyDBClusterParameterGroupError {}
 /// Errors returned by ModifyDBClusterSnapshotAttribute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBClusterSnapshotAttributeError {
     /// <p> <i>DBClusterSnapshotIdentifier</i> does not refer to an existing DB cluster snapshot.</p>
     DBClusterSnapshotNotFoundFault(String),
@@ -11241,6 +11674,8 @@ pub enum ModifyDBClusterSnapshotAttributeError {
     InvalidDBClusterSnapshotStateFault(String),
     /// <p>You have exceeded the maximum number of accounts that you can share a manual DB snapshot with.</p>
     SharedSnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBClusterSnapshotAttributeError {
@@ -11254,25 +11689,33 @@ impl ModifyDBClusterSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
+                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::service(
                         ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyDBClusterSnapshotAttributeError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11300,12 +11743,16 @@ impl fmt::Display for ModifyDBClusterSnapshotAttributeError {
             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyDBClusterSnapshotAttributeError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ModifyDBClusterSnapshotAttributeError {}
 /// Errors returned by ModifyDBInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBInstanceError {
     /// <p>Specified CIDRIP or EC2 security group is not authorized for the specified DB security group.</p> <p>Neptune may not also be authorized via IAM to perform necessary actions on your behalf.</p>
     AuthorizationNotFoundFault(String),
@@ -11339,6 +11786,8 @@ pub enum ModifyDBInstanceError {
     StorageQuotaExceededFault(String),
     /// <p> <i>StorageType</i> specified cannot be associated with the DB Instance.</p>
     StorageTypeNotSupportedFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBInstanceError {
@@ -11350,106 +11799,122 @@ impl ModifyDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyDBInstanceError::DomainNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyDBInstanceError::Unhandled, &res),
                 }
             }
         }
@@ -11500,17 +11965,21 @@ impl fmt::Display for ModifyDBInstanceError {
             ModifyDBInstanceError::StorageTypeNotSupportedFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyDBInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyDBInstanceError {}
 /// Errors returned by ModifyDBParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
     InvalidDBParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBParameterGroupError {
@@ -11522,20 +11991,24 @@ impl ModifyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(ModifyDBParameterGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -11560,12 +12033,14 @@ impl fmt::Display for ModifyDBParameterGroupError {
             ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyDBParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyDBParameterGroupError {}
 /// Errors returned by ModifyDBSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBSubnetGroupError {
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
     DBSubnetGroupDoesNotCoverEnoughAZs(String),
@@ -11577,6 +12052,8 @@ pub enum ModifyDBSubnetGroupError {
     InvalidSubnet(String),
     /// <p>The DB subnet is already in use in the Availability Zone.</p>
     SubnetAlreadyInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBSubnetGroupError {
@@ -11588,37 +12065,42 @@ impl ModifyDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyDBSubnetGroupError::SubnetAlreadyInUse(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyDBSubnetGroupError::Unhandled, &res),
                 }
             }
         }
@@ -11648,12 +12130,14 @@ impl fmt::Display for ModifyDBSubnetGroupError {
             }
             ModifyDBSubnetGroupError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
             ModifyDBSubnetGroupError::SubnetAlreadyInUse(ref cause) => write!(f, "{}", cause),
+            ModifyDBSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyDBSubnetGroupError {}
 /// Errors returned by ModifyEventSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyEventSubscriptionError {
     /// <p>You have exceeded the number of events you can subscribe to.</p>
     EventSubscriptionQuotaExceededFault(String),
@@ -11667,6 +12151,8 @@ pub enum ModifyEventSubscriptionError {
     SubscriptionCategoryNotFoundFault(String),
     /// <p>The designated subscription could not be found.</p>
     SubscriptionNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyEventSubscriptionError {
@@ -11678,48 +12164,59 @@ impl ModifyEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyEventSubscriptionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11754,17 +12251,21 @@ impl fmt::Display for ModifyEventSubscriptionError {
             ModifyEventSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyEventSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyEventSubscriptionError {}
 /// Errors returned by PromoteReadReplicaDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PromoteReadReplicaDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
     /// <p>The DB cluster is not in a valid state.</p>
     InvalidDBClusterStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PromoteReadReplicaDBClusterError {
@@ -11778,20 +12279,27 @@ impl PromoteReadReplicaDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            PromoteReadReplicaDBClusterError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11816,17 +12324,21 @@ impl fmt::Display for PromoteReadReplicaDBClusterError {
             PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PromoteReadReplicaDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PromoteReadReplicaDBClusterError {}
 /// Errors returned by RebootDBInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootDBInstanceError {
     /// <p> <i>DBInstanceIdentifier</i> does not refer to an existing DB instance.</p>
     DBInstanceNotFoundFault(String),
     /// <p>The specified DB instance is not in the <i>available</i> state.</p>
     InvalidDBInstanceStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootDBInstanceError {
@@ -11838,18 +12350,20 @@ impl RebootDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RebootDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RebootDBInstanceError::Unhandled, &res),
                 }
             }
         }
@@ -11870,12 +12384,14 @@ impl fmt::Display for RebootDBInstanceError {
         match *self {
             RebootDBInstanceError::DBInstanceNotFoundFault(ref cause) => write!(f, "{}", cause),
             RebootDBInstanceError::InvalidDBInstanceStateFault(ref cause) => write!(f, "{}", cause),
+            RebootDBInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootDBInstanceError {}
 /// Errors returned by RemoveRoleFromDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveRoleFromDBClusterError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
@@ -11883,6 +12399,8 @@ pub enum RemoveRoleFromDBClusterError {
     DBClusterRoleNotFoundFault(String),
     /// <p>The DB cluster is not in a valid state.</p>
     InvalidDBClusterStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveRoleFromDBClusterError {
@@ -11894,27 +12412,35 @@ impl RemoveRoleFromDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveRoleFromDBClusterError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterRoleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RemoveRoleFromDBClusterError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11942,17 +12468,21 @@ impl fmt::Display for RemoveRoleFromDBClusterError {
             RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RemoveRoleFromDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveRoleFromDBClusterError {}
 /// Errors returned by RemoveSourceIdentifierFromSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveSourceIdentifierFromSubscriptionError {
     /// <p>The source could not be found.</p>
     SourceNotFoundFault(String),
     /// <p>The designated subscription could not be found.</p>
     SubscriptionNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveSourceIdentifierFromSubscriptionError {
@@ -11966,20 +12496,27 @@ impl RemoveSourceIdentifierFromSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RemoveSourceIdentifierFromSubscriptionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12004,12 +12541,16 @@ impl fmt::Display for RemoveSourceIdentifierFromSubscriptionError {
             RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RemoveSourceIdentifierFromSubscriptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RemoveSourceIdentifierFromSubscriptionError {}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromResourceError {
     /// <p> <i>DBClusterIdentifier</i> does not refer to an existing DB cluster.</p>
     DBClusterNotFoundFault(String),
@@ -12017,6 +12558,8 @@ pub enum RemoveTagsFromResourceError {
     DBInstanceNotFoundFault(String),
     /// <p> <i>DBSnapshotIdentifier</i> does not refer to an existing DB snapshot.</p>
     DBSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromResourceError {
@@ -12028,27 +12571,32 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveTagsFromResourceError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveTagsFromResourceError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(RemoveTagsFromResourceError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -12076,17 +12624,21 @@ impl fmt::Display for RemoveTagsFromResourceError {
             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RemoveTagsFromResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromResourceError {}
 /// Errors returned by ResetDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetDBClusterParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
     InvalidDBParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetDBClusterParameterGroupError {
@@ -12100,20 +12652,27 @@ impl ResetDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ResetDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12138,17 +12697,21 @@ impl fmt::Display for ResetDBClusterParameterGroupError {
             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ResetDBClusterParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetDBClusterParameterGroupError {}
 /// Errors returned by ResetDBParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetDBParameterGroupError {
     /// <p> <i>DBParameterGroupName</i> does not refer to an existing DB parameter group.</p>
     DBParameterGroupNotFoundFault(String),
     /// <p>The DB parameter group is in use or is in an invalid state. If you are attempting to delete the parameter group, you cannot delete it when the parameter group is in this state.</p>
     InvalidDBParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetDBParameterGroupError {
@@ -12160,20 +12723,24 @@ impl ResetDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(ResetDBParameterGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -12198,12 +12765,14 @@ impl fmt::Display for ResetDBParameterGroupError {
             ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ResetDBParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetDBParameterGroupError {}
 /// Errors returned by RestoreDBClusterFromSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreDBClusterFromSnapshotError {
     /// <p>User already has a DB cluster with the given identifier.</p>
     DBClusterAlreadyExistsFault(String),
@@ -12237,6 +12806,8 @@ pub enum RestoreDBClusterFromSnapshotError {
     OptionGroupNotFoundFault(String),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
     StorageQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreDBClusterFromSnapshotError {
@@ -12250,114 +12821,135 @@ impl RestoreDBClusterFromSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
                         RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RestoreDBClusterFromSnapshotError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12422,12 +13014,14 @@ impl fmt::Display for RestoreDBClusterFromSnapshotError {
             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RestoreDBClusterFromSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreDBClusterFromSnapshotError {}
 /// Errors returned by RestoreDBClusterToPointInTime
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreDBClusterToPointInTimeError {
     /// <p>User already has a DB cluster with the given identifier.</p>
     DBClusterAlreadyExistsFault(String),
@@ -12463,6 +13057,8 @@ pub enum RestoreDBClusterToPointInTimeError {
     OptionGroupNotFoundFault(String),
     /// <p>Request would result in user exceeding the allowed amount of storage available across all DB instances.</p>
     StorageQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreDBClusterToPointInTimeError {
@@ -12476,119 +13072,141 @@ impl RestoreDBClusterToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "DBClusterParameterGroupNotFound" => return RusotoError::Service(
+                    "DBClusterParameterGroupNotFound" => return RusotoError::service(
                         RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
                         RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RestoreDBClusterToPointInTimeError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12656,6 +13274,9 @@ impl fmt::Display for RestoreDBClusterToPointInTimeError {
             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RestoreDBClusterToPointInTimeError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -13040,6 +13661,7 @@ impl NeptuneClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> NeptuneClient {
         NeptuneClient {
             client: Client::shared(),
@@ -13065,68 +13687,106 @@ impl NeptuneClient {
     pub fn new_with_client(client: Client, region: region::Region) -> NeptuneClient {
         NeptuneClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<NeptuneClient> {
+        rusoto_core::ClientBuilder::new(NeptuneClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> NeptuneClient {
+        NeptuneClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Neptune for NeptuneClient {
-    /// <p>Associates an Identity and Access Management (IAM) role from an Neptune DB cluster.</p>
-    async fn add_role_to_db_cluster(
+impl NeptuneClient {
+    /// Calls `add_role_to_db_cluster`, also returning the metadata of the response.
+    pub async fn add_role_to_db_cluster_with_metadata(
         &self,
         input: AddRoleToDBClusterMessage,
-    ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddRoleToDBCluster");
-        params.put("Version", "2014-10-31");
-        AddRoleToDBClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<((), ResponseMetadata), RusotoError<AddRoleToDBClusterError>> {
+        let request = self.build_add_role_to_db_cluster_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddRoleToDBClusterError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Adds a source identifier to an existing event notification subscription.</p>
-    async fn add_source_identifier_to_subscription(
+    /// Presigns the request `add_role_to_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_role_to_db_cluster(
         &self,
-        input: AddSourceIdentifierToSubscriptionMessage,
-    ) -> Result<
-        AddSourceIdentifierToSubscriptionResult,
-        RusotoError<AddSourceIdentifierToSubscriptionError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: AddRoleToDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_role_to_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_role_to_db_cluster_request(
+        &self,
+        input: AddRoleToDBClusterMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddRoleToDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "AddSourceIdentifierToSubscription");
+        params.put("Action", "AddRoleToDBCluster");
         params.put("Version", "2014-10-31");
-        AddSourceIdentifierToSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        AddRoleToDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `add_source_identifier_to_subscription`, also returning the metadata of the response.
+    pub async fn add_source_identifier_to_subscription_with_metadata(
+        &self,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> Result<
+        (AddSourceIdentifierToSubscriptionResult, ResponseMetadata),
+        RusotoError<AddSourceIdentifierToSubscriptionError>,
+    > {
+        let request = self.build_add_source_identifier_to_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddSourceIdentifierToSubscriptionError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13148,63 +13808,113 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds metadata tags to an Amazon Neptune resource. These tags can also be used with cost allocation reporting to track cost associated with Amazon Neptune resources, or used in a Condition statement in an IAM policy for Amazon Neptune.</p>
-    async fn add_tags_to_resource(
+    /// Presigns the request `add_source_identifier_to_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_source_identifier_to_subscription(
         &self,
-        input: AddTagsToResourceMessage,
-    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_source_identifier_to_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_source_identifier_to_subscription_request(
+        &self,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddSourceIdentifierToSubscription");
         let mut params = Params::new();
 
-        params.put("Action", "AddTagsToResource");
+        params.put("Action", "AddSourceIdentifierToSubscription");
         params.put("Version", "2014-10-31");
-        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
+        AddSourceIdentifierToSubscriptionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `add_tags_to_resource`, also returning the metadata of the response.
+    pub async fn add_tags_to_resource_with_metadata(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<AddTagsToResourceError>> {
+        let request = self.build_add_tags_to_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddTagsToResourceError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Applies a pending maintenance action to a resource (for example, to a DB instance).</p>
-    async fn apply_pending_maintenance_action(
+    /// Presigns the request `add_tags_to_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_resource(
         &self,
-        input: ApplyPendingMaintenanceActionMessage,
-    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_resource_request(&self, input: AddTagsToResourceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let mut params = Params::new();
 
-        params.put("Action", "ApplyPendingMaintenanceAction");
+        params.put("Action", "AddTagsToResource");
         params.put("Version", "2014-10-31");
-        ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
+        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `apply_pending_maintenance_action`, also returning the metadata of the response.
+    pub async fn apply_pending_maintenance_action_with_metadata(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<
+        (ApplyPendingMaintenanceActionResult, ResponseMetadata),
+        RusotoError<ApplyPendingMaintenanceActionError>,
+    > {
+        let request = self.build_apply_pending_maintenance_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ApplyPendingMaintenanceActionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13226,35 +13936,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Copies the specified DB cluster parameter group.</p>
-    async fn copy_db_cluster_parameter_group(
+    /// Presigns the request `apply_pending_maintenance_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_apply_pending_maintenance_action(
         &self,
-        input: CopyDBClusterParameterGroupMessage,
-    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_apply_pending_maintenance_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_apply_pending_maintenance_action_request(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
         let mut params = Params::new();
 
-        params.put("Action", "CopyDBClusterParameterGroup");
+        params.put("Action", "ApplyPendingMaintenanceAction");
         params.put("Version", "2014-10-31");
-        CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `copy_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn copy_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<
+        (CopyDBClusterParameterGroupResult, ResponseMetadata),
+        RusotoError<CopyDBClusterParameterGroupError>,
+    > {
+        let request = self.build_copy_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CopyDBClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13276,34 +14015,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Copies a snapshot of a DB cluster.</p> <p>To copy a DB cluster snapshot from a shared manual DB cluster snapshot, <code>SourceDBClusterSnapshotIdentifier</code> must be the Amazon Resource Name (ARN) of the shared DB cluster snapshot.</p> <p>You can't copy from one AWS Region to another.</p>
-    async fn copy_db_cluster_snapshot(
+    /// Presigns the request `copy_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_db_cluster_parameter_group(
         &self,
-        input: CopyDBClusterSnapshotMessage,
-    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_db_cluster_parameter_group_request(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CopyDBClusterSnapshot");
+        params.put("Action", "CopyDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `copy_db_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn copy_db_cluster_snapshot_with_metadata(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<
+        (CopyDBClusterSnapshotResult, ResponseMetadata),
+        RusotoError<CopyDBClusterSnapshotError>,
+    > {
+        let request = self.build_copy_db_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CopyDBClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13325,34 +14094,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Copies the specified DB parameter group.</p>
-    async fn copy_db_parameter_group(
+    /// Presigns the request `copy_db_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_db_cluster_snapshot(
         &self,
-        input: CopyDBParameterGroupMessage,
-    ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_db_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_db_cluster_snapshot_request(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "CopyDBParameterGroup");
+        params.put("Action", "CopyDBClusterSnapshot");
         params.put("Version", "2014-10-31");
-        CopyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `copy_db_parameter_group`, also returning the metadata of the response.
+    pub async fn copy_db_parameter_group_with_metadata(
+        &self,
+        input: CopyDBParameterGroupMessage,
+    ) -> Result<
+        (CopyDBParameterGroupResult, ResponseMetadata),
+        RusotoError<CopyDBParameterGroupError>,
+    > {
+        let request = self.build_copy_db_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CopyDBParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13374,34 +14173,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new Amazon Neptune DB cluster.</p> <p>You can use the <code>ReplicationSourceIdentifier</code> parameter to create the DB cluster as a Read Replica of another DB cluster or Amazon Neptune DB instance.</p>
-    async fn create_db_cluster(
+    /// Presigns the request `copy_db_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_db_parameter_group(
         &self,
-        input: CreateDBClusterMessage,
-    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CopyDBParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_db_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_db_parameter_group_request(
+        &self,
+        input: CopyDBParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBCluster");
+        params.put("Action", "CopyDBParameterGroup");
         params.put("Version", "2014-10-31");
-        CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        CopyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_cluster`, also returning the metadata of the response.
+    pub async fn create_db_cluster_with_metadata(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<(CreateDBClusterResult, ResponseMetadata), RusotoError<CreateDBClusterError>> {
+        let request = self.build_create_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13423,35 +14249,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a new DB cluster parameter group.</p> <p>Parameters in a DB cluster parameter group apply to all of the instances in a DB cluster.</p> <p> A DB cluster parameter group is initially created with the default parameters for the database engine used by instances in the DB cluster. To provide custom values for any of the parameters, you must modify the group after creating it using <a>ModifyDBClusterParameterGroup</a>. Once you&#39;ve created a DB cluster parameter group, you need to associate it with your DB cluster using <a>ModifyDBCluster</a>. When you associate a new DB cluster parameter group with a running DB cluster, you need to reboot the DB instances in the DB cluster without failover for the new DB cluster parameter group and associated settings to take effect.</p> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon Neptune to fully complete the create action before the DB cluster parameter group is used as the default for a new DB cluster. This is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the <a href="https://console.aws.amazon.com/rds/">Amazon Neptune console</a> or the <a>DescribeDBClusterParameters</a> command to verify that your DB cluster parameter group has been created or modified.</p> </important></p>
-    async fn create_db_cluster_parameter_group(
+    /// Presigns the request `create_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_cluster(
         &self,
-        input: CreateDBClusterParameterGroupMessage,
-    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_cluster_request(&self, input: CreateDBClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBClusterParameterGroup");
+        params.put("Action", "CreateDBCluster");
         params.put("Version", "2014-10-31");
-        CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn create_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<
+        (CreateDBClusterParameterGroupResult, ResponseMetadata),
+        RusotoError<CreateDBClusterParameterGroupError>,
+    > {
+        let request = self.build_create_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13473,34 +14325,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a snapshot of a DB cluster.</p>
-    async fn create_db_cluster_snapshot(
+    /// Presigns the request `create_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_cluster_parameter_group(
         &self,
-        input: CreateDBClusterSnapshotMessage,
-    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_cluster_parameter_group_request(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBClusterSnapshot");
+        params.put("Action", "CreateDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn create_db_cluster_snapshot_with_metadata(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<
+        (CreateDBClusterSnapshotResult, ResponseMetadata),
+        RusotoError<CreateDBClusterSnapshotError>,
+    > {
+        let request = self.build_create_db_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13522,34 +14404,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new DB instance.</p>
-    async fn create_db_instance(
+    /// Presigns the request `create_db_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_cluster_snapshot(
         &self,
-        input: CreateDBInstanceMessage,
-    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_cluster_snapshot_request(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBInstance");
+        params.put("Action", "CreateDBClusterSnapshot");
         params.put("Version", "2014-10-31");
-        CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_instance`, also returning the metadata of the response.
+    pub async fn create_db_instance_with_metadata(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<(CreateDBInstanceResult, ResponseMetadata), RusotoError<CreateDBInstanceError>>
+    {
+        let request = self.build_create_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13571,34 +14481,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a new DB parameter group.</p> <p>A DB parameter group is initially created with the default parameters for the database engine used by the DB instance. To provide custom values for any of the parameters, you must modify the group after creating it using <i>ModifyDBParameterGroup</i>. Once you&#39;ve created a DB parameter group, you need to associate it with your DB instance using <i>ModifyDBInstance</i>. When you associate a new DB parameter group with a running DB instance, you need to reboot the DB instance without failover for the new DB parameter group and associated settings to take effect.</p> <important> <p>After you create a DB parameter group, you should wait at least 5 minutes before creating your first DB instance that uses that DB parameter group as the default parameter group. This allows Amazon Neptune to fully complete the create action before the parameter group is used as the default for a new DB instance. This is especially important for parameters that are critical when creating the default database for a DB instance, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the Amazon Neptune console or the <i>DescribeDBParameters</i> command to verify that your DB parameter group has been created or modified.</p> </important></p>
-    async fn create_db_parameter_group(
+    /// Presigns the request `create_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_instance(
         &self,
-        input: CreateDBParameterGroupMessage,
-    ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBInstanceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_instance_request(&self, input: CreateDBInstanceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstance");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBParameterGroup");
+        params.put("Action", "CreateDBInstance");
         params.put("Version", "2014-10-31");
-        CreateDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_parameter_group`, also returning the metadata of the response.
+    pub async fn create_db_parameter_group_with_metadata(
+        &self,
+        input: CreateDBParameterGroupMessage,
+    ) -> Result<
+        (CreateDBParameterGroupResult, ResponseMetadata),
+        RusotoError<CreateDBParameterGroupError>,
+    > {
+        let request = self.build_create_db_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13620,34 +14557,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new DB subnet group. DB subnet groups must contain at least one subnet in at least two AZs in the AWS Region.</p>
-    async fn create_db_subnet_group(
+    /// Presigns the request `create_db_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_parameter_group(
         &self,
-        input: CreateDBSubnetGroupMessage,
-    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_parameter_group_request(
+        &self,
+        input: CreateDBParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBSubnetGroup");
+        params.put("Action", "CreateDBParameterGroup");
         params.put("Version", "2014-10-31");
-        CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_subnet_group`, also returning the metadata of the response.
+    pub async fn create_db_subnet_group_with_metadata(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<(CreateDBSubnetGroupResult, ResponseMetadata), RusotoError<CreateDBSubnetGroupError>>
+    {
+        let request = self.build_create_db_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBSubnetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13669,34 +14634,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an event notification subscription. This action requires a topic ARN (Amazon Resource Name) created by either the Neptune console, the SNS console, or the SNS API. To obtain an ARN with SNS, you must create a topic in Amazon SNS and subscribe to the topic. The ARN is displayed in the SNS console.</p> <p>You can specify the type of source (SourceType) you want to be notified of, provide a list of Neptune sources (SourceIds) that triggers the events, and provide a list of event categories (EventCategories) for events you want to be notified of. For example, you can specify SourceType = db-instance, SourceIds = mydbinstance1, mydbinstance2 and EventCategories = Availability, Backup.</p> <p>If you specify both the SourceType and SourceIds, such as SourceType = db-instance and SourceIdentifier = myDBInstance1, you are notified of all the db-instance events for the specified source. If you specify a SourceType but do not specify a SourceIdentifier, you receive notice of the events for that source type for all your Neptune sources. If you do not specify either the SourceType nor the SourceIdentifier, you are notified of events generated from all Neptune sources belonging to your customer account.</p>
-    async fn create_event_subscription(
+    /// Presigns the request `create_db_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_subnet_group(
         &self,
-        input: CreateEventSubscriptionMessage,
-    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_subnet_group_request(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateEventSubscription");
+        params.put("Action", "CreateDBSubnetGroup");
         params.put("Version", "2014-10-31");
-        CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_event_subscription`, also returning the metadata of the response.
+    pub async fn create_event_subscription_with_metadata(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<
+        (CreateEventSubscriptionResult, ResponseMetadata),
+        RusotoError<CreateEventSubscriptionError>,
+    > {
+        let request = self.build_create_event_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateEventSubscriptionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13718,34 +14713,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>The DeleteDBCluster action deletes a previously provisioned DB cluster. When you delete a DB cluster, all automated backups for that DB cluster are deleted and can't be recovered. Manual DB cluster snapshots of the specified DB cluster are not deleted.</p>
-    async fn delete_db_cluster(
+    /// Presigns the request `create_event_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_event_subscription(
         &self,
-        input: DeleteDBClusterMessage,
-    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_event_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_event_subscription_request(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBCluster");
+        params.put("Action", "CreateEventSubscription");
         params.put("Version", "2014-10-31");
-        DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_cluster`, also returning the metadata of the response.
+    pub async fn delete_db_cluster_with_metadata(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<(DeleteDBClusterResult, ResponseMetadata), RusotoError<DeleteDBClusterError>> {
+        let request = self.build_delete_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13767,62 +14789,113 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a specified DB cluster parameter group. The DB cluster parameter group to be deleted can't be associated with any DB clusters.</p>
-    async fn delete_db_cluster_parameter_group(
+    /// Presigns the request `delete_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_cluster(
         &self,
-        input: DeleteDBClusterParameterGroupMessage,
-    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_cluster_request(&self, input: DeleteDBClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBClusterParameterGroup");
+        params.put("Action", "DeleteDBCluster");
         params.put("Version", "2014-10-31");
-        DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn delete_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDBClusterParameterGroupError>> {
+        let request = self.build_delete_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBClusterParameterGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes a DB cluster snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The DB cluster snapshot must be in the <code>available</code> state to be deleted.</p> </note></p>
-    async fn delete_db_cluster_snapshot(
+    /// Presigns the request `delete_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_cluster_parameter_group(
         &self,
-        input: DeleteDBClusterSnapshotMessage,
-    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_cluster_parameter_group_request(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBClusterSnapshot");
+        params.put("Action", "DeleteDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn delete_db_cluster_snapshot_with_metadata(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<
+        (DeleteDBClusterSnapshotResult, ResponseMetadata),
+        RusotoError<DeleteDBClusterSnapshotError>,
+    > {
+        let request = self.build_delete_db_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13844,34 +14917,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>The DeleteDBInstance action deletes a previously provisioned DB instance. When you delete a DB instance, all automated backups for that instance are deleted and can't be recovered. Manual DB snapshots of the DB instance to be deleted by <code>DeleteDBInstance</code> are not deleted.</p> <p> If you request a final DB snapshot the status of the Amazon Neptune DB instance is <code>deleting</code> until the DB snapshot is created. The API action <code>DescribeDBInstance</code> is used to monitor the status of this operation. The action can't be canceled or reverted once submitted.</p> <p>Note that when a DB instance is in a failure state and has a status of <code>failed</code>, <code>incompatible-restore</code>, or <code>incompatible-network</code>, you can only delete it when the <code>SkipFinalSnapshot</code> parameter is set to <code>true</code>.</p> <p>You can't delete a DB instance if it is the only instance in the DB cluster.</p>
-    async fn delete_db_instance(
+    /// Presigns the request `delete_db_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_cluster_snapshot(
         &self,
-        input: DeleteDBInstanceMessage,
-    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_cluster_snapshot_request(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBInstance");
+        params.put("Action", "DeleteDBClusterSnapshot");
         params.put("Version", "2014-10-31");
-        DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_instance`, also returning the metadata of the response.
+    pub async fn delete_db_instance_with_metadata(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<(DeleteDBInstanceResult, ResponseMetadata), RusotoError<DeleteDBInstanceError>>
+    {
+        let request = self.build_delete_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13893,90 +14994,165 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a specified DBParameterGroup. The DBParameterGroup to be deleted can't be associated with any DB instances.</p>
-    async fn delete_db_parameter_group(
+    /// Presigns the request `delete_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_instance(
         &self,
-        input: DeleteDBParameterGroupMessage,
-    ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_instance_request(&self, input: DeleteDBInstanceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstance");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBParameterGroup");
+        params.put("Action", "DeleteDBInstance");
         params.put("Version", "2014-10-31");
-        DeleteDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_parameter_group`, also returning the metadata of the response.
+    pub async fn delete_db_parameter_group_with_metadata(
+        &self,
+        input: DeleteDBParameterGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDBParameterGroupError>> {
+        let request = self.build_delete_db_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBParameterGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes a DB subnet group.</p> <note> <p>The specified database subnet group must not be associated with any DB instances.</p> </note></p>
-    async fn delete_db_subnet_group(
+    /// Presigns the request `delete_db_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_parameter_group(
         &self,
-        input: DeleteDBSubnetGroupMessage,
-    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_parameter_group_request(
+        &self,
+        input: DeleteDBParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBSubnetGroup");
+        params.put("Action", "DeleteDBParameterGroup");
         params.put("Version", "2014-10-31");
-        DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_subnet_group`, also returning the metadata of the response.
+    pub async fn delete_db_subnet_group_with_metadata(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDBSubnetGroupError>> {
+        let request = self.build_delete_db_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBSubnetGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes an event notification subscription.</p>
-    async fn delete_event_subscription(
+    /// Presigns the request `delete_db_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_subnet_group(
         &self,
-        input: DeleteEventSubscriptionMessage,
-    ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_subnet_group_request(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteEventSubscription");
+        params.put("Action", "DeleteDBSubnetGroup");
         params.put("Version", "2014-10-31");
-        DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_event_subscription`, also returning the metadata of the response.
+    pub async fn delete_event_subscription_with_metadata(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<
+        (DeleteEventSubscriptionResult, ResponseMetadata),
+        RusotoError<DeleteEventSubscriptionError>,
+    > {
+        let request = self.build_delete_event_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteEventSubscriptionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13998,37 +15174,66 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p> Returns a list of <code>DBClusterParameterGroup</code> descriptions. If a <code>DBClusterParameterGroupName</code> parameter is specified, the list will contain only the description of the specified DB cluster parameter group.</p>
-    async fn describe_db_cluster_parameter_groups(
+    /// Presigns the request `delete_event_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_event_subscription(
         &self,
-        input: DescribeDBClusterParameterGroupsMessage,
-    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_event_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_event_subscription_request(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusterParameterGroups");
+        params.put("Action", "DeleteEventSubscription");
         params.put("Version", "2014-10-31");
-        DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_cluster_parameter_groups`, also returning the metadata of the response.
+    pub async fn describe_db_cluster_parameter_groups_with_metadata(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<
+        (DBClusterParameterGroupsMessage, ResponseMetadata),
+        RusotoError<DescribeDBClusterParameterGroupsError>,
+    > {
+        let request = self.build_describe_db_cluster_parameter_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClusterParameterGroupsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14050,34 +15255,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the detailed parameter list for a particular DB cluster parameter group.</p>
-    async fn describe_db_cluster_parameters(
+    /// Presigns the request `describe_db_cluster_parameter_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_cluster_parameter_groups(
         &self,
-        input: DescribeDBClusterParametersMessage,
-    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_cluster_parameter_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_cluster_parameter_groups_request(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameterGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusterParameters");
+        params.put("Action", "DescribeDBClusterParameterGroups");
         params.put("Version", "2014-10-31");
-        DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_cluster_parameters`, also returning the metadata of the response.
+    pub async fn describe_db_cluster_parameters_with_metadata(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<
+        (DBClusterParameterGroupDetails, ResponseMetadata),
+        RusotoError<DescribeDBClusterParametersError>,
+    > {
+        let request = self.build_describe_db_cluster_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClusterParametersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14099,39 +15334,66 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of DB cluster snapshot attribute names and values for a manual DB cluster snapshot.</p> <p>When sharing snapshots with other AWS accounts, <code>DescribeDBClusterSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual DB cluster snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual DB cluster snapshot is public and can be copied or restored by all AWS accounts.</p> <p>To add or remove access for an AWS account to copy or restore a manual DB cluster snapshot, or to make the manual DB cluster snapshot public or private, use the <a>ModifyDBClusterSnapshotAttribute</a> API action.</p>
-    async fn describe_db_cluster_snapshot_attributes(
+    /// Presigns the request `describe_db_cluster_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_cluster_parameters(
         &self,
-        input: DescribeDBClusterSnapshotAttributesMessage,
-    ) -> Result<
-        DescribeDBClusterSnapshotAttributesResult,
-        RusotoError<DescribeDBClusterSnapshotAttributesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_cluster_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_cluster_parameters_request(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusterSnapshotAttributes");
+        params.put("Action", "DescribeDBClusterParameters");
         params.put("Version", "2014-10-31");
-        DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_cluster_snapshot_attributes`, also returning the metadata of the response.
+    pub async fn describe_db_cluster_snapshot_attributes_with_metadata(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        (DescribeDBClusterSnapshotAttributesResult, ResponseMetadata),
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        let request = self.build_describe_db_cluster_snapshot_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClusterSnapshotAttributesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14153,34 +15415,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about DB cluster snapshots. This API action supports pagination.</p>
-    async fn describe_db_cluster_snapshots(
+    /// Presigns the request `describe_db_cluster_snapshot_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_cluster_snapshot_attributes(
         &self,
-        input: DescribeDBClusterSnapshotsMessage,
-    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_cluster_snapshot_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_cluster_snapshot_attributes_request(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshotAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusterSnapshots");
+        params.put("Action", "DescribeDBClusterSnapshotAttributes");
         params.put("Version", "2014-10-31");
-        DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_cluster_snapshots`, also returning the metadata of the response.
+    pub async fn describe_db_cluster_snapshots_with_metadata(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<
+        (DBClusterSnapshotMessage, ResponseMetadata),
+        RusotoError<DescribeDBClusterSnapshotsError>,
+    > {
+        let request = self.build_describe_db_cluster_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClusterSnapshotsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14202,34 +15494,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about provisioned DB clusters. This API supports pagination.</p>
-    async fn describe_db_clusters(
+    /// Presigns the request `describe_db_cluster_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_cluster_snapshots(
         &self,
-        input: DescribeDBClustersMessage,
-    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_cluster_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_cluster_snapshots_request(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshots");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusters");
+        params.put("Action", "DescribeDBClusterSnapshots");
         params.put("Version", "2014-10-31");
-        DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_clusters`, also returning the metadata of the response.
+    pub async fn describe_db_clusters_with_metadata(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<(DBClusterMessage, ResponseMetadata), RusotoError<DescribeDBClustersError>> {
+        let request = self.build_describe_db_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClustersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14249,34 +15568,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of the available DB engines.</p>
-    async fn describe_db_engine_versions(
+    /// Presigns the request `describe_db_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_clusters(
         &self,
-        input: DescribeDBEngineVersionsMessage,
-    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClustersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_clusters_request(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBEngineVersions");
+        params.put("Action", "DescribeDBClusters");
         params.put("Version", "2014-10-31");
-        DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_engine_versions`, also returning the metadata of the response.
+    pub async fn describe_db_engine_versions_with_metadata(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<
+        (DBEngineVersionMessage, ResponseMetadata),
+        RusotoError<DescribeDBEngineVersionsError>,
+    > {
+        let request = self.build_describe_db_engine_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBEngineVersionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14298,34 +15647,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about provisioned instances. This API supports pagination.</p>
-    async fn describe_db_instances(
+    /// Presigns the request `describe_db_engine_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_engine_versions(
         &self,
-        input: DescribeDBInstancesMessage,
-    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_engine_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_engine_versions_request(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBEngineVersions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBInstances");
+        params.put("Action", "DescribeDBEngineVersions");
         params.put("Version", "2014-10-31");
-        DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_instances`, also returning the metadata of the response.
+    pub async fn describe_db_instances_with_metadata(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(DBInstanceMessage, ResponseMetadata), RusotoError<DescribeDBInstancesError>> {
+        let request = self.build_describe_db_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBInstancesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14347,34 +15723,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of <code>DBParameterGroup</code> descriptions. If a <code>DBParameterGroupName</code> is specified, the list will contain only the description of the specified DB parameter group.</p>
-    async fn describe_db_parameter_groups(
+    /// Presigns the request `describe_db_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_instances(
         &self,
-        input: DescribeDBParameterGroupsMessage,
-    ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_instances_request(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstances");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBParameterGroups");
+        params.put("Action", "DescribeDBInstances");
         params.put("Version", "2014-10-31");
-        DescribeDBParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_parameter_groups`, also returning the metadata of the response.
+    pub async fn describe_db_parameter_groups_with_metadata(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> Result<
+        (DBParameterGroupsMessage, ResponseMetadata),
+        RusotoError<DescribeDBParameterGroupsError>,
+    > {
+        let request = self.build_describe_db_parameter_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBParameterGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14396,34 +15802,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the detailed parameter list for a particular DB parameter group.</p>
-    async fn describe_db_parameters(
+    /// Presigns the request `describe_db_parameter_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_parameter_groups(
         &self,
-        input: DescribeDBParametersMessage,
-    ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_parameter_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_parameter_groups_request(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameterGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBParameters");
+        params.put("Action", "DescribeDBParameterGroups");
         params.put("Version", "2014-10-31");
-        DescribeDBParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_parameters`, also returning the metadata of the response.
+    pub async fn describe_db_parameters_with_metadata(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> Result<(DBParameterGroupDetails, ResponseMetadata), RusotoError<DescribeDBParametersError>>
+    {
+        let request = self.build_describe_db_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBParametersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14445,34 +15879,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of DBSubnetGroup descriptions. If a DBSubnetGroupName is specified, the list will contain only the descriptions of the specified DBSubnetGroup.</p> <p>For an overview of CIDR ranges, go to the <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Wikipedia Tutorial</a>.</p>
-    async fn describe_db_subnet_groups(
+    /// Presigns the request `describe_db_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_parameters(
         &self,
-        input: DescribeDBSubnetGroupsMessage,
-    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_parameters_request(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBSubnetGroups");
+        params.put("Action", "DescribeDBParameters");
         params.put("Version", "2014-10-31");
-        DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_subnet_groups`, also returning the metadata of the response.
+    pub async fn describe_db_subnet_groups_with_metadata(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<(DBSubnetGroupMessage, ResponseMetadata), RusotoError<DescribeDBSubnetGroupsError>>
+    {
+        let request = self.build_describe_db_subnet_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBSubnetGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14494,39 +15956,69 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the default engine and system parameter information for the cluster database engine.</p>
-    async fn describe_engine_default_cluster_parameters(
+    /// Presigns the request `describe_db_subnet_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_subnet_groups(
         &self,
-        input: DescribeEngineDefaultClusterParametersMessage,
-    ) -> Result<
-        DescribeEngineDefaultClusterParametersResult,
-        RusotoError<DescribeEngineDefaultClusterParametersError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_subnet_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_subnet_groups_request(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSubnetGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEngineDefaultClusterParameters");
+        params.put("Action", "DescribeDBSubnetGroups");
         params.put("Version", "2014-10-31");
-        DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_engine_default_cluster_parameters`, also returning the metadata of the response.
+    pub async fn describe_engine_default_cluster_parameters_with_metadata(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        (
+            DescribeEngineDefaultClusterParametersResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        let request = self.build_describe_engine_default_cluster_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEngineDefaultClusterParametersError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14548,39 +16040,66 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the default engine and system parameter information for the specified database engine.</p>
-    async fn describe_engine_default_parameters(
+    /// Presigns the request `describe_engine_default_cluster_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_engine_default_cluster_parameters(
         &self,
-        input: DescribeEngineDefaultParametersMessage,
-    ) -> Result<
-        DescribeEngineDefaultParametersResult,
-        RusotoError<DescribeEngineDefaultParametersError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_engine_default_cluster_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_engine_default_cluster_parameters_request(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultClusterParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEngineDefaultParameters");
+        params.put("Action", "DescribeEngineDefaultClusterParameters");
         params.put("Version", "2014-10-31");
-        DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_engine_default_parameters`, also returning the metadata of the response.
+    pub async fn describe_engine_default_parameters_with_metadata(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        (DescribeEngineDefaultParametersResult, ResponseMetadata),
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        let request = self.build_describe_engine_default_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEngineDefaultParametersError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14602,34 +16121,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Displays a list of categories for all event source types, or, if specified, for a specified source type.</p>
-    async fn describe_event_categories(
+    /// Presigns the request `describe_engine_default_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_engine_default_parameters(
         &self,
-        input: DescribeEventCategoriesMessage,
-    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_engine_default_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_engine_default_parameters_request(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEventCategories");
+        params.put("Action", "DescribeEngineDefaultParameters");
         params.put("Version", "2014-10-31");
-        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_event_categories`, also returning the metadata of the response.
+    pub async fn describe_event_categories_with_metadata(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<(EventCategoriesMessage, ResponseMetadata), RusotoError<DescribeEventCategoriesError>>
+    {
+        let request = self.build_describe_event_categories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventCategoriesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14651,34 +16198,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists all the subscription descriptions for a customer account. The description for a subscription includes SubscriptionName, SNSTopicARN, CustomerID, SourceType, SourceID, CreationTime, and Status.</p> <p>If you specify a SubscriptionName, lists the description for that subscription.</p>
-    async fn describe_event_subscriptions(
+    /// Presigns the request `describe_event_categories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_categories(
         &self,
-        input: DescribeEventSubscriptionsMessage,
-    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_categories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_categories_request(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEventSubscriptions");
+        params.put("Action", "DescribeEventCategories");
         params.put("Version", "2014-10-31");
-        DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_event_subscriptions`, also returning the metadata of the response.
+    pub async fn describe_event_subscriptions_with_metadata(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<
+        (EventSubscriptionsMessage, ResponseMetadata),
+        RusotoError<DescribeEventSubscriptionsError>,
+    > {
+        let request = self.build_describe_event_subscriptions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventSubscriptionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14700,34 +16277,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns events related to DB instances, DB security groups, DB snapshots, and DB parameter groups for the past 14 days. Events specific to a particular DB instance, DB security group, database snapshot, or DB parameter group can be obtained by providing the name as a parameter. By default, the past hour of events are returned.</p>
-    async fn describe_events(
+    /// Presigns the request `describe_event_subscriptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_subscriptions(
         &self,
-        input: DescribeEventsMessage,
-    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_subscriptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_subscriptions_request(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEvents");
+        params.put("Action", "DescribeEventSubscriptions");
         params.put("Version", "2014-10-31");
-        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_events`, also returning the metadata of the response.
+    pub async fn describe_events_with_metadata(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<(EventsMessage, ResponseMetadata), RusotoError<DescribeEventsError>> {
+        let request = self.build_describe_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14746,39 +16350,63 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of orderable DB instance options for the specified engine.</p>
-    async fn describe_orderable_db_instance_options(
+    /// Presigns the request `describe_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events(
         &self,
-        input: DescribeOrderableDBInstanceOptionsMessage,
-    ) -> Result<
-        OrderableDBInstanceOptionsMessage,
-        RusotoError<DescribeOrderableDBInstanceOptionsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_request(&self, input: DescribeEventsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeOrderableDBInstanceOptions");
+        params.put("Action", "DescribeEvents");
         params.put("Version", "2014-10-31");
-        DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_orderable_db_instance_options`, also returning the metadata of the response.
+    pub async fn describe_orderable_db_instance_options_with_metadata(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        (OrderableDBInstanceOptionsMessage, ResponseMetadata),
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        let request = self.build_describe_orderable_db_instance_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeOrderableDBInstanceOptionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14800,37 +16428,66 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of resources (for example, DB instances) that have at least one pending maintenance action.</p>
-    async fn describe_pending_maintenance_actions(
+    /// Presigns the request `describe_orderable_db_instance_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_orderable_db_instance_options(
         &self,
-        input: DescribePendingMaintenanceActionsMessage,
-    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_orderable_db_instance_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_orderable_db_instance_options_request(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOrderableDBInstanceOptions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribePendingMaintenanceActions");
+        params.put("Action", "DescribeOrderableDBInstanceOptions");
         params.put("Version", "2014-10-31");
-        DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_pending_maintenance_actions`, also returning the metadata of the response.
+    pub async fn describe_pending_maintenance_actions_with_metadata(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<
+        (PendingMaintenanceActionsMessage, ResponseMetadata),
+        RusotoError<DescribePendingMaintenanceActionsError>,
+    > {
+        let request = self.build_describe_pending_maintenance_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribePendingMaintenanceActionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14852,39 +16509,66 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>You can call <a>DescribeValidDBInstanceModifications</a> to learn what modifications you can make to your DB instance. You can use this information when you call <a>ModifyDBInstance</a>.</p>
-    async fn describe_valid_db_instance_modifications(
+    /// Presigns the request `describe_pending_maintenance_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_pending_maintenance_actions(
         &self,
-        input: DescribeValidDBInstanceModificationsMessage,
-    ) -> Result<
-        DescribeValidDBInstanceModificationsResult,
-        RusotoError<DescribeValidDBInstanceModificationsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_pending_maintenance_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_pending_maintenance_actions_request(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeValidDBInstanceModifications");
+        params.put("Action", "DescribePendingMaintenanceActions");
         params.put("Version", "2014-10-31");
-        DescribeValidDBInstanceModificationsMessageSerializer::serialize(&mut params, "", &input);
+        DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_valid_db_instance_modifications`, also returning the metadata of the response.
+    pub async fn describe_valid_db_instance_modifications_with_metadata(
+        &self,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> Result<
+        (DescribeValidDBInstanceModificationsResult, ResponseMetadata),
+        RusotoError<DescribeValidDBInstanceModificationsError>,
+    > {
+        let request = self.build_describe_valid_db_instance_modifications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeValidDBInstanceModificationsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14906,34 +16590,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Forces a failover for a DB cluster.</p> <p>A failover for a DB cluster promotes one of the Read Replicas (read-only instances) in the DB cluster to be the primary instance (the cluster writer).</p> <p>Amazon Neptune will automatically fail over to a Read Replica, if one exists, when the primary instance fails. You can force a failover when you want to simulate a failure of a primary instance for testing. Because each instance in a DB cluster has its own endpoint address, you will need to clean up and re-establish any existing connections that use those endpoint addresses when the failover is complete.</p>
-    async fn failover_db_cluster(
+    /// Presigns the request `describe_valid_db_instance_modifications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_valid_db_instance_modifications(
         &self,
-        input: FailoverDBClusterMessage,
-    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_valid_db_instance_modifications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_valid_db_instance_modifications_request(
+        &self,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeValidDBInstanceModifications");
         let mut params = Params::new();
 
-        params.put("Action", "FailoverDBCluster");
+        params.put("Action", "DescribeValidDBInstanceModifications");
         params.put("Version", "2014-10-31");
-        FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        DescribeValidDBInstanceModificationsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `failover_db_cluster`, also returning the metadata of the response.
+    pub async fn failover_db_cluster_with_metadata(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<(FailoverDBClusterResult, ResponseMetadata), RusotoError<FailoverDBClusterError>>
+    {
+        let request = self.build_failover_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(FailoverDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14955,34 +16667,58 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists all tags on an Amazon Neptune resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `failover_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_failover_db_cluster(
         &self,
-        input: ListTagsForResourceMessage,
-    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: FailoverDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_failover_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_failover_db_cluster_request(&self, input: FailoverDBClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("FailoverDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "ListTagsForResource");
+        params.put("Action", "FailoverDBCluster");
         params.put("Version", "2014-10-31");
-        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
+        FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<(TagListMessage, ResponseMetadata), RusotoError<ListTagsForResourceError>> {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTagsForResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15002,34 +16738,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modify a setting for a DB cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request.</p>
-    async fn modify_db_cluster(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
-        input: ModifyDBClusterMessage,
-    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        params.put("Action", "ModifyDBCluster");
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+        let mut params = Params::new();
+
+        params.put("Action", "ListTagsForResource");
         params.put("Version", "2014-10-31");
-        ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_cluster`, also returning the metadata of the response.
+    pub async fn modify_db_cluster_with_metadata(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<(ModifyDBClusterResult, ResponseMetadata), RusotoError<ModifyDBClusterError>> {
+        let request = self.build_modify_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15051,35 +16814,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p> Modifies the parameters of a DB cluster parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request.</p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot without failover to the DB cluster associated with the parameter group before the change can take effect.</p> </note> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon Neptune to fully complete the create action before the parameter group is used as the default for a new DB cluster. This is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the Amazon Neptune console or the <a>DescribeDBClusterParameters</a> command to verify that your DB cluster parameter group has been created or modified.</p> </important></p>
-    async fn modify_db_cluster_parameter_group(
+    /// Presigns the request `modify_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_cluster(
         &self,
-        input: ModifyDBClusterParameterGroupMessage,
-    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_cluster_request(&self, input: ModifyDBClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBClusterParameterGroup");
+        params.put("Action", "ModifyDBCluster");
         params.put("Version", "2014-10-31");
-        ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn modify_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<
+        (DBClusterParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ModifyDBClusterParameterGroupError>,
+    > {
+        let request = self.build_modify_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15101,39 +16890,66 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds an attribute and values to, or removes an attribute and values from, a manual DB cluster snapshot.</p> <p>To share a manual DB cluster snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code> and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual DB cluster snapshot. Use the value <code>all</code> to make the manual DB cluster snapshot public, which means that it can be copied or restored by all AWS accounts. Do not add the <code>all</code> value for any manual DB cluster snapshots that contain private information that you don't want available to all AWS accounts. If a manual DB cluster snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can't use <code>all</code> as a value for that parameter in this case.</p> <p>To view which AWS accounts have access to copy or restore a manual DB cluster snapshot, or whether a manual DB cluster snapshot public or private, use the <a>DescribeDBClusterSnapshotAttributes</a> API action.</p>
-    async fn modify_db_cluster_snapshot_attribute(
+    /// Presigns the request `modify_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_cluster_parameter_group(
         &self,
-        input: ModifyDBClusterSnapshotAttributeMessage,
-    ) -> Result<
-        ModifyDBClusterSnapshotAttributeResult,
-        RusotoError<ModifyDBClusterSnapshotAttributeError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_cluster_parameter_group_request(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBClusterSnapshotAttribute");
+        params.put("Action", "ModifyDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_cluster_snapshot_attribute`, also returning the metadata of the response.
+    pub async fn modify_db_cluster_snapshot_attribute_with_metadata(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        (ModifyDBClusterSnapshotAttributeResult, ResponseMetadata),
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        let request = self.build_modify_db_cluster_snapshot_attribute_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBClusterSnapshotAttributeError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15155,34 +16971,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies settings for a DB instance. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. To learn what modifications you can make to your DB instance, call <a>DescribeValidDBInstanceModifications</a> before you call <a>ModifyDBInstance</a>.</p>
-    async fn modify_db_instance(
+    /// Presigns the request `modify_db_cluster_snapshot_attribute` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_cluster_snapshot_attribute(
         &self,
-        input: ModifyDBInstanceMessage,
-    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_cluster_snapshot_attribute_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_cluster_snapshot_attribute_request(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterSnapshotAttribute");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBInstance");
+        params.put("Action", "ModifyDBClusterSnapshotAttribute");
         params.put("Version", "2014-10-31");
-        ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_instance`, also returning the metadata of the response.
+    pub async fn modify_db_instance_with_metadata(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<(ModifyDBInstanceResult, ResponseMetadata), RusotoError<ModifyDBInstanceError>>
+    {
+        let request = self.build_modify_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15204,34 +17048,61 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Modifies the parameters of a DB parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request.</p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot without failover to the DB instance associated with the parameter group before the change can take effect.</p> </note> <important> <p>After you modify a DB parameter group, you should wait at least 5 minutes before creating your first DB instance that uses that DB parameter group as the default parameter group. This allows Amazon Neptune to fully complete the modify action before the parameter group is used as the default for a new DB instance. This is especially important for parameters that are critical when creating the default database for a DB instance, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the Amazon Neptune console or the <i>DescribeDBParameters</i> command to verify that your DB parameter group has been created or modified.</p> </important></p>
-    async fn modify_db_parameter_group(
+    /// Presigns the request `modify_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_instance(
         &self,
-        input: ModifyDBParameterGroupMessage,
-    ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_instance_request(&self, input: ModifyDBInstanceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBInstance");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBParameterGroup");
+        params.put("Action", "ModifyDBInstance");
         params.put("Version", "2014-10-31");
-        ModifyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_parameter_group`, also returning the metadata of the response.
+    pub async fn modify_db_parameter_group_with_metadata(
+        &self,
+        input: ModifyDBParameterGroupMessage,
+    ) -> Result<
+        (DBParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ModifyDBParameterGroupError>,
+    > {
+        let request = self.build_modify_db_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15253,34 +17124,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies an existing DB subnet group. DB subnet groups must contain at least one subnet in at least two AZs in the AWS Region.</p>
-    async fn modify_db_subnet_group(
+    /// Presigns the request `modify_db_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_parameter_group(
         &self,
-        input: ModifyDBSubnetGroupMessage,
-    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_parameter_group_request(
+        &self,
+        input: ModifyDBParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBSubnetGroup");
+        params.put("Action", "ModifyDBParameterGroup");
         params.put("Version", "2014-10-31");
-        ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_subnet_group`, also returning the metadata of the response.
+    pub async fn modify_db_subnet_group_with_metadata(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<(ModifyDBSubnetGroupResult, ResponseMetadata), RusotoError<ModifyDBSubnetGroupError>>
+    {
+        let request = self.build_modify_db_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBSubnetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15302,34 +17201,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies an existing event notification subscription. Note that you can't modify the source identifiers using this call; to change source identifiers for a subscription, use the <a>AddSourceIdentifierToSubscription</a> and <a>RemoveSourceIdentifierFromSubscription</a> calls.</p> <p>You can see a list of the event categories for a given SourceType by using the <b>DescribeEventCategories</b> action.</p>
-    async fn modify_event_subscription(
+    /// Presigns the request `modify_db_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_subnet_group(
         &self,
-        input: ModifyEventSubscriptionMessage,
-    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_subnet_group_request(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyEventSubscription");
+        params.put("Action", "ModifyDBSubnetGroup");
         params.put("Version", "2014-10-31");
-        ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_event_subscription`, also returning the metadata of the response.
+    pub async fn modify_event_subscription_with_metadata(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<
+        (ModifyEventSubscriptionResult, ResponseMetadata),
+        RusotoError<ModifyEventSubscriptionError>,
+    > {
+        let request = self.build_modify_event_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyEventSubscriptionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15351,35 +17280,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Not supported.</p>
-    async fn promote_read_replica_db_cluster(
+    /// Presigns the request `modify_event_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_event_subscription(
         &self,
-        input: PromoteReadReplicaDBClusterMessage,
-    ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_event_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_event_subscription_request(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
         let mut params = Params::new();
 
-        params.put("Action", "PromoteReadReplicaDBCluster");
+        params.put("Action", "ModifyEventSubscription");
         params.put("Version", "2014-10-31");
-        PromoteReadReplicaDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `promote_read_replica_db_cluster`, also returning the metadata of the response.
+    pub async fn promote_read_replica_db_cluster_with_metadata(
+        &self,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> Result<
+        (PromoteReadReplicaDBClusterResult, ResponseMetadata),
+        RusotoError<PromoteReadReplicaDBClusterError>,
+    > {
+        let request = self.build_promote_read_replica_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PromoteReadReplicaDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15401,34 +17359,62 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>You might need to reboot your DB instance, usually for maintenance reasons. For example, if you make certain modifications, or if you change the DB parameter group associated with the DB instance, you must reboot the instance for the changes to take effect.</p> <p>Rebooting a DB instance restarts the database engine service. Rebooting a DB instance results in a momentary outage, during which the DB instance status is set to rebooting.</p>
-    async fn reboot_db_instance(
+    /// Presigns the request `promote_read_replica_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_promote_read_replica_db_cluster(
         &self,
-        input: RebootDBInstanceMessage,
-    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_promote_read_replica_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_promote_read_replica_db_cluster_request(
+        &self,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("PromoteReadReplicaDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "RebootDBInstance");
+        params.put("Action", "PromoteReadReplicaDBCluster");
         params.put("Version", "2014-10-31");
-        RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        PromoteReadReplicaDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reboot_db_instance`, also returning the metadata of the response.
+    pub async fn reboot_db_instance_with_metadata(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<(RebootDBInstanceResult, ResponseMetadata), RusotoError<RebootDBInstanceError>>
+    {
+        let request = self.build_reboot_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RebootDBInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15450,67 +17436,118 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Disassociates an Identity and Access Management (IAM) role from a DB cluster.</p>
-    async fn remove_role_from_db_cluster(
+    /// Presigns the request `reboot_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_db_instance(
         &self,
-        input: RemoveRoleFromDBClusterMessage,
-    ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RebootDBInstanceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_db_instance_request(&self, input: RebootDBInstanceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RebootDBInstance");
         let mut params = Params::new();
 
-        params.put("Action", "RemoveRoleFromDBCluster");
+        params.put("Action", "RebootDBInstance");
         params.put("Version", "2014-10-31");
-        RemoveRoleFromDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_role_from_db_cluster`, also returning the metadata of the response.
+    pub async fn remove_role_from_db_cluster_with_metadata(
+        &self,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveRoleFromDBClusterError>> {
+        let request = self.build_remove_role_from_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemoveRoleFromDBClusterError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Removes a source identifier from an existing event notification subscription.</p>
-    async fn remove_source_identifier_from_subscription(
+    /// Presigns the request `remove_role_from_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_role_from_db_cluster(
         &self,
-        input: RemoveSourceIdentifierFromSubscriptionMessage,
-    ) -> Result<
-        RemoveSourceIdentifierFromSubscriptionResult,
-        RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_role_from_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_role_from_db_cluster_request(
+        &self,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveRoleFromDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "RemoveSourceIdentifierFromSubscription");
+        params.put("Action", "RemoveRoleFromDBCluster");
         params.put("Version", "2014-10-31");
-        RemoveSourceIdentifierFromSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        RemoveRoleFromDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_source_identifier_from_subscription`, also returning the metadata of the response.
+    pub async fn remove_source_identifier_from_subscription_with_metadata(
+        &self,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> Result<
+        (
+            RemoveSourceIdentifierFromSubscriptionResult,
+            ResponseMetadata,
+        ),
+        RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
+    > {
+        let request = self.build_remove_source_identifier_from_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemoveSourceIdentifierFromSubscriptionError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15532,63 +17569,116 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes metadata tags from an Amazon Neptune resource.</p>
-    async fn remove_tags_from_resource(
+    /// Presigns the request `remove_source_identifier_from_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_source_identifier_from_subscription(
         &self,
-        input: RemoveTagsFromResourceMessage,
-    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_source_identifier_from_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_source_identifier_from_subscription_request(
+        &self,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveSourceIdentifierFromSubscription");
         let mut params = Params::new();
 
-        params.put("Action", "RemoveTagsFromResource");
+        params.put("Action", "RemoveSourceIdentifierFromSubscription");
         params.put("Version", "2014-10-31");
-        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
+        RemoveSourceIdentifierFromSubscriptionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_tags_from_resource`, also returning the metadata of the response.
+    pub async fn remove_tags_from_resource_with_metadata(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveTagsFromResourceError>> {
+        let request = self.build_remove_tags_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemoveTagsFromResourceError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p> Modifies the parameters of a DB cluster parameter group to the default value. To reset specific parameters submit a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB cluster parameter group, specify the <code>DBClusterParameterGroupName</code> and <code>ResetAllParameters</code> parameters.</p> <p> When resetting the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance restart or <a>RebootDBInstance</a> request. You must call <a>RebootDBInstance</a> for every DB instance in your DB cluster that you want the updated static parameter to apply to.</p>
-    async fn reset_db_cluster_parameter_group(
+    /// Presigns the request `remove_tags_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_resource(
         &self,
-        input: ResetDBClusterParameterGroupMessage,
-    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_resource_request(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let mut params = Params::new();
 
-        params.put("Action", "ResetDBClusterParameterGroup");
+        params.put("Action", "RemoveTagsFromResource");
         params.put("Version", "2014-10-31");
-        ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reset_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn reset_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<
+        (DBClusterParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ResetDBClusterParameterGroupError>,
+    > {
+        let request = self.build_reset_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ResetDBClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15610,34 +17700,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the parameters of a DB parameter group to the engine/system default value. To reset specific parameters, provide a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB parameter group, specify the <code>DBParameterGroup</code> name and <code>ResetAllParameters</code> parameters. When resetting the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance restart or <code>RebootDBInstance</code> request.</p>
-    async fn reset_db_parameter_group(
+    /// Presigns the request `reset_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_db_cluster_parameter_group(
         &self,
-        input: ResetDBParameterGroupMessage,
-    ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_db_cluster_parameter_group_request(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ResetDBParameterGroup");
+        params.put("Action", "ResetDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        ResetDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reset_db_parameter_group`, also returning the metadata of the response.
+    pub async fn reset_db_parameter_group_with_metadata(
+        &self,
+        input: ResetDBParameterGroupMessage,
+    ) -> Result<
+        (DBParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ResetDBParameterGroupError>,
+    > {
+        let request = self.build_reset_db_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ResetDBParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15659,35 +17779,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new DB cluster from a DB snapshot or DB cluster snapshot.</p> <p>If a DB snapshot is specified, the target DB cluster is created from the source DB snapshot with a default configuration and default security group.</p> <p>If a DB cluster snapshot is specified, the target DB cluster is created from the source DB cluster restore point with the same configuration as the original source DB cluster, except that the new DB cluster is created with the default security group.</p>
-    async fn restore_db_cluster_from_snapshot(
+    /// Presigns the request `reset_db_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_db_parameter_group(
         &self,
-        input: RestoreDBClusterFromSnapshotMessage,
-    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ResetDBParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_db_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_db_parameter_group_request(
+        &self,
+        input: ResetDBParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "RestoreDBClusterFromSnapshot");
+        params.put("Action", "ResetDBParameterGroup");
         params.put("Version", "2014-10-31");
-        RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        ResetDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `restore_db_cluster_from_snapshot`, also returning the metadata of the response.
+    pub async fn restore_db_cluster_from_snapshot_with_metadata(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<
+        (RestoreDBClusterFromSnapshotResult, ResponseMetadata),
+        RusotoError<RestoreDBClusterFromSnapshotError>,
+    > {
+        let request = self.build_restore_db_cluster_from_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RestoreDBClusterFromSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15709,35 +17858,64 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Restores a DB cluster to an arbitrary point in time. Users can restore to any point in time before <code>LatestRestorableTime</code> for up to <code>BackupRetentionPeriod</code> days. The target DB cluster is created from the source DB cluster with the same configuration as the original DB cluster, except that the new DB cluster is created with the default DB security group.</p> <note> <p>This action only restores the DB cluster, not the DB instances for that DB cluster. You must invoke the <a>CreateDBInstance</a> action to create DB instances for the restored DB cluster, specifying the identifier of the restored DB cluster in <code>DBClusterIdentifier</code>. You can create DB instances only after the <code>RestoreDBClusterToPointInTime</code> action has completed and the DB cluster is available.</p> </note></p>
-    async fn restore_db_cluster_to_point_in_time(
+    /// Presigns the request `restore_db_cluster_from_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_db_cluster_from_snapshot(
         &self,
-        input: RestoreDBClusterToPointInTimeMessage,
-    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_db_cluster_from_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_db_cluster_from_snapshot_request(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "RestoreDBClusterToPointInTime");
+        params.put("Action", "RestoreDBClusterFromSnapshot");
         params.put("Version", "2014-10-31");
-        RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
+        RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `restore_db_cluster_to_point_in_time`, also returning the metadata of the response.
+    pub async fn restore_db_cluster_to_point_in_time_with_metadata(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<
+        (RestoreDBClusterToPointInTimeResult, ResponseMetadata),
+        RusotoError<RestoreDBClusterToPointInTimeError>,
+    > {
+        let request = self.build_restore_db_cluster_to_point_in_time_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RestoreDBClusterToPointInTimeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -15759,6 +17937,1342 @@ impl Neptune for NeptuneClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `restore_db_cluster_to_point_in_time` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_db_cluster_to_point_in_time(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_db_cluster_to_point_in_time_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_db_cluster_to_point_in_time_request(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterToPointInTime");
+        let mut params = Params::new();
+
+        params.put("Action", "RestoreDBClusterToPointInTime");
+        params.put("Version", "2014-10-31");
+        RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeDBClusterParameterGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_cluster_parameter_groups_pages(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DBClusterParameterGroupsMessage,
+            RusotoError<DescribeDBClusterParameterGroupsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move {
+                client.describe_db_cluster_parameter_groups(input).await
+            },
+            |input: &DescribeDBClusterParameterGroupsMessage,
+             output: &DBClusterParameterGroupsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBClusterParameterGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBClusterParameterGroups` of every page of `DescribeDBClusterParameterGroups` results for `input`, as `describe_db_cluster_parameter_groups_pages` requests them.
+    pub fn describe_db_cluster_parameter_groups_stream(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBClusterParameterGroup, RusotoError<DescribeDBClusterParameterGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_db_cluster_parameter_groups_pages(input),
+            |page| page.db_cluster_parameter_groups.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeDBClusterParameters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_cluster_parameters_pages(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DBClusterParameterGroupDetails,
+            RusotoError<DescribeDBClusterParametersError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move {
+                client.describe_db_cluster_parameters(input).await
+            },
+            |input: &DescribeDBClusterParametersMessage,
+             output: &DBClusterParameterGroupDetails| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBClusterParametersMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `DescribeDBClusterParameters` results for `input`, as `describe_db_cluster_parameters_pages` requests them.
+    pub fn describe_db_cluster_parameters_stream(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> impl futures::Stream<Item = Result<Parameter, RusotoError<DescribeDBClusterParametersError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_db_cluster_parameters_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBClusterSnapshots` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_cluster_snapshots_pages(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move {
+                client.describe_db_cluster_snapshots(input).await
+            },
+            |input: &DescribeDBClusterSnapshotsMessage, output: &DBClusterSnapshotMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBClusterSnapshotsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBClusterSnapshots` of every page of `DescribeDBClusterSnapshots` results for `input`, as `describe_db_cluster_snapshots_pages` requests them.
+    pub fn describe_db_cluster_snapshots_stream(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBClusterSnapshot, RusotoError<DescribeDBClusterSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_db_cluster_snapshots_pages(input), |page| {
+            page.db_cluster_snapshots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBClusters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_clusters_pages(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> impl futures::Stream<Item = Result<DBClusterMessage, RusotoError<DescribeDBClustersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move { client.describe_db_clusters(input).await },
+            |input: &DescribeDBClustersMessage, output: &DBClusterMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBClustersMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBClusters` of every page of `DescribeDBClusters` results for `input`, as `describe_db_clusters_pages` requests them.
+    pub fn describe_db_clusters_stream(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> impl futures::Stream<Item = Result<DBCluster, RusotoError<DescribeDBClustersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_db_clusters_pages(input), |page| {
+            page.db_clusters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBEngineVersions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_engine_versions_pages(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move {
+                client.describe_db_engine_versions(input).await
+            },
+            |input: &DescribeDBEngineVersionsMessage, output: &DBEngineVersionMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBEngineVersionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBEngineVersions` of every page of `DescribeDBEngineVersions` results for `input`, as `describe_db_engine_versions_pages` requests them.
+    pub fn describe_db_engine_versions_stream(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBEngineVersion, RusotoError<DescribeDBEngineVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_db_engine_versions_pages(input), |page| {
+            page.db_engine_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBInstances` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_instances_pages(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> impl futures::Stream<Item = Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move { client.describe_db_instances(input).await },
+            |input: &DescribeDBInstancesMessage, output: &DBInstanceMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBInstancesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBInstances` of every page of `DescribeDBInstances` results for `input`, as `describe_db_instances_pages` requests them.
+    pub fn describe_db_instances_stream(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> impl futures::Stream<Item = Result<DBInstance, RusotoError<DescribeDBInstancesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_db_instances_pages(input), |page| {
+            page.db_instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBParameterGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_parameter_groups_pages(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move {
+                client.describe_db_parameter_groups(input).await
+            },
+            |input: &DescribeDBParameterGroupsMessage, output: &DBParameterGroupsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBParameterGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBParameterGroups` of every page of `DescribeDBParameterGroups` results for `input`, as `describe_db_parameter_groups_pages` requests them.
+    pub fn describe_db_parameter_groups_stream(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBParameterGroup, RusotoError<DescribeDBParameterGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_db_parameter_groups_pages(input), |page| {
+            page.db_parameter_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBParameters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_parameters_pages(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move { client.describe_db_parameters(input).await },
+            |input: &DescribeDBParametersMessage, output: &DBParameterGroupDetails| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBParametersMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `DescribeDBParameters` results for `input`, as `describe_db_parameters_pages` requests them.
+    pub fn describe_db_parameters_stream(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> impl futures::Stream<Item = Result<Parameter, RusotoError<DescribeDBParametersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_db_parameters_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBSubnetGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_subnet_groups_pages(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move { client.describe_db_subnet_groups(input).await },
+            |input: &DescribeDBSubnetGroupsMessage, output: &DBSubnetGroupMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBSubnetGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBSubnetGroups` of every page of `DescribeDBSubnetGroups` results for `input`, as `describe_db_subnet_groups_pages` requests them.
+    pub fn describe_db_subnet_groups_stream(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> impl futures::Stream<Item = Result<DBSubnetGroup, RusotoError<DescribeDBSubnetGroupsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_db_subnet_groups_pages(input), |page| {
+            page.db_subnet_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEventSubscriptions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_event_subscriptions_pages(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move {
+                client.describe_event_subscriptions(input).await
+            },
+            |input: &DescribeEventSubscriptionsMessage, output: &EventSubscriptionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEventSubscriptionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EventSubscriptionsList` of every page of `DescribeEventSubscriptions` results for `input`, as `describe_event_subscriptions_pages` requests them.
+    pub fn describe_event_subscriptions_stream(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<EventSubscription, RusotoError<DescribeEventSubscriptionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_event_subscriptions_pages(input), |page| {
+            page.event_subscriptions_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEvents` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<EventsMessage, RusotoError<DescribeEventsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move { client.describe_events(input).await },
+            |input: &DescribeEventsMessage, output: &EventsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEventsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Events` of every page of `DescribeEvents` results for `input`, as `describe_events_pages` requests them.
+    pub fn describe_events_stream(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<Event, RusotoError<DescribeEventsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeOrderableDBInstanceOptions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_orderable_db_instance_options_pages(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrderableDBInstanceOptionsMessage,
+            RusotoError<DescribeOrderableDBInstanceOptionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move {
+                client.describe_orderable_db_instance_options(input).await
+            },
+            |input: &DescribeOrderableDBInstanceOptionsMessage,
+             output: &OrderableDBInstanceOptionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeOrderableDBInstanceOptionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrderableDBInstanceOptions` of every page of `DescribeOrderableDBInstanceOptions` results for `input`, as `describe_orderable_db_instance_options_pages` requests them.
+    pub fn describe_orderable_db_instance_options_stream(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrderableDBInstanceOption,
+            RusotoError<DescribeOrderableDBInstanceOptionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_orderable_db_instance_options_pages(input),
+            |page| page.orderable_db_instance_options.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribePendingMaintenanceActions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_pending_maintenance_actions_pages(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            PendingMaintenanceActionsMessage,
+            RusotoError<DescribePendingMaintenanceActionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NeptuneClient, input| async move {
+                client.describe_pending_maintenance_actions(input).await
+            },
+            |input: &DescribePendingMaintenanceActionsMessage,
+             output: &PendingMaintenanceActionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribePendingMaintenanceActionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PendingMaintenanceActions` of every page of `DescribePendingMaintenanceActions` results for `input`, as `describe_pending_maintenance_actions_pages` requests them.
+    pub fn describe_pending_maintenance_actions_stream(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ResourcePendingMaintenanceActions,
+            RusotoError<DescribePendingMaintenanceActionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_pending_maintenance_actions_pages(input),
+            |page| page.pending_maintenance_actions.unwrap_or_default(),
+        )
+    }
+
+    /// Waits for `DBInstanceAvailable`, polling `DescribeDBInstances` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_db_instance_available(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `DBInstanceAvailable` like `wait_until_db_instance_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_db_instance_available_with(
+        &self,
+        input: DescribeDBInstancesMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_db_instances(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.db_instances.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.db_instance_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "available")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "deleted"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "deleting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "incompatible-restore"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "incompatible-parameters"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `DBInstanceDeleted`, polling `DescribeDBInstances` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_db_instance_deleted(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_deleted_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `DBInstanceDeleted` like `wait_until_db_instance_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_db_instance_deleted_with(
+        &self,
+        input: DescribeDBInstancesMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_db_instances(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.db_instances.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.db_instance_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "deleted")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("DBInstanceNotFound")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "creating"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "modifying"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "rebooting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "resetting-master-credentials"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Neptune for NeptuneClient {
+    /// <p>Associates an Identity and Access Management (IAM) role from an Neptune DB cluster.</p>
+    async fn add_role_to_db_cluster(
+        &self,
+        input: AddRoleToDBClusterMessage,
+    ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
+        self.add_role_to_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a source identifier to an existing event notification subscription.</p>
+    async fn add_source_identifier_to_subscription(
+        &self,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> Result<
+        AddSourceIdentifierToSubscriptionResult,
+        RusotoError<AddSourceIdentifierToSubscriptionError>,
+    > {
+        self.add_source_identifier_to_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds metadata tags to an Amazon Neptune resource. These tags can also be used with cost allocation reporting to track cost associated with Amazon Neptune resources, or used in a Condition statement in an IAM policy for Amazon Neptune.</p>
+    async fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
+        self.add_tags_to_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Applies a pending maintenance action to a resource (for example, to a DB instance).</p>
+    async fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
+    {
+        self.apply_pending_maintenance_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Copies the specified DB cluster parameter group.</p>
+    async fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
+    {
+        self.copy_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Copies a snapshot of a DB cluster.</p> <p>To copy a DB cluster snapshot from a shared manual DB cluster snapshot, <code>SourceDBClusterSnapshotIdentifier</code> must be the Amazon Resource Name (ARN) of the shared DB cluster snapshot.</p> <p>You can't copy from one AWS Region to another.</p>
+    async fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        self.copy_db_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Copies the specified DB parameter group.</p>
+    async fn copy_db_parameter_group(
+        &self,
+        input: CopyDBParameterGroupMessage,
+    ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
+        self.copy_db_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Amazon Neptune DB cluster.</p> <p>You can use the <code>ReplicationSourceIdentifier</code> parameter to create the DB cluster as a Read Replica of another DB cluster or Amazon Neptune DB instance.</p>
+    async fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        self.create_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new DB cluster parameter group.</p> <p>Parameters in a DB cluster parameter group apply to all of the instances in a DB cluster.</p> <p> A DB cluster parameter group is initially created with the default parameters for the database engine used by instances in the DB cluster. To provide custom values for any of the parameters, you must modify the group after creating it using <a>ModifyDBClusterParameterGroup</a>. Once you&#39;ve created a DB cluster parameter group, you need to associate it with your DB cluster using <a>ModifyDBCluster</a>. When you associate a new DB cluster parameter group with a running DB cluster, you need to reboot the DB instances in the DB cluster without failover for the new DB cluster parameter group and associated settings to take effect.</p> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon Neptune to fully complete the create action before the DB cluster parameter group is used as the default for a new DB cluster. This is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the <a href="https://console.aws.amazon.com/rds/">Amazon Neptune console</a> or the <a>DescribeDBClusterParameters</a> command to verify that your DB cluster parameter group has been created or modified.</p> </important></p>
+    async fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
+    {
+        self.create_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a snapshot of a DB cluster.</p>
+    async fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        self.create_db_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new DB instance.</p>
+    async fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
+        self.create_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new DB parameter group.</p> <p>A DB parameter group is initially created with the default parameters for the database engine used by the DB instance. To provide custom values for any of the parameters, you must modify the group after creating it using <i>ModifyDBParameterGroup</i>. Once you&#39;ve created a DB parameter group, you need to associate it with your DB instance using <i>ModifyDBInstance</i>. When you associate a new DB parameter group with a running DB instance, you need to reboot the DB instance without failover for the new DB parameter group and associated settings to take effect.</p> <important> <p>After you create a DB parameter group, you should wait at least 5 minutes before creating your first DB instance that uses that DB parameter group as the default parameter group. This allows Amazon Neptune to fully complete the create action before the parameter group is used as the default for a new DB instance. This is especially important for parameters that are critical when creating the default database for a DB instance, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the Amazon Neptune console or the <i>DescribeDBParameters</i> command to verify that your DB parameter group has been created or modified.</p> </important></p>
+    async fn create_db_parameter_group(
+        &self,
+        input: CreateDBParameterGroupMessage,
+    ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
+        self.create_db_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new DB subnet group. DB subnet groups must contain at least one subnet in at least two AZs in the AWS Region.</p>
+    async fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        self.create_db_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an event notification subscription. This action requires a topic ARN (Amazon Resource Name) created by either the Neptune console, the SNS console, or the SNS API. To obtain an ARN with SNS, you must create a topic in Amazon SNS and subscribe to the topic. The ARN is displayed in the SNS console.</p> <p>You can specify the type of source (SourceType) you want to be notified of, provide a list of Neptune sources (SourceIds) that triggers the events, and provide a list of event categories (EventCategories) for events you want to be notified of. For example, you can specify SourceType = db-instance, SourceIds = mydbinstance1, mydbinstance2 and EventCategories = Availability, Backup.</p> <p>If you specify both the SourceType and SourceIds, such as SourceType = db-instance and SourceIdentifier = myDBInstance1, you are notified of all the db-instance events for the specified source. If you specify a SourceType but do not specify a SourceIdentifier, you receive notice of the events for that source type for all your Neptune sources. If you do not specify either the SourceType nor the SourceIdentifier, you are notified of events generated from all Neptune sources belonging to your customer account.</p>
+    async fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        self.create_event_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The DeleteDBCluster action deletes a previously provisioned DB cluster. When you delete a DB cluster, all automated backups for that DB cluster are deleted and can't be recovered. Manual DB cluster snapshots of the specified DB cluster are not deleted.</p>
+    async fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        self.delete_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified DB cluster parameter group. The DB cluster parameter group to be deleted can't be associated with any DB clusters.</p>
+    async fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        self.delete_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a DB cluster snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The DB cluster snapshot must be in the <code>available</code> state to be deleted.</p> </note></p>
+    async fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        self.delete_db_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The DeleteDBInstance action deletes a previously provisioned DB instance. When you delete a DB instance, all automated backups for that instance are deleted and can't be recovered. Manual DB snapshots of the DB instance to be deleted by <code>DeleteDBInstance</code> are not deleted.</p> <p> If you request a final DB snapshot the status of the Amazon Neptune DB instance is <code>deleting</code> until the DB snapshot is created. The API action <code>DescribeDBInstance</code> is used to monitor the status of this operation. The action can't be canceled or reverted once submitted.</p> <p>Note that when a DB instance is in a failure state and has a status of <code>failed</code>, <code>incompatible-restore</code>, or <code>incompatible-network</code>, you can only delete it when the <code>SkipFinalSnapshot</code> parameter is set to <code>true</code>.</p> <p>You can't delete a DB instance if it is the only instance in the DB cluster.</p>
+    async fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        self.delete_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified DBParameterGroup. The DBParameterGroup to be deleted can't be associated with any DB instances.</p>
+    async fn delete_db_parameter_group(
+        &self,
+        input: DeleteDBParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
+        self.delete_db_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a DB subnet group.</p> <note> <p>The specified database subnet group must not be associated with any DB instances.</p> </note></p>
+    async fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        self.delete_db_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an event notification subscription.</p>
+    async fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
+        self.delete_event_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of <code>DBClusterParameterGroup</code> descriptions. If a <code>DBClusterParameterGroupName</code> parameter is specified, the list will contain only the description of the specified DB cluster parameter group.</p>
+    async fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
+    {
+        self.describe_db_cluster_parameter_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the detailed parameter list for a particular DB cluster parameter group.</p>
+    async fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        self.describe_db_cluster_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of DB cluster snapshot attribute names and values for a manual DB cluster snapshot.</p> <p>When sharing snapshots with other AWS accounts, <code>DescribeDBClusterSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual DB cluster snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual DB cluster snapshot is public and can be copied or restored by all AWS accounts.</p> <p>To add or remove access for an AWS account to copy or restore a manual DB cluster snapshot, or to make the manual DB cluster snapshot public or private, use the <a>ModifyDBClusterSnapshotAttribute</a> API action.</p>
+    async fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        DescribeDBClusterSnapshotAttributesResult,
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        self.describe_db_cluster_snapshot_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about DB cluster snapshots. This API action supports pagination.</p>
+    async fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        self.describe_db_cluster_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about provisioned DB clusters. This API supports pagination.</p>
+    async fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        self.describe_db_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the available DB engines.</p>
+    async fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        self.describe_db_engine_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about provisioned instances. This API supports pagination.</p>
+    async fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        self.describe_db_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <code>DBParameterGroup</code> descriptions. If a <code>DBParameterGroupName</code> is specified, the list will contain only the description of the specified DB parameter group.</p>
+    async fn describe_db_parameter_groups(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
+        self.describe_db_parameter_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the detailed parameter list for a particular DB parameter group.</p>
+    async fn describe_db_parameters(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
+        self.describe_db_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of DBSubnetGroup descriptions. If a DBSubnetGroupName is specified, the list will contain only the descriptions of the specified DBSubnetGroup.</p> <p>For an overview of CIDR ranges, go to the <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Wikipedia Tutorial</a>.</p>
+    async fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        self.describe_db_subnet_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the default engine and system parameter information for the cluster database engine.</p>
+    async fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultClusterParametersResult,
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        self.describe_engine_default_cluster_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the default engine and system parameter information for the specified database engine.</p>
+    async fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultParametersResult,
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        self.describe_engine_default_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays a list of categories for all event source types, or, if specified, for a specified source type.</p>
+    async fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.describe_event_categories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the subscription descriptions for a customer account. The description for a subscription includes SubscriptionName, SNSTopicARN, CustomerID, SourceType, SourceID, CreationTime, and Status.</p> <p>If you specify a SubscriptionName, lists the description for that subscription.</p>
+    async fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        self.describe_event_subscriptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns events related to DB instances, DB security groups, DB snapshots, and DB parameter groups for the past 14 days. Events specific to a particular DB instance, DB security group, database snapshot, or DB parameter group can be obtained by providing the name as a parameter. By default, the past hour of events are returned.</p>
+    async fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.describe_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of orderable DB instance options for the specified engine.</p>
+    async fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        OrderableDBInstanceOptionsMessage,
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        self.describe_orderable_db_instance_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of resources (for example, DB instances) that have at least one pending maintenance action.</p>
+    async fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
+    {
+        self.describe_pending_maintenance_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>You can call <a>DescribeValidDBInstanceModifications</a> to learn what modifications you can make to your DB instance. You can use this information when you call <a>ModifyDBInstance</a>.</p>
+    async fn describe_valid_db_instance_modifications(
+        &self,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> Result<
+        DescribeValidDBInstanceModificationsResult,
+        RusotoError<DescribeValidDBInstanceModificationsError>,
+    > {
+        self.describe_valid_db_instance_modifications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Forces a failover for a DB cluster.</p> <p>A failover for a DB cluster promotes one of the Read Replicas (read-only instances) in the DB cluster to be the primary instance (the cluster writer).</p> <p>Amazon Neptune will automatically fail over to a Read Replica, if one exists, when the primary instance fails. You can force a failover when you want to simulate a failure of a primary instance for testing. Because each instance in a DB cluster has its own endpoint address, you will need to clean up and re-establish any existing connections that use those endpoint addresses when the failover is complete.</p>
+    async fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        self.failover_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags on an Amazon Neptune resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modify a setting for a DB cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request.</p>
+    async fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
+        self.modify_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Modifies the parameters of a DB cluster parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request.</p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot without failover to the DB cluster associated with the parameter group before the change can take effect.</p> </note> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon Neptune to fully complete the create action before the parameter group is used as the default for a new DB cluster. This is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the Amazon Neptune console or the <a>DescribeDBClusterParameters</a> command to verify that your DB cluster parameter group has been created or modified.</p> </important></p>
+    async fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
+    {
+        self.modify_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an attribute and values to, or removes an attribute and values from, a manual DB cluster snapshot.</p> <p>To share a manual DB cluster snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code> and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual DB cluster snapshot. Use the value <code>all</code> to make the manual DB cluster snapshot public, which means that it can be copied or restored by all AWS accounts. Do not add the <code>all</code> value for any manual DB cluster snapshots that contain private information that you don't want available to all AWS accounts. If a manual DB cluster snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can't use <code>all</code> as a value for that parameter in this case.</p> <p>To view which AWS accounts have access to copy or restore a manual DB cluster snapshot, or whether a manual DB cluster snapshot public or private, use the <a>DescribeDBClusterSnapshotAttributes</a> API action.</p>
+    async fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        ModifyDBClusterSnapshotAttributeResult,
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        self.modify_db_cluster_snapshot_attribute_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies settings for a DB instance. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. To learn what modifications you can make to your DB instance, call <a>DescribeValidDBInstanceModifications</a> before you call <a>ModifyDBInstance</a>.</p>
+    async fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        self.modify_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies the parameters of a DB parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request.</p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot without failover to the DB instance associated with the parameter group before the change can take effect.</p> </note> <important> <p>After you modify a DB parameter group, you should wait at least 5 minutes before creating your first DB instance that uses that DB parameter group as the default parameter group. This allows Amazon Neptune to fully complete the modify action before the parameter group is used as the default for a new DB instance. This is especially important for parameters that are critical when creating the default database for a DB instance, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the Amazon Neptune console or the <i>DescribeDBParameters</i> command to verify that your DB parameter group has been created or modified.</p> </important></p>
+    async fn modify_db_parameter_group(
+        &self,
+        input: ModifyDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
+        self.modify_db_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an existing DB subnet group. DB subnet groups must contain at least one subnet in at least two AZs in the AWS Region.</p>
+    async fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        self.modify_db_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an existing event notification subscription. Note that you can't modify the source identifiers using this call; to change source identifiers for a subscription, use the <a>AddSourceIdentifierToSubscription</a> and <a>RemoveSourceIdentifierFromSubscription</a> calls.</p> <p>You can see a list of the event categories for a given SourceType by using the <b>DescribeEventCategories</b> action.</p>
+    async fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        self.modify_event_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Not supported.</p>
+    async fn promote_read_replica_db_cluster(
+        &self,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
+    {
+        self.promote_read_replica_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>You might need to reboot your DB instance, usually for maintenance reasons. For example, if you make certain modifications, or if you change the DB parameter group associated with the DB instance, you must reboot the instance for the changes to take effect.</p> <p>Rebooting a DB instance restarts the database engine service. Rebooting a DB instance results in a momentary outage, during which the DB instance status is set to rebooting.</p>
+    async fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        self.reboot_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates an Identity and Access Management (IAM) role from a DB cluster.</p>
+    async fn remove_role_from_db_cluster(
+        &self,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
+        self.remove_role_from_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a source identifier from an existing event notification subscription.</p>
+    async fn remove_source_identifier_from_subscription(
+        &self,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> Result<
+        RemoveSourceIdentifierFromSubscriptionResult,
+        RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
+    > {
+        self.remove_source_identifier_from_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes metadata tags from an Amazon Neptune resource.</p>
+    async fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        self.remove_tags_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Modifies the parameters of a DB cluster parameter group to the default value. To reset specific parameters submit a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB cluster parameter group, specify the <code>DBClusterParameterGroupName</code> and <code>ResetAllParameters</code> parameters.</p> <p> When resetting the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance restart or <a>RebootDBInstance</a> request. You must call <a>RebootDBInstance</a> for every DB instance in your DB cluster that you want the updated static parameter to apply to.</p>
+    async fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
+    {
+        self.reset_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the parameters of a DB parameter group to the engine/system default value. To reset specific parameters, provide a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB parameter group, specify the <code>DBParameterGroup</code> name and <code>ResetAllParameters</code> parameters. When resetting the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance restart or <code>RebootDBInstance</code> request.</p>
+    async fn reset_db_parameter_group(
+        &self,
+        input: ResetDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
+        self.reset_db_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new DB cluster from a DB snapshot or DB cluster snapshot.</p> <p>If a DB snapshot is specified, the target DB cluster is created from the source DB snapshot with a default configuration and default security group.</p> <p>If a DB cluster snapshot is specified, the target DB cluster is created from the source DB cluster restore point with the same configuration as the original source DB cluster, except that the new DB cluster is created with the default security group.</p>
+    async fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
+    {
+        self.restore_db_cluster_from_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Restores a DB cluster to an arbitrary point in time. Users can restore to any point in time before <code>LatestRestorableTime</code> for up to <code>BackupRetentionPeriod</code> days. The target DB cluster is created from the source DB cluster with the same configuration as the original DB cluster, except that the new DB cluster is created with the default DB security group.</p> <note> <p>This action only restores the DB cluster, not the DB instances for that DB cluster. You must invoke the <a>CreateDBInstance</a> action to create DB instances for the restored DB cluster, specifying the identifier of the restored DB cluster in <code>DBClusterIdentifier</code>. You can create DB instances only after the <code>RestoreDBClusterToPointInTime</code> action has completed and the DB cluster is available.</p> </note></p>
+    async fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
+    {
+        self.restore_db_cluster_to_point_in_time_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1111,6 +1111,7 @@ pub struct ValidationExceptionField {
 
 /// Errors returned by AssociateCustomerGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateCustomerGatewayError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1124,6 +1125,8 @@ pub enum AssociateCustomerGatewayError {
     ServiceQuotaExceeded(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateCustomerGatewayError {
@@ -1131,33 +1134,43 @@ impl AssociateCustomerGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCustomerGatewayError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::Conflict(err.msg))
+                    return RusotoError::service(
+                        AssociateCustomerGatewayError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCustomerGatewayError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateCustomerGatewayError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateCustomerGatewayError::ServiceQuotaExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::Throttling(err.msg))
+                    return RusotoError::service(
+                        AssociateCustomerGatewayError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateCustomerGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1175,12 +1188,14 @@ impl fmt::Display for AssociateCustomerGatewayError {
                 write!(f, "{}", cause)
             }
             AssociateCustomerGatewayError::Throttling(ref cause) => write!(f, "{}", cause),
+            AssociateCustomerGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateCustomerGatewayError {}
 /// Errors returned by AssociateLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateLinkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1194,6 +1209,8 @@ pub enum AssociateLinkError {
     ServiceQuotaExceeded(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateLinkError {
@@ -1201,25 +1218,31 @@ impl AssociateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(AssociateLinkError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateLinkError::Conflict(err.msg))
+                    return RusotoError::service(AssociateLinkError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AssociateLinkError::InternalServer(err.msg))
+                    return RusotoError::service(AssociateLinkError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AssociateLinkError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(AssociateLinkError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        AssociateLinkError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateLinkError::Throttling(err.msg))
+                    return RusotoError::service(AssociateLinkError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1235,12 +1258,14 @@ impl fmt::Display for AssociateLinkError {
             AssociateLinkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AssociateLinkError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             AssociateLinkError::Throttling(ref cause) => write!(f, "{}", cause),
+            AssociateLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateLinkError {}
 /// Errors returned by CreateDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeviceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1254,6 +1279,8 @@ pub enum CreateDeviceError {
     ServiceQuotaExceeded(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeviceError {
@@ -1261,25 +1288,28 @@ impl CreateDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDeviceError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateDeviceError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDeviceError::Conflict(err.msg))
+                    return RusotoError::service(CreateDeviceError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDeviceError::InternalServer(err.msg))
+                    return RusotoError::service(CreateDeviceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateDeviceError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateDeviceError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDeviceError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeviceError::Throttling(err.msg))
+                    return RusotoError::service(CreateDeviceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1295,12 +1325,14 @@ impl fmt::Display for CreateDeviceError {
             CreateDeviceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateDeviceError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateDeviceError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeviceError {}
 /// Errors returned by CreateGlobalNetwork
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGlobalNetworkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1312,6 +1344,8 @@ pub enum CreateGlobalNetworkError {
     ServiceQuotaExceeded(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGlobalNetworkError {
@@ -1319,24 +1353,34 @@ impl CreateGlobalNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateGlobalNetworkError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::Conflict(err.msg))
+                    return RusotoError::service(CreateGlobalNetworkError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateGlobalNetworkError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGlobalNetworkError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateGlobalNetworkError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGlobalNetworkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1351,12 +1395,14 @@ impl fmt::Display for CreateGlobalNetworkError {
             CreateGlobalNetworkError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateGlobalNetworkError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateGlobalNetworkError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateGlobalNetworkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGlobalNetworkError {}
 /// Errors returned by CreateLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLinkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1370,6 +1416,8 @@ pub enum CreateLinkError {
     ServiceQuotaExceeded(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLinkError {
@@ -1377,25 +1425,28 @@ impl CreateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateLinkError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateLinkError::Conflict(err.msg))
+                    return RusotoError::service(CreateLinkError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateLinkError::InternalServer(err.msg))
+                    return RusotoError::service(CreateLinkError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateLinkError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateLinkError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateLinkError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateLinkError::Throttling(err.msg))
+                    return RusotoError::service(CreateLinkError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1411,12 +1462,14 @@ impl fmt::Display for CreateLinkError {
             CreateLinkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateLinkError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateLinkError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLinkError {}
 /// Errors returned by CreateSite
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSiteError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1430,6 +1483,8 @@ pub enum CreateSiteError {
     ServiceQuotaExceeded(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSiteError {
@@ -1437,25 +1492,28 @@ impl CreateSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSiteError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateSiteError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateSiteError::Conflict(err.msg))
+                    return RusotoError::service(CreateSiteError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSiteError::InternalServer(err.msg))
+                    return RusotoError::service(CreateSiteError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSiteError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateSiteError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateSiteError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateSiteError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSiteError::Throttling(err.msg))
+                    return RusotoError::service(CreateSiteError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSiteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1471,12 +1529,14 @@ impl fmt::Display for CreateSiteError {
             CreateSiteError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateSiteError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateSiteError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateSiteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSiteError {}
 /// Errors returned by DeleteDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeviceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1488,6 +1548,8 @@ pub enum DeleteDeviceError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeviceError {
@@ -1495,22 +1557,22 @@ impl DeleteDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDeviceError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteDeviceError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDeviceError::Conflict(err.msg))
+                    return RusotoError::service(DeleteDeviceError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDeviceError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteDeviceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteDeviceError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDeviceError::Throttling(err.msg))
+                    return RusotoError::service(DeleteDeviceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1525,12 +1587,14 @@ impl fmt::Display for DeleteDeviceError {
             DeleteDeviceError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteDeviceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDeviceError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeviceError {}
 /// Errors returned by DeleteGlobalNetwork
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGlobalNetworkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1542,6 +1606,8 @@ pub enum DeleteGlobalNetworkError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGlobalNetworkError {
@@ -1549,24 +1615,34 @@ impl DeleteGlobalNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteGlobalNetworkError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::Conflict(err.msg))
+                    return RusotoError::service(DeleteGlobalNetworkError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteGlobalNetworkError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGlobalNetworkError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteGlobalNetworkError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGlobalNetworkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1581,12 +1657,14 @@ impl fmt::Display for DeleteGlobalNetworkError {
             DeleteGlobalNetworkError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteGlobalNetworkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteGlobalNetworkError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteGlobalNetworkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGlobalNetworkError {}
 /// Errors returned by DeleteLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLinkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1598,6 +1676,8 @@ pub enum DeleteLinkError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLinkError {
@@ -1605,22 +1685,22 @@ impl DeleteLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteLinkError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteLinkError::Conflict(err.msg))
+                    return RusotoError::service(DeleteLinkError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteLinkError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteLinkError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteLinkError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteLinkError::Throttling(err.msg))
+                    return RusotoError::service(DeleteLinkError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1635,12 +1715,14 @@ impl fmt::Display for DeleteLinkError {
             DeleteLinkError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteLinkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteLinkError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLinkError {}
 /// Errors returned by DeleteSite
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSiteError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1652,6 +1734,8 @@ pub enum DeleteSiteError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSiteError {
@@ -1659,22 +1743,22 @@ impl DeleteSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSiteError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteSiteError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSiteError::Conflict(err.msg))
+                    return RusotoError::service(DeleteSiteError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSiteError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteSiteError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSiteError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteSiteError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSiteError::Throttling(err.msg))
+                    return RusotoError::service(DeleteSiteError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSiteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1689,12 +1773,14 @@ impl fmt::Display for DeleteSiteError {
             DeleteSiteError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteSiteError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteSiteError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteSiteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSiteError {}
 /// Errors returned by DeregisterTransitGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterTransitGatewayError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1706,6 +1792,8 @@ pub enum DeregisterTransitGatewayError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterTransitGatewayError {
@@ -1713,28 +1801,37 @@ impl DeregisterTransitGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterTransitGatewayError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeregisterTransitGatewayError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterTransitGatewayError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterTransitGatewayError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeregisterTransitGatewayError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterTransitGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1749,12 +1846,14 @@ impl fmt::Display for DeregisterTransitGatewayError {
             DeregisterTransitGatewayError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeregisterTransitGatewayError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeregisterTransitGatewayError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeregisterTransitGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterTransitGatewayError {}
 /// Errors returned by DescribeGlobalNetworks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeGlobalNetworksError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1764,6 +1863,8 @@ pub enum DescribeGlobalNetworksError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeGlobalNetworksError {
@@ -1771,23 +1872,31 @@ impl DescribeGlobalNetworksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeGlobalNetworksError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGlobalNetworksError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGlobalNetworksError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeGlobalNetworksError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeGlobalNetworksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1801,12 +1910,14 @@ impl fmt::Display for DescribeGlobalNetworksError {
             DescribeGlobalNetworksError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeGlobalNetworksError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeGlobalNetworksError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeGlobalNetworksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeGlobalNetworksError {}
 /// Errors returned by DisassociateCustomerGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateCustomerGatewayError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1818,6 +1929,8 @@ pub enum DisassociateCustomerGatewayError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateCustomerGatewayError {
@@ -1827,32 +1940,42 @@ impl DisassociateCustomerGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateCustomerGatewayError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateCustomerGatewayError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateCustomerGatewayError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateCustomerGatewayError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateCustomerGatewayError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateCustomerGatewayError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1867,12 +1990,14 @@ impl fmt::Display for DisassociateCustomerGatewayError {
             DisassociateCustomerGatewayError::InternalServer(ref cause) => write!(f, "{}", cause),
             DisassociateCustomerGatewayError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DisassociateCustomerGatewayError::Throttling(ref cause) => write!(f, "{}", cause),
+            DisassociateCustomerGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateCustomerGatewayError {}
 /// Errors returned by DisassociateLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateLinkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1884,6 +2009,8 @@ pub enum DisassociateLinkError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateLinkError {
@@ -1891,22 +2018,28 @@ impl DisassociateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(DisassociateLinkError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateLinkError::Conflict(err.msg))
+                    return RusotoError::service(DisassociateLinkError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateLinkError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DisassociateLinkError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DisassociateLinkError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateLinkError::Throttling(err.msg))
+                    return RusotoError::service(DisassociateLinkError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1921,12 +2054,14 @@ impl fmt::Display for DisassociateLinkError {
             DisassociateLinkError::InternalServer(ref cause) => write!(f, "{}", cause),
             DisassociateLinkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DisassociateLinkError::Throttling(ref cause) => write!(f, "{}", cause),
+            DisassociateLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateLinkError {}
 /// Errors returned by GetCustomerGatewayAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCustomerGatewayAssociationsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1938,6 +2073,8 @@ pub enum GetCustomerGatewayAssociationsError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCustomerGatewayAssociationsError {
@@ -1947,32 +2084,42 @@ impl GetCustomerGatewayAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCustomerGatewayAssociationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCustomerGatewayAssociationsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetCustomerGatewayAssociationsError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCustomerGatewayAssociationsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCustomerGatewayAssociationsError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCustomerGatewayAssociationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCustomerGatewayAssociationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCustomerGatewayAssociationsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetCustomerGatewayAssociationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1991,12 +2138,16 @@ impl fmt::Display for GetCustomerGatewayAssociationsError {
                 write!(f, "{}", cause)
             }
             GetCustomerGatewayAssociationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetCustomerGatewayAssociationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetCustomerGatewayAssociationsError {}
 /// Errors returned by GetDevices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDevicesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2006,6 +2157,8 @@ pub enum GetDevicesError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDevicesError {
@@ -2013,19 +2166,19 @@ impl GetDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDevicesError::AccessDenied(err.msg))
+                    return RusotoError::service(GetDevicesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDevicesError::InternalServer(err.msg))
+                    return RusotoError::service(GetDevicesError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetDevicesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDevicesError::Throttling(err.msg))
+                    return RusotoError::service(GetDevicesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDevicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2039,12 +2192,14 @@ impl fmt::Display for GetDevicesError {
             GetDevicesError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetDevicesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetDevicesError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetDevicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDevicesError {}
 /// Errors returned by GetLinkAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLinkAssociationsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2054,6 +2209,8 @@ pub enum GetLinkAssociationsError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLinkAssociationsError {
@@ -2061,21 +2218,31 @@ impl GetLinkAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetLinkAssociationsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetLinkAssociationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLinkAssociationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetLinkAssociationsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLinkAssociationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2089,12 +2256,14 @@ impl fmt::Display for GetLinkAssociationsError {
             GetLinkAssociationsError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetLinkAssociationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLinkAssociationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetLinkAssociationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLinkAssociationsError {}
 /// Errors returned by GetLinks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLinksError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2104,6 +2273,8 @@ pub enum GetLinksError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLinksError {
@@ -2111,19 +2282,19 @@ impl GetLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLinksError::AccessDenied(err.msg))
+                    return RusotoError::service(GetLinksError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetLinksError::InternalServer(err.msg))
+                    return RusotoError::service(GetLinksError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLinksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetLinksError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetLinksError::Throttling(err.msg))
+                    return RusotoError::service(GetLinksError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLinksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2137,12 +2308,14 @@ impl fmt::Display for GetLinksError {
             GetLinksError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetLinksError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLinksError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetLinksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLinksError {}
 /// Errors returned by GetSites
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSitesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2152,6 +2325,8 @@ pub enum GetSitesError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSitesError {
@@ -2159,19 +2334,19 @@ impl GetSitesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSitesError::AccessDenied(err.msg))
+                    return RusotoError::service(GetSitesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetSitesError::InternalServer(err.msg))
+                    return RusotoError::service(GetSitesError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSitesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetSitesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSitesError::Throttling(err.msg))
+                    return RusotoError::service(GetSitesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSitesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2185,12 +2360,14 @@ impl fmt::Display for GetSitesError {
             GetSitesError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetSitesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetSitesError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetSitesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSitesError {}
 /// Errors returned by GetTransitGatewayRegistrations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTransitGatewayRegistrationsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2200,6 +2377,8 @@ pub enum GetTransitGatewayRegistrationsError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTransitGatewayRegistrationsError {
@@ -2209,27 +2388,36 @@ impl GetTransitGatewayRegistrationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTransitGatewayRegistrationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetTransitGatewayRegistrationsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetTransitGatewayRegistrationsError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetTransitGatewayRegistrationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetTransitGatewayRegistrationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetTransitGatewayRegistrationsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetTransitGatewayRegistrationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2247,12 +2435,16 @@ impl fmt::Display for GetTransitGatewayRegistrationsError {
                 write!(f, "{}", cause)
             }
             GetTransitGatewayRegistrationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetTransitGatewayRegistrationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetTransitGatewayRegistrationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2262,6 +2454,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2269,21 +2463,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2297,12 +2501,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by RegisterTransitGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterTransitGatewayError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2314,6 +2520,8 @@ pub enum RegisterTransitGatewayError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterTransitGatewayError {
@@ -2321,26 +2529,37 @@ impl RegisterTransitGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        RegisterTransitGatewayError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::Conflict(err.msg))
+                    return RusotoError::service(
+                        RegisterTransitGatewayError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterTransitGatewayError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterTransitGatewayError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::Throttling(err.msg))
+                    return RusotoError::service(
+                        RegisterTransitGatewayError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterTransitGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2355,12 +2574,14 @@ impl fmt::Display for RegisterTransitGatewayError {
             RegisterTransitGatewayError::InternalServer(ref cause) => write!(f, "{}", cause),
             RegisterTransitGatewayError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RegisterTransitGatewayError::Throttling(ref cause) => write!(f, "{}", cause),
+            RegisterTransitGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterTransitGatewayError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2374,6 +2595,8 @@ pub enum TagResourceError {
     ServiceQuotaExceeded(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2381,25 +2604,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(TagResourceError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service(TagResourceError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(TagResourceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(TagResourceError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(TagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2415,12 +2641,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2432,6 +2660,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2439,22 +2669,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UntagResourceError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service(UntagResourceError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(UntagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2469,12 +2702,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeviceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2486,6 +2721,8 @@ pub enum UpdateDeviceError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeviceError {
@@ -2493,22 +2730,22 @@ impl UpdateDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDeviceError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateDeviceError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDeviceError::Conflict(err.msg))
+                    return RusotoError::service(UpdateDeviceError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDeviceError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateDeviceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateDeviceError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDeviceError::Throttling(err.msg))
+                    return RusotoError::service(UpdateDeviceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2523,12 +2760,14 @@ impl fmt::Display for UpdateDeviceError {
             UpdateDeviceError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateDeviceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDeviceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDeviceError {}
 /// Errors returned by UpdateGlobalNetwork
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGlobalNetworkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2540,6 +2779,8 @@ pub enum UpdateGlobalNetworkError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGlobalNetworkError {
@@ -2547,24 +2788,34 @@ impl UpdateGlobalNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateGlobalNetworkError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::Conflict(err.msg))
+                    return RusotoError::service(UpdateGlobalNetworkError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateGlobalNetworkError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalNetworkError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateGlobalNetworkError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGlobalNetworkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2579,12 +2830,14 @@ impl fmt::Display for UpdateGlobalNetworkError {
             UpdateGlobalNetworkError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateGlobalNetworkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateGlobalNetworkError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateGlobalNetworkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGlobalNetworkError {}
 /// Errors returned by UpdateLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLinkError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2598,6 +2851,8 @@ pub enum UpdateLinkError {
     ServiceQuotaExceeded(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLinkError {
@@ -2605,25 +2860,28 @@ impl UpdateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateLinkError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateLinkError::Conflict(err.msg))
+                    return RusotoError::service(UpdateLinkError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateLinkError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateLinkError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateLinkError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(UpdateLinkError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateLinkError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateLinkError::Throttling(err.msg))
+                    return RusotoError::service(UpdateLinkError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2639,12 +2897,14 @@ impl fmt::Display for UpdateLinkError {
             UpdateLinkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateLinkError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             UpdateLinkError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateLinkError {}
 /// Errors returned by UpdateSite
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSiteError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -2656,6 +2916,8 @@ pub enum UpdateSiteError {
     ResourceNotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSiteError {
@@ -2663,22 +2925,22 @@ impl UpdateSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSiteError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateSiteError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateSiteError::Conflict(err.msg))
+                    return RusotoError::service(UpdateSiteError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateSiteError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateSiteError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSiteError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateSiteError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSiteError::Throttling(err.msg))
+                    return RusotoError::service(UpdateSiteError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSiteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2693,6 +2955,7 @@ impl fmt::Display for UpdateSiteError {
             UpdateSiteError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateSiteError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateSiteError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateSiteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2885,6 +3148,7 @@ impl NetworkManagerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> NetworkManagerClient {
         NetworkManagerClient {
             client: Client::shared(),
@@ -2910,210 +3174,417 @@ impl NetworkManagerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> NetworkManagerClient {
         NetworkManagerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<NetworkManagerClient> {
+        rusoto_core::ClientBuilder::new(NetworkManagerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> NetworkManagerClient {
+        NetworkManagerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl NetworkManager for NetworkManagerClient {
-    /// <p>Associates a customer gateway with a device and optionally, with a link. If you specify a link, it must be associated with the specified device. </p> <p>You can only associate customer gateways that are connected to a VPN attachment on a transit gateway. The transit gateway must be registered in your global network. When you register a transit gateway, customer gateways that are connected to the transit gateway are automatically included in the global network. To list customer gateways that are connected to a transit gateway, use the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeVpnConnections.html">DescribeVpnConnections</a> EC2 API and filter by <code>transit-gateway-id</code>.</p> <p>You cannot associate a customer gateway with more than one device and link. </p>
-    async fn associate_customer_gateway(
+impl NetworkManagerClient {
+    /// Calls `associate_customer_gateway`, also returning the metadata of the response.
+    pub async fn associate_customer_gateway_with_metadata(
         &self,
         input: AssociateCustomerGatewayRequest,
-    ) -> Result<AssociateCustomerGatewayResponse, RusotoError<AssociateCustomerGatewayError>> {
+    ) -> Result<
+        (AssociateCustomerGatewayResponse, ResponseMetadata),
+        RusotoError<AssociateCustomerGatewayError>,
+    > {
+        let request = self.build_associate_customer_gateway_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateCustomerGatewayResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociateCustomerGatewayError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_customer_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_customer_gateway(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateCustomerGatewayRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_customer_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_customer_gateway_request(
+        &self,
+        input: AssociateCustomerGatewayRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/customer-gateway-associations",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("AssociateCustomerGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `associate_link`, also returning the metadata of the response.
+    pub async fn associate_link_with_metadata(
+        &self,
+        input: AssociateLinkRequest,
+    ) -> Result<(AssociateLinkResponse, ResponseMetadata), RusotoError<AssociateLinkError>> {
+        let request = self.build_associate_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateCustomerGatewayResponse, _>()?;
+                .deserialize::<AssociateLinkResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateCustomerGatewayError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociateLinkError::from_response(response))
         }
     }
 
-    /// <p>Associates a link to a device. A device can be associated to multiple links and a link can be associated to multiple devices. The device and link must be in the same global network and the same site.</p>
-    async fn associate_link(
+    /// Presigns the request `associate_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateLinkRequest,
-    ) -> Result<AssociateLinkResponse, RusotoError<AssociateLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_link_request(&self, input: AssociateLinkRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/link-associations",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("AssociateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_device`, also returning the metadata of the response.
+    pub async fn create_device_with_metadata(
+        &self,
+        input: CreateDeviceRequest,
+    ) -> Result<(CreateDeviceResponse, ResponseMetadata), RusotoError<CreateDeviceError>> {
+        let request = self.build_create_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateLinkResponse, _>()?;
+                .deserialize::<CreateDeviceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDeviceError::from_response(response))
         }
     }
 
-    /// <p>Creates a new device in a global network. If you specify both a site ID and a location, the location of the site is used for visualization in the Network Manager console.</p>
-    async fn create_device(
+    /// Presigns the request `create_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDeviceRequest,
-    ) -> Result<CreateDeviceResponse, RusotoError<CreateDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_device_request(&self, input: CreateDeviceRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/devices",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_global_network`, also returning the metadata of the response.
+    pub async fn create_global_network_with_metadata(
+        &self,
+        input: CreateGlobalNetworkRequest,
+    ) -> Result<
+        (CreateGlobalNetworkResponse, ResponseMetadata),
+        RusotoError<CreateGlobalNetworkError>,
+    > {
+        let request = self.build_create_global_network_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDeviceResponse, _>()?;
+                .deserialize::<CreateGlobalNetworkResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeviceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateGlobalNetworkError::from_response(response))
         }
     }
 
-    /// <p>Creates a new, empty global network.</p>
-    async fn create_global_network(
+    /// Presigns the request `create_global_network` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_global_network(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGlobalNetworkRequest,
-    ) -> Result<CreateGlobalNetworkResponse, RusotoError<CreateGlobalNetworkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_global_network_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_global_network_request(
+        &self,
+        input: CreateGlobalNetworkRequest,
+    ) -> SignedRequest {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_link`, also returning the metadata of the response.
+    pub async fn create_link_with_metadata(
+        &self,
+        input: CreateLinkRequest,
+    ) -> Result<(CreateLinkResponse, ResponseMetadata), RusotoError<CreateLinkError>> {
+        let request = self.build_create_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateGlobalNetworkResponse, _>()?;
+                .deserialize::<CreateLinkResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGlobalNetworkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateLinkError::from_response(response))
         }
     }
 
-    /// <p>Creates a new link for a specified site.</p>
-    async fn create_link(
+    /// Presigns the request `create_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLinkRequest,
-    ) -> Result<CreateLinkResponse, RusotoError<CreateLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_link_request(&self, input: CreateLinkRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/links",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_site`, also returning the metadata of the response.
+    pub async fn create_site_with_metadata(
+        &self,
+        input: CreateSiteRequest,
+    ) -> Result<(CreateSiteResponse, ResponseMetadata), RusotoError<CreateSiteError>> {
+        let request = self.build_create_site_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLinkResponse, _>()?;
+                .deserialize::<CreateSiteResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateSiteError::from_response(response))
         }
     }
 
-    /// <p>Creates a new site in a global network.</p>
-    async fn create_site(
+    /// Presigns the request `create_site` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_site(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSiteRequest,
-    ) -> Result<CreateSiteResponse, RusotoError<CreateSiteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_site_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_site_request(&self, input: CreateSiteRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/sites",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_device`, also returning the metadata of the response.
+    pub async fn delete_device_with_metadata(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> Result<(DeleteDeviceResponse, ResponseMetadata), RusotoError<DeleteDeviceError>> {
+        let request = self.build_delete_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSiteResponse, _>()?;
+                .deserialize::<DeleteDeviceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSiteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDeviceError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing device. You must first disassociate the device from any links and customer gateways.</p>
-    async fn delete_device(
+    /// Presigns the request `delete_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeviceRequest,
-    ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_device_request(&self, input: DeleteDeviceRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/devices/{device_id}",
             device_id = input.device_id,
@@ -3122,30 +3593,60 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_global_network`, also returning the metadata of the response.
+    pub async fn delete_global_network_with_metadata(
+        &self,
+        input: DeleteGlobalNetworkRequest,
+    ) -> Result<
+        (DeleteGlobalNetworkResponse, ResponseMetadata),
+        RusotoError<DeleteGlobalNetworkError>,
+    > {
+        let request = self.build_delete_global_network_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDeviceResponse, _>()?;
+                .deserialize::<DeleteGlobalNetworkResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeviceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteGlobalNetworkError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing global network. You must first delete all global network objects (devices, links, and sites) and deregister all transit gateways.</p>
-    async fn delete_global_network(
+    /// Presigns the request `delete_global_network` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_global_network(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGlobalNetworkRequest,
-    ) -> Result<DeleteGlobalNetworkResponse, RusotoError<DeleteGlobalNetworkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_global_network_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_global_network_request(
+        &self,
+        input: DeleteGlobalNetworkRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}",
             global_network_id = input.global_network_id
@@ -3153,30 +3654,54 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_link`, also returning the metadata of the response.
+    pub async fn delete_link_with_metadata(
+        &self,
+        input: DeleteLinkRequest,
+    ) -> Result<(DeleteLinkResponse, ResponseMetadata), RusotoError<DeleteLinkError>> {
+        let request = self.build_delete_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGlobalNetworkResponse, _>()?;
+                .deserialize::<DeleteLinkResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGlobalNetworkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteLinkError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing link. You must first disassociate the link from any devices and customer gateways.</p>
-    async fn delete_link(
+    /// Presigns the request `delete_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLinkRequest,
-    ) -> Result<DeleteLinkResponse, RusotoError<DeleteLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_link_request(&self, input: DeleteLinkRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/links/{link_id}",
             global_network_id = input.global_network_id,
@@ -3185,30 +3710,54 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_site`, also returning the metadata of the response.
+    pub async fn delete_site_with_metadata(
+        &self,
+        input: DeleteSiteRequest,
+    ) -> Result<(DeleteSiteResponse, ResponseMetadata), RusotoError<DeleteSiteError>> {
+        let request = self.build_delete_site_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLinkResponse, _>()?;
+                .deserialize::<DeleteSiteResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteSiteError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing site. The site cannot be associated with any device or link.</p>
-    async fn delete_site(
+    /// Presigns the request `delete_site` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_site(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSiteRequest,
-    ) -> Result<DeleteSiteResponse, RusotoError<DeleteSiteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_site_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_site_request(&self, input: DeleteSiteRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/sites/{site_id}",
             global_network_id = input.global_network_id,
@@ -3217,61 +3766,122 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `deregister_transit_gateway`, also returning the metadata of the response.
+    pub async fn deregister_transit_gateway_with_metadata(
+        &self,
+        input: DeregisterTransitGatewayRequest,
+    ) -> Result<
+        (DeregisterTransitGatewayResponse, ResponseMetadata),
+        RusotoError<DeregisterTransitGatewayError>,
+    > {
+        let request = self.build_deregister_transit_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSiteResponse, _>()?;
+                .deserialize::<DeregisterTransitGatewayResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSiteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeregisterTransitGatewayError::from_response(response))
         }
     }
 
-    /// <p>Deregisters a transit gateway from your global network. This action does not delete your transit gateway, or modify any of its attachments. This action removes any customer gateway associations.</p>
-    async fn deregister_transit_gateway(
+    /// Presigns the request `deregister_transit_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_transit_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterTransitGatewayRequest,
-    ) -> Result<DeregisterTransitGatewayResponse, RusotoError<DeregisterTransitGatewayError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_transit_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_transit_gateway_request(
+        &self,
+        input: DeregisterTransitGatewayRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/global-networks/{global_network_id}/transit-gateway-registrations/{transit_gateway_arn}", global_network_id = input.global_network_id, transit_gateway_arn = input.transit_gateway_arn);
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeregisterTransitGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `describe_global_networks`, also returning the metadata of the response.
+    pub async fn describe_global_networks_with_metadata(
+        &self,
+        input: DescribeGlobalNetworksRequest,
+    ) -> Result<
+        (DescribeGlobalNetworksResponse, ResponseMetadata),
+        RusotoError<DescribeGlobalNetworksError>,
+    > {
+        let request = self.build_describe_global_networks_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterTransitGatewayResponse, _>()?;
+                .deserialize::<DescribeGlobalNetworksResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterTransitGatewayError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeGlobalNetworksError::from_response(response))
         }
     }
 
-    /// <p>Describes one or more global networks. By default, all global networks are described. To describe the objects in your global network, you must use the appropriate <code>Get*</code> action. For example, to list the transit gateways in your global network, use <a>GetTransitGatewayRegistrations</a>.</p>
-    async fn describe_global_networks(
+    /// Presigns the request `describe_global_networks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_global_networks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeGlobalNetworksRequest,
-    ) -> Result<DescribeGlobalNetworksResponse, RusotoError<DescribeGlobalNetworksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_global_networks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_global_networks_request(
+        &self,
+        input: DescribeGlobalNetworksRequest,
+    ) -> SignedRequest {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DescribeGlobalNetworks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3288,57 +3898,110 @@ impl NetworkManager for NetworkManagerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `disassociate_customer_gateway`, also returning the metadata of the response.
+    pub async fn disassociate_customer_gateway_with_metadata(
+        &self,
+        input: DisassociateCustomerGatewayRequest,
+    ) -> Result<
+        (DisassociateCustomerGatewayResponse, ResponseMetadata),
+        RusotoError<DisassociateCustomerGatewayError>,
+    > {
+        let request = self.build_disassociate_customer_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeGlobalNetworksResponse, _>()?;
+                .deserialize::<DisassociateCustomerGatewayResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeGlobalNetworksError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateCustomerGatewayError::from_response(response))
         }
     }
 
-    /// <p>Disassociates a customer gateway from a device and a link.</p>
-    async fn disassociate_customer_gateway(
+    /// Presigns the request `disassociate_customer_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_customer_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateCustomerGatewayRequest,
-    ) -> Result<DisassociateCustomerGatewayResponse, RusotoError<DisassociateCustomerGatewayError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_customer_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_customer_gateway_request(
+        &self,
+        input: DisassociateCustomerGatewayRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/global-networks/{global_network_id}/customer-gateway-associations/{customer_gateway_arn}", customer_gateway_arn = input.customer_gateway_arn, global_network_id = input.global_network_id);
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DisassociateCustomerGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `disassociate_link`, also returning the metadata of the response.
+    pub async fn disassociate_link_with_metadata(
+        &self,
+        input: DisassociateLinkRequest,
+    ) -> Result<(DisassociateLinkResponse, ResponseMetadata), RusotoError<DisassociateLinkError>>
+    {
+        let request = self.build_disassociate_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateCustomerGatewayResponse, _>()?;
+                .deserialize::<DisassociateLinkResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateCustomerGatewayError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateLinkError::from_response(response))
         }
     }
 
-    /// <p>Disassociates an existing device from a link. You must first disassociate any customer gateways that are associated with the link.</p>
-    async fn disassociate_link(
+    /// Presigns the request `disassociate_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateLinkRequest,
-    ) -> Result<DisassociateLinkResponse, RusotoError<DisassociateLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_link_request(&self, input: DisassociateLinkRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/link-associations",
             global_network_id = input.global_network_id
@@ -3346,6 +4009,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DisassociateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3353,37 +4017,64 @@ impl NetworkManager for NetworkManagerClient {
         params.put("linkId", &input.link_id);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_customer_gateway_associations`, also returning the metadata of the response.
+    pub async fn get_customer_gateway_associations_with_metadata(
+        &self,
+        input: GetCustomerGatewayAssociationsRequest,
+    ) -> Result<
+        (GetCustomerGatewayAssociationsResponse, ResponseMetadata),
+        RusotoError<GetCustomerGatewayAssociationsError>,
+    > {
+        let request = self.build_get_customer_gateway_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateLinkResponse, _>()?;
+                .deserialize::<GetCustomerGatewayAssociationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetCustomerGatewayAssociationsError::from_response(response))
         }
     }
 
-    /// <p>Gets the association information for customer gateways that are associated with devices and links in your global network.</p>
-    async fn get_customer_gateway_associations(
+    /// Presigns the request `get_customer_gateway_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_customer_gateway_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCustomerGatewayAssociationsRequest,
-    ) -> Result<
-        GetCustomerGatewayAssociationsResponse,
-        RusotoError<GetCustomerGatewayAssociationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_customer_gateway_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_customer_gateway_associations_request(
+        &self,
+        input: GetCustomerGatewayAssociationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/customer-gateway-associations",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetCustomerGatewayAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3400,34 +4091,58 @@ impl NetworkManager for NetworkManagerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_devices`, also returning the metadata of the response.
+    pub async fn get_devices_with_metadata(
+        &self,
+        input: GetDevicesRequest,
+    ) -> Result<(GetDevicesResponse, ResponseMetadata), RusotoError<GetDevicesError>> {
+        let request = self.build_get_devices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCustomerGatewayAssociationsResponse, _>()?;
+                .deserialize::<GetDevicesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCustomerGatewayAssociationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDevicesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more of your devices in a global network.</p>
-    async fn get_devices(
+    /// Presigns the request `get_devices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_devices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDevicesRequest,
-    ) -> Result<GetDevicesResponse, RusotoError<GetDevicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_devices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_devices_request(&self, input: GetDevicesRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/devices",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3447,34 +4162,64 @@ impl NetworkManager for NetworkManagerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_link_associations`, also returning the metadata of the response.
+    pub async fn get_link_associations_with_metadata(
+        &self,
+        input: GetLinkAssociationsRequest,
+    ) -> Result<
+        (GetLinkAssociationsResponse, ResponseMetadata),
+        RusotoError<GetLinkAssociationsError>,
+    > {
+        let request = self.build_get_link_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDevicesResponse, _>()?;
+                .deserialize::<GetLinkAssociationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDevicesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLinkAssociationsError::from_response(response))
         }
     }
 
-    /// <p>Gets the link associations for a device or a link. Either the device ID or the link ID must be specified.</p>
-    async fn get_link_associations(
+    /// Presigns the request `get_link_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_link_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLinkAssociationsRequest,
-    ) -> Result<GetLinkAssociationsResponse, RusotoError<GetLinkAssociationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_link_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_link_associations_request(
+        &self,
+        input: GetLinkAssociationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/link-associations",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetLinkAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3492,34 +4237,58 @@ impl NetworkManager for NetworkManagerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_links`, also returning the metadata of the response.
+    pub async fn get_links_with_metadata(
+        &self,
+        input: GetLinksRequest,
+    ) -> Result<(GetLinksResponse, ResponseMetadata), RusotoError<GetLinksError>> {
+        let request = self.build_get_links_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLinkAssociationsResponse, _>()?;
+                .deserialize::<GetLinksResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLinkAssociationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLinksError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more links in a specified global network.</p> <p>If you specify the site ID, you cannot specify the type or provider in the same request. You can specify the type and provider in the same request.</p>
-    async fn get_links(
+    /// Presigns the request `get_links` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_links(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLinksRequest,
-    ) -> Result<GetLinksResponse, RusotoError<GetLinksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_links_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_links_request(&self, input: GetLinksRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/links",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3545,34 +4314,58 @@ impl NetworkManager for NetworkManagerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_sites`, also returning the metadata of the response.
+    pub async fn get_sites_with_metadata(
+        &self,
+        input: GetSitesRequest,
+    ) -> Result<(GetSitesResponse, ResponseMetadata), RusotoError<GetSitesError>> {
+        let request = self.build_get_sites_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLinksResponse, _>()?;
+                .deserialize::<GetSitesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLinksError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSitesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more of your sites in a global network.</p>
-    async fn get_sites(
+    /// Presigns the request `get_sites` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_sites(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSitesRequest,
-    ) -> Result<GetSitesResponse, RusotoError<GetSitesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_sites_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_sites_request(&self, input: GetSitesRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/sites",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetSites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3589,37 +4382,64 @@ impl NetworkManager for NetworkManagerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_transit_gateway_registrations`, also returning the metadata of the response.
+    pub async fn get_transit_gateway_registrations_with_metadata(
+        &self,
+        input: GetTransitGatewayRegistrationsRequest,
+    ) -> Result<
+        (GetTransitGatewayRegistrationsResponse, ResponseMetadata),
+        RusotoError<GetTransitGatewayRegistrationsError>,
+    > {
+        let request = self.build_get_transit_gateway_registrations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSitesResponse, _>()?;
+                .deserialize::<GetTransitGatewayRegistrationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSitesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetTransitGatewayRegistrationsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the transit gateway registrations in a specified global network.</p>
-    async fn get_transit_gateway_registrations(
+    /// Presigns the request `get_transit_gateway_registrations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_transit_gateway_registrations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTransitGatewayRegistrationsRequest,
-    ) -> Result<
-        GetTransitGatewayRegistrationsResponse,
-        RusotoError<GetTransitGatewayRegistrationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_transit_gateway_registrations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_transit_gateway_registrations_request(
+        &self,
+        input: GetTransitGatewayRegistrationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/transit-gateway-registrations",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetTransitGatewayRegistrations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3636,122 +4456,230 @@ impl NetworkManager for NetworkManagerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetTransitGatewayRegistrationsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTransitGatewayRegistrationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for a specified resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `register_transit_gateway`, also returning the metadata of the response.
+    pub async fn register_transit_gateway_with_metadata(
+        &self,
+        input: RegisterTransitGatewayRequest,
+    ) -> Result<
+        (RegisterTransitGatewayResponse, ResponseMetadata),
+        RusotoError<RegisterTransitGatewayError>,
+    > {
+        let request = self.build_register_transit_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<RegisterTransitGatewayResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RegisterTransitGatewayError::from_response(response))
         }
     }
 
-    /// <p>Registers a transit gateway in your global network. The transit gateway can be in any AWS Region, but it must be owned by the same AWS account that owns the global network. You cannot register a transit gateway in more than one global network.</p>
-    async fn register_transit_gateway(
+    /// Presigns the request `register_transit_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_transit_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterTransitGatewayRequest,
-    ) -> Result<RegisterTransitGatewayResponse, RusotoError<RegisterTransitGatewayError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_transit_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_transit_gateway_request(
+        &self,
+        input: RegisterTransitGatewayRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/transit-gateway-registrations",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("RegisterTransitGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterTransitGatewayResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterTransitGatewayError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Tags a specified resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from a specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3760,28 +4688,51 @@ impl NetworkManager for NetworkManagerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_device`, also returning the metadata of the response.
+    pub async fn update_device_with_metadata(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> Result<(UpdateDeviceResponse, ResponseMetadata), RusotoError<UpdateDeviceError>> {
+        let request = self.build_update_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateDeviceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDeviceError::from_response(response))
         }
     }
 
-    /// <p>Updates the details for an existing device. To remove information for any of the parameters, specify an empty string.</p>
-    async fn update_device(
+    /// Presigns the request `update_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDeviceRequest,
-    ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_device_request(&self, input: UpdateDeviceRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/devices/{device_id}",
             device_id = input.device_id,
@@ -3789,66 +4740,120 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_global_network`, also returning the metadata of the response.
+    pub async fn update_global_network_with_metadata(
+        &self,
+        input: UpdateGlobalNetworkRequest,
+    ) -> Result<
+        (UpdateGlobalNetworkResponse, ResponseMetadata),
+        RusotoError<UpdateGlobalNetworkError>,
+    > {
+        let request = self.build_update_global_network_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDeviceResponse, _>()?;
+                .deserialize::<UpdateGlobalNetworkResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDeviceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateGlobalNetworkError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing global network. To remove information for any of the parameters, specify an empty string.</p>
-    async fn update_global_network(
+    /// Presigns the request `update_global_network` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_global_network(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGlobalNetworkRequest,
-    ) -> Result<UpdateGlobalNetworkResponse, RusotoError<UpdateGlobalNetworkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_global_network_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_global_network_request(
+        &self,
+        input: UpdateGlobalNetworkRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}",
             global_network_id = input.global_network_id
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `update_link`, also returning the metadata of the response.
+    pub async fn update_link_with_metadata(
+        &self,
+        input: UpdateLinkRequest,
+    ) -> Result<(UpdateLinkResponse, ResponseMetadata), RusotoError<UpdateLinkError>> {
+        let request = self.build_update_link_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGlobalNetworkResponse, _>()?;
+                .deserialize::<UpdateLinkResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGlobalNetworkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateLinkError::from_response(response))
         }
     }
 
-    /// <p>Updates the details for an existing link. To remove information for any of the parameters, specify an empty string.</p>
-    async fn update_link(
+    /// Presigns the request `update_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLinkRequest,
-    ) -> Result<UpdateLinkResponse, RusotoError<UpdateLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_link_request(&self, input: UpdateLinkRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/links/{link_id}",
             global_network_id = input.global_network_id,
@@ -3856,33 +4861,57 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_site`, also returning the metadata of the response.
+    pub async fn update_site_with_metadata(
+        &self,
+        input: UpdateSiteRequest,
+    ) -> Result<(UpdateSiteResponse, ResponseMetadata), RusotoError<UpdateSiteError>> {
+        let request = self.build_update_site_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateLinkResponse, _>()?;
+                .deserialize::<UpdateSiteResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateSiteError::from_response(response))
         }
     }
 
-    /// <p>Updates the information for an existing site. To remove information for any of the parameters, specify an empty string.</p>
-    async fn update_site(
+    /// Presigns the request `update_site` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_site(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSiteRequest,
-    ) -> Result<UpdateSiteResponse, RusotoError<UpdateSiteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_site_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_site_request(&self, input: UpdateSiteRequest) -> SignedRequest {
         let request_uri = format!(
             "/global-networks/{global_network_id}/sites/{site_id}",
             global_network_id = input.global_network_id,
@@ -3890,25 +4919,575 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeGlobalNetworks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_global_networks_pages(
+        &self,
+        input: DescribeGlobalNetworksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeGlobalNetworksResponse, RusotoError<DescribeGlobalNetworksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input| async move {
+                client.describe_global_networks(input).await
+            },
+            |input: &DescribeGlobalNetworksRequest, output: &DescribeGlobalNetworksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeGlobalNetworksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `GlobalNetworks` of every page of `DescribeGlobalNetworks` results for `input`, as `describe_global_networks_pages` requests them.
+    pub fn describe_global_networks_stream(
+        &self,
+        input: DescribeGlobalNetworksRequest,
+    ) -> impl futures::Stream<Item = Result<GlobalNetwork, RusotoError<DescribeGlobalNetworksError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_global_networks_pages(input), |page| {
+            page.global_networks.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetCustomerGatewayAssociations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_customer_gateway_associations_pages(
+        &self,
+        input: GetCustomerGatewayAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetCustomerGatewayAssociationsResponse,
+            RusotoError<GetCustomerGatewayAssociationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input| async move {
+                client.get_customer_gateway_associations(input).await
+            },
+            |input: &GetCustomerGatewayAssociationsRequest,
+             output: &GetCustomerGatewayAssociationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetCustomerGatewayAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CustomerGatewayAssociations` of every page of `GetCustomerGatewayAssociations` results for `input`, as `get_customer_gateway_associations_pages` requests them.
+    pub fn get_customer_gateway_associations_stream(
+        &self,
+        input: GetCustomerGatewayAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<CustomerGatewayAssociation, RusotoError<GetCustomerGatewayAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_customer_gateway_associations_pages(input),
+            |page| page.customer_gateway_associations.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetDevices` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_devices_pages(
+        &self,
+        input: GetDevicesRequest,
+    ) -> impl futures::Stream<Item = Result<GetDevicesResponse, RusotoError<GetDevicesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input| async move { client.get_devices(input).await },
+            |input: &GetDevicesRequest, output: &GetDevicesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetDevicesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Devices` of every page of `GetDevices` results for `input`, as `get_devices_pages` requests them.
+    pub fn get_devices_stream(
+        &self,
+        input: GetDevicesRequest,
+    ) -> impl futures::Stream<Item = Result<Device, RusotoError<GetDevicesError>>> + Send {
+        rusoto_core::pagination::items(self.get_devices_pages(input), |page| {
+            page.devices.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetLinkAssociations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_link_associations_pages(
+        &self,
+        input: GetLinkAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetLinkAssociationsResponse, RusotoError<GetLinkAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input| async move {
+                client.get_link_associations(input).await
+            },
+            |input: &GetLinkAssociationsRequest, output: &GetLinkAssociationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetLinkAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LinkAssociations` of every page of `GetLinkAssociations` results for `input`, as `get_link_associations_pages` requests them.
+    pub fn get_link_associations_stream(
+        &self,
+        input: GetLinkAssociationsRequest,
+    ) -> impl futures::Stream<Item = Result<LinkAssociation, RusotoError<GetLinkAssociationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_link_associations_pages(input), |page| {
+            page.link_associations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetLinks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_links_pages(
+        &self,
+        input: GetLinksRequest,
+    ) -> impl futures::Stream<Item = Result<GetLinksResponse, RusotoError<GetLinksError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input| async move { client.get_links(input).await },
+            |input: &GetLinksRequest, output: &GetLinksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetLinksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Links` of every page of `GetLinks` results for `input`, as `get_links_pages` requests them.
+    pub fn get_links_stream(
+        &self,
+        input: GetLinksRequest,
+    ) -> impl futures::Stream<Item = Result<Link, RusotoError<GetLinksError>>> + Send {
+        rusoto_core::pagination::items(self.get_links_pages(input), |page| {
+            page.links.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetSites` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_sites_pages(
+        &self,
+        input: GetSitesRequest,
+    ) -> impl futures::Stream<Item = Result<GetSitesResponse, RusotoError<GetSitesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input| async move { client.get_sites(input).await },
+            |input: &GetSitesRequest, output: &GetSitesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetSitesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Sites` of every page of `GetSites` results for `input`, as `get_sites_pages` requests them.
+    pub fn get_sites_stream(
+        &self,
+        input: GetSitesRequest,
+    ) -> impl futures::Stream<Item = Result<Site, RusotoError<GetSitesError>>> + Send {
+        rusoto_core::pagination::items(self.get_sites_pages(input), |page| {
+            page.sites.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetTransitGatewayRegistrations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_transit_gateway_registrations_pages(
+        &self,
+        input: GetTransitGatewayRegistrationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetTransitGatewayRegistrationsResponse,
+            RusotoError<GetTransitGatewayRegistrationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: NetworkManagerClient, input| async move {
+                client.get_transit_gateway_registrations(input).await
+            },
+            |input: &GetTransitGatewayRegistrationsRequest,
+             output: &GetTransitGatewayRegistrationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetTransitGatewayRegistrationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TransitGatewayRegistrations` of every page of `GetTransitGatewayRegistrations` results for `input`, as `get_transit_gateway_registrations_pages` requests them.
+    pub fn get_transit_gateway_registrations_stream(
+        &self,
+        input: GetTransitGatewayRegistrationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<TransitGatewayRegistration, RusotoError<GetTransitGatewayRegistrationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_transit_gateway_registrations_pages(input),
+            |page| page.transit_gateway_registrations.unwrap_or_default(),
+        )
+    }
+}
+
+#[async_trait]
+impl NetworkManager for NetworkManagerClient {
+    /// <p>Associates a customer gateway with a device and optionally, with a link. If you specify a link, it must be associated with the specified device. </p> <p>You can only associate customer gateways that are connected to a VPN attachment on a transit gateway. The transit gateway must be registered in your global network. When you register a transit gateway, customer gateways that are connected to the transit gateway are automatically included in the global network. To list customer gateways that are connected to a transit gateway, use the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeVpnConnections.html">DescribeVpnConnections</a> EC2 API and filter by <code>transit-gateway-id</code>.</p> <p>You cannot associate a customer gateway with more than one device and link. </p>
+    async fn associate_customer_gateway(
+        &self,
+        input: AssociateCustomerGatewayRequest,
+    ) -> Result<AssociateCustomerGatewayResponse, RusotoError<AssociateCustomerGatewayError>> {
+        self.associate_customer_gateway_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSiteResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSiteError::from_response(response))
-        }
+    /// <p>Associates a link to a device. A device can be associated to multiple links and a link can be associated to multiple devices. The device and link must be in the same global network and the same site.</p>
+    async fn associate_link(
+        &self,
+        input: AssociateLinkRequest,
+    ) -> Result<AssociateLinkResponse, RusotoError<AssociateLinkError>> {
+        self.associate_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new device in a global network. If you specify both a site ID and a location, the location of the site is used for visualization in the Network Manager console.</p>
+    async fn create_device(
+        &self,
+        input: CreateDeviceRequest,
+    ) -> Result<CreateDeviceResponse, RusotoError<CreateDeviceError>> {
+        self.create_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new, empty global network.</p>
+    async fn create_global_network(
+        &self,
+        input: CreateGlobalNetworkRequest,
+    ) -> Result<CreateGlobalNetworkResponse, RusotoError<CreateGlobalNetworkError>> {
+        self.create_global_network_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new link for a specified site.</p>
+    async fn create_link(
+        &self,
+        input: CreateLinkRequest,
+    ) -> Result<CreateLinkResponse, RusotoError<CreateLinkError>> {
+        self.create_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new site in a global network.</p>
+    async fn create_site(
+        &self,
+        input: CreateSiteRequest,
+    ) -> Result<CreateSiteResponse, RusotoError<CreateSiteError>> {
+        self.create_site_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing device. You must first disassociate the device from any links and customer gateways.</p>
+    async fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
+        self.delete_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing global network. You must first delete all global network objects (devices, links, and sites) and deregister all transit gateways.</p>
+    async fn delete_global_network(
+        &self,
+        input: DeleteGlobalNetworkRequest,
+    ) -> Result<DeleteGlobalNetworkResponse, RusotoError<DeleteGlobalNetworkError>> {
+        self.delete_global_network_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing link. You must first disassociate the link from any devices and customer gateways.</p>
+    async fn delete_link(
+        &self,
+        input: DeleteLinkRequest,
+    ) -> Result<DeleteLinkResponse, RusotoError<DeleteLinkError>> {
+        self.delete_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing site. The site cannot be associated with any device or link.</p>
+    async fn delete_site(
+        &self,
+        input: DeleteSiteRequest,
+    ) -> Result<DeleteSiteResponse, RusotoError<DeleteSiteError>> {
+        self.delete_site_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters a transit gateway from your global network. This action does not delete your transit gateway, or modify any of its attachments. This action removes any customer gateway associations.</p>
+    async fn deregister_transit_gateway(
+        &self,
+        input: DeregisterTransitGatewayRequest,
+    ) -> Result<DeregisterTransitGatewayResponse, RusotoError<DeregisterTransitGatewayError>> {
+        self.deregister_transit_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more global networks. By default, all global networks are described. To describe the objects in your global network, you must use the appropriate <code>Get*</code> action. For example, to list the transit gateways in your global network, use <a>GetTransitGatewayRegistrations</a>.</p>
+    async fn describe_global_networks(
+        &self,
+        input: DescribeGlobalNetworksRequest,
+    ) -> Result<DescribeGlobalNetworksResponse, RusotoError<DescribeGlobalNetworksError>> {
+        self.describe_global_networks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a customer gateway from a device and a link.</p>
+    async fn disassociate_customer_gateway(
+        &self,
+        input: DisassociateCustomerGatewayRequest,
+    ) -> Result<DisassociateCustomerGatewayResponse, RusotoError<DisassociateCustomerGatewayError>>
+    {
+        self.disassociate_customer_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates an existing device from a link. You must first disassociate any customer gateways that are associated with the link.</p>
+    async fn disassociate_link(
+        &self,
+        input: DisassociateLinkRequest,
+    ) -> Result<DisassociateLinkResponse, RusotoError<DisassociateLinkError>> {
+        self.disassociate_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the association information for customer gateways that are associated with devices and links in your global network.</p>
+    async fn get_customer_gateway_associations(
+        &self,
+        input: GetCustomerGatewayAssociationsRequest,
+    ) -> Result<
+        GetCustomerGatewayAssociationsResponse,
+        RusotoError<GetCustomerGatewayAssociationsError>,
+    > {
+        self.get_customer_gateway_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more of your devices in a global network.</p>
+    async fn get_devices(
+        &self,
+        input: GetDevicesRequest,
+    ) -> Result<GetDevicesResponse, RusotoError<GetDevicesError>> {
+        self.get_devices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the link associations for a device or a link. Either the device ID or the link ID must be specified.</p>
+    async fn get_link_associations(
+        &self,
+        input: GetLinkAssociationsRequest,
+    ) -> Result<GetLinkAssociationsResponse, RusotoError<GetLinkAssociationsError>> {
+        self.get_link_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more links in a specified global network.</p> <p>If you specify the site ID, you cannot specify the type or provider in the same request. You can specify the type and provider in the same request.</p>
+    async fn get_links(
+        &self,
+        input: GetLinksRequest,
+    ) -> Result<GetLinksResponse, RusotoError<GetLinksError>> {
+        self.get_links_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more of your sites in a global network.</p>
+    async fn get_sites(
+        &self,
+        input: GetSitesRequest,
+    ) -> Result<GetSitesResponse, RusotoError<GetSitesError>> {
+        self.get_sites_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the transit gateway registrations in a specified global network.</p>
+    async fn get_transit_gateway_registrations(
+        &self,
+        input: GetTransitGatewayRegistrationsRequest,
+    ) -> Result<
+        GetTransitGatewayRegistrationsResponse,
+        RusotoError<GetTransitGatewayRegistrationsError>,
+    > {
+        self.get_transit_gateway_registrations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for a specified resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a transit gateway in your global network. The transit gateway can be in any AWS Region, but it must be owned by the same AWS account that owns the global network. You cannot register a transit gateway in more than one global network.</p>
+    async fn register_transit_gateway(
+        &self,
+        input: RegisterTransitGatewayRequest,
+    ) -> Result<RegisterTransitGatewayResponse, RusotoError<RegisterTransitGatewayError>> {
+        self.register_transit_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Tags a specified resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from a specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the details for an existing device. To remove information for any of the parameters, specify an empty string.</p>
+    async fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
+        self.update_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing global network. To remove information for any of the parameters, specify an empty string.</p>
+    async fn update_global_network(
+        &self,
+        input: UpdateGlobalNetworkRequest,
+    ) -> Result<UpdateGlobalNetworkResponse, RusotoError<UpdateGlobalNetworkError>> {
+        self.update_global_network_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the details for an existing link. To remove information for any of the parameters, specify an empty string.</p>
+    async fn update_link(
+        &self,
+        input: UpdateLinkRequest,
+    ) -> Result<UpdateLinkResponse, RusotoError<UpdateLinkError>> {
+        self.update_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the information for an existing site. To remove information for any of the parameters, specify an empty string.</p>
+    async fn update_site(
+        &self,
+        input: UpdateSiteRequest,
+    ) -> Result<UpdateSiteResponse, RusotoError<UpdateSiteError>> {
+        self.update_site_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3332,9 +3332,12 @@ pub struct WeeklyAutoScalingSchedule {
 
 /// Errors returned by AssignInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssignInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssignInstanceError {
@@ -3342,10 +3345,13 @@ impl AssignInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssignInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AssignInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssignInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3356,15 +3362,19 @@ impl fmt::Display for AssignInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AssignInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssignInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssignInstanceError {}
 /// Errors returned by AssignVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssignVolumeError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssignVolumeError {
@@ -3372,10 +3382,10 @@ impl AssignVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssignVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(AssignVolumeError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssignVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3386,15 +3396,19 @@ impl fmt::Display for AssignVolumeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AssignVolumeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssignVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssignVolumeError {}
 /// Errors returned by AssociateElasticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateElasticIpError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateElasticIpError {
@@ -3402,10 +3416,13 @@ impl AssociateElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateElasticIpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AssociateElasticIpError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateElasticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3416,15 +3433,19 @@ impl fmt::Display for AssociateElasticIpError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AssociateElasticIpError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssociateElasticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateElasticIpError {}
 /// Errors returned by AttachElasticLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachElasticLoadBalancerError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachElasticLoadBalancerError {
@@ -3432,12 +3453,15 @@ impl AttachElasticLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachElasticLoadBalancerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachElasticLoadBalancerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(AttachElasticLoadBalancerError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3448,15 +3472,19 @@ impl fmt::Display for AttachElasticLoadBalancerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AttachElasticLoadBalancerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AttachElasticLoadBalancerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachElasticLoadBalancerError {}
 /// Errors returned by CloneStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CloneStackError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CloneStackError {
@@ -3464,10 +3492,10 @@ impl CloneStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CloneStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CloneStackError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CloneStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3478,15 +3506,19 @@ impl fmt::Display for CloneStackError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CloneStackError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CloneStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CloneStackError {}
 /// Errors returned by CreateApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAppError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAppError {
@@ -3494,10 +3526,10 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateAppError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3508,15 +3540,19 @@ impl fmt::Display for CreateAppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateAppError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAppError {}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentError {
@@ -3524,10 +3560,13 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3538,15 +3577,19 @@ impl fmt::Display for CreateDeploymentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateDeploymentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentError {}
 /// Errors returned by CreateInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInstanceError {
@@ -3554,10 +3597,13 @@ impl CreateInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3568,15 +3614,19 @@ impl fmt::Display for CreateInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInstanceError {}
 /// Errors returned by CreateLayer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLayerError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLayerError {
@@ -3584,10 +3634,10 @@ impl CreateLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLayerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateLayerError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLayerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3598,20 +3648,25 @@ impl fmt::Display for CreateLayerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateLayerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateLayerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLayerError {}
 /// Errors returned by CreateStack
 #[derive(Debug, PartialEq)]
-pub enum CreateStackError {}
+#[non_exhaustive]
+pub enum CreateStackError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl CreateStackError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateStackError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3620,20 +3675,26 @@ impl CreateStackError {
 impl fmt::Display for CreateStackError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            CreateStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateStackError {}
 /// Errors returned by CreateUserProfile
 #[derive(Debug, PartialEq)]
-pub enum CreateUserProfileError {}
+#[non_exhaustive]
+pub enum CreateUserProfileError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl CreateUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3642,15 +3703,20 @@ impl CreateUserProfileError {
 impl fmt::Display for CreateUserProfileError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            CreateUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateUserProfileError {}
 /// Errors returned by DeleteApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAppError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAppError {
@@ -3658,10 +3724,10 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteAppError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3672,15 +3738,19 @@ impl fmt::Display for DeleteAppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteAppError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAppError {}
 /// Errors returned by DeleteInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInstanceError {
@@ -3688,10 +3758,13 @@ impl DeleteInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3702,15 +3775,19 @@ impl fmt::Display for DeleteInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInstanceError {}
 /// Errors returned by DeleteLayer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLayerError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLayerError {
@@ -3718,10 +3795,10 @@ impl DeleteLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLayerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteLayerError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLayerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3732,15 +3809,19 @@ impl fmt::Display for DeleteLayerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteLayerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteLayerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLayerError {}
 /// Errors returned by DeleteStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStackError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStackError {
@@ -3748,10 +3829,10 @@ impl DeleteStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteStackError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3762,15 +3843,19 @@ impl fmt::Display for DeleteStackError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteStackError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStackError {}
 /// Errors returned by DeleteUserProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserProfileError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserProfileError {
@@ -3778,10 +3863,13 @@ impl DeleteUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteUserProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3792,15 +3880,19 @@ impl fmt::Display for DeleteUserProfileError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteUserProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserProfileError {}
 /// Errors returned by DeregisterEcsCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterEcsClusterError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterEcsClusterError {
@@ -3808,12 +3900,13 @@ impl DeregisterEcsClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterEcsClusterError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterEcsClusterError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterEcsClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3824,15 +3917,19 @@ impl fmt::Display for DeregisterEcsClusterError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeregisterEcsClusterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterEcsClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterEcsClusterError {}
 /// Errors returned by DeregisterElasticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterElasticIpError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterElasticIpError {
@@ -3840,12 +3937,13 @@ impl DeregisterElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterElasticIpError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterElasticIpError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterElasticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3856,15 +3954,19 @@ impl fmt::Display for DeregisterElasticIpError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeregisterElasticIpError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterElasticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterElasticIpError {}
 /// Errors returned by DeregisterInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterInstanceError {
@@ -3872,10 +3974,13 @@ impl DeregisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeregisterInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3886,15 +3991,19 @@ impl fmt::Display for DeregisterInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeregisterInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterInstanceError {}
 /// Errors returned by DeregisterRdsDbInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterRdsDbInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterRdsDbInstanceError {
@@ -3902,12 +4011,13 @@ impl DeregisterRdsDbInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterRdsDbInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterRdsDbInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterRdsDbInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3918,15 +4028,19 @@ impl fmt::Display for DeregisterRdsDbInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeregisterRdsDbInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterRdsDbInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterRdsDbInstanceError {}
 /// Errors returned by DeregisterVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterVolumeError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterVolumeError {
@@ -3934,10 +4048,13 @@ impl DeregisterVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeregisterVolumeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3948,15 +4065,19 @@ impl fmt::Display for DeregisterVolumeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeregisterVolumeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterVolumeError {}
 /// Errors returned by DescribeAgentVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAgentVersionsError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAgentVersionsError {
@@ -3964,12 +4085,13 @@ impl DescribeAgentVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAgentVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAgentVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAgentVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3980,15 +4102,19 @@ impl fmt::Display for DescribeAgentVersionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeAgentVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeAgentVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAgentVersionsError {}
 /// Errors returned by DescribeApps
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAppsError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAppsError {
@@ -3996,10 +4122,10 @@ impl DescribeAppsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAppsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeAppsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAppsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4010,15 +4136,19 @@ impl fmt::Display for DescribeAppsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeAppsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeAppsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAppsError {}
 /// Errors returned by DescribeCommands
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCommandsError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCommandsError {
@@ -4026,10 +4156,13 @@ impl DescribeCommandsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCommandsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeCommandsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCommandsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4040,15 +4173,19 @@ impl fmt::Display for DescribeCommandsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeCommandsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeCommandsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCommandsError {}
 /// Errors returned by DescribeDeployments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDeploymentsError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDeploymentsError {
@@ -4056,12 +4193,13 @@ impl DescribeDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeploymentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDeploymentsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDeploymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4072,15 +4210,19 @@ impl fmt::Display for DescribeDeploymentsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeDeploymentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDeploymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDeploymentsError {}
 /// Errors returned by DescribeEcsClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEcsClustersError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEcsClustersError {
@@ -4088,12 +4230,13 @@ impl DescribeEcsClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEcsClustersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEcsClustersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEcsClustersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4104,15 +4247,19 @@ impl fmt::Display for DescribeEcsClustersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeEcsClustersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeEcsClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEcsClustersError {}
 /// Errors returned by DescribeElasticIps
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeElasticIpsError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeElasticIpsError {
@@ -4120,10 +4267,13 @@ impl DescribeElasticIpsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeElasticIpsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeElasticIpsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeElasticIpsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4134,15 +4284,19 @@ impl fmt::Display for DescribeElasticIpsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeElasticIpsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeElasticIpsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeElasticIpsError {}
 /// Errors returned by DescribeElasticLoadBalancers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeElasticLoadBalancersError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeElasticLoadBalancersError {
@@ -4152,12 +4306,18 @@ impl DescribeElasticLoadBalancersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeElasticLoadBalancersError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeElasticLoadBalancersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4170,15 +4330,19 @@ impl fmt::Display for DescribeElasticLoadBalancersError {
             DescribeElasticLoadBalancersError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeElasticLoadBalancersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeElasticLoadBalancersError {}
 /// Errors returned by DescribeInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInstancesError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInstancesError {
@@ -4186,10 +4350,13 @@ impl DescribeInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeInstancesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeInstancesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4200,15 +4367,19 @@ impl fmt::Display for DescribeInstancesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeInstancesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInstancesError {}
 /// Errors returned by DescribeLayers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLayersError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLayersError {
@@ -4216,10 +4387,13 @@ impl DescribeLayersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLayersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeLayersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLayersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4230,15 +4404,19 @@ impl fmt::Display for DescribeLayersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeLayersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeLayersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLayersError {}
 /// Errors returned by DescribeLoadBasedAutoScaling
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoadBasedAutoScalingError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoadBasedAutoScalingError {
@@ -4248,12 +4426,18 @@ impl DescribeLoadBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeLoadBasedAutoScalingError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeLoadBasedAutoScalingError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4266,20 +4450,25 @@ impl fmt::Display for DescribeLoadBasedAutoScalingError {
             DescribeLoadBasedAutoScalingError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLoadBasedAutoScalingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLoadBasedAutoScalingError {}
 /// Errors returned by DescribeMyUserProfile
 #[derive(Debug, PartialEq)]
-pub enum DescribeMyUserProfileError {}
+#[non_exhaustive]
+pub enum DescribeMyUserProfileError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeMyUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeMyUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMyUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4288,20 +4477,26 @@ impl DescribeMyUserProfileError {
 impl fmt::Display for DescribeMyUserProfileError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeMyUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeMyUserProfileError {}
 /// Errors returned by DescribeOperatingSystems
 #[derive(Debug, PartialEq)]
-pub enum DescribeOperatingSystemsError {}
+#[non_exhaustive]
+pub enum DescribeOperatingSystemsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeOperatingSystemsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeOperatingSystemsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeOperatingSystemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4310,15 +4505,20 @@ impl DescribeOperatingSystemsError {
 impl fmt::Display for DescribeOperatingSystemsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeOperatingSystemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeOperatingSystemsError {}
 /// Errors returned by DescribePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePermissionsError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePermissionsError {
@@ -4326,12 +4526,13 @@ impl DescribePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePermissionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4342,15 +4543,19 @@ impl fmt::Display for DescribePermissionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribePermissionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePermissionsError {}
 /// Errors returned by DescribeRaidArrays
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRaidArraysError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRaidArraysError {
@@ -4358,10 +4563,13 @@ impl DescribeRaidArraysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRaidArraysError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeRaidArraysError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRaidArraysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4372,15 +4580,19 @@ impl fmt::Display for DescribeRaidArraysError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeRaidArraysError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeRaidArraysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRaidArraysError {}
 /// Errors returned by DescribeRdsDbInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRdsDbInstancesError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRdsDbInstancesError {
@@ -4388,12 +4600,13 @@ impl DescribeRdsDbInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRdsDbInstancesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRdsDbInstancesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRdsDbInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4404,15 +4617,19 @@ impl fmt::Display for DescribeRdsDbInstancesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeRdsDbInstancesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeRdsDbInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRdsDbInstancesError {}
 /// Errors returned by DescribeServiceErrors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServiceErrorsError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServiceErrorsError {
@@ -4420,12 +4637,13 @@ impl DescribeServiceErrorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServiceErrorsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeServiceErrorsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeServiceErrorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4436,15 +4654,19 @@ impl fmt::Display for DescribeServiceErrorsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeServiceErrorsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeServiceErrorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeServiceErrorsError {}
 /// Errors returned by DescribeStackProvisioningParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStackProvisioningParametersError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStackProvisioningParametersError {
@@ -4454,12 +4676,18 @@ impl DescribeStackProvisioningParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeStackProvisioningParametersError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeStackProvisioningParametersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4472,15 +4700,21 @@ impl fmt::Display for DescribeStackProvisioningParametersError {
             DescribeStackProvisioningParametersError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeStackProvisioningParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeStackProvisioningParametersError {}
 /// Errors returned by DescribeStackSummary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStackSummaryError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStackSummaryError {
@@ -4488,12 +4722,13 @@ impl DescribeStackSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStackSummaryError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStackSummaryError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStackSummaryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4504,15 +4739,19 @@ impl fmt::Display for DescribeStackSummaryError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeStackSummaryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStackSummaryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStackSummaryError {}
 /// Errors returned by DescribeStacks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStacksError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStacksError {
@@ -4520,10 +4759,13 @@ impl DescribeStacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStacksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeStacksError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStacksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4534,15 +4776,19 @@ impl fmt::Display for DescribeStacksError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeStacksError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStacksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStacksError {}
 /// Errors returned by DescribeTimeBasedAutoScaling
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTimeBasedAutoScalingError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTimeBasedAutoScalingError {
@@ -4552,12 +4798,18 @@ impl DescribeTimeBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTimeBasedAutoScalingError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeTimeBasedAutoScalingError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4570,15 +4822,19 @@ impl fmt::Display for DescribeTimeBasedAutoScalingError {
             DescribeTimeBasedAutoScalingError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTimeBasedAutoScalingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTimeBasedAutoScalingError {}
 /// Errors returned by DescribeUserProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserProfilesError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserProfilesError {
@@ -4586,12 +4842,13 @@ impl DescribeUserProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserProfilesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4602,15 +4859,19 @@ impl fmt::Display for DescribeUserProfilesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeUserProfilesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeUserProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserProfilesError {}
 /// Errors returned by DescribeVolumes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVolumesError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVolumesError {
@@ -4618,10 +4879,13 @@ impl DescribeVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeVolumesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeVolumesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeVolumesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4632,15 +4896,19 @@ impl fmt::Display for DescribeVolumesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeVolumesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeVolumesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeVolumesError {}
 /// Errors returned by DetachElasticLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachElasticLoadBalancerError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachElasticLoadBalancerError {
@@ -4648,12 +4916,15 @@ impl DetachElasticLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachElasticLoadBalancerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetachElasticLoadBalancerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DetachElasticLoadBalancerError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4664,15 +4935,19 @@ impl fmt::Display for DetachElasticLoadBalancerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DetachElasticLoadBalancerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DetachElasticLoadBalancerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachElasticLoadBalancerError {}
 /// Errors returned by DisassociateElasticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateElasticIpError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateElasticIpError {
@@ -4680,12 +4955,13 @@ impl DisassociateElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateElasticIpError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateElasticIpError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateElasticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4696,15 +4972,19 @@ impl fmt::Display for DisassociateElasticIpError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DisassociateElasticIpError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateElasticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateElasticIpError {}
 /// Errors returned by GetHostnameSuggestion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetHostnameSuggestionError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetHostnameSuggestionError {
@@ -4712,12 +4992,13 @@ impl GetHostnameSuggestionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetHostnameSuggestionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetHostnameSuggestionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetHostnameSuggestionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4728,15 +5009,19 @@ impl fmt::Display for GetHostnameSuggestionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetHostnameSuggestionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetHostnameSuggestionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetHostnameSuggestionError {}
 /// Errors returned by GrantAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GrantAccessError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GrantAccessError {
@@ -4744,10 +5029,10 @@ impl GrantAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GrantAccessError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GrantAccessError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GrantAccessError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4758,15 +5043,19 @@ impl fmt::Display for GrantAccessError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GrantAccessError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GrantAccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GrantAccessError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
@@ -4774,10 +5063,10 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4788,15 +5077,19 @@ impl fmt::Display for ListTagsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by RebootInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootInstanceError {
@@ -4804,10 +5097,13 @@ impl RebootInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RebootInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RebootInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RebootInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4818,15 +5114,19 @@ impl fmt::Display for RebootInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RebootInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RebootInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootInstanceError {}
 /// Errors returned by RegisterEcsCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterEcsClusterError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterEcsClusterError {
@@ -4834,10 +5134,13 @@ impl RegisterEcsClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterEcsClusterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RegisterEcsClusterError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterEcsClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4848,15 +5151,19 @@ impl fmt::Display for RegisterEcsClusterError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RegisterEcsClusterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RegisterEcsClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterEcsClusterError {}
 /// Errors returned by RegisterElasticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterElasticIpError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterElasticIpError {
@@ -4864,10 +5171,13 @@ impl RegisterElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterElasticIpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RegisterElasticIpError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterElasticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4878,15 +5188,19 @@ impl fmt::Display for RegisterElasticIpError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RegisterElasticIpError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RegisterElasticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterElasticIpError {}
 /// Errors returned by RegisterInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterInstanceError {
@@ -4894,10 +5208,13 @@ impl RegisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RegisterInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4908,15 +5225,19 @@ impl fmt::Display for RegisterInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RegisterInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RegisterInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterInstanceError {}
 /// Errors returned by RegisterRdsDbInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterRdsDbInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterRdsDbInstanceError {
@@ -4924,12 +5245,13 @@ impl RegisterRdsDbInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterRdsDbInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterRdsDbInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterRdsDbInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4940,15 +5262,19 @@ impl fmt::Display for RegisterRdsDbInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RegisterRdsDbInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RegisterRdsDbInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterRdsDbInstanceError {}
 /// Errors returned by RegisterVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterVolumeError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterVolumeError {
@@ -4956,10 +5282,13 @@ impl RegisterVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RegisterVolumeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4970,15 +5299,19 @@ impl fmt::Display for RegisterVolumeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RegisterVolumeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RegisterVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterVolumeError {}
 /// Errors returned by SetLoadBasedAutoScaling
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetLoadBasedAutoScalingError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetLoadBasedAutoScalingError {
@@ -4986,12 +5319,13 @@ impl SetLoadBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetLoadBasedAutoScalingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetLoadBasedAutoScalingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetLoadBasedAutoScalingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5002,15 +5336,19 @@ impl fmt::Display for SetLoadBasedAutoScalingError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SetLoadBasedAutoScalingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            SetLoadBasedAutoScalingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetLoadBasedAutoScalingError {}
 /// Errors returned by SetPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetPermissionError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetPermissionError {
@@ -5018,10 +5356,13 @@ impl SetPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SetPermissionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetPermissionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5032,15 +5373,19 @@ impl fmt::Display for SetPermissionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SetPermissionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            SetPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetPermissionError {}
 /// Errors returned by SetTimeBasedAutoScaling
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetTimeBasedAutoScalingError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetTimeBasedAutoScalingError {
@@ -5048,12 +5393,13 @@ impl SetTimeBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetTimeBasedAutoScalingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetTimeBasedAutoScalingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetTimeBasedAutoScalingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5064,15 +5410,19 @@ impl fmt::Display for SetTimeBasedAutoScalingError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SetTimeBasedAutoScalingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            SetTimeBasedAutoScalingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetTimeBasedAutoScalingError {}
 /// Errors returned by StartInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartInstanceError {
@@ -5080,10 +5430,13 @@ impl StartInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5094,15 +5447,19 @@ impl fmt::Display for StartInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StartInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartInstanceError {}
 /// Errors returned by StartStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartStackError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartStackError {
@@ -5110,10 +5467,10 @@ impl StartStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StartStackError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5124,15 +5481,19 @@ impl fmt::Display for StartStackError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StartStackError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartStackError {}
 /// Errors returned by StopInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopInstanceError {
@@ -5140,10 +5501,10 @@ impl StopInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StopInstanceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5154,15 +5515,19 @@ impl fmt::Display for StopInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopInstanceError {}
 /// Errors returned by StopStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopStackError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopStackError {
@@ -5170,10 +5535,10 @@ impl StopStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StopStackError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5184,15 +5549,19 @@ impl fmt::Display for StopStackError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopStackError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopStackError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -5200,10 +5569,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5214,15 +5583,19 @@ impl fmt::Display for TagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UnassignInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnassignInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnassignInstanceError {
@@ -5230,10 +5603,13 @@ impl UnassignInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnassignInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UnassignInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnassignInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5244,15 +5620,19 @@ impl fmt::Display for UnassignInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UnassignInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UnassignInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnassignInstanceError {}
 /// Errors returned by UnassignVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnassignVolumeError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnassignVolumeError {
@@ -5260,10 +5640,13 @@ impl UnassignVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnassignVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UnassignVolumeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnassignVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5274,15 +5657,19 @@ impl fmt::Display for UnassignVolumeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UnassignVolumeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UnassignVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnassignVolumeError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -5290,10 +5677,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5304,15 +5694,19 @@ impl fmt::Display for UntagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAppError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAppError {
@@ -5320,10 +5714,10 @@ impl UpdateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateAppError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5334,15 +5728,19 @@ impl fmt::Display for UpdateAppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateAppError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAppError {}
 /// Errors returned by UpdateElasticIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateElasticIpError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateElasticIpError {
@@ -5350,10 +5748,13 @@ impl UpdateElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateElasticIpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateElasticIpError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateElasticIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5364,15 +5765,19 @@ impl fmt::Display for UpdateElasticIpError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateElasticIpError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateElasticIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateElasticIpError {}
 /// Errors returned by UpdateInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateInstanceError {
@@ -5380,10 +5785,13 @@ impl UpdateInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5394,15 +5802,19 @@ impl fmt::Display for UpdateInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateInstanceError {}
 /// Errors returned by UpdateLayer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLayerError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLayerError {
@@ -5410,10 +5822,10 @@ impl UpdateLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLayerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateLayerError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateLayerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5424,20 +5836,25 @@ impl fmt::Display for UpdateLayerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateLayerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateLayerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateLayerError {}
 /// Errors returned by UpdateMyUserProfile
 #[derive(Debug, PartialEq)]
-pub enum UpdateMyUserProfileError {}
+#[non_exhaustive]
+pub enum UpdateMyUserProfileError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UpdateMyUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateMyUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMyUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5446,15 +5863,20 @@ impl UpdateMyUserProfileError {
 impl fmt::Display for UpdateMyUserProfileError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UpdateMyUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateMyUserProfileError {}
 /// Errors returned by UpdateRdsDbInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRdsDbInstanceError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRdsDbInstanceError {
@@ -5462,12 +5884,13 @@ impl UpdateRdsDbInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRdsDbInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRdsDbInstanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRdsDbInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5478,15 +5901,19 @@ impl fmt::Display for UpdateRdsDbInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateRdsDbInstanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateRdsDbInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRdsDbInstanceError {}
 /// Errors returned by UpdateStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStackError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStackError {
@@ -5494,10 +5921,10 @@ impl UpdateStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateStackError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5508,15 +5935,19 @@ impl fmt::Display for UpdateStackError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateStackError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateStackError {}
 /// Errors returned by UpdateUserProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserProfileError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserProfileError {
@@ -5524,10 +5955,13 @@ impl UpdateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateUserProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5538,15 +5972,19 @@ impl fmt::Display for UpdateUserProfileError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateUserProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserProfileError {}
 /// Errors returned by UpdateVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVolumeError {
     /// <p>Indicates that a resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVolumeError {
@@ -5554,10 +5992,10 @@ impl UpdateVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateVolumeError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5568,6 +6006,7 @@ impl fmt::Display for UpdateVolumeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateVolumeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -6022,6 +6461,7 @@ impl OpsWorksClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> OpsWorksClient {
         OpsWorksClient {
             client: Client::shared(),
@@ -6047,97 +6487,209 @@ impl OpsWorksClient {
     pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksClient {
         OpsWorksClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<OpsWorksClient> {
+        rusoto_core::ClientBuilder::new(OpsWorksClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> OpsWorksClient {
+        OpsWorksClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl OpsWorks for OpsWorksClient {
-    /// <p>Assign a registered instance to a layer.</p> <ul> <li> <p>You can assign registered on-premises instances to any layer type.</p> </li> <li> <p>You can assign registered Amazon EC2 instances only to custom layers.</p> </li> <li> <p>You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> </li> </ul> <p> <b>Required Permissions</b>: To use this action, an AWS Identity and Access Management (IAM) user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn assign_instance(
+impl OpsWorksClient {
+    /// Calls `assign_instance`, also returning the metadata of the response.
+    pub async fn assign_instance_with_metadata(
         &self,
         input: AssignInstanceRequest,
-    ) -> Result<(), RusotoError<AssignInstanceError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<AssignInstanceError>> {
+        let request = self.build_assign_instance_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssignInstanceError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `assign_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_assign_instance(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssignInstanceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_assign_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_assign_instance_request(&self, input: AssignInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AssignInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.AssignInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `assign_volume`, also returning the metadata of the response.
+    pub async fn assign_volume_with_metadata(
+        &self,
+        input: AssignVolumeRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<AssignVolumeError>> {
+        let request = self.build_assign_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssignInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssignVolumeError::from_response(response))
         }
     }
 
-    /// <p>Assigns one of the stack's registered Amazon EBS volumes to a specified instance. The volume must first be registered with the stack by calling <a>RegisterVolume</a>. After you register the volume, you must call <a>UpdateVolume</a> to specify a mount point before calling <code>AssignVolume</code>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn assign_volume(
+    /// Presigns the request `assign_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_assign_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssignVolumeRequest,
-    ) -> Result<(), RusotoError<AssignVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_assign_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_assign_volume_request(&self, input: AssignVolumeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AssignVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.AssignVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_elastic_ip`, also returning the metadata of the response.
+    pub async fn associate_elastic_ip_with_metadata(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<AssociateElasticIpError>> {
+        let request = self.build_associate_elastic_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssignVolumeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateElasticIpError::from_response(response))
         }
     }
 
-    /// <p>Associates one of the stack's registered Elastic IP addresses with a specified instance. The address must first be registered with the stack by calling <a>RegisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn associate_elastic_ip(
+    /// Presigns the request `associate_elastic_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_elastic_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateElasticIpRequest,
-    ) -> Result<(), RusotoError<AssociateElasticIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_elastic_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_elastic_ip_request(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AssociateElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.AssociateElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `attach_elastic_load_balancer`, also returning the metadata of the response.
+    pub async fn attach_elastic_load_balancer_with_metadata(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<AttachElasticLoadBalancerError>> {
+        let request = self.build_attach_elastic_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateElasticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AttachElasticLoadBalancerError::from_response(response))
         }
     }
 
-    /// <p>Attaches an Elastic Load Balancing load balancer to a specified layer. AWS OpsWorks Stacks does not support Application Load Balancer. You can only use Classic Load Balancer with AWS OpsWorks Stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/layers-elb.html">Elastic Load Balancing</a>.</p> <note> <p>You must create the Elastic Load Balancing instance separately, by using the Elastic Load Balancing console, API, or CLI. For more information, see <a href="https://docs.aws.amazon.com/ElasticLoadBalancing/latest/DeveloperGuide/Welcome.html"> Elastic Load Balancing Developer Guide</a>.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn attach_elastic_load_balancer(
+    /// Presigns the request `attach_elastic_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_elastic_load_balancer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachElasticLoadBalancerRequest,
-    ) -> Result<(), RusotoError<AttachElasticLoadBalancerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_elastic_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_elastic_load_balancer_request(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AttachElasticLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6147,650 +6699,1234 @@ impl OpsWorks for OpsWorksClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `clone_stack`, also returning the metadata of the response.
+    pub async fn clone_stack_with_metadata(
+        &self,
+        input: CloneStackRequest,
+    ) -> Result<(CloneStackResult, ResponseMetadata), RusotoError<CloneStackError>> {
+        let request = self.build_clone_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CloneStackResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachElasticLoadBalancerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CloneStackError::from_response(response))
         }
     }
 
-    /// <p>Creates a clone of a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-cloning.html">Clone a Stack</a>. By default, all parameters are set to the values used by the parent stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn clone_stack(
+    /// Presigns the request `clone_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_clone_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CloneStackRequest,
-    ) -> Result<CloneStackResult, RusotoError<CloneStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_clone_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_clone_stack_request(&self, input: CloneStackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CloneStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CloneStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_app`, also returning the metadata of the response.
+    pub async fn create_app_with_metadata(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<(CreateAppResult, ResponseMetadata), RusotoError<CreateAppError>> {
+        let request = self.build_create_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CloneStackResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CloneStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAppError::from_response(response))
         }
     }
 
-    /// <p>Creates an app for a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-creating.html">Creating Apps</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn create_app(
+    /// Presigns the request `create_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAppRequest,
-    ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_app_request(&self, input: CreateAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_deployment`, also returning the metadata of the response.
+    pub async fn create_deployment_with_metadata(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<(CreateDeploymentResult, ResponseMetadata), RusotoError<CreateDeploymentError>>
+    {
+        let request = self.build_create_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDeploymentResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Runs deployment or stack commands. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-deploying.html">Deploying Apps</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-commands.html">Run Stack Commands</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn create_deployment(
+    /// Presigns the request `create_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDeploymentRequest,
-    ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_request(&self, input: CreateDeploymentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_instance`, also returning the metadata of the response.
+    pub async fn create_instance_with_metadata(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> Result<(CreateInstanceResult, ResponseMetadata), RusotoError<CreateInstanceError>> {
+        let request = self.build_create_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDeploymentResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeploymentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateInstanceError::from_response(response))
         }
     }
 
-    /// <p>Creates an instance in a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html">Adding an Instance to a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn create_instance(
+    /// Presigns the request `create_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateInstanceRequest,
-    ) -> Result<CreateInstanceResult, RusotoError<CreateInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_instance_request(&self, input: CreateInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_layer`, also returning the metadata of the response.
+    pub async fn create_layer_with_metadata(
+        &self,
+        input: CreateLayerRequest,
+    ) -> Result<(CreateLayerResult, ResponseMetadata), RusotoError<CreateLayerError>> {
+        let request = self.build_create_layer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLayerResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLayerError::from_response(response))
         }
     }
 
-    /// <p>Creates a layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-create.html">How to Create a Layer</a>.</p> <note> <p>You should use <b>CreateLayer</b> for noncustom layer types such as PHP App Server only if the stack does not have an existing layer of that type. A stack can have at most one instance of each noncustom layer; if you attempt to create a second instance, <b>CreateLayer</b> fails. A stack can have an arbitrary number of custom layers, so you can call <b>CreateLayer</b> as many times as you like for that layer type.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn create_layer(
+    /// Presigns the request `create_layer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_layer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLayerRequest,
-    ) -> Result<CreateLayerResult, RusotoError<CreateLayerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_layer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_layer_request(&self, input: CreateLayerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateLayer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateLayer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_stack`, also returning the metadata of the response.
+    pub async fn create_stack_with_metadata(
+        &self,
+        input: CreateStackRequest,
+    ) -> Result<(CreateStackResult, ResponseMetadata), RusotoError<CreateStackError>> {
+        let request = self.build_create_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateLayerResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateStackResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLayerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateStackError::from_response(response))
         }
     }
 
-    /// <p>Creates a new stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-edit.html">Create a New Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn create_stack(
+    /// Presigns the request `create_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateStackRequest,
-    ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stack_request(&self, input: CreateStackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user_profile`, also returning the metadata of the response.
+    pub async fn create_user_profile_with_metadata(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<(CreateUserProfileResult, ResponseMetadata), RusotoError<CreateUserProfileError>>
+    {
+        let request = self.build_create_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateStackResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates a new user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn create_user_profile(
+    /// Presigns the request `create_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserProfileRequest,
-    ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_profile_request(&self, input: CreateUserProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_app`, also returning the metadata of the response.
+    pub async fn delete_app_with_metadata(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAppError>> {
+        let request = self.build_delete_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateUserProfileResult, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAppError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
+    /// Presigns the request `delete_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_app(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteAppRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_app_request(&self, input: DeleteAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_instance`, also returning the metadata of the response.
+    pub async fn delete_instance_with_metadata(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteInstanceError>> {
+        let request = self.build_delete_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteInstanceError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified instance, which terminates the associated Amazon EC2 instance. You must stop an instance before you can delete it.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-delete.html">Deleting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn delete_instance(
+    /// Presigns the request `delete_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInstanceRequest,
-    ) -> Result<(), RusotoError<DeleteInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_instance_request(&self, input: DeleteInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `delete_layer`, also returning the metadata of the response.
+    pub async fn delete_layer_with_metadata(
+        &self,
+        input: DeleteLayerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteLayerError>> {
+        let request = self.build_delete_layer_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLayerError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified layer. You must first stop and then delete all associated instances or unassign registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-delete.html">How to Delete a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn delete_layer(
+    /// Presigns the request `delete_layer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_layer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLayerRequest,
-    ) -> Result<(), RusotoError<DeleteLayerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_layer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_layer_request(&self, input: DeleteLayerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteLayer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteLayer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_stack`, also returning the metadata of the response.
+    pub async fn delete_stack_with_metadata(
+        &self,
+        input: DeleteStackRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteStackError>> {
+        let request = self.build_delete_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLayerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteStackError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified stack. You must first delete all instances, layers, and apps or deregister registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-shutting.html">Shut Down a Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn delete_stack(
+    /// Presigns the request `delete_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteStackRequest,
-    ) -> Result<(), RusotoError<DeleteStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stack_request(&self, input: DeleteStackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user_profile`, also returning the metadata of the response.
+    pub async fn delete_user_profile_with_metadata(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUserProfileError>> {
+        let request = self.build_delete_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Deletes a user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn delete_user_profile(
+    /// Presigns the request `delete_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserProfileRequest,
-    ) -> Result<(), RusotoError<DeleteUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_profile_request(&self, input: DeleteUserProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_ecs_cluster`, also returning the metadata of the response.
+    pub async fn deregister_ecs_cluster_with_metadata(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeregisterEcsClusterError>> {
+        let request = self.build_deregister_ecs_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterEcsClusterError::from_response(response))
         }
     }
 
-    /// <p>Deregisters a specified Amazon ECS cluster from a stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html#workinglayers-ecscluster-delete"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html</a>.</p>
-    async fn deregister_ecs_cluster(
+    /// Presigns the request `deregister_ecs_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_ecs_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterEcsClusterRequest,
-    ) -> Result<(), RusotoError<DeregisterEcsClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_ecs_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_ecs_cluster_request(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterEcsCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterEcsCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_elastic_ip`, also returning the metadata of the response.
+    pub async fn deregister_elastic_ip_with_metadata(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeregisterElasticIpError>> {
+        let request = self.build_deregister_elastic_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterEcsClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterElasticIpError::from_response(response))
         }
     }
 
-    /// <p>Deregisters a specified Elastic IP address. The address can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn deregister_elastic_ip(
+    /// Presigns the request `deregister_elastic_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_elastic_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterElasticIpRequest,
-    ) -> Result<(), RusotoError<DeregisterElasticIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_elastic_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_elastic_ip_request(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_instance`, also returning the metadata of the response.
+    pub async fn deregister_instance_with_metadata(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeregisterInstanceError>> {
+        let request = self.build_deregister_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterElasticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterInstanceError::from_response(response))
         }
     }
 
-    /// <p>Deregister a registered Amazon EC2 or on-premises instance. This action removes the instance from the stack and returns it to your control. This action cannot be used with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn deregister_instance(
+    /// Presigns the request `deregister_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterInstanceRequest,
-    ) -> Result<(), RusotoError<DeregisterInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_instance_request(&self, input: DeregisterInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_rds_db_instance`, also returning the metadata of the response.
+    pub async fn deregister_rds_db_instance_with_metadata(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeregisterRdsDbInstanceError>> {
+        let request = self.build_deregister_rds_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterRdsDbInstanceError::from_response(response))
         }
     }
 
-    /// <p>Deregisters an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn deregister_rds_db_instance(
+    /// Presigns the request `deregister_rds_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_rds_db_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterRdsDbInstanceRequest,
-    ) -> Result<(), RusotoError<DeregisterRdsDbInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_rds_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_rds_db_instance_request(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterRdsDbInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterRdsDbInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_volume`, also returning the metadata of the response.
+    pub async fn deregister_volume_with_metadata(
+        &self,
+        input: DeregisterVolumeRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeregisterVolumeError>> {
+        let request = self.build_deregister_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterRdsDbInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterVolumeError::from_response(response))
         }
     }
 
-    /// <p>Deregisters an Amazon EBS volume. The volume can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn deregister_volume(
+    /// Presigns the request `deregister_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterVolumeRequest,
-    ) -> Result<(), RusotoError<DeregisterVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_volume_request(&self, input: DeregisterVolumeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_agent_versions`, also returning the metadata of the response.
+    pub async fn describe_agent_versions_with_metadata(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> Result<
+        (DescribeAgentVersionsResult, ResponseMetadata),
+        RusotoError<DescribeAgentVersionsError>,
+    > {
+        let request = self.build_describe_agent_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAgentVersionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterVolumeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAgentVersionsError::from_response(response))
         }
     }
 
-    /// <p>Describes the available AWS OpsWorks Stacks agent versions. You must specify a stack ID or a configuration manager. <code>DescribeAgentVersions</code> returns a list of available agent versions for the specified stack or configuration manager.</p>
-    async fn describe_agent_versions(
+    /// Presigns the request `describe_agent_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_agent_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAgentVersionsRequest,
-    ) -> Result<DescribeAgentVersionsResult, RusotoError<DescribeAgentVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_agent_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_agent_versions_request(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeAgentVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeAgentVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_apps`, also returning the metadata of the response.
+    pub async fn describe_apps_with_metadata(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> Result<(DescribeAppsResult, ResponseMetadata), RusotoError<DescribeAppsError>> {
+        let request = self.build_describe_apps_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAgentVersionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAppsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAgentVersionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAppsError::from_response(response))
         }
     }
 
-    /// <p>Requests a description of a specified set of apps.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_apps(
+    /// Presigns the request `describe_apps` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_apps(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAppsRequest,
-    ) -> Result<DescribeAppsResult, RusotoError<DescribeAppsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_apps_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_apps_request(&self, input: DescribeAppsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeApps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeApps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_commands`, also returning the metadata of the response.
+    pub async fn describe_commands_with_metadata(
+        &self,
+        input: DescribeCommandsRequest,
+    ) -> Result<(DescribeCommandsResult, ResponseMetadata), RusotoError<DescribeCommandsError>>
+    {
+        let request = self.build_describe_commands_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAppsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCommandsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAppsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCommandsError::from_response(response))
         }
     }
 
-    /// <p>Describes the results of specified commands.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_commands(
+    /// Presigns the request `describe_commands` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_commands(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCommandsRequest,
-    ) -> Result<DescribeCommandsResult, RusotoError<DescribeCommandsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_commands_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_commands_request(&self, input: DescribeCommandsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeCommands");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeCommands");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_deployments`, also returning the metadata of the response.
+    pub async fn describe_deployments_with_metadata(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> Result<(DescribeDeploymentsResult, ResponseMetadata), RusotoError<DescribeDeploymentsError>>
+    {
+        let request = self.build_describe_deployments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeCommandsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDeploymentsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCommandsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDeploymentsError::from_response(response))
         }
     }
 
-    /// <p>Requests a description of a specified set of deployments.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_deployments(
+    /// Presigns the request `describe_deployments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_deployments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDeploymentsRequest,
-    ) -> Result<DescribeDeploymentsResult, RusotoError<DescribeDeploymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_deployments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_deployments_request(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeDeployments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeDeployments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_ecs_clusters`, also returning the metadata of the response.
+    pub async fn describe_ecs_clusters_with_metadata(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> Result<(DescribeEcsClustersResult, ResponseMetadata), RusotoError<DescribeEcsClustersError>>
+    {
+        let request = self.build_describe_ecs_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDeploymentsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEcsClustersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDeploymentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEcsClustersError::from_response(response))
         }
     }
 
-    /// <p>Describes Amazon ECS clusters that are registered with a stack. If you specify only a stack ID, you can use the <code>MaxResults</code> and <code>NextToken</code> parameters to paginate the response. However, AWS OpsWorks Stacks currently supports only one cluster per layer, so the result set has a maximum of one element.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permission. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
-    async fn describe_ecs_clusters(
+    /// Presigns the request `describe_ecs_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_ecs_clusters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEcsClustersRequest,
-    ) -> Result<DescribeEcsClustersResult, RusotoError<DescribeEcsClustersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_ecs_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_ecs_clusters_request(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeEcsClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeEcsClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_elastic_ips`, also returning the metadata of the response.
+    pub async fn describe_elastic_ips_with_metadata(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> Result<(DescribeElasticIpsResult, ResponseMetadata), RusotoError<DescribeElasticIpsError>>
+    {
+        let request = self.build_describe_elastic_ips_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEcsClustersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeElasticIpsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEcsClustersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeElasticIpsError::from_response(response))
         }
     }
 
-    /// <p>Describes <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP addresses</a>.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_elastic_ips(
+    /// Presigns the request `describe_elastic_ips` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_elastic_ips(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeElasticIpsRequest,
-    ) -> Result<DescribeElasticIpsResult, RusotoError<DescribeElasticIpsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_elastic_ips_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_elastic_ips_request(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeElasticIps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeElasticIps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_elastic_load_balancers`, also returning the metadata of the response.
+    pub async fn describe_elastic_load_balancers_with_metadata(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> Result<
+        (DescribeElasticLoadBalancersResult, ResponseMetadata),
+        RusotoError<DescribeElasticLoadBalancersError>,
+    > {
+        let request = self.build_describe_elastic_load_balancers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeElasticIpsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeElasticLoadBalancersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeElasticIpsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeElasticLoadBalancersError::from_response(response))
         }
     }
 
-    /// <p>Describes a stack's Elastic Load Balancing instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_elastic_load_balancers(
+    /// Presigns the request `describe_elastic_load_balancers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_elastic_load_balancers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeElasticLoadBalancersRequest,
-    ) -> Result<DescribeElasticLoadBalancersResult, RusotoError<DescribeElasticLoadBalancersError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_elastic_load_balancers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_elastic_load_balancers_request(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeElasticLoadBalancers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6800,83 +7936,163 @@ impl OpsWorks for OpsWorksClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_instances`, also returning the metadata of the response.
+    pub async fn describe_instances_with_metadata(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<(DescribeInstancesResult, ResponseMetadata), RusotoError<DescribeInstancesError>>
+    {
+        let request = self.build_describe_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeElasticLoadBalancersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeInstancesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeElasticLoadBalancersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInstancesError::from_response(response))
         }
     }
 
-    /// <p>Requests a description of a set of instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_instances(
+    /// Presigns the request `describe_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInstancesRequest,
-    ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_instances_request(&self, input: DescribeInstancesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_layers`, also returning the metadata of the response.
+    pub async fn describe_layers_with_metadata(
+        &self,
+        input: DescribeLayersRequest,
+    ) -> Result<(DescribeLayersResult, ResponseMetadata), RusotoError<DescribeLayersError>> {
+        let request = self.build_describe_layers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeInstancesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLayersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLayersError::from_response(response))
         }
     }
 
-    /// <p>Requests a description of one or more layers in a specified stack.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_layers(
+    /// Presigns the request `describe_layers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_layers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLayersRequest,
-    ) -> Result<DescribeLayersResult, RusotoError<DescribeLayersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_layers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_layers_request(&self, input: DescribeLayersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeLayers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeLayers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_load_based_auto_scaling`, also returning the metadata of the response.
+    pub async fn describe_load_based_auto_scaling_with_metadata(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> Result<
+        (DescribeLoadBasedAutoScalingResult, ResponseMetadata),
+        RusotoError<DescribeLoadBasedAutoScalingError>,
+    > {
+        let request = self.build_describe_load_based_auto_scaling_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeLayersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLoadBasedAutoScalingResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLayersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLoadBasedAutoScalingError::from_response(response))
         }
     }
 
-    /// <p>Describes load-based auto scaling configurations for specified layers.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_load_based_auto_scaling(
+    /// Presigns the request `describe_load_based_auto_scaling` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_load_based_auto_scaling(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLoadBasedAutoScalingRequest,
-    ) -> Result<DescribeLoadBasedAutoScalingResult, RusotoError<DescribeLoadBasedAutoScalingError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_load_based_auto_scaling_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_load_based_auto_scaling_request(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeLoadBasedAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6886,195 +8102,392 @@ impl OpsWorks for OpsWorksClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
+        request
+    }
+
+    /// Calls `describe_my_user_profile`, also returning the metadata of the response.
+    pub async fn describe_my_user_profile_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeMyUserProfileResult, ResponseMetadata),
+        RusotoError<DescribeMyUserProfileError>,
+    > {
+        let request = self.build_describe_my_user_profile_request();
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLoadBasedAutoScalingResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMyUserProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLoadBasedAutoScalingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMyUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Describes a user's SSH information.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_my_user_profile(
+    /// Presigns the request `describe_my_user_profile` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_my_user_profile(
         &self,
-    ) -> Result<DescribeMyUserProfileResult, RusotoError<DescribeMyUserProfileError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_my_user_profile_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_my_user_profile_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeMyUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeMyUserProfile");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_operating_systems`, also returning the metadata of the response.
+    pub async fn describe_operating_systems_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeOperatingSystemsResponse, ResponseMetadata),
+        RusotoError<DescribeOperatingSystemsError>,
+    > {
+        let request = self.build_describe_operating_systems_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMyUserProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeOperatingSystemsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMyUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeOperatingSystemsError::from_response(response))
         }
     }
 
-    /// <p>Describes the operating systems that are supported by AWS OpsWorks Stacks.</p>
-    async fn describe_operating_systems(
+    /// Presigns the request `describe_operating_systems` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_operating_systems(
         &self,
-    ) -> Result<DescribeOperatingSystemsResponse, RusotoError<DescribeOperatingSystemsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_operating_systems_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_operating_systems_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeOperatingSystems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeOperatingSystems");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_permissions`, also returning the metadata of the response.
+    pub async fn describe_permissions_with_metadata(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> Result<(DescribePermissionsResult, ResponseMetadata), RusotoError<DescribePermissionsError>>
+    {
+        let request = self.build_describe_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOperatingSystemsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePermissionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOperatingSystemsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Describes the permissions for a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_permissions(
+    /// Presigns the request `describe_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePermissionsRequest,
-    ) -> Result<DescribePermissionsResult, RusotoError<DescribePermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_permissions_request(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribePermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribePermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_raid_arrays`, also returning the metadata of the response.
+    pub async fn describe_raid_arrays_with_metadata(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> Result<(DescribeRaidArraysResult, ResponseMetadata), RusotoError<DescribeRaidArraysError>>
+    {
+        let request = self.build_describe_raid_arrays_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePermissionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRaidArraysResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRaidArraysError::from_response(response))
         }
     }
 
-    /// <p>Describe an instance's RAID arrays.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_raid_arrays(
+    /// Presigns the request `describe_raid_arrays` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_raid_arrays(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRaidArraysRequest,
-    ) -> Result<DescribeRaidArraysResult, RusotoError<DescribeRaidArraysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_raid_arrays_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_raid_arrays_request(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeRaidArrays");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRaidArrays");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_rds_db_instances`, also returning the metadata of the response.
+    pub async fn describe_rds_db_instances_with_metadata(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> Result<
+        (DescribeRdsDbInstancesResult, ResponseMetadata),
+        RusotoError<DescribeRdsDbInstancesError>,
+    > {
+        let request = self.build_describe_rds_db_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRaidArraysResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRdsDbInstancesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRaidArraysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRdsDbInstancesError::from_response(response))
         }
     }
 
-    /// <p>Describes Amazon RDS instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
-    async fn describe_rds_db_instances(
+    /// Presigns the request `describe_rds_db_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_rds_db_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRdsDbInstancesRequest,
-    ) -> Result<DescribeRdsDbInstancesResult, RusotoError<DescribeRdsDbInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_rds_db_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_rds_db_instances_request(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeRdsDbInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRdsDbInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_service_errors`, also returning the metadata of the response.
+    pub async fn describe_service_errors_with_metadata(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> Result<
+        (DescribeServiceErrorsResult, ResponseMetadata),
+        RusotoError<DescribeServiceErrorsError>,
+    > {
+        let request = self.build_describe_service_errors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRdsDbInstancesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeServiceErrorsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRdsDbInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeServiceErrorsError::from_response(response))
         }
     }
 
-    /// <p>Describes AWS OpsWorks Stacks service errors.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
-    async fn describe_service_errors(
+    /// Presigns the request `describe_service_errors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_service_errors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeServiceErrorsRequest,
-    ) -> Result<DescribeServiceErrorsResult, RusotoError<DescribeServiceErrorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_service_errors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_service_errors_request(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeServiceErrors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeServiceErrors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_stack_provisioning_parameters`, also returning the metadata of the response.
+    pub async fn describe_stack_provisioning_parameters_with_metadata(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> Result<
+        (DescribeStackProvisioningParametersResult, ResponseMetadata),
+        RusotoError<DescribeStackProvisioningParametersError>,
+    > {
+        let request = self.build_describe_stack_provisioning_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeServiceErrorsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStackProvisioningParametersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeServiceErrorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStackProvisioningParametersError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Requests a description of a stack's provisioning parameters.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_stack_provisioning_parameters(
+    /// Presigns the request `describe_stack_provisioning_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_provisioning_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStackProvisioningParametersRequest,
-    ) -> Result<
-        DescribeStackProvisioningParametersResult,
-        RusotoError<DescribeStackProvisioningParametersError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_provisioning_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_provisioning_parameters_request(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeStackProvisioningParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7084,86 +8497,168 @@ impl OpsWorks for OpsWorksClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_stack_summary`, also returning the metadata of the response.
+    pub async fn describe_stack_summary_with_metadata(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> Result<
+        (DescribeStackSummaryResult, ResponseMetadata),
+        RusotoError<DescribeStackSummaryError>,
+    > {
+        let request = self.build_describe_stack_summary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeStackProvisioningParametersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStackSummaryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStackProvisioningParametersError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStackSummaryError::from_response(response))
         }
     }
 
-    /// <p>Describes the number of layers and apps in a specified stack, and the number of instances in each state, such as <code>running_setup</code> or <code>online</code>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_stack_summary(
+    /// Presigns the request `describe_stack_summary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_summary(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStackSummaryRequest,
-    ) -> Result<DescribeStackSummaryResult, RusotoError<DescribeStackSummaryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_summary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_summary_request(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeStackSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStackSummary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_stacks`, also returning the metadata of the response.
+    pub async fn describe_stacks_with_metadata(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> Result<(DescribeStacksResult, ResponseMetadata), RusotoError<DescribeStacksError>> {
+        let request = self.build_describe_stacks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeStackSummaryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStacksResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStackSummaryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStacksError::from_response(response))
         }
     }
 
-    /// <p>Requests a description of one or more stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_stacks(
+    /// Presigns the request `describe_stacks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stacks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStacksRequest,
-    ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stacks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stacks_request(&self, input: DescribeStacksRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeStacks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStacks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_time_based_auto_scaling`, also returning the metadata of the response.
+    pub async fn describe_time_based_auto_scaling_with_metadata(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> Result<
+        (DescribeTimeBasedAutoScalingResult, ResponseMetadata),
+        RusotoError<DescribeTimeBasedAutoScalingError>,
+    > {
+        let request = self.build_describe_time_based_auto_scaling_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeStacksResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTimeBasedAutoScalingResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStacksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTimeBasedAutoScalingError::from_response(response))
         }
     }
 
-    /// <p>Describes time-based auto scaling configurations for specified instances.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_time_based_auto_scaling(
+    /// Presigns the request `describe_time_based_auto_scaling` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_time_based_auto_scaling(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTimeBasedAutoScalingRequest,
-    ) -> Result<DescribeTimeBasedAutoScalingResult, RusotoError<DescribeTimeBasedAutoScalingError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_time_based_auto_scaling_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_time_based_auto_scaling_request(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeTimeBasedAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7173,83 +8668,160 @@ impl OpsWorks for OpsWorksClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_user_profiles`, also returning the metadata of the response.
+    pub async fn describe_user_profiles_with_metadata(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> Result<
+        (DescribeUserProfilesResult, ResponseMetadata),
+        RusotoError<DescribeUserProfilesError>,
+    > {
+        let request = self.build_describe_user_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTimeBasedAutoScalingResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserProfilesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTimeBasedAutoScalingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUserProfilesError::from_response(response))
         }
     }
 
-    /// <p>Describe specified users.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_user_profiles(
+    /// Presigns the request `describe_user_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserProfilesRequest,
-    ) -> Result<DescribeUserProfilesResult, RusotoError<DescribeUserProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_profiles_request(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeUserProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeUserProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_volumes`, also returning the metadata of the response.
+    pub async fn describe_volumes_with_metadata(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> Result<(DescribeVolumesResult, ResponseMetadata), RusotoError<DescribeVolumesError>> {
+        let request = self.build_describe_volumes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserProfilesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeVolumesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserProfilesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeVolumesError::from_response(response))
         }
     }
 
-    /// <p>Describes an instance's Amazon EBS volumes.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn describe_volumes(
+    /// Presigns the request `describe_volumes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_volumes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeVolumesRequest,
-    ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_volumes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_volumes_request(&self, input: DescribeVolumesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeVolumes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeVolumes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detach_elastic_load_balancer`, also returning the metadata of the response.
+    pub async fn detach_elastic_load_balancer_with_metadata(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DetachElasticLoadBalancerError>> {
+        let request = self.build_detach_elastic_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeVolumesResult, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVolumesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetachElasticLoadBalancerError::from_response(response))
         }
     }
 
-    /// <p>Detaches a specified Elastic Load Balancing instance from its layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn detach_elastic_load_balancer(
+    /// Presigns the request `detach_elastic_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_elastic_load_balancer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachElasticLoadBalancerRequest,
-    ) -> Result<(), RusotoError<DetachElasticLoadBalancerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_elastic_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_elastic_load_balancer_request(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DetachElasticLoadBalancer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7259,824 +8831,2219 @@ impl OpsWorks for OpsWorksClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_elastic_ip`, also returning the metadata of the response.
+    pub async fn disassociate_elastic_ip_with_metadata(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisassociateElasticIpError>> {
+        let request = self.build_disassociate_elastic_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachElasticLoadBalancerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateElasticIpError::from_response(response))
         }
     }
 
-    /// <p>Disassociates an Elastic IP address from its instance. The address remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn disassociate_elastic_ip(
+    /// Presigns the request `disassociate_elastic_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_elastic_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateElasticIpRequest,
-    ) -> Result<(), RusotoError<DisassociateElasticIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_elastic_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_elastic_ip_request(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DisassociateElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.DisassociateElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_hostname_suggestion`, also returning the metadata of the response.
+    pub async fn get_hostname_suggestion_with_metadata(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> Result<
+        (GetHostnameSuggestionResult, ResponseMetadata),
+        RusotoError<GetHostnameSuggestionError>,
+    > {
+        let request = self.build_get_hostname_suggestion_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetHostnameSuggestionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateElasticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetHostnameSuggestionError::from_response(response))
         }
     }
 
-    /// <p>Gets a generated host name for the specified layer, based on the current host name theme.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn get_hostname_suggestion(
+    /// Presigns the request `get_hostname_suggestion` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_hostname_suggestion(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetHostnameSuggestionRequest,
-    ) -> Result<GetHostnameSuggestionResult, RusotoError<GetHostnameSuggestionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_hostname_suggestion_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_hostname_suggestion_request(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("GetHostnameSuggestion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.GetHostnameSuggestion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `grant_access`, also returning the metadata of the response.
+    pub async fn grant_access_with_metadata(
+        &self,
+        input: GrantAccessRequest,
+    ) -> Result<(GrantAccessResult, ResponseMetadata), RusotoError<GrantAccessError>> {
+        let request = self.build_grant_access_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetHostnameSuggestionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GrantAccessResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetHostnameSuggestionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GrantAccessError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This action can be used only with Windows stacks.</p> </note> <p>Grants RDP access to a Windows instance for a specified time period.</p></p>
-    async fn grant_access(
+    /// Presigns the request `grant_access` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_grant_access(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GrantAccessRequest,
-    ) -> Result<GrantAccessResult, RusotoError<GrantAccessError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_grant_access_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_grant_access_request(&self, input: GrantAccessRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("GrantAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.GrantAccess");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<(ListTagsResult, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GrantAccessResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GrantAccessError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of tags that are applied to the specified stack or layer.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsRequest,
-    ) -> Result<ListTagsResult, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reboot_instance`, also returning the metadata of the response.
+    pub async fn reboot_instance_with_metadata(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RebootInstanceError>> {
+        let request = self.build_reboot_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResult, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RebootInstanceError::from_response(response))
         }
     }
 
-    /// <p>Reboots a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn reboot_instance(
+    /// Presigns the request `reboot_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RebootInstanceRequest,
-    ) -> Result<(), RusotoError<RebootInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_instance_request(&self, input: RebootInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RebootInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RebootInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_ecs_cluster`, also returning the metadata of the response.
+    pub async fn register_ecs_cluster_with_metadata(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> Result<(RegisterEcsClusterResult, ResponseMetadata), RusotoError<RegisterEcsClusterError>>
+    {
+        let request = self.build_register_ecs_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterEcsClusterResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RebootInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterEcsClusterError::from_response(response))
         }
     }
 
-    /// <p>Registers a specified Amazon ECS cluster with a stack. You can register only one cluster with a stack. A cluster can be registered with only one stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html"> Managing User Permissions</a>.</p>
-    async fn register_ecs_cluster(
+    /// Presigns the request `register_ecs_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_ecs_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterEcsClusterRequest,
-    ) -> Result<RegisterEcsClusterResult, RusotoError<RegisterEcsClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_ecs_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_ecs_cluster_request(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterEcsCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterEcsCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_elastic_ip`, also returning the metadata of the response.
+    pub async fn register_elastic_ip_with_metadata(
+        &self,
+        input: RegisterElasticIpRequest,
+    ) -> Result<(RegisterElasticIpResult, ResponseMetadata), RusotoError<RegisterElasticIpError>>
+    {
+        let request = self.build_register_elastic_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterEcsClusterResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterElasticIpResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterEcsClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterElasticIpError::from_response(response))
         }
     }
 
-    /// <p>Registers an Elastic IP address with a specified stack. An address can be registered with only one stack at a time. If the address is already registered, you must first deregister it by calling <a>DeregisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn register_elastic_ip(
+    /// Presigns the request `register_elastic_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_elastic_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterElasticIpRequest,
-    ) -> Result<RegisterElasticIpResult, RusotoError<RegisterElasticIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_elastic_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_elastic_ip_request(&self, input: RegisterElasticIpRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_instance`, also returning the metadata of the response.
+    pub async fn register_instance_with_metadata(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> Result<(RegisterInstanceResult, ResponseMetadata), RusotoError<RegisterInstanceError>>
+    {
+        let request = self.build_register_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RegisterElasticIpResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterElasticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterInstanceError::from_response(response))
         }
     }
 
-    /// <p>Registers instances that were created outside of AWS OpsWorks Stacks with a specified stack.</p> <note> <p>We do not recommend using this action to register instances. The complete registration operation includes two tasks: installing the AWS OpsWorks Stacks agent on the instance, and registering the instance with the stack. <code>RegisterInstance</code> handles only the second step. You should instead use the AWS CLI <code>register</code> command, which performs the entire registration operation. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register.html"> Registering an Instance with an AWS OpsWorks Stacks Stack</a>.</p> </note> <p>Registered instances have the same requirements as instances that are created by using the <a>CreateInstance</a> API. For example, registered instances must be running a supported Linux-based operating system, and they must have a supported instance type. For more information about requirements for instances that you want to register, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register-registering-preparer.html"> Preparing the Instance</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn register_instance(
+    /// Presigns the request `register_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterInstanceRequest,
-    ) -> Result<RegisterInstanceResult, RusotoError<RegisterInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_instance_request(&self, input: RegisterInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_rds_db_instance`, also returning the metadata of the response.
+    pub async fn register_rds_db_instance_with_metadata(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RegisterRdsDbInstanceError>> {
+        let request = self.build_register_rds_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RegisterInstanceResult, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterRdsDbInstanceError::from_response(response))
         }
     }
 
-    /// <p>Registers an Amazon RDS instance with a stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn register_rds_db_instance(
+    /// Presigns the request `register_rds_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_rds_db_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterRdsDbInstanceRequest,
-    ) -> Result<(), RusotoError<RegisterRdsDbInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_rds_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_rds_db_instance_request(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterRdsDbInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterRdsDbInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_volume`, also returning the metadata of the response.
+    pub async fn register_volume_with_metadata(
+        &self,
+        input: RegisterVolumeRequest,
+    ) -> Result<(RegisterVolumeResult, ResponseMetadata), RusotoError<RegisterVolumeError>> {
+        let request = self.build_register_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterVolumeResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterRdsDbInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterVolumeError::from_response(response))
         }
     }
 
-    /// <p>Registers an Amazon EBS volume with a specified stack. A volume can be registered with only one stack at a time. If the volume is already registered, you must first deregister it by calling <a>DeregisterVolume</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn register_volume(
+    /// Presigns the request `register_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterVolumeRequest,
-    ) -> Result<RegisterVolumeResult, RusotoError<RegisterVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_volume_request(&self, input: RegisterVolumeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_load_based_auto_scaling`, also returning the metadata of the response.
+    pub async fn set_load_based_auto_scaling_with_metadata(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetLoadBasedAutoScalingError>> {
+        let request = self.build_set_load_based_auto_scaling_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RegisterVolumeResult, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterVolumeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetLoadBasedAutoScalingError::from_response(response))
         }
     }
 
-    /// <p>Specify the load-based auto scaling configuration for a specified layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <note> <p>To use load-based auto scaling, you must create a set of load-based auto scaling instances. Load-based auto scaling operates only on the instances from that set, so you must ensure that you have created enough instances to handle the maximum anticipated load.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn set_load_based_auto_scaling(
+    /// Presigns the request `set_load_based_auto_scaling` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_load_based_auto_scaling(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetLoadBasedAutoScalingRequest,
-    ) -> Result<(), RusotoError<SetLoadBasedAutoScalingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_load_based_auto_scaling_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_load_based_auto_scaling_request(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("SetLoadBasedAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.SetLoadBasedAutoScaling");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_permission`, also returning the metadata of the response.
+    pub async fn set_permission_with_metadata(
+        &self,
+        input: SetPermissionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetPermissionError>> {
+        let request = self.build_set_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetLoadBasedAutoScalingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetPermissionError::from_response(response))
         }
     }
 
-    /// <p>Specifies a user's permissions. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingsecurity.html">Security and Permissions</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn set_permission(
+    /// Presigns the request `set_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetPermissionRequest,
-    ) -> Result<(), RusotoError<SetPermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_permission_request(&self, input: SetPermissionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("SetPermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.SetPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_time_based_auto_scaling`, also returning the metadata of the response.
+    pub async fn set_time_based_auto_scaling_with_metadata(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetTimeBasedAutoScalingError>> {
+        let request = self.build_set_time_based_auto_scaling_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetPermissionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetTimeBasedAutoScalingError::from_response(response))
         }
     }
 
-    /// <p>Specify the time-based auto scaling configuration for a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn set_time_based_auto_scaling(
+    /// Presigns the request `set_time_based_auto_scaling` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_time_based_auto_scaling(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetTimeBasedAutoScalingRequest,
-    ) -> Result<(), RusotoError<SetTimeBasedAutoScalingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_time_based_auto_scaling_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_time_based_auto_scaling_request(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("SetTimeBasedAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.SetTimeBasedAutoScaling");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_instance`, also returning the metadata of the response.
+    pub async fn start_instance_with_metadata(
+        &self,
+        input: StartInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StartInstanceError>> {
+        let request = self.build_start_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetTimeBasedAutoScalingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartInstanceError::from_response(response))
         }
     }
 
-    /// <p>Starts a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn start_instance(
+    /// Presigns the request `start_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartInstanceRequest,
-    ) -> Result<(), RusotoError<StartInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_instance_request(&self, input: StartInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StartInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.StartInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_stack`, also returning the metadata of the response.
+    pub async fn start_stack_with_metadata(
+        &self,
+        input: StartStackRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StartStackError>> {
+        let request = self.build_start_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartStackError::from_response(response))
         }
     }
 
-    /// <p>Starts a stack's instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn start_stack(
+    /// Presigns the request `start_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartStackRequest,
-    ) -> Result<(), RusotoError<StartStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_stack_request(&self, input: StartStackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StartStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.StartStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_instance`, also returning the metadata of the response.
+    pub async fn stop_instance_with_metadata(
+        &self,
+        input: StopInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopInstanceError>> {
+        let request = self.build_stop_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopInstanceError::from_response(response))
         }
     }
 
-    /// <p>Stops a specified instance. When you stop a standard instance, the data disappears and must be reinstalled when you restart the instance. You can stop an Amazon EBS-backed instance without losing data. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn stop_instance(
+    /// Presigns the request `stop_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopInstanceRequest,
-    ) -> Result<(), RusotoError<StopInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_instance_request(&self, input: StopInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StopInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.StopInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_stack`, also returning the metadata of the response.
+    pub async fn stop_stack_with_metadata(
+        &self,
+        input: StopStackRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopStackError>> {
+        let request = self.build_stop_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopStackError::from_response(response))
         }
     }
 
-    /// <p>Stops a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn stop_stack(&self, input: StopStackRequest) -> Result<(), RusotoError<StopStackError>> {
+    /// Presigns the request `stop_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_stack(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: StopStackRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_stack_request(&self, input: StopStackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StopStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.StopStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Apply cost-allocation tags to a specified stack or layer in AWS OpsWorks Stacks. For more information about how tagging works, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/tagging.html">Tags</a> in the AWS OpsWorks User Guide.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `unassign_instance`, also returning the metadata of the response.
+    pub async fn unassign_instance_with_metadata(
+        &self,
+        input: UnassignInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UnassignInstanceError>> {
+        let request = self.build_unassign_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UnassignInstanceError::from_response(response))
         }
     }
 
-    /// <p>Unassigns a registered instance from all layers that are using the instance. The instance remains in the stack as an unassigned instance, and can be assigned to another layer as needed. You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn unassign_instance(
+    /// Presigns the request `unassign_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unassign_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnassignInstanceRequest,
-    ) -> Result<(), RusotoError<UnassignInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unassign_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unassign_instance_request(&self, input: UnassignInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UnassignInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UnassignInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `unassign_volume`, also returning the metadata of the response.
+    pub async fn unassign_volume_with_metadata(
+        &self,
+        input: UnassignVolumeRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UnassignVolumeError>> {
+        let request = self.build_unassign_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UnassignInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UnassignVolumeError::from_response(response))
         }
     }
 
-    /// <p>Unassigns an assigned Amazon EBS volume. The volume remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn unassign_volume(
+    /// Presigns the request `unassign_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unassign_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnassignVolumeRequest,
-    ) -> Result<(), RusotoError<UnassignVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unassign_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unassign_volume_request(&self, input: UnassignVolumeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UnassignVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UnassignVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UnassignVolumeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from a specified stack or layer.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_app`, also returning the metadata of the response.
+    pub async fn update_app_with_metadata(
+        &self,
+        input: UpdateAppRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateAppError>> {
+        let request = self.build_update_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAppError::from_response(response))
         }
     }
 
-    /// <p>Updates a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn update_app(&self, input: UpdateAppRequest) -> Result<(), RusotoError<UpdateAppError>> {
+    /// Presigns the request `update_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_app(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateAppRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_app_request(&self, input: UpdateAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_elastic_ip`, also returning the metadata of the response.
+    pub async fn update_elastic_ip_with_metadata(
+        &self,
+        input: UpdateElasticIpRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateElasticIpError>> {
+        let request = self.build_update_elastic_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateElasticIpError::from_response(response))
         }
     }
 
-    /// <p>Updates a registered Elastic IP address's name. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn update_elastic_ip(
+    /// Presigns the request `update_elastic_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_elastic_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateElasticIpRequest,
-    ) -> Result<(), RusotoError<UpdateElasticIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_elastic_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_elastic_ip_request(&self, input: UpdateElasticIpRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateElasticIp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_instance`, also returning the metadata of the response.
+    pub async fn update_instance_with_metadata(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateInstanceError>> {
+        let request = self.build_update_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateElasticIpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateInstanceError::from_response(response))
         }
     }
 
-    /// <p>Updates a specified instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn update_instance(
+    /// Presigns the request `update_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateInstanceRequest,
-    ) -> Result<(), RusotoError<UpdateInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_instance_request(&self, input: UpdateInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_layer`, also returning the metadata of the response.
+    pub async fn update_layer_with_metadata(
+        &self,
+        input: UpdateLayerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateLayerError>> {
+        let request = self.build_update_layer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateLayerError::from_response(response))
         }
     }
 
-    /// <p>Updates a specified layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn update_layer(
+    /// Presigns the request `update_layer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_layer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLayerRequest,
-    ) -> Result<(), RusotoError<UpdateLayerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_layer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_layer_request(&self, input: UpdateLayerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateLayer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateLayer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_my_user_profile`, also returning the metadata of the response.
+    pub async fn update_my_user_profile_with_metadata(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateMyUserProfileError>> {
+        let request = self.build_update_my_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLayerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateMyUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates a user's SSH public key.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn update_my_user_profile(
+    /// Presigns the request `update_my_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_my_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMyUserProfileRequest,
-    ) -> Result<(), RusotoError<UpdateMyUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_my_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_my_user_profile_request(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateMyUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateMyUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_rds_db_instance`, also returning the metadata of the response.
+    pub async fn update_rds_db_instance_with_metadata(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateRdsDbInstanceError>> {
+        let request = self.build_update_rds_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMyUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRdsDbInstanceError::from_response(response))
         }
     }
 
-    /// <p>Updates an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn update_rds_db_instance(
+    /// Presigns the request `update_rds_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_rds_db_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRdsDbInstanceRequest,
-    ) -> Result<(), RusotoError<UpdateRdsDbInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_rds_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_rds_db_instance_request(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateRdsDbInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateRdsDbInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_stack`, also returning the metadata of the response.
+    pub async fn update_stack_with_metadata(
+        &self,
+        input: UpdateStackRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateStackError>> {
+        let request = self.build_update_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRdsDbInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateStackError::from_response(response))
         }
     }
 
-    /// <p>Updates a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn update_stack(
+    /// Presigns the request `update_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateStackRequest,
-    ) -> Result<(), RusotoError<UpdateStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_stack_request(&self, input: UpdateStackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateStack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_user_profile`, also returning the metadata of the response.
+    pub async fn update_user_profile_with_metadata(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateUserProfileError>> {
+        let request = self.build_update_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates a specified user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn update_user_profile(
+    /// Presigns the request `update_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserProfileRequest,
-    ) -> Result<(), RusotoError<UpdateUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_profile_request(&self, input: UpdateUserProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_volume`, also returning the metadata of the response.
+    pub async fn update_volume_with_metadata(
+        &self,
+        input: UpdateVolumeRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateVolumeError>> {
+        let request = self.build_update_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateVolumeError::from_response(response))
         }
     }
 
-    /// <p>Updates an Amazon EBS volume's name or mount point. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
-    async fn update_volume(
+    /// Presigns the request `update_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVolumeRequest,
-    ) -> Result<(), RusotoError<UpdateVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_volume_request(&self, input: UpdateVolumeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl OpsWorks for OpsWorksClient {
+    /// <p>Assign a registered instance to a layer.</p> <ul> <li> <p>You can assign registered on-premises instances to any layer type.</p> </li> <li> <p>You can assign registered Amazon EC2 instances only to custom layers.</p> </li> <li> <p>You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> </li> </ul> <p> <b>Required Permissions</b>: To use this action, an AWS Identity and Access Management (IAM) user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn assign_instance(
+        &self,
+        input: AssignInstanceRequest,
+    ) -> Result<(), RusotoError<AssignInstanceError>> {
+        self.assign_instance_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVolumeError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns one of the stack's registered Amazon EBS volumes to a specified instance. The volume must first be registered with the stack by calling <a>RegisterVolume</a>. After you register the volume, you must call <a>UpdateVolume</a> to specify a mount point before calling <code>AssignVolume</code>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn assign_volume(
+        &self,
+        input: AssignVolumeRequest,
+    ) -> Result<(), RusotoError<AssignVolumeError>> {
+        self.assign_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates one of the stack's registered Elastic IP addresses with a specified instance. The address must first be registered with the stack by calling <a>RegisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn associate_elastic_ip(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> Result<(), RusotoError<AssociateElasticIpError>> {
+        self.associate_elastic_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches an Elastic Load Balancing load balancer to a specified layer. AWS OpsWorks Stacks does not support Application Load Balancer. You can only use Classic Load Balancer with AWS OpsWorks Stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/layers-elb.html">Elastic Load Balancing</a>.</p> <note> <p>You must create the Elastic Load Balancing instance separately, by using the Elastic Load Balancing console, API, or CLI. For more information, see <a href="https://docs.aws.amazon.com/ElasticLoadBalancing/latest/DeveloperGuide/Welcome.html"> Elastic Load Balancing Developer Guide</a>.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn attach_elastic_load_balancer(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> Result<(), RusotoError<AttachElasticLoadBalancerError>> {
+        self.attach_elastic_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a clone of a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-cloning.html">Clone a Stack</a>. By default, all parameters are set to the values used by the parent stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn clone_stack(
+        &self,
+        input: CloneStackRequest,
+    ) -> Result<CloneStackResult, RusotoError<CloneStackError>> {
+        self.clone_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an app for a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-creating.html">Creating Apps</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
+        self.create_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Runs deployment or stack commands. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-deploying.html">Deploying Apps</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-commands.html">Run Stack Commands</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
+        self.create_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an instance in a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html">Adding an Instance to a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> Result<CreateInstanceResult, RusotoError<CreateInstanceError>> {
+        self.create_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-create.html">How to Create a Layer</a>.</p> <note> <p>You should use <b>CreateLayer</b> for noncustom layer types such as PHP App Server only if the stack does not have an existing layer of that type. A stack can have at most one instance of each noncustom layer; if you attempt to create a second instance, <b>CreateLayer</b> fails. A stack can have an arbitrary number of custom layers, so you can call <b>CreateLayer</b> as many times as you like for that layer type.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn create_layer(
+        &self,
+        input: CreateLayerRequest,
+    ) -> Result<CreateLayerResult, RusotoError<CreateLayerError>> {
+        self.create_layer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-edit.html">Create a New Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
+        self.create_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
+        self.create_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
+        self.delete_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified instance, which terminates the associated Amazon EC2 instance. You must stop an instance before you can delete it.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-delete.html">Deleting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> Result<(), RusotoError<DeleteInstanceError>> {
+        self.delete_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified layer. You must first stop and then delete all associated instances or unassign registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-delete.html">How to Delete a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn delete_layer(
+        &self,
+        input: DeleteLayerRequest,
+    ) -> Result<(), RusotoError<DeleteLayerError>> {
+        self.delete_layer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified stack. You must first delete all instances, layers, and apps or deregister registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-shutting.html">Shut Down a Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> Result<(), RusotoError<DeleteStackError>> {
+        self.delete_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<(), RusotoError<DeleteUserProfileError>> {
+        self.delete_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters a specified Amazon ECS cluster from a stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html#workinglayers-ecscluster-delete"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html</a>.</p>
+    async fn deregister_ecs_cluster(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> Result<(), RusotoError<DeregisterEcsClusterError>> {
+        self.deregister_ecs_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters a specified Elastic IP address. The address can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn deregister_elastic_ip(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> Result<(), RusotoError<DeregisterElasticIpError>> {
+        self.deregister_elastic_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregister a registered Amazon EC2 or on-premises instance. This action removes the instance from the stack and returns it to your control. This action cannot be used with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> Result<(), RusotoError<DeregisterInstanceError>> {
+        self.deregister_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn deregister_rds_db_instance(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> Result<(), RusotoError<DeregisterRdsDbInstanceError>> {
+        self.deregister_rds_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters an Amazon EBS volume. The volume can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn deregister_volume(
+        &self,
+        input: DeregisterVolumeRequest,
+    ) -> Result<(), RusotoError<DeregisterVolumeError>> {
+        self.deregister_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the available AWS OpsWorks Stacks agent versions. You must specify a stack ID or a configuration manager. <code>DescribeAgentVersions</code> returns a list of available agent versions for the specified stack or configuration manager.</p>
+    async fn describe_agent_versions(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> Result<DescribeAgentVersionsResult, RusotoError<DescribeAgentVersionsError>> {
+        self.describe_agent_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests a description of a specified set of apps.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_apps(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> Result<DescribeAppsResult, RusotoError<DescribeAppsError>> {
+        self.describe_apps_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the results of specified commands.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_commands(
+        &self,
+        input: DescribeCommandsRequest,
+    ) -> Result<DescribeCommandsResult, RusotoError<DescribeCommandsError>> {
+        self.describe_commands_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests a description of a specified set of deployments.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_deployments(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> Result<DescribeDeploymentsResult, RusotoError<DescribeDeploymentsError>> {
+        self.describe_deployments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes Amazon ECS clusters that are registered with a stack. If you specify only a stack ID, you can use the <code>MaxResults</code> and <code>NextToken</code> parameters to paginate the response. However, AWS OpsWorks Stacks currently supports only one cluster per layer, so the result set has a maximum of one element.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permission. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
+    async fn describe_ecs_clusters(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> Result<DescribeEcsClustersResult, RusotoError<DescribeEcsClustersError>> {
+        self.describe_ecs_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP addresses</a>.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_elastic_ips(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> Result<DescribeElasticIpsResult, RusotoError<DescribeElasticIpsError>> {
+        self.describe_elastic_ips_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a stack's Elastic Load Balancing instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_elastic_load_balancers(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> Result<DescribeElasticLoadBalancersResult, RusotoError<DescribeElasticLoadBalancersError>>
+    {
+        self.describe_elastic_load_balancers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests a description of a set of instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
+        self.describe_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests a description of one or more layers in a specified stack.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_layers(
+        &self,
+        input: DescribeLayersRequest,
+    ) -> Result<DescribeLayersResult, RusotoError<DescribeLayersError>> {
+        self.describe_layers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes load-based auto scaling configurations for specified layers.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_load_based_auto_scaling(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> Result<DescribeLoadBasedAutoScalingResult, RusotoError<DescribeLoadBasedAutoScalingError>>
+    {
+        self.describe_load_based_auto_scaling_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a user's SSH information.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_my_user_profile(
+        &self,
+    ) -> Result<DescribeMyUserProfileResult, RusotoError<DescribeMyUserProfileError>> {
+        self.describe_my_user_profile_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the operating systems that are supported by AWS OpsWorks Stacks.</p>
+    async fn describe_operating_systems(
+        &self,
+    ) -> Result<DescribeOperatingSystemsResponse, RusotoError<DescribeOperatingSystemsError>> {
+        self.describe_operating_systems_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the permissions for a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_permissions(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> Result<DescribePermissionsResult, RusotoError<DescribePermissionsError>> {
+        self.describe_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe an instance's RAID arrays.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_raid_arrays(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> Result<DescribeRaidArraysResult, RusotoError<DescribeRaidArraysError>> {
+        self.describe_raid_arrays_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes Amazon RDS instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
+    async fn describe_rds_db_instances(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> Result<DescribeRdsDbInstancesResult, RusotoError<DescribeRdsDbInstancesError>> {
+        self.describe_rds_db_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes AWS OpsWorks Stacks service errors.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
+    async fn describe_service_errors(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> Result<DescribeServiceErrorsResult, RusotoError<DescribeServiceErrorsError>> {
+        self.describe_service_errors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests a description of a stack's provisioning parameters.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_stack_provisioning_parameters(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> Result<
+        DescribeStackProvisioningParametersResult,
+        RusotoError<DescribeStackProvisioningParametersError>,
+    > {
+        self.describe_stack_provisioning_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the number of layers and apps in a specified stack, and the number of instances in each state, such as <code>running_setup</code> or <code>online</code>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_stack_summary(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> Result<DescribeStackSummaryResult, RusotoError<DescribeStackSummaryError>> {
+        self.describe_stack_summary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests a description of one or more stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
+        self.describe_stacks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes time-based auto scaling configurations for specified instances.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_time_based_auto_scaling(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> Result<DescribeTimeBasedAutoScalingResult, RusotoError<DescribeTimeBasedAutoScalingError>>
+    {
+        self.describe_time_based_auto_scaling_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe specified users.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_user_profiles(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> Result<DescribeUserProfilesResult, RusotoError<DescribeUserProfilesError>> {
+        self.describe_user_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an instance's Amazon EBS volumes.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
+        self.describe_volumes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches a specified Elastic Load Balancing instance from its layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn detach_elastic_load_balancer(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> Result<(), RusotoError<DetachElasticLoadBalancerError>> {
+        self.detach_elastic_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates an Elastic IP address from its instance. The address remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn disassociate_elastic_ip(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> Result<(), RusotoError<DisassociateElasticIpError>> {
+        self.disassociate_elastic_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a generated host name for the specified layer, based on the current host name theme.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn get_hostname_suggestion(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> Result<GetHostnameSuggestionResult, RusotoError<GetHostnameSuggestionError>> {
+        self.get_hostname_suggestion_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This action can be used only with Windows stacks.</p> </note> <p>Grants RDP access to a Windows instance for a specified time period.</p></p>
+    async fn grant_access(
+        &self,
+        input: GrantAccessRequest,
+    ) -> Result<GrantAccessResult, RusotoError<GrantAccessError>> {
+        self.grant_access_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of tags that are applied to the specified stack or layer.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResult, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Reboots a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> Result<(), RusotoError<RebootInstanceError>> {
+        self.reboot_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a specified Amazon ECS cluster with a stack. You can register only one cluster with a stack. A cluster can be registered with only one stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html"> Managing User Permissions</a>.</p>
+    async fn register_ecs_cluster(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> Result<RegisterEcsClusterResult, RusotoError<RegisterEcsClusterError>> {
+        self.register_ecs_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers an Elastic IP address with a specified stack. An address can be registered with only one stack at a time. If the address is already registered, you must first deregister it by calling <a>DeregisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn register_elastic_ip(
+        &self,
+        input: RegisterElasticIpRequest,
+    ) -> Result<RegisterElasticIpResult, RusotoError<RegisterElasticIpError>> {
+        self.register_elastic_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers instances that were created outside of AWS OpsWorks Stacks with a specified stack.</p> <note> <p>We do not recommend using this action to register instances. The complete registration operation includes two tasks: installing the AWS OpsWorks Stacks agent on the instance, and registering the instance with the stack. <code>RegisterInstance</code> handles only the second step. You should instead use the AWS CLI <code>register</code> command, which performs the entire registration operation. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register.html"> Registering an Instance with an AWS OpsWorks Stacks Stack</a>.</p> </note> <p>Registered instances have the same requirements as instances that are created by using the <a>CreateInstance</a> API. For example, registered instances must be running a supported Linux-based operating system, and they must have a supported instance type. For more information about requirements for instances that you want to register, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register-registering-preparer.html"> Preparing the Instance</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> Result<RegisterInstanceResult, RusotoError<RegisterInstanceError>> {
+        self.register_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers an Amazon RDS instance with a stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn register_rds_db_instance(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> Result<(), RusotoError<RegisterRdsDbInstanceError>> {
+        self.register_rds_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers an Amazon EBS volume with a specified stack. A volume can be registered with only one stack at a time. If the volume is already registered, you must first deregister it by calling <a>DeregisterVolume</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn register_volume(
+        &self,
+        input: RegisterVolumeRequest,
+    ) -> Result<RegisterVolumeResult, RusotoError<RegisterVolumeError>> {
+        self.register_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Specify the load-based auto scaling configuration for a specified layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <note> <p>To use load-based auto scaling, you must create a set of load-based auto scaling instances. Load-based auto scaling operates only on the instances from that set, so you must ensure that you have created enough instances to handle the maximum anticipated load.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn set_load_based_auto_scaling(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> Result<(), RusotoError<SetLoadBasedAutoScalingError>> {
+        self.set_load_based_auto_scaling_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Specifies a user's permissions. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingsecurity.html">Security and Permissions</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn set_permission(
+        &self,
+        input: SetPermissionRequest,
+    ) -> Result<(), RusotoError<SetPermissionError>> {
+        self.set_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Specify the time-based auto scaling configuration for a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn set_time_based_auto_scaling(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> Result<(), RusotoError<SetTimeBasedAutoScalingError>> {
+        self.set_time_based_auto_scaling_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> Result<(), RusotoError<StartInstanceError>> {
+        self.start_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a stack's instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn start_stack(
+        &self,
+        input: StartStackRequest,
+    ) -> Result<(), RusotoError<StartStackError>> {
+        self.start_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a specified instance. When you stop a standard instance, the data disappears and must be reinstalled when you restart the instance. You can stop an Amazon EBS-backed instance without losing data. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> Result<(), RusotoError<StopInstanceError>> {
+        self.stop_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn stop_stack(&self, input: StopStackRequest) -> Result<(), RusotoError<StopStackError>> {
+        self.stop_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Apply cost-allocation tags to a specified stack or layer in AWS OpsWorks Stacks. For more information about how tagging works, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/tagging.html">Tags</a> in the AWS OpsWorks User Guide.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Unassigns a registered instance from all layers that are using the instance. The instance remains in the stack as an unassigned instance, and can be assigned to another layer as needed. You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn unassign_instance(
+        &self,
+        input: UnassignInstanceRequest,
+    ) -> Result<(), RusotoError<UnassignInstanceError>> {
+        self.unassign_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Unassigns an assigned Amazon EBS volume. The volume remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn unassign_volume(
+        &self,
+        input: UnassignVolumeRequest,
+    ) -> Result<(), RusotoError<UnassignVolumeError>> {
+        self.unassign_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from a specified stack or layer.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn update_app(&self, input: UpdateAppRequest) -> Result<(), RusotoError<UpdateAppError>> {
+        self.update_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a registered Elastic IP address's name. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn update_elastic_ip(
+        &self,
+        input: UpdateElasticIpRequest,
+    ) -> Result<(), RusotoError<UpdateElasticIpError>> {
+        self.update_elastic_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a specified instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> Result<(), RusotoError<UpdateInstanceError>> {
+        self.update_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a specified layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn update_layer(
+        &self,
+        input: UpdateLayerRequest,
+    ) -> Result<(), RusotoError<UpdateLayerError>> {
+        self.update_layer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a user's SSH public key.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn update_my_user_profile(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> Result<(), RusotoError<UpdateMyUserProfileError>> {
+        self.update_my_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn update_rds_db_instance(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> Result<(), RusotoError<UpdateRdsDbInstanceError>> {
+        self.update_rds_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> Result<(), RusotoError<UpdateStackError>> {
+        self.update_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a specified user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<(), RusotoError<UpdateUserProfileError>> {
+        self.update_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an Amazon EBS volume's name or mount point. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    async fn update_volume(
+        &self,
+        input: UpdateVolumeRequest,
+    ) -> Result<(), RusotoError<UpdateVolumeError>> {
+        self.update_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -792,11 +792,14 @@ pub struct UpdateServerResponse {
 
 /// Errors returned by AssociateNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateNodeError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateNodeError {
@@ -804,13 +807,16 @@ impl AssociateNodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(AssociateNodeError::InvalidState(err.msg))
+                    return RusotoError::service(AssociateNodeError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AssociateNodeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -822,12 +828,14 @@ impl fmt::Display for AssociateNodeError {
         match *self {
             AssociateNodeError::InvalidState(ref cause) => write!(f, "{}", cause),
             AssociateNodeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssociateNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateNodeError {}
 /// Errors returned by CreateBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBackupError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
@@ -835,6 +843,8 @@ pub enum CreateBackupError {
     LimitExceeded(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBackupError {
@@ -842,16 +852,16 @@ impl CreateBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreateBackupError::InvalidState(err.msg))
+                    return RusotoError::service(CreateBackupError::InvalidState(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateBackupError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateBackupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateBackupError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -864,12 +874,14 @@ impl fmt::Display for CreateBackupError {
             CreateBackupError::InvalidState(ref cause) => write!(f, "{}", cause),
             CreateBackupError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateBackupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBackupError {}
 /// Errors returned by CreateServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateServerError {
     /// <p>The limit of servers or backups has been reached. </p>
     LimitExceeded(String),
@@ -877,6 +889,8 @@ pub enum CreateServerError {
     ResourceAlreadyExists(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateServerError {
@@ -884,16 +898,19 @@ impl CreateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateServerError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateServerError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateServerError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateServerError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateServerError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -906,17 +923,21 @@ impl fmt::Display for CreateServerError {
             CreateServerError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateServerError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateServerError {}
 /// Errors returned by DeleteBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackupError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackupError {
@@ -924,13 +945,13 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeleteBackupError::InvalidState(err.msg))
+                    return RusotoError::service(DeleteBackupError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteBackupError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -942,17 +963,21 @@ impl fmt::Display for DeleteBackupError {
         match *self {
             DeleteBackupError::InvalidState(ref cause) => write!(f, "{}", cause),
             DeleteBackupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBackupError {}
 /// Errors returned by DeleteServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteServerError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteServerError {
@@ -960,13 +985,13 @@ impl DeleteServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeleteServerError::InvalidState(err.msg))
+                    return RusotoError::service(DeleteServerError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteServerError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -978,20 +1003,27 @@ impl fmt::Display for DeleteServerError {
         match *self {
             DeleteServerError::InvalidState(ref cause) => write!(f, "{}", cause),
             DeleteServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteServerError {}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
-pub enum DescribeAccountAttributesError {}
+#[non_exhaustive]
+pub enum DescribeAccountAttributesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeAccountAttributesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountAttributesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeAccountAttributesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1000,17 +1032,22 @@ impl DescribeAccountAttributesError {
 impl fmt::Display for DescribeAccountAttributesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeAccountAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeAccountAttributesError {}
 /// Errors returned by DescribeBackups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBackupsError {
     /// <p>This occurs when the provided nextToken is not valid. </p>
     InvalidNextToken(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBackupsError {
@@ -1018,13 +1055,19 @@ impl DescribeBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeBackupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeBackupsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeBackupsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBackupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1036,17 +1079,21 @@ impl fmt::Display for DescribeBackupsError {
         match *self {
             DescribeBackupsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeBackupsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeBackupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBackupsError {}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventsError {
     /// <p>This occurs when the provided nextToken is not valid. </p>
     InvalidNextToken(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventsError {
@@ -1054,13 +1101,19 @@ impl DescribeEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeEventsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeEventsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1072,15 +1125,19 @@ impl fmt::Display for DescribeEventsError {
         match *self {
             DescribeEventsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeEventsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventsError {}
 /// Errors returned by DescribeNodeAssociationStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeNodeAssociationStatusError {
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeNodeAssociationStatusError {
@@ -1090,12 +1147,18 @@ impl DescribeNodeAssociationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeNodeAssociationStatusError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeNodeAssociationStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1108,17 +1171,23 @@ impl fmt::Display for DescribeNodeAssociationStatusError {
             DescribeNodeAssociationStatusError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeNodeAssociationStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeNodeAssociationStatusError {}
 /// Errors returned by DescribeServers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServersError {
     /// <p>This occurs when the provided nextToken is not valid. </p>
     InvalidNextToken(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServersError {
@@ -1126,13 +1195,19 @@ impl DescribeServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeServersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeServersError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeServersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeServersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1144,17 +1219,21 @@ impl fmt::Display for DescribeServersError {
         match *self {
             DescribeServersError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeServersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeServersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeServersError {}
 /// Errors returned by DisassociateNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateNodeError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateNodeError {
@@ -1162,13 +1241,16 @@ impl DisassociateNodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DisassociateNodeError::InvalidState(err.msg))
+                    return RusotoError::service(DisassociateNodeError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DisassociateNodeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1180,17 +1262,21 @@ impl fmt::Display for DisassociateNodeError {
         match *self {
             DisassociateNodeError::InvalidState(ref cause) => write!(f, "{}", cause),
             DisassociateNodeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateNodeError {}
 /// Errors returned by ExportServerEngineAttribute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExportServerEngineAttributeError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExportServerEngineAttributeError {
@@ -1200,17 +1286,24 @@ impl ExportServerEngineAttributeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(ExportServerEngineAttributeError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportServerEngineAttributeError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ExportServerEngineAttributeError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ExportServerEngineAttributeError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1222,15 +1315,19 @@ impl fmt::Display for ExportServerEngineAttributeError {
         match *self {
             ExportServerEngineAttributeError::InvalidState(ref cause) => write!(f, "{}", cause),
             ExportServerEngineAttributeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ExportServerEngineAttributeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExportServerEngineAttributeError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1238,12 +1335,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1254,17 +1352,21 @@ impl fmt::Display for ListTagsForResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by RestoreServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreServerError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreServerError {
@@ -1272,13 +1374,16 @@ impl RestoreServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(RestoreServerError::InvalidState(err.msg))
+                    return RusotoError::service(RestoreServerError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RestoreServerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RestoreServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1290,17 +1395,21 @@ impl fmt::Display for RestoreServerError {
         match *self {
             RestoreServerError::InvalidState(ref cause) => write!(f, "{}", cause),
             RestoreServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RestoreServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreServerError {}
 /// Errors returned by StartMaintenance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartMaintenanceError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartMaintenanceError {
@@ -1308,13 +1417,16 @@ impl StartMaintenanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(StartMaintenanceError::InvalidState(err.msg))
+                    return RusotoError::service(StartMaintenanceError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartMaintenanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartMaintenanceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartMaintenanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1326,17 +1438,21 @@ impl fmt::Display for StartMaintenanceError {
         match *self {
             StartMaintenanceError::InvalidState(ref cause) => write!(f, "{}", cause),
             StartMaintenanceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartMaintenanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartMaintenanceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1344,13 +1460,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(TagResourceError::InvalidState(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1362,17 +1478,21 @@ impl fmt::Display for TagResourceError {
         match *self {
             TagResourceError::InvalidState(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1380,13 +1500,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidState(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1398,17 +1521,21 @@ impl fmt::Display for UntagResourceError {
         match *self {
             UntagResourceError::InvalidState(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServerError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServerError {
@@ -1416,13 +1543,13 @@ impl UpdateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateServerError::InvalidState(err.msg))
+                    return RusotoError::service(UpdateServerError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateServerError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1434,17 +1561,21 @@ impl fmt::Display for UpdateServerError {
         match *self {
             UpdateServerError::InvalidState(ref cause) => write!(f, "{}", cause),
             UpdateServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateServerError {}
 /// Errors returned by UpdateServerEngineAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServerEngineAttributesError {
     /// <p>The resource is in a state that does not allow you to perform a specified action. </p>
     InvalidState(String),
     /// <p>The requested resource does not exist, or access was denied. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServerEngineAttributesError {
@@ -1454,17 +1585,24 @@ impl UpdateServerEngineAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateServerEngineAttributesError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServerEngineAttributesError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateServerEngineAttributesError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateServerEngineAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1478,6 +1616,7 @@ impl fmt::Display for UpdateServerEngineAttributesError {
             UpdateServerEngineAttributesError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateServerEngineAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1612,6 +1751,7 @@ impl OpsWorksCMClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> OpsWorksCMClient {
         OpsWorksCMClient {
             client: Client::shared(),
@@ -1637,151 +1777,328 @@ impl OpsWorksCMClient {
     pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksCMClient {
         OpsWorksCMClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<OpsWorksCMClient> {
+        rusoto_core::ClientBuilder::new(OpsWorksCMClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> OpsWorksCMClient {
+        OpsWorksCMClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl OpsWorksCM for OpsWorksCMClient {
-    /// <p> Associates a new node with the server. For more information about how to disassociate a node, see <a>DisassociateNode</a>.</p> <p> On a Chef server: This command is an alternative to <code>knife bootstrap</code>.</p> <p> Example (Chef): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>CHEF_ORGANIZATION</i>,Value=default" "Name=<i>CHEF_NODE_PUBLIC_KEY</i>,Value=<i>public-key-pem</i>"</code> </p> <p> On a Puppet server, this command is an alternative to the <code>puppet cert sign</code> command that signs a Puppet node CSR. </p> <p> Example (Chef): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>PUPPET_NODE_CSR</i>,Value=<i>csr-pem</i>"</code> </p> <p> A node can can only be associated with servers that are in a <code>HEALTHY</code> state. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. The AssociateNode API call can be integrated into Auto Scaling configurations, AWS Cloudformation templates, or the user data of a server's instance. </p>
-    async fn associate_node(
+impl OpsWorksCMClient {
+    /// Calls `associate_node`, also returning the metadata of the response.
+    pub async fn associate_node_with_metadata(
         &self,
         input: AssociateNodeRequest,
-    ) -> Result<AssociateNodeResponse, RusotoError<AssociateNodeError>> {
+    ) -> Result<(AssociateNodeResponse, ResponseMetadata), RusotoError<AssociateNodeError>> {
+        let request = self.build_associate_node_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateNodeResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateNodeError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_node(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateNodeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_node_request(&self, input: AssociateNodeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("AssociateNode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.AssociateNode");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_backup`, also returning the metadata of the response.
+    pub async fn create_backup_with_metadata(
+        &self,
+        input: CreateBackupRequest,
+    ) -> Result<(CreateBackupResponse, ResponseMetadata), RusotoError<CreateBackupError>> {
+        let request = self.build_create_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AssociateNodeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBackupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateNodeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBackupError::from_response(response))
         }
     }
 
-    /// <p> Creates an application-level backup of a server. While the server is in the <code>BACKING_UP</code> state, the server cannot be changed, and no additional backup can be created. </p> <p> Backups can be created for servers in <code>RUNNING</code>, <code>HEALTHY</code>, and <code>UNHEALTHY</code> states. By default, you can create a maximum of 50 manual backups. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when the maximum number of manual backups is reached. An <code>InvalidStateException</code> is thrown when the server is not in any of the following states: RUNNING, HEALTHY, or UNHEALTHY. A <code>ResourceNotFoundException</code> is thrown when the server is not found. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
-    async fn create_backup(
+    /// Presigns the request `create_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBackupRequest,
-    ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_backup_request(&self, input: CreateBackupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("CreateBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_server`, also returning the metadata of the response.
+    pub async fn create_server_with_metadata(
+        &self,
+        input: CreateServerRequest,
+    ) -> Result<(CreateServerResponse, ResponseMetadata), RusotoError<CreateServerError>> {
+        let request = self.build_create_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateBackupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateServerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateServerError::from_response(response))
         }
     }
 
-    /// <p> Creates and immedately starts a new server. The server is ready to use when it is in the <code>HEALTHY</code> state. By default, you can create a maximum of 10 servers. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when you have created the maximum number of servers (10). A <code>ResourceAlreadyExistsException</code> is thrown when a server with the same name already exists in the account. A <code>ResourceNotFoundException</code> is thrown when you specify a backup ID that is not valid or is for a backup that does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p> <p> If you do not specify a security group by adding the <code>SecurityGroupIds</code> parameter, AWS OpsWorks creates a new security group. </p> <p> <i>Chef Automate:</i> The default security group opens the Chef server to the world on TCP port 443. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p> <i>Puppet Enterprise:</i> The default security group opens TCP ports 22, 443, 4433, 8140, 8142, 8143, and 8170. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p>By default, your server is accessible from any IP address. We recommend that you update your security group rules to allow access from known IP addresses and address ranges only. To edit security group rules, open Security Groups in the navigation pane of the EC2 management console. </p> <p>To specify your own domain for a server, and provide your own self-signed or CA-signed certificate and private key, specify values for <code>CustomDomain</code>, <code>CustomCertificate</code>, and <code>CustomPrivateKey</code>.</p>
-    async fn create_server(
+    /// Presigns the request `create_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateServerRequest,
-    ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_server_request(&self, input: CreateServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("CreateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_backup`, also returning the metadata of the response.
+    pub async fn delete_backup_with_metadata(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<(DeleteBackupResponse, ResponseMetadata), RusotoError<DeleteBackupError>> {
+        let request = self.build_delete_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBackupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBackupError::from_response(response))
         }
     }
 
-    /// <p> Deletes a backup. You can delete both manual and automated backups. This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when a backup deletion is already in progress. A <code>ResourceNotFoundException</code> is thrown when the backup does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
-    async fn delete_backup(
+    /// Presigns the request `delete_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackupRequest,
-    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backup_request(&self, input: DeleteBackupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DeleteBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_server`, also returning the metadata of the response.
+    pub async fn delete_server_with_metadata(
+        &self,
+        input: DeleteServerRequest,
+    ) -> Result<(DeleteServerResponse, ResponseMetadata), RusotoError<DeleteServerError>> {
+        let request = self.build_delete_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteBackupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteServerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteServerError::from_response(response))
         }
     }
 
-    /// <p> Deletes the server and the underlying AWS CloudFormation stacks (including the server's EC2 instance). When you run this command, the server state is updated to <code>DELETING</code>. After the server is deleted, it is no longer returned by <code>DescribeServer</code> requests. If the AWS CloudFormation stack cannot be deleted, the server cannot be deleted. </p> <p> This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when a server deletion is already in progress. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p> <p> </p>
-    async fn delete_server(
+    /// Presigns the request `delete_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteServerRequest,
-    ) -> Result<DeleteServerResponse, RusotoError<DeleteServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_server_request(&self, input: DeleteServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DeleteServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_account_attributes`, also returning the metadata of the response.
+    pub async fn describe_account_attributes_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeAccountAttributesResponse, ResponseMetadata),
+        RusotoError<DescribeAccountAttributesError>,
+    > {
+        let request = self.build_describe_account_attributes_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAccountAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAccountAttributesError::from_response(response))
         }
     }
 
-    /// <p> Describes your OpsWorks-CM account attributes. </p> <p> This operation is synchronous. </p>
-    async fn describe_account_attributes(
+    /// Presigns the request `describe_account_attributes` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_account_attributes(
         &self,
-    ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_account_attributes_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_account_attributes_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1790,85 +2107,163 @@ impl OpsWorksCM for OpsWorksCMClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_backups`, also returning the metadata of the response.
+    pub async fn describe_backups_with_metadata(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<(DescribeBackupsResponse, ResponseMetadata), RusotoError<DescribeBackupsError>>
+    {
+        let request = self.build_describe_backups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAccountAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBackupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAccountAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBackupsError::from_response(response))
         }
     }
 
-    /// <p> Describes backups. The results are ordered by time, with newest backups first. If you do not specify a BackupId or ServerName, the command returns all backups. </p> <p> This operation is synchronous. </p> <p> A <code>ResourceNotFoundException</code> is thrown when the backup does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
-    async fn describe_backups(
+    /// Presigns the request `describe_backups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_backups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBackupsRequest,
-    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_backups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_backups_request(&self, input: DescribeBackupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeBackups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_events`, also returning the metadata of the response.
+    pub async fn describe_events_with_metadata(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<(DescribeEventsResponse, ResponseMetadata), RusotoError<DescribeEventsError>> {
+        let request = self.build_describe_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeBackupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBackupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventsError::from_response(response))
         }
     }
 
-    /// <p> Describes events for a specified server. Results are ordered by time, with newest events first. </p> <p> This operation is synchronous. </p> <p> A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
-    async fn describe_events(
+    /// Presigns the request `describe_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventsRequest,
-    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_request(&self, input: DescribeEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_node_association_status`, also returning the metadata of the response.
+    pub async fn describe_node_association_status_with_metadata(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> Result<
+        (DescribeNodeAssociationStatusResponse, ResponseMetadata),
+        RusotoError<DescribeNodeAssociationStatusError>,
+    > {
+        let request = self.build_describe_node_association_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeNodeAssociationStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeNodeAssociationStatusError::from_response(response))
         }
     }
 
-    /// <p> Returns the current status of an existing association or disassociation request. </p> <p> A <code>ResourceNotFoundException</code> is thrown when no recent association or disassociation request with the specified token is found, or when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
-    async fn describe_node_association_status(
+    /// Presigns the request `describe_node_association_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_node_association_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeNodeAssociationStatusRequest,
-    ) -> Result<
-        DescribeNodeAssociationStatusResponse,
-        RusotoError<DescribeNodeAssociationStatusError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_node_association_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_node_association_status_request(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeNodeAssociationStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1878,84 +2273,164 @@ impl OpsWorksCM for OpsWorksCMClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_servers`, also returning the metadata of the response.
+    pub async fn describe_servers_with_metadata(
+        &self,
+        input: DescribeServersRequest,
+    ) -> Result<(DescribeServersResponse, ResponseMetadata), RusotoError<DescribeServersError>>
+    {
+        let request = self.build_describe_servers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeNodeAssociationStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeServersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeNodeAssociationStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeServersError::from_response(response))
         }
     }
 
-    /// <p> Lists all configuration management servers that are identified with your account. Only the stored results from Amazon DynamoDB are returned. AWS OpsWorks CM does not query other services. </p> <p> This operation is synchronous. </p> <p> A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
-    async fn describe_servers(
+    /// Presigns the request `describe_servers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_servers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeServersRequest,
-    ) -> Result<DescribeServersResponse, RusotoError<DescribeServersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_servers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_servers_request(&self, input: DescribeServersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeServers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_node`, also returning the metadata of the response.
+    pub async fn disassociate_node_with_metadata(
+        &self,
+        input: DisassociateNodeRequest,
+    ) -> Result<(DisassociateNodeResponse, ResponseMetadata), RusotoError<DisassociateNodeError>>
+    {
+        let request = self.build_disassociate_node_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeServersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateNodeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeServersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateNodeError::from_response(response))
         }
     }
 
-    /// <p> Disassociates a node from an AWS OpsWorks CM server, and removes the node from the server's managed nodes. After a node is disassociated, the node key pair is no longer valid for accessing the configuration manager's API. For more information about how to associate a node, see <a>AssociateNode</a>. </p> <p>A node can can only be disassociated from a server that is in a <code>HEALTHY</code> state. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
-    async fn disassociate_node(
+    /// Presigns the request `disassociate_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_node(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateNodeRequest,
-    ) -> Result<DisassociateNodeResponse, RusotoError<DisassociateNodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_node_request(&self, input: DisassociateNodeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DisassociateNode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DisassociateNode");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `export_server_engine_attribute`, also returning the metadata of the response.
+    pub async fn export_server_engine_attribute_with_metadata(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> Result<
+        (ExportServerEngineAttributeResponse, ResponseMetadata),
+        RusotoError<ExportServerEngineAttributeError>,
+    > {
+        let request = self.build_export_server_engine_attribute_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateNodeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ExportServerEngineAttributeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateNodeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ExportServerEngineAttributeError::from_response(response))
         }
     }
 
-    /// <p> Exports a specified server engine attribute as a base64-encoded string. For example, you can export user data that you can use in EC2 to associate nodes with a server. </p> <p> This operation is synchronous. </p> <p> A <code>ValidationException</code> is raised when parameters of the request are not valid. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. An <code>InvalidStateException</code> is thrown when the server is in any of the following states: CREATING, TERMINATED, FAILED or DELETING. </p>
-    async fn export_server_engine_attribute(
+    /// Presigns the request `export_server_engine_attribute` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_export_server_engine_attribute(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExportServerEngineAttributeRequest,
-    ) -> Result<ExportServerEngineAttributeResponse, RusotoError<ExportServerEngineAttributeError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_export_server_engine_attribute_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_export_server_engine_attribute_request(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("ExportServerEngineAttribute");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1965,193 +2440,377 @@ impl OpsWorksCM for OpsWorksCMClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ExportServerEngineAttributeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ExportServerEngineAttributeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of tags that are applied to the specified AWS OpsWorks for Chef Automate or AWS OpsWorks for Puppet Enterprise servers or backups.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `restore_server`, also returning the metadata of the response.
+    pub async fn restore_server_with_metadata(
+        &self,
+        input: RestoreServerRequest,
+    ) -> Result<(RestoreServerResponse, ResponseMetadata), RusotoError<RestoreServerError>> {
+        let request = self.build_restore_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RestoreServerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RestoreServerError::from_response(response))
         }
     }
 
-    /// <p> Restores a backup to a server that is in a <code>CONNECTION_LOST</code>, <code>HEALTHY</code>, <code>RUNNING</code>, <code>UNHEALTHY</code>, or <code>TERMINATED</code> state. When you run RestoreServer, the server's EC2 instance is deleted, and a new EC2 instance is configured. RestoreServer maintains the existing server endpoint, so configuration management of the server's client devices (nodes) should continue to work. </p> <p>Restoring from a backup is performed by creating a new EC2 instance. If restoration is successful, and the server is in a <code>HEALTHY</code> state, AWS OpsWorks CM switches traffic over to the new instance. After restoration is finished, the old EC2 instance is maintained in a <code>Running</code> or <code>Stopped</code> state, but is eventually terminated.</p> <p> This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when the server is not in a valid state. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
-    async fn restore_server(
+    /// Presigns the request `restore_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestoreServerRequest,
-    ) -> Result<RestoreServerResponse, RusotoError<RestoreServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_server_request(&self, input: RestoreServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("RestoreServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.RestoreServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_maintenance`, also returning the metadata of the response.
+    pub async fn start_maintenance_with_metadata(
+        &self,
+        input: StartMaintenanceRequest,
+    ) -> Result<(StartMaintenanceResponse, ResponseMetadata), RusotoError<StartMaintenanceError>>
+    {
+        let request = self.build_start_maintenance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RestoreServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartMaintenanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RestoreServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartMaintenanceError::from_response(response))
         }
     }
 
-    /// <p> Manually starts server maintenance. This command can be useful if an earlier maintenance attempt failed, and the underlying cause of maintenance failure has been resolved. The server is in an <code>UNDER_MAINTENANCE</code> state while maintenance is in progress. </p> <p> Maintenance can only be started on servers in <code>HEALTHY</code> and <code>UNHEALTHY</code> states. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
-    async fn start_maintenance(
+    /// Presigns the request `start_maintenance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_maintenance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartMaintenanceRequest,
-    ) -> Result<StartMaintenanceResponse, RusotoError<StartMaintenanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_maintenance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_maintenance_request(&self, input: StartMaintenanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("StartMaintenance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.StartMaintenance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartMaintenanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartMaintenanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Applies tags to an AWS OpsWorks for Chef Automate or AWS OpsWorks for Puppet Enterprise server, or to server backups.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes specified tags from an AWS OpsWorks-CM server or backup.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_server`, also returning the metadata of the response.
+    pub async fn update_server_with_metadata(
+        &self,
+        input: UpdateServerRequest,
+    ) -> Result<(UpdateServerResponse, ResponseMetadata), RusotoError<UpdateServerError>> {
+        let request = self.build_update_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateServerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateServerError::from_response(response))
         }
     }
 
-    /// <p> Updates settings for a server. </p> <p> This operation is synchronous. </p>
-    async fn update_server(
+    /// Presigns the request `update_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateServerRequest,
-    ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_server_request(&self, input: UpdateServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("UpdateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.UpdateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_server_engine_attributes`, also returning the metadata of the response.
+    pub async fn update_server_engine_attributes_with_metadata(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> Result<
+        (UpdateServerEngineAttributesResponse, ResponseMetadata),
+        RusotoError<UpdateServerEngineAttributesError>,
+    > {
+        let request = self.build_update_server_engine_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateServerEngineAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateServerEngineAttributesError::from_response(response))
         }
     }
 
-    /// <p> Updates engine-specific attributes on a specified server. The server enters the <code>MODIFYING</code> state when this operation is in progress. Only one update can occur at a time. You can use this command to reset a Chef server's public key (<code>CHEF_PIVOTAL_KEY</code>) or a Puppet server's admin password (<code>PUPPET_ADMIN_PASSWORD</code>). </p> <p> This operation is asynchronous. </p> <p> This operation can only be called for servers in <code>HEALTHY</code> or <code>UNHEALTHY</code> states. Otherwise, an <code>InvalidStateException</code> is raised. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
-    async fn update_server_engine_attributes(
+    /// Presigns the request `update_server_engine_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_server_engine_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateServerEngineAttributesRequest,
-    ) -> Result<UpdateServerEngineAttributesResponse, RusotoError<UpdateServerEngineAttributesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_server_engine_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_server_engine_attributes_request(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("UpdateServerEngineAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2161,19 +2820,204 @@ impl OpsWorksCM for OpsWorksCMClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl OpsWorksCM for OpsWorksCMClient {
+    /// <p> Associates a new node with the server. For more information about how to disassociate a node, see <a>DisassociateNode</a>.</p> <p> On a Chef server: This command is an alternative to <code>knife bootstrap</code>.</p> <p> Example (Chef): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>CHEF_ORGANIZATION</i>,Value=default" "Name=<i>CHEF_NODE_PUBLIC_KEY</i>,Value=<i>public-key-pem</i>"</code> </p> <p> On a Puppet server, this command is an alternative to the <code>puppet cert sign</code> command that signs a Puppet node CSR. </p> <p> Example (Chef): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>PUPPET_NODE_CSR</i>,Value=<i>csr-pem</i>"</code> </p> <p> A node can can only be associated with servers that are in a <code>HEALTHY</code> state. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. The AssociateNode API call can be integrated into Auto Scaling configurations, AWS Cloudformation templates, or the user data of a server's instance. </p>
+    async fn associate_node(
+        &self,
+        input: AssociateNodeRequest,
+    ) -> Result<AssociateNodeResponse, RusotoError<AssociateNodeError>> {
+        self.associate_node_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateServerEngineAttributesResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateServerEngineAttributesError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates an application-level backup of a server. While the server is in the <code>BACKING_UP</code> state, the server cannot be changed, and no additional backup can be created. </p> <p> Backups can be created for servers in <code>RUNNING</code>, <code>HEALTHY</code>, and <code>UNHEALTHY</code> states. By default, you can create a maximum of 50 manual backups. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when the maximum number of manual backups is reached. An <code>InvalidStateException</code> is thrown when the server is not in any of the following states: RUNNING, HEALTHY, or UNHEALTHY. A <code>ResourceNotFoundException</code> is thrown when the server is not found. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
+    async fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
+        self.create_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates and immedately starts a new server. The server is ready to use when it is in the <code>HEALTHY</code> state. By default, you can create a maximum of 10 servers. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when you have created the maximum number of servers (10). A <code>ResourceAlreadyExistsException</code> is thrown when a server with the same name already exists in the account. A <code>ResourceNotFoundException</code> is thrown when you specify a backup ID that is not valid or is for a backup that does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p> <p> If you do not specify a security group by adding the <code>SecurityGroupIds</code> parameter, AWS OpsWorks creates a new security group. </p> <p> <i>Chef Automate:</i> The default security group opens the Chef server to the world on TCP port 443. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p> <i>Puppet Enterprise:</i> The default security group opens TCP ports 22, 443, 4433, 8140, 8142, 8143, and 8170. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p>By default, your server is accessible from any IP address. We recommend that you update your security group rules to allow access from known IP addresses and address ranges only. To edit security group rules, open Security Groups in the navigation pane of the EC2 management console. </p> <p>To specify your own domain for a server, and provide your own self-signed or CA-signed certificate and private key, specify values for <code>CustomDomain</code>, <code>CustomCertificate</code>, and <code>CustomPrivateKey</code>.</p>
+    async fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
+        self.create_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a backup. You can delete both manual and automated backups. This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when a backup deletion is already in progress. A <code>ResourceNotFoundException</code> is thrown when the backup does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
+    async fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        self.delete_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes the server and the underlying AWS CloudFormation stacks (including the server's EC2 instance). When you run this command, the server state is updated to <code>DELETING</code>. After the server is deleted, it is no longer returned by <code>DescribeServer</code> requests. If the AWS CloudFormation stack cannot be deleted, the server cannot be deleted. </p> <p> This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when a server deletion is already in progress. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p> <p> </p>
+    async fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> Result<DeleteServerResponse, RusotoError<DeleteServerError>> {
+        self.delete_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Describes your OpsWorks-CM account attributes. </p> <p> This operation is synchronous. </p>
+    async fn describe_account_attributes(
+        &self,
+    ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
+    {
+        self.describe_account_attributes_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Describes backups. The results are ordered by time, with newest backups first. If you do not specify a BackupId or ServerName, the command returns all backups. </p> <p> This operation is synchronous. </p> <p> A <code>ResourceNotFoundException</code> is thrown when the backup does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    async fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+        self.describe_backups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Describes events for a specified server. Results are ordered by time, with newest events first. </p> <p> This operation is synchronous. </p> <p> A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    async fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.describe_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns the current status of an existing association or disassociation request. </p> <p> A <code>ResourceNotFoundException</code> is thrown when no recent association or disassociation request with the specified token is found, or when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    async fn describe_node_association_status(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> Result<
+        DescribeNodeAssociationStatusResponse,
+        RusotoError<DescribeNodeAssociationStatusError>,
+    > {
+        self.describe_node_association_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Lists all configuration management servers that are identified with your account. Only the stored results from Amazon DynamoDB are returned. AWS OpsWorks CM does not query other services. </p> <p> This operation is synchronous. </p> <p> A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    async fn describe_servers(
+        &self,
+        input: DescribeServersRequest,
+    ) -> Result<DescribeServersResponse, RusotoError<DescribeServersError>> {
+        self.describe_servers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Disassociates a node from an AWS OpsWorks CM server, and removes the node from the server's managed nodes. After a node is disassociated, the node key pair is no longer valid for accessing the configuration manager's API. For more information about how to associate a node, see <a>AssociateNode</a>. </p> <p>A node can can only be disassociated from a server that is in a <code>HEALTHY</code> state. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    async fn disassociate_node(
+        &self,
+        input: DisassociateNodeRequest,
+    ) -> Result<DisassociateNodeResponse, RusotoError<DisassociateNodeError>> {
+        self.disassociate_node_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Exports a specified server engine attribute as a base64-encoded string. For example, you can export user data that you can use in EC2 to associate nodes with a server. </p> <p> This operation is synchronous. </p> <p> A <code>ValidationException</code> is raised when parameters of the request are not valid. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. An <code>InvalidStateException</code> is thrown when the server is in any of the following states: CREATING, TERMINATED, FAILED or DELETING. </p>
+    async fn export_server_engine_attribute(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> Result<ExportServerEngineAttributeResponse, RusotoError<ExportServerEngineAttributeError>>
+    {
+        self.export_server_engine_attribute_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of tags that are applied to the specified AWS OpsWorks for Chef Automate or AWS OpsWorks for Puppet Enterprise servers or backups.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Restores a backup to a server that is in a <code>CONNECTION_LOST</code>, <code>HEALTHY</code>, <code>RUNNING</code>, <code>UNHEALTHY</code>, or <code>TERMINATED</code> state. When you run RestoreServer, the server's EC2 instance is deleted, and a new EC2 instance is configured. RestoreServer maintains the existing server endpoint, so configuration management of the server's client devices (nodes) should continue to work. </p> <p>Restoring from a backup is performed by creating a new EC2 instance. If restoration is successful, and the server is in a <code>HEALTHY</code> state, AWS OpsWorks CM switches traffic over to the new instance. After restoration is finished, the old EC2 instance is maintained in a <code>Running</code> or <code>Stopped</code> state, but is eventually terminated.</p> <p> This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when the server is not in a valid state. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    async fn restore_server(
+        &self,
+        input: RestoreServerRequest,
+    ) -> Result<RestoreServerResponse, RusotoError<RestoreServerError>> {
+        self.restore_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Manually starts server maintenance. This command can be useful if an earlier maintenance attempt failed, and the underlying cause of maintenance failure has been resolved. The server is in an <code>UNDER_MAINTENANCE</code> state while maintenance is in progress. </p> <p> Maintenance can only be started on servers in <code>HEALTHY</code> and <code>UNHEALTHY</code> states. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    async fn start_maintenance(
+        &self,
+        input: StartMaintenanceRequest,
+    ) -> Result<StartMaintenanceResponse, RusotoError<StartMaintenanceError>> {
+        self.start_maintenance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Applies tags to an AWS OpsWorks for Chef Automate or AWS OpsWorks for Puppet Enterprise server, or to server backups.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes specified tags from an AWS OpsWorks-CM server or backup.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Updates settings for a server. </p> <p> This operation is synchronous. </p>
+    async fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
+        self.update_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Updates engine-specific attributes on a specified server. The server enters the <code>MODIFYING</code> state when this operation is in progress. Only one update can occur at a time. You can use this command to reset a Chef server's public key (<code>CHEF_PIVOTAL_KEY</code>) or a Puppet server's admin password (<code>PUPPET_ADMIN_PASSWORD</code>). </p> <p> This operation is asynchronous. </p> <p> This operation can only be called for servers in <code>HEALTHY</code> or <code>UNHEALTHY</code> states. Otherwise, an <code>InvalidStateException</code> is raised. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    async fn update_server_engine_attributes(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> Result<UpdateServerEngineAttributesResponse, RusotoError<UpdateServerEngineAttributesError>>
+    {
+        self.update_server_engine_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1317,6 +1317,7 @@ pub struct UpdatePolicyResponse {
 
 /// Errors returned by AcceptHandshake
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptHandshakeError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -1340,6 +1341,8 @@ pub enum AcceptHandshakeError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptHandshakeError {
@@ -1347,52 +1350,64 @@ impl AcceptHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(AcceptHandshakeError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptHandshakeError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AcceptHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service(AcceptHandshakeError::AccessDenied(err.msg), &res)
                 }
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(AcceptHandshakeError::AccessDeniedForDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptHandshakeError::AccessDeniedForDependency(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AcceptHandshakeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptHandshakeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "HandshakeAlreadyInStateException" => {
-                    return RusotoError::Service(AcceptHandshakeError::HandshakeAlreadyInState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptHandshakeError::HandshakeAlreadyInState(err.msg),
+                        &res,
+                    )
                 }
                 "HandshakeConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptHandshakeError::HandshakeConstraintViolation(err.msg),
+                        &res,
                     )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(AcceptHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service(
+                        AcceptHandshakeError::HandshakeNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidHandshakeTransitionException" => {
-                    return RusotoError::Service(AcceptHandshakeError::InvalidHandshakeTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptHandshakeError::InvalidHandshakeTransition(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AcceptHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service(AcceptHandshakeError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AcceptHandshakeError::Service(err.msg))
+                    return RusotoError::service(AcceptHandshakeError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AcceptHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AcceptHandshakeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AcceptHandshakeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1413,12 +1428,14 @@ impl fmt::Display for AcceptHandshakeError {
             AcceptHandshakeError::InvalidInput(ref cause) => write!(f, "{}", cause),
             AcceptHandshakeError::Service(ref cause) => write!(f, "{}", cause),
             AcceptHandshakeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            AcceptHandshakeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcceptHandshakeError {}
 /// Errors returned by AttachPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachPolicyError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -1446,6 +1463,8 @@ pub enum AttachPolicyError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachPolicyError {
@@ -1453,52 +1472,67 @@ impl AttachPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(AttachPolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachPolicyError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(AttachPolicyError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AttachPolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        AttachPolicyError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(AttachPolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        AttachPolicyError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicatePolicyAttachmentException" => {
-                    return RusotoError::Service(AttachPolicyError::DuplicatePolicyAttachment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachPolicyError::DuplicatePolicyAttachment(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(AttachPolicyError::InvalidInput(err.msg), &res)
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(AttachPolicyError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachPolicyError::PolicyChangesInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(AttachPolicyError::PolicyNotFound(err.msg), &res)
                 }
                 "PolicyTypeNotEnabledException" => {
-                    return RusotoError::Service(AttachPolicyError::PolicyTypeNotEnabled(err.msg))
+                    return RusotoError::service(
+                        AttachPolicyError::PolicyTypeNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachPolicyError::Service(err.msg))
+                    return RusotoError::service(AttachPolicyError::Service(err.msg), &res)
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::TargetNotFound(err.msg))
+                    return RusotoError::service(AttachPolicyError::TargetNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AttachPolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(AttachPolicyError::TooManyRequests(err.msg), &res)
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(AttachPolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        AttachPolicyError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AttachPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1521,12 +1555,14 @@ impl fmt::Display for AttachPolicyError {
             AttachPolicyError::TargetNotFound(ref cause) => write!(f, "{}", cause),
             AttachPolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AttachPolicyError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            AttachPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachPolicyError {}
 /// Errors returned by CancelHandshake
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelHandshakeError {
     /// <p>You don't have permissions to perform the requested operation. The user or role that is making the request must have at least one IAM permissions policy attached that grants the required permissions. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access.html">Access Management</a> in the <i>IAM User Guide.</i> </p>
     AccessDenied(String),
@@ -1544,6 +1580,8 @@ pub enum CancelHandshakeError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelHandshakeError {
@@ -1551,37 +1589,46 @@ impl CancelHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service(CancelHandshakeError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CancelHandshakeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelHandshakeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "HandshakeAlreadyInStateException" => {
-                    return RusotoError::Service(CancelHandshakeError::HandshakeAlreadyInState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelHandshakeError::HandshakeAlreadyInState(err.msg),
+                        &res,
+                    )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(CancelHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service(
+                        CancelHandshakeError::HandshakeNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidHandshakeTransitionException" => {
-                    return RusotoError::Service(CancelHandshakeError::InvalidHandshakeTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelHandshakeError::InvalidHandshakeTransition(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CancelHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service(CancelHandshakeError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CancelHandshakeError::Service(err.msg))
+                    return RusotoError::service(CancelHandshakeError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CancelHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CancelHandshakeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelHandshakeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1599,12 +1646,14 @@ impl fmt::Display for CancelHandshakeError {
             CancelHandshakeError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CancelHandshakeError::Service(ref cause) => write!(f, "{}", cause),
             CancelHandshakeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CancelHandshakeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelHandshakeError {}
 /// Errors returned by CreateAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAccountError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -1624,6 +1673,8 @@ pub enum CreateAccountError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAccountError {
@@ -1631,42 +1682,49 @@ impl CreateAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(CreateAccountError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAccountError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateAccountError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateAccountError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAccountError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreateAccountError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        CreateAccountError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "FinalizingOrganizationException" => {
-                    return RusotoError::Service(CreateAccountError::FinalizingOrganization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAccountError::FinalizingOrganization(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateAccountError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateAccountError::Service(err.msg))
+                    return RusotoError::service(CreateAccountError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateAccountError::TooManyRequests(err.msg), &res)
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(CreateAccountError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAccountError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1685,12 +1743,14 @@ impl fmt::Display for CreateAccountError {
             CreateAccountError::Service(ref cause) => write!(f, "{}", cause),
             CreateAccountError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateAccountError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            CreateAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAccountError {}
 /// Errors returned by CreateGovCloudAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGovCloudAccountError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -1710,6 +1770,8 @@ pub enum CreateGovCloudAccountError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGovCloudAccountError {
@@ -1717,46 +1779,58 @@ impl CreateGovCloudAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateGovCloudAccountError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateGovCloudAccountError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateGovCloudAccountError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGovCloudAccountError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "FinalizingOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateGovCloudAccountError::FinalizingOrganization(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateGovCloudAccountError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::Service(err.msg))
+                    return RusotoError::service(CreateGovCloudAccountError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGovCloudAccountError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateGovCloudAccountError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGovCloudAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1777,12 +1851,14 @@ impl fmt::Display for CreateGovCloudAccountError {
             CreateGovCloudAccountError::Service(ref cause) => write!(f, "{}", cause),
             CreateGovCloudAccountError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateGovCloudAccountError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            CreateGovCloudAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGovCloudAccountError {}
 /// Errors returned by CreateOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateOrganizationError {
     /// <p>You don't have permissions to perform the requested operation. The user or role that is making the request must have at least one IAM permissions policy attached that grants the required permissions. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access.html">Access Management</a> in the <i>IAM User Guide.</i> </p>
     AccessDenied(String),
@@ -1800,6 +1876,8 @@ pub enum CreateOrganizationError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateOrganizationError {
@@ -1807,39 +1885,52 @@ impl CreateOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateOrganizationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateOrganizationError::AccessDeniedForDependency(err.msg),
+                        &res,
                     )
                 }
                 "AlreadyInOrganizationException" => {
-                    return RusotoError::Service(CreateOrganizationError::AlreadyInOrganization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOrganizationError::AlreadyInOrganization(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateOrganizationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOrganizationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreateOrganizationError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOrganizationError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateOrganizationError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateOrganizationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateOrganizationError::Service(err.msg))
+                    return RusotoError::service(CreateOrganizationError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateOrganizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateOrganizationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateOrganizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1857,12 +1948,14 @@ impl fmt::Display for CreateOrganizationError {
             CreateOrganizationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateOrganizationError::Service(ref cause) => write!(f, "{}", cause),
             CreateOrganizationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateOrganizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateOrganizationError {}
 /// Errors returned by CreateOrganizationalUnit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateOrganizationalUnitError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -1882,6 +1975,8 @@ pub enum CreateOrganizationalUnitError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateOrganizationalUnitError {
@@ -1889,50 +1984,61 @@ impl CreateOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOrganizationalUnitError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateOrganizationalUnitError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateOrganizationalUnitError::ConstraintViolation(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateOrganizationalUnitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateOrganizationalUnitError::DuplicateOrganizationalUnit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOrganizationalUnitError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::ParentNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOrganizationalUnitError::ParentNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service(
+                        CreateOrganizationalUnitError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOrganizationalUnitError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateOrganizationalUnitError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1957,12 +2063,14 @@ impl fmt::Display for CreateOrganizationalUnitError {
             CreateOrganizationalUnitError::ParentNotFound(ref cause) => write!(f, "{}", cause),
             CreateOrganizationalUnitError::Service(ref cause) => write!(f, "{}", cause),
             CreateOrganizationalUnitError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateOrganizationalUnitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateOrganizationalUnitError {}
 /// Errors returned by CreatePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePolicyError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -1986,6 +2094,8 @@ pub enum CreatePolicyError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePolicyError {
@@ -1993,46 +2103,58 @@ impl CreatePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(CreatePolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePolicyError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(CreatePolicyError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreatePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        CreatePolicyError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreatePolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        CreatePolicyError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicatePolicyException" => {
-                    return RusotoError::Service(CreatePolicyError::DuplicatePolicy(err.msg))
+                    return RusotoError::service(CreatePolicyError::DuplicatePolicy(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(CreatePolicyError::InvalidInput(err.msg), &res)
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(CreatePolicyError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePolicyError::MalformedPolicyDocument(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyTypeNotAvailableForOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePolicyError::PolicyTypeNotAvailableForOrganization(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreatePolicyError::Service(err.msg))
+                    return RusotoError::service(CreatePolicyError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreatePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreatePolicyError::TooManyRequests(err.msg), &res)
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(CreatePolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        CreatePolicyError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2055,12 +2177,14 @@ impl fmt::Display for CreatePolicyError {
             CreatePolicyError::Service(ref cause) => write!(f, "{}", cause),
             CreatePolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreatePolicyError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            CreatePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePolicyError {}
 /// Errors returned by DeclineHandshake
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeclineHandshakeError {
     /// <p>You don't have permissions to perform the requested operation. The user or role that is making the request must have at least one IAM permissions policy attached that grants the required permissions. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access.html">Access Management</a> in the <i>IAM User Guide.</i> </p>
     AccessDenied(String),
@@ -2078,6 +2202,8 @@ pub enum DeclineHandshakeError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeclineHandshakeError {
@@ -2085,37 +2211,46 @@ impl DeclineHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeclineHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service(DeclineHandshakeError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeclineHandshakeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeclineHandshakeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "HandshakeAlreadyInStateException" => {
-                    return RusotoError::Service(DeclineHandshakeError::HandshakeAlreadyInState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeclineHandshakeError::HandshakeAlreadyInState(err.msg),
+                        &res,
+                    )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(DeclineHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service(
+                        DeclineHandshakeError::HandshakeNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidHandshakeTransitionException" => {
-                    return RusotoError::Service(DeclineHandshakeError::InvalidHandshakeTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeclineHandshakeError::InvalidHandshakeTransition(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeclineHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service(DeclineHandshakeError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeclineHandshakeError::Service(err.msg))
+                    return RusotoError::service(DeclineHandshakeError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeclineHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeclineHandshakeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeclineHandshakeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2133,12 +2268,14 @@ impl fmt::Display for DeclineHandshakeError {
             DeclineHandshakeError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeclineHandshakeError::Service(ref cause) => write!(f, "{}", cause),
             DeclineHandshakeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeclineHandshakeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeclineHandshakeError {}
 /// Errors returned by DeleteOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteOrganizationError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2154,6 +2291,8 @@ pub enum DeleteOrganizationError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteOrganizationError {
@@ -2161,34 +2300,46 @@ impl DeleteOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DeleteOrganizationError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteOrganizationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteOrganizationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOrganizationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteOrganizationError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteOrganizationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OrganizationNotEmptyException" => {
-                    return RusotoError::Service(DeleteOrganizationError::OrganizationNotEmpty(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOrganizationError::OrganizationNotEmpty(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteOrganizationError::Service(err.msg))
+                    return RusotoError::service(DeleteOrganizationError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteOrganizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteOrganizationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteOrganizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2205,12 +2356,14 @@ impl fmt::Display for DeleteOrganizationError {
             DeleteOrganizationError::OrganizationNotEmpty(ref cause) => write!(f, "{}", cause),
             DeleteOrganizationError::Service(ref cause) => write!(f, "{}", cause),
             DeleteOrganizationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteOrganizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteOrganizationError {}
 /// Errors returned by DeleteOrganizationalUnit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteOrganizationalUnitError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2228,6 +2381,8 @@ pub enum DeleteOrganizationalUnitError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteOrganizationalUnitError {
@@ -2235,45 +2390,55 @@ impl DeleteOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOrganizationalUnitError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteOrganizationalUnitError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOrganizationalUnitError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OrganizationalUnitNotEmptyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteOrganizationalUnitError::OrganizationalUnitNotEmpty(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationalUnitNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteOrganizationalUnitError::OrganizationalUnitNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service(
+                        DeleteOrganizationalUnitError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOrganizationalUnitError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteOrganizationalUnitError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2299,12 +2464,14 @@ impl fmt::Display for DeleteOrganizationalUnitError {
             }
             DeleteOrganizationalUnitError::Service(ref cause) => write!(f, "{}", cause),
             DeleteOrganizationalUnitError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteOrganizationalUnitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteOrganizationalUnitError {}
 /// Errors returned by DeletePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePolicyError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2324,6 +2491,8 @@ pub enum DeletePolicyError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePolicyError {
@@ -2331,36 +2500,43 @@ impl DeletePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DeletePolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePolicyError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(DeletePolicyError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeletePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DeletePolicyError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(DeletePolicyError::InvalidInput(err.msg), &res)
                 }
                 "PolicyInUseException" => {
-                    return RusotoError::Service(DeletePolicyError::PolicyInUse(err.msg))
+                    return RusotoError::service(DeletePolicyError::PolicyInUse(err.msg), &res)
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(DeletePolicyError::PolicyNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeletePolicyError::Service(err.msg))
+                    return RusotoError::service(DeletePolicyError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeletePolicyError::TooManyRequests(err.msg), &res)
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DeletePolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        DeletePolicyError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2379,12 +2555,14 @@ impl fmt::Display for DeletePolicyError {
             DeletePolicyError::Service(ref cause) => write!(f, "{}", cause),
             DeletePolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeletePolicyError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            DeletePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePolicyError {}
 /// Errors returned by DescribeAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAccountError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2398,6 +2576,8 @@ pub enum DescribeAccountError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAccountError {
@@ -2405,27 +2585,34 @@ impl DescribeAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DescribeAccountError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAccountError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeAccountError::AccessDenied(err.msg), &res)
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(DescribeAccountError::AccountNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeAccountError::AccountNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeAccountError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeAccountError::Service(err.msg))
+                    return RusotoError::service(DescribeAccountError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeAccountError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2441,12 +2628,14 @@ impl fmt::Display for DescribeAccountError {
             DescribeAccountError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeAccountError::Service(ref cause) => write!(f, "{}", cause),
             DescribeAccountError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAccountError {}
 /// Errors returned by DescribeCreateAccountStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCreateAccountStatusError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2462,6 +2651,8 @@ pub enum DescribeCreateAccountStatusError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCreateAccountStatusError {
@@ -2471,40 +2662,54 @@ impl DescribeCreateAccountStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCreateAccountStatusError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCreateAccountStatusError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CreateAccountStatusNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCreateAccountStatusError::CreateAccountStatusNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCreateAccountStatusError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::Service(err.msg))
+                    return RusotoError::service(
+                        DescribeCreateAccountStatusError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCreateAccountStatusError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCreateAccountStatusError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeCreateAccountStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2527,12 +2732,14 @@ impl fmt::Display for DescribeCreateAccountStatusError {
             DescribeCreateAccountStatusError::UnsupportedAPIEndpoint(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCreateAccountStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCreateAccountStatusError {}
 /// Errors returned by DescribeEffectivePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEffectivePolicyError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2552,6 +2759,8 @@ pub enum DescribeEffectivePolicyError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEffectivePolicyError {
@@ -2559,50 +2768,61 @@ impl DescribeEffectivePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectivePolicyError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEffectivePolicyError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEffectivePolicyError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "EffectivePolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectivePolicyError::EffectivePolicyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEffectivePolicyError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::Service(err.msg))
+                    return RusotoError::service(
+                        DescribeEffectivePolicyError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::TargetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEffectivePolicyError::TargetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEffectivePolicyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectivePolicyError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEffectivePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2627,12 +2847,14 @@ impl fmt::Display for DescribeEffectivePolicyError {
             DescribeEffectivePolicyError::UnsupportedAPIEndpoint(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEffectivePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEffectivePolicyError {}
 /// Errors returned by DescribeHandshake
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHandshakeError {
     /// <p>You don't have permissions to perform the requested operation. The user or role that is making the request must have at least one IAM permissions policy attached that grants the required permissions. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access.html">Access Management</a> in the <i>IAM User Guide.</i> </p>
     AccessDenied(String),
@@ -2646,6 +2868,8 @@ pub enum DescribeHandshakeError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHandshakeError {
@@ -2653,27 +2877,40 @@ impl DescribeHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeHandshakeError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DescribeHandshakeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeHandshakeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(DescribeHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeHandshakeError::HandshakeNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeHandshakeError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeHandshakeError::Service(err.msg))
+                    return RusotoError::service(DescribeHandshakeError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeHandshakeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeHandshakeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2689,12 +2926,14 @@ impl fmt::Display for DescribeHandshakeError {
             DescribeHandshakeError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeHandshakeError::Service(ref cause) => write!(f, "{}", cause),
             DescribeHandshakeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeHandshakeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHandshakeError {}
 /// Errors returned by DescribeOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeOrganizationError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2706,6 +2945,8 @@ pub enum DescribeOrganizationError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeOrganizationError {
@@ -2713,28 +2954,34 @@ impl DescribeOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeOrganizationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DescribeOrganizationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOrganizationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeOrganizationError::Service(err.msg))
+                    return RusotoError::service(DescribeOrganizationError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOrganizationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOrganizationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeOrganizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2751,12 +2998,14 @@ impl fmt::Display for DescribeOrganizationError {
             DescribeOrganizationError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DescribeOrganizationError::Service(ref cause) => write!(f, "{}", cause),
             DescribeOrganizationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeOrganizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeOrganizationError {}
 /// Errors returned by DescribeOrganizationalUnit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeOrganizationalUnitError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2770,6 +3019,8 @@ pub enum DescribeOrganizationalUnitError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeOrganizationalUnitError {
@@ -2779,35 +3030,45 @@ impl DescribeOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOrganizationalUnitError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOrganizationalUnitError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OrganizationalUnitNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationalUnitError::OrganizationalUnitNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service(
+                        DescribeOrganizationalUnitError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOrganizationalUnitError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeOrganizationalUnitError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2827,12 +3088,14 @@ impl fmt::Display for DescribeOrganizationalUnitError {
             }
             DescribeOrganizationalUnitError::Service(ref cause) => write!(f, "{}", cause),
             DescribeOrganizationalUnitError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeOrganizationalUnitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeOrganizationalUnitError {}
 /// Errors returned by DescribePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePolicyError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2848,6 +3111,8 @@ pub enum DescribePolicyError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePolicyError {
@@ -2855,32 +3120,37 @@ impl DescribePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DescribePolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePolicyError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribePolicyError::AccessDenied(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribePolicyError::InvalidInput(err.msg), &res)
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DescribePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(DescribePolicyError::PolicyNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribePolicyError::Service(err.msg))
+                    return RusotoError::service(DescribePolicyError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribePolicyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DescribePolicyError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePolicyError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2897,12 +3167,14 @@ impl fmt::Display for DescribePolicyError {
             DescribePolicyError::Service(ref cause) => write!(f, "{}", cause),
             DescribePolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribePolicyError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            DescribePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePolicyError {}
 /// Errors returned by DetachPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachPolicyError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -2928,6 +3200,8 @@ pub enum DetachPolicyError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachPolicyError {
@@ -2935,47 +3209,61 @@ impl DetachPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DetachPolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetachPolicyError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(DetachPolicyError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DetachPolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DetachPolicyError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DetachPolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        DetachPolicyError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DetachPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(DetachPolicyError::InvalidInput(err.msg), &res)
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(DetachPolicyError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetachPolicyError::PolicyChangesInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyNotAttachedException" => {
-                    return RusotoError::Service(DetachPolicyError::PolicyNotAttached(err.msg))
+                    return RusotoError::service(
+                        DetachPolicyError::PolicyNotAttached(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DetachPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(DetachPolicyError::PolicyNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachPolicyError::Service(err.msg))
+                    return RusotoError::service(DetachPolicyError::Service(err.msg), &res)
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(DetachPolicyError::TargetNotFound(err.msg))
+                    return RusotoError::service(DetachPolicyError::TargetNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetachPolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(DetachPolicyError::TooManyRequests(err.msg), &res)
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DetachPolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        DetachPolicyError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetachPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2997,12 +3285,14 @@ impl fmt::Display for DetachPolicyError {
             DetachPolicyError::TargetNotFound(ref cause) => write!(f, "{}", cause),
             DetachPolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DetachPolicyError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            DetachPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachPolicyError {}
 /// Errors returned by DisableAWSServiceAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableAWSServiceAccessError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3018,6 +3308,8 @@ pub enum DisableAWSServiceAccessError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableAWSServiceAccessError {
@@ -3025,40 +3317,49 @@ impl DisableAWSServiceAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableAWSServiceAccessError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableAWSServiceAccessError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableAWSServiceAccessError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableAWSServiceAccessError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableAWSServiceAccessError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::Service(err.msg))
+                    return RusotoError::service(
+                        DisableAWSServiceAccessError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableAWSServiceAccessError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableAWSServiceAccessError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3079,12 +3380,14 @@ impl fmt::Display for DisableAWSServiceAccessError {
             DisableAWSServiceAccessError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DisableAWSServiceAccessError::Service(ref cause) => write!(f, "{}", cause),
             DisableAWSServiceAccessError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DisableAWSServiceAccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableAWSServiceAccessError {}
 /// Errors returned by DisablePolicyType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisablePolicyTypeError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3108,6 +3411,8 @@ pub enum DisablePolicyTypeError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisablePolicyTypeError {
@@ -3115,52 +3420,70 @@ impl DisablePolicyTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::PolicyChangesInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyTypeNotEnabledException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::PolicyTypeNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::PolicyTypeNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "RootNotFoundException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::RootNotFound(err.msg))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::RootNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::Service(err.msg))
+                    return RusotoError::service(DisablePolicyTypeError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisablePolicyTypeError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisablePolicyTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3181,12 +3504,14 @@ impl fmt::Display for DisablePolicyTypeError {
             DisablePolicyTypeError::Service(ref cause) => write!(f, "{}", cause),
             DisablePolicyTypeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DisablePolicyTypeError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            DisablePolicyTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisablePolicyTypeError {}
 /// Errors returned by EnableAWSServiceAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableAWSServiceAccessError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3202,6 +3527,8 @@ pub enum EnableAWSServiceAccessError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableAWSServiceAccessError {
@@ -3209,36 +3536,49 @@ impl EnableAWSServiceAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableAWSServiceAccessError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        EnableAWSServiceAccessError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableAWSServiceAccessError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableAWSServiceAccessError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        EnableAWSServiceAccessError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::Service(err.msg))
+                    return RusotoError::service(
+                        EnableAWSServiceAccessError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableAWSServiceAccessError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableAWSServiceAccessError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3259,12 +3599,14 @@ impl fmt::Display for EnableAWSServiceAccessError {
             EnableAWSServiceAccessError::InvalidInput(ref cause) => write!(f, "{}", cause),
             EnableAWSServiceAccessError::Service(ref cause) => write!(f, "{}", cause),
             EnableAWSServiceAccessError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            EnableAWSServiceAccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableAWSServiceAccessError {}
 /// Errors returned by EnableAllFeatures
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableAllFeaturesError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3280,6 +3622,8 @@ pub enum EnableAllFeaturesError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableAllFeaturesError {
@@ -3287,34 +3631,46 @@ impl EnableAllFeaturesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableAllFeaturesError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        EnableAllFeaturesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableAllFeaturesError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "HandshakeConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableAllFeaturesError::HandshakeConstraintViolation(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        EnableAllFeaturesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::Service(err.msg))
+                    return RusotoError::service(EnableAllFeaturesError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        EnableAllFeaturesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableAllFeaturesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3333,12 +3689,14 @@ impl fmt::Display for EnableAllFeaturesError {
             EnableAllFeaturesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             EnableAllFeaturesError::Service(ref cause) => write!(f, "{}", cause),
             EnableAllFeaturesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            EnableAllFeaturesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableAllFeaturesError {}
 /// Errors returned by EnablePolicyType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnablePolicyTypeError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3364,6 +3722,8 @@ pub enum EnablePolicyTypeError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnablePolicyTypeError {
@@ -3371,57 +3731,67 @@ impl EnablePolicyTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnablePolicyTypeError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::AccessDenied(err.msg))
+                    return RusotoError::service(EnablePolicyTypeError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnablePolicyTypeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnablePolicyTypeError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::InvalidInput(err.msg))
+                    return RusotoError::service(EnablePolicyTypeError::InvalidInput(err.msg), &res)
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnablePolicyTypeError::PolicyChangesInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyTypeAlreadyEnabledException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::PolicyTypeAlreadyEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnablePolicyTypeError::PolicyTypeAlreadyEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyTypeNotAvailableForOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnablePolicyTypeError::PolicyTypeNotAvailableForOrganization(err.msg),
+                        &res,
                     )
                 }
                 "RootNotFoundException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::RootNotFound(err.msg))
+                    return RusotoError::service(EnablePolicyTypeError::RootNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::Service(err.msg))
+                    return RusotoError::service(EnablePolicyTypeError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        EnablePolicyTypeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnablePolicyTypeError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnablePolicyTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3445,12 +3815,14 @@ impl fmt::Display for EnablePolicyTypeError {
             EnablePolicyTypeError::Service(ref cause) => write!(f, "{}", cause),
             EnablePolicyTypeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             EnablePolicyTypeError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            EnablePolicyTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnablePolicyTypeError {}
 /// Errors returned by InviteAccountToOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InviteAccountToOrganizationError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3472,6 +3844,8 @@ pub enum InviteAccountToOrganizationError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InviteAccountToOrganizationError {
@@ -3481,55 +3855,72 @@ impl InviteAccountToOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         InviteAccountToOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InviteAccountToOrganizationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountOwnerNotVerifiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         InviteAccountToOrganizationError::AccountOwnerNotVerified(err.msg),
+                        &res,
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         InviteAccountToOrganizationError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateHandshakeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         InviteAccountToOrganizationError::DuplicateHandshake(err.msg),
+                        &res,
                     )
                 }
                 "FinalizingOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         InviteAccountToOrganizationError::FinalizingOrganization(err.msg),
+                        &res,
                     )
                 }
                 "HandshakeConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         InviteAccountToOrganizationError::HandshakeConstraintViolation(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InviteAccountToOrganizationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::Service(err.msg))
+                    return RusotoError::service(
+                        InviteAccountToOrganizationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InviteAccountToOrganizationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        InviteAccountToOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3561,12 +3952,14 @@ impl fmt::Display for InviteAccountToOrganizationError {
             InviteAccountToOrganizationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             InviteAccountToOrganizationError::Service(ref cause) => write!(f, "{}", cause),
             InviteAccountToOrganizationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            InviteAccountToOrganizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InviteAccountToOrganizationError {}
 /// Errors returned by LeaveOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum LeaveOrganizationError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3586,6 +3979,8 @@ pub enum LeaveOrganizationError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl LeaveOrganizationError {
@@ -3593,42 +3988,58 @@ impl LeaveOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(LeaveOrganizationError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LeaveOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(LeaveOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        LeaveOrganizationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(LeaveOrganizationError::AccountNotFound(err.msg))
+                    return RusotoError::service(
+                        LeaveOrganizationError::AccountNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(LeaveOrganizationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LeaveOrganizationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(LeaveOrganizationError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LeaveOrganizationError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(LeaveOrganizationError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        LeaveOrganizationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "MasterCannotLeaveOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         LeaveOrganizationError::MasterCannotLeaveOrganization(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(LeaveOrganizationError::Service(err.msg))
+                    return RusotoError::service(LeaveOrganizationError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(LeaveOrganizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        LeaveOrganizationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(LeaveOrganizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3649,12 +4060,14 @@ impl fmt::Display for LeaveOrganizationError {
             }
             LeaveOrganizationError::Service(ref cause) => write!(f, "{}", cause),
             LeaveOrganizationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            LeaveOrganizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for LeaveOrganizationError {}
 /// Errors returned by ListAWSServiceAccessForOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAWSServiceAccessForOrganizationError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3668,6 +4081,8 @@ pub enum ListAWSServiceAccessForOrganizationError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAWSServiceAccessForOrganizationError {
@@ -3677,37 +4092,48 @@ impl ListAWSServiceAccessForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAWSServiceAccessForOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAWSServiceAccessForOrganizationError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAWSServiceAccessForOrganizationError::ConstraintViolation(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAWSServiceAccessForOrganizationError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAWSServiceAccessForOrganizationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAWSServiceAccessForOrganizationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAWSServiceAccessForOrganizationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListAWSServiceAccessForOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3733,12 +4159,16 @@ impl fmt::Display for ListAWSServiceAccessForOrganizationError {
             ListAWSServiceAccessForOrganizationError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListAWSServiceAccessForOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListAWSServiceAccessForOrganizationError {}
 /// Errors returned by ListAccounts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAccountsError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3750,6 +4180,8 @@ pub enum ListAccountsError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAccountsError {
@@ -3757,24 +4189,25 @@ impl ListAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListAccountsError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAccountsError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAccountsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListAccountsError::AccessDenied(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAccountsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListAccountsError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAccountsError::Service(err.msg))
+                    return RusotoError::service(ListAccountsError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListAccountsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAccountsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3789,12 +4222,14 @@ impl fmt::Display for ListAccountsError {
             ListAccountsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListAccountsError::Service(ref cause) => write!(f, "{}", cause),
             ListAccountsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListAccountsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAccountsError {}
 /// Errors returned by ListAccountsForParent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAccountsForParentError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3808,6 +4243,8 @@ pub enum ListAccountsForParentError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAccountsForParentError {
@@ -3815,31 +4252,40 @@ impl ListAccountsForParentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAccountsForParentError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAccountsForParentError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListAccountsForParentError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAccountsForParentError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListAccountsForParentError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(ListAccountsForParentError::ParentNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAccountsForParentError::ParentNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAccountsForParentError::Service(err.msg))
+                    return RusotoError::service(ListAccountsForParentError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountsForParentError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAccountsForParentError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAccountsForParentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3857,12 +4303,14 @@ impl fmt::Display for ListAccountsForParentError {
             ListAccountsForParentError::ParentNotFound(ref cause) => write!(f, "{}", cause),
             ListAccountsForParentError::Service(ref cause) => write!(f, "{}", cause),
             ListAccountsForParentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListAccountsForParentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAccountsForParentError {}
 /// Errors returned by ListChildren
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListChildrenError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3876,6 +4324,8 @@ pub enum ListChildrenError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListChildrenError {
@@ -3883,27 +4333,28 @@ impl ListChildrenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListChildrenError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListChildrenError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListChildrenError::AccessDenied(err.msg))
+                    return RusotoError::service(ListChildrenError::AccessDenied(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListChildrenError::InvalidInput(err.msg))
+                    return RusotoError::service(ListChildrenError::InvalidInput(err.msg), &res)
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(ListChildrenError::ParentNotFound(err.msg))
+                    return RusotoError::service(ListChildrenError::ParentNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListChildrenError::Service(err.msg))
+                    return RusotoError::service(ListChildrenError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListChildrenError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListChildrenError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListChildrenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3919,12 +4370,14 @@ impl fmt::Display for ListChildrenError {
             ListChildrenError::ParentNotFound(ref cause) => write!(f, "{}", cause),
             ListChildrenError::Service(ref cause) => write!(f, "{}", cause),
             ListChildrenError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListChildrenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListChildrenError {}
 /// Errors returned by ListCreateAccountStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCreateAccountStatusError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -3938,6 +4391,8 @@ pub enum ListCreateAccountStatusError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCreateAccountStatusError {
@@ -3945,35 +4400,43 @@ impl ListCreateAccountStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListCreateAccountStatusError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCreateAccountStatusError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCreateAccountStatusError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::Service(err.msg))
+                    return RusotoError::service(
+                        ListCreateAccountStatusError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCreateAccountStatusError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListCreateAccountStatusError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCreateAccountStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3993,12 +4456,14 @@ impl fmt::Display for ListCreateAccountStatusError {
             ListCreateAccountStatusError::UnsupportedAPIEndpoint(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListCreateAccountStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCreateAccountStatusError {}
 /// Errors returned by ListHandshakesForAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHandshakesForAccountError {
     /// <p>You don't have permissions to perform the requested operation. The user or role that is making the request must have at least one IAM permissions policy attached that grants the required permissions. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access.html">Access Management</a> in the <i>IAM User Guide.</i> </p>
     AccessDenied(String),
@@ -4010,6 +4475,8 @@ pub enum ListHandshakesForAccountError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHandshakesForAccountError {
@@ -4017,30 +4484,37 @@ impl ListHandshakesForAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHandshakesForAccountError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListHandshakesForAccountError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHandshakesForAccountError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::Service(err.msg))
+                    return RusotoError::service(
+                        ListHandshakesForAccountError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHandshakesForAccountError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListHandshakesForAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4057,12 +4531,14 @@ impl fmt::Display for ListHandshakesForAccountError {
             ListHandshakesForAccountError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListHandshakesForAccountError::Service(ref cause) => write!(f, "{}", cause),
             ListHandshakesForAccountError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListHandshakesForAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHandshakesForAccountError {}
 /// Errors returned by ListHandshakesForOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHandshakesForOrganizationError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4076,6 +4552,8 @@ pub enum ListHandshakesForOrganizationError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHandshakesForOrganizationError {
@@ -4085,37 +4563,48 @@ impl ListHandshakesForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListHandshakesForOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListHandshakesForOrganizationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHandshakesForOrganizationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListHandshakesForOrganizationError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListHandshakesForOrganizationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHandshakesForOrganizationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListHandshakesForOrganizationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHandshakesForOrganizationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListHandshakesForOrganizationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListHandshakesForOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4137,12 +4626,16 @@ impl fmt::Display for ListHandshakesForOrganizationError {
             ListHandshakesForOrganizationError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListHandshakesForOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListHandshakesForOrganizationError {}
 /// Errors returned by ListOrganizationalUnitsForParent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOrganizationalUnitsForParentError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4156,6 +4649,8 @@ pub enum ListOrganizationalUnitsForParentError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOrganizationalUnitsForParentError {
@@ -4165,37 +4660,48 @@ impl ListOrganizationalUnitsForParentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOrganizationalUnitsForParentError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOrganizationalUnitsForParentError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOrganizationalUnitsForParentError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOrganizationalUnitsForParentError::ParentNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListOrganizationalUnitsForParentError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOrganizationalUnitsForParentError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOrganizationalUnitsForParentError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListOrganizationalUnitsForParentError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4221,12 +4727,16 @@ impl fmt::Display for ListOrganizationalUnitsForParentError {
             ListOrganizationalUnitsForParentError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListOrganizationalUnitsForParentError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListOrganizationalUnitsForParentError {}
 /// Errors returned by ListParents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListParentsError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4240,6 +4750,8 @@ pub enum ListParentsError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListParentsError {
@@ -4247,27 +4759,28 @@ impl ListParentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListParentsError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListParentsError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListParentsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListParentsError::AccessDenied(err.msg), &res)
                 }
                 "ChildNotFoundException" => {
-                    return RusotoError::Service(ListParentsError::ChildNotFound(err.msg))
+                    return RusotoError::service(ListParentsError::ChildNotFound(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListParentsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListParentsError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListParentsError::Service(err.msg))
+                    return RusotoError::service(ListParentsError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListParentsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListParentsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListParentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4283,12 +4796,14 @@ impl fmt::Display for ListParentsError {
             ListParentsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListParentsError::Service(ref cause) => write!(f, "{}", cause),
             ListParentsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListParentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListParentsError {}
 /// Errors returned by ListPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPoliciesError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4302,6 +4817,8 @@ pub enum ListPoliciesError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPoliciesError {
@@ -4309,27 +4826,31 @@ impl ListPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListPoliciesError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPoliciesError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPoliciesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListPoliciesError::AccessDenied(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPoliciesError::InvalidInput(err.msg))
+                    return RusotoError::service(ListPoliciesError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListPoliciesError::Service(err.msg))
+                    return RusotoError::service(ListPoliciesError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPoliciesError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListPoliciesError::TooManyRequests(err.msg), &res)
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(ListPoliciesError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        ListPoliciesError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPoliciesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4345,12 +4866,14 @@ impl fmt::Display for ListPoliciesError {
             ListPoliciesError::Service(ref cause) => write!(f, "{}", cause),
             ListPoliciesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListPoliciesError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            ListPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPoliciesError {}
 /// Errors returned by ListPoliciesForTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPoliciesForTargetError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4366,6 +4889,8 @@ pub enum ListPoliciesForTargetError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPoliciesForTargetError {
@@ -4373,36 +4898,46 @@ impl ListPoliciesForTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPoliciesForTargetError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListPoliciesForTargetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListPoliciesForTargetError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::Service(err.msg))
+                    return RusotoError::service(ListPoliciesForTargetError::Service(err.msg), &res)
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::TargetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPoliciesForTargetError::TargetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPoliciesForTargetError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPoliciesForTargetError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPoliciesForTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4421,12 +4956,14 @@ impl fmt::Display for ListPoliciesForTargetError {
             ListPoliciesForTargetError::TargetNotFound(ref cause) => write!(f, "{}", cause),
             ListPoliciesForTargetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListPoliciesForTargetError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            ListPoliciesForTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPoliciesForTargetError {}
 /// Errors returned by ListRoots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRootsError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4438,6 +4975,8 @@ pub enum ListRootsError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRootsError {
@@ -4445,22 +4984,25 @@ impl ListRootsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListRootsError::AWSOrganizationsNotInUse(err.msg))
+                    return RusotoError::service(
+                        ListRootsError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRootsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListRootsError::AccessDenied(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListRootsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListRootsError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListRootsError::Service(err.msg))
+                    return RusotoError::service(ListRootsError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRootsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListRootsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRootsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4475,12 +5017,14 @@ impl fmt::Display for ListRootsError {
             ListRootsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListRootsError::Service(ref cause) => write!(f, "{}", cause),
             ListRootsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListRootsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRootsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4494,6 +5038,8 @@ pub enum ListTagsForResourceError {
     TargetNotFound(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -4501,27 +5047,40 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTagsForResourceError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Service(err.msg), &res)
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TargetNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::TargetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4537,12 +5096,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::Service(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TargetNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTargetsForPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTargetsForPolicyError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4558,6 +5119,8 @@ pub enum ListTargetsForPolicyError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTargetsForPolicyError {
@@ -4565,34 +5128,46 @@ impl ListTargetsForPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTargetsForPolicyError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTargetsForPolicyError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListTargetsForPolicyError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTargetsForPolicyError::PolicyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::Service(err.msg))
+                    return RusotoError::service(ListTargetsForPolicyError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTargetsForPolicyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTargetsForPolicyError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTargetsForPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4611,12 +5186,14 @@ impl fmt::Display for ListTargetsForPolicyError {
             ListTargetsForPolicyError::Service(ref cause) => write!(f, "{}", cause),
             ListTargetsForPolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListTargetsForPolicyError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            ListTargetsForPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTargetsForPolicyError {}
 /// Errors returned by MoveAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MoveAccountError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4638,6 +5215,8 @@ pub enum MoveAccountError {
     SourceParentNotFound(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MoveAccountError {
@@ -4645,41 +5224,49 @@ impl MoveAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(MoveAccountError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MoveAccountError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(MoveAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(MoveAccountError::AccessDenied(err.msg), &res)
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(MoveAccountError::AccountNotFound(err.msg))
+                    return RusotoError::service(MoveAccountError::AccountNotFound(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(MoveAccountError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        MoveAccountError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "DestinationParentNotFoundException" => {
-                    return RusotoError::Service(MoveAccountError::DestinationParentNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MoveAccountError::DestinationParentNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateAccountException" => {
-                    return RusotoError::Service(MoveAccountError::DuplicateAccount(err.msg))
+                    return RusotoError::service(MoveAccountError::DuplicateAccount(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(MoveAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(MoveAccountError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(MoveAccountError::Service(err.msg))
+                    return RusotoError::service(MoveAccountError::Service(err.msg), &res)
                 }
                 "SourceParentNotFoundException" => {
-                    return RusotoError::Service(MoveAccountError::SourceParentNotFound(err.msg))
+                    return RusotoError::service(
+                        MoveAccountError::SourceParentNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(MoveAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(MoveAccountError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(MoveAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4699,12 +5286,14 @@ impl fmt::Display for MoveAccountError {
             MoveAccountError::Service(ref cause) => write!(f, "{}", cause),
             MoveAccountError::SourceParentNotFound(ref cause) => write!(f, "{}", cause),
             MoveAccountError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            MoveAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for MoveAccountError {}
 /// Errors returned by RemoveAccountFromOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveAccountFromOrganizationError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4724,6 +5313,8 @@ pub enum RemoveAccountFromOrganizationError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveAccountFromOrganizationError {
@@ -4733,52 +5324,66 @@ impl RemoveAccountFromOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveAccountFromOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveAccountFromOrganizationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveAccountFromOrganizationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveAccountFromOrganizationError::AccountNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveAccountFromOrganizationError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveAccountFromOrganizationError::ConstraintViolation(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RemoveAccountFromOrganizationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveAccountFromOrganizationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "MasterCannotLeaveOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveAccountFromOrganizationError::MasterCannotLeaveOrganization(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveAccountFromOrganizationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveAccountFromOrganizationError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveAccountFromOrganizationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RemoveAccountFromOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4809,12 +5414,16 @@ impl fmt::Display for RemoveAccountFromOrganizationError {
             RemoveAccountFromOrganizationError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RemoveAccountFromOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RemoveAccountFromOrganizationError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4832,6 +5441,8 @@ pub enum TagResourceError {
     TargetNotFound(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4839,33 +5450,40 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(TagResourceError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(TagResourceError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(TagResourceError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service(TagResourceError::Service(err.msg), &res)
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::TargetNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::TargetNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4883,12 +5501,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::Service(ref cause) => write!(f, "{}", cause),
             TagResourceError::TargetNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4906,6 +5526,8 @@ pub enum UntagResourceError {
     TargetNotFound(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4913,35 +5535,40 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(UntagResourceError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UntagResourceError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(UntagResourceError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidInput(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service(UntagResourceError::Service(err.msg), &res)
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::TargetNotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::TargetNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4959,12 +5586,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::Service(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TargetNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateOrganizationalUnit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateOrganizationalUnitError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -4982,6 +5611,8 @@ pub enum UpdateOrganizationalUnitError {
     Service(String),
     /// <p>You have sent too many requests in too short a period of time. The limit helps protect against denial-of-service attacks. Try again later.</p> <p>For information on limits that affect AWS Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_limits.html">Limits of AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateOrganizationalUnitError {
@@ -4989,45 +5620,55 @@ impl UpdateOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateOrganizationalUnitError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateOrganizationalUnitError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateOrganizationalUnitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateOrganizationalUnitError::DuplicateOrganizationalUnit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateOrganizationalUnitError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OrganizationalUnitNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateOrganizationalUnitError::OrganizationalUnitNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service(
+                        UpdateOrganizationalUnitError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateOrganizationalUnitError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateOrganizationalUnitError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5053,12 +5694,14 @@ impl fmt::Display for UpdateOrganizationalUnitError {
             }
             UpdateOrganizationalUnitError::Service(ref cause) => write!(f, "{}", cause),
             UpdateOrganizationalUnitError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateOrganizationalUnitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateOrganizationalUnitError {}
 /// Errors returned by UpdatePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePolicyError {
     /// <p>Your account isn't a member of an organization. To make this request, you must use the credentials of an account that belongs to an organization.</p>
     AWSOrganizationsNotInUse(String),
@@ -5084,6 +5727,8 @@ pub enum UpdatePolicyError {
     TooManyRequests(String),
     /// <p>This action isn't available in the current Region.</p>
     UnsupportedAPIEndpoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePolicyError {
@@ -5091,49 +5736,61 @@ impl UpdatePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(UpdatePolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePolicyError::AWSOrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdatePolicyError::AccessDenied(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdatePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        UpdatePolicyError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(UpdatePolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        UpdatePolicyError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicatePolicyException" => {
-                    return RusotoError::Service(UpdatePolicyError::DuplicatePolicy(err.msg))
+                    return RusotoError::service(UpdatePolicyError::DuplicatePolicy(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdatePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdatePolicyError::InvalidInput(err.msg), &res)
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(UpdatePolicyError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePolicyError::MalformedPolicyDocument(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(UpdatePolicyError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePolicyError::PolicyChangesInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(UpdatePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(UpdatePolicyError::PolicyNotFound(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdatePolicyError::Service(err.msg))
+                    return RusotoError::service(UpdatePolicyError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdatePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdatePolicyError::TooManyRequests(err.msg), &res)
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(UpdatePolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        UpdatePolicyError::UnsupportedAPIEndpoint(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5155,6 +5812,7 @@ impl fmt::Display for UpdatePolicyError {
             UpdatePolicyError::Service(ref cause) => write!(f, "{}", cause),
             UpdatePolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdatePolicyError::UnsupportedAPIEndpoint(ref cause) => write!(f, "{}", cause),
+            UpdatePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -5456,6 +6114,7 @@ impl OrganizationsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> OrganizationsClient {
         OrganizationsClient {
             client: Client::shared(),
@@ -5481,124 +6140,278 @@ impl OrganizationsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> OrganizationsClient {
         OrganizationsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<OrganizationsClient> {
+        rusoto_core::ClientBuilder::new(OrganizationsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> OrganizationsClient {
+        OrganizationsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Organizations for OrganizationsClient {
-    /// <p>Sends a response to the originator of a handshake agreeing to the action proposed by the handshake request. </p> <p>This operation can be called only by the following principals when they also have the relevant IAM permissions:</p> <ul> <li> <p> <b>Invitation to join</b> or <b>Approve all features request</b> handshakes: only a principal from the member account. </p> <p>The user who calls the API for an invitation to join must have the <code>organizations:AcceptHandshake</code> permission. If you enabled all features in the organization, the user must also have the <code>iam:CreateServiceLinkedRole</code> permission so that AWS Organizations can create the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integration_services.html#orgs_integration_service-linked-roles">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p> <b>Enable all features final confirmation</b> handshake: only a principal from the master account.</p> <p>For more information about invitations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_invites.html">Inviting an AWS Account to Join Your Organization</a> in the <i>AWS Organizations User Guide.</i> For more information about requests to enable all features in the organization, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>After you accept a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
-    async fn accept_handshake(
+impl OrganizationsClient {
+    /// Calls `accept_handshake`, also returning the metadata of the response.
+    pub async fn accept_handshake_with_metadata(
         &self,
         input: AcceptHandshakeRequest,
-    ) -> Result<AcceptHandshakeResponse, RusotoError<AcceptHandshakeError>> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.AcceptHandshake");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<(AcceptHandshakeResponse, ResponseMetadata), RusotoError<AcceptHandshakeError>>
+    {
+        let request = self.build_accept_handshake_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AcceptHandshakeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcceptHandshakeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AcceptHandshakeError::from_response(response))
         }
     }
 
-    /// <p>Attaches a policy to a root, an organizational unit (OU), or an individual account.</p> <p>How the policy affects accounts depends on the type of policy:</p> <ul> <li> <p>For more information about attaching SCPs, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html">How SCPs Work</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>For information about attaching tag policies, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies-inheritance.html">How Policy Inheritance Works</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>This operation can be called only from the organization's master account.</p>
-    async fn attach_policy(
+    /// Presigns the request `accept_handshake` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_handshake(
         &self,
-        input: AttachPolicyRequest,
-    ) -> Result<(), RusotoError<AttachPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AcceptHandshakeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_handshake_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_handshake_request(&self, input: AcceptHandshakeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("AcceptHandshake");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.AttachPolicy");
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.AcceptHandshake");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `attach_policy`, also returning the metadata of the response.
+    pub async fn attach_policy_with_metadata(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<AttachPolicyError>> {
+        let request = self.build_attach_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AttachPolicyError::from_response(response))
         }
     }
 
-    /// <p>Cancels a handshake. Canceling a handshake sets the handshake state to <code>CANCELED</code>. </p> <p>This operation can be called only from the account that originated the handshake. The recipient of the handshake can't cancel it, but can use <a>DeclineHandshake</a> instead. After a handshake is canceled, the recipient can no longer respond to that handshake.</p> <p>After you cancel a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
-    async fn cancel_handshake(
+    /// Presigns the request `attach_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_policy(
         &self,
-        input: CancelHandshakeRequest,
-    ) -> Result<CancelHandshakeResponse, RusotoError<CancelHandshakeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AttachPolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_policy_request(&self, input: AttachPolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("AttachPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.CancelHandshake");
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.AttachPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_handshake`, also returning the metadata of the response.
+    pub async fn cancel_handshake_with_metadata(
+        &self,
+        input: CancelHandshakeRequest,
+    ) -> Result<(CancelHandshakeResponse, ResponseMetadata), RusotoError<CancelHandshakeError>>
+    {
+        let request = self.build_cancel_handshake_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CancelHandshakeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelHandshakeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CancelHandshakeError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an AWS account that is automatically a member of the organization whose credentials made the request. This is an asynchronous request that AWS performs in the background. Because <code>CreateAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>The user who calls the API to create an account must have the <code>organizations:CreateAccount</code> permission. If you enabled all features in the organization, AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> <p>AWS Organizations preconfigures the new member account with a role (named <code>OrganizationAccountAccessRole</code> by default) that grants users in the master account administrator permissions in the new member account. Principals in the master account can assume the role. AWS Organizations clones the company name and address information for the new account from the organization&#39;s master account.</p> <p>This operation can be called only from the organization&#39;s master account.</p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>When you create an account in an organization, the information required for the account to operate as a standalone account is <i>not</i> automatically collected. For example, information about the payment method and signing the end user license agreement (EULA) is not collected. If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the Billing and Cost Management Console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
-    async fn create_account(
+    /// Presigns the request `cancel_handshake` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_handshake(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelHandshakeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_handshake_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_handshake_request(&self, input: CancelHandshakeRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CancelHandshake");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.CancelHandshake");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `create_account`, also returning the metadata of the response.
+    pub async fn create_account_with_metadata(
         &self,
         input: CreateAccountRequest,
-    ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
+    ) -> Result<(CreateAccountResponse, ResponseMetadata), RusotoError<CreateAccountError>> {
+        let request = self.build_create_account_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAccountResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAccountError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_account(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateAccountRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_account_request(&self, input: CreateAccountRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreateAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_gov_cloud_account`, also returning the metadata of the response.
+    pub async fn create_gov_cloud_account_with_metadata(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> Result<
+        (CreateGovCloudAccountResponse, ResponseMetadata),
+        RusotoError<CreateGovCloudAccountError>,
+    > {
+        let request = self.build_create_gov_cloud_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAccountResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateGovCloudAccountResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateGovCloudAccountError::from_response(response))
         }
     }
 
-    /// <p><p>This action is available if all of the following are true:</p> <ul> <li> <p>You&#39;re authorized to create accounts in the AWS GovCloud (US) Region. For more information on the AWS GovCloud (US) Region, see the <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/welcome.html"> <i>AWS GovCloud User Guide</i>.</a> </p> </li> <li> <p>You already have an account in the AWS GovCloud (US) Region that is associated with your master account in the commercial Region. </p> </li> <li> <p>You call this action from the master account of your organization in the commercial Region.</p> </li> <li> <p>You have the <code>organizations:CreateGovCloudAccount</code> permission. AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>AWS automatically enables AWS CloudTrail for AWS GovCloud (US) accounts, but you should also do the following:</p> <ul> <li> <p>Verify that AWS CloudTrail is enabled to store logs.</p> </li> <li> <p>Create an S3 bucket for AWS CloudTrail log storage.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/verifying-cloudtrail.html">Verifying AWS CloudTrail Is Enabled</a> in the <i>AWS GovCloud User Guide</i>. </p> </li> </ul> <p>You call this action from the master account of your organization in the commercial Region to create a standalone AWS account in the AWS GovCloud (US) Region. After the account is created, the master account of an organization in the AWS GovCloud (US) Region can invite it to that organization. For more information on inviting standalone accounts in the AWS GovCloud (US) to join an organization, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>Calling <code>CreateGovCloudAccount</code> is an asynchronous request that AWS performs in the background. Because <code>CreateGovCloudAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>When you call the <code>CreateGovCloudAccount</code> action, you create two accounts: a standalone account in the AWS GovCloud (US) Region and an associated account in the commercial Region for billing and support purposes. The account in the commercial Region is automatically a member of the organization whose credentials made the request. Both accounts are associated with the same email address.</p> <p>A role is created in the new account in the commercial Region that allows the master account in the organization in the commercial Region to assume it. An AWS GovCloud (US) account is then created and associated with the commercial account that you just created. A role is created in the new AWS GovCloud (US) account. This role can be assumed by the AWS GovCloud (US) account that is associated with the master account of the commercial organization. For more information and to view a diagram that explains how account access works, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>You can create an account in an organization using the AWS Organizations console, API, or CLI commands. When you do, the information required for the account to operate as a standalone account, such as a payment method, is <i>not</i> automatically collected. If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateGovCloudAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the AWS Billing and Cost Management console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
-    async fn create_gov_cloud_account(
+    /// Presigns the request `create_gov_cloud_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_gov_cloud_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGovCloudAccountRequest,
-    ) -> Result<CreateGovCloudAccountResponse, RusotoError<CreateGovCloudAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_gov_cloud_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_gov_cloud_account_request(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateGovCloudAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5608,28 +6421,53 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_organization`, also returning the metadata of the response.
+    pub async fn create_organization_with_metadata(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> Result<(CreateOrganizationResponse, ResponseMetadata), RusotoError<CreateOrganizationError>>
+    {
+        let request = self.build_create_organization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateGovCloudAccountResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateOrganizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGovCloudAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateOrganizationError::from_response(response))
         }
     }
 
-    /// <p>Creates an AWS organization. The account whose user is calling the <code>CreateOrganization</code> operation automatically becomes the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/orgs_getting-started_concepts.html#account">master account</a> of the new organization.</p> <p>This operation must be called using credentials from the account that is to become the new organization's master account. The principal must also have the relevant IAM permissions.</p> <p>By default (or if you set the <code>FeatureSet</code> parameter to <code>ALL</code>), the new organization is created with all features enabled. In addition, service control policies are automatically enabled in the root. If you instead create the organization supporting only the consolidated billing features, no policy types are enabled by default, and you can't use organization policies.</p>
-    async fn create_organization(
+    /// Presigns the request `create_organization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_organization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateOrganizationRequest,
-    ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_organization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_organization_request(&self, input: CreateOrganizationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5639,28 +6477,58 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_organizational_unit`, also returning the metadata of the response.
+    pub async fn create_organizational_unit_with_metadata(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> Result<
+        (CreateOrganizationalUnitResponse, ResponseMetadata),
+        RusotoError<CreateOrganizationalUnitError>,
+    > {
+        let request = self.build_create_organizational_unit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateOrganizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateOrganizationalUnitResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateOrganizationalUnitError::from_response(response))
         }
     }
 
-    /// <p>Creates an organizational unit (OU) within a root or parent OU. An OU is a container for accounts that enables you to organize your accounts to apply policies according to your business requirements. The number of levels deep that you can nest OUs is dependent upon the policy types enabled for that root. For service control policies, the limit is five. </p> <p>For more information about OUs, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_ous.html">Managing Organizational Units</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
-    async fn create_organizational_unit(
+    /// Presigns the request `create_organizational_unit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_organizational_unit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateOrganizationalUnitRequest,
-    ) -> Result<CreateOrganizationalUnitResponse, RusotoError<CreateOrganizationalUnitError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_organizational_unit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_organizational_unit_request(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateOrganizationalUnit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5670,80 +6538,150 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_policy`, also returning the metadata of the response.
+    pub async fn create_policy_with_metadata(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> Result<(CreatePolicyResponse, ResponseMetadata), RusotoError<CreatePolicyError>> {
+        let request = self.build_create_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateOrganizationalUnitResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateOrganizationalUnitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePolicyError::from_response(response))
         }
     }
 
-    /// <p>Creates a policy of a specified type that you can attach to a root, an organizational unit (OU), or an individual AWS account.</p> <p>For more information about policies and their use, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies.html">Managing Organization Policies</a>.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn create_policy(
+    /// Presigns the request `create_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePolicyRequest,
-    ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_policy_request(&self, input: CreatePolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreatePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreatePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `decline_handshake`, also returning the metadata of the response.
+    pub async fn decline_handshake_with_metadata(
+        &self,
+        input: DeclineHandshakeRequest,
+    ) -> Result<(DeclineHandshakeResponse, ResponseMetadata), RusotoError<DeclineHandshakeError>>
+    {
+        let request = self.build_decline_handshake_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreatePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeclineHandshakeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeclineHandshakeError::from_response(response))
         }
     }
 
-    /// <p>Declines a handshake request. This sets the handshake state to <code>DECLINED</code> and effectively deactivates the request.</p> <p>This operation can be called only from the account that received the handshake. The originator of the handshake can use <a>CancelHandshake</a> instead. The originator can't reactivate a declined request, but can reinitiate the process with a new handshake request.</p> <p>After you decline a handshake, it continues to appear in the results of relevant API operations for only 30 days. After that, it's deleted.</p>
-    async fn decline_handshake(
+    /// Presigns the request `decline_handshake` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_decline_handshake(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeclineHandshakeRequest,
-    ) -> Result<DeclineHandshakeResponse, RusotoError<DeclineHandshakeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_decline_handshake_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_decline_handshake_request(&self, input: DeclineHandshakeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeclineHandshake");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeclineHandshake");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_organization`, also returning the metadata of the response.
+    pub async fn delete_organization_with_metadata(
+        &self,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteOrganizationError>> {
+        let request = self.build_delete_organization_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeclineHandshakeResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeclineHandshakeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteOrganizationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the organization. You can delete an organization only by using credentials from the master account. The organization must be empty of member accounts.</p>
-    async fn delete_organization(&self) -> Result<(), RusotoError<DeleteOrganizationError>> {
+    /// Presigns the request `delete_organization` would send with `presigner` instead of sending it.
+    pub async fn presign_delete_organization(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_organization_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_organization_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeleteOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5752,27 +6690,50 @@ impl Organizations for OrganizationsClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `delete_organizational_unit`, also returning the metadata of the response.
+    pub async fn delete_organizational_unit_with_metadata(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteOrganizationalUnitError>> {
+        let request = self.build_delete_organizational_unit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteOrganizationalUnitError::from_response(response))
         }
     }
 
-    /// <p>Deletes an organizational unit (OU) from a root or another OU. You must first remove all accounts and child OUs from the OU that you want to delete.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn delete_organizational_unit(
+    /// Presigns the request `delete_organizational_unit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_organizational_unit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteOrganizationalUnitRequest,
-    ) -> Result<(), RusotoError<DeleteOrganizationalUnitError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_organizational_unit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_organizational_unit_request(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeleteOrganizationalUnit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5782,82 +6743,158 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_policy`, also returning the metadata of the response.
+    pub async fn delete_policy_with_metadata(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePolicyError>> {
+        let request = self.build_delete_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteOrganizationalUnitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified policy from your organization. Before you perform this operation, you must first detach the policy from all organizational units (OUs), roots, and accounts.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn delete_policy(
+    /// Presigns the request `delete_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePolicyRequest,
-    ) -> Result<(), RusotoError<DeletePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_policy_request(&self, input: DeletePolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeletePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeletePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_account`, also returning the metadata of the response.
+    pub async fn describe_account_with_metadata(
+        &self,
+        input: DescribeAccountRequest,
+    ) -> Result<(DescribeAccountResponse, ResponseMetadata), RusotoError<DescribeAccountError>>
+    {
+        let request = self.build_describe_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAccountResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAccountError::from_response(response))
         }
     }
 
-    /// <p>Retrieves AWS Organizations related information about the specified account.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn describe_account(
+    /// Presigns the request `describe_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAccountRequest,
-    ) -> Result<DescribeAccountResponse, RusotoError<DescribeAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_account_request(&self, input: DescribeAccountRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribeAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_create_account_status`, also returning the metadata of the response.
+    pub async fn describe_create_account_status_with_metadata(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> Result<
+        (DescribeCreateAccountStatusResponse, ResponseMetadata),
+        RusotoError<DescribeCreateAccountStatusError>,
+    > {
+        let request = self.build_describe_create_account_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAccountResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCreateAccountStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCreateAccountStatusError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the current status of an asynchronous request to create an account.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn describe_create_account_status(
+    /// Presigns the request `describe_create_account_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_create_account_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCreateAccountStatusRequest,
-    ) -> Result<DescribeCreateAccountStatusResponse, RusotoError<DescribeCreateAccountStatusError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_create_account_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_create_account_status_request(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeCreateAccountStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5867,28 +6904,58 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_effective_policy`, also returning the metadata of the response.
+    pub async fn describe_effective_policy_with_metadata(
+        &self,
+        input: DescribeEffectivePolicyRequest,
+    ) -> Result<
+        (DescribeEffectivePolicyResponse, ResponseMetadata),
+        RusotoError<DescribeEffectivePolicyError>,
+    > {
+        let request = self.build_describe_effective_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCreateAccountStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEffectivePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCreateAccountStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEffectivePolicyError::from_response(response))
         }
     }
 
-    /// <p>Returns the contents of the effective tag policy for the account. The effective tag policy is the aggregation of any tag policies the account inherits, plus any policy directly that is attached to the account. </p> <p>This action returns information on tag policies only.</p> <p>For more information on policy inheritance, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies-inheritance.html">How Policy Inheritance Works</a> in the <i>AWS Organizations User Guide</i>.</p> <p>This operation can be called from any account in the organization.</p>
-    async fn describe_effective_policy(
+    /// Presigns the request `describe_effective_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_effective_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEffectivePolicyRequest,
-    ) -> Result<DescribeEffectivePolicyResponse, RusotoError<DescribeEffectivePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_effective_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_effective_policy_request(
+        &self,
+        input: DescribeEffectivePolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeEffectivePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5898,28 +6965,53 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_handshake`, also returning the metadata of the response.
+    pub async fn describe_handshake_with_metadata(
+        &self,
+        input: DescribeHandshakeRequest,
+    ) -> Result<(DescribeHandshakeResponse, ResponseMetadata), RusotoError<DescribeHandshakeError>>
+    {
+        let request = self.build_describe_handshake_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEffectivePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeHandshakeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEffectivePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeHandshakeError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a previously requested handshake. The handshake ID comes from the response to the original <a>InviteAccountToOrganization</a> operation that generated the handshake.</p> <p>You can access handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> for only 30 days after they change to that state. They're then deleted and no longer accessible.</p> <p>This operation can be called from any account in the organization.</p>
-    async fn describe_handshake(
+    /// Presigns the request `describe_handshake` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_handshake(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHandshakeRequest,
-    ) -> Result<DescribeHandshakeResponse, RusotoError<DescribeHandshakeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_handshake_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_handshake_request(&self, input: DescribeHandshakeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeHandshake");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5929,27 +7021,53 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_organization`, also returning the metadata of the response.
+    pub async fn describe_organization_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeOrganizationResponse, ResponseMetadata),
+        RusotoError<DescribeOrganizationError>,
+    > {
+        let request = self.build_describe_organization_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeHandshakeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeOrganizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHandshakeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeOrganizationError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves information about the organization that the user&#39;s account belongs to.</p> <p>This operation can be called from any account in the organization.</p> <note> <p>Even if a policy type is shown as available in the organization, you can disable it separately at the root level with <a>DisablePolicyType</a>. Use <a>ListRoots</a> to see the status of policy types for a specified root.</p> </note></p>
-    async fn describe_organization(
+    /// Presigns the request `describe_organization` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_organization(
         &self,
-    ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_organization_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_organization_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5958,29 +7076,58 @@ impl Organizations for OrganizationsClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_organizational_unit`, also returning the metadata of the response.
+    pub async fn describe_organizational_unit_with_metadata(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> Result<
+        (DescribeOrganizationalUnitResponse, ResponseMetadata),
+        RusotoError<DescribeOrganizationalUnitError>,
+    > {
+        let request = self.build_describe_organizational_unit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOrganizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeOrganizationalUnitResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeOrganizationalUnitError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about an organizational unit (OU).</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn describe_organizational_unit(
+    /// Presigns the request `describe_organizational_unit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_organizational_unit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeOrganizationalUnitRequest,
-    ) -> Result<DescribeOrganizationalUnitResponse, RusotoError<DescribeOrganizationalUnitError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_organizational_unit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_organizational_unit_request(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeOrganizationalUnit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5990,82 +7137,149 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_policy`, also returning the metadata of the response.
+    pub async fn describe_policy_with_metadata(
+        &self,
+        input: DescribePolicyRequest,
+    ) -> Result<(DescribePolicyResponse, ResponseMetadata), RusotoError<DescribePolicyError>> {
+        let request = self.build_describe_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOrganizationalUnitResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOrganizationalUnitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePolicyError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a policy.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn describe_policy(
+    /// Presigns the request `describe_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePolicyRequest,
-    ) -> Result<DescribePolicyResponse, RusotoError<DescribePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_policy_request(&self, input: DescribePolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detach_policy`, also returning the metadata of the response.
+    pub async fn detach_policy_with_metadata(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DetachPolicyError>> {
+        let request = self.build_detach_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribePolicyResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetachPolicyError::from_response(response))
         }
     }
 
-    /// <p>Detaches a policy from a target root, organizational unit (OU), or account. If the policy being detached is a service control policy (SCP), the changes to permissions for IAM users and roles in affected accounts are immediate.</p> <p> <b>Note:</b> Every root, OU, and account must have at least one SCP attached. You can replace the default <code>FullAWSAccess</code> policy with one that limits the permissions that can be delegated. To do that, you must attach the replacement policy before you can remove the default one. This is the authorization strategy of using an <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html#orgs_policies_whitelist">allow list</a>. You could instead attach a second SCP and leave the <code>FullAWSAccess</code> SCP still attached. You could then specify <code>"Effect": "Deny"</code> in the second SCP to override the <code>"Effect": "Allow"</code> in the <code>FullAWSAccess</code> policy (or any other attached SCP). If you take these steps, you're using the authorization strategy of a <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html#orgs_policies_blacklist">deny list</a>. </p> <p>This operation can be called only from the organization's master account.</p>
-    async fn detach_policy(
+    /// Presigns the request `detach_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachPolicyRequest,
-    ) -> Result<(), RusotoError<DetachPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_policy_request(&self, input: DetachPolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DetachPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DetachPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_aws_service_access`, also returning the metadata of the response.
+    pub async fn disable_aws_service_access_with_metadata(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisableAWSServiceAccessError>> {
+        let request = self.build_disable_aws_service_access_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableAWSServiceAccessError::from_response(response))
         }
     }
 
-    /// <p>Disables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you disable integration, the specified service no longer can create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in <i>new</i> accounts in your organization. This means the service can't perform operations on your behalf on any new accounts in your organization. The service can still perform operations in older accounts until the service completes its clean-up from AWS Organizations.</p> <p/> <important> <p>We recommend that you disable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the other service is aware that it can clean up any resources that are required only for the integration. How the service cleans up its resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>After you perform the <code>DisableAWSServiceAccess</code> operation, the specified service can no longer perform operations in your organization's accounts. The only exception is when the operations are explicitly permitted by IAM policies that are attached to your roles. </p> <p>For more information about integrating other services with AWS Organizations, including the list of services that work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
-    async fn disable_aws_service_access(
+    /// Presigns the request `disable_aws_service_access` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_aws_service_access(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableAWSServiceAccessRequest,
-    ) -> Result<(), RusotoError<DisableAWSServiceAccessError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_aws_service_access_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_aws_service_access_request(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DisableAWSServiceAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6075,27 +7289,53 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_policy_type`, also returning the metadata of the response.
+    pub async fn disable_policy_type_with_metadata(
+        &self,
+        input: DisablePolicyTypeRequest,
+    ) -> Result<(DisablePolicyTypeResponse, ResponseMetadata), RusotoError<DisablePolicyTypeError>>
+    {
+        let request = self.build_disable_policy_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisablePolicyTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableAWSServiceAccessError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisablePolicyTypeError::from_response(response))
         }
     }
 
-    /// <p>Disables an organizational control policy type in a root and detaches all policies of that type from the organization root, OUs, and accounts. A policy of a certain type can be attached to entities in a root only if that type is enabled in the root. After you perform this operation, you no longer can attach policies of the specified type to that root or to any organizational unit (OU) or account in that root. You can undo this by using the <a>EnablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. If you disable a policy for a root, it still appears enabled for the organization if <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">all features</a> are enabled for the organization. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p> To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
-    async fn disable_policy_type(
+    /// Presigns the request `disable_policy_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_policy_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisablePolicyTypeRequest,
-    ) -> Result<DisablePolicyTypeResponse, RusotoError<DisablePolicyTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_policy_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_policy_type_request(&self, input: DisablePolicyTypeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DisablePolicyType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6105,28 +7345,50 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_aws_service_access`, also returning the metadata of the response.
+    pub async fn enable_aws_service_access_with_metadata(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<EnableAWSServiceAccessError>> {
+        let request = self.build_enable_aws_service_access_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisablePolicyTypeResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisablePolicyTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableAWSServiceAccessError::from_response(response))
         }
     }
 
-    /// <p>Enables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you enable integration, you allow the specified service to create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in all the accounts in your organization. This allows the service to perform operations on your behalf in your organization and its accounts.</p> <important> <p>We recommend that you enable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the service is aware that it can create the resources that are required for the integration. How the service creates those resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>For more information about enabling services to integrate with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account and only if the organization has <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">enabled all features</a>.</p>
-    async fn enable_aws_service_access(
+    /// Presigns the request `enable_aws_service_access` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_aws_service_access(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableAWSServiceAccessRequest,
-    ) -> Result<(), RusotoError<EnableAWSServiceAccessError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_aws_service_access_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_aws_service_access_request(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("EnableAWSServiceAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6136,26 +7398,51 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_all_features`, also returning the metadata of the response.
+    pub async fn enable_all_features_with_metadata(
+        &self,
+    ) -> Result<(EnableAllFeaturesResponse, ResponseMetadata), RusotoError<EnableAllFeaturesError>>
+    {
+        let request = self.build_enable_all_features_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnableAllFeaturesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableAWSServiceAccessError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableAllFeaturesError::from_response(response))
         }
     }
 
-    /// <p>Enables all features in an organization. This enables the use of organization policies that can restrict the services and actions that can be called in each account. Until you enable all features, you have access only to consolidated billing. You can't use any of the advanced account administration features that AWS Organizations supports. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <p>This operation is required only for organizations that were created explicitly with only the consolidated billing features enabled. Calling this operation sends a handshake to every invited account in the organization. The feature set change can be finalized and the additional features enabled only after all administrators in the invited accounts approve the change. Accepting the handshake approves the change.</p> </important> <p>After you enable all features, you can separately enable or disable individual policy types in a root using <a>EnablePolicyType</a> and <a>DisablePolicyType</a>. To see the status of policy types in a root, use <a>ListRoots</a>.</p> <p>After all invited member accounts accept the handshake, you finalize the feature set change by accepting the handshake that contains <code>"Action": "ENABLE_ALL_FEATURES"</code>. This completes the change.</p> <p>After you enable all features in your organization, the master account in the organization can apply policies on all member accounts. These policies can restrict what users and even administrators in those accounts can do. The master account can apply policies that prevent accounts from leaving the organization. Ensure that your account administrators are aware of this.</p> <p>This operation can be called only from the organization's master account. </p>
-    async fn enable_all_features(
+    /// Presigns the request `enable_all_features` would send with `presigner` instead of sending it.
+    pub async fn presign_enable_all_features(
         &self,
-    ) -> Result<EnableAllFeaturesResponse, RusotoError<EnableAllFeaturesError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_all_features_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_all_features_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("EnableAllFeatures");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6164,57 +7451,111 @@ impl Organizations for OrganizationsClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `enable_policy_type`, also returning the metadata of the response.
+    pub async fn enable_policy_type_with_metadata(
+        &self,
+        input: EnablePolicyTypeRequest,
+    ) -> Result<(EnablePolicyTypeResponse, ResponseMetadata), RusotoError<EnablePolicyTypeError>>
+    {
+        let request = self.build_enable_policy_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<EnableAllFeaturesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnablePolicyTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableAllFeaturesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnablePolicyTypeError::from_response(response))
         }
     }
 
-    /// <p>Enables a policy type in a root. After you enable a policy type in a root, you can attach policies of that type to the root, any organizational unit (OU), or account in that root. You can undo this by using the <a>DisablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p>You can enable a policy type in a root only if that policy type is available in the organization. To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
-    async fn enable_policy_type(
+    /// Presigns the request `enable_policy_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_policy_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnablePolicyTypeRequest,
-    ) -> Result<EnablePolicyTypeResponse, RusotoError<EnablePolicyTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_policy_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_policy_type_request(&self, input: EnablePolicyTypeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("EnablePolicyType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.EnablePolicyType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `invite_account_to_organization`, also returning the metadata of the response.
+    pub async fn invite_account_to_organization_with_metadata(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> Result<
+        (InviteAccountToOrganizationResponse, ResponseMetadata),
+        RusotoError<InviteAccountToOrganizationError>,
+    > {
+        let request = self.build_invite_account_to_organization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<EnablePolicyTypeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<InviteAccountToOrganizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnablePolicyTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(InviteAccountToOrganizationError::from_response(response))
         }
     }
 
-    /// <p>Sends an invitation to another account to join your organization as a member account. AWS Organizations sends email on your behalf to the email address that is associated with the other account's owner. The invitation is implemented as a <a>Handshake</a> whose details are in the response.</p> <important> <ul> <li> <p>You can invite AWS accounts only from the same seller as the master account. For example, assume that your organization's master account was created by Amazon Internet Services Pvt. Ltd (AISPL), an AWS seller in India. You can invite only other AISPL accounts to your organization. You can't combine accounts from AISPL and AWS or from any other AWS seller. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/useconsolidatedbilliing-India.html">Consolidated Billing in India</a>.</p> </li> <li> <p>You might receive an exception that indicates that you exceeded your account limits for the organization or that the operation failed because your organization is still initializing. If so, wait one hour and then try again. If the error persists after an hour, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> </ul> </important> <p>This operation can be called only from the organization's master account.</p>
-    async fn invite_account_to_organization(
+    /// Presigns the request `invite_account_to_organization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_invite_account_to_organization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InviteAccountToOrganizationRequest,
-    ) -> Result<InviteAccountToOrganizationResponse, RusotoError<InviteAccountToOrganizationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_invite_account_to_organization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_invite_account_to_organization_request(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("InviteAccountToOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6224,25 +7565,45 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `leave_organization`, also returning the metadata of the response.
+    pub async fn leave_organization_with_metadata(
+        &self,
+    ) -> Result<((), ResponseMetadata), RusotoError<LeaveOrganizationError>> {
+        let request = self.build_leave_organization_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<InviteAccountToOrganizationResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(InviteAccountToOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(LeaveOrganizationError::from_response(response))
         }
     }
 
-    /// <p><p>Removes a member account from its parent organization. This version of the operation is performed by the account that wants to leave. To remove a member account as a user in the master account, use <a>RemoveAccountFromOrganization</a> instead.</p> <p>This operation can be called only from a member account in the organization.</p> <important> <ul> <li> <p>The master account in an organization with all features enabled can set service control policies (SCPs) that can restrict what administrators of member accounts can do. These restrictions can include preventing member accounts from successfully calling <code>LeaveOrganization</code>. </p> </li> <li> <p>You can leave an organization as a member account only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI, the information required of standalone accounts is <i>not</i> automatically collected. For each account that you want to make standalone, you must accept the end user license agreement (EULA). You must also choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You can leave an organization only after you enable IAM user access to billing in your account. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> </li> </ul> </important></p>
-    async fn leave_organization(&self) -> Result<(), RusotoError<LeaveOrganizationError>> {
+    /// Presigns the request `leave_organization` would send with `presigner` instead of sending it.
+    pub async fn presign_leave_organization(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_leave_organization_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_leave_organization_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("LeaveOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6251,30 +7612,63 @@ impl Organizations for OrganizationsClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `list_aws_service_access_for_organization`, also returning the metadata of the response.
+    pub async fn list_aws_service_access_for_organization_with_metadata(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> Result<
+        (
+            ListAWSServiceAccessForOrganizationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListAWSServiceAccessForOrganizationError>,
+    > {
+        let request = self.build_list_aws_service_access_for_organization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAWSServiceAccessForOrganizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(LeaveOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAWSServiceAccessForOrganizationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns a list of the AWS services that you enabled to integrate with your organization. After a service on this list creates the resources that it requires for the integration, it can perform operations on your organization and its accounts.</p> <p>For more information about integrating other services with AWS Organizations, including the list of services that currently work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_aws_service_access_for_organization(
+    /// Presigns the request `list_aws_service_access_for_organization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_aws_service_access_for_organization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAWSServiceAccessForOrganizationRequest,
-    ) -> Result<
-        ListAWSServiceAccessForOrganizationResponse,
-        RusotoError<ListAWSServiceAccessForOrganizationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_aws_service_access_for_organization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_aws_service_access_for_organization_request(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListAWSServiceAccessForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6284,57 +7678,110 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_accounts`, also returning the metadata of the response.
+    pub async fn list_accounts_with_metadata(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<(ListAccountsResponse, ResponseMetadata), RusotoError<ListAccountsError>> {
+        let request = self.build_list_accounts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAWSServiceAccessForOrganizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAccountsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAWSServiceAccessForOrganizationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAccountsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the accounts in the organization. To request only the accounts in a specified root or organizational unit (OU), use the <a>ListAccountsForParent</a> operation instead.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_accounts(
+    /// Presigns the request `list_accounts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_accounts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAccountsRequest,
-    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_accounts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_accounts_request(&self, input: ListAccountsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_accounts_for_parent`, also returning the metadata of the response.
+    pub async fn list_accounts_for_parent_with_metadata(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> Result<
+        (ListAccountsForParentResponse, ResponseMetadata),
+        RusotoError<ListAccountsForParentError>,
+    > {
+        let request = self.build_list_accounts_for_parent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAccountsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAccountsForParentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAccountsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAccountsForParentError::from_response(response))
         }
     }
 
-    /// <p>Lists the accounts in an organization that are contained by the specified target root or organizational unit (OU). If you specify the root, you get a list of all the accounts that aren't in any OU. If you specify an OU, you get a list of all the accounts in only that OU and not in any child OUs. To get a list of all accounts in the organization, use the <a>ListAccounts</a> operation.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_accounts_for_parent(
+    /// Presigns the request `list_accounts_for_parent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_accounts_for_parent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAccountsForParentRequest,
-    ) -> Result<ListAccountsForParentResponse, RusotoError<ListAccountsForParentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_accounts_for_parent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_accounts_for_parent_request(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListAccountsForParent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6344,55 +7791,110 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_children`, also returning the metadata of the response.
+    pub async fn list_children_with_metadata(
+        &self,
+        input: ListChildrenRequest,
+    ) -> Result<(ListChildrenResponse, ResponseMetadata), RusotoError<ListChildrenError>> {
+        let request = self.build_list_children_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAccountsForParentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListChildrenResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAccountsForParentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListChildrenError::from_response(response))
         }
     }
 
-    /// <p>Lists all of the organizational units (OUs) or accounts that are contained in the specified parent OU or root. This operation, along with <a>ListParents</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_children(
+    /// Presigns the request `list_children` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_children(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListChildrenRequest,
-    ) -> Result<ListChildrenResponse, RusotoError<ListChildrenError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_children_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_children_request(&self, input: ListChildrenRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListChildren");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListChildren");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_create_account_status`, also returning the metadata of the response.
+    pub async fn list_create_account_status_with_metadata(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> Result<
+        (ListCreateAccountStatusResponse, ResponseMetadata),
+        RusotoError<ListCreateAccountStatusError>,
+    > {
+        let request = self.build_list_create_account_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListChildrenResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCreateAccountStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListChildrenError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCreateAccountStatusError::from_response(response))
         }
     }
 
-    /// <p>Lists the account creation requests that match the specified status that is currently being tracked for the organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_create_account_status(
+    /// Presigns the request `list_create_account_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_create_account_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCreateAccountStatusRequest,
-    ) -> Result<ListCreateAccountStatusResponse, RusotoError<ListCreateAccountStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_create_account_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_create_account_status_request(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListCreateAccountStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6402,28 +7904,58 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_handshakes_for_account`, also returning the metadata of the response.
+    pub async fn list_handshakes_for_account_with_metadata(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> Result<
+        (ListHandshakesForAccountResponse, ResponseMetadata),
+        RusotoError<ListHandshakesForAccountError>,
+    > {
+        let request = self.build_list_handshakes_for_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCreateAccountStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListHandshakesForAccountResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCreateAccountStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListHandshakesForAccountError::from_response(response))
         }
     }
 
-    /// <p>Lists the current handshakes that are associated with the account of the requesting user.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called from any account in the organization.</p>
-    async fn list_handshakes_for_account(
+    /// Presigns the request `list_handshakes_for_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_handshakes_for_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHandshakesForAccountRequest,
-    ) -> Result<ListHandshakesForAccountResponse, RusotoError<ListHandshakesForAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_handshakes_for_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_handshakes_for_account_request(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListHandshakesForAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6433,31 +7965,58 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_handshakes_for_organization`, also returning the metadata of the response.
+    pub async fn list_handshakes_for_organization_with_metadata(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> Result<
+        (ListHandshakesForOrganizationResponse, ResponseMetadata),
+        RusotoError<ListHandshakesForOrganizationError>,
+    > {
+        let request = self.build_list_handshakes_for_organization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListHandshakesForAccountResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListHandshakesForOrganizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHandshakesForAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListHandshakesForOrganizationError::from_response(response))
         }
     }
 
-    /// <p>Lists the handshakes that are associated with the organization that the requesting user is part of. The <code>ListHandshakesForOrganization</code> operation returns a list of handshake structures. Each structure contains details and status about a handshake.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_handshakes_for_organization(
+    /// Presigns the request `list_handshakes_for_organization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_handshakes_for_organization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHandshakesForOrganizationRequest,
-    ) -> Result<
-        ListHandshakesForOrganizationResponse,
-        RusotoError<ListHandshakesForOrganizationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_handshakes_for_organization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_handshakes_for_organization_request(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListHandshakesForOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6467,31 +8026,60 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_organizational_units_for_parent`, also returning the metadata of the response.
+    pub async fn list_organizational_units_for_parent_with_metadata(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> Result<
+        (ListOrganizationalUnitsForParentResponse, ResponseMetadata),
+        RusotoError<ListOrganizationalUnitsForParentError>,
+    > {
+        let request = self.build_list_organizational_units_for_parent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListHandshakesForOrganizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListOrganizationalUnitsForParentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHandshakesForOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListOrganizationalUnitsForParentError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists the organizational units (OUs) in a parent organizational unit or root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_organizational_units_for_parent(
+    /// Presigns the request `list_organizational_units_for_parent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_organizational_units_for_parent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOrganizationalUnitsForParentRequest,
-    ) -> Result<
-        ListOrganizationalUnitsForParentResponse,
-        RusotoError<ListOrganizationalUnitsForParentError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_organizational_units_for_parent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_organizational_units_for_parent_request(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListOrganizationalUnitsForParent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6501,84 +8089,162 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_parents`, also returning the metadata of the response.
+    pub async fn list_parents_with_metadata(
+        &self,
+        input: ListParentsRequest,
+    ) -> Result<(ListParentsResponse, ResponseMetadata), RusotoError<ListParentsError>> {
+        let request = self.build_list_parents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListOrganizationalUnitsForParentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListParentsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOrganizationalUnitsForParentError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListParentsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the root or organizational units (OUs) that serve as the immediate parent of the specified child OU or account. This operation, along with <a>ListChildren</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account.</p> <note> <p>In the current release, a child can have only a single parent. </p> </note></p>
-    async fn list_parents(
+    /// Presigns the request `list_parents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_parents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListParentsRequest,
-    ) -> Result<ListParentsResponse, RusotoError<ListParentsError>> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_parents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_parents_request(&self, input: ListParentsRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListParents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListParents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_policies`, also returning the metadata of the response.
+    pub async fn list_policies_with_metadata(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<(ListPoliciesResponse, ResponseMetadata), RusotoError<ListPoliciesError>> {
+        let request = self.build_list_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListParentsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPoliciesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListParentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPoliciesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the list of all policies in an organization of a specified type.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_policies(
+    /// Presigns the request `list_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_policies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPoliciesRequest,
-    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_policies_request(&self, input: ListPoliciesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_policies_for_target`, also returning the metadata of the response.
+    pub async fn list_policies_for_target_with_metadata(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> Result<
+        (ListPoliciesForTargetResponse, ResponseMetadata),
+        RusotoError<ListPoliciesForTargetError>,
+    > {
+        let request = self.build_list_policies_for_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPoliciesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPoliciesForTargetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPoliciesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPoliciesForTargetError::from_response(response))
         }
     }
 
-    /// <p>Lists the policies that are directly attached to the specified target root, organizational unit (OU), or account. You must specify the policy type that you want included in the returned list.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_policies_for_target(
+    /// Presigns the request `list_policies_for_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_policies_for_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPoliciesForTargetRequest,
-    ) -> Result<ListPoliciesForTargetResponse, RusotoError<ListPoliciesForTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_policies_for_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_policies_for_target_request(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListPoliciesForTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6588,55 +8254,110 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_roots`, also returning the metadata of the response.
+    pub async fn list_roots_with_metadata(
+        &self,
+        input: ListRootsRequest,
+    ) -> Result<(ListRootsResponse, ResponseMetadata), RusotoError<ListRootsError>> {
+        let request = self.build_list_roots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPoliciesForTargetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRootsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPoliciesForTargetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRootsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the roots that are defined in the current organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account.</p> <note> <p>Policy types can be enabled and disabled in roots. This is distinct from whether they&#39;re available in the organization. When you enable all features, you make policy types available for use in that organization. Individual policy types can then be enabled and disabled in a root. To see the availability of a policy type in an organization, use <a>DescribeOrganization</a>.</p> </note></p>
-    async fn list_roots(
+    /// Presigns the request `list_roots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_roots(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRootsRequest,
-    ) -> Result<ListRootsResponse, RusotoError<ListRootsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_roots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_roots_request(&self, input: ListRootsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListRoots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListRoots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListRootsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRootsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists tags for the specified resource. </p> <p>Currently, you can list tags on an account in AWS Organizations.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6646,28 +8367,58 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_targets_for_policy`, also returning the metadata of the response.
+    pub async fn list_targets_for_policy_with_metadata(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> Result<
+        (ListTargetsForPolicyResponse, ResponseMetadata),
+        RusotoError<ListTargetsForPolicyError>,
+    > {
+        let request = self.build_list_targets_for_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTargetsForPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTargetsForPolicyError::from_response(response))
         }
     }
 
-    /// <p>Lists all the roots, organizational units (OUs), and accounts that the specified policy is attached to.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
-    async fn list_targets_for_policy(
+    /// Presigns the request `list_targets_for_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_targets_for_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTargetsForPolicyRequest,
-    ) -> Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_targets_for_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_targets_for_policy_request(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListTargetsForPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6677,55 +8428,97 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `move_account`, also returning the metadata of the response.
+    pub async fn move_account_with_metadata(
+        &self,
+        input: MoveAccountRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<MoveAccountError>> {
+        let request = self.build_move_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTargetsForPolicyResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTargetsForPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MoveAccountError::from_response(response))
         }
     }
 
-    /// <p>Moves an account from its current source parent root or organizational unit (OU) to the specified destination parent root or OU.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn move_account(
+    /// Presigns the request `move_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_move_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MoveAccountRequest,
-    ) -> Result<(), RusotoError<MoveAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_move_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_move_account_request(&self, input: MoveAccountRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("MoveAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.MoveAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_account_from_organization`, also returning the metadata of the response.
+    pub async fn remove_account_from_organization_with_metadata(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveAccountFromOrganizationError>> {
+        let request = self.build_remove_account_from_organization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MoveAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveAccountFromOrganizationError::from_response(response))
         }
     }
 
-    /// <p><p>Removes the specified account from the organization.</p> <p>The removed account becomes a standalone account that isn&#39;t a member of any organization. It&#39;s no longer subject to any policies and is responsible for its own bill payments. The organization&#39;s master account is no longer charged for any expenses accrued by the member account after it&#39;s removed from the organization.</p> <p>This operation can be called only from the organization&#39;s master account. Member accounts can remove themselves with <a>LeaveOrganization</a> instead.</p> <important> <p>You can remove an account from your organization only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI, the information required of standalone accounts is <i>not</i> automatically collected. For an account that you want to make standalone, you must accept the end user license agreement (EULA). You must also choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. To remove an account that doesn&#39;t yet have this information, you must sign in as the member account. Then follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </important></p>
-    async fn remove_account_from_organization(
+    /// Presigns the request `remove_account_from_organization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_account_from_organization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveAccountFromOrganizationRequest,
-    ) -> Result<(), RusotoError<RemoveAccountFromOrganizationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_account_from_organization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_account_from_organization_request(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("RemoveAccountFromOrganization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6735,81 +8528,152 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveAccountFromOrganizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to the specified resource.</p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes a tag from the specified resource. </p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_organizational_unit`, also returning the metadata of the response.
+    pub async fn update_organizational_unit_with_metadata(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> Result<
+        (UpdateOrganizationalUnitResponse, ResponseMetadata),
+        RusotoError<UpdateOrganizationalUnitError>,
+    > {
+        let request = self.build_update_organizational_unit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateOrganizationalUnitResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateOrganizationalUnitError::from_response(response))
         }
     }
 
-    /// <p>Renames the specified organizational unit (OU). The ID and ARN don't change. The child OUs and accounts remain in place, and any attached policies of the OU remain attached. </p> <p>This operation can be called only from the organization's master account.</p>
-    async fn update_organizational_unit(
+    /// Presigns the request `update_organizational_unit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_organizational_unit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateOrganizationalUnitRequest,
-    ) -> Result<UpdateOrganizationalUnitResponse, RusotoError<UpdateOrganizationalUnitError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_organizational_unit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_organizational_unit_request(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("UpdateOrganizationalUnit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6819,46 +8683,1083 @@ impl Organizations for OrganizationsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_policy`, also returning the metadata of the response.
+    pub async fn update_policy_with_metadata(
+        &self,
+        input: UpdatePolicyRequest,
+    ) -> Result<(UpdatePolicyResponse, ResponseMetadata), RusotoError<UpdatePolicyError>> {
+        let request = self.build_update_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateOrganizationalUnitResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateOrganizationalUnitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePolicyError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing policy with a new name, description, or content. If you don't supply any parameter, that value remains unchanged. You can't change a policy's type.</p> <p>This operation can be called only from the organization's master account.</p>
-    async fn update_policy(
+    /// Presigns the request `update_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePolicyRequest,
-    ) -> Result<UpdatePolicyResponse, RusotoError<UpdatePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_policy_request(&self, input: UpdatePolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("UpdatePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.UpdatePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdatePolicyResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePolicyError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `ListAWSServiceAccessForOrganization` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_aws_service_access_for_organization_pages(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListAWSServiceAccessForOrganizationResponse,
+            RusotoError<ListAWSServiceAccessForOrganizationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move {
+                client.list_aws_service_access_for_organization(input).await
+            },
+            |input: &ListAWSServiceAccessForOrganizationRequest,
+             output: &ListAWSServiceAccessForOrganizationResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAWSServiceAccessForOrganizationRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EnabledServicePrincipals` of every page of `ListAWSServiceAccessForOrganization` results for `input`, as `list_aws_service_access_for_organization_pages` requests them.
+    pub fn list_aws_service_access_for_organization_stream(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            EnabledServicePrincipal,
+            RusotoError<ListAWSServiceAccessForOrganizationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_aws_service_access_for_organization_pages(input),
+            |page| page.enabled_service_principals.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListAccounts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_accounts_pages(
+        &self,
+        input: ListAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAccountsResponse, RusotoError<ListAccountsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move { client.list_accounts(input).await },
+            |input: &ListAccountsRequest, output: &ListAccountsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAccountsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Accounts` of every page of `ListAccounts` results for `input`, as `list_accounts_pages` requests them.
+    pub fn list_accounts_stream(
+        &self,
+        input: ListAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<Account, RusotoError<ListAccountsError>>> + Send {
+        rusoto_core::pagination::items(self.list_accounts_pages(input), |page| {
+            page.accounts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAccountsForParent` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_accounts_for_parent_pages(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAccountsForParentResponse, RusotoError<ListAccountsForParentError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move {
+                client.list_accounts_for_parent(input).await
+            },
+            |input: &ListAccountsForParentRequest, output: &ListAccountsForParentResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAccountsForParentRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Accounts` of every page of `ListAccountsForParent` results for `input`, as `list_accounts_for_parent_pages` requests them.
+    pub fn list_accounts_for_parent_stream(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> impl futures::Stream<Item = Result<Account, RusotoError<ListAccountsForParentError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_accounts_for_parent_pages(input), |page| {
+            page.accounts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListChildren` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_children_pages(
+        &self,
+        input: ListChildrenRequest,
+    ) -> impl futures::Stream<Item = Result<ListChildrenResponse, RusotoError<ListChildrenError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move { client.list_children(input).await },
+            |input: &ListChildrenRequest, output: &ListChildrenResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListChildrenRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Children` of every page of `ListChildren` results for `input`, as `list_children_pages` requests them.
+    pub fn list_children_stream(
+        &self,
+        input: ListChildrenRequest,
+    ) -> impl futures::Stream<Item = Result<Child, RusotoError<ListChildrenError>>> + Send {
+        rusoto_core::pagination::items(self.list_children_pages(input), |page| {
+            page.children.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCreateAccountStatus` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_create_account_status_pages(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListCreateAccountStatusResponse, RusotoError<ListCreateAccountStatusError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move {
+                client.list_create_account_status(input).await
+            },
+            |input: &ListCreateAccountStatusRequest, output: &ListCreateAccountStatusResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCreateAccountStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CreateAccountStatuses` of every page of `ListCreateAccountStatus` results for `input`, as `list_create_account_status_pages` requests them.
+    pub fn list_create_account_status_stream(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<CreateAccountStatus, RusotoError<ListCreateAccountStatusError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_create_account_status_pages(input), |page| {
+            page.create_account_statuses.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListHandshakesForAccount` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_handshakes_for_account_pages(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListHandshakesForAccountResponse, RusotoError<ListHandshakesForAccountError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move {
+                client.list_handshakes_for_account(input).await
+            },
+            |input: &ListHandshakesForAccountRequest, output: &ListHandshakesForAccountResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHandshakesForAccountRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Handshakes` of every page of `ListHandshakesForAccount` results for `input`, as `list_handshakes_for_account_pages` requests them.
+    pub fn list_handshakes_for_account_stream(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> impl futures::Stream<Item = Result<Handshake, RusotoError<ListHandshakesForAccountError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_handshakes_for_account_pages(input), |page| {
+            page.handshakes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListHandshakesForOrganization` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_handshakes_for_organization_pages(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListHandshakesForOrganizationResponse,
+            RusotoError<ListHandshakesForOrganizationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move {
+                client.list_handshakes_for_organization(input).await
+            },
+            |input: &ListHandshakesForOrganizationRequest,
+             output: &ListHandshakesForOrganizationResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHandshakesForOrganizationRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Handshakes` of every page of `ListHandshakesForOrganization` results for `input`, as `list_handshakes_for_organization_pages` requests them.
+    pub fn list_handshakes_for_organization_stream(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<Handshake, RusotoError<ListHandshakesForOrganizationError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_handshakes_for_organization_pages(input), |page| {
+            page.handshakes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOrganizationalUnitsForParent` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_organizational_units_for_parent_pages(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListOrganizationalUnitsForParentResponse,
+            RusotoError<ListOrganizationalUnitsForParentError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move {
+                client.list_organizational_units_for_parent(input).await
+            },
+            |input: &ListOrganizationalUnitsForParentRequest,
+             output: &ListOrganizationalUnitsForParentResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOrganizationalUnitsForParentRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrganizationalUnits` of every page of `ListOrganizationalUnitsForParent` results for `input`, as `list_organizational_units_for_parent_pages` requests them.
+    pub fn list_organizational_units_for_parent_stream(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> impl futures::Stream<
+        Item = Result<OrganizationalUnit, RusotoError<ListOrganizationalUnitsForParentError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_organizational_units_for_parent_pages(input),
+            |page| page.organizational_units.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListParents` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_parents_pages(
+        &self,
+        input: ListParentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListParentsResponse, RusotoError<ListParentsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move { client.list_parents(input).await },
+            |input: &ListParentsRequest, output: &ListParentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListParentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parents` of every page of `ListParents` results for `input`, as `list_parents_pages` requests them.
+    pub fn list_parents_stream(
+        &self,
+        input: ListParentsRequest,
+    ) -> impl futures::Stream<Item = Result<Parent, RusotoError<ListParentsError>>> + Send {
+        rusoto_core::pagination::items(self.list_parents_pages(input), |page| {
+            page.parents.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPolicies` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_policies_pages(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<ListPoliciesResponse, RusotoError<ListPoliciesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move { client.list_policies(input).await },
+            |input: &ListPoliciesRequest, output: &ListPoliciesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPoliciesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Policies` of every page of `ListPolicies` results for `input`, as `list_policies_pages` requests them.
+    pub fn list_policies_stream(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<PolicySummary, RusotoError<ListPoliciesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_policies_pages(input), |page| {
+            page.policies.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPoliciesForTarget` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_policies_for_target_pages(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListPoliciesForTargetResponse, RusotoError<ListPoliciesForTargetError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move {
+                client.list_policies_for_target(input).await
+            },
+            |input: &ListPoliciesForTargetRequest, output: &ListPoliciesForTargetResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPoliciesForTargetRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Policies` of every page of `ListPoliciesForTarget` results for `input`, as `list_policies_for_target_pages` requests them.
+    pub fn list_policies_for_target_stream(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> impl futures::Stream<Item = Result<PolicySummary, RusotoError<ListPoliciesForTargetError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_policies_for_target_pages(input), |page| {
+            page.policies.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRoots` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_roots_pages(
+        &self,
+        input: ListRootsRequest,
+    ) -> impl futures::Stream<Item = Result<ListRootsResponse, RusotoError<ListRootsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move { client.list_roots(input).await },
+            |input: &ListRootsRequest, output: &ListRootsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRootsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Roots` of every page of `ListRoots` results for `input`, as `list_roots_pages` requests them.
+    pub fn list_roots_stream(
+        &self,
+        input: ListRootsRequest,
+    ) -> impl futures::Stream<Item = Result<Root, RusotoError<ListRootsError>>> + Send {
+        rusoto_core::pagination::items(self.list_roots_pages(input), |page| {
+            page.roots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTargetsForPolicy` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_targets_for_policy_pages(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OrganizationsClient, input| async move {
+                client.list_targets_for_policy(input).await
+            },
+            |input: &ListTargetsForPolicyRequest, output: &ListTargetsForPolicyResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTargetsForPolicyRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Targets` of every page of `ListTargetsForPolicy` results for `input`, as `list_targets_for_policy_pages` requests them.
+    pub fn list_targets_for_policy_stream(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> impl futures::Stream<
+        Item = Result<PolicyTargetSummary, RusotoError<ListTargetsForPolicyError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_targets_for_policy_pages(input), |page| {
+            page.targets.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Organizations for OrganizationsClient {
+    /// <p>Sends a response to the originator of a handshake agreeing to the action proposed by the handshake request. </p> <p>This operation can be called only by the following principals when they also have the relevant IAM permissions:</p> <ul> <li> <p> <b>Invitation to join</b> or <b>Approve all features request</b> handshakes: only a principal from the member account. </p> <p>The user who calls the API for an invitation to join must have the <code>organizations:AcceptHandshake</code> permission. If you enabled all features in the organization, the user must also have the <code>iam:CreateServiceLinkedRole</code> permission so that AWS Organizations can create the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integration_services.html#orgs_integration_service-linked-roles">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p> <b>Enable all features final confirmation</b> handshake: only a principal from the master account.</p> <p>For more information about invitations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_invites.html">Inviting an AWS Account to Join Your Organization</a> in the <i>AWS Organizations User Guide.</i> For more information about requests to enable all features in the organization, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>After you accept a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
+    async fn accept_handshake(
+        &self,
+        input: AcceptHandshakeRequest,
+    ) -> Result<AcceptHandshakeResponse, RusotoError<AcceptHandshakeError>> {
+        self.accept_handshake_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches a policy to a root, an organizational unit (OU), or an individual account.</p> <p>How the policy affects accounts depends on the type of policy:</p> <ul> <li> <p>For more information about attaching SCPs, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html">How SCPs Work</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>For information about attaching tag policies, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies-inheritance.html">How Policy Inheritance Works</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>This operation can be called only from the organization's master account.</p>
+    async fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<(), RusotoError<AttachPolicyError>> {
+        self.attach_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels a handshake. Canceling a handshake sets the handshake state to <code>CANCELED</code>. </p> <p>This operation can be called only from the account that originated the handshake. The recipient of the handshake can't cancel it, but can use <a>DeclineHandshake</a> instead. After a handshake is canceled, the recipient can no longer respond to that handshake.</p> <p>After you cancel a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
+    async fn cancel_handshake(
+        &self,
+        input: CancelHandshakeRequest,
+    ) -> Result<CancelHandshakeResponse, RusotoError<CancelHandshakeError>> {
+        self.cancel_handshake_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an AWS account that is automatically a member of the organization whose credentials made the request. This is an asynchronous request that AWS performs in the background. Because <code>CreateAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>The user who calls the API to create an account must have the <code>organizations:CreateAccount</code> permission. If you enabled all features in the organization, AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> <p>AWS Organizations preconfigures the new member account with a role (named <code>OrganizationAccountAccessRole</code> by default) that grants users in the master account administrator permissions in the new member account. Principals in the master account can assume the role. AWS Organizations clones the company name and address information for the new account from the organization&#39;s master account.</p> <p>This operation can be called only from the organization&#39;s master account.</p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>When you create an account in an organization, the information required for the account to operate as a standalone account is <i>not</i> automatically collected. For example, information about the payment method and signing the end user license agreement (EULA) is not collected. If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the Billing and Cost Management Console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
+    async fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
+        self.create_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This action is available if all of the following are true:</p> <ul> <li> <p>You&#39;re authorized to create accounts in the AWS GovCloud (US) Region. For more information on the AWS GovCloud (US) Region, see the <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/welcome.html"> <i>AWS GovCloud User Guide</i>.</a> </p> </li> <li> <p>You already have an account in the AWS GovCloud (US) Region that is associated with your master account in the commercial Region. </p> </li> <li> <p>You call this action from the master account of your organization in the commercial Region.</p> </li> <li> <p>You have the <code>organizations:CreateGovCloudAccount</code> permission. AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>AWS automatically enables AWS CloudTrail for AWS GovCloud (US) accounts, but you should also do the following:</p> <ul> <li> <p>Verify that AWS CloudTrail is enabled to store logs.</p> </li> <li> <p>Create an S3 bucket for AWS CloudTrail log storage.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/verifying-cloudtrail.html">Verifying AWS CloudTrail Is Enabled</a> in the <i>AWS GovCloud User Guide</i>. </p> </li> </ul> <p>You call this action from the master account of your organization in the commercial Region to create a standalone AWS account in the AWS GovCloud (US) Region. After the account is created, the master account of an organization in the AWS GovCloud (US) Region can invite it to that organization. For more information on inviting standalone accounts in the AWS GovCloud (US) to join an organization, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>Calling <code>CreateGovCloudAccount</code> is an asynchronous request that AWS performs in the background. Because <code>CreateGovCloudAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>When you call the <code>CreateGovCloudAccount</code> action, you create two accounts: a standalone account in the AWS GovCloud (US) Region and an associated account in the commercial Region for billing and support purposes. The account in the commercial Region is automatically a member of the organization whose credentials made the request. Both accounts are associated with the same email address.</p> <p>A role is created in the new account in the commercial Region that allows the master account in the organization in the commercial Region to assume it. An AWS GovCloud (US) account is then created and associated with the commercial account that you just created. A role is created in the new AWS GovCloud (US) account. This role can be assumed by the AWS GovCloud (US) account that is associated with the master account of the commercial organization. For more information and to view a diagram that explains how account access works, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>You can create an account in an organization using the AWS Organizations console, API, or CLI commands. When you do, the information required for the account to operate as a standalone account, such as a payment method, is <i>not</i> automatically collected. If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateGovCloudAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the AWS Billing and Cost Management console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
+    async fn create_gov_cloud_account(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> Result<CreateGovCloudAccountResponse, RusotoError<CreateGovCloudAccountError>> {
+        self.create_gov_cloud_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an AWS organization. The account whose user is calling the <code>CreateOrganization</code> operation automatically becomes the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/orgs_getting-started_concepts.html#account">master account</a> of the new organization.</p> <p>This operation must be called using credentials from the account that is to become the new organization's master account. The principal must also have the relevant IAM permissions.</p> <p>By default (or if you set the <code>FeatureSet</code> parameter to <code>ALL</code>), the new organization is created with all features enabled. In addition, service control policies are automatically enabled in the root. If you instead create the organization supporting only the consolidated billing features, no policy types are enabled by default, and you can't use organization policies.</p>
+    async fn create_organization(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
+        self.create_organization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an organizational unit (OU) within a root or parent OU. An OU is a container for accounts that enables you to organize your accounts to apply policies according to your business requirements. The number of levels deep that you can nest OUs is dependent upon the policy types enabled for that root. For service control policies, the limit is five. </p> <p>For more information about OUs, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_ous.html">Managing Organizational Units</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    async fn create_organizational_unit(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> Result<CreateOrganizationalUnitResponse, RusotoError<CreateOrganizationalUnitError>> {
+        self.create_organizational_unit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a policy of a specified type that you can attach to a root, an organizational unit (OU), or an individual AWS account.</p> <p>For more information about policies and their use, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies.html">Managing Organization Policies</a>.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
+        self.create_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Declines a handshake request. This sets the handshake state to <code>DECLINED</code> and effectively deactivates the request.</p> <p>This operation can be called only from the account that received the handshake. The originator of the handshake can use <a>CancelHandshake</a> instead. The originator can't reactivate a declined request, but can reinitiate the process with a new handshake request.</p> <p>After you decline a handshake, it continues to appear in the results of relevant API operations for only 30 days. After that, it's deleted.</p>
+    async fn decline_handshake(
+        &self,
+        input: DeclineHandshakeRequest,
+    ) -> Result<DeclineHandshakeResponse, RusotoError<DeclineHandshakeError>> {
+        self.decline_handshake_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the organization. You can delete an organization only by using credentials from the master account. The organization must be empty of member accounts.</p>
+    async fn delete_organization(&self) -> Result<(), RusotoError<DeleteOrganizationError>> {
+        self.delete_organization_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an organizational unit (OU) from a root or another OU. You must first remove all accounts and child OUs from the OU that you want to delete.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn delete_organizational_unit(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> Result<(), RusotoError<DeleteOrganizationalUnitError>> {
+        self.delete_organizational_unit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified policy from your organization. Before you perform this operation, you must first detach the policy from all organizational units (OUs), roots, and accounts.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.delete_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves AWS Organizations related information about the specified account.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn describe_account(
+        &self,
+        input: DescribeAccountRequest,
+    ) -> Result<DescribeAccountResponse, RusotoError<DescribeAccountError>> {
+        self.describe_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the current status of an asynchronous request to create an account.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn describe_create_account_status(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> Result<DescribeCreateAccountStatusResponse, RusotoError<DescribeCreateAccountStatusError>>
+    {
+        self.describe_create_account_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the contents of the effective tag policy for the account. The effective tag policy is the aggregation of any tag policies the account inherits, plus any policy directly that is attached to the account. </p> <p>This action returns information on tag policies only.</p> <p>For more information on policy inheritance, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies-inheritance.html">How Policy Inheritance Works</a> in the <i>AWS Organizations User Guide</i>.</p> <p>This operation can be called from any account in the organization.</p>
+    async fn describe_effective_policy(
+        &self,
+        input: DescribeEffectivePolicyRequest,
+    ) -> Result<DescribeEffectivePolicyResponse, RusotoError<DescribeEffectivePolicyError>> {
+        self.describe_effective_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a previously requested handshake. The handshake ID comes from the response to the original <a>InviteAccountToOrganization</a> operation that generated the handshake.</p> <p>You can access handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> for only 30 days after they change to that state. They're then deleted and no longer accessible.</p> <p>This operation can be called from any account in the organization.</p>
+    async fn describe_handshake(
+        &self,
+        input: DescribeHandshakeRequest,
+    ) -> Result<DescribeHandshakeResponse, RusotoError<DescribeHandshakeError>> {
+        self.describe_handshake_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves information about the organization that the user&#39;s account belongs to.</p> <p>This operation can be called from any account in the organization.</p> <note> <p>Even if a policy type is shown as available in the organization, you can disable it separately at the root level with <a>DisablePolicyType</a>. Use <a>ListRoots</a> to see the status of policy types for a specified root.</p> </note></p>
+    async fn describe_organization(
+        &self,
+    ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
+        self.describe_organization_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about an organizational unit (OU).</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn describe_organizational_unit(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> Result<DescribeOrganizationalUnitResponse, RusotoError<DescribeOrganizationalUnitError>>
+    {
+        self.describe_organizational_unit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a policy.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn describe_policy(
+        &self,
+        input: DescribePolicyRequest,
+    ) -> Result<DescribePolicyResponse, RusotoError<DescribePolicyError>> {
+        self.describe_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches a policy from a target root, organizational unit (OU), or account. If the policy being detached is a service control policy (SCP), the changes to permissions for IAM users and roles in affected accounts are immediate.</p> <p> <b>Note:</b> Every root, OU, and account must have at least one SCP attached. You can replace the default <code>FullAWSAccess</code> policy with one that limits the permissions that can be delegated. To do that, you must attach the replacement policy before you can remove the default one. This is the authorization strategy of using an <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html#orgs_policies_whitelist">allow list</a>. You could instead attach a second SCP and leave the <code>FullAWSAccess</code> SCP still attached. You could then specify <code>"Effect": "Deny"</code> in the second SCP to override the <code>"Effect": "Allow"</code> in the <code>FullAWSAccess</code> policy (or any other attached SCP). If you take these steps, you're using the authorization strategy of a <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html#orgs_policies_blacklist">deny list</a>. </p> <p>This operation can be called only from the organization's master account.</p>
+    async fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<(), RusotoError<DetachPolicyError>> {
+        self.detach_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you disable integration, the specified service no longer can create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in <i>new</i> accounts in your organization. This means the service can't perform operations on your behalf on any new accounts in your organization. The service can still perform operations in older accounts until the service completes its clean-up from AWS Organizations.</p> <p/> <important> <p>We recommend that you disable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the other service is aware that it can clean up any resources that are required only for the integration. How the service cleans up its resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>After you perform the <code>DisableAWSServiceAccess</code> operation, the specified service can no longer perform operations in your organization's accounts. The only exception is when the operations are explicitly permitted by IAM policies that are attached to your roles. </p> <p>For more information about integrating other services with AWS Organizations, including the list of services that work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    async fn disable_aws_service_access(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> Result<(), RusotoError<DisableAWSServiceAccessError>> {
+        self.disable_aws_service_access_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables an organizational control policy type in a root and detaches all policies of that type from the organization root, OUs, and accounts. A policy of a certain type can be attached to entities in a root only if that type is enabled in the root. After you perform this operation, you no longer can attach policies of the specified type to that root or to any organizational unit (OU) or account in that root. You can undo this by using the <a>EnablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. If you disable a policy for a root, it still appears enabled for the organization if <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">all features</a> are enabled for the organization. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p> To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
+    async fn disable_policy_type(
+        &self,
+        input: DisablePolicyTypeRequest,
+    ) -> Result<DisablePolicyTypeResponse, RusotoError<DisablePolicyTypeError>> {
+        self.disable_policy_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you enable integration, you allow the specified service to create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in all the accounts in your organization. This allows the service to perform operations on your behalf in your organization and its accounts.</p> <important> <p>We recommend that you enable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the service is aware that it can create the resources that are required for the integration. How the service creates those resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>For more information about enabling services to integrate with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account and only if the organization has <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">enabled all features</a>.</p>
+    async fn enable_aws_service_access(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> Result<(), RusotoError<EnableAWSServiceAccessError>> {
+        self.enable_aws_service_access_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables all features in an organization. This enables the use of organization policies that can restrict the services and actions that can be called in each account. Until you enable all features, you have access only to consolidated billing. You can't use any of the advanced account administration features that AWS Organizations supports. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <p>This operation is required only for organizations that were created explicitly with only the consolidated billing features enabled. Calling this operation sends a handshake to every invited account in the organization. The feature set change can be finalized and the additional features enabled only after all administrators in the invited accounts approve the change. Accepting the handshake approves the change.</p> </important> <p>After you enable all features, you can separately enable or disable individual policy types in a root using <a>EnablePolicyType</a> and <a>DisablePolicyType</a>. To see the status of policy types in a root, use <a>ListRoots</a>.</p> <p>After all invited member accounts accept the handshake, you finalize the feature set change by accepting the handshake that contains <code>"Action": "ENABLE_ALL_FEATURES"</code>. This completes the change.</p> <p>After you enable all features in your organization, the master account in the organization can apply policies on all member accounts. These policies can restrict what users and even administrators in those accounts can do. The master account can apply policies that prevent accounts from leaving the organization. Ensure that your account administrators are aware of this.</p> <p>This operation can be called only from the organization's master account. </p>
+    async fn enable_all_features(
+        &self,
+    ) -> Result<EnableAllFeaturesResponse, RusotoError<EnableAllFeaturesError>> {
+        self.enable_all_features_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables a policy type in a root. After you enable a policy type in a root, you can attach policies of that type to the root, any organizational unit (OU), or account in that root. You can undo this by using the <a>DisablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p>You can enable a policy type in a root only if that policy type is available in the organization. To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
+    async fn enable_policy_type(
+        &self,
+        input: EnablePolicyTypeRequest,
+    ) -> Result<EnablePolicyTypeResponse, RusotoError<EnablePolicyTypeError>> {
+        self.enable_policy_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends an invitation to another account to join your organization as a member account. AWS Organizations sends email on your behalf to the email address that is associated with the other account's owner. The invitation is implemented as a <a>Handshake</a> whose details are in the response.</p> <important> <ul> <li> <p>You can invite AWS accounts only from the same seller as the master account. For example, assume that your organization's master account was created by Amazon Internet Services Pvt. Ltd (AISPL), an AWS seller in India. You can invite only other AISPL accounts to your organization. You can't combine accounts from AISPL and AWS or from any other AWS seller. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/useconsolidatedbilliing-India.html">Consolidated Billing in India</a>.</p> </li> <li> <p>You might receive an exception that indicates that you exceeded your account limits for the organization or that the operation failed because your organization is still initializing. If so, wait one hour and then try again. If the error persists after an hour, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> </ul> </important> <p>This operation can be called only from the organization's master account.</p>
+    async fn invite_account_to_organization(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> Result<InviteAccountToOrganizationResponse, RusotoError<InviteAccountToOrganizationError>>
+    {
+        self.invite_account_to_organization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes a member account from its parent organization. This version of the operation is performed by the account that wants to leave. To remove a member account as a user in the master account, use <a>RemoveAccountFromOrganization</a> instead.</p> <p>This operation can be called only from a member account in the organization.</p> <important> <ul> <li> <p>The master account in an organization with all features enabled can set service control policies (SCPs) that can restrict what administrators of member accounts can do. These restrictions can include preventing member accounts from successfully calling <code>LeaveOrganization</code>. </p> </li> <li> <p>You can leave an organization as a member account only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI, the information required of standalone accounts is <i>not</i> automatically collected. For each account that you want to make standalone, you must accept the end user license agreement (EULA). You must also choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You can leave an organization only after you enable IAM user access to billing in your account. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> </li> </ul> </important></p>
+    async fn leave_organization(&self) -> Result<(), RusotoError<LeaveOrganizationError>> {
+        self.leave_organization_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the AWS services that you enabled to integrate with your organization. After a service on this list creates the resources that it requires for the integration, it can perform operations on your organization and its accounts.</p> <p>For more information about integrating other services with AWS Organizations, including the list of services that currently work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_aws_service_access_for_organization(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> Result<
+        ListAWSServiceAccessForOrganizationResponse,
+        RusotoError<ListAWSServiceAccessForOrganizationError>,
+    > {
+        self.list_aws_service_access_for_organization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the accounts in the organization. To request only the accounts in a specified root or organizational unit (OU), use the <a>ListAccountsForParent</a> operation instead.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+        self.list_accounts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the accounts in an organization that are contained by the specified target root or organizational unit (OU). If you specify the root, you get a list of all the accounts that aren't in any OU. If you specify an OU, you get a list of all the accounts in only that OU and not in any child OUs. To get a list of all accounts in the organization, use the <a>ListAccounts</a> operation.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_accounts_for_parent(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> Result<ListAccountsForParentResponse, RusotoError<ListAccountsForParentError>> {
+        self.list_accounts_for_parent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all of the organizational units (OUs) or accounts that are contained in the specified parent OU or root. This operation, along with <a>ListParents</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_children(
+        &self,
+        input: ListChildrenRequest,
+    ) -> Result<ListChildrenResponse, RusotoError<ListChildrenError>> {
+        self.list_children_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the account creation requests that match the specified status that is currently being tracked for the organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_create_account_status(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> Result<ListCreateAccountStatusResponse, RusotoError<ListCreateAccountStatusError>> {
+        self.list_create_account_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the current handshakes that are associated with the account of the requesting user.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called from any account in the organization.</p>
+    async fn list_handshakes_for_account(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> Result<ListHandshakesForAccountResponse, RusotoError<ListHandshakesForAccountError>> {
+        self.list_handshakes_for_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the handshakes that are associated with the organization that the requesting user is part of. The <code>ListHandshakesForOrganization</code> operation returns a list of handshake structures. Each structure contains details and status about a handshake.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_handshakes_for_organization(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> Result<
+        ListHandshakesForOrganizationResponse,
+        RusotoError<ListHandshakesForOrganizationError>,
+    > {
+        self.list_handshakes_for_organization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the organizational units (OUs) in a parent organizational unit or root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_organizational_units_for_parent(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> Result<
+        ListOrganizationalUnitsForParentResponse,
+        RusotoError<ListOrganizationalUnitsForParentError>,
+    > {
+        self.list_organizational_units_for_parent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the root or organizational units (OUs) that serve as the immediate parent of the specified child OU or account. This operation, along with <a>ListChildren</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account.</p> <note> <p>In the current release, a child can have only a single parent. </p> </note></p>
+    async fn list_parents(
+        &self,
+        input: ListParentsRequest,
+    ) -> Result<ListParentsResponse, RusotoError<ListParentsError>> {
+        self.list_parents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the list of all policies in an organization of a specified type.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.list_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the policies that are directly attached to the specified target root, organizational unit (OU), or account. You must specify the policy type that you want included in the returned list.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_policies_for_target(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> Result<ListPoliciesForTargetResponse, RusotoError<ListPoliciesForTargetError>> {
+        self.list_policies_for_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the roots that are defined in the current organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account.</p> <note> <p>Policy types can be enabled and disabled in roots. This is distinct from whether they&#39;re available in the organization. When you enable all features, you make policy types available for use in that organization. Individual policy types can then be enabled and disabled in a root. To see the availability of a policy type in an organization, use <a>DescribeOrganization</a>.</p> </note></p>
+    async fn list_roots(
+        &self,
+        input: ListRootsRequest,
+    ) -> Result<ListRootsResponse, RusotoError<ListRootsError>> {
+        self.list_roots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists tags for the specified resource. </p> <p>Currently, you can list tags on an account in AWS Organizations.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the roots, organizational units (OUs), and accounts that the specified policy is attached to.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    async fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>> {
+        self.list_targets_for_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Moves an account from its current source parent root or organizational unit (OU) to the specified destination parent root or OU.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn move_account(
+        &self,
+        input: MoveAccountRequest,
+    ) -> Result<(), RusotoError<MoveAccountError>> {
+        self.move_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes the specified account from the organization.</p> <p>The removed account becomes a standalone account that isn&#39;t a member of any organization. It&#39;s no longer subject to any policies and is responsible for its own bill payments. The organization&#39;s master account is no longer charged for any expenses accrued by the member account after it&#39;s removed from the organization.</p> <p>This operation can be called only from the organization&#39;s master account. Member accounts can remove themselves with <a>LeaveOrganization</a> instead.</p> <important> <p>You can remove an account from your organization only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI, the information required of standalone accounts is <i>not</i> automatically collected. For an account that you want to make standalone, you must accept the end user license agreement (EULA). You must also choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. To remove an account that doesn&#39;t yet have this information, you must sign in as the member account. Then follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </important></p>
+    async fn remove_account_from_organization(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> Result<(), RusotoError<RemoveAccountFromOrganizationError>> {
+        self.remove_account_from_organization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to the specified resource.</p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a tag from the specified resource. </p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Renames the specified organizational unit (OU). The ID and ARN don't change. The child OUs and accounts remain in place, and any attached policies of the OU remain attached. </p> <p>This operation can be called only from the organization's master account.</p>
+    async fn update_organizational_unit(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> Result<UpdateOrganizationalUnitResponse, RusotoError<UpdateOrganizationalUnitError>> {
+        self.update_organizational_unit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing policy with a new name, description, or content. If you don't supply any parameter, that value remains unchanged. You can't change a policy's type.</p> <p>This operation can be called only from the organization's master account.</p>
+    async fn update_policy(
+        &self,
+        input: UpdatePolicyRequest,
+    ) -> Result<UpdatePolicyResponse, RusotoError<UpdatePolicyError>> {
+        self.update_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -203,6 +203,7 @@ pub struct Site {
 
 /// Errors returned by CreateOutpost
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateOutpostError {
     /// <p>You do not have permission to perform this operation.</p>
     AccessDenied(String),
@@ -212,6 +213,8 @@ pub enum CreateOutpostError {
     NotFound(String),
     /// <p>You have exceeded a service quota.</p>
     ServiceQuotaExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateOutpostError {
@@ -219,19 +222,22 @@ impl CreateOutpostError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateOutpostError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateOutpostError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateOutpostError::InternalServer(err.msg))
+                    return RusotoError::service(CreateOutpostError::InternalServer(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateOutpostError::NotFound(err.msg))
+                    return RusotoError::service(CreateOutpostError::NotFound(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateOutpostError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateOutpostError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateOutpostError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -245,12 +251,14 @@ impl fmt::Display for CreateOutpostError {
             CreateOutpostError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateOutpostError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateOutpostError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
+            CreateOutpostError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateOutpostError {}
 /// Errors returned by GetOutpost
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOutpostError {
     /// <p>You do not have permission to perform this operation.</p>
     AccessDenied(String),
@@ -258,6 +266,8 @@ pub enum GetOutpostError {
     InternalServer(String),
     /// <p>The specified request is not valid.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOutpostError {
@@ -265,16 +275,16 @@ impl GetOutpostError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOutpostError::AccessDenied(err.msg))
+                    return RusotoError::service(GetOutpostError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetOutpostError::InternalServer(err.msg))
+                    return RusotoError::service(GetOutpostError::InternalServer(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOutpostError::NotFound(err.msg))
+                    return RusotoError::service(GetOutpostError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOutpostError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -287,12 +297,14 @@ impl fmt::Display for GetOutpostError {
             GetOutpostError::AccessDenied(ref cause) => write!(f, "{}", cause),
             GetOutpostError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetOutpostError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetOutpostError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOutpostError {}
 /// Errors returned by GetOutpostInstanceTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOutpostInstanceTypesError {
     /// <p>You do not have permission to perform this operation.</p>
     AccessDenied(String),
@@ -300,6 +312,8 @@ pub enum GetOutpostInstanceTypesError {
     InternalServer(String),
     /// <p>The specified request is not valid.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOutpostInstanceTypesError {
@@ -307,20 +321,25 @@ impl GetOutpostInstanceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOutpostInstanceTypesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOutpostInstanceTypesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetOutpostInstanceTypesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOutpostInstanceTypesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOutpostInstanceTypesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetOutpostInstanceTypesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOutpostInstanceTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -333,17 +352,21 @@ impl fmt::Display for GetOutpostInstanceTypesError {
             GetOutpostInstanceTypesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             GetOutpostInstanceTypesError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetOutpostInstanceTypesError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetOutpostInstanceTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOutpostInstanceTypesError {}
 /// Errors returned by ListOutposts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOutpostsError {
     /// <p>You do not have permission to perform this operation.</p>
     AccessDenied(String),
     /// <p>An internal error has occurred.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOutpostsError {
@@ -351,13 +374,13 @@ impl ListOutpostsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListOutpostsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListOutpostsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListOutpostsError::InternalServer(err.msg))
+                    return RusotoError::service(ListOutpostsError::InternalServer(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOutpostsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -369,17 +392,21 @@ impl fmt::Display for ListOutpostsError {
         match *self {
             ListOutpostsError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListOutpostsError::InternalServer(ref cause) => write!(f, "{}", cause),
+            ListOutpostsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOutpostsError {}
 /// Errors returned by ListSites
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSitesError {
     /// <p>You do not have permission to perform this operation.</p>
     AccessDenied(String),
     /// <p>An internal error has occurred.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSitesError {
@@ -387,13 +414,13 @@ impl ListSitesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSitesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListSitesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSitesError::InternalServer(err.msg))
+                    return RusotoError::service(ListSitesError::InternalServer(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSitesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -405,6 +432,7 @@ impl fmt::Display for ListSitesError {
         match *self {
             ListSitesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListSitesError::InternalServer(ref cause) => write!(f, "{}", cause),
+            ListSitesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -453,6 +481,7 @@ impl OutpostsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> OutpostsClient {
         OutpostsClient {
             client: Client::shared(),
@@ -478,78 +507,184 @@ impl OutpostsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> OutpostsClient {
         OutpostsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<OutpostsClient> {
+        rusoto_core::ClientBuilder::new(OutpostsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> OutpostsClient {
+        OutpostsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Outposts for OutpostsClient {
-    /// <p>Creates an Outpost.</p>
-    async fn create_outpost(
+impl OutpostsClient {
+    /// Calls `create_outpost`, also returning the metadata of the response.
+    pub async fn create_outpost_with_metadata(
         &self,
         input: CreateOutpostInput,
-    ) -> Result<CreateOutpostOutput, RusotoError<CreateOutpostError>> {
+    ) -> Result<(CreateOutpostOutput, ResponseMetadata), RusotoError<CreateOutpostError>> {
+        let request = self.build_create_outpost_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateOutpostOutput, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateOutpostError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_outpost` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_outpost(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateOutpostInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_outpost_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_outpost_request(&self, input: CreateOutpostInput) -> SignedRequest {
         let request_uri = "/outposts";
 
         let mut request = SignedRequest::new("POST", "outposts", &self.region, &request_uri);
+        request.set_operation("CreateOutpost");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_outpost`, also returning the metadata of the response.
+    pub async fn get_outpost_with_metadata(
+        &self,
+        input: GetOutpostInput,
+    ) -> Result<(GetOutpostOutput, ResponseMetadata), RusotoError<GetOutpostError>> {
+        let request = self.build_get_outpost_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateOutpostOutput, _>()?;
+                .deserialize::<GetOutpostOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateOutpostError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetOutpostError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the specified Outpost.</p>
-    async fn get_outpost(
+    /// Presigns the request `get_outpost` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_outpost(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOutpostInput,
-    ) -> Result<GetOutpostOutput, RusotoError<GetOutpostError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_outpost_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_outpost_request(&self, input: GetOutpostInput) -> SignedRequest {
         let request_uri = format!("/outposts/{outpost_id}", outpost_id = input.outpost_id);
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("GetOutpost");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_outpost_instance_types`, also returning the metadata of the response.
+    pub async fn get_outpost_instance_types_with_metadata(
+        &self,
+        input: GetOutpostInstanceTypesInput,
+    ) -> Result<
+        (GetOutpostInstanceTypesOutput, ResponseMetadata),
+        RusotoError<GetOutpostInstanceTypesError>,
+    > {
+        let request = self.build_get_outpost_instance_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetOutpostOutput, _>()?;
+                .deserialize::<GetOutpostInstanceTypesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOutpostError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetOutpostInstanceTypesError::from_response(response))
         }
     }
 
-    /// <p>Lists the instance types for the specified Outpost.</p>
-    async fn get_outpost_instance_types(
+    /// Presigns the request `get_outpost_instance_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_outpost_instance_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOutpostInstanceTypesInput,
-    ) -> Result<GetOutpostInstanceTypesOutput, RusotoError<GetOutpostInstanceTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_outpost_instance_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_outpost_instance_types_request(
+        &self,
+        input: GetOutpostInstanceTypesInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/outposts/{outpost_id}/instanceTypes",
             outpost_id = input.outpost_id
         );
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("GetOutpostInstanceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -561,31 +696,55 @@ impl Outposts for OutpostsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_outposts`, also returning the metadata of the response.
+    pub async fn list_outposts_with_metadata(
+        &self,
+        input: ListOutpostsInput,
+    ) -> Result<(ListOutpostsOutput, ResponseMetadata), RusotoError<ListOutpostsError>> {
+        let request = self.build_list_outposts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetOutpostInstanceTypesOutput, _>()?;
+                .deserialize::<ListOutpostsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOutpostInstanceTypesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListOutpostsError::from_response(response))
         }
     }
 
-    /// <p>List the Outposts for your AWS account.</p>
-    async fn list_outposts(
+    /// Presigns the request `list_outposts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_outposts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOutpostsInput,
-    ) -> Result<ListOutpostsOutput, RusotoError<ListOutpostsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_outposts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_outposts_request(&self, input: ListOutpostsInput) -> SignedRequest {
         let request_uri = "/outposts";
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("ListOutposts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -597,31 +756,55 @@ impl Outposts for OutpostsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_sites`, also returning the metadata of the response.
+    pub async fn list_sites_with_metadata(
+        &self,
+        input: ListSitesInput,
+    ) -> Result<(ListSitesOutput, ResponseMetadata), RusotoError<ListSitesError>> {
+        let request = self.build_list_sites_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListOutpostsOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListSitesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOutpostsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSitesError::from_response(response))
         }
     }
 
-    /// <p>Lists the sites for the specified AWS account.</p>
-    async fn list_sites(
+    /// Presigns the request `list_sites` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_sites(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSitesInput,
-    ) -> Result<ListSitesOutput, RusotoError<ListSitesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_sites_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_sites_request(&self, input: ListSitesInput) -> SignedRequest {
         let request_uri = "/sites";
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation("ListSites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -633,20 +816,169 @@ impl Outposts for OutpostsClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetOutpostInstanceTypes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_outpost_instance_types_pages(
+        &self,
+        input: GetOutpostInstanceTypesInput,
+    ) -> impl futures::Stream<
+        Item = Result<GetOutpostInstanceTypesOutput, RusotoError<GetOutpostInstanceTypesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OutpostsClient, input| async move {
+                client.get_outpost_instance_types(input).await
+            },
+            |input: &GetOutpostInstanceTypesInput, output: &GetOutpostInstanceTypesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetOutpostInstanceTypesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `InstanceTypes` of every page of `GetOutpostInstanceTypes` results for `input`, as `get_outpost_instance_types_pages` requests them.
+    pub fn get_outpost_instance_types_stream(
+        &self,
+        input: GetOutpostInstanceTypesInput,
+    ) -> impl futures::Stream<
+        Item = Result<InstanceTypeItem, RusotoError<GetOutpostInstanceTypesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_outpost_instance_types_pages(input), |page| {
+            page.instance_types.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOutposts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_outposts_pages(
+        &self,
+        input: ListOutpostsInput,
+    ) -> impl futures::Stream<Item = Result<ListOutpostsOutput, RusotoError<ListOutpostsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OutpostsClient, input| async move { client.list_outposts(input).await },
+            |input: &ListOutpostsInput, output: &ListOutpostsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOutpostsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Outposts` of every page of `ListOutposts` results for `input`, as `list_outposts_pages` requests them.
+    pub fn list_outposts_stream(
+        &self,
+        input: ListOutpostsInput,
+    ) -> impl futures::Stream<Item = Result<Outpost, RusotoError<ListOutpostsError>>> + Send {
+        rusoto_core::pagination::items(self.list_outposts_pages(input), |page| {
+            page.outposts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSites` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_sites_pages(
+        &self,
+        input: ListSitesInput,
+    ) -> impl futures::Stream<Item = Result<ListSitesOutput, RusotoError<ListSitesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: OutpostsClient, input| async move { client.list_sites(input).await },
+            |input: &ListSitesInput, output: &ListSitesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSitesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Sites` of every page of `ListSites` results for `input`, as `list_sites_pages` requests them.
+    pub fn list_sites_stream(
+        &self,
+        input: ListSitesInput,
+    ) -> impl futures::Stream<Item = Result<Site, RusotoError<ListSitesError>>> + Send {
+        rusoto_core::pagination::items(self.list_sites_pages(input), |page| {
+            page.sites.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Outposts for OutpostsClient {
+    /// <p>Creates an Outpost.</p>
+    async fn create_outpost(
+        &self,
+        input: CreateOutpostInput,
+    ) -> Result<CreateOutpostOutput, RusotoError<CreateOutpostError>> {
+        self.create_outpost_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<ListSitesOutput, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSitesError::from_response(response))
-        }
+    /// <p>Gets information about the specified Outpost.</p>
+    async fn get_outpost(
+        &self,
+        input: GetOutpostInput,
+    ) -> Result<GetOutpostOutput, RusotoError<GetOutpostError>> {
+        self.get_outpost_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the instance types for the specified Outpost.</p>
+    async fn get_outpost_instance_types(
+        &self,
+        input: GetOutpostInstanceTypesInput,
+    ) -> Result<GetOutpostInstanceTypesOutput, RusotoError<GetOutpostInstanceTypesError>> {
+        self.get_outpost_instance_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the Outposts for your AWS account.</p>
+    async fn list_outposts(
+        &self,
+        input: ListOutpostsInput,
+    ) -> Result<ListOutpostsOutput, RusotoError<ListOutpostsError>> {
+        self.list_outposts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the sites for the specified AWS account.</p>
+    async fn list_sites(
+        &self,
+        input: ListSitesInput,
+    ) -> Result<ListSitesOutput, RusotoError<ListSitesError>> {
+        self.list_sites_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -63,9 +63,12 @@ pub struct PutEventsRequest {
 
 /// Errors returned by PutEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutEventsError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutEventsError {
@@ -73,10 +76,10 @@ impl PutEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutEventsError::InvalidInput(err.msg))
+                    return RusotoError::service(PutEventsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -87,6 +90,7 @@ impl fmt::Display for PutEventsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PutEventsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            PutEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -108,6 +112,7 @@ impl PersonalizeEventsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> PersonalizeEventsClient {
         PersonalizeEventsClient {
             client: Client::shared(),
@@ -133,34 +138,85 @@ impl PersonalizeEventsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeEventsClient {
         PersonalizeEventsClient { client, region }
     }
-}
 
-#[async_trait]
-impl PersonalizeEvents for PersonalizeEventsClient {
-    /// <p>Records user interaction event data.</p>
-    async fn put_events(&self, input: PutEventsRequest) -> Result<(), RusotoError<PutEventsError>> {
-        let request_uri = "/events";
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<PersonalizeEventsClient> {
+        rusoto_core::ClientBuilder::new(PersonalizeEventsClient::new_with_client)
+    }
 
-        let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> PersonalizeEventsClient {
+        PersonalizeEventsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
+}
 
-        request.set_endpoint_prefix("personalize-events".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+impl PersonalizeEventsClient {
+    /// Calls `put_events`, also returning the metadata of the response.
+    pub async fn put_events_with_metadata(
+        &self,
+        input: PutEventsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutEventsError>> {
+        let request = self.build_put_events_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PutEventsError::from_response(response))
         }
     }
+
+    /// Presigns the request `put_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_events(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutEventsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_events_request(&self, input: PutEventsRequest) -> SignedRequest {
+        let request_uri = "/events";
+
+        let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("PutEvents");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("personalize-events".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+}
+
+#[async_trait]
+impl PersonalizeEvents for PersonalizeEventsClient {
+    /// <p>Records user interaction event data.</p>
+    async fn put_events(&self, input: PutEventsRequest) -> Result<(), RusotoError<PutEventsError>> {
+        self.put_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
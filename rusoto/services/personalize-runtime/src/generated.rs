@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -96,11 +96,14 @@ pub struct PredictedItem {
 
 /// Errors returned by GetPersonalizedRanking
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPersonalizedRankingError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>The specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPersonalizedRankingError {
@@ -108,15 +111,19 @@ impl GetPersonalizedRankingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPersonalizedRankingError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetPersonalizedRankingError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPersonalizedRankingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPersonalizedRankingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPersonalizedRankingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -128,17 +135,21 @@ impl fmt::Display for GetPersonalizedRankingError {
         match *self {
             GetPersonalizedRankingError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetPersonalizedRankingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetPersonalizedRankingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPersonalizedRankingError {}
 /// Errors returned by GetRecommendations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRecommendationsError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>The specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRecommendationsError {
@@ -146,13 +157,19 @@ impl GetRecommendationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRecommendationsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetRecommendationsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecommendationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetRecommendationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRecommendationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -164,6 +181,7 @@ impl fmt::Display for GetRecommendationsError {
         match *self {
             GetRecommendationsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetRecommendationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetRecommendationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -194,6 +212,7 @@ impl PersonalizeRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> PersonalizeRuntimeClient {
         PersonalizeRuntimeClient {
             client: Client::shared(),
@@ -219,69 +238,161 @@ impl PersonalizeRuntimeClient {
     pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeRuntimeClient {
         PersonalizeRuntimeClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<PersonalizeRuntimeClient> {
+        rusoto_core::ClientBuilder::new(PersonalizeRuntimeClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> PersonalizeRuntimeClient {
+        PersonalizeRuntimeClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl PersonalizeRuntime for PersonalizeRuntimeClient {
-    /// <p><p>Re-ranks a list of recommended items for the given user. The first item in the list is deemed the most likely item to be of interest to the user.</p> <note> <p>The solution backing the campaign must have been created using a recipe of type PERSONALIZED_RANKING.</p> </note></p>
-    async fn get_personalized_ranking(
+impl PersonalizeRuntimeClient {
+    /// Calls `get_personalized_ranking`, also returning the metadata of the response.
+    pub async fn get_personalized_ranking_with_metadata(
         &self,
         input: GetPersonalizedRankingRequest,
-    ) -> Result<GetPersonalizedRankingResponse, RusotoError<GetPersonalizedRankingError>> {
-        let request_uri = "/personalize-ranking";
-
-        let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("personalize-runtime".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (GetPersonalizedRankingResponse, ResponseMetadata),
+        RusotoError<GetPersonalizedRankingError>,
+    > {
+        let request = self.build_get_personalized_ranking_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetPersonalizedRankingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetPersonalizedRankingError::from_response(response))
         }
     }
 
-    /// <p><p>Returns a list of recommended items. The required input depends on the recipe type used to create the solution backing the campaign, as follows:</p> <ul> <li> <p>RELATED<em>ITEMS - <code>itemId</code> required, <code>userId</code> not used</p> </li> <li> <p>USER</em>PERSONALIZATION - <code>itemId</code> optional, <code>userId</code> required</p> </li> </ul> <note> <p>Campaigns that are backed by a solution created using a recipe of type PERSONALIZED_RANKING use the API.</p> </note></p>
-    async fn get_recommendations(
+    /// Presigns the request `get_personalized_ranking` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_personalized_ranking(
         &self,
-        input: GetRecommendationsRequest,
-    ) -> Result<GetRecommendationsResponse, RusotoError<GetRecommendationsError>> {
-        let request_uri = "/recommendations";
+        presigner: &rusoto_core::Presigner,
+        input: GetPersonalizedRankingRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_personalized_ranking_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_personalized_ranking_request(
+        &self,
+        input: GetPersonalizedRankingRequest,
+    ) -> SignedRequest {
+        let request_uri = "/personalize-ranking";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("GetPersonalizedRanking");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-runtime".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_recommendations`, also returning the metadata of the response.
+    pub async fn get_recommendations_with_metadata(
+        &self,
+        input: GetRecommendationsRequest,
+    ) -> Result<(GetRecommendationsResponse, ResponseMetadata), RusotoError<GetRecommendationsError>>
+    {
+        let request = self.build_get_recommendations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetRecommendationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetRecommendationsError::from_response(response))
         }
     }
+
+    /// Presigns the request `get_recommendations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_recommendations(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetRecommendationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_recommendations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_recommendations_request(&self, input: GetRecommendationsRequest) -> SignedRequest {
+        let request_uri = "/recommendations";
+
+        let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("GetRecommendations");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("personalize-runtime".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+}
+
+#[async_trait]
+impl PersonalizeRuntime for PersonalizeRuntimeClient {
+    /// <p><p>Re-ranks a list of recommended items for the given user. The first item in the list is deemed the most likely item to be of interest to the user.</p> <note> <p>The solution backing the campaign must have been created using a recipe of type PERSONALIZED_RANKING.</p> </note></p>
+    async fn get_personalized_ranking(
+        &self,
+        input: GetPersonalizedRankingRequest,
+    ) -> Result<GetPersonalizedRankingResponse, RusotoError<GetPersonalizedRankingError>> {
+        self.get_personalized_ranking_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a list of recommended items. The required input depends on the recipe type used to create the solution backing the campaign, as follows:</p> <ul> <li> <p>RELATED<em>ITEMS - <code>itemId</code> required, <code>userId</code> not used</p> </li> <li> <p>USER</em>PERSONALIZATION - <code>itemId</code> optional, <code>userId</code> required</p> </li> </ul> <note> <p>Campaigns that are backed by a solution created using a recipe of type PERSONALIZED_RANKING use the API.</p> </note></p>
+    async fn get_recommendations(
+        &self,
+        input: GetRecommendationsRequest,
+    ) -> Result<GetRecommendationsResponse, RusotoError<GetRecommendationsError>> {
+        self.get_recommendations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
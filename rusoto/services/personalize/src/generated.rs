@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1960,6 +1960,7 @@ pub struct UpdateCampaignResponse {
 
 /// Errors returned by CreateBatchInferenceJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBatchInferenceJobError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -1971,6 +1972,8 @@ pub enum CreateBatchInferenceJobError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBatchInferenceJobError {
@@ -1978,32 +1981,37 @@ impl CreateBatchInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBatchInferenceJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBatchInferenceJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateBatchInferenceJobError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBatchInferenceJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBatchInferenceJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBatchInferenceJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2020,12 +2028,14 @@ impl fmt::Display for CreateBatchInferenceJobError {
             }
             CreateBatchInferenceJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateBatchInferenceJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateBatchInferenceJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBatchInferenceJobError {}
 /// Errors returned by CreateCampaign
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCampaignError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2037,6 +2047,8 @@ pub enum CreateCampaignError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCampaignError {
@@ -2044,24 +2056,28 @@ impl CreateCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateCampaignError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCampaignError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateCampaignError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateCampaignError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCampaignError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateCampaignError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateCampaignError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateCampaignError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCampaignError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2076,12 +2092,14 @@ impl fmt::Display for CreateCampaignError {
             CreateCampaignError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateCampaignError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateCampaignError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateCampaignError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCampaignError {}
 /// Errors returned by CreateDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatasetError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2093,6 +2111,8 @@ pub enum CreateDatasetError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatasetError {
@@ -2100,22 +2120,28 @@ impl CreateDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateDatasetError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateDatasetError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateDatasetError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2130,12 +2156,14 @@ impl fmt::Display for CreateDatasetError {
             CreateDatasetError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateDatasetError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatasetError {}
 /// Errors returned by CreateDatasetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatasetGroupError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2143,6 +2171,8 @@ pub enum CreateDatasetGroupError {
     LimitExceeded(String),
     /// <p>The specified resource already exists.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatasetGroupError {
@@ -2150,18 +2180,25 @@ impl CreateDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetGroupError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetGroupError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatasetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2174,12 +2211,14 @@ impl fmt::Display for CreateDatasetGroupError {
             CreateDatasetGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateDatasetGroupError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDatasetGroupError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateDatasetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatasetGroupError {}
 /// Errors returned by CreateDatasetImportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatasetImportJobError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2191,6 +2230,8 @@ pub enum CreateDatasetImportJobError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatasetImportJobError {
@@ -2198,30 +2239,37 @@ impl CreateDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetImportJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetImportJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDatasetImportJobError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetImportJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetImportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatasetImportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2236,12 +2284,14 @@ impl fmt::Display for CreateDatasetImportJobError {
             CreateDatasetImportJobError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateDatasetImportJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateDatasetImportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateDatasetImportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatasetImportJobError {}
 /// Errors returned by CreateEventTracker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEventTrackerError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2253,6 +2303,8 @@ pub enum CreateEventTrackerError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEventTrackerError {
@@ -2260,24 +2312,37 @@ impl CreateEventTrackerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateEventTrackerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateEventTrackerError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEventTrackerError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateEventTrackerError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateEventTrackerError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEventTrackerError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateEventTrackerError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateEventTrackerError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEventTrackerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateEventTrackerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEventTrackerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2292,12 +2357,14 @@ impl fmt::Display for CreateEventTrackerError {
             CreateEventTrackerError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateEventTrackerError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateEventTrackerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateEventTrackerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEventTrackerError {}
 /// Errors returned by CreateSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSchemaError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2305,6 +2372,8 @@ pub enum CreateSchemaError {
     LimitExceeded(String),
     /// <p>The specified resource already exists.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSchemaError {
@@ -2312,16 +2381,19 @@ impl CreateSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateSchemaError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateSchemaError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSchemaError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateSchemaError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2334,12 +2406,14 @@ impl fmt::Display for CreateSchemaError {
             CreateSchemaError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateSchemaError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateSchemaError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSchemaError {}
 /// Errors returned by CreateSolution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSolutionError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2351,6 +2425,8 @@ pub enum CreateSolutionError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSolutionError {
@@ -2358,24 +2434,28 @@ impl CreateSolutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSolutionError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateSolutionError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSolutionError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateSolutionError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSolutionError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSolutionError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateSolutionError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateSolutionError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSolutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateSolutionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSolutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2390,12 +2470,14 @@ impl fmt::Display for CreateSolutionError {
             CreateSolutionError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateSolutionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateSolutionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateSolutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSolutionError {}
 /// Errors returned by CreateSolutionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSolutionVersionError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2403,6 +2485,8 @@ pub enum CreateSolutionVersionError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSolutionVersionError {
@@ -2410,18 +2494,25 @@ impl CreateSolutionVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateSolutionVersionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateSolutionVersionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSolutionVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSolutionVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2434,12 +2525,14 @@ impl fmt::Display for CreateSolutionVersionError {
             CreateSolutionVersionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateSolutionVersionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateSolutionVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateSolutionVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSolutionVersionError {}
 /// Errors returned by DeleteCampaign
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCampaignError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2447,6 +2540,8 @@ pub enum DeleteCampaignError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCampaignError {
@@ -2454,16 +2549,19 @@ impl DeleteCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteCampaignError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteCampaignError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteCampaignError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteCampaignError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCampaignError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2476,12 +2574,14 @@ impl fmt::Display for DeleteCampaignError {
             DeleteCampaignError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteCampaignError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteCampaignError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteCampaignError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCampaignError {}
 /// Errors returned by DeleteDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatasetError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2489,6 +2589,8 @@ pub enum DeleteDatasetError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatasetError {
@@ -2496,16 +2598,19 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteDatasetError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteDatasetError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2518,12 +2623,14 @@ impl fmt::Display for DeleteDatasetError {
             DeleteDatasetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteDatasetError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatasetError {}
 /// Errors returned by DeleteDatasetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatasetGroupError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2531,6 +2638,8 @@ pub enum DeleteDatasetGroupError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatasetGroupError {
@@ -2538,16 +2647,25 @@ impl DeleteDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetGroupError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatasetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2560,12 +2678,14 @@ impl fmt::Display for DeleteDatasetGroupError {
             DeleteDatasetGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteDatasetGroupError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteDatasetGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDatasetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatasetGroupError {}
 /// Errors returned by DeleteEventTracker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEventTrackerError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2573,6 +2693,8 @@ pub enum DeleteEventTrackerError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEventTrackerError {
@@ -2580,16 +2702,25 @@ impl DeleteEventTrackerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteEventTrackerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteEventTrackerError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEventTrackerError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteEventTrackerError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEventTrackerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteEventTrackerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEventTrackerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2602,12 +2733,14 @@ impl fmt::Display for DeleteEventTrackerError {
             DeleteEventTrackerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteEventTrackerError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteEventTrackerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteEventTrackerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEventTrackerError {}
 /// Errors returned by DeleteSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSchemaError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2615,6 +2748,8 @@ pub enum DeleteSchemaError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSchemaError {
@@ -2622,16 +2757,16 @@ impl DeleteSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteSchemaError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSchemaError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteSchemaError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteSchemaError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2644,12 +2779,14 @@ impl fmt::Display for DeleteSchemaError {
             DeleteSchemaError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteSchemaError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteSchemaError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSchemaError {}
 /// Errors returned by DeleteSolution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSolutionError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -2657,6 +2794,8 @@ pub enum DeleteSolutionError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSolutionError {
@@ -2664,16 +2803,19 @@ impl DeleteSolutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSolutionError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteSolutionError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSolutionError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteSolutionError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSolutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteSolutionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSolutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2686,17 +2828,21 @@ impl fmt::Display for DeleteSolutionError {
             DeleteSolutionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteSolutionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteSolutionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSolutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSolutionError {}
 /// Errors returned by DescribeAlgorithm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAlgorithmError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAlgorithmError {
@@ -2704,13 +2850,19 @@ impl DescribeAlgorithmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAlgorithmError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeAlgorithmError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAlgorithmError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeAlgorithmError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAlgorithmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2722,17 +2874,21 @@ impl fmt::Display for DescribeAlgorithmError {
         match *self {
             DescribeAlgorithmError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeAlgorithmError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeAlgorithmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAlgorithmError {}
 /// Errors returned by DescribeBatchInferenceJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBatchInferenceJobError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBatchInferenceJobError {
@@ -2740,17 +2896,21 @@ impl DescribeBatchInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeBatchInferenceJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBatchInferenceJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBatchInferenceJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBatchInferenceJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeBatchInferenceJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2762,17 +2922,21 @@ impl fmt::Display for DescribeBatchInferenceJobError {
         match *self {
             DescribeBatchInferenceJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeBatchInferenceJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeBatchInferenceJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBatchInferenceJobError {}
 /// Errors returned by DescribeCampaign
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCampaignError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCampaignError {
@@ -2780,13 +2944,16 @@ impl DescribeCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeCampaignError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeCampaignError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCampaignError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2798,17 +2965,21 @@ impl fmt::Display for DescribeCampaignError {
         match *self {
             DescribeCampaignError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeCampaignError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeCampaignError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCampaignError {}
 /// Errors returned by DescribeDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDatasetError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDatasetError {
@@ -2816,13 +2987,16 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeDatasetError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2834,17 +3008,21 @@ impl fmt::Display for DescribeDatasetError {
         match *self {
             DescribeDatasetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDatasetError {}
 /// Errors returned by DescribeDatasetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDatasetGroupError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDatasetGroupError {
@@ -2852,15 +3030,19 @@ impl DescribeDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDatasetGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDatasetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2872,17 +3054,21 @@ impl fmt::Display for DescribeDatasetGroupError {
         match *self {
             DescribeDatasetGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeDatasetGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDatasetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDatasetGroupError {}
 /// Errors returned by DescribeDatasetImportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDatasetImportJobError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDatasetImportJobError {
@@ -2890,17 +3076,19 @@ impl DescribeDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDatasetImportJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDatasetImportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDatasetImportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2912,17 +3100,21 @@ impl fmt::Display for DescribeDatasetImportJobError {
         match *self {
             DescribeDatasetImportJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeDatasetImportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDatasetImportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDatasetImportJobError {}
 /// Errors returned by DescribeEventTracker
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventTrackerError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventTrackerError {
@@ -2930,15 +3122,19 @@ impl DescribeEventTrackerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeEventTrackerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeEventTrackerError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventTrackerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventTrackerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventTrackerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2950,17 +3146,21 @@ impl fmt::Display for DescribeEventTrackerError {
         match *self {
             DescribeEventTrackerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeEventTrackerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeEventTrackerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventTrackerError {}
 /// Errors returned by DescribeFeatureTransformation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFeatureTransformationError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFeatureTransformationError {
@@ -2970,17 +3170,24 @@ impl DescribeFeatureTransformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeFeatureTransformationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFeatureTransformationError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeFeatureTransformationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeFeatureTransformationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2994,17 +3201,23 @@ impl fmt::Display for DescribeFeatureTransformationError {
             DescribeFeatureTransformationError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeFeatureTransformationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeFeatureTransformationError {}
 /// Errors returned by DescribeRecipe
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRecipeError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRecipeError {
@@ -3012,13 +3225,16 @@ impl DescribeRecipeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeRecipeError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeRecipeError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRecipeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeRecipeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRecipeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3030,17 +3246,21 @@ impl fmt::Display for DescribeRecipeError {
         match *self {
             DescribeRecipeError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeRecipeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeRecipeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRecipeError {}
 /// Errors returned by DescribeSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSchemaError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSchemaError {
@@ -3048,13 +3268,16 @@ impl DescribeSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeSchemaError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeSchemaError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3066,17 +3289,21 @@ impl fmt::Display for DescribeSchemaError {
         match *self {
             DescribeSchemaError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeSchemaError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSchemaError {}
 /// Errors returned by DescribeSolution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSolutionError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSolutionError {
@@ -3084,13 +3311,16 @@ impl DescribeSolutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeSolutionError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeSolutionError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSolutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeSolutionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSolutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3102,17 +3332,21 @@ impl fmt::Display for DescribeSolutionError {
         match *self {
             DescribeSolutionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeSolutionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSolutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSolutionError {}
 /// Errors returned by DescribeSolutionVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSolutionVersionError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSolutionVersionError {
@@ -3120,17 +3354,19 @@ impl DescribeSolutionVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeSolutionVersionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSolutionVersionError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSolutionVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSolutionVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSolutionVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3142,12 +3378,14 @@ impl fmt::Display for DescribeSolutionVersionError {
         match *self {
             DescribeSolutionVersionError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeSolutionVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSolutionVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSolutionVersionError {}
 /// Errors returned by GetSolutionMetrics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSolutionMetricsError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -3155,6 +3393,8 @@ pub enum GetSolutionMetricsError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSolutionMetricsError {
@@ -3162,16 +3402,25 @@ impl GetSolutionMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSolutionMetricsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetSolutionMetricsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(GetSolutionMetricsError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        GetSolutionMetricsError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSolutionMetricsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetSolutionMetricsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSolutionMetricsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3184,17 +3433,21 @@ impl fmt::Display for GetSolutionMetricsError {
             GetSolutionMetricsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetSolutionMetricsError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             GetSolutionMetricsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetSolutionMetricsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSolutionMetricsError {}
 /// Errors returned by ListBatchInferenceJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBatchInferenceJobsError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>The token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBatchInferenceJobsError {
@@ -3202,15 +3455,19 @@ impl ListBatchInferenceJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBatchInferenceJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListBatchInferenceJobsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListBatchInferenceJobsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBatchInferenceJobsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBatchInferenceJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3222,17 +3479,21 @@ impl fmt::Display for ListBatchInferenceJobsError {
         match *self {
             ListBatchInferenceJobsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListBatchInferenceJobsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListBatchInferenceJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBatchInferenceJobsError {}
 /// Errors returned by ListCampaigns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCampaignsError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>The token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCampaignsError {
@@ -3240,13 +3501,16 @@ impl ListCampaignsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListCampaignsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListCampaignsError::InvalidInput(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCampaignsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListCampaignsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCampaignsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3258,15 +3522,19 @@ impl fmt::Display for ListCampaignsError {
         match *self {
             ListCampaignsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListCampaignsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListCampaignsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCampaignsError {}
 /// Errors returned by ListDatasetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatasetGroupsError {
     /// <p>The token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatasetGroupsError {
@@ -3274,10 +3542,13 @@ impl ListDatasetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListDatasetGroupsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatasetGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3288,17 +3559,21 @@ impl fmt::Display for ListDatasetGroupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDatasetGroupsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDatasetGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatasetGroupsError {}
 /// Errors returned by ListDatasetImportJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatasetImportJobsError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>The token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatasetImportJobsError {
@@ -3306,15 +3581,19 @@ impl ListDatasetImportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListDatasetImportJobsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDatasetImportJobsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatasetImportJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3326,17 +3605,21 @@ impl fmt::Display for ListDatasetImportJobsError {
         match *self {
             ListDatasetImportJobsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListDatasetImportJobsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDatasetImportJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatasetImportJobsError {}
 /// Errors returned by ListDatasets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatasetsError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>The token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatasetsError {
@@ -3344,13 +3627,13 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListDatasetsError::InvalidInput(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListDatasetsError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatasetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3362,17 +3645,21 @@ impl fmt::Display for ListDatasetsError {
         match *self {
             ListDatasetsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListDatasetsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDatasetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatasetsError {}
 /// Errors returned by ListEventTrackers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEventTrackersError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>The token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEventTrackersError {
@@ -3380,13 +3667,19 @@ impl ListEventTrackersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListEventTrackersError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListEventTrackersError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListEventTrackersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListEventTrackersError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEventTrackersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3398,15 +3691,19 @@ impl fmt::Display for ListEventTrackersError {
         match *self {
             ListEventTrackersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListEventTrackersError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListEventTrackersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEventTrackersError {}
 /// Errors returned by ListRecipes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRecipesError {
     /// <p>The token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRecipesError {
@@ -3414,10 +3711,10 @@ impl ListRecipesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListRecipesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListRecipesError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRecipesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3428,15 +3725,19 @@ impl fmt::Display for ListRecipesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListRecipesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListRecipesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRecipesError {}
 /// Errors returned by ListSchemas
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSchemasError {
     /// <p>The token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSchemasError {
@@ -3444,10 +3745,10 @@ impl ListSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSchemasError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListSchemasError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSchemasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3458,12 +3759,14 @@ impl fmt::Display for ListSchemasError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListSchemasError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListSchemasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSchemasError {}
 /// Errors returned by ListSolutionVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSolutionVersionsError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -3471,6 +3774,8 @@ pub enum ListSolutionVersionsError {
     InvalidNextToken(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSolutionVersionsError {
@@ -3478,20 +3783,25 @@ impl ListSolutionVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSolutionVersionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListSolutionVersionsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSolutionVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSolutionVersionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSolutionVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSolutionVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSolutionVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3504,17 +3814,21 @@ impl fmt::Display for ListSolutionVersionsError {
             ListSolutionVersionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListSolutionVersionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListSolutionVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListSolutionVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSolutionVersionsError {}
 /// Errors returned by ListSolutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSolutionsError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
     /// <p>The token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSolutionsError {
@@ -3522,13 +3836,16 @@ impl ListSolutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSolutionsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListSolutionsError::InvalidInput(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSolutionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListSolutionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSolutionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3540,12 +3857,14 @@ impl fmt::Display for ListSolutionsError {
         match *self {
             ListSolutionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListSolutionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListSolutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSolutionsError {}
 /// Errors returned by UpdateCampaign
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCampaignError {
     /// <p>Provide a valid value for the field or parameter.</p>
     InvalidInput(String),
@@ -3553,6 +3872,8 @@ pub enum UpdateCampaignError {
     ResourceInUse(String),
     /// <p>Could not find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCampaignError {
@@ -3560,16 +3881,19 @@ impl UpdateCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateCampaignError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateCampaignError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdateCampaignError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateCampaignError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateCampaignError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3582,6 +3906,7 @@ impl fmt::Display for UpdateCampaignError {
             UpdateCampaignError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateCampaignError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateCampaignError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateCampaignError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3837,6 +4162,7 @@ impl PersonalizeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> PersonalizeClient {
         PersonalizeClient {
             client: Client::shared(),
@@ -3862,455 +4188,908 @@ impl PersonalizeClient {
     pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeClient {
         PersonalizeClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<PersonalizeClient> {
+        rusoto_core::ClientBuilder::new(PersonalizeClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> PersonalizeClient {
+        PersonalizeClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Personalize for PersonalizeClient {
-    /// <p>Creates a batch inference job. The operation can handle up to 50 million records and the input file must be in JSON format. For more information, see <a>recommendations-batch</a>.</p>
-    async fn create_batch_inference_job(
+impl PersonalizeClient {
+    /// Calls `create_batch_inference_job`, also returning the metadata of the response.
+    pub async fn create_batch_inference_job_with_metadata(
         &self,
         input: CreateBatchInferenceJobRequest,
-    ) -> Result<CreateBatchInferenceJobResponse, RusotoError<CreateBatchInferenceJobError>> {
+    ) -> Result<
+        (CreateBatchInferenceJobResponse, ResponseMetadata),
+        RusotoError<CreateBatchInferenceJobError>,
+    > {
+        let request = self.build_create_batch_inference_job_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBatchInferenceJobResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBatchInferenceJobError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_batch_inference_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_batch_inference_job(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateBatchInferenceJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_batch_inference_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_batch_inference_job_request(
+        &self,
+        input: CreateBatchInferenceJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateBatchInferenceJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateBatchInferenceJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_campaign`, also returning the metadata of the response.
+    pub async fn create_campaign_with_metadata(
+        &self,
+        input: CreateCampaignRequest,
+    ) -> Result<(CreateCampaignResponse, ResponseMetadata), RusotoError<CreateCampaignError>> {
+        let request = self.build_create_campaign_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBatchInferenceJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCampaignResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBatchInferenceJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCampaignError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a campaign by deploying a solution version. When a client calls the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> and <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetPersonalizedRanking.html">GetPersonalizedRanking</a> APIs, a campaign is specified in the request.</p> <p> <b>Minimum Provisioned TPS and Auto-Scaling</b> </p> <p>A transaction is a single <code>GetRecommendations</code> or <code>GetPersonalizedRanking</code> call. Transactions per second (TPS) is the throughput and unit of billing for Amazon Personalize. The minimum provisioned TPS (<code>minProvisionedTPS</code>) specifies the baseline throughput provisioned by Amazon Personalize, and thus, the minimum billing charge. If your TPS increases beyond <code>minProvisionedTPS</code>, Amazon Personalize auto-scales the provisioned capacity up and down, but never below <code>minProvisionedTPS</code>, to maintain a 70% utilization. There&#39;s a short time delay while the capacity is increased that might cause loss of transactions. It&#39;s recommended to start with a low <code>minProvisionedTPS</code>, track your usage using Amazon CloudWatch metrics, and then increase the <code>minProvisionedTPS</code> as necessary.</p> <p> <b>Status</b> </p> <p>A campaign can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN<em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN</em>PROGRESS</p> </li> </ul> <p>To get the campaign status, call <a>DescribeCampaign</a>.</p> <note> <p>Wait until the <code>status</code> of the campaign is <code>ACTIVE</code> before asking the campaign for recommendations.</p> </note> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListCampaigns</a> </p> </li> <li> <p> <a>DescribeCampaign</a> </p> </li> <li> <p> <a>UpdateCampaign</a> </p> </li> <li> <p> <a>DeleteCampaign</a> </p> </li> </ul></p>
-    async fn create_campaign(
+    /// Presigns the request `create_campaign` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_campaign(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCampaignRequest,
-    ) -> Result<CreateCampaignResponse, RusotoError<CreateCampaignError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_campaign_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_campaign_request(&self, input: CreateCampaignRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateCampaign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_dataset`, also returning the metadata of the response.
+    pub async fn create_dataset_with_metadata(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<(CreateDatasetResponse, ResponseMetadata), RusotoError<CreateDatasetError>> {
+        let request = self.build_create_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateCampaignResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDatasetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCampaignError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDatasetError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an empty dataset and adds it to the specified dataset group. Use <a>CreateDatasetImportJob</a> to import your training data to a dataset.</p> <p>There are three types of datasets:</p> <ul> <li> <p>Interactions</p> </li> <li> <p>Items</p> </li> <li> <p>Users</p> </li> </ul> <p>Each dataset type has an associated schema with required field types. Only the <code>Interactions</code> dataset is required in order to train a model (also referred to as creating a solution).</p> <p>A dataset can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN<em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN</em>PROGRESS</p> </li> </ul> <p>To get the status of the dataset, call <a>DescribeDataset</a>.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>CreateDatasetGroup</a> </p> </li> <li> <p> <a>ListDatasets</a> </p> </li> <li> <p> <a>DescribeDataset</a> </p> </li> <li> <p> <a>DeleteDataset</a> </p> </li> </ul></p>
-    async fn create_dataset(
+    /// Presigns the request `create_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDatasetRequest,
-    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dataset_request(&self, input: CreateDatasetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_dataset_group`, also returning the metadata of the response.
+    pub async fn create_dataset_group_with_metadata(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> Result<(CreateDatasetGroupResponse, ResponseMetadata), RusotoError<CreateDatasetGroupError>>
+    {
+        let request = self.build_create_dataset_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDatasetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDatasetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatasetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDatasetGroupError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an empty dataset group. A dataset group contains related datasets that supply data for training a model. A dataset group can contain at most three datasets, one for each type of dataset:</p> <ul> <li> <p>Interactions</p> </li> <li> <p>Items</p> </li> <li> <p>Users</p> </li> </ul> <p>To train a model (create a solution), a dataset group that contains an <code>Interactions</code> dataset is required. Call <a>CreateDataset</a> to add a dataset to the group.</p> <p>A dataset group can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING</p> </li> </ul> <p>To get the status of the dataset group, call <a>DescribeDatasetGroup</a>. If the status shows as CREATE FAILED, the response includes a <code>failureReason</code> key, which describes why the creation failed.</p> <note> <p>You must wait until the <code>status</code> of the dataset group is <code>ACTIVE</code> before adding a dataset to the group.</p> </note> <p>You can specify an AWS Key Management Service (KMS) key to encrypt the datasets in the group. If you specify a KMS key, you must also include an AWS Identity and Access Management (IAM) role that has permission to access the key.</p> <p class="title"> <b>APIs that require a dataset group ARN in the request</b> </p> <ul> <li> <p> <a>CreateDataset</a> </p> </li> <li> <p> <a>CreateEventTracker</a> </p> </li> <li> <p> <a>CreateSolution</a> </p> </li> </ul> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListDatasetGroups</a> </p> </li> <li> <p> <a>DescribeDatasetGroup</a> </p> </li> <li> <p> <a>DeleteDatasetGroup</a> </p> </li> </ul></p>
-    async fn create_dataset_group(
+    /// Presigns the request `create_dataset_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dataset_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDatasetGroupRequest,
-    ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dataset_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dataset_group_request(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_dataset_import_job`, also returning the metadata of the response.
+    pub async fn create_dataset_import_job_with_metadata(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> Result<
+        (CreateDatasetImportJobResponse, ResponseMetadata),
+        RusotoError<CreateDatasetImportJobError>,
+    > {
+        let request = self.build_create_dataset_import_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDatasetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDatasetImportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatasetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDatasetImportJobError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a job that imports training data from your data source (an Amazon S3 bucket) to an Amazon Personalize dataset. To allow Amazon Personalize to import the training data, you must specify an AWS Identity and Access Management (IAM) role that has permission to read from the data source.</p> <important> <p>The dataset import job replaces any previous data in the dataset.</p> </important> <p> <b>Status</b> </p> <p>A dataset import job can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> </ul> <p>To get the status of the import job, call <a>DescribeDatasetImportJob</a>, providing the Amazon Resource Name (ARN) of the dataset import job. The dataset import is complete when the status shows as ACTIVE. If the status shows as CREATE FAILED, the response includes a <code>failureReason</code> key, which describes why the job failed.</p> <note> <p>Importing takes time. You must wait until the status shows as ACTIVE before training a model using the dataset.</p> </note> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListDatasetImportJobs</a> </p> </li> <li> <p> <a>DescribeDatasetImportJob</a> </p> </li> </ul></p>
-    async fn create_dataset_import_job(
+    /// Presigns the request `create_dataset_import_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dataset_import_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDatasetImportJobRequest,
-    ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dataset_import_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dataset_import_job_request(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_event_tracker`, also returning the metadata of the response.
+    pub async fn create_event_tracker_with_metadata(
+        &self,
+        input: CreateEventTrackerRequest,
+    ) -> Result<(CreateEventTrackerResponse, ResponseMetadata), RusotoError<CreateEventTrackerError>>
+    {
+        let request = self.build_create_event_tracker_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDatasetImportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEventTrackerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatasetImportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEventTrackerError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an event tracker that you use when sending event data to the specified dataset group using the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_UBS_PutEvents.html">PutEvents</a> API.</p> <p>When Amazon Personalize creates an event tracker, it also creates an <i>event-interactions</i> dataset in the dataset group associated with the event tracker. The event-interactions dataset stores the event data from the <code>PutEvents</code> call. The contents of this dataset are not available to the user.</p> <note> <p>Only one event tracker can be associated with a dataset group. You will get an error if you call <code>CreateEventTracker</code> using the same dataset group as an existing event tracker.</p> </note> <p>When you send event data you include your tracking ID. The tracking ID identifies the customer and authorizes the customer to send the data.</p> <p>The event tracker can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN<em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN</em>PROGRESS</p> </li> </ul> <p>To get the status of the event tracker, call <a>DescribeEventTracker</a>.</p> <note> <p>The event tracker must be in the ACTIVE state before using the tracking ID.</p> </note> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListEventTrackers</a> </p> </li> <li> <p> <a>DescribeEventTracker</a> </p> </li> <li> <p> <a>DeleteEventTracker</a> </p> </li> </ul></p>
-    async fn create_event_tracker(
+    /// Presigns the request `create_event_tracker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_event_tracker(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEventTrackerRequest,
-    ) -> Result<CreateEventTrackerResponse, RusotoError<CreateEventTrackerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_event_tracker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_event_tracker_request(
+        &self,
+        input: CreateEventTrackerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateEventTracker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateEventTracker");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_schema`, also returning the metadata of the response.
+    pub async fn create_schema_with_metadata(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<(CreateSchemaResponse, ResponseMetadata), RusotoError<CreateSchemaError>> {
+        let request = self.build_create_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateEventTrackerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSchemaResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEventTrackerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSchemaError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an Amazon Personalize schema from the specified schema string. The schema you create must be in Avro JSON format.</p> <p>Amazon Personalize recognizes three schema variants. Each schema is associated with a dataset type and has a set of required field and keywords. You specify a schema when you call <a>CreateDataset</a>.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListSchemas</a> </p> </li> <li> <p> <a>DescribeSchema</a> </p> </li> <li> <p> <a>DeleteSchema</a> </p> </li> </ul></p>
-    async fn create_schema(
+    /// Presigns the request `create_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSchemaRequest,
-    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_schema_request(&self, input: CreateSchemaRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_solution`, also returning the metadata of the response.
+    pub async fn create_solution_with_metadata(
+        &self,
+        input: CreateSolutionRequest,
+    ) -> Result<(CreateSolutionResponse, ResponseMetadata), RusotoError<CreateSolutionError>> {
+        let request = self.build_create_solution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateSchemaResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSolutionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSchemaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSolutionError::from_response(response))
         }
     }
 
-    /// <p><p>Creates the configuration for training a model. A trained model is known as a solution. After the configuration is created, you train the model (create a solution) by calling the <a>CreateSolutionVersion</a> operation. Every time you call <code>CreateSolutionVersion</code>, a new version of the solution is created.</p> <p>After creating a solution version, you check its accuracy by calling <a>GetSolutionMetrics</a>. When you are satisfied with the version, you deploy it using <a>CreateCampaign</a>. The campaign provides recommendations to a client through the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> API.</p> <p>To train a model, Amazon Personalize requires training data and a recipe. The training data comes from the dataset group that you provide in the request. A recipe specifies the training algorithm and a feature transformation. You can specify one of the predefined recipes provided by Amazon Personalize. Alternatively, you can specify <code>performAutoML</code> and Amazon Personalize will analyze your data and select the optimum USER<em>PERSONALIZATION recipe for you.</p> <p> <b>Status</b> </p> <p>A solution can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN</em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN_PROGRESS</p> </li> </ul> <p>To get the status of the solution, call <a>DescribeSolution</a>. Wait until the status shows as ACTIVE before calling <code>CreateSolutionVersion</code>.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListSolutions</a> </p> </li> <li> <p> <a>CreateSolutionVersion</a> </p> </li> <li> <p> <a>DescribeSolution</a> </p> </li> <li> <p> <a>DeleteSolution</a> </p> </li> </ul> <ul> <li> <p> <a>ListSolutionVersions</a> </p> </li> <li> <p> <a>DescribeSolutionVersion</a> </p> </li> </ul></p>
-    async fn create_solution(
+    /// Presigns the request `create_solution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_solution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSolutionRequest,
-    ) -> Result<CreateSolutionResponse, RusotoError<CreateSolutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_solution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_solution_request(&self, input: CreateSolutionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateSolution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSolution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_solution_version`, also returning the metadata of the response.
+    pub async fn create_solution_version_with_metadata(
+        &self,
+        input: CreateSolutionVersionRequest,
+    ) -> Result<
+        (CreateSolutionVersionResponse, ResponseMetadata),
+        RusotoError<CreateSolutionVersionError>,
+    > {
+        let request = self.build_create_solution_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateSolutionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSolutionVersionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSolutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSolutionVersionError::from_response(response))
         }
     }
 
-    /// <p><p>Trains or retrains an active solution. A solution is created using the <a>CreateSolution</a> operation and must be in the ACTIVE state before calling <code>CreateSolutionVersion</code>. A new version of the solution is created every time you call this operation.</p> <p> <b>Status</b> </p> <p>A solution version can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> </ul> <p>To get the status of the version, call <a>DescribeSolutionVersion</a>. Wait until the status shows as ACTIVE before calling <code>CreateCampaign</code>.</p> <p>If the status shows as CREATE FAILED, the response includes a <code>failureReason</code> key, which describes why the job failed.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListSolutionVersions</a> </p> </li> <li> <p> <a>DescribeSolutionVersion</a> </p> </li> </ul> <ul> <li> <p> <a>ListSolutions</a> </p> </li> <li> <p> <a>CreateSolution</a> </p> </li> <li> <p> <a>DescribeSolution</a> </p> </li> <li> <p> <a>DeleteSolution</a> </p> </li> </ul></p>
-    async fn create_solution_version(
+    /// Presigns the request `create_solution_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_solution_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSolutionVersionRequest,
-    ) -> Result<CreateSolutionVersionResponse, RusotoError<CreateSolutionVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_solution_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_solution_version_request(
+        &self,
+        input: CreateSolutionVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("CreateSolutionVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSolutionVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_campaign`, also returning the metadata of the response.
+    pub async fn delete_campaign_with_metadata(
+        &self,
+        input: DeleteCampaignRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCampaignError>> {
+        let request = self.build_delete_campaign_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSolutionVersionResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSolutionVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCampaignError::from_response(response))
         }
     }
 
-    /// <p>Removes a campaign by deleting the solution deployment. The solution that the campaign is based on is not deleted and can be redeployed when needed. A deleted campaign can no longer be specified in a <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> request. For more information on campaigns, see <a>CreateCampaign</a>.</p>
-    async fn delete_campaign(
+    /// Presigns the request `delete_campaign` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_campaign(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCampaignRequest,
-    ) -> Result<(), RusotoError<DeleteCampaignError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_campaign_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_campaign_request(&self, input: DeleteCampaignRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteCampaign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_dataset`, also returning the metadata of the response.
+    pub async fn delete_dataset_with_metadata(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDatasetError>> {
+        let request = self.build_delete_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCampaignError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDatasetError::from_response(response))
         }
     }
 
-    /// <p>Deletes a dataset. You can't delete a dataset if an associated <code>DatasetImportJob</code> or <code>SolutionVersion</code> is in the CREATE PENDING or IN PROGRESS state. For more information on datasets, see <a>CreateDataset</a>.</p>
-    async fn delete_dataset(
+    /// Presigns the request `delete_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatasetRequest,
-    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dataset_request(&self, input: DeleteDatasetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_dataset_group`, also returning the metadata of the response.
+    pub async fn delete_dataset_group_with_metadata(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDatasetGroupError>> {
+        let request = self.build_delete_dataset_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatasetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDatasetGroupError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a dataset group. Before you delete a dataset group, you must delete the following:</p> <ul> <li> <p>All associated event trackers.</p> </li> <li> <p>All associated solutions.</p> </li> <li> <p>All datasets in the dataset group.</p> </li> </ul></p>
-    async fn delete_dataset_group(
+    /// Presigns the request `delete_dataset_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dataset_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatasetGroupRequest,
-    ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dataset_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dataset_group_request(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `delete_event_tracker`, also returning the metadata of the response.
+    pub async fn delete_event_tracker_with_metadata(
+        &self,
+        input: DeleteEventTrackerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEventTrackerError>> {
+        let request = self.build_delete_event_tracker_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatasetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEventTrackerError::from_response(response))
         }
     }
 
-    /// <p>Deletes the event tracker. Does not delete the event-interactions dataset from the associated dataset group. For more information on event trackers, see <a>CreateEventTracker</a>.</p>
-    async fn delete_event_tracker(
+    /// Presigns the request `delete_event_tracker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_event_tracker(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEventTrackerRequest,
-    ) -> Result<(), RusotoError<DeleteEventTrackerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_event_tracker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_event_tracker_request(
+        &self,
+        input: DeleteEventTrackerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteEventTracker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteEventTracker");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_schema`, also returning the metadata of the response.
+    pub async fn delete_schema_with_metadata(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSchemaError>> {
+        let request = self.build_delete_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEventTrackerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSchemaError::from_response(response))
         }
     }
 
-    /// <p>Deletes a schema. Before deleting a schema, you must delete all datasets referencing the schema. For more information on schemas, see <a>CreateSchema</a>.</p>
-    async fn delete_schema(
+    /// Presigns the request `delete_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSchemaRequest,
-    ) -> Result<(), RusotoError<DeleteSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_schema_request(&self, input: DeleteSchemaRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_solution`, also returning the metadata of the response.
+    pub async fn delete_solution_with_metadata(
+        &self,
+        input: DeleteSolutionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSolutionError>> {
+        let request = self.build_delete_solution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSchemaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSolutionError::from_response(response))
         }
     }
 
-    /// <p>Deletes all versions of a solution and the <code>Solution</code> object itself. Before deleting a solution, you must delete all campaigns based on the solution. To determine what campaigns are using the solution, call <a>ListCampaigns</a> and supply the Amazon Resource Name (ARN) of the solution. You can't delete a solution if an associated <code>SolutionVersion</code> is in the CREATE PENDING or IN PROGRESS state. For more information on solutions, see <a>CreateSolution</a>.</p>
-    async fn delete_solution(
+    /// Presigns the request `delete_solution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_solution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSolutionRequest,
-    ) -> Result<(), RusotoError<DeleteSolutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_solution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_solution_request(&self, input: DeleteSolutionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DeleteSolution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteSolution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_algorithm`, also returning the metadata of the response.
+    pub async fn describe_algorithm_with_metadata(
+        &self,
+        input: DescribeAlgorithmRequest,
+    ) -> Result<(DescribeAlgorithmResponse, ResponseMetadata), RusotoError<DescribeAlgorithmError>>
+    {
+        let request = self.build_describe_algorithm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAlgorithmResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSolutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAlgorithmError::from_response(response))
         }
     }
 
-    /// <p>Describes the given algorithm.</p>
-    async fn describe_algorithm(
+    /// Presigns the request `describe_algorithm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_algorithm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAlgorithmRequest,
-    ) -> Result<DescribeAlgorithmResponse, RusotoError<DescribeAlgorithmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_algorithm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_algorithm_request(&self, input: DescribeAlgorithmRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeAlgorithm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_batch_inference_job`, also returning the metadata of the response.
+    pub async fn describe_batch_inference_job_with_metadata(
+        &self,
+        input: DescribeBatchInferenceJobRequest,
+    ) -> Result<
+        (DescribeBatchInferenceJobResponse, ResponseMetadata),
+        RusotoError<DescribeBatchInferenceJobError>,
+    > {
+        let request = self.build_describe_batch_inference_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAlgorithmResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBatchInferenceJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAlgorithmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBatchInferenceJobError::from_response(response))
         }
     }
 
-    /// <p>Gets the properties of a batch inference job including name, Amazon Resource Name (ARN), status, input and output configurations, and the ARN of the solution version used to generate the recommendations.</p>
-    async fn describe_batch_inference_job(
+    /// Presigns the request `describe_batch_inference_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_batch_inference_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBatchInferenceJobRequest,
-    ) -> Result<DescribeBatchInferenceJobResponse, RusotoError<DescribeBatchInferenceJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_batch_inference_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_batch_inference_job_request(
+        &self,
+        input: DescribeBatchInferenceJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeBatchInferenceJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4320,170 +5099,338 @@ impl Personalize for PersonalizeClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_campaign`, also returning the metadata of the response.
+    pub async fn describe_campaign_with_metadata(
+        &self,
+        input: DescribeCampaignRequest,
+    ) -> Result<(DescribeCampaignResponse, ResponseMetadata), RusotoError<DescribeCampaignError>>
+    {
+        let request = self.build_describe_campaign_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBatchInferenceJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCampaignResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBatchInferenceJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCampaignError::from_response(response))
         }
     }
 
-    /// <p>Describes the given campaign, including its status.</p> <p>A campaign can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN_PROGRESS</p> </li> </ul> <p>When the <code>status</code> is <code>CREATE FAILED</code>, the response includes the <code>failureReason</code> key, which describes why.</p> <p>For more information on campaigns, see <a>CreateCampaign</a>.</p>
-    async fn describe_campaign(
+    /// Presigns the request `describe_campaign` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_campaign(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCampaignRequest,
-    ) -> Result<DescribeCampaignResponse, RusotoError<DescribeCampaignError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_campaign_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_campaign_request(&self, input: DescribeCampaignRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeCampaign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_dataset`, also returning the metadata of the response.
+    pub async fn describe_dataset_with_metadata(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<(DescribeDatasetResponse, ResponseMetadata), RusotoError<DescribeDatasetError>>
+    {
+        let request = self.build_describe_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCampaignResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDatasetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCampaignError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDatasetError::from_response(response))
         }
     }
 
-    /// <p>Describes the given dataset. For more information on datasets, see <a>CreateDataset</a>.</p>
-    async fn describe_dataset(
+    /// Presigns the request `describe_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDatasetRequest,
-    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dataset_request(&self, input: DescribeDatasetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_dataset_group`, also returning the metadata of the response.
+    pub async fn describe_dataset_group_with_metadata(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> Result<
+        (DescribeDatasetGroupResponse, ResponseMetadata),
+        RusotoError<DescribeDatasetGroupError>,
+    > {
+        let request = self.build_describe_dataset_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeDatasetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDatasetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDatasetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDatasetGroupError::from_response(response))
         }
     }
 
-    /// <p>Describes the given dataset group. For more information on dataset groups, see <a>CreateDatasetGroup</a>.</p>
-    async fn describe_dataset_group(
+    /// Presigns the request `describe_dataset_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dataset_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDatasetGroupRequest,
-    ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dataset_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dataset_group_request(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_dataset_import_job`, also returning the metadata of the response.
+    pub async fn describe_dataset_import_job_with_metadata(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> Result<
+        (DescribeDatasetImportJobResponse, ResponseMetadata),
+        RusotoError<DescribeDatasetImportJobError>,
+    > {
+        let request = self.build_describe_dataset_import_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDatasetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDatasetImportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDatasetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDatasetImportJobError::from_response(response))
         }
     }
 
-    /// <p>Describes the dataset import job created by <a>CreateDatasetImportJob</a>, including the import job status.</p>
-    async fn describe_dataset_import_job(
+    /// Presigns the request `describe_dataset_import_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dataset_import_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDatasetImportJobRequest,
-    ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dataset_import_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dataset_import_job_request(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_tracker`, also returning the metadata of the response.
+    pub async fn describe_event_tracker_with_metadata(
+        &self,
+        input: DescribeEventTrackerRequest,
+    ) -> Result<
+        (DescribeEventTrackerResponse, ResponseMetadata),
+        RusotoError<DescribeEventTrackerError>,
+    > {
+        let request = self.build_describe_event_tracker_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDatasetImportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventTrackerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDatasetImportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventTrackerError::from_response(response))
         }
     }
 
-    /// <p>Describes an event tracker. The response includes the <code>trackingId</code> and <code>status</code> of the event tracker. For more information on event trackers, see <a>CreateEventTracker</a>.</p>
-    async fn describe_event_tracker(
+    /// Presigns the request `describe_event_tracker` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_tracker(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventTrackerRequest,
-    ) -> Result<DescribeEventTrackerResponse, RusotoError<DescribeEventTrackerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_tracker_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_tracker_request(
+        &self,
+        input: DescribeEventTrackerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeEventTracker");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeEventTracker");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_feature_transformation`, also returning the metadata of the response.
+    pub async fn describe_feature_transformation_with_metadata(
+        &self,
+        input: DescribeFeatureTransformationRequest,
+    ) -> Result<
+        (DescribeFeatureTransformationResponse, ResponseMetadata),
+        RusotoError<DescribeFeatureTransformationError>,
+    > {
+        let request = self.build_describe_feature_transformation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventTrackerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFeatureTransformationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventTrackerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFeatureTransformationError::from_response(response))
         }
     }
 
-    /// <p>Describes the given feature transformation.</p>
-    async fn describe_feature_transformation(
+    /// Presigns the request `describe_feature_transformation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_feature_transformation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFeatureTransformationRequest,
-    ) -> Result<
-        DescribeFeatureTransformationResponse,
-        RusotoError<DescribeFeatureTransformationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_feature_transformation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_feature_transformation_request(
+        &self,
+        input: DescribeFeatureTransformationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeFeatureTransformation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4493,459 +5440,1638 @@ impl Personalize for PersonalizeClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_recipe`, also returning the metadata of the response.
+    pub async fn describe_recipe_with_metadata(
+        &self,
+        input: DescribeRecipeRequest,
+    ) -> Result<(DescribeRecipeResponse, ResponseMetadata), RusotoError<DescribeRecipeError>> {
+        let request = self.build_describe_recipe_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFeatureTransformationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRecipeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFeatureTransformationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRecipeError::from_response(response))
         }
     }
 
-    /// <p>Describes a recipe.</p> <p>A recipe contains three items:</p> <ul> <li> <p>An algorithm that trains a model.</p> </li> <li> <p>Hyperparameters that govern the training.</p> </li> <li> <p>Feature transformation information for modifying the input data before training.</p> </li> </ul> <p>Amazon Personalize provides a set of predefined recipes. You specify a recipe when you create a solution with the <a>CreateSolution</a> API. <code>CreateSolution</code> trains a model by using the algorithm in the specified recipe and a training dataset. The solution, when deployed as a campaign, can provide recommendations using the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> API.</p>
-    async fn describe_recipe(
+    /// Presigns the request `describe_recipe` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_recipe(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRecipeRequest,
-    ) -> Result<DescribeRecipeResponse, RusotoError<DescribeRecipeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_recipe_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_recipe_request(&self, input: DescribeRecipeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeRecipe");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeRecipe");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_schema`, also returning the metadata of the response.
+    pub async fn describe_schema_with_metadata(
+        &self,
+        input: DescribeSchemaRequest,
+    ) -> Result<(DescribeSchemaResponse, ResponseMetadata), RusotoError<DescribeSchemaError>> {
+        let request = self.build_describe_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeRecipeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSchemaResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRecipeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSchemaError::from_response(response))
         }
     }
 
-    /// <p>Describes a schema. For more information on schemas, see <a>CreateSchema</a>.</p>
-    async fn describe_schema(
+    /// Presigns the request `describe_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSchemaRequest,
-    ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_schema_request(&self, input: DescribeSchemaRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_solution`, also returning the metadata of the response.
+    pub async fn describe_solution_with_metadata(
+        &self,
+        input: DescribeSolutionRequest,
+    ) -> Result<(DescribeSolutionResponse, ResponseMetadata), RusotoError<DescribeSolutionError>>
+    {
+        let request = self.build_describe_solution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeSchemaResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSolutionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSchemaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSolutionError::from_response(response))
         }
     }
 
-    /// <p>Describes a solution. For more information on solutions, see <a>CreateSolution</a>.</p>
-    async fn describe_solution(
+    /// Presigns the request `describe_solution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_solution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSolutionRequest,
-    ) -> Result<DescribeSolutionResponse, RusotoError<DescribeSolutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_solution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_solution_request(&self, input: DescribeSolutionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeSolution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSolution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_solution_version`, also returning the metadata of the response.
+    pub async fn describe_solution_version_with_metadata(
+        &self,
+        input: DescribeSolutionVersionRequest,
+    ) -> Result<
+        (DescribeSolutionVersionResponse, ResponseMetadata),
+        RusotoError<DescribeSolutionVersionError>,
+    > {
+        let request = self.build_describe_solution_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSolutionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSolutionVersionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSolutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSolutionVersionError::from_response(response))
         }
     }
 
-    /// <p>Describes a specific version of a solution. For more information on solutions, see <a>CreateSolution</a>.</p>
-    async fn describe_solution_version(
+    /// Presigns the request `describe_solution_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_solution_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSolutionVersionRequest,
-    ) -> Result<DescribeSolutionVersionResponse, RusotoError<DescribeSolutionVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_solution_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_solution_version_request(
+        &self,
+        input: DescribeSolutionVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("DescribeSolutionVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSolutionVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_solution_metrics`, also returning the metadata of the response.
+    pub async fn get_solution_metrics_with_metadata(
+        &self,
+        input: GetSolutionMetricsRequest,
+    ) -> Result<(GetSolutionMetricsResponse, ResponseMetadata), RusotoError<GetSolutionMetricsError>>
+    {
+        let request = self.build_get_solution_metrics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSolutionVersionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSolutionMetricsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSolutionVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSolutionMetricsError::from_response(response))
         }
     }
 
-    /// <p>Gets the metrics for the specified solution version.</p>
-    async fn get_solution_metrics(
+    /// Presigns the request `get_solution_metrics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_solution_metrics(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSolutionMetricsRequest,
-    ) -> Result<GetSolutionMetricsResponse, RusotoError<GetSolutionMetricsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_solution_metrics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_solution_metrics_request(
+        &self,
+        input: GetSolutionMetricsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("GetSolutionMetrics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.GetSolutionMetrics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_batch_inference_jobs`, also returning the metadata of the response.
+    pub async fn list_batch_inference_jobs_with_metadata(
+        &self,
+        input: ListBatchInferenceJobsRequest,
+    ) -> Result<
+        (ListBatchInferenceJobsResponse, ResponseMetadata),
+        RusotoError<ListBatchInferenceJobsError>,
+    > {
+        let request = self.build_list_batch_inference_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSolutionMetricsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBatchInferenceJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSolutionMetricsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBatchInferenceJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the batch inference jobs that have been performed off of a solution version.</p>
-    async fn list_batch_inference_jobs(
+    /// Presigns the request `list_batch_inference_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_batch_inference_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBatchInferenceJobsRequest,
-    ) -> Result<ListBatchInferenceJobsResponse, RusotoError<ListBatchInferenceJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_batch_inference_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_batch_inference_jobs_request(
+        &self,
+        input: ListBatchInferenceJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListBatchInferenceJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListBatchInferenceJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_campaigns`, also returning the metadata of the response.
+    pub async fn list_campaigns_with_metadata(
+        &self,
+        input: ListCampaignsRequest,
+    ) -> Result<(ListCampaignsResponse, ResponseMetadata), RusotoError<ListCampaignsError>> {
+        let request = self.build_list_campaigns_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBatchInferenceJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCampaignsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBatchInferenceJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCampaignsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of campaigns that use the given solution. When a solution is not specified, all the campaigns associated with the account are listed. The response provides the properties for each campaign, including the Amazon Resource Name (ARN). For more information on campaigns, see <a>CreateCampaign</a>.</p>
-    async fn list_campaigns(
+    /// Presigns the request `list_campaigns` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_campaigns(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCampaignsRequest,
-    ) -> Result<ListCampaignsResponse, RusotoError<ListCampaignsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_campaigns_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_campaigns_request(&self, input: ListCampaignsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListCampaigns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListCampaigns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_dataset_groups`, also returning the metadata of the response.
+    pub async fn list_dataset_groups_with_metadata(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> Result<(ListDatasetGroupsResponse, ResponseMetadata), RusotoError<ListDatasetGroupsError>>
+    {
+        let request = self.build_list_dataset_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListCampaignsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDatasetGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCampaignsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDatasetGroupsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of dataset groups. The response provides the properties for each dataset group, including the Amazon Resource Name (ARN). For more information on dataset groups, see <a>CreateDatasetGroup</a>.</p>
-    async fn list_dataset_groups(
+    /// Presigns the request `list_dataset_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dataset_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatasetGroupsRequest,
-    ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dataset_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dataset_groups_request(&self, input: ListDatasetGroupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListDatasetGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasetGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_dataset_import_jobs`, also returning the metadata of the response.
+    pub async fn list_dataset_import_jobs_with_metadata(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> Result<
+        (ListDatasetImportJobsResponse, ResponseMetadata),
+        RusotoError<ListDatasetImportJobsError>,
+    > {
+        let request = self.build_list_dataset_import_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDatasetGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDatasetImportJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatasetGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDatasetImportJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of dataset import jobs that use the given dataset. When a dataset is not specified, all the dataset import jobs associated with the account are listed. The response provides the properties for each dataset import job, including the Amazon Resource Name (ARN). For more information on dataset import jobs, see <a>CreateDatasetImportJob</a>. For more information on datasets, see <a>CreateDataset</a>.</p>
-    async fn list_dataset_import_jobs(
+    /// Presigns the request `list_dataset_import_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dataset_import_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatasetImportJobsRequest,
-    ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dataset_import_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dataset_import_jobs_request(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListDatasetImportJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasetImportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_datasets`, also returning the metadata of the response.
+    pub async fn list_datasets_with_metadata(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<(ListDatasetsResponse, ResponseMetadata), RusotoError<ListDatasetsError>> {
+        let request = self.build_list_datasets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDatasetImportJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDatasetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatasetImportJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDatasetsError::from_response(response))
         }
     }
 
-    /// <p>Returns the list of datasets contained in the given dataset group. The response provides the properties for each dataset, including the Amazon Resource Name (ARN). For more information on datasets, see <a>CreateDataset</a>.</p>
-    async fn list_datasets(
+    /// Presigns the request `list_datasets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_datasets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatasetsRequest,
-    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_datasets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_datasets_request(&self, input: ListDatasetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListDatasets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_event_trackers`, also returning the metadata of the response.
+    pub async fn list_event_trackers_with_metadata(
+        &self,
+        input: ListEventTrackersRequest,
+    ) -> Result<(ListEventTrackersResponse, ResponseMetadata), RusotoError<ListEventTrackersError>>
+    {
+        let request = self.build_list_event_trackers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDatasetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEventTrackersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatasetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEventTrackersError::from_response(response))
         }
     }
 
-    /// <p>Returns the list of event trackers associated with the account. The response provides the properties for each event tracker, including the Amazon Resource Name (ARN) and tracking ID. For more information on event trackers, see <a>CreateEventTracker</a>.</p>
-    async fn list_event_trackers(
+    /// Presigns the request `list_event_trackers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_event_trackers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEventTrackersRequest,
-    ) -> Result<ListEventTrackersResponse, RusotoError<ListEventTrackersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_event_trackers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_event_trackers_request(&self, input: ListEventTrackersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListEventTrackers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListEventTrackers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_recipes`, also returning the metadata of the response.
+    pub async fn list_recipes_with_metadata(
+        &self,
+        input: ListRecipesRequest,
+    ) -> Result<(ListRecipesResponse, ResponseMetadata), RusotoError<ListRecipesError>> {
+        let request = self.build_list_recipes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEventTrackersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRecipesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEventTrackersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRecipesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of available recipes. The response provides the properties for each recipe, including the recipe's Amazon Resource Name (ARN).</p>
-    async fn list_recipes(
+    /// Presigns the request `list_recipes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_recipes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRecipesRequest,
-    ) -> Result<ListRecipesResponse, RusotoError<ListRecipesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_recipes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_recipes_request(&self, input: ListRecipesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListRecipes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListRecipes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_schemas`, also returning the metadata of the response.
+    pub async fn list_schemas_with_metadata(
+        &self,
+        input: ListSchemasRequest,
+    ) -> Result<(ListSchemasResponse, ResponseMetadata), RusotoError<ListSchemasError>> {
+        let request = self.build_list_schemas_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListRecipesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSchemasResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRecipesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSchemasError::from_response(response))
         }
     }
 
-    /// <p>Returns the list of schemas associated with the account. The response provides the properties for each schema, including the Amazon Resource Name (ARN). For more information on schemas, see <a>CreateSchema</a>.</p>
-    async fn list_schemas(
+    /// Presigns the request `list_schemas` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_schemas(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSchemasRequest,
-    ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_schemas_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_schemas_request(&self, input: ListSchemasRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListSchemas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_solution_versions`, also returning the metadata of the response.
+    pub async fn list_solution_versions_with_metadata(
+        &self,
+        input: ListSolutionVersionsRequest,
+    ) -> Result<
+        (ListSolutionVersionsResponse, ResponseMetadata),
+        RusotoError<ListSolutionVersionsError>,
+    > {
+        let request = self.build_list_solution_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListSchemasResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSolutionVersionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSchemasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSolutionVersionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of solution versions for the given solution. When a solution is not specified, all the solution versions associated with the account are listed. The response provides the properties for each solution version, including the Amazon Resource Name (ARN). For more information on solutions, see <a>CreateSolution</a>.</p>
-    async fn list_solution_versions(
+    /// Presigns the request `list_solution_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_solution_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSolutionVersionsRequest,
-    ) -> Result<ListSolutionVersionsResponse, RusotoError<ListSolutionVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_solution_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_solution_versions_request(
+        &self,
+        input: ListSolutionVersionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListSolutionVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListSolutionVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_solutions`, also returning the metadata of the response.
+    pub async fn list_solutions_with_metadata(
+        &self,
+        input: ListSolutionsRequest,
+    ) -> Result<(ListSolutionsResponse, ResponseMetadata), RusotoError<ListSolutionsError>> {
+        let request = self.build_list_solutions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSolutionVersionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSolutionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSolutionVersionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSolutionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of solutions that use the given dataset group. When a dataset group is not specified, all the solutions associated with the account are listed. The response provides the properties for each solution, including the Amazon Resource Name (ARN). For more information on solutions, see <a>CreateSolution</a>.</p>
-    async fn list_solutions(
+    /// Presigns the request `list_solutions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_solutions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSolutionsRequest,
-    ) -> Result<ListSolutionsResponse, RusotoError<ListSolutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_solutions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_solutions_request(&self, input: ListSolutionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("ListSolutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.ListSolutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_campaign`, also returning the metadata of the response.
+    pub async fn update_campaign_with_metadata(
+        &self,
+        input: UpdateCampaignRequest,
+    ) -> Result<(UpdateCampaignResponse, ResponseMetadata), RusotoError<UpdateCampaignError>> {
+        let request = self.build_update_campaign_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListSolutionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateCampaignResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSolutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateCampaignError::from_response(response))
         }
     }
 
-    /// <p>Updates a campaign by either deploying a new solution or changing the value of the campaign's <code>minProvisionedTPS</code> parameter.</p> <p>To update a campaign, the campaign status must be ACTIVE or CREATE FAILED. Check the campaign status using the <a>DescribeCampaign</a> API.</p> <note> <p>You must wait until the <code>status</code> of the updated campaign is <code>ACTIVE</code> before asking the campaign for recommendations.</p> </note> <p>For more information on campaigns, see <a>CreateCampaign</a>.</p>
-    async fn update_campaign(
+    /// Presigns the request `update_campaign` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_campaign(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCampaignRequest,
-    ) -> Result<UpdateCampaignResponse, RusotoError<UpdateCampaignError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_campaign_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_campaign_request(&self, input: UpdateCampaignRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "personalize", &self.region, "/");
+        request.set_operation("UpdateCampaign");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonPersonalize.UpdateCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListBatchInferenceJobs` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_batch_inference_jobs_pages(
+        &self,
+        input: ListBatchInferenceJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListBatchInferenceJobsResponse, RusotoError<ListBatchInferenceJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move {
+                client.list_batch_inference_jobs(input).await
+            },
+            |input: &ListBatchInferenceJobsRequest, output: &ListBatchInferenceJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBatchInferenceJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `batchInferenceJobs` of every page of `ListBatchInferenceJobs` results for `input`, as `list_batch_inference_jobs_pages` requests them.
+    pub fn list_batch_inference_jobs_stream(
+        &self,
+        input: ListBatchInferenceJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<BatchInferenceJobSummary, RusotoError<ListBatchInferenceJobsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_batch_inference_jobs_pages(input), |page| {
+            page.batch_inference_jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCampaigns` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_campaigns_pages(
+        &self,
+        input: ListCampaignsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCampaignsResponse, RusotoError<ListCampaignsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move { client.list_campaigns(input).await },
+            |input: &ListCampaignsRequest, output: &ListCampaignsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCampaignsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `campaigns` of every page of `ListCampaigns` results for `input`, as `list_campaigns_pages` requests them.
+    pub fn list_campaigns_stream(
+        &self,
+        input: ListCampaignsRequest,
+    ) -> impl futures::Stream<Item = Result<CampaignSummary, RusotoError<ListCampaignsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_campaigns_pages(input), |page| {
+            page.campaigns.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDatasetGroups` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_dataset_groups_pages(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move { client.list_dataset_groups(input).await },
+            |input: &ListDatasetGroupsRequest, output: &ListDatasetGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDatasetGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `datasetGroups` of every page of `ListDatasetGroups` results for `input`, as `list_dataset_groups_pages` requests them.
+    pub fn list_dataset_groups_stream(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<DatasetGroupSummary, RusotoError<ListDatasetGroupsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_dataset_groups_pages(input), |page| {
+            page.dataset_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDatasetImportJobs` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_dataset_import_jobs_pages(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move {
+                client.list_dataset_import_jobs(input).await
+            },
+            |input: &ListDatasetImportJobsRequest, output: &ListDatasetImportJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDatasetImportJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `datasetImportJobs` of every page of `ListDatasetImportJobs` results for `input`, as `list_dataset_import_jobs_pages` requests them.
+    pub fn list_dataset_import_jobs_stream(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DatasetImportJobSummary, RusotoError<ListDatasetImportJobsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_dataset_import_jobs_pages(input), |page| {
+            page.dataset_import_jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDatasets` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_datasets_pages(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDatasetsResponse, RusotoError<ListDatasetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move { client.list_datasets(input).await },
+            |input: &ListDatasetsRequest, output: &ListDatasetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDatasetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `datasets` of every page of `ListDatasets` results for `input`, as `list_datasets_pages` requests them.
+    pub fn list_datasets_stream(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<DatasetSummary, RusotoError<ListDatasetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_datasets_pages(input), |page| {
+            page.datasets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListEventTrackers` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_event_trackers_pages(
+        &self,
+        input: ListEventTrackersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListEventTrackersResponse, RusotoError<ListEventTrackersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move { client.list_event_trackers(input).await },
+            |input: &ListEventTrackersRequest, output: &ListEventTrackersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEventTrackersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `eventTrackers` of every page of `ListEventTrackers` results for `input`, as `list_event_trackers_pages` requests them.
+    pub fn list_event_trackers_stream(
+        &self,
+        input: ListEventTrackersRequest,
+    ) -> impl futures::Stream<Item = Result<EventTrackerSummary, RusotoError<ListEventTrackersError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_event_trackers_pages(input), |page| {
+            page.event_trackers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRecipes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_recipes_pages(
+        &self,
+        input: ListRecipesRequest,
+    ) -> impl futures::Stream<Item = Result<ListRecipesResponse, RusotoError<ListRecipesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move { client.list_recipes(input).await },
+            |input: &ListRecipesRequest, output: &ListRecipesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRecipesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `recipes` of every page of `ListRecipes` results for `input`, as `list_recipes_pages` requests them.
+    pub fn list_recipes_stream(
+        &self,
+        input: ListRecipesRequest,
+    ) -> impl futures::Stream<Item = Result<RecipeSummary, RusotoError<ListRecipesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_recipes_pages(input), |page| {
+            page.recipes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSchemas` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_schemas_pages(
+        &self,
+        input: ListSchemasRequest,
+    ) -> impl futures::Stream<Item = Result<ListSchemasResponse, RusotoError<ListSchemasError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move { client.list_schemas(input).await },
+            |input: &ListSchemasRequest, output: &ListSchemasResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSchemasRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `schemas` of every page of `ListSchemas` results for `input`, as `list_schemas_pages` requests them.
+    pub fn list_schemas_stream(
+        &self,
+        input: ListSchemasRequest,
+    ) -> impl futures::Stream<Item = Result<DatasetSchemaSummary, RusotoError<ListSchemasError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_schemas_pages(input), |page| {
+            page.schemas.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSolutionVersions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_solution_versions_pages(
+        &self,
+        input: ListSolutionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListSolutionVersionsResponse, RusotoError<ListSolutionVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move { client.list_solution_versions(input).await },
+            |input: &ListSolutionVersionsRequest, output: &ListSolutionVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSolutionVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `solutionVersions` of every page of `ListSolutionVersions` results for `input`, as `list_solution_versions_pages` requests them.
+    pub fn list_solution_versions_stream(
+        &self,
+        input: ListSolutionVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SolutionVersionSummary, RusotoError<ListSolutionVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_solution_versions_pages(input), |page| {
+            page.solution_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSolutions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_solutions_pages(
+        &self,
+        input: ListSolutionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListSolutionsResponse, RusotoError<ListSolutionsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PersonalizeClient, input| async move { client.list_solutions(input).await },
+            |input: &ListSolutionsRequest, output: &ListSolutionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSolutionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `solutions` of every page of `ListSolutions` results for `input`, as `list_solutions_pages` requests them.
+    pub fn list_solutions_stream(
+        &self,
+        input: ListSolutionsRequest,
+    ) -> impl futures::Stream<Item = Result<SolutionSummary, RusotoError<ListSolutionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_solutions_pages(input), |page| {
+            page.solutions.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Personalize for PersonalizeClient {
+    /// <p>Creates a batch inference job. The operation can handle up to 50 million records and the input file must be in JSON format. For more information, see <a>recommendations-batch</a>.</p>
+    async fn create_batch_inference_job(
+        &self,
+        input: CreateBatchInferenceJobRequest,
+    ) -> Result<CreateBatchInferenceJobResponse, RusotoError<CreateBatchInferenceJobError>> {
+        self.create_batch_inference_job_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateCampaignResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCampaignError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a campaign by deploying a solution version. When a client calls the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> and <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetPersonalizedRanking.html">GetPersonalizedRanking</a> APIs, a campaign is specified in the request.</p> <p> <b>Minimum Provisioned TPS and Auto-Scaling</b> </p> <p>A transaction is a single <code>GetRecommendations</code> or <code>GetPersonalizedRanking</code> call. Transactions per second (TPS) is the throughput and unit of billing for Amazon Personalize. The minimum provisioned TPS (<code>minProvisionedTPS</code>) specifies the baseline throughput provisioned by Amazon Personalize, and thus, the minimum billing charge. If your TPS increases beyond <code>minProvisionedTPS</code>, Amazon Personalize auto-scales the provisioned capacity up and down, but never below <code>minProvisionedTPS</code>, to maintain a 70% utilization. There&#39;s a short time delay while the capacity is increased that might cause loss of transactions. It&#39;s recommended to start with a low <code>minProvisionedTPS</code>, track your usage using Amazon CloudWatch metrics, and then increase the <code>minProvisionedTPS</code> as necessary.</p> <p> <b>Status</b> </p> <p>A campaign can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN<em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN</em>PROGRESS</p> </li> </ul> <p>To get the campaign status, call <a>DescribeCampaign</a>.</p> <note> <p>Wait until the <code>status</code> of the campaign is <code>ACTIVE</code> before asking the campaign for recommendations.</p> </note> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListCampaigns</a> </p> </li> <li> <p> <a>DescribeCampaign</a> </p> </li> <li> <p> <a>UpdateCampaign</a> </p> </li> <li> <p> <a>DeleteCampaign</a> </p> </li> </ul></p>
+    async fn create_campaign(
+        &self,
+        input: CreateCampaignRequest,
+    ) -> Result<CreateCampaignResponse, RusotoError<CreateCampaignError>> {
+        self.create_campaign_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an empty dataset and adds it to the specified dataset group. Use <a>CreateDatasetImportJob</a> to import your training data to a dataset.</p> <p>There are three types of datasets:</p> <ul> <li> <p>Interactions</p> </li> <li> <p>Items</p> </li> <li> <p>Users</p> </li> </ul> <p>Each dataset type has an associated schema with required field types. Only the <code>Interactions</code> dataset is required in order to train a model (also referred to as creating a solution).</p> <p>A dataset can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN<em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN</em>PROGRESS</p> </li> </ul> <p>To get the status of the dataset, call <a>DescribeDataset</a>.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>CreateDatasetGroup</a> </p> </li> <li> <p> <a>ListDatasets</a> </p> </li> <li> <p> <a>DescribeDataset</a> </p> </li> <li> <p> <a>DeleteDataset</a> </p> </li> </ul></p>
+    async fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+        self.create_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an empty dataset group. A dataset group contains related datasets that supply data for training a model. A dataset group can contain at most three datasets, one for each type of dataset:</p> <ul> <li> <p>Interactions</p> </li> <li> <p>Items</p> </li> <li> <p>Users</p> </li> </ul> <p>To train a model (create a solution), a dataset group that contains an <code>Interactions</code> dataset is required. Call <a>CreateDataset</a> to add a dataset to the group.</p> <p>A dataset group can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING</p> </li> </ul> <p>To get the status of the dataset group, call <a>DescribeDatasetGroup</a>. If the status shows as CREATE FAILED, the response includes a <code>failureReason</code> key, which describes why the creation failed.</p> <note> <p>You must wait until the <code>status</code> of the dataset group is <code>ACTIVE</code> before adding a dataset to the group.</p> </note> <p>You can specify an AWS Key Management Service (KMS) key to encrypt the datasets in the group. If you specify a KMS key, you must also include an AWS Identity and Access Management (IAM) role that has permission to access the key.</p> <p class="title"> <b>APIs that require a dataset group ARN in the request</b> </p> <ul> <li> <p> <a>CreateDataset</a> </p> </li> <li> <p> <a>CreateEventTracker</a> </p> </li> <li> <p> <a>CreateSolution</a> </p> </li> </ul> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListDatasetGroups</a> </p> </li> <li> <p> <a>DescribeDatasetGroup</a> </p> </li> <li> <p> <a>DeleteDatasetGroup</a> </p> </li> </ul></p>
+    async fn create_dataset_group(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
+        self.create_dataset_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a job that imports training data from your data source (an Amazon S3 bucket) to an Amazon Personalize dataset. To allow Amazon Personalize to import the training data, you must specify an AWS Identity and Access Management (IAM) role that has permission to read from the data source.</p> <important> <p>The dataset import job replaces any previous data in the dataset.</p> </important> <p> <b>Status</b> </p> <p>A dataset import job can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> </ul> <p>To get the status of the import job, call <a>DescribeDatasetImportJob</a>, providing the Amazon Resource Name (ARN) of the dataset import job. The dataset import is complete when the status shows as ACTIVE. If the status shows as CREATE FAILED, the response includes a <code>failureReason</code> key, which describes why the job failed.</p> <note> <p>Importing takes time. You must wait until the status shows as ACTIVE before training a model using the dataset.</p> </note> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListDatasetImportJobs</a> </p> </li> <li> <p> <a>DescribeDatasetImportJob</a> </p> </li> </ul></p>
+    async fn create_dataset_import_job(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
+        self.create_dataset_import_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an event tracker that you use when sending event data to the specified dataset group using the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_UBS_PutEvents.html">PutEvents</a> API.</p> <p>When Amazon Personalize creates an event tracker, it also creates an <i>event-interactions</i> dataset in the dataset group associated with the event tracker. The event-interactions dataset stores the event data from the <code>PutEvents</code> call. The contents of this dataset are not available to the user.</p> <note> <p>Only one event tracker can be associated with a dataset group. You will get an error if you call <code>CreateEventTracker</code> using the same dataset group as an existing event tracker.</p> </note> <p>When you send event data you include your tracking ID. The tracking ID identifies the customer and authorizes the customer to send the data.</p> <p>The event tracker can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN<em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN</em>PROGRESS</p> </li> </ul> <p>To get the status of the event tracker, call <a>DescribeEventTracker</a>.</p> <note> <p>The event tracker must be in the ACTIVE state before using the tracking ID.</p> </note> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListEventTrackers</a> </p> </li> <li> <p> <a>DescribeEventTracker</a> </p> </li> <li> <p> <a>DeleteEventTracker</a> </p> </li> </ul></p>
+    async fn create_event_tracker(
+        &self,
+        input: CreateEventTrackerRequest,
+    ) -> Result<CreateEventTrackerResponse, RusotoError<CreateEventTrackerError>> {
+        self.create_event_tracker_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an Amazon Personalize schema from the specified schema string. The schema you create must be in Avro JSON format.</p> <p>Amazon Personalize recognizes three schema variants. Each schema is associated with a dataset type and has a set of required field and keywords. You specify a schema when you call <a>CreateDataset</a>.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListSchemas</a> </p> </li> <li> <p> <a>DescribeSchema</a> </p> </li> <li> <p> <a>DeleteSchema</a> </p> </li> </ul></p>
+    async fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+        self.create_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates the configuration for training a model. A trained model is known as a solution. After the configuration is created, you train the model (create a solution) by calling the <a>CreateSolutionVersion</a> operation. Every time you call <code>CreateSolutionVersion</code>, a new version of the solution is created.</p> <p>After creating a solution version, you check its accuracy by calling <a>GetSolutionMetrics</a>. When you are satisfied with the version, you deploy it using <a>CreateCampaign</a>. The campaign provides recommendations to a client through the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> API.</p> <p>To train a model, Amazon Personalize requires training data and a recipe. The training data comes from the dataset group that you provide in the request. A recipe specifies the training algorithm and a feature transformation. You can specify one of the predefined recipes provided by Amazon Personalize. Alternatively, you can specify <code>performAutoML</code> and Amazon Personalize will analyze your data and select the optimum USER<em>PERSONALIZATION recipe for you.</p> <p> <b>Status</b> </p> <p>A solution can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN</em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN_PROGRESS</p> </li> </ul> <p>To get the status of the solution, call <a>DescribeSolution</a>. Wait until the status shows as ACTIVE before calling <code>CreateSolutionVersion</code>.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListSolutions</a> </p> </li> <li> <p> <a>CreateSolutionVersion</a> </p> </li> <li> <p> <a>DescribeSolution</a> </p> </li> <li> <p> <a>DeleteSolution</a> </p> </li> </ul> <ul> <li> <p> <a>ListSolutionVersions</a> </p> </li> <li> <p> <a>DescribeSolutionVersion</a> </p> </li> </ul></p>
+    async fn create_solution(
+        &self,
+        input: CreateSolutionRequest,
+    ) -> Result<CreateSolutionResponse, RusotoError<CreateSolutionError>> {
+        self.create_solution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Trains or retrains an active solution. A solution is created using the <a>CreateSolution</a> operation and must be in the ACTIVE state before calling <code>CreateSolutionVersion</code>. A new version of the solution is created every time you call this operation.</p> <p> <b>Status</b> </p> <p>A solution version can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> </ul> <p>To get the status of the version, call <a>DescribeSolutionVersion</a>. Wait until the status shows as ACTIVE before calling <code>CreateCampaign</code>.</p> <p>If the status shows as CREATE FAILED, the response includes a <code>failureReason</code> key, which describes why the job failed.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListSolutionVersions</a> </p> </li> <li> <p> <a>DescribeSolutionVersion</a> </p> </li> </ul> <ul> <li> <p> <a>ListSolutions</a> </p> </li> <li> <p> <a>CreateSolution</a> </p> </li> <li> <p> <a>DescribeSolution</a> </p> </li> <li> <p> <a>DeleteSolution</a> </p> </li> </ul></p>
+    async fn create_solution_version(
+        &self,
+        input: CreateSolutionVersionRequest,
+    ) -> Result<CreateSolutionVersionResponse, RusotoError<CreateSolutionVersionError>> {
+        self.create_solution_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a campaign by deleting the solution deployment. The solution that the campaign is based on is not deleted and can be redeployed when needed. A deleted campaign can no longer be specified in a <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> request. For more information on campaigns, see <a>CreateCampaign</a>.</p>
+    async fn delete_campaign(
+        &self,
+        input: DeleteCampaignRequest,
+    ) -> Result<(), RusotoError<DeleteCampaignError>> {
+        self.delete_campaign_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a dataset. You can't delete a dataset if an associated <code>DatasetImportJob</code> or <code>SolutionVersion</code> is in the CREATE PENDING or IN PROGRESS state. For more information on datasets, see <a>CreateDataset</a>.</p>
+    async fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+        self.delete_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a dataset group. Before you delete a dataset group, you must delete the following:</p> <ul> <li> <p>All associated event trackers.</p> </li> <li> <p>All associated solutions.</p> </li> <li> <p>All datasets in the dataset group.</p> </li> </ul></p>
+    async fn delete_dataset_group(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
+        self.delete_dataset_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the event tracker. Does not delete the event-interactions dataset from the associated dataset group. For more information on event trackers, see <a>CreateEventTracker</a>.</p>
+    async fn delete_event_tracker(
+        &self,
+        input: DeleteEventTrackerRequest,
+    ) -> Result<(), RusotoError<DeleteEventTrackerError>> {
+        self.delete_event_tracker_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a schema. Before deleting a schema, you must delete all datasets referencing the schema. For more information on schemas, see <a>CreateSchema</a>.</p>
+    async fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<(), RusotoError<DeleteSchemaError>> {
+        self.delete_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes all versions of a solution and the <code>Solution</code> object itself. Before deleting a solution, you must delete all campaigns based on the solution. To determine what campaigns are using the solution, call <a>ListCampaigns</a> and supply the Amazon Resource Name (ARN) of the solution. You can't delete a solution if an associated <code>SolutionVersion</code> is in the CREATE PENDING or IN PROGRESS state. For more information on solutions, see <a>CreateSolution</a>.</p>
+    async fn delete_solution(
+        &self,
+        input: DeleteSolutionRequest,
+    ) -> Result<(), RusotoError<DeleteSolutionError>> {
+        self.delete_solution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the given algorithm.</p>
+    async fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmRequest,
+    ) -> Result<DescribeAlgorithmResponse, RusotoError<DescribeAlgorithmError>> {
+        self.describe_algorithm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties of a batch inference job including name, Amazon Resource Name (ARN), status, input and output configurations, and the ARN of the solution version used to generate the recommendations.</p>
+    async fn describe_batch_inference_job(
+        &self,
+        input: DescribeBatchInferenceJobRequest,
+    ) -> Result<DescribeBatchInferenceJobResponse, RusotoError<DescribeBatchInferenceJobError>>
+    {
+        self.describe_batch_inference_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the given campaign, including its status.</p> <p>A campaign can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN_PROGRESS</p> </li> </ul> <p>When the <code>status</code> is <code>CREATE FAILED</code>, the response includes the <code>failureReason</code> key, which describes why.</p> <p>For more information on campaigns, see <a>CreateCampaign</a>.</p>
+    async fn describe_campaign(
+        &self,
+        input: DescribeCampaignRequest,
+    ) -> Result<DescribeCampaignResponse, RusotoError<DescribeCampaignError>> {
+        self.describe_campaign_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the given dataset. For more information on datasets, see <a>CreateDataset</a>.</p>
+    async fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.describe_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the given dataset group. For more information on dataset groups, see <a>CreateDatasetGroup</a>.</p>
+    async fn describe_dataset_group(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
+        self.describe_dataset_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the dataset import job created by <a>CreateDatasetImportJob</a>, including the import job status.</p>
+    async fn describe_dataset_import_job(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
+        self.describe_dataset_import_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an event tracker. The response includes the <code>trackingId</code> and <code>status</code> of the event tracker. For more information on event trackers, see <a>CreateEventTracker</a>.</p>
+    async fn describe_event_tracker(
+        &self,
+        input: DescribeEventTrackerRequest,
+    ) -> Result<DescribeEventTrackerResponse, RusotoError<DescribeEventTrackerError>> {
+        self.describe_event_tracker_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the given feature transformation.</p>
+    async fn describe_feature_transformation(
+        &self,
+        input: DescribeFeatureTransformationRequest,
+    ) -> Result<
+        DescribeFeatureTransformationResponse,
+        RusotoError<DescribeFeatureTransformationError>,
+    > {
+        self.describe_feature_transformation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a recipe.</p> <p>A recipe contains three items:</p> <ul> <li> <p>An algorithm that trains a model.</p> </li> <li> <p>Hyperparameters that govern the training.</p> </li> <li> <p>Feature transformation information for modifying the input data before training.</p> </li> </ul> <p>Amazon Personalize provides a set of predefined recipes. You specify a recipe when you create a solution with the <a>CreateSolution</a> API. <code>CreateSolution</code> trains a model by using the algorithm in the specified recipe and a training dataset. The solution, when deployed as a campaign, can provide recommendations using the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> API.</p>
+    async fn describe_recipe(
+        &self,
+        input: DescribeRecipeRequest,
+    ) -> Result<DescribeRecipeResponse, RusotoError<DescribeRecipeError>> {
+        self.describe_recipe_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a schema. For more information on schemas, see <a>CreateSchema</a>.</p>
+    async fn describe_schema(
+        &self,
+        input: DescribeSchemaRequest,
+    ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
+        self.describe_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a solution. For more information on solutions, see <a>CreateSolution</a>.</p>
+    async fn describe_solution(
+        &self,
+        input: DescribeSolutionRequest,
+    ) -> Result<DescribeSolutionResponse, RusotoError<DescribeSolutionError>> {
+        self.describe_solution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a specific version of a solution. For more information on solutions, see <a>CreateSolution</a>.</p>
+    async fn describe_solution_version(
+        &self,
+        input: DescribeSolutionVersionRequest,
+    ) -> Result<DescribeSolutionVersionResponse, RusotoError<DescribeSolutionVersionError>> {
+        self.describe_solution_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the metrics for the specified solution version.</p>
+    async fn get_solution_metrics(
+        &self,
+        input: GetSolutionMetricsRequest,
+    ) -> Result<GetSolutionMetricsResponse, RusotoError<GetSolutionMetricsError>> {
+        self.get_solution_metrics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the batch inference jobs that have been performed off of a solution version.</p>
+    async fn list_batch_inference_jobs(
+        &self,
+        input: ListBatchInferenceJobsRequest,
+    ) -> Result<ListBatchInferenceJobsResponse, RusotoError<ListBatchInferenceJobsError>> {
+        self.list_batch_inference_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of campaigns that use the given solution. When a solution is not specified, all the campaigns associated with the account are listed. The response provides the properties for each campaign, including the Amazon Resource Name (ARN). For more information on campaigns, see <a>CreateCampaign</a>.</p>
+    async fn list_campaigns(
+        &self,
+        input: ListCampaignsRequest,
+    ) -> Result<ListCampaignsResponse, RusotoError<ListCampaignsError>> {
+        self.list_campaigns_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of dataset groups. The response provides the properties for each dataset group, including the Amazon Resource Name (ARN). For more information on dataset groups, see <a>CreateDatasetGroup</a>.</p>
+    async fn list_dataset_groups(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
+        self.list_dataset_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of dataset import jobs that use the given dataset. When a dataset is not specified, all the dataset import jobs associated with the account are listed. The response provides the properties for each dataset import job, including the Amazon Resource Name (ARN). For more information on dataset import jobs, see <a>CreateDatasetImportJob</a>. For more information on datasets, see <a>CreateDataset</a>.</p>
+    async fn list_dataset_import_jobs(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
+        self.list_dataset_import_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of datasets contained in the given dataset group. The response provides the properties for each dataset, including the Amazon Resource Name (ARN). For more information on datasets, see <a>CreateDataset</a>.</p>
+    async fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.list_datasets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of event trackers associated with the account. The response provides the properties for each event tracker, including the Amazon Resource Name (ARN) and tracking ID. For more information on event trackers, see <a>CreateEventTracker</a>.</p>
+    async fn list_event_trackers(
+        &self,
+        input: ListEventTrackersRequest,
+    ) -> Result<ListEventTrackersResponse, RusotoError<ListEventTrackersError>> {
+        self.list_event_trackers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of available recipes. The response provides the properties for each recipe, including the recipe's Amazon Resource Name (ARN).</p>
+    async fn list_recipes(
+        &self,
+        input: ListRecipesRequest,
+    ) -> Result<ListRecipesResponse, RusotoError<ListRecipesError>> {
+        self.list_recipes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of schemas associated with the account. The response provides the properties for each schema, including the Amazon Resource Name (ARN). For more information on schemas, see <a>CreateSchema</a>.</p>
+    async fn list_schemas(
+        &self,
+        input: ListSchemasRequest,
+    ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
+        self.list_schemas_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of solution versions for the given solution. When a solution is not specified, all the solution versions associated with the account are listed. The response provides the properties for each solution version, including the Amazon Resource Name (ARN). For more information on solutions, see <a>CreateSolution</a>.</p>
+    async fn list_solution_versions(
+        &self,
+        input: ListSolutionVersionsRequest,
+    ) -> Result<ListSolutionVersionsResponse, RusotoError<ListSolutionVersionsError>> {
+        self.list_solution_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of solutions that use the given dataset group. When a dataset group is not specified, all the solutions associated with the account are listed. The response provides the properties for each solution, including the Amazon Resource Name (ARN). For more information on solutions, see <a>CreateSolution</a>.</p>
+    async fn list_solutions(
+        &self,
+        input: ListSolutionsRequest,
+    ) -> Result<ListSolutionsResponse, RusotoError<ListSolutionsError>> {
+        self.list_solutions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a campaign by either deploying a new solution or changing the value of the campaign's <code>minProvisionedTPS</code> parameter.</p> <p>To update a campaign, the campaign status must be ACTIVE or CREATE FAILED. Check the campaign status using the <a>DescribeCampaign</a> API.</p> <note> <p>You must wait until the <code>status</code> of the updated campaign is <code>ACTIVE</code> before asking the campaign for recommendations.</p> </note> <p>For more information on campaigns, see <a>CreateCampaign</a>.</p>
+    async fn update_campaign(
+        &self,
+        input: UpdateCampaignRequest,
+    ) -> Result<UpdateCampaignResponse, RusotoError<UpdateCampaignError>> {
+        self.update_campaign_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -251,6 +251,7 @@ pub struct ResponseResourceMetricKey {
 
 /// Errors returned by DescribeDimensionKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDimensionKeysError {
     /// <p>The request failed due to an unknown error.</p>
     InternalServiceError(String),
@@ -258,6 +259,8 @@ pub enum DescribeDimensionKeysError {
     InvalidArgument(String),
     /// <p>The user is not authorized to perform this request.</p>
     NotAuthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDimensionKeysError {
@@ -265,20 +268,25 @@ impl DescribeDimensionKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeDimensionKeysError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDimensionKeysError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeDimensionKeysError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDimensionKeysError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeDimensionKeysError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeDimensionKeysError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDimensionKeysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -291,12 +299,14 @@ impl fmt::Display for DescribeDimensionKeysError {
             DescribeDimensionKeysError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DescribeDimensionKeysError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             DescribeDimensionKeysError::NotAuthorized(ref cause) => write!(f, "{}", cause),
+            DescribeDimensionKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDimensionKeysError {}
 /// Errors returned by GetResourceMetrics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourceMetricsError {
     /// <p>The request failed due to an unknown error.</p>
     InternalServiceError(String),
@@ -304,6 +314,8 @@ pub enum GetResourceMetricsError {
     InvalidArgument(String),
     /// <p>The user is not authorized to perform this request.</p>
     NotAuthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourceMetricsError {
@@ -311,18 +323,25 @@ impl GetResourceMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetResourceMetricsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceMetricsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetResourceMetricsError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        GetResourceMetricsError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetResourceMetricsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        GetResourceMetricsError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourceMetricsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -335,6 +354,7 @@ impl fmt::Display for GetResourceMetricsError {
             GetResourceMetricsError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             GetResourceMetricsError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             GetResourceMetricsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
+            GetResourceMetricsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -365,6 +385,7 @@ impl PerformanceInsightsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> PerformanceInsightsClient {
         PerformanceInsightsClient {
             client: Client::shared(),
@@ -390,16 +411,73 @@ impl PerformanceInsightsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> PerformanceInsightsClient {
         PerformanceInsightsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<PerformanceInsightsClient> {
+        rusoto_core::ClientBuilder::new(PerformanceInsightsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> PerformanceInsightsClient {
+        PerformanceInsightsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl PerformanceInsights for PerformanceInsightsClient {
-    /// <p>For a specific time period, retrieve the top <code>N</code> dimension keys for a metric.</p>
-    async fn describe_dimension_keys(
+impl PerformanceInsightsClient {
+    /// Calls `describe_dimension_keys`, also returning the metadata of the response.
+    pub async fn describe_dimension_keys_with_metadata(
         &self,
         input: DescribeDimensionKeysRequest,
-    ) -> Result<DescribeDimensionKeysResponse, RusotoError<DescribeDimensionKeysError>> {
+    ) -> Result<
+        (DescribeDimensionKeysResponse, ResponseMetadata),
+        RusotoError<DescribeDimensionKeysError>,
+    > {
+        let request = self.build_describe_dimension_keys_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDimensionKeysResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDimensionKeysError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `describe_dimension_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dimension_keys(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDimensionKeysRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dimension_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dimension_keys_request(
+        &self,
+        input: DescribeDimensionKeysRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "pi", &self.region, "/");
+        request.set_operation("DescribeDimensionKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -409,28 +487,56 @@ impl PerformanceInsights for PerformanceInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resource_metrics`, also returning the metadata of the response.
+    pub async fn get_resource_metrics_with_metadata(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> Result<(GetResourceMetricsResponse, ResponseMetadata), RusotoError<GetResourceMetricsError>>
+    {
+        let request = self.build_get_resource_metrics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDimensionKeysResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResourceMetricsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDimensionKeysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetResourceMetricsError::from_response(response))
         }
     }
 
-    /// <p>Retrieve Performance Insights metrics for a set of data sources, over a time period. You can provide specific dimension groups and dimensions, and provide aggregation and filtering criteria for each group.</p>
-    async fn get_resource_metrics(
+    /// Presigns the request `get_resource_metrics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_metrics(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourceMetricsRequest,
-    ) -> Result<GetResourceMetricsResponse, RusotoError<GetResourceMetricsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_metrics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_metrics_request(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "pi", &self.region, "/");
+        request.set_operation("GetResourceMetrics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -440,19 +546,29 @@ impl PerformanceInsights for PerformanceInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl PerformanceInsights for PerformanceInsightsClient {
+    /// <p>For a specific time period, retrieve the top <code>N</code> dimension keys for a metric.</p>
+    async fn describe_dimension_keys(
+        &self,
+        input: DescribeDimensionKeysRequest,
+    ) -> Result<DescribeDimensionKeysResponse, RusotoError<DescribeDimensionKeysError>> {
+        self.describe_dimension_keys_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourceMetricsResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourceMetricsError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve Performance Insights metrics for a set of data sources, over a time period. You can provide specific dimension groups and dimensions, and provide aggregation and filtering criteria for each group.</p>
+    async fn get_resource_metrics(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> Result<GetResourceMetricsResponse, RusotoError<GetResourceMetricsError>> {
+        self.get_resource_metrics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -442,11 +442,14 @@ pub struct Voice {
 
 /// Errors returned by DeleteLexicon
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLexiconError {
     /// <p>Amazon Polly can't find the specified lexicon. This could be caused by a lexicon that is missing, its name is misspelled or specifying a lexicon that is in a different region.</p> <p>Verify that the lexicon exists, is in the region (see <a>ListLexicons</a>) and that you spelled its name is spelled correctly. Then try again.</p>
     LexiconNotFound(String),
     /// <p>An unknown condition has caused a service failure.</p>
     ServiceFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLexiconError {
@@ -454,13 +457,13 @@ impl DeleteLexiconError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(DeleteLexiconError::LexiconNotFound(err.msg))
+                    return RusotoError::service(DeleteLexiconError::LexiconNotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteLexiconError::ServiceFailure(err.msg))
+                    return RusotoError::service(DeleteLexiconError::ServiceFailure(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLexiconError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -472,17 +475,21 @@ impl fmt::Display for DeleteLexiconError {
         match *self {
             DeleteLexiconError::LexiconNotFound(ref cause) => write!(f, "{}", cause),
             DeleteLexiconError::ServiceFailure(ref cause) => write!(f, "{}", cause),
+            DeleteLexiconError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLexiconError {}
 /// Errors returned by DescribeVoices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVoicesError {
     /// <p>The NextToken is invalid. Verify that it's spelled correctly, and then try again.</p>
     InvalidNextToken(String),
     /// <p>An unknown condition has caused a service failure.</p>
     ServiceFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVoicesError {
@@ -490,13 +497,16 @@ impl DescribeVoicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeVoicesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeVoicesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeVoicesError::ServiceFailure(err.msg))
+                    return RusotoError::service(DescribeVoicesError::ServiceFailure(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeVoicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -508,17 +518,21 @@ impl fmt::Display for DescribeVoicesError {
         match *self {
             DescribeVoicesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeVoicesError::ServiceFailure(ref cause) => write!(f, "{}", cause),
+            DescribeVoicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeVoicesError {}
 /// Errors returned by GetLexicon
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLexiconError {
     /// <p>Amazon Polly can't find the specified lexicon. This could be caused by a lexicon that is missing, its name is misspelled or specifying a lexicon that is in a different region.</p> <p>Verify that the lexicon exists, is in the region (see <a>ListLexicons</a>) and that you spelled its name is spelled correctly. Then try again.</p>
     LexiconNotFound(String),
     /// <p>An unknown condition has caused a service failure.</p>
     ServiceFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLexiconError {
@@ -526,13 +540,13 @@ impl GetLexiconError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(GetLexiconError::LexiconNotFound(err.msg))
+                    return RusotoError::service(GetLexiconError::LexiconNotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetLexiconError::ServiceFailure(err.msg))
+                    return RusotoError::service(GetLexiconError::ServiceFailure(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLexiconError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -544,12 +558,14 @@ impl fmt::Display for GetLexiconError {
         match *self {
             GetLexiconError::LexiconNotFound(ref cause) => write!(f, "{}", cause),
             GetLexiconError::ServiceFailure(ref cause) => write!(f, "{}", cause),
+            GetLexiconError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLexiconError {}
 /// Errors returned by GetSpeechSynthesisTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSpeechSynthesisTaskError {
     /// <p>The provided Task ID is not valid. Please provide a valid Task ID and try again.</p>
     InvalidTaskId(String),
@@ -557,6 +573,8 @@ pub enum GetSpeechSynthesisTaskError {
     ServiceFailure(String),
     /// <p>The Speech Synthesis task with requested Task ID cannot be found.</p>
     SynthesisTaskNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSpeechSynthesisTaskError {
@@ -564,22 +582,25 @@ impl GetSpeechSynthesisTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidTaskIdException" => {
-                    return RusotoError::Service(GetSpeechSynthesisTaskError::InvalidTaskId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSpeechSynthesisTaskError::InvalidTaskId(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetSpeechSynthesisTaskError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSpeechSynthesisTaskError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "SynthesisTaskNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetSpeechSynthesisTaskError::SynthesisTaskNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSpeechSynthesisTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -592,17 +613,21 @@ impl fmt::Display for GetSpeechSynthesisTaskError {
             GetSpeechSynthesisTaskError::InvalidTaskId(ref cause) => write!(f, "{}", cause),
             GetSpeechSynthesisTaskError::ServiceFailure(ref cause) => write!(f, "{}", cause),
             GetSpeechSynthesisTaskError::SynthesisTaskNotFound(ref cause) => write!(f, "{}", cause),
+            GetSpeechSynthesisTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSpeechSynthesisTaskError {}
 /// Errors returned by ListLexicons
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLexiconsError {
     /// <p>The NextToken is invalid. Verify that it's spelled correctly, and then try again.</p>
     InvalidNextToken(String),
     /// <p>An unknown condition has caused a service failure.</p>
     ServiceFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLexiconsError {
@@ -610,13 +635,13 @@ impl ListLexiconsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListLexiconsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListLexiconsError::InvalidNextToken(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListLexiconsError::ServiceFailure(err.msg))
+                    return RusotoError::service(ListLexiconsError::ServiceFailure(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLexiconsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -628,17 +653,21 @@ impl fmt::Display for ListLexiconsError {
         match *self {
             ListLexiconsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListLexiconsError::ServiceFailure(ref cause) => write!(f, "{}", cause),
+            ListLexiconsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLexiconsError {}
 /// Errors returned by ListSpeechSynthesisTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSpeechSynthesisTasksError {
     /// <p>The NextToken is invalid. Verify that it's spelled correctly, and then try again.</p>
     InvalidNextToken(String),
     /// <p>An unknown condition has caused a service failure.</p>
     ServiceFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSpeechSynthesisTasksError {
@@ -646,17 +675,19 @@ impl ListSpeechSynthesisTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSpeechSynthesisTasksError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSpeechSynthesisTasksError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListSpeechSynthesisTasksError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSpeechSynthesisTasksError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSpeechSynthesisTasksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -668,12 +699,14 @@ impl fmt::Display for ListSpeechSynthesisTasksError {
         match *self {
             ListSpeechSynthesisTasksError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListSpeechSynthesisTasksError::ServiceFailure(ref cause) => write!(f, "{}", cause),
+            ListSpeechSynthesisTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSpeechSynthesisTasksError {}
 /// Errors returned by PutLexicon
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLexiconError {
     /// <p>Amazon Polly can't find the specified lexicon. Verify that the lexicon's name is spelled correctly, and then try again.</p>
     InvalidLexicon(String),
@@ -689,6 +722,8 @@ pub enum PutLexiconError {
     UnsupportedPlsAlphabet(String),
     /// <p>The language specified in the lexicon is unsupported. For a list of supported languages, see <a href="https://docs.aws.amazon.com/polly/latest/dg/API_LexiconAttributes.html">Lexicon Attributes</a>.</p>
     UnsupportedPlsLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLexiconError {
@@ -696,30 +731,43 @@ impl PutLexiconError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidLexiconException" => {
-                    return RusotoError::Service(PutLexiconError::InvalidLexicon(err.msg))
+                    return RusotoError::service(PutLexiconError::InvalidLexicon(err.msg), &res)
                 }
                 "LexiconSizeExceededException" => {
-                    return RusotoError::Service(PutLexiconError::LexiconSizeExceeded(err.msg))
+                    return RusotoError::service(
+                        PutLexiconError::LexiconSizeExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "MaxLexemeLengthExceededException" => {
-                    return RusotoError::Service(PutLexiconError::MaxLexemeLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        PutLexiconError::MaxLexemeLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "MaxLexiconsNumberExceededException" => {
-                    return RusotoError::Service(PutLexiconError::MaxLexiconsNumberExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLexiconError::MaxLexiconsNumberExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutLexiconError::ServiceFailure(err.msg))
+                    return RusotoError::service(PutLexiconError::ServiceFailure(err.msg), &res)
                 }
                 "UnsupportedPlsAlphabetException" => {
-                    return RusotoError::Service(PutLexiconError::UnsupportedPlsAlphabet(err.msg))
+                    return RusotoError::service(
+                        PutLexiconError::UnsupportedPlsAlphabet(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedPlsLanguageException" => {
-                    return RusotoError::Service(PutLexiconError::UnsupportedPlsLanguage(err.msg))
+                    return RusotoError::service(
+                        PutLexiconError::UnsupportedPlsLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutLexiconError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -736,12 +784,14 @@ impl fmt::Display for PutLexiconError {
             PutLexiconError::ServiceFailure(ref cause) => write!(f, "{}", cause),
             PutLexiconError::UnsupportedPlsAlphabet(ref cause) => write!(f, "{}", cause),
             PutLexiconError::UnsupportedPlsLanguage(ref cause) => write!(f, "{}", cause),
+            PutLexiconError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutLexiconError {}
 /// Errors returned by StartSpeechSynthesisTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartSpeechSynthesisTaskError {
     /// <p>This engine is not compatible with the voice that you have designated. Choose a new voice that is compatible with the engine or change the engine and restart the operation.</p>
     EngineNotSupported(String),
@@ -767,6 +817,8 @@ pub enum StartSpeechSynthesisTaskError {
     SsmlMarksNotSupportedForTextType(String),
     /// <p>The value of the "Text" parameter is longer than the accepted limits. For the <code>SynthesizeSpeech</code> API, the limit for input text is a maximum of 6000 characters total, of which no more than 3000 can be billed characters. For the <code>StartSpeechSynthesisTask</code> API, the maximum is 200,000 characters, of which no more than 100,000 can be billed characters. SSML tags are not counted as billed characters.</p>
     TextLengthExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartSpeechSynthesisTaskError {
@@ -774,67 +826,79 @@ impl StartSpeechSynthesisTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EngineNotSupportedException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::EngineNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSpeechSynthesisTaskError::EngineNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3BucketException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidS3Bucket(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSpeechSynthesisTaskError::InvalidS3Bucket(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3KeyException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidS3Key(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSpeechSynthesisTaskError::InvalidS3Key(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSampleRateException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidSampleRate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSpeechSynthesisTaskError::InvalidSampleRate(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSnsTopicArnException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidSnsTopicArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSpeechSynthesisTaskError::InvalidSnsTopicArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSsmlException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidSsml(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSpeechSynthesisTaskError::InvalidSsml(err.msg),
+                        &res,
+                    )
                 }
                 "LanguageNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartSpeechSynthesisTaskError::LanguageNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::LexiconNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSpeechSynthesisTaskError::LexiconNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "MarksNotSupportedForFormatException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartSpeechSynthesisTaskError::MarksNotSupportedForFormat(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSpeechSynthesisTaskError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "SsmlMarksNotSupportedForTextTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartSpeechSynthesisTaskError::SsmlMarksNotSupportedForTextType(err.msg),
+                        &res,
                     )
                 }
                 "TextLengthExceededException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::TextLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSpeechSynthesisTaskError::TextLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartSpeechSynthesisTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -862,12 +926,14 @@ impl fmt::Display for StartSpeechSynthesisTaskError {
                 write!(f, "{}", cause)
             }
             StartSpeechSynthesisTaskError::TextLengthExceeded(ref cause) => write!(f, "{}", cause),
+            StartSpeechSynthesisTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartSpeechSynthesisTaskError {}
 /// Errors returned by SynthesizeSpeech
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SynthesizeSpeechError {
     /// <p>This engine is not compatible with the voice that you have designated. Choose a new voice that is compatible with the engine or change the engine and restart the operation.</p>
     EngineNotSupported(String),
@@ -887,6 +953,8 @@ pub enum SynthesizeSpeechError {
     SsmlMarksNotSupportedForTextType(String),
     /// <p>The value of the "Text" parameter is longer than the accepted limits. For the <code>SynthesizeSpeech</code> API, the limit for input text is a maximum of 6000 characters total, of which no more than 3000 can be billed characters. For the <code>StartSpeechSynthesisTask</code> API, the maximum is 200,000 characters, of which no more than 100,000 can be billed characters. SSML tags are not counted as billed characters.</p>
     TextLengthExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SynthesizeSpeechError {
@@ -894,40 +962,58 @@ impl SynthesizeSpeechError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EngineNotSupportedException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::EngineNotSupported(err.msg))
+                    return RusotoError::service(
+                        SynthesizeSpeechError::EngineNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSampleRateException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::InvalidSampleRate(err.msg))
+                    return RusotoError::service(
+                        SynthesizeSpeechError::InvalidSampleRate(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSsmlException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::InvalidSsml(err.msg))
+                    return RusotoError::service(SynthesizeSpeechError::InvalidSsml(err.msg), &res)
                 }
                 "LanguageNotSupportedException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::LanguageNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SynthesizeSpeechError::LanguageNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::LexiconNotFound(err.msg))
+                    return RusotoError::service(
+                        SynthesizeSpeechError::LexiconNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "MarksNotSupportedForFormatException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::MarksNotSupportedForFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SynthesizeSpeechError::MarksNotSupportedForFormat(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        SynthesizeSpeechError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "SsmlMarksNotSupportedForTextTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SynthesizeSpeechError::SsmlMarksNotSupportedForTextType(err.msg),
+                        &res,
                     )
                 }
                 "TextLengthExceededException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::TextLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        SynthesizeSpeechError::TextLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SynthesizeSpeechError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -948,6 +1034,7 @@ impl fmt::Display for SynthesizeSpeechError {
                 write!(f, "{}", cause)
             }
             SynthesizeSpeechError::TextLengthExceeded(ref cause) => write!(f, "{}", cause),
+            SynthesizeSpeechError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1020,6 +1107,7 @@ impl PollyClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> PollyClient {
         PollyClient {
             client: Client::shared(),
@@ -1045,45 +1133,121 @@ impl PollyClient {
     pub fn new_with_client(client: Client, region: region::Region) -> PollyClient {
         PollyClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<PollyClient> {
+        rusoto_core::ClientBuilder::new(PollyClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> PollyClient {
+        PollyClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Polly for PollyClient {
-    /// <p>Deletes the specified pronunciation lexicon stored in an AWS Region. A lexicon which has been deleted is not available for speech synthesis, nor is it possible to retrieve it using either the <code>GetLexicon</code> or <code>ListLexicon</code> APIs.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
-    async fn delete_lexicon(
+impl PollyClient {
+    /// Calls `delete_lexicon`, also returning the metadata of the response.
+    pub async fn delete_lexicon_with_metadata(
         &self,
         input: DeleteLexiconInput,
-    ) -> Result<DeleteLexiconOutput, RusotoError<DeleteLexiconError>> {
+    ) -> Result<(DeleteLexiconOutput, ResponseMetadata), RusotoError<DeleteLexiconError>> {
+        let request = self.build_delete_lexicon_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLexiconOutput, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteLexiconError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_lexicon` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_lexicon(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteLexiconInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_lexicon_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_lexicon_request(&self, input: DeleteLexiconInput) -> SignedRequest {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "polly", &self.region, &request_uri);
+        request.set_operation("DeleteLexicon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_voices`, also returning the metadata of the response.
+    pub async fn describe_voices_with_metadata(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> Result<(DescribeVoicesOutput, ResponseMetadata), RusotoError<DescribeVoicesError>> {
+        let request = self.build_describe_voices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLexiconOutput, _>()?;
+                .deserialize::<DescribeVoicesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLexiconError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeVoicesError::from_response(response))
         }
     }
 
-    /// <p>Returns the list of voices that are available for use when requesting speech synthesis. Each voice speaks a specified language, is either male or female, and is identified by an ID, which is the ASCII version of the voice name. </p> <p>When synthesizing speech ( <code>SynthesizeSpeech</code> ), you provide the voice ID for the voice you want from the list of voices returned by <code>DescribeVoices</code>.</p> <p>For example, you want your news reader application to read news in a specific language, but giving a user the option to choose the voice. Using the <code>DescribeVoices</code> operation you can provide the user with a list of available voices to select from.</p> <p> You can optionally specify a language code to filter the available voices. For example, if you specify <code>en-US</code>, the operation returns a list of all available US English voices. </p> <p>This operation requires permissions to perform the <code>polly:DescribeVoices</code> action.</p>
-    async fn describe_voices(
+    /// Presigns the request `describe_voices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_voices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeVoicesInput,
-    ) -> Result<DescribeVoicesOutput, RusotoError<DescribeVoicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_voices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_voices_request(&self, input: DescribeVoicesInput) -> SignedRequest {
         let request_uri = "/v1/voices";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("DescribeVoices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1101,85 +1265,163 @@ impl Polly for PollyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_lexicon`, also returning the metadata of the response.
+    pub async fn get_lexicon_with_metadata(
+        &self,
+        input: GetLexiconInput,
+    ) -> Result<(GetLexiconOutput, ResponseMetadata), RusotoError<GetLexiconError>> {
+        let request = self.build_get_lexicon_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeVoicesOutput, _>()?;
+                .deserialize::<GetLexiconOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVoicesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLexiconError::from_response(response))
         }
     }
 
-    /// <p>Returns the content of the specified pronunciation lexicon stored in an AWS Region. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
-    async fn get_lexicon(
+    /// Presigns the request `get_lexicon` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_lexicon(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLexiconInput,
-    ) -> Result<GetLexiconOutput, RusotoError<GetLexiconError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_lexicon_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_lexicon_request(&self, input: GetLexiconInput) -> SignedRequest {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("GetLexicon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_speech_synthesis_task`, also returning the metadata of the response.
+    pub async fn get_speech_synthesis_task_with_metadata(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> Result<
+        (GetSpeechSynthesisTaskOutput, ResponseMetadata),
+        RusotoError<GetSpeechSynthesisTaskError>,
+    > {
+        let request = self.build_get_speech_synthesis_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLexiconOutput, _>()?;
+                .deserialize::<GetSpeechSynthesisTaskOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLexiconError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSpeechSynthesisTaskError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a specific SpeechSynthesisTask object based on its TaskID. This object contains information about the given speech synthesis task, including the status of the task, and a link to the S3 bucket containing the output of the task.</p>
-    async fn get_speech_synthesis_task(
+    /// Presigns the request `get_speech_synthesis_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_speech_synthesis_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSpeechSynthesisTaskInput,
-    ) -> Result<GetSpeechSynthesisTaskOutput, RusotoError<GetSpeechSynthesisTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_speech_synthesis_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_speech_synthesis_task_request(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/synthesisTasks/{task_id}", task_id = input.task_id);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("GetSpeechSynthesisTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_lexicons`, also returning the metadata of the response.
+    pub async fn list_lexicons_with_metadata(
+        &self,
+        input: ListLexiconsInput,
+    ) -> Result<(ListLexiconsOutput, ResponseMetadata), RusotoError<ListLexiconsError>> {
+        let request = self.build_list_lexicons_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSpeechSynthesisTaskOutput, _>()?;
+                .deserialize::<ListLexiconsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSpeechSynthesisTaskError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListLexiconsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of pronunciation lexicons stored in an AWS Region. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
-    async fn list_lexicons(
+    /// Presigns the request `list_lexicons` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_lexicons(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLexiconsInput,
-    ) -> Result<ListLexiconsOutput, RusotoError<ListLexiconsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_lexicons_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_lexicons_request(&self, input: ListLexiconsInput) -> SignedRequest {
         let request_uri = "/v1/lexicons";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("ListLexicons");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1188,31 +1430,61 @@ impl Polly for PollyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_speech_synthesis_tasks`, also returning the metadata of the response.
+    pub async fn list_speech_synthesis_tasks_with_metadata(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> Result<
+        (ListSpeechSynthesisTasksOutput, ResponseMetadata),
+        RusotoError<ListSpeechSynthesisTasksError>,
+    > {
+        let request = self.build_list_speech_synthesis_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLexiconsOutput, _>()?;
+                .deserialize::<ListSpeechSynthesisTasksOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLexiconsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSpeechSynthesisTasksError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of SpeechSynthesisTask objects ordered by their creation date. This operation can filter the tasks by their status, for example, allowing users to list only tasks that are completed.</p>
-    async fn list_speech_synthesis_tasks(
+    /// Presigns the request `list_speech_synthesis_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_speech_synthesis_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSpeechSynthesisTasksInput,
-    ) -> Result<ListSpeechSynthesisTasksOutput, RusotoError<ListSpeechSynthesisTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_speech_synthesis_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_speech_synthesis_tasks_request(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> SignedRequest {
         let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("ListSpeechSynthesisTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1227,103 +1499,142 @@ impl Polly for PollyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_lexicon`, also returning the metadata of the response.
+    pub async fn put_lexicon_with_metadata(
+        &self,
+        input: PutLexiconInput,
+    ) -> Result<(PutLexiconOutput, ResponseMetadata), RusotoError<PutLexiconError>> {
+        let request = self.build_put_lexicon_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSpeechSynthesisTasksOutput, _>()?;
+                .deserialize::<PutLexiconOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSpeechSynthesisTasksError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutLexiconError::from_response(response))
         }
     }
 
-    /// <p>Stores a pronunciation lexicon in an AWS Region. If a lexicon with the same name already exists in the region, it is overwritten by the new lexicon. Lexicon operations have eventual consistency, therefore, it might take some time before the lexicon is available to the SynthesizeSpeech operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
-    async fn put_lexicon(
+    /// Presigns the request `put_lexicon` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_lexicon(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutLexiconInput,
-    ) -> Result<PutLexiconOutput, RusotoError<PutLexiconError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_lexicon_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_lexicon_request(&self, input: PutLexiconInput) -> SignedRequest {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("PUT", "polly", &self.region, &request_uri);
+        request.set_operation("PutLexicon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutLexiconOutput, _>()?;
-
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutLexiconError::from_response(response))
-        }
+        request
     }
 
-    /// <p>Allows the creation of an asynchronous synthesis task, by starting a new <code>SpeechSynthesisTask</code>. This operation requires all the standard information needed for speech synthesis, plus the name of an Amazon S3 bucket for the service to store the output of the synthesis task and two optional parameters (OutputS3KeyPrefix and SnsTopicArn). Once the synthesis task is created, this operation will return a SpeechSynthesisTask object, which will include an identifier of this task as well as the current status.</p>
-    async fn start_speech_synthesis_task(
+    /// Calls `start_speech_synthesis_task`, also returning the metadata of the response.
+    pub async fn start_speech_synthesis_task_with_metadata(
         &self,
         input: StartSpeechSynthesisTaskInput,
-    ) -> Result<StartSpeechSynthesisTaskOutput, RusotoError<StartSpeechSynthesisTaskError>> {
-        let request_uri = "/v1/synthesisTasks";
-
-        let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (StartSpeechSynthesisTaskOutput, ResponseMetadata),
+        RusotoError<StartSpeechSynthesisTaskError>,
+    > {
+        let request = self.build_start_speech_synthesis_task_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartSpeechSynthesisTaskOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(StartSpeechSynthesisTaskError::from_response(response))
         }
     }
 
-    /// <p>Synthesizes UTF-8 input, plain text or SSML, to a stream of bytes. SSML input must be valid, well-formed SSML. Some alphabets might not be available with all the voices (for example, Cyrillic might not be read at all by English voices) unless phoneme mapping is used. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/how-text-to-speech-works.html">How it Works</a>.</p>
-    async fn synthesize_speech(
+    /// Presigns the request `start_speech_synthesis_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_speech_synthesis_task(
         &self,
-        input: SynthesizeSpeechInput,
-    ) -> Result<SynthesizeSpeechOutput, RusotoError<SynthesizeSpeechError>> {
-        let request_uri = "/v1/speech";
+        presigner: &rusoto_core::Presigner,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_speech_synthesis_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_speech_synthesis_task_request(
+        &self,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> SignedRequest {
+        let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation("StartSpeechSynthesisTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `synthesize_speech`, also returning the metadata of the response.
+    pub async fn synthesize_speech_with_metadata(
+        &self,
+        input: SynthesizeSpeechInput,
+    ) -> Result<(SynthesizeSpeechOutput, ResponseMetadata), RusotoError<SynthesizeSpeechError>>
+    {
+        let request = self.build_synthesize_speech_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
 
             let mut result = SynthesizeSpeechOutput::default();
             result.audio_stream = Some(response.body);
@@ -1337,10 +1648,235 @@ impl Polly for PollyClient {
                 result.request_characters = Some(value.parse::<i64>().unwrap())
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(SynthesizeSpeechError::from_response(response))
         }
     }
+
+    /// Presigns the request `synthesize_speech` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_synthesize_speech(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SynthesizeSpeechInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_synthesize_speech_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_synthesize_speech_request(&self, input: SynthesizeSpeechInput) -> SignedRequest {
+        let request_uri = "/v1/speech";
+
+        let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation("SynthesizeSpeech");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// The pages of `DescribeVoices` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_voices_pages(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> impl futures::Stream<Item = Result<DescribeVoicesOutput, RusotoError<DescribeVoicesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PollyClient, input| async move { client.describe_voices(input).await },
+            |input: &DescribeVoicesInput, output: &DescribeVoicesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeVoicesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Voices` of every page of `DescribeVoices` results for `input`, as `describe_voices_pages` requests them.
+    pub fn describe_voices_stream(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> impl futures::Stream<Item = Result<Voice, RusotoError<DescribeVoicesError>>> + Send {
+        rusoto_core::pagination::items(self.describe_voices_pages(input), |page| {
+            page.voices.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLexicons` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_lexicons_pages(
+        &self,
+        input: ListLexiconsInput,
+    ) -> impl futures::Stream<Item = Result<ListLexiconsOutput, RusotoError<ListLexiconsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PollyClient, input| async move { client.list_lexicons(input).await },
+            |input: &ListLexiconsInput, output: &ListLexiconsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLexiconsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Lexicons` of every page of `ListLexicons` results for `input`, as `list_lexicons_pages` requests them.
+    pub fn list_lexicons_stream(
+        &self,
+        input: ListLexiconsInput,
+    ) -> impl futures::Stream<Item = Result<LexiconDescription, RusotoError<ListLexiconsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_lexicons_pages(input), |page| {
+            page.lexicons.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSpeechSynthesisTasks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_speech_synthesis_tasks_pages(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListSpeechSynthesisTasksOutput, RusotoError<ListSpeechSynthesisTasksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PollyClient, input| async move { client.list_speech_synthesis_tasks(input).await },
+            |input: &ListSpeechSynthesisTasksInput, output: &ListSpeechSynthesisTasksOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSpeechSynthesisTasksInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SynthesisTasks` of every page of `ListSpeechSynthesisTasks` results for `input`, as `list_speech_synthesis_tasks_pages` requests them.
+    pub fn list_speech_synthesis_tasks_stream(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> impl futures::Stream<Item = Result<SynthesisTask, RusotoError<ListSpeechSynthesisTasksError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_speech_synthesis_tasks_pages(input), |page| {
+            page.synthesis_tasks.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Polly for PollyClient {
+    /// <p>Deletes the specified pronunciation lexicon stored in an AWS Region. A lexicon which has been deleted is not available for speech synthesis, nor is it possible to retrieve it using either the <code>GetLexicon</code> or <code>ListLexicon</code> APIs.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
+    async fn delete_lexicon(
+        &self,
+        input: DeleteLexiconInput,
+    ) -> Result<DeleteLexiconOutput, RusotoError<DeleteLexiconError>> {
+        self.delete_lexicon_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of voices that are available for use when requesting speech synthesis. Each voice speaks a specified language, is either male or female, and is identified by an ID, which is the ASCII version of the voice name. </p> <p>When synthesizing speech ( <code>SynthesizeSpeech</code> ), you provide the voice ID for the voice you want from the list of voices returned by <code>DescribeVoices</code>.</p> <p>For example, you want your news reader application to read news in a specific language, but giving a user the option to choose the voice. Using the <code>DescribeVoices</code> operation you can provide the user with a list of available voices to select from.</p> <p> You can optionally specify a language code to filter the available voices. For example, if you specify <code>en-US</code>, the operation returns a list of all available US English voices. </p> <p>This operation requires permissions to perform the <code>polly:DescribeVoices</code> action.</p>
+    async fn describe_voices(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> Result<DescribeVoicesOutput, RusotoError<DescribeVoicesError>> {
+        self.describe_voices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the content of the specified pronunciation lexicon stored in an AWS Region. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
+    async fn get_lexicon(
+        &self,
+        input: GetLexiconInput,
+    ) -> Result<GetLexiconOutput, RusotoError<GetLexiconError>> {
+        self.get_lexicon_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a specific SpeechSynthesisTask object based on its TaskID. This object contains information about the given speech synthesis task, including the status of the task, and a link to the S3 bucket containing the output of the task.</p>
+    async fn get_speech_synthesis_task(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> Result<GetSpeechSynthesisTaskOutput, RusotoError<GetSpeechSynthesisTaskError>> {
+        self.get_speech_synthesis_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of pronunciation lexicons stored in an AWS Region. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
+    async fn list_lexicons(
+        &self,
+        input: ListLexiconsInput,
+    ) -> Result<ListLexiconsOutput, RusotoError<ListLexiconsError>> {
+        self.list_lexicons_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of SpeechSynthesisTask objects ordered by their creation date. This operation can filter the tasks by their status, for example, allowing users to list only tasks that are completed.</p>
+    async fn list_speech_synthesis_tasks(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> Result<ListSpeechSynthesisTasksOutput, RusotoError<ListSpeechSynthesisTasksError>> {
+        self.list_speech_synthesis_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stores a pronunciation lexicon in an AWS Region. If a lexicon with the same name already exists in the region, it is overwritten by the new lexicon. Lexicon operations have eventual consistency, therefore, it might take some time before the lexicon is available to the SynthesizeSpeech operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
+    async fn put_lexicon(
+        &self,
+        input: PutLexiconInput,
+    ) -> Result<PutLexiconOutput, RusotoError<PutLexiconError>> {
+        self.put_lexicon_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows the creation of an asynchronous synthesis task, by starting a new <code>SpeechSynthesisTask</code>. This operation requires all the standard information needed for speech synthesis, plus the name of an Amazon S3 bucket for the service to store the output of the synthesis task and two optional parameters (OutputS3KeyPrefix and SnsTopicArn). Once the synthesis task is created, this operation will return a SpeechSynthesisTask object, which will include an identifier of this task as well as the current status.</p>
+    async fn start_speech_synthesis_task(
+        &self,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> Result<StartSpeechSynthesisTaskOutput, RusotoError<StartSpeechSynthesisTaskError>> {
+        self.start_speech_synthesis_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Synthesizes UTF-8 input, plain text or SSML, to a stream of bytes. SSML input must be valid, well-formed SSML. Some alphabets might not be available with all the voices (for example, Cyrillic might not be read at all by English voices) unless phoneme mapping is used. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/how-text-to-speech-works.html">How it Works</a>.</p>
+    async fn synthesize_speech(
+        &self,
+        input: SynthesizeSpeechInput,
+    ) -> Result<SynthesizeSpeechOutput, RusotoError<SynthesizeSpeechError>> {
+        self.synthesize_speech_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -177,6 +177,7 @@ pub struct Service {
 
 /// Errors returned by DescribeServices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServicesError {
     /// <p>The pagination token expired. Try again without a pagination token.</p>
     ExpiredNextToken(String),
@@ -188,6 +189,8 @@ pub enum DescribeServicesError {
     InvalidParameter(String),
     /// <p>The requested resource can't be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServicesError {
@@ -195,22 +198,34 @@ impl DescribeServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(DescribeServicesError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeServicesError::ExpiredNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeServicesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeServicesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeServicesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeServicesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeServicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeServicesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeServicesError::NotFound(err.msg))
+                    return RusotoError::service(DescribeServicesError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeServicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -225,12 +240,14 @@ impl fmt::Display for DescribeServicesError {
             DescribeServicesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeServicesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeServicesError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeServicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeServicesError {}
 /// Errors returned by GetAttributeValues
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAttributeValuesError {
     /// <p>The pagination token expired. Try again without a pagination token.</p>
     ExpiredNextToken(String),
@@ -242,6 +259,8 @@ pub enum GetAttributeValuesError {
     InvalidParameter(String),
     /// <p>The requested resource can't be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAttributeValuesError {
@@ -249,22 +268,34 @@ impl GetAttributeValuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(GetAttributeValuesError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(
+                        GetAttributeValuesError::ExpiredNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetAttributeValuesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetAttributeValuesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetAttributeValuesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        GetAttributeValuesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAttributeValuesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetAttributeValuesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAttributeValuesError::NotFound(err.msg))
+                    return RusotoError::service(GetAttributeValuesError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAttributeValuesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -279,12 +310,14 @@ impl fmt::Display for GetAttributeValuesError {
             GetAttributeValuesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetAttributeValuesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetAttributeValuesError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetAttributeValuesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAttributeValuesError {}
 /// Errors returned by GetProducts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetProductsError {
     /// <p>The pagination token expired. Try again without a pagination token.</p>
     ExpiredNextToken(String),
@@ -296,6 +329,8 @@ pub enum GetProductsError {
     InvalidParameter(String),
     /// <p>The requested resource can't be found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetProductsError {
@@ -303,22 +338,22 @@ impl GetProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(GetProductsError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(GetProductsError::ExpiredNextToken(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetProductsError::InternalError(err.msg))
+                    return RusotoError::service(GetProductsError::InternalError(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetProductsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(GetProductsError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetProductsError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetProductsError::InvalidParameter(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProductsError::NotFound(err.msg))
+                    return RusotoError::service(GetProductsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetProductsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -333,6 +368,7 @@ impl fmt::Display for GetProductsError {
             GetProductsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetProductsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetProductsError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetProductsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -369,6 +405,7 @@ impl PricingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> PricingClient {
         PricingClient {
             client: Client::shared(),
@@ -394,90 +431,324 @@ impl PricingClient {
     pub fn new_with_client(client: Client, region: region::Region) -> PricingClient {
         PricingClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<PricingClient> {
+        rusoto_core::ClientBuilder::new(PricingClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> PricingClient {
+        PricingClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Pricing for PricingClient {
-    /// <p>Returns the metadata for one service or a list of the metadata for all services. Use this without a service code to get the service codes for all services. Use it with a service code, such as <code>AmazonEC2</code>, to get information specific to that service, such as the attribute names available for that service. For example, some of the attribute names available for EC2 are <code>volumeType</code>, <code>maxIopsVolume</code>, <code>operation</code>, <code>locationType</code>, and <code>instanceCapacity10xlarge</code>.</p>
-    async fn describe_services(
+impl PricingClient {
+    /// Calls `describe_services`, also returning the metadata of the response.
+    pub async fn describe_services_with_metadata(
         &self,
         input: DescribeServicesRequest,
-    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
-        let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
-        request.set_endpoint_prefix("api.pricing".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSPriceListService.DescribeServices");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<(DescribeServicesResponse, ResponseMetadata), RusotoError<DescribeServicesError>>
+    {
+        let request = self.build_describe_services_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeServicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeServicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(DescribeServicesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of attribute values. Attibutes are similar to the details in a Price List API offer file. For a list of available attributes, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/reading-an-offer.html#pps-defs">Offer File Definitions</a> in the <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/billing-what-is.html">AWS Billing and Cost Management User Guide</a>.</p>
-    async fn get_attribute_values(
+    /// Presigns the request `describe_services` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_services(
         &self,
-        input: GetAttributeValuesRequest,
-    ) -> Result<GetAttributeValuesResponse, RusotoError<GetAttributeValuesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeServicesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_services_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_services_request(&self, input: DescribeServicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
+        request.set_operation("DescribeServices");
         request.set_endpoint_prefix("api.pricing".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSPriceListService.GetAttributeValues");
+        request.add_header("x-amz-target", "AWSPriceListService.DescribeServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_attribute_values`, also returning the metadata of the response.
+    pub async fn get_attribute_values_with_metadata(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> Result<(GetAttributeValuesResponse, ResponseMetadata), RusotoError<GetAttributeValuesError>>
+    {
+        let request = self.build_get_attribute_values_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAttributeValuesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAttributeValuesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetAttributeValuesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all products that match the filter criteria.</p>
-    async fn get_products(
+    /// Presigns the request `get_attribute_values` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_attribute_values(
         &self,
-        input: GetProductsRequest,
-    ) -> Result<GetProductsResponse, RusotoError<GetProductsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetAttributeValuesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_attribute_values_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_attribute_values_request(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
+        request.set_operation("GetAttributeValues");
         request.set_endpoint_prefix("api.pricing".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSPriceListService.GetProducts");
+        request.add_header("x-amz-target", "AWSPriceListService.GetAttributeValues");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_products`, also returning the metadata of the response.
+    pub async fn get_products_with_metadata(
+        &self,
+        input: GetProductsRequest,
+    ) -> Result<(GetProductsResponse, ResponseMetadata), RusotoError<GetProductsError>> {
+        let request = self.build_get_products_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetProductsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetProductsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetProductsError::from_response(response))
         }
     }
+
+    /// Presigns the request `get_products` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_products(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetProductsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_products_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_products_request(&self, input: GetProductsRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
+        request.set_operation("GetProducts");
+        request.set_endpoint_prefix("api.pricing".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSPriceListService.GetProducts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// The pages of `DescribeServices` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_services_pages(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeServicesResponse, RusotoError<DescribeServicesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PricingClient, input| async move { client.describe_services(input).await },
+            |input: &DescribeServicesRequest, output: &DescribeServicesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeServicesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Services` of every page of `DescribeServices` results for `input`, as `describe_services_pages` requests them.
+    pub fn describe_services_stream(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> impl futures::Stream<Item = Result<Service, RusotoError<DescribeServicesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_services_pages(input), |page| {
+            page.services.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetAttributeValues` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_attribute_values_pages(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetAttributeValuesResponse, RusotoError<GetAttributeValuesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PricingClient, input| async move { client.get_attribute_values(input).await },
+            |input: &GetAttributeValuesRequest, output: &GetAttributeValuesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetAttributeValuesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AttributeValues` of every page of `GetAttributeValues` results for `input`, as `get_attribute_values_pages` requests them.
+    pub fn get_attribute_values_stream(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> impl futures::Stream<Item = Result<AttributeValue, RusotoError<GetAttributeValuesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_attribute_values_pages(input), |page| {
+            page.attribute_values.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetProducts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_products_pages(
+        &self,
+        input: GetProductsRequest,
+    ) -> impl futures::Stream<Item = Result<GetProductsResponse, RusotoError<GetProductsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: PricingClient, input| async move { client.get_products(input).await },
+            |input: &GetProductsRequest, output: &GetProductsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetProductsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PriceList` of every page of `GetProducts` results for `input`, as `get_products_pages` requests them.
+    pub fn get_products_stream(
+        &self,
+        input: GetProductsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<GetProductsError>>> + Send {
+        rusoto_core::pagination::items(self.get_products_pages(input), |page| {
+            page.price_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Pricing for PricingClient {
+    /// <p>Returns the metadata for one service or a list of the metadata for all services. Use this without a service code to get the service codes for all services. Use it with a service code, such as <code>AmazonEC2</code>, to get information specific to that service, such as the attribute names available for that service. For example, some of the attribute names available for EC2 are <code>volumeType</code>, <code>maxIopsVolume</code>, <code>operation</code>, <code>locationType</code>, and <code>instanceCapacity10xlarge</code>.</p>
+    async fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+        self.describe_services_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of attribute values. Attibutes are similar to the details in a Price List API offer file. For a list of available attributes, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/reading-an-offer.html#pps-defs">Offer File Definitions</a> in the <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/billing-what-is.html">AWS Billing and Cost Management User Guide</a>.</p>
+    async fn get_attribute_values(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> Result<GetAttributeValuesResponse, RusotoError<GetAttributeValuesError>> {
+        self.get_attribute_values_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all products that match the filter criteria.</p>
+    async fn get_products(
+        &self,
+        input: GetProductsRequest,
+    ) -> Result<GetProductsResponse, RusotoError<GetProductsError>> {
+        self.get_products_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -266,6 +266,7 @@ pub struct ValueHolder {
 
 /// Errors returned by SendCommand
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendCommandError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -277,6 +278,8 @@ pub enum SendCommandError {
     OccConflict(String),
     /// <p>Returned when the rate of requests exceeds the allowed throughput.</p>
     RateExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendCommandError {
@@ -284,22 +287,22 @@ impl SendCommandError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SendCommandError::BadRequest(err.msg))
+                    return RusotoError::service(SendCommandError::BadRequest(err.msg), &res)
                 }
                 "InvalidSessionException" => {
-                    return RusotoError::Service(SendCommandError::InvalidSession(err.msg))
+                    return RusotoError::service(SendCommandError::InvalidSession(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SendCommandError::LimitExceeded(err.msg))
+                    return RusotoError::service(SendCommandError::LimitExceeded(err.msg), &res)
                 }
                 "OccConflictException" => {
-                    return RusotoError::Service(SendCommandError::OccConflict(err.msg))
+                    return RusotoError::service(SendCommandError::OccConflict(err.msg), &res)
                 }
                 "RateExceededException" => {
-                    return RusotoError::Service(SendCommandError::RateExceeded(err.msg))
+                    return RusotoError::service(SendCommandError::RateExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendCommandError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -314,6 +317,7 @@ impl fmt::Display for SendCommandError {
             SendCommandError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             SendCommandError::OccConflict(ref cause) => write!(f, "{}", cause),
             SendCommandError::RateExceeded(ref cause) => write!(f, "{}", cause),
+            SendCommandError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -338,6 +342,7 @@ impl QldbSessionClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> QldbSessionClient {
         QldbSessionClient {
             client: Client::shared(),
@@ -363,34 +368,86 @@ impl QldbSessionClient {
     pub fn new_with_client(client: Client, region: region::Region) -> QldbSessionClient {
         QldbSessionClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<QldbSessionClient> {
+        rusoto_core::ClientBuilder::new(QldbSessionClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> QldbSessionClient {
+        QldbSessionClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl QldbSession for QldbSessionClient {
-    /// <p>Sends a command to an Amazon QLDB ledger.</p>
-    async fn send_command(
+impl QldbSessionClient {
+    /// Calls `send_command`, also returning the metadata of the response.
+    pub async fn send_command_with_metadata(
         &self,
         input: SendCommandRequest,
-    ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
-        let mut request = SignedRequest::new("POST", "qldb", &self.region, "/");
-        request.set_endpoint_prefix("session.qldb".to_string());
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "QLDBSession.SendCommand");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<(SendCommandResult, ResponseMetadata), RusotoError<SendCommandError>> {
+        let request = self.build_send_command_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SendCommandResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendCommandResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(SendCommandError::from_response(response))
         }
     }
+
+    /// Presigns the request `send_command` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_command(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SendCommandRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_command_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_command_request(&self, input: SendCommandRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "qldb", &self.region, "/");
+        request.set_operation("SendCommand");
+        request.set_endpoint_prefix("session.qldb".to_string());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "QLDBSession.SendCommand");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+}
+
+#[async_trait]
+impl QldbSession for QldbSessionClient {
+    /// <p>Sends a command to an Amazon QLDB ledger.</p>
+    async fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
+        self.send_command_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
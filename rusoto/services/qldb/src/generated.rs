@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -487,6 +487,7 @@ pub struct ValueHolder {
 
 /// Errors returned by CreateLedger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLedgerError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
@@ -496,6 +497,8 @@ pub enum CreateLedgerError {
     ResourceAlreadyExists(String),
     /// <p>The specified resource can't be modified at this time.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLedgerError {
@@ -503,19 +506,22 @@ impl CreateLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateLedgerError::InvalidParameter(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateLedgerError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateLedgerError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLedgerError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateLedgerError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateLedgerError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateLedgerError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLedgerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -529,12 +535,14 @@ impl fmt::Display for CreateLedgerError {
             CreateLedgerError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateLedgerError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateLedgerError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            CreateLedgerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLedgerError {}
 /// Errors returned by DeleteLedger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLedgerError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
@@ -544,6 +552,8 @@ pub enum DeleteLedgerError {
     ResourceNotFound(String),
     /// <p>The operation failed because a condition wasn't satisfied in advance.</p>
     ResourcePreconditionNotMet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLedgerError {
@@ -551,21 +561,22 @@ impl DeleteLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteLedgerError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteLedgerError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteLedgerError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLedgerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteLedgerError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteLedgerError::ResourcePreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLedgerError::ResourcePreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLedgerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -579,15 +590,19 @@ impl fmt::Display for DeleteLedgerError {
             DeleteLedgerError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteLedgerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteLedgerError::ResourcePreconditionNotMet(ref cause) => write!(f, "{}", cause),
+            DeleteLedgerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLedgerError {}
 /// Errors returned by DescribeJournalS3Export
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeJournalS3ExportError {
     /// <p>The specified resource doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeJournalS3ExportError {
@@ -595,12 +610,13 @@ impl DescribeJournalS3ExportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJournalS3ExportError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeJournalS3ExportError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeJournalS3ExportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -611,17 +627,21 @@ impl fmt::Display for DescribeJournalS3ExportError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeJournalS3ExportError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeJournalS3ExportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeJournalS3ExportError {}
 /// Errors returned by DescribeLedger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLedgerError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
     /// <p>The specified resource doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLedgerError {
@@ -629,13 +649,19 @@ impl DescribeLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeLedgerError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLedgerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeLedgerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLedgerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -647,17 +673,21 @@ impl fmt::Display for DescribeLedgerError {
         match *self {
             DescribeLedgerError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeLedgerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeLedgerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLedgerError {}
 /// Errors returned by ExportJournalToS3
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExportJournalToS3Error {
     /// <p>The specified resource doesn't exist.</p>
     ResourceNotFound(String),
     /// <p>The operation failed because a condition wasn't satisfied in advance.</p>
     ResourcePreconditionNotMet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExportJournalToS3Error {
@@ -665,15 +695,19 @@ impl ExportJournalToS3Error {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExportJournalToS3Error::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ExportJournalToS3Error::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ExportJournalToS3Error::ResourcePreconditionNotMet(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExportJournalToS3Error::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -685,12 +719,14 @@ impl fmt::Display for ExportJournalToS3Error {
         match *self {
             ExportJournalToS3Error::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ExportJournalToS3Error::ResourcePreconditionNotMet(ref cause) => write!(f, "{}", cause),
+            ExportJournalToS3Error::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExportJournalToS3Error {}
 /// Errors returned by GetBlock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBlockError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
@@ -698,6 +734,8 @@ pub enum GetBlockError {
     ResourceNotFound(String),
     /// <p>The operation failed because a condition wasn't satisfied in advance.</p>
     ResourcePreconditionNotMet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBlockError {
@@ -705,16 +743,19 @@ impl GetBlockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetBlockError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetBlockError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBlockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetBlockError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(GetBlockError::ResourcePreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        GetBlockError::ResourcePreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBlockError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -727,12 +768,14 @@ impl fmt::Display for GetBlockError {
             GetBlockError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetBlockError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetBlockError::ResourcePreconditionNotMet(ref cause) => write!(f, "{}", cause),
+            GetBlockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBlockError {}
 /// Errors returned by GetDigest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDigestError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
@@ -740,6 +783,8 @@ pub enum GetDigestError {
     ResourceNotFound(String),
     /// <p>The operation failed because a condition wasn't satisfied in advance.</p>
     ResourcePreconditionNotMet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDigestError {
@@ -747,18 +792,19 @@ impl GetDigestError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDigestError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetDigestError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDigestError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetDigestError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(GetDigestError::ResourcePreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDigestError::ResourcePreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDigestError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -771,12 +817,14 @@ impl fmt::Display for GetDigestError {
             GetDigestError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetDigestError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetDigestError::ResourcePreconditionNotMet(ref cause) => write!(f, "{}", cause),
+            GetDigestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDigestError {}
 /// Errors returned by GetRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRevisionError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
@@ -784,6 +832,8 @@ pub enum GetRevisionError {
     ResourceNotFound(String),
     /// <p>The operation failed because a condition wasn't satisfied in advance.</p>
     ResourcePreconditionNotMet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRevisionError {
@@ -791,18 +841,19 @@ impl GetRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRevisionError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetRevisionError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetRevisionError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(GetRevisionError::ResourcePreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRevisionError::ResourcePreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRevisionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -815,20 +866,25 @@ impl fmt::Display for GetRevisionError {
             GetRevisionError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetRevisionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetRevisionError::ResourcePreconditionNotMet(ref cause) => write!(f, "{}", cause),
+            GetRevisionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRevisionError {}
 /// Errors returned by ListJournalS3Exports
 #[derive(Debug, PartialEq)]
-pub enum ListJournalS3ExportsError {}
+#[non_exhaustive]
+pub enum ListJournalS3ExportsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListJournalS3ExportsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListJournalS3ExportsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJournalS3ExportsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -837,13 +893,19 @@ impl ListJournalS3ExportsError {
 impl fmt::Display for ListJournalS3ExportsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListJournalS3ExportsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListJournalS3ExportsError {}
 /// Errors returned by ListJournalS3ExportsForLedger
 #[derive(Debug, PartialEq)]
-pub enum ListJournalS3ExportsForLedgerError {}
+#[non_exhaustive]
+pub enum ListJournalS3ExportsForLedgerError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListJournalS3ExportsForLedgerError {
     pub fn from_response(
@@ -852,7 +914,12 @@ impl ListJournalS3ExportsForLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListJournalS3ExportsForLedgerError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -861,20 +928,28 @@ impl ListJournalS3ExportsForLedgerError {
 impl fmt::Display for ListJournalS3ExportsForLedgerError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListJournalS3ExportsForLedgerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for ListJournalS3ExportsForLedgerError {}
 /// Errors returned by ListLedgers
 #[derive(Debug, PartialEq)]
-pub enum ListLedgersError {}
+#[non_exhaustive]
+pub enum ListLedgersError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListLedgersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListLedgersError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLedgersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -883,17 +958,22 @@ impl ListLedgersError {
 impl fmt::Display for ListLedgersError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListLedgersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListLedgersError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
     /// <p>The specified resource doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -901,17 +981,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -923,17 +1005,21 @@ impl fmt::Display for ListTagsForResourceError {
         match *self {
             ListTagsForResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
     /// <p>The specified resource doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -941,13 +1027,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -959,17 +1045,21 @@ impl fmt::Display for TagResourceError {
         match *self {
             TagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
     /// <p>The specified resource doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -977,13 +1067,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -995,17 +1091,21 @@ impl fmt::Display for UntagResourceError {
         match *self {
             UntagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateLedger
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLedgerError {
     /// <p>One or more parameters in the request aren't valid.</p>
     InvalidParameter(String),
     /// <p>The specified resource doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLedgerError {
@@ -1013,13 +1113,13 @@ impl UpdateLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service(UpdateLedgerError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLedgerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateLedgerError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateLedgerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1031,6 +1131,7 @@ impl fmt::Display for UpdateLedgerError {
         match *self {
             UpdateLedgerError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateLedgerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateLedgerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1142,6 +1243,7 @@ impl QldbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> QldbClient {
         QldbClient {
             client: Client::shared(),
@@ -1167,71 +1269,176 @@ impl QldbClient {
     pub fn new_with_client(client: Client, region: region::Region) -> QldbClient {
         QldbClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<QldbClient> {
+        rusoto_core::ClientBuilder::new(QldbClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> QldbClient {
+        QldbClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Qldb for QldbClient {
-    /// <p>Creates a new ledger in your AWS account.</p>
-    async fn create_ledger(
+impl QldbClient {
+    /// Calls `create_ledger`, also returning the metadata of the response.
+    pub async fn create_ledger_with_metadata(
         &self,
         input: CreateLedgerRequest,
-    ) -> Result<CreateLedgerResponse, RusotoError<CreateLedgerError>> {
+    ) -> Result<(CreateLedgerResponse, ResponseMetadata), RusotoError<CreateLedgerError>> {
+        let request = self.build_create_ledger_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLedgerResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateLedgerError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_ledger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_ledger(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateLedgerRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_ledger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_ledger_request(&self, input: CreateLedgerRequest) -> SignedRequest {
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("CreateLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_ledger`, also returning the metadata of the response.
+    pub async fn delete_ledger_with_metadata(
+        &self,
+        input: DeleteLedgerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteLedgerError>> {
+        let request = self.build_delete_ledger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLedgerResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLedgerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteLedgerError::from_response(response))
         }
     }
 
-    /// <p>Deletes a ledger and all of its contents. This action is irreversible.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
-    async fn delete_ledger(
+    /// Presigns the request `delete_ledger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_ledger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLedgerRequest,
-    ) -> Result<(), RusotoError<DeleteLedgerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_ledger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_ledger_request(&self, input: DeleteLedgerRequest) -> SignedRequest {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation("DeleteLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_journal_s3_export`, also returning the metadata of the response.
+    pub async fn describe_journal_s3_export_with_metadata(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> Result<
+        (DescribeJournalS3ExportResponse, ResponseMetadata),
+        RusotoError<DescribeJournalS3ExportError>,
+    > {
+        let request = self.build_describe_journal_s3_export_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeJournalS3ExportResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLedgerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeJournalS3ExportError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a journal export job, including the ledger name, export ID, when it was created, current status, and its start and end time export parameters.</p> <p>If the export job with the given <code>ExportId</code> doesn't exist, then throws <code>ResourceNotFoundException</code>.</p> <p>If the ledger with the given <code>Name</code> doesn't exist, then throws <code>ResourceNotFoundException</code>.</p>
-    async fn describe_journal_s3_export(
+    /// Presigns the request `describe_journal_s3_export` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_journal_s3_export(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeJournalS3ExportRequest,
-    ) -> Result<DescribeJournalS3ExportResponse, RusotoError<DescribeJournalS3ExportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_journal_s3_export_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_journal_s3_export_request(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/ledgers/{name}/journal-s3-exports/{export_id}",
             export_id = input.export_id,
@@ -1239,177 +1446,329 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("DescribeJournalS3Export");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_ledger`, also returning the metadata of the response.
+    pub async fn describe_ledger_with_metadata(
+        &self,
+        input: DescribeLedgerRequest,
+    ) -> Result<(DescribeLedgerResponse, ResponseMetadata), RusotoError<DescribeLedgerError>> {
+        let request = self.build_describe_ledger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeJournalS3ExportResponse, _>()?;
+                .deserialize::<DescribeLedgerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeJournalS3ExportError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeLedgerError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a ledger, including its state and when it was created.</p>
-    async fn describe_ledger(
+    /// Presigns the request `describe_ledger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_ledger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLedgerRequest,
-    ) -> Result<DescribeLedgerResponse, RusotoError<DescribeLedgerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_ledger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_ledger_request(&self, input: DescribeLedgerRequest) -> SignedRequest {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("DescribeLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `export_journal_to_s3`, also returning the metadata of the response.
+    pub async fn export_journal_to_s3_with_metadata(
+        &self,
+        input: ExportJournalToS3Request,
+    ) -> Result<(ExportJournalToS3Response, ResponseMetadata), RusotoError<ExportJournalToS3Error>>
+    {
+        let request = self.build_export_journal_to_s3_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLedgerResponse, _>()?;
+                .deserialize::<ExportJournalToS3Response, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLedgerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ExportJournalToS3Error::from_response(response))
         }
     }
 
-    /// <p>Exports journal contents within a date and time range from a ledger into a specified Amazon Simple Storage Service (Amazon S3) bucket. The data is written as files in Amazon Ion format.</p> <p>If the ledger with the given <code>Name</code> doesn't exist, then throws <code>ResourceNotFoundException</code>.</p> <p>If the ledger with the given <code>Name</code> is in <code>CREATING</code> status, then throws <code>ResourcePreconditionNotMetException</code>.</p> <p>You can initiate up to two concurrent journal export requests for each ledger. Beyond this limit, journal export requests throw <code>LimitExceededException</code>.</p>
-    async fn export_journal_to_s3(
+    /// Presigns the request `export_journal_to_s3` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_export_journal_to_s3(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExportJournalToS3Request,
-    ) -> Result<ExportJournalToS3Response, RusotoError<ExportJournalToS3Error>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_export_journal_to_s3_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_export_journal_to_s3_request(&self, input: ExportJournalToS3Request) -> SignedRequest {
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("ExportJournalToS3");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_block`, also returning the metadata of the response.
+    pub async fn get_block_with_metadata(
+        &self,
+        input: GetBlockRequest,
+    ) -> Result<(GetBlockResponse, ResponseMetadata), RusotoError<GetBlockError>> {
+        let request = self.build_get_block_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ExportJournalToS3Response, _>()?;
+                .deserialize::<GetBlockResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ExportJournalToS3Error::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBlockError::from_response(response))
         }
     }
 
-    /// <p>Returns a journal block object at a specified address in a ledger. Also returns a proof of the specified block for verification if <code>DigestTipAddress</code> is provided.</p> <p>If the specified ledger doesn't exist or is in <code>DELETING</code> status, then throws <code>ResourceNotFoundException</code>.</p> <p>If the specified ledger is in <code>CREATING</code> status, then throws <code>ResourcePreconditionNotMetException</code>.</p> <p>If no block exists with the specified address, then throws <code>InvalidParameterException</code>.</p>
-    async fn get_block(
+    /// Presigns the request `get_block` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_block(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBlockRequest,
-    ) -> Result<GetBlockResponse, RusotoError<GetBlockError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_block_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_block_request(&self, input: GetBlockRequest) -> SignedRequest {
         let request_uri = format!("/ledgers/{name}/block", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("GetBlock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_digest`, also returning the metadata of the response.
+    pub async fn get_digest_with_metadata(
+        &self,
+        input: GetDigestRequest,
+    ) -> Result<(GetDigestResponse, ResponseMetadata), RusotoError<GetDigestError>> {
+        let request = self.build_get_digest_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBlockResponse, _>()?;
+                .deserialize::<GetDigestResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBlockError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDigestError::from_response(response))
         }
     }
 
-    /// <p>Returns the digest of a ledger at the latest committed block in the journal. The response includes a 256-bit hash value and a block address.</p>
-    async fn get_digest(
+    /// Presigns the request `get_digest` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_digest(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDigestRequest,
-    ) -> Result<GetDigestResponse, RusotoError<GetDigestError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_digest_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_digest_request(&self, input: GetDigestRequest) -> SignedRequest {
         let request_uri = format!("/ledgers/{name}/digest", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("GetDigest");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_revision`, also returning the metadata of the response.
+    pub async fn get_revision_with_metadata(
+        &self,
+        input: GetRevisionRequest,
+    ) -> Result<(GetRevisionResponse, ResponseMetadata), RusotoError<GetRevisionError>> {
+        let request = self.build_get_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDigestResponse, _>()?;
+                .deserialize::<GetRevisionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDigestError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRevisionError::from_response(response))
         }
     }
 
-    /// <p>Returns a revision data object for a specified document ID and block address. Also returns a proof of the specified revision for verification if <code>DigestTipAddress</code> is provided.</p>
-    async fn get_revision(
+    /// Presigns the request `get_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_revision(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRevisionRequest,
-    ) -> Result<GetRevisionResponse, RusotoError<GetRevisionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_revision_request(&self, input: GetRevisionRequest) -> SignedRequest {
         let request_uri = format!("/ledgers/{name}/revision", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("GetRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_journal_s3_exports`, also returning the metadata of the response.
+    pub async fn list_journal_s3_exports_with_metadata(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> Result<
+        (ListJournalS3ExportsResponse, ResponseMetadata),
+        RusotoError<ListJournalS3ExportsError>,
+    > {
+        let request = self.build_list_journal_s3_exports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRevisionResponse, _>()?;
+                .deserialize::<ListJournalS3ExportsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRevisionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListJournalS3ExportsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of journal export job descriptions for all ledgers that are associated with the current AWS account and Region.</p> <p>This action returns a maximum of <code>MaxResults</code> items, and is paginated so that you can retrieve all the items by calling <code>ListJournalS3Exports</code> multiple times.</p>
-    async fn list_journal_s3_exports(
+    /// Presigns the request `list_journal_s3_exports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_journal_s3_exports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJournalS3ExportsRequest,
-    ) -> Result<ListJournalS3ExportsResponse, RusotoError<ListJournalS3ExportsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_journal_s3_exports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_journal_s3_exports_request(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> SignedRequest {
         let request_uri = "/journal-s3-exports";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListJournalS3Exports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1421,34 +1780,61 @@ impl Qldb for QldbClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_journal_s3_exports_for_ledger`, also returning the metadata of the response.
+    pub async fn list_journal_s3_exports_for_ledger_with_metadata(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> Result<
+        (ListJournalS3ExportsForLedgerResponse, ResponseMetadata),
+        RusotoError<ListJournalS3ExportsForLedgerError>,
+    > {
+        let request = self.build_list_journal_s3_exports_for_ledger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListJournalS3ExportsResponse, _>()?;
+                .deserialize::<ListJournalS3ExportsForLedgerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJournalS3ExportsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListJournalS3ExportsForLedgerError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of journal export job descriptions for a specified ledger.</p> <p>This action returns a maximum of <code>MaxResults</code> items, and is paginated so that you can retrieve all the items by calling <code>ListJournalS3ExportsForLedger</code> multiple times.</p>
-    async fn list_journal_s3_exports_for_ledger(
+    /// Presigns the request `list_journal_s3_exports_for_ledger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_journal_s3_exports_for_ledger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJournalS3ExportsForLedgerRequest,
-    ) -> Result<
-        ListJournalS3ExportsForLedgerResponse,
-        RusotoError<ListJournalS3ExportsForLedgerError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_journal_s3_exports_for_ledger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_journal_s3_exports_for_ledger_request(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListJournalS3ExportsForLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1460,31 +1846,55 @@ impl Qldb for QldbClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_ledgers`, also returning the metadata of the response.
+    pub async fn list_ledgers_with_metadata(
+        &self,
+        input: ListLedgersRequest,
+    ) -> Result<(ListLedgersResponse, ResponseMetadata), RusotoError<ListLedgersError>> {
+        let request = self.build_list_ledgers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListJournalS3ExportsForLedgerResponse, _>()?;
+                .deserialize::<ListLedgersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJournalS3ExportsForLedgerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListLedgersError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of ledger summaries that are associated with the current AWS account and Region.</p> <p>This action returns a maximum of 100 items and is paginated so that you can retrieve all the items by calling <code>ListLedgers</code> multiple times.</p>
-    async fn list_ledgers(
+    /// Presigns the request `list_ledgers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_ledgers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLedgersRequest,
-    ) -> Result<ListLedgersResponse, RusotoError<ListLedgersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_ledgers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_ledgers_request(&self, input: ListLedgersRequest) -> SignedRequest {
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListLedgers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1496,88 +1906,166 @@ impl Qldb for QldbClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLedgersResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLedgersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns all tags for a specified Amazon QLDB resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to a specified Amazon QLDB resource.</p> <p>A resource can have up to 50 tags. If you try to create more than 50 tags for a resource, your request fails and returns an error.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from a specified Amazon QLDB resource. You can specify up to 50 tag keys to remove.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1586,50 +2074,216 @@ impl Qldb for QldbClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_ledger`, also returning the metadata of the response.
+    pub async fn update_ledger_with_metadata(
+        &self,
+        input: UpdateLedgerRequest,
+    ) -> Result<(UpdateLedgerResponse, ResponseMetadata), RusotoError<UpdateLedgerError>> {
+        let request = self.build_update_ledger_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateLedgerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateLedgerError::from_response(response))
         }
     }
 
-    /// <p>Updates properties on a ledger.</p>
-    async fn update_ledger(
+    /// Presigns the request `update_ledger` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_ledger(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLedgerRequest,
-    ) -> Result<UpdateLedgerResponse, RusotoError<UpdateLedgerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_ledger_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_ledger_request(&self, input: UpdateLedgerRequest) -> SignedRequest {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PATCH", "qldb", &self.region, &request_uri);
+        request.set_operation("UpdateLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Qldb for QldbClient {
+    /// <p>Creates a new ledger in your AWS account.</p>
+    async fn create_ledger(
+        &self,
+        input: CreateLedgerRequest,
+    ) -> Result<CreateLedgerResponse, RusotoError<CreateLedgerError>> {
+        self.create_ledger_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateLedgerResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLedgerError::from_response(response))
-        }
+    /// <p>Deletes a ledger and all of its contents. This action is irreversible.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
+    async fn delete_ledger(
+        &self,
+        input: DeleteLedgerRequest,
+    ) -> Result<(), RusotoError<DeleteLedgerError>> {
+        self.delete_ledger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a journal export job, including the ledger name, export ID, when it was created, current status, and its start and end time export parameters.</p> <p>If the export job with the given <code>ExportId</code> doesn't exist, then throws <code>ResourceNotFoundException</code>.</p> <p>If the ledger with the given <code>Name</code> doesn't exist, then throws <code>ResourceNotFoundException</code>.</p>
+    async fn describe_journal_s3_export(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> Result<DescribeJournalS3ExportResponse, RusotoError<DescribeJournalS3ExportError>> {
+        self.describe_journal_s3_export_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a ledger, including its state and when it was created.</p>
+    async fn describe_ledger(
+        &self,
+        input: DescribeLedgerRequest,
+    ) -> Result<DescribeLedgerResponse, RusotoError<DescribeLedgerError>> {
+        self.describe_ledger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Exports journal contents within a date and time range from a ledger into a specified Amazon Simple Storage Service (Amazon S3) bucket. The data is written as files in Amazon Ion format.</p> <p>If the ledger with the given <code>Name</code> doesn't exist, then throws <code>ResourceNotFoundException</code>.</p> <p>If the ledger with the given <code>Name</code> is in <code>CREATING</code> status, then throws <code>ResourcePreconditionNotMetException</code>.</p> <p>You can initiate up to two concurrent journal export requests for each ledger. Beyond this limit, journal export requests throw <code>LimitExceededException</code>.</p>
+    async fn export_journal_to_s3(
+        &self,
+        input: ExportJournalToS3Request,
+    ) -> Result<ExportJournalToS3Response, RusotoError<ExportJournalToS3Error>> {
+        self.export_journal_to_s3_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a journal block object at a specified address in a ledger. Also returns a proof of the specified block for verification if <code>DigestTipAddress</code> is provided.</p> <p>If the specified ledger doesn't exist or is in <code>DELETING</code> status, then throws <code>ResourceNotFoundException</code>.</p> <p>If the specified ledger is in <code>CREATING</code> status, then throws <code>ResourcePreconditionNotMetException</code>.</p> <p>If no block exists with the specified address, then throws <code>InvalidParameterException</code>.</p>
+    async fn get_block(
+        &self,
+        input: GetBlockRequest,
+    ) -> Result<GetBlockResponse, RusotoError<GetBlockError>> {
+        self.get_block_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the digest of a ledger at the latest committed block in the journal. The response includes a 256-bit hash value and a block address.</p>
+    async fn get_digest(
+        &self,
+        input: GetDigestRequest,
+    ) -> Result<GetDigestResponse, RusotoError<GetDigestError>> {
+        self.get_digest_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a revision data object for a specified document ID and block address. Also returns a proof of the specified revision for verification if <code>DigestTipAddress</code> is provided.</p>
+    async fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> Result<GetRevisionResponse, RusotoError<GetRevisionError>> {
+        self.get_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of journal export job descriptions for all ledgers that are associated with the current AWS account and Region.</p> <p>This action returns a maximum of <code>MaxResults</code> items, and is paginated so that you can retrieve all the items by calling <code>ListJournalS3Exports</code> multiple times.</p>
+    async fn list_journal_s3_exports(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> Result<ListJournalS3ExportsResponse, RusotoError<ListJournalS3ExportsError>> {
+        self.list_journal_s3_exports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of journal export job descriptions for a specified ledger.</p> <p>This action returns a maximum of <code>MaxResults</code> items, and is paginated so that you can retrieve all the items by calling <code>ListJournalS3ExportsForLedger</code> multiple times.</p>
+    async fn list_journal_s3_exports_for_ledger(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> Result<
+        ListJournalS3ExportsForLedgerResponse,
+        RusotoError<ListJournalS3ExportsForLedgerError>,
+    > {
+        self.list_journal_s3_exports_for_ledger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of ledger summaries that are associated with the current AWS account and Region.</p> <p>This action returns a maximum of 100 items and is paginated so that you can retrieve all the items by calling <code>ListLedgers</code> multiple times.</p>
+    async fn list_ledgers(
+        &self,
+        input: ListLedgersRequest,
+    ) -> Result<ListLedgersResponse, RusotoError<ListLedgersError>> {
+        self.list_ledgers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all tags for a specified Amazon QLDB resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to a specified Amazon QLDB resource.</p> <p>A resource can have up to 50 tags. If you try to create more than 50 tags for a resource, your request fails and returns an error.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from a specified Amazon QLDB resource. You can specify up to 50 tag keys to remove.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates properties on a ledger.</p>
+    async fn update_ledger(
+        &self,
+        input: UpdateLedgerRequest,
+    ) -> Result<UpdateLedgerResponse, RusotoError<UpdateLedgerError>> {
+        self.update_ledger_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -4203,6 +4203,7 @@ pub struct VpcConnectionProperties {
 
 /// Errors returned by CancelIngestion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelIngestionError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -4216,6 +4217,8 @@ pub enum CancelIngestionError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelIngestionError {
@@ -4223,27 +4226,37 @@ impl CancelIngestionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelIngestionError::AccessDenied(err.msg))
+                    return RusotoError::service(CancelIngestionError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CancelIngestionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CancelIngestionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CancelIngestionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelIngestionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CancelIngestionError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        CancelIngestionError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelIngestionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CancelIngestionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelIngestionError::Throttling(err.msg))
+                    return RusotoError::service(CancelIngestionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelIngestionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4259,12 +4272,14 @@ impl fmt::Display for CancelIngestionError {
             CancelIngestionError::ResourceExists(ref cause) => write!(f, "{}", cause),
             CancelIngestionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CancelIngestionError::Throttling(ref cause) => write!(f, "{}", cause),
+            CancelIngestionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelIngestionError {}
 /// Errors returned by CreateDashboard
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDashboardError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -4280,6 +4295,8 @@ pub enum CreateDashboardError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDashboardError {
@@ -4287,32 +4304,43 @@ impl CreateDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDashboardError::Conflict(err.msg))
+                    return RusotoError::service(CreateDashboardError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateDashboardError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateDashboardError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDashboardError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateDashboardError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        CreateDashboardError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDashboardError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDashboardError::Throttling(err.msg))
+                    return RusotoError::service(CreateDashboardError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateDashboardError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDashboardError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDashboardError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4329,12 +4357,14 @@ impl fmt::Display for CreateDashboardError {
             CreateDashboardError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateDashboardError::Throttling(ref cause) => write!(f, "{}", cause),
             CreateDashboardError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            CreateDashboardError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDashboardError {}
 /// Errors returned by CreateDataSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataSetError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -4354,6 +4384,8 @@ pub enum CreateDataSetError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataSetError {
@@ -4361,36 +4393,43 @@ impl CreateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateDataSetError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDataSetError::Conflict(err.msg))
+                    return RusotoError::service(CreateDataSetError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateDataSetError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateDataSetError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        CreateDataSetError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDataSetError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateDataSetError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateDataSetError::ResourceExists(err.msg))
+                    return RusotoError::service(CreateDataSetError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDataSetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSetError::Throttling(err.msg))
+                    return RusotoError::service(CreateDataSetError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateDataSetError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSetError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDataSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4409,12 +4448,14 @@ impl fmt::Display for CreateDataSetError {
             CreateDataSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateDataSetError::Throttling(ref cause) => write!(f, "{}", cause),
             CreateDataSetError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            CreateDataSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataSetError {}
 /// Errors returned by CreateDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataSourceError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -4432,6 +4473,8 @@ pub enum CreateDataSourceError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataSourceError {
@@ -4439,33 +4482,46 @@ impl CreateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateDataSourceError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service(CreateDataSourceError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateDataSourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateDataSourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDataSourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDataSourceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        CreateDataSourceError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(CreateDataSourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4483,12 +4539,14 @@ impl fmt::Display for CreateDataSourceError {
             CreateDataSourceError::ResourceExists(ref cause) => write!(f, "{}", cause),
             CreateDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateDataSourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataSourceError {}
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGroupError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -4508,6 +4566,8 @@ pub enum CreateGroupError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGroupError {
@@ -4515,34 +4575,43 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateGroupError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateGroupError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        CreateGroupError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateGroupError::LimitExceeded(err.msg), &res)
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        CreateGroupError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceExists(err.msg))
+                    return RusotoError::service(CreateGroupError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateGroupError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateGroupError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateGroupError::Throttling(err.msg))
+                    return RusotoError::service(CreateGroupError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4561,12 +4630,14 @@ impl fmt::Display for CreateGroupError {
             CreateGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateGroupError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateGroupError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGroupError {}
 /// Errors returned by CreateGroupMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGroupMembershipError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -4582,6 +4653,8 @@ pub enum CreateGroupMembershipError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGroupMembershipError {
@@ -4589,38 +4662,49 @@ impl CreateGroupMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateGroupMembershipError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGroupMembershipError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGroupMembershipError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGroupMembershipError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGroupMembershipError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGroupMembershipError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateGroupMembershipError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGroupMembershipError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4637,12 +4721,14 @@ impl fmt::Display for CreateGroupMembershipError {
             CreateGroupMembershipError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateGroupMembershipError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateGroupMembershipError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateGroupMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGroupMembershipError {}
 /// Errors returned by CreateIAMPolicyAssignment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIAMPolicyAssignmentError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -4658,6 +4744,8 @@ pub enum CreateIAMPolicyAssignmentError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIAMPolicyAssignmentError {
@@ -4665,42 +4753,51 @@ impl CreateIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIAMPolicyAssignmentError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateIAMPolicyAssignmentError::ConcurrentUpdating(err.msg),
+                        &res,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIAMPolicyAssignmentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIAMPolicyAssignmentError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIAMPolicyAssignmentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIAMPolicyAssignmentError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateIAMPolicyAssignmentError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4719,12 +4816,14 @@ impl fmt::Display for CreateIAMPolicyAssignmentError {
             CreateIAMPolicyAssignmentError::ResourceExists(ref cause) => write!(f, "{}", cause),
             CreateIAMPolicyAssignmentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateIAMPolicyAssignmentError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateIAMPolicyAssignmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIAMPolicyAssignmentError {}
 /// Errors returned by CreateIngestion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIngestionError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -4740,6 +4839,8 @@ pub enum CreateIngestionError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIngestionError {
@@ -4747,30 +4848,40 @@ impl CreateIngestionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIngestionError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateIngestionError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateIngestionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateIngestionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateIngestionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIngestionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIngestionError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateIngestionError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateIngestionError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        CreateIngestionError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIngestionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateIngestionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIngestionError::Throttling(err.msg))
+                    return RusotoError::service(CreateIngestionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateIngestionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4787,12 +4898,14 @@ impl fmt::Display for CreateIngestionError {
             CreateIngestionError::ResourceExists(ref cause) => write!(f, "{}", cause),
             CreateIngestionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateIngestionError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateIngestionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIngestionError {}
 /// Errors returned by CreateTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTemplateError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -4810,6 +4923,8 @@ pub enum CreateTemplateError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTemplateError {
@@ -4817,35 +4932,43 @@ impl CreateTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTemplateError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateTemplateError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateTemplateError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTemplateError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateTemplateError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateTemplateError::ResourceExists(err.msg))
+                    return RusotoError::service(CreateTemplateError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateTemplateError::Throttling(err.msg))
+                    return RusotoError::service(CreateTemplateError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateTemplateError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTemplateError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4863,12 +4986,14 @@ impl fmt::Display for CreateTemplateError {
             CreateTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
             CreateTemplateError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            CreateTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTemplateError {}
 /// Errors returned by CreateTemplateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTemplateAliasError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -4884,6 +5009,8 @@ pub enum CreateTemplateAliasError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTemplateAliasError {
@@ -4891,32 +5018,46 @@ impl CreateTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::Conflict(err.msg))
+                    return RusotoError::service(CreateTemplateAliasError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateTemplateAliasError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateTemplateAliasError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        CreateTemplateAliasError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTemplateAliasError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateTemplateAliasError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTemplateAliasError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTemplateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4933,12 +5074,14 @@ impl fmt::Display for CreateTemplateAliasError {
             CreateTemplateAliasError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateTemplateAliasError::Throttling(ref cause) => write!(f, "{}", cause),
             CreateTemplateAliasError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            CreateTemplateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTemplateAliasError {}
 /// Errors returned by DeleteDashboard
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDashboardError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -4952,6 +5095,8 @@ pub enum DeleteDashboardError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDashboardError {
@@ -4959,29 +5104,37 @@ impl DeleteDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDashboardError::Conflict(err.msg))
+                    return RusotoError::service(DeleteDashboardError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteDashboardError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteDashboardError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDashboardError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDashboardError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDashboardError::Throttling(err.msg))
+                    return RusotoError::service(DeleteDashboardError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteDashboardError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDashboardError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDashboardError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4997,12 +5150,14 @@ impl fmt::Display for DeleteDashboardError {
             DeleteDashboardError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDashboardError::Throttling(ref cause) => write!(f, "{}", cause),
             DeleteDashboardError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            DeleteDashboardError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDashboardError {}
 /// Errors returned by DeleteDataSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDataSetError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5014,6 +5169,8 @@ pub enum DeleteDataSetError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDataSetError {
@@ -5021,22 +5178,28 @@ impl DeleteDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteDataSetError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteDataSetError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteDataSetError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteDataSetError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDataSetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSetError::Throttling(err.msg))
+                    return RusotoError::service(DeleteDataSetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDataSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5051,12 +5214,14 @@ impl fmt::Display for DeleteDataSetError {
             DeleteDataSetError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteDataSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDataSetError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteDataSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDataSetError {}
 /// Errors returned by DeleteDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDataSourceError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5068,6 +5233,8 @@ pub enum DeleteDataSourceError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDataSourceError {
@@ -5075,24 +5242,31 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteDataSourceError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteDataSourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDataSourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(DeleteDataSourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5107,12 +5281,14 @@ impl fmt::Display for DeleteDataSourceError {
             DeleteDataSourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDataSourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDataSourceError {}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGroupError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5128,6 +5304,8 @@ pub enum DeleteGroupError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGroupError {
@@ -5135,28 +5313,37 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteGroupError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteGroupError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteGroupError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        DeleteGroupError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteGroupError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteGroupError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteGroupError::Throttling(err.msg))
+                    return RusotoError::service(DeleteGroupError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5173,12 +5360,14 @@ impl fmt::Display for DeleteGroupError {
             DeleteGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteGroupError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteGroupError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGroupError {}
 /// Errors returned by DeleteGroupMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGroupMembershipError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5194,6 +5383,8 @@ pub enum DeleteGroupMembershipError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGroupMembershipError {
@@ -5201,38 +5392,49 @@ impl DeleteGroupMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteGroupMembershipError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGroupMembershipError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGroupMembershipError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGroupMembershipError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGroupMembershipError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGroupMembershipError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteGroupMembershipError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGroupMembershipError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5249,12 +5451,14 @@ impl fmt::Display for DeleteGroupMembershipError {
             DeleteGroupMembershipError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteGroupMembershipError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteGroupMembershipError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteGroupMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGroupMembershipError {}
 /// Errors returned by DeleteIAMPolicyAssignment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIAMPolicyAssignmentError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5270,6 +5474,8 @@ pub enum DeleteIAMPolicyAssignmentError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIAMPolicyAssignmentError {
@@ -5277,42 +5483,51 @@ impl DeleteIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIAMPolicyAssignmentError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteIAMPolicyAssignmentError::ConcurrentUpdating(err.msg),
+                        &res,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIAMPolicyAssignmentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIAMPolicyAssignmentError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIAMPolicyAssignmentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIAMPolicyAssignmentError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteIAMPolicyAssignmentError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5331,12 +5546,14 @@ impl fmt::Display for DeleteIAMPolicyAssignmentError {
             DeleteIAMPolicyAssignmentError::ResourceExists(ref cause) => write!(f, "{}", cause),
             DeleteIAMPolicyAssignmentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteIAMPolicyAssignmentError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteIAMPolicyAssignmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIAMPolicyAssignmentError {}
 /// Errors returned by DeleteTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTemplateError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -5352,6 +5569,8 @@ pub enum DeleteTemplateError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTemplateError {
@@ -5359,32 +5578,40 @@ impl DeleteTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteTemplateError::Conflict(err.msg))
+                    return RusotoError::service(DeleteTemplateError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteTemplateError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTemplateError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteTemplateError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteTemplateError::Throttling(err.msg))
+                    return RusotoError::service(DeleteTemplateError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteTemplateError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTemplateError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5401,12 +5628,14 @@ impl fmt::Display for DeleteTemplateError {
             DeleteTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
             DeleteTemplateError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            DeleteTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTemplateError {}
 /// Errors returned by DeleteTemplateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTemplateAliasError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -5416,6 +5645,8 @@ pub enum DeleteTemplateAliasError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTemplateAliasError {
@@ -5423,23 +5654,31 @@ impl DeleteTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteTemplateAliasError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTemplateAliasError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteTemplateAliasError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTemplateAliasError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTemplateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5453,12 +5692,14 @@ impl fmt::Display for DeleteTemplateAliasError {
             DeleteTemplateAliasError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTemplateAliasError::Throttling(ref cause) => write!(f, "{}", cause),
             DeleteTemplateAliasError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            DeleteTemplateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTemplateAliasError {}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5472,6 +5713,8 @@ pub enum DeleteUserError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserError {
@@ -5479,25 +5722,31 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteUserError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteUserError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteUserError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteUserError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteUserError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteUserError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserError::Throttling(err.msg))
+                    return RusotoError::service(DeleteUserError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5513,12 +5762,14 @@ impl fmt::Display for DeleteUserError {
             DeleteUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteUserError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteUserError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserError {}
 /// Errors returned by DeleteUserByPrincipalId
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserByPrincipalIdError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5532,6 +5783,8 @@ pub enum DeleteUserByPrincipalIdError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserByPrincipalIdError {
@@ -5539,35 +5792,43 @@ impl DeleteUserByPrincipalIdError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserByPrincipalIdError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserByPrincipalIdError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteUserByPrincipalIdError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserByPrincipalIdError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserByPrincipalIdError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteUserByPrincipalIdError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserByPrincipalIdError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5585,12 +5846,14 @@ impl fmt::Display for DeleteUserByPrincipalIdError {
             DeleteUserByPrincipalIdError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteUserByPrincipalIdError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteUserByPrincipalIdError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteUserByPrincipalIdError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserByPrincipalIdError {}
 /// Errors returned by DescribeDashboard
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDashboardError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5604,6 +5867,8 @@ pub enum DescribeDashboardError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDashboardError {
@@ -5611,29 +5876,40 @@ impl DescribeDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDashboardError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeDashboardError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeDashboardError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeDashboardError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDashboardError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDashboardError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDashboardError::Throttling(err.msg))
+                    return RusotoError::service(DescribeDashboardError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeDashboardError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDashboardError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDashboardError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5649,12 +5925,14 @@ impl fmt::Display for DescribeDashboardError {
             DescribeDashboardError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDashboardError::Throttling(ref cause) => write!(f, "{}", cause),
             DescribeDashboardError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            DescribeDashboardError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDashboardError {}
 /// Errors returned by DescribeDashboardPermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDashboardPermissionsError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -5666,6 +5944,8 @@ pub enum DescribeDashboardPermissionsError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDashboardPermissionsError {
@@ -5675,32 +5955,42 @@ impl DescribeDashboardPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDashboardPermissionsError::InternalFailure(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDashboardPermissionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDashboardPermissionsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDashboardPermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDashboardPermissionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDashboardPermissionsError::UnsupportedUserEdition(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDashboardPermissionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5721,12 +6011,14 @@ impl fmt::Display for DescribeDashboardPermissionsError {
             DescribeDashboardPermissionsError::UnsupportedUserEdition(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDashboardPermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDashboardPermissionsError {}
 /// Errors returned by DescribeDataSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDataSetError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5738,6 +6030,8 @@ pub enum DescribeDataSetError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDataSetError {
@@ -5745,24 +6039,31 @@ impl DescribeDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeDataSetError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSetError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeDataSetError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDataSetError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSetError::Throttling(err.msg))
+                    return RusotoError::service(DescribeDataSetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDataSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5777,12 +6078,14 @@ impl fmt::Display for DescribeDataSetError {
             DescribeDataSetError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeDataSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDataSetError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDataSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDataSetError {}
 /// Errors returned by DescribeDataSetPermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDataSetPermissionsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5794,6 +6097,8 @@ pub enum DescribeDataSetPermissionsError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDataSetPermissionsError {
@@ -5803,32 +6108,39 @@ impl DescribeDataSetPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDataSetPermissionsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDataSetPermissionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDataSetPermissionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDataSetPermissionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDataSetPermissionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeDataSetPermissionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5845,12 +6157,14 @@ impl fmt::Display for DescribeDataSetPermissionsError {
             }
             DescribeDataSetPermissionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDataSetPermissionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDataSetPermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDataSetPermissionsError {}
 /// Errors returned by DescribeDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDataSourceError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5862,6 +6176,8 @@ pub enum DescribeDataSourceError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDataSourceError {
@@ -5869,24 +6185,34 @@ impl DescribeDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeDataSourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDataSourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(DescribeDataSourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5901,12 +6227,14 @@ impl fmt::Display for DescribeDataSourceError {
             DescribeDataSourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDataSourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDataSourceError {}
 /// Errors returned by DescribeDataSourcePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDataSourcePermissionsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5918,6 +6246,8 @@ pub enum DescribeDataSourcePermissionsError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDataSourcePermissionsError {
@@ -5927,32 +6257,42 @@ impl DescribeDataSourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSourcePermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDataSourcePermissionsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDataSourcePermissionsError::InternalFailure(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDataSourcePermissionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDataSourcePermissionsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSourcePermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDataSourcePermissionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDataSourcePermissionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5973,12 +6313,16 @@ impl fmt::Display for DescribeDataSourcePermissionsError {
                 write!(f, "{}", cause)
             }
             DescribeDataSourcePermissionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDataSourcePermissionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDataSourcePermissionsError {}
 /// Errors returned by DescribeGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeGroupError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -5994,6 +6338,8 @@ pub enum DescribeGroupError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeGroupError {
@@ -6001,28 +6347,40 @@ impl DescribeGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeGroupError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(DescribeGroupError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DescribeGroupError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DescribeGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        DescribeGroupError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeGroupError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeGroupError::Throttling(err.msg))
+                    return RusotoError::service(DescribeGroupError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6039,12 +6397,14 @@ impl fmt::Display for DescribeGroupError {
             DescribeGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeGroupError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeGroupError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeGroupError {}
 /// Errors returned by DescribeIAMPolicyAssignment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeIAMPolicyAssignmentError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6058,6 +6418,8 @@ pub enum DescribeIAMPolicyAssignmentError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeIAMPolicyAssignmentError {
@@ -6067,37 +6429,48 @@ impl DescribeIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIAMPolicyAssignmentError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIAMPolicyAssignmentError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeIAMPolicyAssignmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIAMPolicyAssignmentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeIAMPolicyAssignmentError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeIAMPolicyAssignmentError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIAMPolicyAssignmentError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIAMPolicyAssignmentError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeIAMPolicyAssignmentError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6115,12 +6488,14 @@ impl fmt::Display for DescribeIAMPolicyAssignmentError {
             }
             DescribeIAMPolicyAssignmentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeIAMPolicyAssignmentError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeIAMPolicyAssignmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeIAMPolicyAssignmentError {}
 /// Errors returned by DescribeIngestion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeIngestionError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6134,6 +6509,8 @@ pub enum DescribeIngestionError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeIngestionError {
@@ -6141,27 +6518,40 @@ impl DescribeIngestionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIngestionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeIngestionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeIngestionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeIngestionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeIngestionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIngestionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DescribeIngestionError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        DescribeIngestionError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIngestionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeIngestionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIngestionError::Throttling(err.msg))
+                    return RusotoError::service(DescribeIngestionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeIngestionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6177,12 +6567,14 @@ impl fmt::Display for DescribeIngestionError {
             DescribeIngestionError::ResourceExists(ref cause) => write!(f, "{}", cause),
             DescribeIngestionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeIngestionError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeIngestionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeIngestionError {}
 /// Errors returned by DescribeTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTemplateError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6200,6 +6592,8 @@ pub enum DescribeTemplateError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTemplateError {
@@ -6207,35 +6601,46 @@ impl DescribeTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeTemplateError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeTemplateError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeTemplateError::Conflict(err.msg))
+                    return RusotoError::service(DescribeTemplateError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeTemplateError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTemplateError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DescribeTemplateError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        DescribeTemplateError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeTemplateError::Throttling(err.msg))
+                    return RusotoError::service(DescribeTemplateError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeTemplateError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTemplateError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6253,12 +6658,14 @@ impl fmt::Display for DescribeTemplateError {
             DescribeTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
             DescribeTemplateError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            DescribeTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTemplateError {}
 /// Errors returned by DescribeTemplateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTemplateAliasError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -6268,6 +6675,8 @@ pub enum DescribeTemplateAliasError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTemplateAliasError {
@@ -6275,25 +6684,31 @@ impl DescribeTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeTemplateAliasError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTemplateAliasError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTemplateAliasError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTemplateAliasError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeTemplateAliasError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTemplateAliasError::UnsupportedUserEdition(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTemplateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6307,12 +6722,14 @@ impl fmt::Display for DescribeTemplateAliasError {
             DescribeTemplateAliasError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTemplateAliasError::Throttling(ref cause) => write!(f, "{}", cause),
             DescribeTemplateAliasError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            DescribeTemplateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTemplateAliasError {}
 /// Errors returned by DescribeTemplatePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTemplatePermissionsError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -6326,6 +6743,8 @@ pub enum DescribeTemplatePermissionsError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTemplatePermissionsError {
@@ -6335,37 +6754,48 @@ impl DescribeTemplatePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeTemplatePermissionsError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTemplatePermissionsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeTemplatePermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTemplatePermissionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTemplatePermissionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTemplatePermissionsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeTemplatePermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTemplatePermissionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTemplatePermissionsError::UnsupportedUserEdition(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeTemplatePermissionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6385,12 +6815,14 @@ impl fmt::Display for DescribeTemplatePermissionsError {
             DescribeTemplatePermissionsError::UnsupportedUserEdition(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTemplatePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTemplatePermissionsError {}
 /// Errors returned by DescribeUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6404,6 +6836,8 @@ pub enum DescribeUserError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserError {
@@ -6411,25 +6845,31 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeUserError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeUserError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeUserError::InternalFailure(err.msg))
+                    return RusotoError::service(DescribeUserError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DescribeUserError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeUserError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserError::Throttling(err.msg))
+                    return RusotoError::service(DescribeUserError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6445,12 +6885,14 @@ impl fmt::Display for DescribeUserError {
             DescribeUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeUserError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeUserError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserError {}
 /// Errors returned by GetDashboardEmbedUrl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDashboardEmbedUrlError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6474,6 +6916,8 @@ pub enum GetDashboardEmbedUrlError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDashboardEmbedUrlError {
@@ -6481,56 +6925,73 @@ impl GetDashboardEmbedUrlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetDashboardEmbedUrlError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DomainNotWhitelistedException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::DomainNotWhitelisted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDashboardEmbedUrlError::DomainNotWhitelisted(err.msg),
+                        &res,
+                    )
                 }
                 "IdentityTypeNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDashboardEmbedUrlError::IdentityTypeNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDashboardEmbedUrlError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDashboardEmbedUrlError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "QuickSightUserNotFoundException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::QuickSightUserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDashboardEmbedUrlError::QuickSightUserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        GetDashboardEmbedUrlError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDashboardEmbedUrlError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "SessionLifetimeInMinutesInvalidException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDashboardEmbedUrlError::SessionLifetimeInMinutesInvalid(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetDashboardEmbedUrlError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDashboardEmbedUrlError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDashboardEmbedUrlError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6555,12 +7016,14 @@ impl fmt::Display for GetDashboardEmbedUrlError {
             }
             GetDashboardEmbedUrlError::Throttling(ref cause) => write!(f, "{}", cause),
             GetDashboardEmbedUrlError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            GetDashboardEmbedUrlError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDashboardEmbedUrlError {}
 /// Errors returned by ListDashboardVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDashboardVersionsError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -6574,6 +7037,8 @@ pub enum ListDashboardVersionsError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDashboardVersionsError {
@@ -6581,35 +7046,43 @@ impl ListDashboardVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDashboardVersionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDashboardVersionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDashboardVersionsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDashboardVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListDashboardVersionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDashboardVersionsError::UnsupportedUserEdition(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDashboardVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6625,12 +7098,14 @@ impl fmt::Display for ListDashboardVersionsError {
             ListDashboardVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDashboardVersionsError::Throttling(ref cause) => write!(f, "{}", cause),
             ListDashboardVersionsError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            ListDashboardVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDashboardVersionsError {}
 /// Errors returned by ListDashboards
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDashboardsError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -6640,6 +7115,8 @@ pub enum ListDashboardsError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDashboardsError {
@@ -6647,21 +7124,28 @@ impl ListDashboardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDashboardsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListDashboardsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDashboardsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListDashboardsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDashboardsError::Throttling(err.msg))
+                    return RusotoError::service(ListDashboardsError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListDashboardsError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDashboardsError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDashboardsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6675,12 +7159,14 @@ impl fmt::Display for ListDashboardsError {
             ListDashboardsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListDashboardsError::Throttling(ref cause) => write!(f, "{}", cause),
             ListDashboardsError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            ListDashboardsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDashboardsError {}
 /// Errors returned by ListDataSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDataSetsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6692,6 +7178,8 @@ pub enum ListDataSetsError {
     InvalidParameterValue(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDataSetsError {
@@ -6699,22 +7187,25 @@ impl ListDataSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSetsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListDataSetsError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDataSetsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListDataSetsError::InternalFailure(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDataSetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListDataSetsError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDataSetsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListDataSetsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSetsError::Throttling(err.msg))
+                    return RusotoError::service(ListDataSetsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDataSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6729,12 +7220,14 @@ impl fmt::Display for ListDataSetsError {
             ListDataSetsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListDataSetsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListDataSetsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDataSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDataSetsError {}
 /// Errors returned by ListDataSources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDataSourcesError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6746,6 +7239,8 @@ pub enum ListDataSourcesError {
     InvalidParameterValue(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDataSourcesError {
@@ -6753,24 +7248,31 @@ impl ListDataSourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListDataSourcesError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDataSourcesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListDataSourcesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDataSourcesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListDataSourcesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDataSourcesError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDataSourcesError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSourcesError::Throttling(err.msg))
+                    return RusotoError::service(ListDataSourcesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDataSourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6785,12 +7287,14 @@ impl fmt::Display for ListDataSourcesError {
             ListDataSourcesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListDataSourcesError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListDataSourcesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDataSourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDataSourcesError {}
 /// Errors returned by ListGroupMemberships
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGroupMembershipsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6808,6 +7312,8 @@ pub enum ListGroupMembershipsError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGroupMembershipsError {
@@ -6815,43 +7321,55 @@ impl ListGroupMembershipsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListGroupMembershipsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListGroupMembershipsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListGroupMembershipsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListGroupMembershipsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListGroupMembershipsError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListGroupMembershipsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListGroupMembershipsError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListGroupMembershipsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGroupMembershipsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6869,12 +7387,14 @@ impl fmt::Display for ListGroupMembershipsError {
             ListGroupMembershipsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListGroupMembershipsError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             ListGroupMembershipsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListGroupMembershipsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGroupMembershipsError {}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGroupsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6892,6 +7412,8 @@ pub enum ListGroupsError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGroupsError {
@@ -6899,31 +7421,37 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListGroupsError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListGroupsError::InternalFailure(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListGroupsError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListGroupsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(ListGroupsError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(ListGroupsError::PreconditionNotMet(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListGroupsError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListGroupsError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListGroupsError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListGroupsError::Throttling(err.msg))
+                    return RusotoError::service(ListGroupsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6941,12 +7469,14 @@ impl fmt::Display for ListGroupsError {
             ListGroupsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListGroupsError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             ListGroupsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGroupsError {}
 /// Errors returned by ListIAMPolicyAssignments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIAMPolicyAssignmentsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -6960,6 +7490,8 @@ pub enum ListIAMPolicyAssignmentsError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIAMPolicyAssignmentsError {
@@ -6967,35 +7499,43 @@ impl ListIAMPolicyAssignmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIAMPolicyAssignmentsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIAMPolicyAssignmentsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIAMPolicyAssignmentsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListIAMPolicyAssignmentsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIAMPolicyAssignmentsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListIAMPolicyAssignmentsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIAMPolicyAssignmentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7013,12 +7553,14 @@ impl fmt::Display for ListIAMPolicyAssignmentsError {
             }
             ListIAMPolicyAssignmentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListIAMPolicyAssignmentsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListIAMPolicyAssignmentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIAMPolicyAssignmentsError {}
 /// Errors returned by ListIAMPolicyAssignmentsForUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIAMPolicyAssignmentsForUserError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -7034,6 +7576,8 @@ pub enum ListIAMPolicyAssignmentsForUserError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIAMPolicyAssignmentsForUserError {
@@ -7043,42 +7587,54 @@ impl ListIAMPolicyAssignmentsForUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListIAMPolicyAssignmentsForUserError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListIAMPolicyAssignmentsForUserError::ConcurrentUpdating(err.msg),
+                        &res,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListIAMPolicyAssignmentsForUserError::InternalFailure(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListIAMPolicyAssignmentsForUserError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListIAMPolicyAssignmentsForUserError::ResourceExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListIAMPolicyAssignmentsForUserError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsForUserError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIAMPolicyAssignmentsForUserError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListIAMPolicyAssignmentsForUserError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7105,12 +7661,16 @@ impl fmt::Display for ListIAMPolicyAssignmentsForUserError {
                 write!(f, "{}", cause)
             }
             ListIAMPolicyAssignmentsForUserError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListIAMPolicyAssignmentsForUserError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListIAMPolicyAssignmentsForUserError {}
 /// Errors returned by ListIngestions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIngestionsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -7126,6 +7686,8 @@ pub enum ListIngestionsError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIngestionsError {
@@ -7133,30 +7695,40 @@ impl ListIngestionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIngestionsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListIngestionsError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListIngestionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListIngestionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIngestionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListIngestionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListIngestionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIngestionsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(ListIngestionsError::ResourceExists(err.msg))
+                    return RusotoError::service(ListIngestionsError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIngestionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListIngestionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIngestionsError::Throttling(err.msg))
+                    return RusotoError::service(ListIngestionsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIngestionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7173,12 +7745,14 @@ impl fmt::Display for ListIngestionsError {
             ListIngestionsError::ResourceExists(ref cause) => write!(f, "{}", cause),
             ListIngestionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListIngestionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListIngestionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIngestionsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -7190,6 +7764,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -7197,26 +7773,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7231,12 +7818,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTemplateAliases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTemplateAliasesError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -7246,6 +7835,8 @@ pub enum ListTemplateAliasesError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTemplateAliasesError {
@@ -7253,23 +7844,31 @@ impl ListTemplateAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTemplateAliasesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTemplateAliasesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTemplateAliasesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTemplateAliasesError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTemplateAliasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7283,12 +7882,14 @@ impl fmt::Display for ListTemplateAliasesError {
             ListTemplateAliasesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTemplateAliasesError::Throttling(ref cause) => write!(f, "{}", cause),
             ListTemplateAliasesError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            ListTemplateAliasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTemplateAliasesError {}
 /// Errors returned by ListTemplateVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTemplateVersionsError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -7302,6 +7903,8 @@ pub enum ListTemplateVersionsError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTemplateVersionsError {
@@ -7309,35 +7912,43 @@ impl ListTemplateVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTemplateVersionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTemplateVersionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTemplateVersionsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTemplateVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTemplateVersionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTemplateVersionsError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTemplateVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7353,12 +7964,14 @@ impl fmt::Display for ListTemplateVersionsError {
             ListTemplateVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTemplateVersionsError::Throttling(ref cause) => write!(f, "{}", cause),
             ListTemplateVersionsError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            ListTemplateVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTemplateVersionsError {}
 /// Errors returned by ListTemplates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTemplatesError {
     /// <p>An internal failure occurred.</p>
     InternalFailure(String),
@@ -7372,6 +7985,8 @@ pub enum ListTemplatesError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTemplatesError {
@@ -7379,27 +7994,37 @@ impl ListTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTemplatesError::InternalFailure(err.msg))
+                    return RusotoError::service(ListTemplatesError::InternalFailure(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTemplatesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListTemplatesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTemplatesError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListTemplatesError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTemplatesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTemplatesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTemplatesError::Throttling(err.msg))
+                    return RusotoError::service(ListTemplatesError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListTemplatesError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTemplatesError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTemplatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7415,12 +8040,14 @@ impl fmt::Display for ListTemplatesError {
             ListTemplatesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTemplatesError::Throttling(ref cause) => write!(f, "{}", cause),
             ListTemplatesError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            ListTemplatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTemplatesError {}
 /// Errors returned by ListUserGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUserGroupsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -7434,6 +8061,8 @@ pub enum ListUserGroupsError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUserGroupsError {
@@ -7441,27 +8070,37 @@ impl ListUserGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListUserGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListUserGroupsError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListUserGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListUserGroupsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListUserGroupsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListUserGroupsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListUserGroupsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListUserGroupsError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListUserGroupsError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUserGroupsError::Throttling(err.msg))
+                    return RusotoError::service(ListUserGroupsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUserGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7477,12 +8116,14 @@ impl fmt::Display for ListUserGroupsError {
             ListUserGroupsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUserGroupsError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             ListUserGroupsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListUserGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUserGroupsError {}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUsersError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -7498,6 +8139,8 @@ pub enum ListUsersError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUsersError {
@@ -7505,28 +8148,31 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListUsersError::AccessDenied(err.msg))
+                    return RusotoError::service(ListUsersError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListUsersError::InternalFailure(err.msg))
+                    return RusotoError::service(ListUsersError::InternalFailure(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListUsersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListUsersError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListUsersError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListUsersError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListUsersError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(ListUsersError::ResourceUnavailable(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUsersError::Throttling(err.msg))
+                    return RusotoError::service(ListUsersError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7543,12 +8189,14 @@ impl fmt::Display for ListUsersError {
             ListUsersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUsersError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             ListUsersError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUsersError {}
 /// Errors returned by RegisterUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterUserError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -7568,6 +8216,8 @@ pub enum RegisterUserError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterUserError {
@@ -7575,34 +8225,43 @@ impl RegisterUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterUserError::AccessDenied(err.msg))
+                    return RusotoError::service(RegisterUserError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RegisterUserError::InternalFailure(err.msg))
+                    return RusotoError::service(RegisterUserError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RegisterUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        RegisterUserError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterUserError::LimitExceeded(err.msg))
+                    return RusotoError::service(RegisterUserError::LimitExceeded(err.msg), &res)
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(RegisterUserError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        RegisterUserError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(RegisterUserError::ResourceExists(err.msg))
+                    return RusotoError::service(RegisterUserError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(RegisterUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(RegisterUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        RegisterUserError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterUserError::Throttling(err.msg))
+                    return RusotoError::service(RegisterUserError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7621,12 +8280,14 @@ impl fmt::Display for RegisterUserError {
             RegisterUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RegisterUserError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             RegisterUserError::Throttling(ref cause) => write!(f, "{}", cause),
+            RegisterUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterUserError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -7640,6 +8301,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -7647,25 +8310,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(TagResourceError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(TagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(TagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7681,12 +8347,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -7698,6 +8366,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -7705,22 +8375,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UntagResourceError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(UntagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7735,12 +8411,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDashboard
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDashboardError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -7756,6 +8434,8 @@ pub enum UpdateDashboardError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDashboardError {
@@ -7763,32 +8443,40 @@ impl UpdateDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDashboardError::Conflict(err.msg))
+                    return RusotoError::service(UpdateDashboardError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateDashboardError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateDashboardError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDashboardError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDashboardError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateDashboardError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDashboardError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDashboardError::Throttling(err.msg))
+                    return RusotoError::service(UpdateDashboardError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateDashboardError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDashboardError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDashboardError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7805,12 +8493,14 @@ impl fmt::Display for UpdateDashboardError {
             UpdateDashboardError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDashboardError::Throttling(ref cause) => write!(f, "{}", cause),
             UpdateDashboardError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            UpdateDashboardError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDashboardError {}
 /// Errors returned by UpdateDashboardPermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDashboardPermissionsError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -7824,6 +8514,8 @@ pub enum UpdateDashboardPermissionsError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDashboardPermissionsError {
@@ -7833,35 +8525,45 @@ impl UpdateDashboardPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateDashboardPermissionsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDashboardPermissionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDashboardPermissionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDashboardPermissionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDashboardPermissionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDashboardPermissionsError::UnsupportedUserEdition(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateDashboardPermissionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7881,12 +8583,14 @@ impl fmt::Display for UpdateDashboardPermissionsError {
             UpdateDashboardPermissionsError::UnsupportedUserEdition(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateDashboardPermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDashboardPermissionsError {}
 /// Errors returned by UpdateDashboardPublishedVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDashboardPublishedVersionError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -7900,6 +8604,8 @@ pub enum UpdateDashboardPublishedVersionError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDashboardPublishedVersionError {
@@ -7909,37 +8615,48 @@ impl UpdateDashboardPublishedVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDashboardPublishedVersionError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDashboardPublishedVersionError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDashboardPublishedVersionError::InternalFailure(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDashboardPublishedVersionError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDashboardPublishedVersionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDashboardPublishedVersionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDashboardPublishedVersionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDashboardPublishedVersionError::UnsupportedUserEdition(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateDashboardPublishedVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7963,12 +8680,16 @@ impl fmt::Display for UpdateDashboardPublishedVersionError {
             UpdateDashboardPublishedVersionError::UnsupportedUserEdition(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateDashboardPublishedVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateDashboardPublishedVersionError {}
 /// Errors returned by UpdateDataSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDataSetError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -7986,6 +8707,8 @@ pub enum UpdateDataSetError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDataSetError {
@@ -7993,33 +8716,40 @@ impl UpdateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateDataSetError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSetError::Conflict(err.msg))
+                    return RusotoError::service(UpdateDataSetError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateDataSetError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateDataSetError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSetError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDataSetError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateDataSetError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSetError::Throttling(err.msg))
+                    return RusotoError::service(UpdateDataSetError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateDataSetError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSetError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDataSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8037,12 +8767,14 @@ impl fmt::Display for UpdateDataSetError {
             UpdateDataSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDataSetError::Throttling(ref cause) => write!(f, "{}", cause),
             UpdateDataSetError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            UpdateDataSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDataSetError {}
 /// Errors returned by UpdateDataSetPermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDataSetPermissionsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -8056,6 +8788,8 @@ pub enum UpdateDataSetPermissionsError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDataSetPermissionsError {
@@ -8063,33 +8797,43 @@ impl UpdateDataSetPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSetPermissionsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSetPermissionsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSetPermissionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDataSetPermissionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSetPermissionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSetPermissionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDataSetPermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8107,12 +8851,14 @@ impl fmt::Display for UpdateDataSetPermissionsError {
             }
             UpdateDataSetPermissionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDataSetPermissionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDataSetPermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDataSetPermissionsError {}
 /// Errors returned by UpdateDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDataSourceError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -8126,6 +8872,8 @@ pub enum UpdateDataSourceError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDataSourceError {
@@ -8133,27 +8881,34 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8169,12 +8924,14 @@ impl fmt::Display for UpdateDataSourceError {
             UpdateDataSourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             UpdateDataSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDataSourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDataSourceError {}
 /// Errors returned by UpdateDataSourcePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDataSourcePermissionsError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -8188,6 +8945,8 @@ pub enum UpdateDataSourcePermissionsError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDataSourcePermissionsError {
@@ -8197,37 +8956,48 @@ impl UpdateDataSourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSourcePermissionsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSourcePermissionsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSourcePermissionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDataSourcePermissionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDataSourcePermissionsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSourcePermissionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateDataSourcePermissionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8245,12 +9015,14 @@ impl fmt::Display for UpdateDataSourcePermissionsError {
             }
             UpdateDataSourcePermissionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDataSourcePermissionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDataSourcePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDataSourcePermissionsError {}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGroupError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -8266,6 +9038,8 @@ pub enum UpdateGroupError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGroupError {
@@ -8273,28 +9047,37 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateGroupError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateGroupError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UpdateGroupError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(UpdateGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        UpdateGroupError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateGroupError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateGroupError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateGroupError::Throttling(err.msg))
+                    return RusotoError::service(UpdateGroupError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8311,12 +9094,14 @@ impl fmt::Display for UpdateGroupError {
             UpdateGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateGroupError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateGroupError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGroupError {}
 /// Errors returned by UpdateIAMPolicyAssignment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIAMPolicyAssignmentError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -8332,6 +9117,8 @@ pub enum UpdateIAMPolicyAssignmentError {
     ResourceNotFound(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIAMPolicyAssignmentError {
@@ -8339,42 +9126,51 @@ impl UpdateIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIAMPolicyAssignmentError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateIAMPolicyAssignmentError::ConcurrentUpdating(err.msg),
+                        &res,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIAMPolicyAssignmentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIAMPolicyAssignmentError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIAMPolicyAssignmentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIAMPolicyAssignmentError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateIAMPolicyAssignmentError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8393,12 +9189,14 @@ impl fmt::Display for UpdateIAMPolicyAssignmentError {
             UpdateIAMPolicyAssignmentError::ResourceExists(ref cause) => write!(f, "{}", cause),
             UpdateIAMPolicyAssignmentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateIAMPolicyAssignmentError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateIAMPolicyAssignmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateIAMPolicyAssignmentError {}
 /// Errors returned by UpdateTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTemplateError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -8416,6 +9214,8 @@ pub enum UpdateTemplateError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTemplateError {
@@ -8423,35 +9223,43 @@ impl UpdateTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTemplateError::Conflict(err.msg))
+                    return RusotoError::service(UpdateTemplateError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateTemplateError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateTemplateError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTemplateError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateTemplateError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateTemplateError::ResourceExists(err.msg))
+                    return RusotoError::service(UpdateTemplateError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateTemplateError::Throttling(err.msg))
+                    return RusotoError::service(UpdateTemplateError::Throttling(err.msg), &res)
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateTemplateError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTemplateError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8469,12 +9277,14 @@ impl fmt::Display for UpdateTemplateError {
             UpdateTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateTemplateError::Throttling(ref cause) => write!(f, "{}", cause),
             UpdateTemplateError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            UpdateTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTemplateError {}
 /// Errors returned by UpdateTemplateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTemplateAliasError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -8486,6 +9296,8 @@ pub enum UpdateTemplateAliasError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTemplateAliasError {
@@ -8493,26 +9305,34 @@ impl UpdateTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::Conflict(err.msg))
+                    return RusotoError::service(UpdateTemplateAliasError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateTemplateAliasError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTemplateAliasError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateTemplateAliasError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTemplateAliasError::UnsupportedUserEdition(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTemplateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8527,12 +9347,14 @@ impl fmt::Display for UpdateTemplateAliasError {
             UpdateTemplateAliasError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateTemplateAliasError::Throttling(ref cause) => write!(f, "{}", cause),
             UpdateTemplateAliasError::UnsupportedUserEdition(ref cause) => write!(f, "{}", cause),
+            UpdateTemplateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTemplateAliasError {}
 /// Errors returned by UpdateTemplatePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTemplatePermissionsError {
     /// <p>Updating or deleting a resource can cause an inconsistent state.</p>
     Conflict(String),
@@ -8546,6 +9368,8 @@ pub enum UpdateTemplatePermissionsError {
     Throttling(String),
     /// <p>This error indicates that you are calling an operation on an Amazon QuickSight subscription where the edition doesn't include support for that operation. Amazon QuickSight currently has Standard Edition and Enterprise Edition. Not every operation and capability is available in every edition.</p>
     UnsupportedUserEdition(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTemplatePermissionsError {
@@ -8553,35 +9377,45 @@ impl UpdateTemplatePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateTemplatePermissionsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTemplatePermissionsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateTemplatePermissionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTemplatePermissionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTemplatePermissionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateTemplatePermissionsError::UnsupportedUserEdition(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateTemplatePermissionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8601,12 +9435,14 @@ impl fmt::Display for UpdateTemplatePermissionsError {
             UpdateTemplatePermissionsError::UnsupportedUserEdition(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateTemplatePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTemplatePermissionsError {}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserError {
     /// <p>You don't have access to this item. The provided credentials couldn't be validated. You might not be authorized to carry out the request. Make sure that your account is authorized to use the Amazon QuickSight service, that your policies have the correct permissions, and that you are using the correct access keys.</p>
     AccessDenied(String),
@@ -8620,6 +9456,8 @@ pub enum UpdateUserError {
     ResourceUnavailable(String),
     /// <p>Access is throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserError {
@@ -8627,25 +9465,31 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateUserError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateUserError::AccessDenied(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateUserError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateUserError::InternalFailure(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UpdateUserError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateUserError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserError::Throttling(err.msg))
+                    return RusotoError::service(UpdateUserError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8661,6 +9505,7 @@ impl fmt::Display for UpdateUserError {
             UpdateUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateUserError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateUserError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -9078,6 +9923,7 @@ impl QuicksightClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> QuicksightClient {
         QuicksightClient {
             client: Client::shared(),
@@ -9103,15 +9949,68 @@ impl QuicksightClient {
     pub fn new_with_client(client: Client, region: region::Region) -> QuicksightClient {
         QuicksightClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<QuicksightClient> {
+        rusoto_core::ClientBuilder::new(QuicksightClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> QuicksightClient {
+        QuicksightClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Quicksight for QuicksightClient {
-    /// <p>Cancels an ongoing ingestion of data into SPICE.</p>
-    async fn cancel_ingestion(
+impl QuicksightClient {
+    /// Calls `cancel_ingestion`, also returning the metadata of the response.
+    pub async fn cancel_ingestion_with_metadata(
         &self,
         input: CancelIngestionRequest,
-    ) -> Result<CancelIngestionResponse, RusotoError<CancelIngestionError>> {
+    ) -> Result<(CancelIngestionResponse, ResponseMetadata), RusotoError<CancelIngestionError>>
+    {
+        let request = self.build_cancel_ingestion_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let mut result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelIngestionResponse, _>()?;
+
+            result.status = Some(response.status.as_u16() as i64);
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CancelIngestionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_ingestion` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_ingestion(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelIngestionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_ingestion_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_ingestion_request(&self, input: CancelIngestionRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets/{data_set_id}/ingestions/{ingestion_id}",
             aws_account_id = input.aws_account_id,
@@ -9120,31 +10019,56 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("CancelIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `create_dashboard`, also returning the metadata of the response.
+    pub async fn create_dashboard_with_metadata(
+        &self,
+        input: CreateDashboardRequest,
+    ) -> Result<(CreateDashboardResponse, ResponseMetadata), RusotoError<CreateDashboardError>>
+    {
+        let request = self.build_create_dashboard_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelIngestionResponse, _>()?;
+                .deserialize::<CreateDashboardResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelIngestionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDashboardError::from_response(response))
         }
     }
 
-    /// <p>Creates a dashboard from a template. To first create a template, see the CreateTemplate API operation.</p> <p>A dashboard is an entity in QuickSight that identifies QuickSight reports, created from analyses. You can share QuickSight dashboards. With the right permissions, you can create scheduled email reports from them. The <code>CreateDashboard</code>, <code>DescribeDashboard</code>, and <code>ListDashboardsByUser</code> API operations act on the dashboard entity. If you have the correct permissions, you can create a dashboard from a template that exists in a different AWS account.</p>
-    async fn create_dashboard(
+    /// Presigns the request `create_dashboard` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dashboard(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDashboardRequest,
-    ) -> Result<CreateDashboardResponse, RusotoError<CreateDashboardError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dashboard_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dashboard_request(&self, input: CreateDashboardRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards/{dashboard_id}",
             aws_account_id = input.aws_account_id,
@@ -9152,102 +10076,175 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_data_set`, also returning the metadata of the response.
+    pub async fn create_data_set_with_metadata(
+        &self,
+        input: CreateDataSetRequest,
+    ) -> Result<(CreateDataSetResponse, ResponseMetadata), RusotoError<CreateDataSetError>> {
+        let request = self.build_create_data_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDashboardResponse, _>()?;
+                .deserialize::<CreateDataSetResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDashboardError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDataSetError::from_response(response))
         }
     }
 
-    /// <p>Creates a dataset.</p>
-    async fn create_data_set(
+    /// Presigns the request `create_data_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_data_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataSetRequest,
-    ) -> Result<CreateDataSetResponse, RusotoError<CreateDataSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_data_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_data_set_request(&self, input: CreateDataSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets",
             aws_account_id = input.aws_account_id
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_data_source`, also returning the metadata of the response.
+    pub async fn create_data_source_with_metadata(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<(CreateDataSourceResponse, ResponseMetadata), RusotoError<CreateDataSourceError>>
+    {
+        let request = self.build_create_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDataSetResponse, _>()?;
+                .deserialize::<CreateDataSourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Creates a data source.</p>
-    async fn create_data_source(
+    /// Presigns the request `create_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataSourceRequest,
-    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_data_source_request(&self, input: CreateDataSourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sources",
             aws_account_id = input.aws_account_id
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_group`, also returning the metadata of the response.
+    pub async fn create_group_with_metadata(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<(CreateGroupResponse, ResponseMetadata), RusotoError<CreateGroupError>> {
+        let request = self.build_create_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDataSourceResponse, _>()?;
+                .deserialize::<CreateGroupResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataSourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon QuickSight group.</p> <p>The permissions resource is <code>arn:aws:quicksight:us-east-1:<i>&lt;relevant-aws-account-id&gt;</i>:group/default/<i>&lt;group-name&gt;</i> </code>.</p> <p>The response is a group object.</p>
-    async fn create_group(
+    /// Presigns the request `create_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGroupRequest,
-    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_group_request(&self, input: CreateGroupRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/groups",
             aws_account_id = input.aws_account_id,
@@ -9255,63 +10252,122 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_group_membership`, also returning the metadata of the response.
+    pub async fn create_group_membership_with_metadata(
+        &self,
+        input: CreateGroupMembershipRequest,
+    ) -> Result<
+        (CreateGroupMembershipResponse, ResponseMetadata),
+        RusotoError<CreateGroupMembershipError>,
+    > {
+        let request = self.build_create_group_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateGroupResponse, _>()?;
+                .deserialize::<CreateGroupMembershipResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateGroupMembershipError::from_response(response))
         }
     }
 
-    /// <p>Adds an Amazon QuickSight user to an Amazon QuickSight group. </p>
-    async fn create_group_membership(
+    /// Presigns the request `create_group_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_group_membership(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGroupMembershipRequest,
-    ) -> Result<CreateGroupMembershipResponse, RusotoError<CreateGroupMembershipError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_group_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_group_membership_request(
+        &self,
+        input: CreateGroupMembershipRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateGroupMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `create_iam_policy_assignment`, also returning the metadata of the response.
+    pub async fn create_iam_policy_assignment_with_metadata(
+        &self,
+        input: CreateIAMPolicyAssignmentRequest,
+    ) -> Result<
+        (CreateIAMPolicyAssignmentResponse, ResponseMetadata),
+        RusotoError<CreateIAMPolicyAssignmentError>,
+    > {
+        let request = self.build_create_iam_policy_assignment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateGroupMembershipResponse, _>()?;
+                .deserialize::<CreateIAMPolicyAssignmentResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGroupMembershipError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateIAMPolicyAssignmentError::from_response(response))
         }
     }
 
-    /// <p>Creates an assignment with one specified IAM policy, identified by its Amazon Resource Name (ARN). This policy will be assigned to specified groups or users of Amazon QuickSight. The users and groups need to be in the same namespace. </p>
-    async fn create_iam_policy_assignment(
+    /// Presigns the request `create_iam_policy_assignment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_iam_policy_assignment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIAMPolicyAssignmentRequest,
-    ) -> Result<CreateIAMPolicyAssignmentResponse, RusotoError<CreateIAMPolicyAssignmentError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_iam_policy_assignment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_iam_policy_assignment_request(
+        &self,
+        input: CreateIAMPolicyAssignmentRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/",
             aws_account_id = input.aws_account_id,
@@ -9319,34 +10375,59 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_ingestion`, also returning the metadata of the response.
+    pub async fn create_ingestion_with_metadata(
+        &self,
+        input: CreateIngestionRequest,
+    ) -> Result<(CreateIngestionResponse, ResponseMetadata), RusotoError<CreateIngestionError>>
+    {
+        let request = self.build_create_ingestion_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateIAMPolicyAssignmentResponse, _>()?;
+                .deserialize::<CreateIngestionResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIAMPolicyAssignmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateIngestionError::from_response(response))
         }
     }
 
-    /// <p>Creates and starts a new SPICE ingestion on a dataset</p> <p>Any ingestions operating on tagged datasets inherit the same tags automatically for use in access control. For an example, see <a href="https://aws.example.com/premiumsupport/knowledge-center/iam-ec2-resource-tags/">How do I create an IAM policy to control access to Amazon EC2 resources using tags?</a> in the AWS Knowledge Center. Tags are visible on the tagged dataset, but not on the ingestion resource.</p>
-    async fn create_ingestion(
+    /// Presigns the request `create_ingestion` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_ingestion(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIngestionRequest,
-    ) -> Result<CreateIngestionResponse, RusotoError<CreateIngestionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_ingestion_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_ingestion_request(&self, input: CreateIngestionRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets/{data_set_id}/ingestions/{ingestion_id}",
             aws_account_id = input.aws_account_id,
@@ -9355,31 +10436,55 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `create_template`, also returning the metadata of the response.
+    pub async fn create_template_with_metadata(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> Result<(CreateTemplateResponse, ResponseMetadata), RusotoError<CreateTemplateError>> {
+        let request = self.build_create_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateIngestionResponse, _>()?;
+                .deserialize::<CreateTemplateResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIngestionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateTemplateError::from_response(response))
         }
     }
 
-    /// <p>Creates a template from an existing QuickSight analysis or template. You can use the resulting template to create a dashboard.</p> <p>A <i>template</i> is an entity in QuickSight that encapsulates the metadata required to create an analysis and that you can use to create s dashboard. A template adds a layer of abstraction by using placeholders to replace the dataset associated with the analysis. You can use templates to create dashboards by replacing dataset placeholders with datasets that follow the same schema that was used to create the source analysis and template.</p>
-    async fn create_template(
+    /// Presigns the request `create_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTemplateRequest,
-    ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_template_request(&self, input: CreateTemplateRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}",
             aws_account_id = input.aws_account_id,
@@ -9387,34 +10492,64 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_template_alias`, also returning the metadata of the response.
+    pub async fn create_template_alias_with_metadata(
+        &self,
+        input: CreateTemplateAliasRequest,
+    ) -> Result<
+        (CreateTemplateAliasResponse, ResponseMetadata),
+        RusotoError<CreateTemplateAliasError>,
+    > {
+        let request = self.build_create_template_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTemplateResponse, _>()?;
+                .deserialize::<CreateTemplateAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateTemplateAliasError::from_response(response))
         }
     }
 
-    /// <p>Creates a template alias for a template.</p>
-    async fn create_template_alias(
+    /// Presigns the request `create_template_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_template_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTemplateAliasRequest,
-    ) -> Result<CreateTemplateAliasResponse, RusotoError<CreateTemplateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_template_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_template_alias_request(
+        &self,
+        input: CreateTemplateAliasRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}/aliases/{alias_name}",
             alias_name = input.alias_name,
@@ -9423,34 +10558,59 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_dashboard`, also returning the metadata of the response.
+    pub async fn delete_dashboard_with_metadata(
+        &self,
+        input: DeleteDashboardRequest,
+    ) -> Result<(DeleteDashboardResponse, ResponseMetadata), RusotoError<DeleteDashboardError>>
+    {
+        let request = self.build_delete_dashboard_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTemplateAliasResponse, _>()?;
+                .deserialize::<DeleteDashboardResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTemplateAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDashboardError::from_response(response))
         }
     }
 
-    /// <p>Deletes a dashboard.</p>
-    async fn delete_dashboard(
+    /// Presigns the request `delete_dashboard` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dashboard(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDashboardRequest,
-    ) -> Result<DeleteDashboardResponse, RusotoError<DeleteDashboardError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dashboard_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dashboard_request(&self, input: DeleteDashboardRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards/{dashboard_id}",
             aws_account_id = input.aws_account_id,
@@ -9458,6 +10618,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9466,29 +10627,52 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_data_set`, also returning the metadata of the response.
+    pub async fn delete_data_set_with_metadata(
+        &self,
+        input: DeleteDataSetRequest,
+    ) -> Result<(DeleteDataSetResponse, ResponseMetadata), RusotoError<DeleteDataSetError>> {
+        let request = self.build_delete_data_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDashboardResponse, _>()?;
+                .deserialize::<DeleteDataSetResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDashboardError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDataSetError::from_response(response))
         }
     }
 
-    /// <p>Deletes a dataset.</p>
-    async fn delete_data_set(
+    /// Presigns the request `delete_data_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_data_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDataSetRequest,
-    ) -> Result<DeleteDataSetResponse, RusotoError<DeleteDataSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_data_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_data_set_request(&self, input: DeleteDataSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets/{data_set_id}",
             aws_account_id = input.aws_account_id,
@@ -9496,31 +10680,56 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_data_source`, also returning the metadata of the response.
+    pub async fn delete_data_source_with_metadata(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<(DeleteDataSourceResponse, ResponseMetadata), RusotoError<DeleteDataSourceError>>
+    {
+        let request = self.build_delete_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDataSetResponse, _>()?;
+                .deserialize::<DeleteDataSourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDataSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes the data source permanently. This action breaks all the datasets that reference the deleted data source.</p>
-    async fn delete_data_source(
+    /// Presigns the request `delete_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDataSourceRequest,
-    ) -> Result<DeleteDataSourceResponse, RusotoError<DeleteDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_data_source_request(&self, input: DeleteDataSourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sources/{data_source_id}",
             aws_account_id = input.aws_account_id,
@@ -9528,31 +10737,55 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_group`, also returning the metadata of the response.
+    pub async fn delete_group_with_metadata(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<(DeleteGroupResponse, ResponseMetadata), RusotoError<DeleteGroupError>> {
+        let request = self.build_delete_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDataSourceResponse, _>()?;
+                .deserialize::<DeleteGroupResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDataSourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteGroupError::from_response(response))
         }
     }
 
-    /// <p>Removes a user group from Amazon QuickSight. </p>
-    async fn delete_group(
+    /// Presigns the request `delete_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGroupRequest,
-    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_group_request(&self, input: DeleteGroupRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}",
             aws_account_id = input.aws_account_id,
@@ -9561,88 +10794,171 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_group_membership`, also returning the metadata of the response.
+    pub async fn delete_group_membership_with_metadata(
+        &self,
+        input: DeleteGroupMembershipRequest,
+    ) -> Result<
+        (DeleteGroupMembershipResponse, ResponseMetadata),
+        RusotoError<DeleteGroupMembershipError>,
+    > {
+        let request = self.build_delete_group_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGroupResponse, _>()?;
+                .deserialize::<DeleteGroupMembershipResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteGroupMembershipError::from_response(response))
         }
     }
 
-    /// <p>Removes a user from a group so that the user is no longer a member of the group.</p>
-    async fn delete_group_membership(
+    /// Presigns the request `delete_group_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_group_membership(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGroupMembershipRequest,
-    ) -> Result<DeleteGroupMembershipResponse, RusotoError<DeleteGroupMembershipError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_group_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_group_membership_request(
+        &self,
+        input: DeleteGroupMembershipRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteGroupMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_iam_policy_assignment`, also returning the metadata of the response.
+    pub async fn delete_iam_policy_assignment_with_metadata(
+        &self,
+        input: DeleteIAMPolicyAssignmentRequest,
+    ) -> Result<
+        (DeleteIAMPolicyAssignmentResponse, ResponseMetadata),
+        RusotoError<DeleteIAMPolicyAssignmentError>,
+    > {
+        let request = self.build_delete_iam_policy_assignment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGroupMembershipResponse, _>()?;
+                .deserialize::<DeleteIAMPolicyAssignmentResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGroupMembershipError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteIAMPolicyAssignmentError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing IAM policy assignment.</p>
-    async fn delete_iam_policy_assignment(
+    /// Presigns the request `delete_iam_policy_assignment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_iam_policy_assignment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIAMPolicyAssignmentRequest,
-    ) -> Result<DeleteIAMPolicyAssignmentResponse, RusotoError<DeleteIAMPolicyAssignmentError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_iam_policy_assignment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_iam_policy_assignment_request(
+        &self,
+        input: DeleteIAMPolicyAssignmentRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/accounts/{aws_account_id}/namespace/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_template`, also returning the metadata of the response.
+    pub async fn delete_template_with_metadata(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> Result<(DeleteTemplateResponse, ResponseMetadata), RusotoError<DeleteTemplateError>> {
+        let request = self.build_delete_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteIAMPolicyAssignmentResponse, _>()?;
+                .deserialize::<DeleteTemplateResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIAMPolicyAssignmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteTemplateError::from_response(response))
         }
     }
 
-    /// <p>Deletes a template.</p>
-    async fn delete_template(
+    /// Presigns the request `delete_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTemplateRequest,
-    ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_template_request(&self, input: DeleteTemplateRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}",
             aws_account_id = input.aws_account_id,
@@ -9650,6 +10966,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9658,29 +10975,58 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_template_alias`, also returning the metadata of the response.
+    pub async fn delete_template_alias_with_metadata(
+        &self,
+        input: DeleteTemplateAliasRequest,
+    ) -> Result<
+        (DeleteTemplateAliasResponse, ResponseMetadata),
+        RusotoError<DeleteTemplateAliasError>,
+    > {
+        let request = self.build_delete_template_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteTemplateResponse, _>()?;
+                .deserialize::<DeleteTemplateAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteTemplateAliasError::from_response(response))
         }
     }
 
-    /// <p>Deletes the item that the specified template alias points to. If you provide a specific alias, you delete the version of the template that the alias points to.</p>
-    async fn delete_template_alias(
+    /// Presigns the request `delete_template_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_template_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTemplateAliasRequest,
-    ) -> Result<DeleteTemplateAliasResponse, RusotoError<DeleteTemplateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_template_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_template_alias_request(
+        &self,
+        input: DeleteTemplateAliasRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}/aliases/{alias_name}",
             alias_name = input.alias_name,
@@ -9689,31 +11035,55 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_user`, also returning the metadata of the response.
+    pub async fn delete_user_with_metadata(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(DeleteUserResponse, ResponseMetadata), RusotoError<DeleteUserError>> {
+        let request = self.build_delete_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteTemplateAliasResponse, _>()?;
+                .deserialize::<DeleteUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTemplateAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteUserError::from_response(response))
         }
     }
 
-    /// <p>Deletes the Amazon QuickSight user that is associated with the identity of the AWS Identity and Access Management (IAM) user or role that's making the call. The IAM user isn't deleted as a result of this call. </p>
-    async fn delete_user(
+    /// Presigns the request `delete_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserRequest,
-    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_request(&self, input: DeleteUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/users/{user_name}",
             aws_account_id = input.aws_account_id,
@@ -9722,31 +11092,61 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_user_by_principal_id`, also returning the metadata of the response.
+    pub async fn delete_user_by_principal_id_with_metadata(
+        &self,
+        input: DeleteUserByPrincipalIdRequest,
+    ) -> Result<
+        (DeleteUserByPrincipalIdResponse, ResponseMetadata),
+        RusotoError<DeleteUserByPrincipalIdError>,
+    > {
+        let request = self.build_delete_user_by_principal_id_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteUserResponse, _>()?;
+                .deserialize::<DeleteUserByPrincipalIdResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteUserByPrincipalIdError::from_response(response))
         }
     }
 
-    /// <p>Deletes a user identified by its principal ID. </p>
-    async fn delete_user_by_principal_id(
+    /// Presigns the request `delete_user_by_principal_id` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user_by_principal_id(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserByPrincipalIdRequest,
-    ) -> Result<DeleteUserByPrincipalIdResponse, RusotoError<DeleteUserByPrincipalIdError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_by_principal_id_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_by_principal_id_request(
+        &self,
+        input: DeleteUserByPrincipalIdRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/user-principals/{principal_id}",
             aws_account_id = input.aws_account_id,
@@ -9755,31 +11155,56 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteUserByPrincipalId");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_dashboard`, also returning the metadata of the response.
+    pub async fn describe_dashboard_with_metadata(
+        &self,
+        input: DescribeDashboardRequest,
+    ) -> Result<(DescribeDashboardResponse, ResponseMetadata), RusotoError<DescribeDashboardError>>
+    {
+        let request = self.build_describe_dashboard_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteUserByPrincipalIdResponse, _>()?;
+                .deserialize::<DescribeDashboardResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserByPrincipalIdError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDashboardError::from_response(response))
         }
     }
 
-    /// <p>Provides a summary for a dashboard.</p>
-    async fn describe_dashboard(
+    /// Presigns the request `describe_dashboard` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dashboard(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDashboardRequest,
-    ) -> Result<DescribeDashboardResponse, RusotoError<DescribeDashboardError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dashboard_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dashboard_request(&self, input: DescribeDashboardRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards/{dashboard_id}",
             aws_account_id = input.aws_account_id,
@@ -9787,6 +11212,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9798,30 +11224,58 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_dashboard_permissions`, also returning the metadata of the response.
+    pub async fn describe_dashboard_permissions_with_metadata(
+        &self,
+        input: DescribeDashboardPermissionsRequest,
+    ) -> Result<
+        (DescribeDashboardPermissionsResponse, ResponseMetadata),
+        RusotoError<DescribeDashboardPermissionsError>,
+    > {
+        let request = self.build_describe_dashboard_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDashboardResponse, _>()?;
+                .deserialize::<DescribeDashboardPermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDashboardError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDashboardPermissionsError::from_response(response))
         }
     }
 
-    /// <p>Describes read and write permissions for a dashboard.</p>
-    async fn describe_dashboard_permissions(
+    /// Presigns the request `describe_dashboard_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dashboard_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDashboardPermissionsRequest,
-    ) -> Result<DescribeDashboardPermissionsResponse, RusotoError<DescribeDashboardPermissionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dashboard_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dashboard_permissions_request(
+        &self,
+        input: DescribeDashboardPermissionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards/{dashboard_id}/permissions",
             aws_account_id = input.aws_account_id,
@@ -9829,31 +11283,56 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDashboardPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_data_set`, also returning the metadata of the response.
+    pub async fn describe_data_set_with_metadata(
+        &self,
+        input: DescribeDataSetRequest,
+    ) -> Result<(DescribeDataSetResponse, ResponseMetadata), RusotoError<DescribeDataSetError>>
+    {
+        let request = self.build_describe_data_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDashboardPermissionsResponse, _>()?;
+                .deserialize::<DescribeDataSetResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDashboardPermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDataSetError::from_response(response))
         }
     }
 
-    /// <p>Describes a dataset. </p>
-    async fn describe_data_set(
+    /// Presigns the request `describe_data_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_data_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDataSetRequest,
-    ) -> Result<DescribeDataSetResponse, RusotoError<DescribeDataSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_data_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_data_set_request(&self, input: DescribeDataSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets/{data_set_id}",
             aws_account_id = input.aws_account_id,
@@ -9861,32 +11340,61 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_data_set_permissions`, also returning the metadata of the response.
+    pub async fn describe_data_set_permissions_with_metadata(
+        &self,
+        input: DescribeDataSetPermissionsRequest,
+    ) -> Result<
+        (DescribeDataSetPermissionsResponse, ResponseMetadata),
+        RusotoError<DescribeDataSetPermissionsError>,
+    > {
+        let request = self.build_describe_data_set_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDataSetResponse, _>()?;
+                .deserialize::<DescribeDataSetPermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDataSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDataSetPermissionsError::from_response(response))
         }
     }
 
-    /// <p>Describes the permissions on a dataset.</p> <p>The permissions resource is <code>arn:aws:quicksight:region:aws-account-id:dataset/data-set-id</code>.</p>
-    async fn describe_data_set_permissions(
+    /// Presigns the request `describe_data_set_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_data_set_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDataSetPermissionsRequest,
-    ) -> Result<DescribeDataSetPermissionsResponse, RusotoError<DescribeDataSetPermissionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_data_set_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_data_set_permissions_request(
+        &self,
+        input: DescribeDataSetPermissionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets/{data_set_id}/permissions",
             aws_account_id = input.aws_account_id,
@@ -9894,31 +11402,59 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSetPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_data_source`, also returning the metadata of the response.
+    pub async fn describe_data_source_with_metadata(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> Result<(DescribeDataSourceResponse, ResponseMetadata), RusotoError<DescribeDataSourceError>>
+    {
+        let request = self.build_describe_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDataSetPermissionsResponse, _>()?;
+                .deserialize::<DescribeDataSourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDataSetPermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Describes a data source.</p>
-    async fn describe_data_source(
+    /// Presigns the request `describe_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDataSourceRequest,
-    ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_data_source_request(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sources/{data_source_id}",
             aws_account_id = input.aws_account_id,
@@ -9926,34 +11462,61 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_data_source_permissions`, also returning the metadata of the response.
+    pub async fn describe_data_source_permissions_with_metadata(
+        &self,
+        input: DescribeDataSourcePermissionsRequest,
+    ) -> Result<
+        (DescribeDataSourcePermissionsResponse, ResponseMetadata),
+        RusotoError<DescribeDataSourcePermissionsError>,
+    > {
+        let request = self.build_describe_data_source_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDataSourceResponse, _>()?;
+                .deserialize::<DescribeDataSourcePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDataSourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDataSourcePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Describes the resource permissions for a data source.</p>
-    async fn describe_data_source_permissions(
+    /// Presigns the request `describe_data_source_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_data_source_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDataSourcePermissionsRequest,
-    ) -> Result<
-        DescribeDataSourcePermissionsResponse,
-        RusotoError<DescribeDataSourcePermissionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_data_source_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_data_source_permissions_request(
+        &self,
+        input: DescribeDataSourcePermissionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sources/{data_source_id}/permissions",
             aws_account_id = input.aws_account_id,
@@ -9961,31 +11524,55 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_group`, also returning the metadata of the response.
+    pub async fn describe_group_with_metadata(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> Result<(DescribeGroupResponse, ResponseMetadata), RusotoError<DescribeGroupError>> {
+        let request = self.build_describe_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDataSourcePermissionsResponse, _>()?;
+                .deserialize::<DescribeGroupResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDataSourcePermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeGroupError::from_response(response))
         }
     }
 
-    /// <p>Returns an Amazon QuickSight group's description and Amazon Resource Name (ARN). </p>
-    async fn describe_group(
+    /// Presigns the request `describe_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeGroupRequest,
-    ) -> Result<DescribeGroupResponse, RusotoError<DescribeGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_group_request(&self, input: DescribeGroupRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}",
             aws_account_id = input.aws_account_id,
@@ -9994,60 +11581,114 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_iam_policy_assignment`, also returning the metadata of the response.
+    pub async fn describe_iam_policy_assignment_with_metadata(
+        &self,
+        input: DescribeIAMPolicyAssignmentRequest,
+    ) -> Result<
+        (DescribeIAMPolicyAssignmentResponse, ResponseMetadata),
+        RusotoError<DescribeIAMPolicyAssignmentError>,
+    > {
+        let request = self.build_describe_iam_policy_assignment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeGroupResponse, _>()?;
+                .deserialize::<DescribeIAMPolicyAssignmentResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeIAMPolicyAssignmentError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing IAM policy assignment, as specified by the assignment name.</p>
-    async fn describe_iam_policy_assignment(
+    /// Presigns the request `describe_iam_policy_assignment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_iam_policy_assignment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeIAMPolicyAssignmentRequest,
-    ) -> Result<DescribeIAMPolicyAssignmentResponse, RusotoError<DescribeIAMPolicyAssignmentError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_iam_policy_assignment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_iam_policy_assignment_request(
+        &self,
+        input: DescribeIAMPolicyAssignmentRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_ingestion`, also returning the metadata of the response.
+    pub async fn describe_ingestion_with_metadata(
+        &self,
+        input: DescribeIngestionRequest,
+    ) -> Result<(DescribeIngestionResponse, ResponseMetadata), RusotoError<DescribeIngestionError>>
+    {
+        let request = self.build_describe_ingestion_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeIAMPolicyAssignmentResponse, _>()?;
+                .deserialize::<DescribeIngestionResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeIAMPolicyAssignmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeIngestionError::from_response(response))
         }
     }
 
-    /// <p>Describes a SPICE ingestion.</p>
-    async fn describe_ingestion(
+    /// Presigns the request `describe_ingestion` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_ingestion(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeIngestionRequest,
-    ) -> Result<DescribeIngestionResponse, RusotoError<DescribeIngestionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_ingestion_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_ingestion_request(&self, input: DescribeIngestionRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets/{data_set_id}/ingestions/{ingestion_id}",
             aws_account_id = input.aws_account_id,
@@ -10056,31 +11697,56 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_template`, also returning the metadata of the response.
+    pub async fn describe_template_with_metadata(
+        &self,
+        input: DescribeTemplateRequest,
+    ) -> Result<(DescribeTemplateResponse, ResponseMetadata), RusotoError<DescribeTemplateError>>
+    {
+        let request = self.build_describe_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeIngestionResponse, _>()?;
+                .deserialize::<DescribeTemplateResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeIngestionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeTemplateError::from_response(response))
         }
     }
 
-    /// <p>Describes a template's metadata.</p>
-    async fn describe_template(
+    /// Presigns the request `describe_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTemplateRequest,
-    ) -> Result<DescribeTemplateResponse, RusotoError<DescribeTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_template_request(&self, input: DescribeTemplateRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}",
             aws_account_id = input.aws_account_id,
@@ -10088,6 +11754,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10099,29 +11766,58 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_template_alias`, also returning the metadata of the response.
+    pub async fn describe_template_alias_with_metadata(
+        &self,
+        input: DescribeTemplateAliasRequest,
+    ) -> Result<
+        (DescribeTemplateAliasResponse, ResponseMetadata),
+        RusotoError<DescribeTemplateAliasError>,
+    > {
+        let request = self.build_describe_template_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTemplateResponse, _>()?;
+                .deserialize::<DescribeTemplateAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeTemplateAliasError::from_response(response))
         }
     }
 
-    /// <p>Describes the template alias for a template.</p>
-    async fn describe_template_alias(
+    /// Presigns the request `describe_template_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_template_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTemplateAliasRequest,
-    ) -> Result<DescribeTemplateAliasResponse, RusotoError<DescribeTemplateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_template_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_template_alias_request(
+        &self,
+        input: DescribeTemplateAliasRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}/aliases/{alias_name}",
             alias_name = input.alias_name,
@@ -10130,32 +11826,61 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_template_permissions`, also returning the metadata of the response.
+    pub async fn describe_template_permissions_with_metadata(
+        &self,
+        input: DescribeTemplatePermissionsRequest,
+    ) -> Result<
+        (DescribeTemplatePermissionsResponse, ResponseMetadata),
+        RusotoError<DescribeTemplatePermissionsError>,
+    > {
+        let request = self.build_describe_template_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTemplateAliasResponse, _>()?;
+                .deserialize::<DescribeTemplatePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTemplateAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeTemplatePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Describes read and write permissions on a template.</p>
-    async fn describe_template_permissions(
+    /// Presigns the request `describe_template_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_template_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTemplatePermissionsRequest,
-    ) -> Result<DescribeTemplatePermissionsResponse, RusotoError<DescribeTemplatePermissionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_template_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_template_permissions_request(
+        &self,
+        input: DescribeTemplatePermissionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}/permissions",
             aws_account_id = input.aws_account_id,
@@ -10163,31 +11888,55 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplatePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_user`, also returning the metadata of the response.
+    pub async fn describe_user_with_metadata(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<(DescribeUserResponse, ResponseMetadata), RusotoError<DescribeUserError>> {
+        let request = self.build_describe_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTemplatePermissionsResponse, _>()?;
+                .deserialize::<DescribeUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTemplatePermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeUserError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a user, given the user name. </p>
-    async fn describe_user(
+    /// Presigns the request `describe_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserRequest,
-    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_request(&self, input: DescribeUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/users/{user_name}",
             aws_account_id = input.aws_account_id,
@@ -10196,31 +11945,61 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_dashboard_embed_url`, also returning the metadata of the response.
+    pub async fn get_dashboard_embed_url_with_metadata(
+        &self,
+        input: GetDashboardEmbedUrlRequest,
+    ) -> Result<
+        (GetDashboardEmbedUrlResponse, ResponseMetadata),
+        RusotoError<GetDashboardEmbedUrlError>,
+    > {
+        let request = self.build_get_dashboard_embed_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserResponse, _>()?;
+                .deserialize::<GetDashboardEmbedUrlResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDashboardEmbedUrlError::from_response(response))
         }
     }
 
-    /// <p>Generates a server-side embeddable URL and authorization code. For this process to work properly, first configure the dashboards and user permissions. For more information, see <a href="https://docs.aws.amazon.com/quicksight/latest/user/embedding-dashboards.html">Embedding Amazon QuickSight Dashboards</a> in the <i>Amazon QuickSight User Guide</i> or <a href="https://docs.aws.amazon.com/quicksight/latest/APIReference/qs-dev-embedded-dashboards.html">Embedding Amazon QuickSight Dashboards</a> in the <i>Amazon QuickSight API Reference</i>.</p> <p>Currently, you can use <code>GetDashboardEmbedURL</code> only from the server, not from the user’s browser.</p>
-    async fn get_dashboard_embed_url(
+    /// Presigns the request `get_dashboard_embed_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dashboard_embed_url(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDashboardEmbedUrlRequest,
-    ) -> Result<GetDashboardEmbedUrlResponse, RusotoError<GetDashboardEmbedUrlError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dashboard_embed_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dashboard_embed_url_request(
+        &self,
+        input: GetDashboardEmbedUrlRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards/{dashboard_id}/embed-url",
             aws_account_id = input.aws_account_id,
@@ -10228,6 +12007,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("GetDashboardEmbedUrl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10246,29 +12026,58 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_dashboard_versions`, also returning the metadata of the response.
+    pub async fn list_dashboard_versions_with_metadata(
+        &self,
+        input: ListDashboardVersionsRequest,
+    ) -> Result<
+        (ListDashboardVersionsResponse, ResponseMetadata),
+        RusotoError<ListDashboardVersionsError>,
+    > {
+        let request = self.build_list_dashboard_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDashboardEmbedUrlResponse, _>()?;
+                .deserialize::<ListDashboardVersionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDashboardEmbedUrlError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDashboardVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the versions of the dashboards in the QuickSight subscription.</p>
-    async fn list_dashboard_versions(
+    /// Presigns the request `list_dashboard_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dashboard_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDashboardVersionsRequest,
-    ) -> Result<ListDashboardVersionsResponse, RusotoError<ListDashboardVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dashboard_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dashboard_versions_request(
+        &self,
+        input: ListDashboardVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards/{dashboard_id}/versions",
             aws_account_id = input.aws_account_id,
@@ -10276,6 +12085,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDashboardVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10287,35 +12097,59 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_dashboards`, also returning the metadata of the response.
+    pub async fn list_dashboards_with_metadata(
+        &self,
+        input: ListDashboardsRequest,
+    ) -> Result<(ListDashboardsResponse, ResponseMetadata), RusotoError<ListDashboardsError>> {
+        let request = self.build_list_dashboards_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDashboardVersionsResponse, _>()?;
+                .deserialize::<ListDashboardsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDashboardVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDashboardsError::from_response(response))
         }
     }
 
-    /// <p>Lists dashboards in an AWS account.</p>
-    async fn list_dashboards(
+    /// Presigns the request `list_dashboards` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dashboards(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDashboardsRequest,
-    ) -> Result<ListDashboardsResponse, RusotoError<ListDashboardsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dashboards_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dashboards_request(&self, input: ListDashboardsRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards",
             aws_account_id = input.aws_account_id
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDashboards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10327,35 +12161,59 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_data_sets`, also returning the metadata of the response.
+    pub async fn list_data_sets_with_metadata(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> Result<(ListDataSetsResponse, ResponseMetadata), RusotoError<ListDataSetsError>> {
+        let request = self.build_list_data_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDashboardsResponse, _>()?;
+                .deserialize::<ListDataSetsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDashboardsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDataSetsError::from_response(response))
         }
     }
 
-    /// <p>Lists all of the datasets belonging to the current AWS account in an AWS Region.</p> <p>The permissions resource is <code>arn:aws:quicksight:region:aws-account-id:dataset/*</code>.</p>
-    async fn list_data_sets(
+    /// Presigns the request `list_data_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_data_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDataSetsRequest,
-    ) -> Result<ListDataSetsResponse, RusotoError<ListDataSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_data_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_data_sets_request(&self, input: ListDataSetsRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets",
             aws_account_id = input.aws_account_id
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDataSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10367,35 +12225,60 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_data_sources`, also returning the metadata of the response.
+    pub async fn list_data_sources_with_metadata(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<(ListDataSourcesResponse, ResponseMetadata), RusotoError<ListDataSourcesError>>
+    {
+        let request = self.build_list_data_sources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDataSetsResponse, _>()?;
+                .deserialize::<ListDataSourcesResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDataSetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDataSourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists data sources in current AWS Region that belong to this AWS account.</p>
-    async fn list_data_sources(
+    /// Presigns the request `list_data_sources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_data_sources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDataSourcesRequest,
-    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_data_sources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_data_sources_request(&self, input: ListDataSourcesRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sources",
             aws_account_id = input.aws_account_id
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDataSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10407,29 +12290,58 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_group_memberships`, also returning the metadata of the response.
+    pub async fn list_group_memberships_with_metadata(
+        &self,
+        input: ListGroupMembershipsRequest,
+    ) -> Result<
+        (ListGroupMembershipsResponse, ResponseMetadata),
+        RusotoError<ListGroupMembershipsError>,
+    > {
+        let request = self.build_list_group_memberships_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDataSourcesResponse, _>()?;
+                .deserialize::<ListGroupMembershipsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDataSourcesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListGroupMembershipsError::from_response(response))
         }
     }
 
-    /// <p>Lists member users in a group.</p>
-    async fn list_group_memberships(
+    /// Presigns the request `list_group_memberships` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_group_memberships(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGroupMembershipsRequest,
-    ) -> Result<ListGroupMembershipsResponse, RusotoError<ListGroupMembershipsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_group_memberships_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_group_memberships_request(
+        &self,
+        input: ListGroupMembershipsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members",
             aws_account_id = input.aws_account_id,
@@ -10438,6 +12350,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListGroupMemberships");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10449,29 +12362,52 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_groups`, also returning the metadata of the response.
+    pub async fn list_groups_with_metadata(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<(ListGroupsResponse, ResponseMetadata), RusotoError<ListGroupsError>> {
+        let request = self.build_list_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGroupMembershipsResponse, _>()?;
+                .deserialize::<ListGroupsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGroupMembershipsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListGroupsError::from_response(response))
         }
     }
 
-    /// <p>Lists all user groups in Amazon QuickSight. </p>
-    async fn list_groups(
+    /// Presigns the request `list_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGroupsRequest,
-    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_groups_request(&self, input: ListGroupsRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/groups",
             aws_account_id = input.aws_account_id,
@@ -10479,6 +12415,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10490,29 +12427,58 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_iam_policy_assignments`, also returning the metadata of the response.
+    pub async fn list_iam_policy_assignments_with_metadata(
+        &self,
+        input: ListIAMPolicyAssignmentsRequest,
+    ) -> Result<
+        (ListIAMPolicyAssignmentsResponse, ResponseMetadata),
+        RusotoError<ListIAMPolicyAssignmentsError>,
+    > {
+        let request = self.build_list_iam_policy_assignments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGroupsResponse, _>()?;
+                .deserialize::<ListIAMPolicyAssignmentsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListIAMPolicyAssignmentsError::from_response(response))
         }
     }
 
-    /// <p>Lists IAM policy assignments in the current Amazon QuickSight account.</p>
-    async fn list_iam_policy_assignments(
+    /// Presigns the request `list_iam_policy_assignments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_iam_policy_assignments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIAMPolicyAssignmentsRequest,
-    ) -> Result<ListIAMPolicyAssignmentsResponse, RusotoError<ListIAMPolicyAssignmentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_iam_policy_assignments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_iam_policy_assignments_request(
+        &self,
+        input: ListIAMPolicyAssignmentsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments",
             aws_account_id = input.aws_account_id,
@@ -10520,6 +12486,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIAMPolicyAssignments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10534,35 +12501,64 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_iam_policy_assignments_for_user`, also returning the metadata of the response.
+    pub async fn list_iam_policy_assignments_for_user_with_metadata(
+        &self,
+        input: ListIAMPolicyAssignmentsForUserRequest,
+    ) -> Result<
+        (ListIAMPolicyAssignmentsForUserResponse, ResponseMetadata),
+        RusotoError<ListIAMPolicyAssignmentsForUserError>,
+    > {
+        let request = self.build_list_iam_policy_assignments_for_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListIAMPolicyAssignmentsResponse, _>()?;
+                .deserialize::<ListIAMPolicyAssignmentsForUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIAMPolicyAssignmentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListIAMPolicyAssignmentsForUserError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists all the IAM policy assignments, including the Amazon Resource Names (ARNs) for the IAM policies assigned to the specified user and group or groups that the user belongs to.</p>
-    async fn list_iam_policy_assignments_for_user(
+    /// Presigns the request `list_iam_policy_assignments_for_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_iam_policy_assignments_for_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIAMPolicyAssignmentsForUserRequest,
-    ) -> Result<
-        ListIAMPolicyAssignmentsForUserResponse,
-        RusotoError<ListIAMPolicyAssignmentsForUserError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_iam_policy_assignments_for_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_iam_policy_assignments_for_user_request(
+        &self,
+        input: ListIAMPolicyAssignmentsForUserRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/users/{user_name}/iam-policy-assignments", aws_account_id = input.aws_account_id, namespace = input.namespace, user_name = input.user_name);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIAMPolicyAssignmentsForUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10574,31 +12570,52 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_ingestions`, also returning the metadata of the response.
+    pub async fn list_ingestions_with_metadata(
+        &self,
+        input: ListIngestionsRequest,
+    ) -> Result<(ListIngestionsResponse, ResponseMetadata), RusotoError<ListIngestionsError>> {
+        let request = self.build_list_ingestions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListIAMPolicyAssignmentsForUserResponse, _>()?;
+                .deserialize::<ListIngestionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIAMPolicyAssignmentsForUserError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListIngestionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the history of SPICE ingestions for a dataset.</p>
-    async fn list_ingestions(
+    /// Presigns the request `list_ingestions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_ingestions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIngestionsRequest,
-    ) -> Result<ListIngestionsResponse, RusotoError<ListIngestionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_ingestions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_ingestions_request(&self, input: ListIngestionsRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets/{data_set_id}/ingestions",
             aws_account_id = input.aws_account_id,
@@ -10606,6 +12623,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIngestions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10617,60 +12635,119 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListIngestionsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIngestionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags assigned to a resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/resources/{resource_arn}/tags",
             resource_arn = input.resource_arn
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_template_aliases`, also returning the metadata of the response.
+    pub async fn list_template_aliases_with_metadata(
+        &self,
+        input: ListTemplateAliasesRequest,
+    ) -> Result<
+        (ListTemplateAliasesResponse, ResponseMetadata),
+        RusotoError<ListTemplateAliasesError>,
+    > {
+        let request = self.build_list_template_aliases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<ListTemplateAliasesResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTemplateAliasesError::from_response(response))
         }
     }
 
-    /// <p>Lists all the aliases of a template.</p>
-    async fn list_template_aliases(
+    /// Presigns the request `list_template_aliases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_template_aliases(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTemplateAliasesRequest,
-    ) -> Result<ListTemplateAliasesResponse, RusotoError<ListTemplateAliasesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_template_aliases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_template_aliases_request(
+        &self,
+        input: ListTemplateAliasesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}/aliases",
             aws_account_id = input.aws_account_id,
@@ -10678,6 +12755,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplateAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10689,29 +12767,58 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_template_versions`, also returning the metadata of the response.
+    pub async fn list_template_versions_with_metadata(
+        &self,
+        input: ListTemplateVersionsRequest,
+    ) -> Result<
+        (ListTemplateVersionsResponse, ResponseMetadata),
+        RusotoError<ListTemplateVersionsError>,
+    > {
+        let request = self.build_list_template_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTemplateAliasesResponse, _>()?;
+                .deserialize::<ListTemplateVersionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTemplateAliasesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTemplateVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the versions of the templates in the current Amazon QuickSight account.</p>
-    async fn list_template_versions(
+    /// Presigns the request `list_template_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_template_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTemplateVersionsRequest,
-    ) -> Result<ListTemplateVersionsResponse, RusotoError<ListTemplateVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_template_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_template_versions_request(
+        &self,
+        input: ListTemplateVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}/versions",
             aws_account_id = input.aws_account_id,
@@ -10719,6 +12826,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplateVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10730,35 +12838,59 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_templates`, also returning the metadata of the response.
+    pub async fn list_templates_with_metadata(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> Result<(ListTemplatesResponse, ResponseMetadata), RusotoError<ListTemplatesError>> {
+        let request = self.build_list_templates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTemplateVersionsResponse, _>()?;
+                .deserialize::<ListTemplatesResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTemplateVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTemplatesError::from_response(response))
         }
     }
 
-    /// <p>Lists all the templates in the current Amazon QuickSight account.</p>
-    async fn list_templates(
+    /// Presigns the request `list_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_templates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTemplatesRequest,
-    ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_templates_request(&self, input: ListTemplatesRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates",
             aws_account_id = input.aws_account_id
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10770,29 +12902,52 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_user_groups`, also returning the metadata of the response.
+    pub async fn list_user_groups_with_metadata(
+        &self,
+        input: ListUserGroupsRequest,
+    ) -> Result<(ListUserGroupsResponse, ResponseMetadata), RusotoError<ListUserGroupsError>> {
+        let request = self.build_list_user_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTemplatesResponse, _>()?;
+                .deserialize::<ListUserGroupsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTemplatesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListUserGroupsError::from_response(response))
         }
     }
 
-    /// <p>Lists the Amazon QuickSight groups that an Amazon QuickSight user is a member of.</p>
-    async fn list_user_groups(
+    /// Presigns the request `list_user_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_user_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUserGroupsRequest,
-    ) -> Result<ListUserGroupsResponse, RusotoError<ListUserGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_user_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_user_groups_request(&self, input: ListUserGroupsRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/users/{user_name}/groups",
             aws_account_id = input.aws_account_id,
@@ -10801,6 +12956,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListUserGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10812,29 +12968,52 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_users`, also returning the metadata of the response.
+    pub async fn list_users_with_metadata(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<(ListUsersResponse, ResponseMetadata), RusotoError<ListUsersError>> {
+        let request = self.build_list_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUserGroupsResponse, _>()?;
+                .deserialize::<ListUsersResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUserGroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListUsersError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all of the Amazon QuickSight users belonging to this account. </p>
-    async fn list_users(
+    /// Presigns the request `list_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUsersRequest,
-    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_users_request(&self, input: ListUsersRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/users",
             aws_account_id = input.aws_account_id,
@@ -10842,6 +13021,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10853,29 +13033,52 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `register_user`, also returning the metadata of the response.
+    pub async fn register_user_with_metadata(
+        &self,
+        input: RegisterUserRequest,
+    ) -> Result<(RegisterUserResponse, ResponseMetadata), RusotoError<RegisterUserError>> {
+        let request = self.build_register_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUsersResponse, _>()?;
+                .deserialize::<RegisterUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUsersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RegisterUserError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon QuickSight user, whose identity is associated with the AWS Identity and Access Management (IAM) identity or role specified in the request. </p>
-    async fn register_user(
+    /// Presigns the request `register_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterUserRequest,
-    ) -> Result<RegisterUserResponse, RusotoError<RegisterUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_user_request(&self, input: RegisterUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/users",
             aws_account_id = input.aws_account_id,
@@ -10883,74 +13086,123 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("RegisterUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterUserResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p><p>Assigns one or more tags (key-value pairs) to the specified QuickSight resource. </p> <p>Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only resources with certain tag values. You can use the <code>TagResource</code> operation with a resource that already has tags. If you specify a new tag key for the resource, this tag is appended to the list of tags associated with the resource. If you specify a tag key that is already associated with the resource, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource. QuickSight supports tagging on data set, data source, dashboard, and template. </p> <p>Tagging for QuickSight works in a similar way to tagging for other AWS services, except for the following:</p> <ul> <li> <p>You can&#39;t use tags to track AWS costs for QuickSight. This restriction is because QuickSight costs are based on users and SPICE capacity, which aren&#39;t taggable resources.</p> </li> <li> <p>QuickSight doesn&#39;t currently support the Tag Editor for AWS Resource Groups.</p> </li> </ul></p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/resources/{resource_arn}/tags",
             resource_arn = input.resource_arn
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes a tag or tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/resources/{resource_arn}/tags",
             resource_arn = input.resource_arn
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10959,29 +13211,52 @@ impl Quicksight for QuicksightClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_dashboard`, also returning the metadata of the response.
+    pub async fn update_dashboard_with_metadata(
+        &self,
+        input: UpdateDashboardRequest,
+    ) -> Result<(UpdateDashboardResponse, ResponseMetadata), RusotoError<UpdateDashboardError>>
+    {
+        let request = self.build_update_dashboard_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDashboardResponse, _>()?;
 
-            result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDashboardError::from_response(response))
         }
     }
 
-    /// <p>Updates a dashboard in an AWS account.</p>
-    async fn update_dashboard(
+    /// Presigns the request `update_dashboard` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_dashboard(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDashboardRequest,
-    ) -> Result<UpdateDashboardResponse, RusotoError<UpdateDashboardError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_dashboard_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_dashboard_request(&self, input: UpdateDashboardRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards/{dashboard_id}",
             aws_account_id = input.aws_account_id,
@@ -10989,34 +13264,64 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_dashboard_permissions`, also returning the metadata of the response.
+    pub async fn update_dashboard_permissions_with_metadata(
+        &self,
+        input: UpdateDashboardPermissionsRequest,
+    ) -> Result<
+        (UpdateDashboardPermissionsResponse, ResponseMetadata),
+        RusotoError<UpdateDashboardPermissionsError>,
+    > {
+        let request = self.build_update_dashboard_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDashboardResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let mut result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDashboardPermissionsResponse, _>()?;
 
-            Ok(result)
+            result.status = Some(response.status.as_u16() as i64);
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDashboardError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDashboardPermissionsError::from_response(response))
         }
     }
 
-    /// <p>Updates read and write permissions on a dashboard.</p>
-    async fn update_dashboard_permissions(
+    /// Presigns the request `update_dashboard_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_dashboard_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDashboardPermissionsRequest,
-    ) -> Result<UpdateDashboardPermissionsResponse, RusotoError<UpdateDashboardPermissionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_dashboard_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_dashboard_permissions_request(
+        &self,
+        input: UpdateDashboardPermissionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards/{dashboard_id}/permissions",
             aws_account_id = input.aws_account_id,
@@ -11024,37 +13329,66 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboardPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_dashboard_published_version`, also returning the metadata of the response.
+    pub async fn update_dashboard_published_version_with_metadata(
+        &self,
+        input: UpdateDashboardPublishedVersionRequest,
+    ) -> Result<
+        (UpdateDashboardPublishedVersionResponse, ResponseMetadata),
+        RusotoError<UpdateDashboardPublishedVersionError>,
+    > {
+        let request = self.build_update_dashboard_published_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDashboardPermissionsResponse, _>()?;
+                .deserialize::<UpdateDashboardPublishedVersionResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDashboardPermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDashboardPublishedVersionError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Updates the published version of a dashboard.</p>
-    async fn update_dashboard_published_version(
+    /// Presigns the request `update_dashboard_published_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_dashboard_published_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDashboardPublishedVersionRequest,
-    ) -> Result<
-        UpdateDashboardPublishedVersionResponse,
-        RusotoError<UpdateDashboardPublishedVersionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_dashboard_published_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_dashboard_published_version_request(
+        &self,
+        input: UpdateDashboardPublishedVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/dashboards/{dashboard_id}/versions/{version_number}",
             aws_account_id = input.aws_account_id,
@@ -11063,33 +13397,55 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboardPublishedVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `update_data_set`, also returning the metadata of the response.
+    pub async fn update_data_set_with_metadata(
+        &self,
+        input: UpdateDataSetRequest,
+    ) -> Result<(UpdateDataSetResponse, ResponseMetadata), RusotoError<UpdateDataSetError>> {
+        let request = self.build_update_data_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDashboardPublishedVersionResponse, _>()?;
+                .deserialize::<UpdateDataSetResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDashboardPublishedVersionError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDataSetError::from_response(response))
         }
     }
 
-    /// <p>Updates a dataset.</p>
-    async fn update_data_set(
+    /// Presigns the request `update_data_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_data_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDataSetRequest,
-    ) -> Result<UpdateDataSetResponse, RusotoError<UpdateDataSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_data_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_data_set_request(&self, input: UpdateDataSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets/{data_set_id}",
             aws_account_id = input.aws_account_id,
@@ -11097,34 +13453,64 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_data_set_permissions`, also returning the metadata of the response.
+    pub async fn update_data_set_permissions_with_metadata(
+        &self,
+        input: UpdateDataSetPermissionsRequest,
+    ) -> Result<
+        (UpdateDataSetPermissionsResponse, ResponseMetadata),
+        RusotoError<UpdateDataSetPermissionsError>,
+    > {
+        let request = self.build_update_data_set_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDataSetResponse, _>()?;
+                .deserialize::<UpdateDataSetPermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDataSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDataSetPermissionsError::from_response(response))
         }
     }
 
-    /// <p>Updates the permissions on a dataset.</p> <p>The permissions resource is <code>arn:aws:quicksight:region:aws-account-id:dataset/data-set-id</code>.</p>
-    async fn update_data_set_permissions(
+    /// Presigns the request `update_data_set_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_data_set_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDataSetPermissionsRequest,
-    ) -> Result<UpdateDataSetPermissionsResponse, RusotoError<UpdateDataSetPermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_data_set_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_data_set_permissions_request(
+        &self,
+        input: UpdateDataSetPermissionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sets/{data_set_id}/permissions",
             aws_account_id = input.aws_account_id,
@@ -11132,34 +13518,59 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSetPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_data_source`, also returning the metadata of the response.
+    pub async fn update_data_source_with_metadata(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<(UpdateDataSourceResponse, ResponseMetadata), RusotoError<UpdateDataSourceError>>
+    {
+        let request = self.build_update_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDataSetPermissionsResponse, _>()?;
+                .deserialize::<UpdateDataSourceResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDataSetPermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Updates a data source.</p>
-    async fn update_data_source(
+    /// Presigns the request `update_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDataSourceRequest,
-    ) -> Result<UpdateDataSourceResponse, RusotoError<UpdateDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_data_source_request(&self, input: UpdateDataSourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sources/{data_source_id}",
             aws_account_id = input.aws_account_id,
@@ -11167,35 +13578,64 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_data_source_permissions`, also returning the metadata of the response.
+    pub async fn update_data_source_permissions_with_metadata(
+        &self,
+        input: UpdateDataSourcePermissionsRequest,
+    ) -> Result<
+        (UpdateDataSourcePermissionsResponse, ResponseMetadata),
+        RusotoError<UpdateDataSourcePermissionsError>,
+    > {
+        let request = self.build_update_data_source_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDataSourceResponse, _>()?;
+                .deserialize::<UpdateDataSourcePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDataSourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDataSourcePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Updates the permissions to a data source.</p>
-    async fn update_data_source_permissions(
+    /// Presigns the request `update_data_source_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_data_source_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDataSourcePermissionsRequest,
-    ) -> Result<UpdateDataSourcePermissionsResponse, RusotoError<UpdateDataSourcePermissionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_data_source_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_data_source_permissions_request(
+        &self,
+        input: UpdateDataSourcePermissionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/data-sources/{data_source_id}/permissions",
             aws_account_id = input.aws_account_id,
@@ -11203,34 +13643,58 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_group`, also returning the metadata of the response.
+    pub async fn update_group_with_metadata(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<(UpdateGroupResponse, ResponseMetadata), RusotoError<UpdateGroupError>> {
+        let request = self.build_update_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDataSourcePermissionsResponse, _>()?;
+                .deserialize::<UpdateGroupResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDataSourcePermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateGroupError::from_response(response))
         }
     }
 
-    /// <p>Changes a group description. </p>
-    async fn update_group(
+    /// Presigns the request `update_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGroupRequest,
-    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_group_request(&self, input: UpdateGroupRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}",
             aws_account_id = input.aws_account_id,
@@ -11239,66 +13703,119 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `update_iam_policy_assignment`, also returning the metadata of the response.
+    pub async fn update_iam_policy_assignment_with_metadata(
+        &self,
+        input: UpdateIAMPolicyAssignmentRequest,
+    ) -> Result<
+        (UpdateIAMPolicyAssignmentResponse, ResponseMetadata),
+        RusotoError<UpdateIAMPolicyAssignmentError>,
+    > {
+        let request = self.build_update_iam_policy_assignment_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGroupResponse, _>()?;
+                .deserialize::<UpdateIAMPolicyAssignmentResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateIAMPolicyAssignmentError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing IAM policy assignment. This operation updates only the optional parameter or parameters that are specified in the request.</p>
-    async fn update_iam_policy_assignment(
+    /// Presigns the request `update_iam_policy_assignment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_iam_policy_assignment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateIAMPolicyAssignmentRequest,
-    ) -> Result<UpdateIAMPolicyAssignmentResponse, RusotoError<UpdateIAMPolicyAssignmentError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_iam_policy_assignment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_iam_policy_assignment_request(
+        &self,
+        input: UpdateIAMPolicyAssignmentRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_template`, also returning the metadata of the response.
+    pub async fn update_template_with_metadata(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> Result<(UpdateTemplateResponse, ResponseMetadata), RusotoError<UpdateTemplateError>> {
+        let request = self.build_update_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateIAMPolicyAssignmentResponse, _>()?;
+                .deserialize::<UpdateTemplateResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIAMPolicyAssignmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateTemplateError::from_response(response))
         }
     }
 
-    /// <p>Updates a template from an existing Amazon QuickSight analysis or another template.</p>
-    async fn update_template(
+    /// Presigns the request `update_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTemplateRequest,
-    ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_template_request(&self, input: UpdateTemplateRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}",
             aws_account_id = input.aws_account_id,
@@ -11306,34 +13823,64 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_template_alias`, also returning the metadata of the response.
+    pub async fn update_template_alias_with_metadata(
+        &self,
+        input: UpdateTemplateAliasRequest,
+    ) -> Result<
+        (UpdateTemplateAliasResponse, ResponseMetadata),
+        RusotoError<UpdateTemplateAliasError>,
+    > {
+        let request = self.build_update_template_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateTemplateResponse, _>()?;
+                .deserialize::<UpdateTemplateAliasResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateTemplateAliasError::from_response(response))
         }
     }
 
-    /// <p>Updates the template alias of a template.</p>
-    async fn update_template_alias(
+    /// Presigns the request `update_template_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_template_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTemplateAliasRequest,
-    ) -> Result<UpdateTemplateAliasResponse, RusotoError<UpdateTemplateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_template_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_template_alias_request(
+        &self,
+        input: UpdateTemplateAliasRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}/aliases/{alias_name}",
             alias_name = input.alias_name,
@@ -11342,35 +13889,64 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_template_permissions`, also returning the metadata of the response.
+    pub async fn update_template_permissions_with_metadata(
+        &self,
+        input: UpdateTemplatePermissionsRequest,
+    ) -> Result<
+        (UpdateTemplatePermissionsResponse, ResponseMetadata),
+        RusotoError<UpdateTemplatePermissionsError>,
+    > {
+        let request = self.build_update_template_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateTemplateAliasResponse, _>()?;
+                .deserialize::<UpdateTemplatePermissionsResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTemplateAliasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateTemplatePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Updates the resource permissions for a template.</p>
-    async fn update_template_permissions(
+    /// Presigns the request `update_template_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_template_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTemplatePermissionsRequest,
-    ) -> Result<UpdateTemplatePermissionsResponse, RusotoError<UpdateTemplatePermissionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_template_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_template_permissions_request(
+        &self,
+        input: UpdateTemplatePermissionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/templates/{template_id}/permissions",
             aws_account_id = input.aws_account_id,
@@ -11378,34 +13954,58 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplatePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_user`, also returning the metadata of the response.
+    pub async fn update_user_with_metadata(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<(UpdateUserResponse, ResponseMetadata), RusotoError<UpdateUserError>> {
+        let request = self.build_update_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateTemplatePermissionsResponse, _>()?;
+                .deserialize::<UpdateUserResponse, _>()?;
 
             result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTemplatePermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUserError::from_response(response))
         }
     }
 
-    /// <p>Updates an Amazon QuickSight user.</p>
-    async fn update_user(
+    /// Presigns the request `update_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserRequest,
-    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_request(&self, input: UpdateUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{aws_account_id}/namespaces/{namespace}/users/{user_name}",
             aws_account_id = input.aws_account_id,
@@ -11414,26 +14014,1205 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListDashboardVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_dashboard_versions_pages(
+        &self,
+        input: ListDashboardVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDashboardVersionsResponse, RusotoError<ListDashboardVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_dashboard_versions(input).await },
+            |input: &ListDashboardVersionsRequest, output: &ListDashboardVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDashboardVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DashboardVersionSummaryList` of every page of `ListDashboardVersions` results for `input`, as `list_dashboard_versions_pages` requests them.
+    pub fn list_dashboard_versions_stream(
+        &self,
+        input: ListDashboardVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DashboardVersionSummary, RusotoError<ListDashboardVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_dashboard_versions_pages(input), |page| {
+            page.dashboard_version_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDashboards` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_dashboards_pages(
+        &self,
+        input: ListDashboardsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDashboardsResponse, RusotoError<ListDashboardsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_dashboards(input).await },
+            |input: &ListDashboardsRequest, output: &ListDashboardsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDashboardsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DashboardSummaryList` of every page of `ListDashboards` results for `input`, as `list_dashboards_pages` requests them.
+    pub fn list_dashboards_stream(
+        &self,
+        input: ListDashboardsRequest,
+    ) -> impl futures::Stream<Item = Result<DashboardSummary, RusotoError<ListDashboardsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_dashboards_pages(input), |page| {
+            page.dashboard_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDataSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_data_sets_pages(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDataSetsResponse, RusotoError<ListDataSetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_data_sets(input).await },
+            |input: &ListDataSetsRequest, output: &ListDataSetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDataSetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DataSetSummaries` of every page of `ListDataSets` results for `input`, as `list_data_sets_pages` requests them.
+    pub fn list_data_sets_stream(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> impl futures::Stream<Item = Result<DataSetSummary, RusotoError<ListDataSetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_data_sets_pages(input), |page| {
+            page.data_set_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDataSources` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_data_sources_pages(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_data_sources(input).await },
+            |input: &ListDataSourcesRequest, output: &ListDataSourcesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDataSourcesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DataSources` of every page of `ListDataSources` results for `input`, as `list_data_sources_pages` requests them.
+    pub fn list_data_sources_stream(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> impl futures::Stream<Item = Result<DataSource, RusotoError<ListDataSourcesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_data_sources_pages(input), |page| {
+            page.data_sources.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGroupMemberships` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_group_memberships_pages(
+        &self,
+        input: ListGroupMembershipsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListGroupMembershipsResponse, RusotoError<ListGroupMembershipsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_group_memberships(input).await },
+            |input: &ListGroupMembershipsRequest, output: &ListGroupMembershipsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGroupMembershipsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `GroupMemberList` of every page of `ListGroupMemberships` results for `input`, as `list_group_memberships_pages` requests them.
+    pub fn list_group_memberships_stream(
+        &self,
+        input: ListGroupMembershipsRequest,
+    ) -> impl futures::Stream<Item = Result<GroupMember, RusotoError<ListGroupMembershipsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_group_memberships_pages(input), |page| {
+            page.group_member_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<ListGroupsResponse, RusotoError<ListGroupsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_groups(input).await },
+            |input: &ListGroupsRequest, output: &ListGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `GroupList` of every page of `ListGroups` results for `input`, as `list_groups_pages` requests them.
+    pub fn list_groups_stream(
+        &self,
+        input: ListGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<Group, RusotoError<ListGroupsError>>> + Send {
+        rusoto_core::pagination::items(self.list_groups_pages(input), |page| {
+            page.group_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListIAMPolicyAssignments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_iam_policy_assignments_pages(
+        &self,
+        input: ListIAMPolicyAssignmentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListIAMPolicyAssignmentsResponse, RusotoError<ListIAMPolicyAssignmentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move {
+                client.list_iam_policy_assignments(input).await
+            },
+            |input: &ListIAMPolicyAssignmentsRequest, output: &ListIAMPolicyAssignmentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIAMPolicyAssignmentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `IAMPolicyAssignments` of every page of `ListIAMPolicyAssignments` results for `input`, as `list_iam_policy_assignments_pages` requests them.
+    pub fn list_iam_policy_assignments_stream(
+        &self,
+        input: ListIAMPolicyAssignmentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<IAMPolicyAssignmentSummary, RusotoError<ListIAMPolicyAssignmentsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_iam_policy_assignments_pages(input), |page| {
+            page.iam_policy_assignments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListIAMPolicyAssignmentsForUser` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_iam_policy_assignments_for_user_pages(
+        &self,
+        input: ListIAMPolicyAssignmentsForUserRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListIAMPolicyAssignmentsForUserResponse,
+            RusotoError<ListIAMPolicyAssignmentsForUserError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move {
+                client.list_iam_policy_assignments_for_user(input).await
+            },
+            |input: &ListIAMPolicyAssignmentsForUserRequest,
+             output: &ListIAMPolicyAssignmentsForUserResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIAMPolicyAssignmentsForUserRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ActiveAssignments` of every page of `ListIAMPolicyAssignmentsForUser` results for `input`, as `list_iam_policy_assignments_for_user_pages` requests them.
+    pub fn list_iam_policy_assignments_for_user_stream(
+        &self,
+        input: ListIAMPolicyAssignmentsForUserRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ActiveIAMPolicyAssignment, RusotoError<ListIAMPolicyAssignmentsForUserError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_iam_policy_assignments_for_user_pages(input),
+            |page| page.active_assignments.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListIngestions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_ingestions_pages(
+        &self,
+        input: ListIngestionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListIngestionsResponse, RusotoError<ListIngestionsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_ingestions(input).await },
+            |input: &ListIngestionsRequest, output: &ListIngestionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIngestionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Ingestions` of every page of `ListIngestions` results for `input`, as `list_ingestions_pages` requests them.
+    pub fn list_ingestions_stream(
+        &self,
+        input: ListIngestionsRequest,
+    ) -> impl futures::Stream<Item = Result<Ingestion, RusotoError<ListIngestionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_ingestions_pages(input), |page| {
+            page.ingestions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTemplateAliases` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_template_aliases_pages(
+        &self,
+        input: ListTemplateAliasesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTemplateAliasesResponse, RusotoError<ListTemplateAliasesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_template_aliases(input).await },
+            |input: &ListTemplateAliasesRequest, output: &ListTemplateAliasesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTemplateAliasesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TemplateAliasList` of every page of `ListTemplateAliases` results for `input`, as `list_template_aliases_pages` requests them.
+    pub fn list_template_aliases_stream(
+        &self,
+        input: ListTemplateAliasesRequest,
+    ) -> impl futures::Stream<Item = Result<TemplateAlias, RusotoError<ListTemplateAliasesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_template_aliases_pages(input), |page| {
+            page.template_alias_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTemplateVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_template_versions_pages(
+        &self,
+        input: ListTemplateVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTemplateVersionsResponse, RusotoError<ListTemplateVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_template_versions(input).await },
+            |input: &ListTemplateVersionsRequest, output: &ListTemplateVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTemplateVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TemplateVersionSummaryList` of every page of `ListTemplateVersions` results for `input`, as `list_template_versions_pages` requests them.
+    pub fn list_template_versions_stream(
+        &self,
+        input: ListTemplateVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<TemplateVersionSummary, RusotoError<ListTemplateVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_template_versions_pages(input), |page| {
+            page.template_version_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTemplates` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_templates_pages(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<ListTemplatesResponse, RusotoError<ListTemplatesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_templates(input).await },
+            |input: &ListTemplatesRequest, output: &ListTemplatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTemplatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TemplateSummaryList` of every page of `ListTemplates` results for `input`, as `list_templates_pages` requests them.
+    pub fn list_templates_stream(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<TemplateSummary, RusotoError<ListTemplatesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_templates_pages(input), |page| {
+            page.template_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUserGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_user_groups_pages(
+        &self,
+        input: ListUserGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<ListUserGroupsResponse, RusotoError<ListUserGroupsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_user_groups(input).await },
+            |input: &ListUserGroupsRequest, output: &ListUserGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUserGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `GroupList` of every page of `ListUserGroups` results for `input`, as `list_user_groups_pages` requests them.
+    pub fn list_user_groups_stream(
+        &self,
+        input: ListUserGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<Group, RusotoError<ListUserGroupsError>>> + Send {
+        rusoto_core::pagination::items(self.list_user_groups_pages(input), |page| {
+            page.group_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUsers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<ListUsersResponse, RusotoError<ListUsersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: QuicksightClient, input| async move { client.list_users(input).await },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUsersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UserList` of every page of `ListUsers` results for `input`, as `list_users_pages` requests them.
+    pub fn list_users_stream(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<User, RusotoError<ListUsersError>>> + Send {
+        rusoto_core::pagination::items(self.list_users_pages(input), |page| {
+            page.user_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Quicksight for QuicksightClient {
+    /// <p>Cancels an ongoing ingestion of data into SPICE.</p>
+    async fn cancel_ingestion(
+        &self,
+        input: CancelIngestionRequest,
+    ) -> Result<CancelIngestionResponse, RusotoError<CancelIngestionError>> {
+        self.cancel_ingestion_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let mut result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateUserResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            result.status = Some(response.status.as_u16() as i64);
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserError::from_response(response))
-        }
+    /// <p>Creates a dashboard from a template. To first create a template, see the CreateTemplate API operation.</p> <p>A dashboard is an entity in QuickSight that identifies QuickSight reports, created from analyses. You can share QuickSight dashboards. With the right permissions, you can create scheduled email reports from them. The <code>CreateDashboard</code>, <code>DescribeDashboard</code>, and <code>ListDashboardsByUser</code> API operations act on the dashboard entity. If you have the correct permissions, you can create a dashboard from a template that exists in a different AWS account.</p>
+    async fn create_dashboard(
+        &self,
+        input: CreateDashboardRequest,
+    ) -> Result<CreateDashboardResponse, RusotoError<CreateDashboardError>> {
+        self.create_dashboard_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a dataset.</p>
+    async fn create_data_set(
+        &self,
+        input: CreateDataSetRequest,
+    ) -> Result<CreateDataSetResponse, RusotoError<CreateDataSetError>> {
+        self.create_data_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a data source.</p>
+    async fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        self.create_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon QuickSight group.</p> <p>The permissions resource is <code>arn:aws:quicksight:us-east-1:<i>&lt;relevant-aws-account-id&gt;</i>:group/default/<i>&lt;group-name&gt;</i> </code>.</p> <p>The response is a group object.</p>
+    async fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.create_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an Amazon QuickSight user to an Amazon QuickSight group. </p>
+    async fn create_group_membership(
+        &self,
+        input: CreateGroupMembershipRequest,
+    ) -> Result<CreateGroupMembershipResponse, RusotoError<CreateGroupMembershipError>> {
+        self.create_group_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an assignment with one specified IAM policy, identified by its Amazon Resource Name (ARN). This policy will be assigned to specified groups or users of Amazon QuickSight. The users and groups need to be in the same namespace. </p>
+    async fn create_iam_policy_assignment(
+        &self,
+        input: CreateIAMPolicyAssignmentRequest,
+    ) -> Result<CreateIAMPolicyAssignmentResponse, RusotoError<CreateIAMPolicyAssignmentError>>
+    {
+        self.create_iam_policy_assignment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates and starts a new SPICE ingestion on a dataset</p> <p>Any ingestions operating on tagged datasets inherit the same tags automatically for use in access control. For an example, see <a href="https://aws.example.com/premiumsupport/knowledge-center/iam-ec2-resource-tags/">How do I create an IAM policy to control access to Amazon EC2 resources using tags?</a> in the AWS Knowledge Center. Tags are visible on the tagged dataset, but not on the ingestion resource.</p>
+    async fn create_ingestion(
+        &self,
+        input: CreateIngestionRequest,
+    ) -> Result<CreateIngestionResponse, RusotoError<CreateIngestionError>> {
+        self.create_ingestion_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a template from an existing QuickSight analysis or template. You can use the resulting template to create a dashboard.</p> <p>A <i>template</i> is an entity in QuickSight that encapsulates the metadata required to create an analysis and that you can use to create s dashboard. A template adds a layer of abstraction by using placeholders to replace the dataset associated with the analysis. You can use templates to create dashboards by replacing dataset placeholders with datasets that follow the same schema that was used to create the source analysis and template.</p>
+    async fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
+        self.create_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a template alias for a template.</p>
+    async fn create_template_alias(
+        &self,
+        input: CreateTemplateAliasRequest,
+    ) -> Result<CreateTemplateAliasResponse, RusotoError<CreateTemplateAliasError>> {
+        self.create_template_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a dashboard.</p>
+    async fn delete_dashboard(
+        &self,
+        input: DeleteDashboardRequest,
+    ) -> Result<DeleteDashboardResponse, RusotoError<DeleteDashboardError>> {
+        self.delete_dashboard_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a dataset.</p>
+    async fn delete_data_set(
+        &self,
+        input: DeleteDataSetRequest,
+    ) -> Result<DeleteDataSetResponse, RusotoError<DeleteDataSetError>> {
+        self.delete_data_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the data source permanently. This action breaks all the datasets that reference the deleted data source.</p>
+    async fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<DeleteDataSourceResponse, RusotoError<DeleteDataSourceError>> {
+        self.delete_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a user group from Amazon QuickSight. </p>
+    async fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+        self.delete_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a user from a group so that the user is no longer a member of the group.</p>
+    async fn delete_group_membership(
+        &self,
+        input: DeleteGroupMembershipRequest,
+    ) -> Result<DeleteGroupMembershipResponse, RusotoError<DeleteGroupMembershipError>> {
+        self.delete_group_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing IAM policy assignment.</p>
+    async fn delete_iam_policy_assignment(
+        &self,
+        input: DeleteIAMPolicyAssignmentRequest,
+    ) -> Result<DeleteIAMPolicyAssignmentResponse, RusotoError<DeleteIAMPolicyAssignmentError>>
+    {
+        self.delete_iam_policy_assignment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a template.</p>
+    async fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
+        self.delete_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the item that the specified template alias points to. If you provide a specific alias, you delete the version of the template that the alias points to.</p>
+    async fn delete_template_alias(
+        &self,
+        input: DeleteTemplateAliasRequest,
+    ) -> Result<DeleteTemplateAliasResponse, RusotoError<DeleteTemplateAliasError>> {
+        self.delete_template_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the Amazon QuickSight user that is associated with the identity of the AWS Identity and Access Management (IAM) user or role that's making the call. The IAM user isn't deleted as a result of this call. </p>
+    async fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.delete_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a user identified by its principal ID. </p>
+    async fn delete_user_by_principal_id(
+        &self,
+        input: DeleteUserByPrincipalIdRequest,
+    ) -> Result<DeleteUserByPrincipalIdResponse, RusotoError<DeleteUserByPrincipalIdError>> {
+        self.delete_user_by_principal_id_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a summary for a dashboard.</p>
+    async fn describe_dashboard(
+        &self,
+        input: DescribeDashboardRequest,
+    ) -> Result<DescribeDashboardResponse, RusotoError<DescribeDashboardError>> {
+        self.describe_dashboard_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes read and write permissions for a dashboard.</p>
+    async fn describe_dashboard_permissions(
+        &self,
+        input: DescribeDashboardPermissionsRequest,
+    ) -> Result<DescribeDashboardPermissionsResponse, RusotoError<DescribeDashboardPermissionsError>>
+    {
+        self.describe_dashboard_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a dataset. </p>
+    async fn describe_data_set(
+        &self,
+        input: DescribeDataSetRequest,
+    ) -> Result<DescribeDataSetResponse, RusotoError<DescribeDataSetError>> {
+        self.describe_data_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the permissions on a dataset.</p> <p>The permissions resource is <code>arn:aws:quicksight:region:aws-account-id:dataset/data-set-id</code>.</p>
+    async fn describe_data_set_permissions(
+        &self,
+        input: DescribeDataSetPermissionsRequest,
+    ) -> Result<DescribeDataSetPermissionsResponse, RusotoError<DescribeDataSetPermissionsError>>
+    {
+        self.describe_data_set_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a data source.</p>
+    async fn describe_data_source(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
+        self.describe_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the resource permissions for a data source.</p>
+    async fn describe_data_source_permissions(
+        &self,
+        input: DescribeDataSourcePermissionsRequest,
+    ) -> Result<
+        DescribeDataSourcePermissionsResponse,
+        RusotoError<DescribeDataSourcePermissionsError>,
+    > {
+        self.describe_data_source_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an Amazon QuickSight group's description and Amazon Resource Name (ARN). </p>
+    async fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> Result<DescribeGroupResponse, RusotoError<DescribeGroupError>> {
+        self.describe_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing IAM policy assignment, as specified by the assignment name.</p>
+    async fn describe_iam_policy_assignment(
+        &self,
+        input: DescribeIAMPolicyAssignmentRequest,
+    ) -> Result<DescribeIAMPolicyAssignmentResponse, RusotoError<DescribeIAMPolicyAssignmentError>>
+    {
+        self.describe_iam_policy_assignment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a SPICE ingestion.</p>
+    async fn describe_ingestion(
+        &self,
+        input: DescribeIngestionRequest,
+    ) -> Result<DescribeIngestionResponse, RusotoError<DescribeIngestionError>> {
+        self.describe_ingestion_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a template's metadata.</p>
+    async fn describe_template(
+        &self,
+        input: DescribeTemplateRequest,
+    ) -> Result<DescribeTemplateResponse, RusotoError<DescribeTemplateError>> {
+        self.describe_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the template alias for a template.</p>
+    async fn describe_template_alias(
+        &self,
+        input: DescribeTemplateAliasRequest,
+    ) -> Result<DescribeTemplateAliasResponse, RusotoError<DescribeTemplateAliasError>> {
+        self.describe_template_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes read and write permissions on a template.</p>
+    async fn describe_template_permissions(
+        &self,
+        input: DescribeTemplatePermissionsRequest,
+    ) -> Result<DescribeTemplatePermissionsResponse, RusotoError<DescribeTemplatePermissionsError>>
+    {
+        self.describe_template_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a user, given the user name. </p>
+    async fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.describe_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a server-side embeddable URL and authorization code. For this process to work properly, first configure the dashboards and user permissions. For more information, see <a href="https://docs.aws.amazon.com/quicksight/latest/user/embedding-dashboards.html">Embedding Amazon QuickSight Dashboards</a> in the <i>Amazon QuickSight User Guide</i> or <a href="https://docs.aws.amazon.com/quicksight/latest/APIReference/qs-dev-embedded-dashboards.html">Embedding Amazon QuickSight Dashboards</a> in the <i>Amazon QuickSight API Reference</i>.</p> <p>Currently, you can use <code>GetDashboardEmbedURL</code> only from the server, not from the user’s browser.</p>
+    async fn get_dashboard_embed_url(
+        &self,
+        input: GetDashboardEmbedUrlRequest,
+    ) -> Result<GetDashboardEmbedUrlResponse, RusotoError<GetDashboardEmbedUrlError>> {
+        self.get_dashboard_embed_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the versions of the dashboards in the QuickSight subscription.</p>
+    async fn list_dashboard_versions(
+        &self,
+        input: ListDashboardVersionsRequest,
+    ) -> Result<ListDashboardVersionsResponse, RusotoError<ListDashboardVersionsError>> {
+        self.list_dashboard_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists dashboards in an AWS account.</p>
+    async fn list_dashboards(
+        &self,
+        input: ListDashboardsRequest,
+    ) -> Result<ListDashboardsResponse, RusotoError<ListDashboardsError>> {
+        self.list_dashboards_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all of the datasets belonging to the current AWS account in an AWS Region.</p> <p>The permissions resource is <code>arn:aws:quicksight:region:aws-account-id:dataset/*</code>.</p>
+    async fn list_data_sets(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> Result<ListDataSetsResponse, RusotoError<ListDataSetsError>> {
+        self.list_data_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists data sources in current AWS Region that belong to this AWS account.</p>
+    async fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+        self.list_data_sources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists member users in a group.</p>
+    async fn list_group_memberships(
+        &self,
+        input: ListGroupMembershipsRequest,
+    ) -> Result<ListGroupMembershipsResponse, RusotoError<ListGroupMembershipsError>> {
+        self.list_group_memberships_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all user groups in Amazon QuickSight. </p>
+    async fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.list_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists IAM policy assignments in the current Amazon QuickSight account.</p>
+    async fn list_iam_policy_assignments(
+        &self,
+        input: ListIAMPolicyAssignmentsRequest,
+    ) -> Result<ListIAMPolicyAssignmentsResponse, RusotoError<ListIAMPolicyAssignmentsError>> {
+        self.list_iam_policy_assignments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the IAM policy assignments, including the Amazon Resource Names (ARNs) for the IAM policies assigned to the specified user and group or groups that the user belongs to.</p>
+    async fn list_iam_policy_assignments_for_user(
+        &self,
+        input: ListIAMPolicyAssignmentsForUserRequest,
+    ) -> Result<
+        ListIAMPolicyAssignmentsForUserResponse,
+        RusotoError<ListIAMPolicyAssignmentsForUserError>,
+    > {
+        self.list_iam_policy_assignments_for_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the history of SPICE ingestions for a dataset.</p>
+    async fn list_ingestions(
+        &self,
+        input: ListIngestionsRequest,
+    ) -> Result<ListIngestionsResponse, RusotoError<ListIngestionsError>> {
+        self.list_ingestions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags assigned to a resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the aliases of a template.</p>
+    async fn list_template_aliases(
+        &self,
+        input: ListTemplateAliasesRequest,
+    ) -> Result<ListTemplateAliasesResponse, RusotoError<ListTemplateAliasesError>> {
+        self.list_template_aliases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the versions of the templates in the current Amazon QuickSight account.</p>
+    async fn list_template_versions(
+        &self,
+        input: ListTemplateVersionsRequest,
+    ) -> Result<ListTemplateVersionsResponse, RusotoError<ListTemplateVersionsError>> {
+        self.list_template_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the templates in the current Amazon QuickSight account.</p>
+    async fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
+        self.list_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the Amazon QuickSight groups that an Amazon QuickSight user is a member of.</p>
+    async fn list_user_groups(
+        &self,
+        input: ListUserGroupsRequest,
+    ) -> Result<ListUserGroupsResponse, RusotoError<ListUserGroupsError>> {
+        self.list_user_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all of the Amazon QuickSight users belonging to this account. </p>
+    async fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.list_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon QuickSight user, whose identity is associated with the AWS Identity and Access Management (IAM) identity or role specified in the request. </p>
+    async fn register_user(
+        &self,
+        input: RegisterUserRequest,
+    ) -> Result<RegisterUserResponse, RusotoError<RegisterUserError>> {
+        self.register_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Assigns one or more tags (key-value pairs) to the specified QuickSight resource. </p> <p>Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only resources with certain tag values. You can use the <code>TagResource</code> operation with a resource that already has tags. If you specify a new tag key for the resource, this tag is appended to the list of tags associated with the resource. If you specify a tag key that is already associated with the resource, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource. QuickSight supports tagging on data set, data source, dashboard, and template. </p> <p>Tagging for QuickSight works in a similar way to tagging for other AWS services, except for the following:</p> <ul> <li> <p>You can&#39;t use tags to track AWS costs for QuickSight. This restriction is because QuickSight costs are based on users and SPICE capacity, which aren&#39;t taggable resources.</p> </li> <li> <p>QuickSight doesn&#39;t currently support the Tag Editor for AWS Resource Groups.</p> </li> </ul></p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a tag or tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a dashboard in an AWS account.</p>
+    async fn update_dashboard(
+        &self,
+        input: UpdateDashboardRequest,
+    ) -> Result<UpdateDashboardResponse, RusotoError<UpdateDashboardError>> {
+        self.update_dashboard_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates read and write permissions on a dashboard.</p>
+    async fn update_dashboard_permissions(
+        &self,
+        input: UpdateDashboardPermissionsRequest,
+    ) -> Result<UpdateDashboardPermissionsResponse, RusotoError<UpdateDashboardPermissionsError>>
+    {
+        self.update_dashboard_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the published version of a dashboard.</p>
+    async fn update_dashboard_published_version(
+        &self,
+        input: UpdateDashboardPublishedVersionRequest,
+    ) -> Result<
+        UpdateDashboardPublishedVersionResponse,
+        RusotoError<UpdateDashboardPublishedVersionError>,
+    > {
+        self.update_dashboard_published_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a dataset.</p>
+    async fn update_data_set(
+        &self,
+        input: UpdateDataSetRequest,
+    ) -> Result<UpdateDataSetResponse, RusotoError<UpdateDataSetError>> {
+        self.update_data_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the permissions on a dataset.</p> <p>The permissions resource is <code>arn:aws:quicksight:region:aws-account-id:dataset/data-set-id</code>.</p>
+    async fn update_data_set_permissions(
+        &self,
+        input: UpdateDataSetPermissionsRequest,
+    ) -> Result<UpdateDataSetPermissionsResponse, RusotoError<UpdateDataSetPermissionsError>> {
+        self.update_data_set_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a data source.</p>
+    async fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<UpdateDataSourceResponse, RusotoError<UpdateDataSourceError>> {
+        self.update_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the permissions to a data source.</p>
+    async fn update_data_source_permissions(
+        &self,
+        input: UpdateDataSourcePermissionsRequest,
+    ) -> Result<UpdateDataSourcePermissionsResponse, RusotoError<UpdateDataSourcePermissionsError>>
+    {
+        self.update_data_source_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes a group description. </p>
+    async fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+        self.update_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing IAM policy assignment. This operation updates only the optional parameter or parameters that are specified in the request.</p>
+    async fn update_iam_policy_assignment(
+        &self,
+        input: UpdateIAMPolicyAssignmentRequest,
+    ) -> Result<UpdateIAMPolicyAssignmentResponse, RusotoError<UpdateIAMPolicyAssignmentError>>
+    {
+        self.update_iam_policy_assignment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a template from an existing Amazon QuickSight analysis or another template.</p>
+    async fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
+        self.update_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the template alias of a template.</p>
+    async fn update_template_alias(
+        &self,
+        input: UpdateTemplateAliasRequest,
+    ) -> Result<UpdateTemplateAliasResponse, RusotoError<UpdateTemplateAliasError>> {
+        self.update_template_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the resource permissions for a template.</p>
+    async fn update_template_permissions(
+        &self,
+        input: UpdateTemplatePermissionsRequest,
+    ) -> Result<UpdateTemplatePermissionsResponse, RusotoError<UpdateTemplatePermissionsError>>
+    {
+        self.update_template_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an Amazon QuickSight user.</p>
+    async fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.update_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1011,6 +1011,7 @@ pub struct UpdateResourceShareResponse {
 
 /// Errors returned by AcceptResourceShareInvitation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptResourceShareInvitationError {
     /// <p>A client token input parameter was reused with an operation, but at least one of the other input parameters is different from the previous call to the operation.</p>
     IdempotentParameterMismatch(String),
@@ -1032,6 +1033,8 @@ pub enum AcceptResourceShareInvitationError {
     ServerInternal(String),
     /// <p>The service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptResourceShareInvitationError {
@@ -1041,63 +1044,78 @@ impl AcceptResourceShareInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptResourceShareInvitationError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptResourceShareInvitationError::InvalidClientToken(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(AcceptResourceShareInvitationError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptResourceShareInvitationError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptResourceShareInvitationError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationAlreadyAcceptedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationAlreadyRejectedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyRejected(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptResourceShareInvitationError::ResourceShareInvitationArnNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationExpiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptResourceShareInvitationError::ResourceShareInvitationExpired(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptResourceShareInvitationError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptResourceShareInvitationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AcceptResourceShareInvitationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1133,12 +1151,16 @@ impl fmt::Display for AcceptResourceShareInvitationError {
             AcceptResourceShareInvitationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AcceptResourceShareInvitationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AcceptResourceShareInvitationError {}
 /// Errors returned by AssociateResourceShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateResourceShareError {
     /// <p>A client token input parameter was reused with an operation, but at least one of the other input parameters is different from the previous call to the operation.</p>
     IdempotentParameterMismatch(String),
@@ -1160,6 +1182,8 @@ pub enum AssociateResourceShareError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateResourceShareError {
@@ -1167,55 +1191,67 @@ impl AssociateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceShareError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(AssociateResourceShareError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResourceShareError::InvalidClientToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResourceShareError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceShareError::InvalidStateTransition(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(AssociateResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        AssociateResourceShareError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceShareError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ResourceShareLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceShareError::ResourceShareLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(AssociateResourceShareError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResourceShareError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateResourceShareError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResourceShareError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(AssociateResourceShareError::UnknownResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResourceShareError::UnknownResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateResourceShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1241,12 +1277,14 @@ impl fmt::Display for AssociateResourceShareError {
             AssociateResourceShareError::ServerInternal(ref cause) => write!(f, "{}", cause),
             AssociateResourceShareError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             AssociateResourceShareError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            AssociateResourceShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateResourceShareError {}
 /// Errors returned by AssociateResourceSharePermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateResourceSharePermissionError {
     /// <p>A client token is not valid.</p>
     InvalidClientToken(String),
@@ -1262,6 +1300,8 @@ pub enum AssociateResourceSharePermissionError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateResourceSharePermissionError {
@@ -1271,42 +1311,54 @@ impl AssociateResourceSharePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceSharePermissionError::InvalidClientToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceSharePermissionError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceSharePermissionError::MalformedArn(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceSharePermissionError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceSharePermissionError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceSharePermissionError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResourceSharePermissionError::UnknownResource(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateResourceSharePermissionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1337,12 +1389,16 @@ impl fmt::Display for AssociateResourceSharePermissionError {
             AssociateResourceSharePermissionError::UnknownResource(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateResourceSharePermissionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateResourceSharePermissionError {}
 /// Errors returned by CreateResourceShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResourceShareError {
     /// <p>A client token input parameter was reused with an operation, but at least one of the other input parameters is different from the previous call to the operation.</p>
     IdempotentParameterMismatch(String),
@@ -1366,6 +1422,8 @@ pub enum CreateResourceShareError {
     TagPolicyViolation(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResourceShareError {
@@ -1373,56 +1431,73 @@ impl CreateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateResourceShareError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(CreateResourceShareError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceShareError::InvalidClientToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceShareError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(CreateResourceShareError::InvalidStateTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceShareError::InvalidStateTransition(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(CreateResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        CreateResourceShareError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateResourceShareError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceShareError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceShareLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateResourceShareError::ResourceShareLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateResourceShareError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        CreateResourceShareError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateResourceShareError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceShareError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TagPolicyViolationException" => {
-                    return RusotoError::Service(CreateResourceShareError::TagPolicyViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceShareError::TagPolicyViolation(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(CreateResourceShareError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        CreateResourceShareError::UnknownResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateResourceShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1447,12 +1522,14 @@ impl fmt::Display for CreateResourceShareError {
             CreateResourceShareError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateResourceShareError::TagPolicyViolation(ref cause) => write!(f, "{}", cause),
             CreateResourceShareError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            CreateResourceShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateResourceShareError {}
 /// Errors returned by DeleteResourceShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourceShareError {
     /// <p>A client token input parameter was reused with an operation, but at least one of the other input parameters is different from the previous call to the operation.</p>
     IdempotentParameterMismatch(String),
@@ -1472,6 +1549,8 @@ pub enum DeleteResourceShareError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourceShareError {
@@ -1479,46 +1558,61 @@ impl DeleteResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteResourceShareError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(DeleteResourceShareError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourceShareError::InvalidClientToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourceShareError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(DeleteResourceShareError::InvalidStateTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourceShareError::InvalidStateTransition(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(DeleteResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        DeleteResourceShareError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteResourceShareError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourceShareError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DeleteResourceShareError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        DeleteResourceShareError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteResourceShareError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourceShareError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(DeleteResourceShareError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        DeleteResourceShareError::UnknownResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourceShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1539,12 +1633,14 @@ impl fmt::Display for DeleteResourceShareError {
             DeleteResourceShareError::ServerInternal(ref cause) => write!(f, "{}", cause),
             DeleteResourceShareError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteResourceShareError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            DeleteResourceShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourceShareError {}
 /// Errors returned by DisassociateResourceShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateResourceShareError {
     /// <p>A client token input parameter was reused with an operation, but at least one of the other input parameters is different from the previous call to the operation.</p>
     IdempotentParameterMismatch(String),
@@ -1566,6 +1662,8 @@ pub enum DisassociateResourceShareError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateResourceShareError {
@@ -1573,57 +1671,69 @@ impl DisassociateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceShareError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceShareError::InvalidClientToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateResourceShareError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceShareError::InvalidStateTransition(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateResourceShareError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceShareError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ResourceShareLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceShareError::ResourceShareLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateResourceShareError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceShareError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::UnknownResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateResourceShareError::UnknownResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DisassociateResourceShareError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1651,12 +1761,14 @@ impl fmt::Display for DisassociateResourceShareError {
             DisassociateResourceShareError::ServerInternal(ref cause) => write!(f, "{}", cause),
             DisassociateResourceShareError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DisassociateResourceShareError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            DisassociateResourceShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateResourceShareError {}
 /// Errors returned by DisassociateResourceSharePermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateResourceSharePermissionError {
     /// <p>A client token is not valid.</p>
     InvalidClientToken(String),
@@ -1672,6 +1784,8 @@ pub enum DisassociateResourceSharePermissionError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateResourceSharePermissionError {
@@ -1681,42 +1795,54 @@ impl DisassociateResourceSharePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceSharePermissionError::InvalidClientToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceSharePermissionError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceSharePermissionError::MalformedArn(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceSharePermissionError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceSharePermissionError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceSharePermissionError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResourceSharePermissionError::UnknownResource(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateResourceSharePermissionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1747,12 +1873,16 @@ impl fmt::Display for DisassociateResourceSharePermissionError {
             DisassociateResourceSharePermissionError::UnknownResource(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateResourceSharePermissionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateResourceSharePermissionError {}
 /// Errors returned by EnableSharingWithAwsOrganization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableSharingWithAwsOrganizationError {
     /// <p>The requested operation is not permitted.</p>
     OperationNotPermitted(String),
@@ -1760,6 +1890,8 @@ pub enum EnableSharingWithAwsOrganizationError {
     ServerInternal(String),
     /// <p>The service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableSharingWithAwsOrganizationError {
@@ -1769,22 +1901,30 @@ impl EnableSharingWithAwsOrganizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableSharingWithAwsOrganizationError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableSharingWithAwsOrganizationError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableSharingWithAwsOrganizationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        EnableSharingWithAwsOrganizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1803,12 +1943,16 @@ impl fmt::Display for EnableSharingWithAwsOrganizationError {
             EnableSharingWithAwsOrganizationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            EnableSharingWithAwsOrganizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for EnableSharingWithAwsOrganizationError {}
 /// Errors returned by GetPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPermissionError {
     /// <p>A parameter is not valid.</p>
     InvalidParameter(String),
@@ -1822,6 +1966,8 @@ pub enum GetPermissionError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPermissionError {
@@ -1829,25 +1975,34 @@ impl GetPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetPermissionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetPermissionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetPermissionError::MalformedArn(err.msg))
+                    return RusotoError::service(GetPermissionError::MalformedArn(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetPermissionError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        GetPermissionError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetPermissionError::ServerInternal(err.msg))
+                    return RusotoError::service(GetPermissionError::ServerInternal(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPermissionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetPermissionError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetPermissionError::UnknownResource(err.msg))
+                    return RusotoError::service(GetPermissionError::UnknownResource(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPermissionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1863,12 +2018,14 @@ impl fmt::Display for GetPermissionError {
             GetPermissionError::ServerInternal(ref cause) => write!(f, "{}", cause),
             GetPermissionError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetPermissionError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            GetPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPermissionError {}
 /// Errors returned by GetResourcePolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourcePoliciesError {
     /// <p>The specified value for NextToken is not valid.</p>
     InvalidNextToken(String),
@@ -1880,6 +2037,8 @@ pub enum GetResourcePoliciesError {
     ServerInternal(String),
     /// <p>The service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourcePoliciesError {
@@ -1887,28 +2046,37 @@ impl GetResourcePoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourcePoliciesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourcePoliciesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        GetResourcePoliciesError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        GetResourcePoliciesError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourcePoliciesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourcePoliciesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1923,12 +2091,14 @@ impl fmt::Display for GetResourcePoliciesError {
             GetResourcePoliciesError::MalformedArn(ref cause) => write!(f, "{}", cause),
             GetResourcePoliciesError::ServerInternal(ref cause) => write!(f, "{}", cause),
             GetResourcePoliciesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetResourcePoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourcePoliciesError {}
 /// Errors returned by GetResourceShareAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourceShareAssociationsError {
     /// <p>The specified value for NextToken is not valid.</p>
     InvalidNextToken(String),
@@ -1944,6 +2114,8 @@ pub enum GetResourceShareAssociationsError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourceShareAssociationsError {
@@ -1953,42 +2125,54 @@ impl GetResourceShareAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareAssociationsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareAssociationsError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourceShareAssociationsError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceShareAssociationsError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareAssociationsError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourceShareAssociationsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceShareAssociationsError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareAssociationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareAssociationsError::UnknownResource(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetResourceShareAssociationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2013,12 +2197,14 @@ impl fmt::Display for GetResourceShareAssociationsError {
                 write!(f, "{}", cause)
             }
             GetResourceShareAssociationsError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            GetResourceShareAssociationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourceShareAssociationsError {}
 /// Errors returned by GetResourceShareInvitations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourceShareInvitationsError {
     /// <p>The specified value for MaxResults is not valid.</p>
     InvalidMaxResults(String),
@@ -2034,6 +2220,8 @@ pub enum GetResourceShareInvitationsError {
     ServerInternal(String),
     /// <p>The service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourceShareInvitationsError {
@@ -2043,44 +2231,56 @@ impl GetResourceShareInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareInvitationsError::InvalidMaxResults(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareInvitationsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareInvitationsError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourceShareInvitationsError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceShareInvitationsError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareInvitationsError::ResourceShareInvitationArnNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourceShareInvitationsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceShareInvitationsError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceShareInvitationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetResourceShareInvitationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2103,12 +2303,14 @@ impl fmt::Display for GetResourceShareInvitationsError {
             GetResourceShareInvitationsError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetResourceShareInvitationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourceShareInvitationsError {}
 /// Errors returned by GetResourceShares
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourceSharesError {
     /// <p>The specified value for NextToken is not valid.</p>
     InvalidNextToken(String),
@@ -2122,6 +2324,8 @@ pub enum GetResourceSharesError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourceSharesError {
@@ -2129,27 +2333,43 @@ impl GetResourceSharesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetResourceSharesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        GetResourceSharesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResourceSharesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetResourceSharesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourceSharesError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        GetResourceSharesError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourceSharesError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        GetResourceSharesError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetResourceSharesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceSharesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetResourceSharesError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        GetResourceSharesError::UnknownResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourceSharesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2165,12 +2385,14 @@ impl fmt::Display for GetResourceSharesError {
             GetResourceSharesError::ServerInternal(ref cause) => write!(f, "{}", cause),
             GetResourceSharesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetResourceSharesError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            GetResourceSharesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourceSharesError {}
 /// Errors returned by ListPendingInvitationResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPendingInvitationResourcesError {
     /// <p>The specified value for NextToken is not valid.</p>
     InvalidNextToken(String),
@@ -2190,6 +2412,8 @@ pub enum ListPendingInvitationResourcesError {
     ServerInternal(String),
     /// <p>The service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPendingInvitationResourcesError {
@@ -2199,58 +2423,72 @@ impl ListPendingInvitationResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPendingInvitationResourcesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPendingInvitationResourcesError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListPendingInvitationResourcesError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPendingInvitationResourcesError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPendingInvitationResourcesError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationAlreadyRejectedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPendingInvitationResourcesError::ResourceShareInvitationAlreadyRejected(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPendingInvitationResourcesError::ResourceShareInvitationArnNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationExpiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPendingInvitationResourcesError::ResourceShareInvitationExpired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPendingInvitationResourcesError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPendingInvitationResourcesError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListPendingInvitationResourcesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2285,12 +2523,16 @@ impl fmt::Display for ListPendingInvitationResourcesError {
             ListPendingInvitationResourcesError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListPendingInvitationResourcesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListPendingInvitationResourcesError {}
 /// Errors returned by ListPermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPermissionsError {
     /// <p>The specified value for NextToken is not valid.</p>
     InvalidNextToken(String),
@@ -2302,6 +2544,8 @@ pub enum ListPermissionsError {
     ServerInternal(String),
     /// <p>The service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPermissionsError {
@@ -2309,24 +2553,37 @@ impl ListPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListPermissionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListPermissionsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListPermissionsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPermissionsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListPermissionsError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        ListPermissionsError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPermissionsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListPermissionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2341,12 +2598,14 @@ impl fmt::Display for ListPermissionsError {
             ListPermissionsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             ListPermissionsError::ServerInternal(ref cause) => write!(f, "{}", cause),
             ListPermissionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListPermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPermissionsError {}
 /// Errors returned by ListPrincipals
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPrincipalsError {
     /// <p>The specified value for NextToken is not valid.</p>
     InvalidNextToken(String),
@@ -2360,6 +2619,8 @@ pub enum ListPrincipalsError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPrincipalsError {
@@ -2367,25 +2628,37 @@ impl ListPrincipalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPrincipalsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListPrincipalsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPrincipalsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListPrincipalsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListPrincipalsError::MalformedArn(err.msg))
+                    return RusotoError::service(ListPrincipalsError::MalformedArn(err.msg), &res)
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListPrincipalsError::ServerInternal(err.msg))
+                    return RusotoError::service(ListPrincipalsError::ServerInternal(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPrincipalsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListPrincipalsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(ListPrincipalsError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        ListPrincipalsError::UnknownResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPrincipalsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2401,12 +2674,14 @@ impl fmt::Display for ListPrincipalsError {
             ListPrincipalsError::ServerInternal(ref cause) => write!(f, "{}", cause),
             ListPrincipalsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListPrincipalsError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            ListPrincipalsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPrincipalsError {}
 /// Errors returned by ListResourceSharePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourceSharePermissionsError {
     /// <p>The specified value for NextToken is not valid.</p>
     InvalidNextToken(String),
@@ -2422,6 +2697,8 @@ pub enum ListResourceSharePermissionsError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourceSharePermissionsError {
@@ -2431,42 +2708,54 @@ impl ListResourceSharePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourceSharePermissionsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourceSharePermissionsError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListResourceSharePermissionsError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceSharePermissionsError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourceSharePermissionsError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourceSharePermissionsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceSharePermissionsError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourceSharePermissionsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourceSharePermissionsError::UnknownResource(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListResourceSharePermissionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2491,12 +2780,14 @@ impl fmt::Display for ListResourceSharePermissionsError {
                 write!(f, "{}", cause)
             }
             ListResourceSharePermissionsError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            ListResourceSharePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourceSharePermissionsError {}
 /// Errors returned by ListResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourcesError {
     /// <p>The specified value for NextToken is not valid.</p>
     InvalidNextToken(String),
@@ -2512,6 +2803,8 @@ pub enum ListResourcesError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourcesError {
@@ -2519,28 +2812,40 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListResourcesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListResourcesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceTypeException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidResourceType(err.msg))
+                    return RusotoError::service(
+                        ListResourcesError::InvalidResourceType(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListResourcesError::MalformedArn(err.msg))
+                    return RusotoError::service(ListResourcesError::MalformedArn(err.msg), &res)
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourcesError::ServerInternal(err.msg))
+                    return RusotoError::service(ListResourcesError::ServerInternal(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListResourcesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListResourcesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(ListResourcesError::UnknownResource(err.msg))
+                    return RusotoError::service(ListResourcesError::UnknownResource(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2557,12 +2862,14 @@ impl fmt::Display for ListResourcesError {
             ListResourcesError::ServerInternal(ref cause) => write!(f, "{}", cause),
             ListResourcesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListResourcesError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            ListResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourcesError {}
 /// Errors returned by PromoteResourceShareCreatedFromPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PromoteResourceShareCreatedFromPolicyError {
     /// <p>A parameter is not valid.</p>
     InvalidParameter(String),
@@ -2576,6 +2883,8 @@ pub enum PromoteResourceShareCreatedFromPolicyError {
     ServerInternal(String),
     /// <p>The service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PromoteResourceShareCreatedFromPolicyError {
@@ -2585,39 +2894,50 @@ impl PromoteResourceShareCreatedFromPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PromoteResourceShareCreatedFromPolicyError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PromoteResourceShareCreatedFromPolicyError::MalformedArn(err.msg),
+                        &res,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PromoteResourceShareCreatedFromPolicyError::MissingRequiredParameter(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PromoteResourceShareCreatedFromPolicyError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PromoteResourceShareCreatedFromPolicyError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PromoteResourceShareCreatedFromPolicyError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PromoteResourceShareCreatedFromPolicyError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2645,12 +2965,16 @@ impl fmt::Display for PromoteResourceShareCreatedFromPolicyError {
             PromoteResourceShareCreatedFromPolicyError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PromoteResourceShareCreatedFromPolicyError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PromoteResourceShareCreatedFromPolicyError {}
 /// Errors returned by RejectResourceShareInvitation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RejectResourceShareInvitationError {
     /// <p>A client token input parameter was reused with an operation, but at least one of the other input parameters is different from the previous call to the operation.</p>
     IdempotentParameterMismatch(String),
@@ -2672,6 +2996,8 @@ pub enum RejectResourceShareInvitationError {
     ServerInternal(String),
     /// <p>The service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RejectResourceShareInvitationError {
@@ -2681,63 +3007,78 @@ impl RejectResourceShareInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectResourceShareInvitationError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectResourceShareInvitationError::InvalidClientToken(err.msg),
+                        &res,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(RejectResourceShareInvitationError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RejectResourceShareInvitationError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectResourceShareInvitationError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationAlreadyAcceptedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationAlreadyRejectedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectResourceShareInvitationError::ResourceShareInvitationAlreadyRejected(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectResourceShareInvitationError::ResourceShareInvitationArnNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceShareInvitationExpiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectResourceShareInvitationError::ResourceShareInvitationExpired(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectResourceShareInvitationError::ServerInternal(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectResourceShareInvitationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RejectResourceShareInvitationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2773,12 +3114,16 @@ impl fmt::Display for RejectResourceShareInvitationError {
             RejectResourceShareInvitationError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RejectResourceShareInvitationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RejectResourceShareInvitationError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>A parameter is not valid.</p>
     InvalidParameter(String),
@@ -2794,6 +3139,8 @@ pub enum TagResourceError {
     TagLimitExceeded(String),
     /// <p>The specified tag is a reserved word and cannot be used.</p>
     TagPolicyViolation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2801,28 +3148,37 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(TagResourceError::MalformedArn(err.msg))
+                    return RusotoError::service(TagResourceError::MalformedArn(err.msg), &res)
                 }
                 "ResourceArnNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceArnNotFound(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ResourceArnNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(TagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(TagResourceError::ServerInternal(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::TagLimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::TagLimitExceeded(err.msg), &res)
                 }
                 "TagPolicyViolationException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicyViolation(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::TagPolicyViolation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2839,12 +3195,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             TagResourceError::TagLimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::TagPolicyViolation(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>A parameter is not valid.</p>
     InvalidParameter(String),
@@ -2852,6 +3210,8 @@ pub enum UntagResourceError {
     ServerInternal(String),
     /// <p>The service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2859,16 +3219,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UntagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(UntagResourceError::ServerInternal(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2881,12 +3247,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ServerInternal(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateResourceShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResourceShareError {
     /// <p>A client token input parameter was reused with an operation, but at least one of the other input parameters is different from the previous call to the operation.</p>
     IdempotentParameterMismatch(String),
@@ -2906,6 +3274,8 @@ pub enum UpdateResourceShareError {
     ServiceUnavailable(String),
     /// <p>A specified resource was not found.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateResourceShareError {
@@ -2913,46 +3283,61 @@ impl UpdateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateResourceShareError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(UpdateResourceShareError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResourceShareError::InvalidClientToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResourceShareError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(UpdateResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        UpdateResourceShareError::MalformedArn(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateResourceShareError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateResourceShareError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResourceShareError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UpdateResourceShareError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        UpdateResourceShareError::ServerInternal(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateResourceShareError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResourceShareError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(UpdateResourceShareError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        UpdateResourceShareError::UnknownResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateResourceShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2973,6 +3358,7 @@ impl fmt::Display for UpdateResourceShareError {
             UpdateResourceShareError::ServerInternal(ref cause) => write!(f, "{}", cause),
             UpdateResourceShareError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateResourceShareError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            UpdateResourceShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3149,6 +3535,7 @@ impl RamClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> RamClient {
         RamClient {
             client: Client::shared(),
@@ -3174,146 +3561,318 @@ impl RamClient {
     pub fn new_with_client(client: Client, region: region::Region) -> RamClient {
         RamClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<RamClient> {
+        rusoto_core::ClientBuilder::new(RamClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> RamClient {
+        RamClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Ram for RamClient {
-    /// <p>Accepts an invitation to a resource share from another AWS account.</p>
-    async fn accept_resource_share_invitation(
+impl RamClient {
+    /// Calls `accept_resource_share_invitation`, also returning the metadata of the response.
+    pub async fn accept_resource_share_invitation_with_metadata(
         &self,
         input: AcceptResourceShareInvitationRequest,
     ) -> Result<
-        AcceptResourceShareInvitationResponse,
+        (AcceptResourceShareInvitationResponse, ResponseMetadata),
         RusotoError<AcceptResourceShareInvitationError>,
     > {
+        let request = self.build_accept_resource_share_invitation_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcceptResourceShareInvitationResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AcceptResourceShareInvitationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `accept_resource_share_invitation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_resource_share_invitation(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_resource_share_invitation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_resource_share_invitation_request(
+        &self,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> SignedRequest {
         let request_uri = "/acceptresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AcceptResourceShareInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `associate_resource_share`, also returning the metadata of the response.
+    pub async fn associate_resource_share_with_metadata(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> Result<
+        (AssociateResourceShareResponse, ResponseMetadata),
+        RusotoError<AssociateResourceShareError>,
+    > {
+        let request = self.build_associate_resource_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AcceptResourceShareInvitationResponse, _>()?;
+                .deserialize::<AssociateResourceShareResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AcceptResourceShareInvitationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociateResourceShareError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified resource share with the specified principals and resources.</p>
-    async fn associate_resource_share(
+    /// Presigns the request `associate_resource_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_resource_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateResourceShareRequest,
-    ) -> Result<AssociateResourceShareResponse, RusotoError<AssociateResourceShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_resource_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_resource_share_request(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> SignedRequest {
         let request_uri = "/associateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AssociateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `associate_resource_share_permission`, also returning the metadata of the response.
+    pub async fn associate_resource_share_permission_with_metadata(
+        &self,
+        input: AssociateResourceSharePermissionRequest,
+    ) -> Result<
+        (AssociateResourceSharePermissionResponse, ResponseMetadata),
+        RusotoError<AssociateResourceSharePermissionError>,
+    > {
+        let request = self.build_associate_resource_share_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateResourceShareResponse, _>()?;
+                .deserialize::<AssociateResourceSharePermissionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateResourceShareError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociateResourceSharePermissionError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Associates a permission with a resource share.</p>
-    async fn associate_resource_share_permission(
+    /// Presigns the request `associate_resource_share_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_resource_share_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateResourceSharePermissionRequest,
-    ) -> Result<
-        AssociateResourceSharePermissionResponse,
-        RusotoError<AssociateResourceSharePermissionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_resource_share_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_resource_share_permission_request(
+        &self,
+        input: AssociateResourceSharePermissionRequest,
+    ) -> SignedRequest {
         let request_uri = "/associateresourcesharepermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AssociateResourceSharePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_resource_share`, also returning the metadata of the response.
+    pub async fn create_resource_share_with_metadata(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> Result<
+        (CreateResourceShareResponse, ResponseMetadata),
+        RusotoError<CreateResourceShareError>,
+    > {
+        let request = self.build_create_resource_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateResourceSharePermissionResponse, _>()?;
+                .deserialize::<CreateResourceShareResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateResourceSharePermissionError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateResourceShareError::from_response(response))
         }
     }
 
-    /// <p>Creates a resource share.</p>
-    async fn create_resource_share(
+    /// Presigns the request `create_resource_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resource_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResourceShareRequest,
-    ) -> Result<CreateResourceShareResponse, RusotoError<CreateResourceShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resource_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resource_share_request(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> SignedRequest {
         let request_uri = "/createresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("CreateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_resource_share`, also returning the metadata of the response.
+    pub async fn delete_resource_share_with_metadata(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> Result<
+        (DeleteResourceShareResponse, ResponseMetadata),
+        RusotoError<DeleteResourceShareError>,
+    > {
+        let request = self.build_delete_resource_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateResourceShareResponse, _>()?;
+                .deserialize::<DeleteResourceShareResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResourceShareError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteResourceShareError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified resource share.</p>
-    async fn delete_resource_share(
+    /// Presigns the request `delete_resource_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourceShareRequest,
-    ) -> Result<DeleteResourceShareResponse, RusotoError<DeleteResourceShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_share_request(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> SignedRequest {
         let request_uri = "/deleteresourceshare";
 
         let mut request = SignedRequest::new("DELETE", "ram", &self.region, &request_uri);
+        request.set_operation("DeleteResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3323,582 +3882,1539 @@ impl Ram for RamClient {
         params.put("resourceShareArn", &input.resource_share_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `disassociate_resource_share`, also returning the metadata of the response.
+    pub async fn disassociate_resource_share_with_metadata(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> Result<
+        (DisassociateResourceShareResponse, ResponseMetadata),
+        RusotoError<DisassociateResourceShareError>,
+    > {
+        let request = self.build_disassociate_resource_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteResourceShareResponse, _>()?;
+                .deserialize::<DisassociateResourceShareResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourceShareError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateResourceShareError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified principals or resources from the specified resource share.</p>
-    async fn disassociate_resource_share(
+    /// Presigns the request `disassociate_resource_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_resource_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateResourceShareRequest,
-    ) -> Result<DisassociateResourceShareResponse, RusotoError<DisassociateResourceShareError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_resource_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_resource_share_request(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> SignedRequest {
         let request_uri = "/disassociateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("DisassociateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `disassociate_resource_share_permission`, also returning the metadata of the response.
+    pub async fn disassociate_resource_share_permission_with_metadata(
+        &self,
+        input: DisassociateResourceSharePermissionRequest,
+    ) -> Result<
+        (
+            DisassociateResourceSharePermissionResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DisassociateResourceSharePermissionError>,
+    > {
+        let request = self.build_disassociate_resource_share_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateResourceShareResponse, _>()?;
+                .deserialize::<DisassociateResourceSharePermissionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateResourceShareError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateResourceSharePermissionError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Disassociates an AWS RAM permission from a resource share.</p>
-    async fn disassociate_resource_share_permission(
+    /// Presigns the request `disassociate_resource_share_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_resource_share_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateResourceSharePermissionRequest,
-    ) -> Result<
-        DisassociateResourceSharePermissionResponse,
-        RusotoError<DisassociateResourceSharePermissionError>,
-    > {
-        let request_uri = "/disassociateresourcesharepermission";
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_resource_share_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_resource_share_permission_request(
+        &self,
+        input: DisassociateResourceSharePermissionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/disassociateresourcesharepermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("DisassociateResourceSharePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `enable_sharing_with_aws_organization`, also returning the metadata of the response.
+    pub async fn enable_sharing_with_aws_organization_with_metadata(
+        &self,
+    ) -> Result<
+        (EnableSharingWithAwsOrganizationResponse, ResponseMetadata),
+        RusotoError<EnableSharingWithAwsOrganizationError>,
+    > {
+        let request = self.build_enable_sharing_with_aws_organization_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateResourceSharePermissionResponse, _>()?;
+                .deserialize::<EnableSharingWithAwsOrganizationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateResourceSharePermissionError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(EnableSharingWithAwsOrganizationError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Enables resource sharing within your AWS Organization.</p> <p>The caller must be the master account for the AWS Organization.</p>
-    async fn enable_sharing_with_aws_organization(
+    /// Presigns the request `enable_sharing_with_aws_organization` would send with `presigner` instead of sending it.
+    pub async fn presign_enable_sharing_with_aws_organization(
         &self,
-    ) -> Result<
-        EnableSharingWithAwsOrganizationResponse,
-        RusotoError<EnableSharingWithAwsOrganizationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_sharing_with_aws_organization_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_sharing_with_aws_organization_request(&self) -> SignedRequest {
         let request_uri = "/enablesharingwithawsorganization";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("EnableSharingWithAwsOrganization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_permission`, also returning the metadata of the response.
+    pub async fn get_permission_with_metadata(
+        &self,
+        input: GetPermissionRequest,
+    ) -> Result<(GetPermissionResponse, ResponseMetadata), RusotoError<GetPermissionError>> {
+        let request = self.build_get_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<EnableSharingWithAwsOrganizationResponse, _>()?;
+                .deserialize::<GetPermissionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableSharingWithAwsOrganizationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetPermissionError::from_response(response))
         }
     }
 
-    /// <p>Gets the contents of an AWS RAM permission in JSON format.</p>
-    async fn get_permission(
+    /// Presigns the request `get_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPermissionRequest,
-    ) -> Result<GetPermissionResponse, RusotoError<GetPermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_permission_request(&self, input: GetPermissionRequest) -> SignedRequest {
         let request_uri = "/getpermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_resource_policies`, also returning the metadata of the response.
+    pub async fn get_resource_policies_with_metadata(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> Result<
+        (GetResourcePoliciesResponse, ResponseMetadata),
+        RusotoError<GetResourcePoliciesError>,
+    > {
+        let request = self.build_get_resource_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPermissionResponse, _>()?;
+                .deserialize::<GetResourcePoliciesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPermissionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetResourcePoliciesError::from_response(response))
         }
     }
 
-    /// <p>Gets the policies for the specified resources that you own and have shared.</p>
-    async fn get_resource_policies(
+    /// Presigns the request `get_resource_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_policies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourcePoliciesRequest,
-    ) -> Result<GetResourcePoliciesResponse, RusotoError<GetResourcePoliciesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_policies_request(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> SignedRequest {
         let request_uri = "/getresourcepolicies";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourcePolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_resource_share_associations`, also returning the metadata of the response.
+    pub async fn get_resource_share_associations_with_metadata(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> Result<
+        (GetResourceShareAssociationsResponse, ResponseMetadata),
+        RusotoError<GetResourceShareAssociationsError>,
+    > {
+        let request = self.build_get_resource_share_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourcePoliciesResponse, _>()?;
+                .deserialize::<GetResourceShareAssociationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourcePoliciesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetResourceShareAssociationsError::from_response(response))
         }
     }
 
-    /// <p>Gets the resources or principals for the resource shares that you own.</p>
-    async fn get_resource_share_associations(
+    /// Presigns the request `get_resource_share_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_share_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourceShareAssociationsRequest,
-    ) -> Result<GetResourceShareAssociationsResponse, RusotoError<GetResourceShareAssociationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_share_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_share_associations_request(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> SignedRequest {
         let request_uri = "/getresourceshareassociations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShareAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_resource_share_invitations`, also returning the metadata of the response.
+    pub async fn get_resource_share_invitations_with_metadata(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> Result<
+        (GetResourceShareInvitationsResponse, ResponseMetadata),
+        RusotoError<GetResourceShareInvitationsError>,
+    > {
+        let request = self.build_get_resource_share_invitations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourceShareAssociationsResponse, _>()?;
+                .deserialize::<GetResourceShareInvitationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourceShareAssociationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetResourceShareInvitationsError::from_response(response))
         }
     }
 
-    /// <p>Gets the invitations for resource sharing that you've received.</p>
-    async fn get_resource_share_invitations(
+    /// Presigns the request `get_resource_share_invitations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_share_invitations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourceShareInvitationsRequest,
-    ) -> Result<GetResourceShareInvitationsResponse, RusotoError<GetResourceShareInvitationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_share_invitations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_share_invitations_request(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> SignedRequest {
         let request_uri = "/getresourceshareinvitations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShareInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_resource_shares`, also returning the metadata of the response.
+    pub async fn get_resource_shares_with_metadata(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> Result<(GetResourceSharesResponse, ResponseMetadata), RusotoError<GetResourceSharesError>>
+    {
+        let request = self.build_get_resource_shares_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourceShareInvitationsResponse, _>()?;
+                .deserialize::<GetResourceSharesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourceShareInvitationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetResourceSharesError::from_response(response))
         }
     }
 
-    /// <p>Gets the resource shares that you own or the resource shares that are shared with you.</p>
-    async fn get_resource_shares(
+    /// Presigns the request `get_resource_shares` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_shares(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourceSharesRequest,
-    ) -> Result<GetResourceSharesResponse, RusotoError<GetResourceSharesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_shares_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_shares_request(&self, input: GetResourceSharesRequest) -> SignedRequest {
         let request_uri = "/getresourceshares";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShares");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_pending_invitation_resources`, also returning the metadata of the response.
+    pub async fn list_pending_invitation_resources_with_metadata(
+        &self,
+        input: ListPendingInvitationResourcesRequest,
+    ) -> Result<
+        (ListPendingInvitationResourcesResponse, ResponseMetadata),
+        RusotoError<ListPendingInvitationResourcesError>,
+    > {
+        let request = self.build_list_pending_invitation_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourceSharesResponse, _>()?;
+                .deserialize::<ListPendingInvitationResourcesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourceSharesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPendingInvitationResourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists the resources in a resource share that is shared with you but that the invitation is still pending for.</p>
-    async fn list_pending_invitation_resources(
+    /// Presigns the request `list_pending_invitation_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_pending_invitation_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPendingInvitationResourcesRequest,
-    ) -> Result<
-        ListPendingInvitationResourcesResponse,
-        RusotoError<ListPendingInvitationResourcesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_pending_invitation_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_pending_invitation_resources_request(
+        &self,
+        input: ListPendingInvitationResourcesRequest,
+    ) -> SignedRequest {
         let request_uri = "/listpendinginvitationresources";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPendingInvitationResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_permissions`, also returning the metadata of the response.
+    pub async fn list_permissions_with_metadata(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<(ListPermissionsResponse, ResponseMetadata), RusotoError<ListPermissionsError>>
+    {
+        let request = self.build_list_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPendingInvitationResourcesResponse, _>()?;
+                .deserialize::<ListPermissionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPendingInvitationResourcesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPermissionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the AWS RAM permissions.</p>
-    async fn list_permissions(
+    /// Presigns the request `list_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPermissionsRequest,
-    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_permissions_request(&self, input: ListPermissionsRequest) -> SignedRequest {
         let request_uri = "/listpermissions";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_principals`, also returning the metadata of the response.
+    pub async fn list_principals_with_metadata(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> Result<(ListPrincipalsResponse, ResponseMetadata), RusotoError<ListPrincipalsError>> {
+        let request = self.build_list_principals_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPermissionsResponse, _>()?;
+                .deserialize::<ListPrincipalsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPrincipalsError::from_response(response))
         }
     }
 
-    /// <p>Lists the principals that you have shared resources with or that have shared resources with you.</p>
-    async fn list_principals(
+    /// Presigns the request `list_principals` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_principals(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPrincipalsRequest,
-    ) -> Result<ListPrincipalsResponse, RusotoError<ListPrincipalsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_principals_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_principals_request(&self, input: ListPrincipalsRequest) -> SignedRequest {
         let request_uri = "/listprincipals";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPrincipals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_resource_share_permissions`, also returning the metadata of the response.
+    pub async fn list_resource_share_permissions_with_metadata(
+        &self,
+        input: ListResourceSharePermissionsRequest,
+    ) -> Result<
+        (ListResourceSharePermissionsResponse, ResponseMetadata),
+        RusotoError<ListResourceSharePermissionsError>,
+    > {
+        let request = self.build_list_resource_share_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPrincipalsResponse, _>()?;
+                .deserialize::<ListResourceSharePermissionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPrincipalsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListResourceSharePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the AWS RAM permissions that are associated with a resource share.</p>
-    async fn list_resource_share_permissions(
+    /// Presigns the request `list_resource_share_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resource_share_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourceSharePermissionsRequest,
-    ) -> Result<ListResourceSharePermissionsResponse, RusotoError<ListResourceSharePermissionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resource_share_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resource_share_permissions_request(
+        &self,
+        input: ListResourceSharePermissionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/listresourcesharepermissions";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListResourceSharePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_resources`, also returning the metadata of the response.
+    pub async fn list_resources_with_metadata(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<(ListResourcesResponse, ResponseMetadata), RusotoError<ListResourcesError>> {
+        let request = self.build_list_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourceSharePermissionsResponse, _>()?;
+                .deserialize::<ListResourcesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourceSharePermissionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListResourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists the resources that you added to a resource shares or the resources that are shared with you.</p>
-    async fn list_resources(
+    /// Presigns the request `list_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourcesRequest,
-    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resources_request(&self, input: ListResourcesRequest) -> SignedRequest {
         let request_uri = "/listresources";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `promote_resource_share_created_from_policy`, also returning the metadata of the response.
+    pub async fn promote_resource_share_created_from_policy_with_metadata(
+        &self,
+        input: PromoteResourceShareCreatedFromPolicyRequest,
+    ) -> Result<
+        (
+            PromoteResourceShareCreatedFromPolicyResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<PromoteResourceShareCreatedFromPolicyError>,
+    > {
+        let request = self.build_promote_resource_share_created_from_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourcesResponse, _>()?;
+                .deserialize::<PromoteResourceShareCreatedFromPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourcesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PromoteResourceShareCreatedFromPolicyError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Resource shares that were created by attaching a policy to a resource are visible only to the resource share owner, and the resource share cannot be modified in AWS RAM.</p> <p>Use this API action to promote the resource share. When you promote the resource share, it becomes:</p> <ul> <li> <p>Visible to all principals that it is shared with.</p> </li> <li> <p>Modifiable in AWS RAM.</p> </li> </ul></p>
-    async fn promote_resource_share_created_from_policy(
+    /// Presigns the request `promote_resource_share_created_from_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_promote_resource_share_created_from_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PromoteResourceShareCreatedFromPolicyRequest,
-    ) -> Result<
-        PromoteResourceShareCreatedFromPolicyResponse,
-        RusotoError<PromoteResourceShareCreatedFromPolicyError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_promote_resource_share_created_from_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_promote_resource_share_created_from_policy_request(
+        &self,
+        input: PromoteResourceShareCreatedFromPolicyRequest,
+    ) -> SignedRequest {
         let request_uri = "/promoteresourcesharecreatedfrompolicy";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("PromoteResourceShareCreatedFromPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("resourceShareArn", &input.resource_share_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `reject_resource_share_invitation`, also returning the metadata of the response.
+    pub async fn reject_resource_share_invitation_with_metadata(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> Result<
+        (RejectResourceShareInvitationResponse, ResponseMetadata),
+        RusotoError<RejectResourceShareInvitationError>,
+    > {
+        let request = self.build_reject_resource_share_invitation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PromoteResourceShareCreatedFromPolicyResponse, _>()?;
+                .deserialize::<RejectResourceShareInvitationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PromoteResourceShareCreatedFromPolicyError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RejectResourceShareInvitationError::from_response(response))
         }
     }
 
-    /// <p>Rejects an invitation to a resource share from another AWS account.</p>
-    async fn reject_resource_share_invitation(
+    /// Presigns the request `reject_resource_share_invitation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reject_resource_share_invitation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RejectResourceShareInvitationRequest,
-    ) -> Result<
-        RejectResourceShareInvitationResponse,
-        RusotoError<RejectResourceShareInvitationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reject_resource_share_invitation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reject_resource_share_invitation_request(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> SignedRequest {
         let request_uri = "/rejectresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("RejectResourceShareInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RejectResourceShareInvitationResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RejectResourceShareInvitationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds the specified tags to the specified resource share that you own.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
-        let request_uri = "/tagresource";
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
+        let request_uri = "/tagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from the specified resource share that you own.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = "/untagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_resource_share`, also returning the metadata of the response.
+    pub async fn update_resource_share_with_metadata(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> Result<
+        (UpdateResourceShareResponse, ResponseMetadata),
+        RusotoError<UpdateResourceShareError>,
+    > {
+        let request = self.build_update_resource_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateResourceShareResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateResourceShareError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified resource share that you own.</p>
-    async fn update_resource_share(
+    /// Presigns the request `update_resource_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_resource_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateResourceShareRequest,
-    ) -> Result<UpdateResourceShareResponse, RusotoError<UpdateResourceShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_resource_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_resource_share_request(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> SignedRequest {
         let request_uri = "/updateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("UpdateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetResourcePolicies` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_resource_policies_pages(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetResourcePoliciesResponse, RusotoError<GetResourcePoliciesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input| async move { client.get_resource_policies(input).await },
+            |input: &GetResourcePoliciesRequest, output: &GetResourcePoliciesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetResourcePoliciesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `policies` of every page of `GetResourcePolicies` results for `input`, as `get_resource_policies_pages` requests them.
+    pub fn get_resource_policies_stream(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<GetResourcePoliciesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_resource_policies_pages(input), |page| {
+            page.policies.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetResourceShareAssociations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_resource_share_associations_pages(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetResourceShareAssociationsResponse,
+            RusotoError<GetResourceShareAssociationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input| async move {
+                client.get_resource_share_associations(input).await
+            },
+            |input: &GetResourceShareAssociationsRequest,
+             output: &GetResourceShareAssociationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetResourceShareAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `resourceShareAssociations` of every page of `GetResourceShareAssociations` results for `input`, as `get_resource_share_associations_pages` requests them.
+    pub fn get_resource_share_associations_stream(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ResourceShareAssociation, RusotoError<GetResourceShareAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_resource_share_associations_pages(input), |page| {
+            page.resource_share_associations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetResourceShareInvitations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_resource_share_invitations_pages(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetResourceShareInvitationsResponse,
+            RusotoError<GetResourceShareInvitationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input| async move { client.get_resource_share_invitations(input).await },
+            |input: &GetResourceShareInvitationsRequest,
+             output: &GetResourceShareInvitationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetResourceShareInvitationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `resourceShareInvitations` of every page of `GetResourceShareInvitations` results for `input`, as `get_resource_share_invitations_pages` requests them.
+    pub fn get_resource_share_invitations_stream(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ResourceShareInvitation, RusotoError<GetResourceShareInvitationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_resource_share_invitations_pages(input), |page| {
+            page.resource_share_invitations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetResourceShares` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_resource_shares_pages(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetResourceSharesResponse, RusotoError<GetResourceSharesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input| async move { client.get_resource_shares(input).await },
+            |input: &GetResourceSharesRequest, output: &GetResourceSharesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetResourceSharesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `resourceShares` of every page of `GetResourceShares` results for `input`, as `get_resource_shares_pages` requests them.
+    pub fn get_resource_shares_stream(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> impl futures::Stream<Item = Result<ResourceShare, RusotoError<GetResourceSharesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_resource_shares_pages(input), |page| {
+            page.resource_shares.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPrincipals` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_principals_pages(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> impl futures::Stream<Item = Result<ListPrincipalsResponse, RusotoError<ListPrincipalsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input| async move { client.list_principals(input).await },
+            |input: &ListPrincipalsRequest, output: &ListPrincipalsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPrincipalsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `principals` of every page of `ListPrincipals` results for `input`, as `list_principals_pages` requests them.
+    pub fn list_principals_stream(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> impl futures::Stream<Item = Result<Principal, RusotoError<ListPrincipalsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_principals_pages(input), |page| {
+            page.principals.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListResources` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_resources_pages(
+        &self,
+        input: ListResourcesRequest,
+    ) -> impl futures::Stream<Item = Result<ListResourcesResponse, RusotoError<ListResourcesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RamClient, input| async move { client.list_resources(input).await },
+            |input: &ListResourcesRequest, output: &ListResourcesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResourcesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `resources` of every page of `ListResources` results for `input`, as `list_resources_pages` requests them.
+    pub fn list_resources_stream(
+        &self,
+        input: ListResourcesRequest,
+    ) -> impl futures::Stream<Item = Result<Resource, RusotoError<ListResourcesError>>> + Send {
+        rusoto_core::pagination::items(self.list_resources_pages(input), |page| {
+            page.resources.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Ram for RamClient {
+    /// <p>Accepts an invitation to a resource share from another AWS account.</p>
+    async fn accept_resource_share_invitation(
+        &self,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> Result<
+        AcceptResourceShareInvitationResponse,
+        RusotoError<AcceptResourceShareInvitationError>,
+    > {
+        self.accept_resource_share_invitation_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateResourceShareResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateResourceShareError::from_response(response))
-        }
+    /// <p>Associates the specified resource share with the specified principals and resources.</p>
+    async fn associate_resource_share(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> Result<AssociateResourceShareResponse, RusotoError<AssociateResourceShareError>> {
+        self.associate_resource_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a permission with a resource share.</p>
+    async fn associate_resource_share_permission(
+        &self,
+        input: AssociateResourceSharePermissionRequest,
+    ) -> Result<
+        AssociateResourceSharePermissionResponse,
+        RusotoError<AssociateResourceSharePermissionError>,
+    > {
+        self.associate_resource_share_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a resource share.</p>
+    async fn create_resource_share(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> Result<CreateResourceShareResponse, RusotoError<CreateResourceShareError>> {
+        self.create_resource_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified resource share.</p>
+    async fn delete_resource_share(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> Result<DeleteResourceShareResponse, RusotoError<DeleteResourceShareError>> {
+        self.delete_resource_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified principals or resources from the specified resource share.</p>
+    async fn disassociate_resource_share(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> Result<DisassociateResourceShareResponse, RusotoError<DisassociateResourceShareError>>
+    {
+        self.disassociate_resource_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates an AWS RAM permission from a resource share.</p>
+    async fn disassociate_resource_share_permission(
+        &self,
+        input: DisassociateResourceSharePermissionRequest,
+    ) -> Result<
+        DisassociateResourceSharePermissionResponse,
+        RusotoError<DisassociateResourceSharePermissionError>,
+    > {
+        self.disassociate_resource_share_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables resource sharing within your AWS Organization.</p> <p>The caller must be the master account for the AWS Organization.</p>
+    async fn enable_sharing_with_aws_organization(
+        &self,
+    ) -> Result<
+        EnableSharingWithAwsOrganizationResponse,
+        RusotoError<EnableSharingWithAwsOrganizationError>,
+    > {
+        self.enable_sharing_with_aws_organization_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the contents of an AWS RAM permission in JSON format.</p>
+    async fn get_permission(
+        &self,
+        input: GetPermissionRequest,
+    ) -> Result<GetPermissionResponse, RusotoError<GetPermissionError>> {
+        self.get_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the policies for the specified resources that you own and have shared.</p>
+    async fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> Result<GetResourcePoliciesResponse, RusotoError<GetResourcePoliciesError>> {
+        self.get_resource_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the resources or principals for the resource shares that you own.</p>
+    async fn get_resource_share_associations(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> Result<GetResourceShareAssociationsResponse, RusotoError<GetResourceShareAssociationsError>>
+    {
+        self.get_resource_share_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the invitations for resource sharing that you've received.</p>
+    async fn get_resource_share_invitations(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> Result<GetResourceShareInvitationsResponse, RusotoError<GetResourceShareInvitationsError>>
+    {
+        self.get_resource_share_invitations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the resource shares that you own or the resource shares that are shared with you.</p>
+    async fn get_resource_shares(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> Result<GetResourceSharesResponse, RusotoError<GetResourceSharesError>> {
+        self.get_resource_shares_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resources in a resource share that is shared with you but that the invitation is still pending for.</p>
+    async fn list_pending_invitation_resources(
+        &self,
+        input: ListPendingInvitationResourcesRequest,
+    ) -> Result<
+        ListPendingInvitationResourcesResponse,
+        RusotoError<ListPendingInvitationResourcesError>,
+    > {
+        self.list_pending_invitation_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the AWS RAM permissions.</p>
+    async fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+        self.list_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the principals that you have shared resources with or that have shared resources with you.</p>
+    async fn list_principals(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> Result<ListPrincipalsResponse, RusotoError<ListPrincipalsError>> {
+        self.list_principals_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the AWS RAM permissions that are associated with a resource share.</p>
+    async fn list_resource_share_permissions(
+        &self,
+        input: ListResourceSharePermissionsRequest,
+    ) -> Result<ListResourceSharePermissionsResponse, RusotoError<ListResourceSharePermissionsError>>
+    {
+        self.list_resource_share_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resources that you added to a resource shares or the resources that are shared with you.</p>
+    async fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+        self.list_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Resource shares that were created by attaching a policy to a resource are visible only to the resource share owner, and the resource share cannot be modified in AWS RAM.</p> <p>Use this API action to promote the resource share. When you promote the resource share, it becomes:</p> <ul> <li> <p>Visible to all principals that it is shared with.</p> </li> <li> <p>Modifiable in AWS RAM.</p> </li> </ul></p>
+    async fn promote_resource_share_created_from_policy(
+        &self,
+        input: PromoteResourceShareCreatedFromPolicyRequest,
+    ) -> Result<
+        PromoteResourceShareCreatedFromPolicyResponse,
+        RusotoError<PromoteResourceShareCreatedFromPolicyError>,
+    > {
+        self.promote_resource_share_created_from_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Rejects an invitation to a resource share from another AWS account.</p>
+    async fn reject_resource_share_invitation(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> Result<
+        RejectResourceShareInvitationResponse,
+        RusotoError<RejectResourceShareInvitationError>,
+    > {
+        self.reject_resource_share_invitation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified tags to the specified resource share that you own.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from the specified resource share that you own.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified resource share that you own.</p>
+    async fn update_resource_share(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> Result<UpdateResourceShareResponse, RusotoError<UpdateResourceShareError>> {
+        self.update_resource_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -521,6 +521,7 @@ pub struct Value {
 
 /// Errors returned by BatchExecuteStatement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchExecuteStatementError {
     /// <p>There is an error in the call or in a SQL statement.</p>
     BadRequest(String),
@@ -532,6 +533,8 @@ pub enum BatchExecuteStatementError {
     ServiceUnavailableError(String),
     /// <p>The execution of the SQL statement timed out.</p>
     StatementTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchExecuteStatementError {
@@ -539,28 +542,37 @@ impl BatchExecuteStatementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        BatchExecuteStatementError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        BatchExecuteStatementError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchExecuteStatementError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchExecuteStatementError::ServiceUnavailableError(err.msg),
+                        &res,
                     )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::StatementTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchExecuteStatementError::StatementTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchExecuteStatementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -577,12 +589,14 @@ impl fmt::Display for BatchExecuteStatementError {
                 write!(f, "{}", cause)
             }
             BatchExecuteStatementError::StatementTimeout(ref cause) => write!(f, "{}", cause),
+            BatchExecuteStatementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchExecuteStatementError {}
 /// Errors returned by BeginTransaction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BeginTransactionError {
     /// <p>There is an error in the call or in a SQL statement.</p>
     BadRequest(String),
@@ -594,6 +608,8 @@ pub enum BeginTransactionError {
     ServiceUnavailableError(String),
     /// <p>The execution of the SQL statement timed out.</p>
     StatementTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BeginTransactionError {
@@ -601,26 +617,31 @@ impl BeginTransactionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BeginTransactionError::BadRequest(err.msg))
+                    return RusotoError::service(BeginTransactionError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BeginTransactionError::Forbidden(err.msg))
+                    return RusotoError::service(BeginTransactionError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BeginTransactionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BeginTransactionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(BeginTransactionError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BeginTransactionError::ServiceUnavailableError(err.msg),
+                        &res,
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(BeginTransactionError::StatementTimeout(err.msg))
+                    return RusotoError::service(
+                        BeginTransactionError::StatementTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BeginTransactionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -635,12 +656,14 @@ impl fmt::Display for BeginTransactionError {
             BeginTransactionError::InternalServerError(ref cause) => write!(f, "{}", cause),
             BeginTransactionError::ServiceUnavailableError(ref cause) => write!(f, "{}", cause),
             BeginTransactionError::StatementTimeout(ref cause) => write!(f, "{}", cause),
+            BeginTransactionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BeginTransactionError {}
 /// Errors returned by CommitTransaction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CommitTransactionError {
     /// <p>There is an error in the call or in a SQL statement.</p>
     BadRequest(String),
@@ -654,6 +677,8 @@ pub enum CommitTransactionError {
     ServiceUnavailableError(String),
     /// <p>The execution of the SQL statement timed out.</p>
     StatementTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CommitTransactionError {
@@ -661,29 +686,34 @@ impl CommitTransactionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CommitTransactionError::BadRequest(err.msg))
+                    return RusotoError::service(CommitTransactionError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CommitTransactionError::Forbidden(err.msg))
+                    return RusotoError::service(CommitTransactionError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CommitTransactionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CommitTransactionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CommitTransactionError::NotFound(err.msg))
+                    return RusotoError::service(CommitTransactionError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(CommitTransactionError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CommitTransactionError::ServiceUnavailableError(err.msg),
+                        &res,
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(CommitTransactionError::StatementTimeout(err.msg))
+                    return RusotoError::service(
+                        CommitTransactionError::StatementTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CommitTransactionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -699,12 +729,14 @@ impl fmt::Display for CommitTransactionError {
             CommitTransactionError::NotFound(ref cause) => write!(f, "{}", cause),
             CommitTransactionError::ServiceUnavailableError(ref cause) => write!(f, "{}", cause),
             CommitTransactionError::StatementTimeout(ref cause) => write!(f, "{}", cause),
+            CommitTransactionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CommitTransactionError {}
 /// Errors returned by ExecuteSql
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExecuteSqlError {
     /// <p>There is an error in the call or in a SQL statement.</p>
     BadRequest(String),
@@ -714,6 +746,8 @@ pub enum ExecuteSqlError {
     InternalServerError(String),
     /// <p>The service specified by the <code>resourceArn</code> parameter is not available.</p>
     ServiceUnavailableError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExecuteSqlError {
@@ -721,19 +755,25 @@ impl ExecuteSqlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExecuteSqlError::BadRequest(err.msg))
+                    return RusotoError::service(ExecuteSqlError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ExecuteSqlError::Forbidden(err.msg))
+                    return RusotoError::service(ExecuteSqlError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ExecuteSqlError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ExecuteSqlError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(ExecuteSqlError::ServiceUnavailableError(err.msg))
+                    return RusotoError::service(
+                        ExecuteSqlError::ServiceUnavailableError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExecuteSqlError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -747,12 +787,14 @@ impl fmt::Display for ExecuteSqlError {
             ExecuteSqlError::Forbidden(ref cause) => write!(f, "{}", cause),
             ExecuteSqlError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ExecuteSqlError::ServiceUnavailableError(ref cause) => write!(f, "{}", cause),
+            ExecuteSqlError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExecuteSqlError {}
 /// Errors returned by ExecuteStatement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExecuteStatementError {
     /// <p>There is an error in the call or in a SQL statement.</p>
     BadRequest(String),
@@ -764,6 +806,8 @@ pub enum ExecuteStatementError {
     ServiceUnavailableError(String),
     /// <p>The execution of the SQL statement timed out.</p>
     StatementTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExecuteStatementError {
@@ -771,26 +815,31 @@ impl ExecuteStatementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExecuteStatementError::BadRequest(err.msg))
+                    return RusotoError::service(ExecuteStatementError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ExecuteStatementError::Forbidden(err.msg))
+                    return RusotoError::service(ExecuteStatementError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ExecuteStatementError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExecuteStatementError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(ExecuteStatementError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExecuteStatementError::ServiceUnavailableError(err.msg),
+                        &res,
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(ExecuteStatementError::StatementTimeout(err.msg))
+                    return RusotoError::service(
+                        ExecuteStatementError::StatementTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExecuteStatementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -805,12 +854,14 @@ impl fmt::Display for ExecuteStatementError {
             ExecuteStatementError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ExecuteStatementError::ServiceUnavailableError(ref cause) => write!(f, "{}", cause),
             ExecuteStatementError::StatementTimeout(ref cause) => write!(f, "{}", cause),
+            ExecuteStatementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExecuteStatementError {}
 /// Errors returned by RollbackTransaction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RollbackTransactionError {
     /// <p>There is an error in the call or in a SQL statement.</p>
     BadRequest(String),
@@ -824,6 +875,8 @@ pub enum RollbackTransactionError {
     ServiceUnavailableError(String),
     /// <p>The execution of the SQL statement timed out.</p>
     StatementTimeout(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RollbackTransactionError {
@@ -831,31 +884,37 @@ impl RollbackTransactionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RollbackTransactionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        RollbackTransactionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RollbackTransactionError::Forbidden(err.msg))
+                    return RusotoError::service(RollbackTransactionError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RollbackTransactionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RollbackTransactionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RollbackTransactionError::NotFound(err.msg))
+                    return RusotoError::service(RollbackTransactionError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(RollbackTransactionError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RollbackTransactionError::ServiceUnavailableError(err.msg),
+                        &res,
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(RollbackTransactionError::StatementTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RollbackTransactionError::StatementTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RollbackTransactionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -871,6 +930,7 @@ impl fmt::Display for RollbackTransactionError {
             RollbackTransactionError::NotFound(ref cause) => write!(f, "{}", cause),
             RollbackTransactionError::ServiceUnavailableError(ref cause) => write!(f, "{}", cause),
             RollbackTransactionError::StatementTimeout(ref cause) => write!(f, "{}", cause),
+            RollbackTransactionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -925,6 +985,7 @@ impl RdsDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> RdsDataClient {
         RdsDataClient {
             client: Client::shared(),
@@ -950,187 +1011,423 @@ impl RdsDataClient {
     pub fn new_with_client(client: Client, region: region::Region) -> RdsDataClient {
         RdsDataClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<RdsDataClient> {
+        rusoto_core::ClientBuilder::new(RdsDataClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> RdsDataClient {
+        RdsDataClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl RdsData for RdsDataClient {
-    /// <p><p>Runs a batch SQL statement over an array of data.</p> <p>You can run bulk update and insert operations for multiple records using a DML statement with different parameter sets. Bulk operations can provide a significant performance improvement over individual insert and update operations.</p> <important> <p>If a call isn&#39;t part of a transaction because it doesn&#39;t include the <code>transactionID</code> parameter, changes that result from the call are committed automatically.</p> </important></p>
-    async fn batch_execute_statement(
+impl RdsDataClient {
+    /// Calls `batch_execute_statement`, also returning the metadata of the response.
+    pub async fn batch_execute_statement_with_metadata(
         &self,
         input: BatchExecuteStatementRequest,
-    ) -> Result<BatchExecuteStatementResponse, RusotoError<BatchExecuteStatementError>> {
-        let request_uri = "/BatchExecute";
-
-        let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (BatchExecuteStatementResponse, ResponseMetadata),
+        RusotoError<BatchExecuteStatementError>,
+    > {
+        let request = self.build_batch_execute_statement_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchExecuteStatementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(BatchExecuteStatementError::from_response(response))
         }
     }
 
-    /// <p><p>Starts a SQL transaction.</p> <pre><code> &lt;important&gt; &lt;p&gt;A transaction can run for a maximum of 24 hours. A transaction is terminated and rolled back automatically after 24 hours.&lt;/p&gt; &lt;p&gt;A transaction times out if no calls use its transaction ID in three minutes. If a transaction times out before it&#39;s committed, it&#39;s rolled back automatically.&lt;/p&gt; &lt;p&gt;DDL statements inside a transaction cause an implicit commit. We recommend that you run each DDL statement in a separate &lt;code&gt;ExecuteStatement&lt;/code&gt; call with &lt;code&gt;continueAfterTimeout&lt;/code&gt; enabled.&lt;/p&gt; &lt;/important&gt; </code></pre></p>
-    async fn begin_transaction(
+    /// Presigns the request `batch_execute_statement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_execute_statement(
         &self,
-        input: BeginTransactionRequest,
-    ) -> Result<BeginTransactionResponse, RusotoError<BeginTransactionError>> {
-        let request_uri = "/BeginTransaction";
+        presigner: &rusoto_core::Presigner,
+        input: BatchExecuteStatementRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_execute_statement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_execute_statement_request(
+        &self,
+        input: BatchExecuteStatementRequest,
+    ) -> SignedRequest {
+        let request_uri = "/BatchExecute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("BatchExecuteStatement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `begin_transaction`, also returning the metadata of the response.
+    pub async fn begin_transaction_with_metadata(
+        &self,
+        input: BeginTransactionRequest,
+    ) -> Result<(BeginTransactionResponse, ResponseMetadata), RusotoError<BeginTransactionError>>
+    {
+        let request = self.build_begin_transaction_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BeginTransactionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(BeginTransactionError::from_response(response))
         }
     }
 
-    /// <p>Ends a SQL transaction started with the <code>BeginTransaction</code> operation and commits the changes.</p>
-    async fn commit_transaction(
+    /// Presigns the request `begin_transaction` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_begin_transaction(
         &self,
-        input: CommitTransactionRequest,
-    ) -> Result<CommitTransactionResponse, RusotoError<CommitTransactionError>> {
-        let request_uri = "/CommitTransaction";
+        presigner: &rusoto_core::Presigner,
+        input: BeginTransactionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_begin_transaction_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_begin_transaction_request(&self, input: BeginTransactionRequest) -> SignedRequest {
+        let request_uri = "/BeginTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("BeginTransaction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `commit_transaction`, also returning the metadata of the response.
+    pub async fn commit_transaction_with_metadata(
+        &self,
+        input: CommitTransactionRequest,
+    ) -> Result<(CommitTransactionResponse, ResponseMetadata), RusotoError<CommitTransactionError>>
+    {
+        let request = self.build_commit_transaction_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CommitTransactionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CommitTransactionError::from_response(response))
         }
     }
 
-    /// <p><p>Runs one or more SQL statements.</p> <important> <p>This operation is deprecated. Use the <code>BatchExecuteStatement</code> or <code>ExecuteStatement</code> operation.</p> </important></p>
-    async fn execute_sql(
+    /// Presigns the request `commit_transaction` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_commit_transaction(
         &self,
-        input: ExecuteSqlRequest,
-    ) -> Result<ExecuteSqlResponse, RusotoError<ExecuteSqlError>> {
-        let request_uri = "/ExecuteSql";
+        presigner: &rusoto_core::Presigner,
+        input: CommitTransactionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_commit_transaction_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_commit_transaction_request(&self, input: CommitTransactionRequest) -> SignedRequest {
+        let request_uri = "/CommitTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("CommitTransaction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `execute_sql`, also returning the metadata of the response.
+    pub async fn execute_sql_with_metadata(
+        &self,
+        input: ExecuteSqlRequest,
+    ) -> Result<(ExecuteSqlResponse, ResponseMetadata), RusotoError<ExecuteSqlError>> {
+        let request = self.build_execute_sql_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ExecuteSqlResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ExecuteSqlError::from_response(response))
         }
     }
 
-    /// <p>Runs a SQL statement against a database.</p> <important> <p>If a call isn't part of a transaction because it doesn't include the <code>transactionID</code> parameter, changes that result from the call are committed automatically.</p> </important> <p>The response size limit is 1 MB or 1,000 records. If the call returns more than 1 MB of response data or over 1,000 records, the call is terminated.</p>
-    async fn execute_statement(
+    /// Presigns the request `execute_sql` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_execute_sql(
         &self,
-        input: ExecuteStatementRequest,
-    ) -> Result<ExecuteStatementResponse, RusotoError<ExecuteStatementError>> {
-        let request_uri = "/Execute";
+        presigner: &rusoto_core::Presigner,
+        input: ExecuteSqlRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_execute_sql_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_execute_sql_request(&self, input: ExecuteSqlRequest) -> SignedRequest {
+        let request_uri = "/ExecuteSql";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("ExecuteSql");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `execute_statement`, also returning the metadata of the response.
+    pub async fn execute_statement_with_metadata(
+        &self,
+        input: ExecuteStatementRequest,
+    ) -> Result<(ExecuteStatementResponse, ResponseMetadata), RusotoError<ExecuteStatementError>>
+    {
+        let request = self.build_execute_statement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ExecuteStatementResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ExecuteStatementError::from_response(response))
         }
     }
 
-    /// <p>Performs a rollback of a transaction. Rolling back a transaction cancels its changes.</p>
-    async fn rollback_transaction(
+    /// Presigns the request `execute_statement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_execute_statement(
         &self,
-        input: RollbackTransactionRequest,
-    ) -> Result<RollbackTransactionResponse, RusotoError<RollbackTransactionError>> {
-        let request_uri = "/RollbackTransaction";
+        presigner: &rusoto_core::Presigner,
+        input: ExecuteStatementRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_execute_statement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_execute_statement_request(&self, input: ExecuteStatementRequest) -> SignedRequest {
+        let request_uri = "/Execute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("ExecuteStatement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `rollback_transaction`, also returning the metadata of the response.
+    pub async fn rollback_transaction_with_metadata(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> Result<
+        (RollbackTransactionResponse, ResponseMetadata),
+        RusotoError<RollbackTransactionError>,
+    > {
+        let request = self.build_rollback_transaction_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RollbackTransactionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(RollbackTransactionError::from_response(response))
         }
     }
+
+    /// Presigns the request `rollback_transaction` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_rollback_transaction(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: RollbackTransactionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_rollback_transaction_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_rollback_transaction_request(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/RollbackTransaction";
+
+        let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("RollbackTransaction");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+}
+
+#[async_trait]
+impl RdsData for RdsDataClient {
+    /// <p><p>Runs a batch SQL statement over an array of data.</p> <p>You can run bulk update and insert operations for multiple records using a DML statement with different parameter sets. Bulk operations can provide a significant performance improvement over individual insert and update operations.</p> <important> <p>If a call isn&#39;t part of a transaction because it doesn&#39;t include the <code>transactionID</code> parameter, changes that result from the call are committed automatically.</p> </important></p>
+    async fn batch_execute_statement(
+        &self,
+        input: BatchExecuteStatementRequest,
+    ) -> Result<BatchExecuteStatementResponse, RusotoError<BatchExecuteStatementError>> {
+        self.batch_execute_statement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Starts a SQL transaction.</p> <pre><code> &lt;important&gt; &lt;p&gt;A transaction can run for a maximum of 24 hours. A transaction is terminated and rolled back automatically after 24 hours.&lt;/p&gt; &lt;p&gt;A transaction times out if no calls use its transaction ID in three minutes. If a transaction times out before it&#39;s committed, it&#39;s rolled back automatically.&lt;/p&gt; &lt;p&gt;DDL statements inside a transaction cause an implicit commit. We recommend that you run each DDL statement in a separate &lt;code&gt;ExecuteStatement&lt;/code&gt; call with &lt;code&gt;continueAfterTimeout&lt;/code&gt; enabled.&lt;/p&gt; &lt;/important&gt; </code></pre></p>
+    async fn begin_transaction(
+        &self,
+        input: BeginTransactionRequest,
+    ) -> Result<BeginTransactionResponse, RusotoError<BeginTransactionError>> {
+        self.begin_transaction_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Ends a SQL transaction started with the <code>BeginTransaction</code> operation and commits the changes.</p>
+    async fn commit_transaction(
+        &self,
+        input: CommitTransactionRequest,
+    ) -> Result<CommitTransactionResponse, RusotoError<CommitTransactionError>> {
+        self.commit_transaction_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Runs one or more SQL statements.</p> <important> <p>This operation is deprecated. Use the <code>BatchExecuteStatement</code> or <code>ExecuteStatement</code> operation.</p> </important></p>
+    async fn execute_sql(
+        &self,
+        input: ExecuteSqlRequest,
+    ) -> Result<ExecuteSqlResponse, RusotoError<ExecuteSqlError>> {
+        self.execute_sql_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Runs a SQL statement against a database.</p> <important> <p>If a call isn't part of a transaction because it doesn't include the <code>transactionID</code> parameter, changes that result from the call are committed automatically.</p> </important> <p>The response size limit is 1 MB or 1,000 records. If the call returns more than 1 MB of response data or over 1,000 records, the call is terminated.</p>
+    async fn execute_statement(
+        &self,
+        input: ExecuteStatementRequest,
+    ) -> Result<ExecuteStatementResponse, RusotoError<ExecuteStatementError>> {
+        self.execute_statement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Performs a rollback of a transaction. Rolling back a transaction cancels its changes.</p>
+    async fn rollback_transaction(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> Result<RollbackTransactionResponse, RusotoError<RollbackTransactionError>> {
+        self.rollback_transaction_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
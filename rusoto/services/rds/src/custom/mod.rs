@@ -1 +1,3 @@
+mod auth_token;
 
+pub use self::auth_token::{db_auth_token, get_db_auth_token};
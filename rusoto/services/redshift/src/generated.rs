@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -11147,6 +11147,7 @@ impl VpcSecurityGroupMembershipListDeserializer {
 }
 /// Errors returned by AcceptReservedNodeExchange
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptReservedNodeExchangeError {
     /// <p>Your request cannot be completed because a dependent internal service is temporarily unavailable. Wait 30 to 60 seconds and try again.</p>
     DependentServiceUnavailableFault(String),
@@ -11162,6 +11163,8 @@ pub enum AcceptReservedNodeExchangeError {
     ReservedNodeOfferingNotFoundFault(String),
     /// <p>The requested operation isn't supported.</p>
     UnsupportedOperationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptReservedNodeExchangeError {
@@ -11175,55 +11178,67 @@ impl AcceptReservedNodeExchangeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AcceptReservedNodeExchangeError::DependentServiceUnavailableFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidReservedNodeState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AcceptReservedNodeExchangeError::InvalidReservedNodeStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AcceptReservedNodeExchangeError::ReservedNodeAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeAlreadyMigrated" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AcceptReservedNodeExchangeError::ReservedNodeAlreadyMigratedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AcceptReservedNodeExchangeError::ReservedNodeNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AcceptReservedNodeExchangeError::ReservedNodeOfferingNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AcceptReservedNodeExchangeError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            AcceptReservedNodeExchangeError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11263,12 +11278,14 @@ impl fmt::Display for AcceptReservedNodeExchangeError {
             AcceptReservedNodeExchangeError::UnsupportedOperationFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AcceptReservedNodeExchangeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcceptReservedNodeExchangeError {}
 /// Errors returned by AuthorizeClusterSecurityGroupIngress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AuthorizeClusterSecurityGroupIngressError {
     /// <p>The specified CIDR block or EC2 security group is already authorized for the specified cluster security group.</p>
     AuthorizationAlreadyExistsFault(String),
@@ -11278,6 +11295,8 @@ pub enum AuthorizeClusterSecurityGroupIngressError {
     ClusterSecurityGroupNotFoundFault(String),
     /// <p>The state of the cluster security group is not <code>available</code>. </p>
     InvalidClusterSecurityGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AuthorizeClusterSecurityGroupIngressError {
@@ -11290,7 +11309,7 @@ impl AuthorizeClusterSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"AuthorizationQuotaExceeded" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
+                                    "AuthorizationAlreadyExists" => return RusotoError::service(AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message), &res),"AuthorizationQuotaExceeded" => return RusotoError::service(AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault(parsed_error.message), &res),"ClusterSecurityGroupNotFound" => return RusotoError::service(AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message), &res),"InvalidClusterSecurityGroupState" => return RusotoError::service(AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message), &res),_ => return RusotoError::unhandled(AuthorizeClusterSecurityGroupIngressError::Unhandled, &res),
                                 }
             }
         }
@@ -11321,12 +11340,16 @@ impl fmt::Display for AuthorizeClusterSecurityGroupIngressError {
             AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(
                 ref cause,
             ) => write!(f, "{}", cause),
+            AuthorizeClusterSecurityGroupIngressError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AuthorizeClusterSecurityGroupIngressError {}
 /// Errors returned by AuthorizeSnapshotAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AuthorizeSnapshotAccessError {
     /// <p>The specified CIDR block or EC2 security group is already authorized for the specified cluster security group.</p>
     AuthorizationAlreadyExistsFault(String),
@@ -11340,6 +11363,8 @@ pub enum AuthorizeSnapshotAccessError {
     InvalidClusterSnapshotStateFault(String),
     /// <p>The encryption key has exceeded its grant limit in AWS KMS.</p>
     LimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AuthorizeSnapshotAccessError {
@@ -11351,46 +11376,57 @@ impl AuthorizeSnapshotAccessError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AuthorizeSnapshotAccessError::AuthorizationAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "AuthorizationQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AuthorizeSnapshotAccessError::AuthorizationQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AuthorizeSnapshotAccessError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AuthorizeSnapshotAccessError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AuthorizeSnapshotAccessError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AuthorizeSnapshotAccessError::LimitExceededFault(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            AuthorizeSnapshotAccessError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11425,15 +11461,19 @@ impl fmt::Display for AuthorizeSnapshotAccessError {
                 write!(f, "{}", cause)
             }
             AuthorizeSnapshotAccessError::LimitExceededFault(ref cause) => write!(f, "{}", cause),
+            AuthorizeSnapshotAccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AuthorizeSnapshotAccessError {}
 /// Errors returned by BatchDeleteClusterSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeleteClusterSnapshotsError {
     /// <p>The maximum number for a batch delete of snapshots has been reached. The limit is 100. </p>
     BatchDeleteRequestSizeExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeleteClusterSnapshotsError {
@@ -11447,13 +11487,19 @@ impl BatchDeleteClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BatchDeleteRequestSizeExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchDeleteClusterSnapshotsError::BatchDeleteRequestSizeExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            BatchDeleteClusterSnapshotsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11475,17 +11521,21 @@ impl fmt::Display for BatchDeleteClusterSnapshotsError {
             BatchDeleteClusterSnapshotsError::BatchDeleteRequestSizeExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchDeleteClusterSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeleteClusterSnapshotsError {}
 /// Errors returned by BatchModifyClusterSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchModifyClusterSnapshotsError {
     /// <p>The maximum number for snapshot identifiers has been reached. The limit is 100. </p>
     BatchModifyClusterSnapshotsLimitExceededFault(String),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
     InvalidRetentionPeriodFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchModifyClusterSnapshotsError {
@@ -11498,7 +11548,7 @@ impl BatchModifyClusterSnapshotsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "BatchModifyClusterSnapshotsLimitExceededFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault(parsed_error.message)),"InvalidRetentionPeriodFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(parsed_error.message)),_ => {}
+                                    "BatchModifyClusterSnapshotsLimitExceededFault" => return RusotoError::service(BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault(parsed_error.message), &res),"InvalidRetentionPeriodFault" => return RusotoError::service(BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(parsed_error.message), &res),_ => return RusotoError::unhandled(BatchModifyClusterSnapshotsError::Unhandled, &res),
                                 }
             }
         }
@@ -11523,12 +11573,14 @@ impl fmt::Display for BatchModifyClusterSnapshotsError {
             BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchModifyClusterSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchModifyClusterSnapshotsError {}
 /// Errors returned by CancelResize
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelResizeError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -11538,6 +11590,8 @@ pub enum CancelResizeError {
     ResizeNotFoundFault(String),
     /// <p>The requested operation isn't supported.</p>
     UnsupportedOperationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelResizeError {
@@ -11549,26 +11603,30 @@ impl CancelResizeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(CancelResizeError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelResizeError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(CancelResizeError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelResizeError::InvalidClusterStateFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResizeNotFound" => {
-                        return RusotoError::Service(CancelResizeError::ResizeNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelResizeError::ResizeNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(CancelResizeError::UnsupportedOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelResizeError::UnsupportedOperationFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CancelResizeError::Unhandled, &res),
                 }
             }
         }
@@ -11591,12 +11649,14 @@ impl fmt::Display for CancelResizeError {
             CancelResizeError::InvalidClusterStateFault(ref cause) => write!(f, "{}", cause),
             CancelResizeError::ResizeNotFoundFault(ref cause) => write!(f, "{}", cause),
             CancelResizeError::UnsupportedOperationFault(ref cause) => write!(f, "{}", cause),
+            CancelResizeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelResizeError {}
 /// Errors returned by CopyClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyClusterSnapshotError {
     /// <p>The value specified as a snapshot identifier is already used by an existing snapshot.</p>
     ClusterSnapshotAlreadyExistsFault(String),
@@ -11608,6 +11668,8 @@ pub enum CopyClusterSnapshotError {
     InvalidClusterSnapshotStateFault(String),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
     InvalidRetentionPeriodFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyClusterSnapshotError {
@@ -11619,41 +11681,46 @@ impl CopyClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyClusterSnapshotError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyClusterSnapshotError::ClusterSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyClusterSnapshotError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyClusterSnapshotError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CopyClusterSnapshotError::Unhandled, &res),
                 }
             }
         }
@@ -11687,12 +11754,14 @@ impl fmt::Display for CopyClusterSnapshotError {
             CopyClusterSnapshotError::InvalidRetentionPeriodFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CopyClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyClusterSnapshotError {}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterError {
     /// <p>The account already has a cluster with the given identifier.</p>
     ClusterAlreadyExistsFault(String),
@@ -11738,6 +11807,8 @@ pub enum CreateClusterError {
     TagLimitExceededFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterError {
@@ -11749,134 +11820,156 @@ impl CreateClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterAlreadyExists" => {
-                        return RusotoError::Service(CreateClusterError::ClusterAlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterError::ClusterAlreadyExistsFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterQuotaExceeded" => {
-                        return RusotoError::Service(CreateClusterError::ClusterQuotaExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterError::ClusterQuotaExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::HsmConfigurationNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::InsufficientClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::InvalidClusterSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(CreateClusterError::InvalidClusterTrackFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterError::InvalidClusterTrackFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidElasticIpFault" => {
-                        return RusotoError::Service(CreateClusterError::InvalidElasticIpFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterError::InvalidElasticIpFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::InvalidRetentionPeriodFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateClusterError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(CreateClusterError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterError::LimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::NumberOfNodesPerClusterLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::NumberOfNodesQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterError::SnapshotScheduleNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(CreateClusterError::TagLimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterError::TagLimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(CreateClusterError::UnauthorizedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterError::UnauthorizedOperation(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateClusterError::Unhandled, &res),
                 }
             }
         }
@@ -11935,12 +12028,14 @@ impl fmt::Display for CreateClusterError {
             CreateClusterError::SnapshotScheduleNotFoundFault(ref cause) => write!(f, "{}", cause),
             CreateClusterError::TagLimitExceededFault(ref cause) => write!(f, "{}", cause),
             CreateClusterError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            CreateClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterError {}
 /// Errors returned by CreateClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterParameterGroupError {
     /// <p>A cluster parameter group with the same name already exists.</p>
     ClusterParameterGroupAlreadyExistsFault(String),
@@ -11950,6 +12045,8 @@ pub enum CreateClusterParameterGroupError {
     InvalidTagFault(String),
     /// <p>You have exceeded the number of tags allowed.</p>
     TagLimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterParameterGroupError {
@@ -11962,29 +12059,38 @@ impl CreateClusterParameterGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ClusterParameterGroupAlreadyExists" => return RusotoError::Service(
+                    "ClusterParameterGroupAlreadyExists" => return RusotoError::service(
                         CreateClusterParameterGroupError::ClusterParameterGroupAlreadyExistsFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    "ClusterParameterGroupQuotaExceeded" => return RusotoError::Service(
+                    "ClusterParameterGroupQuotaExceeded" => return RusotoError::service(
                         CreateClusterParameterGroupError::ClusterParameterGroupQuotaExceededFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterParameterGroupError::InvalidTagFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterParameterGroupError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12013,12 +12119,14 @@ impl fmt::Display for CreateClusterParameterGroupError {
             CreateClusterParameterGroupError::TagLimitExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateClusterParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterParameterGroupError {}
 /// Errors returned by CreateClusterSecurityGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterSecurityGroupError {
     /// <p>A cluster security group with the same name already exists.</p>
     ClusterSecurityGroupAlreadyExistsFault(String),
@@ -12028,6 +12136,8 @@ pub enum CreateClusterSecurityGroupError {
     InvalidTagFault(String),
     /// <p>You have exceeded the number of tags allowed.</p>
     TagLimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterSecurityGroupError {
@@ -12041,32 +12151,41 @@ impl CreateClusterSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSecurityGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSecurityGroupError::ClusterSecurityGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "QuotaExceeded.ClusterSecurityGroup" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSecurityGroupError::ClusterSecurityGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSecurityGroupError::InvalidTagFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSecurityGroupError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateClusterSecurityGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12095,12 +12214,14 @@ impl fmt::Display for CreateClusterSecurityGroupError {
             CreateClusterSecurityGroupError::TagLimitExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateClusterSecurityGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterSecurityGroupError {}
 /// Errors returned by CreateClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterSnapshotError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -12116,6 +12237,8 @@ pub enum CreateClusterSnapshotError {
     InvalidTagFault(String),
     /// <p>You have exceeded the number of tags allowed.</p>
     TagLimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterSnapshotError {
@@ -12127,49 +12250,58 @@ impl CreateClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSnapshotError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ClusterSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSnapshotError::ClusterSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSnapshotError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSnapshotError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateClusterSnapshotError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterSnapshotError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSnapshotError::TagLimitExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(CreateClusterSnapshotError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -12203,12 +12335,14 @@ impl fmt::Display for CreateClusterSnapshotError {
             }
             CreateClusterSnapshotError::InvalidTagFault(ref cause) => write!(f, "{}", cause),
             CreateClusterSnapshotError::TagLimitExceededFault(ref cause) => write!(f, "{}", cause),
+            CreateClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterSnapshotError {}
 /// Errors returned by CreateClusterSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterSubnetGroupError {
     /// <p>A <i>ClusterSubnetGroupName</i> is already used by an existing cluster subnet group. </p>
     ClusterSubnetGroupAlreadyExistsFault(String),
@@ -12226,6 +12360,8 @@ pub enum CreateClusterSubnetGroupError {
     TagLimitExceededFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterSubnetGroupError {
@@ -12237,58 +12373,71 @@ impl CreateClusterSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSubnetGroupError::ClusterSubnetGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSubnetGroupError::ClusterSubnetGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSubnetGroupError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateClusterSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateClusterSubnetGroupError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSubnetGroupError::InvalidTagFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSubnetGroupError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateClusterSubnetGroupError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateClusterSubnetGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12327,12 +12476,14 @@ impl fmt::Display for CreateClusterSubnetGroupError {
             CreateClusterSubnetGroupError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateClusterSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterSubnetGroupError {}
 /// Errors returned by CreateEventSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEventSubscriptionError {
     /// <p>The request would exceed the allowed number of event subscriptions for this account. For information about increasing your quota, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/amazon-redshift-limits.html">Limits in Amazon Redshift</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
     EventSubscriptionQuotaExceededFault(String),
@@ -12356,6 +12507,8 @@ pub enum CreateEventSubscriptionError {
     SubscriptionSeverityNotFoundFault(String),
     /// <p>You have exceeded the number of tags allowed.</p>
     TagLimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEventSubscriptionError {
@@ -12367,79 +12520,95 @@ impl CreateEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateEventSubscriptionError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateEventSubscriptionError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SubscriptionAlreadyExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionEventIdNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SubscriptionEventIdNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionSeverityNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::SubscriptionSeverityNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEventSubscriptionError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateEventSubscriptionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12485,12 +12654,14 @@ impl fmt::Display for CreateEventSubscriptionError {
             CreateEventSubscriptionError::TagLimitExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateEventSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEventSubscriptionError {}
 /// Errors returned by CreateHsmClientCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHsmClientCertificateError {
     /// <p>There is already an existing Amazon Redshift HSM client certificate with the specified identifier.</p>
     HsmClientCertificateAlreadyExistsFault(String),
@@ -12500,6 +12671,8 @@ pub enum CreateHsmClientCertificateError {
     InvalidTagFault(String),
     /// <p>You have exceeded the number of tags allowed.</p>
     TagLimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHsmClientCertificateError {
@@ -12513,32 +12686,41 @@ impl CreateHsmClientCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmClientCertificateAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHsmClientCertificateError::HsmClientCertificateAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "HsmClientCertificateQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHsmClientCertificateError::HsmClientCertificateQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHsmClientCertificateError::InvalidTagFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHsmClientCertificateError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateHsmClientCertificateError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12567,12 +12749,14 @@ impl fmt::Display for CreateHsmClientCertificateError {
             CreateHsmClientCertificateError::TagLimitExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateHsmClientCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHsmClientCertificateError {}
 /// Errors returned by CreateHsmConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHsmConfigurationError {
     /// <p>There is already an existing Amazon Redshift HSM configuration with the specified identifier.</p>
     HsmConfigurationAlreadyExistsFault(String),
@@ -12582,6 +12766,8 @@ pub enum CreateHsmConfigurationError {
     InvalidTagFault(String),
     /// <p>You have exceeded the number of tags allowed.</p>
     TagLimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHsmConfigurationError {
@@ -12593,32 +12779,38 @@ impl CreateHsmConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmConfigurationAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHsmConfigurationError::HsmConfigurationAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "HsmConfigurationQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHsmConfigurationError::HsmConfigurationQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateHsmConfigurationError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateHsmConfigurationError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHsmConfigurationError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(CreateHsmConfigurationError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -12645,12 +12837,14 @@ impl fmt::Display for CreateHsmConfigurationError {
             }
             CreateHsmConfigurationError::InvalidTagFault(ref cause) => write!(f, "{}", cause),
             CreateHsmConfigurationError::TagLimitExceededFault(ref cause) => write!(f, "{}", cause),
+            CreateHsmConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHsmConfigurationError {}
 /// Errors returned by CreateScheduledAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateScheduledActionError {
     /// <p>The schedule you submitted isn't valid.</p>
     InvalidScheduleFault(String),
@@ -12664,6 +12858,8 @@ pub enum CreateScheduledActionError {
     ScheduledActionTypeUnsupportedFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateScheduledActionError {
@@ -12675,44 +12871,52 @@ impl CreateScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateScheduledActionError::InvalidScheduleFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidScheduledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateScheduledActionError::InvalidScheduledActionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ScheduledActionAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateScheduledActionError::ScheduledActionAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ScheduledActionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateScheduledActionError::ScheduledActionQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ScheduledActionTypeUnsupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateScheduledActionError::ScheduledActionTypeUnsupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateScheduledActionError::UnauthorizedOperation(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(CreateScheduledActionError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -12745,12 +12949,14 @@ impl fmt::Display for CreateScheduledActionError {
                 write!(f, "{}", cause)
             }
             CreateScheduledActionError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            CreateScheduledActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateScheduledActionError {}
 /// Errors returned by CreateSnapshotCopyGrant
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSnapshotCopyGrantError {
     /// <p>The request cannot be completed because a dependent service is throttling requests made by Amazon Redshift on your behalf. Wait and retry the request.</p>
     DependentServiceRequestThrottlingFault(String),
@@ -12764,6 +12970,8 @@ pub enum CreateSnapshotCopyGrantError {
     SnapshotCopyGrantQuotaExceededFault(String),
     /// <p>You have exceeded the number of tags allowed.</p>
     TagLimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSnapshotCopyGrantError {
@@ -12775,44 +12983,55 @@ impl CreateSnapshotCopyGrantError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotCopyGrantError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateSnapshotCopyGrantError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateSnapshotCopyGrantError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotCopyGrantError::LimitExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SnapshotCopyGrantAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotCopyGrantError::SnapshotCopyGrantAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotCopyGrantQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotCopyGrantError::SnapshotCopyGrantQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotCopyGrantError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateSnapshotCopyGrantError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12845,12 +13064,14 @@ impl fmt::Display for CreateSnapshotCopyGrantError {
             CreateSnapshotCopyGrantError::TagLimitExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateSnapshotCopyGrantError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSnapshotCopyGrantError {}
 /// Errors returned by CreateSnapshotSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSnapshotScheduleError {
     /// <p>The schedule you submitted isn't valid.</p>
     InvalidScheduleFault(String),
@@ -12862,6 +13083,8 @@ pub enum CreateSnapshotScheduleError {
     SnapshotScheduleQuotaExceededFault(String),
     /// <p>You have exceeded the number of tags allowed.</p>
     TagLimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSnapshotScheduleError {
@@ -12873,39 +13096,46 @@ impl CreateSnapshotScheduleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotScheduleError::InvalidScheduleFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ScheduleDefinitionTypeUnsupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotScheduleError::ScheduleDefinitionTypeUnsupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotScheduleAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotScheduleError::SnapshotScheduleAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotScheduleQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotScheduleError::SnapshotScheduleQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotScheduleError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(CreateSnapshotScheduleError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -12935,12 +13165,14 @@ impl fmt::Display for CreateSnapshotScheduleError {
                 write!(f, "{}", cause)
             }
             CreateSnapshotScheduleError::TagLimitExceededFault(ref cause) => write!(f, "{}", cause),
+            CreateSnapshotScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSnapshotScheduleError {}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTagsError {
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
@@ -12948,6 +13180,8 @@ pub enum CreateTagsError {
     ResourceNotFoundFault(String),
     /// <p>You have exceeded the number of tags allowed.</p>
     TagLimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTagsError {
@@ -12959,21 +13193,24 @@ impl CreateTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateTagsError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTagsError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(CreateTagsError::ResourceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTagsError::ResourceNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(CreateTagsError::TagLimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTagsError::TagLimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateTagsError::Unhandled, &res),
                 }
             }
         }
@@ -12995,12 +13232,14 @@ impl fmt::Display for CreateTagsError {
             CreateTagsError::InvalidTagFault(ref cause) => write!(f, "{}", cause),
             CreateTagsError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
             CreateTagsError::TagLimitExceededFault(ref cause) => write!(f, "{}", cause),
+            CreateTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTagsError {}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -13012,6 +13251,8 @@ pub enum DeleteClusterError {
     InvalidClusterStateFault(String),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
     InvalidRetentionPeriodFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterError {
@@ -13023,35 +13264,40 @@ impl DeleteClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DeleteClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteClusterError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ClusterSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterError::ClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterError::ClusterSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(DeleteClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteClusterError::InvalidClusterStateFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterError::InvalidRetentionPeriodFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteClusterError::Unhandled, &res),
                 }
             }
         }
@@ -13079,17 +13325,21 @@ impl fmt::Display for DeleteClusterError {
             }
             DeleteClusterError::InvalidClusterStateFault(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::InvalidRetentionPeriodFault(ref cause) => write!(f, "{}", cause),
+            DeleteClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterError {}
 /// Errors returned by DeleteClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterParameterGroupError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
     ClusterParameterGroupNotFoundFault(String),
     /// <p>The cluster parameter group action can not be completed because another task is in progress that involves the parameter group. Wait a few moments and try the operation again.</p>
     InvalidClusterParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterParameterGroupError {
@@ -13103,18 +13353,25 @@ impl DeleteClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InvalidClusterParameterGroupState" => return RusotoError::Service(
+                    "InvalidClusterParameterGroupState" => return RusotoError::service(
                         DeleteClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteClusterParameterGroupError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -13139,17 +13396,21 @@ impl fmt::Display for DeleteClusterParameterGroupError {
             DeleteClusterParameterGroupError::InvalidClusterParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteClusterParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterParameterGroupError {}
 /// Errors returned by DeleteClusterSecurityGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterSecurityGroupError {
     /// <p>The cluster security group name does not refer to an existing cluster security group.</p>
     ClusterSecurityGroupNotFoundFault(String),
     /// <p>The state of the cluster security group is not <code>available</code>. </p>
     InvalidClusterSecurityGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterSecurityGroupError {
@@ -13163,20 +13424,27 @@ impl DeleteClusterSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterSecurityGroupError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterSecurityGroupError::InvalidClusterSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteClusterSecurityGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -13201,17 +13469,21 @@ impl fmt::Display for DeleteClusterSecurityGroupError {
             DeleteClusterSecurityGroupError::InvalidClusterSecurityGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteClusterSecurityGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterSecurityGroupError {}
 /// Errors returned by DeleteClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterSnapshotError {
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
     ClusterSnapshotNotFoundFault(String),
     /// <p>The specified cluster snapshot is not in the <code>available</code> state, or other accounts are authorized to access the snapshot. </p>
     InvalidClusterSnapshotStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterSnapshotError {
@@ -13223,20 +13495,24 @@ impl DeleteClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterSnapshotError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterSnapshotError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteClusterSnapshotError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -13261,12 +13537,14 @@ impl fmt::Display for DeleteClusterSnapshotError {
             DeleteClusterSnapshotError::InvalidClusterSnapshotStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterSnapshotError {}
 /// Errors returned by DeleteClusterSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterSubnetGroupError {
     /// <p>The cluster subnet group name does not refer to an existing cluster subnet group.</p>
     ClusterSubnetGroupNotFoundFault(String),
@@ -13274,6 +13552,8 @@ pub enum DeleteClusterSubnetGroupError {
     InvalidClusterSubnetGroupStateFault(String),
     /// <p>The state of the subnet is invalid.</p>
     InvalidClusterSubnetStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterSubnetGroupError {
@@ -13285,27 +13565,35 @@ impl DeleteClusterSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterSubnetGroupError::InvalidClusterSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSubnetStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteClusterSubnetGroupError::InvalidClusterSubnetStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteClusterSubnetGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -13333,17 +13621,21 @@ impl fmt::Display for DeleteClusterSubnetGroupError {
             DeleteClusterSubnetGroupError::InvalidClusterSubnetStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteClusterSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterSubnetGroupError {}
 /// Errors returned by DeleteEventSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEventSubscriptionError {
     /// <p>The subscription request is invalid because it is a duplicate request. This subscription request is already in progress.</p>
     InvalidSubscriptionStateFault(String),
     /// <p>An Amazon Redshift event notification subscription with the specified name does not exist.</p>
     SubscriptionNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEventSubscriptionError {
@@ -13355,20 +13647,27 @@ impl DeleteEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSubscriptionStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteEventSubscriptionError::InvalidSubscriptionStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteEventSubscriptionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -13393,17 +13692,21 @@ impl fmt::Display for DeleteEventSubscriptionError {
             DeleteEventSubscriptionError::SubscriptionNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteEventSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEventSubscriptionError {}
 /// Errors returned by DeleteHsmClientCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteHsmClientCertificateError {
     /// <p>There is no Amazon Redshift HSM client certificate with the specified identifier.</p>
     HsmClientCertificateNotFoundFault(String),
     /// <p>The specified HSM client certificate is not in the <code>available</code> state, or it is still in use by one or more Amazon Redshift clusters.</p>
     InvalidHsmClientCertificateStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteHsmClientCertificateError {
@@ -13417,20 +13720,27 @@ impl DeleteHsmClientCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteHsmClientCertificateError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidHsmClientCertificateStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteHsmClientCertificateError::InvalidHsmClientCertificateStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteHsmClientCertificateError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -13455,17 +13765,21 @@ impl fmt::Display for DeleteHsmClientCertificateError {
             DeleteHsmClientCertificateError::InvalidHsmClientCertificateStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteHsmClientCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteHsmClientCertificateError {}
 /// Errors returned by DeleteHsmConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteHsmConfigurationError {
     /// <p>There is no Amazon Redshift HSM configuration with the specified identifier.</p>
     HsmConfigurationNotFoundFault(String),
     /// <p>The specified HSM configuration is not in the <code>available</code> state, or it is still in use by one or more Amazon Redshift clusters.</p>
     InvalidHsmConfigurationStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteHsmConfigurationError {
@@ -13477,20 +13791,24 @@ impl DeleteHsmConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteHsmConfigurationError::HsmConfigurationNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidHsmConfigurationStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteHsmConfigurationError::InvalidHsmConfigurationStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteHsmConfigurationError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -13515,17 +13833,21 @@ impl fmt::Display for DeleteHsmConfigurationError {
             DeleteHsmConfigurationError::InvalidHsmConfigurationStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteHsmConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteHsmConfigurationError {}
 /// Errors returned by DeleteScheduledAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteScheduledActionError {
     /// <p>The scheduled action cannot be found. </p>
     ScheduledActionNotFoundFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteScheduledActionError {
@@ -13537,18 +13859,22 @@ impl DeleteScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ScheduledActionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteScheduledActionError::ScheduledActionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteScheduledActionError::UnauthorizedOperation(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteScheduledActionError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -13571,17 +13897,21 @@ impl fmt::Display for DeleteScheduledActionError {
                 write!(f, "{}", cause)
             }
             DeleteScheduledActionError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            DeleteScheduledActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteScheduledActionError {}
 /// Errors returned by DeleteSnapshotCopyGrant
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSnapshotCopyGrantError {
     /// <p>The snapshot copy grant can't be deleted because it is used by one or more clusters.</p>
     InvalidSnapshotCopyGrantStateFault(String),
     /// <p>The specified snapshot copy grant can't be found. Make sure that the name is typed correctly and that the grant exists in the destination region.</p>
     SnapshotCopyGrantNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSnapshotCopyGrantError {
@@ -13593,20 +13923,27 @@ impl DeleteSnapshotCopyGrantError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSnapshotCopyGrantStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteSnapshotCopyGrantError::InvalidSnapshotCopyGrantStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteSnapshotCopyGrantError::SnapshotCopyGrantNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteSnapshotCopyGrantError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -13631,17 +13968,21 @@ impl fmt::Display for DeleteSnapshotCopyGrantError {
             DeleteSnapshotCopyGrantError::SnapshotCopyGrantNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteSnapshotCopyGrantError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSnapshotCopyGrantError {}
 /// Errors returned by DeleteSnapshotSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSnapshotScheduleError {
     /// <p>The cluster snapshot schedule state is not valid.</p>
     InvalidClusterSnapshotScheduleStateFault(String),
     /// <p>We could not find the specified snapshot schedule. </p>
     SnapshotScheduleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSnapshotScheduleError {
@@ -13653,20 +13994,24 @@ impl DeleteSnapshotScheduleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidClusterSnapshotScheduleState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteSnapshotScheduleError::SnapshotScheduleNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteSnapshotScheduleError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -13691,17 +14036,21 @@ impl fmt::Display for DeleteSnapshotScheduleError {
             DeleteSnapshotScheduleError::SnapshotScheduleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteSnapshotScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSnapshotScheduleError {}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTagsError {
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTagsError {
@@ -13713,16 +14062,18 @@ impl DeleteTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(DeleteTagsError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTagsError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(DeleteTagsError::ResourceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTagsError::ResourceNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteTagsError::Unhandled, &res),
                 }
             }
         }
@@ -13743,13 +14094,18 @@ impl fmt::Display for DeleteTagsError {
         match *self {
             DeleteTagsError::InvalidTagFault(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DeleteTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTagsError {}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
-pub enum DescribeAccountAttributesError {}
+#[non_exhaustive]
+pub enum DescribeAccountAttributesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeAccountAttributesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountAttributesError> {
@@ -13757,10 +14113,8 @@ impl DescribeAccountAttributesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeAccountAttributesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -13777,17 +14131,22 @@ impl DescribeAccountAttributesError {
 impl fmt::Display for DescribeAccountAttributesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeAccountAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeAccountAttributesError {}
 /// Errors returned by DescribeClusterDbRevisions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterDbRevisionsError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
     InvalidClusterStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterDbRevisionsError {
@@ -13801,20 +14160,27 @@ impl DescribeClusterDbRevisionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterDbRevisionsError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterDbRevisionsError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeClusterDbRevisionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -13839,17 +14205,21 @@ impl fmt::Display for DescribeClusterDbRevisionsError {
             DescribeClusterDbRevisionsError::InvalidClusterStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeClusterDbRevisionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterDbRevisionsError {}
 /// Errors returned by DescribeClusterParameterGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterParameterGroupsError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
     ClusterParameterGroupNotFoundFault(String),
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterParameterGroupsError {
@@ -13863,20 +14233,27 @@ impl DescribeClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterParameterGroupsError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterParameterGroupsError::InvalidTagFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeClusterParameterGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -13901,15 +14278,21 @@ impl fmt::Display for DescribeClusterParameterGroupsError {
             DescribeClusterParameterGroupsError::InvalidTagFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeClusterParameterGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeClusterParameterGroupsError {}
 /// Errors returned by DescribeClusterParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterParametersError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
     ClusterParameterGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterParametersError {
@@ -13921,13 +14304,19 @@ impl DescribeClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterParametersError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeClusterParametersError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -13949,17 +14338,21 @@ impl fmt::Display for DescribeClusterParametersError {
             DescribeClusterParametersError::ClusterParameterGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeClusterParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterParametersError {}
 /// Errors returned by DescribeClusterSecurityGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterSecurityGroupsError {
     /// <p>The cluster security group name does not refer to an existing cluster security group.</p>
     ClusterSecurityGroupNotFoundFault(String),
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterSecurityGroupsError {
@@ -13973,20 +14366,27 @@ impl DescribeClusterSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterSecurityGroupsError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterSecurityGroupsError::InvalidTagFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeClusterSecurityGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -14011,12 +14411,16 @@ impl fmt::Display for DescribeClusterSecurityGroupsError {
             DescribeClusterSecurityGroupsError::InvalidTagFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeClusterSecurityGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeClusterSecurityGroupsError {}
 /// Errors returned by DescribeClusterSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterSnapshotsError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -14024,6 +14428,8 @@ pub enum DescribeClusterSnapshotsError {
     ClusterSnapshotNotFoundFault(String),
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterSnapshotsError {
@@ -14035,25 +14441,33 @@ impl DescribeClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterSnapshotsError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterSnapshotsError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterSnapshotsError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeClusterSnapshotsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -14079,17 +14493,21 @@ impl fmt::Display for DescribeClusterSnapshotsError {
                 write!(f, "{}", cause)
             }
             DescribeClusterSnapshotsError::InvalidTagFault(ref cause) => write!(f, "{}", cause),
+            DescribeClusterSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterSnapshotsError {}
 /// Errors returned by DescribeClusterSubnetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterSubnetGroupsError {
     /// <p>The cluster subnet group name does not refer to an existing cluster subnet group.</p>
     ClusterSubnetGroupNotFoundFault(String),
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterSubnetGroupsError {
@@ -14103,18 +14521,25 @@ impl DescribeClusterSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterSubnetGroupsError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterSubnetGroupsError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeClusterSubnetGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -14137,17 +14562,21 @@ impl fmt::Display for DescribeClusterSubnetGroupsError {
                 write!(f, "{}", cause)
             }
             DescribeClusterSubnetGroupsError::InvalidTagFault(ref cause) => write!(f, "{}", cause),
+            DescribeClusterSubnetGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterSubnetGroupsError {}
 /// Errors returned by DescribeClusterTracks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterTracksError {
     /// <p>The provided cluster track name is not valid.</p>
     InvalidClusterTrackFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterTracksError {
@@ -14159,18 +14588,22 @@ impl DescribeClusterTracksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterTracksError::InvalidClusterTrackFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeClusterTracksError::UnauthorizedOperation(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeClusterTracksError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -14193,13 +14626,18 @@ impl fmt::Display for DescribeClusterTracksError {
                 write!(f, "{}", cause)
             }
             DescribeClusterTracksError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            DescribeClusterTracksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterTracksError {}
 /// Errors returned by DescribeClusterVersions
 #[derive(Debug, PartialEq)]
-pub enum DescribeClusterVersionsError {}
+#[non_exhaustive]
+pub enum DescribeClusterVersionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeClusterVersionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeClusterVersionsError> {
@@ -14207,10 +14645,8 @@ impl DescribeClusterVersionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeClusterVersionsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -14227,17 +14663,22 @@ impl DescribeClusterVersionsError {
 impl fmt::Display for DescribeClusterVersionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeClusterVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeClusterVersionsError {}
 /// Errors returned by DescribeClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClustersError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClustersError {
@@ -14249,16 +14690,18 @@ impl DescribeClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DescribeClustersError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeClustersError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(DescribeClustersError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeClustersError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeClustersError::Unhandled, &res),
                 }
             }
         }
@@ -14279,13 +14722,18 @@ impl fmt::Display for DescribeClustersError {
         match *self {
             DescribeClustersError::ClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
             DescribeClustersError::InvalidTagFault(ref cause) => write!(f, "{}", cause),
+            DescribeClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClustersError {}
 /// Errors returned by DescribeDefaultClusterParameters
 #[derive(Debug, PartialEq)]
-pub enum DescribeDefaultClusterParametersError {}
+#[non_exhaustive]
+pub enum DescribeDefaultClusterParametersError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeDefaultClusterParametersError {
     pub fn from_response(
@@ -14295,10 +14743,11 @@ impl DescribeDefaultClusterParametersError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DescribeDefaultClusterParametersError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -14315,13 +14764,21 @@ impl DescribeDefaultClusterParametersError {
 impl fmt::Display for DescribeDefaultClusterParametersError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeDefaultClusterParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeDefaultClusterParametersError {}
 /// Errors returned by DescribeEventCategories
 #[derive(Debug, PartialEq)]
-pub enum DescribeEventCategoriesError {}
+#[non_exhaustive]
+pub enum DescribeEventCategoriesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEventCategoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventCategoriesError> {
@@ -14329,10 +14786,8 @@ impl DescribeEventCategoriesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeEventCategoriesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -14349,17 +14804,22 @@ impl DescribeEventCategoriesError {
 impl fmt::Display for DescribeEventCategoriesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEventCategoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEventCategoriesError {}
 /// Errors returned by DescribeEventSubscriptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventSubscriptionsError {
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
     /// <p>An Amazon Redshift event notification subscription with the specified name does not exist.</p>
     SubscriptionNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventSubscriptionsError {
@@ -14373,18 +14833,25 @@ impl DescribeEventSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEventSubscriptionsError::InvalidTagFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeEventSubscriptionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -14407,13 +14874,18 @@ impl fmt::Display for DescribeEventSubscriptionsError {
             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEventSubscriptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventSubscriptionsError {}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
-pub enum DescribeEventsError {}
+#[non_exhaustive]
+pub enum DescribeEventsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEventsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventsError> {
@@ -14421,10 +14893,8 @@ impl DescribeEventsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeEventsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -14441,17 +14911,22 @@ impl DescribeEventsError {
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEventsError {}
 /// Errors returned by DescribeHsmClientCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHsmClientCertificatesError {
     /// <p>There is no Amazon Redshift HSM client certificate with the specified identifier.</p>
     HsmClientCertificateNotFoundFault(String),
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHsmClientCertificatesError {
@@ -14465,20 +14940,27 @@ impl DescribeHsmClientCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeHsmClientCertificatesError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeHsmClientCertificatesError::InvalidTagFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeHsmClientCertificatesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -14503,17 +14985,23 @@ impl fmt::Display for DescribeHsmClientCertificatesError {
             DescribeHsmClientCertificatesError::InvalidTagFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeHsmClientCertificatesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeHsmClientCertificatesError {}
 /// Errors returned by DescribeHsmConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHsmConfigurationsError {
     /// <p>There is no Amazon Redshift HSM configuration with the specified identifier.</p>
     HsmConfigurationNotFoundFault(String),
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHsmConfigurationsError {
@@ -14525,18 +15013,25 @@ impl DescribeHsmConfigurationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeHsmConfigurationsError::HsmConfigurationNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeHsmConfigurationsError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeHsmConfigurationsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -14559,15 +15054,19 @@ impl fmt::Display for DescribeHsmConfigurationsError {
                 write!(f, "{}", cause)
             }
             DescribeHsmConfigurationsError::InvalidTagFault(ref cause) => write!(f, "{}", cause),
+            DescribeHsmConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHsmConfigurationsError {}
 /// Errors returned by DescribeLoggingStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoggingStatusError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoggingStatusError {
@@ -14579,11 +15078,14 @@ impl DescribeLoggingStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoggingStatusError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeLoggingStatusError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -14603,12 +15105,14 @@ impl fmt::Display for DescribeLoggingStatusError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeLoggingStatusError::ClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeLoggingStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLoggingStatusError {}
 /// Errors returned by DescribeNodeConfigurationOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeNodeConfigurationOptionsError {
     /// <p>The owner of the specified snapshot has not authorized your account to access the snapshot.</p>
     AccessToSnapshotDeniedFault(String),
@@ -14618,6 +15122,8 @@ pub enum DescribeNodeConfigurationOptionsError {
     ClusterSnapshotNotFoundFault(String),
     /// <p>The specified cluster snapshot is not in the <code>available</code> state, or other accounts are authorized to access the snapshot. </p>
     InvalidClusterSnapshotStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeNodeConfigurationOptionsError {
@@ -14631,34 +15137,43 @@ impl DescribeNodeConfigurationOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessToSnapshotDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeNodeConfigurationOptionsError::AccessToSnapshotDeniedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeNodeConfigurationOptionsError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeNodeConfigurationOptionsError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeNodeConfigurationOptionsError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeNodeConfigurationOptionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -14689,13 +15204,20 @@ impl fmt::Display for DescribeNodeConfigurationOptionsError {
             DescribeNodeConfigurationOptionsError::InvalidClusterSnapshotStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeNodeConfigurationOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeNodeConfigurationOptionsError {}
 /// Errors returned by DescribeOrderableClusterOptions
 #[derive(Debug, PartialEq)]
-pub enum DescribeOrderableClusterOptionsError {}
+#[non_exhaustive]
+pub enum DescribeOrderableClusterOptionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeOrderableClusterOptionsError {
     pub fn from_response(
@@ -14705,10 +15227,11 @@ impl DescribeOrderableClusterOptionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DescribeOrderableClusterOptionsError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -14725,12 +15248,17 @@ impl DescribeOrderableClusterOptionsError {
 impl fmt::Display for DescribeOrderableClusterOptionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeOrderableClusterOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeOrderableClusterOptionsError {}
 /// Errors returned by DescribeReservedNodeOfferings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReservedNodeOfferingsError {
     /// <p>Your request cannot be completed because a dependent internal service is temporarily unavailable. Wait 30 to 60 seconds and try again.</p>
     DependentServiceUnavailableFault(String),
@@ -14738,6 +15266,8 @@ pub enum DescribeReservedNodeOfferingsError {
     ReservedNodeOfferingNotFoundFault(String),
     /// <p>The requested operation isn't supported.</p>
     UnsupportedOperationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReservedNodeOfferingsError {
@@ -14751,27 +15281,35 @@ impl DescribeReservedNodeOfferingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReservedNodeOfferingsError::DependentServiceUnavailableFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReservedNodeOfferingsError::ReservedNodeOfferingNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReservedNodeOfferingsError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeReservedNodeOfferingsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -14799,17 +15337,23 @@ impl fmt::Display for DescribeReservedNodeOfferingsError {
             DescribeReservedNodeOfferingsError::UnsupportedOperationFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReservedNodeOfferingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeReservedNodeOfferingsError {}
 /// Errors returned by DescribeReservedNodes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReservedNodesError {
     /// <p>Your request cannot be completed because a dependent internal service is temporarily unavailable. Wait 30 to 60 seconds and try again.</p>
     DependentServiceUnavailableFault(String),
     /// <p>The specified reserved compute node not found.</p>
     ReservedNodeNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReservedNodesError {
@@ -14821,20 +15365,24 @@ impl DescribeReservedNodesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReservedNodesError::DependentServiceUnavailableFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReservedNodesError::ReservedNodeNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeReservedNodesError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -14859,17 +15407,21 @@ impl fmt::Display for DescribeReservedNodesError {
             DescribeReservedNodesError::ReservedNodeNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReservedNodesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReservedNodesError {}
 /// Errors returned by DescribeResize
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeResizeError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
     /// <p>A resize operation for the specified cluster is not found.</p>
     ResizeNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeResizeError {
@@ -14881,16 +15433,18 @@ impl DescribeResizeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DescribeResizeError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeResizeError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResizeNotFound" => {
-                        return RusotoError::Service(DescribeResizeError::ResizeNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeResizeError::ResizeNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeResizeError::Unhandled, &res),
                 }
             }
         }
@@ -14911,17 +15465,21 @@ impl fmt::Display for DescribeResizeError {
         match *self {
             DescribeResizeError::ClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
             DescribeResizeError::ResizeNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeResizeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeResizeError {}
 /// Errors returned by DescribeScheduledActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScheduledActionsError {
     /// <p>The scheduled action cannot be found. </p>
     ScheduledActionNotFoundFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScheduledActionsError {
@@ -14933,20 +15491,27 @@ impl DescribeScheduledActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ScheduledActionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeScheduledActionsError::ScheduledActionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeScheduledActionsError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeScheduledActionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -14971,17 +15536,21 @@ impl fmt::Display for DescribeScheduledActionsError {
             DescribeScheduledActionsError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeScheduledActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScheduledActionsError {}
 /// Errors returned by DescribeSnapshotCopyGrants
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSnapshotCopyGrantsError {
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
     /// <p>The specified snapshot copy grant can't be found. Make sure that the name is typed correctly and that the grant exists in the destination region.</p>
     SnapshotCopyGrantNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSnapshotCopyGrantsError {
@@ -14995,18 +15564,25 @@ impl DescribeSnapshotCopyGrantsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeSnapshotCopyGrantsError::InvalidTagFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeSnapshotCopyGrantsError::SnapshotCopyGrantNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeSnapshotCopyGrantsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -15029,13 +15605,18 @@ impl fmt::Display for DescribeSnapshotCopyGrantsError {
             DescribeSnapshotCopyGrantsError::SnapshotCopyGrantNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeSnapshotCopyGrantsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSnapshotCopyGrantsError {}
 /// Errors returned by DescribeSnapshotSchedules
 #[derive(Debug, PartialEq)]
-pub enum DescribeSnapshotSchedulesError {}
+#[non_exhaustive]
+pub enum DescribeSnapshotSchedulesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeSnapshotSchedulesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeSnapshotSchedulesError> {
@@ -15043,10 +15624,8 @@ impl DescribeSnapshotSchedulesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeSnapshotSchedulesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -15063,13 +15642,19 @@ impl DescribeSnapshotSchedulesError {
 impl fmt::Display for DescribeSnapshotSchedulesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeSnapshotSchedulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeSnapshotSchedulesError {}
 /// Errors returned by DescribeStorage
 #[derive(Debug, PartialEq)]
-pub enum DescribeStorageError {}
+#[non_exhaustive]
+pub enum DescribeStorageError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeStorageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeStorageError> {
@@ -15077,10 +15662,8 @@ impl DescribeStorageError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeStorageError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -15097,17 +15680,22 @@ impl DescribeStorageError {
 impl fmt::Display for DescribeStorageError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeStorageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeStorageError {}
 /// Errors returned by DescribeTableRestoreStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTableRestoreStatusError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
     /// <p>The specified <code>TableRestoreRequestId</code> value was not found.</p>
     TableRestoreNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTableRestoreStatusError {
@@ -15121,20 +15709,27 @@ impl DescribeTableRestoreStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeTableRestoreStatusError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TableRestoreNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeTableRestoreStatusError::TableRestoreNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeTableRestoreStatusError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -15159,17 +15754,21 @@ impl fmt::Display for DescribeTableRestoreStatusError {
             DescribeTableRestoreStatusError::TableRestoreNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTableRestoreStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTableRestoreStatusError {}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTagsError {
     /// <p>The tag is invalid.</p>
     InvalidTagFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTagsError {
@@ -15181,16 +15780,18 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(DescribeTagsError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTagsError::InvalidTagFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(DescribeTagsError::ResourceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTagsError::ResourceNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeTagsError::Unhandled, &res),
                 }
             }
         }
@@ -15211,15 +15812,19 @@ impl fmt::Display for DescribeTagsError {
         match *self {
             DescribeTagsError::InvalidTagFault(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTagsError {}
 /// Errors returned by DisableLogging
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableLoggingError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableLoggingError {
@@ -15231,11 +15836,12 @@ impl DisableLoggingError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DisableLoggingError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DisableLoggingError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DisableLoggingError::Unhandled, &res),
                 }
             }
         }
@@ -15255,12 +15861,14 @@ impl fmt::Display for DisableLoggingError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DisableLoggingError::ClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DisableLoggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableLoggingError {}
 /// Errors returned by DisableSnapshotCopy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableSnapshotCopyError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -15270,6 +15878,8 @@ pub enum DisableSnapshotCopyError {
     SnapshotCopyAlreadyDisabledFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableSnapshotCopyError {
@@ -15281,30 +15891,34 @@ impl DisableSnapshotCopyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisableSnapshotCopyError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisableSnapshotCopyError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotCopyAlreadyDisabledFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisableSnapshotCopyError::SnapshotCopyAlreadyDisabledFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisableSnapshotCopyError::UnauthorizedOperation(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DisableSnapshotCopyError::Unhandled, &res),
                 }
             }
         }
@@ -15329,12 +15943,14 @@ impl fmt::Display for DisableSnapshotCopyError {
                 write!(f, "{}", cause)
             }
             DisableSnapshotCopyError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            DisableSnapshotCopyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableSnapshotCopyError {}
 /// Errors returned by EnableLogging
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableLoggingError {
     /// <p>Could not find the specified S3 bucket.</p>
     BucketNotFoundFault(String),
@@ -15346,6 +15962,8 @@ pub enum EnableLoggingError {
     InvalidS3BucketNameFault(String),
     /// <p>The string specified for the logging S3 key prefix does not comply with the documented constraints.</p>
     InvalidS3KeyPrefixFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableLoggingError {
@@ -15357,33 +15975,38 @@ impl EnableLoggingError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketNotFoundFault" => {
-                        return RusotoError::Service(EnableLoggingError::BucketNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            EnableLoggingError::BucketNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ClusterNotFound" => {
-                        return RusotoError::Service(EnableLoggingError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            EnableLoggingError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InsufficientS3BucketPolicyFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableLoggingError::InsufficientS3BucketPolicyFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidS3BucketNameFault" => {
-                        return RusotoError::Service(EnableLoggingError::InvalidS3BucketNameFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            EnableLoggingError::InvalidS3BucketNameFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidS3KeyPrefixFault" => {
-                        return RusotoError::Service(EnableLoggingError::InvalidS3KeyPrefixFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            EnableLoggingError::InvalidS3KeyPrefixFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(EnableLoggingError::Unhandled, &res),
                 }
             }
         }
@@ -15409,12 +16032,14 @@ impl fmt::Display for EnableLoggingError {
             }
             EnableLoggingError::InvalidS3BucketNameFault(ref cause) => write!(f, "{}", cause),
             EnableLoggingError::InvalidS3KeyPrefixFault(ref cause) => write!(f, "{}", cause),
+            EnableLoggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableLoggingError {}
 /// Errors returned by EnableSnapshotCopy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableSnapshotCopyError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -15438,6 +16063,8 @@ pub enum EnableSnapshotCopyError {
     UnauthorizedOperation(String),
     /// <p>The specified region is incorrect or does not exist.</p>
     UnknownSnapshotCopyRegionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableSnapshotCopyError {
@@ -15449,75 +16076,86 @@ impl EnableSnapshotCopyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(EnableSnapshotCopyError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            EnableSnapshotCopyError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "CopyToRegionDisabledFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableSnapshotCopyError::CopyToRegionDisabledFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableSnapshotCopyError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "IncompatibleOrderableOptions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableSnapshotCopyError::IncompatibleOrderableOptions(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableSnapshotCopyError::InvalidClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableSnapshotCopyError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(EnableSnapshotCopyError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            EnableSnapshotCopyError::LimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SnapshotCopyAlreadyEnabledFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableSnapshotCopyError::SnapshotCopyAlreadyEnabledFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableSnapshotCopyError::SnapshotCopyGrantNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableSnapshotCopyError::UnauthorizedOperation(parsed_error.message),
+                            &res,
                         )
                     }
                     "UnknownSnapshotCopyRegionFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableSnapshotCopyError::UnknownSnapshotCopyRegionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(EnableSnapshotCopyError::Unhandled, &res),
                 }
             }
         }
@@ -15559,17 +16197,21 @@ impl fmt::Display for EnableSnapshotCopyError {
             EnableSnapshotCopyError::UnknownSnapshotCopyRegionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            EnableSnapshotCopyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableSnapshotCopyError {}
 /// Errors returned by GetClusterCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetClusterCredentialsError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
     /// <p>The requested operation isn't supported.</p>
     UnsupportedOperationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetClusterCredentialsError {
@@ -15581,18 +16223,22 @@ impl GetClusterCredentialsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetClusterCredentialsError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetClusterCredentialsError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(GetClusterCredentialsError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -15615,12 +16261,14 @@ impl fmt::Display for GetClusterCredentialsError {
             GetClusterCredentialsError::UnsupportedOperationFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetClusterCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetClusterCredentialsError {}
 /// Errors returned by GetReservedNodeExchangeOfferings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetReservedNodeExchangeOfferingsError {
     /// <p>Your request cannot be completed because a dependent internal service is temporarily unavailable. Wait 30 to 60 seconds and try again.</p>
     DependentServiceUnavailableFault(String),
@@ -15634,6 +16282,8 @@ pub enum GetReservedNodeExchangeOfferingsError {
     ReservedNodeOfferingNotFoundFault(String),
     /// <p>The requested operation isn't supported.</p>
     UnsupportedOperationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetReservedNodeExchangeOfferingsError {
@@ -15647,46 +16297,57 @@ impl GetReservedNodeExchangeOfferingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetReservedNodeExchangeOfferingsError::DependentServiceUnavailableFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidReservedNodeState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetReservedNodeExchangeOfferingsError::InvalidReservedNodeStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeAlreadyMigrated" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetReservedNodeExchangeOfferingsError::ReservedNodeAlreadyMigratedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetReservedNodeExchangeOfferingsError::ReservedNodeNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "ReservedNodeOfferingNotFound" => return RusotoError::Service(
+                    "ReservedNodeOfferingNotFound" => return RusotoError::service(
                         GetReservedNodeExchangeOfferingsError::ReservedNodeOfferingNotFoundFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetReservedNodeExchangeOfferingsError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetReservedNodeExchangeOfferingsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -15723,12 +16384,16 @@ impl fmt::Display for GetReservedNodeExchangeOfferingsError {
             GetReservedNodeExchangeOfferingsError::UnsupportedOperationFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetReservedNodeExchangeOfferingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetReservedNodeExchangeOfferingsError {}
 /// Errors returned by ModifyCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyClusterError {
     /// <p>The account already has a cluster with the given identifier.</p>
     ClusterAlreadyExistsFault(String),
@@ -15768,6 +16433,8 @@ pub enum ModifyClusterError {
     UnauthorizedOperation(String),
     /// <p>A request option was specified that is not supported.</p>
     UnsupportedOptionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyClusterError {
@@ -15779,117 +16446,136 @@ impl ModifyClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterAlreadyExists" => {
-                        return RusotoError::Service(ModifyClusterError::ClusterAlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterError::ClusterAlreadyExistsFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ClusterNotFound" => {
-                        return RusotoError::Service(ModifyClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::HsmConfigurationNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::InsufficientClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::InvalidClusterSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(ModifyClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterError::InvalidClusterStateFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(ModifyClusterError::InvalidClusterTrackFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterError::InvalidClusterTrackFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidElasticIpFault" => {
-                        return RusotoError::Service(ModifyClusterError::InvalidElasticIpFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterError::InvalidElasticIpFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::InvalidRetentionPeriodFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(ModifyClusterError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterError::LimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::NumberOfNodesPerClusterLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterError::NumberOfNodesQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TableLimitExceeded" => {
-                        return RusotoError::Service(ModifyClusterError::TableLimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterError::TableLimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(ModifyClusterError::UnauthorizedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterError::UnauthorizedOperation(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnsupportedOptionFault" => {
-                        return RusotoError::Service(ModifyClusterError::UnsupportedOptionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterError::UnsupportedOptionFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyClusterError::Unhandled, &res),
                 }
             }
         }
@@ -15943,12 +16629,14 @@ impl fmt::Display for ModifyClusterError {
             ModifyClusterError::TableLimitExceededFault(ref cause) => write!(f, "{}", cause),
             ModifyClusterError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
             ModifyClusterError::UnsupportedOptionFault(ref cause) => write!(f, "{}", cause),
+            ModifyClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyClusterError {}
 /// Errors returned by ModifyClusterDbRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyClusterDbRevisionError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -15956,6 +16644,8 @@ pub enum ModifyClusterDbRevisionError {
     ClusterOnLatestRevisionFault(String),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
     InvalidClusterStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyClusterDbRevisionError {
@@ -15967,27 +16657,35 @@ impl ModifyClusterDbRevisionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterDbRevisionError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterOnLatestRevision" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterDbRevisionError::ClusterOnLatestRevisionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterDbRevisionError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyClusterDbRevisionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -16013,17 +16711,21 @@ impl fmt::Display for ModifyClusterDbRevisionError {
             ModifyClusterDbRevisionError::InvalidClusterStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyClusterDbRevisionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyClusterDbRevisionError {}
 /// Errors returned by ModifyClusterIamRoles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyClusterIamRolesError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
     InvalidClusterStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyClusterIamRolesError {
@@ -16035,18 +16737,22 @@ impl ModifyClusterIamRolesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterIamRolesError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterIamRolesError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(ModifyClusterIamRolesError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -16069,15 +16775,19 @@ impl fmt::Display for ModifyClusterIamRolesError {
             ModifyClusterIamRolesError::InvalidClusterStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyClusterIamRolesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyClusterIamRolesError {}
 /// Errors returned by ModifyClusterMaintenance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyClusterMaintenanceError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyClusterMaintenanceError {
@@ -16089,13 +16799,19 @@ impl ModifyClusterMaintenanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterMaintenanceError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyClusterMaintenanceError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -16117,17 +16833,21 @@ impl fmt::Display for ModifyClusterMaintenanceError {
             ModifyClusterMaintenanceError::ClusterNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyClusterMaintenanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyClusterMaintenanceError {}
 /// Errors returned by ModifyClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyClusterParameterGroupError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
     ClusterParameterGroupNotFoundFault(String),
     /// <p>The cluster parameter group action can not be completed because another task is in progress that involves the parameter group. Wait a few moments and try the operation again.</p>
     InvalidClusterParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyClusterParameterGroupError {
@@ -16141,18 +16861,25 @@ impl ModifyClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InvalidClusterParameterGroupState" => return RusotoError::Service(
+                    "InvalidClusterParameterGroupState" => return RusotoError::service(
                         ModifyClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyClusterParameterGroupError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -16177,12 +16904,14 @@ impl fmt::Display for ModifyClusterParameterGroupError {
             ModifyClusterParameterGroupError::InvalidClusterParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyClusterParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyClusterParameterGroupError {}
 /// Errors returned by ModifyClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyClusterSnapshotError {
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
     ClusterSnapshotNotFoundFault(String),
@@ -16190,6 +16919,8 @@ pub enum ModifyClusterSnapshotError {
     InvalidClusterSnapshotStateFault(String),
     /// <p>The retention period specified is either in the past or is not a valid value.</p> <p>The value must be either -1 or an integer between 1 and 3,653.</p>
     InvalidRetentionPeriodFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyClusterSnapshotError {
@@ -16201,27 +16932,32 @@ impl ModifyClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterSnapshotError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterSnapshotError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterSnapshotError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(ModifyClusterSnapshotError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -16249,12 +16985,14 @@ impl fmt::Display for ModifyClusterSnapshotError {
             ModifyClusterSnapshotError::InvalidRetentionPeriodFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyClusterSnapshotError {}
 /// Errors returned by ModifyClusterSnapshotSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyClusterSnapshotScheduleError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -16262,6 +17000,8 @@ pub enum ModifyClusterSnapshotScheduleError {
     InvalidClusterSnapshotScheduleStateFault(String),
     /// <p>We could not find the specified snapshot schedule. </p>
     SnapshotScheduleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyClusterSnapshotScheduleError {
@@ -16274,7 +17014,7 @@ impl ModifyClusterSnapshotScheduleError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ClusterNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::ClusterNotFoundFault(parsed_error.message)),"InvalidClusterSnapshotScheduleState" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(parsed_error.message)),"SnapshotScheduleNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(parsed_error.message)),_ => {}
+                                    "ClusterNotFound" => return RusotoError::service(ModifyClusterSnapshotScheduleError::ClusterNotFoundFault(parsed_error.message), &res),"InvalidClusterSnapshotScheduleState" => return RusotoError::service(ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(parsed_error.message), &res),"SnapshotScheduleNotFound" => return RusotoError::service(ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(parsed_error.message), &res),_ => return RusotoError::unhandled(ModifyClusterSnapshotScheduleError::Unhandled, &res),
                                 }
             }
         }
@@ -16302,12 +17042,16 @@ impl fmt::Display for ModifyClusterSnapshotScheduleError {
             ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyClusterSnapshotScheduleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ModifyClusterSnapshotScheduleError {}
 /// Errors returned by ModifyClusterSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyClusterSubnetGroupError {
     /// <p>The cluster subnet group name does not refer to an existing cluster subnet group.</p>
     ClusterSubnetGroupNotFoundFault(String),
@@ -16321,6 +17065,8 @@ pub enum ModifyClusterSubnetGroupError {
     SubnetAlreadyInUse(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyClusterSubnetGroupError {
@@ -16332,44 +17078,55 @@ impl ModifyClusterSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterSubnetGroupError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyClusterSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyClusterSubnetGroupError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterSubnetGroupError::SubnetAlreadyInUse(parsed_error.message),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyClusterSubnetGroupError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyClusterSubnetGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -16402,12 +17159,14 @@ impl fmt::Display for ModifyClusterSubnetGroupError {
             ModifyClusterSubnetGroupError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyClusterSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyClusterSubnetGroupError {}
 /// Errors returned by ModifyEventSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyEventSubscriptionError {
     /// <p>The subscription request is invalid because it is a duplicate request. This subscription request is already in progress.</p>
     InvalidSubscriptionStateFault(String),
@@ -16427,6 +17186,8 @@ pub enum ModifyEventSubscriptionError {
     SubscriptionNotFoundFault(String),
     /// <p>The value specified for the event severity was not one of the allowed values, or it specified a severity that does not apply to the specified source type. The allowed values are ERROR and INFO.</p>
     SubscriptionSeverityNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyEventSubscriptionError {
@@ -16438,67 +17199,81 @@ impl ModifyEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSubscriptionStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::InvalidSubscriptionStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionEventIdNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SubscriptionEventIdNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SubscriptionSeverityNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyEventSubscriptionError::SubscriptionSeverityNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyEventSubscriptionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -16540,12 +17315,14 @@ impl fmt::Display for ModifyEventSubscriptionError {
             ModifyEventSubscriptionError::SubscriptionSeverityNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyEventSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyEventSubscriptionError {}
 /// Errors returned by ModifyScheduledAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyScheduledActionError {
     /// <p>The schedule you submitted isn't valid.</p>
     InvalidScheduleFault(String),
@@ -16557,6 +17334,8 @@ pub enum ModifyScheduledActionError {
     ScheduledActionTypeUnsupportedFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyScheduledActionError {
@@ -16568,37 +17347,44 @@ impl ModifyScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyScheduledActionError::InvalidScheduleFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidScheduledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyScheduledActionError::InvalidScheduledActionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ScheduledActionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyScheduledActionError::ScheduledActionNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ScheduledActionTypeUnsupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyScheduledActionError::ScheduledActionTypeUnsupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyScheduledActionError::UnauthorizedOperation(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(ModifyScheduledActionError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -16628,12 +17414,14 @@ impl fmt::Display for ModifyScheduledActionError {
                 write!(f, "{}", cause)
             }
             ModifyScheduledActionError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            ModifyScheduledActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyScheduledActionError {}
 /// Errors returned by ModifySnapshotCopyRetentionPeriod
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifySnapshotCopyRetentionPeriodError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -16645,6 +17433,8 @@ pub enum ModifySnapshotCopyRetentionPeriodError {
     SnapshotCopyDisabledFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifySnapshotCopyRetentionPeriodError {
@@ -16658,41 +17448,51 @@ impl ModifySnapshotCopyRetentionPeriodError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifySnapshotCopyRetentionPeriodError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifySnapshotCopyRetentionPeriodError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifySnapshotCopyRetentionPeriodError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotCopyDisabledFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifySnapshotCopyRetentionPeriodError::SnapshotCopyDisabledFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifySnapshotCopyRetentionPeriodError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifySnapshotCopyRetentionPeriodError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -16726,12 +17526,16 @@ impl fmt::Display for ModifySnapshotCopyRetentionPeriodError {
             ModifySnapshotCopyRetentionPeriodError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifySnapshotCopyRetentionPeriodError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ModifySnapshotCopyRetentionPeriodError {}
 /// Errors returned by ModifySnapshotSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifySnapshotScheduleError {
     /// <p>The schedule you submitted isn't valid.</p>
     InvalidScheduleFault(String),
@@ -16739,6 +17543,8 @@ pub enum ModifySnapshotScheduleError {
     SnapshotScheduleNotFoundFault(String),
     /// <p>The specified snapshot schedule is already being updated.</p>
     SnapshotScheduleUpdateInProgressFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifySnapshotScheduleError {
@@ -16750,25 +17556,30 @@ impl ModifySnapshotScheduleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifySnapshotScheduleError::InvalidScheduleFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifySnapshotScheduleError::SnapshotScheduleNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotScheduleUpdateInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifySnapshotScheduleError::SnapshotScheduleUpdateInProgressFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(ModifySnapshotScheduleError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -16794,12 +17605,14 @@ impl fmt::Display for ModifySnapshotScheduleError {
             ModifySnapshotScheduleError::SnapshotScheduleUpdateInProgressFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifySnapshotScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifySnapshotScheduleError {}
 /// Errors returned by PurchaseReservedNodeOffering
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PurchaseReservedNodeOfferingError {
     /// <p>User already has a reservation with the given identifier.</p>
     ReservedNodeAlreadyExistsFault(String),
@@ -16809,6 +17622,8 @@ pub enum PurchaseReservedNodeOfferingError {
     ReservedNodeQuotaExceededFault(String),
     /// <p>The requested operation isn't supported.</p>
     UnsupportedOperationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PurchaseReservedNodeOfferingError {
@@ -16822,34 +17637,43 @@ impl PurchaseReservedNodeOfferingError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ReservedNodeAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PurchaseReservedNodeOfferingError::ReservedNodeAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PurchaseReservedNodeOfferingError::ReservedNodeOfferingNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedNodeQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PurchaseReservedNodeOfferingError::ReservedNodeQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PurchaseReservedNodeOfferingError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            PurchaseReservedNodeOfferingError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -16880,17 +17704,21 @@ impl fmt::Display for PurchaseReservedNodeOfferingError {
             PurchaseReservedNodeOfferingError::UnsupportedOperationFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PurchaseReservedNodeOfferingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PurchaseReservedNodeOfferingError {}
 /// Errors returned by RebootCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootClusterError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
     InvalidClusterStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootClusterError {
@@ -16902,16 +17730,18 @@ impl RebootClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(RebootClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RebootClusterError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(RebootClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RebootClusterError::InvalidClusterStateFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RebootClusterError::Unhandled, &res),
                 }
             }
         }
@@ -16932,17 +17762,21 @@ impl fmt::Display for RebootClusterError {
         match *self {
             RebootClusterError::ClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
             RebootClusterError::InvalidClusterStateFault(ref cause) => write!(f, "{}", cause),
+            RebootClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootClusterError {}
 /// Errors returned by ResetClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetClusterParameterGroupError {
     /// <p>The parameter group name does not refer to an existing parameter group.</p>
     ClusterParameterGroupNotFoundFault(String),
     /// <p>The cluster parameter group action can not be completed because another task is in progress that involves the parameter group. Wait a few moments and try the operation again.</p>
     InvalidClusterParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetClusterParameterGroupError {
@@ -16956,20 +17790,27 @@ impl ResetClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ResetClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -16994,12 +17835,14 @@ impl fmt::Display for ResetClusterParameterGroupError {
             ResetClusterParameterGroupError::InvalidClusterParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ResetClusterParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetClusterParameterGroupError {}
 /// Errors returned by ResizeCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResizeClusterError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -17019,6 +17862,8 @@ pub enum ResizeClusterError {
     UnsupportedOperationFault(String),
     /// <p>A request option was specified that is not supported.</p>
     UnsupportedOptionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResizeClusterError {
@@ -17030,57 +17875,66 @@ impl ResizeClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(ResizeClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ResizeClusterError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResizeClusterError::InsufficientClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(ResizeClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ResizeClusterError::InvalidClusterStateFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(ResizeClusterError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ResizeClusterError::LimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResizeClusterError::NumberOfNodesPerClusterLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResizeClusterError::NumberOfNodesQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(ResizeClusterError::UnauthorizedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ResizeClusterError::UnauthorizedOperation(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(ResizeClusterError::UnsupportedOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ResizeClusterError::UnsupportedOperationFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnsupportedOptionFault" => {
-                        return RusotoError::Service(ResizeClusterError::UnsupportedOptionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ResizeClusterError::UnsupportedOptionFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ResizeClusterError::Unhandled, &res),
                 }
             }
         }
@@ -17114,12 +17968,14 @@ impl fmt::Display for ResizeClusterError {
             ResizeClusterError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
             ResizeClusterError::UnsupportedOperationFault(ref cause) => write!(f, "{}", cause),
             ResizeClusterError::UnsupportedOptionFault(ref cause) => write!(f, "{}", cause),
+            ResizeClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResizeClusterError {}
 /// Errors returned by RestoreFromClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreFromClusterSnapshotError {
     /// <p>The owner of the specified snapshot has not authorized your account to access the snapshot.</p>
     AccessToSnapshotDeniedFault(String),
@@ -17171,6 +18027,8 @@ pub enum RestoreFromClusterSnapshotError {
     TagLimitExceededFault(String),
     /// <p>Your account is not authorized to perform the requested operation.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreFromClusterSnapshotError {
@@ -17184,175 +18042,205 @@ impl RestoreFromClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessToSnapshotDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::AccessToSnapshotDeniedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::ClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::ClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::HsmConfigurationNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::InsufficientClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::InvalidClusterSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::InvalidClusterTrackFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidElasticIpFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::InvalidElasticIpFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRestore" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::InvalidSubnet(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::InvalidTagFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::LimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "NumberOfNodesPerClusterLimitExceeded" => return RusotoError::Service(
+                    "NumberOfNodesPerClusterLimitExceeded" => return RusotoError::service(
                         RestoreFromClusterSnapshotError::NumberOfNodesPerClusterLimitExceededFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::NumberOfNodesQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::SnapshotScheduleNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreFromClusterSnapshotError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RestoreFromClusterSnapshotError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -17442,12 +18330,14 @@ impl fmt::Display for RestoreFromClusterSnapshotError {
             RestoreFromClusterSnapshotError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RestoreFromClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreFromClusterSnapshotError {}
 /// Errors returned by RestoreTableFromClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreTableFromClusterSnapshotError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -17463,6 +18353,8 @@ pub enum RestoreTableFromClusterSnapshotError {
     InvalidTableRestoreArgumentFault(String),
     /// <p>The requested operation isn't supported.</p>
     UnsupportedOperationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreTableFromClusterSnapshotError {
@@ -17475,7 +18367,7 @@ impl RestoreTableFromClusterSnapshotError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ClusterNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterNotFoundFault(parsed_error.message)),"ClusterSnapshotNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault(parsed_error.message)),"InProgressTableRestoreQuotaExceededFault" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault(parsed_error.message)),"InvalidClusterSnapshotState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault(parsed_error.message)),"InvalidClusterState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterStateFault(parsed_error.message)),"InvalidTableRestoreArgument" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault(parsed_error.message)),"UnsupportedOperation" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(parsed_error.message)),_ => {}
+                                    "ClusterNotFound" => return RusotoError::service(RestoreTableFromClusterSnapshotError::ClusterNotFoundFault(parsed_error.message), &res),"ClusterSnapshotNotFound" => return RusotoError::service(RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault(parsed_error.message), &res),"InProgressTableRestoreQuotaExceededFault" => return RusotoError::service(RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault(parsed_error.message), &res),"InvalidClusterSnapshotState" => return RusotoError::service(RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault(parsed_error.message), &res),"InvalidClusterState" => return RusotoError::service(RestoreTableFromClusterSnapshotError::InvalidClusterStateFault(parsed_error.message), &res),"InvalidTableRestoreArgument" => return RusotoError::service(RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault(parsed_error.message), &res),"UnsupportedOperation" => return RusotoError::service(RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(parsed_error.message), &res),_ => return RusotoError::unhandled(RestoreTableFromClusterSnapshotError::Unhandled, &res),
                                 }
             }
         }
@@ -17515,12 +18407,16 @@ impl fmt::Display for RestoreTableFromClusterSnapshotError {
             RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RestoreTableFromClusterSnapshotError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RestoreTableFromClusterSnapshotError {}
 /// Errors returned by RevokeClusterSecurityGroupIngress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RevokeClusterSecurityGroupIngressError {
     /// <p>The specified CIDR IP range or EC2 security group is not authorized for the specified cluster security group.</p>
     AuthorizationNotFoundFault(String),
@@ -17528,6 +18424,8 @@ pub enum RevokeClusterSecurityGroupIngressError {
     ClusterSecurityGroupNotFoundFault(String),
     /// <p>The state of the cluster security group is not <code>available</code>. </p>
     InvalidClusterSecurityGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RevokeClusterSecurityGroupIngressError {
@@ -17540,7 +18438,7 @@ impl RevokeClusterSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
+                                    "AuthorizationNotFound" => return RusotoError::service(RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault(parsed_error.message), &res),"ClusterSecurityGroupNotFound" => return RusotoError::service(RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message), &res),"InvalidClusterSecurityGroupState" => return RusotoError::service(RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message), &res),_ => return RusotoError::unhandled(RevokeClusterSecurityGroupIngressError::Unhandled, &res),
                                 }
             }
         }
@@ -17568,12 +18466,16 @@ impl fmt::Display for RevokeClusterSecurityGroupIngressError {
             RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(
                 ref cause,
             ) => write!(f, "{}", cause),
+            RevokeClusterSecurityGroupIngressError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RevokeClusterSecurityGroupIngressError {}
 /// Errors returned by RevokeSnapshotAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RevokeSnapshotAccessError {
     /// <p>The owner of the specified snapshot has not authorized your account to access the snapshot.</p>
     AccessToSnapshotDeniedFault(String),
@@ -17581,6 +18483,8 @@ pub enum RevokeSnapshotAccessError {
     AuthorizationNotFoundFault(String),
     /// <p>The snapshot identifier does not refer to an existing cluster snapshot.</p>
     ClusterSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RevokeSnapshotAccessError {
@@ -17592,27 +18496,30 @@ impl RevokeSnapshotAccessError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessToSnapshotDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RevokeSnapshotAccessError::AccessToSnapshotDeniedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RevokeSnapshotAccessError::AuthorizationNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RevokeSnapshotAccessError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RevokeSnapshotAccessError::Unhandled, &res),
                 }
             }
         }
@@ -17640,12 +18547,14 @@ impl fmt::Display for RevokeSnapshotAccessError {
             RevokeSnapshotAccessError::ClusterSnapshotNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RevokeSnapshotAccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RevokeSnapshotAccessError {}
 /// Errors returned by RotateEncryptionKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RotateEncryptionKeyError {
     /// <p>The <code>ClusterIdentifier</code> parameter does not refer to an existing cluster. </p>
     ClusterNotFoundFault(String),
@@ -17653,6 +18562,8 @@ pub enum RotateEncryptionKeyError {
     DependentServiceRequestThrottlingFault(String),
     /// <p>The specified cluster is not in the <code>available</code> state. </p>
     InvalidClusterStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RotateEncryptionKeyError {
@@ -17664,25 +18575,28 @@ impl RotateEncryptionKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RotateEncryptionKeyError::ClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RotateEncryptionKeyError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RotateEncryptionKeyError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RotateEncryptionKeyError::Unhandled, &res),
                 }
             }
         }
@@ -17706,6 +18620,7 @@ impl fmt::Display for RotateEncryptionKeyError {
                 write!(f, "{}", cause)
             }
             RotateEncryptionKeyError::InvalidClusterStateFault(ref cause) => write!(f, "{}", cause),
+            RotateEncryptionKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -18260,6 +19175,7 @@ impl RedshiftClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> RedshiftClient {
         RedshiftClient {
             client: Client::shared(),
@@ -18285,36 +19201,52 @@ impl RedshiftClient {
     pub fn new_with_client(client: Client, region: region::Region) -> RedshiftClient {
         RedshiftClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<RedshiftClient> {
+        rusoto_core::ClientBuilder::new(RedshiftClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> RedshiftClient {
+        RedshiftClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Redshift for RedshiftClient {
-    /// <p>Exchanges a DC1 Reserved Node for a DC2 Reserved Node with no changes to the configuration (term, payment type, or number of nodes) and no additional costs. </p>
-    async fn accept_reserved_node_exchange(
+impl RedshiftClient {
+    /// Calls `accept_reserved_node_exchange`, also returning the metadata of the response.
+    pub async fn accept_reserved_node_exchange_with_metadata(
         &self,
         input: AcceptReservedNodeExchangeInputMessage,
-    ) -> Result<AcceptReservedNodeExchangeOutputMessage, RusotoError<AcceptReservedNodeExchangeError>>
-    {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AcceptReservedNodeExchange");
-        params.put("Version", "2012-12-01");
-        AcceptReservedNodeExchangeInputMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<
+        (AcceptReservedNodeExchangeOutputMessage, ResponseMetadata),
+        RusotoError<AcceptReservedNodeExchangeError>,
+    > {
+        let request = self.build_accept_reserved_node_exchange_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AcceptReservedNodeExchangeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18336,39 +19268,66 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds an inbound (ingress) rule to an Amazon Redshift security group. Depending on whether the application accessing your cluster is running on the Internet or an Amazon EC2 instance, you can authorize inbound access to either a Classless Interdomain Routing (CIDR)/Internet Protocol (IP) range or to an Amazon EC2 security group. You can add as many as 20 ingress rules to an Amazon Redshift security group.</p> <p>If you authorize access to an Amazon EC2 security group, specify <i>EC2SecurityGroupName</i> and <i>EC2SecurityGroupOwnerId</i>. The Amazon EC2 security group and Amazon Redshift cluster must be in the same AWS Region. </p> <p>If you authorize access to a CIDR/IP address range, specify <i>CIDRIP</i>. For an overview of CIDR blocks, see the Wikipedia article on <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>. </p> <p>You must also associate the security group with a cluster so that clients running on these IP addresses or the EC2 instance are authorized to connect to the cluster. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Working with Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn authorize_cluster_security_group_ingress(
+    /// Presigns the request `accept_reserved_node_exchange` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_reserved_node_exchange(
         &self,
-        input: AuthorizeClusterSecurityGroupIngressMessage,
-    ) -> Result<
-        AuthorizeClusterSecurityGroupIngressResult,
-        RusotoError<AuthorizeClusterSecurityGroupIngressError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_reserved_node_exchange_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_reserved_node_exchange_request(
+        &self,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AcceptReservedNodeExchange");
         let mut params = Params::new();
 
-        params.put("Action", "AuthorizeClusterSecurityGroupIngress");
+        params.put("Action", "AcceptReservedNodeExchange");
         params.put("Version", "2012-12-01");
-        AuthorizeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
+        AcceptReservedNodeExchangeInputMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `authorize_cluster_security_group_ingress`, also returning the metadata of the response.
+    pub async fn authorize_cluster_security_group_ingress_with_metadata(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> Result<
+        (AuthorizeClusterSecurityGroupIngressResult, ResponseMetadata),
+        RusotoError<AuthorizeClusterSecurityGroupIngressError>,
+    > {
+        let request = self.build_authorize_cluster_security_group_ingress_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AuthorizeClusterSecurityGroupIngressError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18390,34 +19349,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Authorizes the specified AWS customer account to restore the specified snapshot.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn authorize_snapshot_access(
+    /// Presigns the request `authorize_cluster_security_group_ingress` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_authorize_cluster_security_group_ingress(
         &self,
-        input: AuthorizeSnapshotAccessMessage,
-    ) -> Result<AuthorizeSnapshotAccessResult, RusotoError<AuthorizeSnapshotAccessError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_authorize_cluster_security_group_ingress_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_authorize_cluster_security_group_ingress_request(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AuthorizeClusterSecurityGroupIngress");
         let mut params = Params::new();
 
-        params.put("Action", "AuthorizeSnapshotAccess");
+        params.put("Action", "AuthorizeClusterSecurityGroupIngress");
         params.put("Version", "2012-12-01");
-        AuthorizeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
+        AuthorizeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `authorize_snapshot_access`, also returning the metadata of the response.
+    pub async fn authorize_snapshot_access_with_metadata(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> Result<
+        (AuthorizeSnapshotAccessResult, ResponseMetadata),
+        RusotoError<AuthorizeSnapshotAccessError>,
+    > {
+        let request = self.build_authorize_snapshot_access_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AuthorizeSnapshotAccessError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18439,35 +19428,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a set of cluster snapshots.</p>
-    async fn batch_delete_cluster_snapshots(
+    /// Presigns the request `authorize_snapshot_access` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_authorize_snapshot_access(
         &self,
-        input: BatchDeleteClusterSnapshotsRequest,
-    ) -> Result<BatchDeleteClusterSnapshotsResult, RusotoError<BatchDeleteClusterSnapshotsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_authorize_snapshot_access_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_authorize_snapshot_access_request(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AuthorizeSnapshotAccess");
         let mut params = Params::new();
 
-        params.put("Action", "BatchDeleteClusterSnapshots");
+        params.put("Action", "AuthorizeSnapshotAccess");
         params.put("Version", "2012-12-01");
-        BatchDeleteClusterSnapshotsRequestSerializer::serialize(&mut params, "", &input);
+        AuthorizeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `batch_delete_cluster_snapshots`, also returning the metadata of the response.
+    pub async fn batch_delete_cluster_snapshots_with_metadata(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> Result<
+        (BatchDeleteClusterSnapshotsResult, ResponseMetadata),
+        RusotoError<BatchDeleteClusterSnapshotsError>,
+    > {
+        let request = self.build_batch_delete_cluster_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(BatchDeleteClusterSnapshotsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18489,37 +19507,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the settings for a set of cluster snapshots.</p>
-    async fn batch_modify_cluster_snapshots(
+    /// Presigns the request `batch_delete_cluster_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_cluster_snapshots(
         &self,
-        input: BatchModifyClusterSnapshotsMessage,
-    ) -> Result<
-        BatchModifyClusterSnapshotsOutputMessage,
-        RusotoError<BatchModifyClusterSnapshotsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_cluster_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_cluster_snapshots_request(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("BatchDeleteClusterSnapshots");
         let mut params = Params::new();
 
-        params.put("Action", "BatchModifyClusterSnapshots");
+        params.put("Action", "BatchDeleteClusterSnapshots");
         params.put("Version", "2012-12-01");
-        BatchModifyClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
+        BatchDeleteClusterSnapshotsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `batch_modify_cluster_snapshots`, also returning the metadata of the response.
+    pub async fn batch_modify_cluster_snapshots_with_metadata(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> Result<
+        (BatchModifyClusterSnapshotsOutputMessage, ResponseMetadata),
+        RusotoError<BatchModifyClusterSnapshotsError>,
+    > {
+        let request = self.build_batch_modify_cluster_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(BatchModifyClusterSnapshotsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18541,34 +19586,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Cancels a resize operation for a cluster.</p>
-    async fn cancel_resize(
+    /// Presigns the request `batch_modify_cluster_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_modify_cluster_snapshots(
         &self,
-        input: CancelResizeMessage,
-    ) -> Result<ResizeProgressMessage, RusotoError<CancelResizeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_modify_cluster_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_modify_cluster_snapshots_request(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("BatchModifyClusterSnapshots");
         let mut params = Params::new();
 
-        params.put("Action", "CancelResize");
+        params.put("Action", "BatchModifyClusterSnapshots");
         params.put("Version", "2012-12-01");
-        CancelResizeMessageSerializer::serialize(&mut params, "", &input);
+        BatchModifyClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `cancel_resize`, also returning the metadata of the response.
+    pub async fn cancel_resize_with_metadata(
+        &self,
+        input: CancelResizeMessage,
+    ) -> Result<(ResizeProgressMessage, ResponseMetadata), RusotoError<CancelResizeError>> {
+        let request = self.build_cancel_resize_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CancelResizeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18588,34 +19660,59 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Copies the specified automated cluster snapshot to a new manual cluster snapshot. The source must be an automated snapshot and it must be in the available state.</p> <p>When you delete a cluster, Amazon Redshift deletes any automated snapshots of the cluster. Also, when the retention period of the snapshot expires, Amazon Redshift automatically deletes it. If you want to keep an automated snapshot for a longer period, you can make a manual copy of the snapshot. Manual snapshots are retained until you delete them.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn copy_cluster_snapshot(
+    /// Presigns the request `cancel_resize` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_resize(
         &self,
-        input: CopyClusterSnapshotMessage,
-    ) -> Result<CopyClusterSnapshotResult, RusotoError<CopyClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CancelResizeMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_resize_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_resize_request(&self, input: CancelResizeMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CancelResize");
         let mut params = Params::new();
 
-        params.put("Action", "CopyClusterSnapshot");
+        params.put("Action", "CancelResize");
         params.put("Version", "2012-12-01");
-        CopyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        CancelResizeMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `copy_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn copy_cluster_snapshot_with_metadata(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> Result<(CopyClusterSnapshotResult, ResponseMetadata), RusotoError<CopyClusterSnapshotError>>
+    {
+        let request = self.build_copy_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CopyClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18637,34 +19734,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new cluster with the specified parameters.</p> <p>To create a cluster in Virtual Private Cloud (VPC), you must provide a cluster subnet group name. The cluster subnet group identifies the subnets of your VPC that Amazon Redshift uses when creating the cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn create_cluster(
+    /// Presigns the request `copy_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_cluster_snapshot(
         &self,
-        input: CreateClusterMessage,
-    ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CopyClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_cluster_snapshot_request(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CopyClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "CreateCluster");
+        params.put("Action", "CopyClusterSnapshot");
         params.put("Version", "2012-12-01");
-        CreateClusterMessageSerializer::serialize(&mut params, "", &input);
+        CopyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_cluster`, also returning the metadata of the response.
+    pub async fn create_cluster_with_metadata(
+        &self,
+        input: CreateClusterMessage,
+    ) -> Result<(CreateClusterResult, ResponseMetadata), RusotoError<CreateClusterError>> {
+        let request = self.build_create_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18684,35 +19808,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an Amazon Redshift parameter group.</p> <p>Creating parameter groups is independent of creating clusters. You can associate a cluster with a parameter group when you create the cluster. You can also associate an existing cluster with a parameter group after the cluster is created by using <a>ModifyCluster</a>. </p> <p>Parameters in the parameter group define specific behavior that applies to the databases you create on the cluster. For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn create_cluster_parameter_group(
+    /// Presigns the request `create_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster(
         &self,
-        input: CreateClusterParameterGroupMessage,
-    ) -> Result<CreateClusterParameterGroupResult, RusotoError<CreateClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_request(&self, input: CreateClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateCluster");
         let mut params = Params::new();
 
-        params.put("Action", "CreateClusterParameterGroup");
+        params.put("Action", "CreateCluster");
         params.put("Version", "2012-12-01");
-        CreateClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn create_cluster_parameter_group_with_metadata(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> Result<
+        (CreateClusterParameterGroupResult, ResponseMetadata),
+        RusotoError<CreateClusterParameterGroupError>,
+    > {
+        let request = self.build_create_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18734,35 +19884,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new Amazon Redshift security group. You use security groups to control access to non-VPC clusters.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn create_cluster_security_group(
+    /// Presigns the request `create_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster_parameter_group(
         &self,
-        input: CreateClusterSecurityGroupMessage,
-    ) -> Result<CreateClusterSecurityGroupResult, RusotoError<CreateClusterSecurityGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_parameter_group_request(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateClusterSecurityGroup");
+        params.put("Action", "CreateClusterParameterGroup");
         params.put("Version", "2012-12-01");
-        CreateClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_cluster_security_group`, also returning the metadata of the response.
+    pub async fn create_cluster_security_group_with_metadata(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> Result<
+        (CreateClusterSecurityGroupResult, ResponseMetadata),
+        RusotoError<CreateClusterSecurityGroupError>,
+    > {
+        let request = self.build_create_cluster_security_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateClusterSecurityGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18784,34 +19963,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a manual snapshot of the specified cluster. The cluster must be in the <code>available</code> state. </p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn create_cluster_snapshot(
+    /// Presigns the request `create_cluster_security_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster_security_group(
         &self,
-        input: CreateClusterSnapshotMessage,
-    ) -> Result<CreateClusterSnapshotResult, RusotoError<CreateClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_security_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_security_group_request(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSecurityGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateClusterSnapshot");
+        params.put("Action", "CreateClusterSecurityGroup");
         params.put("Version", "2012-12-01");
-        CreateClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        CreateClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn create_cluster_snapshot_with_metadata(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> Result<
+        (CreateClusterSnapshotResult, ResponseMetadata),
+        RusotoError<CreateClusterSnapshotError>,
+    > {
+        let request = self.build_create_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18833,34 +20042,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new Amazon Redshift subnet group. You must provide a list of one or more subnets in your existing Amazon Virtual Private Cloud (Amazon VPC) when creating Amazon Redshift subnet group.</p> <p> For information about subnet groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-cluster-subnet-groups.html">Amazon Redshift Cluster Subnet Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn create_cluster_subnet_group(
+    /// Presigns the request `create_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster_snapshot(
         &self,
-        input: CreateClusterSubnetGroupMessage,
-    ) -> Result<CreateClusterSubnetGroupResult, RusotoError<CreateClusterSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_snapshot_request(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "CreateClusterSubnetGroup");
+        params.put("Action", "CreateClusterSnapshot");
         params.put("Version", "2012-12-01");
-        CreateClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_cluster_subnet_group`, also returning the metadata of the response.
+    pub async fn create_cluster_subnet_group_with_metadata(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> Result<
+        (CreateClusterSubnetGroupResult, ResponseMetadata),
+        RusotoError<CreateClusterSubnetGroupError>,
+    > {
+        let request = self.build_create_cluster_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateClusterSubnetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18882,34 +20121,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an Amazon Redshift event notification subscription. This action requires an ARN (Amazon Resource Name) of an Amazon SNS topic created by either the Amazon Redshift console, the Amazon SNS console, or the Amazon SNS API. To obtain an ARN with Amazon SNS, you must create a topic in Amazon SNS and subscribe to the topic. The ARN is displayed in the SNS console.</p> <p>You can specify the source type, and lists of Amazon Redshift source IDs, event categories, and event severities. Notifications will be sent for all events you want that match those criteria. For example, you can specify source type = cluster, source ID = my-cluster-1 and mycluster2, event categories = Availability, Backup, and severity = ERROR. The subscription will only send notifications for those ERROR events in the Availability and Backup categories for the specified clusters.</p> <p>If you specify both the source type and source IDs, such as source type = cluster and source identifier = my-cluster-1, notifications will be sent for all the cluster events for my-cluster-1. If you specify a source type but do not specify a source identifier, you will receive notice of the events for the objects of that type in your AWS account. If you do not specify either the SourceType nor the SourceIdentifier, you will be notified of events generated from all Amazon Redshift sources belonging to your AWS account. You must specify a source type if you specify a source ID.</p>
-    async fn create_event_subscription(
+    /// Presigns the request `create_cluster_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster_subnet_group(
         &self,
-        input: CreateEventSubscriptionMessage,
-    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_subnet_group_request(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateEventSubscription");
+        params.put("Action", "CreateClusterSubnetGroup");
         params.put("Version", "2012-12-01");
-        CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        CreateClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_event_subscription`, also returning the metadata of the response.
+    pub async fn create_event_subscription_with_metadata(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<
+        (CreateEventSubscriptionResult, ResponseMetadata),
+        RusotoError<CreateEventSubscriptionError>,
+    > {
+        let request = self.build_create_event_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateEventSubscriptionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18931,35 +20200,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an HSM client certificate that an Amazon Redshift cluster will use to connect to the client's HSM in order to store and retrieve the keys used to encrypt the cluster databases.</p> <p>The command returns a public key, which you must store in the HSM. In addition to creating the HSM certificate, you must create an Amazon Redshift HSM configuration that provides a cluster the information needed to store and use encryption keys in the HSM. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
-    async fn create_hsm_client_certificate(
+    /// Presigns the request `create_event_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_event_subscription(
         &self,
-        input: CreateHsmClientCertificateMessage,
-    ) -> Result<CreateHsmClientCertificateResult, RusotoError<CreateHsmClientCertificateError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_event_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_event_subscription_request(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
         let mut params = Params::new();
 
-        params.put("Action", "CreateHsmClientCertificate");
+        params.put("Action", "CreateEventSubscription");
         params.put("Version", "2012-12-01");
-        CreateHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
+        CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_hsm_client_certificate`, also returning the metadata of the response.
+    pub async fn create_hsm_client_certificate_with_metadata(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> Result<
+        (CreateHsmClientCertificateResult, ResponseMetadata),
+        RusotoError<CreateHsmClientCertificateError>,
+    > {
+        let request = self.build_create_hsm_client_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateHsmClientCertificateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -18981,34 +20279,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an HSM configuration that contains the information required by an Amazon Redshift cluster to store and use database encryption keys in a Hardware Security Module (HSM). After creating the HSM configuration, you can specify it as a parameter when creating a cluster. The cluster will then store its encryption keys in the HSM.</p> <p>In addition to creating an HSM configuration, you must also create an HSM client certificate. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
-    async fn create_hsm_configuration(
+    /// Presigns the request `create_hsm_client_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hsm_client_certificate(
         &self,
-        input: CreateHsmConfigurationMessage,
-    ) -> Result<CreateHsmConfigurationResult, RusotoError<CreateHsmConfigurationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateHsmClientCertificateMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hsm_client_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_hsm_client_certificate_request(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateHsmClientCertificate");
         let mut params = Params::new();
 
-        params.put("Action", "CreateHsmConfiguration");
+        params.put("Action", "CreateHsmClientCertificate");
         params.put("Version", "2012-12-01");
-        CreateHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
+        CreateHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_hsm_configuration`, also returning the metadata of the response.
+    pub async fn create_hsm_configuration_with_metadata(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> Result<
+        (CreateHsmConfigurationResult, ResponseMetadata),
+        RusotoError<CreateHsmConfigurationError>,
+    > {
+        let request = self.build_create_hsm_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateHsmConfigurationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19030,34 +20358,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a scheduled action. A scheduled action contains a schedule and an Amazon Redshift API action. For example, you can create a schedule of when to run the <code>ResizeCluster</code> API operation. </p>
-    async fn create_scheduled_action(
+    /// Presigns the request `create_hsm_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hsm_configuration(
         &self,
-        input: CreateScheduledActionMessage,
-    ) -> Result<ScheduledAction, RusotoError<CreateScheduledActionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateHsmConfigurationMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hsm_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_hsm_configuration_request(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateHsmConfiguration");
         let mut params = Params::new();
 
-        params.put("Action", "CreateScheduledAction");
+        params.put("Action", "CreateHsmConfiguration");
         params.put("Version", "2012-12-01");
-        CreateScheduledActionMessageSerializer::serialize(&mut params, "", &input);
+        CreateHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_scheduled_action`, also returning the metadata of the response.
+    pub async fn create_scheduled_action_with_metadata(
+        &self,
+        input: CreateScheduledActionMessage,
+    ) -> Result<(ScheduledAction, ResponseMetadata), RusotoError<CreateScheduledActionError>> {
+        let request = self.build_create_scheduled_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateScheduledActionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19079,34 +20434,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a snapshot copy grant that permits Amazon Redshift to use a customer master key (CMK) from AWS Key Management Service (AWS KMS) to encrypt copied snapshots in a destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    async fn create_snapshot_copy_grant(
+    /// Presigns the request `create_scheduled_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_scheduled_action(
         &self,
-        input: CreateSnapshotCopyGrantMessage,
-    ) -> Result<CreateSnapshotCopyGrantResult, RusotoError<CreateSnapshotCopyGrantError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateScheduledActionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_scheduled_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_scheduled_action_request(
+        &self,
+        input: CreateScheduledActionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateScheduledAction");
         let mut params = Params::new();
 
-        params.put("Action", "CreateSnapshotCopyGrant");
+        params.put("Action", "CreateScheduledAction");
         params.put("Version", "2012-12-01");
-        CreateSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
+        CreateScheduledActionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_snapshot_copy_grant`, also returning the metadata of the response.
+    pub async fn create_snapshot_copy_grant_with_metadata(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> Result<
+        (CreateSnapshotCopyGrantResult, ResponseMetadata),
+        RusotoError<CreateSnapshotCopyGrantError>,
+    > {
+        let request = self.build_create_snapshot_copy_grant_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateSnapshotCopyGrantError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19128,34 +20513,62 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a snapshot schedule with the rate of every 12 hours.</p>
-    async fn create_snapshot_schedule(
+    /// Presigns the request `create_snapshot_copy_grant` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_snapshot_copy_grant(
         &self,
-        input: CreateSnapshotScheduleMessage,
-    ) -> Result<SnapshotSchedule, RusotoError<CreateSnapshotScheduleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_snapshot_copy_grant_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_snapshot_copy_grant_request(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateSnapshotCopyGrant");
         let mut params = Params::new();
 
-        params.put("Action", "CreateSnapshotSchedule");
+        params.put("Action", "CreateSnapshotCopyGrant");
         params.put("Version", "2012-12-01");
-        CreateSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
+        CreateSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_snapshot_schedule`, also returning the metadata of the response.
+    pub async fn create_snapshot_schedule_with_metadata(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> Result<(SnapshotSchedule, ResponseMetadata), RusotoError<CreateSnapshotScheduleError>>
+    {
+        let request = self.build_create_snapshot_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateSnapshotScheduleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19177,62 +20590,110 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds tags to a cluster.</p> <p>A resource can have up to 50 tags. If you try to create more than 50 tags for a resource, you will receive an error and the attempt will fail.</p> <p>If you specify a key that already exists for the resource, the value for that key will be updated with the new value.</p>
-    async fn create_tags(
+    /// Presigns the request `create_snapshot_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_snapshot_schedule(
         &self,
-        input: CreateTagsMessage,
-    ) -> Result<(), RusotoError<CreateTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateSnapshotScheduleMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_snapshot_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_snapshot_schedule_request(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateSnapshotSchedule");
         let mut params = Params::new();
 
-        params.put("Action", "CreateTags");
+        params.put("Action", "CreateSnapshotSchedule");
         params.put("Version", "2012-12-01");
-        CreateTagsMessageSerializer::serialize(&mut params, "", &input);
+        CreateSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_tags`, also returning the metadata of the response.
+    pub async fn create_tags_with_metadata(
+        &self,
+        input: CreateTagsMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateTagsError>> {
+        let request = self.build_create_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateTagsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes a previously provisioned cluster without its final snapshot being created. A successful response from the web service indicates that the request was received correctly. Use <a>DescribeClusters</a> to monitor the status of the deletion. The delete operation cannot be canceled or reverted once submitted. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you want to shut down the cluster and retain it for future use, set <i>SkipFinalClusterSnapshot</i> to <code>false</code> and specify a name for <i>FinalClusterSnapshotIdentifier</i>. You can later restore this snapshot to resume using the cluster. If a final cluster snapshot is requested, the status of the cluster will be "final-snapshot" while the snapshot is being taken, then it's "deleting" once Amazon Redshift begins deleting the cluster. </p> <p> For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn delete_cluster(
+    /// Presigns the request `create_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_tags(
         &self,
-        input: DeleteClusterMessage,
-    ) -> Result<DeleteClusterResult, RusotoError<DeleteClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateTagsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_tags_request(&self, input: CreateTagsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateTags");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteCluster");
+        params.put("Action", "CreateTags");
         params.put("Version", "2012-12-01");
-        DeleteClusterMessageSerializer::serialize(&mut params, "", &input);
+        CreateTagsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cluster`, also returning the metadata of the response.
+    pub async fn delete_cluster_with_metadata(
+        &self,
+        input: DeleteClusterMessage,
+    ) -> Result<(DeleteClusterResult, ResponseMetadata), RusotoError<DeleteClusterError>> {
+        let request = self.build_delete_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19252,90 +20713,165 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes a specified Amazon Redshift parameter group.</p> <note> <p>You cannot delete a parameter group if it is associated with a cluster.</p> </note></p>
-    async fn delete_cluster_parameter_group(
+    /// Presigns the request `delete_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster(
         &self,
-        input: DeleteClusterParameterGroupMessage,
-    ) -> Result<(), RusotoError<DeleteClusterParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_request(&self, input: DeleteClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteCluster");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteClusterParameterGroup");
+        params.put("Action", "DeleteCluster");
         params.put("Version", "2012-12-01");
-        DeleteClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn delete_cluster_parameter_group_with_metadata(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteClusterParameterGroupError>> {
+        let request = self.build_delete_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteClusterParameterGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes an Amazon Redshift security group.</p> <note> <p>You cannot delete a security group that is associated with any clusters. You cannot delete the default security group.</p> </note> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn delete_cluster_security_group(
+    /// Presigns the request `delete_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster_parameter_group(
         &self,
-        input: DeleteClusterSecurityGroupMessage,
-    ) -> Result<(), RusotoError<DeleteClusterSecurityGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_parameter_group_request(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteClusterSecurityGroup");
+        params.put("Action", "DeleteClusterParameterGroup");
         params.put("Version", "2012-12-01");
-        DeleteClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cluster_security_group`, also returning the metadata of the response.
+    pub async fn delete_cluster_security_group_with_metadata(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteClusterSecurityGroupError>> {
+        let request = self.build_delete_cluster_security_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteClusterSecurityGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified manual snapshot. The snapshot must be in the <code>available</code> state, with no other users authorized to access the snapshot. </p> <p>Unlike automated snapshots, manual snapshots are retained even after you delete your cluster. Amazon Redshift does not delete your manual snapshots. You must delete manual snapshot explicitly to avoid getting charged. If other accounts are authorized to access the snapshot, you must revoke all of the authorizations before you can delete the snapshot.</p>
-    async fn delete_cluster_snapshot(
+    /// Presigns the request `delete_cluster_security_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster_security_group(
         &self,
-        input: DeleteClusterSnapshotMessage,
-    ) -> Result<DeleteClusterSnapshotResult, RusotoError<DeleteClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_security_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_security_group_request(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSecurityGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteClusterSnapshot");
+        params.put("Action", "DeleteClusterSecurityGroup");
         params.put("Version", "2012-12-01");
-        DeleteClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        DeleteClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn delete_cluster_snapshot_with_metadata(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> Result<
+        (DeleteClusterSnapshotResult, ResponseMetadata),
+        RusotoError<DeleteClusterSnapshotError>,
+    > {
+        let request = self.build_delete_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19357,258 +20893,475 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified cluster subnet group.</p>
-    async fn delete_cluster_subnet_group(
+    /// Presigns the request `delete_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster_snapshot(
         &self,
-        input: DeleteClusterSubnetGroupMessage,
-    ) -> Result<(), RusotoError<DeleteClusterSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_snapshot_request(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteClusterSubnetGroup");
+        params.put("Action", "DeleteClusterSnapshot");
         params.put("Version", "2012-12-01");
-        DeleteClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cluster_subnet_group`, also returning the metadata of the response.
+    pub async fn delete_cluster_subnet_group_with_metadata(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteClusterSubnetGroupError>> {
+        let request = self.build_delete_cluster_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteClusterSubnetGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes an Amazon Redshift event notification subscription.</p>
-    async fn delete_event_subscription(
+    /// Presigns the request `delete_cluster_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster_subnet_group(
         &self,
-        input: DeleteEventSubscriptionMessage,
-    ) -> Result<(), RusotoError<DeleteEventSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_subnet_group_request(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteEventSubscription");
+        params.put("Action", "DeleteClusterSubnetGroup");
         params.put("Version", "2012-12-01");
-        DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        DeleteClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_event_subscription`, also returning the metadata of the response.
+    pub async fn delete_event_subscription_with_metadata(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEventSubscriptionError>> {
+        let request = self.build_delete_event_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteEventSubscriptionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified HSM client certificate.</p>
-    async fn delete_hsm_client_certificate(
+    /// Presigns the request `delete_event_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_event_subscription(
         &self,
-        input: DeleteHsmClientCertificateMessage,
-    ) -> Result<(), RusotoError<DeleteHsmClientCertificateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_event_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_event_subscription_request(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteHsmClientCertificate");
+        params.put("Action", "DeleteEventSubscription");
         params.put("Version", "2012-12-01");
-        DeleteHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
+        DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_hsm_client_certificate`, also returning the metadata of the response.
+    pub async fn delete_hsm_client_certificate_with_metadata(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteHsmClientCertificateError>> {
+        let request = self.build_delete_hsm_client_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteHsmClientCertificateError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified Amazon Redshift HSM configuration.</p>
-    async fn delete_hsm_configuration(
+    /// Presigns the request `delete_hsm_client_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_hsm_client_certificate(
         &self,
-        input: DeleteHsmConfigurationMessage,
-    ) -> Result<(), RusotoError<DeleteHsmConfigurationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_hsm_client_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_hsm_client_certificate_request(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteHsmClientCertificate");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteHsmConfiguration");
+        params.put("Action", "DeleteHsmClientCertificate");
         params.put("Version", "2012-12-01");
-        DeleteHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
+        DeleteHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_hsm_configuration`, also returning the metadata of the response.
+    pub async fn delete_hsm_configuration_with_metadata(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteHsmConfigurationError>> {
+        let request = self.build_delete_hsm_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteHsmConfigurationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes a scheduled action. </p>
-    async fn delete_scheduled_action(
+    /// Presigns the request `delete_hsm_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_hsm_configuration(
         &self,
-        input: DeleteScheduledActionMessage,
-    ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteHsmConfigurationMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_hsm_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_hsm_configuration_request(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteHsmConfiguration");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteScheduledAction");
+        params.put("Action", "DeleteHsmConfiguration");
         params.put("Version", "2012-12-01");
-        DeleteScheduledActionMessageSerializer::serialize(&mut params, "", &input);
+        DeleteHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_scheduled_action`, also returning the metadata of the response.
+    pub async fn delete_scheduled_action_with_metadata(
+        &self,
+        input: DeleteScheduledActionMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteScheduledActionError>> {
+        let request = self.build_delete_scheduled_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteScheduledActionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified snapshot copy grant.</p>
-    async fn delete_snapshot_copy_grant(
+    /// Presigns the request `delete_scheduled_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_scheduled_action(
         &self,
-        input: DeleteSnapshotCopyGrantMessage,
-    ) -> Result<(), RusotoError<DeleteSnapshotCopyGrantError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteScheduledActionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_scheduled_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_scheduled_action_request(
+        &self,
+        input: DeleteScheduledActionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteSnapshotCopyGrant");
+        params.put("Action", "DeleteScheduledAction");
         params.put("Version", "2012-12-01");
-        DeleteSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
+        DeleteScheduledActionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_snapshot_copy_grant`, also returning the metadata of the response.
+    pub async fn delete_snapshot_copy_grant_with_metadata(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSnapshotCopyGrantError>> {
+        let request = self.build_delete_snapshot_copy_grant_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteSnapshotCopyGrantError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes a snapshot schedule.</p>
-    async fn delete_snapshot_schedule(
+    /// Presigns the request `delete_snapshot_copy_grant` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_snapshot_copy_grant(
         &self,
-        input: DeleteSnapshotScheduleMessage,
-    ) -> Result<(), RusotoError<DeleteSnapshotScheduleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_snapshot_copy_grant_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_snapshot_copy_grant_request(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteSnapshotCopyGrant");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteSnapshotSchedule");
+        params.put("Action", "DeleteSnapshotCopyGrant");
         params.put("Version", "2012-12-01");
-        DeleteSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
+        DeleteSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_snapshot_schedule`, also returning the metadata of the response.
+    pub async fn delete_snapshot_schedule_with_metadata(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSnapshotScheduleError>> {
+        let request = self.build_delete_snapshot_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteSnapshotScheduleError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes tags from a resource. You must provide the ARN of the resource from which you want to delete the tag or tags.</p>
-    async fn delete_tags(
+    /// Presigns the request `delete_snapshot_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_snapshot_schedule(
         &self,
-        input: DeleteTagsMessage,
-    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_snapshot_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_snapshot_schedule_request(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteSnapshotSchedule");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteTags");
+        params.put("Action", "DeleteSnapshotSchedule");
         params.put("Version", "2012-12-01");
-        DeleteTagsMessageSerializer::serialize(&mut params, "", &input);
+        DeleteSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_tags`, also returning the metadata of the response.
+    pub async fn delete_tags_with_metadata(
+        &self,
+        input: DeleteTagsMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteTagsError>> {
+        let request = self.build_delete_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteTagsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Returns a list of attributes attached to an account</p>
-    async fn describe_account_attributes(
+    /// Presigns the request `delete_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tags(
         &self,
-        input: DescribeAccountAttributesMessage,
-    ) -> Result<AccountAttributeList, RusotoError<DescribeAccountAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteTagsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tags_request(&self, input: DeleteTagsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteTags");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAccountAttributes");
+        params.put("Action", "DeleteTags");
         params.put("Version", "2012-12-01");
-        DescribeAccountAttributesMessageSerializer::serialize(&mut params, "", &input);
+        DeleteTagsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_account_attributes`, also returning the metadata of the response.
+    pub async fn describe_account_attributes_with_metadata(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> Result<(AccountAttributeList, ResponseMetadata), RusotoError<DescribeAccountAttributesError>>
+    {
+        let request = self.build_describe_account_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAccountAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19630,34 +21383,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns an array of <code>ClusterDbRevision</code> objects.</p>
-    async fn describe_cluster_db_revisions(
+    /// Presigns the request `describe_account_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_account_attributes(
         &self,
-        input: DescribeClusterDbRevisionsMessage,
-    ) -> Result<ClusterDbRevisionsMessage, RusotoError<DescribeClusterDbRevisionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAccountAttributesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_account_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_account_attributes_request(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeClusterDbRevisions");
+        params.put("Action", "DescribeAccountAttributes");
         params.put("Version", "2012-12-01");
-        DescribeClusterDbRevisionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeAccountAttributesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cluster_db_revisions`, also returning the metadata of the response.
+    pub async fn describe_cluster_db_revisions_with_metadata(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> Result<
+        (ClusterDbRevisionsMessage, ResponseMetadata),
+        RusotoError<DescribeClusterDbRevisionsError>,
+    > {
+        let request = self.build_describe_cluster_db_revisions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeClusterDbRevisionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19679,35 +21462,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of Amazon Redshift parameter groups, including parameter groups you created and the default parameter group. For each parameter group, the response includes the parameter group name, description, and parameter group family name. You can optionally specify a name to retrieve the description of a specific parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all parameter groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all parameter groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, parameter groups are returned regardless of whether they have tag keys or values associated with them.</p>
-    async fn describe_cluster_parameter_groups(
+    /// Presigns the request `describe_cluster_db_revisions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster_db_revisions(
         &self,
-        input: DescribeClusterParameterGroupsMessage,
-    ) -> Result<ClusterParameterGroupsMessage, RusotoError<DescribeClusterParameterGroupsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_db_revisions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_db_revisions_request(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterDbRevisions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeClusterParameterGroups");
+        params.put("Action", "DescribeClusterDbRevisions");
         params.put("Version", "2012-12-01");
-        DescribeClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeClusterDbRevisionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cluster_parameter_groups`, also returning the metadata of the response.
+    pub async fn describe_cluster_parameter_groups_with_metadata(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> Result<
+        (ClusterParameterGroupsMessage, ResponseMetadata),
+        RusotoError<DescribeClusterParameterGroupsError>,
+    > {
+        let request = self.build_describe_cluster_parameter_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeClusterParameterGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19729,34 +21541,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a detailed list of parameters contained within the specified Amazon Redshift parameter group. For each parameter the response includes information such as parameter name, description, data type, value, whether the parameter value is modifiable, and so on.</p> <p>You can specify <i>source</i> filter to retrieve parameters of only specific type. For example, to retrieve parameters that were modified by a user action such as from <a>ModifyClusterParameterGroup</a>, you can specify <i>source</i> equal to <i>user</i>.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn describe_cluster_parameters(
+    /// Presigns the request `describe_cluster_parameter_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster_parameter_groups(
         &self,
-        input: DescribeClusterParametersMessage,
-    ) -> Result<ClusterParameterGroupDetails, RusotoError<DescribeClusterParametersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_parameter_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_parameter_groups_request(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterParameterGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeClusterParameters");
+        params.put("Action", "DescribeClusterParameterGroups");
         params.put("Version", "2012-12-01");
-        DescribeClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cluster_parameters`, also returning the metadata of the response.
+    pub async fn describe_cluster_parameters_with_metadata(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> Result<
+        (ClusterParameterGroupDetails, ResponseMetadata),
+        RusotoError<DescribeClusterParametersError>,
+    > {
+        let request = self.build_describe_cluster_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeClusterParametersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19778,34 +21620,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about Amazon Redshift security groups. If the name of a security group is specified, the response will contain only information about only that security group.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all security groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all security groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, security groups are returned regardless of whether they have tag keys or values associated with them.</p>
-    async fn describe_cluster_security_groups(
+    /// Presigns the request `describe_cluster_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster_parameters(
         &self,
-        input: DescribeClusterSecurityGroupsMessage,
-    ) -> Result<ClusterSecurityGroupMessage, RusotoError<DescribeClusterSecurityGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeClusterParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_parameters_request(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeClusterSecurityGroups");
+        params.put("Action", "DescribeClusterParameters");
         params.put("Version", "2012-12-01");
-        DescribeClusterSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeClusterParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cluster_security_groups`, also returning the metadata of the response.
+    pub async fn describe_cluster_security_groups_with_metadata(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> Result<
+        (ClusterSecurityGroupMessage, ResponseMetadata),
+        RusotoError<DescribeClusterSecurityGroupsError>,
+    > {
+        let request = self.build_describe_cluster_security_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeClusterSecurityGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19827,34 +21699,62 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns one or more snapshot objects, which contain metadata about your cluster snapshots. By default, this operation returns information about all snapshots of all clusters that are owned by you AWS customer account. No information is returned for snapshots owned by inactive AWS customer accounts.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all snapshots that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all snapshots that have any combination of those values are returned. Only snapshots that you own are returned in the response; shared snapshots are not returned with the tag key and tag value request parameters.</p> <p>If both tag keys and values are omitted from the request, snapshots are returned regardless of whether they have tag keys or values associated with them.</p>
-    async fn describe_cluster_snapshots(
+    /// Presigns the request `describe_cluster_security_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster_security_groups(
         &self,
-        input: DescribeClusterSnapshotsMessage,
-    ) -> Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_security_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_security_groups_request(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSecurityGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeClusterSnapshots");
+        params.put("Action", "DescribeClusterSecurityGroups");
         params.put("Version", "2012-12-01");
-        DescribeClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeClusterSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `describe_cluster_snapshots`, also returning the metadata of the response.
+    pub async fn describe_cluster_snapshots_with_metadata(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> Result<(SnapshotMessage, ResponseMetadata), RusotoError<DescribeClusterSnapshotsError>>
+    {
+        let request = self.build_describe_cluster_snapshots_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeClusterSnapshotsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19876,34 +21776,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns one or more cluster subnet group objects, which contain metadata about your cluster subnet groups. By default, this operation returns information about all cluster subnet groups that are defined in you AWS account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all subnet groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subnet groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subnet groups are returned regardless of whether they have tag keys or values associated with them.</p>
-    async fn describe_cluster_subnet_groups(
+    /// Presigns the request `describe_cluster_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster_snapshots(
         &self,
-        input: DescribeClusterSubnetGroupsMessage,
-    ) -> Result<ClusterSubnetGroupMessage, RusotoError<DescribeClusterSubnetGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_snapshots_request(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSnapshots");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeClusterSubnetGroups");
+        params.put("Action", "DescribeClusterSnapshots");
         params.put("Version", "2012-12-01");
-        DescribeClusterSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cluster_subnet_groups`, also returning the metadata of the response.
+    pub async fn describe_cluster_subnet_groups_with_metadata(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> Result<
+        (ClusterSubnetGroupMessage, ResponseMetadata),
+        RusotoError<DescribeClusterSubnetGroupsError>,
+    > {
+        let request = self.build_describe_cluster_subnet_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeClusterSubnetGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19925,34 +21855,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of all the available maintenance tracks.</p>
-    async fn describe_cluster_tracks(
+    /// Presigns the request `describe_cluster_subnet_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster_subnet_groups(
         &self,
-        input: DescribeClusterTracksMessage,
-    ) -> Result<TrackListMessage, RusotoError<DescribeClusterTracksError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_subnet_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_subnet_groups_request(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSubnetGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeClusterTracks");
+        params.put("Action", "DescribeClusterSubnetGroups");
         params.put("Version", "2012-12-01");
-        DescribeClusterTracksMessageSerializer::serialize(&mut params, "", &input);
+        DescribeClusterSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cluster_tracks`, also returning the metadata of the response.
+    pub async fn describe_cluster_tracks_with_metadata(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> Result<(TrackListMessage, ResponseMetadata), RusotoError<DescribeClusterTracksError>> {
+        let request = self.build_describe_cluster_tracks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeClusterTracksError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -19974,34 +21931,62 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns descriptions of the available Amazon Redshift cluster versions. You can call this operation even before creating any clusters to learn more about the Amazon Redshift versions. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn describe_cluster_versions(
+    /// Presigns the request `describe_cluster_tracks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster_tracks(
         &self,
-        input: DescribeClusterVersionsMessage,
-    ) -> Result<ClusterVersionsMessage, RusotoError<DescribeClusterVersionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeClusterTracksMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_tracks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_tracks_request(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterTracks");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeClusterVersions");
+        params.put("Action", "DescribeClusterTracks");
         params.put("Version", "2012-12-01");
-        DescribeClusterVersionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeClusterTracksMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cluster_versions`, also returning the metadata of the response.
+    pub async fn describe_cluster_versions_with_metadata(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> Result<(ClusterVersionsMessage, ResponseMetadata), RusotoError<DescribeClusterVersionsError>>
+    {
+        let request = self.build_describe_cluster_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeClusterVersionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20023,34 +22008,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns properties of provisioned clusters including general cluster properties, cluster database properties, maintenance and backup properties, and security and access properties. This operation supports pagination. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all clusters that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all clusters that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, clusters are returned regardless of whether they have tag keys or values associated with them.</p>
-    async fn describe_clusters(
+    /// Presigns the request `describe_cluster_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster_versions(
         &self,
-        input: DescribeClustersMessage,
-    ) -> Result<ClustersMessage, RusotoError<DescribeClustersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeClusterVersionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_versions_request(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterVersions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeClusters");
+        params.put("Action", "DescribeClusterVersions");
         params.put("Version", "2012-12-01");
-        DescribeClustersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeClusterVersionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_clusters`, also returning the metadata of the response.
+    pub async fn describe_clusters_with_metadata(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<(ClustersMessage, ResponseMetadata), RusotoError<DescribeClustersError>> {
+        let request = self.build_describe_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeClustersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20070,39 +22082,63 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of parameter settings for the specified parameter group family.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn describe_default_cluster_parameters(
+    /// Presigns the request `describe_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_clusters(
         &self,
-        input: DescribeDefaultClusterParametersMessage,
-    ) -> Result<
-        DescribeDefaultClusterParametersResult,
-        RusotoError<DescribeDefaultClusterParametersError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeClustersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_clusters_request(&self, input: DescribeClustersMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDefaultClusterParameters");
+        params.put("Action", "DescribeClusters");
         params.put("Version", "2012-12-01");
-        DescribeDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeClustersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_default_cluster_parameters`, also returning the metadata of the response.
+    pub async fn describe_default_cluster_parameters_with_metadata(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> Result<
+        (DescribeDefaultClusterParametersResult, ResponseMetadata),
+        RusotoError<DescribeDefaultClusterParametersError>,
+    > {
+        let request = self.build_describe_default_cluster_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDefaultClusterParametersError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20124,34 +22160,62 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Displays a list of event categories for all event source types, or for a specified source type. For a list of the event categories and source types, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-event-notifications.html">Amazon Redshift Event Notifications</a>.</p>
-    async fn describe_event_categories(
+    /// Presigns the request `describe_default_cluster_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_default_cluster_parameters(
         &self,
-        input: DescribeEventCategoriesMessage,
-    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_default_cluster_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_default_cluster_parameters_request(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeDefaultClusterParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEventCategories");
+        params.put("Action", "DescribeDefaultClusterParameters");
         params.put("Version", "2012-12-01");
-        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_event_categories`, also returning the metadata of the response.
+    pub async fn describe_event_categories_with_metadata(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<(EventCategoriesMessage, ResponseMetadata), RusotoError<DescribeEventCategoriesError>>
+    {
+        let request = self.build_describe_event_categories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventCategoriesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20173,34 +22237,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists descriptions of all the Amazon Redshift event notification subscriptions for a customer account. If you specify a subscription name, lists the description for that subscription.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all event notification subscriptions that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subscriptions that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subscriptions are returned regardless of whether they have tag keys or values associated with them.</p>
-    async fn describe_event_subscriptions(
+    /// Presigns the request `describe_event_categories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_categories(
         &self,
-        input: DescribeEventSubscriptionsMessage,
-    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_categories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_categories_request(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEventSubscriptions");
+        params.put("Action", "DescribeEventCategories");
         params.put("Version", "2012-12-01");
-        DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_event_subscriptions`, also returning the metadata of the response.
+    pub async fn describe_event_subscriptions_with_metadata(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<
+        (EventSubscriptionsMessage, ResponseMetadata),
+        RusotoError<DescribeEventSubscriptionsError>,
+    > {
+        let request = self.build_describe_event_subscriptions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventSubscriptionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20222,34 +22316,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns events related to clusters, security groups, snapshots, and parameter groups for the past 14 days. Events specific to a particular cluster, security group, snapshot or parameter group can be obtained by providing the name as a parameter. By default, the past hour of events are returned.</p>
-    async fn describe_events(
+    /// Presigns the request `describe_event_subscriptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_subscriptions(
         &self,
-        input: DescribeEventsMessage,
-    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_subscriptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_subscriptions_request(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEvents");
+        params.put("Action", "DescribeEventSubscriptions");
         params.put("Version", "2012-12-01");
-        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_events`, also returning the metadata of the response.
+    pub async fn describe_events_with_metadata(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<(EventsMessage, ResponseMetadata), RusotoError<DescribeEventsError>> {
+        let request = self.build_describe_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20268,34 +22389,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about the specified HSM client certificate. If no certificate ID is specified, returns information about all the HSM certificates owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM client certificates that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM client certificates that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM client certificates are returned regardless of whether they have tag keys or values associated with them.</p>
-    async fn describe_hsm_client_certificates(
+    /// Presigns the request `describe_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events(
         &self,
-        input: DescribeHsmClientCertificatesMessage,
-    ) -> Result<HsmClientCertificateMessage, RusotoError<DescribeHsmClientCertificatesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_request(&self, input: DescribeEventsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeHsmClientCertificates");
+        params.put("Action", "DescribeEvents");
         params.put("Version", "2012-12-01");
-        DescribeHsmClientCertificatesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_hsm_client_certificates`, also returning the metadata of the response.
+    pub async fn describe_hsm_client_certificates_with_metadata(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> Result<
+        (HsmClientCertificateMessage, ResponseMetadata),
+        RusotoError<DescribeHsmClientCertificatesError>,
+    > {
+        let request = self.build_describe_hsm_client_certificates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeHsmClientCertificatesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20317,34 +22465,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about the specified Amazon Redshift HSM configuration. If no configuration ID is specified, returns information about all the HSM configurations owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM connections that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM connections that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM connections are returned regardless of whether they have tag keys or values associated with them.</p>
-    async fn describe_hsm_configurations(
+    /// Presigns the request `describe_hsm_client_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_hsm_client_certificates(
         &self,
-        input: DescribeHsmConfigurationsMessage,
-    ) -> Result<HsmConfigurationMessage, RusotoError<DescribeHsmConfigurationsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_hsm_client_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_hsm_client_certificates_request(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeHsmClientCertificates");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeHsmConfigurations");
+        params.put("Action", "DescribeHsmClientCertificates");
         params.put("Version", "2012-12-01");
-        DescribeHsmConfigurationsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeHsmClientCertificatesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_hsm_configurations`, also returning the metadata of the response.
+    pub async fn describe_hsm_configurations_with_metadata(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> Result<
+        (HsmConfigurationMessage, ResponseMetadata),
+        RusotoError<DescribeHsmConfigurationsError>,
+    > {
+        let request = self.build_describe_hsm_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeHsmConfigurationsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20366,34 +22544,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes whether information, such as queries and connection attempts, is being logged for the specified Amazon Redshift cluster.</p>
-    async fn describe_logging_status(
+    /// Presigns the request `describe_hsm_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_hsm_configurations(
         &self,
-        input: DescribeLoggingStatusMessage,
-    ) -> Result<LoggingStatus, RusotoError<DescribeLoggingStatusError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_hsm_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_hsm_configurations_request(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeHsmConfigurations");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoggingStatus");
+        params.put("Action", "DescribeHsmConfigurations");
         params.put("Version", "2012-12-01");
-        DescribeLoggingStatusMessageSerializer::serialize(&mut params, "", &input);
+        DescribeHsmConfigurationsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_logging_status`, also returning the metadata of the response.
+    pub async fn describe_logging_status_with_metadata(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> Result<(LoggingStatus, ResponseMetadata), RusotoError<DescribeLoggingStatusError>> {
+        let request = self.build_describe_logging_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLoggingStatusError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20413,37 +22618,66 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns properties of possible node configurations such as node type, number of nodes, and disk usage for the specified action type.</p>
-    async fn describe_node_configuration_options(
+    /// Presigns the request `describe_logging_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_logging_status(
         &self,
-        input: DescribeNodeConfigurationOptionsMessage,
-    ) -> Result<NodeConfigurationOptionsMessage, RusotoError<DescribeNodeConfigurationOptionsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeLoggingStatusMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_logging_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_logging_status_request(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeLoggingStatus");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeNodeConfigurationOptions");
+        params.put("Action", "DescribeLoggingStatus");
         params.put("Version", "2012-12-01");
-        DescribeNodeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeLoggingStatusMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `describe_node_configuration_options`, also returning the metadata of the response.
+    pub async fn describe_node_configuration_options_with_metadata(
+        &self,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> Result<
+        (NodeConfigurationOptionsMessage, ResponseMetadata),
+        RusotoError<DescribeNodeConfigurationOptionsError>,
+    > {
+        let request = self.build_describe_node_configuration_options_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeNodeConfigurationOptionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20465,37 +22699,66 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of orderable cluster options. Before you create a new cluster you can use this operation to find what options are available, such as the EC2 Availability Zones (AZ) in the specific AWS Region that you can specify, and the node types you can request. The node types differ by available storage, memory, CPU and price. With the cost involved you might want to obtain a list of cluster options in the specific region and specify values when creating a cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn describe_orderable_cluster_options(
+    /// Presigns the request `describe_node_configuration_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_node_configuration_options(
         &self,
-        input: DescribeOrderableClusterOptionsMessage,
-    ) -> Result<OrderableClusterOptionsMessage, RusotoError<DescribeOrderableClusterOptionsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_node_configuration_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_node_configuration_options_request(
+        &self,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeNodeConfigurationOptions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeOrderableClusterOptions");
+        params.put("Action", "DescribeNodeConfigurationOptions");
         params.put("Version", "2012-12-01");
-        DescribeOrderableClusterOptionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeNodeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_orderable_cluster_options`, also returning the metadata of the response.
+    pub async fn describe_orderable_cluster_options_with_metadata(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> Result<
+        (OrderableClusterOptionsMessage, ResponseMetadata),
+        RusotoError<DescribeOrderableClusterOptionsError>,
+    > {
+        let request = self.build_describe_orderable_cluster_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeOrderableClusterOptionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20517,34 +22780,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of the available reserved node offerings by Amazon Redshift with their descriptions including the node type, the fixed and recurring costs of reserving the node and duration the node will be reserved for you. These descriptions help you determine which reserve node offering you want to purchase. You then use the unique offering ID in you call to <a>PurchaseReservedNodeOffering</a> to reserve one or more nodes for your Amazon Redshift cluster. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn describe_reserved_node_offerings(
+    /// Presigns the request `describe_orderable_cluster_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_orderable_cluster_options(
         &self,
-        input: DescribeReservedNodeOfferingsMessage,
-    ) -> Result<ReservedNodeOfferingsMessage, RusotoError<DescribeReservedNodeOfferingsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_orderable_cluster_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_orderable_cluster_options_request(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeOrderableClusterOptions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeReservedNodeOfferings");
+        params.put("Action", "DescribeOrderableClusterOptions");
         params.put("Version", "2012-12-01");
-        DescribeReservedNodeOfferingsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeOrderableClusterOptionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_reserved_node_offerings`, also returning the metadata of the response.
+    pub async fn describe_reserved_node_offerings_with_metadata(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> Result<
+        (ReservedNodeOfferingsMessage, ResponseMetadata),
+        RusotoError<DescribeReservedNodeOfferingsError>,
+    > {
+        let request = self.build_describe_reserved_node_offerings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeReservedNodeOfferingsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20566,34 +22859,62 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the descriptions of the reserved nodes.</p>
-    async fn describe_reserved_nodes(
+    /// Presigns the request `describe_reserved_node_offerings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_reserved_node_offerings(
         &self,
-        input: DescribeReservedNodesMessage,
-    ) -> Result<ReservedNodesMessage, RusotoError<DescribeReservedNodesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_reserved_node_offerings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_reserved_node_offerings_request(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeReservedNodeOfferings");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeReservedNodes");
+        params.put("Action", "DescribeReservedNodeOfferings");
         params.put("Version", "2012-12-01");
-        DescribeReservedNodesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeReservedNodeOfferingsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_reserved_nodes`, also returning the metadata of the response.
+    pub async fn describe_reserved_nodes_with_metadata(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> Result<(ReservedNodesMessage, ResponseMetadata), RusotoError<DescribeReservedNodesError>>
+    {
+        let request = self.build_describe_reserved_nodes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeReservedNodesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20615,34 +22936,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about the last resize operation for the specified cluster. If no resize operation has ever been initiated for the specified cluster, a <code>HTTP 404</code> error is returned. If a resize operation was initiated and completed, the status of the resize remains as <code>SUCCEEDED</code> until the next resize. </p> <p>A resize operation can be requested using <a>ModifyCluster</a> and specifying a different number or type of nodes for the cluster. </p>
-    async fn describe_resize(
+    /// Presigns the request `describe_reserved_nodes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_reserved_nodes(
         &self,
-        input: DescribeResizeMessage,
-    ) -> Result<ResizeProgressMessage, RusotoError<DescribeResizeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeReservedNodesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_reserved_nodes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_reserved_nodes_request(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeReservedNodes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeResize");
+        params.put("Action", "DescribeReservedNodes");
         params.put("Version", "2012-12-01");
-        DescribeResizeMessageSerializer::serialize(&mut params, "", &input);
+        DescribeReservedNodesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_resize`, also returning the metadata of the response.
+    pub async fn describe_resize_with_metadata(
+        &self,
+        input: DescribeResizeMessage,
+    ) -> Result<(ResizeProgressMessage, ResponseMetadata), RusotoError<DescribeResizeError>> {
+        let request = self.build_describe_resize_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeResizeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20662,34 +23010,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes properties of scheduled actions. </p>
-    async fn describe_scheduled_actions(
+    /// Presigns the request `describe_resize` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_resize(
         &self,
-        input: DescribeScheduledActionsMessage,
-    ) -> Result<ScheduledActionsMessage, RusotoError<DescribeScheduledActionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeResizeMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_resize_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_resize_request(&self, input: DescribeResizeMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeResize");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeScheduledActions");
+        params.put("Action", "DescribeResize");
         params.put("Version", "2012-12-01");
-        DescribeScheduledActionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeResizeMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_scheduled_actions`, also returning the metadata of the response.
+    pub async fn describe_scheduled_actions_with_metadata(
+        &self,
+        input: DescribeScheduledActionsMessage,
+    ) -> Result<
+        (ScheduledActionsMessage, ResponseMetadata),
+        RusotoError<DescribeScheduledActionsError>,
+    > {
+        let request = self.build_describe_scheduled_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeScheduledActionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20711,34 +23086,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of snapshot copy grants owned by the AWS account in the destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    async fn describe_snapshot_copy_grants(
+    /// Presigns the request `describe_scheduled_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scheduled_actions(
         &self,
-        input: DescribeSnapshotCopyGrantsMessage,
-    ) -> Result<SnapshotCopyGrantMessage, RusotoError<DescribeSnapshotCopyGrantsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeScheduledActionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scheduled_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scheduled_actions_request(
+        &self,
+        input: DescribeScheduledActionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeSnapshotCopyGrants");
+        params.put("Action", "DescribeScheduledActions");
         params.put("Version", "2012-12-01");
-        DescribeSnapshotCopyGrantsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeScheduledActionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_snapshot_copy_grants`, also returning the metadata of the response.
+    pub async fn describe_snapshot_copy_grants_with_metadata(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> Result<
+        (SnapshotCopyGrantMessage, ResponseMetadata),
+        RusotoError<DescribeSnapshotCopyGrantsError>,
+    > {
+        let request = self.build_describe_snapshot_copy_grants_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeSnapshotCopyGrantsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20760,35 +23165,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of snapshot schedules. </p>
-    async fn describe_snapshot_schedules(
+    /// Presigns the request `describe_snapshot_copy_grants` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_snapshot_copy_grants(
         &self,
-        input: DescribeSnapshotSchedulesMessage,
-    ) -> Result<DescribeSnapshotSchedulesOutputMessage, RusotoError<DescribeSnapshotSchedulesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_snapshot_copy_grants_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_snapshot_copy_grants_request(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeSnapshotCopyGrants");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeSnapshotSchedules");
+        params.put("Action", "DescribeSnapshotCopyGrants");
         params.put("Version", "2012-12-01");
-        DescribeSnapshotSchedulesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeSnapshotCopyGrantsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_snapshot_schedules`, also returning the metadata of the response.
+    pub async fn describe_snapshot_schedules_with_metadata(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> Result<
+        (DescribeSnapshotSchedulesOutputMessage, ResponseMetadata),
+        RusotoError<DescribeSnapshotSchedulesError>,
+    > {
+        let request = self.build_describe_snapshot_schedules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeSnapshotSchedulesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20810,33 +23244,60 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns account level backups storage size and provisional storage.</p>
-    async fn describe_storage(
+    /// Presigns the request `describe_snapshot_schedules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_snapshot_schedules(
         &self,
-    ) -> Result<CustomerStorageMessage, RusotoError<DescribeStorageError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_snapshot_schedules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_snapshot_schedules_request(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeSnapshotSchedules");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStorage");
+        params.put("Action", "DescribeSnapshotSchedules");
         params.put("Version", "2012-12-01");
-
+        DescribeSnapshotSchedulesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_storage`, also returning the metadata of the response.
+    pub async fn describe_storage_with_metadata(
+        &self,
+    ) -> Result<(CustomerStorageMessage, ResponseMetadata), RusotoError<DescribeStorageError>> {
+        let request = self.build_describe_storage_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStorageError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20858,34 +23319,60 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists the status of one or more table restore requests made using the <a>RestoreTableFromClusterSnapshot</a> API action. If you don't specify a value for the <code>TableRestoreRequestId</code> parameter, then <code>DescribeTableRestoreStatus</code> returns the status of all table restore requests ordered by the date and time of the request in ascending order. Otherwise <code>DescribeTableRestoreStatus</code> returns the status of the table specified by <code>TableRestoreRequestId</code>.</p>
-    async fn describe_table_restore_status(
+    /// Presigns the request `describe_storage` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_storage(
         &self,
-        input: DescribeTableRestoreStatusMessage,
-    ) -> Result<TableRestoreStatusMessage, RusotoError<DescribeTableRestoreStatusError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_storage_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_storage_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeStorage");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTableRestoreStatus");
+        params.put("Action", "DescribeStorage");
         params.put("Version", "2012-12-01");
-        DescribeTableRestoreStatusMessageSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_table_restore_status`, also returning the metadata of the response.
+    pub async fn describe_table_restore_status_with_metadata(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> Result<
+        (TableRestoreStatusMessage, ResponseMetadata),
+        RusotoError<DescribeTableRestoreStatusError>,
+    > {
+        let request = self.build_describe_table_restore_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTableRestoreStatusError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20907,34 +23394,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of tags. You can return tags from a specific resource by specifying an ARN, or you can return all tags for a given type of resource, such as clusters, snapshots, and so on.</p> <p>The following are limitations for <code>DescribeTags</code>: </p> <ul> <li> <p>You cannot specify an ARN and a resource-type value together in the same request.</p> </li> <li> <p>You cannot use the <code>MaxRecords</code> and <code>Marker</code> parameters together with the ARN parameter.</p> </li> <li> <p>The <code>MaxRecords</code> parameter can be a range from 10 to 50 results to return in a request.</p> </li> </ul> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all resources that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all resources that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, resources are returned regardless of whether they have tag keys or values associated with them.</p>
-    async fn describe_tags(
+    /// Presigns the request `describe_table_restore_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_table_restore_status(
         &self,
-        input: DescribeTagsMessage,
-    ) -> Result<TaggedResourceListMessage, RusotoError<DescribeTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_table_restore_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_table_restore_status_request(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeTableRestoreStatus");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTags");
+        params.put("Action", "DescribeTableRestoreStatus");
         params.put("Version", "2012-12-01");
-        DescribeTagsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeTableRestoreStatusMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_tags`, also returning the metadata of the response.
+    pub async fn describe_tags_with_metadata(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> Result<(TaggedResourceListMessage, ResponseMetadata), RusotoError<DescribeTagsError>> {
+        let request = self.build_describe_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -20956,34 +23470,58 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Stops logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
-    async fn disable_logging(
+    /// Presigns the request `describe_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tags(
         &self,
-        input: DisableLoggingMessage,
-    ) -> Result<LoggingStatus, RusotoError<DisableLoggingError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTagsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tags_request(&self, input: DescribeTagsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeTags");
         let mut params = Params::new();
 
-        params.put("Action", "DisableLogging");
+        params.put("Action", "DescribeTags");
         params.put("Version", "2012-12-01");
-        DisableLoggingMessageSerializer::serialize(&mut params, "", &input);
+        DescribeTagsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `disable_logging`, also returning the metadata of the response.
+    pub async fn disable_logging_with_metadata(
+        &self,
+        input: DisableLoggingMessage,
+    ) -> Result<(LoggingStatus, ResponseMetadata), RusotoError<DisableLoggingError>> {
+        let request = self.build_disable_logging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DisableLoggingError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21002,34 +23540,59 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Disables the automatic copying of snapshots from one region to another region for a specified cluster.</p> <p>If your cluster and its snapshots are encrypted using a customer master key (CMK) from AWS KMS, use <a>DeleteSnapshotCopyGrant</a> to delete the grant that grants Amazon Redshift permission to the CMK in the destination region. </p>
-    async fn disable_snapshot_copy(
+    /// Presigns the request `disable_logging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_logging(
         &self,
-        input: DisableSnapshotCopyMessage,
-    ) -> Result<DisableSnapshotCopyResult, RusotoError<DisableSnapshotCopyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DisableLoggingMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_logging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_logging_request(&self, input: DisableLoggingMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DisableLogging");
         let mut params = Params::new();
 
-        params.put("Action", "DisableSnapshotCopy");
+        params.put("Action", "DisableLogging");
         params.put("Version", "2012-12-01");
-        DisableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
+        DisableLoggingMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `disable_snapshot_copy`, also returning the metadata of the response.
+    pub async fn disable_snapshot_copy_with_metadata(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> Result<(DisableSnapshotCopyResult, ResponseMetadata), RusotoError<DisableSnapshotCopyError>>
+    {
+        let request = self.build_disable_snapshot_copy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DisableSnapshotCopyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21051,34 +23614,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Starts logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
-    async fn enable_logging(
+    /// Presigns the request `disable_snapshot_copy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_snapshot_copy(
         &self,
-        input: EnableLoggingMessage,
-    ) -> Result<LoggingStatus, RusotoError<EnableLoggingError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DisableSnapshotCopyMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_snapshot_copy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_snapshot_copy_request(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DisableSnapshotCopy");
         let mut params = Params::new();
 
-        params.put("Action", "EnableLogging");
+        params.put("Action", "DisableSnapshotCopy");
         params.put("Version", "2012-12-01");
-        EnableLoggingMessageSerializer::serialize(&mut params, "", &input);
+        DisableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `enable_logging`, also returning the metadata of the response.
+    pub async fn enable_logging_with_metadata(
+        &self,
+        input: EnableLoggingMessage,
+    ) -> Result<(LoggingStatus, ResponseMetadata), RusotoError<EnableLoggingError>> {
+        let request = self.build_enable_logging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(EnableLoggingError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21097,34 +23687,59 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Enables the automatic copy of snapshots from one region to another region for a specified cluster.</p>
-    async fn enable_snapshot_copy(
+    /// Presigns the request `enable_logging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_logging(
         &self,
-        input: EnableSnapshotCopyMessage,
-    ) -> Result<EnableSnapshotCopyResult, RusotoError<EnableSnapshotCopyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: EnableLoggingMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_logging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_logging_request(&self, input: EnableLoggingMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("EnableLogging");
         let mut params = Params::new();
 
-        params.put("Action", "EnableSnapshotCopy");
+        params.put("Action", "EnableLogging");
         params.put("Version", "2012-12-01");
-        EnableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
+        EnableLoggingMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `enable_snapshot_copy`, also returning the metadata of the response.
+    pub async fn enable_snapshot_copy_with_metadata(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> Result<(EnableSnapshotCopyResult, ResponseMetadata), RusotoError<EnableSnapshotCopyError>>
+    {
+        let request = self.build_enable_snapshot_copy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(EnableSnapshotCopyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21146,34 +23761,62 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a database user name and temporary password with temporary authorization to log on to an Amazon Redshift database. The action returns the database user name prefixed with <code>IAM:</code> if <code>AutoCreate</code> is <code>False</code> or <code>IAMA:</code> if <code>AutoCreate</code> is <code>True</code>. You can optionally specify one or more database user groups that the user will join at log on. By default, the temporary credentials expire in 900 seconds. You can optionally specify a duration between 900 seconds (15 minutes) and 3600 seconds (60 minutes). For more information, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/generating-user-credentials.html">Using IAM Authentication to Generate Database User Credentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>The AWS Identity and Access Management (IAM)user or role that executes GetClusterCredentials must have an IAM policy attached that allows access to all necessary actions and resources. For more information about permissions, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/redshift-iam-access-control-identity-based.html#redshift-policy-resources.getclustercredentials-resources">Resource Policies for GetClusterCredentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>If the <code>DbGroups</code> parameter is specified, the IAM policy must allow the <code>redshift:JoinGroup</code> action with access to the listed <code>dbgroups</code>. </p> <p>In addition, if the <code>AutoCreate</code> parameter is set to <code>True</code>, then the policy must include the <code>redshift:CreateClusterUser</code> privilege.</p> <p>If the <code>DbName</code> parameter is specified, the IAM policy must allow access to the resource <code>dbname</code> for the specified database name. </p>
-    async fn get_cluster_credentials(
+    /// Presigns the request `enable_snapshot_copy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_snapshot_copy(
         &self,
-        input: GetClusterCredentialsMessage,
-    ) -> Result<ClusterCredentials, RusotoError<GetClusterCredentialsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: EnableSnapshotCopyMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_snapshot_copy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_snapshot_copy_request(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("EnableSnapshotCopy");
         let mut params = Params::new();
 
-        params.put("Action", "GetClusterCredentials");
+        params.put("Action", "EnableSnapshotCopy");
         params.put("Version", "2012-12-01");
-        GetClusterCredentialsMessageSerializer::serialize(&mut params, "", &input);
+        EnableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_cluster_credentials`, also returning the metadata of the response.
+    pub async fn get_cluster_credentials_with_metadata(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> Result<(ClusterCredentials, ResponseMetadata), RusotoError<GetClusterCredentialsError>>
+    {
+        let request = self.build_get_cluster_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetClusterCredentialsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21195,39 +23838,69 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns an array of DC2 ReservedNodeOfferings that matches the payment type, term, and usage price of the given DC1 reserved node.</p>
-    async fn get_reserved_node_exchange_offerings(
+    /// Presigns the request `get_cluster_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cluster_credentials(
         &self,
-        input: GetReservedNodeExchangeOfferingsInputMessage,
-    ) -> Result<
-        GetReservedNodeExchangeOfferingsOutputMessage,
-        RusotoError<GetReservedNodeExchangeOfferingsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: GetClusterCredentialsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cluster_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_cluster_credentials_request(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("GetClusterCredentials");
         let mut params = Params::new();
 
-        params.put("Action", "GetReservedNodeExchangeOfferings");
+        params.put("Action", "GetClusterCredentials");
         params.put("Version", "2012-12-01");
-        GetReservedNodeExchangeOfferingsInputMessageSerializer::serialize(&mut params, "", &input);
+        GetClusterCredentialsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_reserved_node_exchange_offerings`, also returning the metadata of the response.
+    pub async fn get_reserved_node_exchange_offerings_with_metadata(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> Result<
+        (
+            GetReservedNodeExchangeOfferingsOutputMessage,
+            ResponseMetadata,
+        ),
+        RusotoError<GetReservedNodeExchangeOfferingsError>,
+    > {
+        let request = self.build_get_reserved_node_exchange_offerings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetReservedNodeExchangeOfferingsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21249,34 +23922,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the settings for a cluster.</p> <p>You can also change node type and the number of nodes to scale up or down the cluster. When resizing a cluster, you must specify both the number of nodes and the node type even if one of the parameters does not change.</p> <p>You can add another security or parameter group, or change the master user password. Resetting a cluster password or modifying the security groups associated with a cluster do not need a reboot. However, modifying a parameter group requires a reboot for parameters to take effect. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn modify_cluster(
+    /// Presigns the request `get_reserved_node_exchange_offerings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_reserved_node_exchange_offerings(
         &self,
-        input: ModifyClusterMessage,
-    ) -> Result<ModifyClusterResult, RusotoError<ModifyClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_reserved_node_exchange_offerings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_reserved_node_exchange_offerings_request(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("GetReservedNodeExchangeOfferings");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyCluster");
+        params.put("Action", "GetReservedNodeExchangeOfferings");
         params.put("Version", "2012-12-01");
-        ModifyClusterMessageSerializer::serialize(&mut params, "", &input);
+        GetReservedNodeExchangeOfferingsInputMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cluster`, also returning the metadata of the response.
+    pub async fn modify_cluster_with_metadata(
+        &self,
+        input: ModifyClusterMessage,
+    ) -> Result<(ModifyClusterResult, ResponseMetadata), RusotoError<ModifyClusterError>> {
+        let request = self.build_modify_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21296,34 +23996,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the database revision of a cluster. The database revision is a unique revision of the database running in a cluster.</p>
-    async fn modify_cluster_db_revision(
+    /// Presigns the request `modify_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cluster(
         &self,
-        input: ModifyClusterDbRevisionMessage,
-    ) -> Result<ModifyClusterDbRevisionResult, RusotoError<ModifyClusterDbRevisionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cluster_request(&self, input: ModifyClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyCluster");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyClusterDbRevision");
+        params.put("Action", "ModifyCluster");
         params.put("Version", "2012-12-01");
-        ModifyClusterDbRevisionMessageSerializer::serialize(&mut params, "", &input);
+        ModifyClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cluster_db_revision`, also returning the metadata of the response.
+    pub async fn modify_cluster_db_revision_with_metadata(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> Result<
+        (ModifyClusterDbRevisionResult, ResponseMetadata),
+        RusotoError<ModifyClusterDbRevisionError>,
+    > {
+        let request = self.build_modify_cluster_db_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyClusterDbRevisionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21345,34 +24072,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the list of AWS Identity and Access Management (IAM) roles that can be used by the cluster to access other AWS services.</p> <p>A cluster can have up to 10 IAM roles associated at any time.</p>
-    async fn modify_cluster_iam_roles(
+    /// Presigns the request `modify_cluster_db_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cluster_db_revision(
         &self,
-        input: ModifyClusterIamRolesMessage,
-    ) -> Result<ModifyClusterIamRolesResult, RusotoError<ModifyClusterIamRolesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cluster_db_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cluster_db_revision_request(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterDbRevision");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyClusterIamRoles");
+        params.put("Action", "ModifyClusterDbRevision");
         params.put("Version", "2012-12-01");
-        ModifyClusterIamRolesMessageSerializer::serialize(&mut params, "", &input);
+        ModifyClusterDbRevisionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cluster_iam_roles`, also returning the metadata of the response.
+    pub async fn modify_cluster_iam_roles_with_metadata(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> Result<
+        (ModifyClusterIamRolesResult, ResponseMetadata),
+        RusotoError<ModifyClusterIamRolesError>,
+    > {
+        let request = self.build_modify_cluster_iam_roles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyClusterIamRolesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21394,34 +24151,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the maintenance settings of a cluster.</p>
-    async fn modify_cluster_maintenance(
+    /// Presigns the request `modify_cluster_iam_roles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cluster_iam_roles(
         &self,
-        input: ModifyClusterMaintenanceMessage,
-    ) -> Result<ModifyClusterMaintenanceResult, RusotoError<ModifyClusterMaintenanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyClusterIamRolesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cluster_iam_roles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cluster_iam_roles_request(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterIamRoles");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyClusterMaintenance");
+        params.put("Action", "ModifyClusterIamRoles");
         params.put("Version", "2012-12-01");
-        ModifyClusterMaintenanceMessageSerializer::serialize(&mut params, "", &input);
+        ModifyClusterIamRolesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cluster_maintenance`, also returning the metadata of the response.
+    pub async fn modify_cluster_maintenance_with_metadata(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> Result<
+        (ModifyClusterMaintenanceResult, ResponseMetadata),
+        RusotoError<ModifyClusterMaintenanceError>,
+    > {
+        let request = self.build_modify_cluster_maintenance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyClusterMaintenanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21443,35 +24230,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the parameters of a parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn modify_cluster_parameter_group(
+    /// Presigns the request `modify_cluster_maintenance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cluster_maintenance(
         &self,
-        input: ModifyClusterParameterGroupMessage,
-    ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ModifyClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cluster_maintenance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cluster_maintenance_request(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterMaintenance");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyClusterParameterGroup");
+        params.put("Action", "ModifyClusterMaintenance");
         params.put("Version", "2012-12-01");
-        ModifyClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyClusterMaintenanceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn modify_cluster_parameter_group_with_metadata(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> Result<
+        (ClusterParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ModifyClusterParameterGroupError>,
+    > {
+        let request = self.build_modify_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21493,34 +24309,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the settings for a snapshot.</p> <p>This exanmple modifies the manual retention period setting for a cluster snapshot.</p>
-    async fn modify_cluster_snapshot(
+    /// Presigns the request `modify_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cluster_parameter_group(
         &self,
-        input: ModifyClusterSnapshotMessage,
-    ) -> Result<ModifyClusterSnapshotResult, RusotoError<ModifyClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cluster_parameter_group_request(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyClusterSnapshot");
+        params.put("Action", "ModifyClusterParameterGroup");
         params.put("Version", "2012-12-01");
-        ModifyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        ModifyClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn modify_cluster_snapshot_with_metadata(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> Result<
+        (ModifyClusterSnapshotResult, ResponseMetadata),
+        RusotoError<ModifyClusterSnapshotError>,
+    > {
+        let request = self.build_modify_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21542,62 +24388,116 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies a snapshot schedule for a cluster.</p>
-    async fn modify_cluster_snapshot_schedule(
+    /// Presigns the request `modify_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cluster_snapshot(
         &self,
-        input: ModifyClusterSnapshotScheduleMessage,
-    ) -> Result<(), RusotoError<ModifyClusterSnapshotScheduleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cluster_snapshot_request(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyClusterSnapshotSchedule");
+        params.put("Action", "ModifyClusterSnapshot");
         params.put("Version", "2012-12-01");
-        ModifyClusterSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
+        ModifyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cluster_snapshot_schedule`, also returning the metadata of the response.
+    pub async fn modify_cluster_snapshot_schedule_with_metadata(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<ModifyClusterSnapshotScheduleError>> {
+        let request = self.build_modify_cluster_snapshot_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyClusterSnapshotScheduleError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Modifies a cluster subnet group to include the specified list of VPC subnets. The operation replaces the existing list of subnets with the new list of subnets.</p>
-    async fn modify_cluster_subnet_group(
+    /// Presigns the request `modify_cluster_snapshot_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cluster_snapshot_schedule(
         &self,
-        input: ModifyClusterSubnetGroupMessage,
-    ) -> Result<ModifyClusterSubnetGroupResult, RusotoError<ModifyClusterSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cluster_snapshot_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cluster_snapshot_schedule_request(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSnapshotSchedule");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyClusterSubnetGroup");
+        params.put("Action", "ModifyClusterSnapshotSchedule");
         params.put("Version", "2012-12-01");
-        ModifyClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyClusterSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cluster_subnet_group`, also returning the metadata of the response.
+    pub async fn modify_cluster_subnet_group_with_metadata(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> Result<
+        (ModifyClusterSubnetGroupResult, ResponseMetadata),
+        RusotoError<ModifyClusterSubnetGroupError>,
+    > {
+        let request = self.build_modify_cluster_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyClusterSubnetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21619,34 +24519,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies an existing Amazon Redshift event notification subscription.</p>
-    async fn modify_event_subscription(
+    /// Presigns the request `modify_cluster_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cluster_subnet_group(
         &self,
-        input: ModifyEventSubscriptionMessage,
-    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cluster_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cluster_subnet_group_request(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyEventSubscription");
+        params.put("Action", "ModifyClusterSubnetGroup");
         params.put("Version", "2012-12-01");
-        ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        ModifyClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_event_subscription`, also returning the metadata of the response.
+    pub async fn modify_event_subscription_with_metadata(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<
+        (ModifyEventSubscriptionResult, ResponseMetadata),
+        RusotoError<ModifyEventSubscriptionError>,
+    > {
+        let request = self.build_modify_event_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyEventSubscriptionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21668,34 +24598,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies a scheduled action. </p>
-    async fn modify_scheduled_action(
+    /// Presigns the request `modify_event_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_event_subscription(
         &self,
-        input: ModifyScheduledActionMessage,
-    ) -> Result<ScheduledAction, RusotoError<ModifyScheduledActionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_event_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_event_subscription_request(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyScheduledAction");
+        params.put("Action", "ModifyEventSubscription");
         params.put("Version", "2012-12-01");
-        ModifyScheduledActionMessageSerializer::serialize(&mut params, "", &input);
+        ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_scheduled_action`, also returning the metadata of the response.
+    pub async fn modify_scheduled_action_with_metadata(
+        &self,
+        input: ModifyScheduledActionMessage,
+    ) -> Result<(ScheduledAction, ResponseMetadata), RusotoError<ModifyScheduledActionError>> {
+        let request = self.build_modify_scheduled_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyScheduledActionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21717,39 +24674,66 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the number of days to retain snapshots in the destination AWS Region after they are copied from the source AWS Region. By default, this operation only changes the retention period of copied automated snapshots. The retention periods for both new and existing copied automated snapshots are updated with the new retention period. You can set the manual option to change only the retention periods of copied manual snapshots. If you set this option, only newly copied manual snapshots have the new retention period. </p>
-    async fn modify_snapshot_copy_retention_period(
+    /// Presigns the request `modify_scheduled_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_scheduled_action(
         &self,
-        input: ModifySnapshotCopyRetentionPeriodMessage,
-    ) -> Result<
-        ModifySnapshotCopyRetentionPeriodResult,
-        RusotoError<ModifySnapshotCopyRetentionPeriodError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyScheduledActionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_scheduled_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_scheduled_action_request(
+        &self,
+        input: ModifyScheduledActionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyScheduledAction");
         let mut params = Params::new();
 
-        params.put("Action", "ModifySnapshotCopyRetentionPeriod");
+        params.put("Action", "ModifyScheduledAction");
         params.put("Version", "2012-12-01");
-        ModifySnapshotCopyRetentionPeriodMessageSerializer::serialize(&mut params, "", &input);
+        ModifyScheduledActionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_snapshot_copy_retention_period`, also returning the metadata of the response.
+    pub async fn modify_snapshot_copy_retention_period_with_metadata(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> Result<
+        (ModifySnapshotCopyRetentionPeriodResult, ResponseMetadata),
+        RusotoError<ModifySnapshotCopyRetentionPeriodError>,
+    > {
+        let request = self.build_modify_snapshot_copy_retention_period_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifySnapshotCopyRetentionPeriodError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21771,34 +24755,62 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies a snapshot schedule. Any schedule associated with a cluster is modified asynchronously.</p>
-    async fn modify_snapshot_schedule(
+    /// Presigns the request `modify_snapshot_copy_retention_period` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_snapshot_copy_retention_period(
         &self,
-        input: ModifySnapshotScheduleMessage,
-    ) -> Result<SnapshotSchedule, RusotoError<ModifySnapshotScheduleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_snapshot_copy_retention_period_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_snapshot_copy_retention_period_request(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifySnapshotCopyRetentionPeriod");
         let mut params = Params::new();
 
-        params.put("Action", "ModifySnapshotSchedule");
+        params.put("Action", "ModifySnapshotCopyRetentionPeriod");
         params.put("Version", "2012-12-01");
-        ModifySnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
+        ModifySnapshotCopyRetentionPeriodMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_snapshot_schedule`, also returning the metadata of the response.
+    pub async fn modify_snapshot_schedule_with_metadata(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> Result<(SnapshotSchedule, ResponseMetadata), RusotoError<ModifySnapshotScheduleError>>
+    {
+        let request = self.build_modify_snapshot_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifySnapshotScheduleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21820,35 +24832,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Allows you to purchase reserved nodes. Amazon Redshift offers a predefined set of reserved node offerings. You can purchase one or more of the offerings. You can call the <a>DescribeReservedNodeOfferings</a> API to obtain the available reserved node offerings. You can call this API by providing a specific reserved node offering and the number of nodes you want to reserve. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn purchase_reserved_node_offering(
+    /// Presigns the request `modify_snapshot_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_snapshot_schedule(
         &self,
-        input: PurchaseReservedNodeOfferingMessage,
-    ) -> Result<PurchaseReservedNodeOfferingResult, RusotoError<PurchaseReservedNodeOfferingError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ModifySnapshotScheduleMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_snapshot_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_snapshot_schedule_request(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifySnapshotSchedule");
         let mut params = Params::new();
 
-        params.put("Action", "PurchaseReservedNodeOffering");
+        params.put("Action", "ModifySnapshotSchedule");
         params.put("Version", "2012-12-01");
-        PurchaseReservedNodeOfferingMessageSerializer::serialize(&mut params, "", &input);
+        ModifySnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `purchase_reserved_node_offering`, also returning the metadata of the response.
+    pub async fn purchase_reserved_node_offering_with_metadata(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> Result<
+        (PurchaseReservedNodeOfferingResult, ResponseMetadata),
+        RusotoError<PurchaseReservedNodeOfferingError>,
+    > {
+        let request = self.build_purchase_reserved_node_offering_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PurchaseReservedNodeOfferingError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21870,34 +24911,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Reboots a cluster. This action is taken as soon as possible. It results in a momentary outage to the cluster, during which the cluster status is set to <code>rebooting</code>. A cluster event is created when the reboot is completed. Any pending cluster modifications (see <a>ModifyCluster</a>) are applied at this reboot. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    async fn reboot_cluster(
+    /// Presigns the request `purchase_reserved_node_offering` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_purchase_reserved_node_offering(
         &self,
-        input: RebootClusterMessage,
-    ) -> Result<RebootClusterResult, RusotoError<RebootClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_purchase_reserved_node_offering_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_purchase_reserved_node_offering_request(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("PurchaseReservedNodeOffering");
         let mut params = Params::new();
 
-        params.put("Action", "RebootCluster");
+        params.put("Action", "PurchaseReservedNodeOffering");
         params.put("Version", "2012-12-01");
-        RebootClusterMessageSerializer::serialize(&mut params, "", &input);
+        PurchaseReservedNodeOfferingMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reboot_cluster`, also returning the metadata of the response.
+    pub async fn reboot_cluster_with_metadata(
+        &self,
+        input: RebootClusterMessage,
+    ) -> Result<(RebootClusterResult, ResponseMetadata), RusotoError<RebootClusterError>> {
+        let request = self.build_reboot_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RebootClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21917,35 +24985,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sets one or more parameters of the specified parameter group to their default values and sets the source values of the parameters to "engine-default". To reset the entire parameter group specify the <i>ResetAllParameters</i> parameter. For parameter changes to take effect you must reboot any associated clusters. </p>
-    async fn reset_cluster_parameter_group(
+    /// Presigns the request `reboot_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_cluster(
         &self,
-        input: ResetClusterParameterGroupMessage,
-    ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ResetClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: RebootClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_cluster_request(&self, input: RebootClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RebootCluster");
         let mut params = Params::new();
 
-        params.put("Action", "ResetClusterParameterGroup");
+        params.put("Action", "RebootCluster");
         params.put("Version", "2012-12-01");
-        ResetClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        RebootClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reset_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn reset_cluster_parameter_group_with_metadata(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> Result<
+        (ClusterParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ResetClusterParameterGroupError>,
+    > {
+        let request = self.build_reset_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ResetClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -21967,34 +25061,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Changes the size of the cluster. You can change the cluster&#39;s type, or change the number or type of nodes. The default behavior is to use the elastic resize method. With an elastic resize, your cluster is available for read and write operations more quickly than with the classic resize method. </p> <p>Elastic resize operations have the following restrictions:</p> <ul> <li> <p>You can only resize clusters of the following types:</p> <ul> <li> <p>dc2.large</p> </li> <li> <p>dc2.8xlarge</p> </li> <li> <p>ds2.xlarge</p> </li> <li> <p>ds2.8xlarge</p> </li> <li> <p>ra3.16xlarge</p> </li> </ul> </li> <li> <p>The type of nodes that you add must match the node type for the cluster.</p> </li> </ul></p>
-    async fn resize_cluster(
+    /// Presigns the request `reset_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_cluster_parameter_group(
         &self,
-        input: ResizeClusterMessage,
-    ) -> Result<ResizeClusterResult, RusotoError<ResizeClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ResetClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_cluster_parameter_group_request(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ResetClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ResizeCluster");
+        params.put("Action", "ResetClusterParameterGroup");
         params.put("Version", "2012-12-01");
-        ResizeClusterMessageSerializer::serialize(&mut params, "", &input);
+        ResetClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `resize_cluster`, also returning the metadata of the response.
+    pub async fn resize_cluster_with_metadata(
+        &self,
+        input: ResizeClusterMessage,
+    ) -> Result<(ResizeClusterResult, ResponseMetadata), RusotoError<ResizeClusterError>> {
+        let request = self.build_resize_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ResizeClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -22014,35 +25135,61 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new cluster from a snapshot. By default, Amazon Redshift creates the resulting cluster with the same configuration as the original cluster from which the snapshot was created, except that the new cluster is created with the default cluster security and parameter groups. After Amazon Redshift creates the cluster, you can use the <a>ModifyCluster</a> API to associate a different security group and different parameter group with the restored cluster. If you are using a DS node type, you can also choose to change to another DS node type of the same size during restore.</p> <p>If you restore a cluster into a VPC, you must provide a cluster subnet group where you want the cluster restored.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn restore_from_cluster_snapshot(
+    /// Presigns the request `resize_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resize_cluster(
         &self,
-        input: RestoreFromClusterSnapshotMessage,
-    ) -> Result<RestoreFromClusterSnapshotResult, RusotoError<RestoreFromClusterSnapshotError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ResizeClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resize_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resize_cluster_request(&self, input: ResizeClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ResizeCluster");
         let mut params = Params::new();
 
-        params.put("Action", "RestoreFromClusterSnapshot");
+        params.put("Action", "ResizeCluster");
         params.put("Version", "2012-12-01");
-        RestoreFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        ResizeClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `restore_from_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn restore_from_cluster_snapshot_with_metadata(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> Result<
+        (RestoreFromClusterSnapshotResult, ResponseMetadata),
+        RusotoError<RestoreFromClusterSnapshotError>,
+    > {
+        let request = self.build_restore_from_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RestoreFromClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -22064,39 +25211,66 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new table from a table in an Amazon Redshift cluster snapshot. You must create the new table within the Amazon Redshift cluster that the snapshot was taken from.</p> <p>You cannot use <code>RestoreTableFromClusterSnapshot</code> to restore a table with the same name as an existing table in an Amazon Redshift cluster. That is, you cannot overwrite an existing table in a cluster with a restored table. If you want to replace your original table with a new, restored table, then rename or drop your original table before you call <code>RestoreTableFromClusterSnapshot</code>. When you have renamed your original table, then you can pass the original name of the table as the <code>NewTableName</code> parameter value in the call to <code>RestoreTableFromClusterSnapshot</code>. This way, you can replace the original table with the table created from the snapshot.</p>
-    async fn restore_table_from_cluster_snapshot(
+    /// Presigns the request `restore_from_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_from_cluster_snapshot(
         &self,
-        input: RestoreTableFromClusterSnapshotMessage,
-    ) -> Result<
-        RestoreTableFromClusterSnapshotResult,
-        RusotoError<RestoreTableFromClusterSnapshotError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_from_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_from_cluster_snapshot_request(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RestoreFromClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "RestoreTableFromClusterSnapshot");
+        params.put("Action", "RestoreFromClusterSnapshot");
         params.put("Version", "2012-12-01");
-        RestoreTableFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        RestoreFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `restore_table_from_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn restore_table_from_cluster_snapshot_with_metadata(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> Result<
+        (RestoreTableFromClusterSnapshotResult, ResponseMetadata),
+        RusotoError<RestoreTableFromClusterSnapshotError>,
+    > {
+        let request = self.build_restore_table_from_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RestoreTableFromClusterSnapshotError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -22118,39 +25292,66 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Revokes an ingress rule in an Amazon Redshift security group for a previously authorized IP range or Amazon EC2 security group. To add an ingress rule, see <a>AuthorizeClusterSecurityGroupIngress</a>. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
-    async fn revoke_cluster_security_group_ingress(
+    /// Presigns the request `restore_table_from_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_table_from_cluster_snapshot(
         &self,
-        input: RevokeClusterSecurityGroupIngressMessage,
-    ) -> Result<
-        RevokeClusterSecurityGroupIngressResult,
-        RusotoError<RevokeClusterSecurityGroupIngressError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_table_from_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_table_from_cluster_snapshot_request(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RestoreTableFromClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "RevokeClusterSecurityGroupIngress");
+        params.put("Action", "RestoreTableFromClusterSnapshot");
         params.put("Version", "2012-12-01");
-        RevokeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
+        RestoreTableFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `revoke_cluster_security_group_ingress`, also returning the metadata of the response.
+    pub async fn revoke_cluster_security_group_ingress_with_metadata(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> Result<
+        (RevokeClusterSecurityGroupIngressResult, ResponseMetadata),
+        RusotoError<RevokeClusterSecurityGroupIngressError>,
+    > {
+        let request = self.build_revoke_cluster_security_group_ingress_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RevokeClusterSecurityGroupIngressError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -22172,34 +25373,64 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes the ability of the specified AWS customer account to restore the specified snapshot. If the account is currently restoring the snapshot, the restore will run to completion.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    async fn revoke_snapshot_access(
+    /// Presigns the request `revoke_cluster_security_group_ingress` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_revoke_cluster_security_group_ingress(
         &self,
-        input: RevokeSnapshotAccessMessage,
-    ) -> Result<RevokeSnapshotAccessResult, RusotoError<RevokeSnapshotAccessError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_revoke_cluster_security_group_ingress_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_revoke_cluster_security_group_ingress_request(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RevokeClusterSecurityGroupIngress");
         let mut params = Params::new();
 
-        params.put("Action", "RevokeSnapshotAccess");
+        params.put("Action", "RevokeClusterSecurityGroupIngress");
         params.put("Version", "2012-12-01");
-        RevokeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
+        RevokeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `revoke_snapshot_access`, also returning the metadata of the response.
+    pub async fn revoke_snapshot_access_with_metadata(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> Result<
+        (RevokeSnapshotAccessResult, ResponseMetadata),
+        RusotoError<RevokeSnapshotAccessError>,
+    > {
+        let request = self.build_revoke_snapshot_access_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RevokeSnapshotAccessError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -22221,34 +25452,62 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Rotates the encryption keys for a cluster.</p>
-    async fn rotate_encryption_key(
+    /// Presigns the request `revoke_snapshot_access` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_revoke_snapshot_access(
         &self,
-        input: RotateEncryptionKeyMessage,
-    ) -> Result<RotateEncryptionKeyResult, RusotoError<RotateEncryptionKeyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RevokeSnapshotAccessMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_revoke_snapshot_access_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_revoke_snapshot_access_request(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RevokeSnapshotAccess");
         let mut params = Params::new();
 
-        params.put("Action", "RotateEncryptionKey");
+        params.put("Action", "RevokeSnapshotAccess");
         params.put("Version", "2012-12-01");
-        RotateEncryptionKeyMessageSerializer::serialize(&mut params, "", &input);
+        RevokeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `rotate_encryption_key`, also returning the metadata of the response.
+    pub async fn rotate_encryption_key_with_metadata(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> Result<(RotateEncryptionKeyResult, ResponseMetadata), RusotoError<RotateEncryptionKeyError>>
+    {
+        let request = self.build_rotate_encryption_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RotateEncryptionKeyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -22270,7 +25529,2072 @@ impl Redshift for RedshiftClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `rotate_encryption_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_rotate_encryption_key(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: RotateEncryptionKeyMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_rotate_encryption_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_rotate_encryption_key_request(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RotateEncryptionKey");
+        let mut params = Params::new();
+
+        params.put("Action", "RotateEncryptionKey");
+        params.put("Version", "2012-12-01");
+        RotateEncryptionKeyMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeClusterDbRevisions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cluster_db_revisions_pages(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterDbRevisionsMessage, RusotoError<DescribeClusterDbRevisionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_cluster_db_revisions(input).await
+            },
+            |input: &DescribeClusterDbRevisionsMessage, output: &ClusterDbRevisionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeClusterDbRevisionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ClusterDbRevisions` of every page of `DescribeClusterDbRevisions` results for `input`, as `describe_cluster_db_revisions_pages` requests them.
+    pub fn describe_cluster_db_revisions_stream(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterDbRevision, RusotoError<DescribeClusterDbRevisionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_cluster_db_revisions_pages(input), |page| {
+            page.cluster_db_revisions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeClusterParameterGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cluster_parameter_groups_pages(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ClusterParameterGroupsMessage,
+            RusotoError<DescribeClusterParameterGroupsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_cluster_parameter_groups(input).await
+            },
+            |input: &DescribeClusterParameterGroupsMessage,
+             output: &ClusterParameterGroupsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeClusterParameterGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ParameterGroups` of every page of `DescribeClusterParameterGroups` results for `input`, as `describe_cluster_parameter_groups_pages` requests them.
+    pub fn describe_cluster_parameter_groups_stream(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterParameterGroup, RusotoError<DescribeClusterParameterGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_cluster_parameter_groups_pages(input),
+            |page| page.parameter_groups.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeClusterParameters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cluster_parameters_pages(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterParameterGroupDetails, RusotoError<DescribeClusterParametersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_cluster_parameters(input).await
+            },
+            |input: &DescribeClusterParametersMessage, output: &ClusterParameterGroupDetails| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeClusterParametersMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `DescribeClusterParameters` results for `input`, as `describe_cluster_parameters_pages` requests them.
+    pub fn describe_cluster_parameters_stream(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> impl futures::Stream<Item = Result<Parameter, RusotoError<DescribeClusterParametersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_cluster_parameters_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeClusterSecurityGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cluster_security_groups_pages(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterSecurityGroupMessage, RusotoError<DescribeClusterSecurityGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_cluster_security_groups(input).await
+            },
+            |input: &DescribeClusterSecurityGroupsMessage, output: &ClusterSecurityGroupMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeClusterSecurityGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ClusterSecurityGroups` of every page of `DescribeClusterSecurityGroups` results for `input`, as `describe_cluster_security_groups_pages` requests them.
+    pub fn describe_cluster_security_groups_stream(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterSecurityGroup, RusotoError<DescribeClusterSecurityGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_cluster_security_groups_pages(input), |page| {
+            page.cluster_security_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeClusterSnapshots` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cluster_snapshots_pages(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_cluster_snapshots(input).await
+            },
+            |input: &DescribeClusterSnapshotsMessage, output: &SnapshotMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeClusterSnapshotsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Snapshots` of every page of `DescribeClusterSnapshots` results for `input`, as `describe_cluster_snapshots_pages` requests them.
+    pub fn describe_cluster_snapshots_stream(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> impl futures::Stream<Item = Result<Snapshot, RusotoError<DescribeClusterSnapshotsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_cluster_snapshots_pages(input), |page| {
+            page.snapshots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeClusterSubnetGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cluster_subnet_groups_pages(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterSubnetGroupMessage, RusotoError<DescribeClusterSubnetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_cluster_subnet_groups(input).await
+            },
+            |input: &DescribeClusterSubnetGroupsMessage, output: &ClusterSubnetGroupMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeClusterSubnetGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ClusterSubnetGroups` of every page of `DescribeClusterSubnetGroups` results for `input`, as `describe_cluster_subnet_groups_pages` requests them.
+    pub fn describe_cluster_subnet_groups_stream(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterSubnetGroup, RusotoError<DescribeClusterSubnetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_cluster_subnet_groups_pages(input), |page| {
+            page.cluster_subnet_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeClusterTracks` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cluster_tracks_pages(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> impl futures::Stream<Item = Result<TrackListMessage, RusotoError<DescribeClusterTracksError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move { client.describe_cluster_tracks(input).await },
+            |input: &DescribeClusterTracksMessage, output: &TrackListMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeClusterTracksMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `MaintenanceTracks` of every page of `DescribeClusterTracks` results for `input`, as `describe_cluster_tracks_pages` requests them.
+    pub fn describe_cluster_tracks_stream(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> impl futures::Stream<Item = Result<MaintenanceTrack, RusotoError<DescribeClusterTracksError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_cluster_tracks_pages(input), |page| {
+            page.maintenance_tracks.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeClusterVersions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cluster_versions_pages(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ClusterVersionsMessage, RusotoError<DescribeClusterVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move { client.describe_cluster_versions(input).await },
+            |input: &DescribeClusterVersionsMessage, output: &ClusterVersionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeClusterVersionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ClusterVersions` of every page of `DescribeClusterVersions` results for `input`, as `describe_cluster_versions_pages` requests them.
+    pub fn describe_cluster_versions_stream(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> impl futures::Stream<Item = Result<ClusterVersion, RusotoError<DescribeClusterVersionsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_cluster_versions_pages(input), |page| {
+            page.cluster_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeClusters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_clusters_pages(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> impl futures::Stream<Item = Result<ClustersMessage, RusotoError<DescribeClustersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move { client.describe_clusters(input).await },
+            |input: &DescribeClustersMessage, output: &ClustersMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeClustersMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Clusters` of every page of `DescribeClusters` results for `input`, as `describe_clusters_pages` requests them.
+    pub fn describe_clusters_stream(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> impl futures::Stream<Item = Result<Cluster, RusotoError<DescribeClustersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_clusters_pages(input), |page| {
+            page.clusters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEventSubscriptions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_event_subscriptions_pages(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_event_subscriptions(input).await
+            },
+            |input: &DescribeEventSubscriptionsMessage, output: &EventSubscriptionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEventSubscriptionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EventSubscriptionsList` of every page of `DescribeEventSubscriptions` results for `input`, as `describe_event_subscriptions_pages` requests them.
+    pub fn describe_event_subscriptions_stream(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<EventSubscription, RusotoError<DescribeEventSubscriptionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_event_subscriptions_pages(input), |page| {
+            page.event_subscriptions_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEvents` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<EventsMessage, RusotoError<DescribeEventsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move { client.describe_events(input).await },
+            |input: &DescribeEventsMessage, output: &EventsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEventsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Events` of every page of `DescribeEvents` results for `input`, as `describe_events_pages` requests them.
+    pub fn describe_events_stream(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<Event, RusotoError<DescribeEventsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeHsmClientCertificates` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_hsm_client_certificates_pages(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<HsmClientCertificateMessage, RusotoError<DescribeHsmClientCertificatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_hsm_client_certificates(input).await
+            },
+            |input: &DescribeHsmClientCertificatesMessage, output: &HsmClientCertificateMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeHsmClientCertificatesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HsmClientCertificates` of every page of `DescribeHsmClientCertificates` results for `input`, as `describe_hsm_client_certificates_pages` requests them.
+    pub fn describe_hsm_client_certificates_stream(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<HsmClientCertificate, RusotoError<DescribeHsmClientCertificatesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_hsm_client_certificates_pages(input), |page| {
+            page.hsm_client_certificates.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeHsmConfigurations` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_hsm_configurations_pages(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<HsmConfigurationMessage, RusotoError<DescribeHsmConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_hsm_configurations(input).await
+            },
+            |input: &DescribeHsmConfigurationsMessage, output: &HsmConfigurationMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeHsmConfigurationsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HsmConfigurations` of every page of `DescribeHsmConfigurations` results for `input`, as `describe_hsm_configurations_pages` requests them.
+    pub fn describe_hsm_configurations_stream(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<HsmConfiguration, RusotoError<DescribeHsmConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_hsm_configurations_pages(input), |page| {
+            page.hsm_configurations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeNodeConfigurationOptions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_node_configuration_options_pages(
+        &self,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            NodeConfigurationOptionsMessage,
+            RusotoError<DescribeNodeConfigurationOptionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_node_configuration_options(input).await
+            },
+            |input: &DescribeNodeConfigurationOptionsMessage,
+             output: &NodeConfigurationOptionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeNodeConfigurationOptionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `NodeConfigurationOptionList` of every page of `DescribeNodeConfigurationOptions` results for `input`, as `describe_node_configuration_options_pages` requests them.
+    pub fn describe_node_configuration_options_stream(
+        &self,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<NodeConfigurationOption, RusotoError<DescribeNodeConfigurationOptionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_node_configuration_options_pages(input),
+            |page| page.node_configuration_option_list.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeOrderableClusterOptions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_orderable_cluster_options_pages(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrderableClusterOptionsMessage,
+            RusotoError<DescribeOrderableClusterOptionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_orderable_cluster_options(input).await
+            },
+            |input: &DescribeOrderableClusterOptionsMessage,
+             output: &OrderableClusterOptionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeOrderableClusterOptionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrderableClusterOptions` of every page of `DescribeOrderableClusterOptions` results for `input`, as `describe_orderable_cluster_options_pages` requests them.
+    pub fn describe_orderable_cluster_options_stream(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<OrderableClusterOption, RusotoError<DescribeOrderableClusterOptionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_orderable_cluster_options_pages(input),
+            |page| page.orderable_cluster_options.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeReservedNodeOfferings` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_reserved_node_offerings_pages(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ReservedNodeOfferingsMessage,
+            RusotoError<DescribeReservedNodeOfferingsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_reserved_node_offerings(input).await
+            },
+            |input: &DescribeReservedNodeOfferingsMessage,
+             output: &ReservedNodeOfferingsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeReservedNodeOfferingsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReservedNodeOfferings` of every page of `DescribeReservedNodeOfferings` results for `input`, as `describe_reserved_node_offerings_pages` requests them.
+    pub fn describe_reserved_node_offerings_stream(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReservedNodeOffering, RusotoError<DescribeReservedNodeOfferingsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_reserved_node_offerings_pages(input), |page| {
+            page.reserved_node_offerings.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeReservedNodes` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_reserved_nodes_pages(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReservedNodesMessage, RusotoError<DescribeReservedNodesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move { client.describe_reserved_nodes(input).await },
+            |input: &DescribeReservedNodesMessage, output: &ReservedNodesMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeReservedNodesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReservedNodes` of every page of `DescribeReservedNodes` results for `input`, as `describe_reserved_nodes_pages` requests them.
+    pub fn describe_reserved_nodes_stream(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> impl futures::Stream<Item = Result<ReservedNode, RusotoError<DescribeReservedNodesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_reserved_nodes_pages(input), |page| {
+            page.reserved_nodes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeScheduledActions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_scheduled_actions_pages(
+        &self,
+        input: DescribeScheduledActionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ScheduledActionsMessage, RusotoError<DescribeScheduledActionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_scheduled_actions(input).await
+            },
+            |input: &DescribeScheduledActionsMessage, output: &ScheduledActionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeScheduledActionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScheduledActions` of every page of `DescribeScheduledActions` results for `input`, as `describe_scheduled_actions_pages` requests them.
+    pub fn describe_scheduled_actions_stream(
+        &self,
+        input: DescribeScheduledActionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ScheduledAction, RusotoError<DescribeScheduledActionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_scheduled_actions_pages(input), |page| {
+            page.scheduled_actions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSnapshotCopyGrants` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_snapshot_copy_grants_pages(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<SnapshotCopyGrantMessage, RusotoError<DescribeSnapshotCopyGrantsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_snapshot_copy_grants(input).await
+            },
+            |input: &DescribeSnapshotCopyGrantsMessage, output: &SnapshotCopyGrantMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeSnapshotCopyGrantsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SnapshotCopyGrants` of every page of `DescribeSnapshotCopyGrants` results for `input`, as `describe_snapshot_copy_grants_pages` requests them.
+    pub fn describe_snapshot_copy_grants_stream(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<SnapshotCopyGrant, RusotoError<DescribeSnapshotCopyGrantsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_snapshot_copy_grants_pages(input), |page| {
+            page.snapshot_copy_grants.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSnapshotSchedules` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_snapshot_schedules_pages(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeSnapshotSchedulesOutputMessage,
+            RusotoError<DescribeSnapshotSchedulesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_snapshot_schedules(input).await
+            },
+            |input: &DescribeSnapshotSchedulesMessage,
+             output: &DescribeSnapshotSchedulesOutputMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeSnapshotSchedulesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SnapshotSchedules` of every page of `DescribeSnapshotSchedules` results for `input`, as `describe_snapshot_schedules_pages` requests them.
+    pub fn describe_snapshot_schedules_stream(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<SnapshotSchedule, RusotoError<DescribeSnapshotSchedulesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_snapshot_schedules_pages(input), |page| {
+            page.snapshot_schedules.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTableRestoreStatus` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_table_restore_status_pages(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> impl futures::Stream<
+        Item = Result<TableRestoreStatusMessage, RusotoError<DescribeTableRestoreStatusError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.describe_table_restore_status(input).await
+            },
+            |input: &DescribeTableRestoreStatusMessage, output: &TableRestoreStatusMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeTableRestoreStatusMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TableRestoreStatusDetails` of every page of `DescribeTableRestoreStatus` results for `input`, as `describe_table_restore_status_pages` requests them.
+    pub fn describe_table_restore_status_stream(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> impl futures::Stream<
+        Item = Result<TableRestoreStatus, RusotoError<DescribeTableRestoreStatusError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_table_restore_status_pages(input), |page| {
+            page.table_restore_status_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTags` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_tags_pages(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> impl futures::Stream<Item = Result<TaggedResourceListMessage, RusotoError<DescribeTagsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move { client.describe_tags(input).await },
+            |input: &DescribeTagsMessage, output: &TaggedResourceListMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeTagsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TaggedResources` of every page of `DescribeTags` results for `input`, as `describe_tags_pages` requests them.
+    pub fn describe_tags_stream(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> impl futures::Stream<Item = Result<TaggedResource, RusotoError<DescribeTagsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_tags_pages(input), |page| {
+            page.tagged_resources.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetReservedNodeExchangeOfferings` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn get_reserved_node_exchange_offerings_pages(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetReservedNodeExchangeOfferingsOutputMessage,
+            RusotoError<GetReservedNodeExchangeOfferingsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RedshiftClient, input| async move {
+                client.get_reserved_node_exchange_offerings(input).await
+            },
+            |input: &GetReservedNodeExchangeOfferingsInputMessage,
+             output: &GetReservedNodeExchangeOfferingsOutputMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(GetReservedNodeExchangeOfferingsInputMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReservedNodeOfferings` of every page of `GetReservedNodeExchangeOfferings` results for `input`, as `get_reserved_node_exchange_offerings_pages` requests them.
+    pub fn get_reserved_node_exchange_offerings_stream(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReservedNodeOffering, RusotoError<GetReservedNodeExchangeOfferingsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_reserved_node_exchange_offerings_pages(input),
+            |page| page.reserved_node_offerings.unwrap_or_default(),
+        )
+    }
+
+    /// Waits for `ClusterAvailable`, polling `DescribeClusters` with `input` every 60 seconds, up to 30 times.
+    pub async fn wait_until_cluster_available(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(60), 30);
+        self.wait_until_cluster_available_with(input, &waiter).await
+    }
+
+    /// Waits for `ClusterAvailable` like `wait_until_cluster_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_cluster_available_with(
+        &self,
+        input: DescribeClustersMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_clusters(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.clusters.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.cluster_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "available")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cluster_status.as_ref())
+                        .any(|value| value == "deleting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ClusterNotFound")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ClusterDeleted`, polling `DescribeClusters` with `input` every 60 seconds, up to 30 times.
+    pub async fn wait_until_cluster_deleted(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(60), 30);
+        self.wait_until_cluster_deleted_with(input, &waiter).await
+    }
+
+    /// Waits for `ClusterDeleted` like `wait_until_cluster_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_cluster_deleted_with(
+        &self,
+        input: DescribeClustersMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_clusters(input.clone()),
+            |result| {
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ClusterNotFound")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cluster_status.as_ref())
+                        .any(|value| value == "creating"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cluster_status.as_ref())
+                        .any(|value| value == "modifying"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ClusterRestored`, polling `DescribeClusters` with `input` every 60 seconds, up to 30 times.
+    pub async fn wait_until_cluster_restored(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(60), 30);
+        self.wait_until_cluster_restored_with(input, &waiter).await
+    }
+
+    /// Waits for `ClusterRestored` like `wait_until_cluster_restored`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_cluster_restored_with(
+        &self,
+        input: DescribeClustersMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClustersError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_clusters(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.clusters.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.restore_status.as_ref())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "completed")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cluster_status.as_ref())
+                        .any(|value| value == "deleting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `SnapshotAvailable`, polling `DescribeClusterSnapshots` with `input` every 15 seconds, up to 20 times.
+    pub async fn wait_until_snapshot_available(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterSnapshotsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 20);
+        self.wait_until_snapshot_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `SnapshotAvailable` like `wait_until_snapshot_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_snapshot_available_with(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterSnapshotsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_cluster_snapshots(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.snapshots.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "available")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.snapshots.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.snapshots.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "deleted"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Redshift for RedshiftClient {
+    /// <p>Exchanges a DC1 Reserved Node for a DC2 Reserved Node with no changes to the configuration (term, payment type, or number of nodes) and no additional costs. </p>
+    async fn accept_reserved_node_exchange(
+        &self,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> Result<AcceptReservedNodeExchangeOutputMessage, RusotoError<AcceptReservedNodeExchangeError>>
+    {
+        self.accept_reserved_node_exchange_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an inbound (ingress) rule to an Amazon Redshift security group. Depending on whether the application accessing your cluster is running on the Internet or an Amazon EC2 instance, you can authorize inbound access to either a Classless Interdomain Routing (CIDR)/Internet Protocol (IP) range or to an Amazon EC2 security group. You can add as many as 20 ingress rules to an Amazon Redshift security group.</p> <p>If you authorize access to an Amazon EC2 security group, specify <i>EC2SecurityGroupName</i> and <i>EC2SecurityGroupOwnerId</i>. The Amazon EC2 security group and Amazon Redshift cluster must be in the same AWS Region. </p> <p>If you authorize access to a CIDR/IP address range, specify <i>CIDRIP</i>. For an overview of CIDR blocks, see the Wikipedia article on <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>. </p> <p>You must also associate the security group with a cluster so that clients running on these IP addresses or the EC2 instance are authorized to connect to the cluster. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Working with Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn authorize_cluster_security_group_ingress(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> Result<
+        AuthorizeClusterSecurityGroupIngressResult,
+        RusotoError<AuthorizeClusterSecurityGroupIngressError>,
+    > {
+        self.authorize_cluster_security_group_ingress_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Authorizes the specified AWS customer account to restore the specified snapshot.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn authorize_snapshot_access(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> Result<AuthorizeSnapshotAccessResult, RusotoError<AuthorizeSnapshotAccessError>> {
+        self.authorize_snapshot_access_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a set of cluster snapshots.</p>
+    async fn batch_delete_cluster_snapshots(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> Result<BatchDeleteClusterSnapshotsResult, RusotoError<BatchDeleteClusterSnapshotsError>>
+    {
+        self.batch_delete_cluster_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the settings for a set of cluster snapshots.</p>
+    async fn batch_modify_cluster_snapshots(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> Result<
+        BatchModifyClusterSnapshotsOutputMessage,
+        RusotoError<BatchModifyClusterSnapshotsError>,
+    > {
+        self.batch_modify_cluster_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels a resize operation for a cluster.</p>
+    async fn cancel_resize(
+        &self,
+        input: CancelResizeMessage,
+    ) -> Result<ResizeProgressMessage, RusotoError<CancelResizeError>> {
+        self.cancel_resize_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Copies the specified automated cluster snapshot to a new manual cluster snapshot. The source must be an automated snapshot and it must be in the available state.</p> <p>When you delete a cluster, Amazon Redshift deletes any automated snapshots of the cluster. Also, when the retention period of the snapshot expires, Amazon Redshift automatically deletes it. If you want to keep an automated snapshot for a longer period, you can make a manual copy of the snapshot. Manual snapshots are retained until you delete them.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn copy_cluster_snapshot(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> Result<CopyClusterSnapshotResult, RusotoError<CopyClusterSnapshotError>> {
+        self.copy_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new cluster with the specified parameters.</p> <p>To create a cluster in Virtual Private Cloud (VPC), you must provide a cluster subnet group name. The cluster subnet group identifies the subnets of your VPC that Amazon Redshift uses when creating the cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn create_cluster(
+        &self,
+        input: CreateClusterMessage,
+    ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
+        self.create_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon Redshift parameter group.</p> <p>Creating parameter groups is independent of creating clusters. You can associate a cluster with a parameter group when you create the cluster. You can also associate an existing cluster with a parameter group after the cluster is created by using <a>ModifyCluster</a>. </p> <p>Parameters in the parameter group define specific behavior that applies to the databases you create on the cluster. For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn create_cluster_parameter_group(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> Result<CreateClusterParameterGroupResult, RusotoError<CreateClusterParameterGroupError>>
+    {
+        self.create_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Amazon Redshift security group. You use security groups to control access to non-VPC clusters.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn create_cluster_security_group(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> Result<CreateClusterSecurityGroupResult, RusotoError<CreateClusterSecurityGroupError>>
+    {
+        self.create_cluster_security_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a manual snapshot of the specified cluster. The cluster must be in the <code>available</code> state. </p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn create_cluster_snapshot(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> Result<CreateClusterSnapshotResult, RusotoError<CreateClusterSnapshotError>> {
+        self.create_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Amazon Redshift subnet group. You must provide a list of one or more subnets in your existing Amazon Virtual Private Cloud (Amazon VPC) when creating Amazon Redshift subnet group.</p> <p> For information about subnet groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-cluster-subnet-groups.html">Amazon Redshift Cluster Subnet Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn create_cluster_subnet_group(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> Result<CreateClusterSubnetGroupResult, RusotoError<CreateClusterSubnetGroupError>> {
+        self.create_cluster_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon Redshift event notification subscription. This action requires an ARN (Amazon Resource Name) of an Amazon SNS topic created by either the Amazon Redshift console, the Amazon SNS console, or the Amazon SNS API. To obtain an ARN with Amazon SNS, you must create a topic in Amazon SNS and subscribe to the topic. The ARN is displayed in the SNS console.</p> <p>You can specify the source type, and lists of Amazon Redshift source IDs, event categories, and event severities. Notifications will be sent for all events you want that match those criteria. For example, you can specify source type = cluster, source ID = my-cluster-1 and mycluster2, event categories = Availability, Backup, and severity = ERROR. The subscription will only send notifications for those ERROR events in the Availability and Backup categories for the specified clusters.</p> <p>If you specify both the source type and source IDs, such as source type = cluster and source identifier = my-cluster-1, notifications will be sent for all the cluster events for my-cluster-1. If you specify a source type but do not specify a source identifier, you will receive notice of the events for the objects of that type in your AWS account. If you do not specify either the SourceType nor the SourceIdentifier, you will be notified of events generated from all Amazon Redshift sources belonging to your AWS account. You must specify a source type if you specify a source ID.</p>
+    async fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        self.create_event_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an HSM client certificate that an Amazon Redshift cluster will use to connect to the client's HSM in order to store and retrieve the keys used to encrypt the cluster databases.</p> <p>The command returns a public key, which you must store in the HSM. In addition to creating the HSM certificate, you must create an Amazon Redshift HSM configuration that provides a cluster the information needed to store and use encryption keys in the HSM. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
+    async fn create_hsm_client_certificate(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> Result<CreateHsmClientCertificateResult, RusotoError<CreateHsmClientCertificateError>>
+    {
+        self.create_hsm_client_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an HSM configuration that contains the information required by an Amazon Redshift cluster to store and use database encryption keys in a Hardware Security Module (HSM). After creating the HSM configuration, you can specify it as a parameter when creating a cluster. The cluster will then store its encryption keys in the HSM.</p> <p>In addition to creating an HSM configuration, you must also create an HSM client certificate. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
+    async fn create_hsm_configuration(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> Result<CreateHsmConfigurationResult, RusotoError<CreateHsmConfigurationError>> {
+        self.create_hsm_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a scheduled action. A scheduled action contains a schedule and an Amazon Redshift API action. For example, you can create a schedule of when to run the <code>ResizeCluster</code> API operation. </p>
+    async fn create_scheduled_action(
+        &self,
+        input: CreateScheduledActionMessage,
+    ) -> Result<ScheduledAction, RusotoError<CreateScheduledActionError>> {
+        self.create_scheduled_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a snapshot copy grant that permits Amazon Redshift to use a customer master key (CMK) from AWS Key Management Service (AWS KMS) to encrypt copied snapshots in a destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    async fn create_snapshot_copy_grant(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> Result<CreateSnapshotCopyGrantResult, RusotoError<CreateSnapshotCopyGrantError>> {
+        self.create_snapshot_copy_grant_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a snapshot schedule with the rate of every 12 hours.</p>
+    async fn create_snapshot_schedule(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> Result<SnapshotSchedule, RusotoError<CreateSnapshotScheduleError>> {
+        self.create_snapshot_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tags to a cluster.</p> <p>A resource can have up to 50 tags. If you try to create more than 50 tags for a resource, you will receive an error and the attempt will fail.</p> <p>If you specify a key that already exists for the resource, the value for that key will be updated with the new value.</p>
+    async fn create_tags(
+        &self,
+        input: CreateTagsMessage,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.create_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a previously provisioned cluster without its final snapshot being created. A successful response from the web service indicates that the request was received correctly. Use <a>DescribeClusters</a> to monitor the status of the deletion. The delete operation cannot be canceled or reverted once submitted. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you want to shut down the cluster and retain it for future use, set <i>SkipFinalClusterSnapshot</i> to <code>false</code> and specify a name for <i>FinalClusterSnapshotIdentifier</i>. You can later restore this snapshot to resume using the cluster. If a final cluster snapshot is requested, the status of the cluster will be "final-snapshot" while the snapshot is being taken, then it's "deleting" once Amazon Redshift begins deleting the cluster. </p> <p> For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn delete_cluster(
+        &self,
+        input: DeleteClusterMessage,
+    ) -> Result<DeleteClusterResult, RusotoError<DeleteClusterError>> {
+        self.delete_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a specified Amazon Redshift parameter group.</p> <note> <p>You cannot delete a parameter group if it is associated with a cluster.</p> </note></p>
+    async fn delete_cluster_parameter_group(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteClusterParameterGroupError>> {
+        self.delete_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Amazon Redshift security group.</p> <note> <p>You cannot delete a security group that is associated with any clusters. You cannot delete the default security group.</p> </note> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn delete_cluster_security_group(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> Result<(), RusotoError<DeleteClusterSecurityGroupError>> {
+        self.delete_cluster_security_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified manual snapshot. The snapshot must be in the <code>available</code> state, with no other users authorized to access the snapshot. </p> <p>Unlike automated snapshots, manual snapshots are retained even after you delete your cluster. Amazon Redshift does not delete your manual snapshots. You must delete manual snapshot explicitly to avoid getting charged. If other accounts are authorized to access the snapshot, you must revoke all of the authorizations before you can delete the snapshot.</p>
+    async fn delete_cluster_snapshot(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> Result<DeleteClusterSnapshotResult, RusotoError<DeleteClusterSnapshotError>> {
+        self.delete_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified cluster subnet group.</p>
+    async fn delete_cluster_subnet_group(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteClusterSubnetGroupError>> {
+        self.delete_cluster_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Amazon Redshift event notification subscription.</p>
+    async fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<(), RusotoError<DeleteEventSubscriptionError>> {
+        self.delete_event_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified HSM client certificate.</p>
+    async fn delete_hsm_client_certificate(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> Result<(), RusotoError<DeleteHsmClientCertificateError>> {
+        self.delete_hsm_client_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Amazon Redshift HSM configuration.</p>
+    async fn delete_hsm_configuration(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> Result<(), RusotoError<DeleteHsmConfigurationError>> {
+        self.delete_hsm_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a scheduled action. </p>
+    async fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionMessage,
+    ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
+        self.delete_scheduled_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified snapshot copy grant.</p>
+    async fn delete_snapshot_copy_grant(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> Result<(), RusotoError<DeleteSnapshotCopyGrantError>> {
+        self.delete_snapshot_copy_grant_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a snapshot schedule.</p>
+    async fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> Result<(), RusotoError<DeleteSnapshotScheduleError>> {
+        self.delete_snapshot_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes tags from a resource. You must provide the ARN of the resource from which you want to delete the tag or tags.</p>
+    async fn delete_tags(
+        &self,
+        input: DeleteTagsMessage,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.delete_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of attributes attached to an account</p>
+    async fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> Result<AccountAttributeList, RusotoError<DescribeAccountAttributesError>> {
+        self.describe_account_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <code>ClusterDbRevision</code> objects.</p>
+    async fn describe_cluster_db_revisions(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> Result<ClusterDbRevisionsMessage, RusotoError<DescribeClusterDbRevisionsError>> {
+        self.describe_cluster_db_revisions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of Amazon Redshift parameter groups, including parameter groups you created and the default parameter group. For each parameter group, the response includes the parameter group name, description, and parameter group family name. You can optionally specify a name to retrieve the description of a specific parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all parameter groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all parameter groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, parameter groups are returned regardless of whether they have tag keys or values associated with them.</p>
+    async fn describe_cluster_parameter_groups(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> Result<ClusterParameterGroupsMessage, RusotoError<DescribeClusterParameterGroupsError>>
+    {
+        self.describe_cluster_parameter_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a detailed list of parameters contained within the specified Amazon Redshift parameter group. For each parameter the response includes information such as parameter name, description, data type, value, whether the parameter value is modifiable, and so on.</p> <p>You can specify <i>source</i> filter to retrieve parameters of only specific type. For example, to retrieve parameters that were modified by a user action such as from <a>ModifyClusterParameterGroup</a>, you can specify <i>source</i> equal to <i>user</i>.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn describe_cluster_parameters(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> Result<ClusterParameterGroupDetails, RusotoError<DescribeClusterParametersError>> {
+        self.describe_cluster_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about Amazon Redshift security groups. If the name of a security group is specified, the response will contain only information about only that security group.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all security groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all security groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, security groups are returned regardless of whether they have tag keys or values associated with them.</p>
+    async fn describe_cluster_security_groups(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> Result<ClusterSecurityGroupMessage, RusotoError<DescribeClusterSecurityGroupsError>> {
+        self.describe_cluster_security_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns one or more snapshot objects, which contain metadata about your cluster snapshots. By default, this operation returns information about all snapshots of all clusters that are owned by you AWS customer account. No information is returned for snapshots owned by inactive AWS customer accounts.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all snapshots that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all snapshots that have any combination of those values are returned. Only snapshots that you own are returned in the response; shared snapshots are not returned with the tag key and tag value request parameters.</p> <p>If both tag keys and values are omitted from the request, snapshots are returned regardless of whether they have tag keys or values associated with them.</p>
+    async fn describe_cluster_snapshots(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>> {
+        self.describe_cluster_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns one or more cluster subnet group objects, which contain metadata about your cluster subnet groups. By default, this operation returns information about all cluster subnet groups that are defined in you AWS account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all subnet groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subnet groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subnet groups are returned regardless of whether they have tag keys or values associated with them.</p>
+    async fn describe_cluster_subnet_groups(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> Result<ClusterSubnetGroupMessage, RusotoError<DescribeClusterSubnetGroupsError>> {
+        self.describe_cluster_subnet_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all the available maintenance tracks.</p>
+    async fn describe_cluster_tracks(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> Result<TrackListMessage, RusotoError<DescribeClusterTracksError>> {
+        self.describe_cluster_tracks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns descriptions of the available Amazon Redshift cluster versions. You can call this operation even before creating any clusters to learn more about the Amazon Redshift versions. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn describe_cluster_versions(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> Result<ClusterVersionsMessage, RusotoError<DescribeClusterVersionsError>> {
+        self.describe_cluster_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns properties of provisioned clusters including general cluster properties, cluster database properties, maintenance and backup properties, and security and access properties. This operation supports pagination. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all clusters that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all clusters that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, clusters are returned regardless of whether they have tag keys or values associated with them.</p>
+    async fn describe_clusters(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<ClustersMessage, RusotoError<DescribeClustersError>> {
+        self.describe_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of parameter settings for the specified parameter group family.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn describe_default_cluster_parameters(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeDefaultClusterParametersResult,
+        RusotoError<DescribeDefaultClusterParametersError>,
+    > {
+        self.describe_default_cluster_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays a list of event categories for all event source types, or for a specified source type. For a list of the event categories and source types, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-event-notifications.html">Amazon Redshift Event Notifications</a>.</p>
+    async fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.describe_event_categories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists descriptions of all the Amazon Redshift event notification subscriptions for a customer account. If you specify a subscription name, lists the description for that subscription.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all event notification subscriptions that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subscriptions that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subscriptions are returned regardless of whether they have tag keys or values associated with them.</p>
+    async fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        self.describe_event_subscriptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns events related to clusters, security groups, snapshots, and parameter groups for the past 14 days. Events specific to a particular cluster, security group, snapshot or parameter group can be obtained by providing the name as a parameter. By default, the past hour of events are returned.</p>
+    async fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.describe_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified HSM client certificate. If no certificate ID is specified, returns information about all the HSM certificates owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM client certificates that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM client certificates that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM client certificates are returned regardless of whether they have tag keys or values associated with them.</p>
+    async fn describe_hsm_client_certificates(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> Result<HsmClientCertificateMessage, RusotoError<DescribeHsmClientCertificatesError>> {
+        self.describe_hsm_client_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified Amazon Redshift HSM configuration. If no configuration ID is specified, returns information about all the HSM configurations owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM connections that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM connections that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM connections are returned regardless of whether they have tag keys or values associated with them.</p>
+    async fn describe_hsm_configurations(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> Result<HsmConfigurationMessage, RusotoError<DescribeHsmConfigurationsError>> {
+        self.describe_hsm_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes whether information, such as queries and connection attempts, is being logged for the specified Amazon Redshift cluster.</p>
+    async fn describe_logging_status(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> Result<LoggingStatus, RusotoError<DescribeLoggingStatusError>> {
+        self.describe_logging_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns properties of possible node configurations such as node type, number of nodes, and disk usage for the specified action type.</p>
+    async fn describe_node_configuration_options(
+        &self,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> Result<NodeConfigurationOptionsMessage, RusotoError<DescribeNodeConfigurationOptionsError>>
+    {
+        self.describe_node_configuration_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of orderable cluster options. Before you create a new cluster you can use this operation to find what options are available, such as the EC2 Availability Zones (AZ) in the specific AWS Region that you can specify, and the node types you can request. The node types differ by available storage, memory, CPU and price. With the cost involved you might want to obtain a list of cluster options in the specific region and specify values when creating a cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn describe_orderable_cluster_options(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> Result<OrderableClusterOptionsMessage, RusotoError<DescribeOrderableClusterOptionsError>>
+    {
+        self.describe_orderable_cluster_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the available reserved node offerings by Amazon Redshift with their descriptions including the node type, the fixed and recurring costs of reserving the node and duration the node will be reserved for you. These descriptions help you determine which reserve node offering you want to purchase. You then use the unique offering ID in you call to <a>PurchaseReservedNodeOffering</a> to reserve one or more nodes for your Amazon Redshift cluster. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn describe_reserved_node_offerings(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> Result<ReservedNodeOfferingsMessage, RusotoError<DescribeReservedNodeOfferingsError>> {
+        self.describe_reserved_node_offerings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the descriptions of the reserved nodes.</p>
+    async fn describe_reserved_nodes(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> Result<ReservedNodesMessage, RusotoError<DescribeReservedNodesError>> {
+        self.describe_reserved_nodes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the last resize operation for the specified cluster. If no resize operation has ever been initiated for the specified cluster, a <code>HTTP 404</code> error is returned. If a resize operation was initiated and completed, the status of the resize remains as <code>SUCCEEDED</code> until the next resize. </p> <p>A resize operation can be requested using <a>ModifyCluster</a> and specifying a different number or type of nodes for the cluster. </p>
+    async fn describe_resize(
+        &self,
+        input: DescribeResizeMessage,
+    ) -> Result<ResizeProgressMessage, RusotoError<DescribeResizeError>> {
+        self.describe_resize_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes properties of scheduled actions. </p>
+    async fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsMessage,
+    ) -> Result<ScheduledActionsMessage, RusotoError<DescribeScheduledActionsError>> {
+        self.describe_scheduled_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of snapshot copy grants owned by the AWS account in the destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    async fn describe_snapshot_copy_grants(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> Result<SnapshotCopyGrantMessage, RusotoError<DescribeSnapshotCopyGrantsError>> {
+        self.describe_snapshot_copy_grants_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of snapshot schedules. </p>
+    async fn describe_snapshot_schedules(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> Result<DescribeSnapshotSchedulesOutputMessage, RusotoError<DescribeSnapshotSchedulesError>>
+    {
+        self.describe_snapshot_schedules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns account level backups storage size and provisional storage.</p>
+    async fn describe_storage(
+        &self,
+    ) -> Result<CustomerStorageMessage, RusotoError<DescribeStorageError>> {
+        self.describe_storage_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the status of one or more table restore requests made using the <a>RestoreTableFromClusterSnapshot</a> API action. If you don't specify a value for the <code>TableRestoreRequestId</code> parameter, then <code>DescribeTableRestoreStatus</code> returns the status of all table restore requests ordered by the date and time of the request in ascending order. Otherwise <code>DescribeTableRestoreStatus</code> returns the status of the table specified by <code>TableRestoreRequestId</code>.</p>
+    async fn describe_table_restore_status(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> Result<TableRestoreStatusMessage, RusotoError<DescribeTableRestoreStatusError>> {
+        self.describe_table_restore_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of tags. You can return tags from a specific resource by specifying an ARN, or you can return all tags for a given type of resource, such as clusters, snapshots, and so on.</p> <p>The following are limitations for <code>DescribeTags</code>: </p> <ul> <li> <p>You cannot specify an ARN and a resource-type value together in the same request.</p> </li> <li> <p>You cannot use the <code>MaxRecords</code> and <code>Marker</code> parameters together with the ARN parameter.</p> </li> <li> <p>The <code>MaxRecords</code> parameter can be a range from 10 to 50 results to return in a request.</p> </li> </ul> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all resources that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all resources that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, resources are returned regardless of whether they have tag keys or values associated with them.</p>
+    async fn describe_tags(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> Result<TaggedResourceListMessage, RusotoError<DescribeTagsError>> {
+        self.describe_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
+    async fn disable_logging(
+        &self,
+        input: DisableLoggingMessage,
+    ) -> Result<LoggingStatus, RusotoError<DisableLoggingError>> {
+        self.disable_logging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the automatic copying of snapshots from one region to another region for a specified cluster.</p> <p>If your cluster and its snapshots are encrypted using a customer master key (CMK) from AWS KMS, use <a>DeleteSnapshotCopyGrant</a> to delete the grant that grants Amazon Redshift permission to the CMK in the destination region. </p>
+    async fn disable_snapshot_copy(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> Result<DisableSnapshotCopyResult, RusotoError<DisableSnapshotCopyError>> {
+        self.disable_snapshot_copy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
+    async fn enable_logging(
+        &self,
+        input: EnableLoggingMessage,
+    ) -> Result<LoggingStatus, RusotoError<EnableLoggingError>> {
+        self.enable_logging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the automatic copy of snapshots from one region to another region for a specified cluster.</p>
+    async fn enable_snapshot_copy(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> Result<EnableSnapshotCopyResult, RusotoError<EnableSnapshotCopyError>> {
+        self.enable_snapshot_copy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a database user name and temporary password with temporary authorization to log on to an Amazon Redshift database. The action returns the database user name prefixed with <code>IAM:</code> if <code>AutoCreate</code> is <code>False</code> or <code>IAMA:</code> if <code>AutoCreate</code> is <code>True</code>. You can optionally specify one or more database user groups that the user will join at log on. By default, the temporary credentials expire in 900 seconds. You can optionally specify a duration between 900 seconds (15 minutes) and 3600 seconds (60 minutes). For more information, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/generating-user-credentials.html">Using IAM Authentication to Generate Database User Credentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>The AWS Identity and Access Management (IAM)user or role that executes GetClusterCredentials must have an IAM policy attached that allows access to all necessary actions and resources. For more information about permissions, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/redshift-iam-access-control-identity-based.html#redshift-policy-resources.getclustercredentials-resources">Resource Policies for GetClusterCredentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>If the <code>DbGroups</code> parameter is specified, the IAM policy must allow the <code>redshift:JoinGroup</code> action with access to the listed <code>dbgroups</code>. </p> <p>In addition, if the <code>AutoCreate</code> parameter is set to <code>True</code>, then the policy must include the <code>redshift:CreateClusterUser</code> privilege.</p> <p>If the <code>DbName</code> parameter is specified, the IAM policy must allow access to the resource <code>dbname</code> for the specified database name. </p>
+    async fn get_cluster_credentials(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> Result<ClusterCredentials, RusotoError<GetClusterCredentialsError>> {
+        self.get_cluster_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of DC2 ReservedNodeOfferings that matches the payment type, term, and usage price of the given DC1 reserved node.</p>
+    async fn get_reserved_node_exchange_offerings(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> Result<
+        GetReservedNodeExchangeOfferingsOutputMessage,
+        RusotoError<GetReservedNodeExchangeOfferingsError>,
+    > {
+        self.get_reserved_node_exchange_offerings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the settings for a cluster.</p> <p>You can also change node type and the number of nodes to scale up or down the cluster. When resizing a cluster, you must specify both the number of nodes and the node type even if one of the parameters does not change.</p> <p>You can add another security or parameter group, or change the master user password. Resetting a cluster password or modifying the security groups associated with a cluster do not need a reboot. However, modifying a parameter group requires a reboot for parameters to take effect. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn modify_cluster(
+        &self,
+        input: ModifyClusterMessage,
+    ) -> Result<ModifyClusterResult, RusotoError<ModifyClusterError>> {
+        self.modify_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the database revision of a cluster. The database revision is a unique revision of the database running in a cluster.</p>
+    async fn modify_cluster_db_revision(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> Result<ModifyClusterDbRevisionResult, RusotoError<ModifyClusterDbRevisionError>> {
+        self.modify_cluster_db_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the list of AWS Identity and Access Management (IAM) roles that can be used by the cluster to access other AWS services.</p> <p>A cluster can have up to 10 IAM roles associated at any time.</p>
+    async fn modify_cluster_iam_roles(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> Result<ModifyClusterIamRolesResult, RusotoError<ModifyClusterIamRolesError>> {
+        self.modify_cluster_iam_roles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the maintenance settings of a cluster.</p>
+    async fn modify_cluster_maintenance(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> Result<ModifyClusterMaintenanceResult, RusotoError<ModifyClusterMaintenanceError>> {
+        self.modify_cluster_maintenance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the parameters of a parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn modify_cluster_parameter_group(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ModifyClusterParameterGroupError>>
+    {
+        self.modify_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the settings for a snapshot.</p> <p>This exanmple modifies the manual retention period setting for a cluster snapshot.</p>
+    async fn modify_cluster_snapshot(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> Result<ModifyClusterSnapshotResult, RusotoError<ModifyClusterSnapshotError>> {
+        self.modify_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies a snapshot schedule for a cluster.</p>
+    async fn modify_cluster_snapshot_schedule(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> Result<(), RusotoError<ModifyClusterSnapshotScheduleError>> {
+        self.modify_cluster_snapshot_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies a cluster subnet group to include the specified list of VPC subnets. The operation replaces the existing list of subnets with the new list of subnets.</p>
+    async fn modify_cluster_subnet_group(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> Result<ModifyClusterSubnetGroupResult, RusotoError<ModifyClusterSubnetGroupError>> {
+        self.modify_cluster_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an existing Amazon Redshift event notification subscription.</p>
+    async fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        self.modify_event_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies a scheduled action. </p>
+    async fn modify_scheduled_action(
+        &self,
+        input: ModifyScheduledActionMessage,
+    ) -> Result<ScheduledAction, RusotoError<ModifyScheduledActionError>> {
+        self.modify_scheduled_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the number of days to retain snapshots in the destination AWS Region after they are copied from the source AWS Region. By default, this operation only changes the retention period of copied automated snapshots. The retention periods for both new and existing copied automated snapshots are updated with the new retention period. You can set the manual option to change only the retention periods of copied manual snapshots. If you set this option, only newly copied manual snapshots have the new retention period. </p>
+    async fn modify_snapshot_copy_retention_period(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> Result<
+        ModifySnapshotCopyRetentionPeriodResult,
+        RusotoError<ModifySnapshotCopyRetentionPeriodError>,
+    > {
+        self.modify_snapshot_copy_retention_period_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies a snapshot schedule. Any schedule associated with a cluster is modified asynchronously.</p>
+    async fn modify_snapshot_schedule(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> Result<SnapshotSchedule, RusotoError<ModifySnapshotScheduleError>> {
+        self.modify_snapshot_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows you to purchase reserved nodes. Amazon Redshift offers a predefined set of reserved node offerings. You can purchase one or more of the offerings. You can call the <a>DescribeReservedNodeOfferings</a> API to obtain the available reserved node offerings. You can call this API by providing a specific reserved node offering and the number of nodes you want to reserve. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn purchase_reserved_node_offering(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> Result<PurchaseReservedNodeOfferingResult, RusotoError<PurchaseReservedNodeOfferingError>>
+    {
+        self.purchase_reserved_node_offering_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Reboots a cluster. This action is taken as soon as possible. It results in a momentary outage to the cluster, during which the cluster status is set to <code>rebooting</code>. A cluster event is created when the reboot is completed. Any pending cluster modifications (see <a>ModifyCluster</a>) are applied at this reboot. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    async fn reboot_cluster(
+        &self,
+        input: RebootClusterMessage,
+    ) -> Result<RebootClusterResult, RusotoError<RebootClusterError>> {
+        self.reboot_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets one or more parameters of the specified parameter group to their default values and sets the source values of the parameters to "engine-default". To reset the entire parameter group specify the <i>ResetAllParameters</i> parameter. For parameter changes to take effect you must reboot any associated clusters. </p>
+    async fn reset_cluster_parameter_group(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ResetClusterParameterGroupError>>
+    {
+        self.reset_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Changes the size of the cluster. You can change the cluster&#39;s type, or change the number or type of nodes. The default behavior is to use the elastic resize method. With an elastic resize, your cluster is available for read and write operations more quickly than with the classic resize method. </p> <p>Elastic resize operations have the following restrictions:</p> <ul> <li> <p>You can only resize clusters of the following types:</p> <ul> <li> <p>dc2.large</p> </li> <li> <p>dc2.8xlarge</p> </li> <li> <p>ds2.xlarge</p> </li> <li> <p>ds2.8xlarge</p> </li> <li> <p>ra3.16xlarge</p> </li> </ul> </li> <li> <p>The type of nodes that you add must match the node type for the cluster.</p> </li> </ul></p>
+    async fn resize_cluster(
+        &self,
+        input: ResizeClusterMessage,
+    ) -> Result<ResizeClusterResult, RusotoError<ResizeClusterError>> {
+        self.resize_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new cluster from a snapshot. By default, Amazon Redshift creates the resulting cluster with the same configuration as the original cluster from which the snapshot was created, except that the new cluster is created with the default cluster security and parameter groups. After Amazon Redshift creates the cluster, you can use the <a>ModifyCluster</a> API to associate a different security group and different parameter group with the restored cluster. If you are using a DS node type, you can also choose to change to another DS node type of the same size during restore.</p> <p>If you restore a cluster into a VPC, you must provide a cluster subnet group where you want the cluster restored.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn restore_from_cluster_snapshot(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> Result<RestoreFromClusterSnapshotResult, RusotoError<RestoreFromClusterSnapshotError>>
+    {
+        self.restore_from_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new table from a table in an Amazon Redshift cluster snapshot. You must create the new table within the Amazon Redshift cluster that the snapshot was taken from.</p> <p>You cannot use <code>RestoreTableFromClusterSnapshot</code> to restore a table with the same name as an existing table in an Amazon Redshift cluster. That is, you cannot overwrite an existing table in a cluster with a restored table. If you want to replace your original table with a new, restored table, then rename or drop your original table before you call <code>RestoreTableFromClusterSnapshot</code>. When you have renamed your original table, then you can pass the original name of the table as the <code>NewTableName</code> parameter value in the call to <code>RestoreTableFromClusterSnapshot</code>. This way, you can replace the original table with the table created from the snapshot.</p>
+    async fn restore_table_from_cluster_snapshot(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> Result<
+        RestoreTableFromClusterSnapshotResult,
+        RusotoError<RestoreTableFromClusterSnapshotError>,
+    > {
+        self.restore_table_from_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Revokes an ingress rule in an Amazon Redshift security group for a previously authorized IP range or Amazon EC2 security group. To add an ingress rule, see <a>AuthorizeClusterSecurityGroupIngress</a>. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    async fn revoke_cluster_security_group_ingress(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> Result<
+        RevokeClusterSecurityGroupIngressResult,
+        RusotoError<RevokeClusterSecurityGroupIngressError>,
+    > {
+        self.revoke_cluster_security_group_ingress_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the ability of the specified AWS customer account to restore the specified snapshot. If the account is currently restoring the snapshot, the restore will run to completion.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    async fn revoke_snapshot_access(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> Result<RevokeSnapshotAccessResult, RusotoError<RevokeSnapshotAccessError>> {
+        self.revoke_snapshot_access_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Rotates the encryption keys for a cluster.</p>
+    async fn rotate_encryption_key(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> Result<RotateEncryptionKeyResult, RusotoError<RotateEncryptionKeyError>> {
+        self.rotate_encryption_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
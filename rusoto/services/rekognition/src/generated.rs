@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2362,6 +2362,7 @@ pub struct VideoMetadata {
 
 /// Errors returned by CompareFaces
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CompareFacesError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -2379,6 +2380,8 @@ pub enum CompareFacesError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CompareFacesError {
@@ -2386,33 +2389,40 @@ impl CompareFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CompareFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(CompareFacesError::AccessDenied(err.msg), &res)
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(CompareFacesError::ImageTooLarge(err.msg))
+                    return RusotoError::service(CompareFacesError::ImageTooLarge(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CompareFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CompareFacesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(CompareFacesError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(
+                        CompareFacesError::InvalidImageFormat(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CompareFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(CompareFacesError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(CompareFacesError::InvalidS3Object(err.msg))
+                    return RusotoError::service(CompareFacesError::InvalidS3Object(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(CompareFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompareFacesError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CompareFacesError::Throttling(err.msg))
+                    return RusotoError::service(CompareFacesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CompareFacesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2430,12 +2440,14 @@ impl fmt::Display for CompareFacesError {
             CompareFacesError::InvalidS3Object(ref cause) => write!(f, "{}", cause),
             CompareFacesError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             CompareFacesError::Throttling(ref cause) => write!(f, "{}", cause),
+            CompareFacesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CompareFacesError {}
 /// Errors returned by CreateCollection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCollectionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -2449,6 +2461,8 @@ pub enum CreateCollectionError {
     ResourceAlreadyExists(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCollectionError {
@@ -2456,31 +2470,37 @@ impl CreateCollectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateCollectionError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateCollectionError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateCollectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCollectionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateCollectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateCollectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCollectionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateCollectionError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCollectionError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateCollectionError::Throttling(err.msg))
+                    return RusotoError::service(CreateCollectionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCollectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2498,12 +2518,14 @@ impl fmt::Display for CreateCollectionError {
             }
             CreateCollectionError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateCollectionError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateCollectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCollectionError {}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProjectError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -2519,6 +2541,8 @@ pub enum CreateProjectError {
     ResourceInUse(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProjectError {
@@ -2526,30 +2550,37 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProjectError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateProjectError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateProjectError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateProjectError::LimitExceeded(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(CreateProjectError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProjectError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateProjectError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateProjectError::ResourceInUse(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProjectError::Throttling(err.msg))
+                    return RusotoError::service(CreateProjectError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2566,12 +2597,14 @@ impl fmt::Display for CreateProjectError {
             CreateProjectError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             CreateProjectError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateProjectError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProjectError {}
 /// Errors returned by CreateProjectVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProjectVersionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -2589,6 +2622,8 @@ pub enum CreateProjectVersionError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProjectVersionError {
@@ -2596,39 +2631,55 @@ impl CreateProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateProjectVersionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateProjectVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProjectVersionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateProjectVersionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProjectVersionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateProjectVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateProjectVersionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateProjectVersionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProjectVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProjectVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateProjectVersionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProjectVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2648,12 +2699,14 @@ impl fmt::Display for CreateProjectVersionError {
             CreateProjectVersionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateProjectVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateProjectVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateProjectVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProjectVersionError {}
 /// Errors returned by CreateStreamProcessor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStreamProcessorError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -2669,6 +2722,8 @@ pub enum CreateStreamProcessorError {
     ResourceInUse(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStreamProcessorError {
@@ -2676,34 +2731,49 @@ impl CreateStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateStreamProcessorError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStreamProcessorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStreamProcessorError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateStreamProcessorError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateStreamProcessorError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateStreamProcessorError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStreamProcessorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2722,12 +2792,14 @@ impl fmt::Display for CreateStreamProcessorError {
             }
             CreateStreamProcessorError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateStreamProcessorError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateStreamProcessorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStreamProcessorError {}
 /// Errors returned by DeleteCollection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCollectionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -2741,6 +2813,8 @@ pub enum DeleteCollectionError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCollectionError {
@@ -2748,29 +2822,37 @@ impl DeleteCollectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteCollectionError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteCollectionError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteCollectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCollectionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteCollectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteCollectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteCollectionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCollectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteCollectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteCollectionError::Throttling(err.msg))
+                    return RusotoError::service(DeleteCollectionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCollectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2788,12 +2870,14 @@ impl fmt::Display for DeleteCollectionError {
             }
             DeleteCollectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteCollectionError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteCollectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCollectionError {}
 /// Errors returned by DeleteFaces
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFacesError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -2807,6 +2891,8 @@ pub enum DeleteFacesError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFacesError {
@@ -2814,27 +2900,31 @@ impl DeleteFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteFacesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteFacesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteFacesError::InvalidParameter(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DeleteFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFacesError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteFacesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFacesError::Throttling(err.msg))
+                    return RusotoError::service(DeleteFacesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFacesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2850,12 +2940,14 @@ impl fmt::Display for DeleteFacesError {
             DeleteFacesError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             DeleteFacesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteFacesError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteFacesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFacesError {}
 /// Errors returned by DeleteStreamProcessor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStreamProcessorError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -2871,6 +2963,8 @@ pub enum DeleteStreamProcessorError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStreamProcessorError {
@@ -2878,36 +2972,49 @@ impl DeleteStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteStreamProcessorError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteStreamProcessorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteStreamProcessorError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteStreamProcessorError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteStreamProcessorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteStreamProcessorError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteStreamProcessorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2926,12 +3033,14 @@ impl fmt::Display for DeleteStreamProcessorError {
             DeleteStreamProcessorError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteStreamProcessorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteStreamProcessorError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteStreamProcessorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStreamProcessorError {}
 /// Errors returned by DescribeCollection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCollectionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -2945,6 +3054,8 @@ pub enum DescribeCollectionError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCollectionError {
@@ -2952,29 +3063,40 @@ impl DescribeCollectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeCollectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeCollectionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCollectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCollectionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeCollectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeCollectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCollectionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCollectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeCollectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCollectionError::Throttling(err.msg))
+                    return RusotoError::service(DescribeCollectionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCollectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2992,12 +3114,14 @@ impl fmt::Display for DescribeCollectionError {
             }
             DescribeCollectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeCollectionError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeCollectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCollectionError {}
 /// Errors returned by DescribeProjectVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProjectVersionsError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3013,6 +3137,8 @@ pub enum DescribeProjectVersionsError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProjectVersionsError {
@@ -3020,40 +3146,49 @@ impl DescribeProjectVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProjectVersionsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProjectVersionsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProjectVersionsError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProjectVersionsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProjectVersionsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProjectVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectVersionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProjectVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3074,12 +3209,14 @@ impl fmt::Display for DescribeProjectVersionsError {
             }
             DescribeProjectVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeProjectVersionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeProjectVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProjectVersionsError {}
 /// Errors returned by DescribeProjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProjectsError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3093,6 +3230,8 @@ pub enum DescribeProjectsError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProjectsError {
@@ -3100,31 +3239,37 @@ impl DescribeProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeProjectsError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeProjectsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeProjectsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProjectsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(DescribeProjectsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProjectsError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeProjectsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProjectsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProjectsError::Throttling(err.msg))
+                    return RusotoError::service(DescribeProjectsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3142,12 +3287,14 @@ impl fmt::Display for DescribeProjectsError {
                 write!(f, "{}", cause)
             }
             DescribeProjectsError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeProjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProjectsError {}
 /// Errors returned by DescribeStreamProcessor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStreamProcessorError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3161,6 +3308,8 @@ pub enum DescribeStreamProcessorError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStreamProcessorError {
@@ -3168,35 +3317,43 @@ impl DescribeStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStreamProcessorError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStreamProcessorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStreamProcessorError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStreamProcessorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeStreamProcessorError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStreamProcessorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3214,12 +3371,14 @@ impl fmt::Display for DescribeStreamProcessorError {
             }
             DescribeStreamProcessorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeStreamProcessorError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeStreamProcessorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStreamProcessorError {}
 /// Errors returned by DetectCustomLabels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectCustomLabelsError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3243,6 +3402,8 @@ pub enum DetectCustomLabelsError {
     ResourceNotReady(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectCustomLabelsError {
@@ -3250,46 +3411,70 @@ impl DetectCustomLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DetectCustomLabelsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::ImageTooLarge(err.msg))
+                    return RusotoError::service(
+                        DetectCustomLabelsError::ImageTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectCustomLabelsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InvalidImageFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectCustomLabelsError::InvalidImageFormat(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DetectCustomLabelsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        DetectCustomLabelsError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DetectCustomLabelsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetectCustomLabelsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DetectCustomLabelsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::ResourceNotReady(err.msg))
+                    return RusotoError::service(
+                        DetectCustomLabelsError::ResourceNotReady(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::Throttling(err.msg))
+                    return RusotoError::service(DetectCustomLabelsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectCustomLabelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3312,12 +3497,14 @@ impl fmt::Display for DetectCustomLabelsError {
             DetectCustomLabelsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DetectCustomLabelsError::ResourceNotReady(ref cause) => write!(f, "{}", cause),
             DetectCustomLabelsError::Throttling(ref cause) => write!(f, "{}", cause),
+            DetectCustomLabelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectCustomLabelsError {}
 /// Errors returned by DetectFaces
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectFacesError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3335,6 +3522,8 @@ pub enum DetectFacesError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectFacesError {
@@ -3342,33 +3531,40 @@ impl DetectFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(DetectFacesError::AccessDenied(err.msg), &res)
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectFacesError::ImageTooLarge(err.msg))
+                    return RusotoError::service(DetectFacesError::ImageTooLarge(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DetectFacesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectFacesError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(
+                        DetectFacesError::InvalidImageFormat(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(DetectFacesError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectFacesError::InvalidS3Object(err.msg))
+                    return RusotoError::service(DetectFacesError::InvalidS3Object(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DetectFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectFacesError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectFacesError::Throttling(err.msg))
+                    return RusotoError::service(DetectFacesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectFacesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3386,12 +3582,14 @@ impl fmt::Display for DetectFacesError {
             DetectFacesError::InvalidS3Object(ref cause) => write!(f, "{}", cause),
             DetectFacesError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             DetectFacesError::Throttling(ref cause) => write!(f, "{}", cause),
+            DetectFacesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectFacesError {}
 /// Errors returned by DetectLabels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectLabelsError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3409,6 +3607,8 @@ pub enum DetectLabelsError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectLabelsError {
@@ -3416,33 +3616,40 @@ impl DetectLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service(DetectLabelsError::AccessDenied(err.msg), &res)
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectLabelsError::ImageTooLarge(err.msg))
+                    return RusotoError::service(DetectLabelsError::ImageTooLarge(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectLabelsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DetectLabelsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectLabelsError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(
+                        DetectLabelsError::InvalidImageFormat(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectLabelsError::InvalidParameter(err.msg))
+                    return RusotoError::service(DetectLabelsError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectLabelsError::InvalidS3Object(err.msg))
+                    return RusotoError::service(DetectLabelsError::InvalidS3Object(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DetectLabelsError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectLabelsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectLabelsError::Throttling(err.msg))
+                    return RusotoError::service(DetectLabelsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectLabelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3460,12 +3667,14 @@ impl fmt::Display for DetectLabelsError {
             DetectLabelsError::InvalidS3Object(ref cause) => write!(f, "{}", cause),
             DetectLabelsError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             DetectLabelsError::Throttling(ref cause) => write!(f, "{}", cause),
+            DetectLabelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectLabelsError {}
 /// Errors returned by DetectModerationLabels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectModerationLabelsError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3485,6 +3694,8 @@ pub enum DetectModerationLabelsError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectModerationLabelsError {
@@ -3492,48 +3703,61 @@ impl DetectModerationLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DetectModerationLabelsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "HumanLoopQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetectModerationLabelsError::HumanLoopQuotaExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::ImageTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectModerationLabelsError::ImageTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectModerationLabelsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InvalidImageFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectModerationLabelsError::InvalidImageFormat(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectModerationLabelsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectModerationLabelsError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetectModerationLabelsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DetectModerationLabelsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectModerationLabelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3556,12 +3780,14 @@ impl fmt::Display for DetectModerationLabelsError {
                 write!(f, "{}", cause)
             }
             DetectModerationLabelsError::Throttling(ref cause) => write!(f, "{}", cause),
+            DetectModerationLabelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectModerationLabelsError {}
 /// Errors returned by DetectText
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectTextError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3579,6 +3805,8 @@ pub enum DetectTextError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectTextError {
@@ -3586,33 +3814,37 @@ impl DetectTextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectTextError::AccessDenied(err.msg))
+                    return RusotoError::service(DetectTextError::AccessDenied(err.msg), &res)
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectTextError::ImageTooLarge(err.msg))
+                    return RusotoError::service(DetectTextError::ImageTooLarge(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectTextError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DetectTextError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectTextError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(DetectTextError::InvalidImageFormat(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectTextError::InvalidParameter(err.msg))
+                    return RusotoError::service(DetectTextError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectTextError::InvalidS3Object(err.msg))
+                    return RusotoError::service(DetectTextError::InvalidS3Object(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DetectTextError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectTextError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectTextError::Throttling(err.msg))
+                    return RusotoError::service(DetectTextError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectTextError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3630,12 +3862,14 @@ impl fmt::Display for DetectTextError {
             DetectTextError::InvalidS3Object(ref cause) => write!(f, "{}", cause),
             DetectTextError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             DetectTextError::Throttling(ref cause) => write!(f, "{}", cause),
+            DetectTextError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectTextError {}
 /// Errors returned by GetCelebrityInfo
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCelebrityInfoError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3649,6 +3883,8 @@ pub enum GetCelebrityInfoError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCelebrityInfoError {
@@ -3656,29 +3892,37 @@ impl GetCelebrityInfoError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::AccessDenied(err.msg))
+                    return RusotoError::service(GetCelebrityInfoError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCelebrityInfoError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCelebrityInfoError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetCelebrityInfoError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCelebrityInfoError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetCelebrityInfoError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::Throttling(err.msg))
+                    return RusotoError::service(GetCelebrityInfoError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCelebrityInfoError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3696,12 +3940,14 @@ impl fmt::Display for GetCelebrityInfoError {
             }
             GetCelebrityInfoError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetCelebrityInfoError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetCelebrityInfoError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCelebrityInfoError {}
 /// Errors returned by GetCelebrityRecognition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCelebrityRecognitionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3717,6 +3963,8 @@ pub enum GetCelebrityRecognitionError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCelebrityRecognitionError {
@@ -3724,40 +3972,49 @@ impl GetCelebrityRecognitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCelebrityRecognitionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCelebrityRecognitionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCelebrityRecognitionError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCelebrityRecognitionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCelebrityRecognitionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCelebrityRecognitionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetCelebrityRecognitionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCelebrityRecognitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3778,12 +4035,14 @@ impl fmt::Display for GetCelebrityRecognitionError {
             }
             GetCelebrityRecognitionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetCelebrityRecognitionError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetCelebrityRecognitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCelebrityRecognitionError {}
 /// Errors returned by GetContentModeration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetContentModerationError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3799,6 +4058,8 @@ pub enum GetContentModerationError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetContentModerationError {
@@ -3806,38 +4067,49 @@ impl GetContentModerationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContentModerationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetContentModerationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetContentModerationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContentModerationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetContentModerationError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContentModerationError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetContentModerationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContentModerationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetContentModerationError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetContentModerationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContentModerationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetContentModerationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetContentModerationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetContentModerationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3856,12 +4128,14 @@ impl fmt::Display for GetContentModerationError {
             }
             GetContentModerationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetContentModerationError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetContentModerationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetContentModerationError {}
 /// Errors returned by GetFaceDetection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFaceDetectionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3877,6 +4151,8 @@ pub enum GetFaceDetectionError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFaceDetectionError {
@@ -3884,34 +4160,43 @@ impl GetFaceDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFaceDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(GetFaceDetectionError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetFaceDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFaceDetectionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetFaceDetectionError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFaceDetectionError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetFaceDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetFaceDetectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetFaceDetectionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFaceDetectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetFaceDetectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFaceDetectionError::Throttling(err.msg))
+                    return RusotoError::service(GetFaceDetectionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFaceDetectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3930,12 +4215,14 @@ impl fmt::Display for GetFaceDetectionError {
             }
             GetFaceDetectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFaceDetectionError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetFaceDetectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFaceDetectionError {}
 /// Errors returned by GetFaceSearch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFaceSearchError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -3951,6 +4238,8 @@ pub enum GetFaceSearchError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFaceSearchError {
@@ -3958,32 +4247,43 @@ impl GetFaceSearchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFaceSearchError::AccessDenied(err.msg))
+                    return RusotoError::service(GetFaceSearchError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetFaceSearchError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetFaceSearchError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetFaceSearchError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFaceSearchError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetFaceSearchError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetFaceSearchError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(GetFaceSearchError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFaceSearchError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFaceSearchError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetFaceSearchError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFaceSearchError::Throttling(err.msg))
+                    return RusotoError::service(GetFaceSearchError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFaceSearchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4000,12 +4300,14 @@ impl fmt::Display for GetFaceSearchError {
             GetFaceSearchError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             GetFaceSearchError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFaceSearchError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetFaceSearchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFaceSearchError {}
 /// Errors returned by GetLabelDetection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLabelDetectionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4021,6 +4323,8 @@ pub enum GetLabelDetectionError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLabelDetectionError {
@@ -4028,34 +4332,46 @@ impl GetLabelDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLabelDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetLabelDetectionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetLabelDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLabelDetectionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetLabelDetectionError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLabelDetectionError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLabelDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetLabelDetectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetLabelDetectionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLabelDetectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetLabelDetectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetLabelDetectionError::Throttling(err.msg))
+                    return RusotoError::service(GetLabelDetectionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLabelDetectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4074,12 +4390,14 @@ impl fmt::Display for GetLabelDetectionError {
             }
             GetLabelDetectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLabelDetectionError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetLabelDetectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLabelDetectionError {}
 /// Errors returned by GetPersonTracking
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPersonTrackingError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4095,6 +4413,8 @@ pub enum GetPersonTrackingError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPersonTrackingError {
@@ -4102,34 +4422,46 @@ impl GetPersonTrackingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetPersonTrackingError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetPersonTrackingError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetPersonTrackingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPersonTrackingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetPersonTrackingError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPersonTrackingError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetPersonTrackingError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetPersonTrackingError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPersonTrackingError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPersonTrackingError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetPersonTrackingError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPersonTrackingError::Throttling(err.msg))
+                    return RusotoError::service(GetPersonTrackingError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPersonTrackingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4148,12 +4480,14 @@ impl fmt::Display for GetPersonTrackingError {
             }
             GetPersonTrackingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetPersonTrackingError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetPersonTrackingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPersonTrackingError {}
 /// Errors returned by IndexFaces
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum IndexFacesError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4173,6 +4507,8 @@ pub enum IndexFacesError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl IndexFacesError {
@@ -4180,36 +4516,40 @@ impl IndexFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(IndexFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(IndexFacesError::AccessDenied(err.msg), &res)
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(IndexFacesError::ImageTooLarge(err.msg))
+                    return RusotoError::service(IndexFacesError::ImageTooLarge(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(IndexFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        IndexFacesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(IndexFacesError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(IndexFacesError::InvalidImageFormat(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(IndexFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(IndexFacesError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(IndexFacesError::InvalidS3Object(err.msg))
+                    return RusotoError::service(IndexFacesError::InvalidS3Object(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(IndexFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        IndexFacesError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(IndexFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(IndexFacesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(IndexFacesError::Throttling(err.msg))
+                    return RusotoError::service(IndexFacesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(IndexFacesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4228,12 +4568,14 @@ impl fmt::Display for IndexFacesError {
             IndexFacesError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             IndexFacesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             IndexFacesError::Throttling(ref cause) => write!(f, "{}", cause),
+            IndexFacesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for IndexFacesError {}
 /// Errors returned by ListCollections
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCollectionsError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4249,6 +4591,8 @@ pub enum ListCollectionsError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCollectionsError {
@@ -4256,32 +4600,43 @@ impl ListCollectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCollectionsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListCollectionsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCollectionsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListCollectionsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListCollectionsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCollectionsError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListCollectionsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListCollectionsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListCollectionsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListCollectionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListCollectionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCollectionsError::Throttling(err.msg))
+                    return RusotoError::service(ListCollectionsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCollectionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4300,12 +4655,14 @@ impl fmt::Display for ListCollectionsError {
             }
             ListCollectionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListCollectionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListCollectionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCollectionsError {}
 /// Errors returned by ListFaces
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFacesError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4321,6 +4678,8 @@ pub enum ListFacesError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFacesError {
@@ -4328,30 +4687,34 @@ impl ListFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListFacesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(ListFacesError::InternalServerError(err.msg), &res)
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListFacesError::InvalidPaginationToken(err.msg))
+                    return RusotoError::service(
+                        ListFacesError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListFacesError::InvalidParameter(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(ListFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFacesError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListFacesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFacesError::Throttling(err.msg))
+                    return RusotoError::service(ListFacesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFacesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4368,12 +4731,14 @@ impl fmt::Display for ListFacesError {
             ListFacesError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             ListFacesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListFacesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListFacesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFacesError {}
 /// Errors returned by ListStreamProcessors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStreamProcessorsError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4387,6 +4752,8 @@ pub enum ListStreamProcessorsError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStreamProcessorsError {
@@ -4394,33 +4761,43 @@ impl ListStreamProcessorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListStreamProcessorsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListStreamProcessorsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListStreamProcessorsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListStreamProcessorsError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListStreamProcessorsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListStreamProcessorsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListStreamProcessorsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListStreamProcessorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4438,12 +4815,14 @@ impl fmt::Display for ListStreamProcessorsError {
                 write!(f, "{}", cause)
             }
             ListStreamProcessorsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListStreamProcessorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStreamProcessorsError {}
 /// Errors returned by RecognizeCelebrities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RecognizeCelebritiesError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4461,6 +4840,8 @@ pub enum RecognizeCelebritiesError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RecognizeCelebritiesError {
@@ -4468,41 +4849,55 @@ impl RecognizeCelebritiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        RecognizeCelebritiesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::ImageTooLarge(err.msg))
+                    return RusotoError::service(
+                        RecognizeCelebritiesError::ImageTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RecognizeCelebritiesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InvalidImageFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RecognizeCelebritiesError::InvalidImageFormat(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RecognizeCelebritiesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RecognizeCelebritiesError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RecognizeCelebritiesError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        RecognizeCelebritiesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RecognizeCelebritiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4522,12 +4917,14 @@ impl fmt::Display for RecognizeCelebritiesError {
                 write!(f, "{}", cause)
             }
             RecognizeCelebritiesError::Throttling(ref cause) => write!(f, "{}", cause),
+            RecognizeCelebritiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RecognizeCelebritiesError {}
 /// Errors returned by SearchFaces
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchFacesError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4541,6 +4938,8 @@ pub enum SearchFacesError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchFacesError {
@@ -4548,27 +4947,31 @@ impl SearchFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SearchFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(SearchFacesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SearchFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        SearchFacesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SearchFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(SearchFacesError::InvalidParameter(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(SearchFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchFacesError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(SearchFacesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFacesError::Throttling(err.msg))
+                    return RusotoError::service(SearchFacesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchFacesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4584,12 +4987,14 @@ impl fmt::Display for SearchFacesError {
             SearchFacesError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             SearchFacesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SearchFacesError::Throttling(ref cause) => write!(f, "{}", cause),
+            SearchFacesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchFacesError {}
 /// Errors returned by SearchFacesByImage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchFacesByImageError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4609,6 +5014,8 @@ pub enum SearchFacesByImageError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchFacesByImageError {
@@ -4616,40 +5023,58 @@ impl SearchFacesByImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SearchFacesByImageError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        SearchFacesByImageError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(SearchFacesByImageError::ImageTooLarge(err.msg))
+                    return RusotoError::service(
+                        SearchFacesByImageError::ImageTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SearchFacesByImageError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchFacesByImageError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(SearchFacesByImageError::InvalidImageFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchFacesByImageError::InvalidImageFormat(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SearchFacesByImageError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        SearchFacesByImageError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(SearchFacesByImageError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        SearchFacesByImageError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SearchFacesByImageError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchFacesByImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SearchFacesByImageError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFacesByImageError::Throttling(err.msg))
+                    return RusotoError::service(SearchFacesByImageError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchFacesByImageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4670,12 +5095,14 @@ impl fmt::Display for SearchFacesByImageError {
             }
             SearchFacesByImageError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SearchFacesByImageError::Throttling(ref cause) => write!(f, "{}", cause),
+            SearchFacesByImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchFacesByImageError {}
 /// Errors returned by StartCelebrityRecognition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartCelebrityRecognitionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4695,6 +5122,8 @@ pub enum StartCelebrityRecognitionError {
     Throttling(String),
     /// <p>The file size or duration of the supplied media is too large. The maximum file size is 8GB. The maximum duration is 2 hours. </p>
     VideoTooLarge(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartCelebrityRecognitionError {
@@ -4702,52 +5131,63 @@ impl StartCelebrityRecognitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartCelebrityRecognitionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartCelebrityRecognitionError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartCelebrityRecognitionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartCelebrityRecognitionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartCelebrityRecognitionError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartCelebrityRecognitionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartCelebrityRecognitionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartCelebrityRecognitionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::VideoTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartCelebrityRecognitionError::VideoTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartCelebrityRecognitionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4772,12 +5212,14 @@ impl fmt::Display for StartCelebrityRecognitionError {
             }
             StartCelebrityRecognitionError::Throttling(ref cause) => write!(f, "{}", cause),
             StartCelebrityRecognitionError::VideoTooLarge(ref cause) => write!(f, "{}", cause),
+            StartCelebrityRecognitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartCelebrityRecognitionError {}
 /// Errors returned by StartContentModeration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartContentModerationError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4797,6 +5239,8 @@ pub enum StartContentModerationError {
     Throttling(String),
     /// <p>The file size or duration of the supplied media is too large. The maximum file size is 8GB. The maximum duration is 2 hours. </p>
     VideoTooLarge(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartContentModerationError {
@@ -4804,48 +5248,61 @@ impl StartContentModerationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartContentModerationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartContentModerationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartContentModerationError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartContentModerationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContentModerationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartContentModerationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContentModerationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartContentModerationError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContentModerationError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartContentModerationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContentModerationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartContentModerationError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartContentModerationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        StartContentModerationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartContentModerationError::VideoTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContentModerationError::VideoTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartContentModerationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4868,12 +5325,14 @@ impl fmt::Display for StartContentModerationError {
             }
             StartContentModerationError::Throttling(ref cause) => write!(f, "{}", cause),
             StartContentModerationError::VideoTooLarge(ref cause) => write!(f, "{}", cause),
+            StartContentModerationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartContentModerationError {}
 /// Errors returned by StartFaceDetection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartFaceDetectionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4893,6 +5352,8 @@ pub enum StartFaceDetectionError {
     Throttling(String),
     /// <p>The file size or duration of the supplied media is too large. The maximum file size is 8GB. The maximum duration is 2 hours. </p>
     VideoTooLarge(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartFaceDetectionError {
@@ -4900,40 +5361,58 @@ impl StartFaceDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartFaceDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartFaceDetectionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartFaceDetectionError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartFaceDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartFaceDetectionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartFaceDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StartFaceDetectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartFaceDetectionError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        StartFaceDetectionError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartFaceDetectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartFaceDetectionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartFaceDetectionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartFaceDetectionError::Throttling(err.msg))
+                    return RusotoError::service(StartFaceDetectionError::Throttling(err.msg), &res)
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartFaceDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service(
+                        StartFaceDetectionError::VideoTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartFaceDetectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4956,12 +5435,14 @@ impl fmt::Display for StartFaceDetectionError {
             }
             StartFaceDetectionError::Throttling(ref cause) => write!(f, "{}", cause),
             StartFaceDetectionError::VideoTooLarge(ref cause) => write!(f, "{}", cause),
+            StartFaceDetectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartFaceDetectionError {}
 /// Errors returned by StartFaceSearch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartFaceSearchError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -4983,6 +5464,8 @@ pub enum StartFaceSearchError {
     Throttling(String),
     /// <p>The file size or duration of the supplied media is too large. The maximum file size is 8GB. The maximum duration is 2 hours. </p>
     VideoTooLarge(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartFaceSearchError {
@@ -4990,41 +5473,55 @@ impl StartFaceSearchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartFaceSearchError::AccessDenied(err.msg))
+                    return RusotoError::service(StartFaceSearchError::AccessDenied(err.msg), &res)
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(StartFaceSearchError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartFaceSearchError::IdempotentParameterMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartFaceSearchError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        StartFaceSearchError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartFaceSearchError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StartFaceSearchError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartFaceSearchError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        StartFaceSearchError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartFaceSearchError::LimitExceeded(err.msg))
+                    return RusotoError::service(StartFaceSearchError::LimitExceeded(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartFaceSearchError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartFaceSearchError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartFaceSearchError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartFaceSearchError::Throttling(err.msg))
+                    return RusotoError::service(StartFaceSearchError::Throttling(err.msg), &res)
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartFaceSearchError::VideoTooLarge(err.msg))
+                    return RusotoError::service(StartFaceSearchError::VideoTooLarge(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartFaceSearchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5046,12 +5543,14 @@ impl fmt::Display for StartFaceSearchError {
             StartFaceSearchError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartFaceSearchError::Throttling(ref cause) => write!(f, "{}", cause),
             StartFaceSearchError::VideoTooLarge(ref cause) => write!(f, "{}", cause),
+            StartFaceSearchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartFaceSearchError {}
 /// Errors returned by StartLabelDetection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartLabelDetectionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -5071,6 +5570,8 @@ pub enum StartLabelDetectionError {
     Throttling(String),
     /// <p>The file size or duration of the supplied media is too large. The maximum file size is 8GB. The maximum duration is 2 hours. </p>
     VideoTooLarge(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartLabelDetectionError {
@@ -5078,42 +5579,61 @@ impl StartLabelDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartLabelDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartLabelDetectionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartLabelDetectionError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartLabelDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartLabelDetectionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartLabelDetectionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartLabelDetectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartLabelDetectionError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        StartLabelDetectionError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartLabelDetectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartLabelDetectionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartLabelDetectionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartLabelDetectionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        StartLabelDetectionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartLabelDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service(
+                        StartLabelDetectionError::VideoTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartLabelDetectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5136,12 +5656,14 @@ impl fmt::Display for StartLabelDetectionError {
             }
             StartLabelDetectionError::Throttling(ref cause) => write!(f, "{}", cause),
             StartLabelDetectionError::VideoTooLarge(ref cause) => write!(f, "{}", cause),
+            StartLabelDetectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartLabelDetectionError {}
 /// Errors returned by StartPersonTracking
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartPersonTrackingError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -5161,6 +5683,8 @@ pub enum StartPersonTrackingError {
     Throttling(String),
     /// <p>The file size or duration of the supplied media is too large. The maximum file size is 8GB. The maximum duration is 2 hours. </p>
     VideoTooLarge(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartPersonTrackingError {
@@ -5168,42 +5692,61 @@ impl StartPersonTrackingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartPersonTrackingError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartPersonTrackingError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartPersonTrackingError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartPersonTrackingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartPersonTrackingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartPersonTrackingError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartPersonTrackingError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartPersonTrackingError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        StartPersonTrackingError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartPersonTrackingError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartPersonTrackingError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartPersonTrackingError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartPersonTrackingError::Throttling(err.msg))
+                    return RusotoError::service(
+                        StartPersonTrackingError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartPersonTrackingError::VideoTooLarge(err.msg))
+                    return RusotoError::service(
+                        StartPersonTrackingError::VideoTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartPersonTrackingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5226,12 +5769,14 @@ impl fmt::Display for StartPersonTrackingError {
             }
             StartPersonTrackingError::Throttling(ref cause) => write!(f, "{}", cause),
             StartPersonTrackingError::VideoTooLarge(ref cause) => write!(f, "{}", cause),
+            StartPersonTrackingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartPersonTrackingError {}
 /// Errors returned by StartProjectVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartProjectVersionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -5249,6 +5794,8 @@ pub enum StartProjectVersionError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartProjectVersionError {
@@ -5256,39 +5803,55 @@ impl StartProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartProjectVersionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartProjectVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartProjectVersionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartProjectVersionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartProjectVersionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartProjectVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartProjectVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartProjectVersionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StartProjectVersionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartProjectVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartProjectVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        StartProjectVersionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartProjectVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5308,12 +5871,14 @@ impl fmt::Display for StartProjectVersionError {
             StartProjectVersionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StartProjectVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartProjectVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartProjectVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartProjectVersionError {}
 /// Errors returned by StartStreamProcessor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartStreamProcessorError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -5329,6 +5894,8 @@ pub enum StartStreamProcessorError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartStreamProcessorError {
@@ -5336,36 +5903,49 @@ impl StartStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartStreamProcessorError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartStreamProcessorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartStreamProcessorError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StartStreamProcessorError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartStreamProcessorError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartStreamProcessorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        StartStreamProcessorError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartStreamProcessorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5384,12 +5964,14 @@ impl fmt::Display for StartStreamProcessorError {
             StartStreamProcessorError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StartStreamProcessorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartStreamProcessorError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartStreamProcessorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartStreamProcessorError {}
 /// Errors returned by StopProjectVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopProjectVersionError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -5405,6 +5987,8 @@ pub enum StopProjectVersionError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopProjectVersionError {
@@ -5412,32 +5996,46 @@ impl StopProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StopProjectVersionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopProjectVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopProjectVersionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopProjectVersionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StopProjectVersionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopProjectVersionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StopProjectVersionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopProjectVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopProjectVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service(StopProjectVersionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopProjectVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5456,12 +6054,14 @@ impl fmt::Display for StopProjectVersionError {
             StopProjectVersionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StopProjectVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopProjectVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            StopProjectVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopProjectVersionError {}
 /// Errors returned by StopStreamProcessor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopStreamProcessorError {
     /// <p>You are not authorized to perform the action.</p>
     AccessDenied(String),
@@ -5477,6 +6077,8 @@ pub enum StopStreamProcessorError {
     ResourceNotFound(String),
     /// <p>Amazon Rekognition is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopStreamProcessorError {
@@ -5484,36 +6086,49 @@ impl StopStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StopStreamProcessorError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopStreamProcessorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopStreamProcessorError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StopStreamProcessorError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopStreamProcessorError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopStreamProcessorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        StopStreamProcessorError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopStreamProcessorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5532,6 +6147,7 @@ impl fmt::Display for StopStreamProcessorError {
             StopStreamProcessorError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StopStreamProcessorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopStreamProcessorError::Throttling(ref cause) => write!(f, "{}", cause),
+            StopStreamProcessorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -5796,6 +6412,7 @@ impl RekognitionClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> RekognitionClient {
         RekognitionClient {
             client: Client::shared(),
@@ -5821,874 +6438,1762 @@ impl RekognitionClient {
     pub fn new_with_client(client: Client, region: region::Region) -> RekognitionClient {
         RekognitionClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<RekognitionClient> {
+        rusoto_core::ClientBuilder::new(RekognitionClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> RekognitionClient {
+        RekognitionClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Rekognition for RekognitionClient {
-    /// <p>Compares a face in the <i>source</i> input image with each of the 100 largest faces detected in the <i>target</i> input image. </p> <note> <p> If the source image contains multiple faces, the service detects the largest face and compares it with each face detected in the target image. </p> </note> <p>You pass the input and target images either as base64-encoded image bytes or as references to images in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>In response, the operation returns an array of face matches ordered by similarity score in descending order. For each face match, the response provides a bounding box of the face, facial landmarks, pose details (pitch, role, and yaw), quality (brightness and sharpness), and confidence value (indicating the level of confidence that the bounding box contains a face). The response also provides a similarity score, which indicates how closely the faces match. </p> <note> <p>By default, only faces with a similarity score of greater than or equal to 80% are returned in the response. You can change this value by specifying the <code>SimilarityThreshold</code> parameter.</p> </note> <p> <code>CompareFaces</code> also returns an array of faces that don't match the source image. For each face, it returns a bounding box, confidence value, landmarks, pose details, and quality. The response also returns information about the face in the source image, including the bounding box of the face and confidence value.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet a required quality bar. The quality bar is based on a variety of common use cases. Use <code>QualityFilter</code> to set the quality bar by specifying <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>. If you do not want to filter detected faces, specify <code>NONE</code>. The default value is <code>NONE</code>. </p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model or higher. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>If the image doesn't contain Exif metadata, <code>CompareFaces</code> returns orientation information for the source and target images. Use these values to display the images with the correct image orientation.</p> <p>If no faces are detected in the source or target images, <code>CompareFaces</code> returns an <code>InvalidParameterException</code> error. </p> <note> <p> This is a stateless API operation. That is, data returned by this operation doesn't persist.</p> </note> <p>For an example, see Comparing Faces in Images in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:CompareFaces</code> action.</p>
-    async fn compare_faces(
+impl RekognitionClient {
+    /// Calls `compare_faces`, also returning the metadata of the response.
+    pub async fn compare_faces_with_metadata(
         &self,
         input: CompareFacesRequest,
-    ) -> Result<CompareFacesResponse, RusotoError<CompareFacesError>> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.CompareFaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<(CompareFacesResponse, ResponseMetadata), RusotoError<CompareFacesError>> {
+        let request = self.build_compare_faces_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CompareFacesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CompareFacesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CompareFacesError::from_response(response))
         }
     }
 
-    /// <p>Creates a collection in an AWS Region. You can add faces to the collection using the <a>IndexFaces</a> operation. </p> <p>For example, you might create collections, one for each of your application users. A user can then index faces using the <code>IndexFaces</code> operation and persist results in a specific collection. Then, a user can search the collection for faces in the user-specific container. </p> <p>When you create a collection, it is associated with the latest version of the face model version.</p> <note> <p>Collection names are case-sensitive.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:CreateCollection</code> action.</p>
-    async fn create_collection(
+    /// Presigns the request `compare_faces` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_compare_faces(
         &self,
-        input: CreateCollectionRequest,
-    ) -> Result<CreateCollectionResponse, RusotoError<CreateCollectionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CompareFacesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_compare_faces_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_compare_faces_request(&self, input: CompareFacesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CompareFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.CreateCollection");
+        request.add_header("x-amz-target", "RekognitionService.CompareFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_collection`, also returning the metadata of the response.
+    pub async fn create_collection_with_metadata(
+        &self,
+        input: CreateCollectionRequest,
+    ) -> Result<(CreateCollectionResponse, ResponseMetadata), RusotoError<CreateCollectionError>>
+    {
+        let request = self.build_create_collection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCollectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCollectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateCollectionError::from_response(response))
         }
     }
 
-    /// <p>Creates a new Amazon Rekognition Custom Labels project. A project is a logical grouping of resources (images, Labels, models) and operations (training, evaluation and detection). </p> <p>This operation requires permissions to perform the <code>rekognition:CreateProject</code> action.</p>
-    async fn create_project(
+    /// Presigns the request `create_collection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_collection(
         &self,
-        input: CreateProjectRequest,
-    ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateCollectionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_collection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_collection_request(&self, input: CreateCollectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateCollection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.CreateProject");
+        request.add_header("x-amz-target", "RekognitionService.CreateCollection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_project`, also returning the metadata of the response.
+    pub async fn create_project_with_metadata(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<(CreateProjectResponse, ResponseMetadata), RusotoError<CreateProjectError>> {
+        let request = self.build_create_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateProjectResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProjectResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateProjectError::from_response(response))
         }
     }
 
-    /// <p>Creates a new version of a model and begins training. Models are managed as part of an Amazon Rekognition Custom Labels project. You can specify one training dataset and one testing dataset. The response from <code>CreateProjectVersion</code> is an Amazon Resource Name (ARN) for the version of the model. </p> <p>Training takes a while to complete. You can get the current status by calling <a>DescribeProjectVersions</a>.</p> <p>Once training has successfully completed, call <a>DescribeProjectVersions</a> to get the training results and evaluate the model. </p> <p>After evaluating the model, you start the model by calling <a>StartProjectVersion</a>.</p> <p>This operation requires permissions to perform the <code>rekognition:CreateProjectVersion</code> action.</p>
-    async fn create_project_version(
+    /// Presigns the request `create_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_project(
         &self,
-        input: CreateProjectVersionRequest,
-    ) -> Result<CreateProjectVersionResponse, RusotoError<CreateProjectVersionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateProjectRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_project_request(&self, input: CreateProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.CreateProjectVersion");
+        request.add_header("x-amz-target", "RekognitionService.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `create_project_version`, also returning the metadata of the response.
+    pub async fn create_project_version_with_metadata(
+        &self,
+        input: CreateProjectVersionRequest,
+    ) -> Result<
+        (CreateProjectVersionResponse, ResponseMetadata),
+        RusotoError<CreateProjectVersionError>,
+    > {
+        let request = self.build_create_project_version_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProjectVersionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProjectVersionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateProjectVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon Rekognition stream processor that you can use to detect and recognize faces in a streaming video.</p> <p>Amazon Rekognition Video is a consumer of live video from Amazon Kinesis Video Streams. Amazon Rekognition Video sends analysis results to Amazon Kinesis Data Streams.</p> <p>You provide as input a Kinesis video stream (<code>Input</code>) and a Kinesis data stream (<code>Output</code>) stream. You also specify the face recognition criteria in <code>Settings</code>. For example, the collection containing faces that you want to recognize. Use <code>Name</code> to assign an identifier for the stream processor. You use <code>Name</code> to manage the stream processor. For example, you can start processing the source video by calling <a>StartStreamProcessor</a> with the <code>Name</code> field. </p> <p>After you have finished analyzing a streaming video, use <a>StopStreamProcessor</a> to stop processing. You can delete the stream processor by calling <a>DeleteStreamProcessor</a>.</p>
-    async fn create_stream_processor(
+    /// Presigns the request `create_project_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_project_version(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateProjectVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_project_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_project_version_request(
+        &self,
+        input: CreateProjectVersionRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateProjectVersion");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.CreateProjectVersion");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `create_stream_processor`, also returning the metadata of the response.
+    pub async fn create_stream_processor_with_metadata(
         &self,
         input: CreateStreamProcessorRequest,
-    ) -> Result<CreateStreamProcessorResponse, RusotoError<CreateStreamProcessorError>> {
+    ) -> Result<
+        (CreateStreamProcessorResponse, ResponseMetadata),
+        RusotoError<CreateStreamProcessorError>,
+    > {
+        let request = self.build_create_stream_processor_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateStreamProcessorResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateStreamProcessorError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_stream_processor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stream_processor(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateStreamProcessorRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stream_processor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stream_processor_request(
+        &self,
+        input: CreateStreamProcessorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.CreateStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_collection`, also returning the metadata of the response.
+    pub async fn delete_collection_with_metadata(
+        &self,
+        input: DeleteCollectionRequest,
+    ) -> Result<(DeleteCollectionResponse, ResponseMetadata), RusotoError<DeleteCollectionError>>
+    {
+        let request = self.build_delete_collection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateStreamProcessorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteCollectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateStreamProcessorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCollectionError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified collection. Note that this operation removes all faces in the collection. For an example, see <a>delete-collection-procedure</a>.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteCollection</code> action.</p>
-    async fn delete_collection(
+    /// Presigns the request `delete_collection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_collection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCollectionRequest,
-    ) -> Result<DeleteCollectionResponse, RusotoError<DeleteCollectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_collection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_collection_request(&self, input: DeleteCollectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DeleteCollection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DeleteCollection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_faces`, also returning the metadata of the response.
+    pub async fn delete_faces_with_metadata(
+        &self,
+        input: DeleteFacesRequest,
+    ) -> Result<(DeleteFacesResponse, ResponseMetadata), RusotoError<DeleteFacesError>> {
+        let request = self.build_delete_faces_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteCollectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteFacesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCollectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteFacesError::from_response(response))
         }
     }
 
-    /// <p>Deletes faces from a collection. You specify a collection ID and an array of face IDs to remove from the collection.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteFaces</code> action.</p>
-    async fn delete_faces(
+    /// Presigns the request `delete_faces` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_faces(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFacesRequest,
-    ) -> Result<DeleteFacesResponse, RusotoError<DeleteFacesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_faces_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_faces_request(&self, input: DeleteFacesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DeleteFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DeleteFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_stream_processor`, also returning the metadata of the response.
+    pub async fn delete_stream_processor_with_metadata(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> Result<
+        (DeleteStreamProcessorResponse, ResponseMetadata),
+        RusotoError<DeleteStreamProcessorError>,
+    > {
+        let request = self.build_delete_stream_processor_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteFacesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteStreamProcessorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFacesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteStreamProcessorError::from_response(response))
         }
     }
 
-    /// <p>Deletes the stream processor identified by <code>Name</code>. You assign the value for <code>Name</code> when you create the stream processor with <a>CreateStreamProcessor</a>. You might not be able to use the same name for a stream processor for a few seconds after calling <code>DeleteStreamProcessor</code>.</p>
-    async fn delete_stream_processor(
+    /// Presigns the request `delete_stream_processor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stream_processor(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteStreamProcessorRequest,
-    ) -> Result<DeleteStreamProcessorResponse, RusotoError<DeleteStreamProcessorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stream_processor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stream_processor_request(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DeleteStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DeleteStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_collection`, also returning the metadata of the response.
+    pub async fn describe_collection_with_metadata(
+        &self,
+        input: DescribeCollectionRequest,
+    ) -> Result<(DescribeCollectionResponse, ResponseMetadata), RusotoError<DescribeCollectionError>>
+    {
+        let request = self.build_describe_collection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteStreamProcessorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCollectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteStreamProcessorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCollectionError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified collection. You can use <code>DescribeCollection</code> to get information, such as the number of faces indexed into a collection and the version of the model used by the collection for face detection.</p> <p>For more information, see Describing a Collection in the Amazon Rekognition Developer Guide.</p>
-    async fn describe_collection(
+    /// Presigns the request `describe_collection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_collection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCollectionRequest,
-    ) -> Result<DescribeCollectionResponse, RusotoError<DescribeCollectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_collection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_collection_request(&self, input: DescribeCollectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeCollection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DescribeCollection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_project_versions`, also returning the metadata of the response.
+    pub async fn describe_project_versions_with_metadata(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> Result<
+        (DescribeProjectVersionsResponse, ResponseMetadata),
+        RusotoError<DescribeProjectVersionsError>,
+    > {
+        let request = self.build_describe_project_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCollectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProjectVersionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCollectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProjectVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists and describes the models in an Amazon Rekognition Custom Labels project. You can specify up to 10 model versions in <code>ProjectVersionArns</code>. If you don't specify a value, descriptions for all models are returned.</p> <p>This operation requires permissions to perform the <code>rekognition:DescribeProjectVersions</code> action.</p>
-    async fn describe_project_versions(
+    /// Presigns the request `describe_project_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_project_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProjectVersionsRequest,
-    ) -> Result<DescribeProjectVersionsResponse, RusotoError<DescribeProjectVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_project_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_project_versions_request(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeProjectVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DescribeProjectVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_projects`, also returning the metadata of the response.
+    pub async fn describe_projects_with_metadata(
+        &self,
+        input: DescribeProjectsRequest,
+    ) -> Result<(DescribeProjectsResponse, ResponseMetadata), RusotoError<DescribeProjectsError>>
+    {
+        let request = self.build_describe_projects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProjectVersionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProjectsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProjectVersionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProjectsError::from_response(response))
         }
     }
 
-    /// <p>Lists and gets information about your Amazon Rekognition Custom Labels projects.</p> <p>This operation requires permissions to perform the <code>rekognition:DescribeProjects</code> action.</p>
-    async fn describe_projects(
+    /// Presigns the request `describe_projects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_projects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProjectsRequest,
-    ) -> Result<DescribeProjectsResponse, RusotoError<DescribeProjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_projects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_projects_request(&self, input: DescribeProjectsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DescribeProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_stream_processor`, also returning the metadata of the response.
+    pub async fn describe_stream_processor_with_metadata(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> Result<
+        (DescribeStreamProcessorResponse, ResponseMetadata),
+        RusotoError<DescribeStreamProcessorError>,
+    > {
+        let request = self.build_describe_stream_processor_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProjectsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStreamProcessorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProjectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStreamProcessorError::from_response(response))
         }
     }
 
-    /// <p>Provides information about a stream processor created by <a>CreateStreamProcessor</a>. You can get information about the input and output streams, the input parameters for the face recognition being performed, and the current status of the stream processor.</p>
-    async fn describe_stream_processor(
+    /// Presigns the request `describe_stream_processor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stream_processor(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStreamProcessorRequest,
-    ) -> Result<DescribeStreamProcessorResponse, RusotoError<DescribeStreamProcessorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stream_processor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stream_processor_request(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DescribeStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_custom_labels`, also returning the metadata of the response.
+    pub async fn detect_custom_labels_with_metadata(
+        &self,
+        input: DetectCustomLabelsRequest,
+    ) -> Result<(DetectCustomLabelsResponse, ResponseMetadata), RusotoError<DetectCustomLabelsError>>
+    {
+        let request = self.build_detect_custom_labels_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeStreamProcessorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectCustomLabelsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStreamProcessorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectCustomLabelsError::from_response(response))
         }
     }
 
-    /// <p>Detects custom labels in a supplied image by using an Amazon Rekognition Custom Labels model. </p> <p>You specify which version of a model version to use by using the <code>ProjectVersionArn</code> input parameter. </p> <p>You pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> For each object that the model version detects on an image, the API returns a (<code>CustomLabel</code>) object in an array (<code>CustomLabels</code>). Each <code>CustomLabel</code> object provides the label name (<code>Name</code>), the level of confidence that the image contains the object (<code>Confidence</code>), and object location information, if it exists, for the label on the image (<code>Geometry</code>). </p> <p>During training model calculates a threshold value that determines if a prediction for a label is true. By default, <code>DetectCustomLabels</code> doesn't return labels whose confidence value is below the model's calculated threshold value. To filter labels that are returned, specify a value for <code>MinConfidence</code> that is higher than the model's calculated threshold. You can get the model's calculated threshold from the model's training results shown in the Amazon Rekognition Custom Labels console. To get all labels, regardless of confidence, specify a <code>MinConfidence</code> value of 0. </p> <p>You can also add the <code>MaxResults</code> parameter to limit the number of labels returned. </p> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> <p>This operation requires permissions to perform the <code>rekognition:DetectCustomLabels</code> action. </p>
-    async fn detect_custom_labels(
+    /// Presigns the request `detect_custom_labels` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_custom_labels(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectCustomLabelsRequest,
-    ) -> Result<DetectCustomLabelsResponse, RusotoError<DetectCustomLabelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_custom_labels_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_custom_labels_request(
+        &self,
+        input: DetectCustomLabelsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectCustomLabels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectCustomLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_faces`, also returning the metadata of the response.
+    pub async fn detect_faces_with_metadata(
+        &self,
+        input: DetectFacesRequest,
+    ) -> Result<(DetectFacesResponse, ResponseMetadata), RusotoError<DetectFacesError>> {
+        let request = self.build_detect_faces_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetectCustomLabelsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectFacesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectCustomLabelsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectFacesError::from_response(response))
         }
     }
 
-    /// <p>Detects faces within an image that is provided as input.</p> <p> <code>DetectFaces</code> detects the 100 largest faces in the image. For each face detected, the operation returns face details. These details include a bounding box of the face, a confidence value (that the bounding box contains a face), and a fixed set of attributes such as facial landmarks (for example, coordinates of eye and mouth), presence of beard, sunglasses, and so on. </p> <p>The face-detection algorithm is most effective on frontal faces. For non-frontal or obscured faces, the algorithm might not detect the faces or might detect faces with lower confidence. </p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <note> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:DetectFaces</code> action. </p>
-    async fn detect_faces(
+    /// Presigns the request `detect_faces` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_faces(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectFacesRequest,
-    ) -> Result<DetectFacesResponse, RusotoError<DetectFacesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_faces_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_faces_request(&self, input: DetectFacesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_labels`, also returning the metadata of the response.
+    pub async fn detect_labels_with_metadata(
+        &self,
+        input: DetectLabelsRequest,
+    ) -> Result<(DetectLabelsResponse, ResponseMetadata), RusotoError<DetectLabelsError>> {
+        let request = self.build_detect_labels_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetectFacesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectLabelsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectFacesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectLabelsError::from_response(response))
         }
     }
 
-    /// <p>Detects instances of real-world entities within an image (JPEG or PNG) provided as input. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; and concepts like landscape, evening, and nature. </p> <p>For an example, see Analyzing Images Stored in an Amazon S3 Bucket in the Amazon Rekognition Developer Guide.</p> <note> <p> <code>DetectLabels</code> does not support the detection of activities. However, activity detection is supported for label detection in videos. For more information, see StartLabelDetection in the Amazon Rekognition Developer Guide.</p> </note> <p>You pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> For each object, scene, and concept the API returns one or more labels. Each label provides the object name, and the level of confidence that the image contains the object. For example, suppose the input image has a lighthouse, the sea, and a rock. The response includes all three labels, one for each object. </p> <p> <code>{Name: lighthouse, Confidence: 98.4629}</code> </p> <p> <code>{Name: rock,Confidence: 79.2097}</code> </p> <p> <code> {Name: sea,Confidence: 75.061}</code> </p> <p>In the preceding example, the operation returns one label for each of the three objects. The operation can also return multiple labels for the same object in the image. For example, if the input image shows a flower (for example, a tulip), the operation might return the following three labels. </p> <p> <code>{Name: flower,Confidence: 99.0562}</code> </p> <p> <code>{Name: plant,Confidence: 99.0562}</code> </p> <p> <code>{Name: tulip,Confidence: 99.0562}</code> </p> <p>In this example, the detection algorithm more precisely identifies the flower as a tulip.</p> <p>In response, the API returns an array of labels. In addition, the response also includes the orientation correction. Optionally, you can specify <code>MinConfidence</code> to control the confidence threshold for the labels returned. The default is 55%. You can also add the <code>MaxLabels</code> parameter to limit the number of labels returned. </p> <note> <p>If the object detected is a person, the operation doesn't provide the same facial details that the <a>DetectFaces</a> operation provides.</p> </note> <p> <code>DetectLabels</code> returns bounding boxes for instances of common object labels in an array of <a>Instance</a> objects. An <code>Instance</code> object contains a <a>BoundingBox</a> object, for the location of the label on the image. It also includes the confidence by which the bounding box was detected.</p> <p> <code>DetectLabels</code> also returns a hierarchical taxonomy of detected labels. For example, a detected car might be assigned the label <i>car</i>. The label <i>car</i> has two parent labels: <i>Vehicle</i> (its parent) and <i>Transportation</i> (its grandparent). The response returns the entire list of ancestors for a label. Each ancestor is a unique label in the response. In the previous example, <i>Car</i>, <i>Vehicle</i>, and <i>Transportation</i> are returned as unique labels in the response. </p> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> <p>This operation requires permissions to perform the <code>rekognition:DetectLabels</code> action. </p>
-    async fn detect_labels(
+    /// Presigns the request `detect_labels` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_labels(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectLabelsRequest,
-    ) -> Result<DetectLabelsResponse, RusotoError<DetectLabelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_labels_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_labels_request(&self, input: DetectLabelsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectLabels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_moderation_labels`, also returning the metadata of the response.
+    pub async fn detect_moderation_labels_with_metadata(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> Result<
+        (DetectModerationLabelsResponse, ResponseMetadata),
+        RusotoError<DetectModerationLabelsError>,
+    > {
+        let request = self.build_detect_moderation_labels_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetectLabelsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectModerationLabelsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectLabelsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectModerationLabelsError::from_response(response))
         }
     }
 
-    /// <p>Detects unsafe content in a specified JPEG or PNG format image. Use <code>DetectModerationLabels</code> to moderate images depending on your requirements. For example, you might want to filter images that contain nudity, but not images containing suggestive content.</p> <p>To filter images, use the labels returned by <code>DetectModerationLabels</code> to determine which types of content are appropriate.</p> <p>For information about moderation labels, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p>
-    async fn detect_moderation_labels(
+    /// Presigns the request `detect_moderation_labels` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_moderation_labels(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectModerationLabelsRequest,
-    ) -> Result<DetectModerationLabelsResponse, RusotoError<DetectModerationLabelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_moderation_labels_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_moderation_labels_request(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectModerationLabels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectModerationLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_text`, also returning the metadata of the response.
+    pub async fn detect_text_with_metadata(
+        &self,
+        input: DetectTextRequest,
+    ) -> Result<(DetectTextResponse, ResponseMetadata), RusotoError<DetectTextError>> {
+        let request = self.build_detect_text_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetectModerationLabelsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectTextResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectModerationLabelsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectTextError::from_response(response))
         }
     }
 
-    /// <p>Detects text in the input image and converts it into machine-readable text.</p> <p>Pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, you must pass it as a reference to an image in an Amazon S3 bucket. For the AWS CLI, passing image bytes is not supported. The image must be either a .png or .jpeg formatted file. </p> <p>The <code>DetectText</code> operation returns text in an array of <a>TextDetection</a> elements, <code>TextDetections</code>. Each <code>TextDetection</code> element provides information about a single word or line of text that was detected in the image. </p> <p>A word is one or more ISO basic latin script characters that are not separated by spaces. <code>DetectText</code> can detect up to 50 words in an image.</p> <p>A line is a string of equally spaced words. A line isn't necessarily a complete sentence. For example, a driver's license number is detected as a line. A line ends when there is no aligned text after it. Also, a line ends when there is a large gap between words, relative to the length of the words. This means, depending on the gap between words, Amazon Rekognition may detect multiple lines in text aligned in the same direction. Periods don't represent the end of a line. If a sentence spans multiple lines, the <code>DetectText</code> operation returns multiple lines.</p> <p>To determine whether a <code>TextDetection</code> element is a line of text or a word, use the <code>TextDetection</code> object <code>Type</code> field. </p> <p>To be detected, text must be within +/- 90 degrees orientation of the horizontal axis.</p> <p>For more information, see DetectText in the Amazon Rekognition Developer Guide.</p>
-    async fn detect_text(
+    /// Presigns the request `detect_text` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_text(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectTextRequest,
-    ) -> Result<DetectTextResponse, RusotoError<DetectTextError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_text_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_text_request(&self, input: DetectTextRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectText");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.DetectText");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_celebrity_info`, also returning the metadata of the response.
+    pub async fn get_celebrity_info_with_metadata(
+        &self,
+        input: GetCelebrityInfoRequest,
+    ) -> Result<(GetCelebrityInfoResponse, ResponseMetadata), RusotoError<GetCelebrityInfoError>>
+    {
+        let request = self.build_get_celebrity_info_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetectTextResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCelebrityInfoResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectTextError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCelebrityInfoError::from_response(response))
         }
     }
 
-    /// <p>Gets the name and additional information about a celebrity based on his or her Amazon Rekognition ID. The additional information is returned as an array of URLs. If there is no additional information about the celebrity, this list is empty.</p> <p>For more information, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:GetCelebrityInfo</code> action. </p>
-    async fn get_celebrity_info(
+    /// Presigns the request `get_celebrity_info` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_celebrity_info(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCelebrityInfoRequest,
-    ) -> Result<GetCelebrityInfoResponse, RusotoError<GetCelebrityInfoError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_celebrity_info_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_celebrity_info_request(&self, input: GetCelebrityInfoRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetCelebrityInfo");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetCelebrityInfo");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_celebrity_recognition`, also returning the metadata of the response.
+    pub async fn get_celebrity_recognition_with_metadata(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> Result<
+        (GetCelebrityRecognitionResponse, ResponseMetadata),
+        RusotoError<GetCelebrityRecognitionError>,
+    > {
+        let request = self.build_get_celebrity_recognition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCelebrityInfoResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCelebrityRecognitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCelebrityInfoError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCelebrityRecognitionError::from_response(response))
         }
     }
 
-    /// <p>Gets the celebrity recognition results for a Amazon Rekognition Video analysis started by <a>StartCelebrityRecognition</a>.</p> <p>Celebrity recognition in a video is an asynchronous operation. Analysis is started by a call to <a>StartCelebrityRecognition</a> which returns a job identifier (<code>JobId</code>). When the celebrity recognition operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartCelebrityRecognition</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetCelebrityDetection</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityDetection</code>. </p> <p>For more information, see Working With Stored Videos in the Amazon Rekognition Developer Guide.</p> <p> <code>GetCelebrityRecognition</code> returns detected celebrities and the time(s) they are detected in an array (<code>Celebrities</code>) of <a>CelebrityRecognition</a> objects. Each <code>CelebrityRecognition</code> contains information about the celebrity in a <a>CelebrityDetail</a> object and the time, <code>Timestamp</code>, the celebrity was detected. </p> <note> <p> <code>GetCelebrityRecognition</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Celebrities</code> array is sorted by time (milliseconds from the start of the video). You can also sort the array by celebrity by specifying the value <code>ID</code> in the <code>SortBy</code> input parameter.</p> <p>The <code>CelebrityDetail</code> object includes the celebrity identifer and additional information urls. If you don't store the additional information urls, you can get them later by calling <a>GetCelebrityInfo</a> with the celebrity identifer.</p> <p>No information is returned for faces not recognized as celebrities.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetCelebrityDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetCelebrityRecognition</code>.</p>
-    async fn get_celebrity_recognition(
+    /// Presigns the request `get_celebrity_recognition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_celebrity_recognition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCelebrityRecognitionRequest,
-    ) -> Result<GetCelebrityRecognitionResponse, RusotoError<GetCelebrityRecognitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_celebrity_recognition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_celebrity_recognition_request(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetCelebrityRecognition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetCelebrityRecognition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_content_moderation`, also returning the metadata of the response.
+    pub async fn get_content_moderation_with_metadata(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> Result<
+        (GetContentModerationResponse, ResponseMetadata),
+        RusotoError<GetContentModerationError>,
+    > {
+        let request = self.build_get_content_moderation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCelebrityRecognitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetContentModerationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCelebrityRecognitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetContentModerationError::from_response(response))
         }
     }
 
-    /// <p>Gets the unsafe content analysis results for a Amazon Rekognition Video analysis started by <a>StartContentModeration</a>.</p> <p>Unsafe content analysis of a video is an asynchronous operation. You start analysis by calling <a>StartContentModeration</a> which returns a job identifier (<code>JobId</code>). When analysis finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartContentModeration</code>. To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetContentModeration</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Working with Stored Videos in the Amazon Rekognition Devlopers Guide.</p> <p> <code>GetContentModeration</code> returns detected unsafe content labels, and the time they are detected, in an array, <code>ModerationLabels</code>, of <a>ContentModerationDetection</a> objects. </p> <p>By default, the moderated labels are returned sorted by time, in milliseconds from the start of the video. You can also sort them by moderated label by specifying <code>NAME</code> for the <code>SortBy</code> input parameter. </p> <p>Since video analysis can return a large number of results, use the <code>MaxResults</code> parameter to limit the number of labels returned in a single call to <code>GetContentModeration</code>. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetContentModeration</code> and populate the <code>NextToken</code> request parameter with the value of <code>NextToken</code> returned from the previous call to <code>GetContentModeration</code>.</p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
-    async fn get_content_moderation(
+    /// Presigns the request `get_content_moderation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_content_moderation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetContentModerationRequest,
-    ) -> Result<GetContentModerationResponse, RusotoError<GetContentModerationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_content_moderation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_content_moderation_request(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetContentModeration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetContentModeration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_face_detection`, also returning the metadata of the response.
+    pub async fn get_face_detection_with_metadata(
+        &self,
+        input: GetFaceDetectionRequest,
+    ) -> Result<(GetFaceDetectionResponse, ResponseMetadata), RusotoError<GetFaceDetectionError>>
+    {
+        let request = self.build_get_face_detection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetContentModerationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetFaceDetectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetContentModerationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetFaceDetectionError::from_response(response))
         }
     }
 
-    /// <p>Gets face detection results for a Amazon Rekognition Video analysis started by <a>StartFaceDetection</a>.</p> <p>Face detection with Amazon Rekognition Video is an asynchronous operation. You start face detection by calling <a>StartFaceDetection</a> which returns a job identifier (<code>JobId</code>). When the face detection operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceDetection</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p> <code>GetFaceDetection</code> returns an array of detected faces (<code>Faces</code>) sorted by the time the faces were detected. </p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetFaceDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetFaceDetection</code>.</p>
-    async fn get_face_detection(
+    /// Presigns the request `get_face_detection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_face_detection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFaceDetectionRequest,
-    ) -> Result<GetFaceDetectionResponse, RusotoError<GetFaceDetectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_face_detection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_face_detection_request(&self, input: GetFaceDetectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetFaceDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetFaceDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `get_face_search`, also returning the metadata of the response.
+    pub async fn get_face_search_with_metadata(
+        &self,
+        input: GetFaceSearchRequest,
+    ) -> Result<(GetFaceSearchResponse, ResponseMetadata), RusotoError<GetFaceSearchError>> {
+        let request = self.build_get_face_search_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFaceDetectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetFaceSearchResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFaceDetectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetFaceSearchError::from_response(response))
         }
     }
 
-    /// <p>Gets the face search results for Amazon Rekognition Video face search started by <a>StartFaceSearch</a>. The search returns faces in a collection that match the faces of persons detected in a video. It also includes the time(s) that faces are matched in the video.</p> <p>Face search in a video is an asynchronous operation. You start face search by calling to <a>StartFaceSearch</a> which returns a job identifier (<code>JobId</code>). When the search operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceSearch</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetFaceSearch</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>.</p> <p>For more information, see Searching Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>The search results are retured in an array, <code>Persons</code>, of <a>PersonMatch</a> objects. Each<code>PersonMatch</code> element contains details about the matching faces in the input collection, person information (facial attributes, bounding boxes, and person identifer) for the matched person, and the time the person was matched in the video.</p> <note> <p> <code>GetFaceSearch</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Persons</code> array is sorted by the time, in milliseconds from the start of the video, persons are matched. You can also sort by persons by specifying <code>INDEX</code> for the <code>SORTBY</code> input parameter.</p>
-    async fn get_face_search(
+    /// Presigns the request `get_face_search` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_face_search(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFaceSearchRequest,
-    ) -> Result<GetFaceSearchResponse, RusotoError<GetFaceSearchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_face_search_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_face_search_request(&self, input: GetFaceSearchRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetFaceSearch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetFaceSearch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_label_detection`, also returning the metadata of the response.
+    pub async fn get_label_detection_with_metadata(
+        &self,
+        input: GetLabelDetectionRequest,
+    ) -> Result<(GetLabelDetectionResponse, ResponseMetadata), RusotoError<GetLabelDetectionError>>
+    {
+        let request = self.build_get_label_detection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetFaceSearchResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLabelDetectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFaceSearchError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLabelDetectionError::from_response(response))
         }
     }
 
-    /// <p>Gets the label detection results of a Amazon Rekognition Video analysis started by <a>StartLabelDetection</a>. </p> <p>The label detection operation is started by a call to <a>StartLabelDetection</a> which returns a job identifier (<code>JobId</code>). When the label detection operation finishes, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartlabelDetection</code>. To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p> <code>GetLabelDetection</code> returns an array of detected labels (<code>Labels</code>) sorted by the time the labels were detected. You can also sort by the label name by specifying <code>NAME</code> for the <code>SortBy</code> input parameter.</p> <p>The labels returned include the label name, the percentage confidence in the accuracy of the detected label, and the time the label was detected in the video.</p> <p>The returned labels also include bounding box information for common objects, a hierarchical taxonomy of detected labels, and the version of the label model used for detection.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetlabelDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetLabelDetection</code>.</p>
-    async fn get_label_detection(
+    /// Presigns the request `get_label_detection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_label_detection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLabelDetectionRequest,
-    ) -> Result<GetLabelDetectionResponse, RusotoError<GetLabelDetectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_label_detection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_label_detection_request(&self, input: GetLabelDetectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetLabelDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetLabelDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_person_tracking`, also returning the metadata of the response.
+    pub async fn get_person_tracking_with_metadata(
+        &self,
+        input: GetPersonTrackingRequest,
+    ) -> Result<(GetPersonTrackingResponse, ResponseMetadata), RusotoError<GetPersonTrackingError>>
+    {
+        let request = self.build_get_person_tracking_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLabelDetectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPersonTrackingResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLabelDetectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPersonTrackingError::from_response(response))
         }
     }
 
-    /// <p>Gets the path tracking results of a Amazon Rekognition Video analysis started by <a>StartPersonTracking</a>.</p> <p>The person path tracking operation is started by a call to <code>StartPersonTracking</code> which returns a job identifier (<code>JobId</code>). When the operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartPersonTracking</code>.</p> <p>To get the results of the person path tracking operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p> <p> <code>GetPersonTracking</code> returns an array, <code>Persons</code>, of tracked persons and the time(s) their paths were tracked in the video. </p> <note> <p> <code>GetPersonTracking</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. </p> <p>For more information, see FaceDetail in the Amazon Rekognition Developer Guide.</p> </note> <p>By default, the array is sorted by the time(s) a person's path is tracked in the video. You can sort by tracked persons by specifying <code>INDEX</code> for the <code>SortBy</code> input parameter.</p> <p>Use the <code>MaxResults</code> parameter to limit the number of items returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetPersonTracking</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetPersonTracking</code>.</p>
-    async fn get_person_tracking(
+    /// Presigns the request `get_person_tracking` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_person_tracking(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPersonTrackingRequest,
-    ) -> Result<GetPersonTrackingResponse, RusotoError<GetPersonTrackingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_person_tracking_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_person_tracking_request(&self, input: GetPersonTrackingRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetPersonTracking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.GetPersonTracking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `index_faces`, also returning the metadata of the response.
+    pub async fn index_faces_with_metadata(
+        &self,
+        input: IndexFacesRequest,
+    ) -> Result<(IndexFacesResponse, ResponseMetadata), RusotoError<IndexFacesError>> {
+        let request = self.build_index_faces_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPersonTrackingResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<IndexFacesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPersonTrackingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(IndexFacesError::from_response(response))
         }
     }
 
-    /// <p>Detects faces in the input image and adds them to the specified collection. </p> <p>Amazon Rekognition doesn't save the actual faces that are detected. Instead, the underlying detection algorithm first detects the faces in the input image. For each face, the algorithm extracts facial features into a feature vector, and stores it in the backend database. Amazon Rekognition uses feature vectors when it performs face match and search operations using the <a>SearchFaces</a> and <a>SearchFacesByImage</a> operations.</p> <p>For more information, see Adding Faces to a Collection in the Amazon Rekognition Developer Guide.</p> <p>To get the number of faces in a collection, call <a>DescribeCollection</a>. </p> <p>If you're using version 1.0 of the face detection model, <code>IndexFaces</code> indexes the 15 largest faces in the input image. Later versions of the face detection model index the 100 largest faces in the input image. </p> <p>If you're using version 4 or later of the face model, image orientation information is not returned in the <code>OrientationCorrection</code> field. </p> <p>To determine which version of the model you're using, call <a>DescribeCollection</a> and supply the collection ID. You can also get the model version from the value of <code>FaceModelVersion</code> in the response from <code>IndexFaces</code> </p> <p>For more information, see Model Versioning in the Amazon Rekognition Developer Guide.</p> <p>If you provide the optional <code>ExternalImageID</code> for the input image you provided, Amazon Rekognition associates this ID with all faces that it detects. When you call the <a>ListFaces</a> operation, the response returns the external ID. You can use this external image ID to create a client-side index to associate the faces with each image. You can then use the index to find all faces in an image.</p> <p>You can specify the maximum number of faces to index with the <code>MaxFaces</code> input parameter. This is useful when you want to index the largest faces in an image and don't want to index smaller faces, such as those belonging to people standing in the background.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet a required quality bar. The quality bar is based on a variety of common use cases. By default, <code>IndexFaces</code> chooses the quality bar that's used to filter faces. You can also explicitly choose the quality bar. Use <code>QualityFilter</code>, to set the quality bar by specifying <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>. If you do not want to filter detected faces, specify <code>NONE</code>. </p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model or higher. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>Information about faces detected in an image, but not indexed, is returned in an array of <a>UnindexedFace</a> objects, <code>UnindexedFaces</code>. Faces aren't indexed for reasons such as:</p> <ul> <li> <p>The number of faces detected exceeds the value of the <code>MaxFaces</code> request parameter.</p> </li> <li> <p>The face is too small compared to the image dimensions.</p> </li> <li> <p>The face is too blurry.</p> </li> <li> <p>The image is too dark.</p> </li> <li> <p>The face has an extreme pose.</p> </li> <li> <p>The face doesn’t have enough detail to be suitable for face search.</p> </li> </ul> <p>In response, the <code>IndexFaces</code> operation returns an array of metadata for all detected faces, <code>FaceRecords</code>. This includes: </p> <ul> <li> <p>The bounding box, <code>BoundingBox</code>, of the detected face. </p> </li> <li> <p>A confidence value, <code>Confidence</code>, which indicates the confidence that the bounding box contains a face.</p> </li> <li> <p>A face ID, <code>FaceId</code>, assigned by the service for each face that's detected and stored.</p> </li> <li> <p>An image ID, <code>ImageId</code>, assigned by the service for the input image.</p> </li> </ul> <p>If you request all facial attributes (by using the <code>detectionAttributes</code> parameter), Amazon Rekognition returns detailed facial attributes, such as facial landmarks (for example, location of eye and mouth) and other facial attributes. If you provide the same image, specify the same collection, and use the same external ID in the <code>IndexFaces</code> operation, Amazon Rekognition doesn't save duplicate face metadata.</p> <p/> <p>The input image is passed either as base64-encoded image bytes, or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>This operation requires permissions to perform the <code>rekognition:IndexFaces</code> action.</p>
-    async fn index_faces(
+    /// Presigns the request `index_faces` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_index_faces(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: IndexFacesRequest,
-    ) -> Result<IndexFacesResponse, RusotoError<IndexFacesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_index_faces_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_index_faces_request(&self, input: IndexFacesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("IndexFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.IndexFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_collections`, also returning the metadata of the response.
+    pub async fn list_collections_with_metadata(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> Result<(ListCollectionsResponse, ResponseMetadata), RusotoError<ListCollectionsError>>
+    {
+        let request = self.build_list_collections_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<IndexFacesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCollectionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(IndexFacesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCollectionsError::from_response(response))
         }
     }
 
-    /// <p>Returns list of collection IDs in your account. If the result is truncated, the response also provides a <code>NextToken</code> that you can use in the subsequent request to fetch the next set of collection IDs.</p> <p>For an example, see Listing Collections in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListCollections</code> action.</p>
-    async fn list_collections(
+    /// Presigns the request `list_collections` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_collections(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCollectionsRequest,
-    ) -> Result<ListCollectionsResponse, RusotoError<ListCollectionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_collections_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_collections_request(&self, input: ListCollectionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("ListCollections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.ListCollections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_faces`, also returning the metadata of the response.
+    pub async fn list_faces_with_metadata(
+        &self,
+        input: ListFacesRequest,
+    ) -> Result<(ListFacesResponse, ResponseMetadata), RusotoError<ListFacesError>> {
+        let request = self.build_list_faces_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListCollectionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListFacesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCollectionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListFacesError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata for faces in the specified collection. This metadata includes information such as the bounding box coordinates, the confidence (that the bounding box contains a face), and face ID. For an example, see Listing Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListFaces</code> action.</p>
-    async fn list_faces(
+    /// Presigns the request `list_faces` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_faces(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFacesRequest,
-    ) -> Result<ListFacesResponse, RusotoError<ListFacesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_faces_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_faces_request(&self, input: ListFacesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("ListFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.ListFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_stream_processors`, also returning the metadata of the response.
+    pub async fn list_stream_processors_with_metadata(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> Result<
+        (ListStreamProcessorsResponse, ResponseMetadata),
+        RusotoError<ListStreamProcessorsError>,
+    > {
+        let request = self.build_list_stream_processors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListFacesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListStreamProcessorsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFacesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListStreamProcessorsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of stream processors that you have created with <a>CreateStreamProcessor</a>. </p>
-    async fn list_stream_processors(
+    /// Presigns the request `list_stream_processors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_stream_processors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListStreamProcessorsRequest,
-    ) -> Result<ListStreamProcessorsResponse, RusotoError<ListStreamProcessorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_stream_processors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_stream_processors_request(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("ListStreamProcessors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.ListStreamProcessors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `recognize_celebrities`, also returning the metadata of the response.
+    pub async fn recognize_celebrities_with_metadata(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> Result<
+        (RecognizeCelebritiesResponse, ResponseMetadata),
+        RusotoError<RecognizeCelebritiesError>,
+    > {
+        let request = self.build_recognize_celebrities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListStreamProcessorsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RecognizeCelebritiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListStreamProcessorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RecognizeCelebritiesError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of celebrities recognized in the input image. For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide. </p> <p> <code>RecognizeCelebrities</code> returns the 100 largest faces in the image. It lists recognized celebrities in the <code>CelebrityFaces</code> array and unrecognized faces in the <code>UnrecognizedFaces</code> array. <code>RecognizeCelebrities</code> doesn't return celebrities whose faces aren't among the largest 100 faces in the image.</p> <p>For each celebrity recognized, <code>RecognizeCelebrities</code> returns a <code>Celebrity</code> object. The <code>Celebrity</code> object contains the celebrity name, ID, URL links to additional information, match confidence, and a <code>ComparedFace</code> object that you can use to locate the celebrity's face on the image.</p> <p>Amazon Rekognition doesn't retain information about which images a celebrity has been recognized in. Your application must store this information and use the <code>Celebrity</code> ID property as a unique identifier for the celebrity. If you don't store the celebrity name or additional information URLs returned by <code>RecognizeCelebrities</code>, you will need the ID to identify the celebrity in a call to the <a>GetCelebrityInfo</a> operation.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p>For an example, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:RecognizeCelebrities</code> operation.</p>
-    async fn recognize_celebrities(
+    /// Presigns the request `recognize_celebrities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_recognize_celebrities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RecognizeCelebritiesRequest,
-    ) -> Result<RecognizeCelebritiesResponse, RusotoError<RecognizeCelebritiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_recognize_celebrities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_recognize_celebrities_request(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("RecognizeCelebrities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.RecognizeCelebrities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_faces`, also returning the metadata of the response.
+    pub async fn search_faces_with_metadata(
+        &self,
+        input: SearchFacesRequest,
+    ) -> Result<(SearchFacesResponse, ResponseMetadata), RusotoError<SearchFacesError>> {
+        let request = self.build_search_faces_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RecognizeCelebritiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchFacesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RecognizeCelebritiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchFacesError::from_response(response))
         }
     }
 
-    /// <p>For a given input face ID, searches for matching faces in the collection the face belongs to. You get a face ID when you add a face to the collection using the <a>IndexFaces</a> operation. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>You can also search faces without indexing faces by using the <code>SearchFacesByImage</code> operation.</p> </note> <p> The operation response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match that is found. Along with the metadata, the response also includes a <code>confidence</code> value for each face match, indicating the confidence that the specific face matches the input face. </p> <p>For an example, see Searching for a Face Using Its Face ID in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:SearchFaces</code> action.</p>
-    async fn search_faces(
+    /// Presigns the request `search_faces` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_faces(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchFacesRequest,
-    ) -> Result<SearchFacesResponse, RusotoError<SearchFacesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_faces_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_faces_request(&self, input: SearchFacesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("SearchFaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.SearchFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_faces_by_image`, also returning the metadata of the response.
+    pub async fn search_faces_by_image_with_metadata(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> Result<(SearchFacesByImageResponse, ResponseMetadata), RusotoError<SearchFacesByImageError>>
+    {
+        let request = self.build_search_faces_by_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchFacesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchFacesByImageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchFacesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchFacesByImageError::from_response(response))
         }
     }
 
-    /// <p>For a given input image, first detects the largest face in the image, and then searches the specified collection for matching faces. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>To search for all faces in an input image, you might first call the <a>IndexFaces</a> operation, and then use the face IDs returned in subsequent calls to the <a>SearchFaces</a> operation. </p> <p> You can also call the <code>DetectFaces</code> operation and use the bounding boxes in the response to make face crops, which then you can pass in to the <code>SearchFacesByImage</code> operation. </p> </note> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> The response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match found. Along with the metadata, the response also includes a <code>similarity</code> indicating how similar the face is to the input face. In the response, the operation also returns the bounding box (and a confidence level that the bounding box contains a face) of the face that Amazon Rekognition used for the input image. </p> <p>For an example, Searching for a Face Using an Image in the Amazon Rekognition Developer Guide.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet a required quality bar. The quality bar is based on a variety of common use cases. Use <code>QualityFilter</code> to set the quality bar for filtering by specifying <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>. If you do not want to filter detected faces, specify <code>NONE</code>. The default value is <code>NONE</code>.</p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model or higher. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>This operation requires permissions to perform the <code>rekognition:SearchFacesByImage</code> action.</p>
-    async fn search_faces_by_image(
+    /// Presigns the request `search_faces_by_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_faces_by_image(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchFacesByImageRequest,
-    ) -> Result<SearchFacesByImageResponse, RusotoError<SearchFacesByImageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_faces_by_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_faces_by_image_request(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("SearchFacesByImage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.SearchFacesByImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_celebrity_recognition`, also returning the metadata of the response.
+    pub async fn start_celebrity_recognition_with_metadata(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> Result<
+        (StartCelebrityRecognitionResponse, ResponseMetadata),
+        RusotoError<StartCelebrityRecognitionError>,
+    > {
+        let request = self.build_start_celebrity_recognition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchFacesByImageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartCelebrityRecognitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchFacesByImageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartCelebrityRecognitionError::from_response(response))
         }
     }
 
-    /// <p>Starts asynchronous recognition of celebrities in a stored video.</p> <p>Amazon Rekognition Video can detect celebrities in a video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartCelebrityRecognition</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When celebrity recognition analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetCelebrityRecognition</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityRecognition</code>. </p> <p>For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide.</p>
-    async fn start_celebrity_recognition(
+    /// Presigns the request `start_celebrity_recognition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_celebrity_recognition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartCelebrityRecognitionRequest,
-    ) -> Result<StartCelebrityRecognitionResponse, RusotoError<StartCelebrityRecognitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_celebrity_recognition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_celebrity_recognition_request(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartCelebrityRecognition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6698,270 +8203,1206 @@ impl Rekognition for RekognitionClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_content_moderation`, also returning the metadata of the response.
+    pub async fn start_content_moderation_with_metadata(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> Result<
+        (StartContentModerationResponse, ResponseMetadata),
+        RusotoError<StartContentModerationError>,
+    > {
+        let request = self.build_start_content_moderation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartCelebrityRecognitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartContentModerationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartCelebrityRecognitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartContentModerationError::from_response(response))
         }
     }
 
-    /// <p> Starts asynchronous detection of unsafe content in a stored video.</p> <p>Amazon Rekognition Video can moderate content in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartContentModeration</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When unsafe content analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetContentModeration</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
-    async fn start_content_moderation(
+    /// Presigns the request `start_content_moderation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_content_moderation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartContentModerationRequest,
-    ) -> Result<StartContentModerationResponse, RusotoError<StartContentModerationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_content_moderation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_content_moderation_request(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartContentModeration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartContentModeration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_face_detection`, also returning the metadata of the response.
+    pub async fn start_face_detection_with_metadata(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> Result<(StartFaceDetectionResponse, ResponseMetadata), RusotoError<StartFaceDetectionError>>
+    {
+        let request = self.build_start_face_detection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartContentModerationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartFaceDetectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartContentModerationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartFaceDetectionError::from_response(response))
         }
     }
 
-    /// <p>Starts asynchronous detection of faces in a stored video.</p> <p>Amazon Rekognition Video can detect faces in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceDetection</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When face detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p>For more information, see Detecting Faces in a Stored Video in the Amazon Rekognition Developer Guide.</p>
-    async fn start_face_detection(
+    /// Presigns the request `start_face_detection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_face_detection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartFaceDetectionRequest,
-    ) -> Result<StartFaceDetectionResponse, RusotoError<StartFaceDetectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_face_detection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_face_detection_request(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartFaceDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartFaceDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_face_search`, also returning the metadata of the response.
+    pub async fn start_face_search_with_metadata(
+        &self,
+        input: StartFaceSearchRequest,
+    ) -> Result<(StartFaceSearchResponse, ResponseMetadata), RusotoError<StartFaceSearchError>>
+    {
+        let request = self.build_start_face_search_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartFaceDetectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartFaceSearchResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartFaceDetectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartFaceSearchError::from_response(response))
         }
     }
 
-    /// <p>Starts the asynchronous search for faces in a collection that match the faces of persons detected in a stored video.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceSearch</code> returns a job identifier (<code>JobId</code>) which you use to get the search results once the search has completed. When searching is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceSearch</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>. For more information, see <a>procedure-person-search-videos</a>.</p>
-    async fn start_face_search(
+    /// Presigns the request `start_face_search` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_face_search(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartFaceSearchRequest,
-    ) -> Result<StartFaceSearchResponse, RusotoError<StartFaceSearchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_face_search_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_face_search_request(&self, input: StartFaceSearchRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartFaceSearch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartFaceSearch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_label_detection`, also returning the metadata of the response.
+    pub async fn start_label_detection_with_metadata(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> Result<
+        (StartLabelDetectionResponse, ResponseMetadata),
+        RusotoError<StartLabelDetectionError>,
+    > {
+        let request = self.build_start_label_detection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartFaceSearchResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartLabelDetectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartFaceSearchError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartLabelDetectionError::from_response(response))
         }
     }
 
-    /// <p><p>Starts asynchronous detection of labels in a stored video.</p> <p>Amazon Rekognition Video can detect labels in a video. Labels are instances of real-world entities. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; concepts like landscape, evening, and nature; and activities like a person getting out of a car or a person skiing.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartLabelDetection</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p/></p>
-    async fn start_label_detection(
+    /// Presigns the request `start_label_detection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_label_detection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartLabelDetectionRequest,
-    ) -> Result<StartLabelDetectionResponse, RusotoError<StartLabelDetectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_label_detection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_label_detection_request(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartLabelDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartLabelDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_person_tracking`, also returning the metadata of the response.
+    pub async fn start_person_tracking_with_metadata(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> Result<
+        (StartPersonTrackingResponse, ResponseMetadata),
+        RusotoError<StartPersonTrackingError>,
+    > {
+        let request = self.build_start_person_tracking_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartLabelDetectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartPersonTrackingResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartLabelDetectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartPersonTrackingError::from_response(response))
         }
     }
 
-    /// <p>Starts the asynchronous tracking of a person's path in a stored video.</p> <p>Amazon Rekognition Video can track the path of people in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartPersonTracking</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. </p> <p>To get the results of the person detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p>
-    async fn start_person_tracking(
+    /// Presigns the request `start_person_tracking` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_person_tracking(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartPersonTrackingRequest,
-    ) -> Result<StartPersonTrackingResponse, RusotoError<StartPersonTrackingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_person_tracking_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_person_tracking_request(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartPersonTracking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartPersonTracking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_project_version`, also returning the metadata of the response.
+    pub async fn start_project_version_with_metadata(
+        &self,
+        input: StartProjectVersionRequest,
+    ) -> Result<
+        (StartProjectVersionResponse, ResponseMetadata),
+        RusotoError<StartProjectVersionError>,
+    > {
+        let request = self.build_start_project_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartPersonTrackingResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartProjectVersionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartPersonTrackingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartProjectVersionError::from_response(response))
         }
     }
 
-    /// <p>Starts the running of the version of a model. Starting a model takes a while to complete. To check the current state of the model, use <a>DescribeProjectVersions</a>.</p> <p>Once the model is running, you can detect custom labels in new images by calling <a>DetectCustomLabels</a>.</p> <note> <p>You are charged for the amount of time that the model is running. To stop a running model, call <a>StopProjectVersion</a>.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:StartProjectVersion</code> action.</p>
-    async fn start_project_version(
+    /// Presigns the request `start_project_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_project_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartProjectVersionRequest,
-    ) -> Result<StartProjectVersionResponse, RusotoError<StartProjectVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_project_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_project_version_request(
+        &self,
+        input: StartProjectVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartProjectVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_stream_processor`, also returning the metadata of the response.
+    pub async fn start_stream_processor_with_metadata(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> Result<
+        (StartStreamProcessorResponse, ResponseMetadata),
+        RusotoError<StartStreamProcessorError>,
+    > {
+        let request = self.build_start_stream_processor_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartProjectVersionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartStreamProcessorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartProjectVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartStreamProcessorError::from_response(response))
         }
     }
 
-    /// <p>Starts processing a stream processor. You create a stream processor by calling <a>CreateStreamProcessor</a>. To tell <code>StartStreamProcessor</code> which stream processor to start, use the value of the <code>Name</code> field specified in the call to <code>CreateStreamProcessor</code>.</p>
-    async fn start_stream_processor(
+    /// Presigns the request `start_stream_processor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_stream_processor(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartStreamProcessorRequest,
-    ) -> Result<StartStreamProcessorResponse, RusotoError<StartStreamProcessorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_stream_processor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_stream_processor_request(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StartStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_project_version`, also returning the metadata of the response.
+    pub async fn stop_project_version_with_metadata(
+        &self,
+        input: StopProjectVersionRequest,
+    ) -> Result<(StopProjectVersionResponse, ResponseMetadata), RusotoError<StopProjectVersionError>>
+    {
+        let request = self.build_stop_project_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartStreamProcessorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopProjectVersionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartStreamProcessorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopProjectVersionError::from_response(response))
         }
     }
 
-    /// <p>Stops a running model. The operation might take a while to complete. To check the current status, call <a>DescribeProjectVersions</a>. </p>
-    async fn stop_project_version(
+    /// Presigns the request `stop_project_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_project_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopProjectVersionRequest,
-    ) -> Result<StopProjectVersionResponse, RusotoError<StopProjectVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_project_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_project_version_request(
+        &self,
+        input: StopProjectVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StopProjectVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StopProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_stream_processor`, also returning the metadata of the response.
+    pub async fn stop_stream_processor_with_metadata(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> Result<
+        (StopStreamProcessorResponse, ResponseMetadata),
+        RusotoError<StopStreamProcessorError>,
+    > {
+        let request = self.build_stop_stream_processor_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopProjectVersionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopStreamProcessorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopProjectVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopStreamProcessorError::from_response(response))
         }
     }
 
-    /// <p>Stops a running stream processor that was created by <a>CreateStreamProcessor</a>.</p>
-    async fn stop_stream_processor(
+    /// Presigns the request `stop_stream_processor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_stream_processor(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopStreamProcessorRequest,
-    ) -> Result<StopStreamProcessorResponse, RusotoError<StopStreamProcessorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_stream_processor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_stream_processor_request(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StopStreamProcessor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "RekognitionService.StopStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeProjectVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_project_versions_pages(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeProjectVersionsResponse, RusotoError<DescribeProjectVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input| async move {
+                client.describe_project_versions(input).await
+            },
+            |input: &DescribeProjectVersionsRequest, output: &DescribeProjectVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeProjectVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProjectVersionDescriptions` of every page of `DescribeProjectVersions` results for `input`, as `describe_project_versions_pages` requests them.
+    pub fn describe_project_versions_stream(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ProjectVersionDescription, RusotoError<DescribeProjectVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_project_versions_pages(input), |page| {
+            page.project_version_descriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeProjects` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_projects_pages(
+        &self,
+        input: DescribeProjectsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeProjectsResponse, RusotoError<DescribeProjectsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input| async move { client.describe_projects(input).await },
+            |input: &DescribeProjectsRequest, output: &DescribeProjectsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeProjectsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProjectDescriptions` of every page of `DescribeProjects` results for `input`, as `describe_projects_pages` requests them.
+    pub fn describe_projects_stream(
+        &self,
+        input: DescribeProjectsRequest,
+    ) -> impl futures::Stream<Item = Result<ProjectDescription, RusotoError<DescribeProjectsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_projects_pages(input), |page| {
+            page.project_descriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCollections` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_collections_pages(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListCollectionsResponse, RusotoError<ListCollectionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input| async move { client.list_collections(input).await },
+            |input: &ListCollectionsRequest, output: &ListCollectionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCollectionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `ListFaces` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_faces_pages(
+        &self,
+        input: ListFacesRequest,
+    ) -> impl futures::Stream<Item = Result<ListFacesResponse, RusotoError<ListFacesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input| async move { client.list_faces(input).await },
+            |input: &ListFacesRequest, output: &ListFacesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFacesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Faces` of every page of `ListFaces` results for `input`, as `list_faces_pages` requests them.
+    pub fn list_faces_stream(
+        &self,
+        input: ListFacesRequest,
+    ) -> impl futures::Stream<Item = Result<Face, RusotoError<ListFacesError>>> + Send {
+        rusoto_core::pagination::items(self.list_faces_pages(input), |page| {
+            page.faces.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListStreamProcessors` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_stream_processors_pages(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListStreamProcessorsResponse, RusotoError<ListStreamProcessorsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: RekognitionClient, input| async move { client.list_stream_processors(input).await },
+            |input: &ListStreamProcessorsRequest, output: &ListStreamProcessorsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStreamProcessorsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `StreamProcessors` of every page of `ListStreamProcessors` results for `input`, as `list_stream_processors_pages` requests them.
+    pub fn list_stream_processors_stream(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> impl futures::Stream<Item = Result<StreamProcessor, RusotoError<ListStreamProcessorsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_stream_processors_pages(input), |page| {
+            page.stream_processors.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `ProjectVersionRunning`, polling `DescribeProjectVersions` with `input` every 30 seconds, up to 40 times.
+    pub async fn wait_until_project_version_running(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProjectVersionsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_project_version_running_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopStreamProcessorResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopStreamProcessorError::from_response(response))
-        }
+    }
+
+    /// Waits for `ProjectVersionRunning` like `wait_until_project_version_running`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_project_version_running_with(
+        &self,
+        input: DescribeProjectVersionsRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProjectVersionsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_project_versions(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.project_version_descriptions.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "RUNNING")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.project_version_descriptions.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ProjectVersionTrainingCompleted`, polling `DescribeProjectVersions` with `input` every 120 seconds, up to 360 times.
+    pub async fn wait_until_project_version_training_completed(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProjectVersionsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(120), 360);
+        self.wait_until_project_version_training_completed_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ProjectVersionTrainingCompleted` like `wait_until_project_version_training_completed`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_project_version_training_completed_with(
+        &self,
+        input: DescribeProjectVersionsRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProjectVersionsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_project_versions(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.project_version_descriptions.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "TRAINING_COMPLETED")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.project_version_descriptions.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "TRAINING_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Rekognition for RekognitionClient {
+    /// <p>Compares a face in the <i>source</i> input image with each of the 100 largest faces detected in the <i>target</i> input image. </p> <note> <p> If the source image contains multiple faces, the service detects the largest face and compares it with each face detected in the target image. </p> </note> <p>You pass the input and target images either as base64-encoded image bytes or as references to images in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>In response, the operation returns an array of face matches ordered by similarity score in descending order. For each face match, the response provides a bounding box of the face, facial landmarks, pose details (pitch, role, and yaw), quality (brightness and sharpness), and confidence value (indicating the level of confidence that the bounding box contains a face). The response also provides a similarity score, which indicates how closely the faces match. </p> <note> <p>By default, only faces with a similarity score of greater than or equal to 80% are returned in the response. You can change this value by specifying the <code>SimilarityThreshold</code> parameter.</p> </note> <p> <code>CompareFaces</code> also returns an array of faces that don't match the source image. For each face, it returns a bounding box, confidence value, landmarks, pose details, and quality. The response also returns information about the face in the source image, including the bounding box of the face and confidence value.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet a required quality bar. The quality bar is based on a variety of common use cases. Use <code>QualityFilter</code> to set the quality bar by specifying <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>. If you do not want to filter detected faces, specify <code>NONE</code>. The default value is <code>NONE</code>. </p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model or higher. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>If the image doesn't contain Exif metadata, <code>CompareFaces</code> returns orientation information for the source and target images. Use these values to display the images with the correct image orientation.</p> <p>If no faces are detected in the source or target images, <code>CompareFaces</code> returns an <code>InvalidParameterException</code> error. </p> <note> <p> This is a stateless API operation. That is, data returned by this operation doesn't persist.</p> </note> <p>For an example, see Comparing Faces in Images in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:CompareFaces</code> action.</p>
+    async fn compare_faces(
+        &self,
+        input: CompareFacesRequest,
+    ) -> Result<CompareFacesResponse, RusotoError<CompareFacesError>> {
+        self.compare_faces_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a collection in an AWS Region. You can add faces to the collection using the <a>IndexFaces</a> operation. </p> <p>For example, you might create collections, one for each of your application users. A user can then index faces using the <code>IndexFaces</code> operation and persist results in a specific collection. Then, a user can search the collection for faces in the user-specific container. </p> <p>When you create a collection, it is associated with the latest version of the face model version.</p> <note> <p>Collection names are case-sensitive.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:CreateCollection</code> action.</p>
+    async fn create_collection(
+        &self,
+        input: CreateCollectionRequest,
+    ) -> Result<CreateCollectionResponse, RusotoError<CreateCollectionError>> {
+        self.create_collection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Amazon Rekognition Custom Labels project. A project is a logical grouping of resources (images, Labels, models) and operations (training, evaluation and detection). </p> <p>This operation requires permissions to perform the <code>rekognition:CreateProject</code> action.</p>
+    async fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
+        self.create_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new version of a model and begins training. Models are managed as part of an Amazon Rekognition Custom Labels project. You can specify one training dataset and one testing dataset. The response from <code>CreateProjectVersion</code> is an Amazon Resource Name (ARN) for the version of the model. </p> <p>Training takes a while to complete. You can get the current status by calling <a>DescribeProjectVersions</a>.</p> <p>Once training has successfully completed, call <a>DescribeProjectVersions</a> to get the training results and evaluate the model. </p> <p>After evaluating the model, you start the model by calling <a>StartProjectVersion</a>.</p> <p>This operation requires permissions to perform the <code>rekognition:CreateProjectVersion</code> action.</p>
+    async fn create_project_version(
+        &self,
+        input: CreateProjectVersionRequest,
+    ) -> Result<CreateProjectVersionResponse, RusotoError<CreateProjectVersionError>> {
+        self.create_project_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon Rekognition stream processor that you can use to detect and recognize faces in a streaming video.</p> <p>Amazon Rekognition Video is a consumer of live video from Amazon Kinesis Video Streams. Amazon Rekognition Video sends analysis results to Amazon Kinesis Data Streams.</p> <p>You provide as input a Kinesis video stream (<code>Input</code>) and a Kinesis data stream (<code>Output</code>) stream. You also specify the face recognition criteria in <code>Settings</code>. For example, the collection containing faces that you want to recognize. Use <code>Name</code> to assign an identifier for the stream processor. You use <code>Name</code> to manage the stream processor. For example, you can start processing the source video by calling <a>StartStreamProcessor</a> with the <code>Name</code> field. </p> <p>After you have finished analyzing a streaming video, use <a>StopStreamProcessor</a> to stop processing. You can delete the stream processor by calling <a>DeleteStreamProcessor</a>.</p>
+    async fn create_stream_processor(
+        &self,
+        input: CreateStreamProcessorRequest,
+    ) -> Result<CreateStreamProcessorResponse, RusotoError<CreateStreamProcessorError>> {
+        self.create_stream_processor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified collection. Note that this operation removes all faces in the collection. For an example, see <a>delete-collection-procedure</a>.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteCollection</code> action.</p>
+    async fn delete_collection(
+        &self,
+        input: DeleteCollectionRequest,
+    ) -> Result<DeleteCollectionResponse, RusotoError<DeleteCollectionError>> {
+        self.delete_collection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes faces from a collection. You specify a collection ID and an array of face IDs to remove from the collection.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteFaces</code> action.</p>
+    async fn delete_faces(
+        &self,
+        input: DeleteFacesRequest,
+    ) -> Result<DeleteFacesResponse, RusotoError<DeleteFacesError>> {
+        self.delete_faces_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the stream processor identified by <code>Name</code>. You assign the value for <code>Name</code> when you create the stream processor with <a>CreateStreamProcessor</a>. You might not be able to use the same name for a stream processor for a few seconds after calling <code>DeleteStreamProcessor</code>.</p>
+    async fn delete_stream_processor(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> Result<DeleteStreamProcessorResponse, RusotoError<DeleteStreamProcessorError>> {
+        self.delete_stream_processor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified collection. You can use <code>DescribeCollection</code> to get information, such as the number of faces indexed into a collection and the version of the model used by the collection for face detection.</p> <p>For more information, see Describing a Collection in the Amazon Rekognition Developer Guide.</p>
+    async fn describe_collection(
+        &self,
+        input: DescribeCollectionRequest,
+    ) -> Result<DescribeCollectionResponse, RusotoError<DescribeCollectionError>> {
+        self.describe_collection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists and describes the models in an Amazon Rekognition Custom Labels project. You can specify up to 10 model versions in <code>ProjectVersionArns</code>. If you don't specify a value, descriptions for all models are returned.</p> <p>This operation requires permissions to perform the <code>rekognition:DescribeProjectVersions</code> action.</p>
+    async fn describe_project_versions(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> Result<DescribeProjectVersionsResponse, RusotoError<DescribeProjectVersionsError>> {
+        self.describe_project_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists and gets information about your Amazon Rekognition Custom Labels projects.</p> <p>This operation requires permissions to perform the <code>rekognition:DescribeProjects</code> action.</p>
+    async fn describe_projects(
+        &self,
+        input: DescribeProjectsRequest,
+    ) -> Result<DescribeProjectsResponse, RusotoError<DescribeProjectsError>> {
+        self.describe_projects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information about a stream processor created by <a>CreateStreamProcessor</a>. You can get information about the input and output streams, the input parameters for the face recognition being performed, and the current status of the stream processor.</p>
+    async fn describe_stream_processor(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> Result<DescribeStreamProcessorResponse, RusotoError<DescribeStreamProcessorError>> {
+        self.describe_stream_processor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects custom labels in a supplied image by using an Amazon Rekognition Custom Labels model. </p> <p>You specify which version of a model version to use by using the <code>ProjectVersionArn</code> input parameter. </p> <p>You pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> For each object that the model version detects on an image, the API returns a (<code>CustomLabel</code>) object in an array (<code>CustomLabels</code>). Each <code>CustomLabel</code> object provides the label name (<code>Name</code>), the level of confidence that the image contains the object (<code>Confidence</code>), and object location information, if it exists, for the label on the image (<code>Geometry</code>). </p> <p>During training model calculates a threshold value that determines if a prediction for a label is true. By default, <code>DetectCustomLabels</code> doesn't return labels whose confidence value is below the model's calculated threshold value. To filter labels that are returned, specify a value for <code>MinConfidence</code> that is higher than the model's calculated threshold. You can get the model's calculated threshold from the model's training results shown in the Amazon Rekognition Custom Labels console. To get all labels, regardless of confidence, specify a <code>MinConfidence</code> value of 0. </p> <p>You can also add the <code>MaxResults</code> parameter to limit the number of labels returned. </p> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> <p>This operation requires permissions to perform the <code>rekognition:DetectCustomLabels</code> action. </p>
+    async fn detect_custom_labels(
+        &self,
+        input: DetectCustomLabelsRequest,
+    ) -> Result<DetectCustomLabelsResponse, RusotoError<DetectCustomLabelsError>> {
+        self.detect_custom_labels_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects faces within an image that is provided as input.</p> <p> <code>DetectFaces</code> detects the 100 largest faces in the image. For each face detected, the operation returns face details. These details include a bounding box of the face, a confidence value (that the bounding box contains a face), and a fixed set of attributes such as facial landmarks (for example, coordinates of eye and mouth), presence of beard, sunglasses, and so on. </p> <p>The face-detection algorithm is most effective on frontal faces. For non-frontal or obscured faces, the algorithm might not detect the faces or might detect faces with lower confidence. </p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <note> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:DetectFaces</code> action. </p>
+    async fn detect_faces(
+        &self,
+        input: DetectFacesRequest,
+    ) -> Result<DetectFacesResponse, RusotoError<DetectFacesError>> {
+        self.detect_faces_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects instances of real-world entities within an image (JPEG or PNG) provided as input. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; and concepts like landscape, evening, and nature. </p> <p>For an example, see Analyzing Images Stored in an Amazon S3 Bucket in the Amazon Rekognition Developer Guide.</p> <note> <p> <code>DetectLabels</code> does not support the detection of activities. However, activity detection is supported for label detection in videos. For more information, see StartLabelDetection in the Amazon Rekognition Developer Guide.</p> </note> <p>You pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> For each object, scene, and concept the API returns one or more labels. Each label provides the object name, and the level of confidence that the image contains the object. For example, suppose the input image has a lighthouse, the sea, and a rock. The response includes all three labels, one for each object. </p> <p> <code>{Name: lighthouse, Confidence: 98.4629}</code> </p> <p> <code>{Name: rock,Confidence: 79.2097}</code> </p> <p> <code> {Name: sea,Confidence: 75.061}</code> </p> <p>In the preceding example, the operation returns one label for each of the three objects. The operation can also return multiple labels for the same object in the image. For example, if the input image shows a flower (for example, a tulip), the operation might return the following three labels. </p> <p> <code>{Name: flower,Confidence: 99.0562}</code> </p> <p> <code>{Name: plant,Confidence: 99.0562}</code> </p> <p> <code>{Name: tulip,Confidence: 99.0562}</code> </p> <p>In this example, the detection algorithm more precisely identifies the flower as a tulip.</p> <p>In response, the API returns an array of labels. In addition, the response also includes the orientation correction. Optionally, you can specify <code>MinConfidence</code> to control the confidence threshold for the labels returned. The default is 55%. You can also add the <code>MaxLabels</code> parameter to limit the number of labels returned. </p> <note> <p>If the object detected is a person, the operation doesn't provide the same facial details that the <a>DetectFaces</a> operation provides.</p> </note> <p> <code>DetectLabels</code> returns bounding boxes for instances of common object labels in an array of <a>Instance</a> objects. An <code>Instance</code> object contains a <a>BoundingBox</a> object, for the location of the label on the image. It also includes the confidence by which the bounding box was detected.</p> <p> <code>DetectLabels</code> also returns a hierarchical taxonomy of detected labels. For example, a detected car might be assigned the label <i>car</i>. The label <i>car</i> has two parent labels: <i>Vehicle</i> (its parent) and <i>Transportation</i> (its grandparent). The response returns the entire list of ancestors for a label. Each ancestor is a unique label in the response. In the previous example, <i>Car</i>, <i>Vehicle</i>, and <i>Transportation</i> are returned as unique labels in the response. </p> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> <p>This operation requires permissions to perform the <code>rekognition:DetectLabels</code> action. </p>
+    async fn detect_labels(
+        &self,
+        input: DetectLabelsRequest,
+    ) -> Result<DetectLabelsResponse, RusotoError<DetectLabelsError>> {
+        self.detect_labels_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects unsafe content in a specified JPEG or PNG format image. Use <code>DetectModerationLabels</code> to moderate images depending on your requirements. For example, you might want to filter images that contain nudity, but not images containing suggestive content.</p> <p>To filter images, use the labels returned by <code>DetectModerationLabels</code> to determine which types of content are appropriate.</p> <p>For information about moderation labels, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p>
+    async fn detect_moderation_labels(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> Result<DetectModerationLabelsResponse, RusotoError<DetectModerationLabelsError>> {
+        self.detect_moderation_labels_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects text in the input image and converts it into machine-readable text.</p> <p>Pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, you must pass it as a reference to an image in an Amazon S3 bucket. For the AWS CLI, passing image bytes is not supported. The image must be either a .png or .jpeg formatted file. </p> <p>The <code>DetectText</code> operation returns text in an array of <a>TextDetection</a> elements, <code>TextDetections</code>. Each <code>TextDetection</code> element provides information about a single word or line of text that was detected in the image. </p> <p>A word is one or more ISO basic latin script characters that are not separated by spaces. <code>DetectText</code> can detect up to 50 words in an image.</p> <p>A line is a string of equally spaced words. A line isn't necessarily a complete sentence. For example, a driver's license number is detected as a line. A line ends when there is no aligned text after it. Also, a line ends when there is a large gap between words, relative to the length of the words. This means, depending on the gap between words, Amazon Rekognition may detect multiple lines in text aligned in the same direction. Periods don't represent the end of a line. If a sentence spans multiple lines, the <code>DetectText</code> operation returns multiple lines.</p> <p>To determine whether a <code>TextDetection</code> element is a line of text or a word, use the <code>TextDetection</code> object <code>Type</code> field. </p> <p>To be detected, text must be within +/- 90 degrees orientation of the horizontal axis.</p> <p>For more information, see DetectText in the Amazon Rekognition Developer Guide.</p>
+    async fn detect_text(
+        &self,
+        input: DetectTextRequest,
+    ) -> Result<DetectTextResponse, RusotoError<DetectTextError>> {
+        self.detect_text_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the name and additional information about a celebrity based on his or her Amazon Rekognition ID. The additional information is returned as an array of URLs. If there is no additional information about the celebrity, this list is empty.</p> <p>For more information, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:GetCelebrityInfo</code> action. </p>
+    async fn get_celebrity_info(
+        &self,
+        input: GetCelebrityInfoRequest,
+    ) -> Result<GetCelebrityInfoResponse, RusotoError<GetCelebrityInfoError>> {
+        self.get_celebrity_info_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the celebrity recognition results for a Amazon Rekognition Video analysis started by <a>StartCelebrityRecognition</a>.</p> <p>Celebrity recognition in a video is an asynchronous operation. Analysis is started by a call to <a>StartCelebrityRecognition</a> which returns a job identifier (<code>JobId</code>). When the celebrity recognition operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartCelebrityRecognition</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetCelebrityDetection</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityDetection</code>. </p> <p>For more information, see Working With Stored Videos in the Amazon Rekognition Developer Guide.</p> <p> <code>GetCelebrityRecognition</code> returns detected celebrities and the time(s) they are detected in an array (<code>Celebrities</code>) of <a>CelebrityRecognition</a> objects. Each <code>CelebrityRecognition</code> contains information about the celebrity in a <a>CelebrityDetail</a> object and the time, <code>Timestamp</code>, the celebrity was detected. </p> <note> <p> <code>GetCelebrityRecognition</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Celebrities</code> array is sorted by time (milliseconds from the start of the video). You can also sort the array by celebrity by specifying the value <code>ID</code> in the <code>SortBy</code> input parameter.</p> <p>The <code>CelebrityDetail</code> object includes the celebrity identifer and additional information urls. If you don't store the additional information urls, you can get them later by calling <a>GetCelebrityInfo</a> with the celebrity identifer.</p> <p>No information is returned for faces not recognized as celebrities.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetCelebrityDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetCelebrityRecognition</code>.</p>
+    async fn get_celebrity_recognition(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> Result<GetCelebrityRecognitionResponse, RusotoError<GetCelebrityRecognitionError>> {
+        self.get_celebrity_recognition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the unsafe content analysis results for a Amazon Rekognition Video analysis started by <a>StartContentModeration</a>.</p> <p>Unsafe content analysis of a video is an asynchronous operation. You start analysis by calling <a>StartContentModeration</a> which returns a job identifier (<code>JobId</code>). When analysis finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartContentModeration</code>. To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetContentModeration</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Working with Stored Videos in the Amazon Rekognition Devlopers Guide.</p> <p> <code>GetContentModeration</code> returns detected unsafe content labels, and the time they are detected, in an array, <code>ModerationLabels</code>, of <a>ContentModerationDetection</a> objects. </p> <p>By default, the moderated labels are returned sorted by time, in milliseconds from the start of the video. You can also sort them by moderated label by specifying <code>NAME</code> for the <code>SortBy</code> input parameter. </p> <p>Since video analysis can return a large number of results, use the <code>MaxResults</code> parameter to limit the number of labels returned in a single call to <code>GetContentModeration</code>. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetContentModeration</code> and populate the <code>NextToken</code> request parameter with the value of <code>NextToken</code> returned from the previous call to <code>GetContentModeration</code>.</p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
+    async fn get_content_moderation(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> Result<GetContentModerationResponse, RusotoError<GetContentModerationError>> {
+        self.get_content_moderation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets face detection results for a Amazon Rekognition Video analysis started by <a>StartFaceDetection</a>.</p> <p>Face detection with Amazon Rekognition Video is an asynchronous operation. You start face detection by calling <a>StartFaceDetection</a> which returns a job identifier (<code>JobId</code>). When the face detection operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceDetection</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p> <code>GetFaceDetection</code> returns an array of detected faces (<code>Faces</code>) sorted by the time the faces were detected. </p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetFaceDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetFaceDetection</code>.</p>
+    async fn get_face_detection(
+        &self,
+        input: GetFaceDetectionRequest,
+    ) -> Result<GetFaceDetectionResponse, RusotoError<GetFaceDetectionError>> {
+        self.get_face_detection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the face search results for Amazon Rekognition Video face search started by <a>StartFaceSearch</a>. The search returns faces in a collection that match the faces of persons detected in a video. It also includes the time(s) that faces are matched in the video.</p> <p>Face search in a video is an asynchronous operation. You start face search by calling to <a>StartFaceSearch</a> which returns a job identifier (<code>JobId</code>). When the search operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceSearch</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetFaceSearch</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>.</p> <p>For more information, see Searching Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>The search results are retured in an array, <code>Persons</code>, of <a>PersonMatch</a> objects. Each<code>PersonMatch</code> element contains details about the matching faces in the input collection, person information (facial attributes, bounding boxes, and person identifer) for the matched person, and the time the person was matched in the video.</p> <note> <p> <code>GetFaceSearch</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Persons</code> array is sorted by the time, in milliseconds from the start of the video, persons are matched. You can also sort by persons by specifying <code>INDEX</code> for the <code>SORTBY</code> input parameter.</p>
+    async fn get_face_search(
+        &self,
+        input: GetFaceSearchRequest,
+    ) -> Result<GetFaceSearchResponse, RusotoError<GetFaceSearchError>> {
+        self.get_face_search_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the label detection results of a Amazon Rekognition Video analysis started by <a>StartLabelDetection</a>. </p> <p>The label detection operation is started by a call to <a>StartLabelDetection</a> which returns a job identifier (<code>JobId</code>). When the label detection operation finishes, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartlabelDetection</code>. To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p> <code>GetLabelDetection</code> returns an array of detected labels (<code>Labels</code>) sorted by the time the labels were detected. You can also sort by the label name by specifying <code>NAME</code> for the <code>SortBy</code> input parameter.</p> <p>The labels returned include the label name, the percentage confidence in the accuracy of the detected label, and the time the label was detected in the video.</p> <p>The returned labels also include bounding box information for common objects, a hierarchical taxonomy of detected labels, and the version of the label model used for detection.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetlabelDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetLabelDetection</code>.</p>
+    async fn get_label_detection(
+        &self,
+        input: GetLabelDetectionRequest,
+    ) -> Result<GetLabelDetectionResponse, RusotoError<GetLabelDetectionError>> {
+        self.get_label_detection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the path tracking results of a Amazon Rekognition Video analysis started by <a>StartPersonTracking</a>.</p> <p>The person path tracking operation is started by a call to <code>StartPersonTracking</code> which returns a job identifier (<code>JobId</code>). When the operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartPersonTracking</code>.</p> <p>To get the results of the person path tracking operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p> <p> <code>GetPersonTracking</code> returns an array, <code>Persons</code>, of tracked persons and the time(s) their paths were tracked in the video. </p> <note> <p> <code>GetPersonTracking</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. </p> <p>For more information, see FaceDetail in the Amazon Rekognition Developer Guide.</p> </note> <p>By default, the array is sorted by the time(s) a person's path is tracked in the video. You can sort by tracked persons by specifying <code>INDEX</code> for the <code>SortBy</code> input parameter.</p> <p>Use the <code>MaxResults</code> parameter to limit the number of items returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetPersonTracking</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetPersonTracking</code>.</p>
+    async fn get_person_tracking(
+        &self,
+        input: GetPersonTrackingRequest,
+    ) -> Result<GetPersonTrackingResponse, RusotoError<GetPersonTrackingError>> {
+        self.get_person_tracking_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects faces in the input image and adds them to the specified collection. </p> <p>Amazon Rekognition doesn't save the actual faces that are detected. Instead, the underlying detection algorithm first detects the faces in the input image. For each face, the algorithm extracts facial features into a feature vector, and stores it in the backend database. Amazon Rekognition uses feature vectors when it performs face match and search operations using the <a>SearchFaces</a> and <a>SearchFacesByImage</a> operations.</p> <p>For more information, see Adding Faces to a Collection in the Amazon Rekognition Developer Guide.</p> <p>To get the number of faces in a collection, call <a>DescribeCollection</a>. </p> <p>If you're using version 1.0 of the face detection model, <code>IndexFaces</code> indexes the 15 largest faces in the input image. Later versions of the face detection model index the 100 largest faces in the input image. </p> <p>If you're using version 4 or later of the face model, image orientation information is not returned in the <code>OrientationCorrection</code> field. </p> <p>To determine which version of the model you're using, call <a>DescribeCollection</a> and supply the collection ID. You can also get the model version from the value of <code>FaceModelVersion</code> in the response from <code>IndexFaces</code> </p> <p>For more information, see Model Versioning in the Amazon Rekognition Developer Guide.</p> <p>If you provide the optional <code>ExternalImageID</code> for the input image you provided, Amazon Rekognition associates this ID with all faces that it detects. When you call the <a>ListFaces</a> operation, the response returns the external ID. You can use this external image ID to create a client-side index to associate the faces with each image. You can then use the index to find all faces in an image.</p> <p>You can specify the maximum number of faces to index with the <code>MaxFaces</code> input parameter. This is useful when you want to index the largest faces in an image and don't want to index smaller faces, such as those belonging to people standing in the background.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet a required quality bar. The quality bar is based on a variety of common use cases. By default, <code>IndexFaces</code> chooses the quality bar that's used to filter faces. You can also explicitly choose the quality bar. Use <code>QualityFilter</code>, to set the quality bar by specifying <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>. If you do not want to filter detected faces, specify <code>NONE</code>. </p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model or higher. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>Information about faces detected in an image, but not indexed, is returned in an array of <a>UnindexedFace</a> objects, <code>UnindexedFaces</code>. Faces aren't indexed for reasons such as:</p> <ul> <li> <p>The number of faces detected exceeds the value of the <code>MaxFaces</code> request parameter.</p> </li> <li> <p>The face is too small compared to the image dimensions.</p> </li> <li> <p>The face is too blurry.</p> </li> <li> <p>The image is too dark.</p> </li> <li> <p>The face has an extreme pose.</p> </li> <li> <p>The face doesn’t have enough detail to be suitable for face search.</p> </li> </ul> <p>In response, the <code>IndexFaces</code> operation returns an array of metadata for all detected faces, <code>FaceRecords</code>. This includes: </p> <ul> <li> <p>The bounding box, <code>BoundingBox</code>, of the detected face. </p> </li> <li> <p>A confidence value, <code>Confidence</code>, which indicates the confidence that the bounding box contains a face.</p> </li> <li> <p>A face ID, <code>FaceId</code>, assigned by the service for each face that's detected and stored.</p> </li> <li> <p>An image ID, <code>ImageId</code>, assigned by the service for the input image.</p> </li> </ul> <p>If you request all facial attributes (by using the <code>detectionAttributes</code> parameter), Amazon Rekognition returns detailed facial attributes, such as facial landmarks (for example, location of eye and mouth) and other facial attributes. If you provide the same image, specify the same collection, and use the same external ID in the <code>IndexFaces</code> operation, Amazon Rekognition doesn't save duplicate face metadata.</p> <p/> <p>The input image is passed either as base64-encoded image bytes, or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>This operation requires permissions to perform the <code>rekognition:IndexFaces</code> action.</p>
+    async fn index_faces(
+        &self,
+        input: IndexFacesRequest,
+    ) -> Result<IndexFacesResponse, RusotoError<IndexFacesError>> {
+        self.index_faces_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns list of collection IDs in your account. If the result is truncated, the response also provides a <code>NextToken</code> that you can use in the subsequent request to fetch the next set of collection IDs.</p> <p>For an example, see Listing Collections in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListCollections</code> action.</p>
+    async fn list_collections(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> Result<ListCollectionsResponse, RusotoError<ListCollectionsError>> {
+        self.list_collections_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata for faces in the specified collection. This metadata includes information such as the bounding box coordinates, the confidence (that the bounding box contains a face), and face ID. For an example, see Listing Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListFaces</code> action.</p>
+    async fn list_faces(
+        &self,
+        input: ListFacesRequest,
+    ) -> Result<ListFacesResponse, RusotoError<ListFacesError>> {
+        self.list_faces_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of stream processors that you have created with <a>CreateStreamProcessor</a>. </p>
+    async fn list_stream_processors(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> Result<ListStreamProcessorsResponse, RusotoError<ListStreamProcessorsError>> {
+        self.list_stream_processors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of celebrities recognized in the input image. For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide. </p> <p> <code>RecognizeCelebrities</code> returns the 100 largest faces in the image. It lists recognized celebrities in the <code>CelebrityFaces</code> array and unrecognized faces in the <code>UnrecognizedFaces</code> array. <code>RecognizeCelebrities</code> doesn't return celebrities whose faces aren't among the largest 100 faces in the image.</p> <p>For each celebrity recognized, <code>RecognizeCelebrities</code> returns a <code>Celebrity</code> object. The <code>Celebrity</code> object contains the celebrity name, ID, URL links to additional information, match confidence, and a <code>ComparedFace</code> object that you can use to locate the celebrity's face on the image.</p> <p>Amazon Rekognition doesn't retain information about which images a celebrity has been recognized in. Your application must store this information and use the <code>Celebrity</code> ID property as a unique identifier for the celebrity. If you don't store the celebrity name or additional information URLs returned by <code>RecognizeCelebrities</code>, you will need the ID to identify the celebrity in a call to the <a>GetCelebrityInfo</a> operation.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p>For an example, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:RecognizeCelebrities</code> operation.</p>
+    async fn recognize_celebrities(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> Result<RecognizeCelebritiesResponse, RusotoError<RecognizeCelebritiesError>> {
+        self.recognize_celebrities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For a given input face ID, searches for matching faces in the collection the face belongs to. You get a face ID when you add a face to the collection using the <a>IndexFaces</a> operation. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>You can also search faces without indexing faces by using the <code>SearchFacesByImage</code> operation.</p> </note> <p> The operation response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match that is found. Along with the metadata, the response also includes a <code>confidence</code> value for each face match, indicating the confidence that the specific face matches the input face. </p> <p>For an example, see Searching for a Face Using Its Face ID in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:SearchFaces</code> action.</p>
+    async fn search_faces(
+        &self,
+        input: SearchFacesRequest,
+    ) -> Result<SearchFacesResponse, RusotoError<SearchFacesError>> {
+        self.search_faces_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For a given input image, first detects the largest face in the image, and then searches the specified collection for matching faces. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>To search for all faces in an input image, you might first call the <a>IndexFaces</a> operation, and then use the face IDs returned in subsequent calls to the <a>SearchFaces</a> operation. </p> <p> You can also call the <code>DetectFaces</code> operation and use the bounding boxes in the response to make face crops, which then you can pass in to the <code>SearchFacesByImage</code> operation. </p> </note> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> The response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match found. Along with the metadata, the response also includes a <code>similarity</code> indicating how similar the face is to the input face. In the response, the operation also returns the bounding box (and a confidence level that the bounding box contains a face) of the face that Amazon Rekognition used for the input image. </p> <p>For an example, Searching for a Face Using an Image in the Amazon Rekognition Developer Guide.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet a required quality bar. The quality bar is based on a variety of common use cases. Use <code>QualityFilter</code> to set the quality bar for filtering by specifying <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>. If you do not want to filter detected faces, specify <code>NONE</code>. The default value is <code>NONE</code>.</p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model or higher. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>This operation requires permissions to perform the <code>rekognition:SearchFacesByImage</code> action.</p>
+    async fn search_faces_by_image(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> Result<SearchFacesByImageResponse, RusotoError<SearchFacesByImageError>> {
+        self.search_faces_by_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts asynchronous recognition of celebrities in a stored video.</p> <p>Amazon Rekognition Video can detect celebrities in a video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartCelebrityRecognition</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When celebrity recognition analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetCelebrityRecognition</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityRecognition</code>. </p> <p>For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide.</p>
+    async fn start_celebrity_recognition(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> Result<StartCelebrityRecognitionResponse, RusotoError<StartCelebrityRecognitionError>>
+    {
+        self.start_celebrity_recognition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Starts asynchronous detection of unsafe content in a stored video.</p> <p>Amazon Rekognition Video can moderate content in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartContentModeration</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When unsafe content analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetContentModeration</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
+    async fn start_content_moderation(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> Result<StartContentModerationResponse, RusotoError<StartContentModerationError>> {
+        self.start_content_moderation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts asynchronous detection of faces in a stored video.</p> <p>Amazon Rekognition Video can detect faces in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceDetection</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When face detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p>For more information, see Detecting Faces in a Stored Video in the Amazon Rekognition Developer Guide.</p>
+    async fn start_face_detection(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> Result<StartFaceDetectionResponse, RusotoError<StartFaceDetectionError>> {
+        self.start_face_detection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the asynchronous search for faces in a collection that match the faces of persons detected in a stored video.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceSearch</code> returns a job identifier (<code>JobId</code>) which you use to get the search results once the search has completed. When searching is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceSearch</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>. For more information, see <a>procedure-person-search-videos</a>.</p>
+    async fn start_face_search(
+        &self,
+        input: StartFaceSearchRequest,
+    ) -> Result<StartFaceSearchResponse, RusotoError<StartFaceSearchError>> {
+        self.start_face_search_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Starts asynchronous detection of labels in a stored video.</p> <p>Amazon Rekognition Video can detect labels in a video. Labels are instances of real-world entities. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; concepts like landscape, evening, and nature; and activities like a person getting out of a car or a person skiing.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartLabelDetection</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p/></p>
+    async fn start_label_detection(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> Result<StartLabelDetectionResponse, RusotoError<StartLabelDetectionError>> {
+        self.start_label_detection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the asynchronous tracking of a person's path in a stored video.</p> <p>Amazon Rekognition Video can track the path of people in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartPersonTracking</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. </p> <p>To get the results of the person detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p>
+    async fn start_person_tracking(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> Result<StartPersonTrackingResponse, RusotoError<StartPersonTrackingError>> {
+        self.start_person_tracking_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the running of the version of a model. Starting a model takes a while to complete. To check the current state of the model, use <a>DescribeProjectVersions</a>.</p> <p>Once the model is running, you can detect custom labels in new images by calling <a>DetectCustomLabels</a>.</p> <note> <p>You are charged for the amount of time that the model is running. To stop a running model, call <a>StopProjectVersion</a>.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:StartProjectVersion</code> action.</p>
+    async fn start_project_version(
+        &self,
+        input: StartProjectVersionRequest,
+    ) -> Result<StartProjectVersionResponse, RusotoError<StartProjectVersionError>> {
+        self.start_project_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts processing a stream processor. You create a stream processor by calling <a>CreateStreamProcessor</a>. To tell <code>StartStreamProcessor</code> which stream processor to start, use the value of the <code>Name</code> field specified in the call to <code>CreateStreamProcessor</code>.</p>
+    async fn start_stream_processor(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> Result<StartStreamProcessorResponse, RusotoError<StartStreamProcessorError>> {
+        self.start_stream_processor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a running model. The operation might take a while to complete. To check the current status, call <a>DescribeProjectVersions</a>. </p>
+    async fn stop_project_version(
+        &self,
+        input: StopProjectVersionRequest,
+    ) -> Result<StopProjectVersionResponse, RusotoError<StopProjectVersionError>> {
+        self.stop_project_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a running stream processor that was created by <a>CreateStreamProcessor</a>.</p>
+    async fn stop_stream_processor(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> Result<StopStreamProcessorResponse, RusotoError<StopStreamProcessorError>> {
+        self.stop_stream_processor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
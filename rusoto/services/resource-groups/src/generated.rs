@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -429,6 +429,7 @@ pub struct UpdateGroupQueryOutput {
 
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGroupError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -440,6 +441,8 @@ pub enum CreateGroupError {
     MethodNotAllowed(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGroupError {
@@ -447,22 +450,25 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupError::BadRequest(err.msg))
+                    return RusotoError::service(CreateGroupError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateGroupError::Forbidden(err.msg))
+                    return RusotoError::service(CreateGroupError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateGroupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateGroupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(CreateGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(CreateGroupError::MethodNotAllowed(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateGroupError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -477,12 +483,14 @@ impl fmt::Display for CreateGroupError {
             CreateGroupError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateGroupError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             CreateGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGroupError {}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGroupError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -496,6 +504,8 @@ pub enum DeleteGroupError {
     NotFound(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGroupError {
@@ -503,25 +513,28 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGroupError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteGroupError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteGroupError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteGroupError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteGroupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteGroupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(DeleteGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(DeleteGroupError::MethodNotAllowed(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::NotFound(err.msg))
+                    return RusotoError::service(DeleteGroupError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteGroupError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -537,12 +550,14 @@ impl fmt::Display for DeleteGroupError {
             DeleteGroupError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             DeleteGroupError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGroupError {}
 /// Errors returned by GetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGroupError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -556,6 +571,8 @@ pub enum GetGroupError {
     NotFound(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGroupError {
@@ -563,25 +580,25 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupError::BadRequest(err.msg))
+                    return RusotoError::service(GetGroupError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGroupError::Forbidden(err.msg))
+                    return RusotoError::service(GetGroupError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetGroupError::InternalServerError(err.msg))
+                    return RusotoError::service(GetGroupError::InternalServerError(err.msg), &res)
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(GetGroupError::MethodNotAllowed(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGroupError::NotFound(err.msg))
+                    return RusotoError::service(GetGroupError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetGroupError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -597,12 +614,14 @@ impl fmt::Display for GetGroupError {
             GetGroupError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             GetGroupError::NotFound(ref cause) => write!(f, "{}", cause),
             GetGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGroupError {}
 /// Errors returned by GetGroupQuery
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGroupQueryError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -616,6 +635,8 @@ pub enum GetGroupQueryError {
     NotFound(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGroupQueryError {
@@ -623,25 +644,31 @@ impl GetGroupQueryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupQueryError::BadRequest(err.msg))
+                    return RusotoError::service(GetGroupQueryError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGroupQueryError::Forbidden(err.msg))
+                    return RusotoError::service(GetGroupQueryError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetGroupQueryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetGroupQueryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetGroupQueryError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        GetGroupQueryError::MethodNotAllowed(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGroupQueryError::NotFound(err.msg))
+                    return RusotoError::service(GetGroupQueryError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupQueryError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetGroupQueryError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGroupQueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -657,12 +684,14 @@ impl fmt::Display for GetGroupQueryError {
             GetGroupQueryError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             GetGroupQueryError::NotFound(ref cause) => write!(f, "{}", cause),
             GetGroupQueryError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetGroupQueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGroupQueryError {}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTagsError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -676,6 +705,8 @@ pub enum GetTagsError {
     NotFound(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTagsError {
@@ -683,25 +714,25 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTagsError::BadRequest(err.msg))
+                    return RusotoError::service(GetTagsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetTagsError::Forbidden(err.msg))
+                    return RusotoError::service(GetTagsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(GetTagsError::InternalServerError(err.msg), &res)
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetTagsError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(GetTagsError::MethodNotAllowed(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTagsError::NotFound(err.msg))
+                    return RusotoError::service(GetTagsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetTagsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -717,12 +748,14 @@ impl fmt::Display for GetTagsError {
             GetTagsError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             GetTagsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetTagsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTagsError {}
 /// Errors returned by ListGroupResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGroupResourcesError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -738,6 +771,8 @@ pub enum ListGroupResourcesError {
     TooManyRequests(String),
     /// <p>The request has not been applied because it lacks valid authentication credentials for the target resource.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGroupResourcesError {
@@ -745,30 +780,40 @@ impl ListGroupResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupResourcesError::BadRequest(err.msg))
+                    return RusotoError::service(ListGroupResourcesError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListGroupResourcesError::Forbidden(err.msg))
+                    return RusotoError::service(ListGroupResourcesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListGroupResourcesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListGroupResourcesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(ListGroupResourcesError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        ListGroupResourcesError::MethodNotAllowed(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListGroupResourcesError::NotFound(err.msg))
+                    return RusotoError::service(ListGroupResourcesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGroupResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListGroupResourcesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGroupResourcesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        ListGroupResourcesError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGroupResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -785,12 +830,14 @@ impl fmt::Display for ListGroupResourcesError {
             ListGroupResourcesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListGroupResourcesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListGroupResourcesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListGroupResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGroupResourcesError {}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGroupsError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -802,6 +849,8 @@ pub enum ListGroupsError {
     MethodNotAllowed(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGroupsError {
@@ -809,22 +858,25 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupsError::BadRequest(err.msg))
+                    return RusotoError::service(ListGroupsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListGroupsError::Forbidden(err.msg))
+                    return RusotoError::service(ListGroupsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListGroupsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListGroupsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(ListGroupsError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(ListGroupsError::MethodNotAllowed(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGroupsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListGroupsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -839,12 +891,14 @@ impl fmt::Display for ListGroupsError {
             ListGroupsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListGroupsError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             ListGroupsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGroupsError {}
 /// Errors returned by SearchResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchResourcesError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -858,6 +912,8 @@ pub enum SearchResourcesError {
     TooManyRequests(String),
     /// <p>The request has not been applied because it lacks valid authentication credentials for the target resource.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchResourcesError {
@@ -865,25 +921,34 @@ impl SearchResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SearchResourcesError::BadRequest(err.msg))
+                    return RusotoError::service(SearchResourcesError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SearchResourcesError::Forbidden(err.msg))
+                    return RusotoError::service(SearchResourcesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(SearchResourcesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        SearchResourcesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(SearchResourcesError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        SearchResourcesError::MethodNotAllowed(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SearchResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        SearchResourcesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchResourcesError::Unauthorized(err.msg))
+                    return RusotoError::service(SearchResourcesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -899,12 +964,14 @@ impl fmt::Display for SearchResourcesError {
             SearchResourcesError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             SearchResourcesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             SearchResourcesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            SearchResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchResourcesError {}
 /// Errors returned by Tag
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -918,6 +985,8 @@ pub enum TagError {
     NotFound(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagError {
@@ -925,21 +994,25 @@ impl TagError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagError::BadRequest(err.msg))
+                    return RusotoError::service(TagError::BadRequest(err.msg), &res)
+                }
+                "ForbiddenException" => {
+                    return RusotoError::service(TagError::Forbidden(err.msg), &res)
                 }
-                "ForbiddenException" => return RusotoError::Service(TagError::Forbidden(err.msg)),
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagError::InternalServerError(err.msg))
+                    return RusotoError::service(TagError::InternalServerError(err.msg), &res)
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(TagError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(TagError::MethodNotAllowed(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(TagError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(TagError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -955,12 +1028,14 @@ impl fmt::Display for TagError {
             TagError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             TagError::NotFound(ref cause) => write!(f, "{}", cause),
             TagError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            TagError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagError {}
 /// Errors returned by Untag
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -974,6 +1049,8 @@ pub enum UntagError {
     NotFound(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagError {
@@ -981,23 +1058,25 @@ impl UntagError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagError::BadRequest(err.msg))
+                    return RusotoError::service(UntagError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagError::Forbidden(err.msg))
+                    return RusotoError::service(UntagError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagError::InternalServerError(err.msg))
+                    return RusotoError::service(UntagError::InternalServerError(err.msg), &res)
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UntagError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(UntagError::MethodNotAllowed(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(UntagError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(UntagError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1013,12 +1092,14 @@ impl fmt::Display for UntagError {
             UntagError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             UntagError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UntagError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagError {}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGroupError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -1032,6 +1113,8 @@ pub enum UpdateGroupError {
     NotFound(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGroupError {
@@ -1039,25 +1122,28 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGroupError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateGroupError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGroupError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateGroupError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateGroupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateGroupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UpdateGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(UpdateGroupError::MethodNotAllowed(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGroupError::NotFound(err.msg))
+                    return RusotoError::service(UpdateGroupError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateGroupError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1073,12 +1159,14 @@ impl fmt::Display for UpdateGroupError {
             UpdateGroupError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             UpdateGroupError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGroupError {}
 /// Errors returned by UpdateGroupQuery
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGroupQueryError {
     /// <p>The request does not comply with validation rules that are defined for the request parameters.</p>
     BadRequest(String),
@@ -1092,6 +1180,8 @@ pub enum UpdateGroupQueryError {
     NotFound(String),
     /// <p>The caller has exceeded throttling limits.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGroupQueryError {
@@ -1099,27 +1189,34 @@ impl UpdateGroupQueryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateGroupQueryError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateGroupQueryError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGroupQueryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        UpdateGroupQueryError::MethodNotAllowed(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::NotFound(err.msg))
+                    return RusotoError::service(UpdateGroupQueryError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateGroupQueryError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGroupQueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1135,6 +1232,7 @@ impl fmt::Display for UpdateGroupQueryError {
             UpdateGroupQueryError::MethodNotAllowed(ref cause) => write!(f, "{}", cause),
             UpdateGroupQueryError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateGroupQueryError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateGroupQueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1219,6 +1317,7 @@ impl ResourceGroupsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ResourceGroupsClient {
         ResourceGroupsClient {
             client: Client::shared(),
@@ -1244,160 +1343,333 @@ impl ResourceGroupsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ResourceGroupsClient {
         ResourceGroupsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ResourceGroupsClient> {
+        rusoto_core::ClientBuilder::new(ResourceGroupsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ResourceGroupsClient {
+        ResourceGroupsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ResourceGroups for ResourceGroupsClient {
-    /// <p>Creates a group with a specified name, description, and resource query.</p>
-    async fn create_group(
+impl ResourceGroupsClient {
+    /// Calls `create_group`, also returning the metadata of the response.
+    pub async fn create_group_with_metadata(
         &self,
         input: CreateGroupInput,
-    ) -> Result<CreateGroupOutput, RusotoError<CreateGroupError>> {
+    ) -> Result<(CreateGroupOutput, ResponseMetadata), RusotoError<CreateGroupError>> {
+        let request = self.build_create_group_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateGroupOutput, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateGroupError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_group(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateGroupInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_group_request(&self, input: CreateGroupInput) -> SignedRequest {
         let request_uri = "/groups";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_group`, also returning the metadata of the response.
+    pub async fn delete_group_with_metadata(
+        &self,
+        input: DeleteGroupInput,
+    ) -> Result<(DeleteGroupOutput, ResponseMetadata), RusotoError<DeleteGroupError>> {
+        let request = self.build_delete_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateGroupOutput, _>()?;
+                .deserialize::<DeleteGroupOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified resource group. Deleting a resource group does not delete resources that are members of the group; it only deletes the group structure.</p>
-    async fn delete_group(
+    /// Presigns the request `delete_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGroupInput,
-    ) -> Result<DeleteGroupOutput, RusotoError<DeleteGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_group_request(&self, input: DeleteGroupInput) -> SignedRequest {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request =
             SignedRequest::new("DELETE", "resource-groups", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_group`, also returning the metadata of the response.
+    pub async fn get_group_with_metadata(
+        &self,
+        input: GetGroupInput,
+    ) -> Result<(GetGroupOutput, ResponseMetadata), RusotoError<GetGroupError>> {
+        let request = self.build_get_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGroupOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetGroupOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGroupError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specified resource group.</p>
-    async fn get_group(
+    /// Presigns the request `get_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGroupInput,
-    ) -> Result<GetGroupOutput, RusotoError<GetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_group_request(&self, input: GetGroupInput) -> SignedRequest {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_group_query`, also returning the metadata of the response.
+    pub async fn get_group_query_with_metadata(
+        &self,
+        input: GetGroupQueryInput,
+    ) -> Result<(GetGroupQueryOutput, ResponseMetadata), RusotoError<GetGroupQueryError>> {
+        let request = self.build_get_group_query_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetGroupOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetGroupQueryOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGroupQueryError::from_response(response))
         }
     }
 
-    /// <p>Returns the resource query associated with the specified resource group.</p>
-    async fn get_group_query(
+    /// Presigns the request `get_group_query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_group_query(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGroupQueryInput,
-    ) -> Result<GetGroupQueryOutput, RusotoError<GetGroupQueryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_group_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_group_query_request(&self, input: GetGroupQueryInput) -> SignedRequest {
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroupQuery");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_tags`, also returning the metadata of the response.
+    pub async fn get_tags_with_metadata(
+        &self,
+        input: GetTagsInput,
+    ) -> Result<(GetTagsOutput, ResponseMetadata), RusotoError<GetTagsError>> {
+        let request = self.build_get_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetGroupQueryOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetTagsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGroupQueryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetTagsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of tags that are associated with a resource group, specified by an ARN.</p>
-    async fn get_tags(
+    /// Presigns the request `get_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTagsInput,
-    ) -> Result<GetTagsOutput, RusotoError<GetTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_tags_request(&self, input: GetTagsInput) -> SignedRequest {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_group_resources`, also returning the metadata of the response.
+    pub async fn list_group_resources_with_metadata(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> Result<(ListGroupResourcesOutput, ResponseMetadata), RusotoError<ListGroupResourcesError>>
+    {
+        let request = self.build_list_group_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetTagsOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGroupResourcesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListGroupResourcesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of ARNs of resources that are members of a specified resource group.</p>
-    async fn list_group_resources(
+    /// Presigns the request `list_group_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_group_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGroupResourcesInput,
-    ) -> Result<ListGroupResourcesOutput, RusotoError<ListGroupResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_group_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_group_resources_request(&self, input: ListGroupResourcesInput) -> SignedRequest {
         let request_uri = format!(
             "/groups/{group_name}/resource-identifiers-list",
             group_name = input.group_name
         );
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroupResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1412,31 +1684,55 @@ impl ResourceGroups for ResourceGroupsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_groups`, also returning the metadata of the response.
+    pub async fn list_groups_with_metadata(
+        &self,
+        input: ListGroupsInput,
+    ) -> Result<(ListGroupsOutput, ResponseMetadata), RusotoError<ListGroupsError>> {
+        let request = self.build_list_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGroupResourcesOutput, _>()?;
+                .deserialize::<ListGroupsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGroupResourcesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListGroupsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of existing resource groups in your account.</p>
-    async fn list_groups(
+    /// Presigns the request `list_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGroupsInput,
-    ) -> Result<ListGroupsOutput, RusotoError<ListGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_groups_request(&self, input: ListGroupsInput) -> SignedRequest {
         let request_uri = "/groups-list";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1451,165 +1747,484 @@ impl ResourceGroups for ResourceGroupsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `search_resources`, also returning the metadata of the response.
+    pub async fn search_resources_with_metadata(
+        &self,
+        input: SearchResourcesInput,
+    ) -> Result<(SearchResourcesOutput, ResponseMetadata), RusotoError<SearchResourcesError>> {
+        let request = self.build_search_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGroupsOutput, _>()?;
+                .deserialize::<SearchResourcesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SearchResourcesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of AWS resource identifiers that matches a specified query. The query uses the same format as a resource query in a CreateGroup or UpdateGroupQuery operation.</p>
-    async fn search_resources(
+    /// Presigns the request `search_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchResourcesInput,
-    ) -> Result<SearchResourcesOutput, RusotoError<SearchResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_resources_request(&self, input: SearchResourcesInput) -> SignedRequest {
         let request_uri = "/resources/search";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("SearchResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag`, also returning the metadata of the response.
+    pub async fn tag_with_metadata(
+        &self,
+        input: TagInput,
+    ) -> Result<(TagOutput, ResponseMetadata), RusotoError<TagError>> {
+        let request = self.build_tag_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchResourcesOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<TagOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchResourcesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagError::from_response(response))
         }
     }
 
-    /// <p>Adds tags to a resource group with the specified ARN. Existing tags on a resource group are not changed if they are not specified in the request parameters.</p>
-    async fn tag(&self, input: TagInput) -> Result<TagOutput, RusotoError<TagError>> {
+    /// Presigns the request `tag` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: TagInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_request(&self, input: TagInput) -> SignedRequest {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Tag");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag`, also returning the metadata of the response.
+    pub async fn untag_with_metadata(
+        &self,
+        input: UntagInput,
+    ) -> Result<(UntagOutput, ResponseMetadata), RusotoError<UntagError>> {
+        let request = self.build_untag_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<TagOutput, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<UntagOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagError::from_response(response))
         }
     }
 
-    /// <p>Deletes specified tags from a specified resource.</p>
-    async fn untag(&self, input: UntagInput) -> Result<UntagOutput, RusotoError<UntagError>> {
+    /// Presigns the request `untag` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UntagInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_request(&self, input: UntagInput) -> SignedRequest {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request =
             SignedRequest::new("PATCH", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Untag");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_group`, also returning the metadata of the response.
+    pub async fn update_group_with_metadata(
+        &self,
+        input: UpdateGroupInput,
+    ) -> Result<(UpdateGroupOutput, ResponseMetadata), RusotoError<UpdateGroupError>> {
+        let request = self.build_update_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<UntagOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGroupOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateGroupError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing group with a new or changed description. You cannot update the name of a resource group.</p>
-    async fn update_group(
+    /// Presigns the request `update_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGroupInput,
-    ) -> Result<UpdateGroupOutput, RusotoError<UpdateGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_group_request(&self, input: UpdateGroupInput) -> SignedRequest {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_group_query`, also returning the metadata of the response.
+    pub async fn update_group_query_with_metadata(
+        &self,
+        input: UpdateGroupQueryInput,
+    ) -> Result<(UpdateGroupQueryOutput, ResponseMetadata), RusotoError<UpdateGroupQueryError>>
+    {
+        let request = self.build_update_group_query_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGroupOutput, _>()?;
+                .deserialize::<UpdateGroupQueryOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateGroupQueryError::from_response(response))
         }
     }
 
-    /// <p>Updates the resource query of a group.</p>
-    async fn update_group_query(
+    /// Presigns the request `update_group_query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_group_query(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGroupQueryInput,
-    ) -> Result<UpdateGroupQueryOutput, RusotoError<UpdateGroupQueryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_group_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_group_query_request(&self, input: UpdateGroupQueryInput) -> SignedRequest {
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroupQuery");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListGroupResources` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_group_resources_pages(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListGroupResourcesOutput, RusotoError<ListGroupResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsClient, input| async move {
+                client.list_group_resources(input).await
+            },
+            |input: &ListGroupResourcesInput, output: &ListGroupResourcesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGroupResourcesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `ListGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsInput,
+    ) -> impl futures::Stream<Item = Result<ListGroupsOutput, RusotoError<ListGroupsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsClient, input| async move { client.list_groups(input).await },
+            |input: &ListGroupsInput, output: &ListGroupsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGroupsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `SearchResources` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn search_resources_pages(
+        &self,
+        input: SearchResourcesInput,
+    ) -> impl futures::Stream<Item = Result<SearchResourcesOutput, RusotoError<SearchResourcesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsClient, input| async move { client.search_resources(input).await },
+            |input: &SearchResourcesInput, output: &SearchResourcesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchResourcesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceIdentifiers` of every page of `SearchResources` results for `input`, as `search_resources_pages` requests them.
+    pub fn search_resources_stream(
+        &self,
+        input: SearchResourcesInput,
+    ) -> impl futures::Stream<Item = Result<ResourceIdentifier, RusotoError<SearchResourcesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.search_resources_pages(input), |page| {
+            page.resource_identifiers.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ResourceGroups for ResourceGroupsClient {
+    /// <p>Creates a group with a specified name, description, and resource query.</p>
+    async fn create_group(
+        &self,
+        input: CreateGroupInput,
+    ) -> Result<CreateGroupOutput, RusotoError<CreateGroupError>> {
+        self.create_group_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGroupQueryOutput, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGroupQueryError::from_response(response))
-        }
+    /// <p>Deletes a specified resource group. Deleting a resource group does not delete resources that are members of the group; it only deletes the group structure.</p>
+    async fn delete_group(
+        &self,
+        input: DeleteGroupInput,
+    ) -> Result<DeleteGroupOutput, RusotoError<DeleteGroupError>> {
+        self.delete_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specified resource group.</p>
+    async fn get_group(
+        &self,
+        input: GetGroupInput,
+    ) -> Result<GetGroupOutput, RusotoError<GetGroupError>> {
+        self.get_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the resource query associated with the specified resource group.</p>
+    async fn get_group_query(
+        &self,
+        input: GetGroupQueryInput,
+    ) -> Result<GetGroupQueryOutput, RusotoError<GetGroupQueryError>> {
+        self.get_group_query_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of tags that are associated with a resource group, specified by an ARN.</p>
+    async fn get_tags(
+        &self,
+        input: GetTagsInput,
+    ) -> Result<GetTagsOutput, RusotoError<GetTagsError>> {
+        self.get_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of ARNs of resources that are members of a specified resource group.</p>
+    async fn list_group_resources(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> Result<ListGroupResourcesOutput, RusotoError<ListGroupResourcesError>> {
+        self.list_group_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of existing resource groups in your account.</p>
+    async fn list_groups(
+        &self,
+        input: ListGroupsInput,
+    ) -> Result<ListGroupsOutput, RusotoError<ListGroupsError>> {
+        self.list_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of AWS resource identifiers that matches a specified query. The query uses the same format as a resource query in a CreateGroup or UpdateGroupQuery operation.</p>
+    async fn search_resources(
+        &self,
+        input: SearchResourcesInput,
+    ) -> Result<SearchResourcesOutput, RusotoError<SearchResourcesError>> {
+        self.search_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tags to a resource group with the specified ARN. Existing tags on a resource group are not changed if they are not specified in the request parameters.</p>
+    async fn tag(&self, input: TagInput) -> Result<TagOutput, RusotoError<TagError>> {
+        self.tag_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes specified tags from a specified resource.</p>
+    async fn untag(&self, input: UntagInput) -> Result<UntagOutput, RusotoError<UntagError>> {
+        self.untag_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing group with a new or changed description. You cannot update the name of a resource group.</p>
+    async fn update_group(
+        &self,
+        input: UpdateGroupInput,
+    ) -> Result<UpdateGroupOutput, RusotoError<UpdateGroupError>> {
+        self.update_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the resource query of a group.</p>
+    async fn update_group_query(
+        &self,
+        input: UpdateGroupQueryInput,
+    ) -> Result<UpdateGroupQueryOutput, RusotoError<UpdateGroupQueryError>> {
+        self.update_group_query_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -348,6 +348,7 @@ pub struct UntagResourcesOutput {
 
 /// Errors returned by DescribeReportCreation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReportCreationError {
     /// <p><p>The request was denied because performing this operation violates a constraint. </p> <p>Some of the reasons in the following list might not apply to this specific operation.</p> <ul> <li> <p>You must meet the prerequisites for using tag policies. For information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_tag-policies-prereqs.html">Prerequisites and Permissions for Using Tag Policies</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You must enable the tag policies service principal (<code>tagpolicies.tag.amazonaws.com</code>) to integrate with AWS Organizations For information, see <a href="http://docs.aws.amazon.com/organizations/latest/APIReference/API_EnableAWSServiceAccess.html">EnableAWSServiceAccess</a>.</p> </li> <li> <p>You must have a tag policy attached to the organization root, an OU, or an account.</p> </li> </ul></p>
     ConstraintViolation(String),
@@ -357,6 +358,8 @@ pub enum DescribeReportCreationError {
     InvalidParameter(String),
     /// <p>The request was denied to limit the frequency of submitted requests.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReportCreationError {
@@ -364,25 +367,31 @@ impl DescribeReportCreationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DescribeReportCreationError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeReportCreationError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeReportCreationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeReportCreationError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeReportCreationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeReportCreationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(DescribeReportCreationError::Throttled(err.msg))
+                    return RusotoError::service(
+                        DescribeReportCreationError::Throttled(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeReportCreationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -396,12 +405,14 @@ impl fmt::Display for DescribeReportCreationError {
             DescribeReportCreationError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeReportCreationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeReportCreationError::Throttled(ref cause) => write!(f, "{}", cause),
+            DescribeReportCreationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReportCreationError {}
 /// Errors returned by GetComplianceSummary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetComplianceSummaryError {
     /// <p><p>The request was denied because performing this operation violates a constraint. </p> <p>Some of the reasons in the following list might not apply to this specific operation.</p> <ul> <li> <p>You must meet the prerequisites for using tag policies. For information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_tag-policies-prereqs.html">Prerequisites and Permissions for Using Tag Policies</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You must enable the tag policies service principal (<code>tagpolicies.tag.amazonaws.com</code>) to integrate with AWS Organizations For information, see <a href="http://docs.aws.amazon.com/organizations/latest/APIReference/API_EnableAWSServiceAccess.html">EnableAWSServiceAccess</a>.</p> </li> <li> <p>You must have a tag policy attached to the organization root, an OU, or an account.</p> </li> </ul></p>
     ConstraintViolation(String),
@@ -411,6 +422,8 @@ pub enum GetComplianceSummaryError {
     InvalidParameter(String),
     /// <p>The request was denied to limit the frequency of submitted requests.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetComplianceSummaryError {
@@ -418,25 +431,31 @@ impl GetComplianceSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetComplianceSummaryError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetComplianceSummaryError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetComplianceSummaryError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::Throttled(err.msg))
+                    return RusotoError::service(
+                        GetComplianceSummaryError::Throttled(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetComplianceSummaryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -450,12 +469,14 @@ impl fmt::Display for GetComplianceSummaryError {
             GetComplianceSummaryError::InternalService(ref cause) => write!(f, "{}", cause),
             GetComplianceSummaryError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetComplianceSummaryError::Throttled(ref cause) => write!(f, "{}", cause),
+            GetComplianceSummaryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetComplianceSummaryError {}
 /// Errors returned by GetResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourcesError {
     /// <p>The request processing failed because of an unknown error, exception, or failure. You can retry the request.</p>
     InternalService(String),
@@ -465,6 +486,8 @@ pub enum GetResourcesError {
     PaginationTokenExpired(String),
     /// <p>The request was denied to limit the frequency of submitted requests.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourcesError {
@@ -472,19 +495,22 @@ impl GetResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetResourcesError::InternalService(err.msg))
+                    return RusotoError::service(GetResourcesError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetResourcesError::InvalidParameter(err.msg), &res)
                 }
                 "PaginationTokenExpiredException" => {
-                    return RusotoError::Service(GetResourcesError::PaginationTokenExpired(err.msg))
+                    return RusotoError::service(
+                        GetResourcesError::PaginationTokenExpired(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetResourcesError::Throttled(err.msg))
+                    return RusotoError::service(GetResourcesError::Throttled(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -498,12 +524,14 @@ impl fmt::Display for GetResourcesError {
             GetResourcesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetResourcesError::PaginationTokenExpired(ref cause) => write!(f, "{}", cause),
             GetResourcesError::Throttled(ref cause) => write!(f, "{}", cause),
+            GetResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourcesError {}
 /// Errors returned by GetTagKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTagKeysError {
     /// <p>The request processing failed because of an unknown error, exception, or failure. You can retry the request.</p>
     InternalService(String),
@@ -513,6 +541,8 @@ pub enum GetTagKeysError {
     PaginationTokenExpired(String),
     /// <p>The request was denied to limit the frequency of submitted requests.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTagKeysError {
@@ -520,19 +550,22 @@ impl GetTagKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTagKeysError::InternalService(err.msg))
+                    return RusotoError::service(GetTagKeysError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetTagKeysError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetTagKeysError::InvalidParameter(err.msg), &res)
                 }
                 "PaginationTokenExpiredException" => {
-                    return RusotoError::Service(GetTagKeysError::PaginationTokenExpired(err.msg))
+                    return RusotoError::service(
+                        GetTagKeysError::PaginationTokenExpired(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTagKeysError::Throttled(err.msg))
+                    return RusotoError::service(GetTagKeysError::Throttled(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTagKeysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -546,12 +579,14 @@ impl fmt::Display for GetTagKeysError {
             GetTagKeysError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetTagKeysError::PaginationTokenExpired(ref cause) => write!(f, "{}", cause),
             GetTagKeysError::Throttled(ref cause) => write!(f, "{}", cause),
+            GetTagKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTagKeysError {}
 /// Errors returned by GetTagValues
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTagValuesError {
     /// <p>The request processing failed because of an unknown error, exception, or failure. You can retry the request.</p>
     InternalService(String),
@@ -561,6 +596,8 @@ pub enum GetTagValuesError {
     PaginationTokenExpired(String),
     /// <p>The request was denied to limit the frequency of submitted requests.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTagValuesError {
@@ -568,19 +605,22 @@ impl GetTagValuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTagValuesError::InternalService(err.msg))
+                    return RusotoError::service(GetTagValuesError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetTagValuesError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetTagValuesError::InvalidParameter(err.msg), &res)
                 }
                 "PaginationTokenExpiredException" => {
-                    return RusotoError::Service(GetTagValuesError::PaginationTokenExpired(err.msg))
+                    return RusotoError::service(
+                        GetTagValuesError::PaginationTokenExpired(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTagValuesError::Throttled(err.msg))
+                    return RusotoError::service(GetTagValuesError::Throttled(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTagValuesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -594,12 +634,14 @@ impl fmt::Display for GetTagValuesError {
             GetTagValuesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetTagValuesError::PaginationTokenExpired(ref cause) => write!(f, "{}", cause),
             GetTagValuesError::Throttled(ref cause) => write!(f, "{}", cause),
+            GetTagValuesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTagValuesError {}
 /// Errors returned by StartReportCreation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartReportCreationError {
     /// <p>The target of the operation is currently being modified by a different request. Try again later.</p>
     ConcurrentModification(String),
@@ -611,6 +653,8 @@ pub enum StartReportCreationError {
     InvalidParameter(String),
     /// <p>The request was denied to limit the frequency of submitted requests.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartReportCreationError {
@@ -618,28 +662,34 @@ impl StartReportCreationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartReportCreationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartReportCreationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(StartReportCreationError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartReportCreationError::ConstraintViolation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartReportCreationError::InternalService(err.msg))
+                    return RusotoError::service(
+                        StartReportCreationError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartReportCreationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartReportCreationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(StartReportCreationError::Throttled(err.msg))
+                    return RusotoError::service(StartReportCreationError::Throttled(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartReportCreationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -654,12 +704,14 @@ impl fmt::Display for StartReportCreationError {
             StartReportCreationError::InternalService(ref cause) => write!(f, "{}", cause),
             StartReportCreationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             StartReportCreationError::Throttled(ref cause) => write!(f, "{}", cause),
+            StartReportCreationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartReportCreationError {}
 /// Errors returned by TagResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourcesError {
     /// <p>The request processing failed because of an unknown error, exception, or failure. You can retry the request.</p>
     InternalService(String),
@@ -667,6 +719,8 @@ pub enum TagResourcesError {
     InvalidParameter(String),
     /// <p>The request was denied to limit the frequency of submitted requests.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourcesError {
@@ -674,16 +728,16 @@ impl TagResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourcesError::InternalService(err.msg))
+                    return RusotoError::service(TagResourcesError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourcesError::InvalidParameter(err.msg), &res)
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(TagResourcesError::Throttled(err.msg))
+                    return RusotoError::service(TagResourcesError::Throttled(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -696,12 +750,14 @@ impl fmt::Display for TagResourcesError {
             TagResourcesError::InternalService(ref cause) => write!(f, "{}", cause),
             TagResourcesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             TagResourcesError::Throttled(ref cause) => write!(f, "{}", cause),
+            TagResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourcesError {}
 /// Errors returned by UntagResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourcesError {
     /// <p>The request processing failed because of an unknown error, exception, or failure. You can retry the request.</p>
     InternalService(String),
@@ -709,6 +765,8 @@ pub enum UntagResourcesError {
     InvalidParameter(String),
     /// <p>The request was denied to limit the frequency of submitted requests.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourcesError {
@@ -716,16 +774,22 @@ impl UntagResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourcesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UntagResourcesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourcesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(UntagResourcesError::Throttled(err.msg))
+                    return RusotoError::service(UntagResourcesError::Throttled(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -738,6 +802,7 @@ impl fmt::Display for UntagResourcesError {
             UntagResourcesError::InternalService(ref cause) => write!(f, "{}", cause),
             UntagResourcesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UntagResourcesError::Throttled(ref cause) => write!(f, "{}", cause),
+            UntagResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -803,6 +868,7 @@ impl ResourceGroupsTaggingApiClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ResourceGroupsTaggingApiClient {
         ResourceGroupsTaggingApiClient {
             client: Client::shared(),
@@ -831,15 +897,71 @@ impl ResourceGroupsTaggingApiClient {
     ) -> ResourceGroupsTaggingApiClient {
         ResourceGroupsTaggingApiClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ResourceGroupsTaggingApiClient> {
+        rusoto_core::ClientBuilder::new(ResourceGroupsTaggingApiClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(
+        &self,
+        config: &rusoto_core::OperationConfig,
+    ) -> ResourceGroupsTaggingApiClient {
+        ResourceGroupsTaggingApiClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
-    /// <p>Describes the status of the <code>StartReportCreation</code> operation. </p> <p>You can call this operation only from the organization's master account and from the us-east-1 Region.</p>
-    async fn describe_report_creation(
+impl ResourceGroupsTaggingApiClient {
+    /// Calls `describe_report_creation`, also returning the metadata of the response.
+    pub async fn describe_report_creation_with_metadata(
         &self,
-    ) -> Result<DescribeReportCreationOutput, RusotoError<DescribeReportCreationError>> {
+    ) -> Result<
+        (DescribeReportCreationOutput, ResponseMetadata),
+        RusotoError<DescribeReportCreationError>,
+    > {
+        let request = self.build_describe_report_creation_request();
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeReportCreationOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeReportCreationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `describe_report_creation` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_report_creation(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_report_creation_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_report_creation_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("DescribeReportCreation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -848,28 +970,58 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_compliance_summary`, also returning the metadata of the response.
+    pub async fn get_compliance_summary_with_metadata(
+        &self,
+        input: GetComplianceSummaryInput,
+    ) -> Result<
+        (GetComplianceSummaryOutput, ResponseMetadata),
+        RusotoError<GetComplianceSummaryError>,
+    > {
+        let request = self.build_get_compliance_summary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReportCreationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetComplianceSummaryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReportCreationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetComplianceSummaryError::from_response(response))
         }
     }
 
-    /// <p>Returns a table that shows counts of resources that are noncompliant with their tag policies.</p> <p>For more information on tag policies, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_tag-policies.html">Tag Policies</a> in the <i>AWS Organizations User Guide.</i> </p> <p>You can call this operation only from the organization's master account and from the us-east-1 Region.</p>
-    async fn get_compliance_summary(
+    /// Presigns the request `get_compliance_summary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_compliance_summary(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetComplianceSummaryInput,
-    ) -> Result<GetComplianceSummaryOutput, RusotoError<GetComplianceSummaryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_compliance_summary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_compliance_summary_request(
+        &self,
+        input: GetComplianceSummaryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetComplianceSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -879,28 +1031,52 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resources`, also returning the metadata of the response.
+    pub async fn get_resources_with_metadata(
+        &self,
+        input: GetResourcesInput,
+    ) -> Result<(GetResourcesOutput, ResponseMetadata), RusotoError<GetResourcesError>> {
+        let request = self.build_get_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetComplianceSummaryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResourcesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetComplianceSummaryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetResourcesError::from_response(response))
         }
     }
 
-    /// <p><p>Returns all the tagged or previously tagged resources that are located in the specified Region for the AWS account.</p> <p>Depending on what information you want returned, you can also specify the following:</p> <ul> <li> <p> <i>Filters</i> that specify what tags and resource types you want returned. The response includes all tags that are associated with the requested resources.</p> </li> <li> <p>Information about compliance with the account&#39;s effective tag policy. For more information on tag policies, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_tag-policies.html">Tag Policies</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <note> <p>You can check the <code>PaginationToken</code> response parameter to determine if a query is complete. Queries occasionally return fewer results on a page than allowed. The <code>PaginationToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display. </p> </note></p>
-    async fn get_resources(
+    /// Presigns the request `get_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourcesInput,
-    ) -> Result<GetResourcesOutput, RusotoError<GetResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resources_request(&self, input: GetResourcesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -910,27 +1086,52 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_tag_keys`, also returning the metadata of the response.
+    pub async fn get_tag_keys_with_metadata(
+        &self,
+        input: GetTagKeysInput,
+    ) -> Result<(GetTagKeysOutput, ResponseMetadata), RusotoError<GetTagKeysError>> {
+        let request = self.build_get_tag_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetResourcesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTagKeysOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTagKeysError::from_response(response))
         }
     }
 
-    /// <p>Returns all tag keys in the specified Region for the AWS account.</p>
-    async fn get_tag_keys(
+    /// Presigns the request `get_tag_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_tag_keys(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTagKeysInput,
-    ) -> Result<GetTagKeysOutput, RusotoError<GetTagKeysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_tag_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_tag_keys_request(&self, input: GetTagKeysInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetTagKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -940,27 +1141,52 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_tag_values`, also returning the metadata of the response.
+    pub async fn get_tag_values_with_metadata(
+        &self,
+        input: GetTagValuesInput,
+    ) -> Result<(GetTagValuesOutput, ResponseMetadata), RusotoError<GetTagValuesError>> {
+        let request = self.build_get_tag_values_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTagKeysOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTagValuesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTagKeysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTagValuesError::from_response(response))
         }
     }
 
-    /// <p>Returns all tag values for the specified key in the specified Region for the AWS account.</p>
-    async fn get_tag_values(
+    /// Presigns the request `get_tag_values` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_tag_values(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTagValuesInput,
-    ) -> Result<GetTagValuesOutput, RusotoError<GetTagValuesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_tag_values_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_tag_values_request(&self, input: GetTagValuesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetTagValues");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -970,27 +1196,56 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_report_creation`, also returning the metadata of the response.
+    pub async fn start_report_creation_with_metadata(
+        &self,
+        input: StartReportCreationInput,
+    ) -> Result<(StartReportCreationOutput, ResponseMetadata), RusotoError<StartReportCreationError>>
+    {
+        let request = self.build_start_report_creation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTagValuesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartReportCreationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTagValuesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartReportCreationError::from_response(response))
         }
     }
 
-    /// <p>Generates a report that lists all tagged resources in accounts across your organization and tells whether each resource is compliant with the effective tag policy. Compliance data is refreshed daily. </p> <p>The generated report is saved to the following location:</p> <p> <code>s3://example-bucket/AwsTagPolicies/o-exampleorgid/YYYY-MM-ddTHH:mm:ssZ/report.csv</code> </p> <p>You can call this operation only from the organization's master account and from the us-east-1 Region.</p>
-    async fn start_report_creation(
+    /// Presigns the request `start_report_creation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_report_creation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartReportCreationInput,
-    ) -> Result<StartReportCreationOutput, RusotoError<StartReportCreationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_report_creation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_report_creation_request(
+        &self,
+        input: StartReportCreationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("StartReportCreation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1000,28 +1255,52 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resources`, also returning the metadata of the response.
+    pub async fn tag_resources_with_metadata(
+        &self,
+        input: TagResourcesInput,
+    ) -> Result<(TagResourcesOutput, ResponseMetadata), RusotoError<TagResourcesError>> {
+        let request = self.build_tag_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartReportCreationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourcesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartReportCreationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourcesError::from_response(response))
         }
     }
 
-    /// <p><p>Applies one or more tags to the specified resources. Note the following:</p> <ul> <li> <p>Not all resources can have tags. For a list of services that support tagging, see <a href="http://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/Welcome.html">this list</a>.</p> </li> <li> <p>Each resource can have up to 50 tags. For other limits, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_tagging.html#tag-conventions">Tag Naming and Usage Conventions</a> in the <i>AWS General Reference.</i> </p> </li> <li> <p>You can only tag resources that are located in the specified Region for the AWS account.</p> </li> <li> <p>To add tags to a resource, you need the necessary permissions for the service that the resource belongs to as well as permissions for adding tags. For more information, see <a href="http://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/Welcome.html">this list</a>.</p> </li> </ul></p>
-    async fn tag_resources(
+    /// Presigns the request `tag_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourcesInput,
-    ) -> Result<TagResourcesOutput, RusotoError<TagResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resources_request(&self, input: TagResourcesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("TagResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1031,27 +1310,52 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resources`, also returning the metadata of the response.
+    pub async fn untag_resources_with_metadata(
+        &self,
+        input: UntagResourcesInput,
+    ) -> Result<(UntagResourcesOutput, ResponseMetadata), RusotoError<UntagResourcesError>> {
+        let request = self.build_untag_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourcesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourcesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourcesError::from_response(response))
         }
     }
 
-    /// <p><p>Removes the specified tags from the specified resources. When you specify a tag key, the action removes both that key and its associated value. The operation succeeds even if you attempt to remove tags from a resource that were already removed. Note the following:</p> <ul> <li> <p>To remove tags from a resource, you need the necessary permissions for the service that the resource belongs to as well as permissions for removing tags. For more information, see <a href="http://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/Welcome.html">this list</a>.</p> </li> <li> <p>You can only tag resources that are located in the specified Region for the AWS account.</p> </li> </ul></p>
-    async fn untag_resources(
+    /// Presigns the request `untag_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourcesInput,
-    ) -> Result<UntagResourcesOutput, RusotoError<UntagResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resources_request(&self, input: UntagResourcesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("UntagResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1061,18 +1365,237 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetComplianceSummary` results for `input`, requesting each next page with the `PaginationToken` of the last one as its `PaginationToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_compliance_summary_pages(
+        &self,
+        input: GetComplianceSummaryInput,
+    ) -> impl futures::Stream<
+        Item = Result<GetComplianceSummaryOutput, RusotoError<GetComplianceSummaryError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsTaggingApiClient, input| async move {
+                client.get_compliance_summary(input).await
+            },
+            |input: &GetComplianceSummaryInput, output: &GetComplianceSummaryOutput| {
+                let pagination_token = output.pagination_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if pagination_token.is_none() || pagination_token == input.pagination_token {
+                    return None;
+                }
+                Some(GetComplianceSummaryInput {
+                    pagination_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SummaryList` of every page of `GetComplianceSummary` results for `input`, as `get_compliance_summary_pages` requests them.
+    pub fn get_compliance_summary_stream(
+        &self,
+        input: GetComplianceSummaryInput,
+    ) -> impl futures::Stream<Item = Result<Summary, RusotoError<GetComplianceSummaryError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_compliance_summary_pages(input), |page| {
+            page.summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetResources` results for `input`, requesting each next page with the `PaginationToken` of the last one as its `PaginationToken`.
+    /// The page size is the `ResourcesPerPage` of `input`.
+    pub fn get_resources_pages(
+        &self,
+        input: GetResourcesInput,
+    ) -> impl futures::Stream<Item = Result<GetResourcesOutput, RusotoError<GetResourcesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsTaggingApiClient, input| async move {
+                client.get_resources(input).await
+            },
+            |input: &GetResourcesInput, output: &GetResourcesOutput| {
+                let pagination_token = output.pagination_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if pagination_token.is_none() || pagination_token == input.pagination_token {
+                    return None;
+                }
+                Some(GetResourcesInput {
+                    pagination_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceTagMappingList` of every page of `GetResources` results for `input`, as `get_resources_pages` requests them.
+    pub fn get_resources_stream(
+        &self,
+        input: GetResourcesInput,
+    ) -> impl futures::Stream<Item = Result<ResourceTagMapping, RusotoError<GetResourcesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_resources_pages(input), |page| {
+            page.resource_tag_mapping_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetTagKeys` results for `input`, requesting each next page with the `PaginationToken` of the last one as its `PaginationToken`.
+    pub fn get_tag_keys_pages(
+        &self,
+        input: GetTagKeysInput,
+    ) -> impl futures::Stream<Item = Result<GetTagKeysOutput, RusotoError<GetTagKeysError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsTaggingApiClient, input| async move {
+                client.get_tag_keys(input).await
+            },
+            |input: &GetTagKeysInput, output: &GetTagKeysOutput| {
+                let pagination_token = output.pagination_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if pagination_token.is_none() || pagination_token == input.pagination_token {
+                    return None;
+                }
+                Some(GetTagKeysInput {
+                    pagination_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TagKeys` of every page of `GetTagKeys` results for `input`, as `get_tag_keys_pages` requests them.
+    pub fn get_tag_keys_stream(
+        &self,
+        input: GetTagKeysInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<GetTagKeysError>>> + Send {
+        rusoto_core::pagination::items(self.get_tag_keys_pages(input), |page| {
+            page.tag_keys.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetTagValues` results for `input`, requesting each next page with the `PaginationToken` of the last one as its `PaginationToken`.
+    pub fn get_tag_values_pages(
+        &self,
+        input: GetTagValuesInput,
+    ) -> impl futures::Stream<Item = Result<GetTagValuesOutput, RusotoError<GetTagValuesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ResourceGroupsTaggingApiClient, input| async move {
+                client.get_tag_values(input).await
+            },
+            |input: &GetTagValuesInput, output: &GetTagValuesOutput| {
+                let pagination_token = output.pagination_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if pagination_token.is_none() || pagination_token == input.pagination_token {
+                    return None;
+                }
+                Some(GetTagValuesInput {
+                    pagination_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TagValues` of every page of `GetTagValues` results for `input`, as `get_tag_values_pages` requests them.
+    pub fn get_tag_values_stream(
+        &self,
+        input: GetTagValuesInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<GetTagValuesError>>> + Send {
+        rusoto_core::pagination::items(self.get_tag_values_pages(input), |page| {
+            page.tag_values.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
+    /// <p>Describes the status of the <code>StartReportCreation</code> operation. </p> <p>You can call this operation only from the organization's master account and from the us-east-1 Region.</p>
+    async fn describe_report_creation(
+        &self,
+    ) -> Result<DescribeReportCreationOutput, RusotoError<DescribeReportCreationError>> {
+        self.describe_report_creation_with_metadata()
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourcesOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourcesError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a table that shows counts of resources that are noncompliant with their tag policies.</p> <p>For more information on tag policies, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_tag-policies.html">Tag Policies</a> in the <i>AWS Organizations User Guide.</i> </p> <p>You can call this operation only from the organization's master account and from the us-east-1 Region.</p>
+    async fn get_compliance_summary(
+        &self,
+        input: GetComplianceSummaryInput,
+    ) -> Result<GetComplianceSummaryOutput, RusotoError<GetComplianceSummaryError>> {
+        self.get_compliance_summary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns all the tagged or previously tagged resources that are located in the specified Region for the AWS account.</p> <p>Depending on what information you want returned, you can also specify the following:</p> <ul> <li> <p> <i>Filters</i> that specify what tags and resource types you want returned. The response includes all tags that are associated with the requested resources.</p> </li> <li> <p>Information about compliance with the account&#39;s effective tag policy. For more information on tag policies, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_tag-policies.html">Tag Policies</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <note> <p>You can check the <code>PaginationToken</code> response parameter to determine if a query is complete. Queries occasionally return fewer results on a page than allowed. The <code>PaginationToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display. </p> </note></p>
+    async fn get_resources(
+        &self,
+        input: GetResourcesInput,
+    ) -> Result<GetResourcesOutput, RusotoError<GetResourcesError>> {
+        self.get_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all tag keys in the specified Region for the AWS account.</p>
+    async fn get_tag_keys(
+        &self,
+        input: GetTagKeysInput,
+    ) -> Result<GetTagKeysOutput, RusotoError<GetTagKeysError>> {
+        self.get_tag_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all tag values for the specified key in the specified Region for the AWS account.</p>
+    async fn get_tag_values(
+        &self,
+        input: GetTagValuesInput,
+    ) -> Result<GetTagValuesOutput, RusotoError<GetTagValuesError>> {
+        self.get_tag_values_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a report that lists all tagged resources in accounts across your organization and tells whether each resource is compliant with the effective tag policy. Compliance data is refreshed daily. </p> <p>The generated report is saved to the following location:</p> <p> <code>s3://example-bucket/AwsTagPolicies/o-exampleorgid/YYYY-MM-ddTHH:mm:ssZ/report.csv</code> </p> <p>You can call this operation only from the organization's master account and from the us-east-1 Region.</p>
+    async fn start_report_creation(
+        &self,
+        input: StartReportCreationInput,
+    ) -> Result<StartReportCreationOutput, RusotoError<StartReportCreationError>> {
+        self.start_report_creation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Applies one or more tags to the specified resources. Note the following:</p> <ul> <li> <p>Not all resources can have tags. For a list of services that support tagging, see <a href="http://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/Welcome.html">this list</a>.</p> </li> <li> <p>Each resource can have up to 50 tags. For other limits, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_tagging.html#tag-conventions">Tag Naming and Usage Conventions</a> in the <i>AWS General Reference.</i> </p> </li> <li> <p>You can only tag resources that are located in the specified Region for the AWS account.</p> </li> <li> <p>To add tags to a resource, you need the necessary permissions for the service that the resource belongs to as well as permissions for adding tags. For more information, see <a href="http://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/Welcome.html">this list</a>.</p> </li> </ul></p>
+    async fn tag_resources(
+        &self,
+        input: TagResourcesInput,
+    ) -> Result<TagResourcesOutput, RusotoError<TagResourcesError>> {
+        self.tag_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes the specified tags from the specified resources. When you specify a tag key, the action removes both that key and its associated value. The operation succeeds even if you attempt to remove tags from a resource that were already removed. Note the following:</p> <ul> <li> <p>To remove tags from a resource, you need the necessary permissions for the service that the resource belongs to as well as permissions for removing tags. For more information, see <a href="http://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/Welcome.html">this list</a>.</p> </li> <li> <p>You can only tag resources that are located in the specified Region for the AWS account.</p> </li> </ul></p>
+    async fn untag_resources(
+        &self,
+        input: UntagResourcesInput,
+    ) -> Result<UntagResourcesOutput, RusotoError<UntagResourcesError>> {
+        self.untag_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
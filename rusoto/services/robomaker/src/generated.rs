@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2129,6 +2129,7 @@ pub struct VPCConfigResponse {
 
 /// Errors returned by BatchDescribeSimulationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDescribeSimulationJobError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -2138,6 +2139,8 @@ pub enum BatchDescribeSimulationJobError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDescribeSimulationJobError {
@@ -2147,27 +2150,33 @@ impl BatchDescribeSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDescribeSimulationJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDescribeSimulationJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDescribeSimulationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDescribeSimulationJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(BatchDescribeSimulationJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2181,12 +2190,14 @@ impl fmt::Display for BatchDescribeSimulationJobError {
             BatchDescribeSimulationJobError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             BatchDescribeSimulationJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             BatchDescribeSimulationJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            BatchDescribeSimulationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDescribeSimulationJobError {}
 /// Errors returned by CancelDeploymentJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelDeploymentJobError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -2196,6 +2207,8 @@ pub enum CancelDeploymentJobError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelDeploymentJobError {
@@ -2203,23 +2216,31 @@ impl CancelDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CancelDeploymentJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelDeploymentJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelDeploymentJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CancelDeploymentJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelDeploymentJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2233,12 +2254,14 @@ impl fmt::Display for CancelDeploymentJobError {
             CancelDeploymentJobError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CancelDeploymentJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CancelDeploymentJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            CancelDeploymentJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelDeploymentJobError {}
 /// Errors returned by CancelSimulationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelSimulationJobError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -2248,6 +2271,8 @@ pub enum CancelSimulationJobError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelSimulationJobError {
@@ -2255,23 +2280,31 @@ impl CancelSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelSimulationJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CancelSimulationJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelSimulationJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelSimulationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CancelSimulationJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelSimulationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2285,12 +2318,14 @@ impl fmt::Display for CancelSimulationJobError {
             CancelSimulationJobError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CancelSimulationJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CancelSimulationJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            CancelSimulationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelSimulationJobError {}
 /// Errors returned by CreateDeploymentJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentJobError {
     /// <p>The failure percentage threshold percentage was met.</p>
     ConcurrentDeployment(String),
@@ -2306,6 +2341,8 @@ pub enum CreateDeploymentJobError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentJobError {
@@ -2313,36 +2350,49 @@ impl CreateDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentDeploymentException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::ConcurrentDeployment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentJobError::ConcurrentDeployment(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentJobError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2361,12 +2411,14 @@ impl fmt::Display for CreateDeploymentJobError {
             CreateDeploymentJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDeploymentJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateDeploymentJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDeploymentJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentJobError {}
 /// Errors returned by CreateFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFleetError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -2376,6 +2428,8 @@ pub enum CreateFleetError {
     LimitExceeded(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFleetError {
@@ -2383,19 +2437,19 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateFleetError::InternalServer(err.msg))
+                    return RusotoError::service(CreateFleetError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateFleetError::InvalidParameter(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateFleetError::LimitExceeded(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateFleetError::Throttling(err.msg))
+                    return RusotoError::service(CreateFleetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2409,12 +2463,14 @@ impl fmt::Display for CreateFleetError {
             CreateFleetError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateFleetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateFleetError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFleetError {}
 /// Errors returned by CreateRobot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRobotError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -2426,6 +2482,8 @@ pub enum CreateRobotError {
     ResourceAlreadyExists(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRobotError {
@@ -2433,22 +2491,25 @@ impl CreateRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRobotError::InternalServer(err.msg))
+                    return RusotoError::service(CreateRobotError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateRobotError::InvalidParameter(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRobotError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateRobotError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateRobotError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateRobotError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRobotError::Throttling(err.msg))
+                    return RusotoError::service(CreateRobotError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRobotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2463,12 +2524,14 @@ impl fmt::Display for CreateRobotError {
             CreateRobotError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateRobotError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateRobotError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateRobotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRobotError {}
 /// Errors returned by CreateRobotApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRobotApplicationError {
     /// <p>The request uses the same client token as a previous, but non-identical request. Do not reuse a client token with different requests, unless the requests are identical. </p>
     IdempotentParameterMismatch(String),
@@ -2482,6 +2545,8 @@ pub enum CreateRobotApplicationError {
     ResourceAlreadyExists(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRobotApplicationError {
@@ -2489,35 +2554,43 @@ impl CreateRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRobotApplicationError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRobotApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRobotApplicationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRobotApplicationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRobotApplicationError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateRobotApplicationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRobotApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2535,12 +2608,14 @@ impl fmt::Display for CreateRobotApplicationError {
             CreateRobotApplicationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateRobotApplicationError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateRobotApplicationError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateRobotApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRobotApplicationError {}
 /// Errors returned by CreateRobotApplicationVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRobotApplicationVersionError {
     /// <p>The request uses the same client token as a previous, but non-identical request. Do not reuse a client token with different requests, unless the requests are identical. </p>
     IdempotentParameterMismatch(String),
@@ -2552,6 +2627,8 @@ pub enum CreateRobotApplicationVersionError {
     LimitExceeded(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRobotApplicationVersionError {
@@ -2561,32 +2638,42 @@ impl CreateRobotApplicationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRobotApplicationVersionError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRobotApplicationVersionError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRobotApplicationVersionError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRobotApplicationVersionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRobotApplicationVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRobotApplicationVersionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRobotApplicationVersionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateRobotApplicationVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2605,12 +2692,16 @@ impl fmt::Display for CreateRobotApplicationVersionError {
             }
             CreateRobotApplicationVersionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateRobotApplicationVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateRobotApplicationVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateRobotApplicationVersionError {}
 /// Errors returned by CreateSimulationApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSimulationApplicationError {
     /// <p>The request uses the same client token as a previous, but non-identical request. Do not reuse a client token with different requests, unless the requests are identical. </p>
     IdempotentParameterMismatch(String),
@@ -2624,6 +2715,8 @@ pub enum CreateSimulationApplicationError {
     ResourceAlreadyExists(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSimulationApplicationError {
@@ -2633,37 +2726,48 @@ impl CreateSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSimulationApplicationError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSimulationApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSimulationApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSimulationApplicationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSimulationApplicationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSimulationApplicationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSimulationApplicationError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSimulationApplicationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSimulationApplicationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateSimulationApplicationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2683,12 +2787,14 @@ impl fmt::Display for CreateSimulationApplicationError {
                 write!(f, "{}", cause)
             }
             CreateSimulationApplicationError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateSimulationApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSimulationApplicationError {}
 /// Errors returned by CreateSimulationApplicationVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSimulationApplicationVersionError {
     /// <p>The request uses the same client token as a previous, but non-identical request. Do not reuse a client token with different requests, unless the requests are identical. </p>
     IdempotentParameterMismatch(String),
@@ -2700,6 +2806,8 @@ pub enum CreateSimulationApplicationVersionError {
     LimitExceeded(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSimulationApplicationVersionError {
@@ -2709,34 +2817,44 @@ impl CreateSimulationApplicationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSimulationApplicationVersionError::IdempotentParameterMismatch(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSimulationApplicationVersionError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSimulationApplicationVersionError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSimulationApplicationVersionError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSimulationApplicationVersionError::Throttling(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateSimulationApplicationVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2761,12 +2879,16 @@ impl fmt::Display for CreateSimulationApplicationVersionError {
             CreateSimulationApplicationVersionError::Throttling(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateSimulationApplicationVersionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateSimulationApplicationVersionError {}
 /// Errors returned by CreateSimulationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSimulationJobError {
     /// <p>The request uses the same client token as a previous, but non-identical request. Do not reuse a client token with different requests, unless the requests are identical. </p>
     IdempotentParameterMismatch(String),
@@ -2782,6 +2904,8 @@ pub enum CreateSimulationJobError {
     ServiceUnavailable(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSimulationJobError {
@@ -2789,36 +2913,49 @@ impl CreateSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSimulationJobError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSimulationJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateSimulationJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSimulationJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSimulationJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateSimulationJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSimulationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateSimulationJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSimulationJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateSimulationJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSimulationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2837,12 +2974,14 @@ impl fmt::Display for CreateSimulationJobError {
             CreateSimulationJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateSimulationJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateSimulationJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateSimulationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSimulationJobError {}
 /// Errors returned by DeleteFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFleetError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -2850,6 +2989,8 @@ pub enum DeleteFleetError {
     InvalidParameter(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFleetError {
@@ -2857,16 +2998,16 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteFleetError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteFleetError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteFleetError::InvalidParameter(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFleetError::Throttling(err.msg))
+                    return RusotoError::service(DeleteFleetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2879,12 +3020,14 @@ impl fmt::Display for DeleteFleetError {
             DeleteFleetError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteFleetError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteFleetError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFleetError {}
 /// Errors returned by DeleteRobot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRobotError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -2892,6 +3035,8 @@ pub enum DeleteRobotError {
     InvalidParameter(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRobotError {
@@ -2899,16 +3044,16 @@ impl DeleteRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRobotError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteRobotError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteRobotError::InvalidParameter(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRobotError::Throttling(err.msg))
+                    return RusotoError::service(DeleteRobotError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRobotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2921,12 +3066,14 @@ impl fmt::Display for DeleteRobotError {
             DeleteRobotError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteRobotError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteRobotError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteRobotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRobotError {}
 /// Errors returned by DeleteRobotApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRobotApplicationError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -2934,6 +3081,8 @@ pub enum DeleteRobotApplicationError {
     InvalidParameter(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRobotApplicationError {
@@ -2941,20 +3090,25 @@ impl DeleteRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRobotApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRobotApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRobotApplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRobotApplicationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteRobotApplicationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRobotApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2967,12 +3121,14 @@ impl fmt::Display for DeleteRobotApplicationError {
             DeleteRobotApplicationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteRobotApplicationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteRobotApplicationError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteRobotApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRobotApplicationError {}
 /// Errors returned by DeleteSimulationApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSimulationApplicationError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -2980,6 +3136,8 @@ pub enum DeleteSimulationApplicationError {
     InvalidParameter(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSimulationApplicationError {
@@ -2989,22 +3147,30 @@ impl DeleteSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSimulationApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSimulationApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteSimulationApplicationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSimulationApplicationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSimulationApplicationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteSimulationApplicationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3017,12 +3183,14 @@ impl fmt::Display for DeleteSimulationApplicationError {
             DeleteSimulationApplicationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteSimulationApplicationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteSimulationApplicationError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteSimulationApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSimulationApplicationError {}
 /// Errors returned by DeregisterRobot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterRobotError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3032,6 +3200,8 @@ pub enum DeregisterRobotError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterRobotError {
@@ -3039,19 +3209,28 @@ impl DeregisterRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeregisterRobotError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeregisterRobotError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeregisterRobotError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterRobotError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeregisterRobotError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeregisterRobotError::Throttling(err.msg))
+                    return RusotoError::service(DeregisterRobotError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterRobotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3065,12 +3244,14 @@ impl fmt::Display for DeregisterRobotError {
             DeregisterRobotError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeregisterRobotError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeregisterRobotError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeregisterRobotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterRobotError {}
 /// Errors returned by DescribeDeploymentJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDeploymentJobError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3080,6 +3261,8 @@ pub enum DescribeDeploymentJobError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDeploymentJobError {
@@ -3087,25 +3270,31 @@ impl DescribeDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDeploymentJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDeploymentJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDeploymentJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeDeploymentJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDeploymentJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3119,12 +3308,14 @@ impl fmt::Display for DescribeDeploymentJobError {
             DescribeDeploymentJobError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeDeploymentJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDeploymentJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDeploymentJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDeploymentJobError {}
 /// Errors returned by DescribeFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFleetError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3134,6 +3325,8 @@ pub enum DescribeFleetError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFleetError {
@@ -3141,19 +3334,25 @@ impl DescribeFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeFleetError::InternalServer(err.msg))
+                    return RusotoError::service(DescribeFleetError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeFleetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeFleetError::Throttling(err.msg))
+                    return RusotoError::service(DescribeFleetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3167,12 +3366,14 @@ impl fmt::Display for DescribeFleetError {
             DescribeFleetError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeFleetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeFleetError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFleetError {}
 /// Errors returned by DescribeRobot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRobotError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3182,6 +3383,8 @@ pub enum DescribeRobotError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRobotError {
@@ -3189,19 +3392,25 @@ impl DescribeRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeRobotError::InternalServer(err.msg))
+                    return RusotoError::service(DescribeRobotError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeRobotError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRobotError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeRobotError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRobotError::Throttling(err.msg))
+                    return RusotoError::service(DescribeRobotError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRobotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3215,12 +3424,14 @@ impl fmt::Display for DescribeRobotError {
             DescribeRobotError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeRobotError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeRobotError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeRobotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRobotError {}
 /// Errors returned by DescribeRobotApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRobotApplicationError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3230,6 +3441,8 @@ pub enum DescribeRobotApplicationError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRobotApplicationError {
@@ -3237,25 +3450,31 @@ impl DescribeRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRobotApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRobotApplicationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRobotApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeRobotApplicationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRobotApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3269,12 +3488,14 @@ impl fmt::Display for DescribeRobotApplicationError {
             DescribeRobotApplicationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeRobotApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeRobotApplicationError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeRobotApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRobotApplicationError {}
 /// Errors returned by DescribeSimulationApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSimulationApplicationError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3284,6 +3505,8 @@ pub enum DescribeSimulationApplicationError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSimulationApplicationError {
@@ -3293,27 +3516,36 @@ impl DescribeSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSimulationApplicationError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSimulationApplicationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSimulationApplicationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeSimulationApplicationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSimulationApplicationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeSimulationApplicationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3331,12 +3563,16 @@ impl fmt::Display for DescribeSimulationApplicationError {
                 write!(f, "{}", cause)
             }
             DescribeSimulationApplicationError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeSimulationApplicationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeSimulationApplicationError {}
 /// Errors returned by DescribeSimulationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSimulationJobError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3346,6 +3582,8 @@ pub enum DescribeSimulationJobError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSimulationJobError {
@@ -3353,25 +3591,31 @@ impl DescribeSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSimulationJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSimulationJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSimulationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeSimulationJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSimulationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3385,12 +3629,14 @@ impl fmt::Display for DescribeSimulationJobError {
             DescribeSimulationJobError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeSimulationJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeSimulationJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeSimulationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSimulationJobError {}
 /// Errors returned by ListDeploymentJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeploymentJobsError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3400,6 +3646,8 @@ pub enum ListDeploymentJobsError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeploymentJobsError {
@@ -3407,19 +3655,28 @@ impl ListDeploymentJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListDeploymentJobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListDeploymentJobsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListDeploymentJobsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::Throttling(err.msg))
+                    return RusotoError::service(ListDeploymentJobsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeploymentJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3433,12 +3690,14 @@ impl fmt::Display for ListDeploymentJobsError {
             ListDeploymentJobsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListDeploymentJobsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDeploymentJobsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDeploymentJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeploymentJobsError {}
 /// Errors returned by ListFleets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFleetsError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3448,6 +3707,8 @@ pub enum ListFleetsError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFleetsError {
@@ -3455,19 +3716,19 @@ impl ListFleetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFleetsError::InternalServer(err.msg))
+                    return RusotoError::service(ListFleetsError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListFleetsError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListFleetsError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFleetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListFleetsError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFleetsError::Throttling(err.msg))
+                    return RusotoError::service(ListFleetsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFleetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3481,12 +3742,14 @@ impl fmt::Display for ListFleetsError {
             ListFleetsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListFleetsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListFleetsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListFleetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFleetsError {}
 /// Errors returned by ListRobotApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRobotApplicationsError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3494,6 +3757,8 @@ pub enum ListRobotApplicationsError {
     InvalidParameter(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRobotApplicationsError {
@@ -3501,20 +3766,25 @@ impl ListRobotApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRobotApplicationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRobotApplicationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRobotApplicationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRobotApplicationsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRobotApplicationsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListRobotApplicationsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRobotApplicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3527,12 +3797,14 @@ impl fmt::Display for ListRobotApplicationsError {
             ListRobotApplicationsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListRobotApplicationsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListRobotApplicationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListRobotApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRobotApplicationsError {}
 /// Errors returned by ListRobots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRobotsError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3542,6 +3814,8 @@ pub enum ListRobotsError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRobotsError {
@@ -3549,19 +3823,19 @@ impl ListRobotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRobotsError::InternalServer(err.msg))
+                    return RusotoError::service(ListRobotsError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRobotsError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListRobotsError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRobotsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListRobotsError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRobotsError::Throttling(err.msg))
+                    return RusotoError::service(ListRobotsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRobotsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3575,12 +3849,14 @@ impl fmt::Display for ListRobotsError {
             ListRobotsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListRobotsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListRobotsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListRobotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRobotsError {}
 /// Errors returned by ListSimulationApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSimulationApplicationsError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3588,6 +3864,8 @@ pub enum ListSimulationApplicationsError {
     InvalidParameter(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSimulationApplicationsError {
@@ -3597,22 +3875,27 @@ impl ListSimulationApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSimulationApplicationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSimulationApplicationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSimulationApplicationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSimulationApplicationsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSimulationApplicationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSimulationApplicationsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListSimulationApplicationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3625,12 +3908,14 @@ impl fmt::Display for ListSimulationApplicationsError {
             ListSimulationApplicationsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListSimulationApplicationsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListSimulationApplicationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListSimulationApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSimulationApplicationsError {}
 /// Errors returned by ListSimulationJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSimulationJobsError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3638,6 +3923,8 @@ pub enum ListSimulationJobsError {
     InvalidParameter(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSimulationJobsError {
@@ -3645,16 +3932,22 @@ impl ListSimulationJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSimulationJobsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListSimulationJobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSimulationJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListSimulationJobsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSimulationJobsError::Throttling(err.msg))
+                    return RusotoError::service(ListSimulationJobsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSimulationJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3667,12 +3960,14 @@ impl fmt::Display for ListSimulationJobsError {
             ListSimulationJobsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListSimulationJobsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListSimulationJobsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListSimulationJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSimulationJobsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3682,6 +3977,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3689,23 +3986,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3719,12 +4024,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by RegisterRobot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterRobotError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3736,6 +4043,8 @@ pub enum RegisterRobotError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterRobotError {
@@ -3743,22 +4052,28 @@ impl RegisterRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RegisterRobotError::InternalServer(err.msg))
+                    return RusotoError::service(RegisterRobotError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        RegisterRobotError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterRobotError::LimitExceeded(err.msg))
+                    return RusotoError::service(RegisterRobotError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterRobotError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RegisterRobotError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterRobotError::Throttling(err.msg))
+                    return RusotoError::service(RegisterRobotError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterRobotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3773,12 +4088,14 @@ impl fmt::Display for RegisterRobotError {
             RegisterRobotError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             RegisterRobotError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RegisterRobotError::Throttling(ref cause) => write!(f, "{}", cause),
+            RegisterRobotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterRobotError {}
 /// Errors returned by RestartSimulationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestartSimulationJobError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3790,6 +4107,8 @@ pub enum RestartSimulationJobError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestartSimulationJobError {
@@ -3797,26 +4116,37 @@ impl RestartSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RestartSimulationJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        RestartSimulationJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RestartSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestartSimulationJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RestartSimulationJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        RestartSimulationJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestartSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestartSimulationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RestartSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        RestartSimulationJobError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RestartSimulationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3831,12 +4161,14 @@ impl fmt::Display for RestartSimulationJobError {
             RestartSimulationJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             RestartSimulationJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RestartSimulationJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            RestartSimulationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestartSimulationJobError {}
 /// Errors returned by SyncDeploymentJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SyncDeploymentJobError {
     /// <p>The failure percentage threshold percentage was met.</p>
     ConcurrentDeployment(String),
@@ -3852,6 +4184,8 @@ pub enum SyncDeploymentJobError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SyncDeploymentJobError {
@@ -3859,32 +4193,46 @@ impl SyncDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentDeploymentException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::ConcurrentDeployment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SyncDeploymentJobError::ConcurrentDeployment(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SyncDeploymentJobError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        SyncDeploymentJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        SyncDeploymentJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        SyncDeploymentJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SyncDeploymentJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service(SyncDeploymentJobError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SyncDeploymentJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3903,12 +4251,14 @@ impl fmt::Display for SyncDeploymentJobError {
             SyncDeploymentJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             SyncDeploymentJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SyncDeploymentJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            SyncDeploymentJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SyncDeploymentJobError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3918,6 +4268,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -3925,19 +4277,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(TagResourceError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(TagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3951,12 +4303,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -3966,6 +4320,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -3973,19 +4329,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(UntagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3999,12 +4361,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateRobotApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRobotApplicationError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -4016,6 +4380,8 @@ pub enum UpdateRobotApplicationError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRobotApplicationError {
@@ -4023,30 +4389,37 @@ impl UpdateRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRobotApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRobotApplicationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRobotApplicationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRobotApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateRobotApplicationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRobotApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4061,12 +4434,14 @@ impl fmt::Display for UpdateRobotApplicationError {
             UpdateRobotApplicationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateRobotApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateRobotApplicationError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateRobotApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRobotApplicationError {}
 /// Errors returned by UpdateSimulationApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSimulationApplicationError {
     /// <p>AWS RoboMaker experienced a service issue. Try your call again.</p>
     InternalServer(String),
@@ -4078,6 +4453,8 @@ pub enum UpdateSimulationApplicationError {
     ResourceNotFound(String),
     /// <p>AWS RoboMaker is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSimulationApplicationError {
@@ -4087,32 +4464,42 @@ impl UpdateSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateSimulationApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSimulationApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSimulationApplicationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSimulationApplicationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSimulationApplicationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSimulationApplicationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSimulationApplicationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSimulationApplicationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateSimulationApplicationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4127,6 +4514,7 @@ impl fmt::Display for UpdateSimulationApplicationError {
             UpdateSimulationApplicationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateSimulationApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateSimulationApplicationError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateSimulationApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4370,6 +4758,7 @@ impl RobomakerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> RobomakerClient {
         RobomakerClient {
             client: Client::shared(),
@@ -4395,1020 +4784,1975 @@ impl RobomakerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> RobomakerClient {
         RobomakerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<RobomakerClient> {
+        rusoto_core::ClientBuilder::new(RobomakerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> RobomakerClient {
+        RobomakerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Robomaker for RobomakerClient {
-    /// <p>Describes one or more simulation jobs.</p>
-    async fn batch_describe_simulation_job(
+impl RobomakerClient {
+    /// Calls `batch_describe_simulation_job`, also returning the metadata of the response.
+    pub async fn batch_describe_simulation_job_with_metadata(
         &self,
         input: BatchDescribeSimulationJobRequest,
-    ) -> Result<BatchDescribeSimulationJobResponse, RusotoError<BatchDescribeSimulationJobError>>
-    {
-        let request_uri = "/batchDescribeSimulationJob";
-
-        let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (BatchDescribeSimulationJobResponse, ResponseMetadata),
+        RusotoError<BatchDescribeSimulationJobError>,
+    > {
+        let request = self.build_batch_describe_simulation_job_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<BatchDescribeSimulationJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(BatchDescribeSimulationJobError::from_response(response))
         }
     }
 
-    /// <p>Cancels the specified deployment job.</p>
-    async fn cancel_deployment_job(
+    /// Presigns the request `batch_describe_simulation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_describe_simulation_job(
         &self,
-        input: CancelDeploymentJobRequest,
-    ) -> Result<CancelDeploymentJobResponse, RusotoError<CancelDeploymentJobError>> {
-        let request_uri = "/cancelDeploymentJob";
+        presigner: &rusoto_core::Presigner,
+        input: BatchDescribeSimulationJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_describe_simulation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_describe_simulation_job_request(
+        &self,
+        input: BatchDescribeSimulationJobRequest,
+    ) -> SignedRequest {
+        let request_uri = "/batchDescribeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("BatchDescribeSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `cancel_deployment_job`, also returning the metadata of the response.
+    pub async fn cancel_deployment_job_with_metadata(
+        &self,
+        input: CancelDeploymentJobRequest,
+    ) -> Result<
+        (CancelDeploymentJobResponse, ResponseMetadata),
+        RusotoError<CancelDeploymentJobError>,
+    > {
+        let request = self.build_cancel_deployment_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelDeploymentJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CancelDeploymentJobError::from_response(response))
         }
     }
 
-    /// <p>Cancels the specified simulation job.</p>
-    async fn cancel_simulation_job(
+    /// Presigns the request `cancel_deployment_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_deployment_job(
         &self,
-        input: CancelSimulationJobRequest,
-    ) -> Result<CancelSimulationJobResponse, RusotoError<CancelSimulationJobError>> {
-        let request_uri = "/cancelSimulationJob";
+        presigner: &rusoto_core::Presigner,
+        input: CancelDeploymentJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_deployment_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_deployment_job_request(
+        &self,
+        input: CancelDeploymentJobRequest,
+    ) -> SignedRequest {
+        let request_uri = "/cancelDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `cancel_simulation_job`, also returning the metadata of the response.
+    pub async fn cancel_simulation_job_with_metadata(
+        &self,
+        input: CancelSimulationJobRequest,
+    ) -> Result<
+        (CancelSimulationJobResponse, ResponseMetadata),
+        RusotoError<CancelSimulationJobError>,
+    > {
+        let request = self.build_cancel_simulation_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelSimulationJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CancelSimulationJobError::from_response(response))
         }
     }
 
-    /// <p><p>Deploys a specific version of a robot application to robots in a fleet.</p> <p>The robot application must have a numbered <code>applicationVersion</code> for consistency reasons. To create a new version, use <code>CreateRobotApplicationVersion</code> or see <a href="https://docs.aws.amazon.com/robomaker/latest/dg/create-robot-application-version.html">Creating a Robot Application Version</a>. </p> <note> <p>After 90 days, deployment jobs expire and will be deleted. They will no longer be accessible. </p> </note></p>
-    async fn create_deployment_job(
+    /// Presigns the request `cancel_simulation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_simulation_job(
         &self,
-        input: CreateDeploymentJobRequest,
-    ) -> Result<CreateDeploymentJobResponse, RusotoError<CreateDeploymentJobError>> {
-        let request_uri = "/createDeploymentJob";
+        presigner: &rusoto_core::Presigner,
+        input: CancelSimulationJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_simulation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_simulation_job_request(
+        &self,
+        input: CancelSimulationJobRequest,
+    ) -> SignedRequest {
+        let request_uri = "/cancelSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_deployment_job`, also returning the metadata of the response.
+    pub async fn create_deployment_job_with_metadata(
+        &self,
+        input: CreateDeploymentJobRequest,
+    ) -> Result<
+        (CreateDeploymentJobResponse, ResponseMetadata),
+        RusotoError<CreateDeploymentJobError>,
+    > {
+        let request = self.build_create_deployment_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeploymentJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateDeploymentJobError::from_response(response))
         }
     }
 
-    /// <p>Creates a fleet, a logical group of robots running the same robot application.</p>
-    async fn create_fleet(
+    /// Presigns the request `create_deployment_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment_job(
         &self,
-        input: CreateFleetRequest,
-    ) -> Result<CreateFleetResponse, RusotoError<CreateFleetError>> {
-        let request_uri = "/createFleet";
+        presigner: &rusoto_core::Presigner,
+        input: CreateDeploymentJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_job_request(
+        &self,
+        input: CreateDeploymentJobRequest,
+    ) -> SignedRequest {
+        let request_uri = "/createDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_fleet`, also returning the metadata of the response.
+    pub async fn create_fleet_with_metadata(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<(CreateFleetResponse, ResponseMetadata), RusotoError<CreateFleetError>> {
+        let request = self.build_create_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateFleetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateFleetError::from_response(response))
         }
     }
 
-    /// <p>Creates a robot.</p>
-    async fn create_robot(
+    /// Presigns the request `create_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_fleet(
         &self,
-        input: CreateRobotRequest,
-    ) -> Result<CreateRobotResponse, RusotoError<CreateRobotError>> {
-        let request_uri = "/createRobot";
+        presigner: &rusoto_core::Presigner,
+        input: CreateFleetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_fleet_request(&self, input: CreateFleetRequest) -> SignedRequest {
+        let request_uri = "/createFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_robot`, also returning the metadata of the response.
+    pub async fn create_robot_with_metadata(
+        &self,
+        input: CreateRobotRequest,
+    ) -> Result<(CreateRobotResponse, ResponseMetadata), RusotoError<CreateRobotError>> {
+        let request = self.build_create_robot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRobotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateRobotError::from_response(response))
         }
     }
 
-    /// <p>Creates a robot application. </p>
-    async fn create_robot_application(
+    /// Presigns the request `create_robot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_robot(
         &self,
-        input: CreateRobotApplicationRequest,
-    ) -> Result<CreateRobotApplicationResponse, RusotoError<CreateRobotApplicationError>> {
-        let request_uri = "/createRobotApplication";
+        presigner: &rusoto_core::Presigner,
+        input: CreateRobotRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_robot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_robot_request(&self, input: CreateRobotRequest) -> SignedRequest {
+        let request_uri = "/createRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_robot_application`, also returning the metadata of the response.
+    pub async fn create_robot_application_with_metadata(
+        &self,
+        input: CreateRobotApplicationRequest,
+    ) -> Result<
+        (CreateRobotApplicationResponse, ResponseMetadata),
+        RusotoError<CreateRobotApplicationError>,
+    > {
+        let request = self.build_create_robot_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRobotApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateRobotApplicationError::from_response(response))
         }
     }
 
-    /// <p>Creates a version of a robot application.</p>
-    async fn create_robot_application_version(
+    /// Presigns the request `create_robot_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_robot_application(
         &self,
-        input: CreateRobotApplicationVersionRequest,
-    ) -> Result<
-        CreateRobotApplicationVersionResponse,
-        RusotoError<CreateRobotApplicationVersionError>,
-    > {
-        let request_uri = "/createRobotApplicationVersion";
+        presigner: &rusoto_core::Presigner,
+        input: CreateRobotApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_robot_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_robot_application_request(
+        &self,
+        input: CreateRobotApplicationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/createRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
+        request
+    }
+
+    /// Calls `create_robot_application_version`, also returning the metadata of the response.
+    pub async fn create_robot_application_version_with_metadata(
+        &self,
+        input: CreateRobotApplicationVersionRequest,
+    ) -> Result<
+        (CreateRobotApplicationVersionResponse, ResponseMetadata),
+        RusotoError<CreateRobotApplicationVersionError>,
+    > {
+        let request = self.build_create_robot_application_version_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateRobotApplicationVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateRobotApplicationVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates a simulation application.</p>
-    async fn create_simulation_application(
+    /// Presigns the request `create_robot_application_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_robot_application_version(
         &self,
-        input: CreateSimulationApplicationRequest,
-    ) -> Result<CreateSimulationApplicationResponse, RusotoError<CreateSimulationApplicationError>>
-    {
-        let request_uri = "/createSimulationApplication";
+        presigner: &rusoto_core::Presigner,
+        input: CreateRobotApplicationVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_robot_application_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_robot_application_version_request(
+        &self,
+        input: CreateRobotApplicationVersionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/createRobotApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobotApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_simulation_application`, also returning the metadata of the response.
+    pub async fn create_simulation_application_with_metadata(
+        &self,
+        input: CreateSimulationApplicationRequest,
+    ) -> Result<
+        (CreateSimulationApplicationResponse, ResponseMetadata),
+        RusotoError<CreateSimulationApplicationError>,
+    > {
+        let request = self.build_create_simulation_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSimulationApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateSimulationApplicationError::from_response(response))
         }
     }
 
-    /// <p>Creates a simulation application with a specific revision id.</p>
-    async fn create_simulation_application_version(
+    /// Presigns the request `create_simulation_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_simulation_application(
         &self,
-        input: CreateSimulationApplicationVersionRequest,
-    ) -> Result<
-        CreateSimulationApplicationVersionResponse,
-        RusotoError<CreateSimulationApplicationVersionError>,
-    > {
-        let request_uri = "/createSimulationApplicationVersion";
+        presigner: &rusoto_core::Presigner,
+        input: CreateSimulationApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_simulation_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_simulation_application_request(
+        &self,
+        input: CreateSimulationApplicationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/createSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_simulation_application_version`, also returning the metadata of the response.
+    pub async fn create_simulation_application_version_with_metadata(
+        &self,
+        input: CreateSimulationApplicationVersionRequest,
+    ) -> Result<
+        (CreateSimulationApplicationVersionResponse, ResponseMetadata),
+        RusotoError<CreateSimulationApplicationVersionError>,
+    > {
+        let request = self.build_create_simulation_application_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSimulationApplicationVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateSimulationApplicationVersionError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p><p>Creates a simulation job.</p> <note> <p>After 90 days, simulation jobs expire and will be deleted. They will no longer be accessible. </p> </note></p>
-    async fn create_simulation_job(
+    /// Presigns the request `create_simulation_application_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_simulation_application_version(
         &self,
-        input: CreateSimulationJobRequest,
-    ) -> Result<CreateSimulationJobResponse, RusotoError<CreateSimulationJobError>> {
-        let request_uri = "/createSimulationJob";
+        presigner: &rusoto_core::Presigner,
+        input: CreateSimulationApplicationVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_simulation_application_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_simulation_application_version_request(
+        &self,
+        input: CreateSimulationApplicationVersionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/createSimulationApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_simulation_job`, also returning the metadata of the response.
+    pub async fn create_simulation_job_with_metadata(
+        &self,
+        input: CreateSimulationJobRequest,
+    ) -> Result<
+        (CreateSimulationJobResponse, ResponseMetadata),
+        RusotoError<CreateSimulationJobError>,
+    > {
+        let request = self.build_create_simulation_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSimulationJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateSimulationJobError::from_response(response))
         }
     }
 
-    /// <p>Deletes a fleet.</p>
-    async fn delete_fleet(
+    /// Presigns the request `create_simulation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_simulation_job(
         &self,
-        input: DeleteFleetRequest,
-    ) -> Result<DeleteFleetResponse, RusotoError<DeleteFleetError>> {
-        let request_uri = "/deleteFleet";
+        presigner: &rusoto_core::Presigner,
+        input: CreateSimulationJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_simulation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_simulation_job_request(
+        &self,
+        input: CreateSimulationJobRequest,
+    ) -> SignedRequest {
+        let request_uri = "/createSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_fleet`, also returning the metadata of the response.
+    pub async fn delete_fleet_with_metadata(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<(DeleteFleetResponse, ResponseMetadata), RusotoError<DeleteFleetError>> {
+        let request = self.build_delete_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteFleetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteFleetError::from_response(response))
         }
     }
 
-    /// <p>Deletes a robot.</p>
-    async fn delete_robot(
+    /// Presigns the request `delete_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_fleet(
         &self,
-        input: DeleteRobotRequest,
-    ) -> Result<DeleteRobotResponse, RusotoError<DeleteRobotError>> {
-        let request_uri = "/deleteRobot";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteFleetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_fleet_request(&self, input: DeleteFleetRequest) -> SignedRequest {
+        let request_uri = "/deleteFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_robot`, also returning the metadata of the response.
+    pub async fn delete_robot_with_metadata(
+        &self,
+        input: DeleteRobotRequest,
+    ) -> Result<(DeleteRobotResponse, ResponseMetadata), RusotoError<DeleteRobotError>> {
+        let request = self.build_delete_robot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteRobotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteRobotError::from_response(response))
         }
     }
 
-    /// <p>Deletes a robot application.</p>
-    async fn delete_robot_application(
+    /// Presigns the request `delete_robot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_robot(
         &self,
-        input: DeleteRobotApplicationRequest,
-    ) -> Result<DeleteRobotApplicationResponse, RusotoError<DeleteRobotApplicationError>> {
-        let request_uri = "/deleteRobotApplication";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteRobotRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_robot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_robot_request(&self, input: DeleteRobotRequest) -> SignedRequest {
+        let request_uri = "/deleteRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_robot_application`, also returning the metadata of the response.
+    pub async fn delete_robot_application_with_metadata(
+        &self,
+        input: DeleteRobotApplicationRequest,
+    ) -> Result<
+        (DeleteRobotApplicationResponse, ResponseMetadata),
+        RusotoError<DeleteRobotApplicationError>,
+    > {
+        let request = self.build_delete_robot_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteRobotApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteRobotApplicationError::from_response(response))
         }
     }
 
-    /// <p>Deletes a simulation application.</p>
-    async fn delete_simulation_application(
+    /// Presigns the request `delete_robot_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_robot_application(
         &self,
-        input: DeleteSimulationApplicationRequest,
-    ) -> Result<DeleteSimulationApplicationResponse, RusotoError<DeleteSimulationApplicationError>>
-    {
-        let request_uri = "/deleteSimulationApplication";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteRobotApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_robot_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_robot_application_request(
+        &self,
+        input: DeleteRobotApplicationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/deleteRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_simulation_application`, also returning the metadata of the response.
+    pub async fn delete_simulation_application_with_metadata(
+        &self,
+        input: DeleteSimulationApplicationRequest,
+    ) -> Result<
+        (DeleteSimulationApplicationResponse, ResponseMetadata),
+        RusotoError<DeleteSimulationApplicationError>,
+    > {
+        let request = self.build_delete_simulation_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteSimulationApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteSimulationApplicationError::from_response(response))
         }
     }
 
-    /// <p>Deregisters a robot.</p>
-    async fn deregister_robot(
+    /// Presigns the request `delete_simulation_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_simulation_application(
         &self,
-        input: DeregisterRobotRequest,
-    ) -> Result<DeregisterRobotResponse, RusotoError<DeregisterRobotError>> {
-        let request_uri = "/deregisterRobot";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteSimulationApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_simulation_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_simulation_application_request(
+        &self,
+        input: DeleteSimulationApplicationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/deleteSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `deregister_robot`, also returning the metadata of the response.
+    pub async fn deregister_robot_with_metadata(
+        &self,
+        input: DeregisterRobotRequest,
+    ) -> Result<(DeregisterRobotResponse, ResponseMetadata), RusotoError<DeregisterRobotError>>
+    {
+        let request = self.build_deregister_robot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeregisterRobotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeregisterRobotError::from_response(response))
         }
     }
 
-    /// <p>Describes a deployment job.</p>
-    async fn describe_deployment_job(
+    /// Presigns the request `deregister_robot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_robot(
         &self,
-        input: DescribeDeploymentJobRequest,
-    ) -> Result<DescribeDeploymentJobResponse, RusotoError<DescribeDeploymentJobError>> {
-        let request_uri = "/describeDeploymentJob";
+        presigner: &rusoto_core::Presigner,
+        input: DeregisterRobotRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_robot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_robot_request(&self, input: DeregisterRobotRequest) -> SignedRequest {
+        let request_uri = "/deregisterRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeregisterRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_deployment_job`, also returning the metadata of the response.
+    pub async fn describe_deployment_job_with_metadata(
+        &self,
+        input: DescribeDeploymentJobRequest,
+    ) -> Result<
+        (DescribeDeploymentJobResponse, ResponseMetadata),
+        RusotoError<DescribeDeploymentJobError>,
+    > {
+        let request = self.build_describe_deployment_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeDeploymentJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeDeploymentJobError::from_response(response))
         }
     }
 
-    /// <p>Describes a fleet.</p>
-    async fn describe_fleet(
+    /// Presigns the request `describe_deployment_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_deployment_job(
         &self,
-        input: DescribeFleetRequest,
-    ) -> Result<DescribeFleetResponse, RusotoError<DescribeFleetError>> {
-        let request_uri = "/describeFleet";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDeploymentJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_deployment_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_deployment_job_request(
+        &self,
+        input: DescribeDeploymentJobRequest,
+    ) -> SignedRequest {
+        let request_uri = "/describeDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_fleet`, also returning the metadata of the response.
+    pub async fn describe_fleet_with_metadata(
+        &self,
+        input: DescribeFleetRequest,
+    ) -> Result<(DescribeFleetResponse, ResponseMetadata), RusotoError<DescribeFleetError>> {
+        let request = self.build_describe_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeFleetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeFleetError::from_response(response))
         }
     }
 
-    /// <p>Describes a robot.</p>
-    async fn describe_robot(
+    /// Presigns the request `describe_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_fleet(
         &self,
-        input: DescribeRobotRequest,
-    ) -> Result<DescribeRobotResponse, RusotoError<DescribeRobotError>> {
-        let request_uri = "/describeRobot";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeFleetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_fleet_request(&self, input: DescribeFleetRequest) -> SignedRequest {
+        let request_uri = "/describeFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_robot`, also returning the metadata of the response.
+    pub async fn describe_robot_with_metadata(
+        &self,
+        input: DescribeRobotRequest,
+    ) -> Result<(DescribeRobotResponse, ResponseMetadata), RusotoError<DescribeRobotError>> {
+        let request = self.build_describe_robot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRobotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeRobotError::from_response(response))
         }
     }
 
-    /// <p>Describes a robot application.</p>
-    async fn describe_robot_application(
+    /// Presigns the request `describe_robot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_robot(
         &self,
-        input: DescribeRobotApplicationRequest,
-    ) -> Result<DescribeRobotApplicationResponse, RusotoError<DescribeRobotApplicationError>> {
-        let request_uri = "/describeRobotApplication";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeRobotRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_robot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_robot_request(&self, input: DescribeRobotRequest) -> SignedRequest {
+        let request_uri = "/describeRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_robot_application`, also returning the metadata of the response.
+    pub async fn describe_robot_application_with_metadata(
+        &self,
+        input: DescribeRobotApplicationRequest,
+    ) -> Result<
+        (DescribeRobotApplicationResponse, ResponseMetadata),
+        RusotoError<DescribeRobotApplicationError>,
+    > {
+        let request = self.build_describe_robot_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeRobotApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeRobotApplicationError::from_response(response))
         }
     }
 
-    /// <p>Describes a simulation application.</p>
-    async fn describe_simulation_application(
+    /// Presigns the request `describe_robot_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_robot_application(
         &self,
-        input: DescribeSimulationApplicationRequest,
-    ) -> Result<
-        DescribeSimulationApplicationResponse,
-        RusotoError<DescribeSimulationApplicationError>,
-    > {
-        let request_uri = "/describeSimulationApplication";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeRobotApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_robot_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_robot_application_request(
+        &self,
+        input: DescribeRobotApplicationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/describeRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_simulation_application`, also returning the metadata of the response.
+    pub async fn describe_simulation_application_with_metadata(
+        &self,
+        input: DescribeSimulationApplicationRequest,
+    ) -> Result<
+        (DescribeSimulationApplicationResponse, ResponseMetadata),
+        RusotoError<DescribeSimulationApplicationError>,
+    > {
+        let request = self.build_describe_simulation_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSimulationApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeSimulationApplicationError::from_response(response))
         }
     }
 
-    /// <p>Describes a simulation job.</p>
-    async fn describe_simulation_job(
+    /// Presigns the request `describe_simulation_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_simulation_application(
         &self,
-        input: DescribeSimulationJobRequest,
-    ) -> Result<DescribeSimulationJobResponse, RusotoError<DescribeSimulationJobError>> {
-        let request_uri = "/describeSimulationJob";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSimulationApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_simulation_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_simulation_application_request(
+        &self,
+        input: DescribeSimulationApplicationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/describeSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
+        request
+    }
+
+    /// Calls `describe_simulation_job`, also returning the metadata of the response.
+    pub async fn describe_simulation_job_with_metadata(
+        &self,
+        input: DescribeSimulationJobRequest,
+    ) -> Result<
+        (DescribeSimulationJobResponse, ResponseMetadata),
+        RusotoError<DescribeSimulationJobError>,
+    > {
+        let request = self.build_describe_simulation_job_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSimulationJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeSimulationJobError::from_response(response))
         }
     }
 
-    /// <p><p>Returns a list of deployment jobs for a fleet. You can optionally provide filters to retrieve specific deployment jobs. </p> <note> <p> </p> </note></p>
-    async fn list_deployment_jobs(
+    /// Presigns the request `describe_simulation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_simulation_job(
         &self,
-        input: ListDeploymentJobsRequest,
-    ) -> Result<ListDeploymentJobsResponse, RusotoError<ListDeploymentJobsError>> {
-        let request_uri = "/listDeploymentJobs";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSimulationJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_simulation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_simulation_job_request(
+        &self,
+        input: DescribeSimulationJobRequest,
+    ) -> SignedRequest {
+        let request_uri = "/describeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_deployment_jobs`, also returning the metadata of the response.
+    pub async fn list_deployment_jobs_with_metadata(
+        &self,
+        input: ListDeploymentJobsRequest,
+    ) -> Result<(ListDeploymentJobsResponse, ResponseMetadata), RusotoError<ListDeploymentJobsError>>
+    {
+        let request = self.build_list_deployment_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListDeploymentJobsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListDeploymentJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of fleets. You can optionally provide filters to retrieve specific fleets. </p>
-    async fn list_fleets(
+    /// Presigns the request `list_deployment_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deployment_jobs(
         &self,
-        input: ListFleetsRequest,
-    ) -> Result<ListFleetsResponse, RusotoError<ListFleetsError>> {
-        let request_uri = "/listFleets";
+        presigner: &rusoto_core::Presigner,
+        input: ListDeploymentJobsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deployment_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deployment_jobs_request(
+        &self,
+        input: ListDeploymentJobsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/listDeploymentJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListDeploymentJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_fleets`, also returning the metadata of the response.
+    pub async fn list_fleets_with_metadata(
+        &self,
+        input: ListFleetsRequest,
+    ) -> Result<(ListFleetsResponse, ResponseMetadata), RusotoError<ListFleetsError>> {
+        let request = self.build_list_fleets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListFleetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListFleetsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of robot application. You can optionally provide filters to retrieve specific robot applications.</p>
-    async fn list_robot_applications(
+    /// Presigns the request `list_fleets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_fleets(
         &self,
-        input: ListRobotApplicationsRequest,
-    ) -> Result<ListRobotApplicationsResponse, RusotoError<ListRobotApplicationsError>> {
-        let request_uri = "/listRobotApplications";
+        presigner: &rusoto_core::Presigner,
+        input: ListFleetsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_fleets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_fleets_request(&self, input: ListFleetsRequest) -> SignedRequest {
+        let request_uri = "/listFleets";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListFleets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_robot_applications`, also returning the metadata of the response.
+    pub async fn list_robot_applications_with_metadata(
+        &self,
+        input: ListRobotApplicationsRequest,
+    ) -> Result<
+        (ListRobotApplicationsResponse, ResponseMetadata),
+        RusotoError<ListRobotApplicationsError>,
+    > {
+        let request = self.build_list_robot_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRobotApplicationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListRobotApplicationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of robots. You can optionally provide filters to retrieve specific robots.</p>
-    async fn list_robots(
+    /// Presigns the request `list_robot_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_robot_applications(
         &self,
-        input: ListRobotsRequest,
-    ) -> Result<ListRobotsResponse, RusotoError<ListRobotsError>> {
-        let request_uri = "/listRobots";
+        presigner: &rusoto_core::Presigner,
+        input: ListRobotApplicationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_robot_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_robot_applications_request(
+        &self,
+        input: ListRobotApplicationsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/listRobotApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListRobotApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_robots`, also returning the metadata of the response.
+    pub async fn list_robots_with_metadata(
+        &self,
+        input: ListRobotsRequest,
+    ) -> Result<(ListRobotsResponse, ResponseMetadata), RusotoError<ListRobotsError>> {
+        let request = self.build_list_robots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListRobotsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListRobotsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of simulation applications. You can optionally provide filters to retrieve specific simulation applications. </p>
-    async fn list_simulation_applications(
+    /// Presigns the request `list_robots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_robots(
         &self,
-        input: ListSimulationApplicationsRequest,
-    ) -> Result<ListSimulationApplicationsResponse, RusotoError<ListSimulationApplicationsError>>
-    {
-        let request_uri = "/listSimulationApplications";
+        presigner: &rusoto_core::Presigner,
+        input: ListRobotsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_robots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_robots_request(&self, input: ListRobotsRequest) -> SignedRequest {
+        let request_uri = "/listRobots";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListRobots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_simulation_applications`, also returning the metadata of the response.
+    pub async fn list_simulation_applications_with_metadata(
+        &self,
+        input: ListSimulationApplicationsRequest,
+    ) -> Result<
+        (ListSimulationApplicationsResponse, ResponseMetadata),
+        RusotoError<ListSimulationApplicationsError>,
+    > {
+        let request = self.build_list_simulation_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSimulationApplicationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListSimulationApplicationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of simulation jobs. You can optionally provide filters to retrieve specific simulation jobs. </p>
-    async fn list_simulation_jobs(
+    /// Presigns the request `list_simulation_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_simulation_applications(
         &self,
-        input: ListSimulationJobsRequest,
-    ) -> Result<ListSimulationJobsResponse, RusotoError<ListSimulationJobsError>> {
-        let request_uri = "/listSimulationJobs";
+        presigner: &rusoto_core::Presigner,
+        input: ListSimulationApplicationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_simulation_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_simulation_applications_request(
+        &self,
+        input: ListSimulationApplicationsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/listSimulationApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_simulation_jobs`, also returning the metadata of the response.
+    pub async fn list_simulation_jobs_with_metadata(
+        &self,
+        input: ListSimulationJobsRequest,
+    ) -> Result<(ListSimulationJobsResponse, ResponseMetadata), RusotoError<ListSimulationJobsError>>
+    {
+        let request = self.build_list_simulation_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListSimulationJobsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListSimulationJobsError::from_response(response))
         }
     }
 
-    /// <p>Lists all tags on a AWS RoboMaker resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_simulation_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_simulation_jobs(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListSimulationJobsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_simulation_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_simulation_jobs_request(
+        &self,
+        input: ListSimulationJobsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/listSimulationJobs";
+
+        let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationJobs");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
         &self,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `register_robot`, also returning the metadata of the response.
+    pub async fn register_robot_with_metadata(
+        &self,
+        input: RegisterRobotRequest,
+    ) -> Result<(RegisterRobotResponse, ResponseMetadata), RusotoError<RegisterRobotError>> {
+        let request = self.build_register_robot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<RegisterRobotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RegisterRobotError::from_response(response))
         }
     }
 
-    /// <p>Registers a robot with a fleet.</p>
-    async fn register_robot(
+    /// Presigns the request `register_robot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_robot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterRobotRequest,
-    ) -> Result<RegisterRobotResponse, RusotoError<RegisterRobotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_robot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_robot_request(&self, input: RegisterRobotRequest) -> SignedRequest {
         let request_uri = "/registerRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("RegisterRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `restart_simulation_job`, also returning the metadata of the response.
+    pub async fn restart_simulation_job_with_metadata(
+        &self,
+        input: RestartSimulationJobRequest,
+    ) -> Result<
+        (RestartSimulationJobResponse, ResponseMetadata),
+        RusotoError<RestartSimulationJobError>,
+    > {
+        let request = self.build_restart_simulation_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterRobotResponse, _>()?;
+                .deserialize::<RestartSimulationJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterRobotError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RestartSimulationJobError::from_response(response))
         }
     }
 
-    /// <p>Restarts a running simulation job.</p>
-    async fn restart_simulation_job(
+    /// Presigns the request `restart_simulation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restart_simulation_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestartSimulationJobRequest,
-    ) -> Result<RestartSimulationJobResponse, RusotoError<RestartSimulationJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restart_simulation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restart_simulation_job_request(
+        &self,
+        input: RestartSimulationJobRequest,
+    ) -> SignedRequest {
         let request_uri = "/restartSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("RestartSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `sync_deployment_job`, also returning the metadata of the response.
+    pub async fn sync_deployment_job_with_metadata(
+        &self,
+        input: SyncDeploymentJobRequest,
+    ) -> Result<(SyncDeploymentJobResponse, ResponseMetadata), RusotoError<SyncDeploymentJobError>>
+    {
+        let request = self.build_sync_deployment_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RestartSimulationJobResponse, _>()?;
+                .deserialize::<SyncDeploymentJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RestartSimulationJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SyncDeploymentJobError::from_response(response))
         }
     }
 
-    /// <p>Syncrhonizes robots in a fleet to the latest deployment. This is helpful if robots were added after a deployment.</p>
-    async fn sync_deployment_job(
+    /// Presigns the request `sync_deployment_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_sync_deployment_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SyncDeploymentJobRequest,
-    ) -> Result<SyncDeploymentJobResponse, RusotoError<SyncDeploymentJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_sync_deployment_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_sync_deployment_job_request(&self, input: SyncDeploymentJobRequest) -> SignedRequest {
         let request_uri = "/syncDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("SyncDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<SyncDeploymentJobResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SyncDeploymentJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds or edits tags for a AWS RoboMaker resource.</p> <p>Each tag consists of a tag key and a tag value. Tag keys and tag values are both required, but tag values can be empty strings. </p> <p>For information about the rules that apply to tag keys and tag values, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from the specified AWS RoboMaker resource.</p> <p>To remove a tag, specify the tag key. To change the tag value of an existing tag key, use <a href="https://docs.aws.amazon.com/robomaker/latest/dg/API_TagResource.html"> <code>TagResource</code> </a>. </p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "robomaker", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5417,81 +6761,503 @@ impl Robomaker for RobomakerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_robot_application`, also returning the metadata of the response.
+    pub async fn update_robot_application_with_metadata(
+        &self,
+        input: UpdateRobotApplicationRequest,
+    ) -> Result<
+        (UpdateRobotApplicationResponse, ResponseMetadata),
+        RusotoError<UpdateRobotApplicationError>,
+    > {
+        let request = self.build_update_robot_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateRobotApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRobotApplicationError::from_response(response))
         }
     }
 
-    /// <p>Updates a robot application.</p>
-    async fn update_robot_application(
+    /// Presigns the request `update_robot_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_robot_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRobotApplicationRequest,
-    ) -> Result<UpdateRobotApplicationResponse, RusotoError<UpdateRobotApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_robot_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_robot_application_request(
+        &self,
+        input: UpdateRobotApplicationRequest,
+    ) -> SignedRequest {
         let request_uri = "/updateRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("UpdateRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_simulation_application`, also returning the metadata of the response.
+    pub async fn update_simulation_application_with_metadata(
+        &self,
+        input: UpdateSimulationApplicationRequest,
+    ) -> Result<
+        (UpdateSimulationApplicationResponse, ResponseMetadata),
+        RusotoError<UpdateSimulationApplicationError>,
+    > {
+        let request = self.build_update_simulation_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRobotApplicationResponse, _>()?;
+                .deserialize::<UpdateSimulationApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRobotApplicationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateSimulationApplicationError::from_response(response))
         }
     }
 
-    /// <p>Updates a simulation application.</p>
-    async fn update_simulation_application(
+    /// Presigns the request `update_simulation_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_simulation_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSimulationApplicationRequest,
-    ) -> Result<UpdateSimulationApplicationResponse, RusotoError<UpdateSimulationApplicationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_simulation_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_simulation_application_request(
+        &self,
+        input: UpdateSimulationApplicationRequest,
+    ) -> SignedRequest {
         let request_uri = "/updateSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("UpdateSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Robomaker for RobomakerClient {
+    /// <p>Describes one or more simulation jobs.</p>
+    async fn batch_describe_simulation_job(
+        &self,
+        input: BatchDescribeSimulationJobRequest,
+    ) -> Result<BatchDescribeSimulationJobResponse, RusotoError<BatchDescribeSimulationJobError>>
+    {
+        self.batch_describe_simulation_job_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSimulationApplicationResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSimulationApplicationError::from_response(response))
-        }
+    /// <p>Cancels the specified deployment job.</p>
+    async fn cancel_deployment_job(
+        &self,
+        input: CancelDeploymentJobRequest,
+    ) -> Result<CancelDeploymentJobResponse, RusotoError<CancelDeploymentJobError>> {
+        self.cancel_deployment_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels the specified simulation job.</p>
+    async fn cancel_simulation_job(
+        &self,
+        input: CancelSimulationJobRequest,
+    ) -> Result<CancelSimulationJobResponse, RusotoError<CancelSimulationJobError>> {
+        self.cancel_simulation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deploys a specific version of a robot application to robots in a fleet.</p> <p>The robot application must have a numbered <code>applicationVersion</code> for consistency reasons. To create a new version, use <code>CreateRobotApplicationVersion</code> or see <a href="https://docs.aws.amazon.com/robomaker/latest/dg/create-robot-application-version.html">Creating a Robot Application Version</a>. </p> <note> <p>After 90 days, deployment jobs expire and will be deleted. They will no longer be accessible. </p> </note></p>
+    async fn create_deployment_job(
+        &self,
+        input: CreateDeploymentJobRequest,
+    ) -> Result<CreateDeploymentJobResponse, RusotoError<CreateDeploymentJobError>> {
+        self.create_deployment_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a fleet, a logical group of robots running the same robot application.</p>
+    async fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResponse, RusotoError<CreateFleetError>> {
+        self.create_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a robot.</p>
+    async fn create_robot(
+        &self,
+        input: CreateRobotRequest,
+    ) -> Result<CreateRobotResponse, RusotoError<CreateRobotError>> {
+        self.create_robot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a robot application. </p>
+    async fn create_robot_application(
+        &self,
+        input: CreateRobotApplicationRequest,
+    ) -> Result<CreateRobotApplicationResponse, RusotoError<CreateRobotApplicationError>> {
+        self.create_robot_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of a robot application.</p>
+    async fn create_robot_application_version(
+        &self,
+        input: CreateRobotApplicationVersionRequest,
+    ) -> Result<
+        CreateRobotApplicationVersionResponse,
+        RusotoError<CreateRobotApplicationVersionError>,
+    > {
+        self.create_robot_application_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a simulation application.</p>
+    async fn create_simulation_application(
+        &self,
+        input: CreateSimulationApplicationRequest,
+    ) -> Result<CreateSimulationApplicationResponse, RusotoError<CreateSimulationApplicationError>>
+    {
+        self.create_simulation_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a simulation application with a specific revision id.</p>
+    async fn create_simulation_application_version(
+        &self,
+        input: CreateSimulationApplicationVersionRequest,
+    ) -> Result<
+        CreateSimulationApplicationVersionResponse,
+        RusotoError<CreateSimulationApplicationVersionError>,
+    > {
+        self.create_simulation_application_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a simulation job.</p> <note> <p>After 90 days, simulation jobs expire and will be deleted. They will no longer be accessible. </p> </note></p>
+    async fn create_simulation_job(
+        &self,
+        input: CreateSimulationJobRequest,
+    ) -> Result<CreateSimulationJobResponse, RusotoError<CreateSimulationJobError>> {
+        self.create_simulation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a fleet.</p>
+    async fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<DeleteFleetResponse, RusotoError<DeleteFleetError>> {
+        self.delete_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a robot.</p>
+    async fn delete_robot(
+        &self,
+        input: DeleteRobotRequest,
+    ) -> Result<DeleteRobotResponse, RusotoError<DeleteRobotError>> {
+        self.delete_robot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a robot application.</p>
+    async fn delete_robot_application(
+        &self,
+        input: DeleteRobotApplicationRequest,
+    ) -> Result<DeleteRobotApplicationResponse, RusotoError<DeleteRobotApplicationError>> {
+        self.delete_robot_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a simulation application.</p>
+    async fn delete_simulation_application(
+        &self,
+        input: DeleteSimulationApplicationRequest,
+    ) -> Result<DeleteSimulationApplicationResponse, RusotoError<DeleteSimulationApplicationError>>
+    {
+        self.delete_simulation_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters a robot.</p>
+    async fn deregister_robot(
+        &self,
+        input: DeregisterRobotRequest,
+    ) -> Result<DeregisterRobotResponse, RusotoError<DeregisterRobotError>> {
+        self.deregister_robot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a deployment job.</p>
+    async fn describe_deployment_job(
+        &self,
+        input: DescribeDeploymentJobRequest,
+    ) -> Result<DescribeDeploymentJobResponse, RusotoError<DescribeDeploymentJobError>> {
+        self.describe_deployment_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a fleet.</p>
+    async fn describe_fleet(
+        &self,
+        input: DescribeFleetRequest,
+    ) -> Result<DescribeFleetResponse, RusotoError<DescribeFleetError>> {
+        self.describe_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a robot.</p>
+    async fn describe_robot(
+        &self,
+        input: DescribeRobotRequest,
+    ) -> Result<DescribeRobotResponse, RusotoError<DescribeRobotError>> {
+        self.describe_robot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a robot application.</p>
+    async fn describe_robot_application(
+        &self,
+        input: DescribeRobotApplicationRequest,
+    ) -> Result<DescribeRobotApplicationResponse, RusotoError<DescribeRobotApplicationError>> {
+        self.describe_robot_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a simulation application.</p>
+    async fn describe_simulation_application(
+        &self,
+        input: DescribeSimulationApplicationRequest,
+    ) -> Result<
+        DescribeSimulationApplicationResponse,
+        RusotoError<DescribeSimulationApplicationError>,
+    > {
+        self.describe_simulation_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a simulation job.</p>
+    async fn describe_simulation_job(
+        &self,
+        input: DescribeSimulationJobRequest,
+    ) -> Result<DescribeSimulationJobResponse, RusotoError<DescribeSimulationJobError>> {
+        self.describe_simulation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a list of deployment jobs for a fleet. You can optionally provide filters to retrieve specific deployment jobs. </p> <note> <p> </p> </note></p>
+    async fn list_deployment_jobs(
+        &self,
+        input: ListDeploymentJobsRequest,
+    ) -> Result<ListDeploymentJobsResponse, RusotoError<ListDeploymentJobsError>> {
+        self.list_deployment_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of fleets. You can optionally provide filters to retrieve specific fleets. </p>
+    async fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> Result<ListFleetsResponse, RusotoError<ListFleetsError>> {
+        self.list_fleets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of robot application. You can optionally provide filters to retrieve specific robot applications.</p>
+    async fn list_robot_applications(
+        &self,
+        input: ListRobotApplicationsRequest,
+    ) -> Result<ListRobotApplicationsResponse, RusotoError<ListRobotApplicationsError>> {
+        self.list_robot_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of robots. You can optionally provide filters to retrieve specific robots.</p>
+    async fn list_robots(
+        &self,
+        input: ListRobotsRequest,
+    ) -> Result<ListRobotsResponse, RusotoError<ListRobotsError>> {
+        self.list_robots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of simulation applications. You can optionally provide filters to retrieve specific simulation applications. </p>
+    async fn list_simulation_applications(
+        &self,
+        input: ListSimulationApplicationsRequest,
+    ) -> Result<ListSimulationApplicationsResponse, RusotoError<ListSimulationApplicationsError>>
+    {
+        self.list_simulation_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of simulation jobs. You can optionally provide filters to retrieve specific simulation jobs. </p>
+    async fn list_simulation_jobs(
+        &self,
+        input: ListSimulationJobsRequest,
+    ) -> Result<ListSimulationJobsResponse, RusotoError<ListSimulationJobsError>> {
+        self.list_simulation_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags on a AWS RoboMaker resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a robot with a fleet.</p>
+    async fn register_robot(
+        &self,
+        input: RegisterRobotRequest,
+    ) -> Result<RegisterRobotResponse, RusotoError<RegisterRobotError>> {
+        self.register_robot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Restarts a running simulation job.</p>
+    async fn restart_simulation_job(
+        &self,
+        input: RestartSimulationJobRequest,
+    ) -> Result<RestartSimulationJobResponse, RusotoError<RestartSimulationJobError>> {
+        self.restart_simulation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Syncrhonizes robots in a fleet to the latest deployment. This is helpful if robots were added after a deployment.</p>
+    async fn sync_deployment_job(
+        &self,
+        input: SyncDeploymentJobRequest,
+    ) -> Result<SyncDeploymentJobResponse, RusotoError<SyncDeploymentJobError>> {
+        self.sync_deployment_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or edits tags for a AWS RoboMaker resource.</p> <p>Each tag consists of a tag key and a tag value. Tag keys and tag values are both required, but tag values can be empty strings. </p> <p>For information about the rules that apply to tag keys and tag values, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from the specified AWS RoboMaker resource.</p> <p>To remove a tag, specify the tag key. To change the tag value of an existing tag key, use <a href="https://docs.aws.amazon.com/robomaker/latest/dg/API_TagResource.html"> <code>TagResource</code> </a>. </p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a robot application.</p>
+    async fn update_robot_application(
+        &self,
+        input: UpdateRobotApplicationRequest,
+    ) -> Result<UpdateRobotApplicationResponse, RusotoError<UpdateRobotApplicationError>> {
+        self.update_robot_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a simulation application.</p>
+    async fn update_simulation_application(
+        &self,
+        input: UpdateSimulationApplicationRequest,
+    ) -> Result<UpdateSimulationApplicationResponse, RusotoError<UpdateSimulationApplicationError>>
+    {
+        self.update_simulation_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7914,6 +7914,7 @@ impl VPCsDeserializer {
 }
 /// Errors returned by AssociateVPCWithHostedZone
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateVPCWithHostedZoneError {
     /// <p><p>The cause of this error depends on whether you&#39;re trying to create a public or a private hosted zone:</p> <ul> <li> <p> <b>Public hosted zone:</b> Two hosted zones that have the same name or that have a parent/child relationship (example.com and test.example.com) can&#39;t have any common name servers. You tried to create a hosted zone that has the same name as an existing hosted zone or that&#39;s the parent or child of an existing hosted zone, and you specified a delegation set that shares one or more name servers with the existing hosted zone. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateReusableDelegationSet.html">CreateReusableDelegationSet</a>.</p> </li> <li> <p> <b>Private hosted zone:</b> You specified an Amazon VPC that you&#39;re already using for another hosted zone, and the domain that you specified for one of the hosted zones is a subdomain of the domain that you specified for the other hosted zone. For example, you can&#39;t use the same Amazon VPC for the hosted zones for example.com and test.example.com.</p> </li> </ul></p>
     ConflictingDomainExists(String),
@@ -7929,6 +7930,8 @@ pub enum AssociateVPCWithHostedZoneError {
     NotAuthorized(String),
     /// <p>You're trying to associate a VPC with a public hosted zone. Amazon Route 53 doesn't support associating a VPC with a public hosted zone.</p>
     PublicZoneVPCAssociation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateVPCWithHostedZoneError {
@@ -7942,45 +7945,57 @@ impl AssociateVPCWithHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConflictingDomainExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AssociateVPCWithHostedZoneError::ConflictingDomainExists(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(AssociateVPCWithHostedZoneError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AssociateVPCWithHostedZoneError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(AssociateVPCWithHostedZoneError::InvalidVPCId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AssociateVPCWithHostedZoneError::InvalidVPCId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitsExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AssociateVPCWithHostedZoneError::LimitsExceeded(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AssociateVPCWithHostedZoneError::NoSuchHostedZone(parsed_error.message),
+                            &res,
                         )
                     }
                     "NotAuthorizedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AssociateVPCWithHostedZoneError::NotAuthorized(parsed_error.message),
+                            &res,
                         )
                     }
                     "PublicZoneVPCAssociation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AssociateVPCWithHostedZoneError::PublicZoneVPCAssociation(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            AssociateVPCWithHostedZoneError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8010,12 +8025,14 @@ impl fmt::Display for AssociateVPCWithHostedZoneError {
             AssociateVPCWithHostedZoneError::PublicZoneVPCAssociation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateVPCWithHostedZoneError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateVPCWithHostedZoneError {}
 /// Errors returned by ChangeResourceRecordSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ChangeResourceRecordSetsError {
     /// <p>This exception contains a list of messages that might contain one or more error messages. Each error message indicates one error in the change batch.</p>
     InvalidChangeBatch(String),
@@ -8027,6 +8044,8 @@ pub enum ChangeResourceRecordSetsError {
     NoSuchHostedZone(String),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
     PriorRequestNotComplete(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ChangeResourceRecordSetsError {
@@ -8038,33 +8057,43 @@ impl ChangeResourceRecordSetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidChangeBatch" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeResourceRecordSetsError::InvalidChangeBatch(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ChangeResourceRecordSetsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ChangeResourceRecordSetsError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeResourceRecordSetsError::NoSuchHealthCheck(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeResourceRecordSetsError::NoSuchHostedZone(parsed_error.message),
+                            &res,
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeResourceRecordSetsError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ChangeResourceRecordSetsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8090,12 +8119,14 @@ impl fmt::Display for ChangeResourceRecordSetsError {
             ChangeResourceRecordSetsError::PriorRequestNotComplete(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ChangeResourceRecordSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ChangeResourceRecordSetsError {}
 /// Errors returned by ChangeTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ChangeTagsForResourceError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -8107,6 +8138,8 @@ pub enum ChangeTagsForResourceError {
     PriorRequestNotComplete(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ChangeTagsForResourceError {
@@ -8118,33 +8151,40 @@ impl ChangeTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ChangeTagsForResourceError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ChangeTagsForResourceError::NoSuchHealthCheck(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ChangeTagsForResourceError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeTagsForResourceError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ThrottlingException" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::Throttling(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ChangeTagsForResourceError::Throttling(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(ChangeTagsForResourceError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -8170,12 +8210,14 @@ impl fmt::Display for ChangeTagsForResourceError {
                 write!(f, "{}", cause)
             }
             ChangeTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ChangeTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ChangeTagsForResourceError {}
 /// Errors returned by CreateHealthCheck
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHealthCheckError {
     /// <p><p> The health check you&#39;re attempting to create already exists. Amazon Route 53 returns this error when you submit a request that has the following values:</p> <ul> <li> <p>The same value for <code>CallerReference</code> as an existing health check, and one or more values that differ from the existing health check that has the same caller reference.</p> </li> <li> <p>The same value for <code>CallerReference</code> as a health check that you created and later deleted, regardless of the other settings in the request.</p> </li> </ul></p>
     HealthCheckAlreadyExists(String),
@@ -8183,6 +8225,8 @@ pub enum CreateHealthCheckError {
     InvalidInput(String),
     /// <p>This health check can't be created because the current account has reached the limit on the number of active health checks.</p> <p>For information about default limits, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>For information about how to get the current limit for an account, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetAccountLimit.html">GetAccountLimit</a>. To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p> <p>You have reached the maximum number of active health checks for an AWS account. To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p>
     TooManyHealthChecks(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHealthCheckError {
@@ -8194,21 +8238,24 @@ impl CreateHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthCheckAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHealthCheckError::HealthCheckAlreadyExists(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateHealthCheckError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateHealthCheckError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyHealthChecks" => {
-                        return RusotoError::Service(CreateHealthCheckError::TooManyHealthChecks(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateHealthCheckError::TooManyHealthChecks(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateHealthCheckError::Unhandled, &res),
                 }
             }
         }
@@ -8230,12 +8277,14 @@ impl fmt::Display for CreateHealthCheckError {
             CreateHealthCheckError::HealthCheckAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateHealthCheckError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateHealthCheckError::TooManyHealthChecks(ref cause) => write!(f, "{}", cause),
+            CreateHealthCheckError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHealthCheckError {}
 /// Errors returned by CreateHostedZone
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHostedZoneError {
     /// <p><p>The cause of this error depends on whether you&#39;re trying to create a public or a private hosted zone:</p> <ul> <li> <p> <b>Public hosted zone:</b> Two hosted zones that have the same name or that have a parent/child relationship (example.com and test.example.com) can&#39;t have any common name servers. You tried to create a hosted zone that has the same name as an existing hosted zone or that&#39;s the parent or child of an existing hosted zone, and you specified a delegation set that shares one or more name servers with the existing hosted zone. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateReusableDelegationSet.html">CreateReusableDelegationSet</a>.</p> </li> <li> <p> <b>Private hosted zone:</b> You specified an Amazon VPC that you&#39;re already using for another hosted zone, and the domain that you specified for one of the hosted zones is a subdomain of the domain that you specified for the other hosted zone. For example, you can&#39;t use the same Amazon VPC for the hosted zones for example.com and test.example.com.</p> </li> </ul></p>
     ConflictingDomainExists(String),
@@ -8255,6 +8304,8 @@ pub enum CreateHostedZoneError {
     NoSuchDelegationSet(String),
     /// <p>This operation can't be completed either because the current account has reached the limit on the number of hosted zones or because you've reached the limit on the number of hosted zones that can be associated with a reusable delegation set.</p> <p>For information about default limits, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>To get the current limit on hosted zones that can be created by an account, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetAccountLimit.html">GetAccountLimit</a>.</p> <p>To get the current limit on hosted zones that can be associated with a reusable delegation set, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetReusableDelegationSetLimit.html">GetReusableDelegationSetLimit</a>.</p> <p>To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p>
     TooManyHostedZones(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHostedZoneError {
@@ -8266,51 +8317,60 @@ impl CreateHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConflictingDomainExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHostedZoneError::ConflictingDomainExists(parsed_error.message),
+                            &res,
                         )
                     }
                     "DelegationSetNotAvailable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHostedZoneError::DelegationSetNotAvailable(parsed_error.message),
+                            &res,
                         )
                     }
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHostedZoneError::DelegationSetNotReusable(parsed_error.message),
+                            &res,
                         )
                     }
                     "HostedZoneAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateHostedZoneError::HostedZoneAlreadyExists(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDomainName" => {
-                        return RusotoError::Service(CreateHostedZoneError::InvalidDomainName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateHostedZoneError::InvalidDomainName(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateHostedZoneError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateHostedZoneError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(CreateHostedZoneError::InvalidVPCId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateHostedZoneError::InvalidVPCId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(CreateHostedZoneError::NoSuchDelegationSet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateHostedZoneError::NoSuchDelegationSet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyHostedZones" => {
-                        return RusotoError::Service(CreateHostedZoneError::TooManyHostedZones(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateHostedZoneError::TooManyHostedZones(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateHostedZoneError::Unhandled, &res),
                 }
             }
         }
@@ -8338,12 +8398,14 @@ impl fmt::Display for CreateHostedZoneError {
             CreateHostedZoneError::InvalidVPCId(ref cause) => write!(f, "{}", cause),
             CreateHostedZoneError::NoSuchDelegationSet(ref cause) => write!(f, "{}", cause),
             CreateHostedZoneError::TooManyHostedZones(ref cause) => write!(f, "{}", cause),
+            CreateHostedZoneError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHostedZoneError {}
 /// Errors returned by CreateQueryLoggingConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateQueryLoggingConfigError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
     ConcurrentModification(String),
@@ -8357,6 +8419,8 @@ pub enum CreateQueryLoggingConfigError {
     NoSuchHostedZone(String),
     /// <p>You can create only one query logging configuration for a hosted zone, and a query logging configuration already exists for this hosted zone.</p>
     QueryLoggingConfigAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateQueryLoggingConfigError {
@@ -8368,44 +8432,55 @@ impl CreateQueryLoggingConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateQueryLoggingConfigError::ConcurrentModification(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientCloudWatchLogsResourcePolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateQueryLoggingConfigError::InsufficientCloudWatchLogsResourcePolicy(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateQueryLoggingConfigError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateQueryLoggingConfigError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchCloudWatchLogsLogGroup" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateQueryLoggingConfigError::NoSuchCloudWatchLogsLogGroup(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateQueryLoggingConfigError::NoSuchHostedZone(parsed_error.message),
+                            &res,
                         )
                     }
                     "QueryLoggingConfigAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateQueryLoggingConfigError::QueryLoggingConfigAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateQueryLoggingConfigError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8438,12 +8513,14 @@ impl fmt::Display for CreateQueryLoggingConfigError {
             CreateQueryLoggingConfigError::QueryLoggingConfigAlreadyExists(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateQueryLoggingConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateQueryLoggingConfigError {}
 /// Errors returned by CreateReusableDelegationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReusableDelegationSetError {
     /// <p>A delegation set with the same owner and caller reference combination has already been created.</p>
     DelegationSetAlreadyCreated(String),
@@ -8459,6 +8536,8 @@ pub enum CreateReusableDelegationSetError {
     InvalidInput(String),
     /// <p>This operation can't be completed either because the current account has reached the limit on reusable delegation sets that it can create or because you've reached the limit on the number of Amazon VPCs that you can associate with a private hosted zone. To get the current limit on the number of reusable delegation sets, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetAccountLimit.html">GetAccountLimit</a>. To get the current limit on the number of Amazon VPCs that you can associate with a private hosted zone, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetHostedZoneLimit.html">GetHostedZoneLimit</a>. To request a higher limit, <a href="http://aws.amazon.com/route53-request">create a case</a> with the AWS Support Center.</p>
     LimitsExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReusableDelegationSetError {
@@ -8472,49 +8551,61 @@ impl CreateReusableDelegationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetAlreadyCreated" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateReusableDelegationSetError::DelegationSetAlreadyCreated(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DelegationSetAlreadyReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateReusableDelegationSetError::DelegationSetAlreadyReusable(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DelegationSetNotAvailable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateReusableDelegationSetError::DelegationSetNotAvailable(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "HostedZoneNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateReusableDelegationSetError::HostedZoneNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateReusableDelegationSetError::InvalidArgument(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateReusableDelegationSetError::InvalidInput(parsed_error.message),
+                            &res,
                         )
                     }
                     "LimitsExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateReusableDelegationSetError::LimitsExceeded(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateReusableDelegationSetError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8548,12 +8639,14 @@ impl fmt::Display for CreateReusableDelegationSetError {
             CreateReusableDelegationSetError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             CreateReusableDelegationSetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateReusableDelegationSetError::LimitsExceeded(ref cause) => write!(f, "{}", cause),
+            CreateReusableDelegationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReusableDelegationSetError {}
 /// Errors returned by CreateTrafficPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTrafficPolicyError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -8563,6 +8656,8 @@ pub enum CreateTrafficPolicyError {
     TooManyTrafficPolicies(String),
     /// <p>A traffic policy that has the same value for <code>Name</code> already exists.</p>
     TrafficPolicyAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTrafficPolicyError {
@@ -8574,30 +8669,34 @@ impl CreateTrafficPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateTrafficPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTrafficPolicyError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidTrafficPolicyDocument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTrafficPolicyError::InvalidTrafficPolicyDocument(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyTrafficPolicies" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTrafficPolicyError::TooManyTrafficPolicies(parsed_error.message),
+                            &res,
                         )
                     }
                     "TrafficPolicyAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTrafficPolicyError::TrafficPolicyAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateTrafficPolicyError::Unhandled, &res),
                 }
             }
         }
@@ -8624,12 +8723,14 @@ impl fmt::Display for CreateTrafficPolicyError {
             CreateTrafficPolicyError::TrafficPolicyAlreadyExists(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateTrafficPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTrafficPolicyError {}
 /// Errors returned by CreateTrafficPolicyInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTrafficPolicyInstanceError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -8641,6 +8742,8 @@ pub enum CreateTrafficPolicyInstanceError {
     TooManyTrafficPolicyInstances(String),
     /// <p>There is already a traffic policy instance with the specified ID.</p>
     TrafficPolicyInstanceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTrafficPolicyInstanceError {
@@ -8654,39 +8757,49 @@ impl CreateTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTrafficPolicyInstanceError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTrafficPolicyInstanceError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyTrafficPolicyInstances" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTrafficPolicyInstanceError::TooManyTrafficPolicyInstances(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TrafficPolicyInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTrafficPolicyInstanceError::TrafficPolicyInstanceAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateTrafficPolicyInstanceError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8716,12 +8829,14 @@ impl fmt::Display for CreateTrafficPolicyInstanceError {
             CreateTrafficPolicyInstanceError::TrafficPolicyInstanceAlreadyExists(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateTrafficPolicyInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTrafficPolicyInstanceError {}
 /// Errors returned by CreateTrafficPolicyVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTrafficPolicyVersionError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
     ConcurrentModification(String),
@@ -8733,6 +8848,8 @@ pub enum CreateTrafficPolicyVersionError {
     NoSuchTrafficPolicy(String),
     /// <p>This traffic policy version can't be created because you've reached the limit of 1000 on the number of versions that you can create for the current traffic policy.</p> <p>To create more traffic policy versions, you can use <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetTrafficPolicy.html">GetTrafficPolicy</a> to get the traffic policy document for a specified traffic policy version, and then use <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateTrafficPolicy.html">CreateTrafficPolicy</a> to create a new traffic policy using the traffic policy document.</p>
     TooManyTrafficPolicyVersionsForCurrentPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTrafficPolicyVersionError {
@@ -8745,7 +8862,7 @@ impl CreateTrafficPolicyVersionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConcurrentModification" => return RusotoError::Service(CreateTrafficPolicyVersionError::ConcurrentModification(parsed_error.message)),"InvalidInput" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidInput(parsed_error.message)),"InvalidTrafficPolicyDocument" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument(parsed_error.message)),"NoSuchTrafficPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::NoSuchTrafficPolicy(parsed_error.message)),"TooManyTrafficPolicyVersionsForCurrentPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(parsed_error.message)),_ => {}
+                                    "ConcurrentModification" => return RusotoError::service(CreateTrafficPolicyVersionError::ConcurrentModification(parsed_error.message), &res),"InvalidInput" => return RusotoError::service(CreateTrafficPolicyVersionError::InvalidInput(parsed_error.message), &res),"InvalidTrafficPolicyDocument" => return RusotoError::service(CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument(parsed_error.message), &res),"NoSuchTrafficPolicy" => return RusotoError::service(CreateTrafficPolicyVersionError::NoSuchTrafficPolicy(parsed_error.message), &res),"TooManyTrafficPolicyVersionsForCurrentPolicy" => return RusotoError::service(CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(parsed_error.message), &res),_ => return RusotoError::unhandled(CreateTrafficPolicyVersionError::Unhandled, &res),
                                 }
             }
         }
@@ -8777,12 +8894,14 @@ impl fmt::Display for CreateTrafficPolicyVersionError {
             CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(
                 ref cause,
             ) => write!(f, "{}", cause),
+            CreateTrafficPolicyVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTrafficPolicyVersionError {}
 /// Errors returned by CreateVPCAssociationAuthorization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVPCAssociationAuthorizationError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
     ConcurrentModification(String),
@@ -8794,6 +8913,8 @@ pub enum CreateVPCAssociationAuthorizationError {
     NoSuchHostedZone(String),
     /// <p>You've created the maximum number of authorizations that can be created for the specified hosted zone. To authorize another VPC to be associated with the hosted zone, submit a <code>DeleteVPCAssociationAuthorization</code> request to remove an existing authorization. To get a list of existing authorizations, submit a <code>ListVPCAssociationAuthorizations</code> request.</p>
     TooManyVPCAssociationAuthorizations(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVPCAssociationAuthorizationError {
@@ -8807,39 +8928,49 @@ impl CreateVPCAssociationAuthorizationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateVPCAssociationAuthorizationError::ConcurrentModification(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateVPCAssociationAuthorizationError::InvalidInput(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateVPCAssociationAuthorizationError::InvalidVPCId(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateVPCAssociationAuthorizationError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "TooManyVPCAssociationAuthorizations" => return RusotoError::Service(
+                    "TooManyVPCAssociationAuthorizations" => return RusotoError::service(
                         CreateVPCAssociationAuthorizationError::TooManyVPCAssociationAuthorizations(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateVPCAssociationAuthorizationError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -8873,12 +9004,16 @@ impl fmt::Display for CreateVPCAssociationAuthorizationError {
             CreateVPCAssociationAuthorizationError::TooManyVPCAssociationAuthorizations(
                 ref cause,
             ) => write!(f, "{}", cause),
+            CreateVPCAssociationAuthorizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateVPCAssociationAuthorizationError {}
 /// Errors returned by DeleteHealthCheck
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteHealthCheckError {
     /// <p>This error code is not in use.</p>
     HealthCheckInUse(String),
@@ -8886,6 +9021,8 @@ pub enum DeleteHealthCheckError {
     InvalidInput(String),
     /// <p>No health check exists with the specified ID.</p>
     NoSuchHealthCheck(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteHealthCheckError {
@@ -8897,21 +9034,24 @@ impl DeleteHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthCheckInUse" => {
-                        return RusotoError::Service(DeleteHealthCheckError::HealthCheckInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteHealthCheckError::HealthCheckInUse(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteHealthCheckError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteHealthCheckError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(DeleteHealthCheckError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteHealthCheckError::NoSuchHealthCheck(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteHealthCheckError::Unhandled, &res),
                 }
             }
         }
@@ -8933,12 +9073,14 @@ impl fmt::Display for DeleteHealthCheckError {
             DeleteHealthCheckError::HealthCheckInUse(ref cause) => write!(f, "{}", cause),
             DeleteHealthCheckError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteHealthCheckError::NoSuchHealthCheck(ref cause) => write!(f, "{}", cause),
+            DeleteHealthCheckError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteHealthCheckError {}
 /// Errors returned by DeleteHostedZone
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteHostedZoneError {
     /// <p>The hosted zone contains resource records that are not SOA or NS records.</p>
     HostedZoneNotEmpty(String),
@@ -8950,6 +9092,8 @@ pub enum DeleteHostedZoneError {
     NoSuchHostedZone(String),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
     PriorRequestNotComplete(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteHostedZoneError {
@@ -8961,31 +9105,36 @@ impl DeleteHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HostedZoneNotEmpty" => {
-                        return RusotoError::Service(DeleteHostedZoneError::HostedZoneNotEmpty(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteHostedZoneError::HostedZoneNotEmpty(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidDomainName" => {
-                        return RusotoError::Service(DeleteHostedZoneError::InvalidDomainName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteHostedZoneError::InvalidDomainName(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteHostedZoneError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteHostedZoneError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(DeleteHostedZoneError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteHostedZoneError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteHostedZoneError::PriorRequestNotComplete(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteHostedZoneError::Unhandled, &res),
                 }
             }
         }
@@ -9009,12 +9158,14 @@ impl fmt::Display for DeleteHostedZoneError {
             DeleteHostedZoneError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteHostedZoneError::NoSuchHostedZone(ref cause) => write!(f, "{}", cause),
             DeleteHostedZoneError::PriorRequestNotComplete(ref cause) => write!(f, "{}", cause),
+            DeleteHostedZoneError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteHostedZoneError {}
 /// Errors returned by DeleteQueryLoggingConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteQueryLoggingConfigError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
     ConcurrentModification(String),
@@ -9022,6 +9173,8 @@ pub enum DeleteQueryLoggingConfigError {
     InvalidInput(String),
     /// <p>There is no DNS query logging configuration with the specified ID.</p>
     NoSuchQueryLoggingConfig(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteQueryLoggingConfigError {
@@ -9033,25 +9186,33 @@ impl DeleteQueryLoggingConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteQueryLoggingConfigError::ConcurrentModification(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteQueryLoggingConfigError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteQueryLoggingConfigError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchQueryLoggingConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteQueryLoggingConfigError::NoSuchQueryLoggingConfig(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteQueryLoggingConfigError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9077,12 +9238,14 @@ impl fmt::Display for DeleteQueryLoggingConfigError {
             DeleteQueryLoggingConfigError::NoSuchQueryLoggingConfig(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteQueryLoggingConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteQueryLoggingConfigError {}
 /// Errors returned by DeleteReusableDelegationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReusableDelegationSetError {
     /// <p>The specified delegation contains associated hosted zones which must be deleted before the reusable delegation set can be deleted.</p>
     DelegationSetInUse(String),
@@ -9092,6 +9255,8 @@ pub enum DeleteReusableDelegationSetError {
     InvalidInput(String),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
     NoSuchDelegationSet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReusableDelegationSetError {
@@ -9105,32 +9270,41 @@ impl DeleteReusableDelegationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReusableDelegationSetError::DelegationSetInUse(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReusableDelegationSetError::DelegationSetNotReusable(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReusableDelegationSetError::InvalidInput(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReusableDelegationSetError::NoSuchDelegationSet(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteReusableDelegationSetError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9159,12 +9333,14 @@ impl fmt::Display for DeleteReusableDelegationSetError {
             DeleteReusableDelegationSetError::NoSuchDelegationSet(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteReusableDelegationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReusableDelegationSetError {}
 /// Errors returned by DeleteTrafficPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTrafficPolicyError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
     ConcurrentModification(String),
@@ -9174,6 +9350,8 @@ pub enum DeleteTrafficPolicyError {
     NoSuchTrafficPolicy(String),
     /// <p>One or more traffic policy instances were created by using the specified traffic policy.</p>
     TrafficPolicyInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTrafficPolicyError {
@@ -9185,26 +9363,30 @@ impl DeleteTrafficPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteTrafficPolicyError::ConcurrentModification(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteTrafficPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTrafficPolicyError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(DeleteTrafficPolicyError::NoSuchTrafficPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTrafficPolicyError::NoSuchTrafficPolicy(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TrafficPolicyInUse" => {
-                        return RusotoError::Service(DeleteTrafficPolicyError::TrafficPolicyInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTrafficPolicyError::TrafficPolicyInUse(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteTrafficPolicyError::Unhandled, &res),
                 }
             }
         }
@@ -9227,12 +9409,14 @@ impl fmt::Display for DeleteTrafficPolicyError {
             DeleteTrafficPolicyError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteTrafficPolicyError::NoSuchTrafficPolicy(ref cause) => write!(f, "{}", cause),
             DeleteTrafficPolicyError::TrafficPolicyInUse(ref cause) => write!(f, "{}", cause),
+            DeleteTrafficPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTrafficPolicyError {}
 /// Errors returned by DeleteTrafficPolicyInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTrafficPolicyInstanceError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -9240,6 +9424,8 @@ pub enum DeleteTrafficPolicyInstanceError {
     NoSuchTrafficPolicyInstance(String),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
     PriorRequestNotComplete(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTrafficPolicyInstanceError {
@@ -9253,25 +9439,33 @@ impl DeleteTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteTrafficPolicyInstanceError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteTrafficPolicyInstanceError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9297,12 +9491,14 @@ impl fmt::Display for DeleteTrafficPolicyInstanceError {
             DeleteTrafficPolicyInstanceError::PriorRequestNotComplete(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteTrafficPolicyInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTrafficPolicyInstanceError {}
 /// Errors returned by DeleteVPCAssociationAuthorization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVPCAssociationAuthorizationError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
     ConcurrentModification(String),
@@ -9314,6 +9510,8 @@ pub enum DeleteVPCAssociationAuthorizationError {
     NoSuchHostedZone(String),
     /// <p>The VPC that you specified is not authorized to be associated with the hosted zone.</p>
     VPCAssociationAuthorizationNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVPCAssociationAuthorizationError {
@@ -9327,39 +9525,49 @@ impl DeleteVPCAssociationAuthorizationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteVPCAssociationAuthorizationError::ConcurrentModification(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteVPCAssociationAuthorizationError::InvalidInput(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteVPCAssociationAuthorizationError::InvalidVPCId(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteVPCAssociationAuthorizationError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "VPCAssociationAuthorizationNotFound" => return RusotoError::Service(
+                    "VPCAssociationAuthorizationNotFound" => return RusotoError::service(
                         DeleteVPCAssociationAuthorizationError::VPCAssociationAuthorizationNotFound(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteVPCAssociationAuthorizationError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -9393,12 +9601,16 @@ impl fmt::Display for DeleteVPCAssociationAuthorizationError {
             DeleteVPCAssociationAuthorizationError::VPCAssociationAuthorizationNotFound(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DeleteVPCAssociationAuthorizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteVPCAssociationAuthorizationError {}
 /// Errors returned by DisassociateVPCFromHostedZone
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateVPCFromHostedZoneError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -9410,6 +9622,8 @@ pub enum DisassociateVPCFromHostedZoneError {
     NoSuchHostedZone(String),
     /// <p>The specified VPC and hosted zone are not currently associated.</p>
     VPCAssociationNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateVPCFromHostedZoneError {
@@ -9423,37 +9637,47 @@ impl DisassociateVPCFromHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisassociateVPCFromHostedZoneError::InvalidInput(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisassociateVPCFromHostedZoneError::InvalidVPCId(parsed_error.message),
+                            &res,
                         )
                     }
                     "LastVPCAssociation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisassociateVPCFromHostedZoneError::LastVPCAssociation(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisassociateVPCFromHostedZoneError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "VPCAssociationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisassociateVPCFromHostedZoneError::VPCAssociationNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DisassociateVPCFromHostedZoneError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9483,15 +9707,21 @@ impl fmt::Display for DisassociateVPCFromHostedZoneError {
             DisassociateVPCFromHostedZoneError::VPCAssociationNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateVPCFromHostedZoneError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateVPCFromHostedZoneError {}
 /// Errors returned by GetAccountLimit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAccountLimitError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAccountLimitError {
@@ -9503,11 +9733,12 @@ impl GetAccountLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetAccountLimitError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetAccountLimitError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetAccountLimitError::Unhandled, &res),
                 }
             }
         }
@@ -9527,17 +9758,21 @@ impl fmt::Display for GetAccountLimitError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetAccountLimitError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            GetAccountLimitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAccountLimitError {}
 /// Errors returned by GetChange
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetChangeError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>A change with the specified change ID does not exist.</p>
     NoSuchChange(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetChangeError {
@@ -9549,16 +9784,18 @@ impl GetChangeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetChangeError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetChangeError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchChange" => {
-                        return RusotoError::Service(GetChangeError::NoSuchChange(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetChangeError::NoSuchChange(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetChangeError::Unhandled, &res),
                 }
             }
         }
@@ -9579,13 +9816,18 @@ impl fmt::Display for GetChangeError {
         match *self {
             GetChangeError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetChangeError::NoSuchChange(ref cause) => write!(f, "{}", cause),
+            GetChangeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetChangeError {}
 /// Errors returned by GetCheckerIpRanges
 #[derive(Debug, PartialEq)]
-pub enum GetCheckerIpRangesError {}
+#[non_exhaustive]
+pub enum GetCheckerIpRangesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetCheckerIpRangesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetCheckerIpRangesError> {
@@ -9593,10 +9835,8 @@ impl GetCheckerIpRangesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetCheckerIpRangesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9613,17 +9853,22 @@ impl GetCheckerIpRangesError {
 impl fmt::Display for GetCheckerIpRangesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetCheckerIpRangesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetCheckerIpRangesError {}
 /// Errors returned by GetGeoLocation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGeoLocationError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>Amazon Route 53 doesn't support the specified geographic location.</p>
     NoSuchGeoLocation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGeoLocationError {
@@ -9635,16 +9880,18 @@ impl GetGeoLocationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetGeoLocationError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetGeoLocationError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchGeoLocation" => {
-                        return RusotoError::Service(GetGeoLocationError::NoSuchGeoLocation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetGeoLocationError::NoSuchGeoLocation(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetGeoLocationError::Unhandled, &res),
                 }
             }
         }
@@ -9665,12 +9912,14 @@ impl fmt::Display for GetGeoLocationError {
         match *self {
             GetGeoLocationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetGeoLocationError::NoSuchGeoLocation(ref cause) => write!(f, "{}", cause),
+            GetGeoLocationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGeoLocationError {}
 /// Errors returned by GetHealthCheck
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetHealthCheckError {
     /// <p>The resource you're trying to access is unsupported on this Amazon Route 53 endpoint.</p>
     IncompatibleVersion(String),
@@ -9678,6 +9927,8 @@ pub enum GetHealthCheckError {
     InvalidInput(String),
     /// <p>No health check exists with the specified ID.</p>
     NoSuchHealthCheck(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetHealthCheckError {
@@ -9689,21 +9940,24 @@ impl GetHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleVersion" => {
-                        return RusotoError::Service(GetHealthCheckError::IncompatibleVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHealthCheckError::IncompatibleVersion(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHealthCheckError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHealthCheckError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(GetHealthCheckError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHealthCheckError::NoSuchHealthCheck(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetHealthCheckError::Unhandled, &res),
                 }
             }
         }
@@ -9725,13 +9979,18 @@ impl fmt::Display for GetHealthCheckError {
             GetHealthCheckError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             GetHealthCheckError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetHealthCheckError::NoSuchHealthCheck(ref cause) => write!(f, "{}", cause),
+            GetHealthCheckError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetHealthCheckError {}
 /// Errors returned by GetHealthCheckCount
 #[derive(Debug, PartialEq)]
-pub enum GetHealthCheckCountError {}
+#[non_exhaustive]
+pub enum GetHealthCheckCountError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetHealthCheckCountError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetHealthCheckCountError> {
@@ -9739,10 +9998,8 @@ impl GetHealthCheckCountError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetHealthCheckCountError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9759,17 +10016,22 @@ impl GetHealthCheckCountError {
 impl fmt::Display for GetHealthCheckCountError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetHealthCheckCountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetHealthCheckCountError {}
 /// Errors returned by GetHealthCheckLastFailureReason
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetHealthCheckLastFailureReasonError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No health check exists with the specified ID.</p>
     NoSuchHealthCheck(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetHealthCheckLastFailureReasonError {
@@ -9783,20 +10045,27 @@ impl GetHealthCheckLastFailureReasonError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetHealthCheckLastFailureReasonError::InvalidInput(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetHealthCheckLastFailureReasonError::NoSuchHealthCheck(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetHealthCheckLastFailureReasonError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9819,17 +10088,23 @@ impl fmt::Display for GetHealthCheckLastFailureReasonError {
             GetHealthCheckLastFailureReasonError::NoSuchHealthCheck(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetHealthCheckLastFailureReasonError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetHealthCheckLastFailureReasonError {}
 /// Errors returned by GetHealthCheckStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetHealthCheckStatusError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No health check exists with the specified ID.</p>
     NoSuchHealthCheck(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetHealthCheckStatusError {
@@ -9841,16 +10116,18 @@ impl GetHealthCheckStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHealthCheckStatusError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHealthCheckStatusError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(GetHealthCheckStatusError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHealthCheckStatusError::NoSuchHealthCheck(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetHealthCheckStatusError::Unhandled, &res),
                 }
             }
         }
@@ -9871,17 +10148,21 @@ impl fmt::Display for GetHealthCheckStatusError {
         match *self {
             GetHealthCheckStatusError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetHealthCheckStatusError::NoSuchHealthCheck(ref cause) => write!(f, "{}", cause),
+            GetHealthCheckStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetHealthCheckStatusError {}
 /// Errors returned by GetHostedZone
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetHostedZoneError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No hosted zone exists with the ID that you specified.</p>
     NoSuchHostedZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetHostedZoneError {
@@ -9893,16 +10174,18 @@ impl GetHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHostedZoneError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHostedZoneError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(GetHostedZoneError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHostedZoneError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetHostedZoneError::Unhandled, &res),
                 }
             }
         }
@@ -9923,15 +10206,19 @@ impl fmt::Display for GetHostedZoneError {
         match *self {
             GetHostedZoneError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetHostedZoneError::NoSuchHostedZone(ref cause) => write!(f, "{}", cause),
+            GetHostedZoneError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetHostedZoneError {}
 /// Errors returned by GetHostedZoneCount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetHostedZoneCountError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetHostedZoneCountError {
@@ -9943,11 +10230,12 @@ impl GetHostedZoneCountError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHostedZoneCountError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHostedZoneCountError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetHostedZoneCountError::Unhandled, &res),
                 }
             }
         }
@@ -9967,12 +10255,14 @@ impl fmt::Display for GetHostedZoneCountError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetHostedZoneCountError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            GetHostedZoneCountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetHostedZoneCountError {}
 /// Errors returned by GetHostedZoneLimit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetHostedZoneLimitError {
     /// <p>The specified hosted zone is a public hosted zone, not a private hosted zone.</p>
     HostedZoneNotPrivate(String),
@@ -9980,6 +10270,8 @@ pub enum GetHostedZoneLimitError {
     InvalidInput(String),
     /// <p>No hosted zone exists with the ID that you specified.</p>
     NoSuchHostedZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetHostedZoneLimitError {
@@ -9991,21 +10283,24 @@ impl GetHostedZoneLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HostedZoneNotPrivate" => {
-                        return RusotoError::Service(GetHostedZoneLimitError::HostedZoneNotPrivate(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHostedZoneLimitError::HostedZoneNotPrivate(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHostedZoneLimitError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHostedZoneLimitError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(GetHostedZoneLimitError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetHostedZoneLimitError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetHostedZoneLimitError::Unhandled, &res),
                 }
             }
         }
@@ -10027,17 +10322,21 @@ impl fmt::Display for GetHostedZoneLimitError {
             GetHostedZoneLimitError::HostedZoneNotPrivate(ref cause) => write!(f, "{}", cause),
             GetHostedZoneLimitError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetHostedZoneLimitError::NoSuchHostedZone(ref cause) => write!(f, "{}", cause),
+            GetHostedZoneLimitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetHostedZoneLimitError {}
 /// Errors returned by GetQueryLoggingConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetQueryLoggingConfigError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>There is no DNS query logging configuration with the specified ID.</p>
     NoSuchQueryLoggingConfig(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetQueryLoggingConfigError {
@@ -10049,18 +10348,22 @@ impl GetQueryLoggingConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetQueryLoggingConfigError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetQueryLoggingConfigError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchQueryLoggingConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetQueryLoggingConfigError::NoSuchQueryLoggingConfig(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(GetQueryLoggingConfigError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -10083,12 +10386,14 @@ impl fmt::Display for GetQueryLoggingConfigError {
             GetQueryLoggingConfigError::NoSuchQueryLoggingConfig(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetQueryLoggingConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetQueryLoggingConfigError {}
 /// Errors returned by GetReusableDelegationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetReusableDelegationSetError {
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
     DelegationSetNotReusable(String),
@@ -10096,6 +10401,8 @@ pub enum GetReusableDelegationSetError {
     InvalidInput(String),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
     NoSuchDelegationSet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetReusableDelegationSetError {
@@ -10107,25 +10414,33 @@ impl GetReusableDelegationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetReusableDelegationSetError::DelegationSetNotReusable(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(GetReusableDelegationSetError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetReusableDelegationSetError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetReusableDelegationSetError::NoSuchDelegationSet(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetReusableDelegationSetError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10149,17 +10464,21 @@ impl fmt::Display for GetReusableDelegationSetError {
             }
             GetReusableDelegationSetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetReusableDelegationSetError::NoSuchDelegationSet(ref cause) => write!(f, "{}", cause),
+            GetReusableDelegationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetReusableDelegationSetError {}
 /// Errors returned by GetReusableDelegationSetLimit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetReusableDelegationSetLimitError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
     NoSuchDelegationSet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetReusableDelegationSetLimitError {
@@ -10173,18 +10492,25 @@ impl GetReusableDelegationSetLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetReusableDelegationSetLimitError::InvalidInput(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetReusableDelegationSetLimitError::NoSuchDelegationSet(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetReusableDelegationSetLimitError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10207,17 +10533,23 @@ impl fmt::Display for GetReusableDelegationSetLimitError {
             GetReusableDelegationSetLimitError::NoSuchDelegationSet(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetReusableDelegationSetLimitError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetReusableDelegationSetLimitError {}
 /// Errors returned by GetTrafficPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTrafficPolicyError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No traffic policy exists with the specified ID.</p>
     NoSuchTrafficPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTrafficPolicyError {
@@ -10229,16 +10561,18 @@ impl GetTrafficPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetTrafficPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTrafficPolicyError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(GetTrafficPolicyError::NoSuchTrafficPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTrafficPolicyError::NoSuchTrafficPolicy(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetTrafficPolicyError::Unhandled, &res),
                 }
             }
         }
@@ -10259,17 +10593,21 @@ impl fmt::Display for GetTrafficPolicyError {
         match *self {
             GetTrafficPolicyError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetTrafficPolicyError::NoSuchTrafficPolicy(ref cause) => write!(f, "{}", cause),
+            GetTrafficPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTrafficPolicyError {}
 /// Errors returned by GetTrafficPolicyInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTrafficPolicyInstanceError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No traffic policy instance exists with the specified ID.</p>
     NoSuchTrafficPolicyInstance(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTrafficPolicyInstanceError {
@@ -10281,18 +10619,25 @@ impl GetTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetTrafficPolicyInstanceError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetTrafficPolicyInstanceError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10315,13 +10660,18 @@ impl fmt::Display for GetTrafficPolicyInstanceError {
             GetTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetTrafficPolicyInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTrafficPolicyInstanceError {}
 /// Errors returned by GetTrafficPolicyInstanceCount
 #[derive(Debug, PartialEq)]
-pub enum GetTrafficPolicyInstanceCountError {}
+#[non_exhaustive]
+pub enum GetTrafficPolicyInstanceCountError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetTrafficPolicyInstanceCountError {
     pub fn from_response(
@@ -10331,10 +10681,8 @@ impl GetTrafficPolicyInstanceCountError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetTrafficPolicyInstanceCountError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10351,15 +10699,22 @@ impl GetTrafficPolicyInstanceCountError {
 impl fmt::Display for GetTrafficPolicyInstanceCountError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetTrafficPolicyInstanceCountError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetTrafficPolicyInstanceCountError {}
 /// Errors returned by ListGeoLocations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGeoLocationsError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGeoLocationsError {
@@ -10371,11 +10726,12 @@ impl ListGeoLocationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListGeoLocationsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListGeoLocationsError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListGeoLocationsError::Unhandled, &res),
                 }
             }
         }
@@ -10395,17 +10751,21 @@ impl fmt::Display for ListGeoLocationsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListGeoLocationsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListGeoLocationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGeoLocationsError {}
 /// Errors returned by ListHealthChecks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHealthChecksError {
     /// <p>The resource you're trying to access is unsupported on this Amazon Route 53 endpoint.</p>
     IncompatibleVersion(String),
     /// <p>The input is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHealthChecksError {
@@ -10417,16 +10777,18 @@ impl ListHealthChecksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleVersion" => {
-                        return RusotoError::Service(ListHealthChecksError::IncompatibleVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListHealthChecksError::IncompatibleVersion(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHealthChecksError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListHealthChecksError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListHealthChecksError::Unhandled, &res),
                 }
             }
         }
@@ -10447,12 +10809,14 @@ impl fmt::Display for ListHealthChecksError {
         match *self {
             ListHealthChecksError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             ListHealthChecksError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListHealthChecksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHealthChecksError {}
 /// Errors returned by ListHostedZones
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHostedZonesError {
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
     DelegationSetNotReusable(String),
@@ -10460,6 +10824,8 @@ pub enum ListHostedZonesError {
     InvalidInput(String),
     /// <p>A reusable delegation set with the specified ID does not exist.</p>
     NoSuchDelegationSet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHostedZonesError {
@@ -10471,21 +10837,24 @@ impl ListHostedZonesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListHostedZonesError::DelegationSetNotReusable(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHostedZonesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListHostedZonesError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(ListHostedZonesError::NoSuchDelegationSet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListHostedZonesError::NoSuchDelegationSet(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListHostedZonesError::Unhandled, &res),
                 }
             }
         }
@@ -10507,17 +10876,21 @@ impl fmt::Display for ListHostedZonesError {
             ListHostedZonesError::DelegationSetNotReusable(ref cause) => write!(f, "{}", cause),
             ListHostedZonesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListHostedZonesError::NoSuchDelegationSet(ref cause) => write!(f, "{}", cause),
+            ListHostedZonesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHostedZonesError {}
 /// Errors returned by ListHostedZonesByName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHostedZonesByNameError {
     /// <p>The specified domain name is not valid.</p>
     InvalidDomainName(String),
     /// <p>The input is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHostedZonesByNameError {
@@ -10529,16 +10902,20 @@ impl ListHostedZonesByNameError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidDomainName" => {
-                        return RusotoError::Service(ListHostedZonesByNameError::InvalidDomainName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListHostedZonesByNameError::InvalidDomainName(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHostedZonesByNameError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListHostedZonesByNameError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(ListHostedZonesByNameError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -10559,12 +10936,14 @@ impl fmt::Display for ListHostedZonesByNameError {
         match *self {
             ListHostedZonesByNameError::InvalidDomainName(ref cause) => write!(f, "{}", cause),
             ListHostedZonesByNameError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListHostedZonesByNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHostedZonesByNameError {}
 /// Errors returned by ListQueryLoggingConfigs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListQueryLoggingConfigsError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -10572,6 +10951,8 @@ pub enum ListQueryLoggingConfigsError {
     InvalidPaginationToken(String),
     /// <p>No hosted zone exists with the ID that you specified.</p>
     NoSuchHostedZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListQueryLoggingConfigsError {
@@ -10583,23 +10964,31 @@ impl ListQueryLoggingConfigsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListQueryLoggingConfigsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListQueryLoggingConfigsError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidPaginationToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListQueryLoggingConfigsError::InvalidPaginationToken(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListQueryLoggingConfigsError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListQueryLoggingConfigsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10623,17 +11012,21 @@ impl fmt::Display for ListQueryLoggingConfigsError {
                 write!(f, "{}", cause)
             }
             ListQueryLoggingConfigsError::NoSuchHostedZone(ref cause) => write!(f, "{}", cause),
+            ListQueryLoggingConfigsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListQueryLoggingConfigsError {}
 /// Errors returned by ListResourceRecordSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourceRecordSetsError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No hosted zone exists with the ID that you specified.</p>
     NoSuchHostedZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourceRecordSetsError {
@@ -10645,16 +11038,20 @@ impl ListResourceRecordSetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListResourceRecordSetsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListResourceRecordSetsError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ListResourceRecordSetsError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListResourceRecordSetsError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(ListResourceRecordSetsError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -10675,15 +11072,19 @@ impl fmt::Display for ListResourceRecordSetsError {
         match *self {
             ListResourceRecordSetsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListResourceRecordSetsError::NoSuchHostedZone(ref cause) => write!(f, "{}", cause),
+            ListResourceRecordSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourceRecordSetsError {}
 /// Errors returned by ListReusableDelegationSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListReusableDelegationSetsError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListReusableDelegationSetsError {
@@ -10697,11 +11098,17 @@ impl ListReusableDelegationSetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListReusableDelegationSetsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListReusableDelegationSetsError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListReusableDelegationSetsError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10721,12 +11128,14 @@ impl fmt::Display for ListReusableDelegationSetsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListReusableDelegationSetsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListReusableDelegationSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListReusableDelegationSetsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -10738,6 +11147,8 @@ pub enum ListTagsForResourceError {
     PriorRequestNotComplete(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -10749,31 +11160,36 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(ListTagsForResourceError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::NoSuchHealthCheck(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ListTagsForResourceError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::PriorRequestNotComplete(parsed_error.message),
+                            &res,
                         )
                     }
                     "ThrottlingException" => {
-                        return RusotoError::Service(ListTagsForResourceError::Throttling(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::Throttling(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
                 }
             }
         }
@@ -10797,12 +11213,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::NoSuchHostedZone(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::PriorRequestNotComplete(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTagsForResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourcesError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -10814,6 +11232,8 @@ pub enum ListTagsForResourcesError {
     PriorRequestNotComplete(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourcesError {
@@ -10825,33 +11245,38 @@ impl ListTagsForResourcesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTagsForResourcesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourcesError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(ListTagsForResourcesError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourcesError::NoSuchHealthCheck(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ListTagsForResourcesError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourcesError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourcesError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ThrottlingException" => {
-                        return RusotoError::Service(ListTagsForResourcesError::Throttling(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourcesError::Throttling(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTagsForResourcesError::Unhandled, &res),
                 }
             }
         }
@@ -10875,15 +11300,19 @@ impl fmt::Display for ListTagsForResourcesError {
             ListTagsForResourcesError::NoSuchHostedZone(ref cause) => write!(f, "{}", cause),
             ListTagsForResourcesError::PriorRequestNotComplete(ref cause) => write!(f, "{}", cause),
             ListTagsForResourcesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourcesError {}
 /// Errors returned by ListTrafficPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTrafficPoliciesError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTrafficPoliciesError {
@@ -10895,11 +11324,12 @@ impl ListTrafficPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTrafficPoliciesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTrafficPoliciesError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTrafficPoliciesError::Unhandled, &res),
                 }
             }
         }
@@ -10919,17 +11349,21 @@ impl fmt::Display for ListTrafficPoliciesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTrafficPoliciesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListTrafficPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTrafficPoliciesError {}
 /// Errors returned by ListTrafficPolicyInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTrafficPolicyInstancesError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No traffic policy instance exists with the specified ID.</p>
     NoSuchTrafficPolicyInstance(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTrafficPolicyInstancesError {
@@ -10943,18 +11377,25 @@ impl ListTrafficPolicyInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTrafficPolicyInstancesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTrafficPolicyInstancesError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTrafficPolicyInstancesError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListTrafficPolicyInstancesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10977,12 +11418,14 @@ impl fmt::Display for ListTrafficPolicyInstancesError {
             ListTrafficPolicyInstancesError::NoSuchTrafficPolicyInstance(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListTrafficPolicyInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTrafficPolicyInstancesError {}
 /// Errors returned by ListTrafficPolicyInstancesByHostedZone
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTrafficPolicyInstancesByHostedZoneError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -10990,6 +11433,8 @@ pub enum ListTrafficPolicyInstancesByHostedZoneError {
     NoSuchHostedZone(String),
     /// <p>No traffic policy instance exists with the specified ID.</p>
     NoSuchTrafficPolicyInstance(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTrafficPolicyInstancesByHostedZoneError {
@@ -11003,25 +11448,33 @@ impl ListTrafficPolicyInstancesByHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTrafficPolicyInstancesByHostedZoneError::InvalidInput(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTrafficPolicyInstancesByHostedZoneError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "NoSuchTrafficPolicyInstance" => return RusotoError::Service(
+                    "NoSuchTrafficPolicyInstance" => return RusotoError::service(
                         ListTrafficPolicyInstancesByHostedZoneError::NoSuchTrafficPolicyInstance(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListTrafficPolicyInstancesByHostedZoneError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -11049,12 +11502,16 @@ impl fmt::Display for ListTrafficPolicyInstancesByHostedZoneError {
             ListTrafficPolicyInstancesByHostedZoneError::NoSuchTrafficPolicyInstance(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListTrafficPolicyInstancesByHostedZoneError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListTrafficPolicyInstancesByHostedZoneError {}
 /// Errors returned by ListTrafficPolicyInstancesByPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTrafficPolicyInstancesByPolicyError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -11062,6 +11519,8 @@ pub enum ListTrafficPolicyInstancesByPolicyError {
     NoSuchTrafficPolicy(String),
     /// <p>No traffic policy instance exists with the specified ID.</p>
     NoSuchTrafficPolicyInstance(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTrafficPolicyInstancesByPolicyError {
@@ -11075,27 +11534,35 @@ impl ListTrafficPolicyInstancesByPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTrafficPolicyInstancesByPolicyError::InvalidInput(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListTrafficPolicyInstancesByPolicyError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11123,17 +11590,23 @@ impl fmt::Display for ListTrafficPolicyInstancesByPolicyError {
             ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicyInstance(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListTrafficPolicyInstancesByPolicyError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListTrafficPolicyInstancesByPolicyError {}
 /// Errors returned by ListTrafficPolicyVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTrafficPolicyVersionsError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No traffic policy exists with the specified ID.</p>
     NoSuchTrafficPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTrafficPolicyVersionsError {
@@ -11145,18 +11618,25 @@ impl ListTrafficPolicyVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTrafficPolicyVersionsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTrafficPolicyVersionsError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTrafficPolicyVersionsError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListTrafficPolicyVersionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11179,12 +11659,14 @@ impl fmt::Display for ListTrafficPolicyVersionsError {
             ListTrafficPolicyVersionsError::NoSuchTrafficPolicy(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListTrafficPolicyVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTrafficPolicyVersionsError {}
 /// Errors returned by ListVPCAssociationAuthorizations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVPCAssociationAuthorizationsError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
@@ -11192,6 +11674,8 @@ pub enum ListVPCAssociationAuthorizationsError {
     InvalidPaginationToken(String),
     /// <p>No hosted zone exists with the ID that you specified.</p>
     NoSuchHostedZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVPCAssociationAuthorizationsError {
@@ -11205,27 +11689,35 @@ impl ListVPCAssociationAuthorizationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListVPCAssociationAuthorizationsError::InvalidInput(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidPaginationToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListVPCAssociationAuthorizationsError::InvalidPaginationToken(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListVPCAssociationAuthorizationsError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListVPCAssociationAuthorizationsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11253,17 +11745,23 @@ impl fmt::Display for ListVPCAssociationAuthorizationsError {
             ListVPCAssociationAuthorizationsError::NoSuchHostedZone(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListVPCAssociationAuthorizationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListVPCAssociationAuthorizationsError {}
 /// Errors returned by TestDNSAnswer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestDNSAnswerError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No hosted zone exists with the ID that you specified.</p>
     NoSuchHostedZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestDNSAnswerError {
@@ -11275,16 +11773,18 @@ impl TestDNSAnswerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(TestDNSAnswerError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TestDNSAnswerError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(TestDNSAnswerError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TestDNSAnswerError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(TestDNSAnswerError::Unhandled, &res),
                 }
             }
         }
@@ -11305,12 +11805,14 @@ impl fmt::Display for TestDNSAnswerError {
         match *self {
             TestDNSAnswerError::InvalidInput(ref cause) => write!(f, "{}", cause),
             TestDNSAnswerError::NoSuchHostedZone(ref cause) => write!(f, "{}", cause),
+            TestDNSAnswerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TestDNSAnswerError {}
 /// Errors returned by UpdateHealthCheck
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateHealthCheckError {
     /// <p>The value of <code>HealthCheckVersion</code> in the request doesn't match the value of <code>HealthCheckVersion</code> in the health check.</p>
     HealthCheckVersionMismatch(String),
@@ -11318,6 +11820,8 @@ pub enum UpdateHealthCheckError {
     InvalidInput(String),
     /// <p>No health check exists with the specified ID.</p>
     NoSuchHealthCheck(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateHealthCheckError {
@@ -11329,23 +11833,26 @@ impl UpdateHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthCheckVersionMismatch" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateHealthCheckError::HealthCheckVersionMismatch(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateHealthCheckError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateHealthCheckError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(UpdateHealthCheckError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateHealthCheckError::NoSuchHealthCheck(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UpdateHealthCheckError::Unhandled, &res),
                 }
             }
         }
@@ -11367,17 +11874,21 @@ impl fmt::Display for UpdateHealthCheckError {
             UpdateHealthCheckError::HealthCheckVersionMismatch(ref cause) => write!(f, "{}", cause),
             UpdateHealthCheckError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateHealthCheckError::NoSuchHealthCheck(ref cause) => write!(f, "{}", cause),
+            UpdateHealthCheckError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateHealthCheckError {}
 /// Errors returned by UpdateHostedZoneComment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateHostedZoneCommentError {
     /// <p>The input is not valid.</p>
     InvalidInput(String),
     /// <p>No hosted zone exists with the ID that you specified.</p>
     NoSuchHostedZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateHostedZoneCommentError {
@@ -11389,16 +11900,23 @@ impl UpdateHostedZoneCommentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateHostedZoneCommentError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateHostedZoneCommentError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateHostedZoneCommentError::NoSuchHostedZone(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateHostedZoneCommentError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11419,12 +11937,14 @@ impl fmt::Display for UpdateHostedZoneCommentError {
         match *self {
             UpdateHostedZoneCommentError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateHostedZoneCommentError::NoSuchHostedZone(ref cause) => write!(f, "{}", cause),
+            UpdateHostedZoneCommentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateHostedZoneCommentError {}
 /// Errors returned by UpdateTrafficPolicyComment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTrafficPolicyCommentError {
     /// <p>Another user submitted a request to create, update, or delete the object at the same time that you did. Retry the request. </p>
     ConcurrentModification(String),
@@ -11432,6 +11952,8 @@ pub enum UpdateTrafficPolicyCommentError {
     InvalidInput(String),
     /// <p>No traffic policy exists with the specified ID.</p>
     NoSuchTrafficPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTrafficPolicyCommentError {
@@ -11445,25 +11967,33 @@ impl UpdateTrafficPolicyCommentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTrafficPolicyCommentError::ConcurrentModification(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateTrafficPolicyCommentError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateTrafficPolicyCommentError::InvalidInput(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTrafficPolicyCommentError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateTrafficPolicyCommentError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11489,12 +12019,14 @@ impl fmt::Display for UpdateTrafficPolicyCommentError {
             UpdateTrafficPolicyCommentError::NoSuchTrafficPolicy(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateTrafficPolicyCommentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTrafficPolicyCommentError {}
 /// Errors returned by UpdateTrafficPolicyInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTrafficPolicyInstanceError {
     /// <p>You tried to update a traffic policy instance by using a traffic policy version that has a different DNS type than the current type for the instance. You specified the type in the JSON document in the <code>CreateTrafficPolicy</code> or <code>CreateTrafficPolicyVersion</code>request. </p>
     ConflictingTypes(String),
@@ -11506,6 +12038,8 @@ pub enum UpdateTrafficPolicyInstanceError {
     NoSuchTrafficPolicyInstance(String),
     /// <p>If Amazon Route 53 can't process a request before the next request arrives, it will reject subsequent requests for the same hosted zone and return an <code>HTTP 400 error</code> (<code>Bad request</code>). If Route 53 returns this error repeatedly for the same request, we recommend that you wait, in intervals of increasing duration, before you try the request again.</p>
     PriorRequestNotComplete(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTrafficPolicyInstanceError {
@@ -11519,39 +12053,49 @@ impl UpdateTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConflictingTypes" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTrafficPolicyInstanceError::ConflictingTypes(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTrafficPolicyInstanceError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateTrafficPolicyInstanceError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11581,6 +12125,7 @@ impl fmt::Display for UpdateTrafficPolicyInstanceError {
             UpdateTrafficPolicyInstanceError::PriorRequestNotComplete(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateTrafficPolicyInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -11963,6 +12508,7 @@ impl Route53Client {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> Route53Client {
         Route53Client {
             client: Client::shared(),
@@ -11988,46 +12534,53 @@ impl Route53Client {
     pub fn new_with_client(client: Client, region: region::Region) -> Route53Client {
         Route53Client { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<Route53Client> {
+        rusoto_core::ClientBuilder::new(Route53Client::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> Route53Client {
+        Route53Client {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Route53 for Route53Client {
-    /// <p><p>Associates an Amazon VPC with a private hosted zone. </p> <important> <p>To perform the association, the VPC and the private hosted zone must already exist. You can&#39;t convert a public hosted zone into a private hosted zone.</p> </important> <note> <p>If you want to associate a VPC that was created by using one AWS account with a private hosted zone that was created by using a different account, the AWS account that created the private hosted zone must first submit a <code>CreateVPCAssociationAuthorization</code> request. Then the account that created the VPC must submit an <code>AssociateVPCWithHostedZone</code> request.</p> </note></p>
+impl Route53Client {
+    /// Calls `associate_vpc_with_hosted_zone`, also returning the metadata of the response.
     #[allow(unused_variables, warnings)]
-    async fn associate_vpc_with_hosted_zone(
+    pub async fn associate_vpc_with_hosted_zone_with_metadata(
         &self,
         input: AssociateVPCWithHostedZoneRequest,
-    ) -> Result<AssociateVPCWithHostedZoneResponse, RusotoError<AssociateVPCWithHostedZoneError>>
-    {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/associatevpc",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        AssociateVPCWithHostedZoneRequestSerializer::serialize(
-            &mut writer,
-            "AssociateVPCWithHostedZoneRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
+    ) -> Result<
+        (AssociateVPCWithHostedZoneResponse, ResponseMetadata),
+        RusotoError<AssociateVPCWithHostedZoneError>,
+    > {
+        let request = self.build_associate_vpc_with_hosted_zone_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AssociateVPCWithHostedZoneError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12046,45 +12599,75 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `associate_vpc_with_hosted_zone` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_vpc_with_hosted_zone(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_vpc_with_hosted_zone_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// For TXT records, see <a href="./util/fn.quote_txt_record.html">util::quote_txt_record</a>
-    /// <p>Creates, changes, or deletes a resource record set, which contains authoritative DNS information for a specified domain name or subdomain name. For example, you can use <code>ChangeResourceRecordSets</code> to create a resource record set that routes traffic for test.example.com to a web server that has an IP address of 192.0.2.44.</p> <p> <b>Change Batches and Transactional Changes</b> </p> <p>The request body must include a document with a <code>ChangeResourceRecordSetsRequest</code> element. The request body contains a list of change items, known as a change batch. Change batches are considered transactional changes. When using the Amazon Route 53 API to change resource record sets, Route 53 either makes all or none of the changes in a change batch request. This ensures that Route 53 never partially implements the intended changes to the resource record sets in a hosted zone. </p> <p>For example, a change batch request that deletes the <code>CNAME</code> record for www.example.com and creates an alias resource record set for www.example.com. Route 53 deletes the first resource record set and creates the second resource record set in a single operation. If either the <code>DELETE</code> or the <code>CREATE</code> action fails, then both changes (plus any other changes in the batch) fail, and the original <code>CNAME</code> record continues to exist.</p> <important> <p>Due to the nature of transactional changes, you can't delete the same resource record set more than once in a single change batch. If you attempt to delete the same change batch more than once, Route 53 returns an <code>InvalidChangeBatch</code> error.</p> </important> <p> <b>Traffic Flow</b> </p> <p>To create resource record sets for complex routing configurations, use either the traffic flow visual editor in the Route 53 console or the API actions for traffic policies and traffic policy instances. Save the configuration as a traffic policy, then associate the traffic policy with one or more domain names (such as example.com) or subdomain names (such as www.example.com), in the same hosted zone or in multiple hosted zones. You can roll back the updates if the new configuration isn't performing as expected. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/traffic-flow.html">Using Traffic Flow to Route DNS Traffic</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p> <b>Create, Delete, and Upsert</b> </p> <p>Use <code>ChangeResourceRecordsSetsRequest</code> to perform the following actions:</p> <ul> <li> <p> <code>CREATE</code>: Creates a resource record set that has the specified values.</p> </li> <li> <p> <code>DELETE</code>: Deletes an existing resource record set that has the specified values.</p> </li> <li> <p> <code>UPSERT</code>: If a resource record set does not already exist, AWS creates it. If a resource set does exist, Route 53 updates it with the values in the request. </p> </li> </ul> <p> <b>Syntaxes for Creating, Updating, and Deleting Resource Record Sets</b> </p> <p>The syntax for a request depends on the type of resource record set that you want to create, delete, or update, such as weighted, alias, or failover. The XML elements in your request must appear in the order listed in the syntax. </p> <p>For an example for each type of resource record set, see "Examples."</p> <p>Don't refer to the syntax in the "Parameter Syntax" section, which includes all of the elements for every kind of resource record set that you can create, delete, or update by using <code>ChangeResourceRecordSets</code>. </p> <p> <b>Change Propagation to Route 53 DNS Servers</b> </p> <p>When you submit a <code>ChangeResourceRecordSets</code> request, Route 53 propagates your changes to all of the Route 53 authoritative DNS servers. While your changes are propagating, <code>GetChange</code> returns a status of <code>PENDING</code>. When propagation is complete, <code>GetChange</code> returns a status of <code>INSYNC</code>. Changes generally propagate to all Route 53 name servers within 60 seconds. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetChange.html">GetChange</a>.</p> <p> <b>Limits on ChangeResourceRecordSets Requests</b> </p> <p>For information about the limits on a <code>ChangeResourceRecordSets</code> request, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
     #[allow(unused_variables, warnings)]
-    async fn change_resource_record_sets(
+    fn build_associate_vpc_with_hosted_zone_request(
         &self,
-        input: ChangeResourceRecordSetsRequest,
-    ) -> Result<ChangeResourceRecordSetsResponse, RusotoError<ChangeResourceRecordSetsError>> {
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/rrset/",
+            "/2013-04-01/hostedzone/{id}/associatevpc",
             id = input.hosted_zone_id
         )
         .replace("/hostedzone/hostedzone/", "/hostedzone/")
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("AssociateVPCWithHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
-        ChangeResourceRecordSetsRequestSerializer::serialize(
+        AssociateVPCWithHostedZoneRequestSerializer::serialize(
             &mut writer,
-            "ChangeResourceRecordSetsRequest",
+            "AssociateVPCWithHostedZoneRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `change_resource_record_sets`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn change_resource_record_sets_with_metadata(
+        &self,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> Result<
+        (ChangeResourceRecordSetsResponse, ResponseMetadata),
+        RusotoError<ChangeResourceRecordSetsError>,
+    > {
+        let request = self.build_change_resource_record_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ChangeResourceRecordSetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12103,45 +12686,75 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `change_resource_record_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_change_resource_record_sets(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_change_resource_record_sets_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Adds, edits, or deletes tags for a health check or a hosted zone.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
     #[allow(unused_variables, warnings)]
-    async fn change_tags_for_resource(
+    fn build_change_resource_record_sets_request(
         &self,
-        input: ChangeTagsForResourceRequest,
-    ) -> Result<ChangeTagsForResourceResponse, RusotoError<ChangeTagsForResourceError>> {
+        input: ChangeResourceRecordSetsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2013-04-01/tags/{resource_type}/{resource_id}",
-            resource_id = input.resource_id,
-            resource_type = input.resource_type
+            "/2013-04-01/hostedzone/{id}/rrset/",
+            id = input.hosted_zone_id
         )
         .replace("/hostedzone/hostedzone/", "/hostedzone/")
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeResourceRecordSets");
 
         let mut writer = EventWriter::new(Vec::new());
-        ChangeTagsForResourceRequestSerializer::serialize(
+        ChangeResourceRecordSetsRequestSerializer::serialize(
             &mut writer,
-            "ChangeTagsForResourceRequest",
+            "ChangeResourceRecordSetsRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `change_tags_for_resource`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn change_tags_for_resource_with_metadata(
+        &self,
+        input: ChangeTagsForResourceRequest,
+    ) -> Result<
+        (ChangeTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ChangeTagsForResourceError>,
+    > {
+        let request = self.build_change_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ChangeTagsForResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12160,39 +12773,74 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `change_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_change_tags_for_resource(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ChangeTagsForResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_change_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Creates a new health check.</p> <p>For information about adding health checks to resource record sets, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ResourceRecordSet.html#Route53-Type-ResourceRecordSet-HealthCheckId">HealthCheckId</a> in <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>. </p> <p> <b>ELB Load Balancers</b> </p> <p>If you&#39;re registering EC2 instances with an Elastic Load Balancing (ELB) load balancer, do not create Amazon Route 53 health checks for the EC2 instances. When you register an EC2 instance with a load balancer, you configure settings for an ELB health check, which performs a similar function to a Route 53 health check.</p> <p> <b>Private Hosted Zones</b> </p> <p>You can associate health checks with failover resource record sets in a private hosted zone. Note the following:</p> <ul> <li> <p>Route 53 health checkers are outside the VPC. To check the health of an endpoint within a VPC by IP address, you must assign a public IP address to the instance in the VPC.</p> </li> <li> <p>You can configure a health checker to check the health of an external resource that the instance relies on, such as a database server.</p> </li> <li> <p>You can create a CloudWatch metric, associate an alarm with the metric, and then create a health check that is based on the state of the alarm. For example, you might create a CloudWatch metric that checks the status of the Amazon EC2 <code>StatusCheckFailed</code> metric, add an alarm to the metric, and then create a health check that is based on the state of the alarm. For information about creating CloudWatch metrics and alarms by using the CloudWatch console, see the <a href="http://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/WhatIsCloudWatch.html">Amazon CloudWatch User Guide</a>.</p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn create_health_check(
+    fn build_change_tags_for_resource_request(
         &self,
-        input: CreateHealthCheckRequest,
-    ) -> Result<CreateHealthCheckResponse, RusotoError<CreateHealthCheckError>> {
-        let request_uri = "/2013-04-01/healthcheck";
+        input: ChangeTagsForResourceRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2013-04-01/tags/{resource_type}/{resource_id}",
+            resource_id = input.resource_id,
+            resource_type = input.resource_type
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeTagsForResource");
 
         let mut writer = EventWriter::new(Vec::new());
-        CreateHealthCheckRequestSerializer::serialize(
+        ChangeTagsForResourceRequestSerializer::serialize(
             &mut writer,
-            "CreateHealthCheckRequest",
+            "ChangeTagsForResourceRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_health_check`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_health_check_with_metadata(
+        &self,
+        input: CreateHealthCheckRequest,
+    ) -> Result<(CreateHealthCheckResponse, ResponseMetadata), RusotoError<CreateHealthCheckError>>
+    {
+        let request = self.build_create_health_check_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateHealthCheckError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12210,39 +12858,65 @@ impl Route53 for Route53Client {
         }
         let value = response.headers.get("Location").unwrap().to_owned();
         result.location = value; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new public or private hosted zone. You create records in a public hosted zone to define how you want to route traffic on the internet for a domain, such as example.com, and its subdomains (apex.example.com, acme.example.com). You create records in a private hosted zone to define how you want to route traffic for a domain and its subdomains within one or more Amazon Virtual Private Clouds (Amazon VPCs). </p> <important> <p>You can't convert a public hosted zone to a private hosted zone or vice versa. Instead, you must create a new hosted zone with the same name and create new resource record sets.</p> </important> <p>For more information about charges for hosted zones, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p> <p>Note the following:</p> <ul> <li> <p>You can't create a hosted zone for a top-level domain (TLD) such as .com.</p> </li> <li> <p>For public hosted zones, Amazon Route 53 automatically creates a default SOA record and four NS records for the zone. For more information about SOA and NS records, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/SOA-NSrecords.html">NS and SOA Records that Route 53 Creates for a Hosted Zone</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>If you want to use the same name servers for multiple public hosted zones, you can optionally associate a reusable delegation set with the hosted zone. See the <code>DelegationSetId</code> element.</p> </li> <li> <p>If your domain is registered with a registrar other than Route 53, you must update the name servers with your registrar to make Route 53 the DNS service for the domain. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/MigratingDNS.html">Migrating DNS Service for an Existing Domain to Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>. </p> </li> </ul> <p>When you submit a <code>CreateHostedZone</code> request, the initial status of the hosted zone is <code>PENDING</code>. For public hosted zones, this means that the NS and SOA records are not yet available on all Route 53 DNS servers. When the NS and SOA records are available, the status of the zone changes to <code>INSYNC</code>.</p>
-    #[allow(unused_variables, warnings)]
-    async fn create_hosted_zone(
+    /// Presigns the request `create_health_check` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_health_check(
         &self,
-        input: CreateHostedZoneRequest,
-    ) -> Result<CreateHostedZoneResponse, RusotoError<CreateHostedZoneError>> {
-        let request_uri = "/2013-04-01/hostedzone";
+        presigner: &rusoto_core::Presigner,
+        input: CreateHealthCheckRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_health_check_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_create_health_check_request(&self, input: CreateHealthCheckRequest) -> SignedRequest {
+        let request_uri = "/2013-04-01/healthcheck";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHealthCheck");
 
         let mut writer = EventWriter::new(Vec::new());
-        CreateHostedZoneRequestSerializer::serialize(
+        CreateHealthCheckRequestSerializer::serialize(
             &mut writer,
-            "CreateHostedZoneRequest",
+            "CreateHealthCheckRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_hosted_zone`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_hosted_zone_with_metadata(
+        &self,
+        input: CreateHostedZoneRequest,
+    ) -> Result<(CreateHostedZoneResponse, ResponseMetadata), RusotoError<CreateHostedZoneError>>
+    {
+        let request = self.build_create_hosted_zone_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateHostedZoneError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12260,39 +12934,67 @@ impl Route53 for Route53Client {
         }
         let value = response.headers.get("Location").unwrap().to_owned();
         result.location = value; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a configuration for DNS query logging. After you create a query logging configuration, Amazon Route 53 begins to publish log data to an Amazon CloudWatch Logs log group.</p> <p>DNS query logs contain information about the queries that Route 53 receives for a specified public hosted zone, such as the following:</p> <ul> <li> <p>Route 53 edge location that responded to the DNS query</p> </li> <li> <p>Domain or subdomain that was requested</p> </li> <li> <p>DNS record type, such as A or AAAA</p> </li> <li> <p>DNS response code, such as <code>NoError</code> or <code>ServFail</code> </p> </li> </ul> <dl> <dt>Log Group and Resource Policy</dt> <dd> <p>Before you create a query logging configuration, perform the following operations.</p> <note> <p>If you create a query logging configuration using the Route 53 console, Route 53 performs these operations automatically.</p> </note> <ol> <li> <p>Create a CloudWatch Logs log group, and make note of the ARN, which you specify when you create a query logging configuration. Note the following:</p> <ul> <li> <p>You must create the log group in the us-east-1 region.</p> </li> <li> <p>You must use the same AWS account to create the log group and the hosted zone that you want to configure query logging for.</p> </li> <li> <p>When you create log groups for query logging, we recommend that you use a consistent prefix, for example:</p> <p> <code>/aws/route53/<i>hosted zone name</i> </code> </p> <p>In the next step, you&#39;ll create a resource policy, which controls access to one or more log groups and the associated AWS resources, such as Route 53 hosted zones. There&#39;s a limit on the number of resource policies that you can create, so we recommend that you use a consistent prefix so you can use the same resource policy for all the log groups that you create for query logging.</p> </li> </ul> </li> <li> <p>Create a CloudWatch Logs resource policy, and give it the permissions that Route 53 needs to create log streams and to send query logs to log streams. For the value of <code>Resource</code>, specify the ARN for the log group that you created in the previous step. To use the same resource policy for all the CloudWatch Logs log groups that you created for query logging configurations, replace the hosted zone name with <code><em></code>, for example:</p> <p> <code>arn:aws:logs:us-east-1:123412341234:log-group:/aws/route53/</em></code> </p> <note> <p>You can&#39;t use the CloudWatch console to create or edit a resource policy. You must use the CloudWatch API, one of the AWS SDKs, or the AWS CLI.</p> </note> </li> </ol> </dd> <dt>Log Streams and Edge Locations</dt> <dd> <p>When Route 53 finishes creating the configuration for DNS query logging, it does the following:</p> <ul> <li> <p>Creates a log stream for an edge location the first time that the edge location responds to DNS queries for the specified hosted zone. That log stream is used to log all queries that Route 53 responds to for that edge location.</p> </li> <li> <p>Begins to send query logs to the applicable log stream.</p> </li> </ul> <p>The name of each log stream is in the following format:</p> <p> <code> <i>hosted zone ID</i>/<i>edge location code</i> </code> </p> <p>The edge location code is a three-letter code and an arbitrarily assigned number, for example, DFW3. The three-letter code typically corresponds with the International Air Transport Association airport code for an airport near the edge location. (These abbreviations might change in the future.) For a list of edge locations, see &quot;The Route 53 Global Network&quot; on the <a href="http://aws.amazon.com/route53/details/">Route 53 Product Details</a> page.</p> </dd> <dt>Queries That Are Logged</dt> <dd> <p>Query logs contain only the queries that DNS resolvers forward to Route 53. If a DNS resolver has already cached the response to a query (such as the IP address for a load balancer for example.com), the resolver will continue to return the cached response. It doesn&#39;t forward another query to Route 53 until the TTL for the corresponding resource record set expires. Depending on how many DNS queries are submitted for a resource record set, and depending on the TTL for that resource record set, query logs might contain information about only one query out of every several thousand queries that are submitted to DNS. For more information about how DNS works, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/welcome-dns-service.html">Routing Internet Traffic to Your Website or Web Application</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Log File Format</dt> <dd> <p>For a list of the values in each query log and the format of each value, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Pricing</dt> <dd> <p>For information about charges for query logs, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </dd> <dt>How to Stop Logging</dt> <dd> <p>If you want Route 53 to stop sending query logs to CloudWatch Logs, delete the query logging configuration. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_DeleteQueryLoggingConfig.html">DeleteQueryLoggingConfig</a>.</p> </dd> </dl></p>
-    #[allow(unused_variables, warnings)]
-    async fn create_query_logging_config(
+    /// Presigns the request `create_hosted_zone` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hosted_zone(
         &self,
-        input: CreateQueryLoggingConfigRequest,
-    ) -> Result<CreateQueryLoggingConfigResponse, RusotoError<CreateQueryLoggingConfigError>> {
-        let request_uri = "/2013-04-01/queryloggingconfig";
+        presigner: &rusoto_core::Presigner,
+        input: CreateHostedZoneRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hosted_zone_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_create_hosted_zone_request(&self, input: CreateHostedZoneRequest) -> SignedRequest {
+        let request_uri = "/2013-04-01/hostedzone";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
-        CreateQueryLoggingConfigRequestSerializer::serialize(
+        CreateHostedZoneRequestSerializer::serialize(
             &mut writer,
-            "CreateQueryLoggingConfigRequest",
+            "CreateHostedZoneRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_query_logging_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_query_logging_config_with_metadata(
+        &self,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> Result<
+        (CreateQueryLoggingConfigResponse, ResponseMetadata),
+        RusotoError<CreateQueryLoggingConfigError>,
+    > {
+        let request = self.build_create_query_logging_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateQueryLoggingConfigError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12312,40 +13014,70 @@ impl Route53 for Route53Client {
         }
         let value = response.headers.get("Location").unwrap().to_owned();
         result.location = value; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_query_logging_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_query_logging_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_query_logging_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Creates a delegation set (a group of four name servers) that can be reused by multiple hosted zones. If a hosted zoned ID is specified, <code>CreateReusableDelegationSet</code> marks the delegation set associated with that zone as reusable.</p> <note> <p>You can&#39;t associate a reusable delegation set with a private hosted zone.</p> </note> <p>For information about using a reusable delegation set to configure white label name servers, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/white-label-name-servers.html">Configuring White Label Name Servers</a>.</p> <p>The process for migrating existing hosted zones to use a reusable delegation set is comparable to the process for configuring white label name servers. You need to perform the following steps:</p> <ol> <li> <p>Create a reusable delegation set.</p> </li> <li> <p>Recreate hosted zones, and reduce the TTL to 60 seconds or less.</p> </li> <li> <p>Recreate resource record sets in the new hosted zones.</p> </li> <li> <p>Change the registrar&#39;s name servers to use the name servers for the new hosted zones.</p> </li> <li> <p>Monitor traffic for the website or application.</p> </li> <li> <p>Change TTLs back to their original values.</p> </li> </ol> <p>If you want to migrate existing hosted zones to use a reusable delegation set, the existing hosted zones can&#39;t use any of the name servers that are assigned to the reusable delegation set. If one or more hosted zones do use one or more name servers that are assigned to the reusable delegation set, you can do one of the following:</p> <ul> <li> <p>For small numbers of hosted zones—up to a few hundred—it&#39;s relatively easy to create reusable delegation sets until you get one that has four name servers that don&#39;t overlap with any of the name servers in your hosted zones.</p> </li> <li> <p>For larger numbers of hosted zones, the easiest solution is to use more than one reusable delegation set.</p> </li> <li> <p>For larger numbers of hosted zones, you can also migrate hosted zones that have overlapping name servers to hosted zones that don&#39;t have overlapping name servers, then migrate the hosted zones again to use the reusable delegation set.</p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn create_reusable_delegation_set(
+    fn build_create_query_logging_config_request(
         &self,
-        input: CreateReusableDelegationSetRequest,
-    ) -> Result<CreateReusableDelegationSetResponse, RusotoError<CreateReusableDelegationSetError>>
-    {
-        let request_uri = "/2013-04-01/delegationset";
+        input: CreateQueryLoggingConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateQueryLoggingConfig");
 
         let mut writer = EventWriter::new(Vec::new());
-        CreateReusableDelegationSetRequestSerializer::serialize(
+        CreateQueryLoggingConfigRequestSerializer::serialize(
             &mut writer,
-            "CreateReusableDelegationSetRequest",
+            "CreateQueryLoggingConfigRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_reusable_delegation_set`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_reusable_delegation_set_with_metadata(
+        &self,
+        input: CreateReusableDelegationSetRequest,
+    ) -> Result<
+        (CreateReusableDelegationSetResponse, ResponseMetadata),
+        RusotoError<CreateReusableDelegationSetError>,
+    > {
+        let request = self.build_create_reusable_delegation_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateReusableDelegationSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12365,39 +13097,70 @@ impl Route53 for Route53Client {
         }
         let value = response.headers.get("Location").unwrap().to_owned();
         result.location = value; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_reusable_delegation_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_reusable_delegation_set(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateReusableDelegationSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_reusable_delegation_set_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Creates a traffic policy, which you use to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com).</p>
     #[allow(unused_variables, warnings)]
-    async fn create_traffic_policy(
+    fn build_create_reusable_delegation_set_request(
         &self,
-        input: CreateTrafficPolicyRequest,
-    ) -> Result<CreateTrafficPolicyResponse, RusotoError<CreateTrafficPolicyError>> {
-        let request_uri = "/2013-04-01/trafficpolicy";
+        input: CreateReusableDelegationSetRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateReusableDelegationSet");
 
         let mut writer = EventWriter::new(Vec::new());
-        CreateTrafficPolicyRequestSerializer::serialize(
+        CreateReusableDelegationSetRequestSerializer::serialize(
             &mut writer,
-            "CreateTrafficPolicyRequest",
+            "CreateReusableDelegationSetRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_traffic_policy`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_traffic_policy_with_metadata(
+        &self,
+        input: CreateTrafficPolicyRequest,
+    ) -> Result<
+        (CreateTrafficPolicyResponse, ResponseMetadata),
+        RusotoError<CreateTrafficPolicyError>,
+    > {
+        let request = self.build_create_traffic_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateTrafficPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12415,40 +13178,70 @@ impl Route53 for Route53Client {
         }
         let value = response.headers.get("Location").unwrap().to_owned();
         result.location = value; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_traffic_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_traffic_policy(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateTrafficPolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_traffic_policy_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Creates resource record sets in a specified hosted zone based on the settings in a specified traffic policy version. In addition, <code>CreateTrafficPolicyInstance</code> associates the resource record sets with a specified domain name (such as example.com) or subdomain name (such as www.example.com). Amazon Route 53 responds to DNS queries for the domain or subdomain name by using the resource record sets that <code>CreateTrafficPolicyInstance</code> created.</p>
     #[allow(unused_variables, warnings)]
-    async fn create_traffic_policy_instance(
+    fn build_create_traffic_policy_request(
         &self,
-        input: CreateTrafficPolicyInstanceRequest,
-    ) -> Result<CreateTrafficPolicyInstanceResponse, RusotoError<CreateTrafficPolicyInstanceError>>
-    {
-        let request_uri = "/2013-04-01/trafficpolicyinstance";
+        input: CreateTrafficPolicyRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2013-04-01/trafficpolicy";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicy");
 
         let mut writer = EventWriter::new(Vec::new());
-        CreateTrafficPolicyInstanceRequestSerializer::serialize(
+        CreateTrafficPolicyRequestSerializer::serialize(
             &mut writer,
-            "CreateTrafficPolicyInstanceRequest",
+            "CreateTrafficPolicyRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_traffic_policy_instance`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_traffic_policy_instance_with_metadata(
+        &self,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> Result<
+        (CreateTrafficPolicyInstanceResponse, ResponseMetadata),
+        RusotoError<CreateTrafficPolicyInstanceError>,
+    > {
+        let request = self.build_create_traffic_policy_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateTrafficPolicyInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12468,42 +13261,70 @@ impl Route53 for Route53Client {
         }
         let value = response.headers.get("Location").unwrap().to_owned();
         result.location = value; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_traffic_policy_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_traffic_policy_instance(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_traffic_policy_instance_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Creates a new version of an existing traffic policy. When you create a new version of a traffic policy, you specify the ID of the traffic policy that you want to update and a JSON-formatted document that describes the new version. You use traffic policies to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com). You can create a maximum of 1000 versions of a traffic policy. If you reach the limit and need to create another version, you'll need to start a new traffic policy.</p>
     #[allow(unused_variables, warnings)]
-    async fn create_traffic_policy_version(
+    fn build_create_traffic_policy_instance_request(
         &self,
-        input: CreateTrafficPolicyVersionRequest,
-    ) -> Result<CreateTrafficPolicyVersionResponse, RusotoError<CreateTrafficPolicyVersionError>>
-    {
-        let request_uri = format!("/2013-04-01/trafficpolicy/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2013-04-01/trafficpolicyinstance";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyInstance");
 
         let mut writer = EventWriter::new(Vec::new());
-        CreateTrafficPolicyVersionRequestSerializer::serialize(
+        CreateTrafficPolicyInstanceRequestSerializer::serialize(
             &mut writer,
-            "CreateTrafficPolicyVersionRequest",
+            "CreateTrafficPolicyInstanceRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_traffic_policy_version`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_traffic_policy_version_with_metadata(
+        &self,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> Result<
+        (CreateTrafficPolicyVersionResponse, ResponseMetadata),
+        RusotoError<CreateTrafficPolicyVersionError>,
+    > {
+        let request = self.build_create_traffic_policy_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateTrafficPolicyVersionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12523,49 +13344,74 @@ impl Route53 for Route53Client {
         }
         let value = response.headers.get("Location").unwrap().to_owned();
         result.location = value; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_traffic_policy_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_traffic_policy_version(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_traffic_policy_version_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Authorizes the AWS account that created a specified VPC to submit an <code>AssociateVPCWithHostedZone</code> request to associate the VPC with a specified hosted zone that was created by a different account. To submit a <code>CreateVPCAssociationAuthorization</code> request, you must use the account that created the hosted zone. After you authorize the association, use the account that created the VPC to submit an <code>AssociateVPCWithHostedZone</code> request.</p> <note> <p>If you want to associate multiple VPCs that you created by using one account with a hosted zone that you created by using a different account, you must submit one authorization request for each VPC.</p> </note></p>
     #[allow(unused_variables, warnings)]
-    async fn create_vpc_association_authorization(
+    fn build_create_traffic_policy_version_request(
         &self,
-        input: CreateVPCAssociationAuthorizationRequest,
-    ) -> Result<
-        CreateVPCAssociationAuthorizationResponse,
-        RusotoError<CreateVPCAssociationAuthorizationError>,
-    > {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/authorizevpcassociation",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/trafficpolicy/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyVersion");
 
         let mut writer = EventWriter::new(Vec::new());
-        CreateVPCAssociationAuthorizationRequestSerializer::serialize(
+        CreateTrafficPolicyVersionRequestSerializer::serialize(
             &mut writer,
-            "CreateVPCAssociationAuthorizationRequest",
+            "CreateTrafficPolicyVersionRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_vpc_association_authorization`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_vpc_association_authorization_with_metadata(
+        &self,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> Result<
+        (CreateVPCAssociationAuthorizationResponse, ResponseMetadata),
+        RusotoError<CreateVPCAssociationAuthorizationError>,
+    > {
+        let request = self.build_create_vpc_association_authorization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateVPCAssociationAuthorizationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12584,35 +13430,73 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_vpc_association_authorization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_vpc_association_authorization(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_vpc_association_authorization_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Deletes a health check.</p> <important> <p>Amazon Route 53 does not prevent you from deleting a health check even if the health check is associated with one or more resource record sets. If you delete a health check and you don&#39;t update the associated resource record sets, the future status of the health check can&#39;t be predicted and may change. This will affect the routing of DNS queries for your DNS failover configuration. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html#health-checks-deleting.html">Replacing and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_health_check(
+    fn build_create_vpc_association_authorization_request(
         &self,
-        input: DeleteHealthCheckRequest,
-    ) -> Result<DeleteHealthCheckResponse, RusotoError<DeleteHealthCheckError>> {
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2013-04-01/healthcheck/{health_check_id}",
-            health_check_id = input.health_check_id
+            "/2013-04-01/hostedzone/{id}/authorizevpcassociation",
+            id = input.hosted_zone_id
         )
         .replace("/hostedzone/hostedzone/", "/hostedzone/")
         .replace("/change/change/", "/change/");
 
-        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateVPCAssociationAuthorization");
+
+        let mut writer = EventWriter::new(Vec::new());
+        CreateVPCAssociationAuthorizationRequestSerializer::serialize(
+            &mut writer,
+            "CreateVPCAssociationAuthorizationRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        request
+    }
+
+    /// Calls `delete_health_check`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_health_check_with_metadata(
+        &self,
+        input: DeleteHealthCheckRequest,
+    ) -> Result<(DeleteHealthCheckResponse, ResponseMetadata), RusotoError<DeleteHealthCheckError>>
+    {
+        let request = self.build_delete_health_check_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteHealthCheckError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12629,32 +13513,61 @@ impl Route53 for Route53Client {
                 DeleteHealthCheckResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes a hosted zone.</p> <p>If the hosted zone was created by another service, such as AWS Cloud Map, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DeleteHostedZone.html#delete-public-hosted-zone-created-by-another-service">Deleting Public Hosted Zones That Were Created by Another Service</a> in the <i>Amazon Route 53 Developer Guide</i> for information about how to delete it. (The process is the same for public and private hosted zones that were created by another service.)</p> <p>If you want to keep your domain registration but you want to stop routing internet traffic to your website or web application, we recommend that you delete resource record sets in the hosted zone instead of deleting the hosted zone.</p> <important> <p>If you delete a hosted zone, you can&#39;t undelete it. You must create a new hosted zone and update the name servers for your domain registration, which can require up to 48 hours to take effect. (If you delegated responsibility for a subdomain to a hosted zone and you delete the child hosted zone, you must update the name servers in the parent hosted zone.) In addition, if you delete a hosted zone, someone could hijack the domain and route traffic to their own resources using your domain name.</p> </important> <p>If you want to avoid the monthly charge for the hosted zone, you can transfer DNS service for the domain to a free DNS service. When you transfer DNS service, you have to update the name servers for the domain registration. If the domain is registered with Route 53, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_UpdateDomainNameservers.html">UpdateDomainNameservers</a> for information about how to replace Route 53 name servers with name servers for the new DNS service. If the domain is registered with another registrar, use the method provided by the registrar to update name servers for the domain registration. For more information, perform an internet search on &quot;free DNS service.&quot;</p> <p>You can delete a hosted zone only if it contains only the default SOA record and NS resource record sets. If the hosted zone contains other resource record sets, you must delete them before you can delete the hosted zone. If you try to delete a hosted zone that contains other resource record sets, the request fails, and Route 53 returns a <code>HostedZoneNotEmpty</code> error. For information about deleting records from your hosted zone, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>.</p> <p>To verify that the hosted zone has been deleted, do one of the following:</p> <ul> <li> <p>Use the <code>GetHostedZone</code> action to request information about the hosted zone.</p> </li> <li> <p>Use the <code>ListHostedZones</code> action to get a list of the hosted zones associated with the current AWS account.</p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn delete_hosted_zone(
+    /// Presigns the request `delete_health_check` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_health_check(
         &self,
-        input: DeleteHostedZoneRequest,
-    ) -> Result<DeleteHostedZoneResponse, RusotoError<DeleteHostedZoneError>> {
-        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
+        presigner: &rusoto_core::Presigner,
+        input: DeleteHealthCheckRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_health_check_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_delete_health_check_request(&self, input: DeleteHealthCheckRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/2013-04-01/healthcheck/{health_check_id}",
+            health_check_id = input.health_check_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHealthCheck");
+
+        request
+    }
+
+    /// Calls `delete_hosted_zone`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_hosted_zone_with_metadata(
+        &self,
+        input: DeleteHostedZoneRequest,
+    ) -> Result<(DeleteHostedZoneResponse, ResponseMetadata), RusotoError<DeleteHostedZoneError>>
+    {
+        let request = self.build_delete_hosted_zone_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteHostedZoneError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12671,32 +13584,60 @@ impl Route53 for Route53Client {
                 DeleteHostedZoneResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a configuration for DNS query logging. If you delete a configuration, Amazon Route 53 stops sending query logs to CloudWatch Logs. Route 53 doesn't delete any logs that are already in CloudWatch Logs.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>.</p>
-    #[allow(unused_variables, warnings)]
-    async fn delete_query_logging_config(
+    /// Presigns the request `delete_hosted_zone` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_hosted_zone(
         &self,
-        input: DeleteQueryLoggingConfigRequest,
-    ) -> Result<DeleteQueryLoggingConfigResponse, RusotoError<DeleteQueryLoggingConfigError>> {
-        let request_uri = format!("/2013-04-01/queryloggingconfig/{id}", id = input.id)
+        presigner: &rusoto_core::Presigner,
+        input: DeleteHostedZoneRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_hosted_zone_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_delete_hosted_zone_request(&self, input: DeleteHostedZoneRequest) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
             .replace("/hostedzone/hostedzone/", "/hostedzone/")
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHostedZone");
+
+        request
+    }
+
+    /// Calls `delete_query_logging_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_query_logging_config_with_metadata(
+        &self,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> Result<
+        (DeleteQueryLoggingConfigResponse, ResponseMetadata),
+        RusotoError<DeleteQueryLoggingConfigError>,
+    > {
+        let request = self.build_delete_query_logging_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteQueryLoggingConfigError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12715,33 +13656,63 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `delete_query_logging_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_query_logging_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_query_logging_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Deletes a reusable delegation set.</p> <important> <p>You can delete a reusable delegation set only if it isn't associated with any hosted zones.</p> </important> <p>To verify that the reusable delegation set is not associated with any hosted zones, submit a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetReusableDelegationSet.html">GetReusableDelegationSet</a> request and specify the ID of the reusable delegation set that you want to delete.</p>
     #[allow(unused_variables, warnings)]
-    async fn delete_reusable_delegation_set(
+    fn build_delete_query_logging_config_request(
         &self,
-        input: DeleteReusableDelegationSetRequest,
-    ) -> Result<DeleteReusableDelegationSetResponse, RusotoError<DeleteReusableDelegationSetError>>
-    {
-        let request_uri = format!("/2013-04-01/delegationset/{id}", id = input.id)
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/queryloggingconfig/{id}", id = input.id)
             .replace("/hostedzone/hostedzone/", "/hostedzone/")
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteQueryLoggingConfig");
+
+        request
+    }
+
+    /// Calls `delete_reusable_delegation_set`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_reusable_delegation_set_with_metadata(
+        &self,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> Result<
+        (DeleteReusableDelegationSetResponse, ResponseMetadata),
+        RusotoError<DeleteReusableDelegationSetError>,
+    > {
+        let request = self.build_delete_reusable_delegation_set_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteReusableDelegationSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12760,36 +13731,63 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `delete_reusable_delegation_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_reusable_delegation_set(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_reusable_delegation_set_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Deletes a traffic policy.</p>
     #[allow(unused_variables, warnings)]
-    async fn delete_traffic_policy(
+    fn build_delete_reusable_delegation_set_request(
         &self,
-        input: DeleteTrafficPolicyRequest,
-    ) -> Result<DeleteTrafficPolicyResponse, RusotoError<DeleteTrafficPolicyError>> {
-        let request_uri = format!(
-            "/2013-04-01/trafficpolicy/{id}/{version}",
-            id = input.id,
-            version = input.version
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
+        input: DeleteReusableDelegationSetRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/delegationset/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteReusableDelegationSet");
+
+        request
+    }
+
+    /// Calls `delete_traffic_policy`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_traffic_policy_with_metadata(
+        &self,
+        input: DeleteTrafficPolicyRequest,
+    ) -> Result<
+        (DeleteTrafficPolicyResponse, ResponseMetadata),
+        RusotoError<DeleteTrafficPolicyError>,
+    > {
+        let request = self.build_delete_traffic_policy_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteTrafficPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12806,33 +13804,67 @@ impl Route53 for Route53Client {
                 DeleteTrafficPolicyResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `delete_traffic_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_traffic_policy(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteTrafficPolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_traffic_policy_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Deletes a traffic policy instance and all of the resource record sets that Amazon Route 53 created when you created the instance.</p> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_traffic_policy_instance(
+    fn build_delete_traffic_policy_request(
         &self,
-        input: DeleteTrafficPolicyInstanceRequest,
-    ) -> Result<DeleteTrafficPolicyInstanceResponse, RusotoError<DeleteTrafficPolicyInstanceError>>
-    {
-        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
+        input: DeleteTrafficPolicyRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2013-04-01/trafficpolicy/{id}/{version}",
+            id = input.id,
+            version = input.version
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicy");
+
+        request
+    }
+
+    /// Calls `delete_traffic_policy_instance`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_traffic_policy_instance_with_metadata(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> Result<
+        (DeleteTrafficPolicyInstanceResponse, ResponseMetadata),
+        RusotoError<DeleteTrafficPolicyInstanceError>,
+    > {
+        let request = self.build_delete_traffic_policy_instance_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteTrafficPolicyInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12851,49 +13883,65 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `delete_traffic_policy_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_traffic_policy_instance(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_traffic_policy_instance_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Removes authorization to submit an <code>AssociateVPCWithHostedZone</code> request to associate a specified VPC with a hosted zone that was created by a different account. You must use the account that created the hosted zone to submit a <code>DeleteVPCAssociationAuthorization</code> request.</p> <important> <p>Sending this request only prevents the AWS account that created the VPC from associating the VPC with the Amazon Route 53 hosted zone in the future. If the VPC is already associated with the hosted zone, <code>DeleteVPCAssociationAuthorization</code> won&#39;t disassociate the VPC from the hosted zone. If you want to delete an existing association, use <code>DisassociateVPCFromHostedZone</code>.</p> </important></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_vpc_association_authorization(
+    fn build_delete_traffic_policy_instance_request(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicyInstance");
+
+        request
+    }
+
+    /// Calls `delete_vpc_association_authorization`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_vpc_association_authorization_with_metadata(
         &self,
         input: DeleteVPCAssociationAuthorizationRequest,
     ) -> Result<
-        DeleteVPCAssociationAuthorizationResponse,
+        (DeleteVPCAssociationAuthorizationResponse, ResponseMetadata),
         RusotoError<DeleteVPCAssociationAuthorizationError>,
     > {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/deauthorizevpcassociation",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        DeleteVPCAssociationAuthorizationRequestSerializer::serialize(
-            &mut writer,
-            "DeleteVPCAssociationAuthorizationRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
+        let request = self.build_delete_vpc_association_authorization_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteVPCAssociationAuthorizationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12912,48 +13960,76 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `delete_vpc_association_authorization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vpc_association_authorization(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vpc_association_authorization_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Disassociates a VPC from a Amazon Route 53 private hosted zone. Note the following:</p> <ul> <li> <p>You can&#39;t disassociate the last VPC from a private hosted zone.</p> </li> <li> <p>You can&#39;t convert a private hosted zone into a public hosted zone.</p> </li> <li> <p>You can submit a <code>DisassociateVPCFromHostedZone</code> request using either the account that created the hosted zone or the account that created the VPC.</p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn disassociate_vpc_from_hosted_zone(
+    fn build_delete_vpc_association_authorization_request(
         &self,
-        input: DisassociateVPCFromHostedZoneRequest,
-    ) -> Result<
-        DisassociateVPCFromHostedZoneResponse,
-        RusotoError<DisassociateVPCFromHostedZoneError>,
-    > {
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/disassociatevpc",
+            "/2013-04-01/hostedzone/{id}/deauthorizevpcassociation",
             id = input.hosted_zone_id
         )
         .replace("/hostedzone/hostedzone/", "/hostedzone/")
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteVPCAssociationAuthorization");
 
         let mut writer = EventWriter::new(Vec::new());
-        DisassociateVPCFromHostedZoneRequestSerializer::serialize(
+        DeleteVPCAssociationAuthorizationRequestSerializer::serialize(
             &mut writer,
-            "DisassociateVPCFromHostedZoneRequest",
+            "DeleteVPCAssociationAuthorizationRequest",
             &input,
             "https://route53.amazonaws.com/doc/2013-04-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `disassociate_vpc_from_hosted_zone`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn disassociate_vpc_from_hosted_zone_with_metadata(
+        &self,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> Result<
+        (DisassociateVPCFromHostedZoneResponse, ResponseMetadata),
+        RusotoError<DisassociateVPCFromHostedZoneError>,
+    > {
+        let request = self.build_disassociate_vpc_from_hosted_zone_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DisassociateVPCFromHostedZoneError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-        let mut result;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
+        let mut result;
 
         if xml_response.body.is_empty() {
             result = DisassociateVPCFromHostedZoneResponse::default();
@@ -12971,32 +14047,73 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `disassociate_vpc_from_hosted_zone` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_vpc_from_hosted_zone(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_vpc_from_hosted_zone_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Gets the specified limit for the current account, for example, the maximum number of health checks that you can create using the account.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p> <note> <p>You can also view account limits in AWS Trusted Advisor. Sign in to the AWS Management Console and open the Trusted Advisor console at <a href="https://console.aws.amazon.com/trustedadvisor">https://console.aws.amazon.com/trustedadvisor/</a>. Then choose <b>Service limits</b> in the navigation pane.</p> </note></p>
     #[allow(unused_variables, warnings)]
-    async fn get_account_limit(
+    fn build_disassociate_vpc_from_hosted_zone_request(
         &self,
-        input: GetAccountLimitRequest,
-    ) -> Result<GetAccountLimitResponse, RusotoError<GetAccountLimitError>> {
-        let request_uri = format!("/2013-04-01/accountlimit/{type}", type = input.type_)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2013-04-01/hostedzone/{id}/disassociatevpc",
+            id = input.hosted_zone_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
 
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DisassociateVPCFromHostedZone");
+
+        let mut writer = EventWriter::new(Vec::new());
+        DisassociateVPCFromHostedZoneRequestSerializer::serialize(
+            &mut writer,
+            "DisassociateVPCFromHostedZoneRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        request
+    }
+
+    /// Calls `get_account_limit`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_account_limit_with_metadata(
+        &self,
+        input: GetAccountLimitRequest,
+    ) -> Result<(GetAccountLimitResponse, ResponseMetadata), RusotoError<GetAccountLimitError>>
+    {
+        let request = self.build_get_account_limit_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetAccountLimitError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13013,32 +14130,57 @@ impl Route53 for Route53Client {
                 GetAccountLimitResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns the current status of a change batch request. The status is one of the following values:</p> <ul> <li> <p> <code>PENDING</code> indicates that the changes in this request have not propagated to all Amazon Route 53 DNS servers. This is the initial status of all change batch requests.</p> </li> <li> <p> <code>INSYNC</code> indicates that the changes have propagated to all Route 53 DNS servers. </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_change(
+    /// Presigns the request `get_account_limit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_account_limit(
         &self,
-        input: GetChangeRequest,
-    ) -> Result<GetChangeResponse, RusotoError<GetChangeError>> {
-        let request_uri = format!("/2013-04-01/change/{id}", id = input.id)
+        presigner: &rusoto_core::Presigner,
+        input: GetAccountLimitRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_account_limit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_account_limit_request(&self, input: GetAccountLimitRequest) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/accountlimit/{type}", type = input.type_)
             .replace("/hostedzone/hostedzone/", "/hostedzone/")
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetAccountLimit");
+
+        request
+    }
+
+    /// Calls `get_change`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_change_with_metadata(
+        &self,
+        input: GetChangeRequest,
+    ) -> Result<(GetChangeResponse, ResponseMetadata), RusotoError<GetChangeError>> {
+        let request = self.build_get_change_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetChangeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13054,30 +14196,58 @@ impl Route53 for Route53Client {
             result = GetChangeResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><important> <p> <code>GetCheckerIpRanges</code> still works, but we recommend that you download ip-ranges.json, which includes IP address ranges for all AWS services. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/route-53-ip-addresses.html">IP Address Ranges of Amazon Route 53 Servers</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_checker_ip_ranges(
+    /// Presigns the request `get_change` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_change(
         &self,
-        input: GetCheckerIpRangesRequest,
-    ) -> Result<GetCheckerIpRangesResponse, RusotoError<GetCheckerIpRangesError>> {
-        let request_uri = "/2013-04-01/checkeripranges";
+        presigner: &rusoto_core::Presigner,
+        input: GetChangeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_change_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_change_request(&self, input: GetChangeRequest) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/change/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetChange");
+
+        request
+    }
+
+    /// Calls `get_checker_ip_ranges`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_checker_ip_ranges_with_metadata(
+        &self,
+        input: GetCheckerIpRangesRequest,
+    ) -> Result<(GetCheckerIpRangesResponse, ResponseMetadata), RusotoError<GetCheckerIpRangesError>>
+    {
+        let request = self.build_get_checker_ip_ranges_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetCheckerIpRangesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13094,42 +14264,58 @@ impl Route53 for Route53Client {
                 GetCheckerIpRangesResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_checker_ip_ranges` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_checker_ip_ranges(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetCheckerIpRangesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_checker_ip_ranges_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about whether a specified geographic location is supported for Amazon Route 53 geolocation resource record sets.</p> <p>Use the following syntax to determine whether a continent is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?continentcode=<i>two-letter abbreviation for a continent</i> </code> </p> <p>Use the following syntax to determine whether a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i> </code> </p> <p>Use the following syntax to determine whether a subdivision of a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i>&amp;subdivisioncode=<i>subdivision code</i> </code> </p>
     #[allow(unused_variables, warnings)]
-    async fn get_geo_location(
+    fn build_get_checker_ip_ranges_request(
         &self,
-        input: GetGeoLocationRequest,
-    ) -> Result<GetGeoLocationResponse, RusotoError<GetGeoLocationError>> {
-        let request_uri = "/2013-04-01/geolocation";
+        input: GetCheckerIpRangesRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2013-04-01/checkeripranges";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetCheckerIpRanges");
 
-        let mut params = Params::new();
-        if let Some(ref x) = input.continent_code {
-            params.put("continentcode", x);
-        }
-        if let Some(ref x) = input.country_code {
-            params.put("countrycode", x);
-        }
-        if let Some(ref x) = input.subdivision_code {
-            params.put("subdivisioncode", x);
-        }
-        request.set_params(params);
+        request
+    }
+
+    /// Calls `get_geo_location`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_geo_location_with_metadata(
+        &self,
+        input: GetGeoLocationRequest,
+    ) -> Result<(GetGeoLocationResponse, ResponseMetadata), RusotoError<GetGeoLocationError>> {
+        let request = self.build_get_geo_location_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetGeoLocationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13145,35 +14331,67 @@ impl Route53 for Route53Client {
             result = GetGeoLocationResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets information about a specified health check.</p>
-    #[allow(unused_variables, warnings)]
-    async fn get_health_check(
+    /// Presigns the request `get_geo_location` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_geo_location(
         &self,
-        input: GetHealthCheckRequest,
-    ) -> Result<GetHealthCheckResponse, RusotoError<GetHealthCheckError>> {
-        let request_uri = format!(
-            "/2013-04-01/healthcheck/{health_check_id}",
-            health_check_id = input.health_check_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
+        presigner: &rusoto_core::Presigner,
+        input: GetGeoLocationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_geo_location_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_geo_location_request(&self, input: GetGeoLocationRequest) -> SignedRequest {
+        let request_uri = "/2013-04-01/geolocation";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetGeoLocation");
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.continent_code {
+            params.put("continentcode", x);
+        }
+        if let Some(ref x) = input.country_code {
+            params.put("countrycode", x);
+        }
+        if let Some(ref x) = input.subdivision_code {
+            params.put("subdivisioncode", x);
+        }
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `get_health_check`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_health_check_with_metadata(
+        &self,
+        input: GetHealthCheckRequest,
+    ) -> Result<(GetHealthCheckResponse, ResponseMetadata), RusotoError<GetHealthCheckError>> {
+        let request = self.build_get_health_check_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetHealthCheckError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13189,30 +14407,63 @@ impl Route53 for Route53Client {
             result = GetHealthCheckResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieves the number of health checks that are associated with the current AWS account.</p>
-    #[allow(unused_variables, warnings)]
-    async fn get_health_check_count(
+    /// Presigns the request `get_health_check` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_health_check(
         &self,
-        input: GetHealthCheckCountRequest,
-    ) -> Result<GetHealthCheckCountResponse, RusotoError<GetHealthCheckCountError>> {
-        let request_uri = "/2013-04-01/healthcheckcount";
+        presigner: &rusoto_core::Presigner,
+        input: GetHealthCheckRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_health_check_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_health_check_request(&self, input: GetHealthCheckRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/2013-04-01/healthcheck/{health_check_id}",
+            health_check_id = input.health_check_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheck");
+
+        request
+    }
+
+    /// Calls `get_health_check_count`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_health_check_count_with_metadata(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> Result<
+        (GetHealthCheckCountResponse, ResponseMetadata),
+        RusotoError<GetHealthCheckCountError>,
+    > {
+        let request = self.build_get_health_check_count_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetHealthCheckCountError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13229,40 +14480,63 @@ impl Route53 for Route53Client {
                 GetHealthCheckCountResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_health_check_count` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_health_check_count(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetHealthCheckCountRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_health_check_count_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets the reason that a specified health check failed most recently.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_health_check_last_failure_reason(
+    fn build_get_health_check_count_request(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2013-04-01/healthcheckcount";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckCount");
+
+        request
+    }
+
+    /// Calls `get_health_check_last_failure_reason`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_health_check_last_failure_reason_with_metadata(
         &self,
         input: GetHealthCheckLastFailureReasonRequest,
     ) -> Result<
-        GetHealthCheckLastFailureReasonResponse,
+        (GetHealthCheckLastFailureReasonResponse, ResponseMetadata),
         RusotoError<GetHealthCheckLastFailureReasonError>,
     > {
-        let request_uri = format!(
-            "/2013-04-01/healthcheck/{health_check_id}/lastfailurereason",
-            health_check_id = input.health_check_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        let request = self.build_get_health_check_last_failure_reason_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetHealthCheckLastFailureReasonError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13281,35 +14555,66 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_health_check_last_failure_reason` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_health_check_last_failure_reason(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_health_check_last_failure_reason_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets status of a specified health check. </p>
     #[allow(unused_variables, warnings)]
-    async fn get_health_check_status(
+    fn build_get_health_check_last_failure_reason_request(
         &self,
-        input: GetHealthCheckStatusRequest,
-    ) -> Result<GetHealthCheckStatusResponse, RusotoError<GetHealthCheckStatusError>> {
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2013-04-01/healthcheck/{health_check_id}/status",
+            "/2013-04-01/healthcheck/{health_check_id}/lastfailurereason",
             health_check_id = input.health_check_id
         )
         .replace("/hostedzone/hostedzone/", "/hostedzone/")
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckLastFailureReason");
+
+        request
+    }
+
+    /// Calls `get_health_check_status`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_health_check_status_with_metadata(
+        &self,
+        input: GetHealthCheckStatusRequest,
+    ) -> Result<
+        (GetHealthCheckStatusResponse, ResponseMetadata),
+        RusotoError<GetHealthCheckStatusError>,
+    > {
+        let request = self.build_get_health_check_status_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetHealthCheckStatusError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13328,32 +14633,63 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_health_check_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_health_check_status(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetHealthCheckStatusRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_health_check_status_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about a specified hosted zone including the four name servers assigned to the hosted zone.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_hosted_zone(
+    fn build_get_health_check_status_request(
         &self,
-        input: GetHostedZoneRequest,
-    ) -> Result<GetHostedZoneResponse, RusotoError<GetHostedZoneError>> {
-        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
+        input: GetHealthCheckStatusRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2013-04-01/healthcheck/{health_check_id}/status",
+            health_check_id = input.health_check_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckStatus");
+
+        request
+    }
+
+    /// Calls `get_hosted_zone`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_hosted_zone_with_metadata(
+        &self,
+        input: GetHostedZoneRequest,
+    ) -> Result<(GetHostedZoneResponse, ResponseMetadata), RusotoError<GetHostedZoneError>> {
+        let request = self.build_get_hosted_zone_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetHostedZoneError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13369,30 +14705,58 @@ impl Route53 for Route53Client {
             result = GetHostedZoneResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieves the number of hosted zones that are associated with the current AWS account.</p>
-    #[allow(unused_variables, warnings)]
-    async fn get_hosted_zone_count(
+    /// Presigns the request `get_hosted_zone` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_hosted_zone(
         &self,
-        input: GetHostedZoneCountRequest,
-    ) -> Result<GetHostedZoneCountResponse, RusotoError<GetHostedZoneCountError>> {
-        let request_uri = "/2013-04-01/hostedzonecount";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        presigner: &rusoto_core::Presigner,
+        input: GetHostedZoneRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_hosted_zone_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_hosted_zone_request(&self, input: GetHostedZoneRequest) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZone");
+
+        request
+    }
+
+    /// Calls `get_hosted_zone_count`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_hosted_zone_count_with_metadata(
+        &self,
+        input: GetHostedZoneCountRequest,
+    ) -> Result<(GetHostedZoneCountResponse, ResponseMetadata), RusotoError<GetHostedZoneCountError>>
+    {
+        let request = self.build_get_hosted_zone_count_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetHostedZoneCountError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13409,30 +14773,59 @@ impl Route53 for Route53Client {
                 GetHostedZoneCountResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_hosted_zone_count` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_hosted_zone_count(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetHostedZoneCountRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_hosted_zone_count_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets the specified limit for a specified hosted zone, for example, the maximum number of records that you can create in the hosted zone. </p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_hosted_zone_limit(
+    fn build_get_hosted_zone_count_request(
         &self,
-        input: GetHostedZoneLimitRequest,
-    ) -> Result<GetHostedZoneLimitResponse, RusotoError<GetHostedZoneLimitError>> {
-        let request_uri = format!("/2013-04-01/hostedzonelimit/{id}/{type}", id = input.hosted_zone_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
+        input: GetHostedZoneCountRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2013-04-01/hostedzonecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneCount");
+
+        request
+    }
+
+    /// Calls `get_hosted_zone_limit`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_hosted_zone_limit_with_metadata(
+        &self,
+        input: GetHostedZoneLimitRequest,
+    ) -> Result<(GetHostedZoneLimitResponse, ResponseMetadata), RusotoError<GetHostedZoneLimitError>>
+    {
+        let request = self.build_get_hosted_zone_limit_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetHostedZoneLimitError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13449,32 +14842,61 @@ impl Route53 for Route53Client {
                 GetHostedZoneLimitResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_hosted_zone_limit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_hosted_zone_limit(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetHostedZoneLimitRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_hosted_zone_limit_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about a specified configuration for DNS query logging.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a> and <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a>.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_query_logging_config(
+    fn build_get_hosted_zone_limit_request(
         &self,
-        input: GetQueryLoggingConfigRequest,
-    ) -> Result<GetQueryLoggingConfigResponse, RusotoError<GetQueryLoggingConfigError>> {
-        let request_uri = format!("/2013-04-01/queryloggingconfig/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
+        input: GetHostedZoneLimitRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/hostedzonelimit/{id}/{type}", id = input.hosted_zone_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneLimit");
+
+        request
+    }
+
+    /// Calls `get_query_logging_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_query_logging_config_with_metadata(
+        &self,
+        input: GetQueryLoggingConfigRequest,
+    ) -> Result<
+        (GetQueryLoggingConfigResponse, ResponseMetadata),
+        RusotoError<GetQueryLoggingConfigError>,
+    > {
+        let request = self.build_get_query_logging_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetQueryLoggingConfigError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13493,32 +14915,63 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_query_logging_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_query_logging_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetQueryLoggingConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_query_logging_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Retrieves information about a specified reusable delegation set, including the four name servers that are assigned to the delegation set.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_reusable_delegation_set(
+    fn build_get_query_logging_config_request(
         &self,
-        input: GetReusableDelegationSetRequest,
-    ) -> Result<GetReusableDelegationSetResponse, RusotoError<GetReusableDelegationSetError>> {
-        let request_uri = format!("/2013-04-01/delegationset/{id}", id = input.id)
+        input: GetQueryLoggingConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/queryloggingconfig/{id}", id = input.id)
             .replace("/hostedzone/hostedzone/", "/hostedzone/")
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetQueryLoggingConfig");
+
+        request
+    }
+
+    /// Calls `get_reusable_delegation_set`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_reusable_delegation_set_with_metadata(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> Result<
+        (GetReusableDelegationSetResponse, ResponseMetadata),
+        RusotoError<GetReusableDelegationSetError>,
+    > {
+        let request = self.build_get_reusable_delegation_set_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetReusableDelegationSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13537,33 +14990,63 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_reusable_delegation_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_reusable_delegation_set(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetReusableDelegationSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_reusable_delegation_set_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_reusable_delegation_set_limit(
+    fn build_get_reusable_delegation_set_request(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/delegationset/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSet");
+
+        request
+    }
+
+    /// Calls `get_reusable_delegation_set_limit`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_reusable_delegation_set_limit_with_metadata(
         &self,
         input: GetReusableDelegationSetLimitRequest,
     ) -> Result<
-        GetReusableDelegationSetLimitResponse,
+        (GetReusableDelegationSetLimitResponse, ResponseMetadata),
         RusotoError<GetReusableDelegationSetLimitError>,
     > {
-        let request_uri = format!("/2013-04-01/reusabledelegationsetlimit/{id}/{type}", id = input.delegation_set_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        let request = self.build_get_reusable_delegation_set_limit_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetReusableDelegationSetLimitError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13582,36 +15065,59 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_reusable_delegation_set_limit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_reusable_delegation_set_limit(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_reusable_delegation_set_limit_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about a specific traffic policy version.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_traffic_policy(
+    fn build_get_reusable_delegation_set_limit_request(
         &self,
-        input: GetTrafficPolicyRequest,
-    ) -> Result<GetTrafficPolicyResponse, RusotoError<GetTrafficPolicyError>> {
-        let request_uri = format!(
-            "/2013-04-01/trafficpolicy/{id}/{version}",
-            id = input.id,
-            version = input.version
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/reusabledelegationsetlimit/{id}/{type}", id = input.delegation_set_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSetLimit");
+
+        request
+    }
+
+    /// Calls `get_traffic_policy`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_traffic_policy_with_metadata(
+        &self,
+        input: GetTrafficPolicyRequest,
+    ) -> Result<(GetTrafficPolicyResponse, ResponseMetadata), RusotoError<GetTrafficPolicyError>>
+    {
+        let request = self.build_get_traffic_policy_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetTrafficPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13628,32 +15134,64 @@ impl Route53 for Route53Client {
                 GetTrafficPolicyResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Gets information about a specified traffic policy instance.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there&#39;s a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_traffic_policy_instance(
+    /// Presigns the request `get_traffic_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_traffic_policy(
         &self,
-        input: GetTrafficPolicyInstanceRequest,
-    ) -> Result<GetTrafficPolicyInstanceResponse, RusotoError<GetTrafficPolicyInstanceError>> {
-        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
+        presigner: &rusoto_core::Presigner,
+        input: GetTrafficPolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_traffic_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_traffic_policy_request(&self, input: GetTrafficPolicyRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/2013-04-01/trafficpolicy/{id}/{version}",
+            id = input.id,
+            version = input.version
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicy");
+
+        request
+    }
+
+    /// Calls `get_traffic_policy_instance`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_traffic_policy_instance_with_metadata(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> Result<
+        (GetTrafficPolicyInstanceResponse, ResponseMetadata),
+        RusotoError<GetTrafficPolicyInstanceError>,
+    > {
+        let request = self.build_get_traffic_policy_instance_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetTrafficPolicyInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13672,33 +15210,63 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_traffic_policy_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_traffic_policy_instance(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_traffic_policy_instance_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets the number of traffic policy instances that are associated with the current AWS account.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_traffic_policy_instance_count(
+    fn build_get_traffic_policy_instance_request(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstance");
+
+        request
+    }
+
+    /// Calls `get_traffic_policy_instance_count`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_traffic_policy_instance_count_with_metadata(
         &self,
         input: GetTrafficPolicyInstanceCountRequest,
     ) -> Result<
-        GetTrafficPolicyInstanceCountResponse,
+        (GetTrafficPolicyInstanceCountResponse, ResponseMetadata),
         RusotoError<GetTrafficPolicyInstanceCountError>,
     > {
-        let request_uri = "/2013-04-01/trafficpolicyinstancecount";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        let request = self.build_get_traffic_policy_instance_count_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetTrafficPolicyInstanceCountError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13717,45 +15285,59 @@ impl Route53 for Route53Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_traffic_policy_instance_count` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_traffic_policy_instance_count(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_traffic_policy_instance_count_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Retrieves a list of supported geographic locations.</p> <p>Countries are listed first, and continents are listed last. If Amazon Route 53 supports subdivisions for a country (for example, states or provinces), the subdivisions for that country are listed in alphabetical order immediately after the corresponding country.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_geo_locations(
+    fn build_get_traffic_policy_instance_count_request(
         &self,
-        input: ListGeoLocationsRequest,
-    ) -> Result<ListGeoLocationsResponse, RusotoError<ListGeoLocationsError>> {
-        let request_uri = "/2013-04-01/geolocations";
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2013-04-01/trafficpolicyinstancecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstanceCount");
 
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        if let Some(ref x) = input.start_continent_code {
-            params.put("startcontinentcode", x);
-        }
-        if let Some(ref x) = input.start_country_code {
-            params.put("startcountrycode", x);
-        }
-        if let Some(ref x) = input.start_subdivision_code {
-            params.put("startsubdivisioncode", x);
-        }
-        request.set_params(params);
+        request
+    }
+
+    /// Calls `list_geo_locations`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_geo_locations_with_metadata(
+        &self,
+        input: ListGeoLocationsRequest,
+    ) -> Result<(ListGeoLocationsResponse, ResponseMetadata), RusotoError<ListGeoLocationsError>>
+    {
+        let request = self.build_list_geo_locations_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListGeoLocationsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13772,39 +15354,71 @@ impl Route53 for Route53Client {
                 ListGeoLocationsResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieve a list of the health checks that are associated with the current AWS account. </p>
-    #[allow(unused_variables, warnings)]
-    async fn list_health_checks(
+    /// Presigns the request `list_geo_locations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_geo_locations(
         &self,
-        input: ListHealthChecksRequest,
-    ) -> Result<ListHealthChecksResponse, RusotoError<ListHealthChecksError>> {
-        let request_uri = "/2013-04-01/healthcheck";
+        presigner: &rusoto_core::Presigner,
+        input: ListGeoLocationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_geo_locations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_list_geo_locations_request(&self, input: ListGeoLocationsRequest) -> SignedRequest {
+        let request_uri = "/2013-04-01/geolocations";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListGeoLocations");
 
         let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
         if let Some(ref x) = input.max_items {
             params.put("maxitems", x);
         }
+        if let Some(ref x) = input.start_continent_code {
+            params.put("startcontinentcode", x);
+        }
+        if let Some(ref x) = input.start_country_code {
+            params.put("startcountrycode", x);
+        }
+        if let Some(ref x) = input.start_subdivision_code {
+            params.put("startsubdivisioncode", x);
+        }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_health_checks`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_health_checks_with_metadata(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> Result<(ListHealthChecksResponse, ResponseMetadata), RusotoError<ListHealthChecksError>>
+    {
+        let request = self.build_list_health_checks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListHealthChecksError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13821,23 +15435,27 @@ impl Route53 for Route53Client {
                 ListHealthChecksResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieves a list of the public and private hosted zones that are associated with the current AWS account. The response includes a <code>HostedZones</code> child element for each hosted zone.</p> <p>Amazon Route 53 returns a maximum of 100 items in each response. If you have a lot of hosted zones, you can use the <code>maxitems</code> parameter to list them in groups of up to 100.</p>
-    #[allow(unused_variables, warnings)]
-    async fn list_hosted_zones(
+    /// Presigns the request `list_health_checks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_health_checks(
         &self,
-        input: ListHostedZonesRequest,
-    ) -> Result<ListHostedZonesResponse, RusotoError<ListHostedZonesError>> {
-        let request_uri = "/2013-04-01/hostedzone";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        presigner: &rusoto_core::Presigner,
+        input: ListHealthChecksRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_health_checks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_list_health_checks_request(&self, input: ListHealthChecksRequest) -> SignedRequest {
+        let request_uri = "/2013-04-01/healthcheck";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHealthChecks");
 
         let mut params = Params::new();
-        if let Some(ref x) = input.delegation_set_id {
-            params.put("delegationsetid", x);
-        }
         if let Some(ref x) = input.marker {
             params.put("marker", x);
         }
@@ -13846,17 +15464,36 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_hosted_zones`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_hosted_zones_with_metadata(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> Result<(ListHostedZonesResponse, ResponseMetadata), RusotoError<ListHostedZonesError>>
+    {
+        let request = self.build_list_hosted_zones_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListHostedZonesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13873,18 +15510,110 @@ impl Route53 for Route53Client {
                 ListHostedZonesResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_hosted_zones` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_hosted_zones(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListHostedZonesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_hosted_zones_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Retrieves a list of your hosted zones in lexicographic order. The response includes a <code>HostedZones</code> child element for each hosted zone created by the current AWS account. </p> <p> <code>ListHostedZonesByName</code> sorts hosted zones by name with the labels reversed. For example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order in some circumstances.</p> <p>If the domain name includes escape characters or Punycode, <code>ListHostedZonesByName</code> alphabetizes the domain name using the escaped or Punycoded value, which is the format that Amazon Route 53 saves in its database. For example, to create a hosted zone for exämple.com, you specify ex\344mple.com for the domain name. <code>ListHostedZonesByName</code> alphabetizes it as:</p> <p> <code>com.ex\344mple.</code> </p> <p>The labels are reversed and alphabetized using the escaped value. For more information about valid domain name formats, including internationalized domain names, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DomainNameFormat.html">DNS Domain Name Format</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>Route 53 returns up to 100 items in each response. If you have a lot of hosted zones, use the <code>MaxItems</code> parameter to list them in groups of up to 100. The response includes values that help navigate from one group of <code>MaxItems</code> hosted zones to the next:</p> <ul> <li> <p>The <code>DNSName</code> and <code>HostedZoneId</code> elements in the response contain the values, if any, specified for the <code>dnsname</code> and <code>hostedzoneid</code> parameters in the request that produced the current response.</p> </li> <li> <p>The <code>MaxItems</code> element in the response contains the value, if any, that you specified for the <code>maxitems</code> parameter in the request that produced the current response.</p> </li> <li> <p>If the value of <code>IsTruncated</code> in the response is true, there are more hosted zones associated with the current AWS account. </p> <p>If <code>IsTruncated</code> is false, this response includes the last hosted zone that is associated with the current account. The <code>NextDNSName</code> element and <code>NextHostedZoneId</code> elements are omitted from the response.</p> </li> <li> <p>The <code>NextDNSName</code> and <code>NextHostedZoneId</code> elements in the response contain the domain name and the hosted zone ID of the next hosted zone that is associated with the current AWS account. If you want to list more hosted zones, make another call to <code>ListHostedZonesByName</code>, and specify the value of <code>NextDNSName</code> and <code>NextHostedZoneId</code> in the <code>dnsname</code> and <code>hostedzoneid</code> parameters, respectively.</p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn list_hosted_zones_by_name(
+    fn build_list_hosted_zones_request(&self, input: ListHostedZonesRequest) -> SignedRequest {
+        let request_uri = "/2013-04-01/hostedzone";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZones");
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.delegation_set_id {
+            params.put("delegationsetid", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `list_hosted_zones_by_name`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_hosted_zones_by_name_with_metadata(
         &self,
         input: ListHostedZonesByNameRequest,
-    ) -> Result<ListHostedZonesByNameResponse, RusotoError<ListHostedZonesByNameError>> {
+    ) -> Result<
+        (ListHostedZonesByNameResponse, ResponseMetadata),
+        RusotoError<ListHostedZonesByNameError>,
+    > {
+        let request = self.build_list_hosted_zones_by_name_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if !response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListHostedZonesByNameError::from_response(response));
+        }
+
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
+        let mut result;
+
+        if xml_response.body.is_empty() {
+            result = ListHostedZonesByNameResponse::default();
+        } else {
+            let reader = EventReader::new_with_config(
+                xml_response.body.as_ref(),
+                ParserConfig::new().trim_whitespace(false),
+            );
+            let mut stack = XmlResponse::new(reader.into_iter().peekable());
+            let _start_document = stack.next();
+            let actual_tag_name = peek_at_name(&mut stack)?;
+            result = ListHostedZonesByNameResponseDeserializer::deserialize(
+                &actual_tag_name,
+                &mut stack,
+            )?;
+        }
+        // parse non-payload
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_hosted_zones_by_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_hosted_zones_by_name(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListHostedZonesByNameRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_hosted_zones_by_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_list_hosted_zones_by_name_request(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> SignedRequest {
         let request_uri = "/2013-04-01/hostedzonesbyname";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZonesByName");
 
         let mut params = Params::new();
         if let Some(ref x) = input.dns_name {
@@ -13898,21 +15627,42 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_query_logging_configs`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_query_logging_configs_with_metadata(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> Result<
+        (ListQueryLoggingConfigsResponse, ResponseMetadata),
+        RusotoError<ListQueryLoggingConfigsError>,
+    > {
+        let request = self.build_list_query_logging_configs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListHostedZonesByNameError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListQueryLoggingConfigsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListHostedZonesByNameResponse::default();
+            result = ListQueryLoggingConfigsResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -13921,24 +15671,34 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListHostedZonesByNameResponseDeserializer::deserialize(
+            result = ListQueryLoggingConfigsResponseDeserializer::deserialize(
                 &actual_tag_name,
                 &mut stack,
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_query_logging_configs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_query_logging_configs(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_query_logging_configs_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Lists the configurations for DNS query logging that are associated with the current AWS account or the configuration that is associated with a specified hosted zone.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>. Additional information, including the format of DNS query logs, appears in <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_query_logging_configs(
+    fn build_list_query_logging_configs_request(
         &self,
         input: ListQueryLoggingConfigsRequest,
-    ) -> Result<ListQueryLoggingConfigsResponse, RusotoError<ListQueryLoggingConfigsError>> {
+    ) -> SignedRequest {
         let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListQueryLoggingConfigs");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id {
@@ -13952,21 +15712,42 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_resource_record_sets`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_resource_record_sets_with_metadata(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> Result<
+        (ListResourceRecordSetsResponse, ResponseMetadata),
+        RusotoError<ListResourceRecordSetsError>,
+    > {
+        let request = self.build_list_resource_record_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListQueryLoggingConfigsError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListResourceRecordSetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListQueryLoggingConfigsResponse::default();
+            result = ListResourceRecordSetsResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -13975,21 +15756,30 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListQueryLoggingConfigsResponseDeserializer::deserialize(
+            result = ListResourceRecordSetsResponseDeserializer::deserialize(
                 &actual_tag_name,
                 &mut stack,
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_resource_record_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resource_record_sets(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListResourceRecordSetsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resource_record_sets_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Lists the resource record sets in a specified hosted zone.</p> <p> <code>ListResourceRecordSets</code> returns up to 100 resource record sets at a time in ASCII order, beginning at a position specified by the <code>name</code> and <code>type</code> elements.</p> <p> <b>Sort order</b> </p> <p> <code>ListResourceRecordSets</code> sorts results first by DNS name with the labels reversed, for example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order when the record name contains characters that appear before <code>.</code> (decimal 46) in the ASCII table. These characters include the following: <code>! " # $ % &amp; ' ( ) * + , -</code> </p> <p>When multiple records have the same DNS name, <code>ListResourceRecordSets</code> sorts results by the record type.</p> <p> <b>Specifying where to start listing records</b> </p> <p>You can use the name and type elements to specify the resource record set that the list begins with:</p> <dl> <dt>If you do not specify Name or Type</dt> <dd> <p>The results begin with the first resource record set that the hosted zone contains.</p> </dd> <dt>If you specify Name but not Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>.</p> </dd> <dt>If you specify Type but not Name</dt> <dd> <p>Amazon Route 53 returns the <code>InvalidInput</code> error.</p> </dd> <dt>If you specify both Name and Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>, and whose type is greater than or equal to <code>Type</code>.</p> </dd> </dl> <p> <b>Resource record sets that are PENDING</b> </p> <p>This action returns the most current version of the records. This includes records that are <code>PENDING</code>, and that are not yet available on all Route 53 DNS servers.</p> <p> <b>Changing resource record sets</b> </p> <p>To ensure that you get an accurate listing of the resource record sets for a hosted zone at a point in time, do not submit a <code>ChangeResourceRecordSets</code> request while you're paging through the results of a <code>ListResourceRecordSets</code> request. If you do, some pages may display results without the latest changes while other pages display results with the latest changes.</p> <p> <b>Displaying the next page of results</b> </p> <p>If a <code>ListResourceRecordSets</code> command returns more than one page of results, the value of <code>IsTruncated</code> is <code>true</code>. To display the next page of results, get the values of <code>NextRecordName</code>, <code>NextRecordType</code>, and <code>NextRecordIdentifier</code> (if any) from the response. Then submit another <code>ListResourceRecordSets</code> request, and specify those values for <code>StartRecordName</code>, <code>StartRecordType</code>, and <code>StartRecordIdentifier</code>.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_resource_record_sets(
+    fn build_list_resource_record_sets_request(
         &self,
         input: ListResourceRecordSetsRequest,
-    ) -> Result<ListResourceRecordSetsResponse, RusotoError<ListResourceRecordSetsError>> {
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2013-04-01/hostedzone/{id}/rrset",
             id = input.hosted_zone_id
@@ -13998,6 +15788,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListResourceRecordSets");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14014,21 +15805,42 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_reusable_delegation_sets`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_reusable_delegation_sets_with_metadata(
+        &self,
+        input: ListReusableDelegationSetsRequest,
+    ) -> Result<
+        (ListReusableDelegationSetsResponse, ResponseMetadata),
+        RusotoError<ListReusableDelegationSetsError>,
+    > {
+        let request = self.build_list_reusable_delegation_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListResourceRecordSetsError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListReusableDelegationSetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListResourceRecordSetsResponse::default();
+            result = ListReusableDelegationSetsResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14037,25 +15849,34 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListResourceRecordSetsResponseDeserializer::deserialize(
+            result = ListReusableDelegationSetsResponseDeserializer::deserialize(
                 &actual_tag_name,
                 &mut stack,
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_reusable_delegation_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_reusable_delegation_sets(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListReusableDelegationSetsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_reusable_delegation_sets_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Retrieves a list of the reusable delegation sets that are associated with the current AWS account.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_reusable_delegation_sets(
+    fn build_list_reusable_delegation_sets_request(
         &self,
         input: ListReusableDelegationSetsRequest,
-    ) -> Result<ListReusableDelegationSetsResponse, RusotoError<ListReusableDelegationSetsError>>
-    {
+    ) -> SignedRequest {
         let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListReusableDelegationSets");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14066,21 +15887,42 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListReusableDelegationSetsError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListTagsForResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListReusableDelegationSetsResponse::default();
+            result = ListTagsForResourceResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14089,21 +15931,28 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListReusableDelegationSetsResponseDeserializer::deserialize(
-                &actual_tag_name,
-                &mut stack,
-            )?;
+            result =
+                ListTagsForResourceResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Lists tags for one health check or hosted zone. </p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_tags_for_resource(
+    fn build_list_tags_for_resource_request(
         &self,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2013-04-01/tags/{resource_type}/{resource_id}",
             resource_id = input.resource_id,
@@ -14113,22 +15962,44 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+
+        request
+    }
+
+    /// Calls `list_tags_for_resources`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_tags_for_resources_with_metadata(
+        &self,
+        input: ListTagsForResourcesRequest,
+    ) -> Result<
+        (ListTagsForResourcesResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourcesError>,
+    > {
+        let request = self.build_list_tags_for_resources_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListTagsForResourceError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListTagsForResourcesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListTagsForResourceResponse::default();
+            result = ListTagsForResourcesResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14137,19 +16008,30 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                ListTagsForResourceResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result = ListTagsForResourcesResponseDeserializer::deserialize(
+                &actual_tag_name,
+                &mut stack,
+            )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_tags_for_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resources(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourcesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resources_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Lists tags for up to 10 health checks or hosted zones.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_tags_for_resources(
+    fn build_list_tags_for_resources_request(
         &self,
         input: ListTagsForResourcesRequest,
-    ) -> Result<ListTagsForResourcesResponse, RusotoError<ListTagsForResourcesError>> {
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2013-04-01/tags/{resource_type}",
             resource_type = input.resource_type
@@ -14158,6 +16040,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResources");
 
         let mut writer = EventWriter::new(Vec::new());
         ListTagsForResourcesRequestSerializer::serialize(
@@ -14168,21 +16051,42 @@ impl Route53 for Route53Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `list_traffic_policies`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_traffic_policies_with_metadata(
+        &self,
+        input: ListTrafficPoliciesRequest,
+    ) -> Result<
+        (ListTrafficPoliciesResponse, ResponseMetadata),
+        RusotoError<ListTrafficPoliciesError>,
+    > {
+        let request = self.build_list_traffic_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListTagsForResourcesError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListTrafficPoliciesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListTagsForResourcesResponse::default();
+            result = ListTrafficPoliciesResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14191,24 +16095,32 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListTagsForResourcesResponseDeserializer::deserialize(
-                &actual_tag_name,
-                &mut stack,
-            )?;
+            result =
+                ListTrafficPoliciesResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_traffic_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_traffic_policies(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListTrafficPoliciesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_traffic_policies_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about the latest version for every traffic policy that is associated with the current AWS account. Policies are listed in the order that they were created in. </p>
     #[allow(unused_variables, warnings)]
-    async fn list_traffic_policies(
+    fn build_list_traffic_policies_request(
         &self,
         input: ListTrafficPoliciesRequest,
-    ) -> Result<ListTrafficPoliciesResponse, RusotoError<ListTrafficPoliciesError>> {
+    ) -> SignedRequest {
         let request_uri = "/2013-04-01/trafficpolicies";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicies");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14219,21 +16131,42 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_traffic_policy_instances`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_traffic_policy_instances_with_metadata(
+        &self,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> Result<
+        (ListTrafficPolicyInstancesResponse, ResponseMetadata),
+        RusotoError<ListTrafficPolicyInstancesError>,
+    > {
+        let request = self.build_list_traffic_policy_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListTrafficPoliciesError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListTrafficPolicyInstancesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListTrafficPoliciesResponse::default();
+            result = ListTrafficPolicyInstancesResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14242,23 +16175,34 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                ListTrafficPoliciesResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result = ListTrafficPolicyInstancesResponseDeserializer::deserialize(
+                &actual_tag_name,
+                &mut stack,
+            )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_traffic_policy_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_traffic_policy_instances(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_traffic_policy_instances_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about the traffic policy instances that you created by using the current AWS account.</p> <note> <p>After you submit an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_traffic_policy_instances(
+    fn build_list_traffic_policy_instances_request(
         &self,
         input: ListTrafficPolicyInstancesRequest,
-    ) -> Result<ListTrafficPolicyInstancesResponse, RusotoError<ListTrafficPolicyInstancesError>>
-    {
+    ) -> SignedRequest {
         let request_uri = "/2013-04-01/trafficpolicyinstances";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstances");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -14275,21 +16219,47 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_traffic_policy_instances_by_hosted_zone`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_traffic_policy_instances_by_hosted_zone_with_metadata(
+        &self,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> Result<
+        (
+            ListTrafficPolicyInstancesByHostedZoneResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListTrafficPolicyInstancesByHostedZoneError>,
+    > {
+        let request = self.build_list_traffic_policy_instances_by_hosted_zone_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListTrafficPolicyInstancesError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListTrafficPolicyInstancesByHostedZoneError::from_response(
+                response,
+            ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListTrafficPolicyInstancesResponse::default();
+            result = ListTrafficPolicyInstancesByHostedZoneResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14298,27 +16268,34 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListTrafficPolicyInstancesResponseDeserializer::deserialize(
+            result = ListTrafficPolicyInstancesByHostedZoneResponseDeserializer::deserialize(
                 &actual_tag_name,
                 &mut stack,
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_traffic_policy_instances_by_hosted_zone` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_traffic_policy_instances_by_hosted_zone(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_traffic_policy_instances_by_hosted_zone_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about the traffic policy instances that you created in a specified hosted zone.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_traffic_policy_instances_by_hosted_zone(
+    fn build_list_traffic_policy_instances_by_hosted_zone_request(
         &self,
         input: ListTrafficPolicyInstancesByHostedZoneRequest,
-    ) -> Result<
-        ListTrafficPolicyInstancesByHostedZoneResponse,
-        RusotoError<ListTrafficPolicyInstancesByHostedZoneError>,
-    > {
+    ) -> SignedRequest {
         let request_uri = "/2013-04-01/trafficpolicyinstances/hostedzone";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByHostedZone");
 
         let mut params = Params::new();
         params.put("id", &input.hosted_zone_id);
@@ -14333,23 +16310,44 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_traffic_policy_instances_by_policy`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_traffic_policy_instances_by_policy_with_metadata(
+        &self,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> Result<
+        (ListTrafficPolicyInstancesByPolicyResponse, ResponseMetadata),
+        RusotoError<ListTrafficPolicyInstancesByPolicyError>,
+    > {
+        let request = self.build_list_traffic_policy_instances_by_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListTrafficPolicyInstancesByHostedZoneError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListTrafficPolicyInstancesByPolicyError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListTrafficPolicyInstancesByHostedZoneResponse::default();
+            result = ListTrafficPolicyInstancesByPolicyResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14358,27 +16356,34 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListTrafficPolicyInstancesByHostedZoneResponseDeserializer::deserialize(
+            result = ListTrafficPolicyInstancesByPolicyResponseDeserializer::deserialize(
                 &actual_tag_name,
                 &mut stack,
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_traffic_policy_instances_by_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_traffic_policy_instances_by_policy(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_traffic_policy_instances_by_policy_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about the traffic policy instances that you created by using a specify traffic policy version.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_traffic_policy_instances_by_policy(
+    fn build_list_traffic_policy_instances_by_policy_request(
         &self,
         input: ListTrafficPolicyInstancesByPolicyRequest,
-    ) -> Result<
-        ListTrafficPolicyInstancesByPolicyResponse,
-        RusotoError<ListTrafficPolicyInstancesByPolicyError>,
-    > {
+    ) -> SignedRequest {
         let request_uri = "/2013-04-01/trafficpolicyinstances/trafficpolicy";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByPolicy");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -14397,23 +16402,42 @@ impl Route53 for Route53Client {
         params.put("version", &input.traffic_policy_version);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_traffic_policy_versions`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_traffic_policy_versions_with_metadata(
+        &self,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> Result<
+        (ListTrafficPolicyVersionsResponse, ResponseMetadata),
+        RusotoError<ListTrafficPolicyVersionsError>,
+    > {
+        let request = self.build_list_traffic_policy_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListTrafficPolicyInstancesByPolicyError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListTrafficPolicyVersionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListTrafficPolicyInstancesByPolicyResponse::default();
+            result = ListTrafficPolicyVersionsResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14422,27 +16446,36 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListTrafficPolicyInstancesByPolicyResponseDeserializer::deserialize(
+            result = ListTrafficPolicyVersionsResponseDeserializer::deserialize(
                 &actual_tag_name,
                 &mut stack,
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_traffic_policy_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_traffic_policy_versions(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_traffic_policy_versions_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about all of the versions for a specified traffic policy.</p> <p>Traffic policy versions are listed in numerical order by <code>VersionNumber</code>.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_traffic_policy_versions(
+    fn build_list_traffic_policy_versions_request(
         &self,
         input: ListTrafficPolicyVersionsRequest,
-    ) -> Result<ListTrafficPolicyVersionsResponse, RusotoError<ListTrafficPolicyVersionsError>>
-    {
+    ) -> SignedRequest {
         let request_uri = format!("/2013-04-01/trafficpolicies/{id}/versions", id = input.id)
             .replace("/hostedzone/hostedzone/", "/hostedzone/")
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyVersions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14453,21 +16486,44 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_vpc_association_authorizations`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_vpc_association_authorizations_with_metadata(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> Result<
+        (ListVPCAssociationAuthorizationsResponse, ResponseMetadata),
+        RusotoError<ListVPCAssociationAuthorizationsError>,
+    > {
+        let request = self.build_list_vpc_association_authorizations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListTrafficPolicyVersionsError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(ListVPCAssociationAuthorizationsError::from_response(
+                response,
+            ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListTrafficPolicyVersionsResponse::default();
+            result = ListVPCAssociationAuthorizationsResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14476,24 +16532,30 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListTrafficPolicyVersionsResponseDeserializer::deserialize(
+            result = ListVPCAssociationAuthorizationsResponseDeserializer::deserialize(
                 &actual_tag_name,
                 &mut stack,
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_vpc_association_authorizations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_vpc_association_authorizations(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_vpc_association_authorizations_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets a list of the VPCs that were created by other accounts and that can be associated with a specified hosted zone because you've submitted one or more <code>CreateVPCAssociationAuthorization</code> requests. </p> <p>The response includes a <code>VPCs</code> element with a <code>VPC</code> child element for each VPC that can be associated with the hosted zone.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_vpc_association_authorizations(
+    fn build_list_vpc_association_authorizations_request(
         &self,
         input: ListVPCAssociationAuthorizationsRequest,
-    ) -> Result<
-        ListVPCAssociationAuthorizationsResponse,
-        RusotoError<ListVPCAssociationAuthorizationsError>,
-    > {
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2013-04-01/hostedzone/{id}/authorizevpcassociation",
             id = input.hosted_zone_id
@@ -14502,6 +16564,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListVPCAssociationAuthorizations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_results {
@@ -14512,23 +16575,39 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `test_dns_answer`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn test_dns_answer_with_metadata(
+        &self,
+        input: TestDNSAnswerRequest,
+    ) -> Result<(TestDNSAnswerResponse, ResponseMetadata), RusotoError<TestDNSAnswerError>> {
+        let request = self.build_test_dns_answer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(ListVPCAssociationAuthorizationsError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(TestDNSAnswerError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = ListVPCAssociationAuthorizationsResponse::default();
+            result = TestDNSAnswerResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14537,24 +16616,28 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = ListVPCAssociationAuthorizationsResponseDeserializer::deserialize(
-                &actual_tag_name,
-                &mut stack,
-            )?;
+            result = TestDNSAnswerResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets the value that Amazon Route 53 returns in response to a DNS request for a specified record name and type. You can optionally specify the IP address of a DNS resolver, an EDNS0 client subnet IP address, and a subnet mask. </p>
-    #[allow(unused_variables, warnings)]
-    async fn test_dns_answer(
+    /// Presigns the request `test_dns_answer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_dns_answer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TestDNSAnswerRequest,
-    ) -> Result<TestDNSAnswerResponse, RusotoError<TestDNSAnswerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_dns_answer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_test_dns_answer_request(&self, input: TestDNSAnswerRequest) -> SignedRequest {
         let request_uri = "/2013-04-01/testdnsanswer";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("TestDNSAnswer");
 
         let mut params = Params::new();
         if let Some(ref x) = input.edns0_client_subnet_ip {
@@ -14571,21 +16654,40 @@ impl Route53 for Route53Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_health_check`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_health_check_with_metadata(
+        &self,
+        input: UpdateHealthCheckRequest,
+    ) -> Result<(UpdateHealthCheckResponse, ResponseMetadata), RusotoError<UpdateHealthCheckError>>
+    {
+        let request = self.build_update_health_check_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(TestDNSAnswerError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(UpdateHealthCheckError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = TestDNSAnswerResponse::default();
+            result = UpdateHealthCheckResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14594,18 +16696,25 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = TestDNSAnswerResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result =
+                UpdateHealthCheckResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates an existing health check. Note that some values can't be updated. </p> <p>For more information about updating health checks, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html">Creating, Updating, and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
-    async fn update_health_check(
+    /// Presigns the request `update_health_check` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_health_check(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateHealthCheckRequest,
-    ) -> Result<UpdateHealthCheckResponse, RusotoError<UpdateHealthCheckError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_health_check_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_update_health_check_request(&self, input: UpdateHealthCheckRequest) -> SignedRequest {
         let request_uri = format!(
             "/2013-04-01/healthcheck/{health_check_id}",
             health_check_id = input.health_check_id
@@ -14614,6 +16723,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHealthCheck");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHealthCheckRequestSerializer::serialize(
@@ -14624,21 +16734,42 @@ impl Route53 for Route53Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `update_hosted_zone_comment`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_hosted_zone_comment_with_metadata(
+        &self,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> Result<
+        (UpdateHostedZoneCommentResponse, ResponseMetadata),
+        RusotoError<UpdateHostedZoneCommentError>,
+    > {
+        let request = self.build_update_hosted_zone_comment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(UpdateHealthCheckError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(UpdateHostedZoneCommentError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = UpdateHealthCheckResponse::default();
+            result = UpdateHostedZoneCommentResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14647,24 +16778,36 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                UpdateHealthCheckResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result = UpdateHostedZoneCommentResponseDeserializer::deserialize(
+                &actual_tag_name,
+                &mut stack,
+            )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `update_hosted_zone_comment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_hosted_zone_comment(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_hosted_zone_comment_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Updates the comment for a specified hosted zone.</p>
     #[allow(unused_variables, warnings)]
-    async fn update_hosted_zone_comment(
+    fn build_update_hosted_zone_comment_request(
         &self,
         input: UpdateHostedZoneCommentRequest,
-    ) -> Result<UpdateHostedZoneCommentResponse, RusotoError<UpdateHostedZoneCommentError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
             .replace("/hostedzone/hostedzone/", "/hostedzone/")
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHostedZoneComment");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHostedZoneCommentRequestSerializer::serialize(
@@ -14675,21 +16818,42 @@ impl Route53 for Route53Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `update_traffic_policy_comment`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_traffic_policy_comment_with_metadata(
+        &self,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> Result<
+        (UpdateTrafficPolicyCommentResponse, ResponseMetadata),
+        RusotoError<UpdateTrafficPolicyCommentError>,
+    > {
+        let request = self.build_update_traffic_policy_comment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(UpdateHostedZoneCommentError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(UpdateTrafficPolicyCommentError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = UpdateHostedZoneCommentResponse::default();
+            result = UpdateTrafficPolicyCommentResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14698,22 +16862,30 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = UpdateHostedZoneCommentResponseDeserializer::deserialize(
+            result = UpdateTrafficPolicyCommentResponseDeserializer::deserialize(
                 &actual_tag_name,
                 &mut stack,
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `update_traffic_policy_comment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_traffic_policy_comment(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_traffic_policy_comment_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Updates the comment for a specified traffic policy version.</p>
     #[allow(unused_variables, warnings)]
-    async fn update_traffic_policy_comment(
+    fn build_update_traffic_policy_comment_request(
         &self,
         input: UpdateTrafficPolicyCommentRequest,
-    ) -> Result<UpdateTrafficPolicyCommentResponse, RusotoError<UpdateTrafficPolicyCommentError>>
-    {
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2013-04-01/trafficpolicy/{id}/{version}",
             id = input.id,
@@ -14723,6 +16895,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyComment");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyCommentRequestSerializer::serialize(
@@ -14733,21 +16906,42 @@ impl Route53 for Route53Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `update_traffic_policy_instance`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_traffic_policy_instance_with_metadata(
+        &self,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> Result<
+        (UpdateTrafficPolicyInstanceResponse, ResponseMetadata),
+        RusotoError<UpdateTrafficPolicyInstanceError>,
+    > {
+        let request = self.build_update_traffic_policy_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(UpdateTrafficPolicyCommentError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(UpdateTrafficPolicyInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = UpdateTrafficPolicyCommentResponse::default();
+            result = UpdateTrafficPolicyInstanceResponse::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -14756,27 +16950,36 @@ impl Route53 for Route53Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = UpdateTrafficPolicyCommentResponseDeserializer::deserialize(
+            result = UpdateTrafficPolicyInstanceResponseDeserializer::deserialize(
                 &actual_tag_name,
                 &mut stack,
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `update_traffic_policy_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_traffic_policy_instance(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_traffic_policy_instance_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Updates the resource record sets in a specified hosted zone that were created based on the settings in a specified traffic policy version.</p> <p>When you update a traffic policy instance, Amazon Route 53 continues to respond to DNS queries for the root resource record set name (such as example.com) while it replaces one group of resource record sets with another. Route 53 performs the following operations:</p> <ol> <li> <p>Route 53 creates a new group of resource record sets based on the specified traffic policy. This is true regardless of how significant the differences are between the existing resource record sets and the new resource record sets. </p> </li> <li> <p>When all of the new resource record sets have been created, Route 53 starts to respond to DNS queries for the root resource record set name (such as example.com) by using the new resource record sets.</p> </li> <li> <p>Route 53 deletes the old group of resource record sets that are associated with the root resource record set name.</p> </li> </ol></p>
     #[allow(unused_variables, warnings)]
-    async fn update_traffic_policy_instance(
+    fn build_update_traffic_policy_instance_request(
         &self,
         input: UpdateTrafficPolicyInstanceRequest,
-    ) -> Result<UpdateTrafficPolicyInstanceResponse, RusotoError<UpdateTrafficPolicyInstanceError>>
-    {
+    ) -> SignedRequest {
         let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
             .replace("/hostedzone/hostedzone/", "/hostedzone/")
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyInstance");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyInstanceRequestSerializer::serialize(
@@ -14787,36 +16990,912 @@ impl Route53 for Route53Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(UpdateTrafficPolicyInstanceError::from_response(response));
-        }
-
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-        let mut result;
+        request
+    }
 
-        if xml_response.body.is_empty() {
-            result = UpdateTrafficPolicyInstanceResponse::default();
-        } else {
-            let reader = EventReader::new_with_config(
-                xml_response.body.as_ref(),
-                ParserConfig::new().trim_whitespace(false),
-            );
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            let _start_document = stack.next();
-            let actual_tag_name = peek_at_name(&mut stack)?;
-            result = UpdateTrafficPolicyInstanceResponseDeserializer::deserialize(
-                &actual_tag_name,
-                &mut stack,
-            )?;
-        }
-        // parse non-payload
-        Ok(result)
+    /// The pages of `ListHealthChecks` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker` while its `IsTruncated` is true.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_health_checks_pages(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListHealthChecksResponse, RusotoError<ListHealthChecksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input| async move { client.list_health_checks(input).await },
+            |input: &ListHealthChecksRequest, output: &ListHealthChecksResponse| {
+                if !output.is_truncated {
+                    return None;
+                }
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListHealthChecksRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HealthChecks` of every page of `ListHealthChecks` results for `input`, as `list_health_checks_pages` requests them.
+    pub fn list_health_checks_stream(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> impl futures::Stream<Item = Result<HealthCheck, RusotoError<ListHealthChecksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_health_checks_pages(input), |page| {
+            page.health_checks
+        })
+    }
+
+    /// The pages of `ListHostedZones` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker` while its `IsTruncated` is true.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_hosted_zones_pages(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListHostedZonesResponse, RusotoError<ListHostedZonesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input| async move { client.list_hosted_zones(input).await },
+            |input: &ListHostedZonesRequest, output: &ListHostedZonesResponse| {
+                if !output.is_truncated {
+                    return None;
+                }
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListHostedZonesRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HostedZones` of every page of `ListHostedZones` results for `input`, as `list_hosted_zones_pages` requests them.
+    pub fn list_hosted_zones_stream(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> impl futures::Stream<Item = Result<HostedZone, RusotoError<ListHostedZonesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_hosted_zones_pages(input), |page| {
+            page.hosted_zones
+        })
+    }
+
+    /// The pages of `ListQueryLoggingConfigs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_query_logging_configs_pages(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListQueryLoggingConfigsResponse, RusotoError<ListQueryLoggingConfigsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input| async move { client.list_query_logging_configs(input).await },
+            |input: &ListQueryLoggingConfigsRequest, output: &ListQueryLoggingConfigsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListQueryLoggingConfigsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `QueryLoggingConfigs` of every page of `ListQueryLoggingConfigs` results for `input`, as `list_query_logging_configs_pages` requests them.
+    pub fn list_query_logging_configs_stream(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<QueryLoggingConfig, RusotoError<ListQueryLoggingConfigsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_query_logging_configs_pages(input), |page| {
+            page.query_logging_configs
+        })
+    }
+
+    /// The pages of `ListResourceRecordSets` results for `input`, requesting each next page with the `NextRecordName, NextRecordType, NextRecordIdentifier` of the last one as its `StartRecordName, StartRecordType, StartRecordIdentifier` while its `IsTruncated` is true.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_resource_record_sets_pages(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListResourceRecordSetsResponse, RusotoError<ListResourceRecordSetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input| async move { client.list_resource_record_sets(input).await },
+            |input: &ListResourceRecordSetsRequest, output: &ListResourceRecordSetsResponse| {
+                if !output.is_truncated {
+                    return None;
+                }
+                let start_record_name = output.next_record_name.clone();
+                let start_record_type = output.next_record_type.clone();
+                let start_record_identifier = output.next_record_identifier.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if (start_record_name.is_none()
+                    && start_record_type.is_none()
+                    && start_record_identifier.is_none())
+                    || (start_record_name == input.start_record_name
+                        && start_record_type == input.start_record_type
+                        && start_record_identifier == input.start_record_identifier)
+                {
+                    return None;
+                }
+                Some(ListResourceRecordSetsRequest {
+                    start_record_name,
+                    start_record_type,
+                    start_record_identifier,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceRecordSets` of every page of `ListResourceRecordSets` results for `input`, as `list_resource_record_sets_pages` requests them.
+    pub fn list_resource_record_sets_stream(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ResourceRecordSet, RusotoError<ListResourceRecordSetsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_resource_record_sets_pages(input), |page| {
+            page.resource_record_sets
+        })
+    }
+
+    /// The pages of `ListVPCAssociationAuthorizations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_vpc_association_authorizations_pages(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListVPCAssociationAuthorizationsResponse,
+            RusotoError<ListVPCAssociationAuthorizationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53Client, input| async move {
+                client.list_vpc_association_authorizations(input).await
+            },
+            |input: &ListVPCAssociationAuthorizationsRequest,
+             output: &ListVPCAssociationAuthorizationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListVPCAssociationAuthorizationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `VPCs` of every page of `ListVPCAssociationAuthorizations` results for `input`, as `list_vpc_association_authorizations_pages` requests them.
+    pub fn list_vpc_association_authorizations_stream(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> impl futures::Stream<Item = Result<VPC, RusotoError<ListVPCAssociationAuthorizationsError>>>
+           + Send {
+        rusoto_core::pagination::items(
+            self.list_vpc_association_authorizations_pages(input),
+            |page| page.vp_cs,
+        )
+    }
+
+    /// Waits for `ResourceRecordSetsChanged`, polling `GetChange` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_resource_record_sets_changed(
+        &self,
+        input: GetChangeRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetChangeError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_resource_record_sets_changed_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ResourceRecordSetsChanged` like `wait_until_resource_record_sets_changed`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_resource_record_sets_changed_with(
+        &self,
+        input: GetChangeRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetChangeError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_change(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.change_info)
+                        .map(|value| &value.status)
+                        .any(|value| value == "INSYNC"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Route53 for Route53Client {
+    /// <p><p>Associates an Amazon VPC with a private hosted zone. </p> <important> <p>To perform the association, the VPC and the private hosted zone must already exist. You can&#39;t convert a public hosted zone into a private hosted zone.</p> </important> <note> <p>If you want to associate a VPC that was created by using one AWS account with a private hosted zone that was created by using a different account, the AWS account that created the private hosted zone must first submit a <code>CreateVPCAssociationAuthorization</code> request. Then the account that created the VPC must submit an <code>AssociateVPCWithHostedZone</code> request.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    async fn associate_vpc_with_hosted_zone(
+        &self,
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> Result<AssociateVPCWithHostedZoneResponse, RusotoError<AssociateVPCWithHostedZoneError>>
+    {
+        self.associate_vpc_with_hosted_zone_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// For TXT records, see <a href="./util/fn.quote_txt_record.html">util::quote_txt_record</a>
+    /// <p>Creates, changes, or deletes a resource record set, which contains authoritative DNS information for a specified domain name or subdomain name. For example, you can use <code>ChangeResourceRecordSets</code> to create a resource record set that routes traffic for test.example.com to a web server that has an IP address of 192.0.2.44.</p> <p> <b>Change Batches and Transactional Changes</b> </p> <p>The request body must include a document with a <code>ChangeResourceRecordSetsRequest</code> element. The request body contains a list of change items, known as a change batch. Change batches are considered transactional changes. When using the Amazon Route 53 API to change resource record sets, Route 53 either makes all or none of the changes in a change batch request. This ensures that Route 53 never partially implements the intended changes to the resource record sets in a hosted zone. </p> <p>For example, a change batch request that deletes the <code>CNAME</code> record for www.example.com and creates an alias resource record set for www.example.com. Route 53 deletes the first resource record set and creates the second resource record set in a single operation. If either the <code>DELETE</code> or the <code>CREATE</code> action fails, then both changes (plus any other changes in the batch) fail, and the original <code>CNAME</code> record continues to exist.</p> <important> <p>Due to the nature of transactional changes, you can't delete the same resource record set more than once in a single change batch. If you attempt to delete the same change batch more than once, Route 53 returns an <code>InvalidChangeBatch</code> error.</p> </important> <p> <b>Traffic Flow</b> </p> <p>To create resource record sets for complex routing configurations, use either the traffic flow visual editor in the Route 53 console or the API actions for traffic policies and traffic policy instances. Save the configuration as a traffic policy, then associate the traffic policy with one or more domain names (such as example.com) or subdomain names (such as www.example.com), in the same hosted zone or in multiple hosted zones. You can roll back the updates if the new configuration isn't performing as expected. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/traffic-flow.html">Using Traffic Flow to Route DNS Traffic</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p> <b>Create, Delete, and Upsert</b> </p> <p>Use <code>ChangeResourceRecordsSetsRequest</code> to perform the following actions:</p> <ul> <li> <p> <code>CREATE</code>: Creates a resource record set that has the specified values.</p> </li> <li> <p> <code>DELETE</code>: Deletes an existing resource record set that has the specified values.</p> </li> <li> <p> <code>UPSERT</code>: If a resource record set does not already exist, AWS creates it. If a resource set does exist, Route 53 updates it with the values in the request. </p> </li> </ul> <p> <b>Syntaxes for Creating, Updating, and Deleting Resource Record Sets</b> </p> <p>The syntax for a request depends on the type of resource record set that you want to create, delete, or update, such as weighted, alias, or failover. The XML elements in your request must appear in the order listed in the syntax. </p> <p>For an example for each type of resource record set, see "Examples."</p> <p>Don't refer to the syntax in the "Parameter Syntax" section, which includes all of the elements for every kind of resource record set that you can create, delete, or update by using <code>ChangeResourceRecordSets</code>. </p> <p> <b>Change Propagation to Route 53 DNS Servers</b> </p> <p>When you submit a <code>ChangeResourceRecordSets</code> request, Route 53 propagates your changes to all of the Route 53 authoritative DNS servers. While your changes are propagating, <code>GetChange</code> returns a status of <code>PENDING</code>. When propagation is complete, <code>GetChange</code> returns a status of <code>INSYNC</code>. Changes generally propagate to all Route 53 name servers within 60 seconds. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetChange.html">GetChange</a>.</p> <p> <b>Limits on ChangeResourceRecordSets Requests</b> </p> <p>For information about the limits on a <code>ChangeResourceRecordSets</code> request, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn change_resource_record_sets(
+        &self,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> Result<ChangeResourceRecordSetsResponse, RusotoError<ChangeResourceRecordSetsError>> {
+        self.change_resource_record_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds, edits, or deletes tags for a health check or a hosted zone.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn change_tags_for_resource(
+        &self,
+        input: ChangeTagsForResourceRequest,
+    ) -> Result<ChangeTagsForResourceResponse, RusotoError<ChangeTagsForResourceError>> {
+        self.change_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new health check.</p> <p>For information about adding health checks to resource record sets, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ResourceRecordSet.html#Route53-Type-ResourceRecordSet-HealthCheckId">HealthCheckId</a> in <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>. </p> <p> <b>ELB Load Balancers</b> </p> <p>If you&#39;re registering EC2 instances with an Elastic Load Balancing (ELB) load balancer, do not create Amazon Route 53 health checks for the EC2 instances. When you register an EC2 instance with a load balancer, you configure settings for an ELB health check, which performs a similar function to a Route 53 health check.</p> <p> <b>Private Hosted Zones</b> </p> <p>You can associate health checks with failover resource record sets in a private hosted zone. Note the following:</p> <ul> <li> <p>Route 53 health checkers are outside the VPC. To check the health of an endpoint within a VPC by IP address, you must assign a public IP address to the instance in the VPC.</p> </li> <li> <p>You can configure a health checker to check the health of an external resource that the instance relies on, such as a database server.</p> </li> <li> <p>You can create a CloudWatch metric, associate an alarm with the metric, and then create a health check that is based on the state of the alarm. For example, you might create a CloudWatch metric that checks the status of the Amazon EC2 <code>StatusCheckFailed</code> metric, add an alarm to the metric, and then create a health check that is based on the state of the alarm. For information about creating CloudWatch metrics and alarms by using the CloudWatch console, see the <a href="http://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/WhatIsCloudWatch.html">Amazon CloudWatch User Guide</a>.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn create_health_check(
+        &self,
+        input: CreateHealthCheckRequest,
+    ) -> Result<CreateHealthCheckResponse, RusotoError<CreateHealthCheckError>> {
+        self.create_health_check_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new public or private hosted zone. You create records in a public hosted zone to define how you want to route traffic on the internet for a domain, such as example.com, and its subdomains (apex.example.com, acme.example.com). You create records in a private hosted zone to define how you want to route traffic for a domain and its subdomains within one or more Amazon Virtual Private Clouds (Amazon VPCs). </p> <important> <p>You can't convert a public hosted zone to a private hosted zone or vice versa. Instead, you must create a new hosted zone with the same name and create new resource record sets.</p> </important> <p>For more information about charges for hosted zones, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p> <p>Note the following:</p> <ul> <li> <p>You can't create a hosted zone for a top-level domain (TLD) such as .com.</p> </li> <li> <p>For public hosted zones, Amazon Route 53 automatically creates a default SOA record and four NS records for the zone. For more information about SOA and NS records, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/SOA-NSrecords.html">NS and SOA Records that Route 53 Creates for a Hosted Zone</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>If you want to use the same name servers for multiple public hosted zones, you can optionally associate a reusable delegation set with the hosted zone. See the <code>DelegationSetId</code> element.</p> </li> <li> <p>If your domain is registered with a registrar other than Route 53, you must update the name servers with your registrar to make Route 53 the DNS service for the domain. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/MigratingDNS.html">Migrating DNS Service for an Existing Domain to Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>. </p> </li> </ul> <p>When you submit a <code>CreateHostedZone</code> request, the initial status of the hosted zone is <code>PENDING</code>. For public hosted zones, this means that the NS and SOA records are not yet available on all Route 53 DNS servers. When the NS and SOA records are available, the status of the zone changes to <code>INSYNC</code>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_hosted_zone(
+        &self,
+        input: CreateHostedZoneRequest,
+    ) -> Result<CreateHostedZoneResponse, RusotoError<CreateHostedZoneError>> {
+        self.create_hosted_zone_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a configuration for DNS query logging. After you create a query logging configuration, Amazon Route 53 begins to publish log data to an Amazon CloudWatch Logs log group.</p> <p>DNS query logs contain information about the queries that Route 53 receives for a specified public hosted zone, such as the following:</p> <ul> <li> <p>Route 53 edge location that responded to the DNS query</p> </li> <li> <p>Domain or subdomain that was requested</p> </li> <li> <p>DNS record type, such as A or AAAA</p> </li> <li> <p>DNS response code, such as <code>NoError</code> or <code>ServFail</code> </p> </li> </ul> <dl> <dt>Log Group and Resource Policy</dt> <dd> <p>Before you create a query logging configuration, perform the following operations.</p> <note> <p>If you create a query logging configuration using the Route 53 console, Route 53 performs these operations automatically.</p> </note> <ol> <li> <p>Create a CloudWatch Logs log group, and make note of the ARN, which you specify when you create a query logging configuration. Note the following:</p> <ul> <li> <p>You must create the log group in the us-east-1 region.</p> </li> <li> <p>You must use the same AWS account to create the log group and the hosted zone that you want to configure query logging for.</p> </li> <li> <p>When you create log groups for query logging, we recommend that you use a consistent prefix, for example:</p> <p> <code>/aws/route53/<i>hosted zone name</i> </code> </p> <p>In the next step, you&#39;ll create a resource policy, which controls access to one or more log groups and the associated AWS resources, such as Route 53 hosted zones. There&#39;s a limit on the number of resource policies that you can create, so we recommend that you use a consistent prefix so you can use the same resource policy for all the log groups that you create for query logging.</p> </li> </ul> </li> <li> <p>Create a CloudWatch Logs resource policy, and give it the permissions that Route 53 needs to create log streams and to send query logs to log streams. For the value of <code>Resource</code>, specify the ARN for the log group that you created in the previous step. To use the same resource policy for all the CloudWatch Logs log groups that you created for query logging configurations, replace the hosted zone name with <code><em></code>, for example:</p> <p> <code>arn:aws:logs:us-east-1:123412341234:log-group:/aws/route53/</em></code> </p> <note> <p>You can&#39;t use the CloudWatch console to create or edit a resource policy. You must use the CloudWatch API, one of the AWS SDKs, or the AWS CLI.</p> </note> </li> </ol> </dd> <dt>Log Streams and Edge Locations</dt> <dd> <p>When Route 53 finishes creating the configuration for DNS query logging, it does the following:</p> <ul> <li> <p>Creates a log stream for an edge location the first time that the edge location responds to DNS queries for the specified hosted zone. That log stream is used to log all queries that Route 53 responds to for that edge location.</p> </li> <li> <p>Begins to send query logs to the applicable log stream.</p> </li> </ul> <p>The name of each log stream is in the following format:</p> <p> <code> <i>hosted zone ID</i>/<i>edge location code</i> </code> </p> <p>The edge location code is a three-letter code and an arbitrarily assigned number, for example, DFW3. The three-letter code typically corresponds with the International Air Transport Association airport code for an airport near the edge location. (These abbreviations might change in the future.) For a list of edge locations, see &quot;The Route 53 Global Network&quot; on the <a href="http://aws.amazon.com/route53/details/">Route 53 Product Details</a> page.</p> </dd> <dt>Queries That Are Logged</dt> <dd> <p>Query logs contain only the queries that DNS resolvers forward to Route 53. If a DNS resolver has already cached the response to a query (such as the IP address for a load balancer for example.com), the resolver will continue to return the cached response. It doesn&#39;t forward another query to Route 53 until the TTL for the corresponding resource record set expires. Depending on how many DNS queries are submitted for a resource record set, and depending on the TTL for that resource record set, query logs might contain information about only one query out of every several thousand queries that are submitted to DNS. For more information about how DNS works, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/welcome-dns-service.html">Routing Internet Traffic to Your Website or Web Application</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Log File Format</dt> <dd> <p>For a list of the values in each query log and the format of each value, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Pricing</dt> <dd> <p>For information about charges for query logs, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </dd> <dt>How to Stop Logging</dt> <dd> <p>If you want Route 53 to stop sending query logs to CloudWatch Logs, delete the query logging configuration. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_DeleteQueryLoggingConfig.html">DeleteQueryLoggingConfig</a>.</p> </dd> </dl></p>
+    #[allow(unused_variables, warnings)]
+    async fn create_query_logging_config(
+        &self,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> Result<CreateQueryLoggingConfigResponse, RusotoError<CreateQueryLoggingConfigError>> {
+        self.create_query_logging_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a delegation set (a group of four name servers) that can be reused by multiple hosted zones. If a hosted zoned ID is specified, <code>CreateReusableDelegationSet</code> marks the delegation set associated with that zone as reusable.</p> <note> <p>You can&#39;t associate a reusable delegation set with a private hosted zone.</p> </note> <p>For information about using a reusable delegation set to configure white label name servers, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/white-label-name-servers.html">Configuring White Label Name Servers</a>.</p> <p>The process for migrating existing hosted zones to use a reusable delegation set is comparable to the process for configuring white label name servers. You need to perform the following steps:</p> <ol> <li> <p>Create a reusable delegation set.</p> </li> <li> <p>Recreate hosted zones, and reduce the TTL to 60 seconds or less.</p> </li> <li> <p>Recreate resource record sets in the new hosted zones.</p> </li> <li> <p>Change the registrar&#39;s name servers to use the name servers for the new hosted zones.</p> </li> <li> <p>Monitor traffic for the website or application.</p> </li> <li> <p>Change TTLs back to their original values.</p> </li> </ol> <p>If you want to migrate existing hosted zones to use a reusable delegation set, the existing hosted zones can&#39;t use any of the name servers that are assigned to the reusable delegation set. If one or more hosted zones do use one or more name servers that are assigned to the reusable delegation set, you can do one of the following:</p> <ul> <li> <p>For small numbers of hosted zones—up to a few hundred—it&#39;s relatively easy to create reusable delegation sets until you get one that has four name servers that don&#39;t overlap with any of the name servers in your hosted zones.</p> </li> <li> <p>For larger numbers of hosted zones, the easiest solution is to use more than one reusable delegation set.</p> </li> <li> <p>For larger numbers of hosted zones, you can also migrate hosted zones that have overlapping name servers to hosted zones that don&#39;t have overlapping name servers, then migrate the hosted zones again to use the reusable delegation set.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn create_reusable_delegation_set(
+        &self,
+        input: CreateReusableDelegationSetRequest,
+    ) -> Result<CreateReusableDelegationSetResponse, RusotoError<CreateReusableDelegationSetError>>
+    {
+        self.create_reusable_delegation_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a traffic policy, which you use to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com).</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_traffic_policy(
+        &self,
+        input: CreateTrafficPolicyRequest,
+    ) -> Result<CreateTrafficPolicyResponse, RusotoError<CreateTrafficPolicyError>> {
+        self.create_traffic_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates resource record sets in a specified hosted zone based on the settings in a specified traffic policy version. In addition, <code>CreateTrafficPolicyInstance</code> associates the resource record sets with a specified domain name (such as example.com) or subdomain name (such as www.example.com). Amazon Route 53 responds to DNS queries for the domain or subdomain name by using the resource record sets that <code>CreateTrafficPolicyInstance</code> created.</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_traffic_policy_instance(
+        &self,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> Result<CreateTrafficPolicyInstanceResponse, RusotoError<CreateTrafficPolicyInstanceError>>
+    {
+        self.create_traffic_policy_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new version of an existing traffic policy. When you create a new version of a traffic policy, you specify the ID of the traffic policy that you want to update and a JSON-formatted document that describes the new version. You use traffic policies to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com). You can create a maximum of 1000 versions of a traffic policy. If you reach the limit and need to create another version, you'll need to start a new traffic policy.</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_traffic_policy_version(
+        &self,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> Result<CreateTrafficPolicyVersionResponse, RusotoError<CreateTrafficPolicyVersionError>>
+    {
+        self.create_traffic_policy_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Authorizes the AWS account that created a specified VPC to submit an <code>AssociateVPCWithHostedZone</code> request to associate the VPC with a specified hosted zone that was created by a different account. To submit a <code>CreateVPCAssociationAuthorization</code> request, you must use the account that created the hosted zone. After you authorize the association, use the account that created the VPC to submit an <code>AssociateVPCWithHostedZone</code> request.</p> <note> <p>If you want to associate multiple VPCs that you created by using one account with a hosted zone that you created by using a different account, you must submit one authorization request for each VPC.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    async fn create_vpc_association_authorization(
+        &self,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> Result<
+        CreateVPCAssociationAuthorizationResponse,
+        RusotoError<CreateVPCAssociationAuthorizationError>,
+    > {
+        self.create_vpc_association_authorization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a health check.</p> <important> <p>Amazon Route 53 does not prevent you from deleting a health check even if the health check is associated with one or more resource record sets. If you delete a health check and you don&#39;t update the associated resource record sets, the future status of the health check can&#39;t be predicted and may change. This will affect the routing of DNS queries for your DNS failover configuration. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html#health-checks-deleting.html">Replacing and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_health_check(
+        &self,
+        input: DeleteHealthCheckRequest,
+    ) -> Result<DeleteHealthCheckResponse, RusotoError<DeleteHealthCheckError>> {
+        self.delete_health_check_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a hosted zone.</p> <p>If the hosted zone was created by another service, such as AWS Cloud Map, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DeleteHostedZone.html#delete-public-hosted-zone-created-by-another-service">Deleting Public Hosted Zones That Were Created by Another Service</a> in the <i>Amazon Route 53 Developer Guide</i> for information about how to delete it. (The process is the same for public and private hosted zones that were created by another service.)</p> <p>If you want to keep your domain registration but you want to stop routing internet traffic to your website or web application, we recommend that you delete resource record sets in the hosted zone instead of deleting the hosted zone.</p> <important> <p>If you delete a hosted zone, you can&#39;t undelete it. You must create a new hosted zone and update the name servers for your domain registration, which can require up to 48 hours to take effect. (If you delegated responsibility for a subdomain to a hosted zone and you delete the child hosted zone, you must update the name servers in the parent hosted zone.) In addition, if you delete a hosted zone, someone could hijack the domain and route traffic to their own resources using your domain name.</p> </important> <p>If you want to avoid the monthly charge for the hosted zone, you can transfer DNS service for the domain to a free DNS service. When you transfer DNS service, you have to update the name servers for the domain registration. If the domain is registered with Route 53, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_UpdateDomainNameservers.html">UpdateDomainNameservers</a> for information about how to replace Route 53 name servers with name servers for the new DNS service. If the domain is registered with another registrar, use the method provided by the registrar to update name servers for the domain registration. For more information, perform an internet search on &quot;free DNS service.&quot;</p> <p>You can delete a hosted zone only if it contains only the default SOA record and NS resource record sets. If the hosted zone contains other resource record sets, you must delete them before you can delete the hosted zone. If you try to delete a hosted zone that contains other resource record sets, the request fails, and Route 53 returns a <code>HostedZoneNotEmpty</code> error. For information about deleting records from your hosted zone, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>.</p> <p>To verify that the hosted zone has been deleted, do one of the following:</p> <ul> <li> <p>Use the <code>GetHostedZone</code> action to request information about the hosted zone.</p> </li> <li> <p>Use the <code>ListHostedZones</code> action to get a list of the hosted zones associated with the current AWS account.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_hosted_zone(
+        &self,
+        input: DeleteHostedZoneRequest,
+    ) -> Result<DeleteHostedZoneResponse, RusotoError<DeleteHostedZoneError>> {
+        self.delete_hosted_zone_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a configuration for DNS query logging. If you delete a configuration, Amazon Route 53 stops sending query logs to CloudWatch Logs. Route 53 doesn't delete any logs that are already in CloudWatch Logs.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_query_logging_config(
+        &self,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> Result<DeleteQueryLoggingConfigResponse, RusotoError<DeleteQueryLoggingConfigError>> {
+        self.delete_query_logging_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a reusable delegation set.</p> <important> <p>You can delete a reusable delegation set only if it isn't associated with any hosted zones.</p> </important> <p>To verify that the reusable delegation set is not associated with any hosted zones, submit a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetReusableDelegationSet.html">GetReusableDelegationSet</a> request and specify the ID of the reusable delegation set that you want to delete.</p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_reusable_delegation_set(
+        &self,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> Result<DeleteReusableDelegationSetResponse, RusotoError<DeleteReusableDelegationSetError>>
+    {
+        self.delete_reusable_delegation_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a traffic policy.</p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_traffic_policy(
+        &self,
+        input: DeleteTrafficPolicyRequest,
+    ) -> Result<DeleteTrafficPolicyResponse, RusotoError<DeleteTrafficPolicyError>> {
+        self.delete_traffic_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a traffic policy instance and all of the resource record sets that Amazon Route 53 created when you created the instance.</p> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_traffic_policy_instance(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> Result<DeleteTrafficPolicyInstanceResponse, RusotoError<DeleteTrafficPolicyInstanceError>>
+    {
+        self.delete_traffic_policy_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes authorization to submit an <code>AssociateVPCWithHostedZone</code> request to associate a specified VPC with a hosted zone that was created by a different account. You must use the account that created the hosted zone to submit a <code>DeleteVPCAssociationAuthorization</code> request.</p> <important> <p>Sending this request only prevents the AWS account that created the VPC from associating the VPC with the Amazon Route 53 hosted zone in the future. If the VPC is already associated with the hosted zone, <code>DeleteVPCAssociationAuthorization</code> won&#39;t disassociate the VPC from the hosted zone. If you want to delete an existing association, use <code>DisassociateVPCFromHostedZone</code>.</p> </important></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_vpc_association_authorization(
+        &self,
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> Result<
+        DeleteVPCAssociationAuthorizationResponse,
+        RusotoError<DeleteVPCAssociationAuthorizationError>,
+    > {
+        self.delete_vpc_association_authorization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Disassociates a VPC from a Amazon Route 53 private hosted zone. Note the following:</p> <ul> <li> <p>You can&#39;t disassociate the last VPC from a private hosted zone.</p> </li> <li> <p>You can&#39;t convert a private hosted zone into a public hosted zone.</p> </li> <li> <p>You can submit a <code>DisassociateVPCFromHostedZone</code> request using either the account that created the hosted zone or the account that created the VPC.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn disassociate_vpc_from_hosted_zone(
+        &self,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> Result<
+        DisassociateVPCFromHostedZoneResponse,
+        RusotoError<DisassociateVPCFromHostedZoneError>,
+    > {
+        self.disassociate_vpc_from_hosted_zone_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Gets the specified limit for the current account, for example, the maximum number of health checks that you can create using the account.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p> <note> <p>You can also view account limits in AWS Trusted Advisor. Sign in to the AWS Management Console and open the Trusted Advisor console at <a href="https://console.aws.amazon.com/trustedadvisor">https://console.aws.amazon.com/trustedadvisor/</a>. Then choose <b>Service limits</b> in the navigation pane.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_account_limit(
+        &self,
+        input: GetAccountLimitRequest,
+    ) -> Result<GetAccountLimitResponse, RusotoError<GetAccountLimitError>> {
+        self.get_account_limit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the current status of a change batch request. The status is one of the following values:</p> <ul> <li> <p> <code>PENDING</code> indicates that the changes in this request have not propagated to all Amazon Route 53 DNS servers. This is the initial status of all change batch requests.</p> </li> <li> <p> <code>INSYNC</code> indicates that the changes have propagated to all Route 53 DNS servers. </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_change(
+        &self,
+        input: GetChangeRequest,
+    ) -> Result<GetChangeResponse, RusotoError<GetChangeError>> {
+        self.get_change_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><important> <p> <code>GetCheckerIpRanges</code> still works, but we recommend that you download ip-ranges.json, which includes IP address ranges for all AWS services. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/route-53-ip-addresses.html">IP Address Ranges of Amazon Route 53 Servers</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_checker_ip_ranges(
+        &self,
+        input: GetCheckerIpRangesRequest,
+    ) -> Result<GetCheckerIpRangesResponse, RusotoError<GetCheckerIpRangesError>> {
+        self.get_checker_ip_ranges_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about whether a specified geographic location is supported for Amazon Route 53 geolocation resource record sets.</p> <p>Use the following syntax to determine whether a continent is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?continentcode=<i>two-letter abbreviation for a continent</i> </code> </p> <p>Use the following syntax to determine whether a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i> </code> </p> <p>Use the following syntax to determine whether a subdivision of a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i>&amp;subdivisioncode=<i>subdivision code</i> </code> </p>
+    #[allow(unused_variables, warnings)]
+    async fn get_geo_location(
+        &self,
+        input: GetGeoLocationRequest,
+    ) -> Result<GetGeoLocationResponse, RusotoError<GetGeoLocationError>> {
+        self.get_geo_location_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specified health check.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_health_check(
+        &self,
+        input: GetHealthCheckRequest,
+    ) -> Result<GetHealthCheckResponse, RusotoError<GetHealthCheckError>> {
+        self.get_health_check_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the number of health checks that are associated with the current AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_health_check_count(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> Result<GetHealthCheckCountResponse, RusotoError<GetHealthCheckCountError>> {
+        self.get_health_check_count_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the reason that a specified health check failed most recently.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_health_check_last_failure_reason(
+        &self,
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> Result<
+        GetHealthCheckLastFailureReasonResponse,
+        RusotoError<GetHealthCheckLastFailureReasonError>,
+    > {
+        self.get_health_check_last_failure_reason_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets status of a specified health check. </p>
+    #[allow(unused_variables, warnings)]
+    async fn get_health_check_status(
+        &self,
+        input: GetHealthCheckStatusRequest,
+    ) -> Result<GetHealthCheckStatusResponse, RusotoError<GetHealthCheckStatusError>> {
+        self.get_health_check_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specified hosted zone including the four name servers assigned to the hosted zone.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_hosted_zone(
+        &self,
+        input: GetHostedZoneRequest,
+    ) -> Result<GetHostedZoneResponse, RusotoError<GetHostedZoneError>> {
+        self.get_hosted_zone_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the number of hosted zones that are associated with the current AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_hosted_zone_count(
+        &self,
+        input: GetHostedZoneCountRequest,
+    ) -> Result<GetHostedZoneCountResponse, RusotoError<GetHostedZoneCountError>> {
+        self.get_hosted_zone_count_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the specified limit for a specified hosted zone, for example, the maximum number of records that you can create in the hosted zone. </p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_hosted_zone_limit(
+        &self,
+        input: GetHostedZoneLimitRequest,
+    ) -> Result<GetHostedZoneLimitResponse, RusotoError<GetHostedZoneLimitError>> {
+        self.get_hosted_zone_limit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specified configuration for DNS query logging.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a> and <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_query_logging_config(
+        &self,
+        input: GetQueryLoggingConfigRequest,
+    ) -> Result<GetQueryLoggingConfigResponse, RusotoError<GetQueryLoggingConfigError>> {
+        self.get_query_logging_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a specified reusable delegation set, including the four name servers that are assigned to the delegation set.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_reusable_delegation_set(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> Result<GetReusableDelegationSetResponse, RusotoError<GetReusableDelegationSetError>> {
+        self.get_reusable_delegation_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_reusable_delegation_set_limit(
+        &self,
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> Result<
+        GetReusableDelegationSetLimitResponse,
+        RusotoError<GetReusableDelegationSetLimitError>,
+    > {
+        self.get_reusable_delegation_set_limit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specific traffic policy version.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_traffic_policy(
+        &self,
+        input: GetTrafficPolicyRequest,
+    ) -> Result<GetTrafficPolicyResponse, RusotoError<GetTrafficPolicyError>> {
+        self.get_traffic_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Gets information about a specified traffic policy instance.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there&#39;s a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_traffic_policy_instance(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> Result<GetTrafficPolicyInstanceResponse, RusotoError<GetTrafficPolicyInstanceError>> {
+        self.get_traffic_policy_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the number of traffic policy instances that are associated with the current AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_traffic_policy_instance_count(
+        &self,
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> Result<
+        GetTrafficPolicyInstanceCountResponse,
+        RusotoError<GetTrafficPolicyInstanceCountError>,
+    > {
+        self.get_traffic_policy_instance_count_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of supported geographic locations.</p> <p>Countries are listed first, and continents are listed last. If Amazon Route 53 supports subdivisions for a country (for example, states or provinces), the subdivisions for that country are listed in alphabetical order immediately after the corresponding country.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_geo_locations(
+        &self,
+        input: ListGeoLocationsRequest,
+    ) -> Result<ListGeoLocationsResponse, RusotoError<ListGeoLocationsError>> {
+        self.list_geo_locations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a list of the health checks that are associated with the current AWS account. </p>
+    #[allow(unused_variables, warnings)]
+    async fn list_health_checks(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> Result<ListHealthChecksResponse, RusotoError<ListHealthChecksError>> {
+        self.list_health_checks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of the public and private hosted zones that are associated with the current AWS account. The response includes a <code>HostedZones</code> child element for each hosted zone.</p> <p>Amazon Route 53 returns a maximum of 100 items in each response. If you have a lot of hosted zones, you can use the <code>maxitems</code> parameter to list them in groups of up to 100.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_hosted_zones(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> Result<ListHostedZonesResponse, RusotoError<ListHostedZonesError>> {
+        self.list_hosted_zones_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves a list of your hosted zones in lexicographic order. The response includes a <code>HostedZones</code> child element for each hosted zone created by the current AWS account. </p> <p> <code>ListHostedZonesByName</code> sorts hosted zones by name with the labels reversed. For example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order in some circumstances.</p> <p>If the domain name includes escape characters or Punycode, <code>ListHostedZonesByName</code> alphabetizes the domain name using the escaped or Punycoded value, which is the format that Amazon Route 53 saves in its database. For example, to create a hosted zone for exämple.com, you specify ex\344mple.com for the domain name. <code>ListHostedZonesByName</code> alphabetizes it as:</p> <p> <code>com.ex\344mple.</code> </p> <p>The labels are reversed and alphabetized using the escaped value. For more information about valid domain name formats, including internationalized domain names, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DomainNameFormat.html">DNS Domain Name Format</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>Route 53 returns up to 100 items in each response. If you have a lot of hosted zones, use the <code>MaxItems</code> parameter to list them in groups of up to 100. The response includes values that help navigate from one group of <code>MaxItems</code> hosted zones to the next:</p> <ul> <li> <p>The <code>DNSName</code> and <code>HostedZoneId</code> elements in the response contain the values, if any, specified for the <code>dnsname</code> and <code>hostedzoneid</code> parameters in the request that produced the current response.</p> </li> <li> <p>The <code>MaxItems</code> element in the response contains the value, if any, that you specified for the <code>maxitems</code> parameter in the request that produced the current response.</p> </li> <li> <p>If the value of <code>IsTruncated</code> in the response is true, there are more hosted zones associated with the current AWS account. </p> <p>If <code>IsTruncated</code> is false, this response includes the last hosted zone that is associated with the current account. The <code>NextDNSName</code> element and <code>NextHostedZoneId</code> elements are omitted from the response.</p> </li> <li> <p>The <code>NextDNSName</code> and <code>NextHostedZoneId</code> elements in the response contain the domain name and the hosted zone ID of the next hosted zone that is associated with the current AWS account. If you want to list more hosted zones, make another call to <code>ListHostedZonesByName</code>, and specify the value of <code>NextDNSName</code> and <code>NextHostedZoneId</code> in the <code>dnsname</code> and <code>hostedzoneid</code> parameters, respectively.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn list_hosted_zones_by_name(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> Result<ListHostedZonesByNameResponse, RusotoError<ListHostedZonesByNameError>> {
+        self.list_hosted_zones_by_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the configurations for DNS query logging that are associated with the current AWS account or the configuration that is associated with a specified hosted zone.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>. Additional information, including the format of DNS query logs, appears in <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_query_logging_configs(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> Result<ListQueryLoggingConfigsResponse, RusotoError<ListQueryLoggingConfigsError>> {
+        self.list_query_logging_configs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resource record sets in a specified hosted zone.</p> <p> <code>ListResourceRecordSets</code> returns up to 100 resource record sets at a time in ASCII order, beginning at a position specified by the <code>name</code> and <code>type</code> elements.</p> <p> <b>Sort order</b> </p> <p> <code>ListResourceRecordSets</code> sorts results first by DNS name with the labels reversed, for example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order when the record name contains characters that appear before <code>.</code> (decimal 46) in the ASCII table. These characters include the following: <code>! " # $ % &amp; ' ( ) * + , -</code> </p> <p>When multiple records have the same DNS name, <code>ListResourceRecordSets</code> sorts results by the record type.</p> <p> <b>Specifying where to start listing records</b> </p> <p>You can use the name and type elements to specify the resource record set that the list begins with:</p> <dl> <dt>If you do not specify Name or Type</dt> <dd> <p>The results begin with the first resource record set that the hosted zone contains.</p> </dd> <dt>If you specify Name but not Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>.</p> </dd> <dt>If you specify Type but not Name</dt> <dd> <p>Amazon Route 53 returns the <code>InvalidInput</code> error.</p> </dd> <dt>If you specify both Name and Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>, and whose type is greater than or equal to <code>Type</code>.</p> </dd> </dl> <p> <b>Resource record sets that are PENDING</b> </p> <p>This action returns the most current version of the records. This includes records that are <code>PENDING</code>, and that are not yet available on all Route 53 DNS servers.</p> <p> <b>Changing resource record sets</b> </p> <p>To ensure that you get an accurate listing of the resource record sets for a hosted zone at a point in time, do not submit a <code>ChangeResourceRecordSets</code> request while you're paging through the results of a <code>ListResourceRecordSets</code> request. If you do, some pages may display results without the latest changes while other pages display results with the latest changes.</p> <p> <b>Displaying the next page of results</b> </p> <p>If a <code>ListResourceRecordSets</code> command returns more than one page of results, the value of <code>IsTruncated</code> is <code>true</code>. To display the next page of results, get the values of <code>NextRecordName</code>, <code>NextRecordType</code>, and <code>NextRecordIdentifier</code> (if any) from the response. Then submit another <code>ListResourceRecordSets</code> request, and specify those values for <code>StartRecordName</code>, <code>StartRecordType</code>, and <code>StartRecordIdentifier</code>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_resource_record_sets(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> Result<ListResourceRecordSetsResponse, RusotoError<ListResourceRecordSetsError>> {
+        self.list_resource_record_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of the reusable delegation sets that are associated with the current AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_reusable_delegation_sets(
+        &self,
+        input: ListReusableDelegationSetsRequest,
+    ) -> Result<ListReusableDelegationSetsResponse, RusotoError<ListReusableDelegationSetsError>>
+    {
+        self.list_reusable_delegation_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists tags for one health check or hosted zone. </p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists tags for up to 10 health checks or hosted zones.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_tags_for_resources(
+        &self,
+        input: ListTagsForResourcesRequest,
+    ) -> Result<ListTagsForResourcesResponse, RusotoError<ListTagsForResourcesError>> {
+        self.list_tags_for_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the latest version for every traffic policy that is associated with the current AWS account. Policies are listed in the order that they were created in. </p>
+    #[allow(unused_variables, warnings)]
+    async fn list_traffic_policies(
+        &self,
+        input: ListTrafficPoliciesRequest,
+    ) -> Result<ListTrafficPoliciesResponse, RusotoError<ListTrafficPoliciesError>> {
+        self.list_traffic_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the traffic policy instances that you created by using the current AWS account.</p> <note> <p>After you submit an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_traffic_policy_instances(
+        &self,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> Result<ListTrafficPolicyInstancesResponse, RusotoError<ListTrafficPolicyInstancesError>>
+    {
+        self.list_traffic_policy_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the traffic policy instances that you created in a specified hosted zone.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_traffic_policy_instances_by_hosted_zone(
+        &self,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> Result<
+        ListTrafficPolicyInstancesByHostedZoneResponse,
+        RusotoError<ListTrafficPolicyInstancesByHostedZoneError>,
+    > {
+        self.list_traffic_policy_instances_by_hosted_zone_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the traffic policy instances that you created by using a specify traffic policy version.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_traffic_policy_instances_by_policy(
+        &self,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> Result<
+        ListTrafficPolicyInstancesByPolicyResponse,
+        RusotoError<ListTrafficPolicyInstancesByPolicyError>,
+    > {
+        self.list_traffic_policy_instances_by_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about all of the versions for a specified traffic policy.</p> <p>Traffic policy versions are listed in numerical order by <code>VersionNumber</code>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_traffic_policy_versions(
+        &self,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> Result<ListTrafficPolicyVersionsResponse, RusotoError<ListTrafficPolicyVersionsError>>
+    {
+        self.list_traffic_policy_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the VPCs that were created by other accounts and that can be associated with a specified hosted zone because you've submitted one or more <code>CreateVPCAssociationAuthorization</code> requests. </p> <p>The response includes a <code>VPCs</code> element with a <code>VPC</code> child element for each VPC that can be associated with the hosted zone.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_vpc_association_authorizations(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> Result<
+        ListVPCAssociationAuthorizationsResponse,
+        RusotoError<ListVPCAssociationAuthorizationsError>,
+    > {
+        self.list_vpc_association_authorizations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the value that Amazon Route 53 returns in response to a DNS request for a specified record name and type. You can optionally specify the IP address of a DNS resolver, an EDNS0 client subnet IP address, and a subnet mask. </p>
+    #[allow(unused_variables, warnings)]
+    async fn test_dns_answer(
+        &self,
+        input: TestDNSAnswerRequest,
+    ) -> Result<TestDNSAnswerResponse, RusotoError<TestDNSAnswerError>> {
+        self.test_dns_answer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing health check. Note that some values can't be updated. </p> <p>For more information about updating health checks, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html">Creating, Updating, and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn update_health_check(
+        &self,
+        input: UpdateHealthCheckRequest,
+    ) -> Result<UpdateHealthCheckResponse, RusotoError<UpdateHealthCheckError>> {
+        self.update_health_check_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the comment for a specified hosted zone.</p>
+    #[allow(unused_variables, warnings)]
+    async fn update_hosted_zone_comment(
+        &self,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> Result<UpdateHostedZoneCommentResponse, RusotoError<UpdateHostedZoneCommentError>> {
+        self.update_hosted_zone_comment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the comment for a specified traffic policy version.</p>
+    #[allow(unused_variables, warnings)]
+    async fn update_traffic_policy_comment(
+        &self,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> Result<UpdateTrafficPolicyCommentResponse, RusotoError<UpdateTrafficPolicyCommentError>>
+    {
+        self.update_traffic_policy_comment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the resource record sets in a specified hosted zone that were created based on the settings in a specified traffic policy version.</p> <p>When you update a traffic policy instance, Amazon Route 53 continues to respond to DNS queries for the root resource record set name (such as example.com) while it replaces one group of resource record sets with another. Route 53 performs the following operations:</p> <ol> <li> <p>Route 53 creates a new group of resource record sets based on the specified traffic policy. This is true regardless of how significant the differences are between the existing resource record sets and the new resource record sets. </p> </li> <li> <p>When all of the new resource record sets have been created, Route 53 starts to respond to DNS queries for the root resource record set name (such as example.com) by using the new resource record sets.</p> </li> <li> <p>Route 53 deletes the old group of resource record sets that are associated with the root resource record set name.</p> </li> </ol></p>
+    #[allow(unused_variables, warnings)]
+    async fn update_traffic_policy_instance(
+        &self,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> Result<UpdateTrafficPolicyInstanceResponse, RusotoError<UpdateTrafficPolicyInstanceError>>
+    {
+        self.update_traffic_policy_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
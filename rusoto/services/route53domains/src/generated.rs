@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -895,11 +895,14 @@ pub struct ViewBillingResponse {
 
 /// Errors returned by CheckDomainAvailability
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CheckDomainAvailabilityError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CheckDomainAvailabilityError {
@@ -907,17 +910,19 @@ impl CheckDomainAvailabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(CheckDomainAvailabilityError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CheckDomainAvailabilityError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(CheckDomainAvailabilityError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CheckDomainAvailabilityError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CheckDomainAvailabilityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -929,17 +934,21 @@ impl fmt::Display for CheckDomainAvailabilityError {
         match *self {
             CheckDomainAvailabilityError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CheckDomainAvailabilityError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            CheckDomainAvailabilityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CheckDomainAvailabilityError {}
 /// Errors returned by CheckDomainTransferability
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CheckDomainTransferabilityError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CheckDomainTransferabilityError {
@@ -949,17 +958,21 @@ impl CheckDomainTransferabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(CheckDomainTransferabilityError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CheckDomainTransferabilityError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(CheckDomainTransferabilityError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CheckDomainTransferabilityError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CheckDomainTransferabilityError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -971,12 +984,14 @@ impl fmt::Display for CheckDomainTransferabilityError {
         match *self {
             CheckDomainTransferabilityError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CheckDomainTransferabilityError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            CheckDomainTransferabilityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CheckDomainTransferabilityError {}
 /// Errors returned by DeleteTagsForDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTagsForDomainError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
@@ -984,6 +999,8 @@ pub enum DeleteTagsForDomainError {
     OperationLimitExceeded(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTagsForDomainError {
@@ -991,18 +1008,25 @@ impl DeleteTagsForDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DeleteTagsForDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteTagsForDomainError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(DeleteTagsForDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTagsForDomainError::OperationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(DeleteTagsForDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        DeleteTagsForDomainError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTagsForDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1015,17 +1039,21 @@ impl fmt::Display for DeleteTagsForDomainError {
             DeleteTagsForDomainError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteTagsForDomainError::OperationLimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteTagsForDomainError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            DeleteTagsForDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTagsForDomainError {}
 /// Errors returned by DisableDomainAutoRenew
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableDomainAutoRenewError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableDomainAutoRenewError {
@@ -1033,15 +1061,19 @@ impl DisableDomainAutoRenewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DisableDomainAutoRenewError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DisableDomainAutoRenewError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(DisableDomainAutoRenewError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableDomainAutoRenewError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableDomainAutoRenewError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1053,12 +1085,14 @@ impl fmt::Display for DisableDomainAutoRenewError {
         match *self {
             DisableDomainAutoRenewError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DisableDomainAutoRenewError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            DisableDomainAutoRenewError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableDomainAutoRenewError {}
 /// Errors returned by DisableDomainTransferLock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableDomainTransferLockError {
     /// <p>The request is already in progress for the domain.</p>
     DuplicateRequest(String),
@@ -1070,6 +1104,8 @@ pub enum DisableDomainTransferLockError {
     TLDRulesViolation(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableDomainTransferLockError {
@@ -1077,32 +1113,39 @@ impl DisableDomainTransferLockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableDomainTransferLockError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableDomainTransferLockError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableDomainTransferLockError::OperationLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableDomainTransferLockError::TLDRulesViolation(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableDomainTransferLockError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DisableDomainTransferLockError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1119,12 +1162,14 @@ impl fmt::Display for DisableDomainTransferLockError {
             }
             DisableDomainTransferLockError::TLDRulesViolation(ref cause) => write!(f, "{}", cause),
             DisableDomainTransferLockError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            DisableDomainTransferLockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableDomainTransferLockError {}
 /// Errors returned by EnableDomainAutoRenew
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableDomainAutoRenewError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
@@ -1132,6 +1177,8 @@ pub enum EnableDomainAutoRenewError {
     TLDRulesViolation(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableDomainAutoRenewError {
@@ -1139,20 +1186,25 @@ impl EnableDomainAutoRenewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(EnableDomainAutoRenewError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        EnableDomainAutoRenewError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(EnableDomainAutoRenewError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableDomainAutoRenewError::TLDRulesViolation(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(EnableDomainAutoRenewError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableDomainAutoRenewError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableDomainAutoRenewError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1165,12 +1217,14 @@ impl fmt::Display for EnableDomainAutoRenewError {
             EnableDomainAutoRenewError::InvalidInput(ref cause) => write!(f, "{}", cause),
             EnableDomainAutoRenewError::TLDRulesViolation(ref cause) => write!(f, "{}", cause),
             EnableDomainAutoRenewError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            EnableDomainAutoRenewError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableDomainAutoRenewError {}
 /// Errors returned by EnableDomainTransferLock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableDomainTransferLockError {
     /// <p>The request is already in progress for the domain.</p>
     DuplicateRequest(String),
@@ -1182,6 +1236,8 @@ pub enum EnableDomainTransferLockError {
     TLDRulesViolation(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableDomainTransferLockError {
@@ -1189,32 +1245,37 @@ impl EnableDomainTransferLockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableDomainTransferLockError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableDomainTransferLockError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableDomainTransferLockError::OperationLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableDomainTransferLockError::TLDRulesViolation(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableDomainTransferLockError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableDomainTransferLockError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1231,12 +1292,14 @@ impl fmt::Display for EnableDomainTransferLockError {
             }
             EnableDomainTransferLockError::TLDRulesViolation(ref cause) => write!(f, "{}", cause),
             EnableDomainTransferLockError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            EnableDomainTransferLockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableDomainTransferLockError {}
 /// Errors returned by GetContactReachabilityStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetContactReachabilityStatusError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
@@ -1244,6 +1307,8 @@ pub enum GetContactReachabilityStatusError {
     OperationLimitExceeded(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetContactReachabilityStatusError {
@@ -1253,22 +1318,30 @@ impl GetContactReachabilityStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetContactReachabilityStatusError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContactReachabilityStatusError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetContactReachabilityStatusError::OperationLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(GetContactReachabilityStatusError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContactReachabilityStatusError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetContactReachabilityStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1283,17 +1356,21 @@ impl fmt::Display for GetContactReachabilityStatusError {
                 write!(f, "{}", cause)
             }
             GetContactReachabilityStatusError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            GetContactReachabilityStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetContactReachabilityStatusError {}
 /// Errors returned by GetDomainDetail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainDetailError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainDetailError {
@@ -1301,13 +1378,16 @@ impl GetDomainDetailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetDomainDetailError::InvalidInput(err.msg))
+                    return RusotoError::service(GetDomainDetailError::InvalidInput(err.msg), &res)
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(GetDomainDetailError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        GetDomainDetailError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDomainDetailError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1319,17 +1399,21 @@ impl fmt::Display for GetDomainDetailError {
         match *self {
             GetDomainDetailError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetDomainDetailError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            GetDomainDetailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainDetailError {}
 /// Errors returned by GetDomainSuggestions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainSuggestionsError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainSuggestionsError {
@@ -1337,13 +1421,19 @@ impl GetDomainSuggestionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetDomainSuggestionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetDomainSuggestionsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(GetDomainSuggestionsError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        GetDomainSuggestionsError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDomainSuggestionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1355,15 +1445,19 @@ impl fmt::Display for GetDomainSuggestionsError {
         match *self {
             GetDomainSuggestionsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetDomainSuggestionsError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            GetDomainSuggestionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainSuggestionsError {}
 /// Errors returned by GetOperationDetail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOperationDetailError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOperationDetailError {
@@ -1371,10 +1465,13 @@ impl GetOperationDetailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetOperationDetailError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetOperationDetailError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOperationDetailError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1385,15 +1482,19 @@ impl fmt::Display for GetOperationDetailError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetOperationDetailError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            GetOperationDetailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOperationDetailError {}
 /// Errors returned by ListDomains
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDomainsError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDomainsError {
@@ -1401,10 +1502,10 @@ impl ListDomainsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListDomainsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListDomainsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDomainsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1415,15 +1516,19 @@ impl fmt::Display for ListDomainsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDomainsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListDomainsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDomainsError {}
 /// Errors returned by ListOperations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOperationsError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOperationsError {
@@ -1431,10 +1536,10 @@ impl ListOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListOperationsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListOperationsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOperationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1445,12 +1550,14 @@ impl fmt::Display for ListOperationsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListOperationsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListOperationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOperationsError {}
 /// Errors returned by ListTagsForDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForDomainError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
@@ -1458,6 +1565,8 @@ pub enum ListTagsForDomainError {
     OperationLimitExceeded(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForDomainError {
@@ -1465,18 +1574,25 @@ impl ListTagsForDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListTagsForDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListTagsForDomainError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(ListTagsForDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForDomainError::OperationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(ListTagsForDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        ListTagsForDomainError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1489,12 +1605,14 @@ impl fmt::Display for ListTagsForDomainError {
             ListTagsForDomainError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListTagsForDomainError::OperationLimitExceeded(ref cause) => write!(f, "{}", cause),
             ListTagsForDomainError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            ListTagsForDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForDomainError {}
 /// Errors returned by RegisterDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterDomainError {
     /// <p>The number of domains has exceeded the allowed threshold for the account.</p>
     DomainLimitExceeded(String),
@@ -1508,6 +1626,8 @@ pub enum RegisterDomainError {
     TLDRulesViolation(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterDomainError {
@@ -1515,27 +1635,37 @@ impl RegisterDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainLimitExceeded" => {
-                    return RusotoError::Service(RegisterDomainError::DomainLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        RegisterDomainError::DomainLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateRequest" => {
-                    return RusotoError::Service(RegisterDomainError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        RegisterDomainError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(RegisterDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(RegisterDomainError::InvalidInput(err.msg), &res)
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(RegisterDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterDomainError::OperationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(RegisterDomainError::TLDRulesViolation(err.msg))
+                    return RusotoError::service(
+                        RegisterDomainError::TLDRulesViolation(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(RegisterDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(RegisterDomainError::UnsupportedTLD(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1551,12 +1681,14 @@ impl fmt::Display for RegisterDomainError {
             RegisterDomainError::OperationLimitExceeded(ref cause) => write!(f, "{}", cause),
             RegisterDomainError::TLDRulesViolation(ref cause) => write!(f, "{}", cause),
             RegisterDomainError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            RegisterDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterDomainError {}
 /// Errors returned by RenewDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RenewDomainError {
     /// <p>The request is already in progress for the domain.</p>
     DuplicateRequest(String),
@@ -1568,6 +1700,8 @@ pub enum RenewDomainError {
     TLDRulesViolation(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RenewDomainError {
@@ -1575,22 +1709,25 @@ impl RenewDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(RenewDomainError::DuplicateRequest(err.msg))
+                    return RusotoError::service(RenewDomainError::DuplicateRequest(err.msg), &res)
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(RenewDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(RenewDomainError::InvalidInput(err.msg), &res)
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(RenewDomainError::OperationLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        RenewDomainError::OperationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(RenewDomainError::TLDRulesViolation(err.msg))
+                    return RusotoError::service(RenewDomainError::TLDRulesViolation(err.msg), &res)
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(RenewDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(RenewDomainError::UnsupportedTLD(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RenewDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1605,12 +1742,14 @@ impl fmt::Display for RenewDomainError {
             RenewDomainError::OperationLimitExceeded(ref cause) => write!(f, "{}", cause),
             RenewDomainError::TLDRulesViolation(ref cause) => write!(f, "{}", cause),
             RenewDomainError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            RenewDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RenewDomainError {}
 /// Errors returned by ResendContactReachabilityEmail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResendContactReachabilityEmailError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
@@ -1618,6 +1757,8 @@ pub enum ResendContactReachabilityEmailError {
     OperationLimitExceeded(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResendContactReachabilityEmailError {
@@ -1627,22 +1768,30 @@ impl ResendContactReachabilityEmailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ResendContactReachabilityEmailError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendContactReachabilityEmailError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ResendContactReachabilityEmailError::OperationLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ResendContactReachabilityEmailError::UnsupportedTLD(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ResendContactReachabilityEmailError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1659,17 +1808,23 @@ impl fmt::Display for ResendContactReachabilityEmailError {
             ResendContactReachabilityEmailError::UnsupportedTLD(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ResendContactReachabilityEmailError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ResendContactReachabilityEmailError {}
 /// Errors returned by RetrieveDomainAuthCode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RetrieveDomainAuthCodeError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RetrieveDomainAuthCodeError {
@@ -1677,15 +1832,19 @@ impl RetrieveDomainAuthCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(RetrieveDomainAuthCodeError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        RetrieveDomainAuthCodeError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(RetrieveDomainAuthCodeError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RetrieveDomainAuthCodeError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RetrieveDomainAuthCodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1697,12 +1856,14 @@ impl fmt::Display for RetrieveDomainAuthCodeError {
         match *self {
             RetrieveDomainAuthCodeError::InvalidInput(ref cause) => write!(f, "{}", cause),
             RetrieveDomainAuthCodeError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            RetrieveDomainAuthCodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RetrieveDomainAuthCodeError {}
 /// Errors returned by TransferDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TransferDomainError {
     /// <p>The number of domains has exceeded the allowed threshold for the account.</p>
     DomainLimitExceeded(String),
@@ -1716,6 +1877,8 @@ pub enum TransferDomainError {
     TLDRulesViolation(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TransferDomainError {
@@ -1723,27 +1886,37 @@ impl TransferDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainLimitExceeded" => {
-                    return RusotoError::Service(TransferDomainError::DomainLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        TransferDomainError::DomainLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateRequest" => {
-                    return RusotoError::Service(TransferDomainError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        TransferDomainError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(TransferDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(TransferDomainError::InvalidInput(err.msg), &res)
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(TransferDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TransferDomainError::OperationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(TransferDomainError::TLDRulesViolation(err.msg))
+                    return RusotoError::service(
+                        TransferDomainError::TLDRulesViolation(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(TransferDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(TransferDomainError::UnsupportedTLD(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TransferDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1759,12 +1932,14 @@ impl fmt::Display for TransferDomainError {
             TransferDomainError::OperationLimitExceeded(ref cause) => write!(f, "{}", cause),
             TransferDomainError::TLDRulesViolation(ref cause) => write!(f, "{}", cause),
             TransferDomainError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            TransferDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TransferDomainError {}
 /// Errors returned by UpdateDomainContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDomainContactError {
     /// <p>The request is already in progress for the domain.</p>
     DuplicateRequest(String),
@@ -1776,6 +1951,8 @@ pub enum UpdateDomainContactError {
     TLDRulesViolation(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDomainContactError {
@@ -1783,28 +1960,37 @@ impl UpdateDomainContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateDomainContactError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainContactError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateDomainContactError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainContactError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(UpdateDomainContactError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainContactError::OperationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(UpdateDomainContactError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainContactError::TLDRulesViolation(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateDomainContactError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainContactError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDomainContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1819,12 +2005,14 @@ impl fmt::Display for UpdateDomainContactError {
             UpdateDomainContactError::OperationLimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateDomainContactError::TLDRulesViolation(ref cause) => write!(f, "{}", cause),
             UpdateDomainContactError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            UpdateDomainContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDomainContactError {}
 /// Errors returned by UpdateDomainContactPrivacy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDomainContactPrivacyError {
     /// <p>The request is already in progress for the domain.</p>
     DuplicateRequest(String),
@@ -1836,6 +2024,8 @@ pub enum UpdateDomainContactPrivacyError {
     TLDRulesViolation(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDomainContactPrivacyError {
@@ -1845,32 +2035,39 @@ impl UpdateDomainContactPrivacyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateDomainContactPrivacyError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainContactPrivacyError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateDomainContactPrivacyError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainContactPrivacyError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDomainContactPrivacyError::OperationLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDomainContactPrivacyError::TLDRulesViolation(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateDomainContactPrivacyError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainContactPrivacyError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateDomainContactPrivacyError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1887,12 +2084,14 @@ impl fmt::Display for UpdateDomainContactPrivacyError {
             }
             UpdateDomainContactPrivacyError::TLDRulesViolation(ref cause) => write!(f, "{}", cause),
             UpdateDomainContactPrivacyError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            UpdateDomainContactPrivacyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDomainContactPrivacyError {}
 /// Errors returned by UpdateDomainNameservers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDomainNameserversError {
     /// <p>The request is already in progress for the domain.</p>
     DuplicateRequest(String),
@@ -1904,6 +2103,8 @@ pub enum UpdateDomainNameserversError {
     TLDRulesViolation(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDomainNameserversError {
@@ -1911,32 +2112,37 @@ impl UpdateDomainNameserversError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainNameserversError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainNameserversError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDomainNameserversError::OperationLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainNameserversError::TLDRulesViolation(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainNameserversError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDomainNameserversError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1953,12 +2159,14 @@ impl fmt::Display for UpdateDomainNameserversError {
             }
             UpdateDomainNameserversError::TLDRulesViolation(ref cause) => write!(f, "{}", cause),
             UpdateDomainNameserversError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            UpdateDomainNameserversError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDomainNameserversError {}
 /// Errors returned by UpdateTagsForDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTagsForDomainError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
@@ -1966,6 +2174,8 @@ pub enum UpdateTagsForDomainError {
     OperationLimitExceeded(String),
     /// <p>Amazon Route 53 does not support this top-level domain (TLD).</p>
     UnsupportedTLD(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTagsForDomainError {
@@ -1973,18 +2183,25 @@ impl UpdateTagsForDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateTagsForDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateTagsForDomainError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(UpdateTagsForDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTagsForDomainError::OperationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateTagsForDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        UpdateTagsForDomainError::UnsupportedTLD(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTagsForDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1997,15 +2214,19 @@ impl fmt::Display for UpdateTagsForDomainError {
             UpdateTagsForDomainError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateTagsForDomainError::OperationLimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateTagsForDomainError::UnsupportedTLD(ref cause) => write!(f, "{}", cause),
+            UpdateTagsForDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTagsForDomainError {}
 /// Errors returned by ViewBilling
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ViewBillingError {
     /// <p>The requested item is not acceptable. For example, for an OperationId it might refer to the ID of an operation that is already completed. For a domain name, it might not be a valid domain name or belong to the requester account.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ViewBillingError {
@@ -2013,10 +2234,10 @@ impl ViewBillingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ViewBillingError::InvalidInput(err.msg))
+                    return RusotoError::service(ViewBillingError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ViewBillingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2027,6 +2248,7 @@ impl fmt::Display for ViewBillingError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ViewBillingError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ViewBillingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2192,6 +2414,7 @@ impl Route53DomainsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> Route53DomainsClient {
         Route53DomainsClient {
             client: Client::shared(),
@@ -2217,16 +2440,73 @@ impl Route53DomainsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> Route53DomainsClient {
         Route53DomainsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<Route53DomainsClient> {
+        rusoto_core::ClientBuilder::new(Route53DomainsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> Route53DomainsClient {
+        Route53DomainsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Route53Domains for Route53DomainsClient {
-    /// <p>This operation checks the availability of one domain name. Note that if the availability status of a domain is pending, you must submit another request to determine the availability of the domain name.</p>
-    async fn check_domain_availability(
+impl Route53DomainsClient {
+    /// Calls `check_domain_availability`, also returning the metadata of the response.
+    pub async fn check_domain_availability_with_metadata(
         &self,
         input: CheckDomainAvailabilityRequest,
-    ) -> Result<CheckDomainAvailabilityResponse, RusotoError<CheckDomainAvailabilityError>> {
+    ) -> Result<
+        (CheckDomainAvailabilityResponse, ResponseMetadata),
+        RusotoError<CheckDomainAvailabilityError>,
+    > {
+        let request = self.build_check_domain_availability_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CheckDomainAvailabilityResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CheckDomainAvailabilityError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `check_domain_availability` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_check_domain_availability(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CheckDomainAvailabilityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_check_domain_availability_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_check_domain_availability_request(
+        &self,
+        input: CheckDomainAvailabilityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("CheckDomainAvailability");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2236,29 +2516,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `check_domain_transferability`, also returning the metadata of the response.
+    pub async fn check_domain_transferability_with_metadata(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> Result<
+        (CheckDomainTransferabilityResponse, ResponseMetadata),
+        RusotoError<CheckDomainTransferabilityError>,
+    > {
+        let request = self.build_check_domain_transferability_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CheckDomainAvailabilityResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CheckDomainTransferabilityResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CheckDomainAvailabilityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CheckDomainTransferabilityError::from_response(response))
         }
     }
 
-    /// <p>Checks whether a domain name can be transferred to Amazon Route 53. </p>
-    async fn check_domain_transferability(
+    /// Presigns the request `check_domain_transferability` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_check_domain_transferability(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CheckDomainTransferabilityRequest,
-    ) -> Result<CheckDomainTransferabilityResponse, RusotoError<CheckDomainTransferabilityError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_check_domain_transferability_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_check_domain_transferability_request(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("CheckDomainTransferability");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2268,28 +2577,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_tags_for_domain`, also returning the metadata of the response.
+    pub async fn delete_tags_for_domain_with_metadata(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> Result<
+        (DeleteTagsForDomainResponse, ResponseMetadata),
+        RusotoError<DeleteTagsForDomainError>,
+    > {
+        let request = self.build_delete_tags_for_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CheckDomainTransferabilityResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTagsForDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CheckDomainTransferabilityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTagsForDomainError::from_response(response))
         }
     }
 
-    /// <p>This operation deletes the specified tags for a domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
-    async fn delete_tags_for_domain(
+    /// Presigns the request `delete_tags_for_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tags_for_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTagsForDomainRequest,
-    ) -> Result<DeleteTagsForDomainResponse, RusotoError<DeleteTagsForDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tags_for_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tags_for_domain_request(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("DeleteTagsForDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2299,28 +2638,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_domain_auto_renew`, also returning the metadata of the response.
+    pub async fn disable_domain_auto_renew_with_metadata(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> Result<
+        (DisableDomainAutoRenewResponse, ResponseMetadata),
+        RusotoError<DisableDomainAutoRenewError>,
+    > {
+        let request = self.build_disable_domain_auto_renew_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteTagsForDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableDomainAutoRenewResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTagsForDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableDomainAutoRenewError::from_response(response))
         }
     }
 
-    /// <p>This operation disables automatic renewal of domain registration for the specified domain.</p>
-    async fn disable_domain_auto_renew(
+    /// Presigns the request `disable_domain_auto_renew` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_domain_auto_renew(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableDomainAutoRenewRequest,
-    ) -> Result<DisableDomainAutoRenewResponse, RusotoError<DisableDomainAutoRenewError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_domain_auto_renew_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_domain_auto_renew_request(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("DisableDomainAutoRenew");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2330,29 +2699,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_domain_transfer_lock`, also returning the metadata of the response.
+    pub async fn disable_domain_transfer_lock_with_metadata(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> Result<
+        (DisableDomainTransferLockResponse, ResponseMetadata),
+        RusotoError<DisableDomainTransferLockError>,
+    > {
+        let request = self.build_disable_domain_transfer_lock_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisableDomainAutoRenewResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableDomainTransferLockResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableDomainAutoRenewError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableDomainTransferLockError::from_response(response))
         }
     }
 
-    /// <p>This operation removes the transfer lock on the domain (specifically the <code>clientTransferProhibited</code> status) to allow domain transfers. We recommend you refrain from performing this action unless you intend to transfer the domain to a different registrar. Successful submission returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
-    async fn disable_domain_transfer_lock(
+    /// Presigns the request `disable_domain_transfer_lock` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_domain_transfer_lock(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableDomainTransferLockRequest,
-    ) -> Result<DisableDomainTransferLockResponse, RusotoError<DisableDomainTransferLockError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_domain_transfer_lock_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_domain_transfer_lock_request(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("DisableDomainTransferLock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2362,28 +2760,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_domain_auto_renew`, also returning the metadata of the response.
+    pub async fn enable_domain_auto_renew_with_metadata(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> Result<
+        (EnableDomainAutoRenewResponse, ResponseMetadata),
+        RusotoError<EnableDomainAutoRenewError>,
+    > {
+        let request = self.build_enable_domain_auto_renew_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisableDomainTransferLockResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnableDomainAutoRenewResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableDomainTransferLockError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableDomainAutoRenewError::from_response(response))
         }
     }
 
-    /// <p>This operation configures Amazon Route 53 to automatically renew the specified domain before the domain registration expires. The cost of renewing your domain registration is billed to your AWS account.</p> <p>The period during which you can renew a domain name varies by TLD. For a list of TLDs and their renewal policies, see <a href="http://wiki.gandi.net/en/domains/renew#renewal_restoration_and_deletion_times">"Renewal, restoration, and deletion times"</a> on the website for our registrar associate, Gandi. Amazon Route 53 requires that you renew before the end of the renewal period that is listed on the Gandi website so we can complete processing before the deadline.</p>
-    async fn enable_domain_auto_renew(
+    /// Presigns the request `enable_domain_auto_renew` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_domain_auto_renew(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableDomainAutoRenewRequest,
-    ) -> Result<EnableDomainAutoRenewResponse, RusotoError<EnableDomainAutoRenewError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_domain_auto_renew_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_domain_auto_renew_request(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("EnableDomainAutoRenew");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2393,28 +2821,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_domain_transfer_lock`, also returning the metadata of the response.
+    pub async fn enable_domain_transfer_lock_with_metadata(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> Result<
+        (EnableDomainTransferLockResponse, ResponseMetadata),
+        RusotoError<EnableDomainTransferLockError>,
+    > {
+        let request = self.build_enable_domain_transfer_lock_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<EnableDomainAutoRenewResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnableDomainTransferLockResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableDomainAutoRenewError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableDomainTransferLockError::from_response(response))
         }
     }
 
-    /// <p>This operation sets the transfer lock on the domain (specifically the <code>clientTransferProhibited</code> status) to prevent domain transfers. Successful submission returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
-    async fn enable_domain_transfer_lock(
+    /// Presigns the request `enable_domain_transfer_lock` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_domain_transfer_lock(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableDomainTransferLockRequest,
-    ) -> Result<EnableDomainTransferLockResponse, RusotoError<EnableDomainTransferLockError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_domain_transfer_lock_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_domain_transfer_lock_request(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("EnableDomainTransferLock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2424,29 +2882,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_contact_reachability_status`, also returning the metadata of the response.
+    pub async fn get_contact_reachability_status_with_metadata(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> Result<
+        (GetContactReachabilityStatusResponse, ResponseMetadata),
+        RusotoError<GetContactReachabilityStatusError>,
+    > {
+        let request = self.build_get_contact_reachability_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<EnableDomainTransferLockResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetContactReachabilityStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableDomainTransferLockError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetContactReachabilityStatusError::from_response(response))
         }
     }
 
-    /// <p>For operations that require confirmation that the email address for the registrant contact is valid, such as registering a new domain, this operation returns information about whether the registrant contact has responded.</p> <p>If you want us to resend the email, use the <code>ResendContactReachabilityEmail</code> operation.</p>
-    async fn get_contact_reachability_status(
+    /// Presigns the request `get_contact_reachability_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_contact_reachability_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetContactReachabilityStatusRequest,
-    ) -> Result<GetContactReachabilityStatusResponse, RusotoError<GetContactReachabilityStatusError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_contact_reachability_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_contact_reachability_status_request(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetContactReachabilityStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2456,55 +2943,111 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_domain_detail`, also returning the metadata of the response.
+    pub async fn get_domain_detail_with_metadata(
+        &self,
+        input: GetDomainDetailRequest,
+    ) -> Result<(GetDomainDetailResponse, ResponseMetadata), RusotoError<GetDomainDetailError>>
+    {
+        let request = self.build_get_domain_detail_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetContactReachabilityStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDomainDetailResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetContactReachabilityStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDomainDetailError::from_response(response))
         }
     }
 
-    /// <p>This operation returns detailed information about a specified domain that is associated with the current AWS account. Contact information for the domain is also returned as part of the output.</p>
-    async fn get_domain_detail(
+    /// Presigns the request `get_domain_detail` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain_detail(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainDetailRequest,
-    ) -> Result<GetDomainDetailResponse, RusotoError<GetDomainDetailError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_detail_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_detail_request(&self, input: GetDomainDetailRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetDomainDetail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.GetDomainDetail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_domain_suggestions`, also returning the metadata of the response.
+    pub async fn get_domain_suggestions_with_metadata(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> Result<
+        (GetDomainSuggestionsResponse, ResponseMetadata),
+        RusotoError<GetDomainSuggestionsError>,
+    > {
+        let request = self.build_get_domain_suggestions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDomainDetailResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDomainSuggestionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainDetailError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDomainSuggestionsError::from_response(response))
         }
     }
 
-    /// <p>The GetDomainSuggestions operation returns a list of suggested domain names given a string, which can either be a domain name or simply a word or phrase (without spaces).</p>
-    async fn get_domain_suggestions(
+    /// Presigns the request `get_domain_suggestions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain_suggestions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainSuggestionsRequest,
-    ) -> Result<GetDomainSuggestionsResponse, RusotoError<GetDomainSuggestionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_suggestions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_suggestions_request(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetDomainSuggestions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2514,28 +3057,56 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_operation_detail`, also returning the metadata of the response.
+    pub async fn get_operation_detail_with_metadata(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> Result<(GetOperationDetailResponse, ResponseMetadata), RusotoError<GetOperationDetailError>>
+    {
+        let request = self.build_get_operation_detail_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDomainSuggestionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOperationDetailResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainSuggestionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOperationDetailError::from_response(response))
         }
     }
 
-    /// <p>This operation returns the current status of an operation that is not completed.</p>
-    async fn get_operation_detail(
+    /// Presigns the request `get_operation_detail` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_operation_detail(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOperationDetailRequest,
-    ) -> Result<GetOperationDetailResponse, RusotoError<GetOperationDetailError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_operation_detail_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_operation_detail_request(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetOperationDetail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2545,167 +3116,319 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `list_domains`, also returning the metadata of the response.
+    pub async fn list_domains_with_metadata(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<(ListDomainsResponse, ResponseMetadata), RusotoError<ListDomainsError>> {
+        let request = self.build_list_domains_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetOperationDetailResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDomainsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOperationDetailError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDomainsError::from_response(response))
         }
     }
 
-    /// <p>This operation returns all the domain names registered with Amazon Route 53 for the current AWS account.</p>
-    async fn list_domains(
+    /// Presigns the request `list_domains` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_domains(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDomainsRequest,
-    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_domains_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_domains_request(&self, input: ListDomainsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ListDomains");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_operations`, also returning the metadata of the response.
+    pub async fn list_operations_with_metadata(
+        &self,
+        input: ListOperationsRequest,
+    ) -> Result<(ListOperationsResponse, ResponseMetadata), RusotoError<ListOperationsError>> {
+        let request = self.build_list_operations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDomainsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListOperationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDomainsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListOperationsError::from_response(response))
         }
     }
 
-    /// <p>This operation returns the operation IDs of operations that are not yet complete.</p>
-    async fn list_operations(
+    /// Presigns the request `list_operations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_operations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOperationsRequest,
-    ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_operations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_operations_request(&self, input: ListOperationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ListOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListOperations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_domain`, also returning the metadata of the response.
+    pub async fn list_tags_for_domain_with_metadata(
+        &self,
+        input: ListTagsForDomainRequest,
+    ) -> Result<(ListTagsForDomainResponse, ResponseMetadata), RusotoError<ListTagsForDomainError>>
+    {
+        let request = self.build_list_tags_for_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListOperationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOperationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForDomainError::from_response(response))
         }
     }
 
-    /// <p>This operation returns all of the tags that are associated with the specified domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
-    async fn list_tags_for_domain(
+    /// Presigns the request `list_tags_for_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForDomainRequest,
-    ) -> Result<ListTagsForDomainResponse, RusotoError<ListTagsForDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_domain_request(&self, input: ListTagsForDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ListTagsForDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListTagsForDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_domain`, also returning the metadata of the response.
+    pub async fn register_domain_with_metadata(
+        &self,
+        input: RegisterDomainRequest,
+    ) -> Result<(RegisterDomainResponse, ResponseMetadata), RusotoError<RegisterDomainError>> {
+        let request = self.build_register_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterDomainError::from_response(response))
         }
     }
 
-    /// <p><p>This operation registers a domain. Domains are registered either by Amazon Registrar (for .com, .net, and .org domains) or by our registrar associate, Gandi (for all other domains). For some top-level domains (TLDs), this operation requires extra parameters.</p> <p>When you register a domain, Amazon Route 53 does the following:</p> <ul> <li> <p>Creates a Amazon Route 53 hosted zone that has the same name as the domain. Amazon Route 53 assigns four name servers to your hosted zone and automatically updates your domain registration with the names of these name servers.</p> </li> <li> <p>Enables autorenew, so your domain registration will renew automatically each year. We&#39;ll notify you in advance of the renewal date so you can choose whether to renew the registration.</p> </li> <li> <p>Optionally enables privacy protection, so WHOIS queries return contact information either for Amazon Registrar (for .com, .net, and .org domains) or for our registrar associate, Gandi (for all other TLDs). If you don&#39;t enable privacy protection, WHOIS queries return the information that you entered for the registrant, admin, and tech contacts.</p> </li> <li> <p>If registration is successful, returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant is notified by email.</p> </li> <li> <p>Charges your AWS account an amount based on the top-level domain. For more information, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p> </li> </ul></p>
-    async fn register_domain(
+    /// Presigns the request `register_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterDomainRequest,
-    ) -> Result<RegisterDomainResponse, RusotoError<RegisterDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_domain_request(&self, input: RegisterDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("RegisterDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.RegisterDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `renew_domain`, also returning the metadata of the response.
+    pub async fn renew_domain_with_metadata(
+        &self,
+        input: RenewDomainRequest,
+    ) -> Result<(RenewDomainResponse, ResponseMetadata), RusotoError<RenewDomainError>> {
+        let request = self.build_renew_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RegisterDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RenewDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RenewDomainError::from_response(response))
         }
     }
 
-    /// <p>This operation renews a domain for the specified number of years. The cost of renewing your domain is billed to your AWS account.</p> <p>We recommend that you renew your domain several weeks before the expiration date. Some TLD registries delete domains before the expiration date if you haven't renewed far enough in advance. For more information about renewing domain registration, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/domain-renew.html">Renewing Registration for a Domain</a> in the Amazon Route 53 Developer Guide.</p>
-    async fn renew_domain(
+    /// Presigns the request `renew_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_renew_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RenewDomainRequest,
-    ) -> Result<RenewDomainResponse, RusotoError<RenewDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_renew_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_renew_domain_request(&self, input: RenewDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("RenewDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.RenewDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `resend_contact_reachability_email`, also returning the metadata of the response.
+    pub async fn resend_contact_reachability_email_with_metadata(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> Result<
+        (ResendContactReachabilityEmailResponse, ResponseMetadata),
+        RusotoError<ResendContactReachabilityEmailError>,
+    > {
+        let request = self.build_resend_contact_reachability_email_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RenewDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResendContactReachabilityEmailResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RenewDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResendContactReachabilityEmailError::from_response(response))
         }
     }
 
-    /// <p>For operations that require confirmation that the email address for the registrant contact is valid, such as registering a new domain, this operation resends the confirmation email to the current email address for the registrant contact.</p>
-    async fn resend_contact_reachability_email(
+    /// Presigns the request `resend_contact_reachability_email` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resend_contact_reachability_email(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResendContactReachabilityEmailRequest,
-    ) -> Result<
-        ResendContactReachabilityEmailResponse,
-        RusotoError<ResendContactReachabilityEmailError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resend_contact_reachability_email_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resend_contact_reachability_email_request(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ResendContactReachabilityEmail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2715,28 +3438,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `retrieve_domain_auth_code`, also returning the metadata of the response.
+    pub async fn retrieve_domain_auth_code_with_metadata(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> Result<
+        (RetrieveDomainAuthCodeResponse, ResponseMetadata),
+        RusotoError<RetrieveDomainAuthCodeError>,
+    > {
+        let request = self.build_retrieve_domain_auth_code_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ResendContactReachabilityEmailResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RetrieveDomainAuthCodeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResendContactReachabilityEmailError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RetrieveDomainAuthCodeError::from_response(response))
         }
     }
 
-    /// <p>This operation returns the AuthCode for the domain. To transfer a domain to another registrar, you provide this value to the new registrar.</p>
-    async fn retrieve_domain_auth_code(
+    /// Presigns the request `retrieve_domain_auth_code` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_retrieve_domain_auth_code(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RetrieveDomainAuthCodeRequest,
-    ) -> Result<RetrieveDomainAuthCodeResponse, RusotoError<RetrieveDomainAuthCodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_retrieve_domain_auth_code_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_retrieve_domain_auth_code_request(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("RetrieveDomainAuthCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2746,55 +3499,110 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `transfer_domain`, also returning the metadata of the response.
+    pub async fn transfer_domain_with_metadata(
+        &self,
+        input: TransferDomainRequest,
+    ) -> Result<(TransferDomainResponse, ResponseMetadata), RusotoError<TransferDomainError>> {
+        let request = self.build_transfer_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RetrieveDomainAuthCodeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TransferDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RetrieveDomainAuthCodeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TransferDomainError::from_response(response))
         }
     }
 
-    /// <p>This operation transfers a domain from another registrar to Amazon Route 53. When the transfer is complete, the domain is registered either with Amazon Registrar (for .com, .net, and .org domains) or with our registrar associate, Gandi (for all other TLDs).</p> <p>For transfer requirements, a detailed procedure, and information about viewing the status of a domain transfer, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/domain-transfer-to-route-53.html">Transferring Registration for a Domain to Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>If the registrar for your domain is also the DNS service provider for the domain, we highly recommend that you consider transferring your DNS service to Amazon Route 53 or to another DNS service provider before you transfer your registration. Some registrars provide free DNS service when you purchase a domain registration. When you transfer the registration, the previous registrar will not renew your domain registration and could end your DNS service at any time.</p> <important> <p>If the registrar for your domain is also the DNS service provider for the domain and you don't transfer DNS service to another provider, your website, email, and the web applications associated with the domain might become unavailable.</p> </important> <p>If the transfer is successful, this method returns an operation ID that you can use to track the progress and completion of the action. If the transfer doesn't complete successfully, the domain registrant will be notified by email.</p>
-    async fn transfer_domain(
+    /// Presigns the request `transfer_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_transfer_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TransferDomainRequest,
-    ) -> Result<TransferDomainResponse, RusotoError<TransferDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_transfer_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_transfer_domain_request(&self, input: TransferDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("TransferDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.TransferDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_domain_contact`, also returning the metadata of the response.
+    pub async fn update_domain_contact_with_metadata(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> Result<
+        (UpdateDomainContactResponse, ResponseMetadata),
+        RusotoError<UpdateDomainContactError>,
+    > {
+        let request = self.build_update_domain_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TransferDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDomainContactResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TransferDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDomainContactError::from_response(response))
         }
     }
 
-    /// <p>This operation updates the contact information for a particular domain. You must specify information for at least one contact: registrant, administrator, or technical.</p> <p>If the update is successful, this method returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
-    async fn update_domain_contact(
+    /// Presigns the request `update_domain_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_domain_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDomainContactRequest,
-    ) -> Result<UpdateDomainContactResponse, RusotoError<UpdateDomainContactError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_domain_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_domain_contact_request(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateDomainContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2804,29 +3612,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_domain_contact_privacy`, also returning the metadata of the response.
+    pub async fn update_domain_contact_privacy_with_metadata(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> Result<
+        (UpdateDomainContactPrivacyResponse, ResponseMetadata),
+        RusotoError<UpdateDomainContactPrivacyError>,
+    > {
+        let request = self.build_update_domain_contact_privacy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDomainContactResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDomainContactPrivacyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDomainContactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDomainContactPrivacyError::from_response(response))
         }
     }
 
-    /// <p>This operation updates the specified domain contact's privacy setting. When privacy protection is enabled, contact information such as email address is replaced either with contact information for Amazon Registrar (for .com, .net, and .org domains) or with contact information for our registrar associate, Gandi.</p> <p>This operation affects only the contact information for the specified contact type (registrant, administrator, or tech). If the request succeeds, Amazon Route 53 returns an operation ID that you can use with <a>GetOperationDetail</a> to track the progress and completion of the action. If the request doesn't complete successfully, the domain registrant will be notified by email.</p>
-    async fn update_domain_contact_privacy(
+    /// Presigns the request `update_domain_contact_privacy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_domain_contact_privacy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDomainContactPrivacyRequest,
-    ) -> Result<UpdateDomainContactPrivacyResponse, RusotoError<UpdateDomainContactPrivacyError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_domain_contact_privacy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_domain_contact_privacy_request(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateDomainContactPrivacy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2836,28 +3673,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_domain_nameservers`, also returning the metadata of the response.
+    pub async fn update_domain_nameservers_with_metadata(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> Result<
+        (UpdateDomainNameserversResponse, ResponseMetadata),
+        RusotoError<UpdateDomainNameserversError>,
+    > {
+        let request = self.build_update_domain_nameservers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDomainContactPrivacyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDomainNameserversResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDomainContactPrivacyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDomainNameserversError::from_response(response))
         }
     }
 
-    /// <p>This operation replaces the current set of name servers for the domain with the specified set of name servers. If you use Amazon Route 53 as your DNS service, specify the four name servers in the delegation set for the hosted zone for the domain.</p> <p>If successful, this operation returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
-    async fn update_domain_nameservers(
+    /// Presigns the request `update_domain_nameservers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_domain_nameservers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDomainNameserversRequest,
-    ) -> Result<UpdateDomainNameserversResponse, RusotoError<UpdateDomainNameserversError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_domain_nameservers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_domain_nameservers_request(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateDomainNameservers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2867,28 +3734,58 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_tags_for_domain`, also returning the metadata of the response.
+    pub async fn update_tags_for_domain_with_metadata(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> Result<
+        (UpdateTagsForDomainResponse, ResponseMetadata),
+        RusotoError<UpdateTagsForDomainError>,
+    > {
+        let request = self.build_update_tags_for_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDomainNameserversResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTagsForDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDomainNameserversError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTagsForDomainError::from_response(response))
         }
     }
 
-    /// <p>This operation adds or updates tags for a specified domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
-    async fn update_tags_for_domain(
+    /// Presigns the request `update_tags_for_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_tags_for_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTagsForDomainRequest,
-    ) -> Result<UpdateTagsForDomainResponse, RusotoError<UpdateTagsForDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_tags_for_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_tags_for_domain_request(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateTagsForDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2898,46 +3795,412 @@ impl Route53Domains for Route53DomainsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `view_billing`, also returning the metadata of the response.
+    pub async fn view_billing_with_metadata(
+        &self,
+        input: ViewBillingRequest,
+    ) -> Result<(ViewBillingResponse, ResponseMetadata), RusotoError<ViewBillingError>> {
+        let request = self.build_view_billing_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateTagsForDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ViewBillingResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTagsForDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ViewBillingError::from_response(response))
         }
     }
 
-    /// <p>Returns all the domain-related billing records for the current AWS account for a specified period</p>
-    async fn view_billing(
+    /// Presigns the request `view_billing` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_view_billing(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ViewBillingRequest,
-    ) -> Result<ViewBillingResponse, RusotoError<ViewBillingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_view_billing_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_view_billing_request(&self, input: ViewBillingRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ViewBilling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ViewBilling");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListDomains` results for `input`, requesting each next page with the `NextPageMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_domains_pages(
+        &self,
+        input: ListDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDomainsResponse, RusotoError<ListDomainsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53DomainsClient, input| async move { client.list_domains(input).await },
+            |input: &ListDomainsRequest, output: &ListDomainsResponse| {
+                let marker = output.next_page_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListDomainsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Domains` of every page of `ListDomains` results for `input`, as `list_domains_pages` requests them.
+    pub fn list_domains_stream(
+        &self,
+        input: ListDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<DomainSummary, RusotoError<ListDomainsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_domains_pages(input), |page| page.domains)
+    }
+
+    /// The pages of `ListOperations` results for `input`, requesting each next page with the `NextPageMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_operations_pages(
+        &self,
+        input: ListOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListOperationsResponse, RusotoError<ListOperationsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53DomainsClient, input| async move { client.list_operations(input).await },
+            |input: &ListOperationsRequest, output: &ListOperationsResponse| {
+                let marker = output.next_page_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListOperationsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Operations` of every page of `ListOperations` results for `input`, as `list_operations_pages` requests them.
+    pub fn list_operations_stream(
+        &self,
+        input: ListOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<OperationSummary, RusotoError<ListOperationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_operations_pages(input), |page| page.operations)
+    }
+
+    /// The pages of `ViewBilling` results for `input`, requesting each next page with the `NextPageMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn view_billing_pages(
+        &self,
+        input: ViewBillingRequest,
+    ) -> impl futures::Stream<Item = Result<ViewBillingResponse, RusotoError<ViewBillingError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53DomainsClient, input| async move { client.view_billing(input).await },
+            |input: &ViewBillingRequest, output: &ViewBillingResponse| {
+                let marker = output.next_page_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ViewBillingRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BillingRecords` of every page of `ViewBilling` results for `input`, as `view_billing_pages` requests them.
+    pub fn view_billing_stream(
+        &self,
+        input: ViewBillingRequest,
+    ) -> impl futures::Stream<Item = Result<BillingRecord, RusotoError<ViewBillingError>>> + Send
+    {
+        rusoto_core::pagination::items(self.view_billing_pages(input), |page| {
+            page.billing_records.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Route53Domains for Route53DomainsClient {
+    /// <p>This operation checks the availability of one domain name. Note that if the availability status of a domain is pending, you must submit another request to determine the availability of the domain name.</p>
+    async fn check_domain_availability(
+        &self,
+        input: CheckDomainAvailabilityRequest,
+    ) -> Result<CheckDomainAvailabilityResponse, RusotoError<CheckDomainAvailabilityError>> {
+        self.check_domain_availability_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ViewBillingResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ViewBillingError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Checks whether a domain name can be transferred to Amazon Route 53. </p>
+    async fn check_domain_transferability(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> Result<CheckDomainTransferabilityResponse, RusotoError<CheckDomainTransferabilityError>>
+    {
+        self.check_domain_transferability_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation deletes the specified tags for a domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
+    async fn delete_tags_for_domain(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> Result<DeleteTagsForDomainResponse, RusotoError<DeleteTagsForDomainError>> {
+        self.delete_tags_for_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation disables automatic renewal of domain registration for the specified domain.</p>
+    async fn disable_domain_auto_renew(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> Result<DisableDomainAutoRenewResponse, RusotoError<DisableDomainAutoRenewError>> {
+        self.disable_domain_auto_renew_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation removes the transfer lock on the domain (specifically the <code>clientTransferProhibited</code> status) to allow domain transfers. We recommend you refrain from performing this action unless you intend to transfer the domain to a different registrar. Successful submission returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
+    async fn disable_domain_transfer_lock(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> Result<DisableDomainTransferLockResponse, RusotoError<DisableDomainTransferLockError>>
+    {
+        self.disable_domain_transfer_lock_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation configures Amazon Route 53 to automatically renew the specified domain before the domain registration expires. The cost of renewing your domain registration is billed to your AWS account.</p> <p>The period during which you can renew a domain name varies by TLD. For a list of TLDs and their renewal policies, see <a href="http://wiki.gandi.net/en/domains/renew#renewal_restoration_and_deletion_times">"Renewal, restoration, and deletion times"</a> on the website for our registrar associate, Gandi. Amazon Route 53 requires that you renew before the end of the renewal period that is listed on the Gandi website so we can complete processing before the deadline.</p>
+    async fn enable_domain_auto_renew(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> Result<EnableDomainAutoRenewResponse, RusotoError<EnableDomainAutoRenewError>> {
+        self.enable_domain_auto_renew_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation sets the transfer lock on the domain (specifically the <code>clientTransferProhibited</code> status) to prevent domain transfers. Successful submission returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
+    async fn enable_domain_transfer_lock(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> Result<EnableDomainTransferLockResponse, RusotoError<EnableDomainTransferLockError>> {
+        self.enable_domain_transfer_lock_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For operations that require confirmation that the email address for the registrant contact is valid, such as registering a new domain, this operation returns information about whether the registrant contact has responded.</p> <p>If you want us to resend the email, use the <code>ResendContactReachabilityEmail</code> operation.</p>
+    async fn get_contact_reachability_status(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> Result<GetContactReachabilityStatusResponse, RusotoError<GetContactReachabilityStatusError>>
+    {
+        self.get_contact_reachability_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns detailed information about a specified domain that is associated with the current AWS account. Contact information for the domain is also returned as part of the output.</p>
+    async fn get_domain_detail(
+        &self,
+        input: GetDomainDetailRequest,
+    ) -> Result<GetDomainDetailResponse, RusotoError<GetDomainDetailError>> {
+        self.get_domain_detail_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The GetDomainSuggestions operation returns a list of suggested domain names given a string, which can either be a domain name or simply a word or phrase (without spaces).</p>
+    async fn get_domain_suggestions(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> Result<GetDomainSuggestionsResponse, RusotoError<GetDomainSuggestionsError>> {
+        self.get_domain_suggestions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns the current status of an operation that is not completed.</p>
+    async fn get_operation_detail(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> Result<GetOperationDetailResponse, RusotoError<GetOperationDetailError>> {
+        self.get_operation_detail_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns all the domain names registered with Amazon Route 53 for the current AWS account.</p>
+    async fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+        self.list_domains_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns the operation IDs of operations that are not yet complete.</p>
+    async fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
+        self.list_operations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns all of the tags that are associated with the specified domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
+    async fn list_tags_for_domain(
+        &self,
+        input: ListTagsForDomainRequest,
+    ) -> Result<ListTagsForDomainResponse, RusotoError<ListTagsForDomainError>> {
+        self.list_tags_for_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This operation registers a domain. Domains are registered either by Amazon Registrar (for .com, .net, and .org domains) or by our registrar associate, Gandi (for all other domains). For some top-level domains (TLDs), this operation requires extra parameters.</p> <p>When you register a domain, Amazon Route 53 does the following:</p> <ul> <li> <p>Creates a Amazon Route 53 hosted zone that has the same name as the domain. Amazon Route 53 assigns four name servers to your hosted zone and automatically updates your domain registration with the names of these name servers.</p> </li> <li> <p>Enables autorenew, so your domain registration will renew automatically each year. We&#39;ll notify you in advance of the renewal date so you can choose whether to renew the registration.</p> </li> <li> <p>Optionally enables privacy protection, so WHOIS queries return contact information either for Amazon Registrar (for .com, .net, and .org domains) or for our registrar associate, Gandi (for all other TLDs). If you don&#39;t enable privacy protection, WHOIS queries return the information that you entered for the registrant, admin, and tech contacts.</p> </li> <li> <p>If registration is successful, returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant is notified by email.</p> </li> <li> <p>Charges your AWS account an amount based on the top-level domain. For more information, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p> </li> </ul></p>
+    async fn register_domain(
+        &self,
+        input: RegisterDomainRequest,
+    ) -> Result<RegisterDomainResponse, RusotoError<RegisterDomainError>> {
+        self.register_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation renews a domain for the specified number of years. The cost of renewing your domain is billed to your AWS account.</p> <p>We recommend that you renew your domain several weeks before the expiration date. Some TLD registries delete domains before the expiration date if you haven't renewed far enough in advance. For more information about renewing domain registration, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/domain-renew.html">Renewing Registration for a Domain</a> in the Amazon Route 53 Developer Guide.</p>
+    async fn renew_domain(
+        &self,
+        input: RenewDomainRequest,
+    ) -> Result<RenewDomainResponse, RusotoError<RenewDomainError>> {
+        self.renew_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For operations that require confirmation that the email address for the registrant contact is valid, such as registering a new domain, this operation resends the confirmation email to the current email address for the registrant contact.</p>
+    async fn resend_contact_reachability_email(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> Result<
+        ResendContactReachabilityEmailResponse,
+        RusotoError<ResendContactReachabilityEmailError>,
+    > {
+        self.resend_contact_reachability_email_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns the AuthCode for the domain. To transfer a domain to another registrar, you provide this value to the new registrar.</p>
+    async fn retrieve_domain_auth_code(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> Result<RetrieveDomainAuthCodeResponse, RusotoError<RetrieveDomainAuthCodeError>> {
+        self.retrieve_domain_auth_code_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation transfers a domain from another registrar to Amazon Route 53. When the transfer is complete, the domain is registered either with Amazon Registrar (for .com, .net, and .org domains) or with our registrar associate, Gandi (for all other TLDs).</p> <p>For transfer requirements, a detailed procedure, and information about viewing the status of a domain transfer, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/domain-transfer-to-route-53.html">Transferring Registration for a Domain to Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>If the registrar for your domain is also the DNS service provider for the domain, we highly recommend that you consider transferring your DNS service to Amazon Route 53 or to another DNS service provider before you transfer your registration. Some registrars provide free DNS service when you purchase a domain registration. When you transfer the registration, the previous registrar will not renew your domain registration and could end your DNS service at any time.</p> <important> <p>If the registrar for your domain is also the DNS service provider for the domain and you don't transfer DNS service to another provider, your website, email, and the web applications associated with the domain might become unavailable.</p> </important> <p>If the transfer is successful, this method returns an operation ID that you can use to track the progress and completion of the action. If the transfer doesn't complete successfully, the domain registrant will be notified by email.</p>
+    async fn transfer_domain(
+        &self,
+        input: TransferDomainRequest,
+    ) -> Result<TransferDomainResponse, RusotoError<TransferDomainError>> {
+        self.transfer_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation updates the contact information for a particular domain. You must specify information for at least one contact: registrant, administrator, or technical.</p> <p>If the update is successful, this method returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
+    async fn update_domain_contact(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> Result<UpdateDomainContactResponse, RusotoError<UpdateDomainContactError>> {
+        self.update_domain_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation updates the specified domain contact's privacy setting. When privacy protection is enabled, contact information such as email address is replaced either with contact information for Amazon Registrar (for .com, .net, and .org domains) or with contact information for our registrar associate, Gandi.</p> <p>This operation affects only the contact information for the specified contact type (registrant, administrator, or tech). If the request succeeds, Amazon Route 53 returns an operation ID that you can use with <a>GetOperationDetail</a> to track the progress and completion of the action. If the request doesn't complete successfully, the domain registrant will be notified by email.</p>
+    async fn update_domain_contact_privacy(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> Result<UpdateDomainContactPrivacyResponse, RusotoError<UpdateDomainContactPrivacyError>>
+    {
+        self.update_domain_contact_privacy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation replaces the current set of name servers for the domain with the specified set of name servers. If you use Amazon Route 53 as your DNS service, specify the four name servers in the delegation set for the hosted zone for the domain.</p> <p>If successful, this operation returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
+    async fn update_domain_nameservers(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> Result<UpdateDomainNameserversResponse, RusotoError<UpdateDomainNameserversError>> {
+        self.update_domain_nameservers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation adds or updates tags for a specified domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
+    async fn update_tags_for_domain(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> Result<UpdateTagsForDomainResponse, RusotoError<UpdateTagsForDomainError>> {
+        self.update_tags_for_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all the domain-related billing records for the current AWS account for a specified period</p>
+    async fn view_billing(
+        &self,
+        input: ViewBillingRequest,
+    ) -> Result<ViewBillingResponse, RusotoError<ViewBillingError>> {
+        self.view_billing_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -801,6 +801,7 @@ pub struct UpdateResolverRuleResponse {
 
 /// Errors returned by AssociateResolverEndpointIpAddress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateResolverEndpointIpAddressError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -816,6 +817,8 @@ pub enum AssociateResolverEndpointIpAddressError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateResolverEndpointIpAddressError {
@@ -825,42 +828,54 @@ impl AssociateResolverEndpointIpAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResolverEndpointIpAddressError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResolverEndpointIpAddressError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResolverEndpointIpAddressError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResolverEndpointIpAddressError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResolverEndpointIpAddressError::ResourceExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResolverEndpointIpAddressError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateResolverEndpointIpAddressError::Throttling(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateResolverEndpointIpAddressError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -891,12 +906,16 @@ impl fmt::Display for AssociateResolverEndpointIpAddressError {
             AssociateResolverEndpointIpAddressError::Throttling(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateResolverEndpointIpAddressError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateResolverEndpointIpAddressError {}
 /// Errors returned by AssociateResolverRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateResolverRuleError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -912,6 +931,8 @@ pub enum AssociateResolverRuleError {
     ResourceUnavailable(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateResolverRuleError {
@@ -919,40 +940,49 @@ impl AssociateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResolverRuleError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResolverRuleError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResolverRuleError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResolverRuleError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResolverRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateResolverRuleError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(
+                        AssociateResolverRuleError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateResolverRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -969,12 +999,14 @@ impl fmt::Display for AssociateResolverRuleError {
             AssociateResolverRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AssociateResolverRuleError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             AssociateResolverRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            AssociateResolverRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateResolverRuleError {}
 /// Errors returned by CreateResolverEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResolverEndpointError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -990,6 +1022,8 @@ pub enum CreateResolverEndpointError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResolverEndpointError {
@@ -997,40 +1031,49 @@ impl CreateResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResolverEndpointError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResolverEndpointError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResolverEndpointError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResolverEndpointError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResolverEndpointError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResolverEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateResolverEndpointError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateResolverEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1047,12 +1090,14 @@ impl fmt::Display for CreateResolverEndpointError {
             CreateResolverEndpointError::ResourceExists(ref cause) => write!(f, "{}", cause),
             CreateResolverEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateResolverEndpointError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateResolverEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateResolverEndpointError {}
 /// Errors returned by CreateResolverRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResolverRuleError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1070,6 +1115,8 @@ pub enum CreateResolverRuleError {
     ResourceUnavailable(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResolverRuleError {
@@ -1077,35 +1124,52 @@ impl CreateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResolverRuleError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateResolverRuleError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateResolverRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateResolverRuleError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResolverRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateResolverRuleError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateResolverRuleError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        CreateResolverRuleError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateResolverRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateResolverRuleError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResolverRuleError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(CreateResolverRuleError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateResolverRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1123,12 +1187,14 @@ impl fmt::Display for CreateResolverRuleError {
             CreateResolverRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateResolverRuleError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateResolverRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateResolverRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateResolverRuleError {}
 /// Errors returned by DeleteResolverEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResolverEndpointError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1140,6 +1206,8 @@ pub enum DeleteResolverEndpointError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResolverEndpointError {
@@ -1147,30 +1215,37 @@ impl DeleteResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResolverEndpointError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResolverEndpointError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResolverEndpointError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResolverEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteResolverEndpointError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResolverEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1185,12 +1260,14 @@ impl fmt::Display for DeleteResolverEndpointError {
             DeleteResolverEndpointError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteResolverEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteResolverEndpointError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteResolverEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResolverEndpointError {}
 /// Errors returned by DeleteResolverRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResolverRuleError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1202,6 +1279,8 @@ pub enum DeleteResolverRuleError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResolverRuleError {
@@ -1209,24 +1288,34 @@ impl DeleteResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResolverRuleError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteResolverRuleError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteResolverRuleError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteResolverRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(DeleteResolverRuleError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResolverRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1241,12 +1330,14 @@ impl fmt::Display for DeleteResolverRuleError {
             DeleteResolverRuleError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteResolverRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteResolverRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteResolverRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResolverRuleError {}
 /// Errors returned by DisassociateResolverEndpointIpAddress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateResolverEndpointIpAddressError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1260,6 +1351,8 @@ pub enum DisassociateResolverEndpointIpAddressError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateResolverEndpointIpAddressError {
@@ -1269,37 +1362,48 @@ impl DisassociateResolverEndpointIpAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResolverEndpointIpAddressError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResolverEndpointIpAddressError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResolverEndpointIpAddressError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResolverEndpointIpAddressError::ResourceExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResolverEndpointIpAddressError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResolverEndpointIpAddressError::Throttling(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateResolverEndpointIpAddressError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1327,12 +1431,16 @@ impl fmt::Display for DisassociateResolverEndpointIpAddressError {
             DisassociateResolverEndpointIpAddressError::Throttling(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateResolverEndpointIpAddressError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateResolverEndpointIpAddressError {}
 /// Errors returned by DisassociateResolverRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateResolverRuleError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1342,6 +1450,8 @@ pub enum DisassociateResolverRuleError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateResolverRuleError {
@@ -1349,25 +1459,31 @@ impl DisassociateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateResolverRuleError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateResolverRuleError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateResolverRuleError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateResolverRuleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateResolverRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DisassociateResolverRuleError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateResolverRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1383,12 +1499,14 @@ impl fmt::Display for DisassociateResolverRuleError {
             DisassociateResolverRuleError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DisassociateResolverRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DisassociateResolverRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            DisassociateResolverRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateResolverRuleError {}
 /// Errors returned by GetResolverEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResolverEndpointError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1398,6 +1516,8 @@ pub enum GetResolverEndpointError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResolverEndpointError {
@@ -1405,25 +1525,31 @@ impl GetResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetResolverEndpointError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverEndpointError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverEndpointError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverEndpointError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetResolverEndpointError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResolverEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1437,12 +1563,14 @@ impl fmt::Display for GetResolverEndpointError {
             GetResolverEndpointError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetResolverEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetResolverEndpointError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetResolverEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResolverEndpointError {}
 /// Errors returned by GetResolverRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResolverRuleError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1452,6 +1580,8 @@ pub enum GetResolverRuleError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResolverRuleError {
@@ -1459,21 +1589,28 @@ impl GetResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverRuleError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetResolverRuleError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetResolverRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(GetResolverRuleError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResolverRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1487,12 +1624,14 @@ impl fmt::Display for GetResolverRuleError {
             GetResolverRuleError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetResolverRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetResolverRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetResolverRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResolverRuleError {}
 /// Errors returned by GetResolverRuleAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResolverRuleAssociationError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1502,6 +1641,8 @@ pub enum GetResolverRuleAssociationError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResolverRuleAssociationError {
@@ -1511,27 +1652,33 @@ impl GetResolverRuleAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResolverRuleAssociationError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverRuleAssociationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverRuleAssociationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverRuleAssociationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverRuleAssociationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverRuleAssociationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverRuleAssociationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetResolverRuleAssociationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1547,12 +1694,14 @@ impl fmt::Display for GetResolverRuleAssociationError {
             GetResolverRuleAssociationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetResolverRuleAssociationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetResolverRuleAssociationError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetResolverRuleAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResolverRuleAssociationError {}
 /// Errors returned by GetResolverRulePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResolverRulePolicyError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1560,6 +1709,8 @@ pub enum GetResolverRulePolicyError {
     InvalidParameter(String),
     /// <p>The specified resource doesn't exist.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResolverRulePolicyError {
@@ -1567,22 +1718,25 @@ impl GetResolverRulePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetResolverRulePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverRulePolicyError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverRulePolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverRulePolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetResolverRulePolicyError::UnknownResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResolverRulePolicyError::UnknownResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResolverRulePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1595,12 +1749,14 @@ impl fmt::Display for GetResolverRulePolicyError {
             GetResolverRulePolicyError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             GetResolverRulePolicyError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetResolverRulePolicyError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            GetResolverRulePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResolverRulePolicyError {}
 /// Errors returned by ListResolverEndpointIpAddresses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResolverEndpointIpAddressesError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1612,6 +1768,8 @@ pub enum ListResolverEndpointIpAddressesError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResolverEndpointIpAddressesError {
@@ -1621,32 +1779,42 @@ impl ListResolverEndpointIpAddressesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResolverEndpointIpAddressesError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResolverEndpointIpAddressesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResolverEndpointIpAddressesError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResolverEndpointIpAddressesError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverEndpointIpAddressesError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolverEndpointIpAddressesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListResolverEndpointIpAddressesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1669,12 +1837,16 @@ impl fmt::Display for ListResolverEndpointIpAddressesError {
                 write!(f, "{}", cause)
             }
             ListResolverEndpointIpAddressesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListResolverEndpointIpAddressesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListResolverEndpointIpAddressesError {}
 /// Errors returned by ListResolverEndpoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResolverEndpointsError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1686,6 +1858,8 @@ pub enum ListResolverEndpointsError {
     InvalidRequest(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResolverEndpointsError {
@@ -1693,30 +1867,37 @@ impl ListResolverEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolverEndpointsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolverEndpointsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolverEndpointsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolverEndpointsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListResolverEndpointsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResolverEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1731,12 +1912,14 @@ impl fmt::Display for ListResolverEndpointsError {
             ListResolverEndpointsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListResolverEndpointsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListResolverEndpointsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListResolverEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResolverEndpointsError {}
 /// Errors returned by ListResolverRuleAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResolverRuleAssociationsError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1748,6 +1931,8 @@ pub enum ListResolverRuleAssociationsError {
     InvalidRequest(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResolverRuleAssociationsError {
@@ -1757,32 +1942,42 @@ impl ListResolverRuleAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResolverRuleAssociationsError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResolverRuleAssociationsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResolverRuleAssociationsError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverRuleAssociationsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolverRuleAssociationsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverRuleAssociationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolverRuleAssociationsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListResolverRuleAssociationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1803,12 +1998,14 @@ impl fmt::Display for ListResolverRuleAssociationsError {
             }
             ListResolverRuleAssociationsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListResolverRuleAssociationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListResolverRuleAssociationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResolverRuleAssociationsError {}
 /// Errors returned by ListResolverRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResolverRulesError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1820,6 +2017,8 @@ pub enum ListResolverRulesError {
     InvalidRequest(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResolverRulesError {
@@ -1827,24 +2026,34 @@ impl ListResolverRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListResolverRulesError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolverRulesError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResolverRulesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListResolverRulesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResolverRulesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListResolverRulesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverRulesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListResolverRulesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverRulesError::Throttling(err.msg))
+                    return RusotoError::service(ListResolverRulesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResolverRulesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1859,12 +2068,14 @@ impl fmt::Display for ListResolverRulesError {
             ListResolverRulesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListResolverRulesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListResolverRulesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListResolverRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResolverRulesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1878,6 +2089,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1885,33 +2098,43 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1927,12 +2150,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutResolverRulePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutResolverRulePolicyError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -1942,6 +2167,8 @@ pub enum PutResolverRulePolicyError {
     InvalidPolicyDocument(String),
     /// <p>The specified resource doesn't exist.</p>
     UnknownResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutResolverRulePolicyError {
@@ -1949,27 +2176,31 @@ impl PutResolverRulePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResolverRulePolicyError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResolverRulePolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPolicyDocument" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::InvalidPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResolverRulePolicyError::InvalidPolicyDocument(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::UnknownResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResolverRulePolicyError::UnknownResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutResolverRulePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1983,12 +2214,14 @@ impl fmt::Display for PutResolverRulePolicyError {
             PutResolverRulePolicyError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             PutResolverRulePolicyError::InvalidPolicyDocument(ref cause) => write!(f, "{}", cause),
             PutResolverRulePolicyError::UnknownResource(ref cause) => write!(f, "{}", cause),
+            PutResolverRulePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutResolverRulePolicyError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -2002,6 +2235,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2009,25 +2244,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTag(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidTag(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(TagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2043,12 +2281,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -2058,6 +2298,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2065,19 +2307,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(UntagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2091,12 +2342,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateResolverEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResolverEndpointError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -2108,6 +2361,8 @@ pub enum UpdateResolverEndpointError {
     ResourceNotFound(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateResolverEndpointError {
@@ -2115,30 +2370,37 @@ impl UpdateResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResolverEndpointError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResolverEndpointError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResolverEndpointError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResolverEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateResolverEndpointError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateResolverEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2153,12 +2415,14 @@ impl fmt::Display for UpdateResolverEndpointError {
             UpdateResolverEndpointError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateResolverEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateResolverEndpointError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateResolverEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateResolverEndpointError {}
 /// Errors returned by UpdateResolverRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResolverRuleError {
     /// <p>We encountered an unknown error. Try again in a few minutes.</p>
     InternalServiceError(String),
@@ -2174,6 +2438,8 @@ pub enum UpdateResolverRuleError {
     ResourceUnavailable(String),
     /// <p>The request was throttled. Try again in a few minutes.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateResolverRuleError {
@@ -2181,32 +2447,46 @@ impl UpdateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResolverRuleError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateResolverRuleError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateResolverRuleError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateResolverRuleError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateResolverRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResolverRuleError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(UpdateResolverRuleError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateResolverRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2223,6 +2503,7 @@ impl fmt::Display for UpdateResolverRuleError {
             UpdateResolverRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateResolverRuleError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateResolverRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateResolverRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2382,6 +2663,7 @@ impl Route53ResolverClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> Route53ResolverClient {
         Route53ResolverClient {
             client: Client::shared(),
@@ -2407,19 +2689,75 @@ impl Route53ResolverClient {
     pub fn new_with_client(client: Client, region: region::Region) -> Route53ResolverClient {
         Route53ResolverClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<Route53ResolverClient> {
+        rusoto_core::ClientBuilder::new(Route53ResolverClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> Route53ResolverClient {
+        Route53ResolverClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Route53Resolver for Route53ResolverClient {
-    /// <p>Adds IP addresses to an inbound or an outbound resolver endpoint. If you want to adding more than one IP address, submit one <code>AssociateResolverEndpointIpAddress</code> request for each IP address.</p> <p>To remove an IP address from an endpoint, see <a>DisassociateResolverEndpointIpAddress</a>.</p>
-    async fn associate_resolver_endpoint_ip_address(
+impl Route53ResolverClient {
+    /// Calls `associate_resolver_endpoint_ip_address`, also returning the metadata of the response.
+    pub async fn associate_resolver_endpoint_ip_address_with_metadata(
         &self,
         input: AssociateResolverEndpointIpAddressRequest,
     ) -> Result<
-        AssociateResolverEndpointIpAddressResponse,
+        (AssociateResolverEndpointIpAddressResponse, ResponseMetadata),
         RusotoError<AssociateResolverEndpointIpAddressError>,
     > {
+        let request = self.build_associate_resolver_endpoint_ip_address_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateResolverEndpointIpAddressResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateResolverEndpointIpAddressError::from_response(
+                response,
+            ))
+        }
+    }
+
+    /// Presigns the request `associate_resolver_endpoint_ip_address` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_resolver_endpoint_ip_address(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateResolverEndpointIpAddressRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_resolver_endpoint_ip_address_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_resolver_endpoint_ip_address_request(
+        &self,
+        input: AssociateResolverEndpointIpAddressRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("AssociateResolverEndpointIpAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2429,173 +2767,349 @@ impl Route53Resolver for Route53ResolverClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_resolver_rule`, also returning the metadata of the response.
+    pub async fn associate_resolver_rule_with_metadata(
+        &self,
+        input: AssociateResolverRuleRequest,
+    ) -> Result<
+        (AssociateResolverRuleResponse, ResponseMetadata),
+        RusotoError<AssociateResolverRuleError>,
+    > {
+        let request = self.build_associate_resolver_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateResolverEndpointIpAddressResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateResolverRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateResolverEndpointIpAddressError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateResolverRuleError::from_response(response))
         }
     }
 
-    /// <p>Associates a resolver rule with a VPC. When you associate a rule with a VPC, Resolver forwards all DNS queries for the domain name that is specified in the rule and that originate in the VPC. The queries are forwarded to the IP addresses for the DNS resolvers that are specified in the rule. For more information about rules, see <a>CreateResolverRule</a>. </p>
-    async fn associate_resolver_rule(
+    /// Presigns the request `associate_resolver_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_resolver_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateResolverRuleRequest,
-    ) -> Result<AssociateResolverRuleResponse, RusotoError<AssociateResolverRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_resolver_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_resolver_rule_request(
+        &self,
+        input: AssociateResolverRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("AssociateResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.AssociateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_resolver_endpoint`, also returning the metadata of the response.
+    pub async fn create_resolver_endpoint_with_metadata(
+        &self,
+        input: CreateResolverEndpointRequest,
+    ) -> Result<
+        (CreateResolverEndpointResponse, ResponseMetadata),
+        RusotoError<CreateResolverEndpointError>,
+    > {
+        let request = self.build_create_resolver_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateResolverRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateResolverEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateResolverRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateResolverEndpointError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a resolver endpoint. There are two types of resolver endpoints, inbound and outbound:</p> <ul> <li> <p>An <i>inbound resolver endpoint</i> forwards DNS queries to the DNS service for a VPC from your network or another VPC.</p> </li> <li> <p>An <i>outbound resolver endpoint</i> forwards DNS queries from the DNS service for a VPC to your network or another VPC.</p> </li> </ul></p>
-    async fn create_resolver_endpoint(
+    /// Presigns the request `create_resolver_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resolver_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResolverEndpointRequest,
-    ) -> Result<CreateResolverEndpointResponse, RusotoError<CreateResolverEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resolver_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resolver_endpoint_request(
+        &self,
+        input: CreateResolverEndpointRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("CreateResolverEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.CreateResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_resolver_rule`, also returning the metadata of the response.
+    pub async fn create_resolver_rule_with_metadata(
+        &self,
+        input: CreateResolverRuleRequest,
+    ) -> Result<(CreateResolverRuleResponse, ResponseMetadata), RusotoError<CreateResolverRuleError>>
+    {
+        let request = self.build_create_resolver_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateResolverEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateResolverRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResolverEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateResolverRuleError::from_response(response))
         }
     }
 
-    /// <p>For DNS queries that originate in your VPCs, specifies which resolver endpoint the queries pass through, one domain name that you want to forward to your network, and the IP addresses of the DNS resolvers in your network.</p>
-    async fn create_resolver_rule(
+    /// Presigns the request `create_resolver_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resolver_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResolverRuleRequest,
-    ) -> Result<CreateResolverRuleResponse, RusotoError<CreateResolverRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resolver_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resolver_rule_request(
+        &self,
+        input: CreateResolverRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("CreateResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.CreateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_resolver_endpoint`, also returning the metadata of the response.
+    pub async fn delete_resolver_endpoint_with_metadata(
+        &self,
+        input: DeleteResolverEndpointRequest,
+    ) -> Result<
+        (DeleteResolverEndpointResponse, ResponseMetadata),
+        RusotoError<DeleteResolverEndpointError>,
+    > {
+        let request = self.build_delete_resolver_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateResolverRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteResolverEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResolverRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteResolverEndpointError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a resolver endpoint. The effect of deleting a resolver endpoint depends on whether it&#39;s an inbound or an outbound resolver endpoint:</p> <ul> <li> <p> <b>Inbound</b>: DNS queries from your network or another VPC are no longer routed to the DNS service for the specified VPC.</p> </li> <li> <p> <b>Outbound</b>: DNS queries from a VPC are no longer routed to your network or to another VPC.</p> </li> </ul></p>
-    async fn delete_resolver_endpoint(
+    /// Presigns the request `delete_resolver_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resolver_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResolverEndpointRequest,
-    ) -> Result<DeleteResolverEndpointResponse, RusotoError<DeleteResolverEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resolver_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resolver_endpoint_request(
+        &self,
+        input: DeleteResolverEndpointRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("DeleteResolverEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.DeleteResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_resolver_rule`, also returning the metadata of the response.
+    pub async fn delete_resolver_rule_with_metadata(
+        &self,
+        input: DeleteResolverRuleRequest,
+    ) -> Result<(DeleteResolverRuleResponse, ResponseMetadata), RusotoError<DeleteResolverRuleError>>
+    {
+        let request = self.build_delete_resolver_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteResolverEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteResolverRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResolverEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteResolverRuleError::from_response(response))
         }
     }
 
-    /// <p>Deletes a resolver rule. Before you can delete a resolver rule, you must disassociate it from all the VPCs that you associated the resolver rule with. For more infomation, see <a>DisassociateResolverRule</a>.</p>
-    async fn delete_resolver_rule(
+    /// Presigns the request `delete_resolver_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resolver_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResolverRuleRequest,
-    ) -> Result<DeleteResolverRuleResponse, RusotoError<DeleteResolverRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resolver_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resolver_rule_request(
+        &self,
+        input: DeleteResolverRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("DeleteResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.DeleteResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_resolver_endpoint_ip_address`, also returning the metadata of the response.
+    pub async fn disassociate_resolver_endpoint_ip_address_with_metadata(
+        &self,
+        input: DisassociateResolverEndpointIpAddressRequest,
+    ) -> Result<
+        (
+            DisassociateResolverEndpointIpAddressResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DisassociateResolverEndpointIpAddressError>,
+    > {
+        let request = self.build_disassociate_resolver_endpoint_ip_address_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteResolverRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateResolverEndpointIpAddressResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResolverRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateResolverEndpointIpAddressError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Removes IP addresses from an inbound or an outbound resolver endpoint. If you want to remove more than one IP address, submit one <code>DisassociateResolverEndpointIpAddress</code> request for each IP address.</p> <p>To add an IP address to an endpoint, see <a>AssociateResolverEndpointIpAddress</a>.</p>
-    async fn disassociate_resolver_endpoint_ip_address(
+    /// Presigns the request `disassociate_resolver_endpoint_ip_address` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_resolver_endpoint_ip_address(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateResolverEndpointIpAddressRequest,
-    ) -> Result<
-        DisassociateResolverEndpointIpAddressResponse,
-        RusotoError<DisassociateResolverEndpointIpAddressError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_resolver_endpoint_ip_address_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_resolver_endpoint_ip_address_request(
+        &self,
+        input: DisassociateResolverEndpointIpAddressRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("DisassociateResolverEndpointIpAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2605,173 +3119,345 @@ impl Route53Resolver for Route53ResolverClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_resolver_rule`, also returning the metadata of the response.
+    pub async fn disassociate_resolver_rule_with_metadata(
+        &self,
+        input: DisassociateResolverRuleRequest,
+    ) -> Result<
+        (DisassociateResolverRuleResponse, ResponseMetadata),
+        RusotoError<DisassociateResolverRuleError>,
+    > {
+        let request = self.build_disassociate_resolver_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateResolverEndpointIpAddressResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateResolverRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateResolverEndpointIpAddressError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateResolverRuleError::from_response(response))
         }
     }
 
-    /// <p><p>Removes the association between a specified resolver rule and a specified VPC.</p> <important> <p>If you disassociate a resolver rule from a VPC, Resolver stops forwarding DNS queries for the domain name that you specified in the resolver rule. </p> </important></p>
-    async fn disassociate_resolver_rule(
+    /// Presigns the request `disassociate_resolver_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_resolver_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateResolverRuleRequest,
-    ) -> Result<DisassociateResolverRuleResponse, RusotoError<DisassociateResolverRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_resolver_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_resolver_rule_request(
+        &self,
+        input: DisassociateResolverRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("DisassociateResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.DisassociateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resolver_endpoint`, also returning the metadata of the response.
+    pub async fn get_resolver_endpoint_with_metadata(
+        &self,
+        input: GetResolverEndpointRequest,
+    ) -> Result<
+        (GetResolverEndpointResponse, ResponseMetadata),
+        RusotoError<GetResolverEndpointError>,
+    > {
+        let request = self.build_get_resolver_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateResolverRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResolverEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateResolverRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetResolverEndpointError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a specified resolver endpoint, such as whether it's an inbound or an outbound resolver endpoint, and the current status of the endpoint.</p>
-    async fn get_resolver_endpoint(
+    /// Presigns the request `get_resolver_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resolver_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResolverEndpointRequest,
-    ) -> Result<GetResolverEndpointResponse, RusotoError<GetResolverEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resolver_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resolver_endpoint_request(
+        &self,
+        input: GetResolverEndpointRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("GetResolverEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.GetResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resolver_rule`, also returning the metadata of the response.
+    pub async fn get_resolver_rule_with_metadata(
+        &self,
+        input: GetResolverRuleRequest,
+    ) -> Result<(GetResolverRuleResponse, ResponseMetadata), RusotoError<GetResolverRuleError>>
+    {
+        let request = self.build_get_resolver_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResolverEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResolverRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResolverEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetResolverRuleError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a specified resolver rule, such as the domain name that the rule forwards DNS queries for and the ID of the outbound resolver endpoint that the rule is associated with.</p>
-    async fn get_resolver_rule(
+    /// Presigns the request `get_resolver_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resolver_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResolverRuleRequest,
-    ) -> Result<GetResolverRuleResponse, RusotoError<GetResolverRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resolver_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resolver_rule_request(&self, input: GetResolverRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("GetResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resolver_rule_association`, also returning the metadata of the response.
+    pub async fn get_resolver_rule_association_with_metadata(
+        &self,
+        input: GetResolverRuleAssociationRequest,
+    ) -> Result<
+        (GetResolverRuleAssociationResponse, ResponseMetadata),
+        RusotoError<GetResolverRuleAssociationError>,
+    > {
+        let request = self.build_get_resolver_rule_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetResolverRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResolverRuleAssociationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResolverRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetResolverRuleAssociationError::from_response(response))
         }
     }
 
-    /// <p>Gets information about an association between a specified resolver rule and a VPC. You associate a resolver rule and a VPC using <a>AssociateResolverRule</a>. </p>
-    async fn get_resolver_rule_association(
+    /// Presigns the request `get_resolver_rule_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resolver_rule_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResolverRuleAssociationRequest,
-    ) -> Result<GetResolverRuleAssociationResponse, RusotoError<GetResolverRuleAssociationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resolver_rule_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resolver_rule_association_request(
+        &self,
+        input: GetResolverRuleAssociationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("GetResolverRuleAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRuleAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resolver_rule_policy`, also returning the metadata of the response.
+    pub async fn get_resolver_rule_policy_with_metadata(
+        &self,
+        input: GetResolverRulePolicyRequest,
+    ) -> Result<
+        (GetResolverRulePolicyResponse, ResponseMetadata),
+        RusotoError<GetResolverRulePolicyError>,
+    > {
+        let request = self.build_get_resolver_rule_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResolverRuleAssociationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResolverRulePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResolverRuleAssociationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetResolverRulePolicyError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a resolver rule policy. A resolver rule policy specifies the Resolver operations and resources that you want to allow another AWS account to be able to use. </p>
-    async fn get_resolver_rule_policy(
+    /// Presigns the request `get_resolver_rule_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resolver_rule_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResolverRulePolicyRequest,
-    ) -> Result<GetResolverRulePolicyResponse, RusotoError<GetResolverRulePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resolver_rule_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resolver_rule_policy_request(
+        &self,
+        input: GetResolverRulePolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("GetResolverRulePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRulePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resolver_endpoint_ip_addresses`, also returning the metadata of the response.
+    pub async fn list_resolver_endpoint_ip_addresses_with_metadata(
+        &self,
+        input: ListResolverEndpointIpAddressesRequest,
+    ) -> Result<
+        (ListResolverEndpointIpAddressesResponse, ResponseMetadata),
+        RusotoError<ListResolverEndpointIpAddressesError>,
+    > {
+        let request = self.build_list_resolver_endpoint_ip_addresses_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResolverRulePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResolverEndpointIpAddressesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResolverRulePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResolverEndpointIpAddressesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Gets the IP addresses for a specified resolver endpoint.</p>
-    async fn list_resolver_endpoint_ip_addresses(
+    /// Presigns the request `list_resolver_endpoint_ip_addresses` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resolver_endpoint_ip_addresses(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResolverEndpointIpAddressesRequest,
-    ) -> Result<
-        ListResolverEndpointIpAddressesResponse,
-        RusotoError<ListResolverEndpointIpAddressesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resolver_endpoint_ip_addresses_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resolver_endpoint_ip_addresses_request(
+        &self,
+        input: ListResolverEndpointIpAddressesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListResolverEndpointIpAddresses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2781,59 +3467,116 @@ impl Route53Resolver for Route53ResolverClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resolver_endpoints`, also returning the metadata of the response.
+    pub async fn list_resolver_endpoints_with_metadata(
+        &self,
+        input: ListResolverEndpointsRequest,
+    ) -> Result<
+        (ListResolverEndpointsResponse, ResponseMetadata),
+        RusotoError<ListResolverEndpointsError>,
+    > {
+        let request = self.build_list_resolver_endpoints_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResolverEndpointIpAddressesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResolverEndpointsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResolverEndpointIpAddressesError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResolverEndpointsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the resolver endpoints that were created using the current AWS account.</p>
-    async fn list_resolver_endpoints(
+    /// Presigns the request `list_resolver_endpoints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resolver_endpoints(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResolverEndpointsRequest,
-    ) -> Result<ListResolverEndpointsResponse, RusotoError<ListResolverEndpointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resolver_endpoints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resolver_endpoints_request(
+        &self,
+        input: ListResolverEndpointsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListResolverEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.ListResolverEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resolver_rule_associations`, also returning the metadata of the response.
+    pub async fn list_resolver_rule_associations_with_metadata(
+        &self,
+        input: ListResolverRuleAssociationsRequest,
+    ) -> Result<
+        (ListResolverRuleAssociationsResponse, ResponseMetadata),
+        RusotoError<ListResolverRuleAssociationsError>,
+    > {
+        let request = self.build_list_resolver_rule_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResolverEndpointsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResolverRuleAssociationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResolverEndpointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResolverRuleAssociationsError::from_response(response))
         }
     }
 
-    /// <p>Lists the associations that were created between resolver rules and VPCs using the current AWS account.</p>
-    async fn list_resolver_rule_associations(
+    /// Presigns the request `list_resolver_rule_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resolver_rule_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResolverRuleAssociationsRequest,
-    ) -> Result<ListResolverRuleAssociationsResponse, RusotoError<ListResolverRuleAssociationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resolver_rule_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resolver_rule_associations_request(
+        &self,
+        input: ListResolverRuleAssociationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListResolverRuleAssociations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2843,213 +3586,833 @@ impl Route53Resolver for Route53ResolverClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resolver_rules`, also returning the metadata of the response.
+    pub async fn list_resolver_rules_with_metadata(
+        &self,
+        input: ListResolverRulesRequest,
+    ) -> Result<(ListResolverRulesResponse, ResponseMetadata), RusotoError<ListResolverRulesError>>
+    {
+        let request = self.build_list_resolver_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResolverRuleAssociationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResolverRulesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResolverRuleAssociationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResolverRulesError::from_response(response))
         }
     }
 
-    /// <p>Lists the resolver rules that were created using the current AWS account.</p>
-    async fn list_resolver_rules(
+    /// Presigns the request `list_resolver_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resolver_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResolverRulesRequest,
-    ) -> Result<ListResolverRulesResponse, RusotoError<ListResolverRulesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resolver_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resolver_rules_request(&self, input: ListResolverRulesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListResolverRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.ListResolverRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResolverRulesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResolverRulesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags that you associated with the specified resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_resolver_rule_policy`, also returning the metadata of the response.
+    pub async fn put_resolver_rule_policy_with_metadata(
+        &self,
+        input: PutResolverRulePolicyRequest,
+    ) -> Result<
+        (PutResolverRulePolicyResponse, ResponseMetadata),
+        RusotoError<PutResolverRulePolicyError>,
+    > {
+        let request = self.build_put_resolver_rule_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutResolverRulePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutResolverRulePolicyError::from_response(response))
         }
     }
 
-    /// <p>Specifies the Resolver operations and resources that you want to allow another AWS account to be able to use.</p>
-    async fn put_resolver_rule_policy(
+    /// Presigns the request `put_resolver_rule_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_resolver_rule_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutResolverRulePolicyRequest,
-    ) -> Result<PutResolverRulePolicyResponse, RusotoError<PutResolverRulePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_resolver_rule_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_resolver_rule_policy_request(
+        &self,
+        input: PutResolverRulePolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("PutResolverRulePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.PutResolverRulePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutResolverRulePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutResolverRulePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to a specified resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from a specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_resolver_endpoint`, also returning the metadata of the response.
+    pub async fn update_resolver_endpoint_with_metadata(
+        &self,
+        input: UpdateResolverEndpointRequest,
+    ) -> Result<
+        (UpdateResolverEndpointResponse, ResponseMetadata),
+        RusotoError<UpdateResolverEndpointError>,
+    > {
+        let request = self.build_update_resolver_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateResolverEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateResolverEndpointError::from_response(response))
         }
     }
 
-    /// <p>Updates the name of an inbound or an outbound resolver endpoint. </p>
-    async fn update_resolver_endpoint(
+    /// Presigns the request `update_resolver_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_resolver_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateResolverEndpointRequest,
-    ) -> Result<UpdateResolverEndpointResponse, RusotoError<UpdateResolverEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_resolver_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_resolver_endpoint_request(
+        &self,
+        input: UpdateResolverEndpointRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("UpdateResolverEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.UpdateResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_resolver_rule`, also returning the metadata of the response.
+    pub async fn update_resolver_rule_with_metadata(
+        &self,
+        input: UpdateResolverRuleRequest,
+    ) -> Result<(UpdateResolverRuleResponse, ResponseMetadata), RusotoError<UpdateResolverRuleError>>
+    {
+        let request = self.build_update_resolver_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateResolverEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateResolverRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateResolverEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateResolverRuleError::from_response(response))
         }
     }
 
-    /// <p>Updates settings for a specified resolver rule. <code>ResolverRuleId</code> is required, and all other parameters are optional. If you don't specify a parameter, it retains its current value.</p>
-    async fn update_resolver_rule(
+    /// Presigns the request `update_resolver_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_resolver_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateResolverRuleRequest,
-    ) -> Result<UpdateResolverRuleResponse, RusotoError<UpdateResolverRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_resolver_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_resolver_rule_request(
+        &self,
+        input: UpdateResolverRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "route53resolver", &self.region, "/");
+        request.set_operation("UpdateResolverRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Resolver.UpdateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListResolverEndpointIpAddresses` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resolver_endpoint_ip_addresses_pages(
+        &self,
+        input: ListResolverEndpointIpAddressesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListResolverEndpointIpAddressesResponse,
+            RusotoError<ListResolverEndpointIpAddressesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input| async move {
+                client.list_resolver_endpoint_ip_addresses(input).await
+            },
+            |input: &ListResolverEndpointIpAddressesRequest,
+             output: &ListResolverEndpointIpAddressesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResolverEndpointIpAddressesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `IpAddresses` of every page of `ListResolverEndpointIpAddresses` results for `input`, as `list_resolver_endpoint_ip_addresses_pages` requests them.
+    pub fn list_resolver_endpoint_ip_addresses_stream(
+        &self,
+        input: ListResolverEndpointIpAddressesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<IpAddressResponse, RusotoError<ListResolverEndpointIpAddressesError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_resolver_endpoint_ip_addresses_pages(input),
+            |page| page.ip_addresses.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListResolverEndpoints` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resolver_endpoints_pages(
+        &self,
+        input: ListResolverEndpointsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListResolverEndpointsResponse, RusotoError<ListResolverEndpointsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input| async move {
+                client.list_resolver_endpoints(input).await
+            },
+            |input: &ListResolverEndpointsRequest, output: &ListResolverEndpointsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResolverEndpointsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResolverEndpoints` of every page of `ListResolverEndpoints` results for `input`, as `list_resolver_endpoints_pages` requests them.
+    pub fn list_resolver_endpoints_stream(
+        &self,
+        input: ListResolverEndpointsRequest,
+    ) -> impl futures::Stream<Item = Result<ResolverEndpoint, RusotoError<ListResolverEndpointsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_resolver_endpoints_pages(input), |page| {
+            page.resolver_endpoints.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListResolverRuleAssociations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resolver_rule_associations_pages(
+        &self,
+        input: ListResolverRuleAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListResolverRuleAssociationsResponse,
+            RusotoError<ListResolverRuleAssociationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input| async move {
+                client.list_resolver_rule_associations(input).await
+            },
+            |input: &ListResolverRuleAssociationsRequest,
+             output: &ListResolverRuleAssociationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResolverRuleAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResolverRuleAssociations` of every page of `ListResolverRuleAssociations` results for `input`, as `list_resolver_rule_associations_pages` requests them.
+    pub fn list_resolver_rule_associations_stream(
+        &self,
+        input: ListResolverRuleAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ResolverRuleAssociation, RusotoError<ListResolverRuleAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_resolver_rule_associations_pages(input), |page| {
+            page.resolver_rule_associations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListResolverRules` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resolver_rules_pages(
+        &self,
+        input: ListResolverRulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListResolverRulesResponse, RusotoError<ListResolverRulesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input| async move {
+                client.list_resolver_rules(input).await
+            },
+            |input: &ListResolverRulesRequest, output: &ListResolverRulesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResolverRulesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResolverRules` of every page of `ListResolverRules` results for `input`, as `list_resolver_rules_pages` requests them.
+    pub fn list_resolver_rules_stream(
+        &self,
+        input: ListResolverRulesRequest,
+    ) -> impl futures::Stream<Item = Result<ResolverRule, RusotoError<ListResolverRulesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_resolver_rules_pages(input), |page| {
+            page.resolver_rules.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Route53ResolverClient, input| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Route53Resolver for Route53ResolverClient {
+    /// <p>Adds IP addresses to an inbound or an outbound resolver endpoint. If you want to adding more than one IP address, submit one <code>AssociateResolverEndpointIpAddress</code> request for each IP address.</p> <p>To remove an IP address from an endpoint, see <a>DisassociateResolverEndpointIpAddress</a>.</p>
+    async fn associate_resolver_endpoint_ip_address(
+        &self,
+        input: AssociateResolverEndpointIpAddressRequest,
+    ) -> Result<
+        AssociateResolverEndpointIpAddressResponse,
+        RusotoError<AssociateResolverEndpointIpAddressError>,
+    > {
+        self.associate_resolver_endpoint_ip_address_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateResolverRuleResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateResolverRuleError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a resolver rule with a VPC. When you associate a rule with a VPC, Resolver forwards all DNS queries for the domain name that is specified in the rule and that originate in the VPC. The queries are forwarded to the IP addresses for the DNS resolvers that are specified in the rule. For more information about rules, see <a>CreateResolverRule</a>. </p>
+    async fn associate_resolver_rule(
+        &self,
+        input: AssociateResolverRuleRequest,
+    ) -> Result<AssociateResolverRuleResponse, RusotoError<AssociateResolverRuleError>> {
+        self.associate_resolver_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a resolver endpoint. There are two types of resolver endpoints, inbound and outbound:</p> <ul> <li> <p>An <i>inbound resolver endpoint</i> forwards DNS queries to the DNS service for a VPC from your network or another VPC.</p> </li> <li> <p>An <i>outbound resolver endpoint</i> forwards DNS queries from the DNS service for a VPC to your network or another VPC.</p> </li> </ul></p>
+    async fn create_resolver_endpoint(
+        &self,
+        input: CreateResolverEndpointRequest,
+    ) -> Result<CreateResolverEndpointResponse, RusotoError<CreateResolverEndpointError>> {
+        self.create_resolver_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For DNS queries that originate in your VPCs, specifies which resolver endpoint the queries pass through, one domain name that you want to forward to your network, and the IP addresses of the DNS resolvers in your network.</p>
+    async fn create_resolver_rule(
+        &self,
+        input: CreateResolverRuleRequest,
+    ) -> Result<CreateResolverRuleResponse, RusotoError<CreateResolverRuleError>> {
+        self.create_resolver_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a resolver endpoint. The effect of deleting a resolver endpoint depends on whether it&#39;s an inbound or an outbound resolver endpoint:</p> <ul> <li> <p> <b>Inbound</b>: DNS queries from your network or another VPC are no longer routed to the DNS service for the specified VPC.</p> </li> <li> <p> <b>Outbound</b>: DNS queries from a VPC are no longer routed to your network or to another VPC.</p> </li> </ul></p>
+    async fn delete_resolver_endpoint(
+        &self,
+        input: DeleteResolverEndpointRequest,
+    ) -> Result<DeleteResolverEndpointResponse, RusotoError<DeleteResolverEndpointError>> {
+        self.delete_resolver_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a resolver rule. Before you can delete a resolver rule, you must disassociate it from all the VPCs that you associated the resolver rule with. For more infomation, see <a>DisassociateResolverRule</a>.</p>
+    async fn delete_resolver_rule(
+        &self,
+        input: DeleteResolverRuleRequest,
+    ) -> Result<DeleteResolverRuleResponse, RusotoError<DeleteResolverRuleError>> {
+        self.delete_resolver_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes IP addresses from an inbound or an outbound resolver endpoint. If you want to remove more than one IP address, submit one <code>DisassociateResolverEndpointIpAddress</code> request for each IP address.</p> <p>To add an IP address to an endpoint, see <a>AssociateResolverEndpointIpAddress</a>.</p>
+    async fn disassociate_resolver_endpoint_ip_address(
+        &self,
+        input: DisassociateResolverEndpointIpAddressRequest,
+    ) -> Result<
+        DisassociateResolverEndpointIpAddressResponse,
+        RusotoError<DisassociateResolverEndpointIpAddressError>,
+    > {
+        self.disassociate_resolver_endpoint_ip_address_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes the association between a specified resolver rule and a specified VPC.</p> <important> <p>If you disassociate a resolver rule from a VPC, Resolver stops forwarding DNS queries for the domain name that you specified in the resolver rule. </p> </important></p>
+    async fn disassociate_resolver_rule(
+        &self,
+        input: DisassociateResolverRuleRequest,
+    ) -> Result<DisassociateResolverRuleResponse, RusotoError<DisassociateResolverRuleError>> {
+        self.disassociate_resolver_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specified resolver endpoint, such as whether it's an inbound or an outbound resolver endpoint, and the current status of the endpoint.</p>
+    async fn get_resolver_endpoint(
+        &self,
+        input: GetResolverEndpointRequest,
+    ) -> Result<GetResolverEndpointResponse, RusotoError<GetResolverEndpointError>> {
+        self.get_resolver_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specified resolver rule, such as the domain name that the rule forwards DNS queries for and the ID of the outbound resolver endpoint that the rule is associated with.</p>
+    async fn get_resolver_rule(
+        &self,
+        input: GetResolverRuleRequest,
+    ) -> Result<GetResolverRuleResponse, RusotoError<GetResolverRuleError>> {
+        self.get_resolver_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about an association between a specified resolver rule and a VPC. You associate a resolver rule and a VPC using <a>AssociateResolverRule</a>. </p>
+    async fn get_resolver_rule_association(
+        &self,
+        input: GetResolverRuleAssociationRequest,
+    ) -> Result<GetResolverRuleAssociationResponse, RusotoError<GetResolverRuleAssociationError>>
+    {
+        self.get_resolver_rule_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a resolver rule policy. A resolver rule policy specifies the Resolver operations and resources that you want to allow another AWS account to be able to use. </p>
+    async fn get_resolver_rule_policy(
+        &self,
+        input: GetResolverRulePolicyRequest,
+    ) -> Result<GetResolverRulePolicyResponse, RusotoError<GetResolverRulePolicyError>> {
+        self.get_resolver_rule_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the IP addresses for a specified resolver endpoint.</p>
+    async fn list_resolver_endpoint_ip_addresses(
+        &self,
+        input: ListResolverEndpointIpAddressesRequest,
+    ) -> Result<
+        ListResolverEndpointIpAddressesResponse,
+        RusotoError<ListResolverEndpointIpAddressesError>,
+    > {
+        self.list_resolver_endpoint_ip_addresses_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the resolver endpoints that were created using the current AWS account.</p>
+    async fn list_resolver_endpoints(
+        &self,
+        input: ListResolverEndpointsRequest,
+    ) -> Result<ListResolverEndpointsResponse, RusotoError<ListResolverEndpointsError>> {
+        self.list_resolver_endpoints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the associations that were created between resolver rules and VPCs using the current AWS account.</p>
+    async fn list_resolver_rule_associations(
+        &self,
+        input: ListResolverRuleAssociationsRequest,
+    ) -> Result<ListResolverRuleAssociationsResponse, RusotoError<ListResolverRuleAssociationsError>>
+    {
+        self.list_resolver_rule_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resolver rules that were created using the current AWS account.</p>
+    async fn list_resolver_rules(
+        &self,
+        input: ListResolverRulesRequest,
+    ) -> Result<ListResolverRulesResponse, RusotoError<ListResolverRulesError>> {
+        self.list_resolver_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags that you associated with the specified resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Specifies the Resolver operations and resources that you want to allow another AWS account to be able to use.</p>
+    async fn put_resolver_rule_policy(
+        &self,
+        input: PutResolverRulePolicyRequest,
+    ) -> Result<PutResolverRulePolicyResponse, RusotoError<PutResolverRulePolicyError>> {
+        self.put_resolver_rule_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to a specified resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from a specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the name of an inbound or an outbound resolver endpoint. </p>
+    async fn update_resolver_endpoint(
+        &self,
+        input: UpdateResolverEndpointRequest,
+    ) -> Result<UpdateResolverEndpointResponse, RusotoError<UpdateResolverEndpointError>> {
+        self.update_resolver_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates settings for a specified resolver rule. <code>ResolverRuleId</code> is required, and all other parameters are optional. If you don't specify a parameter, it retains its current value.</p>
+    async fn update_resolver_rule(
+        &self,
+        input: UpdateResolverRuleRequest,
+    ) -> Result<UpdateResolverRuleResponse, RusotoError<UpdateResolverRuleError>> {
+        self.update_resolver_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -0,0 +1,69 @@
+use rusoto_core::{Region, RusotoError};
+
+use crate::generated::{HeadBucketError, HeadBucketRequest, S3Client};
+
+const BUCKET_REGION_HEADER: &str = "x-amz-bucket-region";
+
+impl S3Client {
+    /// The region of the bucket named `bucket`, from the `x-amz-bucket-region` header S3
+    /// answers `HeadBucket` with. The header is read from redirects and `403 Forbidden`
+    /// responses too, so the region of buckets in other regions, or of buckets the caller may
+    /// not access, is found as well.
+    pub async fn get_bucket_region(
+        &self,
+        bucket: &str,
+    ) -> Result<Region, RusotoError<HeadBucketError>> {
+        let request = HeadBucketRequest {
+            bucket: bucket.to_owned(),
+        };
+        let region = match self.head_bucket_with_metadata(request).await {
+            Ok((_, metadata)) => metadata.header(BUCKET_REGION_HEADER).map(str::to_owned),
+            Err(RusotoError::Unknown(response)) => match response.headers.get(BUCKET_REGION_HEADER)
+            {
+                Some(region) => Some(region.clone()),
+                None => return Err(RusotoError::Unknown(response)),
+            },
+            Err(err) => return Err(err),
+        };
+        let region = region.ok_or_else(|| {
+            RusotoError::ParseError(format!("Missing {} header", BUCKET_REGION_HEADER))
+        })?;
+        region
+            .parse()
+            .map_err(|_| RusotoError::ParseError(format!("Unknown bucket region {}", region)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    fn client(status: u16) -> S3Client {
+        let dispatcher = MockRequestDispatcher::with_status(status)
+            .with_request_checker(|request| {
+                assert_eq!(request.method(), "HEAD");
+                assert_eq!(request.path(), "/bucket");
+            })
+            .with_header(BUCKET_REGION_HEADER, "eu-central-1");
+        S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1)
+    }
+
+    #[tokio::test]
+    async fn gets_the_regions_of_buckets() {
+        let region = client(200).get_bucket_region("bucket").await.unwrap();
+        assert_eq!(region, Region::EuCentral1);
+        let region = client(403).get_bucket_region("bucket").await.unwrap();
+        assert_eq!(region, Region::EuCentral1);
+
+        let missing = S3Client::new_with(
+            MockRequestDispatcher::with_status(404),
+            MockCredentialsProvider,
+            Region::UsEast1,
+        );
+        match missing.get_bucket_region("bucket").await {
+            Err(RusotoError::Unknown(response)) => assert_eq!(response.status.as_u16(), 404),
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+}
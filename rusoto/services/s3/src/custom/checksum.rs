@@ -0,0 +1,591 @@
+//! Flexible checksums of uploads and downloads: CRC32, CRC32C, SHA-1 and SHA-256.
+//!
+//! Uploads carry the checksum of their body in an `x-amz-checksum-*` header, or for streamed
+//! bodies in a trailer after the body, and S3 rejects them if the data it received doesn't match.
+//! Downloads ask S3 for the checksum of the object and fail at the end of the body if the data
+//! doesn't match it.
+
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use futures::{stream, FutureExt, StreamExt};
+use http::Request;
+use rusoto_core::credential::ProvideAwsCredentials;
+use rusoto_core::request::DispatchSignedRequestFuture;
+use rusoto_core::signature::{
+    SignedRequest, SignedRequestPayload, STREAMING_UNSIGNED_PAYLOAD_TRAILER,
+};
+use rusoto_core::{ByteStream, Client, DispatchSignedRequest, Region, RequestHook};
+use sha1::Digest;
+
+use super::transfer::SyncStream;
+use crate::generated::S3Client;
+
+/// The size of the chunks of streamed bodies sent with a trailing checksum.
+const TRAILER_CHUNK_SIZE: usize = 64 * 1024;
+
+/// An algorithm S3 checksums objects with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    /// CRC32, as used by gzip.
+    Crc32,
+    /// CRC32C, with the Castagnoli polynomial.
+    Crc32c,
+    /// SHA-1.
+    Sha1,
+    /// SHA-256.
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// The algorithms, in the order downloads check them in.
+    const ALL: [ChecksumAlgorithm; 4] = [
+        ChecksumAlgorithm::Crc32c,
+        ChecksumAlgorithm::Crc32,
+        ChecksumAlgorithm::Sha1,
+        ChecksumAlgorithm::Sha256,
+    ];
+
+    /// The name S3 has for the algorithm, e.g. `CRC32C`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "CRC32",
+            ChecksumAlgorithm::Crc32c => "CRC32C",
+            ChecksumAlgorithm::Sha1 => "SHA1",
+            ChecksumAlgorithm::Sha256 => "SHA256",
+        }
+    }
+
+    /// The header carrying checksums of the algorithm, e.g. `x-amz-checksum-crc32c`.
+    pub fn header_name(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "x-amz-checksum-crc32",
+            ChecksumAlgorithm::Crc32c => "x-amz-checksum-crc32c",
+            ChecksumAlgorithm::Sha1 => "x-amz-checksum-sha1",
+            ChecksumAlgorithm::Sha256 => "x-amz-checksum-sha256",
+        }
+    }
+
+    /// The base64 encoded checksum of `data`, as S3 sends and expects it in headers.
+    pub fn checksum(self, data: &[u8]) -> String {
+        let mut hasher = Hasher::new(self);
+        hasher.update(data);
+        hasher.finish()
+    }
+
+    /// The length of the base64 encoded checksums.
+    fn encoded_length(self) -> usize {
+        match self {
+            ChecksumAlgorithm::Crc32 | ChecksumAlgorithm::Crc32c => 8,
+            ChecksumAlgorithm::Sha1 => 28,
+            ChecksumAlgorithm::Sha256 => 44,
+        }
+    }
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Computes a checksum incrementally.
+enum Hasher {
+    Crc32(crc32fast::Hasher),
+    Crc32c(u32),
+    Sha1(sha1::Sha1),
+    Sha256(sha2::Sha256),
+}
+
+impl Hasher {
+    fn new(algorithm: ChecksumAlgorithm) -> Hasher {
+        match algorithm {
+            ChecksumAlgorithm::Crc32 => Hasher::Crc32(crc32fast::Hasher::new()),
+            ChecksumAlgorithm::Crc32c => Hasher::Crc32c(!0),
+            ChecksumAlgorithm::Sha1 => Hasher::Sha1(sha1::Sha1::new()),
+            ChecksumAlgorithm::Sha256 => Hasher::Sha256(sha2::Sha256::new()),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Hasher::Crc32(hasher) => hasher.update(data),
+            Hasher::Crc32c(crc) => {
+                for byte in data {
+                    *crc = CRC32C_TABLE[((*crc ^ u32::from(*byte)) & 0xff) as usize] ^ (*crc >> 8);
+                }
+            }
+            Hasher::Sha1(hasher) => hasher.input(data),
+            Hasher::Sha256(hasher) => hasher.input(data),
+        }
+    }
+
+    fn finish(self) -> String {
+        match self {
+            Hasher::Crc32(hasher) => base64::encode(&hasher.finalize().to_be_bytes()),
+            Hasher::Crc32c(crc) => base64::encode(&(!crc).to_be_bytes()),
+            Hasher::Sha1(hasher) => base64::encode(&hasher.result()),
+            Hasher::Sha256(hasher) => base64::encode(&hasher.result()),
+        }
+    }
+}
+
+/// The lookup table of the reflected CRC32C polynomial.
+static CRC32C_TABLE: [u32; 256] = crc32c_table();
+
+const fn crc32c_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0x82f6_3b78
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Which checksums an S3 client sends with uploads and checks downloads against.
+///
+/// Used as a request hook, e.g. with `Client::add_request_hook`, it adds the checksums to
+/// `PutObject` and `UploadPart` requests and asks for the checksums of objects with `GetObject`
+/// and `HeadObject` requests. Checking downloads also takes the dispatcher of a client created
+/// with `S3Client::new_with_checksum_mode`.
+///
+/// The checksum of buffered bodies is sent in a header. Streamed bodies of a known length are
+/// sent with the `aws-chunked` content encoding in unsigned chunks followed by the checksum,
+/// which S3 only accepts over HTTPS; streams of an unknown length are sent without a checksum.
+/// Requests that already carry a checksum are left alone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChecksumMode {
+    upload_algorithm: Option<ChecksumAlgorithm>,
+    validate_downloads: bool,
+}
+
+impl Default for ChecksumMode {
+    /// Sends CRC32 checksums with uploads and checks downloads.
+    fn default() -> ChecksumMode {
+        ChecksumMode {
+            upload_algorithm: Some(ChecksumAlgorithm::Crc32),
+            validate_downloads: true,
+        }
+    }
+}
+
+impl ChecksumMode {
+    /// A mode sending no checksums and checking no downloads.
+    pub fn disabled() -> ChecksumMode {
+        ChecksumMode {
+            upload_algorithm: None,
+            validate_downloads: false,
+        }
+    }
+
+    /// Sets the algorithm of the checksums sent with uploads, or `None` to send none.
+    pub fn set_upload_algorithm(&mut self, algorithm: Option<ChecksumAlgorithm>) {
+        self.upload_algorithm = algorithm;
+    }
+
+    /// Sets whether downloads are checked against the checksums of objects. The checksums of
+    /// objects uploaded in parts are checksums of the checksums of their parts, which downloads
+    /// of the whole object can't be checked against.
+    pub fn set_validate_downloads(&mut self, validate_downloads: bool) {
+        self.validate_downloads = validate_downloads;
+    }
+
+    fn add_upload_checksum(&self, algorithm: ChecksumAlgorithm, request: &mut SignedRequest) {
+        if let Some(SignedRequestPayload::Buffer(ref body)) = request.payload {
+            let checksum = algorithm.checksum(body);
+            request.add_header(algorithm.header_name(), &checksum);
+            request.add_header("x-amz-sdk-checksum-algorithm", algorithm.name());
+            return;
+        }
+        let length = request
+            .payload
+            .as_ref()
+            .and_then(SignedRequestPayload::size_hint)
+            .or_else(|| {
+                request
+                    .headers()
+                    .get("content-length")
+                    .and_then(|values| values.first())
+                    .and_then(|value| std::str::from_utf8(value).ok())
+                    .and_then(|value| value.parse().ok())
+            });
+        let (length, stream) = match (length, request.payload.take()) {
+            (Some(length), Some(SignedRequestPayload::Stream(stream))) => (length, stream),
+            (_, payload) => {
+                request.payload = payload;
+                return;
+            }
+        };
+        let content_encoding = match request.headers().get("content-encoding") {
+            Some(values) => format!(
+                "aws-chunked,{}",
+                values
+                    .iter()
+                    .map(|value| String::from_utf8_lossy(value).into_owned())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            None => "aws-chunked".to_owned(),
+        };
+        request.remove_header("content-encoding");
+        request.add_header("content-encoding", &content_encoding);
+        request.remove_header("content-length");
+        request.add_header(
+            "content-length",
+            &trailer_encoded_length(length, algorithm).to_string(),
+        );
+        request.add_header("x-amz-decoded-content-length", &length.to_string());
+        request.add_header("x-amz-trailer", algorithm.header_name());
+        request.add_header("x-amz-sdk-checksum-algorithm", algorithm.name());
+        request.remove_header("x-amz-content-sha256");
+        request.add_header("x-amz-content-sha256", STREAMING_UNSIGNED_PAYLOAD_TRAILER);
+        request.set_payload_stream(trailer_chunks(stream, length, algorithm));
+    }
+}
+
+impl RequestHook for ChecksumMode {
+    fn prepare(&self, request: &mut SignedRequest) {
+        if request.service != "s3" {
+            return;
+        }
+        match request.operation.as_deref() {
+            Some("PutObject") | Some("UploadPart") => {
+                let algorithm = match self.upload_algorithm {
+                    Some(algorithm) => algorithm,
+                    None => return,
+                };
+                let has_checksum = request
+                    .headers()
+                    .keys()
+                    .any(|name| name.starts_with("x-amz-checksum-") || name == "content-md5");
+                if !has_checksum {
+                    self.add_upload_checksum(algorithm, request);
+                }
+            }
+            Some("GetObject") | Some("HeadObject") if self.validate_downloads => {
+                request.remove_header("x-amz-checksum-mode");
+                request.add_header("x-amz-checksum-mode", "ENABLED");
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The length of the encoding of a body of `length` bytes with a trailing checksum.
+fn trailer_encoded_length(length: usize, algorithm: ChecksumAlgorithm) -> usize {
+    let chunk_length = |size: usize| format!("{:x}", size).len() + 2 + size + 2;
+    let remainder = length % TRAILER_CHUNK_SIZE;
+    let mut encoded = length / TRAILER_CHUNK_SIZE * chunk_length(TRAILER_CHUNK_SIZE);
+    if remainder > 0 {
+        encoded += chunk_length(remainder);
+    }
+    // The last chunk, the trailer and the end of the trailers.
+    encoded + 3 + algorithm.header_name().len() + 1 + algorithm.encoded_length() + 2 + 2
+}
+
+/// Encodes the `length` bytes of `body` in unsigned chunks followed by its checksum.
+fn trailer_chunks(body: ByteStream, length: usize, algorithm: ChecksumAlgorithm) -> ByteStream {
+    let state = (body, BytesMut::new(), Some(Hasher::new(algorithm)), length);
+    let chunks = stream::unfold(
+        state,
+        move |(mut body, mut buffer, hasher, remaining)| async move {
+            let mut hasher = hasher?;
+            let size = remaining.min(TRAILER_CHUNK_SIZE);
+            while buffer.len() < size {
+                match body.next().await {
+                    Some(Ok(bytes)) => buffer.extend_from_slice(&bytes),
+                    Some(Err(error)) => return Some((Err(error), (body, buffer, None, 0))),
+                    None => {
+                        let error = io::Error::new(
+                            io::ErrorKind::UnexpectedEof,
+                            "the payload is shorter than its content length",
+                        );
+                        return Some((Err(error), (body, buffer, None, 0)));
+                    }
+                }
+            }
+            if size > 0 {
+                let data = buffer.split_to(size);
+                hasher.update(&data);
+                let mut chunk = BytesMut::with_capacity(size + 16);
+                chunk.extend_from_slice(format!("{:x}\r\n", size).as_bytes());
+                chunk.extend_from_slice(&data);
+                chunk.extend_from_slice(b"\r\n");
+                let state = (body, buffer, Some(hasher), remaining - size);
+                return Some((Ok(Bytes::from(chunk)), state));
+            }
+            if !buffer.is_empty() || body.next().await.is_some() {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "the payload is longer than its content length",
+                );
+                return Some((Err(error), (body, buffer, None, 0)));
+            }
+            let trailer = format!(
+                "0\r\n{}:{}\r\n\r\n",
+                algorithm.header_name(),
+                hasher.finish()
+            );
+            Some((Ok(Bytes::from(trailer)), (body, buffer, None, 0)))
+        },
+    );
+    ByteStream::new_with_size(
+        SyncStream::new(chunks),
+        trailer_encoded_length(length, algorithm),
+    )
+}
+
+/// Checks the bodies of the responses to whole `GetObject` requests against the checksums S3
+/// sends with them.
+struct ChecksumDispatcher<D> {
+    inner: D,
+}
+
+impl<D: DispatchSignedRequest> DispatchSignedRequest for ChecksumDispatcher<D> {
+    fn dispatch(
+        &self,
+        request: Request<ByteStream>,
+        timeout: Option<Duration>,
+    ) -> DispatchSignedRequestFuture {
+        let whole_object = request
+            .extensions()
+            .get::<SignedRequest>()
+            .map_or(false, |request| {
+                request.operation.as_deref() == Some("GetObject")
+                    && request.headers().contains_key("x-amz-checksum-mode")
+                    && !request.headers().contains_key("range")
+                    && !request.params.contains_key("partNumber")
+            });
+        let response = self.inner.dispatch(request, timeout);
+        if !whole_object {
+            return response;
+        }
+        response
+            .map(|response| {
+                response.map(|response| {
+                    if response.status() != 200 {
+                        return response;
+                    }
+                    let expected = ChecksumAlgorithm::ALL.iter().find_map(|algorithm| {
+                        response
+                            .headers()
+                            .get(algorithm.header_name())
+                            .and_then(|checksum| checksum.to_str().ok())
+                            .filter(|checksum| !checksum.contains('-'))
+                            .map(|checksum| (*algorithm, checksum.to_owned()))
+                    });
+                    match expected {
+                        Some((algorithm, expected)) => {
+                            response.map(|body| validated(body, algorithm, expected))
+                        }
+                        None => response,
+                    }
+                })
+            })
+            .boxed()
+    }
+}
+
+/// Passes `body` through, ending with an error if its checksum is not `expected`.
+fn validated(body: ByteStream, algorithm: ChecksumAlgorithm, expected: String) -> ByteStream {
+    let state = (body, Some(Hasher::new(algorithm)));
+    let chunks = stream::unfold(state, move |(mut body, hasher)| {
+        let expected = expected.clone();
+        async move {
+            let mut hasher = hasher?;
+            match body.next().await {
+                Some(Ok(chunk)) => {
+                    hasher.update(&chunk);
+                    Some((Ok(chunk), (body, Some(hasher))))
+                }
+                Some(Err(error)) => Some((Err(error), (body, None))),
+                None => {
+                    let computed = hasher.finish();
+                    if computed == expected {
+                        return None;
+                    }
+                    let error = io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "the {} checksum of the object is {} but its data has {}",
+                            algorithm, expected, computed
+                        ),
+                    );
+                    Some((Err(error), (body, None)))
+                }
+            }
+        }
+    });
+    ByteStream::new(SyncStream::new(chunks))
+}
+
+impl S3Client {
+    /// Creates a client like `new_with`, sending checksums with uploads and checking downloads
+    /// as `checksum_mode` sets.
+    pub fn new_with_checksum_mode<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: Region,
+        checksum_mode: ChecksumMode,
+    ) -> S3Client
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        let dispatcher = ChecksumDispatcher {
+            inner: request_dispatcher,
+        };
+        let mut client = Client::new_with(credentials_provider, dispatcher);
+        client.add_request_hook(checksum_mode);
+        S3Client::new_with_client(client, region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::{GetObjectRequest, S3};
+    use futures::TryStreamExt;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    #[test]
+    fn computes_checksums() {
+        let checksum = |algorithm: ChecksumAlgorithm| algorithm.checksum(b"123456789");
+        // The check values of the CRC catalogue, 0xcbf43926 and 0xe3069283.
+        assert_eq!(checksum(ChecksumAlgorithm::Crc32), "y/Q5Jg==");
+        assert_eq!(checksum(ChecksumAlgorithm::Crc32c), "4waSgw==");
+        assert_eq!(
+            checksum(ChecksumAlgorithm::Sha1),
+            "98O8HYCOBHMq32eZZczDTKeuNEE="
+        );
+        assert_eq!(
+            checksum(ChecksumAlgorithm::Sha256),
+            "FeKw08M4keuw8e9gnsQZQgwg4yDOlMZfvIwzEkSOsiU="
+        );
+    }
+
+    #[test]
+    fn adds_checksums_to_buffered_uploads() {
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_operation("PutObject");
+        request.set_payload(Some(b"123456789".to_vec()));
+        let mut mode = ChecksumMode::default();
+        mode.set_upload_algorithm(Some(ChecksumAlgorithm::Crc32c));
+        mode.prepare(&mut request);
+        assert_eq!(request.headers()["x-amz-checksum-crc32c"][0], b"4waSgw==");
+        assert_eq!(
+            request.headers()["x-amz-sdk-checksum-algorithm"][0],
+            b"CRC32C"
+        );
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_operation("GetObject");
+        mode.prepare(&mut request);
+        assert_eq!(request.headers()["x-amz-checksum-mode"][0], b"ENABLED");
+    }
+
+    #[tokio::test]
+    async fn sends_the_checksums_of_streamed_uploads_in_trailers() {
+        let data = vec![b'a'; TRAILER_CHUNK_SIZE + 10];
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_operation("UploadPart");
+        request.add_header("content-encoding", "gzip");
+        request.set_payload_stream(ByteStream::from(data.clone()));
+        ChecksumMode::default().prepare(&mut request);
+
+        let header = |name: &str| String::from_utf8(request.headers()[name][0].clone()).unwrap();
+        assert_eq!(header("content-encoding"), "aws-chunked,gzip");
+        assert_eq!(header("x-amz-trailer"), "x-amz-checksum-crc32");
+        assert_eq!(
+            header("x-amz-decoded-content-length"),
+            data.len().to_string()
+        );
+        let content_length: usize = header("content-length").parse().unwrap();
+        let body = match request.payload {
+            Some(SignedRequestPayload::Stream(stream)) => stream
+                .try_fold(Vec::new(), |mut body, chunk| async move {
+                    body.extend_from_slice(&chunk);
+                    Ok(body)
+                })
+                .await
+                .unwrap(),
+            _ => panic!("expected a streamed payload"),
+        };
+        let mut expected = b"10000\r\n".to_vec();
+        expected.extend_from_slice(&data[..TRAILER_CHUNK_SIZE]);
+        expected.extend_from_slice(b"\r\na\r\naaaaaaaaaa\r\n0\r\n");
+        expected.extend_from_slice(
+            format!(
+                "x-amz-checksum-crc32:{}\r\n\r\n",
+                ChecksumAlgorithm::Crc32.checksum(&data)
+            )
+            .as_bytes(),
+        );
+        assert_eq!(body, expected);
+        assert_eq!(content_length, expected.len());
+    }
+
+    async fn download(checksum: &str) -> io::Result<Vec<u8>> {
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_body("123456789")
+            .with_header("x-amz-checksum-crc32c", checksum)
+            .with_request_checker(|request| {
+                assert_eq!(request.headers()["x-amz-checksum-mode"][0], b"ENABLED");
+            });
+        let client = S3Client::new_with_checksum_mode(
+            dispatcher,
+            MockCredentialsProvider,
+            Region::UsEast1,
+            ChecksumMode::default(),
+        );
+        let output = client
+            .get_object(GetObjectRequest {
+                bucket: "bucket".to_owned(),
+                key: "key".to_owned(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        output
+            .body
+            .unwrap()
+            .try_fold(Vec::new(), |mut body, chunk| async move {
+                body.extend_from_slice(&chunk);
+                Ok(body)
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn validates_downloads() {
+        assert_eq!(download("4waSgw==").await.unwrap(), b"123456789");
+        let error = download("AAAAAA==").await.unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        // Checksums of objects uploaded in parts are not checked.
+        assert!(download("AAAAAA==-2").await.is_ok());
+    }
+
+    #[test]
+    fn leaves_uploads_with_checksums_alone() {
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_operation("PutObject");
+        request.add_header("x-amz-checksum-sha1", "given");
+        request.set_payload(Some(b"data".to_vec()));
+        ChecksumMode::default().prepare(&mut request);
+        assert_eq!(request.headers()["x-amz-checksum-sha1"][0], b"given");
+        assert!(!request.headers().contains_key("x-amz-checksum-crc32"));
+    }
+}
@@ -0,0 +1,241 @@
+//! Aborts of the multipart uploads left in progress, whose parts S3 keeps and charges for
+//! until the upload is completed or aborted.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::{stream, StreamExt, TryFutureExt, TryStreamExt};
+use rusoto_core::RusotoError;
+
+use super::transfer::{retrying, TransferError, TransferManager};
+use crate::generated::{
+    AbortMultipartUploadRequest, ListMultipartUploadsRequest, ListPartsRequest, MultipartUpload, S3,
+};
+
+/// A multipart upload aborted by `TransferManager::abort_multipart_uploads_older_than`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbortedUpload {
+    /// The key of the object the upload was for.
+    pub key: String,
+    /// The id of the upload.
+    pub upload_id: String,
+    /// When the upload was initiated.
+    pub initiated: DateTime<Utc>,
+    /// How many parts the upload had.
+    pub parts: usize,
+    /// The total size of the parts of the upload, in bytes.
+    pub size: u64,
+}
+
+impl<C> TransferManager<C>
+where
+    C: S3 + Send + Sync + 'static,
+{
+    /// Aborts the multipart uploads in progress in `bucket` for keys starting with `prefix`
+    /// that were initiated at least `age` ago, `concurrency` at a time, and returns them along
+    /// with the parts and bytes their abort frees.
+    ///
+    /// Uploads found completed or aborted by the time they are looked at are skipped, as are
+    /// uploads whose initiation time S3 doesn't give. The age should be longer than any upload
+    /// still running takes, as aborting an upload makes the `UploadPart` and
+    /// `CompleteMultipartUpload` requests of its uploader fail.
+    pub async fn abort_multipart_uploads_older_than(
+        &self,
+        bucket: &str,
+        prefix: Option<&str>,
+        age: Duration,
+    ) -> Result<Vec<AbortedUpload>, TransferError> {
+        let now = Utc::now();
+        let mut aborted = Vec::new();
+        let mut key_marker = None;
+        let mut upload_id_marker = None;
+        loop {
+            let output = retrying(self.part_retries, || {
+                self.client
+                    .list_multipart_uploads(ListMultipartUploadsRequest {
+                        bucket: bucket.to_owned(),
+                        key_marker: key_marker.clone(),
+                        prefix: prefix.map(str::to_owned),
+                        upload_id_marker: upload_id_marker.clone(),
+                        ..Default::default()
+                    })
+                    .map_err(TransferError::ListMultipartUploads)
+            })
+            .await?;
+            let stale = output
+                .uploads
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|upload| stale_upload(upload, now, age));
+            let page: Vec<Option<AbortedUpload>> = stream::iter(stale)
+                .map(|(key, upload_id, initiated)| {
+                    self.abort_upload(bucket, key, upload_id, initiated)
+                })
+                .buffer_unordered(self.concurrency)
+                .try_collect()
+                .await?;
+            aborted.extend(page.into_iter().flatten());
+            if output.is_truncated != Some(true) {
+                break;
+            }
+            key_marker = output.next_key_marker;
+            upload_id_marker = output.next_upload_id_marker;
+        }
+        aborted.sort_by(|a, b| (&a.key, a.initiated).cmp(&(&b.key, b.initiated)));
+        Ok(aborted)
+    }
+
+    /// Aborts an upload after listing its parts, returning `None` if it no longer exists.
+    async fn abort_upload(
+        &self,
+        bucket: &str,
+        key: String,
+        upload_id: String,
+        initiated: DateTime<Utc>,
+    ) -> Result<Option<AbortedUpload>, TransferError> {
+        let mut parts = 0;
+        let mut size = 0;
+        let mut part_number_marker = None;
+        loop {
+            let result = retrying(self.part_retries, || {
+                self.client
+                    .list_parts(ListPartsRequest {
+                        bucket: bucket.to_owned(),
+                        key: key.clone(),
+                        part_number_marker,
+                        upload_id: upload_id.clone(),
+                        ..Default::default()
+                    })
+                    .map_err(TransferError::ListParts)
+            })
+            .await;
+            let output = match result {
+                Ok(output) => output,
+                Err(TransferError::ListParts(RusotoError::Service(_, ref metadata)))
+                    if metadata.code() == Some("NoSuchUpload") =>
+                {
+                    return Ok(None)
+                }
+                Err(error) => return Err(error),
+            };
+            for part in output.parts.unwrap_or_default() {
+                parts += 1;
+                size += part.size.unwrap_or_default() as u64;
+            }
+            match output.next_part_number_marker {
+                Some(marker) if output.is_truncated == Some(true) => {
+                    part_number_marker = Some(marker)
+                }
+                _ => break,
+            }
+        }
+
+        let result = retrying(self.part_retries, || {
+            self.client
+                .abort_multipart_upload(AbortMultipartUploadRequest {
+                    bucket: bucket.to_owned(),
+                    key: key.clone(),
+                    upload_id: upload_id.clone(),
+                    ..Default::default()
+                })
+                .map_err(TransferError::AbortMultipartUpload)
+        })
+        .await;
+        match result {
+            Ok(_) => Ok(Some(AbortedUpload {
+                key,
+                upload_id,
+                initiated,
+                parts,
+                size,
+            })),
+            Err(TransferError::AbortMultipartUpload(RusotoError::Service(_, ref metadata)))
+                if metadata.code() == Some("NoSuchUpload") =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// The key, id and initiation time of `upload` if it was initiated at least `age` before `now`.
+fn stale_upload(
+    upload: MultipartUpload,
+    now: DateTime<Utc>,
+    age: Duration,
+) -> Option<(String, String, DateTime<Utc>)> {
+    let initiated = DateTime::parse_from_rfc3339(upload.initiated.as_deref()?)
+        .ok()?
+        .with_timezone(&Utc);
+    if !now
+        .signed_duration_since(initiated)
+        .to_std()
+        .map_or(false, |elapsed| elapsed >= age)
+    {
+        return None;
+    }
+    Some((upload.key?, upload.upload_id?, initiated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom::fake_s3::FakeS3;
+    use crate::generated::{CreateMultipartUploadRequest, UploadPartRequest};
+
+    async fn create_upload(client: &crate::S3Client, key: &str, parts: &[&[u8]]) -> String {
+        let upload_id = client
+            .create_multipart_upload(CreateMultipartUploadRequest {
+                bucket: "bucket".to_owned(),
+                key: key.to_owned(),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .upload_id
+            .unwrap();
+        for (index, part) in parts.iter().enumerate() {
+            client
+                .upload_part(UploadPartRequest {
+                    body: Some(part.to_vec().into()),
+                    bucket: "bucket".to_owned(),
+                    key: key.to_owned(),
+                    part_number: index as i64 + 1,
+                    upload_id: upload_id.clone(),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+        upload_id
+    }
+
+    #[tokio::test]
+    async fn aborts_stale_multipart_uploads() {
+        let s3 = FakeS3::default();
+        let client = s3.client();
+        let stale = create_upload(&client, "logs/old", &[b"0123", b"45"]).await;
+        let fresh = create_upload(&client, "logs/new", &[b"0123"]).await;
+        let other = create_upload(&client, "data/old", &[]).await;
+        let day_ago = Utc::now() - chrono::Duration::days(1);
+        for upload_id in &[&stale, &other] {
+            s3.state().initiated.insert(upload_id.to_string(), day_ago);
+        }
+        let manager = TransferManager::new(client);
+
+        let aborted = manager
+            .abort_multipart_uploads_older_than("bucket", Some("logs/"), Duration::from_secs(3600))
+            .await
+            .unwrap();
+        assert_eq!(aborted.len(), 1);
+        assert_eq!(aborted[0].key, "logs/old");
+        assert_eq!(aborted[0].upload_id, stale);
+        assert_eq!((aborted[0].parts, aborted[0].size), (2, 6));
+
+        let state = s3.state();
+        assert!(!state.uploads.contains_key(&stale));
+        assert!(state.uploads.contains_key(&fresh));
+        assert!(state.uploads.contains_key(&other));
+    }
+}
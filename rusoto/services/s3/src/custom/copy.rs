@@ -0,0 +1,423 @@
+//! Copies of objects too large for a single `CopyObject` request.
+
+use futures::{stream, StreamExt, TryFutureExt, TryStreamExt};
+
+use super::transfer::{retrying, Tracker, TransferError, TransferManager};
+use crate::generated::{
+    AbortMultipartUploadRequest, CompleteMultipartUploadRequest, CompletedMultipartUpload,
+    CompletedPart, CopyObjectOutput, CopyObjectRequest, CopyObjectResult,
+    CreateMultipartUploadRequest, HeadObjectOutput, HeadObjectRequest, UploadPartCopyRequest, S3,
+};
+
+/// The largest object a single `CopyObject` request can copy.
+pub const MAX_COPY_OBJECT_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+impl<C> TransferManager<C>
+where
+    C: S3 + Send + Sync + 'static,
+{
+    /// Copies an object like `copy_object`, with a single `CopyObject` request if the source is
+    /// at most the copy threshold and with a multipart upload of `UploadPartCopy` requests for
+    /// byte ranges of the source otherwise, `concurrency` at a time.
+    ///
+    /// Multipart copies carry over the content headers, metadata, storage class and website
+    /// redirect of the source unless `request` replaces them, as `CopyObject` does: with a
+    /// metadata directive of `REPLACE` the content headers and metadata of `request` are used,
+    /// and its storage class and website redirect whenever they are set. Tags are only set when
+    /// the tagging directive is `REPLACE`. The parts are copied on the condition that the source
+    /// keeps the entity tag it had when the copy started, and the upload is aborted if a part
+    /// fails for good.
+    pub async fn copy_object_multipart(
+        &self,
+        request: CopyObjectRequest,
+    ) -> Result<CopyObjectOutput, TransferError> {
+        let head = retrying(self.part_retries, || {
+            self.client
+                .head_object(source_head_object_request(&request))
+                .map_err(TransferError::HeadObject)
+        })
+        .await?;
+        let size = head.content_length.unwrap_or(0) as u64;
+        let tracker = self.tracker(Some(size));
+        if size <= self.copy_threshold {
+            let output = retrying(self.part_retries, || {
+                self.client
+                    .copy_object(request.clone())
+                    .map_err(TransferError::CopyObject)
+            })
+            .await?;
+            tracker.add(size);
+            return Ok(output);
+        }
+
+        let created = self
+            .client
+            .create_multipart_upload(copy_multipart_upload_request(&request, &head))
+            .await
+            .map_err(TransferError::CreateMultipartUpload)?;
+        let upload_id = created.upload_id.unwrap_or_default();
+        match self
+            .copy_parts(&request, &head, &upload_id, size, &tracker)
+            .await
+        {
+            Ok(output) => Ok(output),
+            Err(error) => {
+                let _ = self
+                    .client
+                    .abort_multipart_upload(AbortMultipartUploadRequest {
+                        bucket: request.bucket.clone(),
+                        key: request.key.clone(),
+                        request_payer: request.request_payer.clone(),
+                        upload_id,
+                    })
+                    .await;
+                Err(error)
+            }
+        }
+    }
+
+    async fn copy_parts(
+        &self,
+        request: &CopyObjectRequest,
+        head: &HeadObjectOutput,
+        upload_id: &str,
+        size: u64,
+        tracker: &Tracker,
+    ) -> Result<CopyObjectOutput, TransferError> {
+        let part_size = self.part_size_for(Some(size)) as u64;
+        let ranges = (0..size)
+            .step_by(part_size as usize)
+            .enumerate()
+            .map(|(index, start)| (index as i64 + 1, start, (start + part_size).min(size) - 1));
+        let mut completed: Vec<CompletedPart> = stream::iter(ranges)
+            .map(|(part_number, start, end)| {
+                self.copy_part(request, head, upload_id, part_number, start, end, tracker)
+            })
+            .buffer_unordered(self.concurrency)
+            .try_collect()
+            .await?;
+        completed.sort_by_key(|part| part.part_number);
+
+        let output = self
+            .client
+            .complete_multipart_upload(CompleteMultipartUploadRequest {
+                bucket: request.bucket.clone(),
+                key: request.key.clone(),
+                multipart_upload: Some(CompletedMultipartUpload {
+                    parts: Some(completed),
+                }),
+                request_payer: request.request_payer.clone(),
+                upload_id: upload_id.to_owned(),
+            })
+            .await
+            .map_err(TransferError::CompleteMultipartUpload)?;
+        Ok(CopyObjectOutput {
+            copy_object_result: Some(CopyObjectResult {
+                e_tag: output.e_tag,
+                last_modified: None,
+            }),
+            copy_source_version_id: head.version_id.clone(),
+            expiration: output.expiration,
+            request_charged: output.request_charged,
+            ssekms_key_id: output.ssekms_key_id,
+            server_side_encryption: output.server_side_encryption,
+            version_id: output.version_id,
+            ..Default::default()
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn copy_part(
+        &self,
+        request: &CopyObjectRequest,
+        head: &HeadObjectOutput,
+        upload_id: &str,
+        part_number: i64,
+        start: u64,
+        end: u64,
+        tracker: &Tracker,
+    ) -> Result<CompletedPart, TransferError> {
+        let output = retrying(self.part_retries, || {
+            self.client
+                .upload_part_copy(UploadPartCopyRequest {
+                    bucket: request.bucket.clone(),
+                    copy_source: request.copy_source.clone(),
+                    copy_source_if_match: request
+                        .copy_source_if_match
+                        .clone()
+                        .or_else(|| head.e_tag.clone()),
+                    copy_source_range: Some(format!("bytes={}-{}", start, end)),
+                    copy_source_sse_customer_algorithm: request
+                        .copy_source_sse_customer_algorithm
+                        .clone(),
+                    copy_source_sse_customer_key: request.copy_source_sse_customer_key.clone(),
+                    copy_source_sse_customer_key_md5: request
+                        .copy_source_sse_customer_key_md5
+                        .clone(),
+                    key: request.key.clone(),
+                    part_number,
+                    request_payer: request.request_payer.clone(),
+                    sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+                    sse_customer_key: request.sse_customer_key.clone(),
+                    sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+                    upload_id: upload_id.to_owned(),
+                    ..Default::default()
+                })
+                .map_err(TransferError::UploadPartCopy)
+        })
+        .await?;
+        tracker.add(end - start + 1);
+        Ok(CompletedPart {
+            e_tag: output.copy_part_result.and_then(|result| result.e_tag),
+            part_number: Some(part_number),
+        })
+    }
+}
+
+/// The `HeadObject` request of the source of a copy, whose `copy_source` is
+/// `bucket/key?versionId=id` with a URL encoded key and an optional version.
+fn source_head_object_request(request: &CopyObjectRequest) -> HeadObjectRequest {
+    let source = request.copy_source.trim_start_matches('/');
+    let (path, version_id) = match source.find("?versionId=") {
+        Some(query) => (
+            &source[..query],
+            Some(source[query + "?versionId=".len()..].to_owned()),
+        ),
+        None => (source, None),
+    };
+    let (bucket, key) = match path.find('/') {
+        Some(slash) => (&path[..slash], &path[slash + 1..]),
+        None => (path, ""),
+    };
+    HeadObjectRequest {
+        bucket: decode(bucket),
+        if_match: request.copy_source_if_match.clone(),
+        if_modified_since: request.copy_source_if_modified_since.clone(),
+        if_none_match: request.copy_source_if_none_match.clone(),
+        if_unmodified_since: request.copy_source_if_unmodified_since.clone(),
+        key: decode(key),
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.copy_source_sse_customer_algorithm.clone(),
+        sse_customer_key: request.copy_source_sse_customer_key.clone(),
+        sse_customer_key_md5: request.copy_source_sse_customer_key_md5.clone(),
+        version_id: version_id.map(|version_id| decode(&version_id)),
+        ..Default::default()
+    }
+}
+
+/// The `CreateMultipartUpload` request of a copy of the object `head` describes.
+fn copy_multipart_upload_request(
+    request: &CopyObjectRequest,
+    head: &HeadObjectOutput,
+) -> CreateMultipartUploadRequest {
+    let replace = |directive: &Option<String>| directive.as_deref() == Some("REPLACE");
+    let (cache_control, content_disposition, content_encoding, content_language, content_type) =
+        if replace(&request.metadata_directive) {
+            (
+                request.cache_control.clone(),
+                request.content_disposition.clone(),
+                request.content_encoding.clone(),
+                request.content_language.clone(),
+                request.content_type.clone(),
+            )
+        } else {
+            (
+                head.cache_control.clone(),
+                head.content_disposition.clone(),
+                head.content_encoding.clone(),
+                head.content_language.clone(),
+                head.content_type.clone(),
+            )
+        };
+    let (expires, metadata) = if replace(&request.metadata_directive) {
+        (request.expires.clone(), request.metadata.clone())
+    } else {
+        (head.expires.clone(), head.metadata.clone())
+    };
+    CreateMultipartUploadRequest {
+        acl: request.acl.clone(),
+        bucket: request.bucket.clone(),
+        bucket_key_enabled: request.bucket_key_enabled,
+        cache_control,
+        content_disposition,
+        content_encoding,
+        content_language,
+        content_type,
+        expires,
+        grant_full_control: request.grant_full_control.clone(),
+        grant_read: request.grant_read.clone(),
+        grant_read_acp: request.grant_read_acp.clone(),
+        grant_write_acp: request.grant_write_acp.clone(),
+        key: request.key.clone(),
+        metadata,
+        object_lock_legal_hold_status: request.object_lock_legal_hold_status.clone(),
+        object_lock_mode: request.object_lock_mode.clone(),
+        object_lock_retain_until_date: request.object_lock_retain_until_date.clone(),
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+        sse_customer_key: request.sse_customer_key.clone(),
+        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+        ssekms_encryption_context: request.ssekms_encryption_context.clone(),
+        ssekms_key_id: request.ssekms_key_id.clone(),
+        server_side_encryption: request.server_side_encryption.clone(),
+        storage_class: request
+            .storage_class
+            .clone()
+            .or_else(|| head.storage_class.clone()),
+        tagging: if replace(&request.tagging_directive) {
+            request.tagging.clone()
+        } else {
+            None
+        },
+        website_redirect_location: request
+            .website_redirect_location
+            .clone()
+            .or_else(|| head.website_redirect_location.clone()),
+    }
+}
+
+/// Decodes the percent encoded bytes of `encoded`.
+fn decode(encoded: &str) -> String {
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom::fake_s3::FakeS3;
+    use crate::custom::util::encode_key;
+
+    fn copy(source: &str) -> CopyObjectRequest {
+        CopyObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "copy".to_owned(),
+            copy_source: encode_key(source),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parses_copy_sources() {
+        let head = source_head_object_request(&CopyObjectRequest {
+            copy_source: "/source/a%20dir/k%C3%A9y?versionId=v%2B1".to_owned(),
+            ..Default::default()
+        });
+        assert_eq!(head.bucket, "source");
+        assert_eq!(head.key, "a dir/kéy");
+        assert_eq!(head.version_id.as_deref(), Some("v+1"));
+    }
+
+    #[tokio::test]
+    async fn copies_small_objects_with_one_request() {
+        let s3 = FakeS3::default();
+        s3.state()
+            .objects
+            .insert("/bucket/original".to_owned(), b"0123456789".to_vec());
+        let mut manager = TransferManager::new(s3.client());
+        manager.set_copy_threshold(10);
+        manager
+            .copy_object_multipart(copy("bucket/original"))
+            .await
+            .unwrap();
+        let state = s3.state();
+        assert_eq!(
+            state.requests,
+            vec!["HEAD /bucket/original", "PUT /bucket/copy"]
+        );
+        assert_eq!(state.objects["/bucket/copy"], b"0123456789");
+    }
+
+    #[tokio::test]
+    async fn copies_large_objects_in_parts_with_their_metadata() {
+        let s3 = FakeS3::default();
+        s3.state()
+            .objects
+            .insert("/bucket/original".to_owned(), b"0123456789ab".to_vec());
+        s3.state().headers.insert(
+            "/bucket/original".to_owned(),
+            vec![
+                ("content-type".to_owned(), "text/plain".to_owned()),
+                ("x-amz-meta-owner".to_owned(), "me".to_owned()),
+                ("x-amz-storage-class".to_owned(), "STANDARD_IA".to_owned()),
+            ],
+        );
+        s3.state().failures = 1;
+        let mut manager = TransferManager::new(s3.client());
+        manager.set_copy_threshold(10);
+        manager.set_part_size(5);
+        let output = manager
+            .copy_object_multipart(copy("bucket/original"))
+            .await
+            .unwrap();
+        assert!(output
+            .copy_object_result
+            .unwrap()
+            .e_tag
+            .unwrap()
+            .ends_with("-3\""));
+
+        let state = s3.state();
+        assert_eq!(state.objects["/bucket/copy"], b"0123456789ab");
+        let mut headers = state.headers["/bucket/copy"].clone();
+        headers.sort();
+        assert_eq!(
+            headers,
+            vec![
+                ("content-type".to_owned(), "text/plain".to_owned()),
+                ("x-amz-meta-owner".to_owned(), "me".to_owned()),
+                ("x-amz-storage-class".to_owned(), "STANDARD_IA".to_owned()),
+            ]
+        );
+        let parts = state
+            .requests
+            .iter()
+            .filter(|request| request.ends_with("?uploadId?partNumber"))
+            .count();
+        // Three parts, one of which failed once.
+        assert_eq!(parts, 4);
+    }
+
+    #[tokio::test]
+    async fn aborts_failed_copies() {
+        let s3 = FakeS3::default();
+        s3.state()
+            .objects
+            .insert("/bucket/original".to_owned(), b"0123456789ab".to_vec());
+        let mut manager = TransferManager::new(s3.client());
+        manager.set_copy_threshold(10);
+        manager.set_part_size(5);
+        let mut request = copy("bucket/original");
+        request.copy_source_if_match = Some("\"other\"".to_owned());
+        let error = manager.copy_object_multipart(request).await;
+        assert!(matches!(error, Err(TransferError::HeadObject(_))));
+
+        let mut request = copy("bucket/original");
+        request.metadata_directive = Some("REPLACE".to_owned());
+        request.content_type = Some("text/csv".to_owned());
+        s3.state().failing_part = Some(2);
+        let error = manager.copy_object_multipart(request).await;
+        assert!(matches!(error, Err(TransferError::UploadPartCopy(_))));
+        let state = s3.state();
+        assert!(state.uploads.is_empty());
+        assert!(!state.objects.contains_key("/bucket/copy"));
+    }
+}
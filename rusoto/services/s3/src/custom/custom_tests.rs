@@ -6,7 +6,7 @@ use self::rusoto_mock::*;
 use bytes::BytesMut;
 use futures::TryStreamExt;
 use rusoto_core::signature::SignedRequest;
-use rusoto_core::{Region, RusotoError};
+use rusoto_core::{ErrorMetadata, Region, RusotoError};
 
 #[tokio::test]
 async fn test_multipart_upload_copy_response() {
@@ -37,6 +37,72 @@ async fn test_multipart_upload_copy_response() {
     );
 }
 
+#[tokio::test]
+async fn test_errors_in_ok_responses() {
+    let error = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Error>
+            <Code>InternalError</Code>
+            <Message>We encountered an internal error. Please try again.</Message>
+            <RequestId>656c76696e6727732072657175657374</RequestId>
+        </Error>"#;
+    let client = || {
+        let mock = MockRequestDispatcher::with_status(200).with_body(error);
+        S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1)
+    };
+    let assert_internal_error = |metadata: ErrorMetadata| {
+        assert_eq!(metadata.code(), Some("InternalError"));
+        assert_eq!(
+            metadata.request_id(),
+            Some("656c76696e6727732072657175657374")
+        );
+    };
+
+    let copy_object = client()
+        .copy_object(CopyObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            copy_source: "source/key".to_owned(),
+            ..Default::default()
+        })
+        .await;
+    match copy_object {
+        Err(RusotoError::Service(CopyObjectError::Unhandled(_), metadata)) => {
+            assert_internal_error(metadata)
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let upload_part_copy = client()
+        .upload_part_copy(UploadPartCopyRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            copy_source: "source/key".to_owned(),
+            part_number: 1,
+            upload_id: "upload".to_owned(),
+            ..Default::default()
+        })
+        .await;
+    match upload_part_copy {
+        Err(RusotoError::Service(UploadPartCopyError::Unhandled(_), metadata)) => {
+            assert_internal_error(metadata)
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let complete = client()
+        .complete_multipart_upload(CompleteMultipartUploadRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            upload_id: "upload".to_owned(),
+            ..Default::default()
+        })
+        .await;
+    match complete {
+        Err(RusotoError::Service(CompleteMultipartUploadError::Unhandled(_), metadata)) => {
+            assert_internal_error(metadata)
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
 #[tokio::test]
 async fn test_list_object_versions_with_multiple_versions() {
     let mock = MockRequestDispatcher::with_status(200).with_body(
@@ -513,10 +579,11 @@ async fn test_parse_no_such_bucket_error() {
     let result = client.list_objects_v2(request).await;
     assert!(result.is_err());
     let err = result.err().unwrap();
-    assert_eq!(
-        RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
-            "The specified bucket does not exist".to_owned()
-        )),
-        err
-    );
+    match err {
+        RusotoError::Service(ListObjectsV2Error::NoSuchBucket(message), metadata) => {
+            assert_eq!(message, "The specified bucket does not exist");
+            assert_eq!(metadata.request_id(), Some("4442587FB7D0A2F9"));
+        }
+        err => panic!("unexpected error: {:?}", err),
+    }
 }
@@ -0,0 +1,199 @@
+//! Deletions of any number of objects in batches of `DeleteObjects` requests.
+
+use futures::{Stream, StreamExt, TryFutureExt};
+use rusoto_core::RusotoError;
+
+use super::transfer::{retrying, TransferError, TransferManager};
+use crate::generated::{
+    Delete, DeleteObjectsRequest, DeletedObject, ObjectIdentifier, S3Error, S3,
+};
+
+/// The most keys a single `DeleteObjects` request can delete.
+pub const MAX_DELETE_OBJECTS_KEYS: usize = 1000;
+
+impl From<String> for ObjectIdentifier {
+    fn from(key: String) -> ObjectIdentifier {
+        ObjectIdentifier {
+            key,
+            version_id: None,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ObjectIdentifier {
+    fn from(key: &'a str) -> ObjectIdentifier {
+        ObjectIdentifier::from(key.to_owned())
+    }
+}
+
+/// The outcome of deleting objects with `TransferManager::delete_objects_chunked`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeleteObjectsReport {
+    /// The objects deleted.
+    pub deleted: Vec<DeletedObject>,
+    /// The objects that could not be deleted, with the error of each.
+    pub errors: Vec<S3Error>,
+}
+
+impl DeleteObjectsReport {
+    /// Whether every object was deleted.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl<C> TransferManager<C>
+where
+    C: S3 + Send + Sync + 'static,
+{
+    /// Deletes the objects `keys` of `bucket`, in `DeleteObjects` requests of up to 1000 keys,
+    /// `concurrency` at a time, and reports which objects were deleted and which were not.
+    ///
+    /// `keys` may be any stream, such as the keys of a listing, or `stream::iter` of keys or
+    /// `ObjectIdentifier`s. S3 answers a `DeleteObjects` request that deleted only some of its
+    /// objects with success, listing the errors of the others, so the outcome of every object
+    /// is looked at: the errors of the objects S3 did not delete are all in the report rather
+    /// than failing the deletion. Failed requests are retried up to the part retries, and the
+    /// objects of a request that fails for good are reported with the code and message of the
+    /// error.
+    ///
+    /// ```rust,ignore
+    /// let report = manager
+    ///     .delete_objects_chunked("bucket", stream::iter(keys))
+    ///     .await;
+    /// for error in &report.errors {
+    ///     eprintln!("{:?}: {:?}", error.key, error.message);
+    /// }
+    /// ```
+    pub async fn delete_objects_chunked<S, K>(&self, bucket: &str, keys: S) -> DeleteObjectsReport
+    where
+        S: Stream<Item = K>,
+        K: Into<ObjectIdentifier>,
+    {
+        let batches = keys
+            .map(Into::into)
+            .chunks(MAX_DELETE_OBJECTS_KEYS)
+            .map(|objects| self.delete_batch(bucket, objects))
+            .buffer_unordered(self.concurrency);
+        batches
+            .fold(DeleteObjectsReport::default(), |mut report, batch| async {
+                report.deleted.extend(batch.deleted);
+                report.errors.extend(batch.errors);
+                report
+            })
+            .await
+    }
+
+    async fn delete_batch(
+        &self,
+        bucket: &str,
+        objects: Vec<ObjectIdentifier>,
+    ) -> DeleteObjectsReport {
+        let output = retrying(self.part_retries, || {
+            self.client
+                .delete_objects(DeleteObjectsRequest {
+                    bucket: bucket.to_owned(),
+                    delete: Delete {
+                        objects: objects.clone(),
+                        quiet: None,
+                    },
+                    ..Default::default()
+                })
+                .map_err(TransferError::DeleteObjects)
+        })
+        .await;
+        match output {
+            Ok(output) => DeleteObjectsReport {
+                deleted: output.deleted.unwrap_or_default(),
+                errors: output.errors.unwrap_or_default(),
+            },
+            Err(error) => {
+                let code = match error {
+                    TransferError::DeleteObjects(RusotoError::Service(_, ref metadata)) => {
+                        metadata.code().map(str::to_owned)
+                    }
+                    _ => None,
+                };
+                let message = error.to_string();
+                DeleteObjectsReport {
+                    deleted: Vec::new(),
+                    errors: objects
+                        .into_iter()
+                        .map(|object| S3Error {
+                            code: code.clone(),
+                            key: Some(object.key),
+                            message: Some(message.clone()),
+                            version_id: object.version_id,
+                        })
+                        .collect(),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom::fake_s3::FakeS3;
+    use futures::stream;
+
+    fn keys(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("key-{:04}", i)).collect()
+    }
+
+    #[tokio::test]
+    async fn deletes_objects_in_batches() {
+        let s3 = FakeS3::default();
+        for key in keys(2500) {
+            s3.state()
+                .objects
+                .insert(format!("/bucket/{}", key), b"data".to_vec());
+        }
+        s3.state().undeletable.insert("/bucket/key-1234".to_owned());
+        let mut manager = TransferManager::new(s3.client());
+        manager.set_concurrency(2);
+
+        let report = manager
+            .delete_objects_chunked("bucket", stream::iter(keys(2500)))
+            .await;
+        assert!(!report.is_complete());
+        assert_eq!(report.deleted.len(), 2499);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].key.as_deref(), Some("key-1234"));
+        assert_eq!(report.errors[0].code.as_deref(), Some("AccessDenied"));
+
+        let state = s3.state();
+        assert_eq!(state.requests, vec!["POST /bucket?delete"; 3]);
+        assert_eq!(
+            state.objects.keys().collect::<Vec<_>>(),
+            vec!["/bucket/key-1234"]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_the_objects_of_failed_requests() {
+        let s3 = FakeS3::default();
+        s3.state().failures = 2;
+        let mut manager = TransferManager::new(s3.client());
+        manager.set_part_retries(1);
+
+        let report = manager
+            .delete_objects_chunked("bucket", stream::iter(vec!["a", "b"]))
+            .await;
+        assert!(report.deleted.is_empty());
+        let failed: Vec<_> = report
+            .errors
+            .iter()
+            .map(|error| (error.key.as_deref(), error.code.as_deref()))
+            .collect();
+        assert_eq!(
+            failed,
+            vec![
+                (Some("a"), Some("InternalError")),
+                (Some("b"), Some("InternalError"))
+            ]
+        );
+        assert_eq!(s3.state().requests.len(), 2);
+    }
+}
@@ -0,0 +1,483 @@
+//! Client-side encryption of objects, in the envelope format of the AWS encryption clients.
+//!
+//! Every object is encrypted with AES-256-GCM under its own data key, itself encrypted by KMS,
+//! and the encrypted data key, the IV and the algorithms are stored in the metadata of the
+//! object as the version 2 format of the Java, Python and other encryption clients has them,
+//! so that objects put by any of them can be got by the others.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use bytes::{Bytes, BytesMut};
+use futures::StreamExt;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use rusoto_core::{ByteStream, RusotoError};
+use rusoto_kms::{DecryptError, DecryptRequest, GenerateDataKeyError, GenerateDataKeyRequest, Kms};
+
+use crate::generated::{
+    GetObjectError, GetObjectOutput, GetObjectRequest, PutObjectError, PutObjectOutput,
+    PutObjectRequest, S3,
+};
+
+/// The encrypted data key of the version 2 format.
+const KEY_V2: &str = "x-amz-key-v2";
+/// The encrypted data key of the version 1 format, which only KMS wrapped AES/GCM data keys
+/// are read from.
+const KEY_V1: &str = "x-amz-key";
+const IV: &str = "x-amz-iv";
+const MATERIALS_DESCRIPTION: &str = "x-amz-matdesc";
+const CONTENT_ALGORITHM: &str = "x-amz-cek-alg";
+const WRAP_ALGORITHM: &str = "x-amz-wrap-alg";
+const TAG_LENGTH: &str = "x-amz-tag-len";
+const UNENCRYPTED_CONTENT_LENGTH: &str = "x-amz-unencrypted-content-length";
+
+const AES_GCM: &str = "AES/GCM/NoPadding";
+/// The wrap algorithm of data keys encrypted by KMS with the content algorithm in their
+/// encryption context, as the version 2 format requires.
+const KMS_CONTEXT: &str = "kms+context";
+/// The wrap algorithm of data keys encrypted by KMS with the materials description as their
+/// encryption context, as the version 1 format has them.
+const KMS: &str = "kms";
+/// The key of the encryption context binding the content algorithm to the data key.
+const CONTENT_ALGORITHM_CONTEXT: &str = "aws:x-amz-cek-alg";
+const TAG_BITS: &str = "128";
+
+/// An error putting or getting an encrypted object.
+#[derive(Debug)]
+pub enum EncryptionError {
+    /// A `PutObject` request failed.
+    PutObject(RusotoError<PutObjectError>),
+    /// A `GetObject` request failed.
+    GetObject(RusotoError<GetObjectError>),
+    /// Generating a data key with KMS failed.
+    GenerateDataKey(RusotoError<GenerateDataKeyError>),
+    /// Decrypting a data key with KMS failed.
+    Decrypt(RusotoError<DecryptError>),
+    /// Reading the body to encrypt or the body to decrypt failed.
+    Io(io::Error),
+    /// The request or the object isn't one the encryption client handles: the object isn't
+    /// encrypted, or with a format or algorithm the client doesn't read, or a range is asked for.
+    Unsupported(String),
+    /// The data of an object doesn't authenticate with its data key: its data or metadata were
+    /// modified.
+    Authentication,
+}
+
+impl fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncryptionError::PutObject(e) => write!(f, "PutObject failed: {}", e),
+            EncryptionError::GetObject(e) => write!(f, "GetObject failed: {}", e),
+            EncryptionError::GenerateDataKey(e) => write!(f, "GenerateDataKey failed: {}", e),
+            EncryptionError::Decrypt(e) => write!(f, "Decrypt failed: {}", e),
+            EncryptionError::Io(e) => write!(f, "{}", e),
+            EncryptionError::Unsupported(message) => write!(f, "{}", message),
+            EncryptionError::Authentication => {
+                write!(f, "the encrypted object failed to authenticate")
+            }
+        }
+    }
+}
+
+impl Error for EncryptionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EncryptionError::PutObject(e) => Some(e),
+            EncryptionError::GetObject(e) => Some(e),
+            EncryptionError::GenerateDataKey(e) => Some(e),
+            EncryptionError::Decrypt(e) => Some(e),
+            EncryptionError::Io(e) => Some(e),
+            EncryptionError::Unsupported(_) | EncryptionError::Authentication => None,
+        }
+    }
+}
+
+/// Puts objects encrypted on the client and gets them decrypted, with data keys generated and
+/// decrypted by KMS.
+///
+/// ```rust,ignore
+/// let client = S3EncryptionClient::new(s3, kms, "alias/uploads");
+/// client.put_object(PutObjectRequest {
+///     bucket: "bucket".to_owned(),
+///     key: "secret".to_owned(),
+///     body: Some(data.into()),
+///     ..Default::default()
+/// }).await?;
+/// ```
+///
+/// Objects are encrypted and decrypted in memory, as AES-GCM authenticates them only once
+/// all of their data is read.
+pub struct S3EncryptionClient<C, K> {
+    s3: C,
+    kms: K,
+    kms_key_id: String,
+    encryption_context: HashMap<String, String>,
+}
+
+impl<C, K> S3EncryptionClient<C, K>
+where
+    C: S3,
+    K: Kms,
+{
+    /// An encryption client encrypting the data keys of the objects it puts with the KMS key
+    /// `kms_key_id`.
+    pub fn new<S: Into<String>>(s3: C, kms: K, kms_key_id: S) -> S3EncryptionClient<C, K> {
+        S3EncryptionClient {
+            s3,
+            kms,
+            kms_key_id: kms_key_id.into(),
+            encryption_context: HashMap::new(),
+        }
+    }
+
+    /// Sets the encryption context the data keys of the objects put are encrypted with, which
+    /// is stored in their metadata as their materials description.
+    pub fn set_encryption_context(&mut self, encryption_context: HashMap<String, String>) {
+        self.encryption_context = encryption_context;
+    }
+
+    /// Puts an object like `S3::put_object`, with its body encrypted under a new data key
+    /// and the envelope of the data key in its metadata.
+    pub async fn put_object(
+        &self,
+        mut request: PutObjectRequest,
+    ) -> Result<PutObjectOutput, EncryptionError> {
+        let mut context = self.encryption_context.clone();
+        context.insert(CONTENT_ALGORITHM_CONTEXT.to_owned(), AES_GCM.to_owned());
+        let data_key = self
+            .kms
+            .generate_data_key(GenerateDataKeyRequest {
+                encryption_context: Some(context.clone()),
+                key_id: self.kms_key_id.clone(),
+                key_spec: Some("AES_256".to_owned()),
+                ..Default::default()
+            })
+            .await
+            .map_err(EncryptionError::GenerateDataKey)?;
+        let (plaintext_key, encrypted_key) = match (data_key.plaintext, data_key.ciphertext_blob) {
+            (Some(plaintext), Some(ciphertext)) => (plaintext, ciphertext),
+            _ => {
+                return Err(EncryptionError::Unsupported(
+                    "GenerateDataKey returned no data key".to_owned(),
+                ))
+            }
+        };
+
+        let mut iv = [0; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut iv)
+            .map_err(|_| EncryptionError::Unsupported("no random IV available".to_owned()))?;
+        let mut data = read_body(request.body.take()).await?.to_vec();
+        let plaintext_length = data.len();
+        content_key(&plaintext_key)
+            .map_err(EncryptionError::Unsupported)?
+            .seal_in_place_append_tag(Nonce::assume_unique_for_key(iv), Aad::empty(), &mut data)
+            .map_err(|_| EncryptionError::Authentication)?;
+
+        let metadata = request.metadata.get_or_insert_with(HashMap::new);
+        metadata.insert(KEY_V2.to_owned(), base64::encode(&encrypted_key));
+        metadata.insert(IV.to_owned(), base64::encode(&iv));
+        metadata.insert(
+            MATERIALS_DESCRIPTION.to_owned(),
+            serde_json::to_string(&context).expect("a map of strings serializes"),
+        );
+        metadata.insert(CONTENT_ALGORITHM.to_owned(), AES_GCM.to_owned());
+        metadata.insert(WRAP_ALGORITHM.to_owned(), KMS_CONTEXT.to_owned());
+        metadata.insert(TAG_LENGTH.to_owned(), TAG_BITS.to_owned());
+        metadata.insert(
+            UNENCRYPTED_CONTENT_LENGTH.to_owned(),
+            plaintext_length.to_string(),
+        );
+        // The content length and digest of the request are the ones of the plaintext.
+        request.content_length = Some(data.len() as i64);
+        request.content_md5 = None;
+        request.body = Some(data.into());
+        self.s3
+            .put_object(request)
+            .await
+            .map_err(EncryptionError::PutObject)
+    }
+
+    /// Gets an object like `S3::get_object`, with its body decrypted with the data key of the
+    /// envelope in its metadata. Ranges and parts of encrypted objects can't be got, as AES-GCM
+    /// authenticates whole objects.
+    pub async fn get_object(
+        &self,
+        request: GetObjectRequest,
+    ) -> Result<GetObjectOutput, EncryptionError> {
+        if request.range.is_some() || request.part_number.is_some() {
+            return Err(EncryptionError::Unsupported(
+                "ranges of encrypted objects can't be got".to_owned(),
+            ));
+        }
+        let mut output = self
+            .s3
+            .get_object(request)
+            .await
+            .map_err(EncryptionError::GetObject)?;
+        let metadata = output.metadata.clone().unwrap_or_default();
+        let envelope = Envelope::from_metadata(&metadata).map_err(EncryptionError::Unsupported)?;
+        let plaintext_key = self
+            .kms
+            .decrypt(DecryptRequest {
+                ciphertext_blob: envelope.encrypted_key,
+                encryption_context: Some(envelope.context),
+                ..Default::default()
+            })
+            .await
+            .map_err(EncryptionError::Decrypt)?
+            .plaintext
+            .ok_or_else(|| {
+                EncryptionError::Unsupported("Decrypt returned no data key".to_owned())
+            })?;
+
+        let mut data = read_body(output.body.take()).await?.to_vec();
+        let plaintext_length = content_key(&plaintext_key)
+            .map_err(EncryptionError::Unsupported)?
+            .open_in_place(
+                Nonce::assume_unique_for_key(envelope.iv),
+                Aad::empty(),
+                &mut data,
+            )
+            .map_err(|_| EncryptionError::Authentication)?
+            .len();
+        data.truncate(plaintext_length);
+        output.content_length = Some(plaintext_length as i64);
+        output.body = Some(data.into());
+        Ok(output)
+    }
+}
+
+/// The envelope of the data key of an encrypted object.
+struct Envelope {
+    encrypted_key: Bytes,
+    iv: [u8; NONCE_LEN],
+    context: HashMap<String, String>,
+}
+
+impl Envelope {
+    /// The envelope in the metadata of an object, if it is AES/GCM content with a data key
+    /// encrypted by KMS, or why the object can't be decrypted.
+    fn from_metadata(metadata: &HashMap<String, String>) -> Result<Envelope, String> {
+        let field = |name: &str| {
+            metadata
+                .get(name)
+                .map(String::as_str)
+                .ok_or_else(|| format!("the object has no {} metadata", name))
+        };
+        let decode = |name: &str, value: &str| {
+            base64::decode(value).map_err(|_| format!("the {} metadata isn't base64", name))
+        };
+
+        let key_name = if metadata.contains_key(KEY_V2) {
+            KEY_V2
+        } else if metadata.contains_key(KEY_V1) {
+            KEY_V1
+        } else {
+            return Err("the object isn't encrypted by an encryption client".to_owned());
+        };
+        let encrypted_key = decode(key_name, field(key_name)?)?;
+        let content_algorithm = field(CONTENT_ALGORITHM)?;
+        if content_algorithm != AES_GCM {
+            return Err(format!(
+                "content encrypted with {} can't be decrypted",
+                content_algorithm
+            ));
+        }
+        if let Some(tag_length) = metadata.get(TAG_LENGTH) {
+            if tag_length != TAG_BITS {
+                return Err(format!("{} bit tags can't be checked", tag_length));
+            }
+        }
+        let iv = decode(IV, field(IV)?)?;
+        if iv.len() != NONCE_LEN {
+            return Err(format!("{} byte IVs can't be used", iv.len()));
+        }
+        let context: HashMap<String, String> = serde_json::from_str(field(MATERIALS_DESCRIPTION)?)
+            .map_err(|_| {
+                format!(
+                    "the {} metadata isn't a JSON map of strings",
+                    MATERIALS_DESCRIPTION
+                )
+            })?;
+        match field(WRAP_ALGORITHM)? {
+            KMS_CONTEXT => {
+                if context.get(CONTENT_ALGORITHM_CONTEXT).map(String::as_str) != Some(AES_GCM) {
+                    return Err(format!(
+                        "the encryption context doesn't bind the data key to {}",
+                        AES_GCM
+                    ));
+                }
+            }
+            KMS => {}
+            wrap_algorithm => {
+                return Err(format!(
+                    "data keys wrapped with {} can't be decrypted",
+                    wrap_algorithm
+                ))
+            }
+        }
+
+        let mut nonce = [0; NONCE_LEN];
+        nonce.copy_from_slice(&iv);
+        Ok(Envelope {
+            encrypted_key: encrypted_key.into(),
+            iv: nonce,
+            context,
+        })
+    }
+}
+
+fn content_key(data_key: &[u8]) -> Result<LessSafeKey, String> {
+    UnboundKey::new(&AES_256_GCM, data_key)
+        .map(LessSafeKey::new)
+        .map_err(|_| format!("{} byte data keys can't be used", data_key.len()))
+}
+
+async fn read_body(body: Option<ByteStream>) -> Result<Bytes, EncryptionError> {
+    let mut data = BytesMut::new();
+    if let Some(mut body) = body {
+        while let Some(chunk) = body.next().await {
+            data.extend_from_slice(&chunk.map_err(EncryptionError::Io)?);
+        }
+    }
+    Ok(data.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom::fake_s3::FakeS3;
+    use rusoto_core::Region;
+    use rusoto_kms::KmsClient;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    const DATA_KEY: [u8; 32] = [7; 32];
+    const ENCRYPTED_KEY: &[u8] = b"encrypted data key";
+
+    /// A KMS answering every request with `body`, checking it is sent `expected`.
+    fn kms(body: serde_json::Value, expected: serde_json::Value) -> KmsClient {
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_request_checker(move |request| {
+                let sent = match request.payload {
+                    Some(rusoto_core::signature::SignedRequestPayload::Buffer(ref body)) => {
+                        serde_json::from_slice::<serde_json::Value>(body).unwrap()
+                    }
+                    _ => panic!("no payload sent to KMS"),
+                };
+                for (name, value) in expected.as_object().unwrap() {
+                    assert_eq!(&sent[name], value, "{} sent to KMS", name);
+                }
+            })
+            .with_body(&body.to_string());
+        KmsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1)
+    }
+
+    fn context() -> serde_json::Value {
+        serde_json::json!({ "aws:x-amz-cek-alg": AES_GCM, "purpose": "test" })
+    }
+
+    fn put_client(s3: &FakeS3) -> S3EncryptionClient<crate::S3Client, KmsClient> {
+        let kms = kms(
+            serde_json::json!({
+                "CiphertextBlob": base64::encode(ENCRYPTED_KEY),
+                "KeyId": "key",
+                "Plaintext": base64::encode(&DATA_KEY),
+            }),
+            serde_json::json!({ "KeyId": "alias/key", "KeySpec": "AES_256", "EncryptionContext": context() }),
+        );
+        let mut client = S3EncryptionClient::new(s3.client(), kms, "alias/key");
+        let mut encryption_context = HashMap::new();
+        encryption_context.insert("purpose".to_owned(), "test".to_owned());
+        client.set_encryption_context(encryption_context);
+        client
+    }
+
+    fn get_client(s3: &FakeS3) -> S3EncryptionClient<crate::S3Client, KmsClient> {
+        let kms = kms(
+            serde_json::json!({ "KeyId": "key", "Plaintext": base64::encode(&DATA_KEY) }),
+            serde_json::json!({
+                "CiphertextBlob": base64::encode(ENCRYPTED_KEY),
+                "EncryptionContext": context(),
+            }),
+        );
+        S3EncryptionClient::new(s3.client(), kms, "alias/key")
+    }
+
+    fn get_request() -> GetObjectRequest {
+        GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "secret".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn puts_and_gets_encrypted_objects() {
+        let s3 = FakeS3::default();
+        put_client(&s3)
+            .put_object(PutObjectRequest {
+                bucket: "bucket".to_owned(),
+                key: "secret".to_owned(),
+                body: Some(b"attack at dawn".to_vec().into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let stored = s3.state().objects["/bucket/secret"].clone();
+        assert_eq!(stored.len(), 14 + 16);
+        assert!(!stored.starts_with(b"attack"));
+        let headers: HashMap<_, _> = s3.state().headers["/bucket/secret"]
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(headers["x-amz-meta-x-amz-cek-alg"], AES_GCM);
+        assert_eq!(headers["x-amz-meta-x-amz-wrap-alg"], "kms+context");
+        assert_eq!(headers["x-amz-meta-x-amz-tag-len"], "128");
+        assert_eq!(headers["x-amz-meta-x-amz-unencrypted-content-length"], "14");
+        assert_eq!(
+            headers["x-amz-meta-x-amz-key-v2"],
+            base64::encode(ENCRYPTED_KEY)
+        );
+        let description: serde_json::Value =
+            serde_json::from_str(&headers["x-amz-meta-x-amz-matdesc"]).unwrap();
+        assert_eq!(description, context());
+
+        let output = get_client(&s3).get_object(get_request()).await.unwrap();
+        assert_eq!(output.content_length, Some(14));
+        let body = read_body(output.body).await.unwrap();
+        assert_eq!(&body[..], b"attack at dawn");
+    }
+
+    #[tokio::test]
+    async fn rejects_modified_and_unencrypted_objects() {
+        let s3 = FakeS3::default();
+        put_client(&s3)
+            .put_object(PutObjectRequest {
+                bucket: "bucket".to_owned(),
+                key: "secret".to_owned(),
+                body: Some(b"attack at dawn".to_vec().into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        s3.state().objects.get_mut("/bucket/secret").unwrap()[0] ^= 1;
+        match get_client(&s3).get_object(get_request()).await {
+            Err(EncryptionError::Authentication) => {}
+            result => panic!("unexpected result {:?}", result.map(|_| ())),
+        }
+
+        s3.state()
+            .objects
+            .insert("/bucket/secret".to_owned(), b"plain".to_vec());
+        s3.state().headers.remove("/bucket/secret");
+        match get_client(&s3).get_object(get_request()).await {
+            Err(EncryptionError::Unsupported(_)) => {}
+            result => panic!("unexpected result {:?}", result.map(|_| ())),
+        }
+    }
+}
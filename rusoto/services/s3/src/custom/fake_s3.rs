@@ -0,0 +1,450 @@
+//! An in-memory S3, enough of it to test the helpers that chain several requests.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::Bytes;
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures::{FutureExt, TryStreamExt};
+use http::header::HeaderName;
+use http::{HeaderMap, Request, StatusCode};
+use rusoto_core::credential::StaticProvider;
+use rusoto_core::request::{DispatchSignedRequestFuture, HttpResponse};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{ByteStream, Client, DispatchSignedRequest, Region, RetryPolicy};
+
+use crate::generated::S3Client;
+
+#[derive(Default)]
+pub struct State {
+    /// The objects by `/bucket/key`.
+    pub objects: HashMap<String, Vec<u8>>,
+    /// The entity tags of the objects that are not the MD5 digest of their data.
+    pub etags: HashMap<String, String>,
+    /// The stored headers of the objects: their content type, storage class and metadata.
+    pub headers: HashMap<String, Vec<(String, String)>>,
+    /// The part sizes of the objects created by multipart uploads.
+    pub part_sizes: HashMap<String, Vec<usize>>,
+    /// The parts of the multipart uploads in progress by upload id.
+    pub uploads: HashMap<String, BTreeMap<i64, Vec<u8>>>,
+    /// When the multipart uploads in progress were initiated by upload id.
+    pub initiated: HashMap<String, DateTime<Utc>>,
+    /// The requests received, as `METHOD /bucket/key?param`.
+    pub requests: Vec<String>,
+    /// How many of the next `UploadPart` and ranged `GetObject` requests fail with a 500.
+    pub failures: usize,
+    /// The part number whose `UploadPart` requests fail with a 500.
+    pub failing_part: Option<i64>,
+    /// The objects `DeleteObjects` requests fail to delete with `AccessDenied`.
+    pub undeletable: HashSet<String>,
+    upload_headers: HashMap<String, Vec<(String, String)>>,
+    upload_paths: HashMap<String, String>,
+    next_upload: usize,
+}
+
+#[derive(Clone, Default)]
+pub struct FakeS3(pub Arc<Mutex<State>>);
+
+impl FakeS3 {
+    /// An S3 client of this fake, which doesn't retry failures itself.
+    pub fn client(&self) -> S3Client {
+        let credentials =
+            StaticProvider::new_minimal("AKIDEXAMPLE".to_owned(), "secret".to_owned());
+        let mut client = Client::new_with(credentials, self.clone());
+        client.set_retry_policy(RetryPolicy::none());
+        S3Client::new_with_client(client, Region::UsEast1)
+    }
+
+    pub fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.0.lock().unwrap()
+    }
+}
+
+fn etag(data: &[u8]) -> String {
+    format!("\"{:x}\"", md5::compute(data))
+}
+
+fn response(status: u16, headers: Vec<(&'static str, String)>, body: Vec<u8>) -> HttpResponse {
+    let mut header_map = HeaderMap::default();
+    for (name, value) in headers {
+        header_map.insert(name, value);
+    }
+    HttpResponse {
+        status: StatusCode::from_u16(status).unwrap(),
+        body: body.into(),
+        headers: header_map,
+    }
+}
+
+fn header(request: &SignedRequest, name: &str) -> Option<String> {
+    request
+        .headers()
+        .get(name)
+        .map(|values| String::from_utf8(values[0].clone()).unwrap())
+}
+
+/// The headers of `request` to store with the object it creates.
+fn stored_headers(request: &SignedRequest) -> Vec<(String, String)> {
+    request
+        .headers()
+        .keys()
+        .filter(|name| {
+            *name == "content-type"
+                || *name == "x-amz-storage-class"
+                || name.starts_with("x-amz-meta-")
+        })
+        .map(|name| (name.clone(), header(request, name).unwrap()))
+        .collect()
+}
+
+/// The path of the source of a copy.
+fn copy_source(request: &SignedRequest) -> Option<String> {
+    header(request, "x-amz-copy-source")
+        .map(|source| format!("/{}", source.trim_start_matches('/')))
+}
+
+fn param(request: &SignedRequest, name: &str) -> Option<String> {
+    request.params.get(name).cloned().flatten()
+}
+
+impl State {
+    fn fail(&mut self) -> bool {
+        if self.failures > 0 {
+            self.failures -= 1;
+            return true;
+        }
+        false
+    }
+
+    /// The data of the source of a copy, or the response of a failed precondition.
+    fn source(&self, request: &SignedRequest, source: &str) -> Result<Vec<u8>, Box<HttpResponse>> {
+        let data = match self.objects.get(source) {
+            Some(data) => data,
+            None => return Err(Box::new(response(404, vec![], vec![]))),
+        };
+        match header(request, "x-amz-copy-source-if-match") {
+            Some(tag) if tag != self.etag(source, data) => Err(Box::new(response(
+                412,
+                vec![],
+                b"<Error><Code>PreconditionFailed</Code></Error>".to_vec(),
+            ))),
+            _ => Ok(data.clone()),
+        }
+    }
+
+    fn etag(&self, path: &str, data: &[u8]) -> String {
+        self.etags.get(path).cloned().unwrap_or_else(|| etag(data))
+    }
+
+    fn handle(&mut self, request: &SignedRequest, body: Vec<u8>) -> HttpResponse {
+        let path = request.path().to_owned();
+        let has = |name: &str| request.params.contains_key(name);
+        let mut line = format!("{} {}", request.method(), path);
+        for name in &["delete", "uploads", "uploadId", "partNumber"] {
+            if has(name) {
+                line.push_str(&format!("?{}", name));
+            }
+        }
+        self.requests.push(line);
+
+        match request.method() {
+            "PUT" if has("uploadId") => {
+                let upload_id = param(request, "uploadId").unwrap();
+                let part_number = param(request, "partNumber").unwrap().parse().unwrap();
+                if self.fail() || self.failing_part == Some(part_number) {
+                    return response(
+                        500,
+                        vec![],
+                        b"<Error><Code>InternalError</Code></Error>".to_vec(),
+                    );
+                }
+                let copied = match copy_source(request) {
+                    Some(source) => match self.source(request, &source) {
+                        Ok(data) => {
+                            let range = header(request, "x-amz-copy-source-range").unwrap();
+                            let range = range.trim_start_matches("bytes=");
+                            let (start, end) = range.split_at(range.find('-').unwrap());
+                            let start: usize = start.parse().unwrap();
+                            let end: usize = end[1..].parse().unwrap();
+                            Some(data[start..=end].to_vec())
+                        }
+                        Err(response) => return *response,
+                    },
+                    None => None,
+                };
+                let copy = copied.is_some();
+                let body = copied.unwrap_or(body);
+                let tag = etag(&body);
+                match self.uploads.get_mut(&upload_id) {
+                    Some(parts) => {
+                        parts.insert(part_number, body);
+                        if copy {
+                            let body = format!(
+                                "<CopyPartResult><ETag>{}</ETag></CopyPartResult>",
+                                tag.replace('"', "&quot;")
+                            );
+                            return response(200, vec![], body.into_bytes());
+                        }
+                        response(200, vec![("ETag", tag)], vec![])
+                    }
+                    None => response(
+                        404,
+                        vec![],
+                        b"<Error><Code>NoSuchUpload</Code></Error>".to_vec(),
+                    ),
+                }
+            }
+            "PUT" if request.headers().contains_key("x-amz-copy-source") => {
+                let source = copy_source(request).unwrap();
+                let data = match self.source(request, &source) {
+                    Ok(data) => data,
+                    Err(response) => return *response,
+                };
+                let headers = match header(request, "x-amz-metadata-directive").as_deref() {
+                    Some("REPLACE") => stored_headers(request),
+                    _ => self.headers.get(&source).cloned().unwrap_or_default(),
+                };
+                let tag = etag(&data);
+                self.etags.remove(&path);
+                self.part_sizes.remove(&path);
+                self.headers.insert(path.clone(), headers);
+                self.objects.insert(path, data);
+                let body = format!(
+                    "<CopyObjectResult><ETag>{}</ETag></CopyObjectResult>",
+                    tag.replace('"', "&quot;")
+                );
+                response(200, vec![], body.into_bytes())
+            }
+            "PUT" => {
+                let tag = etag(&body);
+                self.etags.remove(&path);
+                self.part_sizes.remove(&path);
+                self.headers.insert(path.clone(), stored_headers(request));
+                self.objects.insert(path, body);
+                response(200, vec![("ETag", tag)], vec![])
+            }
+            "POST" if has("delete") => {
+                if self.fail() {
+                    return response(
+                        500,
+                        vec![],
+                        b"<Error><Code>InternalError</Code></Error>".to_vec(),
+                    );
+                }
+                let listed = String::from_utf8(body).unwrap();
+                let mut body = "<DeleteResult>".to_owned();
+                for rest in listed.split("<Key>").skip(1) {
+                    let key = &rest[..rest.find('<').unwrap()];
+                    let object = format!("{}/{}", path, key);
+                    if self.undeletable.contains(&object) {
+                        body.push_str(&format!(
+                            "<Error><Key>{}</Key><Code>AccessDenied</Code></Error>",
+                            key
+                        ));
+                    } else {
+                        self.objects.remove(&object);
+                        body.push_str(&format!("<Deleted><Key>{}</Key></Deleted>", key));
+                    }
+                }
+                body.push_str("</DeleteResult>");
+                response(200, vec![], body.into_bytes())
+            }
+            "POST" if has("uploads") => {
+                self.next_upload += 1;
+                let upload_id = format!("upload-{}", self.next_upload);
+                self.uploads.insert(upload_id.clone(), BTreeMap::new());
+                self.upload_headers
+                    .insert(upload_id.clone(), stored_headers(request));
+                self.upload_paths.insert(upload_id.clone(), path);
+                self.initiated.insert(upload_id.clone(), Utc::now());
+                let body = format!(
+                    "<InitiateMultipartUploadResult><UploadId>{}</UploadId></InitiateMultipartUploadResult>",
+                    upload_id
+                );
+                response(200, vec![], body.into_bytes())
+            }
+            "POST" if has("uploadId") => {
+                let upload_id = param(request, "uploadId").unwrap();
+                let parts = match self.uploads.remove(&upload_id) {
+                    Some(parts) => parts,
+                    None => {
+                        return response(
+                            404,
+                            vec![],
+                            b"<Error><Code>NoSuchUpload</Code></Error>".to_vec(),
+                        )
+                    }
+                };
+                let listed = String::from_utf8(body).unwrap();
+                let numbers: Vec<i64> = listed
+                    .split("<PartNumber>")
+                    .skip(1)
+                    .map(|rest| rest[..rest.find('<').unwrap()].parse().unwrap())
+                    .collect();
+                let mut data = Vec::new();
+                let mut digests = Vec::new();
+                let mut sizes = Vec::new();
+                for number in &numbers {
+                    let part = &parts[number];
+                    data.extend_from_slice(part);
+                    digests.extend_from_slice(&md5::compute(part).0);
+                    sizes.push(part.len());
+                }
+                let tag = format!("\"{:x}-{}\"", md5::compute(&digests), numbers.len());
+                self.etags.insert(path.clone(), tag.clone());
+                self.part_sizes.insert(path.clone(), sizes);
+                let headers = self.upload_headers.remove(&upload_id).unwrap_or_default();
+                self.upload_paths.remove(&upload_id);
+                self.initiated.remove(&upload_id);
+                self.headers.insert(path.clone(), headers);
+                self.objects.insert(path, data);
+                let body = format!(
+                    "<CompleteMultipartUploadResult><ETag>{}</ETag></CompleteMultipartUploadResult>",
+                    tag.replace('"', "&quot;")
+                );
+                response(200, vec![], body.into_bytes())
+            }
+            "DELETE" if has("uploadId") => {
+                let upload_id = param(request, "uploadId").unwrap();
+                self.uploads.remove(&upload_id);
+                self.upload_headers.remove(&upload_id);
+                self.upload_paths.remove(&upload_id);
+                self.initiated.remove(&upload_id);
+                response(204, vec![], vec![])
+            }
+            "GET" if has("uploads") => {
+                let prefix = format!("{}/{}", path, param(request, "prefix").unwrap_or_default());
+                let mut uploads: Vec<_> = self
+                    .upload_paths
+                    .iter()
+                    .filter(|(_, path)| path.starts_with(&prefix))
+                    .collect();
+                uploads.sort();
+                let mut body =
+                    "<ListMultipartUploadsResult><IsTruncated>false</IsTruncated>".to_owned();
+                for (upload_id, upload_path) in uploads {
+                    body.push_str(&format!(
+                        "<Upload><Key>{}</Key><UploadId>{}</UploadId><Initiated>{}</Initiated></Upload>",
+                        &upload_path[path.len() + 1..],
+                        upload_id,
+                        self.initiated[upload_id].to_rfc3339_opts(SecondsFormat::Millis, true)
+                    ));
+                }
+                body.push_str("</ListMultipartUploadsResult>");
+                response(200, vec![], body.into_bytes())
+            }
+            "GET" if has("uploadId") => {
+                let parts = match self.uploads.get(&param(request, "uploadId").unwrap()) {
+                    Some(parts) => parts,
+                    None => {
+                        return response(
+                            404,
+                            vec![],
+                            b"<Error><Code>NoSuchUpload</Code></Error>".to_vec(),
+                        )
+                    }
+                };
+                let mut body = "<ListPartsResult><IsTruncated>false</IsTruncated>".to_owned();
+                for (number, part) in parts {
+                    body.push_str(&format!(
+                        "<Part><PartNumber>{}</PartNumber><ETag>{}</ETag><Size>{}</Size></Part>",
+                        number,
+                        etag(part).replace('"', "&quot;"),
+                        part.len()
+                    ));
+                }
+                body.push_str("</ListPartsResult>");
+                response(200, vec![], body.into_bytes())
+            }
+            "HEAD" | "GET" => {
+                let data = match self.objects.get(&path) {
+                    Some(data) => data.clone(),
+                    None => return response(404, vec![], vec![]),
+                };
+                let tag = self.etag(&path, &data);
+                if header(request, "if-match").map_or(false, |expected| expected != tag) {
+                    return response(412, vec![], vec![]);
+                }
+                let mut headers = vec![("ETag", tag)];
+                let part_sizes = self
+                    .part_sizes
+                    .get(&path)
+                    .cloned()
+                    .unwrap_or_else(|| vec![data.len()]);
+                let range = match param(request, "partNumber") {
+                    Some(number) => {
+                        let number: usize = number.parse().unwrap();
+                        headers.push(("x-amz-mp-parts-count", part_sizes.len().to_string()));
+                        let start: usize = part_sizes[..number - 1].iter().sum();
+                        Some(format!(
+                            "bytes={}-{}",
+                            start,
+                            start + part_sizes[number - 1] - 1
+                        ))
+                    }
+                    None => header(request, "range"),
+                };
+                let (status, data) = match range {
+                    Some(range) => {
+                        if self.fail() {
+                            return response(500, vec![], vec![]);
+                        }
+                        let range = range.trim_start_matches("bytes=");
+                        let (start, end) = range.split_at(range.find('-').unwrap());
+                        let start: usize = start.parse().unwrap();
+                        let end: usize = end[1..].parse().unwrap();
+                        let end = end.min(data.len() - 1);
+                        headers.push((
+                            "Content-Range",
+                            format!("bytes {}-{}/{}", start, end, data.len()),
+                        ));
+                        (206, data[start..=end].to_vec())
+                    }
+                    None => (200, data),
+                };
+                headers.push(("Content-Length", data.len().to_string()));
+                let body = if request.method() == "HEAD" {
+                    vec![]
+                } else {
+                    data
+                };
+                let mut response = response(status, headers, body);
+                for (name, value) in self.headers.get(&path).into_iter().flatten() {
+                    response.headers.insert(
+                        HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                        value.clone(),
+                    );
+                }
+                response
+            }
+            _ => response(400, vec![], vec![]),
+        }
+    }
+}
+
+impl DispatchSignedRequest for FakeS3 {
+    fn dispatch(
+        &self,
+        request: Request<ByteStream>,
+        _timeout: Option<Duration>,
+    ) -> DispatchSignedRequestFuture {
+        let state = self.0.clone();
+        async move {
+            let (mut parts, body) = request.into_parts();
+            let request = parts
+                .extensions
+                .remove::<SignedRequest>()
+                .expect("requests are sent with their signed request");
+            let body = body
+                .try_fold(Vec::new(), |mut body, chunk: Bytes| async move {
+                    body.extend_from_slice(&chunk);
+                    Ok(body)
+                })
+                .await
+                .unwrap();
+            Ok(state.lock().unwrap().handle(&request, body).into())
+        }
+        .boxed()
+    }
+}
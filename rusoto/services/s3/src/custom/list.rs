@@ -0,0 +1,194 @@
+//! Listings of the objects of a bucket as streams following the continuation tokens of S3.
+
+use futures::{stream, Stream, StreamExt};
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    CommonPrefix, ListObjectsV2Error, ListObjectsV2Request, Object, S3Client, S3,
+};
+
+/// An entry of a listing with a delimiter: an object, or a prefix rolling up the keys sharing
+/// it up to the delimiter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListObjectsV2Entry {
+    /// An object whose key has no delimiter after the prefix.
+    Object(Object),
+    /// A prefix of keys up to the first delimiter after the prefix, like a directory.
+    CommonPrefix(CommonPrefix),
+}
+
+impl S3Client {
+    /// The objects `ListObjectsV2` lists for `request`, in the order of their keys, requesting
+    /// the next page with the continuation token of the last page as they are read.
+    ///
+    /// The prefix, delimiter, start key and page size of `request` are used for every page,
+    /// and its continuation token for the first one. The stream ends after the first error.
+    ///
+    /// ```rust,ignore
+    /// let mut objects = client.list_objects_v2_stream(ListObjectsV2Request {
+    ///     bucket: "bucket".to_owned(),
+    ///     prefix: Some("logs/".to_owned()),
+    ///     ..Default::default()
+    /// });
+    /// while let Some(object) = objects.try_next().await? {
+    ///     println!("{:?}", object.key);
+    /// }
+    /// ```
+    pub fn list_objects_v2_stream(
+        &self,
+        request: ListObjectsV2Request,
+    ) -> impl Stream<Item = Result<Object, RusotoError<ListObjectsV2Error>>> + Send {
+        self.list_objects_v2_entry_stream(request)
+            .filter_map(|entry| async move {
+                match entry {
+                    Ok(ListObjectsV2Entry::Object(object)) => Some(Ok(object)),
+                    Ok(ListObjectsV2Entry::CommonPrefix(_)) => None,
+                    Err(error) => Some(Err(error)),
+                }
+            })
+    }
+
+    /// The objects and common prefixes `ListObjectsV2` lists for `request`, which should have
+    /// a delimiter for there to be common prefixes, like `list_objects_v2_stream`. The objects
+    /// of each page come before its common prefixes.
+    pub fn list_objects_v2_entry_stream(
+        &self,
+        request: ListObjectsV2Request,
+    ) -> impl Stream<Item = Result<ListObjectsV2Entry, RusotoError<ListObjectsV2Error>>> + Send
+    {
+        stream::unfold(
+            (self.clone(), Some(request)),
+            |(client, request)| async move {
+                let request = request?;
+                let (page, next) = match client.list_objects_v2(request.clone()).await {
+                    Ok(output) => {
+                        let next = match output.next_continuation_token {
+                            Some(token) if output.is_truncated == Some(true) => {
+                                Some(ListObjectsV2Request {
+                                    continuation_token: Some(token),
+                                    ..request
+                                })
+                            }
+                            _ => None,
+                        };
+                        let objects = output
+                            .contents
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(ListObjectsV2Entry::Object);
+                        let prefixes = output
+                            .common_prefixes
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(ListObjectsV2Entry::CommonPrefix);
+                        (objects.chain(prefixes).map(Ok).collect(), next)
+                    }
+                    Err(error) => (vec![Err(error)], None),
+                };
+                Some((stream::iter(page), (client, next)))
+            },
+        )
+        .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use rusoto_core::signature::SignedRequest;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    fn page(body: &str, token: Option<&'static str>) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_body(&format!(
+                "<ListBucketResult>{}{}</ListBucketResult>",
+                match token {
+                    Some(token) => format!(
+                        "<IsTruncated>true</IsTruncated><NextContinuationToken>{}</NextContinuationToken>",
+                        token
+                    ),
+                    None => "<IsTruncated>false</IsTruncated>".to_owned(),
+                },
+                body
+            ))
+            .with_request_checker(move |request: &SignedRequest| {
+                assert_eq!(
+                    request.params.get("prefix").cloned().flatten().as_deref(),
+                    Some("logs/")
+                );
+                let sent = request.params.get("continuation-token").cloned().flatten();
+                let expected = match token {
+                    Some("page-2") => None,
+                    Some("page-3") => Some("page-2"),
+                    _ => Some("page-3"),
+                };
+                assert_eq!(sent.as_deref(), expected);
+            })
+    }
+
+    fn client() -> S3Client {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            page("<Contents><Key>logs/a</Key></Contents>", Some("page-2")),
+            page(
+                "<Contents><Key>logs/b</Key></Contents><CommonPrefixes><Prefix>logs/old/</Prefix></CommonPrefixes>",
+                Some("page-3"),
+            ),
+            page("<Contents><Key>logs/c</Key></Contents>", None),
+        ]);
+        S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1)
+    }
+
+    fn request() -> ListObjectsV2Request {
+        ListObjectsV2Request {
+            bucket: "bucket".to_owned(),
+            delimiter: Some("/".to_owned()),
+            prefix: Some("logs/".to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn streams_the_objects_of_every_page() {
+        let keys: Vec<_> = client()
+            .list_objects_v2_stream(request())
+            .map_ok(|object| object.key.unwrap())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(keys, vec!["logs/a", "logs/b", "logs/c"]);
+    }
+
+    #[tokio::test]
+    async fn streams_common_prefixes() {
+        let entries: Vec<_> = client()
+            .list_objects_v2_entry_stream(request())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 4);
+        assert_eq!(
+            entries[2],
+            ListObjectsV2Entry::CommonPrefix(CommonPrefix {
+                prefix: Some("logs/old/".to_owned())
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn ends_after_errors() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            page("<Contents><Key>logs/a</Key></Contents>", Some("page-2")),
+            MockRequestDispatcher::with_status(403)
+                .with_body("<Error><Code>AccessDenied</Code></Error>"),
+        ]);
+        let client = S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let results: Vec<_> = client.list_objects_v2_stream(request()).collect().await;
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+}
@@ -1,5 +1,39 @@
+mod bucket_region;
+mod checksum;
+mod cleanup;
+mod copy;
+mod delete;
+#[cfg(feature = "encryption")]
+mod encryption;
+mod list;
+mod post_policy;
+mod restore;
+mod resumable;
+mod select;
+mod sse;
+mod transfer;
 /// Utility helpers for working with S3
 pub mod util;
 
+pub use self::checksum::{ChecksumAlgorithm, ChecksumMode};
+pub use self::cleanup::AbortedUpload;
+pub use self::copy::MAX_COPY_OBJECT_SIZE;
+pub use self::delete::{DeleteObjectsReport, MAX_DELETE_OBJECTS_KEYS};
+#[cfg(feature = "encryption")]
+pub use self::encryption::{EncryptionError, S3EncryptionClient};
+pub use self::list::ListObjectsV2Entry;
+pub use self::post_policy::{PostPolicy, PresignedPost};
+pub use self::restore::{RestoreError, RestoreStatus, RestoreWaiter};
+pub use self::resumable::{
+    FileSource, FileStateStore, UploadSource, UploadState, UploadStateStore, UploadedPart,
+};
+pub use self::select::{SelectEvent, SelectEventStream};
+pub use self::sse::{
+    CopySourceSseCustomerKeyRequest, SseCustomerKey, SseCustomerKeyRequest, SseKms, SseKmsRequest,
+};
+pub use self::transfer::{TransferError, TransferManager, TransferProgress, MIN_PART_SIZE};
+
 #[cfg(test)]
 mod custom_tests;
+#[cfg(test)]
+mod fake_s3;
@@ -0,0 +1,334 @@
+//! Restores of archived objects, waited for by polling the objects until S3 has restored them.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use rusoto_core::{Runtime, RusotoError, TokioRuntime};
+
+use crate::generated::{
+    HeadObjectError, HeadObjectRequest, RestoreObjectError, RestoreObjectRequest, S3Client, S3,
+};
+
+/// The state of the restore of an archived object, as the `x-amz-restore` header of its
+/// `HeadObject` and `GetObject` responses has it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RestoreStatus {
+    /// S3 is still restoring the object.
+    Ongoing,
+    /// The object is restored, and its restored copy available until the expiry date, if S3
+    /// reports one.
+    Restored {
+        /// When S3 deletes the restored copy of the object.
+        expiry: Option<DateTime<Utc>>,
+    },
+}
+
+impl RestoreStatus {
+    /// The status an `x-amz-restore` header value like
+    /// `ongoing-request="false", expiry-date="Fri, 23 Dec 2012 00:00:00 GMT"` reports, `None`
+    /// unless it has an `ongoing-request`.
+    pub fn from_header(header: &str) -> Option<RestoreStatus> {
+        let mut ongoing = None;
+        let mut expiry = None;
+        let mut rest = header.trim();
+        while !rest.is_empty() {
+            let equals = rest.find('=')?;
+            let name = rest[..equals].trim();
+            let after = rest[equals + 1..].trim_start();
+            // The values are quoted, as the dates they carry have commas.
+            let (value, remaining) = if let Some(quoted) = after.strip_prefix('"') {
+                let end = quoted.find('"')?;
+                (&quoted[..end], &quoted[end + 1..])
+            } else {
+                let end = after.find(',').unwrap_or(after.len());
+                (after[..end].trim(), &after[end..])
+            };
+            match name {
+                "ongoing-request" => ongoing = Some(value == "true"),
+                "expiry-date" => expiry = parse_expiry_date(value),
+                _ => {}
+            }
+            rest = remaining.trim_start().trim_start_matches(',').trim_start();
+        }
+        match ongoing? {
+            true => Some(RestoreStatus::Ongoing),
+            false => Some(RestoreStatus::Restored { expiry }),
+        }
+    }
+}
+
+/// The date of an HTTP date like `Fri, 21 Dec 2012 00:00:00 GMT`, whose weekday is skipped
+/// rather than checked as S3 documents the header with wrong ones.
+fn parse_expiry_date(value: &str) -> Option<DateTime<Utc>> {
+    let date = value.split_once(", ").map_or(value, |(_, date)| date);
+    NaiveDateTime::parse_from_str(date, "%d %b %Y %H:%M:%S GMT")
+        .ok()
+        .map(|date| Utc.from_utc_datetime(&date))
+}
+
+/// How `S3Client::restore_object_and_wait` polls an object: with delays doubling from an
+/// initial delay up to a maximum one, until a deadline.
+///
+/// Restores take from minutes with expedited retrievals to hours for the standard and bulk
+/// ones, which the defaults of up to 15 minutes between polls for up to 48 hours suit.
+#[derive(Clone)]
+pub struct RestoreWaiter {
+    initial_delay: Duration,
+    max_delay: Duration,
+    timeout: Duration,
+    runtime: Arc<dyn Runtime>,
+}
+
+impl RestoreWaiter {
+    /// A waiter polling first after a minute, then with delays doubling up to 15 minutes,
+    /// for up to 48 hours, waiting on tokio.
+    pub fn new() -> RestoreWaiter {
+        RestoreWaiter {
+            initial_delay: Duration::from_secs(60),
+            max_delay: Duration::from_secs(15 * 60),
+            timeout: Duration::from_secs(48 * 60 * 60),
+            runtime: Arc::new(TokioRuntime),
+        }
+    }
+
+    /// Sets the delay before the first poll.
+    pub fn set_initial_delay(&mut self, initial_delay: Duration) {
+        self.initial_delay = initial_delay;
+    }
+
+    /// Sets the longest delay between polls.
+    pub fn set_max_delay(&mut self, max_delay: Duration) {
+        self.max_delay = max_delay;
+    }
+
+    /// Sets how long to wait for the restore overall, in the delays between polls.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Sets the runtime waiting for the delays between polls.
+    pub fn set_runtime<R>(&mut self, runtime: R)
+    where
+        R: Runtime + 'static,
+    {
+        self.runtime = Arc::new(runtime);
+    }
+}
+
+impl Default for RestoreWaiter {
+    fn default() -> RestoreWaiter {
+        RestoreWaiter::new()
+    }
+}
+
+impl fmt::Debug for RestoreWaiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RestoreWaiter")
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// An error restoring an object and waiting for it to be restored.
+#[derive(Debug)]
+pub enum RestoreError {
+    /// The `RestoreObject` request failed.
+    RestoreObject(RusotoError<RestoreObjectError>),
+    /// A `HeadObject` request polling the object failed.
+    HeadObject(RusotoError<HeadObjectError>),
+    /// The object wasn't restored within the timeout of the waiter.
+    TimedOut(Duration),
+}
+
+impl fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RestoreError::RestoreObject(e) => write!(f, "RestoreObject failed: {}", e),
+            RestoreError::HeadObject(e) => write!(f, "HeadObject failed: {}", e),
+            RestoreError::TimedOut(waited) => {
+                write!(f, "the object wasn't restored after {:?}", waited)
+            }
+        }
+    }
+}
+
+impl Error for RestoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RestoreError::RestoreObject(e) => Some(e),
+            RestoreError::HeadObject(e) => Some(e),
+            RestoreError::TimedOut(_) => None,
+        }
+    }
+}
+
+impl S3Client {
+    /// Restores the archived object of `request`, then polls it with `HeadObject` as `waiter`
+    /// does until S3 reports it restored, returning the status with the expiry of the
+    /// restored copy.
+    ///
+    /// A restore S3 already has in progress for the object is waited for like a new one. An
+    /// object without an `x-amz-restore` header yet is polled again.
+    ///
+    /// ```rust,ignore
+    /// let status = client
+    ///     .restore_object_and_wait(request, &RestoreWaiter::new())
+    ///     .await?;
+    /// ```
+    pub async fn restore_object_and_wait(
+        &self,
+        request: RestoreObjectRequest,
+        waiter: &RestoreWaiter,
+    ) -> Result<RestoreStatus, RestoreError> {
+        let head = HeadObjectRequest {
+            bucket: request.bucket.clone(),
+            key: request.key.clone(),
+            version_id: request.version_id.clone(),
+            request_payer: request.request_payer.clone(),
+            ..Default::default()
+        };
+        match self.restore_object(request).await {
+            Ok(_) => {}
+            Err(RusotoError::Service(_, ref metadata))
+                if metadata.code() == Some("RestoreAlreadyInProgress") => {}
+            Err(error) => return Err(RestoreError::RestoreObject(error)),
+        }
+
+        let mut delay = waiter.initial_delay;
+        let mut waited = Duration::from_secs(0);
+        loop {
+            if waited >= waiter.timeout {
+                return Err(RestoreError::TimedOut(waited));
+            }
+            let sleep = delay.min(waiter.timeout - waited);
+            waiter.runtime.sleep(sleep).await;
+            waited += sleep;
+            delay = (delay * 2).min(waiter.max_delay);
+
+            let output = self
+                .head_object(head.clone())
+                .await
+                .map_err(RestoreError::HeadObject)?;
+            match output
+                .restore
+                .as_deref()
+                .and_then(RestoreStatus::from_header)
+            {
+                Some(status @ RestoreStatus::Restored { .. }) => return Ok(status),
+                Some(RestoreStatus::Ongoing) | None => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::{Region, SleepFuture};
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+    use std::sync::Mutex;
+
+    #[derive(Clone, Default)]
+    struct RecordingRuntime {
+        sleeps: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl Runtime for RecordingRuntime {
+        fn sleep(&self, duration: Duration) -> SleepFuture {
+            self.sleeps.lock().unwrap().push(duration);
+            Box::pin(async {})
+        }
+    }
+
+    fn head(restore: &str) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200).with_header("x-amz-restore", restore)
+    }
+
+    fn request() -> RestoreObjectRequest {
+        RestoreObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "archive".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn waiter(runtime: &RecordingRuntime) -> RestoreWaiter {
+        let mut waiter = RestoreWaiter::new();
+        waiter.set_initial_delay(Duration::from_secs(10));
+        waiter.set_max_delay(Duration::from_secs(25));
+        waiter.set_timeout(Duration::from_secs(60));
+        waiter.set_runtime(runtime.clone());
+        waiter
+    }
+
+    #[test]
+    fn parses_restore_headers() {
+        assert_eq!(
+            RestoreStatus::from_header("ongoing-request=\"true\""),
+            Some(RestoreStatus::Ongoing)
+        );
+        assert_eq!(
+            RestoreStatus::from_header(
+                "ongoing-request=\"false\", expiry-date=\"Fri, 23 Dec 2012 00:00:00 GMT\""
+            ),
+            Some(RestoreStatus::Restored {
+                expiry: Some("2012-12-23T00:00:00Z".parse().unwrap())
+            })
+        );
+        assert_eq!(RestoreStatus::from_header("expiry-date=\"soon\""), None);
+        assert_eq!(RestoreStatus::from_header("ongoing-request"), None);
+    }
+
+    #[tokio::test]
+    async fn waits_for_restores_with_backoff() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(409).with_body(
+                "<Error><Code>RestoreAlreadyInProgress</Code><Message>in progress</Message></Error>",
+            ),
+            head("ongoing-request=\"true\""),
+            head("ongoing-request=\"false\", expiry-date=\"Fri, 23 Dec 2012 00:00:00 GMT\""),
+        ]);
+        let client = S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let runtime = RecordingRuntime::default();
+        let status = client
+            .restore_object_and_wait(request(), &waiter(&runtime))
+            .await
+            .unwrap();
+        assert!(matches!(
+            status,
+            RestoreStatus::Restored { expiry: Some(_) }
+        ));
+        assert_eq!(
+            *runtime.sleeps.lock().unwrap(),
+            vec![Duration::from_secs(10), Duration::from_secs(20)]
+        );
+    }
+
+    #[tokio::test]
+    async fn times_out() {
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(202),
+            head("ongoing-request=\"true\""),
+            head("ongoing-request=\"true\""),
+            head("ongoing-request=\"true\""),
+            head("ongoing-request=\"true\""),
+        ]);
+        let client = S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let runtime = RecordingRuntime::default();
+        match client
+            .restore_object_and_wait(request(), &waiter(&runtime))
+            .await
+        {
+            Err(RestoreError::TimedOut(waited)) => assert_eq!(waited, Duration::from_secs(60)),
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_eq!(runtime.sleeps.lock().unwrap().len(), 4);
+    }
+}
@@ -0,0 +1,546 @@
+//! Multipart uploads that resume after an interruption, even from another process.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{lock, stream, StreamExt, TryFutureExt, TryStreamExt};
+use rusoto_core::RusotoError;
+use serde_json::{json, Value};
+
+use super::transfer::{create_multipart_upload_request, retrying, TransferError, TransferManager};
+use crate::generated::{
+    CompleteMultipartUploadRequest, CompletedMultipartUpload, CompletedPart, ListPartsRequest,
+    PutObjectOutput, PutObjectRequest, S3,
+};
+
+/// The state of a resumable multipart upload, saved after every part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploadState {
+    /// The bucket uploaded to.
+    pub bucket: String,
+    /// The key uploaded to.
+    pub key: String,
+    /// The id of the multipart upload.
+    pub upload_id: String,
+    /// The size of the object.
+    pub size: u64,
+    /// The size of the parts.
+    pub part_size: u64,
+    /// The parts uploaded so far.
+    pub parts: Vec<UploadedPart>,
+}
+
+/// A part of a resumable multipart upload that has been uploaded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploadedPart {
+    /// The number of the part.
+    pub part_number: i64,
+    /// The entity tag S3 returned for the part.
+    pub e_tag: String,
+    /// The offset of the part in the object.
+    pub offset: u64,
+    /// The size of the part.
+    pub size: u64,
+}
+
+fn invalid_state(field: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid upload state: bad or missing {}", field),
+    )
+}
+
+fn string(value: &Value, field: &str) -> io::Result<String> {
+    value[field]
+        .as_str()
+        .map(str::to_owned)
+        .ok_or_else(|| invalid_state(field))
+}
+
+fn number(value: &Value, field: &str) -> io::Result<u64> {
+    value[field].as_u64().ok_or_else(|| invalid_state(field))
+}
+
+impl UploadState {
+    /// Serializes the state as JSON.
+    pub fn to_json(&self) -> String {
+        let parts: Vec<Value> = self
+            .parts
+            .iter()
+            .map(|part| {
+                json!({
+                    "partNumber": part.part_number,
+                    "eTag": part.e_tag,
+                    "offset": part.offset,
+                    "size": part.size,
+                })
+            })
+            .collect();
+        json!({
+            "bucket": self.bucket,
+            "key": self.key,
+            "uploadId": self.upload_id,
+            "size": self.size,
+            "partSize": self.part_size,
+            "parts": parts,
+        })
+        .to_string()
+    }
+
+    /// Parses a state serialized with `to_json`.
+    pub fn from_json(json: &str) -> io::Result<UploadState> {
+        let value: Value = serde_json::from_str(json)?;
+        let parts = value["parts"]
+            .as_array()
+            .ok_or_else(|| invalid_state("parts"))?
+            .iter()
+            .map(|part| {
+                Ok(UploadedPart {
+                    part_number: number(part, "partNumber")? as i64,
+                    e_tag: string(part, "eTag")?,
+                    offset: number(part, "offset")?,
+                    size: number(part, "size")?,
+                })
+            })
+            .collect::<io::Result<_>>()?;
+        Ok(UploadState {
+            bucket: string(&value, "bucket")?,
+            key: string(&value, "key")?,
+            upload_id: string(&value, "uploadId")?,
+            size: number(&value, "size")?,
+            part_size: number(&value, "partSize")?,
+            parts,
+        })
+    }
+}
+
+/// Where the state of a resumable upload is kept between attempts.
+#[async_trait]
+pub trait UploadStateStore: Send + Sync {
+    /// Loads the saved state, if any.
+    async fn load(&self) -> io::Result<Option<UploadState>>;
+    /// Saves the state, replacing any saved before.
+    async fn save(&self, state: &UploadState) -> io::Result<()>;
+    /// Removes the saved state once the upload completes.
+    async fn clear(&self) -> io::Result<()>;
+}
+
+/// Keeps the state of a resumable upload in a JSON file, replaced atomically on every save.
+#[derive(Clone, Debug)]
+pub struct FileStateStore {
+    path: PathBuf,
+}
+
+impl FileStateStore {
+    /// Creates a store keeping the state in the file at `path`.
+    pub fn new<P: AsRef<Path>>(path: P) -> FileStateStore {
+        FileStateStore {
+            path: path.as_ref().to_owned(),
+        }
+    }
+}
+
+#[async_trait]
+impl UploadStateStore for FileStateStore {
+    async fn load(&self) -> io::Result<Option<UploadState>> {
+        match fs::read_to_string(&self.path) {
+            Ok(json) => UploadState::from_json(&json).map(Some),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn save(&self, state: &UploadState) -> io::Result<()> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        fs::write(&temporary, state.to_json())?;
+        fs::rename(&temporary, &self.path)
+    }
+
+    async fn clear(&self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
+
+/// The data of a resumable upload, which has to be readable at any offset to resume.
+#[async_trait]
+pub trait UploadSource: Send + Sync {
+    /// The size of the data.
+    fn size(&self) -> u64;
+    /// Reads `length` bytes at `offset`.
+    async fn read_at(&self, offset: u64, length: usize) -> io::Result<Bytes>;
+}
+
+#[async_trait]
+impl UploadSource for Bytes {
+    fn size(&self) -> u64 {
+        self.len() as u64
+    }
+
+    async fn read_at(&self, offset: u64, length: usize) -> io::Result<Bytes> {
+        let offset = offset as usize;
+        if offset + length > self.len() {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(self.slice(offset..offset + length))
+    }
+}
+
+/// A file to upload. Parts are read with blocking reads, which are short next to uploading them.
+#[derive(Debug)]
+pub struct FileSource {
+    file: Mutex<File>,
+    size: u64,
+}
+
+impl FileSource {
+    /// Opens the file at `path`.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<FileSource> {
+        let file = File::open(path)?;
+        let size = file.metadata()?.len();
+        Ok(FileSource {
+            file: Mutex::new(file),
+            size,
+        })
+    }
+}
+
+#[async_trait]
+impl UploadSource for FileSource {
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    async fn read_at(&self, offset: u64, length: usize) -> io::Result<Bytes> {
+        let mut file = self.file.lock().unwrap();
+        file.seek(SeekFrom::Start(offset))?;
+        let mut data = vec![0; length];
+        file.read_exact(&mut data)?;
+        Ok(data.into())
+    }
+}
+
+impl<C> TransferManager<C>
+where
+    C: S3 + Send + Sync + 'static,
+{
+    /// Uploads `source` to the bucket and key of `request` with a multipart upload whose state
+    /// is saved to `store` after every part, so that an upload interrupted by a failure or a
+    /// restart resumes where it stopped when called again with the same store.
+    ///
+    /// When resuming, the parts of the saved state are checked against `ListParts` and uploaded
+    /// again if S3 doesn't have them, and a new upload is started if the saved one is gone or was
+    /// for other data. Failed uploads are not aborted; the state is cleared once the upload
+    /// completes. The body of `request` is ignored.
+    pub async fn upload_resumable<S, T>(
+        &self,
+        request: PutObjectRequest,
+        source: &S,
+        store: &T,
+    ) -> Result<PutObjectOutput, TransferError>
+    where
+        S: UploadSource + ?Sized,
+        T: UploadStateStore + ?Sized,
+    {
+        let size = source.size();
+        let part_size = self.part_size_for(Some(size)) as u64;
+        let saved = store.load().await.map_err(TransferError::StateStore)?;
+        let resumed = match saved {
+            Some(state)
+                if state.bucket == request.bucket
+                    && state.key == request.key
+                    && state.size == size
+                    && state.part_size == part_size =>
+            {
+                self.verify_parts(&request, state).await?
+            }
+            _ => None,
+        };
+        let state = match resumed {
+            Some(state) => state,
+            None => {
+                let created = self
+                    .client
+                    .create_multipart_upload(create_multipart_upload_request(&request))
+                    .await
+                    .map_err(TransferError::CreateMultipartUpload)?;
+                let state = UploadState {
+                    bucket: request.bucket.clone(),
+                    key: request.key.clone(),
+                    upload_id: created.upload_id.unwrap_or_default(),
+                    size,
+                    part_size,
+                    parts: Vec::new(),
+                };
+                store
+                    .save(&state)
+                    .await
+                    .map_err(TransferError::StateStore)?;
+                state
+            }
+        };
+
+        let tracker = self.tracker(Some(size));
+        let uploaded: u64 = state.parts.iter().map(|part| part.size).sum();
+        if uploaded > 0 {
+            tracker.add(uploaded);
+        }
+        let part_count = std::cmp::max(1, (size + part_size - 1) / part_size) as i64;
+        let missing: Vec<i64> = (1..=part_count)
+            .filter(|number| !state.parts.iter().any(|part| part.part_number == *number))
+            .collect();
+        let upload_id = state.upload_id.clone();
+        let state = lock::Mutex::new(state);
+        stream::iter(missing)
+            .map(|part_number| {
+                let offset = (part_number - 1) as u64 * part_size;
+                let length = part_size.min(size - offset);
+                let (request, upload_id, tracker, state) = (&request, &upload_id, &tracker, &state);
+                async move {
+                    let data = source
+                        .read_at(offset, length as usize)
+                        .await
+                        .map_err(TransferError::Io)?;
+                    let part = self
+                        .upload_part(request, upload_id, part_number, data, tracker)
+                        .await?;
+                    let mut state = state.lock().await;
+                    state.parts.push(UploadedPart {
+                        part_number,
+                        e_tag: part.e_tag.unwrap_or_default(),
+                        offset,
+                        size: length,
+                    });
+                    store.save(&state).await.map_err(TransferError::StateStore)
+                }
+            })
+            .buffer_unordered(self.concurrency)
+            .try_collect::<Vec<()>>()
+            .await?;
+
+        let mut parts = state.into_inner().parts;
+        parts.sort_by_key(|part| part.part_number);
+        let output = self
+            .client
+            .complete_multipart_upload(CompleteMultipartUploadRequest {
+                bucket: request.bucket.clone(),
+                key: request.key.clone(),
+                multipart_upload: Some(CompletedMultipartUpload {
+                    parts: Some(
+                        parts
+                            .into_iter()
+                            .map(|part| CompletedPart {
+                                e_tag: Some(part.e_tag),
+                                part_number: Some(part.part_number),
+                            })
+                            .collect(),
+                    ),
+                }),
+                request_payer: request.request_payer.clone(),
+                upload_id,
+            })
+            .await
+            .map_err(TransferError::CompleteMultipartUpload)?;
+        store.clear().await.map_err(TransferError::StateStore)?;
+        Ok(PutObjectOutput {
+            e_tag: output.e_tag,
+            expiration: output.expiration,
+            request_charged: output.request_charged,
+            ssekms_key_id: output.ssekms_key_id,
+            server_side_encryption: output.server_side_encryption,
+            version_id: output.version_id,
+            ..Default::default()
+        })
+    }
+
+    /// Keeps the parts of `state` that `ListParts` lists with the same entity tag and size,
+    /// returning `None` if the upload no longer exists.
+    async fn verify_parts(
+        &self,
+        request: &PutObjectRequest,
+        mut state: UploadState,
+    ) -> Result<Option<UploadState>, TransferError> {
+        let mut listed = HashMap::new();
+        let mut part_number_marker = None;
+        loop {
+            let result = retrying(self.part_retries, || {
+                self.client
+                    .list_parts(ListPartsRequest {
+                        bucket: state.bucket.clone(),
+                        key: state.key.clone(),
+                        part_number_marker,
+                        request_payer: request.request_payer.clone(),
+                        upload_id: state.upload_id.clone(),
+                        ..Default::default()
+                    })
+                    .map_err(TransferError::ListParts)
+            })
+            .await;
+            let output = match result {
+                Ok(output) => output,
+                Err(TransferError::ListParts(RusotoError::Service(_, ref metadata)))
+                    if metadata.code() == Some("NoSuchUpload") =>
+                {
+                    return Ok(None)
+                }
+                Err(error) => return Err(error),
+            };
+            for part in output.parts.unwrap_or_default() {
+                if let (Some(number), Some(e_tag), Some(size)) =
+                    (part.part_number, part.e_tag, part.size)
+                {
+                    listed.insert(number, (e_tag, size as u64));
+                }
+            }
+            match output.next_part_number_marker {
+                Some(marker) if output.is_truncated == Some(true) => {
+                    part_number_marker = Some(marker)
+                }
+                _ => break,
+            }
+        }
+        state
+            .parts
+            .retain(|part| listed.get(&part.part_number) == Some(&(part.e_tag.clone(), part.size)));
+        Ok(Some(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom::fake_s3::FakeS3;
+
+    #[derive(Default)]
+    struct MemoryStore(Mutex<Option<UploadState>>);
+
+    #[async_trait]
+    impl UploadStateStore for MemoryStore {
+        async fn load(&self) -> io::Result<Option<UploadState>> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        async fn save(&self, state: &UploadState) -> io::Result<()> {
+            *self.0.lock().unwrap() = Some(state.clone());
+            Ok(())
+        }
+
+        async fn clear(&self) -> io::Result<()> {
+            *self.0.lock().unwrap() = None;
+            Ok(())
+        }
+    }
+
+    fn manager(s3: &FakeS3) -> TransferManager<crate::S3Client> {
+        let mut manager = TransferManager::new(s3.client());
+        manager.set_part_size(4);
+        manager.set_concurrency(1);
+        manager.set_part_retries(0);
+        manager
+    }
+
+    fn request() -> PutObjectRequest {
+        PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn resumes_interrupted_uploads() {
+        let s3 = FakeS3::default();
+        let source = Bytes::from_static(b"0123456789ab");
+        let store = MemoryStore::default();
+        s3.state().failing_part = Some(2);
+        let failed = manager(&s3)
+            .upload_resumable(request(), &source, &store)
+            .await;
+        assert!(matches!(failed, Err(TransferError::UploadPart(_))));
+        let saved = store.load().await.unwrap().unwrap();
+        assert_eq!(saved.parts.len(), 1);
+        assert_eq!(saved.parts[0].part_number, 1);
+
+        s3.state().failing_part = None;
+        s3.state().requests.clear();
+        manager(&s3)
+            .upload_resumable(request(), &source, &store)
+            .await
+            .unwrap();
+        assert_eq!(store.load().await.unwrap(), None);
+        let state = s3.state();
+        assert_eq!(state.objects["/bucket/key"], b"0123456789ab");
+        assert_eq!(
+            state.requests,
+            vec![
+                "GET /bucket/key?uploadId",
+                "PUT /bucket/key?uploadId?partNumber",
+                "PUT /bucket/key?uploadId?partNumber",
+                "POST /bucket/key?uploadId",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn restarts_uploads_that_are_gone() {
+        let s3 = FakeS3::default();
+        let source = Bytes::from_static(b"0123456789ab");
+        let store = MemoryStore::default();
+        store
+            .save(&UploadState {
+                bucket: "bucket".to_owned(),
+                key: "key".to_owned(),
+                upload_id: "aborted".to_owned(),
+                size: 12,
+                part_size: 4,
+                parts: vec![UploadedPart {
+                    part_number: 1,
+                    e_tag: "\"etag\"".to_owned(),
+                    offset: 0,
+                    size: 4,
+                }],
+            })
+            .await
+            .unwrap();
+        manager(&s3)
+            .upload_resumable(request(), &source, &store)
+            .await
+            .unwrap();
+        let state = s3.state();
+        assert_eq!(state.objects["/bucket/key"], b"0123456789ab");
+        assert_eq!(state.requests[0], "GET /bucket/key?uploadId");
+        assert_eq!(state.requests[1], "POST /bucket/key?uploads");
+    }
+
+    #[tokio::test]
+    async fn keeps_state_in_files() {
+        let path = std::env::temp_dir().join(format!("rusoto-upload-{}.json", std::process::id()));
+        let store = FileStateStore::new(&path);
+        assert_eq!(store.load().await.unwrap(), None);
+        let state = UploadState {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            upload_id: "upload".to_owned(),
+            size: 12,
+            part_size: 4,
+            parts: vec![UploadedPart {
+                part_number: 2,
+                e_tag: "\"etag\"".to_owned(),
+                offset: 4,
+                size: 4,
+            }],
+        };
+        store.save(&state).await.unwrap();
+        assert_eq!(store.load().await.unwrap(), Some(state));
+        store.clear().await.unwrap();
+        assert!(!path.exists());
+    }
+}
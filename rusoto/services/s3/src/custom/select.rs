@@ -0,0 +1,336 @@
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::{Stream, StreamExt};
+use rusoto_core::proto::event_stream::{Message, MessageStream};
+use rusoto_core::RusotoError;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::generated::{
+    ContinuationEvent, EndEvent, Progress, ProgressEvent, RecordsEvent, S3Client,
+    SelectObjectContentError, SelectObjectContentRequest, Stats, StatsEvent,
+};
+
+/// An event of the response of `SelectObjectContent`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SelectEvent {
+    /// A part of the records matching the expression, in the output serialization requested.
+    Records(RecordsEvent),
+    /// The totals of the bytes scanned, processed and returned, sent once before `End`.
+    Stats(StatsEvent),
+    /// The bytes scanned, processed and returned so far, if progress was requested.
+    Progress(ProgressEvent),
+    /// A keep-alive sent while no records matched for a while.
+    Cont(ContinuationEvent),
+    /// The last event, sent once all records were.
+    End(EndEvent),
+}
+
+type SelectError = RusotoError<SelectObjectContentError>;
+
+/// The bytes processed, returned and scanned of a `Stats` or `Progress` event.
+type ByteCounts = (Option<i64>, Option<i64>, Option<i64>);
+
+/// The events of the response of `SelectObjectContent`, decoded as they arrive.
+///
+/// An event stream ending without an `End` event yields an error, as the records received
+/// may not be all the records matching the expression. Events of unknown types are skipped.
+pub struct SelectEventStream {
+    messages: MessageStream,
+    ended: bool,
+}
+
+impl fmt::Debug for SelectEventStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SelectEventStream")
+            .field("ended", &self.ended)
+            .finish()
+    }
+}
+
+impl Stream for SelectEventStream {
+    type Item = Result<SelectEvent, SelectError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.ended {
+                return Poll::Ready(None);
+            }
+            let message = match futures::ready!(self.messages.poll_next_unpin(cx)) {
+                Some(Ok(message)) => message,
+                Some(Err(err)) => {
+                    self.ended = true;
+                    return Poll::Ready(Some(Err(RusotoError::ParseError(err.to_string()))));
+                }
+                None => {
+                    self.ended = true;
+                    return Poll::Ready(Some(Err(RusotoError::ParseError(
+                        "Select event stream ended without an End event".to_owned(),
+                    ))));
+                }
+            };
+            if let Some(metadata) = message.error_metadata() {
+                self.ended = true;
+                return Poll::Ready(Some(Err(RusotoError::Service(
+                    SelectObjectContentError::Unhandled(metadata.clone()),
+                    metadata,
+                ))));
+            }
+            match decode_event(message) {
+                Ok(Some(event)) => {
+                    self.ended = event == SelectEvent::End(EndEvent {});
+                    return Poll::Ready(Some(Ok(event)));
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    self.ended = true;
+                    return Poll::Ready(Some(Err(RusotoError::ParseError(err))));
+                }
+            }
+        }
+    }
+}
+
+/// The event sent as the event message `message`, `None` for events of unknown types.
+fn decode_event(message: Message) -> Result<Option<SelectEvent>, String> {
+    let event = match message.event_type() {
+        Some("Records") => SelectEvent::Records(RecordsEvent {
+            payload: Some(message.payload),
+        }),
+        Some("Stats") => {
+            let (bytes_processed, bytes_returned, bytes_scanned) = byte_counts(&message.payload)?;
+            SelectEvent::Stats(StatsEvent {
+                details: Some(Stats {
+                    bytes_processed,
+                    bytes_returned,
+                    bytes_scanned,
+                }),
+            })
+        }
+        Some("Progress") => {
+            let (bytes_processed, bytes_returned, bytes_scanned) = byte_counts(&message.payload)?;
+            SelectEvent::Progress(ProgressEvent {
+                details: Some(Progress {
+                    bytes_processed,
+                    bytes_returned,
+                    bytes_scanned,
+                }),
+            })
+        }
+        Some("Cont") => SelectEvent::Cont(ContinuationEvent {}),
+        Some("End") => SelectEvent::End(EndEvent {}),
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+/// The `BytesProcessed`, `BytesReturned` and `BytesScanned` of the `Stats` or `Progress`
+/// document of a `Stats` or `Progress` event.
+fn byte_counts(payload: &[u8]) -> Result<ByteCounts, String> {
+    let mut counts = (None, None, None);
+    let mut element = String::new();
+    for event in EventReader::new(payload) {
+        match event.map_err(|err| err.to_string())? {
+            XmlEvent::StartElement { name, .. } => element = name.local_name,
+            XmlEvent::EndElement { .. } => element.clear(),
+            XmlEvent::Characters(text) => {
+                let count = match element.as_str() {
+                    "BytesProcessed" => &mut counts.0,
+                    "BytesReturned" => &mut counts.1,
+                    "BytesScanned" => &mut counts.2,
+                    _ => continue,
+                };
+                let value = text
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("Invalid {} {}", element, text))?;
+                *count = Some(value);
+            }
+            _ => {}
+        }
+    }
+    Ok(counts)
+}
+
+impl S3Client {
+    /// Runs the SQL expression of `input` over an object like `select_object_content`,
+    /// returning the events S3 answers with as a stream decoded as they arrive.
+    ///
+    /// ```rust,ignore
+    /// let mut events = client.select_object_content_stream(request).await?;
+    /// while let Some(event) = events.try_next().await? {
+    ///     if let SelectEvent::Records(records) = event {
+    ///         output.write_all(&records.payload.unwrap_or_default())?;
+    ///     }
+    /// }
+    /// ```
+    pub async fn select_object_content_stream(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> Result<SelectEventStream, SelectError> {
+        let (body, _) = self.select_object_content_event_stream(input).await?;
+        Ok(SelectEventStream {
+            messages: MessageStream::new(body),
+            ended: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::{InputSerialization, OutputSerialization};
+    use futures::TryStreamExt;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    fn crc32(bytes: &[u8]) -> u32 {
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(bytes);
+        hasher.finalize()
+    }
+
+    /// An event stream message with the `String` headers `headers`.
+    fn frame(headers: &[(&str, &str)], payload: &[u8]) -> Vec<u8> {
+        let mut encoded_headers = Vec::new();
+        for (name, value) in headers {
+            encoded_headers.push(name.len() as u8);
+            encoded_headers.extend_from_slice(name.as_bytes());
+            encoded_headers.push(7);
+            encoded_headers.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            encoded_headers.extend_from_slice(value.as_bytes());
+        }
+        let total_length = 16 + encoded_headers.len() + payload.len();
+        let mut message = Vec::new();
+        message.extend_from_slice(&(total_length as u32).to_be_bytes());
+        message.extend_from_slice(&(encoded_headers.len() as u32).to_be_bytes());
+        let prelude_crc = crc32(&message);
+        message.extend_from_slice(&prelude_crc.to_be_bytes());
+        message.extend_from_slice(&encoded_headers);
+        message.extend_from_slice(payload);
+        let message_crc = crc32(&message);
+        message.extend_from_slice(&message_crc.to_be_bytes());
+        message
+    }
+
+    fn event(event_type: &str, payload: &[u8]) -> Vec<u8> {
+        frame(
+            &[(":message-type", "event"), (":event-type", event_type)],
+            payload,
+        )
+    }
+
+    fn request() -> SelectObjectContentRequest {
+        SelectObjectContentRequest {
+            bucket: "bucket".to_owned(),
+            key: "key.csv".to_owned(),
+            expression: "SELECT * FROM S3Object".to_owned(),
+            expression_type: "SQL".to_owned(),
+            input_serialization: InputSerialization::default(),
+            output_serialization: OutputSerialization::default(),
+            ..Default::default()
+        }
+    }
+
+    async fn select(body: &[u8]) -> Vec<Result<SelectEvent, SelectError>> {
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_request_checker(|request| {
+                assert_eq!(request.method(), "POST");
+                assert_eq!(request.path(), "/bucket/key.csv");
+            })
+            .with_binary_body(body);
+        let client = S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let events = client
+            .select_object_content_stream(request())
+            .await
+            .unwrap();
+        events.collect().await
+    }
+
+    #[tokio::test]
+    async fn streams_typed_select_events() {
+        let stats = b"<Stats><BytesScanned>10</BytesScanned>\
+            <BytesProcessed>10</BytesProcessed><BytesReturned>4</BytesReturned></Stats>";
+        let mut body = event("Records", b"a,b\n");
+        body.extend(event("Cont", b""));
+        body.extend(event("Unknown", b""));
+        body.extend(event("Stats", stats));
+        body.extend(event("End", b""));
+        let events = select(&body)
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![
+                SelectEvent::Records(RecordsEvent {
+                    payload: Some(bytes::Bytes::from_static(b"a,b\n")),
+                }),
+                SelectEvent::Cont(ContinuationEvent {}),
+                SelectEvent::Stats(StatsEvent {
+                    details: Some(Stats {
+                        bytes_processed: Some(10),
+                        bytes_returned: Some(4),
+                        bytes_scanned: Some(10),
+                    }),
+                }),
+                SelectEvent::End(EndEvent {}),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_on_errors_and_incomplete_streams() {
+        let mut body = event("Records", b"a,b\n");
+        body.extend(frame(
+            &[
+                (":message-type", "error"),
+                (":error-code", "InternalError"),
+                (":error-message", "try again"),
+            ],
+            b"",
+        ));
+        let events = select(&body).await;
+        assert_eq!(events.len(), 2);
+        match events[1] {
+            Err(RusotoError::Service(SelectObjectContentError::Unhandled(ref metadata), _)) => {
+                assert_eq!(metadata.code(), Some("InternalError"))
+            }
+            ref result => panic!("unexpected result {:?}", result),
+        }
+
+        let events = select(&event("Records", b"a,b\n")).await;
+        assert_eq!(events.len(), 2);
+        assert!(events[0].is_ok());
+        match events[1] {
+            Err(RusotoError::ParseError(_)) => {}
+            ref result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn collects_records_of_streams() {
+        let mut body = event("Records", b"a,b\n");
+        body.extend(event("Records", b"c,d\n"));
+        body.extend(event("End", b""));
+        let dispatcher = MockRequestDispatcher::with_status(200).with_binary_body(&body);
+        let client = S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let records: Vec<u8> = client
+            .select_object_content_stream(request())
+            .await
+            .unwrap()
+            .try_filter_map(|event| async move {
+                Ok(match event {
+                    SelectEvent::Records(records) => records.payload,
+                    _ => None,
+                })
+            })
+            .map_ok(|payload| payload.to_vec())
+            .try_concat()
+            .await
+            .unwrap();
+        assert_eq!(records, b"a,b\nc,d\n");
+    }
+}
@@ -0,0 +1,313 @@
+//! The server-side encryption fields of requests, set consistently from a key.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::generated::{
+    CopyObjectRequest, CreateMultipartUploadRequest, GetObjectRequest, HeadObjectRequest,
+    PutObjectRequest, UploadPartCopyRequest, UploadPartRequest,
+};
+
+/// The only algorithm of server-side encryption with customer keys.
+const AES256: &str = "AES256";
+/// The algorithm of server-side encryption with KMS keys.
+const AWS_KMS: &str = "aws:kms";
+
+/// A key of the customer S3 encrypts an object with (SSE-C).
+///
+/// The key, which S3 doesn't store, has to be sent with every request reading or writing the
+/// object: its `PutObject`, `GetObject` and `HeadObject` requests, the `CreateMultipartUpload`
+/// and every `UploadPart` request of its multipart uploads, and as the copy source key of the
+/// `CopyObject` and `UploadPartCopy` requests copying it.
+///
+/// ```rust,ignore
+/// let key = SseCustomerKey::new([0x42; 32]);
+/// let mut request = CopyObjectRequest { .. };
+/// key.apply_to_copy_source(&mut request);
+/// SseKms::new().key_id("alias/archive").bucket_key(true).apply(&mut request);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct SseCustomerKey {
+    key: [u8; 32],
+}
+
+impl SseCustomerKey {
+    /// The 256 bit AES key `key`.
+    pub fn new(key: [u8; 32]) -> SseCustomerKey {
+        SseCustomerKey { key }
+    }
+
+    /// The 256 bit AES key `key`, `None` unless it is 32 bytes long.
+    pub fn from_slice(key: &[u8]) -> Option<SseCustomerKey> {
+        if key.len() != 32 {
+            return None;
+        }
+        let mut bytes = [0; 32];
+        bytes.copy_from_slice(key);
+        Some(SseCustomerKey::new(bytes))
+    }
+
+    /// The key, base64 encoded as the `x-amz-server-side-encryption-customer-key` headers
+    /// have it.
+    pub fn key_base64(&self) -> String {
+        base64::encode(&self.key)
+    }
+
+    /// The MD5 digest of the key, base64 encoded as the
+    /// `x-amz-server-side-encryption-customer-key-MD5` headers have it.
+    pub fn key_md5(&self) -> String {
+        base64::encode(&md5::compute(self.key).0)
+    }
+
+    /// Sets the algorithm, key and key digest of `request` for an object encrypted with this
+    /// key.
+    pub fn apply<R: SseCustomerKeyRequest>(&self, request: &mut R) {
+        request.set_sse_customer_key(AES256.to_owned(), self.key_base64(), self.key_md5());
+    }
+
+    /// Sets the copy source algorithm, key and key digest of `request` for a copy of an object
+    /// encrypted with this key.
+    pub fn apply_to_copy_source<R: CopySourceSseCustomerKeyRequest>(&self, request: &mut R) {
+        request.set_copy_source_sse_customer_key(
+            AES256.to_owned(),
+            self.key_base64(),
+            self.key_md5(),
+        );
+    }
+}
+
+impl fmt::Debug for SseCustomerKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SseCustomerKey")
+            .field("key_md5", &self.key_md5())
+            .finish()
+    }
+}
+
+/// Server-side encryption with a KMS key (SSE-KMS), built from its optional settings.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SseKms {
+    key_id: Option<String>,
+    encryption_context: Option<HashMap<String, String>>,
+    bucket_key: Option<bool>,
+}
+
+impl SseKms {
+    /// Encryption with the AWS managed KMS key of S3 unless a key is set.
+    pub fn new() -> SseKms {
+        SseKms::default()
+    }
+
+    /// Encrypts with the KMS key whose id, ARN or alias is `key_id`.
+    pub fn key_id<S: Into<String>>(mut self, key_id: S) -> SseKms {
+        self.key_id = Some(key_id.into());
+        self
+    }
+
+    /// Encrypts with the encryption context `encryption_context`, which decrypting requires.
+    pub fn encryption_context(mut self, encryption_context: HashMap<String, String>) -> SseKms {
+        self.encryption_context = Some(encryption_context);
+        self
+    }
+
+    /// Whether S3 encrypts with a bucket key derived from the KMS key, sparing KMS requests,
+    /// rather than the bucket default.
+    pub fn bucket_key(mut self, enabled: bool) -> SseKms {
+        self.bucket_key = Some(enabled);
+        self
+    }
+
+    /// Sets the server-side encryption, KMS key, encryption context and bucket key fields of
+    /// `request`.
+    pub fn apply<R: SseKmsRequest>(&self, request: &mut R) {
+        let encryption_context = self.encryption_context.as_ref().map(|context| {
+            base64::encode(&serde_json::to_string(context).expect("a map of strings serializes"))
+        });
+        request.set_sse_kms(
+            AWS_KMS.to_owned(),
+            self.key_id.clone(),
+            encryption_context,
+            self.bucket_key,
+        );
+    }
+}
+
+/// A request for an object encrypted with a customer key.
+pub trait SseCustomerKeyRequest {
+    /// Sets the algorithm, base64 encoded key and base64 encoded key MD5 of the request.
+    fn set_sse_customer_key(&mut self, algorithm: String, key: String, key_md5: String);
+}
+
+/// A request copying an object encrypted with a customer key.
+pub trait CopySourceSseCustomerKeyRequest {
+    /// Sets the copy source algorithm, base64 encoded key and base64 encoded key MD5 of the
+    /// request.
+    fn set_copy_source_sse_customer_key(&mut self, algorithm: String, key: String, key_md5: String);
+}
+
+/// A request writing an object S3 encrypts with a KMS key.
+pub trait SseKmsRequest {
+    /// Sets the server-side encryption, KMS key id, base64 encoded encryption context and
+    /// bucket key of the request.
+    fn set_sse_kms(
+        &mut self,
+        server_side_encryption: String,
+        key_id: Option<String>,
+        encryption_context: Option<String>,
+        bucket_key_enabled: Option<bool>,
+    );
+}
+
+macro_rules! sse_customer_key_requests {
+    ($($request:ty),*) => {
+        $(
+            impl SseCustomerKeyRequest for $request {
+                fn set_sse_customer_key(&mut self, algorithm: String, key: String, key_md5: String) {
+                    self.sse_customer_algorithm = Some(algorithm);
+                    self.sse_customer_key = Some(key);
+                    self.sse_customer_key_md5 = Some(key_md5);
+                }
+            }
+        )*
+    };
+}
+
+sse_customer_key_requests!(
+    PutObjectRequest,
+    GetObjectRequest,
+    HeadObjectRequest,
+    CreateMultipartUploadRequest,
+    UploadPartRequest,
+    CopyObjectRequest,
+    UploadPartCopyRequest
+);
+
+macro_rules! copy_source_sse_customer_key_requests {
+    ($($request:ty),*) => {
+        $(
+            impl CopySourceSseCustomerKeyRequest for $request {
+                fn set_copy_source_sse_customer_key(
+                    &mut self,
+                    algorithm: String,
+                    key: String,
+                    key_md5: String,
+                ) {
+                    self.copy_source_sse_customer_algorithm = Some(algorithm);
+                    self.copy_source_sse_customer_key = Some(key);
+                    self.copy_source_sse_customer_key_md5 = Some(key_md5);
+                }
+            }
+        )*
+    };
+}
+
+copy_source_sse_customer_key_requests!(CopyObjectRequest, UploadPartCopyRequest);
+
+macro_rules! sse_kms_requests {
+    ($($request:ty),*) => {
+        $(
+            impl SseKmsRequest for $request {
+                fn set_sse_kms(
+                    &mut self,
+                    server_side_encryption: String,
+                    key_id: Option<String>,
+                    encryption_context: Option<String>,
+                    bucket_key_enabled: Option<bool>,
+                ) {
+                    self.server_side_encryption = Some(server_side_encryption);
+                    self.ssekms_key_id = key_id;
+                    self.ssekms_encryption_context = encryption_context;
+                    self.bucket_key_enabled = bucket_key_enabled;
+                }
+            }
+        )*
+    };
+}
+
+sse_kms_requests!(
+    PutObjectRequest,
+    CreateMultipartUploadRequest,
+    CopyObjectRequest
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sets_customer_keys() {
+        let key = SseCustomerKey::new([0; 32]);
+        let mut request = UploadPartCopyRequest::default();
+        key.apply(&mut request);
+        SseCustomerKey::from_slice(&[1; 32])
+            .unwrap()
+            .apply_to_copy_source(&mut request);
+        assert_eq!(request.sse_customer_algorithm.as_deref(), Some("AES256"));
+        assert_eq!(
+            request.sse_customer_key.as_deref(),
+            Some("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=")
+        );
+        assert_eq!(
+            request.sse_customer_key_md5.as_deref(),
+            Some("cLyPS3KoaSFGi/joRB3OUQ==")
+        );
+        assert_eq!(
+            request.copy_source_sse_customer_key.as_deref(),
+            Some("AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=")
+        );
+        assert!(SseCustomerKey::from_slice(&[0; 16]).is_none());
+        assert!(!format!("{:?}", key).contains("AAAA"));
+    }
+
+    #[test]
+    fn sets_kms_encryption() {
+        let mut context = HashMap::new();
+        context.insert("project".to_owned(), "archive".to_owned());
+        let mut request = PutObjectRequest::default();
+        SseKms::new()
+            .key_id("alias/archive")
+            .encryption_context(context)
+            .bucket_key(true)
+            .apply(&mut request);
+        assert_eq!(request.server_side_encryption.as_deref(), Some("aws:kms"));
+        assert_eq!(request.ssekms_key_id.as_deref(), Some("alias/archive"));
+        assert_eq!(
+            request.ssekms_encryption_context.as_deref(),
+            Some("eyJwcm9qZWN0IjoiYXJjaGl2ZSJ9")
+        );
+        assert_eq!(request.bucket_key_enabled, Some(true));
+
+        let mut request = CreateMultipartUploadRequest::default();
+        SseKms::new().apply(&mut request);
+        assert_eq!(request.server_side_encryption.as_deref(), Some("aws:kms"));
+        assert_eq!(request.ssekms_key_id, None);
+        assert_eq!(request.bucket_key_enabled, None);
+    }
+
+    #[tokio::test]
+    async fn sends_bucket_keys() {
+        use crate::generated::{S3Client, S3};
+        use rusoto_core::Region;
+        use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+        let dispatcher = MockRequestDispatcher::with_status(200).with_request_checker(|request| {
+            let header = |name: &str| request.headers().get(name).map(|values| values[0].clone());
+            assert_eq!(
+                header("x-amz-server-side-encryption-bucket-key-enabled"),
+                Some(b"true".to_vec())
+            );
+            assert_eq!(
+                header("x-amz-server-side-encryption"),
+                Some(b"aws:kms".to_vec())
+            );
+        });
+        let client = S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let mut request = PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        SseKms::new().bucket_key(true).apply(&mut request);
+        client.put_object(request).await.unwrap();
+    }
+}
@@ -0,0 +1,1076 @@
+//! Uploads and downloads of large objects in concurrent parts.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::future::Future;
+use std::io::{self, Write};
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use bytes::{Bytes, BytesMut};
+use futures::{stream, Stream, StreamExt, TryFutureExt, TryStreamExt};
+use rusoto_core::{ByteStream, RusotoError};
+
+use super::copy::MAX_COPY_OBJECT_SIZE;
+use crate::generated::{
+    AbortMultipartUploadError, AbortMultipartUploadRequest, CompleteMultipartUploadError,
+    CompleteMultipartUploadRequest, CompletedMultipartUpload, CompletedPart, CopyObjectError,
+    CreateMultipartUploadError, CreateMultipartUploadRequest, DeleteObjectsError, GetObjectError,
+    GetObjectRequest, HeadObjectError, HeadObjectOutput, HeadObjectRequest,
+    ListMultipartUploadsError, ListPartsError, PutObjectError, PutObjectOutput, PutObjectRequest,
+    UploadPartCopyError, UploadPartError, UploadPartRequest, S3,
+};
+
+/// The smallest part size S3 accepts, except for the last part.
+pub const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// The most parts of a multipart upload.
+const MAX_PARTS: u64 = 10_000;
+
+/// The default multipart threshold and part size.
+const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// The progress of a transfer, reported after every part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransferProgress {
+    /// The bytes transferred so far.
+    pub transferred: u64,
+    /// The size of the object, if known.
+    pub total: Option<u64>,
+}
+
+type ProgressCallback = Arc<dyn Fn(TransferProgress) + Send + Sync>;
+
+/// The bytes transferred by one upload or download.
+pub(crate) struct Tracker {
+    transferred: AtomicU64,
+    total: Option<u64>,
+    callback: Option<ProgressCallback>,
+}
+
+impl Tracker {
+    pub(crate) fn add(&self, bytes: u64) {
+        let transferred = self.transferred.fetch_add(bytes, Ordering::SeqCst) + bytes;
+        if let Some(ref callback) = self.callback {
+            callback(TransferProgress {
+                transferred,
+                total: self.total,
+            });
+        }
+    }
+}
+
+/// Uploads and downloads objects, in concurrent parts above a size threshold.
+///
+/// Uploads switch to a multipart upload when the body is larger than the multipart threshold,
+/// uploading up to `concurrency` parts at a time and aborting the upload if a part fails for
+/// good. Downloads of objects larger than the threshold are split into ranged `GetObject`
+/// requests, `concurrency` at a time, and streamed in order. Failed parts are retried up to
+/// `part_retries` times on top of the retries of the client.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::{PutObjectRequest, S3Client, TransferManager};
+///
+/// # async fn upload(data: Vec<u8>) -> Result<(), rusoto_s3::TransferError> {
+/// let mut manager = TransferManager::new(S3Client::new(Region::UsEast1));
+/// manager.set_concurrency(8);
+/// manager.set_progress(|progress| println!("{} bytes sent", progress.transferred));
+/// manager
+///     .upload(PutObjectRequest {
+///         bucket: "my-bucket".to_owned(),
+///         key: "backup.tar".to_owned(),
+///         body: Some(data.into()),
+///         ..Default::default()
+///     })
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct TransferManager<C> {
+    pub(crate) client: Arc<C>,
+    multipart_threshold: usize,
+    part_size: usize,
+    pub(crate) concurrency: usize,
+    pub(crate) part_retries: u32,
+    pub(crate) copy_threshold: u64,
+    verify_downloads: bool,
+    progress: Option<ProgressCallback>,
+}
+
+impl<C> TransferManager<C>
+where
+    C: S3 + Send + Sync + 'static,
+{
+    /// Creates a transfer manager sending its requests through `client`.
+    pub fn new(client: C) -> TransferManager<C> {
+        TransferManager {
+            client: Arc::new(client),
+            multipart_threshold: DEFAULT_PART_SIZE,
+            part_size: DEFAULT_PART_SIZE,
+            concurrency: 4,
+            part_retries: 3,
+            copy_threshold: MAX_COPY_OBJECT_SIZE,
+            verify_downloads: true,
+            progress: None,
+        }
+    }
+
+    /// Sets the size above which objects are transferred in parts, 8 MiB by default.
+    pub fn set_multipart_threshold(&mut self, multipart_threshold: usize) {
+        self.multipart_threshold = multipart_threshold;
+    }
+
+    /// Sets the size of the parts, 8 MiB by default. S3 rejects uploads with parts, other than
+    /// the last, smaller than `MIN_PART_SIZE`. Uploads of a known content length raise the part
+    /// size as needed to fit in the 10,000 parts S3 allows.
+    pub fn set_part_size(&mut self, part_size: usize) {
+        self.part_size = part_size.max(1);
+    }
+
+    /// Sets how many parts are transferred at a time, 4 by default.
+    pub fn set_concurrency(&mut self, concurrency: usize) {
+        self.concurrency = concurrency.max(1);
+    }
+
+    /// Sets how many times a failed part is retried, 3 by default.
+    pub fn set_part_retries(&mut self, part_retries: u32) {
+        self.part_retries = part_retries;
+    }
+
+    /// Sets the size above which `copy_object_multipart` copies objects in parts, at most and by
+    /// default `MAX_COPY_OBJECT_SIZE`.
+    pub fn set_copy_threshold(&mut self, copy_threshold: u64) {
+        self.copy_threshold = copy_threshold.min(MAX_COPY_OBJECT_SIZE);
+    }
+
+    /// Sets whether downloads are checked against the entity tag of the object, true by default.
+    ///
+    /// The entity tags of objects that are neither encrypted with SSE-KMS nor with a customer key
+    /// are the MD5 digest of their data or, for objects uploaded in parts, of the digests of their
+    /// parts. Downloads of such objects end with an error if their data doesn't match, which is
+    /// only detected once the whole object has been read.
+    pub fn set_verify_downloads(&mut self, verify_downloads: bool) {
+        self.verify_downloads = verify_downloads;
+    }
+
+    /// Sets a callback called with the progress of transfers after every part.
+    pub fn set_progress<F>(&mut self, progress: F)
+    where
+        F: Fn(TransferProgress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(progress));
+    }
+
+    /// The part size of an upload of `total` bytes, raised to fit in the parts S3 allows.
+    pub(crate) fn part_size_for(&self, total: Option<u64>) -> usize {
+        match total {
+            Some(total) => self
+                .part_size
+                .max(((total + MAX_PARTS - 1) / MAX_PARTS) as usize),
+            None => self.part_size,
+        }
+    }
+
+    pub(crate) fn tracker(&self, total: Option<u64>) -> Arc<Tracker> {
+        Arc::new(Tracker {
+            transferred: AtomicU64::new(0),
+            total,
+            callback: self.progress.clone(),
+        })
+    }
+
+    /// Uploads the body of `request`, with a single `PutObject` request if it is at most the
+    /// multipart threshold and with a multipart upload carrying the settings of `request`
+    /// otherwise. The content length of `request`, if set, is only used for the progress and the
+    /// part size; its content MD5 is only sent with single requests.
+    pub async fn upload(
+        &self,
+        mut request: PutObjectRequest,
+    ) -> Result<PutObjectOutput, TransferError> {
+        let body = request
+            .body
+            .take()
+            .unwrap_or_else(|| ByteStream::from(Vec::new()));
+        let total = request.content_length.map(|length| length as u64);
+        let tracker = self.tracker(total);
+        let mut reader = PartReader::new(body);
+        reader
+            .fill(self.multipart_threshold + 1)
+            .await
+            .map_err(TransferError::Io)?;
+        if reader.buffer.len() <= self.multipart_threshold {
+            let data = reader.buffer.split().freeze();
+            let output = retrying(self.part_retries, || {
+                let mut attempt = clone_put_object_request(&request);
+                attempt.content_length = Some(data.len() as i64);
+                attempt.body = Some(ByteStream::from(data.clone()));
+                self.client
+                    .put_object(attempt)
+                    .map_err(TransferError::PutObject)
+            })
+            .await?;
+            tracker.add(data.len() as u64);
+            return Ok(output);
+        }
+
+        let part_size = self.part_size_for(total);
+        let created = self
+            .client
+            .create_multipart_upload(create_multipart_upload_request(&request))
+            .await
+            .map_err(TransferError::CreateMultipartUpload)?;
+        let upload_id = created.upload_id.unwrap_or_default();
+        let result = self
+            .upload_parts(&request, &upload_id, reader, part_size, &tracker)
+            .await;
+        match result {
+            Ok(output) => Ok(output),
+            Err(error) => {
+                let _ = self
+                    .client
+                    .abort_multipart_upload(AbortMultipartUploadRequest {
+                        bucket: request.bucket.clone(),
+                        key: request.key.clone(),
+                        request_payer: request.request_payer.clone(),
+                        upload_id,
+                    })
+                    .await;
+                Err(error)
+            }
+        }
+    }
+
+    async fn upload_parts(
+        &self,
+        request: &PutObjectRequest,
+        upload_id: &str,
+        reader: PartReader,
+        part_size: usize,
+        tracker: &Tracker,
+    ) -> Result<PutObjectOutput, TransferError> {
+        let parts = stream::unfold((reader, 1), move |(mut reader, number)| async move {
+            match reader.next_part(part_size).await {
+                Ok(Some(data)) => Some((Ok((number, data)), (reader, number + 1))),
+                Ok(None) => None,
+                Err(error) => Some((Err(TransferError::Io(error)), (reader, number))),
+            }
+        });
+        let mut completed: Vec<CompletedPart> = parts
+            .map_ok(|(number, data)| self.upload_part(request, upload_id, number, data, tracker))
+            .try_buffer_unordered(self.concurrency)
+            .try_collect()
+            .await?;
+        completed.sort_by_key(|part| part.part_number);
+
+        let output = self
+            .client
+            .complete_multipart_upload(CompleteMultipartUploadRequest {
+                bucket: request.bucket.clone(),
+                key: request.key.clone(),
+                multipart_upload: Some(CompletedMultipartUpload {
+                    parts: Some(completed),
+                }),
+                request_payer: request.request_payer.clone(),
+                upload_id: upload_id.to_owned(),
+            })
+            .await
+            .map_err(TransferError::CompleteMultipartUpload)?;
+        Ok(PutObjectOutput {
+            e_tag: output.e_tag,
+            expiration: output.expiration,
+            request_charged: output.request_charged,
+            ssekms_key_id: output.ssekms_key_id,
+            server_side_encryption: output.server_side_encryption,
+            version_id: output.version_id,
+            ..Default::default()
+        })
+    }
+
+    pub(crate) async fn upload_part(
+        &self,
+        request: &PutObjectRequest,
+        upload_id: &str,
+        part_number: i64,
+        data: Bytes,
+        tracker: &Tracker,
+    ) -> Result<CompletedPart, TransferError> {
+        let output = retrying(self.part_retries, || {
+            self.client
+                .upload_part(UploadPartRequest {
+                    body: Some(ByteStream::from(data.clone())),
+                    bucket: request.bucket.clone(),
+                    content_length: Some(data.len() as i64),
+                    key: request.key.clone(),
+                    part_number,
+                    request_payer: request.request_payer.clone(),
+                    sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+                    sse_customer_key: request.sse_customer_key.clone(),
+                    sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+                    upload_id: upload_id.to_owned(),
+                    ..Default::default()
+                })
+                .map_err(TransferError::UploadPart)
+        })
+        .await?;
+        tracker.add(data.len() as u64);
+        Ok(CompletedPart {
+            e_tag: output.e_tag,
+            part_number: Some(part_number),
+        })
+    }
+
+    /// Downloads the object of `request`, with a single `GetObject` request if it is at most the
+    /// multipart threshold or `request` asks for a range or a part, and with concurrent ranged
+    /// requests conditional on the entity tag of the object otherwise. The parts are streamed in
+    /// order, so at most `concurrency` parts are buffered, and checked as set with
+    /// `set_verify_downloads`.
+    pub async fn download(&self, request: GetObjectRequest) -> Result<ByteStream, TransferError> {
+        if request.range.is_some() || request.part_number.is_some() {
+            let tracker = self.tracker(None);
+            return self.get_whole(request, tracker).await;
+        }
+        let head = retrying(self.part_retries, || {
+            self.client
+                .head_object(head_object_request(&request))
+                .map_err(TransferError::HeadObject)
+        })
+        .await?;
+        let size = head.content_length.unwrap_or(0) as u64;
+        let verifier = if self.verify_downloads {
+            self.verifier(&request, &head, size).await?
+        } else {
+            None
+        };
+        let tracker = self.tracker(Some(size));
+        let request = GetObjectRequest {
+            if_match: request.if_match.clone().or(head.e_tag),
+            ..request
+        };
+        let body = if size <= self.multipart_threshold as u64 {
+            self.get_whole(request, tracker).await?
+        } else {
+            self.get_ranges(request, size, tracker)
+        };
+        Ok(match verifier {
+            Some(verifier) => verified(body, verifier, size),
+            None => body,
+        })
+    }
+
+    /// Downloads the object of `request` to the file at `path` like `download`, returning its
+    /// size. The file is left partially written if the download fails.
+    pub async fn download_to_file<P: AsRef<Path>>(
+        &self,
+        request: GetObjectRequest,
+        path: P,
+    ) -> Result<u64, TransferError> {
+        let mut body = self.download(request).await?;
+        let mut file = File::create(path).map_err(TransferError::Io)?;
+        let mut written = 0;
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(TransferError::from_io)?;
+            file.write_all(&chunk).map_err(TransferError::Io)?;
+            written += chunk.len() as u64;
+        }
+        file.flush().map_err(TransferError::Io)?;
+        Ok(written)
+    }
+
+    /// The verifier of the entity tag of an object, if it is an MD5 digest.
+    async fn verifier(
+        &self,
+        request: &GetObjectRequest,
+        head: &HeadObjectOutput,
+        size: u64,
+    ) -> Result<Option<Md5Verifier>, TransferError> {
+        if head.server_side_encryption.as_deref() == Some("aws:kms")
+            || head.sse_customer_algorithm.is_some()
+        {
+            return Ok(None);
+        }
+        let e_tag = match head.e_tag {
+            Some(ref e_tag) => e_tag.trim_matches('"'),
+            None => return Ok(None),
+        };
+        let (digest, parts) = match e_tag.find('-') {
+            Some(dash) => match e_tag[dash + 1..].parse::<u64>() {
+                Ok(parts) if parts > 0 => (&e_tag[..dash], Some(parts)),
+                _ => return Ok(None),
+            },
+            None => (e_tag, None),
+        };
+        if digest.len() != 32 || !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Ok(None);
+        }
+        let parts = match parts {
+            Some(parts) => parts,
+            None => return Ok(Some(Md5Verifier::new(e_tag, None))),
+        };
+        // The boundaries of the parts follow from the size of the first if all but the last have
+        // the same size, as they do for uploads by this and other SDKs.
+        let first = retrying(self.part_retries, || {
+            self.client
+                .head_object(HeadObjectRequest {
+                    part_number: Some(1),
+                    if_match: head.e_tag.clone(),
+                    ..head_object_request(request)
+                })
+                .map_err(TransferError::HeadObject)
+        })
+        .await?;
+        let part_size = first.content_length.unwrap_or(0) as u64;
+        if first.parts_count != Some(parts as i64)
+            || part_size == 0
+            || size <= (parts - 1) * part_size
+            || size > parts * part_size
+        {
+            return Ok(None);
+        }
+        Ok(Some(Md5Verifier::new(e_tag, Some(part_size))))
+    }
+
+    fn get_ranges(
+        &self,
+        request: GetObjectRequest,
+        size: u64,
+        tracker: Arc<Tracker>,
+    ) -> ByteStream {
+        let part_size = self.part_size as u64;
+        let ranges = (0..size)
+            .step_by(self.part_size)
+            .map(move |start| (start, (start + part_size).min(size) - 1));
+        let client = self.client.clone();
+        let retries = self.part_retries;
+        let parts = stream::iter(ranges)
+            .map(move |(start, end)| {
+                let request = GetObjectRequest {
+                    range: Some(format!("bytes={}-{}", start, end)),
+                    ..request.clone()
+                };
+                get_range(
+                    client.clone(),
+                    request,
+                    (end - start + 1) as usize,
+                    retries,
+                    tracker.clone(),
+                )
+            })
+            .buffered(self.concurrency)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err));
+        ByteStream::new_with_size(SyncStream::new(parts), size as usize)
+    }
+
+    async fn get_whole(
+        &self,
+        request: GetObjectRequest,
+        tracker: Arc<Tracker>,
+    ) -> Result<ByteStream, TransferError> {
+        let output = retrying(self.part_retries, || {
+            self.client
+                .get_object(request.clone())
+                .map_err(TransferError::GetObject)
+        })
+        .await?;
+        let body = output
+            .body
+            .unwrap_or_else(|| ByteStream::from(Vec::new()))
+            .inspect_ok(move |chunk| tracker.add(chunk.len() as u64));
+        Ok(match output.content_length {
+            Some(length) => ByteStream::new_with_size(body, length as usize),
+            None => ByteStream::new(body),
+        })
+    }
+}
+
+impl<C> fmt::Debug for TransferManager<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransferManager")
+            .field("multipart_threshold", &self.multipart_threshold)
+            .field("part_size", &self.part_size)
+            .field("concurrency", &self.concurrency)
+            .field("part_retries", &self.part_retries)
+            .finish()
+    }
+}
+
+/// Downloads `length` bytes of the range of `request`, retrying failed requests and bodies
+/// cut short.
+async fn get_range<C>(
+    client: Arc<C>,
+    request: GetObjectRequest,
+    length: usize,
+    retries: u32,
+    tracker: Arc<Tracker>,
+) -> Result<Bytes, TransferError>
+where
+    C: S3 + Send + Sync,
+{
+    let data = retrying(retries, || async {
+        let output = client
+            .get_object(request.clone())
+            .await
+            .map_err(TransferError::GetObject)?;
+        let data = read_body(output.body).await.map_err(TransferError::Io)?;
+        if data.len() != length {
+            return Err(TransferError::Io(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("got {} bytes of a part of {} bytes", data.len(), length),
+            )));
+        }
+        Ok(data)
+    })
+    .await?;
+    tracker.add(length as u64);
+    Ok(data)
+}
+
+/// Computes the entity tag of an object as it is read, in parts of `part_size` if the object
+/// was uploaded in parts.
+struct Md5Verifier {
+    expected: String,
+    part_size: Option<u64>,
+    part: md5::Context,
+    in_part: u64,
+    digests: Vec<u8>,
+    parts: usize,
+}
+
+impl Md5Verifier {
+    fn new(expected: &str, part_size: Option<u64>) -> Md5Verifier {
+        Md5Verifier {
+            expected: expected.to_owned(),
+            part_size,
+            part: md5::Context::new(),
+            in_part: 0,
+            digests: Vec::new(),
+            parts: 0,
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        let part_size = match self.part_size {
+            Some(part_size) => part_size,
+            None => return self.part.consume(data),
+        };
+        while !data.is_empty() {
+            let length = std::cmp::min(part_size - self.in_part, data.len() as u64) as usize;
+            self.part.consume(&data[..length]);
+            self.in_part += length as u64;
+            data = &data[length..];
+            if self.in_part == part_size {
+                self.finish_part();
+            }
+        }
+    }
+
+    fn finish_part(&mut self) {
+        let part = std::mem::replace(&mut self.part, md5::Context::new());
+        self.digests.extend_from_slice(&part.compute().0);
+        self.in_part = 0;
+        self.parts += 1;
+    }
+
+    /// The error of a mismatch, if the entity tag computed doesn't match the expected one.
+    fn mismatch(mut self) -> Option<TransferError> {
+        let computed = match self.part_size {
+            Some(_) => {
+                if self.in_part > 0 {
+                    self.finish_part();
+                }
+                format!("{:x}-{}", md5::compute(&self.digests), self.parts)
+            }
+            None => format!("{:x}", self.part.compute()),
+        };
+        if computed == self.expected {
+            return None;
+        }
+        Some(TransferError::ChecksumMismatch {
+            expected: self.expected,
+            computed,
+        })
+    }
+}
+
+/// Passes `body` through `verifier`, ending with an error if the object doesn't match.
+fn verified(body: ByteStream, verifier: Md5Verifier, size: u64) -> ByteStream {
+    let chunks = stream::unfold(
+        (body, Some(verifier)),
+        |(mut body, mut verifier)| async move {
+            match body.next().await {
+                Some(Ok(chunk)) => {
+                    if let Some(ref mut verifier) = verifier {
+                        verifier.update(&chunk);
+                    }
+                    Some((Ok(chunk), (body, verifier)))
+                }
+                Some(Err(error)) => Some((Err(error), (body, None))),
+                None => verifier.and_then(Md5Verifier::mismatch).map(|error| {
+                    let error = io::Error::new(io::ErrorKind::InvalidData, error);
+                    (Err(error), (body, None))
+                }),
+            }
+        },
+    );
+    ByteStream::new_with_size(SyncStream::new(chunks), size as usize)
+}
+
+async fn read_body(body: Option<ByteStream>) -> io::Result<Bytes> {
+    let mut data = BytesMut::new();
+    if let Some(mut body) = body {
+        while let Some(chunk) = body.next().await {
+            data.extend_from_slice(&chunk?);
+        }
+    }
+    Ok(data.freeze())
+}
+
+/// Runs `attempt` until it succeeds, fails with an error that is not retryable or has been
+/// retried `retries` times.
+pub(crate) async fn retrying<T, F, Fut>(retries: u32, mut attempt: F) -> Result<T, TransferError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, TransferError>>,
+{
+    let mut failures = 0;
+    loop {
+        match attempt().await {
+            Err(ref error) if failures < retries && error.is_retryable() => failures += 1,
+            result => return result,
+        }
+    }
+}
+
+/// Reads a body in parts.
+struct PartReader {
+    body: ByteStream,
+    buffer: BytesMut,
+    eof: bool,
+}
+
+impl PartReader {
+    fn new(body: ByteStream) -> PartReader {
+        PartReader {
+            body,
+            buffer: BytesMut::new(),
+            eof: false,
+        }
+    }
+
+    /// Reads until `size` bytes are buffered or the body ends.
+    async fn fill(&mut self, size: usize) -> io::Result<()> {
+        while !self.eof && self.buffer.len() < size {
+            match self.body.next().await {
+                Some(chunk) => self.buffer.extend_from_slice(&chunk?),
+                None => self.eof = true,
+            }
+        }
+        Ok(())
+    }
+
+    /// The next part of at most `size` bytes, `None` at the end of the body.
+    async fn next_part(&mut self, size: usize) -> io::Result<Option<Bytes>> {
+        self.fill(size).await?;
+        if self.buffer.is_empty() {
+            return Ok(None);
+        }
+        let size = size.min(self.buffer.len());
+        Ok(Some(self.buffer.split_to(size).freeze()))
+    }
+}
+
+/// Makes a stream `Sync`, as a `ByteStream` requires, by only ever polling it through
+/// `Mutex::get_mut`, which cannot block.
+pub(crate) struct SyncStream<S>(Mutex<Pin<Box<S>>>);
+
+impl<S> SyncStream<S> {
+    pub(crate) fn new(stream: S) -> SyncStream<S> {
+        SyncStream(Mutex::new(Box::pin(stream)))
+    }
+}
+
+impl<S: Stream> Stream for SyncStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        match self.0.get_mut() {
+            Ok(stream) => stream.as_mut().poll_next(cx),
+            Err(poisoned) => poisoned.into_inner().as_mut().poll_next(cx),
+        }
+    }
+}
+
+fn clone_put_object_request(request: &PutObjectRequest) -> PutObjectRequest {
+    PutObjectRequest {
+        acl: request.acl.clone(),
+        body: None,
+        bucket: request.bucket.clone(),
+        bucket_key_enabled: request.bucket_key_enabled,
+        cache_control: request.cache_control.clone(),
+        content_disposition: request.content_disposition.clone(),
+        content_encoding: request.content_encoding.clone(),
+        content_language: request.content_language.clone(),
+        content_length: request.content_length,
+        content_md5: request.content_md5.clone(),
+        content_type: request.content_type.clone(),
+        expires: request.expires.clone(),
+        grant_full_control: request.grant_full_control.clone(),
+        grant_read: request.grant_read.clone(),
+        grant_read_acp: request.grant_read_acp.clone(),
+        grant_write_acp: request.grant_write_acp.clone(),
+        key: request.key.clone(),
+        metadata: request.metadata.clone(),
+        object_lock_legal_hold_status: request.object_lock_legal_hold_status.clone(),
+        object_lock_mode: request.object_lock_mode.clone(),
+        object_lock_retain_until_date: request.object_lock_retain_until_date.clone(),
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+        sse_customer_key: request.sse_customer_key.clone(),
+        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+        ssekms_encryption_context: request.ssekms_encryption_context.clone(),
+        ssekms_key_id: request.ssekms_key_id.clone(),
+        server_side_encryption: request.server_side_encryption.clone(),
+        storage_class: request.storage_class.clone(),
+        tagging: request.tagging.clone(),
+        website_redirect_location: request.website_redirect_location.clone(),
+    }
+}
+
+pub(crate) fn create_multipart_upload_request(
+    request: &PutObjectRequest,
+) -> CreateMultipartUploadRequest {
+    CreateMultipartUploadRequest {
+        acl: request.acl.clone(),
+        bucket: request.bucket.clone(),
+        bucket_key_enabled: request.bucket_key_enabled,
+        cache_control: request.cache_control.clone(),
+        content_disposition: request.content_disposition.clone(),
+        content_encoding: request.content_encoding.clone(),
+        content_language: request.content_language.clone(),
+        content_type: request.content_type.clone(),
+        expires: request.expires.clone(),
+        grant_full_control: request.grant_full_control.clone(),
+        grant_read: request.grant_read.clone(),
+        grant_read_acp: request.grant_read_acp.clone(),
+        grant_write_acp: request.grant_write_acp.clone(),
+        key: request.key.clone(),
+        metadata: request.metadata.clone(),
+        object_lock_legal_hold_status: request.object_lock_legal_hold_status.clone(),
+        object_lock_mode: request.object_lock_mode.clone(),
+        object_lock_retain_until_date: request.object_lock_retain_until_date.clone(),
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+        sse_customer_key: request.sse_customer_key.clone(),
+        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+        ssekms_encryption_context: request.ssekms_encryption_context.clone(),
+        ssekms_key_id: request.ssekms_key_id.clone(),
+        server_side_encryption: request.server_side_encryption.clone(),
+        storage_class: request.storage_class.clone(),
+        tagging: request.tagging.clone(),
+        website_redirect_location: request.website_redirect_location.clone(),
+    }
+}
+
+fn head_object_request(request: &GetObjectRequest) -> HeadObjectRequest {
+    HeadObjectRequest {
+        bucket: request.bucket.clone(),
+        if_match: request.if_match.clone(),
+        if_modified_since: request.if_modified_since.clone(),
+        if_none_match: request.if_none_match.clone(),
+        if_unmodified_since: request.if_unmodified_since.clone(),
+        key: request.key.clone(),
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+        sse_customer_key: request.sse_customer_key.clone(),
+        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+        version_id: request.version_id.clone(),
+        ..Default::default()
+    }
+}
+
+/// An error transferring an object, carrying the error of the request that failed.
+#[derive(Debug)]
+pub enum TransferError {
+    /// A `PutObject` request failed.
+    PutObject(RusotoError<PutObjectError>),
+    /// A `CreateMultipartUpload` request failed.
+    CreateMultipartUpload(RusotoError<CreateMultipartUploadError>),
+    /// An `UploadPart` request failed.
+    UploadPart(RusotoError<UploadPartError>),
+    /// A `CopyObject` request failed.
+    CopyObject(RusotoError<CopyObjectError>),
+    /// An `UploadPartCopy` request failed.
+    UploadPartCopy(RusotoError<UploadPartCopyError>),
+    /// A `CompleteMultipartUpload` request failed.
+    CompleteMultipartUpload(RusotoError<CompleteMultipartUploadError>),
+    /// A `HeadObject` request failed.
+    HeadObject(RusotoError<HeadObjectError>),
+    /// A `GetObject` request failed.
+    GetObject(RusotoError<GetObjectError>),
+    /// A `ListParts` request failed.
+    ListParts(RusotoError<ListPartsError>),
+    /// A `DeleteObjects` request failed.
+    DeleteObjects(RusotoError<DeleteObjectsError>),
+    /// A `ListMultipartUploads` request failed.
+    ListMultipartUploads(RusotoError<ListMultipartUploadsError>),
+    /// An `AbortMultipartUpload` request failed.
+    AbortMultipartUpload(RusotoError<AbortMultipartUploadError>),
+    /// Reading the body to upload or a downloaded body failed.
+    Io(io::Error),
+    /// Loading, saving or clearing the state of a resumable upload failed.
+    StateStore(io::Error),
+    /// The checksum of a downloaded object doesn't match the one S3 has.
+    ChecksumMismatch {
+        /// The checksum S3 has.
+        expected: String,
+        /// The checksum of the data downloaded.
+        computed: String,
+    },
+}
+
+impl TransferError {
+    /// The error carried by an error of a stream of this module, or the I/O error itself.
+    fn from_io(error: io::Error) -> TransferError {
+        match error.get_ref().map(|inner| inner.is::<TransferError>()) {
+            Some(true) => *error
+                .into_inner()
+                .and_then(|inner| inner.downcast().ok())
+                .expect("checked to carry a TransferError"),
+            _ => TransferError::Io(error),
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        match self {
+            TransferError::PutObject(e) => e.is_retryable(),
+            TransferError::CreateMultipartUpload(e) => e.is_retryable(),
+            TransferError::UploadPart(e) => e.is_retryable(),
+            TransferError::CompleteMultipartUpload(e) => e.is_retryable(),
+            TransferError::HeadObject(e) => e.is_retryable(),
+            TransferError::GetObject(e) => e.is_retryable(),
+            TransferError::ListParts(e) => e.is_retryable(),
+            TransferError::DeleteObjects(e) => e.is_retryable(),
+            TransferError::ListMultipartUploads(e) => e.is_retryable(),
+            TransferError::AbortMultipartUpload(e) => e.is_retryable(),
+            TransferError::CopyObject(e) => e.is_retryable(),
+            TransferError::UploadPartCopy(e) => e.is_retryable(),
+            TransferError::Io(_) => true,
+            TransferError::StateStore(_) => false,
+            TransferError::ChecksumMismatch { .. } => false,
+        }
+    }
+}
+
+impl fmt::Display for TransferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransferError::PutObject(e) => write!(f, "PutObject failed: {}", e),
+            TransferError::CreateMultipartUpload(e) => {
+                write!(f, "CreateMultipartUpload failed: {}", e)
+            }
+            TransferError::UploadPart(e) => write!(f, "UploadPart failed: {}", e),
+            TransferError::CompleteMultipartUpload(e) => {
+                write!(f, "CompleteMultipartUpload failed: {}", e)
+            }
+            TransferError::HeadObject(e) => write!(f, "HeadObject failed: {}", e),
+            TransferError::GetObject(e) => write!(f, "GetObject failed: {}", e),
+            TransferError::ListParts(e) => write!(f, "ListParts failed: {}", e),
+            TransferError::DeleteObjects(e) => write!(f, "DeleteObjects failed: {}", e),
+            TransferError::ListMultipartUploads(e) => {
+                write!(f, "ListMultipartUploads failed: {}", e)
+            }
+            TransferError::AbortMultipartUpload(e) => {
+                write!(f, "AbortMultipartUpload failed: {}", e)
+            }
+            TransferError::CopyObject(e) => write!(f, "CopyObject failed: {}", e),
+            TransferError::UploadPartCopy(e) => write!(f, "UploadPartCopy failed: {}", e),
+            TransferError::Io(e) => write!(f, "{}", e),
+            TransferError::StateStore(e) => write!(f, "upload state store failed: {}", e),
+            TransferError::ChecksumMismatch { expected, computed } => write!(
+                f,
+                "the checksum of the object is {} but its data has {}",
+                expected, computed
+            ),
+        }
+    }
+}
+
+impl Error for TransferError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TransferError::PutObject(e) => Some(e),
+            TransferError::CreateMultipartUpload(e) => Some(e),
+            TransferError::UploadPart(e) => Some(e),
+            TransferError::CompleteMultipartUpload(e) => Some(e),
+            TransferError::HeadObject(e) => Some(e),
+            TransferError::GetObject(e) => Some(e),
+            TransferError::ListParts(e) => Some(e),
+            TransferError::DeleteObjects(e) => Some(e),
+            TransferError::ListMultipartUploads(e) => Some(e),
+            TransferError::AbortMultipartUpload(e) => Some(e),
+            TransferError::CopyObject(e) => Some(e),
+            TransferError::UploadPartCopy(e) => Some(e),
+            TransferError::Io(e) => Some(e),
+            TransferError::StateStore(e) => Some(e),
+            TransferError::ChecksumMismatch { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom::fake_s3::FakeS3;
+
+    fn manager(s3: &FakeS3) -> TransferManager<crate::S3Client> {
+        let mut manager = TransferManager::new(s3.client());
+        manager.set_multipart_threshold(10);
+        manager.set_part_size(4);
+        manager.set_concurrency(2);
+        manager
+    }
+
+    fn put(data: &[u8]) -> PutObjectRequest {
+        PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            body: Some(data.to_vec().into()),
+            content_type: Some("text/plain".to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn uploads_small_objects_with_one_request() {
+        let s3 = FakeS3::default();
+        manager(&s3).upload(put(b"0123456789")).await.unwrap();
+        let state = s3.state();
+        assert_eq!(state.requests, vec!["PUT /bucket/key"]);
+        assert_eq!(state.objects["/bucket/key"], b"0123456789");
+    }
+
+    #[tokio::test]
+    async fn uploads_large_objects_in_parts() {
+        let s3 = FakeS3::default();
+        s3.state().failures = 1;
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = manager(&s3);
+        let reported = progress.clone();
+        manager.set_progress(move |progress| reported.lock().unwrap().push(progress.transferred));
+        let output = manager.upload(put(b"0123456789ab")).await.unwrap();
+        assert!(output.e_tag.unwrap().ends_with("-3\""));
+
+        let state = s3.state();
+        assert_eq!(state.objects["/bucket/key"], b"0123456789ab");
+        let parts = state
+            .requests
+            .iter()
+            .filter(|request| request.ends_with("?uploadId?partNumber"))
+            .count();
+        // Three parts, one of which failed once.
+        assert_eq!(parts, 4);
+        assert_eq!(progress.lock().unwrap().last(), Some(&12));
+    }
+
+    #[tokio::test]
+    async fn aborts_failed_uploads() {
+        let s3 = FakeS3::default();
+        s3.state().failures = 100;
+        let error = manager(&s3).upload(put(b"0123456789ab")).await;
+        assert!(matches!(error, Err(TransferError::UploadPart(_))));
+        let state = s3.state();
+        assert!(state.uploads.is_empty());
+        assert_eq!(
+            state.requests.last().unwrap(),
+            "DELETE /bucket/key?uploadId"
+        );
+        assert!(state.objects.is_empty());
+    }
+
+    #[tokio::test]
+    async fn downloads_large_objects_in_ranges() {
+        let s3 = FakeS3::default();
+        let data: Vec<u8> = (0..=255).collect();
+        s3.state()
+            .objects
+            .insert("/bucket/key".to_owned(), data.clone());
+        s3.state().failures = 1;
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        let mut manager = manager(&s3);
+        manager.set_part_size(100);
+        let body = manager.download(request.clone()).await.unwrap();
+        assert_eq!(read_body(Some(body)).await.unwrap(), data);
+        let ranges = s3
+            .state()
+            .requests
+            .iter()
+            .filter(|request| request.starts_with("GET"))
+            .count();
+        assert_eq!(ranges, 4);
+
+        manager.set_multipart_threshold(1000);
+        let body = manager.download(request).await.unwrap();
+        assert_eq!(read_body(Some(body)).await.unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn fails_downloads_not_matching_the_entity_tag() {
+        let s3 = FakeS3::default();
+        s3.state()
+            .objects
+            .insert("/bucket/key".to_owned(), b"0123456789ab".to_vec());
+        s3.state().etags.insert(
+            "/bucket/key".to_owned(),
+            format!("\"{:x}\"", md5::compute(b"tampered")),
+        );
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        let path = std::env::temp_dir().join(format!("rusoto-s3-tampered-{}", std::process::id()));
+        let error = manager(&s3).download_to_file(request.clone(), &path).await;
+        assert!(matches!(error, Err(TransferError::ChecksumMismatch { .. })));
+        std::fs::remove_file(&path).unwrap();
+
+        let mut manager = manager(&s3);
+        manager.set_verify_downloads(false);
+        let body = manager.download(request).await.unwrap();
+        assert_eq!(read_body(Some(body)).await.unwrap(), &b"0123456789ab"[..]);
+    }
+
+    #[tokio::test]
+    async fn verifies_downloads_of_objects_uploaded_in_parts() {
+        let s3 = FakeS3::default();
+        let data: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let mut manager = manager(&s3);
+        manager.set_part_size(300);
+        manager.upload(put(&data)).await.unwrap();
+
+        manager.set_part_size(128);
+        let path = std::env::temp_dir().join(format!("rusoto-s3-download-{}", std::process::id()));
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        let size = manager.download_to_file(request, &path).await.unwrap();
+        assert_eq!(size, 1000);
+        assert_eq!(std::fs::read(&path).unwrap(), data);
+        std::fs::remove_file(&path).unwrap();
+        assert!(s3
+            .state()
+            .requests
+            .contains(&"HEAD /bucket/key?partNumber".to_owned()));
+    }
+}
@@ -157,6 +157,7 @@ impl PreSignedRequest for PutObjectRequest {
             grant_full_control, "x-amz-grant-full-control";
             server_side_encryption, "x-amz-server-side-encryption";
             ssekms_key_id, "x-amz-server-side-encryption-aws-kms-key-id";
+            bucket_key_enabled, "x-amz-server-side-encryption-bucket-key-enabled";
             // AWS document has x-amz-server-side-encryption-context parameter but PutObjectRequest does'nt have it.
             //kms_context, "x-amz-server-side-encryption-context";
             sse_customer_algorithm, "x-amz-server-side-encryption-customer-algorithm";
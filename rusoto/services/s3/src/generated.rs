@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -2201,6 +2201,8 @@ pub struct CopyObjectRequest {
     pub acl: Option<String>,
     /// <p>The name of the destination bucket.</p>
     pub bucket: String,
+    /// <p>Specifies whether Amazon S3 should use an S3 Bucket Key for object encryption with server-side encryption using AWS KMS (SSE-KMS). Setting this header to <code>true</code> causes Amazon S3 to use an S3 Bucket Key for object encryption with SSE-KMS.</p> <p>Specifying this header with a COPY action doesn't affect bucket-level settings for S3 Bucket Key.</p>
+    pub bucket_key_enabled: Option<bool>,
     /// <p>Specifies caching behavior along the request/reply chain.</p>
     pub cache_control: Option<String>,
     /// <p>Specifies presentational information for the object.</p>
@@ -2481,6 +2483,8 @@ pub struct CreateMultipartUploadRequest {
     pub acl: Option<String>,
     /// <p>The name of the bucket to which to initiate the upload</p>
     pub bucket: String,
+    /// <p>Specifies whether Amazon S3 should use an S3 Bucket Key for object encryption with server-side encryption using AWS KMS (SSE-KMS). Setting this header to <code>true</code> causes Amazon S3 to use an S3 Bucket Key for object encryption with SSE-KMS.</p> <p>Specifying this header with an object action doesn't affect bucket-level settings for S3 Bucket Key.</p>
+    pub bucket_key_enabled: Option<bool>,
     /// <p>Specifies caching behavior along the request/reply chain.</p>
     pub cache_control: Option<String>,
     /// <p>Specifies presentational information for the object.</p>
@@ -11406,6 +11410,8 @@ pub struct PutObjectRequest {
     pub body: Option<StreamingBody>,
     /// <p>Bucket name to which the PUT operation was initiated. </p> <p>When using this API with an access point, you must direct requests to the access point hostname. The access point hostname takes the form <i>AccessPointName</i>-<i>AccountId</i>.s3-accesspoint.<i>Region</i>.amazonaws.com. When using this operation using an access point through the AWS SDKs, you provide the access point ARN in place of the bucket name. For more information about access point ARNs, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-access-points.html">Using Access Points</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub bucket: String,
+    /// <p>Specifies whether Amazon S3 should use an S3 Bucket Key for object encryption with server-side encryption using AWS KMS (SSE-KMS). Setting this header to <code>true</code> causes Amazon S3 to use an S3 Bucket Key for object encryption with SSE-KMS.</p> <p>Specifying this header with a PUT action doesn't affect bucket-level settings for S3 Bucket Key.</p>
+    pub bucket_key_enabled: Option<bool>,
     /// <p> Can be used to specify caching behavior along the request/reply chain. For more information, see <a href="http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.9">http://www.w3.org/Protocols/rfc2616/rfc2616-sec14.html#sec14.9</a>.</p>
     pub cache_control: Option<String>,
     /// <p>Specifies presentational information for the object. For more information, see <a href="http://www.w3.org/Protocols/rfc2616/rfc2616-sec19.html#sec19.5.1">http://www.w3.org/Protocols/rfc2616/rfc2616-sec19.html#sec19.5.1</a>.</p>
@@ -15778,9 +15784,12 @@ impl YearsSerializer {
 
 /// Errors returned by AbortMultipartUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AbortMultipartUploadError {
     /// <p>The specified multipart upload does not exist.</p>
     NoSuchUpload(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AbortMultipartUploadError {
@@ -15792,11 +15801,12 @@ impl AbortMultipartUploadError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchUpload" => {
-                        return RusotoError::Service(AbortMultipartUploadError::NoSuchUpload(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AbortMultipartUploadError::NoSuchUpload(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AbortMultipartUploadError::Unhandled, &res),
                 }
             }
         }
@@ -15815,13 +15825,18 @@ impl fmt::Display for AbortMultipartUploadError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AbortMultipartUploadError::NoSuchUpload(ref cause) => write!(f, "{}", cause),
+            AbortMultipartUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AbortMultipartUploadError {}
 /// Errors returned by CompleteMultipartUpload
 #[derive(Debug, PartialEq)]
-pub enum CompleteMultipartUploadError {}
+#[non_exhaustive]
+pub enum CompleteMultipartUploadError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl CompleteMultipartUploadError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CompleteMultipartUploadError> {
@@ -15829,10 +15844,8 @@ impl CompleteMultipartUploadError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(CompleteMultipartUploadError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -15848,15 +15861,20 @@ impl CompleteMultipartUploadError {
 impl fmt::Display for CompleteMultipartUploadError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            CompleteMultipartUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CompleteMultipartUploadError {}
 /// Errors returned by CopyObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyObjectError {
     /// <p>The source object of the COPY operation is not in the active tier and is only stored in Amazon S3 Glacier.</p>
     ObjectNotInActiveTierError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyObjectError {
@@ -15868,11 +15886,12 @@ impl CopyObjectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ObjectNotInActiveTierError" => {
-                        return RusotoError::Service(CopyObjectError::ObjectNotInActiveTierError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CopyObjectError::ObjectNotInActiveTierError(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CopyObjectError::Unhandled, &res),
                 }
             }
         }
@@ -15891,17 +15910,21 @@ impl fmt::Display for CopyObjectError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CopyObjectError::ObjectNotInActiveTierError(ref cause) => write!(f, "{}", cause),
+            CopyObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyObjectError {}
 /// Errors returned by CreateBucket
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBucketError {
     /// <p>The requested bucket name is not available. The bucket namespace is shared by all users of the system. Please select a different name and try again.</p>
     BucketAlreadyExists(String),
     /// <p>The bucket you tried to create already exists, and you own it. Amazon S3 returns this error in all AWS Regions except in the North Virginia Region. For legacy compatibility, if you re-create an existing bucket that you already own in the North Virginia Region, Amazon S3 returns 200 OK and resets the bucket access control lists (ACLs).</p>
     BucketAlreadyOwnedByYou(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBucketError {
@@ -15913,16 +15936,18 @@ impl CreateBucketError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketAlreadyExists" => {
-                        return RusotoError::Service(CreateBucketError::BucketAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateBucketError::BucketAlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "BucketAlreadyOwnedByYou" => {
-                        return RusotoError::Service(CreateBucketError::BucketAlreadyOwnedByYou(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateBucketError::BucketAlreadyOwnedByYou(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateBucketError::Unhandled, &res),
                 }
             }
         }
@@ -15942,13 +15967,18 @@ impl fmt::Display for CreateBucketError {
         match *self {
             CreateBucketError::BucketAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateBucketError::BucketAlreadyOwnedByYou(ref cause) => write!(f, "{}", cause),
+            CreateBucketError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBucketError {}
 /// Errors returned by CreateMultipartUpload
 #[derive(Debug, PartialEq)]
-pub enum CreateMultipartUploadError {}
+#[non_exhaustive]
+pub enum CreateMultipartUploadError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl CreateMultipartUploadError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateMultipartUploadError> {
@@ -15956,10 +15986,8 @@ impl CreateMultipartUploadError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(CreateMultipartUploadError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -15975,13 +16003,19 @@ impl CreateMultipartUploadError {
 impl fmt::Display for CreateMultipartUploadError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            CreateMultipartUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateMultipartUploadError {}
 /// Errors returned by DeleteBucket
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketError {}
+#[non_exhaustive]
+pub enum DeleteBucketError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketError> {
@@ -15989,10 +16023,8 @@ impl DeleteBucketError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteBucketError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16008,13 +16040,19 @@ impl DeleteBucketError {
 impl fmt::Display for DeleteBucketError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteBucketError {}
 /// Errors returned by DeleteBucketAnalyticsConfiguration
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketAnalyticsConfigurationError {}
+#[non_exhaustive]
+pub enum DeleteBucketAnalyticsConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketAnalyticsConfigurationError {
     pub fn from_response(
@@ -16024,10 +16062,11 @@ impl DeleteBucketAnalyticsConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DeleteBucketAnalyticsConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -16043,13 +16082,21 @@ impl DeleteBucketAnalyticsConfigurationError {
 impl fmt::Display for DeleteBucketAnalyticsConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketAnalyticsConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DeleteBucketAnalyticsConfigurationError {}
 /// Errors returned by DeleteBucketCors
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketCorsError {}
+#[non_exhaustive]
+pub enum DeleteBucketCorsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketCorsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketCorsError> {
@@ -16057,10 +16104,8 @@ impl DeleteBucketCorsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteBucketCorsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16076,13 +16121,19 @@ impl DeleteBucketCorsError {
 impl fmt::Display for DeleteBucketCorsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketCorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteBucketCorsError {}
 /// Errors returned by DeleteBucketEncryption
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketEncryptionError {}
+#[non_exhaustive]
+pub enum DeleteBucketEncryptionError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketEncryptionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketEncryptionError> {
@@ -16090,10 +16141,8 @@ impl DeleteBucketEncryptionError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteBucketEncryptionError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16109,13 +16158,19 @@ impl DeleteBucketEncryptionError {
 impl fmt::Display for DeleteBucketEncryptionError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketEncryptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteBucketEncryptionError {}
 /// Errors returned by DeleteBucketInventoryConfiguration
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketInventoryConfigurationError {}
+#[non_exhaustive]
+pub enum DeleteBucketInventoryConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketInventoryConfigurationError {
     pub fn from_response(
@@ -16125,10 +16180,11 @@ impl DeleteBucketInventoryConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DeleteBucketInventoryConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -16144,13 +16200,21 @@ impl DeleteBucketInventoryConfigurationError {
 impl fmt::Display for DeleteBucketInventoryConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketInventoryConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DeleteBucketInventoryConfigurationError {}
 /// Errors returned by DeleteBucketLifecycle
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketLifecycleError {}
+#[non_exhaustive]
+pub enum DeleteBucketLifecycleError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketLifecycleError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketLifecycleError> {
@@ -16158,10 +16222,8 @@ impl DeleteBucketLifecycleError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteBucketLifecycleError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16177,13 +16239,19 @@ impl DeleteBucketLifecycleError {
 impl fmt::Display for DeleteBucketLifecycleError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketLifecycleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteBucketLifecycleError {}
 /// Errors returned by DeleteBucketMetricsConfiguration
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketMetricsConfigurationError {}
+#[non_exhaustive]
+pub enum DeleteBucketMetricsConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketMetricsConfigurationError {
     pub fn from_response(
@@ -16193,10 +16261,11 @@ impl DeleteBucketMetricsConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DeleteBucketMetricsConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -16212,13 +16281,21 @@ impl DeleteBucketMetricsConfigurationError {
 impl fmt::Display for DeleteBucketMetricsConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketMetricsConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DeleteBucketMetricsConfigurationError {}
 /// Errors returned by DeleteBucketPolicy
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketPolicyError {}
+#[non_exhaustive]
+pub enum DeleteBucketPolicyError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketPolicyError> {
@@ -16226,10 +16303,8 @@ impl DeleteBucketPolicyError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteBucketPolicyError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16245,13 +16320,19 @@ impl DeleteBucketPolicyError {
 impl fmt::Display for DeleteBucketPolicyError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteBucketPolicyError {}
 /// Errors returned by DeleteBucketReplication
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketReplicationError {}
+#[non_exhaustive]
+pub enum DeleteBucketReplicationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketReplicationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketReplicationError> {
@@ -16259,10 +16340,8 @@ impl DeleteBucketReplicationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteBucketReplicationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16278,13 +16357,19 @@ impl DeleteBucketReplicationError {
 impl fmt::Display for DeleteBucketReplicationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketReplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteBucketReplicationError {}
 /// Errors returned by DeleteBucketTagging
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketTaggingError {}
+#[non_exhaustive]
+pub enum DeleteBucketTaggingError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketTaggingError> {
@@ -16292,10 +16377,8 @@ impl DeleteBucketTaggingError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteBucketTaggingError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16311,13 +16394,19 @@ impl DeleteBucketTaggingError {
 impl fmt::Display for DeleteBucketTaggingError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketTaggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteBucketTaggingError {}
 /// Errors returned by DeleteBucketWebsite
 #[derive(Debug, PartialEq)]
-pub enum DeleteBucketWebsiteError {}
+#[non_exhaustive]
+pub enum DeleteBucketWebsiteError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteBucketWebsiteError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketWebsiteError> {
@@ -16325,10 +16414,8 @@ impl DeleteBucketWebsiteError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteBucketWebsiteError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16344,13 +16431,19 @@ impl DeleteBucketWebsiteError {
 impl fmt::Display for DeleteBucketWebsiteError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteBucketWebsiteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteBucketWebsiteError {}
 /// Errors returned by DeleteObject
 #[derive(Debug, PartialEq)]
-pub enum DeleteObjectError {}
+#[non_exhaustive]
+pub enum DeleteObjectError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteObjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteObjectError> {
@@ -16358,10 +16451,8 @@ impl DeleteObjectError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteObjectError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16377,13 +16468,19 @@ impl DeleteObjectError {
 impl fmt::Display for DeleteObjectError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteObjectError {}
 /// Errors returned by DeleteObjectTagging
 #[derive(Debug, PartialEq)]
-pub enum DeleteObjectTaggingError {}
+#[non_exhaustive]
+pub enum DeleteObjectTaggingError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteObjectTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteObjectTaggingError> {
@@ -16391,10 +16488,8 @@ impl DeleteObjectTaggingError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteObjectTaggingError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16410,13 +16505,19 @@ impl DeleteObjectTaggingError {
 impl fmt::Display for DeleteObjectTaggingError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteObjectTaggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteObjectTaggingError {}
 /// Errors returned by DeleteObjects
 #[derive(Debug, PartialEq)]
-pub enum DeleteObjectsError {}
+#[non_exhaustive]
+pub enum DeleteObjectsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteObjectsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteObjectsError> {
@@ -16424,10 +16525,8 @@ impl DeleteObjectsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteObjectsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16443,13 +16542,19 @@ impl DeleteObjectsError {
 impl fmt::Display for DeleteObjectsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteObjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteObjectsError {}
 /// Errors returned by DeletePublicAccessBlock
 #[derive(Debug, PartialEq)]
-pub enum DeletePublicAccessBlockError {}
+#[non_exhaustive]
+pub enum DeletePublicAccessBlockError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeletePublicAccessBlockError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeletePublicAccessBlockError> {
@@ -16457,10 +16562,8 @@ impl DeletePublicAccessBlockError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeletePublicAccessBlockError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16476,13 +16579,19 @@ impl DeletePublicAccessBlockError {
 impl fmt::Display for DeletePublicAccessBlockError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeletePublicAccessBlockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeletePublicAccessBlockError {}
 /// Errors returned by GetBucketAccelerateConfiguration
 #[derive(Debug, PartialEq)]
-pub enum GetBucketAccelerateConfigurationError {}
+#[non_exhaustive]
+pub enum GetBucketAccelerateConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketAccelerateConfigurationError {
     pub fn from_response(
@@ -16492,10 +16601,11 @@ impl GetBucketAccelerateConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    GetBucketAccelerateConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -16511,13 +16621,21 @@ impl GetBucketAccelerateConfigurationError {
 impl fmt::Display for GetBucketAccelerateConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketAccelerateConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetBucketAccelerateConfigurationError {}
 /// Errors returned by GetBucketAcl
 #[derive(Debug, PartialEq)]
-pub enum GetBucketAclError {}
+#[non_exhaustive]
+pub enum GetBucketAclError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketAclError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketAclError> {
@@ -16525,10 +16643,8 @@ impl GetBucketAclError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketAclError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16544,13 +16660,19 @@ impl GetBucketAclError {
 impl fmt::Display for GetBucketAclError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketAclError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketAclError {}
 /// Errors returned by GetBucketAnalyticsConfiguration
 #[derive(Debug, PartialEq)]
-pub enum GetBucketAnalyticsConfigurationError {}
+#[non_exhaustive]
+pub enum GetBucketAnalyticsConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketAnalyticsConfigurationError {
     pub fn from_response(
@@ -16560,10 +16682,11 @@ impl GetBucketAnalyticsConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    GetBucketAnalyticsConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -16579,13 +16702,21 @@ impl GetBucketAnalyticsConfigurationError {
 impl fmt::Display for GetBucketAnalyticsConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketAnalyticsConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetBucketAnalyticsConfigurationError {}
 /// Errors returned by GetBucketCors
 #[derive(Debug, PartialEq)]
-pub enum GetBucketCorsError {}
+#[non_exhaustive]
+pub enum GetBucketCorsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketCorsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketCorsError> {
@@ -16593,10 +16724,8 @@ impl GetBucketCorsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketCorsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16612,13 +16741,19 @@ impl GetBucketCorsError {
 impl fmt::Display for GetBucketCorsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketCorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketCorsError {}
 /// Errors returned by GetBucketEncryption
 #[derive(Debug, PartialEq)]
-pub enum GetBucketEncryptionError {}
+#[non_exhaustive]
+pub enum GetBucketEncryptionError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketEncryptionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketEncryptionError> {
@@ -16626,10 +16761,8 @@ impl GetBucketEncryptionError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketEncryptionError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16645,13 +16778,19 @@ impl GetBucketEncryptionError {
 impl fmt::Display for GetBucketEncryptionError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketEncryptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketEncryptionError {}
 /// Errors returned by GetBucketInventoryConfiguration
 #[derive(Debug, PartialEq)]
-pub enum GetBucketInventoryConfigurationError {}
+#[non_exhaustive]
+pub enum GetBucketInventoryConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketInventoryConfigurationError {
     pub fn from_response(
@@ -16661,10 +16800,11 @@ impl GetBucketInventoryConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    GetBucketInventoryConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -16680,13 +16820,21 @@ impl GetBucketInventoryConfigurationError {
 impl fmt::Display for GetBucketInventoryConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketInventoryConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetBucketInventoryConfigurationError {}
 /// Errors returned by GetBucketLifecycle
 #[derive(Debug, PartialEq)]
-pub enum GetBucketLifecycleError {}
+#[non_exhaustive]
+pub enum GetBucketLifecycleError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketLifecycleError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketLifecycleError> {
@@ -16694,10 +16842,8 @@ impl GetBucketLifecycleError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketLifecycleError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16713,13 +16859,19 @@ impl GetBucketLifecycleError {
 impl fmt::Display for GetBucketLifecycleError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketLifecycleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketLifecycleError {}
 /// Errors returned by GetBucketLifecycleConfiguration
 #[derive(Debug, PartialEq)]
-pub enum GetBucketLifecycleConfigurationError {}
+#[non_exhaustive]
+pub enum GetBucketLifecycleConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketLifecycleConfigurationError {
     pub fn from_response(
@@ -16729,10 +16881,11 @@ impl GetBucketLifecycleConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    GetBucketLifecycleConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -16748,13 +16901,21 @@ impl GetBucketLifecycleConfigurationError {
 impl fmt::Display for GetBucketLifecycleConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketLifecycleConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetBucketLifecycleConfigurationError {}
 /// Errors returned by GetBucketLocation
 #[derive(Debug, PartialEq)]
-pub enum GetBucketLocationError {}
+#[non_exhaustive]
+pub enum GetBucketLocationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketLocationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketLocationError> {
@@ -16762,10 +16923,8 @@ impl GetBucketLocationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketLocationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16781,13 +16940,19 @@ impl GetBucketLocationError {
 impl fmt::Display for GetBucketLocationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketLocationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketLocationError {}
 /// Errors returned by GetBucketLogging
 #[derive(Debug, PartialEq)]
-pub enum GetBucketLoggingError {}
+#[non_exhaustive]
+pub enum GetBucketLoggingError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketLoggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketLoggingError> {
@@ -16795,10 +16960,8 @@ impl GetBucketLoggingError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketLoggingError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16814,13 +16977,19 @@ impl GetBucketLoggingError {
 impl fmt::Display for GetBucketLoggingError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketLoggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketLoggingError {}
 /// Errors returned by GetBucketMetricsConfiguration
 #[derive(Debug, PartialEq)]
-pub enum GetBucketMetricsConfigurationError {}
+#[non_exhaustive]
+pub enum GetBucketMetricsConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketMetricsConfigurationError {
     pub fn from_response(
@@ -16830,10 +16999,8 @@ impl GetBucketMetricsConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketMetricsConfigurationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16849,13 +17016,21 @@ impl GetBucketMetricsConfigurationError {
 impl fmt::Display for GetBucketMetricsConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketMetricsConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetBucketMetricsConfigurationError {}
 /// Errors returned by GetBucketNotification
 #[derive(Debug, PartialEq)]
-pub enum GetBucketNotificationError {}
+#[non_exhaustive]
+pub enum GetBucketNotificationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketNotificationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketNotificationError> {
@@ -16863,10 +17038,8 @@ impl GetBucketNotificationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketNotificationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16882,13 +17055,19 @@ impl GetBucketNotificationError {
 impl fmt::Display for GetBucketNotificationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketNotificationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketNotificationError {}
 /// Errors returned by GetBucketNotificationConfiguration
 #[derive(Debug, PartialEq)]
-pub enum GetBucketNotificationConfigurationError {}
+#[non_exhaustive]
+pub enum GetBucketNotificationConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketNotificationConfigurationError {
     pub fn from_response(
@@ -16898,10 +17077,11 @@ impl GetBucketNotificationConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    GetBucketNotificationConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -16917,13 +17097,21 @@ impl GetBucketNotificationConfigurationError {
 impl fmt::Display for GetBucketNotificationConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketNotificationConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetBucketNotificationConfigurationError {}
 /// Errors returned by GetBucketPolicy
 #[derive(Debug, PartialEq)]
-pub enum GetBucketPolicyError {}
+#[non_exhaustive]
+pub enum GetBucketPolicyError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketPolicyError> {
@@ -16931,10 +17119,8 @@ impl GetBucketPolicyError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketPolicyError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16950,13 +17136,19 @@ impl GetBucketPolicyError {
 impl fmt::Display for GetBucketPolicyError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketPolicyError {}
 /// Errors returned by GetBucketPolicyStatus
 #[derive(Debug, PartialEq)]
-pub enum GetBucketPolicyStatusError {}
+#[non_exhaustive]
+pub enum GetBucketPolicyStatusError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketPolicyStatusError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketPolicyStatusError> {
@@ -16964,10 +17156,8 @@ impl GetBucketPolicyStatusError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketPolicyStatusError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -16983,13 +17173,19 @@ impl GetBucketPolicyStatusError {
 impl fmt::Display for GetBucketPolicyStatusError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketPolicyStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketPolicyStatusError {}
 /// Errors returned by GetBucketReplication
 #[derive(Debug, PartialEq)]
-pub enum GetBucketReplicationError {}
+#[non_exhaustive]
+pub enum GetBucketReplicationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketReplicationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketReplicationError> {
@@ -16997,10 +17193,8 @@ impl GetBucketReplicationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketReplicationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17016,13 +17210,19 @@ impl GetBucketReplicationError {
 impl fmt::Display for GetBucketReplicationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketReplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketReplicationError {}
 /// Errors returned by GetBucketRequestPayment
 #[derive(Debug, PartialEq)]
-pub enum GetBucketRequestPaymentError {}
+#[non_exhaustive]
+pub enum GetBucketRequestPaymentError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketRequestPaymentError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketRequestPaymentError> {
@@ -17030,10 +17230,8 @@ impl GetBucketRequestPaymentError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketRequestPaymentError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17049,13 +17247,19 @@ impl GetBucketRequestPaymentError {
 impl fmt::Display for GetBucketRequestPaymentError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketRequestPaymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketRequestPaymentError {}
 /// Errors returned by GetBucketTagging
 #[derive(Debug, PartialEq)]
-pub enum GetBucketTaggingError {}
+#[non_exhaustive]
+pub enum GetBucketTaggingError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketTaggingError> {
@@ -17063,10 +17267,8 @@ impl GetBucketTaggingError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketTaggingError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17082,13 +17284,19 @@ impl GetBucketTaggingError {
 impl fmt::Display for GetBucketTaggingError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketTaggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketTaggingError {}
 /// Errors returned by GetBucketVersioning
 #[derive(Debug, PartialEq)]
-pub enum GetBucketVersioningError {}
+#[non_exhaustive]
+pub enum GetBucketVersioningError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketVersioningError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketVersioningError> {
@@ -17096,10 +17304,8 @@ impl GetBucketVersioningError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketVersioningError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17115,13 +17321,19 @@ impl GetBucketVersioningError {
 impl fmt::Display for GetBucketVersioningError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketVersioningError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketVersioningError {}
 /// Errors returned by GetBucketWebsite
 #[derive(Debug, PartialEq)]
-pub enum GetBucketWebsiteError {}
+#[non_exhaustive]
+pub enum GetBucketWebsiteError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetBucketWebsiteError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketWebsiteError> {
@@ -17129,10 +17341,8 @@ impl GetBucketWebsiteError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetBucketWebsiteError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17148,15 +17358,20 @@ impl GetBucketWebsiteError {
 impl fmt::Display for GetBucketWebsiteError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetBucketWebsiteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetBucketWebsiteError {}
 /// Errors returned by GetObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetObjectError {
     /// <p>The specified key does not exist.</p>
     NoSuchKey(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetObjectError {
@@ -17168,11 +17383,12 @@ impl GetObjectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
-                        return RusotoError::Service(GetObjectError::NoSuchKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetObjectError::NoSuchKey(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetObjectError::Unhandled, &res),
                 }
             }
         }
@@ -17191,15 +17407,19 @@ impl fmt::Display for GetObjectError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetObjectError::NoSuchKey(ref cause) => write!(f, "{}", cause),
+            GetObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetObjectError {}
 /// Errors returned by GetObjectAcl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetObjectAclError {
     /// <p>The specified key does not exist.</p>
     NoSuchKey(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetObjectAclError {
@@ -17211,11 +17431,12 @@ impl GetObjectAclError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
-                        return RusotoError::Service(GetObjectAclError::NoSuchKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetObjectAclError::NoSuchKey(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetObjectAclError::Unhandled, &res),
                 }
             }
         }
@@ -17234,13 +17455,18 @@ impl fmt::Display for GetObjectAclError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetObjectAclError::NoSuchKey(ref cause) => write!(f, "{}", cause),
+            GetObjectAclError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetObjectAclError {}
 /// Errors returned by GetObjectLegalHold
 #[derive(Debug, PartialEq)]
-pub enum GetObjectLegalHoldError {}
+#[non_exhaustive]
+pub enum GetObjectLegalHoldError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetObjectLegalHoldError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectLegalHoldError> {
@@ -17248,10 +17474,8 @@ impl GetObjectLegalHoldError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetObjectLegalHoldError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17267,13 +17491,19 @@ impl GetObjectLegalHoldError {
 impl fmt::Display for GetObjectLegalHoldError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetObjectLegalHoldError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetObjectLegalHoldError {}
 /// Errors returned by GetObjectLockConfiguration
 #[derive(Debug, PartialEq)]
-pub enum GetObjectLockConfigurationError {}
+#[non_exhaustive]
+pub enum GetObjectLockConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetObjectLockConfigurationError {
     pub fn from_response(
@@ -17283,10 +17513,8 @@ impl GetObjectLockConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetObjectLockConfigurationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17302,13 +17530,19 @@ impl GetObjectLockConfigurationError {
 impl fmt::Display for GetObjectLockConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
-    }
+        match *self {
+            GetObjectLockConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
+    }
 }
 impl Error for GetObjectLockConfigurationError {}
 /// Errors returned by GetObjectRetention
 #[derive(Debug, PartialEq)]
-pub enum GetObjectRetentionError {}
+#[non_exhaustive]
+pub enum GetObjectRetentionError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetObjectRetentionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectRetentionError> {
@@ -17316,10 +17550,8 @@ impl GetObjectRetentionError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetObjectRetentionError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17335,13 +17567,19 @@ impl GetObjectRetentionError {
 impl fmt::Display for GetObjectRetentionError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetObjectRetentionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetObjectRetentionError {}
 /// Errors returned by GetObjectTagging
 #[derive(Debug, PartialEq)]
-pub enum GetObjectTaggingError {}
+#[non_exhaustive]
+pub enum GetObjectTaggingError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetObjectTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectTaggingError> {
@@ -17349,10 +17587,8 @@ impl GetObjectTaggingError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetObjectTaggingError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17368,13 +17604,19 @@ impl GetObjectTaggingError {
 impl fmt::Display for GetObjectTaggingError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetObjectTaggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetObjectTaggingError {}
 /// Errors returned by GetObjectTorrent
 #[derive(Debug, PartialEq)]
-pub enum GetObjectTorrentError {}
+#[non_exhaustive]
+pub enum GetObjectTorrentError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetObjectTorrentError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectTorrentError> {
@@ -17382,10 +17624,8 @@ impl GetObjectTorrentError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetObjectTorrentError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17401,13 +17641,19 @@ impl GetObjectTorrentError {
 impl fmt::Display for GetObjectTorrentError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetObjectTorrentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetObjectTorrentError {}
 /// Errors returned by GetPublicAccessBlock
 #[derive(Debug, PartialEq)]
-pub enum GetPublicAccessBlockError {}
+#[non_exhaustive]
+pub enum GetPublicAccessBlockError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetPublicAccessBlockError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetPublicAccessBlockError> {
@@ -17415,10 +17661,8 @@ impl GetPublicAccessBlockError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetPublicAccessBlockError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17434,15 +17678,20 @@ impl GetPublicAccessBlockError {
 impl fmt::Display for GetPublicAccessBlockError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetPublicAccessBlockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetPublicAccessBlockError {}
 /// Errors returned by HeadBucket
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum HeadBucketError {
     /// <p>The specified bucket does not exist.</p>
     NoSuchBucket(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl HeadBucketError {
@@ -17454,11 +17703,12 @@ impl HeadBucketError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchBucket" => {
-                        return RusotoError::Service(HeadBucketError::NoSuchBucket(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            HeadBucketError::NoSuchBucket(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(HeadBucketError::Unhandled, &res),
                 }
             }
         }
@@ -17477,15 +17727,19 @@ impl fmt::Display for HeadBucketError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             HeadBucketError::NoSuchBucket(ref cause) => write!(f, "{}", cause),
+            HeadBucketError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for HeadBucketError {}
 /// Errors returned by HeadObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum HeadObjectError {
     /// <p>The specified key does not exist.</p>
     NoSuchKey(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl HeadObjectError {
@@ -17497,11 +17751,12 @@ impl HeadObjectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
-                        return RusotoError::Service(HeadObjectError::NoSuchKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            HeadObjectError::NoSuchKey(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(HeadObjectError::Unhandled, &res),
                 }
             }
         }
@@ -17520,13 +17775,18 @@ impl fmt::Display for HeadObjectError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             HeadObjectError::NoSuchKey(ref cause) => write!(f, "{}", cause),
+            HeadObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for HeadObjectError {}
 /// Errors returned by ListBucketAnalyticsConfigurations
 #[derive(Debug, PartialEq)]
-pub enum ListBucketAnalyticsConfigurationsError {}
+#[non_exhaustive]
+pub enum ListBucketAnalyticsConfigurationsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListBucketAnalyticsConfigurationsError {
     pub fn from_response(
@@ -17536,10 +17796,11 @@ impl ListBucketAnalyticsConfigurationsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    ListBucketAnalyticsConfigurationsError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -17555,13 +17816,21 @@ impl ListBucketAnalyticsConfigurationsError {
 impl fmt::Display for ListBucketAnalyticsConfigurationsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListBucketAnalyticsConfigurationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for ListBucketAnalyticsConfigurationsError {}
 /// Errors returned by ListBucketInventoryConfigurations
 #[derive(Debug, PartialEq)]
-pub enum ListBucketInventoryConfigurationsError {}
+#[non_exhaustive]
+pub enum ListBucketInventoryConfigurationsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListBucketInventoryConfigurationsError {
     pub fn from_response(
@@ -17571,10 +17840,11 @@ impl ListBucketInventoryConfigurationsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    ListBucketInventoryConfigurationsError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -17590,13 +17860,21 @@ impl ListBucketInventoryConfigurationsError {
 impl fmt::Display for ListBucketInventoryConfigurationsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListBucketInventoryConfigurationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for ListBucketInventoryConfigurationsError {}
 /// Errors returned by ListBucketMetricsConfigurations
 #[derive(Debug, PartialEq)]
-pub enum ListBucketMetricsConfigurationsError {}
+#[non_exhaustive]
+pub enum ListBucketMetricsConfigurationsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListBucketMetricsConfigurationsError {
     pub fn from_response(
@@ -17606,10 +17884,11 @@ impl ListBucketMetricsConfigurationsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    ListBucketMetricsConfigurationsError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -17625,13 +17904,21 @@ impl ListBucketMetricsConfigurationsError {
 impl fmt::Display for ListBucketMetricsConfigurationsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListBucketMetricsConfigurationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for ListBucketMetricsConfigurationsError {}
 /// Errors returned by ListBuckets
 #[derive(Debug, PartialEq)]
-pub enum ListBucketsError {}
+#[non_exhaustive]
+pub enum ListBucketsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListBucketsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListBucketsError> {
@@ -17639,10 +17926,8 @@ impl ListBucketsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListBucketsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17658,13 +17943,19 @@ impl ListBucketsError {
 impl fmt::Display for ListBucketsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListBucketsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListBucketsError {}
 /// Errors returned by ListMultipartUploads
 #[derive(Debug, PartialEq)]
-pub enum ListMultipartUploadsError {}
+#[non_exhaustive]
+pub enum ListMultipartUploadsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListMultipartUploadsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListMultipartUploadsError> {
@@ -17672,10 +17963,8 @@ impl ListMultipartUploadsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListMultipartUploadsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17691,13 +17980,19 @@ impl ListMultipartUploadsError {
 impl fmt::Display for ListMultipartUploadsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListMultipartUploadsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListMultipartUploadsError {}
 /// Errors returned by ListObjectVersions
 #[derive(Debug, PartialEq)]
-pub enum ListObjectVersionsError {}
+#[non_exhaustive]
+pub enum ListObjectVersionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListObjectVersionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListObjectVersionsError> {
@@ -17705,10 +18000,8 @@ impl ListObjectVersionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListObjectVersionsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17724,15 +18017,20 @@ impl ListObjectVersionsError {
 impl fmt::Display for ListObjectVersionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListObjectVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListObjectVersionsError {}
 /// Errors returned by ListObjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListObjectsError {
     /// <p>The specified bucket does not exist.</p>
     NoSuchBucket(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListObjectsError {
@@ -17744,11 +18042,12 @@ impl ListObjectsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchBucket" => {
-                        return RusotoError::Service(ListObjectsError::NoSuchBucket(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListObjectsError::NoSuchBucket(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListObjectsError::Unhandled, &res),
                 }
             }
         }
@@ -17767,15 +18066,19 @@ impl fmt::Display for ListObjectsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListObjectsError::NoSuchBucket(ref cause) => write!(f, "{}", cause),
+            ListObjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListObjectsError {}
 /// Errors returned by ListObjectsV2
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListObjectsV2Error {
     /// <p>The specified bucket does not exist.</p>
     NoSuchBucket(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListObjectsV2Error {
@@ -17787,11 +18090,12 @@ impl ListObjectsV2Error {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchBucket" => {
-                        return RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListObjectsV2Error::NoSuchBucket(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListObjectsV2Error::Unhandled, &res),
                 }
             }
         }
@@ -17810,13 +18114,18 @@ impl fmt::Display for ListObjectsV2Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListObjectsV2Error::NoSuchBucket(ref cause) => write!(f, "{}", cause),
+            ListObjectsV2Error::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListObjectsV2Error {}
 /// Errors returned by ListParts
 #[derive(Debug, PartialEq)]
-pub enum ListPartsError {}
+#[non_exhaustive]
+pub enum ListPartsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListPartsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListPartsError> {
@@ -17824,10 +18133,8 @@ impl ListPartsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListPartsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17843,13 +18150,19 @@ impl ListPartsError {
 impl fmt::Display for ListPartsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListPartsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListPartsError {}
 /// Errors returned by PutBucketAccelerateConfiguration
 #[derive(Debug, PartialEq)]
-pub enum PutBucketAccelerateConfigurationError {}
+#[non_exhaustive]
+pub enum PutBucketAccelerateConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketAccelerateConfigurationError {
     pub fn from_response(
@@ -17859,10 +18172,11 @@ impl PutBucketAccelerateConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    PutBucketAccelerateConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -17878,13 +18192,21 @@ impl PutBucketAccelerateConfigurationError {
 impl fmt::Display for PutBucketAccelerateConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketAccelerateConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for PutBucketAccelerateConfigurationError {}
 /// Errors returned by PutBucketAcl
 #[derive(Debug, PartialEq)]
-pub enum PutBucketAclError {}
+#[non_exhaustive]
+pub enum PutBucketAclError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketAclError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketAclError> {
@@ -17892,10 +18214,8 @@ impl PutBucketAclError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketAclError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17911,13 +18231,19 @@ impl PutBucketAclError {
 impl fmt::Display for PutBucketAclError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketAclError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketAclError {}
 /// Errors returned by PutBucketAnalyticsConfiguration
 #[derive(Debug, PartialEq)]
-pub enum PutBucketAnalyticsConfigurationError {}
+#[non_exhaustive]
+pub enum PutBucketAnalyticsConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketAnalyticsConfigurationError {
     pub fn from_response(
@@ -17927,10 +18253,11 @@ impl PutBucketAnalyticsConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    PutBucketAnalyticsConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -17946,13 +18273,21 @@ impl PutBucketAnalyticsConfigurationError {
 impl fmt::Display for PutBucketAnalyticsConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketAnalyticsConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for PutBucketAnalyticsConfigurationError {}
 /// Errors returned by PutBucketCors
 #[derive(Debug, PartialEq)]
-pub enum PutBucketCorsError {}
+#[non_exhaustive]
+pub enum PutBucketCorsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketCorsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketCorsError> {
@@ -17960,10 +18295,8 @@ impl PutBucketCorsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketCorsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -17979,13 +18312,19 @@ impl PutBucketCorsError {
 impl fmt::Display for PutBucketCorsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketCorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketCorsError {}
 /// Errors returned by PutBucketEncryption
 #[derive(Debug, PartialEq)]
-pub enum PutBucketEncryptionError {}
+#[non_exhaustive]
+pub enum PutBucketEncryptionError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketEncryptionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketEncryptionError> {
@@ -17993,10 +18332,8 @@ impl PutBucketEncryptionError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketEncryptionError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18012,13 +18349,19 @@ impl PutBucketEncryptionError {
 impl fmt::Display for PutBucketEncryptionError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketEncryptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketEncryptionError {}
 /// Errors returned by PutBucketInventoryConfiguration
 #[derive(Debug, PartialEq)]
-pub enum PutBucketInventoryConfigurationError {}
+#[non_exhaustive]
+pub enum PutBucketInventoryConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketInventoryConfigurationError {
     pub fn from_response(
@@ -18028,10 +18371,11 @@ impl PutBucketInventoryConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    PutBucketInventoryConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -18047,13 +18391,21 @@ impl PutBucketInventoryConfigurationError {
 impl fmt::Display for PutBucketInventoryConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketInventoryConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for PutBucketInventoryConfigurationError {}
 /// Errors returned by PutBucketLifecycle
 #[derive(Debug, PartialEq)]
-pub enum PutBucketLifecycleError {}
+#[non_exhaustive]
+pub enum PutBucketLifecycleError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketLifecycleError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketLifecycleError> {
@@ -18061,10 +18413,8 @@ impl PutBucketLifecycleError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketLifecycleError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18080,13 +18430,19 @@ impl PutBucketLifecycleError {
 impl fmt::Display for PutBucketLifecycleError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketLifecycleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketLifecycleError {}
 /// Errors returned by PutBucketLifecycleConfiguration
 #[derive(Debug, PartialEq)]
-pub enum PutBucketLifecycleConfigurationError {}
+#[non_exhaustive]
+pub enum PutBucketLifecycleConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketLifecycleConfigurationError {
     pub fn from_response(
@@ -18096,10 +18452,11 @@ impl PutBucketLifecycleConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    PutBucketLifecycleConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -18115,13 +18472,21 @@ impl PutBucketLifecycleConfigurationError {
 impl fmt::Display for PutBucketLifecycleConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketLifecycleConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for PutBucketLifecycleConfigurationError {}
 /// Errors returned by PutBucketLogging
 #[derive(Debug, PartialEq)]
-pub enum PutBucketLoggingError {}
+#[non_exhaustive]
+pub enum PutBucketLoggingError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketLoggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketLoggingError> {
@@ -18129,10 +18494,8 @@ impl PutBucketLoggingError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketLoggingError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18148,13 +18511,19 @@ impl PutBucketLoggingError {
 impl fmt::Display for PutBucketLoggingError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketLoggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketLoggingError {}
 /// Errors returned by PutBucketMetricsConfiguration
 #[derive(Debug, PartialEq)]
-pub enum PutBucketMetricsConfigurationError {}
+#[non_exhaustive]
+pub enum PutBucketMetricsConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketMetricsConfigurationError {
     pub fn from_response(
@@ -18164,10 +18533,8 @@ impl PutBucketMetricsConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketMetricsConfigurationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18183,13 +18550,21 @@ impl PutBucketMetricsConfigurationError {
 impl fmt::Display for PutBucketMetricsConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketMetricsConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for PutBucketMetricsConfigurationError {}
 /// Errors returned by PutBucketNotification
 #[derive(Debug, PartialEq)]
-pub enum PutBucketNotificationError {}
+#[non_exhaustive]
+pub enum PutBucketNotificationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketNotificationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketNotificationError> {
@@ -18197,10 +18572,8 @@ impl PutBucketNotificationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketNotificationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18216,13 +18589,19 @@ impl PutBucketNotificationError {
 impl fmt::Display for PutBucketNotificationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketNotificationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketNotificationError {}
 /// Errors returned by PutBucketNotificationConfiguration
 #[derive(Debug, PartialEq)]
-pub enum PutBucketNotificationConfigurationError {}
+#[non_exhaustive]
+pub enum PutBucketNotificationConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketNotificationConfigurationError {
     pub fn from_response(
@@ -18232,10 +18611,11 @@ impl PutBucketNotificationConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    PutBucketNotificationConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -18251,13 +18631,21 @@ impl PutBucketNotificationConfigurationError {
 impl fmt::Display for PutBucketNotificationConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketNotificationConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for PutBucketNotificationConfigurationError {}
 /// Errors returned by PutBucketPolicy
 #[derive(Debug, PartialEq)]
-pub enum PutBucketPolicyError {}
+#[non_exhaustive]
+pub enum PutBucketPolicyError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketPolicyError> {
@@ -18265,10 +18653,8 @@ impl PutBucketPolicyError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketPolicyError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18284,13 +18670,19 @@ impl PutBucketPolicyError {
 impl fmt::Display for PutBucketPolicyError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketPolicyError {}
 /// Errors returned by PutBucketReplication
 #[derive(Debug, PartialEq)]
-pub enum PutBucketReplicationError {}
+#[non_exhaustive]
+pub enum PutBucketReplicationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketReplicationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketReplicationError> {
@@ -18298,10 +18690,8 @@ impl PutBucketReplicationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketReplicationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18317,13 +18707,19 @@ impl PutBucketReplicationError {
 impl fmt::Display for PutBucketReplicationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketReplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketReplicationError {}
 /// Errors returned by PutBucketRequestPayment
 #[derive(Debug, PartialEq)]
-pub enum PutBucketRequestPaymentError {}
+#[non_exhaustive]
+pub enum PutBucketRequestPaymentError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketRequestPaymentError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketRequestPaymentError> {
@@ -18331,10 +18727,8 @@ impl PutBucketRequestPaymentError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketRequestPaymentError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18350,13 +18744,19 @@ impl PutBucketRequestPaymentError {
 impl fmt::Display for PutBucketRequestPaymentError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketRequestPaymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketRequestPaymentError {}
 /// Errors returned by PutBucketTagging
 #[derive(Debug, PartialEq)]
-pub enum PutBucketTaggingError {}
+#[non_exhaustive]
+pub enum PutBucketTaggingError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketTaggingError> {
@@ -18364,10 +18764,8 @@ impl PutBucketTaggingError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketTaggingError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18383,13 +18781,19 @@ impl PutBucketTaggingError {
 impl fmt::Display for PutBucketTaggingError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketTaggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketTaggingError {}
 /// Errors returned by PutBucketVersioning
 #[derive(Debug, PartialEq)]
-pub enum PutBucketVersioningError {}
+#[non_exhaustive]
+pub enum PutBucketVersioningError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketVersioningError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketVersioningError> {
@@ -18397,10 +18801,8 @@ impl PutBucketVersioningError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketVersioningError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18416,13 +18818,19 @@ impl PutBucketVersioningError {
 impl fmt::Display for PutBucketVersioningError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketVersioningError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketVersioningError {}
 /// Errors returned by PutBucketWebsite
 #[derive(Debug, PartialEq)]
-pub enum PutBucketWebsiteError {}
+#[non_exhaustive]
+pub enum PutBucketWebsiteError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutBucketWebsiteError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketWebsiteError> {
@@ -18430,10 +18838,8 @@ impl PutBucketWebsiteError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutBucketWebsiteError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18449,13 +18855,19 @@ impl PutBucketWebsiteError {
 impl fmt::Display for PutBucketWebsiteError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutBucketWebsiteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutBucketWebsiteError {}
 /// Errors returned by PutObject
 #[derive(Debug, PartialEq)]
-pub enum PutObjectError {}
+#[non_exhaustive]
+pub enum PutObjectError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutObjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutObjectError> {
@@ -18463,10 +18875,8 @@ impl PutObjectError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutObjectError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18482,15 +18892,20 @@ impl PutObjectError {
 impl fmt::Display for PutObjectError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutObjectError {}
 /// Errors returned by PutObjectAcl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutObjectAclError {
     /// <p>The specified key does not exist.</p>
     NoSuchKey(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutObjectAclError {
@@ -18502,11 +18917,12 @@ impl PutObjectAclError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
-                        return RusotoError::Service(PutObjectAclError::NoSuchKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutObjectAclError::NoSuchKey(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutObjectAclError::Unhandled, &res),
                 }
             }
         }
@@ -18525,13 +18941,18 @@ impl fmt::Display for PutObjectAclError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PutObjectAclError::NoSuchKey(ref cause) => write!(f, "{}", cause),
+            PutObjectAclError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutObjectAclError {}
 /// Errors returned by PutObjectLegalHold
 #[derive(Debug, PartialEq)]
-pub enum PutObjectLegalHoldError {}
+#[non_exhaustive]
+pub enum PutObjectLegalHoldError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutObjectLegalHoldError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutObjectLegalHoldError> {
@@ -18539,10 +18960,8 @@ impl PutObjectLegalHoldError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutObjectLegalHoldError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18558,13 +18977,19 @@ impl PutObjectLegalHoldError {
 impl fmt::Display for PutObjectLegalHoldError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutObjectLegalHoldError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutObjectLegalHoldError {}
 /// Errors returned by PutObjectLockConfiguration
 #[derive(Debug, PartialEq)]
-pub enum PutObjectLockConfigurationError {}
+#[non_exhaustive]
+pub enum PutObjectLockConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutObjectLockConfigurationError {
     pub fn from_response(
@@ -18574,10 +18999,8 @@ impl PutObjectLockConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutObjectLockConfigurationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18593,13 +19016,19 @@ impl PutObjectLockConfigurationError {
 impl fmt::Display for PutObjectLockConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutObjectLockConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutObjectLockConfigurationError {}
 /// Errors returned by PutObjectRetention
 #[derive(Debug, PartialEq)]
-pub enum PutObjectRetentionError {}
+#[non_exhaustive]
+pub enum PutObjectRetentionError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutObjectRetentionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutObjectRetentionError> {
@@ -18607,10 +19036,8 @@ impl PutObjectRetentionError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutObjectRetentionError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18626,13 +19053,19 @@ impl PutObjectRetentionError {
 impl fmt::Display for PutObjectRetentionError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutObjectRetentionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutObjectRetentionError {}
 /// Errors returned by PutObjectTagging
 #[derive(Debug, PartialEq)]
-pub enum PutObjectTaggingError {}
+#[non_exhaustive]
+pub enum PutObjectTaggingError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutObjectTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutObjectTaggingError> {
@@ -18640,10 +19073,8 @@ impl PutObjectTaggingError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutObjectTaggingError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18659,13 +19090,19 @@ impl PutObjectTaggingError {
 impl fmt::Display for PutObjectTaggingError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutObjectTaggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutObjectTaggingError {}
 /// Errors returned by PutPublicAccessBlock
 #[derive(Debug, PartialEq)]
-pub enum PutPublicAccessBlockError {}
+#[non_exhaustive]
+pub enum PutPublicAccessBlockError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutPublicAccessBlockError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutPublicAccessBlockError> {
@@ -18673,10 +19110,8 @@ impl PutPublicAccessBlockError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(PutPublicAccessBlockError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18692,15 +19127,20 @@ impl PutPublicAccessBlockError {
 impl fmt::Display for PutPublicAccessBlockError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutPublicAccessBlockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutPublicAccessBlockError {}
 /// Errors returned by RestoreObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreObjectError {
     /// <p>This operation is not allowed against this storage tier.</p>
     ObjectAlreadyInActiveTierError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreObjectError {
@@ -18712,13 +19152,14 @@ impl RestoreObjectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ObjectAlreadyInActiveTierError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreObjectError::ObjectAlreadyInActiveTierError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RestoreObjectError::Unhandled, &res),
                 }
             }
         }
@@ -18737,13 +19178,18 @@ impl fmt::Display for RestoreObjectError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RestoreObjectError::ObjectAlreadyInActiveTierError(ref cause) => write!(f, "{}", cause),
+            RestoreObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreObjectError {}
 /// Errors returned by SelectObjectContent
 #[derive(Debug, PartialEq)]
-pub enum SelectObjectContentError {}
+#[non_exhaustive]
+pub enum SelectObjectContentError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SelectObjectContentError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SelectObjectContentError> {
@@ -18751,10 +19197,8 @@ impl SelectObjectContentError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(SelectObjectContentError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18770,13 +19214,19 @@ impl SelectObjectContentError {
 impl fmt::Display for SelectObjectContentError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SelectObjectContentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SelectObjectContentError {}
 /// Errors returned by UploadPart
 #[derive(Debug, PartialEq)]
-pub enum UploadPartError {}
+#[non_exhaustive]
+pub enum UploadPartError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UploadPartError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UploadPartError> {
@@ -18784,10 +19234,8 @@ impl UploadPartError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(UploadPartError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18803,13 +19251,19 @@ impl UploadPartError {
 impl fmt::Display for UploadPartError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UploadPartError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UploadPartError {}
 /// Errors returned by UploadPartCopy
 #[derive(Debug, PartialEq)]
-pub enum UploadPartCopyError {}
+#[non_exhaustive]
+pub enum UploadPartCopyError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UploadPartCopyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UploadPartCopyError> {
@@ -18817,10 +19271,8 @@ impl UploadPartCopyError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(UploadPartCopyError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -18836,7 +19288,9 @@ impl UploadPartCopyError {
 impl fmt::Display for UploadPartCopyError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UploadPartCopyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UploadPartCopyError {}
@@ -19400,6 +19854,7 @@ impl S3Client {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> S3Client {
         S3Client {
             client: Client::shared(),
@@ -19425,19 +19880,94 @@ impl S3Client {
     pub fn new_with_client(client: Client, region: region::Region) -> S3Client {
         S3Client { client, region }
     }
-}
 
-#[async_trait]
-impl S3 for S3Client {
-    /// <p><p>This operation aborts a multipart upload. After a multipart upload is aborted, no additional parts can be uploaded using that upload ID. The storage consumed by any previously uploaded parts will be freed. However, if any part uploads are currently in progress, those part uploads might or might not succeed. As a result, it might be necessary to abort a given multipart upload multiple times in order to completely free all storage consumed by all parts. </p> <p>To verify that all parts have been removed, so you don&#39;t get charged for the part storage, you should call the <a>ListParts</a> operation and ensure that the parts list is empty.</p> <p>For information about permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p>The following operations are related to <code>AbortMultipartUpload</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<S3Client> {
+        rusoto_core::ClientBuilder::new(S3Client::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> S3Client {
+        S3Client {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
+}
+
+impl S3Client {
+    /// Calls `abort_multipart_upload`, also returning the metadata of the response.
     #[allow(unused_variables, warnings)]
-    async fn abort_multipart_upload(
+    pub async fn abort_multipart_upload_with_metadata(
         &self,
         input: AbortMultipartUploadRequest,
-    ) -> Result<AbortMultipartUploadOutput, RusotoError<AbortMultipartUploadError>> {
+    ) -> Result<
+        (AbortMultipartUploadOutput, ResponseMetadata),
+        RusotoError<AbortMultipartUploadError>,
+    > {
+        let request = self.build_abort_multipart_upload_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if !response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(AbortMultipartUploadError::from_response(response));
+        }
+
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
+        let mut result;
+
+        if xml_response.body.is_empty() {
+            result = AbortMultipartUploadOutput::default();
+        } else {
+            let reader = EventReader::new_with_config(
+                xml_response.body.as_ref(),
+                ParserConfig::new().trim_whitespace(false),
+            );
+            let mut stack = XmlResponse::new(reader.into_iter().peekable());
+            let _start_document = stack.next();
+            let actual_tag_name = peek_at_name(&mut stack)?;
+            result =
+                AbortMultipartUploadOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+        }
+        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+            let value = request_charged.to_owned();
+            result.request_charged = Some(value)
+        }; // parse non-payload
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `abort_multipart_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_abort_multipart_upload(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AbortMultipartUploadRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_abort_multipart_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_abort_multipart_upload_request(
+        &self,
+        input: AbortMultipartUploadRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -19446,21 +19976,42 @@ impl S3 for S3Client {
         params.put("uploadId", &input.upload_id);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `complete_multipart_upload`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn complete_multipart_upload_with_metadata(
+        &self,
+        input: CompleteMultipartUploadRequest,
+    ) -> Result<
+        (CompleteMultipartUploadOutput, ResponseMetadata),
+        RusotoError<CompleteMultipartUploadError>,
+    > {
+        let request = self.build_complete_multipart_upload_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(AbortMultipartUploadError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(CompleteMultipartUploadError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = AbortMultipartUploadOutput::default();
+            result = CompleteMultipartUploadOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -19469,25 +20020,60 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                AbortMultipartUploadOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            if actual_tag_name == "Error" {
+                std::mem::drop(stack);
+                return Err(CompleteMultipartUploadError::from_response(xml_response));
+            }
+            result = CompleteMultipartUploadOutputDeserializer::deserialize(
+                &actual_tag_name,
+                &mut stack,
+            )?;
         }
+        if let Some(expiration) = response.headers.get("x-amz-expiration") {
+            let value = expiration.to_owned();
+            result.expiration = Some(value)
+        };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
             result.request_charged = Some(value)
+        };
+        if let Some(ssekms_key_id) = response
+            .headers
+            .get("x-amz-server-side-encryption-aws-kms-key-id")
+        {
+            let value = ssekms_key_id.to_owned();
+            result.ssekms_key_id = Some(value)
+        };
+        if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
+            let value = server_side_encryption.to_owned();
+            result.server_side_encryption = Some(value)
+        };
+        if let Some(version_id) = response.headers.get("x-amz-version-id") {
+            let value = version_id.to_owned();
+            result.version_id = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `complete_multipart_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_complete_multipart_upload(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CompleteMultipartUploadRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_complete_multipart_upload_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Completes a multipart upload by assembling previously uploaded parts.</p> <p>You first initiate the multipart upload and then upload all parts using the <a>UploadPart</a> operation. After successfully uploading all relevant parts of an upload, you call this operation to complete the upload. Upon receiving this request, Amazon S3 concatenates all the parts in ascending order by part number to create a new object. In the Complete Multipart Upload request, you must provide the parts list. You must ensure that the parts list is complete. This operation concatenates the parts that you provide in the list. For each part in the list, you must provide the part number and the <code>ETag</code> value, returned after that part was uploaded.</p> <p>Processing of a Complete Multipart Upload request could take several minutes to complete. After Amazon S3 begins processing the request, it sends an HTTP response header that specifies a 200 OK response. While processing is in progress, Amazon S3 periodically sends white space characters to keep the connection from timing out. Because a request could fail after the initial 200 OK response has been sent, it is important that you check the response body to determine whether the request succeeded.</p> <p>Note that if <code>CompleteMultipartUpload</code> fails, applications should be prepared to retry the failed requests. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ErrorBestPractices.html">Amazon S3 Error Best Practices</a>.</p> <p>For more information about multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/uploadobjusingmpu.html">Uploading Objects Using Multipart Upload</a>.</p> <p>For information about permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p> <code>GetBucketLifecycle</code> has the following special errors:</p> <ul> <li> <p>Error code: <code>EntityTooSmall</code> </p> <ul> <li> <p>Description: Your proposed upload is smaller than the minimum allowed object size. Each part must be at least 5 MB in size, except the last part.</p> </li> <li> <p>400 Bad Request</p> </li> </ul> </li> <li> <p>Error code: <code>InvalidPart</code> </p> <ul> <li> <p>Description: One or more of the specified parts could not be found. The part might not have been uploaded, or the specified entity tag might not have matched the part&#39;s entity tag.</p> </li> <li> <p>400 Bad Request</p> </li> </ul> </li> <li> <p>Error code: <code>InvalidPartOrder</code> </p> <ul> <li> <p>Description: The list of parts was not in ascending order. The parts list must be specified in order by part number.</p> </li> <li> <p>400 Bad Request</p> </li> </ul> </li> <li> <p>Error code: <code>NoSuchUpload</code> </p> <ul> <li> <p>Description: The specified multipart upload does not exist. The upload ID might be invalid, or the multipart upload might have been aborted or completed.</p> </li> <li> <p>404 Not Found</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn complete_multipart_upload(
+    fn build_complete_multipart_upload_request(
         &self,
         input: CompleteMultipartUploadRequest,
-    ) -> Result<CompleteMultipartUploadOutput, RusotoError<CompleteMultipartUploadError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -19507,21 +20093,39 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        request
+    }
+
+    /// Calls `copy_object`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn copy_object_with_metadata(
+        &self,
+        input: CopyObjectRequest,
+    ) -> Result<(CopyObjectOutput, ResponseMetadata), RusotoError<CopyObjectError>> {
+        let request = self.build_copy_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(CompleteMultipartUploadError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(CopyObjectError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = CompleteMultipartUploadOutput::default();
+            result = CopyObjectOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -19530,11 +20134,16 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = CompleteMultipartUploadOutputDeserializer::deserialize(
-                &actual_tag_name,
-                &mut stack,
-            )?;
+            if actual_tag_name == "Error" {
+                std::mem::drop(stack);
+                return Err(CopyObjectError::from_response(xml_response));
+            }
+            result = CopyObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
+        if let Some(copy_source_version_id) = response.headers.get("x-amz-copy-source-version-id") {
+            let value = copy_source_version_id.to_owned();
+            result.copy_source_version_id = Some(value)
+        };
         if let Some(expiration) = response.headers.get("x-amz-expiration") {
             let value = expiration.to_owned();
             result.expiration = Some(value)
@@ -19543,6 +20152,26 @@ impl S3 for S3Client {
             let value = request_charged.to_owned();
             result.request_charged = Some(value)
         };
+        if let Some(sse_customer_algorithm) = response
+            .headers
+            .get("x-amz-server-side-encryption-customer-algorithm")
+        {
+            let value = sse_customer_algorithm.to_owned();
+            result.sse_customer_algorithm = Some(value)
+        };
+        if let Some(sse_customer_key_md5) = response
+            .headers
+            .get("x-amz-server-side-encryption-customer-key-MD5")
+        {
+            let value = sse_customer_key_md5.to_owned();
+            result.sse_customer_key_md5 = Some(value)
+        };
+        if let Some(ssekms_encryption_context) =
+            response.headers.get("x-amz-server-side-encryption-context")
+        {
+            let value = ssekms_encryption_context.to_owned();
+            result.ssekms_encryption_context = Some(value)
+        };
         if let Some(ssekms_key_id) = response
             .headers
             .get("x-amz-server-side-encryption-aws-kms-key-id")
@@ -19558,23 +20187,37 @@ impl S3 for S3Client {
             let value = version_id.to_owned();
             result.version_id = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a copy of an object that is already stored in Amazon S3.</p> <note> <p>You can store individual objects of up to 5 TB in Amazon S3. You create a copy of your object up to 5 GB in size in a single atomic operation using this API. However, for copying an object greater than 5 GB, you must use the multipart upload Upload Part - Copy API. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/CopyingObjctsUsingRESTMPUapi.html">Copy Object Using the REST Multipart Upload API</a>.</p> </note> <p>When copying an object, you can preserve all metadata (default) or specify new metadata. However, the ACL is not preserved and is set to private for the user making the request. To override the default ACL setting, specify a new ACL when generating a copy request. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>.</p> <important> <p>Amazon S3 transfer acceleration does not support cross-region copies. If you request a cross-region copy using a transfer acceleration endpoint, you get a 400 <code>Bad Request</code> error. For more information about transfer acceleration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/transfer-acceleration.html">Transfer Acceleration</a>.</p> </important> <p>All copy requests must be authenticated. Additionally, you must have <i>read</i> access to the source object and <i>write</i> access to the destination bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/RESTAuthentication.html">REST Authentication</a>. Both the Region that you want to copy the object from and the Region that you want to copy the object to must be enabled for your account.</p> <p>To only copy an object under certain conditions, such as whether the <code>Etag</code> matches or whether the object was modified before or after a specified date, use the request parameters <code>x-amz-copy-source-if-match</code>, <code>x-amz-copy-source-if-none-match</code>, <code>x-amz-copy-source-if-unmodified-since</code>, or <code> x-amz-copy-source-if-modified-since</code>.</p> <note> <p>All headers with the <code>x-amz-</code> prefix, including <code>x-amz-copy-source</code>, must be signed.</p> </note> <p>You can use this operation to change the storage class of an object that is already stored in Amazon S3 using the <code>StorageClass</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-class-intro.html">Storage Classes</a>.</p> <p>The source object that you are copying can be encrypted or unencrypted. If the source object is encrypted, it can be encrypted by server-side encryption using AWS managed encryption keys or by using a customer-provided encryption key. When copying an object, you can request that Amazon S3 encrypt the target object by using either the AWS managed encryption keys or by using your own encryption key. You can do this regardless of the form of server-side encryption that was used to encrypt the source, or even if the source object was not encrypted. For more information about server-side encryption, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Using Server-Side Encryption</a>.</p> <p>A copy request might return an error when Amazon S3 receives the copy request or while Amazon S3 is copying the files. If the error occurs before the copy operation starts, you receive a standard Amazon S3 error. If the error occurs during the copy operation, the error response is embedded in the <code>200 OK</code> response. This means that a <code>200 OK</code> response can contain either a success or an error. Design your application to parse the contents of the response and handle it appropriately.</p> <p>If the copy is successful, you receive a response with information about the copied object.</p> <note> <p>If the request is an HTTP 1.1 request, the response is chunk encoded. If it were not, it would not contain the content-length, and you would need to read the entire body.</p> </note> <p>Consider the following when using request headers:</p> <ul> <li> <p> Consideration 1 – If both the <code>x-amz-copy-source-if-match</code> and <code>x-amz-copy-source-if-unmodified-since</code> headers are present in the request and evaluate as follows, Amazon S3 returns 200 OK and copies the data:</p> <ul> <li> <p> <code>x-amz-copy-source-if-match</code> condition evaluates to true</p> </li> <li> <p> <code>x-amz-copy-source-if-unmodified-since</code> condition evaluates to false</p> </li> </ul> </li> <li> <p> Consideration 2 – If both of the <code>x-amz-copy-source-if-none-match</code> and <code>x-amz-copy-source-if-modified-since</code> headers are present in the request and evaluate as follows, Amazon S3 returns the <code>412 Precondition Failed</code> response code:</p> <ul> <li> <p> <code>x-amz-copy-source-if-none-match</code> condition evaluates to false</p> </li> <li> <p> <code>x-amz-copy-source-if-modified-since</code> condition evaluates to true</p> </li> </ul> </li> </ul> <p>The copy request charge is based on the storage class and Region you specify for the destination object. For pricing information, see <a href="https://aws.amazon.com/s3/pricing/">Amazon S3 Pricing</a>.</p> <p>Following are other considerations when using <code>CopyObject</code>:</p> <dl> <dt>Versioning</dt> <dd> <p>By default, <code>x-amz-copy-source</code> identifies the current version of an object to copy. (If the current version is a delete marker, Amazon S3 behaves as if the object was deleted.) To copy a different version, use the <code>versionId</code> subresource.</p> <p>If you enable versioning on the target bucket, Amazon S3 generates a unique version ID for the object being copied. This version ID is different from the version ID of the source object. Amazon S3 returns the version ID of the copied object in the <code>x-amz-version-id</code> response header in the response.</p> <p>If you do not enable versioning or suspend it on the target bucket, the version ID that Amazon S3 generates is always null.</p> <p>If the source object's storage class is GLACIER, you must restore a copy of this object before you can use it as a source object for the copy operation. For more information, see .</p> </dd> <dt>Access Permissions</dt> <dd> <p>When copying an object, you can optionally specify the accounts or groups that should be granted specific permissions on the new object. There are two ways to grant the permissions using the request headers:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> </dd> <dt>Server-Side- Encryption-Specific Request Headers</dt> <dd> <p>To encrypt the target object, you must provide the appropriate encryption-related request headers. The one you use depends on whether you want to use AWS managed encryption keys or provide your own encryption key. </p> <ul> <li> <p>To encrypt the target object using server-side encryption with an AWS managed encryption key, provide the following request headers, as appropriate.</p> <ul> <li> <p> <code>x-amz-server-side​-encryption</code> </p> </li> <li> <p> <code>x-amz-server-side-encryption-aws-kms-key-id</code> </p> </li> <li> <p> <code>x-amz-server-side-encryption-context</code> </p> </li> </ul> <note> <p>If you specify <code>x-amz-server-side-encryption:aws:kms</code>, but don't provide <code>x-amz-server-side-encryption-aws-kms-key-id</code>, Amazon S3 uses the AWS managed CMK in AWS KMS to protect the data. If you want to use a customer managed AWS KMS CMK, you must provide the <code>x-amz-server-side-encryption-aws-kms-key-id</code> of the symmetric customer managed CMK. Amazon S3 only supports symmetric CMKs and not asymmetric CMKs. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> </note> <important> <p>All GET and PUT requests for an object protected by AWS KMS fail if you don't make them with SSL or by using SigV4.</p> </important> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in KMS</a>.</p> </li> <li> <p>To encrypt the target object using server-side encryption with an encryption key that you provide, use the following headers.</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> </li> <li> <p>If the source object is encrypted using server-side encryption with customer-provided encryption keys, you must use the following headers.</p> <ul> <li> <p>x-amz-copy-source​-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-copy-source​-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-copy-source-​server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in Amazon KMS</a>.</p> </li> </ul> </dd> <dt>Access-Control-List (ACL)-Specific Request Headers</dt> <dd> <p>You also can use the following access control–related headers with this operation. By default, all objects are private. Only the owner has full access control. When adding a new object, you can grant permissions to individual AWS accounts or to predefined groups defined by Amazon S3. These permissions are then added to the access control list (ACL) on the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>. With this operation, you can grant access permissions using one of the following two methods:</p> <ul> <li> <p>Specify a canned ACL (<code>x-amz-acl</code>) — Amazon S3 supports a set of predefined ACLs, known as <i>canned ACLs</i>. Each canned ACL has a predefined set of grantees and permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly — To explicitly grant access permissions to specific AWS accounts or groups, use the following headers. Each header maps to specific permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>. In the header, you specify a list of grantees who get the specific permission. To grant permissions explicitly, use:</p> <ul> <li> <p>x-amz-grant-read</p> </li> <li> <p>x-amz-grant-write</p> </li> <li> <p>x-amz-grant-read-acp</p> </li> <li> <p>x-amz-grant-write-acp</p> </li> <li> <p>x-amz-grant-full-control</p> </li> </ul> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants the AWS accounts identified by email addresses permissions to read object data and its metadata:</p> <p> <code>x-amz-grant-read: emailAddress="xyz@amazon.com", emailAddress="abc@amazon.com" </code> </p> </li> </ul> </dd> </dl> <p>The following operations are related to <code>CopyObject</code>:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>GetObject</a> </p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/CopyingObjectsExamples.html">Copying Objects</a>.</p>
-    #[allow(unused_variables, warnings)]
-    async fn copy_object(
+    /// Presigns the request `copy_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_object(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CopyObjectRequest,
-    ) -> Result<CopyObjectOutput, RusotoError<CopyObjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_copy_object_request(&self, input: CopyObjectRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CopyObject");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
         }
 
+        if let Some(ref bucket_key_enabled) = input.bucket_key_enabled {
+            request.add_header(
+                "x-amz-server-side-encryption-bucket-key-enabled",
+                &bucket_key_enabled.to_string(),
+            );
+        }
+
         if let Some(ref cache_control) = input.cache_control {
             request.add_header("Cache-Control", &cache_control.to_string());
         }
@@ -19761,21 +20404,39 @@ impl S3 for S3Client {
             );
         }
 
+        request
+    }
+
+    /// Calls `create_bucket`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_bucket_with_metadata(
+        &self,
+        input: CreateBucketRequest,
+    ) -> Result<(CreateBucketOutput, ResponseMetadata), RusotoError<CreateBucketError>> {
+        let request = self.build_create_bucket_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(CopyObjectError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(CreateBucketError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = CopyObjectOutput::default();
+            result = CreateBucketOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -19784,67 +20445,31 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = CopyObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result = CreateBucketOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
-        if let Some(copy_source_version_id) = response.headers.get("x-amz-copy-source-version-id") {
-            let value = copy_source_version_id.to_owned();
-            result.copy_source_version_id = Some(value)
-        };
-        if let Some(expiration) = response.headers.get("x-amz-expiration") {
-            let value = expiration.to_owned();
-            result.expiration = Some(value)
-        };
-        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-            let value = request_charged.to_owned();
-            result.request_charged = Some(value)
-        };
-        if let Some(sse_customer_algorithm) = response
-            .headers
-            .get("x-amz-server-side-encryption-customer-algorithm")
-        {
-            let value = sse_customer_algorithm.to_owned();
-            result.sse_customer_algorithm = Some(value)
-        };
-        if let Some(sse_customer_key_md5) = response
-            .headers
-            .get("x-amz-server-side-encryption-customer-key-MD5")
-        {
-            let value = sse_customer_key_md5.to_owned();
-            result.sse_customer_key_md5 = Some(value)
-        };
-        if let Some(ssekms_encryption_context) =
-            response.headers.get("x-amz-server-side-encryption-context")
-        {
-            let value = ssekms_encryption_context.to_owned();
-            result.ssekms_encryption_context = Some(value)
-        };
-        if let Some(ssekms_key_id) = response
-            .headers
-            .get("x-amz-server-side-encryption-aws-kms-key-id")
-        {
-            let value = ssekms_key_id.to_owned();
-            result.ssekms_key_id = Some(value)
-        };
-        if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
-            let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
-        };
-        if let Some(version_id) = response.headers.get("x-amz-version-id") {
-            let value = version_id.to_owned();
-            result.version_id = Some(value)
+        if let Some(location) = response.headers.get("Location") {
+            let value = location.to_owned();
+            result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a new bucket. To create a bucket, you must register with Amazon S3 and have a valid AWS Access Key ID to authenticate requests. Anonymous requests are never allowed to create buckets. By creating the bucket, you become the bucket owner.</p> <p>Not every string is an acceptable bucket name. For information on bucket naming restrictions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingBucket.html">Working with Amazon S3 Buckets</a>.</p> <p>By default, the bucket is created in the US East (N. Virginia) Region. You can optionally specify a Region in the request body. You might choose a Region to optimize latency, minimize costs, or address regulatory requirements. For example, if you reside in Europe, you will probably find it advantageous to create buckets in the EU (Ireland) Region. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingBucket.html#access-bucket-intro">How to Select a Region for Your Buckets</a>.</p> <note> <p>If you send your create bucket request to the <code>s3.amazonaws.com</code> endpoint, the request goes to the us-east-1 Region. Accordingly, the signature calculations in Signature Version 4 must use us-east-1 as the Region, even if the location constraint in the request specifies another Region where the bucket is to be created. If you create a bucket in a Region other than US East (N. Virginia), your application must be able to handle 307 redirect. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/VirtualHosting.html">Virtual Hosting of Buckets</a>.</p> </note> <p>When creating a bucket using this operation, you can optionally specify the accounts or groups that should be granted specific permissions on the bucket. There are two ways to grant the appropriate permissions using the request headers.</p> <ul> <li> <p>Specify a canned ACL using the <code>x-amz-acl</code> request header. Amazon S3 supports a set of predefined ACLs, known as <i>canned ACLs</i>. Each canned ACL has a predefined set of grantees and permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly using the <code>x-amz-grant-read</code>, <code>x-amz-grant-write</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. These headers map to the set of permissions Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants the AWS accounts identified by email addresses permissions to read object data and its metadata:</p> <p> <code>x-amz-grant-read: emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> <note> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> </note> <p>The following operations are related to <code>CreateBucket</code>:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn create_bucket(
+    /// Presigns the request `create_bucket` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_bucket(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBucketRequest,
-    ) -> Result<CreateBucketOutput, RusotoError<CreateBucketError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_bucket_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_create_bucket_request(&self, input: CreateBucketRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CreateBucket");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
@@ -19889,21 +20514,42 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        request
+    }
+
+    /// Calls `create_multipart_upload`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_multipart_upload_with_metadata(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> Result<
+        (CreateMultipartUploadOutput, ResponseMetadata),
+        RusotoError<CreateMultipartUploadError>,
+    > {
+        let request = self.build_create_multipart_upload_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(CreateBucketError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(CreateMultipartUploadError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = CreateBucketOutput::default();
+            result = CreateMultipartUploadOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -19912,29 +20558,86 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = CreateBucketOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result =
+                CreateMultipartUploadOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
-        if let Some(location) = response.headers.get("Location") {
-            let value = location.to_owned();
-            result.location = Some(value)
-        }; // parse non-payload
-        Ok(result)
-    }
-
-    /// <p><p>This operation initiates a multipart upload and returns an upload ID. This upload ID is used to associate all of the parts in the specific multipart upload. You specify this upload ID in each of your subsequent upload part requests (see <a>UploadPart</a>). You also include this upload ID in the final request to either complete or abort the multipart upload request.</p> <p>For more information about multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html">Multipart Upload Overview</a>.</p> <p>If you have configured a lifecycle rule to abort incomplete multipart uploads, the upload must complete within the number of days specified in the bucket lifecycle configuration. Otherwise, the incomplete multipart upload becomes eligible for an abort operation and Amazon S3 aborts the multipart upload. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html#mpu-abort-incomplete-mpu-lifecycle-config">Aborting Incomplete Multipart Uploads Using a Bucket Lifecycle Policy</a>.</p> <p>For information about the permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p>For request signing, multipart upload is just a series of regular requests. You initiate a multipart upload, send one or more requests to upload parts, and then complete the multipart upload process. You sign each request individually. There is nothing special about signing multipart upload requests. For more information about signing, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-authenticating-requests.html">Authenticating Requests (AWS Signature Version 4)</a>.</p> <note> <p> After you initiate a multipart upload and upload one or more parts, to stop being charged for storing the uploaded parts, you must either complete or abort the multipart upload. Amazon S3 frees up the space used to store the parts and stop charging you for storing them only after you either complete or abort a multipart upload. </p> </note> <p>You can optionally request server-side encryption. For server-side encryption, Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it when you access it. You can provide your own encryption key, or use AWS Key Management Service (AWS KMS) customer master keys (CMKs) or Amazon S3-managed encryption keys. If you choose to provide your own encryption key, the request headers you provide in <a>UploadPart</a>) and <a>UploadPartCopy</a>) requests must match the headers you used in the request to initiate the upload by using <code>CreateMultipartUpload</code>. </p> <p>To perform a multipart upload with encryption using an AWS KMS CMK, the requester must have permission to the <code>kms:Encrypt</code>, <code>kms:Decrypt</code>, <code>kms:ReEncrypt<em></code>, <code>kms:GenerateDataKey</em></code>, and <code>kms:DescribeKey</code> actions on the key. These permissions are required because Amazon S3 must decrypt and read data from the encrypted file parts before it completes the multipart upload.</p> <p>If your AWS Identity and Access Management (IAM) user or role is in the same AWS account as the AWS KMS CMK, then you must have these permissions on the key policy. If your IAM user or role belongs to a different account than the key, then you must have the permissions on both the key policy and your IAM user or role.</p> <p> For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Protecting Data Using Server-Side Encryption</a>.</p> <dl> <dt>Access Permissions</dt> <dd> <p>When copying an object, you can optionally specify the accounts or groups that should be granted specific permissions on the new object. There are two ways to grant the permissions using the request headers:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> </dd> <dt>Server-Side- Encryption-Specific Request Headers</dt> <dd> <p>You can optionally tell Amazon S3 to encrypt data at rest using server-side encryption. Server-side encryption is for data encryption at rest. Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it when you access it. The option you use depends on whether you want to use AWS managed encryption keys or provide your own encryption key. </p> <ul> <li> <p>Use encryption keys managed by Amazon S3 or customer master keys (CMKs) stored in AWS Key Management Service (AWS KMS) – If you want AWS to manage the keys used to encrypt data, specify the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption</p> </li> <li> <p>x-amz-server-side-encryption-aws-kms-key-id</p> </li> <li> <p>x-amz-server-side-encryption-context</p> </li> </ul> <note> <p>If you specify <code>x-amz-server-side-encryption:aws:kms</code>, but don&#39;t provide <code>x-amz-server-side-encryption-aws-kms-key-id</code>, Amazon S3 uses the AWS managed CMK in AWS KMS to protect the data.</p> </note> <important> <p>All GET and PUT requests for an object protected by AWS KMS fail if you don&#39;t make them with SSL or by using SigV4.</p> </important> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS KMS</a>.</p> </li> <li> <p>Use customer-provided encryption keys – If you want to manage your own encryption keys, provide all the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS KMS</a>.</p> </li> </ul> </dd> <dt>Access-Control-List (ACL)-Specific Request Headers</dt> <dd> <p>You also can use the following access control–related headers with this operation. By default, all objects are private. Only the owner has full access control. When adding a new object, you can grant permissions to individual AWS accounts or to predefined groups defined by Amazon S3. These permissions are then added to the access control list (ACL) on the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>. With this operation, you can grant access permissions using one of the following two methods:</p> <ul> <li> <p>Specify a canned ACL (<code>x-amz-acl</code>) — Amazon S3 supports a set of predefined ACLs, known as <i>canned ACLs</i>. Each canned ACL has a predefined set of grantees and permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly — To explicitly grant access permissions to specific AWS accounts or groups, use the following headers. Each header maps to specific permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>. In the header, you specify a list of grantees who get the specific permission. To grant permissions explicitly, use:</p> <ul> <li> <p>x-amz-grant-read</p> </li> <li> <p>x-amz-grant-write</p> </li> <li> <p>x-amz-grant-read-acp</p> </li> <li> <p>x-amz-grant-write-acp</p> </li> <li> <p>x-amz-grant-full-control</p> </li> </ul> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants the AWS accounts identified by email addresses permissions to read object data and its metadata:</p> <p> <code>x-amz-grant-read: emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> </dd> </dl> <p>The following operations are related to <code>CreateMultipartUpload</code>:</p> <ul> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn create_multipart_upload(
-        &self,
-        input: CreateMultipartUploadRequest,
-    ) -> Result<CreateMultipartUploadOutput, RusotoError<CreateMultipartUploadError>> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
-
+        if let Some(abort_date) = response.headers.get("x-amz-abort-date") {
+            let value = abort_date.to_owned();
+            result.abort_date = Some(value)
+        };
+        if let Some(abort_rule_id) = response.headers.get("x-amz-abort-rule-id") {
+            let value = abort_rule_id.to_owned();
+            result.abort_rule_id = Some(value)
+        };
+        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+            let value = request_charged.to_owned();
+            result.request_charged = Some(value)
+        };
+        if let Some(sse_customer_algorithm) = response
+            .headers
+            .get("x-amz-server-side-encryption-customer-algorithm")
+        {
+            let value = sse_customer_algorithm.to_owned();
+            result.sse_customer_algorithm = Some(value)
+        };
+        if let Some(sse_customer_key_md5) = response
+            .headers
+            .get("x-amz-server-side-encryption-customer-key-MD5")
+        {
+            let value = sse_customer_key_md5.to_owned();
+            result.sse_customer_key_md5 = Some(value)
+        };
+        if let Some(ssekms_encryption_context) =
+            response.headers.get("x-amz-server-side-encryption-context")
+        {
+            let value = ssekms_encryption_context.to_owned();
+            result.ssekms_encryption_context = Some(value)
+        };
+        if let Some(ssekms_key_id) = response
+            .headers
+            .get("x-amz-server-side-encryption-aws-kms-key-id")
+        {
+            let value = ssekms_key_id.to_owned();
+            result.ssekms_key_id = Some(value)
+        };
+        if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
+            let value = server_side_encryption.to_owned();
+            result.server_side_encryption = Some(value)
+        }; // parse non-payload
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_multipart_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_multipart_upload(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateMultipartUploadRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_multipart_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_create_multipart_upload_request(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+
+        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CreateMultipartUpload");
+
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
         }
 
+        if let Some(ref bucket_key_enabled) = input.bucket_key_enabled {
+            request.add_header(
+                "x-amz-server-side-encryption-bucket-key-enabled",
+                &bucket_key_enabled.to_string(),
+            );
+        }
+
         if let Some(ref cache_control) = input.cache_control {
             request.add_header("Cache-Control", &cache_control.to_string());
         }
@@ -20064,400 +20767,662 @@ impl S3 for S3Client {
         params.put_key("uploads");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_with_metadata(
+        &self,
+        input: DeleteBucketRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketError>> {
+        let request = self.build_delete_bucket_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(CreateMultipartUploadError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-        let mut result;
-
-        if xml_response.body.is_empty() {
-            result = CreateMultipartUploadOutput::default();
-        } else {
-            let reader = EventReader::new_with_config(
-                xml_response.body.as_ref(),
-                ParserConfig::new().trim_whitespace(false),
-            );
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            let _start_document = stack.next();
-            let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                CreateMultipartUploadOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-        }
-        if let Some(abort_date) = response.headers.get("x-amz-abort-date") {
-            let value = abort_date.to_owned();
-            result.abort_date = Some(value)
-        };
-        if let Some(abort_rule_id) = response.headers.get("x-amz-abort-rule-id") {
-            let value = abort_rule_id.to_owned();
-            result.abort_rule_id = Some(value)
-        };
-        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-            let value = request_charged.to_owned();
-            result.request_charged = Some(value)
-        };
-        if let Some(sse_customer_algorithm) = response
-            .headers
-            .get("x-amz-server-side-encryption-customer-algorithm")
-        {
-            let value = sse_customer_algorithm.to_owned();
-            result.sse_customer_algorithm = Some(value)
-        };
-        if let Some(sse_customer_key_md5) = response
-            .headers
-            .get("x-amz-server-side-encryption-customer-key-MD5")
-        {
-            let value = sse_customer_key_md5.to_owned();
-            result.sse_customer_key_md5 = Some(value)
-        };
-        if let Some(ssekms_encryption_context) =
-            response.headers.get("x-amz-server-side-encryption-context")
-        {
-            let value = ssekms_encryption_context.to_owned();
-            result.ssekms_encryption_context = Some(value)
-        };
-        if let Some(ssekms_key_id) = response
-            .headers
-            .get("x-amz-server-side-encryption-aws-kms-key-id")
-        {
-            let value = ssekms_key_id.to_owned();
-            result.ssekms_key_id = Some(value)
-        };
-        if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
-            let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
-        }; // parse non-payload
-        Ok(result)
+        std::mem::drop(response);
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes the bucket. All objects (including all object versions and delete markers) in the bucket must be deleted before the bucket itself can be deleted.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> </p> </li> <li> <p> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn delete_bucket(
+    /// Presigns the request `delete_bucket` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBucketRequest,
-    ) -> Result<(), RusotoError<DeleteBucketError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_delete_bucket_request(&self, input: DeleteBucketRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucket");
+
+        request
+    }
+
+    /// Calls `delete_bucket_analytics_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_analytics_configuration_with_metadata(
+        &self,
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketAnalyticsConfigurationError>> {
+        let request = self.build_delete_bucket_analytics_configuration_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketAnalyticsConfigurationError::from_response(
+                response,
+            ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_bucket_analytics_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_analytics_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_analytics_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Deletes an analytics configuration for the bucket (specified by the analytics configuration ID).</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutAnalyticsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about the Amazon S3 analytics feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/analytics-storage-class.html">Amazon S3 Analytics – Storage Class Analysis</a>. </p> <p>The following operations are related to <code>DeleteBucketAnalyticsConfiguration</code>:</p> <ul> <li> <p> </p> </li> <li> <p> </p> </li> <li> <p> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_bucket_analytics_configuration(
+    fn build_delete_bucket_analytics_configuration_request(
         &self,
         input: DeleteBucketAnalyticsConfigurationRequest,
-    ) -> Result<(), RusotoError<DeleteBucketAnalyticsConfigurationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketAnalyticsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
         params.put_key("analytics");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket_cors`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_cors_with_metadata(
+        &self,
+        input: DeleteBucketCorsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketCorsError>> {
+        let request = self.build_delete_bucket_cors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketAnalyticsConfigurationError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketCorsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes the <code>cors</code> configuration information set for the bucket.</p> <p>To use this operation, you must have permission to perform the <code>s3:PutBucketCORS</code> action. The bucket owner has this permission by default and can grant this permission to others. </p> <p>For information about <code>cors</code>, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cors.html">Enabling Cross-Origin Resource Sharing</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p class="title"> <b>Related Resources:</b> </p> <ul> <li> <p> </p> </li> <li> <p> <a>RESTOPTIONSobject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn delete_bucket_cors(
+    /// Presigns the request `delete_bucket_cors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_cors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBucketCorsRequest,
-    ) -> Result<(), RusotoError<DeleteBucketCorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_cors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_delete_bucket_cors_request(&self, input: DeleteBucketCorsRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketCors");
 
         let mut params = Params::new();
         params.put_key("cors");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket_encryption`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_encryption_with_metadata(
+        &self,
+        input: DeleteBucketEncryptionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketEncryptionError>> {
+        let request = self.build_delete_bucket_encryption_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketCorsError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketEncryptionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_bucket_encryption` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_encryption(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteBucketEncryptionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_encryption_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>This implementation of the DELETE operation removes default encryption from the bucket. For information about the Amazon S3 default encryption feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//bucket-encryption.html">Amazon S3 Default Bucket Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutEncryptionConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>PutBucketEncryption</a> </p> </li> <li> <p> <a>GetBucketEncryption</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_bucket_encryption(
+    fn build_delete_bucket_encryption_request(
         &self,
         input: DeleteBucketEncryptionRequest,
-    ) -> Result<(), RusotoError<DeleteBucketEncryptionError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketEncryption");
 
         let mut params = Params::new();
         params.put_key("encryption");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket_inventory_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_inventory_configuration_with_metadata(
+        &self,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketInventoryConfigurationError>> {
+        let request = self.build_delete_bucket_inventory_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketEncryptionError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketInventoryConfigurationError::from_response(
+                response,
+            ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_bucket_inventory_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_inventory_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_inventory_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Deletes an inventory configuration (identified by the inventory ID) from the bucket.</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutInventoryConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about the Amazon S3 inventory feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-inventory.html">Amazon S3 Inventory</a>.</p> <p>Operations related to <code>DeleteBucketInventoryConfiguration</code> include: </p> <ul> <li> <p> <a>GetBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>PutBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>ListBucketInventoryConfigurations</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_bucket_inventory_configuration(
+    fn build_delete_bucket_inventory_configuration_request(
         &self,
         input: DeleteBucketInventoryConfigurationRequest,
-    ) -> Result<(), RusotoError<DeleteBucketInventoryConfigurationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketInventoryConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
         params.put_key("inventory");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket_lifecycle`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_lifecycle_with_metadata(
+        &self,
+        input: DeleteBucketLifecycleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketLifecycleError>> {
+        let request = self.build_delete_bucket_lifecycle_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketInventoryConfigurationError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketLifecycleError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_bucket_lifecycle` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_lifecycle(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteBucketLifecycleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_lifecycle_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Deletes the lifecycle configuration from the specified bucket. Amazon S3 removes all the lifecycle configuration rules in the lifecycle subresource associated with the bucket. Your objects never expire, and Amazon S3 no longer automatically deletes any objects on the basis of rules contained in the deleted lifecycle configuration.</p> <p>To use this operation, you must have permission to perform the <code>s3:PutLifecycleConfiguration</code> action. By default, the bucket owner has this permission and the bucket owner can grant this permission to others.</p> <p>There is usually some time lag before lifecycle configuration deletion is fully propagated to all the Amazon S3 systems.</p> <p>For more information about the object expiration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/intro-lifecycle-rules.html#intro-lifecycle-rules-actions">Elements to Describe Lifecycle Actions</a>.</p> <p>Related actions include:</p> <ul> <li> <p> <a>PutBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_bucket_lifecycle(
+    fn build_delete_bucket_lifecycle_request(
         &self,
         input: DeleteBucketLifecycleRequest,
-    ) -> Result<(), RusotoError<DeleteBucketLifecycleError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketLifecycle");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket_metrics_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_metrics_configuration_with_metadata(
+        &self,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketMetricsConfigurationError>> {
+        let request = self.build_delete_bucket_metrics_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketLifecycleError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketMetricsConfigurationError::from_response(
+                response,
+            ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_bucket_metrics_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_metrics_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_metrics_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Deletes a metrics configuration for the Amazon CloudWatch request metrics (specified by the metrics configuration ID) from the bucket. Note that this doesn&#39;t include the daily storage metrics.</p> <p> To use this operation, you must have permissions to perform the <code>s3:PutMetricsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about CloudWatch request metrics for Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a>. </p> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>GetBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>PutBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>ListBucketMetricsConfigurations</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_bucket_metrics_configuration(
+    fn build_delete_bucket_metrics_configuration_request(
         &self,
         input: DeleteBucketMetricsConfigurationRequest,
-    ) -> Result<(), RusotoError<DeleteBucketMetricsConfigurationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketMetricsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
         params.put_key("metrics");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket_policy`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_policy_with_metadata(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketPolicyError>> {
+        let request = self.build_delete_bucket_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketMetricsConfigurationError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketPolicyError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>This implementation of the DELETE operation uses the policy subresource to delete the policy of a specified bucket. If you are using an identity other than the root user of the AWS account that owns the bucket, the calling identity must have the <code>DeleteBucketPolicy</code> permissions on the specified bucket and belong to the bucket owner&#39;s account to use this operation. </p> <p>If you don&#39;t have <code>DeleteBucketPolicy</code> permissions, Amazon S3 returns a <code>403 Access Denied</code> error. If you have the correct permissions, but you&#39;re not using an identity that belongs to the bucket owner&#39;s account, Amazon S3 returns a <code>405 Method Not Allowed</code> error. </p> <important> <p>As a security precaution, the root user of the AWS account that owns a bucket can always use this operation, even if the policy explicitly denies the root user the ability to perform this action.</p> </important> <p>For more information about bucket policies, see <a href=" https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies and UserPolicies</a>. </p> <p>The following operations are related to <code>DeleteBucketPolicy</code> </p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn delete_bucket_policy(
+    /// Presigns the request `delete_bucket_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBucketPolicyRequest,
-    ) -> Result<(), RusotoError<DeleteBucketPolicyError>> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_delete_bucket_policy_request(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketPolicy");
 
         let mut params = Params::new();
         params.put_key("policy");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket_replication`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_replication_with_metadata(
+        &self,
+        input: DeleteBucketReplicationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketReplicationError>> {
+        let request = self.build_delete_bucket_replication_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketPolicyError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketReplicationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_bucket_replication` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_replication(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteBucketReplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_replication_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p> Deletes the replication configuration from the bucket.</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutReplicationConfiguration</code> action. The bucket owner has these permissions by default and can grant it to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>. </p> <note> <p>It can take a while for the deletion of a replication configuration to fully propagate.</p> </note> <p> For information about replication configuration, see <a href=" https://docs.aws.amazon.com/AmazonS3/latest/dev/replication.html">Replication</a> in the <i>Amazon S3 Developer Guide</i>. </p> <p>The following operations are related to <code>DeleteBucketReplication</code>:</p> <ul> <li> <p> <a>PutBucketReplication</a> </p> </li> <li> <p> <a>GetBucketReplication</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_bucket_replication(
+    fn build_delete_bucket_replication_request(
         &self,
         input: DeleteBucketReplicationRequest,
-    ) -> Result<(), RusotoError<DeleteBucketReplicationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketReplication");
 
         let mut params = Params::new();
         params.put_key("replication");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket_tagging`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_tagging_with_metadata(
+        &self,
+        input: DeleteBucketTaggingRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketTaggingError>> {
+        let request = self.build_delete_bucket_tagging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketReplicationError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketTaggingError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_bucket_tagging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_tagging(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteBucketTaggingRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_tagging_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Deletes the tags from the bucket.</p> <p>To use this operation, you must have permission to perform the <code>s3:PutBucketTagging</code> action. By default, the bucket owner has this permission and can grant this permission to others. </p> <p>The following operations are related to <code>DeleteBucketTagging</code>:</p> <ul> <li> <p> <a>GetBucketTagging</a> </p> </li> <li> <p> <a>PutBucketTagging</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_bucket_tagging(
+    fn build_delete_bucket_tagging_request(
         &self,
         input: DeleteBucketTaggingRequest,
-    ) -> Result<(), RusotoError<DeleteBucketTaggingError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketTagging");
 
         let mut params = Params::new();
         params.put_key("tagging");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_bucket_website`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_bucket_website_with_metadata(
+        &self,
+        input: DeleteBucketWebsiteRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBucketWebsiteError>> {
+        let request = self.build_delete_bucket_website_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketTaggingError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteBucketWebsiteError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_bucket_website` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bucket_website(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteBucketWebsiteRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bucket_website_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>This operation removes the website configuration for a bucket. Amazon S3 returns a <code>200 OK</code> response upon successfully deleting a website configuration on the specified bucket. You will get a <code>200 OK</code> response if the website configuration you are trying to delete does not exist on the bucket. Amazon S3 returns a <code>404</code> response if the bucket specified in the request does not exist.</p> <p>This DELETE operation requires the <code>S3:DeleteBucketWebsite</code> permission. By default, only the bucket owner can delete the website configuration attached to a bucket. However, bucket owners can grant other users permission to delete the website configuration by writing a bucket policy granting them the <code>S3:DeleteBucketWebsite</code> permission. </p> <p>For more information about hosting websites, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/WebsiteHosting.html">Hosting Websites on Amazon S3</a>. </p> <p>The following operations are related to <code>DeleteBucketWebsite</code>:</p> <ul> <li> <p> <a>GetBucketWebsite</a> </p> </li> <li> <p> <a>PutBucketWebsite</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_bucket_website(
+    fn build_delete_bucket_website_request(
         &self,
         input: DeleteBucketWebsiteRequest,
-    ) -> Result<(), RusotoError<DeleteBucketWebsiteError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketWebsite");
 
         let mut params = Params::new();
         params.put_key("website");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_object`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_object_with_metadata(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> Result<(DeleteObjectOutput, ResponseMetadata), RusotoError<DeleteObjectError>> {
+        let request = self.build_delete_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteBucketWebsiteError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteObjectError::from_response(response));
         }
 
-        std::mem::drop(response);
-        Ok(())
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
+        let mut result;
+
+        if xml_response.body.is_empty() {
+            result = DeleteObjectOutput::default();
+        } else {
+            let reader = EventReader::new_with_config(
+                xml_response.body.as_ref(),
+                ParserConfig::new().trim_whitespace(false),
+            );
+            let mut stack = XmlResponse::new(reader.into_iter().peekable());
+            let _start_document = stack.next();
+            let actual_tag_name = peek_at_name(&mut stack)?;
+            result = DeleteObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+        }
+        if let Some(delete_marker) = response.headers.get("x-amz-delete-marker") {
+            let value = delete_marker.to_owned();
+            result.delete_marker = Some(value.parse::<bool>().unwrap())
+        };
+        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+            let value = request_charged.to_owned();
+            result.request_charged = Some(value)
+        };
+        if let Some(version_id) = response.headers.get("x-amz-version-id") {
+            let value = version_id.to_owned();
+            result.version_id = Some(value)
+        }; // parse non-payload
+        Ok((result, metadata))
     }
 
-    /// <p><p>Removes the null version (if there is one) of an object and inserts a delete marker, which becomes the latest version of the object. If there isn&#39;t a null version, Amazon S3 does not remove any objects.</p> <p>To remove a specific version, you must be the bucket owner and you must use the version Id subresource. Using this subresource permanently deletes the version. If the object deleted is a delete marker, Amazon S3 sets the response header, <code>x-amz-delete-marker</code>, to true. </p> <p>If the object you want to delete is in a bucket where the bucket versioning configuration is MFA Delete enabled, you must include the <code>x-amz-mfa</code> request header in the DELETE <code>versionId</code> request. Requests that include <code>x-amz-mfa</code> must use HTTPS. </p> <p> For more information about MFA Delete, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingMFADelete.html">Using MFA Delete</a>. To see sample requests that use versioning, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectDELETE.html#ExampleVersionObjectDelete">Sample Request</a>. </p> <p>You can delete objects by explicitly calling the DELETE Object API or configure its lifecycle (<a>PutBucketLifecycle</a>) to enable Amazon S3 to remove them for you. If you want to block users or accounts from removing or deleting objects from your bucket, you must deny them the <code>s3:DeleteObject</code>, <code>s3:DeleteObjectVersion</code>, and <code>s3:PutLifeCycleConfiguration</code> actions. </p> <p>The following operation is related to <code>DeleteObject</code>:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn delete_object(
+    /// Presigns the request `delete_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_object(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteObjectRequest,
-    ) -> Result<DeleteObjectOutput, RusotoError<DeleteObjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_delete_object_request(&self, input: DeleteObjectRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObject");
 
         if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
             request.add_header(
@@ -20479,21 +21444,40 @@ impl S3 for S3Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_object_tagging`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_object_tagging_with_metadata(
+        &self,
+        input: DeleteObjectTaggingRequest,
+    ) -> Result<(DeleteObjectTaggingOutput, ResponseMetadata), RusotoError<DeleteObjectTaggingError>>
+    {
+        let request = self.build_delete_object_tagging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteObjectError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteObjectTaggingError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = DeleteObjectOutput::default();
+            result = DeleteObjectTaggingOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -20502,32 +21486,35 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = DeleteObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result =
+                DeleteObjectTaggingOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
-        if let Some(delete_marker) = response.headers.get("x-amz-delete-marker") {
-            let value = delete_marker.to_owned();
-            result.delete_marker = Some(value.parse::<bool>().unwrap())
-        };
-        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-            let value = request_charged.to_owned();
-            result.request_charged = Some(value)
-        };
         if let Some(version_id) = response.headers.get("x-amz-version-id") {
             let value = version_id.to_owned();
             result.version_id = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `delete_object_tagging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_object_tagging(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteObjectTaggingRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_object_tagging_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Removes the entire tag set from the specified object. For more information about managing object tags, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-tagging.html"> Object Tagging</a>.</p> <p>To use this operation, you must have permission to perform the <code>s3:DeleteObjectTagging</code> action.</p> <p>To delete tags of a specific object version, add the <code>versionId</code> query parameter in the request. You will need permission for the <code>s3:DeleteObjectVersionTagging</code> action.</p> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>PutObjectTagging</a> </p> </li> <li> <p> <a>GetObjectTagging</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_object_tagging(
+    fn build_delete_object_tagging_request(
         &self,
         input: DeleteObjectTaggingRequest,
-    ) -> Result<DeleteObjectTaggingOutput, RusotoError<DeleteObjectTaggingError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjectTagging");
 
         let mut params = Params::new();
         if let Some(ref x) = input.version_id {
@@ -20536,21 +21523,39 @@ impl S3 for S3Client {
         params.put_key("tagging");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_objects`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_objects_with_metadata(
+        &self,
+        input: DeleteObjectsRequest,
+    ) -> Result<(DeleteObjectsOutput, ResponseMetadata), RusotoError<DeleteObjectsError>> {
+        let request = self.build_delete_objects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteObjectTaggingError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteObjectsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = DeleteObjectTaggingOutput::default();
+            result = DeleteObjectsOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -20559,25 +21564,31 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                DeleteObjectTaggingOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result = DeleteObjectsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
-        if let Some(version_id) = response.headers.get("x-amz-version-id") {
-            let value = version_id.to_owned();
-            result.version_id = Some(value)
+        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+            let value = request_charged.to_owned();
+            result.request_charged = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>This operation enables you to delete multiple objects from a bucket using a single HTTP request. If you know the object keys that you want to delete, then this operation provides a suitable alternative to sending individual delete requests, reducing per-request overhead.</p> <p>The request contains a list of up to 1000 keys that you want to delete. In the XML, you provide the object key names, and optionally, version IDs if you want to delete a specific version of the object from a versioning-enabled bucket. For each key, Amazon S3 performs a delete operation and returns the result of that delete, success, or failure, in the response. Note that if the object specified in the request is not found, Amazon S3 returns the result as deleted.</p> <p> The operation supports two modes for the response: verbose and quiet. By default, the operation uses verbose mode in which the response includes the result of deletion of each key in your request. In quiet mode the response includes only keys where the delete operation encountered an error. For a successful deletion, the operation does not return any information about the delete in the response body.</p> <p>When performing this operation on an MFA Delete enabled bucket, that attempts to delete any versioned objects, you must include an MFA token. If you do not provide one, the entire request will fail, even if there are non-versioned objects you are trying to delete. If you provide an invalid token, whether there are versioned keys in the request or not, the entire Multi-Object Delete request will fail. For information about MFA Delete, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/Versioning.html#MultiFactorAuthenticationDelete"> MFA Delete</a>.</p> <p>Finally, the Content-MD5 header is required for all Multi-Object Delete requests. Amazon S3 uses the header value to ensure that your request body has not been altered in transit.</p> <p>The following operations are related to <code>DeleteObjects</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn delete_objects(
+    /// Presigns the request `delete_objects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_objects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteObjectsRequest,
-    ) -> Result<DeleteObjectsOutput, RusotoError<DeleteObjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_objects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_delete_objects_request(&self, input: DeleteObjectsRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjects");
 
         if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
             request.add_header(
@@ -20601,96 +21612,93 @@ impl S3 for S3Client {
         request.set_payload(Some(writer.into_inner()));
         request.set_content_md5_header();
 
+        request
+    }
+
+    /// Calls `delete_public_access_block`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_public_access_block_with_metadata(
+        &self,
+        input: DeletePublicAccessBlockRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePublicAccessBlockError>> {
+        let request = self.build_delete_public_access_block_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteObjectsError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeletePublicAccessBlockError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-        let mut result;
+        std::mem::drop(response);
+        Ok(((), metadata))
+    }
 
-        if xml_response.body.is_empty() {
-            result = DeleteObjectsOutput::default();
-        } else {
-            let reader = EventReader::new_with_config(
-                xml_response.body.as_ref(),
-                ParserConfig::new().trim_whitespace(false),
-            );
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            let _start_document = stack.next();
-            let actual_tag_name = peek_at_name(&mut stack)?;
-            result = DeleteObjectsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-        }
-        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-            let value = request_charged.to_owned();
-            result.request_charged = Some(value)
-        }; // parse non-payload
-        Ok(result)
+    /// Presigns the request `delete_public_access_block` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_public_access_block(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeletePublicAccessBlockRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_public_access_block_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Removes the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket. To use this operation, you must have the <code>s3:PutBucketPublicAccessBlock</code> permission. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html">Using Amazon S3 Block Public Access</a> </p> </li> <li> <p> <a>GetPublicAccessBlock</a> </p> </li> <li> <p> <a>PutPublicAccessBlock</a> </p> </li> <li> <p> <a>GetBucketPolicyStatus</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn delete_public_access_block(
+    fn build_delete_public_access_block_request(
         &self,
         input: DeletePublicAccessBlockRequest,
-    ) -> Result<(), RusotoError<DeletePublicAccessBlockError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeletePublicAccessBlock");
 
         let mut params = Params::new();
         params.put_key("publicAccessBlock");
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeletePublicAccessBlockError::from_response(response));
-        }
-
-        std::mem::drop(response);
-        Ok(())
+        request
     }
 
-    /// <p><p>This implementation of the GET operation uses the <code>accelerate</code> subresource to return the Transfer Acceleration state of a bucket, which is either <code>Enabled</code> or <code>Suspended</code>. Amazon S3 Transfer Acceleration is a bucket-level feature that enables you to perform faster data transfers to and from Amazon S3.</p> <p>To use this operation, you must have permission to perform the <code>s3:GetAccelerateConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>You set the Transfer Acceleration state of an existing bucket to <code>Enabled</code> or <code>Suspended</code> by using the <a>PutBucketAccelerateConfiguration</a> operation. </p> <p>A GET <code>accelerate</code> request does not return a state value for a bucket that has no transfer acceleration state. A bucket has no Transfer Acceleration state if a state has never been set on the bucket. </p> <p>For more information about transfer acceleration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//transfer-acceleration.html">Transfer Acceleration</a> in the Amazon Simple Storage Service Developer Guide.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>PutBucketAccelerateConfiguration</a> </p> </li> </ul></p>
+    /// Calls `get_bucket_accelerate_configuration`, also returning the metadata of the response.
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_accelerate_configuration(
+    pub async fn get_bucket_accelerate_configuration_with_metadata(
         &self,
         input: GetBucketAccelerateConfigurationRequest,
     ) -> Result<
-        GetBucketAccelerateConfigurationOutput,
+        (GetBucketAccelerateConfigurationOutput, ResponseMetadata),
         RusotoError<GetBucketAccelerateConfigurationError>,
     > {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put_key("accelerate");
-        request.set_params(params);
+        let request = self.build_get_bucket_accelerate_configuration_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketAccelerateConfigurationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -20709,34 +21717,62 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_accelerate_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_accelerate_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_accelerate_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>This implementation of the <code>GET</code> operation uses the <code>acl</code> subresource to return the access control list (ACL) of a bucket. To use <code>GET</code> to return the ACL of the bucket, you must have <code>READ<em>ACP</code> access to the bucket. If <code>READ</em>ACP</code> permission is granted to the anonymous user, you can return the ACL of the bucket without using an authorization header.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_acl(
+    fn build_get_bucket_accelerate_configuration_request(
         &self,
-        input: GetBucketAclRequest,
-    ) -> Result<GetBucketAclOutput, RusotoError<GetBucketAclError>> {
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAccelerateConfiguration");
 
         let mut params = Params::new();
-        params.put_key("acl");
+        params.put_key("accelerate");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_acl`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_acl_with_metadata(
+        &self,
+        input: GetBucketAclRequest,
+    ) -> Result<(GetBucketAclOutput, ResponseMetadata), RusotoError<GetBucketAclError>> {
+        let request = self.build_get_bucket_acl_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketAclError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -20752,40 +21788,64 @@ impl S3 for S3Client {
             result = GetBucketAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>This implementation of the GET operation returns an analytics configuration (identified by the analytics configuration ID) from the bucket.</p> <p>To use this operation, you must have permissions to perform the <code>s3:GetAnalyticsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources"> Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>For information about Amazon S3 analytics feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/analytics-storage-class.html">Amazon S3 Analytics – Storage Class Analysis</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> </p> </li> <li> <p> </p> </li> <li> <p> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_bucket_analytics_configuration(
+    /// Presigns the request `get_bucket_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_acl(
         &self,
-        input: GetBucketAnalyticsConfigurationRequest,
-    ) -> Result<
-        GetBucketAnalyticsConfigurationOutput,
-        RusotoError<GetBucketAnalyticsConfigurationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketAclRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_bucket_acl_request(&self, input: GetBucketAclRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAcl");
 
         let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("analytics");
+        params.put_key("acl");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_analytics_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_analytics_configuration_with_metadata(
+        &self,
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> Result<
+        (GetBucketAnalyticsConfigurationOutput, ResponseMetadata),
+        RusotoError<GetBucketAnalyticsConfigurationError>,
+    > {
+        let request = self.build_get_bucket_analytics_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketAnalyticsConfigurationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -20804,34 +21864,63 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_analytics_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_analytics_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_analytics_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns the cors configuration information set for the bucket.</p> <p> To use this operation, you must have permission to perform the s3:GetBucketCORS action. By default, the bucket owner has this permission and can grant it to others.</p> <p> For more information about cors, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cors.html"> Enabling Cross-Origin Resource Sharing</a>.</p> <p>The following operations are related to <code>GetBucketCors</code>:</p> <ul> <li> <p> <a>PutBucketCors</a> </p> </li> <li> <p> <a>DeleteBucketCors</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_cors(
+    fn build_get_bucket_analytics_configuration_request(
         &self,
-        input: GetBucketCorsRequest,
-    ) -> Result<GetBucketCorsOutput, RusotoError<GetBucketCorsError>> {
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAnalyticsConfiguration");
 
         let mut params = Params::new();
-        params.put_key("cors");
+        params.put("id", &input.id);
+        params.put_key("analytics");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_cors`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_cors_with_metadata(
+        &self,
+        input: GetBucketCorsRequest,
+    ) -> Result<(GetBucketCorsOutput, ResponseMetadata), RusotoError<GetBucketCorsError>> {
+        let request = self.build_get_bucket_cors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketCorsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -20847,34 +21936,60 @@ impl S3 for S3Client {
             result = GetBucketCorsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns the default encryption configuration for an Amazon S3 bucket. For information about the Amazon S3 default encryption feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/bucket-encryption.html">Amazon S3 Default Bucket Encryption</a>.</p> <p> To use this operation, you must have permission to perform the <code>s3:GetEncryptionConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>The following operations are related to <code>GetBucketEncryption</code>:</p> <ul> <li> <p> <a>PutBucketEncryption</a> </p> </li> <li> <p> <a>DeleteBucketEncryption</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_bucket_encryption(
+    /// Presigns the request `get_bucket_cors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_cors(
         &self,
-        input: GetBucketEncryptionRequest,
-    ) -> Result<GetBucketEncryptionOutput, RusotoError<GetBucketEncryptionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketCorsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_cors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_bucket_cors_request(&self, input: GetBucketCorsRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketCors");
 
         let mut params = Params::new();
-        params.put_key("encryption");
+        params.put_key("cors");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_encryption`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_encryption_with_metadata(
+        &self,
+        input: GetBucketEncryptionRequest,
+    ) -> Result<(GetBucketEncryptionOutput, ResponseMetadata), RusotoError<GetBucketEncryptionError>>
+    {
+        let request = self.build_get_bucket_encryption_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketEncryptionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -20891,40 +22006,67 @@ impl S3 for S3Client {
                 GetBucketEncryptionOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_encryption` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_encryption(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketEncryptionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_encryption_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns an inventory configuration (identified by the inventory configuration ID) from the bucket.</p> <p>To use this operation, you must have permissions to perform the <code>s3:GetInventoryConfiguration</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about the Amazon S3 inventory feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-inventory.html">Amazon S3 Inventory</a>.</p> <p>The following operations are related to <code>GetBucketInventoryConfiguration</code>:</p> <ul> <li> <p> <a>DeleteBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>ListBucketInventoryConfigurations</a> </p> </li> <li> <p> <a>PutBucketInventoryConfiguration</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_inventory_configuration(
+    fn build_get_bucket_encryption_request(
         &self,
-        input: GetBucketInventoryConfigurationRequest,
-    ) -> Result<
-        GetBucketInventoryConfigurationOutput,
-        RusotoError<GetBucketInventoryConfigurationError>,
-    > {
+        input: GetBucketEncryptionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketEncryption");
 
         let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("inventory");
+        params.put_key("encryption");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_inventory_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_inventory_configuration_with_metadata(
+        &self,
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> Result<
+        (GetBucketInventoryConfigurationOutput, ResponseMetadata),
+        RusotoError<GetBucketInventoryConfigurationError>,
+    > {
+        let request = self.build_get_bucket_inventory_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketInventoryConfigurationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -20943,34 +22085,64 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_inventory_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_inventory_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_inventory_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><important> <p>For an updated version of this API, see <a>GetBucketLifecycleConfiguration</a>. If you configured a bucket lifecycle using the <code>filter</code> element, you should see the updated version of this topic. This topic is provided for backward compatibility.</p> </important> <p>Returns the lifecycle configuration information set on the bucket. For information about lifecycle configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a>.</p> <p> To use this operation, you must have permission to perform the <code>s3:GetLifecycleConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> <code>GetBucketLifecycle</code> has the following special error:</p> <ul> <li> <p>Error code: <code>NoSuchLifecycleConfiguration</code> </p> <ul> <li> <p>Description: The lifecycle configuration does not exist.</p> </li> <li> <p>HTTP Status Code: 404 Not Found</p> </li> <li> <p>SOAP Fault Code Prefix: Client</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>GetBucketLifecycle</code>:</p> <ul> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>PutBucketLifecycle</a> </p> </li> <li> <p> <a>DeleteBucketLifecycle</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_lifecycle(
+    fn build_get_bucket_inventory_configuration_request(
         &self,
-        input: GetBucketLifecycleRequest,
-    ) -> Result<GetBucketLifecycleOutput, RusotoError<GetBucketLifecycleError>> {
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketInventoryConfiguration");
 
         let mut params = Params::new();
-        params.put_key("lifecycle");
+        params.put("id", &input.id);
+        params.put_key("inventory");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_lifecycle`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_lifecycle_with_metadata(
+        &self,
+        input: GetBucketLifecycleRequest,
+    ) -> Result<(GetBucketLifecycleOutput, ResponseMetadata), RusotoError<GetBucketLifecycleError>>
+    {
+        let request = self.build_get_bucket_lifecycle_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketLifecycleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -20987,39 +22159,67 @@ impl S3 for S3Client {
                 GetBucketLifecycleOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_lifecycle` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_lifecycle(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketLifecycleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_lifecycle_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><note> <p>Bucket lifecycle configuration now supports specifying a lifecycle rule using an object key name prefix, one or more object tags, or a combination of both. Accordingly, this section describes the latest API. The response describes the new filter element that you can use to specify a filter to select a subset of objects to which the rule applies. If you are still using previous version of the lifecycle configuration, it works. For the earlier API description, see <a>GetBucketLifecycle</a>.</p> </note> <p>Returns the lifecycle configuration information set on the bucket. For information about lifecycle configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a>.</p> <p>To use this operation, you must have permission to perform the <code>s3:GetLifecycleConfiguration</code> action. The bucket owner has this permission, by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> <code>GetBucketLifecycleConfiguration</code> has the following special error:</p> <ul> <li> <p>Error code: <code>NoSuchLifecycleConfiguration</code> </p> <ul> <li> <p>Description: The lifecycle configuration does not exist.</p> </li> <li> <p>HTTP Status Code: 404 Not Found</p> </li> <li> <p>SOAP Fault Code Prefix: Client</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>GetBucketLifecycle</a> </p> </li> <li> <p> <a>PutBucketLifecycle</a> </p> </li> <li> <p> <a>DeleteBucketLifecycle</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_lifecycle_configuration(
+    fn build_get_bucket_lifecycle_request(
         &self,
-        input: GetBucketLifecycleConfigurationRequest,
-    ) -> Result<
-        GetBucketLifecycleConfigurationOutput,
-        RusotoError<GetBucketLifecycleConfigurationError>,
-    > {
+        input: GetBucketLifecycleRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycle");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_lifecycle_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_lifecycle_configuration_with_metadata(
+        &self,
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> Result<
+        (GetBucketLifecycleConfigurationOutput, ResponseMetadata),
+        RusotoError<GetBucketLifecycleConfigurationError>,
+    > {
+        let request = self.build_get_bucket_lifecycle_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketLifecycleConfigurationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21038,34 +22238,63 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_lifecycle_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_lifecycle_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_lifecycle_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns the Region the bucket resides in. You set the bucket&#39;s Region using the <code>LocationConstraint</code> request parameter in a <code>CreateBucket</code> request. For more information, see <a>CreateBucket</a>.</p> <p> To use this implementation of the operation, you must be the bucket owner.</p> <p>The following operations are related to <code>GetBucketLocation</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_location(
+    fn build_get_bucket_lifecycle_configuration_request(
         &self,
-        input: GetBucketLocationRequest,
-    ) -> Result<GetBucketLocationOutput, RusotoError<GetBucketLocationError>> {
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycleConfiguration");
 
         let mut params = Params::new();
-        params.put_key("location");
+        params.put_key("lifecycle");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_location`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_location_with_metadata(
+        &self,
+        input: GetBucketLocationRequest,
+    ) -> Result<(GetBucketLocationOutput, ResponseMetadata), RusotoError<GetBucketLocationError>>
+    {
+        let request = self.build_get_bucket_location_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketLocationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21082,34 +22311,60 @@ impl S3 for S3Client {
                 GetBucketLocationOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns the logging status of a bucket and the permissions users have to view and modify that status. To use GET, you must be the bucket owner.</p> <p>The following operations are related to <code>GetBucketLogging</code>:</p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>PutBucketLogging</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_bucket_logging(
+    /// Presigns the request `get_bucket_location` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_location(
         &self,
-        input: GetBucketLoggingRequest,
-    ) -> Result<GetBucketLoggingOutput, RusotoError<GetBucketLoggingError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketLocationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_location_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_bucket_location_request(&self, input: GetBucketLocationRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLocation");
 
         let mut params = Params::new();
-        params.put_key("logging");
+        params.put_key("location");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_logging`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_logging_with_metadata(
+        &self,
+        input: GetBucketLoggingRequest,
+    ) -> Result<(GetBucketLoggingOutput, ResponseMetadata), RusotoError<GetBucketLoggingError>>
+    {
+        let request = self.build_get_bucket_logging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketLoggingError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21125,36 +22380,62 @@ impl S3 for S3Client {
             result = GetBucketLoggingOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Gets a metrics configuration (specified by the metrics configuration ID) from the bucket. Note that this doesn&#39;t include the daily storage metrics.</p> <p> To use this operation, you must have permissions to perform the <code>s3:GetMetricsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> For information about CloudWatch request metrics for Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a>.</p> <p>The following operations are related to <code>GetBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>PutBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>ListBucketMetricsConfigurations</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_bucket_metrics_configuration(
+    /// Presigns the request `get_bucket_logging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_logging(
         &self,
-        input: GetBucketMetricsConfigurationRequest,
-    ) -> Result<GetBucketMetricsConfigurationOutput, RusotoError<GetBucketMetricsConfigurationError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketLoggingRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_logging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_bucket_logging_request(&self, input: GetBucketLoggingRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLogging");
 
         let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("metrics");
+        params.put_key("logging");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_metrics_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_metrics_configuration_with_metadata(
+        &self,
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> Result<
+        (GetBucketMetricsConfigurationOutput, ResponseMetadata),
+        RusotoError<GetBucketMetricsConfigurationError>,
+    > {
+        let request = self.build_get_bucket_metrics_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketMetricsConfigurationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21173,34 +22454,66 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_metrics_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_metrics_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_metrics_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p> No longer used, see <a>GetBucketNotificationConfiguration</a>.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_notification(
+    fn build_get_bucket_metrics_configuration_request(
         &self,
-        input: GetBucketNotificationConfigurationRequest,
-    ) -> Result<NotificationConfigurationDeprecated, RusotoError<GetBucketNotificationError>> {
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketMetricsConfiguration");
 
         let mut params = Params::new();
-        params.put_key("notification");
+        params.put("id", &input.id);
+        params.put_key("metrics");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_notification`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_notification_with_metadata(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<
+        (NotificationConfigurationDeprecated, ResponseMetadata),
+        RusotoError<GetBucketNotificationError>,
+    > {
+        let request = self.build_get_bucket_notification_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketNotificationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21219,37 +22532,67 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_notification` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_notification(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_notification_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns the notification configuration of a bucket.</p> <p>If notifications are not enabled on the bucket, the operation returns an empty <code>NotificationConfiguration</code> element.</p> <p>By default, you must be the bucket owner to read the notification configuration of a bucket. However, the bucket owner can use a bucket policy to grant permission to other users to read this configuration with the <code>s3:GetBucketNotification</code> permission.</p> <p>For more information about setting and reading the notification configuration on a bucket, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Setting Up Notification of Bucket Events</a>. For more information about bucket policies, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies</a>.</p> <p>The following operation is related to <code>GetBucketNotification</code>:</p> <ul> <li> <p> <a>PutBucketNotification</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_notification_configuration(
+    fn build_get_bucket_notification_request(
         &self,
         input: GetBucketNotificationConfigurationRequest,
-    ) -> Result<NotificationConfiguration, RusotoError<GetBucketNotificationConfigurationError>>
-    {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotification");
 
         let mut params = Params::new();
         params.put_key("notification");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_notification_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_notification_configuration_with_metadata(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<
+        (NotificationConfiguration, ResponseMetadata),
+        RusotoError<GetBucketNotificationConfigurationError>,
+    > {
+        let request = self.build_get_bucket_notification_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketNotificationConfigurationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21266,65 +22609,121 @@ impl S3 for S3Client {
                 NotificationConfigurationDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_notification_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_notification_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_notification_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns the policy of a specified bucket. If you are using an identity other than the root user of the AWS account that owns the bucket, the calling identity must have the <code>GetBucketPolicy</code> permissions on the specified bucket and belong to the bucket owner&#39;s account in order to use this operation.</p> <p>If you don&#39;t have <code>GetBucketPolicy</code> permissions, Amazon S3 returns a <code>403 Access Denied</code> error. If you have the correct permissions, but you&#39;re not using an identity that belongs to the bucket owner&#39;s account, Amazon S3 returns a <code>405 Method Not Allowed</code> error.</p> <important> <p>As a security precaution, the root user of the AWS account that owns a bucket can always use this operation, even if the policy explicitly denies the root user the ability to perform this action.</p> </important> <p>For more information about bucket policies, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies and User Policies</a>.</p> <p>The following operation is related to <code>GetBucketPolicy</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_policy(
+    fn build_get_bucket_notification_configuration_request(
         &self,
-        input: GetBucketPolicyRequest,
-    ) -> Result<GetBucketPolicyOutput, RusotoError<GetBucketPolicyError>> {
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotificationConfiguration");
 
         let mut params = Params::new();
-        params.put_key("policy");
+        params.put_key("notification");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_policy`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_policy_with_metadata(
+        &self,
+        input: GetBucketPolicyRequest,
+    ) -> Result<(GetBucketPolicyOutput, ResponseMetadata), RusotoError<GetBucketPolicyError>> {
+        let request = self.build_get_bucket_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketPolicyError::from_response(response));
         }
 
-        let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result = GetBucketPolicyOutput::default();
         result.policy = Some(String::from_utf8_lossy(response.body.as_ref()).into());
 
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Retrieves the policy status for an Amazon S3 bucket, indicating whether the bucket is public. In order to use this operation, you must have the <code>s3:GetBucketPolicyStatus</code> permission. For more information about Amazon S3 permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>.</p> <p> For more information about when Amazon S3 considers a bucket public, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html#access-control-block-public-access-policy-status">The Meaning of &quot;Public&quot;</a>. </p> <p>The following operations are related to <code>GetBucketPolicyStatus</code>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html">Using Amazon S3 Block Public Access</a> </p> </li> <li> <p> <a>GetPublicAccessBlock</a> </p> </li> <li> <p> <a>PutPublicAccessBlock</a> </p> </li> <li> <p> <a>DeletePublicAccessBlock</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_bucket_policy_status(
+    /// Presigns the request `get_bucket_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_policy(
         &self,
-        input: GetBucketPolicyStatusRequest,
-    ) -> Result<GetBucketPolicyStatusOutput, RusotoError<GetBucketPolicyStatusError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketPolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_bucket_policy_request(&self, input: GetBucketPolicyRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicy");
 
         let mut params = Params::new();
-        params.put_key("policyStatus");
+        params.put_key("policy");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_policy_status`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_policy_status_with_metadata(
+        &self,
+        input: GetBucketPolicyStatusRequest,
+    ) -> Result<
+        (GetBucketPolicyStatusOutput, ResponseMetadata),
+        RusotoError<GetBucketPolicyStatusError>,
+    > {
+        let request = self.build_get_bucket_policy_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketPolicyStatusError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21341,34 +22740,65 @@ impl S3 for S3Client {
                 GetBucketPolicyStatusOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_policy_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_policy_status(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketPolicyStatusRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_policy_status_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns the replication configuration of a bucket.</p> <note> <p> It can take a while to propagate the put or delete a replication configuration to all Amazon S3 systems. Therefore, a get request soon after put or delete can return a wrong result. </p> </note> <p> For information about replication configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/replication.html">Replication</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>This operation requires permissions for the <code>s3:GetReplicationConfiguration</code> action. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies and User Policies</a>.</p> <p>If you include the <code>Filter</code> element in a replication configuration, you must also include the <code>DeleteMarkerReplication</code> and <code>Priority</code> elements. The response also returns those elements.</p> <p>For information about <code>GetBucketReplication</code> errors, see <a>ReplicationErrorCodeList</a> </p> <p>The following operations are related to <code>GetBucketReplication</code>:</p> <ul> <li> <p> <a>PutBucketReplication</a> </p> </li> <li> <p> <a>DeleteBucketReplication</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_replication(
+    fn build_get_bucket_policy_status_request(
         &self,
-        input: GetBucketReplicationRequest,
-    ) -> Result<GetBucketReplicationOutput, RusotoError<GetBucketReplicationError>> {
+        input: GetBucketPolicyStatusRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicyStatus");
 
         let mut params = Params::new();
-        params.put_key("replication");
+        params.put_key("policyStatus");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_replication`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_replication_with_metadata(
+        &self,
+        input: GetBucketReplicationRequest,
+    ) -> Result<
+        (GetBucketReplicationOutput, ResponseMetadata),
+        RusotoError<GetBucketReplicationError>,
+    > {
+        let request = self.build_get_bucket_replication_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketReplicationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21385,34 +22815,65 @@ impl S3 for S3Client {
                 GetBucketReplicationOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_replication` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_replication(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketReplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_replication_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns the request payment configuration of a bucket. To use this version of the operation, you must be the bucket owner. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/RequesterPaysBuckets.html">Requester Pays Buckets</a>.</p> <p>The following operations are related to <code>GetBucketRequestPayment</code>:</p> <ul> <li> <p> <a>ListObjects</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_request_payment(
+    fn build_get_bucket_replication_request(
         &self,
-        input: GetBucketRequestPaymentRequest,
-    ) -> Result<GetBucketRequestPaymentOutput, RusotoError<GetBucketRequestPaymentError>> {
+        input: GetBucketReplicationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketReplication");
 
         let mut params = Params::new();
-        params.put_key("requestPayment");
+        params.put_key("replication");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_request_payment`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_request_payment_with_metadata(
+        &self,
+        input: GetBucketRequestPaymentRequest,
+    ) -> Result<
+        (GetBucketRequestPaymentOutput, ResponseMetadata),
+        RusotoError<GetBucketRequestPaymentError>,
+    > {
+        let request = self.build_get_bucket_request_payment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketRequestPaymentError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21431,34 +22892,63 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_request_payment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_request_payment(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketRequestPaymentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_request_payment_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns the tag set associated with the bucket.</p> <p>To use this operation, you must have permission to perform the <code>s3:GetBucketTagging</code> action. By default, the bucket owner has this permission and can grant this permission to others.</p> <p> <code>GetBucketTagging</code> has the following special error:</p> <ul> <li> <p>Error code: <code>NoSuchTagSetError</code> </p> <ul> <li> <p>Description: There is no tag set associated with the bucket.</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>GetBucketTagging</code>:</p> <ul> <li> <p> <a>PutBucketTagging</a> </p> </li> <li> <p> <a>DeleteBucketTagging</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_tagging(
+    fn build_get_bucket_request_payment_request(
         &self,
-        input: GetBucketTaggingRequest,
-    ) -> Result<GetBucketTaggingOutput, RusotoError<GetBucketTaggingError>> {
+        input: GetBucketRequestPaymentRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketRequestPayment");
 
         let mut params = Params::new();
-        params.put_key("tagging");
+        params.put_key("requestPayment");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_tagging`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_tagging_with_metadata(
+        &self,
+        input: GetBucketTaggingRequest,
+    ) -> Result<(GetBucketTaggingOutput, ResponseMetadata), RusotoError<GetBucketTaggingError>>
+    {
+        let request = self.build_get_bucket_tagging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketTaggingError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21474,34 +22964,60 @@ impl S3 for S3Client {
             result = GetBucketTaggingOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns the versioning state of a bucket.</p> <p>To retrieve the versioning state of a bucket, you must be the bucket owner.</p> <p>This implementation also returns the MFA Delete status of the versioning state. If the MFA Delete status is <code>enabled</code>, the bucket owner must use an authentication device to change the versioning state of the bucket.</p> <p>The following operations are related to <code>GetBucketVersioning</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_bucket_versioning(
+    /// Presigns the request `get_bucket_tagging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_tagging(
         &self,
-        input: GetBucketVersioningRequest,
-    ) -> Result<GetBucketVersioningOutput, RusotoError<GetBucketVersioningError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketTaggingRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_tagging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_bucket_tagging_request(&self, input: GetBucketTaggingRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketTagging");
 
         let mut params = Params::new();
-        params.put_key("versioning");
+        params.put_key("tagging");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_versioning`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_versioning_with_metadata(
+        &self,
+        input: GetBucketVersioningRequest,
+    ) -> Result<(GetBucketVersioningOutput, ResponseMetadata), RusotoError<GetBucketVersioningError>>
+    {
+        let request = self.build_get_bucket_versioning_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketVersioningError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21518,34 +23034,63 @@ impl S3 for S3Client {
                 GetBucketVersioningOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_bucket_versioning` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_versioning(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketVersioningRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_versioning_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns the website configuration for a bucket. To host website on Amazon S3, you can configure a bucket as website by adding a website configuration. For more information about hosting websites, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/WebsiteHosting.html">Hosting Websites on Amazon S3</a>. </p> <p>This GET operation requires the <code>S3:GetBucketWebsite</code> permission. By default, only the bucket owner can read the bucket website configuration. However, bucket owners can allow other users to read the website configuration by writing a bucket policy granting them the <code>S3:GetBucketWebsite</code> permission.</p> <p>The following operations are related to <code>DeleteBucketWebsite</code>:</p> <ul> <li> <p> <a>DeleteBucketWebsite</a> </p> </li> <li> <p> <a>PutBucketWebsite</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_bucket_website(
+    fn build_get_bucket_versioning_request(
         &self,
-        input: GetBucketWebsiteRequest,
-    ) -> Result<GetBucketWebsiteOutput, RusotoError<GetBucketWebsiteError>> {
+        input: GetBucketVersioningRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketVersioning");
 
         let mut params = Params::new();
-        params.put_key("website");
+        params.put_key("versioning");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_bucket_website`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_bucket_website_with_metadata(
+        &self,
+        input: GetBucketWebsiteRequest,
+    ) -> Result<(GetBucketWebsiteOutput, ResponseMetadata), RusotoError<GetBucketWebsiteError>>
+    {
+        let request = self.build_get_bucket_website_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetBucketWebsiteError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21561,97 +23106,52 @@ impl S3 for S3Client {
             result = GetBucketWebsiteOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Retrieves objects from Amazon S3. To use <code>GET</code>, you must have <code>READ</code> access to the object. If you grant <code>READ</code> access to the anonymous user, you can return the object without using an authorization header.</p> <p>An Amazon S3 bucket has no directory hierarchy such as you would find in a typical computer file system. You can, however, create a logical hierarchy by using object key names that imply a folder structure. For example, instead of naming an object <code>sample.jpg</code>, you can name it <code>photos/2006/February/sample.jpg</code>.</p> <p>To get an object from such a logical hierarchy, specify the full key name for the object in the <code>GET</code> operation. For a virtual hosted-style request example, if you have the object <code>photos/2006/February/sample.jpg</code>, specify the resource as <code>/photos/2006/February/sample.jpg</code>. For a path-style request example, if you have the object <code>photos/2006/February/sample.jpg</code> in the bucket named <code>examplebucket</code>, specify the resource as <code>/examplebucket/photos/2006/February/sample.jpg</code>. For more information about request types, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/VirtualHosting.html#VirtualHostingSpecifyBucket">HTTP Host Header Bucket Specification</a>.</p> <p>To distribute large files to many people, you can save bandwidth costs by using BitTorrent. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3Torrent.html">Amazon S3 Torrent</a>. For more information about returning the ACL of an object, see <a>GetObjectAcl</a>.</p> <p>If the object you are retrieving is stored in the GLACIER or DEEP_ARCHIVE storage classes, before you can retrieve the object you must first restore a copy using . Otherwise, this operation returns an <code>InvalidObjectStateError</code> error. For information about restoring archived objects, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/restoring-objects.html">Restoring Archived Objects</a>.</p> <p>Encryption request headers, like <code>x-amz-server-side-encryption</code>, should not be sent for GET requests if your object uses server-side encryption with CMKs stored in AWS KMS (SSE-KMS) or server-side encryption with Amazon S3–managed encryption keys (SSE-S3). If your object does use these types of keys, you’ll get an HTTP 400 BadRequest error.</p> <p>If you encrypt an object by using server-side encryption with customer-provided encryption keys (SSE-C) when you store the object in Amazon S3, then when you GET the object, you must use the following headers:</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about SSE-C, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ServerSideEncryptionCustomerKeys.html">Server-Side Encryption (Using Customer-Provided Encryption Keys)</a>.</p> <p>Assuming you have permission to read object tags (permission for the <code>s3:GetObjectVersionTagging</code> action), the response also returns the <code>x-amz-tagging-count</code> header that provides the count of number of tags associated with the object. You can use <a>GetObjectTagging</a> to retrieve the tag set associated with an object.</p> <p> <b>Permissions</b> </p> <p>You need the <code>s3:GetObject</code> permission for this operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>. If the object you request does not exist, the error Amazon S3 returns depends on whether you also have the <code>s3:ListBucket</code> permission.</p> <ul> <li> <p>If you have the <code>s3:ListBucket</code> permission on the bucket, Amazon S3 will return an HTTP status code 404 (&quot;no such key&quot;) error.</p> </li> <li> <p>If you don’t have the <code>s3:ListBucket</code> permission, Amazon S3 will return an HTTP status code 403 (&quot;access denied&quot;) error.</p> </li> </ul> <p> <b>Versioning</b> </p> <p>By default, the GET operation returns the current version of an object. To return a different version, use the <code>versionId</code> subresource.</p> <note> <p>If the current version of the object is a delete marker, Amazon S3 behaves as if the object was deleted and includes <code>x-amz-delete-marker: true</code> in the response.</p> </note> <p>For more information about versioning, see <a>PutBucketVersioning</a>. </p> <p> <b>Overriding Response Header Values</b> </p> <p>There are times when you want to override certain response header values in a GET response. For example, you might override the Content-Disposition response header value in your GET request.</p> <p>You can override values for a set of response headers using the following query parameters. These response header values are sent only on a successful request, that is, when status code 200 OK is returned. The set of headers you can override using these parameters is a subset of the headers that Amazon S3 accepts when you create an object. The response headers that you can override for the GET response are <code>Content-Type</code>, <code>Content-Language</code>, <code>Expires</code>, <code>Cache-Control</code>, <code>Content-Disposition</code>, and <code>Content-Encoding</code>. To override these header values in the GET response, you use the following request parameters.</p> <note> <p>You must sign the request, either using an Authorization header or a presigned URL, when using these parameters. They cannot be used with an unsigned (anonymous) request.</p> </note> <ul> <li> <p> <code>response-content-type</code> </p> </li> <li> <p> <code>response-content-language</code> </p> </li> <li> <p> <code>response-expires</code> </p> </li> <li> <p> <code>response-cache-control</code> </p> </li> <li> <p> <code>response-content-disposition</code> </p> </li> <li> <p> <code>response-content-encoding</code> </p> </li> </ul> <p> <b>Additional Considerations about Request Headers</b> </p> <p>If both of the <code>If-Match</code> and <code>If-Unmodified-Since</code> headers are present in the request as follows: <code>If-Match</code> condition evaluates to <code>true</code>, and; <code>If-Unmodified-Since</code> condition evaluates to <code>false</code>; then, S3 returns 200 OK and the data requested. </p> <p>If both of the <code>If-None-Match</code> and <code>If-Modified-Since</code> headers are present in the request as follows:<code> If-None-Match</code> condition evaluates to <code>false</code>, and; <code>If-Modified-Since</code> condition evaluates to <code>true</code>; then, S3 returns 304 Not Modified response code.</p> <p>For more information about conditional requests, see <a href="https://tools.ietf.org/html/rfc7232">RFC 7232</a>.</p> <p>The following operations are related to <code>GetObject</code>:</p> <ul> <li> <p> <a>ListBuckets</a> </p> </li> <li> <p> <a>GetObjectAcl</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_object(
+    /// Presigns the request `get_bucket_website` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bucket_website(
         &self,
-        input: GetObjectRequest,
-    ) -> Result<GetObjectOutput, RusotoError<GetObjectError>> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        if let Some(ref if_modified_since) = input.if_modified_since {
-            request.add_header("If-Modified-Since", &if_modified_since.to_string());
-        }
-
-        if let Some(ref if_none_match) = input.if_none_match {
-            request.add_header("If-None-Match", &if_none_match.to_string());
-        }
-
-        if let Some(ref if_unmodified_since) = input.if_unmodified_since {
-            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
-        }
-
-        if let Some(ref range) = input.range {
-            request.add_header("Range", &range.to_string());
-        }
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
+        presigner: &rusoto_core::Presigner,
+        input: GetBucketWebsiteRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bucket_website_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-algorithm",
-                &sse_customer_algorithm.to_string(),
-            );
-        }
+    #[allow(unused_variables, warnings)]
+    fn build_get_bucket_website_request(&self, input: GetBucketWebsiteRequest) -> SignedRequest {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref sse_customer_key) = input.sse_customer_key {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key",
-                &sse_customer_key.to_string(),
-            );
-        }
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketWebsite");
 
-        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key-MD5",
-                &sse_customer_key_md5.to_string(),
-            );
-        }
         let mut params = Params::new();
-        if let Some(ref x) = input.part_number {
-            params.put("partNumber", x);
-        }
-        if let Some(ref x) = input.response_cache_control {
-            params.put("response-cache-control", x);
-        }
-        if let Some(ref x) = input.response_content_disposition {
-            params.put("response-content-disposition", x);
-        }
-        if let Some(ref x) = input.response_content_encoding {
-            params.put("response-content-encoding", x);
-        }
-        if let Some(ref x) = input.response_content_language {
-            params.put("response-content-language", x);
-        }
-        if let Some(ref x) = input.response_content_type {
-            params.put("response-content-type", x);
-        }
-        if let Some(ref x) = input.response_expires {
-            params.put("response-expires", x);
-        }
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
+        params.put_key("website");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_object`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_object_with_metadata(
+        &self,
+        input: GetObjectRequest,
+    ) -> Result<(GetObjectOutput, ResponseMetadata), RusotoError<GetObjectError>> {
+        let request = self.build_get_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetObjectError::from_response(response));
         }
 
@@ -21798,92 +23298,204 @@ impl S3 for S3Client {
             let value = website_redirect_location.to_owned();
             result.website_redirect_location = Some(value)
         };
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns the access control list (ACL) of an object. To use this operation, you must have READ_ACP access to the object.</p> <p> <b>Versioning</b> </p> <p>By default, GET returns ACL information about the current version of an object. To return ACL information about a different version, use the versionId subresource.</p> <p>The following operations are related to <code>GetObjectAcl</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_object_acl(
+    /// Presigns the request `get_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_object(
         &self,
-        input: GetObjectAclRequest,
-    ) -> Result<GetObjectAclOutput, RusotoError<GetObjectAclError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetObjectRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_object_request(&self, input: GetObjectRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObject");
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
         }
-        params.put_key("acl");
-        request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(GetObjectAclError::from_response(response));
+        if let Some(ref if_modified_since) = input.if_modified_since {
+            request.add_header("If-Modified-Since", &if_modified_since.to_string());
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-        let mut result;
-
-        if xml_response.body.is_empty() {
-            result = GetObjectAclOutput::default();
-        } else {
-            let reader = EventReader::new_with_config(
-                xml_response.body.as_ref(),
-                ParserConfig::new().trim_whitespace(false),
-            );
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            let _start_document = stack.next();
-            let actual_tag_name = peek_at_name(&mut stack)?;
-            result = GetObjectAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+        if let Some(ref if_none_match) = input.if_none_match {
+            request.add_header("If-None-Match", &if_none_match.to_string());
         }
-        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-            let value = request_charged.to_owned();
-            result.request_charged = Some(value)
-        }; // parse non-payload
-        Ok(result)
-    }
 
-    /// <p>Gets an object's current Legal Hold status. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a>.</p>
-    #[allow(unused_variables, warnings)]
-    async fn get_object_legal_hold(
-        &self,
-        input: GetObjectLegalHoldRequest,
-    ) -> Result<GetObjectLegalHoldOutput, RusotoError<GetObjectLegalHoldError>> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+        if let Some(ref if_unmodified_since) = input.if_unmodified_since {
+            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
+        }
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        if let Some(ref range) = input.range {
+            request.add_header("Range", &range.to_string());
+        }
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
         }
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
-        params.put_key("legal-hold");
-        request.set_params(params);
 
-        let mut response = self
+        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-algorithm",
+                &sse_customer_algorithm.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key) = input.sse_customer_key {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key",
+                &sse_customer_key.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key-MD5",
+                &sse_customer_key_md5.to_string(),
+            );
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.part_number {
+            params.put("partNumber", x);
+        }
+        if let Some(ref x) = input.response_cache_control {
+            params.put("response-cache-control", x);
+        }
+        if let Some(ref x) = input.response_content_disposition {
+            params.put("response-content-disposition", x);
+        }
+        if let Some(ref x) = input.response_content_encoding {
+            params.put("response-content-encoding", x);
+        }
+        if let Some(ref x) = input.response_content_language {
+            params.put("response-content-language", x);
+        }
+        if let Some(ref x) = input.response_content_type {
+            params.put("response-content-type", x);
+        }
+        if let Some(ref x) = input.response_expires {
+            params.put("response-expires", x);
+        }
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `get_object_acl`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_object_acl_with_metadata(
+        &self,
+        input: GetObjectAclRequest,
+    ) -> Result<(GetObjectAclOutput, ResponseMetadata), RusotoError<GetObjectAclError>> {
+        let request = self.build_get_object_acl_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if !response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(GetObjectAclError::from_response(response));
+        }
+
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
+        let mut result;
+
+        if xml_response.body.is_empty() {
+            result = GetObjectAclOutput::default();
+        } else {
+            let reader = EventReader::new_with_config(
+                xml_response.body.as_ref(),
+                ParserConfig::new().trim_whitespace(false),
+            );
+            let mut stack = XmlResponse::new(reader.into_iter().peekable());
+            let _start_document = stack.next();
+            let actual_tag_name = peek_at_name(&mut stack)?;
+            result = GetObjectAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+        }
+        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+            let value = request_charged.to_owned();
+            result.request_charged = Some(value)
+        }; // parse non-payload
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_object_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_object_acl(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetObjectAclRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_object_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_object_acl_request(&self, input: GetObjectAclRequest) -> SignedRequest {
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectAcl");
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("acl");
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `get_object_legal_hold`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_object_legal_hold_with_metadata(
+        &self,
+        input: GetObjectLegalHoldRequest,
+    ) -> Result<(GetObjectLegalHoldOutput, ResponseMetadata), RusotoError<GetObjectLegalHoldError>>
+    {
+        let request = self.build_get_object_legal_hold_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetObjectLegalHoldError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21900,35 +23512,71 @@ impl S3 for S3Client {
                 GetObjectLegalHoldOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_object_legal_hold` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_object_legal_hold(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetObjectLegalHoldRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_object_legal_hold_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets the Object Lock configuration for a bucket. The rule specified in the Object Lock configuration will be applied by default to every new object placed in the specified bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a>.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_object_lock_configuration(
+    fn build_get_object_legal_hold_request(
         &self,
-        input: GetObjectLockConfigurationRequest,
-    ) -> Result<GetObjectLockConfigurationOutput, RusotoError<GetObjectLockConfigurationError>>
-    {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: GetObjectLegalHoldRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLegalHold");
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("object-lock");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("legal-hold");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_object_lock_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_object_lock_configuration_with_metadata(
+        &self,
+        input: GetObjectLockConfigurationRequest,
+    ) -> Result<
+        (GetObjectLockConfigurationOutput, ResponseMetadata),
+        RusotoError<GetObjectLockConfigurationError>,
+    > {
+        let request = self.build_get_object_lock_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetObjectLockConfigurationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21947,40 +23595,63 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_object_lock_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_object_lock_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetObjectLockConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_object_lock_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Retrieves an object's retention settings. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a>.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_object_retention(
+    fn build_get_object_lock_configuration_request(
         &self,
-        input: GetObjectRetentionRequest,
-    ) -> Result<GetObjectRetentionOutput, RusotoError<GetObjectRetentionError>> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+        input: GetObjectLockConfigurationRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLockConfiguration");
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
         let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
-        params.put_key("retention");
+        params.put_key("object-lock");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_object_retention`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_object_retention_with_metadata(
+        &self,
+        input: GetObjectRetentionRequest,
+    ) -> Result<(GetObjectRetentionOutput, ResponseMetadata), RusotoError<GetObjectRetentionError>>
+    {
+        let request = self.build_get_object_retention_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetObjectRetentionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -21997,37 +23668,69 @@ impl S3 for S3Client {
                 GetObjectRetentionOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_object_retention` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_object_retention(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetObjectRetentionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_object_retention_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns the tag-set of an object. You send the GET request against the tagging subresource associated with the object.</p> <p>To use this operation, you must have permission to perform the <code>s3:GetObjectTagging</code> action. By default, the GET operation returns information about current version of an object. For a versioned bucket, you can have multiple versions of an object in your bucket. To retrieve tags of any other version, use the versionId query parameter. You also need permission for the <code>s3:GetObjectVersionTagging</code> action.</p> <p> By default, the bucket owner has this permission and can grant this permission to others.</p> <p> For information about the Amazon S3 object tagging feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-tagging.html">Object Tagging</a>.</p> <p>The following operation is related to <code>GetObjectTagging</code>:</p> <ul> <li> <p> <a>PutObjectTagging</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn get_object_tagging(
+    fn build_get_object_retention_request(
         &self,
-        input: GetObjectTaggingRequest,
-    ) -> Result<GetObjectTaggingOutput, RusotoError<GetObjectTaggingError>> {
+        input: GetObjectRetentionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectRetention");
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
         if let Some(ref x) = input.version_id {
             params.put("versionId", x);
         }
-        params.put_key("tagging");
+        params.put_key("retention");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_object_tagging`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_object_tagging_with_metadata(
+        &self,
+        input: GetObjectTaggingRequest,
+    ) -> Result<(GetObjectTaggingOutput, ResponseMetadata), RusotoError<GetObjectTaggingError>>
+    {
+        let request = self.build_get_object_tagging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetObjectTaggingError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22046,33 +23749,56 @@ impl S3 for S3Client {
             let value = version_id.to_owned();
             result.version_id = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Return torrent files from a bucket. BitTorrent can save you bandwidth when you&#39;re distributing large files. For more information about BitTorrent, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3Torrent.html">Amazon S3 Torrent</a>.</p> <note> <p>You can get torrent only for objects that are less than 5 GB in size and that are not encrypted using server-side encryption with customer-provided encryption key.</p> </note> <p>To use GET, you must have READ access to the object.</p> <p>The following operation is related to <code>GetObjectTorrent</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_object_torrent(
+    /// Presigns the request `get_object_tagging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_object_tagging(
         &self,
-        input: GetObjectTorrentRequest,
-    ) -> Result<GetObjectTorrentOutput, RusotoError<GetObjectTorrentError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetObjectTaggingRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_object_tagging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_object_tagging_request(&self, input: GetObjectTaggingRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTagging");
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
         let mut params = Params::new();
-        params.put_key("torrent");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("tagging");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_object_torrent`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_object_torrent_with_metadata(
+        &self,
+        input: GetObjectTorrentRequest,
+    ) -> Result<(GetObjectTorrentOutput, ResponseMetadata), RusotoError<GetObjectTorrentError>>
+    {
+        let request = self.build_get_object_torrent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetObjectTorrentError::from_response(response));
         }
 
@@ -22082,34 +23808,65 @@ impl S3 for S3Client {
             let value = request_charged.to_owned();
             result.request_charged = Some(value)
         };
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Retrieves the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket. To use this operation, you must have the <code>s3:GetBucketPublicAccessBlock</code> permission. For more information about Amazon S3 permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>.</p> <important> <p>When Amazon S3 evaluates the <code>PublicAccessBlock</code> configuration for a bucket or an object, it checks the <code>PublicAccessBlock</code> configuration for both the bucket (or the bucket that contains the object) and the bucket owner&#39;s account. If the <code>PublicAccessBlock</code> settings are different between the bucket and the account, Amazon S3 uses the most restrictive combination of the bucket-level and account-level settings.</p> </important> <p>For more information about when Amazon S3 considers a bucket or an object public, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html#access-control-block-public-access-policy-status">The Meaning of &quot;Public&quot;</a>.</p> <p>The following operations are related to <code>GetPublicAccessBlock</code>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html">Using Amazon S3 Block Public Access</a> </p> </li> <li> <p> <a>PutPublicAccessBlock</a> </p> </li> <li> <p> <a>GetPublicAccessBlock</a> </p> </li> <li> <p> <a>DeletePublicAccessBlock</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn get_public_access_block(
+    /// Presigns the request `get_object_torrent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_object_torrent(
         &self,
-        input: GetPublicAccessBlockRequest,
-    ) -> Result<GetPublicAccessBlockOutput, RusotoError<GetPublicAccessBlockError>> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        presigner: &rusoto_core::Presigner,
+        input: GetObjectTorrentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_object_torrent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_object_torrent_request(&self, input: GetObjectTorrentRequest) -> SignedRequest {
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTorrent");
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("publicAccessBlock");
+        params.put_key("torrent");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_public_access_block`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_public_access_block_with_metadata(
+        &self,
+        input: GetPublicAccessBlockRequest,
+    ) -> Result<
+        (GetPublicAccessBlockOutput, ResponseMetadata),
+        RusotoError<GetPublicAccessBlockError>,
+    > {
+        let request = self.build_get_public_access_block_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetPublicAccessBlockError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22126,107 +23883,108 @@ impl S3 for S3Client {
                 GetPublicAccessBlockOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_public_access_block` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_public_access_block(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetPublicAccessBlockRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_public_access_block_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>This operation is useful to determine if a bucket exists and you have permission to access it. The operation returns a <code>200 OK</code> if the bucket exists and you have permission to access it. Otherwise, the operation might return responses such as <code>404 Not Found</code> and <code>403 Forbidden</code>. </p> <p>To use this operation, you must have permissions to perform the <code>s3:ListBucket</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p>
     #[allow(unused_variables, warnings)]
-    async fn head_bucket(
+    fn build_get_public_access_block_request(
         &self,
-        input: HeadBucketRequest,
-    ) -> Result<(), RusotoError<HeadBucketError>> {
+        input: GetPublicAccessBlockRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetPublicAccessBlock");
 
-        let mut response = self
+        let mut params = Params::new();
+        params.put_key("publicAccessBlock");
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `head_bucket`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn head_bucket_with_metadata(
+        &self,
+        input: HeadBucketRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<HeadBucketError>> {
+        let request = self.build_head_bucket_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(HeadBucketError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>The HEAD operation retrieves metadata from an object without returning the object itself. This operation is useful if you&#39;re only interested in an object&#39;s metadata. To use HEAD, you must have READ access to the object.</p> <p>A <code>HEAD</code> request has the same options as a <code>GET</code> operation on an object. The response is identical to the <code>GET</code> response except that there is no response body.</p> <p>If you encrypt an object by using server-side encryption with customer-provided encryption keys (SSE-C) when you store the object in Amazon S3, then when you retrieve the metadata from the object, you must use the following headers:</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about SSE-C, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ServerSideEncryptionCustomerKeys.html">Server-Side Encryption (Using Customer-Provided Encryption Keys)</a>.</p> <note> <p>Encryption request headers, like <code>x-amz-server-side-encryption</code>, should not be sent for GET requests if your object uses server-side encryption with CMKs stored in AWS KMS (SSE-KMS) or server-side encryption with Amazon S3–managed encryption keys (SSE-S3). If your object does use these types of keys, you’ll get an HTTP 400 BadRequest error.</p> </note> <p>Request headers are limited to 8 KB in size. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTCommonRequestHeaders.html">Common Request Headers</a>.</p> <p>Consider the following when using request headers:</p> <ul> <li> <p> Consideration 1 – If both of the <code>If-Match</code> and <code>If-Unmodified-Since</code> headers are present in the request as follows:</p> <ul> <li> <p> <code>If-Match</code> condition evaluates to <code>true</code>, and;</p> </li> <li> <p> <code>If-Unmodified-Since</code> condition evaluates to <code>false</code>;</p> </li> </ul> <p>Then Amazon S3 returns <code>200 OK</code> and the data requested.</p> </li> <li> <p> Consideration 2 – If both of the <code>If-None-Match</code> and <code>If-Modified-Since</code> headers are present in the request as follows:</p> <ul> <li> <p> <code>If-None-Match</code> condition evaluates to <code>false</code>, and;</p> </li> <li> <p> <code>If-Modified-Since</code> condition evaluates to <code>true</code>;</p> </li> </ul> <p>Then Amazon S3 returns the <code>304 Not Modified</code> response code.</p> </li> </ul> <p>For more information about conditional requests, see <a href="https://tools.ietf.org/html/rfc7232">RFC 7232</a>.</p> <p> <b>Permissions</b> </p> <p>You need the <code>s3:GetObject</code> permission for this operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>. If the object you request does not exist, the error Amazon S3 returns depends on whether you also have the s3:ListBucket permission.</p> <ul> <li> <p>If you have the <code>s3:ListBucket</code> permission on the bucket, Amazon S3 returns an HTTP status code 404 (&quot;no such key&quot;) error.</p> </li> <li> <p>If you don’t have the <code>s3:ListBucket</code> permission, Amazon S3 returns an HTTP status code 403 (&quot;access denied&quot;) error.</p> </li> </ul> <p>The following operation is related to <code>HeadObject</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn head_object(
+    /// Presigns the request `head_bucket` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_head_bucket(
         &self,
-        input: HeadObjectRequest,
-    ) -> Result<HeadObjectOutput, RusotoError<HeadObjectError>> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        if let Some(ref if_modified_since) = input.if_modified_since {
-            request.add_header("If-Modified-Since", &if_modified_since.to_string());
-        }
-
-        if let Some(ref if_none_match) = input.if_none_match {
-            request.add_header("If-None-Match", &if_none_match.to_string());
-        }
-
-        if let Some(ref if_unmodified_since) = input.if_unmodified_since {
-            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
-        }
-
-        if let Some(ref range) = input.range {
-            request.add_header("Range", &range.to_string());
-        }
+        presigner: &rusoto_core::Presigner,
+        input: HeadBucketRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_head_bucket_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
+    #[allow(unused_variables, warnings)]
+    fn build_head_bucket_request(&self, input: HeadBucketRequest) -> SignedRequest {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-algorithm",
-                &sse_customer_algorithm.to_string(),
-            );
-        }
+        let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadBucket");
 
-        if let Some(ref sse_customer_key) = input.sse_customer_key {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key",
-                &sse_customer_key.to_string(),
-            );
-        }
+        request
+    }
 
-        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key-MD5",
-                &sse_customer_key_md5.to_string(),
-            );
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.part_number {
-            params.put("partNumber", x);
-        }
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
-        request.set_params(params);
+    /// Calls `head_object`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn head_object_with_metadata(
+        &self,
+        input: HeadObjectRequest,
+    ) -> Result<(HeadObjectOutput, ResponseMetadata), RusotoError<HeadObjectError>> {
+        let request = self.build_head_object_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(HeadObjectError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22374,42 +24132,113 @@ impl S3 for S3Client {
             let value = website_redirect_location.to_owned();
             result.website_redirect_location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Lists the analytics configurations for the bucket. You can have up to 1,000 analytics configurations per bucket.</p> <p>This operation supports list pagination and does not return more than 100 configurations at a time. You should always check the <code>IsTruncated</code> element in the response. If there are no more configurations to list, <code>IsTruncated</code> is set to false. If there are more configurations to list, <code>IsTruncated</code> is set to true, and there will be a value in <code>NextContinuationToken</code>. You use the <code>NextContinuationToken</code> value to continue the pagination of the list by passing the value in continuation-token in the request to <code>GET</code> the next page.</p> <p>To use this operation, you must have permissions to perform the <code>s3:GetAnalyticsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about Amazon S3 analytics feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/analytics-storage-class.html">Amazon S3 Analytics – Storage Class Analysis</a>. </p> <p>The following operations are related to <code>ListBucketAnalyticsConfigurations</code>:</p> <ul> <li> <p> <a>GetBucketAnalyticsConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketAnalyticsConfiguration</a> </p> </li> <li> <p> <a>PutBucketAnalyticsConfiguration</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn list_bucket_analytics_configurations(
+    /// Presigns the request `head_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_head_object(
         &self,
-        input: ListBucketAnalyticsConfigurationsRequest,
-    ) -> Result<
-        ListBucketAnalyticsConfigurationsOutput,
-        RusotoError<ListBucketAnalyticsConfigurationsError>,
-    > {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        presigner: &rusoto_core::Presigner,
+        input: HeadObjectRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_head_object_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+    #[allow(unused_variables, warnings)]
+    fn build_head_object_request(&self, input: HeadObjectRequest) -> SignedRequest {
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+
+        let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadObject");
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        if let Some(ref if_modified_since) = input.if_modified_since {
+            request.add_header("If-Modified-Since", &if_modified_since.to_string());
+        }
+
+        if let Some(ref if_none_match) = input.if_none_match {
+            request.add_header("If-None-Match", &if_none_match.to_string());
+        }
+
+        if let Some(ref if_unmodified_since) = input.if_unmodified_since {
+            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
+        }
+
+        if let Some(ref range) = input.range {
+            request.add_header("Range", &range.to_string());
+        }
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
+
+        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-algorithm",
+                &sse_customer_algorithm.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key) = input.sse_customer_key {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key",
+                &sse_customer_key.to_string(),
+            );
+        }
 
+        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key-MD5",
+                &sse_customer_key_md5.to_string(),
+            );
+        }
         let mut params = Params::new();
-        if let Some(ref x) = input.continuation_token {
-            params.put("continuation-token", x);
+        if let Some(ref x) = input.part_number {
+            params.put("partNumber", x);
+        }
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
         }
-        params.put_key("analytics");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_bucket_analytics_configurations`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_bucket_analytics_configurations_with_metadata(
+        &self,
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> Result<
+        (ListBucketAnalyticsConfigurationsOutput, ResponseMetadata),
+        RusotoError<ListBucketAnalyticsConfigurationsError>,
+    > {
+        let request = self.build_list_bucket_analytics_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListBucketAnalyticsConfigurationsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22428,42 +24257,70 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_bucket_analytics_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_bucket_analytics_configurations(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_bucket_analytics_configurations_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns a list of inventory configurations for the bucket. You can have up to 1,000 analytics configurations per bucket.</p> <p>This operation supports list pagination and does not return more than 100 configurations at a time. Always check the <code>IsTruncated</code> element in the response. If there are no more configurations to list, <code>IsTruncated</code> is set to false. If there are more configurations to list, <code>IsTruncated</code> is set to true, and there is a value in <code>NextContinuationToken</code>. You use the <code>NextContinuationToken</code> value to continue the pagination of the list by passing the value in continuation-token in the request to <code>GET</code> the next page.</p> <p> To use this operation, you must have permissions to perform the <code>s3:GetInventoryConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about the Amazon S3 inventory feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-inventory.html">Amazon S3 Inventory</a> </p> <p>The following operations are related to <code>ListBucketInventoryConfigurations</code>:</p> <ul> <li> <p> <a>GetBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>PutBucketInventoryConfiguration</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn list_bucket_inventory_configurations(
+    fn build_list_bucket_analytics_configurations_request(
         &self,
-        input: ListBucketInventoryConfigurationsRequest,
-    ) -> Result<
-        ListBucketInventoryConfigurationsOutput,
-        RusotoError<ListBucketInventoryConfigurationsError>,
-    > {
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketAnalyticsConfigurations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continuation_token {
             params.put("continuation-token", x);
         }
-        params.put_key("inventory");
+        params.put_key("analytics");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_bucket_inventory_configurations`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_bucket_inventory_configurations_with_metadata(
+        &self,
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> Result<
+        (ListBucketInventoryConfigurationsOutput, ResponseMetadata),
+        RusotoError<ListBucketInventoryConfigurationsError>,
+    > {
+        let request = self.build_list_bucket_inventory_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListBucketInventoryConfigurationsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22482,42 +24339,70 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_bucket_inventory_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_bucket_inventory_configurations(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_bucket_inventory_configurations_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Lists the metrics configurations for the bucket. The metrics configurations are only for the request metrics of the bucket and do not provide information on daily storage metrics. You can have up to 1,000 configurations per bucket.</p> <p>This operation supports list pagination and does not return more than 100 configurations at a time. Always check the <code>IsTruncated</code> element in the response. If there are no more configurations to list, <code>IsTruncated</code> is set to false. If there are more configurations to list, <code>IsTruncated</code> is set to true, and there is a value in <code>NextContinuationToken</code>. You use the <code>NextContinuationToken</code> value to continue the pagination of the list by passing the value in <code>continuation-token</code> in the request to <code>GET</code> the next page.</p> <p>To use this operation, you must have permissions to perform the <code>s3:GetMetricsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For more information about metrics configurations and CloudWatch request metrics, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a>.</p> <p>The following operations are related to <code>ListBucketMetricsConfigurations</code>:</p> <ul> <li> <p> <a>PutBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>GetBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketMetricsConfiguration</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn list_bucket_metrics_configurations(
+    fn build_list_bucket_inventory_configurations_request(
         &self,
-        input: ListBucketMetricsConfigurationsRequest,
-    ) -> Result<
-        ListBucketMetricsConfigurationsOutput,
-        RusotoError<ListBucketMetricsConfigurationsError>,
-    > {
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketInventoryConfigurations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continuation_token {
             params.put("continuation-token", x);
         }
-        params.put_key("metrics");
+        params.put_key("inventory");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_bucket_metrics_configurations`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_bucket_metrics_configurations_with_metadata(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> Result<
+        (ListBucketMetricsConfigurationsOutput, ResponseMetadata),
+        RusotoError<ListBucketMetricsConfigurationsError>,
+    > {
+        let request = self.build_list_bucket_metrics_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListBucketMetricsConfigurationsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22536,27 +24421,64 @@ impl S3 for S3Client {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_bucket_metrics_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_bucket_metrics_configurations(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_bucket_metrics_configurations_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Returns a list of all buckets owned by the authenticated sender of the request.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_buckets(&self) -> Result<ListBucketsOutput, RusotoError<ListBucketsError>> {
-        let request_uri = "/";
+    fn build_list_bucket_metrics_configurations_request(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketMetricsConfigurations");
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.continuation_token {
+            params.put("continuation-token", x);
+        }
+        params.put_key("metrics");
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `list_buckets`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_buckets_with_metadata(
+        &self,
+    ) -> Result<(ListBucketsOutput, ResponseMetadata), RusotoError<ListBucketsError>> {
+        let request = self.build_list_buckets_request();
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListBucketsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22572,52 +24494,57 @@ impl S3 for S3Client {
             result = ListBucketsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>This operation lists in-progress multipart uploads. An in-progress multipart upload is a multipart upload that has been initiated using the Initiate Multipart Upload request, but has not yet been completed or aborted.</p> <p>This operation returns at most 1,000 multipart uploads in the response. 1,000 multipart uploads is the maximum number of uploads a response can include, which is also the default value. You can further limit the number of uploads in a response by specifying the <code>max-uploads</code> parameter in the response. If additional multipart uploads satisfy the list criteria, the response will contain an <code>IsTruncated</code> element with the value true. To list the additional multipart uploads, use the <code>key-marker</code> and <code>upload-id-marker</code> request parameters.</p> <p>In the response, the uploads are sorted by key. If your application has initiated more than one multipart upload using the same object key, then uploads in the response are first sorted by key. Additionally, uploads are sorted in ascending order within each key by the upload initiation time.</p> <p>For more information on multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/uploadobjusingmpu.html">Uploading Objects Using Multipart Upload</a>.</p> <p>For information on permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p>The following operations are related to <code>ListMultipartUploads</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn list_multipart_uploads(
+    /// Presigns the request `list_buckets` would send with `presigner` instead of sending it.
+    pub async fn presign_list_buckets(
         &self,
-        input: ListMultipartUploadsRequest,
-    ) -> Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_buckets_request();
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_list_buckets_request(&self) -> SignedRequest {
+        let request_uri = "/";
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBuckets");
 
-        let mut params = Params::new();
-        if let Some(ref x) = input.delimiter {
-            params.put("delimiter", x);
-        }
-        if let Some(ref x) = input.encoding_type {
-            params.put("encoding-type", x);
-        }
-        if let Some(ref x) = input.key_marker {
-            params.put("key-marker", x);
-        }
-        if let Some(ref x) = input.max_uploads {
-            params.put("max-uploads", x);
-        }
-        if let Some(ref x) = input.prefix {
-            params.put("prefix", x);
-        }
-        if let Some(ref x) = input.upload_id_marker {
-            params.put("upload-id-marker", x);
-        }
-        params.put_key("uploads");
-        request.set_params(params);
+        request
+    }
+
+    /// Calls `list_multipart_uploads`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_multipart_uploads_with_metadata(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> Result<
+        (ListMultipartUploadsOutput, ResponseMetadata),
+        RusotoError<ListMultipartUploadsError>,
+    > {
+        let request = self.build_list_multipart_uploads_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListMultipartUploadsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22634,18 +24561,28 @@ impl S3 for S3Client {
                 ListMultipartUploadsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_multipart_uploads` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_multipart_uploads(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListMultipartUploadsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_multipart_uploads_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns metadata about all of the versions of objects in a bucket. You can also use request parameters as selection criteria to return metadata about a subset of all the object versions. </p> <note> <p> A 200 OK response can contain valid or invalid XML. Make sure to design your application to parse the contents of the response and handle it appropriately.</p> </note> <p>To use this operation, you must have READ access to the bucket.</p> <p>The following operations are related to <code>ListObjectVersions</code>:</p> <ul> <li> <p> <a>ListObjectsV2</a> </p> </li> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn list_object_versions(
+    fn build_list_multipart_uploads_request(
         &self,
-        input: ListObjectVersionsRequest,
-    ) -> Result<ListObjectVersionsOutput, RusotoError<ListObjectVersionsError>> {
+        input: ListMultipartUploadsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
 
         let mut params = Params::new();
         if let Some(ref x) = input.delimiter {
@@ -22657,29 +24594,48 @@ impl S3 for S3Client {
         if let Some(ref x) = input.key_marker {
             params.put("key-marker", x);
         }
-        if let Some(ref x) = input.max_keys {
-            params.put("max-keys", x);
+        if let Some(ref x) = input.max_uploads {
+            params.put("max-uploads", x);
         }
         if let Some(ref x) = input.prefix {
             params.put("prefix", x);
         }
-        if let Some(ref x) = input.version_id_marker {
-            params.put("version-id-marker", x);
+        if let Some(ref x) = input.upload_id_marker {
+            params.put("upload-id-marker", x);
         }
-        params.put_key("versions");
+        params.put_key("uploads");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_object_versions`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_object_versions_with_metadata(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> Result<(ListObjectVersionsOutput, ResponseMetadata), RusotoError<ListObjectVersionsError>>
+    {
+        let request = self.build_list_object_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListObjectVersionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22696,22 +24652,29 @@ impl S3 for S3Client {
                 ListObjectVersionsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_object_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_object_versions(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListObjectVersionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_object_versions_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Returns some or all (up to 1,000) of the objects in a bucket. You can use the request parameters as selection criteria to return a subset of the objects in a bucket. A 200 OK response can contain valid or invalid XML. Be sure to design your application to parse the contents of the response and handle it appropriately.</p> <important> <p>This API has been revised. We recommend that you use the newer version, <a>ListObjectsV2</a>, when developing applications. For backward compatibility, Amazon S3 continues to support <code>ListObjects</code>.</p> </important> <p>The following operations are related to <code>ListObjects</code>:</p> <ul> <li> <p> <a>ListObjectsV2</a> </p> </li> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>ListBuckets</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn list_objects(
+    fn build_list_object_versions_request(
         &self,
-        input: ListObjectsRequest,
-    ) -> Result<ListObjectsOutput, RusotoError<ListObjectsError>> {
+        input: ListObjectVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectVersions");
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
         let mut params = Params::new();
         if let Some(ref x) = input.delimiter {
             params.put("delimiter", x);
@@ -22719,8 +24682,8 @@ impl S3 for S3Client {
         if let Some(ref x) = input.encoding_type {
             params.put("encoding-type", x);
         }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
+        if let Some(ref x) = input.key_marker {
+            params.put("key-marker", x);
         }
         if let Some(ref x) = input.max_keys {
             params.put("max-keys", x);
@@ -22728,19 +24691,41 @@ impl S3 for S3Client {
         if let Some(ref x) = input.prefix {
             params.put("prefix", x);
         }
+        if let Some(ref x) = input.version_id_marker {
+            params.put("version-id-marker", x);
+        }
+        params.put_key("versions");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_objects`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_objects_with_metadata(
+        &self,
+        input: ListObjectsRequest,
+    ) -> Result<(ListObjectsOutput, ResponseMetadata), RusotoError<ListObjectsError>> {
+        let request = self.build_list_objects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListObjectsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22756,34 +24741,38 @@ impl S3 for S3Client {
             result = ListObjectsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns some or all (up to 1,000) of the objects in a bucket. You can use the request parameters as selection criteria to return a subset of the objects in a bucket. A <code>200 OK</code> response can contain valid or invalid XML. Make sure to design your application to parse the contents of the response and handle it appropriately.</p> <p>To use this operation, you must have READ access to the bucket.</p> <p>To use this operation in an AWS Identity and Access Management (IAM) policy, you must have permissions to perform the <code>s3:ListBucket</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <important> <p>This section describes the latest revision of the API. We recommend that you use this revised API for application development. For backward compatibility, Amazon S3 continues to support the prior version of this API, <a>ListObjects</a>.</p> </important> <p>To get a list of your buckets, see <a>ListBuckets</a>.</p> <p>The following operations are related to <code>ListObjectsV2</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn list_objects_v2(
+    /// Presigns the request `list_objects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_objects(
         &self,
-        input: ListObjectsV2Request,
-    ) -> Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListObjectsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_objects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_list_objects_request(&self, input: ListObjectsRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjects");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
         }
         let mut params = Params::new();
-        if let Some(ref x) = input.continuation_token {
-            params.put("continuation-token", x);
-        }
         if let Some(ref x) = input.delimiter {
             params.put("delimiter", x);
         }
         if let Some(ref x) = input.encoding_type {
             params.put("encoding-type", x);
         }
-        if let Some(ref x) = input.fetch_owner {
-            params.put("fetch-owner", x);
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
         }
         if let Some(ref x) = input.max_keys {
             params.put("max-keys", x);
@@ -22791,23 +24780,37 @@ impl S3 for S3Client {
         if let Some(ref x) = input.prefix {
             params.put("prefix", x);
         }
-        if let Some(ref x) = input.start_after {
-            params.put("start-after", x);
-        }
-        params.put("list-type", "2");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_objects_v2`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_objects_v2_with_metadata(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> Result<(ListObjectsV2Output, ResponseMetadata), RusotoError<ListObjectsV2Error>> {
+        let request = self.build_list_objects_v2_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListObjectsV2Error::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22823,43 +24826,83 @@ impl S3 for S3Client {
             result = ListObjectsV2OutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Lists the parts that have been uploaded for a specific multipart upload. This operation must include the upload ID, which you obtain by sending the initiate multipart upload request (see <a>CreateMultipartUpload</a>). This request returns a maximum of 1,000 uploaded parts. The default number of parts returned is 1,000 parts. You can restrict the number of parts returned by specifying the <code>max-parts</code> request parameter. If your multipart upload consists of more than 1,000 parts, the response returns an <code>IsTruncated</code> field with the value of true, and a <code>NextPartNumberMarker</code> element. In subsequent <code>ListParts</code> requests you can include the part-number-marker query string parameter and set its value to the <code>NextPartNumberMarker</code> field value from the previous response.</p> <p>For more information on multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/uploadobjusingmpu.html">Uploading Objects Using Multipart Upload</a>.</p> <p>For information on permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p>The following operations are related to <code>ListParts</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn list_parts(
+    /// Presigns the request `list_objects_v2` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_objects_v2(
         &self,
-        input: ListPartsRequest,
-    ) -> Result<ListPartsOutput, RusotoError<ListPartsError>> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+        presigner: &rusoto_core::Presigner,
+        input: ListObjectsV2Request,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_objects_v2_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_list_objects_v2_request(&self, input: ListObjectsV2Request) -> SignedRequest {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectsV2");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
         }
         let mut params = Params::new();
-        if let Some(ref x) = input.max_parts {
-            params.put("max-parts", x);
+        if let Some(ref x) = input.continuation_token {
+            params.put("continuation-token", x);
         }
-        if let Some(ref x) = input.part_number_marker {
-            params.put("part-number-marker", x);
+        if let Some(ref x) = input.delimiter {
+            params.put("delimiter", x);
         }
-        params.put("uploadId", &input.upload_id);
+        if let Some(ref x) = input.encoding_type {
+            params.put("encoding-type", x);
+        }
+        if let Some(ref x) = input.fetch_owner {
+            params.put("fetch-owner", x);
+        }
+        if let Some(ref x) = input.max_keys {
+            params.put("max-keys", x);
+        }
+        if let Some(ref x) = input.prefix {
+            params.put("prefix", x);
+        }
+        if let Some(ref x) = input.start_after {
+            params.put("start-after", x);
+        }
+        params.put("list-type", "2");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_parts`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_parts_with_metadata(
+        &self,
+        input: ListPartsRequest,
+    ) -> Result<(ListPartsOutput, ResponseMetadata), RusotoError<ListPartsError>> {
+        let request = self.build_list_parts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListPartsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -22886,18 +24929,89 @@ impl S3 for S3Client {
             let value = request_charged.to_owned();
             result.request_charged = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_parts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_parts(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListPartsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_parts_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Sets the accelerate configuration of an existing bucket. Amazon S3 Transfer Acceleration is a bucket-level feature that enables you to perform faster data transfers to Amazon S3.</p> <p> To use this operation, you must have permission to perform the s3:PutAccelerateConfiguration action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> The Transfer Acceleration state of a bucket can be set to one of the following two values:</p> <ul> <li> <p> Enabled – Enables accelerated data transfers to the bucket.</p> </li> <li> <p> Suspended – Disables accelerated data transfers to the bucket.</p> </li> </ul> <p>The <a>GetBucketAccelerateConfiguration</a> operation returns the transfer acceleration state of a bucket.</p> <p>After setting the Transfer Acceleration state of a bucket to Enabled, it might take up to thirty minutes before the data transfer rates to the bucket increase.</p> <p> The name of the bucket used for Transfer Acceleration must be DNS-compliant and must not contain periods (&quot;.&quot;).</p> <p> For more information about transfer acceleration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/transfer-acceleration.html">Transfer Acceleration</a>.</p> <p>The following operations are related to <code>PutBucketAccelerateConfiguration</code>:</p> <ul> <li> <p> <a>GetBucketAccelerateConfiguration</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_accelerate_configuration(
+    fn build_list_parts_request(&self, input: ListPartsRequest) -> SignedRequest {
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListParts");
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_parts {
+            params.put("max-parts", x);
+        }
+        if let Some(ref x) = input.part_number_marker {
+            params.put("part-number-marker", x);
+        }
+        params.put("uploadId", &input.upload_id);
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `put_bucket_accelerate_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_accelerate_configuration_with_metadata(
         &self,
         input: PutBucketAccelerateConfigurationRequest,
-    ) -> Result<(), RusotoError<PutBucketAccelerateConfigurationError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketAccelerateConfigurationError>> {
+        let request = self.build_put_bucket_accelerate_configuration_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if !response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketAccelerateConfigurationError::from_response(
+                response,
+            ));
+        }
+
+        std::mem::drop(response);
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_accelerate_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_accelerate_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_accelerate_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_bucket_accelerate_configuration_request(
+        &self,
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAccelerateConfiguration");
 
         let mut params = Params::new();
         params.put_key("accelerate");
@@ -22910,31 +25024,51 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_acl`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_acl_with_metadata(
+        &self,
+        input: PutBucketAclRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketAclError>> {
+        let request = self.build_put_bucket_acl_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketAccelerateConfigurationError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketAclError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Sets the permissions on an existing bucket using access control lists (ACL). For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>. To set the ACL of a bucket, you must have <code>WRITE_ACP</code> permission.</p> <p>You can use one of the following two ways to set a bucket&#39;s permissions:</p> <ul> <li> <p>Specify the ACL in the request body</p> </li> <li> <p>Specify permissions using request headers</p> </li> </ul> <note> <p>You cannot specify access permission using both the body and the request headers.</p> </note> <p>Depending on your application needs, you may choose to set the ACL on a bucket using either the request body or the headers. For example, if you have an existing application that updates a bucket ACL using the request body, then you can continue to use that approach.</p> <p> <b>Access Permissions</b> </p> <p>You can set access permissions using one of the following methods:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. Amazon S3 supports a set of predefined ACLs, known as <i>canned ACLs</i>. Each canned ACL has a predefined set of grantees and permissions. Specify the canned ACL name as the value of <code>x-amz-acl</code>. If you use this header, you cannot use other access control-specific headers in your request. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. When using these headers, you specify explicit access permissions and grantees (AWS accounts or Amazon S3 groups) who will receive the permission. If you use these ACL-specific headers, you cannot use the <code>x-amz-acl</code> header to set a canned ACL. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-write</code> header grants create, overwrite, and delete objects permission to LogDelivery group predefined by Amazon S3 and two AWS accounts identified by their email addresses.</p> <p> <code>x-amz-grant-write: uri=&quot;http://acs.amazonaws.com/groups/s3/LogDelivery&quot;, emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> <p> <b>Grantee Values</b> </p> <p>You can specify the person (grantee) to whom you&#39;re assigning access rights (using request elements) in the following ways:</p> <ul> <li> <p>By Email address:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;AmazonCustomerByEmail&quot;&gt;&lt;EmailAddress&gt;&lt;&gt;Grantees@email.com&lt;&gt;&lt;/EmailAddress&gt;lt;/Grantee&gt;</code> </p> <p>The grantee is resolved to the CanonicalUser and, in a response to a GET Object acl request, appears as the CanonicalUser.</p> </li> <li> <p>By the person&#39;s ID:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;CanonicalUser&quot;&gt;&lt;ID&gt;&lt;&gt;ID&lt;&gt;&lt;/ID&gt;&lt;DisplayName&gt;&lt;&gt;GranteesEmail&lt;&gt;&lt;/DisplayName&gt; &lt;/Grantee&gt;</code> </p> <p>DisplayName is optional and ignored in the request</p> </li> <li> <p>By URI:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;Group&quot;&gt;&lt;URI&gt;&lt;&gt;http://acs.amazonaws.com/groups/global/AuthenticatedUsers&lt;&gt;&lt;/URI&gt;&lt;/Grantee&gt;</code> </p> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> <li> <p> <a>GetObjectAcl</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn put_bucket_acl(
+    /// Presigns the request `put_bucket_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_acl(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBucketAclRequest,
-    ) -> Result<(), RusotoError<PutBucketAclError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_bucket_acl_request(&self, input: PutBucketAclRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAcl");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
@@ -22978,29 +25112,56 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        request
+    }
+
+    /// Calls `put_bucket_analytics_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_analytics_configuration_with_metadata(
+        &self,
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketAnalyticsConfigurationError>> {
+        let request = self.build_put_bucket_analytics_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketAclError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketAnalyticsConfigurationError::from_response(
+                response,
+            ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_analytics_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_analytics_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_analytics_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Sets an analytics configuration for the bucket (specified by the analytics configuration ID). You can have up to 1,000 analytics configurations per bucket.</p> <p>You can choose to have storage class analysis export analysis reports sent to a comma-separated values (CSV) flat file. See the <code>DataExport</code> request element. Reports are updated daily and are based on the object filters that you configure. When selecting data export, you specify a destination bucket and an optional destination prefix where the file is written. You can export the data to a destination bucket in a different account. However, the destination bucket must be in the same Region as the bucket that you are making the PUT analytics configuration to. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/analytics-storage-class.html">Amazon S3 Analytics – Storage Class Analysis</a>. </p> <important> <p>You must create a bucket policy on the destination bucket where the exported file is written to grant permissions to Amazon S3 to write objects to the bucket. For an example policy, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/example-bucket-policies.html#example-bucket-policies-use-case-9">Granting Permissions for Amazon S3 Inventory and Storage Class Analysis</a>.</p> </important> <p>To use this operation, you must have permissions to perform the <code>s3:PutAnalyticsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <ul> <li> <p> <i>HTTP Error: HTTP 400 Bad Request</i> </p> </li> <li> <p> <i>Code: InvalidArgument</i> </p> </li> <li> <p> <i>Cause: Invalid argument.</i> </p> </li> </ul> </li> <li> <ul> <li> <p> <i>HTTP Error: HTTP 400 Bad Request</i> </p> </li> <li> <p> <i>Code: TooManyConfigurations</i> </p> </li> <li> <p> <i>Cause: You are attempting to create a new configuration but have already reached the 1,000-configuration limit.</i> </p> </li> </ul> </li> <li> <ul> <li> <p> <i>HTTP Error: HTTP 403 Forbidden</i> </p> </li> <li> <p> <i>Code: AccessDenied</i> </p> </li> <li> <p> <i>Cause: You are not the owner of the specified bucket, or you do not have the s3:PutAnalyticsConfiguration bucket permission to set the configuration on the bucket.</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> </p> </li> <li> <p> </p> </li> <li> <p> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_analytics_configuration(
+    fn build_put_bucket_analytics_configuration_request(
         &self,
         input: PutBucketAnalyticsConfigurationRequest,
-    ) -> Result<(), RusotoError<PutBucketAnalyticsConfigurationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAnalyticsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -23014,31 +25175,51 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_cors`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_cors_with_metadata(
+        &self,
+        input: PutBucketCorsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketCorsError>> {
+        let request = self.build_put_bucket_cors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketAnalyticsConfigurationError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketCorsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Sets the <code>cors</code> configuration for your bucket. If the configuration exists, Amazon S3 replaces it.</p> <p>To use this operation, you must be allowed to perform the <code>s3:PutBucketCORS</code> action. By default, the bucket owner has this permission and can grant it to others.</p> <p>You set this configuration on a bucket so that the bucket can service cross-origin requests. For example, you might want to enable a request whose origin is <code>http://www.example.com</code> to access your Amazon S3 bucket at <code>my.example.bucket.com</code> by using the browser&#39;s <code>XMLHttpRequest</code> capability.</p> <p>To enable cross-origin resource sharing (CORS) on a bucket, you add the <code>cors</code> subresource to the bucket. The <code>cors</code> subresource is an XML document in which you configure rules that identify origins and the HTTP methods that can be executed on your bucket. The document is limited to 64 KB in size. </p> <p>When Amazon S3 receives a cross-origin request (or a pre-flight OPTIONS request) against a bucket, it evaluates the <code>cors</code> configuration on the bucket and uses the first <code>CORSRule</code> rule that matches the incoming browser request to enable a cross-origin request. For a rule to match, the following conditions must be met:</p> <ul> <li> <p>The request&#39;s <code>Origin</code> header must match <code>AllowedOrigin</code> elements.</p> </li> <li> <p>The request method (for example, GET, PUT, HEAD, and so on) or the <code>Access-Control-Request-Method</code> header in case of a pre-flight <code>OPTIONS</code> request must be one of the <code>AllowedMethod</code> elements. </p> </li> <li> <p>Every header specified in the <code>Access-Control-Request-Headers</code> request header of a pre-flight request must match an <code>AllowedHeader</code> element. </p> </li> </ul> <p> For more information about CORS, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cors.html">Enabling Cross-Origin Resource Sharing</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetBucketCors</a> </p> </li> <li> <p> <a>DeleteBucketCors</a> </p> </li> <li> <p> <a>RESTOPTIONSobject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn put_bucket_cors(
+    /// Presigns the request `put_bucket_cors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_cors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBucketCorsRequest,
-    ) -> Result<(), RusotoError<PutBucketCorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_cors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_bucket_cors_request(&self, input: PutBucketCorsRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketCors");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23055,29 +25236,54 @@ impl S3 for S3Client {
         request.set_payload(Some(writer.into_inner()));
         request.set_content_md5_header();
 
+        request
+    }
+
+    /// Calls `put_bucket_encryption`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_encryption_with_metadata(
+        &self,
+        input: PutBucketEncryptionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketEncryptionError>> {
+        let request = self.build_put_bucket_encryption_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketCorsError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketEncryptionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_encryption` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_encryption(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketEncryptionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_encryption_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>This implementation of the <code>PUT</code> operation uses the <code>encryption</code> subresource to set the default encryption state of an existing bucket.</p> <p>This implementation of the <code>PUT</code> operation sets default encryption for a bucket using server-side encryption with Amazon S3-managed keys SSE-S3 or AWS KMS customer master keys (CMKs) (SSE-KMS).</p> <important> <p>This operation requires AWS Signature Version 4. For more information, see <a href="sig-v4-authenticating-requests.html"> Authenticating Requests (AWS Signature Version 4)</a>. </p> </important> <p>To use this operation, you must have permissions to perform the <code>s3:PutEncryptionConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a> in the Amazon Simple Storage Service Developer Guide. </p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetBucketEncryption</a> </p> </li> <li> <p> <a>DeleteBucketEncryption</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_encryption(
+    fn build_put_bucket_encryption_request(
         &self,
         input: PutBucketEncryptionRequest,
-    ) -> Result<(), RusotoError<PutBucketEncryptionError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketEncryption");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23093,29 +25299,56 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_inventory_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_inventory_configuration_with_metadata(
+        &self,
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketInventoryConfigurationError>> {
+        let request = self.build_put_bucket_inventory_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketEncryptionError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketInventoryConfigurationError::from_response(
+                response,
+            ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_inventory_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_inventory_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_inventory_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>This implementation of the <code>PUT</code> operation adds an inventory configuration (identified by the inventory ID) to the bucket. You can have up to 1,000 inventory configurations per bucket. </p> <p>Amazon S3 inventory generates inventories of the objects in the bucket on a daily or weekly basis, and the results are published to a flat file. The bucket that is inventoried is called the <i>source</i> bucket, and the bucket where the inventory flat file is stored is called the <i>destination</i> bucket. The <i>destination</i> bucket must be in the same AWS Region as the <i>source</i> bucket. </p> <p>When you configure an inventory for a <i>source</i> bucket, you specify the <i>destination</i> bucket where you want the inventory to be stored, and whether to generate the inventory daily or weekly. You can also configure what object metadata to include and whether to inventory all object versions or only current versions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//storage-inventory.html">Amazon S3 Inventory</a> in the Amazon Simple Storage Service Developer Guide.</p> <important> <p>You must create a bucket policy on the <i>destination</i> bucket to grant permissions to Amazon S3 to write objects to the bucket in the defined location. For an example policy, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/example-bucket-policies.html#example-bucket-policies-use-case-9"> Granting Permissions for Amazon S3 Inventory and Storage Class Analysis.</a> </p> </important> <p>To use this operation, you must have permissions to perform the <code>s3:PutInventoryConfiguration</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a> in the Amazon Simple Storage Service Developer Guide.</p> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b>HTTP 400 Bad Request Error</b> </p> <ul> <li> <p> <i>Code:</i> InvalidArgument</p> </li> <li> <p> <i>Cause:</i> Invalid Argument</p> </li> </ul> </li> <li> <p class="title"> <b>HTTP 400 Bad Request Error</b> </p> <ul> <li> <p> <i>Code:</i> TooManyConfigurations</p> </li> <li> <p> <i>Cause:</i> You are attempting to create a new configuration but have already reached the 1,000-configuration limit. </p> </li> </ul> </li> <li> <p class="title"> <b>HTTP 403 Forbidden Error</b> </p> <ul> <li> <p> <i>Code:</i> AccessDenied</p> </li> <li> <p> <i>Cause:</i> You are not the owner of the specified bucket, or you do not have the <code>s3:PutInventoryConfiguration</code> bucket permission to set the configuration on the bucket </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>ListBucketInventoryConfigurations</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_inventory_configuration(
+    fn build_put_bucket_inventory_configuration_request(
         &self,
         input: PutBucketInventoryConfigurationRequest,
-    ) -> Result<(), RusotoError<PutBucketInventoryConfigurationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketInventoryConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -23129,31 +25362,54 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_lifecycle`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_lifecycle_with_metadata(
+        &self,
+        input: PutBucketLifecycleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketLifecycleError>> {
+        let request = self.build_put_bucket_lifecycle_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketInventoryConfigurationError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketLifecycleError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_lifecycle` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_lifecycle(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketLifecycleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_lifecycle_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><important> <p>For an updated version of this API, see <a>PutBucketLifecycleConfiguration</a>. This version has been deprecated. Existing lifecycle configurations will work. For new lifecycle configurations, use the updated API. </p> </important> <p>Creates a new lifecycle configuration for the bucket or replaces an existing lifecycle configuration. For information about lifecycle configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//object-lifecycle-mgmt.html">Object Lifecycle Management</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>By default, all Amazon S3 resources, including buckets, objects, and related subresources (for example, lifecycle configuration and website configuration) are private. Only the resource owner, the AWS account that created the resource, can access it. The resource owner can optionally grant access permissions to others by writing an access policy. For this operation, users must get the <code>s3:PutLifecycleConfiguration</code> permission.</p> <p>You can also explicitly deny permissions. Explicit denial also supersedes any other permissions. If you want to prevent users or accounts from removing or deleting objects from your bucket, you must deny them permissions for the following actions: </p> <ul> <li> <p> <code>s3:DeleteObject</code> </p> </li> <li> <p> <code>s3:DeleteObjectVersion</code> </p> </li> <li> <p> <code>s3:PutLifecycleConfiguration</code> </p> </li> </ul> <p>For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>For more examples of transitioning objects to storage classes such as STANDARD<em>IA or ONEZONE</em>IA, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//intro-lifecycle-rules.html#lifecycle-configuration-examples">Examples of Lifecycle Configuration</a>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetBucketLifecycle</a>(Deprecated)</p> </li> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> <li> <p> </p> </li> <li> <p>By default, a resource owner—in this case, a bucket owner, which is the AWS account that created the bucket—can perform any of the operations. A resource owner can also grant others permission to perform the operation. For more information, see the following topics in the Amazon Simple Storage Service Developer Guide: </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html">Specifying Permissions in a Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to your Amazon S3 Resources</a> </p> </li> </ul> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_lifecycle(
+    fn build_put_bucket_lifecycle_request(
         &self,
         input: PutBucketLifecycleRequest,
-    ) -> Result<(), RusotoError<PutBucketLifecycleError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycle");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23174,29 +25430,56 @@ impl S3 for S3Client {
         }
         request.set_content_md5_header();
 
+        request
+    }
+
+    /// Calls `put_bucket_lifecycle_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_lifecycle_configuration_with_metadata(
+        &self,
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketLifecycleConfigurationError>> {
+        let request = self.build_put_bucket_lifecycle_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketLifecycleError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketLifecycleConfigurationError::from_response(
+                response,
+            ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_lifecycle_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_lifecycle_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_lifecycle_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Creates a new lifecycle configuration for the bucket or replaces an existing lifecycle configuration. For information about lifecycle configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <note> <p>Bucket lifecycle configuration now supports specifying a lifecycle rule using an object key name prefix, one or more object tags, or a combination of both. Accordingly, this section describes the latest API. The previous version of the API supported filtering based only on an object key name prefix, which is supported for backward compatibility. For the related API description, see <a>PutBucketLifecycle</a>.</p> </note> <p> <b>Rules</b> </p> <p>You specify the lifecycle configuration in your request body. The lifecycle configuration is specified as XML consisting of one or more rules. Each rule consists of the following:</p> <ul> <li> <p>Filter identifying a subset of objects to which the rule applies. The filter can be based on a key name prefix, object tags, or a combination of both.</p> </li> <li> <p>Status whether the rule is in effect.</p> </li> <li> <p>One or more lifecycle transition and expiration actions that you want Amazon S3 to perform on the objects identified by the filter. If the state of your bucket is versioning-enabled or versioning-suspended, you can have many versions of the same object (one current version and zero or more noncurrent versions). Amazon S3 provides predefined actions that you can specify for current and noncurrent object versions.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/intro-lifecycle-rules.html">Lifecycle Configuration Elements</a>.</p> <p> <b>Permissions</b> </p> <p>By default, all Amazon S3 resources are private, including buckets, objects, and related subresources (for example, lifecycle configuration and website configuration). Only the resource owner (that is, the AWS account that created it) can access the resource. The resource owner can optionally grant access permissions to others by writing an access policy. For this operation, a user must get the s3:PutLifecycleConfiguration permission.</p> <p>You can also explicitly deny permissions. Explicit deny also supersedes any other permissions. If you want to block users or accounts from removing or deleting objects from your bucket, you must deny them permissions for the following actions:</p> <ul> <li> <p>s3:DeleteObject</p> </li> <li> <p>s3:DeleteObjectVersion</p> </li> <li> <p>s3:PutLifecycleConfiguration</p> </li> </ul> <p>For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>The following are related to <code>PutBucketLifecycleConfiguration</code>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/lifecycle-configuration-examples.html">Examples of Lifecycle Configuration</a> </p> </li> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketLifecycle</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_lifecycle_configuration(
+    fn build_put_bucket_lifecycle_configuration_request(
         &self,
         input: PutBucketLifecycleConfigurationRequest,
-    ) -> Result<(), RusotoError<PutBucketLifecycleConfigurationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycleConfiguration");
 
         let mut params = Params::new();
         params.put_key("lifecycle");
@@ -23214,31 +25497,51 @@ impl S3 for S3Client {
         }
         request.set_content_md5_header();
 
+        request
+    }
+
+    /// Calls `put_bucket_logging`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_logging_with_metadata(
+        &self,
+        input: PutBucketLoggingRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketLoggingError>> {
+        let request = self.build_put_bucket_logging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketLifecycleConfigurationError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketLoggingError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Set the logging parameters for a bucket and to specify permissions for who can view and modify the logging parameters. All logs are saved to buckets in the same AWS Region as the source bucket. To set the logging status of a bucket, you must be the bucket owner.</p> <p>The bucket owner is automatically granted FULL_CONTROL to all logs. You use the <code>Grantee</code> request element to grant access to other people. The <code>Permissions</code> request element specifies the kind of access the grantee has to the logs.</p> <p> <b>Grantee Values</b> </p> <p>You can specify the person (grantee) to whom you&#39;re assigning access rights (using request elements) in the following ways:</p> <ul> <li> <p>By the person&#39;s ID:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;CanonicalUser&quot;&gt;&lt;ID&gt;&lt;&gt;ID&lt;&gt;&lt;/ID&gt;&lt;DisplayName&gt;&lt;&gt;GranteesEmail&lt;&gt;&lt;/DisplayName&gt; &lt;/Grantee&gt;</code> </p> <p>DisplayName is optional and ignored in the request.</p> </li> <li> <p>By Email address:</p> <p> <code> &lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;AmazonCustomerByEmail&quot;&gt;&lt;EmailAddress&gt;&lt;&gt;Grantees@email.com&lt;&gt;&lt;/EmailAddress&gt;&lt;/Grantee&gt;</code> </p> <p>The grantee is resolved to the CanonicalUser and, in a response to a GET Object acl request, appears as the CanonicalUser.</p> </li> <li> <p>By URI:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;Group&quot;&gt;&lt;URI&gt;&lt;&gt;http://acs.amazonaws.com/groups/global/AuthenticatedUsers&lt;&gt;&lt;/URI&gt;&lt;/Grantee&gt;</code> </p> </li> </ul> <p>To enable logging, you use LoggingEnabled and its children request elements. To disable logging, you use an empty BucketLoggingStatus request element:</p> <p> <code>&lt;BucketLoggingStatus xmlns=&quot;http://doc.s3.amazonaws.com/2006-03-01&quot; /&gt;</code> </p> <p>For more information about server access logging, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ServerLogs.html">Server Access Logging</a>. </p> <p>For more information about creating a bucket, see <a>CreateBucket</a>. For more information about returning the logging status of a bucket, see <a>GetBucketLogging</a>.</p> <p>The following operations are related to <code>PutBucketLogging</code>:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>GetBucketLogging</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn put_bucket_logging(
+    /// Presigns the request `put_bucket_logging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_logging(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBucketLoggingRequest,
-    ) -> Result<(), RusotoError<PutBucketLoggingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_logging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_bucket_logging_request(&self, input: PutBucketLoggingRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLogging");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23254,29 +25557,54 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_metrics_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_metrics_configuration_with_metadata(
+        &self,
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketMetricsConfigurationError>> {
+        let request = self.build_put_bucket_metrics_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketLoggingError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketMetricsConfigurationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_metrics_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_metrics_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_metrics_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Sets a metrics configuration (specified by the metrics configuration ID) for the bucket. You can have up to 1,000 metrics configurations per bucket. If you&#39;re updating an existing metrics configuration, note that this is a full replacement of the existing metrics configuration. If you don&#39;t include the elements you want to keep, they are erased.</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutMetricsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about CloudWatch request metrics for Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a>.</p> <p>The following operations are related to <code>PutBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>DeleteBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>PutBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>ListBucketMetricsConfigurations</a> </p> </li> </ul> <p> <code>GetBucketLifecycle</code> has the following special error:</p> <ul> <li> <p>Error code: <code>TooManyConfigurations</code> </p> <ul> <li> <p>Description: You are attempting to create a new configuration but have already reached the 1,000-configuration limit.</p> </li> <li> <p>HTTP Status Code: HTTP 400 Bad Request</p> </li> </ul> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_metrics_configuration(
+    fn build_put_bucket_metrics_configuration_request(
         &self,
         input: PutBucketMetricsConfigurationRequest,
-    ) -> Result<(), RusotoError<PutBucketMetricsConfigurationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketMetricsConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -23290,29 +25618,54 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_notification`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_notification_with_metadata(
+        &self,
+        input: PutBucketNotificationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketNotificationError>> {
+        let request = self.build_put_bucket_notification_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketMetricsConfigurationError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketNotificationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_notification` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_notification(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketNotificationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_notification_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p> No longer used, see the <a>PutBucketNotificationConfiguration</a> operation.</p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_notification(
+    fn build_put_bucket_notification_request(
         &self,
         input: PutBucketNotificationRequest,
-    ) -> Result<(), RusotoError<PutBucketNotificationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotification");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23328,29 +25681,56 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_notification_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_notification_configuration_with_metadata(
+        &self,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketNotificationConfigurationError>> {
+        let request = self.build_put_bucket_notification_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketNotificationError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketNotificationConfigurationError::from_response(
+                response,
+            ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_notification_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_notification_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_notification_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Enables notifications of specified events for a bucket. For more information about event notifications, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Configuring Event Notifications</a>.</p> <p>Using this API, you can replace an existing notification configuration. The configuration is an XML file that defines the event types that you want Amazon S3 to publish and the destination where you want Amazon S3 to publish an event notification when it detects an event of the specified type.</p> <p>By default, your bucket has no event notifications configured. That is, the notification configuration will be an empty <code>NotificationConfiguration</code>.</p> <p> <code>&lt;NotificationConfiguration&gt;</code> </p> <p> <code>&lt;/NotificationConfiguration&gt;</code> </p> <p>This operation replaces the existing notification configuration with the configuration you include in the request body.</p> <p>After Amazon S3 receives this request, it first verifies that any Amazon Simple Notification Service (Amazon SNS) or Amazon Simple Queue Service (Amazon SQS) destination exists, and that the bucket owner has permission to publish to it by sending a test notification. In the case of AWS Lambda destinations, Amazon S3 verifies that the Lambda function permissions grant Amazon S3 permission to invoke the function from the Amazon S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Configuring Notifications for Amazon S3 Events</a>.</p> <p>You can disable notifications by adding the empty NotificationConfiguration element.</p> <p>By default, only the bucket owner can configure notifications on a bucket. However, bucket owners can use a bucket policy to grant permission to other users to set this configuration with <code>s3:PutBucketNotification</code> permission.</p> <note> <p>The PUT notification is an atomic operation. For example, suppose your notification configuration includes SNS topic, SQS queue, and Lambda function configurations. When you send a PUT request with this configuration, Amazon S3 sends test messages to your SNS topic. If the message fails, the entire PUT operation will fail, and Amazon S3 will not add the configuration to your bucket.</p> </note> <p> <b>Responses</b> </p> <p>If the configuration in the request body includes only one <code>TopicConfiguration</code> specifying only the <code>s3:ReducedRedundancyLostObject</code> event type, the response will also include the <code>x-amz-sns-test-message-id</code> header containing the message ID of the test notification sent to the topic.</p> <p>The following operation is related to <code>PutBucketNotificationConfiguration</code>:</p> <ul> <li> <p> <a>GetBucketNotificationConfiguration</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_notification_configuration(
+    fn build_put_bucket_notification_configuration_request(
         &self,
         input: PutBucketNotificationConfigurationRequest,
-    ) -> Result<(), RusotoError<PutBucketNotificationConfigurationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotificationConfiguration");
 
         let mut params = Params::new();
         params.put_key("notification");
@@ -23363,31 +25743,51 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_policy`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_policy_with_metadata(
+        &self,
+        input: PutBucketPolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketPolicyError>> {
+        let request = self.build_put_bucket_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketNotificationConfigurationError::from_response(
-                response,
-            ));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketPolicyError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Applies an Amazon S3 bucket policy to an Amazon S3 bucket. If you are using an identity other than the root user of the AWS account that owns the bucket, the calling identity must have the <code>PutBucketPolicy</code> permissions on the specified bucket and belong to the bucket owner&#39;s account in order to use this operation.</p> <p>If you don&#39;t have <code>PutBucketPolic</code>y permissions, Amazon S3 returns a <code>403 Access Denied</code> error. If you have the correct permissions, but you&#39;re not using an identity that belongs to the bucket owner&#39;s account, Amazon S3 returns a <code>405 Method Not Allowed</code> error.</p> <important> <p> As a security precaution, the root user of the AWS account that owns a bucket can always use this operation, even if the policy explicitly denies the root user the ability to perform this action. </p> </important> <p>For more information about bucket policies, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies and User Policies</a>.</p> <p>The following operations are related to <code>PutBucketPolicy</code>:</p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn put_bucket_policy(
+    /// Presigns the request `put_bucket_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBucketPolicyRequest,
-    ) -> Result<(), RusotoError<PutBucketPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_bucket_policy_request(&self, input: PutBucketPolicyRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketPolicy");
 
         if let Some(ref confirm_remove_self_bucket_access) = input.confirm_remove_self_bucket_access
         {
@@ -23405,29 +25805,54 @@ impl S3 for S3Client {
         request.set_params(params);
         request.set_payload(Some(input.policy.into_bytes()));
 
+        request
+    }
+
+    /// Calls `put_bucket_replication`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_replication_with_metadata(
+        &self,
+        input: PutBucketReplicationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketReplicationError>> {
+        let request = self.build_put_bucket_replication_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketPolicyError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketReplicationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_replication` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_replication(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketReplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_replication_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p> Creates a replication configuration or replaces an existing one. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/replication.html">Replication</a> in the <i>Amazon S3 Developer Guide</i>. </p> <note> <p>To perform this operation, the user or role performing the operation must have the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">iam:PassRole</a> permission.</p> </note> <p>Specify the replication configuration in the request body. In the replication configuration, you provide the name of the destination bucket where you want Amazon S3 to replicate objects, the IAM role that Amazon S3 can assume to replicate objects on your behalf, and other relevant information.</p> <p>A replication configuration must include at least one rule, and can contain a maximum of 1,000. Each rule identifies a subset of objects to replicate by filtering the objects in the source bucket. To choose additional subsets of objects to replicate, add a rule for each subset. All rules must specify the same destination bucket.</p> <p>To specify a subset of the objects in the source bucket to apply a replication rule to, add the Filter element as a child of the Rule element. You can filter objects based on an object key prefix, one or more object tags, or both. When you add the Filter element in the configuration, you must also add the following elements: <code>DeleteMarkerReplication</code>, <code>Status</code>, and <code>Priority</code>.</p> <p>For information about enabling versioning on a bucket, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/Versioning.html">Using Versioning</a>.</p> <p>By default, a resource owner, in this case the AWS account that created the bucket, can perform this operation. The resource owner can also grant others permissions to perform the operation. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> <b>Handling Replication of Encrypted Objects</b> </p> <p>By default, Amazon S3 doesn&#39;t replicate objects that are stored at rest using server-side encryption with CMKs stored in AWS KMS. To replicate AWS KMS-encrypted objects, add the following: <code>SourceSelectionCriteria</code>, <code>SseKmsEncryptedObjects</code>, <code>Status</code>, <code>EncryptionConfiguration</code>, and <code>ReplicaKmsKeyID</code>. For information about replication configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/replication-config-for-kms-objects.html">Replicating Objects Created with SSE Using CMKs stored in AWS KMS</a>.</p> <p>For information on <code>PutBucketReplication</code> errors, see <a>ReplicationErrorCodeList</a> </p> <p>The following operations are related to <code>PutBucketReplication</code>:</p> <ul> <li> <p> <a>GetBucketReplication</a> </p> </li> <li> <p> <a>DeleteBucketReplication</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_replication(
+    fn build_put_bucket_replication_request(
         &self,
         input: PutBucketReplicationRequest,
-    ) -> Result<(), RusotoError<PutBucketReplicationError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketReplication");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23448,29 +25873,54 @@ impl S3 for S3Client {
         request.set_payload(Some(writer.into_inner()));
         request.set_content_md5_header();
 
+        request
+    }
+
+    /// Calls `put_bucket_request_payment`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_request_payment_with_metadata(
+        &self,
+        input: PutBucketRequestPaymentRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketRequestPaymentError>> {
+        let request = self.build_put_bucket_request_payment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketReplicationError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketRequestPaymentError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_request_payment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_request_payment(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketRequestPaymentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_request_payment_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Sets the request payment configuration for a bucket. By default, the bucket owner pays for downloads from the bucket. This configuration parameter enables the bucket owner (only) to specify that the person requesting the download will be charged for the download. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/RequesterPaysBuckets.html">Requester Pays Buckets</a>.</p> <p>The following operations are related to <code>PutBucketRequestPayment</code>:</p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>GetBucketRequestPayment</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_request_payment(
+    fn build_put_bucket_request_payment_request(
         &self,
         input: PutBucketRequestPaymentRequest,
-    ) -> Result<(), RusotoError<PutBucketRequestPaymentError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketRequestPayment");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23486,29 +25936,51 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_tagging`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_tagging_with_metadata(
+        &self,
+        input: PutBucketTaggingRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketTaggingError>> {
+        let request = self.build_put_bucket_tagging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketRequestPaymentError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketTaggingError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Sets the tags for a bucket.</p> <p>Use tags to organize your AWS bill to reflect your own cost structure. To do this, sign up to get your AWS account bill with tag key values included. Then, to see the cost of combined resources, organize your billing information according to resources with the same tag key values. For example, you can tag several resources with a specific application name, and then organize your billing information to see the total cost of that application across several services. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Cost Allocation and Tagging</a>.</p> <note> <p>Within a bucket, if you add a tag that has the same key as an existing tag, the new value overwrites the old value. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/CostAllocTagging.html">Using Cost Allocation in Amazon S3 Bucket Tags</a>.</p> </note> <p>To use this operation, you must have permissions to perform the <code>s3:PutBucketTagging</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> <code>PutBucketTagging</code> has the following special errors:</p> <ul> <li> <p>Error code: <code>InvalidTagError</code> </p> <ul> <li> <p>Description: The tag provided was not a valid tag. This error can occur if the tag did not pass input validation. For information about tag restrictions, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2//allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> and <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2//aws-tag-restrictions.html">AWS-Generated Cost Allocation Tag Restrictions</a>.</p> </li> </ul> </li> <li> <p>Error code: <code>MalformedXMLError</code> </p> <ul> <li> <p>Description: The XML provided does not match the schema.</p> </li> </ul> </li> <li> <p>Error code: <code>OperationAbortedError </code> </p> <ul> <li> <p>Description: A conflicting conditional operation is currently in progress against this resource. Please try again.</p> </li> </ul> </li> <li> <p>Error code: <code>InternalError</code> </p> <ul> <li> <p>Description: The service was unable to apply the provided tag to the bucket.</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>PutBucketTagging</code>:</p> <ul> <li> <p> <a>GetBucketTagging</a> </p> </li> <li> <p> <a>DeleteBucketTagging</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn put_bucket_tagging(
+    /// Presigns the request `put_bucket_tagging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_tagging(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBucketTaggingRequest,
-    ) -> Result<(), RusotoError<PutBucketTaggingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_tagging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_bucket_tagging_request(&self, input: PutBucketTaggingRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketTagging");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23521,29 +25993,54 @@ impl S3 for S3Client {
         request.set_payload(Some(writer.into_inner()));
         request.set_content_md5_header();
 
+        request
+    }
+
+    /// Calls `put_bucket_versioning`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_versioning_with_metadata(
+        &self,
+        input: PutBucketVersioningRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketVersioningError>> {
+        let request = self.build_put_bucket_versioning_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketTaggingError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketVersioningError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `put_bucket_versioning` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_versioning(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutBucketVersioningRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_versioning_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Sets the versioning state of an existing bucket. To set the versioning state, you must be the bucket owner.</p> <p>You can set the versioning state with one of the following values:</p> <p> <b>Enabled</b>—Enables versioning for the objects in the bucket. All objects added to the bucket receive a unique version ID.</p> <p> <b>Suspended</b>—Disables versioning for the objects in the bucket. All objects added to the bucket receive the version ID null.</p> <p>If the versioning state has never been set on a bucket, it has no versioning state; a <a>GetBucketVersioning</a> request does not return a versioning state value.</p> <p>If the bucket owner enables MFA Delete in the bucket versioning configuration, the bucket owner must include the <code>x-amz-mfa request</code> header and the <code>Status</code> and the <code>MfaDelete</code> request elements in a request to set the versioning state of the bucket.</p> <important> <p>If you have an object expiration lifecycle policy in your non-versioned bucket and you want to maintain the same permanent delete behavior when you enable versioning, you must add a noncurrent expiration policy. The noncurrent expiration lifecycle policy will manage the deletes of the noncurrent object versions in the version-enabled bucket. (A version-enabled bucket maintains one current and zero or more noncurrent object versions.) For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html#lifecycle-and-other-bucket-config">Lifecycle and Versioning</a>.</p> </important> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> <li> <p> <a>GetBucketVersioning</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_bucket_versioning(
+    fn build_put_bucket_versioning_request(
         &self,
         input: PutBucketVersioningRequest,
-    ) -> Result<(), RusotoError<PutBucketVersioningError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketVersioning");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23563,29 +26060,51 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_bucket_website`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_bucket_website_with_metadata(
+        &self,
+        input: PutBucketWebsiteRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBucketWebsiteError>> {
+        let request = self.build_put_bucket_website_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketVersioningError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutBucketWebsiteError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Sets the configuration of the website that is specified in the <code>website</code> subresource. To configure a bucket as a website, you can add this subresource on the bucket with website configuration information such as the file name of the index document and any redirect rules. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/WebsiteHosting.html">Hosting Websites on Amazon S3</a>.</p> <p>This PUT operation requires the <code>S3:PutBucketWebsite</code> permission. By default, only the bucket owner can configure the website attached to a bucket; however, bucket owners can allow other users to set the website configuration by writing a bucket policy that grants them the <code>S3:PutBucketWebsite</code> permission.</p> <p>To redirect all website requests sent to the bucket&#39;s website endpoint, you add a website configuration with the following elements. Because all requests are sent to another website, you don&#39;t need to provide index document name for the bucket.</p> <ul> <li> <p> <code>WebsiteConfiguration</code> </p> </li> <li> <p> <code>RedirectAllRequestsTo</code> </p> </li> <li> <p> <code>HostName</code> </p> </li> <li> <p> <code>Protocol</code> </p> </li> </ul> <p>If you want granular control over redirects, you can use the following elements to add routing rules that describe conditions for redirecting requests and information about the redirect destination. In this case, the website configuration must provide an index document for the bucket, because some requests might not be redirected. </p> <ul> <li> <p> <code>WebsiteConfiguration</code> </p> </li> <li> <p> <code>IndexDocument</code> </p> </li> <li> <p> <code>Suffix</code> </p> </li> <li> <p> <code>ErrorDocument</code> </p> </li> <li> <p> <code>Key</code> </p> </li> <li> <p> <code>RoutingRules</code> </p> </li> <li> <p> <code>RoutingRule</code> </p> </li> <li> <p> <code>Condition</code> </p> </li> <li> <p> <code>HttpErrorCodeReturnedEquals</code> </p> </li> <li> <p> <code>KeyPrefixEquals</code> </p> </li> <li> <p> <code>Redirect</code> </p> </li> <li> <p> <code>Protocol</code> </p> </li> <li> <p> <code>HostName</code> </p> </li> <li> <p> <code>ReplaceKeyPrefixWith</code> </p> </li> <li> <p> <code>ReplaceKeyWith</code> </p> </li> <li> <p> <code>HttpRedirectCode</code> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn put_bucket_website(
+    /// Presigns the request `put_bucket_website` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_bucket_website(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBucketWebsiteRequest,
-    ) -> Result<(), RusotoError<PutBucketWebsiteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_bucket_website_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_bucket_website_request(&self, input: PutBucketWebsiteRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketWebsite");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23601,33 +26120,126 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_object`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_object_with_metadata(
+        &self,
+        input: PutObjectRequest,
+    ) -> Result<(PutObjectOutput, ResponseMetadata), RusotoError<PutObjectError>> {
+        let request = self.build_put_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutBucketWebsiteError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutObjectError::from_response(response));
         }
 
-        std::mem::drop(response);
-        Ok(())
-    }
-
-    /// <p><p>Adds an object to a bucket. You must have WRITE permissions on a bucket to add an object to it.</p> <p>Amazon S3 never adds partial objects; if you receive a success response, Amazon S3 added the entire object to the bucket.</p> <p>Amazon S3 is a distributed system. If it receives multiple write requests for the same object simultaneously, it overwrites all but the last object written. Amazon S3 does not provide object locking; if you need this, make sure to build it into your application layer or use versioning instead.</p> <p>To ensure that data is not corrupted traversing the network, use the <code>Content-MD5</code> header. When you use this header, Amazon S3 checks the object against the provided MD5 value and, if they do not match, returns an error. Additionally, you can calculate the MD5 while putting an object to Amazon S3 and compare the returned ETag to the calculated MD5 value.</p> <note> <p>To configure your application to send the request headers before sending the request body, use the <code>100-continue</code> HTTP status code. For PUT operations, this helps you avoid sending the message body if the message is rejected based on the headers (for example, because authentication fails or a redirect occurs). For more information on the <code>100-continue</code> HTTP status code, see Section 8.2.3 of <a href="http://www.ietf.org/rfc/rfc2616.txt">http://www.ietf.org/rfc/rfc2616.txt</a>.</p> </note> <p>You can optionally request server-side encryption. With server-side encryption, Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts the data when you access it. You have the option to provide your own encryption key or use AWS managed encryption keys. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingServerSideEncryption.html">Using Server-Side Encryption</a>.</p> <dl> <dt>Access Permissions</dt> <dd> <p>You can optionally specify the accounts or groups that should be granted specific permissions on the new object. There are two ways to grant the permissions using the request headers:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> </dd> <dt>Server-Side- Encryption-Specific Request Headers</dt> <dd> <p>You can optionally tell Amazon S3 to encrypt data at rest using server-side encryption. Server-side encryption is for data encryption at rest. Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it when you access it. The option you use depends on whether you want to use AWS managed encryption keys or provide your own encryption key. </p> <ul> <li> <p>Use encryption keys managed by Amazon S3 or customer master keys (CMKs) stored in AWS Key Management Service (AWS KMS) – If you want AWS to manage the keys used to encrypt data, specify the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption</p> </li> <li> <p>x-amz-server-side-encryption-aws-kms-key-id</p> </li> <li> <p>x-amz-server-side-encryption-context</p> </li> </ul> <note> <p>If you specify <code>x-amz-server-side-encryption:aws:kms</code>, but don&#39;t provide <code>x-amz-server-side-encryption-aws-kms-key-id</code>, Amazon S3 uses the AWS managed CMK in AWS KMS to protect the data. If you want to use a customer managed AWS KMS CMK, you must provide the <code>x-amz-server-side-encryption-aws-kms-key-id</code> of the symmetric customer managed CMK. Amazon S3 only supports symmetric CMKs and not asymmetric CMKs. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> </note> <important> <p>All GET and PUT requests for an object protected by AWS KMS fail if you don&#39;t make them with SSL or by using SigV4.</p> </important> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS</a>.</p> </li> <li> <p>Use customer-provided encryption keys – If you want to manage your own encryption keys, provide all the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about server-side encryption with CMKs stored in KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS</a>.</p> </li> </ul> </dd> <dt>Access-Control-List (ACL)-Specific Request Headers</dt> <dd> <p>You also can use the following access control–related headers with this operation. By default, all objects are private. Only the owner has full access control. When adding a new object, you can grant permissions to individual AWS accounts or to predefined groups defined by Amazon S3. These permissions are then added to the Access Control List (ACL) on the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>. With this operation, you can grant access permissions using one of the following two methods:</p> <ul> <li> <p>Specify a canned ACL (<code>x-amz-acl</code>) — Amazon S3 supports a set of predefined ACLs, known as canned ACLs. Each canned ACL has a predefined set of grantees and permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly — To explicitly grant access permissions to specific AWS accounts or groups, use the following headers. Each header maps to specific permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>. In the header, you specify a list of grantees who get the specific permission. To grant permissions explicitly use:</p> <ul> <li> <p>x-amz-grant-read</p> </li> <li> <p>x-amz-grant-write</p> </li> <li> <p>x-amz-grant-read-acp</p> </li> <li> <p>x-amz-grant-write-acp</p> </li> <li> <p>x-amz-grant-full-control</p> </li> </ul> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> <important> <p>Using email addresses to specify a grantee is only supported in the following AWS Regions: </p> <ul> <li> <p>US East (N. Virginia)</p> </li> <li> <p>US West (N. California)</p> </li> <li> <p> US West (Oregon)</p> </li> <li> <p> Asia Pacific (Singapore)</p> </li> <li> <p>Asia Pacific (Sydney)</p> </li> <li> <p>Asia Pacific (Tokyo)</p> </li> <li> <p>EU (Ireland)</p> </li> <li> <p>South America (São Paulo)</p> </li> </ul> <p>For a list of all the Amazon S3 supported Regions and endpoints, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html#s3_region">Regions and Endpoints</a> in the AWS General Reference</p> </important> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants the AWS accounts identified by email addresses permissions to read object data and its metadata:</p> <p> <code>x-amz-grant-read: emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> </dd> <dt>Server-Side- Encryption-Specific Request Headers</dt> <dd> <p>You can optionally tell Amazon S3 to encrypt data at rest using server-side encryption. Server-side encryption is for data encryption at rest. Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it when you access it. The option you use depends on whether you want to use AWS-managed encryption keys or provide your own encryption key. </p> <ul> <li> <p>Use encryption keys managed by Amazon S3 or customer master keys (CMKs) stored in AWS Key Management Service (AWS KMS) – If you want AWS to manage the keys used to encrypt data, specify the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption</p> </li> <li> <p>x-amz-server-side-encryption-aws-kms-key-id</p> </li> <li> <p>x-amz-server-side-encryption-context</p> </li> </ul> <note> <p>If you specify <code>x-amz-server-side-encryption:aws:kms</code>, but don&#39;t provide <code>x-amz-server-side-encryption-aws-kms-key-id</code>, Amazon S3 uses the AWS managed CMK in AWS KMS to protect the data. If you want to use a customer managed AWS KMS CMK, you must provide the <code>x-amz-server-side-encryption-aws-kms-key-id</code> of the symmetric customer managed CMK. Amazon S3 only supports symmetric CMKs and not asymmetric CMKs. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> </note> <important> <p>All GET and PUT requests for an object protected by AWS KMS fail if you don&#39;t make them with SSL or by using SigV4.</p> </important> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS KMS</a>.</p> </li> <li> <p>Use customer-provided encryption keys – If you want to manage your own encryption keys, provide all the following headers in the request.</p> <note> <p>If you use this feature, the ETag value that Amazon S3 returns in the response is not the MD5 of the object.</p> </note> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS KMS</a>.</p> </li> </ul> </dd> </dl> <p> <b>Storage Class Options</b> </p> <p>By default, Amazon S3 uses the Standard storage class to store newly created objects. The Standard storage class provides high durability and high availability. You can specify other storage classes depending on the performance needs. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-class-intro.html">Storage Classes</a> in the Amazon Simple Storage Service Developer Guide.</p> <p> <b>Versioning</b> </p> <p>If you enable versioning for a bucket, Amazon S3 automatically generates a unique version ID for the object being stored. Amazon S3 returns this ID in the response using the <code>x-amz-version-id response</code> header. If versioning is suspended, Amazon S3 always uses null as the version ID for the object stored. For more information about returning the versioning state of a bucket, see <a>GetBucketVersioning</a>. If you enable versioning for a bucket, when Amazon S3 receives multiple write requests for the same object simultaneously, it stores all of the objects.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CopyObject</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn put_object(
-        &self,
-        input: PutObjectRequest,
-    ) -> Result<PutObjectOutput, RusotoError<PutObjectError>> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref acl) = input.acl {
-            request.add_header("x-amz-acl", &acl.to_string());
-        }
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
+        let mut result;
+
+        if xml_response.body.is_empty() {
+            result = PutObjectOutput::default();
+        } else {
+            let reader = EventReader::new_with_config(
+                xml_response.body.as_ref(),
+                ParserConfig::new().trim_whitespace(false),
+            );
+            let mut stack = XmlResponse::new(reader.into_iter().peekable());
+            let _start_document = stack.next();
+            let actual_tag_name = peek_at_name(&mut stack)?;
+            result = PutObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+        }
+        if let Some(e_tag) = response.headers.get("ETag") {
+            let value = e_tag.to_owned();
+            result.e_tag = Some(value)
+        };
+        if let Some(expiration) = response.headers.get("x-amz-expiration") {
+            let value = expiration.to_owned();
+            result.expiration = Some(value)
+        };
+        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+            let value = request_charged.to_owned();
+            result.request_charged = Some(value)
+        };
+        if let Some(sse_customer_algorithm) = response
+            .headers
+            .get("x-amz-server-side-encryption-customer-algorithm")
+        {
+            let value = sse_customer_algorithm.to_owned();
+            result.sse_customer_algorithm = Some(value)
+        };
+        if let Some(sse_customer_key_md5) = response
+            .headers
+            .get("x-amz-server-side-encryption-customer-key-MD5")
+        {
+            let value = sse_customer_key_md5.to_owned();
+            result.sse_customer_key_md5 = Some(value)
+        };
+        if let Some(ssekms_encryption_context) =
+            response.headers.get("x-amz-server-side-encryption-context")
+        {
+            let value = ssekms_encryption_context.to_owned();
+            result.ssekms_encryption_context = Some(value)
+        };
+        if let Some(ssekms_key_id) = response
+            .headers
+            .get("x-amz-server-side-encryption-aws-kms-key-id")
+        {
+            let value = ssekms_key_id.to_owned();
+            result.ssekms_key_id = Some(value)
+        };
+        if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
+            let value = server_side_encryption.to_owned();
+            result.server_side_encryption = Some(value)
+        };
+        if let Some(version_id) = response.headers.get("x-amz-version-id") {
+            let value = version_id.to_owned();
+            result.version_id = Some(value)
+        }; // parse non-payload
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `put_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_object(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutObjectRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_object_request(&self, input: PutObjectRequest) -> SignedRequest {
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObject");
+
+        if let Some(ref acl) = input.acl {
+            request.add_header("x-amz-acl", &acl.to_string());
+        }
+
+        if let Some(ref bucket_key_enabled) = input.bucket_key_enabled {
+            request.add_header(
+                "x-amz-server-side-encryption-bucket-key-enabled",
+                &bucket_key_enabled.to_string(),
+            );
+        }
 
         if let Some(ref cache_control) = input.cache_control {
             request.add_header("Cache-Control", &cache_control.to_string());
@@ -23767,21 +26379,39 @@ impl S3 for S3Client {
             request.set_payload_stream(__body);
         }
 
+        request
+    }
+
+    /// Calls `put_object_acl`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_object_acl_with_metadata(
+        &self,
+        input: PutObjectAclRequest,
+    ) -> Result<(PutObjectAclOutput, ResponseMetadata), RusotoError<PutObjectAclError>> {
+        let request = self.build_put_object_acl_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutObjectError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutObjectAclError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = PutObjectOutput::default();
+            result = PutObjectAclOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -23790,67 +26420,31 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = PutObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result = PutObjectAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
-        if let Some(e_tag) = response.headers.get("ETag") {
-            let value = e_tag.to_owned();
-            result.e_tag = Some(value)
-        };
-        if let Some(expiration) = response.headers.get("x-amz-expiration") {
-            let value = expiration.to_owned();
-            result.expiration = Some(value)
-        };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
             result.request_charged = Some(value)
-        };
-        if let Some(sse_customer_algorithm) = response
-            .headers
-            .get("x-amz-server-side-encryption-customer-algorithm")
-        {
-            let value = sse_customer_algorithm.to_owned();
-            result.sse_customer_algorithm = Some(value)
-        };
-        if let Some(sse_customer_key_md5) = response
-            .headers
-            .get("x-amz-server-side-encryption-customer-key-MD5")
-        {
-            let value = sse_customer_key_md5.to_owned();
-            result.sse_customer_key_md5 = Some(value)
-        };
-        if let Some(ssekms_encryption_context) =
-            response.headers.get("x-amz-server-side-encryption-context")
-        {
-            let value = ssekms_encryption_context.to_owned();
-            result.ssekms_encryption_context = Some(value)
-        };
-        if let Some(ssekms_key_id) = response
-            .headers
-            .get("x-amz-server-side-encryption-aws-kms-key-id")
-        {
-            let value = ssekms_key_id.to_owned();
-            result.ssekms_key_id = Some(value)
-        };
-        if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
-            let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
-        };
-        if let Some(version_id) = response.headers.get("x-amz-version-id") {
-            let value = version_id.to_owned();
-            result.version_id = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Uses the <code>acl</code> subresource to set the access control list (ACL) permissions for an object that already exists in a bucket. You must have <code>WRITE_ACP</code> permission to set the ACL of an object.</p> <p>Depending on your application needs, you can choose to set the ACL on an object using either the request body or the headers. For example, if you have an existing application that updates a bucket ACL using the request body, you can continue to use that approach.</p> <p> <b>Access Permissions</b> </p> <p>You can set access permissions using one of the following methods:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. Amazon S3 supports a set of predefined ACLs, known as canned ACLs. Each canned ACL has a predefined set of grantees and permissions. Specify the canned ACL name as the value of <code>x-amz-ac</code>l. If you use this header, you cannot use other access control-specific headers in your request. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. When using these headers, you specify explicit access permissions and grantees (AWS accounts or Amazon S3 groups) who will receive the permission. If you use these ACL-specific headers, you cannot use <code>x-amz-acl</code> header to set a canned ACL. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants list objects permission to the two AWS accounts identified by their email addresses.</p> <p> <code>x-amz-grant-read: emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> <p> <b>Grantee Values</b> </p> <p>You can specify the person (grantee) to whom you&#39;re assigning access rights (using request elements) in the following ways:</p> <ul> <li> <p>By Email address:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;AmazonCustomerByEmail&quot;&gt;&lt;EmailAddress&gt;&lt;&gt;Grantees@email.com&lt;&gt;&lt;/EmailAddress&gt;lt;/Grantee&gt;</code> </p> <p>The grantee is resolved to the CanonicalUser and, in a response to a GET Object acl request, appears as the CanonicalUser.</p> </li> <li> <p>By the person&#39;s ID:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;CanonicalUser&quot;&gt;&lt;ID&gt;&lt;&gt;ID&lt;&gt;&lt;/ID&gt;&lt;DisplayName&gt;&lt;&gt;GranteesEmail&lt;&gt;&lt;/DisplayName&gt; &lt;/Grantee&gt;</code> </p> <p>DisplayName is optional and ignored in the request.</p> </li> <li> <p>By URI:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;Group&quot;&gt;&lt;URI&gt;&lt;&gt;http://acs.amazonaws.com/groups/global/AuthenticatedUsers&lt;&gt;&lt;/URI&gt;&lt;/Grantee&gt;</code> </p> </li> </ul> <p> <b>Versioning</b> </p> <p>The ACL of an object is set at the object version level. By default, PUT sets the ACL of the current version of an object. To set the ACL of a different version, use the <code>versionId</code> subresource.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CopyObject</a> </p> </li> <li> <p> <a>GetObject</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn put_object_acl(
+    /// Presigns the request `put_object_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_object_acl(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutObjectAclRequest,
-    ) -> Result<PutObjectAclOutput, RusotoError<PutObjectAclError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_object_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_object_acl_request(&self, input: PutObjectAclRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectAcl");
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
@@ -23901,21 +26495,40 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        request
+    }
+
+    /// Calls `put_object_legal_hold`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_object_legal_hold_with_metadata(
+        &self,
+        input: PutObjectLegalHoldRequest,
+    ) -> Result<(PutObjectLegalHoldOutput, ResponseMetadata), RusotoError<PutObjectLegalHoldError>>
+    {
+        let request = self.build_put_object_legal_hold_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutObjectAclError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutObjectLegalHoldError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = PutObjectAclOutput::default();
+            result = PutObjectLegalHoldOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -23924,24 +26537,35 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = PutObjectAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result =
+                PutObjectLegalHoldOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
             result.request_charged = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `put_object_legal_hold` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_object_legal_hold(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutObjectLegalHoldRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_object_legal_hold_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Applies a Legal Hold configuration to the specified object.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_object_legal_hold(
+    fn build_put_object_legal_hold_request(
         &self,
         input: PutObjectLegalHoldRequest,
-    ) -> Result<PutObjectLegalHoldOutput, RusotoError<PutObjectLegalHoldError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLegalHold");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -23968,21 +26592,42 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        request
+    }
+
+    /// Calls `put_object_lock_configuration`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_object_lock_configuration_with_metadata(
+        &self,
+        input: PutObjectLockConfigurationRequest,
+    ) -> Result<
+        (PutObjectLockConfigurationOutput, ResponseMetadata),
+        RusotoError<PutObjectLockConfigurationError>,
+    > {
+        let request = self.build_put_object_lock_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutObjectLegalHoldError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutObjectLockConfigurationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = PutObjectLegalHoldOutput::default();
+            result = PutObjectLockConfigurationOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -23991,26 +26636,37 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                PutObjectLegalHoldOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result = PutObjectLockConfigurationOutputDeserializer::deserialize(
+                &actual_tag_name,
+                &mut stack,
+            )?;
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
             result.request_charged = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `put_object_lock_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_object_lock_configuration(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutObjectLockConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_object_lock_configuration_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Places an Object Lock configuration on the specified bucket. The rule specified in the Object Lock configuration will be applied by default to every new object placed in the specified bucket.</p> <note> <p> <code>DefaultRetention</code> requires either Days or Years. You can&#39;t specify both at the same time.</p> </note> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_object_lock_configuration(
+    fn build_put_object_lock_configuration_request(
         &self,
         input: PutObjectLockConfigurationRequest,
-    ) -> Result<PutObjectLockConfigurationOutput, RusotoError<PutObjectLockConfigurationError>>
-    {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLockConfiguration");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -24038,21 +26694,40 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        request
+    }
+
+    /// Calls `put_object_retention`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_object_retention_with_metadata(
+        &self,
+        input: PutObjectRetentionRequest,
+    ) -> Result<(PutObjectRetentionOutput, ResponseMetadata), RusotoError<PutObjectRetentionError>>
+    {
+        let request = self.build_put_object_retention_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutObjectLockConfigurationError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutObjectRetentionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = PutObjectLockConfigurationOutput::default();
+            result = PutObjectRetentionOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -24061,27 +26736,35 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = PutObjectLockConfigurationOutputDeserializer::deserialize(
-                &actual_tag_name,
-                &mut stack,
-            )?;
+            result =
+                PutObjectRetentionOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
             result.request_charged = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `put_object_retention` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_object_retention(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutObjectRetentionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_object_retention_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Places an Object Retention configuration on an object.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_object_retention(
+    fn build_put_object_retention_request(
         &self,
         input: PutObjectRetentionRequest,
-    ) -> Result<PutObjectRetentionOutput, RusotoError<PutObjectRetentionError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectRetention");
 
         if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
             request.add_header(
@@ -24115,21 +26798,40 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        request
+    }
+
+    /// Calls `put_object_tagging`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_object_tagging_with_metadata(
+        &self,
+        input: PutObjectTaggingRequest,
+    ) -> Result<(PutObjectTaggingOutput, ResponseMetadata), RusotoError<PutObjectTaggingError>>
+    {
+        let request = self.build_put_object_tagging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutObjectRetentionError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutObjectTaggingError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = PutObjectRetentionOutput::default();
+            result = PutObjectTaggingOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -24138,25 +26840,31 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                PutObjectRetentionOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result = PutObjectTaggingOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
-        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-            let value = request_charged.to_owned();
-            result.request_charged = Some(value)
+        if let Some(version_id) = response.headers.get("x-amz-version-id") {
+            let value = version_id.to_owned();
+            result.version_id = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Sets the supplied tag-set to an object that already exists in a bucket</p> <p>A tag is a key-value pair. You can associate tags with an object by sending a PUT request against the tagging subresource that is associated with the object. You can retrieve tags by sending a GET request. For more information, see <a>GetObjectTagging</a>.</p> <p>For tagging-related restrictions related to characters and encodings, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">Tag Restrictions</a>. Note that Amazon S3 limits the maximum number of tags to 10 tags per object.</p> <p>To use this operation, you must have permission to perform the <code>s3:PutObjectTagging</code> action. By default, the bucket owner has this permission and can grant this permission to others.</p> <p>To put tags of any other version, use the <code>versionId</code> query parameter. You also need permission for the <code>s3:PutObjectVersionTagging</code> action.</p> <p>For information about the Amazon S3 object tagging feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-tagging.html">Object Tagging</a>.</p> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: InvalidTagError </i> </p> </li> <li> <p> <i>Cause: The tag provided was not a valid tag. This error can occur if the tag did not pass input validation. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-tagging.html">Object Tagging</a>.</i> </p> </li> </ul> </li> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: MalformedXMLError </i> </p> </li> <li> <p> <i>Cause: The XML provided does not match the schema.</i> </p> </li> </ul> </li> <li> <ul> <li> <p> <i>Code: OperationAbortedError </i> </p> </li> <li> <p> <i>Cause: A conflicting conditional operation is currently in progress against this resource. Please try again.</i> </p> </li> </ul> </li> <li> <ul> <li> <p> <i>Code: InternalError</i> </p> </li> <li> <p> <i>Cause: The service was unable to apply the provided tag to the object.</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetObjectTagging</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn put_object_tagging(
+    /// Presigns the request `put_object_tagging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_object_tagging(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutObjectTaggingRequest,
-    ) -> Result<PutObjectTaggingOutput, RusotoError<PutObjectTaggingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_object_tagging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_put_object_tagging_request(&self, input: PutObjectTaggingRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectTagging");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -24171,47 +26879,54 @@ impl S3 for S3Client {
         TaggingSerializer::serialize(&mut writer, "Tagging", &input.tagging);
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `put_public_access_block`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn put_public_access_block_with_metadata(
+        &self,
+        input: PutPublicAccessBlockRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutPublicAccessBlockError>> {
+        let request = self.build_put_public_access_block_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutObjectTaggingError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(PutPublicAccessBlockError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-        let mut result;
+        std::mem::drop(response);
+        Ok(((), metadata))
+    }
 
-        if xml_response.body.is_empty() {
-            result = PutObjectTaggingOutput::default();
-        } else {
-            let reader = EventReader::new_with_config(
-                xml_response.body.as_ref(),
-                ParserConfig::new().trim_whitespace(false),
-            );
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            let _start_document = stack.next();
-            let actual_tag_name = peek_at_name(&mut stack)?;
-            result = PutObjectTaggingOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-        }
-        if let Some(version_id) = response.headers.get("x-amz-version-id") {
-            let value = version_id.to_owned();
-            result.version_id = Some(value)
-        }; // parse non-payload
-        Ok(result)
+    /// Presigns the request `put_public_access_block` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_public_access_block(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutPublicAccessBlockRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_public_access_block_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Creates or modifies the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket. To use this operation, you must have the <code>s3:PutBucketPublicAccessBlock</code> permission. For more information about Amazon S3 permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>.</p> <important> <p>When Amazon S3 evaluates the <code>PublicAccessBlock</code> configuration for a bucket or an object, it checks the <code>PublicAccessBlock</code> configuration for both the bucket (or the bucket that contains the object) and the bucket owner&#39;s account. If the <code>PublicAccessBlock</code> configurations are different between the bucket and the account, Amazon S3 uses the most restrictive combination of the bucket-level and account-level settings.</p> </important> <p>For more information about when Amazon S3 considers a bucket or an object public, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html#access-control-block-public-access-policy-status">The Meaning of &quot;Public&quot;</a>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetPublicAccessBlock</a> </p> </li> <li> <p> <a>DeletePublicAccessBlock</a> </p> </li> <li> <p> <a>GetBucketPolicyStatus</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html">Using Amazon S3 Block Public Access</a> </p> </li> </ul></p>
     #[allow(unused_variables, warnings)]
-    async fn put_public_access_block(
+    fn build_put_public_access_block_request(
         &self,
         input: PutPublicAccessBlockRequest,
-    ) -> Result<(), RusotoError<PutPublicAccessBlockError>> {
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutPublicAccessBlock");
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
@@ -24227,29 +26942,76 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `restore_object`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn restore_object_with_metadata(
+        &self,
+        input: RestoreObjectRequest,
+    ) -> Result<(RestoreObjectOutput, ResponseMetadata), RusotoError<RestoreObjectError>> {
+        let request = self.build_restore_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(PutPublicAccessBlockError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(RestoreObjectError::from_response(response));
         }
 
-        std::mem::drop(response);
-        Ok(())
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
+        let mut result;
+
+        if xml_response.body.is_empty() {
+            result = RestoreObjectOutput::default();
+        } else {
+            let reader = EventReader::new_with_config(
+                xml_response.body.as_ref(),
+                ParserConfig::new().trim_whitespace(false),
+            );
+            let mut stack = XmlResponse::new(reader.into_iter().peekable());
+            let _start_document = stack.next();
+            let actual_tag_name = peek_at_name(&mut stack)?;
+            result = RestoreObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+        }
+        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+            let value = request_charged.to_owned();
+            result.request_charged = Some(value)
+        };
+        if let Some(restore_output_path) = response.headers.get("x-amz-restore-output-path") {
+            let value = restore_output_path.to_owned();
+            result.restore_output_path = Some(value)
+        }; // parse non-payload
+        Ok((result, metadata))
     }
 
-    /// <p><p>Restores an archived copy of an object back into Amazon S3</p> <p>This operation performs the following types of requests: </p> <ul> <li> <p> <code>select</code> - Perform a select query on an archived object</p> </li> <li> <p> <code>restore an archive</code> - Restore an archived object</p> </li> </ul> <p>To use this operation, you must have permissions to perform the <code>s3:RestoreObject</code> and <code>s3:GetObject</code> actions. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p> <b>Querying Archives with Select Requests</b> </p> <p>You use a select type of request to perform SQL queries on archived objects. The archived objects that are being queried by the select request must be formatted as uncompressed comma-separated values (CSV) files. You can run queries and custom analytics on your archived data without having to restore your data to a hotter Amazon S3 tier. For an overview about select requests, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/querying-glacier-archives.html">Querying Archived Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>When making a select request, do the following:</p> <ul> <li> <p>Define an output location for the select query&#39;s output. This must be an Amazon S3 bucket in the same AWS Region as the bucket that contains the archive object that is being queried. The AWS account that initiates the job must have permissions to write to the S3 bucket. You can specify the storage class and encryption for the output objects stored in the bucket. For more information about output, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/querying-glacier-archives.html">Querying Archived Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>For more information about the <code>S3</code> structure in the request body, see the following:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Managing Access with ACLs</a> in the <i>Amazon Simple Storage Service Developer Guide</i> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Protecting Data Using Server-Side Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i> </p> </li> </ul> </li> <li> <p>Define the SQL expression for the <code>SELECT</code> type of restoration for your query in the request body&#39;s <code>SelectParameters</code> structure. You can use expressions like the following examples.</p> <ul> <li> <p>The following expression returns all records from the specified object.</p> <p> <code>SELECT * FROM Object</code> </p> </li> <li> <p>Assuming that you are not using any headers for data stored in the object, you can specify columns with positional headers.</p> <p> <code>SELECT s.<em>1, s.</em>2 FROM Object s WHERE s.<em>3 &gt; 100</code> </p> </li> <li> <p>If you have headers and you set the <code>fileHeaderInfo</code> in the <code>CSV</code> structure in the request body to <code>USE</code>, you can specify headers in the query. (If you set the <code>fileHeaderInfo</code> field to <code>IGNORE</code>, the first row is skipped for the query.) You cannot mix ordinal positions with header column names. </p> <p> <code>SELECT s.Id, s.FirstName, s.SSN FROM S3Object s</code> </p> </li> </ul> </li> </ul> <p>For more information about using SQL with Glacier Select restore, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-glacier-select-sql-reference.html">SQL Reference for Amazon S3 Select and Glacier Select</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>When making a select request, you can also do the following:</p> <ul> <li> <p>To expedite your queries, specify the <code>Expedited</code> tier. For more information about tiers, see &quot;Restoring Archives,&quot; later in this topic.</p> </li> <li> <p>Specify details about the data serialization format of both the input object that is being queried and the serialization of the CSV-encoded query results.</p> </li> </ul> <p>The following are additional important facts about the select feature:</p> <ul> <li> <p>The output results are new Amazon S3 objects. Unlike archive retrievals, they are stored until explicitly deleted-manually or through a lifecycle policy.</p> </li> <li> <p>You can issue more than one select request on the same Amazon S3 object. Amazon S3 doesn&#39;t deduplicate requests, so avoid issuing duplicate requests.</p> </li> <li> <p> Amazon S3 accepts a select request even if the object has already been restored. A select request doesn’t return error response <code>409</code>.</p> </li> </ul> <p> <b>Restoring Archives</b> </p> <p>Objects in the GLACIER and DEEP</em>ARCHIVE storage classes are archived. To access an archived object, you must first initiate a restore request. This restores a temporary copy of the archived object. In a restore request, you specify the number of days that you want the restored copy to exist. After the specified period, Amazon S3 deletes the temporary copy but the object remains archived in the GLACIER or DEEP<em>ARCHIVE storage class that object was restored from. </p> <p>To restore a specific object version, you can provide a version ID. If you don&#39;t provide a version ID, Amazon S3 restores the current version.</p> <p>The time it takes restore jobs to finish depends on which storage class the object is being restored from and which data access tier you specify. </p> <p>When restoring an archived object (or using a select request), you can specify one of the following data access tier options in the <code>Tier</code> element of the request body: </p> <ul> <li> <p> <b> <code>Expedited</code> </b> - Expedited retrievals allow you to quickly access your data stored in the GLACIER storage class when occasional urgent requests for a subset of archives are required. For all but the largest archived objects (250 MB+), data accessed using Expedited retrievals are typically made available within 1–5 minutes. Provisioned capacity ensures that retrieval capacity for Expedited retrievals is available when you need it. Expedited retrievals and provisioned capacity are not available for the DEEP</em>ARCHIVE storage class.</p> </li> <li> <p> <b> <code>Standard</code> </b> - Standard retrievals allow you to access any of your archived objects within several hours. This is the default option for the GLACIER and DEEP<em>ARCHIVE retrieval requests that do not specify the retrieval option. Standard retrievals typically complete within 3-5 hours from the GLACIER storage class and typically complete within 12 hours from the DEEP</em>ARCHIVE storage class. </p> </li> <li> <p> <b> <code>Bulk</code> </b> - Bulk retrievals are Amazon S3 Glacier’s lowest-cost retrieval option, enabling you to retrieve large amounts, even petabytes, of data inexpensively in a day. Bulk retrievals typically complete within 5-12 hours from the GLACIER storage class and typically complete within 48 hours from the DEEP_ARCHIVE storage class.</p> </li> </ul> <p>For more information about archive retrieval options and provisioned capacity for <code>Expedited</code> data access, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/restoring-objects.html">Restoring Archived Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>You can use Amazon S3 restore speed upgrade to change the restore speed to a faster speed while it is in progress. You upgrade the speed of an in-progress restoration by issuing another restore request to the same object, setting a new <code>Tier</code> request element. When issuing a request to upgrade the restore tier, you must choose a tier that is faster than the tier that the in-progress restore is using. You must not change any other parameters, such as the <code>Days</code> request element. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/restoring-objects.html#restoring-objects-upgrade-tier.title.html"> Upgrading the Speed of an In-Progress Restore</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>To get the status of object restoration, you can send a <code>HEAD</code> request. Operations return the <code>x-amz-restore</code> header, which provides information about the restoration status, in the response. You can use Amazon S3 event notifications to notify you when a restore is initiated or completed. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Configuring Amazon S3 Event Notifications</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>After restoring an archived object, you can update the restoration period by reissuing the request with a new period. Amazon S3 updates the restoration period relative to the current time and charges only for the request-there are no data transfer charges. You cannot update the restoration period when Amazon S3 is actively processing your current restore request for the object.</p> <p>If your bucket has a lifecycle configuration with a rule that includes an expiration action, the object expiration overrides the life span that you specify in a restore request. For example, if you restore an object copy for 10 days, but the object is scheduled to expire in 3 days, Amazon S3 deletes the object in 3 days. For more information about lifecycle configuration, see <a>PutBucketLifecycleConfiguration</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a> in <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p> <b>Responses</b> </p> <p>A successful operation returns either the <code>200 OK</code> or <code>202 Accepted</code> status code. </p> <ul> <li> <p>If the object copy is not previously restored, then Amazon S3 returns <code>202 Accepted</code> in the response. </p> </li> <li> <p>If the object copy is previously restored, Amazon S3 returns <code>200 OK</code> in the response. </p> </li> </ul> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: RestoreAlreadyInProgress</i> </p> </li> <li> <p> <i>Cause: Object restore is already in progress. (This error does not apply to SELECT type requests.)</i> </p> </li> <li> <p> <i>HTTP Status Code: 409 Conflict</i> </p> </li> <li> <p> <i>SOAP Fault Code Prefix: Client</i> </p> </li> </ul> </li> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: GlacierExpeditedRetrievalNotAvailable</i> </p> </li> <li> <p> <i>Cause: Glacier expedited retrievals are currently not available. Try again later. (Returned if there is insufficient capacity to process the Expedited request. This error applies only to Expedited retrievals and not to Standard or Bulk retrievals.)</i> </p> </li> <li> <p> <i>HTTP Status Code: 503</i> </p> </li> <li> <p> <i>SOAP Fault Code Prefix: N/A</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>PutBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>GetBucketNotificationConfiguration</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-glacier-select-sql-reference.html">SQL Reference for Amazon S3 Select and Glacier Select </a> in the <i>Amazon Simple Storage Service Developer Guide</i> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn restore_object(
+    /// Presigns the request `restore_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_object(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestoreObjectRequest,
-    ) -> Result<RestoreObjectOutput, RusotoError<RestoreObjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_restore_object_request(&self, input: RestoreObjectRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("RestoreObject");
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -24272,21 +27034,40 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
 
+        request
+    }
+
+    /// Calls `select_object_content`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn select_object_content_with_metadata(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> Result<(SelectObjectContentOutput, ResponseMetadata), RusotoError<SelectObjectContentError>>
+    {
+        let request = self.build_select_object_content_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(RestoreObjectError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(SelectObjectContentError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = RestoreObjectOutput::default();
+            result = SelectObjectContentOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -24295,28 +27076,58 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = RestoreObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result =
+                SelectObjectContentOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
-        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-            let value = request_charged.to_owned();
-            result.request_charged = Some(value)
-        };
-        if let Some(restore_output_path) = response.headers.get("x-amz-restore-output-path") {
-            let value = restore_output_path.to_owned();
-            result.restore_output_path = Some(value)
-        }; // parse non-payload
-        Ok(result)
+        // parse non-payload
+        Ok((result, metadata))
     }
 
-    /// <p><p>This operation filters the contents of an Amazon S3 object based on a simple structured query language (SQL) statement. In the request, along with the SQL expression, you must also specify a data serialization format (JSON, CSV, or Apache Parquet) of the object. Amazon S3 uses this format to parse object data into records, and returns only records that match the specified SQL expression. You must also specify the data serialization format for the response.</p> <p>For more information about Amazon S3 Select, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/selecting-content-from-objects.html">Selecting Content from Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>For more information about using SQL with Amazon S3 Select, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-glacier-select-sql-reference.html"> SQL Reference for Amazon S3 Select and Glacier Select</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p/> <p> <b>Permissions</b> </p> <p>You must have <code>s3:GetObject</code> permission for this operation. Amazon S3 Select does not support anonymous access. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p/> <p> <i>Object Data Formats</i> </p> <p>You can use Amazon S3 Select to query objects that have the following format properties:</p> <ul> <li> <p> <i>CSV, JSON, and Parquet</i> - Objects must be in CSV, JSON, or Parquet format.</p> </li> <li> <p> <i>UTF-8</i> - UTF-8 is the only encoding type Amazon S3 Select supports.</p> </li> <li> <p> <i>GZIP or BZIP2</i> - CSV and JSON files can be compressed using GZIP or BZIP2. GZIP and BZIP2 are the only compression formats that Amazon S3 Select supports for CSV and JSON files. Amazon S3 Select supports columnar compression for Parquet using GZIP or Snappy. Amazon S3 Select does not support whole-object compression for Parquet objects.</p> </li> <li> <p> <i>Server-side encryption</i> - Amazon S3 Select supports querying objects that are protected with server-side encryption.</p> <p>For objects that are encrypted with customer-provided encryption keys (SSE-C), you must use HTTPS, and you must use the headers that are documented in the <a>GetObject</a>. For more information about SSE-C, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ServerSideEncryptionCustomerKeys.html">Server-Side Encryption (Using Customer-Provided Encryption Keys)</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>For objects that are encrypted with Amazon S3 managed encryption keys (SSE-S3) and customer master keys (CMKs) stored in AWS Key Management Service (SSE-KMS), server-side encryption is handled transparently, so you don&#39;t need to specify anything. For more information about server-side encryption, including SSE-S3 and SSE-KMS, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Protecting Data Using Server-Side Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> </ul> <p> <b>Working with the Response Body</b> </p> <p>Given the response size is unknown, Amazon S3 Select streams the response as a series of messages and includes a <code>Transfer-Encoding</code> header with <code>chunked</code> as its value in the response. For more information, see <a>RESTSelectObjectAppendix</a> .</p> <p/> <p> <b>GetObject Support</b> </p> <p>The <code>SelectObjectContent</code> operation does not support the following <code>GetObject</code> functionality. For more information, see <a>GetObject</a>.</p> <ul> <li> <p> <code>Range</code>: While you can specify a scan range for a Amazon S3 Select request, see <a>SelectObjectContentRequest$ScanRange</a> in the request parameters below, you cannot specify the range of bytes of an object to return. </p> </li> <li> <p>GLACIER, DEEP<em>ARCHIVE and REDUCED</em>REDUNDANCY storage classes: You cannot specify the GLACIER, DEEP<em>ARCHIVE, or <code>REDUCED</em>REDUNDANCY</code> storage classes. For more information, about storage classes see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingMetadata.html#storage-class-intro">Storage Classes</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> </ul> <p/> <p> <b>Special Errors</b> </p> <p>For a list of special errors for this operation and for general information about Amazon S3 errors and a list of error codes, see <a>ErrorResponses</a> </p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>PutBucketLifecycleConfiguration</a> </p> </li> </ul></p>
+    /// Calls `select_object_content`, returning the event stream of the response undecoded.
     #[allow(unused_variables, warnings)]
-    async fn select_object_content(
+    pub(crate) async fn select_object_content_event_stream(
         &self,
         input: SelectObjectContentRequest,
-    ) -> Result<SelectObjectContentOutput, RusotoError<SelectObjectContentError>> {
+    ) -> Result<(rusoto_core::ByteStream, ResponseMetadata), RusotoError<SelectObjectContentError>>
+    {
+        let request = self.build_select_object_content_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if !response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(SelectObjectContentError::from_response(response));
+        }
+
+        Ok((response.body, metadata))
+    }
+
+    /// Presigns the request `select_object_content` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_select_object_content(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SelectObjectContentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_select_object_content_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_select_object_content_request(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("SelectObjectContent");
 
         if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
             request.add_header(
@@ -24351,21 +27162,39 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `upload_part`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn upload_part_with_metadata(
+        &self,
+        input: UploadPartRequest,
+    ) -> Result<(UploadPartOutput, ResponseMetadata), RusotoError<UploadPartError>> {
+        let request = self.build_upload_part_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(SelectObjectContentError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(UploadPartError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = SelectObjectContentOutput::default();
+            result = UploadPartOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -24374,22 +27203,60 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result =
-                SelectObjectContentOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            result = UploadPartOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
-        // parse non-payload
-        Ok(result)
+        if let Some(e_tag) = response.headers.get("ETag") {
+            let value = e_tag.to_owned();
+            result.e_tag = Some(value)
+        };
+        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+            let value = request_charged.to_owned();
+            result.request_charged = Some(value)
+        };
+        if let Some(sse_customer_algorithm) = response
+            .headers
+            .get("x-amz-server-side-encryption-customer-algorithm")
+        {
+            let value = sse_customer_algorithm.to_owned();
+            result.sse_customer_algorithm = Some(value)
+        };
+        if let Some(sse_customer_key_md5) = response
+            .headers
+            .get("x-amz-server-side-encryption-customer-key-MD5")
+        {
+            let value = sse_customer_key_md5.to_owned();
+            result.sse_customer_key_md5 = Some(value)
+        };
+        if let Some(ssekms_key_id) = response
+            .headers
+            .get("x-amz-server-side-encryption-aws-kms-key-id")
+        {
+            let value = ssekms_key_id.to_owned();
+            result.ssekms_key_id = Some(value)
+        };
+        if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
+            let value = server_side_encryption.to_owned();
+            result.server_side_encryption = Some(value)
+        }; // parse non-payload
+        Ok((result, metadata))
     }
 
-    /// <p><p>Uploads a part in a multipart upload.</p> <note> <p>In this operation, you provide part data in your request. However, you have an option to specify your existing Amazon S3 object as a data source for the part you are uploading. To upload a part from an existing object, you use the <a>UploadPartCopy</a> operation. </p> </note> <p>You must initiate a multipart upload (see <a>CreateMultipartUpload</a>) before you can upload any part. In response to your initiate request, Amazon S3 returns an upload ID, a unique identifier, that you must include in your upload part request.</p> <p>Part numbers can be any number from 1 to 10,000, inclusive. A part number uniquely identifies a part and also defines its position within the object being created. If you upload a new part using the same part number that was used with a previous part, the previously uploaded part is overwritten. Each part must be at least 5 MB in size, except the last part. There is no size limit on the last part of your multipart upload.</p> <p>To ensure that data is not corrupted when traversing the network, specify the <code>Content-MD5</code> header in the upload part request. Amazon S3 checks the part data against the provided MD5 value. If they do not match, Amazon S3 returns an error. </p> <p> <b>Note:</b> After you initiate multipart upload and upload one or more parts, you must either complete or abort multipart upload in order to stop getting charged for storage of the uploaded parts. Only after you either complete or abort multipart upload, Amazon S3 frees up the parts storage and stops charging you for the parts storage.</p> <p>For more information on multipart uploads, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html">Multipart Upload Overview</a> in the <i>Amazon Simple Storage Service Developer Guide </i>.</p> <p>For information on the permissions required to use the multipart upload API, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>You can optionally request server-side encryption where Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it for you when you access it. You have the option of providing your own encryption key, or you can use the AWS managed encryption keys. If you choose to provide your own encryption key, the request headers you provide in the request must match the headers you used in the request to initiate the upload by using <a>CreateMultipartUpload</a>. For more information, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingServerSideEncryption.html">Using Server-Side Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>Server-side encryption is supported by the S3 Multipart Upload actions. Unless you are using a customer-provided encryption key, you don&#39;t need to specify the encryption parameters in each UploadPart request. Instead, you only need to specify the server-side encryption parameters in the initial Initiate Multipart request. For more information, see <a>CreateMultipartUpload</a>.</p> <p>If you requested server-side encryption using a customer-provided encryption key in your initiate multipart upload request, you must provide identical encryption information in each part upload using the following headers.</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: NoSuchUpload</i> </p> </li> <li> <p> <i>Cause: The specified multipart upload does not exist. The upload ID might be invalid, or the multipart upload might have been aborted or completed.</i> </p> </li> <li> <p> <i> HTTP Status Code: 404 Not Found </i> </p> </li> <li> <p> <i>SOAP Fault Code Prefix: Client</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn upload_part(
+    /// Presigns the request `upload_part` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upload_part(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UploadPartRequest,
-    ) -> Result<UploadPartOutput, RusotoError<UploadPartError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upload_part_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_upload_part_request(&self, input: UploadPartRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("UploadPart");
 
         if let Some(ref content_length) = input.content_length {
             request.add_header("Content-Length", &content_length.to_string());
@@ -24431,21 +27298,39 @@ impl S3 for S3Client {
             request.set_payload_stream(__body);
         }
 
+        request
+    }
+
+    /// Calls `upload_part_copy`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn upload_part_copy_with_metadata(
+        &self,
+        input: UploadPartCopyRequest,
+    ) -> Result<(UploadPartCopyOutput, ResponseMetadata), RusotoError<UploadPartCopyError>> {
+        let request = self.build_upload_part_copy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(UploadPartError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(UploadPartCopyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
-            result = UploadPartOutput::default();
+            result = UploadPartCopyOutput::default();
         } else {
             let reader = EventReader::new_with_config(
                 xml_response.body.as_ref(),
@@ -24454,11 +27339,15 @@ impl S3 for S3Client {
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             let _start_document = stack.next();
             let actual_tag_name = peek_at_name(&mut stack)?;
-            result = UploadPartOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+            if actual_tag_name == "Error" {
+                std::mem::drop(stack);
+                return Err(UploadPartCopyError::from_response(xml_response));
+            }
+            result = UploadPartCopyOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
-        if let Some(e_tag) = response.headers.get("ETag") {
-            let value = e_tag.to_owned();
-            result.e_tag = Some(value)
+        if let Some(copy_source_version_id) = response.headers.get("x-amz-copy-source-version-id") {
+            let value = copy_source_version_id.to_owned();
+            result.copy_source_version_id = Some(value)
         };
         if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
             let value = request_charged.to_owned();
@@ -24489,18 +27378,25 @@ impl S3 for S3Client {
             let value = server_side_encryption.to_owned();
             result.server_side_encryption = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Uploads a part by copying data from an existing object as data source. You specify the data source by adding the request header <code>x-amz-copy-source</code> in your request and a byte range by adding the request header <code>x-amz-copy-source-range</code> in your request. </p> <p>The minimum allowable part size for a multipart upload is 5 MB. For more information about multipart upload limits, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/qfacts.html">Quick Facts</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <note> <p>Instead of using an existing object as part data, you might use the <a>UploadPart</a> operation and provide data in your request.</p> </note> <p>You must initiate a multipart upload before you can upload any part. In response to your initiate request. Amazon S3 returns a unique identifier, the upload ID, that you must include in your upload part request.</p> <p>For more information about using the <code>UploadPartCopy</code> operation, see the following:</p> <ul> <li> <p>For conceptual information about multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/uploadobjusingmpu.html">Uploading Objects Using Multipart Upload</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> <li> <p>For information about permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> <li> <p>For information about copying objects using a single atomic operation vs. the multipart upload, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ObjectOperations.html">Operations on Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> <li> <p>For information about using server-side encryption with customer-provided encryption keys with the UploadPartCopy operation, see <a>CopyObject</a> and <a>UploadPart</a>.</p> </li> </ul> <p>Note the following additional considerations about the request headers <code>x-amz-copy-source-if-match</code>, <code>x-amz-copy-source-if-none-match</code>, <code>x-amz-copy-source-if-unmodified-since</code>, and <code>x-amz-copy-source-if-modified-since</code>:</p> <p> </p> <ul> <li> <p> <b>Consideration 1</b> - If both of the <code>x-amz-copy-source-if-match</code> and <code>x-amz-copy-source-if-unmodified-since</code> headers are present in the request as follows:</p> <p> <code>x-amz-copy-source-if-match</code> condition evaluates to <code>true</code>, and;</p> <p> <code>x-amz-copy-source-if-unmodified-since</code> condition evaluates to <code>false</code>;</p> <p>Amazon S3 returns <code>200 OK</code> and copies the data. </p> </li> <li> <p> <b>Consideration 2</b> - If both of the <code>x-amz-copy-source-if-none-match</code> and <code>x-amz-copy-source-if-modified-since</code> headers are present in the request as follows:</p> <p> <code>x-amz-copy-source-if-none-match</code> condition evaluates to <code>false</code>, and;</p> <p> <code>x-amz-copy-source-if-modified-since</code> condition evaluates to <code>true</code>;</p> <p>Amazon S3 returns <code>412 Precondition Failed</code> response code. </p> </li> </ul> <p> <b>Versioning</b> </p> <p>If your bucket has versioning enabled, you could have multiple versions of the same object. By default, <code>x-amz-copy-source</code> identifies the current version of the object to copy. If the current version is a delete marker and you don&#39;t specify a versionId in the <code>x-amz-copy-source</code>, Amazon S3 returns a 404 error, because the object does not exist. If you specify versionId in the <code>x-amz-copy-source</code> and the versionId is a delete marker, Amazon S3 returns an HTTP 400 error, because you are not allowed to specify a delete marker as a version for the <code>x-amz-copy-source</code>. </p> <p>You can optionally specify a specific version of the source object to copy by adding the <code>versionId</code> subresource as shown in the following example:</p> <p> <code>x-amz-copy-source: /bucket/object?versionId=version id</code> </p> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: NoSuchUpload</i> </p> </li> <li> <p> <i>Cause: The specified multipart upload does not exist. The upload ID might be invalid, or the multipart upload might have been aborted or completed.</i> </p> </li> <li> <p> <i>HTTP Status Code: 404 Not Found</i> </p> </li> </ul> </li> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: InvalidRequest</i> </p> </li> <li> <p> <i>Cause: The specified copy source is not supported as a byte-range copy source.</i> </p> </li> <li> <p> <i>HTTP Status Code: 400 Bad Request</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    async fn upload_part_copy(
+    /// Presigns the request `upload_part_copy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upload_part_copy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UploadPartCopyRequest,
-    ) -> Result<UploadPartCopyOutput, RusotoError<UploadPartCopyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upload_part_copy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_upload_part_copy_request(&self, input: UploadPartCopyRequest) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("UploadPartCopy");
 
         request.add_header("x-amz-copy-source", &input.copy_source);
 
@@ -24588,65 +27484,1258 @@ impl S3 for S3Client {
         params.put("uploadId", &input.upload_id);
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(UploadPartCopyError::from_response(response));
-        }
+        request
+    }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-        let mut result;
+    /// The pages of `ListMultipartUploads` results for `input`, requesting each next page with the `NextKeyMarker, NextUploadIdMarker` of the last one as its `KeyMarker, UploadIdMarker` while its `IsTruncated` is true.
+    /// The page size is the `MaxUploads` of `input`.
+    pub fn list_multipart_uploads_pages(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: S3Client, input| async move { client.list_multipart_uploads(input).await },
+            |input: &ListMultipartUploadsRequest, output: &ListMultipartUploadsOutput| {
+                if output.is_truncated != Some(true) {
+                    return None;
+                }
+                let key_marker = output.next_key_marker.clone();
+                let upload_id_marker = output.next_upload_id_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if (key_marker.is_none() && upload_id_marker.is_none())
+                    || (key_marker == input.key_marker
+                        && upload_id_marker == input.upload_id_marker)
+                {
+                    return None;
+                }
+                Some(ListMultipartUploadsRequest {
+                    key_marker,
+                    upload_id_marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
 
-        if xml_response.body.is_empty() {
-            result = UploadPartCopyOutput::default();
-        } else {
-            let reader = EventReader::new_with_config(
-                xml_response.body.as_ref(),
-                ParserConfig::new().trim_whitespace(false),
-            );
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            let _start_document = stack.next();
-            let actual_tag_name = peek_at_name(&mut stack)?;
-            result = UploadPartCopyOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-        }
-        if let Some(copy_source_version_id) = response.headers.get("x-amz-copy-source-version-id") {
-            let value = copy_source_version_id.to_owned();
-            result.copy_source_version_id = Some(value)
-        };
-        if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-            let value = request_charged.to_owned();
-            result.request_charged = Some(value)
-        };
-        if let Some(sse_customer_algorithm) = response
-            .headers
-            .get("x-amz-server-side-encryption-customer-algorithm")
-        {
-            let value = sse_customer_algorithm.to_owned();
-            result.sse_customer_algorithm = Some(value)
-        };
-        if let Some(sse_customer_key_md5) = response
-            .headers
-            .get("x-amz-server-side-encryption-customer-key-MD5")
-        {
-            let value = sse_customer_key_md5.to_owned();
-            result.sse_customer_key_md5 = Some(value)
-        };
-        if let Some(ssekms_key_id) = response
-            .headers
-            .get("x-amz-server-side-encryption-aws-kms-key-id")
-        {
-            let value = ssekms_key_id.to_owned();
-            result.ssekms_key_id = Some(value)
-        };
-        if let Some(server_side_encryption) = response.headers.get("x-amz-server-side-encryption") {
-            let value = server_side_encryption.to_owned();
-            result.server_side_encryption = Some(value)
-        }; // parse non-payload
-        Ok(result)
+    /// The pages of `ListObjectVersions` results for `input`, requesting each next page with the `NextKeyMarker, NextVersionIdMarker` of the last one as its `KeyMarker, VersionIdMarker` while its `IsTruncated` is true.
+    /// The page size is the `MaxKeys` of `input`.
+    pub fn list_object_versions_pages(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListObjectVersionsOutput, RusotoError<ListObjectVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: S3Client, input| async move { client.list_object_versions(input).await },
+            |input: &ListObjectVersionsRequest, output: &ListObjectVersionsOutput| {
+                if output.is_truncated != Some(true) {
+                    return None;
+                }
+                let key_marker = output.next_key_marker.clone();
+                let version_id_marker = output.next_version_id_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if (key_marker.is_none() && version_id_marker.is_none())
+                    || (key_marker == input.key_marker
+                        && version_id_marker == input.version_id_marker)
+                {
+                    return None;
+                }
+                Some(ListObjectVersionsRequest {
+                    key_marker,
+                    version_id_marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `ListObjectsV2` results for `input`, requesting each next page with the `NextContinuationToken` of the last one as its `ContinuationToken` while its `IsTruncated` is true.
+    /// The page size is the `MaxKeys` of `input`.
+    pub fn list_objects_v2_pages(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> impl futures::Stream<Item = Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: S3Client, input| async move { client.list_objects_v2(input).await },
+            |input: &ListObjectsV2Request, output: &ListObjectsV2Output| {
+                if output.is_truncated != Some(true) {
+                    return None;
+                }
+                let continuation_token = output.next_continuation_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if continuation_token.is_none() || continuation_token == input.continuation_token {
+                    return None;
+                }
+                Some(ListObjectsV2Request {
+                    continuation_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `ListParts` results for `input`, requesting each next page with the `NextPartNumberMarker` of the last one as its `PartNumberMarker` while its `IsTruncated` is true.
+    /// The page size is the `MaxParts` of `input`.
+    pub fn list_parts_pages(
+        &self,
+        input: ListPartsRequest,
+    ) -> impl futures::Stream<Item = Result<ListPartsOutput, RusotoError<ListPartsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: S3Client, input| async move { client.list_parts(input).await },
+            |input: &ListPartsRequest, output: &ListPartsOutput| {
+                if output.is_truncated != Some(true) {
+                    return None;
+                }
+                let part_number_marker = output.next_part_number_marker;
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if part_number_marker.is_none() || part_number_marker == input.part_number_marker {
+                    return None;
+                }
+                Some(ListPartsRequest {
+                    part_number_marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parts` of every page of `ListParts` results for `input`, as `list_parts_pages` requests them.
+    pub fn list_parts_stream(
+        &self,
+        input: ListPartsRequest,
+    ) -> impl futures::Stream<Item = Result<Part, RusotoError<ListPartsError>>> + Send {
+        rusoto_core::pagination::items(self.list_parts_pages(input), |page| {
+            page.parts.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `BucketExists`, polling `HeadBucket` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_bucket_exists(
+        &self,
+        input: HeadBucketRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadBucketError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_bucket_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `BucketExists` like `wait_until_bucket_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_bucket_exists_with(
+        &self,
+        input: HeadBucketRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadBucketError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.head_bucket(input.clone()),
+            |result| {
+                if rusoto_core::waiter::status(result) == Some(200) {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if rusoto_core::waiter::status(result) == Some(301) {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if rusoto_core::waiter::status(result) == Some(403) {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if rusoto_core::waiter::status(result) == Some(404) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `BucketNotExists`, polling `HeadBucket` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_bucket_not_exists(
+        &self,
+        input: HeadBucketRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadBucketError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_bucket_not_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `BucketNotExists` like `wait_until_bucket_not_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_bucket_not_exists_with(
+        &self,
+        input: HeadBucketRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadBucketError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.head_bucket(input.clone()),
+            |result| {
+                if rusoto_core::waiter::status(result) == Some(404) {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ObjectExists`, polling `HeadObject` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_object_exists(
+        &self,
+        input: HeadObjectRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadObjectError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_object_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `ObjectExists` like `wait_until_object_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_object_exists_with(
+        &self,
+        input: HeadObjectRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadObjectError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.head_object(input.clone()),
+            |result| {
+                if rusoto_core::waiter::status(result) == Some(200) {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if rusoto_core::waiter::status(result) == Some(404) {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ObjectNotExists`, polling `HeadObject` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_object_not_exists(
+        &self,
+        input: HeadObjectRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadObjectError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_object_not_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `ObjectNotExists` like `wait_until_object_not_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_object_not_exists_with(
+        &self,
+        input: HeadObjectRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<HeadObjectError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.head_object(input.clone()),
+            |result| {
+                if rusoto_core::waiter::status(result) == Some(404) {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl S3 for S3Client {
+    /// <p><p>This operation aborts a multipart upload. After a multipart upload is aborted, no additional parts can be uploaded using that upload ID. The storage consumed by any previously uploaded parts will be freed. However, if any part uploads are currently in progress, those part uploads might or might not succeed. As a result, it might be necessary to abort a given multipart upload multiple times in order to completely free all storage consumed by all parts. </p> <p>To verify that all parts have been removed, so you don&#39;t get charged for the part storage, you should call the <a>ListParts</a> operation and ensure that the parts list is empty.</p> <p>For information about permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p>The following operations are related to <code>AbortMultipartUpload</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadRequest,
+    ) -> Result<AbortMultipartUploadOutput, RusotoError<AbortMultipartUploadError>> {
+        self.abort_multipart_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Completes a multipart upload by assembling previously uploaded parts.</p> <p>You first initiate the multipart upload and then upload all parts using the <a>UploadPart</a> operation. After successfully uploading all relevant parts of an upload, you call this operation to complete the upload. Upon receiving this request, Amazon S3 concatenates all the parts in ascending order by part number to create a new object. In the Complete Multipart Upload request, you must provide the parts list. You must ensure that the parts list is complete. This operation concatenates the parts that you provide in the list. For each part in the list, you must provide the part number and the <code>ETag</code> value, returned after that part was uploaded.</p> <p>Processing of a Complete Multipart Upload request could take several minutes to complete. After Amazon S3 begins processing the request, it sends an HTTP response header that specifies a 200 OK response. While processing is in progress, Amazon S3 periodically sends white space characters to keep the connection from timing out. Because a request could fail after the initial 200 OK response has been sent, it is important that you check the response body to determine whether the request succeeded.</p> <p>Note that if <code>CompleteMultipartUpload</code> fails, applications should be prepared to retry the failed requests. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ErrorBestPractices.html">Amazon S3 Error Best Practices</a>.</p> <p>For more information about multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/uploadobjusingmpu.html">Uploading Objects Using Multipart Upload</a>.</p> <p>For information about permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p> <code>GetBucketLifecycle</code> has the following special errors:</p> <ul> <li> <p>Error code: <code>EntityTooSmall</code> </p> <ul> <li> <p>Description: Your proposed upload is smaller than the minimum allowed object size. Each part must be at least 5 MB in size, except the last part.</p> </li> <li> <p>400 Bad Request</p> </li> </ul> </li> <li> <p>Error code: <code>InvalidPart</code> </p> <ul> <li> <p>Description: One or more of the specified parts could not be found. The part might not have been uploaded, or the specified entity tag might not have matched the part&#39;s entity tag.</p> </li> <li> <p>400 Bad Request</p> </li> </ul> </li> <li> <p>Error code: <code>InvalidPartOrder</code> </p> <ul> <li> <p>Description: The list of parts was not in ascending order. The parts list must be specified in order by part number.</p> </li> <li> <p>400 Bad Request</p> </li> </ul> </li> <li> <p>Error code: <code>NoSuchUpload</code> </p> <ul> <li> <p>Description: The specified multipart upload does not exist. The upload ID might be invalid, or the multipart upload might have been aborted or completed.</p> </li> <li> <p>404 Not Found</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadRequest,
+    ) -> Result<CompleteMultipartUploadOutput, RusotoError<CompleteMultipartUploadError>> {
+        self.complete_multipart_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a copy of an object that is already stored in Amazon S3.</p> <note> <p>You can store individual objects of up to 5 TB in Amazon S3. You create a copy of your object up to 5 GB in size in a single atomic operation using this API. However, for copying an object greater than 5 GB, you must use the multipart upload Upload Part - Copy API. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/CopyingObjctsUsingRESTMPUapi.html">Copy Object Using the REST Multipart Upload API</a>.</p> </note> <p>When copying an object, you can preserve all metadata (default) or specify new metadata. However, the ACL is not preserved and is set to private for the user making the request. To override the default ACL setting, specify a new ACL when generating a copy request. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>.</p> <important> <p>Amazon S3 transfer acceleration does not support cross-region copies. If you request a cross-region copy using a transfer acceleration endpoint, you get a 400 <code>Bad Request</code> error. For more information about transfer acceleration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/transfer-acceleration.html">Transfer Acceleration</a>.</p> </important> <p>All copy requests must be authenticated. Additionally, you must have <i>read</i> access to the source object and <i>write</i> access to the destination bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/RESTAuthentication.html">REST Authentication</a>. Both the Region that you want to copy the object from and the Region that you want to copy the object to must be enabled for your account.</p> <p>To only copy an object under certain conditions, such as whether the <code>Etag</code> matches or whether the object was modified before or after a specified date, use the request parameters <code>x-amz-copy-source-if-match</code>, <code>x-amz-copy-source-if-none-match</code>, <code>x-amz-copy-source-if-unmodified-since</code>, or <code> x-amz-copy-source-if-modified-since</code>.</p> <note> <p>All headers with the <code>x-amz-</code> prefix, including <code>x-amz-copy-source</code>, must be signed.</p> </note> <p>You can use this operation to change the storage class of an object that is already stored in Amazon S3 using the <code>StorageClass</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-class-intro.html">Storage Classes</a>.</p> <p>The source object that you are copying can be encrypted or unencrypted. If the source object is encrypted, it can be encrypted by server-side encryption using AWS managed encryption keys or by using a customer-provided encryption key. When copying an object, you can request that Amazon S3 encrypt the target object by using either the AWS managed encryption keys or by using your own encryption key. You can do this regardless of the form of server-side encryption that was used to encrypt the source, or even if the source object was not encrypted. For more information about server-side encryption, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Using Server-Side Encryption</a>.</p> <p>A copy request might return an error when Amazon S3 receives the copy request or while Amazon S3 is copying the files. If the error occurs before the copy operation starts, you receive a standard Amazon S3 error. If the error occurs during the copy operation, the error response is embedded in the <code>200 OK</code> response. This means that a <code>200 OK</code> response can contain either a success or an error. Design your application to parse the contents of the response and handle it appropriately.</p> <p>If the copy is successful, you receive a response with information about the copied object.</p> <note> <p>If the request is an HTTP 1.1 request, the response is chunk encoded. If it were not, it would not contain the content-length, and you would need to read the entire body.</p> </note> <p>Consider the following when using request headers:</p> <ul> <li> <p> Consideration 1 – If both the <code>x-amz-copy-source-if-match</code> and <code>x-amz-copy-source-if-unmodified-since</code> headers are present in the request and evaluate as follows, Amazon S3 returns 200 OK and copies the data:</p> <ul> <li> <p> <code>x-amz-copy-source-if-match</code> condition evaluates to true</p> </li> <li> <p> <code>x-amz-copy-source-if-unmodified-since</code> condition evaluates to false</p> </li> </ul> </li> <li> <p> Consideration 2 – If both of the <code>x-amz-copy-source-if-none-match</code> and <code>x-amz-copy-source-if-modified-since</code> headers are present in the request and evaluate as follows, Amazon S3 returns the <code>412 Precondition Failed</code> response code:</p> <ul> <li> <p> <code>x-amz-copy-source-if-none-match</code> condition evaluates to false</p> </li> <li> <p> <code>x-amz-copy-source-if-modified-since</code> condition evaluates to true</p> </li> </ul> </li> </ul> <p>The copy request charge is based on the storage class and Region you specify for the destination object. For pricing information, see <a href="https://aws.amazon.com/s3/pricing/">Amazon S3 Pricing</a>.</p> <p>Following are other considerations when using <code>CopyObject</code>:</p> <dl> <dt>Versioning</dt> <dd> <p>By default, <code>x-amz-copy-source</code> identifies the current version of an object to copy. (If the current version is a delete marker, Amazon S3 behaves as if the object was deleted.) To copy a different version, use the <code>versionId</code> subresource.</p> <p>If you enable versioning on the target bucket, Amazon S3 generates a unique version ID for the object being copied. This version ID is different from the version ID of the source object. Amazon S3 returns the version ID of the copied object in the <code>x-amz-version-id</code> response header in the response.</p> <p>If you do not enable versioning or suspend it on the target bucket, the version ID that Amazon S3 generates is always null.</p> <p>If the source object's storage class is GLACIER, you must restore a copy of this object before you can use it as a source object for the copy operation. For more information, see .</p> </dd> <dt>Access Permissions</dt> <dd> <p>When copying an object, you can optionally specify the accounts or groups that should be granted specific permissions on the new object. There are two ways to grant the permissions using the request headers:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> </dd> <dt>Server-Side- Encryption-Specific Request Headers</dt> <dd> <p>To encrypt the target object, you must provide the appropriate encryption-related request headers. The one you use depends on whether you want to use AWS managed encryption keys or provide your own encryption key. </p> <ul> <li> <p>To encrypt the target object using server-side encryption with an AWS managed encryption key, provide the following request headers, as appropriate.</p> <ul> <li> <p> <code>x-amz-server-side​-encryption</code> </p> </li> <li> <p> <code>x-amz-server-side-encryption-aws-kms-key-id</code> </p> </li> <li> <p> <code>x-amz-server-side-encryption-context</code> </p> </li> </ul> <note> <p>If you specify <code>x-amz-server-side-encryption:aws:kms</code>, but don't provide <code>x-amz-server-side-encryption-aws-kms-key-id</code>, Amazon S3 uses the AWS managed CMK in AWS KMS to protect the data. If you want to use a customer managed AWS KMS CMK, you must provide the <code>x-amz-server-side-encryption-aws-kms-key-id</code> of the symmetric customer managed CMK. Amazon S3 only supports symmetric CMKs and not asymmetric CMKs. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> </note> <important> <p>All GET and PUT requests for an object protected by AWS KMS fail if you don't make them with SSL or by using SigV4.</p> </important> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in KMS</a>.</p> </li> <li> <p>To encrypt the target object using server-side encryption with an encryption key that you provide, use the following headers.</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> </li> <li> <p>If the source object is encrypted using server-side encryption with customer-provided encryption keys, you must use the following headers.</p> <ul> <li> <p>x-amz-copy-source​-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-copy-source​-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-copy-source-​server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in Amazon KMS</a>.</p> </li> </ul> </dd> <dt>Access-Control-List (ACL)-Specific Request Headers</dt> <dd> <p>You also can use the following access control–related headers with this operation. By default, all objects are private. Only the owner has full access control. When adding a new object, you can grant permissions to individual AWS accounts or to predefined groups defined by Amazon S3. These permissions are then added to the access control list (ACL) on the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>. With this operation, you can grant access permissions using one of the following two methods:</p> <ul> <li> <p>Specify a canned ACL (<code>x-amz-acl</code>) — Amazon S3 supports a set of predefined ACLs, known as <i>canned ACLs</i>. Each canned ACL has a predefined set of grantees and permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly — To explicitly grant access permissions to specific AWS accounts or groups, use the following headers. Each header maps to specific permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>. In the header, you specify a list of grantees who get the specific permission. To grant permissions explicitly, use:</p> <ul> <li> <p>x-amz-grant-read</p> </li> <li> <p>x-amz-grant-write</p> </li> <li> <p>x-amz-grant-read-acp</p> </li> <li> <p>x-amz-grant-write-acp</p> </li> <li> <p>x-amz-grant-full-control</p> </li> </ul> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants the AWS accounts identified by email addresses permissions to read object data and its metadata:</p> <p> <code>x-amz-grant-read: emailAddress="xyz@amazon.com", emailAddress="abc@amazon.com" </code> </p> </li> </ul> </dd> </dl> <p>The following operations are related to <code>CopyObject</code>:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>GetObject</a> </p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/CopyingObjectsExamples.html">Copying Objects</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn copy_object(
+        &self,
+        input: CopyObjectRequest,
+    ) -> Result<CopyObjectOutput, RusotoError<CopyObjectError>> {
+        self.copy_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new bucket. To create a bucket, you must register with Amazon S3 and have a valid AWS Access Key ID to authenticate requests. Anonymous requests are never allowed to create buckets. By creating the bucket, you become the bucket owner.</p> <p>Not every string is an acceptable bucket name. For information on bucket naming restrictions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingBucket.html">Working with Amazon S3 Buckets</a>.</p> <p>By default, the bucket is created in the US East (N. Virginia) Region. You can optionally specify a Region in the request body. You might choose a Region to optimize latency, minimize costs, or address regulatory requirements. For example, if you reside in Europe, you will probably find it advantageous to create buckets in the EU (Ireland) Region. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingBucket.html#access-bucket-intro">How to Select a Region for Your Buckets</a>.</p> <note> <p>If you send your create bucket request to the <code>s3.amazonaws.com</code> endpoint, the request goes to the us-east-1 Region. Accordingly, the signature calculations in Signature Version 4 must use us-east-1 as the Region, even if the location constraint in the request specifies another Region where the bucket is to be created. If you create a bucket in a Region other than US East (N. Virginia), your application must be able to handle 307 redirect. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/VirtualHosting.html">Virtual Hosting of Buckets</a>.</p> </note> <p>When creating a bucket using this operation, you can optionally specify the accounts or groups that should be granted specific permissions on the bucket. There are two ways to grant the appropriate permissions using the request headers.</p> <ul> <li> <p>Specify a canned ACL using the <code>x-amz-acl</code> request header. Amazon S3 supports a set of predefined ACLs, known as <i>canned ACLs</i>. Each canned ACL has a predefined set of grantees and permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly using the <code>x-amz-grant-read</code>, <code>x-amz-grant-write</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. These headers map to the set of permissions Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants the AWS accounts identified by email addresses permissions to read object data and its metadata:</p> <p> <code>x-amz-grant-read: emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> <note> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> </note> <p>The following operations are related to <code>CreateBucket</code>:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn create_bucket(
+        &self,
+        input: CreateBucketRequest,
+    ) -> Result<CreateBucketOutput, RusotoError<CreateBucketError>> {
+        self.create_bucket_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This operation initiates a multipart upload and returns an upload ID. This upload ID is used to associate all of the parts in the specific multipart upload. You specify this upload ID in each of your subsequent upload part requests (see <a>UploadPart</a>). You also include this upload ID in the final request to either complete or abort the multipart upload request.</p> <p>For more information about multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html">Multipart Upload Overview</a>.</p> <p>If you have configured a lifecycle rule to abort incomplete multipart uploads, the upload must complete within the number of days specified in the bucket lifecycle configuration. Otherwise, the incomplete multipart upload becomes eligible for an abort operation and Amazon S3 aborts the multipart upload. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html#mpu-abort-incomplete-mpu-lifecycle-config">Aborting Incomplete Multipart Uploads Using a Bucket Lifecycle Policy</a>.</p> <p>For information about the permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p>For request signing, multipart upload is just a series of regular requests. You initiate a multipart upload, send one or more requests to upload parts, and then complete the multipart upload process. You sign each request individually. There is nothing special about signing multipart upload requests. For more information about signing, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-authenticating-requests.html">Authenticating Requests (AWS Signature Version 4)</a>.</p> <note> <p> After you initiate a multipart upload and upload one or more parts, to stop being charged for storing the uploaded parts, you must either complete or abort the multipart upload. Amazon S3 frees up the space used to store the parts and stop charging you for storing them only after you either complete or abort a multipart upload. </p> </note> <p>You can optionally request server-side encryption. For server-side encryption, Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it when you access it. You can provide your own encryption key, or use AWS Key Management Service (AWS KMS) customer master keys (CMKs) or Amazon S3-managed encryption keys. If you choose to provide your own encryption key, the request headers you provide in <a>UploadPart</a>) and <a>UploadPartCopy</a>) requests must match the headers you used in the request to initiate the upload by using <code>CreateMultipartUpload</code>. </p> <p>To perform a multipart upload with encryption using an AWS KMS CMK, the requester must have permission to the <code>kms:Encrypt</code>, <code>kms:Decrypt</code>, <code>kms:ReEncrypt<em></code>, <code>kms:GenerateDataKey</em></code>, and <code>kms:DescribeKey</code> actions on the key. These permissions are required because Amazon S3 must decrypt and read data from the encrypted file parts before it completes the multipart upload.</p> <p>If your AWS Identity and Access Management (IAM) user or role is in the same AWS account as the AWS KMS CMK, then you must have these permissions on the key policy. If your IAM user or role belongs to a different account than the key, then you must have the permissions on both the key policy and your IAM user or role.</p> <p> For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Protecting Data Using Server-Side Encryption</a>.</p> <dl> <dt>Access Permissions</dt> <dd> <p>When copying an object, you can optionally specify the accounts or groups that should be granted specific permissions on the new object. There are two ways to grant the permissions using the request headers:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> </dd> <dt>Server-Side- Encryption-Specific Request Headers</dt> <dd> <p>You can optionally tell Amazon S3 to encrypt data at rest using server-side encryption. Server-side encryption is for data encryption at rest. Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it when you access it. The option you use depends on whether you want to use AWS managed encryption keys or provide your own encryption key. </p> <ul> <li> <p>Use encryption keys managed by Amazon S3 or customer master keys (CMKs) stored in AWS Key Management Service (AWS KMS) – If you want AWS to manage the keys used to encrypt data, specify the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption</p> </li> <li> <p>x-amz-server-side-encryption-aws-kms-key-id</p> </li> <li> <p>x-amz-server-side-encryption-context</p> </li> </ul> <note> <p>If you specify <code>x-amz-server-side-encryption:aws:kms</code>, but don&#39;t provide <code>x-amz-server-side-encryption-aws-kms-key-id</code>, Amazon S3 uses the AWS managed CMK in AWS KMS to protect the data.</p> </note> <important> <p>All GET and PUT requests for an object protected by AWS KMS fail if you don&#39;t make them with SSL or by using SigV4.</p> </important> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS KMS</a>.</p> </li> <li> <p>Use customer-provided encryption keys – If you want to manage your own encryption keys, provide all the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS KMS</a>.</p> </li> </ul> </dd> <dt>Access-Control-List (ACL)-Specific Request Headers</dt> <dd> <p>You also can use the following access control–related headers with this operation. By default, all objects are private. Only the owner has full access control. When adding a new object, you can grant permissions to individual AWS accounts or to predefined groups defined by Amazon S3. These permissions are then added to the access control list (ACL) on the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>. With this operation, you can grant access permissions using one of the following two methods:</p> <ul> <li> <p>Specify a canned ACL (<code>x-amz-acl</code>) — Amazon S3 supports a set of predefined ACLs, known as <i>canned ACLs</i>. Each canned ACL has a predefined set of grantees and permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly — To explicitly grant access permissions to specific AWS accounts or groups, use the following headers. Each header maps to specific permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>. In the header, you specify a list of grantees who get the specific permission. To grant permissions explicitly, use:</p> <ul> <li> <p>x-amz-grant-read</p> </li> <li> <p>x-amz-grant-write</p> </li> <li> <p>x-amz-grant-read-acp</p> </li> <li> <p>x-amz-grant-write-acp</p> </li> <li> <p>x-amz-grant-full-control</p> </li> </ul> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants the AWS accounts identified by email addresses permissions to read object data and its metadata:</p> <p> <code>x-amz-grant-read: emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> </dd> </dl> <p>The following operations are related to <code>CreateMultipartUpload</code>:</p> <ul> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn create_multipart_upload(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> Result<CreateMultipartUploadOutput, RusotoError<CreateMultipartUploadError>> {
+        self.create_multipart_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the bucket. All objects (including all object versions and delete markers) in the bucket must be deleted before the bucket itself can be deleted.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> </p> </li> <li> <p> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket(
+        &self,
+        input: DeleteBucketRequest,
+    ) -> Result<(), RusotoError<DeleteBucketError>> {
+        self.delete_bucket_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an analytics configuration for the bucket (specified by the analytics configuration ID).</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutAnalyticsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about the Amazon S3 analytics feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/analytics-storage-class.html">Amazon S3 Analytics – Storage Class Analysis</a>. </p> <p>The following operations are related to <code>DeleteBucketAnalyticsConfiguration</code>:</p> <ul> <li> <p> </p> </li> <li> <p> </p> </li> <li> <p> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_analytics_configuration(
+        &self,
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketAnalyticsConfigurationError>> {
+        self.delete_bucket_analytics_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the <code>cors</code> configuration information set for the bucket.</p> <p>To use this operation, you must have permission to perform the <code>s3:PutBucketCORS</code> action. The bucket owner has this permission by default and can grant this permission to others. </p> <p>For information about <code>cors</code>, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cors.html">Enabling Cross-Origin Resource Sharing</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p class="title"> <b>Related Resources:</b> </p> <ul> <li> <p> </p> </li> <li> <p> <a>RESTOPTIONSobject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_cors(
+        &self,
+        input: DeleteBucketCorsRequest,
+    ) -> Result<(), RusotoError<DeleteBucketCorsError>> {
+        self.delete_bucket_cors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This implementation of the DELETE operation removes default encryption from the bucket. For information about the Amazon S3 default encryption feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//bucket-encryption.html">Amazon S3 Default Bucket Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutEncryptionConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>PutBucketEncryption</a> </p> </li> <li> <p> <a>GetBucketEncryption</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_encryption(
+        &self,
+        input: DeleteBucketEncryptionRequest,
+    ) -> Result<(), RusotoError<DeleteBucketEncryptionError>> {
+        self.delete_bucket_encryption_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an inventory configuration (identified by the inventory ID) from the bucket.</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutInventoryConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about the Amazon S3 inventory feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-inventory.html">Amazon S3 Inventory</a>.</p> <p>Operations related to <code>DeleteBucketInventoryConfiguration</code> include: </p> <ul> <li> <p> <a>GetBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>PutBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>ListBucketInventoryConfigurations</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_inventory_configuration(
+        &self,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketInventoryConfigurationError>> {
+        self.delete_bucket_inventory_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the lifecycle configuration from the specified bucket. Amazon S3 removes all the lifecycle configuration rules in the lifecycle subresource associated with the bucket. Your objects never expire, and Amazon S3 no longer automatically deletes any objects on the basis of rules contained in the deleted lifecycle configuration.</p> <p>To use this operation, you must have permission to perform the <code>s3:PutLifecycleConfiguration</code> action. By default, the bucket owner has this permission and the bucket owner can grant this permission to others.</p> <p>There is usually some time lag before lifecycle configuration deletion is fully propagated to all the Amazon S3 systems.</p> <p>For more information about the object expiration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/intro-lifecycle-rules.html#intro-lifecycle-rules-actions">Elements to Describe Lifecycle Actions</a>.</p> <p>Related actions include:</p> <ul> <li> <p> <a>PutBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_lifecycle(
+        &self,
+        input: DeleteBucketLifecycleRequest,
+    ) -> Result<(), RusotoError<DeleteBucketLifecycleError>> {
+        self.delete_bucket_lifecycle_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a metrics configuration for the Amazon CloudWatch request metrics (specified by the metrics configuration ID) from the bucket. Note that this doesn&#39;t include the daily storage metrics.</p> <p> To use this operation, you must have permissions to perform the <code>s3:PutMetricsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about CloudWatch request metrics for Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a>. </p> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>GetBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>PutBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>ListBucketMetricsConfigurations</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_metrics_configuration(
+        &self,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketMetricsConfigurationError>> {
+        self.delete_bucket_metrics_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This implementation of the DELETE operation uses the policy subresource to delete the policy of a specified bucket. If you are using an identity other than the root user of the AWS account that owns the bucket, the calling identity must have the <code>DeleteBucketPolicy</code> permissions on the specified bucket and belong to the bucket owner&#39;s account to use this operation. </p> <p>If you don&#39;t have <code>DeleteBucketPolicy</code> permissions, Amazon S3 returns a <code>403 Access Denied</code> error. If you have the correct permissions, but you&#39;re not using an identity that belongs to the bucket owner&#39;s account, Amazon S3 returns a <code>405 Method Not Allowed</code> error. </p> <important> <p>As a security precaution, the root user of the AWS account that owns a bucket can always use this operation, even if the policy explicitly denies the root user the ability to perform this action.</p> </important> <p>For more information about bucket policies, see <a href=" https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies and UserPolicies</a>. </p> <p>The following operations are related to <code>DeleteBucketPolicy</code> </p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_policy(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteBucketPolicyError>> {
+        self.delete_bucket_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Deletes the replication configuration from the bucket.</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutReplicationConfiguration</code> action. The bucket owner has these permissions by default and can grant it to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>. </p> <note> <p>It can take a while for the deletion of a replication configuration to fully propagate.</p> </note> <p> For information about replication configuration, see <a href=" https://docs.aws.amazon.com/AmazonS3/latest/dev/replication.html">Replication</a> in the <i>Amazon S3 Developer Guide</i>. </p> <p>The following operations are related to <code>DeleteBucketReplication</code>:</p> <ul> <li> <p> <a>PutBucketReplication</a> </p> </li> <li> <p> <a>GetBucketReplication</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_replication(
+        &self,
+        input: DeleteBucketReplicationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketReplicationError>> {
+        self.delete_bucket_replication_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the tags from the bucket.</p> <p>To use this operation, you must have permission to perform the <code>s3:PutBucketTagging</code> action. By default, the bucket owner has this permission and can grant this permission to others. </p> <p>The following operations are related to <code>DeleteBucketTagging</code>:</p> <ul> <li> <p> <a>GetBucketTagging</a> </p> </li> <li> <p> <a>PutBucketTagging</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_tagging(
+        &self,
+        input: DeleteBucketTaggingRequest,
+    ) -> Result<(), RusotoError<DeleteBucketTaggingError>> {
+        self.delete_bucket_tagging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This operation removes the website configuration for a bucket. Amazon S3 returns a <code>200 OK</code> response upon successfully deleting a website configuration on the specified bucket. You will get a <code>200 OK</code> response if the website configuration you are trying to delete does not exist on the bucket. Amazon S3 returns a <code>404</code> response if the bucket specified in the request does not exist.</p> <p>This DELETE operation requires the <code>S3:DeleteBucketWebsite</code> permission. By default, only the bucket owner can delete the website configuration attached to a bucket. However, bucket owners can grant other users permission to delete the website configuration by writing a bucket policy granting them the <code>S3:DeleteBucketWebsite</code> permission. </p> <p>For more information about hosting websites, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/WebsiteHosting.html">Hosting Websites on Amazon S3</a>. </p> <p>The following operations are related to <code>DeleteBucketWebsite</code>:</p> <ul> <li> <p> <a>GetBucketWebsite</a> </p> </li> <li> <p> <a>PutBucketWebsite</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_bucket_website(
+        &self,
+        input: DeleteBucketWebsiteRequest,
+    ) -> Result<(), RusotoError<DeleteBucketWebsiteError>> {
+        self.delete_bucket_website_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes the null version (if there is one) of an object and inserts a delete marker, which becomes the latest version of the object. If there isn&#39;t a null version, Amazon S3 does not remove any objects.</p> <p>To remove a specific version, you must be the bucket owner and you must use the version Id subresource. Using this subresource permanently deletes the version. If the object deleted is a delete marker, Amazon S3 sets the response header, <code>x-amz-delete-marker</code>, to true. </p> <p>If the object you want to delete is in a bucket where the bucket versioning configuration is MFA Delete enabled, you must include the <code>x-amz-mfa</code> request header in the DELETE <code>versionId</code> request. Requests that include <code>x-amz-mfa</code> must use HTTPS. </p> <p> For more information about MFA Delete, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingMFADelete.html">Using MFA Delete</a>. To see sample requests that use versioning, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectDELETE.html#ExampleVersionObjectDelete">Sample Request</a>. </p> <p>You can delete objects by explicitly calling the DELETE Object API or configure its lifecycle (<a>PutBucketLifecycle</a>) to enable Amazon S3 to remove them for you. If you want to block users or accounts from removing or deleting objects from your bucket, you must deny them the <code>s3:DeleteObject</code>, <code>s3:DeleteObjectVersion</code>, and <code>s3:PutLifeCycleConfiguration</code> actions. </p> <p>The following operation is related to <code>DeleteObject</code>:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> Result<DeleteObjectOutput, RusotoError<DeleteObjectError>> {
+        self.delete_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes the entire tag set from the specified object. For more information about managing object tags, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-tagging.html"> Object Tagging</a>.</p> <p>To use this operation, you must have permission to perform the <code>s3:DeleteObjectTagging</code> action.</p> <p>To delete tags of a specific object version, add the <code>versionId</code> query parameter in the request. You will need permission for the <code>s3:DeleteObjectVersionTagging</code> action.</p> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>PutObjectTagging</a> </p> </li> <li> <p> <a>GetObjectTagging</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_object_tagging(
+        &self,
+        input: DeleteObjectTaggingRequest,
+    ) -> Result<DeleteObjectTaggingOutput, RusotoError<DeleteObjectTaggingError>> {
+        self.delete_object_tagging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This operation enables you to delete multiple objects from a bucket using a single HTTP request. If you know the object keys that you want to delete, then this operation provides a suitable alternative to sending individual delete requests, reducing per-request overhead.</p> <p>The request contains a list of up to 1000 keys that you want to delete. In the XML, you provide the object key names, and optionally, version IDs if you want to delete a specific version of the object from a versioning-enabled bucket. For each key, Amazon S3 performs a delete operation and returns the result of that delete, success, or failure, in the response. Note that if the object specified in the request is not found, Amazon S3 returns the result as deleted.</p> <p> The operation supports two modes for the response: verbose and quiet. By default, the operation uses verbose mode in which the response includes the result of deletion of each key in your request. In quiet mode the response includes only keys where the delete operation encountered an error. For a successful deletion, the operation does not return any information about the delete in the response body.</p> <p>When performing this operation on an MFA Delete enabled bucket, that attempts to delete any versioned objects, you must include an MFA token. If you do not provide one, the entire request will fail, even if there are non-versioned objects you are trying to delete. If you provide an invalid token, whether there are versioned keys in the request or not, the entire Multi-Object Delete request will fail. For information about MFA Delete, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/Versioning.html#MultiFactorAuthenticationDelete"> MFA Delete</a>.</p> <p>Finally, the Content-MD5 header is required for all Multi-Object Delete requests. Amazon S3 uses the header value to ensure that your request body has not been altered in transit.</p> <p>The following operations are related to <code>DeleteObjects</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_objects(
+        &self,
+        input: DeleteObjectsRequest,
+    ) -> Result<DeleteObjectsOutput, RusotoError<DeleteObjectsError>> {
+        self.delete_objects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket. To use this operation, you must have the <code>s3:PutBucketPublicAccessBlock</code> permission. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html">Using Amazon S3 Block Public Access</a> </p> </li> <li> <p> <a>GetPublicAccessBlock</a> </p> </li> <li> <p> <a>PutPublicAccessBlock</a> </p> </li> <li> <p> <a>GetBucketPolicyStatus</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_public_access_block(
+        &self,
+        input: DeletePublicAccessBlockRequest,
+    ) -> Result<(), RusotoError<DeletePublicAccessBlockError>> {
+        self.delete_public_access_block_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This implementation of the GET operation uses the <code>accelerate</code> subresource to return the Transfer Acceleration state of a bucket, which is either <code>Enabled</code> or <code>Suspended</code>. Amazon S3 Transfer Acceleration is a bucket-level feature that enables you to perform faster data transfers to and from Amazon S3.</p> <p>To use this operation, you must have permission to perform the <code>s3:GetAccelerateConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>You set the Transfer Acceleration state of an existing bucket to <code>Enabled</code> or <code>Suspended</code> by using the <a>PutBucketAccelerateConfiguration</a> operation. </p> <p>A GET <code>accelerate</code> request does not return a state value for a bucket that has no transfer acceleration state. A bucket has no Transfer Acceleration state if a state has never been set on the bucket. </p> <p>For more information about transfer acceleration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//transfer-acceleration.html">Transfer Acceleration</a> in the Amazon Simple Storage Service Developer Guide.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>PutBucketAccelerateConfiguration</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_accelerate_configuration(
+        &self,
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> Result<
+        GetBucketAccelerateConfigurationOutput,
+        RusotoError<GetBucketAccelerateConfigurationError>,
+    > {
+        self.get_bucket_accelerate_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This implementation of the <code>GET</code> operation uses the <code>acl</code> subresource to return the access control list (ACL) of a bucket. To use <code>GET</code> to return the ACL of the bucket, you must have <code>READ<em>ACP</code> access to the bucket. If <code>READ</em>ACP</code> permission is granted to the anonymous user, you can return the ACL of the bucket without using an authorization header.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_acl(
+        &self,
+        input: GetBucketAclRequest,
+    ) -> Result<GetBucketAclOutput, RusotoError<GetBucketAclError>> {
+        self.get_bucket_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This implementation of the GET operation returns an analytics configuration (identified by the analytics configuration ID) from the bucket.</p> <p>To use this operation, you must have permissions to perform the <code>s3:GetAnalyticsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources"> Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>For information about Amazon S3 analytics feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/analytics-storage-class.html">Amazon S3 Analytics – Storage Class Analysis</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> </p> </li> <li> <p> </p> </li> <li> <p> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_analytics_configuration(
+        &self,
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> Result<
+        GetBucketAnalyticsConfigurationOutput,
+        RusotoError<GetBucketAnalyticsConfigurationError>,
+    > {
+        self.get_bucket_analytics_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the cors configuration information set for the bucket.</p> <p> To use this operation, you must have permission to perform the s3:GetBucketCORS action. By default, the bucket owner has this permission and can grant it to others.</p> <p> For more information about cors, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cors.html"> Enabling Cross-Origin Resource Sharing</a>.</p> <p>The following operations are related to <code>GetBucketCors</code>:</p> <ul> <li> <p> <a>PutBucketCors</a> </p> </li> <li> <p> <a>DeleteBucketCors</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_cors(
+        &self,
+        input: GetBucketCorsRequest,
+    ) -> Result<GetBucketCorsOutput, RusotoError<GetBucketCorsError>> {
+        self.get_bucket_cors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the default encryption configuration for an Amazon S3 bucket. For information about the Amazon S3 default encryption feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/bucket-encryption.html">Amazon S3 Default Bucket Encryption</a>.</p> <p> To use this operation, you must have permission to perform the <code>s3:GetEncryptionConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>The following operations are related to <code>GetBucketEncryption</code>:</p> <ul> <li> <p> <a>PutBucketEncryption</a> </p> </li> <li> <p> <a>DeleteBucketEncryption</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_encryption(
+        &self,
+        input: GetBucketEncryptionRequest,
+    ) -> Result<GetBucketEncryptionOutput, RusotoError<GetBucketEncryptionError>> {
+        self.get_bucket_encryption_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns an inventory configuration (identified by the inventory configuration ID) from the bucket.</p> <p>To use this operation, you must have permissions to perform the <code>s3:GetInventoryConfiguration</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about the Amazon S3 inventory feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-inventory.html">Amazon S3 Inventory</a>.</p> <p>The following operations are related to <code>GetBucketInventoryConfiguration</code>:</p> <ul> <li> <p> <a>DeleteBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>ListBucketInventoryConfigurations</a> </p> </li> <li> <p> <a>PutBucketInventoryConfiguration</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_inventory_configuration(
+        &self,
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> Result<
+        GetBucketInventoryConfigurationOutput,
+        RusotoError<GetBucketInventoryConfigurationError>,
+    > {
+        self.get_bucket_inventory_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><important> <p>For an updated version of this API, see <a>GetBucketLifecycleConfiguration</a>. If you configured a bucket lifecycle using the <code>filter</code> element, you should see the updated version of this topic. This topic is provided for backward compatibility.</p> </important> <p>Returns the lifecycle configuration information set on the bucket. For information about lifecycle configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a>.</p> <p> To use this operation, you must have permission to perform the <code>s3:GetLifecycleConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> <code>GetBucketLifecycle</code> has the following special error:</p> <ul> <li> <p>Error code: <code>NoSuchLifecycleConfiguration</code> </p> <ul> <li> <p>Description: The lifecycle configuration does not exist.</p> </li> <li> <p>HTTP Status Code: 404 Not Found</p> </li> <li> <p>SOAP Fault Code Prefix: Client</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>GetBucketLifecycle</code>:</p> <ul> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>PutBucketLifecycle</a> </p> </li> <li> <p> <a>DeleteBucketLifecycle</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_lifecycle(
+        &self,
+        input: GetBucketLifecycleRequest,
+    ) -> Result<GetBucketLifecycleOutput, RusotoError<GetBucketLifecycleError>> {
+        self.get_bucket_lifecycle_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>Bucket lifecycle configuration now supports specifying a lifecycle rule using an object key name prefix, one or more object tags, or a combination of both. Accordingly, this section describes the latest API. The response describes the new filter element that you can use to specify a filter to select a subset of objects to which the rule applies. If you are still using previous version of the lifecycle configuration, it works. For the earlier API description, see <a>GetBucketLifecycle</a>.</p> </note> <p>Returns the lifecycle configuration information set on the bucket. For information about lifecycle configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a>.</p> <p>To use this operation, you must have permission to perform the <code>s3:GetLifecycleConfiguration</code> action. The bucket owner has this permission, by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> <code>GetBucketLifecycleConfiguration</code> has the following special error:</p> <ul> <li> <p>Error code: <code>NoSuchLifecycleConfiguration</code> </p> <ul> <li> <p>Description: The lifecycle configuration does not exist.</p> </li> <li> <p>HTTP Status Code: 404 Not Found</p> </li> <li> <p>SOAP Fault Code Prefix: Client</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>DeleteBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>GetBucketLifecycle</a> </p> </li> <li> <p> <a>PutBucketLifecycle</a> </p> </li> <li> <p> <a>DeleteBucketLifecycle</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_lifecycle_configuration(
+        &self,
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> Result<
+        GetBucketLifecycleConfigurationOutput,
+        RusotoError<GetBucketLifecycleConfigurationError>,
+    > {
+        self.get_bucket_lifecycle_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the Region the bucket resides in. You set the bucket&#39;s Region using the <code>LocationConstraint</code> request parameter in a <code>CreateBucket</code> request. For more information, see <a>CreateBucket</a>.</p> <p> To use this implementation of the operation, you must be the bucket owner.</p> <p>The following operations are related to <code>GetBucketLocation</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_location(
+        &self,
+        input: GetBucketLocationRequest,
+    ) -> Result<GetBucketLocationOutput, RusotoError<GetBucketLocationError>> {
+        self.get_bucket_location_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the logging status of a bucket and the permissions users have to view and modify that status. To use GET, you must be the bucket owner.</p> <p>The following operations are related to <code>GetBucketLogging</code>:</p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>PutBucketLogging</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_logging(
+        &self,
+        input: GetBucketLoggingRequest,
+    ) -> Result<GetBucketLoggingOutput, RusotoError<GetBucketLoggingError>> {
+        self.get_bucket_logging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Gets a metrics configuration (specified by the metrics configuration ID) from the bucket. Note that this doesn&#39;t include the daily storage metrics.</p> <p> To use this operation, you must have permissions to perform the <code>s3:GetMetricsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> For information about CloudWatch request metrics for Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a>.</p> <p>The following operations are related to <code>GetBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>PutBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>ListBucketMetricsConfigurations</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_metrics_configuration(
+        &self,
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> Result<GetBucketMetricsConfigurationOutput, RusotoError<GetBucketMetricsConfigurationError>>
+    {
+        self.get_bucket_metrics_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> No longer used, see <a>GetBucketNotificationConfiguration</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_notification(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<NotificationConfigurationDeprecated, RusotoError<GetBucketNotificationError>> {
+        self.get_bucket_notification_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the notification configuration of a bucket.</p> <p>If notifications are not enabled on the bucket, the operation returns an empty <code>NotificationConfiguration</code> element.</p> <p>By default, you must be the bucket owner to read the notification configuration of a bucket. However, the bucket owner can use a bucket policy to grant permission to other users to read this configuration with the <code>s3:GetBucketNotification</code> permission.</p> <p>For more information about setting and reading the notification configuration on a bucket, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Setting Up Notification of Bucket Events</a>. For more information about bucket policies, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies</a>.</p> <p>The following operation is related to <code>GetBucketNotification</code>:</p> <ul> <li> <p> <a>PutBucketNotification</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_notification_configuration(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<NotificationConfiguration, RusotoError<GetBucketNotificationConfigurationError>>
+    {
+        self.get_bucket_notification_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the policy of a specified bucket. If you are using an identity other than the root user of the AWS account that owns the bucket, the calling identity must have the <code>GetBucketPolicy</code> permissions on the specified bucket and belong to the bucket owner&#39;s account in order to use this operation.</p> <p>If you don&#39;t have <code>GetBucketPolicy</code> permissions, Amazon S3 returns a <code>403 Access Denied</code> error. If you have the correct permissions, but you&#39;re not using an identity that belongs to the bucket owner&#39;s account, Amazon S3 returns a <code>405 Method Not Allowed</code> error.</p> <important> <p>As a security precaution, the root user of the AWS account that owns a bucket can always use this operation, even if the policy explicitly denies the root user the ability to perform this action.</p> </important> <p>For more information about bucket policies, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies and User Policies</a>.</p> <p>The following operation is related to <code>GetBucketPolicy</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_policy(
+        &self,
+        input: GetBucketPolicyRequest,
+    ) -> Result<GetBucketPolicyOutput, RusotoError<GetBucketPolicyError>> {
+        self.get_bucket_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the policy status for an Amazon S3 bucket, indicating whether the bucket is public. In order to use this operation, you must have the <code>s3:GetBucketPolicyStatus</code> permission. For more information about Amazon S3 permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>.</p> <p> For more information about when Amazon S3 considers a bucket public, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html#access-control-block-public-access-policy-status">The Meaning of &quot;Public&quot;</a>. </p> <p>The following operations are related to <code>GetBucketPolicyStatus</code>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html">Using Amazon S3 Block Public Access</a> </p> </li> <li> <p> <a>GetPublicAccessBlock</a> </p> </li> <li> <p> <a>PutPublicAccessBlock</a> </p> </li> <li> <p> <a>DeletePublicAccessBlock</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_policy_status(
+        &self,
+        input: GetBucketPolicyStatusRequest,
+    ) -> Result<GetBucketPolicyStatusOutput, RusotoError<GetBucketPolicyStatusError>> {
+        self.get_bucket_policy_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the replication configuration of a bucket.</p> <note> <p> It can take a while to propagate the put or delete a replication configuration to all Amazon S3 systems. Therefore, a get request soon after put or delete can return a wrong result. </p> </note> <p> For information about replication configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/replication.html">Replication</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>This operation requires permissions for the <code>s3:GetReplicationConfiguration</code> action. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies and User Policies</a>.</p> <p>If you include the <code>Filter</code> element in a replication configuration, you must also include the <code>DeleteMarkerReplication</code> and <code>Priority</code> elements. The response also returns those elements.</p> <p>For information about <code>GetBucketReplication</code> errors, see <a>ReplicationErrorCodeList</a> </p> <p>The following operations are related to <code>GetBucketReplication</code>:</p> <ul> <li> <p> <a>PutBucketReplication</a> </p> </li> <li> <p> <a>DeleteBucketReplication</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_replication(
+        &self,
+        input: GetBucketReplicationRequest,
+    ) -> Result<GetBucketReplicationOutput, RusotoError<GetBucketReplicationError>> {
+        self.get_bucket_replication_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the request payment configuration of a bucket. To use this version of the operation, you must be the bucket owner. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/RequesterPaysBuckets.html">Requester Pays Buckets</a>.</p> <p>The following operations are related to <code>GetBucketRequestPayment</code>:</p> <ul> <li> <p> <a>ListObjects</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_request_payment(
+        &self,
+        input: GetBucketRequestPaymentRequest,
+    ) -> Result<GetBucketRequestPaymentOutput, RusotoError<GetBucketRequestPaymentError>> {
+        self.get_bucket_request_payment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the tag set associated with the bucket.</p> <p>To use this operation, you must have permission to perform the <code>s3:GetBucketTagging</code> action. By default, the bucket owner has this permission and can grant this permission to others.</p> <p> <code>GetBucketTagging</code> has the following special error:</p> <ul> <li> <p>Error code: <code>NoSuchTagSetError</code> </p> <ul> <li> <p>Description: There is no tag set associated with the bucket.</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>GetBucketTagging</code>:</p> <ul> <li> <p> <a>PutBucketTagging</a> </p> </li> <li> <p> <a>DeleteBucketTagging</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_tagging(
+        &self,
+        input: GetBucketTaggingRequest,
+    ) -> Result<GetBucketTaggingOutput, RusotoError<GetBucketTaggingError>> {
+        self.get_bucket_tagging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the versioning state of a bucket.</p> <p>To retrieve the versioning state of a bucket, you must be the bucket owner.</p> <p>This implementation also returns the MFA Delete status of the versioning state. If the MFA Delete status is <code>enabled</code>, the bucket owner must use an authentication device to change the versioning state of the bucket.</p> <p>The following operations are related to <code>GetBucketVersioning</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_versioning(
+        &self,
+        input: GetBucketVersioningRequest,
+    ) -> Result<GetBucketVersioningOutput, RusotoError<GetBucketVersioningError>> {
+        self.get_bucket_versioning_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the website configuration for a bucket. To host website on Amazon S3, you can configure a bucket as website by adding a website configuration. For more information about hosting websites, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/WebsiteHosting.html">Hosting Websites on Amazon S3</a>. </p> <p>This GET operation requires the <code>S3:GetBucketWebsite</code> permission. By default, only the bucket owner can read the bucket website configuration. However, bucket owners can allow other users to read the website configuration by writing a bucket policy granting them the <code>S3:GetBucketWebsite</code> permission.</p> <p>The following operations are related to <code>DeleteBucketWebsite</code>:</p> <ul> <li> <p> <a>DeleteBucketWebsite</a> </p> </li> <li> <p> <a>PutBucketWebsite</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_bucket_website(
+        &self,
+        input: GetBucketWebsiteRequest,
+    ) -> Result<GetBucketWebsiteOutput, RusotoError<GetBucketWebsiteError>> {
+        self.get_bucket_website_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves objects from Amazon S3. To use <code>GET</code>, you must have <code>READ</code> access to the object. If you grant <code>READ</code> access to the anonymous user, you can return the object without using an authorization header.</p> <p>An Amazon S3 bucket has no directory hierarchy such as you would find in a typical computer file system. You can, however, create a logical hierarchy by using object key names that imply a folder structure. For example, instead of naming an object <code>sample.jpg</code>, you can name it <code>photos/2006/February/sample.jpg</code>.</p> <p>To get an object from such a logical hierarchy, specify the full key name for the object in the <code>GET</code> operation. For a virtual hosted-style request example, if you have the object <code>photos/2006/February/sample.jpg</code>, specify the resource as <code>/photos/2006/February/sample.jpg</code>. For a path-style request example, if you have the object <code>photos/2006/February/sample.jpg</code> in the bucket named <code>examplebucket</code>, specify the resource as <code>/examplebucket/photos/2006/February/sample.jpg</code>. For more information about request types, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/VirtualHosting.html#VirtualHostingSpecifyBucket">HTTP Host Header Bucket Specification</a>.</p> <p>To distribute large files to many people, you can save bandwidth costs by using BitTorrent. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3Torrent.html">Amazon S3 Torrent</a>. For more information about returning the ACL of an object, see <a>GetObjectAcl</a>.</p> <p>If the object you are retrieving is stored in the GLACIER or DEEP_ARCHIVE storage classes, before you can retrieve the object you must first restore a copy using . Otherwise, this operation returns an <code>InvalidObjectStateError</code> error. For information about restoring archived objects, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/restoring-objects.html">Restoring Archived Objects</a>.</p> <p>Encryption request headers, like <code>x-amz-server-side-encryption</code>, should not be sent for GET requests if your object uses server-side encryption with CMKs stored in AWS KMS (SSE-KMS) or server-side encryption with Amazon S3–managed encryption keys (SSE-S3). If your object does use these types of keys, you’ll get an HTTP 400 BadRequest error.</p> <p>If you encrypt an object by using server-side encryption with customer-provided encryption keys (SSE-C) when you store the object in Amazon S3, then when you GET the object, you must use the following headers:</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about SSE-C, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ServerSideEncryptionCustomerKeys.html">Server-Side Encryption (Using Customer-Provided Encryption Keys)</a>.</p> <p>Assuming you have permission to read object tags (permission for the <code>s3:GetObjectVersionTagging</code> action), the response also returns the <code>x-amz-tagging-count</code> header that provides the count of number of tags associated with the object. You can use <a>GetObjectTagging</a> to retrieve the tag set associated with an object.</p> <p> <b>Permissions</b> </p> <p>You need the <code>s3:GetObject</code> permission for this operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>. If the object you request does not exist, the error Amazon S3 returns depends on whether you also have the <code>s3:ListBucket</code> permission.</p> <ul> <li> <p>If you have the <code>s3:ListBucket</code> permission on the bucket, Amazon S3 will return an HTTP status code 404 (&quot;no such key&quot;) error.</p> </li> <li> <p>If you don’t have the <code>s3:ListBucket</code> permission, Amazon S3 will return an HTTP status code 403 (&quot;access denied&quot;) error.</p> </li> </ul> <p> <b>Versioning</b> </p> <p>By default, the GET operation returns the current version of an object. To return a different version, use the <code>versionId</code> subresource.</p> <note> <p>If the current version of the object is a delete marker, Amazon S3 behaves as if the object was deleted and includes <code>x-amz-delete-marker: true</code> in the response.</p> </note> <p>For more information about versioning, see <a>PutBucketVersioning</a>. </p> <p> <b>Overriding Response Header Values</b> </p> <p>There are times when you want to override certain response header values in a GET response. For example, you might override the Content-Disposition response header value in your GET request.</p> <p>You can override values for a set of response headers using the following query parameters. These response header values are sent only on a successful request, that is, when status code 200 OK is returned. The set of headers you can override using these parameters is a subset of the headers that Amazon S3 accepts when you create an object. The response headers that you can override for the GET response are <code>Content-Type</code>, <code>Content-Language</code>, <code>Expires</code>, <code>Cache-Control</code>, <code>Content-Disposition</code>, and <code>Content-Encoding</code>. To override these header values in the GET response, you use the following request parameters.</p> <note> <p>You must sign the request, either using an Authorization header or a presigned URL, when using these parameters. They cannot be used with an unsigned (anonymous) request.</p> </note> <ul> <li> <p> <code>response-content-type</code> </p> </li> <li> <p> <code>response-content-language</code> </p> </li> <li> <p> <code>response-expires</code> </p> </li> <li> <p> <code>response-cache-control</code> </p> </li> <li> <p> <code>response-content-disposition</code> </p> </li> <li> <p> <code>response-content-encoding</code> </p> </li> </ul> <p> <b>Additional Considerations about Request Headers</b> </p> <p>If both of the <code>If-Match</code> and <code>If-Unmodified-Since</code> headers are present in the request as follows: <code>If-Match</code> condition evaluates to <code>true</code>, and; <code>If-Unmodified-Since</code> condition evaluates to <code>false</code>; then, S3 returns 200 OK and the data requested. </p> <p>If both of the <code>If-None-Match</code> and <code>If-Modified-Since</code> headers are present in the request as follows:<code> If-None-Match</code> condition evaluates to <code>false</code>, and; <code>If-Modified-Since</code> condition evaluates to <code>true</code>; then, S3 returns 304 Not Modified response code.</p> <p>For more information about conditional requests, see <a href="https://tools.ietf.org/html/rfc7232">RFC 7232</a>.</p> <p>The following operations are related to <code>GetObject</code>:</p> <ul> <li> <p> <a>ListBuckets</a> </p> </li> <li> <p> <a>GetObjectAcl</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_object(
+        &self,
+        input: GetObjectRequest,
+    ) -> Result<GetObjectOutput, RusotoError<GetObjectError>> {
+        self.get_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the access control list (ACL) of an object. To use this operation, you must have READ_ACP access to the object.</p> <p> <b>Versioning</b> </p> <p>By default, GET returns ACL information about the current version of an object. To return ACL information about a different version, use the versionId subresource.</p> <p>The following operations are related to <code>GetObjectAcl</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_object_acl(
+        &self,
+        input: GetObjectAclRequest,
+    ) -> Result<GetObjectAclOutput, RusotoError<GetObjectAclError>> {
+        self.get_object_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an object's current Legal Hold status. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_object_legal_hold(
+        &self,
+        input: GetObjectLegalHoldRequest,
+    ) -> Result<GetObjectLegalHoldOutput, RusotoError<GetObjectLegalHoldError>> {
+        self.get_object_legal_hold_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the Object Lock configuration for a bucket. The rule specified in the Object Lock configuration will be applied by default to every new object placed in the specified bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_object_lock_configuration(
+        &self,
+        input: GetObjectLockConfigurationRequest,
+    ) -> Result<GetObjectLockConfigurationOutput, RusotoError<GetObjectLockConfigurationError>>
+    {
+        self.get_object_lock_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves an object's retention settings. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_object_retention(
+        &self,
+        input: GetObjectRetentionRequest,
+    ) -> Result<GetObjectRetentionOutput, RusotoError<GetObjectRetentionError>> {
+        self.get_object_retention_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the tag-set of an object. You send the GET request against the tagging subresource associated with the object.</p> <p>To use this operation, you must have permission to perform the <code>s3:GetObjectTagging</code> action. By default, the GET operation returns information about current version of an object. For a versioned bucket, you can have multiple versions of an object in your bucket. To retrieve tags of any other version, use the versionId query parameter. You also need permission for the <code>s3:GetObjectVersionTagging</code> action.</p> <p> By default, the bucket owner has this permission and can grant this permission to others.</p> <p> For information about the Amazon S3 object tagging feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-tagging.html">Object Tagging</a>.</p> <p>The following operation is related to <code>GetObjectTagging</code>:</p> <ul> <li> <p> <a>PutObjectTagging</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_object_tagging(
+        &self,
+        input: GetObjectTaggingRequest,
+    ) -> Result<GetObjectTaggingOutput, RusotoError<GetObjectTaggingError>> {
+        self.get_object_tagging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Return torrent files from a bucket. BitTorrent can save you bandwidth when you&#39;re distributing large files. For more information about BitTorrent, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3Torrent.html">Amazon S3 Torrent</a>.</p> <note> <p>You can get torrent only for objects that are less than 5 GB in size and that are not encrypted using server-side encryption with customer-provided encryption key.</p> </note> <p>To use GET, you must have READ access to the object.</p> <p>The following operation is related to <code>GetObjectTorrent</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_object_torrent(
+        &self,
+        input: GetObjectTorrentRequest,
+    ) -> Result<GetObjectTorrentOutput, RusotoError<GetObjectTorrentError>> {
+        self.get_object_torrent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket. To use this operation, you must have the <code>s3:GetBucketPublicAccessBlock</code> permission. For more information about Amazon S3 permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>.</p> <important> <p>When Amazon S3 evaluates the <code>PublicAccessBlock</code> configuration for a bucket or an object, it checks the <code>PublicAccessBlock</code> configuration for both the bucket (or the bucket that contains the object) and the bucket owner&#39;s account. If the <code>PublicAccessBlock</code> settings are different between the bucket and the account, Amazon S3 uses the most restrictive combination of the bucket-level and account-level settings.</p> </important> <p>For more information about when Amazon S3 considers a bucket or an object public, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html#access-control-block-public-access-policy-status">The Meaning of &quot;Public&quot;</a>.</p> <p>The following operations are related to <code>GetPublicAccessBlock</code>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html">Using Amazon S3 Block Public Access</a> </p> </li> <li> <p> <a>PutPublicAccessBlock</a> </p> </li> <li> <p> <a>GetPublicAccessBlock</a> </p> </li> <li> <p> <a>DeletePublicAccessBlock</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn get_public_access_block(
+        &self,
+        input: GetPublicAccessBlockRequest,
+    ) -> Result<GetPublicAccessBlockOutput, RusotoError<GetPublicAccessBlockError>> {
+        self.get_public_access_block_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation is useful to determine if a bucket exists and you have permission to access it. The operation returns a <code>200 OK</code> if the bucket exists and you have permission to access it. Otherwise, the operation might return responses such as <code>404 Not Found</code> and <code>403 Forbidden</code>. </p> <p>To use this operation, you must have permissions to perform the <code>s3:ListBucket</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn head_bucket(
+        &self,
+        input: HeadBucketRequest,
+    ) -> Result<(), RusotoError<HeadBucketError>> {
+        self.head_bucket_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>The HEAD operation retrieves metadata from an object without returning the object itself. This operation is useful if you&#39;re only interested in an object&#39;s metadata. To use HEAD, you must have READ access to the object.</p> <p>A <code>HEAD</code> request has the same options as a <code>GET</code> operation on an object. The response is identical to the <code>GET</code> response except that there is no response body.</p> <p>If you encrypt an object by using server-side encryption with customer-provided encryption keys (SSE-C) when you store the object in Amazon S3, then when you retrieve the metadata from the object, you must use the following headers:</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about SSE-C, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ServerSideEncryptionCustomerKeys.html">Server-Side Encryption (Using Customer-Provided Encryption Keys)</a>.</p> <note> <p>Encryption request headers, like <code>x-amz-server-side-encryption</code>, should not be sent for GET requests if your object uses server-side encryption with CMKs stored in AWS KMS (SSE-KMS) or server-side encryption with Amazon S3–managed encryption keys (SSE-S3). If your object does use these types of keys, you’ll get an HTTP 400 BadRequest error.</p> </note> <p>Request headers are limited to 8 KB in size. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTCommonRequestHeaders.html">Common Request Headers</a>.</p> <p>Consider the following when using request headers:</p> <ul> <li> <p> Consideration 1 – If both of the <code>If-Match</code> and <code>If-Unmodified-Since</code> headers are present in the request as follows:</p> <ul> <li> <p> <code>If-Match</code> condition evaluates to <code>true</code>, and;</p> </li> <li> <p> <code>If-Unmodified-Since</code> condition evaluates to <code>false</code>;</p> </li> </ul> <p>Then Amazon S3 returns <code>200 OK</code> and the data requested.</p> </li> <li> <p> Consideration 2 – If both of the <code>If-None-Match</code> and <code>If-Modified-Since</code> headers are present in the request as follows:</p> <ul> <li> <p> <code>If-None-Match</code> condition evaluates to <code>false</code>, and;</p> </li> <li> <p> <code>If-Modified-Since</code> condition evaluates to <code>true</code>;</p> </li> </ul> <p>Then Amazon S3 returns the <code>304 Not Modified</code> response code.</p> </li> </ul> <p>For more information about conditional requests, see <a href="https://tools.ietf.org/html/rfc7232">RFC 7232</a>.</p> <p> <b>Permissions</b> </p> <p>You need the <code>s3:GetObject</code> permission for this operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>. If the object you request does not exist, the error Amazon S3 returns depends on whether you also have the s3:ListBucket permission.</p> <ul> <li> <p>If you have the <code>s3:ListBucket</code> permission on the bucket, Amazon S3 returns an HTTP status code 404 (&quot;no such key&quot;) error.</p> </li> <li> <p>If you don’t have the <code>s3:ListBucket</code> permission, Amazon S3 returns an HTTP status code 403 (&quot;access denied&quot;) error.</p> </li> </ul> <p>The following operation is related to <code>HeadObject</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn head_object(
+        &self,
+        input: HeadObjectRequest,
+    ) -> Result<HeadObjectOutput, RusotoError<HeadObjectError>> {
+        self.head_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the analytics configurations for the bucket. You can have up to 1,000 analytics configurations per bucket.</p> <p>This operation supports list pagination and does not return more than 100 configurations at a time. You should always check the <code>IsTruncated</code> element in the response. If there are no more configurations to list, <code>IsTruncated</code> is set to false. If there are more configurations to list, <code>IsTruncated</code> is set to true, and there will be a value in <code>NextContinuationToken</code>. You use the <code>NextContinuationToken</code> value to continue the pagination of the list by passing the value in continuation-token in the request to <code>GET</code> the next page.</p> <p>To use this operation, you must have permissions to perform the <code>s3:GetAnalyticsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about Amazon S3 analytics feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/analytics-storage-class.html">Amazon S3 Analytics – Storage Class Analysis</a>. </p> <p>The following operations are related to <code>ListBucketAnalyticsConfigurations</code>:</p> <ul> <li> <p> <a>GetBucketAnalyticsConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketAnalyticsConfiguration</a> </p> </li> <li> <p> <a>PutBucketAnalyticsConfiguration</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn list_bucket_analytics_configurations(
+        &self,
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> Result<
+        ListBucketAnalyticsConfigurationsOutput,
+        RusotoError<ListBucketAnalyticsConfigurationsError>,
+    > {
+        self.list_bucket_analytics_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a list of inventory configurations for the bucket. You can have up to 1,000 analytics configurations per bucket.</p> <p>This operation supports list pagination and does not return more than 100 configurations at a time. Always check the <code>IsTruncated</code> element in the response. If there are no more configurations to list, <code>IsTruncated</code> is set to false. If there are more configurations to list, <code>IsTruncated</code> is set to true, and there is a value in <code>NextContinuationToken</code>. You use the <code>NextContinuationToken</code> value to continue the pagination of the list by passing the value in continuation-token in the request to <code>GET</code> the next page.</p> <p> To use this operation, you must have permissions to perform the <code>s3:GetInventoryConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about the Amazon S3 inventory feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-inventory.html">Amazon S3 Inventory</a> </p> <p>The following operations are related to <code>ListBucketInventoryConfigurations</code>:</p> <ul> <li> <p> <a>GetBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>PutBucketInventoryConfiguration</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn list_bucket_inventory_configurations(
+        &self,
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> Result<
+        ListBucketInventoryConfigurationsOutput,
+        RusotoError<ListBucketInventoryConfigurationsError>,
+    > {
+        self.list_bucket_inventory_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the metrics configurations for the bucket. The metrics configurations are only for the request metrics of the bucket and do not provide information on daily storage metrics. You can have up to 1,000 configurations per bucket.</p> <p>This operation supports list pagination and does not return more than 100 configurations at a time. Always check the <code>IsTruncated</code> element in the response. If there are no more configurations to list, <code>IsTruncated</code> is set to false. If there are more configurations to list, <code>IsTruncated</code> is set to true, and there is a value in <code>NextContinuationToken</code>. You use the <code>NextContinuationToken</code> value to continue the pagination of the list by passing the value in <code>continuation-token</code> in the request to <code>GET</code> the next page.</p> <p>To use this operation, you must have permissions to perform the <code>s3:GetMetricsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For more information about metrics configurations and CloudWatch request metrics, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a>.</p> <p>The following operations are related to <code>ListBucketMetricsConfigurations</code>:</p> <ul> <li> <p> <a>PutBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>GetBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketMetricsConfiguration</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn list_bucket_metrics_configurations(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> Result<
+        ListBucketMetricsConfigurationsOutput,
+        RusotoError<ListBucketMetricsConfigurationsError>,
+    > {
+        self.list_bucket_metrics_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all buckets owned by the authenticated sender of the request.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_buckets(&self) -> Result<ListBucketsOutput, RusotoError<ListBucketsError>> {
+        self.list_buckets_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This operation lists in-progress multipart uploads. An in-progress multipart upload is a multipart upload that has been initiated using the Initiate Multipart Upload request, but has not yet been completed or aborted.</p> <p>This operation returns at most 1,000 multipart uploads in the response. 1,000 multipart uploads is the maximum number of uploads a response can include, which is also the default value. You can further limit the number of uploads in a response by specifying the <code>max-uploads</code> parameter in the response. If additional multipart uploads satisfy the list criteria, the response will contain an <code>IsTruncated</code> element with the value true. To list the additional multipart uploads, use the <code>key-marker</code> and <code>upload-id-marker</code> request parameters.</p> <p>In the response, the uploads are sorted by key. If your application has initiated more than one multipart upload using the same object key, then uploads in the response are first sorted by key. Additionally, uploads are sorted in ascending order within each key by the upload initiation time.</p> <p>For more information on multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/uploadobjusingmpu.html">Uploading Objects Using Multipart Upload</a>.</p> <p>For information on permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p>The following operations are related to <code>ListMultipartUploads</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>> {
+        self.list_multipart_uploads_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns metadata about all of the versions of objects in a bucket. You can also use request parameters as selection criteria to return metadata about a subset of all the object versions. </p> <note> <p> A 200 OK response can contain valid or invalid XML. Make sure to design your application to parse the contents of the response and handle it appropriately.</p> </note> <p>To use this operation, you must have READ access to the bucket.</p> <p>The following operations are related to <code>ListObjectVersions</code>:</p> <ul> <li> <p> <a>ListObjectsV2</a> </p> </li> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn list_object_versions(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> Result<ListObjectVersionsOutput, RusotoError<ListObjectVersionsError>> {
+        self.list_object_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns some or all (up to 1,000) of the objects in a bucket. You can use the request parameters as selection criteria to return a subset of the objects in a bucket. A 200 OK response can contain valid or invalid XML. Be sure to design your application to parse the contents of the response and handle it appropriately.</p> <important> <p>This API has been revised. We recommend that you use the newer version, <a>ListObjectsV2</a>, when developing applications. For backward compatibility, Amazon S3 continues to support <code>ListObjects</code>.</p> </important> <p>The following operations are related to <code>ListObjects</code>:</p> <ul> <li> <p> <a>ListObjectsV2</a> </p> </li> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>ListBuckets</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn list_objects(
+        &self,
+        input: ListObjectsRequest,
+    ) -> Result<ListObjectsOutput, RusotoError<ListObjectsError>> {
+        self.list_objects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns some or all (up to 1,000) of the objects in a bucket. You can use the request parameters as selection criteria to return a subset of the objects in a bucket. A <code>200 OK</code> response can contain valid or invalid XML. Make sure to design your application to parse the contents of the response and handle it appropriately.</p> <p>To use this operation, you must have READ access to the bucket.</p> <p>To use this operation in an AWS Identity and Access Management (IAM) policy, you must have permissions to perform the <code>s3:ListBucket</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <important> <p>This section describes the latest revision of the API. We recommend that you use this revised API for application development. For backward compatibility, Amazon S3 continues to support the prior version of this API, <a>ListObjects</a>.</p> </important> <p>To get a list of your buckets, see <a>ListBuckets</a>.</p> <p>The following operations are related to <code>ListObjectsV2</code>:</p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn list_objects_v2(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>> {
+        self.list_objects_v2_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the parts that have been uploaded for a specific multipart upload. This operation must include the upload ID, which you obtain by sending the initiate multipart upload request (see <a>CreateMultipartUpload</a>). This request returns a maximum of 1,000 uploaded parts. The default number of parts returned is 1,000 parts. You can restrict the number of parts returned by specifying the <code>max-parts</code> request parameter. If your multipart upload consists of more than 1,000 parts, the response returns an <code>IsTruncated</code> field with the value of true, and a <code>NextPartNumberMarker</code> element. In subsequent <code>ListParts</code> requests you can include the part-number-marker query string parameter and set its value to the <code>NextPartNumberMarker</code> field value from the previous response.</p> <p>For more information on multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/uploadobjusingmpu.html">Uploading Objects Using Multipart Upload</a>.</p> <p>For information on permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a>.</p> <p>The following operations are related to <code>ListParts</code>:</p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn list_parts(
+        &self,
+        input: ListPartsRequest,
+    ) -> Result<ListPartsOutput, RusotoError<ListPartsError>> {
+        self.list_parts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the accelerate configuration of an existing bucket. Amazon S3 Transfer Acceleration is a bucket-level feature that enables you to perform faster data transfers to Amazon S3.</p> <p> To use this operation, you must have permission to perform the s3:PutAccelerateConfiguration action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> The Transfer Acceleration state of a bucket can be set to one of the following two values:</p> <ul> <li> <p> Enabled – Enables accelerated data transfers to the bucket.</p> </li> <li> <p> Suspended – Disables accelerated data transfers to the bucket.</p> </li> </ul> <p>The <a>GetBucketAccelerateConfiguration</a> operation returns the transfer acceleration state of a bucket.</p> <p>After setting the Transfer Acceleration state of a bucket to Enabled, it might take up to thirty minutes before the data transfer rates to the bucket increase.</p> <p> The name of the bucket used for Transfer Acceleration must be DNS-compliant and must not contain periods (&quot;.&quot;).</p> <p> For more information about transfer acceleration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/transfer-acceleration.html">Transfer Acceleration</a>.</p> <p>The following operations are related to <code>PutBucketAccelerateConfiguration</code>:</p> <ul> <li> <p> <a>GetBucketAccelerateConfiguration</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_accelerate_configuration(
+        &self,
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketAccelerateConfigurationError>> {
+        self.put_bucket_accelerate_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the permissions on an existing bucket using access control lists (ACL). For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>. To set the ACL of a bucket, you must have <code>WRITE_ACP</code> permission.</p> <p>You can use one of the following two ways to set a bucket&#39;s permissions:</p> <ul> <li> <p>Specify the ACL in the request body</p> </li> <li> <p>Specify permissions using request headers</p> </li> </ul> <note> <p>You cannot specify access permission using both the body and the request headers.</p> </note> <p>Depending on your application needs, you may choose to set the ACL on a bucket using either the request body or the headers. For example, if you have an existing application that updates a bucket ACL using the request body, then you can continue to use that approach.</p> <p> <b>Access Permissions</b> </p> <p>You can set access permissions using one of the following methods:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. Amazon S3 supports a set of predefined ACLs, known as <i>canned ACLs</i>. Each canned ACL has a predefined set of grantees and permissions. Specify the canned ACL name as the value of <code>x-amz-acl</code>. If you use this header, you cannot use other access control-specific headers in your request. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. When using these headers, you specify explicit access permissions and grantees (AWS accounts or Amazon S3 groups) who will receive the permission. If you use these ACL-specific headers, you cannot use the <code>x-amz-acl</code> header to set a canned ACL. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-write</code> header grants create, overwrite, and delete objects permission to LogDelivery group predefined by Amazon S3 and two AWS accounts identified by their email addresses.</p> <p> <code>x-amz-grant-write: uri=&quot;http://acs.amazonaws.com/groups/s3/LogDelivery&quot;, emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> <p> <b>Grantee Values</b> </p> <p>You can specify the person (grantee) to whom you&#39;re assigning access rights (using request elements) in the following ways:</p> <ul> <li> <p>By Email address:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;AmazonCustomerByEmail&quot;&gt;&lt;EmailAddress&gt;&lt;&gt;Grantees@email.com&lt;&gt;&lt;/EmailAddress&gt;lt;/Grantee&gt;</code> </p> <p>The grantee is resolved to the CanonicalUser and, in a response to a GET Object acl request, appears as the CanonicalUser.</p> </li> <li> <p>By the person&#39;s ID:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;CanonicalUser&quot;&gt;&lt;ID&gt;&lt;&gt;ID&lt;&gt;&lt;/ID&gt;&lt;DisplayName&gt;&lt;&gt;GranteesEmail&lt;&gt;&lt;/DisplayName&gt; &lt;/Grantee&gt;</code> </p> <p>DisplayName is optional and ignored in the request</p> </li> <li> <p>By URI:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;Group&quot;&gt;&lt;URI&gt;&lt;&gt;http://acs.amazonaws.com/groups/global/AuthenticatedUsers&lt;&gt;&lt;/URI&gt;&lt;/Grantee&gt;</code> </p> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> <li> <p> <a>GetObjectAcl</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_acl(
+        &self,
+        input: PutBucketAclRequest,
+    ) -> Result<(), RusotoError<PutBucketAclError>> {
+        self.put_bucket_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets an analytics configuration for the bucket (specified by the analytics configuration ID). You can have up to 1,000 analytics configurations per bucket.</p> <p>You can choose to have storage class analysis export analysis reports sent to a comma-separated values (CSV) flat file. See the <code>DataExport</code> request element. Reports are updated daily and are based on the object filters that you configure. When selecting data export, you specify a destination bucket and an optional destination prefix where the file is written. You can export the data to a destination bucket in a different account. However, the destination bucket must be in the same Region as the bucket that you are making the PUT analytics configuration to. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/analytics-storage-class.html">Amazon S3 Analytics – Storage Class Analysis</a>. </p> <important> <p>You must create a bucket policy on the destination bucket where the exported file is written to grant permissions to Amazon S3 to write objects to the bucket. For an example policy, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/example-bucket-policies.html#example-bucket-policies-use-case-9">Granting Permissions for Amazon S3 Inventory and Storage Class Analysis</a>.</p> </important> <p>To use this operation, you must have permissions to perform the <code>s3:PutAnalyticsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <ul> <li> <p> <i>HTTP Error: HTTP 400 Bad Request</i> </p> </li> <li> <p> <i>Code: InvalidArgument</i> </p> </li> <li> <p> <i>Cause: Invalid argument.</i> </p> </li> </ul> </li> <li> <ul> <li> <p> <i>HTTP Error: HTTP 400 Bad Request</i> </p> </li> <li> <p> <i>Code: TooManyConfigurations</i> </p> </li> <li> <p> <i>Cause: You are attempting to create a new configuration but have already reached the 1,000-configuration limit.</i> </p> </li> </ul> </li> <li> <ul> <li> <p> <i>HTTP Error: HTTP 403 Forbidden</i> </p> </li> <li> <p> <i>Code: AccessDenied</i> </p> </li> <li> <p> <i>Cause: You are not the owner of the specified bucket, or you do not have the s3:PutAnalyticsConfiguration bucket permission to set the configuration on the bucket.</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> </p> </li> <li> <p> </p> </li> <li> <p> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_analytics_configuration(
+        &self,
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketAnalyticsConfigurationError>> {
+        self.put_bucket_analytics_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the <code>cors</code> configuration for your bucket. If the configuration exists, Amazon S3 replaces it.</p> <p>To use this operation, you must be allowed to perform the <code>s3:PutBucketCORS</code> action. By default, the bucket owner has this permission and can grant it to others.</p> <p>You set this configuration on a bucket so that the bucket can service cross-origin requests. For example, you might want to enable a request whose origin is <code>http://www.example.com</code> to access your Amazon S3 bucket at <code>my.example.bucket.com</code> by using the browser&#39;s <code>XMLHttpRequest</code> capability.</p> <p>To enable cross-origin resource sharing (CORS) on a bucket, you add the <code>cors</code> subresource to the bucket. The <code>cors</code> subresource is an XML document in which you configure rules that identify origins and the HTTP methods that can be executed on your bucket. The document is limited to 64 KB in size. </p> <p>When Amazon S3 receives a cross-origin request (or a pre-flight OPTIONS request) against a bucket, it evaluates the <code>cors</code> configuration on the bucket and uses the first <code>CORSRule</code> rule that matches the incoming browser request to enable a cross-origin request. For a rule to match, the following conditions must be met:</p> <ul> <li> <p>The request&#39;s <code>Origin</code> header must match <code>AllowedOrigin</code> elements.</p> </li> <li> <p>The request method (for example, GET, PUT, HEAD, and so on) or the <code>Access-Control-Request-Method</code> header in case of a pre-flight <code>OPTIONS</code> request must be one of the <code>AllowedMethod</code> elements. </p> </li> <li> <p>Every header specified in the <code>Access-Control-Request-Headers</code> request header of a pre-flight request must match an <code>AllowedHeader</code> element. </p> </li> </ul> <p> For more information about CORS, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cors.html">Enabling Cross-Origin Resource Sharing</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetBucketCors</a> </p> </li> <li> <p> <a>DeleteBucketCors</a> </p> </li> <li> <p> <a>RESTOPTIONSobject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_cors(
+        &self,
+        input: PutBucketCorsRequest,
+    ) -> Result<(), RusotoError<PutBucketCorsError>> {
+        self.put_bucket_cors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This implementation of the <code>PUT</code> operation uses the <code>encryption</code> subresource to set the default encryption state of an existing bucket.</p> <p>This implementation of the <code>PUT</code> operation sets default encryption for a bucket using server-side encryption with Amazon S3-managed keys SSE-S3 or AWS KMS customer master keys (CMKs) (SSE-KMS).</p> <important> <p>This operation requires AWS Signature Version 4. For more information, see <a href="sig-v4-authenticating-requests.html"> Authenticating Requests (AWS Signature Version 4)</a>. </p> </important> <p>To use this operation, you must have permissions to perform the <code>s3:PutEncryptionConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a> in the Amazon Simple Storage Service Developer Guide. </p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetBucketEncryption</a> </p> </li> <li> <p> <a>DeleteBucketEncryption</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_encryption(
+        &self,
+        input: PutBucketEncryptionRequest,
+    ) -> Result<(), RusotoError<PutBucketEncryptionError>> {
+        self.put_bucket_encryption_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This implementation of the <code>PUT</code> operation adds an inventory configuration (identified by the inventory ID) to the bucket. You can have up to 1,000 inventory configurations per bucket. </p> <p>Amazon S3 inventory generates inventories of the objects in the bucket on a daily or weekly basis, and the results are published to a flat file. The bucket that is inventoried is called the <i>source</i> bucket, and the bucket where the inventory flat file is stored is called the <i>destination</i> bucket. The <i>destination</i> bucket must be in the same AWS Region as the <i>source</i> bucket. </p> <p>When you configure an inventory for a <i>source</i> bucket, you specify the <i>destination</i> bucket where you want the inventory to be stored, and whether to generate the inventory daily or weekly. You can also configure what object metadata to include and whether to inventory all object versions or only current versions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//storage-inventory.html">Amazon S3 Inventory</a> in the Amazon Simple Storage Service Developer Guide.</p> <important> <p>You must create a bucket policy on the <i>destination</i> bucket to grant permissions to Amazon S3 to write objects to the bucket in the defined location. For an example policy, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/example-bucket-policies.html#example-bucket-policies-use-case-9"> Granting Permissions for Amazon S3 Inventory and Storage Class Analysis.</a> </p> </important> <p>To use this operation, you must have permissions to perform the <code>s3:PutInventoryConfiguration</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a> in the Amazon Simple Storage Service Developer Guide.</p> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b>HTTP 400 Bad Request Error</b> </p> <ul> <li> <p> <i>Code:</i> InvalidArgument</p> </li> <li> <p> <i>Cause:</i> Invalid Argument</p> </li> </ul> </li> <li> <p class="title"> <b>HTTP 400 Bad Request Error</b> </p> <ul> <li> <p> <i>Code:</i> TooManyConfigurations</p> </li> <li> <p> <i>Cause:</i> You are attempting to create a new configuration but have already reached the 1,000-configuration limit. </p> </li> </ul> </li> <li> <p class="title"> <b>HTTP 403 Forbidden Error</b> </p> <ul> <li> <p> <i>Code:</i> AccessDenied</p> </li> <li> <p> <i>Cause:</i> You are not the owner of the specified bucket, or you do not have the <code>s3:PutInventoryConfiguration</code> bucket permission to set the configuration on the bucket </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketInventoryConfiguration</a> </p> </li> <li> <p> <a>ListBucketInventoryConfigurations</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_inventory_configuration(
+        &self,
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketInventoryConfigurationError>> {
+        self.put_bucket_inventory_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><important> <p>For an updated version of this API, see <a>PutBucketLifecycleConfiguration</a>. This version has been deprecated. Existing lifecycle configurations will work. For new lifecycle configurations, use the updated API. </p> </important> <p>Creates a new lifecycle configuration for the bucket or replaces an existing lifecycle configuration. For information about lifecycle configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//object-lifecycle-mgmt.html">Object Lifecycle Management</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>By default, all Amazon S3 resources, including buckets, objects, and related subresources (for example, lifecycle configuration and website configuration) are private. Only the resource owner, the AWS account that created the resource, can access it. The resource owner can optionally grant access permissions to others by writing an access policy. For this operation, users must get the <code>s3:PutLifecycleConfiguration</code> permission.</p> <p>You can also explicitly deny permissions. Explicit denial also supersedes any other permissions. If you want to prevent users or accounts from removing or deleting objects from your bucket, you must deny them permissions for the following actions: </p> <ul> <li> <p> <code>s3:DeleteObject</code> </p> </li> <li> <p> <code>s3:DeleteObjectVersion</code> </p> </li> <li> <p> <code>s3:PutLifecycleConfiguration</code> </p> </li> </ul> <p>For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>For more examples of transitioning objects to storage classes such as STANDARD<em>IA or ONEZONE</em>IA, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//intro-lifecycle-rules.html#lifecycle-configuration-examples">Examples of Lifecycle Configuration</a>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetBucketLifecycle</a>(Deprecated)</p> </li> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> <li> <p> </p> </li> <li> <p>By default, a resource owner—in this case, a bucket owner, which is the AWS account that created the bucket—can perform any of the operations. A resource owner can also grant others permission to perform the operation. For more information, see the following topics in the Amazon Simple Storage Service Developer Guide: </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//using-with-s3-actions.html">Specifying Permissions in a Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev//s3-access-control.html">Managing Access Permissions to your Amazon S3 Resources</a> </p> </li> </ul> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_lifecycle(
+        &self,
+        input: PutBucketLifecycleRequest,
+    ) -> Result<(), RusotoError<PutBucketLifecycleError>> {
+        self.put_bucket_lifecycle_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new lifecycle configuration for the bucket or replaces an existing lifecycle configuration. For information about lifecycle configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <note> <p>Bucket lifecycle configuration now supports specifying a lifecycle rule using an object key name prefix, one or more object tags, or a combination of both. Accordingly, this section describes the latest API. The previous version of the API supported filtering based only on an object key name prefix, which is supported for backward compatibility. For the related API description, see <a>PutBucketLifecycle</a>.</p> </note> <p> <b>Rules</b> </p> <p>You specify the lifecycle configuration in your request body. The lifecycle configuration is specified as XML consisting of one or more rules. Each rule consists of the following:</p> <ul> <li> <p>Filter identifying a subset of objects to which the rule applies. The filter can be based on a key name prefix, object tags, or a combination of both.</p> </li> <li> <p>Status whether the rule is in effect.</p> </li> <li> <p>One or more lifecycle transition and expiration actions that you want Amazon S3 to perform on the objects identified by the filter. If the state of your bucket is versioning-enabled or versioning-suspended, you can have many versions of the same object (one current version and zero or more noncurrent versions). Amazon S3 provides predefined actions that you can specify for current and noncurrent object versions.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/intro-lifecycle-rules.html">Lifecycle Configuration Elements</a>.</p> <p> <b>Permissions</b> </p> <p>By default, all Amazon S3 resources are private, including buckets, objects, and related subresources (for example, lifecycle configuration and website configuration). Only the resource owner (that is, the AWS account that created it) can access the resource. The resource owner can optionally grant access permissions to others by writing an access policy. For this operation, a user must get the s3:PutLifecycleConfiguration permission.</p> <p>You can also explicitly deny permissions. Explicit deny also supersedes any other permissions. If you want to block users or accounts from removing or deleting objects from your bucket, you must deny them permissions for the following actions:</p> <ul> <li> <p>s3:DeleteObject</p> </li> <li> <p>s3:DeleteObjectVersion</p> </li> <li> <p>s3:PutLifecycleConfiguration</p> </li> </ul> <p>For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>The following are related to <code>PutBucketLifecycleConfiguration</code>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/lifecycle-configuration-examples.html">Examples of Lifecycle Configuration</a> </p> </li> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>DeleteBucketLifecycle</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_lifecycle_configuration(
+        &self,
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketLifecycleConfigurationError>> {
+        self.put_bucket_lifecycle_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Set the logging parameters for a bucket and to specify permissions for who can view and modify the logging parameters. All logs are saved to buckets in the same AWS Region as the source bucket. To set the logging status of a bucket, you must be the bucket owner.</p> <p>The bucket owner is automatically granted FULL_CONTROL to all logs. You use the <code>Grantee</code> request element to grant access to other people. The <code>Permissions</code> request element specifies the kind of access the grantee has to the logs.</p> <p> <b>Grantee Values</b> </p> <p>You can specify the person (grantee) to whom you&#39;re assigning access rights (using request elements) in the following ways:</p> <ul> <li> <p>By the person&#39;s ID:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;CanonicalUser&quot;&gt;&lt;ID&gt;&lt;&gt;ID&lt;&gt;&lt;/ID&gt;&lt;DisplayName&gt;&lt;&gt;GranteesEmail&lt;&gt;&lt;/DisplayName&gt; &lt;/Grantee&gt;</code> </p> <p>DisplayName is optional and ignored in the request.</p> </li> <li> <p>By Email address:</p> <p> <code> &lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;AmazonCustomerByEmail&quot;&gt;&lt;EmailAddress&gt;&lt;&gt;Grantees@email.com&lt;&gt;&lt;/EmailAddress&gt;&lt;/Grantee&gt;</code> </p> <p>The grantee is resolved to the CanonicalUser and, in a response to a GET Object acl request, appears as the CanonicalUser.</p> </li> <li> <p>By URI:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;Group&quot;&gt;&lt;URI&gt;&lt;&gt;http://acs.amazonaws.com/groups/global/AuthenticatedUsers&lt;&gt;&lt;/URI&gt;&lt;/Grantee&gt;</code> </p> </li> </ul> <p>To enable logging, you use LoggingEnabled and its children request elements. To disable logging, you use an empty BucketLoggingStatus request element:</p> <p> <code>&lt;BucketLoggingStatus xmlns=&quot;http://doc.s3.amazonaws.com/2006-03-01&quot; /&gt;</code> </p> <p>For more information about server access logging, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ServerLogs.html">Server Access Logging</a>. </p> <p>For more information about creating a bucket, see <a>CreateBucket</a>. For more information about returning the logging status of a bucket, see <a>GetBucketLogging</a>.</p> <p>The following operations are related to <code>PutBucketLogging</code>:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>GetBucketLogging</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_logging(
+        &self,
+        input: PutBucketLoggingRequest,
+    ) -> Result<(), RusotoError<PutBucketLoggingError>> {
+        self.put_bucket_logging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets a metrics configuration (specified by the metrics configuration ID) for the bucket. You can have up to 1,000 metrics configurations per bucket. If you&#39;re updating an existing metrics configuration, note that this is a full replacement of the existing metrics configuration. If you don&#39;t include the elements you want to keep, they are erased.</p> <p>To use this operation, you must have permissions to perform the <code>s3:PutMetricsConfiguration</code> action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p>For information about CloudWatch request metrics for Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cloudwatch-monitoring.html">Monitoring Metrics with Amazon CloudWatch</a>.</p> <p>The following operations are related to <code>PutBucketMetricsConfiguration</code>:</p> <ul> <li> <p> <a>DeleteBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>PutBucketMetricsConfiguration</a> </p> </li> <li> <p> <a>ListBucketMetricsConfigurations</a> </p> </li> </ul> <p> <code>GetBucketLifecycle</code> has the following special error:</p> <ul> <li> <p>Error code: <code>TooManyConfigurations</code> </p> <ul> <li> <p>Description: You are attempting to create a new configuration but have already reached the 1,000-configuration limit.</p> </li> <li> <p>HTTP Status Code: HTTP 400 Bad Request</p> </li> </ul> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_metrics_configuration(
+        &self,
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketMetricsConfigurationError>> {
+        self.put_bucket_metrics_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> No longer used, see the <a>PutBucketNotificationConfiguration</a> operation.</p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_notification(
+        &self,
+        input: PutBucketNotificationRequest,
+    ) -> Result<(), RusotoError<PutBucketNotificationError>> {
+        self.put_bucket_notification_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Enables notifications of specified events for a bucket. For more information about event notifications, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Configuring Event Notifications</a>.</p> <p>Using this API, you can replace an existing notification configuration. The configuration is an XML file that defines the event types that you want Amazon S3 to publish and the destination where you want Amazon S3 to publish an event notification when it detects an event of the specified type.</p> <p>By default, your bucket has no event notifications configured. That is, the notification configuration will be an empty <code>NotificationConfiguration</code>.</p> <p> <code>&lt;NotificationConfiguration&gt;</code> </p> <p> <code>&lt;/NotificationConfiguration&gt;</code> </p> <p>This operation replaces the existing notification configuration with the configuration you include in the request body.</p> <p>After Amazon S3 receives this request, it first verifies that any Amazon Simple Notification Service (Amazon SNS) or Amazon Simple Queue Service (Amazon SQS) destination exists, and that the bucket owner has permission to publish to it by sending a test notification. In the case of AWS Lambda destinations, Amazon S3 verifies that the Lambda function permissions grant Amazon S3 permission to invoke the function from the Amazon S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Configuring Notifications for Amazon S3 Events</a>.</p> <p>You can disable notifications by adding the empty NotificationConfiguration element.</p> <p>By default, only the bucket owner can configure notifications on a bucket. However, bucket owners can use a bucket policy to grant permission to other users to set this configuration with <code>s3:PutBucketNotification</code> permission.</p> <note> <p>The PUT notification is an atomic operation. For example, suppose your notification configuration includes SNS topic, SQS queue, and Lambda function configurations. When you send a PUT request with this configuration, Amazon S3 sends test messages to your SNS topic. If the message fails, the entire PUT operation will fail, and Amazon S3 will not add the configuration to your bucket.</p> </note> <p> <b>Responses</b> </p> <p>If the configuration in the request body includes only one <code>TopicConfiguration</code> specifying only the <code>s3:ReducedRedundancyLostObject</code> event type, the response will also include the <code>x-amz-sns-test-message-id</code> header containing the message ID of the test notification sent to the topic.</p> <p>The following operation is related to <code>PutBucketNotificationConfiguration</code>:</p> <ul> <li> <p> <a>GetBucketNotificationConfiguration</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_notification_configuration(
+        &self,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketNotificationConfigurationError>> {
+        self.put_bucket_notification_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Applies an Amazon S3 bucket policy to an Amazon S3 bucket. If you are using an identity other than the root user of the AWS account that owns the bucket, the calling identity must have the <code>PutBucketPolicy</code> permissions on the specified bucket and belong to the bucket owner&#39;s account in order to use this operation.</p> <p>If you don&#39;t have <code>PutBucketPolic</code>y permissions, Amazon S3 returns a <code>403 Access Denied</code> error. If you have the correct permissions, but you&#39;re not using an identity that belongs to the bucket owner&#39;s account, Amazon S3 returns a <code>405 Method Not Allowed</code> error.</p> <important> <p> As a security precaution, the root user of the AWS account that owns a bucket can always use this operation, even if the policy explicitly denies the root user the ability to perform this action. </p> </important> <p>For more information about bucket policies, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-iam-policies.html">Using Bucket Policies and User Policies</a>.</p> <p>The following operations are related to <code>PutBucketPolicy</code>:</p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_policy(
+        &self,
+        input: PutBucketPolicyRequest,
+    ) -> Result<(), RusotoError<PutBucketPolicyError>> {
+        self.put_bucket_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Creates a replication configuration or replaces an existing one. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/replication.html">Replication</a> in the <i>Amazon S3 Developer Guide</i>. </p> <note> <p>To perform this operation, the user or role performing the operation must have the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">iam:PassRole</a> permission.</p> </note> <p>Specify the replication configuration in the request body. In the replication configuration, you provide the name of the destination bucket where you want Amazon S3 to replicate objects, the IAM role that Amazon S3 can assume to replicate objects on your behalf, and other relevant information.</p> <p>A replication configuration must include at least one rule, and can contain a maximum of 1,000. Each rule identifies a subset of objects to replicate by filtering the objects in the source bucket. To choose additional subsets of objects to replicate, add a rule for each subset. All rules must specify the same destination bucket.</p> <p>To specify a subset of the objects in the source bucket to apply a replication rule to, add the Filter element as a child of the Rule element. You can filter objects based on an object key prefix, one or more object tags, or both. When you add the Filter element in the configuration, you must also add the following elements: <code>DeleteMarkerReplication</code>, <code>Status</code>, and <code>Priority</code>.</p> <p>For information about enabling versioning on a bucket, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/Versioning.html">Using Versioning</a>.</p> <p>By default, a resource owner, in this case the AWS account that created the bucket, can perform this operation. The resource owner can also grant others permissions to perform the operation. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> <b>Handling Replication of Encrypted Objects</b> </p> <p>By default, Amazon S3 doesn&#39;t replicate objects that are stored at rest using server-side encryption with CMKs stored in AWS KMS. To replicate AWS KMS-encrypted objects, add the following: <code>SourceSelectionCriteria</code>, <code>SseKmsEncryptedObjects</code>, <code>Status</code>, <code>EncryptionConfiguration</code>, and <code>ReplicaKmsKeyID</code>. For information about replication configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/replication-config-for-kms-objects.html">Replicating Objects Created with SSE Using CMKs stored in AWS KMS</a>.</p> <p>For information on <code>PutBucketReplication</code> errors, see <a>ReplicationErrorCodeList</a> </p> <p>The following operations are related to <code>PutBucketReplication</code>:</p> <ul> <li> <p> <a>GetBucketReplication</a> </p> </li> <li> <p> <a>DeleteBucketReplication</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_replication(
+        &self,
+        input: PutBucketReplicationRequest,
+    ) -> Result<(), RusotoError<PutBucketReplicationError>> {
+        self.put_bucket_replication_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the request payment configuration for a bucket. By default, the bucket owner pays for downloads from the bucket. This configuration parameter enables the bucket owner (only) to specify that the person requesting the download will be charged for the download. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/RequesterPaysBuckets.html">Requester Pays Buckets</a>.</p> <p>The following operations are related to <code>PutBucketRequestPayment</code>:</p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>GetBucketRequestPayment</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_request_payment(
+        &self,
+        input: PutBucketRequestPaymentRequest,
+    ) -> Result<(), RusotoError<PutBucketRequestPaymentError>> {
+        self.put_bucket_request_payment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the tags for a bucket.</p> <p>Use tags to organize your AWS bill to reflect your own cost structure. To do this, sign up to get your AWS account bill with tag key values included. Then, to see the cost of combined resources, organize your billing information according to resources with the same tag key values. For example, you can tag several resources with a specific application name, and then organize your billing information to see the total cost of that application across several services. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Cost Allocation and Tagging</a>.</p> <note> <p>Within a bucket, if you add a tag that has the same key as an existing tag, the new value overwrites the old value. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/CostAllocTagging.html">Using Cost Allocation in Amazon S3 Bucket Tags</a>.</p> </note> <p>To use this operation, you must have permissions to perform the <code>s3:PutBucketTagging</code> action. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a>.</p> <p> <code>PutBucketTagging</code> has the following special errors:</p> <ul> <li> <p>Error code: <code>InvalidTagError</code> </p> <ul> <li> <p>Description: The tag provided was not a valid tag. This error can occur if the tag did not pass input validation. For information about tag restrictions, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2//allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> and <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2//aws-tag-restrictions.html">AWS-Generated Cost Allocation Tag Restrictions</a>.</p> </li> </ul> </li> <li> <p>Error code: <code>MalformedXMLError</code> </p> <ul> <li> <p>Description: The XML provided does not match the schema.</p> </li> </ul> </li> <li> <p>Error code: <code>OperationAbortedError </code> </p> <ul> <li> <p>Description: A conflicting conditional operation is currently in progress against this resource. Please try again.</p> </li> </ul> </li> <li> <p>Error code: <code>InternalError</code> </p> <ul> <li> <p>Description: The service was unable to apply the provided tag to the bucket.</p> </li> </ul> </li> </ul> <p>The following operations are related to <code>PutBucketTagging</code>:</p> <ul> <li> <p> <a>GetBucketTagging</a> </p> </li> <li> <p> <a>DeleteBucketTagging</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_tagging(
+        &self,
+        input: PutBucketTaggingRequest,
+    ) -> Result<(), RusotoError<PutBucketTaggingError>> {
+        self.put_bucket_tagging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the versioning state of an existing bucket. To set the versioning state, you must be the bucket owner.</p> <p>You can set the versioning state with one of the following values:</p> <p> <b>Enabled</b>—Enables versioning for the objects in the bucket. All objects added to the bucket receive a unique version ID.</p> <p> <b>Suspended</b>—Disables versioning for the objects in the bucket. All objects added to the bucket receive the version ID null.</p> <p>If the versioning state has never been set on a bucket, it has no versioning state; a <a>GetBucketVersioning</a> request does not return a versioning state value.</p> <p>If the bucket owner enables MFA Delete in the bucket versioning configuration, the bucket owner must include the <code>x-amz-mfa request</code> header and the <code>Status</code> and the <code>MfaDelete</code> request elements in a request to set the versioning state of the bucket.</p> <important> <p>If you have an object expiration lifecycle policy in your non-versioned bucket and you want to maintain the same permanent delete behavior when you enable versioning, you must add a noncurrent expiration policy. The noncurrent expiration lifecycle policy will manage the deletes of the noncurrent object versions in the version-enabled bucket. (A version-enabled bucket maintains one current and zero or more noncurrent object versions.) For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html#lifecycle-and-other-bucket-config">Lifecycle and Versioning</a>.</p> </important> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CreateBucket</a> </p> </li> <li> <p> <a>DeleteBucket</a> </p> </li> <li> <p> <a>GetBucketVersioning</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_versioning(
+        &self,
+        input: PutBucketVersioningRequest,
+    ) -> Result<(), RusotoError<PutBucketVersioningError>> {
+        self.put_bucket_versioning_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the configuration of the website that is specified in the <code>website</code> subresource. To configure a bucket as a website, you can add this subresource on the bucket with website configuration information such as the file name of the index document and any redirect rules. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/WebsiteHosting.html">Hosting Websites on Amazon S3</a>.</p> <p>This PUT operation requires the <code>S3:PutBucketWebsite</code> permission. By default, only the bucket owner can configure the website attached to a bucket; however, bucket owners can allow other users to set the website configuration by writing a bucket policy that grants them the <code>S3:PutBucketWebsite</code> permission.</p> <p>To redirect all website requests sent to the bucket&#39;s website endpoint, you add a website configuration with the following elements. Because all requests are sent to another website, you don&#39;t need to provide index document name for the bucket.</p> <ul> <li> <p> <code>WebsiteConfiguration</code> </p> </li> <li> <p> <code>RedirectAllRequestsTo</code> </p> </li> <li> <p> <code>HostName</code> </p> </li> <li> <p> <code>Protocol</code> </p> </li> </ul> <p>If you want granular control over redirects, you can use the following elements to add routing rules that describe conditions for redirecting requests and information about the redirect destination. In this case, the website configuration must provide an index document for the bucket, because some requests might not be redirected. </p> <ul> <li> <p> <code>WebsiteConfiguration</code> </p> </li> <li> <p> <code>IndexDocument</code> </p> </li> <li> <p> <code>Suffix</code> </p> </li> <li> <p> <code>ErrorDocument</code> </p> </li> <li> <p> <code>Key</code> </p> </li> <li> <p> <code>RoutingRules</code> </p> </li> <li> <p> <code>RoutingRule</code> </p> </li> <li> <p> <code>Condition</code> </p> </li> <li> <p> <code>HttpErrorCodeReturnedEquals</code> </p> </li> <li> <p> <code>KeyPrefixEquals</code> </p> </li> <li> <p> <code>Redirect</code> </p> </li> <li> <p> <code>Protocol</code> </p> </li> <li> <p> <code>HostName</code> </p> </li> <li> <p> <code>ReplaceKeyPrefixWith</code> </p> </li> <li> <p> <code>ReplaceKeyWith</code> </p> </li> <li> <p> <code>HttpRedirectCode</code> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_bucket_website(
+        &self,
+        input: PutBucketWebsiteRequest,
+    ) -> Result<(), RusotoError<PutBucketWebsiteError>> {
+        self.put_bucket_website_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Adds an object to a bucket. You must have WRITE permissions on a bucket to add an object to it.</p> <p>Amazon S3 never adds partial objects; if you receive a success response, Amazon S3 added the entire object to the bucket.</p> <p>Amazon S3 is a distributed system. If it receives multiple write requests for the same object simultaneously, it overwrites all but the last object written. Amazon S3 does not provide object locking; if you need this, make sure to build it into your application layer or use versioning instead.</p> <p>To ensure that data is not corrupted traversing the network, use the <code>Content-MD5</code> header. When you use this header, Amazon S3 checks the object against the provided MD5 value and, if they do not match, returns an error. Additionally, you can calculate the MD5 while putting an object to Amazon S3 and compare the returned ETag to the calculated MD5 value.</p> <note> <p>To configure your application to send the request headers before sending the request body, use the <code>100-continue</code> HTTP status code. For PUT operations, this helps you avoid sending the message body if the message is rejected based on the headers (for example, because authentication fails or a redirect occurs). For more information on the <code>100-continue</code> HTTP status code, see Section 8.2.3 of <a href="http://www.ietf.org/rfc/rfc2616.txt">http://www.ietf.org/rfc/rfc2616.txt</a>.</p> </note> <p>You can optionally request server-side encryption. With server-side encryption, Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts the data when you access it. You have the option to provide your own encryption key or use AWS managed encryption keys. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingServerSideEncryption.html">Using Server-Side Encryption</a>.</p> <dl> <dt>Access Permissions</dt> <dd> <p>You can optionally specify the accounts or groups that should be granted specific permissions on the new object. There are two ways to grant the permissions using the request headers:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> </dd> <dt>Server-Side- Encryption-Specific Request Headers</dt> <dd> <p>You can optionally tell Amazon S3 to encrypt data at rest using server-side encryption. Server-side encryption is for data encryption at rest. Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it when you access it. The option you use depends on whether you want to use AWS managed encryption keys or provide your own encryption key. </p> <ul> <li> <p>Use encryption keys managed by Amazon S3 or customer master keys (CMKs) stored in AWS Key Management Service (AWS KMS) – If you want AWS to manage the keys used to encrypt data, specify the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption</p> </li> <li> <p>x-amz-server-side-encryption-aws-kms-key-id</p> </li> <li> <p>x-amz-server-side-encryption-context</p> </li> </ul> <note> <p>If you specify <code>x-amz-server-side-encryption:aws:kms</code>, but don&#39;t provide <code>x-amz-server-side-encryption-aws-kms-key-id</code>, Amazon S3 uses the AWS managed CMK in AWS KMS to protect the data. If you want to use a customer managed AWS KMS CMK, you must provide the <code>x-amz-server-side-encryption-aws-kms-key-id</code> of the symmetric customer managed CMK. Amazon S3 only supports symmetric CMKs and not asymmetric CMKs. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> </note> <important> <p>All GET and PUT requests for an object protected by AWS KMS fail if you don&#39;t make them with SSL or by using SigV4.</p> </important> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS</a>.</p> </li> <li> <p>Use customer-provided encryption keys – If you want to manage your own encryption keys, provide all the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about server-side encryption with CMKs stored in KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS</a>.</p> </li> </ul> </dd> <dt>Access-Control-List (ACL)-Specific Request Headers</dt> <dd> <p>You also can use the following access control–related headers with this operation. By default, all objects are private. Only the owner has full access control. When adding a new object, you can grant permissions to individual AWS accounts or to predefined groups defined by Amazon S3. These permissions are then added to the Access Control List (ACL) on the object. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Using ACLs</a>. With this operation, you can grant access permissions using one of the following two methods:</p> <ul> <li> <p>Specify a canned ACL (<code>x-amz-acl</code>) — Amazon S3 supports a set of predefined ACLs, known as canned ACLs. Each canned ACL has a predefined set of grantees and permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly — To explicitly grant access permissions to specific AWS accounts or groups, use the following headers. Each header maps to specific permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>. In the header, you specify a list of grantees who get the specific permission. To grant permissions explicitly use:</p> <ul> <li> <p>x-amz-grant-read</p> </li> <li> <p>x-amz-grant-write</p> </li> <li> <p>x-amz-grant-read-acp</p> </li> <li> <p>x-amz-grant-write-acp</p> </li> <li> <p>x-amz-grant-full-control</p> </li> </ul> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> <important> <p>Using email addresses to specify a grantee is only supported in the following AWS Regions: </p> <ul> <li> <p>US East (N. Virginia)</p> </li> <li> <p>US West (N. California)</p> </li> <li> <p> US West (Oregon)</p> </li> <li> <p> Asia Pacific (Singapore)</p> </li> <li> <p>Asia Pacific (Sydney)</p> </li> <li> <p>Asia Pacific (Tokyo)</p> </li> <li> <p>EU (Ireland)</p> </li> <li> <p>South America (São Paulo)</p> </li> </ul> <p>For a list of all the Amazon S3 supported Regions and endpoints, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html#s3_region">Regions and Endpoints</a> in the AWS General Reference</p> </important> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants the AWS accounts identified by email addresses permissions to read object data and its metadata:</p> <p> <code>x-amz-grant-read: emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> </dd> <dt>Server-Side- Encryption-Specific Request Headers</dt> <dd> <p>You can optionally tell Amazon S3 to encrypt data at rest using server-side encryption. Server-side encryption is for data encryption at rest. Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it when you access it. The option you use depends on whether you want to use AWS-managed encryption keys or provide your own encryption key. </p> <ul> <li> <p>Use encryption keys managed by Amazon S3 or customer master keys (CMKs) stored in AWS Key Management Service (AWS KMS) – If you want AWS to manage the keys used to encrypt data, specify the following headers in the request.</p> <ul> <li> <p>x-amz-server-side​-encryption</p> </li> <li> <p>x-amz-server-side-encryption-aws-kms-key-id</p> </li> <li> <p>x-amz-server-side-encryption-context</p> </li> </ul> <note> <p>If you specify <code>x-amz-server-side-encryption:aws:kms</code>, but don&#39;t provide <code>x-amz-server-side-encryption-aws-kms-key-id</code>, Amazon S3 uses the AWS managed CMK in AWS KMS to protect the data. If you want to use a customer managed AWS KMS CMK, you must provide the <code>x-amz-server-side-encryption-aws-kms-key-id</code> of the symmetric customer managed CMK. Amazon S3 only supports symmetric CMKs and not asymmetric CMKs. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> </note> <important> <p>All GET and PUT requests for an object protected by AWS KMS fail if you don&#39;t make them with SSL or by using SigV4.</p> </important> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS KMS</a>.</p> </li> <li> <p>Use customer-provided encryption keys – If you want to manage your own encryption keys, provide all the following headers in the request.</p> <note> <p>If you use this feature, the ETag value that Amazon S3 returns in the response is not the MD5 of the object.</p> </note> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p>For more information about server-side encryption with CMKs stored in AWS KMS (SSE-KMS), see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingKMSEncryption.html">Protecting Data Using Server-Side Encryption with CMKs stored in AWS KMS</a>.</p> </li> </ul> </dd> </dl> <p> <b>Storage Class Options</b> </p> <p>By default, Amazon S3 uses the Standard storage class to store newly created objects. The Standard storage class provides high durability and high availability. You can specify other storage classes depending on the performance needs. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/storage-class-intro.html">Storage Classes</a> in the Amazon Simple Storage Service Developer Guide.</p> <p> <b>Versioning</b> </p> <p>If you enable versioning for a bucket, Amazon S3 automatically generates a unique version ID for the object being stored. Amazon S3 returns this ID in the response using the <code>x-amz-version-id response</code> header. If versioning is suspended, Amazon S3 always uses null as the version ID for the object stored. For more information about returning the versioning state of a bucket, see <a>GetBucketVersioning</a>. If you enable versioning for a bucket, when Amazon S3 receives multiple write requests for the same object simultaneously, it stores all of the objects.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CopyObject</a> </p> </li> <li> <p> <a>DeleteObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_object(
+        &self,
+        input: PutObjectRequest,
+    ) -> Result<PutObjectOutput, RusotoError<PutObjectError>> {
+        self.put_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Uses the <code>acl</code> subresource to set the access control list (ACL) permissions for an object that already exists in a bucket. You must have <code>WRITE_ACP</code> permission to set the ACL of an object.</p> <p>Depending on your application needs, you can choose to set the ACL on an object using either the request body or the headers. For example, if you have an existing application that updates a bucket ACL using the request body, you can continue to use that approach.</p> <p> <b>Access Permissions</b> </p> <p>You can set access permissions using one of the following methods:</p> <ul> <li> <p>Specify a canned ACL with the <code>x-amz-acl</code> request header. Amazon S3 supports a set of predefined ACLs, known as canned ACLs. Each canned ACL has a predefined set of grantees and permissions. Specify the canned ACL name as the value of <code>x-amz-ac</code>l. If you use this header, you cannot use other access control-specific headers in your request. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#CannedACL">Canned ACL</a>.</p> </li> <li> <p>Specify access permissions explicitly with the <code>x-amz-grant-read</code>, <code>x-amz-grant-read-acp</code>, <code>x-amz-grant-write-acp</code>, and <code>x-amz-grant-full-control</code> headers. When using these headers, you specify explicit access permissions and grantees (AWS accounts or Amazon S3 groups) who will receive the permission. If you use these ACL-specific headers, you cannot use <code>x-amz-acl</code> header to set a canned ACL. These parameters map to the set of permissions that Amazon S3 supports in an ACL. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html">Access Control List (ACL) Overview</a>.</p> <p>You specify each grantee as a type=value pair, where the type is one of the following:</p> <ul> <li> <p> <code>emailAddress</code> – if the value specified is the email address of an AWS account</p> </li> <li> <p> <code>id</code> – if the value specified is the canonical user ID of an AWS account</p> </li> <li> <p> <code>uri</code> – if you are granting permissions to a predefined group</p> </li> </ul> <p>For example, the following <code>x-amz-grant-read</code> header grants list objects permission to the two AWS accounts identified by their email addresses.</p> <p> <code>x-amz-grant-read: emailAddress=&quot;xyz@amazon.com&quot;, emailAddress=&quot;abc@amazon.com&quot; </code> </p> </li> </ul> <p>You can use either a canned ACL or specify access permissions explicitly. You cannot do both.</p> <p> <b>Grantee Values</b> </p> <p>You can specify the person (grantee) to whom you&#39;re assigning access rights (using request elements) in the following ways:</p> <ul> <li> <p>By Email address:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;AmazonCustomerByEmail&quot;&gt;&lt;EmailAddress&gt;&lt;&gt;Grantees@email.com&lt;&gt;&lt;/EmailAddress&gt;lt;/Grantee&gt;</code> </p> <p>The grantee is resolved to the CanonicalUser and, in a response to a GET Object acl request, appears as the CanonicalUser.</p> </li> <li> <p>By the person&#39;s ID:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;CanonicalUser&quot;&gt;&lt;ID&gt;&lt;&gt;ID&lt;&gt;&lt;/ID&gt;&lt;DisplayName&gt;&lt;&gt;GranteesEmail&lt;&gt;&lt;/DisplayName&gt; &lt;/Grantee&gt;</code> </p> <p>DisplayName is optional and ignored in the request.</p> </li> <li> <p>By URI:</p> <p> <code>&lt;Grantee xmlns:xsi=&quot;http://www.w3.org/2001/XMLSchema-instance&quot; xsi:type=&quot;Group&quot;&gt;&lt;URI&gt;&lt;&gt;http://acs.amazonaws.com/groups/global/AuthenticatedUsers&lt;&gt;&lt;/URI&gt;&lt;/Grantee&gt;</code> </p> </li> </ul> <p> <b>Versioning</b> </p> <p>The ACL of an object is set at the object version level. By default, PUT sets the ACL of the current version of an object. To set the ACL of a different version, use the <code>versionId</code> subresource.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CopyObject</a> </p> </li> <li> <p> <a>GetObject</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_object_acl(
+        &self,
+        input: PutObjectAclRequest,
+    ) -> Result<PutObjectAclOutput, RusotoError<PutObjectAclError>> {
+        self.put_object_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Applies a Legal Hold configuration to the specified object.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_object_legal_hold(
+        &self,
+        input: PutObjectLegalHoldRequest,
+    ) -> Result<PutObjectLegalHoldOutput, RusotoError<PutObjectLegalHoldError>> {
+        self.put_object_legal_hold_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Places an Object Lock configuration on the specified bucket. The rule specified in the Object Lock configuration will be applied by default to every new object placed in the specified bucket.</p> <note> <p> <code>DefaultRetention</code> requires either Days or Years. You can&#39;t specify both at the same time.</p> </note> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_object_lock_configuration(
+        &self,
+        input: PutObjectLockConfigurationRequest,
+    ) -> Result<PutObjectLockConfigurationOutput, RusotoError<PutObjectLockConfigurationError>>
+    {
+        self.put_object_lock_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Places an Object Retention configuration on an object.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lock.html">Locking Objects</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_object_retention(
+        &self,
+        input: PutObjectRetentionRequest,
+    ) -> Result<PutObjectRetentionOutput, RusotoError<PutObjectRetentionError>> {
+        self.put_object_retention_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the supplied tag-set to an object that already exists in a bucket</p> <p>A tag is a key-value pair. You can associate tags with an object by sending a PUT request against the tagging subresource that is associated with the object. You can retrieve tags by sending a GET request. For more information, see <a>GetObjectTagging</a>.</p> <p>For tagging-related restrictions related to characters and encodings, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">Tag Restrictions</a>. Note that Amazon S3 limits the maximum number of tags to 10 tags per object.</p> <p>To use this operation, you must have permission to perform the <code>s3:PutObjectTagging</code> action. By default, the bucket owner has this permission and can grant this permission to others.</p> <p>To put tags of any other version, use the <code>versionId</code> query parameter. You also need permission for the <code>s3:PutObjectVersionTagging</code> action.</p> <p>For information about the Amazon S3 object tagging feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-tagging.html">Object Tagging</a>.</p> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: InvalidTagError </i> </p> </li> <li> <p> <i>Cause: The tag provided was not a valid tag. This error can occur if the tag did not pass input validation. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-tagging.html">Object Tagging</a>.</i> </p> </li> </ul> </li> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: MalformedXMLError </i> </p> </li> <li> <p> <i>Cause: The XML provided does not match the schema.</i> </p> </li> </ul> </li> <li> <ul> <li> <p> <i>Code: OperationAbortedError </i> </p> </li> <li> <p> <i>Cause: A conflicting conditional operation is currently in progress against this resource. Please try again.</i> </p> </li> </ul> </li> <li> <ul> <li> <p> <i>Code: InternalError</i> </p> </li> <li> <p> <i>Cause: The service was unable to apply the provided tag to the object.</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetObjectTagging</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_object_tagging(
+        &self,
+        input: PutObjectTaggingRequest,
+    ) -> Result<PutObjectTaggingOutput, RusotoError<PutObjectTaggingError>> {
+        self.put_object_tagging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates or modifies the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket. To use this operation, you must have the <code>s3:PutBucketPublicAccessBlock</code> permission. For more information about Amazon S3 permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a>.</p> <important> <p>When Amazon S3 evaluates the <code>PublicAccessBlock</code> configuration for a bucket or an object, it checks the <code>PublicAccessBlock</code> configuration for both the bucket (or the bucket that contains the object) and the bucket owner&#39;s account. If the <code>PublicAccessBlock</code> configurations are different between the bucket and the account, Amazon S3 uses the most restrictive combination of the bucket-level and account-level settings.</p> </important> <p>For more information about when Amazon S3 considers a bucket or an object public, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html#access-control-block-public-access-policy-status">The Meaning of &quot;Public&quot;</a>.</p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetPublicAccessBlock</a> </p> </li> <li> <p> <a>DeletePublicAccessBlock</a> </p> </li> <li> <p> <a>GetBucketPolicyStatus</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/access-control-block-public-access.html">Using Amazon S3 Block Public Access</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn put_public_access_block(
+        &self,
+        input: PutPublicAccessBlockRequest,
+    ) -> Result<(), RusotoError<PutPublicAccessBlockError>> {
+        self.put_public_access_block_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Restores an archived copy of an object back into Amazon S3</p> <p>This operation performs the following types of requests: </p> <ul> <li> <p> <code>select</code> - Perform a select query on an archived object</p> </li> <li> <p> <code>restore an archive</code> - Restore an archived object</p> </li> </ul> <p>To use this operation, you must have permissions to perform the <code>s3:RestoreObject</code> and <code>s3:GetObject</code> actions. The bucket owner has this permission by default and can grant this permission to others. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html#using-with-s3-actions-related-to-bucket-subresources">Permissions Related to Bucket Subresource Operations</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">Managing Access Permissions to Your Amazon S3 Resources</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p> <b>Querying Archives with Select Requests</b> </p> <p>You use a select type of request to perform SQL queries on archived objects. The archived objects that are being queried by the select request must be formatted as uncompressed comma-separated values (CSV) files. You can run queries and custom analytics on your archived data without having to restore your data to a hotter Amazon S3 tier. For an overview about select requests, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/querying-glacier-archives.html">Querying Archived Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>When making a select request, do the following:</p> <ul> <li> <p>Define an output location for the select query&#39;s output. This must be an Amazon S3 bucket in the same AWS Region as the bucket that contains the archive object that is being queried. The AWS account that initiates the job must have permissions to write to the S3 bucket. You can specify the storage class and encryption for the output objects stored in the bucket. For more information about output, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/querying-glacier-archives.html">Querying Archived Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>For more information about the <code>S3</code> structure in the request body, see the following:</p> <ul> <li> <p> <a>PutObject</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/S3_ACLs_UsingACLs.html">Managing Access with ACLs</a> in the <i>Amazon Simple Storage Service Developer Guide</i> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Protecting Data Using Server-Side Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i> </p> </li> </ul> </li> <li> <p>Define the SQL expression for the <code>SELECT</code> type of restoration for your query in the request body&#39;s <code>SelectParameters</code> structure. You can use expressions like the following examples.</p> <ul> <li> <p>The following expression returns all records from the specified object.</p> <p> <code>SELECT * FROM Object</code> </p> </li> <li> <p>Assuming that you are not using any headers for data stored in the object, you can specify columns with positional headers.</p> <p> <code>SELECT s.<em>1, s.</em>2 FROM Object s WHERE s.<em>3 &gt; 100</code> </p> </li> <li> <p>If you have headers and you set the <code>fileHeaderInfo</code> in the <code>CSV</code> structure in the request body to <code>USE</code>, you can specify headers in the query. (If you set the <code>fileHeaderInfo</code> field to <code>IGNORE</code>, the first row is skipped for the query.) You cannot mix ordinal positions with header column names. </p> <p> <code>SELECT s.Id, s.FirstName, s.SSN FROM S3Object s</code> </p> </li> </ul> </li> </ul> <p>For more information about using SQL with Glacier Select restore, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-glacier-select-sql-reference.html">SQL Reference for Amazon S3 Select and Glacier Select</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>When making a select request, you can also do the following:</p> <ul> <li> <p>To expedite your queries, specify the <code>Expedited</code> tier. For more information about tiers, see &quot;Restoring Archives,&quot; later in this topic.</p> </li> <li> <p>Specify details about the data serialization format of both the input object that is being queried and the serialization of the CSV-encoded query results.</p> </li> </ul> <p>The following are additional important facts about the select feature:</p> <ul> <li> <p>The output results are new Amazon S3 objects. Unlike archive retrievals, they are stored until explicitly deleted-manually or through a lifecycle policy.</p> </li> <li> <p>You can issue more than one select request on the same Amazon S3 object. Amazon S3 doesn&#39;t deduplicate requests, so avoid issuing duplicate requests.</p> </li> <li> <p> Amazon S3 accepts a select request even if the object has already been restored. A select request doesn’t return error response <code>409</code>.</p> </li> </ul> <p> <b>Restoring Archives</b> </p> <p>Objects in the GLACIER and DEEP</em>ARCHIVE storage classes are archived. To access an archived object, you must first initiate a restore request. This restores a temporary copy of the archived object. In a restore request, you specify the number of days that you want the restored copy to exist. After the specified period, Amazon S3 deletes the temporary copy but the object remains archived in the GLACIER or DEEP<em>ARCHIVE storage class that object was restored from. </p> <p>To restore a specific object version, you can provide a version ID. If you don&#39;t provide a version ID, Amazon S3 restores the current version.</p> <p>The time it takes restore jobs to finish depends on which storage class the object is being restored from and which data access tier you specify. </p> <p>When restoring an archived object (or using a select request), you can specify one of the following data access tier options in the <code>Tier</code> element of the request body: </p> <ul> <li> <p> <b> <code>Expedited</code> </b> - Expedited retrievals allow you to quickly access your data stored in the GLACIER storage class when occasional urgent requests for a subset of archives are required. For all but the largest archived objects (250 MB+), data accessed using Expedited retrievals are typically made available within 1–5 minutes. Provisioned capacity ensures that retrieval capacity for Expedited retrievals is available when you need it. Expedited retrievals and provisioned capacity are not available for the DEEP</em>ARCHIVE storage class.</p> </li> <li> <p> <b> <code>Standard</code> </b> - Standard retrievals allow you to access any of your archived objects within several hours. This is the default option for the GLACIER and DEEP<em>ARCHIVE retrieval requests that do not specify the retrieval option. Standard retrievals typically complete within 3-5 hours from the GLACIER storage class and typically complete within 12 hours from the DEEP</em>ARCHIVE storage class. </p> </li> <li> <p> <b> <code>Bulk</code> </b> - Bulk retrievals are Amazon S3 Glacier’s lowest-cost retrieval option, enabling you to retrieve large amounts, even petabytes, of data inexpensively in a day. Bulk retrievals typically complete within 5-12 hours from the GLACIER storage class and typically complete within 48 hours from the DEEP_ARCHIVE storage class.</p> </li> </ul> <p>For more information about archive retrieval options and provisioned capacity for <code>Expedited</code> data access, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/restoring-objects.html">Restoring Archived Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>You can use Amazon S3 restore speed upgrade to change the restore speed to a faster speed while it is in progress. You upgrade the speed of an in-progress restoration by issuing another restore request to the same object, setting a new <code>Tier</code> request element. When issuing a request to upgrade the restore tier, you must choose a tier that is faster than the tier that the in-progress restore is using. You must not change any other parameters, such as the <code>Days</code> request element. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/restoring-objects.html#restoring-objects-upgrade-tier.title.html"> Upgrading the Speed of an In-Progress Restore</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <p>To get the status of object restoration, you can send a <code>HEAD</code> request. Operations return the <code>x-amz-restore</code> header, which provides information about the restoration status, in the response. You can use Amazon S3 event notifications to notify you when a restore is initiated or completed. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Configuring Amazon S3 Event Notifications</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>After restoring an archived object, you can update the restoration period by reissuing the request with a new period. Amazon S3 updates the restoration period relative to the current time and charges only for the request-there are no data transfer charges. You cannot update the restoration period when Amazon S3 is actively processing your current restore request for the object.</p> <p>If your bucket has a lifecycle configuration with a rule that includes an expiration action, the object expiration overrides the life span that you specify in a restore request. For example, if you restore an object copy for 10 days, but the object is scheduled to expire in 3 days, Amazon S3 deletes the object in 3 days. For more information about lifecycle configuration, see <a>PutBucketLifecycleConfiguration</a> and <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a> in <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p> <b>Responses</b> </p> <p>A successful operation returns either the <code>200 OK</code> or <code>202 Accepted</code> status code. </p> <ul> <li> <p>If the object copy is not previously restored, then Amazon S3 returns <code>202 Accepted</code> in the response. </p> </li> <li> <p>If the object copy is previously restored, Amazon S3 returns <code>200 OK</code> in the response. </p> </li> </ul> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: RestoreAlreadyInProgress</i> </p> </li> <li> <p> <i>Cause: Object restore is already in progress. (This error does not apply to SELECT type requests.)</i> </p> </li> <li> <p> <i>HTTP Status Code: 409 Conflict</i> </p> </li> <li> <p> <i>SOAP Fault Code Prefix: Client</i> </p> </li> </ul> </li> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: GlacierExpeditedRetrievalNotAvailable</i> </p> </li> <li> <p> <i>Cause: Glacier expedited retrievals are currently not available. Try again later. (Returned if there is insufficient capacity to process the Expedited request. This error applies only to Expedited retrievals and not to Standard or Bulk retrievals.)</i> </p> </li> <li> <p> <i>HTTP Status Code: 503</i> </p> </li> <li> <p> <i>SOAP Fault Code Prefix: N/A</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>PutBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>GetBucketNotificationConfiguration</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-glacier-select-sql-reference.html">SQL Reference for Amazon S3 Select and Glacier Select </a> in the <i>Amazon Simple Storage Service Developer Guide</i> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn restore_object(
+        &self,
+        input: RestoreObjectRequest,
+    ) -> Result<RestoreObjectOutput, RusotoError<RestoreObjectError>> {
+        self.restore_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This operation filters the contents of an Amazon S3 object based on a simple structured query language (SQL) statement. In the request, along with the SQL expression, you must also specify a data serialization format (JSON, CSV, or Apache Parquet) of the object. Amazon S3 uses this format to parse object data into records, and returns only records that match the specified SQL expression. You must also specify the data serialization format for the response.</p> <p>For more information about Amazon S3 Select, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/selecting-content-from-objects.html">Selecting Content from Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>For more information about using SQL with Amazon S3 Select, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-glacier-select-sql-reference.html"> SQL Reference for Amazon S3 Select and Glacier Select</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p/> <p> <b>Permissions</b> </p> <p>You must have <code>s3:GetObject</code> permission for this operation. Amazon S3 Select does not support anonymous access. For more information about permissions, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/using-with-s3-actions.html">Specifying Permissions in a Policy</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p/> <p> <i>Object Data Formats</i> </p> <p>You can use Amazon S3 Select to query objects that have the following format properties:</p> <ul> <li> <p> <i>CSV, JSON, and Parquet</i> - Objects must be in CSV, JSON, or Parquet format.</p> </li> <li> <p> <i>UTF-8</i> - UTF-8 is the only encoding type Amazon S3 Select supports.</p> </li> <li> <p> <i>GZIP or BZIP2</i> - CSV and JSON files can be compressed using GZIP or BZIP2. GZIP and BZIP2 are the only compression formats that Amazon S3 Select supports for CSV and JSON files. Amazon S3 Select supports columnar compression for Parquet using GZIP or Snappy. Amazon S3 Select does not support whole-object compression for Parquet objects.</p> </li> <li> <p> <i>Server-side encryption</i> - Amazon S3 Select supports querying objects that are protected with server-side encryption.</p> <p>For objects that are encrypted with customer-provided encryption keys (SSE-C), you must use HTTPS, and you must use the headers that are documented in the <a>GetObject</a>. For more information about SSE-C, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ServerSideEncryptionCustomerKeys.html">Server-Side Encryption (Using Customer-Provided Encryption Keys)</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>For objects that are encrypted with Amazon S3 managed encryption keys (SSE-S3) and customer master keys (CMKs) stored in AWS Key Management Service (SSE-KMS), server-side encryption is handled transparently, so you don&#39;t need to specify anything. For more information about server-side encryption, including SSE-S3 and SSE-KMS, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Protecting Data Using Server-Side Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> </ul> <p> <b>Working with the Response Body</b> </p> <p>Given the response size is unknown, Amazon S3 Select streams the response as a series of messages and includes a <code>Transfer-Encoding</code> header with <code>chunked</code> as its value in the response. For more information, see <a>RESTSelectObjectAppendix</a> .</p> <p/> <p> <b>GetObject Support</b> </p> <p>The <code>SelectObjectContent</code> operation does not support the following <code>GetObject</code> functionality. For more information, see <a>GetObject</a>.</p> <ul> <li> <p> <code>Range</code>: While you can specify a scan range for a Amazon S3 Select request, see <a>SelectObjectContentRequest$ScanRange</a> in the request parameters below, you cannot specify the range of bytes of an object to return. </p> </li> <li> <p>GLACIER, DEEP<em>ARCHIVE and REDUCED</em>REDUNDANCY storage classes: You cannot specify the GLACIER, DEEP<em>ARCHIVE, or <code>REDUCED</em>REDUNDANCY</code> storage classes. For more information, about storage classes see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingMetadata.html#storage-class-intro">Storage Classes</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> </ul> <p/> <p> <b>Special Errors</b> </p> <p>For a list of special errors for this operation and for general information about Amazon S3 errors and a list of error codes, see <a>ErrorResponses</a> </p> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>GetObject</a> </p> </li> <li> <p> <a>GetBucketLifecycleConfiguration</a> </p> </li> <li> <p> <a>PutBucketLifecycleConfiguration</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn select_object_content(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> Result<SelectObjectContentOutput, RusotoError<SelectObjectContentError>> {
+        self.select_object_content_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Uploads a part in a multipart upload.</p> <note> <p>In this operation, you provide part data in your request. However, you have an option to specify your existing Amazon S3 object as a data source for the part you are uploading. To upload a part from an existing object, you use the <a>UploadPartCopy</a> operation. </p> </note> <p>You must initiate a multipart upload (see <a>CreateMultipartUpload</a>) before you can upload any part. In response to your initiate request, Amazon S3 returns an upload ID, a unique identifier, that you must include in your upload part request.</p> <p>Part numbers can be any number from 1 to 10,000, inclusive. A part number uniquely identifies a part and also defines its position within the object being created. If you upload a new part using the same part number that was used with a previous part, the previously uploaded part is overwritten. Each part must be at least 5 MB in size, except the last part. There is no size limit on the last part of your multipart upload.</p> <p>To ensure that data is not corrupted when traversing the network, specify the <code>Content-MD5</code> header in the upload part request. Amazon S3 checks the part data against the provided MD5 value. If they do not match, Amazon S3 returns an error. </p> <p> <b>Note:</b> After you initiate multipart upload and upload one or more parts, you must either complete or abort multipart upload in order to stop getting charged for storage of the uploaded parts. Only after you either complete or abort multipart upload, Amazon S3 frees up the parts storage and stops charging you for the parts storage.</p> <p>For more information on multipart uploads, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html">Multipart Upload Overview</a> in the <i>Amazon Simple Storage Service Developer Guide </i>.</p> <p>For information on the permissions required to use the multipart upload API, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>You can optionally request server-side encryption where Amazon S3 encrypts your data as it writes it to disks in its data centers and decrypts it for you when you access it. You have the option of providing your own encryption key, or you can use the AWS managed encryption keys. If you choose to provide your own encryption key, the request headers you provide in the request must match the headers you used in the request to initiate the upload by using <a>CreateMultipartUpload</a>. For more information, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingServerSideEncryption.html">Using Server-Side Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> <p>Server-side encryption is supported by the S3 Multipart Upload actions. Unless you are using a customer-provided encryption key, you don&#39;t need to specify the encryption parameters in each UploadPart request. Instead, you only need to specify the server-side encryption parameters in the initial Initiate Multipart request. For more information, see <a>CreateMultipartUpload</a>.</p> <p>If you requested server-side encryption using a customer-provided encryption key in your initiate multipart upload request, you must provide identical encryption information in each part upload using the following headers.</p> <ul> <li> <p>x-amz-server-side​-encryption​-customer-algorithm</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key</p> </li> <li> <p>x-amz-server-side​-encryption​-customer-key-MD5</p> </li> </ul> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: NoSuchUpload</i> </p> </li> <li> <p> <i>Cause: The specified multipart upload does not exist. The upload ID might be invalid, or the multipart upload might have been aborted or completed.</i> </p> </li> <li> <p> <i> HTTP Status Code: 404 Not Found </i> </p> </li> <li> <p> <i>SOAP Fault Code Prefix: Client</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn upload_part(
+        &self,
+        input: UploadPartRequest,
+    ) -> Result<UploadPartOutput, RusotoError<UploadPartError>> {
+        self.upload_part_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Uploads a part by copying data from an existing object as data source. You specify the data source by adding the request header <code>x-amz-copy-source</code> in your request and a byte range by adding the request header <code>x-amz-copy-source-range</code> in your request. </p> <p>The minimum allowable part size for a multipart upload is 5 MB. For more information about multipart upload limits, go to <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/qfacts.html">Quick Facts</a> in the <i>Amazon Simple Storage Service Developer Guide</i>. </p> <note> <p>Instead of using an existing object as part data, you might use the <a>UploadPart</a> operation and provide data in your request.</p> </note> <p>You must initiate a multipart upload before you can upload any part. In response to your initiate request. Amazon S3 returns a unique identifier, the upload ID, that you must include in your upload part request.</p> <p>For more information about using the <code>UploadPartCopy</code> operation, see the following:</p> <ul> <li> <p>For conceptual information about multipart uploads, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/uploadobjusingmpu.html">Uploading Objects Using Multipart Upload</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> <li> <p>For information about permissions required to use the multipart upload API, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuAndPermissions.html">Multipart Upload API and Permissions</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> <li> <p>For information about copying objects using a single atomic operation vs. the multipart upload, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/ObjectOperations.html">Operations on Objects</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p> </li> <li> <p>For information about using server-side encryption with customer-provided encryption keys with the UploadPartCopy operation, see <a>CopyObject</a> and <a>UploadPart</a>.</p> </li> </ul> <p>Note the following additional considerations about the request headers <code>x-amz-copy-source-if-match</code>, <code>x-amz-copy-source-if-none-match</code>, <code>x-amz-copy-source-if-unmodified-since</code>, and <code>x-amz-copy-source-if-modified-since</code>:</p> <p> </p> <ul> <li> <p> <b>Consideration 1</b> - If both of the <code>x-amz-copy-source-if-match</code> and <code>x-amz-copy-source-if-unmodified-since</code> headers are present in the request as follows:</p> <p> <code>x-amz-copy-source-if-match</code> condition evaluates to <code>true</code>, and;</p> <p> <code>x-amz-copy-source-if-unmodified-since</code> condition evaluates to <code>false</code>;</p> <p>Amazon S3 returns <code>200 OK</code> and copies the data. </p> </li> <li> <p> <b>Consideration 2</b> - If both of the <code>x-amz-copy-source-if-none-match</code> and <code>x-amz-copy-source-if-modified-since</code> headers are present in the request as follows:</p> <p> <code>x-amz-copy-source-if-none-match</code> condition evaluates to <code>false</code>, and;</p> <p> <code>x-amz-copy-source-if-modified-since</code> condition evaluates to <code>true</code>;</p> <p>Amazon S3 returns <code>412 Precondition Failed</code> response code. </p> </li> </ul> <p> <b>Versioning</b> </p> <p>If your bucket has versioning enabled, you could have multiple versions of the same object. By default, <code>x-amz-copy-source</code> identifies the current version of the object to copy. If the current version is a delete marker and you don&#39;t specify a versionId in the <code>x-amz-copy-source</code>, Amazon S3 returns a 404 error, because the object does not exist. If you specify versionId in the <code>x-amz-copy-source</code> and the versionId is a delete marker, Amazon S3 returns an HTTP 400 error, because you are not allowed to specify a delete marker as a version for the <code>x-amz-copy-source</code>. </p> <p>You can optionally specify a specific version of the source object to copy by adding the <code>versionId</code> subresource as shown in the following example:</p> <p> <code>x-amz-copy-source: /bucket/object?versionId=version id</code> </p> <p class="title"> <b>Special Errors</b> </p> <ul> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: NoSuchUpload</i> </p> </li> <li> <p> <i>Cause: The specified multipart upload does not exist. The upload ID might be invalid, or the multipart upload might have been aborted or completed.</i> </p> </li> <li> <p> <i>HTTP Status Code: 404 Not Found</i> </p> </li> </ul> </li> <li> <p class="title"> <b/> </p> <ul> <li> <p> <i>Code: InvalidRequest</i> </p> </li> <li> <p> <i>Cause: The specified copy source is not supported as a byte-range copy source.</i> </p> </li> <li> <p> <i>HTTP Status Code: 400 Bad Request</i> </p> </li> </ul> </li> </ul> <p class="title"> <b>Related Resources</b> </p> <ul> <li> <p> <a>CreateMultipartUpload</a> </p> </li> <li> <p> <a>UploadPart</a> </p> </li> <li> <p> <a>CompleteMultipartUpload</a> </p> </li> <li> <p> <a>AbortMultipartUpload</a> </p> </li> <li> <p> <a>ListParts</a> </p> </li> <li> <p> <a>ListMultipartUploads</a> </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    async fn upload_part_copy(
+        &self,
+        input: UploadPartCopyRequest,
+    ) -> Result<UploadPartCopyOutput, RusotoError<UploadPartCopyError>> {
+        self.upload_part_copy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -238,6 +238,7 @@ pub struct StopHumanLoopResponse {}
 
 /// Errors returned by DeleteHumanLoop
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteHumanLoopError {
     /// <p>Your request could not be processed.</p>
     InternalServer(String),
@@ -245,6 +246,8 @@ pub enum DeleteHumanLoopError {
     ResourceNotFound(String),
     /// <p>Your request has exceeded the allowed amount of requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteHumanLoopError {
@@ -252,16 +255,22 @@ impl DeleteHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteHumanLoopError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteHumanLoopError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteHumanLoopError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service(DeleteHumanLoopError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteHumanLoopError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -274,12 +283,14 @@ impl fmt::Display for DeleteHumanLoopError {
             DeleteHumanLoopError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteHumanLoopError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteHumanLoopError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteHumanLoopError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteHumanLoopError {}
 /// Errors returned by DescribeHumanLoop
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHumanLoopError {
     /// <p>Your request could not be processed.</p>
     InternalServer(String),
@@ -287,6 +298,8 @@ pub enum DescribeHumanLoopError {
     ResourceNotFound(String),
     /// <p>Your request has exceeded the allowed amount of requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHumanLoopError {
@@ -294,16 +307,22 @@ impl DescribeHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeHumanLoopError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeHumanLoopError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeHumanLoopError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service(DescribeHumanLoopError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeHumanLoopError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -316,17 +335,21 @@ impl fmt::Display for DescribeHumanLoopError {
             DescribeHumanLoopError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeHumanLoopError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeHumanLoopError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeHumanLoopError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHumanLoopError {}
 /// Errors returned by ListHumanLoops
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHumanLoopsError {
     /// <p>Your request could not be processed.</p>
     InternalServer(String),
     /// <p>Your request has exceeded the allowed amount of requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHumanLoopsError {
@@ -334,13 +357,13 @@ impl ListHumanLoopsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListHumanLoopsError::InternalServer(err.msg))
+                    return RusotoError::service(ListHumanLoopsError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListHumanLoopsError::Throttling(err.msg))
+                    return RusotoError::service(ListHumanLoopsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListHumanLoopsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -352,12 +375,14 @@ impl fmt::Display for ListHumanLoopsError {
         match *self {
             ListHumanLoopsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListHumanLoopsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListHumanLoopsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHumanLoopsError {}
 /// Errors returned by StartHumanLoop
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartHumanLoopError {
     /// <p>Your request could not be processed.</p>
     InternalServer(String),
@@ -365,6 +390,8 @@ pub enum StartHumanLoopError {
     ServiceQuotaExceeded(String),
     /// <p>Your request has exceeded the allowed amount of requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartHumanLoopError {
@@ -372,16 +399,19 @@ impl StartHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service(StartHumanLoopError::InternalServer(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartHumanLoopError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        StartHumanLoopError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service(StartHumanLoopError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartHumanLoopError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -394,12 +424,14 @@ impl fmt::Display for StartHumanLoopError {
             StartHumanLoopError::InternalServer(ref cause) => write!(f, "{}", cause),
             StartHumanLoopError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             StartHumanLoopError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartHumanLoopError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartHumanLoopError {}
 /// Errors returned by StopHumanLoop
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopHumanLoopError {
     /// <p>Your request could not be processed.</p>
     InternalServer(String),
@@ -407,6 +439,8 @@ pub enum StopHumanLoopError {
     ResourceNotFound(String),
     /// <p>Your request has exceeded the allowed amount of requests.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopHumanLoopError {
@@ -414,16 +448,19 @@ impl StopHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service(StopHumanLoopError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopHumanLoopError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopHumanLoopError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service(StopHumanLoopError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopHumanLoopError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -436,6 +473,7 @@ impl fmt::Display for StopHumanLoopError {
             StopHumanLoopError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopHumanLoopError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopHumanLoopError::Throttling(ref cause) => write!(f, "{}", cause),
+            StopHumanLoopError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -484,6 +522,7 @@ impl SagemakerA2iRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SagemakerA2iRuntimeClient {
         SagemakerA2iRuntimeClient {
             client: Client::shared(),
@@ -509,82 +548,184 @@ impl SagemakerA2iRuntimeClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SagemakerA2iRuntimeClient {
         SagemakerA2iRuntimeClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SagemakerA2iRuntimeClient> {
+        rusoto_core::ClientBuilder::new(SagemakerA2iRuntimeClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SagemakerA2iRuntimeClient {
+        SagemakerA2iRuntimeClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
-    /// <p>Deletes the specified human loop for a flow definition.</p>
-    async fn delete_human_loop(
+impl SagemakerA2iRuntimeClient {
+    /// Calls `delete_human_loop`, also returning the metadata of the response.
+    pub async fn delete_human_loop_with_metadata(
         &self,
         input: DeleteHumanLoopRequest,
-    ) -> Result<DeleteHumanLoopResponse, RusotoError<DeleteHumanLoopError>> {
+    ) -> Result<(DeleteHumanLoopResponse, ResponseMetadata), RusotoError<DeleteHumanLoopError>>
+    {
+        let request = self.build_delete_human_loop_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteHumanLoopResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteHumanLoopError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_human_loop` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_human_loop(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteHumanLoopRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_human_loop_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_human_loop_request(&self, input: DeleteHumanLoopRequest) -> SignedRequest {
         let request_uri = format!(
             "/human-loops/{human_loop_name}",
             human_loop_name = input.human_loop_name
         );
 
         let mut request = SignedRequest::new("DELETE", "sagemaker", &self.region, &request_uri);
+        request.set_operation("DeleteHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
 
+        request
+    }
+
+    /// Calls `describe_human_loop`, also returning the metadata of the response.
+    pub async fn describe_human_loop_with_metadata(
+        &self,
+        input: DescribeHumanLoopRequest,
+    ) -> Result<(DescribeHumanLoopResponse, ResponseMetadata), RusotoError<DescribeHumanLoopError>>
+    {
+        let request = self.build_describe_human_loop_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteHumanLoopResponse, _>()?;
+                .deserialize::<DescribeHumanLoopResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteHumanLoopError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeHumanLoopError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified human loop.</p>
-    async fn describe_human_loop(
+    /// Presigns the request `describe_human_loop` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_human_loop(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHumanLoopRequest,
-    ) -> Result<DescribeHumanLoopResponse, RusotoError<DescribeHumanLoopError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_human_loop_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_human_loop_request(&self, input: DescribeHumanLoopRequest) -> SignedRequest {
         let request_uri = format!(
             "/human-loops/{human_loop_name}",
             human_loop_name = input.human_loop_name
         );
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation("DescribeHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
 
+        request
+    }
+
+    /// Calls `list_human_loops`, also returning the metadata of the response.
+    pub async fn list_human_loops_with_metadata(
+        &self,
+        input: ListHumanLoopsRequest,
+    ) -> Result<(ListHumanLoopsResponse, ResponseMetadata), RusotoError<ListHumanLoopsError>> {
+        let request = self.build_list_human_loops_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeHumanLoopResponse, _>()?;
+                .deserialize::<ListHumanLoopsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHumanLoopError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListHumanLoopsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about human loops, given the specified parameters.</p>
-    async fn list_human_loops(
+    /// Presigns the request `list_human_loops` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_human_loops(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHumanLoopsRequest,
-    ) -> Result<ListHumanLoopsResponse, RusotoError<ListHumanLoopsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_human_loops_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_human_loops_request(&self, input: ListHumanLoopsRequest) -> SignedRequest {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation("ListHumanLoops");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -607,82 +748,207 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `start_human_loop`, also returning the metadata of the response.
+    pub async fn start_human_loop_with_metadata(
+        &self,
+        input: StartHumanLoopRequest,
+    ) -> Result<(StartHumanLoopResponse, ResponseMetadata), RusotoError<StartHumanLoopError>> {
+        let request = self.build_start_human_loop_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListHumanLoopsResponse, _>()?;
+                .deserialize::<StartHumanLoopResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHumanLoopsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartHumanLoopError::from_response(response))
         }
     }
 
-    /// <p>Starts a human loop, provided that at least one activation condition is met.</p>
-    async fn start_human_loop(
+    /// Presigns the request `start_human_loop` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_human_loop(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartHumanLoopRequest,
-    ) -> Result<StartHumanLoopResponse, RusotoError<StartHumanLoopError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_human_loop_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_human_loop_request(&self, input: StartHumanLoopRequest) -> SignedRequest {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("StartHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `stop_human_loop`, also returning the metadata of the response.
+    pub async fn stop_human_loop_with_metadata(
+        &self,
+        input: StopHumanLoopRequest,
+    ) -> Result<(StopHumanLoopResponse, ResponseMetadata), RusotoError<StopHumanLoopError>> {
+        let request = self.build_stop_human_loop_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartHumanLoopResponse, _>()?;
+                .deserialize::<StopHumanLoopResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartHumanLoopError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopHumanLoopError::from_response(response))
         }
     }
 
-    /// <p>Stops the specified human loop.</p>
-    async fn stop_human_loop(
+    /// Presigns the request `stop_human_loop` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_human_loop(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopHumanLoopRequest,
-    ) -> Result<StopHumanLoopResponse, RusotoError<StopHumanLoopError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_human_loop_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_human_loop_request(&self, input: StopHumanLoopRequest) -> SignedRequest {
         let request_uri = "/human-loops/stop";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("StopHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListHumanLoops` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_human_loops_pages(
+        &self,
+        input: ListHumanLoopsRequest,
+    ) -> impl futures::Stream<Item = Result<ListHumanLoopsResponse, RusotoError<ListHumanLoopsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SagemakerA2iRuntimeClient, input| async move {
+                client.list_human_loops(input).await
+            },
+            |input: &ListHumanLoopsRequest, output: &ListHumanLoopsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHumanLoopsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HumanLoopSummaries` of every page of `ListHumanLoops` results for `input`, as `list_human_loops_pages` requests them.
+    pub fn list_human_loops_stream(
+        &self,
+        input: ListHumanLoopsRequest,
+    ) -> impl futures::Stream<Item = Result<HumanLoopSummary, RusotoError<ListHumanLoopsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_human_loops_pages(input), |page| {
+            page.human_loop_summaries
+        })
+    }
+}
+
+#[async_trait]
+impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
+    /// <p>Deletes the specified human loop for a flow definition.</p>
+    async fn delete_human_loop(
+        &self,
+        input: DeleteHumanLoopRequest,
+    ) -> Result<DeleteHumanLoopResponse, RusotoError<DeleteHumanLoopError>> {
+        self.delete_human_loop_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopHumanLoopResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopHumanLoopError::from_response(response))
-        }
+    /// <p>Returns information about the specified human loop.</p>
+    async fn describe_human_loop(
+        &self,
+        input: DescribeHumanLoopRequest,
+    ) -> Result<DescribeHumanLoopResponse, RusotoError<DescribeHumanLoopError>> {
+        self.describe_human_loop_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about human loops, given the specified parameters.</p>
+    async fn list_human_loops(
+        &self,
+        input: ListHumanLoopsRequest,
+    ) -> Result<ListHumanLoopsResponse, RusotoError<ListHumanLoopsError>> {
+        self.list_human_loops_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a human loop, provided that at least one activation condition is met.</p>
+    async fn start_human_loop(
+        &self,
+        input: StartHumanLoopRequest,
+    ) -> Result<StartHumanLoopResponse, RusotoError<StartHumanLoopError>> {
+        self.start_human_loop_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the specified human loop.</p>
+    async fn stop_human_loop(
+        &self,
+        input: StopHumanLoopRequest,
+    ) -> Result<StopHumanLoopResponse, RusotoError<StopHumanLoopError>> {
+        self.stop_human_loop_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -69,6 +69,7 @@ pub struct InvokeEndpointOutput {
 
 /// Errors returned by InvokeEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InvokeEndpointError {
     /// <p> An internal failure occurred. </p>
     InternalFailure(String),
@@ -78,6 +79,8 @@ pub enum InvokeEndpointError {
     ServiceUnavailable(String),
     /// <p> Inspect your request and try again. </p>
     ValidationError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InvokeEndpointError {
@@ -85,19 +88,28 @@ impl InvokeEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailure" => {
-                    return RusotoError::Service(InvokeEndpointError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        InvokeEndpointError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ModelError" => {
-                    return RusotoError::Service(InvokeEndpointError::ModelError(err.msg))
+                    return RusotoError::service(InvokeEndpointError::ModelError(err.msg), &res)
                 }
                 "ServiceUnavailable" => {
-                    return RusotoError::Service(InvokeEndpointError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        InvokeEndpointError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationError" => {
-                    return RusotoError::Service(InvokeEndpointError::ValidationError(err.msg))
+                    return RusotoError::service(
+                        InvokeEndpointError::ValidationError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InvokeEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -111,6 +123,7 @@ impl fmt::Display for InvokeEndpointError {
             InvokeEndpointError::ModelError(ref cause) => write!(f, "{}", cause),
             InvokeEndpointError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             InvokeEndpointError::ValidationError(ref cause) => write!(f, "{}", cause),
+            InvokeEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -135,6 +148,7 @@ impl SageMakerRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SageMakerRuntimeClient {
         SageMakerRuntimeClient {
             client: Client::shared(),
@@ -160,21 +174,91 @@ impl SageMakerRuntimeClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SageMakerRuntimeClient {
         SageMakerRuntimeClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SageMakerRuntimeClient> {
+        rusoto_core::ClientBuilder::new(SageMakerRuntimeClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SageMakerRuntimeClient {
+        SageMakerRuntimeClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SageMakerRuntime for SageMakerRuntimeClient {
-    /// <p><p>After you deploy a model into production using Amazon SageMaker hosting services, your client applications use this API to get inferences from the model hosted at the specified endpoint. </p> <p>For an overview of Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p> <p>Amazon SageMaker strips all POST headers except those supported by the API. Amazon SageMaker might add additional headers. You should not rely on the behavior of headers outside those enumerated in the request syntax. </p> <p>Calls to <code>InvokeEndpoint</code> are authenticated by using AWS Signature Version 4. For information, see <a href="http://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-authenticating-requests.html">Authenticating Requests (AWS Signature Version 4)</a> in the <i>Amazon S3 API Reference</i>.</p> <p>A customer&#39;s model containers must respond to requests within 60 seconds. The model itself can have a maximum processing time of 60 seconds before responding to the /invocations. If your model is going to take 50-60 seconds of processing time, the SDK socket timeout should be set to be 70 seconds.</p> <note> <p>Endpoints are scoped to an individual account, and are not public. The URL does not contain the account ID, but Amazon SageMaker determines the account ID from the authentication token that is supplied by the caller.</p> </note></p>
-    async fn invoke_endpoint(
+impl SageMakerRuntimeClient {
+    /// Calls `invoke_endpoint`, also returning the metadata of the response.
+    pub async fn invoke_endpoint_with_metadata(
         &self,
         input: InvokeEndpointInput,
-    ) -> Result<InvokeEndpointOutput, RusotoError<InvokeEndpointError>> {
+    ) -> Result<(InvokeEndpointOutput, ResponseMetadata), RusotoError<InvokeEndpointError>> {
+        let request = self.build_invoke_endpoint_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+
+            let mut result = InvokeEndpointOutput::default();
+            result.body = response.body;
+
+            if let Some(content_type) = response.headers.get("Content-Type") {
+                let value = content_type.to_owned();
+                result.content_type = Some(value)
+            };
+            if let Some(custom_attributes) =
+                response.headers.get("X-Amzn-SageMaker-Custom-Attributes")
+            {
+                let value = custom_attributes.to_owned();
+                result.custom_attributes = Some(value)
+            };
+            if let Some(invoked_production_variant) =
+                response.headers.get("x-Amzn-Invoked-Production-Variant")
+            {
+                let value = invoked_production_variant.to_owned();
+                result.invoked_production_variant = Some(value)
+            };
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(InvokeEndpointError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `invoke_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_invoke_endpoint(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: InvokeEndpointInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_invoke_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_invoke_endpoint_request(&self, input: InvokeEndpointInput) -> SignedRequest {
         let request_uri = format!(
             "/endpoints/{endpoint_name}/invocations",
             endpoint_name = input.endpoint_name
         );
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("InvokeEndpoint");
         if input.content_type.is_none() {
             request.set_content_type("application/x-amz-json-1.1".to_owned());
         }
@@ -202,38 +286,19 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
             request.add_header("X-Amzn-SageMaker-Target-Model", &target_model.to_string());
         }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-
-            let mut result = InvokeEndpointOutput::default();
-            result.body = response.body;
-
-            if let Some(content_type) = response.headers.get("Content-Type") {
-                let value = content_type.to_owned();
-                result.content_type = Some(value)
-            };
-            if let Some(custom_attributes) =
-                response.headers.get("X-Amzn-SageMaker-Custom-Attributes")
-            {
-                let value = custom_attributes.to_owned();
-                result.custom_attributes = Some(value)
-            };
-            if let Some(invoked_production_variant) =
-                response.headers.get("x-Amzn-Invoked-Production-Variant")
-            {
-                let value = invoked_production_variant.to_owned();
-                result.invoked_production_variant = Some(value)
-            };
+        request
+    }
+}
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(InvokeEndpointError::from_response(response))
-        }
+#[async_trait]
+impl SageMakerRuntime for SageMakerRuntimeClient {
+    /// <p><p>After you deploy a model into production using Amazon SageMaker hosting services, your client applications use this API to get inferences from the model hosted at the specified endpoint. </p> <p>For an overview of Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p> <p>Amazon SageMaker strips all POST headers except those supported by the API. Amazon SageMaker might add additional headers. You should not rely on the behavior of headers outside those enumerated in the request syntax. </p> <p>Calls to <code>InvokeEndpoint</code> are authenticated by using AWS Signature Version 4. For information, see <a href="http://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-authenticating-requests.html">Authenticating Requests (AWS Signature Version 4)</a> in the <i>Amazon S3 API Reference</i>.</p> <p>A customer&#39;s model containers must respond to requests within 60 seconds. The model itself can have a maximum processing time of 60 seconds before responding to the /invocations. If your model is going to take 50-60 seconds of processing time, the SDK socket timeout should be set to be 70 seconds.</p> <note> <p>Endpoints are scoped to an individual account, and are not public. The URL does not contain the account ID, but Amazon SageMaker determines the account ID from the authentication token that is supplied by the caller.</p> </note></p>
+    async fn invoke_endpoint(
+        &self,
+        input: InvokeEndpointInput,
+    ) -> Result<InvokeEndpointOutput, RusotoError<InvokeEndpointError>> {
+        self.invoke_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -8642,14 +8642,18 @@ pub struct Workteam {
 
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
-pub enum AddTagsError {}
+#[non_exhaustive]
+pub enum AddTagsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl AddTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<AddTagsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8658,17 +8662,22 @@ impl AddTagsError {
 impl fmt::Display for AddTagsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            AddTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for AddTagsError {}
 /// Errors returned by AssociateTrialComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateTrialComponentError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateTrialComponentError {
@@ -8676,17 +8685,19 @@ impl AssociateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateTrialComponentError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(AssociateTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateTrialComponentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateTrialComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8700,20 +8711,25 @@ impl fmt::Display for AssociateTrialComponentError {
                 write!(f, "{}", cause)
             }
             AssociateTrialComponentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssociateTrialComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateTrialComponentError {}
 /// Errors returned by CreateAlgorithm
 #[derive(Debug, PartialEq)]
-pub enum CreateAlgorithmError {}
+#[non_exhaustive]
+pub enum CreateAlgorithmError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl CreateAlgorithmError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateAlgorithmError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAlgorithmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8722,17 +8738,22 @@ impl CreateAlgorithmError {
 impl fmt::Display for CreateAlgorithmError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            CreateAlgorithmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateAlgorithmError {}
 /// Errors returned by CreateApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAppError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAppError {
@@ -8740,13 +8761,16 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateAppError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateAppError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateAppError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateAppError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8758,17 +8782,21 @@ impl fmt::Display for CreateAppError {
         match *self {
             CreateAppError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateAppError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAppError {}
 /// Errors returned by CreateAutoMLJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAutoMLJobError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAutoMLJobError {
@@ -8776,15 +8804,16 @@ impl CreateAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateAutoMLJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateAutoMLJobError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateAutoMLJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAutoMLJobError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAutoMLJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8796,20 +8825,25 @@ impl fmt::Display for CreateAutoMLJobError {
         match *self {
             CreateAutoMLJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateAutoMLJobError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateAutoMLJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAutoMLJobError {}
 /// Errors returned by CreateCodeRepository
 #[derive(Debug, PartialEq)]
-pub enum CreateCodeRepositoryError {}
+#[non_exhaustive]
+pub enum CreateCodeRepositoryError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl CreateCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCodeRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8818,17 +8852,22 @@ impl CreateCodeRepositoryError {
 impl fmt::Display for CreateCodeRepositoryError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            CreateCodeRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateCodeRepositoryError {}
 /// Errors returned by CreateCompilationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCompilationJobError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCompilationJobError {
@@ -8836,15 +8875,19 @@ impl CreateCompilationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateCompilationJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateCompilationJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateCompilationJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCompilationJobError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCompilationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8856,17 +8899,21 @@ impl fmt::Display for CreateCompilationJobError {
         match *self {
             CreateCompilationJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateCompilationJobError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateCompilationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCompilationJobError {}
 /// Errors returned by CreateDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDomainError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDomainError {
@@ -8874,13 +8921,16 @@ impl CreateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateDomainError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateDomainError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateDomainError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDomainError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8892,15 +8942,19 @@ impl fmt::Display for CreateDomainError {
         match *self {
             CreateDomainError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateDomainError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDomainError {}
 /// Errors returned by CreateEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEndpointError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEndpointError {
@@ -8908,12 +8962,13 @@ impl CreateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8924,15 +8979,19 @@ impl fmt::Display for CreateEndpointError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateEndpointError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEndpointError {}
 /// Errors returned by CreateEndpointConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEndpointConfigError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEndpointConfigError {
@@ -8940,12 +8999,13 @@ impl CreateEndpointConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateEndpointConfigError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointConfigError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEndpointConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8956,15 +9016,19 @@ impl fmt::Display for CreateEndpointConfigError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateEndpointConfigError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateEndpointConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEndpointConfigError {}
 /// Errors returned by CreateExperiment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateExperimentError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateExperimentError {
@@ -8972,12 +9036,13 @@ impl CreateExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateExperimentError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateExperimentError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateExperimentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8988,17 +9053,21 @@ impl fmt::Display for CreateExperimentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateExperimentError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateExperimentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateExperimentError {}
 /// Errors returned by CreateFlowDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFlowDefinitionError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFlowDefinitionError {
@@ -9006,15 +9075,19 @@ impl CreateFlowDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateFlowDefinitionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateFlowDefinitionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateFlowDefinitionError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFlowDefinitionError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFlowDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9026,17 +9099,21 @@ impl fmt::Display for CreateFlowDefinitionError {
         match *self {
             CreateFlowDefinitionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateFlowDefinitionError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateFlowDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFlowDefinitionError {}
 /// Errors returned by CreateHumanTaskUi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHumanTaskUiError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHumanTaskUiError {
@@ -9044,15 +9121,19 @@ impl CreateHumanTaskUiError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateHumanTaskUiError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateHumanTaskUiError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateHumanTaskUiError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHumanTaskUiError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHumanTaskUiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9064,17 +9145,21 @@ impl fmt::Display for CreateHumanTaskUiError {
         match *self {
             CreateHumanTaskUiError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateHumanTaskUiError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateHumanTaskUiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHumanTaskUiError {}
 /// Errors returned by CreateHyperParameterTuningJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHyperParameterTuningJobError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHyperParameterTuningJobError {
@@ -9084,17 +9169,24 @@ impl CreateHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateHyperParameterTuningJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHyperParameterTuningJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateHyperParameterTuningJobError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateHyperParameterTuningJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9108,17 +9200,23 @@ impl fmt::Display for CreateHyperParameterTuningJobError {
             CreateHyperParameterTuningJobError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateHyperParameterTuningJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateHyperParameterTuningJobError {}
 /// Errors returned by CreateLabelingJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLabelingJobError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLabelingJobError {
@@ -9126,15 +9224,19 @@ impl CreateLabelingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateLabelingJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateLabelingJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateLabelingJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLabelingJobError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLabelingJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9146,15 +9248,19 @@ impl fmt::Display for CreateLabelingJobError {
         match *self {
             CreateLabelingJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateLabelingJobError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateLabelingJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLabelingJobError {}
 /// Errors returned by CreateModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateModelError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateModelError {
@@ -9162,10 +9268,13 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateModelError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateModelError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9176,20 +9285,25 @@ impl fmt::Display for CreateModelError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateModelError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateModelError {}
 /// Errors returned by CreateModelPackage
 #[derive(Debug, PartialEq)]
-pub enum CreateModelPackageError {}
+#[non_exhaustive]
+pub enum CreateModelPackageError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl CreateModelPackageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateModelPackageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateModelPackageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9198,17 +9312,22 @@ impl CreateModelPackageError {
 impl fmt::Display for CreateModelPackageError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            CreateModelPackageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateModelPackageError {}
 /// Errors returned by CreateMonitoringSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMonitoringScheduleError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMonitoringScheduleError {
@@ -9216,17 +9335,19 @@ impl CreateMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateMonitoringScheduleError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMonitoringScheduleError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMonitoringScheduleError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMonitoringScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9240,15 +9361,19 @@ impl fmt::Display for CreateMonitoringScheduleError {
             CreateMonitoringScheduleError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateMonitoringScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMonitoringScheduleError {}
 /// Errors returned by CreateNotebookInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNotebookInstanceError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNotebookInstanceError {
@@ -9256,12 +9381,13 @@ impl CreateNotebookInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateNotebookInstanceError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNotebookInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9272,15 +9398,19 @@ impl fmt::Display for CreateNotebookInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateNotebookInstanceError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateNotebookInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNotebookInstanceError {}
 /// Errors returned by CreateNotebookInstanceLifecycleConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNotebookInstanceLifecycleConfigError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNotebookInstanceLifecycleConfigError {
@@ -9290,12 +9420,18 @@ impl CreateNotebookInstanceLifecycleConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateNotebookInstanceLifecycleConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9308,15 +9444,21 @@ impl fmt::Display for CreateNotebookInstanceLifecycleConfigError {
             CreateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateNotebookInstanceLifecycleConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateNotebookInstanceLifecycleConfigError {}
 /// Errors returned by CreatePresignedDomainUrl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePresignedDomainUrlError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePresignedDomainUrlError {
@@ -9324,12 +9466,13 @@ impl CreatePresignedDomainUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreatePresignedDomainUrlError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePresignedDomainUrlError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePresignedDomainUrlError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9340,13 +9483,18 @@ impl fmt::Display for CreatePresignedDomainUrlError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreatePresignedDomainUrlError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreatePresignedDomainUrlError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePresignedDomainUrlError {}
 /// Errors returned by CreatePresignedNotebookInstanceUrl
 #[derive(Debug, PartialEq)]
-pub enum CreatePresignedNotebookInstanceUrlError {}
+#[non_exhaustive]
+pub enum CreatePresignedNotebookInstanceUrlError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl CreatePresignedNotebookInstanceUrlError {
     pub fn from_response(
@@ -9355,7 +9503,12 @@ impl CreatePresignedNotebookInstanceUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreatePresignedNotebookInstanceUrlError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9364,12 +9517,17 @@ impl CreatePresignedNotebookInstanceUrlError {
 impl fmt::Display for CreatePresignedNotebookInstanceUrlError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            CreatePresignedNotebookInstanceUrlError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for CreatePresignedNotebookInstanceUrlError {}
 /// Errors returned by CreateProcessingJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProcessingJobError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
@@ -9377,6 +9535,8 @@ pub enum CreateProcessingJobError {
     ResourceLimitExceeded(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProcessingJobError {
@@ -9384,20 +9544,25 @@ impl CreateProcessingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateProcessingJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateProcessingJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateProcessingJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProcessingJobError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateProcessingJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProcessingJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProcessingJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9410,12 +9575,14 @@ impl fmt::Display for CreateProcessingJobError {
             CreateProcessingJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateProcessingJobError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateProcessingJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateProcessingJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProcessingJobError {}
 /// Errors returned by CreateTrainingJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTrainingJobError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
@@ -9423,6 +9590,8 @@ pub enum CreateTrainingJobError {
     ResourceLimitExceeded(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTrainingJobError {
@@ -9430,18 +9599,25 @@ impl CreateTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateTrainingJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateTrainingJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTrainingJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrainingJobError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateTrainingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateTrainingJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTrainingJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9454,12 +9630,14 @@ impl fmt::Display for CreateTrainingJobError {
             CreateTrainingJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateTrainingJobError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateTrainingJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateTrainingJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTrainingJobError {}
 /// Errors returned by CreateTransformJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTransformJobError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
@@ -9467,6 +9645,8 @@ pub enum CreateTransformJobError {
     ResourceLimitExceeded(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTransformJobError {
@@ -9474,18 +9654,25 @@ impl CreateTransformJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateTransformJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateTransformJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTransformJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTransformJobError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateTransformJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateTransformJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTransformJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9498,17 +9685,21 @@ impl fmt::Display for CreateTransformJobError {
             CreateTransformJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateTransformJobError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateTransformJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateTransformJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTransformJobError {}
 /// Errors returned by CreateTrial
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTrialError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTrialError {
@@ -9516,13 +9707,16 @@ impl CreateTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTrialError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateTrialError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateTrialError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTrialError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9534,15 +9728,19 @@ impl fmt::Display for CreateTrialError {
         match *self {
             CreateTrialError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateTrialError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateTrialError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTrialError {}
 /// Errors returned by CreateTrialComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTrialComponentError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTrialComponentError {
@@ -9550,12 +9748,13 @@ impl CreateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTrialComponentError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrialComponentError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTrialComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9566,17 +9765,21 @@ impl fmt::Display for CreateTrialComponentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateTrialComponentError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateTrialComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTrialComponentError {}
 /// Errors returned by CreateUserProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserProfileError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserProfileError {
@@ -9584,15 +9787,19 @@ impl CreateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateUserProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateUserProfileError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateUserProfileError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserProfileError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9604,17 +9811,21 @@ impl fmt::Display for CreateUserProfileError {
         match *self {
             CreateUserProfileError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateUserProfileError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserProfileError {}
 /// Errors returned by CreateWorkteam
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateWorkteamError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateWorkteamError {
@@ -9622,15 +9833,16 @@ impl CreateWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateWorkteamError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateWorkteamError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateWorkteamError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateWorkteamError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateWorkteamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9642,20 +9854,25 @@ impl fmt::Display for CreateWorkteamError {
         match *self {
             CreateWorkteamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateWorkteamError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateWorkteamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateWorkteamError {}
 /// Errors returned by DeleteAlgorithm
 #[derive(Debug, PartialEq)]
-pub enum DeleteAlgorithmError {}
+#[non_exhaustive]
+pub enum DeleteAlgorithmError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteAlgorithmError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteAlgorithmError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAlgorithmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9664,17 +9881,22 @@ impl DeleteAlgorithmError {
 impl fmt::Display for DeleteAlgorithmError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteAlgorithmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteAlgorithmError {}
 /// Errors returned by DeleteApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAppError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAppError {
@@ -9682,13 +9904,13 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteAppError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteAppError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteAppError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9700,20 +9922,25 @@ impl fmt::Display for DeleteAppError {
         match *self {
             DeleteAppError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteAppError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAppError {}
 /// Errors returned by DeleteCodeRepository
 #[derive(Debug, PartialEq)]
-pub enum DeleteCodeRepositoryError {}
+#[non_exhaustive]
+pub enum DeleteCodeRepositoryError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCodeRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9722,17 +9949,22 @@ impl DeleteCodeRepositoryError {
 impl fmt::Display for DeleteCodeRepositoryError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteCodeRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteCodeRepositoryError {}
 /// Errors returned by DeleteDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDomainError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDomainError {
@@ -9740,13 +9972,13 @@ impl DeleteDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteDomainError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteDomainError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteDomainError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9758,20 +9990,25 @@ impl fmt::Display for DeleteDomainError {
         match *self {
             DeleteDomainError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDomainError {}
 /// Errors returned by DeleteEndpoint
 #[derive(Debug, PartialEq)]
-pub enum DeleteEndpointError {}
+#[non_exhaustive]
+pub enum DeleteEndpointError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteEndpointError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteEndpointError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9780,20 +10017,26 @@ impl DeleteEndpointError {
 impl fmt::Display for DeleteEndpointError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteEndpointError {}
 /// Errors returned by DeleteEndpointConfig
 #[derive(Debug, PartialEq)]
-pub enum DeleteEndpointConfigError {}
+#[non_exhaustive]
+pub enum DeleteEndpointConfigError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteEndpointConfigError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteEndpointConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEndpointConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9802,15 +10045,20 @@ impl DeleteEndpointConfigError {
 impl fmt::Display for DeleteEndpointConfigError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteEndpointConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteEndpointConfigError {}
 /// Errors returned by DeleteExperiment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteExperimentError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteExperimentError {
@@ -9818,10 +10066,13 @@ impl DeleteExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteExperimentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteExperimentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteExperimentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9832,15 +10083,19 @@ impl fmt::Display for DeleteExperimentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteExperimentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteExperimentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteExperimentError {}
 /// Errors returned by DeleteFlowDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFlowDefinitionError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFlowDefinitionError {
@@ -9848,12 +10103,13 @@ impl DeleteFlowDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteFlowDefinitionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFlowDefinitionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFlowDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9864,20 +10120,25 @@ impl fmt::Display for DeleteFlowDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteFlowDefinitionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteFlowDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFlowDefinitionError {}
 /// Errors returned by DeleteModel
 #[derive(Debug, PartialEq)]
-pub enum DeleteModelError {}
+#[non_exhaustive]
+pub enum DeleteModelError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteModelError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteModelError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9886,20 +10147,26 @@ impl DeleteModelError {
 impl fmt::Display for DeleteModelError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteModelError {}
 /// Errors returned by DeleteModelPackage
 #[derive(Debug, PartialEq)]
-pub enum DeleteModelPackageError {}
+#[non_exhaustive]
+pub enum DeleteModelPackageError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteModelPackageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteModelPackageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteModelPackageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9908,15 +10175,20 @@ impl DeleteModelPackageError {
 impl fmt::Display for DeleteModelPackageError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteModelPackageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteModelPackageError {}
 /// Errors returned by DeleteMonitoringSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMonitoringScheduleError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMonitoringScheduleError {
@@ -9924,12 +10196,13 @@ impl DeleteMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMonitoringScheduleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMonitoringScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9940,20 +10213,25 @@ impl fmt::Display for DeleteMonitoringScheduleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteMonitoringScheduleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteMonitoringScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMonitoringScheduleError {}
 /// Errors returned by DeleteNotebookInstance
 #[derive(Debug, PartialEq)]
-pub enum DeleteNotebookInstanceError {}
+#[non_exhaustive]
+pub enum DeleteNotebookInstanceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteNotebookInstanceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteNotebookInstanceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNotebookInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9962,13 +10240,19 @@ impl DeleteNotebookInstanceError {
 impl fmt::Display for DeleteNotebookInstanceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteNotebookInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteNotebookInstanceError {}
 /// Errors returned by DeleteNotebookInstanceLifecycleConfig
 #[derive(Debug, PartialEq)]
-pub enum DeleteNotebookInstanceLifecycleConfigError {}
+#[non_exhaustive]
+pub enum DeleteNotebookInstanceLifecycleConfigError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteNotebookInstanceLifecycleConfigError {
     pub fn from_response(
@@ -9977,7 +10261,12 @@ impl DeleteNotebookInstanceLifecycleConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteNotebookInstanceLifecycleConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9986,20 +10275,28 @@ impl DeleteNotebookInstanceLifecycleConfigError {
 impl fmt::Display for DeleteNotebookInstanceLifecycleConfigError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteNotebookInstanceLifecycleConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DeleteNotebookInstanceLifecycleConfigError {}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
-pub enum DeleteTagsError {}
+#[non_exhaustive]
+pub enum DeleteTagsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTagsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10008,15 +10305,20 @@ impl DeleteTagsError {
 impl fmt::Display for DeleteTagsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteTagsError {}
 /// Errors returned by DeleteTrial
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTrialError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTrialError {
@@ -10024,10 +10326,10 @@ impl DeleteTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteTrialError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTrialError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10038,15 +10340,19 @@ impl fmt::Display for DeleteTrialError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteTrialError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteTrialError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTrialError {}
 /// Errors returned by DeleteTrialComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTrialComponentError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTrialComponentError {
@@ -10054,12 +10360,13 @@ impl DeleteTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTrialComponentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTrialComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10070,17 +10377,21 @@ impl fmt::Display for DeleteTrialComponentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteTrialComponentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteTrialComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTrialComponentError {}
 /// Errors returned by DeleteUserProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserProfileError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserProfileError {
@@ -10088,13 +10399,19 @@ impl DeleteUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteUserProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteUserProfileError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteUserProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10106,15 +10423,19 @@ impl fmt::Display for DeleteUserProfileError {
         match *self {
             DeleteUserProfileError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteUserProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserProfileError {}
 /// Errors returned by DeleteWorkteam
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteWorkteamError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteWorkteamError {
@@ -10122,12 +10443,13 @@ impl DeleteWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(DeleteWorkteamError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteWorkteamError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteWorkteamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10138,20 +10460,25 @@ impl fmt::Display for DeleteWorkteamError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteWorkteamError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            DeleteWorkteamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteWorkteamError {}
 /// Errors returned by DescribeAlgorithm
 #[derive(Debug, PartialEq)]
-pub enum DescribeAlgorithmError {}
+#[non_exhaustive]
+pub enum DescribeAlgorithmError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeAlgorithmError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAlgorithmError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAlgorithmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10160,15 +10487,20 @@ impl DescribeAlgorithmError {
 impl fmt::Display for DescribeAlgorithmError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeAlgorithmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeAlgorithmError {}
 /// Errors returned by DescribeApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAppError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAppError {
@@ -10176,10 +10508,10 @@ impl DescribeAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeAppError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10190,15 +10522,19 @@ impl fmt::Display for DescribeAppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeAppError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAppError {}
 /// Errors returned by DescribeAutoMLJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAutoMLJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAutoMLJobError {
@@ -10206,10 +10542,13 @@ impl DescribeAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeAutoMLJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeAutoMLJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAutoMLJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10220,20 +10559,25 @@ impl fmt::Display for DescribeAutoMLJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeAutoMLJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeAutoMLJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAutoMLJobError {}
 /// Errors returned by DescribeCodeRepository
 #[derive(Debug, PartialEq)]
-pub enum DescribeCodeRepositoryError {}
+#[non_exhaustive]
+pub enum DescribeCodeRepositoryError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCodeRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10242,15 +10586,20 @@ impl DescribeCodeRepositoryError {
 impl fmt::Display for DescribeCodeRepositoryError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeCodeRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeCodeRepositoryError {}
 /// Errors returned by DescribeCompilationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCompilationJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCompilationJobError {
@@ -10258,12 +10607,13 @@ impl DescribeCompilationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeCompilationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCompilationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCompilationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10274,15 +10624,19 @@ impl fmt::Display for DescribeCompilationJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeCompilationJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeCompilationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCompilationJobError {}
 /// Errors returned by DescribeDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDomainError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDomainError {
@@ -10290,10 +10644,13 @@ impl DescribeDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDomainError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10304,20 +10661,25 @@ impl fmt::Display for DescribeDomainError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDomainError {}
 /// Errors returned by DescribeEndpoint
 #[derive(Debug, PartialEq)]
-pub enum DescribeEndpointError {}
+#[non_exhaustive]
+pub enum DescribeEndpointError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEndpointError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10326,20 +10688,26 @@ impl DescribeEndpointError {
 impl fmt::Display for DescribeEndpointError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEndpointError {}
 /// Errors returned by DescribeEndpointConfig
 #[derive(Debug, PartialEq)]
-pub enum DescribeEndpointConfigError {}
+#[non_exhaustive]
+pub enum DescribeEndpointConfigError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEndpointConfigError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEndpointConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10348,15 +10716,20 @@ impl DescribeEndpointConfigError {
 impl fmt::Display for DescribeEndpointConfigError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEndpointConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEndpointConfigError {}
 /// Errors returned by DescribeExperiment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeExperimentError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeExperimentError {
@@ -10364,10 +10737,13 @@ impl DescribeExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeExperimentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeExperimentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeExperimentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10378,15 +10754,19 @@ impl fmt::Display for DescribeExperimentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeExperimentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeExperimentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeExperimentError {}
 /// Errors returned by DescribeFlowDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFlowDefinitionError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFlowDefinitionError {
@@ -10394,12 +10774,13 @@ impl DescribeFlowDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeFlowDefinitionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFlowDefinitionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFlowDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10410,15 +10791,19 @@ impl fmt::Display for DescribeFlowDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeFlowDefinitionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeFlowDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFlowDefinitionError {}
 /// Errors returned by DescribeHumanTaskUi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHumanTaskUiError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHumanTaskUiError {
@@ -10426,12 +10811,13 @@ impl DescribeHumanTaskUiError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeHumanTaskUiError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeHumanTaskUiError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeHumanTaskUiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10442,15 +10828,19 @@ impl fmt::Display for DescribeHumanTaskUiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeHumanTaskUiError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeHumanTaskUiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHumanTaskUiError {}
 /// Errors returned by DescribeHyperParameterTuningJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHyperParameterTuningJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHyperParameterTuningJobError {
@@ -10460,12 +10850,18 @@ impl DescribeHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeHyperParameterTuningJobError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeHyperParameterTuningJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10478,15 +10874,21 @@ impl fmt::Display for DescribeHyperParameterTuningJobError {
             DescribeHyperParameterTuningJobError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeHyperParameterTuningJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeHyperParameterTuningJobError {}
 /// Errors returned by DescribeLabelingJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLabelingJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLabelingJobError {
@@ -10494,12 +10896,13 @@ impl DescribeLabelingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeLabelingJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLabelingJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLabelingJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10510,20 +10913,25 @@ impl fmt::Display for DescribeLabelingJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeLabelingJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeLabelingJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLabelingJobError {}
 /// Errors returned by DescribeModel
 #[derive(Debug, PartialEq)]
-pub enum DescribeModelError {}
+#[non_exhaustive]
+pub enum DescribeModelError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeModelError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeModelError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10532,20 +10940,26 @@ impl DescribeModelError {
 impl fmt::Display for DescribeModelError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeModelError {}
 /// Errors returned by DescribeModelPackage
 #[derive(Debug, PartialEq)]
-pub enum DescribeModelPackageError {}
+#[non_exhaustive]
+pub enum DescribeModelPackageError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeModelPackageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeModelPackageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeModelPackageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10554,15 +10968,20 @@ impl DescribeModelPackageError {
 impl fmt::Display for DescribeModelPackageError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeModelPackageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeModelPackageError {}
 /// Errors returned by DescribeMonitoringSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMonitoringScheduleError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMonitoringScheduleError {
@@ -10572,12 +10991,15 @@ impl DescribeMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMonitoringScheduleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeMonitoringScheduleError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10588,20 +11010,25 @@ impl fmt::Display for DescribeMonitoringScheduleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeMonitoringScheduleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeMonitoringScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMonitoringScheduleError {}
 /// Errors returned by DescribeNotebookInstance
 #[derive(Debug, PartialEq)]
-pub enum DescribeNotebookInstanceError {}
+#[non_exhaustive]
+pub enum DescribeNotebookInstanceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeNotebookInstanceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeNotebookInstanceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeNotebookInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10610,13 +11037,19 @@ impl DescribeNotebookInstanceError {
 impl fmt::Display for DescribeNotebookInstanceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeNotebookInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeNotebookInstanceError {}
 /// Errors returned by DescribeNotebookInstanceLifecycleConfig
 #[derive(Debug, PartialEq)]
-pub enum DescribeNotebookInstanceLifecycleConfigError {}
+#[non_exhaustive]
+pub enum DescribeNotebookInstanceLifecycleConfigError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeNotebookInstanceLifecycleConfigError {
     pub fn from_response(
@@ -10625,7 +11058,12 @@ impl DescribeNotebookInstanceLifecycleConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeNotebookInstanceLifecycleConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10634,15 +11072,22 @@ impl DescribeNotebookInstanceLifecycleConfigError {
 impl fmt::Display for DescribeNotebookInstanceLifecycleConfigError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeNotebookInstanceLifecycleConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeNotebookInstanceLifecycleConfigError {}
 /// Errors returned by DescribeProcessingJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProcessingJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProcessingJobError {
@@ -10650,12 +11095,13 @@ impl DescribeProcessingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeProcessingJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProcessingJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProcessingJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10666,13 +11112,18 @@ impl fmt::Display for DescribeProcessingJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeProcessingJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProcessingJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProcessingJobError {}
 /// Errors returned by DescribeSubscribedWorkteam
 #[derive(Debug, PartialEq)]
-pub enum DescribeSubscribedWorkteamError {}
+#[non_exhaustive]
+pub enum DescribeSubscribedWorkteamError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeSubscribedWorkteamError {
     pub fn from_response(
@@ -10681,7 +11132,9 @@ impl DescribeSubscribedWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeSubscribedWorkteamError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10690,15 +11143,20 @@ impl DescribeSubscribedWorkteamError {
 impl fmt::Display for DescribeSubscribedWorkteamError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeSubscribedWorkteamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeSubscribedWorkteamError {}
 /// Errors returned by DescribeTrainingJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTrainingJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTrainingJobError {
@@ -10706,12 +11164,13 @@ impl DescribeTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTrainingJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTrainingJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTrainingJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10722,15 +11181,19 @@ impl fmt::Display for DescribeTrainingJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeTrainingJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTrainingJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTrainingJobError {}
 /// Errors returned by DescribeTransformJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTransformJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTransformJobError {
@@ -10738,12 +11201,13 @@ impl DescribeTransformJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTransformJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTransformJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTransformJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10754,15 +11218,19 @@ impl fmt::Display for DescribeTransformJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeTransformJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTransformJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTransformJobError {}
 /// Errors returned by DescribeTrial
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTrialError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTrialError {
@@ -10770,10 +11238,13 @@ impl DescribeTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeTrialError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTrialError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10784,15 +11255,19 @@ impl fmt::Display for DescribeTrialError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeTrialError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTrialError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTrialError {}
 /// Errors returned by DescribeTrialComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTrialComponentError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTrialComponentError {
@@ -10800,12 +11275,13 @@ impl DescribeTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTrialComponentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTrialComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10816,15 +11292,19 @@ impl fmt::Display for DescribeTrialComponentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeTrialComponentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTrialComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTrialComponentError {}
 /// Errors returned by DescribeUserProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserProfileError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserProfileError {
@@ -10832,12 +11312,13 @@ impl DescribeUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeUserProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10848,20 +11329,25 @@ impl fmt::Display for DescribeUserProfileError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeUserProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserProfileError {}
 /// Errors returned by DescribeWorkforce
 #[derive(Debug, PartialEq)]
-pub enum DescribeWorkforceError {}
+#[non_exhaustive]
+pub enum DescribeWorkforceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeWorkforceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeWorkforceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeWorkforceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10870,20 +11356,26 @@ impl DescribeWorkforceError {
 impl fmt::Display for DescribeWorkforceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeWorkforceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeWorkforceError {}
 /// Errors returned by DescribeWorkteam
 #[derive(Debug, PartialEq)]
-pub enum DescribeWorkteamError {}
+#[non_exhaustive]
+pub enum DescribeWorkteamError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeWorkteamError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeWorkteamError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeWorkteamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10892,15 +11384,20 @@ impl DescribeWorkteamError {
 impl fmt::Display for DescribeWorkteamError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeWorkteamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeWorkteamError {}
 /// Errors returned by DisassociateTrialComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateTrialComponentError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateTrialComponentError {
@@ -10910,12 +11407,15 @@ impl DisassociateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DisassociateTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateTrialComponentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DisassociateTrialComponentError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10926,20 +11426,25 @@ impl fmt::Display for DisassociateTrialComponentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DisassociateTrialComponentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateTrialComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateTrialComponentError {}
 /// Errors returned by GetSearchSuggestions
 #[derive(Debug, PartialEq)]
-pub enum GetSearchSuggestionsError {}
+#[non_exhaustive]
+pub enum GetSearchSuggestionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetSearchSuggestionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetSearchSuggestionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSearchSuggestionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10948,20 +11453,26 @@ impl GetSearchSuggestionsError {
 impl fmt::Display for GetSearchSuggestionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetSearchSuggestionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetSearchSuggestionsError {}
 /// Errors returned by ListAlgorithms
 #[derive(Debug, PartialEq)]
-pub enum ListAlgorithmsError {}
+#[non_exhaustive]
+pub enum ListAlgorithmsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListAlgorithmsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAlgorithmsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAlgorithmsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10970,20 +11481,26 @@ impl ListAlgorithmsError {
 impl fmt::Display for ListAlgorithmsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListAlgorithmsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListAlgorithmsError {}
 /// Errors returned by ListApps
 #[derive(Debug, PartialEq)]
-pub enum ListAppsError {}
+#[non_exhaustive]
+pub enum ListAppsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListAppsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAppsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAppsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10992,20 +11509,26 @@ impl ListAppsError {
 impl fmt::Display for ListAppsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListAppsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListAppsError {}
 /// Errors returned by ListAutoMLJobs
 #[derive(Debug, PartialEq)]
-pub enum ListAutoMLJobsError {}
+#[non_exhaustive]
+pub enum ListAutoMLJobsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListAutoMLJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAutoMLJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAutoMLJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11014,15 +11537,20 @@ impl ListAutoMLJobsError {
 impl fmt::Display for ListAutoMLJobsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListAutoMLJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListAutoMLJobsError {}
 /// Errors returned by ListCandidatesForAutoMLJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCandidatesForAutoMLJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCandidatesForAutoMLJobError {
@@ -11032,12 +11560,15 @@ impl ListCandidatesForAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListCandidatesForAutoMLJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCandidatesForAutoMLJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListCandidatesForAutoMLJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11048,20 +11579,25 @@ impl fmt::Display for ListCandidatesForAutoMLJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListCandidatesForAutoMLJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListCandidatesForAutoMLJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCandidatesForAutoMLJobError {}
 /// Errors returned by ListCodeRepositories
 #[derive(Debug, PartialEq)]
-pub enum ListCodeRepositoriesError {}
+#[non_exhaustive]
+pub enum ListCodeRepositoriesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListCodeRepositoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListCodeRepositoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCodeRepositoriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11070,20 +11606,26 @@ impl ListCodeRepositoriesError {
 impl fmt::Display for ListCodeRepositoriesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListCodeRepositoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListCodeRepositoriesError {}
 /// Errors returned by ListCompilationJobs
 #[derive(Debug, PartialEq)]
-pub enum ListCompilationJobsError {}
+#[non_exhaustive]
+pub enum ListCompilationJobsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListCompilationJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListCompilationJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCompilationJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11092,20 +11634,26 @@ impl ListCompilationJobsError {
 impl fmt::Display for ListCompilationJobsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListCompilationJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListCompilationJobsError {}
 /// Errors returned by ListDomains
 #[derive(Debug, PartialEq)]
-pub enum ListDomainsError {}
+#[non_exhaustive]
+pub enum ListDomainsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListDomainsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDomainsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDomainsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11114,20 +11662,26 @@ impl ListDomainsError {
 impl fmt::Display for ListDomainsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListDomainsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListDomainsError {}
 /// Errors returned by ListEndpointConfigs
 #[derive(Debug, PartialEq)]
-pub enum ListEndpointConfigsError {}
+#[non_exhaustive]
+pub enum ListEndpointConfigsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListEndpointConfigsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListEndpointConfigsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEndpointConfigsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11136,20 +11690,26 @@ impl ListEndpointConfigsError {
 impl fmt::Display for ListEndpointConfigsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListEndpointConfigsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListEndpointConfigsError {}
 /// Errors returned by ListEndpoints
 #[derive(Debug, PartialEq)]
-pub enum ListEndpointsError {}
+#[non_exhaustive]
+pub enum ListEndpointsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListEndpointsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListEndpointsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11158,20 +11718,26 @@ impl ListEndpointsError {
 impl fmt::Display for ListEndpointsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListEndpointsError {}
 /// Errors returned by ListExperiments
 #[derive(Debug, PartialEq)]
-pub enum ListExperimentsError {}
+#[non_exhaustive]
+pub enum ListExperimentsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListExperimentsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListExperimentsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListExperimentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11180,20 +11746,26 @@ impl ListExperimentsError {
 impl fmt::Display for ListExperimentsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListExperimentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListExperimentsError {}
 /// Errors returned by ListFlowDefinitions
 #[derive(Debug, PartialEq)]
-pub enum ListFlowDefinitionsError {}
+#[non_exhaustive]
+pub enum ListFlowDefinitionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListFlowDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListFlowDefinitionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFlowDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11202,20 +11774,26 @@ impl ListFlowDefinitionsError {
 impl fmt::Display for ListFlowDefinitionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListFlowDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListFlowDefinitionsError {}
 /// Errors returned by ListHumanTaskUis
 #[derive(Debug, PartialEq)]
-pub enum ListHumanTaskUisError {}
+#[non_exhaustive]
+pub enum ListHumanTaskUisError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListHumanTaskUisError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListHumanTaskUisError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListHumanTaskUisError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11224,13 +11802,19 @@ impl ListHumanTaskUisError {
 impl fmt::Display for ListHumanTaskUisError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListHumanTaskUisError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListHumanTaskUisError {}
 /// Errors returned by ListHyperParameterTuningJobs
 #[derive(Debug, PartialEq)]
-pub enum ListHyperParameterTuningJobsError {}
+#[non_exhaustive]
+pub enum ListHyperParameterTuningJobsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListHyperParameterTuningJobsError {
     pub fn from_response(
@@ -11239,7 +11823,12 @@ impl ListHyperParameterTuningJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListHyperParameterTuningJobsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11248,20 +11837,26 @@ impl ListHyperParameterTuningJobsError {
 impl fmt::Display for ListHyperParameterTuningJobsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListHyperParameterTuningJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListHyperParameterTuningJobsError {}
 /// Errors returned by ListLabelingJobs
 #[derive(Debug, PartialEq)]
-pub enum ListLabelingJobsError {}
+#[non_exhaustive]
+pub enum ListLabelingJobsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListLabelingJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListLabelingJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLabelingJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11270,15 +11865,20 @@ impl ListLabelingJobsError {
 impl fmt::Display for ListLabelingJobsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListLabelingJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListLabelingJobsError {}
 /// Errors returned by ListLabelingJobsForWorkteam
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLabelingJobsForWorkteamError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLabelingJobsForWorkteamError {
@@ -11288,12 +11888,18 @@ impl ListLabelingJobsForWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLabelingJobsForWorkteamError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListLabelingJobsForWorkteamError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11304,20 +11910,25 @@ impl fmt::Display for ListLabelingJobsForWorkteamError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListLabelingJobsForWorkteamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListLabelingJobsForWorkteamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLabelingJobsForWorkteamError {}
 /// Errors returned by ListModelPackages
 #[derive(Debug, PartialEq)]
-pub enum ListModelPackagesError {}
+#[non_exhaustive]
+pub enum ListModelPackagesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListModelPackagesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListModelPackagesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListModelPackagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11326,20 +11937,26 @@ impl ListModelPackagesError {
 impl fmt::Display for ListModelPackagesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListModelPackagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListModelPackagesError {}
 /// Errors returned by ListModels
 #[derive(Debug, PartialEq)]
-pub enum ListModelsError {}
+#[non_exhaustive]
+pub enum ListModelsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListModelsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListModelsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListModelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11348,20 +11965,26 @@ impl ListModelsError {
 impl fmt::Display for ListModelsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListModelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListModelsError {}
 /// Errors returned by ListMonitoringExecutions
 #[derive(Debug, PartialEq)]
-pub enum ListMonitoringExecutionsError {}
+#[non_exhaustive]
+pub enum ListMonitoringExecutionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListMonitoringExecutionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListMonitoringExecutionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMonitoringExecutionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11370,20 +11993,26 @@ impl ListMonitoringExecutionsError {
 impl fmt::Display for ListMonitoringExecutionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListMonitoringExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListMonitoringExecutionsError {}
 /// Errors returned by ListMonitoringSchedules
 #[derive(Debug, PartialEq)]
-pub enum ListMonitoringSchedulesError {}
+#[non_exhaustive]
+pub enum ListMonitoringSchedulesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListMonitoringSchedulesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListMonitoringSchedulesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMonitoringSchedulesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11392,13 +12021,19 @@ impl ListMonitoringSchedulesError {
 impl fmt::Display for ListMonitoringSchedulesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListMonitoringSchedulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListMonitoringSchedulesError {}
 /// Errors returned by ListNotebookInstanceLifecycleConfigs
 #[derive(Debug, PartialEq)]
-pub enum ListNotebookInstanceLifecycleConfigsError {}
+#[non_exhaustive]
+pub enum ListNotebookInstanceLifecycleConfigsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListNotebookInstanceLifecycleConfigsError {
     pub fn from_response(
@@ -11407,7 +12042,12 @@ impl ListNotebookInstanceLifecycleConfigsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListNotebookInstanceLifecycleConfigsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11416,20 +12056,28 @@ impl ListNotebookInstanceLifecycleConfigsError {
 impl fmt::Display for ListNotebookInstanceLifecycleConfigsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListNotebookInstanceLifecycleConfigsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for ListNotebookInstanceLifecycleConfigsError {}
 /// Errors returned by ListNotebookInstances
 #[derive(Debug, PartialEq)]
-pub enum ListNotebookInstancesError {}
+#[non_exhaustive]
+pub enum ListNotebookInstancesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListNotebookInstancesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListNotebookInstancesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListNotebookInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11438,20 +12086,26 @@ impl ListNotebookInstancesError {
 impl fmt::Display for ListNotebookInstancesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListNotebookInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListNotebookInstancesError {}
 /// Errors returned by ListProcessingJobs
 #[derive(Debug, PartialEq)]
-pub enum ListProcessingJobsError {}
+#[non_exhaustive]
+pub enum ListProcessingJobsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListProcessingJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListProcessingJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProcessingJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11460,20 +12114,26 @@ impl ListProcessingJobsError {
 impl fmt::Display for ListProcessingJobsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListProcessingJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListProcessingJobsError {}
 /// Errors returned by ListSubscribedWorkteams
 #[derive(Debug, PartialEq)]
-pub enum ListSubscribedWorkteamsError {}
+#[non_exhaustive]
+pub enum ListSubscribedWorkteamsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListSubscribedWorkteamsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSubscribedWorkteamsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSubscribedWorkteamsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11482,20 +12142,26 @@ impl ListSubscribedWorkteamsError {
 impl fmt::Display for ListSubscribedWorkteamsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListSubscribedWorkteamsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListSubscribedWorkteamsError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
-pub enum ListTagsError {}
+#[non_exhaustive]
+pub enum ListTagsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11504,20 +12170,26 @@ impl ListTagsError {
 impl fmt::Display for ListTagsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by ListTrainingJobs
 #[derive(Debug, PartialEq)]
-pub enum ListTrainingJobsError {}
+#[non_exhaustive]
+pub enum ListTrainingJobsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListTrainingJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTrainingJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTrainingJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11526,15 +12198,20 @@ impl ListTrainingJobsError {
 impl fmt::Display for ListTrainingJobsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListTrainingJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListTrainingJobsError {}
 /// Errors returned by ListTrainingJobsForHyperParameterTuningJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTrainingJobsForHyperParameterTuningJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTrainingJobsForHyperParameterTuningJobError {
@@ -11544,12 +12221,18 @@ impl ListTrainingJobsForHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTrainingJobsForHyperParameterTuningJobError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListTrainingJobsForHyperParameterTuningJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11562,20 +12245,27 @@ impl fmt::Display for ListTrainingJobsForHyperParameterTuningJobError {
             ListTrainingJobsForHyperParameterTuningJobError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListTrainingJobsForHyperParameterTuningJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListTrainingJobsForHyperParameterTuningJobError {}
 /// Errors returned by ListTransformJobs
 #[derive(Debug, PartialEq)]
-pub enum ListTransformJobsError {}
+#[non_exhaustive]
+pub enum ListTransformJobsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListTransformJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTransformJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTransformJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11584,15 +12274,20 @@ impl ListTransformJobsError {
 impl fmt::Display for ListTransformJobsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListTransformJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListTransformJobsError {}
 /// Errors returned by ListTrialComponents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTrialComponentsError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTrialComponentsError {
@@ -11600,12 +12295,13 @@ impl ListTrialComponentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTrialComponentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTrialComponentsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTrialComponentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11616,15 +12312,19 @@ impl fmt::Display for ListTrialComponentsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTrialComponentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTrialComponentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTrialComponentsError {}
 /// Errors returned by ListTrials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTrialsError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTrialsError {
@@ -11632,10 +12332,10 @@ impl ListTrialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTrialsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListTrialsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTrialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11646,20 +12346,25 @@ impl fmt::Display for ListTrialsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTrialsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTrialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTrialsError {}
 /// Errors returned by ListUserProfiles
 #[derive(Debug, PartialEq)]
-pub enum ListUserProfilesError {}
+#[non_exhaustive]
+pub enum ListUserProfilesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListUserProfilesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListUserProfilesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUserProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11668,20 +12373,26 @@ impl ListUserProfilesError {
 impl fmt::Display for ListUserProfilesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListUserProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListUserProfilesError {}
 /// Errors returned by ListWorkteams
 #[derive(Debug, PartialEq)]
-pub enum ListWorkteamsError {}
+#[non_exhaustive]
+pub enum ListWorkteamsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListWorkteamsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListWorkteamsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListWorkteamsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11690,20 +12401,26 @@ impl ListWorkteamsError {
 impl fmt::Display for ListWorkteamsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListWorkteamsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListWorkteamsError {}
 /// Errors returned by RenderUiTemplate
 #[derive(Debug, PartialEq)]
-pub enum RenderUiTemplateError {}
+#[non_exhaustive]
+pub enum RenderUiTemplateError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl RenderUiTemplateError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RenderUiTemplateError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RenderUiTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11712,20 +12429,26 @@ impl RenderUiTemplateError {
 impl fmt::Display for RenderUiTemplateError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            RenderUiTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for RenderUiTemplateError {}
 /// Errors returned by Search
 #[derive(Debug, PartialEq)]
-pub enum SearchError {}
+#[non_exhaustive]
+pub enum SearchError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SearchError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11734,15 +12457,20 @@ impl SearchError {
 impl fmt::Display for SearchError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SearchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SearchError {}
 /// Errors returned by StartMonitoringSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartMonitoringScheduleError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartMonitoringScheduleError {
@@ -11750,12 +12478,13 @@ impl StartMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StartMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartMonitoringScheduleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartMonitoringScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11766,15 +12495,19 @@ impl fmt::Display for StartMonitoringScheduleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StartMonitoringScheduleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartMonitoringScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartMonitoringScheduleError {}
 /// Errors returned by StartNotebookInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartNotebookInstanceError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartNotebookInstanceError {
@@ -11782,12 +12515,13 @@ impl StartNotebookInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(StartNotebookInstanceError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartNotebookInstanceError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartNotebookInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11798,15 +12532,19 @@ impl fmt::Display for StartNotebookInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StartNotebookInstanceError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            StartNotebookInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartNotebookInstanceError {}
 /// Errors returned by StopAutoMLJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopAutoMLJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopAutoMLJobError {
@@ -11814,10 +12552,13 @@ impl StopAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopAutoMLJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopAutoMLJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopAutoMLJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11828,15 +12569,19 @@ impl fmt::Display for StopAutoMLJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopAutoMLJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopAutoMLJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopAutoMLJobError {}
 /// Errors returned by StopCompilationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopCompilationJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopCompilationJobError {
@@ -11844,10 +12589,13 @@ impl StopCompilationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopCompilationJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopCompilationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopCompilationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11858,15 +12606,19 @@ impl fmt::Display for StopCompilationJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopCompilationJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopCompilationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopCompilationJobError {}
 /// Errors returned by StopHyperParameterTuningJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopHyperParameterTuningJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopHyperParameterTuningJobError {
@@ -11876,13 +12628,19 @@ impl StopHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopHyperParameterTuningJobError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
+                _ => {
+                    return RusotoError::unhandled(
+                        StopHyperParameterTuningJobError::Unhandled,
+                        &res,
+                    )
+                }
+            }
         }
         RusotoError::Unknown(res)
     }
@@ -11892,15 +12650,19 @@ impl fmt::Display for StopHyperParameterTuningJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopHyperParameterTuningJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopHyperParameterTuningJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopHyperParameterTuningJobError {}
 /// Errors returned by StopLabelingJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopLabelingJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopLabelingJobError {
@@ -11908,10 +12670,13 @@ impl StopLabelingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopLabelingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopLabelingJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopLabelingJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11922,15 +12687,19 @@ impl fmt::Display for StopLabelingJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopLabelingJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopLabelingJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopLabelingJobError {}
 /// Errors returned by StopMonitoringSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopMonitoringScheduleError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopMonitoringScheduleError {
@@ -11938,12 +12707,13 @@ impl StopMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopMonitoringScheduleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopMonitoringScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11954,20 +12724,25 @@ impl fmt::Display for StopMonitoringScheduleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopMonitoringScheduleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopMonitoringScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopMonitoringScheduleError {}
 /// Errors returned by StopNotebookInstance
 #[derive(Debug, PartialEq)]
-pub enum StopNotebookInstanceError {}
+#[non_exhaustive]
+pub enum StopNotebookInstanceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl StopNotebookInstanceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StopNotebookInstanceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopNotebookInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11976,15 +12751,20 @@ impl StopNotebookInstanceError {
 impl fmt::Display for StopNotebookInstanceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            StopNotebookInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for StopNotebookInstanceError {}
 /// Errors returned by StopProcessingJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopProcessingJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopProcessingJobError {
@@ -11992,10 +12772,13 @@ impl StopProcessingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopProcessingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopProcessingJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopProcessingJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12006,15 +12789,19 @@ impl fmt::Display for StopProcessingJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopProcessingJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopProcessingJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopProcessingJobError {}
 /// Errors returned by StopTrainingJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopTrainingJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopTrainingJobError {
@@ -12022,10 +12809,13 @@ impl StopTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopTrainingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopTrainingJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopTrainingJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12036,15 +12826,19 @@ impl fmt::Display for StopTrainingJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopTrainingJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopTrainingJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopTrainingJobError {}
 /// Errors returned by StopTransformJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopTransformJobError {
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopTransformJobError {
@@ -12052,10 +12846,13 @@ impl StopTransformJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopTransformJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopTransformJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopTransformJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12066,20 +12863,25 @@ impl fmt::Display for StopTransformJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StopTransformJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopTransformJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopTransformJobError {}
 /// Errors returned by UpdateCodeRepository
 #[derive(Debug, PartialEq)]
-pub enum UpdateCodeRepositoryError {}
+#[non_exhaustive]
+pub enum UpdateCodeRepositoryError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UpdateCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateCodeRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12088,12 +12890,15 @@ impl UpdateCodeRepositoryError {
 impl fmt::Display for UpdateCodeRepositoryError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UpdateCodeRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateCodeRepositoryError {}
 /// Errors returned by UpdateDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDomainError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
@@ -12101,6 +12906,8 @@ pub enum UpdateDomainError {
     ResourceLimitExceeded(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDomainError {
@@ -12108,16 +12915,19 @@ impl UpdateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(UpdateDomainError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdateDomainError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateDomainError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateDomainError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12130,15 +12940,19 @@ impl fmt::Display for UpdateDomainError {
             UpdateDomainError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateDomainError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDomainError {}
 /// Errors returned by UpdateEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEndpointError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEndpointError {
@@ -12146,12 +12960,13 @@ impl UpdateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEndpointError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12162,15 +12977,19 @@ impl fmt::Display for UpdateEndpointError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateEndpointError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            UpdateEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateEndpointError {}
 /// Errors returned by UpdateEndpointWeightsAndCapacities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEndpointWeightsAndCapacitiesError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEndpointWeightsAndCapacitiesError {
@@ -12180,12 +12999,18 @@ impl UpdateEndpointWeightsAndCapacitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateEndpointWeightsAndCapacitiesError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateEndpointWeightsAndCapacitiesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -12198,17 +13023,23 @@ impl fmt::Display for UpdateEndpointWeightsAndCapacitiesError {
             UpdateEndpointWeightsAndCapacitiesError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateEndpointWeightsAndCapacitiesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateEndpointWeightsAndCapacitiesError {}
 /// Errors returned by UpdateExperiment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateExperimentError {
     /// <p>There was a conflict when you attempted to modify an experiment, trial, or trial component.</p>
     Conflict(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateExperimentError {
@@ -12216,13 +13047,16 @@ impl UpdateExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateExperimentError::Conflict(err.msg))
+                    return RusotoError::service(UpdateExperimentError::Conflict(err.msg), &res)
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateExperimentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateExperimentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateExperimentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12234,17 +13068,21 @@ impl fmt::Display for UpdateExperimentError {
         match *self {
             UpdateExperimentError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateExperimentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateExperimentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateExperimentError {}
 /// Errors returned by UpdateMonitoringSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMonitoringScheduleError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMonitoringScheduleError {
@@ -12252,17 +13090,19 @@ impl UpdateMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateMonitoringScheduleError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMonitoringScheduleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMonitoringScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12276,15 +13116,19 @@ impl fmt::Display for UpdateMonitoringScheduleError {
                 write!(f, "{}", cause)
             }
             UpdateMonitoringScheduleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateMonitoringScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMonitoringScheduleError {}
 /// Errors returned by UpdateNotebookInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNotebookInstanceError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNotebookInstanceError {
@@ -12292,12 +13136,13 @@ impl UpdateNotebookInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateNotebookInstanceError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateNotebookInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12308,15 +13153,19 @@ impl fmt::Display for UpdateNotebookInstanceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateNotebookInstanceError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            UpdateNotebookInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateNotebookInstanceError {}
 /// Errors returned by UpdateNotebookInstanceLifecycleConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNotebookInstanceLifecycleConfigError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNotebookInstanceLifecycleConfigError {
@@ -12326,12 +13175,18 @@ impl UpdateNotebookInstanceLifecycleConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateNotebookInstanceLifecycleConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -12344,17 +13199,23 @@ impl fmt::Display for UpdateNotebookInstanceLifecycleConfigError {
             UpdateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateNotebookInstanceLifecycleConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateNotebookInstanceLifecycleConfigError {}
 /// Errors returned by UpdateTrial
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTrialError {
     /// <p>There was a conflict when you attempted to modify an experiment, trial, or trial component.</p>
     Conflict(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTrialError {
@@ -12362,13 +13223,13 @@ impl UpdateTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTrialError::Conflict(err.msg))
+                    return RusotoError::service(UpdateTrialError::Conflict(err.msg), &res)
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateTrialError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTrialError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12380,17 +13241,21 @@ impl fmt::Display for UpdateTrialError {
         match *self {
             UpdateTrialError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateTrialError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateTrialError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTrialError {}
 /// Errors returned by UpdateTrialComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTrialComponentError {
     /// <p>There was a conflict when you attempted to modify an experiment, trial, or trial component.</p>
     Conflict(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTrialComponentError {
@@ -12398,15 +13263,16 @@ impl UpdateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTrialComponentError::Conflict(err.msg))
+                    return RusotoError::service(UpdateTrialComponentError::Conflict(err.msg), &res)
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTrialComponentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTrialComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12418,12 +13284,14 @@ impl fmt::Display for UpdateTrialComponentError {
         match *self {
             UpdateTrialComponentError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateTrialComponentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateTrialComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTrialComponentError {}
 /// Errors returned by UpdateUserProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserProfileError {
     /// <p>Resource being accessed is in use.</p>
     ResourceInUse(String),
@@ -12431,6 +13299,8 @@ pub enum UpdateUserProfileError {
     ResourceLimitExceeded(String),
     /// <p>Resource being access is not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserProfileError {
@@ -12438,18 +13308,25 @@ impl UpdateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateUserProfileError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserProfileError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateUserProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12462,20 +13339,25 @@ impl fmt::Display for UpdateUserProfileError {
             UpdateUserProfileError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateUserProfileError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateUserProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserProfileError {}
 /// Errors returned by UpdateWorkforce
 #[derive(Debug, PartialEq)]
-pub enum UpdateWorkforceError {}
+#[non_exhaustive]
+pub enum UpdateWorkforceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UpdateWorkforceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateWorkforceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateWorkforceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12484,15 +13366,20 @@ impl UpdateWorkforceError {
 impl fmt::Display for UpdateWorkforceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UpdateWorkforceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateWorkforceError {}
 /// Errors returned by UpdateWorkteam
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateWorkteamError {
     /// <p> You have exceeded an Amazon SageMaker resource limit. For example, you might have too many training jobs created. </p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateWorkteamError {
@@ -12500,12 +13387,13 @@ impl UpdateWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateWorkteamError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateWorkteamError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateWorkteamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12516,6 +13404,7 @@ impl fmt::Display for UpdateWorkteamError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateWorkteamError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            UpdateWorkteamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -13356,6 +14245,7 @@ impl SageMakerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SageMakerClient {
         SageMakerClient {
             client: Client::shared(),
@@ -13381,547 +14271,1119 @@ impl SageMakerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SageMakerClient {
         SageMakerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SageMakerClient> {
+        rusoto_core::ClientBuilder::new(SageMakerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SageMakerClient {
+        SageMakerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SageMaker for SageMakerClient {
-    /// <p><p>Adds or overwrites one or more tags for the specified Amazon SageMaker resource. You can add tags to notebook instances, training jobs, hyperparameter tuning jobs, batch transform jobs, models, labeling jobs, work teams, endpoint configurations, and endpoints.</p> <p>Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see For more information, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>.</p> <note> <p>Tags that you add to a hyperparameter tuning job by calling this API are also added to any training jobs that the hyperparameter tuning job launches after you call this API, but not to training jobs that the hyperparameter tuning job launched before you called this API. To make sure that the tags associated with a hyperparameter tuning job are also added to all training jobs that the hyperparameter tuning job launches, add the tags when you first create the tuning job by specifying them in the <code>Tags</code> parameter of <a>CreateHyperParameterTuningJob</a> </p> </note></p>
-    async fn add_tags(
+impl SageMakerClient {
+    /// Calls `add_tags`, also returning the metadata of the response.
+    pub async fn add_tags_with_metadata(
         &self,
         input: AddTagsInput,
-    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+    ) -> Result<(AddTagsOutput, ResponseMetadata), RusotoError<AddTagsError>> {
+        let request = self.build_add_tags_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_request(&self, input: AddTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("AddTags");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_trial_component`, also returning the metadata of the response.
+    pub async fn associate_trial_component_with_metadata(
+        &self,
+        input: AssociateTrialComponentRequest,
+    ) -> Result<
+        (AssociateTrialComponentResponse, ResponseMetadata),
+        RusotoError<AssociateTrialComponentError>,
+    > {
+        let request = self.build_associate_trial_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateTrialComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateTrialComponentError::from_response(response))
         }
     }
 
-    /// <p>Associates a trial component with a trial. A trial component can be associated with multiple trials. To disassociate a trial component from a trial, call the <a>DisassociateTrialComponent</a> API.</p>
-    async fn associate_trial_component(
+    /// Presigns the request `associate_trial_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_trial_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateTrialComponentRequest,
-    ) -> Result<AssociateTrialComponentResponse, RusotoError<AssociateTrialComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_trial_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_trial_component_request(
+        &self,
+        input: AssociateTrialComponentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("AssociateTrialComponent");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.AssociateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_algorithm`, also returning the metadata of the response.
+    pub async fn create_algorithm_with_metadata(
+        &self,
+        input: CreateAlgorithmInput,
+    ) -> Result<(CreateAlgorithmOutput, ResponseMetadata), RusotoError<CreateAlgorithmError>> {
+        let request = self.build_create_algorithm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateTrialComponentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAlgorithmOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateTrialComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAlgorithmError::from_response(response))
         }
     }
 
-    /// <p>Create a machine learning algorithm that you can use in Amazon SageMaker and list in the AWS Marketplace.</p>
-    async fn create_algorithm(
+    /// Presigns the request `create_algorithm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_algorithm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAlgorithmInput,
-    ) -> Result<CreateAlgorithmOutput, RusotoError<CreateAlgorithmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_algorithm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_algorithm_request(&self, input: CreateAlgorithmInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateAlgorithm");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_app`, also returning the metadata of the response.
+    pub async fn create_app_with_metadata(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<(CreateAppResponse, ResponseMetadata), RusotoError<CreateAppError>> {
+        let request = self.build_create_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAlgorithmOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAppResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAlgorithmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAppError::from_response(response))
         }
     }
 
-    /// <p>Creates a running App for the specified UserProfile. Supported Apps are JupyterServer and KernelGateway. This operation is automatically invoked by Amazon SageMaker Amazon SageMaker Studio (Studio) upon access to the associated Studio Domain, and when new kernel configurations are selected by the user. A user may have multiple Apps active simultaneously. Apps will automatically terminate and be deleted when stopped from within Studio, or when the DeleteApp API is manually called. UserProfiles are limited to 5 concurrently running Apps at a time.</p>
-    async fn create_app(
+    /// Presigns the request `create_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAppRequest,
-    ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_app_request(&self, input: CreateAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateApp");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_auto_ml_job`, also returning the metadata of the response.
+    pub async fn create_auto_ml_job_with_metadata(
+        &self,
+        input: CreateAutoMLJobRequest,
+    ) -> Result<(CreateAutoMLJobResponse, ResponseMetadata), RusotoError<CreateAutoMLJobError>>
+    {
+        let request = self.build_create_auto_ml_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAutoMLJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAutoMLJobError::from_response(response))
         }
     }
 
-    /// <p>Creates an AutoPilot job.</p>
-    async fn create_auto_ml_job(
+    /// Presigns the request `create_auto_ml_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_auto_ml_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAutoMLJobRequest,
-    ) -> Result<CreateAutoMLJobResponse, RusotoError<CreateAutoMLJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_auto_ml_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_auto_ml_job_request(&self, input: CreateAutoMLJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateAutoMLJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_code_repository`, also returning the metadata of the response.
+    pub async fn create_code_repository_with_metadata(
+        &self,
+        input: CreateCodeRepositoryInput,
+    ) -> Result<
+        (CreateCodeRepositoryOutput, ResponseMetadata),
+        RusotoError<CreateCodeRepositoryError>,
+    > {
+        let request = self.build_create_code_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAutoMLJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCodeRepositoryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAutoMLJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCodeRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Creates a Git repository as a resource in your Amazon SageMaker account. You can associate the repository with notebook instances so that you can use Git source control for the notebooks you create. The Git repository is a resource in your Amazon SageMaker account, so it can be associated with more than one notebook instance, and it persists independently from the lifecycle of any notebook instances it is associated with.</p> <p>The repository can be hosted either in <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/welcome.html">AWS CodeCommit</a> or in any other Git repository.</p>
-    async fn create_code_repository(
+    /// Presigns the request `create_code_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_code_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCodeRepositoryInput,
-    ) -> Result<CreateCodeRepositoryOutput, RusotoError<CreateCodeRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_code_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_code_repository_request(
+        &self,
+        input: CreateCodeRepositoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateCodeRepository");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_compilation_job`, also returning the metadata of the response.
+    pub async fn create_compilation_job_with_metadata(
+        &self,
+        input: CreateCompilationJobRequest,
+    ) -> Result<
+        (CreateCompilationJobResponse, ResponseMetadata),
+        RusotoError<CreateCompilationJobError>,
+    > {
+        let request = self.build_create_compilation_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCodeRepositoryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCompilationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCodeRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCompilationJobError::from_response(response))
         }
     }
 
-    /// <p>Starts a model compilation job. After the model has been compiled, Amazon SageMaker saves the resulting model artifacts to an Amazon Simple Storage Service (Amazon S3) bucket that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts with AWS IoT Greengrass. In that case, deploy them as an ML resource.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p>A name for the compilation job</p> </li> <li> <p> Information about the input model artifacts </p> </li> <li> <p>The output location for the compiled model and the device (target) that the model runs on </p> </li> <li> <p> <code>The Amazon Resource Name (ARN) of the IAM role that Amazon SageMaker assumes to perform the model compilation job</code> </p> </li> </ul> <p>You can also provide a <code>Tag</code> to track the model compilation job's resource use and costs. The response body contains the <code>CompilationJobArn</code> for the compiled job.</p> <p>To stop a model compilation job, use <a>StopCompilationJob</a>. To get information about a particular model compilation job, use <a>DescribeCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
-    async fn create_compilation_job(
+    /// Presigns the request `create_compilation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_compilation_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCompilationJobRequest,
-    ) -> Result<CreateCompilationJobResponse, RusotoError<CreateCompilationJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_compilation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_compilation_job_request(
+        &self,
+        input: CreateCompilationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateCompilationJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateCompilationJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_domain`, also returning the metadata of the response.
+    pub async fn create_domain_with_metadata(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<(CreateDomainResponse, ResponseMetadata), RusotoError<CreateDomainError>> {
+        let request = self.build_create_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCompilationJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCompilationJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDomainError::from_response(response))
         }
     }
 
-    /// <p>Creates a Domain for Amazon SageMaker Amazon SageMaker Studio (Studio), which can be accessed by end-users in a web browser. A Domain has an associated directory, list of authorized users, and a variety of security, application, policies, and Amazon Virtual Private Cloud configurations. An AWS account is limited to one Domain, per region. Users within a domain can share notebook files and other artifacts with each other. When a Domain is created, an Amazon Elastic File System (EFS) is also created for use by all of the users within the Domain. Each user receives a private home directory within the EFS for notebooks, Git repositories, and data files. </p>
-    async fn create_domain(
+    /// Presigns the request `create_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDomainRequest,
-    ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_domain_request(&self, input: CreateDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateDomain");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_endpoint`, also returning the metadata of the response.
+    pub async fn create_endpoint_with_metadata(
+        &self,
+        input: CreateEndpointInput,
+    ) -> Result<(CreateEndpointOutput, ResponseMetadata), RusotoError<CreateEndpointError>> {
+        let request = self.build_create_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEndpointOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEndpointError::from_response(response))
         }
     }
 
-    /// <p>Creates an endpoint using the endpoint configuration specified in the request. Amazon SageMaker uses the endpoint to provision resources and deploy models. You create the endpoint configuration with the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpointConfig.html">CreateEndpointConfig</a> API. </p> <note> <p> Use this API only for hosting models using Amazon SageMaker hosting services. </p> <p> You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> </note> <p>The endpoint name must be unique within an AWS Region in your AWS account. </p> <p>When it receives the request, Amazon SageMaker creates the endpoint, launches the resources (ML compute instances), and deploys the model(s) on them. </p> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Creating</code>. After it creates the endpoint, it sets the status to <code>InService</code>. Amazon SageMaker can then process incoming requests for inferences. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API.</p> <p>For an example, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/ex1.html">Exercise 1: Using the K-Means Algorithm Provided by Amazon SageMaker</a>. </p> <p>If any of the models hosted at this endpoint get model data from an Amazon S3 location, Amazon SageMaker uses AWS Security Token Service to download model artifacts from the S3 path you provided. AWS STS is activated in your IAM user account by default. If you previously deactivated AWS STS for a region, you need to reactivate AWS STS for that region. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide</i>.</p>
-    async fn create_endpoint(
+    /// Presigns the request `create_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEndpointInput,
-    ) -> Result<CreateEndpointOutput, RusotoError<CreateEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_endpoint_request(&self, input: CreateEndpointInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateEndpoint");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_endpoint_config`, also returning the metadata of the response.
+    pub async fn create_endpoint_config_with_metadata(
+        &self,
+        input: CreateEndpointConfigInput,
+    ) -> Result<
+        (CreateEndpointConfigOutput, ResponseMetadata),
+        RusotoError<CreateEndpointConfigError>,
+    > {
+        let request = self.build_create_endpoint_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateEndpointOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEndpointConfigOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEndpointConfigError::from_response(response))
         }
     }
 
-    /// <p>Creates an endpoint configuration that Amazon SageMaker hosting services uses to deploy models. In the configuration, you identify one or more models, created using the <code>CreateModel</code> API, to deploy and the resources that you want Amazon SageMaker to provision. Then you call the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpoint.html">CreateEndpoint</a> API.</p> <note> <p> Use this API only if you want to use Amazon SageMaker hosting services to deploy models into production. </p> </note> <p>In the request, you define one or more <code>ProductionVariant</code>s, each of which identifies a model. Each <code>ProductionVariant</code> parameter also describes the resources that you want Amazon SageMaker to provision. This includes the number and type of ML compute instances to deploy. </p> <p>If you are hosting multiple models, you also assign a <code>VariantWeight</code> to specify how much traffic you want to allocate to each model. For example, suppose that you want to host two models, A and B, and you assign traffic weight 2 for model A and 1 for model B. Amazon SageMaker distributes two-thirds of the traffic to Model A, and one-third to model B. </p>
-    async fn create_endpoint_config(
+    /// Presigns the request `create_endpoint_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_endpoint_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEndpointConfigInput,
-    ) -> Result<CreateEndpointConfigOutput, RusotoError<CreateEndpointConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_endpoint_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_endpoint_config_request(
+        &self,
+        input: CreateEndpointConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateEndpointConfig");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateEndpointConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_experiment`, also returning the metadata of the response.
+    pub async fn create_experiment_with_metadata(
+        &self,
+        input: CreateExperimentRequest,
+    ) -> Result<(CreateExperimentResponse, ResponseMetadata), RusotoError<CreateExperimentError>>
+    {
+        let request = self.build_create_experiment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateEndpointConfigOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateExperimentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEndpointConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateExperimentError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon SageMaker <i>experiment</i>. An experiment is a collection of <i>trials</i> that are observed, compared and evaluated as a group. A trial is a set of steps, called <i>trial components</i>, that produce a machine learning model.</p> <p>The goal of an experiment is to determine the components that produce the best model. Multiple trials are performed, each one isolating and measuring the impact of a change to one or more inputs, while keeping the remaining inputs constant.</p> <p>When you use Amazon SageMaker Studio or the Amazon SageMaker Python SDK, all experiments, trials, and trial components are automatically tracked, logged, and indexed. When you use the AWS SDK for Python (Boto), you must use the logging APIs provided by the SDK.</p> <p>You can add tags to experiments, trials, trial components and then use the <a>Search</a> API to search for the tags.</p> <p>To add a description to an experiment, specify the optional <code>Description</code> parameter. To add a description later, or to change the description, call the <a>UpdateExperiment</a> API.</p> <p>To get a list of all your experiments, call the <a>ListExperiments</a> API. To view an experiment's properties, call the <a>DescribeExperiment</a> API. To get a list of all the trials associated with an experiment, call the <a>ListTrials</a> API. To create a trial call the <a>CreateTrial</a> API.</p>
-    async fn create_experiment(
+    /// Presigns the request `create_experiment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_experiment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateExperimentRequest,
-    ) -> Result<CreateExperimentResponse, RusotoError<CreateExperimentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_experiment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_experiment_request(&self, input: CreateExperimentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateExperiment");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_flow_definition`, also returning the metadata of the response.
+    pub async fn create_flow_definition_with_metadata(
+        &self,
+        input: CreateFlowDefinitionRequest,
+    ) -> Result<
+        (CreateFlowDefinitionResponse, ResponseMetadata),
+        RusotoError<CreateFlowDefinitionError>,
+    > {
+        let request = self.build_create_flow_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateExperimentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateFlowDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateExperimentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateFlowDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a flow definition.</p>
-    async fn create_flow_definition(
+    /// Presigns the request `create_flow_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_flow_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFlowDefinitionRequest,
-    ) -> Result<CreateFlowDefinitionResponse, RusotoError<CreateFlowDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_flow_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_flow_definition_request(
+        &self,
+        input: CreateFlowDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateFlowDefinition");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateFlowDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_human_task_ui`, also returning the metadata of the response.
+    pub async fn create_human_task_ui_with_metadata(
+        &self,
+        input: CreateHumanTaskUiRequest,
+    ) -> Result<(CreateHumanTaskUiResponse, ResponseMetadata), RusotoError<CreateHumanTaskUiError>>
+    {
+        let request = self.build_create_human_task_ui_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateFlowDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHumanTaskUiResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFlowDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHumanTaskUiError::from_response(response))
         }
     }
 
-    /// <p>Defines the settings you will use for the human review workflow user interface. Reviewers will see a three-panel interface with an instruction area, the item to review, and an input area.</p>
-    async fn create_human_task_ui(
+    /// Presigns the request `create_human_task_ui` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_human_task_ui(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateHumanTaskUiRequest,
-    ) -> Result<CreateHumanTaskUiResponse, RusotoError<CreateHumanTaskUiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_human_task_ui_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_human_task_ui_request(&self, input: CreateHumanTaskUiRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateHumanTaskUi");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateHumanTaskUi");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_hyper_parameter_tuning_job`, also returning the metadata of the response.
+    pub async fn create_hyper_parameter_tuning_job_with_metadata(
+        &self,
+        input: CreateHyperParameterTuningJobRequest,
+    ) -> Result<
+        (CreateHyperParameterTuningJobResponse, ResponseMetadata),
+        RusotoError<CreateHyperParameterTuningJobError>,
+    > {
+        let request = self.build_create_hyper_parameter_tuning_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateHumanTaskUiResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHyperParameterTuningJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHumanTaskUiError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHyperParameterTuningJobError::from_response(response))
         }
     }
 
-    /// <p>Starts a hyperparameter tuning job. A hyperparameter tuning job finds the best version of a model by running many training jobs on your dataset using the algorithm you choose and values for hyperparameters within ranges that you specify. It then chooses the hyperparameter values that result in a model that performs the best, as measured by an objective metric that you choose.</p>
-    async fn create_hyper_parameter_tuning_job(
+    /// Presigns the request `create_hyper_parameter_tuning_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hyper_parameter_tuning_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateHyperParameterTuningJobRequest,
-    ) -> Result<
-        CreateHyperParameterTuningJobResponse,
-        RusotoError<CreateHyperParameterTuningJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hyper_parameter_tuning_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_hyper_parameter_tuning_job_request(
+        &self,
+        input: CreateHyperParameterTuningJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateHyperParameterTuningJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateHyperParameterTuningJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_labeling_job`, also returning the metadata of the response.
+    pub async fn create_labeling_job_with_metadata(
+        &self,
+        input: CreateLabelingJobRequest,
+    ) -> Result<(CreateLabelingJobResponse, ResponseMetadata), RusotoError<CreateLabelingJobError>>
+    {
+        let request = self.build_create_labeling_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateHyperParameterTuningJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLabelingJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHyperParameterTuningJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLabelingJobError::from_response(response))
         }
     }
 
-    /// <p>Creates a job that uses workers to label the data objects in your input dataset. You can use the labeled data to train machine learning models.</p> <p>You can select your workforce from one of three providers:</p> <ul> <li> <p>A private workforce that you create. It can include employees, contractors, and outside experts. Use a private workforce when want the data to stay within your organization or when a specific set of skills is required.</p> </li> <li> <p>One or more vendors that you select from the AWS Marketplace. Vendors provide expertise in specific areas. </p> </li> <li> <p>The Amazon Mechanical Turk workforce. This is the largest workforce, but it should only be used for public data or data that has been stripped of any personally identifiable information.</p> </li> </ul> <p>You can also use <i>automated data labeling</i> to reduce the number of data objects that need to be labeled by a human. Automated data labeling uses <i>active learning</i> to determine if a data object can be labeled by machine or if it needs to be sent to a human worker. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-automated-labeling.html">Using Automated Data Labeling</a>.</p> <p>The data objects to be labeled are contained in an Amazon S3 bucket. You create a <i>manifest file</i> that describes the location of each object. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-data.html">Using Input and Output Data</a>.</p> <p>The output can be used as the manifest file for another labeling job or as training data for your machine learning models.</p>
-    async fn create_labeling_job(
+    /// Presigns the request `create_labeling_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_labeling_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLabelingJobRequest,
-    ) -> Result<CreateLabelingJobResponse, RusotoError<CreateLabelingJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_labeling_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_labeling_job_request(&self, input: CreateLabelingJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateLabelingJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateLabelingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_model`, also returning the metadata of the response.
+    pub async fn create_model_with_metadata(
+        &self,
+        input: CreateModelInput,
+    ) -> Result<(CreateModelOutput, ResponseMetadata), RusotoError<CreateModelError>> {
+        let request = self.build_create_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLabelingJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateModelOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLabelingJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateModelError::from_response(response))
         }
     }
 
-    /// <p>Creates a model in Amazon SageMaker. In the request, you name the model and describe a primary container. For the primary container, you specify the docker image containing inference code, artifacts (from prior training), and custom environment map that the inference code uses when you deploy the model for predictions.</p> <p>Use this API to create a model if you want to use Amazon SageMaker hosting services or run a batch transform job.</p> <p>To host your model, you create an endpoint configuration with the <code>CreateEndpointConfig</code> API, and then create an endpoint with the <code>CreateEndpoint</code> API. Amazon SageMaker then deploys all of the containers that you defined for the model in the hosting environment. </p> <p>To run a batch transform using your model, you start a job with the <code>CreateTransformJob</code> API. Amazon SageMaker uses your model and your dataset to get inferences which are then saved to a specified S3 location.</p> <p>In the <code>CreateModel</code> request, you must define a container with the <code>PrimaryContainer</code> parameter.</p> <p>In the request, you also provide an IAM role that Amazon SageMaker can assume to access model artifacts and docker image for deployment on ML compute hosting instances or for batch transform jobs. In addition, you also use the IAM role to manage permissions the inference code needs. For example, if the inference code access any other AWS resources, you grant necessary permissions via this role.</p>
-    async fn create_model(
+    /// Presigns the request `create_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateModelInput,
-    ) -> Result<CreateModelOutput, RusotoError<CreateModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_model_request(&self, input: CreateModelInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateModel");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_model_package`, also returning the metadata of the response.
+    pub async fn create_model_package_with_metadata(
+        &self,
+        input: CreateModelPackageInput,
+    ) -> Result<(CreateModelPackageOutput, ResponseMetadata), RusotoError<CreateModelPackageError>>
+    {
+        let request = self.build_create_model_package_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateModelOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateModelPackageOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateModelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateModelPackageError::from_response(response))
         }
     }
 
-    /// <p>Creates a model package that you can use to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p> <p>To create a model package by specifying a Docker container that contains your inference code and the Amazon S3 location of your model artifacts, provide values for <code>InferenceSpecification</code>. To create a model from an algorithm resource that you created or subscribed to in AWS Marketplace, provide a value for <code>SourceAlgorithmSpecification</code>.</p>
-    async fn create_model_package(
+    /// Presigns the request `create_model_package` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_model_package(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateModelPackageInput,
-    ) -> Result<CreateModelPackageOutput, RusotoError<CreateModelPackageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_model_package_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_model_package_request(&self, input: CreateModelPackageInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateModelPackage");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_monitoring_schedule`, also returning the metadata of the response.
+    pub async fn create_monitoring_schedule_with_metadata(
+        &self,
+        input: CreateMonitoringScheduleRequest,
+    ) -> Result<
+        (CreateMonitoringScheduleResponse, ResponseMetadata),
+        RusotoError<CreateMonitoringScheduleError>,
+    > {
+        let request = self.build_create_monitoring_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateModelPackageOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateMonitoringScheduleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateModelPackageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateMonitoringScheduleError::from_response(response))
         }
     }
 
-    /// <p>Creates a schedule that regularly starts Amazon SageMaker Processing Jobs to monitor the data captured for an Amazon SageMaker Endoint.</p>
-    async fn create_monitoring_schedule(
+    /// Presigns the request `create_monitoring_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_monitoring_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMonitoringScheduleRequest,
-    ) -> Result<CreateMonitoringScheduleResponse, RusotoError<CreateMonitoringScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_monitoring_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_monitoring_schedule_request(
+        &self,
+        input: CreateMonitoringScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateMonitoringSchedule");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_notebook_instance`, also returning the metadata of the response.
+    pub async fn create_notebook_instance_with_metadata(
+        &self,
+        input: CreateNotebookInstanceInput,
+    ) -> Result<
+        (CreateNotebookInstanceOutput, ResponseMetadata),
+        RusotoError<CreateNotebookInstanceError>,
+    > {
+        let request = self.build_create_notebook_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMonitoringScheduleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateNotebookInstanceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMonitoringScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateNotebookInstanceError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon SageMaker notebook instance. A notebook instance is a machine learning (ML) compute instance running on a Jupyter notebook. </p> <p>In a <code>CreateNotebookInstance</code> request, specify the type of ML compute instance that you want to run. Amazon SageMaker launches the instance, installs common libraries that you can use to explore datasets for model training, and attaches an ML storage volume to the notebook instance. </p> <p>Amazon SageMaker also provides a set of example notebooks. Each notebook demonstrates how to use Amazon SageMaker with a specific algorithm or with a machine learning framework. </p> <p>After receiving the request, Amazon SageMaker does the following:</p> <ol> <li> <p>Creates a network interface in the Amazon SageMaker VPC.</p> </li> <li> <p>(Option) If you specified <code>SubnetId</code>, Amazon SageMaker creates a network interface in your own VPC, which is inferred from the subnet ID that you provide in the input. When creating this network interface, Amazon SageMaker attaches the security group that you specified in the request to the network interface that it creates in your VPC.</p> </li> <li> <p>Launches an EC2 instance of the type specified in the request in the Amazon SageMaker VPC. If you specified <code>SubnetId</code> of your VPC, Amazon SageMaker specifies both network interfaces when launching this instance. This enables inbound traffic from your own VPC to the notebook instance, assuming that the security groups allow it.</p> </li> </ol> <p>After creating the notebook instance, Amazon SageMaker returns its Amazon Resource Name (ARN). You can't change the name of a notebook instance after you create it.</p> <p>After Amazon SageMaker creates the notebook instance, you can connect to the Jupyter server and work in Jupyter notebooks. For example, you can write code to explore a dataset that you can use for model training, train a model, host models by creating Amazon SageMaker endpoints, and validate hosted models. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
-    async fn create_notebook_instance(
+    /// Presigns the request `create_notebook_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_notebook_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNotebookInstanceInput,
-    ) -> Result<CreateNotebookInstanceOutput, RusotoError<CreateNotebookInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_notebook_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_notebook_instance_request(
+        &self,
+        input: CreateNotebookInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateNotebookInstance");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_notebook_instance_lifecycle_config`, also returning the metadata of the response.
+    pub async fn create_notebook_instance_lifecycle_config_with_metadata(
+        &self,
+        input: CreateNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        (
+            CreateNotebookInstanceLifecycleConfigOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateNotebookInstanceLifecycleConfigError>,
+    > {
+        let request = self.build_create_notebook_instance_lifecycle_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateNotebookInstanceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateNotebookInstanceLifecycleConfigOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNotebookInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateNotebookInstanceLifecycleConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Creates a lifecycle configuration that you can associate with a notebook instance. A <i>lifecycle configuration</i> is a collection of shell scripts that run when you create or start a notebook instance.</p> <p>Each lifecycle configuration script has a limit of 16384 characters.</p> <p>The value of the <code>$PATH</code> environment variable that is available to both scripts is <code>/sbin:bin:/usr/sbin:/usr/bin</code>.</p> <p>View CloudWatch Logs for notebook instance lifecycle configurations in log group <code>/aws/sagemaker/NotebookInstances</code> in log stream <code>[notebook-instance-name]/[LifecycleConfigHook]</code>.</p> <p>Lifecycle configuration scripts cannot run for longer than 5 minutes. If a script runs for longer than 5 minutes, it fails and the notebook instance is not created or started.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
-    async fn create_notebook_instance_lifecycle_config(
+    /// Presigns the request `create_notebook_instance_lifecycle_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_notebook_instance_lifecycle_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNotebookInstanceLifecycleConfigInput,
-    ) -> Result<
-        CreateNotebookInstanceLifecycleConfigOutput,
-        RusotoError<CreateNotebookInstanceLifecycleConfigError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_notebook_instance_lifecycle_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_notebook_instance_lifecycle_config_request(
+        &self,
+        input: CreateNotebookInstanceLifecycleConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateNotebookInstanceLifecycleConfig");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13931,61 +15393,118 @@ impl SageMaker for SageMakerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_presigned_domain_url`, also returning the metadata of the response.
+    pub async fn create_presigned_domain_url_with_metadata(
+        &self,
+        input: CreatePresignedDomainUrlRequest,
+    ) -> Result<
+        (CreatePresignedDomainUrlResponse, ResponseMetadata),
+        RusotoError<CreatePresignedDomainUrlError>,
+    > {
+        let request = self.build_create_presigned_domain_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateNotebookInstanceLifecycleConfigOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePresignedDomainUrlResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNotebookInstanceLifecycleConfigError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePresignedDomainUrlError::from_response(response))
         }
     }
 
-    /// <p>Creates a URL for a specified UserProfile in a Domain. When accessed in a web browser, the user will be automatically signed in to Amazon SageMaker Amazon SageMaker Studio (Studio), and granted access to all of the Apps and files associated with that Amazon Elastic File System (EFS). This operation can only be called when AuthMode equals IAM. </p>
-    async fn create_presigned_domain_url(
+    /// Presigns the request `create_presigned_domain_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_presigned_domain_url(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePresignedDomainUrlRequest,
-    ) -> Result<CreatePresignedDomainUrlResponse, RusotoError<CreatePresignedDomainUrlError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_presigned_domain_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_presigned_domain_url_request(
+        &self,
+        input: CreatePresignedDomainUrlRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreatePresignedDomainUrl");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreatePresignedDomainUrl");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_presigned_notebook_instance_url`, also returning the metadata of the response.
+    pub async fn create_presigned_notebook_instance_url_with_metadata(
+        &self,
+        input: CreatePresignedNotebookInstanceUrlInput,
+    ) -> Result<
+        (CreatePresignedNotebookInstanceUrlOutput, ResponseMetadata),
+        RusotoError<CreatePresignedNotebookInstanceUrlError>,
+    > {
+        let request = self.build_create_presigned_notebook_instance_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePresignedDomainUrlResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePresignedNotebookInstanceUrlOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePresignedDomainUrlError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePresignedNotebookInstanceUrlError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Returns a URL that you can use to connect to the Jupyter server from a notebook instance. In the Amazon SageMaker console, when you choose <code>Open</code> next to a notebook instance, Amazon SageMaker opens a new tab showing the Jupyter server home page from the notebook instance. The console uses this API to get the URL and show the page.</p> <p>IAM authorization policies for this API are also enforced for every HTTP request and WebSocket frame that attempts to connect to the notebook instance.For example, you can restrict access to this API and to the URL that it returns to a list of IP addresses that you specify. Use the <code>NotIpAddress</code> condition operator and the <code>aws:SourceIP</code> condition context key to specify the list of IP addresses that you want to have access to the notebook instance. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/security_iam_id-based-policy-examples.html#nbi-ip-filter">Limit Access to a Notebook Instance by IP Address</a>.</p> <note> <p>The URL that you get from a call to is valid only for 5 minutes. If you try to use the URL after the 5-minute limit expires, you are directed to the AWS console sign-in page.</p> </note></p>
-    async fn create_presigned_notebook_instance_url(
+    /// Presigns the request `create_presigned_notebook_instance_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_presigned_notebook_instance_url(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePresignedNotebookInstanceUrlInput,
-    ) -> Result<
-        CreatePresignedNotebookInstanceUrlOutput,
-        RusotoError<CreatePresignedNotebookInstanceUrlError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_presigned_notebook_instance_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_presigned_notebook_instance_url_request(
+        &self,
+        input: CreatePresignedNotebookInstanceUrlInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreatePresignedNotebookInstanceUrl");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13995,547 +15514,1028 @@ impl SageMaker for SageMakerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_processing_job`, also returning the metadata of the response.
+    pub async fn create_processing_job_with_metadata(
+        &self,
+        input: CreateProcessingJobRequest,
+    ) -> Result<
+        (CreateProcessingJobResponse, ResponseMetadata),
+        RusotoError<CreateProcessingJobError>,
+    > {
+        let request = self.build_create_processing_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePresignedNotebookInstanceUrlOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProcessingJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePresignedNotebookInstanceUrlError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProcessingJobError::from_response(response))
         }
     }
 
-    /// <p>Creates a processing job.</p>
-    async fn create_processing_job(
+    /// Presigns the request `create_processing_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_processing_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProcessingJobRequest,
-    ) -> Result<CreateProcessingJobResponse, RusotoError<CreateProcessingJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_processing_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_processing_job_request(
+        &self,
+        input: CreateProcessingJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateProcessingJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateProcessingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_training_job`, also returning the metadata of the response.
+    pub async fn create_training_job_with_metadata(
+        &self,
+        input: CreateTrainingJobRequest,
+    ) -> Result<(CreateTrainingJobResponse, ResponseMetadata), RusotoError<CreateTrainingJobError>>
+    {
+        let request = self.build_create_training_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProcessingJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTrainingJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProcessingJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTrainingJobError::from_response(response))
         }
     }
 
-    /// <p>Starts a model training job. After training completes, Amazon SageMaker saves the resulting model artifacts to an Amazon S3 location that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts in a machine learning service other than Amazon SageMaker, provided that you know how to use them for inferences. </p> <p>In the request body, you provide the following: </p> <ul> <li> <p> <code>AlgorithmSpecification</code> - Identifies the training algorithm to use. </p> </li> <li> <p> <code>HyperParameters</code> - Specify these algorithm-specific parameters to enable the estimation of model parameters during training. Hyperparameters can be tuned to optimize this learning process. For a list of hyperparameters for each training algorithm provided by Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/algos.html">Algorithms</a>. </p> </li> <li> <p> <code>InputDataConfig</code> - Describes the training dataset and the Amazon S3, EFS, or FSx location where it is stored.</p> </li> <li> <p> <code>OutputDataConfig</code> - Identifies the Amazon S3 bucket where you want Amazon SageMaker to save the results of model training. </p> <p/> </li> <li> <p> <code>ResourceConfig</code> - Identifies the resources, ML compute instances, and ML storage volumes to deploy for model training. In distributed training, you specify more than one instance. </p> </li> <li> <p> <code>EnableManagedSpotTraining</code> - Optimize the cost of training machine learning models by up to 80% by using Amazon EC2 Spot instances. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/model-managed-spot-training.html">Managed Spot Training</a>. </p> </li> <li> <p> <code>RoleARN</code> - The Amazon Resource Number (ARN) that Amazon SageMaker assumes to perform tasks on your behalf during model training. You must grant this role the necessary permissions so that Amazon SageMaker can successfully complete model training. </p> </li> <li> <p> <code>StoppingCondition</code> - To help cap training costs, use <code>MaxRuntimeInSeconds</code> to set a time limit for training. Use <code>MaxWaitTimeInSeconds</code> to specify how long you are willing to wait for a managed spot training job to complete. </p> </li> </ul> <p> For more information about Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
-    async fn create_training_job(
+    /// Presigns the request `create_training_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_training_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTrainingJobRequest,
-    ) -> Result<CreateTrainingJobResponse, RusotoError<CreateTrainingJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_training_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_training_job_request(&self, input: CreateTrainingJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateTrainingJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_transform_job`, also returning the metadata of the response.
+    pub async fn create_transform_job_with_metadata(
+        &self,
+        input: CreateTransformJobRequest,
+    ) -> Result<(CreateTransformJobResponse, ResponseMetadata), RusotoError<CreateTransformJobError>>
+    {
+        let request = self.build_create_transform_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTrainingJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTransformJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTrainingJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTransformJobError::from_response(response))
         }
     }
 
-    /// <p>Starts a transform job. A transform job uses a trained model to get inferences on a dataset and saves these results to an Amazon S3 location that you specify.</p> <p>To perform batch transformations, you create a transform job and use the data that you have readily available.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p> <code>TransformJobName</code> - Identifies the transform job. The name must be unique within an AWS Region in an AWS account.</p> </li> <li> <p> <code>ModelName</code> - Identifies the model to use. <code>ModelName</code> must be the name of an existing Amazon SageMaker model in the same AWS Region and AWS account. For information on creating a model, see <a>CreateModel</a>.</p> </li> <li> <p> <code>TransformInput</code> - Describes the dataset to be transformed and the Amazon S3 location where it is stored.</p> </li> <li> <p> <code>TransformOutput</code> - Identifies the Amazon S3 location where you want Amazon SageMaker to save the results from the transform job.</p> </li> <li> <p> <code>TransformResources</code> - Identifies the ML compute instances for the transform job.</p> </li> </ul> <p>For more information about how batch transformation works, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/batch-transform.html">Batch Transform</a>.</p>
-    async fn create_transform_job(
+    /// Presigns the request `create_transform_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_transform_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTransformJobRequest,
-    ) -> Result<CreateTransformJobResponse, RusotoError<CreateTransformJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_transform_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_transform_job_request(
+        &self,
+        input: CreateTransformJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateTransformJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateTransformJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_trial`, also returning the metadata of the response.
+    pub async fn create_trial_with_metadata(
+        &self,
+        input: CreateTrialRequest,
+    ) -> Result<(CreateTrialResponse, ResponseMetadata), RusotoError<CreateTrialError>> {
+        let request = self.build_create_trial_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTransformJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTrialResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTransformJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTrialError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon SageMaker <i>trial</i>. A trial is a set of steps called <i>trial components</i> that produce a machine learning model. A trial is part of a single Amazon SageMaker <i>experiment</i>.</p> <p>When you use Amazon SageMaker Studio or the Amazon SageMaker Python SDK, all experiments, trials, and trial components are automatically tracked, logged, and indexed. When you use the AWS SDK for Python (Boto), you must use the logging APIs provided by the SDK.</p> <p>You can add tags to a trial and then use the <a>Search</a> API to search for the tags.</p> <p>To get a list of all your trials, call the <a>ListTrials</a> API. To view a trial's properties, call the <a>DescribeTrial</a> API. To create a trial component, call the <a>CreateTrialComponent</a> API.</p>
-    async fn create_trial(
+    /// Presigns the request `create_trial` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_trial(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTrialRequest,
-    ) -> Result<CreateTrialResponse, RusotoError<CreateTrialError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_trial_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_trial_request(&self, input: CreateTrialRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateTrial");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_trial_component`, also returning the metadata of the response.
+    pub async fn create_trial_component_with_metadata(
+        &self,
+        input: CreateTrialComponentRequest,
+    ) -> Result<
+        (CreateTrialComponentResponse, ResponseMetadata),
+        RusotoError<CreateTrialComponentError>,
+    > {
+        let request = self.build_create_trial_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTrialResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTrialComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTrialError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTrialComponentError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a <i>trial component</i>, which is a stage of a machine learning <i>trial</i>. A trial is composed of one or more trial components. A trial component can be used in multiple trials.</p> <p>Trial components include pre-processing jobs, training jobs, and batch transform jobs.</p> <p>When you use Amazon SageMaker Studio or the Amazon SageMaker Python SDK, all experiments, trials, and trial components are automatically tracked, logged, and indexed. When you use the AWS SDK for Python (Boto), you must use the logging APIs provided by the SDK.</p> <p>You can add tags to a trial component and then use the <a>Search</a> API to search for the tags.</p> <note> <p> <code>CreateTrialComponent</code> can only be invoked from within an Amazon SageMaker managed environment. This includes Amazon SageMaker training jobs, processing jobs, transform jobs, and Amazon SageMaker notebooks. A call to <code>CreateTrialComponent</code> from outside one of these environments results in an error.</p> </note></p>
-    async fn create_trial_component(
+    /// Presigns the request `create_trial_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_trial_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTrialComponentRequest,
-    ) -> Result<CreateTrialComponentResponse, RusotoError<CreateTrialComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_trial_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_trial_component_request(
+        &self,
+        input: CreateTrialComponentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateTrialComponent");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user_profile`, also returning the metadata of the response.
+    pub async fn create_user_profile_with_metadata(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<(CreateUserProfileResponse, ResponseMetadata), RusotoError<CreateUserProfileError>>
+    {
+        let request = self.build_create_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTrialComponentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTrialComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates a new user profile. A user profile represents a single user within a Domain, and is the main way to reference a "person" for the purposes of sharing, reporting and other user-oriented features. This entity is created during on-boarding. If an administrator invites a person by email or imports them from SSO, a new UserProfile is automatically created. This entity is the primary holder of settings for an individual user and has a reference to the user's private Amazon Elastic File System (EFS) home directory. </p>
-    async fn create_user_profile(
+    /// Presigns the request `create_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserProfileRequest,
-    ) -> Result<CreateUserProfileResponse, RusotoError<CreateUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_profile_request(&self, input: CreateUserProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateUserProfile");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_workteam`, also returning the metadata of the response.
+    pub async fn create_workteam_with_metadata(
+        &self,
+        input: CreateWorkteamRequest,
+    ) -> Result<(CreateWorkteamResponse, ResponseMetadata), RusotoError<CreateWorkteamError>> {
+        let request = self.build_create_workteam_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUserProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateWorkteamResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateWorkteamError::from_response(response))
         }
     }
 
-    /// <p>Creates a new work team for labeling your data. A work team is defined by one or more Amazon Cognito user pools. You must first create the user pools before you can create a work team.</p> <p>You cannot create more than 25 work teams in an account and region.</p>
-    async fn create_workteam(
+    /// Presigns the request `create_workteam` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_workteam(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateWorkteamRequest,
-    ) -> Result<CreateWorkteamResponse, RusotoError<CreateWorkteamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_workteam_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_workteam_request(&self, input: CreateWorkteamRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateWorkteam");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.CreateWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_algorithm`, also returning the metadata of the response.
+    pub async fn delete_algorithm_with_metadata(
+        &self,
+        input: DeleteAlgorithmInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAlgorithmError>> {
+        let request = self.build_delete_algorithm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateWorkteamResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateWorkteamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAlgorithmError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified algorithm from your account.</p>
-    async fn delete_algorithm(
+    /// Presigns the request `delete_algorithm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_algorithm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAlgorithmInput,
-    ) -> Result<(), RusotoError<DeleteAlgorithmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_algorithm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_algorithm_request(&self, input: DeleteAlgorithmInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteAlgorithm");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_app`, also returning the metadata of the response.
+    pub async fn delete_app_with_metadata(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAppError>> {
+        let request = self.build_delete_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAlgorithmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAppError::from_response(response))
         }
     }
 
-    /// <p>Used to stop and delete an app.</p>
-    async fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
+    /// Presigns the request `delete_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_app(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteAppRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_app_request(&self, input: DeleteAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteApp");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_code_repository`, also returning the metadata of the response.
+    pub async fn delete_code_repository_with_metadata(
+        &self,
+        input: DeleteCodeRepositoryInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCodeRepositoryError>> {
+        let request = self.build_delete_code_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCodeRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified Git repository from your account.</p>
-    async fn delete_code_repository(
+    /// Presigns the request `delete_code_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_code_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCodeRepositoryInput,
-    ) -> Result<(), RusotoError<DeleteCodeRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_code_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_code_repository_request(
+        &self,
+        input: DeleteCodeRepositoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteCodeRepository");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_domain`, also returning the metadata of the response.
+    pub async fn delete_domain_with_metadata(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDomainError>> {
+        let request = self.build_delete_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCodeRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDomainError::from_response(response))
         }
     }
 
-    /// <p>Used to delete a domain. If you on-boarded with IAM mode, you will need to delete your domain to on-board again using SSO. Use with caution. All of the members of the domain will lose access to their EFS volume, including data, notebooks, and other artifacts. </p>
-    async fn delete_domain(
+    /// Presigns the request `delete_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDomainRequest,
-    ) -> Result<(), RusotoError<DeleteDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_domain_request(&self, input: DeleteDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteDomain");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_endpoint`, also returning the metadata of the response.
+    pub async fn delete_endpoint_with_metadata(
+        &self,
+        input: DeleteEndpointInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEndpointError>> {
+        let request = self.build_delete_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEndpointError::from_response(response))
         }
     }
 
-    /// <p>Deletes an endpoint. Amazon SageMaker frees up all of the resources that were deployed when the endpoint was created. </p> <p>Amazon SageMaker retires any custom KMS key grants associated with the endpoint, meaning you don't need to use the <a href="http://docs.aws.amazon.com/kms/latest/APIReference/API_RevokeGrant.html">RevokeGrant</a> API call.</p>
-    async fn delete_endpoint(
+    /// Presigns the request `delete_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEndpointInput,
-    ) -> Result<(), RusotoError<DeleteEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_endpoint_request(&self, input: DeleteEndpointInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_endpoint_config`, also returning the metadata of the response.
+    pub async fn delete_endpoint_config_with_metadata(
+        &self,
+        input: DeleteEndpointConfigInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEndpointConfigError>> {
+        let request = self.build_delete_endpoint_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEndpointConfigError::from_response(response))
         }
     }
 
-    /// <p>Deletes an endpoint configuration. The <code>DeleteEndpointConfig</code> API deletes only the specified configuration. It does not delete endpoints created using the configuration. </p>
-    async fn delete_endpoint_config(
+    /// Presigns the request `delete_endpoint_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_endpoint_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEndpointConfigInput,
-    ) -> Result<(), RusotoError<DeleteEndpointConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_endpoint_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_endpoint_config_request(
+        &self,
+        input: DeleteEndpointConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteEndpointConfig");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteEndpointConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_experiment`, also returning the metadata of the response.
+    pub async fn delete_experiment_with_metadata(
+        &self,
+        input: DeleteExperimentRequest,
+    ) -> Result<(DeleteExperimentResponse, ResponseMetadata), RusotoError<DeleteExperimentError>>
+    {
+        let request = self.build_delete_experiment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteExperimentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEndpointConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteExperimentError::from_response(response))
         }
     }
 
-    /// <p>Deletes an Amazon SageMaker experiment. All trials associated with the experiment must be deleted first. Use the <a>ListTrials</a> API to get a list of the trials associated with the experiment.</p>
-    async fn delete_experiment(
+    /// Presigns the request `delete_experiment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_experiment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteExperimentRequest,
-    ) -> Result<DeleteExperimentResponse, RusotoError<DeleteExperimentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_experiment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_experiment_request(&self, input: DeleteExperimentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteExperiment");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_flow_definition`, also returning the metadata of the response.
+    pub async fn delete_flow_definition_with_metadata(
+        &self,
+        input: DeleteFlowDefinitionRequest,
+    ) -> Result<
+        (DeleteFlowDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteFlowDefinitionError>,
+    > {
+        let request = self.build_delete_flow_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteExperimentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteFlowDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteExperimentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteFlowDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified flow definition.</p>
-    async fn delete_flow_definition(
+    /// Presigns the request `delete_flow_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_flow_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFlowDefinitionRequest,
-    ) -> Result<DeleteFlowDefinitionResponse, RusotoError<DeleteFlowDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_flow_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_flow_definition_request(
+        &self,
+        input: DeleteFlowDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteFlowDefinition");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteFlowDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_model`, also returning the metadata of the response.
+    pub async fn delete_model_with_metadata(
+        &self,
+        input: DeleteModelInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteModelError>> {
+        let request = self.build_delete_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteFlowDefinitionResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFlowDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteModelError::from_response(response))
         }
     }
 
-    /// <p>Deletes a model. The <code>DeleteModel</code> API deletes only the model entry that was created in Amazon SageMaker when you called the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateModel.html">CreateModel</a> API. It does not delete model artifacts, inference code, or the IAM role that you specified when creating the model. </p>
-    async fn delete_model(
+    /// Presigns the request `delete_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteModelInput,
-    ) -> Result<(), RusotoError<DeleteModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_model_request(&self, input: DeleteModelInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteModel");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_model_package`, also returning the metadata of the response.
+    pub async fn delete_model_package_with_metadata(
+        &self,
+        input: DeleteModelPackageInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteModelPackageError>> {
+        let request = self.build_delete_model_package_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteModelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteModelPackageError::from_response(response))
         }
     }
 
-    /// <p>Deletes a model package.</p> <p>A model package is used to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p>
-    async fn delete_model_package(
+    /// Presigns the request `delete_model_package` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_model_package(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteModelPackageInput,
-    ) -> Result<(), RusotoError<DeleteModelPackageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_model_package_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_model_package_request(&self, input: DeleteModelPackageInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteModelPackage");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_monitoring_schedule`, also returning the metadata of the response.
+    pub async fn delete_monitoring_schedule_with_metadata(
+        &self,
+        input: DeleteMonitoringScheduleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteMonitoringScheduleError>> {
+        let request = self.build_delete_monitoring_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteModelPackageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteMonitoringScheduleError::from_response(response))
         }
     }
 
-    /// <p>Deletes a monitoring schedule. Also stops the schedule had not already been stopped. This does not delete the job execution history of the monitoring schedule. </p>
-    async fn delete_monitoring_schedule(
+    /// Presigns the request `delete_monitoring_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_monitoring_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMonitoringScheduleRequest,
-    ) -> Result<(), RusotoError<DeleteMonitoringScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_monitoring_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_monitoring_schedule_request(
+        &self,
+        input: DeleteMonitoringScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteMonitoringSchedule");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_notebook_instance`, also returning the metadata of the response.
+    pub async fn delete_notebook_instance_with_metadata(
+        &self,
+        input: DeleteNotebookInstanceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteNotebookInstanceError>> {
+        let request = self.build_delete_notebook_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMonitoringScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteNotebookInstanceError::from_response(response))
         }
     }
 
-    /// <p><p> Deletes an Amazon SageMaker notebook instance. Before you can delete a notebook instance, you must call the <code>StopNotebookInstance</code> API. </p> <important> <p>When you delete a notebook instance, you lose all of your data. Amazon SageMaker removes the ML compute instance, and deletes the ML storage volume and the network interface associated with the notebook instance. </p> </important></p>
-    async fn delete_notebook_instance(
+    /// Presigns the request `delete_notebook_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_notebook_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteNotebookInstanceInput,
-    ) -> Result<(), RusotoError<DeleteNotebookInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_notebook_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_notebook_instance_request(
+        &self,
+        input: DeleteNotebookInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteNotebookInstance");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_notebook_instance_lifecycle_config`, also returning the metadata of the response.
+    pub async fn delete_notebook_instance_lifecycle_config_with_metadata(
+        &self,
+        input: DeleteNotebookInstanceLifecycleConfigInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteNotebookInstanceLifecycleConfigError>>
+    {
+        let request = self.build_delete_notebook_instance_lifecycle_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNotebookInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteNotebookInstanceLifecycleConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes a notebook instance lifecycle configuration.</p>
-    async fn delete_notebook_instance_lifecycle_config(
+    /// Presigns the request `delete_notebook_instance_lifecycle_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_notebook_instance_lifecycle_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteNotebookInstanceLifecycleConfigInput,
-    ) -> Result<(), RusotoError<DeleteNotebookInstanceLifecycleConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_notebook_instance_lifecycle_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_notebook_instance_lifecycle_config_request(
+        &self,
+        input: DeleteNotebookInstanceLifecycleConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteNotebookInstanceLifecycleConfig");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14545,645 +16545,1274 @@ impl SageMaker for SageMakerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_tags`, also returning the metadata of the response.
+    pub async fn delete_tags_with_metadata(
+        &self,
+        input: DeleteTagsInput,
+    ) -> Result<(DeleteTagsOutput, ResponseMetadata), RusotoError<DeleteTagsError>> {
+        let request = self.build_delete_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTagsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNotebookInstanceLifecycleConfigError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTagsError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes the specified tags from an Amazon SageMaker resource.</p> <p>To list a resource&#39;s tags, use the <code>ListTags</code> API. </p> <note> <p>When you call this API to delete tags from a hyperparameter tuning job, the deleted tags are not removed from training jobs that the hyperparameter tuning job launched before you called this API.</p> </note></p>
-    async fn delete_tags(
+    /// Presigns the request `delete_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTagsInput,
-    ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tags_request(&self, input: DeleteTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteTags");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_trial`, also returning the metadata of the response.
+    pub async fn delete_trial_with_metadata(
+        &self,
+        input: DeleteTrialRequest,
+    ) -> Result<(DeleteTrialResponse, ResponseMetadata), RusotoError<DeleteTrialError>> {
+        let request = self.build_delete_trial_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTrialResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTrialError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified trial. All trial components that make up the trial must be deleted first. Use the <a>DescribeTrialComponent</a> API to get the list of trial components.</p>
-    async fn delete_trial(
+    /// Presigns the request `delete_trial` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_trial(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTrialRequest,
-    ) -> Result<DeleteTrialResponse, RusotoError<DeleteTrialError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_trial_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_trial_request(&self, input: DeleteTrialRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteTrial");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_trial_component`, also returning the metadata of the response.
+    pub async fn delete_trial_component_with_metadata(
+        &self,
+        input: DeleteTrialComponentRequest,
+    ) -> Result<
+        (DeleteTrialComponentResponse, ResponseMetadata),
+        RusotoError<DeleteTrialComponentError>,
+    > {
+        let request = self.build_delete_trial_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTrialResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTrialComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTrialError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTrialComponentError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified trial component. A trial component must be disassociated from all trials before the trial component can be deleted. To disassociate a trial component from a trial, call the <a>DisassociateTrialComponent</a> API.</p>
-    async fn delete_trial_component(
+    /// Presigns the request `delete_trial_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_trial_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTrialComponentRequest,
-    ) -> Result<DeleteTrialComponentResponse, RusotoError<DeleteTrialComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_trial_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_trial_component_request(
+        &self,
+        input: DeleteTrialComponentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteTrialComponent");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user_profile`, also returning the metadata of the response.
+    pub async fn delete_user_profile_with_metadata(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUserProfileError>> {
+        let request = self.build_delete_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteTrialComponentResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTrialComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Deletes a user profile.</p>
-    async fn delete_user_profile(
+    /// Presigns the request `delete_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserProfileRequest,
-    ) -> Result<(), RusotoError<DeleteUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_profile_request(&self, input: DeleteUserProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteUserProfile");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_workteam`, also returning the metadata of the response.
+    pub async fn delete_workteam_with_metadata(
+        &self,
+        input: DeleteWorkteamRequest,
+    ) -> Result<(DeleteWorkteamResponse, ResponseMetadata), RusotoError<DeleteWorkteamError>> {
+        let request = self.build_delete_workteam_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteWorkteamResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteWorkteamError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing work team. This operation can't be undone.</p>
-    async fn delete_workteam(
+    /// Presigns the request `delete_workteam` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_workteam(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteWorkteamRequest,
-    ) -> Result<DeleteWorkteamResponse, RusotoError<DeleteWorkteamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_workteam_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_workteam_request(&self, input: DeleteWorkteamRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteWorkteam");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DeleteWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_algorithm`, also returning the metadata of the response.
+    pub async fn describe_algorithm_with_metadata(
+        &self,
+        input: DescribeAlgorithmInput,
+    ) -> Result<(DescribeAlgorithmOutput, ResponseMetadata), RusotoError<DescribeAlgorithmError>>
+    {
+        let request = self.build_describe_algorithm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteWorkteamResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAlgorithmOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteWorkteamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAlgorithmError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of the specified algorithm that is in your account.</p>
-    async fn describe_algorithm(
+    /// Presigns the request `describe_algorithm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_algorithm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAlgorithmInput,
-    ) -> Result<DescribeAlgorithmOutput, RusotoError<DescribeAlgorithmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_algorithm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_algorithm_request(&self, input: DescribeAlgorithmInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeAlgorithm");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_app`, also returning the metadata of the response.
+    pub async fn describe_app_with_metadata(
+        &self,
+        input: DescribeAppRequest,
+    ) -> Result<(DescribeAppResponse, ResponseMetadata), RusotoError<DescribeAppError>> {
+        let request = self.build_describe_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAlgorithmOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAppResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAlgorithmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAppError::from_response(response))
         }
     }
 
-    /// <p>Describes the app.</p>
-    async fn describe_app(
+    /// Presigns the request `describe_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAppRequest,
-    ) -> Result<DescribeAppResponse, RusotoError<DescribeAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_app_request(&self, input: DescribeAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeApp");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_auto_ml_job`, also returning the metadata of the response.
+    pub async fn describe_auto_ml_job_with_metadata(
+        &self,
+        input: DescribeAutoMLJobRequest,
+    ) -> Result<(DescribeAutoMLJobResponse, ResponseMetadata), RusotoError<DescribeAutoMLJobError>>
+    {
+        let request = self.build_describe_auto_ml_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAppResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAutoMLJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAutoMLJobError::from_response(response))
         }
     }
 
-    /// <p>Returns information about an Amazon SageMaker job.</p>
-    async fn describe_auto_ml_job(
+    /// Presigns the request `describe_auto_ml_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_auto_ml_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAutoMLJobRequest,
-    ) -> Result<DescribeAutoMLJobResponse, RusotoError<DescribeAutoMLJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_auto_ml_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_auto_ml_job_request(&self, input: DescribeAutoMLJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeAutoMLJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_code_repository`, also returning the metadata of the response.
+    pub async fn describe_code_repository_with_metadata(
+        &self,
+        input: DescribeCodeRepositoryInput,
+    ) -> Result<
+        (DescribeCodeRepositoryOutput, ResponseMetadata),
+        RusotoError<DescribeCodeRepositoryError>,
+    > {
+        let request = self.build_describe_code_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAutoMLJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCodeRepositoryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAutoMLJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCodeRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Gets details about the specified Git repository.</p>
-    async fn describe_code_repository(
+    /// Presigns the request `describe_code_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_code_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCodeRepositoryInput,
-    ) -> Result<DescribeCodeRepositoryOutput, RusotoError<DescribeCodeRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_code_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_code_repository_request(
+        &self,
+        input: DescribeCodeRepositoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeCodeRepository");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_compilation_job`, also returning the metadata of the response.
+    pub async fn describe_compilation_job_with_metadata(
+        &self,
+        input: DescribeCompilationJobRequest,
+    ) -> Result<
+        (DescribeCompilationJobResponse, ResponseMetadata),
+        RusotoError<DescribeCompilationJobError>,
+    > {
+        let request = self.build_describe_compilation_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCodeRepositoryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCompilationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCodeRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCompilationJobError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a model compilation job.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
-    async fn describe_compilation_job(
+    /// Presigns the request `describe_compilation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_compilation_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCompilationJobRequest,
-    ) -> Result<DescribeCompilationJobResponse, RusotoError<DescribeCompilationJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_compilation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_compilation_job_request(
+        &self,
+        input: DescribeCompilationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeCompilationJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeCompilationJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_domain`, also returning the metadata of the response.
+    pub async fn describe_domain_with_metadata(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> Result<(DescribeDomainResponse, ResponseMetadata), RusotoError<DescribeDomainError>> {
+        let request = self.build_describe_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCompilationJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCompilationJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDomainError::from_response(response))
         }
     }
 
-    /// <p>The desciption of the domain.</p>
-    async fn describe_domain(
+    /// Presigns the request `describe_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDomainRequest,
-    ) -> Result<DescribeDomainResponse, RusotoError<DescribeDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_domain_request(&self, input: DescribeDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeDomain");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_endpoint`, also returning the metadata of the response.
+    pub async fn describe_endpoint_with_metadata(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> Result<(DescribeEndpointOutput, ResponseMetadata), RusotoError<DescribeEndpointError>>
+    {
+        let request = self.build_describe_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEndpointOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEndpointError::from_response(response))
         }
     }
 
-    /// <p>Returns the description of an endpoint.</p>
-    async fn describe_endpoint(
+    /// Presigns the request `describe_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEndpointInput,
-    ) -> Result<DescribeEndpointOutput, RusotoError<DescribeEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_endpoint_request(&self, input: DescribeEndpointInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeEndpoint");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_endpoint_config`, also returning the metadata of the response.
+    pub async fn describe_endpoint_config_with_metadata(
+        &self,
+        input: DescribeEndpointConfigInput,
+    ) -> Result<
+        (DescribeEndpointConfigOutput, ResponseMetadata),
+        RusotoError<DescribeEndpointConfigError>,
+    > {
+        let request = self.build_describe_endpoint_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeEndpointOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEndpointConfigOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEndpointConfigError::from_response(response))
         }
     }
 
-    /// <p>Returns the description of an endpoint configuration created using the <code>CreateEndpointConfig</code> API.</p>
-    async fn describe_endpoint_config(
+    /// Presigns the request `describe_endpoint_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_endpoint_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEndpointConfigInput,
-    ) -> Result<DescribeEndpointConfigOutput, RusotoError<DescribeEndpointConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_endpoint_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_endpoint_config_request(
+        &self,
+        input: DescribeEndpointConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeEndpointConfig");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeEndpointConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_experiment`, also returning the metadata of the response.
+    pub async fn describe_experiment_with_metadata(
+        &self,
+        input: DescribeExperimentRequest,
+    ) -> Result<(DescribeExperimentResponse, ResponseMetadata), RusotoError<DescribeExperimentError>>
+    {
+        let request = self.build_describe_experiment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEndpointConfigOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeExperimentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEndpointConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeExperimentError::from_response(response))
         }
     }
 
-    /// <p>Provides a list of an experiment's properties.</p>
-    async fn describe_experiment(
+    /// Presigns the request `describe_experiment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_experiment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeExperimentRequest,
-    ) -> Result<DescribeExperimentResponse, RusotoError<DescribeExperimentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_experiment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_experiment_request(&self, input: DescribeExperimentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeExperiment");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_flow_definition`, also returning the metadata of the response.
+    pub async fn describe_flow_definition_with_metadata(
+        &self,
+        input: DescribeFlowDefinitionRequest,
+    ) -> Result<
+        (DescribeFlowDefinitionResponse, ResponseMetadata),
+        RusotoError<DescribeFlowDefinitionError>,
+    > {
+        let request = self.build_describe_flow_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeExperimentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFlowDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeExperimentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFlowDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified flow definition.</p>
-    async fn describe_flow_definition(
+    /// Presigns the request `describe_flow_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_flow_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFlowDefinitionRequest,
-    ) -> Result<DescribeFlowDefinitionResponse, RusotoError<DescribeFlowDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_flow_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_flow_definition_request(
+        &self,
+        input: DescribeFlowDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeFlowDefinition");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeFlowDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_human_task_ui`, also returning the metadata of the response.
+    pub async fn describe_human_task_ui_with_metadata(
+        &self,
+        input: DescribeHumanTaskUiRequest,
+    ) -> Result<
+        (DescribeHumanTaskUiResponse, ResponseMetadata),
+        RusotoError<DescribeHumanTaskUiError>,
+    > {
+        let request = self.build_describe_human_task_ui_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFlowDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeHumanTaskUiResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFlowDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeHumanTaskUiError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the requested human task user interface.</p>
-    async fn describe_human_task_ui(
+    /// Presigns the request `describe_human_task_ui` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_human_task_ui(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHumanTaskUiRequest,
-    ) -> Result<DescribeHumanTaskUiResponse, RusotoError<DescribeHumanTaskUiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_human_task_ui_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_human_task_ui_request(
+        &self,
+        input: DescribeHumanTaskUiRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeHumanTaskUi");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeHumanTaskUi");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_hyper_parameter_tuning_job`, also returning the metadata of the response.
+    pub async fn describe_hyper_parameter_tuning_job_with_metadata(
+        &self,
+        input: DescribeHyperParameterTuningJobRequest,
+    ) -> Result<
+        (DescribeHyperParameterTuningJobResponse, ResponseMetadata),
+        RusotoError<DescribeHyperParameterTuningJobError>,
+    > {
+        let request = self.build_describe_hyper_parameter_tuning_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeHumanTaskUiResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeHyperParameterTuningJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHumanTaskUiError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeHyperParameterTuningJobError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Gets a description of a hyperparameter tuning job.</p>
-    async fn describe_hyper_parameter_tuning_job(
+    /// Presigns the request `describe_hyper_parameter_tuning_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_hyper_parameter_tuning_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHyperParameterTuningJobRequest,
-    ) -> Result<
-        DescribeHyperParameterTuningJobResponse,
-        RusotoError<DescribeHyperParameterTuningJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_hyper_parameter_tuning_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_hyper_parameter_tuning_job_request(
+        &self,
+        input: DescribeHyperParameterTuningJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeHyperParameterTuningJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeHyperParameterTuningJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_labeling_job`, also returning the metadata of the response.
+    pub async fn describe_labeling_job_with_metadata(
+        &self,
+        input: DescribeLabelingJobRequest,
+    ) -> Result<
+        (DescribeLabelingJobResponse, ResponseMetadata),
+        RusotoError<DescribeLabelingJobError>,
+    > {
+        let request = self.build_describe_labeling_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeHyperParameterTuningJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLabelingJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHyperParameterTuningJobError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLabelingJobError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a labeling job.</p>
-    async fn describe_labeling_job(
+    /// Presigns the request `describe_labeling_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_labeling_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLabelingJobRequest,
-    ) -> Result<DescribeLabelingJobResponse, RusotoError<DescribeLabelingJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_labeling_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_labeling_job_request(
+        &self,
+        input: DescribeLabelingJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeLabelingJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeLabelingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_model`, also returning the metadata of the response.
+    pub async fn describe_model_with_metadata(
+        &self,
+        input: DescribeModelInput,
+    ) -> Result<(DescribeModelOutput, ResponseMetadata), RusotoError<DescribeModelError>> {
+        let request = self.build_describe_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLabelingJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeModelOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLabelingJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeModelError::from_response(response))
         }
     }
 
-    /// <p>Describes a model that you created using the <code>CreateModel</code> API.</p>
-    async fn describe_model(
+    /// Presigns the request `describe_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeModelInput,
-    ) -> Result<DescribeModelOutput, RusotoError<DescribeModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_model_request(&self, input: DescribeModelInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeModel");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_model_package`, also returning the metadata of the response.
+    pub async fn describe_model_package_with_metadata(
+        &self,
+        input: DescribeModelPackageInput,
+    ) -> Result<
+        (DescribeModelPackageOutput, ResponseMetadata),
+        RusotoError<DescribeModelPackageError>,
+    > {
+        let request = self.build_describe_model_package_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeModelOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeModelPackageOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeModelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeModelPackageError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of the specified model package, which is used to create Amazon SageMaker models or list them on AWS Marketplace.</p> <p>To create models in Amazon SageMaker, buyers can subscribe to model packages listed on AWS Marketplace.</p>
-    async fn describe_model_package(
+    /// Presigns the request `describe_model_package` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_model_package(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeModelPackageInput,
-    ) -> Result<DescribeModelPackageOutput, RusotoError<DescribeModelPackageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_model_package_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_model_package_request(
+        &self,
+        input: DescribeModelPackageInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeModelPackage");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_monitoring_schedule`, also returning the metadata of the response.
+    pub async fn describe_monitoring_schedule_with_metadata(
+        &self,
+        input: DescribeMonitoringScheduleRequest,
+    ) -> Result<
+        (DescribeMonitoringScheduleResponse, ResponseMetadata),
+        RusotoError<DescribeMonitoringScheduleError>,
+    > {
+        let request = self.build_describe_monitoring_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeModelPackageOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMonitoringScheduleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeModelPackageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMonitoringScheduleError::from_response(response))
         }
     }
 
-    /// <p>Describes the schedule for a monitoring job.</p>
-    async fn describe_monitoring_schedule(
+    /// Presigns the request `describe_monitoring_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_monitoring_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMonitoringScheduleRequest,
-    ) -> Result<DescribeMonitoringScheduleResponse, RusotoError<DescribeMonitoringScheduleError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_monitoring_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_monitoring_schedule_request(
+        &self,
+        input: DescribeMonitoringScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeMonitoringSchedule");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_notebook_instance`, also returning the metadata of the response.
+    pub async fn describe_notebook_instance_with_metadata(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> Result<
+        (DescribeNotebookInstanceOutput, ResponseMetadata),
+        RusotoError<DescribeNotebookInstanceError>,
+    > {
+        let request = self.build_describe_notebook_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMonitoringScheduleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeNotebookInstanceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMonitoringScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeNotebookInstanceError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a notebook instance.</p>
-    async fn describe_notebook_instance(
+    /// Presigns the request `describe_notebook_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_notebook_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeNotebookInstanceInput,
-    ) -> Result<DescribeNotebookInstanceOutput, RusotoError<DescribeNotebookInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_notebook_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_notebook_instance_request(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeNotebookInstance");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_notebook_instance_lifecycle_config`, also returning the metadata of the response.
+    pub async fn describe_notebook_instance_lifecycle_config_with_metadata(
+        &self,
+        input: DescribeNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        (
+            DescribeNotebookInstanceLifecycleConfigOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeNotebookInstanceLifecycleConfigError>,
+    > {
+        let request = self.build_describe_notebook_instance_lifecycle_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeNotebookInstanceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeNotebookInstanceLifecycleConfigOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeNotebookInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeNotebookInstanceLifecycleConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns a description of a notebook instance lifecycle configuration.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
-    async fn describe_notebook_instance_lifecycle_config(
+    /// Presigns the request `describe_notebook_instance_lifecycle_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_notebook_instance_lifecycle_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeNotebookInstanceLifecycleConfigInput,
-    ) -> Result<
-        DescribeNotebookInstanceLifecycleConfigOutput,
-        RusotoError<DescribeNotebookInstanceLifecycleConfigError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_notebook_instance_lifecycle_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_notebook_instance_lifecycle_config_request(
+        &self,
+        input: DescribeNotebookInstanceLifecycleConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeNotebookInstanceLifecycleConfig");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15193,869 +17822,1726 @@ impl SageMaker for SageMakerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_processing_job`, also returning the metadata of the response.
+    pub async fn describe_processing_job_with_metadata(
+        &self,
+        input: DescribeProcessingJobRequest,
+    ) -> Result<
+        (DescribeProcessingJobResponse, ResponseMetadata),
+        RusotoError<DescribeProcessingJobError>,
+    > {
+        let request = self.build_describe_processing_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeNotebookInstanceLifecycleConfigOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProcessingJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeNotebookInstanceLifecycleConfigError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProcessingJobError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of a processing job.</p>
-    async fn describe_processing_job(
+    /// Presigns the request `describe_processing_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_processing_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProcessingJobRequest,
-    ) -> Result<DescribeProcessingJobResponse, RusotoError<DescribeProcessingJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_processing_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_processing_job_request(
+        &self,
+        input: DescribeProcessingJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeProcessingJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeProcessingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `describe_subscribed_workteam`, also returning the metadata of the response.
+    pub async fn describe_subscribed_workteam_with_metadata(
+        &self,
+        input: DescribeSubscribedWorkteamRequest,
+    ) -> Result<
+        (DescribeSubscribedWorkteamResponse, ResponseMetadata),
+        RusotoError<DescribeSubscribedWorkteamError>,
+    > {
+        let request = self.build_describe_subscribed_workteam_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProcessingJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSubscribedWorkteamResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProcessingJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSubscribedWorkteamError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a work team provided by a vendor. It returns details about the subscription with a vendor in the AWS Marketplace.</p>
-    async fn describe_subscribed_workteam(
+    /// Presigns the request `describe_subscribed_workteam` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_subscribed_workteam(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSubscribedWorkteamRequest,
-    ) -> Result<DescribeSubscribedWorkteamResponse, RusotoError<DescribeSubscribedWorkteamError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_subscribed_workteam_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_subscribed_workteam_request(
+        &self,
+        input: DescribeSubscribedWorkteamRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeSubscribedWorkteam");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeSubscribedWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_training_job`, also returning the metadata of the response.
+    pub async fn describe_training_job_with_metadata(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> Result<
+        (DescribeTrainingJobResponse, ResponseMetadata),
+        RusotoError<DescribeTrainingJobError>,
+    > {
+        let request = self.build_describe_training_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSubscribedWorkteamResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTrainingJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSubscribedWorkteamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTrainingJobError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a training job.</p>
-    async fn describe_training_job(
+    /// Presigns the request `describe_training_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_training_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTrainingJobRequest,
-    ) -> Result<DescribeTrainingJobResponse, RusotoError<DescribeTrainingJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_training_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_training_job_request(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeTrainingJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_transform_job`, also returning the metadata of the response.
+    pub async fn describe_transform_job_with_metadata(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> Result<
+        (DescribeTransformJobResponse, ResponseMetadata),
+        RusotoError<DescribeTransformJobError>,
+    > {
+        let request = self.build_describe_transform_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTrainingJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTransformJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTrainingJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTransformJobError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a transform job.</p>
-    async fn describe_transform_job(
+    /// Presigns the request `describe_transform_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_transform_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTransformJobRequest,
-    ) -> Result<DescribeTransformJobResponse, RusotoError<DescribeTransformJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_transform_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_transform_job_request(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeTransformJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeTransformJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_trial`, also returning the metadata of the response.
+    pub async fn describe_trial_with_metadata(
+        &self,
+        input: DescribeTrialRequest,
+    ) -> Result<(DescribeTrialResponse, ResponseMetadata), RusotoError<DescribeTrialError>> {
+        let request = self.build_describe_trial_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTransformJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTrialResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTransformJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTrialError::from_response(response))
         }
     }
 
-    /// <p>Provides a list of a trial's properties.</p>
-    async fn describe_trial(
+    /// Presigns the request `describe_trial` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_trial(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTrialRequest,
-    ) -> Result<DescribeTrialResponse, RusotoError<DescribeTrialError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_trial_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_trial_request(&self, input: DescribeTrialRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeTrial");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_trial_component`, also returning the metadata of the response.
+    pub async fn describe_trial_component_with_metadata(
+        &self,
+        input: DescribeTrialComponentRequest,
+    ) -> Result<
+        (DescribeTrialComponentResponse, ResponseMetadata),
+        RusotoError<DescribeTrialComponentError>,
+    > {
+        let request = self.build_describe_trial_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTrialResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTrialComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTrialError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTrialComponentError::from_response(response))
         }
     }
 
-    /// <p>Provides a list of a trials component's properties.</p>
-    async fn describe_trial_component(
+    /// Presigns the request `describe_trial_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_trial_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTrialComponentRequest,
-    ) -> Result<DescribeTrialComponentResponse, RusotoError<DescribeTrialComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_trial_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_trial_component_request(
+        &self,
+        input: DescribeTrialComponentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeTrialComponent");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_user_profile`, also returning the metadata of the response.
+    pub async fn describe_user_profile_with_metadata(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> Result<
+        (DescribeUserProfileResponse, ResponseMetadata),
+        RusotoError<DescribeUserProfileError>,
+    > {
+        let request = self.build_describe_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTrialComponentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTrialComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Describes the user profile.</p>
-    async fn describe_user_profile(
+    /// Presigns the request `describe_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserProfileRequest,
-    ) -> Result<DescribeUserProfileResponse, RusotoError<DescribeUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_profile_request(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeUserProfile");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_workforce`, also returning the metadata of the response.
+    pub async fn describe_workforce_with_metadata(
+        &self,
+        input: DescribeWorkforceRequest,
+    ) -> Result<(DescribeWorkforceResponse, ResponseMetadata), RusotoError<DescribeWorkforceError>>
+    {
+        let request = self.build_describe_workforce_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeWorkforceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeWorkforceError::from_response(response))
         }
     }
 
-    /// <p><p>Lists private workforce information, including workforce name, Amazon Resource Name (ARN), and, if applicable, allowed IP address ranges (<a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html">CIDRs</a>). Allowable IP address ranges are the IP addresses that workers can use to access tasks. </p> <important> <p>This operation applies only to private workforces.</p> </important></p>
-    async fn describe_workforce(
+    /// Presigns the request `describe_workforce` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_workforce(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeWorkforceRequest,
-    ) -> Result<DescribeWorkforceResponse, RusotoError<DescribeWorkforceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_workforce_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_workforce_request(&self, input: DescribeWorkforceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeWorkforce");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeWorkforce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_workteam`, also returning the metadata of the response.
+    pub async fn describe_workteam_with_metadata(
+        &self,
+        input: DescribeWorkteamRequest,
+    ) -> Result<(DescribeWorkteamResponse, ResponseMetadata), RusotoError<DescribeWorkteamError>>
+    {
+        let request = self.build_describe_workteam_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeWorkforceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeWorkteamResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeWorkforceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeWorkteamError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a specific work team. You can see information such as the create date, the last updated date, membership information, and the work team's Amazon Resource Name (ARN).</p>
-    async fn describe_workteam(
+    /// Presigns the request `describe_workteam` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_workteam(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeWorkteamRequest,
-    ) -> Result<DescribeWorkteamResponse, RusotoError<DescribeWorkteamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_workteam_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_workteam_request(&self, input: DescribeWorkteamRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeWorkteam");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DescribeWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_trial_component`, also returning the metadata of the response.
+    pub async fn disassociate_trial_component_with_metadata(
+        &self,
+        input: DisassociateTrialComponentRequest,
+    ) -> Result<
+        (DisassociateTrialComponentResponse, ResponseMetadata),
+        RusotoError<DisassociateTrialComponentError>,
+    > {
+        let request = self.build_disassociate_trial_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeWorkteamResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateTrialComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeWorkteamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateTrialComponentError::from_response(response))
         }
     }
 
-    /// <p>Disassociates a trial component from a trial. This doesn't effect other trials the component is associated with. Before you can delete a component, you must disassociate the component from all trials it is associated with. To associate a trial component with a trial, call the <a>AssociateTrialComponent</a> API.</p>
-    async fn disassociate_trial_component(
+    /// Presigns the request `disassociate_trial_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_trial_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateTrialComponentRequest,
-    ) -> Result<DisassociateTrialComponentResponse, RusotoError<DisassociateTrialComponentError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_trial_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_trial_component_request(
+        &self,
+        input: DisassociateTrialComponentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DisassociateTrialComponent");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.DisassociateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_search_suggestions`, also returning the metadata of the response.
+    pub async fn get_search_suggestions_with_metadata(
+        &self,
+        input: GetSearchSuggestionsRequest,
+    ) -> Result<
+        (GetSearchSuggestionsResponse, ResponseMetadata),
+        RusotoError<GetSearchSuggestionsError>,
+    > {
+        let request = self.build_get_search_suggestions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateTrialComponentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSearchSuggestionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateTrialComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSearchSuggestionsError::from_response(response))
         }
     }
 
-    /// <p>An auto-complete API for the search functionality in the Amazon SageMaker console. It returns suggestions of possible matches for the property name to use in <code>Search</code> queries. Provides suggestions for <code>HyperParameters</code>, <code>Tags</code>, and <code>Metrics</code>.</p>
-    async fn get_search_suggestions(
+    /// Presigns the request `get_search_suggestions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_search_suggestions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSearchSuggestionsRequest,
-    ) -> Result<GetSearchSuggestionsResponse, RusotoError<GetSearchSuggestionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_search_suggestions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_search_suggestions_request(
+        &self,
+        input: GetSearchSuggestionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("GetSearchSuggestions");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.GetSearchSuggestions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_algorithms`, also returning the metadata of the response.
+    pub async fn list_algorithms_with_metadata(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> Result<(ListAlgorithmsOutput, ResponseMetadata), RusotoError<ListAlgorithmsError>> {
+        let request = self.build_list_algorithms_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSearchSuggestionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAlgorithmsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSearchSuggestionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAlgorithmsError::from_response(response))
         }
     }
 
-    /// <p>Lists the machine learning algorithms that have been created.</p>
-    async fn list_algorithms(
+    /// Presigns the request `list_algorithms` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_algorithms(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAlgorithmsInput,
-    ) -> Result<ListAlgorithmsOutput, RusotoError<ListAlgorithmsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_algorithms_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_algorithms_request(&self, input: ListAlgorithmsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListAlgorithms");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListAlgorithms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_apps`, also returning the metadata of the response.
+    pub async fn list_apps_with_metadata(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<(ListAppsResponse, ResponseMetadata), RusotoError<ListAppsError>> {
+        let request = self.build_list_apps_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAlgorithmsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAppsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAlgorithmsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAppsError::from_response(response))
         }
     }
 
-    /// <p>Lists apps.</p>
-    async fn list_apps(
+    /// Presigns the request `list_apps` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_apps(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAppsRequest,
-    ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_apps_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_apps_request(&self, input: ListAppsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListApps");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListApps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_auto_ml_jobs`, also returning the metadata of the response.
+    pub async fn list_auto_ml_jobs_with_metadata(
+        &self,
+        input: ListAutoMLJobsRequest,
+    ) -> Result<(ListAutoMLJobsResponse, ResponseMetadata), RusotoError<ListAutoMLJobsError>> {
+        let request = self.build_list_auto_ml_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAppsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAutoMLJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAppsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAutoMLJobsError::from_response(response))
         }
     }
 
-    /// <p>Request a list of jobs.</p>
-    async fn list_auto_ml_jobs(
+    /// Presigns the request `list_auto_ml_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_auto_ml_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAutoMLJobsRequest,
-    ) -> Result<ListAutoMLJobsResponse, RusotoError<ListAutoMLJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_auto_ml_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_auto_ml_jobs_request(&self, input: ListAutoMLJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListAutoMLJobs");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListAutoMLJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_candidates_for_auto_ml_job`, also returning the metadata of the response.
+    pub async fn list_candidates_for_auto_ml_job_with_metadata(
+        &self,
+        input: ListCandidatesForAutoMLJobRequest,
+    ) -> Result<
+        (ListCandidatesForAutoMLJobResponse, ResponseMetadata),
+        RusotoError<ListCandidatesForAutoMLJobError>,
+    > {
+        let request = self.build_list_candidates_for_auto_ml_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAutoMLJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCandidatesForAutoMLJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAutoMLJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCandidatesForAutoMLJobError::from_response(response))
         }
     }
 
-    /// <p>List the Candidates created for the job.</p>
-    async fn list_candidates_for_auto_ml_job(
+    /// Presigns the request `list_candidates_for_auto_ml_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_candidates_for_auto_ml_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCandidatesForAutoMLJobRequest,
-    ) -> Result<ListCandidatesForAutoMLJobResponse, RusotoError<ListCandidatesForAutoMLJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_candidates_for_auto_ml_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_candidates_for_auto_ml_job_request(
+        &self,
+        input: ListCandidatesForAutoMLJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListCandidatesForAutoMLJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListCandidatesForAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_code_repositories`, also returning the metadata of the response.
+    pub async fn list_code_repositories_with_metadata(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> Result<
+        (ListCodeRepositoriesOutput, ResponseMetadata),
+        RusotoError<ListCodeRepositoriesError>,
+    > {
+        let request = self.build_list_code_repositories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCandidatesForAutoMLJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCodeRepositoriesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCandidatesForAutoMLJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCodeRepositoriesError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the Git repositories in your account.</p>
-    async fn list_code_repositories(
+    /// Presigns the request `list_code_repositories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_code_repositories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCodeRepositoriesInput,
-    ) -> Result<ListCodeRepositoriesOutput, RusotoError<ListCodeRepositoriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_code_repositories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_code_repositories_request(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListCodeRepositories");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListCodeRepositories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_compilation_jobs`, also returning the metadata of the response.
+    pub async fn list_compilation_jobs_with_metadata(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> Result<
+        (ListCompilationJobsResponse, ResponseMetadata),
+        RusotoError<ListCompilationJobsError>,
+    > {
+        let request = self.build_list_compilation_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCodeRepositoriesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCompilationJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCodeRepositoriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCompilationJobsError::from_response(response))
         }
     }
 
-    /// <p>Lists model compilation jobs that satisfy various filters.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about a particular model compilation job you have created, use <a>DescribeCompilationJob</a>.</p>
-    async fn list_compilation_jobs(
+    /// Presigns the request `list_compilation_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_compilation_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCompilationJobsRequest,
-    ) -> Result<ListCompilationJobsResponse, RusotoError<ListCompilationJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_compilation_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_compilation_jobs_request(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListCompilationJobs");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListCompilationJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_domains`, also returning the metadata of the response.
+    pub async fn list_domains_with_metadata(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<(ListDomainsResponse, ResponseMetadata), RusotoError<ListDomainsError>> {
+        let request = self.build_list_domains_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCompilationJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDomainsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCompilationJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDomainsError::from_response(response))
         }
     }
 
-    /// <p>Lists the domains.</p>
-    async fn list_domains(
+    /// Presigns the request `list_domains` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_domains(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDomainsRequest,
-    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_domains_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_domains_request(&self, input: ListDomainsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListDomains");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `list_endpoint_configs`, also returning the metadata of the response.
+    pub async fn list_endpoint_configs_with_metadata(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> Result<(ListEndpointConfigsOutput, ResponseMetadata), RusotoError<ListEndpointConfigsError>>
+    {
+        let request = self.build_list_endpoint_configs_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDomainsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEndpointConfigsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDomainsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEndpointConfigsError::from_response(response))
         }
     }
 
-    /// <p>Lists endpoint configurations.</p>
-    async fn list_endpoint_configs(
+    /// Presigns the request `list_endpoint_configs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_endpoint_configs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEndpointConfigsInput,
-    ) -> Result<ListEndpointConfigsOutput, RusotoError<ListEndpointConfigsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_endpoint_configs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_endpoint_configs_request(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListEndpointConfigs");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListEndpointConfigs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_endpoints`, also returning the metadata of the response.
+    pub async fn list_endpoints_with_metadata(
+        &self,
+        input: ListEndpointsInput,
+    ) -> Result<(ListEndpointsOutput, ResponseMetadata), RusotoError<ListEndpointsError>> {
+        let request = self.build_list_endpoints_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEndpointConfigsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEndpointsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEndpointConfigsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEndpointsError::from_response(response))
         }
     }
 
-    /// <p>Lists endpoints.</p>
-    async fn list_endpoints(
+    /// Presigns the request `list_endpoints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_endpoints(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEndpointsInput,
-    ) -> Result<ListEndpointsOutput, RusotoError<ListEndpointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_endpoints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_endpoints_request(&self, input: ListEndpointsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListEndpoints");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_experiments`, also returning the metadata of the response.
+    pub async fn list_experiments_with_metadata(
+        &self,
+        input: ListExperimentsRequest,
+    ) -> Result<(ListExperimentsResponse, ResponseMetadata), RusotoError<ListExperimentsError>>
+    {
+        let request = self.build_list_experiments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListEndpointsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListExperimentsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEndpointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListExperimentsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the experiments in your account. The list can be filtered to show only experiments that were created in a specific time range. The list can be sorted by experiment name or creation time.</p>
-    async fn list_experiments(
+    /// Presigns the request `list_experiments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_experiments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListExperimentsRequest,
-    ) -> Result<ListExperimentsResponse, RusotoError<ListExperimentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_experiments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_experiments_request(&self, input: ListExperimentsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListExperiments");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListExperiments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_flow_definitions`, also returning the metadata of the response.
+    pub async fn list_flow_definitions_with_metadata(
+        &self,
+        input: ListFlowDefinitionsRequest,
+    ) -> Result<
+        (ListFlowDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListFlowDefinitionsError>,
+    > {
+        let request = self.build_list_flow_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListExperimentsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListFlowDefinitionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListExperimentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListFlowDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the flow definitions in your account.</p>
-    async fn list_flow_definitions(
+    /// Presigns the request `list_flow_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_flow_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFlowDefinitionsRequest,
-    ) -> Result<ListFlowDefinitionsResponse, RusotoError<ListFlowDefinitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_flow_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_flow_definitions_request(
+        &self,
+        input: ListFlowDefinitionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListFlowDefinitions");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListFlowDefinitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_human_task_uis`, also returning the metadata of the response.
+    pub async fn list_human_task_uis_with_metadata(
+        &self,
+        input: ListHumanTaskUisRequest,
+    ) -> Result<(ListHumanTaskUisResponse, ResponseMetadata), RusotoError<ListHumanTaskUisError>>
+    {
+        let request = self.build_list_human_task_uis_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFlowDefinitionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListHumanTaskUisResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFlowDefinitionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListHumanTaskUisError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the human task user interfaces in your account.</p>
-    async fn list_human_task_uis(
+    /// Presigns the request `list_human_task_uis` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_human_task_uis(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHumanTaskUisRequest,
-    ) -> Result<ListHumanTaskUisResponse, RusotoError<ListHumanTaskUisError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_human_task_uis_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_human_task_uis_request(&self, input: ListHumanTaskUisRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListHumanTaskUis");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListHumanTaskUis");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_hyper_parameter_tuning_jobs`, also returning the metadata of the response.
+    pub async fn list_hyper_parameter_tuning_jobs_with_metadata(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> Result<
+        (ListHyperParameterTuningJobsResponse, ResponseMetadata),
+        RusotoError<ListHyperParameterTuningJobsError>,
+    > {
+        let request = self.build_list_hyper_parameter_tuning_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListHumanTaskUisResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListHyperParameterTuningJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHumanTaskUisError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListHyperParameterTuningJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of <a>HyperParameterTuningJobSummary</a> objects that describe the hyperparameter tuning jobs launched in your account.</p>
-    async fn list_hyper_parameter_tuning_jobs(
+    /// Presigns the request `list_hyper_parameter_tuning_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_hyper_parameter_tuning_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHyperParameterTuningJobsRequest,
-    ) -> Result<ListHyperParameterTuningJobsResponse, RusotoError<ListHyperParameterTuningJobsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_hyper_parameter_tuning_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_hyper_parameter_tuning_jobs_request(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListHyperParameterTuningJobs");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListHyperParameterTuningJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_labeling_jobs`, also returning the metadata of the response.
+    pub async fn list_labeling_jobs_with_metadata(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> Result<(ListLabelingJobsResponse, ResponseMetadata), RusotoError<ListLabelingJobsError>>
+    {
+        let request = self.build_list_labeling_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListHyperParameterTuningJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLabelingJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHyperParameterTuningJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLabelingJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of labeling jobs.</p>
-    async fn list_labeling_jobs(
+    /// Presigns the request `list_labeling_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_labeling_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLabelingJobsRequest,
-    ) -> Result<ListLabelingJobsResponse, RusotoError<ListLabelingJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_labeling_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_labeling_jobs_request(&self, input: ListLabelingJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListLabelingJobs");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListLabelingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_labeling_jobs_for_workteam`, also returning the metadata of the response.
+    pub async fn list_labeling_jobs_for_workteam_with_metadata(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> Result<
+        (ListLabelingJobsForWorkteamResponse, ResponseMetadata),
+        RusotoError<ListLabelingJobsForWorkteamError>,
+    > {
+        let request = self.build_list_labeling_jobs_for_workteam_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLabelingJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLabelingJobsForWorkteamResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLabelingJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLabelingJobsForWorkteamError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of labeling jobs assigned to a specified work team.</p>
-    async fn list_labeling_jobs_for_workteam(
+    /// Presigns the request `list_labeling_jobs_for_workteam` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_labeling_jobs_for_workteam(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLabelingJobsForWorkteamRequest,
-    ) -> Result<ListLabelingJobsForWorkteamResponse, RusotoError<ListLabelingJobsForWorkteamError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_labeling_jobs_for_workteam_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_labeling_jobs_for_workteam_request(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListLabelingJobsForWorkteam");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListLabelingJobsForWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_model_packages`, also returning the metadata of the response.
+    pub async fn list_model_packages_with_metadata(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> Result<(ListModelPackagesOutput, ResponseMetadata), RusotoError<ListModelPackagesError>>
+    {
+        let request = self.build_list_model_packages_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLabelingJobsForWorkteamResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListModelPackagesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLabelingJobsForWorkteamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListModelPackagesError::from_response(response))
         }
     }
 
-    /// <p>Lists the model packages that have been created.</p>
-    async fn list_model_packages(
+    /// Presigns the request `list_model_packages` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_model_packages(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListModelPackagesInput,
-    ) -> Result<ListModelPackagesOutput, RusotoError<ListModelPackagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_model_packages_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_model_packages_request(&self, input: ListModelPackagesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListModelPackages");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListModelPackages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_models`, also returning the metadata of the response.
+    pub async fn list_models_with_metadata(
+        &self,
+        input: ListModelsInput,
+    ) -> Result<(ListModelsOutput, ResponseMetadata), RusotoError<ListModelsError>> {
+        let request = self.build_list_models_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListModelPackagesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListModelsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListModelPackagesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListModelsError::from_response(response))
         }
     }
 
-    /// <p>Lists models created with the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateModel.html">CreateModel</a> API.</p>
-    async fn list_models(
+    /// Presigns the request `list_models` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_models(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListModelsInput,
-    ) -> Result<ListModelsOutput, RusotoError<ListModelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_models_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_models_request(&self, input: ListModelsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListModels");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_monitoring_executions`, also returning the metadata of the response.
+    pub async fn list_monitoring_executions_with_metadata(
+        &self,
+        input: ListMonitoringExecutionsRequest,
+    ) -> Result<
+        (ListMonitoringExecutionsResponse, ResponseMetadata),
+        RusotoError<ListMonitoringExecutionsError>,
+    > {
+        let request = self.build_list_monitoring_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListModelsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListMonitoringExecutionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListModelsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListMonitoringExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Returns list of all monitoring job executions.</p>
-    async fn list_monitoring_executions(
+    /// Presigns the request `list_monitoring_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_monitoring_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMonitoringExecutionsRequest,
-    ) -> Result<ListMonitoringExecutionsResponse, RusotoError<ListMonitoringExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_monitoring_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_monitoring_executions_request(
+        &self,
+        input: ListMonitoringExecutionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListMonitoringExecutions");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListMonitoringExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_monitoring_schedules`, also returning the metadata of the response.
+    pub async fn list_monitoring_schedules_with_metadata(
+        &self,
+        input: ListMonitoringSchedulesRequest,
+    ) -> Result<
+        (ListMonitoringSchedulesResponse, ResponseMetadata),
+        RusotoError<ListMonitoringSchedulesError>,
+    > {
+        let request = self.build_list_monitoring_schedules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMonitoringExecutionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListMonitoringSchedulesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMonitoringExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListMonitoringSchedulesError::from_response(response))
         }
     }
 
-    /// <p>Returns list of all monitoring schedules.</p>
-    async fn list_monitoring_schedules(
+    /// Presigns the request `list_monitoring_schedules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_monitoring_schedules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMonitoringSchedulesRequest,
-    ) -> Result<ListMonitoringSchedulesResponse, RusotoError<ListMonitoringSchedulesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_monitoring_schedules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_monitoring_schedules_request(
+        &self,
+        input: ListMonitoringSchedulesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListMonitoringSchedules");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListMonitoringSchedules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_notebook_instance_lifecycle_configs`, also returning the metadata of the response.
+    pub async fn list_notebook_instance_lifecycle_configs_with_metadata(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> Result<
+        (ListNotebookInstanceLifecycleConfigsOutput, ResponseMetadata),
+        RusotoError<ListNotebookInstanceLifecycleConfigsError>,
+    > {
+        let request = self.build_list_notebook_instance_lifecycle_configs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMonitoringSchedulesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListNotebookInstanceLifecycleConfigsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMonitoringSchedulesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListNotebookInstanceLifecycleConfigsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists notebook instance lifestyle configurations created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
-    async fn list_notebook_instance_lifecycle_configs(
+    /// Presigns the request `list_notebook_instance_lifecycle_configs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_notebook_instance_lifecycle_configs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListNotebookInstanceLifecycleConfigsInput,
-    ) -> Result<
-        ListNotebookInstanceLifecycleConfigsOutput,
-        RusotoError<ListNotebookInstanceLifecycleConfigsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_notebook_instance_lifecycle_configs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_notebook_instance_lifecycle_configs_request(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListNotebookInstanceLifecycleConfigs");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16065,172 +19551,338 @@ impl SageMaker for SageMakerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_notebook_instances`, also returning the metadata of the response.
+    pub async fn list_notebook_instances_with_metadata(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> Result<
+        (ListNotebookInstancesOutput, ResponseMetadata),
+        RusotoError<ListNotebookInstancesError>,
+    > {
+        let request = self.build_list_notebook_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListNotebookInstanceLifecycleConfigsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListNotebookInstancesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListNotebookInstanceLifecycleConfigsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListNotebookInstancesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the Amazon SageMaker notebook instances in the requester's account in an AWS Region. </p>
-    async fn list_notebook_instances(
+    /// Presigns the request `list_notebook_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_notebook_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListNotebookInstancesInput,
-    ) -> Result<ListNotebookInstancesOutput, RusotoError<ListNotebookInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_notebook_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_notebook_instances_request(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListNotebookInstances");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListNotebookInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_processing_jobs`, also returning the metadata of the response.
+    pub async fn list_processing_jobs_with_metadata(
+        &self,
+        input: ListProcessingJobsRequest,
+    ) -> Result<(ListProcessingJobsResponse, ResponseMetadata), RusotoError<ListProcessingJobsError>>
+    {
+        let request = self.build_list_processing_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListNotebookInstancesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProcessingJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListNotebookInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProcessingJobsError::from_response(response))
         }
     }
 
-    /// <p>Lists processing jobs that satisfy various filters.</p>
-    async fn list_processing_jobs(
+    /// Presigns the request `list_processing_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_processing_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProcessingJobsRequest,
-    ) -> Result<ListProcessingJobsResponse, RusotoError<ListProcessingJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_processing_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_processing_jobs_request(
+        &self,
+        input: ListProcessingJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListProcessingJobs");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListProcessingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_subscribed_workteams`, also returning the metadata of the response.
+    pub async fn list_subscribed_workteams_with_metadata(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> Result<
+        (ListSubscribedWorkteamsResponse, ResponseMetadata),
+        RusotoError<ListSubscribedWorkteamsError>,
+    > {
+        let request = self.build_list_subscribed_workteams_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProcessingJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSubscribedWorkteamsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProcessingJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSubscribedWorkteamsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the work teams that you are subscribed to in the AWS Marketplace. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
-    async fn list_subscribed_workteams(
+    /// Presigns the request `list_subscribed_workteams` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_subscribed_workteams(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSubscribedWorkteamsRequest,
-    ) -> Result<ListSubscribedWorkteamsResponse, RusotoError<ListSubscribedWorkteamsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_subscribed_workteams_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_subscribed_workteams_request(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListSubscribedWorkteams");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListSubscribedWorkteams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsInput,
+    ) -> Result<(ListTagsOutput, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSubscribedWorkteamsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListTagsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSubscribedWorkteamsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Returns the tags for the specified Amazon SageMaker resource.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsInput,
-    ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTags");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_training_jobs`, also returning the metadata of the response.
+    pub async fn list_training_jobs_with_metadata(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> Result<(ListTrainingJobsResponse, ResponseMetadata), RusotoError<ListTrainingJobsError>>
+    {
+        let request = self.build_list_training_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTrainingJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTrainingJobsError::from_response(response))
         }
     }
 
-    /// <p>Lists training jobs.</p>
-    async fn list_training_jobs(
+    /// Presigns the request `list_training_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_training_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTrainingJobsRequest,
-    ) -> Result<ListTrainingJobsResponse, RusotoError<ListTrainingJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_training_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_training_jobs_request(&self, input: ListTrainingJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTrainingJobs");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListTrainingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_training_jobs_for_hyper_parameter_tuning_job`, also returning the metadata of the response.
+    pub async fn list_training_jobs_for_hyper_parameter_tuning_job_with_metadata(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> Result<
+        (
+            ListTrainingJobsForHyperParameterTuningJobResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
+    > {
+        let request = self.build_list_training_jobs_for_hyper_parameter_tuning_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTrainingJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTrainingJobsForHyperParameterTuningJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTrainingJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTrainingJobsForHyperParameterTuningJobError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of <a>TrainingJobSummary</a> objects that describe the training jobs that a hyperparameter tuning job launched.</p>
-    async fn list_training_jobs_for_hyper_parameter_tuning_job(
+    /// Presigns the request `list_training_jobs_for_hyper_parameter_tuning_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_training_jobs_for_hyper_parameter_tuning_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTrainingJobsForHyperParameterTuningJobRequest,
-    ) -> Result<
-        ListTrainingJobsForHyperParameterTuningJobResponse,
-        RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_training_jobs_for_hyper_parameter_tuning_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_training_jobs_for_hyper_parameter_tuning_job_request(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTrainingJobsForHyperParameterTuningJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16240,603 +19892,1130 @@ impl SageMaker for SageMakerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_transform_jobs`, also returning the metadata of the response.
+    pub async fn list_transform_jobs_with_metadata(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> Result<(ListTransformJobsResponse, ResponseMetadata), RusotoError<ListTransformJobsError>>
+    {
+        let request = self.build_list_transform_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTrainingJobsForHyperParameterTuningJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTransformJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTrainingJobsForHyperParameterTuningJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTransformJobsError::from_response(response))
         }
     }
 
-    /// <p>Lists transform jobs.</p>
-    async fn list_transform_jobs(
+    /// Presigns the request `list_transform_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_transform_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTransformJobsRequest,
-    ) -> Result<ListTransformJobsResponse, RusotoError<ListTransformJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_transform_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_transform_jobs_request(&self, input: ListTransformJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTransformJobs");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListTransformJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_trial_components`, also returning the metadata of the response.
+    pub async fn list_trial_components_with_metadata(
+        &self,
+        input: ListTrialComponentsRequest,
+    ) -> Result<
+        (ListTrialComponentsResponse, ResponseMetadata),
+        RusotoError<ListTrialComponentsError>,
+    > {
+        let request = self.build_list_trial_components_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTransformJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTrialComponentsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTransformJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTrialComponentsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the trial components in your account. You can sort the list by trial component name or creation time. You can filter the list to show only components that were created in a specific time range. You can also filter on one of the following:</p> <ul> <li> <p> <code>ExperimentName</code> </p> </li> <li> <p> <code>SourceArn</code> </p> </li> <li> <p> <code>TrialName</code> </p> </li> </ul></p>
-    async fn list_trial_components(
+    /// Presigns the request `list_trial_components` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_trial_components(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTrialComponentsRequest,
-    ) -> Result<ListTrialComponentsResponse, RusotoError<ListTrialComponentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_trial_components_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_trial_components_request(
+        &self,
+        input: ListTrialComponentsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTrialComponents");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListTrialComponents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_trials`, also returning the metadata of the response.
+    pub async fn list_trials_with_metadata(
+        &self,
+        input: ListTrialsRequest,
+    ) -> Result<(ListTrialsResponse, ResponseMetadata), RusotoError<ListTrialsError>> {
+        let request = self.build_list_trials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTrialComponentsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTrialsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTrialComponentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTrialsError::from_response(response))
         }
     }
 
-    /// <p>Lists the trials in your account. Specify an experiment name to limit the list to the trials that are part of that experiment. The list can be filtered to show only trials that were created in a specific time range. The list can be sorted by trial name or creation time.</p>
-    async fn list_trials(
+    /// Presigns the request `list_trials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_trials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTrialsRequest,
-    ) -> Result<ListTrialsResponse, RusotoError<ListTrialsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_trials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_trials_request(&self, input: ListTrialsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTrials");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListTrials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_user_profiles`, also returning the metadata of the response.
+    pub async fn list_user_profiles_with_metadata(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> Result<(ListUserProfilesResponse, ResponseMetadata), RusotoError<ListUserProfilesError>>
+    {
+        let request = self.build_list_user_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTrialsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUserProfilesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTrialsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUserProfilesError::from_response(response))
         }
     }
 
-    /// <p>Lists user profiles.</p>
-    async fn list_user_profiles(
+    /// Presigns the request `list_user_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_user_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUserProfilesRequest,
-    ) -> Result<ListUserProfilesResponse, RusotoError<ListUserProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_user_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_user_profiles_request(&self, input: ListUserProfilesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListUserProfiles");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListUserProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_workteams`, also returning the metadata of the response.
+    pub async fn list_workteams_with_metadata(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> Result<(ListWorkteamsResponse, ResponseMetadata), RusotoError<ListWorkteamsError>> {
+        let request = self.build_list_workteams_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUserProfilesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListWorkteamsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUserProfilesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListWorkteamsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of work teams that you have defined in a region. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
-    async fn list_workteams(
+    /// Presigns the request `list_workteams` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_workteams(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListWorkteamsRequest,
-    ) -> Result<ListWorkteamsResponse, RusotoError<ListWorkteamsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_workteams_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_workteams_request(&self, input: ListWorkteamsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListWorkteams");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.ListWorkteams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `render_ui_template`, also returning the metadata of the response.
+    pub async fn render_ui_template_with_metadata(
+        &self,
+        input: RenderUiTemplateRequest,
+    ) -> Result<(RenderUiTemplateResponse, ResponseMetadata), RusotoError<RenderUiTemplateError>>
+    {
+        let request = self.build_render_ui_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListWorkteamsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RenderUiTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListWorkteamsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RenderUiTemplateError::from_response(response))
         }
     }
 
-    /// <p>Renders the UI template so that you can preview the worker's experience. </p>
-    async fn render_ui_template(
+    /// Presigns the request `render_ui_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_render_ui_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RenderUiTemplateRequest,
-    ) -> Result<RenderUiTemplateResponse, RusotoError<RenderUiTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_render_ui_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_render_ui_template_request(&self, input: RenderUiTemplateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("RenderUiTemplate");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.RenderUiTemplate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search`, also returning the metadata of the response.
+    pub async fn search_with_metadata(
+        &self,
+        input: SearchRequest,
+    ) -> Result<(SearchResponse, ResponseMetadata), RusotoError<SearchError>> {
+        let request = self.build_search_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RenderUiTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<SearchResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RenderUiTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchError::from_response(response))
         }
     }
 
-    /// <p>Finds Amazon SageMaker resources that match a search query. Matching resource objects are returned as a list of <code>SearchResult</code> objects in the response. You can sort the search results by any resource property in a ascending or descending order.</p> <p>You can query against the following value types: numeric, text, Boolean, and timestamp.</p>
-    async fn search(
+    /// Presigns the request `search` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchRequest,
-    ) -> Result<SearchResponse, RusotoError<SearchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_request(&self, input: SearchRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("Search");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.Search");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_monitoring_schedule`, also returning the metadata of the response.
+    pub async fn start_monitoring_schedule_with_metadata(
+        &self,
+        input: StartMonitoringScheduleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StartMonitoringScheduleError>> {
+        let request = self.build_start_monitoring_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartMonitoringScheduleError::from_response(response))
         }
     }
 
-    /// <p><p>Starts a previously stopped monitoring schedule.</p> <note> <p>New monitoring schedules are immediately started after creation.</p> </note></p>
-    async fn start_monitoring_schedule(
+    /// Presigns the request `start_monitoring_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_monitoring_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartMonitoringScheduleRequest,
-    ) -> Result<(), RusotoError<StartMonitoringScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_monitoring_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_monitoring_schedule_request(
+        &self,
+        input: StartMonitoringScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StartMonitoringSchedule");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StartMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_notebook_instance`, also returning the metadata of the response.
+    pub async fn start_notebook_instance_with_metadata(
+        &self,
+        input: StartNotebookInstanceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<StartNotebookInstanceError>> {
+        let request = self.build_start_notebook_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartMonitoringScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartNotebookInstanceError::from_response(response))
         }
     }
 
-    /// <p>Launches an ML compute instance with the latest version of the libraries and attaches your ML storage volume. After configuring the notebook instance, Amazon SageMaker sets the notebook instance status to <code>InService</code>. A notebook instance's status must be <code>InService</code> before you can connect to your Jupyter notebook. </p>
-    async fn start_notebook_instance(
+    /// Presigns the request `start_notebook_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_notebook_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartNotebookInstanceInput,
-    ) -> Result<(), RusotoError<StartNotebookInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_notebook_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_notebook_instance_request(
+        &self,
+        input: StartNotebookInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StartNotebookInstance");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StartNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_auto_ml_job`, also returning the metadata of the response.
+    pub async fn stop_auto_ml_job_with_metadata(
+        &self,
+        input: StopAutoMLJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopAutoMLJobError>> {
+        let request = self.build_stop_auto_ml_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartNotebookInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopAutoMLJobError::from_response(response))
         }
     }
 
-    /// <p>A method for forcing the termination of a running job.</p>
-    async fn stop_auto_ml_job(
+    /// Presigns the request `stop_auto_ml_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_auto_ml_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopAutoMLJobRequest,
-    ) -> Result<(), RusotoError<StopAutoMLJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_auto_ml_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_auto_ml_job_request(&self, input: StopAutoMLJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopAutoMLJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StopAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_compilation_job`, also returning the metadata of the response.
+    pub async fn stop_compilation_job_with_metadata(
+        &self,
+        input: StopCompilationJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopCompilationJobError>> {
+        let request = self.build_stop_compilation_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopAutoMLJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopCompilationJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a model compilation job.</p> <p> To stop a job, Amazon SageMaker sends the algorithm the SIGTERM signal. This gracefully shuts the job down. If the job hasn't stopped, it sends the SIGKILL signal.</p> <p>When it receives a <code>StopCompilationJob</code> request, Amazon SageMaker changes the <a>CompilationJobSummary$CompilationJobStatus</a> of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the <a>CompilationJobSummary$CompilationJobStatus</a> to <code>Stopped</code>. </p>
-    async fn stop_compilation_job(
+    /// Presigns the request `stop_compilation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_compilation_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopCompilationJobRequest,
-    ) -> Result<(), RusotoError<StopCompilationJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_compilation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_compilation_job_request(
+        &self,
+        input: StopCompilationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopCompilationJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StopCompilationJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_hyper_parameter_tuning_job`, also returning the metadata of the response.
+    pub async fn stop_hyper_parameter_tuning_job_with_metadata(
+        &self,
+        input: StopHyperParameterTuningJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopHyperParameterTuningJobError>> {
+        let request = self.build_stop_hyper_parameter_tuning_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopCompilationJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopHyperParameterTuningJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a running hyperparameter tuning job and all running training jobs that the tuning job launched.</p> <p>All model artifacts output from the training jobs are stored in Amazon Simple Storage Service (Amazon S3). All data that the training jobs write to Amazon CloudWatch Logs are still available in CloudWatch. After the tuning job moves to the <code>Stopped</code> state, it releases all reserved resources for the tuning job.</p>
-    async fn stop_hyper_parameter_tuning_job(
+    /// Presigns the request `stop_hyper_parameter_tuning_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_hyper_parameter_tuning_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopHyperParameterTuningJobRequest,
-    ) -> Result<(), RusotoError<StopHyperParameterTuningJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_hyper_parameter_tuning_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_hyper_parameter_tuning_job_request(
+        &self,
+        input: StopHyperParameterTuningJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopHyperParameterTuningJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StopHyperParameterTuningJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_labeling_job`, also returning the metadata of the response.
+    pub async fn stop_labeling_job_with_metadata(
+        &self,
+        input: StopLabelingJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopLabelingJobError>> {
+        let request = self.build_stop_labeling_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopHyperParameterTuningJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopLabelingJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a running labeling job. A job that is stopped cannot be restarted. Any results obtained before the job is stopped are placed in the Amazon S3 output bucket.</p>
-    async fn stop_labeling_job(
+    /// Presigns the request `stop_labeling_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_labeling_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopLabelingJobRequest,
-    ) -> Result<(), RusotoError<StopLabelingJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_labeling_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_labeling_job_request(&self, input: StopLabelingJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopLabelingJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StopLabelingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_monitoring_schedule`, also returning the metadata of the response.
+    pub async fn stop_monitoring_schedule_with_metadata(
+        &self,
+        input: StopMonitoringScheduleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopMonitoringScheduleError>> {
+        let request = self.build_stop_monitoring_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopLabelingJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopMonitoringScheduleError::from_response(response))
         }
     }
 
-    /// <p>Stops a previously started monitoring schedule.</p>
-    async fn stop_monitoring_schedule(
+    /// Presigns the request `stop_monitoring_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_monitoring_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopMonitoringScheduleRequest,
-    ) -> Result<(), RusotoError<StopMonitoringScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_monitoring_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_monitoring_schedule_request(
+        &self,
+        input: StopMonitoringScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopMonitoringSchedule");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StopMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_notebook_instance`, also returning the metadata of the response.
+    pub async fn stop_notebook_instance_with_metadata(
+        &self,
+        input: StopNotebookInstanceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopNotebookInstanceError>> {
+        let request = self.build_stop_notebook_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopMonitoringScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopNotebookInstanceError::from_response(response))
         }
     }
 
-    /// <p>Terminates the ML compute instance. Before terminating the instance, Amazon SageMaker disconnects the ML storage volume from it. Amazon SageMaker preserves the ML storage volume. Amazon SageMaker stops charging you for the ML compute instance when you call <code>StopNotebookInstance</code>.</p> <p>To access data on the ML storage volume for a notebook instance that has been terminated, call the <code>StartNotebookInstance</code> API. <code>StartNotebookInstance</code> launches another ML compute instance, configures it, and attaches the preserved ML storage volume so you can continue your work. </p>
-    async fn stop_notebook_instance(
+    /// Presigns the request `stop_notebook_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_notebook_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopNotebookInstanceInput,
-    ) -> Result<(), RusotoError<StopNotebookInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_notebook_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_notebook_instance_request(
+        &self,
+        input: StopNotebookInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopNotebookInstance");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StopNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_processing_job`, also returning the metadata of the response.
+    pub async fn stop_processing_job_with_metadata(
+        &self,
+        input: StopProcessingJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopProcessingJobError>> {
+        let request = self.build_stop_processing_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopNotebookInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopProcessingJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a processing job.</p>
-    async fn stop_processing_job(
+    /// Presigns the request `stop_processing_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_processing_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopProcessingJobRequest,
-    ) -> Result<(), RusotoError<StopProcessingJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_processing_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_processing_job_request(&self, input: StopProcessingJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopProcessingJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StopProcessingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_training_job`, also returning the metadata of the response.
+    pub async fn stop_training_job_with_metadata(
+        &self,
+        input: StopTrainingJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopTrainingJobError>> {
+        let request = self.build_stop_training_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopProcessingJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopTrainingJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a training job. To stop a job, Amazon SageMaker sends the algorithm the <code>SIGTERM</code> signal, which delays job termination for 120 seconds. Algorithms might use this 120-second window to save the model artifacts, so the results of the training is not lost. </p> <p>When it receives a <code>StopTrainingJob</code> request, Amazon SageMaker changes the status of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the status to <code>Stopped</code>.</p>
-    async fn stop_training_job(
+    /// Presigns the request `stop_training_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_training_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopTrainingJobRequest,
-    ) -> Result<(), RusotoError<StopTrainingJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_training_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_training_job_request(&self, input: StopTrainingJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopTrainingJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StopTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_transform_job`, also returning the metadata of the response.
+    pub async fn stop_transform_job_with_metadata(
+        &self,
+        input: StopTransformJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopTransformJobError>> {
+        let request = self.build_stop_transform_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopTrainingJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopTransformJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a transform job.</p> <p>When Amazon SageMaker receives a <code>StopTransformJob</code> request, the status of the job changes to <code>Stopping</code>. After Amazon SageMaker stops the job, the status is set to <code>Stopped</code>. When you stop a transform job before it is completed, Amazon SageMaker doesn't store the job's output in Amazon S3.</p>
-    async fn stop_transform_job(
+    /// Presigns the request `stop_transform_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_transform_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopTransformJobRequest,
-    ) -> Result<(), RusotoError<StopTransformJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_transform_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_transform_job_request(&self, input: StopTransformJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopTransformJob");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.StopTransformJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_code_repository`, also returning the metadata of the response.
+    pub async fn update_code_repository_with_metadata(
+        &self,
+        input: UpdateCodeRepositoryInput,
+    ) -> Result<
+        (UpdateCodeRepositoryOutput, ResponseMetadata),
+        RusotoError<UpdateCodeRepositoryError>,
+    > {
+        let request = self.build_update_code_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateCodeRepositoryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopTransformJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateCodeRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified Git repository with the specified values.</p>
-    async fn update_code_repository(
+    /// Presigns the request `update_code_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_code_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCodeRepositoryInput,
-    ) -> Result<UpdateCodeRepositoryOutput, RusotoError<UpdateCodeRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_code_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_code_repository_request(
+        &self,
+        input: UpdateCodeRepositoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateCodeRepository");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_domain`, also returning the metadata of the response.
+    pub async fn update_domain_with_metadata(
+        &self,
+        input: UpdateDomainRequest,
+    ) -> Result<(UpdateDomainResponse, ResponseMetadata), RusotoError<UpdateDomainError>> {
+        let request = self.build_update_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateCodeRepositoryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCodeRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDomainError::from_response(response))
         }
     }
 
-    /// <p>Updates a domain. Changes will impact all of the people in the domain.</p>
-    async fn update_domain(
+    /// Presigns the request `update_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDomainRequest,
-    ) -> Result<UpdateDomainResponse, RusotoError<UpdateDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_domain_request(&self, input: UpdateDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateDomain");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_endpoint`, also returning the metadata of the response.
+    pub async fn update_endpoint_with_metadata(
+        &self,
+        input: UpdateEndpointInput,
+    ) -> Result<(UpdateEndpointOutput, ResponseMetadata), RusotoError<UpdateEndpointError>> {
+        let request = self.build_update_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateEndpointOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateEndpointError::from_response(response))
         }
     }
 
-    /// <p><p>Deploys the new <code>EndpointConfig</code> specified in the request, switches to using newly created endpoint, and then deletes resources provisioned for the endpoint using the previous <code>EndpointConfig</code> (there is no availability loss). </p> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API. </p> <note> <p>You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> </note></p>
-    async fn update_endpoint(
+    /// Presigns the request `update_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEndpointInput,
-    ) -> Result<UpdateEndpointOutput, RusotoError<UpdateEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_endpoint_request(&self, input: UpdateEndpointInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateEndpoint");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_endpoint_weights_and_capacities`, also returning the metadata of the response.
+    pub async fn update_endpoint_weights_and_capacities_with_metadata(
+        &self,
+        input: UpdateEndpointWeightsAndCapacitiesInput,
+    ) -> Result<
+        (UpdateEndpointWeightsAndCapacitiesOutput, ResponseMetadata),
+        RusotoError<UpdateEndpointWeightsAndCapacitiesError>,
+    > {
+        let request = self.build_update_endpoint_weights_and_capacities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateEndpointOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateEndpointWeightsAndCapacitiesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateEndpointWeightsAndCapacitiesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Updates variant weight of one or more variants associated with an existing endpoint, or capacity of one variant associated with an existing endpoint. When it receives the request, Amazon SageMaker sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API. </p>
-    async fn update_endpoint_weights_and_capacities(
+    /// Presigns the request `update_endpoint_weights_and_capacities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_endpoint_weights_and_capacities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEndpointWeightsAndCapacitiesInput,
-    ) -> Result<
-        UpdateEndpointWeightsAndCapacitiesOutput,
-        RusotoError<UpdateEndpointWeightsAndCapacitiesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_endpoint_weights_and_capacities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_endpoint_weights_and_capacities_request(
+        &self,
+        input: UpdateEndpointWeightsAndCapacitiesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateEndpointWeightsAndCapacities");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16846,117 +21025,232 @@ impl SageMaker for SageMakerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_experiment`, also returning the metadata of the response.
+    pub async fn update_experiment_with_metadata(
+        &self,
+        input: UpdateExperimentRequest,
+    ) -> Result<(UpdateExperimentResponse, ResponseMetadata), RusotoError<UpdateExperimentError>>
+    {
+        let request = self.build_update_experiment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateEndpointWeightsAndCapacitiesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateExperimentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEndpointWeightsAndCapacitiesError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateExperimentError::from_response(response))
         }
     }
 
-    /// <p>Adds, updates, or removes the description of an experiment. Updates the display name of an experiment.</p>
-    async fn update_experiment(
+    /// Presigns the request `update_experiment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_experiment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateExperimentRequest,
-    ) -> Result<UpdateExperimentResponse, RusotoError<UpdateExperimentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_experiment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_experiment_request(&self, input: UpdateExperimentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateExperiment");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_monitoring_schedule`, also returning the metadata of the response.
+    pub async fn update_monitoring_schedule_with_metadata(
+        &self,
+        input: UpdateMonitoringScheduleRequest,
+    ) -> Result<
+        (UpdateMonitoringScheduleResponse, ResponseMetadata),
+        RusotoError<UpdateMonitoringScheduleError>,
+    > {
+        let request = self.build_update_monitoring_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateExperimentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateMonitoringScheduleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateExperimentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateMonitoringScheduleError::from_response(response))
         }
     }
 
-    /// <p>Updates a previously created schedule.</p>
-    async fn update_monitoring_schedule(
+    /// Presigns the request `update_monitoring_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_monitoring_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMonitoringScheduleRequest,
-    ) -> Result<UpdateMonitoringScheduleResponse, RusotoError<UpdateMonitoringScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_monitoring_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_monitoring_schedule_request(
+        &self,
+        input: UpdateMonitoringScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateMonitoringSchedule");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_notebook_instance`, also returning the metadata of the response.
+    pub async fn update_notebook_instance_with_metadata(
+        &self,
+        input: UpdateNotebookInstanceInput,
+    ) -> Result<
+        (UpdateNotebookInstanceOutput, ResponseMetadata),
+        RusotoError<UpdateNotebookInstanceError>,
+    > {
+        let request = self.build_update_notebook_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMonitoringScheduleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateNotebookInstanceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMonitoringScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateNotebookInstanceError::from_response(response))
         }
     }
 
-    /// <p>Updates a notebook instance. NotebookInstance updates include upgrading or downgrading the ML compute instance used for your notebook instance to accommodate changes in your workload requirements.</p>
-    async fn update_notebook_instance(
+    /// Presigns the request `update_notebook_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_notebook_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNotebookInstanceInput,
-    ) -> Result<UpdateNotebookInstanceOutput, RusotoError<UpdateNotebookInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_notebook_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_notebook_instance_request(
+        &self,
+        input: UpdateNotebookInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateNotebookInstance");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_notebook_instance_lifecycle_config`, also returning the metadata of the response.
+    pub async fn update_notebook_instance_lifecycle_config_with_metadata(
+        &self,
+        input: UpdateNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        (
+            UpdateNotebookInstanceLifecycleConfigOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateNotebookInstanceLifecycleConfigError>,
+    > {
+        let request = self.build_update_notebook_instance_lifecycle_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNotebookInstanceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateNotebookInstanceLifecycleConfigOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNotebookInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateNotebookInstanceLifecycleConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Updates a notebook instance lifecycle configuration created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
-    async fn update_notebook_instance_lifecycle_config(
+    /// Presigns the request `update_notebook_instance_lifecycle_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_notebook_instance_lifecycle_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNotebookInstanceLifecycleConfigInput,
-    ) -> Result<
-        UpdateNotebookInstanceLifecycleConfigOutput,
-        RusotoError<UpdateNotebookInstanceLifecycleConfigError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_notebook_instance_lifecycle_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_notebook_instance_lifecycle_config_request(
+        &self,
+        input: UpdateNotebookInstanceLifecycleConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateNotebookInstanceLifecycleConfig");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16966,158 +21260,3261 @@ impl SageMaker for SageMakerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_trial`, also returning the metadata of the response.
+    pub async fn update_trial_with_metadata(
+        &self,
+        input: UpdateTrialRequest,
+    ) -> Result<(UpdateTrialResponse, ResponseMetadata), RusotoError<UpdateTrialError>> {
+        let request = self.build_update_trial_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNotebookInstanceLifecycleConfigOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTrialResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNotebookInstanceLifecycleConfigError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTrialError::from_response(response))
         }
     }
 
-    /// <p>Updates the display name of a trial.</p>
-    async fn update_trial(
+    /// Presigns the request `update_trial` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_trial(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTrialRequest,
-    ) -> Result<UpdateTrialResponse, RusotoError<UpdateTrialError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_trial_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_trial_request(&self, input: UpdateTrialRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateTrial");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_trial_component`, also returning the metadata of the response.
+    pub async fn update_trial_component_with_metadata(
+        &self,
+        input: UpdateTrialComponentRequest,
+    ) -> Result<
+        (UpdateTrialComponentResponse, ResponseMetadata),
+        RusotoError<UpdateTrialComponentError>,
+    > {
+        let request = self.build_update_trial_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTrialResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTrialComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTrialError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTrialComponentError::from_response(response))
         }
     }
 
-    /// <p>Updates one or more properties of a trial component.</p>
-    async fn update_trial_component(
+    /// Presigns the request `update_trial_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_trial_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTrialComponentRequest,
-    ) -> Result<UpdateTrialComponentResponse, RusotoError<UpdateTrialComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_trial_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_trial_component_request(
+        &self,
+        input: UpdateTrialComponentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateTrialComponent");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_user_profile`, also returning the metadata of the response.
+    pub async fn update_user_profile_with_metadata(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<(UpdateUserProfileResponse, ResponseMetadata), RusotoError<UpdateUserProfileError>>
+    {
+        let request = self.build_update_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateTrialComponentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateUserProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTrialComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates a user profile.</p>
-    async fn update_user_profile(
+    /// Presigns the request `update_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserProfileRequest,
-    ) -> Result<UpdateUserProfileResponse, RusotoError<UpdateUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_profile_request(&self, input: UpdateUserProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateUserProfile");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_workforce`, also returning the metadata of the response.
+    pub async fn update_workforce_with_metadata(
+        &self,
+        input: UpdateWorkforceRequest,
+    ) -> Result<(UpdateWorkforceResponse, ResponseMetadata), RusotoError<UpdateWorkforceError>>
+    {
+        let request = self.build_update_workforce_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateUserProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateWorkforceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateWorkforceError::from_response(response))
         }
     }
 
-    /// <p><p>Restricts access to tasks assigned to workers in the specified workforce to those within specific ranges of IP addresses. You specify allowed IP addresses by creating a list of up to four <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html">CIDRs</a>.</p> <p>By default, a workforce isn&#39;t restricted to specific IP addresses. If you specify a range of IP addresses, workers who attempt to access tasks using any IP address outside the specified range are denied access and get a <code>Not Found</code> error message on the worker portal. After restricting access with this operation, you can see the allowed IP values for a private workforce with the operation.</p> <important> <p>This operation applies only to private workforces.</p> </important></p>
-    async fn update_workforce(
+    /// Presigns the request `update_workforce` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_workforce(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateWorkforceRequest,
-    ) -> Result<UpdateWorkforceResponse, RusotoError<UpdateWorkforceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_workforce_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_workforce_request(&self, input: UpdateWorkforceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateWorkforce");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateWorkforce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_workteam`, also returning the metadata of the response.
+    pub async fn update_workteam_with_metadata(
+        &self,
+        input: UpdateWorkteamRequest,
+    ) -> Result<(UpdateWorkteamResponse, ResponseMetadata), RusotoError<UpdateWorkteamError>> {
+        let request = self.build_update_workteam_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkforceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateWorkteamResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateWorkforceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateWorkteamError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing work team with new member definitions or description.</p>
-    async fn update_workteam(
+    /// Presigns the request `update_workteam` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_workteam(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateWorkteamRequest,
-    ) -> Result<UpdateWorkteamResponse, RusotoError<UpdateWorkteamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_workteam_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_workteam_request(&self, input: UpdateWorkteamRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateWorkteam");
         request.set_endpoint_prefix("api.sagemaker".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "SageMaker.UpdateWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkteamResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateWorkteamError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `ListAlgorithms` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_algorithms_pages(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> impl futures::Stream<Item = Result<ListAlgorithmsOutput, RusotoError<ListAlgorithmsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_algorithms(input).await },
+            |input: &ListAlgorithmsInput, output: &ListAlgorithmsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAlgorithmsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AlgorithmSummaryList` of every page of `ListAlgorithms` results for `input`, as `list_algorithms_pages` requests them.
+    pub fn list_algorithms_stream(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> impl futures::Stream<Item = Result<AlgorithmSummary, RusotoError<ListAlgorithmsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_algorithms_pages(input), |page| {
+            page.algorithm_summary_list
+        })
+    }
+
+    /// The pages of `ListApps` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_apps_pages(
+        &self,
+        input: ListAppsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAppsResponse, RusotoError<ListAppsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_apps(input).await },
+            |input: &ListAppsRequest, output: &ListAppsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAppsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Apps` of every page of `ListApps` results for `input`, as `list_apps_pages` requests them.
+    pub fn list_apps_stream(
+        &self,
+        input: ListAppsRequest,
+    ) -> impl futures::Stream<Item = Result<AppDetails, RusotoError<ListAppsError>>> + Send {
+        rusoto_core::pagination::items(self.list_apps_pages(input), |page| {
+            page.apps.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAutoMLJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_auto_ml_jobs_pages(
+        &self,
+        input: ListAutoMLJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAutoMLJobsResponse, RusotoError<ListAutoMLJobsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_auto_ml_jobs(input).await },
+            |input: &ListAutoMLJobsRequest, output: &ListAutoMLJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAutoMLJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AutoMLJobSummaries` of every page of `ListAutoMLJobs` results for `input`, as `list_auto_ml_jobs_pages` requests them.
+    pub fn list_auto_ml_jobs_stream(
+        &self,
+        input: ListAutoMLJobsRequest,
+    ) -> impl futures::Stream<Item = Result<AutoMLJobSummary, RusotoError<ListAutoMLJobsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_auto_ml_jobs_pages(input), |page| {
+            page.auto_ml_job_summaries
+        })
+    }
+
+    /// The pages of `ListCandidatesForAutoMLJob` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_candidates_for_auto_ml_job_pages(
+        &self,
+        input: ListCandidatesForAutoMLJobRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListCandidatesForAutoMLJobResponse,
+            RusotoError<ListCandidatesForAutoMLJobError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move {
+                client.list_candidates_for_auto_ml_job(input).await
+            },
+            |input: &ListCandidatesForAutoMLJobRequest,
+             output: &ListCandidatesForAutoMLJobResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCandidatesForAutoMLJobRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Candidates` of every page of `ListCandidatesForAutoMLJob` results for `input`, as `list_candidates_for_auto_ml_job_pages` requests them.
+    pub fn list_candidates_for_auto_ml_job_stream(
+        &self,
+        input: ListCandidatesForAutoMLJobRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AutoMLCandidate, RusotoError<ListCandidatesForAutoMLJobError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_candidates_for_auto_ml_job_pages(input), |page| {
+            page.candidates
+        })
+    }
+
+    /// The pages of `ListCodeRepositories` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_code_repositories_pages(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListCodeRepositoriesOutput, RusotoError<ListCodeRepositoriesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_code_repositories(input).await },
+            |input: &ListCodeRepositoriesInput, output: &ListCodeRepositoriesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCodeRepositoriesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CodeRepositorySummaryList` of every page of `ListCodeRepositories` results for `input`, as `list_code_repositories_pages` requests them.
+    pub fn list_code_repositories_stream(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> impl futures::Stream<
+        Item = Result<CodeRepositorySummary, RusotoError<ListCodeRepositoriesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_code_repositories_pages(input), |page| {
+            page.code_repository_summary_list
+        })
+    }
+
+    /// The pages of `ListCompilationJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_compilation_jobs_pages(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListCompilationJobsResponse, RusotoError<ListCompilationJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_compilation_jobs(input).await },
+            |input: &ListCompilationJobsRequest, output: &ListCompilationJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCompilationJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CompilationJobSummaries` of every page of `ListCompilationJobs` results for `input`, as `list_compilation_jobs_pages` requests them.
+    pub fn list_compilation_jobs_stream(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<CompilationJobSummary, RusotoError<ListCompilationJobsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_compilation_jobs_pages(input), |page| {
+            page.compilation_job_summaries
+        })
+    }
+
+    /// The pages of `ListDomains` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_domains_pages(
+        &self,
+        input: ListDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDomainsResponse, RusotoError<ListDomainsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_domains(input).await },
+            |input: &ListDomainsRequest, output: &ListDomainsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDomainsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Domains` of every page of `ListDomains` results for `input`, as `list_domains_pages` requests them.
+    pub fn list_domains_stream(
+        &self,
+        input: ListDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<DomainDetails, RusotoError<ListDomainsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_domains_pages(input), |page| {
+            page.domains.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListEndpointConfigs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_endpoint_configs_pages(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListEndpointConfigsOutput, RusotoError<ListEndpointConfigsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_endpoint_configs(input).await },
+            |input: &ListEndpointConfigsInput, output: &ListEndpointConfigsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEndpointConfigsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EndpointConfigs` of every page of `ListEndpointConfigs` results for `input`, as `list_endpoint_configs_pages` requests them.
+    pub fn list_endpoint_configs_stream(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> impl futures::Stream<
+        Item = Result<EndpointConfigSummary, RusotoError<ListEndpointConfigsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_endpoint_configs_pages(input), |page| {
+            page.endpoint_configs
+        })
+    }
+
+    /// The pages of `ListEndpoints` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_endpoints_pages(
+        &self,
+        input: ListEndpointsInput,
+    ) -> impl futures::Stream<Item = Result<ListEndpointsOutput, RusotoError<ListEndpointsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_endpoints(input).await },
+            |input: &ListEndpointsInput, output: &ListEndpointsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEndpointsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Endpoints` of every page of `ListEndpoints` results for `input`, as `list_endpoints_pages` requests them.
+    pub fn list_endpoints_stream(
+        &self,
+        input: ListEndpointsInput,
+    ) -> impl futures::Stream<Item = Result<EndpointSummary, RusotoError<ListEndpointsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_endpoints_pages(input), |page| page.endpoints)
+    }
+
+    /// The pages of `ListExperiments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_experiments_pages(
+        &self,
+        input: ListExperimentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListExperimentsResponse, RusotoError<ListExperimentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_experiments(input).await },
+            |input: &ListExperimentsRequest, output: &ListExperimentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListExperimentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ExperimentSummaries` of every page of `ListExperiments` results for `input`, as `list_experiments_pages` requests them.
+    pub fn list_experiments_stream(
+        &self,
+        input: ListExperimentsRequest,
+    ) -> impl futures::Stream<Item = Result<ExperimentSummary, RusotoError<ListExperimentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_experiments_pages(input), |page| {
+            page.experiment_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFlowDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_flow_definitions_pages(
+        &self,
+        input: ListFlowDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListFlowDefinitionsResponse, RusotoError<ListFlowDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_flow_definitions(input).await },
+            |input: &ListFlowDefinitionsRequest, output: &ListFlowDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFlowDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FlowDefinitionSummaries` of every page of `ListFlowDefinitions` results for `input`, as `list_flow_definitions_pages` requests them.
+    pub fn list_flow_definitions_stream(
+        &self,
+        input: ListFlowDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<FlowDefinitionSummary, RusotoError<ListFlowDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_flow_definitions_pages(input), |page| {
+            page.flow_definition_summaries
+        })
+    }
+
+    /// The pages of `ListHumanTaskUis` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_human_task_uis_pages(
+        &self,
+        input: ListHumanTaskUisRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListHumanTaskUisResponse, RusotoError<ListHumanTaskUisError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_human_task_uis(input).await },
+            |input: &ListHumanTaskUisRequest, output: &ListHumanTaskUisResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHumanTaskUisRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HumanTaskUiSummaries` of every page of `ListHumanTaskUis` results for `input`, as `list_human_task_uis_pages` requests them.
+    pub fn list_human_task_uis_stream(
+        &self,
+        input: ListHumanTaskUisRequest,
+    ) -> impl futures::Stream<Item = Result<HumanTaskUiSummary, RusotoError<ListHumanTaskUisError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_human_task_uis_pages(input), |page| {
+            page.human_task_ui_summaries
+        })
+    }
+
+    /// The pages of `ListHyperParameterTuningJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_hyper_parameter_tuning_jobs_pages(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListHyperParameterTuningJobsResponse,
+            RusotoError<ListHyperParameterTuningJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move {
+                client.list_hyper_parameter_tuning_jobs(input).await
+            },
+            |input: &ListHyperParameterTuningJobsRequest,
+             output: &ListHyperParameterTuningJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHyperParameterTuningJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HyperParameterTuningJobSummaries` of every page of `ListHyperParameterTuningJobs` results for `input`, as `list_hyper_parameter_tuning_jobs_pages` requests them.
+    pub fn list_hyper_parameter_tuning_jobs_stream(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            HyperParameterTuningJobSummary,
+            RusotoError<ListHyperParameterTuningJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(self.list_hyper_parameter_tuning_jobs_pages(input), |page| {
+            page.hyper_parameter_tuning_job_summaries
+        })
+    }
+
+    /// The pages of `ListLabelingJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_labeling_jobs_pages(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListLabelingJobsResponse, RusotoError<ListLabelingJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_labeling_jobs(input).await },
+            |input: &ListLabelingJobsRequest, output: &ListLabelingJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLabelingJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LabelingJobSummaryList` of every page of `ListLabelingJobs` results for `input`, as `list_labeling_jobs_pages` requests them.
+    pub fn list_labeling_jobs_stream(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> impl futures::Stream<Item = Result<LabelingJobSummary, RusotoError<ListLabelingJobsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_labeling_jobs_pages(input), |page| {
+            page.labeling_job_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLabelingJobsForWorkteam` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_labeling_jobs_for_workteam_pages(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListLabelingJobsForWorkteamResponse,
+            RusotoError<ListLabelingJobsForWorkteamError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move {
+                client.list_labeling_jobs_for_workteam(input).await
+            },
+            |input: &ListLabelingJobsForWorkteamRequest,
+             output: &ListLabelingJobsForWorkteamResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLabelingJobsForWorkteamRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LabelingJobSummaryList` of every page of `ListLabelingJobsForWorkteam` results for `input`, as `list_labeling_jobs_for_workteam_pages` requests them.
+    pub fn list_labeling_jobs_for_workteam_stream(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> impl futures::Stream<
+        Item = Result<LabelingJobForWorkteamSummary, RusotoError<ListLabelingJobsForWorkteamError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_labeling_jobs_for_workteam_pages(input), |page| {
+            page.labeling_job_summary_list
+        })
+    }
+
+    /// The pages of `ListModelPackages` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_model_packages_pages(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListModelPackagesOutput, RusotoError<ListModelPackagesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_model_packages(input).await },
+            |input: &ListModelPackagesInput, output: &ListModelPackagesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListModelPackagesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ModelPackageSummaryList` of every page of `ListModelPackages` results for `input`, as `list_model_packages_pages` requests them.
+    pub fn list_model_packages_stream(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> impl futures::Stream<Item = Result<ModelPackageSummary, RusotoError<ListModelPackagesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_model_packages_pages(input), |page| {
+            page.model_package_summary_list
+        })
+    }
+
+    /// The pages of `ListModels` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_models_pages(
+        &self,
+        input: ListModelsInput,
+    ) -> impl futures::Stream<Item = Result<ListModelsOutput, RusotoError<ListModelsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_models(input).await },
+            |input: &ListModelsInput, output: &ListModelsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListModelsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Models` of every page of `ListModels` results for `input`, as `list_models_pages` requests them.
+    pub fn list_models_stream(
+        &self,
+        input: ListModelsInput,
+    ) -> impl futures::Stream<Item = Result<ModelSummary, RusotoError<ListModelsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_models_pages(input), |page| page.models)
+    }
+
+    /// The pages of `ListMonitoringExecutions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_monitoring_executions_pages(
+        &self,
+        input: ListMonitoringExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListMonitoringExecutionsResponse, RusotoError<ListMonitoringExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move {
+                client.list_monitoring_executions(input).await
+            },
+            |input: &ListMonitoringExecutionsRequest, output: &ListMonitoringExecutionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMonitoringExecutionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `MonitoringExecutionSummaries` of every page of `ListMonitoringExecutions` results for `input`, as `list_monitoring_executions_pages` requests them.
+    pub fn list_monitoring_executions_stream(
+        &self,
+        input: ListMonitoringExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<MonitoringExecutionSummary, RusotoError<ListMonitoringExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_monitoring_executions_pages(input), |page| {
+            page.monitoring_execution_summaries
+        })
+    }
+
+    /// The pages of `ListMonitoringSchedules` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_monitoring_schedules_pages(
+        &self,
+        input: ListMonitoringSchedulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListMonitoringSchedulesResponse, RusotoError<ListMonitoringSchedulesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move {
+                client.list_monitoring_schedules(input).await
+            },
+            |input: &ListMonitoringSchedulesRequest, output: &ListMonitoringSchedulesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMonitoringSchedulesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `MonitoringScheduleSummaries` of every page of `ListMonitoringSchedules` results for `input`, as `list_monitoring_schedules_pages` requests them.
+    pub fn list_monitoring_schedules_stream(
+        &self,
+        input: ListMonitoringSchedulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<MonitoringScheduleSummary, RusotoError<ListMonitoringSchedulesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_monitoring_schedules_pages(input), |page| {
+            page.monitoring_schedule_summaries
+        })
+    }
+
+    /// The pages of `ListNotebookInstanceLifecycleConfigs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_notebook_instance_lifecycle_configs_pages(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListNotebookInstanceLifecycleConfigsOutput,
+            RusotoError<ListNotebookInstanceLifecycleConfigsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move {
+                client.list_notebook_instance_lifecycle_configs(input).await
+            },
+            |input: &ListNotebookInstanceLifecycleConfigsInput,
+             output: &ListNotebookInstanceLifecycleConfigsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListNotebookInstanceLifecycleConfigsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `NotebookInstanceLifecycleConfigs` of every page of `ListNotebookInstanceLifecycleConfigs` results for `input`, as `list_notebook_instance_lifecycle_configs_pages` requests them.
+    pub fn list_notebook_instance_lifecycle_configs_stream(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            NotebookInstanceLifecycleConfigSummary,
+            RusotoError<ListNotebookInstanceLifecycleConfigsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_notebook_instance_lifecycle_configs_pages(input),
+            |page| page.notebook_instance_lifecycle_configs.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListNotebookInstances` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_notebook_instances_pages(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListNotebookInstancesOutput, RusotoError<ListNotebookInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_notebook_instances(input).await },
+            |input: &ListNotebookInstancesInput, output: &ListNotebookInstancesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListNotebookInstancesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `NotebookInstances` of every page of `ListNotebookInstances` results for `input`, as `list_notebook_instances_pages` requests them.
+    pub fn list_notebook_instances_stream(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> impl futures::Stream<
+        Item = Result<NotebookInstanceSummary, RusotoError<ListNotebookInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_notebook_instances_pages(input), |page| {
+            page.notebook_instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListProcessingJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_processing_jobs_pages(
+        &self,
+        input: ListProcessingJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListProcessingJobsResponse, RusotoError<ListProcessingJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_processing_jobs(input).await },
+            |input: &ListProcessingJobsRequest, output: &ListProcessingJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListProcessingJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProcessingJobSummaries` of every page of `ListProcessingJobs` results for `input`, as `list_processing_jobs_pages` requests them.
+    pub fn list_processing_jobs_stream(
+        &self,
+        input: ListProcessingJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ProcessingJobSummary, RusotoError<ListProcessingJobsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_processing_jobs_pages(input), |page| {
+            page.processing_job_summaries
+        })
+    }
+
+    /// The pages of `ListSubscribedWorkteams` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_subscribed_workteams_pages(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListSubscribedWorkteamsResponse, RusotoError<ListSubscribedWorkteamsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move {
+                client.list_subscribed_workteams(input).await
+            },
+            |input: &ListSubscribedWorkteamsRequest, output: &ListSubscribedWorkteamsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSubscribedWorkteamsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SubscribedWorkteams` of every page of `ListSubscribedWorkteams` results for `input`, as `list_subscribed_workteams_pages` requests them.
+    pub fn list_subscribed_workteams_stream(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SubscribedWorkteam, RusotoError<ListSubscribedWorkteamsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_subscribed_workteams_pages(input), |page| {
+            page.subscribed_workteams
+        })
+    }
+
+    /// The pages of `ListTags` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsInput,
+    ) -> impl futures::Stream<Item = Result<ListTagsOutput, RusotoError<ListTagsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_tags(input).await },
+            |input: &ListTagsInput, output: &ListTagsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTags` results for `input`, as `list_tags_pages` requests them.
+    pub fn list_tags_stream(
+        &self,
+        input: ListTagsInput,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsError>>> + Send {
+        rusoto_core::pagination::items(self.list_tags_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTrainingJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_training_jobs_pages(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTrainingJobsResponse, RusotoError<ListTrainingJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_training_jobs(input).await },
+            |input: &ListTrainingJobsRequest, output: &ListTrainingJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTrainingJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TrainingJobSummaries` of every page of `ListTrainingJobs` results for `input`, as `list_training_jobs_pages` requests them.
+    pub fn list_training_jobs_stream(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> impl futures::Stream<Item = Result<TrainingJobSummary, RusotoError<ListTrainingJobsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_training_jobs_pages(input), |page| {
+            page.training_job_summaries
+        })
+    }
+
+    /// The pages of `ListTrainingJobsForHyperParameterTuningJob` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_training_jobs_for_hyper_parameter_tuning_job_pages(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListTrainingJobsForHyperParameterTuningJobResponse,
+            RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move {
+                client
+                    .list_training_jobs_for_hyper_parameter_tuning_job(input)
+                    .await
+            },
+            |input: &ListTrainingJobsForHyperParameterTuningJobRequest,
+             output: &ListTrainingJobsForHyperParameterTuningJobResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTrainingJobsForHyperParameterTuningJobRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TrainingJobSummaries` of every page of `ListTrainingJobsForHyperParameterTuningJob` results for `input`, as `list_training_jobs_for_hyper_parameter_tuning_job_pages` requests them.
+    pub fn list_training_jobs_for_hyper_parameter_tuning_job_stream(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            HyperParameterTrainingJobSummary,
+            RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_training_jobs_for_hyper_parameter_tuning_job_pages(input),
+            |page| page.training_job_summaries,
+        )
+    }
+
+    /// The pages of `ListTransformJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_transform_jobs_pages(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTransformJobsResponse, RusotoError<ListTransformJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_transform_jobs(input).await },
+            |input: &ListTransformJobsRequest, output: &ListTransformJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTransformJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TransformJobSummaries` of every page of `ListTransformJobs` results for `input`, as `list_transform_jobs_pages` requests them.
+    pub fn list_transform_jobs_stream(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> impl futures::Stream<Item = Result<TransformJobSummary, RusotoError<ListTransformJobsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_transform_jobs_pages(input), |page| {
+            page.transform_job_summaries
+        })
+    }
+
+    /// The pages of `ListTrialComponents` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_trial_components_pages(
+        &self,
+        input: ListTrialComponentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTrialComponentsResponse, RusotoError<ListTrialComponentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_trial_components(input).await },
+            |input: &ListTrialComponentsRequest, output: &ListTrialComponentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTrialComponentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TrialComponentSummaries` of every page of `ListTrialComponents` results for `input`, as `list_trial_components_pages` requests them.
+    pub fn list_trial_components_stream(
+        &self,
+        input: ListTrialComponentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<TrialComponentSummary, RusotoError<ListTrialComponentsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_trial_components_pages(input), |page| {
+            page.trial_component_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTrials` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_trials_pages(
+        &self,
+        input: ListTrialsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTrialsResponse, RusotoError<ListTrialsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_trials(input).await },
+            |input: &ListTrialsRequest, output: &ListTrialsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTrialsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TrialSummaries` of every page of `ListTrials` results for `input`, as `list_trials_pages` requests them.
+    pub fn list_trials_stream(
+        &self,
+        input: ListTrialsRequest,
+    ) -> impl futures::Stream<Item = Result<TrialSummary, RusotoError<ListTrialsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_trials_pages(input), |page| {
+            page.trial_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUserProfiles` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_user_profiles_pages(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListUserProfilesResponse, RusotoError<ListUserProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_user_profiles(input).await },
+            |input: &ListUserProfilesRequest, output: &ListUserProfilesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUserProfilesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UserProfiles` of every page of `ListUserProfiles` results for `input`, as `list_user_profiles_pages` requests them.
+    pub fn list_user_profiles_stream(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<UserProfileDetails, RusotoError<ListUserProfilesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_user_profiles_pages(input), |page| {
+            page.user_profiles.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListWorkteams` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_workteams_pages(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> impl futures::Stream<Item = Result<ListWorkteamsResponse, RusotoError<ListWorkteamsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.list_workteams(input).await },
+            |input: &ListWorkteamsRequest, output: &ListWorkteamsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListWorkteamsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Workteams` of every page of `ListWorkteams` results for `input`, as `list_workteams_pages` requests them.
+    pub fn list_workteams_stream(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> impl futures::Stream<Item = Result<Workteam, RusotoError<ListWorkteamsError>>> + Send {
+        rusoto_core::pagination::items(self.list_workteams_pages(input), |page| page.workteams)
+    }
+
+    /// The pages of `Search` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn search_pages(
+        &self,
+        input: SearchRequest,
+    ) -> impl futures::Stream<Item = Result<SearchResponse, RusotoError<SearchError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SageMakerClient, input| async move { client.search(input).await },
+            |input: &SearchRequest, output: &SearchResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Results` of every page of `Search` results for `input`, as `search_pages` requests them.
+    pub fn search_stream(
+        &self,
+        input: SearchRequest,
+    ) -> impl futures::Stream<Item = Result<SearchRecord, RusotoError<SearchError>>> + Send {
+        rusoto_core::pagination::items(self.search_pages(input), |page| {
+            page.results.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `EndpointDeleted`, polling `DescribeEndpoint` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_endpoint_deleted(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEndpointError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_endpoint_deleted_with(input, &waiter).await
+    }
+
+    /// Waits for `EndpointDeleted` like `wait_until_endpoint_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_endpoint_deleted_with(
+        &self,
+        input: DescribeEndpointInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEndpointError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_endpoint(input.clone()),
+            |result| {
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.endpoint_status)
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `EndpointInService`, polling `DescribeEndpoint` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_endpoint_in_service(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEndpointError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_endpoint_in_service_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `EndpointInService` like `wait_until_endpoint_in_service`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_endpoint_in_service_with(
+        &self,
+        input: DescribeEndpointInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEndpointError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_endpoint(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.endpoint_status)
+                        .any(|value| value == "InService"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.endpoint_status)
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `NotebookInstanceDeleted`, polling `DescribeNotebookInstance` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_notebook_instance_deleted(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNotebookInstanceError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_notebook_instance_deleted_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `NotebookInstanceDeleted` like `wait_until_notebook_instance_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_notebook_instance_deleted_with(
+        &self,
+        input: DescribeNotebookInstanceInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNotebookInstanceError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_notebook_instance(input.clone()),
+            |result| {
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.notebook_instance_status.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `NotebookInstanceInService`, polling `DescribeNotebookInstance` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_notebook_instance_in_service(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNotebookInstanceError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_notebook_instance_in_service_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `NotebookInstanceInService` like `wait_until_notebook_instance_in_service`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_notebook_instance_in_service_with(
+        &self,
+        input: DescribeNotebookInstanceInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNotebookInstanceError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_notebook_instance(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.notebook_instance_status.as_ref())
+                        .any(|value| value == "InService"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.notebook_instance_status.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `NotebookInstanceStopped`, polling `DescribeNotebookInstance` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_notebook_instance_stopped(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNotebookInstanceError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_notebook_instance_stopped_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `NotebookInstanceStopped` like `wait_until_notebook_instance_stopped`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_notebook_instance_stopped_with(
+        &self,
+        input: DescribeNotebookInstanceInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNotebookInstanceError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_notebook_instance(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.notebook_instance_status.as_ref())
+                        .any(|value| value == "Stopped"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.notebook_instance_status.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ProcessingJobCompletedOrStopped`, polling `DescribeProcessingJob` with `input` every 60 seconds, up to 60 times.
+    pub async fn wait_until_processing_job_completed_or_stopped(
+        &self,
+        input: DescribeProcessingJobRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProcessingJobError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(60), 60);
+        self.wait_until_processing_job_completed_or_stopped_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ProcessingJobCompletedOrStopped` like `wait_until_processing_job_completed_or_stopped`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_processing_job_completed_or_stopped_with(
+        &self,
+        input: DescribeProcessingJobRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeProcessingJobError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_processing_job(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.processing_job_status)
+                        .any(|value| value == "Completed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.processing_job_status)
+                        .any(|value| value == "Stopped"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.processing_job_status)
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TrainingJobCompletedOrStopped`, polling `DescribeTrainingJob` with `input` every 120 seconds, up to 180 times.
+    pub async fn wait_until_training_job_completed_or_stopped(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTrainingJobError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(120), 180);
+        self.wait_until_training_job_completed_or_stopped_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `TrainingJobCompletedOrStopped` like `wait_until_training_job_completed_or_stopped`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_training_job_completed_or_stopped_with(
+        &self,
+        input: DescribeTrainingJobRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTrainingJobError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_training_job(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.training_job_status)
+                        .any(|value| value == "Completed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.training_job_status)
+                        .any(|value| value == "Stopped"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.training_job_status)
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TransformJobCompletedOrStopped`, polling `DescribeTransformJob` with `input` every 60 seconds, up to 60 times.
+    pub async fn wait_until_transform_job_completed_or_stopped(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTransformJobError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(60), 60);
+        self.wait_until_transform_job_completed_or_stopped_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `TransformJobCompletedOrStopped` like `wait_until_transform_job_completed_or_stopped`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_transform_job_completed_or_stopped_with(
+        &self,
+        input: DescribeTransformJobRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTransformJobError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_transform_job(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.transform_job_status)
+                        .any(|value| value == "Completed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.transform_job_status)
+                        .any(|value| value == "Stopped"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.transform_job_status)
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl SageMaker for SageMakerClient {
+    /// <p><p>Adds or overwrites one or more tags for the specified Amazon SageMaker resource. You can add tags to notebook instances, training jobs, hyperparameter tuning jobs, batch transform jobs, models, labeling jobs, work teams, endpoint configurations, and endpoints.</p> <p>Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see For more information, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>.</p> <note> <p>Tags that you add to a hyperparameter tuning job by calling this API are also added to any training jobs that the hyperparameter tuning job launches after you call this API, but not to training jobs that the hyperparameter tuning job launched before you called this API. To make sure that the tags associated with a hyperparameter tuning job are also added to all training jobs that the hyperparameter tuning job launches, add the tags when you first create the tuning job by specifying them in the <code>Tags</code> parameter of <a>CreateHyperParameterTuningJob</a> </p> </note></p>
+    async fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.add_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a trial component with a trial. A trial component can be associated with multiple trials. To disassociate a trial component from a trial, call the <a>DisassociateTrialComponent</a> API.</p>
+    async fn associate_trial_component(
+        &self,
+        input: AssociateTrialComponentRequest,
+    ) -> Result<AssociateTrialComponentResponse, RusotoError<AssociateTrialComponentError>> {
+        self.associate_trial_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a machine learning algorithm that you can use in Amazon SageMaker and list in the AWS Marketplace.</p>
+    async fn create_algorithm(
+        &self,
+        input: CreateAlgorithmInput,
+    ) -> Result<CreateAlgorithmOutput, RusotoError<CreateAlgorithmError>> {
+        self.create_algorithm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a running App for the specified UserProfile. Supported Apps are JupyterServer and KernelGateway. This operation is automatically invoked by Amazon SageMaker Amazon SageMaker Studio (Studio) upon access to the associated Studio Domain, and when new kernel configurations are selected by the user. A user may have multiple Apps active simultaneously. Apps will automatically terminate and be deleted when stopped from within Studio, or when the DeleteApp API is manually called. UserProfiles are limited to 5 concurrently running Apps at a time.</p>
+    async fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
+        self.create_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an AutoPilot job.</p>
+    async fn create_auto_ml_job(
+        &self,
+        input: CreateAutoMLJobRequest,
+    ) -> Result<CreateAutoMLJobResponse, RusotoError<CreateAutoMLJobError>> {
+        self.create_auto_ml_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Git repository as a resource in your Amazon SageMaker account. You can associate the repository with notebook instances so that you can use Git source control for the notebooks you create. The Git repository is a resource in your Amazon SageMaker account, so it can be associated with more than one notebook instance, and it persists independently from the lifecycle of any notebook instances it is associated with.</p> <p>The repository can be hosted either in <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/welcome.html">AWS CodeCommit</a> or in any other Git repository.</p>
+    async fn create_code_repository(
+        &self,
+        input: CreateCodeRepositoryInput,
+    ) -> Result<CreateCodeRepositoryOutput, RusotoError<CreateCodeRepositoryError>> {
+        self.create_code_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a model compilation job. After the model has been compiled, Amazon SageMaker saves the resulting model artifacts to an Amazon Simple Storage Service (Amazon S3) bucket that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts with AWS IoT Greengrass. In that case, deploy them as an ML resource.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p>A name for the compilation job</p> </li> <li> <p> Information about the input model artifacts </p> </li> <li> <p>The output location for the compiled model and the device (target) that the model runs on </p> </li> <li> <p> <code>The Amazon Resource Name (ARN) of the IAM role that Amazon SageMaker assumes to perform the model compilation job</code> </p> </li> </ul> <p>You can also provide a <code>Tag</code> to track the model compilation job's resource use and costs. The response body contains the <code>CompilationJobArn</code> for the compiled job.</p> <p>To stop a model compilation job, use <a>StopCompilationJob</a>. To get information about a particular model compilation job, use <a>DescribeCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
+    async fn create_compilation_job(
+        &self,
+        input: CreateCompilationJobRequest,
+    ) -> Result<CreateCompilationJobResponse, RusotoError<CreateCompilationJobError>> {
+        self.create_compilation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Domain for Amazon SageMaker Amazon SageMaker Studio (Studio), which can be accessed by end-users in a web browser. A Domain has an associated directory, list of authorized users, and a variety of security, application, policies, and Amazon Virtual Private Cloud configurations. An AWS account is limited to one Domain, per region. Users within a domain can share notebook files and other artifacts with each other. When a Domain is created, an Amazon Elastic File System (EFS) is also created for use by all of the users within the Domain. Each user receives a private home directory within the EFS for notebooks, Git repositories, and data files. </p>
+    async fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
+        self.create_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an endpoint using the endpoint configuration specified in the request. Amazon SageMaker uses the endpoint to provision resources and deploy models. You create the endpoint configuration with the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpointConfig.html">CreateEndpointConfig</a> API. </p> <note> <p> Use this API only for hosting models using Amazon SageMaker hosting services. </p> <p> You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> </note> <p>The endpoint name must be unique within an AWS Region in your AWS account. </p> <p>When it receives the request, Amazon SageMaker creates the endpoint, launches the resources (ML compute instances), and deploys the model(s) on them. </p> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Creating</code>. After it creates the endpoint, it sets the status to <code>InService</code>. Amazon SageMaker can then process incoming requests for inferences. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API.</p> <p>For an example, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/ex1.html">Exercise 1: Using the K-Means Algorithm Provided by Amazon SageMaker</a>. </p> <p>If any of the models hosted at this endpoint get model data from an Amazon S3 location, Amazon SageMaker uses AWS Security Token Service to download model artifacts from the S3 path you provided. AWS STS is activated in your IAM user account by default. If you previously deactivated AWS STS for a region, you need to reactivate AWS STS for that region. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide</i>.</p>
+    async fn create_endpoint(
+        &self,
+        input: CreateEndpointInput,
+    ) -> Result<CreateEndpointOutput, RusotoError<CreateEndpointError>> {
+        self.create_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an endpoint configuration that Amazon SageMaker hosting services uses to deploy models. In the configuration, you identify one or more models, created using the <code>CreateModel</code> API, to deploy and the resources that you want Amazon SageMaker to provision. Then you call the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpoint.html">CreateEndpoint</a> API.</p> <note> <p> Use this API only if you want to use Amazon SageMaker hosting services to deploy models into production. </p> </note> <p>In the request, you define one or more <code>ProductionVariant</code>s, each of which identifies a model. Each <code>ProductionVariant</code> parameter also describes the resources that you want Amazon SageMaker to provision. This includes the number and type of ML compute instances to deploy. </p> <p>If you are hosting multiple models, you also assign a <code>VariantWeight</code> to specify how much traffic you want to allocate to each model. For example, suppose that you want to host two models, A and B, and you assign traffic weight 2 for model A and 1 for model B. Amazon SageMaker distributes two-thirds of the traffic to Model A, and one-third to model B. </p>
+    async fn create_endpoint_config(
+        &self,
+        input: CreateEndpointConfigInput,
+    ) -> Result<CreateEndpointConfigOutput, RusotoError<CreateEndpointConfigError>> {
+        self.create_endpoint_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon SageMaker <i>experiment</i>. An experiment is a collection of <i>trials</i> that are observed, compared and evaluated as a group. A trial is a set of steps, called <i>trial components</i>, that produce a machine learning model.</p> <p>The goal of an experiment is to determine the components that produce the best model. Multiple trials are performed, each one isolating and measuring the impact of a change to one or more inputs, while keeping the remaining inputs constant.</p> <p>When you use Amazon SageMaker Studio or the Amazon SageMaker Python SDK, all experiments, trials, and trial components are automatically tracked, logged, and indexed. When you use the AWS SDK for Python (Boto), you must use the logging APIs provided by the SDK.</p> <p>You can add tags to experiments, trials, trial components and then use the <a>Search</a> API to search for the tags.</p> <p>To add a description to an experiment, specify the optional <code>Description</code> parameter. To add a description later, or to change the description, call the <a>UpdateExperiment</a> API.</p> <p>To get a list of all your experiments, call the <a>ListExperiments</a> API. To view an experiment's properties, call the <a>DescribeExperiment</a> API. To get a list of all the trials associated with an experiment, call the <a>ListTrials</a> API. To create a trial call the <a>CreateTrial</a> API.</p>
+    async fn create_experiment(
+        &self,
+        input: CreateExperimentRequest,
+    ) -> Result<CreateExperimentResponse, RusotoError<CreateExperimentError>> {
+        self.create_experiment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a flow definition.</p>
+    async fn create_flow_definition(
+        &self,
+        input: CreateFlowDefinitionRequest,
+    ) -> Result<CreateFlowDefinitionResponse, RusotoError<CreateFlowDefinitionError>> {
+        self.create_flow_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Defines the settings you will use for the human review workflow user interface. Reviewers will see a three-panel interface with an instruction area, the item to review, and an input area.</p>
+    async fn create_human_task_ui(
+        &self,
+        input: CreateHumanTaskUiRequest,
+    ) -> Result<CreateHumanTaskUiResponse, RusotoError<CreateHumanTaskUiError>> {
+        self.create_human_task_ui_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a hyperparameter tuning job. A hyperparameter tuning job finds the best version of a model by running many training jobs on your dataset using the algorithm you choose and values for hyperparameters within ranges that you specify. It then chooses the hyperparameter values that result in a model that performs the best, as measured by an objective metric that you choose.</p>
+    async fn create_hyper_parameter_tuning_job(
+        &self,
+        input: CreateHyperParameterTuningJobRequest,
+    ) -> Result<
+        CreateHyperParameterTuningJobResponse,
+        RusotoError<CreateHyperParameterTuningJobError>,
+    > {
+        self.create_hyper_parameter_tuning_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a job that uses workers to label the data objects in your input dataset. You can use the labeled data to train machine learning models.</p> <p>You can select your workforce from one of three providers:</p> <ul> <li> <p>A private workforce that you create. It can include employees, contractors, and outside experts. Use a private workforce when want the data to stay within your organization or when a specific set of skills is required.</p> </li> <li> <p>One or more vendors that you select from the AWS Marketplace. Vendors provide expertise in specific areas. </p> </li> <li> <p>The Amazon Mechanical Turk workforce. This is the largest workforce, but it should only be used for public data or data that has been stripped of any personally identifiable information.</p> </li> </ul> <p>You can also use <i>automated data labeling</i> to reduce the number of data objects that need to be labeled by a human. Automated data labeling uses <i>active learning</i> to determine if a data object can be labeled by machine or if it needs to be sent to a human worker. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-automated-labeling.html">Using Automated Data Labeling</a>.</p> <p>The data objects to be labeled are contained in an Amazon S3 bucket. You create a <i>manifest file</i> that describes the location of each object. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-data.html">Using Input and Output Data</a>.</p> <p>The output can be used as the manifest file for another labeling job or as training data for your machine learning models.</p>
+    async fn create_labeling_job(
+        &self,
+        input: CreateLabelingJobRequest,
+    ) -> Result<CreateLabelingJobResponse, RusotoError<CreateLabelingJobError>> {
+        self.create_labeling_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a model in Amazon SageMaker. In the request, you name the model and describe a primary container. For the primary container, you specify the docker image containing inference code, artifacts (from prior training), and custom environment map that the inference code uses when you deploy the model for predictions.</p> <p>Use this API to create a model if you want to use Amazon SageMaker hosting services or run a batch transform job.</p> <p>To host your model, you create an endpoint configuration with the <code>CreateEndpointConfig</code> API, and then create an endpoint with the <code>CreateEndpoint</code> API. Amazon SageMaker then deploys all of the containers that you defined for the model in the hosting environment. </p> <p>To run a batch transform using your model, you start a job with the <code>CreateTransformJob</code> API. Amazon SageMaker uses your model and your dataset to get inferences which are then saved to a specified S3 location.</p> <p>In the <code>CreateModel</code> request, you must define a container with the <code>PrimaryContainer</code> parameter.</p> <p>In the request, you also provide an IAM role that Amazon SageMaker can assume to access model artifacts and docker image for deployment on ML compute hosting instances or for batch transform jobs. In addition, you also use the IAM role to manage permissions the inference code needs. For example, if the inference code access any other AWS resources, you grant necessary permissions via this role.</p>
+    async fn create_model(
+        &self,
+        input: CreateModelInput,
+    ) -> Result<CreateModelOutput, RusotoError<CreateModelError>> {
+        self.create_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a model package that you can use to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p> <p>To create a model package by specifying a Docker container that contains your inference code and the Amazon S3 location of your model artifacts, provide values for <code>InferenceSpecification</code>. To create a model from an algorithm resource that you created or subscribed to in AWS Marketplace, provide a value for <code>SourceAlgorithmSpecification</code>.</p>
+    async fn create_model_package(
+        &self,
+        input: CreateModelPackageInput,
+    ) -> Result<CreateModelPackageOutput, RusotoError<CreateModelPackageError>> {
+        self.create_model_package_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a schedule that regularly starts Amazon SageMaker Processing Jobs to monitor the data captured for an Amazon SageMaker Endoint.</p>
+    async fn create_monitoring_schedule(
+        &self,
+        input: CreateMonitoringScheduleRequest,
+    ) -> Result<CreateMonitoringScheduleResponse, RusotoError<CreateMonitoringScheduleError>> {
+        self.create_monitoring_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon SageMaker notebook instance. A notebook instance is a machine learning (ML) compute instance running on a Jupyter notebook. </p> <p>In a <code>CreateNotebookInstance</code> request, specify the type of ML compute instance that you want to run. Amazon SageMaker launches the instance, installs common libraries that you can use to explore datasets for model training, and attaches an ML storage volume to the notebook instance. </p> <p>Amazon SageMaker also provides a set of example notebooks. Each notebook demonstrates how to use Amazon SageMaker with a specific algorithm or with a machine learning framework. </p> <p>After receiving the request, Amazon SageMaker does the following:</p> <ol> <li> <p>Creates a network interface in the Amazon SageMaker VPC.</p> </li> <li> <p>(Option) If you specified <code>SubnetId</code>, Amazon SageMaker creates a network interface in your own VPC, which is inferred from the subnet ID that you provide in the input. When creating this network interface, Amazon SageMaker attaches the security group that you specified in the request to the network interface that it creates in your VPC.</p> </li> <li> <p>Launches an EC2 instance of the type specified in the request in the Amazon SageMaker VPC. If you specified <code>SubnetId</code> of your VPC, Amazon SageMaker specifies both network interfaces when launching this instance. This enables inbound traffic from your own VPC to the notebook instance, assuming that the security groups allow it.</p> </li> </ol> <p>After creating the notebook instance, Amazon SageMaker returns its Amazon Resource Name (ARN). You can't change the name of a notebook instance after you create it.</p> <p>After Amazon SageMaker creates the notebook instance, you can connect to the Jupyter server and work in Jupyter notebooks. For example, you can write code to explore a dataset that you can use for model training, train a model, host models by creating Amazon SageMaker endpoints, and validate hosted models. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
+    async fn create_notebook_instance(
+        &self,
+        input: CreateNotebookInstanceInput,
+    ) -> Result<CreateNotebookInstanceOutput, RusotoError<CreateNotebookInstanceError>> {
+        self.create_notebook_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a lifecycle configuration that you can associate with a notebook instance. A <i>lifecycle configuration</i> is a collection of shell scripts that run when you create or start a notebook instance.</p> <p>Each lifecycle configuration script has a limit of 16384 characters.</p> <p>The value of the <code>$PATH</code> environment variable that is available to both scripts is <code>/sbin:bin:/usr/sbin:/usr/bin</code>.</p> <p>View CloudWatch Logs for notebook instance lifecycle configurations in log group <code>/aws/sagemaker/NotebookInstances</code> in log stream <code>[notebook-instance-name]/[LifecycleConfigHook]</code>.</p> <p>Lifecycle configuration scripts cannot run for longer than 5 minutes. If a script runs for longer than 5 minutes, it fails and the notebook instance is not created or started.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
+    async fn create_notebook_instance_lifecycle_config(
+        &self,
+        input: CreateNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        CreateNotebookInstanceLifecycleConfigOutput,
+        RusotoError<CreateNotebookInstanceLifecycleConfigError>,
+    > {
+        self.create_notebook_instance_lifecycle_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a URL for a specified UserProfile in a Domain. When accessed in a web browser, the user will be automatically signed in to Amazon SageMaker Amazon SageMaker Studio (Studio), and granted access to all of the Apps and files associated with that Amazon Elastic File System (EFS). This operation can only be called when AuthMode equals IAM. </p>
+    async fn create_presigned_domain_url(
+        &self,
+        input: CreatePresignedDomainUrlRequest,
+    ) -> Result<CreatePresignedDomainUrlResponse, RusotoError<CreatePresignedDomainUrlError>> {
+        self.create_presigned_domain_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a URL that you can use to connect to the Jupyter server from a notebook instance. In the Amazon SageMaker console, when you choose <code>Open</code> next to a notebook instance, Amazon SageMaker opens a new tab showing the Jupyter server home page from the notebook instance. The console uses this API to get the URL and show the page.</p> <p>IAM authorization policies for this API are also enforced for every HTTP request and WebSocket frame that attempts to connect to the notebook instance.For example, you can restrict access to this API and to the URL that it returns to a list of IP addresses that you specify. Use the <code>NotIpAddress</code> condition operator and the <code>aws:SourceIP</code> condition context key to specify the list of IP addresses that you want to have access to the notebook instance. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/security_iam_id-based-policy-examples.html#nbi-ip-filter">Limit Access to a Notebook Instance by IP Address</a>.</p> <note> <p>The URL that you get from a call to is valid only for 5 minutes. If you try to use the URL after the 5-minute limit expires, you are directed to the AWS console sign-in page.</p> </note></p>
+    async fn create_presigned_notebook_instance_url(
+        &self,
+        input: CreatePresignedNotebookInstanceUrlInput,
+    ) -> Result<
+        CreatePresignedNotebookInstanceUrlOutput,
+        RusotoError<CreatePresignedNotebookInstanceUrlError>,
+    > {
+        self.create_presigned_notebook_instance_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a processing job.</p>
+    async fn create_processing_job(
+        &self,
+        input: CreateProcessingJobRequest,
+    ) -> Result<CreateProcessingJobResponse, RusotoError<CreateProcessingJobError>> {
+        self.create_processing_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a model training job. After training completes, Amazon SageMaker saves the resulting model artifacts to an Amazon S3 location that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts in a machine learning service other than Amazon SageMaker, provided that you know how to use them for inferences. </p> <p>In the request body, you provide the following: </p> <ul> <li> <p> <code>AlgorithmSpecification</code> - Identifies the training algorithm to use. </p> </li> <li> <p> <code>HyperParameters</code> - Specify these algorithm-specific parameters to enable the estimation of model parameters during training. Hyperparameters can be tuned to optimize this learning process. For a list of hyperparameters for each training algorithm provided by Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/algos.html">Algorithms</a>. </p> </li> <li> <p> <code>InputDataConfig</code> - Describes the training dataset and the Amazon S3, EFS, or FSx location where it is stored.</p> </li> <li> <p> <code>OutputDataConfig</code> - Identifies the Amazon S3 bucket where you want Amazon SageMaker to save the results of model training. </p> <p/> </li> <li> <p> <code>ResourceConfig</code> - Identifies the resources, ML compute instances, and ML storage volumes to deploy for model training. In distributed training, you specify more than one instance. </p> </li> <li> <p> <code>EnableManagedSpotTraining</code> - Optimize the cost of training machine learning models by up to 80% by using Amazon EC2 Spot instances. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/model-managed-spot-training.html">Managed Spot Training</a>. </p> </li> <li> <p> <code>RoleARN</code> - The Amazon Resource Number (ARN) that Amazon SageMaker assumes to perform tasks on your behalf during model training. You must grant this role the necessary permissions so that Amazon SageMaker can successfully complete model training. </p> </li> <li> <p> <code>StoppingCondition</code> - To help cap training costs, use <code>MaxRuntimeInSeconds</code> to set a time limit for training. Use <code>MaxWaitTimeInSeconds</code> to specify how long you are willing to wait for a managed spot training job to complete. </p> </li> </ul> <p> For more information about Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
+    async fn create_training_job(
+        &self,
+        input: CreateTrainingJobRequest,
+    ) -> Result<CreateTrainingJobResponse, RusotoError<CreateTrainingJobError>> {
+        self.create_training_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a transform job. A transform job uses a trained model to get inferences on a dataset and saves these results to an Amazon S3 location that you specify.</p> <p>To perform batch transformations, you create a transform job and use the data that you have readily available.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p> <code>TransformJobName</code> - Identifies the transform job. The name must be unique within an AWS Region in an AWS account.</p> </li> <li> <p> <code>ModelName</code> - Identifies the model to use. <code>ModelName</code> must be the name of an existing Amazon SageMaker model in the same AWS Region and AWS account. For information on creating a model, see <a>CreateModel</a>.</p> </li> <li> <p> <code>TransformInput</code> - Describes the dataset to be transformed and the Amazon S3 location where it is stored.</p> </li> <li> <p> <code>TransformOutput</code> - Identifies the Amazon S3 location where you want Amazon SageMaker to save the results from the transform job.</p> </li> <li> <p> <code>TransformResources</code> - Identifies the ML compute instances for the transform job.</p> </li> </ul> <p>For more information about how batch transformation works, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/batch-transform.html">Batch Transform</a>.</p>
+    async fn create_transform_job(
+        &self,
+        input: CreateTransformJobRequest,
+    ) -> Result<CreateTransformJobResponse, RusotoError<CreateTransformJobError>> {
+        self.create_transform_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon SageMaker <i>trial</i>. A trial is a set of steps called <i>trial components</i> that produce a machine learning model. A trial is part of a single Amazon SageMaker <i>experiment</i>.</p> <p>When you use Amazon SageMaker Studio or the Amazon SageMaker Python SDK, all experiments, trials, and trial components are automatically tracked, logged, and indexed. When you use the AWS SDK for Python (Boto), you must use the logging APIs provided by the SDK.</p> <p>You can add tags to a trial and then use the <a>Search</a> API to search for the tags.</p> <p>To get a list of all your trials, call the <a>ListTrials</a> API. To view a trial's properties, call the <a>DescribeTrial</a> API. To create a trial component, call the <a>CreateTrialComponent</a> API.</p>
+    async fn create_trial(
+        &self,
+        input: CreateTrialRequest,
+    ) -> Result<CreateTrialResponse, RusotoError<CreateTrialError>> {
+        self.create_trial_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a <i>trial component</i>, which is a stage of a machine learning <i>trial</i>. A trial is composed of one or more trial components. A trial component can be used in multiple trials.</p> <p>Trial components include pre-processing jobs, training jobs, and batch transform jobs.</p> <p>When you use Amazon SageMaker Studio or the Amazon SageMaker Python SDK, all experiments, trials, and trial components are automatically tracked, logged, and indexed. When you use the AWS SDK for Python (Boto), you must use the logging APIs provided by the SDK.</p> <p>You can add tags to a trial component and then use the <a>Search</a> API to search for the tags.</p> <note> <p> <code>CreateTrialComponent</code> can only be invoked from within an Amazon SageMaker managed environment. This includes Amazon SageMaker training jobs, processing jobs, transform jobs, and Amazon SageMaker notebooks. A call to <code>CreateTrialComponent</code> from outside one of these environments results in an error.</p> </note></p>
+    async fn create_trial_component(
+        &self,
+        input: CreateTrialComponentRequest,
+    ) -> Result<CreateTrialComponentResponse, RusotoError<CreateTrialComponentError>> {
+        self.create_trial_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new user profile. A user profile represents a single user within a Domain, and is the main way to reference a "person" for the purposes of sharing, reporting and other user-oriented features. This entity is created during on-boarding. If an administrator invites a person by email or imports them from SSO, a new UserProfile is automatically created. This entity is the primary holder of settings for an individual user and has a reference to the user's private Amazon Elastic File System (EFS) home directory. </p>
+    async fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<CreateUserProfileResponse, RusotoError<CreateUserProfileError>> {
+        self.create_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new work team for labeling your data. A work team is defined by one or more Amazon Cognito user pools. You must first create the user pools before you can create a work team.</p> <p>You cannot create more than 25 work teams in an account and region.</p>
+    async fn create_workteam(
+        &self,
+        input: CreateWorkteamRequest,
+    ) -> Result<CreateWorkteamResponse, RusotoError<CreateWorkteamError>> {
+        self.create_workteam_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified algorithm from your account.</p>
+    async fn delete_algorithm(
+        &self,
+        input: DeleteAlgorithmInput,
+    ) -> Result<(), RusotoError<DeleteAlgorithmError>> {
+        self.delete_algorithm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used to stop and delete an app.</p>
+    async fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
+        self.delete_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Git repository from your account.</p>
+    async fn delete_code_repository(
+        &self,
+        input: DeleteCodeRepositoryInput,
+    ) -> Result<(), RusotoError<DeleteCodeRepositoryError>> {
+        self.delete_code_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used to delete a domain. If you on-boarded with IAM mode, you will need to delete your domain to on-board again using SSO. Use with caution. All of the members of the domain will lose access to their EFS volume, including data, notebooks, and other artifacts. </p>
+    async fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<(), RusotoError<DeleteDomainError>> {
+        self.delete_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an endpoint. Amazon SageMaker frees up all of the resources that were deployed when the endpoint was created. </p> <p>Amazon SageMaker retires any custom KMS key grants associated with the endpoint, meaning you don't need to use the <a href="http://docs.aws.amazon.com/kms/latest/APIReference/API_RevokeGrant.html">RevokeGrant</a> API call.</p>
+    async fn delete_endpoint(
+        &self,
+        input: DeleteEndpointInput,
+    ) -> Result<(), RusotoError<DeleteEndpointError>> {
+        self.delete_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an endpoint configuration. The <code>DeleteEndpointConfig</code> API deletes only the specified configuration. It does not delete endpoints created using the configuration. </p>
+    async fn delete_endpoint_config(
+        &self,
+        input: DeleteEndpointConfigInput,
+    ) -> Result<(), RusotoError<DeleteEndpointConfigError>> {
+        self.delete_endpoint_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Amazon SageMaker experiment. All trials associated with the experiment must be deleted first. Use the <a>ListTrials</a> API to get a list of the trials associated with the experiment.</p>
+    async fn delete_experiment(
+        &self,
+        input: DeleteExperimentRequest,
+    ) -> Result<DeleteExperimentResponse, RusotoError<DeleteExperimentError>> {
+        self.delete_experiment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified flow definition.</p>
+    async fn delete_flow_definition(
+        &self,
+        input: DeleteFlowDefinitionRequest,
+    ) -> Result<DeleteFlowDefinitionResponse, RusotoError<DeleteFlowDefinitionError>> {
+        self.delete_flow_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a model. The <code>DeleteModel</code> API deletes only the model entry that was created in Amazon SageMaker when you called the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateModel.html">CreateModel</a> API. It does not delete model artifacts, inference code, or the IAM role that you specified when creating the model. </p>
+    async fn delete_model(
+        &self,
+        input: DeleteModelInput,
+    ) -> Result<(), RusotoError<DeleteModelError>> {
+        self.delete_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a model package.</p> <p>A model package is used to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p>
+    async fn delete_model_package(
+        &self,
+        input: DeleteModelPackageInput,
+    ) -> Result<(), RusotoError<DeleteModelPackageError>> {
+        self.delete_model_package_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a monitoring schedule. Also stops the schedule had not already been stopped. This does not delete the job execution history of the monitoring schedule. </p>
+    async fn delete_monitoring_schedule(
+        &self,
+        input: DeleteMonitoringScheduleRequest,
+    ) -> Result<(), RusotoError<DeleteMonitoringScheduleError>> {
+        self.delete_monitoring_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Deletes an Amazon SageMaker notebook instance. Before you can delete a notebook instance, you must call the <code>StopNotebookInstance</code> API. </p> <important> <p>When you delete a notebook instance, you lose all of your data. Amazon SageMaker removes the ML compute instance, and deletes the ML storage volume and the network interface associated with the notebook instance. </p> </important></p>
+    async fn delete_notebook_instance(
+        &self,
+        input: DeleteNotebookInstanceInput,
+    ) -> Result<(), RusotoError<DeleteNotebookInstanceError>> {
+        self.delete_notebook_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a notebook instance lifecycle configuration.</p>
+    async fn delete_notebook_instance_lifecycle_config(
+        &self,
+        input: DeleteNotebookInstanceLifecycleConfigInput,
+    ) -> Result<(), RusotoError<DeleteNotebookInstanceLifecycleConfigError>> {
+        self.delete_notebook_instance_lifecycle_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified tags from an Amazon SageMaker resource.</p> <p>To list a resource&#39;s tags, use the <code>ListTags</code> API. </p> <note> <p>When you call this API to delete tags from a hyperparameter tuning job, the deleted tags are not removed from training jobs that the hyperparameter tuning job launched before you called this API.</p> </note></p>
+    async fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
+        self.delete_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified trial. All trial components that make up the trial must be deleted first. Use the <a>DescribeTrialComponent</a> API to get the list of trial components.</p>
+    async fn delete_trial(
+        &self,
+        input: DeleteTrialRequest,
+    ) -> Result<DeleteTrialResponse, RusotoError<DeleteTrialError>> {
+        self.delete_trial_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified trial component. A trial component must be disassociated from all trials before the trial component can be deleted. To disassociate a trial component from a trial, call the <a>DisassociateTrialComponent</a> API.</p>
+    async fn delete_trial_component(
+        &self,
+        input: DeleteTrialComponentRequest,
+    ) -> Result<DeleteTrialComponentResponse, RusotoError<DeleteTrialComponentError>> {
+        self.delete_trial_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a user profile.</p>
+    async fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<(), RusotoError<DeleteUserProfileError>> {
+        self.delete_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing work team. This operation can't be undone.</p>
+    async fn delete_workteam(
+        &self,
+        input: DeleteWorkteamRequest,
+    ) -> Result<DeleteWorkteamResponse, RusotoError<DeleteWorkteamError>> {
+        self.delete_workteam_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of the specified algorithm that is in your account.</p>
+    async fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmInput,
+    ) -> Result<DescribeAlgorithmOutput, RusotoError<DescribeAlgorithmError>> {
+        self.describe_algorithm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the app.</p>
+    async fn describe_app(
+        &self,
+        input: DescribeAppRequest,
+    ) -> Result<DescribeAppResponse, RusotoError<DescribeAppError>> {
+        self.describe_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about an Amazon SageMaker job.</p>
+    async fn describe_auto_ml_job(
+        &self,
+        input: DescribeAutoMLJobRequest,
+    ) -> Result<DescribeAutoMLJobResponse, RusotoError<DescribeAutoMLJobError>> {
+        self.describe_auto_ml_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details about the specified Git repository.</p>
+    async fn describe_code_repository(
+        &self,
+        input: DescribeCodeRepositoryInput,
+    ) -> Result<DescribeCodeRepositoryOutput, RusotoError<DescribeCodeRepositoryError>> {
+        self.describe_code_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a model compilation job.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
+    async fn describe_compilation_job(
+        &self,
+        input: DescribeCompilationJobRequest,
+    ) -> Result<DescribeCompilationJobResponse, RusotoError<DescribeCompilationJobError>> {
+        self.describe_compilation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The desciption of the domain.</p>
+    async fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> Result<DescribeDomainResponse, RusotoError<DescribeDomainError>> {
+        self.describe_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the description of an endpoint.</p>
+    async fn describe_endpoint(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> Result<DescribeEndpointOutput, RusotoError<DescribeEndpointError>> {
+        self.describe_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the description of an endpoint configuration created using the <code>CreateEndpointConfig</code> API.</p>
+    async fn describe_endpoint_config(
+        &self,
+        input: DescribeEndpointConfigInput,
+    ) -> Result<DescribeEndpointConfigOutput, RusotoError<DescribeEndpointConfigError>> {
+        self.describe_endpoint_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a list of an experiment's properties.</p>
+    async fn describe_experiment(
+        &self,
+        input: DescribeExperimentRequest,
+    ) -> Result<DescribeExperimentResponse, RusotoError<DescribeExperimentError>> {
+        self.describe_experiment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified flow definition.</p>
+    async fn describe_flow_definition(
+        &self,
+        input: DescribeFlowDefinitionRequest,
+    ) -> Result<DescribeFlowDefinitionResponse, RusotoError<DescribeFlowDefinitionError>> {
+        self.describe_flow_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the requested human task user interface.</p>
+    async fn describe_human_task_ui(
+        &self,
+        input: DescribeHumanTaskUiRequest,
+    ) -> Result<DescribeHumanTaskUiResponse, RusotoError<DescribeHumanTaskUiError>> {
+        self.describe_human_task_ui_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a description of a hyperparameter tuning job.</p>
+    async fn describe_hyper_parameter_tuning_job(
+        &self,
+        input: DescribeHyperParameterTuningJobRequest,
+    ) -> Result<
+        DescribeHyperParameterTuningJobResponse,
+        RusotoError<DescribeHyperParameterTuningJobError>,
+    > {
+        self.describe_hyper_parameter_tuning_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a labeling job.</p>
+    async fn describe_labeling_job(
+        &self,
+        input: DescribeLabelingJobRequest,
+    ) -> Result<DescribeLabelingJobResponse, RusotoError<DescribeLabelingJobError>> {
+        self.describe_labeling_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a model that you created using the <code>CreateModel</code> API.</p>
+    async fn describe_model(
+        &self,
+        input: DescribeModelInput,
+    ) -> Result<DescribeModelOutput, RusotoError<DescribeModelError>> {
+        self.describe_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of the specified model package, which is used to create Amazon SageMaker models or list them on AWS Marketplace.</p> <p>To create models in Amazon SageMaker, buyers can subscribe to model packages listed on AWS Marketplace.</p>
+    async fn describe_model_package(
+        &self,
+        input: DescribeModelPackageInput,
+    ) -> Result<DescribeModelPackageOutput, RusotoError<DescribeModelPackageError>> {
+        self.describe_model_package_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the schedule for a monitoring job.</p>
+    async fn describe_monitoring_schedule(
+        &self,
+        input: DescribeMonitoringScheduleRequest,
+    ) -> Result<DescribeMonitoringScheduleResponse, RusotoError<DescribeMonitoringScheduleError>>
+    {
+        self.describe_monitoring_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a notebook instance.</p>
+    async fn describe_notebook_instance(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> Result<DescribeNotebookInstanceOutput, RusotoError<DescribeNotebookInstanceError>> {
+        self.describe_notebook_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of a notebook instance lifecycle configuration.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
+    async fn describe_notebook_instance_lifecycle_config(
+        &self,
+        input: DescribeNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        DescribeNotebookInstanceLifecycleConfigOutput,
+        RusotoError<DescribeNotebookInstanceLifecycleConfigError>,
+    > {
+        self.describe_notebook_instance_lifecycle_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of a processing job.</p>
+    async fn describe_processing_job(
+        &self,
+        input: DescribeProcessingJobRequest,
+    ) -> Result<DescribeProcessingJobResponse, RusotoError<DescribeProcessingJobError>> {
+        self.describe_processing_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a work team provided by a vendor. It returns details about the subscription with a vendor in the AWS Marketplace.</p>
+    async fn describe_subscribed_workteam(
+        &self,
+        input: DescribeSubscribedWorkteamRequest,
+    ) -> Result<DescribeSubscribedWorkteamResponse, RusotoError<DescribeSubscribedWorkteamError>>
+    {
+        self.describe_subscribed_workteam_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a training job.</p>
+    async fn describe_training_job(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> Result<DescribeTrainingJobResponse, RusotoError<DescribeTrainingJobError>> {
+        self.describe_training_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a transform job.</p>
+    async fn describe_transform_job(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> Result<DescribeTransformJobResponse, RusotoError<DescribeTransformJobError>> {
+        self.describe_transform_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a list of a trial's properties.</p>
+    async fn describe_trial(
+        &self,
+        input: DescribeTrialRequest,
+    ) -> Result<DescribeTrialResponse, RusotoError<DescribeTrialError>> {
+        self.describe_trial_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a list of a trials component's properties.</p>
+    async fn describe_trial_component(
+        &self,
+        input: DescribeTrialComponentRequest,
+    ) -> Result<DescribeTrialComponentResponse, RusotoError<DescribeTrialComponentError>> {
+        self.describe_trial_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the user profile.</p>
+    async fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> Result<DescribeUserProfileResponse, RusotoError<DescribeUserProfileError>> {
+        self.describe_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists private workforce information, including workforce name, Amazon Resource Name (ARN), and, if applicable, allowed IP address ranges (<a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html">CIDRs</a>). Allowable IP address ranges are the IP addresses that workers can use to access tasks. </p> <important> <p>This operation applies only to private workforces.</p> </important></p>
+    async fn describe_workforce(
+        &self,
+        input: DescribeWorkforceRequest,
+    ) -> Result<DescribeWorkforceResponse, RusotoError<DescribeWorkforceError>> {
+        self.describe_workforce_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specific work team. You can see information such as the create date, the last updated date, membership information, and the work team's Amazon Resource Name (ARN).</p>
+    async fn describe_workteam(
+        &self,
+        input: DescribeWorkteamRequest,
+    ) -> Result<DescribeWorkteamResponse, RusotoError<DescribeWorkteamError>> {
+        self.describe_workteam_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a trial component from a trial. This doesn't effect other trials the component is associated with. Before you can delete a component, you must disassociate the component from all trials it is associated with. To associate a trial component with a trial, call the <a>AssociateTrialComponent</a> API.</p>
+    async fn disassociate_trial_component(
+        &self,
+        input: DisassociateTrialComponentRequest,
+    ) -> Result<DisassociateTrialComponentResponse, RusotoError<DisassociateTrialComponentError>>
+    {
+        self.disassociate_trial_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>An auto-complete API for the search functionality in the Amazon SageMaker console. It returns suggestions of possible matches for the property name to use in <code>Search</code> queries. Provides suggestions for <code>HyperParameters</code>, <code>Tags</code>, and <code>Metrics</code>.</p>
+    async fn get_search_suggestions(
+        &self,
+        input: GetSearchSuggestionsRequest,
+    ) -> Result<GetSearchSuggestionsResponse, RusotoError<GetSearchSuggestionsError>> {
+        self.get_search_suggestions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the machine learning algorithms that have been created.</p>
+    async fn list_algorithms(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> Result<ListAlgorithmsOutput, RusotoError<ListAlgorithmsError>> {
+        self.list_algorithms_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists apps.</p>
+    async fn list_apps(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
+        self.list_apps_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Request a list of jobs.</p>
+    async fn list_auto_ml_jobs(
+        &self,
+        input: ListAutoMLJobsRequest,
+    ) -> Result<ListAutoMLJobsResponse, RusotoError<ListAutoMLJobsError>> {
+        self.list_auto_ml_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the Candidates created for the job.</p>
+    async fn list_candidates_for_auto_ml_job(
+        &self,
+        input: ListCandidatesForAutoMLJobRequest,
+    ) -> Result<ListCandidatesForAutoMLJobResponse, RusotoError<ListCandidatesForAutoMLJobError>>
+    {
+        self.list_candidates_for_auto_ml_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the Git repositories in your account.</p>
+    async fn list_code_repositories(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> Result<ListCodeRepositoriesOutput, RusotoError<ListCodeRepositoriesError>> {
+        self.list_code_repositories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists model compilation jobs that satisfy various filters.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about a particular model compilation job you have created, use <a>DescribeCompilationJob</a>.</p>
+    async fn list_compilation_jobs(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> Result<ListCompilationJobsResponse, RusotoError<ListCompilationJobsError>> {
+        self.list_compilation_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the domains.</p>
+    async fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+        self.list_domains_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists endpoint configurations.</p>
+    async fn list_endpoint_configs(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> Result<ListEndpointConfigsOutput, RusotoError<ListEndpointConfigsError>> {
+        self.list_endpoint_configs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists endpoints.</p>
+    async fn list_endpoints(
+        &self,
+        input: ListEndpointsInput,
+    ) -> Result<ListEndpointsOutput, RusotoError<ListEndpointsError>> {
+        self.list_endpoints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the experiments in your account. The list can be filtered to show only experiments that were created in a specific time range. The list can be sorted by experiment name or creation time.</p>
+    async fn list_experiments(
+        &self,
+        input: ListExperimentsRequest,
+    ) -> Result<ListExperimentsResponse, RusotoError<ListExperimentsError>> {
+        self.list_experiments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the flow definitions in your account.</p>
+    async fn list_flow_definitions(
+        &self,
+        input: ListFlowDefinitionsRequest,
+    ) -> Result<ListFlowDefinitionsResponse, RusotoError<ListFlowDefinitionsError>> {
+        self.list_flow_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the human task user interfaces in your account.</p>
+    async fn list_human_task_uis(
+        &self,
+        input: ListHumanTaskUisRequest,
+    ) -> Result<ListHumanTaskUisResponse, RusotoError<ListHumanTaskUisError>> {
+        self.list_human_task_uis_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of <a>HyperParameterTuningJobSummary</a> objects that describe the hyperparameter tuning jobs launched in your account.</p>
+    async fn list_hyper_parameter_tuning_jobs(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> Result<ListHyperParameterTuningJobsResponse, RusotoError<ListHyperParameterTuningJobsError>>
+    {
+        self.list_hyper_parameter_tuning_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of labeling jobs.</p>
+    async fn list_labeling_jobs(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> Result<ListLabelingJobsResponse, RusotoError<ListLabelingJobsError>> {
+        self.list_labeling_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of labeling jobs assigned to a specified work team.</p>
+    async fn list_labeling_jobs_for_workteam(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> Result<ListLabelingJobsForWorkteamResponse, RusotoError<ListLabelingJobsForWorkteamError>>
+    {
+        self.list_labeling_jobs_for_workteam_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the model packages that have been created.</p>
+    async fn list_model_packages(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> Result<ListModelPackagesOutput, RusotoError<ListModelPackagesError>> {
+        self.list_model_packages_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists models created with the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateModel.html">CreateModel</a> API.</p>
+    async fn list_models(
+        &self,
+        input: ListModelsInput,
+    ) -> Result<ListModelsOutput, RusotoError<ListModelsError>> {
+        self.list_models_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns list of all monitoring job executions.</p>
+    async fn list_monitoring_executions(
+        &self,
+        input: ListMonitoringExecutionsRequest,
+    ) -> Result<ListMonitoringExecutionsResponse, RusotoError<ListMonitoringExecutionsError>> {
+        self.list_monitoring_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns list of all monitoring schedules.</p>
+    async fn list_monitoring_schedules(
+        &self,
+        input: ListMonitoringSchedulesRequest,
+    ) -> Result<ListMonitoringSchedulesResponse, RusotoError<ListMonitoringSchedulesError>> {
+        self.list_monitoring_schedules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists notebook instance lifestyle configurations created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
+    async fn list_notebook_instance_lifecycle_configs(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> Result<
+        ListNotebookInstanceLifecycleConfigsOutput,
+        RusotoError<ListNotebookInstanceLifecycleConfigsError>,
+    > {
+        self.list_notebook_instance_lifecycle_configs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the Amazon SageMaker notebook instances in the requester's account in an AWS Region. </p>
+    async fn list_notebook_instances(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> Result<ListNotebookInstancesOutput, RusotoError<ListNotebookInstancesError>> {
+        self.list_notebook_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists processing jobs that satisfy various filters.</p>
+    async fn list_processing_jobs(
+        &self,
+        input: ListProcessingJobsRequest,
+    ) -> Result<ListProcessingJobsResponse, RusotoError<ListProcessingJobsError>> {
+        self.list_processing_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the work teams that you are subscribed to in the AWS Marketplace. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
+    async fn list_subscribed_workteams(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> Result<ListSubscribedWorkteamsResponse, RusotoError<ListSubscribedWorkteamsError>> {
+        self.list_subscribed_workteams_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the tags for the specified Amazon SageMaker resource.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsInput,
+    ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists training jobs.</p>
+    async fn list_training_jobs(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> Result<ListTrainingJobsResponse, RusotoError<ListTrainingJobsError>> {
+        self.list_training_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of <a>TrainingJobSummary</a> objects that describe the training jobs that a hyperparameter tuning job launched.</p>
+    async fn list_training_jobs_for_hyper_parameter_tuning_job(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> Result<
+        ListTrainingJobsForHyperParameterTuningJobResponse,
+        RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
+    > {
+        self.list_training_jobs_for_hyper_parameter_tuning_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists transform jobs.</p>
+    async fn list_transform_jobs(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> Result<ListTransformJobsResponse, RusotoError<ListTransformJobsError>> {
+        self.list_transform_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the trial components in your account. You can sort the list by trial component name or creation time. You can filter the list to show only components that were created in a specific time range. You can also filter on one of the following:</p> <ul> <li> <p> <code>ExperimentName</code> </p> </li> <li> <p> <code>SourceArn</code> </p> </li> <li> <p> <code>TrialName</code> </p> </li> </ul></p>
+    async fn list_trial_components(
+        &self,
+        input: ListTrialComponentsRequest,
+    ) -> Result<ListTrialComponentsResponse, RusotoError<ListTrialComponentsError>> {
+        self.list_trial_components_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the trials in your account. Specify an experiment name to limit the list to the trials that are part of that experiment. The list can be filtered to show only trials that were created in a specific time range. The list can be sorted by trial name or creation time.</p>
+    async fn list_trials(
+        &self,
+        input: ListTrialsRequest,
+    ) -> Result<ListTrialsResponse, RusotoError<ListTrialsError>> {
+        self.list_trials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists user profiles.</p>
+    async fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> Result<ListUserProfilesResponse, RusotoError<ListUserProfilesError>> {
+        self.list_user_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of work teams that you have defined in a region. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
+    async fn list_workteams(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> Result<ListWorkteamsResponse, RusotoError<ListWorkteamsError>> {
+        self.list_workteams_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Renders the UI template so that you can preview the worker's experience. </p>
+    async fn render_ui_template(
+        &self,
+        input: RenderUiTemplateRequest,
+    ) -> Result<RenderUiTemplateResponse, RusotoError<RenderUiTemplateError>> {
+        self.render_ui_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Finds Amazon SageMaker resources that match a search query. Matching resource objects are returned as a list of <code>SearchResult</code> objects in the response. You can sort the search results by any resource property in a ascending or descending order.</p> <p>You can query against the following value types: numeric, text, Boolean, and timestamp.</p>
+    async fn search(
+        &self,
+        input: SearchRequest,
+    ) -> Result<SearchResponse, RusotoError<SearchError>> {
+        self.search_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Starts a previously stopped monitoring schedule.</p> <note> <p>New monitoring schedules are immediately started after creation.</p> </note></p>
+    async fn start_monitoring_schedule(
+        &self,
+        input: StartMonitoringScheduleRequest,
+    ) -> Result<(), RusotoError<StartMonitoringScheduleError>> {
+        self.start_monitoring_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Launches an ML compute instance with the latest version of the libraries and attaches your ML storage volume. After configuring the notebook instance, Amazon SageMaker sets the notebook instance status to <code>InService</code>. A notebook instance's status must be <code>InService</code> before you can connect to your Jupyter notebook. </p>
+    async fn start_notebook_instance(
+        &self,
+        input: StartNotebookInstanceInput,
+    ) -> Result<(), RusotoError<StartNotebookInstanceError>> {
+        self.start_notebook_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>A method for forcing the termination of a running job.</p>
+    async fn stop_auto_ml_job(
+        &self,
+        input: StopAutoMLJobRequest,
+    ) -> Result<(), RusotoError<StopAutoMLJobError>> {
+        self.stop_auto_ml_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a model compilation job.</p> <p> To stop a job, Amazon SageMaker sends the algorithm the SIGTERM signal. This gracefully shuts the job down. If the job hasn't stopped, it sends the SIGKILL signal.</p> <p>When it receives a <code>StopCompilationJob</code> request, Amazon SageMaker changes the <a>CompilationJobSummary$CompilationJobStatus</a> of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the <a>CompilationJobSummary$CompilationJobStatus</a> to <code>Stopped</code>. </p>
+    async fn stop_compilation_job(
+        &self,
+        input: StopCompilationJobRequest,
+    ) -> Result<(), RusotoError<StopCompilationJobError>> {
+        self.stop_compilation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a running hyperparameter tuning job and all running training jobs that the tuning job launched.</p> <p>All model artifacts output from the training jobs are stored in Amazon Simple Storage Service (Amazon S3). All data that the training jobs write to Amazon CloudWatch Logs are still available in CloudWatch. After the tuning job moves to the <code>Stopped</code> state, it releases all reserved resources for the tuning job.</p>
+    async fn stop_hyper_parameter_tuning_job(
+        &self,
+        input: StopHyperParameterTuningJobRequest,
+    ) -> Result<(), RusotoError<StopHyperParameterTuningJobError>> {
+        self.stop_hyper_parameter_tuning_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a running labeling job. A job that is stopped cannot be restarted. Any results obtained before the job is stopped are placed in the Amazon S3 output bucket.</p>
+    async fn stop_labeling_job(
+        &self,
+        input: StopLabelingJobRequest,
+    ) -> Result<(), RusotoError<StopLabelingJobError>> {
+        self.stop_labeling_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a previously started monitoring schedule.</p>
+    async fn stop_monitoring_schedule(
+        &self,
+        input: StopMonitoringScheduleRequest,
+    ) -> Result<(), RusotoError<StopMonitoringScheduleError>> {
+        self.stop_monitoring_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Terminates the ML compute instance. Before terminating the instance, Amazon SageMaker disconnects the ML storage volume from it. Amazon SageMaker preserves the ML storage volume. Amazon SageMaker stops charging you for the ML compute instance when you call <code>StopNotebookInstance</code>.</p> <p>To access data on the ML storage volume for a notebook instance that has been terminated, call the <code>StartNotebookInstance</code> API. <code>StartNotebookInstance</code> launches another ML compute instance, configures it, and attaches the preserved ML storage volume so you can continue your work. </p>
+    async fn stop_notebook_instance(
+        &self,
+        input: StopNotebookInstanceInput,
+    ) -> Result<(), RusotoError<StopNotebookInstanceError>> {
+        self.stop_notebook_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a processing job.</p>
+    async fn stop_processing_job(
+        &self,
+        input: StopProcessingJobRequest,
+    ) -> Result<(), RusotoError<StopProcessingJobError>> {
+        self.stop_processing_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a training job. To stop a job, Amazon SageMaker sends the algorithm the <code>SIGTERM</code> signal, which delays job termination for 120 seconds. Algorithms might use this 120-second window to save the model artifacts, so the results of the training is not lost. </p> <p>When it receives a <code>StopTrainingJob</code> request, Amazon SageMaker changes the status of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the status to <code>Stopped</code>.</p>
+    async fn stop_training_job(
+        &self,
+        input: StopTrainingJobRequest,
+    ) -> Result<(), RusotoError<StopTrainingJobError>> {
+        self.stop_training_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a transform job.</p> <p>When Amazon SageMaker receives a <code>StopTransformJob</code> request, the status of the job changes to <code>Stopping</code>. After Amazon SageMaker stops the job, the status is set to <code>Stopped</code>. When you stop a transform job before it is completed, Amazon SageMaker doesn't store the job's output in Amazon S3.</p>
+    async fn stop_transform_job(
+        &self,
+        input: StopTransformJobRequest,
+    ) -> Result<(), RusotoError<StopTransformJobError>> {
+        self.stop_transform_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified Git repository with the specified values.</p>
+    async fn update_code_repository(
+        &self,
+        input: UpdateCodeRepositoryInput,
+    ) -> Result<UpdateCodeRepositoryOutput, RusotoError<UpdateCodeRepositoryError>> {
+        self.update_code_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a domain. Changes will impact all of the people in the domain.</p>
+    async fn update_domain(
+        &self,
+        input: UpdateDomainRequest,
+    ) -> Result<UpdateDomainResponse, RusotoError<UpdateDomainError>> {
+        self.update_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deploys the new <code>EndpointConfig</code> specified in the request, switches to using newly created endpoint, and then deletes resources provisioned for the endpoint using the previous <code>EndpointConfig</code> (there is no availability loss). </p> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API. </p> <note> <p>You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> </note></p>
+    async fn update_endpoint(
+        &self,
+        input: UpdateEndpointInput,
+    ) -> Result<UpdateEndpointOutput, RusotoError<UpdateEndpointError>> {
+        self.update_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates variant weight of one or more variants associated with an existing endpoint, or capacity of one variant associated with an existing endpoint. When it receives the request, Amazon SageMaker sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API. </p>
+    async fn update_endpoint_weights_and_capacities(
+        &self,
+        input: UpdateEndpointWeightsAndCapacitiesInput,
+    ) -> Result<
+        UpdateEndpointWeightsAndCapacitiesOutput,
+        RusotoError<UpdateEndpointWeightsAndCapacitiesError>,
+    > {
+        self.update_endpoint_weights_and_capacities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds, updates, or removes the description of an experiment. Updates the display name of an experiment.</p>
+    async fn update_experiment(
+        &self,
+        input: UpdateExperimentRequest,
+    ) -> Result<UpdateExperimentResponse, RusotoError<UpdateExperimentError>> {
+        self.update_experiment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a previously created schedule.</p>
+    async fn update_monitoring_schedule(
+        &self,
+        input: UpdateMonitoringScheduleRequest,
+    ) -> Result<UpdateMonitoringScheduleResponse, RusotoError<UpdateMonitoringScheduleError>> {
+        self.update_monitoring_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a notebook instance. NotebookInstance updates include upgrading or downgrading the ML compute instance used for your notebook instance to accommodate changes in your workload requirements.</p>
+    async fn update_notebook_instance(
+        &self,
+        input: UpdateNotebookInstanceInput,
+    ) -> Result<UpdateNotebookInstanceOutput, RusotoError<UpdateNotebookInstanceError>> {
+        self.update_notebook_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a notebook instance lifecycle configuration created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
+    async fn update_notebook_instance_lifecycle_config(
+        &self,
+        input: UpdateNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        UpdateNotebookInstanceLifecycleConfigOutput,
+        RusotoError<UpdateNotebookInstanceLifecycleConfigError>,
+    > {
+        self.update_notebook_instance_lifecycle_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the display name of a trial.</p>
+    async fn update_trial(
+        &self,
+        input: UpdateTrialRequest,
+    ) -> Result<UpdateTrialResponse, RusotoError<UpdateTrialError>> {
+        self.update_trial_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates one or more properties of a trial component.</p>
+    async fn update_trial_component(
+        &self,
+        input: UpdateTrialComponentRequest,
+    ) -> Result<UpdateTrialComponentResponse, RusotoError<UpdateTrialComponentError>> {
+        self.update_trial_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a user profile.</p>
+    async fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<UpdateUserProfileResponse, RusotoError<UpdateUserProfileError>> {
+        self.update_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Restricts access to tasks assigned to workers in the specified workforce to those within specific ranges of IP addresses. You specify allowed IP addresses by creating a list of up to four <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html">CIDRs</a>.</p> <p>By default, a workforce isn&#39;t restricted to specific IP addresses. If you specify a range of IP addresses, workers who attempt to access tasks using any IP address outside the specified range are denied access and get a <code>Not Found</code> error message on the worker portal. After restricting access with this operation, you can see the allowed IP values for a private workforce with the operation.</p> <important> <p>This operation applies only to private workforces.</p> </important></p>
+    async fn update_workforce(
+        &self,
+        input: UpdateWorkforceRequest,
+    ) -> Result<UpdateWorkforceResponse, RusotoError<UpdateWorkforceError>> {
+        self.update_workforce_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing work team with new member definitions or description.</p>
+    async fn update_workteam(
+        &self,
+        input: UpdateWorkteamRequest,
+    ) -> Result<UpdateWorkteamResponse, RusotoError<UpdateWorkteamError>> {
+        self.update_workteam_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -644,6 +644,7 @@ pub struct UntagResourceResponse {}
 
 /// Errors returned by CreateSavingsPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSavingsPlanError {
     /// <p>An unexpected error occurred.</p>
     InternalServer(String),
@@ -651,6 +652,8 @@ pub enum CreateSavingsPlanError {
     ResourceNotFound(String),
     /// <p>A service quota has been exceeded.</p>
     ServiceQuotaExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSavingsPlanError {
@@ -658,18 +661,25 @@ impl CreateSavingsPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSavingsPlanError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateSavingsPlanError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSavingsPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateSavingsPlanError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateSavingsPlanError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSavingsPlanError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSavingsPlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -682,15 +692,19 @@ impl fmt::Display for CreateSavingsPlanError {
             CreateSavingsPlanError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateSavingsPlanError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateSavingsPlanError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
+            CreateSavingsPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSavingsPlanError {}
 /// Errors returned by DescribeSavingsPlanRates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSavingsPlanRatesError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSavingsPlanRatesError {
@@ -698,12 +712,13 @@ impl DescribeSavingsPlanRatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSavingsPlanRatesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSavingsPlanRatesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSavingsPlanRatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -714,15 +729,19 @@ impl fmt::Display for DescribeSavingsPlanRatesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeSavingsPlanRatesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSavingsPlanRatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSavingsPlanRatesError {}
 /// Errors returned by DescribeSavingsPlans
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSavingsPlansError {
     /// <p>An unexpected error occurred.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSavingsPlansError {
@@ -730,10 +749,13 @@ impl DescribeSavingsPlansError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeSavingsPlansError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeSavingsPlansError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSavingsPlansError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -744,15 +766,19 @@ impl fmt::Display for DescribeSavingsPlansError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeSavingsPlansError::InternalServer(ref cause) => write!(f, "{}", cause),
+            DescribeSavingsPlansError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSavingsPlansError {}
 /// Errors returned by DescribeSavingsPlansOfferingRates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSavingsPlansOfferingRatesError {
     /// <p>An unexpected error occurred.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSavingsPlansOfferingRatesError {
@@ -762,12 +788,18 @@ impl DescribeSavingsPlansOfferingRatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSavingsPlansOfferingRatesError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeSavingsPlansOfferingRatesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -780,15 +812,21 @@ impl fmt::Display for DescribeSavingsPlansOfferingRatesError {
             DescribeSavingsPlansOfferingRatesError::InternalServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeSavingsPlansOfferingRatesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeSavingsPlansOfferingRatesError {}
 /// Errors returned by DescribeSavingsPlansOfferings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSavingsPlansOfferingsError {
     /// <p>An unexpected error occurred.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSavingsPlansOfferingsError {
@@ -798,12 +836,18 @@ impl DescribeSavingsPlansOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSavingsPlansOfferingsError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeSavingsPlansOfferingsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -814,17 +858,23 @@ impl fmt::Display for DescribeSavingsPlansOfferingsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeSavingsPlansOfferingsError::InternalServer(ref cause) => write!(f, "{}", cause),
+            DescribeSavingsPlansOfferingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeSavingsPlansOfferingsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>An unexpected error occurred.</p>
     InternalServer(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -832,15 +882,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -852,12 +906,14 @@ impl fmt::Display for ListTagsForResourceError {
         match *self {
             ListTagsForResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>An unexpected error occurred.</p>
     InternalServer(String),
@@ -865,6 +921,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>A service quota has been exceeded.</p>
     ServiceQuotaExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -872,16 +930,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(TagResourceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(TagResourceError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -894,17 +955,21 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>An unexpected error occurred.</p>
     InternalServer(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -912,13 +977,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -930,6 +998,7 @@ impl fmt::Display for UntagResourceError {
         match *self {
             UntagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1002,6 +1071,7 @@ impl SavingsPlansClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SavingsPlansClient {
         SavingsPlansClient {
             client: Client::shared(),
@@ -1027,255 +1097,575 @@ impl SavingsPlansClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SavingsPlansClient {
         SavingsPlansClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SavingsPlansClient> {
+        rusoto_core::ClientBuilder::new(SavingsPlansClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SavingsPlansClient {
+        SavingsPlansClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SavingsPlans for SavingsPlansClient {
-    /// <p>Creates a Savings Plan.</p>
-    async fn create_savings_plan(
+impl SavingsPlansClient {
+    /// Calls `create_savings_plan`, also returning the metadata of the response.
+    pub async fn create_savings_plan_with_metadata(
         &self,
         input: CreateSavingsPlanRequest,
-    ) -> Result<CreateSavingsPlanResponse, RusotoError<CreateSavingsPlanError>> {
-        let request_uri = "/CreateSavingsPlan";
-
-        let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<(CreateSavingsPlanResponse, ResponseMetadata), RusotoError<CreateSavingsPlanError>>
+    {
+        let request = self.build_create_savings_plan_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateSavingsPlanResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateSavingsPlanError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified Savings Plans rates.</p>
-    async fn describe_savings_plan_rates(
+    /// Presigns the request `create_savings_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_savings_plan(
         &self,
-        input: DescribeSavingsPlanRatesRequest,
-    ) -> Result<DescribeSavingsPlanRatesResponse, RusotoError<DescribeSavingsPlanRatesError>> {
-        let request_uri = "/DescribeSavingsPlanRates";
+        presigner: &rusoto_core::Presigner,
+        input: CreateSavingsPlanRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_savings_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_savings_plan_request(&self, input: CreateSavingsPlanRequest) -> SignedRequest {
+        let request_uri = "/CreateSavingsPlan";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("CreateSavingsPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_savings_plan_rates`, also returning the metadata of the response.
+    pub async fn describe_savings_plan_rates_with_metadata(
+        &self,
+        input: DescribeSavingsPlanRatesRequest,
+    ) -> Result<
+        (DescribeSavingsPlanRatesResponse, ResponseMetadata),
+        RusotoError<DescribeSavingsPlanRatesError>,
+    > {
+        let request = self.build_describe_savings_plan_rates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSavingsPlanRatesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeSavingsPlanRatesError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified Savings Plans.</p>
-    async fn describe_savings_plans(
+    /// Presigns the request `describe_savings_plan_rates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_savings_plan_rates(
         &self,
-        input: DescribeSavingsPlansRequest,
-    ) -> Result<DescribeSavingsPlansResponse, RusotoError<DescribeSavingsPlansError>> {
-        let request_uri = "/DescribeSavingsPlans";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSavingsPlanRatesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_savings_plan_rates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_savings_plan_rates_request(
+        &self,
+        input: DescribeSavingsPlanRatesRequest,
+    ) -> SignedRequest {
+        let request_uri = "/DescribeSavingsPlanRates";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlanRates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_savings_plans`, also returning the metadata of the response.
+    pub async fn describe_savings_plans_with_metadata(
+        &self,
+        input: DescribeSavingsPlansRequest,
+    ) -> Result<
+        (DescribeSavingsPlansResponse, ResponseMetadata),
+        RusotoError<DescribeSavingsPlansError>,
+    > {
+        let request = self.build_describe_savings_plans_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSavingsPlansResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeSavingsPlansError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified Savings Plans offering rates.</p>
-    async fn describe_savings_plans_offering_rates(
+    /// Presigns the request `describe_savings_plans` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_savings_plans(
         &self,
-        input: DescribeSavingsPlansOfferingRatesRequest,
-    ) -> Result<
-        DescribeSavingsPlansOfferingRatesResponse,
-        RusotoError<DescribeSavingsPlansOfferingRatesError>,
-    > {
-        let request_uri = "/DescribeSavingsPlansOfferingRates";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSavingsPlansRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_savings_plans_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_savings_plans_request(
+        &self,
+        input: DescribeSavingsPlansRequest,
+    ) -> SignedRequest {
+        let request_uri = "/DescribeSavingsPlans";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_savings_plans_offering_rates`, also returning the metadata of the response.
+    pub async fn describe_savings_plans_offering_rates_with_metadata(
+        &self,
+        input: DescribeSavingsPlansOfferingRatesRequest,
+    ) -> Result<
+        (DescribeSavingsPlansOfferingRatesResponse, ResponseMetadata),
+        RusotoError<DescribeSavingsPlansOfferingRatesError>,
+    > {
+        let request = self.build_describe_savings_plans_offering_rates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSavingsPlansOfferingRatesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeSavingsPlansOfferingRatesError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Describes the specified Savings Plans offerings.</p>
-    async fn describe_savings_plans_offerings(
+    /// Presigns the request `describe_savings_plans_offering_rates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_savings_plans_offering_rates(
         &self,
-        input: DescribeSavingsPlansOfferingsRequest,
-    ) -> Result<
-        DescribeSavingsPlansOfferingsResponse,
-        RusotoError<DescribeSavingsPlansOfferingsError>,
-    > {
-        let request_uri = "/DescribeSavingsPlansOfferings";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSavingsPlansOfferingRatesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_savings_plans_offering_rates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_savings_plans_offering_rates_request(
+        &self,
+        input: DescribeSavingsPlansOfferingRatesRequest,
+    ) -> SignedRequest {
+        let request_uri = "/DescribeSavingsPlansOfferingRates";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlansOfferingRates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_savings_plans_offerings`, also returning the metadata of the response.
+    pub async fn describe_savings_plans_offerings_with_metadata(
+        &self,
+        input: DescribeSavingsPlansOfferingsRequest,
+    ) -> Result<
+        (DescribeSavingsPlansOfferingsResponse, ResponseMetadata),
+        RusotoError<DescribeSavingsPlansOfferingsError>,
+    > {
+        let request = self.build_describe_savings_plans_offerings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeSavingsPlansOfferingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeSavingsPlansOfferingsError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for the specified resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `describe_savings_plans_offerings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_savings_plans_offerings(
         &self,
-        input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
-        let request_uri = "/ListTagsForResource";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSavingsPlansOfferingsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_savings_plans_offerings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_savings_plans_offerings_request(
+        &self,
+        input: DescribeSavingsPlansOfferingsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/DescribeSavingsPlansOfferings";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("DescribeSavingsPlansOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds the specified tags to the specified resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
-        input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
-        let request_uri = "/TagResource";
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
+        let request_uri = "/ListTagsForResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from the specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
-        input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
-        let request_uri = "/UntagResource";
+        presigner: &rusoto_core::Presigner,
+        input: TagResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
+        let request_uri = "/TagResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UntagResourceError::from_response(response))
         }
     }
+
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UntagResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
+        let request_uri = "/UntagResource";
+
+        let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+}
+
+#[async_trait]
+impl SavingsPlans for SavingsPlansClient {
+    /// <p>Creates a Savings Plan.</p>
+    async fn create_savings_plan(
+        &self,
+        input: CreateSavingsPlanRequest,
+    ) -> Result<CreateSavingsPlanResponse, RusotoError<CreateSavingsPlanError>> {
+        self.create_savings_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified Savings Plans rates.</p>
+    async fn describe_savings_plan_rates(
+        &self,
+        input: DescribeSavingsPlanRatesRequest,
+    ) -> Result<DescribeSavingsPlanRatesResponse, RusotoError<DescribeSavingsPlanRatesError>> {
+        self.describe_savings_plan_rates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified Savings Plans.</p>
+    async fn describe_savings_plans(
+        &self,
+        input: DescribeSavingsPlansRequest,
+    ) -> Result<DescribeSavingsPlansResponse, RusotoError<DescribeSavingsPlansError>> {
+        self.describe_savings_plans_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified Savings Plans offering rates.</p>
+    async fn describe_savings_plans_offering_rates(
+        &self,
+        input: DescribeSavingsPlansOfferingRatesRequest,
+    ) -> Result<
+        DescribeSavingsPlansOfferingRatesResponse,
+        RusotoError<DescribeSavingsPlansOfferingRatesError>,
+    > {
+        self.describe_savings_plans_offering_rates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified Savings Plans offerings.</p>
+    async fn describe_savings_plans_offerings(
+        &self,
+        input: DescribeSavingsPlansOfferingsRequest,
+    ) -> Result<
+        DescribeSavingsPlansOfferingsResponse,
+        RusotoError<DescribeSavingsPlansOfferingsError>,
+    > {
+        self.describe_savings_plans_offerings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for the specified resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified tags to the specified resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from the specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
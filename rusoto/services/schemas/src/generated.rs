@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -924,6 +924,7 @@ pub struct UpdateSchemaResponse {
 
 /// Errors returned by CreateDiscoverer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDiscovererError {
     BadRequest(String),
 
@@ -936,6 +937,8 @@ pub enum CreateDiscovererError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDiscovererError {
@@ -943,27 +946,31 @@ impl CreateDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(CreateDiscovererError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDiscovererError::Conflict(err.msg))
+                    return RusotoError::service(CreateDiscovererError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(CreateDiscovererError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateDiscovererError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDiscovererError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateDiscovererError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateDiscovererError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDiscovererError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -979,12 +986,14 @@ impl fmt::Display for CreateDiscovererError {
             CreateDiscovererError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateDiscovererError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateDiscovererError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateDiscovererError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDiscovererError {}
 /// Errors returned by CreateRegistry
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRegistryError {
     BadRequest(String),
 
@@ -997,6 +1006,8 @@ pub enum CreateRegistryError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRegistryError {
@@ -1004,25 +1015,31 @@ impl CreateRegistryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRegistryError::BadRequest(err.msg))
+                    return RusotoError::service(CreateRegistryError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRegistryError::Conflict(err.msg))
+                    return RusotoError::service(CreateRegistryError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRegistryError::Forbidden(err.msg))
+                    return RusotoError::service(CreateRegistryError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateRegistryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateRegistryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRegistryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateRegistryError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRegistryError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateRegistryError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRegistryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1038,12 +1055,14 @@ impl fmt::Display for CreateRegistryError {
             CreateRegistryError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateRegistryError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateRegistryError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateRegistryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRegistryError {}
 /// Errors returned by CreateSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSchemaError {
     BadRequest(String),
 
@@ -1052,6 +1071,8 @@ pub enum CreateSchemaError {
     InternalServerError(String),
 
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSchemaError {
@@ -1059,19 +1080,25 @@ impl CreateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(CreateSchemaError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(CreateSchemaError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateSchemaError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateSchemaError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateSchemaError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateSchemaError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1085,12 +1112,14 @@ impl fmt::Display for CreateSchemaError {
             CreateSchemaError::Forbidden(ref cause) => write!(f, "{}", cause),
             CreateSchemaError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateSchemaError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSchemaError {}
 /// Errors returned by DeleteDiscoverer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDiscovererError {
     BadRequest(String),
 
@@ -1103,6 +1132,8 @@ pub enum DeleteDiscovererError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDiscovererError {
@@ -1110,27 +1141,31 @@ impl DeleteDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteDiscovererError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteDiscovererError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteDiscovererError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDiscovererError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDiscovererError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteDiscovererError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteDiscovererError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDiscovererError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1146,12 +1181,14 @@ impl fmt::Display for DeleteDiscovererError {
             DeleteDiscovererError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDiscovererError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteDiscovererError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteDiscovererError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDiscovererError {}
 /// Errors returned by DeleteRegistry
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRegistryError {
     BadRequest(String),
 
@@ -1164,6 +1201,8 @@ pub enum DeleteRegistryError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRegistryError {
@@ -1171,25 +1210,31 @@ impl DeleteRegistryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRegistryError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteRegistryError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRegistryError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteRegistryError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteRegistryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteRegistryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRegistryError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRegistryError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRegistryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteRegistryError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRegistryError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteRegistryError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRegistryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1205,12 +1250,14 @@ impl fmt::Display for DeleteRegistryError {
             DeleteRegistryError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteRegistryError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteRegistryError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteRegistryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRegistryError {}
 /// Errors returned by DeleteSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSchemaError {
     BadRequest(String),
 
@@ -1223,6 +1270,8 @@ pub enum DeleteSchemaError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSchemaError {
@@ -1230,25 +1279,31 @@ impl DeleteSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteSchemaError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteSchemaError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteSchemaError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteSchemaError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaError::NotFound(err.msg))
+                    return RusotoError::service(DeleteSchemaError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSchemaError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteSchemaError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteSchemaError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteSchemaError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1264,12 +1319,14 @@ impl fmt::Display for DeleteSchemaError {
             DeleteSchemaError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteSchemaError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteSchemaError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSchemaError {}
 /// Errors returned by DeleteSchemaVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSchemaVersionError {
     BadRequest(String),
 
@@ -1282,6 +1339,8 @@ pub enum DeleteSchemaVersionError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSchemaVersionError {
@@ -1289,29 +1348,37 @@ impl DeleteSchemaVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteSchemaVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteSchemaVersionError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSchemaVersionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::NotFound(err.msg))
+                    return RusotoError::service(DeleteSchemaVersionError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSchemaVersionError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteSchemaVersionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSchemaVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1327,12 +1394,14 @@ impl fmt::Display for DeleteSchemaVersionError {
             DeleteSchemaVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteSchemaVersionError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteSchemaVersionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteSchemaVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSchemaVersionError {}
 /// Errors returned by DescribeCodeBinding
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCodeBindingError {
     BadRequest(String),
 
@@ -1345,6 +1414,8 @@ pub enum DescribeCodeBindingError {
     TooManyRequests(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCodeBindingError {
@@ -1352,27 +1423,37 @@ impl DescribeCodeBindingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeCodeBindingError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeCodeBindingError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCodeBindingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::NotFound(err.msg))
+                    return RusotoError::service(DescribeCodeBindingError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeCodeBindingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeCodeBindingError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCodeBindingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1388,12 +1469,14 @@ impl fmt::Display for DescribeCodeBindingError {
             DescribeCodeBindingError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeCodeBindingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribeCodeBindingError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeCodeBindingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCodeBindingError {}
 /// Errors returned by DescribeDiscoverer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDiscovererError {
     BadRequest(String),
 
@@ -1406,6 +1489,8 @@ pub enum DescribeDiscovererError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDiscovererError {
@@ -1413,29 +1498,34 @@ impl DescribeDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeDiscovererError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeDiscovererError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeDiscovererError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDiscovererError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(DescribeDiscovererError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDiscovererError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDiscovererError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeDiscovererError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDiscovererError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1451,12 +1541,14 @@ impl fmt::Display for DescribeDiscovererError {
             DescribeDiscovererError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeDiscovererError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeDiscovererError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeDiscovererError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDiscovererError {}
 /// Errors returned by DescribeRegistry
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRegistryError {
     BadRequest(String),
 
@@ -1469,6 +1561,8 @@ pub enum DescribeRegistryError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRegistryError {
@@ -1476,27 +1570,31 @@ impl DescribeRegistryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeRegistryError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeRegistryError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeRegistryError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeRegistryError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeRegistryError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRegistryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRegistryError::NotFound(err.msg))
+                    return RusotoError::service(DescribeRegistryError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRegistryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeRegistryError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeRegistryError::Unauthorized(err.msg))
+                    return RusotoError::service(DescribeRegistryError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRegistryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1512,12 +1610,14 @@ impl fmt::Display for DescribeRegistryError {
             DescribeRegistryError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeRegistryError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeRegistryError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeRegistryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRegistryError {}
 /// Errors returned by DescribeSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSchemaError {
     BadRequest(String),
 
@@ -1530,6 +1630,8 @@ pub enum DescribeSchemaError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSchemaError {
@@ -1537,25 +1639,31 @@ impl DescribeSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeSchemaError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeSchemaError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeSchemaError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeSchemaError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeSchemaError::NotFound(err.msg))
+                    return RusotoError::service(DescribeSchemaError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeSchemaError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeSchemaError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeSchemaError::Unauthorized(err.msg))
+                    return RusotoError::service(DescribeSchemaError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1571,12 +1679,14 @@ impl fmt::Display for DescribeSchemaError {
             DescribeSchemaError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeSchemaError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeSchemaError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSchemaError {}
 /// Errors returned by GetCodeBindingSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCodeBindingSourceError {
     BadRequest(String),
 
@@ -1589,6 +1699,8 @@ pub enum GetCodeBindingSourceError {
     TooManyRequests(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCodeBindingSourceError {
@@ -1596,29 +1708,40 @@ impl GetCodeBindingSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetCodeBindingSourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        GetCodeBindingSourceError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCodeBindingSourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::NotFound(err.msg))
+                    return RusotoError::service(GetCodeBindingSourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCodeBindingSourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetCodeBindingSourceError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCodeBindingSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1634,12 +1757,14 @@ impl fmt::Display for GetCodeBindingSourceError {
             GetCodeBindingSourceError::NotFound(ref cause) => write!(f, "{}", cause),
             GetCodeBindingSourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetCodeBindingSourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetCodeBindingSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCodeBindingSourceError {}
 /// Errors returned by GetDiscoveredSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDiscoveredSchemaError {
     BadRequest(String),
 
@@ -1650,6 +1775,8 @@ pub enum GetDiscoveredSchemaError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDiscoveredSchemaError {
@@ -1657,26 +1784,34 @@ impl GetDiscoveredSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetDiscoveredSchemaError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(GetDiscoveredSchemaError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiscoveredSchemaError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiscoveredSchemaError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetDiscoveredSchemaError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDiscoveredSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1691,12 +1826,14 @@ impl fmt::Display for GetDiscoveredSchemaError {
             GetDiscoveredSchemaError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetDiscoveredSchemaError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetDiscoveredSchemaError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDiscoveredSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDiscoveredSchemaError {}
 /// Errors returned by ListDiscoverers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDiscoverersError {
     BadRequest(String),
 
@@ -1707,6 +1844,8 @@ pub enum ListDiscoverersError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDiscoverersError {
@@ -1714,22 +1853,28 @@ impl ListDiscoverersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDiscoverersError::BadRequest(err.msg))
+                    return RusotoError::service(ListDiscoverersError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListDiscoverersError::Forbidden(err.msg))
+                    return RusotoError::service(ListDiscoverersError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListDiscoverersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListDiscoverersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDiscoverersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListDiscoverersError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDiscoverersError::Unauthorized(err.msg))
+                    return RusotoError::service(ListDiscoverersError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDiscoverersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1744,12 +1889,14 @@ impl fmt::Display for ListDiscoverersError {
             ListDiscoverersError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListDiscoverersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListDiscoverersError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListDiscoverersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDiscoverersError {}
 /// Errors returned by ListRegistries
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRegistriesError {
     BadRequest(String),
 
@@ -1760,6 +1907,8 @@ pub enum ListRegistriesError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRegistriesError {
@@ -1767,22 +1916,28 @@ impl ListRegistriesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRegistriesError::BadRequest(err.msg))
+                    return RusotoError::service(ListRegistriesError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRegistriesError::Forbidden(err.msg))
+                    return RusotoError::service(ListRegistriesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListRegistriesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListRegistriesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRegistriesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListRegistriesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListRegistriesError::Unauthorized(err.msg))
+                    return RusotoError::service(ListRegistriesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRegistriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1797,12 +1952,14 @@ impl fmt::Display for ListRegistriesError {
             ListRegistriesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListRegistriesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListRegistriesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListRegistriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRegistriesError {}
 /// Errors returned by ListSchemaVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSchemaVersionsError {
     BadRequest(String),
 
@@ -1815,6 +1972,8 @@ pub enum ListSchemaVersionsError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSchemaVersionsError {
@@ -1822,29 +1981,34 @@ impl ListSchemaVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(ListSchemaVersionsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::Forbidden(err.msg))
+                    return RusotoError::service(ListSchemaVersionsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSchemaVersionsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::NotFound(err.msg))
+                    return RusotoError::service(ListSchemaVersionsError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSchemaVersionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        ListSchemaVersionsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSchemaVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1860,12 +2024,14 @@ impl fmt::Display for ListSchemaVersionsError {
             ListSchemaVersionsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListSchemaVersionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListSchemaVersionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListSchemaVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSchemaVersionsError {}
 /// Errors returned by ListSchemas
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSchemasError {
     BadRequest(String),
 
@@ -1876,6 +2042,8 @@ pub enum ListSchemasError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSchemasError {
@@ -1883,22 +2051,28 @@ impl ListSchemasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListSchemasError::BadRequest(err.msg))
+                    return RusotoError::service(ListSchemasError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListSchemasError::Forbidden(err.msg))
+                    return RusotoError::service(ListSchemasError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListSchemasError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListSchemasError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListSchemasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListSchemasError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListSchemasError::Unauthorized(err.msg))
+                    return RusotoError::service(ListSchemasError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSchemasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1913,12 +2087,14 @@ impl fmt::Display for ListSchemasError {
             ListSchemasError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListSchemasError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListSchemasError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListSchemasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSchemasError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     BadRequest(String),
 
@@ -1927,6 +2103,8 @@ pub enum ListTagsForResourceError {
     InternalServerError(String),
 
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1934,21 +2112,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1962,12 +2144,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by LockServiceLinkedRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum LockServiceLinkedRoleError {
     BadRequest(String),
 
@@ -1978,6 +2162,8 @@ pub enum LockServiceLinkedRoleError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl LockServiceLinkedRoleError {
@@ -1985,26 +2171,37 @@ impl LockServiceLinkedRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(LockServiceLinkedRoleError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        LockServiceLinkedRoleError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(LockServiceLinkedRoleError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        LockServiceLinkedRoleError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(LockServiceLinkedRoleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LockServiceLinkedRoleError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(LockServiceLinkedRoleError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LockServiceLinkedRoleError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(LockServiceLinkedRoleError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        LockServiceLinkedRoleError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(LockServiceLinkedRoleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2019,12 +2216,14 @@ impl fmt::Display for LockServiceLinkedRoleError {
             LockServiceLinkedRoleError::InternalServerError(ref cause) => write!(f, "{}", cause),
             LockServiceLinkedRoleError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             LockServiceLinkedRoleError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            LockServiceLinkedRoleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for LockServiceLinkedRoleError {}
 /// Errors returned by PutCodeBinding
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutCodeBindingError {
     BadRequest(String),
 
@@ -2039,6 +2238,8 @@ pub enum PutCodeBindingError {
     TooManyRequests(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutCodeBindingError {
@@ -2046,26 +2247,34 @@ impl PutCodeBindingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutCodeBindingError::BadRequest(err.msg))
+                    return RusotoError::service(PutCodeBindingError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutCodeBindingError::Forbidden(err.msg))
+                    return RusotoError::service(PutCodeBindingError::Forbidden(err.msg), &res)
+                }
+                "GoneException" => {
+                    return RusotoError::service(PutCodeBindingError::Gone(err.msg), &res)
                 }
-                "GoneException" => return RusotoError::Service(PutCodeBindingError::Gone(err.msg)),
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(PutCodeBindingError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        PutCodeBindingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutCodeBindingError::NotFound(err.msg))
+                    return RusotoError::service(PutCodeBindingError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutCodeBindingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        PutCodeBindingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutCodeBindingError::Unauthorized(err.msg))
+                    return RusotoError::service(PutCodeBindingError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutCodeBindingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2082,12 +2291,14 @@ impl fmt::Display for PutCodeBindingError {
             PutCodeBindingError::NotFound(ref cause) => write!(f, "{}", cause),
             PutCodeBindingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             PutCodeBindingError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PutCodeBindingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutCodeBindingError {}
 /// Errors returned by SearchSchemas
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchSchemasError {
     BadRequest(String),
 
@@ -2098,6 +2309,8 @@ pub enum SearchSchemasError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchSchemasError {
@@ -2105,22 +2318,28 @@ impl SearchSchemasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SearchSchemasError::BadRequest(err.msg))
+                    return RusotoError::service(SearchSchemasError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SearchSchemasError::Forbidden(err.msg))
+                    return RusotoError::service(SearchSchemasError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(SearchSchemasError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        SearchSchemasError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SearchSchemasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        SearchSchemasError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchSchemasError::Unauthorized(err.msg))
+                    return RusotoError::service(SearchSchemasError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchSchemasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2135,12 +2354,14 @@ impl fmt::Display for SearchSchemasError {
             SearchSchemasError::InternalServerError(ref cause) => write!(f, "{}", cause),
             SearchSchemasError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             SearchSchemasError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            SearchSchemasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchSchemasError {}
 /// Errors returned by StartDiscoverer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDiscovererError {
     BadRequest(String),
 
@@ -2153,6 +2374,8 @@ pub enum StartDiscovererError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDiscovererError {
@@ -2160,25 +2383,31 @@ impl StartDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(StartDiscovererError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(StartDiscovererError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartDiscovererError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        StartDiscovererError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(StartDiscovererError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        StartDiscovererError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(StartDiscovererError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartDiscovererError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2194,12 +2423,14 @@ impl fmt::Display for StartDiscovererError {
             StartDiscovererError::NotFound(ref cause) => write!(f, "{}", cause),
             StartDiscovererError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             StartDiscovererError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StartDiscovererError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartDiscovererError {}
 /// Errors returned by StopDiscoverer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopDiscovererError {
     BadRequest(String),
 
@@ -2212,6 +2443,8 @@ pub enum StopDiscovererError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopDiscovererError {
@@ -2219,25 +2452,31 @@ impl StopDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(StopDiscovererError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(StopDiscovererError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopDiscovererError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        StopDiscovererError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(StopDiscovererError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        StopDiscovererError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(StopDiscovererError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopDiscovererError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2253,12 +2492,14 @@ impl fmt::Display for StopDiscovererError {
             StopDiscovererError::NotFound(ref cause) => write!(f, "{}", cause),
             StopDiscovererError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             StopDiscovererError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StopDiscovererError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopDiscovererError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     BadRequest(String),
 
@@ -2267,6 +2508,8 @@ pub enum TagResourceError {
     InternalServerError(String),
 
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2274,19 +2517,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(TagResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2300,12 +2546,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::Forbidden(ref cause) => write!(f, "{}", cause),
             TagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UnlockServiceLinkedRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnlockServiceLinkedRoleError {
     BadRequest(String),
 
@@ -2316,6 +2564,8 @@ pub enum UnlockServiceLinkedRoleError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnlockServiceLinkedRoleError {
@@ -2323,28 +2573,37 @@ impl UnlockServiceLinkedRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UnlockServiceLinkedRoleError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UnlockServiceLinkedRoleError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UnlockServiceLinkedRoleError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UnlockServiceLinkedRoleError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UnlockServiceLinkedRoleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnlockServiceLinkedRoleError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UnlockServiceLinkedRoleError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnlockServiceLinkedRoleError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UnlockServiceLinkedRoleError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnlockServiceLinkedRoleError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnlockServiceLinkedRoleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2359,12 +2618,14 @@ impl fmt::Display for UnlockServiceLinkedRoleError {
             UnlockServiceLinkedRoleError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UnlockServiceLinkedRoleError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UnlockServiceLinkedRoleError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UnlockServiceLinkedRoleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnlockServiceLinkedRoleError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     BadRequest(String),
 
@@ -2373,6 +2634,8 @@ pub enum UntagResourceError {
     InternalServerError(String),
 
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2380,19 +2643,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(UntagResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2406,12 +2672,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::Forbidden(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDiscoverer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDiscovererError {
     BadRequest(String),
 
@@ -2424,6 +2692,8 @@ pub enum UpdateDiscovererError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDiscovererError {
@@ -2431,27 +2701,31 @@ impl UpdateDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateDiscovererError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateDiscovererError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateDiscovererError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDiscovererError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDiscovererError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateDiscovererError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateDiscovererError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDiscovererError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2467,12 +2741,14 @@ impl fmt::Display for UpdateDiscovererError {
             UpdateDiscovererError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDiscovererError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateDiscovererError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateDiscovererError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDiscovererError {}
 /// Errors returned by UpdateRegistry
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRegistryError {
     BadRequest(String),
 
@@ -2485,6 +2761,8 @@ pub enum UpdateRegistryError {
     ServiceUnavailable(String),
 
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRegistryError {
@@ -2492,25 +2770,31 @@ impl UpdateRegistryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRegistryError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateRegistryError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRegistryError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateRegistryError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateRegistryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateRegistryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRegistryError::NotFound(err.msg))
+                    return RusotoError::service(UpdateRegistryError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRegistryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateRegistryError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRegistryError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateRegistryError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRegistryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2526,12 +2810,14 @@ impl fmt::Display for UpdateRegistryError {
             UpdateRegistryError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateRegistryError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateRegistryError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateRegistryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRegistryError {}
 /// Errors returned by UpdateSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSchemaError {
     BadRequest(String),
 
@@ -2542,6 +2828,8 @@ pub enum UpdateSchemaError {
     NotFound(String),
 
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSchemaError {
@@ -2549,22 +2837,28 @@ impl UpdateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateSchemaError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateSchemaError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateSchemaError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateSchemaError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSchemaError::NotFound(err.msg))
+                    return RusotoError::service(UpdateSchemaError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateSchemaError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateSchemaError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2579,6 +2873,7 @@ impl fmt::Display for UpdateSchemaError {
             UpdateSchemaError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateSchemaError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateSchemaError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            UpdateSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2769,6 +3064,7 @@ impl SchemasClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SchemasClient {
         SchemasClient {
             client: Client::shared(),
@@ -2794,78 +3090,178 @@ impl SchemasClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SchemasClient {
         SchemasClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SchemasClient> {
+        rusoto_core::ClientBuilder::new(SchemasClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SchemasClient {
+        SchemasClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Schemas for SchemasClient {
-    /// <p>Creates a discoverer.</p>
-    async fn create_discoverer(
+impl SchemasClient {
+    /// Calls `create_discoverer`, also returning the metadata of the response.
+    pub async fn create_discoverer_with_metadata(
         &self,
         input: CreateDiscovererRequest,
-    ) -> Result<CreateDiscovererResponse, RusotoError<CreateDiscovererError>> {
+    ) -> Result<(CreateDiscovererResponse, ResponseMetadata), RusotoError<CreateDiscovererError>>
+    {
+        let request = self.build_create_discoverer_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDiscovererResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDiscovererError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_discoverer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_discoverer(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateDiscovererRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_discoverer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_discoverer_request(&self, input: CreateDiscovererRequest) -> SignedRequest {
         let request_uri = "/v1/discoverers";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("CreateDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_registry`, also returning the metadata of the response.
+    pub async fn create_registry_with_metadata(
+        &self,
+        input: CreateRegistryRequest,
+    ) -> Result<(CreateRegistryResponse, ResponseMetadata), RusotoError<CreateRegistryError>> {
+        let request = self.build_create_registry_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDiscovererResponse, _>()?;
+                .deserialize::<CreateRegistryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDiscovererError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateRegistryError::from_response(response))
         }
     }
 
-    /// <p>Creates a registry.</p>
-    async fn create_registry(
+    /// Presigns the request `create_registry` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_registry(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRegistryRequest,
-    ) -> Result<CreateRegistryResponse, RusotoError<CreateRegistryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_registry_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_registry_request(&self, input: CreateRegistryRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}",
             registry_name = input.registry_name
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("CreateRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_schema`, also returning the metadata of the response.
+    pub async fn create_schema_with_metadata(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<(CreateSchemaResponse, ResponseMetadata), RusotoError<CreateSchemaError>> {
+        let request = self.build_create_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRegistryResponse, _>()?;
+                .deserialize::<CreateSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRegistryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateSchemaError::from_response(response))
         }
     }
 
-    /// <p>Creates a schema definition.</p>
-    async fn create_schema(
+    /// Presigns the request `create_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSchemaRequest,
-    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_schema_request(&self, input: CreateSchemaRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas/name/{schema_name}",
             registry_name = input.registry_name,
@@ -2873,91 +3269,162 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("CreateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_discoverer`, also returning the metadata of the response.
+    pub async fn delete_discoverer_with_metadata(
+        &self,
+        input: DeleteDiscovererRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDiscovererError>> {
+        let request = self.build_delete_discoverer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSchemaResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDiscovererError::from_response(response))
         }
     }
 
-    /// <p>Deletes a discoverer.</p>
-    async fn delete_discoverer(
+    /// Presigns the request `delete_discoverer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_discoverer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDiscovererRequest,
-    ) -> Result<(), RusotoError<DeleteDiscovererError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_discoverer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_discoverer_request(&self, input: DeleteDiscovererRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/discoverers/id/{discoverer_id}",
             discoverer_id = input.discoverer_id
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_registry`, also returning the metadata of the response.
+    pub async fn delete_registry_with_metadata(
+        &self,
+        input: DeleteRegistryRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRegistryError>> {
+        let request = self.build_delete_registry_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDiscovererError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRegistryError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Registry.</p>
-    async fn delete_registry(
+    /// Presigns the request `delete_registry` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_registry(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRegistryRequest,
-    ) -> Result<(), RusotoError<DeleteRegistryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_registry_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_registry_request(&self, input: DeleteRegistryRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}",
             registry_name = input.registry_name
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_schema`, also returning the metadata of the response.
+    pub async fn delete_schema_with_metadata(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSchemaError>> {
+        let request = self.build_delete_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRegistryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteSchemaError::from_response(response))
         }
     }
 
-    /// <p>Delete a schema definition.</p>
-    async fn delete_schema(
+    /// Presigns the request `delete_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSchemaRequest,
-    ) -> Result<(), RusotoError<DeleteSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_schema_request(&self, input: DeleteSchemaRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas/name/{schema_name}",
             registry_name = input.registry_name,
@@ -2965,55 +3432,113 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_schema_version`, also returning the metadata of the response.
+    pub async fn delete_schema_version_with_metadata(
+        &self,
+        input: DeleteSchemaVersionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSchemaVersionError>> {
+        let request = self.build_delete_schema_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteSchemaVersionError::from_response(response))
         }
     }
 
-    /// <p>Delete the schema version definition</p>
-    async fn delete_schema_version(
+    /// Presigns the request `delete_schema_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_schema_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSchemaVersionRequest,
-    ) -> Result<(), RusotoError<DeleteSchemaVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_schema_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_schema_version_request(
+        &self,
+        input: DeleteSchemaVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/registries/name/{registry_name}/schemas/name/{schema_name}/version/{schema_version}", registry_name = input.registry_name, schema_name = input.schema_name, schema_version = input.schema_version);
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("DeleteSchemaVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_code_binding`, also returning the metadata of the response.
+    pub async fn describe_code_binding_with_metadata(
+        &self,
+        input: DescribeCodeBindingRequest,
+    ) -> Result<
+        (DescribeCodeBindingResponse, ResponseMetadata),
+        RusotoError<DescribeCodeBindingError>,
+    > {
+        let request = self.build_describe_code_binding_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCodeBindingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSchemaVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeCodeBindingError::from_response(response))
         }
     }
 
-    /// <p>Describe the code binding URI.</p>
-    async fn describe_code_binding(
+    /// Presigns the request `describe_code_binding` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_code_binding(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCodeBindingRequest,
-    ) -> Result<DescribeCodeBindingResponse, RusotoError<DescribeCodeBindingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_code_binding_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_code_binding_request(
+        &self,
+        input: DescribeCodeBindingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas/name/{schema_name}/language/{language}",
             language = input.language,
@@ -3022,6 +3547,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeCodeBinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3030,88 +3556,161 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_discoverer`, also returning the metadata of the response.
+    pub async fn describe_discoverer_with_metadata(
+        &self,
+        input: DescribeDiscovererRequest,
+    ) -> Result<(DescribeDiscovererResponse, ResponseMetadata), RusotoError<DescribeDiscovererError>>
+    {
+        let request = self.build_describe_discoverer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCodeBindingResponse, _>()?;
+                .deserialize::<DescribeDiscovererResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCodeBindingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDiscovererError::from_response(response))
         }
     }
 
-    /// <p>Describes the discoverer.</p>
-    async fn describe_discoverer(
+    /// Presigns the request `describe_discoverer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_discoverer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDiscovererRequest,
-    ) -> Result<DescribeDiscovererResponse, RusotoError<DescribeDiscovererError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_discoverer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_discoverer_request(&self, input: DescribeDiscovererRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/discoverers/id/{discoverer_id}",
             discoverer_id = input.discoverer_id
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_registry`, also returning the metadata of the response.
+    pub async fn describe_registry_with_metadata(
+        &self,
+        input: DescribeRegistryRequest,
+    ) -> Result<(DescribeRegistryResponse, ResponseMetadata), RusotoError<DescribeRegistryError>>
+    {
+        let request = self.build_describe_registry_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDiscovererResponse, _>()?;
+                .deserialize::<DescribeRegistryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDiscovererError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeRegistryError::from_response(response))
         }
     }
 
-    /// <p>Describes the registry.</p>
-    async fn describe_registry(
+    /// Presigns the request `describe_registry` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_registry(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRegistryRequest,
-    ) -> Result<DescribeRegistryResponse, RusotoError<DescribeRegistryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_registry_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_registry_request(&self, input: DescribeRegistryRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}",
             registry_name = input.registry_name
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_schema`, also returning the metadata of the response.
+    pub async fn describe_schema_with_metadata(
+        &self,
+        input: DescribeSchemaRequest,
+    ) -> Result<(DescribeSchemaResponse, ResponseMetadata), RusotoError<DescribeSchemaError>> {
+        let request = self.build_describe_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRegistryResponse, _>()?;
+                .deserialize::<DescribeSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRegistryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeSchemaError::from_response(response))
         }
     }
 
-    /// <p>Retrieve the schema definition.</p>
-    async fn describe_schema(
+    /// Presigns the request `describe_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSchemaRequest,
-    ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_schema_request(&self, input: DescribeSchemaRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas/name/{schema_name}",
             registry_name = input.registry_name,
@@ -3119,6 +3718,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("DescribeSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3127,31 +3727,62 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_code_binding_source`, also returning the metadata of the response.
+    pub async fn get_code_binding_source_with_metadata(
+        &self,
+        input: GetCodeBindingSourceRequest,
+    ) -> Result<
+        (GetCodeBindingSourceResponse, ResponseMetadata),
+        RusotoError<GetCodeBindingSourceError>,
+    > {
+        let request = self.build_get_code_binding_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSchemaResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+
+            let mut result = GetCodeBindingSourceResponse::default();
+            result.body = Some(response.body);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetCodeBindingSourceError::from_response(response))
         }
     }
 
-    /// <p>Get the code binding source URI.</p>
-    async fn get_code_binding_source(
+    /// Presigns the request `get_code_binding_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_code_binding_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCodeBindingSourceRequest,
-    ) -> Result<GetCodeBindingSourceResponse, RusotoError<GetCodeBindingSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_code_binding_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_code_binding_source_request(
+        &self,
+        input: GetCodeBindingSourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/registries/name/{registry_name}/schemas/name/{schema_name}/language/{language}/source", language = input.language, registry_name = input.registry_name, schema_name = input.schema_name);
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("GetCodeBindingSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3160,62 +3791,116 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_discovered_schema`, also returning the metadata of the response.
+    pub async fn get_discovered_schema_with_metadata(
+        &self,
+        input: GetDiscoveredSchemaRequest,
+    ) -> Result<
+        (GetDiscoveredSchemaResponse, ResponseMetadata),
+        RusotoError<GetDiscoveredSchemaError>,
+    > {
+        let request = self.build_get_discovered_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-
-            let mut result = GetCodeBindingSourceResponse::default();
-            result.body = Some(response.body);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDiscoveredSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCodeBindingSourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDiscoveredSchemaError::from_response(response))
         }
     }
 
-    /// <p>Get the discovered schema that was generated based on sampled events.</p>
-    async fn get_discovered_schema(
+    /// Presigns the request `get_discovered_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_discovered_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDiscoveredSchemaRequest,
-    ) -> Result<GetDiscoveredSchemaResponse, RusotoError<GetDiscoveredSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_discovered_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_discovered_schema_request(
+        &self,
+        input: GetDiscoveredSchemaRequest,
+    ) -> SignedRequest {
         let request_uri = "/v1/discover";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("GetDiscoveredSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_discoverers`, also returning the metadata of the response.
+    pub async fn list_discoverers_with_metadata(
+        &self,
+        input: ListDiscoverersRequest,
+    ) -> Result<(ListDiscoverersResponse, ResponseMetadata), RusotoError<ListDiscoverersError>>
+    {
+        let request = self.build_list_discoverers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDiscoveredSchemaResponse, _>()?;
+                .deserialize::<ListDiscoverersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDiscoveredSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDiscoverersError::from_response(response))
         }
     }
 
-    /// <p>List the discoverers.</p>
-    async fn list_discoverers(
+    /// Presigns the request `list_discoverers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_discoverers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDiscoverersRequest,
-    ) -> Result<ListDiscoverersResponse, RusotoError<ListDiscoverersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_discoverers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_discoverers_request(&self, input: ListDiscoverersRequest) -> SignedRequest {
         let request_uri = "/v1/discoverers";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListDiscoverers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3233,31 +3918,55 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_registries`, also returning the metadata of the response.
+    pub async fn list_registries_with_metadata(
+        &self,
+        input: ListRegistriesRequest,
+    ) -> Result<(ListRegistriesResponse, ResponseMetadata), RusotoError<ListRegistriesError>> {
+        let request = self.build_list_registries_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDiscoverersResponse, _>()?;
+                .deserialize::<ListRegistriesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDiscoverersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRegistriesError::from_response(response))
         }
     }
 
-    /// <p>List the registries.</p>
-    async fn list_registries(
+    /// Presigns the request `list_registries` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_registries(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRegistriesRequest,
-    ) -> Result<ListRegistriesResponse, RusotoError<ListRegistriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_registries_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_registries_request(&self, input: ListRegistriesRequest) -> SignedRequest {
         let request_uri = "/v1/registries";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListRegistries");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3275,28 +3984,55 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_schema_versions`, also returning the metadata of the response.
+    pub async fn list_schema_versions_with_metadata(
+        &self,
+        input: ListSchemaVersionsRequest,
+    ) -> Result<(ListSchemaVersionsResponse, ResponseMetadata), RusotoError<ListSchemaVersionsError>>
+    {
+        let request = self.build_list_schema_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRegistriesResponse, _>()?;
+                .deserialize::<ListSchemaVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRegistriesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSchemaVersionsError::from_response(response))
         }
     }
 
-    /// <p>Provides a list of the schema versions and related information.</p>
-    async fn list_schema_versions(
+    /// Presigns the request `list_schema_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_schema_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSchemaVersionsRequest,
-    ) -> Result<ListSchemaVersionsResponse, RusotoError<ListSchemaVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_schema_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_schema_versions_request(
+        &self,
+        input: ListSchemaVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas/name/{schema_name}/versions",
             registry_name = input.registry_name,
@@ -3304,6 +4040,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListSchemaVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3315,34 +4052,58 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_schemas`, also returning the metadata of the response.
+    pub async fn list_schemas_with_metadata(
+        &self,
+        input: ListSchemasRequest,
+    ) -> Result<(ListSchemasResponse, ResponseMetadata), RusotoError<ListSchemasError>> {
+        let request = self.build_list_schemas_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSchemaVersionsResponse, _>()?;
+                .deserialize::<ListSchemasResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSchemaVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSchemasError::from_response(response))
         }
     }
 
-    /// <p>List the schemas.</p>
-    async fn list_schemas(
+    /// Presigns the request `list_schemas` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_schemas(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSchemasRequest,
-    ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_schemas_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_schemas_request(&self, input: ListSchemasRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas",
             registry_name = input.registry_name
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListSchemas");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3357,84 +4118,168 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSchemasResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSchemasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Get tags for resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `lock_service_linked_role`, also returning the metadata of the response.
+    pub async fn lock_service_linked_role_with_metadata(
+        &self,
+        input: LockServiceLinkedRoleRequest,
+    ) -> Result<
+        (LockServiceLinkedRoleResponse, ResponseMetadata),
+        RusotoError<LockServiceLinkedRoleError>,
+    > {
+        let request = self.build_lock_service_linked_role_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<LockServiceLinkedRoleResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(LockServiceLinkedRoleError::from_response(response))
         }
     }
 
-    async fn lock_service_linked_role(
+    /// Presigns the request `lock_service_linked_role` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_lock_service_linked_role(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: LockServiceLinkedRoleRequest,
-    ) -> Result<LockServiceLinkedRoleResponse, RusotoError<LockServiceLinkedRoleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_lock_service_linked_role_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_lock_service_linked_role_request(
+        &self,
+        input: LockServiceLinkedRoleRequest,
+    ) -> SignedRequest {
         let request_uri = "/slr-deletion/lock";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("LockServiceLinkedRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_code_binding`, also returning the metadata of the response.
+    pub async fn put_code_binding_with_metadata(
+        &self,
+        input: PutCodeBindingRequest,
+    ) -> Result<(PutCodeBindingResponse, ResponseMetadata), RusotoError<PutCodeBindingError>> {
+        let request = self.build_put_code_binding_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<LockServiceLinkedRoleResponse, _>()?;
+                .deserialize::<PutCodeBindingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(LockServiceLinkedRoleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutCodeBindingError::from_response(response))
         }
     }
 
-    /// <p>Put code binding URI</p>
-    async fn put_code_binding(
+    /// Presigns the request `put_code_binding` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_code_binding(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutCodeBindingRequest,
-    ) -> Result<PutCodeBindingResponse, RusotoError<PutCodeBindingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_code_binding_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_code_binding_request(&self, input: PutCodeBindingRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas/name/{schema_name}/language/{language}",
             language = input.language,
@@ -3443,6 +4288,7 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("PutCodeBinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3451,34 +4297,58 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `search_schemas`, also returning the metadata of the response.
+    pub async fn search_schemas_with_metadata(
+        &self,
+        input: SearchSchemasRequest,
+    ) -> Result<(SearchSchemasResponse, ResponseMetadata), RusotoError<SearchSchemasError>> {
+        let request = self.build_search_schemas_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutCodeBindingResponse, _>()?;
+                .deserialize::<SearchSchemasResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutCodeBindingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SearchSchemasError::from_response(response))
         }
     }
 
-    /// <p>Search the schemas</p>
-    async fn search_schemas(
+    /// Presigns the request `search_schemas` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_schemas(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchSchemasRequest,
-    ) -> Result<SearchSchemasResponse, RusotoError<SearchSchemasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_schemas_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_schemas_request(&self, input: SearchSchemasRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas/search",
             registry_name = input.registry_name
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation("SearchSchemas");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3491,149 +4361,276 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `start_discoverer`, also returning the metadata of the response.
+    pub async fn start_discoverer_with_metadata(
+        &self,
+        input: StartDiscovererRequest,
+    ) -> Result<(StartDiscovererResponse, ResponseMetadata), RusotoError<StartDiscovererError>>
+    {
+        let request = self.build_start_discoverer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchSchemasResponse, _>()?;
+                .deserialize::<StartDiscovererResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchSchemasError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartDiscovererError::from_response(response))
         }
     }
 
-    /// <p>Starts the discoverer</p>
-    async fn start_discoverer(
+    /// Presigns the request `start_discoverer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_discoverer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDiscovererRequest,
-    ) -> Result<StartDiscovererResponse, RusotoError<StartDiscovererError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_discoverer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_discoverer_request(&self, input: StartDiscovererRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/discoverers/id/{discoverer_id}/start",
             discoverer_id = input.discoverer_id
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("StartDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `stop_discoverer`, also returning the metadata of the response.
+    pub async fn stop_discoverer_with_metadata(
+        &self,
+        input: StopDiscovererRequest,
+    ) -> Result<(StopDiscovererResponse, ResponseMetadata), RusotoError<StopDiscovererError>> {
+        let request = self.build_stop_discoverer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartDiscovererResponse, _>()?;
+                .deserialize::<StopDiscovererResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDiscovererError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopDiscovererError::from_response(response))
         }
     }
 
-    /// <p>Stops the discoverer</p>
-    async fn stop_discoverer(
+    /// Presigns the request `stop_discoverer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_discoverer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopDiscovererRequest,
-    ) -> Result<StopDiscovererResponse, RusotoError<StopDiscovererError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_discoverer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_discoverer_request(&self, input: StopDiscovererRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/discoverers/id/{discoverer_id}/stop",
             discoverer_id = input.discoverer_id
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("StopDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopDiscovererResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopDiscovererError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Add tags to a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `unlock_service_linked_role`, also returning the metadata of the response.
+    pub async fn unlock_service_linked_role_with_metadata(
+        &self,
+        input: UnlockServiceLinkedRoleRequest,
+    ) -> Result<
+        (UnlockServiceLinkedRoleResponse, ResponseMetadata),
+        RusotoError<UnlockServiceLinkedRoleError>,
+    > {
+        let request = self.build_unlock_service_linked_role_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UnlockServiceLinkedRoleResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UnlockServiceLinkedRoleError::from_response(response))
         }
     }
 
-    async fn unlock_service_linked_role(
+    /// Presigns the request `unlock_service_linked_role` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unlock_service_linked_role(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnlockServiceLinkedRoleRequest,
-    ) -> Result<UnlockServiceLinkedRoleResponse, RusotoError<UnlockServiceLinkedRoleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unlock_service_linked_role_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unlock_service_linked_role_request(
+        &self,
+        input: UnlockServiceLinkedRoleRequest,
+    ) -> SignedRequest {
         let request_uri = "/slr-deletion/unlock";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation("UnlockServiceLinkedRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UnlockServiceLinkedRoleResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UnlockServiceLinkedRoleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3642,93 +4639,166 @@ impl Schemas for SchemasClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_discoverer`, also returning the metadata of the response.
+    pub async fn update_discoverer_with_metadata(
+        &self,
+        input: UpdateDiscovererRequest,
+    ) -> Result<(UpdateDiscovererResponse, ResponseMetadata), RusotoError<UpdateDiscovererError>>
+    {
+        let request = self.build_update_discoverer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDiscovererResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDiscovererError::from_response(response))
         }
     }
 
-    /// <p>Updates the discoverer</p>
-    async fn update_discoverer(
+    /// Presigns the request `update_discoverer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_discoverer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDiscovererRequest,
-    ) -> Result<UpdateDiscovererResponse, RusotoError<UpdateDiscovererError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_discoverer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_discoverer_request(&self, input: UpdateDiscovererRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/discoverers/id/{discoverer_id}",
             discoverer_id = input.discoverer_id
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("UpdateDiscoverer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_registry`, also returning the metadata of the response.
+    pub async fn update_registry_with_metadata(
+        &self,
+        input: UpdateRegistryRequest,
+    ) -> Result<(UpdateRegistryResponse, ResponseMetadata), RusotoError<UpdateRegistryError>> {
+        let request = self.build_update_registry_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDiscovererResponse, _>()?;
+                .deserialize::<UpdateRegistryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDiscovererError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRegistryError::from_response(response))
         }
     }
 
-    /// <p>Updates a registry.</p>
-    async fn update_registry(
+    /// Presigns the request `update_registry` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_registry(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRegistryRequest,
-    ) -> Result<UpdateRegistryResponse, RusotoError<UpdateRegistryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_registry_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_registry_request(&self, input: UpdateRegistryRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}",
             registry_name = input.registry_name
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("UpdateRegistry");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_schema`, also returning the metadata of the response.
+    pub async fn update_schema_with_metadata(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> Result<(UpdateSchemaResponse, ResponseMetadata), RusotoError<UpdateSchemaError>> {
+        let request = self.build_update_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRegistryResponse, _>()?;
+                .deserialize::<UpdateSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRegistryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateSchemaError::from_response(response))
         }
     }
 
-    /// <p>Updates the schema definition</p>
-    async fn update_schema(
+    /// Presigns the request `update_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSchemaRequest,
-    ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_schema_request(&self, input: UpdateSchemaRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas/name/{schema_name}",
             registry_name = input.registry_name,
@@ -3736,25 +4806,545 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation("UpdateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListDiscoverers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_discoverers_pages(
+        &self,
+        input: ListDiscoverersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDiscoverersResponse, RusotoError<ListDiscoverersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input| async move { client.list_discoverers(input).await },
+            |input: &ListDiscoverersRequest, output: &ListDiscoverersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDiscoverersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Discoverers` of every page of `ListDiscoverers` results for `input`, as `list_discoverers_pages` requests them.
+    pub fn list_discoverers_stream(
+        &self,
+        input: ListDiscoverersRequest,
+    ) -> impl futures::Stream<Item = Result<DiscovererSummary, RusotoError<ListDiscoverersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_discoverers_pages(input), |page| {
+            page.discoverers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRegistries` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_registries_pages(
+        &self,
+        input: ListRegistriesRequest,
+    ) -> impl futures::Stream<Item = Result<ListRegistriesResponse, RusotoError<ListRegistriesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input| async move { client.list_registries(input).await },
+            |input: &ListRegistriesRequest, output: &ListRegistriesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRegistriesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Registries` of every page of `ListRegistries` results for `input`, as `list_registries_pages` requests them.
+    pub fn list_registries_stream(
+        &self,
+        input: ListRegistriesRequest,
+    ) -> impl futures::Stream<Item = Result<RegistrySummary, RusotoError<ListRegistriesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_registries_pages(input), |page| {
+            page.registries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSchemaVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_schema_versions_pages(
+        &self,
+        input: ListSchemaVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListSchemaVersionsResponse, RusotoError<ListSchemaVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input| async move { client.list_schema_versions(input).await },
+            |input: &ListSchemaVersionsRequest, output: &ListSchemaVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSchemaVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SchemaVersions` of every page of `ListSchemaVersions` results for `input`, as `list_schema_versions_pages` requests them.
+    pub fn list_schema_versions_stream(
+        &self,
+        input: ListSchemaVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SchemaVersionSummary, RusotoError<ListSchemaVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_schema_versions_pages(input), |page| {
+            page.schema_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSchemas` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_schemas_pages(
+        &self,
+        input: ListSchemasRequest,
+    ) -> impl futures::Stream<Item = Result<ListSchemasResponse, RusotoError<ListSchemasError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input| async move { client.list_schemas(input).await },
+            |input: &ListSchemasRequest, output: &ListSchemasResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSchemasRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Schemas` of every page of `ListSchemas` results for `input`, as `list_schemas_pages` requests them.
+    pub fn list_schemas_stream(
+        &self,
+        input: ListSchemasRequest,
+    ) -> impl futures::Stream<Item = Result<SchemaSummary, RusotoError<ListSchemasError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_schemas_pages(input), |page| {
+            page.schemas.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SearchSchemas` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn search_schemas_pages(
+        &self,
+        input: SearchSchemasRequest,
+    ) -> impl futures::Stream<Item = Result<SearchSchemasResponse, RusotoError<SearchSchemasError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SchemasClient, input| async move { client.search_schemas(input).await },
+            |input: &SearchSchemasRequest, output: &SearchSchemasResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchSchemasRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Schemas` of every page of `SearchSchemas` results for `input`, as `search_schemas_pages` requests them.
+    pub fn search_schemas_stream(
+        &self,
+        input: SearchSchemasRequest,
+    ) -> impl futures::Stream<Item = Result<SearchSchemaSummary, RusotoError<SearchSchemasError>>> + Send
+    {
+        rusoto_core::pagination::items(self.search_schemas_pages(input), |page| {
+            page.schemas.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `CodeBindingExists`, polling `DescribeCodeBinding` with `input` every 2 seconds, up to 30 times.
+    pub async fn wait_until_code_binding_exists(
+        &self,
+        input: DescribeCodeBindingRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCodeBindingError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(2), 30);
+        self.wait_until_code_binding_exists_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSchemaResponse, _>()?;
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSchemaError::from_response(response))
-        }
+    /// Waits for `CodeBindingExists` like `wait_until_code_binding_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_code_binding_exists_with(
+        &self,
+        input: DescribeCodeBindingRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCodeBindingError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_code_binding(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "CREATE_COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "CREATE_IN_PROGRESS"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "CREATE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("NotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Schemas for SchemasClient {
+    /// <p>Creates a discoverer.</p>
+    async fn create_discoverer(
+        &self,
+        input: CreateDiscovererRequest,
+    ) -> Result<CreateDiscovererResponse, RusotoError<CreateDiscovererError>> {
+        self.create_discoverer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a registry.</p>
+    async fn create_registry(
+        &self,
+        input: CreateRegistryRequest,
+    ) -> Result<CreateRegistryResponse, RusotoError<CreateRegistryError>> {
+        self.create_registry_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a schema definition.</p>
+    async fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+        self.create_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a discoverer.</p>
+    async fn delete_discoverer(
+        &self,
+        input: DeleteDiscovererRequest,
+    ) -> Result<(), RusotoError<DeleteDiscovererError>> {
+        self.delete_discoverer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Registry.</p>
+    async fn delete_registry(
+        &self,
+        input: DeleteRegistryRequest,
+    ) -> Result<(), RusotoError<DeleteRegistryError>> {
+        self.delete_registry_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a schema definition.</p>
+    async fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<(), RusotoError<DeleteSchemaError>> {
+        self.delete_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete the schema version definition</p>
+    async fn delete_schema_version(
+        &self,
+        input: DeleteSchemaVersionRequest,
+    ) -> Result<(), RusotoError<DeleteSchemaVersionError>> {
+        self.delete_schema_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe the code binding URI.</p>
+    async fn describe_code_binding(
+        &self,
+        input: DescribeCodeBindingRequest,
+    ) -> Result<DescribeCodeBindingResponse, RusotoError<DescribeCodeBindingError>> {
+        self.describe_code_binding_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the discoverer.</p>
+    async fn describe_discoverer(
+        &self,
+        input: DescribeDiscovererRequest,
+    ) -> Result<DescribeDiscovererResponse, RusotoError<DescribeDiscovererError>> {
+        self.describe_discoverer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the registry.</p>
+    async fn describe_registry(
+        &self,
+        input: DescribeRegistryRequest,
+    ) -> Result<DescribeRegistryResponse, RusotoError<DescribeRegistryError>> {
+        self.describe_registry_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve the schema definition.</p>
+    async fn describe_schema(
+        &self,
+        input: DescribeSchemaRequest,
+    ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
+        self.describe_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the code binding source URI.</p>
+    async fn get_code_binding_source(
+        &self,
+        input: GetCodeBindingSourceRequest,
+    ) -> Result<GetCodeBindingSourceResponse, RusotoError<GetCodeBindingSourceError>> {
+        self.get_code_binding_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the discovered schema that was generated based on sampled events.</p>
+    async fn get_discovered_schema(
+        &self,
+        input: GetDiscoveredSchemaRequest,
+    ) -> Result<GetDiscoveredSchemaResponse, RusotoError<GetDiscoveredSchemaError>> {
+        self.get_discovered_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the discoverers.</p>
+    async fn list_discoverers(
+        &self,
+        input: ListDiscoverersRequest,
+    ) -> Result<ListDiscoverersResponse, RusotoError<ListDiscoverersError>> {
+        self.list_discoverers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the registries.</p>
+    async fn list_registries(
+        &self,
+        input: ListRegistriesRequest,
+    ) -> Result<ListRegistriesResponse, RusotoError<ListRegistriesError>> {
+        self.list_registries_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a list of the schema versions and related information.</p>
+    async fn list_schema_versions(
+        &self,
+        input: ListSchemaVersionsRequest,
+    ) -> Result<ListSchemaVersionsResponse, RusotoError<ListSchemaVersionsError>> {
+        self.list_schema_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the schemas.</p>
+    async fn list_schemas(
+        &self,
+        input: ListSchemasRequest,
+    ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
+        self.list_schemas_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get tags for resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn lock_service_linked_role(
+        &self,
+        input: LockServiceLinkedRoleRequest,
+    ) -> Result<LockServiceLinkedRoleResponse, RusotoError<LockServiceLinkedRoleError>> {
+        self.lock_service_linked_role_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Put code binding URI</p>
+    async fn put_code_binding(
+        &self,
+        input: PutCodeBindingRequest,
+    ) -> Result<PutCodeBindingResponse, RusotoError<PutCodeBindingError>> {
+        self.put_code_binding_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Search the schemas</p>
+    async fn search_schemas(
+        &self,
+        input: SearchSchemasRequest,
+    ) -> Result<SearchSchemasResponse, RusotoError<SearchSchemasError>> {
+        self.search_schemas_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the discoverer</p>
+    async fn start_discoverer(
+        &self,
+        input: StartDiscovererRequest,
+    ) -> Result<StartDiscovererResponse, RusotoError<StartDiscovererError>> {
+        self.start_discoverer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the discoverer</p>
+    async fn stop_discoverer(
+        &self,
+        input: StopDiscovererRequest,
+    ) -> Result<StopDiscovererResponse, RusotoError<StopDiscovererError>> {
+        self.stop_discoverer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add tags to a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn unlock_service_linked_role(
+        &self,
+        input: UnlockServiceLinkedRoleRequest,
+    ) -> Result<UnlockServiceLinkedRoleResponse, RusotoError<UnlockServiceLinkedRoleError>> {
+        self.unlock_service_linked_role_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the discoverer</p>
+    async fn update_discoverer(
+        &self,
+        input: UpdateDiscovererRequest,
+    ) -> Result<UpdateDiscovererResponse, RusotoError<UpdateDiscovererError>> {
+        self.update_discoverer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a registry.</p>
+    async fn update_registry(
+        &self,
+        input: UpdateRegistryRequest,
+    ) -> Result<UpdateRegistryResponse, RusotoError<UpdateRegistryError>> {
+        self.update_registry_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the schema definition</p>
+    async fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
+        self.update_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -883,7 +883,11 @@ impl UpdateConditionSerializer {
 
 /// Errors returned by BatchDeleteAttributes
 #[derive(Debug, PartialEq)]
-pub enum BatchDeleteAttributesError {}
+#[non_exhaustive]
+pub enum BatchDeleteAttributesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl BatchDeleteAttributesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchDeleteAttributesError> {
@@ -891,10 +895,8 @@ impl BatchDeleteAttributesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(BatchDeleteAttributesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -911,12 +913,15 @@ impl BatchDeleteAttributesError {
 impl fmt::Display for BatchDeleteAttributesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            BatchDeleteAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for BatchDeleteAttributesError {}
 /// Errors returned by BatchPutAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchPutAttributesError {
     /// <p>The item name was specified more than once. </p>
     DuplicateItemName(String),
@@ -936,6 +941,8 @@ pub enum BatchPutAttributesError {
     NumberSubmittedAttributesExceeded(String),
     /// <p>Too many items exist in a single call.</p>
     NumberSubmittedItemsExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchPutAttributesError {
@@ -947,61 +954,70 @@ impl BatchPutAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateItemName" => {
-                        return RusotoError::Service(BatchPutAttributesError::DuplicateItemName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            BatchPutAttributesError::DuplicateItemName(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchPutAttributesError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(BatchPutAttributesError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            BatchPutAttributesError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(BatchPutAttributesError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            BatchPutAttributesError::NoSuchDomain(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NumberDomainAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchPutAttributesError::NumberDomainAttributesExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NumberDomainBytesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchPutAttributesError::NumberDomainBytesExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NumberItemAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchPutAttributesError::NumberItemAttributesExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NumberSubmittedAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchPutAttributesError::NumberSubmittedAttributesExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NumberSubmittedItemsExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchPutAttributesError::NumberSubmittedItemsExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(BatchPutAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -1037,12 +1053,14 @@ impl fmt::Display for BatchPutAttributesError {
             BatchPutAttributesError::NumberSubmittedItemsExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchPutAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchPutAttributesError {}
 /// Errors returned by CreateDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDomainError {
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
@@ -1050,6 +1068,8 @@ pub enum CreateDomainError {
     MissingParameter(String),
     /// <p>Too many domains exist per this account.</p>
     NumberDomainsExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDomainError {
@@ -1061,21 +1081,24 @@ impl CreateDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CreateDomainError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDomainError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(CreateDomainError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDomainError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NumberDomainsExceeded" => {
-                        return RusotoError::Service(CreateDomainError::NumberDomainsExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDomainError::NumberDomainsExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateDomainError::Unhandled, &res),
                 }
             }
         }
@@ -1097,12 +1120,14 @@ impl fmt::Display for CreateDomainError {
             CreateDomainError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             CreateDomainError::MissingParameter(ref cause) => write!(f, "{}", cause),
             CreateDomainError::NumberDomainsExceeded(ref cause) => write!(f, "{}", cause),
+            CreateDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDomainError {}
 /// Errors returned by DeleteAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAttributesError {
     /// <p>The specified attribute does not exist.</p>
     AttributeDoesNotExist(String),
@@ -1112,6 +1137,8 @@ pub enum DeleteAttributesError {
     MissingParameter(String),
     /// <p>The specified domain does not exist.</p>
     NoSuchDomain(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAttributesError {
@@ -1123,26 +1150,30 @@ impl DeleteAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AttributeDoesNotExist" => {
-                        return RusotoError::Service(DeleteAttributesError::AttributeDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAttributesError::AttributeDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteAttributesError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAttributesError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(DeleteAttributesError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAttributesError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(DeleteAttributesError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAttributesError::NoSuchDomain(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -1165,15 +1196,19 @@ impl fmt::Display for DeleteAttributesError {
             DeleteAttributesError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteAttributesError::MissingParameter(ref cause) => write!(f, "{}", cause),
             DeleteAttributesError::NoSuchDomain(ref cause) => write!(f, "{}", cause),
+            DeleteAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAttributesError {}
 /// Errors returned by DeleteDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDomainError {
     /// <p>The request must contain the specified missing parameter.</p>
     MissingParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDomainError {
@@ -1185,11 +1220,12 @@ impl DeleteDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MissingParameter" => {
-                        return RusotoError::Service(DeleteDomainError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDomainError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDomainError::Unhandled, &res),
                 }
             }
         }
@@ -1209,17 +1245,21 @@ impl fmt::Display for DeleteDomainError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteDomainError::MissingParameter(ref cause) => write!(f, "{}", cause),
+            DeleteDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDomainError {}
 /// Errors returned by DomainMetadata
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DomainMetadataError {
     /// <p>The request must contain the specified missing parameter.</p>
     MissingParameter(String),
     /// <p>The specified domain does not exist.</p>
     NoSuchDomain(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DomainMetadataError {
@@ -1231,16 +1271,18 @@ impl DomainMetadataError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MissingParameter" => {
-                        return RusotoError::Service(DomainMetadataError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DomainMetadataError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(DomainMetadataError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DomainMetadataError::NoSuchDomain(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DomainMetadataError::Unhandled, &res),
                 }
             }
         }
@@ -1261,12 +1303,14 @@ impl fmt::Display for DomainMetadataError {
         match *self {
             DomainMetadataError::MissingParameter(ref cause) => write!(f, "{}", cause),
             DomainMetadataError::NoSuchDomain(ref cause) => write!(f, "{}", cause),
+            DomainMetadataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DomainMetadataError {}
 /// Errors returned by GetAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAttributesError {
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
@@ -1274,6 +1318,8 @@ pub enum GetAttributesError {
     MissingParameter(String),
     /// <p>The specified domain does not exist.</p>
     NoSuchDomain(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAttributesError {
@@ -1285,21 +1331,24 @@ impl GetAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(GetAttributesError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetAttributesError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(GetAttributesError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetAttributesError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(GetAttributesError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetAttributesError::NoSuchDomain(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -1321,17 +1370,21 @@ impl fmt::Display for GetAttributesError {
             GetAttributesError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             GetAttributesError::MissingParameter(ref cause) => write!(f, "{}", cause),
             GetAttributesError::NoSuchDomain(ref cause) => write!(f, "{}", cause),
+            GetAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAttributesError {}
 /// Errors returned by ListDomains
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDomainsError {
     /// <p>The specified NextToken is not valid. </p>
     InvalidNextToken(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDomainsError {
@@ -1343,16 +1396,18 @@ impl ListDomainsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(ListDomainsError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListDomainsError::InvalidNextToken(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ListDomainsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListDomainsError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListDomainsError::Unhandled, &res),
                 }
             }
         }
@@ -1373,12 +1428,14 @@ impl fmt::Display for ListDomainsError {
         match *self {
             ListDomainsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListDomainsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
+            ListDomainsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDomainsError {}
 /// Errors returned by PutAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAttributesError {
     /// <p>The specified attribute does not exist.</p>
     AttributeDoesNotExist(String),
@@ -1394,6 +1451,8 @@ pub enum PutAttributesError {
     NumberDomainBytesExceeded(String),
     /// <p>Too many attributes in this item.</p>
     NumberItemAttributesExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAttributesError {
@@ -1405,43 +1464,50 @@ impl PutAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AttributeDoesNotExist" => {
-                        return RusotoError::Service(PutAttributesError::AttributeDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutAttributesError::AttributeDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(PutAttributesError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutAttributesError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(PutAttributesError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutAttributesError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(PutAttributesError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutAttributesError::NoSuchDomain(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NumberDomainAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutAttributesError::NumberDomainAttributesExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NumberDomainBytesExceeded" => {
-                        return RusotoError::Service(PutAttributesError::NumberDomainBytesExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutAttributesError::NumberDomainBytesExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NumberItemAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutAttributesError::NumberItemAttributesExceeded(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -1467,12 +1533,14 @@ impl fmt::Display for PutAttributesError {
             PutAttributesError::NumberDomainAttributesExceeded(ref cause) => write!(f, "{}", cause),
             PutAttributesError::NumberDomainBytesExceeded(ref cause) => write!(f, "{}", cause),
             PutAttributesError::NumberItemAttributesExceeded(ref cause) => write!(f, "{}", cause),
+            PutAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutAttributesError {}
 /// Errors returned by Select
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SelectError {
     /// <p>The specified NextToken is not valid. </p>
     InvalidNextToken(String),
@@ -1492,6 +1560,8 @@ pub enum SelectError {
     RequestTimeout(String),
     /// <p>Too many attributes requested.</p>
     TooManyRequestedAttributes(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SelectError {
@@ -1503,51 +1573,60 @@ impl SelectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(SelectError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SelectError::InvalidNextToken(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidNumberPredicates" => {
-                        return RusotoError::Service(SelectError::InvalidNumberPredicates(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SelectError::InvalidNumberPredicates(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidNumberValueTests" => {
-                        return RusotoError::Service(SelectError::InvalidNumberValueTests(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SelectError::InvalidNumberValueTests(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(SelectError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SelectError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidQueryExpression" => {
-                        return RusotoError::Service(SelectError::InvalidQueryExpression(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SelectError::InvalidQueryExpression(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(SelectError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SelectError::MissingParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(SelectError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SelectError::NoSuchDomain(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RequestTimeout" => {
-                        return RusotoError::Service(SelectError::RequestTimeout(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SelectError::RequestTimeout(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyRequestedAttributes" => {
-                        return RusotoError::Service(SelectError::TooManyRequestedAttributes(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SelectError::TooManyRequestedAttributes(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SelectError::Unhandled, &res),
                 }
             }
         }
@@ -1575,6 +1654,7 @@ impl fmt::Display for SelectError {
             SelectError::NoSuchDomain(ref cause) => write!(f, "{}", cause),
             SelectError::RequestTimeout(ref cause) => write!(f, "{}", cause),
             SelectError::TooManyRequestedAttributes(ref cause) => write!(f, "{}", cause),
+            SelectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1650,6 +1730,7 @@ impl SimpleDbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SimpleDbClient {
         SimpleDbClient {
             client: Client::shared(),
@@ -1675,16 +1756,65 @@ impl SimpleDbClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SimpleDbClient {
         SimpleDbClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SimpleDbClient> {
+        rusoto_core::ClientBuilder::new(SimpleDbClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SimpleDbClient {
+        SimpleDbClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SimpleDb for SimpleDbClient {
-    /// <p> Performs multiple DeleteAttributes operations in a single call, which reduces round trips and latencies. This enables Amazon SimpleDB to optimize requests, which generally yields better throughput. </p> <p> The following limitations are enforced for this operation: <ul> <li>1 MB request size</li> <li>25 item limit per BatchDeleteAttributes operation</li> </ul> </p>
-    async fn batch_delete_attributes(
+impl SimpleDbClient {
+    /// Calls `batch_delete_attributes`, also returning the metadata of the response.
+    pub async fn batch_delete_attributes_with_metadata(
         &self,
         input: BatchDeleteAttributesRequest,
-    ) -> Result<(), RusotoError<BatchDeleteAttributesError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<BatchDeleteAttributesError>> {
+        let request = self.build_batch_delete_attributes_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if !response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(BatchDeleteAttributesError::from_response(response));
+        }
+
+        std::mem::drop(response);
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `batch_delete_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_attributes(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchDeleteAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_attributes_request(
+        &self,
+        input: BatchDeleteAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("BatchDeleteAttributes");
         let mut params = Params::new();
 
         params.put("Action", "BatchDeleteAttributes");
@@ -1693,26 +1823,50 @@ impl SimpleDb for SimpleDbClient {
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `batch_put_attributes`, also returning the metadata of the response.
+    pub async fn batch_put_attributes_with_metadata(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<BatchPutAttributesError>> {
+        let request = self.build_batch_put_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(BatchDeleteAttributesError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(BatchPutAttributesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p> The <code>BatchPutAttributes</code> operation creates or replaces attributes within one or more items. By using this operation, the client can perform multiple <a>PutAttribute</a> operation with a single call. This helps yield savings in round trips and latencies, enabling Amazon SimpleDB to optimize requests and generally produce better throughput. </p> <p> The client may specify the item name with the <code>Item.X.ItemName</code> parameter. The client may specify new attributes using a combination of the <code>Item.X.Attribute.Y.Name</code> and <code>Item.X.Attribute.Y.Value</code> parameters. The client may specify the first attribute for the first item using the parameters <code>Item.0.Attribute.0.Name</code> and <code>Item.0.Attribute.0.Value</code>, and for the second attribute for the first item by the parameters <code>Item.0.Attribute.1.Name</code> and <code>Item.0.Attribute.1.Value</code>, and so on. </p> <p> Attributes are uniquely identified within an item by their name/value combination. For example, a single item can have the attributes <code>{ "first_name", "first_value" }</code> and <code>{ "first_name", "second_value" }</code>. However, it cannot have two attribute instances where both the <code>Item.X.Attribute.Y.Name</code> and <code>Item.X.Attribute.Y.Value</code> are the same. </p> <p> Optionally, the requester can supply the <code>Replace</code> parameter for each individual value. Setting this value to <code>true</code> will cause the new attribute values to replace the existing attribute values. For example, if an item <code>I</code> has the attributes <code>{ 'a', '1' }, { 'b', '2'}</code> and <code>{ 'b', '3' }</code> and the requester does a BatchPutAttributes of <code>{'I', 'b', '4' }</code> with the Replace parameter set to true, the final attributes of the item will be <code>{ 'a', '1' }</code> and <code>{ 'b', '4' }</code>, replacing the previous values of the 'b' attribute with the new value. </p> <important> This operation is vulnerable to exceeding the maximum URL size when making a REST request using the HTTP GET method. This operation does not support conditions using <code>Expected.X.Name</code>, <code>Expected.X.Value</code>, or <code>Expected.X.Exists</code>. </important> <p> You can execute multiple <code>BatchPutAttributes</code> operations and other operations in parallel. However, large numbers of concurrent <code>BatchPutAttributes</code> calls can result in Service Unavailable (503) responses. </p> <p> The following limitations are enforced for this operation: <ul> <li>256 attribute name-value pairs per item</li> <li>1 MB request size</li> <li>1 billion attributes per domain</li> <li>10 GB of total user data storage per domain</li> <li>25 item limit per <code>BatchPutAttributes</code> operation</li> </ul> </p>
-    async fn batch_put_attributes(
+    /// Presigns the request `batch_put_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_put_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchPutAttributesRequest,
-    ) -> Result<(), RusotoError<BatchPutAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_put_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_put_attributes_request(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("BatchPutAttributes");
         let mut params = Params::new();
 
         params.put("Action", "BatchPutAttributes");
@@ -1721,26 +1875,47 @@ impl SimpleDb for SimpleDbClient {
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_domain`, also returning the metadata of the response.
+    pub async fn create_domain_with_metadata(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateDomainError>> {
+        let request = self.build_create_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(BatchPutAttributesError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(CreateDomainError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p> The <code>CreateDomain</code> operation creates a new domain. The domain name should be unique among the domains associated with the Access Key ID provided in the request. The <code>CreateDomain</code> operation may take 10 or more seconds to complete. </p> <p> The client can create up to 100 domains per account. </p> <p> If the client requires additional domains, go to <a href="http://aws.amazon.com/contact-us/simpledb-limit-request/"> http://aws.amazon.com/contact-us/simpledb-limit-request/</a>. </p>
-    async fn create_domain(
+    /// Presigns the request `create_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDomainRequest,
-    ) -> Result<(), RusotoError<CreateDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_domain_request(&self, input: CreateDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("CreateDomain");
         let mut params = Params::new();
 
         params.put("Action", "CreateDomain");
@@ -1749,26 +1924,47 @@ impl SimpleDb for SimpleDbClient {
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_attributes`, also returning the metadata of the response.
+    pub async fn delete_attributes_with_metadata(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAttributesError>> {
+        let request = self.build_delete_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(CreateDomainError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteAttributesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p> Deletes one or more attributes associated with an item. If all attributes of the item are deleted, the item is deleted. </p> <p> <code>DeleteAttributes</code> is an idempotent operation; running it multiple times on the same item or attribute does not result in an error response. </p> <p> Because Amazon SimpleDB makes multiple copies of item data and uses an eventual consistency update model, performing a <a>GetAttributes</a> or <a>Select</a> operation (read) immediately after a <code>DeleteAttributes</code> or <a>PutAttributes</a> operation (write) might not return updated item data. </p>
-    async fn delete_attributes(
+    /// Presigns the request `delete_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAttributesRequest,
-    ) -> Result<(), RusotoError<DeleteAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_attributes_request(&self, input: DeleteAttributesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DeleteAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAttributes");
@@ -1777,26 +1973,47 @@ impl SimpleDb for SimpleDbClient {
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_domain`, also returning the metadata of the response.
+    pub async fn delete_domain_with_metadata(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDomainError>> {
+        let request = self.build_delete_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteAttributesError::from_response(response));
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            return Err(DeleteDomainError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p> The <code>DeleteDomain</code> operation deletes a domain. Any items (and their attributes) in the domain are deleted as well. The <code>DeleteDomain</code> operation might take 10 or more seconds to complete. </p>
-    async fn delete_domain(
+    /// Presigns the request `delete_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDomainRequest,
-    ) -> Result<(), RusotoError<DeleteDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_domain_request(&self, input: DeleteDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DeleteDomain");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDomain");
@@ -1805,45 +2022,34 @@ impl SimpleDb for SimpleDbClient {
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            return Err(DeleteDomainError::from_response(response));
-        }
-
-        std::mem::drop(response);
-        Ok(())
+        request
     }
 
-    /// <p> Returns information about the domain, including when the domain was created, the number of items and attributes in the domain, and the size of the attribute names and values. </p>
-    async fn domain_metadata(
+    /// Calls `domain_metadata`, also returning the metadata of the response.
+    pub async fn domain_metadata_with_metadata(
         &self,
         input: DomainMetadataRequest,
-    ) -> Result<DomainMetadataResult, RusotoError<DomainMetadataError>> {
-        let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DomainMetadata");
-        params.put("Version", "2009-04-15");
-        DomainMetadataRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<(DomainMetadataResult, ResponseMetadata), RusotoError<DomainMetadataError>> {
+        let request = self.build_domain_metadata_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DomainMetadataError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -1863,34 +2069,58 @@ impl SimpleDb for SimpleDbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p> Returns all of the attributes associated with the specified item. Optionally, the attributes returned can be limited to one or more attributes by specifying an attribute name parameter. </p> <p> If the item does not exist on the replica that was accessed for this operation, an empty set is returned. The system does not return an error as it cannot guarantee the item does not exist on other replicas. </p>
-    async fn get_attributes(
+    /// Presigns the request `domain_metadata` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_domain_metadata(
         &self,
-        input: GetAttributesRequest,
-    ) -> Result<GetAttributesResult, RusotoError<GetAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DomainMetadataRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_domain_metadata_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_domain_metadata_request(&self, input: DomainMetadataRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DomainMetadata");
         let mut params = Params::new();
 
-        params.put("Action", "GetAttributes");
+        params.put("Action", "DomainMetadata");
         params.put("Version", "2009-04-15");
-        GetAttributesRequestSerializer::serialize(&mut params, "", &input);
+        DomainMetadataRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_attributes`, also returning the metadata of the response.
+    pub async fn get_attributes_with_metadata(
+        &self,
+        input: GetAttributesRequest,
+    ) -> Result<(GetAttributesResult, ResponseMetadata), RusotoError<GetAttributesError>> {
+        let request = self.build_get_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -1910,34 +2140,58 @@ impl SimpleDb for SimpleDbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p> The <code>ListDomains</code> operation lists all domains associated with the Access Key ID. It returns domain names up to the limit set by <a href="#MaxNumberOfDomains">MaxNumberOfDomains</a>. A <a href="#NextToken">NextToken</a> is returned if there are more than <code>MaxNumberOfDomains</code> domains. Calling <code>ListDomains</code> successive times with the <code>NextToken</code> provided by the operation returns up to <code>MaxNumberOfDomains</code> more domain names with each successive operation call. </p>
-    async fn list_domains(
+    /// Presigns the request `get_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_attributes(
         &self,
-        input: ListDomainsRequest,
-    ) -> Result<ListDomainsResult, RusotoError<ListDomainsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_attributes_request(&self, input: GetAttributesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("GetAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "ListDomains");
+        params.put("Action", "GetAttributes");
         params.put("Version", "2009-04-15");
-        ListDomainsRequestSerializer::serialize(&mut params, "", &input);
+        GetAttributesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_domains`, also returning the metadata of the response.
+    pub async fn list_domains_with_metadata(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<(ListDomainsResult, ResponseMetadata), RusotoError<ListDomainsError>> {
+        let request = self.build_list_domains_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListDomainsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -1956,59 +2210,107 @@ impl SimpleDb for SimpleDbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p> The PutAttributes operation creates or replaces attributes in an item. The client may specify new attributes using a combination of the <code>Attribute.X.Name</code> and <code>Attribute.X.Value</code> parameters. The client specifies the first attribute by the parameters <code>Attribute.0.Name</code> and <code>Attribute.0.Value</code>, the second attribute by the parameters <code>Attribute.1.Name</code> and <code>Attribute.1.Value</code>, and so on. </p> <p> Attributes are uniquely identified in an item by their name/value combination. For example, a single item can have the attributes <code>{ "first_name", "first_value" }</code> and <code>{ "first_name", second_value" }</code>. However, it cannot have two attribute instances where both the <code>Attribute.X.Name</code> and <code>Attribute.X.Value</code> are the same. </p> <p> Optionally, the requestor can supply the <code>Replace</code> parameter for each individual attribute. Setting this value to <code>true</code> causes the new attribute value to replace the existing attribute value(s). For example, if an item has the attributes <code>{ 'a', '1' }</code>, <code>{ 'b', '2'}</code> and <code>{ 'b', '3' }</code> and the requestor calls <code>PutAttributes</code> using the attributes <code>{ 'b', '4' }</code> with the <code>Replace</code> parameter set to true, the final attributes of the item are changed to <code>{ 'a', '1' }</code> and <code>{ 'b', '4' }</code>, which replaces the previous values of the 'b' attribute with the new value. </p> <p> You cannot specify an empty string as an attribute name. </p> <p> Because Amazon SimpleDB makes multiple copies of client data and uses an eventual consistency update model, an immediate <a>GetAttributes</a> or <a>Select</a> operation (read) immediately after a <a>PutAttributes</a> or <a>DeleteAttributes</a> operation (write) might not return the updated data. </p> <p> The following limitations are enforced for this operation: <ul> <li>256 total attribute name-value pairs per item</li> <li>One billion attributes per domain</li> <li>10 GB of total user data storage per domain</li> </ul> </p>
-    async fn put_attributes(
+    /// Presigns the request `list_domains` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_domains(
         &self,
-        input: PutAttributesRequest,
-    ) -> Result<(), RusotoError<PutAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListDomainsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_domains_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_domains_request(&self, input: ListDomainsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("ListDomains");
         let mut params = Params::new();
 
-        params.put("Action", "PutAttributes");
+        params.put("Action", "ListDomains");
         params.put("Version", "2009-04-15");
-        PutAttributesRequestSerializer::serialize(&mut params, "", &input);
+        ListDomainsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_attributes`, also returning the metadata of the response.
+    pub async fn put_attributes_with_metadata(
+        &self,
+        input: PutAttributesRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutAttributesError>> {
+        let request = self.build_put_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutAttributesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p> The <code>Select</code> operation returns a set of attributes for <code>ItemNames</code> that match the select expression. <code>Select</code> is similar to the standard SQL SELECT statement. </p> <p> The total size of the response cannot exceed 1 MB in total size. Amazon SimpleDB automatically adjusts the number of items returned per page to enforce this limit. For example, if the client asks to retrieve 2500 items, but each individual item is 10 kB in size, the system returns 100 items and an appropriate <code>NextToken</code> so the client can access the next page of results. </p> <p> For information on how to construct select expressions, see Using Select to Create Amazon SimpleDB Queries in the Developer Guide. </p>
-    async fn select(&self, input: SelectRequest) -> Result<SelectResult, RusotoError<SelectError>> {
+    /// Presigns the request `put_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_attributes(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PutAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_attributes_request(&self, input: PutAttributesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("PutAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "Select");
+        params.put("Action", "PutAttributes");
         params.put("Version", "2009-04-15");
-        SelectRequestSerializer::serialize(&mut params, "", &input);
+        PutAttributesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `select`, also returning the metadata of the response.
+    pub async fn select_with_metadata(
+        &self,
+        input: SelectRequest,
+    ) -> Result<(SelectResult, ResponseMetadata), RusotoError<SelectError>> {
+        let request = self.build_select_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SelectError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -2027,6 +2329,198 @@ impl SimpleDb for SimpleDbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `select` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_select(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SelectRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_select_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_select_request(&self, input: SelectRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("Select");
+        let mut params = Params::new();
+
+        params.put("Action", "Select");
+        params.put("Version", "2009-04-15");
+        SelectRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `ListDomains` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxNumberOfDomains` of `input`.
+    pub fn list_domains_pages(
+        &self,
+        input: ListDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDomainsResult, RusotoError<ListDomainsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SimpleDbClient, input| async move { client.list_domains(input).await },
+            |input: &ListDomainsRequest, output: &ListDomainsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDomainsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DomainNames` of every page of `ListDomains` results for `input`, as `list_domains_pages` requests them.
+    pub fn list_domains_stream(
+        &self,
+        input: ListDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListDomainsError>>> + Send {
+        rusoto_core::pagination::items(self.list_domains_pages(input), |page| {
+            page.domain_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `Select` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn select_pages(
+        &self,
+        input: SelectRequest,
+    ) -> impl futures::Stream<Item = Result<SelectResult, RusotoError<SelectError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SimpleDbClient, input| async move { client.select(input).await },
+            |input: &SelectRequest, output: &SelectResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SelectRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `Select` results for `input`, as `select_pages` requests them.
+    pub fn select_stream(
+        &self,
+        input: SelectRequest,
+    ) -> impl futures::Stream<Item = Result<Item, RusotoError<SelectError>>> + Send {
+        rusoto_core::pagination::items(self.select_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl SimpleDb for SimpleDbClient {
+    /// <p> Performs multiple DeleteAttributes operations in a single call, which reduces round trips and latencies. This enables Amazon SimpleDB to optimize requests, which generally yields better throughput. </p> <p> The following limitations are enforced for this operation: <ul> <li>1 MB request size</li> <li>25 item limit per BatchDeleteAttributes operation</li> </ul> </p>
+    async fn batch_delete_attributes(
+        &self,
+        input: BatchDeleteAttributesRequest,
+    ) -> Result<(), RusotoError<BatchDeleteAttributesError>> {
+        self.batch_delete_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>BatchPutAttributes</code> operation creates or replaces attributes within one or more items. By using this operation, the client can perform multiple <a>PutAttribute</a> operation with a single call. This helps yield savings in round trips and latencies, enabling Amazon SimpleDB to optimize requests and generally produce better throughput. </p> <p> The client may specify the item name with the <code>Item.X.ItemName</code> parameter. The client may specify new attributes using a combination of the <code>Item.X.Attribute.Y.Name</code> and <code>Item.X.Attribute.Y.Value</code> parameters. The client may specify the first attribute for the first item using the parameters <code>Item.0.Attribute.0.Name</code> and <code>Item.0.Attribute.0.Value</code>, and for the second attribute for the first item by the parameters <code>Item.0.Attribute.1.Name</code> and <code>Item.0.Attribute.1.Value</code>, and so on. </p> <p> Attributes are uniquely identified within an item by their name/value combination. For example, a single item can have the attributes <code>{ "first_name", "first_value" }</code> and <code>{ "first_name", "second_value" }</code>. However, it cannot have two attribute instances where both the <code>Item.X.Attribute.Y.Name</code> and <code>Item.X.Attribute.Y.Value</code> are the same. </p> <p> Optionally, the requester can supply the <code>Replace</code> parameter for each individual value. Setting this value to <code>true</code> will cause the new attribute values to replace the existing attribute values. For example, if an item <code>I</code> has the attributes <code>{ 'a', '1' }, { 'b', '2'}</code> and <code>{ 'b', '3' }</code> and the requester does a BatchPutAttributes of <code>{'I', 'b', '4' }</code> with the Replace parameter set to true, the final attributes of the item will be <code>{ 'a', '1' }</code> and <code>{ 'b', '4' }</code>, replacing the previous values of the 'b' attribute with the new value. </p> <important> This operation is vulnerable to exceeding the maximum URL size when making a REST request using the HTTP GET method. This operation does not support conditions using <code>Expected.X.Name</code>, <code>Expected.X.Value</code>, or <code>Expected.X.Exists</code>. </important> <p> You can execute multiple <code>BatchPutAttributes</code> operations and other operations in parallel. However, large numbers of concurrent <code>BatchPutAttributes</code> calls can result in Service Unavailable (503) responses. </p> <p> The following limitations are enforced for this operation: <ul> <li>256 attribute name-value pairs per item</li> <li>1 MB request size</li> <li>1 billion attributes per domain</li> <li>10 GB of total user data storage per domain</li> <li>25 item limit per <code>BatchPutAttributes</code> operation</li> </ul> </p>
+    async fn batch_put_attributes(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> Result<(), RusotoError<BatchPutAttributesError>> {
+        self.batch_put_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>CreateDomain</code> operation creates a new domain. The domain name should be unique among the domains associated with the Access Key ID provided in the request. The <code>CreateDomain</code> operation may take 10 or more seconds to complete. </p> <p> The client can create up to 100 domains per account. </p> <p> If the client requires additional domains, go to <a href="http://aws.amazon.com/contact-us/simpledb-limit-request/"> http://aws.amazon.com/contact-us/simpledb-limit-request/</a>. </p>
+    async fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<(), RusotoError<CreateDomainError>> {
+        self.create_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes one or more attributes associated with an item. If all attributes of the item are deleted, the item is deleted. </p> <p> <code>DeleteAttributes</code> is an idempotent operation; running it multiple times on the same item or attribute does not result in an error response. </p> <p> Because Amazon SimpleDB makes multiple copies of item data and uses an eventual consistency update model, performing a <a>GetAttributes</a> or <a>Select</a> operation (read) immediately after a <code>DeleteAttributes</code> or <a>PutAttributes</a> operation (write) might not return updated item data. </p>
+    async fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> Result<(), RusotoError<DeleteAttributesError>> {
+        self.delete_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>DeleteDomain</code> operation deletes a domain. Any items (and their attributes) in the domain are deleted as well. The <code>DeleteDomain</code> operation might take 10 or more seconds to complete. </p>
+    async fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<(), RusotoError<DeleteDomainError>> {
+        self.delete_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns information about the domain, including when the domain was created, the number of items and attributes in the domain, and the size of the attribute names and values. </p>
+    async fn domain_metadata(
+        &self,
+        input: DomainMetadataRequest,
+    ) -> Result<DomainMetadataResult, RusotoError<DomainMetadataError>> {
+        self.domain_metadata_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns all of the attributes associated with the specified item. Optionally, the attributes returned can be limited to one or more attributes by specifying an attribute name parameter. </p> <p> If the item does not exist on the replica that was accessed for this operation, an empty set is returned. The system does not return an error as it cannot guarantee the item does not exist on other replicas. </p>
+    async fn get_attributes(
+        &self,
+        input: GetAttributesRequest,
+    ) -> Result<GetAttributesResult, RusotoError<GetAttributesError>> {
+        self.get_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>ListDomains</code> operation lists all domains associated with the Access Key ID. It returns domain names up to the limit set by <a href="#MaxNumberOfDomains">MaxNumberOfDomains</a>. A <a href="#NextToken">NextToken</a> is returned if there are more than <code>MaxNumberOfDomains</code> domains. Calling <code>ListDomains</code> successive times with the <code>NextToken</code> provided by the operation returns up to <code>MaxNumberOfDomains</code> more domain names with each successive operation call. </p>
+    async fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResult, RusotoError<ListDomainsError>> {
+        self.list_domains_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The PutAttributes operation creates or replaces attributes in an item. The client may specify new attributes using a combination of the <code>Attribute.X.Name</code> and <code>Attribute.X.Value</code> parameters. The client specifies the first attribute by the parameters <code>Attribute.0.Name</code> and <code>Attribute.0.Value</code>, the second attribute by the parameters <code>Attribute.1.Name</code> and <code>Attribute.1.Value</code>, and so on. </p> <p> Attributes are uniquely identified in an item by their name/value combination. For example, a single item can have the attributes <code>{ "first_name", "first_value" }</code> and <code>{ "first_name", second_value" }</code>. However, it cannot have two attribute instances where both the <code>Attribute.X.Name</code> and <code>Attribute.X.Value</code> are the same. </p> <p> Optionally, the requestor can supply the <code>Replace</code> parameter for each individual attribute. Setting this value to <code>true</code> causes the new attribute value to replace the existing attribute value(s). For example, if an item has the attributes <code>{ 'a', '1' }</code>, <code>{ 'b', '2'}</code> and <code>{ 'b', '3' }</code> and the requestor calls <code>PutAttributes</code> using the attributes <code>{ 'b', '4' }</code> with the <code>Replace</code> parameter set to true, the final attributes of the item are changed to <code>{ 'a', '1' }</code> and <code>{ 'b', '4' }</code>, which replaces the previous values of the 'b' attribute with the new value. </p> <p> You cannot specify an empty string as an attribute name. </p> <p> Because Amazon SimpleDB makes multiple copies of client data and uses an eventual consistency update model, an immediate <a>GetAttributes</a> or <a>Select</a> operation (read) immediately after a <a>PutAttributes</a> or <a>DeleteAttributes</a> operation (write) might not return the updated data. </p> <p> The following limitations are enforced for this operation: <ul> <li>256 total attribute name-value pairs per item</li> <li>One billion attributes per domain</li> <li>10 GB of total user data storage per domain</li> </ul> </p>
+    async fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> Result<(), RusotoError<PutAttributesError>> {
+        self.put_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> The <code>Select</code> operation returns a set of attributes for <code>ItemNames</code> that match the select expression. <code>Select</code> is similar to the standard SQL SELECT statement. </p> <p> The total size of the response cannot exceed 1 MB in total size. Amazon SimpleDB automatically adjusts the number of items returned per page to enforce this limit. For example, if the client asks to retrieve 2500 items, but each individual item is 10 kB in size, the system returns 100 items and an appropriate <code>NextToken</code> so the client can access the next page of results. </p> <p> For information on how to construct select expressions, see Using Select to Create Amazon SimpleDB Queries in the Developer Guide. </p>
+    async fn select(&self, input: SelectRequest) -> Result<SelectResult, RusotoError<SelectError>> {
+        self.select_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -760,6 +760,7 @@ pub struct UpdateSecretVersionStageResponse {
 
 /// Errors returned by CancelRotateSecret
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelRotateSecretError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -769,6 +770,8 @@ pub enum CancelRotateSecretError {
     InvalidRequest(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelRotateSecretError {
@@ -776,21 +779,31 @@ impl CancelRotateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(CancelRotateSecretError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelRotateSecretError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelRotateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CancelRotateSecretError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelRotateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CancelRotateSecretError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelRotateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CancelRotateSecretError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelRotateSecretError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -804,12 +817,14 @@ impl fmt::Display for CancelRotateSecretError {
             CancelRotateSecretError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CancelRotateSecretError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CancelRotateSecretError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CancelRotateSecretError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelRotateSecretError {}
 /// Errors returned by CreateSecret
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSecretError {
     /// <p>Secrets Manager can't encrypt the protected secret text using the provided KMS key. Check that the customer master key (CMK) is available, enabled, and not in an invalid state. For more information, see <a href="http://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a>.</p>
     EncryptionFailure(String),
@@ -829,6 +844,8 @@ pub enum CreateSecretError {
     ResourceExists(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSecretError {
@@ -836,36 +853,46 @@ impl CreateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionFailure" => {
-                    return RusotoError::Service(CreateSecretError::EncryptionFailure(err.msg))
+                    return RusotoError::service(
+                        CreateSecretError::EncryptionFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreateSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        CreateSecretError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateSecretError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateSecretError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSecretError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateSecretError::LimitExceeded(err.msg), &res)
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(CreateSecretError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSecretError::MalformedPolicyDocument(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateSecretError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        CreateSecretError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateSecretError::ResourceExists(err.msg))
+                    return RusotoError::service(CreateSecretError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateSecretError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSecretError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -884,12 +911,14 @@ impl fmt::Display for CreateSecretError {
             CreateSecretError::PreconditionNotMet(ref cause) => write!(f, "{}", cause),
             CreateSecretError::ResourceExists(ref cause) => write!(f, "{}", cause),
             CreateSecretError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateSecretError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSecretError {}
 /// Errors returned by DeleteResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourcePolicyError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -897,6 +926,8 @@ pub enum DeleteResourcePolicyError {
     InvalidRequest(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourcePolicyError {
@@ -904,20 +935,25 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -930,12 +966,14 @@ impl fmt::Display for DeleteResourcePolicyError {
             DeleteResourcePolicyError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DeleteResourcePolicyError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteResourcePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourcePolicyError {}
 /// Errors returned by DeleteSecret
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSecretError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -945,6 +983,8 @@ pub enum DeleteSecretError {
     InvalidRequest(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSecretError {
@@ -952,19 +992,22 @@ impl DeleteSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DeleteSecretError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteSecretError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteSecretError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteSecretError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSecretError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -978,17 +1021,21 @@ impl fmt::Display for DeleteSecretError {
             DeleteSecretError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteSecretError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteSecretError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSecretError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSecretError {}
 /// Errors returned by DescribeSecret
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSecretError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSecretError {
@@ -996,13 +1043,19 @@ impl DescribeSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DescribeSecretError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeSecretError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSecretError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1014,12 +1067,14 @@ impl fmt::Display for DescribeSecretError {
         match *self {
             DescribeSecretError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DescribeSecretError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSecretError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSecretError {}
 /// Errors returned by GetRandomPassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRandomPasswordError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1027,6 +1082,8 @@ pub enum GetRandomPasswordError {
     InvalidParameter(String),
     /// <p><p>You provided a parameter value that is not valid for the current state of the resource.</p> <p>Possible causes:</p> <ul> <li> <p>You tried to perform the operation on a secret that&#39;s currently marked deleted.</p> </li> <li> <p>You tried to enable rotation on a secret that doesn&#39;t already have a Lambda function ARN configured and you didn&#39;t include such an ARN as a parameter in this call. </p> </li> </ul></p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRandomPasswordError {
@@ -1034,18 +1091,25 @@ impl GetRandomPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetRandomPasswordError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRandomPasswordError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRandomPasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetRandomPasswordError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetRandomPasswordError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetRandomPasswordError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRandomPasswordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1058,12 +1122,14 @@ impl fmt::Display for GetRandomPasswordError {
             GetRandomPasswordError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             GetRandomPasswordError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetRandomPasswordError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            GetRandomPasswordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRandomPasswordError {}
 /// Errors returned by GetResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourcePolicyError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1071,6 +1137,8 @@ pub enum GetResourcePolicyError {
     InvalidRequest(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourcePolicyError {
@@ -1078,18 +1146,25 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetResourcePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourcePolicyError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetResourcePolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetResourcePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1102,12 +1177,14 @@ impl fmt::Display for GetResourcePolicyError {
             GetResourcePolicyError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             GetResourcePolicyError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetResourcePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourcePolicyError {}
 /// Errors returned by GetSecretValue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSecretValueError {
     /// <p>Secrets Manager can't decrypt the protected secret text using the provided KMS key. </p>
     DecryptionFailure(String),
@@ -1119,6 +1196,8 @@ pub enum GetSecretValueError {
     InvalidRequest(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSecretValueError {
@@ -1126,22 +1205,34 @@ impl GetSecretValueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DecryptionFailure" => {
-                    return RusotoError::Service(GetSecretValueError::DecryptionFailure(err.msg))
+                    return RusotoError::service(
+                        GetSecretValueError::DecryptionFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetSecretValueError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        GetSecretValueError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSecretValueError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetSecretValueError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSecretValueError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetSecretValueError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSecretValueError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetSecretValueError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSecretValueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1156,12 +1247,14 @@ impl fmt::Display for GetSecretValueError {
             GetSecretValueError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetSecretValueError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetSecretValueError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetSecretValueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSecretValueError {}
 /// Errors returned by ListSecretVersionIds
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSecretVersionIdsError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1169,6 +1262,8 @@ pub enum ListSecretVersionIdsError {
     InvalidNextToken(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSecretVersionIdsError {
@@ -1176,22 +1271,25 @@ impl ListSecretVersionIdsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListSecretVersionIdsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSecretVersionIdsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSecretVersionIdsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSecretVersionIdsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSecretVersionIdsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSecretVersionIdsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSecretVersionIdsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1204,12 +1302,14 @@ impl fmt::Display for ListSecretVersionIdsError {
             ListSecretVersionIdsError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListSecretVersionIdsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListSecretVersionIdsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListSecretVersionIdsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSecretVersionIdsError {}
 /// Errors returned by ListSecrets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSecretsError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1217,6 +1317,8 @@ pub enum ListSecretsError {
     InvalidNextToken(String),
     /// <p>You provided an invalid value for a parameter.</p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSecretsError {
@@ -1224,16 +1326,19 @@ impl ListSecretsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListSecretsError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        ListSecretsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSecretsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListSecretsError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSecretsError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListSecretsError::InvalidParameter(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSecretsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1246,12 +1351,14 @@ impl fmt::Display for ListSecretsError {
             ListSecretsError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListSecretsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListSecretsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            ListSecretsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSecretsError {}
 /// Errors returned by PutResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutResourcePolicyError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1263,6 +1370,8 @@ pub enum PutResourcePolicyError {
     MalformedPolicyDocument(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutResourcePolicyError {
@@ -1270,26 +1379,37 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(PutResourcePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourcePolicyError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(PutResourcePolicyError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourcePolicyError::MalformedPolicyDocument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1304,12 +1424,14 @@ impl fmt::Display for PutResourcePolicyError {
             PutResourcePolicyError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             PutResourcePolicyError::MalformedPolicyDocument(ref cause) => write!(f, "{}", cause),
             PutResourcePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutResourcePolicyError {}
 /// Errors returned by PutSecretValue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutSecretValueError {
     /// <p>Secrets Manager can't encrypt the protected secret text using the provided KMS key. Check that the customer master key (CMK) is available, enabled, and not in an invalid state. For more information, see <a href="http://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a>.</p>
     EncryptionFailure(String),
@@ -1325,6 +1447,8 @@ pub enum PutSecretValueError {
     ResourceExists(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutSecretValueError {
@@ -1332,28 +1456,40 @@ impl PutSecretValueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionFailure" => {
-                    return RusotoError::Service(PutSecretValueError::EncryptionFailure(err.msg))
+                    return RusotoError::service(
+                        PutSecretValueError::EncryptionFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(PutSecretValueError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        PutSecretValueError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutSecretValueError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        PutSecretValueError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutSecretValueError::InvalidRequest(err.msg))
+                    return RusotoError::service(PutSecretValueError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSecretValueError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutSecretValueError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(PutSecretValueError::ResourceExists(err.msg))
+                    return RusotoError::service(PutSecretValueError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSecretValueError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutSecretValueError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutSecretValueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1370,12 +1506,14 @@ impl fmt::Display for PutSecretValueError {
             PutSecretValueError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutSecretValueError::ResourceExists(ref cause) => write!(f, "{}", cause),
             PutSecretValueError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutSecretValueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutSecretValueError {}
 /// Errors returned by RestoreSecret
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreSecretError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1385,6 +1523,8 @@ pub enum RestoreSecretError {
     InvalidRequest(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreSecretError {
@@ -1392,19 +1532,28 @@ impl RestoreSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(RestoreSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        RestoreSecretError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RestoreSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        RestoreSecretError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RestoreSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(RestoreSecretError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RestoreSecretError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RestoreSecretError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1418,12 +1567,14 @@ impl fmt::Display for RestoreSecretError {
             RestoreSecretError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RestoreSecretError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             RestoreSecretError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RestoreSecretError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreSecretError {}
 /// Errors returned by RotateSecret
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RotateSecretError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1433,6 +1584,8 @@ pub enum RotateSecretError {
     InvalidRequest(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RotateSecretError {
@@ -1440,19 +1593,22 @@ impl RotateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(RotateSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        RotateSecretError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RotateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(RotateSecretError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RotateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(RotateSecretError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RotateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(RotateSecretError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RotateSecretError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1466,12 +1622,14 @@ impl fmt::Display for RotateSecretError {
             RotateSecretError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RotateSecretError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             RotateSecretError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RotateSecretError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RotateSecretError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1481,6 +1639,8 @@ pub enum TagResourceError {
     InvalidRequest(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1488,19 +1648,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1514,12 +1677,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1529,6 +1694,8 @@ pub enum UntagResourceError {
     InvalidRequest(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1536,19 +1703,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1562,12 +1738,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateSecret
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSecretError {
     /// <p>Secrets Manager can't encrypt the protected secret text using the provided KMS key. Check that the customer master key (CMK) is available, enabled, and not in an invalid state. For more information, see <a href="http://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a>.</p>
     EncryptionFailure(String),
@@ -1587,6 +1765,8 @@ pub enum UpdateSecretError {
     ResourceExists(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSecretError {
@@ -1594,36 +1774,46 @@ impl UpdateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionFailure" => {
-                    return RusotoError::Service(UpdateSecretError::EncryptionFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateSecretError::EncryptionFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(UpdateSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        UpdateSecretError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(UpdateSecretError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateSecretError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSecretError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateSecretError::LimitExceeded(err.msg), &res)
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(UpdateSecretError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSecretError::MalformedPolicyDocument(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(UpdateSecretError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        UpdateSecretError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateSecretError::ResourceExists(err.msg))
+                    return RusotoError::service(UpdateSecretError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateSecretError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSecretError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1642,12 +1832,14 @@ impl fmt::Display for UpdateSecretError {
             UpdateSecretError::PreconditionNotMet(ref cause) => write!(f, "{}", cause),
             UpdateSecretError::ResourceExists(ref cause) => write!(f, "{}", cause),
             UpdateSecretError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateSecretError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateSecretError {}
 /// Errors returned by UpdateSecretVersionStage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSecretVersionStageError {
     /// <p>An error occurred on the server side.</p>
     InternalServiceError(String),
@@ -1659,6 +1851,8 @@ pub enum UpdateSecretVersionStageError {
     LimitExceeded(String),
     /// <p>We can't find the resource that you asked for.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSecretVersionStageError {
@@ -1666,32 +1860,37 @@ impl UpdateSecretVersionStageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSecretVersionStageError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSecretVersionStageError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSecretVersionStageError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSecretVersionStageError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSecretVersionStageError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSecretVersionStageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1708,6 +1907,7 @@ impl fmt::Display for UpdateSecretVersionStageError {
             UpdateSecretVersionStageError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateSecretVersionStageError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateSecretVersionStageError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateSecretVersionStageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1834,6 +2034,7 @@ impl SecretsManagerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SecretsManagerClient {
         SecretsManagerClient {
             client: Client::shared(),
@@ -1859,500 +2060,1191 @@ impl SecretsManagerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SecretsManagerClient {
         SecretsManagerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SecretsManagerClient> {
+        rusoto_core::ClientBuilder::new(SecretsManagerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SecretsManagerClient {
+        SecretsManagerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SecretsManager for SecretsManagerClient {
-    /// <p><p>Disables automatic scheduled rotation and cancels the rotation of a secret if one is currently in progress.</p> <p>To re-enable scheduled rotation, call <a>RotateSecret</a> with <code>AutomaticallyRotateAfterDays</code> set to a value greater than 0. This will immediately rotate your secret and then enable the automatic schedule.</p> <note> <p>If you cancel a rotation that is in progress, it can leave the <code>VersionStage</code> labels in an unexpected state. Depending on what step of the rotation was in progress, you might need to remove the staging label <code>AWSPENDING</code> from the partially created version, specified by the <code>VersionId</code> response value. You should also evaluate the partially rotated new version to see if it should be deleted, which you can do by removing all staging labels from the new version&#39;s <code>VersionStage</code> field.</p> </note> <p>To successfully start a rotation, the staging label <code>AWSPENDING</code> must be in one of the following states:</p> <ul> <li> <p>Not be attached to any version at all</p> </li> <li> <p>Attached to the same version as the staging label <code>AWSCURRENT</code> </p> </li> </ul> <p>If the staging label <code>AWSPENDING</code> is attached to a different version than the version with <code>AWSCURRENT</code> then the attempt to rotate fails.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CancelRotateSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To configure rotation for a secret or to manually trigger a rotation, use <a>RotateSecret</a>.</p> </li> <li> <p>To get the rotation configuration details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> <li> <p>To list all of the versions currently associated with a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
-    async fn cancel_rotate_secret(
+impl SecretsManagerClient {
+    /// Calls `cancel_rotate_secret`, also returning the metadata of the response.
+    pub async fn cancel_rotate_secret_with_metadata(
         &self,
         input: CancelRotateSecretRequest,
-    ) -> Result<CancelRotateSecretResponse, RusotoError<CancelRotateSecretError>> {
+    ) -> Result<(CancelRotateSecretResponse, ResponseMetadata), RusotoError<CancelRotateSecretError>>
+    {
+        let request = self.build_cancel_rotate_secret_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelRotateSecretResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelRotateSecretError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_rotate_secret` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_rotate_secret(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelRotateSecretRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_rotate_secret_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_rotate_secret_request(
+        &self,
+        input: CancelRotateSecretRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("CancelRotateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.CancelRotateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_secret`, also returning the metadata of the response.
+    pub async fn create_secret_with_metadata(
+        &self,
+        input: CreateSecretRequest,
+    ) -> Result<(CreateSecretResponse, ResponseMetadata), RusotoError<CreateSecretError>> {
+        let request = self.build_create_secret_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelRotateSecretResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSecretResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelRotateSecretError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSecretError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new secret. A secret in Secrets Manager consists of both the protected secret data and the important information needed to manage the secret.</p> <p>Secrets Manager stores the encrypted secret data in one of a collection of &quot;versions&quot; associated with the secret. Each version contains a copy of the encrypted secret data. Each version is associated with one or more &quot;staging labels&quot; that identify where the version is in the rotation cycle. The <code>SecretVersionsToStages</code> field of the secret contains the mapping of staging labels to the active versions of the secret. Versions without a staging label are considered deprecated and are not included in the list.</p> <p>You provide the secret data to be encrypted by putting text in either the <code>SecretString</code> parameter or binary data in the <code>SecretBinary</code> parameter, but not both. If you include <code>SecretString</code> or <code>SecretBinary</code> then Secrets Manager also creates an initial secret version and automatically attaches the staging label <code>AWSCURRENT</code> to the new version.</p> <note> <ul> <li> <p>If you call an operation that needs to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS having to create the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret is in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CreateSecret</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>kms:Decrypt - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>secretsmanager:TagResource - needed only if you include the <code>Tags</code> parameter. </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To delete a secret, use <a>DeleteSecret</a>.</p> </li> <li> <p>To modify an existing secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To create a new version of a secret, use <a>PutSecretValue</a>.</p> </li> <li> <p>To retrieve the encrypted secure string and secure binary values, use <a>GetSecretValue</a>.</p> </li> <li> <p>To retrieve all other details for a secret, use <a>DescribeSecret</a>. This does not include the encrypted secure string and secure binary values.</p> </li> <li> <p>To retrieve the list of secret versions associated with the current secret, use <a>DescribeSecret</a> and examine the <code>SecretVersionsToStages</code> response value.</p> </li> </ul></p>
-    async fn create_secret(
+    /// Presigns the request `create_secret` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_secret(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSecretRequest,
-    ) -> Result<CreateSecretResponse, RusotoError<CreateSecretError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_secret_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_secret_request(&self, input: CreateSecretRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("CreateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.CreateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_resource_policy`, also returning the metadata of the response.
+    pub async fn delete_resource_policy_with_metadata(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<
+        (DeleteResourcePolicyResponse, ResponseMetadata),
+        RusotoError<DeleteResourcePolicyError>,
+    > {
+        let request = self.build_delete_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateSecretResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteResourcePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSecretError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes the resource-based permission policy that&#39;s attached to the secret.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To attach a resource policy to a secret, use <a>PutResourcePolicy</a>.</p> </li> <li> <p>To retrieve the current resource-based policy that&#39;s attached to a secret, use <a>GetResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
-    async fn delete_resource_policy(
+    /// Presigns the request `delete_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourcePolicyRequest,
-    ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_policy_request(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_secret`, also returning the metadata of the response.
+    pub async fn delete_secret_with_metadata(
+        &self,
+        input: DeleteSecretRequest,
+    ) -> Result<(DeleteSecretResponse, ResponseMetadata), RusotoError<DeleteSecretError>> {
+        let request = self.build_delete_secret_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteResourcePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSecretResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSecretError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes an entire secret and all of its versions. You can optionally include a recovery window during which you can restore the secret. If you don&#39;t specify a recovery window value, the operation defaults to 30 days. Secrets Manager attaches a <code>DeletionDate</code> stamp to the secret that specifies the end of the recovery window. At the end of the recovery window, Secrets Manager deletes the secret permanently.</p> <p>At any time before recovery window ends, you can use <a>RestoreSecret</a> to remove the <code>DeletionDate</code> and cancel the deletion of the secret.</p> <p>You cannot access the encrypted secret information in any secret that is scheduled for deletion. If you need to access that information, you must cancel the deletion with <a>RestoreSecret</a> and then retrieve the information.</p> <note> <ul> <li> <p>There is no explicit operation to delete a version of a secret. Instead, remove all staging labels from the <code>VersionStage</code> field of a version. That marks the version as deprecated and allows Secrets Manager to delete it as needed. Versions that do not have any staging labels do not show up in <a>ListSecretVersionIds</a> unless you specify <code>IncludeDeprecated</code>.</p> </li> <li> <p>The permanent secret deletion at the end of the waiting period is performed as a background task with low priority. There is no guarantee of a specific time after the recovery window for the actual delete operation to occur.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To cancel deletion of a version of a secret before the recovery window has expired, use <a>RestoreSecret</a>.</p> </li> </ul></p>
-    async fn delete_secret(
+    /// Presigns the request `delete_secret` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_secret(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSecretRequest,
-    ) -> Result<DeleteSecretResponse, RusotoError<DeleteSecretError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_secret_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_secret_request(&self, input: DeleteSecretRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("DeleteSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.DeleteSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_secret`, also returning the metadata of the response.
+    pub async fn describe_secret_with_metadata(
+        &self,
+        input: DescribeSecretRequest,
+    ) -> Result<(DescribeSecretResponse, ResponseMetadata), RusotoError<DescribeSecretError>> {
+        let request = self.build_describe_secret_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteSecretResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSecretResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSecretError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSecretError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the details of a secret. It does not include the encrypted fields. Only those fields that are populated with a value are returned in the response. </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DescribeSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To modify a secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To retrieve the encrypted secret information in a version of the secret, use <a>GetSecretValue</a>.</p> </li> <li> <p>To list all of the secrets in the AWS account, use <a>ListSecrets</a>.</p> </li> </ul></p>
-    async fn describe_secret(
+    /// Presigns the request `describe_secret` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_secret(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSecretRequest,
-    ) -> Result<DescribeSecretResponse, RusotoError<DescribeSecretError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_secret_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_secret_request(&self, input: DescribeSecretRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("DescribeSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.DescribeSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_random_password`, also returning the metadata of the response.
+    pub async fn get_random_password_with_metadata(
+        &self,
+        input: GetRandomPasswordRequest,
+    ) -> Result<(GetRandomPasswordResponse, ResponseMetadata), RusotoError<GetRandomPasswordError>>
+    {
+        let request = self.build_get_random_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeSecretResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRandomPasswordResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSecretError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRandomPasswordError::from_response(response))
         }
     }
 
-    /// <p><p>Generates a random password of the specified complexity. This operation is intended for use in the Lambda rotation function. Per best practice, we recommend that you specify the maximum length and include every character type that the system you are generating a password for can support.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:GetRandomPassword</p> </li> </ul></p>
-    async fn get_random_password(
+    /// Presigns the request `get_random_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_random_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRandomPasswordRequest,
-    ) -> Result<GetRandomPasswordResponse, RusotoError<GetRandomPasswordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_random_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_random_password_request(&self, input: GetRandomPasswordRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("GetRandomPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.GetRandomPassword");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resource_policy`, also returning the metadata of the response.
+    pub async fn get_resource_policy_with_metadata(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> Result<(GetResourcePolicyResponse, ResponseMetadata), RusotoError<GetResourcePolicyError>>
+    {
+        let request = self.build_get_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRandomPasswordResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResourcePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRandomPasswordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the JSON text of the resource-based policy document that&#39;s attached to the specified secret. The JSON request string input and response output are shown formatted with white space and line breaks for better readability. Submit your input as a single line JSON string.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:GetResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To attach a resource policy to a secret, use <a>PutResourcePolicy</a>.</p> </li> <li> <p>To delete the resource-based policy that&#39;s attached to a secret, use <a>DeleteResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
-    async fn get_resource_policy(
+    /// Presigns the request `get_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourcePolicyRequest,
-    ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_policy_request(&self, input: GetResourcePolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("GetResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.GetResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_secret_value`, also returning the metadata of the response.
+    pub async fn get_secret_value_with_metadata(
+        &self,
+        input: GetSecretValueRequest,
+    ) -> Result<(GetSecretValueResponse, ResponseMetadata), RusotoError<GetSecretValueError>> {
+        let request = self.build_get_secret_value_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourcePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSecretValueResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSecretValueError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the contents of the encrypted fields <code>SecretString</code> or <code>SecretBinary</code> from the specified version of a secret, whichever contains content.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:GetSecretValue</p> </li> <li> <p>kms:Decrypt - required only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a new version of the secret with different encrypted information, use <a>PutSecretValue</a>.</p> </li> <li> <p>To retrieve the non-encrypted details for the secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
-    async fn get_secret_value(
+    /// Presigns the request `get_secret_value` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_secret_value(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSecretValueRequest,
-    ) -> Result<GetSecretValueResponse, RusotoError<GetSecretValueError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_secret_value_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_secret_value_request(&self, input: GetSecretValueRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("GetSecretValue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.GetSecretValue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_secret_version_ids`, also returning the metadata of the response.
+    pub async fn list_secret_version_ids_with_metadata(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> Result<
+        (ListSecretVersionIdsResponse, ResponseMetadata),
+        RusotoError<ListSecretVersionIdsError>,
+    > {
+        let request = self.build_list_secret_version_ids_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetSecretValueResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSecretVersionIdsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSecretValueError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSecretVersionIdsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists all of the versions attached to the specified secret. The output does not include the <code>SecretString</code> or <code>SecretBinary</code> fields. By default, the list includes only versions that have at least one staging label in <code>VersionStage</code> attached.</p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can occasionally return an empty or shorter than expected list of results even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass to the next call to the same API to request the next part of the list.</p> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:ListSecretVersionIds</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To list the secrets in an account, use <a>ListSecrets</a>.</p> </li> </ul></p>
-    async fn list_secret_version_ids(
+    /// Presigns the request `list_secret_version_ids` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_secret_version_ids(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSecretVersionIdsRequest,
-    ) -> Result<ListSecretVersionIdsResponse, RusotoError<ListSecretVersionIdsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_secret_version_ids_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_secret_version_ids_request(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("ListSecretVersionIds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.ListSecretVersionIds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_secrets`, also returning the metadata of the response.
+    pub async fn list_secrets_with_metadata(
+        &self,
+        input: ListSecretsRequest,
+    ) -> Result<(ListSecretsResponse, ResponseMetadata), RusotoError<ListSecretsError>> {
+        let request = self.build_list_secrets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSecretVersionIdsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSecretsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSecretVersionIdsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSecretsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists all of the secrets that are stored by Secrets Manager in the AWS account. To list the versions currently stored for a specific secret, use <a>ListSecretVersionIds</a>. The encrypted fields <code>SecretString</code> and <code>SecretBinary</code> are not included in the output. To get that information, call the <a>GetSecretValue</a> operation.</p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can occasionally return an empty or shorter than expected list of results even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass to the next call to the same API to request the next part of the list.</p> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:ListSecrets</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To list the versions attached to a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
-    async fn list_secrets(
+    /// Presigns the request `list_secrets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_secrets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSecretsRequest,
-    ) -> Result<ListSecretsResponse, RusotoError<ListSecretsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_secrets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_secrets_request(&self, input: ListSecretsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("ListSecrets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.ListSecrets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_resource_policy`, also returning the metadata of the response.
+    pub async fn put_resource_policy_with_metadata(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<(PutResourcePolicyResponse, ResponseMetadata), RusotoError<PutResourcePolicyError>>
+    {
+        let request = self.build_put_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListSecretsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutResourcePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSecretsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p><p>Attaches the contents of the specified resource-based permission policy to a secret. A resource-based policy is optional. Alternatively, you can use IAM identity-based policies that specify the secret&#39;s Amazon Resource Name (ARN) in the policy statement&#39;s <code>Resources</code> element. You can also use a combination of both identity-based and resource-based policies. The affected users and roles receive the permissions that are permitted by all of the relevant policies. For more information, see <a href="http://docs.aws.amazon.com/secretsmanager/latest/userguide/auth-and-access_resource-based-policies.html">Using Resource-Based Policies for AWS Secrets Manager</a>. For the complete description of the AWS policy syntax and grammar, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies.html">IAM JSON Policy Reference</a> in the <i>IAM User Guide</i>.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:PutResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To retrieve the resource policy that&#39;s attached to a secret, use <a>GetResourcePolicy</a>.</p> </li> <li> <p>To delete the resource-based policy that&#39;s attached to a secret, use <a>DeleteResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
-    async fn put_resource_policy(
+    /// Presigns the request `put_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutResourcePolicyRequest,
-    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_resource_policy_request(&self, input: PutResourcePolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_secret_value`, also returning the metadata of the response.
+    pub async fn put_secret_value_with_metadata(
+        &self,
+        input: PutSecretValueRequest,
+    ) -> Result<(PutSecretValueResponse, ResponseMetadata), RusotoError<PutSecretValueError>> {
+        let request = self.build_put_secret_value_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutResourcePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutSecretValueResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutSecretValueError::from_response(response))
         }
     }
 
-    /// <p><p>Stores a new encrypted secret value in the specified secret. To do this, the operation creates a new version and attaches it to the secret. The version can contain a new <code>SecretString</code> value or a new <code>SecretBinary</code> value. You can also specify the staging labels that are initially attached to the new version.</p> <note> <p>The Secrets Manager console uses only the <code>SecretString</code> field. To add binary data to a secret with the <code>SecretBinary</code> field you must use the AWS CLI or one of the AWS SDKs.</p> </note> <ul> <li> <p>If this operation creates the first version for the secret then Secrets Manager automatically attaches the staging label <code>AWSCURRENT</code> to the new version.</p> </li> <li> <p>If another version of this secret already exists, then this operation does not automatically move any staging labels other than those that you explicitly specify in the <code>VersionStages</code> parameter.</p> </li> <li> <p>If this operation moves the staging label <code>AWSCURRENT</code> from another version to this version (because you included it in the <code>StagingLabels</code> parameter) then Secrets Manager also automatically moves the staging label <code>AWSPREVIOUS</code> to the version that <code>AWSCURRENT</code> was removed from.</p> </li> <li> <p>This operation is idempotent. If a version with a <code>VersionId</code> with the same value as the <code>ClientRequestToken</code> parameter already exists and you specify the same secret data, the operation succeeds but does nothing. However, if the secret data is different, then the operation fails because you cannot modify an existing version; you can only create new ones.</p> </li> </ul> <note> <ul> <li> <p>If you call an operation that needs to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS having to create the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret is in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:PutSecretValue</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To retrieve the encrypted value you store in the version of a secret, use <a>GetSecretValue</a>.</p> </li> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To get the details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list the versions attached to a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
-    async fn put_secret_value(
+    /// Presigns the request `put_secret_value` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_secret_value(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutSecretValueRequest,
-    ) -> Result<PutSecretValueResponse, RusotoError<PutSecretValueError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_secret_value_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_secret_value_request(&self, input: PutSecretValueRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("PutSecretValue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.PutSecretValue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `restore_secret`, also returning the metadata of the response.
+    pub async fn restore_secret_with_metadata(
+        &self,
+        input: RestoreSecretRequest,
+    ) -> Result<(RestoreSecretResponse, ResponseMetadata), RusotoError<RestoreSecretError>> {
+        let request = self.build_restore_secret_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutSecretValueResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RestoreSecretResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutSecretValueError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RestoreSecretError::from_response(response))
         }
     }
 
-    /// <p><p>Cancels the scheduled deletion of a secret by removing the <code>DeletedDate</code> time stamp. This makes the secret accessible to query once again.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:RestoreSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To delete a secret, use <a>DeleteSecret</a>.</p> </li> </ul></p>
-    async fn restore_secret(
+    /// Presigns the request `restore_secret` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_secret(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestoreSecretRequest,
-    ) -> Result<RestoreSecretResponse, RusotoError<RestoreSecretError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_secret_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_secret_request(&self, input: RestoreSecretRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("RestoreSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.RestoreSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `rotate_secret`, also returning the metadata of the response.
+    pub async fn rotate_secret_with_metadata(
+        &self,
+        input: RotateSecretRequest,
+    ) -> Result<(RotateSecretResponse, ResponseMetadata), RusotoError<RotateSecretError>> {
+        let request = self.build_rotate_secret_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RestoreSecretResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RotateSecretResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RestoreSecretError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RotateSecretError::from_response(response))
         }
     }
 
-    /// <p><p>Configures and starts the asynchronous process of rotating this secret. If you include the configuration parameters, the operation sets those values for the secret and then immediately starts a rotation. If you do not include the configuration parameters, the operation starts a rotation with the values already stored in the secret. After the rotation completes, the protected service and its clients all use the new version of the secret. </p> <p>This required configuration information includes the ARN of an AWS Lambda function and the time between scheduled rotations. The Lambda rotation function creates a new version of the secret and creates or updates the credentials on the protected service to match. After testing the new credentials, the function marks the new secret with the staging label <code>AWSCURRENT</code> so that your clients all immediately begin to use the new version. For more information about rotating secrets and how to configure a Lambda function to rotate the secrets for your protected service, see <a href="https://docs.aws.amazon.com/secretsmanager/latest/userguide/rotating-secrets.html">Rotating Secrets in AWS Secrets Manager</a> in the <i>AWS Secrets Manager User Guide</i>.</p> <p>Secrets Manager schedules the next rotation when the previous one is complete. Secrets Manager schedules the date by adding the rotation interval (number of days) to the actual date of the last rotation. The service chooses the hour within that 24-hour date window randomly. The minute is also chosen somewhat randomly, but weighted towards the top of the hour and influenced by a variety of factors that help distribute load.</p> <p>The rotation function must end with the versions of the secret in one of two states:</p> <ul> <li> <p>The <code>AWSPENDING</code> and <code>AWSCURRENT</code> staging labels are attached to the same version of the secret, or</p> </li> <li> <p>The <code>AWSPENDING</code> staging label is not attached to any version of the secret.</p> </li> </ul> <p>If instead the <code>AWSPENDING</code> staging label is present but is not attached to the same version as <code>AWSCURRENT</code> then any later invocation of <code>RotateSecret</code> assumes that a previous rotation request is still in progress and returns an error.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:RotateSecret</p> </li> <li> <p>lambda:InvokeFunction (on the function specified in the secret&#39;s metadata)</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To list the secrets in your account, use <a>ListSecrets</a>.</p> </li> <li> <p>To get the details for a version of a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To create a new version of a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To attach staging labels to or remove staging labels from a version of a secret, use <a>UpdateSecretVersionStage</a>.</p> </li> </ul></p>
-    async fn rotate_secret(
+    /// Presigns the request `rotate_secret` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_rotate_secret(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RotateSecretRequest,
-    ) -> Result<RotateSecretResponse, RusotoError<RotateSecretError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_rotate_secret_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_rotate_secret_request(&self, input: RotateSecretRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("RotateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.RotateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RotateSecretResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RotateSecretError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p><p>Attaches one or more tags, each consisting of a key name and a value, to the specified secret. Tags are part of the secret&#39;s overall metadata, and are not associated with any specific version of the secret. This operation only appends tags to the existing list of tags. To remove tags, you must use <a>UntagResource</a>.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per secret—50</p> </li> <li> <p>Maximum key length—127 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length—255 Unicode characters in UTF-8</p> </li> <li> <p>Tag keys and values are case sensitive.</p> </li> <li> <p>Do not use the <code>aws:</code> prefix in your tag names or values because it is reserved for AWS use. You can&#39;t edit or delete tag names or values with this prefix. Tags with this prefix do not count against your tags per secret limit.</p> </li> <li> <p>If your tagging schema will be used across multiple services and resources, remember that other services might have restrictions on allowed characters. Generally allowed characters are: letters, spaces, and numbers representable in UTF-8, plus the following special characters: + - = . _ : / @.</p> </li> </ul> <important> <p>If you use tags as part of your security strategy, then adding or removing a tag can change permissions. If successfully completing this operation would result in you losing your permissions for this secret, then the operation is blocked and returns an Access Denied error.</p> </important> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:TagResource</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To remove one or more tags from the collection attached to a secret, use <a>UntagResource</a>.</p> </li> <li> <p>To view the list of tags attached to a secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p><p>Removes one or more tags from the specified secret.</p> <p>This operation is idempotent. If a requested tag is not attached to the secret, no error is returned and the secret metadata is unchanged.</p> <important> <p>If you use tags as part of your security strategy, then removing a tag can change permissions. If successfully completing this operation would result in you losing your permissions for this secret, then the operation is blocked and returns an Access Denied error.</p> </important> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UntagResource</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To add one or more tags to the collection attached to a secret, use <a>TagResource</a>.</p> </li> <li> <p>To view the list of tags attached to a secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_secret`, also returning the metadata of the response.
+    pub async fn update_secret_with_metadata(
+        &self,
+        input: UpdateSecretRequest,
+    ) -> Result<(UpdateSecretResponse, ResponseMetadata), RusotoError<UpdateSecretError>> {
+        let request = self.build_update_secret_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSecretResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSecretError::from_response(response))
         }
     }
 
-    /// <p><p>Modifies many of the details of the specified secret. If you include a <code>ClientRequestToken</code> and <i>either</i> <code>SecretString</code> or <code>SecretBinary</code> then it also creates a new version attached to the secret.</p> <p>To modify the rotation configuration of a secret, use <a>RotateSecret</a> instead.</p> <note> <p>The Secrets Manager console uses only the <code>SecretString</code> parameter and therefore limits you to encrypting and storing only a text string. To encrypt and store binary data as part of the version of a secret, you must use either the AWS CLI or one of the AWS SDKs.</p> </note> <ul> <li> <p>If a version with a <code>VersionId</code> with the same value as the <code>ClientRequestToken</code> parameter already exists, the operation results in an error. You cannot modify an existing version, you can only create a new version.</p> </li> <li> <p>If you include <code>SecretString</code> or <code>SecretBinary</code> to create a new secret version, Secrets Manager automatically attaches the staging label <code>AWSCURRENT</code> to the new version. </p> </li> </ul> <note> <ul> <li> <p>If you call an operation that needs to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS having to create the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret is in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UpdateSecret</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a custom AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s AWS managed CMK for Secrets Manager.</p> </li> <li> <p>kms:Decrypt - needed only if you use a custom AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s AWS managed CMK for Secrets Manager.</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a new secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To add only a new version to an existing secret, use <a>PutSecretValue</a>.</p> </li> <li> <p>To get the details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list the versions contained in a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
-    async fn update_secret(
+    /// Presigns the request `update_secret` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_secret(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSecretRequest,
-    ) -> Result<UpdateSecretResponse, RusotoError<UpdateSecretError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_secret_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_secret_request(&self, input: UpdateSecretRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("UpdateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.UpdateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_secret_version_stage`, also returning the metadata of the response.
+    pub async fn update_secret_version_stage_with_metadata(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> Result<
+        (UpdateSecretVersionStageResponse, ResponseMetadata),
+        RusotoError<UpdateSecretVersionStageError>,
+    > {
+        let request = self.build_update_secret_version_stage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateSecretResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSecretVersionStageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSecretError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSecretVersionStageError::from_response(response))
         }
     }
 
-    /// <p><p>Modifies the staging labels attached to a version of a secret. Staging labels are used to track a version as it progresses through the secret rotation process. You can attach a staging label to only one version of a secret at a time. If a staging label to be added is already attached to another version, then it is moved--removed from the other version first and then attached to this one. For more information about staging labels, see <a href="https://docs.aws.amazon.com/secretsmanager/latest/userguide/terms-concepts.html#term_staging-label">Staging Labels</a> in the <i>AWS Secrets Manager User Guide</i>. </p> <p>The staging labels that you specify in the <code>VersionStage</code> parameter are added to the existing list of staging labels--they don&#39;t replace it.</p> <p>You can move the <code>AWSCURRENT</code> staging label to this version by including it in this call.</p> <note> <p>Whenever you move <code>AWSCURRENT</code>, Secrets Manager automatically moves the label <code>AWSPREVIOUS</code> to the version that <code>AWSCURRENT</code> was removed from.</p> </note> <p>If this action results in the last label being removed from a version, then the version is considered to be &#39;deprecated&#39; and can be deleted by Secrets Manager.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UpdateSecretVersionStage</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To get the list of staging labels that are currently associated with a version of a secret, use <code> <a>DescribeSecret</a> </code> and examine the <code>SecretVersionsToStages</code> response value. </p> </li> </ul></p>
-    async fn update_secret_version_stage(
+    /// Presigns the request `update_secret_version_stage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_secret_version_stage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSecretVersionStageRequest,
-    ) -> Result<UpdateSecretVersionStageResponse, RusotoError<UpdateSecretVersionStageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_secret_version_stage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_secret_version_stage_request(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("UpdateSecretVersionStage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.UpdateSecretVersionStage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListSecrets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_secrets_pages(
+        &self,
+        input: ListSecretsRequest,
+    ) -> impl futures::Stream<Item = Result<ListSecretsResponse, RusotoError<ListSecretsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecretsManagerClient, input| async move { client.list_secrets(input).await },
+            |input: &ListSecretsRequest, output: &ListSecretsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSecretsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SecretList` of every page of `ListSecrets` results for `input`, as `list_secrets_pages` requests them.
+    pub fn list_secrets_stream(
+        &self,
+        input: ListSecretsRequest,
+    ) -> impl futures::Stream<Item = Result<SecretListEntry, RusotoError<ListSecretsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_secrets_pages(input), |page| {
+            page.secret_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl SecretsManager for SecretsManagerClient {
+    /// <p><p>Disables automatic scheduled rotation and cancels the rotation of a secret if one is currently in progress.</p> <p>To re-enable scheduled rotation, call <a>RotateSecret</a> with <code>AutomaticallyRotateAfterDays</code> set to a value greater than 0. This will immediately rotate your secret and then enable the automatic schedule.</p> <note> <p>If you cancel a rotation that is in progress, it can leave the <code>VersionStage</code> labels in an unexpected state. Depending on what step of the rotation was in progress, you might need to remove the staging label <code>AWSPENDING</code> from the partially created version, specified by the <code>VersionId</code> response value. You should also evaluate the partially rotated new version to see if it should be deleted, which you can do by removing all staging labels from the new version&#39;s <code>VersionStage</code> field.</p> </note> <p>To successfully start a rotation, the staging label <code>AWSPENDING</code> must be in one of the following states:</p> <ul> <li> <p>Not be attached to any version at all</p> </li> <li> <p>Attached to the same version as the staging label <code>AWSCURRENT</code> </p> </li> </ul> <p>If the staging label <code>AWSPENDING</code> is attached to a different version than the version with <code>AWSCURRENT</code> then the attempt to rotate fails.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CancelRotateSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To configure rotation for a secret or to manually trigger a rotation, use <a>RotateSecret</a>.</p> </li> <li> <p>To get the rotation configuration details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> <li> <p>To list all of the versions currently associated with a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+    async fn cancel_rotate_secret(
+        &self,
+        input: CancelRotateSecretRequest,
+    ) -> Result<CancelRotateSecretResponse, RusotoError<CancelRotateSecretError>> {
+        self.cancel_rotate_secret_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSecretVersionStageResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSecretVersionStageError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new secret. A secret in Secrets Manager consists of both the protected secret data and the important information needed to manage the secret.</p> <p>Secrets Manager stores the encrypted secret data in one of a collection of &quot;versions&quot; associated with the secret. Each version contains a copy of the encrypted secret data. Each version is associated with one or more &quot;staging labels&quot; that identify where the version is in the rotation cycle. The <code>SecretVersionsToStages</code> field of the secret contains the mapping of staging labels to the active versions of the secret. Versions without a staging label are considered deprecated and are not included in the list.</p> <p>You provide the secret data to be encrypted by putting text in either the <code>SecretString</code> parameter or binary data in the <code>SecretBinary</code> parameter, but not both. If you include <code>SecretString</code> or <code>SecretBinary</code> then Secrets Manager also creates an initial secret version and automatically attaches the staging label <code>AWSCURRENT</code> to the new version.</p> <note> <ul> <li> <p>If you call an operation that needs to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS having to create the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret is in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CreateSecret</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>kms:Decrypt - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>secretsmanager:TagResource - needed only if you include the <code>Tags</code> parameter. </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To delete a secret, use <a>DeleteSecret</a>.</p> </li> <li> <p>To modify an existing secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To create a new version of a secret, use <a>PutSecretValue</a>.</p> </li> <li> <p>To retrieve the encrypted secure string and secure binary values, use <a>GetSecretValue</a>.</p> </li> <li> <p>To retrieve all other details for a secret, use <a>DescribeSecret</a>. This does not include the encrypted secure string and secure binary values.</p> </li> <li> <p>To retrieve the list of secret versions associated with the current secret, use <a>DescribeSecret</a> and examine the <code>SecretVersionsToStages</code> response value.</p> </li> </ul></p>
+    async fn create_secret(
+        &self,
+        input: CreateSecretRequest,
+    ) -> Result<CreateSecretResponse, RusotoError<CreateSecretError>> {
+        self.create_secret_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the resource-based permission policy that&#39;s attached to the secret.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To attach a resource policy to a secret, use <a>PutResourcePolicy</a>.</p> </li> <li> <p>To retrieve the current resource-based policy that&#39;s attached to a secret, use <a>GetResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    async fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
+        self.delete_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an entire secret and all of its versions. You can optionally include a recovery window during which you can restore the secret. If you don&#39;t specify a recovery window value, the operation defaults to 30 days. Secrets Manager attaches a <code>DeletionDate</code> stamp to the secret that specifies the end of the recovery window. At the end of the recovery window, Secrets Manager deletes the secret permanently.</p> <p>At any time before recovery window ends, you can use <a>RestoreSecret</a> to remove the <code>DeletionDate</code> and cancel the deletion of the secret.</p> <p>You cannot access the encrypted secret information in any secret that is scheduled for deletion. If you need to access that information, you must cancel the deletion with <a>RestoreSecret</a> and then retrieve the information.</p> <note> <ul> <li> <p>There is no explicit operation to delete a version of a secret. Instead, remove all staging labels from the <code>VersionStage</code> field of a version. That marks the version as deprecated and allows Secrets Manager to delete it as needed. Versions that do not have any staging labels do not show up in <a>ListSecretVersionIds</a> unless you specify <code>IncludeDeprecated</code>.</p> </li> <li> <p>The permanent secret deletion at the end of the waiting period is performed as a background task with low priority. There is no guarantee of a specific time after the recovery window for the actual delete operation to occur.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To cancel deletion of a version of a secret before the recovery window has expired, use <a>RestoreSecret</a>.</p> </li> </ul></p>
+    async fn delete_secret(
+        &self,
+        input: DeleteSecretRequest,
+    ) -> Result<DeleteSecretResponse, RusotoError<DeleteSecretError>> {
+        self.delete_secret_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the details of a secret. It does not include the encrypted fields. Only those fields that are populated with a value are returned in the response. </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DescribeSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To modify a secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To retrieve the encrypted secret information in a version of the secret, use <a>GetSecretValue</a>.</p> </li> <li> <p>To list all of the secrets in the AWS account, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    async fn describe_secret(
+        &self,
+        input: DescribeSecretRequest,
+    ) -> Result<DescribeSecretResponse, RusotoError<DescribeSecretError>> {
+        self.describe_secret_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Generates a random password of the specified complexity. This operation is intended for use in the Lambda rotation function. Per best practice, we recommend that you specify the maximum length and include every character type that the system you are generating a password for can support.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:GetRandomPassword</p> </li> </ul></p>
+    async fn get_random_password(
+        &self,
+        input: GetRandomPasswordRequest,
+    ) -> Result<GetRandomPasswordResponse, RusotoError<GetRandomPasswordError>> {
+        self.get_random_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the JSON text of the resource-based policy document that&#39;s attached to the specified secret. The JSON request string input and response output are shown formatted with white space and line breaks for better readability. Submit your input as a single line JSON string.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:GetResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To attach a resource policy to a secret, use <a>PutResourcePolicy</a>.</p> </li> <li> <p>To delete the resource-based policy that&#39;s attached to a secret, use <a>DeleteResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    async fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
+        self.get_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the contents of the encrypted fields <code>SecretString</code> or <code>SecretBinary</code> from the specified version of a secret, whichever contains content.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:GetSecretValue</p> </li> <li> <p>kms:Decrypt - required only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a new version of the secret with different encrypted information, use <a>PutSecretValue</a>.</p> </li> <li> <p>To retrieve the non-encrypted details for the secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
+    async fn get_secret_value(
+        &self,
+        input: GetSecretValueRequest,
+    ) -> Result<GetSecretValueResponse, RusotoError<GetSecretValueError>> {
+        self.get_secret_value_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists all of the versions attached to the specified secret. The output does not include the <code>SecretString</code> or <code>SecretBinary</code> fields. By default, the list includes only versions that have at least one staging label in <code>VersionStage</code> attached.</p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can occasionally return an empty or shorter than expected list of results even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass to the next call to the same API to request the next part of the list.</p> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:ListSecretVersionIds</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To list the secrets in an account, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    async fn list_secret_version_ids(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> Result<ListSecretVersionIdsResponse, RusotoError<ListSecretVersionIdsError>> {
+        self.list_secret_version_ids_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists all of the secrets that are stored by Secrets Manager in the AWS account. To list the versions currently stored for a specific secret, use <a>ListSecretVersionIds</a>. The encrypted fields <code>SecretString</code> and <code>SecretBinary</code> are not included in the output. To get that information, call the <a>GetSecretValue</a> operation.</p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can occasionally return an empty or shorter than expected list of results even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass to the next call to the same API to request the next part of the list.</p> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:ListSecrets</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To list the versions attached to a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+    async fn list_secrets(
+        &self,
+        input: ListSecretsRequest,
+    ) -> Result<ListSecretsResponse, RusotoError<ListSecretsError>> {
+        self.list_secrets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Attaches the contents of the specified resource-based permission policy to a secret. A resource-based policy is optional. Alternatively, you can use IAM identity-based policies that specify the secret&#39;s Amazon Resource Name (ARN) in the policy statement&#39;s <code>Resources</code> element. You can also use a combination of both identity-based and resource-based policies. The affected users and roles receive the permissions that are permitted by all of the relevant policies. For more information, see <a href="http://docs.aws.amazon.com/secretsmanager/latest/userguide/auth-and-access_resource-based-policies.html">Using Resource-Based Policies for AWS Secrets Manager</a>. For the complete description of the AWS policy syntax and grammar, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies.html">IAM JSON Policy Reference</a> in the <i>IAM User Guide</i>.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:PutResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To retrieve the resource policy that&#39;s attached to a secret, use <a>GetResourcePolicy</a>.</p> </li> <li> <p>To delete the resource-based policy that&#39;s attached to a secret, use <a>DeleteResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    async fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.put_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Stores a new encrypted secret value in the specified secret. To do this, the operation creates a new version and attaches it to the secret. The version can contain a new <code>SecretString</code> value or a new <code>SecretBinary</code> value. You can also specify the staging labels that are initially attached to the new version.</p> <note> <p>The Secrets Manager console uses only the <code>SecretString</code> field. To add binary data to a secret with the <code>SecretBinary</code> field you must use the AWS CLI or one of the AWS SDKs.</p> </note> <ul> <li> <p>If this operation creates the first version for the secret then Secrets Manager automatically attaches the staging label <code>AWSCURRENT</code> to the new version.</p> </li> <li> <p>If another version of this secret already exists, then this operation does not automatically move any staging labels other than those that you explicitly specify in the <code>VersionStages</code> parameter.</p> </li> <li> <p>If this operation moves the staging label <code>AWSCURRENT</code> from another version to this version (because you included it in the <code>StagingLabels</code> parameter) then Secrets Manager also automatically moves the staging label <code>AWSPREVIOUS</code> to the version that <code>AWSCURRENT</code> was removed from.</p> </li> <li> <p>This operation is idempotent. If a version with a <code>VersionId</code> with the same value as the <code>ClientRequestToken</code> parameter already exists and you specify the same secret data, the operation succeeds but does nothing. However, if the secret data is different, then the operation fails because you cannot modify an existing version; you can only create new ones.</p> </li> </ul> <note> <ul> <li> <p>If you call an operation that needs to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS having to create the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret is in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:PutSecretValue</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To retrieve the encrypted value you store in the version of a secret, use <a>GetSecretValue</a>.</p> </li> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To get the details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list the versions attached to a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+    async fn put_secret_value(
+        &self,
+        input: PutSecretValueRequest,
+    ) -> Result<PutSecretValueResponse, RusotoError<PutSecretValueError>> {
+        self.put_secret_value_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Cancels the scheduled deletion of a secret by removing the <code>DeletedDate</code> time stamp. This makes the secret accessible to query once again.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:RestoreSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To delete a secret, use <a>DeleteSecret</a>.</p> </li> </ul></p>
+    async fn restore_secret(
+        &self,
+        input: RestoreSecretRequest,
+    ) -> Result<RestoreSecretResponse, RusotoError<RestoreSecretError>> {
+        self.restore_secret_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Configures and starts the asynchronous process of rotating this secret. If you include the configuration parameters, the operation sets those values for the secret and then immediately starts a rotation. If you do not include the configuration parameters, the operation starts a rotation with the values already stored in the secret. After the rotation completes, the protected service and its clients all use the new version of the secret. </p> <p>This required configuration information includes the ARN of an AWS Lambda function and the time between scheduled rotations. The Lambda rotation function creates a new version of the secret and creates or updates the credentials on the protected service to match. After testing the new credentials, the function marks the new secret with the staging label <code>AWSCURRENT</code> so that your clients all immediately begin to use the new version. For more information about rotating secrets and how to configure a Lambda function to rotate the secrets for your protected service, see <a href="https://docs.aws.amazon.com/secretsmanager/latest/userguide/rotating-secrets.html">Rotating Secrets in AWS Secrets Manager</a> in the <i>AWS Secrets Manager User Guide</i>.</p> <p>Secrets Manager schedules the next rotation when the previous one is complete. Secrets Manager schedules the date by adding the rotation interval (number of days) to the actual date of the last rotation. The service chooses the hour within that 24-hour date window randomly. The minute is also chosen somewhat randomly, but weighted towards the top of the hour and influenced by a variety of factors that help distribute load.</p> <p>The rotation function must end with the versions of the secret in one of two states:</p> <ul> <li> <p>The <code>AWSPENDING</code> and <code>AWSCURRENT</code> staging labels are attached to the same version of the secret, or</p> </li> <li> <p>The <code>AWSPENDING</code> staging label is not attached to any version of the secret.</p> </li> </ul> <p>If instead the <code>AWSPENDING</code> staging label is present but is not attached to the same version as <code>AWSCURRENT</code> then any later invocation of <code>RotateSecret</code> assumes that a previous rotation request is still in progress and returns an error.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:RotateSecret</p> </li> <li> <p>lambda:InvokeFunction (on the function specified in the secret&#39;s metadata)</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To list the secrets in your account, use <a>ListSecrets</a>.</p> </li> <li> <p>To get the details for a version of a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To create a new version of a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To attach staging labels to or remove staging labels from a version of a secret, use <a>UpdateSecretVersionStage</a>.</p> </li> </ul></p>
+    async fn rotate_secret(
+        &self,
+        input: RotateSecretRequest,
+    ) -> Result<RotateSecretResponse, RusotoError<RotateSecretError>> {
+        self.rotate_secret_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Attaches one or more tags, each consisting of a key name and a value, to the specified secret. Tags are part of the secret&#39;s overall metadata, and are not associated with any specific version of the secret. This operation only appends tags to the existing list of tags. To remove tags, you must use <a>UntagResource</a>.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per secret—50</p> </li> <li> <p>Maximum key length—127 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length—255 Unicode characters in UTF-8</p> </li> <li> <p>Tag keys and values are case sensitive.</p> </li> <li> <p>Do not use the <code>aws:</code> prefix in your tag names or values because it is reserved for AWS use. You can&#39;t edit or delete tag names or values with this prefix. Tags with this prefix do not count against your tags per secret limit.</p> </li> <li> <p>If your tagging schema will be used across multiple services and resources, remember that other services might have restrictions on allowed characters. Generally allowed characters are: letters, spaces, and numbers representable in UTF-8, plus the following special characters: + - = . _ : / @.</p> </li> </ul> <important> <p>If you use tags as part of your security strategy, then adding or removing a tag can change permissions. If successfully completing this operation would result in you losing your permissions for this secret, then the operation is blocked and returns an Access Denied error.</p> </important> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:TagResource</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To remove one or more tags from the collection attached to a secret, use <a>UntagResource</a>.</p> </li> <li> <p>To view the list of tags attached to a secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes one or more tags from the specified secret.</p> <p>This operation is idempotent. If a requested tag is not attached to the secret, no error is returned and the secret metadata is unchanged.</p> <important> <p>If you use tags as part of your security strategy, then removing a tag can change permissions. If successfully completing this operation would result in you losing your permissions for this secret, then the operation is blocked and returns an Access Denied error.</p> </important> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UntagResource</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To add one or more tags to the collection attached to a secret, use <a>TagResource</a>.</p> </li> <li> <p>To view the list of tags attached to a secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies many of the details of the specified secret. If you include a <code>ClientRequestToken</code> and <i>either</i> <code>SecretString</code> or <code>SecretBinary</code> then it also creates a new version attached to the secret.</p> <p>To modify the rotation configuration of a secret, use <a>RotateSecret</a> instead.</p> <note> <p>The Secrets Manager console uses only the <code>SecretString</code> parameter and therefore limits you to encrypting and storing only a text string. To encrypt and store binary data as part of the version of a secret, you must use either the AWS CLI or one of the AWS SDKs.</p> </note> <ul> <li> <p>If a version with a <code>VersionId</code> with the same value as the <code>ClientRequestToken</code> parameter already exists, the operation results in an error. You cannot modify an existing version, you can only create a new version.</p> </li> <li> <p>If you include <code>SecretString</code> or <code>SecretBinary</code> to create a new secret version, Secrets Manager automatically attaches the staging label <code>AWSCURRENT</code> to the new version. </p> </li> </ul> <note> <ul> <li> <p>If you call an operation that needs to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS having to create the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret is in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UpdateSecret</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a custom AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s AWS managed CMK for Secrets Manager.</p> </li> <li> <p>kms:Decrypt - needed only if you use a custom AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s AWS managed CMK for Secrets Manager.</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a new secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To add only a new version to an existing secret, use <a>PutSecretValue</a>.</p> </li> <li> <p>To get the details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list the versions contained in a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+    async fn update_secret(
+        &self,
+        input: UpdateSecretRequest,
+    ) -> Result<UpdateSecretResponse, RusotoError<UpdateSecretError>> {
+        self.update_secret_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies the staging labels attached to a version of a secret. Staging labels are used to track a version as it progresses through the secret rotation process. You can attach a staging label to only one version of a secret at a time. If a staging label to be added is already attached to another version, then it is moved--removed from the other version first and then attached to this one. For more information about staging labels, see <a href="https://docs.aws.amazon.com/secretsmanager/latest/userguide/terms-concepts.html#term_staging-label">Staging Labels</a> in the <i>AWS Secrets Manager User Guide</i>. </p> <p>The staging labels that you specify in the <code>VersionStage</code> parameter are added to the existing list of staging labels--they don&#39;t replace it.</p> <p>You can move the <code>AWSCURRENT</code> staging label to this version by including it in this call.</p> <note> <p>Whenever you move <code>AWSCURRENT</code>, Secrets Manager automatically moves the label <code>AWSPREVIOUS</code> to the version that <code>AWSCURRENT</code> was removed from.</p> </note> <p>If this action results in the last label being removed from a version, then the version is considered to be &#39;deprecated&#39; and can be deleted by Secrets Manager.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UpdateSecretVersionStage</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To get the list of staging labels that are currently associated with a version of a secret, use <code> <a>DescribeSecret</a> </code> and examine the <code>SecretVersionsToStages</code> response value. </p> </li> </ul></p>
+    async fn update_secret_version_stage(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> Result<UpdateSecretVersionStageResponse, RusotoError<UpdateSecretVersionStageError>> {
+        self.update_secret_version_stage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
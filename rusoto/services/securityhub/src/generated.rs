@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2454,6 +2454,7 @@ pub struct UpdateStandardsControlResponse {}
 
 /// Errors returned by AcceptInvitation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptInvitationError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2465,6 +2466,8 @@ pub enum AcceptInvitationError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptInvitationError {
@@ -2472,22 +2475,31 @@ impl AcceptInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(AcceptInvitationError::Internal(err.msg))
+                    return RusotoError::service(AcceptInvitationError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(AcceptInvitationError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        AcceptInvitationError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AcceptInvitationError::InvalidInput(err.msg))
+                    return RusotoError::service(AcceptInvitationError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AcceptInvitationError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        AcceptInvitationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AcceptInvitationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AcceptInvitationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2502,12 +2514,14 @@ impl fmt::Display for AcceptInvitationError {
             AcceptInvitationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             AcceptInvitationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             AcceptInvitationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AcceptInvitationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcceptInvitationError {}
 /// Errors returned by BatchDisableStandards
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDisableStandardsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2517,6 +2531,8 @@ pub enum BatchDisableStandardsError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDisableStandardsError {
@@ -2524,19 +2540,31 @@ impl BatchDisableStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::Internal(err.msg))
+                    return RusotoError::service(
+                        BatchDisableStandardsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        BatchDisableStandardsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchDisableStandardsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        BatchDisableStandardsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDisableStandardsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2550,12 +2578,14 @@ impl fmt::Display for BatchDisableStandardsError {
             BatchDisableStandardsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             BatchDisableStandardsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchDisableStandardsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            BatchDisableStandardsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDisableStandardsError {}
 /// Errors returned by BatchEnableStandards
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchEnableStandardsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2565,6 +2595,8 @@ pub enum BatchEnableStandardsError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchEnableStandardsError {
@@ -2572,19 +2604,28 @@ impl BatchEnableStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::Internal(err.msg))
+                    return RusotoError::service(BatchEnableStandardsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        BatchEnableStandardsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchEnableStandardsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        BatchEnableStandardsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchEnableStandardsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2598,12 +2639,14 @@ impl fmt::Display for BatchEnableStandardsError {
             BatchEnableStandardsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             BatchEnableStandardsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchEnableStandardsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            BatchEnableStandardsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchEnableStandardsError {}
 /// Errors returned by BatchImportFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchImportFindingsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2613,6 +2656,8 @@ pub enum BatchImportFindingsError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchImportFindingsError {
@@ -2620,19 +2665,28 @@ impl BatchImportFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchImportFindingsError::Internal(err.msg))
+                    return RusotoError::service(BatchImportFindingsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchImportFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        BatchImportFindingsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchImportFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchImportFindingsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchImportFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        BatchImportFindingsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchImportFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2646,12 +2700,14 @@ impl fmt::Display for BatchImportFindingsError {
             BatchImportFindingsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             BatchImportFindingsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             BatchImportFindingsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            BatchImportFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchImportFindingsError {}
 /// Errors returned by CreateActionTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateActionTargetError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2663,6 +2719,8 @@ pub enum CreateActionTargetError {
     LimitExceeded(String),
     /// <p>The resource specified in the request conflicts with an existing resource.</p>
     ResourceConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateActionTargetError {
@@ -2670,22 +2728,34 @@ impl CreateActionTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateActionTargetError::Internal(err.msg))
+                    return RusotoError::service(CreateActionTargetError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(CreateActionTargetError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        CreateActionTargetError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateActionTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateActionTargetError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateActionTargetError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateActionTargetError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateActionTargetError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        CreateActionTargetError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateActionTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2700,12 +2770,14 @@ impl fmt::Display for CreateActionTargetError {
             CreateActionTargetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateActionTargetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateActionTargetError::ResourceConflict(ref cause) => write!(f, "{}", cause),
+            CreateActionTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateActionTargetError {}
 /// Errors returned by CreateInsight
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInsightError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2717,6 +2789,8 @@ pub enum CreateInsightError {
     LimitExceeded(String),
     /// <p>The resource specified in the request conflicts with an existing resource.</p>
     ResourceConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInsightError {
@@ -2724,22 +2798,25 @@ impl CreateInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateInsightError::Internal(err.msg))
+                    return RusotoError::service(CreateInsightError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(CreateInsightError::InvalidAccess(err.msg))
+                    return RusotoError::service(CreateInsightError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInsightError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateInsightError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateInsightError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateInsightError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateInsightError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        CreateInsightError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateInsightError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2754,12 +2831,14 @@ impl fmt::Display for CreateInsightError {
             CreateInsightError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateInsightError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateInsightError::ResourceConflict(ref cause) => write!(f, "{}", cause),
+            CreateInsightError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInsightError {}
 /// Errors returned by CreateMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMembersError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2771,6 +2850,8 @@ pub enum CreateMembersError {
     LimitExceeded(String),
     /// <p>The resource specified in the request conflicts with an existing resource.</p>
     ResourceConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMembersError {
@@ -2778,22 +2859,25 @@ impl CreateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateMembersError::Internal(err.msg))
+                    return RusotoError::service(CreateMembersError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(CreateMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(CreateMembersError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateMembersError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateMembersError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateMembersError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        CreateMembersError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2808,12 +2892,14 @@ impl fmt::Display for CreateMembersError {
             CreateMembersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateMembersError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateMembersError::ResourceConflict(ref cause) => write!(f, "{}", cause),
+            CreateMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMembersError {}
 /// Errors returned by DeclineInvitations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeclineInvitationsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2823,6 +2909,8 @@ pub enum DeclineInvitationsError {
     InvalidInput(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeclineInvitationsError {
@@ -2830,19 +2918,28 @@ impl DeclineInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeclineInvitationsError::Internal(err.msg))
+                    return RusotoError::service(DeclineInvitationsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeclineInvitationsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        DeclineInvitationsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeclineInvitationsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeclineInvitationsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeclineInvitationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeclineInvitationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeclineInvitationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2856,12 +2953,14 @@ impl fmt::Display for DeclineInvitationsError {
             DeclineInvitationsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             DeclineInvitationsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeclineInvitationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeclineInvitationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeclineInvitationsError {}
 /// Errors returned by DeleteActionTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteActionTargetError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2871,6 +2970,8 @@ pub enum DeleteActionTargetError {
     InvalidInput(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteActionTargetError {
@@ -2878,19 +2979,28 @@ impl DeleteActionTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteActionTargetError::Internal(err.msg))
+                    return RusotoError::service(DeleteActionTargetError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteActionTargetError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        DeleteActionTargetError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteActionTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteActionTargetError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteActionTargetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteActionTargetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteActionTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2904,12 +3014,14 @@ impl fmt::Display for DeleteActionTargetError {
             DeleteActionTargetError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             DeleteActionTargetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteActionTargetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteActionTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteActionTargetError {}
 /// Errors returned by DeleteInsight
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInsightError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2921,6 +3033,8 @@ pub enum DeleteInsightError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInsightError {
@@ -2928,22 +3042,25 @@ impl DeleteInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteInsightError::Internal(err.msg))
+                    return RusotoError::service(DeleteInsightError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteInsightError::InvalidAccess(err.msg))
+                    return RusotoError::service(DeleteInsightError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInsightError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteInsightError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteInsightError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteInsightError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInsightError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteInsightError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInsightError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2958,12 +3075,14 @@ impl fmt::Display for DeleteInsightError {
             DeleteInsightError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteInsightError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteInsightError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteInsightError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInsightError {}
 /// Errors returned by DeleteInvitations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInvitationsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -2975,6 +3094,8 @@ pub enum DeleteInvitationsError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInvitationsError {
@@ -2982,22 +3103,34 @@ impl DeleteInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteInvitationsError::Internal(err.msg))
+                    return RusotoError::service(DeleteInvitationsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteInvitationsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        DeleteInvitationsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInvitationsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteInvitationsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteInvitationsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteInvitationsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInvitationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteInvitationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInvitationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3012,12 +3145,14 @@ impl fmt::Display for DeleteInvitationsError {
             DeleteInvitationsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteInvitationsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteInvitationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteInvitationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInvitationsError {}
 /// Errors returned by DeleteMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMembersError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3029,6 +3164,8 @@ pub enum DeleteMembersError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMembersError {
@@ -3036,22 +3173,25 @@ impl DeleteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteMembersError::Internal(err.msg))
+                    return RusotoError::service(DeleteMembersError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(DeleteMembersError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteMembersError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteMembersError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteMembersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3066,12 +3206,14 @@ impl fmt::Display for DeleteMembersError {
             DeleteMembersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteMembersError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteMembersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMembersError {}
 /// Errors returned by DescribeActionTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeActionTargetsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3081,6 +3223,8 @@ pub enum DescribeActionTargetsError {
     InvalidInput(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeActionTargetsError {
@@ -3088,21 +3232,31 @@ impl DescribeActionTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::Internal(err.msg))
+                    return RusotoError::service(
+                        DescribeActionTargetsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        DescribeActionTargetsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeActionTargetsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeActionTargetsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeActionTargetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3116,12 +3270,14 @@ impl fmt::Display for DescribeActionTargetsError {
             DescribeActionTargetsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             DescribeActionTargetsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeActionTargetsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeActionTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeActionTargetsError {}
 /// Errors returned by DescribeHub
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHubError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3133,6 +3289,8 @@ pub enum DescribeHubError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHubError {
@@ -3140,22 +3298,22 @@ impl DescribeHubError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeHubError::Internal(err.msg))
+                    return RusotoError::service(DescribeHubError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeHubError::InvalidAccess(err.msg))
+                    return RusotoError::service(DescribeHubError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeHubError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeHubError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeHubError::LimitExceeded(err.msg))
+                    return RusotoError::service(DescribeHubError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeHubError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeHubError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeHubError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3170,12 +3328,14 @@ impl fmt::Display for DescribeHubError {
             DescribeHubError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeHubError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DescribeHubError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeHubError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHubError {}
 /// Errors returned by DescribeProducts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProductsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3185,6 +3345,8 @@ pub enum DescribeProductsError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProductsError {
@@ -3192,19 +3354,25 @@ impl DescribeProductsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeProductsError::Internal(err.msg))
+                    return RusotoError::service(DescribeProductsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeProductsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        DescribeProductsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeProductsError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeProductsError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeProductsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DescribeProductsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProductsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3218,12 +3386,14 @@ impl fmt::Display for DescribeProductsError {
             DescribeProductsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             DescribeProductsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeProductsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            DescribeProductsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProductsError {}
 /// Errors returned by DescribeStandardsControls
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStandardsControlsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3233,6 +3403,8 @@ pub enum DescribeStandardsControlsError {
     InvalidInput(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStandardsControlsError {
@@ -3240,25 +3412,33 @@ impl DescribeStandardsControlsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::Internal(err.msg))
+                    return RusotoError::service(
+                        DescribeStandardsControlsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::InvalidAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStandardsControlsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStandardsControlsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStandardsControlsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeStandardsControlsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3272,12 +3452,14 @@ impl fmt::Display for DescribeStandardsControlsError {
             DescribeStandardsControlsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             DescribeStandardsControlsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeStandardsControlsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStandardsControlsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStandardsControlsError {}
 /// Errors returned by DisableImportFindingsForProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableImportFindingsForProductError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3289,6 +3471,8 @@ pub enum DisableImportFindingsForProductError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableImportFindingsForProductError {
@@ -3298,32 +3482,42 @@ impl DisableImportFindingsForProductError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisableImportFindingsForProductError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableImportFindingsForProductError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableImportFindingsForProductError::InvalidAccess(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableImportFindingsForProductError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableImportFindingsForProductError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableImportFindingsForProductError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisableImportFindingsForProductError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3344,12 +3538,16 @@ impl fmt::Display for DisableImportFindingsForProductError {
             DisableImportFindingsForProductError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisableImportFindingsForProductError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisableImportFindingsForProductError {}
 /// Errors returned by DisableSecurityHub
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableSecurityHubError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3359,6 +3557,8 @@ pub enum DisableSecurityHubError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableSecurityHubError {
@@ -3366,19 +3566,28 @@ impl DisableSecurityHubError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisableSecurityHubError::Internal(err.msg))
+                    return RusotoError::service(DisableSecurityHubError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DisableSecurityHubError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        DisableSecurityHubError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisableSecurityHubError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DisableSecurityHubError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableSecurityHubError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DisableSecurityHubError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableSecurityHubError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3392,12 +3601,14 @@ impl fmt::Display for DisableSecurityHubError {
             DisableSecurityHubError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             DisableSecurityHubError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DisableSecurityHubError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisableSecurityHubError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableSecurityHubError {}
 /// Errors returned by DisassociateFromMasterAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateFromMasterAccountError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3409,6 +3620,8 @@ pub enum DisassociateFromMasterAccountError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateFromMasterAccountError {
@@ -3418,32 +3631,42 @@ impl DisassociateFromMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateFromMasterAccountError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::InvalidAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateFromMasterAccountError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateFromMasterAccountError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateFromMasterAccountError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateFromMasterAccountError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateFromMasterAccountError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3460,12 +3683,16 @@ impl fmt::Display for DisassociateFromMasterAccountError {
             DisassociateFromMasterAccountError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateFromMasterAccountError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateFromMasterAccountError {}
 /// Errors returned by DisassociateMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateMembersError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3477,6 +3704,8 @@ pub enum DisassociateMembersError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateMembersError {
@@ -3484,24 +3713,34 @@ impl DisassociateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateMembersError::Internal(err.msg))
+                    return RusotoError::service(DisassociateMembersError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DisassociateMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        DisassociateMembersError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DisassociateMembersError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisassociateMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DisassociateMembersError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateMembersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateMembersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3516,12 +3755,14 @@ impl fmt::Display for DisassociateMembersError {
             DisassociateMembersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DisassociateMembersError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DisassociateMembersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateMembersError {}
 /// Errors returned by EnableImportFindingsForProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableImportFindingsForProductError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3533,6 +3774,8 @@ pub enum EnableImportFindingsForProductError {
     LimitExceeded(String),
     /// <p>The resource specified in the request conflicts with an existing resource.</p>
     ResourceConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableImportFindingsForProductError {
@@ -3542,32 +3785,42 @@ impl EnableImportFindingsForProductError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(EnableImportFindingsForProductError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableImportFindingsForProductError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableImportFindingsForProductError::InvalidAccess(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableImportFindingsForProductError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableImportFindingsForProductError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableImportFindingsForProductError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableImportFindingsForProductError::ResourceConflict(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        EnableImportFindingsForProductError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3584,12 +3837,16 @@ impl fmt::Display for EnableImportFindingsForProductError {
             EnableImportFindingsForProductError::ResourceConflict(ref cause) => {
                 write!(f, "{}", cause)
             }
+            EnableImportFindingsForProductError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for EnableImportFindingsForProductError {}
 /// Errors returned by EnableSecurityHub
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableSecurityHubError {
     /// <p>You don't have permission to perform the action specified in the request.</p>
     AccessDenied(String),
@@ -3601,6 +3858,8 @@ pub enum EnableSecurityHubError {
     LimitExceeded(String),
     /// <p>The resource specified in the request conflicts with an existing resource.</p>
     ResourceConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableSecurityHubError {
@@ -3608,22 +3867,34 @@ impl EnableSecurityHubError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableSecurityHubError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        EnableSecurityHubError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(EnableSecurityHubError::Internal(err.msg))
+                    return RusotoError::service(EnableSecurityHubError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(EnableSecurityHubError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        EnableSecurityHubError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableSecurityHubError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        EnableSecurityHubError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(EnableSecurityHubError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        EnableSecurityHubError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableSecurityHubError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3638,12 +3909,14 @@ impl fmt::Display for EnableSecurityHubError {
             EnableSecurityHubError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             EnableSecurityHubError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             EnableSecurityHubError::ResourceConflict(ref cause) => write!(f, "{}", cause),
+            EnableSecurityHubError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableSecurityHubError {}
 /// Errors returned by GetEnabledStandards
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEnabledStandardsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3653,6 +3926,8 @@ pub enum GetEnabledStandardsError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEnabledStandardsError {
@@ -3660,19 +3935,28 @@ impl GetEnabledStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::Internal(err.msg))
+                    return RusotoError::service(GetEnabledStandardsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        GetEnabledStandardsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetEnabledStandardsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetEnabledStandardsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEnabledStandardsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3686,12 +3970,14 @@ impl fmt::Display for GetEnabledStandardsError {
             GetEnabledStandardsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             GetEnabledStandardsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetEnabledStandardsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetEnabledStandardsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEnabledStandardsError {}
 /// Errors returned by GetFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFindingsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3701,6 +3987,8 @@ pub enum GetFindingsError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFindingsError {
@@ -3708,19 +3996,19 @@ impl GetFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetFindingsError::Internal(err.msg))
+                    return RusotoError::service(GetFindingsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service(GetFindingsError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetFindingsError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetFindingsError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3734,12 +4022,14 @@ impl fmt::Display for GetFindingsError {
             GetFindingsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             GetFindingsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetFindingsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFindingsError {}
 /// Errors returned by GetInsightResults
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInsightResultsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3751,6 +4041,8 @@ pub enum GetInsightResultsError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInsightResultsError {
@@ -3758,22 +4050,34 @@ impl GetInsightResultsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetInsightResultsError::Internal(err.msg))
+                    return RusotoError::service(GetInsightResultsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetInsightResultsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        GetInsightResultsError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInsightResultsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetInsightResultsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInsightResultsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetInsightResultsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetInsightResultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetInsightResultsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInsightResultsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3788,12 +4092,14 @@ impl fmt::Display for GetInsightResultsError {
             GetInsightResultsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetInsightResultsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetInsightResultsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetInsightResultsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInsightResultsError {}
 /// Errors returned by GetInsights
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInsightsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3805,6 +4111,8 @@ pub enum GetInsightsError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInsightsError {
@@ -3812,22 +4120,22 @@ impl GetInsightsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetInsightsError::Internal(err.msg))
+                    return RusotoError::service(GetInsightsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetInsightsError::InvalidAccess(err.msg))
+                    return RusotoError::service(GetInsightsError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInsightsError::InvalidInput(err.msg))
+                    return RusotoError::service(GetInsightsError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInsightsError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetInsightsError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetInsightsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetInsightsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInsightsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3842,12 +4150,14 @@ impl fmt::Display for GetInsightsError {
             GetInsightsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetInsightsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetInsightsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetInsightsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInsightsError {}
 /// Errors returned by GetInvitationsCount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInvitationsCountError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3857,6 +4167,8 @@ pub enum GetInvitationsCountError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInvitationsCountError {
@@ -3864,19 +4176,28 @@ impl GetInvitationsCountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetInvitationsCountError::Internal(err.msg))
+                    return RusotoError::service(GetInvitationsCountError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetInvitationsCountError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        GetInvitationsCountError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInvitationsCountError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetInvitationsCountError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInvitationsCountError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetInvitationsCountError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInvitationsCountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3890,12 +4211,14 @@ impl fmt::Display for GetInvitationsCountError {
             GetInvitationsCountError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             GetInvitationsCountError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetInvitationsCountError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetInvitationsCountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInvitationsCountError {}
 /// Errors returned by GetMasterAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMasterAccountError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3907,6 +4230,8 @@ pub enum GetMasterAccountError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMasterAccountError {
@@ -3914,22 +4239,31 @@ impl GetMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetMasterAccountError::Internal(err.msg))
+                    return RusotoError::service(GetMasterAccountError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetMasterAccountError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        GetMasterAccountError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMasterAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(GetMasterAccountError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMasterAccountError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetMasterAccountError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMasterAccountError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetMasterAccountError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMasterAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3944,12 +4278,14 @@ impl fmt::Display for GetMasterAccountError {
             GetMasterAccountError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetMasterAccountError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetMasterAccountError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetMasterAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMasterAccountError {}
 /// Errors returned by GetMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMembersError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -3961,6 +4297,8 @@ pub enum GetMembersError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMembersError {
@@ -3968,22 +4306,22 @@ impl GetMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetMembersError::Internal(err.msg))
+                    return RusotoError::service(GetMembersError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(GetMembersError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(GetMembersError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetMembersError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetMembersError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3998,12 +4336,14 @@ impl fmt::Display for GetMembersError {
             GetMembersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetMembersError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetMembersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMembersError {}
 /// Errors returned by InviteMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InviteMembersError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4015,6 +4355,8 @@ pub enum InviteMembersError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InviteMembersError {
@@ -4022,22 +4364,25 @@ impl InviteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(InviteMembersError::Internal(err.msg))
+                    return RusotoError::service(InviteMembersError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(InviteMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(InviteMembersError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(InviteMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(InviteMembersError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(InviteMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(InviteMembersError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InviteMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        InviteMembersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InviteMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4052,12 +4397,14 @@ impl fmt::Display for InviteMembersError {
             InviteMembersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             InviteMembersError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             InviteMembersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            InviteMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InviteMembersError {}
 /// Errors returned by ListEnabledProductsForImport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEnabledProductsForImportError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4065,6 +4412,8 @@ pub enum ListEnabledProductsForImportError {
     InvalidAccess(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEnabledProductsForImportError {
@@ -4074,22 +4423,30 @@ impl ListEnabledProductsForImportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListEnabledProductsForImportError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEnabledProductsForImportError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListEnabledProductsForImportError::InvalidAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEnabledProductsForImportError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListEnabledProductsForImportError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEnabledProductsForImportError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListEnabledProductsForImportError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4102,12 +4459,14 @@ impl fmt::Display for ListEnabledProductsForImportError {
             ListEnabledProductsForImportError::Internal(ref cause) => write!(f, "{}", cause),
             ListEnabledProductsForImportError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             ListEnabledProductsForImportError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            ListEnabledProductsForImportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEnabledProductsForImportError {}
 /// Errors returned by ListInvitations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInvitationsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4117,6 +4476,8 @@ pub enum ListInvitationsError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInvitationsError {
@@ -4124,19 +4485,19 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListInvitationsError::Internal(err.msg))
+                    return RusotoError::service(ListInvitationsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListInvitationsError::InvalidAccess(err.msg))
+                    return RusotoError::service(ListInvitationsError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListInvitationsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListInvitationsError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListInvitationsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListInvitationsError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInvitationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4150,12 +4511,14 @@ impl fmt::Display for ListInvitationsError {
             ListInvitationsError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             ListInvitationsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListInvitationsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            ListInvitationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInvitationsError {}
 /// Errors returned by ListMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMembersError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4165,6 +4528,8 @@ pub enum ListMembersError {
     InvalidInput(String),
     /// <p>The request was rejected because it attempted to create resources beyond the current AWS account limits. The error code describes the limit exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMembersError {
@@ -4172,19 +4537,19 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListMembersError::Internal(err.msg))
+                    return RusotoError::service(ListMembersError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(ListMembersError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(ListMembersError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListMembersError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4198,12 +4563,14 @@ impl fmt::Display for ListMembersError {
             ListMembersError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             ListMembersError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListMembersError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            ListMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMembersError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4211,6 +4578,8 @@ pub enum ListTagsForResourceError {
     InvalidInput(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -4218,18 +4587,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4242,12 +4615,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::Internal(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4255,6 +4630,8 @@ pub enum TagResourceError {
     InvalidInput(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4262,16 +4639,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(TagResourceError::Internal(err.msg))
+                    return RusotoError::service(TagResourceError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4284,12 +4661,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::Internal(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4297,6 +4676,8 @@ pub enum UntagResourceError {
     InvalidInput(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4304,16 +4685,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UntagResourceError::Internal(err.msg))
+                    return RusotoError::service(UntagResourceError::Internal(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4326,12 +4710,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::Internal(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateActionTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateActionTargetError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4341,6 +4727,8 @@ pub enum UpdateActionTargetError {
     InvalidInput(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateActionTargetError {
@@ -4348,19 +4736,28 @@ impl UpdateActionTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateActionTargetError::Internal(err.msg))
+                    return RusotoError::service(UpdateActionTargetError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateActionTargetError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        UpdateActionTargetError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateActionTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateActionTargetError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateActionTargetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateActionTargetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateActionTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4374,12 +4771,14 @@ impl fmt::Display for UpdateActionTargetError {
             UpdateActionTargetError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             UpdateActionTargetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateActionTargetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateActionTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateActionTargetError {}
 /// Errors returned by UpdateFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFindingsError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4391,6 +4790,8 @@ pub enum UpdateFindingsError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFindingsError {
@@ -4398,22 +4799,25 @@ impl UpdateFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateFindingsError::Internal(err.msg))
+                    return RusotoError::service(UpdateFindingsError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service(UpdateFindingsError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateFindingsError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateFindingsError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFindingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateFindingsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4428,12 +4832,14 @@ impl fmt::Display for UpdateFindingsError {
             UpdateFindingsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateFindingsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateFindingsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFindingsError {}
 /// Errors returned by UpdateInsight
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateInsightError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4445,6 +4851,8 @@ pub enum UpdateInsightError {
     LimitExceeded(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateInsightError {
@@ -4452,22 +4860,25 @@ impl UpdateInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateInsightError::Internal(err.msg))
+                    return RusotoError::service(UpdateInsightError::Internal(err.msg), &res)
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateInsightError::InvalidAccess(err.msg))
+                    return RusotoError::service(UpdateInsightError::InvalidAccess(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateInsightError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateInsightError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateInsightError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateInsightError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateInsightError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateInsightError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateInsightError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4482,12 +4893,14 @@ impl fmt::Display for UpdateInsightError {
             UpdateInsightError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateInsightError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateInsightError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateInsightError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateInsightError {}
 /// Errors returned by UpdateStandardsControl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStandardsControlError {
     /// <p>Internal server error.</p>
     Internal(String),
@@ -4497,6 +4910,8 @@ pub enum UpdateStandardsControlError {
     InvalidInput(String),
     /// <p>The request was rejected because we can't find the specified resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStandardsControlError {
@@ -4504,23 +4919,31 @@ impl UpdateStandardsControlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::Internal(err.msg))
+                    return RusotoError::service(
+                        UpdateStandardsControlError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::InvalidAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateStandardsControlError::InvalidAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateStandardsControlError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateStandardsControlError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateStandardsControlError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4534,6 +4957,7 @@ impl fmt::Display for UpdateStandardsControlError {
             UpdateStandardsControlError::InvalidAccess(ref cause) => write!(f, "{}", cause),
             UpdateStandardsControlError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateStandardsControlError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateStandardsControlError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4797,6 +5221,7 @@ impl SecurityHubClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SecurityHubClient {
         SecurityHubClient {
             client: Client::shared(),
@@ -4822,405 +5247,804 @@ impl SecurityHubClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SecurityHubClient {
         SecurityHubClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SecurityHubClient> {
+        rusoto_core::ClientBuilder::new(SecurityHubClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SecurityHubClient {
+        SecurityHubClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SecurityHub for SecurityHubClient {
-    /// <p>Accepts the invitation to be a member account and be monitored by the Security Hub master account that the invitation was sent from. When the member account accepts the invitation, permission is granted to the master account to view findings generated in the member account.</p>
-    async fn accept_invitation(
+impl SecurityHubClient {
+    /// Calls `accept_invitation`, also returning the metadata of the response.
+    pub async fn accept_invitation_with_metadata(
         &self,
         input: AcceptInvitationRequest,
-    ) -> Result<AcceptInvitationResponse, RusotoError<AcceptInvitationError>> {
+    ) -> Result<(AcceptInvitationResponse, ResponseMetadata), RusotoError<AcceptInvitationError>>
+    {
+        let request = self.build_accept_invitation_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcceptInvitationResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AcceptInvitationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `accept_invitation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_invitation(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AcceptInvitationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_invitation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_invitation_request(&self, input: AcceptInvitationRequest) -> SignedRequest {
         let request_uri = "/master";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `batch_disable_standards`, also returning the metadata of the response.
+    pub async fn batch_disable_standards_with_metadata(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> Result<
+        (BatchDisableStandardsResponse, ResponseMetadata),
+        RusotoError<BatchDisableStandardsError>,
+    > {
+        let request = self.build_batch_disable_standards_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AcceptInvitationResponse, _>()?;
+                .deserialize::<BatchDisableStandardsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AcceptInvitationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchDisableStandardsError::from_response(response))
         }
     }
 
-    /// <p>Disables the standards specified by the provided <code>StandardsSubscriptionArns</code>. For more information, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Standards Supported in AWS Security Hub</a>.</p>
-    async fn batch_disable_standards(
+    /// Presigns the request `batch_disable_standards` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_disable_standards(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDisableStandardsRequest,
-    ) -> Result<BatchDisableStandardsResponse, RusotoError<BatchDisableStandardsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_disable_standards_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_disable_standards_request(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> SignedRequest {
         let request_uri = "/standards/deregister";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchDisableStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `batch_enable_standards`, also returning the metadata of the response.
+    pub async fn batch_enable_standards_with_metadata(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> Result<
+        (BatchEnableStandardsResponse, ResponseMetadata),
+        RusotoError<BatchEnableStandardsError>,
+    > {
+        let request = self.build_batch_enable_standards_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDisableStandardsResponse, _>()?;
+                .deserialize::<BatchEnableStandardsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDisableStandardsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchEnableStandardsError::from_response(response))
         }
     }
 
-    /// <p>Enables the standards specified by the provided <code>standardsArn</code>. In this release, only CIS AWS Foundations standards are supported. For more information, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Standards Supported in AWS Security Hub</a>.</p>
-    async fn batch_enable_standards(
+    /// Presigns the request `batch_enable_standards` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_enable_standards(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchEnableStandardsRequest,
-    ) -> Result<BatchEnableStandardsResponse, RusotoError<BatchEnableStandardsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_enable_standards_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_enable_standards_request(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> SignedRequest {
         let request_uri = "/standards/register";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchEnableStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `batch_import_findings`, also returning the metadata of the response.
+    pub async fn batch_import_findings_with_metadata(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> Result<
+        (BatchImportFindingsResponse, ResponseMetadata),
+        RusotoError<BatchImportFindingsError>,
+    > {
+        let request = self.build_batch_import_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchEnableStandardsResponse, _>()?;
+                .deserialize::<BatchImportFindingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchEnableStandardsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchImportFindingsError::from_response(response))
         }
     }
 
-    /// <p>Imports security findings generated from an integrated third-party product into Security Hub. This action is requested by the integrated product to import its findings into Security Hub. The maximum allowed size for a finding is 240 Kb. An error is returned for any finding larger than 240 Kb.</p>
-    async fn batch_import_findings(
+    /// Presigns the request `batch_import_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_import_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchImportFindingsRequest,
-    ) -> Result<BatchImportFindingsResponse, RusotoError<BatchImportFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_import_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_import_findings_request(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> SignedRequest {
         let request_uri = "/findings/import";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchImportFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_action_target`, also returning the metadata of the response.
+    pub async fn create_action_target_with_metadata(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> Result<(CreateActionTargetResponse, ResponseMetadata), RusotoError<CreateActionTargetError>>
+    {
+        let request = self.build_create_action_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchImportFindingsResponse, _>()?;
+                .deserialize::<CreateActionTargetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchImportFindingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateActionTargetError::from_response(response))
         }
     }
 
-    /// <p>Creates a custom action target in Security Hub. You can use custom actions on findings and insights in Security Hub to trigger target actions in Amazon CloudWatch Events.</p>
-    async fn create_action_target(
+    /// Presigns the request `create_action_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_action_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateActionTargetRequest,
-    ) -> Result<CreateActionTargetResponse, RusotoError<CreateActionTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_action_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_action_target_request(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> SignedRequest {
         let request_uri = "/actionTargets";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateActionTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_insight`, also returning the metadata of the response.
+    pub async fn create_insight_with_metadata(
+        &self,
+        input: CreateInsightRequest,
+    ) -> Result<(CreateInsightResponse, ResponseMetadata), RusotoError<CreateInsightError>> {
+        let request = self.build_create_insight_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateActionTargetResponse, _>()?;
+                .deserialize::<CreateInsightResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateActionTargetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateInsightError::from_response(response))
         }
     }
 
-    /// <p>Creates a custom insight in Security Hub. An insight is a consolidation of findings that relate to a security issue that requires attention or remediation. Use the <code>GroupByAttribute</code> to group the related findings in the insight.</p>
-    async fn create_insight(
+    /// Presigns the request `create_insight` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_insight(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateInsightRequest,
-    ) -> Result<CreateInsightResponse, RusotoError<CreateInsightError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_insight_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_insight_request(&self, input: CreateInsightRequest) -> SignedRequest {
         let request_uri = "/insights";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_members`, also returning the metadata of the response.
+    pub async fn create_members_with_metadata(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<(CreateMembersResponse, ResponseMetadata), RusotoError<CreateMembersError>> {
+        let request = self.build_create_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateInsightResponse, _>()?;
+                .deserialize::<CreateMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateInsightError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateMembersError::from_response(response))
         }
     }
 
-    /// <p>Creates a member association in Security Hub between the specified accounts and the account used to make the request, which is the master account. To successfully create a member, you must use this action from an account that already has Security Hub enabled. You can use the <a>EnableSecurityHub</a> to enable Security Hub.</p> <p>After you use <code>CreateMembers</code> to create member account associations in Security Hub, you need to use the <a>InviteMembers</a> action, which invites the accounts to enable Security Hub and become member accounts in Security Hub. If the invitation is accepted by the account owner, the account becomes a member account in Security Hub, and a permission policy is added that permits the master account to view the findings generated in the member account. When Security Hub is enabled in the invited account, findings start being sent to both the member and master accounts.</p> <p>You can remove the association between the master and member accounts by using the <a>DisassociateFromMasterAccount</a> or <a>DisassociateMembers</a> operation.</p>
-    async fn create_members(
+    /// Presigns the request `create_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMembersRequest,
-    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_members_request(&self, input: CreateMembersRequest) -> SignedRequest {
         let request_uri = "/members";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `decline_invitations`, also returning the metadata of the response.
+    pub async fn decline_invitations_with_metadata(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> Result<(DeclineInvitationsResponse, ResponseMetadata), RusotoError<DeclineInvitationsError>>
+    {
+        let request = self.build_decline_invitations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMembersResponse, _>()?;
+                .deserialize::<DeclineInvitationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeclineInvitationsError::from_response(response))
         }
     }
 
-    /// <p>Declines invitations to become a member account.</p>
-    async fn decline_invitations(
+    /// Presigns the request `decline_invitations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_decline_invitations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeclineInvitationsRequest,
-    ) -> Result<DeclineInvitationsResponse, RusotoError<DeclineInvitationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_decline_invitations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_decline_invitations_request(&self, input: DeclineInvitationsRequest) -> SignedRequest {
         let request_uri = "/invitations/decline";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeclineInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_action_target`, also returning the metadata of the response.
+    pub async fn delete_action_target_with_metadata(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> Result<(DeleteActionTargetResponse, ResponseMetadata), RusotoError<DeleteActionTargetError>>
+    {
+        let request = self.build_delete_action_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeclineInvitationsResponse, _>()?;
+                .deserialize::<DeleteActionTargetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeclineInvitationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteActionTargetError::from_response(response))
         }
     }
 
-    /// <p>Deletes a custom action target from Security Hub. Deleting a custom action target doesn't affect any findings or insights that were already sent to Amazon CloudWatch Events using the custom action.</p>
-    async fn delete_action_target(
+    /// Presigns the request `delete_action_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_action_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteActionTargetRequest,
-    ) -> Result<DeleteActionTargetResponse, RusotoError<DeleteActionTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_action_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_action_target_request(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/actionTargets/{action_target_arn}",
             action_target_arn = input.action_target_arn
         );
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteActionTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_insight`, also returning the metadata of the response.
+    pub async fn delete_insight_with_metadata(
+        &self,
+        input: DeleteInsightRequest,
+    ) -> Result<(DeleteInsightResponse, ResponseMetadata), RusotoError<DeleteInsightError>> {
+        let request = self.build_delete_insight_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteActionTargetResponse, _>()?;
+                .deserialize::<DeleteInsightResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteActionTargetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteInsightError::from_response(response))
         }
     }
 
-    /// <p>Deletes the insight specified by the <code>InsightArn</code>.</p>
-    async fn delete_insight(
+    /// Presigns the request `delete_insight` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_insight(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInsightRequest,
-    ) -> Result<DeleteInsightResponse, RusotoError<DeleteInsightError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_insight_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_insight_request(&self, input: DeleteInsightRequest) -> SignedRequest {
         let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_invitations`, also returning the metadata of the response.
+    pub async fn delete_invitations_with_metadata(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> Result<(DeleteInvitationsResponse, ResponseMetadata), RusotoError<DeleteInvitationsError>>
+    {
+        let request = self.build_delete_invitations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInsightResponse, _>()?;
+                .deserialize::<DeleteInvitationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInsightError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteInvitationsError::from_response(response))
         }
     }
 
-    /// <p>Deletes invitations received by the AWS account to become a member account.</p>
-    async fn delete_invitations(
+    /// Presigns the request `delete_invitations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_invitations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInvitationsRequest,
-    ) -> Result<DeleteInvitationsResponse, RusotoError<DeleteInvitationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_invitations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_invitations_request(&self, input: DeleteInvitationsRequest) -> SignedRequest {
         let request_uri = "/invitations/delete";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_members`, also returning the metadata of the response.
+    pub async fn delete_members_with_metadata(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<(DeleteMembersResponse, ResponseMetadata), RusotoError<DeleteMembersError>> {
+        let request = self.build_delete_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInvitationsResponse, _>()?;
+                .deserialize::<DeleteMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInvitationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMembersError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified member accounts from Security Hub.</p>
-    async fn delete_members(
+    /// Presigns the request `delete_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMembersRequest,
-    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_members_request(&self, input: DeleteMembersRequest) -> SignedRequest {
         let request_uri = "/members/delete";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_action_targets`, also returning the metadata of the response.
+    pub async fn describe_action_targets_with_metadata(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> Result<
+        (DescribeActionTargetsResponse, ResponseMetadata),
+        RusotoError<DescribeActionTargetsError>,
+    > {
+        let request = self.build_describe_action_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMembersResponse, _>()?;
+                .deserialize::<DescribeActionTargetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeActionTargetsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the custom action targets in Security Hub in your account.</p>
-    async fn describe_action_targets(
+    /// Presigns the request `describe_action_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_action_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeActionTargetsRequest,
-    ) -> Result<DescribeActionTargetsResponse, RusotoError<DescribeActionTargetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_action_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_action_targets_request(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> SignedRequest {
         let request_uri = "/actionTargets/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeActionTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_hub`, also returning the metadata of the response.
+    pub async fn describe_hub_with_metadata(
+        &self,
+        input: DescribeHubRequest,
+    ) -> Result<(DescribeHubResponse, ResponseMetadata), RusotoError<DescribeHubError>> {
+        let request = self.build_describe_hub_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeActionTargetsResponse, _>()?;
+                .deserialize::<DescribeHubResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeActionTargetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeHubError::from_response(response))
         }
     }
 
-    /// <p>Returns details about the Hub resource in your account, including the <code>HubArn</code> and the time when you enabled Security Hub.</p>
-    async fn describe_hub(
+    /// Presigns the request `describe_hub` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_hub(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHubRequest,
-    ) -> Result<DescribeHubResponse, RusotoError<DescribeHubError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_hub_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_hub_request(&self, input: DescribeHubRequest) -> SignedRequest {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeHub");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5229,31 +6053,56 @@ impl SecurityHub for SecurityHubClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_products`, also returning the metadata of the response.
+    pub async fn describe_products_with_metadata(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> Result<(DescribeProductsResponse, ResponseMetadata), RusotoError<DescribeProductsError>>
+    {
+        let request = self.build_describe_products_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeHubResponse, _>()?;
+                .deserialize::<DescribeProductsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHubError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeProductsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the products available that you can subscribe to and integrate with Security Hub to consolidate findings.</p>
-    async fn describe_products(
+    /// Presigns the request `describe_products` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_products(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProductsRequest,
-    ) -> Result<DescribeProductsResponse, RusotoError<DescribeProductsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_products_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_products_request(&self, input: DescribeProductsRequest) -> SignedRequest {
         let request_uri = "/products";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeProducts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5265,35 +6114,64 @@ impl SecurityHub for SecurityHubClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_standards_controls`, also returning the metadata of the response.
+    pub async fn describe_standards_controls_with_metadata(
+        &self,
+        input: DescribeStandardsControlsRequest,
+    ) -> Result<
+        (DescribeStandardsControlsResponse, ResponseMetadata),
+        RusotoError<DescribeStandardsControlsError>,
+    > {
+        let request = self.build_describe_standards_controls_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProductsResponse, _>()?;
+                .deserialize::<DescribeStandardsControlsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProductsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeStandardsControlsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of compliance standards controls.</p> <p>For each control, the results include information about whether it is currently enabled, the severity, and a link to remediation information.</p>
-    async fn describe_standards_controls(
+    /// Presigns the request `describe_standards_controls` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_standards_controls(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStandardsControlsRequest,
-    ) -> Result<DescribeStandardsControlsResponse, RusotoError<DescribeStandardsControlsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_standards_controls_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_standards_controls_request(
+        &self,
+        input: DescribeStandardsControlsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/standards/controls/{standards_subscription_arn}",
             standards_subscription_arn = input.standards_subscription_arn
         );
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeStandardsControls");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5305,447 +6183,833 @@ impl SecurityHub for SecurityHubClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeStandardsControlsResponse, _>()?;
-
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStandardsControlsError::from_response(response))
-        }
+        request
     }
 
-    /// <p>Disables the integration of the specified product with Security Hub. Findings from that product are no longer sent to Security Hub after the integration is disabled.</p>
-    async fn disable_import_findings_for_product(
+    /// Calls `disable_import_findings_for_product`, also returning the metadata of the response.
+    pub async fn disable_import_findings_for_product_with_metadata(
         &self,
         input: DisableImportFindingsForProductRequest,
     ) -> Result<
-        DisableImportFindingsForProductResponse,
+        (DisableImportFindingsForProductResponse, ResponseMetadata),
         RusotoError<DisableImportFindingsForProductError>,
     > {
-        let request_uri = format!(
-            "/productSubscriptions/{product_subscription_arn}",
-            product_subscription_arn = input.product_subscription_arn
-        );
-
-        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        let request = self.build_disable_import_findings_for_product_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisableImportFindingsForProductResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DisableImportFindingsForProductError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Disables Security Hub in your account only in the current Region. To disable Security Hub in all Regions, you must submit one request per Region where you have enabled Security Hub. When you disable Security Hub for a master account, it doesn't disable Security Hub for any associated member accounts.</p> <p>When you disable Security Hub, your existing findings and insights and any Security Hub configuration settings are deleted after 90 days and can't be recovered. Any standards that were enabled are disabled, and your master and member account associations are removed. If you want to save your existing findings, you must export them before you disable Security Hub.</p>
-    async fn disable_security_hub(
+    /// Presigns the request `disable_import_findings_for_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_import_findings_for_product(
         &self,
-    ) -> Result<DisableSecurityHubResponse, RusotoError<DisableSecurityHubError>> {
-        let request_uri = "/accounts";
+        presigner: &rusoto_core::Presigner,
+        input: DisableImportFindingsForProductRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_import_findings_for_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_import_findings_for_product_request(
+        &self,
+        input: DisableImportFindingsForProductRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/productSubscriptions/{product_subscription_arn}",
+            product_subscription_arn = input.product_subscription_arn
+        );
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisableImportFindingsForProduct");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `disable_security_hub`, also returning the metadata of the response.
+    pub async fn disable_security_hub_with_metadata(
+        &self,
+    ) -> Result<(DisableSecurityHubResponse, ResponseMetadata), RusotoError<DisableSecurityHubError>>
+    {
+        let request = self.build_disable_security_hub_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisableSecurityHubResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DisableSecurityHubError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the current Security Hub member account from the associated master account.</p>
-    async fn disassociate_from_master_account(
+    /// Presigns the request `disable_security_hub` would send with `presigner` instead of sending it.
+    pub async fn presign_disable_security_hub(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_security_hub_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_security_hub_request(&self) -> SignedRequest {
+        let request_uri = "/accounts";
+
+        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisableSecurityHub");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request
+    }
+
+    /// Calls `disassociate_from_master_account`, also returning the metadata of the response.
+    pub async fn disassociate_from_master_account_with_metadata(
         &self,
     ) -> Result<
-        DisassociateFromMasterAccountResponse,
+        (DisassociateFromMasterAccountResponse, ResponseMetadata),
         RusotoError<DisassociateFromMasterAccountError>,
     > {
-        let request_uri = "/master/disassociate";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        let request = self.build_disassociate_from_master_account_request();
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateFromMasterAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DisassociateFromMasterAccountError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified member accounts from the associated master account.</p>
-    async fn disassociate_members(
+    /// Presigns the request `disassociate_from_master_account` would send with `presigner` instead of sending it.
+    pub async fn presign_disassociate_from_master_account(
         &self,
-        input: DisassociateMembersRequest,
-    ) -> Result<DisassociateMembersResponse, RusotoError<DisassociateMembersError>> {
-        let request_uri = "/members/disassociate";
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_from_master_account_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_from_master_account_request(&self) -> SignedRequest {
+        let request_uri = "/master/disassociate";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisassociateFromMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+        request
+    }
+
+    /// Calls `disassociate_members`, also returning the metadata of the response.
+    pub async fn disassociate_members_with_metadata(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> Result<
+        (DisassociateMembersResponse, ResponseMetadata),
+        RusotoError<DisassociateMembersError>,
+    > {
+        let request = self.build_disassociate_members_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisassociateMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DisassociateMembersError::from_response(response))
         }
     }
 
-    /// <p>Enables the integration of a partner product with Security Hub. Integrated products send findings to Security Hub. When you enable a product integration, a permission policy that grants permission for the product to send findings to Security Hub is applied.</p>
-    async fn enable_import_findings_for_product(
+    /// Presigns the request `disassociate_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_members(
         &self,
-        input: EnableImportFindingsForProductRequest,
-    ) -> Result<
-        EnableImportFindingsForProductResponse,
-        RusotoError<EnableImportFindingsForProductError>,
-    > {
-        let request_uri = "/productSubscriptions";
+        presigner: &rusoto_core::Presigner,
+        input: DisassociateMembersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_members_request(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> SignedRequest {
+        let request_uri = "/members/disassociate";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisassociateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `enable_import_findings_for_product`, also returning the metadata of the response.
+    pub async fn enable_import_findings_for_product_with_metadata(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> Result<
+        (EnableImportFindingsForProductResponse, ResponseMetadata),
+        RusotoError<EnableImportFindingsForProductError>,
+    > {
+        let request = self.build_enable_import_findings_for_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<EnableImportFindingsForProductResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(EnableImportFindingsForProductError::from_response(response))
         }
     }
 
-    /// <p>Enables Security Hub for your account in the current Region or the Region you specify in the request. Enabling Security Hub also enables the CIS AWS Foundations standard. When you enable Security Hub, you grant to Security Hub the permissions necessary to gather findings from AWS Config, Amazon GuardDuty, Amazon Inspector, and Amazon Macie. To learn more, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-settingup.html">Setting Up AWS Security Hub</a>.</p>
-    async fn enable_security_hub(
+    /// Presigns the request `enable_import_findings_for_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_import_findings_for_product(
         &self,
-        input: EnableSecurityHubRequest,
-    ) -> Result<EnableSecurityHubResponse, RusotoError<EnableSecurityHubError>> {
-        let request_uri = "/accounts";
+        presigner: &rusoto_core::Presigner,
+        input: EnableImportFindingsForProductRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_import_findings_for_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_import_findings_for_product_request(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> SignedRequest {
+        let request_uri = "/productSubscriptions";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("EnableImportFindingsForProduct");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `enable_security_hub`, also returning the metadata of the response.
+    pub async fn enable_security_hub_with_metadata(
+        &self,
+        input: EnableSecurityHubRequest,
+    ) -> Result<(EnableSecurityHubResponse, ResponseMetadata), RusotoError<EnableSecurityHubError>>
+    {
+        let request = self.build_enable_security_hub_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<EnableSecurityHubResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(EnableSecurityHubError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the standards that are currently enabled.</p>
-    async fn get_enabled_standards(
+    /// Presigns the request `enable_security_hub` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_security_hub(
         &self,
-        input: GetEnabledStandardsRequest,
-    ) -> Result<GetEnabledStandardsResponse, RusotoError<GetEnabledStandardsError>> {
-        let request_uri = "/standards/get";
+        presigner: &rusoto_core::Presigner,
+        input: EnableSecurityHubRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_security_hub_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_security_hub_request(&self, input: EnableSecurityHubRequest) -> SignedRequest {
+        let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("EnableSecurityHub");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_enabled_standards`, also returning the metadata of the response.
+    pub async fn get_enabled_standards_with_metadata(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> Result<
+        (GetEnabledStandardsResponse, ResponseMetadata),
+        RusotoError<GetEnabledStandardsError>,
+    > {
+        let request = self.build_get_enabled_standards_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetEnabledStandardsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetEnabledStandardsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of findings that match the specified criteria.</p>
-    async fn get_findings(
+    /// Presigns the request `get_enabled_standards` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_enabled_standards(
         &self,
-        input: GetFindingsRequest,
-    ) -> Result<GetFindingsResponse, RusotoError<GetFindingsError>> {
-        let request_uri = "/findings";
+        presigner: &rusoto_core::Presigner,
+        input: GetEnabledStandardsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_enabled_standards_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_enabled_standards_request(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/standards/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetEnabledStandards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `get_findings`, also returning the metadata of the response.
+    pub async fn get_findings_with_metadata(
+        &self,
+        input: GetFindingsRequest,
+    ) -> Result<(GetFindingsResponse, ResponseMetadata), RusotoError<GetFindingsError>> {
+        let request = self.build_get_findings_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetFindingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetFindingsError::from_response(response))
         }
     }
 
-    /// <p>Lists the results of the Security Hub insight that the insight ARN specifies.</p>
-    async fn get_insight_results(
+    /// Presigns the request `get_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_findings(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetFindingsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_findings_request(&self, input: GetFindingsRequest) -> SignedRequest {
+        let request_uri = "/findings";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetFindings");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `get_insight_results`, also returning the metadata of the response.
+    pub async fn get_insight_results_with_metadata(
         &self,
         input: GetInsightResultsRequest,
-    ) -> Result<GetInsightResultsResponse, RusotoError<GetInsightResultsError>> {
+    ) -> Result<(GetInsightResultsResponse, ResponseMetadata), RusotoError<GetInsightResultsError>>
+    {
+        let request = self.build_get_insight_results_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInsightResultsResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetInsightResultsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `get_insight_results` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_insight_results(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetInsightResultsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_insight_results_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_insight_results_request(&self, input: GetInsightResultsRequest) -> SignedRequest {
         let request_uri = format!(
             "/insights/results/{insight_arn}",
             insight_arn = input.insight_arn
         );
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInsightResults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_insights`, also returning the metadata of the response.
+    pub async fn get_insights_with_metadata(
+        &self,
+        input: GetInsightsRequest,
+    ) -> Result<(GetInsightsResponse, ResponseMetadata), RusotoError<GetInsightsError>> {
+        let request = self.build_get_insights_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInsightResultsResponse, _>()?;
+                .deserialize::<GetInsightsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInsightResultsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetInsightsError::from_response(response))
         }
     }
 
-    /// <p>Lists and describes insights that insight ARNs specify.</p>
-    async fn get_insights(
+    /// Presigns the request `get_insights` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_insights(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInsightsRequest,
-    ) -> Result<GetInsightsResponse, RusotoError<GetInsightsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_insights_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_insights_request(&self, input: GetInsightsRequest) -> SignedRequest {
         let request_uri = "/insights/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInsights");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_invitations_count`, also returning the metadata of the response.
+    pub async fn get_invitations_count_with_metadata(
+        &self,
+    ) -> Result<
+        (GetInvitationsCountResponse, ResponseMetadata),
+        RusotoError<GetInvitationsCountError>,
+    > {
+        let request = self.build_get_invitations_count_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInsightsResponse, _>()?;
+                .deserialize::<GetInvitationsCountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInsightsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetInvitationsCountError::from_response(response))
         }
     }
 
-    /// <p>Returns the count of all Security Hub membership invitations that were sent to the current member account, not including the currently accepted invitation. </p>
-    async fn get_invitations_count(
+    /// Presigns the request `get_invitations_count` would send with `presigner` instead of sending it.
+    pub async fn presign_get_invitations_count(
         &self,
-    ) -> Result<GetInvitationsCountResponse, RusotoError<GetInvitationsCountError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_invitations_count_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_invitations_count_request(&self) -> SignedRequest {
         let request_uri = "/invitations/count";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInvitationsCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_master_account`, also returning the metadata of the response.
+    pub async fn get_master_account_with_metadata(
+        &self,
+    ) -> Result<(GetMasterAccountResponse, ResponseMetadata), RusotoError<GetMasterAccountError>>
+    {
+        let request = self.build_get_master_account_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInvitationsCountResponse, _>()?;
+                .deserialize::<GetMasterAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInvitationsCountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMasterAccountError::from_response(response))
         }
     }
 
-    /// <p>Provides the details for the Security Hub master account to the current member account. </p>
-    async fn get_master_account(
+    /// Presigns the request `get_master_account` would send with `presigner` instead of sending it.
+    pub async fn presign_get_master_account(
         &self,
-    ) -> Result<GetMasterAccountResponse, RusotoError<GetMasterAccountError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_master_account_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_master_account_request(&self) -> SignedRequest {
         let request_uri = "/master";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_members`, also returning the metadata of the response.
+    pub async fn get_members_with_metadata(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<(GetMembersResponse, ResponseMetadata), RusotoError<GetMembersError>> {
+        let request = self.build_get_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMasterAccountResponse, _>()?;
+                .deserialize::<GetMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMasterAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMembersError::from_response(response))
         }
     }
 
-    /// <p>Returns the details on the Security Hub member accounts that the account IDs specify.</p>
-    async fn get_members(
+    /// Presigns the request `get_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMembersRequest,
-    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_members_request(&self, input: GetMembersRequest) -> SignedRequest {
         let request_uri = "/members/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `invite_members`, also returning the metadata of the response.
+    pub async fn invite_members_with_metadata(
+        &self,
+        input: InviteMembersRequest,
+    ) -> Result<(InviteMembersResponse, ResponseMetadata), RusotoError<InviteMembersError>> {
+        let request = self.build_invite_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMembersResponse, _>()?;
+                .deserialize::<InviteMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(InviteMembersError::from_response(response))
         }
     }
 
-    /// <p>Invites other AWS accounts to become member accounts for the Security Hub master account that the invitation is sent from. Before you can use this action to invite a member, you must first create the member account in Security Hub by using the <a>CreateMembers</a> action. When the account owner accepts the invitation to become a member account and enables Security Hub, the master account can view the findings generated from member account.</p>
-    async fn invite_members(
+    /// Presigns the request `invite_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_invite_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InviteMembersRequest,
-    ) -> Result<InviteMembersResponse, RusotoError<InviteMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_invite_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_invite_members_request(&self, input: InviteMembersRequest) -> SignedRequest {
         let request_uri = "/members/invite";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("InviteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_enabled_products_for_import`, also returning the metadata of the response.
+    pub async fn list_enabled_products_for_import_with_metadata(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> Result<
+        (ListEnabledProductsForImportResponse, ResponseMetadata),
+        RusotoError<ListEnabledProductsForImportError>,
+    > {
+        let request = self.build_list_enabled_products_for_import_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<InviteMembersResponse, _>()?;
+                .deserialize::<ListEnabledProductsForImportResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(InviteMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListEnabledProductsForImportError::from_response(response))
         }
     }
 
-    /// <p>Lists all findings-generating solutions (products) whose findings you have subscribed to receive in Security Hub.</p>
-    async fn list_enabled_products_for_import(
+    /// Presigns the request `list_enabled_products_for_import` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_enabled_products_for_import(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEnabledProductsForImportRequest,
-    ) -> Result<ListEnabledProductsForImportResponse, RusotoError<ListEnabledProductsForImportError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_enabled_products_for_import_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_enabled_products_for_import_request(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> SignedRequest {
         let request_uri = "/productSubscriptions";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListEnabledProductsForImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5757,31 +7021,56 @@ impl SecurityHub for SecurityHubClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_invitations`, also returning the metadata of the response.
+    pub async fn list_invitations_with_metadata(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<(ListInvitationsResponse, ResponseMetadata), RusotoError<ListInvitationsError>>
+    {
+        let request = self.build_list_invitations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEnabledProductsForImportResponse, _>()?;
+                .deserialize::<ListInvitationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEnabledProductsForImportError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListInvitationsError::from_response(response))
         }
     }
 
-    /// <p>Lists all Security Hub membership invitations that were sent to the current AWS account. </p>
-    async fn list_invitations(
+    /// Presigns the request `list_invitations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_invitations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInvitationsRequest,
-    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_invitations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_invitations_request(&self, input: ListInvitationsRequest) -> SignedRequest {
         let request_uri = "/invitations";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5793,31 +7082,55 @@ impl SecurityHub for SecurityHubClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_members`, also returning the metadata of the response.
+    pub async fn list_members_with_metadata(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<(ListMembersResponse, ResponseMetadata), RusotoError<ListMembersError>> {
+        let request = self.build_list_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInvitationsResponse, _>()?;
+                .deserialize::<ListMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInvitationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListMembersError::from_response(response))
         }
     }
 
-    /// <p>Lists details about all member accounts for the current Security Hub master account.</p>
-    async fn list_members(
+    /// Presigns the request `list_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMembersRequest,
-    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_members_request(&self, input: ListMembersRequest) -> SignedRequest {
         let request_uri = "/members";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5832,88 +7145,166 @@ impl SecurityHub for SecurityHubClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMembersResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMembersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of tags associated with a resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5922,146 +7313,986 @@ impl SecurityHub for SecurityHubClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_action_target`, also returning the metadata of the response.
+    pub async fn update_action_target_with_metadata(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> Result<(UpdateActionTargetResponse, ResponseMetadata), RusotoError<UpdateActionTargetError>>
+    {
+        let request = self.build_update_action_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateActionTargetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateActionTargetError::from_response(response))
         }
     }
 
-    /// <p>Updates the name and description of a custom action target in Security Hub.</p>
-    async fn update_action_target(
+    /// Presigns the request `update_action_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_action_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateActionTargetRequest,
-    ) -> Result<UpdateActionTargetResponse, RusotoError<UpdateActionTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_action_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_action_target_request(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/actionTargets/{action_target_arn}",
             action_target_arn = input.action_target_arn
         );
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateActionTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_findings`, also returning the metadata of the response.
+    pub async fn update_findings_with_metadata(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> Result<(UpdateFindingsResponse, ResponseMetadata), RusotoError<UpdateFindingsError>> {
+        let request = self.build_update_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateActionTargetResponse, _>()?;
+                .deserialize::<UpdateFindingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateActionTargetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFindingsError::from_response(response))
         }
     }
 
-    /// <p>Updates the <code>Note</code> and <code>RecordState</code> of the Security Hub-aggregated findings that the filter attributes specify. Any member account that can view the finding also sees the update to the finding.</p>
-    async fn update_findings(
+    /// Presigns the request `update_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFindingsRequest,
-    ) -> Result<UpdateFindingsResponse, RusotoError<UpdateFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_findings_request(&self, input: UpdateFindingsRequest) -> SignedRequest {
         let request_uri = "/findings";
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_insight`, also returning the metadata of the response.
+    pub async fn update_insight_with_metadata(
+        &self,
+        input: UpdateInsightRequest,
+    ) -> Result<(UpdateInsightResponse, ResponseMetadata), RusotoError<UpdateInsightError>> {
+        let request = self.build_update_insight_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFindingsResponse, _>()?;
+                .deserialize::<UpdateInsightResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFindingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateInsightError::from_response(response))
         }
     }
 
-    /// <p>Updates the Security Hub insight that the insight ARN specifies.</p>
-    async fn update_insight(
+    /// Presigns the request `update_insight` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_insight(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateInsightRequest,
-    ) -> Result<UpdateInsightResponse, RusotoError<UpdateInsightError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_insight_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_insight_request(&self, input: UpdateInsightRequest) -> SignedRequest {
         let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateInsight");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `update_standards_control`, also returning the metadata of the response.
+    pub async fn update_standards_control_with_metadata(
+        &self,
+        input: UpdateStandardsControlRequest,
+    ) -> Result<
+        (UpdateStandardsControlResponse, ResponseMetadata),
+        RusotoError<UpdateStandardsControlError>,
+    > {
+        let request = self.build_update_standards_control_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateInsightResponse, _>()?;
+                .deserialize::<UpdateStandardsControlResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateInsightError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateStandardsControlError::from_response(response))
         }
     }
 
-    /// <p>Used to control whether an individual compliance standard control is enabled or disabled.</p>
-    async fn update_standards_control(
+    /// Presigns the request `update_standards_control` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_standards_control(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateStandardsControlRequest,
-    ) -> Result<UpdateStandardsControlResponse, RusotoError<UpdateStandardsControlError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_standards_control_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_standards_control_request(
+        &self,
+        input: UpdateStandardsControlRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/standards/control/{standards_control_arn}",
             standards_control_arn = input.standards_control_arn
         );
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateStandardsControl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeActionTargets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_action_targets_pages(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeActionTargetsResponse, RusotoError<DescribeActionTargetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input| async move {
+                client.describe_action_targets(input).await
+            },
+            |input: &DescribeActionTargetsRequest, output: &DescribeActionTargetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeActionTargetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ActionTargets` of every page of `DescribeActionTargets` results for `input`, as `describe_action_targets_pages` requests them.
+    pub fn describe_action_targets_stream(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> impl futures::Stream<Item = Result<ActionTarget, RusotoError<DescribeActionTargetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_action_targets_pages(input), |page| {
+            page.action_targets
+        })
+    }
+
+    /// The pages of `DescribeProducts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_products_pages(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeProductsResponse, RusotoError<DescribeProductsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input| async move { client.describe_products(input).await },
+            |input: &DescribeProductsRequest, output: &DescribeProductsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeProductsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Products` of every page of `DescribeProducts` results for `input`, as `describe_products_pages` requests them.
+    pub fn describe_products_stream(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> impl futures::Stream<Item = Result<Product, RusotoError<DescribeProductsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_products_pages(input), |page| page.products)
+    }
+
+    /// The pages of `DescribeStandardsControls` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_standards_controls_pages(
+        &self,
+        input: DescribeStandardsControlsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeStandardsControlsResponse,
+            RusotoError<DescribeStandardsControlsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input| async move {
+                client.describe_standards_controls(input).await
+            },
+            |input: &DescribeStandardsControlsRequest,
+             output: &DescribeStandardsControlsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeStandardsControlsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Controls` of every page of `DescribeStandardsControls` results for `input`, as `describe_standards_controls_pages` requests them.
+    pub fn describe_standards_controls_stream(
+        &self,
+        input: DescribeStandardsControlsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<StandardsControl, RusotoError<DescribeStandardsControlsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_standards_controls_pages(input), |page| {
+            page.controls.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetEnabledStandards` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_enabled_standards_pages(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetEnabledStandardsResponse, RusotoError<GetEnabledStandardsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input| async move { client.get_enabled_standards(input).await },
+            |input: &GetEnabledStandardsRequest, output: &GetEnabledStandardsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetEnabledStandardsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `StandardsSubscriptions` of every page of `GetEnabledStandards` results for `input`, as `get_enabled_standards_pages` requests them.
+    pub fn get_enabled_standards_stream(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<StandardsSubscription, RusotoError<GetEnabledStandardsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_enabled_standards_pages(input), |page| {
+            page.standards_subscriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetFindings` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_findings_pages(
+        &self,
+        input: GetFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<GetFindingsResponse, RusotoError<GetFindingsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input| async move { client.get_findings(input).await },
+            |input: &GetFindingsRequest, output: &GetFindingsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetFindingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Findings` of every page of `GetFindings` results for `input`, as `get_findings_pages` requests them.
+    pub fn get_findings_stream(
+        &self,
+        input: GetFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<AwsSecurityFinding, RusotoError<GetFindingsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_findings_pages(input), |page| page.findings)
+    }
+
+    /// The pages of `GetInsights` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_insights_pages(
+        &self,
+        input: GetInsightsRequest,
+    ) -> impl futures::Stream<Item = Result<GetInsightsResponse, RusotoError<GetInsightsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input| async move { client.get_insights(input).await },
+            |input: &GetInsightsRequest, output: &GetInsightsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetInsightsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Insights` of every page of `GetInsights` results for `input`, as `get_insights_pages` requests them.
+    pub fn get_insights_stream(
+        &self,
+        input: GetInsightsRequest,
+    ) -> impl futures::Stream<Item = Result<Insight, RusotoError<GetInsightsError>>> + Send {
+        rusoto_core::pagination::items(self.get_insights_pages(input), |page| page.insights)
+    }
+
+    /// The pages of `ListEnabledProductsForImport` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_enabled_products_for_import_pages(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListEnabledProductsForImportResponse,
+            RusotoError<ListEnabledProductsForImportError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input| async move {
+                client.list_enabled_products_for_import(input).await
+            },
+            |input: &ListEnabledProductsForImportRequest,
+             output: &ListEnabledProductsForImportResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEnabledProductsForImportRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProductSubscriptions` of every page of `ListEnabledProductsForImport` results for `input`, as `list_enabled_products_for_import_pages` requests them.
+    pub fn list_enabled_products_for_import_stream(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListEnabledProductsForImportError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_enabled_products_for_import_pages(input), |page| {
+            page.product_subscriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListInvitations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_invitations_pages(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListInvitationsResponse, RusotoError<ListInvitationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input| async move { client.list_invitations(input).await },
+            |input: &ListInvitationsRequest, output: &ListInvitationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListInvitationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Invitations` of every page of `ListInvitations` results for `input`, as `list_invitations_pages` requests them.
+    pub fn list_invitations_stream(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> impl futures::Stream<Item = Result<Invitation, RusotoError<ListInvitationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_invitations_pages(input), |page| {
+            page.invitations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListMembers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_members_pages(
+        &self,
+        input: ListMembersRequest,
+    ) -> impl futures::Stream<Item = Result<ListMembersResponse, RusotoError<ListMembersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SecurityHubClient, input| async move { client.list_members(input).await },
+            |input: &ListMembersRequest, output: &ListMembersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMembersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Members` of every page of `ListMembers` results for `input`, as `list_members_pages` requests them.
+    pub fn list_members_stream(
+        &self,
+        input: ListMembersRequest,
+    ) -> impl futures::Stream<Item = Result<Member, RusotoError<ListMembersError>>> + Send {
+        rusoto_core::pagination::items(self.list_members_pages(input), |page| {
+            page.members.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl SecurityHub for SecurityHubClient {
+    /// <p>Accepts the invitation to be a member account and be monitored by the Security Hub master account that the invitation was sent from. When the member account accepts the invitation, permission is granted to the master account to view findings generated in the member account.</p>
+    async fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> Result<AcceptInvitationResponse, RusotoError<AcceptInvitationError>> {
+        self.accept_invitation_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateStandardsControlResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateStandardsControlError::from_response(response))
-        }
+    /// <p>Disables the standards specified by the provided <code>StandardsSubscriptionArns</code>. For more information, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Standards Supported in AWS Security Hub</a>.</p>
+    async fn batch_disable_standards(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> Result<BatchDisableStandardsResponse, RusotoError<BatchDisableStandardsError>> {
+        self.batch_disable_standards_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the standards specified by the provided <code>standardsArn</code>. In this release, only CIS AWS Foundations standards are supported. For more information, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Standards Supported in AWS Security Hub</a>.</p>
+    async fn batch_enable_standards(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> Result<BatchEnableStandardsResponse, RusotoError<BatchEnableStandardsError>> {
+        self.batch_enable_standards_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Imports security findings generated from an integrated third-party product into Security Hub. This action is requested by the integrated product to import its findings into Security Hub. The maximum allowed size for a finding is 240 Kb. An error is returned for any finding larger than 240 Kb.</p>
+    async fn batch_import_findings(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> Result<BatchImportFindingsResponse, RusotoError<BatchImportFindingsError>> {
+        self.batch_import_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a custom action target in Security Hub. You can use custom actions on findings and insights in Security Hub to trigger target actions in Amazon CloudWatch Events.</p>
+    async fn create_action_target(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> Result<CreateActionTargetResponse, RusotoError<CreateActionTargetError>> {
+        self.create_action_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a custom insight in Security Hub. An insight is a consolidation of findings that relate to a security issue that requires attention or remediation. Use the <code>GroupByAttribute</code> to group the related findings in the insight.</p>
+    async fn create_insight(
+        &self,
+        input: CreateInsightRequest,
+    ) -> Result<CreateInsightResponse, RusotoError<CreateInsightError>> {
+        self.create_insight_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a member association in Security Hub between the specified accounts and the account used to make the request, which is the master account. To successfully create a member, you must use this action from an account that already has Security Hub enabled. You can use the <a>EnableSecurityHub</a> to enable Security Hub.</p> <p>After you use <code>CreateMembers</code> to create member account associations in Security Hub, you need to use the <a>InviteMembers</a> action, which invites the accounts to enable Security Hub and become member accounts in Security Hub. If the invitation is accepted by the account owner, the account becomes a member account in Security Hub, and a permission policy is added that permits the master account to view the findings generated in the member account. When Security Hub is enabled in the invited account, findings start being sent to both the member and master accounts.</p> <p>You can remove the association between the master and member accounts by using the <a>DisassociateFromMasterAccount</a> or <a>DisassociateMembers</a> operation.</p>
+    async fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+        self.create_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Declines invitations to become a member account.</p>
+    async fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> Result<DeclineInvitationsResponse, RusotoError<DeclineInvitationsError>> {
+        self.decline_invitations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a custom action target from Security Hub. Deleting a custom action target doesn't affect any findings or insights that were already sent to Amazon CloudWatch Events using the custom action.</p>
+    async fn delete_action_target(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> Result<DeleteActionTargetResponse, RusotoError<DeleteActionTargetError>> {
+        self.delete_action_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the insight specified by the <code>InsightArn</code>.</p>
+    async fn delete_insight(
+        &self,
+        input: DeleteInsightRequest,
+    ) -> Result<DeleteInsightResponse, RusotoError<DeleteInsightError>> {
+        self.delete_insight_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes invitations received by the AWS account to become a member account.</p>
+    async fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> Result<DeleteInvitationsResponse, RusotoError<DeleteInvitationsError>> {
+        self.delete_invitations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified member accounts from Security Hub.</p>
+    async fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+        self.delete_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the custom action targets in Security Hub in your account.</p>
+    async fn describe_action_targets(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> Result<DescribeActionTargetsResponse, RusotoError<DescribeActionTargetsError>> {
+        self.describe_action_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns details about the Hub resource in your account, including the <code>HubArn</code> and the time when you enabled Security Hub.</p>
+    async fn describe_hub(
+        &self,
+        input: DescribeHubRequest,
+    ) -> Result<DescribeHubResponse, RusotoError<DescribeHubError>> {
+        self.describe_hub_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the products available that you can subscribe to and integrate with Security Hub to consolidate findings.</p>
+    async fn describe_products(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> Result<DescribeProductsResponse, RusotoError<DescribeProductsError>> {
+        self.describe_products_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of compliance standards controls.</p> <p>For each control, the results include information about whether it is currently enabled, the severity, and a link to remediation information.</p>
+    async fn describe_standards_controls(
+        &self,
+        input: DescribeStandardsControlsRequest,
+    ) -> Result<DescribeStandardsControlsResponse, RusotoError<DescribeStandardsControlsError>>
+    {
+        self.describe_standards_controls_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the integration of the specified product with Security Hub. Findings from that product are no longer sent to Security Hub after the integration is disabled.</p>
+    async fn disable_import_findings_for_product(
+        &self,
+        input: DisableImportFindingsForProductRequest,
+    ) -> Result<
+        DisableImportFindingsForProductResponse,
+        RusotoError<DisableImportFindingsForProductError>,
+    > {
+        self.disable_import_findings_for_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables Security Hub in your account only in the current Region. To disable Security Hub in all Regions, you must submit one request per Region where you have enabled Security Hub. When you disable Security Hub for a master account, it doesn't disable Security Hub for any associated member accounts.</p> <p>When you disable Security Hub, your existing findings and insights and any Security Hub configuration settings are deleted after 90 days and can't be recovered. Any standards that were enabled are disabled, and your master and member account associations are removed. If you want to save your existing findings, you must export them before you disable Security Hub.</p>
+    async fn disable_security_hub(
+        &self,
+    ) -> Result<DisableSecurityHubResponse, RusotoError<DisableSecurityHubError>> {
+        self.disable_security_hub_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the current Security Hub member account from the associated master account.</p>
+    async fn disassociate_from_master_account(
+        &self,
+    ) -> Result<
+        DisassociateFromMasterAccountResponse,
+        RusotoError<DisassociateFromMasterAccountError>,
+    > {
+        self.disassociate_from_master_account_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified member accounts from the associated master account.</p>
+    async fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> Result<DisassociateMembersResponse, RusotoError<DisassociateMembersError>> {
+        self.disassociate_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the integration of a partner product with Security Hub. Integrated products send findings to Security Hub. When you enable a product integration, a permission policy that grants permission for the product to send findings to Security Hub is applied.</p>
+    async fn enable_import_findings_for_product(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> Result<
+        EnableImportFindingsForProductResponse,
+        RusotoError<EnableImportFindingsForProductError>,
+    > {
+        self.enable_import_findings_for_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables Security Hub for your account in the current Region or the Region you specify in the request. Enabling Security Hub also enables the CIS AWS Foundations standard. When you enable Security Hub, you grant to Security Hub the permissions necessary to gather findings from AWS Config, Amazon GuardDuty, Amazon Inspector, and Amazon Macie. To learn more, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-settingup.html">Setting Up AWS Security Hub</a>.</p>
+    async fn enable_security_hub(
+        &self,
+        input: EnableSecurityHubRequest,
+    ) -> Result<EnableSecurityHubResponse, RusotoError<EnableSecurityHubError>> {
+        self.enable_security_hub_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the standards that are currently enabled.</p>
+    async fn get_enabled_standards(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> Result<GetEnabledStandardsResponse, RusotoError<GetEnabledStandardsError>> {
+        self.get_enabled_standards_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of findings that match the specified criteria.</p>
+    async fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> Result<GetFindingsResponse, RusotoError<GetFindingsError>> {
+        self.get_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the results of the Security Hub insight that the insight ARN specifies.</p>
+    async fn get_insight_results(
+        &self,
+        input: GetInsightResultsRequest,
+    ) -> Result<GetInsightResultsResponse, RusotoError<GetInsightResultsError>> {
+        self.get_insight_results_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists and describes insights that insight ARNs specify.</p>
+    async fn get_insights(
+        &self,
+        input: GetInsightsRequest,
+    ) -> Result<GetInsightsResponse, RusotoError<GetInsightsError>> {
+        self.get_insights_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the count of all Security Hub membership invitations that were sent to the current member account, not including the currently accepted invitation. </p>
+    async fn get_invitations_count(
+        &self,
+    ) -> Result<GetInvitationsCountResponse, RusotoError<GetInvitationsCountError>> {
+        self.get_invitations_count_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides the details for the Security Hub master account to the current member account. </p>
+    async fn get_master_account(
+        &self,
+    ) -> Result<GetMasterAccountResponse, RusotoError<GetMasterAccountError>> {
+        self.get_master_account_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details on the Security Hub member accounts that the account IDs specify.</p>
+    async fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+        self.get_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Invites other AWS accounts to become member accounts for the Security Hub master account that the invitation is sent from. Before you can use this action to invite a member, you must first create the member account in Security Hub by using the <a>CreateMembers</a> action. When the account owner accepts the invitation to become a member account and enables Security Hub, the master account can view the findings generated from member account.</p>
+    async fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> Result<InviteMembersResponse, RusotoError<InviteMembersError>> {
+        self.invite_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all findings-generating solutions (products) whose findings you have subscribed to receive in Security Hub.</p>
+    async fn list_enabled_products_for_import(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> Result<ListEnabledProductsForImportResponse, RusotoError<ListEnabledProductsForImportError>>
+    {
+        self.list_enabled_products_for_import_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all Security Hub membership invitations that were sent to the current AWS account. </p>
+    async fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+        self.list_invitations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists details about all member accounts for the current Security Hub master account.</p>
+    async fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+        self.list_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of tags associated with a resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the name and description of a custom action target in Security Hub.</p>
+    async fn update_action_target(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> Result<UpdateActionTargetResponse, RusotoError<UpdateActionTargetError>> {
+        self.update_action_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the <code>Note</code> and <code>RecordState</code> of the Security Hub-aggregated findings that the filter attributes specify. Any member account that can view the finding also sees the update to the finding.</p>
+    async fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> Result<UpdateFindingsResponse, RusotoError<UpdateFindingsError>> {
+        self.update_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the Security Hub insight that the insight ARN specifies.</p>
+    async fn update_insight(
+        &self,
+        input: UpdateInsightRequest,
+    ) -> Result<UpdateInsightResponse, RusotoError<UpdateInsightError>> {
+        self.update_insight_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used to control whether an individual compliance standard control is enabled or disabled.</p>
+    async fn update_standards_control(
+        &self,
+        input: UpdateStandardsControlRequest,
+    ) -> Result<UpdateStandardsControlResponse, RusotoError<UpdateStandardsControlError>> {
+        self.update_standards_control_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1024,6 +1024,7 @@ pub struct VersionSummary {
 
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1035,6 +1036,8 @@ pub enum CreateApplicationError {
     InternalServerError(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationError {
@@ -1042,24 +1045,28 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(CreateApplicationError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApplicationError::Conflict(err.msg))
+                    return RusotoError::service(CreateApplicationError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateApplicationError::Forbidden(err.msg))
+                    return RusotoError::service(CreateApplicationError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateApplicationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApplicationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1074,12 +1081,14 @@ impl fmt::Display for CreateApplicationError {
             CreateApplicationError::Forbidden(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationError {}
 /// Errors returned by CreateApplicationVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationVersionError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1091,6 +1100,8 @@ pub enum CreateApplicationVersionError {
     InternalServerError(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationVersionError {
@@ -1098,26 +1109,37 @@ impl CreateApplicationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApplicationVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApplicationVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationVersionError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateApplicationVersionError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationVersionError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateApplicationVersionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApplicationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationVersionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApplicationVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1132,12 +1154,14 @@ impl fmt::Display for CreateApplicationVersionError {
             CreateApplicationVersionError::Forbidden(ref cause) => write!(f, "{}", cause),
             CreateApplicationVersionError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateApplicationVersionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateApplicationVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationVersionError {}
 /// Errors returned by CreateCloudFormationChangeSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCloudFormationChangeSetError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1147,6 +1171,8 @@ pub enum CreateCloudFormationChangeSetError {
     InternalServerError(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCloudFormationChangeSetError {
@@ -1156,27 +1182,36 @@ impl CreateCloudFormationChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCloudFormationChangeSetError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCloudFormationChangeSetError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateCloudFormationChangeSetError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCloudFormationChangeSetError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCloudFormationChangeSetError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCloudFormationChangeSetError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateCloudFormationChangeSetError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1194,12 +1229,16 @@ impl fmt::Display for CreateCloudFormationChangeSetError {
             CreateCloudFormationChangeSetError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateCloudFormationChangeSetError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateCloudFormationChangeSetError {}
 /// Errors returned by CreateCloudFormationTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCloudFormationTemplateError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1211,6 +1250,8 @@ pub enum CreateCloudFormationTemplateError {
     NotFound(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCloudFormationTemplateError {
@@ -1220,32 +1261,42 @@ impl CreateCloudFormationTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCloudFormationTemplateError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCloudFormationTemplateError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateCloudFormationTemplateError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCloudFormationTemplateError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCloudFormationTemplateError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateCloudFormationTemplateError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCloudFormationTemplateError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCloudFormationTemplateError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateCloudFormationTemplateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1262,12 +1313,14 @@ impl fmt::Display for CreateCloudFormationTemplateError {
             }
             CreateCloudFormationTemplateError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateCloudFormationTemplateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateCloudFormationTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCloudFormationTemplateError {}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1281,6 +1334,8 @@ pub enum DeleteApplicationError {
     NotFound(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationError {
@@ -1288,27 +1343,31 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteApplicationError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteApplicationError::Conflict(err.msg))
+                    return RusotoError::service(DeleteApplicationError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteApplicationError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteApplicationError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteApplicationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::NotFound(err.msg))
+                    return RusotoError::service(DeleteApplicationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApplicationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1324,12 +1383,14 @@ impl fmt::Display for DeleteApplicationError {
             DeleteApplicationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationError {}
 /// Errors returned by GetApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApplicationError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1341,6 +1402,8 @@ pub enum GetApplicationError {
     NotFound(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApplicationError {
@@ -1348,22 +1411,28 @@ impl GetApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(GetApplicationError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetApplicationError::Forbidden(err.msg))
+                    return RusotoError::service(GetApplicationError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetApplicationError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetApplicationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApplicationError::NotFound(err.msg))
+                    return RusotoError::service(GetApplicationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApplicationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetApplicationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1378,12 +1447,14 @@ impl fmt::Display for GetApplicationError {
             GetApplicationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetApplicationError::NotFound(ref cause) => write!(f, "{}", cause),
             GetApplicationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApplicationError {}
 /// Errors returned by GetApplicationPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApplicationPolicyError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1395,6 +1466,8 @@ pub enum GetApplicationPolicyError {
     NotFound(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApplicationPolicyError {
@@ -1402,26 +1475,34 @@ impl GetApplicationPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetApplicationPolicyError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        GetApplicationPolicyError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetApplicationPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::NotFound(err.msg))
+                    return RusotoError::service(GetApplicationPolicyError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetApplicationPolicyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApplicationPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1436,12 +1517,14 @@ impl fmt::Display for GetApplicationPolicyError {
             GetApplicationPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetApplicationPolicyError::NotFound(ref cause) => write!(f, "{}", cause),
             GetApplicationPolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetApplicationPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApplicationPolicyError {}
 /// Errors returned by GetCloudFormationTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCloudFormationTemplateError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1453,6 +1536,8 @@ pub enum GetCloudFormationTemplateError {
     NotFound(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCloudFormationTemplateError {
@@ -1460,28 +1545,39 @@ impl GetCloudFormationTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCloudFormationTemplateError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCloudFormationTemplateError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetCloudFormationTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        GetCloudFormationTemplateError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCloudFormationTemplateError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCloudFormationTemplateError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetCloudFormationTemplateError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCloudFormationTemplateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCloudFormationTemplateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetCloudFormationTemplateError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1498,12 +1594,14 @@ impl fmt::Display for GetCloudFormationTemplateError {
             }
             GetCloudFormationTemplateError::NotFound(ref cause) => write!(f, "{}", cause),
             GetCloudFormationTemplateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetCloudFormationTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCloudFormationTemplateError {}
 /// Errors returned by ListApplicationDependencies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationDependenciesError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1515,6 +1613,8 @@ pub enum ListApplicationDependenciesError {
     NotFound(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationDependenciesError {
@@ -1524,32 +1624,42 @@ impl ListApplicationDependenciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApplicationDependenciesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationDependenciesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListApplicationDependenciesError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationDependenciesError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApplicationDependenciesError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListApplicationDependenciesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationDependenciesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListApplicationDependenciesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationDependenciesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListApplicationDependenciesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1566,12 +1676,14 @@ impl fmt::Display for ListApplicationDependenciesError {
             }
             ListApplicationDependenciesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListApplicationDependenciesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListApplicationDependenciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationDependenciesError {}
 /// Errors returned by ListApplicationVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationVersionsError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1583,6 +1695,8 @@ pub enum ListApplicationVersionsError {
     NotFound(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationVersionsError {
@@ -1590,26 +1704,37 @@ impl ListApplicationVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListApplicationVersionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        ListApplicationVersionsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationVersionsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListApplicationVersionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationVersionsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1624,12 +1749,14 @@ impl fmt::Display for ListApplicationVersionsError {
             ListApplicationVersionsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListApplicationVersionsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListApplicationVersionsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListApplicationVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationVersionsError {}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationsError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1639,6 +1766,8 @@ pub enum ListApplicationsError {
     InternalServerError(String),
     /// <p>The resource (for example, an access policy statement) specified in the request doesn't exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationsError {
@@ -1646,21 +1775,22 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApplicationsError::BadRequest(err.msg))
+                    return RusotoError::service(ListApplicationsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListApplicationsError::Forbidden(err.msg))
+                    return RusotoError::service(ListApplicationsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListApplicationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListApplicationsError::NotFound(err.msg))
+                    return RusotoError::service(ListApplicationsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1674,12 +1804,14 @@ impl fmt::Display for ListApplicationsError {
             ListApplicationsError::Forbidden(ref cause) => write!(f, "{}", cause),
             ListApplicationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListApplicationsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationsError {}
 /// Errors returned by PutApplicationPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutApplicationPolicyError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1691,6 +1823,8 @@ pub enum PutApplicationPolicyError {
     NotFound(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutApplicationPolicyError {
@@ -1698,26 +1832,34 @@ impl PutApplicationPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        PutApplicationPolicyError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        PutApplicationPolicyError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutApplicationPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::NotFound(err.msg))
+                    return RusotoError::service(PutApplicationPolicyError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutApplicationPolicyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutApplicationPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1732,12 +1874,14 @@ impl fmt::Display for PutApplicationPolicyError {
             PutApplicationPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
             PutApplicationPolicyError::NotFound(ref cause) => write!(f, "{}", cause),
             PutApplicationPolicyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            PutApplicationPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutApplicationPolicyError {}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApplicationError {
     /// <p>One of the parameters in the request is invalid.</p>
     BadRequest(String),
@@ -1751,6 +1895,8 @@ pub enum UpdateApplicationError {
     NotFound(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApplicationError {
@@ -1758,27 +1904,31 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateApplicationError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApplicationError::Conflict(err.msg))
+                    return RusotoError::service(UpdateApplicationError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateApplicationError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateApplicationError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateApplicationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::NotFound(err.msg))
+                    return RusotoError::service(UpdateApplicationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApplicationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1794,6 +1944,7 @@ impl fmt::Display for UpdateApplicationError {
             UpdateApplicationError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1896,6 +2047,7 @@ impl ServerlessRepoClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ServerlessRepoClient {
         ServerlessRepoClient {
             client: Client::shared(),
@@ -1921,45 +2073,127 @@ impl ServerlessRepoClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ServerlessRepoClient {
         ServerlessRepoClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ServerlessRepoClient> {
+        rusoto_core::ClientBuilder::new(ServerlessRepoClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ServerlessRepoClient {
+        ServerlessRepoClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ServerlessRepo for ServerlessRepoClient {
-    /// <p>Creates an application, optionally including an AWS SAM file to create the first application version in the same call.</p>
-    async fn create_application(
+impl ServerlessRepoClient {
+    /// Calls `create_application`, also returning the metadata of the response.
+    pub async fn create_application_with_metadata(
         &self,
         input: CreateApplicationRequest,
-    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+    ) -> Result<(CreateApplicationResponse, ResponseMetadata), RusotoError<CreateApplicationError>>
+    {
+        let request = self.build_create_application_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApplicationResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateApplicationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_request(&self, input: CreateApplicationRequest) -> SignedRequest {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_application_version`, also returning the metadata of the response.
+    pub async fn create_application_version_with_metadata(
+        &self,
+        input: CreateApplicationVersionRequest,
+    ) -> Result<
+        (CreateApplicationVersionResponse, ResponseMetadata),
+        RusotoError<CreateApplicationVersionError>,
+    > {
+        let request = self.build_create_application_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApplicationResponse, _>()?;
+                .deserialize::<CreateApplicationVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApplicationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateApplicationVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates an application version.</p>
-    async fn create_application_version(
+    /// Presigns the request `create_application_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateApplicationVersionRequest,
-    ) -> Result<CreateApplicationVersionResponse, RusotoError<CreateApplicationVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_version_request(
+        &self,
+        input: CreateApplicationVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/versions/{semantic_version}",
             application_id = input.application_id,
@@ -1967,103 +2201,182 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_cloud_formation_change_set`, also returning the metadata of the response.
+    pub async fn create_cloud_formation_change_set_with_metadata(
+        &self,
+        input: CreateCloudFormationChangeSetRequest,
+    ) -> Result<
+        (CreateCloudFormationChangeSetResponse, ResponseMetadata),
+        RusotoError<CreateCloudFormationChangeSetError>,
+    > {
+        let request = self.build_create_cloud_formation_change_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApplicationVersionResponse, _>()?;
+                .deserialize::<CreateCloudFormationChangeSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApplicationVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateCloudFormationChangeSetError::from_response(response))
         }
     }
 
-    /// <p>Creates an AWS CloudFormation change set for the given application.</p>
-    async fn create_cloud_formation_change_set(
+    /// Presigns the request `create_cloud_formation_change_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cloud_formation_change_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCloudFormationChangeSetRequest,
-    ) -> Result<
-        CreateCloudFormationChangeSetResponse,
-        RusotoError<CreateCloudFormationChangeSetError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cloud_formation_change_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cloud_formation_change_set_request(
+        &self,
+        input: CreateCloudFormationChangeSetRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/changesets",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateCloudFormationChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_cloud_formation_template`, also returning the metadata of the response.
+    pub async fn create_cloud_formation_template_with_metadata(
+        &self,
+        input: CreateCloudFormationTemplateRequest,
+    ) -> Result<
+        (CreateCloudFormationTemplateResponse, ResponseMetadata),
+        RusotoError<CreateCloudFormationTemplateError>,
+    > {
+        let request = self.build_create_cloud_formation_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCloudFormationChangeSetResponse, _>()?;
+                .deserialize::<CreateCloudFormationTemplateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCloudFormationChangeSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateCloudFormationTemplateError::from_response(response))
         }
     }
 
-    /// <p>Creates an AWS CloudFormation template.</p>
-    async fn create_cloud_formation_template(
+    /// Presigns the request `create_cloud_formation_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cloud_formation_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCloudFormationTemplateRequest,
-    ) -> Result<CreateCloudFormationTemplateResponse, RusotoError<CreateCloudFormationTemplateError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cloud_formation_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cloud_formation_template_request(
+        &self,
+        input: CreateCloudFormationTemplateRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/templates",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateCloudFormationTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_application`, also returning the metadata of the response.
+    pub async fn delete_application_with_metadata(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteApplicationError>> {
+        let request = self.build_delete_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCloudFormationTemplateResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCloudFormationTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteApplicationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified application.</p>
-    async fn delete_application(
+    /// Presigns the request `delete_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationRequest,
-    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_request(&self, input: DeleteApplicationRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}",
             application_id = input.application_id
@@ -2071,35 +2384,61 @@ impl ServerlessRepo for ServerlessRepoClient {
 
         let mut request =
             SignedRequest::new("DELETE", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("DeleteApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_application`, also returning the metadata of the response.
+    pub async fn get_application_with_metadata(
+        &self,
+        input: GetApplicationRequest,
+    ) -> Result<(GetApplicationResponse, ResponseMetadata), RusotoError<GetApplicationError>> {
+        let request = self.build_get_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApplicationError::from_response(response))
         }
     }
 
-    /// <p>Gets the specified application.</p>
-    async fn get_application(
+    /// Presigns the request `get_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApplicationRequest,
-    ) -> Result<GetApplicationResponse, RusotoError<GetApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_application_request(&self, input: GetApplicationRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2108,59 +2447,117 @@ impl ServerlessRepo for ServerlessRepoClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_application_policy`, also returning the metadata of the response.
+    pub async fn get_application_policy_with_metadata(
+        &self,
+        input: GetApplicationPolicyRequest,
+    ) -> Result<
+        (GetApplicationPolicyResponse, ResponseMetadata),
+        RusotoError<GetApplicationPolicyError>,
+    > {
+        let request = self.build_get_application_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetApplicationResponse, _>()?;
+                .deserialize::<GetApplicationPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApplicationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApplicationPolicyError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the policy for the application.</p>
-    async fn get_application_policy(
+    /// Presigns the request `get_application_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_application_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApplicationPolicyRequest,
-    ) -> Result<GetApplicationPolicyResponse, RusotoError<GetApplicationPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_application_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_application_policy_request(
+        &self,
+        input: GetApplicationPolicyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/policy",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetApplicationPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_cloud_formation_template`, also returning the metadata of the response.
+    pub async fn get_cloud_formation_template_with_metadata(
+        &self,
+        input: GetCloudFormationTemplateRequest,
+    ) -> Result<
+        (GetCloudFormationTemplateResponse, ResponseMetadata),
+        RusotoError<GetCloudFormationTemplateError>,
+    > {
+        let request = self.build_get_cloud_formation_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetApplicationPolicyResponse, _>()?;
+                .deserialize::<GetCloudFormationTemplateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApplicationPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetCloudFormationTemplateError::from_response(response))
         }
     }
 
-    /// <p>Gets the specified AWS CloudFormation template.</p>
-    async fn get_cloud_formation_template(
+    /// Presigns the request `get_cloud_formation_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cloud_formation_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCloudFormationTemplateRequest,
-    ) -> Result<GetCloudFormationTemplateResponse, RusotoError<GetCloudFormationTemplateError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cloud_formation_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_cloud_formation_template_request(
+        &self,
+        input: GetCloudFormationTemplateRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/templates/{template_id}",
             application_id = input.application_id,
@@ -2168,37 +2565,67 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetCloudFormationTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_application_dependencies`, also returning the metadata of the response.
+    pub async fn list_application_dependencies_with_metadata(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> Result<
+        (ListApplicationDependenciesResponse, ResponseMetadata),
+        RusotoError<ListApplicationDependenciesError>,
+    > {
+        let request = self.build_list_application_dependencies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCloudFormationTemplateResponse, _>()?;
+                .deserialize::<ListApplicationDependenciesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCloudFormationTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListApplicationDependenciesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the list of applications nested in the containing application.</p>
-    async fn list_application_dependencies(
+    /// Presigns the request `list_application_dependencies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_application_dependencies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationDependenciesRequest,
-    ) -> Result<ListApplicationDependenciesResponse, RusotoError<ListApplicationDependenciesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_application_dependencies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_application_dependencies_request(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/dependencies",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplicationDependencies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2213,34 +2640,64 @@ impl ServerlessRepo for ServerlessRepoClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_application_versions`, also returning the metadata of the response.
+    pub async fn list_application_versions_with_metadata(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> Result<
+        (ListApplicationVersionsResponse, ResponseMetadata),
+        RusotoError<ListApplicationVersionsError>,
+    > {
+        let request = self.build_list_application_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApplicationDependenciesResponse, _>()?;
+                .deserialize::<ListApplicationVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationDependenciesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListApplicationVersionsError::from_response(response))
         }
     }
 
-    /// <p>Lists versions for the specified application.</p>
-    async fn list_application_versions(
+    /// Presigns the request `list_application_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_application_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationVersionsRequest,
-    ) -> Result<ListApplicationVersionsResponse, RusotoError<ListApplicationVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_application_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_application_versions_request(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/versions",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplicationVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2252,31 +2709,56 @@ impl ServerlessRepo for ServerlessRepoClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_applications`, also returning the metadata of the response.
+    pub async fn list_applications_with_metadata(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<(ListApplicationsResponse, ResponseMetadata), RusotoError<ListApplicationsError>>
+    {
+        let request = self.build_list_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApplicationVersionsResponse, _>()?;
+                .deserialize::<ListApplicationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListApplicationsError::from_response(response))
         }
     }
 
-    /// <p>Lists applications owned by the requester.</p>
-    async fn list_applications(
+    /// Presigns the request `list_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_applications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationsRequest,
-    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_applications_request(&self, input: ListApplicationsRequest) -> SignedRequest {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2288,89 +2770,389 @@ impl ServerlessRepo for ServerlessRepoClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_application_policy`, also returning the metadata of the response.
+    pub async fn put_application_policy_with_metadata(
+        &self,
+        input: PutApplicationPolicyRequest,
+    ) -> Result<
+        (PutApplicationPolicyResponse, ResponseMetadata),
+        RusotoError<PutApplicationPolicyError>,
+    > {
+        let request = self.build_put_application_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApplicationsResponse, _>()?;
+                .deserialize::<PutApplicationPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutApplicationPolicyError::from_response(response))
         }
     }
 
-    /// <p>Sets the permission policy for an application. For the list of actions supported for this operation, see
-    /// <a href="https://docs.aws.amazon.com/serverlessrepo/latest/devguide/access-control-resource-based.html#application-permissions">Application
-    /// Permissions</a>
-    /// .</p>
-    async fn put_application_policy(
+    /// Presigns the request `put_application_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_application_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutApplicationPolicyRequest,
-    ) -> Result<PutApplicationPolicyResponse, RusotoError<PutApplicationPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_application_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_application_policy_request(
+        &self,
+        input: PutApplicationPolicyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/policy",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("PutApplicationPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_application`, also returning the metadata of the response.
+    pub async fn update_application_with_metadata(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<(UpdateApplicationResponse, ResponseMetadata), RusotoError<UpdateApplicationError>>
+    {
+        let request = self.build_update_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutApplicationPolicyResponse, _>()?;
+                .deserialize::<UpdateApplicationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutApplicationPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateApplicationError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified application.</p>
-    async fn update_application(
+    /// Presigns the request `update_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApplicationRequest,
-    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_request(&self, input: UpdateApplicationRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("PATCH", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("UpdateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListApplicationDependencies` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_application_dependencies_pages(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListApplicationDependenciesResponse,
+            RusotoError<ListApplicationDependenciesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerlessRepoClient, input| async move {
+                client.list_application_dependencies(input).await
+            },
+            |input: &ListApplicationDependenciesRequest,
+             output: &ListApplicationDependenciesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApplicationDependenciesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Dependencies` of every page of `ListApplicationDependencies` results for `input`, as `list_application_dependencies_pages` requests them.
+    pub fn list_application_dependencies_stream(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ApplicationDependencySummary, RusotoError<ListApplicationDependenciesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_application_dependencies_pages(input), |page| {
+            page.dependencies.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListApplicationVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_application_versions_pages(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListApplicationVersionsResponse, RusotoError<ListApplicationVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerlessRepoClient, input| async move {
+                client.list_application_versions(input).await
+            },
+            |input: &ListApplicationVersionsRequest, output: &ListApplicationVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApplicationVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Versions` of every page of `ListApplicationVersions` results for `input`, as `list_application_versions_pages` requests them.
+    pub fn list_application_versions_stream(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<VersionSummary, RusotoError<ListApplicationVersionsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_application_versions_pages(input), |page| {
+            page.versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListApplications` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_applications_pages(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListApplicationsResponse, RusotoError<ListApplicationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServerlessRepoClient, input| async move { client.list_applications(input).await },
+            |input: &ListApplicationsRequest, output: &ListApplicationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApplicationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Applications` of every page of `ListApplications` results for `input`, as `list_applications_pages` requests them.
+    pub fn list_applications_stream(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> impl futures::Stream<Item = Result<ApplicationSummary, RusotoError<ListApplicationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_applications_pages(input), |page| {
+            page.applications.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ServerlessRepo for ServerlessRepoClient {
+    /// <p>Creates an application, optionally including an AWS SAM file to create the first application version in the same call.</p>
+    async fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.create_application_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApplicationResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApplicationError::from_response(response))
-        }
+    /// <p>Creates an application version.</p>
+    async fn create_application_version(
+        &self,
+        input: CreateApplicationVersionRequest,
+    ) -> Result<CreateApplicationVersionResponse, RusotoError<CreateApplicationVersionError>> {
+        self.create_application_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an AWS CloudFormation change set for the given application.</p>
+    async fn create_cloud_formation_change_set(
+        &self,
+        input: CreateCloudFormationChangeSetRequest,
+    ) -> Result<
+        CreateCloudFormationChangeSetResponse,
+        RusotoError<CreateCloudFormationChangeSetError>,
+    > {
+        self.create_cloud_formation_change_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an AWS CloudFormation template.</p>
+    async fn create_cloud_formation_template(
+        &self,
+        input: CreateCloudFormationTemplateRequest,
+    ) -> Result<CreateCloudFormationTemplateResponse, RusotoError<CreateCloudFormationTemplateError>>
+    {
+        self.create_cloud_formation_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified application.</p>
+    async fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.delete_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the specified application.</p>
+    async fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> Result<GetApplicationResponse, RusotoError<GetApplicationError>> {
+        self.get_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the policy for the application.</p>
+    async fn get_application_policy(
+        &self,
+        input: GetApplicationPolicyRequest,
+    ) -> Result<GetApplicationPolicyResponse, RusotoError<GetApplicationPolicyError>> {
+        self.get_application_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the specified AWS CloudFormation template.</p>
+    async fn get_cloud_formation_template(
+        &self,
+        input: GetCloudFormationTemplateRequest,
+    ) -> Result<GetCloudFormationTemplateResponse, RusotoError<GetCloudFormationTemplateError>>
+    {
+        self.get_cloud_formation_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the list of applications nested in the containing application.</p>
+    async fn list_application_dependencies(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> Result<ListApplicationDependenciesResponse, RusotoError<ListApplicationDependenciesError>>
+    {
+        self.list_application_dependencies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists versions for the specified application.</p>
+    async fn list_application_versions(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> Result<ListApplicationVersionsResponse, RusotoError<ListApplicationVersionsError>> {
+        self.list_application_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists applications owned by the requester.</p>
+    async fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.list_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the permission policy for an application. For the list of actions supported for this operation, see
+    /// <a href="https://docs.aws.amazon.com/serverlessrepo/latest/devguide/access-control-resource-based.html#application-permissions">Application
+    /// Permissions</a>
+    /// .</p>
+    async fn put_application_policy(
+        &self,
+        input: PutApplicationPolicyRequest,
+    ) -> Result<PutApplicationPolicyResponse, RusotoError<PutApplicationPolicyError>> {
+        self.put_application_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified application.</p>
+    async fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.update_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -609,6 +609,7 @@ pub struct ServiceQuotaIncreaseRequestInTemplate {
 
 /// Errors returned by AssociateServiceQuotaTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateServiceQuotaTemplateError {
     /// <p>The action you attempted is not allowed unless Service Access with Service Quotas is enabled in your organization. To enable, call <a>AssociateServiceQuotaTemplate</a>.</p>
     AWSServiceAccessNotEnabled(String),
@@ -626,6 +627,8 @@ pub enum AssociateServiceQuotaTemplateError {
     TemplatesNotAvailableInRegion(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateServiceQuotaTemplateError {
@@ -635,49 +638,62 @@ impl AssociateServiceQuotaTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateServiceQuotaTemplateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateServiceQuotaTemplateError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceQuotaTemplateError::DependencyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceQuotaTemplateError::NoAvailableOrganization(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationNotInAllFeaturesModeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceQuotaTemplateError::OrganizationNotInAllFeaturesMode(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AssociateServiceQuotaTemplateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateServiceQuotaTemplateError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TemplatesNotAvailableInRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceQuotaTemplateError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateServiceQuotaTemplateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -707,12 +723,16 @@ impl fmt::Display for AssociateServiceQuotaTemplateError {
             AssociateServiceQuotaTemplateError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateServiceQuotaTemplateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateServiceQuotaTemplateError {}
 /// Errors returned by DeleteServiceQuotaIncreaseRequestFromTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteServiceQuotaIncreaseRequestFromTemplateError {
     /// <p>The action you attempted is not allowed unless Service Access with Service Quotas is enabled in your organization. To enable, call <a>AssociateServiceQuotaTemplate</a>.</p>
     AWSServiceAccessNotEnabled(String),
@@ -732,6 +752,8 @@ pub enum DeleteServiceQuotaIncreaseRequestFromTemplateError {
     TemplatesNotAvailableInRegion(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteServiceQuotaIncreaseRequestFromTemplateError {
@@ -740,17 +762,17 @@ impl DeleteServiceQuotaIncreaseRequestFromTemplateError {
     ) -> RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "AWSServiceAccessNotEnabledException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(err.msg)),
-"AccessDeniedException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(err.msg)),
-"DependencyAccessDeniedException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(err.msg)),
-"IllegalArgumentException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(err.msg)),
-"NoAvailableOrganizationException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(err.msg)),
-"NoSuchResourceException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(err.msg)),
-"ServiceException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::Service(err.msg)),
-"TemplatesNotAvailableInRegionException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(err.msg)),
-"TooManyRequestsException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(err.msg)),
+                                "AWSServiceAccessNotEnabledException" => return RusotoError::service(DeleteServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(err.msg), &res),
+"AccessDeniedException" => return RusotoError::service(DeleteServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(err.msg), &res),
+"DependencyAccessDeniedException" => return RusotoError::service(DeleteServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(err.msg), &res),
+"IllegalArgumentException" => return RusotoError::service(DeleteServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(err.msg), &res),
+"NoAvailableOrganizationException" => return RusotoError::service(DeleteServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(err.msg), &res),
+"NoSuchResourceException" => return RusotoError::service(DeleteServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(err.msg), &res),
+"ServiceException" => return RusotoError::service(DeleteServiceQuotaIncreaseRequestFromTemplateError::Service(err.msg), &res),
+"TemplatesNotAvailableInRegionException" => return RusotoError::service(DeleteServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(err.msg), &res),
+"TooManyRequestsException" => return RusotoError::service(DeleteServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(DeleteServiceQuotaIncreaseRequestFromTemplateError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -787,12 +809,16 @@ impl fmt::Display for DeleteServiceQuotaIncreaseRequestFromTemplateError {
             DeleteServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteServiceQuotaIncreaseRequestFromTemplateError {}
 /// Errors returned by DisassociateServiceQuotaTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateServiceQuotaTemplateError {
     /// <p>The action you attempted is not allowed unless Service Access with Service Quotas is enabled in your organization. To enable, call <a>AssociateServiceQuotaTemplate</a>.</p>
     AWSServiceAccessNotEnabled(String),
@@ -810,6 +836,8 @@ pub enum DisassociateServiceQuotaTemplateError {
     TemplatesNotAvailableInRegion(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateServiceQuotaTemplateError {
@@ -819,51 +847,64 @@ impl DisassociateServiceQuotaTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled(err.msg),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateServiceQuotaTemplateError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateServiceQuotaTemplateError::DependencyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateServiceQuotaTemplateError::NoAvailableOrganization(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisassociateServiceQuotaTemplateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateServiceQuotaTemplateError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaTemplateNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "TemplatesNotAvailableInRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateServiceQuotaTemplateError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateServiceQuotaTemplateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -895,12 +936,16 @@ impl fmt::Display for DisassociateServiceQuotaTemplateError {
             DisassociateServiceQuotaTemplateError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateServiceQuotaTemplateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateServiceQuotaTemplateError {}
 /// Errors returned by GetAWSDefaultServiceQuota
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAWSDefaultServiceQuotaError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -912,6 +957,8 @@ pub enum GetAWSDefaultServiceQuotaError {
     Service(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAWSDefaultServiceQuotaError {
@@ -919,30 +966,39 @@ impl GetAWSDefaultServiceQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAWSDefaultServiceQuotaError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::IllegalArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAWSDefaultServiceQuotaError::IllegalArgument(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::NoSuchResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAWSDefaultServiceQuotaError::NoSuchResource(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::Service(err.msg))
+                    return RusotoError::service(
+                        GetAWSDefaultServiceQuotaError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAWSDefaultServiceQuotaError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetAWSDefaultServiceQuotaError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -957,12 +1013,14 @@ impl fmt::Display for GetAWSDefaultServiceQuotaError {
             GetAWSDefaultServiceQuotaError::NoSuchResource(ref cause) => write!(f, "{}", cause),
             GetAWSDefaultServiceQuotaError::Service(ref cause) => write!(f, "{}", cause),
             GetAWSDefaultServiceQuotaError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetAWSDefaultServiceQuotaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAWSDefaultServiceQuotaError {}
 /// Errors returned by GetAssociationForServiceQuotaTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAssociationForServiceQuotaTemplateError {
     /// <p>The action you attempted is not allowed unless Service Access with Service Quotas is enabled in your organization. To enable, call <a>AssociateServiceQuotaTemplate</a>.</p>
     AWSServiceAccessNotEnabled(String),
@@ -980,6 +1038,8 @@ pub enum GetAssociationForServiceQuotaTemplateError {
     TemplatesNotAvailableInRegion(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAssociationForServiceQuotaTemplateError {
@@ -989,55 +1049,68 @@ impl GetAssociationForServiceQuotaTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAssociationForServiceQuotaTemplateError::AWSServiceAccessNotEnabled(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAssociationForServiceQuotaTemplateError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAssociationForServiceQuotaTemplateError::DependencyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAssociationForServiceQuotaTemplateError::NoAvailableOrganization(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAssociationForServiceQuotaTemplateError::Service(err.msg),
+                        &res,
                     )
                 }
                 "ServiceQuotaTemplateNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAssociationForServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "TemplatesNotAvailableInRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAssociationForServiceQuotaTemplateError::TemplatesNotAvailableInRegion(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAssociationForServiceQuotaTemplateError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetAssociationForServiceQuotaTemplateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1071,12 +1144,16 @@ impl fmt::Display for GetAssociationForServiceQuotaTemplateError {
             GetAssociationForServiceQuotaTemplateError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetAssociationForServiceQuotaTemplateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetAssociationForServiceQuotaTemplateError {}
 /// Errors returned by GetRequestedServiceQuotaChange
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRequestedServiceQuotaChangeError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1088,6 +1165,8 @@ pub enum GetRequestedServiceQuotaChangeError {
     Service(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRequestedServiceQuotaChangeError {
@@ -1097,32 +1176,42 @@ impl GetRequestedServiceQuotaChangeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRequestedServiceQuotaChangeError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRequestedServiceQuotaChangeError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRequestedServiceQuotaChangeError::IllegalArgument(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRequestedServiceQuotaChangeError::NoSuchResource(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRequestedServiceQuotaChangeError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRequestedServiceQuotaChangeError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRequestedServiceQuotaChangeError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRequestedServiceQuotaChangeError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1143,12 +1232,16 @@ impl fmt::Display for GetRequestedServiceQuotaChangeError {
             GetRequestedServiceQuotaChangeError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRequestedServiceQuotaChangeError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRequestedServiceQuotaChangeError {}
 /// Errors returned by GetServiceQuota
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetServiceQuotaError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1160,6 +1253,8 @@ pub enum GetServiceQuotaError {
     Service(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetServiceQuotaError {
@@ -1167,22 +1262,31 @@ impl GetServiceQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetServiceQuotaError::AccessDenied(err.msg))
+                    return RusotoError::service(GetServiceQuotaError::AccessDenied(err.msg), &res)
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(GetServiceQuotaError::IllegalArgument(err.msg))
+                    return RusotoError::service(
+                        GetServiceQuotaError::IllegalArgument(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(GetServiceQuotaError::NoSuchResource(err.msg))
+                    return RusotoError::service(
+                        GetServiceQuotaError::NoSuchResource(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetServiceQuotaError::Service(err.msg))
+                    return RusotoError::service(GetServiceQuotaError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetServiceQuotaError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetServiceQuotaError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetServiceQuotaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1197,12 +1301,14 @@ impl fmt::Display for GetServiceQuotaError {
             GetServiceQuotaError::NoSuchResource(ref cause) => write!(f, "{}", cause),
             GetServiceQuotaError::Service(ref cause) => write!(f, "{}", cause),
             GetServiceQuotaError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetServiceQuotaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetServiceQuotaError {}
 /// Errors returned by GetServiceQuotaIncreaseRequestFromTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetServiceQuotaIncreaseRequestFromTemplateError {
     /// <p>The action you attempted is not allowed unless Service Access with Service Quotas is enabled in your organization. To enable, call <a>AssociateServiceQuotaTemplate</a>.</p>
     AWSServiceAccessNotEnabled(String),
@@ -1222,6 +1328,8 @@ pub enum GetServiceQuotaIncreaseRequestFromTemplateError {
     TemplatesNotAvailableInRegion(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetServiceQuotaIncreaseRequestFromTemplateError {
@@ -1231,58 +1339,72 @@ impl GetServiceQuotaIncreaseRequestFromTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(err.msg),
+                        &res,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetServiceQuotaIncreaseRequestFromTemplateError::Service(err.msg),
+                        &res,
                     )
                 }
-                "TemplatesNotAvailableInRegionException" => return RusotoError::Service(
+                "TemplatesNotAvailableInRegionException" => return RusotoError::service(
                     GetServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetServiceQuotaIncreaseRequestFromTemplateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1319,12 +1441,16 @@ impl fmt::Display for GetServiceQuotaIncreaseRequestFromTemplateError {
             GetServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetServiceQuotaIncreaseRequestFromTemplateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetServiceQuotaIncreaseRequestFromTemplateError {}
 /// Errors returned by ListAWSDefaultServiceQuotas
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAWSDefaultServiceQuotasError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1338,6 +1464,8 @@ pub enum ListAWSDefaultServiceQuotasError {
     Service(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAWSDefaultServiceQuotasError {
@@ -1347,35 +1475,48 @@ impl ListAWSDefaultServiceQuotasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAWSDefaultServiceQuotasError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::IllegalArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAWSDefaultServiceQuotasError::IllegalArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAWSDefaultServiceQuotasError::InvalidPaginationToken(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::NoSuchResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAWSDefaultServiceQuotasError::NoSuchResource(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::Service(err.msg))
+                    return RusotoError::service(
+                        ListAWSDefaultServiceQuotasError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAWSDefaultServiceQuotasError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListAWSDefaultServiceQuotasError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1393,12 +1534,14 @@ impl fmt::Display for ListAWSDefaultServiceQuotasError {
             ListAWSDefaultServiceQuotasError::NoSuchResource(ref cause) => write!(f, "{}", cause),
             ListAWSDefaultServiceQuotasError::Service(ref cause) => write!(f, "{}", cause),
             ListAWSDefaultServiceQuotasError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListAWSDefaultServiceQuotasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAWSDefaultServiceQuotasError {}
 /// Errors returned by ListRequestedServiceQuotaChangeHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRequestedServiceQuotaChangeHistoryError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1412,6 +1555,8 @@ pub enum ListRequestedServiceQuotaChangeHistoryError {
     Service(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRequestedServiceQuotaChangeHistoryError {
@@ -1421,39 +1566,50 @@ impl ListRequestedServiceQuotaChangeHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryError::IllegalArgument(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryError::InvalidPaginationToken(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryError::NoSuchResource(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryError::Service(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListRequestedServiceQuotaChangeHistoryError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1481,12 +1637,16 @@ impl fmt::Display for ListRequestedServiceQuotaChangeHistoryError {
             ListRequestedServiceQuotaChangeHistoryError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListRequestedServiceQuotaChangeHistoryError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListRequestedServiceQuotaChangeHistoryError {}
 /// Errors returned by ListRequestedServiceQuotaChangeHistoryByQuota
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRequestedServiceQuotaChangeHistoryByQuotaError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1500,6 +1660,8 @@ pub enum ListRequestedServiceQuotaChangeHistoryByQuotaError {
     Service(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRequestedServiceQuotaChangeHistoryByQuotaError {
@@ -1509,43 +1671,54 @@ impl ListRequestedServiceQuotaChangeHistoryByQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::IllegalArgument(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::InvalidPaginationToken(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::NoSuchResource(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::Service(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::TooManyRequests(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListRequestedServiceQuotaChangeHistoryByQuotaError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1573,12 +1746,16 @@ impl fmt::Display for ListRequestedServiceQuotaChangeHistoryByQuotaError {
             ListRequestedServiceQuotaChangeHistoryByQuotaError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListRequestedServiceQuotaChangeHistoryByQuotaError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListRequestedServiceQuotaChangeHistoryByQuotaError {}
 /// Errors returned by ListServiceQuotaIncreaseRequestsInTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListServiceQuotaIncreaseRequestsInTemplateError {
     /// <p>The action you attempted is not allowed unless Service Access with Service Quotas is enabled in your organization. To enable, call <a>AssociateServiceQuotaTemplate</a>.</p>
     AWSServiceAccessNotEnabled(String),
@@ -1596,6 +1773,8 @@ pub enum ListServiceQuotaIncreaseRequestsInTemplateError {
     TemplatesNotAvailableInRegion(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListServiceQuotaIncreaseRequestsInTemplateError {
@@ -1605,53 +1784,66 @@ impl ListServiceQuotaIncreaseRequestsInTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListServiceQuotaIncreaseRequestsInTemplateError::AWSServiceAccessNotEnabled(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListServiceQuotaIncreaseRequestsInTemplateError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListServiceQuotaIncreaseRequestsInTemplateError::DependencyAccessDenied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListServiceQuotaIncreaseRequestsInTemplateError::IllegalArgument(err.msg),
+                        &res,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListServiceQuotaIncreaseRequestsInTemplateError::NoAvailableOrganization(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListServiceQuotaIncreaseRequestsInTemplateError::Service(err.msg),
+                        &res,
                     )
                 }
-                "TemplatesNotAvailableInRegionException" => return RusotoError::Service(
+                "TemplatesNotAvailableInRegionException" => return RusotoError::service(
                     ListServiceQuotaIncreaseRequestsInTemplateError::TemplatesNotAvailableInRegion(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListServiceQuotaIncreaseRequestsInTemplateError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListServiceQuotaIncreaseRequestsInTemplateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1685,12 +1877,16 @@ impl fmt::Display for ListServiceQuotaIncreaseRequestsInTemplateError {
             ListServiceQuotaIncreaseRequestsInTemplateError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListServiceQuotaIncreaseRequestsInTemplateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListServiceQuotaIncreaseRequestsInTemplateError {}
 /// Errors returned by ListServiceQuotas
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListServiceQuotasError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1704,6 +1900,8 @@ pub enum ListServiceQuotasError {
     Service(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListServiceQuotasError {
@@ -1711,27 +1909,40 @@ impl ListServiceQuotasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListServiceQuotasError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListServiceQuotasError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListServiceQuotasError::IllegalArgument(err.msg))
+                    return RusotoError::service(
+                        ListServiceQuotasError::IllegalArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListServiceQuotasError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListServiceQuotasError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(ListServiceQuotasError::NoSuchResource(err.msg))
+                    return RusotoError::service(
+                        ListServiceQuotasError::NoSuchResource(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListServiceQuotasError::Service(err.msg))
+                    return RusotoError::service(ListServiceQuotasError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListServiceQuotasError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListServiceQuotasError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListServiceQuotasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1747,12 +1958,14 @@ impl fmt::Display for ListServiceQuotasError {
             ListServiceQuotasError::NoSuchResource(ref cause) => write!(f, "{}", cause),
             ListServiceQuotasError::Service(ref cause) => write!(f, "{}", cause),
             ListServiceQuotasError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListServiceQuotasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListServiceQuotasError {}
 /// Errors returned by ListServices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListServicesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1764,6 +1977,8 @@ pub enum ListServicesError {
     Service(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListServicesError {
@@ -1771,22 +1986,25 @@ impl ListServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListServicesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListServicesError::AccessDenied(err.msg), &res)
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListServicesError::IllegalArgument(err.msg))
+                    return RusotoError::service(ListServicesError::IllegalArgument(err.msg), &res)
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListServicesError::InvalidPaginationToken(err.msg))
+                    return RusotoError::service(
+                        ListServicesError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListServicesError::Service(err.msg))
+                    return RusotoError::service(ListServicesError::Service(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListServicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListServicesError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListServicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1801,12 +2019,14 @@ impl fmt::Display for ListServicesError {
             ListServicesError::InvalidPaginationToken(ref cause) => write!(f, "{}", cause),
             ListServicesError::Service(ref cause) => write!(f, "{}", cause),
             ListServicesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListServicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListServicesError {}
 /// Errors returned by PutServiceQuotaIncreaseRequestIntoTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutServiceQuotaIncreaseRequestIntoTemplateError {
     /// <p>The action you attempted is not allowed unless Service Access with Service Quotas is enabled in your organization. To enable, call <a>AssociateServiceQuotaTemplate</a>.</p>
     AWSServiceAccessNotEnabled(String),
@@ -1828,6 +2048,8 @@ pub enum PutServiceQuotaIncreaseRequestIntoTemplateError {
     TemplatesNotAvailableInRegion(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutServiceQuotaIncreaseRequestIntoTemplateError {
@@ -1837,63 +2059,78 @@ impl PutServiceQuotaIncreaseRequestIntoTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutServiceQuotaIncreaseRequestIntoTemplateError::AWSServiceAccessNotEnabled(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutServiceQuotaIncreaseRequestIntoTemplateError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutServiceQuotaIncreaseRequestIntoTemplateError::DependencyAccessDenied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutServiceQuotaIncreaseRequestIntoTemplateError::IllegalArgument(err.msg),
+                        &res,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutServiceQuotaIncreaseRequestIntoTemplateError::NoAvailableOrganization(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutServiceQuotaIncreaseRequestIntoTemplateError::NoSuchResource(err.msg),
+                        &res,
                     )
                 }
                 "QuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutServiceQuotaIncreaseRequestIntoTemplateError::QuotaExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutServiceQuotaIncreaseRequestIntoTemplateError::Service(err.msg),
+                        &res,
                     )
                 }
-                "TemplatesNotAvailableInRegionException" => return RusotoError::Service(
+                "TemplatesNotAvailableInRegionException" => return RusotoError::service(
                     PutServiceQuotaIncreaseRequestIntoTemplateError::TemplatesNotAvailableInRegion(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutServiceQuotaIncreaseRequestIntoTemplateError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutServiceQuotaIncreaseRequestIntoTemplateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1933,12 +2170,16 @@ impl fmt::Display for PutServiceQuotaIncreaseRequestIntoTemplateError {
             PutServiceQuotaIncreaseRequestIntoTemplateError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutServiceQuotaIncreaseRequestIntoTemplateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutServiceQuotaIncreaseRequestIntoTemplateError {}
 /// Errors returned by RequestServiceQuotaIncrease
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RequestServiceQuotaIncreaseError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1958,6 +2199,8 @@ pub enum RequestServiceQuotaIncreaseError {
     Service(String),
     /// <p>Due to throttling, the request was denied. Slow down the rate of request calls, or request an increase for this quota. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RequestServiceQuotaIncreaseError {
@@ -1967,50 +2210,66 @@ impl RequestServiceQuotaIncreaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RequestServiceQuotaIncreaseError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RequestServiceQuotaIncreaseError::DependencyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::IllegalArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RequestServiceQuotaIncreaseError::IllegalArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RequestServiceQuotaIncreaseError::InvalidResourceState(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::NoSuchResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RequestServiceQuotaIncreaseError::NoSuchResource(err.msg),
+                        &res,
+                    )
                 }
                 "QuotaExceededException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::QuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RequestServiceQuotaIncreaseError::QuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RequestServiceQuotaIncreaseError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::Service(err.msg))
+                    return RusotoError::service(
+                        RequestServiceQuotaIncreaseError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RequestServiceQuotaIncreaseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RequestServiceQuotaIncreaseError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2035,6 +2294,7 @@ impl fmt::Display for RequestServiceQuotaIncreaseError {
             }
             RequestServiceQuotaIncreaseError::Service(ref cause) => write!(f, "{}", cause),
             RequestServiceQuotaIncreaseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            RequestServiceQuotaIncreaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2176,6 +2436,7 @@ impl ServiceQuotasClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ServiceQuotasClient {
         ServiceQuotasClient {
             client: Client::shared(),
@@ -2201,18 +2462,68 @@ impl ServiceQuotasClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceQuotasClient {
         ServiceQuotasClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ServiceQuotasClient> {
+        rusoto_core::ClientBuilder::new(ServiceQuotasClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ServiceQuotasClient {
+        ServiceQuotasClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ServiceQuotas for ServiceQuotasClient {
-    /// <p>Associates the Service Quotas template with your organization so that when new accounts are created in your organization, the template submits increase requests for the specified service quotas. Use the Service Quotas template to request an increase for any adjustable quota value. After you define the Service Quotas template, use this operation to associate, or enable, the template. </p>
-    async fn associate_service_quota_template(
+impl ServiceQuotasClient {
+    /// Calls `associate_service_quota_template`, also returning the metadata of the response.
+    pub async fn associate_service_quota_template_with_metadata(
         &self,
     ) -> Result<
-        AssociateServiceQuotaTemplateResponse,
+        (AssociateServiceQuotaTemplateResponse, ResponseMetadata),
         RusotoError<AssociateServiceQuotaTemplateError>,
     > {
+        let request = self.build_associate_service_quota_template_request();
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateServiceQuotaTemplateResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateServiceQuotaTemplateError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_service_quota_template` would send with `presigner` instead of sending it.
+    pub async fn presign_associate_service_quota_template(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_service_quota_template_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_service_quota_template_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("AssociateServiceQuotaTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2221,31 +2532,62 @@ impl ServiceQuotas for ServiceQuotasClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `delete_service_quota_increase_request_from_template`, also returning the metadata of the response.
+    pub async fn delete_service_quota_increase_request_from_template_with_metadata(
+        &self,
+        input: DeleteServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> Result<
+        (
+            DeleteServiceQuotaIncreaseRequestFromTemplateResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError>,
+    > {
+        let request = self.build_delete_service_quota_increase_request_from_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateServiceQuotaTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteServiceQuotaIncreaseRequestFromTemplateResponse, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateServiceQuotaTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteServiceQuotaIncreaseRequestFromTemplateError::from_response(response))
         }
     }
 
-    /// <p>Removes a service quota increase request from the Service Quotas template. </p>
-    async fn delete_service_quota_increase_request_from_template(
+    /// Presigns the request `delete_service_quota_increase_request_from_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_service_quota_increase_request_from_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteServiceQuotaIncreaseRequestFromTemplateRequest,
-    ) -> Result<
-        DeleteServiceQuotaIncreaseRequestFromTemplateResponse,
-        RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_service_quota_increase_request_from_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_service_quota_increase_request_from_template_request(
+        &self,
+        input: DeleteServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("DeleteServiceQuotaIncreaseRequestFromTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2255,30 +2597,55 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_service_quota_template`, also returning the metadata of the response.
+    pub async fn disassociate_service_quota_template_with_metadata(
+        &self,
+    ) -> Result<
+        (DisassociateServiceQuotaTemplateResponse, ResponseMetadata),
+        RusotoError<DisassociateServiceQuotaTemplateError>,
+    > {
+        let request = self.build_disassociate_service_quota_template_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteServiceQuotaIncreaseRequestFromTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateServiceQuotaTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteServiceQuotaIncreaseRequestFromTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateServiceQuotaTemplateError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Disables the Service Quotas template. Once the template is disabled, it does not request quota increases for new accounts in your organization. Disabling the quota template does not apply the quota increase requests from the template. </p> <p> <b>Related operations</b> </p> <ul> <li> <p>To enable the quota template, call <a>AssociateServiceQuotaTemplate</a>. </p> </li> <li> <p>To delete a specific service quota from the template, use <a>DeleteServiceQuotaIncreaseRequestFromTemplate</a>.</p> </li> </ul></p>
-    async fn disassociate_service_quota_template(
+    /// Presigns the request `disassociate_service_quota_template` would send with `presigner` instead of sending it.
+    pub async fn presign_disassociate_service_quota_template(
         &self,
-    ) -> Result<
-        DisassociateServiceQuotaTemplateResponse,
-        RusotoError<DisassociateServiceQuotaTemplateError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_service_quota_template_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_service_quota_template_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("DisassociateServiceQuotaTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2287,31 +2654,58 @@ impl ServiceQuotas for ServiceQuotasClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_aws_default_service_quota`, also returning the metadata of the response.
+    pub async fn get_aws_default_service_quota_with_metadata(
+        &self,
+        input: GetAWSDefaultServiceQuotaRequest,
+    ) -> Result<
+        (GetAWSDefaultServiceQuotaResponse, ResponseMetadata),
+        RusotoError<GetAWSDefaultServiceQuotaError>,
+    > {
+        let request = self.build_get_aws_default_service_quota_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateServiceQuotaTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAWSDefaultServiceQuotaResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateServiceQuotaTemplateError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAWSDefaultServiceQuotaError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the default service quotas values. The Value returned for each quota is the AWS default value, even if the quotas have been increased.. </p>
-    async fn get_aws_default_service_quota(
+    /// Presigns the request `get_aws_default_service_quota` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_aws_default_service_quota(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAWSDefaultServiceQuotaRequest,
-    ) -> Result<GetAWSDefaultServiceQuotaResponse, RusotoError<GetAWSDefaultServiceQuotaError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_aws_default_service_quota_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_aws_default_service_quota_request(
+        &self,
+        input: GetAWSDefaultServiceQuotaRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetAWSDefaultServiceQuota");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2321,30 +2715,58 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_association_for_service_quota_template`, also returning the metadata of the response.
+    pub async fn get_association_for_service_quota_template_with_metadata(
+        &self,
+    ) -> Result<
+        (
+            GetAssociationForServiceQuotaTemplateResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetAssociationForServiceQuotaTemplateError>,
+    > {
+        let request = self.build_get_association_for_service_quota_template_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAWSDefaultServiceQuotaResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAssociationForServiceQuotaTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAWSDefaultServiceQuotaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAssociationForServiceQuotaTemplateError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the <code>ServiceQuotaTemplateAssociationStatus</code> value from the service. Use this action to determine if the Service Quota template is associated, or enabled. </p>
-    async fn get_association_for_service_quota_template(
+    /// Presigns the request `get_association_for_service_quota_template` would send with `presigner` instead of sending it.
+    pub async fn presign_get_association_for_service_quota_template(
         &self,
-    ) -> Result<
-        GetAssociationForServiceQuotaTemplateResponse,
-        RusotoError<GetAssociationForServiceQuotaTemplateError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_association_for_service_quota_template_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_association_for_service_quota_template_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetAssociationForServiceQuotaTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2353,33 +2775,58 @@ impl ServiceQuotas for ServiceQuotasClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_requested_service_quota_change`, also returning the metadata of the response.
+    pub async fn get_requested_service_quota_change_with_metadata(
+        &self,
+        input: GetRequestedServiceQuotaChangeRequest,
+    ) -> Result<
+        (GetRequestedServiceQuotaChangeResponse, ResponseMetadata),
+        RusotoError<GetRequestedServiceQuotaChangeError>,
+    > {
+        let request = self.build_get_requested_service_quota_change_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAssociationForServiceQuotaTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRequestedServiceQuotaChangeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAssociationForServiceQuotaTemplateError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRequestedServiceQuotaChangeError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the details for a particular increase request. </p>
-    async fn get_requested_service_quota_change(
+    /// Presigns the request `get_requested_service_quota_change` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_requested_service_quota_change(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRequestedServiceQuotaChangeRequest,
-    ) -> Result<
-        GetRequestedServiceQuotaChangeResponse,
-        RusotoError<GetRequestedServiceQuotaChangeError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_requested_service_quota_change_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_requested_service_quota_change_request(
+        &self,
+        input: GetRequestedServiceQuotaChangeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetRequestedServiceQuotaChange");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2389,58 +2836,114 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_service_quota`, also returning the metadata of the response.
+    pub async fn get_service_quota_with_metadata(
+        &self,
+        input: GetServiceQuotaRequest,
+    ) -> Result<(GetServiceQuotaResponse, ResponseMetadata), RusotoError<GetServiceQuotaError>>
+    {
+        let request = self.build_get_service_quota_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRequestedServiceQuotaChangeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetServiceQuotaResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRequestedServiceQuotaChangeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetServiceQuotaError::from_response(response))
         }
     }
 
-    /// <p>Returns the details for the specified service quota. This operation provides a different Value than the <code>GetAWSDefaultServiceQuota</code> operation. This operation returns the applied value for each quota. <code>GetAWSDefaultServiceQuota</code> returns the default AWS value for each quota. </p>
-    async fn get_service_quota(
+    /// Presigns the request `get_service_quota` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_service_quota(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetServiceQuotaRequest,
-    ) -> Result<GetServiceQuotaResponse, RusotoError<GetServiceQuotaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_service_quota_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_service_quota_request(&self, input: GetServiceQuotaRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetServiceQuota");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ServiceQuotasV20190624.GetServiceQuota");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_service_quota_increase_request_from_template`, also returning the metadata of the response.
+    pub async fn get_service_quota_increase_request_from_template_with_metadata(
+        &self,
+        input: GetServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> Result<
+        (
+            GetServiceQuotaIncreaseRequestFromTemplateResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetServiceQuotaIncreaseRequestFromTemplateError>,
+    > {
+        let request = self.build_get_service_quota_increase_request_from_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetServiceQuotaResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetServiceQuotaIncreaseRequestFromTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetServiceQuotaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetServiceQuotaIncreaseRequestFromTemplateError::from_response(response))
         }
     }
 
-    /// <p>Returns the details of the service quota increase request in your template.</p>
-    async fn get_service_quota_increase_request_from_template(
+    /// Presigns the request `get_service_quota_increase_request_from_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_service_quota_increase_request_from_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetServiceQuotaIncreaseRequestFromTemplateRequest,
-    ) -> Result<
-        GetServiceQuotaIncreaseRequestFromTemplateResponse,
-        RusotoError<GetServiceQuotaIncreaseRequestFromTemplateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_service_quota_increase_request_from_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_service_quota_increase_request_from_template_request(
+        &self,
+        input: GetServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("GetServiceQuotaIncreaseRequestFromTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2450,29 +2953,58 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_aws_default_service_quotas`, also returning the metadata of the response.
+    pub async fn list_aws_default_service_quotas_with_metadata(
+        &self,
+        input: ListAWSDefaultServiceQuotasRequest,
+    ) -> Result<
+        (ListAWSDefaultServiceQuotasResponse, ResponseMetadata),
+        RusotoError<ListAWSDefaultServiceQuotasError>,
+    > {
+        let request = self.build_list_aws_default_service_quotas_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetServiceQuotaIncreaseRequestFromTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAWSDefaultServiceQuotasResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetServiceQuotaIncreaseRequestFromTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAWSDefaultServiceQuotasError::from_response(response))
         }
     }
 
-    /// <p><p>Lists all default service quotas for the specified AWS service or all AWS services. ListAWSDefaultServiceQuotas is similar to <a>ListServiceQuotas</a> except for the Value object. The Value object returned by <code>ListAWSDefaultServiceQuotas</code> is the default value assigned by AWS. This request returns a list of all service quotas for the specified service. The listing of each you&#39;ll see the default values are the values that AWS provides for the quotas. </p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can return an unexpected list of results, even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass the next call to the same API to request the next part of the list.</p> </note></p>
-    async fn list_aws_default_service_quotas(
+    /// Presigns the request `list_aws_default_service_quotas` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_aws_default_service_quotas(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAWSDefaultServiceQuotasRequest,
-    ) -> Result<ListAWSDefaultServiceQuotasResponse, RusotoError<ListAWSDefaultServiceQuotasError>>
-    {
-        let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_aws_default_service_quotas_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_aws_default_service_quotas_request(
+        &self,
+        input: ListAWSDefaultServiceQuotasRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListAWSDefaultServiceQuotas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2482,31 +3014,63 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_requested_service_quota_change_history`, also returning the metadata of the response.
+    pub async fn list_requested_service_quota_change_history_with_metadata(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryRequest,
+    ) -> Result<
+        (
+            ListRequestedServiceQuotaChangeHistoryResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
+    > {
+        let request = self.build_list_requested_service_quota_change_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAWSDefaultServiceQuotasResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRequestedServiceQuotaChangeHistoryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAWSDefaultServiceQuotasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRequestedServiceQuotaChangeHistoryError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Requests a list of the changes to quotas for a service.</p>
-    async fn list_requested_service_quota_change_history(
+    /// Presigns the request `list_requested_service_quota_change_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_requested_service_quota_change_history(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRequestedServiceQuotaChangeHistoryRequest,
-    ) -> Result<
-        ListRequestedServiceQuotaChangeHistoryResponse,
-        RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_requested_service_quota_change_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_requested_service_quota_change_history_request(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListRequestedServiceQuotaChangeHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2516,33 +3080,64 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_requested_service_quota_change_history_by_quota`, also returning the metadata of the response.
+    pub async fn list_requested_service_quota_change_history_by_quota_with_metadata(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+    ) -> Result<
+        (
+            ListRequestedServiceQuotaChangeHistoryByQuotaResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
+    > {
+        let request =
+            self.build_list_requested_service_quota_change_history_by_quota_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRequestedServiceQuotaChangeHistoryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRequestedServiceQuotaChangeHistoryByQuotaResponse, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRequestedServiceQuotaChangeHistoryError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRequestedServiceQuotaChangeHistoryByQuotaError::from_response(response))
         }
     }
 
-    /// <p>Requests a list of the changes to specific service quotas. This command provides additional granularity over the <code>ListRequestedServiceQuotaChangeHistory</code> command. Once a quota change request has reached <code>CASE_CLOSED, APPROVED,</code> or <code>DENIED</code>, the history has been kept for 90 days.</p>
-    async fn list_requested_service_quota_change_history_by_quota(
+    /// Presigns the request `list_requested_service_quota_change_history_by_quota` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_requested_service_quota_change_history_by_quota(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
-    ) -> Result<
-        ListRequestedServiceQuotaChangeHistoryByQuotaResponse,
-        RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_list_requested_service_quota_change_history_by_quota_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_requested_service_quota_change_history_by_quota_request(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListRequestedServiceQuotaChangeHistoryByQuota");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2552,31 +3147,61 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_service_quota_increase_requests_in_template`, also returning the metadata of the response.
+    pub async fn list_service_quota_increase_requests_in_template_with_metadata(
+        &self,
+        input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
+    ) -> Result<
+        (
+            ListServiceQuotaIncreaseRequestsInTemplateResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
+    > {
+        let request = self.build_list_service_quota_increase_requests_in_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRequestedServiceQuotaChangeHistoryByQuotaResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListServiceQuotaIncreaseRequestsInTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRequestedServiceQuotaChangeHistoryByQuotaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListServiceQuotaIncreaseRequestsInTemplateError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the quota increase requests in the template. </p>
-    async fn list_service_quota_increase_requests_in_template(
+    /// Presigns the request `list_service_quota_increase_requests_in_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_service_quota_increase_requests_in_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
-    ) -> Result<
-        ListServiceQuotaIncreaseRequestsInTemplateResponse,
-        RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_service_quota_increase_requests_in_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_service_quota_increase_requests_in_template_request(
+        &self,
+        input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListServiceQuotaIncreaseRequestsInTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2586,86 +3211,166 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_service_quotas`, also returning the metadata of the response.
+    pub async fn list_service_quotas_with_metadata(
+        &self,
+        input: ListServiceQuotasRequest,
+    ) -> Result<(ListServiceQuotasResponse, ResponseMetadata), RusotoError<ListServiceQuotasError>>
+    {
+        let request = self.build_list_service_quotas_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListServiceQuotaIncreaseRequestsInTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListServiceQuotasResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListServiceQuotaIncreaseRequestsInTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListServiceQuotasError::from_response(response))
         }
     }
 
-    /// <p><p>Lists all service quotas for the specified AWS service. This request returns a list of the service quotas for the specified service. you&#39;ll see the default values are the values that AWS provides for the quotas. </p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can return an unexpected list of results, even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass the next call to the same API to request the next part of the list.</p> </note></p>
-    async fn list_service_quotas(
+    /// Presigns the request `list_service_quotas` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_service_quotas(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListServiceQuotasRequest,
-    ) -> Result<ListServiceQuotasResponse, RusotoError<ListServiceQuotasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_service_quotas_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_service_quotas_request(&self, input: ListServiceQuotasRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListServiceQuotas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ServiceQuotasV20190624.ListServiceQuotas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_services`, also returning the metadata of the response.
+    pub async fn list_services_with_metadata(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<(ListServicesResponse, ResponseMetadata), RusotoError<ListServicesError>> {
+        let request = self.build_list_services_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListServiceQuotasResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListServicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListServiceQuotasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListServicesError::from_response(response))
         }
     }
 
-    /// <p>Lists the AWS services available in Service Quotas. Not all AWS services are available in Service Quotas. To list the see the list of the service quotas for a specific service, use <a>ListServiceQuotas</a>.</p>
-    async fn list_services(
+    /// Presigns the request `list_services` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_services(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListServicesRequest,
-    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_services_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_services_request(&self, input: ListServicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("ListServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ServiceQuotasV20190624.ListServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_service_quota_increase_request_into_template`, also returning the metadata of the response.
+    pub async fn put_service_quota_increase_request_into_template_with_metadata(
+        &self,
+        input: PutServiceQuotaIncreaseRequestIntoTemplateRequest,
+    ) -> Result<
+        (
+            PutServiceQuotaIncreaseRequestIntoTemplateResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<PutServiceQuotaIncreaseRequestIntoTemplateError>,
+    > {
+        let request = self.build_put_service_quota_increase_request_into_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListServicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutServiceQuotaIncreaseRequestIntoTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListServicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutServiceQuotaIncreaseRequestIntoTemplateError::from_response(response))
         }
     }
 
-    /// <p>Defines and adds a quota to the service quota template. To add a quota to the template, you must provide the <code>ServiceCode</code>, <code>QuotaCode</code>, <code>AwsRegion</code>, and <code>DesiredValue</code>. Once you add a quota to the template, use <a>ListServiceQuotaIncreaseRequestsInTemplate</a> to see the list of quotas in the template.</p>
-    async fn put_service_quota_increase_request_into_template(
+    /// Presigns the request `put_service_quota_increase_request_into_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_service_quota_increase_request_into_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutServiceQuotaIncreaseRequestIntoTemplateRequest,
-    ) -> Result<
-        PutServiceQuotaIncreaseRequestIntoTemplateResponse,
-        RusotoError<PutServiceQuotaIncreaseRequestIntoTemplateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_service_quota_increase_request_into_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_service_quota_increase_request_into_template_request(
+        &self,
+        input: PutServiceQuotaIncreaseRequestIntoTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("PutServiceQuotaIncreaseRequestIntoTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2675,29 +3380,58 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `request_service_quota_increase`, also returning the metadata of the response.
+    pub async fn request_service_quota_increase_with_metadata(
+        &self,
+        input: RequestServiceQuotaIncreaseRequest,
+    ) -> Result<
+        (RequestServiceQuotaIncreaseResponse, ResponseMetadata),
+        RusotoError<RequestServiceQuotaIncreaseError>,
+    > {
+        let request = self.build_request_service_quota_increase_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutServiceQuotaIncreaseRequestIntoTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RequestServiceQuotaIncreaseResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutServiceQuotaIncreaseRequestIntoTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RequestServiceQuotaIncreaseError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the details of a service quota increase request. The response to this command provides the details in the <a>RequestedServiceQuotaChange</a> object. </p>
-    async fn request_service_quota_increase(
+    /// Presigns the request `request_service_quota_increase` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_request_service_quota_increase(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RequestServiceQuotaIncreaseRequest,
-    ) -> Result<RequestServiceQuotaIncreaseResponse, RusotoError<RequestServiceQuotaIncreaseError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_request_service_quota_increase_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_request_service_quota_increase_request(
+        &self,
+        input: RequestServiceQuotaIncreaseRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicequotas", &self.region, "/");
+        request.set_operation("RequestServiceQuotaIncrease");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2707,19 +3441,469 @@ impl ServiceQuotas for ServiceQuotasClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAWSDefaultServiceQuotas` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_aws_default_service_quotas_pages(
+        &self,
+        input: ListAWSDefaultServiceQuotasRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListAWSDefaultServiceQuotasResponse,
+            RusotoError<ListAWSDefaultServiceQuotasError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input| async move {
+                client.list_aws_default_service_quotas(input).await
+            },
+            |input: &ListAWSDefaultServiceQuotasRequest,
+             output: &ListAWSDefaultServiceQuotasResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAWSDefaultServiceQuotasRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Quotas` of every page of `ListAWSDefaultServiceQuotas` results for `input`, as `list_aws_default_service_quotas_pages` requests them.
+    pub fn list_aws_default_service_quotas_stream(
+        &self,
+        input: ListAWSDefaultServiceQuotasRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ServiceQuota, RusotoError<ListAWSDefaultServiceQuotasError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_aws_default_service_quotas_pages(input), |page| {
+            page.quotas.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRequestedServiceQuotaChangeHistory` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_requested_service_quota_change_history_pages(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListRequestedServiceQuotaChangeHistoryResponse,
+            RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input| async move {
+                client
+                    .list_requested_service_quota_change_history(input)
+                    .await
+            },
+            |input: &ListRequestedServiceQuotaChangeHistoryRequest,
+             output: &ListRequestedServiceQuotaChangeHistoryResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRequestedServiceQuotaChangeHistoryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RequestedQuotas` of every page of `ListRequestedServiceQuotaChangeHistory` results for `input`, as `list_requested_service_quota_change_history_pages` requests them.
+    pub fn list_requested_service_quota_change_history_stream(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            RequestedServiceQuotaChange,
+            RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_requested_service_quota_change_history_pages(input),
+            |page| page.requested_quotas.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListRequestedServiceQuotaChangeHistoryByQuota` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_requested_service_quota_change_history_by_quota_pages(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListRequestedServiceQuotaChangeHistoryByQuotaResponse,
+            RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input| async move {
+                client
+                    .list_requested_service_quota_change_history_by_quota(input)
+                    .await
+            },
+            |input: &ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+             output: &ListRequestedServiceQuotaChangeHistoryByQuotaResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRequestedServiceQuotaChangeHistoryByQuotaRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RequestedQuotas` of every page of `ListRequestedServiceQuotaChangeHistoryByQuota` results for `input`, as `list_requested_service_quota_change_history_by_quota_pages` requests them.
+    pub fn list_requested_service_quota_change_history_by_quota_stream(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            RequestedServiceQuotaChange,
+            RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_requested_service_quota_change_history_by_quota_pages(input),
+            |page| page.requested_quotas.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListServiceQuotaIncreaseRequestsInTemplate` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_service_quota_increase_requests_in_template_pages(
+        &self,
+        input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListServiceQuotaIncreaseRequestsInTemplateResponse,
+            RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input| async move {
+                client
+                    .list_service_quota_increase_requests_in_template(input)
+                    .await
+            },
+            |input: &ListServiceQuotaIncreaseRequestsInTemplateRequest,
+             output: &ListServiceQuotaIncreaseRequestsInTemplateResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListServiceQuotaIncreaseRequestsInTemplateRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ServiceQuotaIncreaseRequestInTemplateList` of every page of `ListServiceQuotaIncreaseRequestsInTemplate` results for `input`, as `list_service_quota_increase_requests_in_template_pages` requests them.
+    pub fn list_service_quota_increase_requests_in_template_stream(
+        &self,
+        input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ServiceQuotaIncreaseRequestInTemplate,
+            RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_service_quota_increase_requests_in_template_pages(input),
+            |page| {
+                page.service_quota_increase_request_in_template_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `ListServiceQuotas` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_service_quotas_pages(
+        &self,
+        input: ListServiceQuotasRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListServiceQuotasResponse, RusotoError<ListServiceQuotasError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input| async move { client.list_service_quotas(input).await },
+            |input: &ListServiceQuotasRequest, output: &ListServiceQuotasResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListServiceQuotasRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Quotas` of every page of `ListServiceQuotas` results for `input`, as `list_service_quotas_pages` requests them.
+    pub fn list_service_quotas_stream(
+        &self,
+        input: ListServiceQuotasRequest,
+    ) -> impl futures::Stream<Item = Result<ServiceQuota, RusotoError<ListServiceQuotasError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_service_quotas_pages(input), |page| {
+            page.quotas.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListServices` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_services_pages(
+        &self,
+        input: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListServicesResponse, RusotoError<ListServicesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceQuotasClient, input| async move { client.list_services(input).await },
+            |input: &ListServicesRequest, output: &ListServicesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListServicesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Services` of every page of `ListServices` results for `input`, as `list_services_pages` requests them.
+    pub fn list_services_stream(
+        &self,
+        input: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ServiceInfo, RusotoError<ListServicesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_services_pages(input), |page| {
+            page.services.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ServiceQuotas for ServiceQuotasClient {
+    /// <p>Associates the Service Quotas template with your organization so that when new accounts are created in your organization, the template submits increase requests for the specified service quotas. Use the Service Quotas template to request an increase for any adjustable quota value. After you define the Service Quotas template, use this operation to associate, or enable, the template. </p>
+    async fn associate_service_quota_template(
+        &self,
+    ) -> Result<
+        AssociateServiceQuotaTemplateResponse,
+        RusotoError<AssociateServiceQuotaTemplateError>,
+    > {
+        self.associate_service_quota_template_with_metadata()
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RequestServiceQuotaIncreaseResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RequestServiceQuotaIncreaseError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a service quota increase request from the Service Quotas template. </p>
+    async fn delete_service_quota_increase_request_from_template(
+        &self,
+        input: DeleteServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> Result<
+        DeleteServiceQuotaIncreaseRequestFromTemplateResponse,
+        RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError>,
+    > {
+        self.delete_service_quota_increase_request_from_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Disables the Service Quotas template. Once the template is disabled, it does not request quota increases for new accounts in your organization. Disabling the quota template does not apply the quota increase requests from the template. </p> <p> <b>Related operations</b> </p> <ul> <li> <p>To enable the quota template, call <a>AssociateServiceQuotaTemplate</a>. </p> </li> <li> <p>To delete a specific service quota from the template, use <a>DeleteServiceQuotaIncreaseRequestFromTemplate</a>.</p> </li> </ul></p>
+    async fn disassociate_service_quota_template(
+        &self,
+    ) -> Result<
+        DisassociateServiceQuotaTemplateResponse,
+        RusotoError<DisassociateServiceQuotaTemplateError>,
+    > {
+        self.disassociate_service_quota_template_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the default service quotas values. The Value returned for each quota is the AWS default value, even if the quotas have been increased.. </p>
+    async fn get_aws_default_service_quota(
+        &self,
+        input: GetAWSDefaultServiceQuotaRequest,
+    ) -> Result<GetAWSDefaultServiceQuotaResponse, RusotoError<GetAWSDefaultServiceQuotaError>>
+    {
+        self.get_aws_default_service_quota_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the <code>ServiceQuotaTemplateAssociationStatus</code> value from the service. Use this action to determine if the Service Quota template is associated, or enabled. </p>
+    async fn get_association_for_service_quota_template(
+        &self,
+    ) -> Result<
+        GetAssociationForServiceQuotaTemplateResponse,
+        RusotoError<GetAssociationForServiceQuotaTemplateError>,
+    > {
+        self.get_association_for_service_quota_template_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the details for a particular increase request. </p>
+    async fn get_requested_service_quota_change(
+        &self,
+        input: GetRequestedServiceQuotaChangeRequest,
+    ) -> Result<
+        GetRequestedServiceQuotaChangeResponse,
+        RusotoError<GetRequestedServiceQuotaChangeError>,
+    > {
+        self.get_requested_service_quota_change_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details for the specified service quota. This operation provides a different Value than the <code>GetAWSDefaultServiceQuota</code> operation. This operation returns the applied value for each quota. <code>GetAWSDefaultServiceQuota</code> returns the default AWS value for each quota. </p>
+    async fn get_service_quota(
+        &self,
+        input: GetServiceQuotaRequest,
+    ) -> Result<GetServiceQuotaResponse, RusotoError<GetServiceQuotaError>> {
+        self.get_service_quota_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details of the service quota increase request in your template.</p>
+    async fn get_service_quota_increase_request_from_template(
+        &self,
+        input: GetServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> Result<
+        GetServiceQuotaIncreaseRequestFromTemplateResponse,
+        RusotoError<GetServiceQuotaIncreaseRequestFromTemplateError>,
+    > {
+        self.get_service_quota_increase_request_from_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists all default service quotas for the specified AWS service or all AWS services. ListAWSDefaultServiceQuotas is similar to <a>ListServiceQuotas</a> except for the Value object. The Value object returned by <code>ListAWSDefaultServiceQuotas</code> is the default value assigned by AWS. This request returns a list of all service quotas for the specified service. The listing of each you&#39;ll see the default values are the values that AWS provides for the quotas. </p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can return an unexpected list of results, even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass the next call to the same API to request the next part of the list.</p> </note></p>
+    async fn list_aws_default_service_quotas(
+        &self,
+        input: ListAWSDefaultServiceQuotasRequest,
+    ) -> Result<ListAWSDefaultServiceQuotasResponse, RusotoError<ListAWSDefaultServiceQuotasError>>
+    {
+        self.list_aws_default_service_quotas_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests a list of the changes to quotas for a service.</p>
+    async fn list_requested_service_quota_change_history(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryRequest,
+    ) -> Result<
+        ListRequestedServiceQuotaChangeHistoryResponse,
+        RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
+    > {
+        self.list_requested_service_quota_change_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests a list of the changes to specific service quotas. This command provides additional granularity over the <code>ListRequestedServiceQuotaChangeHistory</code> command. Once a quota change request has reached <code>CASE_CLOSED, APPROVED,</code> or <code>DENIED</code>, the history has been kept for 90 days.</p>
+    async fn list_requested_service_quota_change_history_by_quota(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+    ) -> Result<
+        ListRequestedServiceQuotaChangeHistoryByQuotaResponse,
+        RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
+    > {
+        self.list_requested_service_quota_change_history_by_quota_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the quota increase requests in the template. </p>
+    async fn list_service_quota_increase_requests_in_template(
+        &self,
+        input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
+    ) -> Result<
+        ListServiceQuotaIncreaseRequestsInTemplateResponse,
+        RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
+    > {
+        self.list_service_quota_increase_requests_in_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists all service quotas for the specified AWS service. This request returns a list of the service quotas for the specified service. you&#39;ll see the default values are the values that AWS provides for the quotas. </p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can return an unexpected list of results, even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass the next call to the same API to request the next part of the list.</p> </note></p>
+    async fn list_service_quotas(
+        &self,
+        input: ListServiceQuotasRequest,
+    ) -> Result<ListServiceQuotasResponse, RusotoError<ListServiceQuotasError>> {
+        self.list_service_quotas_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the AWS services available in Service Quotas. Not all AWS services are available in Service Quotas. To list the see the list of the service quotas for a specific service, use <a>ListServiceQuotas</a>.</p>
+    async fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+        self.list_services_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Defines and adds a quota to the service quota template. To add a quota to the template, you must provide the <code>ServiceCode</code>, <code>QuotaCode</code>, <code>AwsRegion</code>, and <code>DesiredValue</code>. Once you add a quota to the template, use <a>ListServiceQuotaIncreaseRequestsInTemplate</a> to see the list of quotas in the template.</p>
+    async fn put_service_quota_increase_request_into_template(
+        &self,
+        input: PutServiceQuotaIncreaseRequestIntoTemplateRequest,
+    ) -> Result<
+        PutServiceQuotaIncreaseRequestIntoTemplateResponse,
+        RusotoError<PutServiceQuotaIncreaseRequestIntoTemplateError>,
+    > {
+        self.put_service_quota_increase_request_into_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the details of a service quota increase request. The response to this command provides the details in the <a>RequestedServiceQuotaChange</a> object. </p>
+    async fn request_service_quota_increase(
+        &self,
+        input: RequestServiceQuotaIncreaseRequest,
+    ) -> Result<RequestServiceQuotaIncreaseResponse, RusotoError<RequestServiceQuotaIncreaseError>>
+    {
+        self.request_service_quota_increase_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3700,6 +3700,7 @@ pub struct UsageInstruction {
 
 /// Errors returned by AcceptPortfolioShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptPortfolioShareError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -3707,6 +3708,8 @@ pub enum AcceptPortfolioShareError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptPortfolioShareError {
@@ -3714,20 +3717,25 @@ impl AcceptPortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(AcceptPortfolioShareError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptPortfolioShareError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AcceptPortfolioShareError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        AcceptPortfolioShareError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptPortfolioShareError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptPortfolioShareError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AcceptPortfolioShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3740,12 +3748,14 @@ impl fmt::Display for AcceptPortfolioShareError {
             AcceptPortfolioShareError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             AcceptPortfolioShareError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             AcceptPortfolioShareError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AcceptPortfolioShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcceptPortfolioShareError {}
 /// Errors returned by AssociateBudgetWithResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateBudgetWithResourceError {
     /// <p>The specified resource is a duplicate.</p>
     DuplicateResource(String),
@@ -3755,6 +3765,8 @@ pub enum AssociateBudgetWithResourceError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateBudgetWithResourceError {
@@ -3764,27 +3776,36 @@ impl AssociateBudgetWithResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateBudgetWithResourceError::DuplicateResource(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateBudgetWithResourceError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateBudgetWithResourceError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateBudgetWithResourceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateBudgetWithResourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateBudgetWithResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3802,12 +3823,14 @@ impl fmt::Display for AssociateBudgetWithResourceError {
             }
             AssociateBudgetWithResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             AssociateBudgetWithResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssociateBudgetWithResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateBudgetWithResourceError {}
 /// Errors returned by AssociatePrincipalWithPortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociatePrincipalWithPortfolioError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -3815,6 +3838,8 @@ pub enum AssociatePrincipalWithPortfolioError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociatePrincipalWithPortfolioError {
@@ -3824,22 +3849,30 @@ impl AssociatePrincipalWithPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePrincipalWithPortfolioError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePrincipalWithPortfolioError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePrincipalWithPortfolioError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociatePrincipalWithPortfolioError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3858,12 +3891,16 @@ impl fmt::Display for AssociatePrincipalWithPortfolioError {
             AssociatePrincipalWithPortfolioError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociatePrincipalWithPortfolioError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociatePrincipalWithPortfolioError {}
 /// Errors returned by AssociateProductWithPortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateProductWithPortfolioError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -3871,6 +3908,8 @@ pub enum AssociateProductWithPortfolioError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateProductWithPortfolioError {
@@ -3880,22 +3919,30 @@ impl AssociateProductWithPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateProductWithPortfolioError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateProductWithPortfolioError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateProductWithPortfolioError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateProductWithPortfolioError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateProductWithPortfolioError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3912,12 +3959,16 @@ impl fmt::Display for AssociateProductWithPortfolioError {
             AssociateProductWithPortfolioError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateProductWithPortfolioError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateProductWithPortfolioError {}
 /// Errors returned by AssociateServiceActionWithProvisioningArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateServiceActionWithProvisioningArtifactError {
     /// <p>The specified resource is a duplicate.</p>
     DuplicateResource(String),
@@ -3925,6 +3976,8 @@ pub enum AssociateServiceActionWithProvisioningArtifactError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateServiceActionWithProvisioningArtifactError {
@@ -3934,26 +3987,34 @@ impl AssociateServiceActionWithProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceActionWithProvisioningArtifactError::DuplicateResource(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceActionWithProvisioningArtifactError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateServiceActionWithProvisioningArtifactError::ResourceNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateServiceActionWithProvisioningArtifactError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3972,12 +4033,16 @@ impl fmt::Display for AssociateServiceActionWithProvisioningArtifactError {
             AssociateServiceActionWithProvisioningArtifactError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateServiceActionWithProvisioningArtifactError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateServiceActionWithProvisioningArtifactError {}
 /// Errors returned by AssociateTagOptionWithResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateTagOptionWithResourceError {
     /// <p>The specified resource is a duplicate.</p>
     DuplicateResource(String),
@@ -3991,6 +4056,8 @@ pub enum AssociateTagOptionWithResourceError {
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateTagOptionWithResourceError {
@@ -4000,37 +4067,48 @@ impl AssociateTagOptionWithResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateTagOptionWithResourceError::DuplicateResource(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateTagOptionWithResourceError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(AssociateTagOptionWithResourceError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateTagOptionWithResourceError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateTagOptionWithResourceError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateTagOptionWithResourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateTagOptionWithResourceError::TagOptionNotMigrated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateTagOptionWithResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4054,15 +4132,21 @@ impl fmt::Display for AssociateTagOptionWithResourceError {
             AssociateTagOptionWithResourceError::TagOptionNotMigrated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateTagOptionWithResourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateTagOptionWithResourceError {}
 /// Errors returned by BatchAssociateServiceActionWithProvisioningArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchAssociateServiceActionWithProvisioningArtifactError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchAssociateServiceActionWithProvisioningArtifactError {
@@ -4072,14 +4156,20 @@ impl BatchAssociateServiceActionWithProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchAssociateServiceActionWithProvisioningArtifactError::InvalidParameters(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        BatchAssociateServiceActionWithProvisioningArtifactError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4092,15 +4182,21 @@ impl fmt::Display for BatchAssociateServiceActionWithProvisioningArtifactError {
             BatchAssociateServiceActionWithProvisioningArtifactError::InvalidParameters(
                 ref cause,
             ) => write!(f, "{}", cause),
+            BatchAssociateServiceActionWithProvisioningArtifactError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for BatchAssociateServiceActionWithProvisioningArtifactError {}
 /// Errors returned by BatchDisassociateServiceActionFromProvisioningArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDisassociateServiceActionFromProvisioningArtifactError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDisassociateServiceActionFromProvisioningArtifactError {
@@ -4109,13 +4205,19 @@ impl BatchDisassociateServiceActionFromProvisioningArtifactError {
     ) -> RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "InvalidParametersException" => return RusotoError::Service(
+                "InvalidParametersException" => return RusotoError::service(
                     BatchDisassociateServiceActionFromProvisioningArtifactError::InvalidParameters(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        BatchDisassociateServiceActionFromProvisioningArtifactError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4128,17 +4230,23 @@ impl fmt::Display for BatchDisassociateServiceActionFromProvisioningArtifactErro
             BatchDisassociateServiceActionFromProvisioningArtifactError::InvalidParameters(
                 ref cause,
             ) => write!(f, "{}", cause),
+            BatchDisassociateServiceActionFromProvisioningArtifactError::Unhandled(
+                ref metadata,
+            ) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDisassociateServiceActionFromProvisioningArtifactError {}
 /// Errors returned by CopyProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyProductError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyProductError {
@@ -4146,13 +4254,13 @@ impl CopyProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CopyProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(CopyProductError::InvalidParameters(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CopyProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CopyProductError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CopyProductError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4164,12 +4272,14 @@ impl fmt::Display for CopyProductError {
         match *self {
             CopyProductError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             CopyProductError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CopyProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyProductError {}
 /// Errors returned by CreateConstraint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConstraintError {
     /// <p>The specified resource is a duplicate.</p>
     DuplicateResource(String),
@@ -4179,6 +4289,8 @@ pub enum CreateConstraintError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConstraintError {
@@ -4186,19 +4298,31 @@ impl CreateConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateConstraintError::DuplicateResource(err.msg))
+                    return RusotoError::service(
+                        CreateConstraintError::DuplicateResource(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreateConstraintError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        CreateConstraintError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConstraintError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateConstraintError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateConstraintError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConstraintError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4212,12 +4336,14 @@ impl fmt::Display for CreateConstraintError {
             CreateConstraintError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             CreateConstraintError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateConstraintError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateConstraintError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConstraintError {}
 /// Errors returned by CreatePortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePortfolioError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -4225,6 +4351,8 @@ pub enum CreatePortfolioError {
     LimitExceeded(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePortfolioError {
@@ -4232,18 +4360,22 @@ impl CreatePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreatePortfolioError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        CreatePortfolioError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePortfolioError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreatePortfolioError::LimitExceeded(err.msg), &res)
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(CreatePortfolioError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePortfolioError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePortfolioError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4256,12 +4388,14 @@ impl fmt::Display for CreatePortfolioError {
             CreatePortfolioError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             CreatePortfolioError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreatePortfolioError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            CreatePortfolioError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePortfolioError {}
 /// Errors returned by CreatePortfolioShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePortfolioShareError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -4273,6 +4407,8 @@ pub enum CreatePortfolioShareError {
     OperationNotSupported(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePortfolioShareError {
@@ -4280,28 +4416,37 @@ impl CreatePortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePortfolioShareError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        CreatePortfolioShareError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreatePortfolioShareError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::OperationNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePortfolioShareError::OperationNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePortfolioShareError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePortfolioShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4316,12 +4461,14 @@ impl fmt::Display for CreatePortfolioShareError {
             CreatePortfolioShareError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreatePortfolioShareError::OperationNotSupported(ref cause) => write!(f, "{}", cause),
             CreatePortfolioShareError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreatePortfolioShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePortfolioShareError {}
 /// Errors returned by CreateProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProductError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -4329,6 +4476,8 @@ pub enum CreateProductError {
     LimitExceeded(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProductError {
@@ -4336,16 +4485,22 @@ impl CreateProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreateProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        CreateProductError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProductError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateProductError::LimitExceeded(err.msg), &res)
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(CreateProductError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service(
+                        CreateProductError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProductError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4358,12 +4513,14 @@ impl fmt::Display for CreateProductError {
             CreateProductError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             CreateProductError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateProductError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            CreateProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProductError {}
 /// Errors returned by CreateProvisionedProductPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProvisionedProductPlanError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -4371,6 +4528,8 @@ pub enum CreateProvisionedProductPlanError {
     InvalidState(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProvisionedProductPlanError {
@@ -4380,22 +4539,30 @@ impl CreateProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateProvisionedProductPlanError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreateProvisionedProductPlanError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProvisionedProductPlanError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateProvisionedProductPlanError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateProvisionedProductPlanError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4412,12 +4579,14 @@ impl fmt::Display for CreateProvisionedProductPlanError {
             CreateProvisionedProductPlanError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateProvisionedProductPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProvisionedProductPlanError {}
 /// Errors returned by CreateProvisioningArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProvisioningArtifactError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -4425,6 +4594,8 @@ pub enum CreateProvisioningArtifactError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProvisioningArtifactError {
@@ -4434,22 +4605,27 @@ impl CreateProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateProvisioningArtifactError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProvisioningArtifactError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProvisioningArtifactError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProvisioningArtifactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProvisioningArtifactError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateProvisioningArtifactError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4462,17 +4638,21 @@ impl fmt::Display for CreateProvisioningArtifactError {
             CreateProvisioningArtifactError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             CreateProvisioningArtifactError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateProvisioningArtifactError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateProvisioningArtifactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProvisioningArtifactError {}
 /// Errors returned by CreateServiceAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateServiceActionError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The current limits of the service would have been exceeded by this operation. Decrease your resource use or increase your service limits and retry the operation.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateServiceActionError {
@@ -4480,15 +4660,19 @@ impl CreateServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreateServiceActionError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateServiceActionError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateServiceActionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateServiceActionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateServiceActionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4500,12 +4684,14 @@ impl fmt::Display for CreateServiceActionError {
         match *self {
             CreateServiceActionError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             CreateServiceActionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateServiceActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateServiceActionError {}
 /// Errors returned by CreateTagOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTagOptionError {
     /// <p>The specified resource is a duplicate.</p>
     DuplicateResource(String),
@@ -4513,6 +4699,8 @@ pub enum CreateTagOptionError {
     LimitExceeded(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTagOptionError {
@@ -4520,18 +4708,22 @@ impl CreateTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateTagOptionError::DuplicateResource(err.msg))
+                    return RusotoError::service(
+                        CreateTagOptionError::DuplicateResource(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTagOptionError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateTagOptionError::LimitExceeded(err.msg), &res)
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(CreateTagOptionError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTagOptionError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTagOptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4544,17 +4736,21 @@ impl fmt::Display for CreateTagOptionError {
             CreateTagOptionError::DuplicateResource(ref cause) => write!(f, "{}", cause),
             CreateTagOptionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateTagOptionError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            CreateTagOptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTagOptionError {}
 /// Errors returned by DeleteConstraint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConstraintError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConstraintError {
@@ -4562,13 +4758,19 @@ impl DeleteConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeleteConstraintError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        DeleteConstraintError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteConstraintError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConstraintError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4580,12 +4782,14 @@ impl fmt::Display for DeleteConstraintError {
         match *self {
             DeleteConstraintError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             DeleteConstraintError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteConstraintError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConstraintError {}
 /// Errors returned by DeletePortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePortfolioError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -4595,6 +4799,8 @@ pub enum DeletePortfolioError {
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePortfolioError {
@@ -4602,21 +4808,28 @@ impl DeletePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeletePortfolioError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        DeletePortfolioError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePortfolioError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeletePortfolioError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePortfolioError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeletePortfolioError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DeletePortfolioError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePortfolioError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePortfolioError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4630,12 +4843,14 @@ impl fmt::Display for DeletePortfolioError {
             DeletePortfolioError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeletePortfolioError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeletePortfolioError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            DeletePortfolioError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePortfolioError {}
 /// Errors returned by DeletePortfolioShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePortfolioShareError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -4645,6 +4860,8 @@ pub enum DeletePortfolioShareError {
     OperationNotSupported(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePortfolioShareError {
@@ -4652,25 +4869,31 @@ impl DeletePortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePortfolioShareError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        DeletePortfolioShareError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::OperationNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePortfolioShareError::OperationNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePortfolioShareError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePortfolioShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4684,12 +4907,14 @@ impl fmt::Display for DeletePortfolioShareError {
             DeletePortfolioShareError::InvalidState(ref cause) => write!(f, "{}", cause),
             DeletePortfolioShareError::OperationNotSupported(ref cause) => write!(f, "{}", cause),
             DeletePortfolioShareError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeletePortfolioShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePortfolioShareError {}
 /// Errors returned by DeleteProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProductError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -4699,6 +4924,8 @@ pub enum DeleteProductError {
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProductError {
@@ -4706,19 +4933,28 @@ impl DeleteProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeleteProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        DeleteProductError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProductError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteProductError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteProductError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DeleteProductError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service(
+                        DeleteProductError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteProductError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4732,17 +4968,21 @@ impl fmt::Display for DeleteProductError {
             DeleteProductError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteProductError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteProductError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            DeleteProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProductError {}
 /// Errors returned by DeleteProvisionedProductPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProvisionedProductPlanError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProvisionedProductPlanError {
@@ -4752,17 +4992,24 @@ impl DeleteProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProvisionedProductPlanError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProvisionedProductPlanError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteProvisionedProductPlanError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4778,12 +5025,14 @@ impl fmt::Display for DeleteProvisionedProductPlanError {
             DeleteProvisionedProductPlanError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteProvisionedProductPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProvisionedProductPlanError {}
 /// Errors returned by DeleteProvisioningArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProvisioningArtifactError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -4791,6 +5040,8 @@ pub enum DeleteProvisioningArtifactError {
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProvisioningArtifactError {
@@ -4800,22 +5051,27 @@ impl DeleteProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteProvisioningArtifactError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProvisioningArtifactError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProvisioningArtifactError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProvisioningArtifactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProvisioningArtifactError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteProvisioningArtifactError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4828,17 +5084,21 @@ impl fmt::Display for DeleteProvisioningArtifactError {
             DeleteProvisioningArtifactError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             DeleteProvisioningArtifactError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteProvisioningArtifactError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteProvisioningArtifactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProvisioningArtifactError {}
 /// Errors returned by DeleteServiceAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteServiceActionError {
     /// <p>A resource that is currently in use. Ensure that the resource is not in use and retry the operation.</p>
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteServiceActionError {
@@ -4846,15 +5106,19 @@ impl DeleteServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteServiceActionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteServiceActionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteServiceActionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteServiceActionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteServiceActionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4866,12 +5130,14 @@ impl fmt::Display for DeleteServiceActionError {
         match *self {
             DeleteServiceActionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteServiceActionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteServiceActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteServiceActionError {}
 /// Errors returned by DeleteTagOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTagOptionError {
     /// <p>A resource that is currently in use. Ensure that the resource is not in use and retry the operation.</p>
     ResourceInUse(String),
@@ -4879,6 +5145,8 @@ pub enum DeleteTagOptionError {
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTagOptionError {
@@ -4886,18 +5154,22 @@ impl DeleteTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteTagOptionError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteTagOptionError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagOptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteTagOptionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DeleteTagOptionError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTagOptionError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTagOptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4910,15 +5182,19 @@ impl fmt::Display for DeleteTagOptionError {
             DeleteTagOptionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteTagOptionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTagOptionError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            DeleteTagOptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTagOptionError {}
 /// Errors returned by DescribeConstraint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConstraintError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConstraintError {
@@ -4926,10 +5202,13 @@ impl DescribeConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeConstraintError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeConstraintError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4940,15 +5219,19 @@ impl fmt::Display for DescribeConstraintError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeConstraintError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeConstraintError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConstraintError {}
 /// Errors returned by DescribeCopyProductStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCopyProductStatusError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCopyProductStatusError {
@@ -4956,12 +5239,15 @@ impl DescribeCopyProductStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCopyProductStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCopyProductStatusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeCopyProductStatusError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4972,15 +5258,19 @@ impl fmt::Display for DescribeCopyProductStatusError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeCopyProductStatusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeCopyProductStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCopyProductStatusError {}
 /// Errors returned by DescribePortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePortfolioError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePortfolioError {
@@ -4988,10 +5278,13 @@ impl DescribePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePortfolioError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribePortfolioError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePortfolioError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5002,12 +5295,14 @@ impl fmt::Display for DescribePortfolioError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribePortfolioError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribePortfolioError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePortfolioError {}
 /// Errors returned by DescribePortfolioShareStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePortfolioShareStatusError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -5015,6 +5310,8 @@ pub enum DescribePortfolioShareStatusError {
     OperationNotSupported(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePortfolioShareStatusError {
@@ -5024,22 +5321,30 @@ impl DescribePortfolioShareStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePortfolioShareStatusError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePortfolioShareStatusError::OperationNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePortfolioShareStatusError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribePortfolioShareStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5058,17 +5363,21 @@ impl fmt::Display for DescribePortfolioShareStatusError {
             DescribePortfolioShareStatusError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribePortfolioShareStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePortfolioShareStatusError {}
 /// Errors returned by DescribeProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProductError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProductError {
@@ -5076,13 +5385,19 @@ impl DescribeProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DescribeProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        DescribeProductError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeProductError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProductError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5094,15 +5409,19 @@ impl fmt::Display for DescribeProductError {
         match *self {
             DescribeProductError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             DescribeProductError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProductError {}
 /// Errors returned by DescribeProductAsAdmin
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProductAsAdminError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProductAsAdminError {
@@ -5110,12 +5429,13 @@ impl DescribeProductAsAdminError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProductAsAdminError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProductAsAdminError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProductAsAdminError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5126,17 +5446,21 @@ impl fmt::Display for DescribeProductAsAdminError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeProductAsAdminError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProductAsAdminError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProductAsAdminError {}
 /// Errors returned by DescribeProductView
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProductViewError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProductViewError {
@@ -5144,17 +5468,19 @@ impl DescribeProductViewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DescribeProductViewError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProductViewError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProductViewError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProductViewError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProductViewError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5166,15 +5492,19 @@ impl fmt::Display for DescribeProductViewError {
         match *self {
             DescribeProductViewError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             DescribeProductViewError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProductViewError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProductViewError {}
 /// Errors returned by DescribeProvisionedProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProvisionedProductError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProvisionedProductError {
@@ -5184,12 +5514,15 @@ impl DescribeProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProvisionedProductError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProvisionedProductError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeProvisionedProductError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5200,17 +5533,21 @@ impl fmt::Display for DescribeProvisionedProductError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeProvisionedProductError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProvisionedProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProvisionedProductError {}
 /// Errors returned by DescribeProvisionedProductPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProvisionedProductPlanError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProvisionedProductPlanError {
@@ -5220,17 +5557,24 @@ impl DescribeProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProvisionedProductPlanError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProvisionedProductPlanError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeProvisionedProductPlanError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5246,15 +5590,21 @@ impl fmt::Display for DescribeProvisionedProductPlanError {
             DescribeProvisionedProductPlanError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeProvisionedProductPlanError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeProvisionedProductPlanError {}
 /// Errors returned by DescribeProvisioningArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProvisioningArtifactError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProvisioningArtifactError {
@@ -5264,12 +5614,18 @@ impl DescribeProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProvisioningArtifactError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeProvisioningArtifactError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5282,17 +5638,21 @@ impl fmt::Display for DescribeProvisioningArtifactError {
             DescribeProvisioningArtifactError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeProvisioningArtifactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProvisioningArtifactError {}
 /// Errors returned by DescribeProvisioningParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProvisioningParametersError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProvisioningParametersError {
@@ -5302,17 +5662,24 @@ impl DescribeProvisioningParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProvisioningParametersError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProvisioningParametersError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeProvisioningParametersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5328,15 +5695,21 @@ impl fmt::Display for DescribeProvisioningParametersError {
             DescribeProvisioningParametersError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeProvisioningParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeProvisioningParametersError {}
 /// Errors returned by DescribeRecord
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRecordError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRecordError {
@@ -5344,10 +5717,13 @@ impl DescribeRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeRecordError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRecordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5358,15 +5734,19 @@ impl fmt::Display for DescribeRecordError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeRecordError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeRecordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRecordError {}
 /// Errors returned by DescribeServiceAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServiceActionError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServiceActionError {
@@ -5374,12 +5754,13 @@ impl DescribeServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServiceActionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeServiceActionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeServiceActionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5390,17 +5771,21 @@ impl fmt::Display for DescribeServiceActionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeServiceActionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeServiceActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeServiceActionError {}
 /// Errors returned by DescribeServiceActionExecutionParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServiceActionExecutionParametersError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServiceActionExecutionParametersError {
@@ -5410,17 +5795,24 @@ impl DescribeServiceActionExecutionParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeServiceActionExecutionParametersError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeServiceActionExecutionParametersError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeServiceActionExecutionParametersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5436,17 +5828,23 @@ impl fmt::Display for DescribeServiceActionExecutionParametersError {
             DescribeServiceActionExecutionParametersError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeServiceActionExecutionParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeServiceActionExecutionParametersError {}
 /// Errors returned by DescribeTagOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTagOptionError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTagOptionError {
@@ -5454,15 +5852,19 @@ impl DescribeTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagOptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeTagOptionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DescribeTagOptionError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTagOptionError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTagOptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5474,12 +5876,14 @@ impl fmt::Display for DescribeTagOptionError {
         match *self {
             DescribeTagOptionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTagOptionError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            DescribeTagOptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTagOptionError {}
 /// Errors returned by DisableAWSOrganizationsAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableAWSOrganizationsAccessError {
     /// <p>An attempt was made to modify a resource that is in a state that is not valid. Check your resources to ensure that they are in valid states before retrying the operation.</p>
     InvalidState(String),
@@ -5487,6 +5891,8 @@ pub enum DisableAWSOrganizationsAccessError {
     OperationNotSupported(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableAWSOrganizationsAccessError {
@@ -5496,22 +5902,30 @@ impl DisableAWSOrganizationsAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DisableAWSOrganizationsAccessError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableAWSOrganizationsAccessError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableAWSOrganizationsAccessError::OperationNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisableAWSOrganizationsAccessError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisableAWSOrganizationsAccessError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5528,15 +5942,21 @@ impl fmt::Display for DisableAWSOrganizationsAccessError {
             DisableAWSOrganizationsAccessError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisableAWSOrganizationsAccessError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisableAWSOrganizationsAccessError {}
 /// Errors returned by DisassociateBudgetFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateBudgetFromResourceError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateBudgetFromResourceError {
@@ -5546,12 +5966,18 @@ impl DisassociateBudgetFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateBudgetFromResourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateBudgetFromResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5564,17 +5990,23 @@ impl fmt::Display for DisassociateBudgetFromResourceError {
             DisassociateBudgetFromResourceError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateBudgetFromResourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateBudgetFromResourceError {}
 /// Errors returned by DisassociatePrincipalFromPortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociatePrincipalFromPortfolioError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociatePrincipalFromPortfolioError {
@@ -5584,17 +6016,24 @@ impl DisassociatePrincipalFromPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePrincipalFromPortfolioError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePrincipalFromPortfolioError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociatePrincipalFromPortfolioError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5610,12 +6049,16 @@ impl fmt::Display for DisassociatePrincipalFromPortfolioError {
             DisassociatePrincipalFromPortfolioError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociatePrincipalFromPortfolioError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociatePrincipalFromPortfolioError {}
 /// Errors returned by DisassociateProductFromPortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateProductFromPortfolioError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -5623,6 +6066,8 @@ pub enum DisassociateProductFromPortfolioError {
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateProductFromPortfolioError {
@@ -5632,22 +6077,30 @@ impl DisassociateProductFromPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateProductFromPortfolioError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateProductFromPortfolioError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateProductFromPortfolioError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateProductFromPortfolioError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5666,15 +6119,21 @@ impl fmt::Display for DisassociateProductFromPortfolioError {
             DisassociateProductFromPortfolioError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateProductFromPortfolioError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateProductFromPortfolioError {}
 /// Errors returned by DisassociateServiceActionFromProvisioningArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateServiceActionFromProvisioningArtifactError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateServiceActionFromProvisioningArtifactError {
@@ -5684,14 +6143,20 @@ impl DisassociateServiceActionFromProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateServiceActionFromProvisioningArtifactError::ResourceNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateServiceActionFromProvisioningArtifactError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5704,17 +6169,23 @@ impl fmt::Display for DisassociateServiceActionFromProvisioningArtifactError {
             DisassociateServiceActionFromProvisioningArtifactError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateServiceActionFromProvisioningArtifactError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateServiceActionFromProvisioningArtifactError {}
 /// Errors returned by DisassociateTagOptionFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateTagOptionFromResourceError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateTagOptionFromResourceError {
@@ -5724,17 +6195,24 @@ impl DisassociateTagOptionFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateTagOptionFromResourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateTagOptionFromResourceError::TagOptionNotMigrated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateTagOptionFromResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5750,12 +6228,16 @@ impl fmt::Display for DisassociateTagOptionFromResourceError {
             DisassociateTagOptionFromResourceError::TagOptionNotMigrated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateTagOptionFromResourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateTagOptionFromResourceError {}
 /// Errors returned by EnableAWSOrganizationsAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableAWSOrganizationsAccessError {
     /// <p>An attempt was made to modify a resource that is in a state that is not valid. Check your resources to ensure that they are in valid states before retrying the operation.</p>
     InvalidState(String),
@@ -5763,6 +6245,8 @@ pub enum EnableAWSOrganizationsAccessError {
     OperationNotSupported(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableAWSOrganizationsAccessError {
@@ -5772,22 +6256,30 @@ impl EnableAWSOrganizationsAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(EnableAWSOrganizationsAccessError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableAWSOrganizationsAccessError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableAWSOrganizationsAccessError::OperationNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EnableAWSOrganizationsAccessError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        EnableAWSOrganizationsAccessError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5804,12 +6296,14 @@ impl fmt::Display for EnableAWSOrganizationsAccessError {
             EnableAWSOrganizationsAccessError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            EnableAWSOrganizationsAccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableAWSOrganizationsAccessError {}
 /// Errors returned by ExecuteProvisionedProductPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExecuteProvisionedProductPlanError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -5817,6 +6311,8 @@ pub enum ExecuteProvisionedProductPlanError {
     InvalidState(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExecuteProvisionedProductPlanError {
@@ -5826,22 +6322,30 @@ impl ExecuteProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ExecuteProvisionedProductPlanError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ExecuteProvisionedProductPlanError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExecuteProvisionedProductPlanError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ExecuteProvisionedProductPlanError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ExecuteProvisionedProductPlanError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5858,12 +6362,16 @@ impl fmt::Display for ExecuteProvisionedProductPlanError {
             ExecuteProvisionedProductPlanError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ExecuteProvisionedProductPlanError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ExecuteProvisionedProductPlanError {}
 /// Errors returned by ExecuteProvisionedProductServiceAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExecuteProvisionedProductServiceActionError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -5871,6 +6379,8 @@ pub enum ExecuteProvisionedProductServiceActionError {
     InvalidState(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExecuteProvisionedProductServiceActionError {
@@ -5880,22 +6390,30 @@ impl ExecuteProvisionedProductServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ExecuteProvisionedProductServiceActionError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ExecuteProvisionedProductServiceActionError::InvalidState(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ExecuteProvisionedProductServiceActionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ExecuteProvisionedProductServiceActionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5914,17 +6432,23 @@ impl fmt::Display for ExecuteProvisionedProductServiceActionError {
             ExecuteProvisionedProductServiceActionError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ExecuteProvisionedProductServiceActionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ExecuteProvisionedProductServiceActionError {}
 /// Errors returned by GetAWSOrganizationsAccessStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAWSOrganizationsAccessStatusError {
     /// <p>The operation is not supported.</p>
     OperationNotSupported(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAWSOrganizationsAccessStatusError {
@@ -5934,17 +6458,24 @@ impl GetAWSOrganizationsAccessStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAWSOrganizationsAccessStatusError::OperationNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAWSOrganizationsAccessStatusError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetAWSOrganizationsAccessStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5960,17 +6491,23 @@ impl fmt::Display for GetAWSOrganizationsAccessStatusError {
             GetAWSOrganizationsAccessStatusError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetAWSOrganizationsAccessStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetAWSOrganizationsAccessStatusError {}
 /// Errors returned by ListAcceptedPortfolioShares
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAcceptedPortfolioSharesError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The operation is not supported.</p>
     OperationNotSupported(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAcceptedPortfolioSharesError {
@@ -5980,17 +6517,24 @@ impl ListAcceptedPortfolioSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAcceptedPortfolioSharesError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAcceptedPortfolioSharesError::OperationNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListAcceptedPortfolioSharesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6006,17 +6550,21 @@ impl fmt::Display for ListAcceptedPortfolioSharesError {
             ListAcceptedPortfolioSharesError::OperationNotSupported(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListAcceptedPortfolioSharesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAcceptedPortfolioSharesError {}
 /// Errors returned by ListBudgetsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBudgetsForResourceError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBudgetsForResourceError {
@@ -6024,17 +6572,19 @@ impl ListBudgetsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListBudgetsForResourceError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBudgetsForResourceError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBudgetsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBudgetsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBudgetsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6046,17 +6596,21 @@ impl fmt::Display for ListBudgetsForResourceError {
         match *self {
             ListBudgetsForResourceError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             ListBudgetsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListBudgetsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBudgetsForResourceError {}
 /// Errors returned by ListConstraintsForPortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConstraintsForPortfolioError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConstraintsForPortfolioError {
@@ -6066,17 +6620,24 @@ impl ListConstraintsForPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListConstraintsForPortfolioError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListConstraintsForPortfolioError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListConstraintsForPortfolioError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6090,17 +6651,21 @@ impl fmt::Display for ListConstraintsForPortfolioError {
                 write!(f, "{}", cause)
             }
             ListConstraintsForPortfolioError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListConstraintsForPortfolioError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConstraintsForPortfolioError {}
 /// Errors returned by ListLaunchPaths
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLaunchPathsError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLaunchPathsError {
@@ -6108,13 +6673,19 @@ impl ListLaunchPathsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListLaunchPathsError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        ListLaunchPathsError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLaunchPathsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListLaunchPathsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLaunchPathsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6126,12 +6697,14 @@ impl fmt::Display for ListLaunchPathsError {
         match *self {
             ListLaunchPathsError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             ListLaunchPathsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListLaunchPathsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLaunchPathsError {}
 /// Errors returned by ListOrganizationPortfolioAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOrganizationPortfolioAccessError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -6139,6 +6712,8 @@ pub enum ListOrganizationPortfolioAccessError {
     OperationNotSupported(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOrganizationPortfolioAccessError {
@@ -6148,22 +6723,30 @@ impl ListOrganizationPortfolioAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOrganizationPortfolioAccessError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOrganizationPortfolioAccessError::OperationNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOrganizationPortfolioAccessError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListOrganizationPortfolioAccessError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6182,15 +6765,21 @@ impl fmt::Display for ListOrganizationPortfolioAccessError {
             ListOrganizationPortfolioAccessError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListOrganizationPortfolioAccessError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListOrganizationPortfolioAccessError {}
 /// Errors returned by ListPortfolioAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPortfolioAccessError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPortfolioAccessError {
@@ -6198,12 +6787,13 @@ impl ListPortfolioAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPortfolioAccessError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPortfolioAccessError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPortfolioAccessError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6214,15 +6804,19 @@ impl fmt::Display for ListPortfolioAccessError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListPortfolioAccessError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListPortfolioAccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPortfolioAccessError {}
 /// Errors returned by ListPortfolios
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPortfoliosError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPortfoliosError {
@@ -6230,10 +6824,13 @@ impl ListPortfoliosError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListPortfoliosError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        ListPortfoliosError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPortfoliosError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6244,17 +6841,21 @@ impl fmt::Display for ListPortfoliosError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListPortfoliosError::InvalidParameters(ref cause) => write!(f, "{}", cause),
+            ListPortfoliosError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPortfoliosError {}
 /// Errors returned by ListPortfoliosForProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPortfoliosForProductError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPortfoliosForProductError {
@@ -6262,17 +6863,19 @@ impl ListPortfoliosForProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListPortfoliosForProductError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPortfoliosForProductError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPortfoliosForProductError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPortfoliosForProductError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPortfoliosForProductError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6284,17 +6887,21 @@ impl fmt::Display for ListPortfoliosForProductError {
         match *self {
             ListPortfoliosForProductError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             ListPortfoliosForProductError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListPortfoliosForProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPortfoliosForProductError {}
 /// Errors returned by ListPrincipalsForPortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPrincipalsForPortfolioError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPrincipalsForPortfolioError {
@@ -6304,17 +6911,21 @@ impl ListPrincipalsForPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPrincipalsForPortfolioError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPrincipalsForPortfolioError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPrincipalsForPortfolioError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListPrincipalsForPortfolioError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6326,17 +6937,21 @@ impl fmt::Display for ListPrincipalsForPortfolioError {
         match *self {
             ListPrincipalsForPortfolioError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             ListPrincipalsForPortfolioError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListPrincipalsForPortfolioError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPrincipalsForPortfolioError {}
 /// Errors returned by ListProvisionedProductPlans
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProvisionedProductPlansError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProvisionedProductPlansError {
@@ -6346,17 +6961,24 @@ impl ListProvisionedProductPlansError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProvisionedProductPlansError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProvisionedProductPlansError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListProvisionedProductPlansError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6370,17 +6992,21 @@ impl fmt::Display for ListProvisionedProductPlansError {
                 write!(f, "{}", cause)
             }
             ListProvisionedProductPlansError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListProvisionedProductPlansError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProvisionedProductPlansError {}
 /// Errors returned by ListProvisioningArtifacts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProvisioningArtifactsError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProvisioningArtifactsError {
@@ -6388,17 +7014,21 @@ impl ListProvisioningArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListProvisioningArtifactsError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProvisioningArtifactsError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProvisioningArtifactsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProvisioningArtifactsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListProvisioningArtifactsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6410,17 +7040,21 @@ impl fmt::Display for ListProvisioningArtifactsError {
         match *self {
             ListProvisioningArtifactsError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             ListProvisioningArtifactsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListProvisioningArtifactsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProvisioningArtifactsError {}
 /// Errors returned by ListProvisioningArtifactsForServiceAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProvisioningArtifactsForServiceActionError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProvisioningArtifactsForServiceActionError {
@@ -6430,17 +7064,24 @@ impl ListProvisioningArtifactsForServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProvisioningArtifactsForServiceActionError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProvisioningArtifactsForServiceActionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListProvisioningArtifactsForServiceActionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6456,15 +7097,21 @@ impl fmt::Display for ListProvisioningArtifactsForServiceActionError {
             ListProvisioningArtifactsForServiceActionError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListProvisioningArtifactsForServiceActionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListProvisioningArtifactsForServiceActionError {}
 /// Errors returned by ListRecordHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRecordHistoryError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRecordHistoryError {
@@ -6472,10 +7119,13 @@ impl ListRecordHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListRecordHistoryError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        ListRecordHistoryError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRecordHistoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6486,12 +7136,14 @@ impl fmt::Display for ListRecordHistoryError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListRecordHistoryError::InvalidParameters(ref cause) => write!(f, "{}", cause),
+            ListRecordHistoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRecordHistoryError {}
 /// Errors returned by ListResourcesForTagOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourcesForTagOptionError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -6499,6 +7151,8 @@ pub enum ListResourcesForTagOptionError {
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourcesForTagOptionError {
@@ -6506,22 +7160,27 @@ impl ListResourcesForTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListResourcesForTagOptionError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourcesForTagOptionError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListResourcesForTagOptionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourcesForTagOptionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourcesForTagOptionError::TagOptionNotMigrated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListResourcesForTagOptionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6536,15 +7195,19 @@ impl fmt::Display for ListResourcesForTagOptionError {
             ListResourcesForTagOptionError::TagOptionNotMigrated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListResourcesForTagOptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourcesForTagOptionError {}
 /// Errors returned by ListServiceActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListServiceActionsError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListServiceActionsError {
@@ -6552,12 +7215,13 @@ impl ListServiceActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListServiceActionsError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListServiceActionsError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListServiceActionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6568,17 +7232,21 @@ impl fmt::Display for ListServiceActionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListServiceActionsError::InvalidParameters(ref cause) => write!(f, "{}", cause),
+            ListServiceActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListServiceActionsError {}
 /// Errors returned by ListServiceActionsForProvisioningArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListServiceActionsForProvisioningArtifactError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListServiceActionsForProvisioningArtifactError {
@@ -6588,17 +7256,24 @@ impl ListServiceActionsForProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListServiceActionsForProvisioningArtifactError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListServiceActionsForProvisioningArtifactError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListServiceActionsForProvisioningArtifactError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6614,17 +7289,23 @@ impl fmt::Display for ListServiceActionsForProvisioningArtifactError {
             ListServiceActionsForProvisioningArtifactError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListServiceActionsForProvisioningArtifactError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListServiceActionsForProvisioningArtifactError {}
 /// Errors returned by ListStackInstancesForProvisionedProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStackInstancesForProvisionedProductError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStackInstancesForProvisionedProductError {
@@ -6634,17 +7315,24 @@ impl ListStackInstancesForProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListStackInstancesForProvisionedProductError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListStackInstancesForProvisionedProductError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListStackInstancesForProvisionedProductError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6660,17 +7348,23 @@ impl fmt::Display for ListStackInstancesForProvisionedProductError {
             ListStackInstancesForProvisionedProductError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListStackInstancesForProvisionedProductError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListStackInstancesForProvisionedProductError {}
 /// Errors returned by ListTagOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagOptionsError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagOptionsError {
@@ -6678,13 +7372,19 @@ impl ListTagOptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListTagOptionsError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        ListTagOptionsError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(ListTagOptionsError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service(
+                        ListTagOptionsError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagOptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6696,12 +7396,14 @@ impl fmt::Display for ListTagOptionsError {
         match *self {
             ListTagOptionsError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             ListTagOptionsError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            ListTagOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagOptionsError {}
 /// Errors returned by ProvisionProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ProvisionProductError {
     /// <p>The specified resource is a duplicate.</p>
     DuplicateResource(String),
@@ -6709,6 +7411,8 @@ pub enum ProvisionProductError {
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ProvisionProductError {
@@ -6716,16 +7420,25 @@ impl ProvisionProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(ProvisionProductError::DuplicateResource(err.msg))
+                    return RusotoError::service(
+                        ProvisionProductError::DuplicateResource(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ProvisionProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        ProvisionProductError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ProvisionProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ProvisionProductError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ProvisionProductError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6738,15 +7451,19 @@ impl fmt::Display for ProvisionProductError {
             ProvisionProductError::DuplicateResource(ref cause) => write!(f, "{}", cause),
             ProvisionProductError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             ProvisionProductError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ProvisionProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ProvisionProductError {}
 /// Errors returned by RejectPortfolioShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RejectPortfolioShareError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RejectPortfolioShareError {
@@ -6754,12 +7471,13 @@ impl RejectPortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectPortfolioShareError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RejectPortfolioShareError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RejectPortfolioShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6770,15 +7488,19 @@ impl fmt::Display for RejectPortfolioShareError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RejectPortfolioShareError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RejectPortfolioShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RejectPortfolioShareError {}
 /// Errors returned by ScanProvisionedProducts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ScanProvisionedProductsError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ScanProvisionedProductsError {
@@ -6786,12 +7508,13 @@ impl ScanProvisionedProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ScanProvisionedProductsError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ScanProvisionedProductsError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ScanProvisionedProductsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6802,15 +7525,19 @@ impl fmt::Display for ScanProvisionedProductsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ScanProvisionedProductsError::InvalidParameters(ref cause) => write!(f, "{}", cause),
+            ScanProvisionedProductsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ScanProvisionedProductsError {}
 /// Errors returned by SearchProducts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchProductsError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchProductsError {
@@ -6818,10 +7545,13 @@ impl SearchProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(SearchProductsError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        SearchProductsError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchProductsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6832,17 +7562,21 @@ impl fmt::Display for SearchProductsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SearchProductsError::InvalidParameters(ref cause) => write!(f, "{}", cause),
+            SearchProductsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchProductsError {}
 /// Errors returned by SearchProductsAsAdmin
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchProductsAsAdminError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchProductsAsAdminError {
@@ -6850,17 +7584,19 @@ impl SearchProductsAsAdminError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(SearchProductsAsAdminError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchProductsAsAdminError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchProductsAsAdminError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchProductsAsAdminError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchProductsAsAdminError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6872,15 +7608,19 @@ impl fmt::Display for SearchProductsAsAdminError {
         match *self {
             SearchProductsAsAdminError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             SearchProductsAsAdminError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            SearchProductsAsAdminError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchProductsAsAdminError {}
 /// Errors returned by SearchProvisionedProducts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchProvisionedProductsError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchProvisionedProductsError {
@@ -6888,12 +7628,15 @@ impl SearchProvisionedProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(SearchProvisionedProductsError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchProvisionedProductsError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(SearchProvisionedProductsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6904,15 +7647,19 @@ impl fmt::Display for SearchProvisionedProductsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SearchProvisionedProductsError::InvalidParameters(ref cause) => write!(f, "{}", cause),
+            SearchProvisionedProductsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchProvisionedProductsError {}
 /// Errors returned by TerminateProvisionedProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TerminateProvisionedProductError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TerminateProvisionedProductError {
@@ -6922,12 +7669,18 @@ impl TerminateProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TerminateProvisionedProductError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        TerminateProvisionedProductError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6938,17 +7691,21 @@ impl fmt::Display for TerminateProvisionedProductError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             TerminateProvisionedProductError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TerminateProvisionedProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TerminateProvisionedProductError {}
 /// Errors returned by UpdateConstraint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConstraintError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConstraintError {
@@ -6956,13 +7713,19 @@ impl UpdateConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateConstraintError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        UpdateConstraintError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateConstraintError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateConstraintError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6974,12 +7737,14 @@ impl fmt::Display for UpdateConstraintError {
         match *self {
             UpdateConstraintError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             UpdateConstraintError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateConstraintError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConstraintError {}
 /// Errors returned by UpdatePortfolio
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePortfolioError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -6989,6 +7754,8 @@ pub enum UpdatePortfolioError {
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePortfolioError {
@@ -6996,21 +7763,28 @@ impl UpdatePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdatePortfolioError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        UpdatePortfolioError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdatePortfolioError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdatePortfolioError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePortfolioError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdatePortfolioError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdatePortfolioError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePortfolioError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePortfolioError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7024,12 +7798,14 @@ impl fmt::Display for UpdatePortfolioError {
             UpdatePortfolioError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdatePortfolioError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdatePortfolioError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            UpdatePortfolioError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePortfolioError {}
 /// Errors returned by UpdateProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProductError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -7037,6 +7813,8 @@ pub enum UpdateProductError {
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProductError {
@@ -7044,16 +7822,25 @@ impl UpdateProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        UpdateProductError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateProductError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdateProductError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service(
+                        UpdateProductError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateProductError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7066,17 +7853,21 @@ impl fmt::Display for UpdateProductError {
             UpdateProductError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             UpdateProductError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateProductError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            UpdateProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateProductError {}
 /// Errors returned by UpdateProvisionedProduct
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProvisionedProductError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProvisionedProductError {
@@ -7084,17 +7875,19 @@ impl UpdateProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateProvisionedProductError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateProvisionedProductError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProvisionedProductError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateProvisionedProductError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateProvisionedProductError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7106,12 +7899,14 @@ impl fmt::Display for UpdateProvisionedProductError {
         match *self {
             UpdateProvisionedProductError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             UpdateProvisionedProductError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateProvisionedProductError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateProvisionedProductError {}
 /// Errors returned by UpdateProvisionedProductProperties
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProvisionedProductPropertiesError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
@@ -7119,6 +7914,8 @@ pub enum UpdateProvisionedProductPropertiesError {
     InvalidState(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProvisionedProductPropertiesError {
@@ -7128,22 +7925,30 @@ impl UpdateProvisionedProductPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateProvisionedProductPropertiesError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateProvisionedProductPropertiesError::InvalidState(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateProvisionedProductPropertiesError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateProvisionedProductPropertiesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7162,17 +7967,23 @@ impl fmt::Display for UpdateProvisionedProductPropertiesError {
             UpdateProvisionedProductPropertiesError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateProvisionedProductPropertiesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateProvisionedProductPropertiesError {}
 /// Errors returned by UpdateProvisioningArtifact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProvisioningArtifactError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProvisioningArtifactError {
@@ -7182,17 +7993,21 @@ impl UpdateProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateProvisioningArtifactError::InvalidParameters(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProvisioningArtifactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateProvisioningArtifactError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateProvisioningArtifactError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7204,17 +8019,21 @@ impl fmt::Display for UpdateProvisioningArtifactError {
         match *self {
             UpdateProvisioningArtifactError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             UpdateProvisioningArtifactError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateProvisioningArtifactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateProvisioningArtifactError {}
 /// Errors returned by UpdateServiceAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServiceActionError {
     /// <p>One or more parameters provided to the operation are not valid.</p>
     InvalidParameters(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServiceActionError {
@@ -7222,17 +8041,19 @@ impl UpdateServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateServiceActionError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServiceActionError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateServiceActionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServiceActionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateServiceActionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7244,12 +8065,14 @@ impl fmt::Display for UpdateServiceActionError {
         match *self {
             UpdateServiceActionError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             UpdateServiceActionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateServiceActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateServiceActionError {}
 /// Errors returned by UpdateTagOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTagOptionError {
     /// <p>The specified resource is a duplicate.</p>
     DuplicateResource(String),
@@ -7259,6 +8082,8 @@ pub enum UpdateTagOptionError {
     ResourceNotFound(String),
     /// <p>An operation requiring TagOptions failed because the TagOptions migration process has not been performed for this account. Please use the AWS console to perform the migration process before retrying the operation.</p>
     TagOptionNotMigrated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTagOptionError {
@@ -7266,21 +8091,31 @@ impl UpdateTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(UpdateTagOptionError::DuplicateResource(err.msg))
+                    return RusotoError::service(
+                        UpdateTagOptionError::DuplicateResource(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateTagOptionError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        UpdateTagOptionError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTagOptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateTagOptionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdateTagOptionError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTagOptionError::TagOptionNotMigrated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTagOptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7294,6 +8129,7 @@ impl fmt::Display for UpdateTagOptionError {
             UpdateTagOptionError::InvalidParameters(ref cause) => write!(f, "{}", cause),
             UpdateTagOptionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateTagOptionError::TagOptionNotMigrated(ref cause) => write!(f, "{}", cause),
+            UpdateTagOptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -7866,6 +8702,7 @@ impl ServiceCatalogClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ServiceCatalogClient {
         ServiceCatalogClient {
             client: Client::shared(),
@@ -7891,16 +8728,73 @@ impl ServiceCatalogClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceCatalogClient {
         ServiceCatalogClient { client, region }
     }
-}
 
-#[async_trait]
-impl ServiceCatalog for ServiceCatalogClient {
-    /// <p>Accepts an offer to share the specified portfolio.</p>
-    async fn accept_portfolio_share(
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ServiceCatalogClient> {
+        rusoto_core::ClientBuilder::new(ServiceCatalogClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ServiceCatalogClient {
+        ServiceCatalogClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
+}
+
+impl ServiceCatalogClient {
+    /// Calls `accept_portfolio_share`, also returning the metadata of the response.
+    pub async fn accept_portfolio_share_with_metadata(
         &self,
         input: AcceptPortfolioShareInput,
-    ) -> Result<AcceptPortfolioShareOutput, RusotoError<AcceptPortfolioShareError>> {
+    ) -> Result<
+        (AcceptPortfolioShareOutput, ResponseMetadata),
+        RusotoError<AcceptPortfolioShareError>,
+    > {
+        let request = self.build_accept_portfolio_share_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcceptPortfolioShareOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AcceptPortfolioShareError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `accept_portfolio_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_portfolio_share(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AcceptPortfolioShareInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_portfolio_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_portfolio_share_request(
+        &self,
+        input: AcceptPortfolioShareInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AcceptPortfolioShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7910,29 +8804,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_budget_with_resource`, also returning the metadata of the response.
+    pub async fn associate_budget_with_resource_with_metadata(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> Result<
+        (AssociateBudgetWithResourceOutput, ResponseMetadata),
+        RusotoError<AssociateBudgetWithResourceError>,
+    > {
+        let request = self.build_associate_budget_with_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AcceptPortfolioShareOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateBudgetWithResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AcceptPortfolioShareError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateBudgetWithResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified budget with the specified resource.</p>
-    async fn associate_budget_with_resource(
+    /// Presigns the request `associate_budget_with_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_budget_with_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateBudgetWithResourceInput,
-    ) -> Result<AssociateBudgetWithResourceOutput, RusotoError<AssociateBudgetWithResourceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_budget_with_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_budget_with_resource_request(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateBudgetWithResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7942,31 +8865,60 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_principal_with_portfolio`, also returning the metadata of the response.
+    pub async fn associate_principal_with_portfolio_with_metadata(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> Result<
+        (AssociatePrincipalWithPortfolioOutput, ResponseMetadata),
+        RusotoError<AssociatePrincipalWithPortfolioError>,
+    > {
+        let request = self.build_associate_principal_with_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateBudgetWithResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociatePrincipalWithPortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateBudgetWithResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociatePrincipalWithPortfolioError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Associates the specified principal ARN with the specified portfolio.</p>
-    async fn associate_principal_with_portfolio(
+    /// Presigns the request `associate_principal_with_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_principal_with_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociatePrincipalWithPortfolioInput,
-    ) -> Result<
-        AssociatePrincipalWithPortfolioOutput,
-        RusotoError<AssociatePrincipalWithPortfolioError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_principal_with_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_principal_with_portfolio_request(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociatePrincipalWithPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7976,31 +8928,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_product_with_portfolio`, also returning the metadata of the response.
+    pub async fn associate_product_with_portfolio_with_metadata(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> Result<
+        (AssociateProductWithPortfolioOutput, ResponseMetadata),
+        RusotoError<AssociateProductWithPortfolioError>,
+    > {
+        let request = self.build_associate_product_with_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociatePrincipalWithPortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateProductWithPortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociatePrincipalWithPortfolioError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateProductWithPortfolioError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified product with the specified portfolio.</p>
-    async fn associate_product_with_portfolio(
+    /// Presigns the request `associate_product_with_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_product_with_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateProductWithPortfolioInput,
-    ) -> Result<AssociateProductWithPortfolioOutput, RusotoError<AssociateProductWithPortfolioError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_product_with_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_product_with_portfolio_request(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateProductWithPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8010,31 +8989,62 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_service_action_with_provisioning_artifact`, also returning the metadata of the response.
+    pub async fn associate_service_action_with_provisioning_artifact_with_metadata(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> Result<
+        (
+            AssociateServiceActionWithProvisioningArtifactOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
+    > {
+        let request = self.build_associate_service_action_with_provisioning_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateProductWithPortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateServiceActionWithProvisioningArtifactOutput, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateProductWithPortfolioError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateServiceActionWithProvisioningArtifactError::from_response(response))
         }
     }
 
-    /// <p>Associates a self-service action with a provisioning artifact.</p>
-    async fn associate_service_action_with_provisioning_artifact(
+    /// Presigns the request `associate_service_action_with_provisioning_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_service_action_with_provisioning_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateServiceActionWithProvisioningArtifactInput,
-    ) -> Result<
-        AssociateServiceActionWithProvisioningArtifactOutput,
-        RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_service_action_with_provisioning_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_service_action_with_provisioning_artifact_request(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateServiceActionWithProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8044,31 +9054,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_tag_option_with_resource`, also returning the metadata of the response.
+    pub async fn associate_tag_option_with_resource_with_metadata(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> Result<
+        (AssociateTagOptionWithResourceOutput, ResponseMetadata),
+        RusotoError<AssociateTagOptionWithResourceError>,
+    > {
+        let request = self.build_associate_tag_option_with_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateServiceActionWithProvisioningArtifactOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateTagOptionWithResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateServiceActionWithProvisioningArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateTagOptionWithResourceError::from_response(response))
         }
     }
 
-    /// <p>Associate the specified TagOption with the specified portfolio or product.</p>
-    async fn associate_tag_option_with_resource(
+    /// Presigns the request `associate_tag_option_with_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_tag_option_with_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateTagOptionWithResourceInput,
-    ) -> Result<
-        AssociateTagOptionWithResourceOutput,
-        RusotoError<AssociateTagOptionWithResourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_tag_option_with_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_tag_option_with_resource_request(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateTagOptionWithResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8078,31 +9115,64 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_associate_service_action_with_provisioning_artifact`, also returning the metadata of the response.
+    pub async fn batch_associate_service_action_with_provisioning_artifact_with_metadata(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> Result<
+        (
+            BatchAssociateServiceActionWithProvisioningArtifactOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
+    > {
+        let request =
+            self.build_batch_associate_service_action_with_provisioning_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateTagOptionWithResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchAssociateServiceActionWithProvisioningArtifactOutput, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateTagOptionWithResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchAssociateServiceActionWithProvisioningArtifactError::from_response(response))
         }
     }
 
-    /// <p>Associates multiple self-service actions with provisioning artifacts.</p>
-    async fn batch_associate_service_action_with_provisioning_artifact(
+    /// Presigns the request `batch_associate_service_action_with_provisioning_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_associate_service_action_with_provisioning_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchAssociateServiceActionWithProvisioningArtifactInput,
-    ) -> Result<
-        BatchAssociateServiceActionWithProvisioningArtifactOutput,
-        RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_batch_associate_service_action_with_provisioning_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_associate_service_action_with_provisioning_artifact_request(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("BatchAssociateServiceActionWithProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8112,31 +9182,68 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_disassociate_service_action_from_provisioning_artifact`, also returning the metadata of the response.
+    pub async fn batch_disassociate_service_action_from_provisioning_artifact_with_metadata(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> Result<
+        (
+            BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
+    > {
+        let request =
+            self.build_batch_disassociate_service_action_from_provisioning_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchAssociateServiceActionWithProvisioningArtifactOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDisassociateServiceActionFromProvisioningArtifactOutput, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchAssociateServiceActionWithProvisioningArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(
+                BatchDisassociateServiceActionFromProvisioningArtifactError::from_response(
+                    response,
+                ),
+            )
         }
     }
 
-    /// <p>Disassociates a batch of self-service actions from the specified provisioning artifact.</p>
-    async fn batch_disassociate_service_action_from_provisioning_artifact(
+    /// Presigns the request `batch_disassociate_service_action_from_provisioning_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_disassociate_service_action_from_provisioning_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
-    ) -> Result<
-        BatchDisassociateServiceActionFromProvisioningArtifactOutput,
-        RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_batch_disassociate_service_action_from_provisioning_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_disassociate_service_action_from_provisioning_artifact_request(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("BatchDisassociateServiceActionFromProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8146,59 +9253,105 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `copy_product`, also returning the metadata of the response.
+    pub async fn copy_product_with_metadata(
+        &self,
+        input: CopyProductInput,
+    ) -> Result<(CopyProductOutput, ResponseMetadata), RusotoError<CopyProductError>> {
+        let request = self.build_copy_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDisassociateServiceActionFromProvisioningArtifactOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CopyProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(
-                BatchDisassociateServiceActionFromProvisioningArtifactError::from_response(
-                    response,
-                ),
-            )
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CopyProductError::from_response(response))
         }
     }
 
-    /// <p>Copies the specified source product to the specified target product or a new product.</p> <p>You can copy a product to the same account or another account. You can copy a product to the same region or another region.</p> <p>This operation is performed asynchronously. To track the progress of the operation, use <a>DescribeCopyProductStatus</a>.</p>
-    async fn copy_product(
+    /// Presigns the request `copy_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CopyProductInput,
-    ) -> Result<CopyProductOutput, RusotoError<CopyProductError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_product_request(&self, input: CopyProductInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CopyProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CopyProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_constraint`, also returning the metadata of the response.
+    pub async fn create_constraint_with_metadata(
+        &self,
+        input: CreateConstraintInput,
+    ) -> Result<(CreateConstraintOutput, ResponseMetadata), RusotoError<CreateConstraintError>>
+    {
+        let request = self.build_create_constraint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CopyProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateConstraintOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CopyProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateConstraintError::from_response(response))
         }
     }
 
-    /// <p>Creates a constraint.</p>
-    async fn create_constraint(
+    /// Presigns the request `create_constraint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_constraint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConstraintInput,
-    ) -> Result<CreateConstraintOutput, RusotoError<CreateConstraintError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_constraint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_constraint_request(&self, input: CreateConstraintInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateConstraint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8208,27 +9361,52 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_portfolio`, also returning the metadata of the response.
+    pub async fn create_portfolio_with_metadata(
+        &self,
+        input: CreatePortfolioInput,
+    ) -> Result<(CreatePortfolioOutput, ResponseMetadata), RusotoError<CreatePortfolioError>> {
+        let request = self.build_create_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateConstraintOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConstraintError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePortfolioError::from_response(response))
         }
     }
 
-    /// <p>Creates a portfolio.</p>
-    async fn create_portfolio(
+    /// Presigns the request `create_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePortfolioInput,
-    ) -> Result<CreatePortfolioOutput, RusotoError<CreatePortfolioError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_portfolio_request(&self, input: CreatePortfolioInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreatePortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8238,27 +9416,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_portfolio_share`, also returning the metadata of the response.
+    pub async fn create_portfolio_share_with_metadata(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> Result<
+        (CreatePortfolioShareOutput, ResponseMetadata),
+        RusotoError<CreatePortfolioShareError>,
+    > {
+        let request = self.build_create_portfolio_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreatePortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePortfolioShareOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePortfolioError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePortfolioShareError::from_response(response))
         }
     }
 
-    /// <p>Shares the specified portfolio with the specified account or organization node. Shares to an organization node can only be created by the master account of an Organization. AWSOrganizationsAccess must be enabled in order to create a portfolio share to an organization node.</p>
-    async fn create_portfolio_share(
+    /// Presigns the request `create_portfolio_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_portfolio_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePortfolioShareInput,
-    ) -> Result<CreatePortfolioShareOutput, RusotoError<CreatePortfolioShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_portfolio_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_portfolio_share_request(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreatePortfolioShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8268,56 +9477,110 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_product`, also returning the metadata of the response.
+    pub async fn create_product_with_metadata(
+        &self,
+        input: CreateProductInput,
+    ) -> Result<(CreateProductOutput, ResponseMetadata), RusotoError<CreateProductError>> {
+        let request = self.build_create_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePortfolioShareOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePortfolioShareError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProductError::from_response(response))
         }
     }
 
-    /// <p>Creates a product.</p>
-    async fn create_product(
+    /// Presigns the request `create_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProductInput,
-    ) -> Result<CreateProductOutput, RusotoError<CreateProductError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_product_request(&self, input: CreateProductInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CreateProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_provisioned_product_plan`, also returning the metadata of the response.
+    pub async fn create_provisioned_product_plan_with_metadata(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> Result<
+        (CreateProvisionedProductPlanOutput, ResponseMetadata),
+        RusotoError<CreateProvisionedProductPlanError>,
+    > {
+        let request = self.build_create_provisioned_product_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProvisionedProductPlanOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProvisionedProductPlanError::from_response(response))
         }
     }
 
-    /// <p>Creates a plan. A plan includes the list of resources to be created (when provisioning a new product) or modified (when updating a provisioned product) when the plan is executed.</p> <p>You can create one plan per provisioned product. To create a plan for an existing provisioned product, the product status must be AVAILBLE or TAINTED.</p> <p>To view the resource changes in the change set, use <a>DescribeProvisionedProductPlan</a>. To create or modify the provisioned product, use <a>ExecuteProvisionedProductPlan</a>.</p>
-    async fn create_provisioned_product_plan(
+    /// Presigns the request `create_provisioned_product_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_provisioned_product_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProvisionedProductPlanInput,
-    ) -> Result<CreateProvisionedProductPlanOutput, RusotoError<CreateProvisionedProductPlanError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_provisioned_product_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_provisioned_product_plan_request(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateProvisionedProductPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8327,29 +9590,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_provisioning_artifact`, also returning the metadata of the response.
+    pub async fn create_provisioning_artifact_with_metadata(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> Result<
+        (CreateProvisioningArtifactOutput, ResponseMetadata),
+        RusotoError<CreateProvisioningArtifactError>,
+    > {
+        let request = self.build_create_provisioning_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProvisionedProductPlanOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProvisioningArtifactOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProvisionedProductPlanError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProvisioningArtifactError::from_response(response))
         }
     }
 
-    /// <p>Creates a provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot create a provisioning artifact for a product that was shared with you.</p>
-    async fn create_provisioning_artifact(
+    /// Presigns the request `create_provisioning_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_provisioning_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProvisioningArtifactInput,
-    ) -> Result<CreateProvisioningArtifactOutput, RusotoError<CreateProvisioningArtifactError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_provisioning_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_provisioning_artifact_request(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8359,28 +9651,56 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_service_action`, also returning the metadata of the response.
+    pub async fn create_service_action_with_metadata(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> Result<(CreateServiceActionOutput, ResponseMetadata), RusotoError<CreateServiceActionError>>
+    {
+        let request = self.build_create_service_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProvisioningArtifactOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateServiceActionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProvisioningArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateServiceActionError::from_response(response))
         }
     }
 
-    /// <p>Creates a self-service action.</p>
-    async fn create_service_action(
+    /// Presigns the request `create_service_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_service_action(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateServiceActionInput,
-    ) -> Result<CreateServiceActionOutput, RusotoError<CreateServiceActionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_service_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_service_action_request(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8390,28 +9710,52 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_tag_option`, also returning the metadata of the response.
+    pub async fn create_tag_option_with_metadata(
+        &self,
+        input: CreateTagOptionInput,
+    ) -> Result<(CreateTagOptionOutput, ResponseMetadata), RusotoError<CreateTagOptionError>> {
+        let request = self.build_create_tag_option_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateServiceActionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTagOptionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateServiceActionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTagOptionError::from_response(response))
         }
     }
 
-    /// <p>Creates a TagOption.</p>
-    async fn create_tag_option(
+    /// Presigns the request `create_tag_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_tag_option(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTagOptionInput,
-    ) -> Result<CreateTagOptionOutput, RusotoError<CreateTagOptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_tag_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_tag_option_request(&self, input: CreateTagOptionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8421,27 +9765,53 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_constraint`, also returning the metadata of the response.
+    pub async fn delete_constraint_with_metadata(
+        &self,
+        input: DeleteConstraintInput,
+    ) -> Result<(DeleteConstraintOutput, ResponseMetadata), RusotoError<DeleteConstraintError>>
+    {
+        let request = self.build_delete_constraint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTagOptionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteConstraintOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTagOptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConstraintError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified constraint.</p>
-    async fn delete_constraint(
+    /// Presigns the request `delete_constraint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_constraint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConstraintInput,
-    ) -> Result<DeleteConstraintOutput, RusotoError<DeleteConstraintError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_constraint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_constraint_request(&self, input: DeleteConstraintInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteConstraint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8451,27 +9821,52 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_portfolio`, also returning the metadata of the response.
+    pub async fn delete_portfolio_with_metadata(
+        &self,
+        input: DeletePortfolioInput,
+    ) -> Result<(DeletePortfolioOutput, ResponseMetadata), RusotoError<DeletePortfolioError>> {
+        let request = self.build_delete_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteConstraintOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeletePortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConstraintError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePortfolioError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified portfolio.</p> <p>You cannot delete a portfolio if it was shared with you or if it has associated products, users, constraints, or shared accounts.</p>
-    async fn delete_portfolio(
+    /// Presigns the request `delete_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePortfolioInput,
-    ) -> Result<DeletePortfolioOutput, RusotoError<DeletePortfolioError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_portfolio_request(&self, input: DeletePortfolioInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeletePortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8481,27 +9876,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_portfolio_share`, also returning the metadata of the response.
+    pub async fn delete_portfolio_share_with_metadata(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> Result<
+        (DeletePortfolioShareOutput, ResponseMetadata),
+        RusotoError<DeletePortfolioShareError>,
+    > {
+        let request = self.build_delete_portfolio_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeletePortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeletePortfolioShareOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePortfolioError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePortfolioShareError::from_response(response))
         }
     }
 
-    /// <p>Stops sharing the specified portfolio with the specified account or organization node. Shares to an organization node can only be deleted by the master account of an Organization.</p>
-    async fn delete_portfolio_share(
+    /// Presigns the request `delete_portfolio_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_portfolio_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePortfolioShareInput,
-    ) -> Result<DeletePortfolioShareOutput, RusotoError<DeletePortfolioShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_portfolio_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_portfolio_share_request(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeletePortfolioShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8511,56 +9937,110 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_product`, also returning the metadata of the response.
+    pub async fn delete_product_with_metadata(
+        &self,
+        input: DeleteProductInput,
+    ) -> Result<(DeleteProductOutput, ResponseMetadata), RusotoError<DeleteProductError>> {
+        let request = self.build_delete_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePortfolioShareOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePortfolioShareError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteProductError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified product.</p> <p>You cannot delete a product if it was shared with you or is associated with a portfolio.</p>
-    async fn delete_product(
+    /// Presigns the request `delete_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProductInput,
-    ) -> Result<DeleteProductOutput, RusotoError<DeleteProductError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_product_request(&self, input: DeleteProductInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.DeleteProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_provisioned_product_plan`, also returning the metadata of the response.
+    pub async fn delete_provisioned_product_plan_with_metadata(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> Result<
+        (DeleteProvisionedProductPlanOutput, ResponseMetadata),
+        RusotoError<DeleteProvisionedProductPlanError>,
+    > {
+        let request = self.build_delete_provisioned_product_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteProvisionedProductPlanOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteProvisionedProductPlanError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified plan.</p>
-    async fn delete_provisioned_product_plan(
+    /// Presigns the request `delete_provisioned_product_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_provisioned_product_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProvisionedProductPlanInput,
-    ) -> Result<DeleteProvisionedProductPlanOutput, RusotoError<DeleteProvisionedProductPlanError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_provisioned_product_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_provisioned_product_plan_request(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteProvisionedProductPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8570,29 +10050,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_provisioning_artifact`, also returning the metadata of the response.
+    pub async fn delete_provisioning_artifact_with_metadata(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> Result<
+        (DeleteProvisioningArtifactOutput, ResponseMetadata),
+        RusotoError<DeleteProvisioningArtifactError>,
+    > {
+        let request = self.build_delete_provisioning_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteProvisionedProductPlanOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteProvisioningArtifactOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProvisionedProductPlanError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteProvisioningArtifactError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot delete a provisioning artifact associated with a product that was shared with you. You cannot delete the last provisioning artifact for a product, because a product must have at least one provisioning artifact.</p>
-    async fn delete_provisioning_artifact(
+    /// Presigns the request `delete_provisioning_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_provisioning_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProvisioningArtifactInput,
-    ) -> Result<DeleteProvisioningArtifactOutput, RusotoError<DeleteProvisioningArtifactError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_provisioning_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_provisioning_artifact_request(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8602,28 +10111,56 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_service_action`, also returning the metadata of the response.
+    pub async fn delete_service_action_with_metadata(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> Result<(DeleteServiceActionOutput, ResponseMetadata), RusotoError<DeleteServiceActionError>>
+    {
+        let request = self.build_delete_service_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteProvisioningArtifactOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteServiceActionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProvisioningArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteServiceActionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a self-service action.</p>
-    async fn delete_service_action(
+    /// Presigns the request `delete_service_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_service_action(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteServiceActionInput,
-    ) -> Result<DeleteServiceActionOutput, RusotoError<DeleteServiceActionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_service_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_service_action_request(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8633,28 +10170,52 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_tag_option`, also returning the metadata of the response.
+    pub async fn delete_tag_option_with_metadata(
+        &self,
+        input: DeleteTagOptionInput,
+    ) -> Result<(DeleteTagOptionOutput, ResponseMetadata), RusotoError<DeleteTagOptionError>> {
+        let request = self.build_delete_tag_option_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteServiceActionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTagOptionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteServiceActionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTagOptionError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified TagOption.</p> <p>You cannot delete a TagOption if it is associated with a product or portfolio.</p>
-    async fn delete_tag_option(
+    /// Presigns the request `delete_tag_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tag_option(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTagOptionInput,
-    ) -> Result<DeleteTagOptionOutput, RusotoError<DeleteTagOptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tag_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tag_option_request(&self, input: DeleteTagOptionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8664,27 +10225,53 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_constraint`, also returning the metadata of the response.
+    pub async fn describe_constraint_with_metadata(
+        &self,
+        input: DescribeConstraintInput,
+    ) -> Result<(DescribeConstraintOutput, ResponseMetadata), RusotoError<DescribeConstraintError>>
+    {
+        let request = self.build_describe_constraint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagOptionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConstraintOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTagOptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConstraintError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the specified constraint.</p>
-    async fn describe_constraint(
+    /// Presigns the request `describe_constraint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_constraint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConstraintInput,
-    ) -> Result<DescribeConstraintOutput, RusotoError<DescribeConstraintError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_constraint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_constraint_request(&self, input: DescribeConstraintInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeConstraint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8694,28 +10281,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_copy_product_status`, also returning the metadata of the response.
+    pub async fn describe_copy_product_status_with_metadata(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> Result<
+        (DescribeCopyProductStatusOutput, ResponseMetadata),
+        RusotoError<DescribeCopyProductStatusError>,
+    > {
+        let request = self.build_describe_copy_product_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConstraintOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCopyProductStatusOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConstraintError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCopyProductStatusError::from_response(response))
         }
     }
 
-    /// <p>Gets the status of the specified copy product operation.</p>
-    async fn describe_copy_product_status(
+    /// Presigns the request `describe_copy_product_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_copy_product_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCopyProductStatusInput,
-    ) -> Result<DescribeCopyProductStatusOutput, RusotoError<DescribeCopyProductStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_copy_product_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_copy_product_status_request(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeCopyProductStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8725,28 +10342,53 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_portfolio`, also returning the metadata of the response.
+    pub async fn describe_portfolio_with_metadata(
+        &self,
+        input: DescribePortfolioInput,
+    ) -> Result<(DescribePortfolioOutput, ResponseMetadata), RusotoError<DescribePortfolioError>>
+    {
+        let request = self.build_describe_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCopyProductStatusOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCopyProductStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePortfolioError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the specified portfolio.</p>
-    async fn describe_portfolio(
+    /// Presigns the request `describe_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePortfolioInput,
-    ) -> Result<DescribePortfolioOutput, RusotoError<DescribePortfolioError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_portfolio_request(&self, input: DescribePortfolioInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribePortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8756,28 +10398,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_portfolio_share_status`, also returning the metadata of the response.
+    pub async fn describe_portfolio_share_status_with_metadata(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> Result<
+        (DescribePortfolioShareStatusOutput, ResponseMetadata),
+        RusotoError<DescribePortfolioShareStatusError>,
+    > {
+        let request = self.build_describe_portfolio_share_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribePortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePortfolioShareStatusOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePortfolioError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePortfolioShareStatusError::from_response(response))
         }
     }
 
-    /// <p>Gets the status of the specified portfolio share operation. This API can only be called by the master account in the organization.</p>
-    async fn describe_portfolio_share_status(
+    /// Presigns the request `describe_portfolio_share_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_portfolio_share_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePortfolioShareStatusInput,
-    ) -> Result<DescribePortfolioShareStatusOutput, RusotoError<DescribePortfolioShareStatusError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_portfolio_share_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_portfolio_share_status_request(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribePortfolioShareStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8787,28 +10459,52 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_product`, also returning the metadata of the response.
+    pub async fn describe_product_with_metadata(
+        &self,
+        input: DescribeProductInput,
+    ) -> Result<(DescribeProductOutput, ResponseMetadata), RusotoError<DescribeProductError>> {
+        let request = self.build_describe_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePortfolioShareStatusOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePortfolioShareStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProductError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the specified product.</p>
-    async fn describe_product(
+    /// Presigns the request `describe_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProductInput,
-    ) -> Result<DescribeProductOutput, RusotoError<DescribeProductError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_product_request(&self, input: DescribeProductInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8818,27 +10514,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_product_as_admin`, also returning the metadata of the response.
+    pub async fn describe_product_as_admin_with_metadata(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> Result<
+        (DescribeProductAsAdminOutput, ResponseMetadata),
+        RusotoError<DescribeProductAsAdminError>,
+    > {
+        let request = self.build_describe_product_as_admin_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProductAsAdminOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProductAsAdminError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the specified product. This operation is run with administrator access.</p>
-    async fn describe_product_as_admin(
+    /// Presigns the request `describe_product_as_admin` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_product_as_admin(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProductAsAdminInput,
-    ) -> Result<DescribeProductAsAdminOutput, RusotoError<DescribeProductAsAdminError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_product_as_admin_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_product_as_admin_request(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProductAsAdmin");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8848,28 +10575,56 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_product_view`, also returning the metadata of the response.
+    pub async fn describe_product_view_with_metadata(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> Result<(DescribeProductViewOutput, ResponseMetadata), RusotoError<DescribeProductViewError>>
+    {
+        let request = self.build_describe_product_view_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProductAsAdminOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProductViewOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProductAsAdminError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProductViewError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the specified product.</p>
-    async fn describe_product_view(
+    /// Presigns the request `describe_product_view` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_product_view(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProductViewInput,
-    ) -> Result<DescribeProductViewOutput, RusotoError<DescribeProductViewError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_product_view_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_product_view_request(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProductView");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8879,29 +10634,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_provisioned_product`, also returning the metadata of the response.
+    pub async fn describe_provisioned_product_with_metadata(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> Result<
+        (DescribeProvisionedProductOutput, ResponseMetadata),
+        RusotoError<DescribeProvisionedProductError>,
+    > {
+        let request = self.build_describe_provisioned_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProductViewOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProvisionedProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProductViewError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProvisionedProductError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the specified provisioned product.</p>
-    async fn describe_provisioned_product(
+    /// Presigns the request `describe_provisioned_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_provisioned_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProvisionedProductInput,
-    ) -> Result<DescribeProvisionedProductOutput, RusotoError<DescribeProvisionedProductError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_provisioned_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_provisioned_product_request(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisionedProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8911,31 +10695,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_provisioned_product_plan`, also returning the metadata of the response.
+    pub async fn describe_provisioned_product_plan_with_metadata(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> Result<
+        (DescribeProvisionedProductPlanOutput, ResponseMetadata),
+        RusotoError<DescribeProvisionedProductPlanError>,
+    > {
+        let request = self.build_describe_provisioned_product_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProvisionedProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProvisionedProductPlanOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProvisionedProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProvisionedProductPlanError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the resource changes for the specified plan.</p>
-    async fn describe_provisioned_product_plan(
+    /// Presigns the request `describe_provisioned_product_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_provisioned_product_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProvisionedProductPlanInput,
-    ) -> Result<
-        DescribeProvisionedProductPlanOutput,
-        RusotoError<DescribeProvisionedProductPlanError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_provisioned_product_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_provisioned_product_plan_request(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisionedProductPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8945,29 +10756,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_provisioning_artifact`, also returning the metadata of the response.
+    pub async fn describe_provisioning_artifact_with_metadata(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> Result<
+        (DescribeProvisioningArtifactOutput, ResponseMetadata),
+        RusotoError<DescribeProvisioningArtifactError>,
+    > {
+        let request = self.build_describe_provisioning_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProvisionedProductPlanOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProvisioningArtifactOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProvisionedProductPlanError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProvisioningArtifactError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the specified provisioning artifact (also known as a version) for the specified product.</p>
-    async fn describe_provisioning_artifact(
+    /// Presigns the request `describe_provisioning_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_provisioning_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProvisioningArtifactInput,
-    ) -> Result<DescribeProvisioningArtifactOutput, RusotoError<DescribeProvisioningArtifactError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_provisioning_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_provisioning_artifact_request(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8977,31 +10817,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_provisioning_parameters`, also returning the metadata of the response.
+    pub async fn describe_provisioning_parameters_with_metadata(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> Result<
+        (DescribeProvisioningParametersOutput, ResponseMetadata),
+        RusotoError<DescribeProvisioningParametersError>,
+    > {
+        let request = self.build_describe_provisioning_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProvisioningArtifactOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProvisioningParametersOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProvisioningArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProvisioningParametersError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the configuration required to provision the specified product using the specified provisioning artifact.</p> <p>If the output contains a TagOption key with an empty list of values, there is a TagOption conflict for that key. The end user cannot take action to fix the conflict, and launch is not blocked. In subsequent calls to <a>ProvisionProduct</a>, do not include conflicted TagOption keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>". Tag the provisioned product with the value <code>sc-tagoption-conflict-portfolioId-productId</code>.</p>
-    async fn describe_provisioning_parameters(
+    /// Presigns the request `describe_provisioning_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_provisioning_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProvisioningParametersInput,
-    ) -> Result<
-        DescribeProvisioningParametersOutput,
-        RusotoError<DescribeProvisioningParametersError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_provisioning_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_provisioning_parameters_request(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisioningParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9011,55 +10878,110 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_record`, also returning the metadata of the response.
+    pub async fn describe_record_with_metadata(
+        &self,
+        input: DescribeRecordInput,
+    ) -> Result<(DescribeRecordOutput, ResponseMetadata), RusotoError<DescribeRecordError>> {
+        let request = self.build_describe_record_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProvisioningParametersOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRecordOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProvisioningParametersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRecordError::from_response(response))
         }
     }
 
-    /// <p><p>Gets information about the specified request operation.</p> <p>Use this operation after calling a request operation (for example, <a>ProvisionProduct</a>, <a>TerminateProvisionedProduct</a>, or <a>UpdateProvisionedProduct</a>). </p> <note> <p>If a provisioned product was transferred to a new owner using <a>UpdateProvisionedProductProperties</a>, the new owner will be able to describe all past records for that product. The previous owner will no longer be able to describe the records, but will be able to use <a>ListRecordHistory</a> to see the product&#39;s history from when he was the owner.</p> </note></p>
-    async fn describe_record(
+    /// Presigns the request `describe_record` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_record(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRecordInput,
-    ) -> Result<DescribeRecordOutput, RusotoError<DescribeRecordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_record_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_record_request(&self, input: DescribeRecordInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeRecord");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.DescribeRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_service_action`, also returning the metadata of the response.
+    pub async fn describe_service_action_with_metadata(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> Result<
+        (DescribeServiceActionOutput, ResponseMetadata),
+        RusotoError<DescribeServiceActionError>,
+    > {
+        let request = self.build_describe_service_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeRecordOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeServiceActionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRecordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeServiceActionError::from_response(response))
         }
     }
 
-    /// <p>Describes a self-service action.</p>
-    async fn describe_service_action(
+    /// Presigns the request `describe_service_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_service_action(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeServiceActionInput,
-    ) -> Result<DescribeServiceActionOutput, RusotoError<DescribeServiceActionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_service_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_service_action_request(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9069,30 +10991,61 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_service_action_execution_parameters`, also returning the metadata of the response.
+    pub async fn describe_service_action_execution_parameters_with_metadata(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> Result<
+        (
+            DescribeServiceActionExecutionParametersOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeServiceActionExecutionParametersError>,
+    > {
+        let request = self.build_describe_service_action_execution_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeServiceActionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeServiceActionExecutionParametersOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeServiceActionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeServiceActionExecutionParametersError::from_response(response))
         }
     }
 
-    async fn describe_service_action_execution_parameters(
+    /// Presigns the request `describe_service_action_execution_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_service_action_execution_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeServiceActionExecutionParametersInput,
-    ) -> Result<
-        DescribeServiceActionExecutionParametersOutput,
-        RusotoError<DescribeServiceActionExecutionParametersError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_service_action_execution_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_service_action_execution_parameters_request(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeServiceActionExecutionParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9102,28 +11055,53 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_tag_option`, also returning the metadata of the response.
+    pub async fn describe_tag_option_with_metadata(
+        &self,
+        input: DescribeTagOptionInput,
+    ) -> Result<(DescribeTagOptionOutput, ResponseMetadata), RusotoError<DescribeTagOptionError>>
+    {
+        let request = self.build_describe_tag_option_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeServiceActionExecutionParametersOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTagOptionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeServiceActionExecutionParametersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTagOptionError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the specified TagOption.</p>
-    async fn describe_tag_option(
+    /// Presigns the request `describe_tag_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tag_option(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTagOptionInput,
-    ) -> Result<DescribeTagOptionOutput, RusotoError<DescribeTagOptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tag_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tag_option_request(&self, input: DescribeTagOptionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9133,27 +11111,53 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_aws_organizations_access`, also returning the metadata of the response.
+    pub async fn disable_aws_organizations_access_with_metadata(
+        &self,
+    ) -> Result<
+        (DisableAWSOrganizationsAccessOutput, ResponseMetadata),
+        RusotoError<DisableAWSOrganizationsAccessError>,
+    > {
+        let request = self.build_disable_aws_organizations_access_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTagOptionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableAWSOrganizationsAccessOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTagOptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableAWSOrganizationsAccessError::from_response(response))
         }
     }
 
-    /// <p>Disable portfolio sharing through AWS Organizations feature. This feature will not delete your current shares but it will prevent you from creating new shares throughout your organization. Current shares will not be in sync with your organization structure if it changes after calling this API. This API can only be called by the master account in the organization.</p>
-    async fn disable_aws_organizations_access(
+    /// Presigns the request `disable_aws_organizations_access` would send with `presigner` instead of sending it.
+    pub async fn presign_disable_aws_organizations_access(
         &self,
-    ) -> Result<DisableAWSOrganizationsAccessOutput, RusotoError<DisableAWSOrganizationsAccessError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_aws_organizations_access_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_aws_organizations_access_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisableAWSOrganizationsAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9162,31 +11166,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `disassociate_budget_from_resource`, also returning the metadata of the response.
+    pub async fn disassociate_budget_from_resource_with_metadata(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> Result<
+        (DisassociateBudgetFromResourceOutput, ResponseMetadata),
+        RusotoError<DisassociateBudgetFromResourceError>,
+    > {
+        let request = self.build_disassociate_budget_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisableAWSOrganizationsAccessOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateBudgetFromResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableAWSOrganizationsAccessError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateBudgetFromResourceError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified budget from the specified resource.</p>
-    async fn disassociate_budget_from_resource(
+    /// Presigns the request `disassociate_budget_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_budget_from_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateBudgetFromResourceInput,
-    ) -> Result<
-        DisassociateBudgetFromResourceOutput,
-        RusotoError<DisassociateBudgetFromResourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_budget_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_budget_from_resource_request(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateBudgetFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9196,31 +11227,60 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_principal_from_portfolio`, also returning the metadata of the response.
+    pub async fn disassociate_principal_from_portfolio_with_metadata(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> Result<
+        (DisassociatePrincipalFromPortfolioOutput, ResponseMetadata),
+        RusotoError<DisassociatePrincipalFromPortfolioError>,
+    > {
+        let request = self.build_disassociate_principal_from_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateBudgetFromResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociatePrincipalFromPortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateBudgetFromResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociatePrincipalFromPortfolioError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Disassociates a previously associated principal ARN from a specified portfolio.</p>
-    async fn disassociate_principal_from_portfolio(
+    /// Presigns the request `disassociate_principal_from_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_principal_from_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociatePrincipalFromPortfolioInput,
-    ) -> Result<
-        DisassociatePrincipalFromPortfolioOutput,
-        RusotoError<DisassociatePrincipalFromPortfolioError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_principal_from_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_principal_from_portfolio_request(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociatePrincipalFromPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9230,33 +11290,60 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_product_from_portfolio`, also returning the metadata of the response.
+    pub async fn disassociate_product_from_portfolio_with_metadata(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> Result<
+        (DisassociateProductFromPortfolioOutput, ResponseMetadata),
+        RusotoError<DisassociateProductFromPortfolioError>,
+    > {
+        let request = self.build_disassociate_product_from_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociatePrincipalFromPortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateProductFromPortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociatePrincipalFromPortfolioError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateProductFromPortfolioError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Disassociates the specified product from the specified portfolio. </p>
-    async fn disassociate_product_from_portfolio(
+    /// Presigns the request `disassociate_product_from_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_product_from_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateProductFromPortfolioInput,
-    ) -> Result<
-        DisassociateProductFromPortfolioOutput,
-        RusotoError<DisassociateProductFromPortfolioError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_product_from_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_product_from_portfolio_request(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateProductFromPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9266,33 +11353,64 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_service_action_from_provisioning_artifact`, also returning the metadata of the response.
+    pub async fn disassociate_service_action_from_provisioning_artifact_with_metadata(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> Result<
+        (
+            DisassociateServiceActionFromProvisioningArtifactOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
+    > {
+        let request =
+            self.build_disassociate_service_action_from_provisioning_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateProductFromPortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateServiceActionFromProvisioningArtifactOutput, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateProductFromPortfolioError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateServiceActionFromProvisioningArtifactError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified self-service action association from the specified provisioning artifact.</p>
-    async fn disassociate_service_action_from_provisioning_artifact(
+    /// Presigns the request `disassociate_service_action_from_provisioning_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_service_action_from_provisioning_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateServiceActionFromProvisioningArtifactInput,
-    ) -> Result<
-        DisassociateServiceActionFromProvisioningArtifactOutput,
-        RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_disassociate_service_action_from_provisioning_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_service_action_from_provisioning_artifact_request(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateServiceActionFromProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9302,31 +11420,60 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_tag_option_from_resource`, also returning the metadata of the response.
+    pub async fn disassociate_tag_option_from_resource_with_metadata(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> Result<
+        (DisassociateTagOptionFromResourceOutput, ResponseMetadata),
+        RusotoError<DisassociateTagOptionFromResourceError>,
+    > {
+        let request = self.build_disassociate_tag_option_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateServiceActionFromProvisioningArtifactOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateTagOptionFromResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateServiceActionFromProvisioningArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateTagOptionFromResourceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Disassociates the specified TagOption from the specified resource.</p>
-    async fn disassociate_tag_option_from_resource(
+    /// Presigns the request `disassociate_tag_option_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_tag_option_from_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateTagOptionFromResourceInput,
-    ) -> Result<
-        DisassociateTagOptionFromResourceOutput,
-        RusotoError<DisassociateTagOptionFromResourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_tag_option_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_tag_option_from_resource_request(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateTagOptionFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9336,30 +11483,53 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_aws_organizations_access`, also returning the metadata of the response.
+    pub async fn enable_aws_organizations_access_with_metadata(
+        &self,
+    ) -> Result<
+        (EnableAWSOrganizationsAccessOutput, ResponseMetadata),
+        RusotoError<EnableAWSOrganizationsAccessError>,
+    > {
+        let request = self.build_enable_aws_organizations_access_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateTagOptionFromResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnableAWSOrganizationsAccessOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateTagOptionFromResourceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableAWSOrganizationsAccessError::from_response(response))
         }
     }
 
-    /// <p>Enable portfolio sharing feature through AWS Organizations. This API will allow Service Catalog to receive updates on your organization in order to sync your shares with the current structure. This API can only be called by the master account in the organization.</p> <p>By calling this API Service Catalog will make a call to organizations:EnableAWSServiceAccess on your behalf so that your shares can be in sync with any changes in your AWS Organizations structure.</p>
-    async fn enable_aws_organizations_access(
+    /// Presigns the request `enable_aws_organizations_access` would send with `presigner` instead of sending it.
+    pub async fn presign_enable_aws_organizations_access(
         &self,
-    ) -> Result<EnableAWSOrganizationsAccessOutput, RusotoError<EnableAWSOrganizationsAccessError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_aws_organizations_access_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_aws_organizations_access_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("EnableAWSOrganizationsAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9368,29 +11538,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `execute_provisioned_product_plan`, also returning the metadata of the response.
+    pub async fn execute_provisioned_product_plan_with_metadata(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> Result<
+        (ExecuteProvisionedProductPlanOutput, ResponseMetadata),
+        RusotoError<ExecuteProvisionedProductPlanError>,
+    > {
+        let request = self.build_execute_provisioned_product_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<EnableAWSOrganizationsAccessOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ExecuteProvisionedProductPlanOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableAWSOrganizationsAccessError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ExecuteProvisionedProductPlanError::from_response(response))
         }
     }
 
-    /// <p>Provisions or modifies a product based on the resource changes for the specified plan.</p>
-    async fn execute_provisioned_product_plan(
+    /// Presigns the request `execute_provisioned_product_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_execute_provisioned_product_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExecuteProvisionedProductPlanInput,
-    ) -> Result<ExecuteProvisionedProductPlanOutput, RusotoError<ExecuteProvisionedProductPlanError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_execute_provisioned_product_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_execute_provisioned_product_plan_request(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ExecuteProvisionedProductPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9400,31 +11599,63 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `execute_provisioned_product_service_action`, also returning the metadata of the response.
+    pub async fn execute_provisioned_product_service_action_with_metadata(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> Result<
+        (
+            ExecuteProvisionedProductServiceActionOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<ExecuteProvisionedProductServiceActionError>,
+    > {
+        let request = self.build_execute_provisioned_product_service_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ExecuteProvisionedProductPlanOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ExecuteProvisionedProductServiceActionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ExecuteProvisionedProductPlanError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ExecuteProvisionedProductServiceActionError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Executes a self-service action against a provisioned product.</p>
-    async fn execute_provisioned_product_service_action(
+    /// Presigns the request `execute_provisioned_product_service_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_execute_provisioned_product_service_action(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExecuteProvisionedProductServiceActionInput,
-    ) -> Result<
-        ExecuteProvisionedProductServiceActionOutput,
-        RusotoError<ExecuteProvisionedProductServiceActionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_execute_provisioned_product_service_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_execute_provisioned_product_service_action_request(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ExecuteProvisionedProductServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9434,32 +11665,55 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_aws_organizations_access_status`, also returning the metadata of the response.
+    pub async fn get_aws_organizations_access_status_with_metadata(
+        &self,
+    ) -> Result<
+        (GetAWSOrganizationsAccessStatusOutput, ResponseMetadata),
+        RusotoError<GetAWSOrganizationsAccessStatusError>,
+    > {
+        let request = self.build_get_aws_organizations_access_status_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ExecuteProvisionedProductServiceActionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAWSOrganizationsAccessStatusOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ExecuteProvisionedProductServiceActionError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAWSOrganizationsAccessStatusError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Get the Access Status for AWS Organization portfolio share feature. This API can only be called by the master account in the organization.</p>
-    async fn get_aws_organizations_access_status(
+    /// Presigns the request `get_aws_organizations_access_status` would send with `presigner` instead of sending it.
+    pub async fn presign_get_aws_organizations_access_status(
         &self,
-    ) -> Result<
-        GetAWSOrganizationsAccessStatusOutput,
-        RusotoError<GetAWSOrganizationsAccessStatusError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_aws_organizations_access_status_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_aws_organizations_access_status_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("GetAWSOrganizationsAccessStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9468,31 +11722,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `list_accepted_portfolio_shares`, also returning the metadata of the response.
+    pub async fn list_accepted_portfolio_shares_with_metadata(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> Result<
+        (ListAcceptedPortfolioSharesOutput, ResponseMetadata),
+        RusotoError<ListAcceptedPortfolioSharesError>,
+    > {
+        let request = self.build_list_accepted_portfolio_shares_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAWSOrganizationsAccessStatusOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAcceptedPortfolioSharesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAWSOrganizationsAccessStatusError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAcceptedPortfolioSharesError::from_response(response))
         }
     }
 
-    /// <p>Lists all portfolios for which sharing was accepted by this account.</p>
-    async fn list_accepted_portfolio_shares(
+    /// Presigns the request `list_accepted_portfolio_shares` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_accepted_portfolio_shares(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAcceptedPortfolioSharesInput,
-    ) -> Result<ListAcceptedPortfolioSharesOutput, RusotoError<ListAcceptedPortfolioSharesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_accepted_portfolio_shares_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_accepted_portfolio_shares_request(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListAcceptedPortfolioShares");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9502,28 +11783,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_budgets_for_resource`, also returning the metadata of the response.
+    pub async fn list_budgets_for_resource_with_metadata(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> Result<
+        (ListBudgetsForResourceOutput, ResponseMetadata),
+        RusotoError<ListBudgetsForResourceError>,
+    > {
+        let request = self.build_list_budgets_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAcceptedPortfolioSharesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBudgetsForResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAcceptedPortfolioSharesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBudgetsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists all the budgets associated to the specified resource.</p>
-    async fn list_budgets_for_resource(
+    /// Presigns the request `list_budgets_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_budgets_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBudgetsForResourceInput,
-    ) -> Result<ListBudgetsForResourceOutput, RusotoError<ListBudgetsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_budgets_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_budgets_for_resource_request(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListBudgetsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9533,29 +11844,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_constraints_for_portfolio`, also returning the metadata of the response.
+    pub async fn list_constraints_for_portfolio_with_metadata(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> Result<
+        (ListConstraintsForPortfolioOutput, ResponseMetadata),
+        RusotoError<ListConstraintsForPortfolioError>,
+    > {
+        let request = self.build_list_constraints_for_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBudgetsForResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListConstraintsForPortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBudgetsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListConstraintsForPortfolioError::from_response(response))
         }
     }
 
-    /// <p>Lists the constraints for the specified portfolio and product.</p>
-    async fn list_constraints_for_portfolio(
+    /// Presigns the request `list_constraints_for_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_constraints_for_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConstraintsForPortfolioInput,
-    ) -> Result<ListConstraintsForPortfolioOutput, RusotoError<ListConstraintsForPortfolioError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_constraints_for_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_constraints_for_portfolio_request(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListConstraintsForPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9565,28 +11905,52 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_launch_paths`, also returning the metadata of the response.
+    pub async fn list_launch_paths_with_metadata(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> Result<(ListLaunchPathsOutput, ResponseMetadata), RusotoError<ListLaunchPathsError>> {
+        let request = self.build_list_launch_paths_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConstraintsForPortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLaunchPathsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConstraintsForPortfolioError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLaunchPathsError::from_response(response))
         }
     }
 
-    /// <p>Lists the paths to the specified product. A path is how the user has access to a specified product, and is necessary when provisioning a product. A path also determines the constraints put on the product.</p>
-    async fn list_launch_paths(
+    /// Presigns the request `list_launch_paths` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_launch_paths(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLaunchPathsInput,
-    ) -> Result<ListLaunchPathsOutput, RusotoError<ListLaunchPathsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_launch_paths_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_launch_paths_request(&self, input: ListLaunchPathsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListLaunchPaths");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9596,30 +11960,60 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_organization_portfolio_access`, also returning the metadata of the response.
+    pub async fn list_organization_portfolio_access_with_metadata(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> Result<
+        (ListOrganizationPortfolioAccessOutput, ResponseMetadata),
+        RusotoError<ListOrganizationPortfolioAccessError>,
+    > {
+        let request = self.build_list_organization_portfolio_access_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListLaunchPathsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListOrganizationPortfolioAccessOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLaunchPathsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListOrganizationPortfolioAccessError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists the organization nodes that have access to the specified portfolio. This API can only be called by the master account in the organization.</p>
-    async fn list_organization_portfolio_access(
+    /// Presigns the request `list_organization_portfolio_access` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_organization_portfolio_access(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOrganizationPortfolioAccessInput,
-    ) -> Result<
-        ListOrganizationPortfolioAccessOutput,
-        RusotoError<ListOrganizationPortfolioAccessError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_organization_portfolio_access_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_organization_portfolio_access_request(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListOrganizationPortfolioAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9629,30 +12023,56 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_portfolio_access`, also returning the metadata of the response.
+    pub async fn list_portfolio_access_with_metadata(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> Result<(ListPortfolioAccessOutput, ResponseMetadata), RusotoError<ListPortfolioAccessError>>
+    {
+        let request = self.build_list_portfolio_access_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListOrganizationPortfolioAccessOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPortfolioAccessOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOrganizationPortfolioAccessError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPortfolioAccessError::from_response(response))
         }
     }
 
-    /// <p>Lists the account IDs that have access to the specified portfolio.</p>
-    async fn list_portfolio_access(
+    /// Presigns the request `list_portfolio_access` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_portfolio_access(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPortfolioAccessInput,
-    ) -> Result<ListPortfolioAccessOutput, RusotoError<ListPortfolioAccessError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_portfolio_access_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_portfolio_access_request(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPortfolioAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9662,55 +12082,110 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_portfolios`, also returning the metadata of the response.
+    pub async fn list_portfolios_with_metadata(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> Result<(ListPortfoliosOutput, ResponseMetadata), RusotoError<ListPortfoliosError>> {
+        let request = self.build_list_portfolios_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPortfolioAccessOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPortfoliosOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPortfolioAccessError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPortfoliosError::from_response(response))
         }
     }
 
-    /// <p>Lists all portfolios in the catalog.</p>
-    async fn list_portfolios(
+    /// Presigns the request `list_portfolios` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_portfolios(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPortfoliosInput,
-    ) -> Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_portfolios_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_portfolios_request(&self, input: ListPortfoliosInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPortfolios");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListPortfolios");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_portfolios_for_product`, also returning the metadata of the response.
+    pub async fn list_portfolios_for_product_with_metadata(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> Result<
+        (ListPortfoliosForProductOutput, ResponseMetadata),
+        RusotoError<ListPortfoliosForProductError>,
+    > {
+        let request = self.build_list_portfolios_for_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPortfoliosOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPortfoliosForProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPortfoliosError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPortfoliosForProductError::from_response(response))
         }
     }
 
-    /// <p>Lists all portfolios that the specified product is associated with.</p>
-    async fn list_portfolios_for_product(
+    /// Presigns the request `list_portfolios_for_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_portfolios_for_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPortfoliosForProductInput,
-    ) -> Result<ListPortfoliosForProductOutput, RusotoError<ListPortfoliosForProductError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_portfolios_for_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_portfolios_for_product_request(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPortfoliosForProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9720,29 +12195,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_principals_for_portfolio`, also returning the metadata of the response.
+    pub async fn list_principals_for_portfolio_with_metadata(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> Result<
+        (ListPrincipalsForPortfolioOutput, ResponseMetadata),
+        RusotoError<ListPrincipalsForPortfolioError>,
+    > {
+        let request = self.build_list_principals_for_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPortfoliosForProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPrincipalsForPortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPortfoliosForProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPrincipalsForPortfolioError::from_response(response))
         }
     }
 
-    /// <p>Lists all principal ARNs associated with the specified portfolio.</p>
-    async fn list_principals_for_portfolio(
+    /// Presigns the request `list_principals_for_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_principals_for_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPrincipalsForPortfolioInput,
-    ) -> Result<ListPrincipalsForPortfolioOutput, RusotoError<ListPrincipalsForPortfolioError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_principals_for_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_principals_for_portfolio_request(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPrincipalsForPortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9752,29 +12256,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_provisioned_product_plans`, also returning the metadata of the response.
+    pub async fn list_provisioned_product_plans_with_metadata(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> Result<
+        (ListProvisionedProductPlansOutput, ResponseMetadata),
+        RusotoError<ListProvisionedProductPlansError>,
+    > {
+        let request = self.build_list_provisioned_product_plans_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPrincipalsForPortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProvisionedProductPlansOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPrincipalsForPortfolioError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProvisionedProductPlansError::from_response(response))
         }
     }
 
-    /// <p>Lists the plans for the specified provisioned product or all plans to which the user has access.</p>
-    async fn list_provisioned_product_plans(
+    /// Presigns the request `list_provisioned_product_plans` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_provisioned_product_plans(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProvisionedProductPlansInput,
-    ) -> Result<ListProvisionedProductPlansOutput, RusotoError<ListProvisionedProductPlansError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_provisioned_product_plans_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_provisioned_product_plans_request(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListProvisionedProductPlans");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9784,28 +12317,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_provisioning_artifacts`, also returning the metadata of the response.
+    pub async fn list_provisioning_artifacts_with_metadata(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> Result<
+        (ListProvisioningArtifactsOutput, ResponseMetadata),
+        RusotoError<ListProvisioningArtifactsError>,
+    > {
+        let request = self.build_list_provisioning_artifacts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProvisionedProductPlansOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProvisioningArtifactsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProvisionedProductPlansError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProvisioningArtifactsError::from_response(response))
         }
     }
 
-    /// <p>Lists all provisioning artifacts (also known as versions) for the specified product.</p>
-    async fn list_provisioning_artifacts(
+    /// Presigns the request `list_provisioning_artifacts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_provisioning_artifacts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProvisioningArtifactsInput,
-    ) -> Result<ListProvisioningArtifactsOutput, RusotoError<ListProvisioningArtifactsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_provisioning_artifacts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_provisioning_artifacts_request(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListProvisioningArtifacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9815,31 +12378,61 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_provisioning_artifacts_for_service_action`, also returning the metadata of the response.
+    pub async fn list_provisioning_artifacts_for_service_action_with_metadata(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> Result<
+        (
+            ListProvisioningArtifactsForServiceActionOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<ListProvisioningArtifactsForServiceActionError>,
+    > {
+        let request = self.build_list_provisioning_artifacts_for_service_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProvisioningArtifactsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProvisioningArtifactsForServiceActionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProvisioningArtifactsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProvisioningArtifactsForServiceActionError::from_response(response))
         }
     }
 
-    /// <p>Lists all provisioning artifacts (also known as versions) for the specified self-service action.</p>
-    async fn list_provisioning_artifacts_for_service_action(
+    /// Presigns the request `list_provisioning_artifacts_for_service_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_provisioning_artifacts_for_service_action(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProvisioningArtifactsForServiceActionInput,
-    ) -> Result<
-        ListProvisioningArtifactsForServiceActionOutput,
-        RusotoError<ListProvisioningArtifactsForServiceActionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_provisioning_artifacts_for_service_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_provisioning_artifacts_for_service_action_request(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListProvisioningArtifactsForServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9849,28 +12442,53 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_record_history`, also returning the metadata of the response.
+    pub async fn list_record_history_with_metadata(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> Result<(ListRecordHistoryOutput, ResponseMetadata), RusotoError<ListRecordHistoryError>>
+    {
+        let request = self.build_list_record_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProvisioningArtifactsForServiceActionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRecordHistoryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProvisioningArtifactsForServiceActionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRecordHistoryError::from_response(response))
         }
     }
 
-    /// <p>Lists the specified requests or all performed requests.</p>
-    async fn list_record_history(
+    /// Presigns the request `list_record_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_record_history(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRecordHistoryInput,
-    ) -> Result<ListRecordHistoryOutput, RusotoError<ListRecordHistoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_record_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_record_history_request(&self, input: ListRecordHistoryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListRecordHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9880,27 +12498,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resources_for_tag_option`, also returning the metadata of the response.
+    pub async fn list_resources_for_tag_option_with_metadata(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> Result<
+        (ListResourcesForTagOptionOutput, ResponseMetadata),
+        RusotoError<ListResourcesForTagOptionError>,
+    > {
+        let request = self.build_list_resources_for_tag_option_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListRecordHistoryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResourcesForTagOptionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRecordHistoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResourcesForTagOptionError::from_response(response))
         }
     }
 
-    /// <p>Lists the resources associated with the specified TagOption.</p>
-    async fn list_resources_for_tag_option(
+    /// Presigns the request `list_resources_for_tag_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resources_for_tag_option(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourcesForTagOptionInput,
-    ) -> Result<ListResourcesForTagOptionOutput, RusotoError<ListResourcesForTagOptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resources_for_tag_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resources_for_tag_option_request(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListResourcesForTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9910,28 +12559,53 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_service_actions`, also returning the metadata of the response.
+    pub async fn list_service_actions_with_metadata(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> Result<(ListServiceActionsOutput, ResponseMetadata), RusotoError<ListServiceActionsError>>
+    {
+        let request = self.build_list_service_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourcesForTagOptionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListServiceActionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourcesForTagOptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListServiceActionsError::from_response(response))
         }
     }
 
-    /// <p>Lists all self-service actions.</p>
-    async fn list_service_actions(
+    /// Presigns the request `list_service_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_service_actions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListServiceActionsInput,
-    ) -> Result<ListServiceActionsOutput, RusotoError<ListServiceActionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_service_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_service_actions_request(&self, input: ListServiceActionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListServiceActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9941,31 +12615,61 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_service_actions_for_provisioning_artifact`, also returning the metadata of the response.
+    pub async fn list_service_actions_for_provisioning_artifact_with_metadata(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> Result<
+        (
+            ListServiceActionsForProvisioningArtifactOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<ListServiceActionsForProvisioningArtifactError>,
+    > {
+        let request = self.build_list_service_actions_for_provisioning_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListServiceActionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListServiceActionsForProvisioningArtifactOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListServiceActionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListServiceActionsForProvisioningArtifactError::from_response(response))
         }
     }
 
-    /// <p>Returns a paginated list of self-service actions associated with the specified Product ID and Provisioning Artifact ID.</p>
-    async fn list_service_actions_for_provisioning_artifact(
+    /// Presigns the request `list_service_actions_for_provisioning_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_service_actions_for_provisioning_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListServiceActionsForProvisioningArtifactInput,
-    ) -> Result<
-        ListServiceActionsForProvisioningArtifactOutput,
-        RusotoError<ListServiceActionsForProvisioningArtifactError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_service_actions_for_provisioning_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_service_actions_for_provisioning_artifact_request(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListServiceActionsForProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9975,31 +12679,63 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_stack_instances_for_provisioned_product`, also returning the metadata of the response.
+    pub async fn list_stack_instances_for_provisioned_product_with_metadata(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> Result<
+        (
+            ListStackInstancesForProvisionedProductOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<ListStackInstancesForProvisionedProductError>,
+    > {
+        let request = self.build_list_stack_instances_for_provisioned_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListServiceActionsForProvisioningArtifactOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListStackInstancesForProvisionedProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListServiceActionsForProvisioningArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListStackInstancesForProvisionedProductError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns summary information about stack instances that are associated with the specified <code>CFN_STACKSET</code> type provisioned product. You can filter for stack instances that are associated with a specific AWS account name or region. </p>
-    async fn list_stack_instances_for_provisioned_product(
+    /// Presigns the request `list_stack_instances_for_provisioned_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_stack_instances_for_provisioned_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListStackInstancesForProvisionedProductInput,
-    ) -> Result<
-        ListStackInstancesForProvisionedProductOutput,
-        RusotoError<ListStackInstancesForProvisionedProductError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_stack_instances_for_provisioned_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_stack_instances_for_provisioned_product_request(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListStackInstancesForProvisionedProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10009,57 +12745,105 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tag_options`, also returning the metadata of the response.
+    pub async fn list_tag_options_with_metadata(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> Result<(ListTagOptionsOutput, ResponseMetadata), RusotoError<ListTagOptionsError>> {
+        let request = self.build_list_tag_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListStackInstancesForProvisionedProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagOptionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListStackInstancesForProvisionedProductError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagOptionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the specified TagOptions or all TagOptions.</p>
-    async fn list_tag_options(
+    /// Presigns the request `list_tag_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tag_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagOptionsInput,
-    ) -> Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tag_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tag_options_request(&self, input: ListTagOptionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListTagOptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListTagOptions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `provision_product`, also returning the metadata of the response.
+    pub async fn provision_product_with_metadata(
+        &self,
+        input: ProvisionProductInput,
+    ) -> Result<(ProvisionProductOutput, ResponseMetadata), RusotoError<ProvisionProductError>>
+    {
+        let request = self.build_provision_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTagOptionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ProvisionProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagOptionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ProvisionProductError::from_response(response))
         }
     }
 
-    /// <p>Provisions the specified product.</p> <p>A provisioned product is a resourced instance of a product. For example, provisioning a product based on a CloudFormation template launches a CloudFormation stack and its underlying resources. You can check the status of this request using <a>DescribeRecord</a>.</p> <p>If the request contains a tag key with an empty list of values, there is a tag conflict for that key. Do not include conflicted keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>".</p>
-    async fn provision_product(
+    /// Presigns the request `provision_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_provision_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ProvisionProductInput,
-    ) -> Result<ProvisionProductOutput, RusotoError<ProvisionProductError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_provision_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_provision_product_request(&self, input: ProvisionProductInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ProvisionProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10069,27 +12853,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reject_portfolio_share`, also returning the metadata of the response.
+    pub async fn reject_portfolio_share_with_metadata(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> Result<
+        (RejectPortfolioShareOutput, ResponseMetadata),
+        RusotoError<RejectPortfolioShareError>,
+    > {
+        let request = self.build_reject_portfolio_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ProvisionProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RejectPortfolioShareOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ProvisionProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RejectPortfolioShareError::from_response(response))
         }
     }
 
-    /// <p>Rejects an offer to share the specified portfolio.</p>
-    async fn reject_portfolio_share(
+    /// Presigns the request `reject_portfolio_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reject_portfolio_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RejectPortfolioShareInput,
-    ) -> Result<RejectPortfolioShareOutput, RusotoError<RejectPortfolioShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reject_portfolio_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reject_portfolio_share_request(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("RejectPortfolioShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10099,28 +12914,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `scan_provisioned_products`, also returning the metadata of the response.
+    pub async fn scan_provisioned_products_with_metadata(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> Result<
+        (ScanProvisionedProductsOutput, ResponseMetadata),
+        RusotoError<ScanProvisionedProductsError>,
+    > {
+        let request = self.build_scan_provisioned_products_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RejectPortfolioShareOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ScanProvisionedProductsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RejectPortfolioShareError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ScanProvisionedProductsError::from_response(response))
         }
     }
 
-    /// <p>Lists the provisioned products that are available (not terminated).</p> <p>To use additional filtering, see <a>SearchProvisionedProducts</a>.</p>
-    async fn scan_provisioned_products(
+    /// Presigns the request `scan_provisioned_products` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_scan_provisioned_products(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ScanProvisionedProductsInput,
-    ) -> Result<ScanProvisionedProductsOutput, RusotoError<ScanProvisionedProductsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_scan_provisioned_products_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_scan_provisioned_products_request(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ScanProvisionedProducts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10130,55 +12975,110 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_products`, also returning the metadata of the response.
+    pub async fn search_products_with_metadata(
+        &self,
+        input: SearchProductsInput,
+    ) -> Result<(SearchProductsOutput, ResponseMetadata), RusotoError<SearchProductsError>> {
+        let request = self.build_search_products_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ScanProvisionedProductsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchProductsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ScanProvisionedProductsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchProductsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the products to which the caller has access.</p>
-    async fn search_products(
+    /// Presigns the request `search_products` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_products(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchProductsInput,
-    ) -> Result<SearchProductsOutput, RusotoError<SearchProductsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_products_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_products_request(&self, input: SearchProductsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("SearchProducts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.SearchProducts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_products_as_admin`, also returning the metadata of the response.
+    pub async fn search_products_as_admin_with_metadata(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> Result<
+        (SearchProductsAsAdminOutput, ResponseMetadata),
+        RusotoError<SearchProductsAsAdminError>,
+    > {
+        let request = self.build_search_products_as_admin_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchProductsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchProductsAsAdminOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchProductsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchProductsAsAdminError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the products for the specified portfolio or all products.</p>
-    async fn search_products_as_admin(
+    /// Presigns the request `search_products_as_admin` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_products_as_admin(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchProductsAsAdminInput,
-    ) -> Result<SearchProductsAsAdminOutput, RusotoError<SearchProductsAsAdminError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_products_as_admin_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_products_as_admin_request(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("SearchProductsAsAdmin");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10188,28 +13088,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_provisioned_products`, also returning the metadata of the response.
+    pub async fn search_provisioned_products_with_metadata(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> Result<
+        (SearchProvisionedProductsOutput, ResponseMetadata),
+        RusotoError<SearchProvisionedProductsError>,
+    > {
+        let request = self.build_search_provisioned_products_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchProductsAsAdminOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchProvisionedProductsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchProductsAsAdminError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchProvisionedProductsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the provisioned products that meet the specified criteria.</p>
-    async fn search_provisioned_products(
+    /// Presigns the request `search_provisioned_products` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_provisioned_products(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchProvisionedProductsInput,
-    ) -> Result<SearchProvisionedProductsOutput, RusotoError<SearchProvisionedProductsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_provisioned_products_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_provisioned_products_request(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("SearchProvisionedProducts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10219,29 +13149,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `terminate_provisioned_product`, also returning the metadata of the response.
+    pub async fn terminate_provisioned_product_with_metadata(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> Result<
+        (TerminateProvisionedProductOutput, ResponseMetadata),
+        RusotoError<TerminateProvisionedProductError>,
+    > {
+        let request = self.build_terminate_provisioned_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchProvisionedProductsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TerminateProvisionedProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchProvisionedProductsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TerminateProvisionedProductError::from_response(response))
         }
     }
 
-    /// <p>Terminates the specified provisioned product.</p> <p>This operation does not delete any records associated with the provisioned product.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
-    async fn terminate_provisioned_product(
+    /// Presigns the request `terminate_provisioned_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_terminate_provisioned_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TerminateProvisionedProductInput,
-    ) -> Result<TerminateProvisionedProductOutput, RusotoError<TerminateProvisionedProductError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_terminate_provisioned_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_terminate_provisioned_product_request(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("TerminateProvisionedProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10251,28 +13210,53 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_constraint`, also returning the metadata of the response.
+    pub async fn update_constraint_with_metadata(
+        &self,
+        input: UpdateConstraintInput,
+    ) -> Result<(UpdateConstraintOutput, ResponseMetadata), RusotoError<UpdateConstraintError>>
+    {
+        let request = self.build_update_constraint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<TerminateProvisionedProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateConstraintOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TerminateProvisionedProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateConstraintError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified constraint.</p>
-    async fn update_constraint(
+    /// Presigns the request `update_constraint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_constraint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConstraintInput,
-    ) -> Result<UpdateConstraintOutput, RusotoError<UpdateConstraintError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_constraint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_constraint_request(&self, input: UpdateConstraintInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateConstraint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10282,27 +13266,52 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_portfolio`, also returning the metadata of the response.
+    pub async fn update_portfolio_with_metadata(
+        &self,
+        input: UpdatePortfolioInput,
+    ) -> Result<(UpdatePortfolioOutput, ResponseMetadata), RusotoError<UpdatePortfolioError>> {
+        let request = self.build_update_portfolio_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateConstraintOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePortfolioOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConstraintError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePortfolioError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified portfolio.</p> <p>You cannot update a product that was shared with you.</p>
-    async fn update_portfolio(
+    /// Presigns the request `update_portfolio` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_portfolio(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePortfolioInput,
-    ) -> Result<UpdatePortfolioOutput, RusotoError<UpdatePortfolioError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_portfolio_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_portfolio_request(&self, input: UpdatePortfolioInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdatePortfolio");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10312,54 +13321,110 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `update_product`, also returning the metadata of the response.
+    pub async fn update_product_with_metadata(
+        &self,
+        input: UpdateProductInput,
+    ) -> Result<(UpdateProductOutput, ResponseMetadata), RusotoError<UpdateProductError>> {
+        let request = self.build_update_product_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdatePortfolioOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePortfolioError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateProductError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified product.</p>
-    async fn update_product(
+    /// Presigns the request `update_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProductInput,
-    ) -> Result<UpdateProductOutput, RusotoError<UpdateProductError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_product_request(&self, input: UpdateProductInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.UpdateProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_provisioned_product`, also returning the metadata of the response.
+    pub async fn update_provisioned_product_with_metadata(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> Result<
+        (UpdateProvisionedProductOutput, ResponseMetadata),
+        RusotoError<UpdateProvisionedProductError>,
+    > {
+        let request = self.build_update_provisioned_product_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateProvisionedProductOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateProvisionedProductError::from_response(response))
         }
     }
 
-    /// <p>Requests updates to the configuration of the specified provisioned product.</p> <p>If there are tags associated with the object, they cannot be updated or added. Depending on the specific updates requested, this operation can update with no interruption, with some interruption, or replace the provisioned product entirely.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
-    async fn update_provisioned_product(
+    /// Presigns the request `update_provisioned_product` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_provisioned_product(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProvisionedProductInput,
-    ) -> Result<UpdateProvisionedProductOutput, RusotoError<UpdateProvisionedProductError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_provisioned_product_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_provisioned_product_request(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProvisionedProduct");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10369,31 +13434,60 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_provisioned_product_properties`, also returning the metadata of the response.
+    pub async fn update_provisioned_product_properties_with_metadata(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> Result<
+        (UpdateProvisionedProductPropertiesOutput, ResponseMetadata),
+        RusotoError<UpdateProvisionedProductPropertiesError>,
+    > {
+        let request = self.build_update_provisioned_product_properties_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateProvisionedProductOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateProvisionedProductPropertiesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProvisionedProductError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateProvisionedProductPropertiesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Requests updates to the properties of the specified provisioned product.</p>
-    async fn update_provisioned_product_properties(
+    /// Presigns the request `update_provisioned_product_properties` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_provisioned_product_properties(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProvisionedProductPropertiesInput,
-    ) -> Result<
-        UpdateProvisionedProductPropertiesOutput,
-        RusotoError<UpdateProvisionedProductPropertiesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_provisioned_product_properties_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_provisioned_product_properties_request(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProvisionedProductProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10403,31 +13497,58 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_provisioning_artifact`, also returning the metadata of the response.
+    pub async fn update_provisioning_artifact_with_metadata(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> Result<
+        (UpdateProvisioningArtifactOutput, ResponseMetadata),
+        RusotoError<UpdateProvisioningArtifactError>,
+    > {
+        let request = self.build_update_provisioning_artifact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateProvisionedProductPropertiesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateProvisioningArtifactOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProvisionedProductPropertiesError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateProvisioningArtifactError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot update a provisioning artifact for a product that was shared with you.</p>
-    async fn update_provisioning_artifact(
+    /// Presigns the request `update_provisioning_artifact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_provisioning_artifact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProvisioningArtifactInput,
-    ) -> Result<UpdateProvisioningArtifactOutput, RusotoError<UpdateProvisioningArtifactError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_provisioning_artifact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_provisioning_artifact_request(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProvisioningArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10437,28 +13558,56 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_service_action`, also returning the metadata of the response.
+    pub async fn update_service_action_with_metadata(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> Result<(UpdateServiceActionOutput, ResponseMetadata), RusotoError<UpdateServiceActionError>>
+    {
+        let request = self.build_update_service_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateProvisioningArtifactOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateServiceActionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProvisioningArtifactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateServiceActionError::from_response(response))
         }
     }
 
-    /// <p>Updates a self-service action.</p>
-    async fn update_service_action(
+    /// Presigns the request `update_service_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_service_action(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateServiceActionInput,
-    ) -> Result<UpdateServiceActionOutput, RusotoError<UpdateServiceActionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_service_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_service_action_request(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateServiceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10468,28 +13617,52 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_tag_option`, also returning the metadata of the response.
+    pub async fn update_tag_option_with_metadata(
+        &self,
+        input: UpdateTagOptionInput,
+    ) -> Result<(UpdateTagOptionOutput, ResponseMetadata), RusotoError<UpdateTagOptionError>> {
+        let request = self.build_update_tag_option_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateServiceActionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTagOptionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateServiceActionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTagOptionError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified TagOption.</p>
-    async fn update_tag_option(
+    /// Presigns the request `update_tag_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_tag_option(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTagOptionInput,
-    ) -> Result<UpdateTagOptionOutput, RusotoError<UpdateTagOptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_tag_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_tag_option_request(&self, input: UpdateTagOptionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateTagOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10499,18 +13672,1577 @@ impl ServiceCatalog for ServiceCatalogClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAcceptedPortfolioShares` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_accepted_portfolio_shares_pages(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListAcceptedPortfolioSharesOutput,
+            RusotoError<ListAcceptedPortfolioSharesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.list_accepted_portfolio_shares(input).await
+            },
+            |input: &ListAcceptedPortfolioSharesInput,
+             output: &ListAcceptedPortfolioSharesOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListAcceptedPortfolioSharesInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PortfolioDetails` of every page of `ListAcceptedPortfolioShares` results for `input`, as `list_accepted_portfolio_shares_pages` requests them.
+    pub fn list_accepted_portfolio_shares_stream(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> impl futures::Stream<
+        Item = Result<PortfolioDetail, RusotoError<ListAcceptedPortfolioSharesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_accepted_portfolio_shares_pages(input), |page| {
+            page.portfolio_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListConstraintsForPortfolio` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_constraints_for_portfolio_pages(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListConstraintsForPortfolioOutput,
+            RusotoError<ListConstraintsForPortfolioError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.list_constraints_for_portfolio(input).await
+            },
+            |input: &ListConstraintsForPortfolioInput,
+             output: &ListConstraintsForPortfolioOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListConstraintsForPortfolioInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConstraintDetails` of every page of `ListConstraintsForPortfolio` results for `input`, as `list_constraints_for_portfolio_pages` requests them.
+    pub fn list_constraints_for_portfolio_stream(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> impl futures::Stream<
+        Item = Result<ConstraintDetail, RusotoError<ListConstraintsForPortfolioError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_constraints_for_portfolio_pages(input), |page| {
+            page.constraint_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLaunchPaths` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_launch_paths_pages(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> impl futures::Stream<Item = Result<ListLaunchPathsOutput, RusotoError<ListLaunchPathsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move { client.list_launch_paths(input).await },
+            |input: &ListLaunchPathsInput, output: &ListLaunchPathsOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListLaunchPathsInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LaunchPathSummaries` of every page of `ListLaunchPaths` results for `input`, as `list_launch_paths_pages` requests them.
+    pub fn list_launch_paths_stream(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> impl futures::Stream<Item = Result<LaunchPathSummary, RusotoError<ListLaunchPathsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_launch_paths_pages(input), |page| {
+            page.launch_path_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOrganizationPortfolioAccess` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_organization_portfolio_access_pages(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListOrganizationPortfolioAccessOutput,
+            RusotoError<ListOrganizationPortfolioAccessError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.list_organization_portfolio_access(input).await
+            },
+            |input: &ListOrganizationPortfolioAccessInput,
+             output: &ListOrganizationPortfolioAccessOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListOrganizationPortfolioAccessInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrganizationNodes` of every page of `ListOrganizationPortfolioAccess` results for `input`, as `list_organization_portfolio_access_pages` requests them.
+    pub fn list_organization_portfolio_access_stream(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> impl futures::Stream<
+        Item = Result<OrganizationNode, RusotoError<ListOrganizationPortfolioAccessError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_organization_portfolio_access_pages(input),
+            |page| page.organization_nodes.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListPortfolios` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_portfolios_pages(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> impl futures::Stream<Item = Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move { client.list_portfolios(input).await },
+            |input: &ListPortfoliosInput, output: &ListPortfoliosOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListPortfoliosInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PortfolioDetails` of every page of `ListPortfolios` results for `input`, as `list_portfolios_pages` requests them.
+    pub fn list_portfolios_stream(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> impl futures::Stream<Item = Result<PortfolioDetail, RusotoError<ListPortfoliosError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_portfolios_pages(input), |page| {
+            page.portfolio_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPortfoliosForProduct` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_portfolios_for_product_pages(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListPortfoliosForProductOutput, RusotoError<ListPortfoliosForProductError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.list_portfolios_for_product(input).await
+            },
+            |input: &ListPortfoliosForProductInput, output: &ListPortfoliosForProductOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListPortfoliosForProductInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PortfolioDetails` of every page of `ListPortfoliosForProduct` results for `input`, as `list_portfolios_for_product_pages` requests them.
+    pub fn list_portfolios_for_product_stream(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> impl futures::Stream<
+        Item = Result<PortfolioDetail, RusotoError<ListPortfoliosForProductError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_portfolios_for_product_pages(input), |page| {
+            page.portfolio_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPrincipalsForPortfolio` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_principals_for_portfolio_pages(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListPrincipalsForPortfolioOutput,
+            RusotoError<ListPrincipalsForPortfolioError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.list_principals_for_portfolio(input).await
+            },
+            |input: &ListPrincipalsForPortfolioInput, output: &ListPrincipalsForPortfolioOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListPrincipalsForPortfolioInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Principals` of every page of `ListPrincipalsForPortfolio` results for `input`, as `list_principals_for_portfolio_pages` requests them.
+    pub fn list_principals_for_portfolio_stream(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> impl futures::Stream<Item = Result<Principal, RusotoError<ListPrincipalsForPortfolioError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_principals_for_portfolio_pages(input), |page| {
+            page.principals.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListProvisionedProductPlans` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_provisioned_product_plans_pages(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListProvisionedProductPlansOutput,
+            RusotoError<ListProvisionedProductPlansError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.list_provisioned_product_plans(input).await
+            },
+            |input: &ListProvisionedProductPlansInput,
+             output: &ListProvisionedProductPlansOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListProvisionedProductPlansInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProvisionedProductPlans` of every page of `ListProvisionedProductPlans` results for `input`, as `list_provisioned_product_plans_pages` requests them.
+    pub fn list_provisioned_product_plans_stream(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> impl futures::Stream<
+        Item = Result<ProvisionedProductPlanSummary, RusotoError<ListProvisionedProductPlansError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_provisioned_product_plans_pages(input), |page| {
+            page.provisioned_product_plans.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListProvisioningArtifactsForServiceAction` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_provisioning_artifacts_for_service_action_pages(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListProvisioningArtifactsForServiceActionOutput,
+            RusotoError<ListProvisioningArtifactsForServiceActionError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client
+                    .list_provisioning_artifacts_for_service_action(input)
+                    .await
+            },
+            |input: &ListProvisioningArtifactsForServiceActionInput,
+             output: &ListProvisioningArtifactsForServiceActionOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListProvisioningArtifactsForServiceActionInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProvisioningArtifactViews` of every page of `ListProvisioningArtifactsForServiceAction` results for `input`, as `list_provisioning_artifacts_for_service_action_pages` requests them.
+    pub fn list_provisioning_artifacts_for_service_action_stream(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ProvisioningArtifactView,
+            RusotoError<ListProvisioningArtifactsForServiceActionError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_provisioning_artifacts_for_service_action_pages(input),
+            |page| page.provisioning_artifact_views.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListRecordHistory` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_record_history_pages(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListRecordHistoryOutput, RusotoError<ListRecordHistoryError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move { client.list_record_history(input).await },
+            |input: &ListRecordHistoryInput, output: &ListRecordHistoryOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListRecordHistoryInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RecordDetails` of every page of `ListRecordHistory` results for `input`, as `list_record_history_pages` requests them.
+    pub fn list_record_history_stream(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> impl futures::Stream<Item = Result<RecordDetail, RusotoError<ListRecordHistoryError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_record_history_pages(input), |page| {
+            page.record_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListResourcesForTagOption` results for `input`, requesting each next page with the `PageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_resources_for_tag_option_pages(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListResourcesForTagOptionOutput, RusotoError<ListResourcesForTagOptionError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.list_resources_for_tag_option(input).await
+            },
+            |input: &ListResourcesForTagOptionInput, output: &ListResourcesForTagOptionOutput| {
+                let page_token = output.page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListResourcesForTagOptionInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceDetails` of every page of `ListResourcesForTagOption` results for `input`, as `list_resources_for_tag_option_pages` requests them.
+    pub fn list_resources_for_tag_option_stream(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> impl futures::Stream<
+        Item = Result<ResourceDetail, RusotoError<ListResourcesForTagOptionError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_resources_for_tag_option_pages(input), |page| {
+            page.resource_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListServiceActions` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_service_actions_pages(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListServiceActionsOutput, RusotoError<ListServiceActionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.list_service_actions(input).await
+            },
+            |input: &ListServiceActionsInput, output: &ListServiceActionsOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListServiceActionsInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ServiceActionSummaries` of every page of `ListServiceActions` results for `input`, as `list_service_actions_pages` requests them.
+    pub fn list_service_actions_stream(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ServiceActionSummary, RusotoError<ListServiceActionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_service_actions_pages(input), |page| {
+            page.service_action_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListServiceActionsForProvisioningArtifact` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_service_actions_for_provisioning_artifact_pages(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListServiceActionsForProvisioningArtifactOutput,
+            RusotoError<ListServiceActionsForProvisioningArtifactError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client
+                    .list_service_actions_for_provisioning_artifact(input)
+                    .await
+            },
+            |input: &ListServiceActionsForProvisioningArtifactInput,
+             output: &ListServiceActionsForProvisioningArtifactOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListServiceActionsForProvisioningArtifactInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ServiceActionSummaries` of every page of `ListServiceActionsForProvisioningArtifact` results for `input`, as `list_service_actions_for_provisioning_artifact_pages` requests them.
+    pub fn list_service_actions_for_provisioning_artifact_stream(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ServiceActionSummary,
+            RusotoError<ListServiceActionsForProvisioningArtifactError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_service_actions_for_provisioning_artifact_pages(input),
+            |page| page.service_action_summaries.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListTagOptions` results for `input`, requesting each next page with the `PageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn list_tag_options_pages(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> impl futures::Stream<Item = Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move { client.list_tag_options(input).await },
+            |input: &ListTagOptionsInput, output: &ListTagOptionsOutput| {
+                let page_token = output.page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ListTagOptionsInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TagOptionDetails` of every page of `ListTagOptions` results for `input`, as `list_tag_options_pages` requests them.
+    pub fn list_tag_options_stream(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> impl futures::Stream<Item = Result<TagOptionDetail, RusotoError<ListTagOptionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tag_options_pages(input), |page| {
+            page.tag_option_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ScanProvisionedProducts` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn scan_provisioned_products_pages(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ScanProvisionedProductsOutput, RusotoError<ScanProvisionedProductsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.scan_provisioned_products(input).await
+            },
+            |input: &ScanProvisionedProductsInput, output: &ScanProvisionedProductsOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(ScanProvisionedProductsInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProvisionedProducts` of every page of `ScanProvisionedProducts` results for `input`, as `scan_provisioned_products_pages` requests them.
+    pub fn scan_provisioned_products_stream(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ProvisionedProductDetail, RusotoError<ScanProvisionedProductsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.scan_provisioned_products_pages(input), |page| {
+            page.provisioned_products.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SearchProductsAsAdmin` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `PageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn search_products_as_admin_pages(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> impl futures::Stream<
+        Item = Result<SearchProductsAsAdminOutput, RusotoError<SearchProductsAsAdminError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceCatalogClient, input| async move {
+                client.search_products_as_admin(input).await
+            },
+            |input: &SearchProductsAsAdminInput, output: &SearchProductsAsAdminOutput| {
+                let page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if page_token.is_none() || page_token == input.page_token {
+                    return None;
+                }
+                Some(SearchProductsAsAdminInput {
+                    page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ProductViewDetails` of every page of `SearchProductsAsAdmin` results for `input`, as `search_products_as_admin_pages` requests them.
+    pub fn search_products_as_admin_stream(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> impl futures::Stream<
+        Item = Result<ProductViewDetail, RusotoError<SearchProductsAsAdminError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.search_products_as_admin_pages(input), |page| {
+            page.product_view_details.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ServiceCatalog for ServiceCatalogClient {
+    /// <p>Accepts an offer to share the specified portfolio.</p>
+    async fn accept_portfolio_share(
+        &self,
+        input: AcceptPortfolioShareInput,
+    ) -> Result<AcceptPortfolioShareOutput, RusotoError<AcceptPortfolioShareError>> {
+        self.accept_portfolio_share_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTagOptionOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTagOptionError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified budget with the specified resource.</p>
+    async fn associate_budget_with_resource(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> Result<AssociateBudgetWithResourceOutput, RusotoError<AssociateBudgetWithResourceError>>
+    {
+        self.associate_budget_with_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified principal ARN with the specified portfolio.</p>
+    async fn associate_principal_with_portfolio(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> Result<
+        AssociatePrincipalWithPortfolioOutput,
+        RusotoError<AssociatePrincipalWithPortfolioError>,
+    > {
+        self.associate_principal_with_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified product with the specified portfolio.</p>
+    async fn associate_product_with_portfolio(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> Result<AssociateProductWithPortfolioOutput, RusotoError<AssociateProductWithPortfolioError>>
+    {
+        self.associate_product_with_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a self-service action with a provisioning artifact.</p>
+    async fn associate_service_action_with_provisioning_artifact(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> Result<
+        AssociateServiceActionWithProvisioningArtifactOutput,
+        RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
+    > {
+        self.associate_service_action_with_provisioning_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associate the specified TagOption with the specified portfolio or product.</p>
+    async fn associate_tag_option_with_resource(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> Result<
+        AssociateTagOptionWithResourceOutput,
+        RusotoError<AssociateTagOptionWithResourceError>,
+    > {
+        self.associate_tag_option_with_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates multiple self-service actions with provisioning artifacts.</p>
+    async fn batch_associate_service_action_with_provisioning_artifact(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> Result<
+        BatchAssociateServiceActionWithProvisioningArtifactOutput,
+        RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
+    > {
+        self.batch_associate_service_action_with_provisioning_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a batch of self-service actions from the specified provisioning artifact.</p>
+    async fn batch_disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> Result<
+        BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+        RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
+    > {
+        self.batch_disassociate_service_action_from_provisioning_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Copies the specified source product to the specified target product or a new product.</p> <p>You can copy a product to the same account or another account. You can copy a product to the same region or another region.</p> <p>This operation is performed asynchronously. To track the progress of the operation, use <a>DescribeCopyProductStatus</a>.</p>
+    async fn copy_product(
+        &self,
+        input: CopyProductInput,
+    ) -> Result<CopyProductOutput, RusotoError<CopyProductError>> {
+        self.copy_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a constraint.</p>
+    async fn create_constraint(
+        &self,
+        input: CreateConstraintInput,
+    ) -> Result<CreateConstraintOutput, RusotoError<CreateConstraintError>> {
+        self.create_constraint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a portfolio.</p>
+    async fn create_portfolio(
+        &self,
+        input: CreatePortfolioInput,
+    ) -> Result<CreatePortfolioOutput, RusotoError<CreatePortfolioError>> {
+        self.create_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Shares the specified portfolio with the specified account or organization node. Shares to an organization node can only be created by the master account of an Organization. AWSOrganizationsAccess must be enabled in order to create a portfolio share to an organization node.</p>
+    async fn create_portfolio_share(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> Result<CreatePortfolioShareOutput, RusotoError<CreatePortfolioShareError>> {
+        self.create_portfolio_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a product.</p>
+    async fn create_product(
+        &self,
+        input: CreateProductInput,
+    ) -> Result<CreateProductOutput, RusotoError<CreateProductError>> {
+        self.create_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a plan. A plan includes the list of resources to be created (when provisioning a new product) or modified (when updating a provisioned product) when the plan is executed.</p> <p>You can create one plan per provisioned product. To create a plan for an existing provisioned product, the product status must be AVAILBLE or TAINTED.</p> <p>To view the resource changes in the change set, use <a>DescribeProvisionedProductPlan</a>. To create or modify the provisioned product, use <a>ExecuteProvisionedProductPlan</a>.</p>
+    async fn create_provisioned_product_plan(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> Result<CreateProvisionedProductPlanOutput, RusotoError<CreateProvisionedProductPlanError>>
+    {
+        self.create_provisioned_product_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot create a provisioning artifact for a product that was shared with you.</p>
+    async fn create_provisioning_artifact(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> Result<CreateProvisioningArtifactOutput, RusotoError<CreateProvisioningArtifactError>>
+    {
+        self.create_provisioning_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a self-service action.</p>
+    async fn create_service_action(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> Result<CreateServiceActionOutput, RusotoError<CreateServiceActionError>> {
+        self.create_service_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a TagOption.</p>
+    async fn create_tag_option(
+        &self,
+        input: CreateTagOptionInput,
+    ) -> Result<CreateTagOptionOutput, RusotoError<CreateTagOptionError>> {
+        self.create_tag_option_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified constraint.</p>
+    async fn delete_constraint(
+        &self,
+        input: DeleteConstraintInput,
+    ) -> Result<DeleteConstraintOutput, RusotoError<DeleteConstraintError>> {
+        self.delete_constraint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified portfolio.</p> <p>You cannot delete a portfolio if it was shared with you or if it has associated products, users, constraints, or shared accounts.</p>
+    async fn delete_portfolio(
+        &self,
+        input: DeletePortfolioInput,
+    ) -> Result<DeletePortfolioOutput, RusotoError<DeletePortfolioError>> {
+        self.delete_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops sharing the specified portfolio with the specified account or organization node. Shares to an organization node can only be deleted by the master account of an Organization.</p>
+    async fn delete_portfolio_share(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> Result<DeletePortfolioShareOutput, RusotoError<DeletePortfolioShareError>> {
+        self.delete_portfolio_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified product.</p> <p>You cannot delete a product if it was shared with you or is associated with a portfolio.</p>
+    async fn delete_product(
+        &self,
+        input: DeleteProductInput,
+    ) -> Result<DeleteProductOutput, RusotoError<DeleteProductError>> {
+        self.delete_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified plan.</p>
+    async fn delete_provisioned_product_plan(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> Result<DeleteProvisionedProductPlanOutput, RusotoError<DeleteProvisionedProductPlanError>>
+    {
+        self.delete_provisioned_product_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot delete a provisioning artifact associated with a product that was shared with you. You cannot delete the last provisioning artifact for a product, because a product must have at least one provisioning artifact.</p>
+    async fn delete_provisioning_artifact(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> Result<DeleteProvisioningArtifactOutput, RusotoError<DeleteProvisioningArtifactError>>
+    {
+        self.delete_provisioning_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a self-service action.</p>
+    async fn delete_service_action(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> Result<DeleteServiceActionOutput, RusotoError<DeleteServiceActionError>> {
+        self.delete_service_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified TagOption.</p> <p>You cannot delete a TagOption if it is associated with a product or portfolio.</p>
+    async fn delete_tag_option(
+        &self,
+        input: DeleteTagOptionInput,
+    ) -> Result<DeleteTagOptionOutput, RusotoError<DeleteTagOptionError>> {
+        self.delete_tag_option_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the specified constraint.</p>
+    async fn describe_constraint(
+        &self,
+        input: DescribeConstraintInput,
+    ) -> Result<DescribeConstraintOutput, RusotoError<DescribeConstraintError>> {
+        self.describe_constraint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the status of the specified copy product operation.</p>
+    async fn describe_copy_product_status(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> Result<DescribeCopyProductStatusOutput, RusotoError<DescribeCopyProductStatusError>> {
+        self.describe_copy_product_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the specified portfolio.</p>
+    async fn describe_portfolio(
+        &self,
+        input: DescribePortfolioInput,
+    ) -> Result<DescribePortfolioOutput, RusotoError<DescribePortfolioError>> {
+        self.describe_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the status of the specified portfolio share operation. This API can only be called by the master account in the organization.</p>
+    async fn describe_portfolio_share_status(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> Result<DescribePortfolioShareStatusOutput, RusotoError<DescribePortfolioShareStatusError>>
+    {
+        self.describe_portfolio_share_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the specified product.</p>
+    async fn describe_product(
+        &self,
+        input: DescribeProductInput,
+    ) -> Result<DescribeProductOutput, RusotoError<DescribeProductError>> {
+        self.describe_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the specified product. This operation is run with administrator access.</p>
+    async fn describe_product_as_admin(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> Result<DescribeProductAsAdminOutput, RusotoError<DescribeProductAsAdminError>> {
+        self.describe_product_as_admin_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the specified product.</p>
+    async fn describe_product_view(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> Result<DescribeProductViewOutput, RusotoError<DescribeProductViewError>> {
+        self.describe_product_view_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the specified provisioned product.</p>
+    async fn describe_provisioned_product(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> Result<DescribeProvisionedProductOutput, RusotoError<DescribeProvisionedProductError>>
+    {
+        self.describe_provisioned_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the resource changes for the specified plan.</p>
+    async fn describe_provisioned_product_plan(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> Result<
+        DescribeProvisionedProductPlanOutput,
+        RusotoError<DescribeProvisionedProductPlanError>,
+    > {
+        self.describe_provisioned_product_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the specified provisioning artifact (also known as a version) for the specified product.</p>
+    async fn describe_provisioning_artifact(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> Result<DescribeProvisioningArtifactOutput, RusotoError<DescribeProvisioningArtifactError>>
+    {
+        self.describe_provisioning_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the configuration required to provision the specified product using the specified provisioning artifact.</p> <p>If the output contains a TagOption key with an empty list of values, there is a TagOption conflict for that key. The end user cannot take action to fix the conflict, and launch is not blocked. In subsequent calls to <a>ProvisionProduct</a>, do not include conflicted TagOption keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>". Tag the provisioned product with the value <code>sc-tagoption-conflict-portfolioId-productId</code>.</p>
+    async fn describe_provisioning_parameters(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> Result<
+        DescribeProvisioningParametersOutput,
+        RusotoError<DescribeProvisioningParametersError>,
+    > {
+        self.describe_provisioning_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Gets information about the specified request operation.</p> <p>Use this operation after calling a request operation (for example, <a>ProvisionProduct</a>, <a>TerminateProvisionedProduct</a>, or <a>UpdateProvisionedProduct</a>). </p> <note> <p>If a provisioned product was transferred to a new owner using <a>UpdateProvisionedProductProperties</a>, the new owner will be able to describe all past records for that product. The previous owner will no longer be able to describe the records, but will be able to use <a>ListRecordHistory</a> to see the product&#39;s history from when he was the owner.</p> </note></p>
+    async fn describe_record(
+        &self,
+        input: DescribeRecordInput,
+    ) -> Result<DescribeRecordOutput, RusotoError<DescribeRecordError>> {
+        self.describe_record_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a self-service action.</p>
+    async fn describe_service_action(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> Result<DescribeServiceActionOutput, RusotoError<DescribeServiceActionError>> {
+        self.describe_service_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn describe_service_action_execution_parameters(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> Result<
+        DescribeServiceActionExecutionParametersOutput,
+        RusotoError<DescribeServiceActionExecutionParametersError>,
+    > {
+        self.describe_service_action_execution_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the specified TagOption.</p>
+    async fn describe_tag_option(
+        &self,
+        input: DescribeTagOptionInput,
+    ) -> Result<DescribeTagOptionOutput, RusotoError<DescribeTagOptionError>> {
+        self.describe_tag_option_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disable portfolio sharing through AWS Organizations feature. This feature will not delete your current shares but it will prevent you from creating new shares throughout your organization. Current shares will not be in sync with your organization structure if it changes after calling this API. This API can only be called by the master account in the organization.</p>
+    async fn disable_aws_organizations_access(
+        &self,
+    ) -> Result<DisableAWSOrganizationsAccessOutput, RusotoError<DisableAWSOrganizationsAccessError>>
+    {
+        self.disable_aws_organizations_access_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified budget from the specified resource.</p>
+    async fn disassociate_budget_from_resource(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> Result<
+        DisassociateBudgetFromResourceOutput,
+        RusotoError<DisassociateBudgetFromResourceError>,
+    > {
+        self.disassociate_budget_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a previously associated principal ARN from a specified portfolio.</p>
+    async fn disassociate_principal_from_portfolio(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> Result<
+        DisassociatePrincipalFromPortfolioOutput,
+        RusotoError<DisassociatePrincipalFromPortfolioError>,
+    > {
+        self.disassociate_principal_from_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified product from the specified portfolio. </p>
+    async fn disassociate_product_from_portfolio(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> Result<
+        DisassociateProductFromPortfolioOutput,
+        RusotoError<DisassociateProductFromPortfolioError>,
+    > {
+        self.disassociate_product_from_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified self-service action association from the specified provisioning artifact.</p>
+    async fn disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> Result<
+        DisassociateServiceActionFromProvisioningArtifactOutput,
+        RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
+    > {
+        self.disassociate_service_action_from_provisioning_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified TagOption from the specified resource.</p>
+    async fn disassociate_tag_option_from_resource(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> Result<
+        DisassociateTagOptionFromResourceOutput,
+        RusotoError<DisassociateTagOptionFromResourceError>,
+    > {
+        self.disassociate_tag_option_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enable portfolio sharing feature through AWS Organizations. This API will allow Service Catalog to receive updates on your organization in order to sync your shares with the current structure. This API can only be called by the master account in the organization.</p> <p>By calling this API Service Catalog will make a call to organizations:EnableAWSServiceAccess on your behalf so that your shares can be in sync with any changes in your AWS Organizations structure.</p>
+    async fn enable_aws_organizations_access(
+        &self,
+    ) -> Result<EnableAWSOrganizationsAccessOutput, RusotoError<EnableAWSOrganizationsAccessError>>
+    {
+        self.enable_aws_organizations_access_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provisions or modifies a product based on the resource changes for the specified plan.</p>
+    async fn execute_provisioned_product_plan(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> Result<ExecuteProvisionedProductPlanOutput, RusotoError<ExecuteProvisionedProductPlanError>>
+    {
+        self.execute_provisioned_product_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Executes a self-service action against a provisioned product.</p>
+    async fn execute_provisioned_product_service_action(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> Result<
+        ExecuteProvisionedProductServiceActionOutput,
+        RusotoError<ExecuteProvisionedProductServiceActionError>,
+    > {
+        self.execute_provisioned_product_service_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the Access Status for AWS Organization portfolio share feature. This API can only be called by the master account in the organization.</p>
+    async fn get_aws_organizations_access_status(
+        &self,
+    ) -> Result<
+        GetAWSOrganizationsAccessStatusOutput,
+        RusotoError<GetAWSOrganizationsAccessStatusError>,
+    > {
+        self.get_aws_organizations_access_status_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all portfolios for which sharing was accepted by this account.</p>
+    async fn list_accepted_portfolio_shares(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> Result<ListAcceptedPortfolioSharesOutput, RusotoError<ListAcceptedPortfolioSharesError>>
+    {
+        self.list_accepted_portfolio_shares_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the budgets associated to the specified resource.</p>
+    async fn list_budgets_for_resource(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> Result<ListBudgetsForResourceOutput, RusotoError<ListBudgetsForResourceError>> {
+        self.list_budgets_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the constraints for the specified portfolio and product.</p>
+    async fn list_constraints_for_portfolio(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> Result<ListConstraintsForPortfolioOutput, RusotoError<ListConstraintsForPortfolioError>>
+    {
+        self.list_constraints_for_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the paths to the specified product. A path is how the user has access to a specified product, and is necessary when provisioning a product. A path also determines the constraints put on the product.</p>
+    async fn list_launch_paths(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> Result<ListLaunchPathsOutput, RusotoError<ListLaunchPathsError>> {
+        self.list_launch_paths_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the organization nodes that have access to the specified portfolio. This API can only be called by the master account in the organization.</p>
+    async fn list_organization_portfolio_access(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> Result<
+        ListOrganizationPortfolioAccessOutput,
+        RusotoError<ListOrganizationPortfolioAccessError>,
+    > {
+        self.list_organization_portfolio_access_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the account IDs that have access to the specified portfolio.</p>
+    async fn list_portfolio_access(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> Result<ListPortfolioAccessOutput, RusotoError<ListPortfolioAccessError>> {
+        self.list_portfolio_access_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all portfolios in the catalog.</p>
+    async fn list_portfolios(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>> {
+        self.list_portfolios_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all portfolios that the specified product is associated with.</p>
+    async fn list_portfolios_for_product(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> Result<ListPortfoliosForProductOutput, RusotoError<ListPortfoliosForProductError>> {
+        self.list_portfolios_for_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all principal ARNs associated with the specified portfolio.</p>
+    async fn list_principals_for_portfolio(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> Result<ListPrincipalsForPortfolioOutput, RusotoError<ListPrincipalsForPortfolioError>>
+    {
+        self.list_principals_for_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the plans for the specified provisioned product or all plans to which the user has access.</p>
+    async fn list_provisioned_product_plans(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> Result<ListProvisionedProductPlansOutput, RusotoError<ListProvisionedProductPlansError>>
+    {
+        self.list_provisioned_product_plans_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all provisioning artifacts (also known as versions) for the specified product.</p>
+    async fn list_provisioning_artifacts(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> Result<ListProvisioningArtifactsOutput, RusotoError<ListProvisioningArtifactsError>> {
+        self.list_provisioning_artifacts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all provisioning artifacts (also known as versions) for the specified self-service action.</p>
+    async fn list_provisioning_artifacts_for_service_action(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> Result<
+        ListProvisioningArtifactsForServiceActionOutput,
+        RusotoError<ListProvisioningArtifactsForServiceActionError>,
+    > {
+        self.list_provisioning_artifacts_for_service_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the specified requests or all performed requests.</p>
+    async fn list_record_history(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> Result<ListRecordHistoryOutput, RusotoError<ListRecordHistoryError>> {
+        self.list_record_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resources associated with the specified TagOption.</p>
+    async fn list_resources_for_tag_option(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> Result<ListResourcesForTagOptionOutput, RusotoError<ListResourcesForTagOptionError>> {
+        self.list_resources_for_tag_option_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all self-service actions.</p>
+    async fn list_service_actions(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> Result<ListServiceActionsOutput, RusotoError<ListServiceActionsError>> {
+        self.list_service_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a paginated list of self-service actions associated with the specified Product ID and Provisioning Artifact ID.</p>
+    async fn list_service_actions_for_provisioning_artifact(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> Result<
+        ListServiceActionsForProvisioningArtifactOutput,
+        RusotoError<ListServiceActionsForProvisioningArtifactError>,
+    > {
+        self.list_service_actions_for_provisioning_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns summary information about stack instances that are associated with the specified <code>CFN_STACKSET</code> type provisioned product. You can filter for stack instances that are associated with a specific AWS account name or region. </p>
+    async fn list_stack_instances_for_provisioned_product(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> Result<
+        ListStackInstancesForProvisionedProductOutput,
+        RusotoError<ListStackInstancesForProvisionedProductError>,
+    > {
+        self.list_stack_instances_for_provisioned_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the specified TagOptions or all TagOptions.</p>
+    async fn list_tag_options(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>> {
+        self.list_tag_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provisions the specified product.</p> <p>A provisioned product is a resourced instance of a product. For example, provisioning a product based on a CloudFormation template launches a CloudFormation stack and its underlying resources. You can check the status of this request using <a>DescribeRecord</a>.</p> <p>If the request contains a tag key with an empty list of values, there is a tag conflict for that key. Do not include conflicted keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>".</p>
+    async fn provision_product(
+        &self,
+        input: ProvisionProductInput,
+    ) -> Result<ProvisionProductOutput, RusotoError<ProvisionProductError>> {
+        self.provision_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Rejects an offer to share the specified portfolio.</p>
+    async fn reject_portfolio_share(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> Result<RejectPortfolioShareOutput, RusotoError<RejectPortfolioShareError>> {
+        self.reject_portfolio_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the provisioned products that are available (not terminated).</p> <p>To use additional filtering, see <a>SearchProvisionedProducts</a>.</p>
+    async fn scan_provisioned_products(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> Result<ScanProvisionedProductsOutput, RusotoError<ScanProvisionedProductsError>> {
+        self.scan_provisioned_products_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the products to which the caller has access.</p>
+    async fn search_products(
+        &self,
+        input: SearchProductsInput,
+    ) -> Result<SearchProductsOutput, RusotoError<SearchProductsError>> {
+        self.search_products_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the products for the specified portfolio or all products.</p>
+    async fn search_products_as_admin(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> Result<SearchProductsAsAdminOutput, RusotoError<SearchProductsAsAdminError>> {
+        self.search_products_as_admin_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the provisioned products that meet the specified criteria.</p>
+    async fn search_provisioned_products(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> Result<SearchProvisionedProductsOutput, RusotoError<SearchProvisionedProductsError>> {
+        self.search_provisioned_products_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Terminates the specified provisioned product.</p> <p>This operation does not delete any records associated with the provisioned product.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    async fn terminate_provisioned_product(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> Result<TerminateProvisionedProductOutput, RusotoError<TerminateProvisionedProductError>>
+    {
+        self.terminate_provisioned_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified constraint.</p>
+    async fn update_constraint(
+        &self,
+        input: UpdateConstraintInput,
+    ) -> Result<UpdateConstraintOutput, RusotoError<UpdateConstraintError>> {
+        self.update_constraint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified portfolio.</p> <p>You cannot update a product that was shared with you.</p>
+    async fn update_portfolio(
+        &self,
+        input: UpdatePortfolioInput,
+    ) -> Result<UpdatePortfolioOutput, RusotoError<UpdatePortfolioError>> {
+        self.update_portfolio_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified product.</p>
+    async fn update_product(
+        &self,
+        input: UpdateProductInput,
+    ) -> Result<UpdateProductOutput, RusotoError<UpdateProductError>> {
+        self.update_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests updates to the configuration of the specified provisioned product.</p> <p>If there are tags associated with the object, they cannot be updated or added. Depending on the specific updates requested, this operation can update with no interruption, with some interruption, or replace the provisioned product entirely.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    async fn update_provisioned_product(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> Result<UpdateProvisionedProductOutput, RusotoError<UpdateProvisionedProductError>> {
+        self.update_provisioned_product_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests updates to the properties of the specified provisioned product.</p>
+    async fn update_provisioned_product_properties(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> Result<
+        UpdateProvisionedProductPropertiesOutput,
+        RusotoError<UpdateProvisionedProductPropertiesError>,
+    > {
+        self.update_provisioned_product_properties_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot update a provisioning artifact for a product that was shared with you.</p>
+    async fn update_provisioning_artifact(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> Result<UpdateProvisioningArtifactOutput, RusotoError<UpdateProvisioningArtifactError>>
+    {
+        self.update_provisioning_artifact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a self-service action.</p>
+    async fn update_service_action(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> Result<UpdateServiceActionOutput, RusotoError<UpdateServiceActionError>> {
+        self.update_service_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified TagOption.</p>
+    async fn update_tag_option(
+        &self,
+        input: UpdateTagOptionInput,
+    ) -> Result<UpdateTagOptionOutput, RusotoError<UpdateTagOptionError>> {
+        self.update_tag_option_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -942,6 +942,7 @@ pub struct UpdateServiceResponse {
 
 /// Errors returned by CreateHttpNamespace
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHttpNamespaceError {
     /// <p>The operation is already in progress.</p>
     DuplicateRequest(String),
@@ -951,6 +952,8 @@ pub enum CreateHttpNamespaceError {
     NamespaceAlreadyExists(String),
     /// <p>The resource can't be created because you've reached the limit on the number of resources.</p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHttpNamespaceError {
@@ -958,25 +961,31 @@ impl CreateHttpNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHttpNamespaceError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateHttpNamespaceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NamespaceAlreadyExists" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::NamespaceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHttpNamespaceError::NamespaceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateHttpNamespaceError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHttpNamespaceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -990,12 +999,14 @@ impl fmt::Display for CreateHttpNamespaceError {
             CreateHttpNamespaceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateHttpNamespaceError::NamespaceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateHttpNamespaceError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateHttpNamespaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHttpNamespaceError {}
 /// Errors returned by CreatePrivateDnsNamespace
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePrivateDnsNamespaceError {
     /// <p>The operation is already in progress.</p>
     DuplicateRequest(String),
@@ -1005,6 +1016,8 @@ pub enum CreatePrivateDnsNamespaceError {
     NamespaceAlreadyExists(String),
     /// <p>The resource can't be created because you've reached the limit on the number of resources.</p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePrivateDnsNamespaceError {
@@ -1012,27 +1025,33 @@ impl CreatePrivateDnsNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(CreatePrivateDnsNamespaceError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePrivateDnsNamespaceError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(CreatePrivateDnsNamespaceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePrivateDnsNamespaceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NamespaceAlreadyExists" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePrivateDnsNamespaceError::NamespaceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePrivateDnsNamespaceError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreatePrivateDnsNamespaceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1050,12 +1069,14 @@ impl fmt::Display for CreatePrivateDnsNamespaceError {
             CreatePrivateDnsNamespaceError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreatePrivateDnsNamespaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePrivateDnsNamespaceError {}
 /// Errors returned by CreatePublicDnsNamespace
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePublicDnsNamespaceError {
     /// <p>The operation is already in progress.</p>
     DuplicateRequest(String),
@@ -1065,6 +1086,8 @@ pub enum CreatePublicDnsNamespaceError {
     NamespaceAlreadyExists(String),
     /// <p>The resource can't be created because you've reached the limit on the number of resources.</p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePublicDnsNamespaceError {
@@ -1072,27 +1095,31 @@ impl CreatePublicDnsNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(CreatePublicDnsNamespaceError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePublicDnsNamespaceError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(CreatePublicDnsNamespaceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePublicDnsNamespaceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NamespaceAlreadyExists" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePublicDnsNamespaceError::NamespaceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePublicDnsNamespaceError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePublicDnsNamespaceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1110,12 +1137,14 @@ impl fmt::Display for CreatePublicDnsNamespaceError {
             CreatePublicDnsNamespaceError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreatePublicDnsNamespaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePublicDnsNamespaceError {}
 /// Errors returned by CreateService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateServiceError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
@@ -1125,6 +1154,8 @@ pub enum CreateServiceError {
     ResourceLimitExceeded(String),
     /// <p>The service can't be created because a service with the same name already exists.</p>
     ServiceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateServiceError {
@@ -1132,19 +1163,28 @@ impl CreateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(CreateServiceError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateServiceError::InvalidInput(err.msg), &res)
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(CreateServiceError::NamespaceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateServiceError::NamespaceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateServiceError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateServiceError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAlreadyExists" => {
-                    return RusotoError::Service(CreateServiceError::ServiceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateServiceError::ServiceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1158,12 +1198,14 @@ impl fmt::Display for CreateServiceError {
             CreateServiceError::NamespaceNotFound(ref cause) => write!(f, "{}", cause),
             CreateServiceError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateServiceError::ServiceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateServiceError {}
 /// Errors returned by DeleteNamespace
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNamespaceError {
     /// <p>The operation is already in progress.</p>
     DuplicateRequest(String),
@@ -1173,6 +1215,8 @@ pub enum DeleteNamespaceError {
     NamespaceNotFound(String),
     /// <p>The specified resource can't be deleted because it contains other resources. For example, you can't delete a service that contains any instances.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNamespaceError {
@@ -1180,19 +1224,25 @@ impl DeleteNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(DeleteNamespaceError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteNamespaceError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(DeleteNamespaceError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteNamespaceError::InvalidInput(err.msg), &res)
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(DeleteNamespaceError::NamespaceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteNamespaceError::NamespaceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteNamespaceError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteNamespaceError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNamespaceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1206,12 +1256,14 @@ impl fmt::Display for DeleteNamespaceError {
             DeleteNamespaceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteNamespaceError::NamespaceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteNamespaceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteNamespaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNamespaceError {}
 /// Errors returned by DeleteService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteServiceError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
@@ -1219,6 +1271,8 @@ pub enum DeleteServiceError {
     ResourceInUse(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteServiceError {
@@ -1226,16 +1280,16 @@ impl DeleteServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DeleteServiceError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteServiceError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteServiceError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteServiceError::ResourceInUse(err.msg), &res)
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(DeleteServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(DeleteServiceError::ServiceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1248,12 +1302,14 @@ impl fmt::Display for DeleteServiceError {
             DeleteServiceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteServiceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteServiceError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteServiceError {}
 /// Errors returned by DeregisterInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterInstanceError {
     /// <p>The operation is already in progress.</p>
     DuplicateRequest(String),
@@ -1265,6 +1321,8 @@ pub enum DeregisterInstanceError {
     ResourceInUse(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterInstanceError {
@@ -1272,22 +1330,37 @@ impl DeregisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(DeregisterInstanceError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        DeregisterInstanceError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InstanceNotFound" => {
-                    return RusotoError::Service(DeregisterInstanceError::InstanceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeregisterInstanceError::InstanceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(DeregisterInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeregisterInstanceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeregisterInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeregisterInstanceError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(DeregisterInstanceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeregisterInstanceError::ServiceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1302,12 +1375,14 @@ impl fmt::Display for DeregisterInstanceError {
             DeregisterInstanceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeregisterInstanceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeregisterInstanceError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterInstanceError {}
 /// Errors returned by DiscoverInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DiscoverInstancesError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
@@ -1315,6 +1390,8 @@ pub enum DiscoverInstancesError {
     NamespaceNotFound(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DiscoverInstancesError {
@@ -1322,16 +1399,25 @@ impl DiscoverInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DiscoverInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DiscoverInstancesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(DiscoverInstancesError::NamespaceNotFound(err.msg))
+                    return RusotoError::service(
+                        DiscoverInstancesError::NamespaceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(DiscoverInstancesError::ServiceNotFound(err.msg))
+                    return RusotoError::service(
+                        DiscoverInstancesError::ServiceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DiscoverInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1344,12 +1430,14 @@ impl fmt::Display for DiscoverInstancesError {
             DiscoverInstancesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DiscoverInstancesError::NamespaceNotFound(ref cause) => write!(f, "{}", cause),
             DiscoverInstancesError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            DiscoverInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DiscoverInstancesError {}
 /// Errors returned by GetInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstanceError {
     /// <p>No instance exists with the specified ID, or the instance was recently registered, and information about the instance hasn't propagated yet.</p>
     InstanceNotFound(String),
@@ -1357,6 +1445,8 @@ pub enum GetInstanceError {
     InvalidInput(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstanceError {
@@ -1364,16 +1454,16 @@ impl GetInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNotFound" => {
-                    return RusotoError::Service(GetInstanceError::InstanceNotFound(err.msg))
+                    return RusotoError::service(GetInstanceError::InstanceNotFound(err.msg), &res)
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(GetInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(GetInstanceError::InvalidInput(err.msg), &res)
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(GetInstanceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(GetInstanceError::ServiceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1386,12 +1476,14 @@ impl fmt::Display for GetInstanceError {
             GetInstanceError::InstanceNotFound(ref cause) => write!(f, "{}", cause),
             GetInstanceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetInstanceError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            GetInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstanceError {}
 /// Errors returned by GetInstancesHealthStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstancesHealthStatusError {
     /// <p>No instance exists with the specified ID, or the instance was recently registered, and information about the instance hasn't propagated yet.</p>
     InstanceNotFound(String),
@@ -1399,6 +1491,8 @@ pub enum GetInstancesHealthStatusError {
     InvalidInput(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstancesHealthStatusError {
@@ -1406,22 +1500,25 @@ impl GetInstancesHealthStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNotFound" => {
-                    return RusotoError::Service(GetInstancesHealthStatusError::InstanceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstancesHealthStatusError::InstanceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(GetInstancesHealthStatusError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstancesHealthStatusError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(GetInstancesHealthStatusError::ServiceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInstancesHealthStatusError::ServiceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstancesHealthStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1434,17 +1531,21 @@ impl fmt::Display for GetInstancesHealthStatusError {
             GetInstancesHealthStatusError::InstanceNotFound(ref cause) => write!(f, "{}", cause),
             GetInstancesHealthStatusError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetInstancesHealthStatusError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            GetInstancesHealthStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstancesHealthStatusError {}
 /// Errors returned by GetNamespace
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetNamespaceError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
     /// <p>No namespace exists with the specified ID.</p>
     NamespaceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetNamespaceError {
@@ -1452,13 +1553,16 @@ impl GetNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetNamespaceError::InvalidInput(err.msg))
+                    return RusotoError::service(GetNamespaceError::InvalidInput(err.msg), &res)
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(GetNamespaceError::NamespaceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetNamespaceError::NamespaceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetNamespaceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1470,17 +1574,21 @@ impl fmt::Display for GetNamespaceError {
         match *self {
             GetNamespaceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetNamespaceError::NamespaceNotFound(ref cause) => write!(f, "{}", cause),
+            GetNamespaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetNamespaceError {}
 /// Errors returned by GetOperation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOperationError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
     /// <p>No operation exists with the specified ID.</p>
     OperationNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOperationError {
@@ -1488,13 +1596,16 @@ impl GetOperationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetOperationError::InvalidInput(err.msg))
+                    return RusotoError::service(GetOperationError::InvalidInput(err.msg), &res)
                 }
                 "OperationNotFound" => {
-                    return RusotoError::Service(GetOperationError::OperationNotFound(err.msg))
+                    return RusotoError::service(
+                        GetOperationError::OperationNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOperationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1506,17 +1617,21 @@ impl fmt::Display for GetOperationError {
         match *self {
             GetOperationError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetOperationError::OperationNotFound(ref cause) => write!(f, "{}", cause),
+            GetOperationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOperationError {}
 /// Errors returned by GetService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetServiceError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetServiceError {
@@ -1524,13 +1639,13 @@ impl GetServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetServiceError::InvalidInput(err.msg))
+                    return RusotoError::service(GetServiceError::InvalidInput(err.msg), &res)
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(GetServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(GetServiceError::ServiceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1542,17 +1657,21 @@ impl fmt::Display for GetServiceError {
         match *self {
             GetServiceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetServiceError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            GetServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetServiceError {}
 /// Errors returned by ListInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInstancesError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInstancesError {
@@ -1560,13 +1679,13 @@ impl ListInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service(ListInstancesError::InvalidInput(err.msg), &res)
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(ListInstancesError::ServiceNotFound(err.msg))
+                    return RusotoError::service(ListInstancesError::ServiceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1578,15 +1697,19 @@ impl fmt::Display for ListInstancesError {
         match *self {
             ListInstancesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListInstancesError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            ListInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInstancesError {}
 /// Errors returned by ListNamespaces
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListNamespacesError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListNamespacesError {
@@ -1594,10 +1717,10 @@ impl ListNamespacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListNamespacesError::InvalidInput(err.msg))
+                    return RusotoError::service(ListNamespacesError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListNamespacesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1608,15 +1731,19 @@ impl fmt::Display for ListNamespacesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListNamespacesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListNamespacesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListNamespacesError {}
 /// Errors returned by ListOperations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOperationsError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOperationsError {
@@ -1624,10 +1751,10 @@ impl ListOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListOperationsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListOperationsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOperationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1638,15 +1765,19 @@ impl fmt::Display for ListOperationsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListOperationsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListOperationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOperationsError {}
 /// Errors returned by ListServices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListServicesError {
     /// <p>One or more specified values aren't valid. For example, a required value might be missing, a numeric value might be outside the allowed range, or a string value might exceed length constraints.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListServicesError {
@@ -1654,10 +1785,10 @@ impl ListServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListServicesError::InvalidInput(err.msg))
+                    return RusotoError::service(ListServicesError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListServicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1668,12 +1799,14 @@ impl fmt::Display for ListServicesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListServicesError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListServicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListServicesError {}
 /// Errors returned by RegisterInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterInstanceError {
     /// <p>The operation is already in progress.</p>
     DuplicateRequest(String),
@@ -1685,6 +1818,8 @@ pub enum RegisterInstanceError {
     ResourceLimitExceeded(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterInstanceError {
@@ -1692,24 +1827,34 @@ impl RegisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(RegisterInstanceError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        RegisterInstanceError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(RegisterInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(RegisterInstanceError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(RegisterInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        RegisterInstanceError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(RegisterInstanceError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterInstanceError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(RegisterInstanceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(
+                        RegisterInstanceError::ServiceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1724,12 +1869,14 @@ impl fmt::Display for RegisterInstanceError {
             RegisterInstanceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             RegisterInstanceError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             RegisterInstanceError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            RegisterInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterInstanceError {}
 /// Errors returned by UpdateInstanceCustomHealthStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateInstanceCustomHealthStatusError {
     /// <p>The health check for the instance that is specified by <code>ServiceId</code> and <code>InstanceId</code> is not a custom health check. </p>
     CustomHealthNotFound(String),
@@ -1739,6 +1886,8 @@ pub enum UpdateInstanceCustomHealthStatusError {
     InvalidInput(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateInstanceCustomHealthStatusError {
@@ -1748,27 +1897,36 @@ impl UpdateInstanceCustomHealthStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomHealthNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInstanceCustomHealthStatusError::CustomHealthNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InstanceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInstanceCustomHealthStatusError::InstanceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInstanceCustomHealthStatusError::InvalidInput(err.msg),
+                        &res,
                     )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateInstanceCustomHealthStatusError::ServiceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateInstanceCustomHealthStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1790,12 +1948,16 @@ impl fmt::Display for UpdateInstanceCustomHealthStatusError {
             UpdateInstanceCustomHealthStatusError::ServiceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateInstanceCustomHealthStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateInstanceCustomHealthStatusError {}
 /// Errors returned by UpdateService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServiceError {
     /// <p>The operation is already in progress.</p>
     DuplicateRequest(String),
@@ -1803,6 +1965,8 @@ pub enum UpdateServiceError {
     InvalidInput(String),
     /// <p>No service exists with the specified ID.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServiceError {
@@ -1810,16 +1974,19 @@ impl UpdateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateServiceError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateServiceError::DuplicateRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateServiceError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateServiceError::InvalidInput(err.msg), &res)
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(UpdateServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(UpdateServiceError::ServiceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1832,6 +1999,7 @@ impl fmt::Display for UpdateServiceError {
             UpdateServiceError::DuplicateRequest(ref cause) => write!(f, "{}", cause),
             UpdateServiceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateServiceError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1970,6 +2138,7 @@ impl ServiceDiscoveryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ServiceDiscoveryClient {
         ServiceDiscoveryClient {
             client: Client::shared(),
@@ -1995,16 +2164,73 @@ impl ServiceDiscoveryClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceDiscoveryClient {
         ServiceDiscoveryClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ServiceDiscoveryClient> {
+        rusoto_core::ClientBuilder::new(ServiceDiscoveryClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ServiceDiscoveryClient {
+        ServiceDiscoveryClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ServiceDiscovery for ServiceDiscoveryClient {
-    /// <p>Creates an HTTP namespace. Service instances that you register using an HTTP namespace can be discovered using a <code>DiscoverInstances</code> request but can't be discovered using DNS. </p> <p>For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
-    async fn create_http_namespace(
+impl ServiceDiscoveryClient {
+    /// Calls `create_http_namespace`, also returning the metadata of the response.
+    pub async fn create_http_namespace_with_metadata(
         &self,
         input: CreateHttpNamespaceRequest,
-    ) -> Result<CreateHttpNamespaceResponse, RusotoError<CreateHttpNamespaceError>> {
+    ) -> Result<
+        (CreateHttpNamespaceResponse, ResponseMetadata),
+        RusotoError<CreateHttpNamespaceError>,
+    > {
+        let request = self.build_create_http_namespace_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHttpNamespaceResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHttpNamespaceError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_http_namespace` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_http_namespace(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateHttpNamespaceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_http_namespace_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_http_namespace_request(
+        &self,
+        input: CreateHttpNamespaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreateHttpNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2014,29 +2240,58 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_private_dns_namespace`, also returning the metadata of the response.
+    pub async fn create_private_dns_namespace_with_metadata(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> Result<
+        (CreatePrivateDnsNamespaceResponse, ResponseMetadata),
+        RusotoError<CreatePrivateDnsNamespaceError>,
+    > {
+        let request = self.build_create_private_dns_namespace_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateHttpNamespaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePrivateDnsNamespaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHttpNamespaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePrivateDnsNamespaceError::from_response(response))
         }
     }
 
-    /// <p>Creates a private namespace based on DNS, which will be visible only inside a specified Amazon VPC. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
-    async fn create_private_dns_namespace(
+    /// Presigns the request `create_private_dns_namespace` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_private_dns_namespace(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePrivateDnsNamespaceRequest,
-    ) -> Result<CreatePrivateDnsNamespaceResponse, RusotoError<CreatePrivateDnsNamespaceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_private_dns_namespace_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_private_dns_namespace_request(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreatePrivateDnsNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2046,28 +2301,58 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_public_dns_namespace`, also returning the metadata of the response.
+    pub async fn create_public_dns_namespace_with_metadata(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> Result<
+        (CreatePublicDnsNamespaceResponse, ResponseMetadata),
+        RusotoError<CreatePublicDnsNamespaceError>,
+    > {
+        let request = self.build_create_public_dns_namespace_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePrivateDnsNamespaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePublicDnsNamespaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePrivateDnsNamespaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePublicDnsNamespaceError::from_response(response))
         }
     }
 
-    /// <p>Creates a public namespace based on DNS, which will be visible on the internet. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
-    async fn create_public_dns_namespace(
+    /// Presigns the request `create_public_dns_namespace` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_public_dns_namespace(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePublicDnsNamespaceRequest,
-    ) -> Result<CreatePublicDnsNamespaceResponse, RusotoError<CreatePublicDnsNamespaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_public_dns_namespace_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_public_dns_namespace_request(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreatePublicDnsNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2077,55 +2362,105 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_service`, also returning the metadata of the response.
+    pub async fn create_service_with_metadata(
+        &self,
+        input: CreateServiceRequest,
+    ) -> Result<(CreateServiceResponse, ResponseMetadata), RusotoError<CreateServiceError>> {
+        let request = self.build_create_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePublicDnsNamespaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateServiceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePublicDnsNamespaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateServiceError::from_response(response))
         }
     }
 
-    /// <p>Creates a service, which defines the configuration for the following entities:</p> <ul> <li> <p>For public and private DNS namespaces, one of the following combinations of DNS records in Amazon Route 53:</p> <ul> <li> <p>A</p> </li> <li> <p>AAAA</p> </li> <li> <p>A and AAAA</p> </li> <li> <p>SRV</p> </li> <li> <p>CNAME</p> </li> </ul> </li> <li> <p>Optionally, a health check</p> </li> </ul> <p>After you create the service, you can submit a <a>RegisterInstance</a> request, and AWS Cloud Map uses the values in the configuration to create the specified entities.</p> <p>For the current limit on the number of instances that you can register using the same namespace and using the same service, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
-    async fn create_service(
+    /// Presigns the request `create_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateServiceRequest,
-    ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_service_request(&self, input: CreateServiceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.CreateService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_namespace`, also returning the metadata of the response.
+    pub async fn delete_namespace_with_metadata(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> Result<(DeleteNamespaceResponse, ResponseMetadata), RusotoError<DeleteNamespaceError>>
+    {
+        let request = self.build_delete_namespace_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateServiceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteNamespaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateServiceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteNamespaceError::from_response(response))
         }
     }
 
-    /// <p>Deletes a namespace from the current account. If the namespace still contains one or more services, the request fails.</p>
-    async fn delete_namespace(
+    /// Presigns the request `delete_namespace` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_namespace(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteNamespaceRequest,
-    ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_namespace_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_namespace_request(&self, input: DeleteNamespaceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DeleteNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2135,54 +2470,105 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_service`, also returning the metadata of the response.
+    pub async fn delete_service_with_metadata(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> Result<(DeleteServiceResponse, ResponseMetadata), RusotoError<DeleteServiceError>> {
+        let request = self.build_delete_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteNamespaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteServiceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNamespaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteServiceError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified service. If the service still contains one or more registered instances, the request fails.</p>
-    async fn delete_service(
+    /// Presigns the request `delete_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteServiceRequest,
-    ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_service_request(&self, input: DeleteServiceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DeleteService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.DeleteService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_instance`, also returning the metadata of the response.
+    pub async fn deregister_instance_with_metadata(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> Result<(DeregisterInstanceResponse, ResponseMetadata), RusotoError<DeregisterInstanceError>>
+    {
+        let request = self.build_deregister_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteServiceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteServiceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterInstanceError::from_response(response))
         }
     }
 
-    /// <p>Deletes the Amazon Route 53 DNS records and health check, if any, that AWS Cloud Map created for the specified instance.</p>
-    async fn deregister_instance(
+    /// Presigns the request `deregister_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterInstanceRequest,
-    ) -> Result<DeregisterInstanceResponse, RusotoError<DeregisterInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_instance_request(&self, input: DeregisterInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DeregisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2192,28 +2578,53 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `discover_instances`, also returning the metadata of the response.
+    pub async fn discover_instances_with_metadata(
+        &self,
+        input: DiscoverInstancesRequest,
+    ) -> Result<(DiscoverInstancesResponse, ResponseMetadata), RusotoError<DiscoverInstancesError>>
+    {
+        let request = self.build_discover_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DiscoverInstancesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DiscoverInstancesError::from_response(response))
         }
     }
 
-    /// <p>Discovers registered instances for a specified namespace and service.</p>
-    async fn discover_instances(
+    /// Presigns the request `discover_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_discover_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DiscoverInstancesRequest,
-    ) -> Result<DiscoverInstancesResponse, RusotoError<DiscoverInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_discover_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_discover_instances_request(&self, input: DiscoverInstancesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DiscoverInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2223,55 +2634,110 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instance`, also returning the metadata of the response.
+    pub async fn get_instance_with_metadata(
+        &self,
+        input: GetInstanceRequest,
+    ) -> Result<(GetInstanceResponse, ResponseMetadata), RusotoError<GetInstanceError>> {
+        let request = self.build_get_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DiscoverInstancesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DiscoverInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstanceError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a specified instance.</p>
-    async fn get_instance(
+    /// Presigns the request `get_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstanceRequest,
-    ) -> Result<GetInstanceResponse, RusotoError<GetInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_request(&self, input: GetInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instances_health_status`, also returning the metadata of the response.
+    pub async fn get_instances_health_status_with_metadata(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> Result<
+        (GetInstancesHealthStatusResponse, ResponseMetadata),
+        RusotoError<GetInstancesHealthStatusError>,
+    > {
+        let request = self.build_get_instances_health_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstancesHealthStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstancesHealthStatusError::from_response(response))
         }
     }
 
-    /// <p><p>Gets the current health status (<code>Healthy</code>, <code>Unhealthy</code>, or <code>Unknown</code>) of one or more instances that are associated with a specified service.</p> <note> <p>There is a brief delay between when you register an instance and when the health status for the instance is available. </p> </note></p>
-    async fn get_instances_health_status(
+    /// Presigns the request `get_instances_health_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instances_health_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstancesHealthStatusRequest,
-    ) -> Result<GetInstancesHealthStatusResponse, RusotoError<GetInstancesHealthStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instances_health_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instances_health_status_request(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetInstancesHealthStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2281,217 +2747,417 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_namespace`, also returning the metadata of the response.
+    pub async fn get_namespace_with_metadata(
+        &self,
+        input: GetNamespaceRequest,
+    ) -> Result<(GetNamespaceResponse, ResponseMetadata), RusotoError<GetNamespaceError>> {
+        let request = self.build_get_namespace_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInstancesHealthStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetNamespaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstancesHealthStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetNamespaceError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a namespace.</p>
-    async fn get_namespace(
+    /// Presigns the request `get_namespace` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_namespace(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetNamespaceRequest,
-    ) -> Result<GetNamespaceResponse, RusotoError<GetNamespaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_namespace_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_namespace_request(&self, input: GetNamespaceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetNamespace");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_operation`, also returning the metadata of the response.
+    pub async fn get_operation_with_metadata(
+        &self,
+        input: GetOperationRequest,
+    ) -> Result<(GetOperationResponse, ResponseMetadata), RusotoError<GetOperationError>> {
+        let request = self.build_get_operation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetNamespaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOperationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetNamespaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOperationError::from_response(response))
         }
     }
 
-    /// <p><p>Gets information about any operation that returns an operation ID in the response, such as a <code>CreateService</code> request.</p> <note> <p>To get a list of operations that match specified criteria, see <a>ListOperations</a>.</p> </note></p>
-    async fn get_operation(
+    /// Presigns the request `get_operation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_operation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOperationRequest,
-    ) -> Result<GetOperationResponse, RusotoError<GetOperationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_operation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_operation_request(&self, input: GetOperationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetOperation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetOperation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_service`, also returning the metadata of the response.
+    pub async fn get_service_with_metadata(
+        &self,
+        input: GetServiceRequest,
+    ) -> Result<(GetServiceResponse, ResponseMetadata), RusotoError<GetServiceError>> {
+        let request = self.build_get_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetOperationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetServiceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOperationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetServiceError::from_response(response))
         }
     }
 
-    /// <p>Gets the settings for a specified service.</p>
-    async fn get_service(
+    /// Presigns the request `get_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetServiceRequest,
-    ) -> Result<GetServiceResponse, RusotoError<GetServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_service_request(&self, input: GetServiceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_instances`, also returning the metadata of the response.
+    pub async fn list_instances_with_metadata(
+        &self,
+        input: ListInstancesRequest,
+    ) -> Result<(ListInstancesResponse, ResponseMetadata), RusotoError<ListInstancesError>> {
+        let request = self.build_list_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetServiceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListInstancesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetServiceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListInstancesError::from_response(response))
         }
     }
 
-    /// <p>Lists summary information about the instances that you registered by using a specified service.</p>
-    async fn list_instances(
+    /// Presigns the request `list_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInstancesRequest,
-    ) -> Result<ListInstancesResponse, RusotoError<ListInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_instances_request(&self, input: ListInstancesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_namespaces`, also returning the metadata of the response.
+    pub async fn list_namespaces_with_metadata(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> Result<(ListNamespacesResponse, ResponseMetadata), RusotoError<ListNamespacesError>> {
+        let request = self.build_list_namespaces_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListInstancesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListNamespacesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListNamespacesError::from_response(response))
         }
     }
 
-    /// <p>Lists summary information about the namespaces that were created by the current AWS account.</p>
-    async fn list_namespaces(
+    /// Presigns the request `list_namespaces` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_namespaces(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListNamespacesRequest,
-    ) -> Result<ListNamespacesResponse, RusotoError<ListNamespacesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_namespaces_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_namespaces_request(&self, input: ListNamespacesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListNamespaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListNamespaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_operations`, also returning the metadata of the response.
+    pub async fn list_operations_with_metadata(
+        &self,
+        input: ListOperationsRequest,
+    ) -> Result<(ListOperationsResponse, ResponseMetadata), RusotoError<ListOperationsError>> {
+        let request = self.build_list_operations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListNamespacesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListOperationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListNamespacesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListOperationsError::from_response(response))
         }
     }
 
-    /// <p>Lists operations that match the criteria that you specify.</p>
-    async fn list_operations(
+    /// Presigns the request `list_operations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_operations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOperationsRequest,
-    ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_operations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_operations_request(&self, input: ListOperationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListOperations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `list_services`, also returning the metadata of the response.
+    pub async fn list_services_with_metadata(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<(ListServicesResponse, ResponseMetadata), RusotoError<ListServicesError>> {
+        let request = self.build_list_services_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListOperationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListServicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOperationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListServicesError::from_response(response))
         }
     }
 
-    /// <p>Lists summary information for all the services that are associated with one or more specified namespaces.</p>
-    async fn list_services(
+    /// Presigns the request `list_services` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_services(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListServicesRequest,
-    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_services_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_services_request(&self, input: ListServicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_instance`, also returning the metadata of the response.
+    pub async fn register_instance_with_metadata(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> Result<(RegisterInstanceResponse, ResponseMetadata), RusotoError<RegisterInstanceError>>
+    {
+        let request = self.build_register_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListServicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListServicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterInstanceError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates one or more records and, optionally, creates a health check based on the settings in a specified service. When you submit a <code>RegisterInstance</code> request, the following occurs:</p> <ul> <li> <p>For each DNS record that you define in the service that is specified by <code>ServiceId</code>, a record is created or updated in the hosted zone that is associated with the corresponding namespace.</p> </li> <li> <p>If the service includes <code>HealthCheckConfig</code>, a health check is created based on the settings in the health check configuration.</p> </li> <li> <p>The health check, if any, is associated with each of the new or updated records.</p> </li> </ul> <important> <p>One <code>RegisterInstance</code> request must complete before you can submit another request and specify the same service ID and instance ID.</p> </important> <p>For more information, see <a>CreateService</a>.</p> <p>When AWS Cloud Map receives a DNS query for the specified DNS name, it returns the applicable value:</p> <ul> <li> <p> <b>If the health check is healthy</b>: returns all the records</p> </li> <li> <p> <b>If the health check is unhealthy</b>: returns the applicable value for the last healthy instance</p> </li> <li> <p> <b>If you didn't specify a health check configuration</b>: returns all the records</p> </li> </ul> <p>For the current limit on the number of instances that you can register using the same namespace and using the same service, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
-    async fn register_instance(
+    /// Presigns the request `register_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterInstanceRequest,
-    ) -> Result<RegisterInstanceResponse, RusotoError<RegisterInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_instance_request(&self, input: RegisterInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("RegisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2501,28 +3167,52 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_instance_custom_health_status`, also returning the metadata of the response.
+    pub async fn update_instance_custom_health_status_with_metadata(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateInstanceCustomHealthStatusError>> {
+        let request = self.build_update_instance_custom_health_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterInstanceResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateInstanceCustomHealthStatusError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Submits a request to change the health status of a custom health check to healthy or unhealthy.</p> <p>You can use <code>UpdateInstanceCustomHealthStatus</code> to change the status only for custom health checks, which you define using <code>HealthCheckCustomConfig</code> when you create a service. You can't use it to change the status for Route 53 health checks, which you define using <code>HealthCheckConfig</code>.</p> <p>For more information, see <a>HealthCheckCustomConfig</a>.</p>
-    async fn update_instance_custom_health_status(
+    /// Presigns the request `update_instance_custom_health_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_instance_custom_health_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateInstanceCustomHealthStatusRequest,
-    ) -> Result<(), RusotoError<UpdateInstanceCustomHealthStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_instance_custom_health_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_instance_custom_health_status_request(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("UpdateInstanceCustomHealthStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2532,47 +3222,406 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_service`, also returning the metadata of the response.
+    pub async fn update_service_with_metadata(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> Result<(UpdateServiceResponse, ResponseMetadata), RusotoError<UpdateServiceError>> {
+        let request = self.build_update_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateServiceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateInstanceCustomHealthStatusError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateServiceError::from_response(response))
         }
     }
 
-    /// <p>Submits a request to perform the following operations:</p> <ul> <li> <p>Add or delete <code>DnsRecords</code> configurations</p> </li> <li> <p>Update the TTL setting for existing <code>DnsRecords</code> configurations</p> </li> <li> <p>Add, update, or delete <code>HealthCheckConfig</code> for a specified service</p> </li> </ul> <p>For public and private DNS namespaces, you must specify all <code>DnsRecords</code> configurations (and, optionally, <code>HealthCheckConfig</code>) that you want to appear in the updated service. Any current configurations that don't appear in an <code>UpdateService</code> request are deleted.</p> <p>When you update the TTL setting for a service, AWS Cloud Map also updates the corresponding settings in all the records and health checks that were created by using the specified service.</p>
-    async fn update_service(
+    /// Presigns the request `update_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateServiceRequest,
-    ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_service_request(&self, input: UpdateServiceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("UpdateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.UpdateService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListInstances` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_instances_pages(
+        &self,
+        input: ListInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<ListInstancesResponse, RusotoError<ListInstancesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceDiscoveryClient, input| async move { client.list_instances(input).await },
+            |input: &ListInstancesRequest, output: &ListInstancesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListInstancesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Instances` of every page of `ListInstances` results for `input`, as `list_instances_pages` requests them.
+    pub fn list_instances_stream(
+        &self,
+        input: ListInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<InstanceSummary, RusotoError<ListInstancesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_instances_pages(input), |page| {
+            page.instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListNamespaces` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_namespaces_pages(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> impl futures::Stream<Item = Result<ListNamespacesResponse, RusotoError<ListNamespacesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceDiscoveryClient, input| async move { client.list_namespaces(input).await },
+            |input: &ListNamespacesRequest, output: &ListNamespacesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListNamespacesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Namespaces` of every page of `ListNamespaces` results for `input`, as `list_namespaces_pages` requests them.
+    pub fn list_namespaces_stream(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> impl futures::Stream<Item = Result<NamespaceSummary, RusotoError<ListNamespacesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_namespaces_pages(input), |page| {
+            page.namespaces.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOperations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_operations_pages(
+        &self,
+        input: ListOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListOperationsResponse, RusotoError<ListOperationsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceDiscoveryClient, input| async move { client.list_operations(input).await },
+            |input: &ListOperationsRequest, output: &ListOperationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOperationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Operations` of every page of `ListOperations` results for `input`, as `list_operations_pages` requests them.
+    pub fn list_operations_stream(
+        &self,
+        input: ListOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<OperationSummary, RusotoError<ListOperationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_operations_pages(input), |page| {
+            page.operations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListServices` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_services_pages(
+        &self,
+        input: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListServicesResponse, RusotoError<ListServicesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ServiceDiscoveryClient, input| async move { client.list_services(input).await },
+            |input: &ListServicesRequest, output: &ListServicesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListServicesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Services` of every page of `ListServices` results for `input`, as `list_services_pages` requests them.
+    pub fn list_services_stream(
+        &self,
+        input: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ServiceSummary, RusotoError<ListServicesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_services_pages(input), |page| {
+            page.services.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ServiceDiscovery for ServiceDiscoveryClient {
+    /// <p>Creates an HTTP namespace. Service instances that you register using an HTTP namespace can be discovered using a <code>DiscoverInstances</code> request but can't be discovered using DNS. </p> <p>For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    async fn create_http_namespace(
+        &self,
+        input: CreateHttpNamespaceRequest,
+    ) -> Result<CreateHttpNamespaceResponse, RusotoError<CreateHttpNamespaceError>> {
+        self.create_http_namespace_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateServiceError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a private namespace based on DNS, which will be visible only inside a specified Amazon VPC. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    async fn create_private_dns_namespace(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> Result<CreatePrivateDnsNamespaceResponse, RusotoError<CreatePrivateDnsNamespaceError>>
+    {
+        self.create_private_dns_namespace_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a public namespace based on DNS, which will be visible on the internet. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    async fn create_public_dns_namespace(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> Result<CreatePublicDnsNamespaceResponse, RusotoError<CreatePublicDnsNamespaceError>> {
+        self.create_public_dns_namespace_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a service, which defines the configuration for the following entities:</p> <ul> <li> <p>For public and private DNS namespaces, one of the following combinations of DNS records in Amazon Route 53:</p> <ul> <li> <p>A</p> </li> <li> <p>AAAA</p> </li> <li> <p>A and AAAA</p> </li> <li> <p>SRV</p> </li> <li> <p>CNAME</p> </li> </ul> </li> <li> <p>Optionally, a health check</p> </li> </ul> <p>After you create the service, you can submit a <a>RegisterInstance</a> request, and AWS Cloud Map uses the values in the configuration to create the specified entities.</p> <p>For the current limit on the number of instances that you can register using the same namespace and using the same service, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    async fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
+        self.create_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a namespace from the current account. If the namespace still contains one or more services, the request fails.</p>
+    async fn delete_namespace(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
+        self.delete_namespace_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified service. If the service still contains one or more registered instances, the request fails.</p>
+    async fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
+        self.delete_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the Amazon Route 53 DNS records and health check, if any, that AWS Cloud Map created for the specified instance.</p>
+    async fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> Result<DeregisterInstanceResponse, RusotoError<DeregisterInstanceError>> {
+        self.deregister_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Discovers registered instances for a specified namespace and service.</p>
+    async fn discover_instances(
+        &self,
+        input: DiscoverInstancesRequest,
+    ) -> Result<DiscoverInstancesResponse, RusotoError<DiscoverInstancesError>> {
+        self.discover_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specified instance.</p>
+    async fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> Result<GetInstanceResponse, RusotoError<GetInstanceError>> {
+        self.get_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Gets the current health status (<code>Healthy</code>, <code>Unhealthy</code>, or <code>Unknown</code>) of one or more instances that are associated with a specified service.</p> <note> <p>There is a brief delay between when you register an instance and when the health status for the instance is available. </p> </note></p>
+    async fn get_instances_health_status(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> Result<GetInstancesHealthStatusResponse, RusotoError<GetInstancesHealthStatusError>> {
+        self.get_instances_health_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a namespace.</p>
+    async fn get_namespace(
+        &self,
+        input: GetNamespaceRequest,
+    ) -> Result<GetNamespaceResponse, RusotoError<GetNamespaceError>> {
+        self.get_namespace_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Gets information about any operation that returns an operation ID in the response, such as a <code>CreateService</code> request.</p> <note> <p>To get a list of operations that match specified criteria, see <a>ListOperations</a>.</p> </note></p>
+    async fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> Result<GetOperationResponse, RusotoError<GetOperationError>> {
+        self.get_operation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the settings for a specified service.</p>
+    async fn get_service(
+        &self,
+        input: GetServiceRequest,
+    ) -> Result<GetServiceResponse, RusotoError<GetServiceError>> {
+        self.get_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists summary information about the instances that you registered by using a specified service.</p>
+    async fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> Result<ListInstancesResponse, RusotoError<ListInstancesError>> {
+        self.list_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists summary information about the namespaces that were created by the current AWS account.</p>
+    async fn list_namespaces(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> Result<ListNamespacesResponse, RusotoError<ListNamespacesError>> {
+        self.list_namespaces_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists operations that match the criteria that you specify.</p>
+    async fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
+        self.list_operations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists summary information for all the services that are associated with one or more specified namespaces.</p>
+    async fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+        self.list_services_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates one or more records and, optionally, creates a health check based on the settings in a specified service. When you submit a <code>RegisterInstance</code> request, the following occurs:</p> <ul> <li> <p>For each DNS record that you define in the service that is specified by <code>ServiceId</code>, a record is created or updated in the hosted zone that is associated with the corresponding namespace.</p> </li> <li> <p>If the service includes <code>HealthCheckConfig</code>, a health check is created based on the settings in the health check configuration.</p> </li> <li> <p>The health check, if any, is associated with each of the new or updated records.</p> </li> </ul> <important> <p>One <code>RegisterInstance</code> request must complete before you can submit another request and specify the same service ID and instance ID.</p> </important> <p>For more information, see <a>CreateService</a>.</p> <p>When AWS Cloud Map receives a DNS query for the specified DNS name, it returns the applicable value:</p> <ul> <li> <p> <b>If the health check is healthy</b>: returns all the records</p> </li> <li> <p> <b>If the health check is unhealthy</b>: returns the applicable value for the last healthy instance</p> </li> <li> <p> <b>If you didn't specify a health check configuration</b>: returns all the records</p> </li> </ul> <p>For the current limit on the number of instances that you can register using the same namespace and using the same service, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    async fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> Result<RegisterInstanceResponse, RusotoError<RegisterInstanceError>> {
+        self.register_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Submits a request to change the health status of a custom health check to healthy or unhealthy.</p> <p>You can use <code>UpdateInstanceCustomHealthStatus</code> to change the status only for custom health checks, which you define using <code>HealthCheckCustomConfig</code> when you create a service. You can't use it to change the status for Route 53 health checks, which you define using <code>HealthCheckConfig</code>.</p> <p>For more information, see <a>HealthCheckCustomConfig</a>.</p>
+    async fn update_instance_custom_health_status(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> Result<(), RusotoError<UpdateInstanceCustomHealthStatusError>> {
+        self.update_instance_custom_health_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Submits a request to perform the following operations:</p> <ul> <li> <p>Add or delete <code>DnsRecords</code> configurations</p> </li> <li> <p>Update the TTL setting for existing <code>DnsRecords</code> configurations</p> </li> <li> <p>Add, update, or delete <code>HealthCheckConfig</code> for a specified service</p> </li> </ul> <p>For public and private DNS namespaces, you must specify all <code>DnsRecords</code> configurations (and, optionally, <code>HealthCheckConfig</code>) that you want to appear in the updated service. Any current configurations that don't appear in an <code>UpdateService</code> request are deleted.</p> <p>When you update the TTL setting for a service, AWS Cloud Map also updates the corresponding settings in all the records and health checks that were created by using the specified service.</p>
+    async fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
+        self.update_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7108,6 +7108,7 @@ impl WorkmailActionSerializer {
 
 /// Errors returned by CloneReceiptRuleSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CloneReceiptRuleSetError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
     AlreadyExists(String),
@@ -7115,6 +7116,8 @@ pub enum CloneReceiptRuleSetError {
     LimitExceeded(String),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
     RuleSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CloneReceiptRuleSetError {
@@ -7126,21 +7129,24 @@ impl CloneReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CloneReceiptRuleSetError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CloneReceiptRuleSetError::AlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CloneReceiptRuleSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CloneReceiptRuleSetError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(CloneReceiptRuleSetError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CloneReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CloneReceiptRuleSetError::Unhandled, &res),
                 }
             }
         }
@@ -7162,12 +7168,14 @@ impl fmt::Display for CloneReceiptRuleSetError {
             CloneReceiptRuleSetError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CloneReceiptRuleSetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CloneReceiptRuleSetError::RuleSetDoesNotExist(ref cause) => write!(f, "{}", cause),
+            CloneReceiptRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CloneReceiptRuleSetError {}
 /// Errors returned by CreateConfigurationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationSetError {
     /// <p>Indicates that the configuration set could not be created because of a naming conflict.</p>
     ConfigurationSetAlreadyExists(String),
@@ -7175,6 +7183,8 @@ pub enum CreateConfigurationSetError {
     InvalidConfigurationSet(String),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationSetError {
@@ -7186,25 +7196,30 @@ impl CreateConfigurationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateConfigurationSetError::ConfigurationSetAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationSet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateConfigurationSetError::InvalidConfigurationSet(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateConfigurationSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateConfigurationSetError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(CreateConfigurationSetError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -7230,12 +7245,14 @@ impl fmt::Display for CreateConfigurationSetError {
                 write!(f, "{}", cause)
             }
             CreateConfigurationSetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateConfigurationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConfigurationSetError {}
 /// Errors returned by CreateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationSetEventDestinationError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
@@ -7249,6 +7266,8 @@ pub enum CreateConfigurationSetEventDestinationError {
     InvalidSNSDestination(String),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationSetEventDestinationError {
@@ -7261,43 +7280,54 @@ impl CreateConfigurationSetEventDestinationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    "EventDestinationAlreadyExists" => return RusotoError::Service(
+                    "EventDestinationAlreadyExists" => return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::EventDestinationAlreadyExists(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    "InvalidCloudWatchDestination" => return RusotoError::Service(
+                    "InvalidCloudWatchDestination" => return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidFirehoseDestination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateConfigurationSetEventDestinationError::InvalidFirehoseDestination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSNSDestination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateConfigurationSetEventDestinationError::InvalidSNSDestination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateConfigurationSetEventDestinationError::LimitExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateConfigurationSetEventDestinationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7334,12 +7364,16 @@ impl fmt::Display for CreateConfigurationSetEventDestinationError {
             CreateConfigurationSetEventDestinationError::LimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateConfigurationSetEventDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateConfigurationSetEventDestinationError {}
 /// Errors returned by CreateConfigurationSetTrackingOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationSetTrackingOptionsError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
@@ -7347,6 +7381,8 @@ pub enum CreateConfigurationSetTrackingOptionsError {
     InvalidTrackingOptions(String),
     /// <p>Indicates that the configuration set you specified already contains a TrackingOptions object.</p>
     TrackingOptionsAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationSetTrackingOptionsError {
@@ -7359,24 +7395,32 @@ impl CreateConfigurationSetTrackingOptionsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
                         CreateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidTrackingOptions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "TrackingOptionsAlreadyExistsException" => return RusotoError::Service(
+                    "TrackingOptionsAlreadyExistsException" => return RusotoError::service(
                         CreateConfigurationSetTrackingOptionsError::TrackingOptionsAlreadyExists(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateConfigurationSetTrackingOptionsError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -7404,12 +7448,16 @@ impl fmt::Display for CreateConfigurationSetTrackingOptionsError {
             CreateConfigurationSetTrackingOptionsError::TrackingOptionsAlreadyExists(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateConfigurationSetTrackingOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateConfigurationSetTrackingOptionsError {}
 /// Errors returned by CreateCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCustomVerificationEmailTemplateError {
     /// <p>Indicates that custom verification email template provided content is invalid.</p>
     CustomVerificationEmailInvalidContent(String),
@@ -7419,6 +7467,8 @@ pub enum CreateCustomVerificationEmailTemplateError {
     FromEmailAddressNotVerified(String),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCustomVerificationEmailTemplateError {
@@ -7431,7 +7481,7 @@ impl CreateCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateAlreadyExists" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),"LimitExceeded" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::LimitExceeded(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailInvalidContent" => return RusotoError::service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message), &res),"CustomVerificationEmailTemplateAlreadyExists" => return RusotoError::service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(parsed_error.message), &res),"FromEmailAddressNotVerified" => return RusotoError::service(CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message), &res),"LimitExceeded" => return RusotoError::service(CreateCustomVerificationEmailTemplateError::LimitExceeded(parsed_error.message), &res),_ => return RusotoError::unhandled(CreateCustomVerificationEmailTemplateError::Unhandled, &res),
                                 }
             }
         }
@@ -7453,18 +7503,22 @@ impl fmt::Display for CreateCustomVerificationEmailTemplateError {
                             CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(ref cause) => write!(f, "{}", cause),
 CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(ref cause) => write!(f, "{}", cause),
 CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(ref cause) => write!(f, "{}", cause),
-CreateCustomVerificationEmailTemplateError::LimitExceeded(ref cause) => write!(f, "{}", cause)
-                        }
+CreateCustomVerificationEmailTemplateError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateCustomVerificationEmailTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateCustomVerificationEmailTemplateError {}
 /// Errors returned by CreateReceiptFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReceiptFilterError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
     AlreadyExists(String),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReceiptFilterError {
@@ -7476,16 +7530,18 @@ impl CreateReceiptFilterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateReceiptFilterError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptFilterError::AlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateReceiptFilterError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptFilterError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateReceiptFilterError::Unhandled, &res),
                 }
             }
         }
@@ -7506,12 +7562,14 @@ impl fmt::Display for CreateReceiptFilterError {
         match *self {
             CreateReceiptFilterError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateReceiptFilterError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateReceiptFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReceiptFilterError {}
 /// Errors returned by CreateReceiptRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReceiptRuleError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
     AlreadyExists(String),
@@ -7527,6 +7585,8 @@ pub enum CreateReceiptRuleError {
     RuleDoesNotExist(String),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
     RuleSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReceiptRuleError {
@@ -7538,41 +7598,48 @@ impl CreateReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateReceiptRuleError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptRuleError::AlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidLambdaFunction" => {
-                        return RusotoError::Service(CreateReceiptRuleError::InvalidLambdaFunction(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptRuleError::InvalidLambdaFunction(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidS3Configuration" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateReceiptRuleError::InvalidS3Configuration(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidSnsTopic" => {
-                        return RusotoError::Service(CreateReceiptRuleError::InvalidSnsTopic(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptRuleError::InvalidSnsTopic(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateReceiptRuleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptRuleError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(CreateReceiptRuleError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptRuleError::RuleDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(CreateReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptRuleError::RuleSetDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateReceiptRuleError::Unhandled, &res),
                 }
             }
         }
@@ -7598,17 +7665,21 @@ impl fmt::Display for CreateReceiptRuleError {
             CreateReceiptRuleError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateReceiptRuleError::RuleDoesNotExist(ref cause) => write!(f, "{}", cause),
             CreateReceiptRuleError::RuleSetDoesNotExist(ref cause) => write!(f, "{}", cause),
+            CreateReceiptRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReceiptRuleError {}
 /// Errors returned by CreateReceiptRuleSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReceiptRuleSetError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
     AlreadyExists(String),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReceiptRuleSetError {
@@ -7620,16 +7691,18 @@ impl CreateReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateReceiptRuleSetError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptRuleSetError::AlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateReceiptRuleSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateReceiptRuleSetError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateReceiptRuleSetError::Unhandled, &res),
                 }
             }
         }
@@ -7650,12 +7723,14 @@ impl fmt::Display for CreateReceiptRuleSetError {
         match *self {
             CreateReceiptRuleSetError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateReceiptRuleSetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateReceiptRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReceiptRuleSetError {}
 /// Errors returned by CreateTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTemplateError {
     /// <p>Indicates that a resource could not be created because of a naming conflict.</p>
     AlreadyExists(String),
@@ -7663,6 +7738,8 @@ pub enum CreateTemplateError {
     InvalidTemplate(String),
     /// <p>Indicates that a resource could not be created because of service limits. For a list of Amazon SES limits, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/limits.html">Amazon SES Developer Guide</a>.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTemplateError {
@@ -7674,21 +7751,24 @@ impl CreateTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateTemplateError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTemplateError::AlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidTemplate" => {
-                        return RusotoError::Service(CreateTemplateError::InvalidTemplate(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTemplateError::InvalidTemplate(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateTemplateError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTemplateError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateTemplateError::Unhandled, &res),
                 }
             }
         }
@@ -7710,15 +7790,19 @@ impl fmt::Display for CreateTemplateError {
             CreateTemplateError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateTemplateError::InvalidTemplate(ref cause) => write!(f, "{}", cause),
             CreateTemplateError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTemplateError {}
 /// Errors returned by DeleteConfigurationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationSetError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationSetError {
@@ -7730,13 +7814,16 @@ impl DeleteConfigurationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteConfigurationSetError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteConfigurationSetError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -7758,17 +7845,21 @@ impl fmt::Display for DeleteConfigurationSetError {
             DeleteConfigurationSetError::ConfigurationSetDoesNotExist(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteConfigurationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConfigurationSetError {}
 /// Errors returned by DeleteConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationSetEventDestinationError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
     /// <p>Indicates that the event destination does not exist.</p>
     EventDestinationDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationSetEventDestinationError {
@@ -7781,17 +7872,24 @@ impl DeleteConfigurationSetEventDestinationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
                         DeleteConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    "EventDestinationDoesNotExist" => return RusotoError::Service(
+                    "EventDestinationDoesNotExist" => return RusotoError::service(
                         DeleteConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteConfigurationSetEventDestinationError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -7816,17 +7914,23 @@ impl fmt::Display for DeleteConfigurationSetEventDestinationError {
             DeleteConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DeleteConfigurationSetEventDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteConfigurationSetEventDestinationError {}
 /// Errors returned by DeleteConfigurationSetTrackingOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationSetTrackingOptionsError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
     /// <p>Indicates that the TrackingOptions object you specified does not exist.</p>
     TrackingOptionsDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationSetTrackingOptionsError {
@@ -7839,19 +7943,26 @@ impl DeleteConfigurationSetTrackingOptionsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
                         DeleteConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "TrackingOptionsDoesNotExistException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteConfigurationSetTrackingOptionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7876,13 +7987,20 @@ impl fmt::Display for DeleteConfigurationSetTrackingOptionsError {
             DeleteConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteConfigurationSetTrackingOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteConfigurationSetTrackingOptionsError {}
 /// Errors returned by DeleteCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
-pub enum DeleteCustomVerificationEmailTemplateError {}
+#[non_exhaustive]
+pub enum DeleteCustomVerificationEmailTemplateError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteCustomVerificationEmailTemplateError {
     pub fn from_response(
@@ -7892,10 +8010,11 @@ impl DeleteCustomVerificationEmailTemplateError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DeleteCustomVerificationEmailTemplateError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -7912,13 +8031,21 @@ impl DeleteCustomVerificationEmailTemplateError {
 impl fmt::Display for DeleteCustomVerificationEmailTemplateError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteCustomVerificationEmailTemplateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DeleteCustomVerificationEmailTemplateError {}
 /// Errors returned by DeleteIdentity
 #[derive(Debug, PartialEq)]
-pub enum DeleteIdentityError {}
+#[non_exhaustive]
+pub enum DeleteIdentityError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteIdentityError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteIdentityError> {
@@ -7926,10 +8053,8 @@ impl DeleteIdentityError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteIdentityError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -7946,13 +8071,19 @@ impl DeleteIdentityError {
 impl fmt::Display for DeleteIdentityError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteIdentityError {}
 /// Errors returned by DeleteIdentityPolicy
 #[derive(Debug, PartialEq)]
-pub enum DeleteIdentityPolicyError {}
+#[non_exhaustive]
+pub enum DeleteIdentityPolicyError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteIdentityPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteIdentityPolicyError> {
@@ -7960,10 +8091,8 @@ impl DeleteIdentityPolicyError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteIdentityPolicyError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -7980,13 +8109,19 @@ impl DeleteIdentityPolicyError {
 impl fmt::Display for DeleteIdentityPolicyError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteIdentityPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteIdentityPolicyError {}
 /// Errors returned by DeleteReceiptFilter
 #[derive(Debug, PartialEq)]
-pub enum DeleteReceiptFilterError {}
+#[non_exhaustive]
+pub enum DeleteReceiptFilterError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteReceiptFilterError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteReceiptFilterError> {
@@ -7994,10 +8129,8 @@ impl DeleteReceiptFilterError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteReceiptFilterError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8014,15 +8147,20 @@ impl DeleteReceiptFilterError {
 impl fmt::Display for DeleteReceiptFilterError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteReceiptFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteReceiptFilterError {}
 /// Errors returned by DeleteReceiptRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReceiptRuleError {
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
     RuleSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReceiptRuleError {
@@ -8034,11 +8172,12 @@ impl DeleteReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(DeleteReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteReceiptRuleError::RuleSetDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteReceiptRuleError::Unhandled, &res),
                 }
             }
         }
@@ -8058,15 +8197,19 @@ impl fmt::Display for DeleteReceiptRuleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteReceiptRuleError::RuleSetDoesNotExist(ref cause) => write!(f, "{}", cause),
+            DeleteReceiptRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReceiptRuleError {}
 /// Errors returned by DeleteReceiptRuleSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReceiptRuleSetError {
     /// <p>Indicates that the delete operation could not be completed.</p>
     CannotDelete(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReceiptRuleSetError {
@@ -8078,11 +8221,12 @@ impl DeleteReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CannotDelete" => {
-                        return RusotoError::Service(DeleteReceiptRuleSetError::CannotDelete(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteReceiptRuleSetError::CannotDelete(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteReceiptRuleSetError::Unhandled, &res),
                 }
             }
         }
@@ -8102,13 +8246,18 @@ impl fmt::Display for DeleteReceiptRuleSetError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteReceiptRuleSetError::CannotDelete(ref cause) => write!(f, "{}", cause),
+            DeleteReceiptRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReceiptRuleSetError {}
 /// Errors returned by DeleteTemplate
 #[derive(Debug, PartialEq)]
-pub enum DeleteTemplateError {}
+#[non_exhaustive]
+pub enum DeleteTemplateError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteTemplateError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTemplateError> {
@@ -8116,10 +8265,8 @@ impl DeleteTemplateError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteTemplateError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8136,13 +8283,19 @@ impl DeleteTemplateError {
 impl fmt::Display for DeleteTemplateError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteTemplateError {}
 /// Errors returned by DeleteVerifiedEmailAddress
 #[derive(Debug, PartialEq)]
-pub enum DeleteVerifiedEmailAddressError {}
+#[non_exhaustive]
+pub enum DeleteVerifiedEmailAddressError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteVerifiedEmailAddressError {
     pub fn from_response(
@@ -8152,10 +8305,8 @@ impl DeleteVerifiedEmailAddressError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteVerifiedEmailAddressError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8172,13 +8323,19 @@ impl DeleteVerifiedEmailAddressError {
 impl fmt::Display for DeleteVerifiedEmailAddressError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteVerifiedEmailAddressError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteVerifiedEmailAddressError {}
 /// Errors returned by DescribeActiveReceiptRuleSet
 #[derive(Debug, PartialEq)]
-pub enum DescribeActiveReceiptRuleSetError {}
+#[non_exhaustive]
+pub enum DescribeActiveReceiptRuleSetError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeActiveReceiptRuleSetError {
     pub fn from_response(
@@ -8188,10 +8345,8 @@ impl DescribeActiveReceiptRuleSetError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeActiveReceiptRuleSetError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8208,15 +8363,20 @@ impl DescribeActiveReceiptRuleSetError {
 impl fmt::Display for DescribeActiveReceiptRuleSetError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeActiveReceiptRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeActiveReceiptRuleSetError {}
 /// Errors returned by DescribeConfigurationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationSetError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationSetError {
@@ -8228,13 +8388,19 @@ impl DescribeConfigurationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeConfigurationSetError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeConfigurationSetError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8256,17 +8422,21 @@ impl fmt::Display for DescribeConfigurationSetError {
             DescribeConfigurationSetError::ConfigurationSetDoesNotExist(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConfigurationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConfigurationSetError {}
 /// Errors returned by DescribeReceiptRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReceiptRuleError {
     /// <p>Indicates that the provided receipt rule does not exist.</p>
     RuleDoesNotExist(String),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
     RuleSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReceiptRuleError {
@@ -8278,16 +8448,18 @@ impl DescribeReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(DescribeReceiptRuleError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeReceiptRuleError::RuleDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(DescribeReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeReceiptRuleError::RuleSetDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeReceiptRuleError::Unhandled, &res),
                 }
             }
         }
@@ -8308,15 +8480,19 @@ impl fmt::Display for DescribeReceiptRuleError {
         match *self {
             DescribeReceiptRuleError::RuleDoesNotExist(ref cause) => write!(f, "{}", cause),
             DescribeReceiptRuleError::RuleSetDoesNotExist(ref cause) => write!(f, "{}", cause),
+            DescribeReceiptRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReceiptRuleError {}
 /// Errors returned by DescribeReceiptRuleSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReceiptRuleSetError {
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
     RuleSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReceiptRuleSetError {
@@ -8328,11 +8504,14 @@ impl DescribeReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeReceiptRuleSetError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -8352,13 +8531,18 @@ impl fmt::Display for DescribeReceiptRuleSetError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeReceiptRuleSetError::RuleSetDoesNotExist(ref cause) => write!(f, "{}", cause),
+            DescribeReceiptRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReceiptRuleSetError {}
 /// Errors returned by GetAccountSendingEnabled
 #[derive(Debug, PartialEq)]
-pub enum GetAccountSendingEnabledError {}
+#[non_exhaustive]
+pub enum GetAccountSendingEnabledError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetAccountSendingEnabledError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetAccountSendingEnabledError> {
@@ -8366,10 +8550,8 @@ impl GetAccountSendingEnabledError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetAccountSendingEnabledError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8386,15 +8568,20 @@ impl GetAccountSendingEnabledError {
 impl fmt::Display for GetAccountSendingEnabledError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetAccountSendingEnabledError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetAccountSendingEnabledError {}
 /// Errors returned by GetCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCustomVerificationEmailTemplateError {
     /// <p>Indicates that a custom verification email template with the name you specified does not exist.</p>
     CustomVerificationEmailTemplateDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCustomVerificationEmailTemplateError {
@@ -8407,7 +8594,7 @@ impl GetCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::service(GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message), &res),_ => return RusotoError::unhandled(GetCustomVerificationEmailTemplateError::Unhandled, &res),
                                 }
             }
         }
@@ -8426,14 +8613,19 @@ impl fmt::Display for GetCustomVerificationEmailTemplateError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-                            GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(ref cause) => write!(f, "{}", cause)
-                        }
+                            GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(ref cause) => write!(f, "{}", cause),
+            GetCustomVerificationEmailTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetCustomVerificationEmailTemplateError {}
 /// Errors returned by GetIdentityDkimAttributes
 #[derive(Debug, PartialEq)]
-pub enum GetIdentityDkimAttributesError {}
+#[non_exhaustive]
+pub enum GetIdentityDkimAttributesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetIdentityDkimAttributesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetIdentityDkimAttributesError> {
@@ -8441,10 +8633,8 @@ impl GetIdentityDkimAttributesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetIdentityDkimAttributesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8461,13 +8651,19 @@ impl GetIdentityDkimAttributesError {
 impl fmt::Display for GetIdentityDkimAttributesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetIdentityDkimAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetIdentityDkimAttributesError {}
 /// Errors returned by GetIdentityMailFromDomainAttributes
 #[derive(Debug, PartialEq)]
-pub enum GetIdentityMailFromDomainAttributesError {}
+#[non_exhaustive]
+pub enum GetIdentityMailFromDomainAttributesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetIdentityMailFromDomainAttributesError {
     pub fn from_response(
@@ -8477,10 +8673,11 @@ impl GetIdentityMailFromDomainAttributesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    GetIdentityMailFromDomainAttributesError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -8497,13 +8694,21 @@ impl GetIdentityMailFromDomainAttributesError {
 impl fmt::Display for GetIdentityMailFromDomainAttributesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetIdentityMailFromDomainAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetIdentityMailFromDomainAttributesError {}
 /// Errors returned by GetIdentityNotificationAttributes
 #[derive(Debug, PartialEq)]
-pub enum GetIdentityNotificationAttributesError {}
+#[non_exhaustive]
+pub enum GetIdentityNotificationAttributesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetIdentityNotificationAttributesError {
     pub fn from_response(
@@ -8513,10 +8718,11 @@ impl GetIdentityNotificationAttributesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    GetIdentityNotificationAttributesError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -8533,13 +8739,21 @@ impl GetIdentityNotificationAttributesError {
 impl fmt::Display for GetIdentityNotificationAttributesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetIdentityNotificationAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetIdentityNotificationAttributesError {}
 /// Errors returned by GetIdentityPolicies
 #[derive(Debug, PartialEq)]
-pub enum GetIdentityPoliciesError {}
+#[non_exhaustive]
+pub enum GetIdentityPoliciesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetIdentityPoliciesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetIdentityPoliciesError> {
@@ -8547,10 +8761,8 @@ impl GetIdentityPoliciesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetIdentityPoliciesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8567,13 +8779,19 @@ impl GetIdentityPoliciesError {
 impl fmt::Display for GetIdentityPoliciesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetIdentityPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetIdentityPoliciesError {}
 /// Errors returned by GetIdentityVerificationAttributes
 #[derive(Debug, PartialEq)]
-pub enum GetIdentityVerificationAttributesError {}
+#[non_exhaustive]
+pub enum GetIdentityVerificationAttributesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetIdentityVerificationAttributesError {
     pub fn from_response(
@@ -8583,10 +8801,11 @@ impl GetIdentityVerificationAttributesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    GetIdentityVerificationAttributesError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -8603,13 +8822,21 @@ impl GetIdentityVerificationAttributesError {
 impl fmt::Display for GetIdentityVerificationAttributesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetIdentityVerificationAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetIdentityVerificationAttributesError {}
 /// Errors returned by GetSendQuota
 #[derive(Debug, PartialEq)]
-pub enum GetSendQuotaError {}
+#[non_exhaustive]
+pub enum GetSendQuotaError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetSendQuotaError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetSendQuotaError> {
@@ -8617,10 +8844,8 @@ impl GetSendQuotaError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetSendQuotaError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8637,13 +8862,19 @@ impl GetSendQuotaError {
 impl fmt::Display for GetSendQuotaError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetSendQuotaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetSendQuotaError {}
 /// Errors returned by GetSendStatistics
 #[derive(Debug, PartialEq)]
-pub enum GetSendStatisticsError {}
+#[non_exhaustive]
+pub enum GetSendStatisticsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetSendStatisticsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetSendStatisticsError> {
@@ -8651,10 +8882,8 @@ impl GetSendStatisticsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetSendStatisticsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8671,15 +8900,20 @@ impl GetSendStatisticsError {
 impl fmt::Display for GetSendStatisticsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetSendStatisticsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetSendStatisticsError {}
 /// Errors returned by GetTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTemplateError {
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
     TemplateDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTemplateError {
@@ -8691,11 +8925,12 @@ impl GetTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(GetTemplateError::TemplateDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTemplateError::TemplateDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetTemplateError::Unhandled, &res),
                 }
             }
         }
@@ -8715,13 +8950,18 @@ impl fmt::Display for GetTemplateError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetTemplateError::TemplateDoesNotExist(ref cause) => write!(f, "{}", cause),
+            GetTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTemplateError {}
 /// Errors returned by ListConfigurationSets
 #[derive(Debug, PartialEq)]
-pub enum ListConfigurationSetsError {}
+#[non_exhaustive]
+pub enum ListConfigurationSetsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListConfigurationSetsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListConfigurationSetsError> {
@@ -8729,10 +8969,8 @@ impl ListConfigurationSetsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListConfigurationSetsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8749,13 +8987,19 @@ impl ListConfigurationSetsError {
 impl fmt::Display for ListConfigurationSetsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListConfigurationSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListConfigurationSetsError {}
 /// Errors returned by ListCustomVerificationEmailTemplates
 #[derive(Debug, PartialEq)]
-pub enum ListCustomVerificationEmailTemplatesError {}
+#[non_exhaustive]
+pub enum ListCustomVerificationEmailTemplatesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListCustomVerificationEmailTemplatesError {
     pub fn from_response(
@@ -8765,10 +9009,11 @@ impl ListCustomVerificationEmailTemplatesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    ListCustomVerificationEmailTemplatesError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -8785,13 +9030,21 @@ impl ListCustomVerificationEmailTemplatesError {
 impl fmt::Display for ListCustomVerificationEmailTemplatesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListCustomVerificationEmailTemplatesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for ListCustomVerificationEmailTemplatesError {}
 /// Errors returned by ListIdentities
 #[derive(Debug, PartialEq)]
-pub enum ListIdentitiesError {}
+#[non_exhaustive]
+pub enum ListIdentitiesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListIdentitiesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListIdentitiesError> {
@@ -8799,10 +9052,8 @@ impl ListIdentitiesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListIdentitiesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8819,13 +9070,19 @@ impl ListIdentitiesError {
 impl fmt::Display for ListIdentitiesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListIdentitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListIdentitiesError {}
 /// Errors returned by ListIdentityPolicies
 #[derive(Debug, PartialEq)]
-pub enum ListIdentityPoliciesError {}
+#[non_exhaustive]
+pub enum ListIdentityPoliciesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListIdentityPoliciesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListIdentityPoliciesError> {
@@ -8833,10 +9090,8 @@ impl ListIdentityPoliciesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListIdentityPoliciesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8853,13 +9108,19 @@ impl ListIdentityPoliciesError {
 impl fmt::Display for ListIdentityPoliciesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListIdentityPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListIdentityPoliciesError {}
 /// Errors returned by ListReceiptFilters
 #[derive(Debug, PartialEq)]
-pub enum ListReceiptFiltersError {}
+#[non_exhaustive]
+pub enum ListReceiptFiltersError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListReceiptFiltersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListReceiptFiltersError> {
@@ -8867,10 +9128,8 @@ impl ListReceiptFiltersError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListReceiptFiltersError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8887,13 +9146,19 @@ impl ListReceiptFiltersError {
 impl fmt::Display for ListReceiptFiltersError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListReceiptFiltersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListReceiptFiltersError {}
 /// Errors returned by ListReceiptRuleSets
 #[derive(Debug, PartialEq)]
-pub enum ListReceiptRuleSetsError {}
+#[non_exhaustive]
+pub enum ListReceiptRuleSetsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListReceiptRuleSetsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListReceiptRuleSetsError> {
@@ -8901,10 +9166,8 @@ impl ListReceiptRuleSetsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListReceiptRuleSetsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8921,13 +9184,19 @@ impl ListReceiptRuleSetsError {
 impl fmt::Display for ListReceiptRuleSetsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListReceiptRuleSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListReceiptRuleSetsError {}
 /// Errors returned by ListTemplates
 #[derive(Debug, PartialEq)]
-pub enum ListTemplatesError {}
+#[non_exhaustive]
+pub enum ListTemplatesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListTemplatesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTemplatesError> {
@@ -8935,10 +9204,8 @@ impl ListTemplatesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListTemplatesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8955,13 +9222,19 @@ impl ListTemplatesError {
 impl fmt::Display for ListTemplatesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListTemplatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListTemplatesError {}
 /// Errors returned by ListVerifiedEmailAddresses
 #[derive(Debug, PartialEq)]
-pub enum ListVerifiedEmailAddressesError {}
+#[non_exhaustive]
+pub enum ListVerifiedEmailAddressesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListVerifiedEmailAddressesError {
     pub fn from_response(
@@ -8971,10 +9244,8 @@ impl ListVerifiedEmailAddressesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListVerifiedEmailAddressesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8991,17 +9262,22 @@ impl ListVerifiedEmailAddressesError {
 impl fmt::Display for ListVerifiedEmailAddressesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListVerifiedEmailAddressesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListVerifiedEmailAddressesError {}
 /// Errors returned by PutConfigurationSetDeliveryOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConfigurationSetDeliveryOptionsError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
     /// <p>Indicates that provided delivery option is invalid.</p>
     InvalidDeliveryOptions(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConfigurationSetDeliveryOptionsError {
@@ -9015,20 +9291,27 @@ impl PutConfigurationSetDeliveryOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutConfigurationSetDeliveryOptionsError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDeliveryOptions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutConfigurationSetDeliveryOptionsError::InvalidDeliveryOptions(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            PutConfigurationSetDeliveryOptionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9053,15 +9336,21 @@ impl fmt::Display for PutConfigurationSetDeliveryOptionsError {
             PutConfigurationSetDeliveryOptionsError::InvalidDeliveryOptions(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutConfigurationSetDeliveryOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutConfigurationSetDeliveryOptionsError {}
 /// Errors returned by PutIdentityPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutIdentityPolicyError {
     /// <p>Indicates that the provided policy is invalid. Check the error stack for more information about what caused the error.</p>
     InvalidPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutIdentityPolicyError {
@@ -9073,11 +9362,12 @@ impl PutIdentityPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidPolicy" => {
-                        return RusotoError::Service(PutIdentityPolicyError::InvalidPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutIdentityPolicyError::InvalidPolicy(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutIdentityPolicyError::Unhandled, &res),
                 }
             }
         }
@@ -9097,17 +9387,21 @@ impl fmt::Display for PutIdentityPolicyError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PutIdentityPolicyError::InvalidPolicy(ref cause) => write!(f, "{}", cause),
+            PutIdentityPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutIdentityPolicyError {}
 /// Errors returned by ReorderReceiptRuleSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReorderReceiptRuleSetError {
     /// <p>Indicates that the provided receipt rule does not exist.</p>
     RuleDoesNotExist(String),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
     RuleSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReorderReceiptRuleSetError {
@@ -9119,16 +9413,20 @@ impl ReorderReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(ReorderReceiptRuleSetError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ReorderReceiptRuleSetError::RuleDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ReorderReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(ReorderReceiptRuleSetError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9149,15 +9447,19 @@ impl fmt::Display for ReorderReceiptRuleSetError {
         match *self {
             ReorderReceiptRuleSetError::RuleDoesNotExist(ref cause) => write!(f, "{}", cause),
             ReorderReceiptRuleSetError::RuleSetDoesNotExist(ref cause) => write!(f, "{}", cause),
+            ReorderReceiptRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReorderReceiptRuleSetError {}
 /// Errors returned by SendBounce
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendBounceError {
     /// <p>Indicates that the action failed, and the message could not be sent. Check the error stack for more information about what caused the error.</p>
     MessageRejected(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendBounceError {
@@ -9169,11 +9471,12 @@ impl SendBounceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MessageRejected" => {
-                        return RusotoError::Service(SendBounceError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendBounceError::MessageRejected(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SendBounceError::Unhandled, &res),
                 }
             }
         }
@@ -9193,12 +9496,14 @@ impl fmt::Display for SendBounceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SendBounceError::MessageRejected(ref cause) => write!(f, "{}", cause),
+            SendBounceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendBounceError {}
 /// Errors returned by SendBulkTemplatedEmail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendBulkTemplatedEmailError {
     /// <p>Indicates that email sending is disabled for your entire Amazon SES account.</p> <p>You can enable or disable email sending for your Amazon SES account using <a>UpdateAccountSendingEnabled</a>.</p>
     AccountSendingPaused(String),
@@ -9212,6 +9517,8 @@ pub enum SendBulkTemplatedEmailError {
     MessageRejected(String),
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
     TemplateDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendBulkTemplatedEmailError {
@@ -9223,42 +9530,50 @@ impl SendBulkTemplatedEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendBulkTemplatedEmailError::AccountSendingPaused(parsed_error.message),
+                            &res,
                         )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendBulkTemplatedEmailError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendBulkTemplatedEmailError::ConfigurationSetSendingPaused(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendBulkTemplatedEmailError::MailFromDomainNotVerified(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendBulkTemplatedEmailError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendBulkTemplatedEmailError::MessageRejected(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendBulkTemplatedEmailError::TemplateDoesNotExist(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(SendBulkTemplatedEmailError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9289,12 +9604,14 @@ impl fmt::Display for SendBulkTemplatedEmailError {
             }
             SendBulkTemplatedEmailError::MessageRejected(ref cause) => write!(f, "{}", cause),
             SendBulkTemplatedEmailError::TemplateDoesNotExist(ref cause) => write!(f, "{}", cause),
+            SendBulkTemplatedEmailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendBulkTemplatedEmailError {}
 /// Errors returned by SendCustomVerificationEmail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendCustomVerificationEmailError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
@@ -9306,6 +9623,8 @@ pub enum SendCustomVerificationEmailError {
     MessageRejected(String),
     /// <p>Indicates that the account has not been granted production access.</p>
     ProductionAccessNotGranted(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendCustomVerificationEmailError {
@@ -9318,7 +9637,7 @@ impl SendCustomVerificationEmailError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::ConfigurationSetDoesNotExist(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(SendCustomVerificationEmailError::FromEmailAddressNotVerified(parsed_error.message)),"MessageRejected" => return RusotoError::Service(SendCustomVerificationEmailError::MessageRejected(parsed_error.message)),"ProductionAccessNotGranted" => return RusotoError::Service(SendCustomVerificationEmailError::ProductionAccessNotGranted(parsed_error.message)),_ => {}
+                                    "ConfigurationSetDoesNotExist" => return RusotoError::service(SendCustomVerificationEmailError::ConfigurationSetDoesNotExist(parsed_error.message), &res),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::service(SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message), &res),"FromEmailAddressNotVerified" => return RusotoError::service(SendCustomVerificationEmailError::FromEmailAddressNotVerified(parsed_error.message), &res),"MessageRejected" => return RusotoError::service(SendCustomVerificationEmailError::MessageRejected(parsed_error.message), &res),"ProductionAccessNotGranted" => return RusotoError::service(SendCustomVerificationEmailError::ProductionAccessNotGranted(parsed_error.message), &res),_ => return RusotoError::unhandled(SendCustomVerificationEmailError::Unhandled, &res),
                                 }
             }
         }
@@ -9350,12 +9669,14 @@ impl fmt::Display for SendCustomVerificationEmailError {
             SendCustomVerificationEmailError::ProductionAccessNotGranted(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SendCustomVerificationEmailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendCustomVerificationEmailError {}
 /// Errors returned by SendEmail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendEmailError {
     /// <p>Indicates that email sending is disabled for your entire Amazon SES account.</p> <p>You can enable or disable email sending for your Amazon SES account using <a>UpdateAccountSendingEnabled</a>.</p>
     AccountSendingPaused(String),
@@ -9367,6 +9688,8 @@ pub enum SendEmailError {
     MailFromDomainNotVerified(String),
     /// <p>Indicates that the action failed, and the message could not be sent. Check the error stack for more information about what caused the error.</p>
     MessageRejected(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendEmailError {
@@ -9378,31 +9701,36 @@ impl SendEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(SendEmailError::AccountSendingPaused(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendEmailError::AccountSendingPaused(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(SendEmailError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendEmailError::ConfigurationSetDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(SendEmailError::ConfigurationSetSendingPaused(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendEmailError::ConfigurationSetSendingPaused(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(SendEmailError::MailFromDomainNotVerified(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendEmailError::MailFromDomainNotVerified(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendEmailError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendEmailError::MessageRejected(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SendEmailError::Unhandled, &res),
                 }
             }
         }
@@ -9426,12 +9754,14 @@ impl fmt::Display for SendEmailError {
             SendEmailError::ConfigurationSetSendingPaused(ref cause) => write!(f, "{}", cause),
             SendEmailError::MailFromDomainNotVerified(ref cause) => write!(f, "{}", cause),
             SendEmailError::MessageRejected(ref cause) => write!(f, "{}", cause),
+            SendEmailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendEmailError {}
 /// Errors returned by SendRawEmail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendRawEmailError {
     /// <p>Indicates that email sending is disabled for your entire Amazon SES account.</p> <p>You can enable or disable email sending for your Amazon SES account using <a>UpdateAccountSendingEnabled</a>.</p>
     AccountSendingPaused(String),
@@ -9443,6 +9773,8 @@ pub enum SendRawEmailError {
     MailFromDomainNotVerified(String),
     /// <p>Indicates that the action failed, and the message could not be sent. Check the error stack for more information about what caused the error.</p>
     MessageRejected(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendRawEmailError {
@@ -9454,31 +9786,36 @@ impl SendRawEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(SendRawEmailError::AccountSendingPaused(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendRawEmailError::AccountSendingPaused(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendRawEmailError::ConfigurationSetDoesNotExist(parsed_error.message),
+                            &res,
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendRawEmailError::ConfigurationSetSendingPaused(parsed_error.message),
+                            &res,
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(SendRawEmailError::MailFromDomainNotVerified(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendRawEmailError::MailFromDomainNotVerified(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendRawEmailError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendRawEmailError::MessageRejected(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SendRawEmailError::Unhandled, &res),
                 }
             }
         }
@@ -9502,12 +9839,14 @@ impl fmt::Display for SendRawEmailError {
             SendRawEmailError::ConfigurationSetSendingPaused(ref cause) => write!(f, "{}", cause),
             SendRawEmailError::MailFromDomainNotVerified(ref cause) => write!(f, "{}", cause),
             SendRawEmailError::MessageRejected(ref cause) => write!(f, "{}", cause),
+            SendRawEmailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendRawEmailError {}
 /// Errors returned by SendTemplatedEmail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendTemplatedEmailError {
     /// <p>Indicates that email sending is disabled for your entire Amazon SES account.</p> <p>You can enable or disable email sending for your Amazon SES account using <a>UpdateAccountSendingEnabled</a>.</p>
     AccountSendingPaused(String),
@@ -9521,6 +9860,8 @@ pub enum SendTemplatedEmailError {
     MessageRejected(String),
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
     TemplateDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendTemplatedEmailError {
@@ -9532,42 +9873,48 @@ impl SendTemplatedEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(SendTemplatedEmailError::AccountSendingPaused(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendTemplatedEmailError::AccountSendingPaused(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendTemplatedEmailError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendTemplatedEmailError::ConfigurationSetSendingPaused(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendTemplatedEmailError::MailFromDomainNotVerified(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendTemplatedEmailError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendTemplatedEmailError::MessageRejected(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(SendTemplatedEmailError::TemplateDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendTemplatedEmailError::TemplateDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SendTemplatedEmailError::Unhandled, &res),
                 }
             }
         }
@@ -9596,15 +9943,19 @@ impl fmt::Display for SendTemplatedEmailError {
             SendTemplatedEmailError::MailFromDomainNotVerified(ref cause) => write!(f, "{}", cause),
             SendTemplatedEmailError::MessageRejected(ref cause) => write!(f, "{}", cause),
             SendTemplatedEmailError::TemplateDoesNotExist(ref cause) => write!(f, "{}", cause),
+            SendTemplatedEmailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendTemplatedEmailError {}
 /// Errors returned by SetActiveReceiptRuleSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetActiveReceiptRuleSetError {
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
     RuleSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetActiveReceiptRuleSetError {
@@ -9616,11 +9967,17 @@ impl SetActiveReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetActiveReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            SetActiveReceiptRuleSetError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9640,13 +9997,18 @@ impl fmt::Display for SetActiveReceiptRuleSetError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SetActiveReceiptRuleSetError::RuleSetDoesNotExist(ref cause) => write!(f, "{}", cause),
+            SetActiveReceiptRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetActiveReceiptRuleSetError {}
 /// Errors returned by SetIdentityDkimEnabled
 #[derive(Debug, PartialEq)]
-pub enum SetIdentityDkimEnabledError {}
+#[non_exhaustive]
+pub enum SetIdentityDkimEnabledError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SetIdentityDkimEnabledError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SetIdentityDkimEnabledError> {
@@ -9654,10 +10016,8 @@ impl SetIdentityDkimEnabledError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(SetIdentityDkimEnabledError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9674,13 +10034,19 @@ impl SetIdentityDkimEnabledError {
 impl fmt::Display for SetIdentityDkimEnabledError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SetIdentityDkimEnabledError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SetIdentityDkimEnabledError {}
 /// Errors returned by SetIdentityFeedbackForwardingEnabled
 #[derive(Debug, PartialEq)]
-pub enum SetIdentityFeedbackForwardingEnabledError {}
+#[non_exhaustive]
+pub enum SetIdentityFeedbackForwardingEnabledError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SetIdentityFeedbackForwardingEnabledError {
     pub fn from_response(
@@ -9690,10 +10056,11 @@ impl SetIdentityFeedbackForwardingEnabledError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    SetIdentityFeedbackForwardingEnabledError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -9710,13 +10077,21 @@ impl SetIdentityFeedbackForwardingEnabledError {
 impl fmt::Display for SetIdentityFeedbackForwardingEnabledError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SetIdentityFeedbackForwardingEnabledError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for SetIdentityFeedbackForwardingEnabledError {}
 /// Errors returned by SetIdentityHeadersInNotificationsEnabled
 #[derive(Debug, PartialEq)]
-pub enum SetIdentityHeadersInNotificationsEnabledError {}
+#[non_exhaustive]
+pub enum SetIdentityHeadersInNotificationsEnabledError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SetIdentityHeadersInNotificationsEnabledError {
     pub fn from_response(
@@ -9726,10 +10101,11 @@ impl SetIdentityHeadersInNotificationsEnabledError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    SetIdentityHeadersInNotificationsEnabledError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -9746,13 +10122,21 @@ impl SetIdentityHeadersInNotificationsEnabledError {
 impl fmt::Display for SetIdentityHeadersInNotificationsEnabledError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SetIdentityHeadersInNotificationsEnabledError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for SetIdentityHeadersInNotificationsEnabledError {}
 /// Errors returned by SetIdentityMailFromDomain
 #[derive(Debug, PartialEq)]
-pub enum SetIdentityMailFromDomainError {}
+#[non_exhaustive]
+pub enum SetIdentityMailFromDomainError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SetIdentityMailFromDomainError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SetIdentityMailFromDomainError> {
@@ -9760,10 +10144,8 @@ impl SetIdentityMailFromDomainError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(SetIdentityMailFromDomainError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9780,13 +10162,19 @@ impl SetIdentityMailFromDomainError {
 impl fmt::Display for SetIdentityMailFromDomainError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SetIdentityMailFromDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SetIdentityMailFromDomainError {}
 /// Errors returned by SetIdentityNotificationTopic
 #[derive(Debug, PartialEq)]
-pub enum SetIdentityNotificationTopicError {}
+#[non_exhaustive]
+pub enum SetIdentityNotificationTopicError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SetIdentityNotificationTopicError {
     pub fn from_response(
@@ -9796,10 +10184,8 @@ impl SetIdentityNotificationTopicError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(SetIdentityNotificationTopicError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9816,17 +10202,22 @@ impl SetIdentityNotificationTopicError {
 impl fmt::Display for SetIdentityNotificationTopicError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SetIdentityNotificationTopicError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SetIdentityNotificationTopicError {}
 /// Errors returned by SetReceiptRulePosition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetReceiptRulePositionError {
     /// <p>Indicates that the provided receipt rule does not exist.</p>
     RuleDoesNotExist(String),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
     RuleSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetReceiptRulePositionError {
@@ -9838,16 +10229,20 @@ impl SetReceiptRulePositionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(SetReceiptRulePositionError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetReceiptRulePositionError::RuleDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetReceiptRulePositionError::RuleSetDoesNotExist(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(SetReceiptRulePositionError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9868,12 +10263,14 @@ impl fmt::Display for SetReceiptRulePositionError {
         match *self {
             SetReceiptRulePositionError::RuleDoesNotExist(ref cause) => write!(f, "{}", cause),
             SetReceiptRulePositionError::RuleSetDoesNotExist(ref cause) => write!(f, "{}", cause),
+            SetReceiptRulePositionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetReceiptRulePositionError {}
 /// Errors returned by TestRenderTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestRenderTemplateError {
     /// <p>Indicates that one or more of the replacement values you provided is invalid. This error may occur when the TemplateData object contains invalid JSON.</p>
     InvalidRenderingParameter(String),
@@ -9881,6 +10278,8 @@ pub enum TestRenderTemplateError {
     MissingRenderingAttribute(String),
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
     TemplateDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestRenderTemplateError {
@@ -9892,25 +10291,28 @@ impl TestRenderTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidRenderingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TestRenderTemplateError::InvalidRenderingParameter(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "MissingRenderingAttribute" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TestRenderTemplateError::MissingRenderingAttribute(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(TestRenderTemplateError::TemplateDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TestRenderTemplateError::TemplateDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(TestRenderTemplateError::Unhandled, &res),
                 }
             }
         }
@@ -9932,13 +10334,18 @@ impl fmt::Display for TestRenderTemplateError {
             TestRenderTemplateError::InvalidRenderingParameter(ref cause) => write!(f, "{}", cause),
             TestRenderTemplateError::MissingRenderingAttribute(ref cause) => write!(f, "{}", cause),
             TestRenderTemplateError::TemplateDoesNotExist(ref cause) => write!(f, "{}", cause),
+            TestRenderTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TestRenderTemplateError {}
 /// Errors returned by UpdateAccountSendingEnabled
 #[derive(Debug, PartialEq)]
-pub enum UpdateAccountSendingEnabledError {}
+#[non_exhaustive]
+pub enum UpdateAccountSendingEnabledError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UpdateAccountSendingEnabledError {
     pub fn from_response(
@@ -9948,10 +10355,8 @@ impl UpdateAccountSendingEnabledError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(UpdateAccountSendingEnabledError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9968,12 +10373,15 @@ impl UpdateAccountSendingEnabledError {
 impl fmt::Display for UpdateAccountSendingEnabledError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UpdateAccountSendingEnabledError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateAccountSendingEnabledError {}
 /// Errors returned by UpdateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigurationSetEventDestinationError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
@@ -9985,6 +10393,8 @@ pub enum UpdateConfigurationSetEventDestinationError {
     InvalidFirehoseDestination(String),
     /// <p>Indicates that the Amazon Simple Notification Service (Amazon SNS) destination is invalid. See the error message for details.</p>
     InvalidSNSDestination(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigurationSetEventDestinationError {
@@ -9997,36 +10407,46 @@ impl UpdateConfigurationSetEventDestinationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    "EventDestinationDoesNotExist" => return RusotoError::Service(
+                    "EventDestinationDoesNotExist" => return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    "InvalidCloudWatchDestination" => return RusotoError::Service(
+                    "InvalidCloudWatchDestination" => return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidFirehoseDestination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateConfigurationSetEventDestinationError::InvalidFirehoseDestination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSNSDestination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateConfigurationSetEventDestinationError::InvalidSNSDestination(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateConfigurationSetEventDestinationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10060,15 +10480,21 @@ impl fmt::Display for UpdateConfigurationSetEventDestinationError {
             UpdateConfigurationSetEventDestinationError::InvalidSNSDestination(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateConfigurationSetEventDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateConfigurationSetEventDestinationError {}
 /// Errors returned by UpdateConfigurationSetReputationMetricsEnabled
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigurationSetReputationMetricsEnabledError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigurationSetReputationMetricsEnabledError {
@@ -10081,7 +10507,7 @@ impl UpdateConfigurationSetReputationMetricsEnabledError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(parsed_error.message)),_ => {}
+                                    "ConfigurationSetDoesNotExist" => return RusotoError::service(UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(parsed_error.message), &res),_ => return RusotoError::unhandled(UpdateConfigurationSetReputationMetricsEnabledError::Unhandled, &res),
                                 }
             }
         }
@@ -10103,15 +10529,21 @@ impl fmt::Display for UpdateConfigurationSetReputationMetricsEnabledError {
             UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(
                 ref cause,
             ) => write!(f, "{}", cause),
+            UpdateConfigurationSetReputationMetricsEnabledError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateConfigurationSetReputationMetricsEnabledError {}
 /// Errors returned by UpdateConfigurationSetSendingEnabled
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigurationSetSendingEnabledError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigurationSetSendingEnabledError {
@@ -10125,13 +10557,19 @@ impl UpdateConfigurationSetSendingEnabledError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateConfigurationSetSendingEnabledError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateConfigurationSetSendingEnabledError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10153,12 +10591,16 @@ impl fmt::Display for UpdateConfigurationSetSendingEnabledError {
             UpdateConfigurationSetSendingEnabledError::ConfigurationSetDoesNotExist(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateConfigurationSetSendingEnabledError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateConfigurationSetSendingEnabledError {}
 /// Errors returned by UpdateConfigurationSetTrackingOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigurationSetTrackingOptionsError {
     /// <p>Indicates that the configuration set does not exist.</p>
     ConfigurationSetDoesNotExist(String),
@@ -10166,6 +10608,8 @@ pub enum UpdateConfigurationSetTrackingOptionsError {
     InvalidTrackingOptions(String),
     /// <p>Indicates that the TrackingOptions object you specified does not exist.</p>
     TrackingOptionsDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigurationSetTrackingOptionsError {
@@ -10178,26 +10622,34 @@ impl UpdateConfigurationSetTrackingOptionsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
                         UpdateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidTrackingOptions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TrackingOptionsDoesNotExistException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateConfigurationSetTrackingOptionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10225,12 +10677,16 @@ impl fmt::Display for UpdateConfigurationSetTrackingOptionsError {
             UpdateConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateConfigurationSetTrackingOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateConfigurationSetTrackingOptionsError {}
 /// Errors returned by UpdateCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCustomVerificationEmailTemplateError {
     /// <p>Indicates that custom verification email template provided content is invalid.</p>
     CustomVerificationEmailInvalidContent(String),
@@ -10238,6 +10694,8 @@ pub enum UpdateCustomVerificationEmailTemplateError {
     CustomVerificationEmailTemplateDoesNotExist(String),
     /// <p>Indicates that the sender address specified for a custom verification email is not verified, and is therefore not eligible to send the custom verification email. </p>
     FromEmailAddressNotVerified(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCustomVerificationEmailTemplateError {
@@ -10250,7 +10708,7 @@ impl UpdateCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailInvalidContent" => return RusotoError::service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message), &res),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message), &res),"FromEmailAddressNotVerified" => return RusotoError::service(UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message), &res),_ => return RusotoError::unhandled(UpdateCustomVerificationEmailTemplateError::Unhandled, &res),
                                 }
             }
         }
@@ -10271,13 +10729,15 @@ impl fmt::Display for UpdateCustomVerificationEmailTemplateError {
         match *self {
                             UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(ref cause) => write!(f, "{}", cause),
 UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(ref cause) => write!(f, "{}", cause),
-UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(ref cause) => write!(f, "{}", cause)
-                        }
+UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(ref cause) => write!(f, "{}", cause),
+            UpdateCustomVerificationEmailTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateCustomVerificationEmailTemplateError {}
 /// Errors returned by UpdateReceiptRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateReceiptRuleError {
     /// <p>Indicates that the provided AWS Lambda function is invalid, or that Amazon SES could not execute the provided function, possibly due to permissions issues. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p>
     InvalidLambdaFunction(String),
@@ -10291,6 +10751,8 @@ pub enum UpdateReceiptRuleError {
     RuleDoesNotExist(String),
     /// <p>Indicates that the provided receipt rule set does not exist.</p>
     RuleSetDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateReceiptRuleError {
@@ -10302,36 +10764,42 @@ impl UpdateReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidLambdaFunction" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::InvalidLambdaFunction(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateReceiptRuleError::InvalidLambdaFunction(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidS3Configuration" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateReceiptRuleError::InvalidS3Configuration(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidSnsTopic" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::InvalidSnsTopic(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateReceiptRuleError::InvalidSnsTopic(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateReceiptRuleError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateReceiptRuleError::RuleDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateReceiptRuleError::RuleSetDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UpdateReceiptRuleError::Unhandled, &res),
                 }
             }
         }
@@ -10356,17 +10824,21 @@ impl fmt::Display for UpdateReceiptRuleError {
             UpdateReceiptRuleError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateReceiptRuleError::RuleDoesNotExist(ref cause) => write!(f, "{}", cause),
             UpdateReceiptRuleError::RuleSetDoesNotExist(ref cause) => write!(f, "{}", cause),
+            UpdateReceiptRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateReceiptRuleError {}
 /// Errors returned by UpdateTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTemplateError {
     /// <p>Indicates that the template that you specified could not be rendered. This issue may occur when a template refers to a partial that does not exist.</p>
     InvalidTemplate(String),
     /// <p>Indicates that the Template object you specified does not exist in your Amazon SES account.</p>
     TemplateDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTemplateError {
@@ -10378,16 +10850,18 @@ impl UpdateTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTemplate" => {
-                        return RusotoError::Service(UpdateTemplateError::InvalidTemplate(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateTemplateError::InvalidTemplate(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(UpdateTemplateError::TemplateDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateTemplateError::TemplateDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UpdateTemplateError::Unhandled, &res),
                 }
             }
         }
@@ -10408,13 +10882,18 @@ impl fmt::Display for UpdateTemplateError {
         match *self {
             UpdateTemplateError::InvalidTemplate(ref cause) => write!(f, "{}", cause),
             UpdateTemplateError::TemplateDoesNotExist(ref cause) => write!(f, "{}", cause),
+            UpdateTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTemplateError {}
 /// Errors returned by VerifyDomainDkim
 #[derive(Debug, PartialEq)]
-pub enum VerifyDomainDkimError {}
+#[non_exhaustive]
+pub enum VerifyDomainDkimError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl VerifyDomainDkimError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<VerifyDomainDkimError> {
@@ -10422,10 +10901,8 @@ impl VerifyDomainDkimError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(VerifyDomainDkimError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10442,13 +10919,19 @@ impl VerifyDomainDkimError {
 impl fmt::Display for VerifyDomainDkimError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            VerifyDomainDkimError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for VerifyDomainDkimError {}
 /// Errors returned by VerifyDomainIdentity
 #[derive(Debug, PartialEq)]
-pub enum VerifyDomainIdentityError {}
+#[non_exhaustive]
+pub enum VerifyDomainIdentityError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl VerifyDomainIdentityError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<VerifyDomainIdentityError> {
@@ -10456,10 +10939,8 @@ impl VerifyDomainIdentityError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(VerifyDomainIdentityError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10476,13 +10957,19 @@ impl VerifyDomainIdentityError {
 impl fmt::Display for VerifyDomainIdentityError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            VerifyDomainIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for VerifyDomainIdentityError {}
 /// Errors returned by VerifyEmailAddress
 #[derive(Debug, PartialEq)]
-pub enum VerifyEmailAddressError {}
+#[non_exhaustive]
+pub enum VerifyEmailAddressError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl VerifyEmailAddressError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<VerifyEmailAddressError> {
@@ -10490,10 +10977,8 @@ impl VerifyEmailAddressError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(VerifyEmailAddressError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10510,13 +10995,19 @@ impl VerifyEmailAddressError {
 impl fmt::Display for VerifyEmailAddressError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            VerifyEmailAddressError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for VerifyEmailAddressError {}
 /// Errors returned by VerifyEmailIdentity
 #[derive(Debug, PartialEq)]
-pub enum VerifyEmailIdentityError {}
+#[non_exhaustive]
+pub enum VerifyEmailIdentityError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl VerifyEmailIdentityError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<VerifyEmailIdentityError> {
@@ -10524,10 +11015,8 @@ impl VerifyEmailIdentityError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(VerifyEmailIdentityError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10544,7 +11033,9 @@ impl VerifyEmailIdentityError {
 impl fmt::Display for VerifyEmailIdentityError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            VerifyEmailIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for VerifyEmailIdentityError {}
@@ -11024,6 +11515,7 @@ impl SesClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SesClient {
         SesClient {
             client: Client::shared(),
@@ -11049,35 +11541,52 @@ impl SesClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SesClient {
         SesClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SesClient> {
+        rusoto_core::ClientBuilder::new(SesClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SesClient {
+        SesClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Ses for SesClient {
-    /// <p>Creates a receipt rule set by cloning an existing one. All receipt rules and configurations are copied to the new receipt rule set and are completely independent of the source rule set.</p> <p>For information about setting up rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn clone_receipt_rule_set(
+impl SesClient {
+    /// Calls `clone_receipt_rule_set`, also returning the metadata of the response.
+    pub async fn clone_receipt_rule_set_with_metadata(
         &self,
         input: CloneReceiptRuleSetRequest,
-    ) -> Result<CloneReceiptRuleSetResponse, RusotoError<CloneReceiptRuleSetError>> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CloneReceiptRuleSet");
-        params.put("Version", "2010-12-01");
-        CloneReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<
+        (CloneReceiptRuleSetResponse, ResponseMetadata),
+        RusotoError<CloneReceiptRuleSetError>,
+    > {
+        let request = self.build_clone_receipt_rule_set_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CloneReceiptRuleSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11099,34 +11608,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a configuration set.</p> <p>Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn create_configuration_set(
+    /// Presigns the request `clone_receipt_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_clone_receipt_rule_set(
         &self,
-        input: CreateConfigurationSetRequest,
-    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CloneReceiptRuleSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_clone_receipt_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_clone_receipt_rule_set_request(
+        &self,
+        input: CloneReceiptRuleSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CloneReceiptRuleSet");
         let mut params = Params::new();
 
-        params.put("Action", "CreateConfigurationSet");
+        params.put("Action", "CloneReceiptRuleSet");
         params.put("Version", "2010-12-01");
-        CreateConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
+        CloneReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_configuration_set`, also returning the metadata of the response.
+    pub async fn create_configuration_set_with_metadata(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<
+        (CreateConfigurationSetResponse, ResponseMetadata),
+        RusotoError<CreateConfigurationSetError>,
+    > {
+        let request = self.build_create_configuration_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateConfigurationSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11148,39 +11687,69 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a configuration set event destination.</p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>An event destination is the AWS service to which Amazon SES publishes the email sending events associated with a configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn create_configuration_set_event_destination(
+    /// Presigns the request `create_configuration_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration_set(
         &self,
-        input: CreateConfigurationSetEventDestinationRequest,
-    ) -> Result<
-        CreateConfigurationSetEventDestinationResponse,
-        RusotoError<CreateConfigurationSetEventDestinationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_set_request(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSet");
         let mut params = Params::new();
 
-        params.put("Action", "CreateConfigurationSetEventDestination");
+        params.put("Action", "CreateConfigurationSet");
         params.put("Version", "2010-12-01");
-        CreateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
+        CreateConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_configuration_set_event_destination`, also returning the metadata of the response.
+    pub async fn create_configuration_set_event_destination_with_metadata(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        (
+            CreateConfigurationSetEventDestinationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        let request = self.build_create_configuration_set_event_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateConfigurationSetEventDestinationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11202,39 +11771,69 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
-    async fn create_configuration_set_tracking_options(
+    /// Presigns the request `create_configuration_set_event_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration_set_event_destination(
         &self,
-        input: CreateConfigurationSetTrackingOptionsRequest,
-    ) -> Result<
-        CreateConfigurationSetTrackingOptionsResponse,
-        RusotoError<CreateConfigurationSetTrackingOptionsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_set_event_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_set_event_destination_request(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSetEventDestination");
         let mut params = Params::new();
 
-        params.put("Action", "CreateConfigurationSetTrackingOptions");
+        params.put("Action", "CreateConfigurationSetEventDestination");
         params.put("Version", "2010-12-01");
-        CreateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
+        CreateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_configuration_set_tracking_options`, also returning the metadata of the response.
+    pub async fn create_configuration_set_tracking_options_with_metadata(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        (
+            CreateConfigurationSetTrackingOptionsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateConfigurationSetTrackingOptionsError>,
+    > {
+        let request = self.build_create_configuration_set_tracking_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateConfigurationSetTrackingOptionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11256,64 +11855,119 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn create_custom_verification_email_template(
+    /// Presigns the request `create_configuration_set_tracking_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration_set_tracking_options(
         &self,
-        input: CreateCustomVerificationEmailTemplateRequest,
-    ) -> Result<(), RusotoError<CreateCustomVerificationEmailTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_set_tracking_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_set_tracking_options_request(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSetTrackingOptions");
         let mut params = Params::new();
 
-        params.put("Action", "CreateCustomVerificationEmailTemplate");
+        params.put("Action", "CreateConfigurationSetTrackingOptions");
         params.put("Version", "2010-12-01");
-        CreateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
+        CreateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_custom_verification_email_template`, also returning the metadata of the response.
+    pub async fn create_custom_verification_email_template_with_metadata(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateCustomVerificationEmailTemplateError>>
+    {
+        let request = self.build_create_custom_verification_email_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateCustomVerificationEmailTemplateError::from_response(
                 response,
             ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Creates a new IP address filter.</p> <p>For information about setting up IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn create_receipt_filter(
+    /// Presigns the request `create_custom_verification_email_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_custom_verification_email_template(
         &self,
-        input: CreateReceiptFilterRequest,
-    ) -> Result<CreateReceiptFilterResponse, RusotoError<CreateReceiptFilterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_custom_verification_email_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_custom_verification_email_template_request(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateCustomVerificationEmailTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "CreateReceiptFilter");
+        params.put("Action", "CreateCustomVerificationEmailTemplate");
         params.put("Version", "2010-12-01");
-        CreateReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
+        CreateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_receipt_filter`, also returning the metadata of the response.
+    pub async fn create_receipt_filter_with_metadata(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> Result<
+        (CreateReceiptFilterResponse, ResponseMetadata),
+        RusotoError<CreateReceiptFilterError>,
+    > {
+        let request = self.build_create_receipt_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateReceiptFilterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11335,34 +11989,62 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn create_receipt_rule(
+    /// Presigns the request `create_receipt_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_receipt_filter(
         &self,
-        input: CreateReceiptRuleRequest,
-    ) -> Result<CreateReceiptRuleResponse, RusotoError<CreateReceiptRuleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateReceiptFilterRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_receipt_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_receipt_filter_request(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptFilter");
         let mut params = Params::new();
 
-        params.put("Action", "CreateReceiptRule");
+        params.put("Action", "CreateReceiptFilter");
         params.put("Version", "2010-12-01");
-        CreateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
+        CreateReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_receipt_rule`, also returning the metadata of the response.
+    pub async fn create_receipt_rule_with_metadata(
+        &self,
+        input: CreateReceiptRuleRequest,
+    ) -> Result<(CreateReceiptRuleResponse, ResponseMetadata), RusotoError<CreateReceiptRuleError>>
+    {
+        let request = self.build_create_receipt_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateReceiptRuleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11384,34 +12066,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an empty receipt rule set.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn create_receipt_rule_set(
+    /// Presigns the request `create_receipt_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_receipt_rule(
         &self,
-        input: CreateReceiptRuleSetRequest,
-    ) -> Result<CreateReceiptRuleSetResponse, RusotoError<CreateReceiptRuleSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateReceiptRuleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_receipt_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_receipt_rule_request(&self, input: CreateReceiptRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptRule");
         let mut params = Params::new();
 
-        params.put("Action", "CreateReceiptRuleSet");
+        params.put("Action", "CreateReceiptRule");
         params.put("Version", "2010-12-01");
-        CreateReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        CreateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        request
+    }
+
+    /// Calls `create_receipt_rule_set`, also returning the metadata of the response.
+    pub async fn create_receipt_rule_set_with_metadata(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> Result<
+        (CreateReceiptRuleSetResponse, ResponseMetadata),
+        RusotoError<CreateReceiptRuleSetError>,
+    > {
+        let request = self.build_create_receipt_rule_set_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if !response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateReceiptRuleSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11433,34 +12142,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn create_template(
+    /// Presigns the request `create_receipt_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_receipt_rule_set(
         &self,
-        input: CreateTemplateRequest,
-    ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateReceiptRuleSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_receipt_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_receipt_rule_set_request(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptRuleSet");
         let mut params = Params::new();
 
-        params.put("Action", "CreateTemplate");
+        params.put("Action", "CreateReceiptRuleSet");
         params.put("Version", "2010-12-01");
-        CreateTemplateRequestSerializer::serialize(&mut params, "", &input);
+        CreateReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_template`, also returning the metadata of the response.
+    pub async fn create_template_with_metadata(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> Result<(CreateTemplateResponse, ResponseMetadata), RusotoError<CreateTemplateError>> {
+        let request = self.build_create_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateTemplateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11482,34 +12218,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn delete_configuration_set(
+    /// Presigns the request `create_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_template(
         &self,
-        input: DeleteConfigurationSetRequest,
-    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_template_request(&self, input: CreateTemplateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteConfigurationSet");
+        params.put("Action", "CreateTemplate");
         params.put("Version", "2010-12-01");
-        DeleteConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
+        CreateTemplateRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_configuration_set`, also returning the metadata of the response.
+    pub async fn delete_configuration_set_with_metadata(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<
+        (DeleteConfigurationSetResponse, ResponseMetadata),
+        RusotoError<DeleteConfigurationSetError>,
+    > {
+        let request = self.build_delete_configuration_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteConfigurationSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11531,39 +12294,69 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a configuration set event destination. Configuration set event destinations are associated with configuration sets, which enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn delete_configuration_set_event_destination(
+    /// Presigns the request `delete_configuration_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_set(
         &self,
-        input: DeleteConfigurationSetEventDestinationRequest,
-    ) -> Result<
-        DeleteConfigurationSetEventDestinationResponse,
-        RusotoError<DeleteConfigurationSetEventDestinationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_set_request(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSet");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteConfigurationSetEventDestination");
+        params.put("Action", "DeleteConfigurationSet");
         params.put("Version", "2010-12-01");
-        DeleteConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
+        DeleteConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_configuration_set_event_destination`, also returning the metadata of the response.
+    pub async fn delete_configuration_set_event_destination_with_metadata(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        (
+            DeleteConfigurationSetEventDestinationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        let request = self.build_delete_configuration_set_event_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteConfigurationSetEventDestinationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11585,39 +12378,69 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes an association between a configuration set and a custom domain for open and click event tracking.</p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p> <note> <p>Deleting this kind of association will result in emails sent using the specified configuration set to capture open and click events using the standard, Amazon SES-operated domains.</p> </note></p>
-    async fn delete_configuration_set_tracking_options(
+    /// Presigns the request `delete_configuration_set_event_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_set_event_destination(
         &self,
-        input: DeleteConfigurationSetTrackingOptionsRequest,
-    ) -> Result<
-        DeleteConfigurationSetTrackingOptionsResponse,
-        RusotoError<DeleteConfigurationSetTrackingOptionsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_set_event_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_set_event_destination_request(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSetEventDestination");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteConfigurationSetTrackingOptions");
+        params.put("Action", "DeleteConfigurationSetEventDestination");
         params.put("Version", "2010-12-01");
-        DeleteConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
+        DeleteConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_configuration_set_tracking_options`, also returning the metadata of the response.
+    pub async fn delete_configuration_set_tracking_options_with_metadata(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        (
+            DeleteConfigurationSetTrackingOptionsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteConfigurationSetTrackingOptionsError>,
+    > {
+        let request = self.build_delete_configuration_set_tracking_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteConfigurationSetTrackingOptionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11639,64 +12462,116 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes an existing custom verification email template. </p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn delete_custom_verification_email_template(
+    /// Presigns the request `delete_configuration_set_tracking_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_set_tracking_options(
         &self,
-        input: DeleteCustomVerificationEmailTemplateRequest,
-    ) -> Result<(), RusotoError<DeleteCustomVerificationEmailTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_set_tracking_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_set_tracking_options_request(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSetTrackingOptions");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteCustomVerificationEmailTemplate");
+        params.put("Action", "DeleteConfigurationSetTrackingOptions");
         params.put("Version", "2010-12-01");
-        DeleteCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
+        DeleteConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_custom_verification_email_template`, also returning the metadata of the response.
+    pub async fn delete_custom_verification_email_template_with_metadata(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCustomVerificationEmailTemplateError>>
+    {
+        let request = self.build_delete_custom_verification_email_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteCustomVerificationEmailTemplateError::from_response(
                 response,
             ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified identity (an email address or a domain) from the list of verified identities.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn delete_identity(
+    /// Presigns the request `delete_custom_verification_email_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_custom_verification_email_template(
         &self,
-        input: DeleteIdentityRequest,
-    ) -> Result<DeleteIdentityResponse, RusotoError<DeleteIdentityError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_custom_verification_email_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_custom_verification_email_template_request(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteCustomVerificationEmailTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteIdentity");
+        params.put("Action", "DeleteCustomVerificationEmailTemplate");
         params.put("Version", "2010-12-01");
-        DeleteIdentityRequestSerializer::serialize(&mut params, "", &input);
+        DeleteCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_identity`, also returning the metadata of the response.
+    pub async fn delete_identity_with_metadata(
+        &self,
+        input: DeleteIdentityRequest,
+    ) -> Result<(DeleteIdentityResponse, ResponseMetadata), RusotoError<DeleteIdentityError>> {
+        let request = self.build_delete_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteIdentityError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11718,34 +12593,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified sending authorization policy for the given identity (an email address or a domain). This API returns successfully even if a policy with the specified name does not exist.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn delete_identity_policy(
+    /// Presigns the request `delete_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_identity(
         &self,
-        input: DeleteIdentityPolicyRequest,
-    ) -> Result<DeleteIdentityPolicyResponse, RusotoError<DeleteIdentityPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteIdentityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_identity_request(&self, input: DeleteIdentityRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteIdentity");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteIdentityPolicy");
+        params.put("Action", "DeleteIdentity");
         params.put("Version", "2010-12-01");
-        DeleteIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
+        DeleteIdentityRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_identity_policy`, also returning the metadata of the response.
+    pub async fn delete_identity_policy_with_metadata(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> Result<
+        (DeleteIdentityPolicyResponse, ResponseMetadata),
+        RusotoError<DeleteIdentityPolicyError>,
+    > {
+        let request = self.build_delete_identity_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteIdentityPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11767,34 +12669,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified IP address filter.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn delete_receipt_filter(
+    /// Presigns the request `delete_identity_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_identity_policy(
         &self,
-        input: DeleteReceiptFilterRequest,
-    ) -> Result<DeleteReceiptFilterResponse, RusotoError<DeleteReceiptFilterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteIdentityPolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_identity_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_identity_policy_request(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteIdentityPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteReceiptFilter");
+        params.put("Action", "DeleteIdentityPolicy");
         params.put("Version", "2010-12-01");
-        DeleteReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
+        DeleteIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_receipt_filter`, also returning the metadata of the response.
+    pub async fn delete_receipt_filter_with_metadata(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> Result<
+        (DeleteReceiptFilterResponse, ResponseMetadata),
+        RusotoError<DeleteReceiptFilterError>,
+    > {
+        let request = self.build_delete_receipt_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteReceiptFilterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11816,34 +12748,62 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn delete_receipt_rule(
+    /// Presigns the request `delete_receipt_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_receipt_filter(
         &self,
-        input: DeleteReceiptRuleRequest,
-    ) -> Result<DeleteReceiptRuleResponse, RusotoError<DeleteReceiptRuleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteReceiptFilterRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_receipt_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_receipt_filter_request(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptFilter");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteReceiptRule");
+        params.put("Action", "DeleteReceiptFilter");
         params.put("Version", "2010-12-01");
-        DeleteReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
+        DeleteReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_receipt_rule`, also returning the metadata of the response.
+    pub async fn delete_receipt_rule_with_metadata(
+        &self,
+        input: DeleteReceiptRuleRequest,
+    ) -> Result<(DeleteReceiptRuleResponse, ResponseMetadata), RusotoError<DeleteReceiptRuleError>>
+    {
+        let request = self.build_delete_receipt_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteReceiptRuleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11865,34 +12825,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified receipt rule set and all of the receipt rules it contains.</p> <note> <p>The currently active rule set cannot be deleted.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn delete_receipt_rule_set(
+    /// Presigns the request `delete_receipt_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_receipt_rule(
         &self,
-        input: DeleteReceiptRuleSetRequest,
-    ) -> Result<DeleteReceiptRuleSetResponse, RusotoError<DeleteReceiptRuleSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteReceiptRuleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_receipt_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_receipt_rule_request(&self, input: DeleteReceiptRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptRule");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteReceiptRuleSet");
+        params.put("Action", "DeleteReceiptRule");
         params.put("Version", "2010-12-01");
-        DeleteReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        DeleteReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_receipt_rule_set`, also returning the metadata of the response.
+    pub async fn delete_receipt_rule_set_with_metadata(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> Result<
+        (DeleteReceiptRuleSetResponse, ResponseMetadata),
+        RusotoError<DeleteReceiptRuleSetError>,
+    > {
+        let request = self.build_delete_receipt_rule_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteReceiptRuleSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11914,34 +12901,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes an email template.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn delete_template(
+    /// Presigns the request `delete_receipt_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_receipt_rule_set(
         &self,
-        input: DeleteTemplateRequest,
-    ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_receipt_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_receipt_rule_set_request(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptRuleSet");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteTemplate");
+        params.put("Action", "DeleteReceiptRuleSet");
         params.put("Version", "2010-12-01");
-        DeleteTemplateRequestSerializer::serialize(&mut params, "", &input);
+        DeleteReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_template`, also returning the metadata of the response.
+    pub async fn delete_template_with_metadata(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> Result<(DeleteTemplateResponse, ResponseMetadata), RusotoError<DeleteTemplateError>> {
+        let request = self.build_delete_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteTemplateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11963,63 +12977,113 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deprecated. Use the <code>DeleteIdentity</code> operation to delete email addresses and domains.</p>
-    async fn delete_verified_email_address(
+    /// Presigns the request `delete_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_template(
         &self,
-        input: DeleteVerifiedEmailAddressRequest,
-    ) -> Result<(), RusotoError<DeleteVerifiedEmailAddressError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_template_request(&self, input: DeleteTemplateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteVerifiedEmailAddress");
+        params.put("Action", "DeleteTemplate");
         params.put("Version", "2010-12-01");
-        DeleteVerifiedEmailAddressRequestSerializer::serialize(&mut params, "", &input);
+        DeleteTemplateRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_verified_email_address`, also returning the metadata of the response.
+    pub async fn delete_verified_email_address_with_metadata(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVerifiedEmailAddressError>> {
+        let request = self.build_delete_verified_email_address_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteVerifiedEmailAddressError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Returns the metadata and receipt rules for the receipt rule set that is currently active.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn describe_active_receipt_rule_set(
+    /// Presigns the request `delete_verified_email_address` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_verified_email_address(
         &self,
-        input: DescribeActiveReceiptRuleSetRequest,
-    ) -> Result<DescribeActiveReceiptRuleSetResponse, RusotoError<DescribeActiveReceiptRuleSetError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_verified_email_address_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_verified_email_address_request(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteVerifiedEmailAddress");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeActiveReceiptRuleSet");
+        params.put("Action", "DeleteVerifiedEmailAddress");
         params.put("Version", "2010-12-01");
-        DescribeActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        DeleteVerifiedEmailAddressRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_active_receipt_rule_set`, also returning the metadata of the response.
+    pub async fn describe_active_receipt_rule_set_with_metadata(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> Result<
+        (DescribeActiveReceiptRuleSetResponse, ResponseMetadata),
+        RusotoError<DescribeActiveReceiptRuleSetError>,
+    > {
+        let request = self.build_describe_active_receipt_rule_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeActiveReceiptRuleSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12041,34 +13105,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the details of the specified configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn describe_configuration_set(
+    /// Presigns the request `describe_active_receipt_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_active_receipt_rule_set(
         &self,
-        input: DescribeConfigurationSetRequest,
-    ) -> Result<DescribeConfigurationSetResponse, RusotoError<DescribeConfigurationSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_active_receipt_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_active_receipt_rule_set_request(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeActiveReceiptRuleSet");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeConfigurationSet");
+        params.put("Action", "DescribeActiveReceiptRuleSet");
         params.put("Version", "2010-12-01");
-        DescribeConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
+        DescribeActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_configuration_set`, also returning the metadata of the response.
+    pub async fn describe_configuration_set_with_metadata(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> Result<
+        (DescribeConfigurationSetResponse, ResponseMetadata),
+        RusotoError<DescribeConfigurationSetError>,
+    > {
+        let request = self.build_describe_configuration_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeConfigurationSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12090,34 +13184,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the details of the specified receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn describe_receipt_rule(
+    /// Presigns the request `describe_configuration_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration_set(
         &self,
-        input: DescribeReceiptRuleRequest,
-    ) -> Result<DescribeReceiptRuleResponse, RusotoError<DescribeReceiptRuleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeConfigurationSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_set_request(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeConfigurationSet");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeReceiptRule");
+        params.put("Action", "DescribeConfigurationSet");
         params.put("Version", "2010-12-01");
-        DescribeReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
+        DescribeConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_receipt_rule`, also returning the metadata of the response.
+    pub async fn describe_receipt_rule_with_metadata(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> Result<
+        (DescribeReceiptRuleResponse, ResponseMetadata),
+        RusotoError<DescribeReceiptRuleError>,
+    > {
+        let request = self.build_describe_receipt_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeReceiptRuleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12139,34 +13263,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the details of the specified receipt rule set.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn describe_receipt_rule_set(
+    /// Presigns the request `describe_receipt_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_receipt_rule(
         &self,
-        input: DescribeReceiptRuleSetRequest,
-    ) -> Result<DescribeReceiptRuleSetResponse, RusotoError<DescribeReceiptRuleSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeReceiptRuleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_receipt_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_receipt_rule_request(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeReceiptRule");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeReceiptRuleSet");
+        params.put("Action", "DescribeReceiptRule");
         params.put("Version", "2010-12-01");
-        DescribeReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        DescribeReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_receipt_rule_set`, also returning the metadata of the response.
+    pub async fn describe_receipt_rule_set_with_metadata(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> Result<
+        (DescribeReceiptRuleSetResponse, ResponseMetadata),
+        RusotoError<DescribeReceiptRuleSetError>,
+    > {
+        let request = self.build_describe_receipt_rule_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeReceiptRuleSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12188,33 +13342,63 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the email sending status of the Amazon SES account for the current region.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn get_account_sending_enabled(
+    /// Presigns the request `describe_receipt_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_receipt_rule_set(
         &self,
-    ) -> Result<GetAccountSendingEnabledResponse, RusotoError<GetAccountSendingEnabledError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_receipt_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_receipt_rule_set_request(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeReceiptRuleSet");
         let mut params = Params::new();
 
-        params.put("Action", "GetAccountSendingEnabled");
+        params.put("Action", "DescribeReceiptRuleSet");
         params.put("Version", "2010-12-01");
-
+        DescribeReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_account_sending_enabled`, also returning the metadata of the response.
+    pub async fn get_account_sending_enabled_with_metadata(
+        &self,
+    ) -> Result<
+        (GetAccountSendingEnabledResponse, ResponseMetadata),
+        RusotoError<GetAccountSendingEnabledError>,
+    > {
+        let request = self.build_get_account_sending_enabled_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetAccountSendingEnabledError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12236,39 +13420,62 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the custom email verification template for the template name you specify.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn get_custom_verification_email_template(
+    /// Presigns the request `get_account_sending_enabled` would send with `presigner` instead of sending it.
+    pub async fn presign_get_account_sending_enabled(
         &self,
-        input: GetCustomVerificationEmailTemplateRequest,
-    ) -> Result<
-        GetCustomVerificationEmailTemplateResponse,
-        RusotoError<GetCustomVerificationEmailTemplateError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_account_sending_enabled_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_account_sending_enabled_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetAccountSendingEnabled");
         let mut params = Params::new();
 
-        params.put("Action", "GetCustomVerificationEmailTemplate");
+        params.put("Action", "GetAccountSendingEnabled");
         params.put("Version", "2010-12-01");
-        GetCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_custom_verification_email_template`, also returning the metadata of the response.
+    pub async fn get_custom_verification_email_template_with_metadata(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> Result<
+        (GetCustomVerificationEmailTemplateResponse, ResponseMetadata),
+        RusotoError<GetCustomVerificationEmailTemplateError>,
+    > {
+        let request = self.build_get_custom_verification_email_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetCustomVerificationEmailTemplateError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12290,35 +13497,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the current status of Easy DKIM signing for an entity. For domain name identities, this operation also returns the DKIM tokens that are required for Easy DKIM signing, and whether Amazon SES has successfully verified that these tokens have been published.</p> <p>This operation takes a list of identities as input and returns the following information for each:</p> <ul> <li> <p>Whether Easy DKIM signing is enabled or disabled.</p> </li> <li> <p>A set of DKIM tokens that represent the identity. If the identity is an email address, the tokens represent the domain of that address.</p> </li> <li> <p>Whether Amazon SES has successfully verified the DKIM tokens published in the domain's DNS. This information is only returned for domain name identities, not for email addresses.</p> </li> </ul> <p>This operation is throttled at one request per second and can only get DKIM attributes for up to 100 identities at a time.</p> <p>For more information about creating DNS records using DKIM tokens, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim-dns-records.html">Amazon SES Developer Guide</a>.</p>
-    async fn get_identity_dkim_attributes(
+    /// Presigns the request `get_custom_verification_email_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_custom_verification_email_template(
         &self,
-        input: GetIdentityDkimAttributesRequest,
-    ) -> Result<GetIdentityDkimAttributesResponse, RusotoError<GetIdentityDkimAttributesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_custom_verification_email_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_custom_verification_email_template_request(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetCustomVerificationEmailTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "GetIdentityDkimAttributes");
+        params.put("Action", "GetCustomVerificationEmailTemplate");
         params.put("Version", "2010-12-01");
-        GetIdentityDkimAttributesRequestSerializer::serialize(&mut params, "", &input);
+        GetCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_identity_dkim_attributes`, also returning the metadata of the response.
+    pub async fn get_identity_dkim_attributes_with_metadata(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> Result<
+        (GetIdentityDkimAttributesResponse, ResponseMetadata),
+        RusotoError<GetIdentityDkimAttributesError>,
+    > {
+        let request = self.build_get_identity_dkim_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetIdentityDkimAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12340,39 +13576,69 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the custom MAIL FROM attributes for a list of identities (email addresses : domains).</p> <p>This operation is throttled at one request per second and can only get custom MAIL FROM attributes for up to 100 identities at a time.</p>
-    async fn get_identity_mail_from_domain_attributes(
+    /// Presigns the request `get_identity_dkim_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_identity_dkim_attributes(
         &self,
-        input: GetIdentityMailFromDomainAttributesRequest,
-    ) -> Result<
-        GetIdentityMailFromDomainAttributesResponse,
-        RusotoError<GetIdentityMailFromDomainAttributesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_identity_dkim_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_identity_dkim_attributes_request(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityDkimAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "GetIdentityMailFromDomainAttributes");
+        params.put("Action", "GetIdentityDkimAttributes");
         params.put("Version", "2010-12-01");
-        GetIdentityMailFromDomainAttributesRequestSerializer::serialize(&mut params, "", &input);
+        GetIdentityDkimAttributesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_identity_mail_from_domain_attributes`, also returning the metadata of the response.
+    pub async fn get_identity_mail_from_domain_attributes_with_metadata(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> Result<
+        (
+            GetIdentityMailFromDomainAttributesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetIdentityMailFromDomainAttributesError>,
+    > {
+        let request = self.build_get_identity_mail_from_domain_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetIdentityMailFromDomainAttributesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12394,39 +13660,66 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Given a list of verified identities (email addresses and/or domains), returns a structure describing identity notification attributes.</p> <p>This operation is throttled at one request per second and can only get notification attributes for up to 100 identities at a time.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
-    async fn get_identity_notification_attributes(
+    /// Presigns the request `get_identity_mail_from_domain_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_identity_mail_from_domain_attributes(
         &self,
-        input: GetIdentityNotificationAttributesRequest,
-    ) -> Result<
-        GetIdentityNotificationAttributesResponse,
-        RusotoError<GetIdentityNotificationAttributesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_identity_mail_from_domain_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_identity_mail_from_domain_attributes_request(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityMailFromDomainAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "GetIdentityNotificationAttributes");
+        params.put("Action", "GetIdentityMailFromDomainAttributes");
         params.put("Version", "2010-12-01");
-        GetIdentityNotificationAttributesRequestSerializer::serialize(&mut params, "", &input);
+        GetIdentityMailFromDomainAttributesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_identity_notification_attributes`, also returning the metadata of the response.
+    pub async fn get_identity_notification_attributes_with_metadata(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> Result<
+        (GetIdentityNotificationAttributesResponse, ResponseMetadata),
+        RusotoError<GetIdentityNotificationAttributesError>,
+    > {
+        let request = self.build_get_identity_notification_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetIdentityNotificationAttributesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12448,34 +13741,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the requested sending authorization policies for the given identity (an email address or a domain). The policies are returned as a map of policy names to policy contents. You can retrieve a maximum of 20 policies at a time.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn get_identity_policies(
+    /// Presigns the request `get_identity_notification_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_identity_notification_attributes(
         &self,
-        input: GetIdentityPoliciesRequest,
-    ) -> Result<GetIdentityPoliciesResponse, RusotoError<GetIdentityPoliciesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_identity_notification_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_identity_notification_attributes_request(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityNotificationAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "GetIdentityPolicies");
+        params.put("Action", "GetIdentityNotificationAttributes");
         params.put("Version", "2010-12-01");
-        GetIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
+        GetIdentityNotificationAttributesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_identity_policies`, also returning the metadata of the response.
+    pub async fn get_identity_policies_with_metadata(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> Result<
+        (GetIdentityPoliciesResponse, ResponseMetadata),
+        RusotoError<GetIdentityPoliciesError>,
+    > {
+        let request = self.build_get_identity_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetIdentityPoliciesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12497,39 +13820,66 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Given a list of identities (email addresses and/or domains), returns the verification status and (for domain identities) the verification token for each identity.</p> <p>The verification status of an email address is "Pending" until the email address owner clicks the link within the verification email that Amazon SES sent to that address. If the email address owner clicks the link within 24 hours, the verification status of the email address changes to "Success". If the link is not clicked within 24 hours, the verification status changes to "Failed." In that case, if you still want to verify the email address, you must restart the verification process from the beginning.</p> <p>For domain identities, the domain's verification status is "Pending" as Amazon SES searches for the required TXT record in the DNS settings of the domain. When Amazon SES detects the record, the domain's verification status changes to "Success". If Amazon SES is unable to detect the record within 72 hours, the domain's verification status changes to "Failed." In that case, if you still want to verify the domain, you must restart the verification process from the beginning.</p> <p>This operation is throttled at one request per second and can only get verification attributes for up to 100 identities at a time.</p>
-    async fn get_identity_verification_attributes(
+    /// Presigns the request `get_identity_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_identity_policies(
         &self,
-        input: GetIdentityVerificationAttributesRequest,
-    ) -> Result<
-        GetIdentityVerificationAttributesResponse,
-        RusotoError<GetIdentityVerificationAttributesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: GetIdentityPoliciesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_identity_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_identity_policies_request(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityPolicies");
         let mut params = Params::new();
 
-        params.put("Action", "GetIdentityVerificationAttributes");
+        params.put("Action", "GetIdentityPolicies");
         params.put("Version", "2010-12-01");
-        GetIdentityVerificationAttributesRequestSerializer::serialize(&mut params, "", &input);
+        GetIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `get_identity_verification_attributes`, also returning the metadata of the response.
+    pub async fn get_identity_verification_attributes_with_metadata(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> Result<
+        (GetIdentityVerificationAttributesResponse, ResponseMetadata),
+        RusotoError<GetIdentityVerificationAttributesError>,
+    > {
+        let request = self.build_get_identity_verification_attributes_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetIdentityVerificationAttributesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12551,31 +13901,60 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Provides the sending limits for the Amazon SES account. </p> <p>You can execute this operation no more than once per second.</p>
-    async fn get_send_quota(&self) -> Result<GetSendQuotaResponse, RusotoError<GetSendQuotaError>> {
+    /// Presigns the request `get_identity_verification_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_identity_verification_attributes(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_identity_verification_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_identity_verification_attributes_request(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityVerificationAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "GetSendQuota");
+        params.put("Action", "GetIdentityVerificationAttributes");
         params.put("Version", "2010-12-01");
-
+        GetIdentityVerificationAttributesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_send_quota`, also returning the metadata of the response.
+    pub async fn get_send_quota_with_metadata(
+        &self,
+    ) -> Result<(GetSendQuotaResponse, ResponseMetadata), RusotoError<GetSendQuotaError>> {
+        let request = self.build_get_send_quota_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetSendQuotaError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12595,33 +13974,57 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Provides sending statistics for the current AWS Region. The result is a list of data points, representing the last two weeks of sending activity. Each data point in the list contains statistics for a 15-minute period of time.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn get_send_statistics(
+    /// Presigns the request `get_send_quota` would send with `presigner` instead of sending it.
+    pub async fn presign_get_send_quota(
         &self,
-    ) -> Result<GetSendStatisticsResponse, RusotoError<GetSendStatisticsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_send_quota_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_send_quota_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetSendQuota");
         let mut params = Params::new();
 
-        params.put("Action", "GetSendStatistics");
+        params.put("Action", "GetSendQuota");
         params.put("Version", "2010-12-01");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_send_statistics`, also returning the metadata of the response.
+    pub async fn get_send_statistics_with_metadata(
+        &self,
+    ) -> Result<(GetSendStatisticsResponse, ResponseMetadata), RusotoError<GetSendStatisticsError>>
+    {
+        let request = self.build_get_send_statistics_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetSendStatisticsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12643,34 +14046,57 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Displays the template object (which includes the Subject line, HTML part and text part) for the template you specify.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn get_template(
+    /// Presigns the request `get_send_statistics` would send with `presigner` instead of sending it.
+    pub async fn presign_get_send_statistics(
         &self,
-        input: GetTemplateRequest,
-    ) -> Result<GetTemplateResponse, RusotoError<GetTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_send_statistics_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_send_statistics_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetSendStatistics");
         let mut params = Params::new();
 
-        params.put("Action", "GetTemplate");
+        params.put("Action", "GetSendStatistics");
         params.put("Version", "2010-12-01");
-        GetTemplateRequestSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_template`, also returning the metadata of the response.
+    pub async fn get_template_with_metadata(
+        &self,
+        input: GetTemplateRequest,
+    ) -> Result<(GetTemplateResponse, ResponseMetadata), RusotoError<GetTemplateError>> {
+        let request = self.build_get_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetTemplateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12689,34 +14115,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Provides a list of the configuration sets associated with your Amazon SES account in the current AWS Region. For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second. This operation will return up to 1,000 configuration sets each time it is run. If your Amazon SES account has more than 1,000 configuration sets, this operation will also return a NextToken element. You can then execute the <code>ListConfigurationSets</code> operation again, passing the <code>NextToken</code> parameter and the value of the NextToken element to retrieve additional results.</p>
-    async fn list_configuration_sets(
+    /// Presigns the request `get_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_template(
         &self,
-        input: ListConfigurationSetsRequest,
-    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_template_request(&self, input: GetTemplateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "ListConfigurationSets");
+        params.put("Action", "GetTemplate");
         params.put("Version", "2010-12-01");
-        ListConfigurationSetsRequestSerializer::serialize(&mut params, "", &input);
+        GetTemplateRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_configuration_sets`, also returning the metadata of the response.
+    pub async fn list_configuration_sets_with_metadata(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<
+        (ListConfigurationSetsResponse, ResponseMetadata),
+        RusotoError<ListConfigurationSetsError>,
+    > {
+        let request = self.build_list_configuration_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListConfigurationSetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12738,39 +14191,69 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists the existing custom verification email templates for your account in the current AWS Region.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn list_custom_verification_email_templates(
+    /// Presigns the request `list_configuration_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configuration_sets(
         &self,
-        input: ListCustomVerificationEmailTemplatesRequest,
-    ) -> Result<
-        ListCustomVerificationEmailTemplatesResponse,
-        RusotoError<ListCustomVerificationEmailTemplatesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configuration_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configuration_sets_request(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListConfigurationSets");
         let mut params = Params::new();
 
-        params.put("Action", "ListCustomVerificationEmailTemplates");
+        params.put("Action", "ListConfigurationSets");
         params.put("Version", "2010-12-01");
-        ListCustomVerificationEmailTemplatesRequestSerializer::serialize(&mut params, "", &input);
+        ListConfigurationSetsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_custom_verification_email_templates`, also returning the metadata of the response.
+    pub async fn list_custom_verification_email_templates_with_metadata(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> Result<
+        (
+            ListCustomVerificationEmailTemplatesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListCustomVerificationEmailTemplatesError>,
+    > {
+        let request = self.build_list_custom_verification_email_templates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListCustomVerificationEmailTemplatesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12792,34 +14275,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list containing all of the identities (email addresses and domains) for your AWS account in the current AWS Region, regardless of verification status.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn list_identities(
+    /// Presigns the request `list_custom_verification_email_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_custom_verification_email_templates(
         &self,
-        input: ListIdentitiesRequest,
-    ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_custom_verification_email_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_custom_verification_email_templates_request(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListCustomVerificationEmailTemplates");
         let mut params = Params::new();
 
-        params.put("Action", "ListIdentities");
+        params.put("Action", "ListCustomVerificationEmailTemplates");
         params.put("Version", "2010-12-01");
-        ListIdentitiesRequestSerializer::serialize(&mut params, "", &input);
+        ListCustomVerificationEmailTemplatesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_identities`, also returning the metadata of the response.
+    pub async fn list_identities_with_metadata(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> Result<(ListIdentitiesResponse, ResponseMetadata), RusotoError<ListIdentitiesError>> {
+        let request = self.build_list_identities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListIdentitiesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12841,34 +14351,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of sending authorization policies that are attached to the given identity (an email address or a domain). This API returns only a list. If you want the actual policy content, you can use <code>GetIdentityPolicies</code>.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn list_identity_policies(
+    /// Presigns the request `list_identities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_identities(
         &self,
-        input: ListIdentityPoliciesRequest,
-    ) -> Result<ListIdentityPoliciesResponse, RusotoError<ListIdentityPoliciesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListIdentitiesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_identities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_identities_request(&self, input: ListIdentitiesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListIdentities");
         let mut params = Params::new();
 
-        params.put("Action", "ListIdentityPolicies");
+        params.put("Action", "ListIdentities");
         params.put("Version", "2010-12-01");
-        ListIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
+        ListIdentitiesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_identity_policies`, also returning the metadata of the response.
+    pub async fn list_identity_policies_with_metadata(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> Result<
+        (ListIdentityPoliciesResponse, ResponseMetadata),
+        RusotoError<ListIdentityPoliciesError>,
+    > {
+        let request = self.build_list_identity_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListIdentityPoliciesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12890,34 +14427,62 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists the IP address filters associated with your AWS account in the current AWS Region.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn list_receipt_filters(
+    /// Presigns the request `list_identity_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_identity_policies(
         &self,
-        input: ListReceiptFiltersRequest,
-    ) -> Result<ListReceiptFiltersResponse, RusotoError<ListReceiptFiltersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListIdentityPoliciesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_identity_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_identity_policies_request(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListIdentityPolicies");
         let mut params = Params::new();
 
-        params.put("Action", "ListReceiptFilters");
+        params.put("Action", "ListIdentityPolicies");
         params.put("Version", "2010-12-01");
-        ListReceiptFiltersRequestSerializer::serialize(&mut params, "", &input);
+        ListIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_receipt_filters`, also returning the metadata of the response.
+    pub async fn list_receipt_filters_with_metadata(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> Result<(ListReceiptFiltersResponse, ResponseMetadata), RusotoError<ListReceiptFiltersError>>
+    {
+        let request = self.build_list_receipt_filters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListReceiptFiltersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12939,34 +14504,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists the receipt rule sets that exist under your AWS account in the current AWS Region. If there are additional receipt rule sets to be retrieved, you will receive a <code>NextToken</code> that you can provide to the next call to <code>ListReceiptRuleSets</code> to retrieve the additional entries.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn list_receipt_rule_sets(
+    /// Presigns the request `list_receipt_filters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_receipt_filters(
         &self,
-        input: ListReceiptRuleSetsRequest,
-    ) -> Result<ListReceiptRuleSetsResponse, RusotoError<ListReceiptRuleSetsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListReceiptFiltersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_receipt_filters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_receipt_filters_request(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListReceiptFilters");
         let mut params = Params::new();
 
-        params.put("Action", "ListReceiptRuleSets");
+        params.put("Action", "ListReceiptFilters");
         params.put("Version", "2010-12-01");
-        ListReceiptRuleSetsRequestSerializer::serialize(&mut params, "", &input);
+        ListReceiptFiltersRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_receipt_rule_sets`, also returning the metadata of the response.
+    pub async fn list_receipt_rule_sets_with_metadata(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> Result<
+        (ListReceiptRuleSetsResponse, ResponseMetadata),
+        RusotoError<ListReceiptRuleSetsError>,
+    > {
+        let request = self.build_list_receipt_rule_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListReceiptRuleSetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12988,34 +14583,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists the email templates present in your Amazon SES account in the current AWS Region.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn list_templates(
+    /// Presigns the request `list_receipt_rule_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_receipt_rule_sets(
         &self,
-        input: ListTemplatesRequest,
-    ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListReceiptRuleSetsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_receipt_rule_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_receipt_rule_sets_request(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListReceiptRuleSets");
         let mut params = Params::new();
 
-        params.put("Action", "ListTemplates");
+        params.put("Action", "ListReceiptRuleSets");
         params.put("Version", "2010-12-01");
-        ListTemplatesRequestSerializer::serialize(&mut params, "", &input);
+        ListReceiptRuleSetsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_templates`, also returning the metadata of the response.
+    pub async fn list_templates_with_metadata(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> Result<(ListTemplatesResponse, ResponseMetadata), RusotoError<ListTemplatesError>> {
+        let request = self.build_list_templates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTemplatesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13035,34 +14657,60 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deprecated. Use the <code>ListIdentities</code> operation to list the email addresses and domains associated with your account.</p>
-    async fn list_verified_email_addresses(
+    /// Presigns the request `list_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_templates(
         &self,
-    ) -> Result<ListVerifiedEmailAddressesResponse, RusotoError<ListVerifiedEmailAddressesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ListTemplatesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_templates_request(&self, input: ListTemplatesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListTemplates");
         let mut params = Params::new();
 
-        params.put("Action", "ListVerifiedEmailAddresses");
+        params.put("Action", "ListTemplates");
         params.put("Version", "2010-12-01");
-
+        ListTemplatesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_verified_email_addresses`, also returning the metadata of the response.
+    pub async fn list_verified_email_addresses_with_metadata(
+        &self,
+    ) -> Result<
+        (ListVerifiedEmailAddressesResponse, ResponseMetadata),
+        RusotoError<ListVerifiedEmailAddressesError>,
+    > {
+        let request = self.build_list_verified_email_addresses_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListVerifiedEmailAddressesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13084,39 +14732,62 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds or updates the delivery options for a configuration set.</p>
-    async fn put_configuration_set_delivery_options(
+    /// Presigns the request `list_verified_email_addresses` would send with `presigner` instead of sending it.
+    pub async fn presign_list_verified_email_addresses(
         &self,
-        input: PutConfigurationSetDeliveryOptionsRequest,
-    ) -> Result<
-        PutConfigurationSetDeliveryOptionsResponse,
-        RusotoError<PutConfigurationSetDeliveryOptionsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_verified_email_addresses_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_verified_email_addresses_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListVerifiedEmailAddresses");
         let mut params = Params::new();
 
-        params.put("Action", "PutConfigurationSetDeliveryOptions");
+        params.put("Action", "ListVerifiedEmailAddresses");
         params.put("Version", "2010-12-01");
-        PutConfigurationSetDeliveryOptionsRequestSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_configuration_set_delivery_options`, also returning the metadata of the response.
+    pub async fn put_configuration_set_delivery_options_with_metadata(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> Result<
+        (PutConfigurationSetDeliveryOptionsResponse, ResponseMetadata),
+        RusotoError<PutConfigurationSetDeliveryOptionsError>,
+    > {
+        let request = self.build_put_configuration_set_delivery_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutConfigurationSetDeliveryOptionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13138,34 +14809,62 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds or updates a sending authorization policy for the specified identity (an email address or a domain).</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn put_identity_policy(
+    /// Presigns the request `put_configuration_set_delivery_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_configuration_set_delivery_options(
         &self,
-        input: PutIdentityPolicyRequest,
-    ) -> Result<PutIdentityPolicyResponse, RusotoError<PutIdentityPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_configuration_set_delivery_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_configuration_set_delivery_options_request(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("PutConfigurationSetDeliveryOptions");
         let mut params = Params::new();
 
-        params.put("Action", "PutIdentityPolicy");
+        params.put("Action", "PutConfigurationSetDeliveryOptions");
         params.put("Version", "2010-12-01");
-        PutIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
+        PutConfigurationSetDeliveryOptionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_identity_policy`, also returning the metadata of the response.
+    pub async fn put_identity_policy_with_metadata(
+        &self,
+        input: PutIdentityPolicyRequest,
+    ) -> Result<(PutIdentityPolicyResponse, ResponseMetadata), RusotoError<PutIdentityPolicyError>>
+    {
+        let request = self.build_put_identity_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutIdentityPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13187,34 +14886,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Reorders the receipt rules within a receipt rule set.</p> <note> <p>All of the rules in the rule set must be represented in this request. That is, this API will return an error if the reorder request doesn't explicitly position all of the rules.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn reorder_receipt_rule_set(
+    /// Presigns the request `put_identity_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_identity_policy(
         &self,
-        input: ReorderReceiptRuleSetRequest,
-    ) -> Result<ReorderReceiptRuleSetResponse, RusotoError<ReorderReceiptRuleSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutIdentityPolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_identity_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_identity_policy_request(&self, input: PutIdentityPolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("PutIdentityPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "ReorderReceiptRuleSet");
+        params.put("Action", "PutIdentityPolicy");
         params.put("Version", "2010-12-01");
-        ReorderReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        PutIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reorder_receipt_rule_set`, also returning the metadata of the response.
+    pub async fn reorder_receipt_rule_set_with_metadata(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> Result<
+        (ReorderReceiptRuleSetResponse, ResponseMetadata),
+        RusotoError<ReorderReceiptRuleSetError>,
+    > {
+        let request = self.build_reorder_receipt_rule_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ReorderReceiptRuleSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13236,34 +14962,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Generates and sends a bounce message to the sender of an email you received through Amazon SES. You can only use this API on an email up to 24 hours after you receive it.</p> <note> <p>You cannot use this API to send generic bounces for mail that was not received by Amazon SES.</p> </note> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn send_bounce(
+    /// Presigns the request `reorder_receipt_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reorder_receipt_rule_set(
         &self,
-        input: SendBounceRequest,
-    ) -> Result<SendBounceResponse, RusotoError<SendBounceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reorder_receipt_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reorder_receipt_rule_set_request(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ReorderReceiptRuleSet");
         let mut params = Params::new();
 
-        params.put("Action", "SendBounce");
+        params.put("Action", "ReorderReceiptRuleSet");
         params.put("Version", "2010-12-01");
-        SendBounceRequestSerializer::serialize(&mut params, "", &input);
+        ReorderReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `send_bounce`, also returning the metadata of the response.
+    pub async fn send_bounce_with_metadata(
+        &self,
+        input: SendBounceRequest,
+    ) -> Result<(SendBounceResponse, ResponseMetadata), RusotoError<SendBounceError>> {
+        let request = self.build_send_bounce_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SendBounceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13282,34 +15035,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Composes an email message to multiple destinations. The message body is created using an email template.</p> <p>In order to send email using the <code>SendBulkTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Each <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendBulkTemplatedEmail</code> operation several times to send the message to each group.</p> </li> <li> <p>The number of destinations you can contact in a single call to the API may be limited by your account&#39;s maximum sending rate.</p> </li> </ul></p>
-    async fn send_bulk_templated_email(
+    /// Presigns the request `send_bounce` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_bounce(
         &self,
-        input: SendBulkTemplatedEmailRequest,
-    ) -> Result<SendBulkTemplatedEmailResponse, RusotoError<SendBulkTemplatedEmailError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SendBounceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_bounce_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_bounce_request(&self, input: SendBounceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendBounce");
         let mut params = Params::new();
 
-        params.put("Action", "SendBulkTemplatedEmail");
+        params.put("Action", "SendBounce");
         params.put("Version", "2010-12-01");
-        SendBulkTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
+        SendBounceRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `send_bulk_templated_email`, also returning the metadata of the response.
+    pub async fn send_bulk_templated_email_with_metadata(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> Result<
+        (SendBulkTemplatedEmailResponse, ResponseMetadata),
+        RusotoError<SendBulkTemplatedEmailError>,
+    > {
+        let request = self.build_send_bulk_templated_email_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SendBulkTemplatedEmailError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13331,35 +15111,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. As a result of executing this operation, a customized verification email is sent to the specified address.</p> <p>To use this operation, you must first create a custom verification email template. For more information about creating and using custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn send_custom_verification_email(
+    /// Presigns the request `send_bulk_templated_email` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_bulk_templated_email(
         &self,
-        input: SendCustomVerificationEmailRequest,
-    ) -> Result<SendCustomVerificationEmailResponse, RusotoError<SendCustomVerificationEmailError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_bulk_templated_email_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_bulk_templated_email_request(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendBulkTemplatedEmail");
         let mut params = Params::new();
 
-        params.put("Action", "SendCustomVerificationEmail");
+        params.put("Action", "SendBulkTemplatedEmail");
         params.put("Version", "2010-12-01");
-        SendCustomVerificationEmailRequestSerializer::serialize(&mut params, "", &input);
+        SendBulkTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `send_custom_verification_email`, also returning the metadata of the response.
+    pub async fn send_custom_verification_email_with_metadata(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> Result<
+        (SendCustomVerificationEmailResponse, ResponseMetadata),
+        RusotoError<SendCustomVerificationEmailError>,
+    > {
+        let request = self.build_send_custom_verification_email_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SendCustomVerificationEmailError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13381,34 +15190,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Composes an email message and immediately queues it for sending. In order to send email using the <code>SendEmail</code> operation, your message must meet the following requirements:</p> <ul> <li> <p>The message must be sent from a verified email address or domain. If you attempt to send email using a non-verified address or domain, the operation will result in an &quot;Email address not verified&quot; error. </p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>The message must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendEmail</code> operation several times to send the message to each group.</p> </li> </ul> <important> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </important></p>
-    async fn send_email(
+    /// Presigns the request `send_custom_verification_email` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_custom_verification_email(
         &self,
-        input: SendEmailRequest,
-    ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SendCustomVerificationEmailRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_custom_verification_email_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_custom_verification_email_request(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendCustomVerificationEmail");
         let mut params = Params::new();
 
-        params.put("Action", "SendEmail");
+        params.put("Action", "SendCustomVerificationEmail");
         params.put("Version", "2010-12-01");
-        SendEmailRequestSerializer::serialize(&mut params, "", &input);
+        SendCustomVerificationEmailRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `send_email`, also returning the metadata of the response.
+    pub async fn send_email_with_metadata(
+        &self,
+        input: SendEmailRequest,
+    ) -> Result<(SendEmailResponse, ResponseMetadata), RusotoError<SendEmailError>> {
+        let request = self.build_send_email_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SendEmailError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13427,34 +15263,58 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Composes an email message and immediately queues it for sending.</p> <p>This operation is more flexible than the <code>SendEmail</code> API operation. When you use the <code>SendRawEmail</code> operation, you can specify the headers of the message as well as its content. This flexibility is useful, for example, when you want to send a multipart MIME email (such a message that contains both a text and an HTML version). You can also use this operation to send messages that include attachments.</p> <p>The <code>SendRawEmail</code> operation has the following requirements:</p> <ul> <li> <p>You can only send email from <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">verified email addresses or domains</a>. If you try to send email from an address that isn&#39;t verified, the operation results in an &quot;Email address not verified&quot; error.</p> </li> <li> <p>If your account is still in the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/request-production-access.html">Amazon SES sandbox</a>, you can only send email to other verified addresses in your account, or to addresses that are associated with the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mailbox-simulator.html">Amazon SES mailbox simulator</a>.</p> </li> <li> <p>The maximum message size, including attachments, is 10 MB.</p> </li> <li> <p>Each message has to include at least one recipient address. A recipient address includes any address on the To:, CC:, or BCC: lines.</p> </li> <li> <p>If you send a single message to more than one recipient address, and one of the recipient addresses isn&#39;t in a valid format (that is, it&#39;s not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), Amazon SES rejects the entire message, even if the other addresses are valid.</p> </li> <li> <p>Each message can include up to 50 recipient addresses across the To:, CC:, or BCC: lines. If you need to send a single message to more than 50 recipients, you have to split the list of recipient addresses into groups of less than 50 recipients, and send separate messages to each group.</p> </li> <li> <p>Amazon SES allows you to specify 8-bit Content-Transfer-Encoding for MIME message parts. However, if Amazon SES has to modify the contents of your message (for example, if you use open and click tracking), 8-bit content isn&#39;t preserved. For this reason, we highly recommend that you encode all content that isn&#39;t 7-bit ASCII. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-email-raw.html#send-email-mime-encoding">MIME Encoding</a> in the <i>Amazon SES Developer Guide</i>.</p> </li> </ul> <p>Additionally, keep the following considerations in mind when using the <code>SendRawEmail</code> operation:</p> <ul> <li> <p>Although you can customize the message headers when using the <code>SendRawEmail</code> operation, Amazon SES will automatically apply its own <code>Message-ID</code> and <code>Date</code> headers; if you passed these headers when creating the message, they will be overwritten by the values that Amazon SES provides.</p> </li> <li> <p>If you are using sending authorization to send on behalf of another user, <code>SendRawEmail</code> enables you to specify the cross-account identity for the email&#39;s Source, From, and Return-Path parameters in one of two ways: you can pass optional parameters <code>SourceArn</code>, <code>FromArn</code>, and/or <code>ReturnPathArn</code> to the API, or you can include the following X-headers in the header of your raw email:</p> <ul> <li> <p> <code>X-SES-SOURCE-ARN</code> </p> </li> <li> <p> <code>X-SES-FROM-ARN</code> </p> </li> <li> <p> <code>X-SES-RETURN-PATH-ARN</code> </p> </li> </ul> <important> <p>Don&#39;t include these X-headers in the DKIM signature. Amazon SES removes these before it sends the email.</p> </important> <p>If you only specify the <code>SourceIdentityArn</code> parameter, Amazon SES sets the From and Return-Path addresses to the same identity that you specified.</p> <p>For more information about sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Using Sending Authorization with Amazon SES</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> </ul></p>
-    async fn send_raw_email(
+    /// Presigns the request `send_email` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_email(
         &self,
-        input: SendRawEmailRequest,
-    ) -> Result<SendRawEmailResponse, RusotoError<SendRawEmailError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SendEmailRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_email_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_email_request(&self, input: SendEmailRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendEmail");
         let mut params = Params::new();
 
-        params.put("Action", "SendRawEmail");
+        params.put("Action", "SendEmail");
         params.put("Version", "2010-12-01");
-        SendRawEmailRequestSerializer::serialize(&mut params, "", &input);
+        SendEmailRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `send_raw_email`, also returning the metadata of the response.
+    pub async fn send_raw_email_with_metadata(
+        &self,
+        input: SendRawEmailRequest,
+    ) -> Result<(SendRawEmailResponse, ResponseMetadata), RusotoError<SendRawEmailError>> {
+        let request = self.build_send_raw_email_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SendRawEmailError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13474,34 +15334,59 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Composes an email message using an email template and immediately queues it for sending.</p> <p>In order to send email using the <code>SendTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Calls to the <code>SendTemplatedEmail</code> operation may only include one <code>Destination</code> parameter. A destination is a set of recipients who will receive the same version of the email. The <code>Destination</code> parameter can include up to 50 recipients, across the To:, CC: and BCC: fields.</p> </li> <li> <p>The <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> </ul> <important> <p>If your call to the <code>SendTemplatedEmail</code> operation includes all of the required parameters, Amazon SES accepts it and returns a Message ID. However, if Amazon SES can&#39;t render the email because the template contains errors, it doesn&#39;t send the email. Additionally, because it already accepted the message, Amazon SES doesn&#39;t return a message stating that it was unable to send the email.</p> <p>For these reasons, we highly recommend that you set up Amazon SES to send you notifications when Rendering Failure events occur. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Sending Personalized Email Using the Amazon SES API</a> in the <i>Amazon Simple Email Service Developer Guide</i>.</p> </important></p>
-    async fn send_templated_email(
+    /// Presigns the request `send_raw_email` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_raw_email(
         &self,
-        input: SendTemplatedEmailRequest,
-    ) -> Result<SendTemplatedEmailResponse, RusotoError<SendTemplatedEmailError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SendRawEmailRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_raw_email_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_raw_email_request(&self, input: SendRawEmailRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendRawEmail");
         let mut params = Params::new();
 
-        params.put("Action", "SendTemplatedEmail");
+        params.put("Action", "SendRawEmail");
         params.put("Version", "2010-12-01");
-        SendTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
+        SendRawEmailRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `send_templated_email`, also returning the metadata of the response.
+    pub async fn send_templated_email_with_metadata(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> Result<(SendTemplatedEmailResponse, ResponseMetadata), RusotoError<SendTemplatedEmailError>>
+    {
+        let request = self.build_send_templated_email_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SendTemplatedEmailError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13523,34 +15408,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sets the specified receipt rule set as the active receipt rule set.</p> <note> <p>To disable your email-receiving through Amazon SES completely, you can call this API with RuleSetName set to null.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn set_active_receipt_rule_set(
+    /// Presigns the request `send_templated_email` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_templated_email(
         &self,
-        input: SetActiveReceiptRuleSetRequest,
-    ) -> Result<SetActiveReceiptRuleSetResponse, RusotoError<SetActiveReceiptRuleSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SendTemplatedEmailRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_templated_email_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_templated_email_request(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendTemplatedEmail");
         let mut params = Params::new();
 
-        params.put("Action", "SetActiveReceiptRuleSet");
+        params.put("Action", "SendTemplatedEmail");
         params.put("Version", "2010-12-01");
-        SetActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        SendTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_active_receipt_rule_set`, also returning the metadata of the response.
+    pub async fn set_active_receipt_rule_set_with_metadata(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> Result<
+        (SetActiveReceiptRuleSetResponse, ResponseMetadata),
+        RusotoError<SetActiveReceiptRuleSetError>,
+    > {
+        let request = self.build_set_active_receipt_rule_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetActiveReceiptRuleSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13572,34 +15487,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Enables or disables Easy DKIM signing of email sent from an identity. If Easy DKIM signing is enabled for a domain, then Amazon SES uses DKIM to sign all email that it sends from addresses on that domain. If Easy DKIM signing is enabled for an email address, then Amazon SES uses DKIM to sign all email it sends from that address.</p> <note> <p>For email addresses (for example, <code>user@example.com</code>), you can only enable DKIM signing if the corresponding domain (in this case, <code>example.com</code>) has been set up to use Easy DKIM.</p> </note> <p>You can enable DKIM signing for an identity at any time after you start the verification process for the identity, even if the verification process isn't complete. </p> <p>You can execute this operation no more than once per second.</p> <p>For more information about Easy DKIM signing, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
-    async fn set_identity_dkim_enabled(
+    /// Presigns the request `set_active_receipt_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_active_receipt_rule_set(
         &self,
-        input: SetIdentityDkimEnabledRequest,
-    ) -> Result<SetIdentityDkimEnabledResponse, RusotoError<SetIdentityDkimEnabledError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_active_receipt_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_active_receipt_rule_set_request(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetActiveReceiptRuleSet");
         let mut params = Params::new();
 
-        params.put("Action", "SetIdentityDkimEnabled");
+        params.put("Action", "SetActiveReceiptRuleSet");
         params.put("Version", "2010-12-01");
-        SetIdentityDkimEnabledRequestSerializer::serialize(&mut params, "", &input);
+        SetActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_identity_dkim_enabled`, also returning the metadata of the response.
+    pub async fn set_identity_dkim_enabled_with_metadata(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> Result<
+        (SetIdentityDkimEnabledResponse, ResponseMetadata),
+        RusotoError<SetIdentityDkimEnabledError>,
+    > {
+        let request = self.build_set_identity_dkim_enabled_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetIdentityDkimEnabledError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13621,39 +15566,69 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Given an identity (an email address or a domain), enables or disables whether Amazon SES forwards bounce and complaint notifications as email. Feedback forwarding can only be disabled when Amazon Simple Notification Service (Amazon SNS) topics are specified for both bounces and complaints.</p> <note> <p>Feedback forwarding does not apply to delivery notifications. Delivery notifications are only available through Amazon SNS.</p> </note> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
-    async fn set_identity_feedback_forwarding_enabled(
+    /// Presigns the request `set_identity_dkim_enabled` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_identity_dkim_enabled(
         &self,
-        input: SetIdentityFeedbackForwardingEnabledRequest,
-    ) -> Result<
-        SetIdentityFeedbackForwardingEnabledResponse,
-        RusotoError<SetIdentityFeedbackForwardingEnabledError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_identity_dkim_enabled_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_identity_dkim_enabled_request(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityDkimEnabled");
         let mut params = Params::new();
 
-        params.put("Action", "SetIdentityFeedbackForwardingEnabled");
+        params.put("Action", "SetIdentityDkimEnabled");
         params.put("Version", "2010-12-01");
-        SetIdentityFeedbackForwardingEnabledRequestSerializer::serialize(&mut params, "", &input);
+        SetIdentityDkimEnabledRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_identity_feedback_forwarding_enabled`, also returning the metadata of the response.
+    pub async fn set_identity_feedback_forwarding_enabled_with_metadata(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> Result<
+        (
+            SetIdentityFeedbackForwardingEnabledResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<SetIdentityFeedbackForwardingEnabledError>,
+    > {
+        let request = self.build_set_identity_feedback_forwarding_enabled_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetIdentityFeedbackForwardingEnabledError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13675,42 +15650,68 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Given an identity (an email address or a domain), sets whether Amazon SES includes the original email headers in the Amazon Simple Notification Service (Amazon SNS) notifications of a specified type.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
-    async fn set_identity_headers_in_notifications_enabled(
+    /// Presigns the request `set_identity_feedback_forwarding_enabled` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_identity_feedback_forwarding_enabled(
         &self,
-        input: SetIdentityHeadersInNotificationsEnabledRequest,
-    ) -> Result<
-        SetIdentityHeadersInNotificationsEnabledResponse,
-        RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_identity_feedback_forwarding_enabled_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_identity_feedback_forwarding_enabled_request(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityFeedbackForwardingEnabled");
         let mut params = Params::new();
 
-        params.put("Action", "SetIdentityHeadersInNotificationsEnabled");
+        params.put("Action", "SetIdentityFeedbackForwardingEnabled");
         params.put("Version", "2010-12-01");
-        SetIdentityHeadersInNotificationsEnabledRequestSerializer::serialize(
-            &mut params,
-            "",
-            &input,
-        );
+        SetIdentityFeedbackForwardingEnabledRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_identity_headers_in_notifications_enabled`, also returning the metadata of the response.
+    pub async fn set_identity_headers_in_notifications_enabled_with_metadata(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> Result<
+        (
+            SetIdentityHeadersInNotificationsEnabledResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
+    > {
+        let request = self.build_set_identity_headers_in_notifications_enabled_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetIdentityHeadersInNotificationsEnabledError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-        let result;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
+        let result;
 
         if xml_response.body.is_empty() {
             result = SetIdentityHeadersInNotificationsEnabledResponse::default();
@@ -13731,35 +15732,68 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Enables or disables the custom MAIL FROM domain setup for a verified identity (an email address or a domain).</p> <important> <p>To send emails using the specified MAIL FROM domain, you must add an MX record to your MAIL FROM domain's DNS settings. If you want your emails to pass Sender Policy Framework (SPF) checks, you must also add or update an SPF record. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from-set.html">Amazon SES Developer Guide</a>.</p> </important> <p>You can execute this operation no more than once per second.</p>
-    async fn set_identity_mail_from_domain(
+    /// Presigns the request `set_identity_headers_in_notifications_enabled` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_identity_headers_in_notifications_enabled(
         &self,
-        input: SetIdentityMailFromDomainRequest,
-    ) -> Result<SetIdentityMailFromDomainResponse, RusotoError<SetIdentityMailFromDomainError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_identity_headers_in_notifications_enabled_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_identity_headers_in_notifications_enabled_request(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityHeadersInNotificationsEnabled");
         let mut params = Params::new();
 
-        params.put("Action", "SetIdentityMailFromDomain");
+        params.put("Action", "SetIdentityHeadersInNotificationsEnabled");
         params.put("Version", "2010-12-01");
-        SetIdentityMailFromDomainRequestSerializer::serialize(&mut params, "", &input);
+        SetIdentityHeadersInNotificationsEnabledRequestSerializer::serialize(
+            &mut params,
+            "",
+            &input,
+        );
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_identity_mail_from_domain`, also returning the metadata of the response.
+    pub async fn set_identity_mail_from_domain_with_metadata(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> Result<
+        (SetIdentityMailFromDomainResponse, ResponseMetadata),
+        RusotoError<SetIdentityMailFromDomainError>,
+    > {
+        let request = self.build_set_identity_mail_from_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetIdentityMailFromDomainError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13781,35 +15815,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sets an Amazon Simple Notification Service (Amazon SNS) topic to use when delivering notifications. When you use this operation, you specify a verified identity, such as an email address or domain. When you send an email that uses the chosen identity in the Source field, Amazon SES sends notifications to the topic you specified. You can send bounce, complaint, or delivery notifications (or any combination of the three) to the Amazon SNS topic that you specify.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about feedback notification, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
-    async fn set_identity_notification_topic(
+    /// Presigns the request `set_identity_mail_from_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_identity_mail_from_domain(
         &self,
-        input: SetIdentityNotificationTopicRequest,
-    ) -> Result<SetIdentityNotificationTopicResponse, RusotoError<SetIdentityNotificationTopicError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_identity_mail_from_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_identity_mail_from_domain_request(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityMailFromDomain");
         let mut params = Params::new();
 
-        params.put("Action", "SetIdentityNotificationTopic");
+        params.put("Action", "SetIdentityMailFromDomain");
         params.put("Version", "2010-12-01");
-        SetIdentityNotificationTopicRequestSerializer::serialize(&mut params, "", &input);
+        SetIdentityMailFromDomainRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_identity_notification_topic`, also returning the metadata of the response.
+    pub async fn set_identity_notification_topic_with_metadata(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> Result<
+        (SetIdentityNotificationTopicResponse, ResponseMetadata),
+        RusotoError<SetIdentityNotificationTopicError>,
+    > {
+        let request = self.build_set_identity_notification_topic_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetIdentityNotificationTopicError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13831,34 +15894,64 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sets the position of the specified receipt rule in the receipt rule set.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn set_receipt_rule_position(
+    /// Presigns the request `set_identity_notification_topic` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_identity_notification_topic(
         &self,
-        input: SetReceiptRulePositionRequest,
-    ) -> Result<SetReceiptRulePositionResponse, RusotoError<SetReceiptRulePositionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_identity_notification_topic_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_identity_notification_topic_request(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityNotificationTopic");
         let mut params = Params::new();
 
-        params.put("Action", "SetReceiptRulePosition");
+        params.put("Action", "SetIdentityNotificationTopic");
         params.put("Version", "2010-12-01");
-        SetReceiptRulePositionRequestSerializer::serialize(&mut params, "", &input);
+        SetIdentityNotificationTopicRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_receipt_rule_position`, also returning the metadata of the response.
+    pub async fn set_receipt_rule_position_with_metadata(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> Result<
+        (SetReceiptRulePositionResponse, ResponseMetadata),
+        RusotoError<SetReceiptRulePositionError>,
+    > {
+        let request = self.build_set_receipt_rule_position_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetReceiptRulePositionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13880,34 +15973,62 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a preview of the MIME content of an email when provided with a template and a set of replacement data.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn test_render_template(
+    /// Presigns the request `set_receipt_rule_position` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_receipt_rule_position(
         &self,
-        input: TestRenderTemplateRequest,
-    ) -> Result<TestRenderTemplateResponse, RusotoError<TestRenderTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetReceiptRulePositionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_receipt_rule_position_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_receipt_rule_position_request(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetReceiptRulePosition");
         let mut params = Params::new();
 
-        params.put("Action", "TestRenderTemplate");
+        params.put("Action", "SetReceiptRulePosition");
         params.put("Version", "2010-12-01");
-        TestRenderTemplateRequestSerializer::serialize(&mut params, "", &input);
+        SetReceiptRulePositionRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `test_render_template`, also returning the metadata of the response.
+    pub async fn test_render_template_with_metadata(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> Result<(TestRenderTemplateResponse, ResponseMetadata), RusotoError<TestRenderTemplateError>>
+    {
+        let request = self.build_test_render_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(TestRenderTemplateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13929,67 +16050,121 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Enables or disables email sending across your entire Amazon SES account in the current AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending across your Amazon SES account in a given AWS Region when reputation metrics (such as your bounce or complaint rates) reach certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn update_account_sending_enabled(
+    /// Presigns the request `test_render_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_render_template(
         &self,
-        input: UpdateAccountSendingEnabledRequest,
-    ) -> Result<(), RusotoError<UpdateAccountSendingEnabledError>> {
+        presigner: &rusoto_core::Presigner,
+        input: TestRenderTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_render_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_render_template_request(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("TestRenderTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateAccountSendingEnabled");
+        params.put("Action", "TestRenderTemplate");
         params.put("Version", "2010-12-01");
-        UpdateAccountSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
+        TestRenderTemplateRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_account_sending_enabled`, also returning the metadata of the response.
+    pub async fn update_account_sending_enabled_with_metadata(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateAccountSendingEnabledError>> {
+        let request = self.build_update_account_sending_enabled_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateAccountSendingEnabledError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Updates the event destination of a configuration set. Event destinations are associated with configuration sets, which enable you to publish email sending events to Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS). For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>You can execute this operation no more than once per second.</p>
-    async fn update_configuration_set_event_destination(
+    /// Presigns the request `update_account_sending_enabled` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_account_sending_enabled(
         &self,
-        input: UpdateConfigurationSetEventDestinationRequest,
-    ) -> Result<
-        UpdateConfigurationSetEventDestinationResponse,
-        RusotoError<UpdateConfigurationSetEventDestinationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_account_sending_enabled_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_account_sending_enabled_request(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateAccountSendingEnabled");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateConfigurationSetEventDestination");
+        params.put("Action", "UpdateAccountSendingEnabled");
         params.put("Version", "2010-12-01");
-        UpdateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
+        UpdateAccountSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_configuration_set_event_destination`, also returning the metadata of the response.
+    pub async fn update_configuration_set_event_destination_with_metadata(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        (
+            UpdateConfigurationSetEventDestinationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        let request = self.build_update_configuration_set_event_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateConfigurationSetEventDestinationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14011,103 +16186,185 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Enables or disables the publishing of reputation metrics for emails sent using a specific configuration set in a given AWS Region. Reputation metrics include bounce and complaint rates. These metrics are published to Amazon CloudWatch. By using CloudWatch, you can create alarms when bounce or complaint rates exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn update_configuration_set_reputation_metrics_enabled(
+    /// Presigns the request `update_configuration_set_event_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_configuration_set_event_destination(
         &self,
-        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
-    ) -> Result<(), RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_configuration_set_event_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_configuration_set_event_destination_request(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetEventDestination");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateConfigurationSetReputationMetricsEnabled");
+        params.put("Action", "UpdateConfigurationSetEventDestination");
         params.put("Version", "2010-12-01");
-        UpdateConfigurationSetReputationMetricsEnabledRequestSerializer::serialize(
-            &mut params,
-            "",
-            &input,
-        );
+        UpdateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_configuration_set_reputation_metrics_enabled`, also returning the metadata of the response.
+    pub async fn update_configuration_set_reputation_metrics_enabled_with_metadata(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> Result<
+        ((), ResponseMetadata),
+        RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>,
+    > {
+        let request = self.build_update_configuration_set_reputation_metrics_enabled_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(
                 UpdateConfigurationSetReputationMetricsEnabledError::from_response(response),
             );
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Enables or disables email sending for messages sent using a specific configuration set in a given AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending for a configuration set when the reputation metrics for that configuration set (such as your bounce on complaint rate) exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn update_configuration_set_sending_enabled(
+    /// Presigns the request `update_configuration_set_reputation_metrics_enabled` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_configuration_set_reputation_metrics_enabled(
         &self,
-        input: UpdateConfigurationSetSendingEnabledRequest,
-    ) -> Result<(), RusotoError<UpdateConfigurationSetSendingEnabledError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_configuration_set_reputation_metrics_enabled_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_configuration_set_reputation_metrics_enabled_request(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetReputationMetricsEnabled");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateConfigurationSetSendingEnabled");
+        params.put("Action", "UpdateConfigurationSetReputationMetricsEnabled");
         params.put("Version", "2010-12-01");
-        UpdateConfigurationSetSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
+        UpdateConfigurationSetReputationMetricsEnabledRequestSerializer::serialize(
+            &mut params,
+            "",
+            &input,
+        );
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_configuration_set_sending_enabled`, also returning the metadata of the response.
+    pub async fn update_configuration_set_sending_enabled_with_metadata(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateConfigurationSetSendingEnabledError>>
+    {
+        let request = self.build_update_configuration_set_sending_enabled_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateConfigurationSetSendingEnabledError::from_response(
                 response,
             ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Modifies an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
-    async fn update_configuration_set_tracking_options(
+    /// Presigns the request `update_configuration_set_sending_enabled` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_configuration_set_sending_enabled(
         &self,
-        input: UpdateConfigurationSetTrackingOptionsRequest,
-    ) -> Result<
-        UpdateConfigurationSetTrackingOptionsResponse,
-        RusotoError<UpdateConfigurationSetTrackingOptionsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_configuration_set_sending_enabled_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_configuration_set_sending_enabled_request(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetSendingEnabled");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateConfigurationSetTrackingOptions");
+        params.put("Action", "UpdateConfigurationSetSendingEnabled");
         params.put("Version", "2010-12-01");
-        UpdateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
+        UpdateConfigurationSetSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_configuration_set_tracking_options`, also returning the metadata of the response.
+    pub async fn update_configuration_set_tracking_options_with_metadata(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        (
+            UpdateConfigurationSetTrackingOptionsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateConfigurationSetTrackingOptionsError>,
+    > {
+        let request = self.build_update_configuration_set_tracking_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateConfigurationSetTrackingOptionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14129,64 +16386,117 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates an existing custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn update_custom_verification_email_template(
+    /// Presigns the request `update_configuration_set_tracking_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_configuration_set_tracking_options(
         &self,
-        input: UpdateCustomVerificationEmailTemplateRequest,
-    ) -> Result<(), RusotoError<UpdateCustomVerificationEmailTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_configuration_set_tracking_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_configuration_set_tracking_options_request(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetTrackingOptions");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateCustomVerificationEmailTemplate");
+        params.put("Action", "UpdateConfigurationSetTrackingOptions");
         params.put("Version", "2010-12-01");
-        UpdateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
+        UpdateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_custom_verification_email_template`, also returning the metadata of the response.
+    pub async fn update_custom_verification_email_template_with_metadata(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateCustomVerificationEmailTemplateError>>
+    {
+        let request = self.build_update_custom_verification_email_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateCustomVerificationEmailTemplateError::from_response(
                 response,
             ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Updates a receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn update_receipt_rule(
+    /// Presigns the request `update_custom_verification_email_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_custom_verification_email_template(
         &self,
-        input: UpdateReceiptRuleRequest,
-    ) -> Result<UpdateReceiptRuleResponse, RusotoError<UpdateReceiptRuleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_custom_verification_email_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_custom_verification_email_template_request(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateCustomVerificationEmailTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateReceiptRule");
+        params.put("Action", "UpdateCustomVerificationEmailTemplate");
         params.put("Version", "2010-12-01");
-        UpdateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
+        UpdateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_receipt_rule`, also returning the metadata of the response.
+    pub async fn update_receipt_rule_with_metadata(
+        &self,
+        input: UpdateReceiptRuleRequest,
+    ) -> Result<(UpdateReceiptRuleResponse, ResponseMetadata), RusotoError<UpdateReceiptRuleError>>
+    {
+        let request = self.build_update_receipt_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateReceiptRuleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14208,34 +16518,58 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn update_template(
+    /// Presigns the request `update_receipt_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_receipt_rule(
         &self,
-        input: UpdateTemplateRequest,
-    ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateReceiptRuleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_receipt_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_receipt_rule_request(&self, input: UpdateReceiptRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateReceiptRule");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateTemplate");
+        params.put("Action", "UpdateReceiptRule");
         params.put("Version", "2010-12-01");
-        UpdateTemplateRequestSerializer::serialize(&mut params, "", &input);
+        UpdateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_template`, also returning the metadata of the response.
+    pub async fn update_template_with_metadata(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> Result<(UpdateTemplateResponse, ResponseMetadata), RusotoError<UpdateTemplateError>> {
+        let request = self.build_update_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateTemplateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14257,34 +16591,59 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a set of DKIM tokens for a domain identity.</p> <important> <p>When you execute the <code>VerifyDomainDkim</code> operation, the domain that you specify is added to the list of identities that are associated with your account. This is true even if you haven't already associated the domain with your account by using the <code>VerifyDomainIdentity</code> operation. However, you can't send email from the domain until you either successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-domains.html">verify it</a> or you successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">set up DKIM for it</a>.</p> </important> <p>You use the tokens that are generated by this operation to create CNAME records. When Amazon SES detects that you've added these records to the DNS configuration for a domain, you can start sending email from that domain. You can start sending email even if you haven't added the TXT record provided by the VerifyDomainIdentity operation to the DNS configuration for your domain. All email that you send from the domain is authenticated using DKIM.</p> <p>To create the CNAME records for DKIM authentication, use the following values:</p> <ul> <li> <p> <b>Name</b>: <i>token</i>._domainkey.<i>example.com</i> </p> </li> <li> <p> <b>Type</b>: CNAME</p> </li> <li> <p> <b>Value</b>: <i>token</i>.dkim.amazonses.com</p> </li> </ul> <p>In the preceding example, replace <i>token</i> with one of the tokens that are generated when you execute this operation. Replace <i>example.com</i> with your domain. Repeat this process for each token that's generated by this operation.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn verify_domain_dkim(
+    /// Presigns the request `update_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_template(
         &self,
-        input: VerifyDomainDkimRequest,
-    ) -> Result<VerifyDomainDkimResponse, RusotoError<VerifyDomainDkimError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateTemplateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_template_request(&self, input: UpdateTemplateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "VerifyDomainDkim");
+        params.put("Action", "UpdateTemplate");
         params.put("Version", "2010-12-01");
-        VerifyDomainDkimRequestSerializer::serialize(&mut params, "", &input);
+        UpdateTemplateRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `verify_domain_dkim`, also returning the metadata of the response.
+    pub async fn verify_domain_dkim_with_metadata(
+        &self,
+        input: VerifyDomainDkimRequest,
+    ) -> Result<(VerifyDomainDkimResponse, ResponseMetadata), RusotoError<VerifyDomainDkimError>>
+    {
+        let request = self.build_verify_domain_dkim_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(VerifyDomainDkimError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14306,34 +16665,61 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds a domain to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. For more information about verifying domains, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second.</p>
-    async fn verify_domain_identity(
+    /// Presigns the request `verify_domain_dkim` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_verify_domain_dkim(
         &self,
-        input: VerifyDomainIdentityRequest,
-    ) -> Result<VerifyDomainIdentityResponse, RusotoError<VerifyDomainIdentityError>> {
+        presigner: &rusoto_core::Presigner,
+        input: VerifyDomainDkimRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_verify_domain_dkim_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_verify_domain_dkim_request(&self, input: VerifyDomainDkimRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyDomainDkim");
         let mut params = Params::new();
 
-        params.put("Action", "VerifyDomainIdentity");
+        params.put("Action", "VerifyDomainDkim");
         params.put("Version", "2010-12-01");
-        VerifyDomainIdentityRequestSerializer::serialize(&mut params, "", &input);
+        VerifyDomainDkimRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `verify_domain_identity`, also returning the metadata of the response.
+    pub async fn verify_domain_identity_with_metadata(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> Result<
+        (VerifyDomainIdentityResponse, ResponseMetadata),
+        RusotoError<VerifyDomainIdentityError>,
+    > {
+        let request = self.build_verify_domain_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(VerifyDomainIdentityError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14355,62 +16741,116 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deprecated. Use the <code>VerifyEmailIdentity</code> operation to verify a new email address.</p>
-    async fn verify_email_address(
+    /// Presigns the request `verify_domain_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_verify_domain_identity(
         &self,
-        input: VerifyEmailAddressRequest,
-    ) -> Result<(), RusotoError<VerifyEmailAddressError>> {
+        presigner: &rusoto_core::Presigner,
+        input: VerifyDomainIdentityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_verify_domain_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_verify_domain_identity_request(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyDomainIdentity");
         let mut params = Params::new();
 
-        params.put("Action", "VerifyEmailAddress");
+        params.put("Action", "VerifyDomainIdentity");
         params.put("Version", "2010-12-01");
-        VerifyEmailAddressRequestSerializer::serialize(&mut params, "", &input);
+        VerifyDomainIdentityRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `verify_email_address`, also returning the metadata of the response.
+    pub async fn verify_email_address_with_metadata(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<VerifyEmailAddressError>> {
+        let request = self.build_verify_email_address_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(VerifyEmailAddressError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS region and attempts to verify it. As a result of executing this operation, a verification email is sent to the specified address.</p> <p>You can execute this operation no more than once per second.</p>
-    async fn verify_email_identity(
+    /// Presigns the request `verify_email_address` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_verify_email_address(
         &self,
-        input: VerifyEmailIdentityRequest,
-    ) -> Result<VerifyEmailIdentityResponse, RusotoError<VerifyEmailIdentityError>> {
+        presigner: &rusoto_core::Presigner,
+        input: VerifyEmailAddressRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_verify_email_address_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_verify_email_address_request(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyEmailAddress");
         let mut params = Params::new();
 
-        params.put("Action", "VerifyEmailIdentity");
+        params.put("Action", "VerifyEmailAddress");
         params.put("Version", "2010-12-01");
-        VerifyEmailIdentityRequestSerializer::serialize(&mut params, "", &input);
+        VerifyEmailAddressRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `verify_email_identity`, also returning the metadata of the response.
+    pub async fn verify_email_identity_with_metadata(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> Result<
+        (VerifyEmailIdentityResponse, ResponseMetadata),
+        RusotoError<VerifyEmailIdentityError>,
+    > {
+        let request = self.build_verify_email_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(VerifyEmailIdentityError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14432,7 +16872,980 @@ impl Ses for SesClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `verify_email_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_verify_email_identity(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: VerifyEmailIdentityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_verify_email_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_verify_email_identity_request(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyEmailIdentity");
+        let mut params = Params::new();
+
+        params.put("Action", "VerifyEmailIdentity");
+        params.put("Version", "2010-12-01");
+        VerifyEmailIdentityRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `ListConfigurationSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_configuration_sets_pages(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input| async move { client.list_configuration_sets(input).await },
+            |input: &ListConfigurationSetsRequest, output: &ListConfigurationSetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListConfigurationSetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConfigurationSets` of every page of `ListConfigurationSets` results for `input`, as `list_configuration_sets_pages` requests them.
+    pub fn list_configuration_sets_stream(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ConfigurationSet, RusotoError<ListConfigurationSetsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_configuration_sets_pages(input), |page| {
+            page.configuration_sets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCustomVerificationEmailTemplates` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_custom_verification_email_templates_pages(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListCustomVerificationEmailTemplatesResponse,
+            RusotoError<ListCustomVerificationEmailTemplatesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input| async move {
+                client.list_custom_verification_email_templates(input).await
+            },
+            |input: &ListCustomVerificationEmailTemplatesRequest,
+             output: &ListCustomVerificationEmailTemplatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCustomVerificationEmailTemplatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CustomVerificationEmailTemplates` of every page of `ListCustomVerificationEmailTemplates` results for `input`, as `list_custom_verification_email_templates_pages` requests them.
+    pub fn list_custom_verification_email_templates_stream(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            CustomVerificationEmailTemplate,
+            RusotoError<ListCustomVerificationEmailTemplatesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_custom_verification_email_templates_pages(input),
+            |page| page.custom_verification_email_templates.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListIdentities` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_identities_pages(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> impl futures::Stream<Item = Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input| async move { client.list_identities(input).await },
+            |input: &ListIdentitiesRequest, output: &ListIdentitiesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIdentitiesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Identities` of every page of `ListIdentities` results for `input`, as `list_identities_pages` requests them.
+    pub fn list_identities_stream(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListIdentitiesError>>> + Send {
+        rusoto_core::pagination::items(self.list_identities_pages(input), |page| page.identities)
+    }
+
+    /// The pages of `ListReceiptRuleSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_receipt_rule_sets_pages(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListReceiptRuleSetsResponse, RusotoError<ListReceiptRuleSetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input| async move { client.list_receipt_rule_sets(input).await },
+            |input: &ListReceiptRuleSetsRequest, output: &ListReceiptRuleSetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListReceiptRuleSetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RuleSets` of every page of `ListReceiptRuleSets` results for `input`, as `list_receipt_rule_sets_pages` requests them.
+    pub fn list_receipt_rule_sets_stream(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ReceiptRuleSetMetadata, RusotoError<ListReceiptRuleSetsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_receipt_rule_sets_pages(input), |page| {
+            page.rule_sets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTemplates` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_templates_pages(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<ListTemplatesResponse, RusotoError<ListTemplatesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SesClient, input| async move { client.list_templates(input).await },
+            |input: &ListTemplatesRequest, output: &ListTemplatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTemplatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TemplatesMetadata` of every page of `ListTemplates` results for `input`, as `list_templates_pages` requests them.
+    pub fn list_templates_stream(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<TemplateMetadata, RusotoError<ListTemplatesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_templates_pages(input), |page| {
+            page.templates_metadata.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Ses for SesClient {
+    /// <p>Creates a receipt rule set by cloning an existing one. All receipt rules and configurations are copied to the new receipt rule set and are completely independent of the source rule set.</p> <p>For information about setting up rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn clone_receipt_rule_set(
+        &self,
+        input: CloneReceiptRuleSetRequest,
+    ) -> Result<CloneReceiptRuleSetResponse, RusotoError<CloneReceiptRuleSetError>> {
+        self.clone_receipt_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a configuration set.</p> <p>Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+        self.create_configuration_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a configuration set event destination.</p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>An event destination is the AWS service to which Amazon SES publishes the email sending events associated with a configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        CreateConfigurationSetEventDestinationResponse,
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        self.create_configuration_set_event_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
+    async fn create_configuration_set_tracking_options(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        CreateConfigurationSetTrackingOptionsResponse,
+        RusotoError<CreateConfigurationSetTrackingOptionsError>,
+    > {
+        self.create_configuration_set_tracking_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn create_custom_verification_email_template(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> Result<(), RusotoError<CreateCustomVerificationEmailTemplateError>> {
+        self.create_custom_verification_email_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new IP address filter.</p> <p>For information about setting up IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn create_receipt_filter(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> Result<CreateReceiptFilterResponse, RusotoError<CreateReceiptFilterError>> {
+        self.create_receipt_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn create_receipt_rule(
+        &self,
+        input: CreateReceiptRuleRequest,
+    ) -> Result<CreateReceiptRuleResponse, RusotoError<CreateReceiptRuleError>> {
+        self.create_receipt_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an empty receipt rule set.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn create_receipt_rule_set(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> Result<CreateReceiptRuleSetResponse, RusotoError<CreateReceiptRuleSetError>> {
+        self.create_receipt_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
+        self.create_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+        self.delete_configuration_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a configuration set event destination. Configuration set event destinations are associated with configuration sets, which enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        DeleteConfigurationSetEventDestinationResponse,
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        self.delete_configuration_set_event_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an association between a configuration set and a custom domain for open and click event tracking.</p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p> <note> <p>Deleting this kind of association will result in emails sent using the specified configuration set to capture open and click events using the standard, Amazon SES-operated domains.</p> </note></p>
+    async fn delete_configuration_set_tracking_options(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        DeleteConfigurationSetTrackingOptionsResponse,
+        RusotoError<DeleteConfigurationSetTrackingOptionsError>,
+    > {
+        self.delete_configuration_set_tracking_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing custom verification email template. </p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn delete_custom_verification_email_template(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> Result<(), RusotoError<DeleteCustomVerificationEmailTemplateError>> {
+        self.delete_custom_verification_email_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified identity (an email address or a domain) from the list of verified identities.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn delete_identity(
+        &self,
+        input: DeleteIdentityRequest,
+    ) -> Result<DeleteIdentityResponse, RusotoError<DeleteIdentityError>> {
+        self.delete_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified sending authorization policy for the given identity (an email address or a domain). This API returns successfully even if a policy with the specified name does not exist.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn delete_identity_policy(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> Result<DeleteIdentityPolicyResponse, RusotoError<DeleteIdentityPolicyError>> {
+        self.delete_identity_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified IP address filter.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn delete_receipt_filter(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> Result<DeleteReceiptFilterResponse, RusotoError<DeleteReceiptFilterError>> {
+        self.delete_receipt_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn delete_receipt_rule(
+        &self,
+        input: DeleteReceiptRuleRequest,
+    ) -> Result<DeleteReceiptRuleResponse, RusotoError<DeleteReceiptRuleError>> {
+        self.delete_receipt_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified receipt rule set and all of the receipt rules it contains.</p> <note> <p>The currently active rule set cannot be deleted.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn delete_receipt_rule_set(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> Result<DeleteReceiptRuleSetResponse, RusotoError<DeleteReceiptRuleSetError>> {
+        self.delete_receipt_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an email template.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
+        self.delete_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecated. Use the <code>DeleteIdentity</code> operation to delete email addresses and domains.</p>
+    async fn delete_verified_email_address(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> Result<(), RusotoError<DeleteVerifiedEmailAddressError>> {
+        self.delete_verified_email_address_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the metadata and receipt rules for the receipt rule set that is currently active.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn describe_active_receipt_rule_set(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> Result<DescribeActiveReceiptRuleSetResponse, RusotoError<DescribeActiveReceiptRuleSetError>>
+    {
+        self.describe_active_receipt_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details of the specified configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn describe_configuration_set(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> Result<DescribeConfigurationSetResponse, RusotoError<DescribeConfigurationSetError>> {
+        self.describe_configuration_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details of the specified receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn describe_receipt_rule(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> Result<DescribeReceiptRuleResponse, RusotoError<DescribeReceiptRuleError>> {
+        self.describe_receipt_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details of the specified receipt rule set.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn describe_receipt_rule_set(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> Result<DescribeReceiptRuleSetResponse, RusotoError<DescribeReceiptRuleSetError>> {
+        self.describe_receipt_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the email sending status of the Amazon SES account for the current region.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn get_account_sending_enabled(
+        &self,
+    ) -> Result<GetAccountSendingEnabledResponse, RusotoError<GetAccountSendingEnabledError>> {
+        self.get_account_sending_enabled_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the custom email verification template for the template name you specify.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn get_custom_verification_email_template(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> Result<
+        GetCustomVerificationEmailTemplateResponse,
+        RusotoError<GetCustomVerificationEmailTemplateError>,
+    > {
+        self.get_custom_verification_email_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the current status of Easy DKIM signing for an entity. For domain name identities, this operation also returns the DKIM tokens that are required for Easy DKIM signing, and whether Amazon SES has successfully verified that these tokens have been published.</p> <p>This operation takes a list of identities as input and returns the following information for each:</p> <ul> <li> <p>Whether Easy DKIM signing is enabled or disabled.</p> </li> <li> <p>A set of DKIM tokens that represent the identity. If the identity is an email address, the tokens represent the domain of that address.</p> </li> <li> <p>Whether Amazon SES has successfully verified the DKIM tokens published in the domain's DNS. This information is only returned for domain name identities, not for email addresses.</p> </li> </ul> <p>This operation is throttled at one request per second and can only get DKIM attributes for up to 100 identities at a time.</p> <p>For more information about creating DNS records using DKIM tokens, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim-dns-records.html">Amazon SES Developer Guide</a>.</p>
+    async fn get_identity_dkim_attributes(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> Result<GetIdentityDkimAttributesResponse, RusotoError<GetIdentityDkimAttributesError>>
+    {
+        self.get_identity_dkim_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the custom MAIL FROM attributes for a list of identities (email addresses : domains).</p> <p>This operation is throttled at one request per second and can only get custom MAIL FROM attributes for up to 100 identities at a time.</p>
+    async fn get_identity_mail_from_domain_attributes(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> Result<
+        GetIdentityMailFromDomainAttributesResponse,
+        RusotoError<GetIdentityMailFromDomainAttributesError>,
+    > {
+        self.get_identity_mail_from_domain_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Given a list of verified identities (email addresses and/or domains), returns a structure describing identity notification attributes.</p> <p>This operation is throttled at one request per second and can only get notification attributes for up to 100 identities at a time.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    async fn get_identity_notification_attributes(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> Result<
+        GetIdentityNotificationAttributesResponse,
+        RusotoError<GetIdentityNotificationAttributesError>,
+    > {
+        self.get_identity_notification_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the requested sending authorization policies for the given identity (an email address or a domain). The policies are returned as a map of policy names to policy contents. You can retrieve a maximum of 20 policies at a time.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn get_identity_policies(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> Result<GetIdentityPoliciesResponse, RusotoError<GetIdentityPoliciesError>> {
+        self.get_identity_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Given a list of identities (email addresses and/or domains), returns the verification status and (for domain identities) the verification token for each identity.</p> <p>The verification status of an email address is "Pending" until the email address owner clicks the link within the verification email that Amazon SES sent to that address. If the email address owner clicks the link within 24 hours, the verification status of the email address changes to "Success". If the link is not clicked within 24 hours, the verification status changes to "Failed." In that case, if you still want to verify the email address, you must restart the verification process from the beginning.</p> <p>For domain identities, the domain's verification status is "Pending" as Amazon SES searches for the required TXT record in the DNS settings of the domain. When Amazon SES detects the record, the domain's verification status changes to "Success". If Amazon SES is unable to detect the record within 72 hours, the domain's verification status changes to "Failed." In that case, if you still want to verify the domain, you must restart the verification process from the beginning.</p> <p>This operation is throttled at one request per second and can only get verification attributes for up to 100 identities at a time.</p>
+    async fn get_identity_verification_attributes(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> Result<
+        GetIdentityVerificationAttributesResponse,
+        RusotoError<GetIdentityVerificationAttributesError>,
+    > {
+        self.get_identity_verification_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides the sending limits for the Amazon SES account. </p> <p>You can execute this operation no more than once per second.</p>
+    async fn get_send_quota(&self) -> Result<GetSendQuotaResponse, RusotoError<GetSendQuotaError>> {
+        self.get_send_quota_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides sending statistics for the current AWS Region. The result is a list of data points, representing the last two weeks of sending activity. Each data point in the list contains statistics for a 15-minute period of time.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn get_send_statistics(
+        &self,
+    ) -> Result<GetSendStatisticsResponse, RusotoError<GetSendStatisticsError>> {
+        self.get_send_statistics_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays the template object (which includes the Subject line, HTML part and text part) for the template you specify.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn get_template(
+        &self,
+        input: GetTemplateRequest,
+    ) -> Result<GetTemplateResponse, RusotoError<GetTemplateError>> {
+        self.get_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a list of the configuration sets associated with your Amazon SES account in the current AWS Region. For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second. This operation will return up to 1,000 configuration sets each time it is run. If your Amazon SES account has more than 1,000 configuration sets, this operation will also return a NextToken element. You can then execute the <code>ListConfigurationSets</code> operation again, passing the <code>NextToken</code> parameter and the value of the NextToken element to retrieve additional results.</p>
+    async fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+        self.list_configuration_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the existing custom verification email templates for your account in the current AWS Region.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn list_custom_verification_email_templates(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> Result<
+        ListCustomVerificationEmailTemplatesResponse,
+        RusotoError<ListCustomVerificationEmailTemplatesError>,
+    > {
+        self.list_custom_verification_email_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list containing all of the identities (email addresses and domains) for your AWS account in the current AWS Region, regardless of verification status.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn list_identities(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
+        self.list_identities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of sending authorization policies that are attached to the given identity (an email address or a domain). This API returns only a list. If you want the actual policy content, you can use <code>GetIdentityPolicies</code>.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn list_identity_policies(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> Result<ListIdentityPoliciesResponse, RusotoError<ListIdentityPoliciesError>> {
+        self.list_identity_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the IP address filters associated with your AWS account in the current AWS Region.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn list_receipt_filters(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> Result<ListReceiptFiltersResponse, RusotoError<ListReceiptFiltersError>> {
+        self.list_receipt_filters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the receipt rule sets that exist under your AWS account in the current AWS Region. If there are additional receipt rule sets to be retrieved, you will receive a <code>NextToken</code> that you can provide to the next call to <code>ListReceiptRuleSets</code> to retrieve the additional entries.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn list_receipt_rule_sets(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> Result<ListReceiptRuleSetsResponse, RusotoError<ListReceiptRuleSetsError>> {
+        self.list_receipt_rule_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the email templates present in your Amazon SES account in the current AWS Region.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
+        self.list_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecated. Use the <code>ListIdentities</code> operation to list the email addresses and domains associated with your account.</p>
+    async fn list_verified_email_addresses(
+        &self,
+    ) -> Result<ListVerifiedEmailAddressesResponse, RusotoError<ListVerifiedEmailAddressesError>>
+    {
+        self.list_verified_email_addresses_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates the delivery options for a configuration set.</p>
+    async fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetDeliveryOptionsResponse,
+        RusotoError<PutConfigurationSetDeliveryOptionsError>,
+    > {
+        self.put_configuration_set_delivery_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates a sending authorization policy for the specified identity (an email address or a domain).</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn put_identity_policy(
+        &self,
+        input: PutIdentityPolicyRequest,
+    ) -> Result<PutIdentityPolicyResponse, RusotoError<PutIdentityPolicyError>> {
+        self.put_identity_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Reorders the receipt rules within a receipt rule set.</p> <note> <p>All of the rules in the rule set must be represented in this request. That is, this API will return an error if the reorder request doesn't explicitly position all of the rules.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn reorder_receipt_rule_set(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> Result<ReorderReceiptRuleSetResponse, RusotoError<ReorderReceiptRuleSetError>> {
+        self.reorder_receipt_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates and sends a bounce message to the sender of an email you received through Amazon SES. You can only use this API on an email up to 24 hours after you receive it.</p> <note> <p>You cannot use this API to send generic bounces for mail that was not received by Amazon SES.</p> </note> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn send_bounce(
+        &self,
+        input: SendBounceRequest,
+    ) -> Result<SendBounceResponse, RusotoError<SendBounceError>> {
+        self.send_bounce_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Composes an email message to multiple destinations. The message body is created using an email template.</p> <p>In order to send email using the <code>SendBulkTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Each <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendBulkTemplatedEmail</code> operation several times to send the message to each group.</p> </li> <li> <p>The number of destinations you can contact in a single call to the API may be limited by your account&#39;s maximum sending rate.</p> </li> </ul></p>
+    async fn send_bulk_templated_email(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> Result<SendBulkTemplatedEmailResponse, RusotoError<SendBulkTemplatedEmailError>> {
+        self.send_bulk_templated_email_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. As a result of executing this operation, a customized verification email is sent to the specified address.</p> <p>To use this operation, you must first create a custom verification email template. For more information about creating and using custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn send_custom_verification_email(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> Result<SendCustomVerificationEmailResponse, RusotoError<SendCustomVerificationEmailError>>
+    {
+        self.send_custom_verification_email_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Composes an email message and immediately queues it for sending. In order to send email using the <code>SendEmail</code> operation, your message must meet the following requirements:</p> <ul> <li> <p>The message must be sent from a verified email address or domain. If you attempt to send email using a non-verified address or domain, the operation will result in an &quot;Email address not verified&quot; error. </p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>The message must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendEmail</code> operation several times to send the message to each group.</p> </li> </ul> <important> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </important></p>
+    async fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
+        self.send_email_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Composes an email message and immediately queues it for sending.</p> <p>This operation is more flexible than the <code>SendEmail</code> API operation. When you use the <code>SendRawEmail</code> operation, you can specify the headers of the message as well as its content. This flexibility is useful, for example, when you want to send a multipart MIME email (such a message that contains both a text and an HTML version). You can also use this operation to send messages that include attachments.</p> <p>The <code>SendRawEmail</code> operation has the following requirements:</p> <ul> <li> <p>You can only send email from <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">verified email addresses or domains</a>. If you try to send email from an address that isn&#39;t verified, the operation results in an &quot;Email address not verified&quot; error.</p> </li> <li> <p>If your account is still in the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/request-production-access.html">Amazon SES sandbox</a>, you can only send email to other verified addresses in your account, or to addresses that are associated with the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mailbox-simulator.html">Amazon SES mailbox simulator</a>.</p> </li> <li> <p>The maximum message size, including attachments, is 10 MB.</p> </li> <li> <p>Each message has to include at least one recipient address. A recipient address includes any address on the To:, CC:, or BCC: lines.</p> </li> <li> <p>If you send a single message to more than one recipient address, and one of the recipient addresses isn&#39;t in a valid format (that is, it&#39;s not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), Amazon SES rejects the entire message, even if the other addresses are valid.</p> </li> <li> <p>Each message can include up to 50 recipient addresses across the To:, CC:, or BCC: lines. If you need to send a single message to more than 50 recipients, you have to split the list of recipient addresses into groups of less than 50 recipients, and send separate messages to each group.</p> </li> <li> <p>Amazon SES allows you to specify 8-bit Content-Transfer-Encoding for MIME message parts. However, if Amazon SES has to modify the contents of your message (for example, if you use open and click tracking), 8-bit content isn&#39;t preserved. For this reason, we highly recommend that you encode all content that isn&#39;t 7-bit ASCII. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-email-raw.html#send-email-mime-encoding">MIME Encoding</a> in the <i>Amazon SES Developer Guide</i>.</p> </li> </ul> <p>Additionally, keep the following considerations in mind when using the <code>SendRawEmail</code> operation:</p> <ul> <li> <p>Although you can customize the message headers when using the <code>SendRawEmail</code> operation, Amazon SES will automatically apply its own <code>Message-ID</code> and <code>Date</code> headers; if you passed these headers when creating the message, they will be overwritten by the values that Amazon SES provides.</p> </li> <li> <p>If you are using sending authorization to send on behalf of another user, <code>SendRawEmail</code> enables you to specify the cross-account identity for the email&#39;s Source, From, and Return-Path parameters in one of two ways: you can pass optional parameters <code>SourceArn</code>, <code>FromArn</code>, and/or <code>ReturnPathArn</code> to the API, or you can include the following X-headers in the header of your raw email:</p> <ul> <li> <p> <code>X-SES-SOURCE-ARN</code> </p> </li> <li> <p> <code>X-SES-FROM-ARN</code> </p> </li> <li> <p> <code>X-SES-RETURN-PATH-ARN</code> </p> </li> </ul> <important> <p>Don&#39;t include these X-headers in the DKIM signature. Amazon SES removes these before it sends the email.</p> </important> <p>If you only specify the <code>SourceIdentityArn</code> parameter, Amazon SES sets the From and Return-Path addresses to the same identity that you specified.</p> <p>For more information about sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Using Sending Authorization with Amazon SES</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> </ul></p>
+    async fn send_raw_email(
+        &self,
+        input: SendRawEmailRequest,
+    ) -> Result<SendRawEmailResponse, RusotoError<SendRawEmailError>> {
+        self.send_raw_email_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Composes an email message using an email template and immediately queues it for sending.</p> <p>In order to send email using the <code>SendTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Calls to the <code>SendTemplatedEmail</code> operation may only include one <code>Destination</code> parameter. A destination is a set of recipients who will receive the same version of the email. The <code>Destination</code> parameter can include up to 50 recipients, across the To:, CC: and BCC: fields.</p> </li> <li> <p>The <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> </ul> <important> <p>If your call to the <code>SendTemplatedEmail</code> operation includes all of the required parameters, Amazon SES accepts it and returns a Message ID. However, if Amazon SES can&#39;t render the email because the template contains errors, it doesn&#39;t send the email. Additionally, because it already accepted the message, Amazon SES doesn&#39;t return a message stating that it was unable to send the email.</p> <p>For these reasons, we highly recommend that you set up Amazon SES to send you notifications when Rendering Failure events occur. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Sending Personalized Email Using the Amazon SES API</a> in the <i>Amazon Simple Email Service Developer Guide</i>.</p> </important></p>
+    async fn send_templated_email(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> Result<SendTemplatedEmailResponse, RusotoError<SendTemplatedEmailError>> {
+        self.send_templated_email_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the specified receipt rule set as the active receipt rule set.</p> <note> <p>To disable your email-receiving through Amazon SES completely, you can call this API with RuleSetName set to null.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn set_active_receipt_rule_set(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> Result<SetActiveReceiptRuleSetResponse, RusotoError<SetActiveReceiptRuleSetError>> {
+        self.set_active_receipt_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables or disables Easy DKIM signing of email sent from an identity. If Easy DKIM signing is enabled for a domain, then Amazon SES uses DKIM to sign all email that it sends from addresses on that domain. If Easy DKIM signing is enabled for an email address, then Amazon SES uses DKIM to sign all email it sends from that address.</p> <note> <p>For email addresses (for example, <code>user@example.com</code>), you can only enable DKIM signing if the corresponding domain (in this case, <code>example.com</code>) has been set up to use Easy DKIM.</p> </note> <p>You can enable DKIM signing for an identity at any time after you start the verification process for the identity, even if the verification process isn't complete. </p> <p>You can execute this operation no more than once per second.</p> <p>For more information about Easy DKIM signing, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
+    async fn set_identity_dkim_enabled(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> Result<SetIdentityDkimEnabledResponse, RusotoError<SetIdentityDkimEnabledError>> {
+        self.set_identity_dkim_enabled_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Given an identity (an email address or a domain), enables or disables whether Amazon SES forwards bounce and complaint notifications as email. Feedback forwarding can only be disabled when Amazon Simple Notification Service (Amazon SNS) topics are specified for both bounces and complaints.</p> <note> <p>Feedback forwarding does not apply to delivery notifications. Delivery notifications are only available through Amazon SNS.</p> </note> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    async fn set_identity_feedback_forwarding_enabled(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> Result<
+        SetIdentityFeedbackForwardingEnabledResponse,
+        RusotoError<SetIdentityFeedbackForwardingEnabledError>,
+    > {
+        self.set_identity_feedback_forwarding_enabled_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Given an identity (an email address or a domain), sets whether Amazon SES includes the original email headers in the Amazon Simple Notification Service (Amazon SNS) notifications of a specified type.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    async fn set_identity_headers_in_notifications_enabled(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> Result<
+        SetIdentityHeadersInNotificationsEnabledResponse,
+        RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
+    > {
+        self.set_identity_headers_in_notifications_enabled_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables or disables the custom MAIL FROM domain setup for a verified identity (an email address or a domain).</p> <important> <p>To send emails using the specified MAIL FROM domain, you must add an MX record to your MAIL FROM domain's DNS settings. If you want your emails to pass Sender Policy Framework (SPF) checks, you must also add or update an SPF record. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from-set.html">Amazon SES Developer Guide</a>.</p> </important> <p>You can execute this operation no more than once per second.</p>
+    async fn set_identity_mail_from_domain(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> Result<SetIdentityMailFromDomainResponse, RusotoError<SetIdentityMailFromDomainError>>
+    {
+        self.set_identity_mail_from_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets an Amazon Simple Notification Service (Amazon SNS) topic to use when delivering notifications. When you use this operation, you specify a verified identity, such as an email address or domain. When you send an email that uses the chosen identity in the Source field, Amazon SES sends notifications to the topic you specified. You can send bounce, complaint, or delivery notifications (or any combination of the three) to the Amazon SNS topic that you specify.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about feedback notification, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    async fn set_identity_notification_topic(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> Result<SetIdentityNotificationTopicResponse, RusotoError<SetIdentityNotificationTopicError>>
+    {
+        self.set_identity_notification_topic_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the position of the specified receipt rule in the receipt rule set.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn set_receipt_rule_position(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> Result<SetReceiptRulePositionResponse, RusotoError<SetReceiptRulePositionError>> {
+        self.set_receipt_rule_position_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a preview of the MIME content of an email when provided with a template and a set of replacement data.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn test_render_template(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> Result<TestRenderTemplateResponse, RusotoError<TestRenderTemplateError>> {
+        self.test_render_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables or disables email sending across your entire Amazon SES account in the current AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending across your Amazon SES account in a given AWS Region when reputation metrics (such as your bounce or complaint rates) reach certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn update_account_sending_enabled(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> Result<(), RusotoError<UpdateAccountSendingEnabledError>> {
+        self.update_account_sending_enabled_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the event destination of a configuration set. Event destinations are associated with configuration sets, which enable you to publish email sending events to Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS). For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>You can execute this operation no more than once per second.</p>
+    async fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        UpdateConfigurationSetEventDestinationResponse,
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        self.update_configuration_set_event_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables or disables the publishing of reputation metrics for emails sent using a specific configuration set in a given AWS Region. Reputation metrics include bounce and complaint rates. These metrics are published to Amazon CloudWatch. By using CloudWatch, you can create alarms when bounce or complaint rates exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn update_configuration_set_reputation_metrics_enabled(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> Result<(), RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>> {
+        self.update_configuration_set_reputation_metrics_enabled_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables or disables email sending for messages sent using a specific configuration set in a given AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending for a configuration set when the reputation metrics for that configuration set (such as your bounce on complaint rate) exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn update_configuration_set_sending_enabled(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> Result<(), RusotoError<UpdateConfigurationSetSendingEnabledError>> {
+        self.update_configuration_set_sending_enabled_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
+    async fn update_configuration_set_tracking_options(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        UpdateConfigurationSetTrackingOptionsResponse,
+        RusotoError<UpdateConfigurationSetTrackingOptionsError>,
+    > {
+        self.update_configuration_set_tracking_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn update_custom_verification_email_template(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> Result<(), RusotoError<UpdateCustomVerificationEmailTemplateError>> {
+        self.update_custom_verification_email_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn update_receipt_rule(
+        &self,
+        input: UpdateReceiptRuleRequest,
+    ) -> Result<UpdateReceiptRuleResponse, RusotoError<UpdateReceiptRuleError>> {
+        self.update_receipt_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
+        self.update_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a set of DKIM tokens for a domain identity.</p> <important> <p>When you execute the <code>VerifyDomainDkim</code> operation, the domain that you specify is added to the list of identities that are associated with your account. This is true even if you haven't already associated the domain with your account by using the <code>VerifyDomainIdentity</code> operation. However, you can't send email from the domain until you either successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-domains.html">verify it</a> or you successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">set up DKIM for it</a>.</p> </important> <p>You use the tokens that are generated by this operation to create CNAME records. When Amazon SES detects that you've added these records to the DNS configuration for a domain, you can start sending email from that domain. You can start sending email even if you haven't added the TXT record provided by the VerifyDomainIdentity operation to the DNS configuration for your domain. All email that you send from the domain is authenticated using DKIM.</p> <p>To create the CNAME records for DKIM authentication, use the following values:</p> <ul> <li> <p> <b>Name</b>: <i>token</i>._domainkey.<i>example.com</i> </p> </li> <li> <p> <b>Type</b>: CNAME</p> </li> <li> <p> <b>Value</b>: <i>token</i>.dkim.amazonses.com</p> </li> </ul> <p>In the preceding example, replace <i>token</i> with one of the tokens that are generated when you execute this operation. Replace <i>example.com</i> with your domain. Repeat this process for each token that's generated by this operation.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn verify_domain_dkim(
+        &self,
+        input: VerifyDomainDkimRequest,
+    ) -> Result<VerifyDomainDkimResponse, RusotoError<VerifyDomainDkimError>> {
+        self.verify_domain_dkim_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a domain to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. For more information about verifying domains, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second.</p>
+    async fn verify_domain_identity(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> Result<VerifyDomainIdentityResponse, RusotoError<VerifyDomainIdentityError>> {
+        self.verify_domain_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecated. Use the <code>VerifyEmailIdentity</code> operation to verify a new email address.</p>
+    async fn verify_email_address(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> Result<(), RusotoError<VerifyEmailAddressError>> {
+        self.verify_email_address_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS region and attempts to verify it. As a result of executing this operation, a verification email is sent to the specified address.</p> <p>You can execute this operation no more than once per second.</p>
+    async fn verify_email_identity(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> Result<VerifyEmailIdentityResponse, RusotoError<VerifyEmailIdentityError>> {
+        self.verify_email_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
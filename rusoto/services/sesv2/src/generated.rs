@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1879,6 +1879,7 @@ pub struct VolumeStatistics {
 
 /// Errors returned by CreateConfigurationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationSetError {
     /// <p>The resource specified in your request already exists.</p>
     AlreadyExists(String),
@@ -1892,6 +1893,8 @@ pub enum CreateConfigurationSetError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationSetError {
@@ -1899,33 +1902,43 @@ impl CreateConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConfigurationSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1943,12 +1956,14 @@ impl fmt::Display for CreateConfigurationSetError {
             CreateConfigurationSetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateConfigurationSetError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateConfigurationSetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateConfigurationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConfigurationSetError {}
 /// Errors returned by CreateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationSetEventDestinationError {
     /// <p>The resource specified in your request already exists.</p>
     AlreadyExists(String),
@@ -1960,6 +1975,8 @@ pub enum CreateConfigurationSetEventDestinationError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationSetEventDestinationError {
@@ -1969,32 +1986,42 @@ impl CreateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::AlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateConfigurationSetEventDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2019,12 +2046,16 @@ impl fmt::Display for CreateConfigurationSetEventDestinationError {
             CreateConfigurationSetEventDestinationError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateConfigurationSetEventDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateConfigurationSetEventDestinationError {}
 /// Errors returned by CreateDedicatedIpPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDedicatedIpPoolError {
     /// <p>The resource specified in your request already exists.</p>
     AlreadyExists(String),
@@ -2036,6 +2067,8 @@ pub enum CreateDedicatedIpPoolError {
     LimitExceeded(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDedicatedIpPoolError {
@@ -2043,26 +2076,37 @@ impl CreateDedicatedIpPoolError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateDedicatedIpPoolError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateDedicatedIpPoolError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDedicatedIpPoolError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDedicatedIpPoolError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDedicatedIpPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDedicatedIpPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2077,12 +2121,14 @@ impl fmt::Display for CreateDedicatedIpPoolError {
             CreateDedicatedIpPoolError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             CreateDedicatedIpPoolError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDedicatedIpPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateDedicatedIpPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDedicatedIpPoolError {}
 /// Errors returned by CreateDeliverabilityTestReport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeliverabilityTestReportError {
     /// <p>The message can't be sent because the account's ability to send email has been permanently restricted.</p>
     AccountSuspended(String),
@@ -2102,6 +2148,8 @@ pub enum CreateDeliverabilityTestReportError {
     SendingPaused(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeliverabilityTestReportError {
@@ -2111,52 +2159,66 @@ impl CreateDeliverabilityTestReportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountSuspendedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeliverabilityTestReportError::AccountSuspended(err.msg),
+                        &res,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeliverabilityTestReportError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeliverabilityTestReportError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeliverabilityTestReportError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeliverabilityTestReportError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MailFromDomainNotVerifiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeliverabilityTestReportError::MailFromDomainNotVerified(err.msg),
+                        &res,
                     )
                 }
                 "MessageRejected" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeliverabilityTestReportError::MessageRejected(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDeliverabilityTestReportError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeliverabilityTestReportError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "SendingPausedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeliverabilityTestReportError::SendingPaused(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeliverabilityTestReportError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateDeliverabilityTestReportError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2185,12 +2247,16 @@ impl fmt::Display for CreateDeliverabilityTestReportError {
             CreateDeliverabilityTestReportError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDeliverabilityTestReportError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateDeliverabilityTestReportError {}
 /// Errors returned by CreateEmailIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEmailIdentityError {
     /// <p>The resource specified in your request already exists.</p>
     AlreadyExists(String),
@@ -2202,6 +2268,8 @@ pub enum CreateEmailIdentityError {
     LimitExceeded(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEmailIdentityError {
@@ -2209,24 +2277,37 @@ impl CreateEmailIdentityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateEmailIdentityError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateEmailIdentityError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEmailIdentityError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateEmailIdentityError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateEmailIdentityError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEmailIdentityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2241,12 +2322,14 @@ impl fmt::Display for CreateEmailIdentityError {
             CreateEmailIdentityError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             CreateEmailIdentityError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateEmailIdentityError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateEmailIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEmailIdentityError {}
 /// Errors returned by DeleteConfigurationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationSetError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2256,6 +2339,8 @@ pub enum DeleteConfigurationSetError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationSetError {
@@ -2263,23 +2348,31 @@ impl DeleteConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteConfigurationSetError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationSetError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteConfigurationSetError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConfigurationSetError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConfigurationSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2295,12 +2388,14 @@ impl fmt::Display for DeleteConfigurationSetError {
             }
             DeleteConfigurationSetError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteConfigurationSetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteConfigurationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConfigurationSetError {}
 /// Errors returned by DeleteConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationSetEventDestinationError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2308,6 +2403,8 @@ pub enum DeleteConfigurationSetEventDestinationError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationSetEventDestinationError {
@@ -2317,22 +2414,30 @@ impl DeleteConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationSetEventDestinationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationSetEventDestinationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationSetEventDestinationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteConfigurationSetEventDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2351,12 +2456,16 @@ impl fmt::Display for DeleteConfigurationSetEventDestinationError {
             DeleteConfigurationSetEventDestinationError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteConfigurationSetEventDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteConfigurationSetEventDestinationError {}
 /// Errors returned by DeleteDedicatedIpPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDedicatedIpPoolError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2366,6 +2475,8 @@ pub enum DeleteDedicatedIpPoolError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDedicatedIpPoolError {
@@ -2373,23 +2484,31 @@ impl DeleteDedicatedIpPoolError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDedicatedIpPoolError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteDedicatedIpPoolError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDedicatedIpPoolError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDedicatedIpPoolError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDedicatedIpPoolError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDedicatedIpPoolError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDedicatedIpPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDedicatedIpPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2403,12 +2522,14 @@ impl fmt::Display for DeleteDedicatedIpPoolError {
             DeleteDedicatedIpPoolError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteDedicatedIpPoolError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDedicatedIpPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteDedicatedIpPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDedicatedIpPoolError {}
 /// Errors returned by DeleteEmailIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEmailIdentityError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2418,6 +2539,8 @@ pub enum DeleteEmailIdentityError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEmailIdentityError {
@@ -2425,21 +2548,28 @@ impl DeleteEmailIdentityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteEmailIdentityError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEmailIdentityError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::NotFound(err.msg))
+                    return RusotoError::service(DeleteEmailIdentityError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteEmailIdentityError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEmailIdentityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2453,12 +2583,14 @@ impl fmt::Display for DeleteEmailIdentityError {
             DeleteEmailIdentityError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteEmailIdentityError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteEmailIdentityError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteEmailIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEmailIdentityError {}
 /// Errors returned by DeleteSuppressedDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSuppressedDestinationError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2466,6 +2598,8 @@ pub enum DeleteSuppressedDestinationError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSuppressedDestinationError {
@@ -2475,22 +2609,30 @@ impl DeleteSuppressedDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSuppressedDestinationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSuppressedDestinationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSuppressedDestinationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSuppressedDestinationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteSuppressedDestinationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSuppressedDestinationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteSuppressedDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2503,17 +2645,21 @@ impl fmt::Display for DeleteSuppressedDestinationError {
             DeleteSuppressedDestinationError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteSuppressedDestinationError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteSuppressedDestinationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteSuppressedDestinationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSuppressedDestinationError {}
 /// Errors returned by GetAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAccountError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAccountError {
@@ -2521,13 +2667,13 @@ impl GetAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountError::BadRequest(err.msg))
+                    return RusotoError::service(GetAccountError::BadRequest(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetAccountError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2539,12 +2685,14 @@ impl fmt::Display for GetAccountError {
         match *self {
             GetAccountError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetAccountError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAccountError {}
 /// Errors returned by GetBlacklistReports
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBlacklistReportsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2552,6 +2700,8 @@ pub enum GetBlacklistReportsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBlacklistReportsError {
@@ -2559,16 +2709,22 @@ impl GetBlacklistReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBlacklistReportsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetBlacklistReportsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBlacklistReportsError::NotFound(err.msg))
+                    return RusotoError::service(GetBlacklistReportsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetBlacklistReportsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetBlacklistReportsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBlacklistReportsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2581,12 +2737,14 @@ impl fmt::Display for GetBlacklistReportsError {
             GetBlacklistReportsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetBlacklistReportsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetBlacklistReportsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetBlacklistReportsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBlacklistReportsError {}
 /// Errors returned by GetConfigurationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConfigurationSetError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2594,6 +2752,8 @@ pub enum GetConfigurationSetError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConfigurationSetError {
@@ -2601,16 +2761,22 @@ impl GetConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetConfigurationSetError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service(GetConfigurationSetError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetConfigurationSetError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetConfigurationSetError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConfigurationSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2623,12 +2789,14 @@ impl fmt::Display for GetConfigurationSetError {
             GetConfigurationSetError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetConfigurationSetError::NotFound(ref cause) => write!(f, "{}", cause),
             GetConfigurationSetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetConfigurationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConfigurationSetError {}
 /// Errors returned by GetConfigurationSetEventDestinations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConfigurationSetEventDestinationsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2636,6 +2804,8 @@ pub enum GetConfigurationSetEventDestinationsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConfigurationSetEventDestinationsError {
@@ -2645,22 +2815,30 @@ impl GetConfigurationSetEventDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConfigurationSetEventDestinationsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConfigurationSetEventDestinationsError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConfigurationSetEventDestinationsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetConfigurationSetEventDestinationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2679,12 +2857,16 @@ impl fmt::Display for GetConfigurationSetEventDestinationsError {
             GetConfigurationSetEventDestinationsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetConfigurationSetEventDestinationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetConfigurationSetEventDestinationsError {}
 /// Errors returned by GetDedicatedIp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDedicatedIpError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2692,6 +2874,8 @@ pub enum GetDedicatedIpError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDedicatedIpError {
@@ -2699,16 +2883,19 @@ impl GetDedicatedIpError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDedicatedIpError::BadRequest(err.msg))
+                    return RusotoError::service(GetDedicatedIpError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDedicatedIpError::NotFound(err.msg))
+                    return RusotoError::service(GetDedicatedIpError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDedicatedIpError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetDedicatedIpError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDedicatedIpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2721,12 +2908,14 @@ impl fmt::Display for GetDedicatedIpError {
             GetDedicatedIpError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDedicatedIpError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDedicatedIpError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetDedicatedIpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDedicatedIpError {}
 /// Errors returned by GetDedicatedIps
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDedicatedIpsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2734,6 +2923,8 @@ pub enum GetDedicatedIpsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDedicatedIpsError {
@@ -2741,16 +2932,19 @@ impl GetDedicatedIpsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDedicatedIpsError::BadRequest(err.msg))
+                    return RusotoError::service(GetDedicatedIpsError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDedicatedIpsError::NotFound(err.msg))
+                    return RusotoError::service(GetDedicatedIpsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDedicatedIpsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetDedicatedIpsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDedicatedIpsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2763,12 +2957,14 @@ impl fmt::Display for GetDedicatedIpsError {
             GetDedicatedIpsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDedicatedIpsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDedicatedIpsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetDedicatedIpsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDedicatedIpsError {}
 /// Errors returned by GetDeliverabilityDashboardOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeliverabilityDashboardOptionsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2776,6 +2972,8 @@ pub enum GetDeliverabilityDashboardOptionsError {
     LimitExceeded(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeliverabilityDashboardOptionsError {
@@ -2785,22 +2983,30 @@ impl GetDeliverabilityDashboardOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeliverabilityDashboardOptionsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeliverabilityDashboardOptionsError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeliverabilityDashboardOptionsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetDeliverabilityDashboardOptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2817,12 +3023,16 @@ impl fmt::Display for GetDeliverabilityDashboardOptionsError {
             GetDeliverabilityDashboardOptionsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetDeliverabilityDashboardOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetDeliverabilityDashboardOptionsError {}
 /// Errors returned by GetDeliverabilityTestReport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeliverabilityTestReportError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2830,6 +3040,8 @@ pub enum GetDeliverabilityTestReportError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeliverabilityTestReportError {
@@ -2839,22 +3051,30 @@ impl GetDeliverabilityTestReportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeliverabilityTestReportError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeliverabilityTestReportError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeliverabilityTestReportError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeliverabilityTestReportError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeliverabilityTestReportError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeliverabilityTestReportError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetDeliverabilityTestReportError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2867,12 +3087,14 @@ impl fmt::Display for GetDeliverabilityTestReportError {
             GetDeliverabilityTestReportError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDeliverabilityTestReportError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDeliverabilityTestReportError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetDeliverabilityTestReportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeliverabilityTestReportError {}
 /// Errors returned by GetDomainDeliverabilityCampaign
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainDeliverabilityCampaignError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2880,6 +3102,8 @@ pub enum GetDomainDeliverabilityCampaignError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainDeliverabilityCampaignError {
@@ -2889,22 +3113,30 @@ impl GetDomainDeliverabilityCampaignError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainDeliverabilityCampaignError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDomainDeliverabilityCampaignError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainDeliverabilityCampaignError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDomainDeliverabilityCampaignError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDomainDeliverabilityCampaignError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetDomainDeliverabilityCampaignError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2919,12 +3151,16 @@ impl fmt::Display for GetDomainDeliverabilityCampaignError {
             GetDomainDeliverabilityCampaignError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetDomainDeliverabilityCampaignError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetDomainDeliverabilityCampaignError {}
 /// Errors returned by GetDomainStatisticsReport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainStatisticsReportError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2932,6 +3168,8 @@ pub enum GetDomainStatisticsReportError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainStatisticsReportError {
@@ -2939,20 +3177,27 @@ impl GetDomainStatisticsReportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainStatisticsReportError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDomainStatisticsReportError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainStatisticsReportError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetDomainStatisticsReportError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainStatisticsReportError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDomainStatisticsReportError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetDomainStatisticsReportError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2965,12 +3210,14 @@ impl fmt::Display for GetDomainStatisticsReportError {
             GetDomainStatisticsReportError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDomainStatisticsReportError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDomainStatisticsReportError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetDomainStatisticsReportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainStatisticsReportError {}
 /// Errors returned by GetEmailIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEmailIdentityError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -2978,6 +3225,8 @@ pub enum GetEmailIdentityError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEmailIdentityError {
@@ -2985,16 +3234,19 @@ impl GetEmailIdentityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEmailIdentityError::BadRequest(err.msg))
+                    return RusotoError::service(GetEmailIdentityError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetEmailIdentityError::NotFound(err.msg))
+                    return RusotoError::service(GetEmailIdentityError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetEmailIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetEmailIdentityError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEmailIdentityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3007,12 +3259,14 @@ impl fmt::Display for GetEmailIdentityError {
             GetEmailIdentityError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetEmailIdentityError::NotFound(ref cause) => write!(f, "{}", cause),
             GetEmailIdentityError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetEmailIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEmailIdentityError {}
 /// Errors returned by GetSuppressedDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSuppressedDestinationError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3020,6 +3274,8 @@ pub enum GetSuppressedDestinationError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSuppressedDestinationError {
@@ -3027,18 +3283,25 @@ impl GetSuppressedDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSuppressedDestinationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetSuppressedDestinationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSuppressedDestinationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetSuppressedDestinationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSuppressedDestinationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSuppressedDestinationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSuppressedDestinationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3051,17 +3314,21 @@ impl fmt::Display for GetSuppressedDestinationError {
             GetSuppressedDestinationError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetSuppressedDestinationError::NotFound(ref cause) => write!(f, "{}", cause),
             GetSuppressedDestinationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetSuppressedDestinationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSuppressedDestinationError {}
 /// Errors returned by ListConfigurationSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigurationSetsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigurationSetsError {
@@ -3069,15 +3336,19 @@ impl ListConfigurationSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationSetsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListConfigurationSetsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListConfigurationSetsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationSetsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConfigurationSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3089,17 +3360,21 @@ impl fmt::Display for ListConfigurationSetsError {
         match *self {
             ListConfigurationSetsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListConfigurationSetsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListConfigurationSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigurationSetsError {}
 /// Errors returned by ListDedicatedIpPools
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDedicatedIpPoolsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDedicatedIpPoolsError {
@@ -3107,15 +3382,19 @@ impl ListDedicatedIpPoolsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDedicatedIpPoolsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListDedicatedIpPoolsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDedicatedIpPoolsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDedicatedIpPoolsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDedicatedIpPoolsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3127,12 +3406,14 @@ impl fmt::Display for ListDedicatedIpPoolsError {
         match *self {
             ListDedicatedIpPoolsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListDedicatedIpPoolsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListDedicatedIpPoolsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDedicatedIpPoolsError {}
 /// Errors returned by ListDeliverabilityTestReports
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeliverabilityTestReportsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3140,6 +3421,8 @@ pub enum ListDeliverabilityTestReportsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeliverabilityTestReportsError {
@@ -3149,22 +3432,30 @@ impl ListDeliverabilityTestReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeliverabilityTestReportsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeliverabilityTestReportsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDeliverabilityTestReportsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeliverabilityTestReportsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeliverabilityTestReportsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListDeliverabilityTestReportsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3179,12 +3470,16 @@ impl fmt::Display for ListDeliverabilityTestReportsError {
             ListDeliverabilityTestReportsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListDeliverabilityTestReportsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListDeliverabilityTestReportsError {}
 /// Errors returned by ListDomainDeliverabilityCampaigns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDomainDeliverabilityCampaignsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3192,6 +3487,8 @@ pub enum ListDomainDeliverabilityCampaignsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDomainDeliverabilityCampaignsError {
@@ -3201,22 +3498,30 @@ impl ListDomainDeliverabilityCampaignsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDomainDeliverabilityCampaignsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDomainDeliverabilityCampaignsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDomainDeliverabilityCampaignsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDomainDeliverabilityCampaignsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListDomainDeliverabilityCampaignsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3231,17 +3536,23 @@ impl fmt::Display for ListDomainDeliverabilityCampaignsError {
             ListDomainDeliverabilityCampaignsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListDomainDeliverabilityCampaignsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListDomainDeliverabilityCampaignsError {}
 /// Errors returned by ListEmailIdentities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEmailIdentitiesError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEmailIdentitiesError {
@@ -3249,13 +3560,19 @@ impl ListEmailIdentitiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEmailIdentitiesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListEmailIdentitiesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEmailIdentitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListEmailIdentitiesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEmailIdentitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3267,12 +3584,14 @@ impl fmt::Display for ListEmailIdentitiesError {
         match *self {
             ListEmailIdentitiesError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListEmailIdentitiesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListEmailIdentitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEmailIdentitiesError {}
 /// Errors returned by ListSuppressedDestinations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSuppressedDestinationsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3280,6 +3599,8 @@ pub enum ListSuppressedDestinationsError {
     InvalidNextToken(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSuppressedDestinationsError {
@@ -3289,22 +3610,27 @@ impl ListSuppressedDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListSuppressedDestinationsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSuppressedDestinationsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSuppressedDestinationsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSuppressedDestinationsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListSuppressedDestinationsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSuppressedDestinationsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListSuppressedDestinationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3317,12 +3643,14 @@ impl fmt::Display for ListSuppressedDestinationsError {
             ListSuppressedDestinationsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListSuppressedDestinationsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListSuppressedDestinationsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListSuppressedDestinationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSuppressedDestinationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3330,6 +3658,8 @@ pub enum ListTagsForResourceError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3337,16 +3667,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3359,17 +3695,21 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutAccountDedicatedIpWarmupAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAccountDedicatedIpWarmupAttributesError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAccountDedicatedIpWarmupAttributesError {
@@ -3379,17 +3719,24 @@ impl PutAccountDedicatedIpWarmupAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAccountDedicatedIpWarmupAttributesError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAccountDedicatedIpWarmupAttributesError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutAccountDedicatedIpWarmupAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3405,17 +3752,23 @@ impl fmt::Display for PutAccountDedicatedIpWarmupAttributesError {
             PutAccountDedicatedIpWarmupAttributesError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutAccountDedicatedIpWarmupAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutAccountDedicatedIpWarmupAttributesError {}
 /// Errors returned by PutAccountSendingAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAccountSendingAttributesError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAccountSendingAttributesError {
@@ -3425,17 +3778,24 @@ impl PutAccountSendingAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutAccountSendingAttributesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutAccountSendingAttributesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutAccountSendingAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutAccountSendingAttributesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutAccountSendingAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3447,17 +3807,21 @@ impl fmt::Display for PutAccountSendingAttributesError {
         match *self {
             PutAccountSendingAttributesError::BadRequest(ref cause) => write!(f, "{}", cause),
             PutAccountSendingAttributesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            PutAccountSendingAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutAccountSendingAttributesError {}
 /// Errors returned by PutAccountSuppressionAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAccountSuppressionAttributesError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAccountSuppressionAttributesError {
@@ -3467,17 +3831,24 @@ impl PutAccountSuppressionAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutAccountSuppressionAttributesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutAccountSuppressionAttributesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAccountSuppressionAttributesError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutAccountSuppressionAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3491,12 +3862,16 @@ impl fmt::Display for PutAccountSuppressionAttributesError {
             PutAccountSuppressionAttributesError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutAccountSuppressionAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutAccountSuppressionAttributesError {}
 /// Errors returned by PutConfigurationSetDeliveryOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConfigurationSetDeliveryOptionsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3504,6 +3879,8 @@ pub enum PutConfigurationSetDeliveryOptionsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConfigurationSetDeliveryOptionsError {
@@ -3513,22 +3890,30 @@ impl PutConfigurationSetDeliveryOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetDeliveryOptionsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConfigurationSetDeliveryOptionsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutConfigurationSetDeliveryOptionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetDeliveryOptionsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutConfigurationSetDeliveryOptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3545,12 +3930,16 @@ impl fmt::Display for PutConfigurationSetDeliveryOptionsError {
             PutConfigurationSetDeliveryOptionsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutConfigurationSetDeliveryOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutConfigurationSetDeliveryOptionsError {}
 /// Errors returned by PutConfigurationSetReputationOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConfigurationSetReputationOptionsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3558,6 +3947,8 @@ pub enum PutConfigurationSetReputationOptionsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConfigurationSetReputationOptionsError {
@@ -3567,22 +3958,30 @@ impl PutConfigurationSetReputationOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetReputationOptionsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetReputationOptionsError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetReputationOptionsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutConfigurationSetReputationOptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3601,12 +4000,16 @@ impl fmt::Display for PutConfigurationSetReputationOptionsError {
             PutConfigurationSetReputationOptionsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutConfigurationSetReputationOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutConfigurationSetReputationOptionsError {}
 /// Errors returned by PutConfigurationSetSendingOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConfigurationSetSendingOptionsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3614,6 +4017,8 @@ pub enum PutConfigurationSetSendingOptionsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConfigurationSetSendingOptionsError {
@@ -3623,22 +4028,30 @@ impl PutConfigurationSetSendingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetSendingOptionsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConfigurationSetSendingOptionsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutConfigurationSetSendingOptionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetSendingOptionsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutConfigurationSetSendingOptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3653,12 +4066,16 @@ impl fmt::Display for PutConfigurationSetSendingOptionsError {
             PutConfigurationSetSendingOptionsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutConfigurationSetSendingOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutConfigurationSetSendingOptionsError {}
 /// Errors returned by PutConfigurationSetSuppressionOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConfigurationSetSuppressionOptionsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3666,6 +4083,8 @@ pub enum PutConfigurationSetSuppressionOptionsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConfigurationSetSuppressionOptionsError {
@@ -3675,22 +4094,30 @@ impl PutConfigurationSetSuppressionOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetSuppressionOptionsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetSuppressionOptionsError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetSuppressionOptionsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutConfigurationSetSuppressionOptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3709,12 +4136,16 @@ impl fmt::Display for PutConfigurationSetSuppressionOptionsError {
             PutConfigurationSetSuppressionOptionsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutConfigurationSetSuppressionOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutConfigurationSetSuppressionOptionsError {}
 /// Errors returned by PutConfigurationSetTrackingOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConfigurationSetTrackingOptionsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3722,6 +4153,8 @@ pub enum PutConfigurationSetTrackingOptionsError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConfigurationSetTrackingOptionsError {
@@ -3731,22 +4164,30 @@ impl PutConfigurationSetTrackingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetTrackingOptionsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConfigurationSetTrackingOptionsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutConfigurationSetTrackingOptionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationSetTrackingOptionsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutConfigurationSetTrackingOptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3763,12 +4204,16 @@ impl fmt::Display for PutConfigurationSetTrackingOptionsError {
             PutConfigurationSetTrackingOptionsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutConfigurationSetTrackingOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutConfigurationSetTrackingOptionsError {}
 /// Errors returned by PutDedicatedIpInPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDedicatedIpInPoolError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3776,6 +4221,8 @@ pub enum PutDedicatedIpInPoolError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDedicatedIpInPoolError {
@@ -3783,18 +4230,22 @@ impl PutDedicatedIpInPoolError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutDedicatedIpInPoolError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        PutDedicatedIpInPoolError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutDedicatedIpInPoolError::NotFound(err.msg))
+                    return RusotoError::service(PutDedicatedIpInPoolError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutDedicatedIpInPoolError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDedicatedIpInPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutDedicatedIpInPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3807,12 +4258,14 @@ impl fmt::Display for PutDedicatedIpInPoolError {
             PutDedicatedIpInPoolError::BadRequest(ref cause) => write!(f, "{}", cause),
             PutDedicatedIpInPoolError::NotFound(ref cause) => write!(f, "{}", cause),
             PutDedicatedIpInPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            PutDedicatedIpInPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutDedicatedIpInPoolError {}
 /// Errors returned by PutDedicatedIpWarmupAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDedicatedIpWarmupAttributesError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3820,6 +4273,8 @@ pub enum PutDedicatedIpWarmupAttributesError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDedicatedIpWarmupAttributesError {
@@ -3829,22 +4284,30 @@ impl PutDedicatedIpWarmupAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutDedicatedIpWarmupAttributesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDedicatedIpWarmupAttributesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutDedicatedIpWarmupAttributesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDedicatedIpWarmupAttributesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDedicatedIpWarmupAttributesError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutDedicatedIpWarmupAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3859,12 +4322,16 @@ impl fmt::Display for PutDedicatedIpWarmupAttributesError {
             PutDedicatedIpWarmupAttributesError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutDedicatedIpWarmupAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutDedicatedIpWarmupAttributesError {}
 /// Errors returned by PutDeliverabilityDashboardOption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDeliverabilityDashboardOptionError {
     /// <p>The resource specified in your request already exists.</p>
     AlreadyExists(String),
@@ -3876,6 +4343,8 @@ pub enum PutDeliverabilityDashboardOptionError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDeliverabilityDashboardOptionError {
@@ -3885,32 +4354,42 @@ impl PutDeliverabilityDashboardOptionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDeliverabilityDashboardOptionError::AlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutDeliverabilityDashboardOptionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDeliverabilityDashboardOptionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDeliverabilityDashboardOptionError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutDeliverabilityDashboardOptionError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDeliverabilityDashboardOptionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDeliverabilityDashboardOptionError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutDeliverabilityDashboardOptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3931,12 +4410,16 @@ impl fmt::Display for PutDeliverabilityDashboardOptionError {
             PutDeliverabilityDashboardOptionError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutDeliverabilityDashboardOptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutDeliverabilityDashboardOptionError {}
 /// Errors returned by PutEmailIdentityDkimAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutEmailIdentityDkimAttributesError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3944,6 +4427,8 @@ pub enum PutEmailIdentityDkimAttributesError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutEmailIdentityDkimAttributesError {
@@ -3953,22 +4438,30 @@ impl PutEmailIdentityDkimAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutEmailIdentityDkimAttributesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEmailIdentityDkimAttributesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEmailIdentityDkimAttributesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEmailIdentityDkimAttributesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEmailIdentityDkimAttributesError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutEmailIdentityDkimAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3983,12 +4476,16 @@ impl fmt::Display for PutEmailIdentityDkimAttributesError {
             PutEmailIdentityDkimAttributesError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutEmailIdentityDkimAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutEmailIdentityDkimAttributesError {}
 /// Errors returned by PutEmailIdentityDkimSigningAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutEmailIdentityDkimSigningAttributesError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -3996,6 +4493,8 @@ pub enum PutEmailIdentityDkimSigningAttributesError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutEmailIdentityDkimSigningAttributesError {
@@ -4005,22 +4504,30 @@ impl PutEmailIdentityDkimSigningAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEmailIdentityDkimSigningAttributesError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEmailIdentityDkimSigningAttributesError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEmailIdentityDkimSigningAttributesError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutEmailIdentityDkimSigningAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4039,12 +4546,16 @@ impl fmt::Display for PutEmailIdentityDkimSigningAttributesError {
             PutEmailIdentityDkimSigningAttributesError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutEmailIdentityDkimSigningAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutEmailIdentityDkimSigningAttributesError {}
 /// Errors returned by PutEmailIdentityFeedbackAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutEmailIdentityFeedbackAttributesError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -4052,6 +4563,8 @@ pub enum PutEmailIdentityFeedbackAttributesError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutEmailIdentityFeedbackAttributesError {
@@ -4061,22 +4574,30 @@ impl PutEmailIdentityFeedbackAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEmailIdentityFeedbackAttributesError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEmailIdentityFeedbackAttributesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEmailIdentityFeedbackAttributesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEmailIdentityFeedbackAttributesError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutEmailIdentityFeedbackAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4093,12 +4614,16 @@ impl fmt::Display for PutEmailIdentityFeedbackAttributesError {
             PutEmailIdentityFeedbackAttributesError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutEmailIdentityFeedbackAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutEmailIdentityFeedbackAttributesError {}
 /// Errors returned by PutEmailIdentityMailFromAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutEmailIdentityMailFromAttributesError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -4106,6 +4631,8 @@ pub enum PutEmailIdentityMailFromAttributesError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutEmailIdentityMailFromAttributesError {
@@ -4115,22 +4642,30 @@ impl PutEmailIdentityMailFromAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEmailIdentityMailFromAttributesError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEmailIdentityMailFromAttributesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEmailIdentityMailFromAttributesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEmailIdentityMailFromAttributesError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutEmailIdentityMailFromAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4147,17 +4682,23 @@ impl fmt::Display for PutEmailIdentityMailFromAttributesError {
             PutEmailIdentityMailFromAttributesError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutEmailIdentityMailFromAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutEmailIdentityMailFromAttributesError {}
 /// Errors returned by PutSuppressedDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutSuppressedDestinationError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutSuppressedDestinationError {
@@ -4165,15 +4706,19 @@ impl PutSuppressedDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutSuppressedDestinationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        PutSuppressedDestinationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutSuppressedDestinationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutSuppressedDestinationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutSuppressedDestinationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4185,12 +4730,14 @@ impl fmt::Display for PutSuppressedDestinationError {
         match *self {
             PutSuppressedDestinationError::BadRequest(ref cause) => write!(f, "{}", cause),
             PutSuppressedDestinationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            PutSuppressedDestinationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutSuppressedDestinationError {}
 /// Errors returned by SendEmail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendEmailError {
     /// <p>The message can't be sent because the account's ability to send email has been permanently restricted.</p>
     AccountSuspended(String),
@@ -4208,6 +4755,8 @@ pub enum SendEmailError {
     SendingPaused(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendEmailError {
@@ -4215,31 +4764,34 @@ impl SendEmailError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountSuspendedException" => {
-                    return RusotoError::Service(SendEmailError::AccountSuspended(err.msg))
+                    return RusotoError::service(SendEmailError::AccountSuspended(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(SendEmailError::BadRequest(err.msg))
+                    return RusotoError::service(SendEmailError::BadRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SendEmailError::LimitExceeded(err.msg))
+                    return RusotoError::service(SendEmailError::LimitExceeded(err.msg), &res)
                 }
                 "MailFromDomainNotVerifiedException" => {
-                    return RusotoError::Service(SendEmailError::MailFromDomainNotVerified(err.msg))
+                    return RusotoError::service(
+                        SendEmailError::MailFromDomainNotVerified(err.msg),
+                        &res,
+                    )
                 }
                 "MessageRejected" => {
-                    return RusotoError::Service(SendEmailError::MessageRejected(err.msg))
+                    return RusotoError::service(SendEmailError::MessageRejected(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SendEmailError::NotFound(err.msg))
+                    return RusotoError::service(SendEmailError::NotFound(err.msg), &res)
                 }
                 "SendingPausedException" => {
-                    return RusotoError::Service(SendEmailError::SendingPaused(err.msg))
+                    return RusotoError::service(SendEmailError::SendingPaused(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SendEmailError::TooManyRequests(err.msg))
+                    return RusotoError::service(SendEmailError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendEmailError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4257,12 +4809,14 @@ impl fmt::Display for SendEmailError {
             SendEmailError::NotFound(ref cause) => write!(f, "{}", cause),
             SendEmailError::SendingPaused(ref cause) => write!(f, "{}", cause),
             SendEmailError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            SendEmailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendEmailError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -4272,6 +4826,8 @@ pub enum TagResourceError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4279,19 +4835,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4305,12 +4864,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -4320,6 +4881,8 @@ pub enum UntagResourceError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4327,21 +4890,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4355,12 +4919,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigurationSetEventDestinationError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -4368,6 +4934,8 @@ pub enum UpdateConfigurationSetEventDestinationError {
     NotFound(String),
     /// <p>Too many requests have been made to the operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigurationSetEventDestinationError {
@@ -4377,22 +4945,30 @@ impl UpdateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateConfigurationSetEventDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4411,6 +4987,9 @@ impl fmt::Display for UpdateConfigurationSetEventDestinationError {
             UpdateConfigurationSetEventDestinationError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateConfigurationSetEventDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -4785,6 +5364,7 @@ impl SesV2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SesV2Client {
         SesV2Client {
             client: Client::shared(),
@@ -4810,373 +5390,752 @@ impl SesV2Client {
     pub fn new_with_client(client: Client, region: region::Region) -> SesV2Client {
         SesV2Client { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SesV2Client> {
+        rusoto_core::ClientBuilder::new(SesV2Client::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SesV2Client {
+        SesV2Client {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SesV2 for SesV2Client {
-    /// <p>Create a configuration set. <i>Configuration sets</i> are groups of rules that you can apply to the emails that you send. You apply a configuration set to an email by specifying the name of the configuration set when you call the Amazon SES API v2. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email. </p>
-    async fn create_configuration_set(
+impl SesV2Client {
+    /// Calls `create_configuration_set`, also returning the metadata of the response.
+    pub async fn create_configuration_set_with_metadata(
         &self,
         input: CreateConfigurationSetRequest,
-    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
-        let request_uri = "/v2/email/configuration-sets";
-
-        let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("email".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (CreateConfigurationSetResponse, ResponseMetadata),
+        RusotoError<CreateConfigurationSetError>,
+    > {
+        let request = self.build_create_configuration_set_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateConfigurationSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateConfigurationSetError::from_response(response))
         }
     }
 
-    /// <p>Create an event destination. <i>Events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p> <p>A single configuration set can include more than one event destination.</p>
-    async fn create_configuration_set_event_destination(
+    /// Presigns the request `create_configuration_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration_set(
         &self,
-        input: CreateConfigurationSetEventDestinationRequest,
-    ) -> Result<
-        CreateConfigurationSetEventDestinationResponse,
-        RusotoError<CreateConfigurationSetEventDestinationError>,
-    > {
-        let request_uri = format!(
-            "/v2/email/configuration-sets/{configuration_set_name}/event-destinations",
-            configuration_set_name = input.configuration_set_name
-        );
+        presigner: &rusoto_core::Presigner,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_set_request(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v2/email/configuration-sets";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_configuration_set_event_destination`, also returning the metadata of the response.
+    pub async fn create_configuration_set_event_destination_with_metadata(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        (
+            CreateConfigurationSetEventDestinationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        let request = self.build_create_configuration_set_event_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateConfigurationSetEventDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateConfigurationSetEventDestinationError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Create a new pool of dedicated IP addresses. A pool can include one or more dedicated IP addresses that are associated with your AWS account. You can associate a pool with a configuration set. When you send an email that uses that configuration set, the message is sent from one of the addresses in the associated pool.</p>
-    async fn create_dedicated_ip_pool(
+    /// Presigns the request `create_configuration_set_event_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration_set_event_destination(
         &self,
-        input: CreateDedicatedIpPoolRequest,
-    ) -> Result<CreateDedicatedIpPoolResponse, RusotoError<CreateDedicatedIpPoolError>> {
-        let request_uri = "/v2/email/dedicated-ip-pools";
+        presigner: &rusoto_core::Presigner,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_set_event_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_set_event_destination_request(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/v2/email/configuration-sets/{configuration_set_name}/event-destinations",
+            configuration_set_name = input.configuration_set_name
+        );
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_dedicated_ip_pool`, also returning the metadata of the response.
+    pub async fn create_dedicated_ip_pool_with_metadata(
+        &self,
+        input: CreateDedicatedIpPoolRequest,
+    ) -> Result<
+        (CreateDedicatedIpPoolResponse, ResponseMetadata),
+        RusotoError<CreateDedicatedIpPoolError>,
+    > {
+        let request = self.build_create_dedicated_ip_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDedicatedIpPoolResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateDedicatedIpPoolError::from_response(response))
         }
     }
 
-    /// <p>Create a new predictive inbox placement test. Predictive inbox placement tests can help you predict how your messages will be handled by various email providers around the world. When you perform a predictive inbox placement test, you provide a sample message that contains the content that you plan to send to your customers. Amazon SES then sends that message to special email addresses spread across several major email providers. After about 24 hours, the test is complete, and you can use the <code>GetDeliverabilityTestReport</code> operation to view the results of the test.</p>
-    async fn create_deliverability_test_report(
+    /// Presigns the request `create_dedicated_ip_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dedicated_ip_pool(
         &self,
-        input: CreateDeliverabilityTestReportRequest,
-    ) -> Result<
-        CreateDeliverabilityTestReportResponse,
-        RusotoError<CreateDeliverabilityTestReportError>,
-    > {
-        let request_uri = "/v2/email/deliverability-dashboard/test";
+        presigner: &rusoto_core::Presigner,
+        input: CreateDedicatedIpPoolRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dedicated_ip_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dedicated_ip_pool_request(
+        &self,
+        input: CreateDedicatedIpPoolRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v2/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_deliverability_test_report`, also returning the metadata of the response.
+    pub async fn create_deliverability_test_report_with_metadata(
+        &self,
+        input: CreateDeliverabilityTestReportRequest,
+    ) -> Result<
+        (CreateDeliverabilityTestReportResponse, ResponseMetadata),
+        RusotoError<CreateDeliverabilityTestReportError>,
+    > {
+        let request = self.build_create_deliverability_test_report_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateDeliverabilityTestReportResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateDeliverabilityTestReportError::from_response(response))
         }
     }
 
-    /// <p>Starts the process of verifying an email identity. An <i>identity</i> is an email address or domain that you use when you send email. Before you can use an identity to send email, you first have to verify it. By verifying an identity, you demonstrate that you're the owner of the identity, and that you've given Amazon SES API v2 permission to send email from the identity.</p> <p>When you verify an email address, Amazon SES sends an email to the address. Your email address is verified as soon as you follow the link in the verification email. </p> <p>When you verify a domain without specifying the <code>DkimSigningAttributes</code> object, this operation provides a set of DKIM tokens. You can convert these tokens into CNAME records, which you then add to the DNS configuration for your domain. Your domain is verified when Amazon SES detects these records in the DNS configuration for your domain. This verification method is known as <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Easy DKIM</a>.</p> <p>Alternatively, you can perform the verification process by providing your own public-private key pair. This verification method is known as Bring Your Own DKIM (BYODKIM). To use BYODKIM, your call to the <code>CreateEmailIdentity</code> operation has to include the <code>DkimSigningAttributes</code> object. When you specify this object, you provide a selector (a component of the DNS record name that identifies the public key that you want to use for DKIM authentication) and a private key.</p>
-    async fn create_email_identity(
+    /// Presigns the request `create_deliverability_test_report` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deliverability_test_report(
         &self,
-        input: CreateEmailIdentityRequest,
-    ) -> Result<CreateEmailIdentityResponse, RusotoError<CreateEmailIdentityError>> {
-        let request_uri = "/v2/email/identities";
+        presigner: &rusoto_core::Presigner,
+        input: CreateDeliverabilityTestReportRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deliverability_test_report_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deliverability_test_report_request(
+        &self,
+        input: CreateDeliverabilityTestReportRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v2/email/deliverability-dashboard/test";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDeliverabilityTestReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_email_identity`, also returning the metadata of the response.
+    pub async fn create_email_identity_with_metadata(
+        &self,
+        input: CreateEmailIdentityRequest,
+    ) -> Result<
+        (CreateEmailIdentityResponse, ResponseMetadata),
+        RusotoError<CreateEmailIdentityError>,
+    > {
+        let request = self.build_create_email_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateEmailIdentityResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateEmailIdentityError::from_response(response))
         }
     }
 
-    /// <p>Delete an existing configuration set.</p> <p> <i>Configuration sets</i> are groups of rules that you can apply to the emails you send. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email.</p>
-    async fn delete_configuration_set(
+    /// Presigns the request `create_email_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_email_identity(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateEmailIdentityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_email_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_email_identity_request(
+        &self,
+        input: CreateEmailIdentityRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v2/email/identities";
+
+        let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateEmailIdentity");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("email".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `delete_configuration_set`, also returning the metadata of the response.
+    pub async fn delete_configuration_set_with_metadata(
         &self,
         input: DeleteConfigurationSetRequest,
-    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+    ) -> Result<
+        (DeleteConfigurationSetResponse, ResponseMetadata),
+        RusotoError<DeleteConfigurationSetError>,
+    > {
+        let request = self.build_delete_configuration_set_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteConfigurationSetResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteConfigurationSetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_configuration_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_set(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_set_request(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/configuration-sets/{configuration_set_name}",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `delete_configuration_set_event_destination`, also returning the metadata of the response.
+    pub async fn delete_configuration_set_event_destination_with_metadata(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        (
+            DeleteConfigurationSetEventDestinationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        let request = self.build_delete_configuration_set_event_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteConfigurationSetResponse, _>()?;
+                .deserialize::<DeleteConfigurationSetEventDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConfigurationSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteConfigurationSetEventDestinationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Delete an event destination.</p> <p> <i>Events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p>
-    async fn delete_configuration_set_event_destination(
+    /// Presigns the request `delete_configuration_set_event_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_set_event_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConfigurationSetEventDestinationRequest,
-    ) -> Result<
-        DeleteConfigurationSetEventDestinationResponse,
-        RusotoError<DeleteConfigurationSetEventDestinationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_set_event_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_set_event_destination_request(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v2/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `delete_dedicated_ip_pool`, also returning the metadata of the response.
+    pub async fn delete_dedicated_ip_pool_with_metadata(
+        &self,
+        input: DeleteDedicatedIpPoolRequest,
+    ) -> Result<
+        (DeleteDedicatedIpPoolResponse, ResponseMetadata),
+        RusotoError<DeleteDedicatedIpPoolError>,
+    > {
+        let request = self.build_delete_dedicated_ip_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteConfigurationSetEventDestinationResponse, _>()?;
+                .deserialize::<DeleteDedicatedIpPoolResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConfigurationSetEventDestinationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDedicatedIpPoolError::from_response(response))
         }
     }
 
-    /// <p>Delete a dedicated IP pool.</p>
-    async fn delete_dedicated_ip_pool(
+    /// Presigns the request `delete_dedicated_ip_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dedicated_ip_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDedicatedIpPoolRequest,
-    ) -> Result<DeleteDedicatedIpPoolResponse, RusotoError<DeleteDedicatedIpPoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dedicated_ip_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dedicated_ip_pool_request(
+        &self,
+        input: DeleteDedicatedIpPoolRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/dedicated-ip-pools/{pool_name}",
             pool_name = input.pool_name
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `delete_email_identity`, also returning the metadata of the response.
+    pub async fn delete_email_identity_with_metadata(
+        &self,
+        input: DeleteEmailIdentityRequest,
+    ) -> Result<
+        (DeleteEmailIdentityResponse, ResponseMetadata),
+        RusotoError<DeleteEmailIdentityError>,
+    > {
+        let request = self.build_delete_email_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDedicatedIpPoolResponse, _>()?;
+                .deserialize::<DeleteEmailIdentityResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDedicatedIpPoolError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteEmailIdentityError::from_response(response))
         }
     }
 
-    /// <p>Deletes an email identity. An identity can be either an email address or a domain name.</p>
-    async fn delete_email_identity(
+    /// Presigns the request `delete_email_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_email_identity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEmailIdentityRequest,
-    ) -> Result<DeleteEmailIdentityResponse, RusotoError<DeleteEmailIdentityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_email_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_email_identity_request(
+        &self,
+        input: DeleteEmailIdentityRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/identities/{email_identity}",
             email_identity = input.email_identity
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `delete_suppressed_destination`, also returning the metadata of the response.
+    pub async fn delete_suppressed_destination_with_metadata(
+        &self,
+        input: DeleteSuppressedDestinationRequest,
+    ) -> Result<
+        (DeleteSuppressedDestinationResponse, ResponseMetadata),
+        RusotoError<DeleteSuppressedDestinationError>,
+    > {
+        let request = self.build_delete_suppressed_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteEmailIdentityResponse, _>()?;
+                .deserialize::<DeleteSuppressedDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEmailIdentityError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteSuppressedDestinationError::from_response(response))
         }
     }
 
-    /// <p>Removes an email address from the suppression list for your account.</p>
-    async fn delete_suppressed_destination(
+    /// Presigns the request `delete_suppressed_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_suppressed_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSuppressedDestinationRequest,
-    ) -> Result<DeleteSuppressedDestinationResponse, RusotoError<DeleteSuppressedDestinationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_suppressed_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_suppressed_destination_request(
+        &self,
+        input: DeleteSuppressedDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/suppression/addresses/{email_address}",
             email_address = input.email_address
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteSuppressedDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `get_account`, also returning the metadata of the response.
+    pub async fn get_account_with_metadata(
+        &self,
+    ) -> Result<(GetAccountResponse, ResponseMetadata), RusotoError<GetAccountError>> {
+        let request = self.build_get_account_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSuppressedDestinationResponse, _>()?;
+                .deserialize::<GetAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSuppressedDestinationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAccountError::from_response(response))
         }
     }
 
-    /// <p>Obtain information about the email-sending status and capabilities of your Amazon SES account in the current AWS Region.</p>
-    async fn get_account(&self) -> Result<GetAccountResponse, RusotoError<GetAccountError>> {
+    /// Presigns the request `get_account` would send with `presigner` instead of sending it.
+    pub async fn presign_get_account(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_account_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_account_request(&self) -> SignedRequest {
         let request_uri = "/v2/email/account";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `get_blacklist_reports`, also returning the metadata of the response.
+    pub async fn get_blacklist_reports_with_metadata(
+        &self,
+        input: GetBlacklistReportsRequest,
+    ) -> Result<
+        (GetBlacklistReportsResponse, ResponseMetadata),
+        RusotoError<GetBlacklistReportsError>,
+    > {
+        let request = self.build_get_blacklist_reports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAccountResponse, _>()?;
+                .deserialize::<GetBlacklistReportsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBlacklistReportsError::from_response(response))
         }
     }
 
-    /// <p>Retrieve a list of the blacklists that your dedicated IP addresses appear on.</p>
-    async fn get_blacklist_reports(
+    /// Presigns the request `get_blacklist_reports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_blacklist_reports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBlacklistReportsRequest,
-    ) -> Result<GetBlacklistReportsResponse, RusotoError<GetBlacklistReportsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_blacklist_reports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_blacklist_reports_request(
+        &self,
+        input: GetBlacklistReportsRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/deliverability-dashboard/blacklist-report";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetBlacklistReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5187,129 +6146,238 @@ impl SesV2 for SesV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_configuration_set`, also returning the metadata of the response.
+    pub async fn get_configuration_set_with_metadata(
+        &self,
+        input: GetConfigurationSetRequest,
+    ) -> Result<
+        (GetConfigurationSetResponse, ResponseMetadata),
+        RusotoError<GetConfigurationSetError>,
+    > {
+        let request = self.build_get_configuration_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBlacklistReportsResponse, _>()?;
+                .deserialize::<GetConfigurationSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBlacklistReportsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetConfigurationSetError::from_response(response))
         }
     }
 
-    /// <p>Get information about an existing configuration set, including the dedicated IP pool that it's associated with, whether or not it's enabled for sending email, and more.</p> <p> <i>Configuration sets</i> are groups of rules that you can apply to the emails you send. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email.</p>
-    async fn get_configuration_set(
+    /// Presigns the request `get_configuration_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_configuration_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConfigurationSetRequest,
-    ) -> Result<GetConfigurationSetResponse, RusotoError<GetConfigurationSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_configuration_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_configuration_set_request(
+        &self,
+        input: GetConfigurationSetRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/configuration-sets/{configuration_set_name}",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `get_configuration_set_event_destinations`, also returning the metadata of the response.
+    pub async fn get_configuration_set_event_destinations_with_metadata(
+        &self,
+        input: GetConfigurationSetEventDestinationsRequest,
+    ) -> Result<
+        (
+            GetConfigurationSetEventDestinationsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetConfigurationSetEventDestinationsError>,
+    > {
+        let request = self.build_get_configuration_set_event_destinations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConfigurationSetResponse, _>()?;
+                .deserialize::<GetConfigurationSetEventDestinationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConfigurationSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetConfigurationSetEventDestinationsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieve a list of event destinations that are associated with a configuration set.</p> <p> <i>Events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p>
-    async fn get_configuration_set_event_destinations(
+    /// Presigns the request `get_configuration_set_event_destinations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_configuration_set_event_destinations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConfigurationSetEventDestinationsRequest,
-    ) -> Result<
-        GetConfigurationSetEventDestinationsResponse,
-        RusotoError<GetConfigurationSetEventDestinationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_configuration_set_event_destinations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_configuration_set_event_destinations_request(
+        &self,
+        input: GetConfigurationSetEventDestinationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/configuration-sets/{configuration_set_name}/event-destinations",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSetEventDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `get_dedicated_ip`, also returning the metadata of the response.
+    pub async fn get_dedicated_ip_with_metadata(
+        &self,
+        input: GetDedicatedIpRequest,
+    ) -> Result<(GetDedicatedIpResponse, ResponseMetadata), RusotoError<GetDedicatedIpError>> {
+        let request = self.build_get_dedicated_ip_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConfigurationSetEventDestinationsResponse, _>()?;
+                .deserialize::<GetDedicatedIpResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConfigurationSetEventDestinationsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDedicatedIpError::from_response(response))
         }
     }
 
-    /// <p>Get information about a dedicated IP address, including the name of the dedicated IP pool that it's associated with, as well information about the automatic warm-up process for the address.</p>
-    async fn get_dedicated_ip(
+    /// Presigns the request `get_dedicated_ip` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dedicated_ip(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDedicatedIpRequest,
-    ) -> Result<GetDedicatedIpResponse, RusotoError<GetDedicatedIpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dedicated_ip_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dedicated_ip_request(&self, input: GetDedicatedIpRequest) -> SignedRequest {
         let request_uri = format!("/v2/email/dedicated-ips/{ip}", ip = input.ip);
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `get_dedicated_ips`, also returning the metadata of the response.
+    pub async fn get_dedicated_ips_with_metadata(
+        &self,
+        input: GetDedicatedIpsRequest,
+    ) -> Result<(GetDedicatedIpsResponse, ResponseMetadata), RusotoError<GetDedicatedIpsError>>
+    {
+        let request = self.build_get_dedicated_ips_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDedicatedIpResponse, _>()?;
+                .deserialize::<GetDedicatedIpsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDedicatedIpError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDedicatedIpsError::from_response(response))
         }
     }
 
-    /// <p>List the dedicated IP addresses that are associated with your AWS account.</p>
-    async fn get_dedicated_ips(
+    /// Presigns the request `get_dedicated_ips` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dedicated_ips(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDedicatedIpsRequest,
-    ) -> Result<GetDedicatedIpsResponse, RusotoError<GetDedicatedIpsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dedicated_ips_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dedicated_ips_request(&self, input: GetDedicatedIpsRequest) -> SignedRequest {
         let request_uri = "/v2/email/dedicated-ips";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIps");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5326,138 +6394,246 @@ impl SesV2 for SesV2Client {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDedicatedIpsResponse, _>()?;
-
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDedicatedIpsError::from_response(response))
-        }
+        request
     }
 
-    /// <p>Retrieve information about the status of the Deliverability dashboard for your account. When the Deliverability dashboard is enabled, you gain access to reputation, deliverability, and other metrics for the domains that you use to send email. You also gain the ability to perform predictive inbox placement tests.</p> <p>When you use the Deliverability dashboard, you pay a monthly subscription charge, in addition to any other fees that you accrue by using Amazon SES and other AWS services. For more information about the features and cost of a Deliverability dashboard subscription, see <a href="http://aws.amazon.com/ses/pricing/">Amazon SES Pricing</a>.</p>
-    async fn get_deliverability_dashboard_options(
+    /// Calls `get_deliverability_dashboard_options`, also returning the metadata of the response.
+    pub async fn get_deliverability_dashboard_options_with_metadata(
         &self,
     ) -> Result<
-        GetDeliverabilityDashboardOptionsResponse,
+        (GetDeliverabilityDashboardOptionsResponse, ResponseMetadata),
         RusotoError<GetDeliverabilityDashboardOptionsError>,
     > {
-        let request_uri = "/v2/email/deliverability-dashboard";
-
-        let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("email".to_string());
+        let request = self.build_get_deliverability_dashboard_options_request();
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeliverabilityDashboardOptionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetDeliverabilityDashboardOptionsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Retrieve the results of a predictive inbox placement test.</p>
-    async fn get_deliverability_test_report(
+    /// Presigns the request `get_deliverability_dashboard_options` would send with `presigner` instead of sending it.
+    pub async fn presign_get_deliverability_dashboard_options(
         &self,
-        input: GetDeliverabilityTestReportRequest,
-    ) -> Result<GetDeliverabilityTestReportResponse, RusotoError<GetDeliverabilityTestReportError>>
-    {
-        let request_uri = format!(
-            "/v2/email/deliverability-dashboard/test-reports/{report_id}",
-            report_id = input.report_id
-        );
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deliverability_dashboard_options_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deliverability_dashboard_options_request(&self) -> SignedRequest {
+        let request_uri = "/v2/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityDashboardOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `get_deliverability_test_report`, also returning the metadata of the response.
+    pub async fn get_deliverability_test_report_with_metadata(
+        &self,
+        input: GetDeliverabilityTestReportRequest,
+    ) -> Result<
+        (GetDeliverabilityTestReportResponse, ResponseMetadata),
+        RusotoError<GetDeliverabilityTestReportError>,
+    > {
+        let request = self.build_get_deliverability_test_report_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetDeliverabilityTestReportResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetDeliverabilityTestReportError::from_response(response))
         }
     }
 
-    /// <p>Retrieve all the deliverability data for a specific campaign. This data is available for a campaign only if the campaign sent email by using a domain that the Deliverability dashboard is enabled for.</p>
-    async fn get_domain_deliverability_campaign(
+    /// Presigns the request `get_deliverability_test_report` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deliverability_test_report(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetDeliverabilityTestReportRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deliverability_test_report_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deliverability_test_report_request(
+        &self,
+        input: GetDeliverabilityTestReportRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/v2/email/deliverability-dashboard/test-reports/{report_id}",
+            report_id = input.report_id
+        );
+
+        let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityTestReport");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("email".to_string());
+
+        request
+    }
+
+    /// Calls `get_domain_deliverability_campaign`, also returning the metadata of the response.
+    pub async fn get_domain_deliverability_campaign_with_metadata(
         &self,
         input: GetDomainDeliverabilityCampaignRequest,
     ) -> Result<
-        GetDomainDeliverabilityCampaignResponse,
+        (GetDomainDeliverabilityCampaignResponse, ResponseMetadata),
         RusotoError<GetDomainDeliverabilityCampaignError>,
     > {
+        let request = self.build_get_domain_deliverability_campaign_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDomainDeliverabilityCampaignResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDomainDeliverabilityCampaignError::from_response(
+                response,
+            ))
+        }
+    }
+
+    /// Presigns the request `get_domain_deliverability_campaign` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain_deliverability_campaign(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetDomainDeliverabilityCampaignRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_deliverability_campaign_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_deliverability_campaign_request(
+        &self,
+        input: GetDomainDeliverabilityCampaignRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/deliverability-dashboard/campaigns/{campaign_id}",
             campaign_id = input.campaign_id
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainDeliverabilityCampaign");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `get_domain_statistics_report`, also returning the metadata of the response.
+    pub async fn get_domain_statistics_report_with_metadata(
+        &self,
+        input: GetDomainStatisticsReportRequest,
+    ) -> Result<
+        (GetDomainStatisticsReportResponse, ResponseMetadata),
+        RusotoError<GetDomainStatisticsReportError>,
+    > {
+        let request = self.build_get_domain_statistics_report_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDomainDeliverabilityCampaignResponse, _>()?;
+                .deserialize::<GetDomainStatisticsReportResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainDeliverabilityCampaignError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDomainStatisticsReportError::from_response(response))
         }
     }
 
-    /// <p>Retrieve inbox placement and engagement rates for the domains that you use to send email.</p>
-    async fn get_domain_statistics_report(
+    /// Presigns the request `get_domain_statistics_report` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain_statistics_report(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainStatisticsReportRequest,
-    ) -> Result<GetDomainStatisticsReportResponse, RusotoError<GetDomainStatisticsReportError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_statistics_report_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_statistics_report_request(
+        &self,
+        input: GetDomainStatisticsReportRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/deliverability-dashboard/statistics-report/{domain}",
             domain = input.domain
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainStatisticsReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5467,95 +6643,180 @@ impl SesV2 for SesV2Client {
         params.put("StartDate", &input.start_date);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_email_identity`, also returning the metadata of the response.
+    pub async fn get_email_identity_with_metadata(
+        &self,
+        input: GetEmailIdentityRequest,
+    ) -> Result<(GetEmailIdentityResponse, ResponseMetadata), RusotoError<GetEmailIdentityError>>
+    {
+        let request = self.build_get_email_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDomainStatisticsReportResponse, _>()?;
+                .deserialize::<GetEmailIdentityResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainStatisticsReportError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetEmailIdentityError::from_response(response))
         }
     }
 
-    /// <p>Provides information about a specific identity, including the identity's verification status, its DKIM authentication status, and its custom Mail-From settings.</p>
-    async fn get_email_identity(
+    /// Presigns the request `get_email_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_email_identity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEmailIdentityRequest,
-    ) -> Result<GetEmailIdentityResponse, RusotoError<GetEmailIdentityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_email_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_email_identity_request(&self, input: GetEmailIdentityRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/identities/{email_identity}",
             email_identity = input.email_identity
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `get_suppressed_destination`, also returning the metadata of the response.
+    pub async fn get_suppressed_destination_with_metadata(
+        &self,
+        input: GetSuppressedDestinationRequest,
+    ) -> Result<
+        (GetSuppressedDestinationResponse, ResponseMetadata),
+        RusotoError<GetSuppressedDestinationError>,
+    > {
+        let request = self.build_get_suppressed_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetEmailIdentityResponse, _>()?;
+                .deserialize::<GetSuppressedDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEmailIdentityError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSuppressedDestinationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a specific email address that's on the suppression list for your account.</p>
-    async fn get_suppressed_destination(
+    /// Presigns the request `get_suppressed_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_suppressed_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSuppressedDestinationRequest,
-    ) -> Result<GetSuppressedDestinationResponse, RusotoError<GetSuppressedDestinationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_suppressed_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_suppressed_destination_request(
+        &self,
+        input: GetSuppressedDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/suppression/addresses/{email_address}",
             email_address = input.email_address
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetSuppressedDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
 
+        request
+    }
+
+    /// Calls `list_configuration_sets`, also returning the metadata of the response.
+    pub async fn list_configuration_sets_with_metadata(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<
+        (ListConfigurationSetsResponse, ResponseMetadata),
+        RusotoError<ListConfigurationSetsError>,
+    > {
+        let request = self.build_list_configuration_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSuppressedDestinationResponse, _>()?;
+                .deserialize::<ListConfigurationSetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSuppressedDestinationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConfigurationSetsError::from_response(response))
         }
     }
 
-    /// <p>List all of the configuration sets associated with your account in the current region.</p> <p> <i>Configuration sets</i> are groups of rules that you can apply to the emails you send. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email.</p>
-    async fn list_configuration_sets(
+    /// Presigns the request `list_configuration_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configuration_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigurationSetsRequest,
-    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configuration_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configuration_sets_request(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/configuration-sets";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListConfigurationSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5569,31 +6830,61 @@ impl SesV2 for SesV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_dedicated_ip_pools`, also returning the metadata of the response.
+    pub async fn list_dedicated_ip_pools_with_metadata(
+        &self,
+        input: ListDedicatedIpPoolsRequest,
+    ) -> Result<
+        (ListDedicatedIpPoolsResponse, ResponseMetadata),
+        RusotoError<ListDedicatedIpPoolsError>,
+    > {
+        let request = self.build_list_dedicated_ip_pools_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConfigurationSetsResponse, _>()?;
+                .deserialize::<ListDedicatedIpPoolsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigurationSetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDedicatedIpPoolsError::from_response(response))
         }
     }
 
-    /// <p>List all of the dedicated IP pools that exist in your AWS account in the current Region.</p>
-    async fn list_dedicated_ip_pools(
+    /// Presigns the request `list_dedicated_ip_pools` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dedicated_ip_pools(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDedicatedIpPoolsRequest,
-    ) -> Result<ListDedicatedIpPoolsResponse, RusotoError<ListDedicatedIpPoolsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dedicated_ip_pools_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dedicated_ip_pools_request(
+        &self,
+        input: ListDedicatedIpPoolsRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDedicatedIpPools");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5607,34 +6898,61 @@ impl SesV2 for SesV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_deliverability_test_reports`, also returning the metadata of the response.
+    pub async fn list_deliverability_test_reports_with_metadata(
+        &self,
+        input: ListDeliverabilityTestReportsRequest,
+    ) -> Result<
+        (ListDeliverabilityTestReportsResponse, ResponseMetadata),
+        RusotoError<ListDeliverabilityTestReportsError>,
+    > {
+        let request = self.build_list_deliverability_test_reports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDedicatedIpPoolsResponse, _>()?;
+                .deserialize::<ListDeliverabilityTestReportsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDedicatedIpPoolsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDeliverabilityTestReportsError::from_response(response))
         }
     }
 
-    /// <p>Show a list of the predictive inbox placement tests that you've performed, regardless of their statuses. For predictive inbox placement tests that are complete, you can use the <code>GetDeliverabilityTestReport</code> operation to view the results.</p>
-    async fn list_deliverability_test_reports(
+    /// Presigns the request `list_deliverability_test_reports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deliverability_test_reports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeliverabilityTestReportsRequest,
-    ) -> Result<
-        ListDeliverabilityTestReportsResponse,
-        RusotoError<ListDeliverabilityTestReportsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deliverability_test_reports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deliverability_test_reports_request(
+        &self,
+        input: ListDeliverabilityTestReportsRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/deliverability-dashboard/test-reports";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDeliverabilityTestReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5648,37 +6966,66 @@ impl SesV2 for SesV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_domain_deliverability_campaigns`, also returning the metadata of the response.
+    pub async fn list_domain_deliverability_campaigns_with_metadata(
+        &self,
+        input: ListDomainDeliverabilityCampaignsRequest,
+    ) -> Result<
+        (ListDomainDeliverabilityCampaignsResponse, ResponseMetadata),
+        RusotoError<ListDomainDeliverabilityCampaignsError>,
+    > {
+        let request = self.build_list_domain_deliverability_campaigns_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeliverabilityTestReportsResponse, _>()?;
+                .deserialize::<ListDomainDeliverabilityCampaignsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeliverabilityTestReportsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDomainDeliverabilityCampaignsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieve deliverability data for all the campaigns that used a specific domain to send email during a specified time range. This data is available for a domain only if you enabled the Deliverability dashboard for the domain.</p>
-    async fn list_domain_deliverability_campaigns(
+    /// Presigns the request `list_domain_deliverability_campaigns` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_domain_deliverability_campaigns(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDomainDeliverabilityCampaignsRequest,
-    ) -> Result<
-        ListDomainDeliverabilityCampaignsResponse,
-        RusotoError<ListDomainDeliverabilityCampaignsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_domain_deliverability_campaigns_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_domain_deliverability_campaigns_request(
+        &self,
+        input: ListDomainDeliverabilityCampaignsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/deliverability-dashboard/domains/{subscribed_domain}/campaigns",
             subscribed_domain = input.subscribed_domain
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDomainDeliverabilityCampaigns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5694,33 +7041,61 @@ impl SesV2 for SesV2Client {
         params.put("StartDate", &input.start_date);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_email_identities`, also returning the metadata of the response.
+    pub async fn list_email_identities_with_metadata(
+        &self,
+        input: ListEmailIdentitiesRequest,
+    ) -> Result<
+        (ListEmailIdentitiesResponse, ResponseMetadata),
+        RusotoError<ListEmailIdentitiesError>,
+    > {
+        let request = self.build_list_email_identities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDomainDeliverabilityCampaignsResponse, _>()?;
+                .deserialize::<ListEmailIdentitiesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDomainDeliverabilityCampaignsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListEmailIdentitiesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all of the email identities that are associated with your AWS account. An identity can be either an email address or a domain. This operation returns identities that are verified as well as those that aren't. This operation returns identities that are associated with Amazon SES and Amazon Pinpoint.</p>
-    async fn list_email_identities(
+    /// Presigns the request `list_email_identities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_email_identities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEmailIdentitiesRequest,
-    ) -> Result<ListEmailIdentitiesResponse, RusotoError<ListEmailIdentitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_email_identities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_email_identities_request(
+        &self,
+        input: ListEmailIdentitiesRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/identities";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListEmailIdentities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5734,32 +7109,61 @@ impl SesV2 for SesV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_suppressed_destinations`, also returning the metadata of the response.
+    pub async fn list_suppressed_destinations_with_metadata(
+        &self,
+        input: ListSuppressedDestinationsRequest,
+    ) -> Result<
+        (ListSuppressedDestinationsResponse, ResponseMetadata),
+        RusotoError<ListSuppressedDestinationsError>,
+    > {
+        let request = self.build_list_suppressed_destinations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEmailIdentitiesResponse, _>()?;
+                .deserialize::<ListSuppressedDestinationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEmailIdentitiesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSuppressedDestinationsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of email addresses that are on the suppression list for your account.</p>
-    async fn list_suppressed_destinations(
+    /// Presigns the request `list_suppressed_destinations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_suppressed_destinations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSuppressedDestinationsRequest,
-    ) -> Result<ListSuppressedDestinationsResponse, RusotoError<ListSuppressedDestinationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_suppressed_destinations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_suppressed_destinations_request(
+        &self,
+        input: ListSuppressedDestinationsRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/suppression/addresses";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListSuppressedDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5784,31 +7188,61 @@ impl SesV2 for SesV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSuppressedDestinationsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSuppressedDestinationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieve a list of the tags (keys and values) that are associated with a specified resource. A <i>tag</i> is a label that you optionally define and associate with a resource. Each tag consists of a required <i>tag key</i> and an optional associated <i>tag value</i>. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/tags";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5817,678 +7251,1202 @@ impl SesV2 for SesV2Client {
         params.put("ResourceArn", &input.resource_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_account_dedicated_ip_warmup_attributes`, also returning the metadata of the response.
+    pub async fn put_account_dedicated_ip_warmup_attributes_with_metadata(
+        &self,
+        input: PutAccountDedicatedIpWarmupAttributesRequest,
+    ) -> Result<
+        (
+            PutAccountDedicatedIpWarmupAttributesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<PutAccountDedicatedIpWarmupAttributesError>,
+    > {
+        let request = self.build_put_account_dedicated_ip_warmup_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<PutAccountDedicatedIpWarmupAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutAccountDedicatedIpWarmupAttributesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Enable or disable the automatic warm-up feature for dedicated IP addresses.</p>
-    async fn put_account_dedicated_ip_warmup_attributes(
+    /// Presigns the request `put_account_dedicated_ip_warmup_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_account_dedicated_ip_warmup_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAccountDedicatedIpWarmupAttributesRequest,
-    ) -> Result<
-        PutAccountDedicatedIpWarmupAttributesResponse,
-        RusotoError<PutAccountDedicatedIpWarmupAttributesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_account_dedicated_ip_warmup_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_account_dedicated_ip_warmup_attributes_request(
+        &self,
+        input: PutAccountDedicatedIpWarmupAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/account/dedicated-ips/warmup";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_account_sending_attributes`, also returning the metadata of the response.
+    pub async fn put_account_sending_attributes_with_metadata(
+        &self,
+        input: PutAccountSendingAttributesRequest,
+    ) -> Result<
+        (PutAccountSendingAttributesResponse, ResponseMetadata),
+        RusotoError<PutAccountSendingAttributesError>,
+    > {
+        let request = self.build_put_account_sending_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutAccountDedicatedIpWarmupAttributesResponse, _>()?;
+                .deserialize::<PutAccountSendingAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAccountDedicatedIpWarmupAttributesError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutAccountSendingAttributesError::from_response(response))
         }
     }
 
-    /// <p>Enable or disable the ability of your account to send email.</p>
-    async fn put_account_sending_attributes(
+    /// Presigns the request `put_account_sending_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_account_sending_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAccountSendingAttributesRequest,
-    ) -> Result<PutAccountSendingAttributesResponse, RusotoError<PutAccountSendingAttributesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_account_sending_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_account_sending_attributes_request(
+        &self,
+        input: PutAccountSendingAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/account/sending";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountSendingAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_account_suppression_attributes`, also returning the metadata of the response.
+    pub async fn put_account_suppression_attributes_with_metadata(
+        &self,
+        input: PutAccountSuppressionAttributesRequest,
+    ) -> Result<
+        (PutAccountSuppressionAttributesResponse, ResponseMetadata),
+        RusotoError<PutAccountSuppressionAttributesError>,
+    > {
+        let request = self.build_put_account_suppression_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutAccountSendingAttributesResponse, _>()?;
+                .deserialize::<PutAccountSuppressionAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAccountSendingAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutAccountSuppressionAttributesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Change the settings for the account-level suppression list.</p>
-    async fn put_account_suppression_attributes(
+    /// Presigns the request `put_account_suppression_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_account_suppression_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAccountSuppressionAttributesRequest,
-    ) -> Result<
-        PutAccountSuppressionAttributesResponse,
-        RusotoError<PutAccountSuppressionAttributesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_account_suppression_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_account_suppression_attributes_request(
+        &self,
+        input: PutAccountSuppressionAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/account/suppression";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountSuppressionAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_configuration_set_delivery_options`, also returning the metadata of the response.
+    pub async fn put_configuration_set_delivery_options_with_metadata(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> Result<
+        (PutConfigurationSetDeliveryOptionsResponse, ResponseMetadata),
+        RusotoError<PutConfigurationSetDeliveryOptionsError>,
+    > {
+        let request = self.build_put_configuration_set_delivery_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutAccountSuppressionAttributesResponse, _>()?;
+                .deserialize::<PutConfigurationSetDeliveryOptionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAccountSuppressionAttributesError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutConfigurationSetDeliveryOptionsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Associate a configuration set with a dedicated IP pool. You can use dedicated IP pools to create groups of dedicated IP addresses for sending specific types of email.</p>
-    async fn put_configuration_set_delivery_options(
+    /// Presigns the request `put_configuration_set_delivery_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_configuration_set_delivery_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConfigurationSetDeliveryOptionsRequest,
-    ) -> Result<
-        PutConfigurationSetDeliveryOptionsResponse,
-        RusotoError<PutConfigurationSetDeliveryOptionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_configuration_set_delivery_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_configuration_set_delivery_options_request(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/configuration-sets/{configuration_set_name}/delivery-options",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetDeliveryOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_configuration_set_reputation_options`, also returning the metadata of the response.
+    pub async fn put_configuration_set_reputation_options_with_metadata(
+        &self,
+        input: PutConfigurationSetReputationOptionsRequest,
+    ) -> Result<
+        (
+            PutConfigurationSetReputationOptionsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<PutConfigurationSetReputationOptionsError>,
+    > {
+        let request = self.build_put_configuration_set_reputation_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutConfigurationSetDeliveryOptionsResponse, _>()?;
+                .deserialize::<PutConfigurationSetReputationOptionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConfigurationSetDeliveryOptionsError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutConfigurationSetReputationOptionsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Enable or disable collection of reputation metrics for emails that you send using a particular configuration set in a specific AWS Region.</p>
-    async fn put_configuration_set_reputation_options(
+    /// Presigns the request `put_configuration_set_reputation_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_configuration_set_reputation_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConfigurationSetReputationOptionsRequest,
-    ) -> Result<
-        PutConfigurationSetReputationOptionsResponse,
-        RusotoError<PutConfigurationSetReputationOptionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_configuration_set_reputation_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_configuration_set_reputation_options_request(
+        &self,
+        input: PutConfigurationSetReputationOptionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/configuration-sets/{configuration_set_name}/reputation-options",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetReputationOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_configuration_set_sending_options`, also returning the metadata of the response.
+    pub async fn put_configuration_set_sending_options_with_metadata(
+        &self,
+        input: PutConfigurationSetSendingOptionsRequest,
+    ) -> Result<
+        (PutConfigurationSetSendingOptionsResponse, ResponseMetadata),
+        RusotoError<PutConfigurationSetSendingOptionsError>,
+    > {
+        let request = self.build_put_configuration_set_sending_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutConfigurationSetReputationOptionsResponse, _>()?;
+                .deserialize::<PutConfigurationSetSendingOptionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConfigurationSetReputationOptionsError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutConfigurationSetSendingOptionsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Enable or disable email sending for messages that use a particular configuration set in a specific AWS Region.</p>
-    async fn put_configuration_set_sending_options(
+    /// Presigns the request `put_configuration_set_sending_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_configuration_set_sending_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConfigurationSetSendingOptionsRequest,
-    ) -> Result<
-        PutConfigurationSetSendingOptionsResponse,
-        RusotoError<PutConfigurationSetSendingOptionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_configuration_set_sending_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_configuration_set_sending_options_request(
+        &self,
+        input: PutConfigurationSetSendingOptionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/configuration-sets/{configuration_set_name}/sending",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetSendingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_configuration_set_suppression_options`, also returning the metadata of the response.
+    pub async fn put_configuration_set_suppression_options_with_metadata(
+        &self,
+        input: PutConfigurationSetSuppressionOptionsRequest,
+    ) -> Result<
+        (
+            PutConfigurationSetSuppressionOptionsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<PutConfigurationSetSuppressionOptionsError>,
+    > {
+        let request = self.build_put_configuration_set_suppression_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutConfigurationSetSendingOptionsResponse, _>()?;
+                .deserialize::<PutConfigurationSetSuppressionOptionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConfigurationSetSendingOptionsError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutConfigurationSetSuppressionOptionsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Specify the account suppression list preferences for a configuration set.</p>
-    async fn put_configuration_set_suppression_options(
+    /// Presigns the request `put_configuration_set_suppression_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_configuration_set_suppression_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConfigurationSetSuppressionOptionsRequest,
-    ) -> Result<
-        PutConfigurationSetSuppressionOptionsResponse,
-        RusotoError<PutConfigurationSetSuppressionOptionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_configuration_set_suppression_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_configuration_set_suppression_options_request(
+        &self,
+        input: PutConfigurationSetSuppressionOptionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/configuration-sets/{configuration_set_name}/suppression-options",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetSuppressionOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_configuration_set_tracking_options`, also returning the metadata of the response.
+    pub async fn put_configuration_set_tracking_options_with_metadata(
+        &self,
+        input: PutConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        (PutConfigurationSetTrackingOptionsResponse, ResponseMetadata),
+        RusotoError<PutConfigurationSetTrackingOptionsError>,
+    > {
+        let request = self.build_put_configuration_set_tracking_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutConfigurationSetSuppressionOptionsResponse, _>()?;
+                .deserialize::<PutConfigurationSetTrackingOptionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConfigurationSetSuppressionOptionsError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutConfigurationSetTrackingOptionsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Specify a custom domain to use for open and click tracking elements in email that you send.</p>
-    async fn put_configuration_set_tracking_options(
+    /// Presigns the request `put_configuration_set_tracking_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_configuration_set_tracking_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConfigurationSetTrackingOptionsRequest,
-    ) -> Result<
-        PutConfigurationSetTrackingOptionsResponse,
-        RusotoError<PutConfigurationSetTrackingOptionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_configuration_set_tracking_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_configuration_set_tracking_options_request(
+        &self,
+        input: PutConfigurationSetTrackingOptionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/configuration-sets/{configuration_set_name}/tracking-options",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetTrackingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_dedicated_ip_in_pool`, also returning the metadata of the response.
+    pub async fn put_dedicated_ip_in_pool_with_metadata(
+        &self,
+        input: PutDedicatedIpInPoolRequest,
+    ) -> Result<
+        (PutDedicatedIpInPoolResponse, ResponseMetadata),
+        RusotoError<PutDedicatedIpInPoolError>,
+    > {
+        let request = self.build_put_dedicated_ip_in_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutConfigurationSetTrackingOptionsResponse, _>()?;
+                .deserialize::<PutDedicatedIpInPoolResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConfigurationSetTrackingOptionsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutDedicatedIpInPoolError::from_response(response))
         }
     }
 
-    /// <p><p>Move a dedicated IP address to an existing dedicated IP pool.</p> <note> <p>The dedicated IP address that you specify must already exist, and must be associated with your AWS account. </p> <p>The dedicated IP pool you specify must already exist. You can create a new pool by using the <code>CreateDedicatedIpPool</code> operation.</p> </note></p>
-    async fn put_dedicated_ip_in_pool(
+    /// Presigns the request `put_dedicated_ip_in_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_dedicated_ip_in_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutDedicatedIpInPoolRequest,
-    ) -> Result<PutDedicatedIpInPoolResponse, RusotoError<PutDedicatedIpInPoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_dedicated_ip_in_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_dedicated_ip_in_pool_request(
+        &self,
+        input: PutDedicatedIpInPoolRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v2/email/dedicated-ips/{ip}/pool", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpInPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_dedicated_ip_warmup_attributes`, also returning the metadata of the response.
+    pub async fn put_dedicated_ip_warmup_attributes_with_metadata(
+        &self,
+        input: PutDedicatedIpWarmupAttributesRequest,
+    ) -> Result<
+        (PutDedicatedIpWarmupAttributesResponse, ResponseMetadata),
+        RusotoError<PutDedicatedIpWarmupAttributesError>,
+    > {
+        let request = self.build_put_dedicated_ip_warmup_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutDedicatedIpInPoolResponse, _>()?;
+                .deserialize::<PutDedicatedIpWarmupAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutDedicatedIpInPoolError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutDedicatedIpWarmupAttributesError::from_response(response))
         }
     }
 
-    /// <p><p/></p>
-    async fn put_dedicated_ip_warmup_attributes(
+    /// Presigns the request `put_dedicated_ip_warmup_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_dedicated_ip_warmup_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutDedicatedIpWarmupAttributesRequest,
-    ) -> Result<
-        PutDedicatedIpWarmupAttributesResponse,
-        RusotoError<PutDedicatedIpWarmupAttributesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_dedicated_ip_warmup_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_dedicated_ip_warmup_attributes_request(
+        &self,
+        input: PutDedicatedIpWarmupAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v2/email/dedicated-ips/{ip}/warmup", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_deliverability_dashboard_option`, also returning the metadata of the response.
+    pub async fn put_deliverability_dashboard_option_with_metadata(
+        &self,
+        input: PutDeliverabilityDashboardOptionRequest,
+    ) -> Result<
+        (PutDeliverabilityDashboardOptionResponse, ResponseMetadata),
+        RusotoError<PutDeliverabilityDashboardOptionError>,
+    > {
+        let request = self.build_put_deliverability_dashboard_option_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutDedicatedIpWarmupAttributesResponse, _>()?;
+                .deserialize::<PutDeliverabilityDashboardOptionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutDedicatedIpWarmupAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutDeliverabilityDashboardOptionError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Enable or disable the Deliverability dashboard. When you enable the Deliverability dashboard, you gain access to reputation, deliverability, and other metrics for the domains that you use to send email. You also gain the ability to perform predictive inbox placement tests.</p> <p>When you use the Deliverability dashboard, you pay a monthly subscription charge, in addition to any other fees that you accrue by using Amazon SES and other AWS services. For more information about the features and cost of a Deliverability dashboard subscription, see <a href="http://aws.amazon.com/ses/pricing/">Amazon SES Pricing</a>.</p>
-    async fn put_deliverability_dashboard_option(
+    /// Presigns the request `put_deliverability_dashboard_option` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_deliverability_dashboard_option(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutDeliverabilityDashboardOptionRequest,
-    ) -> Result<
-        PutDeliverabilityDashboardOptionResponse,
-        RusotoError<PutDeliverabilityDashboardOptionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_deliverability_dashboard_option_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_deliverability_dashboard_option_request(
+        &self,
+        input: PutDeliverabilityDashboardOptionRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDeliverabilityDashboardOption");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_email_identity_dkim_attributes`, also returning the metadata of the response.
+    pub async fn put_email_identity_dkim_attributes_with_metadata(
+        &self,
+        input: PutEmailIdentityDkimAttributesRequest,
+    ) -> Result<
+        (PutEmailIdentityDkimAttributesResponse, ResponseMetadata),
+        RusotoError<PutEmailIdentityDkimAttributesError>,
+    > {
+        let request = self.build_put_email_identity_dkim_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutDeliverabilityDashboardOptionResponse, _>()?;
+                .deserialize::<PutEmailIdentityDkimAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutDeliverabilityDashboardOptionError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutEmailIdentityDkimAttributesError::from_response(response))
         }
     }
 
-    /// <p>Used to enable or disable DKIM authentication for an email identity.</p>
-    async fn put_email_identity_dkim_attributes(
+    /// Presigns the request `put_email_identity_dkim_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_email_identity_dkim_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutEmailIdentityDkimAttributesRequest,
-    ) -> Result<
-        PutEmailIdentityDkimAttributesResponse,
-        RusotoError<PutEmailIdentityDkimAttributesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_email_identity_dkim_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_email_identity_dkim_attributes_request(
+        &self,
+        input: PutEmailIdentityDkimAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/identities/{email_identity}/dkim",
             email_identity = input.email_identity
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityDkimAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_email_identity_dkim_signing_attributes`, also returning the metadata of the response.
+    pub async fn put_email_identity_dkim_signing_attributes_with_metadata(
+        &self,
+        input: PutEmailIdentityDkimSigningAttributesRequest,
+    ) -> Result<
+        (
+            PutEmailIdentityDkimSigningAttributesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<PutEmailIdentityDkimSigningAttributesError>,
+    > {
+        let request = self.build_put_email_identity_dkim_signing_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutEmailIdentityDkimAttributesResponse, _>()?;
+                .deserialize::<PutEmailIdentityDkimSigningAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutEmailIdentityDkimAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutEmailIdentityDkimSigningAttributesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Used to configure or change the DKIM authentication settings for an email domain identity. You can use this operation to do any of the following:</p> <ul> <li> <p>Update the signing attributes for an identity that uses Bring Your Own DKIM (BYODKIM).</p> </li> <li> <p>Change from using no DKIM authentication to using Easy DKIM.</p> </li> <li> <p>Change from using no DKIM authentication to using BYODKIM.</p> </li> <li> <p>Change from using Easy DKIM to using BYODKIM.</p> </li> <li> <p>Change from using BYODKIM to using Easy DKIM.</p> </li> </ul></p>
-    async fn put_email_identity_dkim_signing_attributes(
+    /// Presigns the request `put_email_identity_dkim_signing_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_email_identity_dkim_signing_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutEmailIdentityDkimSigningAttributesRequest,
-    ) -> Result<
-        PutEmailIdentityDkimSigningAttributesResponse,
-        RusotoError<PutEmailIdentityDkimSigningAttributesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_email_identity_dkim_signing_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_email_identity_dkim_signing_attributes_request(
+        &self,
+        input: PutEmailIdentityDkimSigningAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/email/identities/{email_identity}/dkim/signing",
             email_identity = input.email_identity
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityDkimSigningAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_email_identity_feedback_attributes`, also returning the metadata of the response.
+    pub async fn put_email_identity_feedback_attributes_with_metadata(
+        &self,
+        input: PutEmailIdentityFeedbackAttributesRequest,
+    ) -> Result<
+        (PutEmailIdentityFeedbackAttributesResponse, ResponseMetadata),
+        RusotoError<PutEmailIdentityFeedbackAttributesError>,
+    > {
+        let request = self.build_put_email_identity_feedback_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutEmailIdentityDkimSigningAttributesResponse, _>()?;
+                .deserialize::<PutEmailIdentityFeedbackAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutEmailIdentityDkimSigningAttributesError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutEmailIdentityFeedbackAttributesError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Used to enable or disable feedback forwarding for an identity. This setting determines what happens when an identity is used to send an email that results in a bounce or complaint event.</p> <p>If the value is <code>true</code>, you receive email notifications when bounce or complaint events occur. These notifications are sent to the address that you specified in the <code>Return-Path</code> header of the original email.</p> <p>You're required to have a method of tracking bounces and complaints. If you haven't set up another mechanism for receiving bounce or complaint notifications (for example, by setting up an event destination), you receive an email notification when these events occur (even if this setting is disabled).</p>
-    async fn put_email_identity_feedback_attributes(
+    /// Presigns the request `put_email_identity_feedback_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_email_identity_feedback_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutEmailIdentityFeedbackAttributesRequest,
-    ) -> Result<
-        PutEmailIdentityFeedbackAttributesResponse,
-        RusotoError<PutEmailIdentityFeedbackAttributesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_email_identity_feedback_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_email_identity_feedback_attributes_request(
+        &self,
+        input: PutEmailIdentityFeedbackAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/identities/{email_identity}/feedback",
             email_identity = input.email_identity
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityFeedbackAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_email_identity_mail_from_attributes`, also returning the metadata of the response.
+    pub async fn put_email_identity_mail_from_attributes_with_metadata(
+        &self,
+        input: PutEmailIdentityMailFromAttributesRequest,
+    ) -> Result<
+        (PutEmailIdentityMailFromAttributesResponse, ResponseMetadata),
+        RusotoError<PutEmailIdentityMailFromAttributesError>,
+    > {
+        let request = self.build_put_email_identity_mail_from_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutEmailIdentityFeedbackAttributesResponse, _>()?;
+                .deserialize::<PutEmailIdentityMailFromAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutEmailIdentityFeedbackAttributesError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutEmailIdentityMailFromAttributesError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Used to enable or disable the custom Mail-From domain configuration for an email identity.</p>
-    async fn put_email_identity_mail_from_attributes(
+    /// Presigns the request `put_email_identity_mail_from_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_email_identity_mail_from_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutEmailIdentityMailFromAttributesRequest,
-    ) -> Result<
-        PutEmailIdentityMailFromAttributesResponse,
-        RusotoError<PutEmailIdentityMailFromAttributesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_email_identity_mail_from_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_email_identity_mail_from_attributes_request(
+        &self,
+        input: PutEmailIdentityMailFromAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/email/identities/{email_identity}/mail-from",
             email_identity = input.email_identity
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityMailFromAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_suppressed_destination`, also returning the metadata of the response.
+    pub async fn put_suppressed_destination_with_metadata(
+        &self,
+        input: PutSuppressedDestinationRequest,
+    ) -> Result<
+        (PutSuppressedDestinationResponse, ResponseMetadata),
+        RusotoError<PutSuppressedDestinationError>,
+    > {
+        let request = self.build_put_suppressed_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutEmailIdentityMailFromAttributesResponse, _>()?;
+                .deserialize::<PutSuppressedDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutEmailIdentityMailFromAttributesError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutSuppressedDestinationError::from_response(response))
         }
     }
 
-    /// <p>Adds an email address to the suppression list for your account.</p>
-    async fn put_suppressed_destination(
+    /// Presigns the request `put_suppressed_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_suppressed_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutSuppressedDestinationRequest,
-    ) -> Result<PutSuppressedDestinationResponse, RusotoError<PutSuppressedDestinationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_suppressed_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_suppressed_destination_request(
+        &self,
+        input: PutSuppressedDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = "/v2/email/suppression/addresses";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutSuppressedDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `send_email`, also returning the metadata of the response.
+    pub async fn send_email_with_metadata(
+        &self,
+        input: SendEmailRequest,
+    ) -> Result<(SendEmailResponse, ResponseMetadata), RusotoError<SendEmailError>> {
+        let request = self.build_send_email_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutSuppressedDestinationResponse, _>()?;
+                .deserialize::<SendEmailResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutSuppressedDestinationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SendEmailError::from_response(response))
         }
     }
 
-    /// <p><p>Sends an email message. You can use the Amazon SES API v2 to send two types of messages:</p> <ul> <li> <p> <b>Simple</b> – A standard email message. When you create this type of message, you specify the sender, the recipient, and the message body, and Amazon SES assembles the message for you.</p> </li> <li> <p> <b>Raw</b> – A raw, MIME-formatted email message. When you send this type of email, you have to specify all of the message headers, as well as the message body. You can use this message type to send messages that contain attachments. The message that you specify has to be a valid MIME message.</p> </li> </ul></p>
-    async fn send_email(
+    /// Presigns the request `send_email` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_email(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendEmailRequest,
-    ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_email_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_email_request(&self, input: SendEmailRequest) -> SignedRequest {
         let request_uri = "/v2/email/outbound-emails";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("SendEmail");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<SendEmailResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SendEmailError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Add one or more tags (keys and values) to a specified resource. A <i>tag</i> is a label that you optionally define and associate with a resource. Tags can help you categorize and manage resources in different ways, such as by purpose, owner, environment, or other criteria. A resource can have as many as 50 tags.</p> <p>Each tag consists of a required <i>tag key</i> and an associated <i>tag value</i>, both of which you define. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = "/v2/email/tags";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Remove one or more tags (keys and values) from a specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = "/v2/email/tags";
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -6500,56 +8458,632 @@ impl SesV2 for SesV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_configuration_set_event_destination`, also returning the metadata of the response.
+    pub async fn update_configuration_set_event_destination_with_metadata(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        (
+            UpdateConfigurationSetEventDestinationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        let request = self.build_update_configuration_set_event_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateConfigurationSetEventDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateConfigurationSetEventDestinationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Update the configuration of an event destination for a configuration set.</p> <p> <i>Events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p>
-    async fn update_configuration_set_event_destination(
+    /// Presigns the request `update_configuration_set_event_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_configuration_set_event_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConfigurationSetEventDestinationRequest,
-    ) -> Result<
-        UpdateConfigurationSetEventDestinationResponse,
-        RusotoError<UpdateConfigurationSetEventDestinationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_configuration_set_event_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_configuration_set_event_destination_request(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v2/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl SesV2 for SesV2Client {
+    /// <p>Create a configuration set. <i>Configuration sets</i> are groups of rules that you can apply to the emails that you send. You apply a configuration set to an email by specifying the name of the configuration set when you call the Amazon SES API v2. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email. </p>
+    async fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+        self.create_configuration_set_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateConfigurationSetEventDestinationResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConfigurationSetEventDestinationError::from_response(
-                response,
-            ))
-        }
+    /// <p>Create an event destination. <i>Events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p> <p>A single configuration set can include more than one event destination.</p>
+    async fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        CreateConfigurationSetEventDestinationResponse,
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        self.create_configuration_set_event_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new pool of dedicated IP addresses. A pool can include one or more dedicated IP addresses that are associated with your AWS account. You can associate a pool with a configuration set. When you send an email that uses that configuration set, the message is sent from one of the addresses in the associated pool.</p>
+    async fn create_dedicated_ip_pool(
+        &self,
+        input: CreateDedicatedIpPoolRequest,
+    ) -> Result<CreateDedicatedIpPoolResponse, RusotoError<CreateDedicatedIpPoolError>> {
+        self.create_dedicated_ip_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new predictive inbox placement test. Predictive inbox placement tests can help you predict how your messages will be handled by various email providers around the world. When you perform a predictive inbox placement test, you provide a sample message that contains the content that you plan to send to your customers. Amazon SES then sends that message to special email addresses spread across several major email providers. After about 24 hours, the test is complete, and you can use the <code>GetDeliverabilityTestReport</code> operation to view the results of the test.</p>
+    async fn create_deliverability_test_report(
+        &self,
+        input: CreateDeliverabilityTestReportRequest,
+    ) -> Result<
+        CreateDeliverabilityTestReportResponse,
+        RusotoError<CreateDeliverabilityTestReportError>,
+    > {
+        self.create_deliverability_test_report_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the process of verifying an email identity. An <i>identity</i> is an email address or domain that you use when you send email. Before you can use an identity to send email, you first have to verify it. By verifying an identity, you demonstrate that you're the owner of the identity, and that you've given Amazon SES API v2 permission to send email from the identity.</p> <p>When you verify an email address, Amazon SES sends an email to the address. Your email address is verified as soon as you follow the link in the verification email. </p> <p>When you verify a domain without specifying the <code>DkimSigningAttributes</code> object, this operation provides a set of DKIM tokens. You can convert these tokens into CNAME records, which you then add to the DNS configuration for your domain. Your domain is verified when Amazon SES detects these records in the DNS configuration for your domain. This verification method is known as <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Easy DKIM</a>.</p> <p>Alternatively, you can perform the verification process by providing your own public-private key pair. This verification method is known as Bring Your Own DKIM (BYODKIM). To use BYODKIM, your call to the <code>CreateEmailIdentity</code> operation has to include the <code>DkimSigningAttributes</code> object. When you specify this object, you provide a selector (a component of the DNS record name that identifies the public key that you want to use for DKIM authentication) and a private key.</p>
+    async fn create_email_identity(
+        &self,
+        input: CreateEmailIdentityRequest,
+    ) -> Result<CreateEmailIdentityResponse, RusotoError<CreateEmailIdentityError>> {
+        self.create_email_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete an existing configuration set.</p> <p> <i>Configuration sets</i> are groups of rules that you can apply to the emails you send. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email.</p>
+    async fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+        self.delete_configuration_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete an event destination.</p> <p> <i>Events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p>
+    async fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        DeleteConfigurationSetEventDestinationResponse,
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        self.delete_configuration_set_event_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a dedicated IP pool.</p>
+    async fn delete_dedicated_ip_pool(
+        &self,
+        input: DeleteDedicatedIpPoolRequest,
+    ) -> Result<DeleteDedicatedIpPoolResponse, RusotoError<DeleteDedicatedIpPoolError>> {
+        self.delete_dedicated_ip_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an email identity. An identity can be either an email address or a domain name.</p>
+    async fn delete_email_identity(
+        &self,
+        input: DeleteEmailIdentityRequest,
+    ) -> Result<DeleteEmailIdentityResponse, RusotoError<DeleteEmailIdentityError>> {
+        self.delete_email_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes an email address from the suppression list for your account.</p>
+    async fn delete_suppressed_destination(
+        &self,
+        input: DeleteSuppressedDestinationRequest,
+    ) -> Result<DeleteSuppressedDestinationResponse, RusotoError<DeleteSuppressedDestinationError>>
+    {
+        self.delete_suppressed_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Obtain information about the email-sending status and capabilities of your Amazon SES account in the current AWS Region.</p>
+    async fn get_account(&self) -> Result<GetAccountResponse, RusotoError<GetAccountError>> {
+        self.get_account_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a list of the blacklists that your dedicated IP addresses appear on.</p>
+    async fn get_blacklist_reports(
+        &self,
+        input: GetBlacklistReportsRequest,
+    ) -> Result<GetBlacklistReportsResponse, RusotoError<GetBlacklistReportsError>> {
+        self.get_blacklist_reports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get information about an existing configuration set, including the dedicated IP pool that it's associated with, whether or not it's enabled for sending email, and more.</p> <p> <i>Configuration sets</i> are groups of rules that you can apply to the emails you send. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email.</p>
+    async fn get_configuration_set(
+        &self,
+        input: GetConfigurationSetRequest,
+    ) -> Result<GetConfigurationSetResponse, RusotoError<GetConfigurationSetError>> {
+        self.get_configuration_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a list of event destinations that are associated with a configuration set.</p> <p> <i>Events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p>
+    async fn get_configuration_set_event_destinations(
+        &self,
+        input: GetConfigurationSetEventDestinationsRequest,
+    ) -> Result<
+        GetConfigurationSetEventDestinationsResponse,
+        RusotoError<GetConfigurationSetEventDestinationsError>,
+    > {
+        self.get_configuration_set_event_destinations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get information about a dedicated IP address, including the name of the dedicated IP pool that it's associated with, as well information about the automatic warm-up process for the address.</p>
+    async fn get_dedicated_ip(
+        &self,
+        input: GetDedicatedIpRequest,
+    ) -> Result<GetDedicatedIpResponse, RusotoError<GetDedicatedIpError>> {
+        self.get_dedicated_ip_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the dedicated IP addresses that are associated with your AWS account.</p>
+    async fn get_dedicated_ips(
+        &self,
+        input: GetDedicatedIpsRequest,
+    ) -> Result<GetDedicatedIpsResponse, RusotoError<GetDedicatedIpsError>> {
+        self.get_dedicated_ips_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve information about the status of the Deliverability dashboard for your account. When the Deliverability dashboard is enabled, you gain access to reputation, deliverability, and other metrics for the domains that you use to send email. You also gain the ability to perform predictive inbox placement tests.</p> <p>When you use the Deliverability dashboard, you pay a monthly subscription charge, in addition to any other fees that you accrue by using Amazon SES and other AWS services. For more information about the features and cost of a Deliverability dashboard subscription, see <a href="http://aws.amazon.com/ses/pricing/">Amazon SES Pricing</a>.</p>
+    async fn get_deliverability_dashboard_options(
+        &self,
+    ) -> Result<
+        GetDeliverabilityDashboardOptionsResponse,
+        RusotoError<GetDeliverabilityDashboardOptionsError>,
+    > {
+        self.get_deliverability_dashboard_options_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve the results of a predictive inbox placement test.</p>
+    async fn get_deliverability_test_report(
+        &self,
+        input: GetDeliverabilityTestReportRequest,
+    ) -> Result<GetDeliverabilityTestReportResponse, RusotoError<GetDeliverabilityTestReportError>>
+    {
+        self.get_deliverability_test_report_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve all the deliverability data for a specific campaign. This data is available for a campaign only if the campaign sent email by using a domain that the Deliverability dashboard is enabled for.</p>
+    async fn get_domain_deliverability_campaign(
+        &self,
+        input: GetDomainDeliverabilityCampaignRequest,
+    ) -> Result<
+        GetDomainDeliverabilityCampaignResponse,
+        RusotoError<GetDomainDeliverabilityCampaignError>,
+    > {
+        self.get_domain_deliverability_campaign_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve inbox placement and engagement rates for the domains that you use to send email.</p>
+    async fn get_domain_statistics_report(
+        &self,
+        input: GetDomainStatisticsReportRequest,
+    ) -> Result<GetDomainStatisticsReportResponse, RusotoError<GetDomainStatisticsReportError>>
+    {
+        self.get_domain_statistics_report_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information about a specific identity, including the identity's verification status, its DKIM authentication status, and its custom Mail-From settings.</p>
+    async fn get_email_identity(
+        &self,
+        input: GetEmailIdentityRequest,
+    ) -> Result<GetEmailIdentityResponse, RusotoError<GetEmailIdentityError>> {
+        self.get_email_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a specific email address that's on the suppression list for your account.</p>
+    async fn get_suppressed_destination(
+        &self,
+        input: GetSuppressedDestinationRequest,
+    ) -> Result<GetSuppressedDestinationResponse, RusotoError<GetSuppressedDestinationError>> {
+        self.get_suppressed_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all of the configuration sets associated with your account in the current region.</p> <p> <i>Configuration sets</i> are groups of rules that you can apply to the emails you send. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email.</p>
+    async fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+        self.list_configuration_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all of the dedicated IP pools that exist in your AWS account in the current Region.</p>
+    async fn list_dedicated_ip_pools(
+        &self,
+        input: ListDedicatedIpPoolsRequest,
+    ) -> Result<ListDedicatedIpPoolsResponse, RusotoError<ListDedicatedIpPoolsError>> {
+        self.list_dedicated_ip_pools_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Show a list of the predictive inbox placement tests that you've performed, regardless of their statuses. For predictive inbox placement tests that are complete, you can use the <code>GetDeliverabilityTestReport</code> operation to view the results.</p>
+    async fn list_deliverability_test_reports(
+        &self,
+        input: ListDeliverabilityTestReportsRequest,
+    ) -> Result<
+        ListDeliverabilityTestReportsResponse,
+        RusotoError<ListDeliverabilityTestReportsError>,
+    > {
+        self.list_deliverability_test_reports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve deliverability data for all the campaigns that used a specific domain to send email during a specified time range. This data is available for a domain only if you enabled the Deliverability dashboard for the domain.</p>
+    async fn list_domain_deliverability_campaigns(
+        &self,
+        input: ListDomainDeliverabilityCampaignsRequest,
+    ) -> Result<
+        ListDomainDeliverabilityCampaignsResponse,
+        RusotoError<ListDomainDeliverabilityCampaignsError>,
+    > {
+        self.list_domain_deliverability_campaigns_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all of the email identities that are associated with your AWS account. An identity can be either an email address or a domain. This operation returns identities that are verified as well as those that aren't. This operation returns identities that are associated with Amazon SES and Amazon Pinpoint.</p>
+    async fn list_email_identities(
+        &self,
+        input: ListEmailIdentitiesRequest,
+    ) -> Result<ListEmailIdentitiesResponse, RusotoError<ListEmailIdentitiesError>> {
+        self.list_email_identities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of email addresses that are on the suppression list for your account.</p>
+    async fn list_suppressed_destinations(
+        &self,
+        input: ListSuppressedDestinationsRequest,
+    ) -> Result<ListSuppressedDestinationsResponse, RusotoError<ListSuppressedDestinationsError>>
+    {
+        self.list_suppressed_destinations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a list of the tags (keys and values) that are associated with a specified resource. A <i>tag</i> is a label that you optionally define and associate with a resource. Each tag consists of a required <i>tag key</i> and an optional associated <i>tag value</i>. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enable or disable the automatic warm-up feature for dedicated IP addresses.</p>
+    async fn put_account_dedicated_ip_warmup_attributes(
+        &self,
+        input: PutAccountDedicatedIpWarmupAttributesRequest,
+    ) -> Result<
+        PutAccountDedicatedIpWarmupAttributesResponse,
+        RusotoError<PutAccountDedicatedIpWarmupAttributesError>,
+    > {
+        self.put_account_dedicated_ip_warmup_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enable or disable the ability of your account to send email.</p>
+    async fn put_account_sending_attributes(
+        &self,
+        input: PutAccountSendingAttributesRequest,
+    ) -> Result<PutAccountSendingAttributesResponse, RusotoError<PutAccountSendingAttributesError>>
+    {
+        self.put_account_sending_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Change the settings for the account-level suppression list.</p>
+    async fn put_account_suppression_attributes(
+        &self,
+        input: PutAccountSuppressionAttributesRequest,
+    ) -> Result<
+        PutAccountSuppressionAttributesResponse,
+        RusotoError<PutAccountSuppressionAttributesError>,
+    > {
+        self.put_account_suppression_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associate a configuration set with a dedicated IP pool. You can use dedicated IP pools to create groups of dedicated IP addresses for sending specific types of email.</p>
+    async fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetDeliveryOptionsResponse,
+        RusotoError<PutConfigurationSetDeliveryOptionsError>,
+    > {
+        self.put_configuration_set_delivery_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enable or disable collection of reputation metrics for emails that you send using a particular configuration set in a specific AWS Region.</p>
+    async fn put_configuration_set_reputation_options(
+        &self,
+        input: PutConfigurationSetReputationOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetReputationOptionsResponse,
+        RusotoError<PutConfigurationSetReputationOptionsError>,
+    > {
+        self.put_configuration_set_reputation_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enable or disable email sending for messages that use a particular configuration set in a specific AWS Region.</p>
+    async fn put_configuration_set_sending_options(
+        &self,
+        input: PutConfigurationSetSendingOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetSendingOptionsResponse,
+        RusotoError<PutConfigurationSetSendingOptionsError>,
+    > {
+        self.put_configuration_set_sending_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Specify the account suppression list preferences for a configuration set.</p>
+    async fn put_configuration_set_suppression_options(
+        &self,
+        input: PutConfigurationSetSuppressionOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetSuppressionOptionsResponse,
+        RusotoError<PutConfigurationSetSuppressionOptionsError>,
+    > {
+        self.put_configuration_set_suppression_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Specify a custom domain to use for open and click tracking elements in email that you send.</p>
+    async fn put_configuration_set_tracking_options(
+        &self,
+        input: PutConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetTrackingOptionsResponse,
+        RusotoError<PutConfigurationSetTrackingOptionsError>,
+    > {
+        self.put_configuration_set_tracking_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Move a dedicated IP address to an existing dedicated IP pool.</p> <note> <p>The dedicated IP address that you specify must already exist, and must be associated with your AWS account. </p> <p>The dedicated IP pool you specify must already exist. You can create a new pool by using the <code>CreateDedicatedIpPool</code> operation.</p> </note></p>
+    async fn put_dedicated_ip_in_pool(
+        &self,
+        input: PutDedicatedIpInPoolRequest,
+    ) -> Result<PutDedicatedIpInPoolResponse, RusotoError<PutDedicatedIpInPoolError>> {
+        self.put_dedicated_ip_in_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p/></p>
+    async fn put_dedicated_ip_warmup_attributes(
+        &self,
+        input: PutDedicatedIpWarmupAttributesRequest,
+    ) -> Result<
+        PutDedicatedIpWarmupAttributesResponse,
+        RusotoError<PutDedicatedIpWarmupAttributesError>,
+    > {
+        self.put_dedicated_ip_warmup_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enable or disable the Deliverability dashboard. When you enable the Deliverability dashboard, you gain access to reputation, deliverability, and other metrics for the domains that you use to send email. You also gain the ability to perform predictive inbox placement tests.</p> <p>When you use the Deliverability dashboard, you pay a monthly subscription charge, in addition to any other fees that you accrue by using Amazon SES and other AWS services. For more information about the features and cost of a Deliverability dashboard subscription, see <a href="http://aws.amazon.com/ses/pricing/">Amazon SES Pricing</a>.</p>
+    async fn put_deliverability_dashboard_option(
+        &self,
+        input: PutDeliverabilityDashboardOptionRequest,
+    ) -> Result<
+        PutDeliverabilityDashboardOptionResponse,
+        RusotoError<PutDeliverabilityDashboardOptionError>,
+    > {
+        self.put_deliverability_dashboard_option_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used to enable or disable DKIM authentication for an email identity.</p>
+    async fn put_email_identity_dkim_attributes(
+        &self,
+        input: PutEmailIdentityDkimAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityDkimAttributesResponse,
+        RusotoError<PutEmailIdentityDkimAttributesError>,
+    > {
+        self.put_email_identity_dkim_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Used to configure or change the DKIM authentication settings for an email domain identity. You can use this operation to do any of the following:</p> <ul> <li> <p>Update the signing attributes for an identity that uses Bring Your Own DKIM (BYODKIM).</p> </li> <li> <p>Change from using no DKIM authentication to using Easy DKIM.</p> </li> <li> <p>Change from using no DKIM authentication to using BYODKIM.</p> </li> <li> <p>Change from using Easy DKIM to using BYODKIM.</p> </li> <li> <p>Change from using BYODKIM to using Easy DKIM.</p> </li> </ul></p>
+    async fn put_email_identity_dkim_signing_attributes(
+        &self,
+        input: PutEmailIdentityDkimSigningAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityDkimSigningAttributesResponse,
+        RusotoError<PutEmailIdentityDkimSigningAttributesError>,
+    > {
+        self.put_email_identity_dkim_signing_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used to enable or disable feedback forwarding for an identity. This setting determines what happens when an identity is used to send an email that results in a bounce or complaint event.</p> <p>If the value is <code>true</code>, you receive email notifications when bounce or complaint events occur. These notifications are sent to the address that you specified in the <code>Return-Path</code> header of the original email.</p> <p>You're required to have a method of tracking bounces and complaints. If you haven't set up another mechanism for receiving bounce or complaint notifications (for example, by setting up an event destination), you receive an email notification when these events occur (even if this setting is disabled).</p>
+    async fn put_email_identity_feedback_attributes(
+        &self,
+        input: PutEmailIdentityFeedbackAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityFeedbackAttributesResponse,
+        RusotoError<PutEmailIdentityFeedbackAttributesError>,
+    > {
+        self.put_email_identity_feedback_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used to enable or disable the custom Mail-From domain configuration for an email identity.</p>
+    async fn put_email_identity_mail_from_attributes(
+        &self,
+        input: PutEmailIdentityMailFromAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityMailFromAttributesResponse,
+        RusotoError<PutEmailIdentityMailFromAttributesError>,
+    > {
+        self.put_email_identity_mail_from_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an email address to the suppression list for your account.</p>
+    async fn put_suppressed_destination(
+        &self,
+        input: PutSuppressedDestinationRequest,
+    ) -> Result<PutSuppressedDestinationResponse, RusotoError<PutSuppressedDestinationError>> {
+        self.put_suppressed_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sends an email message. You can use the Amazon SES API v2 to send two types of messages:</p> <ul> <li> <p> <b>Simple</b> – A standard email message. When you create this type of message, you specify the sender, the recipient, and the message body, and Amazon SES assembles the message for you.</p> </li> <li> <p> <b>Raw</b> – A raw, MIME-formatted email message. When you send this type of email, you have to specify all of the message headers, as well as the message body. You can use this message type to send messages that contain attachments. The message that you specify has to be a valid MIME message.</p> </li> </ul></p>
+    async fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
+        self.send_email_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add one or more tags (keys and values) to a specified resource. A <i>tag</i> is a label that you optionally define and associate with a resource. Tags can help you categorize and manage resources in different ways, such as by purpose, owner, environment, or other criteria. A resource can have as many as 50 tags.</p> <p>Each tag consists of a required <i>tag key</i> and an associated <i>tag value</i>, both of which you define. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove one or more tags (keys and values) from a specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update the configuration of an event destination for a configuration set.</p> <p> <i>Events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p>
+    async fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        UpdateConfigurationSetEventDestinationResponse,
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        self.update_configuration_set_event_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
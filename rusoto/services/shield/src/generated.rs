@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -570,6 +570,7 @@ pub struct UpdateSubscriptionResponse {}
 
 /// Errors returned by AssociateDRTLogBucket
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateDRTLogBucketError {
     /// <p>In order to grant the necessary access to the DDoS Response Team, the user submitting <code>AssociateDRTRole</code> must have the <code>iam:PassRole</code> permission. This error indicates the user did not have the appropriate permissions. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">Granting a User Permissions to Pass a Role to an AWS Service</a>. </p>
     AccessDeniedForDependency(String),
@@ -587,6 +588,8 @@ pub enum AssociateDRTLogBucketError {
     OptimisticLock(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateDRTLogBucketError {
@@ -594,45 +597,55 @@ impl AssociateDRTLogBucketError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDRTLogBucketError::AccessDeniedForDependency(err.msg),
+                        &res,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AssociateDRTLogBucketError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDRTLogBucketError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDRTLogBucketError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitsExceededException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::LimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDRTLogBucketError::LimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NoAssociatedRoleException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::NoAssociatedRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDRTLogBucketError::NoAssociatedRole(err.msg),
+                        &res,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::OptimisticLock(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDRTLogBucketError::OptimisticLock(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDRTLogBucketError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateDRTLogBucketError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -652,12 +665,14 @@ impl fmt::Display for AssociateDRTLogBucketError {
             AssociateDRTLogBucketError::NoAssociatedRole(ref cause) => write!(f, "{}", cause),
             AssociateDRTLogBucketError::OptimisticLock(ref cause) => write!(f, "{}", cause),
             AssociateDRTLogBucketError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssociateDRTLogBucketError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateDRTLogBucketError {}
 /// Errors returned by AssociateDRTRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateDRTRoleError {
     /// <p>In order to grant the necessary access to the DDoS Response Team, the user submitting <code>AssociateDRTRole</code> must have the <code>iam:PassRole</code> permission. This error indicates the user did not have the appropriate permissions. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">Granting a User Permissions to Pass a Role to an AWS Service</a>. </p>
     AccessDeniedForDependency(String),
@@ -671,6 +686,8 @@ pub enum AssociateDRTRoleError {
     OptimisticLock(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateDRTRoleError {
@@ -678,27 +695,43 @@ impl AssociateDRTRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::AccessDeniedForDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDRTRoleError::AccessDeniedForDependency(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AssociateDRTRoleError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        AssociateDRTRoleError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AssociateDRTRoleError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        AssociateDRTRoleError::OptimisticLock(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AssociateDRTRoleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateDRTRoleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -714,12 +747,14 @@ impl fmt::Display for AssociateDRTRoleError {
             AssociateDRTRoleError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             AssociateDRTRoleError::OptimisticLock(ref cause) => write!(f, "{}", cause),
             AssociateDRTRoleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssociateDRTRoleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateDRTRoleError {}
 /// Errors returned by CreateProtection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProtectionError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
@@ -735,6 +770,8 @@ pub enum CreateProtectionError {
     ResourceAlreadyExists(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProtectionError {
@@ -742,30 +779,49 @@ impl CreateProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateProtectionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateProtectionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(CreateProtectionError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        CreateProtectionError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateProtectionError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        CreateProtectionError::InvalidResource(err.msg),
+                        &res,
+                    )
                 }
                 "LimitsExceededException" => {
-                    return RusotoError::Service(CreateProtectionError::LimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateProtectionError::LimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(CreateProtectionError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        CreateProtectionError::OptimisticLock(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProtectionError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProtectionError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProtectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateProtectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProtectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -782,17 +838,21 @@ impl fmt::Display for CreateProtectionError {
             CreateProtectionError::OptimisticLock(ref cause) => write!(f, "{}", cause),
             CreateProtectionError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateProtectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateProtectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProtectionError {}
 /// Errors returned by CreateSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSubscriptionError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
     /// <p>Exception indicating the specified resource already exists.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSubscriptionError {
@@ -800,15 +860,19 @@ impl CreateSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateSubscriptionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSubscriptionError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSubscriptionError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSubscriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -820,12 +884,14 @@ impl fmt::Display for CreateSubscriptionError {
         match *self {
             CreateSubscriptionError::InternalError(ref cause) => write!(f, "{}", cause),
             CreateSubscriptionError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSubscriptionError {}
 /// Errors returned by DeleteProtection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProtectionError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
@@ -833,6 +899,8 @@ pub enum DeleteProtectionError {
     OptimisticLock(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProtectionError {
@@ -840,16 +908,25 @@ impl DeleteProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteProtectionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteProtectionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DeleteProtectionError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        DeleteProtectionError::OptimisticLock(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProtectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteProtectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteProtectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -862,12 +939,14 @@ impl fmt::Display for DeleteProtectionError {
             DeleteProtectionError::InternalError(ref cause) => write!(f, "{}", cause),
             DeleteProtectionError::OptimisticLock(ref cause) => write!(f, "{}", cause),
             DeleteProtectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteProtectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProtectionError {}
 /// Errors returned by DeleteSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSubscriptionError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
@@ -875,6 +954,8 @@ pub enum DeleteSubscriptionError {
     LockedSubscription(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSubscriptionError {
@@ -882,18 +963,25 @@ impl DeleteSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteSubscriptionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "LockedSubscriptionException" => {
-                    return RusotoError::Service(DeleteSubscriptionError::LockedSubscription(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSubscriptionError::LockedSubscription(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSubscriptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteSubscriptionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSubscriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -906,17 +994,21 @@ impl fmt::Display for DeleteSubscriptionError {
             DeleteSubscriptionError::InternalError(ref cause) => write!(f, "{}", cause),
             DeleteSubscriptionError::LockedSubscription(ref cause) => write!(f, "{}", cause),
             DeleteSubscriptionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSubscriptionError {}
 /// Errors returned by DescribeAttack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAttackError {
     /// <p>Exception that indicates the specified <code>AttackId</code> does not exist, or the requester does not have the appropriate permissions to access the <code>AttackId</code>.</p>
     AccessDenied(String),
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAttackError {
@@ -924,13 +1016,13 @@ impl DescribeAttackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAttackError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeAttackError::AccessDenied(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeAttackError::InternalError(err.msg))
+                    return RusotoError::service(DescribeAttackError::InternalError(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAttackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -942,17 +1034,21 @@ impl fmt::Display for DescribeAttackError {
         match *self {
             DescribeAttackError::AccessDenied(ref cause) => write!(f, "{}", cause),
             DescribeAttackError::InternalError(ref cause) => write!(f, "{}", cause),
+            DescribeAttackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAttackError {}
 /// Errors returned by DescribeDRTAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDRTAccessError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDRTAccessError {
@@ -960,13 +1056,19 @@ impl DescribeDRTAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeDRTAccessError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeDRTAccessError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDRTAccessError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDRTAccessError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDRTAccessError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -978,17 +1080,21 @@ impl fmt::Display for DescribeDRTAccessError {
         match *self {
             DescribeDRTAccessError::InternalError(ref cause) => write!(f, "{}", cause),
             DescribeDRTAccessError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDRTAccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDRTAccessError {}
 /// Errors returned by DescribeEmergencyContactSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEmergencyContactSettingsError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEmergencyContactSettingsError {
@@ -998,17 +1104,24 @@ impl DescribeEmergencyContactSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEmergencyContactSettingsError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEmergencyContactSettingsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeEmergencyContactSettingsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1024,12 +1137,16 @@ impl fmt::Display for DescribeEmergencyContactSettingsError {
             DescribeEmergencyContactSettingsError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEmergencyContactSettingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEmergencyContactSettingsError {}
 /// Errors returned by DescribeProtection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProtectionError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
@@ -1037,6 +1154,8 @@ pub enum DescribeProtectionError {
     InvalidParameter(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProtectionError {
@@ -1044,16 +1163,25 @@ impl DescribeProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeProtectionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeProtectionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeProtectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeProtectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProtectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeProtectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProtectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1066,17 +1194,21 @@ impl fmt::Display for DescribeProtectionError {
             DescribeProtectionError::InternalError(ref cause) => write!(f, "{}", cause),
             DescribeProtectionError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeProtectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProtectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProtectionError {}
 /// Errors returned by DescribeSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSubscriptionError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSubscriptionError {
@@ -1084,15 +1216,19 @@ impl DescribeSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeSubscriptionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSubscriptionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSubscriptionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSubscriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1104,12 +1240,14 @@ impl fmt::Display for DescribeSubscriptionError {
         match *self {
             DescribeSubscriptionError::InternalError(ref cause) => write!(f, "{}", cause),
             DescribeSubscriptionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSubscriptionError {}
 /// Errors returned by DisassociateDRTLogBucket
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateDRTLogBucketError {
     /// <p>In order to grant the necessary access to the DDoS Response Team, the user submitting <code>AssociateDRTRole</code> must have the <code>iam:PassRole</code> permission. This error indicates the user did not have the appropriate permissions. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">Granting a User Permissions to Pass a Role to an AWS Service</a>. </p>
     AccessDeniedForDependency(String),
@@ -1123,6 +1261,8 @@ pub enum DisassociateDRTLogBucketError {
     OptimisticLock(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateDRTLogBucketError {
@@ -1130,37 +1270,43 @@ impl DisassociateDRTLogBucketError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDRTLogBucketError::AccessDeniedForDependency(err.msg),
+                        &res,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateDRTLogBucketError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateDRTLogBucketError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "NoAssociatedRoleException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::NoAssociatedRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateDRTLogBucketError::NoAssociatedRole(err.msg),
+                        &res,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::OptimisticLock(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateDRTLogBucketError::OptimisticLock(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateDRTLogBucketError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateDRTLogBucketError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1178,12 +1324,14 @@ impl fmt::Display for DisassociateDRTLogBucketError {
             DisassociateDRTLogBucketError::NoAssociatedRole(ref cause) => write!(f, "{}", cause),
             DisassociateDRTLogBucketError::OptimisticLock(ref cause) => write!(f, "{}", cause),
             DisassociateDRTLogBucketError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateDRTLogBucketError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateDRTLogBucketError {}
 /// Errors returned by DisassociateDRTRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateDRTRoleError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
@@ -1193,6 +1341,8 @@ pub enum DisassociateDRTRoleError {
     OptimisticLock(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateDRTRoleError {
@@ -1200,23 +1350,31 @@ impl DisassociateDRTRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DisassociateDRTRoleError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateDRTRoleError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        DisassociateDRTRoleError::OptimisticLock(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateDRTRoleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateDRTRoleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1230,15 +1388,19 @@ impl fmt::Display for DisassociateDRTRoleError {
             DisassociateDRTRoleError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             DisassociateDRTRoleError::OptimisticLock(ref cause) => write!(f, "{}", cause),
             DisassociateDRTRoleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateDRTRoleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateDRTRoleError {}
 /// Errors returned by GetSubscriptionState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSubscriptionStateError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSubscriptionStateError {
@@ -1246,10 +1408,13 @@ impl GetSubscriptionStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetSubscriptionStateError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetSubscriptionStateError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSubscriptionStateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1260,12 +1425,14 @@ impl fmt::Display for GetSubscriptionStateError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetSubscriptionStateError::InternalError(ref cause) => write!(f, "{}", cause),
+            GetSubscriptionStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSubscriptionStateError {}
 /// Errors returned by ListAttacks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAttacksError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
@@ -1273,6 +1440,8 @@ pub enum ListAttacksError {
     InvalidOperation(String),
     /// <p>Exception that indicates that the parameters passed to the API are invalid. </p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAttacksError {
@@ -1280,16 +1449,16 @@ impl ListAttacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListAttacksError::InternalError(err.msg))
+                    return RusotoError::service(ListAttacksError::InternalError(err.msg), &res)
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListAttacksError::InvalidOperation(err.msg))
+                    return RusotoError::service(ListAttacksError::InvalidOperation(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAttacksError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListAttacksError::InvalidParameter(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAttacksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1302,12 +1471,14 @@ impl fmt::Display for ListAttacksError {
             ListAttacksError::InternalError(ref cause) => write!(f, "{}", cause),
             ListAttacksError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             ListAttacksError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            ListAttacksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAttacksError {}
 /// Errors returned by ListProtections
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProtectionsError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
@@ -1315,6 +1486,8 @@ pub enum ListProtectionsError {
     InvalidPaginationToken(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProtectionsError {
@@ -1322,18 +1495,22 @@ impl ListProtectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListProtectionsError::InternalError(err.msg))
+                    return RusotoError::service(ListProtectionsError::InternalError(err.msg), &res)
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListProtectionsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProtectionsError::InvalidPaginationToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProtectionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListProtectionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProtectionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1346,12 +1523,14 @@ impl fmt::Display for ListProtectionsError {
             ListProtectionsError::InternalError(ref cause) => write!(f, "{}", cause),
             ListProtectionsError::InvalidPaginationToken(ref cause) => write!(f, "{}", cause),
             ListProtectionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListProtectionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProtectionsError {}
 /// Errors returned by UpdateEmergencyContactSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEmergencyContactSettingsError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
@@ -1361,6 +1540,8 @@ pub enum UpdateEmergencyContactSettingsError {
     OptimisticLock(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEmergencyContactSettingsError {
@@ -1370,27 +1551,36 @@ impl UpdateEmergencyContactSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateEmergencyContactSettingsError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateEmergencyContactSettingsError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateEmergencyContactSettingsError::OptimisticLock(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateEmergencyContactSettingsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateEmergencyContactSettingsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1410,12 +1600,16 @@ impl fmt::Display for UpdateEmergencyContactSettingsError {
             UpdateEmergencyContactSettingsError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateEmergencyContactSettingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateEmergencyContactSettingsError {}
 /// Errors returned by UpdateSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSubscriptionError {
     /// <p>Exception that indicates that a problem occurred with the service infrastructure. You can retry the request.</p>
     InternalError(String),
@@ -1427,6 +1621,8 @@ pub enum UpdateSubscriptionError {
     OptimisticLock(String),
     /// <p>Exception indicating the specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSubscriptionError {
@@ -1434,24 +1630,37 @@ impl UpdateSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateSubscriptionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateSubscriptionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LockedSubscriptionException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::LockedSubscription(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSubscriptionError::LockedSubscription(err.msg),
+                        &res,
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        UpdateSubscriptionError::OptimisticLock(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateSubscriptionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSubscriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1466,6 +1675,7 @@ impl fmt::Display for UpdateSubscriptionError {
             UpdateSubscriptionError::LockedSubscription(ref cause) => write!(f, "{}", cause),
             UpdateSubscriptionError::OptimisticLock(ref cause) => write!(f, "{}", cause),
             UpdateSubscriptionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1591,6 +1801,7 @@ impl ShieldClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ShieldClient {
         ShieldClient {
             client: Client::shared(),
@@ -1616,235 +1827,489 @@ impl ShieldClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ShieldClient {
         ShieldClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ShieldClient> {
+        rusoto_core::ClientBuilder::new(ShieldClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ShieldClient {
+        ShieldClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Shield for ShieldClient {
-    /// <p>Authorizes the DDoS Response team (DRT) to access the specified Amazon S3 bucket containing your AWS WAF logs. You can associate up to 10 Amazon S3 buckets with your subscription.</p> <p>To use the services of the DRT and make an <code>AssociateDRTLogBucket</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
-    async fn associate_drt_log_bucket(
+impl ShieldClient {
+    /// Calls `associate_drt_log_bucket`, also returning the metadata of the response.
+    pub async fn associate_drt_log_bucket_with_metadata(
         &self,
         input: AssociateDRTLogBucketRequest,
-    ) -> Result<AssociateDRTLogBucketResponse, RusotoError<AssociateDRTLogBucketError>> {
+    ) -> Result<
+        (AssociateDRTLogBucketResponse, ResponseMetadata),
+        RusotoError<AssociateDRTLogBucketError>,
+    > {
+        let request = self.build_associate_drt_log_bucket_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateDRTLogBucketResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateDRTLogBucketError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_drt_log_bucket` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_drt_log_bucket(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateDRTLogBucketRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_drt_log_bucket_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_drt_log_bucket_request(
+        &self,
+        input: AssociateDRTLogBucketRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("AssociateDRTLogBucket");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTLogBucket");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_drt_role`, also returning the metadata of the response.
+    pub async fn associate_drt_role_with_metadata(
+        &self,
+        input: AssociateDRTRoleRequest,
+    ) -> Result<(AssociateDRTRoleResponse, ResponseMetadata), RusotoError<AssociateDRTRoleError>>
+    {
+        let request = self.build_associate_drt_role_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateDRTLogBucketResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateDRTRoleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateDRTLogBucketError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateDRTRoleError::from_response(response))
         }
     }
 
-    /// <p>Authorizes the DDoS Response team (DRT), using the specified role, to access your AWS account to assist with DDoS attack mitigation during potential attacks. This enables the DRT to inspect your AWS WAF configuration and create or update AWS WAF rules and web ACLs.</p> <p>You can associate only one <code>RoleArn</code> with your subscription. If you submit an <code>AssociateDRTRole</code> request for an account that already has an associated role, the new <code>RoleArn</code> will replace the existing <code>RoleArn</code>. </p> <p>Prior to making the <code>AssociateDRTRole</code> request, you must attach the <a href="https://console.aws.amazon.com/iam/home?#/policies/arn:aws:iam::aws:policy/service-role/AWSShieldDRTAccessPolicy">AWSShieldDRTAccessPolicy</a> managed policy to the role you will specify in the request. For more information see <a href=" https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_manage-attach-detach.html">Attaching and Detaching IAM Policies</a>. The role must also trust the service principal <code> drt.shield.amazonaws.com</code>. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_elements_principal.html">IAM JSON Policy Elements: Principal</a>.</p> <p>The DRT will have access only to your AWS WAF and Shield resources. By submitting this request, you authorize the DRT to inspect your AWS WAF and Shield configuration and create and update AWS WAF rules and web ACLs on your behalf. The DRT takes these actions only if explicitly authorized by you.</p> <p>You must have the <code>iam:PassRole</code> permission to make an <code>AssociateDRTRole</code> request. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">Granting a User Permissions to Pass a Role to an AWS Service</a>. </p> <p>To use the services of the DRT and make an <code>AssociateDRTRole</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
-    async fn associate_drt_role(
+    /// Presigns the request `associate_drt_role` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_drt_role(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateDRTRoleRequest,
-    ) -> Result<AssociateDRTRoleResponse, RusotoError<AssociateDRTRoleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_drt_role_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_drt_role_request(&self, input: AssociateDRTRoleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("AssociateDRTRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTRole");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_protection`, also returning the metadata of the response.
+    pub async fn create_protection_with_metadata(
+        &self,
+        input: CreateProtectionRequest,
+    ) -> Result<(CreateProtectionResponse, ResponseMetadata), RusotoError<CreateProtectionError>>
+    {
+        let request = self.build_create_protection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateDRTRoleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProtectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateDRTRoleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProtectionError::from_response(response))
         }
     }
 
-    /// <p>Enables AWS Shield Advanced for a specific AWS resource. The resource can be an Amazon CloudFront distribution, Elastic Load Balancing load balancer, AWS Global Accelerator accelerator, Elastic IP Address, or an Amazon Route 53 hosted zone.</p> <p>You can add protection to only a single resource with each CreateProtection request. If you want to add protection to multiple resources at once, use the <a href="https://console.aws.amazon.com/waf/">AWS WAF console</a>. For more information see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/getting-started-ddos.html">Getting Started with AWS Shield Advanced</a> and <a href="https://docs.aws.amazon.com/waf/latest/developerguide/configure-new-protection.html">Add AWS Shield Advanced Protection to more AWS Resources</a>.</p>
-    async fn create_protection(
+    /// Presigns the request `create_protection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_protection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProtectionRequest,
-    ) -> Result<CreateProtectionResponse, RusotoError<CreateProtectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_protection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_protection_request(&self, input: CreateProtectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("CreateProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.CreateProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_subscription`, also returning the metadata of the response.
+    pub async fn create_subscription_with_metadata(
+        &self,
+    ) -> Result<(CreateSubscriptionResponse, ResponseMetadata), RusotoError<CreateSubscriptionError>>
+    {
+        let request = self.build_create_subscription_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProtectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSubscriptionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProtectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSubscriptionError::from_response(response))
         }
     }
 
-    /// <p>Activates AWS Shield Advanced for an account.</p> <p>As part of this request you can specify <code>EmergencySettings</code> that automaticaly grant the DDoS response team (DRT) needed permissions to assist you during a suspected DDoS attack. For more information see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/authorize-DRT.html">Authorize the DDoS Response Team to Create Rules and Web ACLs on Your Behalf</a>.</p> <p>To use the services of the DRT, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p> <p>When you initally create a subscription, your subscription is set to be automatically renewed at the end of the existing subscription period. You can change this by submitting an <code>UpdateSubscription</code> request. </p>
-    async fn create_subscription(
+    /// Presigns the request `create_subscription` would send with `presigner` instead of sending it.
+    pub async fn presign_create_subscription(
         &self,
-    ) -> Result<CreateSubscriptionResponse, RusotoError<CreateSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_subscription_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_subscription_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("CreateSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.CreateSubscription");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `delete_protection`, also returning the metadata of the response.
+    pub async fn delete_protection_with_metadata(
+        &self,
+        input: DeleteProtectionRequest,
+    ) -> Result<(DeleteProtectionResponse, ResponseMetadata), RusotoError<DeleteProtectionError>>
+    {
+        let request = self.build_delete_protection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSubscriptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteProtectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteProtectionError::from_response(response))
         }
     }
 
-    /// <p>Deletes an AWS Shield Advanced <a>Protection</a>.</p>
-    async fn delete_protection(
+    /// Presigns the request `delete_protection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_protection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProtectionRequest,
-    ) -> Result<DeleteProtectionResponse, RusotoError<DeleteProtectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_protection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_protection_request(&self, input: DeleteProtectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DeleteProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DeleteProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_subscription`, also returning the metadata of the response.
+    pub async fn delete_subscription_with_metadata(
+        &self,
+    ) -> Result<(DeleteSubscriptionResponse, ResponseMetadata), RusotoError<DeleteSubscriptionError>>
+    {
+        let request = self.build_delete_subscription_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteProtectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSubscriptionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProtectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSubscriptionError::from_response(response))
         }
     }
 
-    /// <p>Removes AWS Shield Advanced from an account. AWS Shield Advanced requires a 1-year subscription commitment. You cannot delete a subscription prior to the completion of that commitment. </p>
-    async fn delete_subscription(
+    /// Presigns the request `delete_subscription` would send with `presigner` instead of sending it.
+    pub async fn presign_delete_subscription(
         &self,
-    ) -> Result<DeleteSubscriptionResponse, RusotoError<DeleteSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_subscription_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_subscription_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DeleteSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DeleteSubscription");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_attack`, also returning the metadata of the response.
+    pub async fn describe_attack_with_metadata(
+        &self,
+        input: DescribeAttackRequest,
+    ) -> Result<(DescribeAttackResponse, ResponseMetadata), RusotoError<DescribeAttackError>> {
+        let request = self.build_describe_attack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSubscriptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAttackResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAttackError::from_response(response))
         }
     }
 
-    /// <p>Describes the details of a DDoS attack. </p>
-    async fn describe_attack(
+    /// Presigns the request `describe_attack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_attack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAttackRequest,
-    ) -> Result<DescribeAttackResponse, RusotoError<DescribeAttackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_attack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_attack_request(&self, input: DescribeAttackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeAttack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeAttack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_drt_access`, also returning the metadata of the response.
+    pub async fn describe_drt_access_with_metadata(
+        &self,
+    ) -> Result<(DescribeDRTAccessResponse, ResponseMetadata), RusotoError<DescribeDRTAccessError>>
+    {
+        let request = self.build_describe_drt_access_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAttackResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDRTAccessResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAttackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDRTAccessError::from_response(response))
         }
     }
 
-    /// <p>Returns the current role and list of Amazon S3 log buckets used by the DDoS Response team (DRT) to access your AWS account while assisting with attack mitigation.</p>
-    async fn describe_drt_access(
+    /// Presigns the request `describe_drt_access` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_drt_access(
         &self,
-    ) -> Result<DescribeDRTAccessResponse, RusotoError<DescribeDRTAccessError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_drt_access_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_drt_access_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeDRTAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeDRTAccess");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_emergency_contact_settings`, also returning the metadata of the response.
+    pub async fn describe_emergency_contact_settings_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeEmergencyContactSettingsResponse, ResponseMetadata),
+        RusotoError<DescribeEmergencyContactSettingsError>,
+    > {
+        let request = self.build_describe_emergency_contact_settings_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDRTAccessResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEmergencyContactSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDRTAccessError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEmergencyContactSettingsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists the email addresses that the DRT can use to contact you during a suspected attack.</p>
-    async fn describe_emergency_contact_settings(
+    /// Presigns the request `describe_emergency_contact_settings` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_emergency_contact_settings(
         &self,
-    ) -> Result<
-        DescribeEmergencyContactSettingsResponse,
-        RusotoError<DescribeEmergencyContactSettingsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_emergency_contact_settings_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_emergency_contact_settings_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeEmergencyContactSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1853,84 +2318,163 @@ impl Shield for ShieldClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_protection`, also returning the metadata of the response.
+    pub async fn describe_protection_with_metadata(
+        &self,
+        input: DescribeProtectionRequest,
+    ) -> Result<(DescribeProtectionResponse, ResponseMetadata), RusotoError<DescribeProtectionError>>
+    {
+        let request = self.build_describe_protection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEmergencyContactSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProtectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEmergencyContactSettingsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProtectionError::from_response(response))
         }
     }
 
-    /// <p>Lists the details of a <a>Protection</a> object.</p>
-    async fn describe_protection(
+    /// Presigns the request `describe_protection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_protection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProtectionRequest,
-    ) -> Result<DescribeProtectionResponse, RusotoError<DescribeProtectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_protection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_protection_request(&self, input: DescribeProtectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_subscription`, also returning the metadata of the response.
+    pub async fn describe_subscription_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeSubscriptionResponse, ResponseMetadata),
+        RusotoError<DescribeSubscriptionError>,
+    > {
+        let request = self.build_describe_subscription_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProtectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSubscriptionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProtectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSubscriptionError::from_response(response))
         }
     }
 
-    /// <p>Provides details about the AWS Shield Advanced subscription for an account.</p>
-    async fn describe_subscription(
+    /// Presigns the request `describe_subscription` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_subscription(
         &self,
-    ) -> Result<DescribeSubscriptionResponse, RusotoError<DescribeSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_subscription_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_subscription_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeSubscription");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `disassociate_drt_log_bucket`, also returning the metadata of the response.
+    pub async fn disassociate_drt_log_bucket_with_metadata(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> Result<
+        (DisassociateDRTLogBucketResponse, ResponseMetadata),
+        RusotoError<DisassociateDRTLogBucketError>,
+    > {
+        let request = self.build_disassociate_drt_log_bucket_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSubscriptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateDRTLogBucketResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateDRTLogBucketError::from_response(response))
         }
     }
 
-    /// <p>Removes the DDoS Response team's (DRT) access to the specified Amazon S3 bucket containing your AWS WAF logs.</p> <p>To make a <code>DisassociateDRTLogBucket</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>. However, if you are not subscribed to one of these support plans, but had been previously and had granted the DRT access to your account, you can submit a <code>DisassociateDRTLogBucket</code> request to remove this access.</p>
-    async fn disassociate_drt_log_bucket(
+    /// Presigns the request `disassociate_drt_log_bucket` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_drt_log_bucket(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateDRTLogBucketRequest,
-    ) -> Result<DisassociateDRTLogBucketResponse, RusotoError<DisassociateDRTLogBucketError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_drt_log_bucket_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_drt_log_bucket_request(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DisassociateDRTLogBucket");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1940,137 +2484,267 @@ impl Shield for ShieldClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_drt_role`, also returning the metadata of the response.
+    pub async fn disassociate_drt_role_with_metadata(
+        &self,
+    ) -> Result<
+        (DisassociateDRTRoleResponse, ResponseMetadata),
+        RusotoError<DisassociateDRTRoleError>,
+    > {
+        let request = self.build_disassociate_drt_role_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateDRTLogBucketResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateDRTRoleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateDRTLogBucketError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateDRTRoleError::from_response(response))
         }
     }
 
-    /// <p>Removes the DDoS Response team's (DRT) access to your AWS account.</p> <p>To make a <code>DisassociateDRTRole</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>. However, if you are not subscribed to one of these support plans, but had been previously and had granted the DRT access to your account, you can submit a <code>DisassociateDRTRole</code> request to remove this access.</p>
-    async fn disassociate_drt_role(
+    /// Presigns the request `disassociate_drt_role` would send with `presigner` instead of sending it.
+    pub async fn presign_disassociate_drt_role(
         &self,
-    ) -> Result<DisassociateDRTRoleResponse, RusotoError<DisassociateDRTRoleError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_drt_role_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_drt_role_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DisassociateDRTRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DisassociateDRTRole");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_subscription_state`, also returning the metadata of the response.
+    pub async fn get_subscription_state_with_metadata(
+        &self,
+    ) -> Result<
+        (GetSubscriptionStateResponse, ResponseMetadata),
+        RusotoError<GetSubscriptionStateError>,
+    > {
+        let request = self.build_get_subscription_state_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateDRTRoleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSubscriptionStateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateDRTRoleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSubscriptionStateError::from_response(response))
         }
     }
 
-    /// <p>Returns the <code>SubscriptionState</code>, either <code>Active</code> or <code>Inactive</code>.</p>
-    async fn get_subscription_state(
+    /// Presigns the request `get_subscription_state` would send with `presigner` instead of sending it.
+    pub async fn presign_get_subscription_state(
         &self,
-    ) -> Result<GetSubscriptionStateResponse, RusotoError<GetSubscriptionStateError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_subscription_state_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_subscription_state_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("GetSubscriptionState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.GetSubscriptionState");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `list_attacks`, also returning the metadata of the response.
+    pub async fn list_attacks_with_metadata(
+        &self,
+        input: ListAttacksRequest,
+    ) -> Result<(ListAttacksResponse, ResponseMetadata), RusotoError<ListAttacksError>> {
+        let request = self.build_list_attacks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSubscriptionStateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAttacksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSubscriptionStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAttacksError::from_response(response))
         }
     }
 
-    /// <p>Returns all ongoing DDoS attacks or all DDoS attacks during a specified time period.</p>
-    async fn list_attacks(
+    /// Presigns the request `list_attacks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_attacks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAttacksRequest,
-    ) -> Result<ListAttacksResponse, RusotoError<ListAttacksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_attacks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_attacks_request(&self, input: ListAttacksRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("ListAttacks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.ListAttacks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_protections`, also returning the metadata of the response.
+    pub async fn list_protections_with_metadata(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> Result<(ListProtectionsResponse, ResponseMetadata), RusotoError<ListProtectionsError>>
+    {
+        let request = self.build_list_protections_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAttacksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProtectionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAttacksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProtectionsError::from_response(response))
         }
     }
 
-    /// <p>Lists all <a>Protection</a> objects for the account.</p>
-    async fn list_protections(
+    /// Presigns the request `list_protections` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_protections(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProtectionsRequest,
-    ) -> Result<ListProtectionsResponse, RusotoError<ListProtectionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_protections_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_protections_request(&self, input: ListProtectionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("ListProtections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.ListProtections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_emergency_contact_settings`, also returning the metadata of the response.
+    pub async fn update_emergency_contact_settings_with_metadata(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> Result<
+        (UpdateEmergencyContactSettingsResponse, ResponseMetadata),
+        RusotoError<UpdateEmergencyContactSettingsError>,
+    > {
+        let request = self.build_update_emergency_contact_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListProtectionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateEmergencyContactSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProtectionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateEmergencyContactSettingsError::from_response(response))
         }
     }
 
-    /// <p>Updates the details of the list of email addresses that the DRT can use to contact you during a suspected attack.</p>
-    async fn update_emergency_contact_settings(
+    /// Presigns the request `update_emergency_contact_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_emergency_contact_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEmergencyContactSettingsRequest,
-    ) -> Result<
-        UpdateEmergencyContactSettingsResponse,
-        RusotoError<UpdateEmergencyContactSettingsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_emergency_contact_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_emergency_contact_settings_request(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("UpdateEmergencyContactSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2080,47 +2754,314 @@ impl Shield for ShieldClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_subscription`, also returning the metadata of the response.
+    pub async fn update_subscription_with_metadata(
+        &self,
+        input: UpdateSubscriptionRequest,
+    ) -> Result<(UpdateSubscriptionResponse, ResponseMetadata), RusotoError<UpdateSubscriptionError>>
+    {
+        let request = self.build_update_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateEmergencyContactSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSubscriptionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEmergencyContactSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSubscriptionError::from_response(response))
         }
     }
 
-    /// <p>Updates the details of an existing subscription. Only enter values for parameters you want to change. Empty parameters are not updated.</p>
-    async fn update_subscription(
+    /// Presigns the request `update_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_subscription(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSubscriptionRequest,
-    ) -> Result<UpdateSubscriptionResponse, RusotoError<UpdateSubscriptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_subscription_request(&self, input: UpdateSubscriptionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("UpdateSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.UpdateSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAttacks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_attacks_pages(
+        &self,
+        input: ListAttacksRequest,
+    ) -> impl futures::Stream<Item = Result<ListAttacksResponse, RusotoError<ListAttacksError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ShieldClient, input| async move { client.list_attacks(input).await },
+            |input: &ListAttacksRequest, output: &ListAttacksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAttacksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AttackSummaries` of every page of `ListAttacks` results for `input`, as `list_attacks_pages` requests them.
+    pub fn list_attacks_stream(
+        &self,
+        input: ListAttacksRequest,
+    ) -> impl futures::Stream<Item = Result<AttackSummary, RusotoError<ListAttacksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_attacks_pages(input), |page| {
+            page.attack_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListProtections` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_protections_pages(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListProtectionsResponse, RusotoError<ListProtectionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ShieldClient, input| async move { client.list_protections(input).await },
+            |input: &ListProtectionsRequest, output: &ListProtectionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListProtectionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Protections` of every page of `ListProtections` results for `input`, as `list_protections_pages` requests them.
+    pub fn list_protections_stream(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> impl futures::Stream<Item = Result<Protection, RusotoError<ListProtectionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_protections_pages(input), |page| {
+            page.protections.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Shield for ShieldClient {
+    /// <p>Authorizes the DDoS Response team (DRT) to access the specified Amazon S3 bucket containing your AWS WAF logs. You can associate up to 10 Amazon S3 buckets with your subscription.</p> <p>To use the services of the DRT and make an <code>AssociateDRTLogBucket</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
+    async fn associate_drt_log_bucket(
+        &self,
+        input: AssociateDRTLogBucketRequest,
+    ) -> Result<AssociateDRTLogBucketResponse, RusotoError<AssociateDRTLogBucketError>> {
+        self.associate_drt_log_bucket_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSubscriptionResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSubscriptionError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Authorizes the DDoS Response team (DRT), using the specified role, to access your AWS account to assist with DDoS attack mitigation during potential attacks. This enables the DRT to inspect your AWS WAF configuration and create or update AWS WAF rules and web ACLs.</p> <p>You can associate only one <code>RoleArn</code> with your subscription. If you submit an <code>AssociateDRTRole</code> request for an account that already has an associated role, the new <code>RoleArn</code> will replace the existing <code>RoleArn</code>. </p> <p>Prior to making the <code>AssociateDRTRole</code> request, you must attach the <a href="https://console.aws.amazon.com/iam/home?#/policies/arn:aws:iam::aws:policy/service-role/AWSShieldDRTAccessPolicy">AWSShieldDRTAccessPolicy</a> managed policy to the role you will specify in the request. For more information see <a href=" https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_manage-attach-detach.html">Attaching and Detaching IAM Policies</a>. The role must also trust the service principal <code> drt.shield.amazonaws.com</code>. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_elements_principal.html">IAM JSON Policy Elements: Principal</a>.</p> <p>The DRT will have access only to your AWS WAF and Shield resources. By submitting this request, you authorize the DRT to inspect your AWS WAF and Shield configuration and create and update AWS WAF rules and web ACLs on your behalf. The DRT takes these actions only if explicitly authorized by you.</p> <p>You must have the <code>iam:PassRole</code> permission to make an <code>AssociateDRTRole</code> request. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">Granting a User Permissions to Pass a Role to an AWS Service</a>. </p> <p>To use the services of the DRT and make an <code>AssociateDRTRole</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
+    async fn associate_drt_role(
+        &self,
+        input: AssociateDRTRoleRequest,
+    ) -> Result<AssociateDRTRoleResponse, RusotoError<AssociateDRTRoleError>> {
+        self.associate_drt_role_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables AWS Shield Advanced for a specific AWS resource. The resource can be an Amazon CloudFront distribution, Elastic Load Balancing load balancer, AWS Global Accelerator accelerator, Elastic IP Address, or an Amazon Route 53 hosted zone.</p> <p>You can add protection to only a single resource with each CreateProtection request. If you want to add protection to multiple resources at once, use the <a href="https://console.aws.amazon.com/waf/">AWS WAF console</a>. For more information see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/getting-started-ddos.html">Getting Started with AWS Shield Advanced</a> and <a href="https://docs.aws.amazon.com/waf/latest/developerguide/configure-new-protection.html">Add AWS Shield Advanced Protection to more AWS Resources</a>.</p>
+    async fn create_protection(
+        &self,
+        input: CreateProtectionRequest,
+    ) -> Result<CreateProtectionResponse, RusotoError<CreateProtectionError>> {
+        self.create_protection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Activates AWS Shield Advanced for an account.</p> <p>As part of this request you can specify <code>EmergencySettings</code> that automaticaly grant the DDoS response team (DRT) needed permissions to assist you during a suspected DDoS attack. For more information see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/authorize-DRT.html">Authorize the DDoS Response Team to Create Rules and Web ACLs on Your Behalf</a>.</p> <p>To use the services of the DRT, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p> <p>When you initally create a subscription, your subscription is set to be automatically renewed at the end of the existing subscription period. You can change this by submitting an <code>UpdateSubscription</code> request. </p>
+    async fn create_subscription(
+        &self,
+    ) -> Result<CreateSubscriptionResponse, RusotoError<CreateSubscriptionError>> {
+        self.create_subscription_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an AWS Shield Advanced <a>Protection</a>.</p>
+    async fn delete_protection(
+        &self,
+        input: DeleteProtectionRequest,
+    ) -> Result<DeleteProtectionResponse, RusotoError<DeleteProtectionError>> {
+        self.delete_protection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes AWS Shield Advanced from an account. AWS Shield Advanced requires a 1-year subscription commitment. You cannot delete a subscription prior to the completion of that commitment. </p>
+    async fn delete_subscription(
+        &self,
+    ) -> Result<DeleteSubscriptionResponse, RusotoError<DeleteSubscriptionError>> {
+        self.delete_subscription_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the details of a DDoS attack. </p>
+    async fn describe_attack(
+        &self,
+        input: DescribeAttackRequest,
+    ) -> Result<DescribeAttackResponse, RusotoError<DescribeAttackError>> {
+        self.describe_attack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the current role and list of Amazon S3 log buckets used by the DDoS Response team (DRT) to access your AWS account while assisting with attack mitigation.</p>
+    async fn describe_drt_access(
+        &self,
+    ) -> Result<DescribeDRTAccessResponse, RusotoError<DescribeDRTAccessError>> {
+        self.describe_drt_access_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the email addresses that the DRT can use to contact you during a suspected attack.</p>
+    async fn describe_emergency_contact_settings(
+        &self,
+    ) -> Result<
+        DescribeEmergencyContactSettingsResponse,
+        RusotoError<DescribeEmergencyContactSettingsError>,
+    > {
+        self.describe_emergency_contact_settings_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the details of a <a>Protection</a> object.</p>
+    async fn describe_protection(
+        &self,
+        input: DescribeProtectionRequest,
+    ) -> Result<DescribeProtectionResponse, RusotoError<DescribeProtectionError>> {
+        self.describe_protection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides details about the AWS Shield Advanced subscription for an account.</p>
+    async fn describe_subscription(
+        &self,
+    ) -> Result<DescribeSubscriptionResponse, RusotoError<DescribeSubscriptionError>> {
+        self.describe_subscription_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the DDoS Response team's (DRT) access to the specified Amazon S3 bucket containing your AWS WAF logs.</p> <p>To make a <code>DisassociateDRTLogBucket</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>. However, if you are not subscribed to one of these support plans, but had been previously and had granted the DRT access to your account, you can submit a <code>DisassociateDRTLogBucket</code> request to remove this access.</p>
+    async fn disassociate_drt_log_bucket(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> Result<DisassociateDRTLogBucketResponse, RusotoError<DisassociateDRTLogBucketError>> {
+        self.disassociate_drt_log_bucket_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the DDoS Response team's (DRT) access to your AWS account.</p> <p>To make a <code>DisassociateDRTRole</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>. However, if you are not subscribed to one of these support plans, but had been previously and had granted the DRT access to your account, you can submit a <code>DisassociateDRTRole</code> request to remove this access.</p>
+    async fn disassociate_drt_role(
+        &self,
+    ) -> Result<DisassociateDRTRoleResponse, RusotoError<DisassociateDRTRoleError>> {
+        self.disassociate_drt_role_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <code>SubscriptionState</code>, either <code>Active</code> or <code>Inactive</code>.</p>
+    async fn get_subscription_state(
+        &self,
+    ) -> Result<GetSubscriptionStateResponse, RusotoError<GetSubscriptionStateError>> {
+        self.get_subscription_state_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all ongoing DDoS attacks or all DDoS attacks during a specified time period.</p>
+    async fn list_attacks(
+        &self,
+        input: ListAttacksRequest,
+    ) -> Result<ListAttacksResponse, RusotoError<ListAttacksError>> {
+        self.list_attacks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all <a>Protection</a> objects for the account.</p>
+    async fn list_protections(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> Result<ListProtectionsResponse, RusotoError<ListProtectionsError>> {
+        self.list_protections_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the details of the list of email addresses that the DRT can use to contact you during a suspected attack.</p>
+    async fn update_emergency_contact_settings(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> Result<
+        UpdateEmergencyContactSettingsResponse,
+        RusotoError<UpdateEmergencyContactSettingsError>,
+    > {
+        self.update_emergency_contact_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the details of an existing subscription. Only enter values for parameters you want to change. Empty parameters are not updated.</p>
+    async fn update_subscription(
+        &self,
+        input: UpdateSubscriptionRequest,
+    ) -> Result<UpdateSubscriptionResponse, RusotoError<UpdateSubscriptionError>> {
+        self.update_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
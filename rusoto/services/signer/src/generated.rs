@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -655,6 +655,7 @@ pub struct UntagResourceResponse {}
 
 /// Errors returned by CancelSigningProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelSigningProfileError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -664,6 +665,8 @@ pub enum CancelSigningProfileError {
     ResourceNotFound(String),
     /// <p>The signing job has been throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelSigningProfileError {
@@ -671,23 +674,31 @@ impl CancelSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CancelSigningProfileError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CancelSigningProfileError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelSigningProfileError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelSigningProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelSigningProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelSigningProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CancelSigningProfileError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelSigningProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -701,12 +712,14 @@ impl fmt::Display for CancelSigningProfileError {
             CancelSigningProfileError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             CancelSigningProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CancelSigningProfileError::Throttling(ref cause) => write!(f, "{}", cause),
+            CancelSigningProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelSigningProfileError {}
 /// Errors returned by DescribeSigningJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSigningJobError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -714,6 +727,8 @@ pub enum DescribeSigningJobError {
     InternalServiceError(String),
     /// <p>A specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSigningJobError {
@@ -721,18 +736,25 @@ impl DescribeSigningJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeSigningJobError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DescribeSigningJobError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeSigningJobError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSigningJobError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSigningJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeSigningJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSigningJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -745,12 +767,14 @@ impl fmt::Display for DescribeSigningJobError {
             DescribeSigningJobError::AccessDenied(ref cause) => write!(f, "{}", cause),
             DescribeSigningJobError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DescribeSigningJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSigningJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSigningJobError {}
 /// Errors returned by GetSigningPlatform
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSigningPlatformError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -758,6 +782,8 @@ pub enum GetSigningPlatformError {
     InternalServiceError(String),
     /// <p>A specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSigningPlatformError {
@@ -765,18 +791,25 @@ impl GetSigningPlatformError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSigningPlatformError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetSigningPlatformError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetSigningPlatformError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSigningPlatformError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSigningPlatformError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetSigningPlatformError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSigningPlatformError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -789,12 +822,14 @@ impl fmt::Display for GetSigningPlatformError {
             GetSigningPlatformError::AccessDenied(ref cause) => write!(f, "{}", cause),
             GetSigningPlatformError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             GetSigningPlatformError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetSigningPlatformError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSigningPlatformError {}
 /// Errors returned by GetSigningProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSigningProfileError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -804,6 +839,8 @@ pub enum GetSigningProfileError {
     ResourceNotFound(String),
     /// <p>The signing job has been throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSigningProfileError {
@@ -811,21 +848,28 @@ impl GetSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetSigningProfileError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetSigningProfileError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSigningProfileError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSigningProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetSigningProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSigningProfileError::Throttling(err.msg))
+                    return RusotoError::service(GetSigningProfileError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSigningProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -839,12 +883,14 @@ impl fmt::Display for GetSigningProfileError {
             GetSigningProfileError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             GetSigningProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetSigningProfileError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetSigningProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSigningProfileError {}
 /// Errors returned by ListSigningJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSigningJobsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -852,6 +898,8 @@ pub enum ListSigningJobsError {
     InternalServiceError(String),
     /// <p>The signing job has been throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSigningJobsError {
@@ -859,18 +907,19 @@ impl ListSigningJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSigningJobsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListSigningJobsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListSigningJobsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSigningJobsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSigningJobsError::Throttling(err.msg))
+                    return RusotoError::service(ListSigningJobsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSigningJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -883,12 +932,14 @@ impl fmt::Display for ListSigningJobsError {
             ListSigningJobsError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListSigningJobsError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListSigningJobsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListSigningJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSigningJobsError {}
 /// Errors returned by ListSigningPlatforms
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSigningPlatformsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -896,6 +947,8 @@ pub enum ListSigningPlatformsError {
     InternalServiceError(String),
     /// <p>The signing job has been throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSigningPlatformsError {
@@ -903,18 +956,25 @@ impl ListSigningPlatformsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSigningPlatformsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListSigningPlatformsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListSigningPlatformsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSigningPlatformsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSigningPlatformsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListSigningPlatformsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSigningPlatformsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -927,12 +987,14 @@ impl fmt::Display for ListSigningPlatformsError {
             ListSigningPlatformsError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListSigningPlatformsError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListSigningPlatformsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListSigningPlatformsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSigningPlatformsError {}
 /// Errors returned by ListSigningProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSigningProfilesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -940,6 +1002,8 @@ pub enum ListSigningProfilesError {
     InternalServiceError(String),
     /// <p>The signing job has been throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSigningProfilesError {
@@ -947,18 +1011,25 @@ impl ListSigningProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSigningProfilesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListSigningProfilesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListSigningProfilesError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSigningProfilesError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSigningProfilesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListSigningProfilesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSigningProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -971,12 +1042,14 @@ impl fmt::Display for ListSigningProfilesError {
             ListSigningProfilesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListSigningProfilesError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListSigningProfilesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListSigningProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSigningProfilesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The request contains invalid parameters for the ARN or tags. This exception also occurs when you call a tagging API on a cancelled signing profile.</p>
     BadRequest(String),
@@ -984,6 +1057,8 @@ pub enum ListTagsForResourceError {
     InternalServiceError(String),
     /// <p>The signing profile was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -991,18 +1066,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1015,12 +1094,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutSigningProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutSigningProfileError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1030,6 +1111,8 @@ pub enum PutSigningProfileError {
     ResourceNotFound(String),
     /// <p>The signing job has been throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutSigningProfileError {
@@ -1037,21 +1120,28 @@ impl PutSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        PutSigningProfileError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(PutSigningProfileError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutSigningProfileError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSigningProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutSigningProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutSigningProfileError::Throttling(err.msg))
+                    return RusotoError::service(PutSigningProfileError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutSigningProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1065,12 +1155,14 @@ impl fmt::Display for PutSigningProfileError {
             PutSigningProfileError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             PutSigningProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutSigningProfileError::Throttling(ref cause) => write!(f, "{}", cause),
+            PutSigningProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutSigningProfileError {}
 /// Errors returned by StartSigningJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartSigningJobError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1080,6 +1172,8 @@ pub enum StartSigningJobError {
     ResourceNotFound(String),
     /// <p>The signing job has been throttled.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartSigningJobError {
@@ -1087,21 +1181,25 @@ impl StartSigningJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartSigningJobError::AccessDenied(err.msg))
+                    return RusotoError::service(StartSigningJobError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(StartSigningJobError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSigningJobError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartSigningJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartSigningJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartSigningJobError::Throttling(err.msg))
+                    return RusotoError::service(StartSigningJobError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartSigningJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1115,12 +1213,14 @@ impl fmt::Display for StartSigningJobError {
             StartSigningJobError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             StartSigningJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartSigningJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartSigningJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartSigningJobError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The request contains invalid parameters for the ARN or tags. This exception also occurs when you call a tagging API on a cancelled signing profile.</p>
     BadRequest(String),
@@ -1128,6 +1228,8 @@ pub enum TagResourceError {
     InternalServiceError(String),
     /// <p>The signing profile was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1135,16 +1237,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1157,12 +1262,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The request contains invalid parameters for the ARN or tags. This exception also occurs when you call a tagging API on a cancelled signing profile.</p>
     BadRequest(String),
@@ -1170,6 +1277,8 @@ pub enum UntagResourceError {
     InternalServiceError(String),
     /// <p>The signing profile was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1177,16 +1286,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1199,6 +1311,7 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1289,6 +1402,7 @@ impl SignerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SignerClient {
         SignerClient {
             client: Client::shared(),
@@ -1314,134 +1428,295 @@ impl SignerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SignerClient {
         SignerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SignerClient> {
+        rusoto_core::ClientBuilder::new(SignerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SignerClient {
+        SignerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Signer for SignerClient {
-    /// <p>Changes the state of an <code>ACTIVE</code> signing profile to <code>CANCELED</code>. A canceled profile is still viewable with the <code>ListSigningProfiles</code> operation, but it cannot perform new signing jobs, and is deleted two years after cancelation.</p>
-    async fn cancel_signing_profile(
+impl SignerClient {
+    /// Calls `cancel_signing_profile`, also returning the metadata of the response.
+    pub async fn cancel_signing_profile_with_metadata(
         &self,
         input: CancelSigningProfileRequest,
-    ) -> Result<(), RusotoError<CancelSigningProfileError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<CancelSigningProfileError>> {
+        let request = self.build_cancel_signing_profile_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CancelSigningProfileError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_signing_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_signing_profile(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelSigningProfileRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_signing_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_signing_profile_request(
+        &self,
+        input: CancelSigningProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/signing-profiles/{profile_name}",
             profile_name = input.profile_name
         );
 
         let mut request = SignedRequest::new("DELETE", "signer", &self.region, &request_uri);
+        request.set_operation("CancelSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_signing_job`, also returning the metadata of the response.
+    pub async fn describe_signing_job_with_metadata(
+        &self,
+        input: DescribeSigningJobRequest,
+    ) -> Result<(DescribeSigningJobResponse, ResponseMetadata), RusotoError<DescribeSigningJobError>>
+    {
+        let request = self.build_describe_signing_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSigningJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelSigningProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeSigningJobError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific code signing job. You specify the job by using the <code>jobId</code> value that is returned by the <a>StartSigningJob</a> operation. </p>
-    async fn describe_signing_job(
+    /// Presigns the request `describe_signing_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_signing_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSigningJobRequest,
-    ) -> Result<DescribeSigningJobResponse, RusotoError<DescribeSigningJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_signing_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_signing_job_request(
+        &self,
+        input: DescribeSigningJobRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/signing-jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("DescribeSigningJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_signing_platform`, also returning the metadata of the response.
+    pub async fn get_signing_platform_with_metadata(
+        &self,
+        input: GetSigningPlatformRequest,
+    ) -> Result<(GetSigningPlatformResponse, ResponseMetadata), RusotoError<GetSigningPlatformError>>
+    {
+        let request = self.build_get_signing_platform_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSigningJobResponse, _>()?;
+                .deserialize::<GetSigningPlatformResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSigningJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSigningPlatformError::from_response(response))
         }
     }
 
-    /// <p>Returns information on a specific signing platform.</p>
-    async fn get_signing_platform(
+    /// Presigns the request `get_signing_platform` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_signing_platform(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSigningPlatformRequest,
-    ) -> Result<GetSigningPlatformResponse, RusotoError<GetSigningPlatformError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_signing_platform_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_signing_platform_request(
+        &self,
+        input: GetSigningPlatformRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/signing-platforms/{platform_id}",
             platform_id = input.platform_id
         );
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("GetSigningPlatform");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_signing_profile`, also returning the metadata of the response.
+    pub async fn get_signing_profile_with_metadata(
+        &self,
+        input: GetSigningProfileRequest,
+    ) -> Result<(GetSigningProfileResponse, ResponseMetadata), RusotoError<GetSigningProfileError>>
+    {
+        let request = self.build_get_signing_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSigningPlatformResponse, _>()?;
+                .deserialize::<GetSigningProfileResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSigningPlatformError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSigningProfileError::from_response(response))
         }
     }
 
-    /// <p>Returns information on a specific signing profile.</p>
-    async fn get_signing_profile(
+    /// Presigns the request `get_signing_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_signing_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSigningProfileRequest,
-    ) -> Result<GetSigningProfileResponse, RusotoError<GetSigningProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_signing_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_signing_profile_request(&self, input: GetSigningProfileRequest) -> SignedRequest {
         let request_uri = format!(
             "/signing-profiles/{profile_name}",
             profile_name = input.profile_name
         );
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("GetSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_signing_jobs`, also returning the metadata of the response.
+    pub async fn list_signing_jobs_with_metadata(
+        &self,
+        input: ListSigningJobsRequest,
+    ) -> Result<(ListSigningJobsResponse, ResponseMetadata), RusotoError<ListSigningJobsError>>
+    {
+        let request = self.build_list_signing_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSigningProfileResponse, _>()?;
+                .deserialize::<ListSigningJobsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSigningProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSigningJobsError::from_response(response))
         }
     }
 
-    /// <p>Lists all your signing jobs. You can use the <code>maxResults</code> parameter to limit the number of signing jobs that are returned in the response. If additional jobs remain to be listed, code signing returns a <code>nextToken</code> value. Use this value in subsequent calls to <code>ListSigningJobs</code> to fetch the remaining values. You can continue calling <code>ListSigningJobs</code> with your <code>maxResults</code> parameter and with new values that code signing returns in the <code>nextToken</code> parameter until all of your signing jobs have been returned. </p>
-    async fn list_signing_jobs(
+    /// Presigns the request `list_signing_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_signing_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSigningJobsRequest,
-    ) -> Result<ListSigningJobsResponse, RusotoError<ListSigningJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_signing_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_signing_jobs_request(&self, input: ListSigningJobsRequest) -> SignedRequest {
         let request_uri = "/signing-jobs";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListSigningJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1462,31 +1737,61 @@ impl Signer for SignerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_signing_platforms`, also returning the metadata of the response.
+    pub async fn list_signing_platforms_with_metadata(
+        &self,
+        input: ListSigningPlatformsRequest,
+    ) -> Result<
+        (ListSigningPlatformsResponse, ResponseMetadata),
+        RusotoError<ListSigningPlatformsError>,
+    > {
+        let request = self.build_list_signing_platforms_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSigningJobsResponse, _>()?;
+                .deserialize::<ListSigningPlatformsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSigningJobsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSigningPlatformsError::from_response(response))
         }
     }
 
-    /// <p>Lists all signing platforms available in code signing that match the request parameters. If additional jobs remain to be listed, code signing returns a <code>nextToken</code> value. Use this value in subsequent calls to <code>ListSigningJobs</code> to fetch the remaining values. You can continue calling <code>ListSigningJobs</code> with your <code>maxResults</code> parameter and with new values that code signing returns in the <code>nextToken</code> parameter until all of your signing jobs have been returned.</p>
-    async fn list_signing_platforms(
+    /// Presigns the request `list_signing_platforms` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_signing_platforms(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSigningPlatformsRequest,
-    ) -> Result<ListSigningPlatformsResponse, RusotoError<ListSigningPlatformsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_signing_platforms_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_signing_platforms_request(
+        &self,
+        input: ListSigningPlatformsRequest,
+    ) -> SignedRequest {
         let request_uri = "/signing-platforms";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListSigningPlatforms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1507,31 +1812,61 @@ impl Signer for SignerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_signing_profiles`, also returning the metadata of the response.
+    pub async fn list_signing_profiles_with_metadata(
+        &self,
+        input: ListSigningProfilesRequest,
+    ) -> Result<
+        (ListSigningProfilesResponse, ResponseMetadata),
+        RusotoError<ListSigningProfilesError>,
+    > {
+        let request = self.build_list_signing_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSigningPlatformsResponse, _>()?;
+                .deserialize::<ListSigningProfilesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSigningPlatformsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSigningProfilesError::from_response(response))
         }
     }
 
-    /// <p>Lists all available signing profiles in your AWS account. Returns only profiles with an <code>ACTIVE</code> status unless the <code>includeCanceled</code> request field is set to <code>true</code>. If additional jobs remain to be listed, code signing returns a <code>nextToken</code> value. Use this value in subsequent calls to <code>ListSigningJobs</code> to fetch the remaining values. You can continue calling <code>ListSigningJobs</code> with your <code>maxResults</code> parameter and with new values that code signing returns in the <code>nextToken</code> parameter until all of your signing jobs have been returned.</p>
-    async fn list_signing_profiles(
+    /// Presigns the request `list_signing_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_signing_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSigningProfilesRequest,
-    ) -> Result<ListSigningProfilesResponse, RusotoError<ListSigningProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_signing_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_signing_profiles_request(
+        &self,
+        input: ListSigningProfilesRequest,
+    ) -> SignedRequest {
         let request_uri = "/signing-profiles";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListSigningProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1546,151 +1881,279 @@ impl Signer for SignerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSigningProfilesResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSigningProfilesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the tags associated with a signing profile resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `put_signing_profile`, also returning the metadata of the response.
+    pub async fn put_signing_profile_with_metadata(
+        &self,
+        input: PutSigningProfileRequest,
+    ) -> Result<(PutSigningProfileResponse, ResponseMetadata), RusotoError<PutSigningProfileError>>
+    {
+        let request = self.build_put_signing_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<PutSigningProfileResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutSigningProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates a signing profile. A signing profile is a code signing template that can be used to carry out a pre-defined signing job. For more information, see <a href="http://docs.aws.amazon.com/signer/latest/developerguide/gs-profile.html">http://docs.aws.amazon.com/signer/latest/developerguide/gs-profile.html</a> </p>
-    async fn put_signing_profile(
+    /// Presigns the request `put_signing_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_signing_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutSigningProfileRequest,
-    ) -> Result<PutSigningProfileResponse, RusotoError<PutSigningProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_signing_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_signing_profile_request(&self, input: PutSigningProfileRequest) -> SignedRequest {
         let request_uri = format!(
             "/signing-profiles/{profile_name}",
             profile_name = input.profile_name
         );
 
         let mut request = SignedRequest::new("PUT", "signer", &self.region, &request_uri);
+        request.set_operation("PutSigningProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_signing_job`, also returning the metadata of the response.
+    pub async fn start_signing_job_with_metadata(
+        &self,
+        input: StartSigningJobRequest,
+    ) -> Result<(StartSigningJobResponse, ResponseMetadata), RusotoError<StartSigningJobError>>
+    {
+        let request = self.build_start_signing_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutSigningProfileResponse, _>()?;
+                .deserialize::<StartSigningJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutSigningProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartSigningJobError::from_response(response))
         }
     }
 
-    /// <p>Initiates a signing job to be performed on the code provided. Signing jobs are viewable by the <code>ListSigningJobs</code> operation for two years after they are performed. Note the following requirements: </p> <ul> <li> <p> You must create an Amazon S3 source bucket. For more information, see <a href="http://docs.aws.amazon.com/AmazonS3/latest/gsg/CreatingABucket.html">Create a Bucket</a> in the <i>Amazon S3 Getting Started Guide</i>. </p> </li> <li> <p>Your S3 source bucket must be version enabled.</p> </li> <li> <p>You must create an S3 destination bucket. Code signing uses your S3 destination bucket to write your signed code.</p> </li> <li> <p>You specify the name of the source and destination buckets when calling the <code>StartSigningJob</code> operation.</p> </li> <li> <p>You must also specify a request token that identifies your request to code signing.</p> </li> </ul> <p>You can call the <a>DescribeSigningJob</a> and the <a>ListSigningJobs</a> actions after you call <code>StartSigningJob</code>.</p> <p>For a Java example that shows how to use this action, see <a href="http://docs.aws.amazon.com/acm/latest/userguide/">http://docs.aws.amazon.com/acm/latest/userguide/</a> </p>
-    async fn start_signing_job(
+    /// Presigns the request `start_signing_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_signing_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartSigningJobRequest,
-    ) -> Result<StartSigningJobResponse, RusotoError<StartSigningJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_signing_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_signing_job_request(&self, input: StartSigningJobRequest) -> SignedRequest {
         let request_uri = "/signing-jobs";
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
+        request.set_operation("StartSigningJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartSigningJobResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartSigningJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to a signing profile. Tags are labels that you can use to identify and organize your AWS resources. Each tag consists of a key and an optional value. You specify the signing profile using its Amazon Resource Name (ARN). You specify the tag by using a key-value pair.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Remove one or more tags from a signing profile. Specify a list of tag keys to remove the tags.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "signer", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1699,20 +2162,291 @@ impl Signer for SignerClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListSigningJobs` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_signing_jobs_pages(
+        &self,
+        input: ListSigningJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListSigningJobsResponse, RusotoError<ListSigningJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SignerClient, input| async move { client.list_signing_jobs(input).await },
+            |input: &ListSigningJobsRequest, output: &ListSigningJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSigningJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `jobs` of every page of `ListSigningJobs` results for `input`, as `list_signing_jobs_pages` requests them.
+    pub fn list_signing_jobs_stream(
+        &self,
+        input: ListSigningJobsRequest,
+    ) -> impl futures::Stream<Item = Result<SigningJob, RusotoError<ListSigningJobsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_signing_jobs_pages(input), |page| {
+            page.jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSigningPlatforms` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_signing_platforms_pages(
+        &self,
+        input: ListSigningPlatformsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListSigningPlatformsResponse, RusotoError<ListSigningPlatformsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SignerClient, input| async move { client.list_signing_platforms(input).await },
+            |input: &ListSigningPlatformsRequest, output: &ListSigningPlatformsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSigningPlatformsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `platforms` of every page of `ListSigningPlatforms` results for `input`, as `list_signing_platforms_pages` requests them.
+    pub fn list_signing_platforms_stream(
+        &self,
+        input: ListSigningPlatformsRequest,
+    ) -> impl futures::Stream<Item = Result<SigningPlatform, RusotoError<ListSigningPlatformsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_signing_platforms_pages(input), |page| {
+            page.platforms.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSigningProfiles` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_signing_profiles_pages(
+        &self,
+        input: ListSigningProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListSigningProfilesResponse, RusotoError<ListSigningProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SignerClient, input| async move { client.list_signing_profiles(input).await },
+            |input: &ListSigningProfilesRequest, output: &ListSigningProfilesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSigningProfilesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `profiles` of every page of `ListSigningProfiles` results for `input`, as `list_signing_profiles_pages` requests them.
+    pub fn list_signing_profiles_stream(
+        &self,
+        input: ListSigningProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<SigningProfile, RusotoError<ListSigningProfilesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_signing_profiles_pages(input), |page| {
+            page.profiles.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `SuccessfulSigningJob`, polling `DescribeSigningJob` with `input` every 20 seconds, up to 25 times.
+    pub async fn wait_until_successful_signing_job(
+        &self,
+        input: DescribeSigningJobRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeSigningJobError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(20), 25);
+        self.wait_until_successful_signing_job_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+    /// Waits for `SuccessfulSigningJob` like `wait_until_successful_signing_job`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_successful_signing_job_with(
+        &self,
+        input: DescribeSigningJobRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeSigningJobError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_signing_job(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Succeeded"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Signer for SignerClient {
+    /// <p>Changes the state of an <code>ACTIVE</code> signing profile to <code>CANCELED</code>. A canceled profile is still viewable with the <code>ListSigningProfiles</code> operation, but it cannot perform new signing jobs, and is deleted two years after cancelation.</p>
+    async fn cancel_signing_profile(
+        &self,
+        input: CancelSigningProfileRequest,
+    ) -> Result<(), RusotoError<CancelSigningProfileError>> {
+        self.cancel_signing_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific code signing job. You specify the job by using the <code>jobId</code> value that is returned by the <a>StartSigningJob</a> operation. </p>
+    async fn describe_signing_job(
+        &self,
+        input: DescribeSigningJobRequest,
+    ) -> Result<DescribeSigningJobResponse, RusotoError<DescribeSigningJobError>> {
+        self.describe_signing_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information on a specific signing platform.</p>
+    async fn get_signing_platform(
+        &self,
+        input: GetSigningPlatformRequest,
+    ) -> Result<GetSigningPlatformResponse, RusotoError<GetSigningPlatformError>> {
+        self.get_signing_platform_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information on a specific signing profile.</p>
+    async fn get_signing_profile(
+        &self,
+        input: GetSigningProfileRequest,
+    ) -> Result<GetSigningProfileResponse, RusotoError<GetSigningProfileError>> {
+        self.get_signing_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all your signing jobs. You can use the <code>maxResults</code> parameter to limit the number of signing jobs that are returned in the response. If additional jobs remain to be listed, code signing returns a <code>nextToken</code> value. Use this value in subsequent calls to <code>ListSigningJobs</code> to fetch the remaining values. You can continue calling <code>ListSigningJobs</code> with your <code>maxResults</code> parameter and with new values that code signing returns in the <code>nextToken</code> parameter until all of your signing jobs have been returned. </p>
+    async fn list_signing_jobs(
+        &self,
+        input: ListSigningJobsRequest,
+    ) -> Result<ListSigningJobsResponse, RusotoError<ListSigningJobsError>> {
+        self.list_signing_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all signing platforms available in code signing that match the request parameters. If additional jobs remain to be listed, code signing returns a <code>nextToken</code> value. Use this value in subsequent calls to <code>ListSigningJobs</code> to fetch the remaining values. You can continue calling <code>ListSigningJobs</code> with your <code>maxResults</code> parameter and with new values that code signing returns in the <code>nextToken</code> parameter until all of your signing jobs have been returned.</p>
+    async fn list_signing_platforms(
+        &self,
+        input: ListSigningPlatformsRequest,
+    ) -> Result<ListSigningPlatformsResponse, RusotoError<ListSigningPlatformsError>> {
+        self.list_signing_platforms_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all available signing profiles in your AWS account. Returns only profiles with an <code>ACTIVE</code> status unless the <code>includeCanceled</code> request field is set to <code>true</code>. If additional jobs remain to be listed, code signing returns a <code>nextToken</code> value. Use this value in subsequent calls to <code>ListSigningJobs</code> to fetch the remaining values. You can continue calling <code>ListSigningJobs</code> with your <code>maxResults</code> parameter and with new values that code signing returns in the <code>nextToken</code> parameter until all of your signing jobs have been returned.</p>
+    async fn list_signing_profiles(
+        &self,
+        input: ListSigningProfilesRequest,
+    ) -> Result<ListSigningProfilesResponse, RusotoError<ListSigningProfilesError>> {
+        self.list_signing_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the tags associated with a signing profile resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a signing profile. A signing profile is a code signing template that can be used to carry out a pre-defined signing job. For more information, see <a href="http://docs.aws.amazon.com/signer/latest/developerguide/gs-profile.html">http://docs.aws.amazon.com/signer/latest/developerguide/gs-profile.html</a> </p>
+    async fn put_signing_profile(
+        &self,
+        input: PutSigningProfileRequest,
+    ) -> Result<PutSigningProfileResponse, RusotoError<PutSigningProfileError>> {
+        self.put_signing_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates a signing job to be performed on the code provided. Signing jobs are viewable by the <code>ListSigningJobs</code> operation for two years after they are performed. Note the following requirements: </p> <ul> <li> <p> You must create an Amazon S3 source bucket. For more information, see <a href="http://docs.aws.amazon.com/AmazonS3/latest/gsg/CreatingABucket.html">Create a Bucket</a> in the <i>Amazon S3 Getting Started Guide</i>. </p> </li> <li> <p>Your S3 source bucket must be version enabled.</p> </li> <li> <p>You must create an S3 destination bucket. Code signing uses your S3 destination bucket to write your signed code.</p> </li> <li> <p>You specify the name of the source and destination buckets when calling the <code>StartSigningJob</code> operation.</p> </li> <li> <p>You must also specify a request token that identifies your request to code signing.</p> </li> </ul> <p>You can call the <a>DescribeSigningJob</a> and the <a>ListSigningJobs</a> actions after you call <code>StartSigningJob</code>.</p> <p>For a Java example that shows how to use this action, see <a href="http://docs.aws.amazon.com/acm/latest/userguide/">http://docs.aws.amazon.com/acm/latest/userguide/</a> </p>
+    async fn start_signing_job(
+        &self,
+        input: StartSigningJobRequest,
+    ) -> Result<StartSigningJobResponse, RusotoError<StartSigningJobError>> {
+        self.start_signing_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to a signing profile. Tags are labels that you can use to identify and organize your AWS resources. Each tag consists of a key and an optional value. You specify the signing profile using its Amazon Resource Name (ARN). You specify the tag by using a key-value pair.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove one or more tags from a signing profile. Specify a list of tag keys to remove the tags.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
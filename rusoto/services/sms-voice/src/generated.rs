@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -335,6 +335,7 @@ pub struct VoiceMessageContent {
 
 /// Errors returned by CreateConfigurationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationSetError {
     /// <p>The resource specified in your request already exists.</p>
     AlreadyExists(String),
@@ -346,6 +347,8 @@ pub enum CreateConfigurationSetError {
     LimitExceeded(String),
     /// <p>You&#39;ve issued too many requests to the resource. Wait a few minutes, and then try again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationSetError {
@@ -353,30 +356,37 @@ impl CreateConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationSetError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConfigurationSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -391,12 +401,14 @@ impl fmt::Display for CreateConfigurationSetError {
             CreateConfigurationSetError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             CreateConfigurationSetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateConfigurationSetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateConfigurationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConfigurationSetError {}
 /// Errors returned by CreateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationSetEventDestinationError {
     /// <p>The resource specified in your request already exists.</p>
     AlreadyExists(String),
@@ -410,6 +422,8 @@ pub enum CreateConfigurationSetEventDestinationError {
     NotFound(String),
     /// <p>You&#39;ve issued too many requests to the resource. Wait a few minutes, and then try again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationSetEventDestinationError {
@@ -419,37 +433,48 @@ impl CreateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::AlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateConfigurationSetEventDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -477,12 +502,16 @@ impl fmt::Display for CreateConfigurationSetEventDestinationError {
             CreateConfigurationSetEventDestinationError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateConfigurationSetEventDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateConfigurationSetEventDestinationError {}
 /// Errors returned by DeleteConfigurationSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationSetError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -492,6 +521,8 @@ pub enum DeleteConfigurationSetError {
     NotFound(String),
     /// <p>You&#39;ve issued too many requests to the resource. Wait a few minutes, and then try again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationSetError {
@@ -499,23 +530,31 @@ impl DeleteConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteConfigurationSetError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConfigurationSetError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteConfigurationSetError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConfigurationSetError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConfigurationSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -529,12 +568,14 @@ impl fmt::Display for DeleteConfigurationSetError {
             DeleteConfigurationSetError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DeleteConfigurationSetError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteConfigurationSetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteConfigurationSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConfigurationSetError {}
 /// Errors returned by DeleteConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationSetEventDestinationError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -544,6 +585,8 @@ pub enum DeleteConfigurationSetEventDestinationError {
     NotFound(String),
     /// <p>You&#39;ve issued too many requests to the resource. Wait a few minutes, and then try again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationSetEventDestinationError {
@@ -553,27 +596,36 @@ impl DeleteConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationSetEventDestinationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationSetEventDestinationError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationSetEventDestinationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationSetEventDestinationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteConfigurationSetEventDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -595,12 +647,16 @@ impl fmt::Display for DeleteConfigurationSetEventDestinationError {
             DeleteConfigurationSetEventDestinationError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteConfigurationSetEventDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteConfigurationSetEventDestinationError {}
 /// Errors returned by GetConfigurationSetEventDestinations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConfigurationSetEventDestinationsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -610,6 +666,8 @@ pub enum GetConfigurationSetEventDestinationsError {
     NotFound(String),
     /// <p>You&#39;ve issued too many requests to the resource. Wait a few minutes, and then try again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConfigurationSetEventDestinationsError {
@@ -619,27 +677,36 @@ impl GetConfigurationSetEventDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConfigurationSetEventDestinationsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConfigurationSetEventDestinationsError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConfigurationSetEventDestinationsError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConfigurationSetEventDestinationsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetConfigurationSetEventDestinationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -661,12 +728,16 @@ impl fmt::Display for GetConfigurationSetEventDestinationsError {
             GetConfigurationSetEventDestinationsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetConfigurationSetEventDestinationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetConfigurationSetEventDestinationsError {}
 /// Errors returned by ListConfigurationSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigurationSetsError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -674,6 +745,8 @@ pub enum ListConfigurationSetsError {
     InternalServiceError(String),
     /// <p>You&#39;ve issued too many requests to the resource. Wait a few minutes, and then try again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigurationSetsError {
@@ -681,20 +754,25 @@ impl ListConfigurationSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationSetsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListConfigurationSetsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListConfigurationSetsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationSetsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListConfigurationSetsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationSetsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConfigurationSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -707,12 +785,14 @@ impl fmt::Display for ListConfigurationSetsError {
             ListConfigurationSetsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListConfigurationSetsError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListConfigurationSetsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListConfigurationSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigurationSetsError {}
 /// Errors returned by SendVoiceMessage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendVoiceMessageError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -720,6 +800,8 @@ pub enum SendVoiceMessageError {
     InternalServiceError(String),
     /// <p>You&#39;ve issued too many requests to the resource. Wait a few minutes, and then try again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendVoiceMessageError {
@@ -727,18 +809,22 @@ impl SendVoiceMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SendVoiceMessageError::BadRequest(err.msg))
+                    return RusotoError::service(SendVoiceMessageError::BadRequest(err.msg), &res)
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(SendVoiceMessageError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendVoiceMessageError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SendVoiceMessageError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        SendVoiceMessageError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendVoiceMessageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -751,12 +837,14 @@ impl fmt::Display for SendVoiceMessageError {
             SendVoiceMessageError::BadRequest(ref cause) => write!(f, "{}", cause),
             SendVoiceMessageError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             SendVoiceMessageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            SendVoiceMessageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendVoiceMessageError {}
 /// Errors returned by UpdateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigurationSetEventDestinationError {
     /// <p>The input you provided is invalid.</p>
     BadRequest(String),
@@ -766,6 +854,8 @@ pub enum UpdateConfigurationSetEventDestinationError {
     NotFound(String),
     /// <p>You&#39;ve issued too many requests to the resource. Wait a few minutes, and then try again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigurationSetEventDestinationError {
@@ -775,27 +865,36 @@ impl UpdateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateConfigurationSetEventDestinationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -817,6 +916,9 @@ impl fmt::Display for UpdateConfigurationSetEventDestinationError {
             UpdateConfigurationSetEventDestinationError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateConfigurationSetEventDestinationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -895,6 +997,7 @@ impl SmsVoiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SmsVoiceClient {
         SmsVoiceClient {
             client: Client::shared(),
@@ -920,191 +1023,399 @@ impl SmsVoiceClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SmsVoiceClient {
         SmsVoiceClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SmsVoiceClient> {
+        rusoto_core::ClientBuilder::new(SmsVoiceClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SmsVoiceClient {
+        SmsVoiceClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SmsVoice for SmsVoiceClient {
-    /// <p>Create a new configuration set. After you create the configuration set, you can add one or more event destinations to it.</p>
-    async fn create_configuration_set(
+impl SmsVoiceClient {
+    /// Calls `create_configuration_set`, also returning the metadata of the response.
+    pub async fn create_configuration_set_with_metadata(
         &self,
         input: CreateConfigurationSetRequest,
-    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+    ) -> Result<
+        (CreateConfigurationSetResponse, ResponseMetadata),
+        RusotoError<CreateConfigurationSetError>,
+    > {
+        let request = self.build_create_configuration_set_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateConfigurationSetResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateConfigurationSetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_configuration_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration_set(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_set_request(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> SignedRequest {
         let request_uri = "/v1/sms-voice/configuration-sets";
 
         let mut request = SignedRequest::new("POST", "sms-voice", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_configuration_set_event_destination`, also returning the metadata of the response.
+    pub async fn create_configuration_set_event_destination_with_metadata(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        (
+            CreateConfigurationSetEventDestinationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        let request = self.build_create_configuration_set_event_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateConfigurationSetResponse, _>()?;
+                .deserialize::<CreateConfigurationSetEventDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConfigurationSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateConfigurationSetEventDestinationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Create a new event destination in a configuration set.</p>
-    async fn create_configuration_set_event_destination(
+    /// Presigns the request `create_configuration_set_event_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration_set_event_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConfigurationSetEventDestinationRequest,
-    ) -> Result<
-        CreateConfigurationSetEventDestinationResponse,
-        RusotoError<CreateConfigurationSetEventDestinationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_set_event_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_set_event_destination_request(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/sms-voice/configuration-sets/{configuration_set_name}/event-destinations",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("POST", "sms-voice", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_configuration_set`, also returning the metadata of the response.
+    pub async fn delete_configuration_set_with_metadata(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<
+        (DeleteConfigurationSetResponse, ResponseMetadata),
+        RusotoError<DeleteConfigurationSetError>,
+    > {
+        let request = self.build_delete_configuration_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateConfigurationSetEventDestinationResponse, _>()?;
+                .deserialize::<DeleteConfigurationSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConfigurationSetEventDestinationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteConfigurationSetError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing configuration set.</p>
-    async fn delete_configuration_set(
+    /// Presigns the request `delete_configuration_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConfigurationSetRequest,
-    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_set_request(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/sms-voice/configuration-sets/{configuration_set_name}",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("DELETE", "sms-voice", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
 
+        request
+    }
+
+    /// Calls `delete_configuration_set_event_destination`, also returning the metadata of the response.
+    pub async fn delete_configuration_set_event_destination_with_metadata(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        (
+            DeleteConfigurationSetEventDestinationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        let request = self.build_delete_configuration_set_event_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteConfigurationSetResponse, _>()?;
+                .deserialize::<DeleteConfigurationSetEventDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConfigurationSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteConfigurationSetEventDestinationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes an event destination in a configuration set.</p>
-    async fn delete_configuration_set_event_destination(
+    /// Presigns the request `delete_configuration_set_event_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_set_event_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConfigurationSetEventDestinationRequest,
-    ) -> Result<
-        DeleteConfigurationSetEventDestinationResponse,
-        RusotoError<DeleteConfigurationSetEventDestinationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_set_event_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_set_event_destination_request(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/sms-voice/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("DELETE", "sms-voice", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
 
+        request
+    }
+
+    /// Calls `get_configuration_set_event_destinations`, also returning the metadata of the response.
+    pub async fn get_configuration_set_event_destinations_with_metadata(
+        &self,
+        input: GetConfigurationSetEventDestinationsRequest,
+    ) -> Result<
+        (
+            GetConfigurationSetEventDestinationsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetConfigurationSetEventDestinationsError>,
+    > {
+        let request = self.build_get_configuration_set_event_destinations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteConfigurationSetEventDestinationResponse, _>()?;
+                .deserialize::<GetConfigurationSetEventDestinationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConfigurationSetEventDestinationError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetConfigurationSetEventDestinationsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Obtain information about an event destination, including the types of events it reports, the Amazon Resource Name (ARN) of the destination, and the name of the event destination.</p>
-    async fn get_configuration_set_event_destinations(
+    /// Presigns the request `get_configuration_set_event_destinations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_configuration_set_event_destinations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConfigurationSetEventDestinationsRequest,
-    ) -> Result<
-        GetConfigurationSetEventDestinationsResponse,
-        RusotoError<GetConfigurationSetEventDestinationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_configuration_set_event_destinations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_configuration_set_event_destinations_request(
+        &self,
+        input: GetConfigurationSetEventDestinationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/sms-voice/configuration-sets/{configuration_set_name}/event-destinations",
             configuration_set_name = input.configuration_set_name
         );
 
         let mut request = SignedRequest::new("GET", "sms-voice", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSetEventDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
 
+        request
+    }
+
+    /// Calls `list_configuration_sets`, also returning the metadata of the response.
+    pub async fn list_configuration_sets_with_metadata(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<
+        (ListConfigurationSetsResponse, ResponseMetadata),
+        RusotoError<ListConfigurationSetsError>,
+    > {
+        let request = self.build_list_configuration_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConfigurationSetEventDestinationsResponse, _>()?;
+                .deserialize::<ListConfigurationSetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConfigurationSetEventDestinationsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConfigurationSetsError::from_response(response))
         }
     }
 
-    /// <p>List all of the configuration sets associated with your Amazon Pinpoint account in the current region.</p>
-    async fn list_configuration_sets(
+    /// Presigns the request `list_configuration_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configuration_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigurationSetsRequest,
-    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configuration_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configuration_sets_request(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> SignedRequest {
         let request_uri = "/v1/sms-voice/configuration-sets";
 
         let mut request = SignedRequest::new("GET", "sms-voice", &self.region, &request_uri);
+        request.set_operation("ListConfigurationSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -1118,87 +1429,223 @@ impl SmsVoice for SmsVoiceClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `send_voice_message`, also returning the metadata of the response.
+    pub async fn send_voice_message_with_metadata(
+        &self,
+        input: SendVoiceMessageRequest,
+    ) -> Result<(SendVoiceMessageResponse, ResponseMetadata), RusotoError<SendVoiceMessageError>>
+    {
+        let request = self.build_send_voice_message_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConfigurationSetsResponse, _>()?;
+                .deserialize::<SendVoiceMessageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigurationSetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SendVoiceMessageError::from_response(response))
         }
     }
 
-    /// <p>Create a new voice message and send it to a recipient&#39;s phone number.</p>
-    async fn send_voice_message(
+    /// Presigns the request `send_voice_message` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_voice_message(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendVoiceMessageRequest,
-    ) -> Result<SendVoiceMessageResponse, RusotoError<SendVoiceMessageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_voice_message_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_voice_message_request(&self, input: SendVoiceMessageRequest) -> SignedRequest {
         let request_uri = "/v1/sms-voice/voice/message";
 
         let mut request = SignedRequest::new("POST", "sms-voice", &self.region, &request_uri);
+        request.set_operation("SendVoiceMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_configuration_set_event_destination`, also returning the metadata of the response.
+    pub async fn update_configuration_set_event_destination_with_metadata(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        (
+            UpdateConfigurationSetEventDestinationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        let request = self.build_update_configuration_set_event_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<SendVoiceMessageResponse, _>()?;
+                .deserialize::<UpdateConfigurationSetEventDestinationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SendVoiceMessageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateConfigurationSetEventDestinationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Update an event destination in a configuration set. An event destination is a location that you publish information about your voice calls to. For example, you can log an event to an Amazon CloudWatch destination when a call fails.</p>
-    async fn update_configuration_set_event_destination(
+    /// Presigns the request `update_configuration_set_event_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_configuration_set_event_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConfigurationSetEventDestinationRequest,
-    ) -> Result<
-        UpdateConfigurationSetEventDestinationResponse,
-        RusotoError<UpdateConfigurationSetEventDestinationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_configuration_set_event_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_configuration_set_event_destination_request(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/sms-voice/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("PUT", "sms-voice", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl SmsVoice for SmsVoiceClient {
+    /// <p>Create a new configuration set. After you create the configuration set, you can add one or more event destinations to it.</p>
+    async fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+        self.create_configuration_set_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateConfigurationSetEventDestinationResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConfigurationSetEventDestinationError::from_response(
-                response,
-            ))
-        }
+    /// <p>Create a new event destination in a configuration set.</p>
+    async fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        CreateConfigurationSetEventDestinationResponse,
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        self.create_configuration_set_event_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing configuration set.</p>
+    async fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+        self.delete_configuration_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an event destination in a configuration set.</p>
+    async fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        DeleteConfigurationSetEventDestinationResponse,
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        self.delete_configuration_set_event_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Obtain information about an event destination, including the types of events it reports, the Amazon Resource Name (ARN) of the destination, and the name of the event destination.</p>
+    async fn get_configuration_set_event_destinations(
+        &self,
+        input: GetConfigurationSetEventDestinationsRequest,
+    ) -> Result<
+        GetConfigurationSetEventDestinationsResponse,
+        RusotoError<GetConfigurationSetEventDestinationsError>,
+    > {
+        self.get_configuration_set_event_destinations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all of the configuration sets associated with your Amazon Pinpoint account in the current region.</p>
+    async fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+        self.list_configuration_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new voice message and send it to a recipient&#39;s phone number.</p>
+    async fn send_voice_message(
+        &self,
+        input: SendVoiceMessageRequest,
+    ) -> Result<SendVoiceMessageResponse, RusotoError<SendVoiceMessageError>> {
+        self.send_voice_message_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update an event destination in a configuration set. An event destination is a location that you publish information about your voice calls to. For example, you can log an event to an Amazon CloudWatch destination when a call fails.</p>
+    async fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        UpdateConfigurationSetEventDestinationResponse,
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        self.update_configuration_set_event_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
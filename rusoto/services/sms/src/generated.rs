@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1191,6 +1191,7 @@ pub struct VmServerAddress {
 
 /// Errors returned by CreateApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAppError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1202,6 +1203,8 @@ pub enum CreateAppError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAppError {
@@ -1209,22 +1212,31 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(CreateAppError::InternalError(err.msg))
+                    return RusotoError::service(CreateAppError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateAppError::InvalidParameter(err.msg), &res)
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(CreateAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        CreateAppError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        CreateAppError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        CreateAppError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1239,12 +1251,14 @@ impl fmt::Display for CreateAppError {
             CreateAppError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             CreateAppError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             CreateAppError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            CreateAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAppError {}
 /// Errors returned by CreateReplicationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReplicationJobError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1264,6 +1278,8 @@ pub enum CreateReplicationJobError {
     TemporarilyUnavailable(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReplicationJobError {
@@ -1271,50 +1287,61 @@ impl CreateReplicationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(CreateReplicationJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateReplicationJobError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateReplicationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationJobError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "NoConnectorsAvailableException" => {
-                    return RusotoError::Service(CreateReplicationJobError::NoConnectorsAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationJobError::NoConnectorsAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateReplicationJobError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationJobError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ReplicationJobAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationJobError::ReplicationJobAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ServerCannotBeReplicatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationJobError::ServerCannotBeReplicated(err.msg),
+                        &res,
                     )
                 }
                 "TemporarilyUnavailableException" => {
-                    return RusotoError::Service(CreateReplicationJobError::TemporarilyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationJobError::TemporarilyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateReplicationJobError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationJobError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateReplicationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1339,12 +1366,14 @@ impl fmt::Display for CreateReplicationJobError {
             }
             CreateReplicationJobError::TemporarilyUnavailable(ref cause) => write!(f, "{}", cause),
             CreateReplicationJobError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            CreateReplicationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReplicationJobError {}
 /// Errors returned by DeleteApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAppError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1356,6 +1385,8 @@ pub enum DeleteAppError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAppError {
@@ -1363,22 +1394,31 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(DeleteAppError::InternalError(err.msg))
+                    return RusotoError::service(DeleteAppError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteAppError::InvalidParameter(err.msg), &res)
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(DeleteAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteAppError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        DeleteAppError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        DeleteAppError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1393,12 +1433,14 @@ impl fmt::Display for DeleteAppError {
             DeleteAppError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             DeleteAppError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DeleteAppError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            DeleteAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAppError {}
 /// Errors returned by DeleteAppLaunchConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAppLaunchConfigurationError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1410,6 +1452,8 @@ pub enum DeleteAppLaunchConfigurationError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAppLaunchConfigurationError {
@@ -1419,32 +1463,42 @@ impl DeleteAppLaunchConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(DeleteAppLaunchConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAppLaunchConfigurationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAppLaunchConfigurationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAppLaunchConfigurationError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAppLaunchConfigurationError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteAppLaunchConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1467,12 +1521,14 @@ impl fmt::Display for DeleteAppLaunchConfigurationError {
             DeleteAppLaunchConfigurationError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteAppLaunchConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAppLaunchConfigurationError {}
 /// Errors returned by DeleteAppReplicationConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAppReplicationConfigurationError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1484,6 +1540,8 @@ pub enum DeleteAppReplicationConfigurationError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAppReplicationConfigurationError {
@@ -1493,32 +1551,42 @@ impl DeleteAppReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAppReplicationConfigurationError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAppReplicationConfigurationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAppReplicationConfigurationError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAppReplicationConfigurationError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteAppReplicationConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1543,12 +1611,16 @@ impl fmt::Display for DeleteAppReplicationConfigurationError {
             DeleteAppReplicationConfigurationError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteAppReplicationConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteAppReplicationConfigurationError {}
 /// Errors returned by DeleteReplicationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReplicationJobError {
     /// <p>A specified parameter is not valid.</p>
     InvalidParameter(String),
@@ -1560,6 +1632,8 @@ pub enum DeleteReplicationJobError {
     ReplicationJobNotFound(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReplicationJobError {
@@ -1567,32 +1641,37 @@ impl DeleteReplicationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteReplicationJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteReplicationJobError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteReplicationJobError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ReplicationJobNotFoundException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::ReplicationJobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteReplicationJobError::ReplicationJobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteReplicationJobError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteReplicationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1609,12 +1688,14 @@ impl fmt::Display for DeleteReplicationJobError {
             DeleteReplicationJobError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DeleteReplicationJobError::ReplicationJobNotFound(ref cause) => write!(f, "{}", cause),
             DeleteReplicationJobError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            DeleteReplicationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReplicationJobError {}
 /// Errors returned by DeleteServerCatalog
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteServerCatalogError {
     /// <p>A specified parameter is not valid.</p>
     InvalidParameter(String),
@@ -1624,6 +1705,8 @@ pub enum DeleteServerCatalogError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteServerCatalogError {
@@ -1631,27 +1714,31 @@ impl DeleteServerCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteServerCatalogError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteServerCatalogError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteServerCatalogError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteServerCatalogError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteServerCatalogError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteServerCatalogError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteServerCatalogError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteServerCatalogError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1665,12 +1752,14 @@ impl fmt::Display for DeleteServerCatalogError {
             DeleteServerCatalogError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             DeleteServerCatalogError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DeleteServerCatalogError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            DeleteServerCatalogError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteServerCatalogError {}
 /// Errors returned by DisassociateConnector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateConnectorError {
     /// <p>A specified parameter is not valid.</p>
     InvalidParameter(String),
@@ -1680,6 +1769,8 @@ pub enum DisassociateConnectorError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateConnectorError {
@@ -1687,27 +1778,31 @@ impl DisassociateConnectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateConnectorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateConnectorError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateConnectorError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DisassociateConnectorError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateConnectorError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DisassociateConnectorError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateConnectorError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateConnectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1723,12 +1818,14 @@ impl fmt::Display for DisassociateConnectorError {
             }
             DisassociateConnectorError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DisassociateConnectorError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            DisassociateConnectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateConnectorError {}
 /// Errors returned by GenerateChangeSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateChangeSetError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1740,6 +1837,8 @@ pub enum GenerateChangeSetError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateChangeSetError {
@@ -1747,28 +1846,37 @@ impl GenerateChangeSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GenerateChangeSetError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GenerateChangeSetError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GenerateChangeSetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GenerateChangeSetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GenerateChangeSetError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateChangeSetError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GenerateChangeSetError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateChangeSetError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GenerateChangeSetError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateChangeSetError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GenerateChangeSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1783,12 +1891,14 @@ impl fmt::Display for GenerateChangeSetError {
             GenerateChangeSetError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             GenerateChangeSetError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             GenerateChangeSetError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            GenerateChangeSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GenerateChangeSetError {}
 /// Errors returned by GenerateTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateTemplateError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1800,6 +1910,8 @@ pub enum GenerateTemplateError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateTemplateError {
@@ -1807,28 +1919,37 @@ impl GenerateTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GenerateTemplateError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GenerateTemplateError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GenerateTemplateError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GenerateTemplateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GenerateTemplateError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateTemplateError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GenerateTemplateError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateTemplateError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GenerateTemplateError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateTemplateError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GenerateTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1843,12 +1964,14 @@ impl fmt::Display for GenerateTemplateError {
             GenerateTemplateError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             GenerateTemplateError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             GenerateTemplateError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            GenerateTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GenerateTemplateError {}
 /// Errors returned by GetApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAppError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1860,6 +1983,8 @@ pub enum GetAppError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAppError {
@@ -1867,22 +1992,25 @@ impl GetAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GetAppError::InternalError(err.msg))
+                    return RusotoError::service(GetAppError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetAppError::InvalidParameter(err.msg), &res)
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        GetAppError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(GetAppError::OperationNotPermitted(err.msg), &res)
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(GetAppError::UnauthorizedOperation(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1897,12 +2025,14 @@ impl fmt::Display for GetAppError {
             GetAppError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             GetAppError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             GetAppError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            GetAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAppError {}
 /// Errors returned by GetAppLaunchConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAppLaunchConfigurationError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1914,6 +2044,8 @@ pub enum GetAppLaunchConfigurationError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAppLaunchConfigurationError {
@@ -1921,32 +2053,39 @@ impl GetAppLaunchConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GetAppLaunchConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAppLaunchConfigurationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAppLaunchConfigurationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAppLaunchConfigurationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAppLaunchConfigurationError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAppLaunchConfigurationError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetAppLaunchConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1967,12 +2106,14 @@ impl fmt::Display for GetAppLaunchConfigurationError {
             GetAppLaunchConfigurationError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetAppLaunchConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAppLaunchConfigurationError {}
 /// Errors returned by GetAppReplicationConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAppReplicationConfigurationError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -1984,6 +2125,8 @@ pub enum GetAppReplicationConfigurationError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAppReplicationConfigurationError {
@@ -1993,32 +2136,42 @@ impl GetAppReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAppReplicationConfigurationError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAppReplicationConfigurationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAppReplicationConfigurationError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAppReplicationConfigurationError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetAppReplicationConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2041,15 +2194,21 @@ impl fmt::Display for GetAppReplicationConfigurationError {
             GetAppReplicationConfigurationError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetAppReplicationConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetAppReplicationConfigurationError {}
 /// Errors returned by GetConnectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConnectorsError {
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConnectorsError {
@@ -2057,10 +2216,13 @@ impl GetConnectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetConnectorsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        GetConnectorsError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConnectorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2071,12 +2233,14 @@ impl fmt::Display for GetConnectorsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetConnectorsError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            GetConnectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConnectorsError {}
 /// Errors returned by GetReplicationJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetReplicationJobsError {
     /// <p>A specified parameter is not valid.</p>
     InvalidParameter(String),
@@ -2084,6 +2248,8 @@ pub enum GetReplicationJobsError {
     MissingRequiredParameter(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetReplicationJobsError {
@@ -2091,20 +2257,25 @@ impl GetReplicationJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetReplicationJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetReplicationJobsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetReplicationJobsError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReplicationJobsError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetReplicationJobsError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReplicationJobsError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetReplicationJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2117,12 +2288,14 @@ impl fmt::Display for GetReplicationJobsError {
             GetReplicationJobsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetReplicationJobsError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             GetReplicationJobsError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            GetReplicationJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetReplicationJobsError {}
 /// Errors returned by GetReplicationRuns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetReplicationRunsError {
     /// <p>A specified parameter is not valid.</p>
     InvalidParameter(String),
@@ -2130,6 +2303,8 @@ pub enum GetReplicationRunsError {
     MissingRequiredParameter(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetReplicationRunsError {
@@ -2137,20 +2312,25 @@ impl GetReplicationRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetReplicationRunsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetReplicationRunsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetReplicationRunsError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReplicationRunsError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetReplicationRunsError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReplicationRunsError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetReplicationRunsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2163,15 +2343,19 @@ impl fmt::Display for GetReplicationRunsError {
             GetReplicationRunsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetReplicationRunsError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             GetReplicationRunsError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            GetReplicationRunsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetReplicationRunsError {}
 /// Errors returned by GetServers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetServersError {
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetServersError {
@@ -2179,10 +2363,13 @@ impl GetServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetServersError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        GetServersError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetServersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2193,12 +2380,14 @@ impl fmt::Display for GetServersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetServersError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            GetServersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetServersError {}
 /// Errors returned by ImportServerCatalog
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportServerCatalogError {
     /// <p>A specified parameter is not valid.</p>
     InvalidParameter(String),
@@ -2210,6 +2399,8 @@ pub enum ImportServerCatalogError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportServerCatalogError {
@@ -2217,32 +2408,37 @@ impl ImportServerCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ImportServerCatalogError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportServerCatalogError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportServerCatalogError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "NoConnectorsAvailableException" => {
-                    return RusotoError::Service(ImportServerCatalogError::NoConnectorsAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportServerCatalogError::NoConnectorsAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ImportServerCatalogError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportServerCatalogError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(ImportServerCatalogError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportServerCatalogError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportServerCatalogError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2257,12 +2453,14 @@ impl fmt::Display for ImportServerCatalogError {
             ImportServerCatalogError::NoConnectorsAvailable(ref cause) => write!(f, "{}", cause),
             ImportServerCatalogError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             ImportServerCatalogError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            ImportServerCatalogError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportServerCatalogError {}
 /// Errors returned by LaunchApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum LaunchAppError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -2274,6 +2472,8 @@ pub enum LaunchAppError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl LaunchAppError {
@@ -2281,22 +2481,31 @@ impl LaunchAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(LaunchAppError::InternalError(err.msg))
+                    return RusotoError::service(LaunchAppError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(LaunchAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(LaunchAppError::InvalidParameter(err.msg), &res)
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(LaunchAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        LaunchAppError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(LaunchAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        LaunchAppError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(LaunchAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        LaunchAppError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(LaunchAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2311,12 +2520,14 @@ impl fmt::Display for LaunchAppError {
             LaunchAppError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             LaunchAppError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             LaunchAppError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            LaunchAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for LaunchAppError {}
 /// Errors returned by ListApps
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAppsError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -2328,6 +2539,8 @@ pub enum ListAppsError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAppsError {
@@ -2335,22 +2548,31 @@ impl ListAppsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(ListAppsError::InternalError(err.msg))
+                    return RusotoError::service(ListAppsError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAppsError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListAppsError::InvalidParameter(err.msg), &res)
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(ListAppsError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        ListAppsError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListAppsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        ListAppsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(ListAppsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        ListAppsError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAppsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2365,12 +2587,14 @@ impl fmt::Display for ListAppsError {
             ListAppsError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             ListAppsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             ListAppsError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            ListAppsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAppsError {}
 /// Errors returned by PutAppLaunchConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAppLaunchConfigurationError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -2382,6 +2606,8 @@ pub enum PutAppLaunchConfigurationError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAppLaunchConfigurationError {
@@ -2389,32 +2615,39 @@ impl PutAppLaunchConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(PutAppLaunchConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutAppLaunchConfigurationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAppLaunchConfigurationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutAppLaunchConfigurationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAppLaunchConfigurationError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAppLaunchConfigurationError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(PutAppLaunchConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2435,12 +2668,14 @@ impl fmt::Display for PutAppLaunchConfigurationError {
             PutAppLaunchConfigurationError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutAppLaunchConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutAppLaunchConfigurationError {}
 /// Errors returned by PutAppReplicationConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAppReplicationConfigurationError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -2452,6 +2687,8 @@ pub enum PutAppReplicationConfigurationError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAppReplicationConfigurationError {
@@ -2461,32 +2698,42 @@ impl PutAppReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAppReplicationConfigurationError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAppReplicationConfigurationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAppReplicationConfigurationError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAppReplicationConfigurationError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutAppReplicationConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2509,12 +2756,16 @@ impl fmt::Display for PutAppReplicationConfigurationError {
             PutAppReplicationConfigurationError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutAppReplicationConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutAppReplicationConfigurationError {}
 /// Errors returned by StartAppReplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartAppReplicationError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -2526,6 +2777,8 @@ pub enum StartAppReplicationError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartAppReplicationError {
@@ -2533,30 +2786,37 @@ impl StartAppReplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(StartAppReplicationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        StartAppReplicationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartAppReplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartAppReplicationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAppReplicationError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartAppReplicationError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartAppReplicationError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(StartAppReplicationError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartAppReplicationError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartAppReplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2571,12 +2831,14 @@ impl fmt::Display for StartAppReplicationError {
             StartAppReplicationError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             StartAppReplicationError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             StartAppReplicationError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            StartAppReplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartAppReplicationError {}
 /// Errors returned by StartOnDemandReplicationRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartOnDemandReplicationRunError {
     /// <p>A specified parameter is not valid.</p>
     InvalidParameter(String),
@@ -2588,6 +2850,8 @@ pub enum StartOnDemandReplicationRunError {
     ReplicationRunLimitExceeded(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartOnDemandReplicationRunError {
@@ -2597,32 +2861,42 @@ impl StartOnDemandReplicationRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartOnDemandReplicationRunError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartOnDemandReplicationRunError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartOnDemandReplicationRunError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ReplicationRunLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartOnDemandReplicationRunError::ReplicationRunLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartOnDemandReplicationRunError::UnauthorizedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartOnDemandReplicationRunError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2645,12 +2919,14 @@ impl fmt::Display for StartOnDemandReplicationRunError {
             StartOnDemandReplicationRunError::UnauthorizedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartOnDemandReplicationRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartOnDemandReplicationRunError {}
 /// Errors returned by StopAppReplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopAppReplicationError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -2662,6 +2938,8 @@ pub enum StopAppReplicationError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopAppReplicationError {
@@ -2669,28 +2947,37 @@ impl StopAppReplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(StopAppReplicationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        StopAppReplicationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopAppReplicationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StopAppReplicationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(StopAppReplicationError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopAppReplicationError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopAppReplicationError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopAppReplicationError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(StopAppReplicationError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopAppReplicationError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopAppReplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2705,12 +2992,14 @@ impl fmt::Display for StopAppReplicationError {
             StopAppReplicationError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             StopAppReplicationError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             StopAppReplicationError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            StopAppReplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopAppReplicationError {}
 /// Errors returned by TerminateApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TerminateAppError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -2722,6 +3011,8 @@ pub enum TerminateAppError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TerminateAppError {
@@ -2729,24 +3020,31 @@ impl TerminateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(TerminateAppError::InternalError(err.msg))
+                    return RusotoError::service(TerminateAppError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TerminateAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(TerminateAppError::InvalidParameter(err.msg), &res)
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(TerminateAppError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TerminateAppError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(TerminateAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        TerminateAppError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(TerminateAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        TerminateAppError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TerminateAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2761,12 +3059,14 @@ impl fmt::Display for TerminateAppError {
             TerminateAppError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             TerminateAppError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             TerminateAppError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            TerminateAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TerminateAppError {}
 /// Errors returned by UpdateApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAppError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -2778,6 +3078,8 @@ pub enum UpdateAppError {
     OperationNotPermitted(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAppError {
@@ -2785,22 +3087,31 @@ impl UpdateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(UpdateAppError::InternalError(err.msg))
+                    return RusotoError::service(UpdateAppError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(UpdateAppError::InvalidParameter(err.msg), &res)
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(UpdateAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateAppError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        UpdateAppError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        UpdateAppError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2815,12 +3126,14 @@ impl fmt::Display for UpdateAppError {
             UpdateAppError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             UpdateAppError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             UpdateAppError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            UpdateAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAppError {}
 /// Errors returned by UpdateReplicationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateReplicationJobError {
     /// <p>An internal error occurred.</p>
     InternalError(String),
@@ -2838,6 +3151,8 @@ pub enum UpdateReplicationJobError {
     TemporarilyUnavailable(String),
     /// <p>You lack permissions needed to perform this operation. Check your IAM policies, and ensure that you are using the correct access keys.</p>
     UnauthorizedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateReplicationJobError {
@@ -2845,45 +3160,55 @@ impl UpdateReplicationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(UpdateReplicationJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateReplicationJobError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateReplicationJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateReplicationJobError::MissingRequiredParameter(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateReplicationJobError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ReplicationJobNotFoundException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::ReplicationJobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateReplicationJobError::ReplicationJobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerCannotBeReplicatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateReplicationJobError::ServerCannotBeReplicated(err.msg),
+                        &res,
                     )
                 }
                 "TemporarilyUnavailableException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::TemporarilyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateReplicationJobError::TemporarilyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateReplicationJobError::UnauthorizedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateReplicationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2905,6 +3230,7 @@ impl fmt::Display for UpdateReplicationJobError {
             }
             UpdateReplicationJobError::TemporarilyUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateReplicationJobError::UnauthorizedOperation(ref cause) => write!(f, "{}", cause),
+            UpdateReplicationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3098,6 +3424,7 @@ impl ServerMigrationServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ServerMigrationServiceClient {
         ServerMigrationServiceClient {
             client: Client::shared(),
@@ -3123,16 +3450,67 @@ impl ServerMigrationServiceClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ServerMigrationServiceClient {
         ServerMigrationServiceClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ServerMigrationServiceClient> {
+        rusoto_core::ClientBuilder::new(ServerMigrationServiceClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ServerMigrationServiceClient {
+        ServerMigrationServiceClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ServerMigrationService for ServerMigrationServiceClient {
-    /// <p>Creates an application. An application consists of one or more server groups. Each server group contain one or more servers.</p>
-    async fn create_app(
+impl ServerMigrationServiceClient {
+    /// Calls `create_app`, also returning the metadata of the response.
+    pub async fn create_app_with_metadata(
         &self,
         input: CreateAppRequest,
-    ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
+    ) -> Result<(CreateAppResponse, ResponseMetadata), RusotoError<CreateAppError>> {
+        let request = self.build_create_app_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAppResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAppError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_app(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateAppRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_app_request(&self, input: CreateAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("CreateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3142,27 +3520,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_replication_job`, also returning the metadata of the response.
+    pub async fn create_replication_job_with_metadata(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> Result<
+        (CreateReplicationJobResponse, ResponseMetadata),
+        RusotoError<CreateReplicationJobError>,
+    > {
+        let request = self.build_create_replication_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateReplicationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateReplicationJobError::from_response(response))
         }
     }
 
-    /// <p>Creates a replication job. The replication job schedules periodic replication runs to replicate your server to AWS. Each replication run creates an Amazon Machine Image (AMI).</p>
-    async fn create_replication_job(
+    /// Presigns the request `create_replication_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_replication_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateReplicationJobRequest,
-    ) -> Result<CreateReplicationJobResponse, RusotoError<CreateReplicationJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_replication_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_replication_job_request(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("CreateReplicationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3172,28 +3581,52 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_app`, also returning the metadata of the response.
+    pub async fn delete_app_with_metadata(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<(DeleteAppResponse, ResponseMetadata), RusotoError<DeleteAppError>> {
+        let request = self.build_delete_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateReplicationJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteAppResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateReplicationJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAppError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing application. Optionally deletes the launched stack associated with the application and all AWS SMS replication jobs for servers in the application.</p>
-    async fn delete_app(
+    /// Presigns the request `delete_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAppRequest,
-    ) -> Result<DeleteAppResponse, RusotoError<DeleteAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_app_request(&self, input: DeleteAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3203,28 +3636,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_app_launch_configuration`, also returning the metadata of the response.
+    pub async fn delete_app_launch_configuration_with_metadata(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> Result<
+        (DeleteAppLaunchConfigurationResponse, ResponseMetadata),
+        RusotoError<DeleteAppLaunchConfigurationError>,
+    > {
+        let request = self.build_delete_app_launch_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteAppResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteAppLaunchConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAppLaunchConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes existing launch configuration for an application.</p>
-    async fn delete_app_launch_configuration(
+    /// Presigns the request `delete_app_launch_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_app_launch_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAppLaunchConfigurationRequest,
-    ) -> Result<DeleteAppLaunchConfigurationResponse, RusotoError<DeleteAppLaunchConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_app_launch_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_app_launch_configuration_request(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteAppLaunchConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3234,31 +3697,60 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_app_replication_configuration`, also returning the metadata of the response.
+    pub async fn delete_app_replication_configuration_with_metadata(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> Result<
+        (DeleteAppReplicationConfigurationResponse, ResponseMetadata),
+        RusotoError<DeleteAppReplicationConfigurationError>,
+    > {
+        let request = self.build_delete_app_replication_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteAppLaunchConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteAppReplicationConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAppLaunchConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAppReplicationConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes existing replication configuration for an application.</p>
-    async fn delete_app_replication_configuration(
+    /// Presigns the request `delete_app_replication_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_app_replication_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAppReplicationConfigurationRequest,
-    ) -> Result<
-        DeleteAppReplicationConfigurationResponse,
-        RusotoError<DeleteAppReplicationConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_app_replication_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_app_replication_configuration_request(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteAppReplicationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3268,30 +3760,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_replication_job`, also returning the metadata of the response.
+    pub async fn delete_replication_job_with_metadata(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> Result<
+        (DeleteReplicationJobResponse, ResponseMetadata),
+        RusotoError<DeleteReplicationJobError>,
+    > {
+        let request = self.build_delete_replication_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteAppReplicationConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteReplicationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAppReplicationConfigurationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteReplicationJobError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified replication job.</p> <p>After you delete a replication job, there are no further replication runs. AWS deletes the contents of the Amazon S3 bucket used to store AWS SMS artifacts. The AMIs created by the replication runs are not deleted.</p>
-    async fn delete_replication_job(
+    /// Presigns the request `delete_replication_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_replication_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteReplicationJobRequest,
-    ) -> Result<DeleteReplicationJobResponse, RusotoError<DeleteReplicationJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_replication_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_replication_job_request(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteReplicationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3301,27 +3821,53 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_server_catalog`, also returning the metadata of the response.
+    pub async fn delete_server_catalog_with_metadata(
+        &self,
+    ) -> Result<
+        (DeleteServerCatalogResponse, ResponseMetadata),
+        RusotoError<DeleteServerCatalogError>,
+    > {
+        let request = self.build_delete_server_catalog_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteReplicationJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteServerCatalogResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteReplicationJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteServerCatalogError::from_response(response))
         }
     }
 
-    /// <p>Deletes all servers from your server catalog.</p>
-    async fn delete_server_catalog(
+    /// Presigns the request `delete_server_catalog` would send with `presigner` instead of sending it.
+    pub async fn presign_delete_server_catalog(
         &self,
-    ) -> Result<DeleteServerCatalogResponse, RusotoError<DeleteServerCatalogError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_server_catalog_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_server_catalog_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteServerCatalog");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3330,28 +3876,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `disassociate_connector`, also returning the metadata of the response.
+    pub async fn disassociate_connector_with_metadata(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> Result<
+        (DisassociateConnectorResponse, ResponseMetadata),
+        RusotoError<DisassociateConnectorError>,
+    > {
+        let request = self.build_disassociate_connector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteServerCatalogResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateConnectorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteServerCatalogError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateConnectorError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified connector from AWS SMS.</p> <p>After you disassociate a connector, it is no longer available to support replication jobs.</p>
-    async fn disassociate_connector(
+    /// Presigns the request `disassociate_connector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_connector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateConnectorRequest,
-    ) -> Result<DisassociateConnectorResponse, RusotoError<DisassociateConnectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_connector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_connector_request(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DisassociateConnector");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3361,28 +3937,53 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `generate_change_set`, also returning the metadata of the response.
+    pub async fn generate_change_set_with_metadata(
+        &self,
+        input: GenerateChangeSetRequest,
+    ) -> Result<(GenerateChangeSetResponse, ResponseMetadata), RusotoError<GenerateChangeSetError>>
+    {
+        let request = self.build_generate_change_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateConnectorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GenerateChangeSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateConnectorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GenerateChangeSetError::from_response(response))
         }
     }
 
-    /// <p>Generates a target change set for a currently launched stack and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
-    async fn generate_change_set(
+    /// Presigns the request `generate_change_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_change_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GenerateChangeSetRequest,
-    ) -> Result<GenerateChangeSetResponse, RusotoError<GenerateChangeSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_change_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_change_set_request(&self, input: GenerateChangeSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GenerateChangeSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3392,28 +3993,53 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `generate_template`, also returning the metadata of the response.
+    pub async fn generate_template_with_metadata(
+        &self,
+        input: GenerateTemplateRequest,
+    ) -> Result<(GenerateTemplateResponse, ResponseMetadata), RusotoError<GenerateTemplateError>>
+    {
+        let request = self.build_generate_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GenerateChangeSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GenerateTemplateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateChangeSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GenerateTemplateError::from_response(response))
         }
     }
 
-    /// <p>Generates an Amazon CloudFormation template based on the current launch configuration and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
-    async fn generate_template(
+    /// Presigns the request `generate_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GenerateTemplateRequest,
-    ) -> Result<GenerateTemplateResponse, RusotoError<GenerateTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_template_request(&self, input: GenerateTemplateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GenerateTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3423,28 +4049,52 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_app`, also returning the metadata of the response.
+    pub async fn get_app_with_metadata(
+        &self,
+        input: GetAppRequest,
+    ) -> Result<(GetAppResponse, ResponseMetadata), RusotoError<GetAppError>> {
+        let request = self.build_get_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GenerateTemplateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetAppResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAppError::from_response(response))
         }
     }
 
-    /// <p>Retrieve information about an application.</p>
-    async fn get_app(
+    /// Presigns the request `get_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAppRequest,
-    ) -> Result<GetAppResponse, RusotoError<GetAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_app_request(&self, input: GetAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3454,28 +4104,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_app_launch_configuration`, also returning the metadata of the response.
+    pub async fn get_app_launch_configuration_with_metadata(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> Result<
+        (GetAppLaunchConfigurationResponse, ResponseMetadata),
+        RusotoError<GetAppLaunchConfigurationError>,
+    > {
+        let request = self.build_get_app_launch_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetAppResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAppLaunchConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAppLaunchConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the application launch configuration associated with an application.</p>
-    async fn get_app_launch_configuration(
+    /// Presigns the request `get_app_launch_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_app_launch_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAppLaunchConfigurationRequest,
-    ) -> Result<GetAppLaunchConfigurationResponse, RusotoError<GetAppLaunchConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_app_launch_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_app_launch_configuration_request(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetAppLaunchConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3485,31 +4165,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_app_replication_configuration`, also returning the metadata of the response.
+    pub async fn get_app_replication_configuration_with_metadata(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> Result<
+        (GetAppReplicationConfigurationResponse, ResponseMetadata),
+        RusotoError<GetAppReplicationConfigurationError>,
+    > {
+        let request = self.build_get_app_replication_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAppLaunchConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAppReplicationConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAppLaunchConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAppReplicationConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves an application replication configuration associatd with an application.</p>
-    async fn get_app_replication_configuration(
+    /// Presigns the request `get_app_replication_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_app_replication_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAppReplicationConfigurationRequest,
-    ) -> Result<
-        GetAppReplicationConfigurationResponse,
-        RusotoError<GetAppReplicationConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_app_replication_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_app_replication_configuration_request(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetAppReplicationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3519,28 +4226,52 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_connectors`, also returning the metadata of the response.
+    pub async fn get_connectors_with_metadata(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> Result<(GetConnectorsResponse, ResponseMetadata), RusotoError<GetConnectorsError>> {
+        let request = self.build_get_connectors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAppReplicationConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConnectorsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAppReplicationConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConnectorsError::from_response(response))
         }
     }
 
-    /// <p>Describes the connectors registered with the AWS SMS.</p>
-    async fn get_connectors(
+    /// Presigns the request `get_connectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_connectors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConnectorsRequest,
-    ) -> Result<GetConnectorsResponse, RusotoError<GetConnectorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_connectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_connectors_request(&self, input: GetConnectorsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetConnectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3550,27 +4281,56 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_replication_jobs`, also returning the metadata of the response.
+    pub async fn get_replication_jobs_with_metadata(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> Result<(GetReplicationJobsResponse, ResponseMetadata), RusotoError<GetReplicationJobsError>>
+    {
+        let request = self.build_get_replication_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetConnectorsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetReplicationJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConnectorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetReplicationJobsError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified replication job or all of your replication jobs.</p>
-    async fn get_replication_jobs(
+    /// Presigns the request `get_replication_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_replication_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetReplicationJobsRequest,
-    ) -> Result<GetReplicationJobsResponse, RusotoError<GetReplicationJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_replication_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_replication_jobs_request(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetReplicationJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3580,28 +4340,56 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_replication_runs`, also returning the metadata of the response.
+    pub async fn get_replication_runs_with_metadata(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> Result<(GetReplicationRunsResponse, ResponseMetadata), RusotoError<GetReplicationRunsError>>
+    {
+        let request = self.build_get_replication_runs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetReplicationJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetReplicationRunsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetReplicationJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetReplicationRunsError::from_response(response))
         }
     }
 
-    /// <p>Describes the replication runs for the specified replication job.</p>
-    async fn get_replication_runs(
+    /// Presigns the request `get_replication_runs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_replication_runs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetReplicationRunsRequest,
-    ) -> Result<GetReplicationRunsResponse, RusotoError<GetReplicationRunsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_replication_runs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_replication_runs_request(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetReplicationRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3611,28 +4399,52 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_servers`, also returning the metadata of the response.
+    pub async fn get_servers_with_metadata(
+        &self,
+        input: GetServersRequest,
+    ) -> Result<(GetServersResponse, ResponseMetadata), RusotoError<GetServersError>> {
+        let request = self.build_get_servers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetReplicationRunsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetServersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetReplicationRunsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetServersError::from_response(response))
         }
     }
 
-    /// <p>Describes the servers in your server catalog.</p> <p>Before you can describe your servers, you must import them using <a>ImportServerCatalog</a>.</p>
-    async fn get_servers(
+    /// Presigns the request `get_servers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_servers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetServersRequest,
-    ) -> Result<GetServersResponse, RusotoError<GetServersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_servers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_servers_request(&self, input: GetServersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3642,26 +4454,53 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_server_catalog`, also returning the metadata of the response.
+    pub async fn import_server_catalog_with_metadata(
+        &self,
+    ) -> Result<
+        (ImportServerCatalogResponse, ResponseMetadata),
+        RusotoError<ImportServerCatalogError>,
+    > {
+        let request = self.build_import_server_catalog_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetServersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportServerCatalogResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetServersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportServerCatalogError::from_response(response))
         }
     }
 
-    /// <p>Gathers a complete list of on-premises servers. Connectors must be installed and monitoring all servers that you want to import.</p> <p>This call returns immediately, but might take additional time to retrieve all the servers.</p>
-    async fn import_server_catalog(
+    /// Presigns the request `import_server_catalog` would send with `presigner` instead of sending it.
+    pub async fn presign_import_server_catalog(
         &self,
-    ) -> Result<ImportServerCatalogResponse, RusotoError<ImportServerCatalogError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_server_catalog_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_server_catalog_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("ImportServerCatalog");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3670,28 +4509,52 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `launch_app`, also returning the metadata of the response.
+    pub async fn launch_app_with_metadata(
+        &self,
+        input: LaunchAppRequest,
+    ) -> Result<(LaunchAppResponse, ResponseMetadata), RusotoError<LaunchAppError>> {
+        let request = self.build_launch_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportServerCatalogResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<LaunchAppResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportServerCatalogError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(LaunchAppError::from_response(response))
         }
     }
 
-    /// <p>Launches an application stack.</p>
-    async fn launch_app(
+    /// Presigns the request `launch_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_launch_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: LaunchAppRequest,
-    ) -> Result<LaunchAppResponse, RusotoError<LaunchAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_launch_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_launch_app_request(&self, input: LaunchAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("LaunchApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3701,27 +4564,52 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_apps`, also returning the metadata of the response.
+    pub async fn list_apps_with_metadata(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<(ListAppsResponse, ResponseMetadata), RusotoError<ListAppsError>> {
+        let request = self.build_list_apps_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<LaunchAppResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAppsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(LaunchAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAppsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of summaries for all applications.</p>
-    async fn list_apps(
+    /// Presigns the request `list_apps` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_apps(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAppsRequest,
-    ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_apps_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_apps_request(&self, input: ListAppsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("ListApps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3731,28 +4619,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_app_launch_configuration`, also returning the metadata of the response.
+    pub async fn put_app_launch_configuration_with_metadata(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> Result<
+        (PutAppLaunchConfigurationResponse, ResponseMetadata),
+        RusotoError<PutAppLaunchConfigurationError>,
+    > {
+        let request = self.build_put_app_launch_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAppsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutAppLaunchConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAppsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutAppLaunchConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Creates a launch configuration for an application.</p>
-    async fn put_app_launch_configuration(
+    /// Presigns the request `put_app_launch_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_app_launch_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAppLaunchConfigurationRequest,
-    ) -> Result<PutAppLaunchConfigurationResponse, RusotoError<PutAppLaunchConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_app_launch_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_app_launch_configuration_request(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("PutAppLaunchConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3762,31 +4680,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_app_replication_configuration`, also returning the metadata of the response.
+    pub async fn put_app_replication_configuration_with_metadata(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> Result<
+        (PutAppReplicationConfigurationResponse, ResponseMetadata),
+        RusotoError<PutAppReplicationConfigurationError>,
+    > {
+        let request = self.build_put_app_replication_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutAppLaunchConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutAppReplicationConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAppLaunchConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutAppReplicationConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a replication configuration for an application.</p>
-    async fn put_app_replication_configuration(
+    /// Presigns the request `put_app_replication_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_app_replication_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAppReplicationConfigurationRequest,
-    ) -> Result<
-        PutAppReplicationConfigurationResponse,
-        RusotoError<PutAppReplicationConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_app_replication_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_app_replication_configuration_request(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("PutAppReplicationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3796,28 +4741,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_app_replication`, also returning the metadata of the response.
+    pub async fn start_app_replication_with_metadata(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> Result<
+        (StartAppReplicationResponse, ResponseMetadata),
+        RusotoError<StartAppReplicationError>,
+    > {
+        let request = self.build_start_app_replication_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutAppReplicationConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartAppReplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAppReplicationConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartAppReplicationError::from_response(response))
         }
     }
 
-    /// <p>Starts replicating an application.</p>
-    async fn start_app_replication(
+    /// Presigns the request `start_app_replication` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_app_replication(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartAppReplicationRequest,
-    ) -> Result<StartAppReplicationResponse, RusotoError<StartAppReplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_app_replication_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_app_replication_request(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("StartAppReplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3827,29 +4802,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_on_demand_replication_run`, also returning the metadata of the response.
+    pub async fn start_on_demand_replication_run_with_metadata(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> Result<
+        (StartOnDemandReplicationRunResponse, ResponseMetadata),
+        RusotoError<StartOnDemandReplicationRunError>,
+    > {
+        let request = self.build_start_on_demand_replication_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartAppReplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartOnDemandReplicationRunResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartAppReplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartOnDemandReplicationRunError::from_response(response))
         }
     }
 
-    /// <p>Starts an on-demand replication run for the specified replication job. This replication run starts immediately. This replication run is in addition to the ones already scheduled.</p> <p>There is a limit on the number of on-demand replications runs you can request in a 24-hour period.</p>
-    async fn start_on_demand_replication_run(
+    /// Presigns the request `start_on_demand_replication_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_on_demand_replication_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartOnDemandReplicationRunRequest,
-    ) -> Result<StartOnDemandReplicationRunResponse, RusotoError<StartOnDemandReplicationRunError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_on_demand_replication_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_on_demand_replication_run_request(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("StartOnDemandReplicationRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3859,28 +4863,56 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_app_replication`, also returning the metadata of the response.
+    pub async fn stop_app_replication_with_metadata(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> Result<(StopAppReplicationResponse, ResponseMetadata), RusotoError<StopAppReplicationError>>
+    {
+        let request = self.build_stop_app_replication_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartOnDemandReplicationRunResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopAppReplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartOnDemandReplicationRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopAppReplicationError::from_response(response))
         }
     }
 
-    /// <p>Stops replicating an application.</p>
-    async fn stop_app_replication(
+    /// Presigns the request `stop_app_replication` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_app_replication(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopAppReplicationRequest,
-    ) -> Result<StopAppReplicationResponse, RusotoError<StopAppReplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_app_replication_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_app_replication_request(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("StopAppReplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3890,28 +4922,52 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `terminate_app`, also returning the metadata of the response.
+    pub async fn terminate_app_with_metadata(
+        &self,
+        input: TerminateAppRequest,
+    ) -> Result<(TerminateAppResponse, ResponseMetadata), RusotoError<TerminateAppError>> {
+        let request = self.build_terminate_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopAppReplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TerminateAppResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopAppReplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TerminateAppError::from_response(response))
         }
     }
 
-    /// <p>Terminates the stack for an application.</p>
-    async fn terminate_app(
+    /// Presigns the request `terminate_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_terminate_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TerminateAppRequest,
-    ) -> Result<TerminateAppResponse, RusotoError<TerminateAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_terminate_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_terminate_app_request(&self, input: TerminateAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("TerminateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3921,27 +4977,52 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_app`, also returning the metadata of the response.
+    pub async fn update_app_with_metadata(
+        &self,
+        input: UpdateAppRequest,
+    ) -> Result<(UpdateAppResponse, ResponseMetadata), RusotoError<UpdateAppError>> {
+        let request = self.build_update_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TerminateAppResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAppResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TerminateAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAppError::from_response(response))
         }
     }
 
-    /// <p>Updates an application.</p>
-    async fn update_app(
+    /// Presigns the request `update_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAppRequest,
-    ) -> Result<UpdateAppResponse, RusotoError<UpdateAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_app_request(&self, input: UpdateAppRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("UpdateApp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3951,27 +5032,58 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `update_replication_job`, also returning the metadata of the response.
+    pub async fn update_replication_job_with_metadata(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> Result<
+        (UpdateReplicationJobResponse, ResponseMetadata),
+        RusotoError<UpdateReplicationJobError>,
+    > {
+        let request = self.build_update_replication_job_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateAppResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateReplicationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAppError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateReplicationJobError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified settings for the specified replication job.</p>
-    async fn update_replication_job(
+    /// Presigns the request `update_replication_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_replication_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateReplicationJobRequest,
-    ) -> Result<UpdateReplicationJobResponse, RusotoError<UpdateReplicationJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_replication_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_replication_job_request(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("UpdateReplicationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3981,19 +5093,300 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl ServerMigrationService for ServerMigrationServiceClient {
+    /// <p>Creates an application. An application consists of one or more server groups. Each server group contain one or more servers.</p>
+    async fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
+        self.create_app_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateReplicationJobResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateReplicationJobError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a replication job. The replication job schedules periodic replication runs to replicate your server to AWS. Each replication run creates an Amazon Machine Image (AMI).</p>
+    async fn create_replication_job(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> Result<CreateReplicationJobResponse, RusotoError<CreateReplicationJobError>> {
+        self.create_replication_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing application. Optionally deletes the launched stack associated with the application and all AWS SMS replication jobs for servers in the application.</p>
+    async fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<DeleteAppResponse, RusotoError<DeleteAppError>> {
+        self.delete_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes existing launch configuration for an application.</p>
+    async fn delete_app_launch_configuration(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> Result<DeleteAppLaunchConfigurationResponse, RusotoError<DeleteAppLaunchConfigurationError>>
+    {
+        self.delete_app_launch_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes existing replication configuration for an application.</p>
+    async fn delete_app_replication_configuration(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> Result<
+        DeleteAppReplicationConfigurationResponse,
+        RusotoError<DeleteAppReplicationConfigurationError>,
+    > {
+        self.delete_app_replication_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified replication job.</p> <p>After you delete a replication job, there are no further replication runs. AWS deletes the contents of the Amazon S3 bucket used to store AWS SMS artifacts. The AMIs created by the replication runs are not deleted.</p>
+    async fn delete_replication_job(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> Result<DeleteReplicationJobResponse, RusotoError<DeleteReplicationJobError>> {
+        self.delete_replication_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes all servers from your server catalog.</p>
+    async fn delete_server_catalog(
+        &self,
+    ) -> Result<DeleteServerCatalogResponse, RusotoError<DeleteServerCatalogError>> {
+        self.delete_server_catalog_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified connector from AWS SMS.</p> <p>After you disassociate a connector, it is no longer available to support replication jobs.</p>
+    async fn disassociate_connector(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> Result<DisassociateConnectorResponse, RusotoError<DisassociateConnectorError>> {
+        self.disassociate_connector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a target change set for a currently launched stack and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
+    async fn generate_change_set(
+        &self,
+        input: GenerateChangeSetRequest,
+    ) -> Result<GenerateChangeSetResponse, RusotoError<GenerateChangeSetError>> {
+        self.generate_change_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates an Amazon CloudFormation template based on the current launch configuration and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
+    async fn generate_template(
+        &self,
+        input: GenerateTemplateRequest,
+    ) -> Result<GenerateTemplateResponse, RusotoError<GenerateTemplateError>> {
+        self.generate_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve information about an application.</p>
+    async fn get_app(
+        &self,
+        input: GetAppRequest,
+    ) -> Result<GetAppResponse, RusotoError<GetAppError>> {
+        self.get_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the application launch configuration associated with an application.</p>
+    async fn get_app_launch_configuration(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> Result<GetAppLaunchConfigurationResponse, RusotoError<GetAppLaunchConfigurationError>>
+    {
+        self.get_app_launch_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves an application replication configuration associatd with an application.</p>
+    async fn get_app_replication_configuration(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> Result<
+        GetAppReplicationConfigurationResponse,
+        RusotoError<GetAppReplicationConfigurationError>,
+    > {
+        self.get_app_replication_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the connectors registered with the AWS SMS.</p>
+    async fn get_connectors(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> Result<GetConnectorsResponse, RusotoError<GetConnectorsError>> {
+        self.get_connectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified replication job or all of your replication jobs.</p>
+    async fn get_replication_jobs(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> Result<GetReplicationJobsResponse, RusotoError<GetReplicationJobsError>> {
+        self.get_replication_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the replication runs for the specified replication job.</p>
+    async fn get_replication_runs(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> Result<GetReplicationRunsResponse, RusotoError<GetReplicationRunsError>> {
+        self.get_replication_runs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the servers in your server catalog.</p> <p>Before you can describe your servers, you must import them using <a>ImportServerCatalog</a>.</p>
+    async fn get_servers(
+        &self,
+        input: GetServersRequest,
+    ) -> Result<GetServersResponse, RusotoError<GetServersError>> {
+        self.get_servers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gathers a complete list of on-premises servers. Connectors must be installed and monitoring all servers that you want to import.</p> <p>This call returns immediately, but might take additional time to retrieve all the servers.</p>
+    async fn import_server_catalog(
+        &self,
+    ) -> Result<ImportServerCatalogResponse, RusotoError<ImportServerCatalogError>> {
+        self.import_server_catalog_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Launches an application stack.</p>
+    async fn launch_app(
+        &self,
+        input: LaunchAppRequest,
+    ) -> Result<LaunchAppResponse, RusotoError<LaunchAppError>> {
+        self.launch_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of summaries for all applications.</p>
+    async fn list_apps(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
+        self.list_apps_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a launch configuration for an application.</p>
+    async fn put_app_launch_configuration(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> Result<PutAppLaunchConfigurationResponse, RusotoError<PutAppLaunchConfigurationError>>
+    {
+        self.put_app_launch_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a replication configuration for an application.</p>
+    async fn put_app_replication_configuration(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> Result<
+        PutAppReplicationConfigurationResponse,
+        RusotoError<PutAppReplicationConfigurationError>,
+    > {
+        self.put_app_replication_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts replicating an application.</p>
+    async fn start_app_replication(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> Result<StartAppReplicationResponse, RusotoError<StartAppReplicationError>> {
+        self.start_app_replication_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an on-demand replication run for the specified replication job. This replication run starts immediately. This replication run is in addition to the ones already scheduled.</p> <p>There is a limit on the number of on-demand replications runs you can request in a 24-hour period.</p>
+    async fn start_on_demand_replication_run(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> Result<StartOnDemandReplicationRunResponse, RusotoError<StartOnDemandReplicationRunError>>
+    {
+        self.start_on_demand_replication_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops replicating an application.</p>
+    async fn stop_app_replication(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> Result<StopAppReplicationResponse, RusotoError<StopAppReplicationError>> {
+        self.stop_app_replication_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Terminates the stack for an application.</p>
+    async fn terminate_app(
+        &self,
+        input: TerminateAppRequest,
+    ) -> Result<TerminateAppResponse, RusotoError<TerminateAppError>> {
+        self.terminate_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an application.</p>
+    async fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> Result<UpdateAppResponse, RusotoError<UpdateAppError>> {
+        self.update_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified settings for the specified replication job.</p>
+    async fn update_replication_job(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> Result<UpdateReplicationJobResponse, RusotoError<UpdateReplicationJobError>> {
+        self.update_replication_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
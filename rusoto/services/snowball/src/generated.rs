@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -947,6 +947,7 @@ pub struct UpdateJobResult {}
 
 /// Errors returned by CancelCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelClusterError {
     /// <p>The action can't be performed because the job's current state doesn't allow that action to be performed.</p>
     InvalidJobState(String),
@@ -954,6 +955,8 @@ pub enum CancelClusterError {
     InvalidResource(String),
     /// <p>The provided AWS Key Management Service key lacks the permissions to perform the specified <a>CreateJob</a> or <a>UpdateJob</a> action.</p>
     KMSRequestFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelClusterError {
@@ -961,16 +964,19 @@ impl CancelClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(CancelClusterError::InvalidJobState(err.msg))
+                    return RusotoError::service(CancelClusterError::InvalidJobState(err.msg), &res)
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CancelClusterError::InvalidResource(err.msg))
+                    return RusotoError::service(CancelClusterError::InvalidResource(err.msg), &res)
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CancelClusterError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(
+                        CancelClusterError::KMSRequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -983,12 +989,14 @@ impl fmt::Display for CancelClusterError {
             CancelClusterError::InvalidJobState(ref cause) => write!(f, "{}", cause),
             CancelClusterError::InvalidResource(ref cause) => write!(f, "{}", cause),
             CancelClusterError::KMSRequestFailed(ref cause) => write!(f, "{}", cause),
+            CancelClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelClusterError {}
 /// Errors returned by CancelJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelJobError {
     /// <p>The action can't be performed because the job's current state doesn't allow that action to be performed.</p>
     InvalidJobState(String),
@@ -996,6 +1004,8 @@ pub enum CancelJobError {
     InvalidResource(String),
     /// <p>The provided AWS Key Management Service key lacks the permissions to perform the specified <a>CreateJob</a> or <a>UpdateJob</a> action.</p>
     KMSRequestFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelJobError {
@@ -1003,16 +1013,16 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(CancelJobError::InvalidJobState(err.msg))
+                    return RusotoError::service(CancelJobError::InvalidJobState(err.msg), &res)
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CancelJobError::InvalidResource(err.msg))
+                    return RusotoError::service(CancelJobError::InvalidResource(err.msg), &res)
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CancelJobError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(CancelJobError::KMSRequestFailed(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1025,17 +1035,21 @@ impl fmt::Display for CancelJobError {
             CancelJobError::InvalidJobState(ref cause) => write!(f, "{}", cause),
             CancelJobError::InvalidResource(ref cause) => write!(f, "{}", cause),
             CancelJobError::KMSRequestFailed(ref cause) => write!(f, "{}", cause),
+            CancelJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelJobError {}
 /// Errors returned by CreateAddress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAddressError {
     /// <p>The address provided was invalid. Check the address with your region's carrier, and try again.</p>
     InvalidAddress(String),
     /// <p>The address is either outside the serviceable area for your region, or an error occurred. Check the address with your region's carrier and try again. If the issue persists, contact AWS Support.</p>
     UnsupportedAddress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAddressError {
@@ -1043,13 +1057,16 @@ impl CreateAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAddressException" => {
-                    return RusotoError::Service(CreateAddressError::InvalidAddress(err.msg))
+                    return RusotoError::service(CreateAddressError::InvalidAddress(err.msg), &res)
                 }
                 "UnsupportedAddressException" => {
-                    return RusotoError::Service(CreateAddressError::UnsupportedAddress(err.msg))
+                    return RusotoError::service(
+                        CreateAddressError::UnsupportedAddress(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAddressError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1061,12 +1078,14 @@ impl fmt::Display for CreateAddressError {
         match *self {
             CreateAddressError::InvalidAddress(ref cause) => write!(f, "{}", cause),
             CreateAddressError::UnsupportedAddress(ref cause) => write!(f, "{}", cause),
+            CreateAddressError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAddressError {}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterError {
     /// <p>Your IAM user lacks the necessary Amazon EC2 permissions to perform the attempted action.</p>
     Ec2RequestFailed(String),
@@ -1076,6 +1095,8 @@ pub enum CreateClusterError {
     InvalidResource(String),
     /// <p>The provided AWS Key Management Service key lacks the permissions to perform the specified <a>CreateJob</a> or <a>UpdateJob</a> action.</p>
     KMSRequestFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterError {
@@ -1083,21 +1104,28 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(CreateClusterError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::Ec2RequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidInputCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::InvalidInputCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidResource(err.msg))
+                    return RusotoError::service(CreateClusterError::InvalidResource(err.msg), &res)
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CreateClusterError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::KMSRequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1111,12 +1139,14 @@ impl fmt::Display for CreateClusterError {
             CreateClusterError::InvalidInputCombination(ref cause) => write!(f, "{}", cause),
             CreateClusterError::InvalidResource(ref cause) => write!(f, "{}", cause),
             CreateClusterError::KMSRequestFailed(ref cause) => write!(f, "{}", cause),
+            CreateClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterError {}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateJobError {
     /// <p>Job creation failed. Currently, clusters support five nodes. If you have less than five nodes for your cluster and you have more nodes to create for this cluster, try again and create jobs until your cluster has exactly five notes.</p>
     ClusterLimitExceeded(String),
@@ -1128,6 +1158,8 @@ pub enum CreateJobError {
     InvalidResource(String),
     /// <p>The provided AWS Key Management Service key lacks the permissions to perform the specified <a>CreateJob</a> or <a>UpdateJob</a> action.</p>
     KMSRequestFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateJobError {
@@ -1135,22 +1167,28 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterLimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::ClusterLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateJobError::ClusterLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(CreateJobError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service(CreateJobError::Ec2RequestFailed(err.msg), &res)
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(CreateJobError::InvalidInputCombination(err.msg))
+                    return RusotoError::service(
+                        CreateJobError::InvalidInputCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateJobError::InvalidResource(err.msg))
+                    return RusotoError::service(CreateJobError::InvalidResource(err.msg), &res)
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CreateJobError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(CreateJobError::KMSRequestFailed(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1165,15 +1203,19 @@ impl fmt::Display for CreateJobError {
             CreateJobError::InvalidInputCombination(ref cause) => write!(f, "{}", cause),
             CreateJobError::InvalidResource(ref cause) => write!(f, "{}", cause),
             CreateJobError::KMSRequestFailed(ref cause) => write!(f, "{}", cause),
+            CreateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateJobError {}
 /// Errors returned by DescribeAddress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAddressError {
     /// <p>The specified resource can't be found. Check the information you provided in your last request, and try again.</p>
     InvalidResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAddressError {
@@ -1181,10 +1223,13 @@ impl DescribeAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeAddressError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        DescribeAddressError::InvalidResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAddressError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1195,17 +1240,21 @@ impl fmt::Display for DescribeAddressError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeAddressError::InvalidResource(ref cause) => write!(f, "{}", cause),
+            DescribeAddressError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAddressError {}
 /// Errors returned by DescribeAddresses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAddressesError {
     /// <p>The <code>NextToken</code> string was altered unexpectedly, and the operation has stopped. Run the operation without changing the <code>NextToken</code> string, and try again.</p>
     InvalidNextToken(String),
     /// <p>The specified resource can't be found. Check the information you provided in your last request, and try again.</p>
     InvalidResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAddressesError {
@@ -1213,13 +1262,19 @@ impl DescribeAddressesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeAddressesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeAddressesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeAddressesError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        DescribeAddressesError::InvalidResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAddressesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1231,15 +1286,19 @@ impl fmt::Display for DescribeAddressesError {
         match *self {
             DescribeAddressesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeAddressesError::InvalidResource(ref cause) => write!(f, "{}", cause),
+            DescribeAddressesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAddressesError {}
 /// Errors returned by DescribeCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterError {
     /// <p>The specified resource can't be found. Check the information you provided in your last request, and try again.</p>
     InvalidResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterError {
@@ -1247,10 +1306,13 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeClusterError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        DescribeClusterError::InvalidResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1261,15 +1323,19 @@ impl fmt::Display for DescribeClusterError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeClusterError::InvalidResource(ref cause) => write!(f, "{}", cause),
+            DescribeClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterError {}
 /// Errors returned by DescribeJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeJobError {
     /// <p>The specified resource can't be found. Check the information you provided in your last request, and try again.</p>
     InvalidResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeJobError {
@@ -1277,10 +1343,10 @@ impl DescribeJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeJobError::InvalidResource(err.msg))
+                    return RusotoError::service(DescribeJobError::InvalidResource(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1291,17 +1357,21 @@ impl fmt::Display for DescribeJobError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeJobError::InvalidResource(ref cause) => write!(f, "{}", cause),
+            DescribeJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeJobError {}
 /// Errors returned by GetJobManifest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobManifestError {
     /// <p>The action can't be performed because the job's current state doesn't allow that action to be performed.</p>
     InvalidJobState(String),
     /// <p>The specified resource can't be found. Check the information you provided in your last request, and try again.</p>
     InvalidResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobManifestError {
@@ -1309,13 +1379,19 @@ impl GetJobManifestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(GetJobManifestError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        GetJobManifestError::InvalidJobState(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(GetJobManifestError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        GetJobManifestError::InvalidResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobManifestError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1327,17 +1403,21 @@ impl fmt::Display for GetJobManifestError {
         match *self {
             GetJobManifestError::InvalidJobState(ref cause) => write!(f, "{}", cause),
             GetJobManifestError::InvalidResource(ref cause) => write!(f, "{}", cause),
+            GetJobManifestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobManifestError {}
 /// Errors returned by GetJobUnlockCode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobUnlockCodeError {
     /// <p>The action can't be performed because the job's current state doesn't allow that action to be performed.</p>
     InvalidJobState(String),
     /// <p>The specified resource can't be found. Check the information you provided in your last request, and try again.</p>
     InvalidResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobUnlockCodeError {
@@ -1345,13 +1425,19 @@ impl GetJobUnlockCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(GetJobUnlockCodeError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        GetJobUnlockCodeError::InvalidJobState(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(GetJobUnlockCodeError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        GetJobUnlockCodeError::InvalidResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobUnlockCodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1363,20 +1449,25 @@ impl fmt::Display for GetJobUnlockCodeError {
         match *self {
             GetJobUnlockCodeError::InvalidJobState(ref cause) => write!(f, "{}", cause),
             GetJobUnlockCodeError::InvalidResource(ref cause) => write!(f, "{}", cause),
+            GetJobUnlockCodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobUnlockCodeError {}
 /// Errors returned by GetSnowballUsage
 #[derive(Debug, PartialEq)]
-pub enum GetSnowballUsageError {}
+#[non_exhaustive]
+pub enum GetSnowballUsageError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetSnowballUsageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetSnowballUsageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSnowballUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1385,17 +1476,22 @@ impl GetSnowballUsageError {
 impl fmt::Display for GetSnowballUsageError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetSnowballUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetSnowballUsageError {}
 /// Errors returned by GetSoftwareUpdates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSoftwareUpdatesError {
     /// <p>The action can't be performed because the job's current state doesn't allow that action to be performed.</p>
     InvalidJobState(String),
     /// <p>The specified resource can't be found. Check the information you provided in your last request, and try again.</p>
     InvalidResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSoftwareUpdatesError {
@@ -1403,13 +1499,19 @@ impl GetSoftwareUpdatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(GetSoftwareUpdatesError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        GetSoftwareUpdatesError::InvalidJobState(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(GetSoftwareUpdatesError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        GetSoftwareUpdatesError::InvalidResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSoftwareUpdatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1421,17 +1523,21 @@ impl fmt::Display for GetSoftwareUpdatesError {
         match *self {
             GetSoftwareUpdatesError::InvalidJobState(ref cause) => write!(f, "{}", cause),
             GetSoftwareUpdatesError::InvalidResource(ref cause) => write!(f, "{}", cause),
+            GetSoftwareUpdatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSoftwareUpdatesError {}
 /// Errors returned by ListClusterJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListClusterJobsError {
     /// <p>The <code>NextToken</code> string was altered unexpectedly, and the operation has stopped. Run the operation without changing the <code>NextToken</code> string, and try again.</p>
     InvalidNextToken(String),
     /// <p>The specified resource can't be found. Check the information you provided in your last request, and try again.</p>
     InvalidResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListClusterJobsError {
@@ -1439,13 +1545,19 @@ impl ListClusterJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListClusterJobsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListClusterJobsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(ListClusterJobsError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        ListClusterJobsError::InvalidResource(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListClusterJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1457,15 +1569,19 @@ impl fmt::Display for ListClusterJobsError {
         match *self {
             ListClusterJobsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListClusterJobsError::InvalidResource(ref cause) => write!(f, "{}", cause),
+            ListClusterJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListClusterJobsError {}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListClustersError {
     /// <p>The <code>NextToken</code> string was altered unexpectedly, and the operation has stopped. Run the operation without changing the <code>NextToken</code> string, and try again.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListClustersError {
@@ -1473,10 +1589,10 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListClustersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListClustersError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListClustersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1487,17 +1603,21 @@ impl fmt::Display for ListClustersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListClustersError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListClustersError {}
 /// Errors returned by ListCompatibleImages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCompatibleImagesError {
     /// <p>Your IAM user lacks the necessary Amazon EC2 permissions to perform the attempted action.</p>
     Ec2RequestFailed(String),
     /// <p>The <code>NextToken</code> string was altered unexpectedly, and the operation has stopped. Run the operation without changing the <code>NextToken</code> string, and try again.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCompatibleImagesError {
@@ -1505,17 +1625,19 @@ impl ListCompatibleImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(ListCompatibleImagesError::Ec2RequestFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCompatibleImagesError::Ec2RequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCompatibleImagesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCompatibleImagesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCompatibleImagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1527,15 +1649,19 @@ impl fmt::Display for ListCompatibleImagesError {
         match *self {
             ListCompatibleImagesError::Ec2RequestFailed(ref cause) => write!(f, "{}", cause),
             ListCompatibleImagesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListCompatibleImagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCompatibleImagesError {}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsError {
     /// <p>The <code>NextToken</code> string was altered unexpectedly, and the operation has stopped. Run the operation without changing the <code>NextToken</code> string, and try again.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsError {
@@ -1543,10 +1669,10 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListJobsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListJobsError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1557,12 +1683,14 @@ impl fmt::Display for ListJobsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListJobsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsError {}
 /// Errors returned by UpdateCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateClusterError {
     /// <p>Your IAM user lacks the necessary Amazon EC2 permissions to perform the attempted action.</p>
     Ec2RequestFailed(String),
@@ -1574,6 +1702,8 @@ pub enum UpdateClusterError {
     InvalidResource(String),
     /// <p>The provided AWS Key Management Service key lacks the permissions to perform the specified <a>CreateJob</a> or <a>UpdateJob</a> action.</p>
     KMSRequestFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateClusterError {
@@ -1581,24 +1711,31 @@ impl UpdateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(UpdateClusterError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service(
+                        UpdateClusterError::Ec2RequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidInputCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterError::InvalidInputCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidJobState(err.msg))
+                    return RusotoError::service(UpdateClusterError::InvalidJobState(err.msg), &res)
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidResource(err.msg))
+                    return RusotoError::service(UpdateClusterError::InvalidResource(err.msg), &res)
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(UpdateClusterError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(
+                        UpdateClusterError::KMSRequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1613,12 +1750,14 @@ impl fmt::Display for UpdateClusterError {
             UpdateClusterError::InvalidJobState(ref cause) => write!(f, "{}", cause),
             UpdateClusterError::InvalidResource(ref cause) => write!(f, "{}", cause),
             UpdateClusterError::KMSRequestFailed(ref cause) => write!(f, "{}", cause),
+            UpdateClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateClusterError {}
 /// Errors returned by UpdateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateJobError {
     /// <p>Job creation failed. Currently, clusters support five nodes. If you have less than five nodes for your cluster and you have more nodes to create for this cluster, try again and create jobs until your cluster has exactly five notes.</p>
     ClusterLimitExceeded(String),
@@ -1632,6 +1771,8 @@ pub enum UpdateJobError {
     InvalidResource(String),
     /// <p>The provided AWS Key Management Service key lacks the permissions to perform the specified <a>CreateJob</a> or <a>UpdateJob</a> action.</p>
     KMSRequestFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateJobError {
@@ -1639,25 +1780,31 @@ impl UpdateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterLimitExceededException" => {
-                    return RusotoError::Service(UpdateJobError::ClusterLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateJobError::ClusterLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(UpdateJobError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service(UpdateJobError::Ec2RequestFailed(err.msg), &res)
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidInputCombination(err.msg))
+                    return RusotoError::service(
+                        UpdateJobError::InvalidInputCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidJobState(err.msg))
+                    return RusotoError::service(UpdateJobError::InvalidJobState(err.msg), &res)
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidResource(err.msg))
+                    return RusotoError::service(UpdateJobError::InvalidResource(err.msg), &res)
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(UpdateJobError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(UpdateJobError::KMSRequestFailed(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1673,6 +1820,7 @@ impl fmt::Display for UpdateJobError {
             UpdateJobError::InvalidJobState(ref cause) => write!(f, "{}", cause),
             UpdateJobError::InvalidResource(ref cause) => write!(f, "{}", cause),
             UpdateJobError::KMSRequestFailed(ref cause) => write!(f, "{}", cause),
+            UpdateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1804,6 +1952,7 @@ impl SnowballClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SnowballClient {
         SnowballClient {
             client: Client::shared(),
@@ -1829,16 +1978,67 @@ impl SnowballClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SnowballClient {
         SnowballClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SnowballClient> {
+        rusoto_core::ClientBuilder::new(SnowballClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SnowballClient {
+        SnowballClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Snowball for SnowballClient {
-    /// <p>Cancels a cluster job. You can only cancel a cluster job while it's in the <code>AwaitingQuorum</code> status. You'll have at least an hour after creating a cluster job to cancel it.</p>
-    async fn cancel_cluster(
+impl SnowballClient {
+    /// Calls `cancel_cluster`, also returning the metadata of the response.
+    pub async fn cancel_cluster_with_metadata(
         &self,
         input: CancelClusterRequest,
-    ) -> Result<CancelClusterResult, RusotoError<CancelClusterError>> {
+    ) -> Result<(CancelClusterResult, ResponseMetadata), RusotoError<CancelClusterError>> {
+        let request = self.build_cancel_cluster_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelClusterResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelClusterError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_cluster(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelClusterRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_cluster_request(&self, input: CancelClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CancelCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1848,27 +2048,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_job`, also returning the metadata of the response.
+    pub async fn cancel_job_with_metadata(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<(CancelJobResult, ResponseMetadata), RusotoError<CancelJobError>> {
+        let request = self.build_cancel_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CancelClusterResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<CancelJobResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelJobError::from_response(response))
         }
     }
 
-    /// <p>Cancels the specified job. You can only cancel a job before its <code>JobState</code> value changes to <code>PreparingAppliance</code>. Requesting the <code>ListJobs</code> or <code>DescribeJob</code> action returns a job's <code>JobState</code> as part of the response element data returned.</p>
-    async fn cancel_job(
+    /// Presigns the request `cancel_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CancelJobRequest,
-    ) -> Result<CancelJobResult, RusotoError<CancelJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_job_request(&self, input: CancelJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CancelJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1878,27 +2103,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_address`, also returning the metadata of the response.
+    pub async fn create_address_with_metadata(
+        &self,
+        input: CreateAddressRequest,
+    ) -> Result<(CreateAddressResult, ResponseMetadata), RusotoError<CreateAddressError>> {
+        let request = self.build_create_address_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CancelJobResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAddressResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAddressError::from_response(response))
         }
     }
 
-    /// <p>Creates an address for a Snowball to be shipped to. In most regions, addresses are validated at the time of creation. The address you provide must be located within the serviceable area of your region. If the address is invalid or unsupported, then an exception is thrown.</p>
-    async fn create_address(
+    /// Presigns the request `create_address` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_address(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAddressRequest,
-    ) -> Result<CreateAddressResult, RusotoError<CreateAddressError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_address_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_address_request(&self, input: CreateAddressRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CreateAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1908,27 +2158,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_cluster`, also returning the metadata of the response.
+    pub async fn create_cluster_with_metadata(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<(CreateClusterResult, ResponseMetadata), RusotoError<CreateClusterError>> {
+        let request = self.build_create_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAddressResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateClusterResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAddressError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateClusterError::from_response(response))
         }
     }
 
-    /// <p>Creates an empty cluster. Each cluster supports five nodes. You use the <a>CreateJob</a> action separately to create the jobs for each of these nodes. The cluster does not ship until these five node jobs have been created.</p>
-    async fn create_cluster(
+    /// Presigns the request `create_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateClusterRequest,
-    ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_request(&self, input: CreateClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CreateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1938,27 +2213,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_job`, also returning the metadata of the response.
+    pub async fn create_job_with_metadata(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<(CreateJobResult, ResponseMetadata), RusotoError<CreateJobError>> {
+        let request = self.build_create_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateClusterResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<CreateJobResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateJobError::from_response(response))
         }
     }
 
-    /// <p>Creates a job to import or export data between Amazon S3 and your on-premises data center. Your AWS account must have the right trust policies and permissions in place to create a job for Snowball. If you're creating a job for a node in a cluster, you only need to provide the <code>clusterId</code> value; the other job attributes are inherited from the cluster. </p>
-    async fn create_job(
+    /// Presigns the request `create_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateJobRequest,
-    ) -> Result<CreateJobResult, RusotoError<CreateJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_job_request(&self, input: CreateJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CreateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1968,27 +2268,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_address`, also returning the metadata of the response.
+    pub async fn describe_address_with_metadata(
+        &self,
+        input: DescribeAddressRequest,
+    ) -> Result<(DescribeAddressResult, ResponseMetadata), RusotoError<DescribeAddressError>> {
+        let request = self.build_describe_address_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateJobResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAddressResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAddressError::from_response(response))
         }
     }
 
-    /// <p>Takes an <code>AddressId</code> and returns specific details about that address in the form of an <code>Address</code> object.</p>
-    async fn describe_address(
+    /// Presigns the request `describe_address` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_address(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAddressRequest,
-    ) -> Result<DescribeAddressResult, RusotoError<DescribeAddressError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_address_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_address_request(&self, input: DescribeAddressRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1998,27 +2323,53 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_addresses`, also returning the metadata of the response.
+    pub async fn describe_addresses_with_metadata(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> Result<(DescribeAddressesResult, ResponseMetadata), RusotoError<DescribeAddressesError>>
+    {
+        let request = self.build_describe_addresses_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAddressResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAddressesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAddressError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAddressesError::from_response(response))
         }
     }
 
-    /// <p>Returns a specified number of <code>ADDRESS</code> objects. Calling this API in one of the US regions will return addresses from the list of all addresses associated with this account in all US regions.</p>
-    async fn describe_addresses(
+    /// Presigns the request `describe_addresses` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_addresses(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAddressesRequest,
-    ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_addresses_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_addresses_request(&self, input: DescribeAddressesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeAddresses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2028,27 +2379,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_cluster`, also returning the metadata of the response.
+    pub async fn describe_cluster_with_metadata(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<(DescribeClusterResult, ResponseMetadata), RusotoError<DescribeClusterError>> {
+        let request = self.build_describe_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAddressesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeClusterResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAddressesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeClusterError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific cluster including shipping information, cluster status, and other important metadata.</p>
-    async fn describe_cluster(
+    /// Presigns the request `describe_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeClusterRequest,
-    ) -> Result<DescribeClusterResult, RusotoError<DescribeClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_request(&self, input: DescribeClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2058,27 +2434,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_job`, also returning the metadata of the response.
+    pub async fn describe_job_with_metadata(
+        &self,
+        input: DescribeJobRequest,
+    ) -> Result<(DescribeJobResult, ResponseMetadata), RusotoError<DescribeJobError>> {
+        let request = self.build_describe_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeClusterResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeJobResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeJobError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specific job including shipping information, job status, and other important metadata. </p>
-    async fn describe_job(
+    /// Presigns the request `describe_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeJobRequest,
-    ) -> Result<DescribeJobResult, RusotoError<DescribeJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_job_request(&self, input: DescribeJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2088,27 +2489,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_job_manifest`, also returning the metadata of the response.
+    pub async fn get_job_manifest_with_metadata(
+        &self,
+        input: GetJobManifestRequest,
+    ) -> Result<(GetJobManifestResult, ResponseMetadata), RusotoError<GetJobManifestError>> {
+        let request = self.build_get_job_manifest_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeJobResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetJobManifestResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetJobManifestError::from_response(response))
         }
     }
 
-    /// <p>Returns a link to an Amazon S3 presigned URL for the manifest file associated with the specified <code>JobId</code> value. You can access the manifest file for up to 60 minutes after this request has been made. To access the manifest file after 60 minutes have passed, you'll have to make another call to the <code>GetJobManifest</code> action.</p> <p>The manifest is an encrypted file that you can download after your job enters the <code>WithCustomer</code> status. The manifest is decrypted by using the <code>UnlockCode</code> code value, when you pass both values to the Snowball through the Snowball client when the client is started for the first time.</p> <p>As a best practice, we recommend that you don't save a copy of an <code>UnlockCode</code> value in the same location as the manifest file for that job. Saving these separately helps prevent unauthorized parties from gaining access to the Snowball associated with that job.</p> <p>The credentials of a given job, including its manifest file and unlock code, expire 90 days after the job is created.</p>
-    async fn get_job_manifest(
+    /// Presigns the request `get_job_manifest` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job_manifest(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobManifestRequest,
-    ) -> Result<GetJobManifestResult, RusotoError<GetJobManifestError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_manifest_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_manifest_request(&self, input: GetJobManifestRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetJobManifest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2118,27 +2544,53 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_job_unlock_code`, also returning the metadata of the response.
+    pub async fn get_job_unlock_code_with_metadata(
+        &self,
+        input: GetJobUnlockCodeRequest,
+    ) -> Result<(GetJobUnlockCodeResult, ResponseMetadata), RusotoError<GetJobUnlockCodeError>>
+    {
+        let request = self.build_get_job_unlock_code_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetJobManifestResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetJobUnlockCodeResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobManifestError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetJobUnlockCodeError::from_response(response))
         }
     }
 
-    /// <p>Returns the <code>UnlockCode</code> code value for the specified job. A particular <code>UnlockCode</code> value can be accessed for up to 90 days after the associated job has been created.</p> <p>The <code>UnlockCode</code> value is a 29-character code with 25 alphanumeric characters and 4 hyphens. This code is used to decrypt the manifest file when it is passed along with the manifest to the Snowball through the Snowball client when the client is started for the first time.</p> <p>As a best practice, we recommend that you don't save a copy of the <code>UnlockCode</code> in the same location as the manifest file for that job. Saving these separately helps prevent unauthorized parties from gaining access to the Snowball associated with that job.</p>
-    async fn get_job_unlock_code(
+    /// Presigns the request `get_job_unlock_code` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job_unlock_code(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobUnlockCodeRequest,
-    ) -> Result<GetJobUnlockCodeResult, RusotoError<GetJobUnlockCodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_unlock_code_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_unlock_code_request(&self, input: GetJobUnlockCodeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetJobUnlockCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2148,26 +2600,51 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_snowball_usage`, also returning the metadata of the response.
+    pub async fn get_snowball_usage_with_metadata(
+        &self,
+    ) -> Result<(GetSnowballUsageResult, ResponseMetadata), RusotoError<GetSnowballUsageError>>
+    {
+        let request = self.build_get_snowball_usage_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetJobUnlockCodeResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSnowballUsageResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobUnlockCodeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSnowballUsageError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the Snowball service limit for your account, and also the number of Snowballs your account has in use.</p> <p>The default service limit for the number of Snowballs that you can have at one time is 1. If you want to increase your service limit, contact AWS Support.</p>
-    async fn get_snowball_usage(
+    /// Presigns the request `get_snowball_usage` would send with `presigner` instead of sending it.
+    pub async fn presign_get_snowball_usage(
         &self,
-    ) -> Result<GetSnowballUsageResult, RusotoError<GetSnowballUsageError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_snowball_usage_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_snowball_usage_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetSnowballUsage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2176,27 +2653,56 @@ impl Snowball for SnowballClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_software_updates`, also returning the metadata of the response.
+    pub async fn get_software_updates_with_metadata(
+        &self,
+        input: GetSoftwareUpdatesRequest,
+    ) -> Result<(GetSoftwareUpdatesResult, ResponseMetadata), RusotoError<GetSoftwareUpdatesError>>
+    {
+        let request = self.build_get_software_updates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetSnowballUsageResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSoftwareUpdatesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSnowballUsageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSoftwareUpdatesError::from_response(response))
         }
     }
 
-    /// <p>Returns an Amazon S3 presigned URL for an update file associated with a specified <code>JobId</code>.</p>
-    async fn get_software_updates(
+    /// Presigns the request `get_software_updates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_software_updates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSoftwareUpdatesRequest,
-    ) -> Result<GetSoftwareUpdatesResult, RusotoError<GetSoftwareUpdatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_software_updates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_software_updates_request(
+        &self,
+        input: GetSoftwareUpdatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetSoftwareUpdates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2206,28 +2712,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_cluster_jobs`, also returning the metadata of the response.
+    pub async fn list_cluster_jobs_with_metadata(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> Result<(ListClusterJobsResult, ResponseMetadata), RusotoError<ListClusterJobsError>> {
+        let request = self.build_list_cluster_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSoftwareUpdatesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListClusterJobsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSoftwareUpdatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListClusterJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <code>JobListEntry</code> objects of the specified length. Each <code>JobListEntry</code> object is for a job in the specified cluster and contains a job's state, a job's ID, and other information.</p>
-    async fn list_cluster_jobs(
+    /// Presigns the request `list_cluster_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_cluster_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListClusterJobsRequest,
-    ) -> Result<ListClusterJobsResult, RusotoError<ListClusterJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_cluster_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_cluster_jobs_request(&self, input: ListClusterJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListClusterJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2237,27 +2767,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_clusters`, also returning the metadata of the response.
+    pub async fn list_clusters_with_metadata(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<(ListClustersResult, ResponseMetadata), RusotoError<ListClustersError>> {
+        let request = self.build_list_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListClusterJobsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListClustersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListClusterJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListClustersError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <code>ClusterListEntry</code> objects of the specified length. Each <code>ClusterListEntry</code> object contains a cluster's state, a cluster's ID, and other important status information.</p>
-    async fn list_clusters(
+    /// Presigns the request `list_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_clusters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListClustersRequest,
-    ) -> Result<ListClustersResult, RusotoError<ListClustersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_clusters_request(&self, input: ListClustersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2267,27 +2822,58 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_compatible_images`, also returning the metadata of the response.
+    pub async fn list_compatible_images_with_metadata(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> Result<
+        (ListCompatibleImagesResult, ResponseMetadata),
+        RusotoError<ListCompatibleImagesError>,
+    > {
+        let request = self.build_list_compatible_images_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListClustersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCompatibleImagesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListClustersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCompatibleImagesError::from_response(response))
         }
     }
 
-    /// <p>This action returns a list of the different Amazon EC2 Amazon Machine Images (AMIs) that are owned by your AWS account that would be supported for use on a Snowball Edge device. Currently, supported AMIs are based on the CentOS 7 (x86_64) - with Updates HVM, Ubuntu Server 14.04 LTS (HVM), and Ubuntu 16.04 LTS - Xenial (HVM) images, available on the AWS Marketplace.</p>
-    async fn list_compatible_images(
+    /// Presigns the request `list_compatible_images` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_compatible_images(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCompatibleImagesRequest,
-    ) -> Result<ListCompatibleImagesResult, RusotoError<ListCompatibleImagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_compatible_images_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_compatible_images_request(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListCompatibleImages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2297,55 +2883,104 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_jobs`, also returning the metadata of the response.
+    pub async fn list_jobs_with_metadata(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<(ListJobsResult, ResponseMetadata), RusotoError<ListJobsError>> {
+        let request = self.build_list_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCompatibleImagesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCompatibleImagesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <code>JobListEntry</code> objects of the specified length. Each <code>JobListEntry</code> object contains a job's state, a job's ID, and a value that indicates whether the job is a job part, in the case of export jobs. Calling this API action in one of the US regions will return jobs from the list of all jobs associated with this account in all US regions.</p>
-    async fn list_jobs(
+    /// Presigns the request `list_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJobsRequest,
-    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_request(&self, input: ListJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSIESnowballJobManagementService.ListJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_cluster`, also returning the metadata of the response.
+    pub async fn update_cluster_with_metadata(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> Result<(UpdateClusterResult, ResponseMetadata), RusotoError<UpdateClusterError>> {
+        let request = self.build_update_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateClusterResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateClusterError::from_response(response))
         }
     }
 
-    /// <p>While a cluster's <code>ClusterState</code> value is in the <code>AwaitingQuorum</code> state, you can update some of the information associated with a cluster. Once the cluster changes to a different job state, usually 60 minutes after the cluster being created, this action is no longer available.</p>
-    async fn update_cluster(
+    /// Presigns the request `update_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateClusterRequest,
-    ) -> Result<UpdateClusterResult, RusotoError<UpdateClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_cluster_request(&self, input: UpdateClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("UpdateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2355,27 +2990,52 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_job`, also returning the metadata of the response.
+    pub async fn update_job_with_metadata(
+        &self,
+        input: UpdateJobRequest,
+    ) -> Result<(UpdateJobResult, ResponseMetadata), RusotoError<UpdateJobError>> {
+        let request = self.build_update_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateClusterResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<UpdateJobResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateJobError::from_response(response))
         }
     }
 
-    /// <p>While a job's <code>JobState</code> value is <code>New</code>, you can update some of the information associated with a job. Once the job changes to a different job state, usually within 60 minutes of the job being created, this action is no longer available.</p>
-    async fn update_job(
+    /// Presigns the request `update_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateJobRequest,
-    ) -> Result<UpdateJobResult, RusotoError<UpdateJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_job_request(&self, input: UpdateJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("UpdateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2385,18 +3045,379 @@ impl Snowball for SnowballClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeAddresses` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_addresses_pages(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input| async move { client.describe_addresses(input).await },
+            |input: &DescribeAddressesRequest, output: &DescribeAddressesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAddressesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Addresses` of every page of `DescribeAddresses` results for `input`, as `describe_addresses_pages` requests them.
+    pub fn describe_addresses_stream(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> impl futures::Stream<Item = Result<Address, RusotoError<DescribeAddressesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_addresses_pages(input), |page| {
+            page.addresses.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListClusterJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_cluster_jobs_pages(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListClusterJobsResult, RusotoError<ListClusterJobsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input| async move { client.list_cluster_jobs(input).await },
+            |input: &ListClusterJobsRequest, output: &ListClusterJobsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListClusterJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `JobListEntries` of every page of `ListClusterJobs` results for `input`, as `list_cluster_jobs_pages` requests them.
+    pub fn list_cluster_jobs_stream(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> impl futures::Stream<Item = Result<JobListEntry, RusotoError<ListClusterJobsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_cluster_jobs_pages(input), |page| {
+            page.job_list_entries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListClusters` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<ListClustersResult, RusotoError<ListClustersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input| async move { client.list_clusters(input).await },
+            |input: &ListClustersRequest, output: &ListClustersResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListClustersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ClusterListEntries` of every page of `ListClusters` results for `input`, as `list_clusters_pages` requests them.
+    pub fn list_clusters_stream(
+        &self,
+        input: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<ClusterListEntry, RusotoError<ListClustersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_clusters_pages(input), |page| {
+            page.cluster_list_entries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCompatibleImages` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_compatible_images_pages(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListCompatibleImagesResult, RusotoError<ListCompatibleImagesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input| async move { client.list_compatible_images(input).await },
+            |input: &ListCompatibleImagesRequest, output: &ListCompatibleImagesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCompatibleImagesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CompatibleImages` of every page of `ListCompatibleImages` results for `input`, as `list_compatible_images_pages` requests them.
+    pub fn list_compatible_images_stream(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> impl futures::Stream<Item = Result<CompatibleImage, RusotoError<ListCompatibleImagesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_compatible_images_pages(input), |page| {
+            page.compatible_images.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResult, RusotoError<ListJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnowballClient, input| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `JobListEntries` of every page of `ListJobs` results for `input`, as `list_jobs_pages` requests them.
+    pub fn list_jobs_stream(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<JobListEntry, RusotoError<ListJobsError>>> + Send {
+        rusoto_core::pagination::items(self.list_jobs_pages(input), |page| {
+            page.job_list_entries.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Snowball for SnowballClient {
+    /// <p>Cancels a cluster job. You can only cancel a cluster job while it's in the <code>AwaitingQuorum</code> status. You'll have at least an hour after creating a cluster job to cancel it.</p>
+    async fn cancel_cluster(
+        &self,
+        input: CancelClusterRequest,
+    ) -> Result<CancelClusterResult, RusotoError<CancelClusterError>> {
+        self.cancel_cluster_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateJobResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateJobError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels the specified job. You can only cancel a job before its <code>JobState</code> value changes to <code>PreparingAppliance</code>. Requesting the <code>ListJobs</code> or <code>DescribeJob</code> action returns a job's <code>JobState</code> as part of the response element data returned.</p>
+    async fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResult, RusotoError<CancelJobError>> {
+        self.cancel_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an address for a Snowball to be shipped to. In most regions, addresses are validated at the time of creation. The address you provide must be located within the serviceable area of your region. If the address is invalid or unsupported, then an exception is thrown.</p>
+    async fn create_address(
+        &self,
+        input: CreateAddressRequest,
+    ) -> Result<CreateAddressResult, RusotoError<CreateAddressError>> {
+        self.create_address_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an empty cluster. Each cluster supports five nodes. You use the <a>CreateJob</a> action separately to create the jobs for each of these nodes. The cluster does not ship until these five node jobs have been created.</p>
+    async fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
+        self.create_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a job to import or export data between Amazon S3 and your on-premises data center. Your AWS account must have the right trust policies and permissions in place to create a job for Snowball. If you're creating a job for a node in a cluster, you only need to provide the <code>clusterId</code> value; the other job attributes are inherited from the cluster. </p>
+    async fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResult, RusotoError<CreateJobError>> {
+        self.create_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Takes an <code>AddressId</code> and returns specific details about that address in the form of an <code>Address</code> object.</p>
+    async fn describe_address(
+        &self,
+        input: DescribeAddressRequest,
+    ) -> Result<DescribeAddressResult, RusotoError<DescribeAddressError>> {
+        self.describe_address_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a specified number of <code>ADDRESS</code> objects. Calling this API in one of the US regions will return addresses from the list of all addresses associated with this account in all US regions.</p>
+    async fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
+        self.describe_addresses_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific cluster including shipping information, cluster status, and other important metadata.</p>
+    async fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<DescribeClusterResult, RusotoError<DescribeClusterError>> {
+        self.describe_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specific job including shipping information, job status, and other important metadata. </p>
+    async fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> Result<DescribeJobResult, RusotoError<DescribeJobError>> {
+        self.describe_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a link to an Amazon S3 presigned URL for the manifest file associated with the specified <code>JobId</code> value. You can access the manifest file for up to 60 minutes after this request has been made. To access the manifest file after 60 minutes have passed, you'll have to make another call to the <code>GetJobManifest</code> action.</p> <p>The manifest is an encrypted file that you can download after your job enters the <code>WithCustomer</code> status. The manifest is decrypted by using the <code>UnlockCode</code> code value, when you pass both values to the Snowball through the Snowball client when the client is started for the first time.</p> <p>As a best practice, we recommend that you don't save a copy of an <code>UnlockCode</code> value in the same location as the manifest file for that job. Saving these separately helps prevent unauthorized parties from gaining access to the Snowball associated with that job.</p> <p>The credentials of a given job, including its manifest file and unlock code, expire 90 days after the job is created.</p>
+    async fn get_job_manifest(
+        &self,
+        input: GetJobManifestRequest,
+    ) -> Result<GetJobManifestResult, RusotoError<GetJobManifestError>> {
+        self.get_job_manifest_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <code>UnlockCode</code> code value for the specified job. A particular <code>UnlockCode</code> value can be accessed for up to 90 days after the associated job has been created.</p> <p>The <code>UnlockCode</code> value is a 29-character code with 25 alphanumeric characters and 4 hyphens. This code is used to decrypt the manifest file when it is passed along with the manifest to the Snowball through the Snowball client when the client is started for the first time.</p> <p>As a best practice, we recommend that you don't save a copy of the <code>UnlockCode</code> in the same location as the manifest file for that job. Saving these separately helps prevent unauthorized parties from gaining access to the Snowball associated with that job.</p>
+    async fn get_job_unlock_code(
+        &self,
+        input: GetJobUnlockCodeRequest,
+    ) -> Result<GetJobUnlockCodeResult, RusotoError<GetJobUnlockCodeError>> {
+        self.get_job_unlock_code_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the Snowball service limit for your account, and also the number of Snowballs your account has in use.</p> <p>The default service limit for the number of Snowballs that you can have at one time is 1. If you want to increase your service limit, contact AWS Support.</p>
+    async fn get_snowball_usage(
+        &self,
+    ) -> Result<GetSnowballUsageResult, RusotoError<GetSnowballUsageError>> {
+        self.get_snowball_usage_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an Amazon S3 presigned URL for an update file associated with a specified <code>JobId</code>.</p>
+    async fn get_software_updates(
+        &self,
+        input: GetSoftwareUpdatesRequest,
+    ) -> Result<GetSoftwareUpdatesResult, RusotoError<GetSoftwareUpdatesError>> {
+        self.get_software_updates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <code>JobListEntry</code> objects of the specified length. Each <code>JobListEntry</code> object is for a job in the specified cluster and contains a job's state, a job's ID, and other information.</p>
+    async fn list_cluster_jobs(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> Result<ListClusterJobsResult, RusotoError<ListClusterJobsError>> {
+        self.list_cluster_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <code>ClusterListEntry</code> objects of the specified length. Each <code>ClusterListEntry</code> object contains a cluster's state, a cluster's ID, and other important status information.</p>
+    async fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResult, RusotoError<ListClustersError>> {
+        self.list_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This action returns a list of the different Amazon EC2 Amazon Machine Images (AMIs) that are owned by your AWS account that would be supported for use on a Snowball Edge device. Currently, supported AMIs are based on the CentOS 7 (x86_64) - with Updates HVM, Ubuntu Server 14.04 LTS (HVM), and Ubuntu 16.04 LTS - Xenial (HVM) images, available on the AWS Marketplace.</p>
+    async fn list_compatible_images(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> Result<ListCompatibleImagesResult, RusotoError<ListCompatibleImagesError>> {
+        self.list_compatible_images_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <code>JobListEntry</code> objects of the specified length. Each <code>JobListEntry</code> object contains a job's state, a job's ID, and a value that indicates whether the job is a job part, in the case of export jobs. Calling this API action in one of the US regions will return jobs from the list of all jobs associated with this account in all US regions.</p>
+    async fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+        self.list_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>While a cluster's <code>ClusterState</code> value is in the <code>AwaitingQuorum</code> state, you can update some of the information associated with a cluster. Once the cluster changes to a different job state, usually 60 minutes after the cluster being created, this action is no longer available.</p>
+    async fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> Result<UpdateClusterResult, RusotoError<UpdateClusterError>> {
+        self.update_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>While a job's <code>JobState</code> value is <code>New</code>, you can update some of the information associated with a job. Once the job changes to a different job state, usually within 60 minutes of the job being created, this action is no longer available.</p>
+    async fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> Result<UpdateJobResult, RusotoError<UpdateJobError>> {
+        self.update_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -2296,6 +2296,7 @@ impl UntagResourceResponseDeserializer {
 }
 /// Errors returned by AddPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddPermissionError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2305,6 +2306,8 @@ pub enum AddPermissionError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddPermissionError {
@@ -2316,26 +2319,30 @@ impl AddPermissionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(AddPermissionError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddPermissionError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(AddPermissionError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddPermissionError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(AddPermissionError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddPermissionError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(AddPermissionError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddPermissionError::NotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AddPermissionError::Unhandled, &res),
                 }
             }
         }
@@ -2358,12 +2365,14 @@ impl fmt::Display for AddPermissionError {
             AddPermissionError::InternalError(ref cause) => write!(f, "{}", cause),
             AddPermissionError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             AddPermissionError::NotFound(ref cause) => write!(f, "{}", cause),
+            AddPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddPermissionError {}
 /// Errors returned by CheckIfPhoneNumberIsOptedOut
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CheckIfPhoneNumberIsOptedOutError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2373,6 +2382,8 @@ pub enum CheckIfPhoneNumberIsOptedOutError {
     InvalidParameter(String),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CheckIfPhoneNumberIsOptedOutError {
@@ -2386,30 +2397,39 @@ impl CheckIfPhoneNumberIsOptedOutError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CheckIfPhoneNumberIsOptedOutError::AuthorizationError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CheckIfPhoneNumberIsOptedOutError::InternalError(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CheckIfPhoneNumberIsOptedOutError::InvalidParameter(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(CheckIfPhoneNumberIsOptedOutError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CheckIfPhoneNumberIsOptedOutError::Throttled(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CheckIfPhoneNumberIsOptedOutError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -2436,12 +2456,14 @@ impl fmt::Display for CheckIfPhoneNumberIsOptedOutError {
                 write!(f, "{}", cause)
             }
             CheckIfPhoneNumberIsOptedOutError::Throttled(ref cause) => write!(f, "{}", cause),
+            CheckIfPhoneNumberIsOptedOutError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CheckIfPhoneNumberIsOptedOutError {}
 /// Errors returned by ConfirmSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfirmSubscriptionError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2455,6 +2477,8 @@ pub enum ConfirmSubscriptionError {
     NotFound(String),
     /// <p>Indicates that the customer already owns the maximum allowed number of subscriptions.</p>
     SubscriptionLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfirmSubscriptionError {
@@ -2466,40 +2490,46 @@ impl ConfirmSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ConfirmSubscriptionError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "FilterPolicyLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ConfirmSubscriptionError::FilterPolicyLimitExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ConfirmSubscriptionError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ConfirmSubscriptionError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ConfirmSubscriptionError::NotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SubscriptionLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ConfirmSubscriptionError::SubscriptionLimitExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ConfirmSubscriptionError::Unhandled, &res),
                 }
             }
         }
@@ -2528,12 +2558,14 @@ impl fmt::Display for ConfirmSubscriptionError {
             ConfirmSubscriptionError::SubscriptionLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ConfirmSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ConfirmSubscriptionError {}
 /// Errors returned by CreatePlatformApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePlatformApplicationError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2541,6 +2573,8 @@ pub enum CreatePlatformApplicationError {
     InternalError(String),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePlatformApplicationError {
@@ -2552,23 +2586,31 @@ impl CreatePlatformApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreatePlatformApplicationError::AuthorizationError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(CreatePlatformApplicationError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreatePlatformApplicationError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreatePlatformApplicationError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreatePlatformApplicationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -2590,12 +2632,14 @@ impl fmt::Display for CreatePlatformApplicationError {
             CreatePlatformApplicationError::AuthorizationError(ref cause) => write!(f, "{}", cause),
             CreatePlatformApplicationError::InternalError(ref cause) => write!(f, "{}", cause),
             CreatePlatformApplicationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            CreatePlatformApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePlatformApplicationError {}
 /// Errors returned by CreatePlatformEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePlatformEndpointError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2605,6 +2649,8 @@ pub enum CreatePlatformEndpointError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePlatformEndpointError {
@@ -2616,26 +2662,32 @@ impl CreatePlatformEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreatePlatformEndpointError::AuthorizationError(parsed_error.message),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(CreatePlatformEndpointError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreatePlatformEndpointError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(CreatePlatformEndpointError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreatePlatformEndpointError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(CreatePlatformEndpointError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreatePlatformEndpointError::NotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(CreatePlatformEndpointError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -2658,12 +2710,14 @@ impl fmt::Display for CreatePlatformEndpointError {
             CreatePlatformEndpointError::InternalError(ref cause) => write!(f, "{}", cause),
             CreatePlatformEndpointError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreatePlatformEndpointError::NotFound(ref cause) => write!(f, "{}", cause),
+            CreatePlatformEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePlatformEndpointError {}
 /// Errors returned by CreateTopic
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTopicError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2683,6 +2737,8 @@ pub enum CreateTopicError {
     TagPolicy(String),
     /// <p>Indicates that the customer already owns the maximum allowed number of topics.</p>
     TopicLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTopicError {
@@ -2694,51 +2750,60 @@ impl CreateTopicError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(CreateTopicError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTopicError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(CreateTopicError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTopicError::ConcurrentAccess(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(CreateTopicError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTopicError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(CreateTopicError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTopicError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(CreateTopicError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTopicError::InvalidSecurity(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(CreateTopicError::StaleTag(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTopicError::StaleTag(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagLimitExceeded" => {
-                        return RusotoError::Service(CreateTopicError::TagLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTopicError::TagLimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(CreateTopicError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTopicError::TagPolicy(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TopicLimitExceeded" => {
-                        return RusotoError::Service(CreateTopicError::TopicLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTopicError::TopicLimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateTopicError::Unhandled, &res),
                 }
             }
         }
@@ -2766,12 +2831,14 @@ impl fmt::Display for CreateTopicError {
             CreateTopicError::TagLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateTopicError::TagPolicy(ref cause) => write!(f, "{}", cause),
             CreateTopicError::TopicLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateTopicError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTopicError {}
 /// Errors returned by DeleteEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEndpointError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2779,6 +2846,8 @@ pub enum DeleteEndpointError {
     InternalError(String),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEndpointError {
@@ -2790,21 +2859,24 @@ impl DeleteEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(DeleteEndpointError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteEndpointError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(DeleteEndpointError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteEndpointError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(DeleteEndpointError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteEndpointError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteEndpointError::Unhandled, &res),
                 }
             }
         }
@@ -2826,12 +2898,14 @@ impl fmt::Display for DeleteEndpointError {
             DeleteEndpointError::AuthorizationError(ref cause) => write!(f, "{}", cause),
             DeleteEndpointError::InternalError(ref cause) => write!(f, "{}", cause),
             DeleteEndpointError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            DeleteEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEndpointError {}
 /// Errors returned by DeletePlatformApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePlatformApplicationError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2839,6 +2913,8 @@ pub enum DeletePlatformApplicationError {
     InternalError(String),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePlatformApplicationError {
@@ -2850,23 +2926,31 @@ impl DeletePlatformApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeletePlatformApplicationError::AuthorizationError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(DeletePlatformApplicationError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeletePlatformApplicationError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeletePlatformApplicationError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeletePlatformApplicationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -2888,12 +2972,14 @@ impl fmt::Display for DeletePlatformApplicationError {
             DeletePlatformApplicationError::AuthorizationError(ref cause) => write!(f, "{}", cause),
             DeletePlatformApplicationError::InternalError(ref cause) => write!(f, "{}", cause),
             DeletePlatformApplicationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            DeletePlatformApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePlatformApplicationError {}
 /// Errors returned by DeleteTopic
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTopicError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2909,6 +2995,8 @@ pub enum DeleteTopicError {
     StaleTag(String),
     /// <p>The request doesn't comply with the IAM tag policy. Correct your request and then retry it.</p>
     TagPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTopicError {
@@ -2920,41 +3008,48 @@ impl DeleteTopicError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(DeleteTopicError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTopicError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(DeleteTopicError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTopicError::ConcurrentAccess(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(DeleteTopicError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTopicError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(DeleteTopicError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTopicError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(DeleteTopicError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTopicError::NotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(DeleteTopicError::StaleTag(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTopicError::StaleTag(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(DeleteTopicError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTopicError::TagPolicy(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteTopicError::Unhandled, &res),
                 }
             }
         }
@@ -2980,12 +3075,14 @@ impl fmt::Display for DeleteTopicError {
             DeleteTopicError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteTopicError::StaleTag(ref cause) => write!(f, "{}", cause),
             DeleteTopicError::TagPolicy(ref cause) => write!(f, "{}", cause),
+            DeleteTopicError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTopicError {}
 /// Errors returned by GetEndpointAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEndpointAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -2995,6 +3092,8 @@ pub enum GetEndpointAttributesError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEndpointAttributesError {
@@ -3006,26 +3105,32 @@ impl GetEndpointAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetEndpointAttributesError::AuthorizationError(parsed_error.message),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetEndpointAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetEndpointAttributesError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(GetEndpointAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetEndpointAttributesError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(GetEndpointAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetEndpointAttributesError::NotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(GetEndpointAttributesError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -3048,12 +3153,14 @@ impl fmt::Display for GetEndpointAttributesError {
             GetEndpointAttributesError::InternalError(ref cause) => write!(f, "{}", cause),
             GetEndpointAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetEndpointAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetEndpointAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEndpointAttributesError {}
 /// Errors returned by GetPlatformApplicationAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPlatformApplicationAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3063,6 +3170,8 @@ pub enum GetPlatformApplicationAttributesError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPlatformApplicationAttributesError {
@@ -3076,32 +3185,41 @@ impl GetPlatformApplicationAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetPlatformApplicationAttributesError::AuthorizationError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetPlatformApplicationAttributesError::InternalError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetPlatformApplicationAttributesError::InvalidParameter(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetPlatformApplicationAttributesError::NotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetPlatformApplicationAttributesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -3130,12 +3248,16 @@ impl fmt::Display for GetPlatformApplicationAttributesError {
                 write!(f, "{}", cause)
             }
             GetPlatformApplicationAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetPlatformApplicationAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetPlatformApplicationAttributesError {}
 /// Errors returned by GetSMSAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSMSAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3145,6 +3267,8 @@ pub enum GetSMSAttributesError {
     InvalidParameter(String),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSMSAttributesError {
@@ -3156,26 +3280,30 @@ impl GetSMSAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(GetSMSAttributesError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetSMSAttributesError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetSMSAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetSMSAttributesError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(GetSMSAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetSMSAttributesError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(GetSMSAttributesError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetSMSAttributesError::Throttled(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetSMSAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -3198,12 +3326,14 @@ impl fmt::Display for GetSMSAttributesError {
             GetSMSAttributesError::InternalError(ref cause) => write!(f, "{}", cause),
             GetSMSAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetSMSAttributesError::Throttled(ref cause) => write!(f, "{}", cause),
+            GetSMSAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSMSAttributesError {}
 /// Errors returned by GetSubscriptionAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSubscriptionAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3213,6 +3343,8 @@ pub enum GetSubscriptionAttributesError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSubscriptionAttributesError {
@@ -3224,28 +3356,37 @@ impl GetSubscriptionAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetSubscriptionAttributesError::AuthorizationError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetSubscriptionAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetSubscriptionAttributesError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetSubscriptionAttributesError::InvalidParameter(parsed_error.message),
+                            &res,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(GetSubscriptionAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetSubscriptionAttributesError::NotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetSubscriptionAttributesError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -3268,12 +3409,14 @@ impl fmt::Display for GetSubscriptionAttributesError {
             GetSubscriptionAttributesError::InternalError(ref cause) => write!(f, "{}", cause),
             GetSubscriptionAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetSubscriptionAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetSubscriptionAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSubscriptionAttributesError {}
 /// Errors returned by GetTopicAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTopicAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3285,6 +3428,8 @@ pub enum GetTopicAttributesError {
     InvalidSecurity(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTopicAttributesError {
@@ -3296,31 +3441,36 @@ impl GetTopicAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(GetTopicAttributesError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTopicAttributesError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetTopicAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTopicAttributesError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(GetTopicAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTopicAttributesError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(GetTopicAttributesError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTopicAttributesError::InvalidSecurity(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(GetTopicAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTopicAttributesError::NotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetTopicAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -3344,12 +3494,14 @@ impl fmt::Display for GetTopicAttributesError {
             GetTopicAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetTopicAttributesError::InvalidSecurity(ref cause) => write!(f, "{}", cause),
             GetTopicAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetTopicAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTopicAttributesError {}
 /// Errors returned by ListEndpointsByPlatformApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEndpointsByPlatformApplicationError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3359,6 +3511,8 @@ pub enum ListEndpointsByPlatformApplicationError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEndpointsByPlatformApplicationError {
@@ -3372,32 +3526,41 @@ impl ListEndpointsByPlatformApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListEndpointsByPlatformApplicationError::AuthorizationError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListEndpointsByPlatformApplicationError::InternalError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListEndpointsByPlatformApplicationError::InvalidParameter(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListEndpointsByPlatformApplicationError::NotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListEndpointsByPlatformApplicationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -3426,12 +3589,16 @@ impl fmt::Display for ListEndpointsByPlatformApplicationError {
                 write!(f, "{}", cause)
             }
             ListEndpointsByPlatformApplicationError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListEndpointsByPlatformApplicationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListEndpointsByPlatformApplicationError {}
 /// Errors returned by ListPhoneNumbersOptedOut
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPhoneNumbersOptedOutError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3441,6 +3608,8 @@ pub enum ListPhoneNumbersOptedOutError {
     InvalidParameter(String),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPhoneNumbersOptedOutError {
@@ -3452,26 +3621,35 @@ impl ListPhoneNumbersOptedOutError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListPhoneNumbersOptedOutError::AuthorizationError(parsed_error.message),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListPhoneNumbersOptedOutError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListPhoneNumbersOptedOutError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListPhoneNumbersOptedOutError::InvalidParameter(parsed_error.message),
+                            &res,
                         )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(ListPhoneNumbersOptedOutError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListPhoneNumbersOptedOutError::Throttled(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListPhoneNumbersOptedOutError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -3494,12 +3672,14 @@ impl fmt::Display for ListPhoneNumbersOptedOutError {
             ListPhoneNumbersOptedOutError::InternalError(ref cause) => write!(f, "{}", cause),
             ListPhoneNumbersOptedOutError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListPhoneNumbersOptedOutError::Throttled(ref cause) => write!(f, "{}", cause),
+            ListPhoneNumbersOptedOutError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPhoneNumbersOptedOutError {}
 /// Errors returned by ListPlatformApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPlatformApplicationsError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3507,6 +3687,8 @@ pub enum ListPlatformApplicationsError {
     InternalError(String),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPlatformApplicationsError {
@@ -3518,21 +3700,29 @@ impl ListPlatformApplicationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListPlatformApplicationsError::AuthorizationError(parsed_error.message),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListPlatformApplicationsError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListPlatformApplicationsError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListPlatformApplicationsError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListPlatformApplicationsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -3554,12 +3744,14 @@ impl fmt::Display for ListPlatformApplicationsError {
             ListPlatformApplicationsError::AuthorizationError(ref cause) => write!(f, "{}", cause),
             ListPlatformApplicationsError::InternalError(ref cause) => write!(f, "{}", cause),
             ListPlatformApplicationsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            ListPlatformApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPlatformApplicationsError {}
 /// Errors returned by ListSubscriptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSubscriptionsError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3567,6 +3759,8 @@ pub enum ListSubscriptionsError {
     InternalError(String),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSubscriptionsError {
@@ -3578,21 +3772,24 @@ impl ListSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ListSubscriptionsError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListSubscriptionsError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListSubscriptionsError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListSubscriptionsError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ListSubscriptionsError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListSubscriptionsError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListSubscriptionsError::Unhandled, &res),
                 }
             }
         }
@@ -3614,12 +3811,14 @@ impl fmt::Display for ListSubscriptionsError {
             ListSubscriptionsError::AuthorizationError(ref cause) => write!(f, "{}", cause),
             ListSubscriptionsError::InternalError(ref cause) => write!(f, "{}", cause),
             ListSubscriptionsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            ListSubscriptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSubscriptionsError {}
 /// Errors returned by ListSubscriptionsByTopic
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSubscriptionsByTopicError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3629,6 +3828,8 @@ pub enum ListSubscriptionsByTopicError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSubscriptionsByTopicError {
@@ -3640,26 +3841,35 @@ impl ListSubscriptionsByTopicError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListSubscriptionsByTopicError::AuthorizationError(parsed_error.message),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListSubscriptionsByTopicError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListSubscriptionsByTopicError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListSubscriptionsByTopicError::InvalidParameter(parsed_error.message),
+                            &res,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(ListSubscriptionsByTopicError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListSubscriptionsByTopicError::NotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListSubscriptionsByTopicError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -3682,12 +3892,14 @@ impl fmt::Display for ListSubscriptionsByTopicError {
             ListSubscriptionsByTopicError::InternalError(ref cause) => write!(f, "{}", cause),
             ListSubscriptionsByTopicError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListSubscriptionsByTopicError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListSubscriptionsByTopicError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSubscriptionsByTopicError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3699,6 +3911,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>The request doesn't comply with the IAM tag policy. Correct your request and then retry it.</p>
     TagPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3710,31 +3924,36 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ListTagsForResourceError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(ListTagsForResourceError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::ConcurrentAccess(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(ListTagsForResourceError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::TagPolicy(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
                 }
             }
         }
@@ -3758,12 +3977,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TagPolicy(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTopics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTopicsError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3771,6 +3992,8 @@ pub enum ListTopicsError {
     InternalError(String),
     /// <p>Indicates that a request parameter does not comply with the associated constraints.</p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTopicsError {
@@ -3782,21 +4005,24 @@ impl ListTopicsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ListTopicsError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTopicsError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListTopicsError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTopicsError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ListTopicsError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTopicsError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTopicsError::Unhandled, &res),
                 }
             }
         }
@@ -3818,12 +4044,14 @@ impl fmt::Display for ListTopicsError {
             ListTopicsError::AuthorizationError(ref cause) => write!(f, "{}", cause),
             ListTopicsError::InternalError(ref cause) => write!(f, "{}", cause),
             ListTopicsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            ListTopicsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTopicsError {}
 /// Errors returned by OptInPhoneNumber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum OptInPhoneNumberError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3833,6 +4061,8 @@ pub enum OptInPhoneNumberError {
     InvalidParameter(String),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl OptInPhoneNumberError {
@@ -3844,26 +4074,30 @@ impl OptInPhoneNumberError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(OptInPhoneNumberError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            OptInPhoneNumberError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(OptInPhoneNumberError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            OptInPhoneNumberError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(OptInPhoneNumberError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            OptInPhoneNumberError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(OptInPhoneNumberError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            OptInPhoneNumberError::Throttled(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(OptInPhoneNumberError::Unhandled, &res),
                 }
             }
         }
@@ -3886,12 +4120,14 @@ impl fmt::Display for OptInPhoneNumberError {
             OptInPhoneNumberError::InternalError(ref cause) => write!(f, "{}", cause),
             OptInPhoneNumberError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             OptInPhoneNumberError::Throttled(ref cause) => write!(f, "{}", cause),
+            OptInPhoneNumberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for OptInPhoneNumberError {}
 /// Errors returned by Publish
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PublishError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -3921,6 +4157,8 @@ pub enum PublishError {
     NotFound(String),
     /// <p>Exception error indicating platform application disabled.</p>
     PlatformApplicationDisabled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PublishError {
@@ -3932,74 +4170,90 @@ impl PublishError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(PublishError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "EndpointDisabled" => {
-                        return RusotoError::Service(PublishError::EndpointDisabled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::EndpointDisabled(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(PublishError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(PublishError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ParameterValueInvalid" => {
-                        return RusotoError::Service(PublishError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(PublishError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::InvalidSecurity(parsed_error.message),
+                            &res,
+                        )
                     }
                     "KMSAccessDenied" => {
-                        return RusotoError::Service(PublishError::KMSAccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::KMSAccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "KMSDisabled" => {
-                        return RusotoError::Service(PublishError::KMSDisabled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::KMSDisabled(parsed_error.message),
+                            &res,
+                        )
                     }
                     "KMSInvalidState" => {
-                        return RusotoError::Service(PublishError::KMSInvalidState(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::KMSInvalidState(parsed_error.message),
+                            &res,
+                        )
                     }
                     "KMSNotFound" => {
-                        return RusotoError::Service(PublishError::KMSNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::KMSNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "KMSOptInRequired" => {
-                        return RusotoError::Service(PublishError::KMSOptInRequired(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::KMSOptInRequired(parsed_error.message),
+                            &res,
+                        )
                     }
                     "KMSThrottling" => {
-                        return RusotoError::Service(PublishError::KMSThrottling(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::KMSThrottling(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(PublishError::NotFound(parsed_error.message))
+                        return RusotoError::service(
+                            PublishError::NotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PlatformApplicationDisabled" => {
-                        return RusotoError::Service(PublishError::PlatformApplicationDisabled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PublishError::PlatformApplicationDisabled(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PublishError::Unhandled, &res),
                 }
             }
         }
@@ -4032,12 +4286,14 @@ impl fmt::Display for PublishError {
             PublishError::KMSThrottling(ref cause) => write!(f, "{}", cause),
             PublishError::NotFound(ref cause) => write!(f, "{}", cause),
             PublishError::PlatformApplicationDisabled(ref cause) => write!(f, "{}", cause),
+            PublishError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PublishError {}
 /// Errors returned by RemovePermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemovePermissionError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4047,6 +4303,8 @@ pub enum RemovePermissionError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemovePermissionError {
@@ -4058,26 +4316,30 @@ impl RemovePermissionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(RemovePermissionError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemovePermissionError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(RemovePermissionError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemovePermissionError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(RemovePermissionError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemovePermissionError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(RemovePermissionError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemovePermissionError::NotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RemovePermissionError::Unhandled, &res),
                 }
             }
         }
@@ -4100,12 +4362,14 @@ impl fmt::Display for RemovePermissionError {
             RemovePermissionError::InternalError(ref cause) => write!(f, "{}", cause),
             RemovePermissionError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RemovePermissionError::NotFound(ref cause) => write!(f, "{}", cause),
+            RemovePermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemovePermissionError {}
 /// Errors returned by SetEndpointAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetEndpointAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4115,6 +4379,8 @@ pub enum SetEndpointAttributesError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetEndpointAttributesError {
@@ -4126,26 +4392,32 @@ impl SetEndpointAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetEndpointAttributesError::AuthorizationError(parsed_error.message),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetEndpointAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetEndpointAttributesError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SetEndpointAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetEndpointAttributesError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SetEndpointAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetEndpointAttributesError::NotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(SetEndpointAttributesError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -4168,12 +4440,14 @@ impl fmt::Display for SetEndpointAttributesError {
             SetEndpointAttributesError::InternalError(ref cause) => write!(f, "{}", cause),
             SetEndpointAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             SetEndpointAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
+            SetEndpointAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetEndpointAttributesError {}
 /// Errors returned by SetPlatformApplicationAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetPlatformApplicationAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4183,6 +4457,8 @@ pub enum SetPlatformApplicationAttributesError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetPlatformApplicationAttributesError {
@@ -4196,32 +4472,41 @@ impl SetPlatformApplicationAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetPlatformApplicationAttributesError::AuthorizationError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetPlatformApplicationAttributesError::InternalError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetPlatformApplicationAttributesError::InvalidParameter(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetPlatformApplicationAttributesError::NotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            SetPlatformApplicationAttributesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4250,12 +4535,16 @@ impl fmt::Display for SetPlatformApplicationAttributesError {
                 write!(f, "{}", cause)
             }
             SetPlatformApplicationAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
+            SetPlatformApplicationAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for SetPlatformApplicationAttributesError {}
 /// Errors returned by SetSMSAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetSMSAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4265,6 +4554,8 @@ pub enum SetSMSAttributesError {
     InvalidParameter(String),
     /// <p>Indicates that the rate at which requests have been submitted for this action exceeds the limit for your account.</p>
     Throttled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetSMSAttributesError {
@@ -4276,26 +4567,30 @@ impl SetSMSAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(SetSMSAttributesError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSMSAttributesError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetSMSAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSMSAttributesError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SetSMSAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSMSAttributesError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(SetSMSAttributesError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSMSAttributesError::Throttled(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetSMSAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -4318,12 +4613,14 @@ impl fmt::Display for SetSMSAttributesError {
             SetSMSAttributesError::InternalError(ref cause) => write!(f, "{}", cause),
             SetSMSAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             SetSMSAttributesError::Throttled(ref cause) => write!(f, "{}", cause),
+            SetSMSAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetSMSAttributesError {}
 /// Errors returned by SetSubscriptionAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetSubscriptionAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4335,6 +4632,8 @@ pub enum SetSubscriptionAttributesError {
     InvalidParameter(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetSubscriptionAttributesError {
@@ -4346,35 +4645,45 @@ impl SetSubscriptionAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetSubscriptionAttributesError::AuthorizationError(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "FilterPolicyLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetSubscriptionAttributesError::FilterPolicyLimitExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetSubscriptionAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSubscriptionAttributesError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetSubscriptionAttributesError::InvalidParameter(parsed_error.message),
+                            &res,
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SetSubscriptionAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSubscriptionAttributesError::NotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            SetSubscriptionAttributesError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4400,12 +4709,14 @@ impl fmt::Display for SetSubscriptionAttributesError {
             SetSubscriptionAttributesError::InternalError(ref cause) => write!(f, "{}", cause),
             SetSubscriptionAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             SetSubscriptionAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
+            SetSubscriptionAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetSubscriptionAttributesError {}
 /// Errors returned by SetTopicAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetTopicAttributesError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4417,6 +4728,8 @@ pub enum SetTopicAttributesError {
     InvalidSecurity(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetTopicAttributesError {
@@ -4428,31 +4741,36 @@ impl SetTopicAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(SetTopicAttributesError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetTopicAttributesError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetTopicAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetTopicAttributesError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SetTopicAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetTopicAttributesError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(SetTopicAttributesError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetTopicAttributesError::InvalidSecurity(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SetTopicAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetTopicAttributesError::NotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetTopicAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -4476,12 +4794,14 @@ impl fmt::Display for SetTopicAttributesError {
             SetTopicAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             SetTopicAttributesError::InvalidSecurity(ref cause) => write!(f, "{}", cause),
             SetTopicAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
+            SetTopicAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetTopicAttributesError {}
 /// Errors returned by Subscribe
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubscribeError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4497,6 +4817,8 @@ pub enum SubscribeError {
     NotFound(String),
     /// <p>Indicates that the customer already owns the maximum allowed number of subscriptions.</p>
     SubscriptionLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubscribeError {
@@ -4508,39 +4830,48 @@ impl SubscribeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(SubscribeError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SubscribeError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "FilterPolicyLimitExceeded" => {
-                        return RusotoError::Service(SubscribeError::FilterPolicyLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SubscribeError::FilterPolicyLimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SubscribeError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SubscribeError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SubscribeError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SubscribeError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(SubscribeError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SubscribeError::InvalidSecurity(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SubscribeError::NotFound(parsed_error.message))
+                        return RusotoError::service(
+                            SubscribeError::NotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SubscriptionLimitExceeded" => {
-                        return RusotoError::Service(SubscribeError::SubscriptionLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SubscribeError::SubscriptionLimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SubscribeError::Unhandled, &res),
                 }
             }
         }
@@ -4566,12 +4897,14 @@ impl fmt::Display for SubscribeError {
             SubscribeError::InvalidSecurity(ref cause) => write!(f, "{}", cause),
             SubscribeError::NotFound(ref cause) => write!(f, "{}", cause),
             SubscribeError::SubscriptionLimitExceeded(ref cause) => write!(f, "{}", cause),
+            SubscribeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubscribeError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4587,6 +4920,8 @@ pub enum TagResourceError {
     TagLimitExceeded(String),
     /// <p>The request doesn't comply with the IAM tag policy. Correct your request and then retry it.</p>
     TagPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4598,41 +4933,48 @@ impl TagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(TagResourceError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(TagResourceError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::ConcurrentAccess(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(TagResourceError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(TagResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(TagResourceError::StaleTag(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::StaleTag(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagLimitExceeded" => {
-                        return RusotoError::Service(TagResourceError::TagLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::TagLimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(TagResourceError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::TagPolicy(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
                 }
             }
         }
@@ -4658,12 +5000,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::StaleTag(ref cause) => write!(f, "{}", cause),
             TagResourceError::TagLimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::TagPolicy(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by Unsubscribe
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnsubscribeError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4675,6 +5019,8 @@ pub enum UnsubscribeError {
     InvalidSecurity(String),
     /// <p>Indicates that the requested resource does not exist.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnsubscribeError {
@@ -4686,31 +5032,36 @@ impl UnsubscribeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(UnsubscribeError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UnsubscribeError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(UnsubscribeError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UnsubscribeError::InternalError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(UnsubscribeError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UnsubscribeError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(UnsubscribeError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UnsubscribeError::InvalidSecurity(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(UnsubscribeError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UnsubscribeError::NotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UnsubscribeError::Unhandled, &res),
                 }
             }
         }
@@ -4734,12 +5085,14 @@ impl fmt::Display for UnsubscribeError {
             UnsubscribeError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UnsubscribeError::InvalidSecurity(ref cause) => write!(f, "{}", cause),
             UnsubscribeError::NotFound(ref cause) => write!(f, "{}", cause),
+            UnsubscribeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnsubscribeError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Indicates that the user has been denied access to the requested resource.</p>
     AuthorizationError(String),
@@ -4755,6 +5108,8 @@ pub enum UntagResourceError {
     TagLimitExceeded(String),
     /// <p>The request doesn't comply with the IAM tag policy. Correct your request and then retry it.</p>
     TagPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4766,41 +5121,48 @@ impl UntagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(UntagResourceError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::AuthorizationError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(UntagResourceError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::ConcurrentAccess(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(UntagResourceError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::InvalidParameter(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(UntagResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(UntagResourceError::StaleTag(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::StaleTag(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagLimitExceeded" => {
-                        return RusotoError::Service(UntagResourceError::TagLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::TagLimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(UntagResourceError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::TagPolicy(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
                 }
             }
         }
@@ -4826,6 +5188,7 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::StaleTag(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TagLimitExceeded(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TagPolicy(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -5048,6 +5411,7 @@ impl SnsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SnsClient {
         SnsClient {
             client: Client::shared(),
@@ -5073,64 +5437,101 @@ impl SnsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SnsClient {
         SnsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SnsClient> {
+        rusoto_core::ClientBuilder::new(SnsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SnsClient {
+        SnsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Sns for SnsClient {
-    /// <p>Adds a statement to a topic's access control policy, granting access for the specified AWS accounts to the specified actions.</p>
-    async fn add_permission(
+impl SnsClient {
+    /// Calls `add_permission`, also returning the metadata of the response.
+    pub async fn add_permission_with_metadata(
         &self,
         input: AddPermissionInput,
-    ) -> Result<(), RusotoError<AddPermissionError>> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddPermission");
-        params.put("Version", "2010-03-31");
-        AddPermissionInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<((), ResponseMetadata), RusotoError<AddPermissionError>> {
+        let request = self.build_add_permission_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddPermissionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Accepts a phone number and indicates whether the phone holder has opted out of receiving SMS messages from your account. You cannot send SMS messages to a number that is opted out.</p> <p>To resume sending messages, you can opt in the number by using the <code>OptInPhoneNumber</code> action.</p>
-    async fn check_if_phone_number_is_opted_out(
+    /// Presigns the request `add_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_permission(
         &self,
-        input: CheckIfPhoneNumberIsOptedOutInput,
-    ) -> Result<CheckIfPhoneNumberIsOptedOutResponse, RusotoError<CheckIfPhoneNumberIsOptedOutError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: AddPermissionInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_permission_request(&self, input: AddPermissionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("AddPermission");
         let mut params = Params::new();
 
-        params.put("Action", "CheckIfPhoneNumberIsOptedOut");
+        params.put("Action", "AddPermission");
         params.put("Version", "2010-03-31");
-        CheckIfPhoneNumberIsOptedOutInputSerializer::serialize(&mut params, "", &input);
+        AddPermissionInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `check_if_phone_number_is_opted_out`, also returning the metadata of the response.
+    pub async fn check_if_phone_number_is_opted_out_with_metadata(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> Result<
+        (CheckIfPhoneNumberIsOptedOutResponse, ResponseMetadata),
+        RusotoError<CheckIfPhoneNumberIsOptedOutError>,
+    > {
+        let request = self.build_check_if_phone_number_is_opted_out_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CheckIfPhoneNumberIsOptedOutError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5152,34 +5553,64 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Verifies an endpoint owner's intent to receive messages by validating the token sent to the endpoint by an earlier <code>Subscribe</code> action. If the token is valid, the action creates a new subscription and returns its Amazon Resource Name (ARN). This call requires an AWS signature only when the <code>AuthenticateOnUnsubscribe</code> flag is set to "true".</p>
-    async fn confirm_subscription(
+    /// Presigns the request `check_if_phone_number_is_opted_out` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_check_if_phone_number_is_opted_out(
         &self,
-        input: ConfirmSubscriptionInput,
-    ) -> Result<ConfirmSubscriptionResponse, RusotoError<ConfirmSubscriptionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_check_if_phone_number_is_opted_out_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_check_if_phone_number_is_opted_out_request(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CheckIfPhoneNumberIsOptedOut");
         let mut params = Params::new();
 
-        params.put("Action", "ConfirmSubscription");
+        params.put("Action", "CheckIfPhoneNumberIsOptedOut");
         params.put("Version", "2010-03-31");
-        ConfirmSubscriptionInputSerializer::serialize(&mut params, "", &input);
+        CheckIfPhoneNumberIsOptedOutInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `confirm_subscription`, also returning the metadata of the response.
+    pub async fn confirm_subscription_with_metadata(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> Result<
+        (ConfirmSubscriptionResponse, ResponseMetadata),
+        RusotoError<ConfirmSubscriptionError>,
+    > {
+        let request = self.build_confirm_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ConfirmSubscriptionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5201,35 +5632,61 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a platform application object for one of the supported push notification services, such as APNS and FCM, to which devices and mobile apps may register. You must specify PlatformPrincipal and PlatformCredential attributes when using the <code>CreatePlatformApplication</code> action. The PlatformPrincipal is received from the notification service. For APNS/APNS_SANDBOX, PlatformPrincipal is "SSL certificate". For FCM, PlatformPrincipal is not applicable. For ADM, PlatformPrincipal is "client id". The PlatformCredential is also received from the notification service. For WNS, PlatformPrincipal is "Package Security Identifier". For MPNS, PlatformPrincipal is "TLS certificate". For Baidu, PlatformPrincipal is "API key".</p> <p>For APNS/APNS_SANDBOX, PlatformCredential is "private key". For FCM, PlatformCredential is "API key". For ADM, PlatformCredential is "client secret". For WNS, PlatformCredential is "secret key". For MPNS, PlatformCredential is "private key". For Baidu, PlatformCredential is "secret key". The PlatformApplicationArn that is returned when using <code>CreatePlatformApplication</code> is then used as an attribute for the <code>CreatePlatformEndpoint</code> action.</p>
-    async fn create_platform_application(
+    /// Presigns the request `confirm_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_confirm_subscription(
         &self,
-        input: CreatePlatformApplicationInput,
-    ) -> Result<CreatePlatformApplicationResponse, RusotoError<CreatePlatformApplicationError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ConfirmSubscriptionInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_confirm_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_confirm_subscription_request(&self, input: ConfirmSubscriptionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ConfirmSubscription");
         let mut params = Params::new();
 
-        params.put("Action", "CreatePlatformApplication");
+        params.put("Action", "ConfirmSubscription");
         params.put("Version", "2010-03-31");
-        CreatePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
+        ConfirmSubscriptionInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_platform_application`, also returning the metadata of the response.
+    pub async fn create_platform_application_with_metadata(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> Result<
+        (CreatePlatformApplicationResponse, ResponseMetadata),
+        RusotoError<CreatePlatformApplicationError>,
+    > {
+        let request = self.build_create_platform_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreatePlatformApplicationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5251,34 +5708,62 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an endpoint for a device and mobile app on one of the supported push notification services, such as FCM and APNS. <code>CreatePlatformEndpoint</code> requires the PlatformApplicationArn that is returned from <code>CreatePlatformApplication</code>. The EndpointArn that is returned when using <code>CreatePlatformEndpoint</code> can then be used by the <code>Publish</code> action to send a message to a mobile app or by the <code>Subscribe</code> action for subscription to a topic. The <code>CreatePlatformEndpoint</code> action is idempotent, so if the requester already owns an endpoint with the same device token and attributes, that endpoint's ARN is returned without creating a new endpoint. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When using <code>CreatePlatformEndpoint</code> with Baidu, two attributes must be provided: ChannelId and UserId. The token field must also contain the ChannelId. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePushBaiduEndpoint.html">Creating an Amazon SNS Endpoint for Baidu</a>. </p>
-    async fn create_platform_endpoint(
+    /// Presigns the request `create_platform_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_platform_application(
         &self,
-        input: CreatePlatformEndpointInput,
-    ) -> Result<CreateEndpointResponse, RusotoError<CreatePlatformEndpointError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreatePlatformApplicationInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_platform_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_platform_application_request(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreatePlatformApplication");
         let mut params = Params::new();
 
-        params.put("Action", "CreatePlatformEndpoint");
+        params.put("Action", "CreatePlatformApplication");
         params.put("Version", "2010-03-31");
-        CreatePlatformEndpointInputSerializer::serialize(&mut params, "", &input);
+        CreatePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_platform_endpoint`, also returning the metadata of the response.
+    pub async fn create_platform_endpoint_with_metadata(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> Result<(CreateEndpointResponse, ResponseMetadata), RusotoError<CreatePlatformEndpointError>>
+    {
+        let request = self.build_create_platform_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreatePlatformEndpointError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5300,34 +5785,61 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a topic to which notifications can be published. Users can create at most 100,000 topics. For more information, see <a href="http://aws.amazon.com/sns/">https://aws.amazon.com/sns</a>. This action is idempotent, so if the requester already owns a topic with the specified name, that topic's ARN is returned without creating a new topic.</p>
-    async fn create_topic(
+    /// Presigns the request `create_platform_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_platform_endpoint(
         &self,
-        input: CreateTopicInput,
-    ) -> Result<CreateTopicResponse, RusotoError<CreateTopicError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreatePlatformEndpointInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_platform_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_platform_endpoint_request(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreatePlatformEndpoint");
         let mut params = Params::new();
 
-        params.put("Action", "CreateTopic");
+        params.put("Action", "CreatePlatformEndpoint");
         params.put("Version", "2010-03-31");
-        CreateTopicInputSerializer::serialize(&mut params, "", &input);
+        CreatePlatformEndpointInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_topic`, also returning the metadata of the response.
+    pub async fn create_topic_with_metadata(
+        &self,
+        input: CreateTopicInput,
+    ) -> Result<(CreateTopicResponse, ResponseMetadata), RusotoError<CreateTopicError>> {
+        let request = self.build_create_topic_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateTopicError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5346,118 +5858,211 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the endpoint for a device and mobile app from Amazon SNS. This action is idempotent. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When you delete an endpoint that is also subscribed to a topic, then you must also unsubscribe the endpoint from the topic.</p>
-    async fn delete_endpoint(
+    /// Presigns the request `create_topic` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_topic(
         &self,
-        input: DeleteEndpointInput,
-    ) -> Result<(), RusotoError<DeleteEndpointError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateTopicInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_topic_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_topic_request(&self, input: CreateTopicInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreateTopic");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteEndpoint");
+        params.put("Action", "CreateTopic");
         params.put("Version", "2010-03-31");
-        DeleteEndpointInputSerializer::serialize(&mut params, "", &input);
+        CreateTopicInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_endpoint`, also returning the metadata of the response.
+    pub async fn delete_endpoint_with_metadata(
+        &self,
+        input: DeleteEndpointInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEndpointError>> {
+        let request = self.build_delete_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteEndpointError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes a platform application object for one of the supported push notification services, such as APNS and FCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
-    async fn delete_platform_application(
+    /// Presigns the request `delete_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_endpoint(
         &self,
-        input: DeletePlatformApplicationInput,
-    ) -> Result<(), RusotoError<DeletePlatformApplicationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteEndpointInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_endpoint_request(&self, input: DeleteEndpointInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
         let mut params = Params::new();
 
-        params.put("Action", "DeletePlatformApplication");
+        params.put("Action", "DeleteEndpoint");
         params.put("Version", "2010-03-31");
-        DeletePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
+        DeleteEndpointInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_platform_application`, also returning the metadata of the response.
+    pub async fn delete_platform_application_with_metadata(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePlatformApplicationError>> {
+        let request = self.build_delete_platform_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeletePlatformApplicationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes a topic and all its subscriptions. Deleting a topic might prevent some messages previously sent to the topic from being delivered to subscribers. This action is idempotent, so deleting a topic that does not exist does not result in an error.</p>
-    async fn delete_topic(
+    /// Presigns the request `delete_platform_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_platform_application(
         &self,
-        input: DeleteTopicInput,
-    ) -> Result<(), RusotoError<DeleteTopicError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeletePlatformApplicationInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_platform_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_platform_application_request(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeletePlatformApplication");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteTopic");
+        params.put("Action", "DeletePlatformApplication");
         params.put("Version", "2010-03-31");
-        DeleteTopicInputSerializer::serialize(&mut params, "", &input);
+        DeletePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_topic`, also returning the metadata of the response.
+    pub async fn delete_topic_with_metadata(
+        &self,
+        input: DeleteTopicInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteTopicError>> {
+        let request = self.build_delete_topic_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteTopicError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Retrieves the endpoint attributes for a device on one of the supported push notification services, such as FCM and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
-    async fn get_endpoint_attributes(
+    /// Presigns the request `delete_topic` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_topic(
         &self,
-        input: GetEndpointAttributesInput,
-    ) -> Result<GetEndpointAttributesResponse, RusotoError<GetEndpointAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteTopicInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_topic_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_topic_request(&self, input: DeleteTopicInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeleteTopic");
         let mut params = Params::new();
 
-        params.put("Action", "GetEndpointAttributes");
+        params.put("Action", "DeleteTopic");
         params.put("Version", "2010-03-31");
-        GetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
+        DeleteTopicInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_endpoint_attributes`, also returning the metadata of the response.
+    pub async fn get_endpoint_attributes_with_metadata(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> Result<
+        (GetEndpointAttributesResponse, ResponseMetadata),
+        RusotoError<GetEndpointAttributesError>,
+    > {
+        let request = self.build_get_endpoint_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetEndpointAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5479,39 +6084,66 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieves the attributes of the platform application object for the supported push notification services, such as APNS and FCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
-    async fn get_platform_application_attributes(
+    /// Presigns the request `get_endpoint_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_endpoint_attributes(
         &self,
-        input: GetPlatformApplicationAttributesInput,
-    ) -> Result<
-        GetPlatformApplicationAttributesResponse,
-        RusotoError<GetPlatformApplicationAttributesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: GetEndpointAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_endpoint_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_endpoint_attributes_request(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetEndpointAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "GetPlatformApplicationAttributes");
+        params.put("Action", "GetEndpointAttributes");
         params.put("Version", "2010-03-31");
-        GetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
+        GetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_platform_application_attributes`, also returning the metadata of the response.
+    pub async fn get_platform_application_attributes_with_metadata(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> Result<
+        (GetPlatformApplicationAttributesResponse, ResponseMetadata),
+        RusotoError<GetPlatformApplicationAttributesError>,
+    > {
+        let request = self.build_get_platform_application_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetPlatformApplicationAttributesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5533,34 +6165,62 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the settings for sending SMS messages from your account.</p> <p>These settings are set with the <code>SetSMSAttributes</code> action.</p>
-    async fn get_sms_attributes(
+    /// Presigns the request `get_platform_application_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_platform_application_attributes(
         &self,
-        input: GetSMSAttributesInput,
-    ) -> Result<GetSMSAttributesResponse, RusotoError<GetSMSAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_platform_application_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_platform_application_attributes_request(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetPlatformApplicationAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "GetSMSAttributes");
+        params.put("Action", "GetPlatformApplicationAttributes");
         params.put("Version", "2010-03-31");
-        GetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
+        GetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_sms_attributes`, also returning the metadata of the response.
+    pub async fn get_sms_attributes_with_metadata(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> Result<(GetSMSAttributesResponse, ResponseMetadata), RusotoError<GetSMSAttributesError>>
+    {
+        let request = self.build_get_sms_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetSMSAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5582,35 +6242,61 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns all of the properties of a subscription.</p>
-    async fn get_subscription_attributes(
+    /// Presigns the request `get_sms_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_sms_attributes(
         &self,
-        input: GetSubscriptionAttributesInput,
-    ) -> Result<GetSubscriptionAttributesResponse, RusotoError<GetSubscriptionAttributesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: GetSMSAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_sms_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_sms_attributes_request(&self, input: GetSMSAttributesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetSMSAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "GetSubscriptionAttributes");
+        params.put("Action", "GetSMSAttributes");
         params.put("Version", "2010-03-31");
-        GetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
+        GetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_subscription_attributes`, also returning the metadata of the response.
+    pub async fn get_subscription_attributes_with_metadata(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> Result<
+        (GetSubscriptionAttributesResponse, ResponseMetadata),
+        RusotoError<GetSubscriptionAttributesError>,
+    > {
+        let request = self.build_get_subscription_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetSubscriptionAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5632,34 +6318,62 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns all of the properties of a topic. Topic properties returned might differ based on the authorization of the user.</p>
-    async fn get_topic_attributes(
+    /// Presigns the request `get_subscription_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_subscription_attributes(
         &self,
-        input: GetTopicAttributesInput,
-    ) -> Result<GetTopicAttributesResponse, RusotoError<GetTopicAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetSubscriptionAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_subscription_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_subscription_attributes_request(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetSubscriptionAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "GetTopicAttributes");
+        params.put("Action", "GetSubscriptionAttributes");
         params.put("Version", "2010-03-31");
-        GetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
+        GetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_topic_attributes`, also returning the metadata of the response.
+    pub async fn get_topic_attributes_with_metadata(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> Result<(GetTopicAttributesResponse, ResponseMetadata), RusotoError<GetTopicAttributesError>>
+    {
+        let request = self.build_get_topic_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetTopicAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5681,39 +6395,63 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists the endpoints and endpoint attributes for devices in a supported push notification service, such as FCM and APNS. The results for <code>ListEndpointsByPlatformApplication</code> are paginated and return a limited list of endpoints, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListEndpointsByPlatformApplication</code> again using the NextToken string received from the previous call. When there are no more records to return, NextToken will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 30 transactions per second (TPS).</p>
-    async fn list_endpoints_by_platform_application(
+    /// Presigns the request `get_topic_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_topic_attributes(
         &self,
-        input: ListEndpointsByPlatformApplicationInput,
-    ) -> Result<
-        ListEndpointsByPlatformApplicationResponse,
-        RusotoError<ListEndpointsByPlatformApplicationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: GetTopicAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_topic_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_topic_attributes_request(&self, input: GetTopicAttributesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetTopicAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "ListEndpointsByPlatformApplication");
+        params.put("Action", "GetTopicAttributes");
         params.put("Version", "2010-03-31");
-        ListEndpointsByPlatformApplicationInputSerializer::serialize(&mut params, "", &input);
+        GetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_endpoints_by_platform_application`, also returning the metadata of the response.
+    pub async fn list_endpoints_by_platform_application_with_metadata(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> Result<
+        (ListEndpointsByPlatformApplicationResponse, ResponseMetadata),
+        RusotoError<ListEndpointsByPlatformApplicationError>,
+    > {
+        let request = self.build_list_endpoints_by_platform_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListEndpointsByPlatformApplicationError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5735,34 +6473,64 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of phone numbers that are opted out, meaning you cannot send SMS messages to them.</p> <p>The results for <code>ListPhoneNumbersOptedOut</code> are paginated, and each page returns up to 100 phone numbers. If additional phone numbers are available after the first page of results, then a <code>NextToken</code> string will be returned. To receive the next page, you call <code>ListPhoneNumbersOptedOut</code> again using the <code>NextToken</code> string received from the previous call. When there are no more records to return, <code>NextToken</code> will be null.</p>
-    async fn list_phone_numbers_opted_out(
+    /// Presigns the request `list_endpoints_by_platform_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_endpoints_by_platform_application(
         &self,
-        input: ListPhoneNumbersOptedOutInput,
-    ) -> Result<ListPhoneNumbersOptedOutResponse, RusotoError<ListPhoneNumbersOptedOutError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_endpoints_by_platform_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_endpoints_by_platform_application_request(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListEndpointsByPlatformApplication");
         let mut params = Params::new();
 
-        params.put("Action", "ListPhoneNumbersOptedOut");
+        params.put("Action", "ListEndpointsByPlatformApplication");
         params.put("Version", "2010-03-31");
-        ListPhoneNumbersOptedOutInputSerializer::serialize(&mut params, "", &input);
+        ListEndpointsByPlatformApplicationInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_phone_numbers_opted_out`, also returning the metadata of the response.
+    pub async fn list_phone_numbers_opted_out_with_metadata(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> Result<
+        (ListPhoneNumbersOptedOutResponse, ResponseMetadata),
+        RusotoError<ListPhoneNumbersOptedOutError>,
+    > {
+        let request = self.build_list_phone_numbers_opted_out_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListPhoneNumbersOptedOutError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5784,34 +6552,64 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists the platform application objects for the supported push notification services, such as APNS and FCM. The results for <code>ListPlatformApplications</code> are paginated and return a limited list of applications, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListPlatformApplications</code> using the NextToken string received from the previous call. When there are no more records to return, NextToken will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 15 transactions per second (TPS).</p>
-    async fn list_platform_applications(
+    /// Presigns the request `list_phone_numbers_opted_out` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_phone_numbers_opted_out(
         &self,
-        input: ListPlatformApplicationsInput,
-    ) -> Result<ListPlatformApplicationsResponse, RusotoError<ListPlatformApplicationsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_phone_numbers_opted_out_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_phone_numbers_opted_out_request(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListPhoneNumbersOptedOut");
         let mut params = Params::new();
 
-        params.put("Action", "ListPlatformApplications");
+        params.put("Action", "ListPhoneNumbersOptedOut");
         params.put("Version", "2010-03-31");
-        ListPlatformApplicationsInputSerializer::serialize(&mut params, "", &input);
+        ListPhoneNumbersOptedOutInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_platform_applications`, also returning the metadata of the response.
+    pub async fn list_platform_applications_with_metadata(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> Result<
+        (ListPlatformApplicationsResponse, ResponseMetadata),
+        RusotoError<ListPlatformApplicationsError>,
+    > {
+        let request = self.build_list_platform_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListPlatformApplicationsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5833,34 +6631,62 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of the requester's subscriptions. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptions</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
-    async fn list_subscriptions(
+    /// Presigns the request `list_platform_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_platform_applications(
         &self,
-        input: ListSubscriptionsInput,
-    ) -> Result<ListSubscriptionsResponse, RusotoError<ListSubscriptionsError>> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListSubscriptions");
+        presigner: &rusoto_core::Presigner,
+        input: ListPlatformApplicationsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_platform_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_platform_applications_request(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListPlatformApplications");
+        let mut params = Params::new();
+
+        params.put("Action", "ListPlatformApplications");
         params.put("Version", "2010-03-31");
-        ListSubscriptionsInputSerializer::serialize(&mut params, "", &input);
+        ListPlatformApplicationsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_subscriptions`, also returning the metadata of the response.
+    pub async fn list_subscriptions_with_metadata(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> Result<(ListSubscriptionsResponse, ResponseMetadata), RusotoError<ListSubscriptionsError>>
+    {
+        let request = self.build_list_subscriptions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListSubscriptionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5882,34 +6708,61 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of the subscriptions to a specific topic. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptionsByTopic</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
-    async fn list_subscriptions_by_topic(
+    /// Presigns the request `list_subscriptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_subscriptions(
         &self,
-        input: ListSubscriptionsByTopicInput,
-    ) -> Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListSubscriptionsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_subscriptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_subscriptions_request(&self, input: ListSubscriptionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListSubscriptions");
         let mut params = Params::new();
 
-        params.put("Action", "ListSubscriptionsByTopic");
+        params.put("Action", "ListSubscriptions");
         params.put("Version", "2010-03-31");
-        ListSubscriptionsByTopicInputSerializer::serialize(&mut params, "", &input);
+        ListSubscriptionsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_subscriptions_by_topic`, also returning the metadata of the response.
+    pub async fn list_subscriptions_by_topic_with_metadata(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> Result<
+        (ListSubscriptionsByTopicResponse, ResponseMetadata),
+        RusotoError<ListSubscriptionsByTopicError>,
+    > {
+        let request = self.build_list_subscriptions_by_topic_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListSubscriptionsByTopicError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5931,34 +6784,64 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>List all tags added to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon Simple Notification Service Developer Guide</i>.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_subscriptions_by_topic` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_subscriptions_by_topic(
         &self,
-        input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListSubscriptionsByTopicInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_subscriptions_by_topic_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_subscriptions_by_topic_request(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListSubscriptionsByTopic");
         let mut params = Params::new();
 
-        params.put("Action", "ListTagsForResource");
+        params.put("Action", "ListSubscriptionsByTopic");
         params.put("Version", "2010-03-31");
-        ListTagsForResourceRequestSerializer::serialize(&mut params, "", &input);
+        ListSubscriptionsByTopicInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTagsForResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -5980,34 +6863,61 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of the requester's topics. Each call returns a limited list of topics, up to 100. If there are more topics, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListTopics</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
-    async fn list_topics(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
-        input: ListTopicsInput,
-    ) -> Result<ListTopicsResponse, RusotoError<ListTopicsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
-        params.put("Action", "ListTopics");
+        params.put("Action", "ListTagsForResource");
         params.put("Version", "2010-03-31");
-        ListTopicsInputSerializer::serialize(&mut params, "", &input);
+        ListTagsForResourceRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_topics`, also returning the metadata of the response.
+    pub async fn list_topics_with_metadata(
+        &self,
+        input: ListTopicsInput,
+    ) -> Result<(ListTopicsResponse, ResponseMetadata), RusotoError<ListTopicsError>> {
+        let request = self.build_list_topics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTopicsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6026,34 +6936,59 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Use this request to opt in a phone number that is opted out, which enables you to resume sending SMS messages to the number.</p> <p>You can opt in a phone number only once every 30 days.</p>
-    async fn opt_in_phone_number(
+    /// Presigns the request `list_topics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_topics(
         &self,
-        input: OptInPhoneNumberInput,
-    ) -> Result<OptInPhoneNumberResponse, RusotoError<OptInPhoneNumberError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListTopicsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_topics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_topics_request(&self, input: ListTopicsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListTopics");
         let mut params = Params::new();
 
-        params.put("Action", "OptInPhoneNumber");
+        params.put("Action", "ListTopics");
         params.put("Version", "2010-03-31");
-        OptInPhoneNumberInputSerializer::serialize(&mut params, "", &input);
+        ListTopicsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `opt_in_phone_number`, also returning the metadata of the response.
+    pub async fn opt_in_phone_number_with_metadata(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> Result<(OptInPhoneNumberResponse, ResponseMetadata), RusotoError<OptInPhoneNumberError>>
+    {
+        let request = self.build_opt_in_phone_number_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(OptInPhoneNumberError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6075,34 +7010,58 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sends a message to an Amazon SNS topic or sends a text message (SMS message) directly to a phone number. </p> <p>If you send a message to a topic, Amazon SNS delivers the message to each endpoint that is subscribed to the topic. The format of the message depends on the notification protocol for each subscribed endpoint.</p> <p>When a <code>messageId</code> is returned, the message has been saved and Amazon SNS will attempt to deliver it shortly.</p> <p>To use the <code>Publish</code> action for sending a message to a mobile endpoint, such as an app on a Kindle device or mobile phone, you must specify the EndpointArn for the TargetArn parameter. The EndpointArn is returned when making a call with the <code>CreatePlatformEndpoint</code> action. </p> <p>For more information about formatting messages, see <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-send-custommessage.html">Send Custom Platform-Specific Payloads in Messages to Mobile Devices</a>. </p>
-    async fn publish(
+    /// Presigns the request `opt_in_phone_number` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_opt_in_phone_number(
         &self,
-        input: PublishInput,
-    ) -> Result<PublishResponse, RusotoError<PublishError>> {
+        presigner: &rusoto_core::Presigner,
+        input: OptInPhoneNumberInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_opt_in_phone_number_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_opt_in_phone_number_request(&self, input: OptInPhoneNumberInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("OptInPhoneNumber");
         let mut params = Params::new();
 
-        params.put("Action", "Publish");
+        params.put("Action", "OptInPhoneNumber");
         params.put("Version", "2010-03-31");
-        PublishInputSerializer::serialize(&mut params, "", &input);
+        OptInPhoneNumberInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `publish`, also returning the metadata of the response.
+    pub async fn publish_with_metadata(
+        &self,
+        input: PublishInput,
+    ) -> Result<(PublishResponse, ResponseMetadata), RusotoError<PublishError>> {
+        let request = self.build_publish_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PublishError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6121,120 +7080,214 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes a statement from a topic's access control policy.</p>
-    async fn remove_permission(
+    /// Presigns the request `publish` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_publish(
         &self,
-        input: RemovePermissionInput,
-    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PublishInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_publish_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_publish_request(&self, input: PublishInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Publish");
         let mut params = Params::new();
 
-        params.put("Action", "RemovePermission");
+        params.put("Action", "Publish");
         params.put("Version", "2010-03-31");
-        RemovePermissionInputSerializer::serialize(&mut params, "", &input);
+        PublishInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_permission`, also returning the metadata of the response.
+    pub async fn remove_permission_with_metadata(
+        &self,
+        input: RemovePermissionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemovePermissionError>> {
+        let request = self.build_remove_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemovePermissionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Sets the attributes for an endpoint for a device on one of the supported push notification services, such as FCM and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
-    async fn set_endpoint_attributes(
+    /// Presigns the request `remove_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_permission(
         &self,
-        input: SetEndpointAttributesInput,
-    ) -> Result<(), RusotoError<SetEndpointAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RemovePermissionInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_permission_request(&self, input: RemovePermissionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("RemovePermission");
         let mut params = Params::new();
 
-        params.put("Action", "SetEndpointAttributes");
+        params.put("Action", "RemovePermission");
         params.put("Version", "2010-03-31");
-        SetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
+        RemovePermissionInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_endpoint_attributes`, also returning the metadata of the response.
+    pub async fn set_endpoint_attributes_with_metadata(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetEndpointAttributesError>> {
+        let request = self.build_set_endpoint_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetEndpointAttributesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Sets the attributes of the platform application object for the supported push notification services, such as APNS and FCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. For information on configuring attributes for message delivery status, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-msg-status.html">Using Amazon SNS Application Attributes for Message Delivery Status</a>. </p>
-    async fn set_platform_application_attributes(
+    /// Presigns the request `set_endpoint_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_endpoint_attributes(
         &self,
-        input: SetPlatformApplicationAttributesInput,
-    ) -> Result<(), RusotoError<SetPlatformApplicationAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetEndpointAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_endpoint_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_endpoint_attributes_request(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetEndpointAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "SetPlatformApplicationAttributes");
+        params.put("Action", "SetEndpointAttributes");
         params.put("Version", "2010-03-31");
-        SetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
+        SetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_platform_application_attributes`, also returning the metadata of the response.
+    pub async fn set_platform_application_attributes_with_metadata(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetPlatformApplicationAttributesError>> {
+        let request = self.build_set_platform_application_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetPlatformApplicationAttributesError::from_response(
                 response,
             ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Use this request to set the default settings for sending SMS messages and receiving daily SMS usage reports.</p> <p>You can override some of these settings for a single message when you use the <code>Publish</code> action with the <code>MessageAttributes.entry.N</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sms_publish-to-phone.html">Sending an SMS Message</a> in the <i>Amazon SNS Developer Guide</i>.</p>
-    async fn set_sms_attributes(
+    /// Presigns the request `set_platform_application_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_platform_application_attributes(
         &self,
-        input: SetSMSAttributesInput,
-    ) -> Result<SetSMSAttributesResponse, RusotoError<SetSMSAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_platform_application_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_platform_application_attributes_request(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetPlatformApplicationAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "SetSMSAttributes");
+        params.put("Action", "SetPlatformApplicationAttributes");
         params.put("Version", "2010-03-31");
-        SetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
+        SetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_sms_attributes`, also returning the metadata of the response.
+    pub async fn set_sms_attributes_with_metadata(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> Result<(SetSMSAttributesResponse, ResponseMetadata), RusotoError<SetSMSAttributesError>>
+    {
+        let request = self.build_set_sms_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetSMSAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6256,90 +7309,159 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Allows a subscription owner to set an attribute of the subscription to a new value.</p>
-    async fn set_subscription_attributes(
+    /// Presigns the request `set_sms_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_sms_attributes(
         &self,
-        input: SetSubscriptionAttributesInput,
-    ) -> Result<(), RusotoError<SetSubscriptionAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetSMSAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_sms_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_sms_attributes_request(&self, input: SetSMSAttributesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetSMSAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "SetSubscriptionAttributes");
+        params.put("Action", "SetSMSAttributes");
         params.put("Version", "2010-03-31");
-        SetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
+        SetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_subscription_attributes`, also returning the metadata of the response.
+    pub async fn set_subscription_attributes_with_metadata(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetSubscriptionAttributesError>> {
+        let request = self.build_set_subscription_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetSubscriptionAttributesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Allows a topic owner to set an attribute of the topic to a new value.</p>
-    async fn set_topic_attributes(
+    /// Presigns the request `set_subscription_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_subscription_attributes(
         &self,
-        input: SetTopicAttributesInput,
-    ) -> Result<(), RusotoError<SetTopicAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetSubscriptionAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_subscription_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_subscription_attributes_request(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetSubscriptionAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "SetTopicAttributes");
+        params.put("Action", "SetSubscriptionAttributes");
         params.put("Version", "2010-03-31");
-        SetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
+        SetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_topic_attributes`, also returning the metadata of the response.
+    pub async fn set_topic_attributes_with_metadata(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetTopicAttributesError>> {
+        let request = self.build_set_topic_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetTopicAttributesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Prepares to subscribe an endpoint by sending the endpoint a confirmation message. To actually create a subscription, the endpoint owner must call the <code>ConfirmSubscription</code> action with the token from the confirmation message. Confirmation tokens are valid for three days.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
-    async fn subscribe(
+    /// Presigns the request `set_topic_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_topic_attributes(
         &self,
-        input: SubscribeInput,
-    ) -> Result<SubscribeResponse, RusotoError<SubscribeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetTopicAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_topic_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_topic_attributes_request(&self, input: SetTopicAttributesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetTopicAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "Subscribe");
+        params.put("Action", "SetTopicAttributes");
         params.put("Version", "2010-03-31");
-        SubscribeInputSerializer::serialize(&mut params, "", &input);
+        SetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `subscribe`, also returning the metadata of the response.
+    pub async fn subscribe_with_metadata(
+        &self,
+        input: SubscribeInput,
+    ) -> Result<(SubscribeResponse, ResponseMetadata), RusotoError<SubscribeError>> {
+        let request = self.build_subscribe_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SubscribeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6358,34 +7480,58 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Add tags to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p> <p>When you use topic tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a topic isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SNS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> <li> <p>Tagging actions are limited to 10 TPS per AWS account, per AWS region. If your application requires a higher throughput, file a <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=technical">technical support request</a>.</p> </li> </ul></p>
-    async fn tag_resource(
+    /// Presigns the request `subscribe` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_subscribe(
         &self,
-        input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SubscribeInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_subscribe_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_subscribe_request(&self, input: SubscribeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Subscribe");
         let mut params = Params::new();
 
-        params.put("Action", "TagResource");
+        params.put("Action", "Subscribe");
         params.put("Version", "2010-03-31");
-        TagResourceRequestSerializer::serialize(&mut params, "", &input);
+        SubscribeInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(TagResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6404,62 +7550,107 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a subscription. If the subscription requires authentication for deletion, only the owner of the subscription or the topic's owner can unsubscribe, and an AWS signature is required. If the <code>Unsubscribe</code> call does not require authentication and the requester is not the subscription owner, a final cancellation message is delivered to the endpoint, so that the endpoint owner can easily resubscribe to the topic if the <code>Unsubscribe</code> request was unintended.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
-    async fn unsubscribe(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
-        input: UnsubscribeInput,
-    ) -> Result<(), RusotoError<UnsubscribeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: TagResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("TagResource");
         let mut params = Params::new();
 
-        params.put("Action", "Unsubscribe");
+        params.put("Action", "TagResource");
         params.put("Version", "2010-03-31");
-        UnsubscribeInputSerializer::serialize(&mut params, "", &input);
+        TagResourceRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `unsubscribe`, also returning the metadata of the response.
+    pub async fn unsubscribe_with_metadata(
+        &self,
+        input: UnsubscribeInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UnsubscribeError>> {
+        let request = self.build_unsubscribe_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UnsubscribeError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Remove tags from the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p>
-    async fn untag_resource(
+    /// Presigns the request `unsubscribe` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unsubscribe(
         &self,
-        input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UnsubscribeInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unsubscribe_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unsubscribe_request(&self, input: UnsubscribeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Unsubscribe");
         let mut params = Params::new();
 
-        params.put("Action", "UntagResource");
+        params.put("Action", "Unsubscribe");
         params.put("Version", "2010-03-31");
-        UntagResourceRequestSerializer::serialize(&mut params, "", &input);
+        UnsubscribeInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UntagResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6479,7 +7670,596 @@ impl Sns for SnsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UntagResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("UntagResource");
+        let mut params = Params::new();
+
+        params.put("Action", "UntagResource");
+        params.put("Version", "2010-03-31");
+        UntagResourceRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `ListEndpointsByPlatformApplication` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_endpoints_by_platform_application_pages(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListEndpointsByPlatformApplicationResponse,
+            RusotoError<ListEndpointsByPlatformApplicationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input| async move {
+                client.list_endpoints_by_platform_application(input).await
+            },
+            |input: &ListEndpointsByPlatformApplicationInput,
+             output: &ListEndpointsByPlatformApplicationResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEndpointsByPlatformApplicationInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Endpoints` of every page of `ListEndpointsByPlatformApplication` results for `input`, as `list_endpoints_by_platform_application_pages` requests them.
+    pub fn list_endpoints_by_platform_application_stream(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> impl futures::Stream<
+        Item = Result<String, RusotoError<ListEndpointsByPlatformApplicationError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_endpoints_by_platform_application_pages(input),
+            |page| page.endpoints.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListPhoneNumbersOptedOut` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_phone_numbers_opted_out_pages(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListPhoneNumbersOptedOutResponse, RusotoError<ListPhoneNumbersOptedOutError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input| async move { client.list_phone_numbers_opted_out(input).await },
+            |input: &ListPhoneNumbersOptedOutInput, output: &ListPhoneNumbersOptedOutResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPhoneNumbersOptedOutInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `phoneNumbers` of every page of `ListPhoneNumbersOptedOut` results for `input`, as `list_phone_numbers_opted_out_pages` requests them.
+    pub fn list_phone_numbers_opted_out_stream(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListPhoneNumbersOptedOutError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_phone_numbers_opted_out_pages(input), |page| {
+            page.phone_numbers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPlatformApplications` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_platform_applications_pages(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListPlatformApplicationsResponse, RusotoError<ListPlatformApplicationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input| async move { client.list_platform_applications(input).await },
+            |input: &ListPlatformApplicationsInput, output: &ListPlatformApplicationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPlatformApplicationsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PlatformApplications` of every page of `ListPlatformApplications` results for `input`, as `list_platform_applications_pages` requests them.
+    pub fn list_platform_applications_stream(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<PlatformApplication, RusotoError<ListPlatformApplicationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_platform_applications_pages(input), |page| {
+            page.platform_applications.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSubscriptions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_subscriptions_pages(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListSubscriptionsResponse, RusotoError<ListSubscriptionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input| async move { client.list_subscriptions(input).await },
+            |input: &ListSubscriptionsInput, output: &ListSubscriptionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSubscriptionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Subscriptions` of every page of `ListSubscriptions` results for `input`, as `list_subscriptions_pages` requests them.
+    pub fn list_subscriptions_stream(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> impl futures::Stream<Item = Result<Subscription, RusotoError<ListSubscriptionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_subscriptions_pages(input), |page| {
+            page.subscriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSubscriptionsByTopic` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_subscriptions_by_topic_pages(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input| async move { client.list_subscriptions_by_topic(input).await },
+            |input: &ListSubscriptionsByTopicInput, output: &ListSubscriptionsByTopicResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSubscriptionsByTopicInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Subscriptions` of every page of `ListSubscriptionsByTopic` results for `input`, as `list_subscriptions_by_topic_pages` requests them.
+    pub fn list_subscriptions_by_topic_stream(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> impl futures::Stream<Item = Result<Subscription, RusotoError<ListSubscriptionsByTopicError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_subscriptions_by_topic_pages(input), |page| {
+            page.subscriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTopics` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_topics_pages(
+        &self,
+        input: ListTopicsInput,
+    ) -> impl futures::Stream<Item = Result<ListTopicsResponse, RusotoError<ListTopicsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SnsClient, input| async move { client.list_topics(input).await },
+            |input: &ListTopicsInput, output: &ListTopicsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTopicsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Topics` of every page of `ListTopics` results for `input`, as `list_topics_pages` requests them.
+    pub fn list_topics_stream(
+        &self,
+        input: ListTopicsInput,
+    ) -> impl futures::Stream<Item = Result<Topic, RusotoError<ListTopicsError>>> + Send {
+        rusoto_core::pagination::items(self.list_topics_pages(input), |page| {
+            page.topics.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Sns for SnsClient {
+    /// <p>Adds a statement to a topic's access control policy, granting access for the specified AWS accounts to the specified actions.</p>
+    async fn add_permission(
+        &self,
+        input: AddPermissionInput,
+    ) -> Result<(), RusotoError<AddPermissionError>> {
+        self.add_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Accepts a phone number and indicates whether the phone holder has opted out of receiving SMS messages from your account. You cannot send SMS messages to a number that is opted out.</p> <p>To resume sending messages, you can opt in the number by using the <code>OptInPhoneNumber</code> action.</p>
+    async fn check_if_phone_number_is_opted_out(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> Result<CheckIfPhoneNumberIsOptedOutResponse, RusotoError<CheckIfPhoneNumberIsOptedOutError>>
+    {
+        self.check_if_phone_number_is_opted_out_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Verifies an endpoint owner's intent to receive messages by validating the token sent to the endpoint by an earlier <code>Subscribe</code> action. If the token is valid, the action creates a new subscription and returns its Amazon Resource Name (ARN). This call requires an AWS signature only when the <code>AuthenticateOnUnsubscribe</code> flag is set to "true".</p>
+    async fn confirm_subscription(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> Result<ConfirmSubscriptionResponse, RusotoError<ConfirmSubscriptionError>> {
+        self.confirm_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a platform application object for one of the supported push notification services, such as APNS and FCM, to which devices and mobile apps may register. You must specify PlatformPrincipal and PlatformCredential attributes when using the <code>CreatePlatformApplication</code> action. The PlatformPrincipal is received from the notification service. For APNS/APNS_SANDBOX, PlatformPrincipal is "SSL certificate". For FCM, PlatformPrincipal is not applicable. For ADM, PlatformPrincipal is "client id". The PlatformCredential is also received from the notification service. For WNS, PlatformPrincipal is "Package Security Identifier". For MPNS, PlatformPrincipal is "TLS certificate". For Baidu, PlatformPrincipal is "API key".</p> <p>For APNS/APNS_SANDBOX, PlatformCredential is "private key". For FCM, PlatformCredential is "API key". For ADM, PlatformCredential is "client secret". For WNS, PlatformCredential is "secret key". For MPNS, PlatformCredential is "private key". For Baidu, PlatformCredential is "secret key". The PlatformApplicationArn that is returned when using <code>CreatePlatformApplication</code> is then used as an attribute for the <code>CreatePlatformEndpoint</code> action.</p>
+    async fn create_platform_application(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> Result<CreatePlatformApplicationResponse, RusotoError<CreatePlatformApplicationError>>
+    {
+        self.create_platform_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an endpoint for a device and mobile app on one of the supported push notification services, such as FCM and APNS. <code>CreatePlatformEndpoint</code> requires the PlatformApplicationArn that is returned from <code>CreatePlatformApplication</code>. The EndpointArn that is returned when using <code>CreatePlatformEndpoint</code> can then be used by the <code>Publish</code> action to send a message to a mobile app or by the <code>Subscribe</code> action for subscription to a topic. The <code>CreatePlatformEndpoint</code> action is idempotent, so if the requester already owns an endpoint with the same device token and attributes, that endpoint's ARN is returned without creating a new endpoint. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When using <code>CreatePlatformEndpoint</code> with Baidu, two attributes must be provided: ChannelId and UserId. The token field must also contain the ChannelId. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePushBaiduEndpoint.html">Creating an Amazon SNS Endpoint for Baidu</a>. </p>
+    async fn create_platform_endpoint(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreatePlatformEndpointError>> {
+        self.create_platform_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a topic to which notifications can be published. Users can create at most 100,000 topics. For more information, see <a href="http://aws.amazon.com/sns/">https://aws.amazon.com/sns</a>. This action is idempotent, so if the requester already owns a topic with the specified name, that topic's ARN is returned without creating a new topic.</p>
+    async fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> Result<CreateTopicResponse, RusotoError<CreateTopicError>> {
+        self.create_topic_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the endpoint for a device and mobile app from Amazon SNS. This action is idempotent. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When you delete an endpoint that is also subscribed to a topic, then you must also unsubscribe the endpoint from the topic.</p>
+    async fn delete_endpoint(
+        &self,
+        input: DeleteEndpointInput,
+    ) -> Result<(), RusotoError<DeleteEndpointError>> {
+        self.delete_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a platform application object for one of the supported push notification services, such as APNS and FCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    async fn delete_platform_application(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> Result<(), RusotoError<DeletePlatformApplicationError>> {
+        self.delete_platform_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a topic and all its subscriptions. Deleting a topic might prevent some messages previously sent to the topic from being delivered to subscribers. This action is idempotent, so deleting a topic that does not exist does not result in an error.</p>
+    async fn delete_topic(
+        &self,
+        input: DeleteTopicInput,
+    ) -> Result<(), RusotoError<DeleteTopicError>> {
+        self.delete_topic_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the endpoint attributes for a device on one of the supported push notification services, such as FCM and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    async fn get_endpoint_attributes(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> Result<GetEndpointAttributesResponse, RusotoError<GetEndpointAttributesError>> {
+        self.get_endpoint_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the attributes of the platform application object for the supported push notification services, such as APNS and FCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    async fn get_platform_application_attributes(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> Result<
+        GetPlatformApplicationAttributesResponse,
+        RusotoError<GetPlatformApplicationAttributesError>,
+    > {
+        self.get_platform_application_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the settings for sending SMS messages from your account.</p> <p>These settings are set with the <code>SetSMSAttributes</code> action.</p>
+    async fn get_sms_attributes(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> Result<GetSMSAttributesResponse, RusotoError<GetSMSAttributesError>> {
+        self.get_sms_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all of the properties of a subscription.</p>
+    async fn get_subscription_attributes(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> Result<GetSubscriptionAttributesResponse, RusotoError<GetSubscriptionAttributesError>>
+    {
+        self.get_subscription_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all of the properties of a topic. Topic properties returned might differ based on the authorization of the user.</p>
+    async fn get_topic_attributes(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> Result<GetTopicAttributesResponse, RusotoError<GetTopicAttributesError>> {
+        self.get_topic_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the endpoints and endpoint attributes for devices in a supported push notification service, such as FCM and APNS. The results for <code>ListEndpointsByPlatformApplication</code> are paginated and return a limited list of endpoints, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListEndpointsByPlatformApplication</code> again using the NextToken string received from the previous call. When there are no more records to return, NextToken will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    async fn list_endpoints_by_platform_application(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> Result<
+        ListEndpointsByPlatformApplicationResponse,
+        RusotoError<ListEndpointsByPlatformApplicationError>,
+    > {
+        self.list_endpoints_by_platform_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of phone numbers that are opted out, meaning you cannot send SMS messages to them.</p> <p>The results for <code>ListPhoneNumbersOptedOut</code> are paginated, and each page returns up to 100 phone numbers. If additional phone numbers are available after the first page of results, then a <code>NextToken</code> string will be returned. To receive the next page, you call <code>ListPhoneNumbersOptedOut</code> again using the <code>NextToken</code> string received from the previous call. When there are no more records to return, <code>NextToken</code> will be null.</p>
+    async fn list_phone_numbers_opted_out(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> Result<ListPhoneNumbersOptedOutResponse, RusotoError<ListPhoneNumbersOptedOutError>> {
+        self.list_phone_numbers_opted_out_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the platform application objects for the supported push notification services, such as APNS and FCM. The results for <code>ListPlatformApplications</code> are paginated and return a limited list of applications, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListPlatformApplications</code> using the NextToken string received from the previous call. When there are no more records to return, NextToken will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 15 transactions per second (TPS).</p>
+    async fn list_platform_applications(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> Result<ListPlatformApplicationsResponse, RusotoError<ListPlatformApplicationsError>> {
+        self.list_platform_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the requester's subscriptions. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptions</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    async fn list_subscriptions(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> Result<ListSubscriptionsResponse, RusotoError<ListSubscriptionsError>> {
+        self.list_subscriptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the subscriptions to a specific topic. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptionsByTopic</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    async fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>> {
+        self.list_subscriptions_by_topic_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all tags added to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon Simple Notification Service Developer Guide</i>.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the requester's topics. Each call returns a limited list of topics, up to 100. If there are more topics, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListTopics</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    async fn list_topics(
+        &self,
+        input: ListTopicsInput,
+    ) -> Result<ListTopicsResponse, RusotoError<ListTopicsError>> {
+        self.list_topics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Use this request to opt in a phone number that is opted out, which enables you to resume sending SMS messages to the number.</p> <p>You can opt in a phone number only once every 30 days.</p>
+    async fn opt_in_phone_number(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> Result<OptInPhoneNumberResponse, RusotoError<OptInPhoneNumberError>> {
+        self.opt_in_phone_number_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends a message to an Amazon SNS topic or sends a text message (SMS message) directly to a phone number. </p> <p>If you send a message to a topic, Amazon SNS delivers the message to each endpoint that is subscribed to the topic. The format of the message depends on the notification protocol for each subscribed endpoint.</p> <p>When a <code>messageId</code> is returned, the message has been saved and Amazon SNS will attempt to deliver it shortly.</p> <p>To use the <code>Publish</code> action for sending a message to a mobile endpoint, such as an app on a Kindle device or mobile phone, you must specify the EndpointArn for the TargetArn parameter. The EndpointArn is returned when making a call with the <code>CreatePlatformEndpoint</code> action. </p> <p>For more information about formatting messages, see <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-send-custommessage.html">Send Custom Platform-Specific Payloads in Messages to Mobile Devices</a>. </p>
+    async fn publish(
+        &self,
+        input: PublishInput,
+    ) -> Result<PublishResponse, RusotoError<PublishError>> {
+        self.publish_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a statement from a topic's access control policy.</p>
+    async fn remove_permission(
+        &self,
+        input: RemovePermissionInput,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.remove_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the attributes for an endpoint for a device on one of the supported push notification services, such as FCM and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    async fn set_endpoint_attributes(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> Result<(), RusotoError<SetEndpointAttributesError>> {
+        self.set_endpoint_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the attributes of the platform application object for the supported push notification services, such as APNS and FCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. For information on configuring attributes for message delivery status, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-msg-status.html">Using Amazon SNS Application Attributes for Message Delivery Status</a>. </p>
+    async fn set_platform_application_attributes(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> Result<(), RusotoError<SetPlatformApplicationAttributesError>> {
+        self.set_platform_application_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Use this request to set the default settings for sending SMS messages and receiving daily SMS usage reports.</p> <p>You can override some of these settings for a single message when you use the <code>Publish</code> action with the <code>MessageAttributes.entry.N</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sms_publish-to-phone.html">Sending an SMS Message</a> in the <i>Amazon SNS Developer Guide</i>.</p>
+    async fn set_sms_attributes(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> Result<SetSMSAttributesResponse, RusotoError<SetSMSAttributesError>> {
+        self.set_sms_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows a subscription owner to set an attribute of the subscription to a new value.</p>
+    async fn set_subscription_attributes(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> Result<(), RusotoError<SetSubscriptionAttributesError>> {
+        self.set_subscription_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows a topic owner to set an attribute of the topic to a new value.</p>
+    async fn set_topic_attributes(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> Result<(), RusotoError<SetTopicAttributesError>> {
+        self.set_topic_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Prepares to subscribe an endpoint by sending the endpoint a confirmation message. To actually create a subscription, the endpoint owner must call the <code>ConfirmSubscription</code> action with the token from the confirmation message. Confirmation tokens are valid for three days.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
+    async fn subscribe(
+        &self,
+        input: SubscribeInput,
+    ) -> Result<SubscribeResponse, RusotoError<SubscribeError>> {
+        self.subscribe_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Add tags to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p> <p>When you use topic tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a topic isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SNS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> <li> <p>Tagging actions are limited to 10 TPS per AWS account, per AWS region. If your application requires a higher throughput, file a <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=technical">technical support request</a>.</p> </li> </ul></p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a subscription. If the subscription requires authentication for deletion, only the owner of the subscription or the topic's owner can unsubscribe, and an AWS signature is required. If the <code>Unsubscribe</code> call does not require authentication and the requester is not the subscription owner, a final cancellation message is delivered to the endpoint, so that the endpoint owner can easily resubscribe to the topic if the <code>Unsubscribe</code> request was unintended.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
+    async fn unsubscribe(
+        &self,
+        input: UnsubscribeInput,
+    ) -> Result<(), RusotoError<UnsubscribeError>> {
+        self.unsubscribe_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove tags from the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
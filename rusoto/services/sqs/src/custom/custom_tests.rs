@@ -172,10 +172,51 @@ async fn test_parse_queue_does_not_exist_error() {
     let result = client.get_queue_url(request).await;
     assert!(result.is_err());
     let err = result.err().unwrap();
-    assert_eq!(
-        RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(
-            "The specified queue does not exist for this wsdl version.".to_owned()
-        )),
-        err
+    match err {
+        RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(message), metadata) => {
+            assert_eq!(
+                message,
+                "The specified queue does not exist for this wsdl version."
+            );
+            assert_eq!(
+                metadata.request_id(),
+                Some("8f8f9957-c0d9-536a-9ca6-ca7483be06ad")
+            );
+        }
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn test_parse_unhandled_error() {
+    let mock = MockRequestDispatcher::with_status(400).with_body(
+        r#"<?xml version="1.0"?>
+        <ErrorResponse xmlns="http://queue.amazonaws.com/doc/2012-11-05/">
+            <Error>
+                <Type>Sender</Type>
+                <Code>AWS.SimpleQueueService.BrandNewError</Code>
+                <Message>Something new went wrong.</Message>
+                <Detail/>
+            </Error>
+            <RequestId>8f8f9957-c0d9-536a-9ca6-ca7483be06ad</RequestId>
+        </ErrorResponse>"#,
     );
+
+    let request = GetQueueUrlRequest {
+        queue_name: "some-queue".to_owned(),
+        ..Default::default()
+    };
+
+    let client = SqsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let err = client.get_queue_url(request).await.unwrap_err();
+    match err {
+        RusotoError::Service(GetQueueUrlError::Unhandled(metadata), _) => {
+            assert_eq!(
+                metadata.code(),
+                Some("AWS.SimpleQueueService.BrandNewError")
+            );
+            assert_eq!(metadata.message(), Some("Something new went wrong."));
+        }
+        err => panic!("unexpected error: {:?}", err),
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -2067,9 +2067,12 @@ impl UntagQueueRequestSerializer {
 
 /// Errors returned by AddPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddPermissionError {
     /// <p>The specified action violates a limit. For example, <code>ReceiveMessage</code> returns this error if the maximum number of inflight messages is reached and <code>AddPermission</code> returns this error if the maximum number of permissions for the queue is reached.</p>
     OverLimit(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddPermissionError {
@@ -2081,11 +2084,12 @@ impl AddPermissionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OverLimit" => {
-                        return RusotoError::Service(AddPermissionError::OverLimit(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddPermissionError::OverLimit(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AddPermissionError::Unhandled, &res),
                 }
             }
         }
@@ -2105,17 +2109,21 @@ impl fmt::Display for AddPermissionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AddPermissionError::OverLimit(ref cause) => write!(f, "{}", cause),
+            AddPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddPermissionError {}
 /// Errors returned by ChangeMessageVisibility
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ChangeMessageVisibilityError {
     /// <p>The specified message isn't in flight.</p>
     MessageNotInflight(String),
     /// <p>The specified receipt handle isn't valid.</p>
     ReceiptHandleIsInvalid(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ChangeMessageVisibilityError {
@@ -2127,18 +2135,25 @@ impl ChangeMessageVisibilityError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.MessageNotInflight" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeMessageVisibilityError::MessageNotInflight(parsed_error.message),
+                            &res,
                         )
                     }
                     "ReceiptHandleIsInvalid" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeMessageVisibilityError::ReceiptHandleIsInvalid(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ChangeMessageVisibilityError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -2161,12 +2176,14 @@ impl fmt::Display for ChangeMessageVisibilityError {
             ChangeMessageVisibilityError::ReceiptHandleIsInvalid(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ChangeMessageVisibilityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ChangeMessageVisibilityError {}
 /// Errors returned by ChangeMessageVisibilityBatch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ChangeMessageVisibilityBatchError {
     /// <p>Two or more batch entries in the request have the same <code>Id</code>.</p>
     BatchEntryIdsNotDistinct(String),
@@ -2176,6 +2193,8 @@ pub enum ChangeMessageVisibilityBatchError {
     InvalidBatchEntryId(String),
     /// <p>The batch request contains more entries than permissible.</p>
     TooManyEntriesInBatchRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ChangeMessageVisibilityBatchError {
@@ -2189,34 +2208,43 @@ impl ChangeMessageVisibilityBatchError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeMessageVisibilityBatchError::BatchEntryIdsNotDistinct(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeMessageVisibilityBatchError::EmptyBatchRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeMessageVisibilityBatchError::InvalidBatchEntryId(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ChangeMessageVisibilityBatchError::TooManyEntriesInBatchRequest(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ChangeMessageVisibilityBatchError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -2247,17 +2275,21 @@ impl fmt::Display for ChangeMessageVisibilityBatchError {
             ChangeMessageVisibilityBatchError::TooManyEntriesInBatchRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ChangeMessageVisibilityBatchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ChangeMessageVisibilityBatchError {}
 /// Errors returned by CreateQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateQueueError {
     /// <p>You must wait 60 seconds after deleting a queue before you can create another queue with the same name.</p>
     QueueDeletedRecently(String),
     /// <p>A queue with this name already exists. Amazon SQS returns this error only if the request includes attributes whose values differ from those of the existing queue.</p>
     QueueNameExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateQueueError {
@@ -2269,16 +2301,18 @@ impl CreateQueueError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.QueueDeletedRecently" => {
-                        return RusotoError::Service(CreateQueueError::QueueDeletedRecently(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateQueueError::QueueDeletedRecently(parsed_error.message),
+                            &res,
+                        )
                     }
                     "QueueAlreadyExists" => {
-                        return RusotoError::Service(CreateQueueError::QueueNameExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateQueueError::QueueNameExists(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateQueueError::Unhandled, &res),
                 }
             }
         }
@@ -2299,17 +2333,21 @@ impl fmt::Display for CreateQueueError {
         match *self {
             CreateQueueError::QueueDeletedRecently(ref cause) => write!(f, "{}", cause),
             CreateQueueError::QueueNameExists(ref cause) => write!(f, "{}", cause),
+            CreateQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateQueueError {}
 /// Errors returned by DeleteMessage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMessageError {
     /// <p>The specified receipt handle isn't valid for the current version.</p>
     InvalidIdFormat(String),
     /// <p>The specified receipt handle isn't valid.</p>
     ReceiptHandleIsInvalid(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMessageError {
@@ -2321,16 +2359,18 @@ impl DeleteMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidIdFormat" => {
-                        return RusotoError::Service(DeleteMessageError::InvalidIdFormat(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteMessageError::InvalidIdFormat(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ReceiptHandleIsInvalid" => {
-                        return RusotoError::Service(DeleteMessageError::ReceiptHandleIsInvalid(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteMessageError::ReceiptHandleIsInvalid(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteMessageError::Unhandled, &res),
                 }
             }
         }
@@ -2351,12 +2391,14 @@ impl fmt::Display for DeleteMessageError {
         match *self {
             DeleteMessageError::InvalidIdFormat(ref cause) => write!(f, "{}", cause),
             DeleteMessageError::ReceiptHandleIsInvalid(ref cause) => write!(f, "{}", cause),
+            DeleteMessageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMessageError {}
 /// Errors returned by DeleteMessageBatch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMessageBatchError {
     /// <p>Two or more batch entries in the request have the same <code>Id</code>.</p>
     BatchEntryIdsNotDistinct(String),
@@ -2366,6 +2408,8 @@ pub enum DeleteMessageBatchError {
     InvalidBatchEntryId(String),
     /// <p>The batch request contains more entries than permissible.</p>
     TooManyEntriesInBatchRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMessageBatchError {
@@ -2377,28 +2421,32 @@ impl DeleteMessageBatchError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteMessageBatchError::BatchEntryIdsNotDistinct(parsed_error.message),
+                            &res,
                         )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
-                        return RusotoError::Service(DeleteMessageBatchError::EmptyBatchRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteMessageBatchError::EmptyBatchRequest(parsed_error.message),
+                            &res,
+                        )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
-                        return RusotoError::Service(DeleteMessageBatchError::InvalidBatchEntryId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteMessageBatchError::InvalidBatchEntryId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteMessageBatchError::TooManyEntriesInBatchRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteMessageBatchError::Unhandled, &res),
                 }
             }
         }
@@ -2423,13 +2471,18 @@ impl fmt::Display for DeleteMessageBatchError {
             DeleteMessageBatchError::TooManyEntriesInBatchRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteMessageBatchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMessageBatchError {}
 /// Errors returned by DeleteQueue
 #[derive(Debug, PartialEq)]
-pub enum DeleteQueueError {}
+#[non_exhaustive]
+pub enum DeleteQueueError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteQueueError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteQueueError> {
@@ -2437,10 +2490,8 @@ impl DeleteQueueError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteQueueError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -2457,15 +2508,20 @@ impl DeleteQueueError {
 impl fmt::Display for DeleteQueueError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteQueueError {}
 /// Errors returned by GetQueueAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetQueueAttributesError {
     /// <p>The specified attribute doesn't exist.</p>
     InvalidAttributeName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetQueueAttributesError {
@@ -2477,11 +2533,12 @@ impl GetQueueAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAttributeName" => {
-                        return RusotoError::Service(GetQueueAttributesError::InvalidAttributeName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetQueueAttributesError::InvalidAttributeName(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetQueueAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -2501,15 +2558,19 @@ impl fmt::Display for GetQueueAttributesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetQueueAttributesError::InvalidAttributeName(ref cause) => write!(f, "{}", cause),
+            GetQueueAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetQueueAttributesError {}
 /// Errors returned by GetQueueUrl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetQueueUrlError {
     /// <p>The specified queue doesn't exist.</p>
     QueueDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetQueueUrlError {
@@ -2521,11 +2582,12 @@ impl GetQueueUrlError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.NonExistentQueue" => {
-                        return RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetQueueUrlError::QueueDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetQueueUrlError::Unhandled, &res),
                 }
             }
         }
@@ -2545,15 +2607,19 @@ impl fmt::Display for GetQueueUrlError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetQueueUrlError::QueueDoesNotExist(ref cause) => write!(f, "{}", cause),
+            GetQueueUrlError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetQueueUrlError {}
 /// Errors returned by ListDeadLetterSourceQueues
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeadLetterSourceQueuesError {
     /// <p>The specified queue doesn't exist.</p>
     QueueDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeadLetterSourceQueuesError {
@@ -2567,13 +2633,19 @@ impl ListDeadLetterSourceQueuesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.NonExistentQueue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListDeadLetterSourceQueuesError::QueueDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListDeadLetterSourceQueuesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -2593,13 +2665,18 @@ impl fmt::Display for ListDeadLetterSourceQueuesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDeadLetterSourceQueuesError::QueueDoesNotExist(ref cause) => write!(f, "{}", cause),
+            ListDeadLetterSourceQueuesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeadLetterSourceQueuesError {}
 /// Errors returned by ListQueueTags
 #[derive(Debug, PartialEq)]
-pub enum ListQueueTagsError {}
+#[non_exhaustive]
+pub enum ListQueueTagsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListQueueTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListQueueTagsError> {
@@ -2607,10 +2684,8 @@ impl ListQueueTagsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListQueueTagsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -2627,13 +2702,19 @@ impl ListQueueTagsError {
 impl fmt::Display for ListQueueTagsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListQueueTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListQueueTagsError {}
 /// Errors returned by ListQueues
 #[derive(Debug, PartialEq)]
-pub enum ListQueuesError {}
+#[non_exhaustive]
+pub enum ListQueuesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListQueuesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListQueuesError> {
@@ -2641,10 +2722,8 @@ impl ListQueuesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListQueuesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -2661,17 +2740,22 @@ impl ListQueuesError {
 impl fmt::Display for ListQueuesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListQueuesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListQueuesError {}
 /// Errors returned by PurgeQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PurgeQueueError {
     /// <p>Indicates that the specified queue previously received a <code>PurgeQueue</code> request within the last 60 seconds (the time it can take to delete the messages in the queue).</p>
     PurgeQueueInProgress(String),
     /// <p>The specified queue doesn't exist.</p>
     QueueDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PurgeQueueError {
@@ -2683,16 +2767,18 @@ impl PurgeQueueError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.PurgeQueueInProgress" => {
-                        return RusotoError::Service(PurgeQueueError::PurgeQueueInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PurgeQueueError::PurgeQueueInProgress(parsed_error.message),
+                            &res,
+                        )
                     }
                     "AWS.SimpleQueueService.NonExistentQueue" => {
-                        return RusotoError::Service(PurgeQueueError::QueueDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PurgeQueueError::QueueDoesNotExist(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PurgeQueueError::Unhandled, &res),
                 }
             }
         }
@@ -2713,15 +2799,19 @@ impl fmt::Display for PurgeQueueError {
         match *self {
             PurgeQueueError::PurgeQueueInProgress(ref cause) => write!(f, "{}", cause),
             PurgeQueueError::QueueDoesNotExist(ref cause) => write!(f, "{}", cause),
+            PurgeQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PurgeQueueError {}
 /// Errors returned by ReceiveMessage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReceiveMessageError {
     /// <p>The specified action violates a limit. For example, <code>ReceiveMessage</code> returns this error if the maximum number of inflight messages is reached and <code>AddPermission</code> returns this error if the maximum number of permissions for the queue is reached.</p>
     OverLimit(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReceiveMessageError {
@@ -2733,11 +2823,12 @@ impl ReceiveMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OverLimit" => {
-                        return RusotoError::Service(ReceiveMessageError::OverLimit(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ReceiveMessageError::OverLimit(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ReceiveMessageError::Unhandled, &res),
                 }
             }
         }
@@ -2757,13 +2848,18 @@ impl fmt::Display for ReceiveMessageError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ReceiveMessageError::OverLimit(ref cause) => write!(f, "{}", cause),
+            ReceiveMessageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReceiveMessageError {}
 /// Errors returned by RemovePermission
 #[derive(Debug, PartialEq)]
-pub enum RemovePermissionError {}
+#[non_exhaustive]
+pub enum RemovePermissionError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl RemovePermissionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RemovePermissionError> {
@@ -2771,10 +2867,8 @@ impl RemovePermissionError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(RemovePermissionError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -2791,17 +2885,22 @@ impl RemovePermissionError {
 impl fmt::Display for RemovePermissionError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            RemovePermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for RemovePermissionError {}
 /// Errors returned by SendMessage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendMessageError {
     /// <p>The message contains characters outside the allowed set.</p>
     InvalidMessageContents(String),
     /// <p>Error code 400. Unsupported operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendMessageError {
@@ -2813,16 +2912,18 @@ impl SendMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidMessageContents" => {
-                        return RusotoError::Service(SendMessageError::InvalidMessageContents(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendMessageError::InvalidMessageContents(parsed_error.message),
+                            &res,
+                        )
                     }
                     "AWS.SimpleQueueService.UnsupportedOperation" => {
-                        return RusotoError::Service(SendMessageError::UnsupportedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendMessageError::UnsupportedOperation(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SendMessageError::Unhandled, &res),
                 }
             }
         }
@@ -2843,12 +2944,14 @@ impl fmt::Display for SendMessageError {
         match *self {
             SendMessageError::InvalidMessageContents(ref cause) => write!(f, "{}", cause),
             SendMessageError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            SendMessageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendMessageError {}
 /// Errors returned by SendMessageBatch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendMessageBatchError {
     /// <p>Two or more batch entries in the request have the same <code>Id</code>.</p>
     BatchEntryIdsNotDistinct(String),
@@ -2862,6 +2965,8 @@ pub enum SendMessageBatchError {
     TooManyEntriesInBatchRequest(String),
     /// <p>Error code 400. Unsupported operation.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendMessageBatchError {
@@ -2873,38 +2978,44 @@ impl SendMessageBatchError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendMessageBatchError::BatchEntryIdsNotDistinct(parsed_error.message),
+                            &res,
                         )
                     }
                     "AWS.SimpleQueueService.BatchRequestTooLong" => {
-                        return RusotoError::Service(SendMessageBatchError::BatchRequestTooLong(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendMessageBatchError::BatchRequestTooLong(parsed_error.message),
+                            &res,
+                        )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
-                        return RusotoError::Service(SendMessageBatchError::EmptyBatchRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendMessageBatchError::EmptyBatchRequest(parsed_error.message),
+                            &res,
+                        )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
-                        return RusotoError::Service(SendMessageBatchError::InvalidBatchEntryId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendMessageBatchError::InvalidBatchEntryId(parsed_error.message),
+                            &res,
+                        )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SendMessageBatchError::TooManyEntriesInBatchRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "AWS.SimpleQueueService.UnsupportedOperation" => {
-                        return RusotoError::Service(SendMessageBatchError::UnsupportedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SendMessageBatchError::UnsupportedOperation(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SendMessageBatchError::Unhandled, &res),
                 }
             }
         }
@@ -2931,15 +3042,19 @@ impl fmt::Display for SendMessageBatchError {
                 write!(f, "{}", cause)
             }
             SendMessageBatchError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            SendMessageBatchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendMessageBatchError {}
 /// Errors returned by SetQueueAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetQueueAttributesError {
     /// <p>The specified attribute doesn't exist.</p>
     InvalidAttributeName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetQueueAttributesError {
@@ -2951,11 +3066,12 @@ impl SetQueueAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAttributeName" => {
-                        return RusotoError::Service(SetQueueAttributesError::InvalidAttributeName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetQueueAttributesError::InvalidAttributeName(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetQueueAttributesError::Unhandled, &res),
                 }
             }
         }
@@ -2975,13 +3091,18 @@ impl fmt::Display for SetQueueAttributesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SetQueueAttributesError::InvalidAttributeName(ref cause) => write!(f, "{}", cause),
+            SetQueueAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetQueueAttributesError {}
 /// Errors returned by TagQueue
 #[derive(Debug, PartialEq)]
-pub enum TagQueueError {}
+#[non_exhaustive]
+pub enum TagQueueError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl TagQueueError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagQueueError> {
@@ -2989,10 +3110,8 @@ impl TagQueueError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(TagQueueError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -3009,13 +3128,19 @@ impl TagQueueError {
 impl fmt::Display for TagQueueError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            TagQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for TagQueueError {}
 /// Errors returned by UntagQueue
 #[derive(Debug, PartialEq)]
-pub enum UntagQueueError {}
+#[non_exhaustive]
+pub enum UntagQueueError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UntagQueueError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UntagQueueError> {
@@ -3023,10 +3148,8 @@ impl UntagQueueError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(UntagQueueError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -3043,7 +3166,9 @@ impl UntagQueueError {
 impl fmt::Display for UntagQueueError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UntagQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UntagQueueError {}
@@ -3178,6 +3303,7 @@ impl SqsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SqsClient {
         SqsClient {
             client: Client::shared(),
@@ -3203,92 +3329,153 @@ impl SqsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SqsClient {
         SqsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SqsClient> {
+        rusoto_core::ClientBuilder::new(SqsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SqsClient {
+        SqsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Sqs for SqsClient {
-    /// <p><p>Adds a permission to a queue for a specific <a href="https://docs.aws.amazon.com/general/latest/gr/glos-chap.html#P">principal</a>. This allows sharing access to the queue.</p> <p>When you create a queue, you have full control access rights for the queue. Only you, the owner of the queue, can grant or deny permissions to the queue. For more information about these permissions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-writing-an-sqs-policy.html#write-messages-to-shared-queue">Allow Developers to Write Messages to a Shared Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <ul> <li> <p> <code>AddPermission</code> generates a policy for you. You can use <code> <a>SetQueueAttributes</a> </code> to upload your policy. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-creating-custom-policies.html">Using Custom Policies with the Amazon SQS Access Policy Language</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>An Amazon SQS policy can have a maximum of 7 actions.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
-    async fn add_permission(
+impl SqsClient {
+    /// Calls `add_permission`, also returning the metadata of the response.
+    pub async fn add_permission_with_metadata(
         &self,
         input: AddPermissionRequest,
-    ) -> Result<(), RusotoError<AddPermissionError>> {
-        let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddPermission");
-        params.put("Version", "2012-11-05");
-        AddPermissionRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<((), ResponseMetadata), RusotoError<AddPermissionError>> {
+        let request = self.build_add_permission_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddPermissionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Changes the visibility timeout of a specified message in a queue to a new value. The default visibility timeout for a message is 30 seconds. The minimum is 0 seconds. The maximum is 12 hours. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>For example, you have a message with a visibility timeout of 5 minutes. After 3 minutes, you call <code>ChangeMessageVisibility</code> with a timeout of 10 minutes. You can continue to call <code>ChangeMessageVisibility</code> to extend the visibility timeout to the maximum allowed time. If you try to extend the visibility timeout beyond the maximum, your request is rejected.</p> <p>An Amazon SQS message has three basic states:</p> <ol> <li> <p>Sent to a queue by a producer.</p> </li> <li> <p>Received from the queue by a consumer.</p> </li> <li> <p>Deleted from the queue.</p> </li> </ol> <p>A message is considered to be <i>stored</i> after it is sent to a queue by a producer, but not yet received from the queue by a consumer (that is, between states 1 and 2). There is no limit to the number of stored messages. A message is considered to be <i>in flight</i> after it is received from a queue by a consumer, but not yet deleted from the queue (that is, between states 2 and 3). There is a limit to the number of inflight messages.</p> <p>Limits that apply to inflight messages are unrelated to the <i>unlimited</i> number of stored messages.</p> <p>For most standard queues (depending on queue traffic and message backlog), there can be a maximum of approximately 120,000 inflight messages (received from a queue by a consumer, but not yet deleted from the queue). If you reach this limit, Amazon SQS returns the <code>OverLimit</code> error message. To avoid reaching the limit, you should delete messages from the queue after they&#39;re processed. You can also increase the number of queues you use to process your messages. To request a limit increase, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-sqs">file a support request</a>.</p> <p>For FIFO queues, there can be a maximum of 20,000 inflight messages (received from a queue by a consumer, but not yet deleted from the queue). If you reach this limit, Amazon SQS returns no error messages.</p> <important> <p>If you attempt to set the <code>VisibilityTimeout</code> to a value greater than the maximum time left, Amazon SQS returns an error. Amazon SQS doesn&#39;t automatically recalculate and increase the timeout to the maximum remaining time.</p> <p>Unlike with a queue, when you change the visibility timeout for a specific message the timeout value is applied immediately but isn&#39;t saved in memory for that message. If you don&#39;t delete a message after it is received, the visibility timeout for the message reverts to the original timeout value (not to the value you set using the <code>ChangeMessageVisibility</code> action) the next time the message is received.</p> </important></p>
-    async fn change_message_visibility(
+    /// Presigns the request `add_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_permission(
         &self,
-        input: ChangeMessageVisibilityRequest,
-    ) -> Result<(), RusotoError<ChangeMessageVisibilityError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AddPermissionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_permission_request(&self, input: AddPermissionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("AddPermission");
         let mut params = Params::new();
 
-        params.put("Action", "ChangeMessageVisibility");
+        params.put("Action", "AddPermission");
         params.put("Version", "2012-11-05");
-        ChangeMessageVisibilityRequestSerializer::serialize(&mut params, "", &input);
+        AddPermissionRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `change_message_visibility`, also returning the metadata of the response.
+    pub async fn change_message_visibility_with_metadata(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<ChangeMessageVisibilityError>> {
+        let request = self.build_change_message_visibility_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ChangeMessageVisibilityError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Changes the visibility timeout of multiple messages. This is a batch version of <code> <a>ChangeMessageVisibility</a>.</code> The result of the action on each message is reported individually in the response. You can send up to 10 <code> <a>ChangeMessageVisibility</a> </code> requests with each <code>ChangeMessageVisibilityBatch</code> action.</p> <important> <p>Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of <code>200</code>.</p> </important> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p>
-    async fn change_message_visibility_batch(
+    /// Presigns the request `change_message_visibility` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_change_message_visibility(
         &self,
-        input: ChangeMessageVisibilityBatchRequest,
-    ) -> Result<ChangeMessageVisibilityBatchResult, RusotoError<ChangeMessageVisibilityBatchError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ChangeMessageVisibilityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_change_message_visibility_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_change_message_visibility_request(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ChangeMessageVisibility");
         let mut params = Params::new();
 
-        params.put("Action", "ChangeMessageVisibilityBatch");
+        params.put("Action", "ChangeMessageVisibility");
         params.put("Version", "2012-11-05");
-        ChangeMessageVisibilityBatchRequestSerializer::serialize(&mut params, "", &input);
+        ChangeMessageVisibilityRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `change_message_visibility_batch`, also returning the metadata of the response.
+    pub async fn change_message_visibility_batch_with_metadata(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> Result<
+        (ChangeMessageVisibilityBatchResult, ResponseMetadata),
+        RusotoError<ChangeMessageVisibilityBatchError>,
+    > {
+        let request = self.build_change_message_visibility_batch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ChangeMessageVisibilityBatchError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3310,34 +3497,61 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a new standard or FIFO queue. You can pass one or more attributes in the request. Keep the following caveats in mind:</p> <ul> <li> <p>If you don&#39;t specify the <code>FifoQueue</code> attribute, Amazon SQS creates a standard queue.</p> <note> <p>You can&#39;t change the queue type after you create it and you can&#39;t convert an existing standard queue into a FIFO queue. You must either create a new FIFO queue for your application or delete your existing standard queue and recreate it as a FIFO queue. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-moving">Moving From a Standard Queue to a FIFO Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> </note> </li> <li> <p>If you don&#39;t provide a value for an attribute, the queue is created with the default value for the attribute.</p> </li> <li> <p>If you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.</p> </li> </ul> <p>To successfully create a new queue, you must provide a queue name that adheres to the <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/limits-queues.html">limits related to queues</a> and is unique within the scope of your queues.</p> <p>To get the queue URL, use the <code> <a>GetQueueUrl</a> </code> action. <code> <a>GetQueueUrl</a> </code> requires only the <code>QueueName</code> parameter. be aware of existing queue names:</p> <ul> <li> <p>If you provide the name of an existing queue along with the exact names and values of all the queue&#39;s attributes, <code>CreateQueue</code> returns the queue URL for the existing queue.</p> </li> <li> <p>If the queue name, attribute names, or attribute values don&#39;t match an existing queue, <code>CreateQueue</code> returns an error.</p> </li> </ul> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
-    async fn create_queue(
+    /// Presigns the request `change_message_visibility_batch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_change_message_visibility_batch(
         &self,
-        input: CreateQueueRequest,
-    ) -> Result<CreateQueueResult, RusotoError<CreateQueueError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_change_message_visibility_batch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_change_message_visibility_batch_request(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ChangeMessageVisibilityBatch");
         let mut params = Params::new();
 
-        params.put("Action", "CreateQueue");
+        params.put("Action", "ChangeMessageVisibilityBatch");
         params.put("Version", "2012-11-05");
-        CreateQueueRequestSerializer::serialize(&mut params, "", &input);
+        ChangeMessageVisibilityBatchRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_queue`, also returning the metadata of the response.
+    pub async fn create_queue_with_metadata(
+        &self,
+        input: CreateQueueRequest,
+    ) -> Result<(CreateQueueResult, ResponseMetadata), RusotoError<CreateQueueError>> {
+        let request = self.build_create_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateQueueError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3356,62 +3570,108 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes the specified message from the specified queue. To select the message to delete, use the <code>ReceiptHandle</code> of the message (<i>not</i> the <code>MessageId</code> which you receive when you send the message). Amazon SQS can delete a message from a queue even if a visibility timeout setting causes the message to be locked by another consumer. Amazon SQS automatically deletes messages left in a queue longer than the retention period configured for the queue. </p> <note> <p>The <code>ReceiptHandle</code> is associated with a <i>specific instance</i> of receiving a message. If you receive a message more than once, the <code>ReceiptHandle</code> is different each time you receive a message. When you use the <code>DeleteMessage</code> action, you must provide the most recently received <code>ReceiptHandle</code> for the message (otherwise, the request succeeds, but the message might not be deleted).</p> <p>For standard queues, it is possible to receive a message even after you delete it. This might happen on rare occasions if one of the servers which stores a copy of the message is unavailable when you send the request to delete the message. The copy remains on the server and might be returned to you during a subsequent receive request. You should ensure that your application is idempotent, so that receiving a message more than once does not cause issues.</p> </note></p>
-    async fn delete_message(
+    /// Presigns the request `create_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_queue(
         &self,
-        input: DeleteMessageRequest,
-    ) -> Result<(), RusotoError<DeleteMessageError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_queue_request(&self, input: CreateQueueRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("CreateQueue");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteMessage");
+        params.put("Action", "CreateQueue");
         params.put("Version", "2012-11-05");
-        DeleteMessageRequestSerializer::serialize(&mut params, "", &input);
+        CreateQueueRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_message`, also returning the metadata of the response.
+    pub async fn delete_message_with_metadata(
+        &self,
+        input: DeleteMessageRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteMessageError>> {
+        let request = self.build_delete_message_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteMessageError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes up to ten messages from the specified queue. This is a batch version of <code> <a>DeleteMessage</a>.</code> The result of the action on each message is reported individually in the response.</p> <important> <p>Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of <code>200</code>.</p> </important> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p>
-    async fn delete_message_batch(
+    /// Presigns the request `delete_message` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_message(
         &self,
-        input: DeleteMessageBatchRequest,
-    ) -> Result<DeleteMessageBatchResult, RusotoError<DeleteMessageBatchError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteMessageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_message_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_message_request(&self, input: DeleteMessageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteMessage");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteMessageBatch");
+        params.put("Action", "DeleteMessage");
         params.put("Version", "2012-11-05");
-        DeleteMessageBatchRequestSerializer::serialize(&mut params, "", &input);
+        DeleteMessageRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_message_batch`, also returning the metadata of the response.
+    pub async fn delete_message_batch_with_metadata(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> Result<(DeleteMessageBatchResult, ResponseMetadata), RusotoError<DeleteMessageBatchError>>
+    {
+        let request = self.build_delete_message_batch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteMessageBatchError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3433,62 +3693,111 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes the queue specified by the <code>QueueUrl</code>, regardless of the queue&#39;s contents. If the specified queue doesn&#39;t exist, Amazon SQS returns a successful response.</p> <important> <p>Be careful with the <code>DeleteQueue</code> action: When you delete a queue, any messages in the queue are no longer available. </p> </important> <p>When you delete a queue, the deletion process takes up to 60 seconds. Requests you send involving that queue during the 60 seconds might succeed. For example, a <code> <a>SendMessage</a> </code> request might succeed, but after 60 seconds the queue and the message you sent no longer exist.</p> <p>When you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
-    async fn delete_queue(
+    /// Presigns the request `delete_message_batch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_message_batch(
         &self,
-        input: DeleteQueueRequest,
-    ) -> Result<(), RusotoError<DeleteQueueError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteMessageBatchRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_message_batch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_message_batch_request(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteMessageBatch");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteQueue");
+        params.put("Action", "DeleteMessageBatch");
         params.put("Version", "2012-11-05");
-        DeleteQueueRequestSerializer::serialize(&mut params, "", &input);
+        DeleteMessageBatchRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_queue`, also returning the metadata of the response.
+    pub async fn delete_queue_with_metadata(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteQueueError>> {
+        let request = self.build_delete_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteQueueError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Gets attributes for the specified queue.</p> <note> <p>To determine whether a queue is <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO</a>, you can check whether <code>QueueName</code> ends with the <code>.fifo</code> suffix.</p> </note> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p>
-    async fn get_queue_attributes(
+    /// Presigns the request `delete_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_queue(
         &self,
-        input: GetQueueAttributesRequest,
-    ) -> Result<GetQueueAttributesResult, RusotoError<GetQueueAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_queue_request(&self, input: DeleteQueueRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteQueue");
         let mut params = Params::new();
 
-        params.put("Action", "GetQueueAttributes");
+        params.put("Action", "DeleteQueue");
         params.put("Version", "2012-11-05");
-        GetQueueAttributesRequestSerializer::serialize(&mut params, "", &input);
+        DeleteQueueRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_queue_attributes`, also returning the metadata of the response.
+    pub async fn get_queue_attributes_with_metadata(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> Result<(GetQueueAttributesResult, ResponseMetadata), RusotoError<GetQueueAttributesError>>
+    {
+        let request = self.build_get_queue_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetQueueAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3510,34 +3819,61 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the URL of an existing Amazon SQS queue.</p> <p>To access a queue that belongs to another AWS account, use the <code>QueueOwnerAWSAccountId</code> parameter to specify the account ID of the queue's owner. The queue's owner must grant you permission to access the queue. For more information about shared queue access, see <code> <a>AddPermission</a> </code> or see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-writing-an-sqs-policy.html#write-messages-to-shared-queue">Allow Developers to Write Messages to a Shared Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p>
-    async fn get_queue_url(
+    /// Presigns the request `get_queue_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_queue_attributes(
         &self,
-        input: GetQueueUrlRequest,
-    ) -> Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetQueueAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_queue_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_queue_attributes_request(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("GetQueueAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "GetQueueUrl");
+        params.put("Action", "GetQueueAttributes");
         params.put("Version", "2012-11-05");
-        GetQueueUrlRequestSerializer::serialize(&mut params, "", &input);
+        GetQueueAttributesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_queue_url`, also returning the metadata of the response.
+    pub async fn get_queue_url_with_metadata(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> Result<(GetQueueUrlResult, ResponseMetadata), RusotoError<GetQueueUrlError>> {
+        let request = self.build_get_queue_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetQueueUrlError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3556,35 +3892,61 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of your queues that have the <code>RedrivePolicy</code> queue attribute configured with a dead-letter queue.</p> <p>For more information about using dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
-    async fn list_dead_letter_source_queues(
+    /// Presigns the request `get_queue_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_queue_url(
         &self,
-        input: ListDeadLetterSourceQueuesRequest,
-    ) -> Result<ListDeadLetterSourceQueuesResult, RusotoError<ListDeadLetterSourceQueuesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: GetQueueUrlRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_queue_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_queue_url_request(&self, input: GetQueueUrlRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("GetQueueUrl");
         let mut params = Params::new();
 
-        params.put("Action", "ListDeadLetterSourceQueues");
+        params.put("Action", "GetQueueUrl");
         params.put("Version", "2012-11-05");
-        ListDeadLetterSourceQueuesRequestSerializer::serialize(&mut params, "", &input);
+        GetQueueUrlRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_dead_letter_source_queues`, also returning the metadata of the response.
+    pub async fn list_dead_letter_source_queues_with_metadata(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> Result<
+        (ListDeadLetterSourceQueuesResult, ResponseMetadata),
+        RusotoError<ListDeadLetterSourceQueuesError>,
+    > {
+        let request = self.build_list_dead_letter_source_queues_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListDeadLetterSourceQueuesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3606,34 +3968,61 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>List all cost allocation tags added to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
-    async fn list_queue_tags(
+    /// Presigns the request `list_dead_letter_source_queues` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dead_letter_source_queues(
         &self,
-        input: ListQueueTagsRequest,
-    ) -> Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>> {
-        let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListQueueTags");
+        presigner: &rusoto_core::Presigner,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dead_letter_source_queues_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dead_letter_source_queues_request(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListDeadLetterSourceQueues");
+        let mut params = Params::new();
+
+        params.put("Action", "ListDeadLetterSourceQueues");
         params.put("Version", "2012-11-05");
-        ListQueueTagsRequestSerializer::serialize(&mut params, "", &input);
+        ListDeadLetterSourceQueuesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_queue_tags`, also returning the metadata of the response.
+    pub async fn list_queue_tags_with_metadata(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> Result<(ListQueueTagsResult, ResponseMetadata), RusotoError<ListQueueTagsError>> {
+        let request = self.build_list_queue_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListQueueTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3653,34 +4042,58 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns a list of your queues. The maximum number of queues that can be returned is 1,000. If you specify a value for the optional <code>QueueNamePrefix</code> parameter, only queues with a name that begins with the specified value are returned.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
-    async fn list_queues(
+    /// Presigns the request `list_queue_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_queue_tags(
         &self,
-        input: ListQueuesRequest,
-    ) -> Result<ListQueuesResult, RusotoError<ListQueuesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListQueueTagsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_queue_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_queue_tags_request(&self, input: ListQueueTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListQueueTags");
         let mut params = Params::new();
 
-        params.put("Action", "ListQueues");
+        params.put("Action", "ListQueueTags");
         params.put("Version", "2012-11-05");
-        ListQueuesRequestSerializer::serialize(&mut params, "", &input);
+        ListQueueTagsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_queues`, also returning the metadata of the response.
+    pub async fn list_queues_with_metadata(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<(ListQueuesResult, ResponseMetadata), RusotoError<ListQueuesError>> {
+        let request = self.build_list_queues_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListQueuesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3699,62 +4112,107 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the messages in a queue specified by the <code>QueueURL</code> parameter.</p> <important> <p>When you use the <code>PurgeQueue</code> action, you can't retrieve any messages deleted from a queue.</p> <p>The message deletion process takes up to 60 seconds. We recommend waiting for 60 seconds regardless of your queue's size. </p> </important> <p>Messages sent to the queue <i>before</i> you call <code>PurgeQueue</code> might be received but are deleted within the next minute.</p> <p>Messages sent to the queue <i>after</i> you call <code>PurgeQueue</code> might be deleted while the queue is being purged.</p>
-    async fn purge_queue(
+    /// Presigns the request `list_queues` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_queues(
         &self,
-        input: PurgeQueueRequest,
-    ) -> Result<(), RusotoError<PurgeQueueError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListQueuesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_queues_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_queues_request(&self, input: ListQueuesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListQueues");
         let mut params = Params::new();
 
-        params.put("Action", "PurgeQueue");
+        params.put("Action", "ListQueues");
         params.put("Version", "2012-11-05");
-        PurgeQueueRequestSerializer::serialize(&mut params, "", &input);
+        ListQueuesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `purge_queue`, also returning the metadata of the response.
+    pub async fn purge_queue_with_metadata(
+        &self,
+        input: PurgeQueueRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PurgeQueueError>> {
+        let request = self.build_purge_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PurgeQueueError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Retrieves one or more messages (up to 10), from the specified queue. Using the <code>WaitTimeSeconds</code> parameter enables long-poll support. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-long-polling.html">Amazon SQS Long Polling</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <p>Short poll is the default behavior where a weighted random set of machines is sampled on a <code>ReceiveMessage</code> call. Thus, only the messages on the sampled machines are returned. If the number of messages in the queue is small (fewer than 1,000), you most likely get fewer messages than you requested per <code>ReceiveMessage</code> call. If the number of messages in the queue is extremely small, you might not receive any messages in a particular <code>ReceiveMessage</code> response. If this happens, repeat the request. </p> <p>For each message returned, the response includes the following:</p> <ul> <li> <p>The message body.</p> </li> <li> <p>An MD5 digest of the message body. For information about MD5, see <a href="https://www.ietf.org/rfc/rfc1321.txt">RFC1321</a>.</p> </li> <li> <p>The <code>MessageId</code> you received when you sent the message to the queue.</p> </li> <li> <p>The receipt handle.</p> </li> <li> <p>The message attributes.</p> </li> <li> <p>An MD5 digest of the message attributes.</p> </li> </ul> <p>The receipt handle is the identifier you must provide when deleting the message. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-message-identifiers.html">Queue and Message Identifiers</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>You can provide the <code>VisibilityTimeout</code> parameter in your request. The parameter is applied to the messages that Amazon SQS returns in the response. If you don&#39;t include the parameter, the overall visibility timeout for the queue is used for the returned messages. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>A message that isn&#39;t deleted or a message whose visibility isn&#39;t extended before the visibility timeout expires counts as a failed receive. Depending on the configuration of the queue, the message might be sent to the dead-letter queue.</p> <note> <p>In the future, new attributes might be added. If you write code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.</p> </note></p>
-    async fn receive_message(
+    /// Presigns the request `purge_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_purge_queue(
         &self,
-        input: ReceiveMessageRequest,
-    ) -> Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PurgeQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_purge_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_purge_queue_request(&self, input: PurgeQueueRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("PurgeQueue");
         let mut params = Params::new();
 
-        params.put("Action", "ReceiveMessage");
+        params.put("Action", "PurgeQueue");
         params.put("Version", "2012-11-05");
-        ReceiveMessageRequestSerializer::serialize(&mut params, "", &input);
+        PurgeQueueRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `receive_message`, also returning the metadata of the response.
+    pub async fn receive_message_with_metadata(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> Result<(ReceiveMessageResult, ResponseMetadata), RusotoError<ReceiveMessageError>> {
+        let request = self.build_receive_message_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ReceiveMessageError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3774,62 +4232,107 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Revokes any permissions in the queue policy that matches the specified <code>Label</code> parameter.</p> <note> <ul> <li> <p>Only the owner of a queue can remove permissions from it.</p> </li> <li> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note></p>
-    async fn remove_permission(
+    /// Presigns the request `receive_message` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_receive_message(
         &self,
-        input: RemovePermissionRequest,
-    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ReceiveMessageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_receive_message_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_receive_message_request(&self, input: ReceiveMessageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ReceiveMessage");
         let mut params = Params::new();
 
-        params.put("Action", "RemovePermission");
+        params.put("Action", "ReceiveMessage");
         params.put("Version", "2012-11-05");
-        RemovePermissionRequestSerializer::serialize(&mut params, "", &input);
+        ReceiveMessageRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_permission`, also returning the metadata of the response.
+    pub async fn remove_permission_with_metadata(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemovePermissionError>> {
+        let request = self.build_remove_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemovePermissionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Delivers a message to the specified queue.</p> <important> <p>A message can include only XML, JSON, and unformatted text. The following Unicode characters are allowed:</p> <p> <code>#x9</code> | <code>#xA</code> | <code>#xD</code> | <code>#x20</code> to <code>#xD7FF</code> | <code>#xE000</code> to <code>#xFFFD</code> | <code>#x10000</code> to <code>#x10FFFF</code> </p> <p>Any characters not included in this list will be rejected. For more information, see the <a href="http://www.w3.org/TR/REC-xml/#charsets">W3C specification for characters</a>.</p> </important></p>
-    async fn send_message(
+    /// Presigns the request `remove_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_permission(
         &self,
-        input: SendMessageRequest,
-    ) -> Result<SendMessageResult, RusotoError<SendMessageError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RemovePermissionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_permission_request(&self, input: RemovePermissionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("RemovePermission");
         let mut params = Params::new();
 
-        params.put("Action", "SendMessage");
+        params.put("Action", "RemovePermission");
         params.put("Version", "2012-11-05");
-        SendMessageRequestSerializer::serialize(&mut params, "", &input);
+        RemovePermissionRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `send_message`, also returning the metadata of the response.
+    pub async fn send_message_with_metadata(
+        &self,
+        input: SendMessageRequest,
+    ) -> Result<(SendMessageResult, ResponseMetadata), RusotoError<SendMessageError>> {
+        let request = self.build_send_message_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SendMessageError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3848,34 +4351,59 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Delivers up to ten messages to the specified queue. This is a batch version of <code> <a>SendMessage</a>.</code> For a FIFO queue, multiple messages within a single batch are enqueued in the order they are sent.</p> <p>The result of sending each message is reported individually in the response. Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of <code>200</code>.</p> <p>The maximum allowed individual message size and the maximum total payload size (the sum of the individual lengths of all of the batched messages) are both 256 KB (262,144 bytes).</p> <important> <p>A message can include only XML, JSON, and unformatted text. The following Unicode characters are allowed:</p> <p> <code>#x9</code> | <code>#xA</code> | <code>#xD</code> | <code>#x20</code> to <code>#xD7FF</code> | <code>#xE000</code> to <code>#xFFFD</code> | <code>#x10000</code> to <code>#x10FFFF</code> </p> <p>Any characters not included in this list will be rejected. For more information, see the <a href="http://www.w3.org/TR/REC-xml/#charsets">W3C specification for characters</a>.</p> </important> <p>If you don't specify the <code>DelaySeconds</code> parameter for an entry, Amazon SQS uses the default value for the queue.</p> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p>
-    async fn send_message_batch(
+    /// Presigns the request `send_message` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_message(
         &self,
-        input: SendMessageBatchRequest,
-    ) -> Result<SendMessageBatchResult, RusotoError<SendMessageBatchError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SendMessageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_message_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_message_request(&self, input: SendMessageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SendMessage");
         let mut params = Params::new();
 
-        params.put("Action", "SendMessageBatch");
+        params.put("Action", "SendMessage");
         params.put("Version", "2012-11-05");
-        SendMessageBatchRequestSerializer::serialize(&mut params, "", &input);
+        SendMessageRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `send_message_batch`, also returning the metadata of the response.
+    pub async fn send_message_batch_with_metadata(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> Result<(SendMessageBatchResult, ResponseMetadata), RusotoError<SendMessageBatchError>>
+    {
+        let request = self.build_send_message_batch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SendMessageBatchError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -3897,88 +4425,383 @@ impl Sqs for SqsClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Sets the value of one or more queue attributes. When you change a queue&#39;s attributes, the change can take up to 60 seconds for most of the attributes to propagate throughout the Amazon SQS system. Changes made to the <code>MessageRetentionPeriod</code> attribute can take up to 15 minutes.</p> <note> <ul> <li> <p>In the future, new attributes might be added. If you write code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.</p> </li> <li> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note></p>
-    async fn set_queue_attributes(
+    /// Presigns the request `send_message_batch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_message_batch(
         &self,
-        input: SetQueueAttributesRequest,
-    ) -> Result<(), RusotoError<SetQueueAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SendMessageBatchRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_message_batch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_message_batch_request(&self, input: SendMessageBatchRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SendMessageBatch");
         let mut params = Params::new();
 
-        params.put("Action", "SetQueueAttributes");
+        params.put("Action", "SendMessageBatch");
         params.put("Version", "2012-11-05");
-        SetQueueAttributesRequestSerializer::serialize(&mut params, "", &input);
+        SendMessageBatchRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_queue_attributes`, also returning the metadata of the response.
+    pub async fn set_queue_attributes_with_metadata(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetQueueAttributesError>> {
+        let request = self.build_set_queue_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetQueueAttributesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Add cost allocation tags to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>When you use queue tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a queue isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SQS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-limits.html#limits-queues">Limits Related to Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
-    async fn tag_queue(&self, input: TagQueueRequest) -> Result<(), RusotoError<TagQueueError>> {
+    /// Presigns the request `set_queue_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_queue_attributes(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SetQueueAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_queue_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_queue_attributes_request(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SetQueueAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "TagQueue");
+        params.put("Action", "SetQueueAttributes");
         params.put("Version", "2012-11-05");
-        TagQueueRequestSerializer::serialize(&mut params, "", &input);
+        SetQueueAttributesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_queue`, also returning the metadata of the response.
+    pub async fn tag_queue_with_metadata(
+        &self,
+        input: TagQueueRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagQueueError>> {
+        let request = self.build_tag_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(TagQueueError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Remove cost allocation tags from the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
-    async fn untag_queue(
+    /// Presigns the request `tag_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_queue(
         &self,
-        input: UntagQueueRequest,
-    ) -> Result<(), RusotoError<UntagQueueError>> {
+        presigner: &rusoto_core::Presigner,
+        input: TagQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_queue_request(&self, input: TagQueueRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("TagQueue");
         let mut params = Params::new();
 
-        params.put("Action", "UntagQueue");
+        params.put("Action", "TagQueue");
         params.put("Version", "2012-11-05");
-        UntagQueueRequestSerializer::serialize(&mut params, "", &input);
+        TagQueueRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `untag_queue`, also returning the metadata of the response.
+    pub async fn untag_queue_with_metadata(
+        &self,
+        input: UntagQueueRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagQueueError>> {
+        let request = self.build_untag_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UntagQueueError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `untag_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_queue(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UntagQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_queue_request(&self, input: UntagQueueRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("UntagQueue");
+        let mut params = Params::new();
+
+        params.put("Action", "UntagQueue");
+        params.put("Version", "2012-11-05");
+        UntagQueueRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+}
+
+#[async_trait]
+impl Sqs for SqsClient {
+    /// <p><p>Adds a permission to a queue for a specific <a href="https://docs.aws.amazon.com/general/latest/gr/glos-chap.html#P">principal</a>. This allows sharing access to the queue.</p> <p>When you create a queue, you have full control access rights for the queue. Only you, the owner of the queue, can grant or deny permissions to the queue. For more information about these permissions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-writing-an-sqs-policy.html#write-messages-to-shared-queue">Allow Developers to Write Messages to a Shared Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <ul> <li> <p> <code>AddPermission</code> generates a policy for you. You can use <code> <a>SetQueueAttributes</a> </code> to upload your policy. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-creating-custom-policies.html">Using Custom Policies with the Amazon SQS Access Policy Language</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>An Amazon SQS policy can have a maximum of 7 actions.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    async fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> Result<(), RusotoError<AddPermissionError>> {
+        self.add_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Changes the visibility timeout of a specified message in a queue to a new value. The default visibility timeout for a message is 30 seconds. The minimum is 0 seconds. The maximum is 12 hours. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>For example, you have a message with a visibility timeout of 5 minutes. After 3 minutes, you call <code>ChangeMessageVisibility</code> with a timeout of 10 minutes. You can continue to call <code>ChangeMessageVisibility</code> to extend the visibility timeout to the maximum allowed time. If you try to extend the visibility timeout beyond the maximum, your request is rejected.</p> <p>An Amazon SQS message has three basic states:</p> <ol> <li> <p>Sent to a queue by a producer.</p> </li> <li> <p>Received from the queue by a consumer.</p> </li> <li> <p>Deleted from the queue.</p> </li> </ol> <p>A message is considered to be <i>stored</i> after it is sent to a queue by a producer, but not yet received from the queue by a consumer (that is, between states 1 and 2). There is no limit to the number of stored messages. A message is considered to be <i>in flight</i> after it is received from a queue by a consumer, but not yet deleted from the queue (that is, between states 2 and 3). There is a limit to the number of inflight messages.</p> <p>Limits that apply to inflight messages are unrelated to the <i>unlimited</i> number of stored messages.</p> <p>For most standard queues (depending on queue traffic and message backlog), there can be a maximum of approximately 120,000 inflight messages (received from a queue by a consumer, but not yet deleted from the queue). If you reach this limit, Amazon SQS returns the <code>OverLimit</code> error message. To avoid reaching the limit, you should delete messages from the queue after they&#39;re processed. You can also increase the number of queues you use to process your messages. To request a limit increase, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-sqs">file a support request</a>.</p> <p>For FIFO queues, there can be a maximum of 20,000 inflight messages (received from a queue by a consumer, but not yet deleted from the queue). If you reach this limit, Amazon SQS returns no error messages.</p> <important> <p>If you attempt to set the <code>VisibilityTimeout</code> to a value greater than the maximum time left, Amazon SQS returns an error. Amazon SQS doesn&#39;t automatically recalculate and increase the timeout to the maximum remaining time.</p> <p>Unlike with a queue, when you change the visibility timeout for a specific message the timeout value is applied immediately but isn&#39;t saved in memory for that message. If you don&#39;t delete a message after it is received, the visibility timeout for the message reverts to the original timeout value (not to the value you set using the <code>ChangeMessageVisibility</code> action) the next time the message is received.</p> </important></p>
+    async fn change_message_visibility(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> Result<(), RusotoError<ChangeMessageVisibilityError>> {
+        self.change_message_visibility_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the visibility timeout of multiple messages. This is a batch version of <code> <a>ChangeMessageVisibility</a>.</code> The result of the action on each message is reported individually in the response. You can send up to 10 <code> <a>ChangeMessageVisibility</a> </code> requests with each <code>ChangeMessageVisibilityBatch</code> action.</p> <important> <p>Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of <code>200</code>.</p> </important> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p>
+    async fn change_message_visibility_batch(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> Result<ChangeMessageVisibilityBatchResult, RusotoError<ChangeMessageVisibilityBatchError>>
+    {
+        self.change_message_visibility_batch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new standard or FIFO queue. You can pass one or more attributes in the request. Keep the following caveats in mind:</p> <ul> <li> <p>If you don&#39;t specify the <code>FifoQueue</code> attribute, Amazon SQS creates a standard queue.</p> <note> <p>You can&#39;t change the queue type after you create it and you can&#39;t convert an existing standard queue into a FIFO queue. You must either create a new FIFO queue for your application or delete your existing standard queue and recreate it as a FIFO queue. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-moving">Moving From a Standard Queue to a FIFO Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> </note> </li> <li> <p>If you don&#39;t provide a value for an attribute, the queue is created with the default value for the attribute.</p> </li> <li> <p>If you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.</p> </li> </ul> <p>To successfully create a new queue, you must provide a queue name that adheres to the <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/limits-queues.html">limits related to queues</a> and is unique within the scope of your queues.</p> <p>To get the queue URL, use the <code> <a>GetQueueUrl</a> </code> action. <code> <a>GetQueueUrl</a> </code> requires only the <code>QueueName</code> parameter. be aware of existing queue names:</p> <ul> <li> <p>If you provide the name of an existing queue along with the exact names and values of all the queue&#39;s attributes, <code>CreateQueue</code> returns the queue URL for the existing queue.</p> </li> <li> <p>If the queue name, attribute names, or attribute values don&#39;t match an existing queue, <code>CreateQueue</code> returns an error.</p> </li> </ul> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    async fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> Result<CreateQueueResult, RusotoError<CreateQueueError>> {
+        self.create_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified message from the specified queue. To select the message to delete, use the <code>ReceiptHandle</code> of the message (<i>not</i> the <code>MessageId</code> which you receive when you send the message). Amazon SQS can delete a message from a queue even if a visibility timeout setting causes the message to be locked by another consumer. Amazon SQS automatically deletes messages left in a queue longer than the retention period configured for the queue. </p> <note> <p>The <code>ReceiptHandle</code> is associated with a <i>specific instance</i> of receiving a message. If you receive a message more than once, the <code>ReceiptHandle</code> is different each time you receive a message. When you use the <code>DeleteMessage</code> action, you must provide the most recently received <code>ReceiptHandle</code> for the message (otherwise, the request succeeds, but the message might not be deleted).</p> <p>For standard queues, it is possible to receive a message even after you delete it. This might happen on rare occasions if one of the servers which stores a copy of the message is unavailable when you send the request to delete the message. The copy remains on the server and might be returned to you during a subsequent receive request. You should ensure that your application is idempotent, so that receiving a message more than once does not cause issues.</p> </note></p>
+    async fn delete_message(
+        &self,
+        input: DeleteMessageRequest,
+    ) -> Result<(), RusotoError<DeleteMessageError>> {
+        self.delete_message_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes up to ten messages from the specified queue. This is a batch version of <code> <a>DeleteMessage</a>.</code> The result of the action on each message is reported individually in the response.</p> <important> <p>Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of <code>200</code>.</p> </important> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p>
+    async fn delete_message_batch(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> Result<DeleteMessageBatchResult, RusotoError<DeleteMessageBatchError>> {
+        self.delete_message_batch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the queue specified by the <code>QueueUrl</code>, regardless of the queue&#39;s contents. If the specified queue doesn&#39;t exist, Amazon SQS returns a successful response.</p> <important> <p>Be careful with the <code>DeleteQueue</code> action: When you delete a queue, any messages in the queue are no longer available. </p> </important> <p>When you delete a queue, the deletion process takes up to 60 seconds. Requests you send involving that queue during the 60 seconds might succeed. For example, a <code> <a>SendMessage</a> </code> request might succeed, but after 60 seconds the queue and the message you sent no longer exist.</p> <p>When you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    async fn delete_queue(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> Result<(), RusotoError<DeleteQueueError>> {
+        self.delete_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets attributes for the specified queue.</p> <note> <p>To determine whether a queue is <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO</a>, you can check whether <code>QueueName</code> ends with the <code>.fifo</code> suffix.</p> </note> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p>
+    async fn get_queue_attributes(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> Result<GetQueueAttributesResult, RusotoError<GetQueueAttributesError>> {
+        self.get_queue_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the URL of an existing Amazon SQS queue.</p> <p>To access a queue that belongs to another AWS account, use the <code>QueueOwnerAWSAccountId</code> parameter to specify the account ID of the queue's owner. The queue's owner must grant you permission to access the queue. For more information about shared queue access, see <code> <a>AddPermission</a> </code> or see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-writing-an-sqs-policy.html#write-messages-to-shared-queue">Allow Developers to Write Messages to a Shared Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p>
+    async fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>> {
+        self.get_queue_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of your queues that have the <code>RedrivePolicy</code> queue attribute configured with a dead-letter queue.</p> <p>For more information about using dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
+    async fn list_dead_letter_source_queues(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> Result<ListDeadLetterSourceQueuesResult, RusotoError<ListDeadLetterSourceQueuesError>>
+    {
+        self.list_dead_letter_source_queues_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>List all cost allocation tags added to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    async fn list_queue_tags(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>> {
+        self.list_queue_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a list of your queues. The maximum number of queues that can be returned is 1,000. If you specify a value for the optional <code>QueueNamePrefix</code> parameter, only queues with a name that begins with the specified value are returned.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    async fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<ListQueuesResult, RusotoError<ListQueuesError>> {
+        self.list_queues_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the messages in a queue specified by the <code>QueueURL</code> parameter.</p> <important> <p>When you use the <code>PurgeQueue</code> action, you can't retrieve any messages deleted from a queue.</p> <p>The message deletion process takes up to 60 seconds. We recommend waiting for 60 seconds regardless of your queue's size. </p> </important> <p>Messages sent to the queue <i>before</i> you call <code>PurgeQueue</code> might be received but are deleted within the next minute.</p> <p>Messages sent to the queue <i>after</i> you call <code>PurgeQueue</code> might be deleted while the queue is being purged.</p>
+    async fn purge_queue(
+        &self,
+        input: PurgeQueueRequest,
+    ) -> Result<(), RusotoError<PurgeQueueError>> {
+        self.purge_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves one or more messages (up to 10), from the specified queue. Using the <code>WaitTimeSeconds</code> parameter enables long-poll support. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-long-polling.html">Amazon SQS Long Polling</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <p>Short poll is the default behavior where a weighted random set of machines is sampled on a <code>ReceiveMessage</code> call. Thus, only the messages on the sampled machines are returned. If the number of messages in the queue is small (fewer than 1,000), you most likely get fewer messages than you requested per <code>ReceiveMessage</code> call. If the number of messages in the queue is extremely small, you might not receive any messages in a particular <code>ReceiveMessage</code> response. If this happens, repeat the request. </p> <p>For each message returned, the response includes the following:</p> <ul> <li> <p>The message body.</p> </li> <li> <p>An MD5 digest of the message body. For information about MD5, see <a href="https://www.ietf.org/rfc/rfc1321.txt">RFC1321</a>.</p> </li> <li> <p>The <code>MessageId</code> you received when you sent the message to the queue.</p> </li> <li> <p>The receipt handle.</p> </li> <li> <p>The message attributes.</p> </li> <li> <p>An MD5 digest of the message attributes.</p> </li> </ul> <p>The receipt handle is the identifier you must provide when deleting the message. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-message-identifiers.html">Queue and Message Identifiers</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>You can provide the <code>VisibilityTimeout</code> parameter in your request. The parameter is applied to the messages that Amazon SQS returns in the response. If you don&#39;t include the parameter, the overall visibility timeout for the queue is used for the returned messages. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>A message that isn&#39;t deleted or a message whose visibility isn&#39;t extended before the visibility timeout expires counts as a failed receive. Depending on the configuration of the queue, the message might be sent to the dead-letter queue.</p> <note> <p>In the future, new attributes might be added. If you write code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.</p> </note></p>
+    async fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>> {
+        self.receive_message_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Revokes any permissions in the queue policy that matches the specified <code>Label</code> parameter.</p> <note> <ul> <li> <p>Only the owner of a queue can remove permissions from it.</p> </li> <li> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note></p>
+    async fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.remove_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Delivers a message to the specified queue.</p> <important> <p>A message can include only XML, JSON, and unformatted text. The following Unicode characters are allowed:</p> <p> <code>#x9</code> | <code>#xA</code> | <code>#xD</code> | <code>#x20</code> to <code>#xD7FF</code> | <code>#xE000</code> to <code>#xFFFD</code> | <code>#x10000</code> to <code>#x10FFFF</code> </p> <p>Any characters not included in this list will be rejected. For more information, see the <a href="http://www.w3.org/TR/REC-xml/#charsets">W3C specification for characters</a>.</p> </important></p>
+    async fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> Result<SendMessageResult, RusotoError<SendMessageError>> {
+        self.send_message_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delivers up to ten messages to the specified queue. This is a batch version of <code> <a>SendMessage</a>.</code> For a FIFO queue, multiple messages within a single batch are enqueued in the order they are sent.</p> <p>The result of sending each message is reported individually in the response. Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of <code>200</code>.</p> <p>The maximum allowed individual message size and the maximum total payload size (the sum of the individual lengths of all of the batched messages) are both 256 KB (262,144 bytes).</p> <important> <p>A message can include only XML, JSON, and unformatted text. The following Unicode characters are allowed:</p> <p> <code>#x9</code> | <code>#xA</code> | <code>#xD</code> | <code>#x20</code> to <code>#xD7FF</code> | <code>#xE000</code> to <code>#xFFFD</code> | <code>#x10000</code> to <code>#x10FFFF</code> </p> <p>Any characters not included in this list will be rejected. For more information, see the <a href="http://www.w3.org/TR/REC-xml/#charsets">W3C specification for characters</a>.</p> </important> <p>If you don't specify the <code>DelaySeconds</code> parameter for an entry, Amazon SQS uses the default value for the queue.</p> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p>
+    async fn send_message_batch(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> Result<SendMessageBatchResult, RusotoError<SendMessageBatchError>> {
+        self.send_message_batch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the value of one or more queue attributes. When you change a queue&#39;s attributes, the change can take up to 60 seconds for most of the attributes to propagate throughout the Amazon SQS system. Changes made to the <code>MessageRetentionPeriod</code> attribute can take up to 15 minutes.</p> <note> <ul> <li> <p>In the future, new attributes might be added. If you write code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.</p> </li> <li> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note></p>
+    async fn set_queue_attributes(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> Result<(), RusotoError<SetQueueAttributesError>> {
+        self.set_queue_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Add cost allocation tags to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>When you use queue tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a queue isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SQS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-limits.html#limits-queues">Limits Related to Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    async fn tag_queue(&self, input: TagQueueRequest) -> Result<(), RusotoError<TagQueueError>> {
+        self.tag_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Remove cost allocation tags from the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    async fn untag_queue(
+        &self,
+        input: UntagQueueRequest,
+    ) -> Result<(), RusotoError<UntagQueueError>> {
+        self.untag_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 